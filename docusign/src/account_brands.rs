@@ -32,6 +32,24 @@ impl AccountBrands {
         exclude_distributor_brand: &str,
         include_logos: &str,
     ) -> ClientResult<crate::types::AccountBrands> {
+        Ok(self
+            .brands_get_with_response(account_id, exclude_distributor_brand, include_logos)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of brands.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands` endpoint.
+     *
+     * As opposed to `brands_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brands_get_with_response(
+        &self,
+        account_id: &str,
+        exclude_distributor_brand: &str,
+        include_logos: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_distributor_brand.is_empty() {
             query_args.push((
@@ -52,7 +70,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -83,6 +101,20 @@ impl AccountBrands {
         account_id: &str,
         body: &crate::types::Brand,
     ) -> ClientResult<crate::types::AccountBrands> {
+        Ok(self.brands_post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Creates one or more brand profiles for an account.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/brands` endpoint.
+     *
+     * As opposed to `brands_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brands_post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::Brand,
+    ) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands",
@@ -91,7 +123,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -118,6 +150,23 @@ impl AccountBrands {
         account_id: &str,
         body: &crate::types::BrandsRequest,
     ) -> ClientResult<crate::types::AccountBrands> {
+        Ok(self
+            .brands_delete_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes one or more brand profiles.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/brands` endpoint.
+     *
+     * As opposed to `brands_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brands_delete_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AccountBrands>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands",
@@ -126,7 +175,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -158,6 +207,30 @@ impl AccountBrands {
         include_external_references: &str,
         include_logos: &str,
     ) -> ClientResult<crate::types::Brand> {
+        Ok(self
+            .brand_get_with_response(
+                account_id,
+                brand_id,
+                include_external_references,
+                include_logos,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets information about a brand.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}` endpoint.
+     *
+     * As opposed to `brand_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_get_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        include_external_references: &str,
+        include_logos: &str,
+    ) -> ClientResult<crate::Response<crate::types::Brand>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_external_references.is_empty() {
             query_args.push((
@@ -179,7 +252,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -208,6 +281,24 @@ impl AccountBrands {
         brand_id: &str,
         body: &crate::types::Brand,
     ) -> ClientResult<crate::types::Brand> {
+        Ok(self
+            .brand_put_with_response(account_id, brand_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an existing brand.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/brands/{brandId}` endpoint.
+     *
+     * As opposed to `brand_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_put_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        body: &crate::types::Brand,
+    ) -> ClientResult<crate::Response<crate::types::Brand>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}",
@@ -217,7 +308,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -241,6 +332,22 @@ impl AccountBrands {
      * * `brand_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn brand_delete(&self, account_id: &str, brand_id: &str) -> ClientResult<()> {
+        self.brand_delete_with_response(account_id, brand_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a brand.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/brands/{brandId}` endpoint.
+     *
+     * As opposed to `brand_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_delete_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}",
@@ -250,7 +357,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -278,6 +385,22 @@ impl AccountBrands {
         account_id: &str,
         brand_id: &str,
     ) -> ClientResult<()> {
+        self.brand_export_get_file_with_response(account_id, brand_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Exports a brand.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}/file` endpoint.
+     *
+     * As opposed to `brand_export_get_file`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_export_get_file_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}/file",
@@ -287,7 +410,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -317,6 +440,23 @@ impl AccountBrands {
         brand_id: &str,
         logo_type: &str,
     ) -> ClientResult<()> {
+        self.brand_logo_get_with_response(account_id, brand_id, logo_type)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Gets a brand logo.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}` endpoint.
+     *
+     * As opposed to `brand_logo_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_logo_get_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}/logos/{}",
@@ -327,7 +467,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -362,6 +502,24 @@ impl AccountBrands {
         logo_type: &str,
         body: bytes::Bytes,
     ) -> ClientResult<()> {
+        self.brand_logo_put_with_response::<T>(account_id, brand_id, logo_type, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Updates a brand logo.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}` endpoint.
+     *
+     * As opposed to `brand_logo_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_logo_put_with_response<T: Into<reqwest::Body>>(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+        body: bytes::Bytes,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}/logos/{}",
@@ -372,7 +530,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(body.into()),
@@ -402,6 +560,23 @@ impl AccountBrands {
         brand_id: &str,
         logo_type: &str,
     ) -> ClientResult<()> {
+        self.brand_logo_delete_with_response(account_id, brand_id, logo_type)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a brand logo.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}` endpoint.
+     *
+     * As opposed to `brand_logo_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_logo_delete_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}/logos/{}",
@@ -412,7 +587,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -440,6 +615,23 @@ impl AccountBrands {
         account_id: &str,
         brand_id: &str,
     ) -> ClientResult<crate::types::BrandResourcesList> {
+        Ok(self
+            .brand_resources_get_list_with_response(account_id, brand_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns metadata about the branding resources for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}/resources` endpoint.
+     *
+     * As opposed to `brand_resources_get_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_resources_get_list_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BrandResourcesList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}/resources",
@@ -449,7 +641,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -492,6 +684,31 @@ impl AccountBrands {
         langcode: &str,
         return_master: &str,
     ) -> ClientResult<()> {
+        self.brand_resources_get_with_response(
+            account_id,
+            brand_id,
+            resource_content_type,
+            langcode,
+            return_master,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Returns a branding resource file.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}` endpoint.
+     *
+     * As opposed to `brand_resources_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_resources_get_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        langcode: &str,
+        return_master: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !langcode.is_empty() {
             query_args.push(("langcode".to_string(), langcode.to_string()));
@@ -511,7 +728,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -554,6 +771,24 @@ impl AccountBrands {
         brand_id: &str,
         resource_content_type: &str,
     ) -> ClientResult<crate::types::BrandResources> {
+        Ok(self
+            .brand_resources_put_with_response(account_id, brand_id, resource_content_type)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a branding resource file.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}` endpoint.
+     *
+     * As opposed to `brand_resources_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brand_resources_put_with_response(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::BrandResources>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/brands/{}/resources/{}",
@@ -564,7 +799,7 @@ impl AccountBrands {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
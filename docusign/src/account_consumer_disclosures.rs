@@ -78,6 +78,23 @@ impl AccountConsumerDisclosures {
         account_id: &str,
         lang_code: &str,
     ) -> ClientResult<crate::types::AccountConsumerDisclosures> {
+        Ok(self
+            .consumer_disclosure_get_with_response(account_id, lang_code)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the default Electronic Record and Signature Disclosure for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/consumer_disclosure` endpoint.
+     *
+     * As opposed to `consumer_disclosure_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn consumer_disclosure_get_with_response(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountConsumerDisclosures>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !lang_code.is_empty() {
             query_args.push(("langCode".to_string(), lang_code.to_string()));
@@ -92,7 +109,7 @@ impl AccountConsumerDisclosures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -168,6 +185,23 @@ impl AccountConsumerDisclosures {
         account_id: &str,
         lang_code: &str,
     ) -> ClientResult<crate::types::AccountConsumerDisclosures> {
+        Ok(self
+            .consumer_disclosure_get_lang_code_with_response(account_id, lang_code)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the Electronic Record and Signature Disclosure for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}` endpoint.
+     *
+     * As opposed to `consumer_disclosure_get_lang_code`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn consumer_disclosure_get_lang_code_with_response(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountConsumerDisclosures>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/consumer_disclosure/{}",
@@ -177,7 +211,7 @@ impl AccountConsumerDisclosures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -301,6 +335,25 @@ impl AccountConsumerDisclosures {
         include_metadata: &str,
         body: &crate::types::ConsumerDisclosure,
     ) -> ClientResult<crate::types::ConsumerDisclosure> {
+        Ok(self
+            .consumer_disclosure_put_with_response(account_id, lang_code, include_metadata, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates the Electronic Record and Signature Disclosure for an account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}` endpoint.
+     *
+     * As opposed to `consumer_disclosure_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn consumer_disclosure_put_with_response(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        include_metadata: &str,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_metadata.is_empty() {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
@@ -316,7 +369,7 @@ impl AccountConsumerDisclosures {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -23,6 +23,19 @@ impl AccountCustomFields {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn get(&self, account_id: &str) -> ClientResult<crate::types::AccountCustomFields> {
+        Ok(self.get_with_response(account_id).await?.body)
+    }
+    /**
+     * Gets a list of custom fields.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/custom_fields` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/custom_fields",
@@ -31,7 +44,7 @@ impl AccountCustomFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -58,6 +71,24 @@ impl AccountCustomFields {
         apply_to_templates: &str,
         body: &crate::types::CustomField,
     ) -> ClientResult<crate::types::AccountCustomFields> {
+        Ok(self
+            .post_with_response(account_id, apply_to_templates, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates an account custom field.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/custom_fields` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        apply_to_templates: &str,
+        body: &crate::types::CustomField,
+    ) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !apply_to_templates.is_empty() {
             query_args.push((
@@ -75,7 +106,7 @@ impl AccountCustomFields {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -104,6 +135,25 @@ impl AccountCustomFields {
         apply_to_templates: &str,
         body: &crate::types::CustomField,
     ) -> ClientResult<crate::types::AccountCustomFields> {
+        Ok(self
+            .put_with_response(account_id, custom_field_id, apply_to_templates, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an account custom field.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/custom_fields/{customFieldId}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        apply_to_templates: &str,
+        body: &crate::types::CustomField,
+    ) -> ClientResult<crate::Response<crate::types::AccountCustomFields>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !apply_to_templates.is_empty() {
             query_args.push((
@@ -122,7 +172,7 @@ impl AccountCustomFields {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -150,6 +200,23 @@ impl AccountCustomFields {
         custom_field_id: &str,
         apply_to_templates: &str,
     ) -> ClientResult<()> {
+        self.delete_with_response(account_id, custom_field_id, apply_to_templates)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes an account custom field.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/custom_fields/{customFieldId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        apply_to_templates: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !apply_to_templates.is_empty() {
             query_args.push((
@@ -168,7 +235,7 @@ impl AccountCustomFields {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
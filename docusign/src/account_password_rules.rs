@@ -26,6 +26,19 @@ impl AccountPasswordRules {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::AccountPasswordRulesData> {
+        Ok(self.get_with_response(account_id).await?.body)
+    }
+    /**
+     * Gets the password rules for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/password_rules` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountPasswordRulesData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/password_rules",
@@ -34,7 +47,7 @@ impl AccountPasswordRules {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -61,6 +74,20 @@ impl AccountPasswordRules {
         account_id: &str,
         body: &crate::types::AccountPasswordRulesData,
     ) -> ClientResult<crate::types::AccountPasswordRulesData> {
+        Ok(self.put_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Updates the password rules for an account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/settings/password_rules` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPasswordRulesData,
+    ) -> ClientResult<crate::Response<crate::types::AccountPasswordRulesData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/password_rules",
@@ -69,7 +96,7 @@ impl AccountPasswordRules {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -86,9 +113,21 @@ impl AccountPasswordRules {
      *
      */
     pub async fn password_rules_get(&self) -> ClientResult<crate::types::UserPasswordRules> {
+        Ok(self.password_rules_get_with_response().await?.body)
+    }
+    /**
+     * Gets membership account password rules.
+     *
+     * This function performs a `GET` to the `/v2.1/current_user/password_rules` endpoint.
+     *
+     * As opposed to `password_rules_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn password_rules_get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::UserPasswordRules>> {
         let url = self.client.url("/v2.1/current_user/password_rules", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -36,6 +36,23 @@ impl AccountPermissionProfiles {
         account_id: &str,
         include: &str,
     ) -> ClientResult<crate::types::PermissionProfileInformation> {
+        Ok(self
+            .permission_profiles_get_with_response(account_id, include)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of permission profiles.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/permission_profiles` endpoint.
+     *
+     * As opposed to `permission_profiles_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn permission_profiles_get_with_response(
+        &self,
+        account_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfileInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -50,7 +67,7 @@ impl AccountPermissionProfiles {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -77,6 +94,24 @@ impl AccountPermissionProfiles {
         include: &str,
         body: &crate::types::PermissionProfile,
     ) -> ClientResult<crate::types::PermissionProfile> {
+        Ok(self
+            .permission_profiles_post_with_response(account_id, include, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a new permission profile for an account.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/permission_profiles` endpoint.
+     *
+     * As opposed to `permission_profiles_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn permission_profiles_post_with_response(
+        &self,
+        account_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -91,7 +126,7 @@ impl AccountPermissionProfiles {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -125,6 +160,28 @@ impl AccountPermissionProfiles {
         permission_profile_id: &str,
         include: &str,
     ) -> ClientResult<crate::types::PermissionProfile> {
+        Ok(self
+            .permission_profiles_get_profile_with_response(
+                account_id,
+                permission_profile_id,
+                include,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns a permission profile for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}` endpoint.
+     *
+     * As opposed to `permission_profiles_get_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn permission_profiles_get_profile_with_response(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -140,7 +197,7 @@ impl AccountPermissionProfiles {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -175,6 +232,25 @@ impl AccountPermissionProfiles {
         include: &str,
         body: &crate::types::PermissionProfile,
     ) -> ClientResult<crate::types::PermissionProfile> {
+        Ok(self
+            .permission_profiles_put_with_response(account_id, permission_profile_id, include, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a permission profile.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}` endpoint.
+     *
+     * As opposed to `permission_profiles_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn permission_profiles_put_with_response(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> ClientResult<crate::Response<crate::types::PermissionProfile>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -190,7 +266,7 @@ impl AccountPermissionProfiles {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -226,6 +302,27 @@ impl AccountPermissionProfiles {
         permission_profile_id: &str,
         move_users_to: &str,
     ) -> ClientResult<()> {
+        self.permission_profiles_delete_with_response(
+            account_id,
+            permission_profile_id,
+            move_users_to,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a permission profile from an account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}` endpoint.
+     *
+     * As opposed to `permission_profiles_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn permission_profiles_delete_with_response(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !move_users_to.is_empty() {
             query_args.push(("move_users_to".to_string(), move_users_to.to_string()));
@@ -241,7 +338,7 @@ impl AccountPermissionProfiles {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -26,6 +26,22 @@ impl AccountSealProviders {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::AccountSealProviders> {
+        Ok(self
+            .account_signature_providers_get_seal_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns available seals for specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/seals` endpoint.
+     *
+     * As opposed to `account_signature_providers_get_seal`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_signature_providers_get_seal_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSealProviders>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/seals",
@@ -34,7 +50,7 @@ impl AccountSealProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -26,6 +26,19 @@ impl AccountSignatureProviders {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::AccountSignatureProvidersData> {
+        Ok(self.get_with_response(account_id).await?.body)
+    }
+    /**
+     * Gets the available signature providers for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatureProviders` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignatureProvidersData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signatureProviders",
@@ -34,7 +47,7 @@ impl AccountSignatureProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
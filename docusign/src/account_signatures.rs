@@ -32,6 +32,25 @@ impl AccountSignatures {
         stamp_name: &str,
         stamp_type: &str,
     ) -> ClientResult<crate::types::AccountSignaturesInformation> {
+        Ok(self
+            .get_with_response(account_id, stamp_format, stamp_name, stamp_type)
+            .await?
+            .body)
+    }
+    /**
+     * Returns the managed signature definitions for the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatures` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        stamp_format: &str,
+        stamp_name: &str,
+        stamp_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !stamp_format.is_empty() {
             query_args.push(("stamp_format".to_string(), stamp_format.to_string()));
@@ -52,7 +71,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -78,6 +97,23 @@ impl AccountSignatures {
         account_id: &str,
         body: &crate::types::AccountSignaturesInformation,
     ) -> ClientResult<crate::types::AccountSignaturesInformation> {
+        Ok(self
+            .put_signature_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an account signature.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signatures` endpoint.
+     *
+     * As opposed to `put_signature`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_signature_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signatures",
@@ -86,7 +122,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -114,6 +150,24 @@ impl AccountSignatures {
         decode_only: &str,
         body: &crate::types::AccountSignaturesInformation,
     ) -> ClientResult<crate::types::AccountSignaturesInformation> {
+        Ok(self
+            .post_with_response(account_id, decode_only, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds or updates one or more account signatures.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/signatures` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        decode_only: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignaturesInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !decode_only.is_empty() {
             query_args.push(("decode_only".to_string(), decode_only.to_string()));
@@ -128,7 +182,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -154,6 +208,23 @@ impl AccountSignatures {
         account_id: &str,
         signature_id: &str,
     ) -> ClientResult<crate::types::AccountSignature> {
+        Ok(self
+            .get_signature_with_response(account_id, signature_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns information about the specified signature.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `get_signature`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_signature_with_response(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signatures/{}",
@@ -163,7 +234,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -192,6 +263,30 @@ impl AccountSignatures {
         close_existing_signature: &str,
         body: &crate::types::AccountSignatureDefinition,
     ) -> ClientResult<crate::types::AccountSignature> {
+        Ok(self
+            .put_signature_account_signatures_with_response(
+                account_id,
+                signature_id,
+                close_existing_signature,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates an account signature.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `put_signature_account_signatures`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_signature_account_signatures_with_response(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !close_existing_signature.is_empty() {
             query_args.push((
@@ -210,7 +305,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -232,6 +327,22 @@ impl AccountSignatures {
      * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete_signature(&self, account_id: &str, signature_id: &str) -> ClientResult<()> {
+        self.delete_signature_with_response(account_id, signature_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Close the specified signature by ID.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `delete_signature`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_signature_with_response(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signatures/{}",
@@ -241,7 +352,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -274,6 +385,29 @@ impl AccountSignatures {
         signature_id: &str,
         include_chrome: &str,
     ) -> ClientResult<()> {
+        self.get_signature_image_with_response(
+            account_id,
+            image_type,
+            signature_id,
+            include_chrome,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Returns a signature image, initials, or stamp.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `get_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_signature_image_with_response(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -290,7 +424,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -323,6 +457,30 @@ impl AccountSignatures {
         signature_id: &str,
         transparent_png: &str,
     ) -> ClientResult<crate::types::AccountSignature> {
+        Ok(self
+            .put_signature_image_with_response(
+                account_id,
+                image_type,
+                signature_id,
+                transparent_png,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Sets a signature image, initials, or stamp.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `put_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_signature_image_with_response(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        transparent_png: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !transparent_png.is_empty() {
             query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
@@ -339,7 +497,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -370,6 +528,24 @@ impl AccountSignatures {
         image_type: &str,
         signature_id: &str,
     ) -> ClientResult<crate::types::AccountSignature> {
+        Ok(self
+            .delete_signature_image_with_response(account_id, image_type, signature_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes a signature image, initials, or stamp.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `delete_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_signature_image_with_response(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSignature>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signatures/{}/{}",
@@ -380,7 +556,7 @@ impl AccountSignatures {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
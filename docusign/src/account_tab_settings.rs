@@ -26,6 +26,19 @@ impl AccountTabSettings {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::TabAccountSettings> {
+        Ok(self.tab_settings_get_with_response(account_id).await?.body)
+    }
+    /**
+     * Returns tab settings list for specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/tabs` endpoint.
+     *
+     * As opposed to `tab_settings_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tab_settings_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TabAccountSettings>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/tabs",
@@ -34,7 +47,7 @@ impl AccountTabSettings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,6 +72,23 @@ impl AccountTabSettings {
         account_id: &str,
         body: &crate::types::TabAccountSettings,
     ) -> ClientResult<crate::types::TabAccountSettings> {
+        Ok(self
+            .tab_settings_put_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Modifies tab settings for specified account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/settings/tabs` endpoint.
+     *
+     * As opposed to `tab_settings_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tab_settings_put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabAccountSettings,
+    ) -> ClientResult<crate::Response<crate::types::TabAccountSettings>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/tabs",
@@ -67,7 +97,7 @@ impl AccountTabSettings {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
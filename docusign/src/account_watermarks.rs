@@ -23,6 +23,19 @@ impl AccountWatermarks {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn watermark_get(&self, account_id: &str) -> ClientResult<crate::types::Watermark> {
+        Ok(self.watermark_get_with_response(account_id).await?.body)
+    }
+    /**
+     * Get watermark information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/watermark` endpoint.
+     *
+     * As opposed to `watermark_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watermark_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Watermark>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/watermark",
@@ -31,7 +44,7 @@ impl AccountWatermarks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,6 +69,23 @@ impl AccountWatermarks {
         account_id: &str,
         body: &crate::types::Watermark,
     ) -> ClientResult<crate::types::Watermark> {
+        Ok(self
+            .watermark_put_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update watermark information.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/watermark` endpoint.
+     *
+     * As opposed to `watermark_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watermark_put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> ClientResult<crate::Response<crate::types::Watermark>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/watermark",
@@ -64,7 +94,7 @@ impl AccountWatermarks {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -89,6 +119,23 @@ impl AccountWatermarks {
         account_id: &str,
         body: &crate::types::Watermark,
     ) -> ClientResult<crate::types::Watermark> {
+        Ok(self
+            .watermark_preview_put_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Get watermark preview.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/watermark/preview` endpoint.
+     *
+     * As opposed to `watermark_preview_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watermark_preview_put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> ClientResult<crate::Response<crate::types::Watermark>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/watermark/preview",
@@ -97,7 +144,7 @@ impl AccountWatermarks {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
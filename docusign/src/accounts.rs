@@ -146,9 +146,22 @@ impl Accounts {
         &self,
         body: &crate::types::NewAccountDefinition,
     ) -> ClientResult<crate::types::NewAccountSummary> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Creates new accounts.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::NewAccountDefinition,
+    ) -> ClientResult<crate::Response<crate::types::NewAccountSummary>> {
         let url = self.client.url("/v2.1/accounts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -165,9 +178,21 @@ impl Accounts {
      * Retrieves the account provisioning information for the account.
      */
     pub async fn get_provisioning(&self) -> ClientResult<crate::types::ProvisioningInformation> {
+        Ok(self.get_provisioning_with_response().await?.body)
+    }
+    /**
+     * Retrieves the account provisioning information for the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/provisioning` endpoint.
+     *
+     * As opposed to `get_provisioning`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_provisioning_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ProvisioningInformation>> {
         let url = self.client.url("/v2.1/accounts/provisioning", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -197,6 +222,23 @@ impl Accounts {
         account_id: &str,
         include_account_settings: &str,
     ) -> ClientResult<crate::types::AccountInformation> {
+        Ok(self
+            .get_with_response(account_id, include_account_settings)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves the account information for the specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        include_account_settings: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_account_settings.is_empty() {
             query_args.push((
@@ -214,7 +256,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -235,6 +277,20 @@ impl Accounts {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete(&self, account_id: &str) -> ClientResult<()> {
+        self.delete_with_response(account_id).await?;
+        Ok(())
+    }
+    /**
+     * Deletes the specified account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}",
@@ -243,7 +299,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -276,6 +332,23 @@ impl Accounts {
         account_id: &str,
         include_charges: &str,
     ) -> ClientResult<crate::types::BillingChargeResponse> {
+        Ok(self
+            .billing_charges_get_with_response(account_id, include_charges)
+            .await?
+            .body)
+    }
+    /**
+     * Gets list of recurring and usage charges for the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_charges` endpoint.
+     *
+     * As opposed to `billing_charges_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn billing_charges_get_with_response(
+        &self,
+        account_id: &str,
+        include_charges: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingChargeResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_charges.is_empty() {
             query_args.push(("include_charges".to_string(), include_charges.to_string()));
@@ -290,7 +363,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -317,6 +390,24 @@ impl Accounts {
         recipient_part: &str,
         body: &crate::types::CaptiveRecipientInformation,
     ) -> ClientResult<crate::types::CaptiveRecipientInformation> {
+        Ok(self
+            .captive_recipients_delete_part_with_response(account_id, recipient_part, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the signature for one or more captive recipient records.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/captive_recipients/{recipientPart}` endpoint.
+     *
+     * As opposed to `captive_recipients_delete_part`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn captive_recipients_delete_part_with_response(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+        body: &crate::types::CaptiveRecipientInformation,
+    ) -> ClientResult<crate::Response<crate::types::CaptiveRecipientInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/captive_recipients/{}",
@@ -326,7 +417,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -352,6 +443,23 @@ impl Accounts {
         account_id: &str,
         email: &str,
     ) -> ClientResult<crate::types::RecipientNamesResponse> {
+        Ok(self
+            .recipient_names_get_with_response(account_id, email)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the recipient names associated with an email address.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/recipient_names` endpoint.
+     *
+     * As opposed to `recipient_names_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipient_names_get_with_response(
+        &self,
+        account_id: &str,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::RecipientNamesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
@@ -366,7 +474,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -390,6 +498,19 @@ impl Accounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::AccountSettingsInformation> {
+        Ok(self.settings_get_with_response(account_id).await?.body)
+    }
+    /**
+     * Gets account settings information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings` endpoint.
+     *
+     * As opposed to `settings_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn settings_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSettingsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings",
@@ -398,7 +519,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -423,6 +544,21 @@ impl Accounts {
         account_id: &str,
         body: &crate::types::AccountSettingsInformation,
     ) -> ClientResult<()> {
+        self.settings_put_with_response(account_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Updates the account settings for an account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/settings` endpoint.
+     *
+     * As opposed to `settings_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn settings_put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSettingsInformation,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings",
@@ -431,7 +567,7 @@ impl Accounts {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -457,6 +593,22 @@ impl Accounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::EnvelopePurgeConfiguration> {
+        Ok(self
+            .envelope_purge_configuration_get_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the envelope purge configuration for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/envelope_purge_configuration` endpoint.
+     *
+     * As opposed to `envelope_purge_configuration_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_purge_configuration_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopePurgeConfiguration>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/envelope_purge_configuration",
@@ -465,7 +617,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -494,6 +646,23 @@ impl Accounts {
         account_id: &str,
         body: &crate::types::EnvelopePurgeConfiguration,
     ) -> ClientResult<crate::types::EnvelopePurgeConfiguration> {
+        Ok(self
+            .envelope_purge_configuration_put_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Sets the envelope purge configuration for an account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/settings/envelope_purge_configuration` endpoint.
+     *
+     * As opposed to `envelope_purge_configuration_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_purge_configuration_put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopePurgeConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopePurgeConfiguration>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/envelope_purge_configuration",
@@ -502,7 +671,7 @@ impl Accounts {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -526,6 +695,22 @@ impl Accounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::NotificationDefaultsData> {
+        Ok(self
+            .notification_defaults_get_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets envelope notification defaults.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/notification_defaults` endpoint.
+     *
+     * As opposed to `notification_defaults_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn notification_defaults_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotificationDefaultsData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/notification_defaults",
@@ -534,7 +719,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -559,6 +744,23 @@ impl Accounts {
         account_id: &str,
         body: &crate::types::NotificationDefaultsData,
     ) -> ClientResult<crate::types::NotificationDefaultsData> {
+        Ok(self
+            .notification_defaults_put_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates envelope notification default settings.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/settings/notification_defaults` endpoint.
+     *
+     * As opposed to `notification_defaults_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn notification_defaults_put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::NotificationDefaultsData,
+    ) -> ClientResult<crate::Response<crate::types::NotificationDefaultsData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/notification_defaults",
@@ -567,7 +769,7 @@ impl Accounts {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -629,6 +831,40 @@ impl Accounts {
         start_position: &str,
         user_ids: &str,
     ) -> ClientResult<crate::types::AccountSharedAccess> {
+        Ok(self
+            .shared_access_get_with_response(
+                account_id,
+                count,
+                envelopes_not_shared_user_status,
+                folder_ids,
+                item_type,
+                search_text,
+                shared,
+                start_position,
+                user_ids,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Reserved: Gets the shared item status for one or more users.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/shared_access` endpoint.
+     *
+     * As opposed to `shared_access_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn shared_access_get_with_response(
+        &self,
+        account_id: &str,
+        count: &str,
+        envelopes_not_shared_user_status: &str,
+        folder_ids: &str,
+        item_type: &str,
+        search_text: &str,
+        shared: &str,
+        start_position: &str,
+        user_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -667,7 +903,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -714,6 +950,32 @@ impl Accounts {
         user_ids: &str,
         body: &crate::types::AccountSharedAccess,
     ) -> ClientResult<crate::types::AccountSharedAccess> {
+        Ok(self
+            .shared_access_put_with_response(
+                account_id,
+                item_type,
+                preserve_existing_shared_access,
+                user_ids,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Reserved: Sets the shared access information for users.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/shared_access` endpoint.
+     *
+     * As opposed to `shared_access_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn shared_access_put_with_response(
+        &self,
+        account_id: &str,
+        item_type: &str,
+        preserve_existing_shared_access: &str,
+        user_ids: &str,
+        body: &crate::types::AccountSharedAccess,
+    ) -> ClientResult<crate::Response<crate::types::AccountSharedAccess>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !item_type.is_empty() {
             query_args.push(("item_type".to_string(), item_type.to_string()));
@@ -737,7 +999,7 @@ impl Accounts {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -767,6 +1029,22 @@ impl Accounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::SupportedLanguages> {
+        Ok(self
+            .supported_languages_get_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the supported languages for envelope recipients.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/supported_languages` endpoint.
+     *
+     * As opposed to `supported_languages_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn supported_languages_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SupportedLanguages>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/supported_languages",
@@ -775,7 +1053,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -799,6 +1077,22 @@ impl Accounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::FileTypeList> {
+        Ok(self
+            .unsupported_file_types_get_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of unsupported file types.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/unsupported_file_types` endpoint.
+     *
+     * As opposed to `unsupported_file_types_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unsupported_file_types_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FileTypeList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/unsupported_file_types",
@@ -807,7 +1101,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -833,6 +1127,22 @@ impl Accounts {
         organization_id: &str,
         result_id: &str,
     ) -> ClientResult<()> {
+        self.organization_exports_get_settings_export_with_response(organization_id, result_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves an account settings comparison.
+     *
+     * This function performs a `GET` to the `/v2.1/organization_exports/{organizationId}/account_settings/{resultId}` endpoint.
+     *
+     * As opposed to `organization_exports_get_settings_export`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn organization_exports_get_settings_export_with_response(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/organization_exports/{}/account_settings/{}",
@@ -842,7 +1152,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -26,6 +26,22 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_dynamic_system_setting_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns document pages for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/display_appliance_info/dynamicsystemsettings` endpoint.
+     *
+     * As opposed to `envelope_get_dynamic_system_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_dynamic_system_setting_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/display_appliance_info/dynamicsystemsettings",
@@ -34,7 +50,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -58,6 +74,22 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_template_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns whether a template was encrypted by Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/display_appliance_info/templateInfo` endpoint.
+     *
+     * As opposed to `envelope_get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_template_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/display_appliance_info/templateInfo",
@@ -66,7 +98,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -92,6 +124,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns envelope and recipient information for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info` endpoint.
+     *
+     * As opposed to `envelope_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info",
@@ -101,7 +150,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,6 +176,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::DisplayApplianceAccount> {
+        Ok(self
+            .envelope_get_account_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns envelope account information for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/account_info` endpoint.
+     *
+     * As opposed to `envelope_get_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_account_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DisplayApplianceAccount>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/account_info",
@@ -136,7 +202,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -162,6 +228,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_custom_field_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Return custom fields information for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields` endpoint.
+     *
+     * As opposed to `envelope_get_custom_field`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_custom_field_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields",
@@ -171,7 +254,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -197,6 +280,22 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_delete_custom_fields_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes custom fields information for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields/delete` endpoint.
+     *
+     * As opposed to `envelope_delete_custom_fields`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_delete_custom_fields_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields/delete",
@@ -206,7 +305,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -232,6 +331,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_date_signed_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets date signed information for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/date_signed` endpoint.
+     *
+     * As opposed to `envelope_get_date_signed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_date_signed_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/date_signed",
@@ -241,7 +357,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -269,6 +385,23 @@ impl ApplianceInfo {
         document_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_put_document_with_response(account_id, document_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Updates document information for Display Applianc.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}` endpoint.
+     *
+     * As opposed to `envelope_put_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_put_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
@@ -279,7 +412,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -307,6 +440,23 @@ impl ApplianceInfo {
         document_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_delete_document_with_response(account_id, document_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes document information for Display Appliance.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}` endpoint.
+     *
+     * As opposed to `envelope_delete_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_delete_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
@@ -317,7 +467,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -343,6 +493,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_document_page_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Return document pages for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document_page_list` endpoint.
+     *
+     * As opposed to `envelope_get_document_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_document_page_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document_page_list",
@@ -352,7 +519,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -378,6 +545,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_image_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns images for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/image` endpoint.
+     *
+     * As opposed to `envelope_get_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_image_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/image",
@@ -387,7 +571,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -416,6 +600,24 @@ impl ApplianceInfo {
         envelope_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_locale_policy_with_response(account_id, envelope_id, user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns locale policy information for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/localepolicy/{userId}` endpoint.
+     *
+     * As opposed to `envelope_get_locale_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_locale_policy_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/localepolicy/{}",
@@ -426,7 +628,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -448,6 +650,22 @@ impl ApplianceInfo {
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> ClientResult<()> {
+        self.envelope_put_page_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Updates page information for Display Appliance.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info` endpoint.
+     *
+     * As opposed to `envelope_put_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_put_page_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
@@ -457,7 +675,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -483,6 +701,22 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_post_page_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Creates page information for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info` endpoint.
+     *
+     * As opposed to `envelope_post_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_post_page_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
@@ -492,7 +726,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -518,6 +752,22 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_delete_page_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes page information for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info/delete` endpoint.
+     *
+     * As opposed to `envelope_delete_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_delete_page_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info/delete",
@@ -527,7 +777,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -553,6 +803,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_put_pdf_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Sets latest PDF for Display Appliance.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf` endpoint.
+     *
+     * As opposed to `envelope_put_pdf`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_put_pdf_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf",
@@ -562,7 +829,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -592,6 +859,24 @@ impl ApplianceInfo {
         envelope_id: &str,
         pdf_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_pdf_with_response(account_id, envelope_id, pdf_id)
+            .await?
+            .body)
+    }
+    /**
+     * Return PDF for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf/{pdfId}` endpoint.
+     *
+     * As opposed to `envelope_get_pdf`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_pdf_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf/{}",
@@ -602,7 +887,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -628,6 +913,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_pdf_blob_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns PDF blobs for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs` endpoint.
+     *
+     * As opposed to `envelope_get_pdf_blob`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_pdf_blob_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
@@ -637,7 +939,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -663,6 +965,22 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_put_pdf_blob_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Updates PDF blobs for Display Appliance.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs` endpoint.
+     *
+     * As opposed to `envelope_put_pdf_blob`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_put_pdf_blob_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
@@ -672,7 +990,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -698,6 +1016,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_post_pdf_blob_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Adds PDF blobs for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs` endpoint.
+     *
+     * As opposed to `envelope_post_pdf_blob`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_post_pdf_blob_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
@@ -707,7 +1042,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -733,6 +1068,22 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_put_recipient_denied_document_copy_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Updates RecipientDeniedDocumentCopy for Display Appliance.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy` endpoint.
+     *
+     * As opposed to `envelope_put_recipient_denied_document_copy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_put_recipient_denied_document_copy_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
@@ -742,7 +1093,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -768,6 +1119,22 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_delete_recipient_denied_document_copy_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes RecipientDeniedDocumentCopy for Display Appliance.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy` endpoint.
+     *
+     * As opposed to `envelope_delete_recipient_denied_document_copy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_delete_recipient_denied_document_copy_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
@@ -777,7 +1144,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -803,6 +1170,23 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self
+            .envelope_get_signer_attachment_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns signer attachment information for Display Appliance.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info` endpoint.
+     *
+     * As opposed to `envelope_get_signer_attachment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_get_signer_attachment_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
@@ -812,7 +1196,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -838,6 +1222,22 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.envelope_delete_signer_attachment_with_response(account_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes signer attachment information for Display Appliance.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info` endpoint.
+     *
+     * As opposed to `envelope_delete_signer_attachment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_delete_signer_attachment_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
@@ -847,7 +1247,7 @@ impl ApplianceInfo {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -864,9 +1264,20 @@ impl ApplianceInfo {
      *
      */
     pub async fn envelope_post_error(&self) -> ClientResult<()> {
+        self.envelope_post_error_with_response().await?;
+        Ok(())
+    }
+    /**
+     * Uploads Kazmon error for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/display_appliance_info/error` endpoint.
+     *
+     * As opposed to `envelope_post_error`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_post_error_with_response(&self) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/v2.1/display_appliance_info/error", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -883,9 +1294,21 @@ impl ApplianceInfo {
      *
      */
     pub async fn envelope_post_redeem(&self) -> ClientResult<crate::types::ApplianceInfo> {
+        Ok(self.envelope_post_redeem_with_response().await?.body)
+    }
+    /**
+     * Returns signing URL for Display Appliance.
+     *
+     * This function performs a `POST` to the `/v2.1/display_appliance_info/redeem` endpoint.
+     *
+     * As opposed to `envelope_post_redeem`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn envelope_post_redeem_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ApplianceInfo>> {
         let url = self.client.url("/v2.1/display_appliance_info/redeem", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -30,6 +30,24 @@ impl BccEmailArchive {
         count: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::BccEmailArchiveList> {
+        Ok(self
+            .get_list_with_response(account_id, count, start_position)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the BCC email archive configurations for an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/bcc_email_archives` endpoint.
+     *
+     * As opposed to `get_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_list_with_response(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::BccEmailArchiveList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -47,7 +65,7 @@ impl BccEmailArchive {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -77,6 +95,20 @@ impl BccEmailArchive {
         account_id: &str,
         body: &crate::types::BccEmailArchiveData,
     ) -> ClientResult<crate::types::BccEmailArchiveData> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Creates a BCC email archive configuration.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/settings/bcc_email_archives` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::BccEmailArchiveData,
+    ) -> ClientResult<crate::Response<crate::types::BccEmailArchiveData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/bcc_email_archives",
@@ -85,7 +117,7 @@ impl BccEmailArchive {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -115,6 +147,25 @@ impl BccEmailArchive {
         count: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::BccEmailArchiveHistoryList> {
+        Ok(self
+            .get_history_list_with_response(account_id, bcc_email_archive_id, count, start_position)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a BCC email archive configuration and its history.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}` endpoint.
+     *
+     * As opposed to `get_history_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_history_list_with_response(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::BccEmailArchiveHistoryList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -133,7 +184,7 @@ impl BccEmailArchive {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -158,6 +209,22 @@ impl BccEmailArchive {
      * * `bcc_email_archive_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> ClientResult<()> {
+        self.delete_with_response(account_id, bcc_email_archive_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a BCC email archive configuration.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
@@ -167,7 +234,7 @@ impl BccEmailArchive {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
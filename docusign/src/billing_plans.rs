@@ -41,6 +41,30 @@ impl BillingPlans {
         include_metadata: &str,
         include_successor_plans: &str,
     ) -> ClientResult<crate::types::AccountBillingPlanResponse> {
+        Ok(self
+            .get_with_response(
+                account_id,
+                include_credit_card_information,
+                include_metadata,
+                include_successor_plans,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get Account Billing Plan.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_plan` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        include_credit_card_information: &str,
+        include_metadata: &str,
+        include_successor_plans: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountBillingPlanResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_credit_card_information.is_empty() {
             query_args.push((
@@ -67,7 +91,7 @@ impl BillingPlans {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -94,6 +118,24 @@ impl BillingPlans {
         preview_billing_plan: &str,
         body: &crate::types::BillingPlanInformation,
     ) -> ClientResult<crate::types::BillingPlanUpdateResponse> {
+        Ok(self
+            .put_with_response(account_id, preview_billing_plan, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an account billing plan.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/billing_plan` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        preview_billing_plan: &str,
+        body: &crate::types::BillingPlanInformation,
+    ) -> ClientResult<crate::Response<crate::types::BillingPlanUpdateResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !preview_billing_plan.is_empty() {
             query_args.push((
@@ -111,7 +153,7 @@ impl BillingPlans {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -135,6 +177,22 @@ impl BillingPlans {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::CreditCardInformation> {
+        Ok(self
+            .get_credit_card_info_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get credit card information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_plan/credit_card` endpoint.
+     *
+     * As opposed to `get_credit_card_info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_credit_card_info_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CreditCardInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_plan/credit_card",
@@ -143,7 +201,7 @@ impl BillingPlans {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -167,6 +225,22 @@ impl BillingPlans {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::DowngradRequestBillingInfoResponse> {
+        Ok(self
+            .get_downgrade_request_info_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns downgrade plan information for the specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_plan/downgrade` endpoint.
+     *
+     * As opposed to `get_downgrade_request_info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_downgrade_request_info_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DowngradRequestBillingInfoResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_plan/downgrade",
@@ -175,7 +249,7 @@ impl BillingPlans {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -200,6 +274,23 @@ impl BillingPlans {
         account_id: &str,
         body: &crate::types::DowngradeBillingPlanInformation,
     ) -> ClientResult<crate::types::DowngradePlanUpdateResponse> {
+        Ok(self
+            .put_downgrade_account_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Queues downgrade billing plan request for an account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/billing_plan/downgrade` endpoint.
+     *
+     * As opposed to `put_downgrade_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_downgrade_account_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::DowngradeBillingPlanInformation,
+    ) -> ClientResult<crate::Response<crate::types::DowngradePlanUpdateResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_plan/downgrade",
@@ -208,7 +299,7 @@ impl BillingPlans {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -233,6 +324,22 @@ impl BillingPlans {
         account_id: &str,
         body: &crate::types::PurchasedEnvelopesInformation,
     ) -> ClientResult<()> {
+        self.purchased_envelopes_put_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Reserverd: Purchase additional envelopes.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/billing_plan/purchased_envelopes` endpoint.
+     *
+     * As opposed to `purchased_envelopes_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn purchased_envelopes_put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::PurchasedEnvelopesInformation,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_plan/purchased_envelopes",
@@ -241,7 +348,7 @@ impl BillingPlans {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -258,9 +365,21 @@ impl BillingPlans {
      * Retrieves a list of the billing plans associated with a distributor.
      */
     pub async fn get_billing_plans(&self) -> ClientResult<crate::types::BillingPlansResponse> {
+        Ok(self.get_billing_plans_with_response().await?.body)
+    }
+    /**
+     * Gets a list of available billing plans.
+     *
+     * This function performs a `GET` to the `/v2.1/billing_plans` endpoint.
+     *
+     * As opposed to `get_billing_plans`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_billing_plans_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::BillingPlansResponse>> {
         let url = self.client.url("/v2.1/billing_plans", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -284,6 +403,19 @@ impl BillingPlans {
         &self,
         billing_plan_id: &str,
     ) -> ClientResult<crate::types::BillingPlanResponse> {
+        Ok(self.get_plan_with_response(billing_plan_id).await?.body)
+    }
+    /**
+     * Gets billing plan details.
+     *
+     * This function performs a `GET` to the `/v2.1/billing_plans/{billingPlanId}` endpoint.
+     *
+     * As opposed to `get_plan`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_plan_with_response(
+        &self,
+        billing_plan_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingPlanResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/billing_plans/{}",
@@ -292,7 +424,7 @@ impl BillingPlans {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -43,6 +43,26 @@ impl BulkSend {
         start_position: &str,
         status: &str,
     ) -> ClientResult<crate::types::BulkSendBatchSummaries> {
+        Ok(self
+            .batch_get_batche_with_response(account_id, batch_ids, count, start_position, status)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a list of bulk send batch summaries. .
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/bulk_send_batch` endpoint.
+     *
+     * As opposed to `batch_get_batche`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn batch_get_batche_with_response(
+        &self,
+        account_id: &str,
+        batch_ids: &str,
+        count: &str,
+        start_position: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendBatchSummaries>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !batch_ids.is_empty() {
             query_args.push(("batch_ids".to_string(), batch_ids.to_string()));
@@ -66,7 +86,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -98,6 +118,23 @@ impl BulkSend {
         account_id: &str,
         bulk_send_batch_id: &str,
     ) -> ClientResult<crate::types::BulkSendBatchStatus> {
+        Ok(self
+            .batch_get_statu_with_response(account_id, bulk_send_batch_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the status of a specific bulk send batch.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}` endpoint.
+     *
+     * As opposed to `batch_get_statu`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn batch_get_statu_with_response(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendBatchStatus>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_batch/{}",
@@ -107,7 +144,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -134,6 +171,24 @@ impl BulkSend {
         bulk_send_batch_id: &str,
         body: &crate::types::BulkSendBatchRequest,
     ) -> ClientResult<crate::types::BulkSendBatchStatus> {
+        Ok(self
+            .batch_put_status_with_response(account_id, bulk_send_batch_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a specific bulk send batch status.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}` endpoint.
+     *
+     * As opposed to `batch_put_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn batch_put_status_with_response(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+        body: &crate::types::BulkSendBatchRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendBatchStatus>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_batch/{}",
@@ -143,7 +198,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -167,6 +222,19 @@ impl BulkSend {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::BulkSendingListSummaries> {
+        Ok(self.v_2crud_get_list_with_response(account_id).await?.body)
+    }
+    /**
+     * Gets bulk send lists.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/bulk_send_lists` endpoint.
+     *
+     * As opposed to `v_2crud_get_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn v_2crud_get_list_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingListSummaries>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_lists",
@@ -175,7 +243,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -226,6 +294,23 @@ impl BulkSend {
         account_id: &str,
         body: &crate::types::BulkSendingList,
     ) -> ClientResult<crate::types::BulkSendingList> {
+        Ok(self
+            .v_2crud_post_list_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a bulk send list.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/bulk_send_lists` endpoint.
+     *
+     * As opposed to `v_2crud_post_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn v_2crud_post_list_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_lists",
@@ -234,7 +319,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -260,6 +345,23 @@ impl BulkSend {
         account_id: &str,
         bulk_send_list_id: &str,
     ) -> ClientResult<crate::types::BulkSendingList> {
+        Ok(self
+            .v_2crud_get_list_bulk_send_with_response(account_id, bulk_send_list_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a specific bulk send list.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}` endpoint.
+     *
+     * As opposed to `v_2crud_get_list_bulk_send`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn v_2crud_get_list_bulk_send_with_response(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_lists/{}",
@@ -269,7 +371,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -296,6 +398,24 @@ impl BulkSend {
         bulk_send_list_id: &str,
         body: &crate::types::BulkSendingList,
     ) -> ClientResult<crate::types::BulkSendingList> {
+        Ok(self
+            .v_2crud_put_list_with_response(account_id, bulk_send_list_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a bulk send list.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}` endpoint.
+     *
+     * As opposed to `v_2crud_put_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn v_2crud_put_list_with_response(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_lists/{}",
@@ -305,7 +425,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -331,6 +451,23 @@ impl BulkSend {
         account_id: &str,
         bulk_send_list_id: &str,
     ) -> ClientResult<crate::types::BulkSendingListSummaries> {
+        Ok(self
+            .v_2crud_delete_list_with_response(account_id, bulk_send_list_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes a bulk send list.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}` endpoint.
+     *
+     * As opposed to `v_2crud_delete_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn v_2crud_delete_list_with_response(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendingListSummaries>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_lists/{}",
@@ -340,7 +477,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -404,6 +541,24 @@ impl BulkSend {
         bulk_send_list_id: &str,
         body: &crate::types::BulkSendRequest,
     ) -> ClientResult<crate::types::BulkSendResponse> {
+        Ok(self
+            .post_request_with_response(account_id, bulk_send_list_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a bulk send request.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/send` endpoint.
+     *
+     * As opposed to `post_request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_request_with_response(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_lists/{}/send",
@@ -413,7 +568,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -477,6 +632,24 @@ impl BulkSend {
         bulk_send_list_id: &str,
         body: &crate::types::BulkSendRequest,
     ) -> ClientResult<crate::types::BulkSendTestResponse> {
+        Ok(self
+            .test_post_request_with_response(account_id, bulk_send_list_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a bulk send test.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/test` endpoint.
+     *
+     * As opposed to `test_post_request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn test_post_request_with_response(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkSendTestResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/bulk_send_lists/{}/test",
@@ -486,7 +659,7 @@ impl BulkSend {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
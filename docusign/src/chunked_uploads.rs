@@ -27,6 +27,20 @@ impl ChunkedUploads {
         account_id: &str,
         body: &crate::types::ChunkedUploadRequest,
     ) -> ClientResult<crate::types::ChunkedUploadResponse> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Initiate a new chunked upload.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/chunked_uploads` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/chunked_uploads",
@@ -35,7 +49,7 @@ impl ChunkedUploads {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -65,6 +79,24 @@ impl ChunkedUploads {
         chunked_upload_id: &str,
         include: &str,
     ) -> ClientResult<crate::types::ChunkedUploadResponse> {
+        Ok(self
+            .get_upload_with_response(account_id, chunked_upload_id, include)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves metadata about a chunked upload.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}` endpoint.
+     *
+     * As opposed to `get_upload`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_upload_with_response(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -80,7 +112,7 @@ impl ChunkedUploads {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -112,6 +144,24 @@ impl ChunkedUploads {
         chunked_upload_id: &str,
         action: &str,
     ) -> ClientResult<crate::types::ChunkedUploadResponse> {
+        Ok(self
+            .put_with_response(account_id, chunked_upload_id, action)
+            .await?
+            .body)
+    }
+    /**
+     * Commit a chunked upload.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        action: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !action.is_empty() {
             query_args.push(("action".to_string(), action.to_string()));
@@ -127,7 +177,7 @@ impl ChunkedUploads {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -161,6 +211,23 @@ impl ChunkedUploads {
         account_id: &str,
         chunked_upload_id: &str,
     ) -> ClientResult<crate::types::ChunkedUploadResponse> {
+        Ok(self
+            .delete_upload_with_response(account_id, chunked_upload_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes a chunked upload.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}` endpoint.
+     *
+     * As opposed to `delete_upload`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_upload_with_response(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/chunked_uploads/{}",
@@ -170,7 +237,7 @@ impl ChunkedUploads {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +282,30 @@ impl ChunkedUploads {
         chunked_upload_part_seq: &str,
         body: &crate::types::ChunkedUploadRequest,
     ) -> ClientResult<crate::types::ChunkedUploadResponse> {
+        Ok(self
+            .put_upload_part_with_response(
+                account_id,
+                chunked_upload_id,
+                chunked_upload_part_seq,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Add a chunk to an existing chunked upload.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}/{chunkedUploadPartSeq}` endpoint.
+     *
+     * As opposed to `put_upload_part`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_upload_part_with_response(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChunkedUploadResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/chunked_uploads/{}/{}",
@@ -225,7 +316,7 @@ impl ChunkedUploads {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -58,6 +58,40 @@ impl CloudStorage {
         search_text: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::ExternalFolder> {
+        Ok(self
+            .folder_get_all_with_response(
+                account_id,
+                service_id,
+                user_id,
+                cloud_storage_folder_path,
+                count,
+                order,
+                order_by,
+                search_text,
+                start_position,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of all the items in a specified folder from the specified cloud storage provider.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders` endpoint.
+     *
+     * As opposed to `folder_get_all`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn folder_get_all_with_response(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cloud_storage_folder_path.is_empty() {
             query_args.push((
@@ -92,7 +126,7 @@ impl CloudStorage {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -154,6 +188,44 @@ impl CloudStorage {
         search_text: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::ExternalFolder> {
+        Ok(self
+            .folder_get_with_response(
+                account_id,
+                folder_id,
+                service_id,
+                user_id,
+                cloud_storage_folder_path,
+                cloud_storage_folderid_plain,
+                count,
+                order,
+                order_by,
+                search_text,
+                start_position,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of items from a cloud storage provider.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `folder_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn folder_get_with_response(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        cloud_storage_folderid_plain: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::ExternalFolder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cloud_storage_folder_path.is_empty() {
             query_args.push((
@@ -195,7 +267,7 @@ impl CloudStorage {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -35,6 +35,24 @@ impl CloudStorageProviders {
         user_id: &str,
         redirect_url: &str,
     ) -> ClientResult<crate::types::CloudStorageProvidersData> {
+        Ok(self
+            .cloud_storage_get_provider_with_response(account_id, user_id, redirect_url)
+            .await?
+            .body)
+    }
+    /**
+     * Get the Cloud Storage Provider configuration for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage` endpoint.
+     *
+     * As opposed to `cloud_storage_get_provider`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn cloud_storage_get_provider_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !redirect_url.is_empty() {
             query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
@@ -50,7 +68,7 @@ impl CloudStorageProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -78,6 +96,24 @@ impl CloudStorageProviders {
         user_id: &str,
         body: &crate::types::CloudStorageProvidersData,
     ) -> ClientResult<crate::types::CloudStorageProvidersData> {
+        Ok(self
+            .cloud_storage_post_with_response(account_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Configures the redirect URL information  for one or more cloud storage providers for the specified user.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage` endpoint.
+     *
+     * As opposed to `cloud_storage_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn cloud_storage_post_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/cloud_storage",
@@ -87,7 +123,7 @@ impl CloudStorageProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -115,6 +151,24 @@ impl CloudStorageProviders {
         user_id: &str,
         body: &crate::types::CloudStorageProvidersData,
     ) -> ClientResult<crate::types::CloudStorageProvidersData> {
+        Ok(self
+            .cloud_storage_delete_providers_with_response(account_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the user authentication information for one or more cloud storage providers.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage` endpoint.
+     *
+     * As opposed to `cloud_storage_delete_providers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn cloud_storage_delete_providers_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/cloud_storage",
@@ -124,7 +178,7 @@ impl CloudStorageProviders {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -160,6 +214,25 @@ impl CloudStorageProviders {
         user_id: &str,
         redirect_url: &str,
     ) -> ClientResult<crate::types::CloudStorageProvidersData> {
+        Ok(self
+            .cloud_storage_get_with_response(account_id, service_id, user_id, redirect_url)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the specified Cloud Storage Provider configuration for the User.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}` endpoint.
+     *
+     * As opposed to `cloud_storage_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn cloud_storage_get_with_response(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !redirect_url.is_empty() {
             query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
@@ -176,7 +249,7 @@ impl CloudStorageProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -207,6 +280,24 @@ impl CloudStorageProviders {
         service_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::CloudStorageProvidersData> {
+        Ok(self
+            .cloud_storage_delete_with_response(account_id, service_id, user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the user authentication information for the specified cloud storage provider.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}` endpoint.
+     *
+     * As opposed to `cloud_storage_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn cloud_storage_delete_with_response(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudStorageProvidersData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
@@ -217,7 +308,7 @@ impl CloudStorageProviders {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -32,6 +32,23 @@ impl Comments {
         envelope_id: &str,
         encoding: &str,
     ) -> ClientResult<()> {
+        self.get_transcript_with_response(account_id, envelope_id, encoding)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Gets a PDF transcript of all of the comments in an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript` endpoint.
+     *
+     * As opposed to `get_transcript`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_transcript_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encoding.is_empty() {
             query_args.push(("encoding".to_string(), encoding.to_string()));
@@ -47,7 +64,7 @@ impl Comments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
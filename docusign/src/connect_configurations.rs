@@ -28,6 +28,22 @@ impl ConnectConfigurations {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::ConnectConfigResults> {
+        Ok(self
+            .connect_get_config_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get Connect Configuration Information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect` endpoint.
+     *
+     * As opposed to `connect_get_config`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_get_config_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectConfigResults>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect",
@@ -36,7 +52,7 @@ impl ConnectConfigurations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -63,6 +79,23 @@ impl ConnectConfigurations {
         account_id: &str,
         body: &crate::types::ConnectCustomConfiguration,
     ) -> ClientResult<crate::types::ConnectCustomConfiguration> {
+        Ok(self
+            .connect_put_configuration_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a specified Connect configuration.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/connect` endpoint.
+     *
+     * As opposed to `connect_put_configuration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_put_configuration_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ConnectCustomConfiguration>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect",
@@ -71,7 +104,7 @@ impl ConnectConfigurations {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -99,6 +132,23 @@ impl ConnectConfigurations {
         account_id: &str,
         body: &crate::types::ConnectCustomConfiguration,
     ) -> ClientResult<crate::types::ConnectCustomConfiguration> {
+        Ok(self
+            .connect_post_configuration_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a connect configuration for the specified account.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/connect` endpoint.
+     *
+     * As opposed to `connect_post_configuration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_post_configuration_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ConnectCustomConfiguration>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect",
@@ -107,7 +157,7 @@ impl ConnectConfigurations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -136,6 +186,23 @@ impl ConnectConfigurations {
         account_id: &str,
         connect_id: &str,
     ) -> ClientResult<crate::types::ConnectConfigResults> {
+        Ok(self
+            .connect_get_config_connect_configurations_with_response(account_id, connect_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the details about a Connect configuration.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/{connectId}` endpoint.
+     *
+     * As opposed to `connect_get_config_connect_configurations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_get_config_connect_configurations_with_response(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectConfigResults>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/{}",
@@ -145,7 +212,7 @@ impl ConnectConfigurations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -176,6 +243,22 @@ impl ConnectConfigurations {
         account_id: &str,
         connect_id: &str,
     ) -> ClientResult<()> {
+        self.connect_delete_config_with_response(account_id, connect_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes the specified connect configuration.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/{connectId}` endpoint.
+     *
+     * As opposed to `connect_delete_config`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_delete_config_with_response(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/{}",
@@ -185,7 +268,7 @@ impl ConnectConfigurations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -234,6 +317,38 @@ impl ConnectConfigurations {
         status: &str,
         user_name_substring: &str,
     ) -> ClientResult<crate::types::IntegratedUserInfoList> {
+        Ok(self
+            .connect_get_user_with_response(
+                account_id,
+                connect_id,
+                count,
+                email_substring,
+                list_included_users,
+                start_position,
+                status,
+                user_name_substring,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns users from the configured Connect service.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/{connectId}/users` endpoint.
+     *
+     * As opposed to `connect_get_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_get_user_with_response(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        count: &str,
+        email_substring: &str,
+        list_included_users: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> ClientResult<crate::Response<crate::types::IntegratedUserInfoList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -270,7 +385,7 @@ impl ConnectConfigurations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,23 @@ impl ConnectEvents {
         account_id: &str,
         body: &crate::types::ConnectFailureFilter,
     ) -> ClientResult<crate::types::ConnectFailureResults> {
+        Ok(self
+            .connect_publish_put_retry_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Republishes Connect information for multiple envelopes.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/connect/envelopes/retry_queue` endpoint.
+     *
+     * As opposed to `connect_publish_put_retry`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_publish_put_retry_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectFailureFilter,
+    ) -> ClientResult<crate::Response<crate::types::ConnectFailureResults>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/envelopes/retry_queue",
@@ -36,7 +53,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -62,6 +79,23 @@ impl ConnectEvents {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::ConnectFailureResults> {
+        Ok(self
+            .connect_publish_put_retry_envelope_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Republishes Connect information for the specified envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/connect/envelopes/{envelopeId}/retry_queue` endpoint.
+     *
+     * As opposed to `connect_publish_put_retry_envelope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_publish_put_retry_envelope_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectFailureResults>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/envelopes/{}/retry_queue",
@@ -71,7 +105,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -103,6 +137,24 @@ impl ConnectEvents {
         from_date: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::ConnectLogs> {
+        Ok(self
+            .connect_failures_get_log_with_response(account_id, from_date, to_date)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the Connect failure log information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/failures` endpoint.
+     *
+     * As opposed to `connect_failures_get_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_failures_get_log_with_response(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from_date.is_empty() {
             query_args.push(("from_date".to_string(), from_date.to_string()));
@@ -120,7 +172,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -146,6 +198,22 @@ impl ConnectEvents {
         account_id: &str,
         failure_id: &str,
     ) -> ClientResult<()> {
+        self.connect_failures_delete_failure_log_with_response(account_id, failure_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a Connect failure log entry.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/failures/{failureId}` endpoint.
+     *
+     * As opposed to `connect_failures_delete_failure_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_failures_delete_failure_log_with_response(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/failures/{}",
@@ -155,7 +223,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -189,6 +257,24 @@ impl ConnectEvents {
         from_date: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::ConnectLogs> {
+        Ok(self
+            .connect_log_get_log_with_response(account_id, from_date, to_date)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the Connect log.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/logs` endpoint.
+     *
+     * As opposed to `connect_log_get_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_log_get_log_with_response(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectLogs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from_date.is_empty() {
             query_args.push(("from_date".to_string(), from_date.to_string()));
@@ -206,7 +292,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -227,6 +313,21 @@ impl ConnectEvents {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn connect_log_delete_logs(&self, account_id: &str) -> ClientResult<()> {
+        self.connect_log_delete_logs_with_response(account_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a list of Connect log entries.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/logs` endpoint.
+     *
+     * As opposed to `connect_log_delete_logs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_log_delete_logs_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/logs",
@@ -235,7 +336,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -265,6 +366,24 @@ impl ConnectEvents {
         log_id: &str,
         additional_info: &str,
     ) -> ClientResult<crate::types::ConnectLog> {
+        Ok(self
+            .connect_log_get_with_response(account_id, log_id, additional_info)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a Connect log entry.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/connect/logs/{logId}` endpoint.
+     *
+     * As opposed to `connect_log_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_log_get_with_response(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        additional_info: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConnectLog>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !additional_info.is_empty() {
             query_args.push(("additional_info".to_string(), additional_info.to_string()));
@@ -280,7 +399,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -303,6 +422,22 @@ impl ConnectEvents {
      * * `log_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn connect_log_delete(&self, account_id: &str, log_id: &str) -> ClientResult<()> {
+        self.connect_log_delete_with_response(account_id, log_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a specified Connect log entry.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/logs/{logId}` endpoint.
+     *
+     * As opposed to `connect_log_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_log_delete_with_response(
+        &self,
+        account_id: &str,
+        log_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/logs/{}",
@@ -312,7 +447,7 @@ impl ConnectEvents {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,22 @@ impl ConnectSecret {
         account_id: &str,
         key_id: &str,
     ) -> ClientResult<()> {
+        self.connect_hmac_delete_secret_with_response(account_id, key_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes the connect HMAC Secret for specified account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/secret/{keyId}` endpoint.
+     *
+     * As opposed to `connect_hmac_delete_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn connect_hmac_delete_secret_with_response(
+        &self,
+        account_id: &str,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/connect/secret/{}",
@@ -37,7 +53,7 @@ impl ConnectSecret {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
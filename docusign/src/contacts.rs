@@ -27,6 +27,20 @@ impl Contacts {
         account_id: &str,
         body: &crate::types::ContactModRequest,
     ) -> ClientResult<crate::types::ContactUpdateResponse> {
+        Ok(self.put_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Updates one or more contacts.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/contacts` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/contacts",
@@ -35,7 +49,7 @@ impl Contacts {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -62,6 +76,20 @@ impl Contacts {
         account_id: &str,
         body: &crate::types::ContactModRequest,
     ) -> ClientResult<crate::types::ContactUpdateResponse> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Imports new contacts into a contacts list.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/contacts` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/contacts",
@@ -70,7 +98,7 @@ impl Contacts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -95,6 +123,20 @@ impl Contacts {
         account_id: &str,
         body: &crate::types::ContactModRequest,
     ) -> ClientResult<crate::types::ContactUpdateResponse> {
+        Ok(self.delete_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Deletes multiple contacts from an account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/contacts` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/contacts",
@@ -103,7 +145,7 @@ impl Contacts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -145,6 +187,24 @@ impl Contacts {
         contact_id: &str,
         cloud_provider: &str,
     ) -> ClientResult<crate::types::ContactGetResponse> {
+        Ok(self
+            .get_with_response(account_id, contact_id, cloud_provider)
+            .await?
+            .body)
+    }
+    /**
+     * Gets one or more contacts.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/contacts/{contactId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        cloud_provider: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactGetResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cloud_provider.is_empty() {
             query_args.push(("cloud_provider".to_string(), cloud_provider.to_string()));
@@ -160,7 +220,7 @@ impl Contacts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -186,6 +246,23 @@ impl Contacts {
         account_id: &str,
         contact_id: &str,
     ) -> ClientResult<crate::types::ContactUpdateResponse> {
+        Ok(self
+            .delete_contacts_with_response(account_id, contact_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes a contact.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/contacts/{contactId}` endpoint.
+     *
+     * As opposed to `delete_contacts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contacts_with_response(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactUpdateResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/contacts/{}",
@@ -195,7 +272,7 @@ impl Contacts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
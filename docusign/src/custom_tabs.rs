@@ -28,6 +28,23 @@ impl CustomTabs {
         account_id: &str,
         custom_tab_only: &str,
     ) -> ClientResult<crate::types::TabMetadataList> {
+        Ok(self
+            .tabs_get_tab_definition_with_response(account_id, custom_tab_only)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of all account tabs.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/tab_definitions` endpoint.
+     *
+     * As opposed to `tabs_get_tab_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_get_tab_definition_with_response(
+        &self,
+        account_id: &str,
+        custom_tab_only: &str,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadataList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_tab_only.is_empty() {
             query_args.push(("custom_tab_only".to_string(), custom_tab_only.to_string()));
@@ -42,7 +59,7 @@ impl CustomTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +86,23 @@ impl CustomTabs {
         account_id: &str,
         body: &crate::types::TabMetadata,
     ) -> ClientResult<crate::types::TabMetadata> {
+        Ok(self
+            .tabs_post_tab_definitions_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a custom tab.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/tab_definitions` endpoint.
+     *
+     * As opposed to `tabs_post_tab_definitions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_post_tab_definitions_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadata>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/tab_definitions",
@@ -77,7 +111,7 @@ impl CustomTabs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -103,6 +137,23 @@ impl CustomTabs {
         account_id: &str,
         custom_tab_id: &str,
     ) -> ClientResult<crate::types::TabMetadata> {
+        Ok(self
+            .tab_get_custom_with_response(account_id, custom_tab_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets custom tab information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/tab_definitions/{customTabId}` endpoint.
+     *
+     * As opposed to `tab_get_custom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tab_get_custom_with_response(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadata>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/tab_definitions/{}",
@@ -112,7 +163,7 @@ impl CustomTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -141,6 +192,24 @@ impl CustomTabs {
         custom_tab_id: &str,
         body: &crate::types::TabMetadata,
     ) -> ClientResult<crate::types::TabMetadata> {
+        Ok(self
+            .tab_put_custom_with_response(account_id, custom_tab_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates custom tab information.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/tab_definitions/{customTabId}` endpoint.
+     *
+     * As opposed to `tab_put_custom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tab_put_custom_with_response(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> ClientResult<crate::Response<crate::types::TabMetadata>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/tab_definitions/{}",
@@ -150,7 +219,7 @@ impl CustomTabs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -176,6 +245,22 @@ impl CustomTabs {
         account_id: &str,
         custom_tab_id: &str,
     ) -> ClientResult<()> {
+        self.tab_delete_custom_with_response(account_id, custom_tab_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes custom tab information.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/tab_definitions/{customTabId}` endpoint.
+     *
+     * As opposed to `tab_delete_custom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tab_delete_custom_with_response(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/tab_definitions/{}",
@@ -185,7 +270,7 @@ impl CustomTabs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -37,6 +37,30 @@ impl DocumentResponsiveHtmlPreview {
         envelope_id: &str,
         body: &crate::types::DocumentHtmlDefinition,
     ) -> ClientResult<crate::types::DocumentHtmlDefinitions> {
+        Ok(self
+            .responsive_html_post_document_preview_with_response(
+                account_id,
+                document_id,
+                envelope_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Creates a preview of the responsive version of a document.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/responsive_html_preview` endpoint.
+     *
+     * As opposed to `responsive_html_post_document_preview`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn responsive_html_post_document_preview_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/responsive_html_preview",
@@ -47,7 +71,7 @@ impl DocumentResponsiveHtmlPreview {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
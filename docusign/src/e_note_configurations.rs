@@ -23,6 +23,19 @@ impl ENoteConfigurations {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn get(&self, account_id: &str) -> ClientResult<crate::types::ENoteConfiguration> {
+        Ok(self.get_with_response(account_id).await?.body)
+    }
+    /**
+     * Returns the configuration information for the eNote eOriginal integration.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/settings/enote_configuration` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ENoteConfiguration>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/enote_configuration",
@@ -31,7 +44,7 @@ impl ENoteConfigurations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,6 +69,20 @@ impl ENoteConfigurations {
         account_id: &str,
         body: &crate::types::ENoteConfiguration,
     ) -> ClientResult<crate::types::ENoteConfiguration> {
+        Ok(self.put_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Updates configuration information for the eNote eOriginal integration.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/settings/enote_configuration` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ENoteConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ENoteConfiguration>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/enote_configuration",
@@ -64,7 +91,7 @@ impl ENoteConfigurations {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -85,6 +112,20 @@ impl ENoteConfigurations {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete(&self, account_id: &str) -> ClientResult<()> {
+        self.delete_with_response(account_id).await?;
+        Ok(())
+    }
+    /**
+     * Deletes configuration information for the eNote eOriginal integration.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/settings/enote_configuration` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/settings/enote_configuration",
@@ -93,7 +134,7 @@ impl ENoteConfigurations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,23 @@ impl EnvelopeAttachments {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EnvelopeAttachmentsResult> {
+        Ok(self
+            .attachments_get_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a list of attachments associated with the specified envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments` endpoint.
+     *
+     * As opposed to `attachments_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn attachments_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/attachments",
@@ -37,7 +54,7 @@ impl EnvelopeAttachments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -64,6 +81,24 @@ impl EnvelopeAttachments {
         envelope_id: &str,
         body: &crate::types::EnvelopeAttachmentsRequest,
     ) -> ClientResult<crate::types::EnvelopeAttachmentsResult> {
+        Ok(self
+            .attachments_put_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add one or more attachments to a draft or in-process envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments` endpoint.
+     *
+     * As opposed to `attachments_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn attachments_put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/attachments",
@@ -73,7 +108,7 @@ impl EnvelopeAttachments {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -100,6 +135,24 @@ impl EnvelopeAttachments {
         envelope_id: &str,
         body: &crate::types::EnvelopeAttachmentsRequest,
     ) -> ClientResult<crate::types::EnvelopeAttachmentsResult> {
+        Ok(self
+            .attachments_delete_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete one or more attachments from a DRAFT envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments` endpoint.
+     *
+     * As opposed to `attachments_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn attachments_delete_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/attachments",
@@ -109,7 +162,7 @@ impl EnvelopeAttachments {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -137,6 +190,23 @@ impl EnvelopeAttachments {
         attachment_id: &str,
         envelope_id: &str,
     ) -> ClientResult<()> {
+        self.attachments_get_attachment_with_response(account_id, attachment_id, envelope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves an attachment from the envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}` endpoint.
+     *
+     * As opposed to `attachments_get_attachment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn attachments_get_attachment_with_response(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
@@ -147,7 +217,7 @@ impl EnvelopeAttachments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -176,6 +246,25 @@ impl EnvelopeAttachments {
         envelope_id: &str,
         body: &crate::types::Attachment,
     ) -> ClientResult<crate::types::EnvelopeAttachmentsResult> {
+        Ok(self
+            .attachments_put_attachment_with_response(account_id, attachment_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add an attachment to a DRAFT or IN-PROCESS envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}` endpoint.
+     *
+     * As opposed to `attachments_put_attachment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn attachments_put_attachment_with_response(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Attachment,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAttachmentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
@@ -186,7 +275,7 @@ impl EnvelopeAttachments {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
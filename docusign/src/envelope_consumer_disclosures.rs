@@ -82,6 +82,30 @@ impl EnvelopeConsumerDisclosures {
         recipient_id: &str,
         lang_code: &str,
     ) -> ClientResult<crate::types::ConsumerDisclosure> {
+        Ok(self
+            .consumer_disclosure_get_envelope_recipient_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+                lang_code,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the default Electronic Record and Signature Disclosure for an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure` endpoint.
+     *
+     * As opposed to `consumer_disclosure_get_envelope_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn consumer_disclosure_get_envelope_recipient_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        lang_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !lang_code.is_empty() {
             query_args.push(("langCode".to_string(), lang_code.to_string()));
@@ -98,7 +122,7 @@ impl EnvelopeConsumerDisclosures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -223,6 +247,30 @@ impl EnvelopeConsumerDisclosures {
         lang_code: &str,
         recipient_id: &str,
     ) -> ClientResult<crate::types::ConsumerDisclosure> {
+        Ok(self
+            .consumer_disclosure_get_envelope_recipient_lang_code_with_response(
+                account_id,
+                envelope_id,
+                lang_code,
+                recipient_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the Electronic Record and Signature Disclosure for a specific envelope recipient.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure/{langCode}` endpoint.
+     *
+     * As opposed to `consumer_disclosure_get_envelope_recipient_lang_code`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn consumer_disclosure_get_envelope_recipient_lang_code_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConsumerDisclosure>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure/{}",
@@ -234,7 +282,7 @@ impl EnvelopeConsumerDisclosures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -30,6 +30,23 @@ impl EnvelopeCustomFields {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::CustomFieldsEnvelope> {
+        Ok(self
+            .custom_fields_get_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the custom field information for the specified envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomFieldsEnvelope>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/custom_fields",
@@ -39,7 +56,7 @@ impl EnvelopeCustomFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +86,24 @@ impl EnvelopeCustomFields {
         envelope_id: &str,
         body: &crate::types::EnvelopeCustomFields,
     ) -> ClientResult<crate::types::EnvelopeCustomFields> {
+        Ok(self
+            .custom_fields_put_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates envelope custom fields in an envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeCustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/custom_fields",
@@ -78,7 +113,7 @@ impl EnvelopeCustomFields {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -108,6 +143,24 @@ impl EnvelopeCustomFields {
         envelope_id: &str,
         body: &crate::types::EnvelopeCustomFields,
     ) -> ClientResult<crate::types::EnvelopeCustomFields> {
+        Ok(self
+            .custom_fields_post_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates envelope custom fields for an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_post_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeCustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/custom_fields",
@@ -117,7 +170,7 @@ impl EnvelopeCustomFields {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -144,6 +197,24 @@ impl EnvelopeCustomFields {
         envelope_id: &str,
         body: &crate::types::EnvelopeCustomFields,
     ) -> ClientResult<crate::types::EnvelopeCustomFields> {
+        Ok(self
+            .custom_fields_delete_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes envelope custom fields for draft and in-process envelopes.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_delete_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeCustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/custom_fields",
@@ -153,7 +224,7 @@ impl EnvelopeCustomFields {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -30,6 +30,24 @@ impl EnvelopeDocumentFields {
         document_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EnvelopeDocumentFields> {
+        Ok(self
+            .document_fields_get_with_response(account_id, document_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the custom document fields from an  existing envelope document.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_get_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
@@ -40,7 +58,7 @@ impl EnvelopeDocumentFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +87,25 @@ impl EnvelopeDocumentFields {
         envelope_id: &str,
         body: &crate::types::EnvelopeDocumentFields,
     ) -> ClientResult<crate::types::EnvelopeDocumentFields> {
+        Ok(self
+            .document_fields_put_with_response(account_id, document_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates existing custom document fields in an existing envelope document.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_put_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
@@ -79,7 +116,7 @@ impl EnvelopeDocumentFields {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -108,6 +145,25 @@ impl EnvelopeDocumentFields {
         envelope_id: &str,
         body: &crate::types::EnvelopeDocumentFields,
     ) -> ClientResult<crate::types::EnvelopeDocumentFields> {
+        Ok(self
+            .document_fields_post_with_response(account_id, document_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates custom document fields in an existing envelope document.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_post_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
@@ -118,7 +174,7 @@ impl EnvelopeDocumentFields {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -147,6 +203,25 @@ impl EnvelopeDocumentFields {
         envelope_id: &str,
         body: &crate::types::EnvelopeDocumentFields,
     ) -> ClientResult<crate::types::EnvelopeDocumentFields> {
+        Ok(self
+            .document_fields_delete_with_response(account_id, document_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes custom document fields from an existing envelope document.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_delete_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
@@ -157,7 +232,7 @@ impl EnvelopeDocumentFields {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
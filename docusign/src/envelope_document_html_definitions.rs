@@ -31,6 +31,28 @@ impl EnvelopeDocumentHtmlDefinitions {
         document_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::DocumentHtmlDefinitionOriginals> {
+        Ok(self
+            .responsive_html_get_envelope_document_definition_with_response(
+                account_id,
+                document_id,
+                envelope_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the Original HTML Definition used to.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/html_definitions` endpoint.
+     *
+     * As opposed to `responsive_html_get_envelope_document_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn responsive_html_get_envelope_document_definition_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitionOriginals>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/html_definitions",
@@ -41,7 +63,7 @@ impl EnvelopeDocumentHtmlDefinitions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
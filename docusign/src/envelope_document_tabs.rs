@@ -32,6 +32,25 @@ impl EnvelopeDocumentTabs {
         envelope_id: &str,
         page_number: &str,
     ) -> ClientResult<crate::types::EnvelopeDocumentTabs> {
+        Ok(self
+            .tabs_get_page_with_response(account_id, document_id, envelope_id, page_number)
+            .await?
+            .body)
+    }
+    /**
+     * Returns tabs on the specified page.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/tabs` endpoint.
+     *
+     * As opposed to `tabs_get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_get_page_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentTabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/tabs",
@@ -43,7 +62,7 @@ impl EnvelopeDocumentTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,6 +98,32 @@ impl EnvelopeDocumentTabs {
         include_metadata: &str,
         page_numbers: &str,
     ) -> ClientResult<crate::types::EnvelopeDocumentTabs> {
+        Ok(self
+            .tabs_get_document_with_response(
+                account_id,
+                document_id,
+                envelope_id,
+                include_metadata,
+                page_numbers,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns the tabs on a document.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_get_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_get_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include_metadata: &str,
+        page_numbers: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentTabs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_metadata.is_empty() {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
@@ -98,7 +143,7 @@ impl EnvelopeDocumentTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,6 +172,25 @@ impl EnvelopeDocumentTabs {
         envelope_id: &str,
         body: &crate::types::Tabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .tabs_put_document_with_response(account_id, document_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates the tabs for an envelope document.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_put_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_put_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
@@ -137,7 +201,7 @@ impl EnvelopeDocumentTabs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -166,6 +230,25 @@ impl EnvelopeDocumentTabs {
         envelope_id: &str,
         body: &crate::types::Tabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .tabs_post_document_with_response(account_id, document_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds the tabs to an envelope document.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_post_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_post_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
@@ -176,7 +259,7 @@ impl EnvelopeDocumentTabs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -205,6 +288,25 @@ impl EnvelopeDocumentTabs {
         envelope_id: &str,
         body: &crate::types::Tabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .tabs_delete_document_with_response(account_id, document_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes tabs from an envelope document.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_delete_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_delete_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
@@ -215,7 +317,7 @@ impl EnvelopeDocumentTabs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
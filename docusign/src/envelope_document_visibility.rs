@@ -30,6 +30,28 @@ impl EnvelopeDocumentVisibility {
         envelope_id: &str,
         recipient_id: &str,
     ) -> ClientResult<crate::types::DocumentVisibilityList> {
+        Ok(self
+            .recipients_get_recipient_document_visibility_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns document visibility for a recipient.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_document_visibility`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_recipient_document_visibility_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
@@ -40,7 +62,7 @@ impl EnvelopeDocumentVisibility {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,6 +93,30 @@ impl EnvelopeDocumentVisibility {
         recipient_id: &str,
         body: &crate::types::DocumentVisibilityList,
     ) -> ClientResult<crate::types::DocumentVisibilityList> {
+        Ok(self
+            .recipients_put_recipient_document_visibility_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates document visibility for a recipient.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility` endpoint.
+     *
+     * As opposed to `recipients_put_recipient_document_visibility`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_recipient_document_visibility_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
@@ -81,7 +127,7 @@ impl EnvelopeDocumentVisibility {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
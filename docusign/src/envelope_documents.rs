@@ -40,6 +40,38 @@ impl EnvelopeDocuments {
         recipient_id: &str,
         shared_user_id: &str,
     ) -> ClientResult<crate::types::EnvelopeDocumentsResult> {
+        Ok(self
+            .documents_get_with_response(
+                account_id,
+                envelope_id,
+                documents_by_userid,
+                include_document_size,
+                include_metadata,
+                include_tabs,
+                recipient_id,
+                shared_user_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of envelope documents.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents` endpoint.
+     *
+     * As opposed to `documents_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        documents_by_userid: &str,
+        include_document_size: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !documents_by_userid.is_empty() {
             query_args.push((
@@ -76,7 +108,7 @@ impl EnvelopeDocuments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -112,6 +144,24 @@ impl EnvelopeDocuments {
         envelope_id: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> ClientResult<crate::types::EnvelopeDocumentsResult> {
+        Ok(self
+            .documents_put_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds one or more documents to an existing envelope document.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents` endpoint.
+     *
+     * As opposed to `documents_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents",
@@ -121,7 +171,7 @@ impl EnvelopeDocuments {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -171,6 +221,24 @@ impl EnvelopeDocuments {
         envelope_id: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> ClientResult<crate::types::EnvelopeDocumentsResult> {
+        Ok(self
+            .documents_delete_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes documents from a draft envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents` endpoint.
+     *
+     * As opposed to `documents_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_delete_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocumentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents",
@@ -180,7 +248,7 @@ impl EnvelopeDocuments {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -230,6 +298,45 @@ impl EnvelopeDocuments {
         show_changes: &str,
         watermark: &str,
     ) -> ClientResult<()> {
+        self.documents_get_document_with_response(
+            account_id,
+            document_id,
+            envelope_id,
+            certificate,
+            documents_by_userid,
+            encoding,
+            encrypt,
+            language,
+            recipient_id,
+            shared_user_id,
+            show_changes,
+            watermark,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Gets a document from an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_get_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !certificate.is_empty() {
             query_args.push(("certificate".to_string(), certificate.to_string()));
@@ -273,7 +380,7 @@ impl EnvelopeDocuments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -317,6 +424,24 @@ impl EnvelopeDocuments {
         document_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EnvelopeDocument> {
+        Ok(self
+            .documents_put_document_with_response(account_id, document_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Adds a document to an existing draft envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_put_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_put_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocument>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}",
@@ -327,7 +452,7 @@ impl EnvelopeDocuments {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,23 @@ impl EnvelopeEmailSettings {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EmailSettings> {
+        Ok(self
+            .email_settings_get_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the email setting overrides for an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings` endpoint.
+     *
+     * As opposed to `email_settings_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn email_settings_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/email_settings",
@@ -37,7 +54,7 @@ impl EnvelopeEmailSettings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,6 +83,24 @@ impl EnvelopeEmailSettings {
         envelope_id: &str,
         body: &crate::types::EmailSettings,
     ) -> ClientResult<crate::types::EmailSettings> {
+        Ok(self
+            .email_settings_put_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates the email setting overrides for an envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings` endpoint.
+     *
+     * As opposed to `email_settings_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn email_settings_put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/email_settings",
@@ -75,7 +110,7 @@ impl EnvelopeEmailSettings {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -104,6 +139,24 @@ impl EnvelopeEmailSettings {
         envelope_id: &str,
         body: &crate::types::EmailSettings,
     ) -> ClientResult<crate::types::EmailSettings> {
+        Ok(self
+            .email_settings_post_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds email setting overrides to an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings` endpoint.
+     *
+     * As opposed to `email_settings_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn email_settings_post_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/email_settings",
@@ -113,7 +166,7 @@ impl EnvelopeEmailSettings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -139,6 +192,23 @@ impl EnvelopeEmailSettings {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EmailSettings> {
+        Ok(self
+            .email_settings_delete_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the email setting overrides for an envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings` endpoint.
+     *
+     * As opposed to `email_settings_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn email_settings_delete_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailSettings>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/email_settings",
@@ -148,7 +218,7 @@ impl EnvelopeEmailSettings {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
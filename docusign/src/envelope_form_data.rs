@@ -30,6 +30,23 @@ impl EnvelopeFormData {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EnvelopeFormDataType> {
+        Ok(self
+            .form_data_get_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns envelope form data for an existing envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/form_data` endpoint.
+     *
+     * As opposed to `form_data_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn form_data_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeFormDataType>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/form_data",
@@ -39,7 +56,7 @@ impl EnvelopeFormData {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,23 @@ impl EnvelopeHtmlDefinitions {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::DocumentHtmlDefinitionOriginals> {
+        Ok(self
+            .responsive_html_get_envelope_definition_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the Original HTML Definition used to generate the Responsive HTML for the envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/html_definitions` endpoint.
+     *
+     * As opposed to `responsive_html_get_envelope_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn responsive_html_get_envelope_definition_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitionOriginals>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/html_definitions",
@@ -37,7 +54,7 @@ impl EnvelopeHtmlDefinitions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -40,6 +40,23 @@ impl EnvelopeLocks {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EnvelopeLocks> {
+        Ok(self
+            .lock_get_envelope_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets envelope lock information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock` endpoint.
+     *
+     * As opposed to `lock_get_envelope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_get_envelope_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/lock",
@@ -49,7 +66,7 @@ impl EnvelopeLocks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -98,6 +115,24 @@ impl EnvelopeLocks {
         envelope_id: &str,
         body: &crate::types::LockRequest,
     ) -> ClientResult<crate::types::EnvelopeLocks> {
+        Ok(self
+            .lock_put_envelope_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an envelope lock.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock` endpoint.
+     *
+     * As opposed to `lock_put_envelope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_put_envelope_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/lock",
@@ -107,7 +142,7 @@ impl EnvelopeLocks {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -190,6 +225,24 @@ impl EnvelopeLocks {
         envelope_id: &str,
         body: &crate::types::LockRequest,
     ) -> ClientResult<crate::types::EnvelopeLocks> {
+        Ok(self
+            .lock_post_envelope_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Locks an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock` endpoint.
+     *
+     * As opposed to `lock_post_envelope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_post_envelope_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/lock",
@@ -199,7 +252,7 @@ impl EnvelopeLocks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -241,6 +294,23 @@ impl EnvelopeLocks {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EnvelopeLocks> {
+        Ok(self
+            .lock_delete_envelope_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes an envelope lock.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock` endpoint.
+     *
+     * As opposed to `lock_delete_envelope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_delete_envelope_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeLocks>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/lock",
@@ -250,7 +320,7 @@ impl EnvelopeLocks {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -40,6 +40,32 @@ impl EnvelopeRecipientTabs {
         include_anchor_tab_locations: &str,
         include_metadata: &str,
     ) -> ClientResult<crate::types::EnvelopeRecipientTabs> {
+        Ok(self
+            .recipients_get_recipient_tab_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+                include_anchor_tab_locations,
+                include_metadata,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the tabs information for a signer or sign-in-person recipient in an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_tab`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_recipient_tab_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
@@ -62,7 +88,7 @@ impl EnvelopeRecipientTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -94,6 +120,30 @@ impl EnvelopeRecipientTabs {
         recipient_id: &str,
         body: &crate::types::EnvelopeRecipientTabs,
     ) -> ClientResult<crate::types::EnvelopeRecipientTabs> {
+        Ok(self
+            .recipients_put_recipient_tabs_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates the tabs for a recipient.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_put_recipient_tabs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_recipient_tabs_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
@@ -104,7 +154,7 @@ impl EnvelopeRecipientTabs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -133,6 +183,30 @@ impl EnvelopeRecipientTabs {
         recipient_id: &str,
         body: &crate::types::EnvelopeRecipientTabs,
     ) -> ClientResult<crate::types::EnvelopeRecipientTabs> {
+        Ok(self
+            .recipients_post_recipient_tabs_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Adds tabs for a recipient.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_post_recipient_tabs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_post_recipient_tabs_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
@@ -143,7 +217,7 @@ impl EnvelopeRecipientTabs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -172,6 +246,30 @@ impl EnvelopeRecipientTabs {
         recipient_id: &str,
         body: &crate::types::EnvelopeRecipientTabs,
     ) -> ClientResult<crate::types::EnvelopeRecipientTabs> {
+        Ok(self
+            .recipients_delete_recipient_tabs_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the tabs associated with a recipient.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_delete_recipient_tabs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_delete_recipient_tabs_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipientTabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
@@ -182,7 +280,7 @@ impl EnvelopeRecipientTabs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -38,6 +38,34 @@ impl EnvelopeRecipients {
         include_metadata: &str,
         include_tabs: &str,
     ) -> ClientResult<crate::types::EnvelopeRecipients> {
+        Ok(self
+            .recipients_get_with_response(
+                account_id,
+                envelope_id,
+                include_anchor_tab_locations,
+                include_extended,
+                include_metadata,
+                include_tabs,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the status of recipients for an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
@@ -65,7 +93,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,6 +155,34 @@ impl EnvelopeRecipients {
         resend_envelope: &str,
         body: &crate::types::EnvelopeRecipients,
     ) -> ClientResult<crate::types::RecipientsUpdateSummary> {
+        Ok(self
+            .recipients_put_with_response(
+                account_id,
+                envelope_id,
+                combine_same_order_recipients,
+                offline_signing,
+                resend_envelope,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates recipients in a draft envelope or corrects recipient information for an in-process envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        combine_same_order_recipients: &str,
+        offline_signing: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !combine_same_order_recipients.is_empty() {
             query_args.push((
@@ -151,7 +207,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -182,6 +238,25 @@ impl EnvelopeRecipients {
         resend_envelope: &str,
         body: &crate::types::EnvelopeRecipients,
     ) -> ClientResult<crate::types::EnvelopeRecipients> {
+        Ok(self
+            .recipients_post_with_response(account_id, envelope_id, resend_envelope, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds one or more recipients to an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_post_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !resend_envelope.is_empty() {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
@@ -197,7 +272,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -226,6 +301,24 @@ impl EnvelopeRecipients {
         envelope_id: &str,
         body: &crate::types::EnvelopeRecipients,
     ) -> ClientResult<crate::types::EnvelopeRecipients> {
+        Ok(self
+            .recipients_delete_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes recipients from an envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_delete_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients",
@@ -235,7 +328,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -264,6 +357,24 @@ impl EnvelopeRecipients {
         envelope_id: &str,
         body: &crate::types::DocumentVisibilityList,
     ) -> ClientResult<crate::types::DocumentVisibilityList> {
+        Ok(self
+            .recipients_put_document_visibility_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates document visibility for recipients.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/document_visibility` endpoint.
+     *
+     * As opposed to `recipients_put_document_visibility`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_document_visibility_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/document_visibility",
@@ -273,7 +384,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -303,6 +414,24 @@ impl EnvelopeRecipients {
         envelope_id: &str,
         recipient_id: &str,
     ) -> ClientResult<crate::types::EnvelopeRecipients> {
+        Ok(self
+            .recipients_delete_recipient_with_response(account_id, envelope_id, recipient_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes a recipient from an envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}` endpoint.
+     *
+     * As opposed to `recipients_delete_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_delete_recipient_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeRecipients>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}",
@@ -313,7 +442,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -341,6 +470,28 @@ impl EnvelopeRecipients {
         envelope_id: &str,
         recipient_id: &str,
     ) -> ClientResult<crate::types::ProofServiceResourceToken> {
+        Ok(self
+            .recipients_post_recipient_proof_file_resource_token_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Creates a resource token for a sender to request ID Evidence data. .
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/identity_proof_token` endpoint.
+     *
+     * As opposed to `recipients_post_recipient_proof_file_resource_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_post_recipient_proof_file_resource_token_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProofServiceResourceToken>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/identity_proof_token",
@@ -351,7 +502,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -392,6 +543,28 @@ impl EnvelopeRecipients {
         envelope_id: &str,
         recipient_id: &str,
     ) -> ClientResult<crate::types::ViewUrl> {
+        Ok(self
+            .views_post_recipient_manual_review_view_with_response(
+                account_id,
+                envelope_id,
+                recipient_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Create the link to the page for manually reviewing IDs.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/views/identity_manual_review` endpoint.
+     *
+     * As opposed to `views_post_recipient_manual_review_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_recipient_manual_review_view_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/views/identity_manual_review",
@@ -402,7 +575,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -431,6 +604,24 @@ impl EnvelopeRecipients {
         envelope_id: &str,
         body: &crate::types::RecipientPreviewRequest,
     ) -> ClientResult<crate::types::ViewUrl> {
+        Ok(self
+            .views_post_preview_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates an envelope recipient preview.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient_preview` endpoint.
+     *
+     * As opposed to `views_post_preview`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_preview_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/views/recipient_preview",
@@ -440,7 +631,7 @@ impl EnvelopeRecipients {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
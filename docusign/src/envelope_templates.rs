@@ -37,6 +37,25 @@ impl EnvelopeTemplates {
         envelope_id: &str,
         include: &str,
     ) -> ClientResult<crate::types::TemplateInformation> {
+        Ok(self
+            .templates_get_document_with_response(account_id, document_id, envelope_id, include)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the templates associated with a document in an existing envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates` endpoint.
+     *
+     * As opposed to `templates_get_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn templates_get_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -53,7 +72,7 @@ impl EnvelopeTemplates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -84,6 +103,32 @@ impl EnvelopeTemplates {
         preserve_template_recipient: &str,
         body: &crate::types::DocumentTemplateList,
     ) -> ClientResult<crate::types::DocumentTemplateList> {
+        Ok(self
+            .templates_post_document_with_response(
+                account_id,
+                document_id,
+                envelope_id,
+                preserve_template_recipient,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Adds templates to a document in an  envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates` endpoint.
+     *
+     * As opposed to `templates_post_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn templates_post_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !preserve_template_recipient.is_empty() {
             query_args.push((
@@ -103,7 +148,7 @@ impl EnvelopeTemplates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -133,6 +178,29 @@ impl EnvelopeTemplates {
         envelope_id: &str,
         template_id: &str,
     ) -> ClientResult<()> {
+        self.templates_delete_document_with_response(
+            account_id,
+            document_id,
+            envelope_id,
+            template_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a template from a document in an existing envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}` endpoint.
+     *
+     * As opposed to `templates_delete_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn templates_delete_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates/{}",
@@ -144,7 +212,7 @@ impl EnvelopeTemplates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -173,6 +241,24 @@ impl EnvelopeTemplates {
         envelope_id: &str,
         include: &str,
     ) -> ClientResult<crate::types::TemplateInformation> {
+        Ok(self
+            .templates_get_envelope_with_response(account_id, envelope_id, include)
+            .await?
+            .body)
+    }
+    /**
+     * Get List of Templates used in an Envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates` endpoint.
+     *
+     * As opposed to `templates_get_envelope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn templates_get_envelope_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -188,7 +274,7 @@ impl EnvelopeTemplates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -217,6 +303,30 @@ impl EnvelopeTemplates {
         preserve_template_recipient: &str,
         body: &crate::types::DocumentTemplateList,
     ) -> ClientResult<crate::types::DocumentTemplateList> {
+        Ok(self
+            .templates_post_envelope_with_response(
+                account_id,
+                envelope_id,
+                preserve_template_recipient,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Adds templates to an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates` endpoint.
+     *
+     * As opposed to `templates_post_envelope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn templates_post_envelope_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> ClientResult<crate::Response<crate::types::DocumentTemplateList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !preserve_template_recipient.is_empty() {
             query_args.push((
@@ -235,7 +345,7 @@ impl EnvelopeTemplates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
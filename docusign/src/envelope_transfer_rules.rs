@@ -32,6 +32,24 @@ impl EnvelopeTransferRules {
         count: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::EnvelopeTransferRuleInformation> {
+        Ok(self
+            .get_with_response(account_id, count, start_position)
+            .await?
+            .body)
+    }
+    /**
+     * Gets envelope transfer rules.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/transfer_rules` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -49,7 +67,7 @@ impl EnvelopeTransferRules {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -76,6 +94,20 @@ impl EnvelopeTransferRules {
         account_id: &str,
         body: &crate::types::EnvelopeTransferRuleInformation,
     ) -> ClientResult<crate::types::EnvelopeTransferRuleInformation> {
+        Ok(self.put_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Changes the status of multiple envelope transfer rules.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/transfer_rules` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleInformation,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/transfer_rules",
@@ -84,7 +116,7 @@ impl EnvelopeTransferRules {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -120,6 +152,20 @@ impl EnvelopeTransferRules {
         account_id: &str,
         body: &crate::types::EnvelopeTransferRuleRequest,
     ) -> ClientResult<crate::types::EnvelopeTransferRuleInformation> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Creates an envelope transfer rule.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/transfer_rules` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRuleInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/transfer_rules",
@@ -128,7 +174,7 @@ impl EnvelopeTransferRules {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -159,6 +205,24 @@ impl EnvelopeTransferRules {
         envelope_transfer_rule_id: &str,
         body: &crate::types::EnvelopeTransferRule,
     ) -> ClientResult<crate::types::EnvelopeTransferRule> {
+        Ok(self
+            .put_rule_with_response(account_id, envelope_transfer_rule_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Changes the status of an envelope transfer rule.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}` endpoint.
+     *
+     * As opposed to `put_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_rule_with_response(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+        body: &crate::types::EnvelopeTransferRule,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTransferRule>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
@@ -168,7 +232,7 @@ impl EnvelopeTransferRules {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -196,6 +260,22 @@ impl EnvelopeTransferRules {
         account_id: &str,
         envelope_transfer_rule_id: &str,
     ) -> ClientResult<()> {
+        self.delete_with_response(account_id, envelope_transfer_rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes an envelope transfer rule.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
@@ -205,7 +285,7 @@ impl EnvelopeTransferRules {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
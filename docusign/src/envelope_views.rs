@@ -44,6 +44,24 @@ impl EnvelopeViews {
         envelope_id: &str,
         body: &crate::types::CorrectViewRequest,
     ) -> ClientResult<crate::types::EnvelopeViews> {
+        Ok(self
+            .views_post_envelope_correct_view_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a URL to the envelope correction UI.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct` endpoint.
+     *
+     * As opposed to `views_post_envelope_correct_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_envelope_correct_view_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/views/correct",
@@ -53,7 +71,7 @@ impl EnvelopeViews {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -80,6 +98,23 @@ impl EnvelopeViews {
         envelope_id: &str,
         body: &crate::types::CorrectViewRequest,
     ) -> ClientResult<()> {
+        self.views_delete_envelope_correct_view_with_response(account_id, envelope_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Revokes the correction view URL to the Envelope UI.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct` endpoint.
+     *
+     * As opposed to `views_delete_envelope_correct_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_delete_envelope_correct_view_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/views/correct",
@@ -89,7 +124,7 @@ impl EnvelopeViews {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -133,6 +168,24 @@ impl EnvelopeViews {
         envelope_id: &str,
         body: &crate::types::ReturnUrlRequest,
     ) -> ClientResult<crate::types::EnvelopeViews> {
+        Ok(self
+            .views_post_envelope_edit_view_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a URL to the edit view UI.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/edit` endpoint.
+     *
+     * As opposed to `views_post_envelope_edit_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_envelope_edit_view_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/views/edit",
@@ -142,7 +195,7 @@ impl EnvelopeViews {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -195,6 +248,24 @@ impl EnvelopeViews {
         envelope_id: &str,
         body: &crate::types::RecipientViewRequest,
     ) -> ClientResult<crate::types::EnvelopeViews> {
+        Ok(self
+            .views_post_envelope_recipient_view_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a URL to the recipient view UI.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient` endpoint.
+     *
+     * As opposed to `views_post_envelope_recipient_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_envelope_recipient_view_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/views/recipient",
@@ -204,7 +275,7 @@ impl EnvelopeViews {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -259,6 +330,24 @@ impl EnvelopeViews {
         envelope_id: &str,
         body: &crate::types::ReturnUrlRequest,
     ) -> ClientResult<crate::types::EnvelopeViews> {
+        Ok(self
+            .views_post_envelope_sender_view_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a URL to the sender view UI.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/sender` endpoint.
+     *
+     * As opposed to `views_post_envelope_sender_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_envelope_sender_view_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/views/sender",
@@ -268,7 +357,7 @@ impl EnvelopeViews {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -300,6 +389,24 @@ impl EnvelopeViews {
         envelope_id: &str,
         body: &crate::types::RecipientViewRequest,
     ) -> ClientResult<crate::types::ViewUrl> {
+        Ok(self
+            .views_post_envelope_recipient_shared_view_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a URL to the shared recipient view UI for an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/shared` endpoint.
+     *
+     * As opposed to `views_post_envelope_recipient_shared_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_envelope_recipient_shared_view_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/views/shared",
@@ -309,7 +416,7 @@ impl EnvelopeViews {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -339,6 +446,23 @@ impl EnvelopeViews {
         account_id: &str,
         body: &crate::types::ConsoleViewRequest,
     ) -> ClientResult<crate::types::EnvelopeViews> {
+        Ok(self
+            .views_post_account_console_view_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a URL to the authentication view UI.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/views/console` endpoint.
+     *
+     * As opposed to `views_post_account_console_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_account_console_view_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConsoleViewRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeViews>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/views/console",
@@ -347,7 +471,7 @@ impl EnvelopeViews {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -28,6 +28,20 @@ impl EnvelopeWorkflowDefinition {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::Workflow> {
+        Ok(self.get_with_response(account_id, envelope_id).await?.body)
+    }
+    /**
+     * Gets an envelope's workflow definition.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/workflow",
@@ -37,7 +51,7 @@ impl EnvelopeWorkflowDefinition {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -64,6 +78,24 @@ impl EnvelopeWorkflowDefinition {
         envelope_id: &str,
         body: &crate::types::Workflow,
     ) -> ClientResult<crate::types::Workflow> {
+        Ok(self
+            .put_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an envelope's workflow definition.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Workflow,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/workflow",
@@ -73,7 +105,7 @@ impl EnvelopeWorkflowDefinition {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -96,6 +128,21 @@ impl EnvelopeWorkflowDefinition {
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete(&self, account_id: &str, envelope_id: &str) -> ClientResult<()> {
+        self.delete_with_response(account_id, envelope_id).await?;
+        Ok(())
+    }
+    /**
+     * Deletes an envelope's workflow definition.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/workflow",
@@ -105,7 +152,7 @@ impl EnvelopeWorkflowDefinition {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -131,6 +178,23 @@ impl EnvelopeWorkflowDefinition {
         account_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::Workflow> {
+        Ok(self
+            .get_template_with_response(account_id, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets template's workflow definition.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/workflow` endpoint.
+     *
+     * As opposed to `get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/workflow",
@@ -140,7 +204,7 @@ impl EnvelopeWorkflowDefinition {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -167,6 +231,24 @@ impl EnvelopeWorkflowDefinition {
         template_id: &str,
         body: &crate::types::Workflow,
     ) -> ClientResult<crate::types::Workflow> {
+        Ok(self
+            .put_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a template's workflow definiton.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/workflow` endpoint.
+     *
+     * As opposed to `put_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::Workflow,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/workflow",
@@ -176,7 +258,7 @@ impl EnvelopeWorkflowDefinition {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -198,6 +280,22 @@ impl EnvelopeWorkflowDefinition {
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete_template(&self, account_id: &str, template_id: &str) -> ClientResult<()> {
+        self.delete_template_with_response(account_id, template_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a template's workflow definition.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/workflow` endpoint.
+     *
+     * As opposed to `delete_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/workflow",
@@ -207,7 +305,7 @@ impl EnvelopeWorkflowDefinition {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -302,6 +302,84 @@ impl Envelopes {
         user_id: &str,
         user_name: &str,
     ) -> ClientResult<crate::types::EnvelopesInformation> {
+        Ok(self
+            .get_with_response(
+                account_id,
+                ac_status,
+                block,
+                cdse_mode,
+                continuation_token,
+                count,
+                custom_field,
+                email,
+                envelope_ids,
+                exclude,
+                folder_ids,
+                folder_types,
+                from_date,
+                from_to_status,
+                include,
+                include_purge_information,
+                intersecting_folder_ids,
+                last_queried_date,
+                order,
+                order_by,
+                powerformids,
+                query_budget,
+                requester_date_format,
+                search_text,
+                start_position,
+                status,
+                to_date,
+                transaction_ids,
+                user_filter,
+                user_id,
+                user_name,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets status changes for one or more envelopes.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        cdse_mode: &str,
+        continuation_token: &str,
+        count: &str,
+        custom_field: &str,
+        email: &str,
+        envelope_ids: &str,
+        exclude: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        from_to_status: &str,
+        include: &str,
+        include_purge_information: &str,
+        intersecting_folder_ids: &str,
+        last_queried_date: &str,
+        order: &str,
+        order_by: &str,
+        powerformids: &str,
+        query_budget: &str,
+        requester_date_format: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_filter: &str,
+        user_id: &str,
+        user_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ac_status.is_empty() {
             query_args.push(("ac_status".to_string(), ac_status.to_string()));
@@ -418,7 +496,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -522,6 +600,34 @@ impl Envelopes {
         merge_roles_on_draft: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> ClientResult<crate::types::EnvelopeSummary> {
+        Ok(self
+            .post_with_response(
+                account_id,
+                cdse_mode,
+                change_routing_order,
+                completed_documents_only,
+                merge_roles_on_draft,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Creates an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        cdse_mode: &str,
+        change_routing_order: &str,
+        completed_documents_only: &str,
+        merge_roles_on_draft: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeSummary>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cdse_mode.is_empty() {
             query_args.push(("cdse_mode".to_string(), cdse_mode.to_string()));
@@ -554,7 +660,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -693,6 +799,50 @@ impl Envelopes {
         user_name: &str,
         body: &crate::types::EnvelopeIdsRequest,
     ) -> ClientResult<crate::types::EnvelopesInformation> {
+        Ok(self
+            .put_status_with_response(
+                account_id,
+                ac_status,
+                block,
+                count,
+                email,
+                envelope_ids,
+                from_date,
+                from_to_status,
+                start_position,
+                status,
+                to_date,
+                transaction_ids,
+                user_name,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets envelope statuses for a set of envelopes.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/status` endpoint.
+     *
+     * As opposed to `put_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_status_with_response(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        count: &str,
+        email: &str,
+        envelope_ids: &str,
+        from_date: &str,
+        from_to_status: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_name: &str,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopesInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ac_status.is_empty() {
             query_args.push(("ac_status".to_string(), ac_status.to_string()));
@@ -740,7 +890,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -783,6 +933,25 @@ impl Envelopes {
         advanced_update: &str,
         include: &str,
     ) -> ClientResult<crate::types::Envelope> {
+        Ok(self
+            .get_envelopes_with_response(account_id, envelope_id, advanced_update, include)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the status of a single envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}` endpoint.
+     *
+     * As opposed to `get_envelopes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_envelopes_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::Envelope>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !advanced_update.is_empty() {
             query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
@@ -801,7 +970,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -996,6 +1165,32 @@ impl Envelopes {
         resend_envelope: &str,
         body: &crate::types::Envelope,
     ) -> ClientResult<crate::types::EnvelopeUpdateSummary> {
+        Ok(self
+            .put_with_response(
+                account_id,
+                envelope_id,
+                advanced_update,
+                resend_envelope,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Send, void, or modify a draft envelope. Purge documents from a completed envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        resend_envelope: &str,
+        body: &crate::types::Envelope,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeUpdateSummary>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !advanced_update.is_empty() {
             query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
@@ -1014,7 +1209,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1040,6 +1235,23 @@ impl Envelopes {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::EnvelopeAuditEventResponse> {
+        Ok(self
+            .audit_events_get_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the envelope audit events for an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/audit_events` endpoint.
+     *
+     * As opposed to `audit_events_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn audit_events_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeAuditEventResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/audit_events",
@@ -1049,7 +1261,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1091,6 +1303,42 @@ impl Envelopes {
         show_changes: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::PageImages> {
+        Ok(self
+            .pages_get_page_image_with_response(
+                account_id,
+                document_id,
+                envelope_id,
+                count,
+                dpi,
+                max_height,
+                max_width,
+                nocache,
+                show_changes,
+                start_position,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns document page image(s) based on input.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages` endpoint.
+     *
+     * As opposed to `pages_get_page_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_get_page_image_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageImages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1125,7 +1373,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1155,6 +1403,24 @@ impl Envelopes {
         envelope_id: &str,
         page_number: &str,
     ) -> ClientResult<()> {
+        self.pages_delete_page_with_response(account_id, document_id, envelope_id, page_number)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a page from a document in an envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}` endpoint.
+     *
+     * As opposed to `pages_delete_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_delete_page_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}",
@@ -1166,7 +1432,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1204,6 +1470,37 @@ impl Envelopes {
         max_width: &str,
         show_changes: &str,
     ) -> ClientResult<()> {
+        self.pages_get_page_image_envelopes_with_response(
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            dpi,
+            max_height,
+            max_width,
+            show_changes,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Gets a page image from an envelope for display.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_get_page_image_envelopes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_get_page_image_envelopes_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !dpi.is_empty() {
             query_args.push(("dpi".to_string(), dpi.to_string()));
@@ -1230,7 +1527,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1261,6 +1558,31 @@ impl Envelopes {
         page_number: &str,
         body: &crate::types::PageRequest,
     ) -> ClientResult<()> {
+        self.pages_put_page_image_with_response(
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Rotates page image from an envelope for display.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_put_page_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_put_page_image_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        body: &crate::types::PageRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
@@ -1272,7 +1594,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1298,6 +1620,23 @@ impl Envelopes {
         account_id: &str,
         envelope_id: &str,
     ) -> ClientResult<crate::types::Notification> {
+        Ok(self
+            .notification_get_with_response(account_id, envelope_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets envelope notification information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification` endpoint.
+     *
+     * As opposed to `notification_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn notification_get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/notification",
@@ -1307,7 +1646,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1336,6 +1675,24 @@ impl Envelopes {
         envelope_id: &str,
         body: &crate::types::EnvelopeNotificationRequest,
     ) -> ClientResult<crate::types::Notification> {
+        Ok(self
+            .notification_put_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Sets envelope notifications for an existing envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification` endpoint.
+     *
+     * As opposed to `notification_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn notification_put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeNotificationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/notification",
@@ -1345,7 +1702,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1383,6 +1740,29 @@ impl Envelopes {
         recipient_id: &str,
         include_chrome: &str,
     ) -> ClientResult<()> {
+        self.recipients_get_recipient_initials_image_with_response(
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Gets the initials image for a user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_initials_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_recipient_initials_image_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -1399,7 +1779,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1429,6 +1809,27 @@ impl Envelopes {
         envelope_id: &str,
         recipient_id: &str,
     ) -> ClientResult<()> {
+        self.recipients_put_recipient_initials_image_with_response(
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Sets the initials image for an accountless signer.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image` endpoint.
+     *
+     * As opposed to `recipients_put_recipient_initials_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_recipient_initials_image_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
@@ -1439,7 +1840,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1467,6 +1868,24 @@ impl Envelopes {
         envelope_id: &str,
         recipient_id: &str,
     ) -> ClientResult<crate::types::UserSignature> {
+        Ok(self
+            .recipients_get_recipient_signature_with_response(account_id, envelope_id, recipient_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets signature information for a signer or sign-in-person recipient.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_signature`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_recipient_signature_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature",
@@ -1477,7 +1896,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1515,6 +1934,29 @@ impl Envelopes {
         recipient_id: &str,
         include_chrome: &str,
     ) -> ClientResult<()> {
+        self.recipients_get_recipient_signature_image_with_response(
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Retrieve signature image information for a signer/sign-in-person recipient.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_recipient_signature_image_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -1531,7 +1973,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1561,6 +2003,27 @@ impl Envelopes {
         envelope_id: &str,
         recipient_id: &str,
     ) -> ClientResult<()> {
+        self.recipients_put_recipient_signature_image_with_response(
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Sets the signature image for an accountless signer.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image` endpoint.
+     *
+     * As opposed to `recipients_put_recipient_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_recipient_signature_image_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
@@ -1571,7 +2034,7 @@ impl Envelopes {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
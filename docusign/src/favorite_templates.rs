@@ -23,6 +23,19 @@ impl FavoriteTemplates {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn get(&self, account_id: &str) -> ClientResult<crate::types::FavoriteTemplatesInfo> {
+        Ok(self.get_with_response(account_id).await?.body)
+    }
+    /**
+     * Retrieves the list of favorited templates for this caller.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/favorite_templates` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FavoriteTemplatesInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/favorite_templates",
@@ -31,7 +44,7 @@ impl FavoriteTemplates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,6 +70,23 @@ impl FavoriteTemplates {
         account_id: &str,
         body: &crate::types::FavoriteTemplatesInfo,
     ) -> ClientResult<crate::types::FavoriteTemplatesInfo> {
+        Ok(self
+            .put_template_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Sets a template as a favorite.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/favorite_templates` endpoint.
+     *
+     * As opposed to `put_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_template_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> ClientResult<crate::Response<crate::types::FavoriteTemplatesInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/favorite_templates",
@@ -65,7 +95,7 @@ impl FavoriteTemplates {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -90,6 +120,20 @@ impl FavoriteTemplates {
         account_id: &str,
         body: &crate::types::FavoriteTemplatesInfo,
     ) -> ClientResult<crate::types::FavoriteTemplatesInfo> {
+        Ok(self.un_template_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Unfavorites a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/favorite_templates` endpoint.
+     *
+     * As opposed to `un_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn un_template_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> ClientResult<crate::Response<crate::types::FavoriteTemplatesInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/favorite_templates",
@@ -98,7 +142,7 @@ impl FavoriteTemplates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -47,6 +47,34 @@ impl Folders {
         template: &str,
         user_filter: &str,
     ) -> ClientResult<crate::types::FoldersResponse> {
+        Ok(self
+            .get_with_response(
+                account_id,
+                include,
+                include_items,
+                start_position,
+                template,
+                user_filter,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of the folders for the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/folders` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        include: &str,
+        include_items: &str,
+        start_position: &str,
+        template: &str,
+        user_filter: &str,
+    ) -> ClientResult<crate::Response<crate::types::FoldersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -73,7 +101,7 @@ impl Folders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -115,6 +143,42 @@ impl Folders {
         status: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::FolderItemsResponse> {
+        Ok(self
+            .get_items_with_response(
+                account_id,
+                folder_id,
+                from_date,
+                include_items,
+                owner_email,
+                owner_name,
+                search_text,
+                start_position,
+                status,
+                to_date,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of the envelopes in the specified folder.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `get_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_items_with_response(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        from_date: &str,
+        include_items: &str,
+        owner_email: &str,
+        owner_name: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::FolderItemsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from_date.is_empty() {
             query_args.push(("from_date".to_string(), from_date.to_string()));
@@ -151,7 +215,7 @@ impl Folders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -183,6 +247,24 @@ impl Folders {
         folder_id: &str,
         body: &crate::types::FoldersRequest,
     ) -> ClientResult<crate::types::FoldersResponse> {
+        Ok(self
+            .put_with_response(account_id, folder_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Moves an envelope from its current folder to the specified folder.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        body: &crate::types::FoldersRequest,
+    ) -> ClientResult<crate::Response<crate::types::FoldersResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/folders/{}",
@@ -192,7 +274,7 @@ impl Folders {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -240,6 +322,42 @@ impl Folders {
         start_position: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::FolderItemResponse> {
+        Ok(self
+            .search_get_contents_with_response(
+                account_id,
+                search_folder_id,
+                all,
+                count,
+                from_date,
+                include_recipients,
+                order,
+                order_by,
+                start_position,
+                to_date,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of envelopes in folders matching the specified criteria.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/search_folders/{searchFolderId}` endpoint.
+     *
+     * As opposed to `search_get_contents`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn search_get_contents_with_response(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        all: &str,
+        count: &str,
+        from_date: &str,
+        include_recipients: &str,
+        order: &str,
+        order_by: &str,
+        start_position: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::FolderItemResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !all.is_empty() {
             query_args.push(("all".to_string(), all.to_string()));
@@ -279,7 +397,7 @@ impl Folders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,23 @@ impl GroupBrands {
         account_id: &str,
         group_id: &str,
     ) -> ClientResult<crate::types::GroupBrands> {
+        Ok(self
+            .brands_get_group_with_response(account_id, group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the brand information for a group.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/groups/{groupId}/brands` endpoint.
+     *
+     * As opposed to `brands_get_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brands_get_group_with_response(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupBrands>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups/{}/brands",
@@ -37,7 +54,7 @@ impl GroupBrands {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -64,6 +81,24 @@ impl GroupBrands {
         group_id: &str,
         body: &crate::types::BrandsRequest,
     ) -> ClientResult<crate::types::GroupBrands> {
+        Ok(self
+            .brands_put_group_with_response(account_id, group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds an existing brand to a group.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/groups/{groupId}/brands` endpoint.
+     *
+     * As opposed to `brands_put_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brands_put_group_with_response(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupBrands>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups/{}/brands",
@@ -73,7 +108,7 @@ impl GroupBrands {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -100,6 +135,24 @@ impl GroupBrands {
         group_id: &str,
         body: &crate::types::BrandsRequest,
     ) -> ClientResult<crate::types::GroupBrands> {
+        Ok(self
+            .brands_delete_group_with_response(account_id, group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes brand information from a group.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/groups/{groupId}/brands` endpoint.
+     *
+     * As opposed to `brands_delete_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn brands_delete_group_with_response(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupBrands>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups/{}/brands",
@@ -109,7 +162,7 @@ impl GroupBrands {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
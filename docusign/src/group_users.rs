@@ -32,6 +32,25 @@ impl GroupUsers {
         count: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::UsersResponse> {
+        Ok(self
+            .groups_get_with_response(account_id, group_id, count, start_position)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of users in a group.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `groups_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn groups_get_with_response(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -50,7 +69,7 @@ impl GroupUsers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -77,6 +96,24 @@ impl GroupUsers {
         group_id: &str,
         body: &crate::types::UserInfoList,
     ) -> ClientResult<crate::types::UsersResponse> {
+        Ok(self
+            .groups_put_with_response(account_id, group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds one or more users to an existing group.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `groups_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn groups_put_with_response(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups/{}/users",
@@ -86,7 +123,7 @@ impl GroupUsers {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -114,6 +151,24 @@ impl GroupUsers {
         group_id: &str,
         body: &crate::types::UserInfoList,
     ) -> ClientResult<crate::types::UsersResponse> {
+        Ok(self
+            .groups_delete_with_response(account_id, group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes one or more users from a group.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `groups_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn groups_delete_with_response(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups/{}/users",
@@ -123,7 +178,7 @@ impl GroupUsers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -36,6 +36,34 @@ impl Groups {
         search_text: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::GroupInformation> {
+        Ok(self
+            .get_with_response(
+                account_id,
+                count,
+                group_type,
+                include_usercount,
+                search_text,
+                start_position,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets information about groups associated with the account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/groups` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        count: &str,
+        group_type: &str,
+        include_usercount: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -65,7 +93,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -90,6 +118,20 @@ impl Groups {
         account_id: &str,
         body: &crate::types::GroupInformation,
     ) -> ClientResult<crate::types::GroupInformation> {
+        Ok(self.put_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Updates the group information for a group.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/groups` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups",
@@ -98,7 +140,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -125,6 +167,20 @@ impl Groups {
         account_id: &str,
         body: &crate::types::GroupInformation,
     ) -> ClientResult<crate::types::GroupInformation> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Creates one or more groups for the account.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/groups` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups",
@@ -133,7 +189,7 @@ impl Groups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -172,6 +228,20 @@ impl Groups {
         account_id: &str,
         body: &crate::types::GroupInformation,
     ) -> ClientResult<crate::types::GroupInformation> {
+        Ok(self.delete_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Deletes an existing user group.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/groups` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/groups",
@@ -180,7 +250,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -28,6 +28,19 @@ impl IdentityVerifications {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::AccountIdentityVerificationResponse> {
+        Ok(self.account_get_with_response(account_id).await?.body)
+    }
+    /**
+     * Retrieves the Identity Verification workflows available to an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/identity_verification` endpoint.
+     *
+     * As opposed to `account_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountIdentityVerificationResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/identity_verification",
@@ -36,7 +49,7 @@ impl IdentityVerifications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
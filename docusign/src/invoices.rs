@@ -32,6 +32,24 @@ impl Invoices {
         from_date: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::BillingInvoicesResponse> {
+        Ok(self
+            .billing_get_with_response(account_id, from_date, to_date)
+            .await?
+            .body)
+    }
+    /**
+     * Get a List of Billing Invoices.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_invoices` endpoint.
+     *
+     * As opposed to `billing_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn billing_get_with_response(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingInvoicesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from_date.is_empty() {
             query_args.push(("from_date".to_string(), from_date.to_string()));
@@ -49,7 +67,7 @@ impl Invoices {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -108,6 +126,23 @@ impl Invoices {
         account_id: &str,
         invoice_id: &str,
     ) -> ClientResult<crate::types::BillingInvoice> {
+        Ok(self
+            .billing_get_invoices_with_response(account_id, invoice_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a billing invoice.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_invoices/{invoiceId}` endpoint.
+     *
+     * As opposed to `billing_get_invoices`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn billing_get_invoices_with_response(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingInvoice>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_invoices/{}",
@@ -117,7 +152,7 @@ impl Invoices {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -143,6 +178,22 @@ impl Invoices {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::BillingInvoicesSummary> {
+        Ok(self
+            .billing_get_past_due_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a list of past due invoices.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_invoices_past_due` endpoint.
+     *
+     * As opposed to `billing_get_past_due`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn billing_get_past_due_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingInvoicesSummary>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_invoices_past_due",
@@ -151,7 +202,7 @@ impl Invoices {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -27,6 +27,19 @@ impl Notary {
         &self,
         include_jurisdictions: &str,
     ) -> ClientResult<crate::types::NotaryResult> {
+        Ok(self.get_with_response(include_jurisdictions).await?.body)
+    }
+    /**
+     * Gets settings for a  notary user.
+     *
+     * This function performs a `GET` to the `/v2.1/current_user/notary` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        include_jurisdictions: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotaryResult>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_jurisdictions.is_empty() {
             query_args.push((
@@ -39,7 +52,7 @@ impl Notary {
             .client
             .url(&format!("/v2.1/current_user/notary?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,9 +72,22 @@ impl Notary {
         &self,
         body: &crate::types::NotaryData,
     ) -> ClientResult<crate::types::NotaryData> {
+        Ok(self.put_with_response(body).await?.body)
+    }
+    /**
+     * Updates notary information for the current user.
+     *
+     * This function performs a `PUT` to the `/v2.1/current_user/notary` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        body: &crate::types::NotaryData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryData>> {
         let url = self.client.url("/v2.1/current_user/notary", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -81,9 +107,22 @@ impl Notary {
         &self,
         body: &crate::types::NotaryData,
     ) -> ClientResult<crate::types::NotaryData> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Registers the current user as a notary.
+     *
+     * This function performs a `POST` to the `/v2.1/current_user/notary` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::NotaryData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryData>> {
         let url = self.client.url("/v2.1/current_user/notary", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
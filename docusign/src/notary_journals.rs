@@ -30,6 +30,24 @@ impl NotaryJournals {
         search_text: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::NotaryJournalList> {
+        Ok(self
+            .get_with_response(count, search_text, start_position)
+            .await?
+            .body)
+    }
+    /**
+     * Gets notary jurisdictions for a user.
+     *
+     * This function performs a `GET` to the `/v2.1/current_user/notary/journals` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        count: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJournalList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -46,7 +64,7 @@ impl NotaryJournals {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -20,11 +20,23 @@ impl NotaryJurisdiction {
      * The current user must be a notary.
      */
     pub async fn s_get(&self) -> ClientResult<crate::types::NotaryJurisdictionList> {
+        Ok(self.s_get_with_response().await?.body)
+    }
+    /**
+     * Returns a list of jurisdictions that the notary is registered in.
+     *
+     * This function performs a `GET` to the `/v2.1/current_user/notary/jurisdictions` endpoint.
+     *
+     * As opposed to `s_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn s_get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionList>> {
         let url = self
             .client
             .url("/v2.1/current_user/notary/jurisdictions", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -44,11 +56,24 @@ impl NotaryJurisdiction {
         &self,
         body: &crate::types::NotaryJurisdictionData,
     ) -> ClientResult<crate::types::NotaryJurisdictionData> {
+        Ok(self.s_post_with_response(body).await?.body)
+    }
+    /**
+     * Creates a jurisdiction object.
+     *
+     * This function performs a `POST` to the `/v2.1/current_user/notary/jurisdictions` endpoint.
+     *
+     * As opposed to `s_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn s_post_with_response(
+        &self,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionData>> {
         let url = self
             .client
             .url("/v2.1/current_user/notary/jurisdictions", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -104,6 +129,22 @@ impl NotaryJurisdiction {
         &self,
         jurisdiction_id: &str,
     ) -> ClientResult<crate::types::NotaryJurisdictionData> {
+        Ok(self
+            .s_get_jurisdiction_with_response(jurisdiction_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a jurisdiction object for the current user. The user must be a notary.
+     *
+     * This function performs a `GET` to the `/v2.1/current_user/notary/jurisdictions/{jurisdictionId}` endpoint.
+     *
+     * As opposed to `s_get_jurisdiction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn s_get_jurisdiction_with_response(
+        &self,
+        jurisdiction_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/current_user/notary/jurisdictions/{}",
@@ -112,7 +153,7 @@ impl NotaryJurisdiction {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -223,6 +264,23 @@ impl NotaryJurisdiction {
         jurisdiction_id: &str,
         body: &crate::types::NotaryJurisdictionData,
     ) -> ClientResult<crate::types::NotaryJurisdictionData> {
+        Ok(self
+            .s_put_jurisdiction_with_response(jurisdiction_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates the jurisdiction information about a notary.
+     *
+     * This function performs a `PUT` to the `/v2.1/current_user/notary/jurisdictions/{jurisdictionId}` endpoint.
+     *
+     * As opposed to `s_put_jurisdiction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn s_put_jurisdiction_with_response(
+        &self,
+        jurisdiction_id: &str,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> ClientResult<crate::Response<crate::types::NotaryJurisdictionData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/current_user/notary/jurisdictions/{}",
@@ -231,7 +289,7 @@ impl NotaryJurisdiction {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -280,6 +338,21 @@ impl NotaryJurisdiction {
      *   .
      */
     pub async fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> ClientResult<()> {
+        self.s_delete_jurisdiction_with_response(jurisdiction_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes the specified jurisdiction.
+     *
+     * This function performs a `DELETE` to the `/v2.1/current_user/notary/jurisdictions/{jurisdictionId}` endpoint.
+     *
+     * As opposed to `s_delete_jurisdiction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn s_delete_jurisdiction_with_response(
+        &self,
+        jurisdiction_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/current_user/notary/jurisdictions/{}",
@@ -288,7 +361,7 @@ impl NotaryJurisdiction {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -26,6 +26,19 @@ impl PaymentGatewayAccounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::PaymentGatewayAccountsInfo> {
+        Ok(self.get_all_with_response(account_id).await?.body)
+    }
+    /**
+     * List payment gateway accounts.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/payment_gateway_accounts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_all_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentGatewayAccountsInfo>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/payment_gateway_accounts",
@@ -34,7 +47,7 @@ impl PaymentGatewayAccounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
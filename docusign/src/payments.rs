@@ -32,6 +32,24 @@ impl Payments {
         from_date: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::BillingPaymentsResponse> {
+        Ok(self
+            .billing_get_list_with_response(account_id, from_date, to_date)
+            .await?
+            .body)
+    }
+    /**
+     * Gets payment information for one or more payments.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_payments` endpoint.
+     *
+     * As opposed to `billing_get_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn billing_get_list_with_response(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingPaymentsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from_date.is_empty() {
             query_args.push(("from_date".to_string(), from_date.to_string()));
@@ -49,7 +67,7 @@ impl Payments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -81,6 +99,23 @@ impl Payments {
         account_id: &str,
         body: &crate::types::BillingPaymentRequest,
     ) -> ClientResult<crate::types::BillingPaymentResponse> {
+        Ok(self
+            .billing_post_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Posts a payment to a past due invoice.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/billing_payments` endpoint.
+     *
+     * As opposed to `billing_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn billing_post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingPaymentRequest,
+    ) -> ClientResult<crate::Response<crate::types::BillingPaymentResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_payments",
@@ -89,7 +124,7 @@ impl Payments {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -117,6 +152,23 @@ impl Payments {
         account_id: &str,
         payment_id: &str,
     ) -> ClientResult<crate::types::BillingPaymentItem> {
+        Ok(self
+            .billing_get_with_response(account_id, payment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets billing payment information for a specific payment.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/billing_payments/{paymentId}` endpoint.
+     *
+     * As opposed to `billing_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn billing_get_with_response(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BillingPaymentItem>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/billing_payments/{}",
@@ -126,7 +178,7 @@ impl Payments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
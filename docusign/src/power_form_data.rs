@@ -50,6 +50,32 @@ impl PowerFormData {
         from_date: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::PowerFormsFormDataResponse> {
+        Ok(self
+            .power_forms_get_form_data_with_response(
+                account_id,
+                power_form_id,
+                data_layout,
+                from_date,
+                to_date,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns the data that users entered in a PowerForm.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/powerforms/{powerFormId}/form_data` endpoint.
+     *
+     * As opposed to `power_forms_get_form_data`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn power_forms_get_form_data_with_response(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        data_layout: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormsFormDataResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !data_layout.is_empty() {
             query_args.push(("data_layout".to_string(), data_layout.to_string()));
@@ -71,7 +97,7 @@ impl PowerFormData {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
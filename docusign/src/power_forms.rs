@@ -49,6 +49,26 @@ impl PowerForms {
         order_by: &str,
         to_date: &str,
     ) -> ClientResult<crate::types::PowerFormsResponse> {
+        Ok(self
+            .get_list_with_response(account_id, from_date, order, order_by, to_date)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a list of PowerForms.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/powerforms` endpoint.
+     *
+     * As opposed to `get_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_list_with_response(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        order: &str,
+        order_by: &str,
+        to_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from_date.is_empty() {
             query_args.push(("from_date".to_string(), from_date.to_string()));
@@ -72,7 +92,7 @@ impl PowerForms {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -136,6 +156,20 @@ impl PowerForms {
         account_id: &str,
         body: &crate::types::PowerForm,
     ) -> ClientResult<crate::types::PowerForm> {
+        Ok(self.post_form_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Creates a new PowerForm.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/powerforms` endpoint.
+     *
+     * As opposed to `post_form`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_form_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> ClientResult<crate::Response<crate::types::PowerForm>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/powerforms",
@@ -144,7 +178,7 @@ impl PowerForms {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -169,6 +203,20 @@ impl PowerForms {
         account_id: &str,
         body: &crate::types::PowerFormsRequest,
     ) -> ClientResult<crate::types::PowerFormsResponse> {
+        Ok(self.delete_list_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Deletes one or more PowerForms.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/powerforms` endpoint.
+     *
+     * As opposed to `delete_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_list_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerFormsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormsResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/powerforms",
@@ -177,7 +225,7 @@ impl PowerForms {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -203,6 +251,23 @@ impl PowerForms {
         account_id: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::PowerFormSendersResponse> {
+        Ok(self
+            .get_senders_with_response(account_id, start_position)
+            .await?
+            .body)
+    }
+    /**
+     * Gets PowerForm senders.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/powerforms/senders` endpoint.
+     *
+     * As opposed to `get_senders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_senders_with_response(
+        &self,
+        account_id: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerFormSendersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !start_position.is_empty() {
             query_args.push(("start_position".to_string(), start_position.to_string()));
@@ -217,7 +282,7 @@ impl PowerForms {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -243,6 +308,23 @@ impl PowerForms {
         account_id: &str,
         power_form_id: &str,
     ) -> ClientResult<crate::types::PowerForm> {
+        Ok(self
+            .get_form_with_response(account_id, power_form_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a single PowerForm.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/powerforms/{powerFormId}` endpoint.
+     *
+     * As opposed to `get_form`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_form_with_response(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PowerForm>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/powerforms/{}",
@@ -252,7 +334,7 @@ impl PowerForms {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -279,6 +361,24 @@ impl PowerForms {
         power_form_id: &str,
         body: &crate::types::PowerForm,
     ) -> ClientResult<crate::types::PowerForm> {
+        Ok(self
+            .put_form_with_response(account_id, power_form_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an existing PowerForm.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/powerforms/{powerFormId}` endpoint.
+     *
+     * As opposed to `put_form`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_form_with_response(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> ClientResult<crate::Response<crate::types::PowerForm>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/powerforms/{}",
@@ -288,7 +388,7 @@ impl PowerForms {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -310,6 +410,22 @@ impl PowerForms {
      * * `power_form_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete_form(&self, account_id: &str, power_form_id: &str) -> ClientResult<()> {
+        self.delete_form_with_response(account_id, power_form_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a PowerForm.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/powerforms/{powerFormId}` endpoint.
+     *
+     * As opposed to `delete_form`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_form_with_response(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/powerforms/{}",
@@ -319,7 +435,7 @@ impl PowerForms {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
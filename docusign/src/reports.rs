@@ -27,6 +27,19 @@ impl Reports {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::ReportInProductList> {
+        Ok(self.product_get_list_with_response(account_id).await?.body)
+    }
+    /**
+     * Gets the descriptors for all of.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/reports` endpoint.
+     *
+     * As opposed to `product_get_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn product_get_list_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/reports",
@@ -35,7 +48,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,6 +73,23 @@ impl Reports {
         account_id: &str,
         body: &crate::types::ReportInProductRunRequest,
     ) -> ClientResult<crate::types::ReportInProductSaveResponse> {
+        Ok(self
+            .product_post_create_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a customized report.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/reports` endpoint.
+     *
+     * As opposed to `product_post_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn product_post_create_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductSaveResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/reports",
@@ -68,7 +98,7 @@ impl Reports {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -93,6 +123,23 @@ impl Reports {
         account_id: &str,
         body: &crate::types::ReportInProductRunRequest,
     ) -> ClientResult<crate::types::ReportInProductRunResponse> {
+        Ok(self
+            .product_put_run_results_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Returns the result set from running the specified report.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/reports/report_results` endpoint.
+     *
+     * As opposed to `product_put_run_results`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn product_put_run_results_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductRunResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/reports/report_results",
@@ -101,7 +148,7 @@ impl Reports {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -126,6 +173,22 @@ impl Reports {
         account_id: &str,
         body: &crate::types::ReportInProductCsvRunRequest,
     ) -> ClientResult<()> {
+        self.product_put_results_csv_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Returns the specified report as a CSV string.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/reports/report_results_csv` endpoint.
+     *
+     * As opposed to `product_put_results_csv`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn product_put_results_csv_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductCsvRunRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/reports/report_results_csv",
@@ -134,7 +197,7 @@ impl Reports {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -160,6 +223,20 @@ impl Reports {
         account_id: &str,
         id: &str,
     ) -> ClientResult<crate::types::ReportInProductGet> {
+        Ok(self.product_get_with_response(account_id, id).await?.body)
+    }
+    /**
+     * Gets the specified report.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/reports/{id}` endpoint.
+     *
+     * As opposed to `product_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn product_get_with_response(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductGet>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/reports/{}",
@@ -169,7 +246,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -196,6 +273,24 @@ impl Reports {
         id: &str,
         body: &crate::types::ReportInProductRunRequest,
     ) -> ClientResult<crate::types::ReportInProductSaveResponse> {
+        Ok(self
+            .product_put_save_with_response(account_id, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Saves a customized report.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/reports/{id}` endpoint.
+     *
+     * As opposed to `product_put_save`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn product_put_save_with_response(
+        &self,
+        account_id: &str,
+        id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductSaveResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/reports/{}",
@@ -205,7 +300,7 @@ impl Reports {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -231,6 +326,23 @@ impl Reports {
         account_id: &str,
         id: &str,
     ) -> ClientResult<crate::types::ReportInProductSaveResponse> {
+        Ok(self
+            .product_delete_with_response(account_id, id)
+            .await?
+            .body)
+    }
+    /**
+     * Removes a customized report.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/reports/{id}` endpoint.
+     *
+     * As opposed to `product_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn product_delete_with_response(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportInProductSaveResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/reports/{}",
@@ -240,7 +352,7 @@ impl Reports {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
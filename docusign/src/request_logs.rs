@@ -30,6 +30,19 @@ impl RequestLogs {
         &self,
         encoding: &str,
     ) -> ClientResult<crate::types::ApiRequestLogsResult> {
+        Ok(self.api_get_log_with_response(encoding).await?.body)
+    }
+    /**
+     * Gets the API request logging log files.
+     *
+     * This function performs a `GET` to the `/v2.1/diagnostics/request_logs` endpoint.
+     *
+     * As opposed to `api_get_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn api_get_log_with_response(
+        &self,
+        encoding: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApiRequestLogsResult>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encoding.is_empty() {
             query_args.push(("encoding".to_string(), encoding.to_string()));
@@ -39,7 +52,7 @@ impl RequestLogs {
             .client
             .url(&format!("/v2.1/diagnostics/request_logs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,9 +69,20 @@ impl RequestLogs {
      * Deletes the request log files.
      */
     pub async fn api_delete_logs(&self) -> ClientResult<()> {
+        self.api_delete_logs_with_response().await?;
+        Ok(())
+    }
+    /**
+     * Deletes the request log files.
+     *
+     * This function performs a `DELETE` to the `/v2.1/diagnostics/request_logs` endpoint.
+     *
+     * As opposed to `api_delete_logs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn api_delete_logs_with_response(&self) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/v2.1/diagnostics/request_logs", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -85,6 +109,19 @@ impl RequestLogs {
      * * `request_log_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn api_get(&self, request_log_id: &str) -> ClientResult<bytes::Bytes> {
+        Ok(self.api_get_with_response(request_log_id).await?.body)
+    }
+    /**
+     * Gets a request logging log file.
+     *
+     * This function performs a `GET` to the `/v2.1/diagnostics/request_logs/{requestLogId}` endpoint.
+     *
+     * As opposed to `api_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn api_get_with_response(
+        &self,
+        request_log_id: &str,
+    ) -> ClientResult<crate::Response<bytes::Bytes>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/diagnostics/request_logs/{}",
@@ -93,7 +130,7 @@ impl RequestLogs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -115,9 +152,21 @@ impl RequestLogs {
     pub async fn api_get_setting(
         &self,
     ) -> ClientResult<crate::types::DiagnosticsSettingsInformation> {
+        Ok(self.api_get_setting_with_response().await?.body)
+    }
+    /**
+     * Gets the API request logging settings.
+     *
+     * This function performs a `GET` to the `/v2.1/diagnostics/settings` endpoint.
+     *
+     * As opposed to `api_get_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn api_get_setting_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DiagnosticsSettingsInformation>> {
         let url = self.client.url("/v2.1/diagnostics/settings", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -148,9 +197,22 @@ impl RequestLogs {
         &self,
         body: &crate::types::DiagnosticsSettingsInformation,
     ) -> ClientResult<crate::types::DiagnosticsSettingsInformation> {
+        Ok(self.api_put_settings_with_response(body).await?.body)
+    }
+    /**
+     * Enables or disables API request logging for troubleshooting.
+     *
+     * This function performs a `PUT` to the `/v2.1/diagnostics/settings` endpoint.
+     *
+     * As opposed to `api_put_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn api_put_settings_with_response(
+        &self,
+        body: &crate::types::DiagnosticsSettingsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DiagnosticsSettingsInformation>> {
         let url = self.client.url("/v2.1/diagnostics/settings", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
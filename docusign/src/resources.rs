@@ -27,9 +27,21 @@ impl Resources {
      * Example: https://demo.docusign.net/restapi/help lists the REST API operations on the DocuSign Demo system with XML and JSON request and response samples.
      */
     pub async fn service_information_get(&self) -> ClientResult<crate::types::ResourceInformation> {
+        Ok(self.service_information_get_with_response().await?.body)
+    }
+    /**
+     * Lists resources for REST version specified.
+     *
+     * This function performs a `GET` to the `/v2.1` endpoint.
+     *
+     * As opposed to `service_information_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn service_information_get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ResourceInformation>> {
         let url = self.client.url("/v2.1", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -40,6 +40,24 @@ impl ResponsiveHtmlPreview {
         envelope_id: &str,
         body: &crate::types::DocumentHtmlDefinition,
     ) -> ClientResult<crate::types::DocumentHtmlDefinitions> {
+        Ok(self
+            .responsive_html_post_preview_with_response(account_id, envelope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a preview of the responsive versions of all of the documents in an envelope.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/responsive_html_preview` endpoint.
+     *
+     * As opposed to `responsive_html_post_preview`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn responsive_html_post_preview_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/responsive_html_preview",
@@ -49,7 +67,7 @@ impl ResponsiveHtmlPreview {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,9 +24,21 @@ impl Services {
      * You do not need an integrator key to view the REST API versions and resources.
      */
     pub async fn information_get(&self) -> ClientResult<crate::types::ServiceInformation> {
+        Ok(self.information_get_with_response().await?.body)
+    }
+    /**
+     * Retrieves the available REST API versions.
+     *
+     * This function performs a `GET` to the `/service_information` endpoint.
+     *
+     * As opposed to `information_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn information_get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ServiceInformation>> {
         let url = self.client.url("/service_information", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
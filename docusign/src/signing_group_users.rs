@@ -30,6 +30,23 @@ impl SigningGroupUsers {
         account_id: &str,
         signing_group_id: &str,
     ) -> ClientResult<crate::types::SigningGroupUsersData> {
+        Ok(self
+            .signing_groups_get_group_user_with_response(account_id, signing_group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of members in a Signing Group.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users` endpoint.
+     *
+     * As opposed to `signing_groups_get_group_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn signing_groups_get_group_user_with_response(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupUsersData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups/{}/users",
@@ -39,7 +56,7 @@ impl SigningGroupUsers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,6 +85,24 @@ impl SigningGroupUsers {
         signing_group_id: &str,
         body: &crate::types::SigningGroupUsersData,
     ) -> ClientResult<crate::types::SigningGroupUsersData> {
+        Ok(self
+            .signing_groups_put_group_users_with_response(account_id, signing_group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds members to a signing group. .
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users` endpoint.
+     *
+     * As opposed to `signing_groups_put_group_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn signing_groups_put_group_users_with_response(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupUsersData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups/{}/users",
@@ -77,7 +112,7 @@ impl SigningGroupUsers {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -106,6 +141,24 @@ impl SigningGroupUsers {
         signing_group_id: &str,
         body: &crate::types::SigningGroupUsersData,
     ) -> ClientResult<crate::types::SigningGroupUsersData> {
+        Ok(self
+            .signing_groups_delete_group_users_with_response(account_id, signing_group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes  one or more members from a signing group.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users` endpoint.
+     *
+     * As opposed to `signing_groups_delete_group_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn signing_groups_delete_group_users_with_response(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupUsersData>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups/{}/users",
@@ -115,7 +168,7 @@ impl SigningGroupUsers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -30,6 +30,24 @@ impl SigningGroups {
         group_type: &str,
         include_users: &str,
     ) -> ClientResult<crate::types::SigningGroupInformation> {
+        Ok(self
+            .get_with_response(account_id, group_type, include_users)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of the Signing Groups in an account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signing_groups` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        group_type: &str,
+        include_users: &str,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !group_type.is_empty() {
             query_args.push(("group_type".to_string(), group_type.to_string()));
@@ -47,7 +65,7 @@ impl SigningGroups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,6 +90,20 @@ impl SigningGroups {
         account_id: &str,
         body: &crate::types::SigningGroupInformation,
     ) -> ClientResult<crate::types::SigningGroupInformation> {
+        Ok(self.put_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Updates signing group names.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signing_groups` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups",
@@ -80,7 +112,7 @@ impl SigningGroups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -111,6 +143,20 @@ impl SigningGroups {
         account_id: &str,
         body: &crate::types::SigningGroupInformation,
     ) -> ClientResult<crate::types::SigningGroupInformation> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Creates a signing group. .
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/signing_groups` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups",
@@ -119,7 +165,7 @@ impl SigningGroups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -144,6 +190,20 @@ impl SigningGroups {
         account_id: &str,
         body: &crate::types::SigningGroupInformation,
     ) -> ClientResult<crate::types::SigningGroupInformation> {
+        Ok(self.delete_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Deletes one or more signing groups.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/signing_groups` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups",
@@ -152,7 +212,7 @@ impl SigningGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -180,6 +240,23 @@ impl SigningGroups {
         account_id: &str,
         signing_group_id: &str,
     ) -> ClientResult<crate::types::SigningGroup> {
+        Ok(self
+            .get_group_with_response(account_id, signing_group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets information about a signing group. .
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}` endpoint.
+     *
+     * As opposed to `get_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_group_with_response(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroup>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups/{}",
@@ -189,7 +266,7 @@ impl SigningGroups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -218,6 +295,24 @@ impl SigningGroups {
         signing_group_id: &str,
         body: &crate::types::SigningGroup,
     ) -> ClientResult<crate::types::SigningGroup> {
+        Ok(self
+            .put_group_with_response(account_id, signing_group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a signing group. .
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}` endpoint.
+     *
+     * As opposed to `put_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_group_with_response(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroup,
+    ) -> ClientResult<crate::Response<crate::types::SigningGroup>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/signing_groups/{}",
@@ -227,7 +322,7 @@ impl SigningGroups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
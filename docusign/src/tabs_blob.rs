@@ -24,6 +24,21 @@ impl TabsBlob {
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn get(&self, account_id: &str, envelope_id: &str) -> ClientResult<()> {
+        self.get_with_response(account_id, envelope_id).await?;
+        Ok(())
+    }
+    /**
+     * Gets encrypted tabs for envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
@@ -33,7 +48,7 @@ impl TabsBlob {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,6 +70,21 @@ impl TabsBlob {
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn put(&self, account_id: &str, envelope_id: &str) -> ClientResult<()> {
+        self.put_with_response(account_id, envelope_id).await?;
+        Ok(())
+    }
+    /**
+     * Updates encrypted tabs for envelope.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
@@ -64,7 +94,7 @@ impl TabsBlob {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -34,6 +34,32 @@ impl TemplateBulkRecipients {
         include_tabs: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::BulkRecipientsResponse> {
+        Ok(self
+            .recipients_get_template_bulk_with_response(
+                account_id,
+                recipient_id,
+                template_id,
+                include_tabs,
+                start_position,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the bulk recipient file from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients` endpoint.
+     *
+     * As opposed to `recipients_get_template_bulk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_template_bulk_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkRecipientsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_tabs.is_empty() {
             query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
@@ -53,7 +79,7 @@ impl TemplateBulkRecipients {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -84,6 +110,25 @@ impl TemplateBulkRecipients {
         template_id: &str,
         body: &crate::types::BulkRecipientsRequest,
     ) -> ClientResult<crate::types::BulkRecipientsSummaryResponse> {
+        Ok(self
+            .recipients_put_template_bulk_with_response(account_id, recipient_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds or replaces the bulk recipients list in a template.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients` endpoint.
+     *
+     * As opposed to `recipients_put_template_bulk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_template_bulk_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::BulkRecipientsRequest,
+    ) -> ClientResult<crate::Response<crate::types::BulkRecipientsSummaryResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
@@ -94,7 +139,7 @@ impl TemplateBulkRecipients {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -122,6 +167,28 @@ impl TemplateBulkRecipients {
         recipient_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::BulkRecipientsUpdateResponse> {
+        Ok(self
+            .recipients_delete_template_bulk_file_with_response(
+                account_id,
+                recipient_id,
+                template_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the bulk recipient list on a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients` endpoint.
+     *
+     * As opposed to `recipients_delete_template_bulk_file`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_delete_template_bulk_file_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BulkRecipientsUpdateResponse>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
@@ -132,7 +199,7 @@ impl TemplateBulkRecipients {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,23 @@ impl TemplateCustomFields {
         account_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::CustomFields> {
+        Ok(self
+            .custom_fields_get_template_with_response(account_id, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the custom document fields from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_get_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/custom_fields",
@@ -37,7 +54,7 @@ impl TemplateCustomFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,6 +83,24 @@ impl TemplateCustomFields {
         template_id: &str,
         body: &crate::types::TemplateCustomFieldsData,
     ) -> ClientResult<crate::types::CustomFields> {
+        Ok(self
+            .custom_fields_put_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates envelope custom fields in a template.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_put_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_put_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/custom_fields",
@@ -75,7 +110,7 @@ impl TemplateCustomFields {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -102,6 +137,24 @@ impl TemplateCustomFields {
         template_id: &str,
         body: &crate::types::TemplateCustomFieldsData,
     ) -> ClientResult<crate::types::CustomFields> {
+        Ok(self
+            .custom_fields_post_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates custom document fields in an existing template document.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_post_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_post_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/custom_fields",
@@ -111,7 +164,7 @@ impl TemplateCustomFields {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -138,6 +191,24 @@ impl TemplateCustomFields {
         template_id: &str,
         body: &crate::types::TemplateCustomFieldsData,
     ) -> ClientResult<crate::types::CustomFields> {
+        Ok(self
+            .custom_fields_delete_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes envelope custom fields in a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields` endpoint.
+     *
+     * As opposed to `custom_fields_delete_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn custom_fields_delete_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> ClientResult<crate::Response<crate::types::CustomFields>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/custom_fields",
@@ -147,7 +218,7 @@ impl TemplateCustomFields {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
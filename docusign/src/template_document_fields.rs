@@ -30,6 +30,24 @@ impl TemplateDocumentFields {
         document_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::DocumentFieldsInformation> {
+        Ok(self
+            .document_fields_get_template_with_response(account_id, document_id, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the custom document fields for a an existing template document.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_get_template_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
@@ -40,7 +58,7 @@ impl TemplateDocumentFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +87,25 @@ impl TemplateDocumentFields {
         template_id: &str,
         body: &crate::types::DocumentFieldsInformation,
     ) -> ClientResult<crate::types::DocumentFieldsInformation> {
+        Ok(self
+            .document_fields_put_template_with_response(account_id, document_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates existing custom document fields in an existing template document.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_put_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_put_template_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
@@ -79,7 +116,7 @@ impl TemplateDocumentFields {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -108,6 +145,25 @@ impl TemplateDocumentFields {
         template_id: &str,
         body: &crate::types::DocumentFieldsInformation,
     ) -> ClientResult<crate::types::DocumentFieldsInformation> {
+        Ok(self
+            .document_fields_post_template_with_response(account_id, document_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates custom document fields in an existing template document.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_post_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_post_template_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
@@ -118,7 +174,7 @@ impl TemplateDocumentFields {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -147,6 +203,30 @@ impl TemplateDocumentFields {
         template_id: &str,
         body: &crate::types::DocumentFieldsInformation,
     ) -> ClientResult<crate::types::DocumentFieldsInformation> {
+        Ok(self
+            .document_fields_delete_template_with_response(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Deletes custom document fields from an existing template document.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields` endpoint.
+     *
+     * As opposed to `document_fields_delete_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn document_fields_delete_template_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> ClientResult<crate::Response<crate::types::DocumentFieldsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
@@ -157,7 +237,7 @@ impl TemplateDocumentFields {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -30,6 +30,28 @@ impl TemplateDocumentHtmlDefinitions {
         document_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::DocumentHtmlDefinitionOriginals> {
+        Ok(self
+            .responsive_html_get_template_document_definition_with_response(
+                account_id,
+                document_id,
+                template_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the Original HTML Definition used to generate the Responsive HTML for a given document in a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/html_definitions` endpoint.
+     *
+     * As opposed to `responsive_html_get_template_document_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn responsive_html_get_template_document_definition_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitionOriginals>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/html_definitions",
@@ -40,7 +62,7 @@ impl TemplateDocumentHtmlDefinitions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -41,6 +41,30 @@ impl TemplateDocumentResponsiveHtmlPreview {
         template_id: &str,
         body: &crate::types::DocumentHtmlDefinition,
     ) -> ClientResult<crate::types::DocumentHtmlDefinitions> {
+        Ok(self
+            .responsive_html_post_template_document_preview_with_response(
+                account_id,
+                document_id,
+                template_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Creates a preview of the responsive version of a template document.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/responsive_html_preview` endpoint.
+     *
+     * As opposed to `responsive_html_post_template_document_preview`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn responsive_html_post_template_document_preview_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/responsive_html_preview",
@@ -51,7 +75,7 @@ impl TemplateDocumentResponsiveHtmlPreview {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
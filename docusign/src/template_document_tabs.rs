@@ -32,6 +32,25 @@ impl TemplateDocumentTabs {
         page_number: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::TemplateDocumentTabs> {
+        Ok(self
+            .tabs_get_template_page_with_response(account_id, document_id, page_number, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Returns tabs on the specified page.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/tabs` endpoint.
+     *
+     * As opposed to `tabs_get_template_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_get_template_page_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentTabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/tabs",
@@ -43,7 +62,7 @@ impl TemplateDocumentTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -75,6 +94,30 @@ impl TemplateDocumentTabs {
         template_id: &str,
         page_numbers: &str,
     ) -> ClientResult<crate::types::TemplateDocumentTabs> {
+        Ok(self
+            .tabs_get_template_document_with_response(
+                account_id,
+                document_id,
+                template_id,
+                page_numbers,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns tabs on the document.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_get_template_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_get_template_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        page_numbers: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentTabs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_numbers.is_empty() {
             query_args.push(("page_numbers".to_string(), page_numbers.to_string()));
@@ -91,7 +134,7 @@ impl TemplateDocumentTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -120,6 +163,25 @@ impl TemplateDocumentTabs {
         template_id: &str,
         body: &crate::types::TemplateTabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .tabs_put_template_document_with_response(account_id, document_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates the tabs for a template.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_put_template_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_put_template_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
@@ -130,7 +192,7 @@ impl TemplateDocumentTabs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -159,6 +221,25 @@ impl TemplateDocumentTabs {
         template_id: &str,
         body: &crate::types::TemplateTabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .tabs_post_template_document_with_response(account_id, document_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create Template Document Tabs.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_post_template_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_post_template_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
@@ -169,7 +250,7 @@ impl TemplateDocumentTabs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -198,6 +279,25 @@ impl TemplateDocumentTabs {
         template_id: &str,
         body: &crate::types::TemplateTabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .tabs_delete_template_document_with_response(account_id, document_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes tabs from an envelope document.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs` endpoint.
+     *
+     * As opposed to `tabs_delete_template_document`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn tabs_delete_template_document_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
@@ -208,7 +308,7 @@ impl TemplateDocumentTabs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -32,6 +32,24 @@ impl TemplateDocumentVisibility {
         template_id: &str,
         body: &crate::types::TemplateDocumentVisibilityList,
     ) -> ClientResult<crate::types::TemplateDocumentVisibilityList> {
+        Ok(self
+            .recipients_put_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates document visibility for template recipients.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/document_visibility` endpoint.
+     *
+     * As opposed to `recipients_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentVisibilityList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/document_visibility",
@@ -41,7 +59,7 @@ impl TemplateDocumentVisibility {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -69,6 +87,28 @@ impl TemplateDocumentVisibility {
         recipient_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::DocumentVisibilityList> {
+        Ok(self
+            .recipients_get_template_recipient_document_visibility_with_response(
+                account_id,
+                recipient_id,
+                template_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns document visibility for a template recipient.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility` endpoint.
+     *
+     * As opposed to `recipients_get_template_recipient_document_visibility`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_template_recipient_document_visibility_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DocumentVisibilityList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
@@ -79,7 +119,7 @@ impl TemplateDocumentVisibility {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -110,6 +150,30 @@ impl TemplateDocumentVisibility {
         template_id: &str,
         body: &crate::types::TemplateDocumentVisibilityList,
     ) -> ClientResult<crate::types::TemplateDocumentVisibilityList> {
+        Ok(self
+            .recipients_put_template_recipient_document_visibility_with_response(
+                account_id,
+                recipient_id,
+                template_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates document visibility for a template recipient.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility` endpoint.
+     *
+     * As opposed to `recipients_put_template_recipient_document_visibility`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_template_recipient_document_visibility_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentVisibilityList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
@@ -120,7 +184,7 @@ impl TemplateDocumentVisibility {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
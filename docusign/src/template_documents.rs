@@ -30,6 +30,24 @@ impl TemplateDocuments {
         template_id: &str,
         include_tabs: &str,
     ) -> ClientResult<crate::types::TemplateDocumentsResult> {
+        Ok(self
+            .documents_get_template_with_response(account_id, template_id, include_tabs)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a list of documents associated with a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents` endpoint.
+     *
+     * As opposed to `documents_get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_get_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_tabs.is_empty() {
             query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
@@ -45,7 +63,7 @@ impl TemplateDocuments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,6 +90,24 @@ impl TemplateDocuments {
         template_id: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> ClientResult<crate::types::TemplateDocumentsResult> {
+        Ok(self
+            .documents_put_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds documents to a template document.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents` endpoint.
+     *
+     * As opposed to `documents_put_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_put_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents",
@@ -81,7 +117,7 @@ impl TemplateDocuments {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -122,6 +158,24 @@ impl TemplateDocuments {
         template_id: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> ClientResult<crate::types::TemplateDocumentsResult> {
+        Ok(self
+            .documents_delete_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes documents from a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents` endpoint.
+     *
+     * As opposed to `documents_delete_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_delete_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDocumentsResult>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents",
@@ -131,7 +185,7 @@ impl TemplateDocuments {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -165,6 +219,31 @@ impl TemplateDocuments {
         encrypt: &str,
         show_changes: &str,
     ) -> ClientResult<()> {
+        self.documents_get_with_response(
+            account_id,
+            document_id,
+            template_id,
+            encrypt,
+            show_changes,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Gets PDF documents from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_get_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encrypt.is_empty() {
             query_args.push(("encrypt".to_string(), encrypt.to_string()));
@@ -184,7 +263,7 @@ impl TemplateDocuments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +294,32 @@ impl TemplateDocuments {
         is_envelope_definition: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> ClientResult<crate::types::EnvelopeDocument> {
+        Ok(self
+            .documents_put_with_response(
+                account_id,
+                document_id,
+                template_id,
+                is_envelope_definition,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates a template document.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn documents_put_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        is_envelope_definition: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeDocument>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !is_envelope_definition.is_empty() {
             query_args.push((
@@ -234,7 +339,7 @@ impl TemplateDocuments {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
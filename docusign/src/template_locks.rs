@@ -41,6 +41,23 @@ impl TemplateLocks {
         account_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::LockInformation> {
+        Ok(self
+            .lock_get_template_with_response(account_id, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets template lock information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/lock` endpoint.
+     *
+     * As opposed to `lock_get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_get_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/lock",
@@ -50,7 +67,7 @@ impl TemplateLocks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -99,6 +116,24 @@ impl TemplateLocks {
         template_id: &str,
         body: &crate::types::LockRequest,
     ) -> ClientResult<crate::types::LockInformation> {
+        Ok(self
+            .lock_put_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a template lock.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/lock` endpoint.
+     *
+     * As opposed to `lock_put_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_put_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/lock",
@@ -108,7 +143,7 @@ impl TemplateLocks {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -191,6 +226,24 @@ impl TemplateLocks {
         template_id: &str,
         body: &crate::types::LockRequest,
     ) -> ClientResult<crate::types::LockInformation> {
+        Ok(self
+            .lock_post_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Locks a template.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/lock` endpoint.
+     *
+     * As opposed to `lock_post_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_post_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/lock",
@@ -200,7 +253,7 @@ impl TemplateLocks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -244,6 +297,24 @@ impl TemplateLocks {
         template_id: &str,
         body: &crate::types::LockRequest,
     ) -> ClientResult<crate::types::LockInformation> {
+        Ok(self
+            .lock_delete_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes a template lock.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/lock` endpoint.
+     *
+     * As opposed to `lock_delete_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn lock_delete_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> ClientResult<crate::Response<crate::types::LockInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/lock",
@@ -253,7 +324,7 @@ impl TemplateLocks {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -34,6 +34,32 @@ impl TemplateRecipientTabs {
         include_anchor_tab_locations: &str,
         include_metadata: &str,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .recipients_get_with_response(
+                account_id,
+                recipient_id,
+                template_id,
+                include_anchor_tab_locations,
+                include_metadata,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets the tabs information for a signer or sign-in-person recipient in a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
@@ -56,7 +82,7 @@ impl TemplateRecipientTabs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -85,6 +111,25 @@ impl TemplateRecipientTabs {
         template_id: &str,
         body: &crate::types::TemplateTabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .recipients_put_with_response(account_id, recipient_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates the tabs for a recipient.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
@@ -95,7 +140,7 @@ impl TemplateRecipientTabs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -124,6 +169,25 @@ impl TemplateRecipientTabs {
         template_id: &str,
         body: &crate::types::TemplateTabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .recipients_post_with_response(account_id, recipient_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds tabs for a recipient.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_post_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
@@ -134,7 +198,7 @@ impl TemplateRecipientTabs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -163,6 +227,25 @@ impl TemplateRecipientTabs {
         template_id: &str,
         body: &crate::types::TemplateTabs,
     ) -> ClientResult<crate::types::Tabs> {
+        Ok(self
+            .recipients_delete_with_response(account_id, recipient_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the tabs associated with a recipient in a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs` endpoint.
+     *
+     * As opposed to `recipients_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_delete_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> ClientResult<crate::Response<crate::types::Tabs>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
@@ -173,7 +256,7 @@ impl TemplateRecipientTabs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
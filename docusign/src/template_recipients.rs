@@ -34,6 +34,32 @@ impl TemplateRecipients {
         include_extended: &str,
         include_tabs: &str,
     ) -> ClientResult<crate::types::Recipients> {
+        Ok(self
+            .recipients_get_template_with_response(
+                account_id,
+                template_id,
+                include_anchor_tab_locations,
+                include_extended,
+                include_tabs,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets recipient information from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_get_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_tabs: &str,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
@@ -58,7 +84,7 @@ impl TemplateRecipients {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -91,6 +117,25 @@ impl TemplateRecipients {
         resend_envelope: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> ClientResult<crate::types::RecipientsUpdateSummary> {
+        Ok(self
+            .recipients_put_template_with_response(account_id, template_id, resend_envelope, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates recipients in a template.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_put_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_put_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::RecipientsUpdateSummary>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !resend_envelope.is_empty() {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
@@ -106,7 +151,7 @@ impl TemplateRecipients {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -137,6 +182,25 @@ impl TemplateRecipients {
         resend_envelope: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> ClientResult<crate::types::Recipients> {
+        Ok(self
+            .recipients_post_template_with_response(account_id, template_id, resend_envelope, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds tabs for a recipient.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_post_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_post_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !resend_envelope.is_empty() {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
@@ -152,7 +216,7 @@ impl TemplateRecipients {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -179,6 +243,24 @@ impl TemplateRecipients {
         template_id: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> ClientResult<crate::types::Recipients> {
+        Ok(self
+            .recipients_delete_template_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes recipients from a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients` endpoint.
+     *
+     * As opposed to `recipients_delete_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_delete_template_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients",
@@ -188,7 +270,7 @@ impl TemplateRecipients {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -217,6 +299,25 @@ impl TemplateRecipients {
         template_id: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> ClientResult<crate::types::Recipients> {
+        Ok(self
+            .recipients_delete_with_response(account_id, recipient_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the specified recipient file from a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}` endpoint.
+     *
+     * As opposed to `recipients_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recipients_delete_with_response(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> ClientResult<crate::Response<crate::types::Recipients>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/recipients/{}",
@@ -227,7 +328,7 @@ impl TemplateRecipients {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -256,6 +357,24 @@ impl TemplateRecipients {
         template_id: &str,
         body: &crate::types::RecipientPreviewRequest,
     ) -> ClientResult<crate::types::ViewUrl> {
+        Ok(self
+            .views_post_preview_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a template recipient preview.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/views/recipient_preview` endpoint.
+     *
+     * As opposed to `views_post_preview`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_preview_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/views/recipient_preview",
@@ -265,7 +384,7 @@ impl TemplateRecipients {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -39,6 +39,24 @@ impl TemplateResponsiveHtmlPreview {
         template_id: &str,
         body: &crate::types::DocumentHtmlDefinition,
     ) -> ClientResult<crate::types::DocumentHtmlDefinitions> {
+        Ok(self
+            .responsive_html_post_template_preview_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a preview of the responsive versions of all of the documents associated with a template.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/responsive_html_preview` endpoint.
+     *
+     * As opposed to `responsive_html_post_template_preview`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn responsive_html_post_template_preview_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> ClientResult<crate::Response<crate::types::DocumentHtmlDefinitions>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/responsive_html_preview",
@@ -48,7 +66,7 @@ impl TemplateResponsiveHtmlPreview {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
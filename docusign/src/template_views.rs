@@ -29,6 +29,24 @@ impl TemplateViews {
         template_id: &str,
         body: &crate::types::ReturnUrlRequest,
     ) -> ClientResult<crate::types::ViewUrl> {
+        Ok(self
+            .views_post_template_edit_view_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a URL for a template edit view.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates/{templateId}/views/edit` endpoint.
+     *
+     * As opposed to `views_post_template_edit_view`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn views_post_template_edit_view_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> ClientResult<crate::Response<crate::types::ViewUrl>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/views/edit",
@@ -38,7 +56,7 @@ impl TemplateViews {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -117,6 +117,70 @@ impl Templates {
         user_filter: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::EnvelopeTemplateResults> {
+        Ok(self
+            .get_with_response(
+                account_id,
+                count,
+                created_from_date,
+                created_to_date,
+                folder_ids,
+                folder_types,
+                from_date,
+                include,
+                is_deleted_template_only,
+                is_download,
+                modified_from_date,
+                modified_to_date,
+                order,
+                order_by,
+                search_fields,
+                search_text,
+                shared_by_me,
+                start_position,
+                template_ids,
+                to_date,
+                used_from_date,
+                used_to_date,
+                user_filter,
+                user_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Gets template definitions.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        count: &str,
+        created_from_date: &str,
+        created_to_date: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        include: &str,
+        is_deleted_template_only: &str,
+        is_download: &str,
+        modified_from_date: &str,
+        modified_to_date: &str,
+        order: &str,
+        order_by: &str,
+        search_fields: &str,
+        search_text: &str,
+        shared_by_me: &str,
+        start_position: &str,
+        template_ids: &str,
+        to_date: &str,
+        used_from_date: &str,
+        used_to_date: &str,
+        user_filter: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTemplateResults>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -206,7 +270,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -281,6 +345,20 @@ impl Templates {
         account_id: &str,
         body: &crate::types::EnvelopeTemplate,
     ) -> ClientResult<crate::types::TemplateSummary> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Creates one or more templates.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/templates` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> ClientResult<crate::Response<crate::types::TemplateSummary>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates",
@@ -289,7 +367,7 @@ impl Templates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -325,6 +403,24 @@ impl Templates {
         template_id: &str,
         include: &str,
     ) -> ClientResult<crate::types::EnvelopeTemplate> {
+        Ok(self
+            .get_templates_with_response(account_id, template_id, include)
+            .await?
+            .body)
+    }
+    /**
+     * Gets a specific template associated with a specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}` endpoint.
+     *
+     * As opposed to `get_templates`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_templates_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvelopeTemplate>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -340,7 +436,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -367,6 +463,24 @@ impl Templates {
         template_id: &str,
         body: &crate::types::EnvelopeTemplate,
     ) -> ClientResult<crate::types::TemplateUpdateSummary> {
+        Ok(self
+            .put_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an existing template.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> ClientResult<crate::Response<crate::types::TemplateUpdateSummary>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}",
@@ -376,7 +490,7 @@ impl Templates {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -418,6 +532,42 @@ impl Templates {
         show_changes: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::PageImages> {
+        Ok(self
+            .pages_get_page_image_with_response(
+                account_id,
+                document_id,
+                template_id,
+                count,
+                dpi,
+                max_height,
+                max_width,
+                nocache,
+                show_changes,
+                start_position,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Returns document page image(s) based on input.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages` endpoint.
+     *
+     * As opposed to `pages_get_page_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_get_page_image_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageImages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -452,7 +602,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -483,6 +633,31 @@ impl Templates {
         template_id: &str,
         body: &crate::types::PageRequest,
     ) -> ClientResult<()> {
+        self.pages_delete_page_with_response(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a page from a document in an template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}` endpoint.
+     *
+     * As opposed to `pages_delete_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_delete_page_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}",
@@ -494,7 +669,7 @@ impl Templates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -532,6 +707,37 @@ impl Templates {
         max_width: &str,
         show_changes: &str,
     ) -> ClientResult<()> {
+        self.pages_get_page_image_templates_with_response(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            dpi,
+            max_height,
+            max_width,
+            show_changes,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Gets a page image from a template for display.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_get_page_image_templates`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_get_page_image_templates_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !dpi.is_empty() {
             query_args.push(("dpi".to_string(), dpi.to_string()));
@@ -558,7 +764,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -589,6 +795,31 @@ impl Templates {
         template_id: &str,
         body: &crate::types::PageRequest,
     ) -> ClientResult<()> {
+        self.pages_put_page_image_with_response(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Rotates page image from a template for display.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_put_page_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn pages_put_page_image_with_response(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
@@ -600,7 +831,7 @@ impl Templates {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -626,6 +857,23 @@ impl Templates {
         account_id: &str,
         template_id: &str,
     ) -> ClientResult<crate::types::Notification> {
+        Ok(self
+            .notification_get_with_response(account_id, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets template notification information.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/notification` endpoint.
+     *
+     * As opposed to `notification_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn notification_get_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/notification",
@@ -635,7 +883,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -662,6 +910,24 @@ impl Templates {
         template_id: &str,
         body: &crate::types::TemplateNotificationRequest,
     ) -> ClientResult<crate::types::Notification> {
+        Ok(self
+            .notification_put_with_response(account_id, template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates the notification  structure for an existing template.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/notification` endpoint.
+     *
+     * As opposed to `notification_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn notification_put_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateNotificationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Notification>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/notification",
@@ -671,7 +937,7 @@ impl Templates {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -702,6 +968,25 @@ impl Templates {
         template_part: &str,
         body: &crate::types::GroupInformation,
     ) -> ClientResult<crate::types::GroupInformation> {
+        Ok(self
+            .put_part_with_response(account_id, template_id, template_part, body)
+            .await?
+            .body)
+    }
+    /**
+     * Shares a template with a group.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}` endpoint.
+     *
+     * As opposed to `put_part`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_part_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/{}",
@@ -712,7 +997,7 @@ impl Templates {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -741,6 +1026,25 @@ impl Templates {
         template_part: &str,
         body: &crate::types::GroupInformation,
     ) -> ClientResult<crate::types::GroupInformation> {
+        Ok(self
+            .delete_part_with_response(account_id, template_id, template_part, body)
+            .await?
+            .body)
+    }
+    /**
+     * Removes a member group's sharing permissions for a template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}` endpoint.
+     *
+     * As opposed to `delete_part`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_part_with_response(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> ClientResult<crate::Response<crate::types::GroupInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/templates/{}/{}",
@@ -751,7 +1055,7 @@ impl Templates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
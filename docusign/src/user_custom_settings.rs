@@ -43,6 +43,20 @@ impl UserCustomSettings {
         account_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::CustomSettingsInformation> {
+        Ok(self.get_with_response(account_id, user_id).await?.body)
+    }
+    /**
+     * Retrieves the custom user settings for a specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/custom_settings` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomSettingsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/custom_settings",
@@ -52,7 +66,7 @@ impl UserCustomSettings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -100,6 +114,24 @@ impl UserCustomSettings {
         user_id: &str,
         body: &crate::types::CustomSettingsInformation,
     ) -> ClientResult<crate::types::CustomSettingsInformation> {
+        Ok(self
+            .put_with_response(account_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds or updates custom user settings for the specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/custom_settings` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> ClientResult<crate::Response<crate::types::CustomSettingsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/custom_settings",
@@ -109,7 +141,7 @@ impl UserCustomSettings {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -147,6 +179,24 @@ impl UserCustomSettings {
         user_id: &str,
         body: &crate::types::CustomSettingsInformation,
     ) -> ClientResult<crate::types::CustomSettingsInformation> {
+        Ok(self
+            .delete_with_response(account_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes custom user settings for a specified user.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/custom_settings` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> ClientResult<crate::Response<crate::types::CustomSettingsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/custom_settings",
@@ -156,7 +206,7 @@ impl UserCustomSettings {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
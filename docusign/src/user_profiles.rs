@@ -31,6 +31,20 @@ impl UserProfiles {
         account_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::UserProfile> {
+        Ok(self.get_with_response(account_id, user_id).await?.body)
+    }
+    /**
+     * Retrieves the user profile for a specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/profile` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserProfile>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/profile",
@@ -40,7 +54,7 @@ impl UserProfiles {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,6 +84,22 @@ impl UserProfiles {
         user_id: &str,
         body: &crate::types::UserProfile,
     ) -> ClientResult<()> {
+        self.put_with_response(account_id, user_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Updates the user profile information for the specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/profile` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserProfile,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/profile",
@@ -79,7 +109,7 @@ impl UserProfiles {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -41,6 +41,24 @@ impl UserSignatures {
         user_id: &str,
         stamp_type: &str,
     ) -> ClientResult<crate::types::UserSignaturesInformation> {
+        Ok(self
+            .get_with_response(account_id, user_id, stamp_type)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of signature definitions for a user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        stamp_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !stamp_type.is_empty() {
             query_args.push(("stamp_type".to_string(), stamp_type.to_string()));
@@ -56,7 +74,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -84,6 +102,24 @@ impl UserSignatures {
         user_id: &str,
         body: &crate::types::UserSignaturesInformation,
     ) -> ClientResult<crate::types::UserSignaturesInformation> {
+        Ok(self
+            .put_signature_with_response(account_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds/updates a user signature.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures` endpoint.
+     *
+     * As opposed to `put_signature`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_signature_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/signatures",
@@ -93,7 +129,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -139,6 +175,24 @@ impl UserSignatures {
         user_id: &str,
         body: &crate::types::UserSignaturesInformation,
     ) -> ClientResult<crate::types::UserSignaturesInformation> {
+        Ok(self
+            .post_with_response(account_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Adds user Signature and initials images to a Signature.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> ClientResult<crate::Response<crate::types::UserSignaturesInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/signatures",
@@ -148,7 +202,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -183,6 +237,24 @@ impl UserSignatures {
         signature_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::UserSignature> {
+        Ok(self
+            .get_signature_with_response(account_id, signature_id, user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the user signature information for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `get_signature`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_signature_with_response(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/signatures/{}",
@@ -193,7 +265,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -233,6 +305,32 @@ impl UserSignatures {
         close_existing_signature: &str,
         body: &crate::types::UserSignatureDefinition,
     ) -> ClientResult<crate::types::UserSignature> {
+        Ok(self
+            .put_signature_user_signatures_with_response(
+                account_id,
+                signature_id,
+                user_id,
+                close_existing_signature,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates the user signature for a specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `put_signature_user_signatures`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_signature_user_signatures_with_response(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !close_existing_signature.is_empty() {
             query_args.push((
@@ -252,7 +350,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -287,6 +385,23 @@ impl UserSignatures {
         signature_id: &str,
         user_id: &str,
     ) -> ClientResult<()> {
+        self.delete_signature_with_response(account_id, signature_id, user_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Removes removes signature information for the specified user.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `delete_signature`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_signature_with_response(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/signatures/{}",
@@ -297,7 +412,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -341,6 +456,31 @@ impl UserSignatures {
         user_id: &str,
         include_chrome: &str,
     ) -> ClientResult<()> {
+        self.get_signature_image_with_response(
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            include_chrome,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves the user initials image or the  user signature image for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `get_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_signature_image_with_response(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        include_chrome: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_chrome.is_empty() {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
@@ -358,7 +498,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -401,6 +541,32 @@ impl UserSignatures {
         user_id: &str,
         transparent_png: &str,
     ) -> ClientResult<crate::types::UserSignature> {
+        Ok(self
+            .put_signature_image_with_response(
+                account_id,
+                image_type,
+                signature_id,
+                user_id,
+                transparent_png,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Updates the user signature image or user initials image for the specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `put_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_signature_image_with_response(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        transparent_png: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !transparent_png.is_empty() {
             query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
@@ -418,7 +584,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -460,6 +626,25 @@ impl UserSignatures {
         signature_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::UserSignature> {
+        Ok(self
+            .delete_signature_image_with_response(account_id, image_type, signature_id, user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Deletes the user initials image or the  user signature image for the specified user.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `delete_signature_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_signature_image_with_response(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSignature>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
@@ -471,7 +656,7 @@ impl UserSignatures {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
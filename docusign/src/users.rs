@@ -63,6 +63,46 @@ impl Users {
         status: &str,
         user_name_substring: &str,
     ) -> ClientResult<crate::types::UserInformationList> {
+        Ok(self
+            .get_with_response(
+                account_id,
+                additional_info,
+                count,
+                email,
+                email_substring,
+                group_id,
+                include_usersettings_for_csv,
+                login_status,
+                not_group_id,
+                start_position,
+                status,
+                user_name_substring,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves the list of users for the specified account.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+        additional_info: &str,
+        count: &str,
+        email: &str,
+        email_substring: &str,
+        group_id: &str,
+        include_usersettings_for_csv: &str,
+        login_status: &str,
+        not_group_id: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserInformationList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !additional_info.is_empty() {
             query_args.push(("additional_info".to_string(), additional_info.to_string()));
@@ -113,7 +153,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -138,6 +178,20 @@ impl Users {
         account_id: &str,
         body: &crate::types::UserInformationList,
     ) -> ClientResult<crate::types::UserInformationList> {
+        Ok(self.put_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Changes one or more users in the specified account.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::UserInformationList,
+    ) -> ClientResult<crate::Response<crate::types::UserInformationList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users",
@@ -146,7 +200,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -229,6 +283,20 @@ impl Users {
         account_id: &str,
         body: &crate::types::NewUsersDefinition,
     ) -> ClientResult<crate::types::NewUsersSummary> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Adds new users to the specified account.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/users` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::NewUsersDefinition,
+    ) -> ClientResult<crate::Response<crate::types::NewUsersSummary>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users",
@@ -237,7 +305,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -266,6 +334,24 @@ impl Users {
         delete: &str,
         body: &crate::types::UserInfoList,
     ) -> ClientResult<crate::types::UsersResponse> {
+        Ok(self
+            .delete_with_response(account_id, delete, body)
+            .await?
+            .body)
+    }
+    /**
+     * Removes users account privileges.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        delete: &str,
+        body: &crate::types::UserInfoList,
+    ) -> ClientResult<crate::Response<crate::types::UsersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !delete.is_empty() {
             query_args.push(("delete".to_string(), delete.to_string()));
@@ -280,7 +366,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -313,6 +399,25 @@ impl Users {
         additional_info: &str,
         email: &str,
     ) -> ClientResult<crate::types::UserInformation> {
+        Ok(self
+            .get_users_with_response(account_id, user_id, additional_info, email)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the user information for a specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}` endpoint.
+     *
+     * As opposed to `get_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_users_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        additional_info: &str,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserInformation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !additional_info.is_empty() {
             query_args.push(("additional_info".to_string(), additional_info.to_string()));
@@ -331,7 +436,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -359,6 +464,24 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserInformation,
     ) -> ClientResult<crate::types::UserInformation> {
+        Ok(self
+            .put_users_with_response(account_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates user information for the specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}` endpoint.
+     *
+     * As opposed to `put_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_users_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserInformation,
+    ) -> ClientResult<crate::Response<crate::types::UserInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}",
@@ -368,7 +491,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -401,6 +524,23 @@ impl Users {
         user_id: &str,
         encoding: &str,
     ) -> ClientResult<()> {
+        self.profile_image_get_with_response(account_id, user_id, encoding)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves the user profile image for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/profile/image` endpoint.
+     *
+     * As opposed to `profile_image_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn profile_image_get_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        encoding: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !encoding.is_empty() {
             query_args.push(("encoding".to_string(), encoding.to_string()));
@@ -416,7 +556,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -441,6 +581,22 @@ impl Users {
      *   .
      */
     pub async fn profile_image_put(&self, account_id: &str, user_id: &str) -> ClientResult<()> {
+        self.profile_image_put_with_response(account_id, user_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Updates the user profile image for a specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/profile/image` endpoint.
+     *
+     * As opposed to `profile_image_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn profile_image_put_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/profile/image",
@@ -450,7 +606,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -475,6 +631,22 @@ impl Users {
      *   .
      */
     pub async fn profile_image_delete(&self, account_id: &str, user_id: &str) -> ClientResult<()> {
+        self.profile_image_delete_with_response(account_id, user_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes the user profile image for the specified user.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/profile/image` endpoint.
+     *
+     * As opposed to `profile_image_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn profile_image_delete_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/profile/image",
@@ -484,7 +656,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -521,6 +693,23 @@ impl Users {
         account_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::UserSettingsInformation> {
+        Ok(self
+            .settings_get_with_response(account_id, user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Gets the user account settings for a specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `settings_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn settings_get_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSettingsInformation>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/settings",
@@ -530,7 +719,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -558,6 +747,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserSettingsInformation,
     ) -> ClientResult<()> {
+        self.settings_put_with_response(account_id, user_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Updates the user account settings for a specified user.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `settings_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn settings_put_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSettingsInformation,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/users/{}/settings",
@@ -567,7 +773,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -44,6 +44,42 @@ impl WorkspaceItems {
         start_position: &str,
         workspace_user_id: &str,
     ) -> ClientResult<crate::types::WorkspaceFolderContents> {
+        Ok(self
+            .workspace_folder_get_with_response(
+                account_id,
+                folder_id,
+                workspace_id,
+                count,
+                include_files,
+                include_sub_folders,
+                include_thumbnails,
+                include_user_detail,
+                start_position,
+                workspace_user_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List workspace folder contents.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `workspace_folder_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn workspace_folder_get_with_response(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        include_files: &str,
+        include_sub_folders: &str,
+        include_thumbnails: &str,
+        include_user_detail: &str,
+        start_position: &str,
+        workspace_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceFolderContents>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -90,7 +126,7 @@ impl WorkspaceItems {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -121,6 +157,24 @@ impl WorkspaceItems {
         workspace_id: &str,
         body: &crate::types::WorkspaceItemList,
     ) -> ClientResult<()> {
+        self.workspace_folder_delete_items_with_response(account_id, folder_id, workspace_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes files or sub-folders from a workspace.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `workspace_folder_delete_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn workspace_folder_delete_items_with_response(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces/{}/folders/{}",
@@ -131,7 +185,7 @@ impl WorkspaceItems {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -159,6 +213,24 @@ impl WorkspaceItems {
         folder_id: &str,
         workspace_id: &str,
     ) -> ClientResult<crate::types::WorkspaceItem> {
+        Ok(self
+            .workspace_file_post_files_with_response(account_id, folder_id, workspace_id)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a workspace file.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files` endpoint.
+     *
+     * As opposed to `workspace_file_post_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn workspace_file_post_files_with_response(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceItem>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces/{}/folders/{}/files",
@@ -169,7 +241,7 @@ impl WorkspaceItems {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -203,6 +275,33 @@ impl WorkspaceItems {
         is_download: &str,
         pdf_version: &str,
     ) -> ClientResult<()> {
+        self.workspace_file_get_with_response(
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            is_download,
+            pdf_version,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Gets a workspace file.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}` endpoint.
+     *
+     * As opposed to `workspace_file_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn workspace_file_get_with_response(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        is_download: &str,
+        pdf_version: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !is_download.is_empty() {
             query_args.push(("is_download".to_string(), is_download.to_string()));
@@ -223,7 +322,7 @@ impl WorkspaceItems {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -253,6 +352,25 @@ impl WorkspaceItems {
         folder_id: &str,
         workspace_id: &str,
     ) -> ClientResult<crate::types::WorkspaceItem> {
+        Ok(self
+            .workspace_file_put_with_response(account_id, file_id, folder_id, workspace_id)
+            .await?
+            .body)
+    }
+    /**
+     * Update workspace file or folder metadata.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}` endpoint.
+     *
+     * As opposed to `workspace_file_put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn workspace_file_put_with_response(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceItem>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
@@ -264,7 +382,7 @@ impl WorkspaceItems {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -304,6 +422,40 @@ impl WorkspaceItems {
         max_width: &str,
         start_position: &str,
     ) -> ClientResult<crate::types::PageImages> {
+        Ok(self
+            .workspace_file_pages_get_with_response(
+                account_id,
+                file_id,
+                folder_id,
+                workspace_id,
+                count,
+                dpi,
+                max_height,
+                max_width,
+                start_position,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List File Pages.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}/pages` endpoint.
+     *
+     * As opposed to `workspace_file_pages_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn workspace_file_pages_get_with_response(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        start_position: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageImages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -333,7 +485,7 @@ impl WorkspaceItems {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
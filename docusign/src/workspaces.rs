@@ -23,6 +23,19 @@ impl Workspaces {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn get(&self, account_id: &str) -> ClientResult<crate::types::WorkspaceList> {
+        Ok(self.get_with_response(account_id).await?.body)
+    }
+    /**
+     * List Workspaces.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkspaceList>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces",
@@ -31,7 +44,7 @@ impl Workspaces {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,6 +69,20 @@ impl Workspaces {
         account_id: &str,
         body: &crate::types::Workspace,
     ) -> ClientResult<crate::types::Workspace> {
+        Ok(self.post_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Create a Workspace.
+     *
+     * This function performs a `POST` to the `/v2.1/accounts/{accountId}/workspaces` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::Workspace,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces",
@@ -64,7 +91,7 @@ impl Workspaces {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -90,6 +117,23 @@ impl Workspaces {
         account_id: &str,
         workspace_id: &str,
     ) -> ClientResult<crate::types::Workspace> {
+        Ok(self
+            .get_workspaces_with_response(account_id, workspace_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get Workspace.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}` endpoint.
+     *
+     * As opposed to `get_workspaces`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_workspaces_with_response(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces/{}",
@@ -99,7 +143,7 @@ impl Workspaces {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -126,6 +170,24 @@ impl Workspaces {
         workspace_id: &str,
         body: &crate::types::Workspace,
     ) -> ClientResult<crate::types::Workspace> {
+        Ok(self
+            .put_with_response(account_id, workspace_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Workspace.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+        body: &crate::types::Workspace,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces/{}",
@@ -135,7 +197,7 @@ impl Workspaces {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -161,6 +223,23 @@ impl Workspaces {
         account_id: &str,
         workspace_id: &str,
     ) -> ClientResult<crate::types::Workspace> {
+        Ok(self
+            .delete_with_response(account_id, workspace_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete Workspace.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workspace>> {
         let url = self.client.url(
             &format!(
                 "/v2.1/accounts/{}/workspaces/{}",
@@ -170,7 +249,7 @@ impl Workspaces {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -13,6 +13,9 @@ pub struct Client {
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -24,17 +27,13 @@ impl Client {
         C: Into<Option<crate::auth::Credentials>>,
     {
         let http = reqwest::Client::builder().build()?;
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         let client = reqwest_middleware::ClientBuilder::new(http)
             // Trace HTTP requests. See the tracing crate to make use of these traces.
             .with(reqwest_tracing::TracingMiddleware::default())
             // Retry failed requests.
-            .with(
-                reqwest_conditional_middleware::ConditionalMiddleware::new(
-                    reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                    |req: &reqwest::Request| req.try_clone().is_some()
-                )
-            )
+            .with(crate::utils::RetryMiddleware::default())
+            // Stay under the rate limits, see `Client::set_rate_limiter`.
+            .with(crate::utils::RateLimitMiddleware)
             .build();
 
         #[cfg(feature = "httpcache")]
@@ -69,6 +68,9 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            coercions: Default::default(),
+            retry: Default::default(),
+            limiter: None,
             http_cache,
         }
     }
@@ -85,6 +87,9 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            coercions: Default::default(),
+            retry: Default::default(),
+            limiter: None,
         }
     }
 
@@ -112,6 +117,47 @@ impl Client {
         format!("{}{}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
     }
 
+    /// Return an error with the JSON path of the offending field when a response
+    /// does not match the generated types, instead of falling back to defaults.
+    pub fn set_strict_deserialization(&mut self, enabled: bool) -> &mut Self {
+        self.coercions.strict = enabled;
+        self
+    }
+
+    /// The fields of past responses that did not match the generated types and
+    /// were replaced with their default value. The log is shared between clones
+    /// of the client and keeps the most recent 1000 fields.
+    pub fn coercions(&self) -> Vec<crate::utils::Coercion> {
+        self.coercions.list()
+    }
+
+    /// Clears the log of coerced fields.
+    pub fn clear_coercions(&self) {
+        self.coercions.clear();
+    }
+
+    /// Sets when and how often failed requests are retried.
+    pub fn set_retry_config(&mut self, config: crate::utils::RetryConfig) -> &mut Self {
+        self.retry = config;
+        self
+    }
+
+    /// Queues requests to stay under the rate limits of the API. The clones
+    /// made from the client afterwards share the same limiter.
+    pub fn set_rate_limiter(&mut self, limiter: crate::utils::RateLimiter) -> &mut Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Carries the retry config and rate limiter to the middleware.
+    fn request_extensions(&self) -> task_local_extensions::Extensions {
+        let mut extensions = self.retry.extensions();
+        if let Some(limiter) = &self.limiter {
+            extensions.insert(limiter.clone());
+        }
+        extensions
+    }
+
     pub fn set_credentials<CR>(&mut self, credentials: CR)
     where
         CR: Into<Option<crate::auth::Credentials>>,
@@ -201,7 +247,7 @@ impl Client {
         message: Message,
         media_type: crate::utils::MediaType,
         authentication: crate::auth::AuthenticationConstraint,
-    ) -> ClientResult<(Option<crate::utils::NextLink>, Out)>
+    ) -> ClientResult<(Option<crate::utils::NextLink>, crate::Response<Out>)>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -243,7 +289,7 @@ impl Client {
         if let Some(body) = message.body {
             req = req.body(body);
         }
-        let response = req.send().await?;
+        let response = req.send_with_extensions(&mut self.request_extensions()).await?;
 
         #[cfg(feature = "httpcache")]
         let instance2 = <&Client>::clone(&self);
@@ -258,6 +304,8 @@ impl Client {
         let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -287,9 +335,9 @@ impl Client {
             let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok((next_link, parsed_response))
+            Ok((next_link, crate::Response { status, headers, raw: response_body, body: parsed_response }))
         } else if status == http::StatusCode::NOT_MODIFIED {
                 // only supported case is when client provides if-none-match
                 // header when cargo builds with --cfg feature="httpcache"
@@ -302,7 +350,7 @@ impl Client {
                         None => instance2.http_cache.lookup_next_link(&uri3)
                             .map(|next_link| next_link.map(crate::utils::NextLink))
                     };
-                    link.map(|link| (link, out))
+                    link.map(|link| (link, crate::Response { status, headers, raw: body.into(), body: out }))
                 }
                 #[cfg(not(feature = "httpcache"))]
                 {
@@ -319,9 +367,11 @@ impl Client {
                 },
                 _ => {
                     if response_body.is_empty() {
-                        ClientError::HttpError{status: status, error: "empty response".into()}
+                        ClientError::HttpError{status, error: "empty response".into(), request_id}
+                    } else if let Some(error) = crate::utils::github_error(status, &response_body, request_id.clone()) {
+                        error
                     } else {
-                        ClientError::HttpError{status: status, error: String::from_utf8_lossy(&response_body).into()}
+                        ClientError::HttpError{status, error: String::from_utf8_lossy(&response_body).into(), request_id}
                     }
                 }
             };
@@ -336,7 +386,7 @@ impl Client {
         message: Message,
         media_type: crate::utils::MediaType,
         authentication: crate::auth::AuthenticationConstraint,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -344,14 +394,22 @@ impl Client {
         Ok(r)
     }
 
+    #[allow(dead_code)]
     async fn get<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.get_with_response(uri, message).await?.body)
+    }
+
+    async fn get_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
         self.get_media(uri, crate::utils::MediaType::Json, message).await
     }
 
-    async fn get_media<D>(&self, uri: &str, media: crate::utils::MediaType, message: Message) -> ClientResult<D>
+    async fn get_media<D>(&self, uri: &str, media: crate::utils::MediaType, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -375,29 +433,39 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        self.request(
+        let (link, r) = self.request(
             http::Method::GET,
             &uri,
             Message::default(),
             crate::utils::MediaType::Json,
             crate::auth::AuthenticationConstraint::Unconstrained,
-        ).await
+        ).await?;
+        Ok((link, r.body))
     }
 
     async fn get_pages_url<D>(&self, url: &reqwest::Url) -> ClientResult<(Option<crate::utils::NextLink>, Vec<D>)>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        self.request(
+        let (link, r) = self.request(
             http::Method::GET,
             url.as_str(),
             Message::default(),
             crate::utils::MediaType::Json,
             crate::auth::AuthenticationConstraint::Unconstrained,
-        ).await
+        ).await?;
+        Ok((link, r.body))
     }
 
+    #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_with_response(uri, message).await?.body)
+    }
+
+    async fn post_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -415,7 +483,7 @@ impl Client {
         message: Message,
         media: crate::utils::MediaType,
         authentication: crate::auth::AuthenticationConstraint,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -428,7 +496,7 @@ impl Client {
         ).await
     }
 
-    async fn patch_media<D>(&self, uri: &str, message: Message, media: crate::utils::MediaType) -> ClientResult<D>
+    async fn patch_media<D>(&self, uri: &str, message: Message, media: crate::utils::MediaType) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -441,21 +509,37 @@ impl Client {
         ).await
     }
 
+    #[allow(dead_code)]
     async fn patch<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.patch_with_response(uri, message).await?.body)
+    }
+
+    async fn patch_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
         self.patch_media(uri, message, crate::utils::MediaType::Json).await
     }
 
+    #[allow(dead_code)]
     async fn put<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.put_with_response(uri, message).await?.body)
+    }
+
+    async fn put_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
         self.put_media(uri, message, crate::utils::MediaType::Json).await
     }
 
-    async fn put_media<D>(&self, uri: &str, message: Message, media: crate::utils::MediaType) -> ClientResult<D>
+    async fn put_media<D>(&self, uri: &str, message: Message, media: crate::utils::MediaType) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -468,7 +552,15 @@ impl Client {
         ).await
     }
 
+    #[allow(dead_code)]
     async fn delete<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.delete_with_response(uri, message).await?.body)
+    }
+
+    async fn delete_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -515,8 +607,43 @@ pub fn generate_client_generic_token(
 ) -> String {
     let mut new_from_env = basic_new_from_env(proper_name, add_post_header, servers);
     if proper_name.starts_with("Google") {
-        new_from_env = GOOGLE_NEW_FROM_ENV_TEMPLATE.to_string();
+        new_from_env = format!(
+            "{}\n{}",
+            GOOGLE_NEW_FROM_ENV_TEMPLATE, GOOGLE_SERVICE_ACCOUNT_TEMPLATE
+        );
+    } else if proper_name == "Shopify" {
+        new_from_env = SHOPIFY_FUNCTIONS.to_string();
+    }
+
+    // Google clients can also authenticate as a service account.
+    let (
+        service_account_field,
+        service_account_struct,
+        service_account_init,
+        refresh_service_account,
+    ) = if proper_name.starts_with("Google") {
+        (
+            "service_account: Option<ServiceAccount>,",
+            GOOGLE_SERVICE_ACCOUNT_STRUCT_TEMPLATE,
+            "service_account: None,",
+            r#"
+    // Service accounts mint a new token with the JWT bearer grant instead.
+    if let Some(service_account) = &self.service_account {
+        return self.refresh_service_account_token(service_account).await;
     }
+"#,
+        )
+    } else if proper_name == "Shopify" {
+        // Shopify clients talk to a single shop, at an API version.
+        (
+            "shop: String,\n    api_version: String,",
+            "",
+            "shop: String::new(),\n                    api_version: DEFAULT_API_VERSION.to_string(),",
+            "",
+        )
+    } else {
+        ("", "", "", "")
+    };
 
     let add_post_header_struct = if add_post_header.is_empty() {
         "".to_string()
@@ -558,7 +685,52 @@ pub fn generate_client_generic_token(
         "{}?client_id={}&response_type=code&redirect_uri={}&state={}"
     };
 
-    let token_auth_template = get_token_auth_template(consent_pattern);
+    // Shopify builds the OAuth endpoints from the shop and wants its scopes
+    // separated by commas.
+    let (token_endpoint_url, user_consent_endpoint_url, scope_separator) =
+        if proper_name == "Shopify" {
+            (
+                "self.shop_url(TOKEN_ENDPOINT)",
+                "self.shop_url(USER_CONSENT_ENDPOINT)",
+                ",",
+            )
+        } else {
+            ("TOKEN_ENDPOINT", "USER_CONSENT_ENDPOINT", " ")
+        };
+
+    // Shopify's endpoints are versioned and paths come without the version, see
+    // `generator/src/shopify.rs`.
+    let (url_path, extra_middleware) = if proper_name == "Shopify" {
+        (
+            "crate::utils::api_path(&self.api_version, path)",
+            "\n                    // Warn about calls to deprecated endpoints.\n                    .with(crate::utils::DeprecationMiddleware)",
+        )
+    } else {
+        ("path", "")
+    };
+
+    // Shopify throttles every app on every shop with a leaky bucket, pace the
+    // requests with it from the start.
+    let limiter = if proper_name == "Shopify" {
+        "Some(crate::utils::RateLimiter::new())"
+    } else {
+        "None"
+    };
+
+    let token_auth_template = get_token_auth_template(
+        consent_pattern,
+        refresh_service_account,
+        token_endpoint_url,
+        user_consent_endpoint_url,
+        scope_separator,
+    );
+
+    // A Shopify client without a shop has no host to send requests to.
+    let new_attrs = if proper_name == "Shopify" {
+        "\n    #[deprecated(note = \"the client has no shop to talk to, use `new_for_shop`\")]"
+    } else {
+        ""
+    };
 
     let server_block = servers.server_block();
     let server_arg = servers.server_arg();
@@ -586,11 +758,14 @@ pub struct Client {{
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-    {}
+    {}{service_account_field}
     auto_refresh: bool,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
-
+{service_account_struct}
 {}
 
 /// Time in seconds before the access token expiration point that a refresh should
@@ -608,7 +783,7 @@ struct InnerToken {{
 impl Client {{
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
-    /// given a valid API key your requests will work.
+    /// given a valid API key your requests will work.{new_attrs}
     pub fn new<I, K, R, T, Q{}>(
         client_id: I,
         client_secret: K,
@@ -626,8 +801,6 @@ impl Client {{
         Q: ToString,
         {}
     {{
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         let client = reqwest::Client::builder().build();
         match client {{
             Ok(c) => {{
@@ -635,12 +808,9 @@ impl Client {{
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
+                    .with(crate::utils::RetryMiddleware::default())
+                    // Stay under the rate limits, see `Client::set_rate_limiter`.
+                    .with(crate::utils::RateLimitMiddleware){extra_middleware}
                     .build();
 
                 {server_to_host}
@@ -656,8 +826,11 @@ impl Client {{
                         refresh_token: refresh_token.to_string(),
                         expires_at: None
                     }})),
-                    {}
+                    {}{service_account_init}
                     auto_refresh: false,
+                    coercions: Default::default(),
+                    retry: Default::default(),
+                    limiter: {limiter},
                     client,
                 }}
             }}
@@ -742,7 +915,7 @@ impl Client {{
     }}
 
     pub(crate) fn url(&self, path: &str, host: Option<&str>) -> String {{
-        format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), path)
+        format!("{{}}{{}}", self.get_host_override().or(host).unwrap_or(self.host.as_str()), {url_path})
     }}
 
     {}
@@ -854,7 +1027,6 @@ where
         .expect("failed to read from google credential env var");
 
     let client = reqwest::Client::builder().build();
-    let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
 
     match client {
         Ok(c) => {
@@ -862,12 +1034,9 @@ where
                 // Trace HTTP requests. See the tracing crate to make use of these traces.
                 .with(reqwest_tracing::TracingMiddleware::default())
                 // Retry failed requests.
-                .with(
-                    reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some()
-                    )
-                )
+                .with(crate::utils::RetryMiddleware::default())
+                // Stay under the rate limits, see `Client::set_rate_limiter`.
+                .with(crate::utils::RateLimitMiddleware)
                 .build();
 
             let host = RootDefaultServer::default().default_url().to_string();
@@ -883,7 +1052,11 @@ where
                     refresh_token: refresh_token.to_string(),
                     expires_at: None
                 })),
+                service_account: None,
                 auto_refresh: false,
+                coercions: Default::default(),
+                retry: Default::default(),
+                limiter: None,
                 client,
             }
         },
@@ -892,6 +1065,172 @@ where
 }
 "#;
 
+const SHOPIFY_FUNCTIONS: &str = r#"
+/// Create a new Client for the shop in `SHOPIFY_SHOP` from environment variables,
+/// see `new_for_shop`. It takes a type that can convert into
+/// an &str (`String` or `Vec<u8>` for example).
+/// We pass in the token and refresh token to the client so if you are storing
+/// it in a database, you can get it first.
+pub fn new_from_env<T, R>(token: T, refresh_token: R) -> Self
+where
+    T: ToString,
+    R: ToString,
+{
+    let shop = env::var("SHOPIFY_SHOP").expect("must set SHOPIFY_SHOP");
+    let shop = crate::utils::shop_name(&shop).expect("SHOPIFY_SHOP is not a shop");
+    let client_id = env::var("SHOPIFY_CLIENT_ID").expect("must set SHOPIFY_CLIENT_ID");
+    let client_secret = env::var("SHOPIFY_CLIENT_SECRET").expect("must set SHOPIFY_CLIENT_SECRET");
+    let redirect_uri = env::var("SHOPIFY_REDIRECT_URI").expect("must set SHOPIFY_REDIRECT_URI");
+
+    #[allow(deprecated)]
+    let mut client = Client::new(client_id, client_secret, redirect_uri, token, refresh_token);
+    client.shop = shop;
+    client.host = client.shop_url(FALLBACK_HOST);
+
+    client
+}
+
+/// Create a new Client for a shop, from its `my-shop.myshopify.com` domain or
+/// just its name. The host and the OAuth endpoints are built from it, so a
+/// process serving many shops makes a client for each of them.
+///
+/// Pass an empty `token` to install the app on the shop with
+/// `user_consent_url` and `get_access_token`.
+pub fn new_for_shop<S, I, K, R, T>(
+    shop: S,
+    client_id: I,
+    client_secret: K,
+    redirect_uri: R,
+    token: T,
+) -> ClientResult<Self>
+where
+    S: ToString,
+    I: ToString,
+    K: ToString,
+    R: ToString,
+    T: ToString,
+{
+    let shop = crate::utils::shop_name(&shop.to_string())?;
+
+    // Shopify access tokens do not get refreshed.
+    #[allow(deprecated)]
+    let mut client = Client::new(client_id, client_secret, redirect_uri, token, "");
+    client.shop = shop;
+    client.host = client.shop_url(FALLBACK_HOST);
+
+    Ok(client)
+}
+
+/// The name of the shop the client talks to, ie. `my-shop`.
+pub fn shop(&self) -> &str {
+    &self.shop
+}
+
+/// Check the query string Shopify redirected the merchant back with, after
+/// they installed the app, was signed with our client secret. Then hand the
+/// `code` to `get_access_token` on a client for the `shop`.
+///
+/// Make sure the `state` is the one you sent the merchant off with.
+pub fn verify_oauth_callback(&self, query: &str) -> ClientResult<crate::utils::OAuthCallback> {
+    crate::utils::verify_oauth_callback(&self.client_secret, query)
+}
+
+fn shop_url(&self, url: &str) -> String {
+    url.replace("{shop}", &self.shop)
+}
+
+/// The Admin API version the client talks to, `DEFAULT_API_VERSION` unless set.
+pub fn api_version(&self) -> &str {
+    &self.api_version
+}
+
+/// Talk to the Admin API at another version, ie. `2026-04` or `unstable`.
+pub fn set_api_version<V>(&mut self, api_version: V) -> &mut Self
+where
+    V: ToString,
+{
+    self.api_version = api_version.to_string();
+    self
+}
+
+/// A client pinned to another Admin API version, for a single request or a few.
+pub fn with_api_version<V>(&self, api_version: V) -> Self
+where
+    V: ToString,
+{
+    let mut client = self.clone();
+    client.api_version = api_version.to_string();
+    client
+}
+"#;
+
+const GOOGLE_SERVICE_ACCOUNT_STRUCT_TEMPLATE: &str = r#"
+/// A service account we mint access tokens for.
+#[derive(Clone)]
+struct ServiceAccount {
+    authenticator: yup_oauth2::authenticator::DefaultAuthenticator,
+    scopes: Vec<String>,
+}
+"#;
+
+const GOOGLE_SERVICE_ACCOUNT_TEMPLATE: &str = r#"
+/// Create a new Client struct that authenticates as a service account. It takes
+/// the service account's JSON key, the scopes to request and, for domain-wide
+/// delegation, an optional user to impersonate. Access tokens are minted with the
+/// JWT bearer grant and refreshed automatically when they expire.
+pub async fn new_from_service_account<K>(key: K, scopes: &[String], subject: Option<&str>) -> ClientResult<Self>
+where
+    K: AsRef<[u8]>,
+{
+    let key = yup_oauth2::parse_service_account_key(key)?;
+    let mut builder = yup_oauth2::ServiceAccountAuthenticator::builder(key);
+    if let Some(subject) = subject {
+        builder = builder.subject(subject);
+    }
+    let authenticator = builder.build().await?;
+
+    let mut client = Client::new("", "", "", "", "");
+    client.service_account = Some(ServiceAccount {
+        authenticator,
+        scopes: scopes.to_vec(),
+    });
+    client.auto_refresh = true;
+
+    // Get our first token, so we know when it expires.
+    client.refresh_access_token().await?;
+
+    Ok(client)
+}
+
+async fn refresh_service_account_token(&self, service_account: &ServiceAccount) -> ClientResult<AccessToken> {
+    let token = service_account.authenticator.token(&service_account.scopes).await?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    let expires_in = token
+        .expiration_time()
+        .map(|t| t.unix_timestamp() - now)
+        .unwrap_or_default();
+
+    let t = AccessToken {
+        token_type: "Bearer".to_string(),
+        access_token: token.token().unwrap_or_default().to_string(),
+        expires_in,
+        scope: service_account.scopes.join(" "),
+        ..Default::default()
+    };
+
+    *self.token.write().await = InnerToken {
+        access_token: t.access_token.clone(),
+        refresh_token: String::new(),
+        expires_at: Self::compute_expires_at(t.expires_in),
+    };
+
+    Ok(t)
+}
+"#;
+
 pub fn generate_client_generic_api_key(
     proper_name: &str,
     add_post_header: &str,
@@ -906,6 +1245,15 @@ pub fn generate_client_generic_api_key(
     let server_arg = servers.server_arg();
     let server_to_host = servers.host_from_server();
 
+    let (stripe_field, stripe_init) = if proper_name == "Stripe" {
+        (
+            "\n    stripe: crate::utils::StripeOptions,",
+            "\n                    stripe: Default::default(),",
+        )
+    } else {
+        ("", "")
+    };
+
     format!(
         r#"use std::env;
 
@@ -917,6 +1265,9 @@ pub struct Client {{
     host: String,
     host_override: Option<String>,
     token: String,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,{stripe_field}
 
     client: reqwest_middleware::ClientWithMiddleware,
 }}
@@ -933,19 +1284,15 @@ impl Client {{
         T: ToString,
     {{
         let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         match client {{
             Ok(c) => {{
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
+                    .with(crate::utils::RetryMiddleware::default())
+                    // Stay under the rate limits, see `Client::set_rate_limiter`.
+                    .with(crate::utils::RateLimitMiddleware)
                     .build();
 
                 {server_to_host}
@@ -954,6 +1301,9 @@ impl Client {{
                     host,
                     host_override: None,
                     token: token.to_string(),
+                    coercions: Default::default(),
+                    retry: Default::default(),
+                    limiter: None,{stripe_init}
 
                     client,
                 }}
@@ -1008,6 +1358,46 @@ impl Client {{
     )
 }
 
+const STRIPE_FUNCTIONS: &str = r#"
+    /// A client that acts on behalf of a connected account, through the
+    /// `Stripe-Account` header.
+    pub fn with_account<A>(&self, account: A) -> Self
+    where
+        A: ToString,
+    {
+        let mut client = self.clone();
+        client.stripe.account = Some(account.to_string());
+        client
+    }
+
+    /// A client pinned to an API version, through the `Stripe-Version` header.
+    pub fn with_api_version<V>(&self, api_version: V) -> Self
+    where
+        V: ToString,
+    {
+        let mut client = self.clone();
+        client.stripe.api_version = Some(api_version.to_string());
+        client
+    }
+
+    /// A client that sends the given `Idempotency-Key` with its POST requests,
+    /// instead of generating one for each of them. Use it for a single request.
+    pub fn with_idempotency_key<K>(&self, key: K) -> Self
+    where
+        K: ToString,
+    {
+        let mut client = self.clone();
+        client.stripe.idempotency_key = Some(key.to_string());
+        client
+    }
+
+    /// Generate an `Idempotency-Key` for every POST request without one, on by default.
+    pub fn set_auto_idempotency_keys(&mut self, enabled: bool) -> &mut Self {
+        self.stripe.auto_idempotency_keys = enabled;
+        self
+    }
+"#;
+
 fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
     let post_header_args = if !add_post_header.is_empty() {
         format!(
@@ -1020,16 +1410,72 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
             to_snake_case(add_post_header),
             to_snake_case(add_post_header)
         )
+    } else if proper_name == "Stripe" {
+        "req = self.stripe.apply(req, &method)?;".to_string()
     } else {
         String::new()
     };
 
+    let stripe_functions = if proper_name == "Stripe" {
+        STRIPE_FUNCTIONS
+    } else {
+        ""
+    };
+
+    // Stripe's account, version and idempotency headers go on every request.
+    let (stripe_apply_post, stripe_apply) = if proper_name == "Stripe" {
+        (
+            "\n    req = self.stripe.apply(req, &http::Method::POST)?;",
+            "\n    req = self.stripe.apply(req, &method)?;",
+        )
+    } else {
+        ("", "")
+    };
+
+    // Shopify wants the token in its own header.
+    let auth_header = if proper_name == "Shopify" {
+        r#""X-Shopify-Access-Token""#
+    } else {
+        "http::header::AUTHORIZATION"
+    };
+
     let bearer = if proper_name == "Okta" {
         "SSWS".to_string()
     } else {
         "Bearer".to_string()
     };
 
+    // Slack always responds with a 200 and tells us about errors in the body,
+    // it also tells us how long to back off for when we are rate limited.
+    let (retry_after, slack_ok, rate_limited) = if proper_name == "Slack" {
+        (
+            "\n    let retry_after = crate::utils::retry_after(response.headers());",
+            "\n        crate::utils::slack_ok(&response_body, request_id)?;",
+            "if let (http::StatusCode::TOO_MANY_REQUESTS, Some(duration)) = (status, retry_after) {\n            ClientError::RateLimited { duration }\n        } else ",
+        )
+    } else {
+        ("", "", "")
+    };
+
+    // Parse the vendor's error envelope when it has one.
+    let api_error = if proper_name == "Stripe" {
+        "stripe_error"
+    } else if proper_name == "Okta" {
+        "okta_error"
+    } else if proper_name.starts_with("Google") {
+        "google_error"
+    } else {
+        ""
+    };
+    let api_error = if api_error.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "if let Some(error) = crate::utils::{}(status, &response_body, request_id.clone()) {{\n            error\n        }} else ",
+            api_error
+        )
+    };
+
     // Add auto refresh functionality to clients that support it
     let raw_request = if proper_name.starts_with("Google")
         || proper_name == "DocuSign"
@@ -1040,7 +1486,13 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         || proper_name == "Slack"
         || proper_name == "Zoom"
     {
-        get_shared_raw_functions_with_refresh("Bearer", &post_header_args)
+        // Shopify wants the bare token.
+        let bearer = if proper_name == "Shopify" {
+            ""
+        } else {
+            "Bearer"
+        };
+        get_shared_raw_functions_with_refresh(bearer, auth_header, &post_header_args)
     } else {
         get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
     };
@@ -1049,34 +1501,78 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         r#"
 {}
 
+    /// Return an error with the JSON path of the offending field when a response
+    /// does not match the generated types, instead of falling back to defaults.
+    pub fn set_strict_deserialization(&mut self, enabled: bool) -> &mut Self {{
+        self.coercions.strict = enabled;
+        self
+    }}
+
+    /// The fields of past responses that did not match the generated types and
+    /// were replaced with their default value. The log is shared between clones
+    /// of the client and keeps the most recent 1000 fields.
+    pub fn coercions(&self) -> Vec<crate::utils::Coercion> {{
+        self.coercions.list()
+    }}
+
+    /// Clears the log of coerced fields.
+    pub fn clear_coercions(&self) {{
+        self.coercions.clear();
+    }}
+
+    /// Sets when and how often failed requests are retried.
+    pub fn set_retry_config(&mut self, config: crate::utils::RetryConfig) -> &mut Self {{
+        self.retry = config;
+        self
+    }}
+
+    /// Queues requests to stay under the rate limits of the API. The clones
+    /// made from the client afterwards share the same limiter.
+    pub fn set_rate_limiter(&mut self, limiter: crate::utils::RateLimiter) -> &mut Self {{
+        self.limiter = Some(limiter);
+        self
+    }}
+
+    /// Carries the retry config and rate limiter to the middleware.
+    fn request_extensions(&self) -> task_local_extensions::Extensions {{
+        let mut extensions = self.retry.extensions();
+        if let Some(limiter) = &self.limiter {{
+            extensions.insert(limiter.clone());
+        }}
+        extensions
+    }}
+{stripe_functions}
+
 async fn request<Out>(
     &self,
     method: reqwest::Method,
     uri: &str,
     message: Message,
-) -> ClientResult<Out>
+) -> ClientResult<crate::Response<Out>>
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
     let response = self.request_raw(method, uri, message).await?;
 
     let status = response.status();
+    let request_id = crate::request_id(response.headers());{retry_after}
+    let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{slack_ok}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)?
+            self.coercions.parse::<Out>(&response_body)?
         }};
-        Ok(parsed_response)
+        Ok(crate::Response {{ status, headers, raw: response_body, body: parsed_response }})
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status: status, error: "empty response".into()}}
+        let error = {rate_limited}{api_error}if response_body.is_empty() {{
+            ClientError::HttpError{{status, error: "empty response".into(), request_id}}
         }} else {{
-            ClientError::HttpError{{status: status, error: String::from_utf8_lossy(&response_body).into()}}
+            ClientError::HttpError{{status, error: String::from_utf8_lossy(&response_body).into(), request_id}}
         }};
 
         Err(error)
@@ -1095,6 +1591,7 @@ where
     let response = self.request_raw(method, uri, message).await?;
 
     let status = response.status();
+    let request_id = crate::request_id(response.headers());{retry_after}
     let link = response
         .headers()
         .get(http::header::LINK)
@@ -1106,25 +1603,24 @@ where
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{slack_ok}
 
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)?
+            self.coercions.parse::<Out>(&response_body)?
         }};
         Ok((link, parsed_response))
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status: status, error: "empty response".into()}}
+        let error = {rate_limited}{api_error}if response_body.is_empty() {{
+            ClientError::HttpError{{status, error: "empty response".into(), request_id}}
         }} else {{
-            ClientError::HttpError{{status: status, error: String::from_utf8_lossy(&response_body).into()}}
+            ClientError::HttpError{{status, error: String::from_utf8_lossy(&response_body).into(), request_id}}
         }};
         Err(error)
     }}
 }}
 
-/* TODO: make this more DRY */
 #[allow(dead_code)]
 async fn post_form<Out>(
     &self,
@@ -1133,6 +1629,19 @@ async fn post_form<Out>(
 ) -> ClientResult<Out>
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
+{{
+    Ok(self.post_form_with_response(uri, form).await?.body)
+}}
+
+/* TODO: make this more DRY */
+#[allow(dead_code)]
+async fn post_form_with_response<Out>(
+    &self,
+    uri: &str,
+    form: reqwest::multipart::Form,
+) -> ClientResult<crate::Response<Out>>
+    where
+    Out: serde::de::DeserializeOwned + 'static + Send,
 {{
     let (url, auth) = self.url_and_auth(uri).await?;
 
@@ -1144,22 +1653,24 @@ async fn post_form<Out>(
     req = req.header(
         reqwest::header::ACCEPT,
         reqwest::header::HeaderValue::from_static("application/json"),
-    );
+    );{stripe_apply_post}
 
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({auth_header}, &*auth_str);
     }}
 
     req = req.multipart(form);
 
-    let response = req.send().await?;
+    let response = req.send_with_extensions(&mut self.request_extensions()).await?;
 
     let status = response.status();
+    let request_id = crate::request_id(response.headers());{retry_after}
+    let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{slack_ok}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
@@ -1167,21 +1678,20 @@ async fn post_form<Out>(
             let s = String::from_utf8(response_body.to_vec())?;
             serde_json::from_value(serde_json::json!(&s))?
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)?
+            self.coercions.parse::<Out>(&response_body)?
         }};
-        Ok(parsed_response)
+        Ok(crate::Response {{ status, headers, raw: response_body, body: parsed_response }})
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status: status, error: "empty response".into()}}
+        let error = {rate_limited}{api_error}if response_body.is_empty() {{
+            ClientError::HttpError{{status, error: "empty response".into(), request_id}}
         }} else {{
-            ClientError::HttpError{{status: status, error: String::from_utf8_lossy(&response_body).into()}}
+            ClientError::HttpError{{status, error: String::from_utf8_lossy(&response_body).into(), request_id}}
         }};
 
         Err(error)
     }}
 }}
 
-/* TODO: make this more DRY */
 #[allow(dead_code)]
 async fn request_with_accept_mime<Out>(
     &self,
@@ -1191,31 +1701,47 @@ async fn request_with_accept_mime<Out>(
 ) -> ClientResult<Out>
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
+{{
+    Ok(self.request_with_accept_mime_with_response(method, uri, accept_mime_type).await?.body)
+}}
+
+/* TODO: make this more DRY */
+#[allow(dead_code)]
+async fn request_with_accept_mime_with_response<Out>(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    accept_mime_type: &str,
+) -> ClientResult<crate::Response<Out>>
+    where
+    Out: serde::de::DeserializeOwned + 'static + Send,
 {{
     let (url, auth) = self.url_and_auth(uri).await?;
 
     let instance = <&Client>::clone(&self);
 
-    let mut req = instance.client.request(method, url);
+    let mut req = instance.client.request(method.clone(), url);
 
     // Set the default headers.
     req = req.header(
         reqwest::header::ACCEPT,
         reqwest::header::HeaderValue::from_str(accept_mime_type)?,
-    );
+    );{stripe_apply}
 
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({auth_header}, &*auth_str);
     }}
 
-    let response = req.send().await?;
+    let response = req.send_with_extensions(&mut self.request_extensions()).await?;
 
     let status = response.status();
+    let request_id = crate::request_id(response.headers());{retry_after}
+    let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{slack_ok}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
@@ -1223,14 +1749,14 @@ async fn request_with_accept_mime<Out>(
             let s = String::from_utf8(response_body.to_vec())?;
             serde_json::from_value(serde_json::json!(&s))?
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)?
+            self.coercions.parse::<Out>(&response_body)?
         }};
-        Ok(parsed_response)
+        Ok(crate::Response {{ status, headers, raw: response_body, body: parsed_response }})
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status: status, error: "empty response".into()}}
+        let error = {rate_limited}{api_error}if response_body.is_empty() {{
+            ClientError::HttpError{{status, error: "empty response".into(), request_id}}
         }} else {{
-            ClientError::HttpError{{status: status, error: String::from_utf8_lossy(&response_body).into()}}
+            ClientError::HttpError{{status, error: String::from_utf8_lossy(&response_body).into(), request_id}}
         }};
 
         Err(error)
@@ -1253,7 +1779,7 @@ async fn request_with_mime<Out>(
 
     let instance = <&Client>::clone(&self);
 
-    let mut req = instance.client.request(method, url);
+    let mut req = instance.client.request(method.clone(), url);
 
     // Set the default headers.
     req = req.header(
@@ -1272,10 +1798,10 @@ async fn request_with_mime<Out>(
     req = req.header(
         reqwest::header::HeaderName::from_static("x-upload-content-length"),
         reqwest::header::HeaderValue::from_bytes(format!("{{}}", content.len()).as_bytes()).unwrap(),
-    );
+    );{stripe_apply}
 
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({auth_header}, &*auth_str);
     }}
 
     if content.len() > 1 {{
@@ -1284,25 +1810,26 @@ async fn request_with_mime<Out>(
         req = req.body(b);
     }}
 
-    let response = req.send().await?;
+    let response = req.send_with_extensions(&mut self.request_extensions()).await?;
 
     let status = response.status();
+    let request_id = crate::request_id(response.headers());{retry_after}
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
-        log::debug!("Received successful response. Read payload.");
+        log::debug!("Received successful response. Read payload.");{slack_ok}
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")?
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)?
+            self.coercions.parse::<Out>(&response_body)?
         }};
         Ok(parsed_response)
     }} else {{
-        let error = if response_body.is_empty() {{
-            ClientError::HttpError{{status: status, error: "empty response".into()}}
+        let error = {rate_limited}{api_error}if response_body.is_empty() {{
+            ClientError::HttpError{{status, error: "empty response".into(), request_id}}
         }} else {{
-            ClientError::HttpError{{status: status, error: String::from_utf8_lossy(&response_body).into()}}
+            ClientError::HttpError{{status, error: String::from_utf8_lossy(&response_body).into(), request_id}}
         }};
 
         Err(error)
@@ -1314,18 +1841,23 @@ async fn request_entity<D>(
     method: http::Method,
     uri: &str,
     message: Message,
-) -> ClientResult<D>
+) -> ClientResult<crate::Response<D>>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let r = self
-        .request(method, uri, message)
-        .await?;
-    Ok(r)
+    self.request(method, uri, message).await
 }}
 
 #[allow(dead_code)]
-async fn get<D>(&self, uri: &str,  message: Message) -> ClientResult<D>
+async fn get<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{{
+    Ok(self.get_with_response(uri, message).await?.body)
+}}
+
+#[allow(dead_code)]
+async fn get_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
@@ -1397,6 +1929,14 @@ where
 
 #[allow(dead_code)]
 async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{{
+    Ok(self.post_with_response(uri, message).await?.body)
+}}
+
+#[allow(dead_code)]
+async fn post_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
@@ -1409,6 +1949,14 @@ where
 
 #[allow(dead_code)]
 async fn patch<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{{
+    Ok(self.patch_with_response(uri, message).await?.body)
+}}
+
+#[allow(dead_code)]
+async fn patch_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
@@ -1421,6 +1969,14 @@ where
 
 #[allow(dead_code)]
 async fn put<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{{
+    Ok(self.put_with_response(uri, message).await?.body)
+}}
+
+#[allow(dead_code)]
+async fn put_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
@@ -1433,6 +1989,14 @@ where
 
 #[allow(dead_code)]
 async fn delete<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+where
+    D: serde::de::DeserializeOwned + 'static + Send,
+{{
+    Ok(self.delete_with_response(uri, message).await?.body)
+}}
+
+#[allow(dead_code)]
+async fn delete_with_response<D>(&self, uri: &str, message: Message) -> ClientResult<crate::Response<D>>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
@@ -1493,14 +2057,27 @@ async fn request_raw(
     if let Some(body) = message.body {{
         req = req.body(body);
     }}
-    Ok(req.send().await?)
+    Ok(req.send_with_extensions(&mut self.request_extensions()).await?)
 }}
 "#,
         bearer, post_header_args
     )
 }
 
-fn get_shared_raw_functions_with_refresh(bearer: &str, post_header_args: &str) -> String {
+fn get_shared_raw_functions_with_refresh(
+    bearer: &str,
+    auth_header: &str,
+    post_header_args: &str,
+) -> String {
+    let auth = if bearer.is_empty() {
+        "self.token.read().await.access_token.to_string()".to_string()
+    } else {
+        format!(
+            r#"format!("{} {{}}", self.token.read().await.access_token)"#,
+            bearer
+        )
+    };
+
     format!(
         r#"
 async fn url_and_auth(
@@ -1509,7 +2086,7 @@ async fn url_and_auth(
 ) -> ClientResult<(reqwest::Url, Option<String>)> {{
     let parsed_url = uri.parse::<reqwest::Url>()?;
 
-    let auth = format!("{} {{}}", self.token.read().await.access_token);
+    let auth = {};
     Ok((parsed_url, Some(auth)))
 }}
 
@@ -1546,7 +2123,7 @@ async fn make_request(
     {}
 
     if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        req = req.header({}, &*auth_str);
     }}
 
     if let Some(body) = message.body {{
@@ -1590,15 +2167,21 @@ async fn request_raw(
     }}
 
     let req = self.make_request(&method, uri, message).await?;
-    let resp = self.client.execute(req).await?;
+    let resp = self.client.execute_with_extensions(req, &mut self.request_extensions()).await?;
 
     Ok(resp)
 }}"#,
-        bearer, post_header_args
+        auth, post_header_args, auth_header
     )
 }
 
-fn get_token_auth_template<S: AsRef<str>>(consent_pattern: S) -> String {
+fn get_token_auth_template<S: AsRef<str>>(
+    consent_pattern: S,
+    refresh_service_account: &str,
+    token_endpoint: &str,
+    user_consent_endpoint: &str,
+    scope_separator: &str,
+) -> String {
     format!(
         r#"
 /// Return a user consent url with an optional set of scopes.
@@ -1608,7 +2191,7 @@ pub fn user_consent_url(&self, scopes: &[String]) -> String {{
 
     let url = format!(
         "{}",
-        USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
+        {user_consent_endpoint}, self.client_id, self.redirect_uri, state
     );
 
     if scopes.is_empty() {{
@@ -1616,12 +2199,12 @@ pub fn user_consent_url(&self, scopes: &[String]) -> String {{
     }}
 
     // Add the scopes.
-    format!("{{}}&scope={{}}", url, scopes.join(" "))
+    format!("{{}}&scope={{}}", url, scopes.join("{scope_separator}"))
 }}
 
 /// Refresh an access token from a refresh token. Client must have a refresh token
 /// for this to work.
-pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{
+pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{{}
     let response = {{
         let refresh_token = &self.token.read().await.refresh_token;
 
@@ -1644,7 +2227,7 @@ pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {{
         ];
         let client = reqwest::Client::new();
         client
-            .post(TOKEN_ENDPOINT)
+            .post({token_endpoint})
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -1685,7 +2268,7 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> ClientResul
     ];
     let client = reqwest::Client::new();
     let resp = client
-        .post(TOKEN_ENDPOINT)
+        .post({token_endpoint})
         .headers(headers)
         .form(&params)
         .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -1703,7 +2286,8 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> ClientResul
 
     Ok(t)
 }}"#,
-        consent_pattern.as_ref()
+        consent_pattern.as_ref(),
+        refresh_service_account,
     )
 }
 
@@ -1769,6 +2353,9 @@ pub struct Client {{
     token: String,
     client_id: String,
     client_secret: String,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
 
@@ -1790,19 +2377,15 @@ impl Client {{
         T: ToString,
     {{
         let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         match client {{
             Ok(c) => {{
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
+                    .with(crate::utils::RetryMiddleware::default())
+                    // Stay under the rate limits, see `Client::set_rate_limiter`.
+                    .with(crate::utils::RateLimitMiddleware)
                     .build();
 
                 {server_to_host}
@@ -1813,6 +2396,9 @@ impl Client {{
                     client_id: client_id.to_string(),
                     client_secret: client_secret.to_string(),
                     token: token.to_string(),
+                    coercions: Default::default(),
+                    retry: Default::default(),
+                    limiter: None,
 
                     client,
                 }}
@@ -138,7 +138,7 @@ pub fn generate_files(
                 content
             };
 
-            let docs = get_fn_docs(o, m, p, parameters, ts)?;
+            let mut docs = get_fn_docs(o, m, p, parameters, ts)?;
 
             let mut bounds: Vec<String> = Vec::new();
 
@@ -203,11 +203,51 @@ pub fn generate_files(
                             } else {
                                 (None, None)
                             }
-                        } else if ct == "multipart/form-data" {
+                        } else if ct == "multipart/form-data" && proper_name != "Stripe" {
                             println!("got multipart/formdata for {}", oid);
                             // Skip it for now.
                             // TODO: fix this later.
                             (None, None)
+                        } else if (ct == "application/x-www-form-urlencoded"
+                            || ct == "multipart/form-data")
+                            && (proper_name == "Stripe" || proper_name == "Slack")
+                        {
+                            // Stripe wants nested form fields with bracket notation,
+                            // so we encode the body in utils::encode_form.
+                            // Slack's files.upload and Stripe's file uploads take the
+                            // file contents as multipart.
+                            let body_func = if (proper_name == "Slack" && p == "/files.upload")
+                                || ct == "multipart/form-data"
+                            {
+                                "multipart"
+                            } else {
+                                "form"
+                            };
+                            if let Some(s) = &mt.schema {
+                                let object_name = format!("{} request", oid_to_object_name(&od));
+                                // Use the type we already generated for this body, selecting
+                                // the schema again can give us a renamed duplicate.
+                                let id = if let Some(id) =
+                                    ts.name_to_id.get(&clean_name(&object_name))
+                                {
+                                    id.clone()
+                                } else {
+                                    ts.select(Some(&object_name), s, "")?
+                                };
+                                let et = ts.id_to_entry.get(&id).unwrap();
+                                if let crate::TypeDetails::Object(p, _) = &et.details {
+                                    if p.is_empty() {
+                                        (None, None)
+                                    } else {
+                                        let rt = ts.render_type(&id, false)?;
+                                        (Some(format!("&{}", rt)), Some(body_func.to_string()))
+                                    }
+                                } else {
+                                    (None, None)
+                                }
+                            } else {
+                                (None, None)
+                            }
                         } else if ct == "application/x-www-form-urlencoded" {
                             println!("got application/x-www-form-urlencoded for {}", oid);
                             // Skip it for now.
@@ -244,9 +284,15 @@ pub fn generate_files(
             /*
              * Get the function parameters.
              */
-            let (fn_params_str, query_params) =
+            let (mut fn_params_str, query_params) =
                 get_fn_params(ts, o, parameters, false, op.parameters.clone(), proper_name)?;
 
+            // Multipart bodies take the file contents alongside the body.
+            if body_func.as_deref() == Some("multipart") {
+                fn_params_str.push("file: Option<&[u8]>,".to_string());
+                docs = add_file_param_docs(&docs);
+            }
+
             // Generate the server to send the request to
             let server_arg = if o.servers.len() == 1 {
                 let servers = generate_servers(&o.servers, &to_pascal_case(&op_id));
@@ -264,6 +310,8 @@ pub fn generate_files(
             /*
              * Generate the URL for the request.
              */
+            let has_sync_token = query_params.contains_key("sync_token");
+
             let tmp = parse(p)?;
             let template = tmp.compile(query_params, &server_arg);
 
@@ -271,7 +319,7 @@ pub fn generate_files(
              * Get the response type.
              */
             let (mut response_type, tid, inner_response_type, pagination_property) =
-                get_response_type(&od, ts, o)?;
+                get_response_type(&od, ts, o, proper_name)?;
 
             if proper_name == "GitHub" && response_type == "crate::types::Data" {
                 response_type = "()".to_string();
@@ -293,6 +341,19 @@ pub fn generate_files(
                 &inner_response_type,
                 &pagination_property,
                 false,
+                false,
+                body_content_type_header.as_deref(),
+            )?;
+            let mut response_inner = get_fn_inner(
+                proper_name,
+                &oid,
+                m,
+                &body_func,
+                &response_type,
+                &inner_response_type,
+                &pagination_property,
+                false,
+                true,
                 body_content_type_header.as_deref(),
             )?;
 
@@ -301,6 +362,9 @@ pub fn generate_files(
                 fn_inner =
                     r#"self.client.request_with_accept_mime(reqwest::Method::GET, &url, &accept.to_string()).await"#
                         .to_string();
+                response_inner =
+                    r#"self.client.request_with_accept_mime_with_response(reqwest::Method::GET, &url, &accept.to_string()).await"#
+                        .to_string();
                 response_type = "String".to_string();
             }
 
@@ -370,18 +434,66 @@ pub fn generate_files(
             }
             fn_names.push(fn_name.clone() + &tag);
 
-            // Print our standard function.
+            let mut response_fn_name = format!("{}_with_response", fn_name);
+            if fn_names.contains(&(response_fn_name.clone() + &tag)) {
+                response_fn_name = format!("{}_all", response_fn_name);
+            }
+            fn_names.push(response_fn_name.clone() + &tag);
+
+            // Print our standard function, it returns the body of the response.
+            let mut args = fn_params_str
+                .iter()
+                .map(|p| p.split(':').next().unwrap_or_default().trim().to_string())
+                .collect::<Vec<String>>();
+            if body_param.is_some() {
+                args.push("body".to_string());
+            }
+            // Not every bound is used by the params, so pass them along explicitly.
+            let generics = if bounds.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "::<{}>",
+                    bounds
+                        .iter()
+                        .map(|b| b.split(':').next().unwrap_or_default().trim())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+            let call = format!(
+                "self.{}{}({}).await?",
+                response_fn_name,
+                generics,
+                args.join(", ")
+            );
             out.add_content(&print_fn(
                 &docs,
                 &bounds,
                 &fn_params_str,
                 &body_param,
                 &frt,
-                &template,
-                &fn_inner,
+                "",
+                &if frt == "()" {
+                    format!("{};\nOk(())", call)
+                } else {
+                    format!("Ok({}.body)", call)
+                },
                 &fn_name,
             ));
 
+            // And the function that returns the whole response.
+            out.add_content(&print_fn(
+                &get_fn_docs_response(o, m, p, &fn_name)?,
+                &bounds,
+                &fn_params_str,
+                &body_param,
+                &format!("crate::Response<{}>", frt),
+                &template,
+                &response_inner,
+                &response_fn_name,
+            ));
+
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
             if frt.starts_with("Vec<") && http::Method::GET == m {
@@ -395,8 +507,19 @@ pub fn generate_files(
                 let (fn_params_str, query_params) =
                     get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
 
+                // Google needs the next page token in the field mask to paginate.
+                let fields_mask = proper_name.starts_with("Google")
+                    && !pagination_property.is_empty()
+                    && query_params.contains_key("fields");
+
                 let tmp = parse(p)?;
-                let template = tmp.compile(query_params, &server_arg);
+                let mut template = tmp.compile(query_params, &server_arg);
+                if fields_mask {
+                    template = format!(
+                        "let fields = crate::utils::fields_with_next_page_token(fields);\n{}",
+                        template
+                    );
+                }
 
                 let fn_inner = get_fn_inner(
                     proper_name,
@@ -407,6 +530,7 @@ pub fn generate_files(
                     &inner_response_type,
                     &pagination_property,
                     true,
+                    false,
                     body_content_type_header.as_deref(),
                 )?;
 
@@ -459,6 +583,124 @@ pub fn generate_files(
                     &fn_inner,
                     &fn_name,
                 ));
+
+                // Also give them the pages as a stream, so we only fetch what we need.
+                let item_type = frt
+                    .strip_prefix("Vec<")
+                    .and_then(|t| t.strip_suffix('>'))
+                    .unwrap_or_default();
+                let pages_inner = get_fn_inner_pages(
+                    proper_name,
+                    &response_type,
+                    item_type,
+                    &pagination_property,
+                );
+                if let Some(pages_inner) =
+                    pages_inner.filter(|_| bounds.is_empty() && body_param.is_none())
+                {
+                    let mut pages_fn_name = format!("{}_pages_stream", fn_name);
+                    if fn_names.contains(&(pages_fn_name.clone() + &tag)) {
+                        pages_fn_name = format!("{}_all", pages_fn_name);
+                    }
+                    fn_names.push(pages_fn_name.clone() + &tag);
+
+                    let mut stream_fn_name = format!("{}_stream", fn_name);
+                    if fn_names.contains(&(stream_fn_name.clone() + &tag)) {
+                        stream_fn_name = format!("{}_all", stream_fn_name);
+                    }
+                    fn_names.push(stream_fn_name.clone() + &tag);
+
+                    let mut pages_params_str = fn_params_str.clone();
+                    pages_params_str.push("page_cursor: Option<String>,".to_string());
+                    out.add_content(&print_stream_fn(
+                        &get_fn_docs_stream(o, m, p, &fn_name, true)?,
+                        &pages_params_str,
+                        &format!("crate::utils::Page<{}>", item_type),
+                        &template,
+                        &pages_inner,
+                        &pages_fn_name,
+                    ));
+
+                    // The items are just the pages flattened.
+                    let args = fn_params_str
+                        .iter()
+                        .map(|p| p.split(':').next().unwrap_or_default().trim().to_string())
+                        .collect::<Vec<String>>();
+                    out.add_content(&print_stream_fn(
+                        &get_fn_docs_stream(o, m, p, &fn_name, false)?,
+                        &fn_params_str,
+                        item_type,
+                        "",
+                        &format!(
+                            "crate::utils::page_items(self.{}({}None))",
+                            pages_fn_name,
+                            args.iter().map(|a| format!("{}, ", a)).collect::<String>()
+                        ),
+                        &stream_fn_name,
+                    ));
+                }
+            }
+
+            // Google Calendar can sync incrementally, so give those lists a function
+            // that returns all the pages along with the next sync token.
+            if proper_name == "Google Calendar"
+                && frt.starts_with("Vec<")
+                && http::Method::GET == m
+                && !pagination_property.is_empty()
+                && has_sync_token
+            {
+                let docs = get_fn_docs_sync(o, m, p, &fn_name)?;
+
+                let (mut fn_params_str, mut query_params) =
+                    get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
+                // Google rejects the filters with a sync token, so don't offer them.
+                fn_params_str.retain(|p| {
+                    !is_google_calendar_sync_filter(p.split(':').next().unwrap_or_default())
+                });
+                query_params.retain(|nam, _| !is_google_calendar_sync_filter(nam));
+                fn_params_str.push("sync_token: &str,".to_string());
+                query_params.insert(
+                    "sync_token".to_string(),
+                    ("&str".to_string(), "syncToken".to_string()),
+                );
+
+                // We need both tokens in the field mask to sync.
+                let fields_mask = query_params.contains_key("fields");
+
+                let tmp = parse(p)?;
+                let mut template = tmp.compile(query_params, &server_arg);
+                if fields_mask {
+                    template = format!(
+                        "let fields = crate::utils::fields_with_sync_tokens(fields);\n{}",
+                        template
+                    );
+                }
+
+                let fn_inner = get_fn_inner_sync(&response_type, &pagination_property);
+
+                let mut fn_name = if fn_name == "list" {
+                    "sync".to_string()
+                } else {
+                    fn_name.replacen("list", "sync", 1)
+                };
+
+                // Do this right before printing. Check if we already have this function name.
+                // This will ensure we don't have any duplicates.
+                if fn_names.contains(&(fn_name.clone() + &tag)) {
+                    fn_name = format!("{}_all", fn_name);
+                }
+                fn_names.push(fn_name.clone() + &tag);
+
+                out.add_content(&print_fn(
+                    &docs,
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    &response_type,
+                    &template,
+                    &fn_inner,
+                    &fn_name,
+                ));
             }
 
             // Add this to our map of functions based on the tag name.
@@ -485,6 +727,7 @@ fn get_response_type_from_object(
     ts: &mut TypeSpace,
     s: Option<&openapiv3::ReferenceOr<openapiv3::Schema>>,
     r: Option<&openapiv3::ReferenceOr<openapiv3::Response>>,
+    proper_name: &str,
 ) -> Result<(
     String,        // original response type
     crate::TypeId, // type id
@@ -593,6 +836,17 @@ fn get_response_type_from_object(
                 }
             }
         }
+
+        // For Shopify, lists come wrapped in an object with just the list, ie.
+        // `{"orders": [...]}`, and the pagination values in the `Link` header.
+        if proper_name == "Shopify" && p.len() == 1 {
+            for (n, id) in p {
+                let rt = ts.render_type(id, false)?;
+                if rt.starts_with("Vec<") {
+                    return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
+                }
+            }
+        }
     }
 
     Ok((og_rt, tid, "".to_string(), "".to_string()))
@@ -602,6 +856,7 @@ fn get_response_type(
     od: &str,
     ts: &mut TypeSpace,
     o: &openapiv3::Operation,
+    proper_name: &str,
 ) -> Result<(
     String,        // original response type
     crate::TypeId, // type id
@@ -646,7 +901,7 @@ fn get_response_type(
                 }
 
                 // Get response type from object.
-                return get_response_type_from_object(od, ts, Some(s), None);
+                return get_response_type_from_object(od, ts, Some(s), None, proper_name);
             }
         }
 
@@ -676,7 +931,7 @@ fn get_response_type(
         }
     } else if let openapiv3::ReferenceOr::Reference { reference: _ } = first.1 {
         // Get response type from object.
-        return get_response_type_from_object(od, ts, None, Some(first.1));
+        return get_response_type_from_object(od, ts, None, Some(first.1), proper_name);
     }
 
     // Basically if we get here, likely its just an empty struct or something.
@@ -749,7 +1004,6 @@ fn get_fn_params(
                 && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
                 && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
                 && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
             {
                 if typ == "chrono::DateTime<chrono::Utc>" {
                     fn_params_str.push(format!("{}: Option<{}>,", nam, typ));
@@ -765,16 +1019,23 @@ fn get_fn_params(
 
             // Check if we have a query.
             // TODO: make this a bool ext.
-            if let openapiv3::Parameter::Query {
-                parameter_data: _,
-                allow_reserved: _,
-                style: openapiv3::QueryStyle::Form,
+            let is_query = match item {
                 // We can ignore the allow empty value, we support this by default and
                 // aren't strict about not allowing empty values on other parameters
                 // merely because specs cannot be trusted.
-                allow_empty_value: _,
-            } = item
-            {
+                openapiv3::Parameter::Query {
+                    style: openapiv3::QueryStyle::Form,
+                    ..
+                } => true,
+                // Stripe marks `expand` as a deep object, we send each field as its
+                // own `expand[]` param.
+                openapiv3::Parameter::Query {
+                    style: openapiv3::QueryStyle::DeepObject,
+                    ..
+                } => proper_name == "Stripe" && nam == "expand",
+                _ => false,
+            };
+            if is_query {
                 if nam == "ref"
                     || nam == "type"
                     || nam == "foo"
@@ -800,7 +1061,6 @@ fn get_fn_params(
                     && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
                     && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
                     && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                    && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
                 {
                     if typ == "chrono::DateTime<chrono::Utc>" {
                         query_params.insert(
@@ -835,11 +1095,25 @@ fn get_fn_inner(
     inner_response_type: &str,
     pagination_property: &str,
     all_pages: bool,
+    with_response: bool,
     content_type: Option<&str>,
 ) -> Result<String> {
+    // The `_with_response` helpers on the client return the whole response
+    // rather than only the parsed body.
+    let suffix = if with_response { "_with_response" } else { "" };
+
+    if body_func.as_deref() == Some("multipart") {
+        return Ok(format!(
+            r#"let form = crate::utils::multipart_form(body, file)?;
+            self.client.post_form{suffix}(&url, form).await"#
+        ));
+    }
+
     let body = if let Some(f) = &body_func {
         if f == "json" {
             "Some(reqwest::Body::from(serde_json::to_vec(body)?))"
+        } else if f == "form" {
+            "Some(reqwest::Body::from(crate::utils::encode_form(body)?))"
         } else {
             "Some(body.into())"
         }
@@ -1081,6 +1355,35 @@ fn get_fn_inner(
             pagination_property,
         );
 
+        return Ok(inner);
+    } else if all_pages && proper_name == "Shopify" {
+        // We will do a custom function here.
+        let inner = format!(
+            r#"let url = format!("{{}}{{}}limit=250", url, if url.contains('?') {{ '&' }} else {{ '?' }});
+            let (mut link, resp): (Option<crate::utils::NextLink>, {}) = self.client.request_with_links(reqwest::Method::GET, &url, crate::Message {{ body: None, content_type: None }}).await?;
+
+            let mut {} = resp.{};
+
+            // Follow the `page_info` cursors in the `Link` header.
+            while let Some(next) = link {{
+                let (next_link, mut resp): (Option<crate::utils::NextLink>, {}) = self.client.request_with_links(reqwest::Method::GET, &next.0, crate::Message {{ body: None, content_type: None }}).await?;
+
+                {}.append(&mut resp.{});
+
+                link = next_link.filter(|l| l.0 != next.0);
+            }}
+
+            // Return our response data.
+            Ok({})"#,
+            response_type,
+            pagination_property,
+            pagination_property,
+            response_type,
+            pagination_property,
+            pagination_property,
+            pagination_property,
+        );
+
         return Ok(inner);
     } else if all_pages && !pagination_property.is_empty() {
         bail!(
@@ -1099,7 +1402,7 @@ fn get_fn_inner(
     {
         if inner_response_type.is_empty() {
             return Ok(format!(
-                "self.client.{}(&url, crate::Message {{ body: {}, content_type: {content_type} }} ).await",
+                "self.client.{}{suffix}(&url, crate::Message {{ body: {}, content_type: {content_type} }} ).await",
                 m.to_lowercase(),
                 body
             ));
@@ -1111,6 +1414,20 @@ fn get_fn_inner(
             ""
         };
 
+        if with_response {
+            return Ok(format!(
+                r#"let resp: crate::Response<{}> = self.client.{}{suffix}(&url, crate::Message {{ body: {}, content_type: {content_type} }}).await?;
+
+                // Return our response data.
+                Ok(resp.map(|r| r.{}{}))"#,
+                response_type,
+                m.to_lowercase(),
+                body,
+                pagination_property,
+                additional
+            ));
+        }
+
         // Okay we have an inner response type, let's return that instead.
         return Ok(format!(
             r#"let resp: {} = self.client.{}(&url, crate::Message {{ body: {}, content_type: {content_type} }}).await?;
@@ -1129,7 +1446,7 @@ fn get_fn_inner(
         bail!("function {} should be authenticated", oid);
     }
 
-    Ok(format!(
+    let inner = format!(
         r#"self.client.post_media(
             &url,
             crate::Message {{
@@ -1139,7 +1456,14 @@ fn get_fn_inner(
             crate::utils::MediaType::Json,
             crate::auth::AuthenticationConstraint::JWT,
         ).await"#
-    ))
+    );
+
+    // The media functions on the client always return the whole response.
+    if with_response {
+        Ok(inner)
+    } else {
+        Ok(format!("Ok({inner}?.body)"))
+    }
 }
 
 fn get_fn_docs(
@@ -1158,7 +1482,10 @@ fn get_fn_docs(
 
     a("/**");
     if let Some(summary) = &o.summary {
-        a(&format!("* {}.", summary.trim_end_matches('.')));
+        a(&format!(
+            "* {}.",
+            summary.trim_end_matches('.').replace('\n', "\n* ")
+        ));
         a("*");
     }
     a(&format!(
@@ -1197,7 +1524,17 @@ fn get_fn_docs(
         let parameter_data = get_parameter_data(item).unwrap();
 
         let pid = ts.select_param(None, par)?;
-        let mut docs = ts.render_docs(&pid);
+        // Basic types are shared between every schema of that type, so an inline
+        // schema has to bring its own description rather than take the shared one.
+        let mut docs = match &parameter_data.format {
+            openapiv3::ParameterSchemaOrContent::Schema(openapiv3::ReferenceOr::Item(s)) => s
+                .schema_data
+                .description
+                .as_ref()
+                .map(|d| format!("* {}", d.replace('*', "\\*").replace('\n', "\n*  ")))
+                .unwrap_or_default(),
+            _ => ts.render_docs(&pid),
+        };
         if let Some(d) = &parameter_data.description {
             if !d.is_empty() && d.len() > docs.len() {
                 docs = format!(" -- {}.", d.trim_end_matches('.').replace('\n', "\n*   "));
@@ -1234,6 +1571,21 @@ fn get_fn_docs(
     Ok(out.trim().to_string())
 }
 
+/*
+ * Add the docs for the file contents parameter of a multipart function.
+ */
+fn add_file_param_docs(docs: &str) -> String {
+    let mut out = docs.trim_end_matches("*/").to_string();
+    if !out.contains("* **Parameters:**") {
+        out.push_str("*\n* **Parameters:**\n*\n");
+    }
+    out.push_str(
+        "* * `file: Option<&[u8]>` -- The file contents, sent as `multipart/form-data`.\n*/",
+    );
+
+    out
+}
+
 fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
     let mut out = String::new();
 
@@ -1244,7 +1596,10 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
 
     a("/**");
     if let Some(summary) = &o.summary {
-        a(&format!("* {}.", summary.trim_end_matches('.')));
+        a(&format!(
+            "* {}.",
+            summary.trim_end_matches('.').replace('\n', "\n* ")
+        ));
         a("*");
     }
     a(&format!(
@@ -1269,6 +1624,324 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
+fn get_fn_docs_response(
+    o: &openapiv3::Operation,
+    m: &str,
+    p: &str,
+    fn_name: &str,
+) -> Result<String> {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/**");
+    // The first line is enough, the rest of the summary is on the other function.
+    if let Some(summary) = o.summary.as_ref().and_then(|s| s.lines().next()) {
+        a(&format!(
+            "* {}.",
+            summary.trim_end_matches('.').replace('\n', "\n* ")
+        ));
+        a("*");
+    }
+    a(&format!(
+        "* This function performs a `{}` to the `{}` endpoint.",
+        m, p
+    ));
+    a("*");
+    a(&format!(
+        "* As opposed to `{}`, this function returns the whole response: the status code, \
+         headers and raw bytes along with the parsed body.",
+        fn_name
+    ));
+    if let Some(external_docs) = &o.external_docs {
+        a("*");
+        a(&format!("* FROM: <{}>", external_docs.url));
+    }
+    a("*/");
+
+    Ok(out.trim().to_string())
+}
+
+fn print_stream_fn(
+    docs: &str,
+    fn_params_str: &[String],
+    item_type: &str,
+    template: &str,
+    fn_inner: &str,
+    fn_name: &str,
+) -> String {
+    format!(
+        r#"{}
+        pub fn {}(&self, {}) -> impl futures::Stream<Item = ClientResult<{}>> + Unpin + '_ {{
+            {}
+            {}
+        }}"#,
+        docs,
+        fn_name,
+        fn_params_str.join(" "),
+        item_type,
+        template,
+        fn_inner
+    )
+}
+
+/// Get the inside of a function that streams the pages of a request. Each page
+/// is fetched as the stream gets to it, starting from the page for `page_cursor`.
+fn get_fn_inner_pages(
+    proper_name: &str,
+    response_type: &str,
+    item_type: &str,
+    pagination_property: &str,
+) -> Option<String> {
+    // Get the page for the cursor, and the cursor for the next page.
+    let fetch = if pagination_property.is_empty() {
+        format!(
+            r#"let (link, items): (Option<crate::utils::NextLink>, Vec<{}>) = self.client.get_pages(cursor.as_deref().unwrap_or(&url)).await?;
+            let next = link.map(|l| l.0);"#,
+            item_type
+        )
+    } else if proper_name.starts_with("Stripe") {
+        format!(
+            r#"let url = match &cursor {{
+                Some(c) => format!("{{}}{{}}starting_after={{}}", url, if url.contains('?') {{ '&' }} else {{ '?' }}, c),
+                None => url,
+            }};
+            let mut resp: {} = self.client.get(&url, crate::Message {{ body: None, content_type: None }}).await?;
+
+            let next = if resp.has_more {{
+                resp.{}.last().and_then(|last| {{
+                    serde_json::json!(last).get("id").and_then(|id| id.as_str()).map(|id| id.to_string())
+                }})
+            }} else {{
+                None
+            }};
+            // Some of these are boxed, so drain them out.
+            let items = resp.{}.drain(..).collect();"#,
+            response_type, pagination_property, pagination_property
+        )
+    } else if proper_name.starts_with("Google") || proper_name == "Zoom" {
+        let param = if proper_name == "Zoom" {
+            "next_page_token"
+        } else {
+            "pageToken"
+        };
+        format!(
+            r#"let url = match &cursor {{
+                Some(c) => format!("{{}}{{}}{}={{}}", url, if url.contains('?') {{ '&' }} else {{ '?' }}, c),
+                None => url,
+            }};
+            let resp: {} = self.client.get(&url, crate::Message {{ body: None, content_type: None }}).await?;
+
+            let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+            let items = resp.{};"#,
+            param, response_type, pagination_property
+        )
+    } else if proper_name == "Ramp" {
+        format!(
+            r#"let resp: {} = match &cursor {{
+                Some(c) => match self.client.get(c.trim_start_matches(&self.client.host), crate::Message {{ body: None, content_type: None }}).await {{
+                    Ok(resp) => resp,
+                    // Ramp sends a 404 when we run out of pages.
+                    Err(crate::ClientError::HttpError {{ status: http::StatusCode::NOT_FOUND, .. }}) => return Ok(None),
+                    Err(e) => return Err(e),
+                }},
+                None => self.client.get(&url, crate::Message {{ body: None, content_type: None }}).await?,
+            }};
+
+            let next = Some(resp.page.next).filter(|n| !n.is_empty());
+            let items = resp.{};"#,
+            response_type, pagination_property
+        )
+    } else if proper_name == "Shopify" {
+        format!(
+            r#"let url = match &cursor {{
+                Some(c) => c.to_string(),
+                None => format!("{{}}{{}}limit=250", url, if url.contains('?') {{ '&' }} else {{ '?' }}),
+            }};
+            let (link, resp): (Option<crate::utils::NextLink>, {}) = self.client.request_with_links(reqwest::Method::GET, &url, crate::Message {{ body: None, content_type: None }}).await?;
+
+            // The cursor is the `Link` to the next page, with its `page_info`.
+            let next = link.map(|l| l.0);
+            let items = resp.{};"#,
+            response_type, pagination_property
+        )
+    } else if proper_name == "TripActions" {
+        format!(
+            r#"let page = cursor.as_deref().unwrap_or("0");
+            let url = format!("{{}}{{}}page={{}}&size=100", url, if url.contains('?') {{ '&' }} else {{ '?' }}, page);
+            let resp: {} = self.client.get(&url, crate::Message {{ body: None, content_type: None }}).await?;
+
+            let next_page = resp.page.current_page + 1;
+            let next = if next_page < resp.page.total_pages {{
+                Some(next_page.to_string())
+            }} else {{
+                None
+            }};
+            let items = resp.{};"#,
+            response_type, pagination_property
+        )
+    } else {
+        return None;
+    };
+
+    Some(format!(
+        r#"Box::pin(futures::stream::try_unfold(Some(page_cursor), move |cursor| {{
+            let url = url.clone();
+            async move {{
+                // We are out of pages.
+                let cursor = match cursor {{
+                    Some(cursor) => cursor,
+                    None => return Ok(None),
+                }};
+
+                {}
+
+                // Don't keep asking for the same page.
+                let next = next.filter(|n| Some(n) != cursor.as_ref());
+                Ok(Some((crate::utils::Page {{ items, next: next.clone() }}, next.map(Some))))
+            }}
+        }}))"#,
+        fetch
+    ))
+}
+
+fn get_fn_docs_stream(
+    o: &openapiv3::Operation,
+    m: &str,
+    p: &str,
+    fn_name: &str,
+    pages: bool,
+) -> Result<String> {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/**");
+    if let Some(summary) = &o.summary {
+        a(&format!(
+            "* {}.",
+            summary.trim_end_matches('.').replace('\n', "\n* ")
+        ));
+        a("*");
+    }
+    a(&format!(
+        "* This function performs a `{}` to the `{}` endpoint.",
+        m, p
+    ));
+    a("*");
+    if pages {
+        a(&format!(
+            "* As opposed to `{}`, this function returns a stream of the pages of the request, \
+             only fetching each page when the stream gets to it. Each page has the `next` cursor, \
+             pass it in as `page_cursor` to pick up where you left off.",
+            fn_name
+        ));
+    } else {
+        a(&format!(
+            "* As opposed to `{}`, this function returns a stream of the items of the request, \
+             only fetching each page when the stream gets to it.",
+            fn_name
+        ));
+    }
+    if let Some(external_docs) = &o.external_docs {
+        a("*");
+        a(&format!("* FROM: <{}>", external_docs.url));
+    }
+    a("*/");
+
+    Ok(out.trim().to_string())
+}
+
+/// Get the inside of a Google Calendar sync function, this returns all the pages
+/// in the response along with the `nextSyncToken` from the last page.
+fn get_fn_inner_sync(response_type: &str, pagination_property: &str) -> String {
+    format!(
+        r#"let mut resp: {} = self.client.get(&url, crate::Message {{ body: None, content_type: None }}).await.map_err(crate::utils::full_sync_required)?;
+
+        let mut {} = resp.{};
+        let mut page = resp.next_page_token.to_string();
+
+        // Paginate if we should.
+        while !page.is_empty() {{
+            if !url.contains('?') {{
+                resp = self.client.get(&format!("{{}}?pageToken={{}}", url, page), crate::Message {{ body: None, content_type: None }}).await.map_err(crate::utils::full_sync_required)?;
+            }} else {{
+                resp = self.client.get(&format!("{{}}&pageToken={{}}", url, page), crate::Message {{ body: None, content_type: None }}).await.map_err(crate::utils::full_sync_required)?;
+            }}
+
+            {}.append(&mut resp.{});
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {{
+                page = resp.next_page_token.to_string();
+            }} else {{
+                page = "".to_string();
+            }}
+        }}
+
+        // The last page has the next sync token, give it back with all the items.
+        resp.{} = {};
+        Ok(resp)"#,
+        response_type,
+        pagination_property,
+        pagination_property,
+        pagination_property,
+        pagination_property,
+        pagination_property,
+        pagination_property,
+    )
+}
+
+fn get_fn_docs_sync(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/**");
+    if let Some(summary) = &o.summary {
+        a(&format!(
+            "* {}.",
+            summary.trim_end_matches('.').replace('\n', "\n* ")
+        ));
+        a("*");
+    }
+    a(&format!(
+        "* This function performs a `{}` to the `{}` endpoint.",
+        m, p
+    ));
+    a("*");
+    a(&format!(
+        "* As opposed to `{}`, this function returns all the pages of the request at once, \
+         along with the `next_sync_token` to pass as `sync_token` the next time you sync. \
+         Leave `sync_token` empty to do a full sync.",
+        fn_name
+    ));
+    a("*");
+    a(
+        "* If the sync token has expired this returns `ClientError::FullSyncRequired`, \
+       clear your storage and do a full sync.",
+    );
+    if let Some(description) = &o.description {
+        a("*");
+        a(&format!("* {}", description.replace('\n', "\n* ")));
+    }
+    if let Some(external_docs) = &o.external_docs {
+        a("*");
+        a(&format!("* FROM: <{}>", external_docs.url));
+    }
+    a("*/");
+
+    Ok(out.trim().to_string())
+}
+
 fn is_page_param(s: &str, proper_name: &str) -> bool {
     s == "page"
         || s == "per_page"
@@ -1285,6 +1958,21 @@ fn is_page_param(s: &str, proper_name: &str) -> bool {
         || s == "ending_before"
         || (s == "after" && proper_name == "Okta")
         || (s == "starting_after" && proper_name == "Stripe")
+        || (s == "page_info" && proper_name == "Shopify")
+}
+
+/// The Google Calendar list filters that can't be combined with a sync token.
+/// FROM: https://developers.google.com/calendar/api/guides/sync
+fn is_google_calendar_sync_filter(s: &str) -> bool {
+    s == "i_cal_uid"
+        || s == "min_access_role"
+        || s == "order_by"
+        || s == "private_extended_property"
+        || s == "q"
+        || s == "shared_extended_property"
+        || s == "time_max"
+        || s == "time_min"
+        || s == "updated_min"
 }
 
 fn is_google_unnecessary_param(proper_name: &str, s: &str) -> bool {
@@ -1294,11 +1982,9 @@ fn is_google_unnecessary_param(proper_name: &str, s: &str) -> bool {
         || s == "pretty_print"
         || s == "xgafv"
         || s == "custom_field_mask"
-        || s == "sync_token"
         || s == "user_ip"
         || s == "quota_user"
         || s == "key"
-        || s == "fields"
         || s == "callback"
         || s == "upload_protocol"
         || s == "upload_type"
@@ -1326,7 +2012,3 @@ fn is_okta_unnecessary_param(s: &str) -> bool {
 fn is_shipbob_unnecessary_param(s: &str) -> bool {
     s == "shipbob_channel_id"
 }
-
-fn is_stripe_unnecessary_param(s: &str) -> bool {
-    s == "expand"
-}
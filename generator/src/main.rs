@@ -1,11 +1,12 @@
 mod client;
 mod functions;
+mod shopify;
 mod template;
 mod types;
 mod utils;
 
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     ffi::OsStr,
     fs::{File, OpenOptions},
     io::Write,
@@ -745,6 +746,22 @@ pub struct TypeSpace {
      */
     name_to_id: BTreeMap<String, TypeId>,
     id_to_entry: BTreeMap<TypeId, TypeEntry>,
+    /*
+     * The types declared inline as request bodies, these are only ever sent
+     * and never returned, with the names of their nullable properties.
+     */
+    request_bodies: BTreeMap<TypeId, BTreeSet<String>>,
+    /*
+     * The descriptions of the properties of object types, by property name.
+     * Types with the same shape share an id, so the docs of the type a
+     * property points at can belong to any other property of that type.
+     */
+    property_docs: BTreeMap<TypeId, BTreeMap<String, Option<String>>>,
+    /*
+     * Render objects that only have additional properties, ie. Stripe's
+     * metadata, as maps instead of as the type of their values.
+     */
+    typed_maps: bool,
 }
 
 impl TypeSpace {
@@ -753,6 +770,9 @@ impl TypeSpace {
             next_id: 1,
             name_to_id: BTreeMap::new(),
             id_to_entry: BTreeMap::new(),
+            request_bodies: BTreeMap::new(),
+            property_docs: BTreeMap::new(),
+            typed_maps: false,
         }
     }
 
@@ -894,10 +914,7 @@ impl TypeSpace {
 
         if let Some(s) = schema {
             if let Some(description) = &s.description {
-                a(&format!(
-                    "* {}",
-                    description.replace('*', "\\*").replace('\n', "\n*  ")
-                ));
+                a(&render_description(description));
             }
             if let Some(external_docs) = &s.external_docs {
                 a("*");
@@ -1002,6 +1019,7 @@ impl TypeSpace {
                     if rt == "String"
                         || rt.starts_with("Vec<")
                         || rt.starts_with("std::collections::HashMap<")
+                        || rt.starts_with("std::collections::BTreeMap<")
                         || rt == "bool"
                         || rt == "i32"
                         || rt == "i64"
@@ -1423,8 +1441,29 @@ impl TypeSpace {
     ) -> Result<TypeId> {
         let (n, details) =
             self.get_type_name_and_details(name, s, parent_name, additional_description)?;
+        let is_object = details.is_object();
+
+        let id = self.add_if_not_exists(n, details, parent_name, false)?;
+
+        if let openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) = &s.schema_kind {
+            if is_object && !self.property_docs.contains_key(&id) {
+                // Only the properties declared inline have a description of their
+                // own, references are documented by the type they point at.
+                let docs = o
+                    .properties
+                    .iter()
+                    .filter_map(|(n, p)| match p {
+                        openapiv3::ReferenceOr::Item(p) => {
+                            Some((n.to_string(), p.schema_data.description.clone()))
+                        }
+                        openapiv3::ReferenceOr::Reference { .. } => None,
+                    })
+                    .collect();
+                self.property_docs.insert(id.clone(), docs);
+            }
+        }
 
-        self.add_if_not_exists(n, details, parent_name, false)
+        Ok(id)
     }
 
     fn get_type_name_and_details(
@@ -1559,6 +1598,16 @@ impl TypeSpace {
                                 name = format!("{} additional properties", name);
                             }
                             let id = self.select(Some(&name), ad, &desc)?;
+                            if self.typed_maps {
+                                let rt = self.render_type(&id, true)?;
+                                return Ok((
+                                    Some(name.to_string()),
+                                    TypeDetails::Basic(
+                                        format!("std::collections::BTreeMap<String, {}>", rt),
+                                        s.schema_data.clone(),
+                                    ),
+                                ));
+                            }
                             return Ok((
                                 Some(name.to_string()),
                                 TypeDetails::NamedType(id, s.schema_data.clone()),
@@ -2190,6 +2239,10 @@ fn render_param(
         {
             continue;
         }
+        // Values like "unknown" are kept by the `Unknown(String)` wildcard.
+        if struct_name(e) == "Unknown" {
+            continue;
+        }
         enums.push(e.to_string());
     }
 
@@ -2200,6 +2253,8 @@ fn render_param(
     }
 
     a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    // Go through strings so values we do not know about survive a round trip.
+    a(r#"#[serde(from = "String", into = "String")]"#);
 
     a(&format!("pub enum {} {{", sn));
     for e in &enums {
@@ -2216,8 +2271,9 @@ fn render_param(
     }
 
     // Let's add the wildcard.
-    a("#[serde(other)]");
-    a("FallthroughString");
+    a("/// A value not known to this version of the client, kept as it was sent.");
+    a("#[schemars(skip)]");
+    a("Unknown(String),");
 
     a("}");
     a("");
@@ -2237,7 +2293,7 @@ fn render_param(
     }
 
     // Let's add the display format for the wildcard.
-    a(&format!(r#"{}::FallthroughString => "*","#, sn));
+    a(&format!(r#"{}::Unknown(s) => s.as_str(),"#, sn));
 
     a("}");
     a(".fmt(f)");
@@ -2245,6 +2301,34 @@ fn render_param(
     a("}");
     a("");
 
+    a(&format!("impl std::convert::From<String> for {} {{", sn));
+    a(&format!("fn from(s: String) -> {} {{", sn));
+    a("match s.as_str() {");
+    for e in &enums {
+        if struct_name(e).is_empty() {
+            continue;
+        }
+        a(&format!(r#""{}" => {}::{},"#, e, sn, struct_name(e)));
+    }
+    if !required && default.is_none() {
+        a(&format!(r#""" => {}::Noop,"#, sn));
+    }
+    a(&format!("_ => {}::Unknown(s),", sn));
+    a("}");
+    a("}");
+    a("}");
+    a("");
+
+    a(&format!("impl std::convert::From<{}> for String {{", sn));
+    a(&format!("fn from(e: {}) -> String {{", sn));
+    a("match e {");
+    a(&format!("{}::Unknown(s) => s,", sn));
+    a("e => e.to_string(),");
+    a("}");
+    a("}");
+    a("}");
+    a("");
+
     // Add a default for the enum if it is not required.
     if !required || default.is_some() {
         a(&format!("impl Default for {} {{", sn));
@@ -2253,11 +2337,12 @@ fn render_param(
             // Use the default that can be passed to the OpenAPI,
             // github is not using that currently for everything but we might want to
             // in the future.
-            a(&format!(
-                "{}::{}",
-                sn,
-                struct_name(&d.to_string().replace('"', ""))
-            ));
+            let d = d.to_string().replace('"', "");
+            if struct_name(&d) == "Unknown" {
+                a(&format!(r#"{}::Unknown("{}".to_string())"#, sn, d));
+            } else {
+                a(&format!("{}::{}", sn, struct_name(&d)));
+            }
         } else {
             a(&format!("{}::Noop", sn));
         }
@@ -2313,6 +2398,13 @@ fn gen(
         a(r#"#[cfg(feature = "httpcache")]"#);
         a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
         a("pub mod http_cache;");
+        a("pub mod webhooks;");
+    }
+    if proper_name == "Stripe" || proper_name == "Slack" || proper_name == "Shopify" {
+        a("pub mod webhooks;");
+    }
+    if proper_name == "Slack" {
+        a("pub mod blocks;");
     }
     if proper_name == "Google Drive"
         || proper_name == "Google Sheets"
@@ -2391,6 +2483,20 @@ pub enum ClientError {"#);
             /// JWT errors from auth.rs
             #[error(transparent)]
             JsonWebTokenError(#[from] jsonwebtoken::errors::Error),
+            /// Webhook signature errors from webhooks.rs
+            #[error("Invalid webhook signature: {0}")]
+            InvalidWebhookSignature(String),
+            /// A webhook delivery is missing a header or has a malformed body
+            #[error("Invalid webhook payload: {0}")]
+            InvalidWebhookPayload(String),
+            /// GitHub responded with an error, `body` is the raw response
+            #[error("GitHub Error. Code: {status}, message: {}", .error.message)]
+            GitHubError {
+                status: http::StatusCode,
+                error: Box<crate::utils::GitHubApiError>,
+                body: String,
+                request_id: Option<String>,
+            },
             /// IO Errors
             #[cfg(feature = "httpcache")]
             #[error(transparent)]
@@ -2424,12 +2530,118 @@ pub enum ClientError {"#);
         ToStrError(#[from] reqwest::header::ToStrError),"#);
     }
 
+    // Slack only, it returns errors in the body of a successful response.
+    if proper_name == "Slack" {
+        a(r#"
+        /// Slack responded with `ok: false`
+        #[error("Slack Error: {error}")]
+        SlackError{
+            error: String,
+            warning: String,
+            response_metadata: Option<crate::utils::SlackResponseMetadata>,
+            request_id: Option<String>,
+        },
+        /// Ratelimited
+        #[error("Rate limited for the next {duration} seconds")]
+        RateLimited{
+            duration: u64,
+        },
+        /// Webhook signature errors from webhooks.rs
+        #[error("Invalid webhook signature: {0}")]
+        InvalidWebhookSignature(String),
+        /// A webhook delivery is missing a header or has a malformed body
+        #[error("Invalid webhook payload: {0}")]
+        InvalidWebhookPayload(String),
+        /// Block Kit validation errors from blocks.rs
+        #[error("Invalid Block Kit payload: {0}")]
+        InvalidBlocks(String),"#);
+    }
+
+    // Stripe only, it has its own error envelope.
+    if proper_name == "Stripe" {
+        a(r#"
+        /// Stripe responded with an error, `body` is the raw response
+        #[error("Stripe Error. Code: {status}, type: {}, message: {}", .error.type_, .error.message)]
+        StripeError {
+            status: http::StatusCode,
+            error: Box<crate::utils::StripeApiError>,
+            body: String,
+            request_id: Option<String>,
+        },
+        /// Webhook signature errors from webhooks.rs
+        #[error("Invalid webhook signature: {0}")]
+        InvalidWebhookSignature(String),"#);
+    }
+
+    // Shopify only, for installing the app on a shop.
+    if proper_name == "Shopify" {
+        a(r#"
+        /// The shop is not a `*.myshopify.com` domain
+        #[error("Invalid shop domain: {0}")]
+        InvalidShopDomain(String),
+        /// The redirect back from Shopify failed the `hmac` check
+        #[error("Invalid OAuth callback: {0}")]
+        InvalidOAuthCallback(String),
+        /// Webhook signature errors from webhooks.rs
+        #[error("Invalid webhook signature: {0}")]
+        InvalidWebhookSignature(String),
+        /// A webhook delivery is missing a header or has a malformed body
+        #[error("Invalid webhook payload: {0}")]
+        InvalidWebhookPayload(String),"#);
+    }
+
+    // Okta only, it has its own error envelope.
+    if proper_name == "Okta" {
+        a(r#"
+        /// Okta responded with an error, `body` is the raw response
+        #[error("Okta Error. Code: {status}, {}: {}", .error.error_code, .error.error_summary)]
+        OktaError {
+            status: http::StatusCode,
+            error: Box<crate::utils::OktaApiError>,
+            body: String,
+            request_id: Option<String>,
+        },"#);
+    }
+
+    // Google only, for its error envelope and service accounts.
+    if proper_name.starts_with("Google") {
+        a(r#"
+        /// Google responded with an error, `body` is the raw response
+        #[error("Google Error. Code: {status}, message: {}", .error.message)]
+        GoogleError {
+            status: http::StatusCode,
+            error: Box<crate::utils::GoogleApiError>,
+            body: String,
+            request_id: Option<String>,
+        },
+        /// Errors minting service account tokens
+        #[error(transparent)]
+        OAuth2Error(#[from] yup_oauth2::Error),
+        /// IO Errors
+        #[error(transparent)]
+        IoError(#[from] std::io::Error),"#);
+    }
+
+    // Google Calendar only, for syncing with a sync token.
+    if proper_name == "Google Calendar" {
+        a(r#"
+        /// The sync token has expired, clear your storage and do a full sync
+        #[error("Sync token expired, a full sync is required")]
+        FullSyncRequired,"#);
+    }
+
     a(r#"/// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// A response did not match the generated types, in strict mode
+    #[error("Unexpected response at `{path}`: {error}")]
+    DeserializationError {
+        path: String,
+        error: String,
+    },
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -2444,6 +2656,7 @@ pub enum ClientError {"#);
     HttpError {
         status: http::StatusCode,
         error: String,
+        request_id: Option<String>,
     },
 }
 "#);
@@ -2456,6 +2669,23 @@ pub enum ClientError {"#);
     ));
     a("");
 
+    a("/// The header the API sends the id of a request back in.");
+    a(&format!(
+        r#"pub const REQUEST_ID_HEADER: &str = "{}";"#,
+        request_id_header(proper_name)
+    ));
+    a("");
+
+    // The Shopify client puts the API version in the URL itself.
+    if proper_name == "Shopify" {
+        a("/// The Admin API version requests go to, unless set on the `Client`.");
+        a(&format!(
+            r#"pub const DEFAULT_API_VERSION: &str = "{}";"#,
+            shopify::API_VERSION
+        ));
+        a("");
+    }
+
     a("mod progenitor_support {");
     a("    use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};");
     a("");
@@ -2495,6 +2725,39 @@ pub enum ClientError {"#);
 pub(crate) struct Message {
     pub body: Option<reqwest::Body>,
     pub content_type: Option<String>,
+}
+
+/// A response from the API: the status code, headers and raw bytes of the
+/// response, along with the parsed body.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub raw: bytes::Bytes,
+    pub body: T,
+}
+
+impl<T> Response<T> {
+    /// Maps the parsed body, keeping the rest of the response.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            status: self.status,
+            headers: self.headers,
+            raw: self.raw,
+            body: f(self.body),
+        }
+    }
+
+    /// The id the API gave this request, if it sent one. This is the id to hand
+    /// to the API's support when something goes wrong.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers.get(REQUEST_ID_HEADER).and_then(|v| v.to_str().ok())
+    }
+}
+
+/// The id the API gave a request, kept on the errors so it can be logged.
+pub(crate) fn request_id(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers.get(REQUEST_ID_HEADER).and_then(|v| v.to_str().ok()).map(|v| v.to_string())
 }"#);
 
     a("");
@@ -2620,6 +2883,16 @@ pub fn make_plural(proper_name: &str, s: &str) -> String {
     format!("{}s", s)
 }
 
+/**
+ * Render a description as the lines of a doc comment.
+ */
+fn render_description(description: &str) -> String {
+    format!(
+        "* {}",
+        description.replace('*', "\\*").replace('\n', "\n*  ")
+    )
+}
+
 fn struct_name(s: &str) -> String {
     let t = to_title_case(&clean_name(s)).replace(' ', "");
 
@@ -2631,6 +2904,10 @@ fn struct_name(s: &str) -> String {
     } else if t == "Option" || t == "Self" {
         // Fix any reserved words.
         format!("{}Data", t)
+    } else if t.starts_with(|c: char| c.is_ascii_digit()) {
+        // Identifiers can't start with a number, this happens for the
+        // Stripe API version enums.
+        format!("V{}", t)
     } else {
         t
     }
@@ -2889,6 +3166,58 @@ fn oid_to_object_name(s: &str) -> String {
     cleaned
 }
 
+/*
+ * The optional, nullable properties of an object schema. We can't get these
+ * from the type space, since basic types are shared no matter their schema
+ * data. Required properties are always sent, so an Option already covers them.
+ */
+fn nullable_properties(schema: &openapiv3::Schema) -> BTreeSet<String> {
+    let mut nullable = BTreeSet::new();
+    if let openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) = &schema.schema_kind {
+        for (name, property) in &o.properties {
+            if let openapiv3::ReferenceOr::Item(p) = property {
+                if p.schema_data.nullable && !o.required.contains(name) {
+                    nullable.insert(name.to_string());
+                }
+            }
+        }
+    }
+    nullable
+}
+
+/*
+ * The header each API sends the id of a request back in.
+ */
+fn request_id_header(proper_name: &str) -> &'static str {
+    match proper_name {
+        "DocuSign" => "x-docusign-tracetoken",
+        "GitHub" => "x-github-request-id",
+        "Okta" => "x-okta-request-id",
+        "Slack" => "x-slack-req-id",
+        "Stripe" => "request-id",
+        "Zoom" => "x-zm-trackingid",
+        _ => "x-request-id",
+    }
+}
+
+/*
+ * The nullable properties of a request body schema, following references into
+ * the component schemas, since bodies are often declared there.
+ */
+fn request_body_nullable_properties(
+    api: &openapiv3::OpenAPI,
+    schema: &openapiv3::ReferenceOr<openapiv3::Schema>,
+) -> BTreeSet<String> {
+    match schema {
+        openapiv3::ReferenceOr::Item(item) => nullable_properties(item),
+        openapiv3::ReferenceOr::Reference { reference } => reference
+            .strip_prefix("#/components/schemas/")
+            .and_then(|name| api.components.as_ref()?.schemas.get(name))
+            .map(|s| request_body_nullable_properties(api, s))
+            .unwrap_or_default(),
+    }
+}
+
 fn main() -> Result<()> {
     let mut opts = getopts::Options::new();
     opts.parsing_style(getopts::ParsingStyle::StopAtFirstFree);
@@ -2928,6 +3257,11 @@ fn main() -> Result<()> {
         "A header to add to post requests",
         "ADD_POST_HEADER",
     );
+    opts.optflag(
+        "",
+        "patch-nullable",
+        "Use Patch<T> for nullable fields in request bodies, so they can be cleared",
+    );
     opts.optflag("", "debug", "Print debug output");
 
     let args = match opts.parse(std::env::args().skip(1)) {
@@ -2944,7 +3278,14 @@ fn main() -> Result<()> {
         }
     };
 
-    let api = load_api(args.opt_str("i").unwrap())?;
+    let mut api = load_api(args.opt_str("i").unwrap())?;
+    let proper_name = args.opt_str("proper-name").unwrap();
+
+    // Shopify's spec does not describe any request or response bodies, we
+    // bring our own for the core resources.
+    if proper_name == "Shopify" {
+        shopify::patch_spec(&mut api)?;
+    }
 
     let servers = client::generate_servers(&api.servers, "Root");
 
@@ -2958,6 +3299,7 @@ fn main() -> Result<()> {
      * Grab all the types defined by schemas and parameters.
      */
     let mut ts = TypeSpace::new();
+    ts.typed_maps = proper_name == "Stripe";
     let mut parameters: BTreeMap<String, &openapiv3::Parameter> = BTreeMap::new();
 
     if let Some(components) = &api.components {
@@ -3097,7 +3439,6 @@ fn main() -> Result<()> {
      * In addition to types defined in schemas, types may be defined inline in
      * request and response bodies.
      */
-    let proper_name = args.opt_str("proper-name").unwrap();
     let mut tags: Vec<String> = Default::default();
     for (pn, p) in api.paths.iter() {
         let op = p.item()?;
@@ -3167,10 +3508,18 @@ fn main() -> Result<()> {
                 let mut req: Vec<String> = Default::default();
                 if let Some(openapiv3::ReferenceOr::Item(body)) = &o.request_body {
                     for (ct, mt) in &body.content {
-                        if ct == "application/json" {
+                        if ct == "application/json"
+                            || (ct == "application/x-www-form-urlencoded"
+                                && (proper_name == "Stripe" || proper_name == "Slack"))
+                            || (ct == "multipart/form-data" && proper_name == "Stripe")
+                        {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
                                 let id = ts.select(Some(&object_name), s, "")?;
+                                ts.request_bodies
+                                    .entry(id.clone())
+                                    .or_default()
+                                    .extend(request_body_nullable_properties(&api, s));
                                 let rt = ts.render_type(&id, true)?;
                                 req.push(format!("{} {:?}", rt, id));
                             }
@@ -3182,6 +3531,17 @@ fn main() -> Result<()> {
                     &o.request_body
                 {
                     let id = ts.select_ref(None, reference.as_str())?;
+                    if let Some(s) = reference
+                        .strip_prefix("#/components/requestBodies/")
+                        .and_then(|name| api.components.as_ref()?.request_bodies.get(name))
+                        .and_then(|body| body.as_item()?.content.get("application/json"))
+                        .and_then(|mt| mt.schema.as_ref())
+                    {
+                        ts.request_bodies
+                            .entry(id.clone())
+                            .or_default()
+                            .extend(request_body_nullable_properties(&api, s));
+                    }
                     req.push(format!("{:?}", id));
                 }
                 if !req.is_empty() {
@@ -3295,6 +3655,7 @@ fn main() -> Result<()> {
     } else {
         String::new()
     };
+    let patch_nullable = args.opt_present("patch-nullable");
     let fail = match gen(
         &api,
         &proper_name,
@@ -3319,19 +3680,31 @@ fn main() -> Result<()> {
              */
             let mut uuid_lib = "".to_string();
             let mut yup_oauth2_lib = "".to_string();
+            let mut base64_dev_lib = r#"
+base64 = "^0.13""#
+                .to_string();
             if proper_name != "GitHub" {
                 uuid_lib = r#"
-bytes = { version = "1", features = ["serde"] }
-async-trait = "^0.1.51"
 uuid = { version = "1.1", features = ["serde", "v4"] }"#
                     .to_string();
             }
 
+            // Two versions of base64 are ambiguous to the tests, so the clients
+            // that need base64 themselves don't get the one for the tests.
             if proper_name.starts_with("Google") {
                 yup_oauth2_lib = r#"
 base64 = "^0.21"
 yup-oauth2 = "^8""#
                     .to_string();
+                base64_dev_lib = "".to_string();
+            }
+
+            // Shopify signs its webhooks with a base64 encoded HMAC.
+            if proper_name == "Shopify" {
+                yup_oauth2_lib = r#"
+base64 = "^0.21""#
+                    .to_string();
+                base64_dev_lib = "".to_string();
             }
 
             let mut toml = root.clone();
@@ -3352,12 +3725,15 @@ default = ["rustls-tls", "reqwest-tracing/opentelemetry_0_17"]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
 native-tls = ["reqwest/default-tls", "openssl"]
-rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
+rustls-tls = ["reqwest/rustls-tls", "pem"]
 
 [dependencies]
 async-recursion = "^1.0"
-chrono = {{ version = "0.4", default-features = false, features = ["serde"] }}
+async-trait = "^0.1.51"
+bytes = {{ version = "1", features = ["serde"] }}
+chrono = {{ version = "0.4", default-features = false, features = ["serde", "std"] }}
 dirs = {{ version = "^3.0.2", optional = true }}
+futures = "0.3"
 http = "^0.2.4"
 jsonwebtoken = "8"
 log = {{ version = "^0.4", features = ["serde"] }}
@@ -3367,21 +3743,20 @@ parse_link_header = "0.3.3"
 pem = {{ version = "1.1.0",  default-features = false, optional = true }}
 percent-encoding = "2.2"
 reqwest = {{ version = "0.11.14", default-features = false, features = ["json", "multipart"] }}
-reqwest-conditional-middleware = "0.1.0"
 reqwest-middleware = "0.1.5"
-reqwest-retry = "0.1.4"
 reqwest-tracing = "0.3.0"
-ring = {{ version = "0.16", default-features = false, optional = true }}
+ring = {{ version = "0.16", default-features = false }}
 schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid1"] }}
 serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
+serde_path_to_error = "0.1"
 serde_urlencoded = "^0.7"
 url = {{ version = "2", features = ["serde"] }}{}{}
+task-local-extensions = "0.1.1"
 thiserror = "1"
 tokio = {{ version = "1.25.0", features = ["full"] }}
 
-[dev-dependencies]
-base64 = "^0.13"
+[dev-dependencies]{}
 dirs = "^3.0.2"
 nom_pem = "4"
 rand = "0.8.5"
@@ -3393,7 +3768,14 @@ wiremock = "0.5.17"
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                uuid_lib,
+                yup_oauth2_lib,
+                base64_dev_lib
             );
             save(&toml, tomlout.as_str())?;
 
@@ -3465,22 +3847,23 @@ rustdoc-args = ["--cfg", "docsrs"]
             librs.push("lib.rs");
             save(librs, lib.as_str())?;
 
-            /*
-             * Create the Rust utils module:
-             */
-            let utils = utils::generate_utils(&proper_name);
-            let mut utilsrs = src.clone();
-            utilsrs.push("utils.rs");
-            save(utilsrs, utils.as_str())?;
-
             /*
              * Create the Rust source types file containing the generated types:
              */
-            let types = types::generate_types(&mut ts, &proper_name)?;
+            let types = types::generate_types(&mut ts, &proper_name, patch_nullable)?;
             let mut typesrs = src.clone();
             typesrs.push("types.rs");
             save(typesrs, types.as_str())?;
 
+            /*
+             * Create the Rust utils module, with Patch only if the types use it:
+             */
+            let uses_patch = types.contains("crate::utils::Patch<");
+            let utils = utils::generate_utils(&proper_name, uses_patch);
+            let mut utilsrs = src.clone();
+            utilsrs.push("utils.rs");
+            save(utilsrs, utils.as_str())?;
+
             /*
              * Create the Rust source files for each of the tags functions:
              */
@@ -3554,3 +3937,46 @@ impl {} {{
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::request_body_nullable_properties;
+
+    #[test]
+    fn nullable_properties_through_refs() -> Result<()> {
+        let api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Zoom", "version": "2.0.0" },
+            "paths": {},
+            "components": {
+                "schemas": {
+                    "Meeting": { "$ref": "#/components/schemas/MeetingInfo" },
+                    "MeetingInfo": {
+                        "type": "object",
+                        "required": ["topic"],
+                        "properties": {
+                            "agenda": { "type": "string", "nullable": true },
+                            "duration": { "type": "integer" },
+                            "topic": { "type": "string", "nullable": true }
+                        }
+                    }
+                }
+            }
+        }))?;
+
+        let schema = openapiv3::ReferenceOr::Reference {
+            reference: "#/components/schemas/Meeting".to_string(),
+        };
+        let nullable = request_body_nullable_properties(&api, &schema);
+        assert_eq!(nullable.into_iter().collect::<Vec<_>>(), vec!["agenda"]);
+
+        let schema = openapiv3::ReferenceOr::Reference {
+            reference: "#/components/schemas/Missing".to_string(),
+        };
+        assert!(request_body_nullable_properties(&api, &schema).is_empty());
+
+        Ok(())
+    }
+}
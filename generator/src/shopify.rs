@@ -0,0 +1,1323 @@
+/*!
+ * Shopify's spec describes the endpoints of the Admin REST API but none of
+ * their request or response bodies. This merges the schemas from
+ * `specs/shopify/resources.yaml` into it, wraps the resources in the root
+ * objects Shopify sends them in, ie. `{"order": {...}}`, and points the
+ * endpoints of the core resources at them.
+ *
+ * It also has a copy of every endpoint for each API version, which we fold
+ * into one, the client picks the version.
+ */
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{anyhow, Result};
+
+const RESOURCES: &str = include_str!("../../specs/shopify/resources.yaml");
+
+/*
+ * The Admin API version clients talk to unless told otherwise. The spec is
+ * for `2020-10`, which Shopify has long retired, and Shopify only supports a
+ * version for about a year after its release, so bump this as they go.
+ */
+pub const API_VERSION: &str = "2026-07";
+
+/*
+ * The root objects the resources are wrapped in, as:
+ * (name, resource schema, key, key in request bodies, list name, list key).
+ *
+ * This gives us `{name}Root`, `{name}RequestRoot` and `{list name}Root`,
+ * for the ones used in `OPERATIONS`.
+ */
+const ROOTS: &[(&str, &str, &str, &str, &str, &str)] = &[
+    ("Order", "Order", "order", "order", "Orders", "orders"),
+    (
+        "Product", "Product", "product", "product", "Products", "products",
+    ),
+    (
+        "ProductImage",
+        "ProductImage",
+        "image",
+        "image",
+        "ProductImages",
+        "images",
+    ),
+    (
+        "Customer",
+        "Customer",
+        "customer",
+        "customer",
+        "Customers",
+        "customers",
+    ),
+    (
+        "CustomerAddress",
+        "Address",
+        "customer_address",
+        "address",
+        "CustomerAddresses",
+        "addresses",
+    ),
+    (
+        "Collection",
+        "Collection",
+        "collection",
+        "collection",
+        "Collections",
+        "collections",
+    ),
+    (
+        "CustomCollection",
+        "Collection",
+        "custom_collection",
+        "custom_collection",
+        "CustomCollections",
+        "custom_collections",
+    ),
+    (
+        "SmartCollection",
+        "Collection",
+        "smart_collection",
+        "smart_collection",
+        "SmartCollections",
+        "smart_collections",
+    ),
+    (
+        "Fulfillment",
+        "Fulfillment",
+        "fulfillment",
+        "fulfillment",
+        "Fulfillments",
+        "fulfillments",
+    ),
+    (
+        "InventoryLevel",
+        "InventoryLevel",
+        "inventory_level",
+        "inventory_level",
+        "InventoryLevels",
+        "inventory_levels",
+    ),
+    (
+        "CustomerSavedSearch",
+        "CustomerSavedSearch",
+        "customer_saved_search",
+        "customer_saved_search",
+        "CustomerSavedSearches",
+        "customer_saved_searches",
+    ),
+    (
+        "OrderRisk",
+        "OrderRisk",
+        "risk",
+        "risk",
+        "OrderRisks",
+        "risks",
+    ),
+    ("Refund", "Refund", "refund", "refund", "Refunds", "refunds"),
+    (
+        "Collect", "Collect", "collect", "collect", "Collects", "collects",
+    ),
+    (
+        "FulfillmentOrder",
+        "FulfillmentOrder",
+        "fulfillment_order",
+        "fulfillment_order",
+        "FulfillmentOrders",
+        "fulfillment_orders",
+    ),
+    (
+        "FulfillmentService",
+        "FulfillmentServiceMessage",
+        "fulfillment_request",
+        "fulfillment_request",
+        "FulfillmentServices",
+        "fulfillment_requests",
+    ),
+    (
+        "FulfillmentServiceCancellation",
+        "FulfillmentServiceMessage",
+        "cancellation_request",
+        "cancellation_request",
+        "FulfillmentServiceCancellations",
+        "cancellation_requests",
+    ),
+    (
+        "InventoryItem",
+        "InventoryItem",
+        "inventory_item",
+        "inventory_item",
+        "InventoryItems",
+        "inventory_items",
+    ),
+    (
+        "Location",
+        "Location",
+        "location",
+        "location",
+        "Locations",
+        "locations",
+    ),
+    (
+        "MoveDestination",
+        "MoveDestination",
+        "location_for_move",
+        "location_for_move",
+        "MoveDestinations",
+        "locations_for_move",
+    ),
+    (
+        "Metafield",
+        "Metafield",
+        "metafield",
+        "metafield",
+        "Metafields",
+        "metafields",
+    ),
+    (
+        "Webhook", "Webhook", "webhook", "webhook", "Webhooks", "webhooks",
+    ),
+];
+
+/*
+ * The typed endpoints, as (method, path without the `/admin/api/{version}`
+ * prefix, request body, response body). These apply to every API version.
+ */
+const OPERATIONS: &[(&str, &str, Option<&str>, &str)] = &[
+    // orders
+    ("get", "/orders.json", None, "OrdersRoot"),
+    (
+        "post",
+        "/orders.json",
+        Some("OrderRequestRoot"),
+        "OrderRoot",
+    ),
+    ("get", "/orders/count.json", None, "Count"),
+    ("get", "/orders/{order_id}.json", None, "OrderRoot"),
+    (
+        "put",
+        "/orders/{order_id}.json",
+        Some("OrderRequestRoot"),
+        "OrderRoot",
+    ),
+    ("post", "/orders/{order_id}/cancel.json", None, "OrderRoot"),
+    ("post", "/orders/{order_id}/close.json", None, "OrderRoot"),
+    ("post", "/orders/{order_id}/open.json", None, "OrderRoot"),
+    (
+        "get",
+        "/orders/{order_id}/risks.json",
+        None,
+        "OrderRisksRoot",
+    ),
+    (
+        "post",
+        "/orders/{order_id}/risks.json",
+        Some("OrderRiskRequestRoot"),
+        "OrderRiskRoot",
+    ),
+    (
+        "get",
+        "/orders/{order_id}/risks/{risk_id}.json",
+        None,
+        "OrderRiskRoot",
+    ),
+    (
+        "put",
+        "/orders/{order_id}/risks/{risk_id}.json",
+        Some("OrderRiskRequestRoot"),
+        "OrderRiskRoot",
+    ),
+    (
+        "get",
+        "/orders/{order_id}/refunds.json",
+        None,
+        "RefundsRoot",
+    ),
+    (
+        "post",
+        "/orders/{order_id}/refunds.json",
+        Some("RefundRequestRoot"),
+        "RefundRoot",
+    ),
+    (
+        "post",
+        "/orders/{order_id}/refunds/calculate.json",
+        Some("RefundRequestRoot"),
+        "RefundRoot",
+    ),
+    (
+        "get",
+        "/orders/{order_id}/refunds/{refund_id}.json",
+        None,
+        "RefundRoot",
+    ),
+    // products
+    ("get", "/products.json", None, "ProductsRoot"),
+    (
+        "post",
+        "/products.json",
+        Some("ProductRequestRoot"),
+        "ProductRoot",
+    ),
+    ("get", "/products/count.json", None, "Count"),
+    ("get", "/products/{product_id}.json", None, "ProductRoot"),
+    (
+        "put",
+        "/products/{product_id}.json",
+        Some("ProductRequestRoot"),
+        "ProductRoot",
+    ),
+    (
+        "get",
+        "/products/{product_id}/images.json",
+        None,
+        "ProductImagesRoot",
+    ),
+    (
+        "post",
+        "/products/{product_id}/images.json",
+        Some("ProductImageRequestRoot"),
+        "ProductImageRoot",
+    ),
+    (
+        "get",
+        "/products/{product_id}/images/count.json",
+        None,
+        "Count",
+    ),
+    (
+        "get",
+        "/products/{product_id}/images/{image_id}.json",
+        None,
+        "ProductImageRoot",
+    ),
+    (
+        "put",
+        "/products/{product_id}/images/{image_id}.json",
+        Some("ProductImageRequestRoot"),
+        "ProductImageRoot",
+    ),
+    (
+        "get",
+        "/collections/{collection_id}.json",
+        None,
+        "CollectionRoot",
+    ),
+    (
+        "get",
+        "/collections/{collection_id}/products.json",
+        None,
+        "ProductsRoot",
+    ),
+    (
+        "get",
+        "/custom_collections.json",
+        None,
+        "CustomCollectionsRoot",
+    ),
+    (
+        "post",
+        "/custom_collections.json",
+        Some("CustomCollectionRequestRoot"),
+        "CustomCollectionRoot",
+    ),
+    ("get", "/custom_collections/count.json", None, "Count"),
+    (
+        "get",
+        "/custom_collections/{custom_collection_id}.json",
+        None,
+        "CustomCollectionRoot",
+    ),
+    (
+        "put",
+        "/custom_collections/{custom_collection_id}.json",
+        Some("CustomCollectionRequestRoot"),
+        "CustomCollectionRoot",
+    ),
+    (
+        "get",
+        "/smart_collections.json",
+        None,
+        "SmartCollectionsRoot",
+    ),
+    (
+        "post",
+        "/smart_collections.json",
+        Some("SmartCollectionRequestRoot"),
+        "SmartCollectionRoot",
+    ),
+    ("get", "/smart_collections/count.json", None, "Count"),
+    (
+        "get",
+        "/smart_collections/{smart_collection_id}.json",
+        None,
+        "SmartCollectionRoot",
+    ),
+    (
+        "put",
+        "/smart_collections/{smart_collection_id}.json",
+        Some("SmartCollectionRequestRoot"),
+        "SmartCollectionRoot",
+    ),
+    ("get", "/collects.json", None, "CollectsRoot"),
+    (
+        "post",
+        "/collects.json",
+        Some("CollectRequestRoot"),
+        "CollectRoot",
+    ),
+    ("get", "/collects/count.json", None, "Count"),
+    ("get", "/collects/{collect_id}.json", None, "CollectRoot"),
+    // customers
+    ("get", "/customers.json", None, "CustomersRoot"),
+    (
+        "post",
+        "/customers.json",
+        Some("CustomerRequestRoot"),
+        "CustomerRoot",
+    ),
+    ("get", "/customers/count.json", None, "Count"),
+    ("get", "/customers/search.json", None, "CustomersRoot"),
+    ("get", "/customers/{customer_id}.json", None, "CustomerRoot"),
+    (
+        "put",
+        "/customers/{customer_id}.json",
+        Some("CustomerRequestRoot"),
+        "CustomerRoot",
+    ),
+    (
+        "get",
+        "/customers/{customer_id}/orders.json",
+        None,
+        "OrdersRoot",
+    ),
+    (
+        "get",
+        "/customers/{customer_id}/addresses.json",
+        None,
+        "CustomerAddressesRoot",
+    ),
+    (
+        "post",
+        "/customers/{customer_id}/addresses.json",
+        Some("CustomerAddressRequestRoot"),
+        "CustomerAddressRoot",
+    ),
+    (
+        "get",
+        "/customers/{customer_id}/addresses/{address_id}.json",
+        None,
+        "CustomerAddressRoot",
+    ),
+    (
+        "put",
+        "/customers/{customer_id}/addresses/{address_id}.json",
+        Some("CustomerAddressRequestRoot"),
+        "CustomerAddressRoot",
+    ),
+    (
+        "put",
+        "/customers/{customer_id}/addresses/{address_id}/default.json",
+        None,
+        "CustomerAddressRoot",
+    ),
+    (
+        "get",
+        "/customer_saved_searches/{customer_saved_search_id}/customers.json",
+        None,
+        "CustomersRoot",
+    ),
+    (
+        "get",
+        "/customer_saved_searches.json",
+        None,
+        "CustomerSavedSearchesRoot",
+    ),
+    (
+        "post",
+        "/customer_saved_searches.json",
+        Some("CustomerSavedSearchRequestRoot"),
+        "CustomerSavedSearchRoot",
+    ),
+    ("get", "/customer_saved_searches/count.json", None, "Count"),
+    (
+        "get",
+        "/customer_saved_searches/{customer_saved_search_id}.json",
+        None,
+        "CustomerSavedSearchRoot",
+    ),
+    (
+        "put",
+        "/customer_saved_searches/{customer_saved_search_id}.json",
+        Some("CustomerSavedSearchRequestRoot"),
+        "CustomerSavedSearchRoot",
+    ),
+    // inventory
+    ("get", "/inventory_levels.json", None, "InventoryLevelsRoot"),
+    (
+        "post",
+        "/inventory_levels/adjust.json",
+        Some("InventoryLevelAdjustment"),
+        "InventoryLevelRoot",
+    ),
+    (
+        "post",
+        "/inventory_levels/connect.json",
+        Some("InventoryLevelConnection"),
+        "InventoryLevelRoot",
+    ),
+    (
+        "post",
+        "/inventory_levels/set.json",
+        Some("InventoryLevelUpdate"),
+        "InventoryLevelRoot",
+    ),
+    (
+        "get",
+        "/locations/{location_id}/inventory_levels.json",
+        None,
+        "InventoryLevelsRoot",
+    ),
+    ("get", "/inventory_items.json", None, "InventoryItemsRoot"),
+    (
+        "get",
+        "/inventory_items/{inventory_item_id}.json",
+        None,
+        "InventoryItemRoot",
+    ),
+    (
+        "put",
+        "/inventory_items/{inventory_item_id}.json",
+        Some("InventoryItemRequestRoot"),
+        "InventoryItemRoot",
+    ),
+    ("get", "/locations.json", None, "LocationsRoot"),
+    ("get", "/locations/count.json", None, "Count"),
+    ("get", "/locations/{location_id}.json", None, "LocationRoot"),
+    // shipping and fulfillment
+    (
+        "post",
+        "/fulfillments.json",
+        Some("FulfillmentRequestRoot"),
+        "FulfillmentRoot",
+    ),
+    (
+        "post",
+        "/fulfillments/{fulfillment_id}/cancel.json",
+        None,
+        "FulfillmentRoot",
+    ),
+    (
+        "post",
+        "/fulfillments/{fulfillment_id}/update_tracking.json",
+        None,
+        "FulfillmentRoot",
+    ),
+    (
+        "get",
+        "/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+        None,
+        "FulfillmentsRoot",
+    ),
+    (
+        "get",
+        "/orders/{order_id}/fulfillments.json",
+        None,
+        "FulfillmentsRoot",
+    ),
+    (
+        "post",
+        "/orders/{order_id}/fulfillments.json",
+        Some("FulfillmentRequestRoot"),
+        "FulfillmentRoot",
+    ),
+    (
+        "get",
+        "/orders/{order_id}/fulfillments/count.json",
+        None,
+        "Count",
+    ),
+    (
+        "get",
+        "/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+        None,
+        "FulfillmentRoot",
+    ),
+    (
+        "put",
+        "/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+        Some("FulfillmentRequestRoot"),
+        "FulfillmentRoot",
+    ),
+    (
+        "post",
+        "/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json",
+        None,
+        "FulfillmentRoot",
+    ),
+    (
+        "post",
+        "/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json",
+        None,
+        "FulfillmentRoot",
+    ),
+    (
+        "post",
+        "/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+        None,
+        "FulfillmentRoot",
+    ),
+    (
+        "get",
+        "/assigned_fulfillment_orders.json",
+        None,
+        "FulfillmentOrdersRoot",
+    ),
+    (
+        "get",
+        "/orders/{order_id}/fulfillment_orders.json",
+        None,
+        "FulfillmentOrdersRoot",
+    ),
+    (
+        "get",
+        "/fulfillment_orders/{fulfillment_order_id}.json",
+        None,
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+        None,
+        "FulfillmentOrderCancellation",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json",
+        Some("FulfillmentServiceCancellationRequestRoot"),
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json",
+        Some("FulfillmentServiceCancellationRequestRoot"),
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json",
+        Some("FulfillmentServiceCancellationRequestRoot"),
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/close.json",
+        Some("FulfillmentOrderRequestRoot"),
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json",
+        Some("FulfillmentServiceRequestRoot"),
+        "FulfillmentOrderSubmission",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json",
+        Some("FulfillmentServiceRequestRoot"),
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json",
+        Some("FulfillmentServiceRequestRoot"),
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "get",
+        "/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json",
+        None,
+        "MoveDestinationsRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/move.json",
+        Some("FulfillmentOrderRequestRoot"),
+        "FulfillmentOrderMove",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/open.json",
+        None,
+        "FulfillmentOrderRoot",
+    ),
+    (
+        "post",
+        "/fulfillment_orders/{fulfillment_order_id}/reschedule.json",
+        Some("FulfillmentOrderRequestRoot"),
+        "FulfillmentOrderRoot",
+    ),
+    // metafield
+    ("get", "/metafields.json", None, "MetafieldsRoot"),
+    (
+        "post",
+        "/metafields.json",
+        Some("MetafieldRequestRoot"),
+        "MetafieldRoot",
+    ),
+    ("get", "/metafields/count.json", None, "Count"),
+    (
+        "get",
+        "/metafields/{metafield_id}.json",
+        None,
+        "MetafieldRoot",
+    ),
+    (
+        "put",
+        "/metafields/{metafield_id}.json",
+        Some("MetafieldRequestRoot"),
+        "MetafieldRoot",
+    ),
+    // events
+    ("get", "/webhooks.json", None, "WebhooksRoot"),
+    (
+        "post",
+        "/webhooks.json",
+        Some("WebhookRequestRoot"),
+        "WebhookRoot",
+    ),
+    ("get", "/webhooks/count.json", None, "Count"),
+    ("get", "/webhooks/{webhook_id}.json", None, "WebhookRoot"),
+    (
+        "put",
+        "/webhooks/{webhook_id}.json",
+        Some("WebhookRequestRoot"),
+        "WebhookRoot",
+    ),
+];
+
+/**
+ * Add the Shopify resource schemas to the spec and type the endpoints that
+ * send and return them.
+ */
+pub fn patch_spec(api: &mut openapiv3::OpenAPI) -> Result<()> {
+    let resources: serde_json::Value = serde_yaml::from_str(RESOURCES)?;
+    let resources = resources["schemas"]
+        .as_object()
+        .ok_or_else(|| anyhow!("shopify resources have no schemas"))?;
+
+    let mut schemas: BTreeMap<String, serde_json::Value> = BTreeMap::new();
+    for (name, schema) in resources {
+        schemas.insert(name.to_string(), strip_properties(schema, "writeOnly"));
+    }
+
+    // Only add the root objects we use, there is no list of smart and custom
+    // collections together for instance.
+    let used: BTreeSet<&str> = OPERATIONS
+        .iter()
+        .flat_map(|(_, _, request, response)| request.iter().chain(std::iter::once(response)))
+        .copied()
+        .collect();
+
+    for (name, schema, key, request_key, list_name, list_key) in ROOTS {
+        let root_name = format!("{}Root", name);
+        if used.contains(root_name.as_str()) {
+            schemas.insert(root_name, root(key, schema, false));
+        }
+
+        let list_root_name = format!("{}Root", list_name);
+        if used.contains(list_root_name.as_str()) {
+            schemas.insert(list_root_name, root(list_key, schema, true));
+        }
+
+        let request_root_name = format!("{}RequestRoot", name);
+        if used.contains(request_root_name.as_str()) {
+            let request = request_schema(resources, schema, &mut schemas)?;
+            schemas.insert(request_root_name, root(request_key, &request, false));
+        }
+    }
+
+    let components = api.components.get_or_insert_with(Default::default);
+    for (name, schema) in schemas {
+        components
+            .schemas
+            .insert(name, serde_json::from_value(schema)?);
+    }
+
+    collapse_versions(api)?;
+    unindent_docs(api)?;
+
+    let list_roots: BTreeSet<String> = ROOTS
+        .iter()
+        .map(|(_, _, _, _, list_name, _)| format!("{}Root", list_name))
+        .collect();
+
+    for (pn, p) in api.paths.paths.iter_mut() {
+        let path = match pn.strip_prefix("/admin/api") {
+            Some(path) => path,
+            None => continue,
+        };
+
+        let item = match p {
+            openapiv3::ReferenceOr::Item(item) => item,
+            openapiv3::ReferenceOr::Reference { .. } => continue,
+        };
+
+        for (method, op_path, request, response) in OPERATIONS {
+            if *op_path != path {
+                continue;
+            }
+
+            let op = match *method {
+                "get" => item.get.as_mut(),
+                "post" => item.post.as_mut(),
+                "put" => item.put.as_mut(),
+                _ => None,
+            };
+            if let Some(op) = op {
+                type_operation(op, *request, response);
+
+                if list_roots.contains(*response) {
+                    add_page_info(op)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/**
+ * Add the `page_info` cursor Shopify pages lists with to an operation. The
+ * cursors come in the `Link` header of the previous page.
+ */
+fn add_page_info(op: &mut openapiv3::Operation) -> Result<()> {
+    let exists = op.parameters.iter().any(|p| match p {
+        openapiv3::ReferenceOr::Item(p) => p.parameter_data_ref().name == "page_info",
+        openapiv3::ReferenceOr::Reference { .. } => false,
+    });
+    if !exists {
+        op.parameters.push(serde_json::from_value(serde_json::json!({
+            "in": "query",
+            "name": "page_info",
+            "description": "The cursor of the page to get, from the `Link` header of the previous page.",
+            "schema": { "type": "string" }
+        }))?);
+    }
+
+    Ok(())
+}
+
+/**
+ * Fold the copies of each endpoint for every API version, ie.
+ * `/admin/api/2020-01/orders.json`, into one at `/admin/api/orders.json`. The
+ * client puts the version it talks to back after `/admin/api`.
+ *
+ * We keep the operation of the version the spec is for, or else of the
+ * newest version that has it, and drop the `deprecated_202001_` prefix from
+ * its id.
+ */
+fn collapse_versions(api: &mut openapiv3::OpenAPI) -> Result<()> {
+    let current = api.info.version.clone();
+
+    // Path without the version -> method -> version -> operation.
+    let mut versioned: BTreeMap<String, BTreeMap<String, BTreeMap<String, serde_json::Value>>> =
+        BTreeMap::new();
+    for (pn, p) in std::mem::take(&mut api.paths.paths) {
+        let (version, path) = match pn
+            .strip_prefix("/admin/api/")
+            .and_then(|rest| rest.split_once('/'))
+        {
+            Some((version, path)) => (version.to_string(), format!("/admin/api/{}", path)),
+            None => {
+                // The spec has a few endpoints outside `/admin/api`, under
+                // a `deprecated_unknown_version_` id.
+                let mut item = serde_json::to_value(&p)?;
+                if let Some(ops) = item.as_object_mut() {
+                    for op in ops.values_mut() {
+                        if let Some(id) = op["operationId"].as_str() {
+                            op["operationId"] = serde_json::json!(unversioned_operation_id(id));
+                        }
+                    }
+                }
+                api.paths.paths.insert(pn, serde_json::from_value(item)?);
+                continue;
+            }
+        };
+
+        // Hold the place of the path, to keep the order of the spec.
+        api.paths
+            .paths
+            .entry(path.clone())
+            .or_insert_with(|| openapiv3::ReferenceOr::Item(Default::default()));
+
+        let item = match serde_json::to_value(&p)? {
+            serde_json::Value::Object(item) => item,
+            _ => continue,
+        };
+        // The spec has nothing but operations on its paths.
+        for (method, op) in item {
+            if !["get", "put", "post", "delete", "patch"].contains(&method.as_str()) {
+                continue;
+            }
+
+            versioned
+                .entry(path.clone())
+                .or_default()
+                .entry(method)
+                .or_default()
+                .insert(version.clone(), op);
+        }
+    }
+
+    for (path, methods) in versioned {
+        let mut item = serde_json::Map::new();
+        for (method, mut ops) in methods {
+            let versions: Vec<&str> = ops.keys().map(String::as_str).collect();
+            let version = match pick_version(&current, &versions) {
+                Some(version) => version.to_string(),
+                None => continue,
+            };
+            let note = version_note(&current, &versions);
+            let mut op = ops.remove(&version).unwrap_or_default();
+
+            if let Some(id) = op["operationId"].as_str() {
+                op["operationId"] = serde_json::json!(unversioned_operation_id(id));
+            }
+            if let Some(note) = note {
+                op["description"] = match op["description"].as_str() {
+                    Some(d) if !d.is_empty() => serde_json::json!(format!("{}\n\n{}", d, note)),
+                    _ => serde_json::json!(note),
+                };
+            }
+
+            item.insert(method, op);
+        }
+
+        api.paths.paths.insert(
+            path,
+            serde_json::from_value(serde_json::Value::Object(item))?,
+        );
+    }
+
+    Ok(())
+}
+
+/**
+ * Drop the indentation the spec has on the lines of the summaries and
+ * descriptions of the endpoints and their parameters. Rustdoc takes the
+ * indented lines for code and fails to compile them as doc tests.
+ */
+fn unindent_docs(api: &mut openapiv3::OpenAPI) -> Result<()> {
+    fn unindent(value: &mut serde_json::Value, key: &str) {
+        if let Some(value) = value.get_mut(key) {
+            if let Some(s) = value.as_str() {
+                *value =
+                    serde_json::json!(s.lines().map(str::trim).collect::<Vec<&str>>().join("\n"));
+            }
+        }
+    }
+
+    for p in api.paths.paths.values_mut() {
+        let mut item = serde_json::to_value(&*p)?;
+        // The spec has nothing but operations on its paths.
+        if let Some(ops) = item.as_object_mut() {
+            for op in ops.values_mut() {
+                unindent(op, "summary");
+                unindent(op, "description");
+                if let Some(params) = op.get_mut("parameters").and_then(|p| p.as_array_mut()) {
+                    for param in params {
+                        unindent(param, "description");
+                    }
+                }
+            }
+        }
+        *p = serde_json::from_value(item)?;
+    }
+
+    Ok(())
+}
+
+/**
+ * The version to take an endpoint from: the one the spec is for, or else the
+ * newest stable version, or else `unstable`.
+ */
+fn pick_version<'a>(current: &str, versions: &[&'a str]) -> Option<&'a str> {
+    versions
+        .iter()
+        .find(|v| **v == current)
+        .or_else(|| versions.iter().filter(|v| **v != "unstable").max())
+        .or_else(|| versions.first())
+        .copied()
+}
+
+/**
+ * A line for the docs of the endpoints the version the spec is for does not
+ * have. The spec misses some of them, ie. listing orders, so this does not
+ * mean Shopify removed them.
+ */
+fn version_note(current: &str, versions: &[&str]) -> Option<String> {
+    if versions.contains(&current) {
+        return None;
+    }
+
+    let plural = if versions.len() > 1 { "s" } else { "" };
+    let versions = versions
+        .iter()
+        .map(|v| format!("`{}`", v))
+        .collect::<Vec<String>>()
+        .join(", ");
+    Some(format!(
+        "The spec only describes this endpoint for API version{} {}.",
+        plural, versions
+    ))
+}
+
+/**
+ * Drop the version from the id of an operation of an older or upcoming API
+ * version, ie. `deprecated_202001_get_orders` becomes `get_orders`, or of an
+ * operation the spec does not know the version of, ie.
+ * `deprecated_unknown_version_get_fetch_stock`.
+ */
+fn unversioned_operation_id(id: &str) -> &str {
+    if let Some(rest) = id.strip_prefix("deprecated_unknown_version_") {
+        return rest;
+    }
+
+    match id
+        .strip_prefix("deprecated_")
+        .and_then(|rest| rest.split_once('_'))
+    {
+        Some((version, rest))
+            if version == "unstable"
+                || (!version.is_empty() && version.chars().all(|c| c.is_ascii_digit())) =>
+        {
+            rest
+        }
+        _ => id,
+    }
+}
+
+/**
+ * Wrap a resource in its root object.
+ */
+fn root(key: &str, schema: &str, list: bool) -> serde_json::Value {
+    let reference = serde_json::json!({ "$ref": format!("#/components/schemas/{}", schema) });
+    let property = if list {
+        serde_json::json!({ "type": "array", "items": reference })
+    } else {
+        reference
+    };
+
+    serde_json::json!({
+        "type": "object",
+        "properties": { key: property },
+    })
+}
+
+/**
+ * Add a `{name}Request` copy of an object schema, and of the object schemas
+ * it refers to, without the fields Shopify does not let you set. Returns the
+ * name of the copy.
+ */
+fn request_schema(
+    resources: &serde_json::Map<String, serde_json::Value>,
+    name: &str,
+    schemas: &mut BTreeMap<String, serde_json::Value>,
+) -> Result<String> {
+    let request_name = format!("{}Request", name);
+    if schemas.contains_key(&request_name) {
+        return Ok(request_name);
+    }
+
+    let mut schema = strip_properties(
+        resources
+            .get(name)
+            .ok_or_else(|| anyhow!("shopify resource {} not found", name))?,
+        "readOnly",
+    );
+    // Insert it before walking the properties, resources can refer to each
+    // other.
+    schemas.insert(request_name.clone(), schema.clone());
+
+    if let Some(properties) = schema["properties"].as_object_mut() {
+        for property in properties.values_mut() {
+            let reference = if property["type"] == "array" {
+                &mut property["items"]["$ref"]
+            } else {
+                &mut property["$ref"]
+            };
+
+            let referenced = match reference
+                .as_str()
+                .and_then(|r| r.strip_prefix("#/components/schemas/"))
+            {
+                Some(r) => r.to_string(),
+                None => continue,
+            };
+            // Enums are the same either way.
+            if resources[&referenced]["type"] != "object" {
+                continue;
+            }
+
+            let referenced = request_schema(resources, &referenced, schemas)?;
+            *reference = serde_json::json!(format!("#/components/schemas/{}", referenced));
+        }
+    }
+
+    schemas.insert(request_name.clone(), schema);
+
+    Ok(request_name)
+}
+
+/**
+ * Remove the properties of an object schema that have `flag` set, ie.
+ * `readOnly`.
+ */
+fn strip_properties(schema: &serde_json::Value, flag: &str) -> serde_json::Value {
+    let mut schema = schema.clone();
+    if let Some(properties) = schema["properties"].as_object_mut() {
+        *properties = std::mem::take(properties)
+            .into_iter()
+            .filter(|(_, p)| p[flag] != true)
+            .collect();
+    }
+    schema
+}
+
+/**
+ * Point the request and the first response of an operation at our schemas.
+ *
+ * The spec lists the fields of some request bodies as query parameters, ie.
+ * the ones of `/inventory_levels/set.json`, so we drop those.
+ */
+fn type_operation(op: &mut openapiv3::Operation, request: Option<&str>, response: &str) {
+    let media_type = |name: &str| openapiv3::MediaType {
+        schema: Some(openapiv3::ReferenceOr::ref_(&format!(
+            "#/components/schemas/{}",
+            name
+        ))),
+        ..Default::default()
+    };
+
+    if let Some(request) = request {
+        op.parameters.retain(|p| {
+            !matches!(
+                p,
+                openapiv3::ReferenceOr::Item(openapiv3::Parameter::Query { .. })
+            )
+        });
+
+        let body = op.request_body.get_or_insert_with(|| {
+            openapiv3::ReferenceOr::Item(openapiv3::RequestBody {
+                required: true,
+                ..Default::default()
+            })
+        });
+        if let openapiv3::ReferenceOr::Item(body) = body {
+            // Keep the examples, they are a good place to start.
+            let examples = body
+                .content
+                .get("application/json")
+                .map(|mt| mt.examples.clone())
+                .unwrap_or_default();
+            body.content.clear();
+            body.content.insert(
+                "application/json".to_string(),
+                openapiv3::MediaType {
+                    examples,
+                    ..media_type(request)
+                },
+            );
+        }
+    }
+
+    if op.responses.responses.is_empty() {
+        op.responses.responses.insert(
+            openapiv3::StatusCode::Code(200),
+            openapiv3::ReferenceOr::Item(Default::default()),
+        );
+    }
+    if let Some((_, openapiv3::ReferenceOr::Item(r))) = op.responses.responses.first_mut() {
+        r.content.clear();
+        r.content
+            .insert("application/json".to_string(), media_type(response));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::patch_spec;
+
+    #[test]
+    fn patch() -> Result<()> {
+        let mut api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Shopify", "version": "2020-10" },
+            "paths": {
+                "/admin/api/2020-10/products/{product_id}.json": {
+                    "put": {
+                        "requestBody": { "content": { "application/json": { "schema": {} } } },
+                        "responses": { "200": { "description": "" } }
+                    }
+                }
+            }
+        }))?;
+        patch_spec(&mut api)?;
+
+        let schemas = &api.components.as_ref().unwrap().schemas;
+        let product = serde_json::to_value(&schemas["Product"])?;
+        let request = serde_json::to_value(&schemas["ProductRequest"])?;
+        // `readOnly` fields are only returned, `writeOnly` fields only sent.
+        assert!(product["properties"].get("created_at").is_some());
+        assert!(product["properties"]
+            .get("metafields_global_title_tag")
+            .is_none());
+        assert!(request["properties"].get("created_at").is_none());
+        assert!(request["properties"]
+            .get("metafields_global_title_tag")
+            .is_some());
+        assert_eq!(
+            request["properties"]["variants"]["items"]["$ref"],
+            "#/components/schemas/VariantRequest"
+        );
+        assert_eq!(
+            request["properties"]["status"]["$ref"],
+            "#/components/schemas/ProductStatus"
+        );
+        // There is no endpoint that lists custom and smart collections together.
+        assert!(schemas.get("CollectionsRoot").is_none());
+
+        let op = serde_json::to_value(
+            api.paths.paths["/admin/api/products/{product_id}.json"]
+                .as_item()
+                .unwrap()
+                .put
+                .as_ref()
+                .unwrap(),
+        )?;
+        assert_eq!(
+            op["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/ProductRequestRoot"
+        );
+        assert_eq!(
+            op["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/ProductRoot"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn patch_body_parameters() -> Result<()> {
+        let param = |location: &str, name: &str| serde_json::json!({ "in": location, "name": name, "schema": {}, "required": location == "path" });
+        let mut api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Shopify", "version": "2020-10" },
+            "paths": {
+                "/admin/api/2020-10/orders/{order_id}/refunds.json": {
+                    "post": {
+                        "parameters": [
+                            param("path", "order_id"),
+                            param("query", "note"),
+                            param("query", "restock\n                  deprecated")
+                        ],
+                        "requestBody": { "content": { "application/json": { "schema": {} } } },
+                        "responses": { "200": { "description": "" } }
+                    }
+                }
+            }
+        }))?;
+        patch_spec(&mut api)?;
+
+        let op = serde_json::to_value(
+            api.paths.paths["/admin/api/orders/{order_id}/refunds.json"]
+                .as_item()
+                .unwrap()
+                .post
+                .as_ref()
+                .unwrap(),
+        )?;
+        // The fields of the refund are sent in the body, not the query.
+        let names: Vec<&str> = op["parameters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["order_id"]);
+        assert_eq!(
+            op["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/RefundRequestRoot"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn collapse_versions() -> Result<()> {
+        let op = |id: &str| serde_json::json!({ "operationId": id, "responses": {} });
+        let mut api: openapiv3::OpenAPI = serde_json::from_value(serde_json::json!({
+            "openapi": "3.0.0",
+            "info": { "title": "Shopify", "version": "2020-10" },
+            "paths": {
+                "/admin/oauth/access_scopes.json": { "get": op("get_admin_oauth_access_scopes") },
+                "/fetch_stock": { "get": op("deprecated_unknown_version_get_fetch_stock") },
+                "/admin/api/2020-01/checkouts.json": {
+                    "get": op("deprecated_202001_get_checkouts"),
+                    "post": op("deprecated_202001_create_checkouts")
+                },
+                "/admin/api/2020-10/checkouts.json": { "get": op("get_checkouts") },
+                "/admin/api/2021-01/checkouts.json": { "get": op("deprecated_202101_get_checkouts") },
+                "/admin/api/unstable/checkouts/count.json": {
+                    "get": op("deprecated_unstable_get_checkouts_count")
+                }
+            }
+        }))?;
+        super::collapse_versions(&mut api)?;
+
+        let paths: Vec<&str> = api.paths.paths.keys().map(|p| p.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/admin/api/checkouts.json",
+                "/admin/api/checkouts/count.json",
+                "/admin/oauth/access_scopes.json",
+                "/fetch_stock"
+            ]
+        );
+        let fetch_stock = api.paths.paths["/fetch_stock"].as_item().unwrap();
+        assert_eq!(
+            fetch_stock.get.as_ref().unwrap().operation_id.as_deref(),
+            Some("get_fetch_stock")
+        );
+
+        let item = api.paths.paths["/admin/api/checkouts.json"]
+            .as_item()
+            .unwrap();
+        let get = item.get.as_ref().unwrap();
+        assert_eq!(get.operation_id.as_deref(), Some("get_checkouts"));
+        assert_eq!(get.description, None);
+        let post = item.post.as_ref().unwrap();
+        assert_eq!(post.operation_id.as_deref(), Some("create_checkouts"));
+        assert_eq!(
+            post.description.as_deref(),
+            Some("The spec only describes this endpoint for API version `2020-01`.")
+        );
+
+        let count = api.paths.paths["/admin/api/checkouts/count.json"]
+            .as_item()
+            .unwrap()
+            .get
+            .as_ref()
+            .unwrap();
+        assert_eq!(count.operation_id.as_deref(), Some("get_checkouts_count"));
+        assert_eq!(
+            count.description.as_deref(),
+            Some("The spec only describes this endpoint for API version `unstable`.")
+        );
+
+        Ok(())
+    }
+}
@@ -7,6 +7,9 @@ use inflector::cases::{kebabcase::to_kebab_case, snakecase::to_snake_case};
 enum Component {
     Constant(String),
     Parameter(String),
+    /// A constant directly following a parameter without a slash, ie. the
+    /// `.json` in Shopify's `/orders/{order_id}.json`.
+    Suffix(String),
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -67,6 +70,12 @@ impl Template {
                         r#"if !{}.is_empty() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
+                } else if value == "&[String]" && prop == "expand" {
+                    // Stripe wants each field to expand as its own `expand[]` param.
+                    a(&format!(
+                        r#"for f in {} {{ query_args.push(("{}[]".to_string(), f.to_string())); }}"#,
+                        nam, prop
+                    ));
                 } else if value == "&[String]" {
                     // TODO: I have no idea how these should be seperated and the docs
                     // don't give any answers either, for an array sent through query
@@ -97,7 +106,7 @@ impl Template {
         let mut has_params = false;
         for c in self.components.iter() {
             match c {
-                Component::Constant(_) => (),
+                Component::Constant(_) | Component::Suffix(_) => (),
                 Component::Parameter(_) => {
                     has_params = true;
                     break;
@@ -108,10 +117,13 @@ impl Template {
         if !has_params && query_params.is_empty() {
             out.push_str(r#"&""#);
             for c in self.components.iter() {
-                out.push('/');
                 match c {
-                    Component::Constant(n) => out.push_str(n),
-                    Component::Parameter(_) => (),
+                    Component::Constant(n) => {
+                        out.push('/');
+                        out.push_str(n);
+                    }
+                    Component::Suffix(n) => out.push_str(n),
+                    Component::Parameter(_) => out.push('/'),
                 }
             }
             out.push_str(&format!(r#"".to_string(), {server_arg});"#));
@@ -121,11 +133,14 @@ impl Template {
 
         out.push_str("&format!(\"");
         for c in self.components.iter() {
-            out.push('/');
             match c {
-                Component::Constant(n) => out.push_str(n),
+                Component::Constant(n) => {
+                    out.push('/');
+                    out.push_str(n);
+                }
+                Component::Suffix(n) => out.push_str(n),
                 Component::Parameter(_) => {
-                    out.push_str("{}");
+                    out.push_str("/{}");
                 }
             }
         }
@@ -178,6 +193,7 @@ fn parse_inner(t: &str) -> Result<Template> {
         Parameter,
         ParameterSlash,
         Constant,
+        Suffix,
     }
 
     let mut s = State::Start;
@@ -226,15 +242,29 @@ fn parse_inner(t: &str) -> Result<Template> {
                 }
             }
             State::ParameterSlash => {
-                if c == '/' || c == ':' || c == '.' {
+                if c == '/' {
+                    s = State::ConstantOrParameter;
+                } else if c == ':' || c == '.' {
                     // Google Admin API has ":issueCommand" so we want to allow that!
                     // Shopify sometimes ends after a parameter with ".json", so we want to allow
-                    // that.
-                    s = State::ConstantOrParameter;
+                    // that. Either way the separator has to be kept in the url.
+                    s = State::Suffix;
+                    a.push(c);
                 } else {
                     bail!("expected a slash after parameter");
                 }
             }
+            State::Suffix => {
+                if c == '/' {
+                    components.push(Component::Suffix(a));
+                    a = String::new();
+                    s = State::ConstantOrParameter;
+                } else if c == '{' || c == '}' {
+                    bail!("unexpected parameter");
+                } else {
+                    a.push(c);
+                }
+            }
         }
     }
 
@@ -242,6 +272,7 @@ fn parse_inner(t: &str) -> Result<Template> {
         State::Start => bail!("empty path"),
         State::ConstantOrParameter | State::ParameterSlash => (),
         State::Constant => components.push(Component::Constant(a)),
+        State::Suffix => components.push(Component::Suffix(a)),
         State::Parameter => bail!("unterminated parameter"),
     }
 
@@ -282,6 +313,16 @@ mod test {
                     ],
                 },
             ),
+            (
+                "/orders/{order_id}.json",
+                Template {
+                    components: vec![
+                        Component::Constant("orders".into()),
+                        Component::Parameter("order_id".into()),
+                        Component::Suffix(".json".into()),
+                    ],
+                },
+            ),
         ];
 
         for (path, want) in trials.iter() {
@@ -299,12 +340,27 @@ mod test {
         let want = r#"let url = self.client.url(
 &format!("/measure/{}",
 crate::progenitor_support::encode_path(&number.to_string()),), None);
+"#;
+        assert_eq!(want, &out);
+
+        let t = parse("/v2/{name}:move")?;
+        let out = t.compile(Default::default(), "None");
+        let want = r#"let url = self.client.url(
+&format!("/v2/{}:move",
+crate::progenitor_support::encode_path(&name.to_string()),), None);
 "#;
         assert_eq!(want, &out);
         Ok(())
     }
 }
 
+/// The name of the client in the examples, e.g. `google_drive` for Google Drive.
+fn client_var_name(proper_name: &str) -> String {
+    proper_name
+        .to_lowercase()
+        .replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+}
+
 pub fn generate_docs_github(
     api: &openapiv3::OpenAPI,
     name: &str,
@@ -314,7 +370,7 @@ pub fn generate_docs_github(
     spec_link: &str,
 ) -> String {
     let info = generate_docs_openapi_info(api, proper_name, spec_link, name);
-    let docs_proper_name = proper_name.to_lowercase();
+    let docs_proper_name = client_var_name(proper_name);
 
     format!(
         r#"{info}
@@ -445,6 +501,24 @@ pub fn generate_docs_github(
 //! );
 //! ```
 //!
+//! ## Receiving webhooks
+//!
+//! The `webhooks` module verifies the `X-Hub-Signature-256` header of a delivery
+//! and parses the payload into a typed event, keyed by the `X-GitHub-Event` header.
+//!
+//! ```rust
+//! use {name}::webhooks::{{verify_and_parse, WebhookEvent}};
+//!
+//! fn handle(secret: &str, headers: &http::HeaderMap, body: &[u8]) -> Result<(), {name}::ClientError> {{
+//!     match verify_and_parse(secret, headers, body)? {{
+//!         WebhookEvent::Push(push) => println!("{{}} was pushed to {{}}", push.after, push.ref_),
+//!         WebhookEvent::PullRequest(pr) => println!("pull request {{}} was {{}}", pr.number, pr.action),
+//!         _ => {{}}
+//!     }}
+//!     Ok(())
+//! }}
+//! ```
+//!
 //! ## Acknowledgements
 //!
 //! Shout out to [hubcaps](https://github.com/softprops/hubcaps) for paving the
@@ -613,6 +687,138 @@ pub fn generate_docs_generic_token(
         String::new()
     };
 
+    // The Google clients read their credentials asynchronously.
+    let from_env_await = if proper_name.starts_with("Google") {
+        ".await"
+    } else {
+        ""
+    };
+
+    let service_account_docs = if proper_name.starts_with("Google") {
+        format!(
+            r#"
+//! For backend automation, you can authenticate as a service account instead.
+//! Pass in the service account's JSON key, the scopes you need and, if you
+//! are using domain-wide delegation, the user to impersonate. Access tokens
+//! are minted and refreshed for you.
+//!
+//! ```
+//! use {}::Client;
+//!
+//! async fn do_call() {{
+//!     let key = std::fs::read("service-account.json").unwrap();
+//!     let {} = Client::new_from_service_account(
+//!         key,
+//!         &["some-scope".to_string()],
+//!         Some("user@example.com"),
+//!     )
+//!     .await
+//!     .unwrap();
+//! }}
+//! ```
+//!"#,
+            name,
+            client_var_name(proper_name),
+        )
+    } else if proper_name == "Shopify" {
+        format!(
+            r#"
+//! Every shop has its own host and OAuth endpoints, so create a client for
+//! the shop you are talking to. To install your app on a shop, check the
+//! redirect back from Shopify before exchanging the code for a token.
+//!
+//! ```
+//! use {}::Client;
+//!
+//! async fn do_call(shop: &str, callback_query: &str) {{
+//!     let mut {} = Client::new_for_shop(
+//!         shop,
+//!         String::from("client-id"),
+//!         String::from("client-secret"),
+//!         String::from("redirect-uri"),
+//!         "",
+//!     )
+//!     .unwrap();
+//!
+//!     let user_consent_url = {}.user_consent_url(&["read_orders".to_string()]);
+//!
+//!     // In your redirect URL, verify the query Shopify sent the merchant
+//!     // back with and check the state is the one you sent.
+//!     let callback = {}.verify_oauth_callback(callback_query).unwrap();
+//!     let access_token = {}
+//!         .get_access_token(&callback.code, &callback.state)
+//!         .await
+//!         .unwrap();
+//! }}
+//! ```
+//!
+//! The `get_all` functions follow the `page_info` cursors in the `Link`
+//! header to fetch every page of a list, and the `_stream` ones fetch the
+//! pages as you go. Requests are paced with the leaky bucket in
+//! `X-Shopify-Shop-Api-Call-Limit`, so they do not run into `429`s.
+//!
+//! Requests go to the Admin API at `DEFAULT_API_VERSION`. Pick another
+//! version for the client with `set_api_version`, or for a single request
+//! with `with_api_version`. Calls Shopify flags with
+//! `X-Shopify-API-Deprecated-Reason` are logged as warnings.
+//!
+//! ```
+//! use {}::Client;
+//!
+//! async fn do_call({}: &mut Client) {{
+//!     {}.set_api_version("2026-04");
+//!
+//!     let orders = {}.with_api_version("unstable").orders();
+//! }}
+//! ```
+//!"#,
+            name,
+            client_var_name(proper_name),
+            client_var_name(proper_name),
+            client_var_name(proper_name),
+            client_var_name(proper_name),
+            name,
+            client_var_name(proper_name),
+            client_var_name(proper_name),
+            client_var_name(proper_name),
+        )
+    } else {
+        String::new()
+    };
+
+    // Shopify clients need a shop to talk to.
+    let (basic_example, shop_env_var) = if proper_name == "Shopify" {
+        (
+            format!(
+                r#"let {} = Client::new_for_shop(
+//!     "my-shop",
+//!     String::from("client-id"),
+//!     String::from("client-secret"),
+//!     String::from("redirect-uri"),
+//!     String::from("token"),
+//! )
+//! .unwrap();"#,
+                client_var_name(proper_name),
+            ),
+            "\n//! - `SHOPIFY_SHOP`",
+        )
+    } else {
+        (
+            format!(
+                r#"let {} = Client::new(
+//!     String::from("client-id"),
+//!     String::from("client-secret"),
+//!     String::from("redirect-uri"),
+//!     String::from("token"),
+//!     String::from("refresh-token"){}
+//! );"#,
+                client_var_name(proper_name),
+                add_post_header_args,
+            ),
+            "",
+        )
+    };
+
     format!(
         r#"{}
 //!
@@ -631,13 +837,7 @@ pub fn generate_docs_generic_token(
 //! ```
 //! use {}::Client;
 //!
-//! let {} = Client::new(
-//!     String::from("client-id"),
-//!     String::from("client-secret"),
-//!     String::from("redirect-uri"),
-//!     String::from("token"),
-//!     String::from("refresh-token"){}
-//! );
+//! {basic_example}
 //! ```
 //!
 //! Alternatively, the library can search for most of the variables required for
@@ -645,11 +845,11 @@ pub fn generate_docs_generic_token(
 //!
 //! - `{}_CLIENT_ID`
 //! - `{}_CLIENT_SECRET`
-//! - `{}_REDIRECT_URI`
+//! - `{}_REDIRECT_URI`{shop_env_var}
 //!
 //! And then you can create a client from the environment.
 //!
-//! ```
+//! ```no_run
 //! use {}::Client;
 //!
 //! let {} = Client::new_from_env(
@@ -667,7 +867,7 @@ pub fn generate_docs_generic_token(
 //! use {}::Client;
 //!
 //! async fn do_call() {{
-//!     let mut {} = Client::new_from_env("", ""{});
+//!     let mut {} = Client::new_from_env("", ""{}){};
 //!
 //!     // Get the URL to request consent from the user.
 //!     // You can optionally pass in scopes. If none are provided, then the
@@ -685,25 +885,25 @@ pub fn generate_docs_generic_token(
 //!     access_token = {}.refresh_access_token().await.unwrap();
 //! }}
 //! ```
-//!"#,
+//!{}"#,
         info,
         name.replace('_', "-").to_lowercase(),
         version,
         name,
-        proper_name.to_lowercase(),
-        add_post_header_args,
         proper_name.to_uppercase(),
         proper_name.to_uppercase(),
         proper_name.to_uppercase(),
         name,
-        proper_name.to_lowercase(),
+        client_var_name(proper_name),
         add_post_header_args,
         name,
-        proper_name.to_lowercase(),
+        client_var_name(proper_name),
         add_post_header_var,
-        proper_name.to_lowercase(),
-        proper_name.to_lowercase(),
-        proper_name.to_lowercase(),
+        from_env_await,
+        client_var_name(proper_name),
+        client_var_name(proper_name),
+        client_var_name(proper_name),
+        service_account_docs,
     )
 }
 
@@ -745,7 +945,7 @@ pub fn generate_docs_generic_api_key(
 //!
 //! And then you can create a client from the environment.
 //!
-//! ```
+//! ```no_run
 //! use {}::Client;
 //!
 //! let {} = Client::new_from_env();
@@ -755,10 +955,10 @@ pub fn generate_docs_generic_api_key(
         name.replace('_', "-").to_lowercase(),
         version,
         name,
-        proper_name.to_lowercase(),
+        client_var_name(proper_name),
         proper_name.to_uppercase(),
         name,
-        proper_name.to_lowercase(),
+        client_var_name(proper_name),
     )
 }
 
@@ -803,7 +1003,7 @@ pub fn generate_docs_generic_client_credentials(
 //!
 //! And then you can create a client from the environment.
 //!
-//! ```
+//! ```no_run
 //! use {}::Client;
 //!
 //! let {} = Client::new_from_env(
@@ -830,13 +1030,13 @@ pub fn generate_docs_generic_client_credentials(
         name.replace('_', "-").to_lowercase(),
         version,
         name,
-        proper_name.to_lowercase(),
+        client_var_name(proper_name),
         proper_name.to_uppercase(),
         proper_name.to_uppercase(),
         name,
-        proper_name.to_lowercase(),
+        client_var_name(proper_name),
         name,
-        proper_name.to_lowercase(),
-        proper_name.to_lowercase(),
+        client_var_name(proper_name),
+        client_var_name(proper_name),
     )
 }
@@ -8,7 +8,11 @@ use crate::{render_param, struct_name, TypeDetails, TypeSpace};
 /*
  * Declare named types we know about:
  */
-pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
+pub fn generate_types(
+    ts: &mut TypeSpace,
+    proper_name: &str,
+    patch_nullable: bool,
+) -> Result<String> {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -40,12 +44,13 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     );
                     a(&p);
                 }
-                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)),
+                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn, false)),
                 TypeDetails::AnyOf(omap, _) => {
                     // When Stripe uses anyof what they really mean is one of.
                     // Because something can not both be a string and an object.
+                    // These are mostly expandable fields, an ID or the expanded object.
                     if proper_name == "Stripe" {
-                        a(&do_one_of_type(ts, omap, sn));
+                        a(&do_one_of_type(ts, omap, sn, true));
                     } else {
                         a(&do_all_of_type(ts, omap, sn));
                     }
@@ -110,6 +115,7 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                              JsonSchema)]");
                     }
                     a(&format!("pub struct {} {{", sn));
+                    let nullable = ts.request_bodies.get(&te.id).cloned().unwrap_or_default();
                     for (name, tid) in omap.iter() {
                         if let Ok(mut rt) = ts.render_type(tid, true) {
                             // Stripe has some really weird recursive types.
@@ -165,8 +171,20 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 prop = name.trim_start_matches('_').to_string();
                             }
 
-                            // Try to render the docs.
-                            let p = ts.render_docs(tid);
+                            // Try to render the docs, the property's own if it has them.
+                            let p = match ts.property_docs.get(&te.id).and_then(|d| d.get(name)) {
+                                Some(Some(description)) => {
+                                    crate::render_description(description).trim().to_string()
+                                }
+                                Some(None) => String::new(),
+                                // References are documented by the type they point
+                                // at, the optional around them has the schema data
+                                // of the object.
+                                None => match ts.id_to_entry.get(tid).map(|te| &te.details) {
+                                    Some(TypeDetails::Optional(itid, _)) => ts.render_docs(itid),
+                                    _ => ts.render_docs(tid),
+                                },
+                            };
                             if !p.is_empty() && p != desc {
                                 a("/**");
                                 a(&p);
@@ -175,11 +193,36 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
 
                             let te = ts.id_to_entry.get(tid).unwrap();
 
+                            // Nullable fields in request bodies can be left out,
+                            // set to null or set to a value.
+                            let mut patch = None;
+                            if patch_nullable && nullable.contains(name) {
+                                let itid = match &te.details {
+                                    TypeDetails::Optional(itid, _) => itid,
+                                    _ => tid,
+                                };
+                                // Dates are already rendered as options.
+                                let inner = ts.render_type(itid, true)?;
+                                patch = Some(
+                                    inner
+                                        .strip_prefix("Option<")
+                                        .and_then(|i| i.strip_suffix('>'))
+                                        .unwrap_or(&inner)
+                                        .to_string(),
+                                );
+                            }
+
                             // Render the serde string.
-                            if rt == "String"
+                            if let Some(inner) = patch {
+                                a(
+                                    r#"#[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent","#,
+                                );
+                                rt = format!("crate::utils::Patch<{}>", inner);
+                            } else if rt == "String"
                                 || rt.starts_with("Vec<")
                                 || rt.starts_with("Option<")
                                 || rt.starts_with("HashMap<")
+                                || rt.starts_with("std::collections::BTreeMap<")
                             {
                                 a(r#"#[serde(default,"#);
                                 if rt == "String" {
@@ -192,6 +235,10 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                     a(
                                         r#"skip_serializing_if = "std::collections::HashMap::is_empty","#,
                                     );
+                                } else if rt.starts_with("std::collections::BTreeMap<") {
+                                    a(
+                                        r#"skip_serializing_if = "std::collections::BTreeMap::is_empty","#,
+                                    );
                                 } else if rt.starts_with("Option<url::Url") {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::deserialize_empty_url::deserialize","#);
@@ -314,7 +361,12 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
     Ok(out.to_string())
 }
 
-fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+fn do_one_of_type(
+    ts: &mut TypeSpace,
+    omap: &[crate::TypeId],
+    sn: String,
+    expandable: bool,
+) -> String {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -347,6 +399,9 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
             flatten = false;
         }
     }
+    // We can't pick the variant from the object if the enum is flattened.
+    let expandable = expandable && !flatten;
+
     description.push_str(
         "\nYou can easily convert this enum to the inner value with `From` and `Into`, as both \
          are implemented for each type.\n",
@@ -354,7 +409,12 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     description = format!("/// {}", description.replace('\n', "\n/// "));
     a(&description);
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    if expandable {
+        // We implement Deserialize ourselves below.
+        a("#[derive(Serialize, PartialEq, Debug, Clone, JsonSchema)]");
+    } else {
+        a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    }
     if !flatten {
         a("#[serde(untagged)]");
     }
@@ -397,6 +457,14 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     a("}");
     a("");
 
+    if expandable {
+        let variants = fns
+            .iter()
+            .map(|f| (f.to_string(), name_map.get(f).unwrap().to_string()))
+            .collect::<Vec<(String, String)>>();
+        a(&do_expandable_deserialize(&variants, &sn));
+    }
+
     // Render the implementation to easily unpack these things for the end user.
     a(&format!("impl {} {{", sn));
     for (fn_name, name) in &name_map {
@@ -500,6 +568,79 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     out
 }
 
+/*
+ * Untagged enums try each variant in order, and since our structs default every
+ * field, the first object variant would match any object. Instead look at the
+ * `object` of the value to pick the variant, and only fall back to trying each
+ * variant in order if that doesn't tell us.
+ */
+fn do_expandable_deserialize(variants: &[(String, String)], sn: &str) -> String {
+    let mut arms = String::new();
+    let mut objects: Vec<String> = Default::default();
+    let mut fallback = String::new();
+    for (fn_name, name) in variants {
+        fallback.push_str(&format!(
+            r#"if let Ok(v) = serde_json::from_value(value.clone()) {{
+                return Ok({}::{}(v));
+            }}
+            "#,
+            sn, fn_name
+        ));
+
+        if name.starts_with("Vec<")
+            || name.starts_with("serde_json")
+            || name == "i64"
+            || name == "i32"
+            || name == "f64"
+            || name == "f32"
+            || name == "bool"
+            || objects.contains(&to_snake_case(name))
+        {
+            continue;
+        }
+        objects.push(to_snake_case(name));
+
+        arms.push_str(&format!(
+            r#""{}" => serde_json::from_value(value).map({}::{}).map_err(serde::de::Error::custom),
+            "#,
+            to_snake_case(name),
+            sn,
+            fn_name
+        ));
+    }
+
+    let fallback = format!(
+        r#"{}Err(serde::de::Error::custom("data did not match any variant of {}"))"#,
+        fallback, sn
+    );
+    let body = if arms.is_empty() {
+        fallback
+    } else {
+        format!(
+            r#"match crate::utils::expandable_object(&value).as_str() {{
+                {}_ => {{
+                    {}
+                }}
+            }}"#,
+            arms, fallback
+        )
+    };
+
+    format!(
+        r#"impl<'de> Deserialize<'de> for {} {{
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {{
+                let value = serde_json::Value::deserialize(deserializer)?;
+                {}
+            }}
+        }}
+        "#,
+        sn, body
+    )
+}
+
 fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
     let mut out = String::new();
 
@@ -1,7 +1,12 @@
-const TEMPLATE: &str = r#"use std::{fmt, str::FromStr};
+const TEMPLATE: &str = r#"use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 
 use parse_link_header::LinkMap;
-use serde::de::{self, Visitor};
+use serde::de::{self, DeserializeOwned, Visitor};
 
 pub struct NextLink(pub String);
 
@@ -9,6 +14,253 @@ pub fn next_link(l: &LinkMap) -> Option<NextLink> {
     l.get(&Some("next".to_string())).map(|link| link.raw_uri.to_string()).map(NextLink)
 }
 
+/// A page of results from a paginated request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+    /// The cursor for the next page, or `None` if this is the last page.
+    /// Pass it back in to pick up where you left off.
+    pub next: Option<String>,
+}
+
+/// Turn a stream of pages into a stream of their items.
+pub fn page_items<'a, T, S>(pages: S) -> impl futures::Stream<Item = crate::ClientResult<T>> + Unpin + 'a
+where
+    T: 'a,
+    S: futures::Stream<Item = crate::ClientResult<Page<T>>> + Unpin + 'a,
+{
+    use futures::TryStreamExt;
+
+    pages
+        .map_ok(|page| futures::stream::iter(page.items.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// The number of coerced fields kept by [`Coercions`].
+const MAX_COERCIONS: usize = 1000;
+
+thread_local! {
+    /// Whether the `deserialize_null_*` helpers should fail instead of falling back to defaults.
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+    /// The path to the value [`Tracked`] is deserializing.
+    static PATH: RefCell<Vec<Segment>> = const { RefCell::new(Vec::new()) };
+    /// The fields that fell back to their default in the current deserialization.
+    static COERCED: RefCell<Vec<Coercion>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A field in a response that did not match the generated types and was
+/// replaced with its default value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coercion {
+    /// The JSON path of the field, e.g. `meetings[3].recording_files[0].file_size`.
+    pub path: String,
+    /// What was expected and what was found instead.
+    pub error: String,
+}
+
+/// Parses response bodies, either strictly or keeping a log of every field
+/// that had to be coerced to its default value.
+#[derive(Debug, Clone, Default)]
+pub struct Coercions {
+    pub(crate) strict: bool,
+    log: Arc<Mutex<Vec<Coercion>>>,
+}
+
+impl Coercions {
+    pub(crate) fn parse<T: DeserializeOwned>(&self, body: &[u8]) -> crate::ClientResult<T> {
+        if self.strict {
+            return with_strict(|| {
+                serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(body))
+                    .map_err(|e| crate::ClientError::DeserializationError {
+                        path: e.path().to_string(),
+                        error: e.into_inner().to_string(),
+                    })
+            });
+        }
+
+        let value: serde_json::Value = serde_json::from_slice(body)?;
+        COERCED.with(|c| c.borrow_mut().clear());
+        let parsed = T::deserialize(Tracked(&value));
+        let found = COERCED.with(|c| c.take());
+        let parsed = parsed?;
+
+        if !found.is_empty() {
+            let mut log = self.log.lock().unwrap();
+            for coercion in found {
+                log::warn!("coerced `{}` to its default: {}", coercion.path, coercion.error);
+                log.push(coercion);
+            }
+            let len = log.len();
+            if len > MAX_COERCIONS {
+                log.drain(..len - MAX_COERCIONS);
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub(crate) fn list(&self) -> Vec<Coercion> {
+        self.log.lock().unwrap().clone()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.log.lock().unwrap().clear();
+    }
+}
+
+fn with_strict<R>(f: impl FnOnce() -> R) -> R {
+    let previous = STRICT.with(|s| s.replace(true));
+    let r = f();
+    STRICT.with(|s| s.set(previous));
+    r
+}
+
+/// A step along the path to a value.
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Runs `f` with `segment` pushed onto the current path.
+fn with_segment<R>(segment: Segment, f: impl FnOnce() -> R) -> R {
+    PATH.with(|p| p.borrow_mut().push(segment));
+    let r = f();
+    PATH.with(|p| p.borrow_mut().pop());
+    r
+}
+
+/// The current path, formatted like `serde_path_to_error` does.
+fn current_path() -> String {
+    PATH.with(|p| {
+        let mut path = String::new();
+        for segment in p.borrow().iter() {
+            match segment {
+                Segment::Key(key) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(key);
+                }
+                Segment::Index(index) => path.push_str(&format!("[{}]", index)),
+            }
+        }
+        if path.is_empty() {
+            path.push('.');
+        }
+        path
+    })
+}
+
+/// Deserializes a `serde_json::Value` while keeping track of the path to the
+/// value being deserialized, so [`coerce`] can tell where it fell back to a
+/// default without parsing the body again.
+struct Tracked<'de>(&'de serde_json::Value);
+
+impl<'de> de::Deserializer<'de> for Tracked<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            serde_json::Value::Array(a) => visitor.visit_seq(TrackedSeq(a.iter().enumerate())),
+            serde_json::Value::Object(o) => visitor.visit_map(TrackedMap { iter: o.iter(), value: None }),
+            v => de::Deserializer::deserialize_any(v, visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            serde_json::Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_enum(self.0, name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct TrackedSeq<'de>(std::iter::Enumerate<std::slice::Iter<'de, serde_json::Value>>);
+
+impl<'de> de::SeqAccess<'de> for TrackedSeq<'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<S: de::DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error> {
+        match self.0.next() {
+            Some((index, value)) => with_segment(Segment::Index(index), || seed.deserialize(Tracked(value))).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct TrackedMap<'de> {
+    iter: serde_json::map::Iter<'de>,
+    value: Option<(&'de String, &'de serde_json::Value)>,
+}
+
+impl<'de> de::MapAccess<'de> for TrackedMap<'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<S: de::DeserializeSeed<'de>>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some((key, value));
+                seed.deserialize(de::value::BorrowedStrDeserializer::new(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S: de::DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, Self::Error> {
+        match self.value.take() {
+            Some((key, value)) => with_segment(Segment::Key(key.to_string()), || seed.deserialize(Tracked(value))),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+/// Called by the `deserialize_null_*` helpers when a field does not match its
+/// type: an error in strict mode, otherwise the default value.
+fn coerce<T: Default, E: de::Error>(e: E) -> Result<T, E> {
+    if STRICT.with(|s| s.get()) {
+        return Err(e);
+    }
+
+    COERCED.with(|c| {
+        c.borrow_mut().push(Coercion {
+            path: current_path(),
+            error: e.to_string(),
+        })
+    });
+    Ok(Default::default())
+}
+
 pub mod date_format {
     use chrono::{NaiveDate};
     use serde::{self, Deserialize, Deserializer};
@@ -130,18 +382,17 @@ pub mod deserialize_empty_url {
     where
         D: Deserializer<'de>,
     {
-        if let Ok(s) = String::deserialize(deserializer) {
-            if s.is_empty() {
-                return Ok(None);
-            }
-
-            match url::Url::parse(&s) {
-                Ok(u) => return Ok(Some(u)),
-                Err(e) => return Err(serde::de::Error::custom(format!("error url parsing {}: {}", s, e))),
-            }
+        let s = Option::<String>::deserialize(deserializer)
+            .or_else(crate::utils::coerce)?
+            .unwrap_or_default();
+        if s.is_empty() {
+            return Ok(None);
         }
 
-        Ok(None)
+        match url::Url::parse(&s) {
+            Ok(u) => Ok(Some(u)),
+            Err(e) => Err(serde::de::Error::custom(format!("error url parsing {}: {}", s, e))),
+        }
     }
 }
 
@@ -159,7 +410,9 @@ pub mod deserialize_null_string {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer).unwrap_or_default();
+        let s = Option::<String>::deserialize(deserializer)
+            .or_else(crate::utils::coerce)?
+            .unwrap_or_default();
 
         Ok(s)
     }
@@ -170,6 +423,20 @@ struct BoolVisitor;
 impl<'de> Visitor<'de> for BoolVisitor {
     type Value = bool;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_bool(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a boolean")
     }
@@ -223,8 +490,8 @@ pub mod deserialize_null_boolean {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_bool(crate::utils::BoolVisitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::BoolVisitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -235,6 +502,20 @@ struct I32Visitor;
 impl<'de> Visitor<'de> for I32Visitor {
     type Value = i32;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_i32(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("an integer between -2^31 and 2^31")
     }
@@ -295,8 +576,8 @@ pub mod deserialize_null_i32 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_i32(crate::utils::I32Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::I32Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -307,6 +588,20 @@ struct I64Visitor;
 impl<'de> Visitor<'de> for I64Visitor {
     type Value = i64;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_i64(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("an integer")
     }
@@ -362,8 +657,8 @@ pub mod deserialize_null_i64 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_i64(crate::utils::I64Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::I64Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -374,6 +669,20 @@ struct F32Visitor;
 impl<'de> Visitor<'de> for F32Visitor {
     type Value = f32;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_f32(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a float between -2^31 and 2^31")
     }
@@ -453,8 +762,8 @@ pub mod deserialize_null_f32 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_f32(crate::utils::F32Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::F32Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -465,6 +774,20 @@ struct F64Visitor;
 impl<'de> Visitor<'de> for F64Visitor {
     type Value = f64;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a float")
     }
@@ -534,8 +857,8 @@ pub mod deserialize_null_f64 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_f64(crate::utils::F64Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::F64Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -597,6 +920,20 @@ where
         formatter.write_str("a very special vector")
     }
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+
     fn visit_seq<A: de::SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
         let mut things: Vec<T> = Default::default();
 
@@ -627,17 +964,15 @@ pub mod deserialize_null_vector {
         T: Deserialize<'de>,
         D: Deserializer<'de>,
     {
-        if let Ok(r) = deserializer.deserialize_seq(VectorVisitor::<T>(std::marker::PhantomData)) {
-            return Ok(r);
-        }
-
-        Ok(Default::default())
+        deserializer
+            .deserialize_option(VectorVisitor::<T>(std::marker::PhantomData))
+            .or_else(crate::utils::coerce)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::next_link;
+    use super::{next_link, page_items, Coercions, Page};
 
     #[test]
     fn test_hyperx_next_link_compat() {
@@ -648,6 +983,106 @@ mod tests {
 
         assert_eq!("https://next-link", next);
     }
+
+    #[tokio::test]
+    async fn test_page_items() {
+        use futures::{StreamExt, TryStreamExt};
+
+        let pages = futures::stream::iter(vec![
+            Ok(Page { items: vec![1, 2], next: Some("2".to_string()) }),
+            Ok(Page { items: vec![], next: Some("3".to_string()) }),
+            Ok(Page { items: vec![3], next: None }),
+        ]);
+        let items: Vec<i32> = page_items(pages).try_collect().await.unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+
+        // We stop early if we hit an error.
+        let pages = futures::stream::iter(vec![
+            Ok(Page { items: vec![1], next: Some("2".to_string()) }),
+            Err(crate::ClientError::HttpError {
+                status: http::StatusCode::INTERNAL_SERVER_ERROR,
+                error: "".to_string(),
+                request_id: None,
+            }),
+        ]);
+        let items: Vec<_> = page_items(pages).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(items[1].is_err());
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Item {
+        #[serde(default, deserialize_with = "crate::utils::deserialize_null_string::deserialize")]
+        name: String,
+        #[serde(default, deserialize_with = "crate::utils::deserialize_null_i64::deserialize")]
+        size: i64,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Items {
+        #[serde(default, deserialize_with = "crate::utils::deserialize_null_vector::deserialize")]
+        items: Vec<Item>,
+    }
+
+    const ITEMS: &str = "{\"items\": [{\"name\": \"a\", \"size\": 1}, {\"name\": 2, \"size\": null}, {\"name\": null, \"size\": \"big\"}]}";
+
+    #[test]
+    fn test_coercions_logged() {
+        let coercions = Coercions::default();
+        let parsed: Items = coercions.parse(ITEMS.as_bytes()).unwrap();
+        assert_eq!(parsed.items.len(), 3);
+        assert_eq!(parsed.items[1].name, "");
+        assert_eq!(parsed.items[1].size, 0);
+        assert_eq!(parsed.items[2].size, 0);
+
+        // Nulls are expected, only the mismatched types are logged.
+        let paths: Vec<String> = coercions.list().into_iter().map(|c| c.path).collect();
+        assert_eq!(paths, vec!["items[1].name", "items[2].size"]);
+
+        coercions.clear();
+        let _: Items = coercions.parse(b"{\"items\": null}").unwrap();
+        assert!(coercions.list().is_empty());
+    }
+
+    #[test]
+    fn test_coercions_strict() {
+        let coercions = Coercions { strict: true, ..Default::default() };
+        match coercions.parse::<Items>(ITEMS.as_bytes()) {
+            Err(crate::ClientError::DeserializationError { path, error }) => {
+                assert_eq!(path, "items[1].name");
+                assert!(error.contains("expected a string"), "{}", error);
+            }
+            r => panic!("expected a deserialization error, got {:?}", r),
+        }
+
+        // Nulls are still fine.
+        let parsed: Items = coercions.parse(b"{\"items\": [{\"name\": null, \"size\": null}]}").unwrap();
+        assert_eq!(parsed.items[0].size, 0);
+    }
+
+    #[test]
+    fn test_response() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(crate::REQUEST_ID_HEADER, "req_123".parse().unwrap());
+        let response = crate::Response {
+            status: reqwest::StatusCode::CREATED,
+            headers,
+            raw: bytes::Bytes::from_static(b"{\"items\": []}"),
+            body: Items { items: vec![] },
+        };
+        assert_eq!(response.request_id(), Some("req_123"));
+
+        let response = response.map(|b| b.items.len());
+        assert_eq!(response.status, reqwest::StatusCode::CREATED);
+        assert_eq!(response.body, 0);
+        assert_eq!(&response.raw[..], b"{\"items\": []}");
+
+        // Only the header of this API counts.
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-amzn-requestid", "req_456".parse().unwrap());
+        let response = crate::Response { headers, ..response };
+        assert_eq!(response.request_id(), None);
+    }
 }
 
 "#;
@@ -740,9 +1175,85 @@ impl From<MediaType> for mime::Mime {
     }
 }
 
+/// The error GitHub sends back, see
+/// <https://docs.github.com/en/rest/overview/resources-in-the-rest-api#client-errors>.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GitHubApiError {
+    #[serde(default)]
+    pub message: String,
+    /// The validation errors, one for each field of the request that was wrong.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_github_errors"
+    )]
+    pub errors: Vec<GitHubErrorDetail>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub documentation_url: String,
+}
+
+/// A validation error for a field of a request.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GitHubErrorDetail {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub resource: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub field: String,
+    /// What was wrong, e.g. `missing_field` or `already_exists`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub code: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+}
+
+/// Some endpoints send the errors as plain strings.
+fn deserialize_github_errors<'de, D>(deserializer: D) -> Result<Vec<GitHubErrorDetail>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Detail {
+        Detail(GitHubErrorDetail),
+        Message(String),
+    }
+
+    let errors: Option<Vec<Detail>> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(errors
+        .unwrap_or_default()
+        .into_iter()
+        .map(|e| match e {
+            Detail::Detail(detail) => detail,
+            Detail::Message(message) => GitHubErrorDetail {
+                message,
+                ..Default::default()
+            },
+        })
+        .collect())
+}
+
+/// Parse the body of a failed request into a `ClientError::GitHubError`, if
+/// it is one of GitHub's errors.
+pub(crate) fn github_error(
+    status: http::StatusCode,
+    body: &[u8],
+    request_id: Option<String>,
+) -> Option<crate::ClientError> {
+    let error = serde_json::from_slice::<GitHubApiError>(body)
+        .ok()
+        .filter(|e| !e.message.is_empty())?;
+
+    Some(crate::ClientError::GitHubError {
+        status,
+        error: Box::new(error),
+        body: String::from_utf8_lossy(body).into(),
+        request_id,
+    })
+}
+
 #[cfg(test)]
 mod github_tests {
-    use super::MediaType;
+    use super::{github_error, MediaType};
 
     #[test]
     fn test_hyperx_qitem_compat() {
@@ -758,15 +1269,2309 @@ mod github_tests {
             )
         }
     }
+
+    #[test]
+    fn test_unknown_enum_round_trip() {
+        use crate::types::AuthorAssociation;
+
+        let known: AuthorAssociation = serde_json::from_str("\"OWNER\"").unwrap();
+        assert_eq!(known, AuthorAssociation::Owner);
+
+        let unknown: AuthorAssociation = serde_json::from_str("\"MAINTAINER\"").unwrap();
+        assert_eq!(unknown, AuthorAssociation::Unknown("MAINTAINER".to_string()));
+        assert_eq!(unknown.to_string(), "MAINTAINER");
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "\"MAINTAINER\"");
+    }
+
+    #[test]
+    fn test_github_error() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "message": "Validation Failed",
+            "errors": [
+                {"resource": "Issue", "field": "title", "code": "missing_field"},
+                "Label does not exist",
+            ],
+            "documentation_url": "https://docs.github.com/rest/issues/issues#create-an-issue",
+        }))
+        .unwrap();
+        match github_error(http::StatusCode::UNPROCESSABLE_ENTITY, &body, Some("req_123".to_string())) {
+            Some(crate::ClientError::GitHubError { status, error, body, request_id }) => {
+                assert_eq!(request_id.as_deref(), Some("req_123"));
+                assert_eq!(status, http::StatusCode::UNPROCESSABLE_ENTITY);
+                assert_eq!(error.message, "Validation Failed");
+                assert_eq!(error.errors.len(), 2);
+                assert_eq!(error.errors[0].field, "title");
+                assert_eq!(error.errors[0].code, "missing_field");
+                assert_eq!(error.errors[1].message, "Label does not exist");
+                assert!(body.contains("Validation Failed"));
+            }
+            r => panic!("expected a github error, got {:?}", r),
+        }
+
+        assert!(github_error(http::StatusCode::BAD_GATEWAY, b"<html></html>", None).is_none());
+        assert!(github_error(http::StatusCode::NOT_FOUND, b"{}", None).is_none());
+    }
 }
 
 "#;
 
-pub fn generate_utils(proper_name: &str) -> String {
-    let mut optional = String::new();
-    if proper_name == "GitHub" {
-        optional = GITHUB_TEMPLATE.to_string();
+const FORM_TEMPLATE: &str = r#"/// Encode a request body as `application/x-www-form-urlencoded`.
+///
+/// Nested objects and arrays are flattened with bracket notation, the way
+/// the API expects them: `metadata[key]=value`, `items[0][price]=price_123`.
+/// Null values are left out of the encoded body.
+pub fn encode_form<T: serde::Serialize>(body: &T) -> Result<String, serde_json::Error> {
+    let value = serde_json::to_value(body)?;
+
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    if let serde_json::Value::Object(o) = value {
+        for (k, v) in o {
+            encode_form_value(&mut serializer, k, v);
+        }
+    }
+
+    Ok(serializer.finish())
+}
+
+fn encode_form_value(
+    serializer: &mut url::form_urlencoded::Serializer<String>,
+    key: String,
+    value: serde_json::Value,
+) {
+    match value {
+        serde_json::Value::Null => {}
+        serde_json::Value::Bool(b) => {
+            serializer.append_pair(&key, &b.to_string());
+        }
+        serde_json::Value::Number(n) => {
+            serializer.append_pair(&key, &n.to_string());
+        }
+        serde_json::Value::String(s) => {
+            serializer.append_pair(&key, &s);
+        }
+        serde_json::Value::Array(a) => {
+            for (i, v) in a.into_iter().enumerate() {
+                encode_form_value(serializer, format!("{}[{}]", key, i), v);
+            }
+        }
+        serde_json::Value::Object(o) => {
+            for (k, v) in o {
+                encode_form_value(serializer, format!("{}[{}]", key, k), v);
+            }
+        }
+    }
+}
+"#;
+
+const STRIPE_FORM_TESTS: &str = r#"#[cfg(test)]
+mod form_tests {
+    use super::{encode_form, multipart_form};
+
+    #[test]
+    fn test_encode_form_brackets() {
+        let body = serde_json::json!({
+            "email": "jenny@example.com",
+            "metadata": {
+                "order_id": "6735",
+            },
+            "items": [
+                {"price": "price_123", "quantity": 2},
+            ],
+            "expand": ["customer"],
+            "livemode": false,
+            "description": null,
+        });
+
+        let encoded = encode_form(&body).unwrap();
+        let mut pairs = url::form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect::<Vec<(String, String)>>();
+        pairs.sort();
+
+        let expected = [
+            ("email", "jenny@example.com"),
+            ("expand[0]", "customer"),
+            ("items[0][price]", "price_123"),
+            ("items[0][quantity]", "2"),
+            ("livemode", "false"),
+            ("metadata[order_id]", "6735"),
+        ];
+        assert_eq!(pairs.len(), expected.len());
+        for ((k, v), (ek, ev)) in pairs.iter().zip(expected.iter()) {
+            assert_eq!(k, ek);
+            assert_eq!(v, ev);
+        }
+    }
+
+    #[test]
+    fn test_encode_form_metadata() {
+        let mut body: crate::types::PostCouponsCouponRequest =
+            serde_json::from_value(serde_json::json!({
+                "name": "Summer sale",
+            }))
+            .unwrap();
+        body.metadata.insert("order_id".to_string(), "6735".to_string());
+
+        let encoded = encode_form(&body).unwrap();
+        let mut pairs = url::form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect::<Vec<(String, String)>>();
+        pairs.sort();
+
+        let expected = [("metadata[order_id]", "6735"), ("name", "Summer sale")];
+        assert_eq!(pairs.len(), expected.len(), "{:?}", pairs);
+        for ((k, v), (ek, ev)) in pairs.iter().zip(expected.iter()) {
+            assert_eq!(k, ek);
+            assert_eq!(v, ev);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_multipart_form_file_upload() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let body: crate::types::PostFilesRequest = serde_json::from_value(serde_json::json!({
+            "purpose": "dispute_evidence",
+            "file_link_data": {"create": true},
+        }))
+        .unwrap();
+        let form = multipart_form(&body, Some(b"%PDF-1.4")).unwrap();
+        reqwest::Client::new()
+            .post(server.uri())
+            .multipart(form)
+            .send()
+            .await
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("name=\"purpose\"\r\n\r\ndispute_evidence\r\n"), "{}", body);
+        assert!(body.contains("name=\"file_link_data[create]\"\r\n\r\ntrue\r\n"), "{}", body);
+        assert!(body.contains("name=\"file\"; filename=\"file\""), "{}", body);
+        assert!(body.contains("%PDF-1.4"), "{}", body);
+    }
+}
+"#;
+
+const MULTIPART_TEMPLATE: &str = r#"/// Build a `multipart/form-data` form from a request body.
+///
+/// Every field of the body is sent as a text part, nested fields with the
+/// bracket notation of `encode_form`. If `file` is set, its contents are sent
+/// in the `file` part, named after the body's `filename`.
+pub fn multipart_form<T: serde::Serialize>(
+    body: &T,
+    file: Option<&[u8]>,
+) -> Result<reqwest::multipart::Form, serde_json::Error> {
+    let encoded = encode_form(body)?;
+
+    let mut form = reqwest::multipart::Form::new();
+    let mut filename = "file".to_string();
+    for (k, v) in url::form_urlencoded::parse(encoded.as_bytes()).into_owned() {
+        if k == "filename" && !v.is_empty() {
+            filename = v.to_string();
+        }
+        if k == "file" && file.is_some() {
+            continue;
+        }
+
+        form = form.text(k, v);
+    }
+
+    if let Some(f) = file {
+        form = form.part("file", reqwest::multipart::Part::bytes(f.to_vec()).file_name(filename));
+    }
+
+    Ok(form)
+}
+"#;
+
+const SLACK_FORM_TESTS: &str = r##"#[cfg(test)]
+mod form_tests {
+    use super::{encode_form, multipart_form};
+
+    #[test]
+    fn test_encode_form_chat_post_message() {
+        let body: crate::types::ChatPostMessageRequest = serde_json::from_value(serde_json::json!({
+            "channel": "C1234567890",
+            "text": "Hello world :tada:",
+            "blocks": r#"[{"type":"section","text":{"type":"mrkdwn","text":"Hello"}}]"#,
+            "thread_ts": "1503435956.000247",
+            "unfurl_links": false,
+            "username": "",
+        }))
+        .unwrap();
+
+        let encoded = encode_form(&body).unwrap();
+        let mut pairs = url::form_urlencoded::parse(encoded.as_bytes())
+            .into_owned()
+            .collect::<Vec<(String, String)>>();
+        pairs.sort();
+
+        // Slack takes blocks and attachments as JSON in a single field, empty
+        // fields are left out.
+        let expected = [
+            ("blocks", r#"[{"type":"section","text":{"type":"mrkdwn","text":"Hello"}}]"#),
+            ("channel", "C1234567890"),
+            ("text", "Hello world :tada:"),
+            ("thread_ts", "1503435956.000247"),
+            ("unfurl_links", "false"),
+        ];
+        assert_eq!(pairs.len(), expected.len(), "{:?}", pairs);
+        for ((k, v), (ek, ev)) in pairs.iter().zip(expected.iter()) {
+            assert_eq!(k, ek);
+            assert_eq!(v, ev);
+        }
+    }
+
+    fn files_upload_request() -> crate::types::FilesUploadRequest {
+        serde_json::from_value(serde_json::json!({
+            "channels": "C1234567890,C0987654321",
+            "filename": "notes.txt",
+            "initial_comment": "The notes from today",
+            "title": "Notes",
+        }))
+        .unwrap()
+    }
+
+    async fn received_body(server: &wiremock::MockServer) -> (String, String) {
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+        let content_type = requests[0]
+            .headers
+            .get(&"content-type".into())
+            .unwrap()
+            .last()
+            .as_str()
+            .to_string();
+        (content_type, String::from_utf8_lossy(&requests[0].body).to_string())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_form() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let form = multipart_form(&files_upload_request(), Some(b"first line\nsecond line")).unwrap();
+        reqwest::Client::new()
+            .post(server.uri())
+            .multipart(form)
+            .send()
+            .await
+            .unwrap();
+
+        let (content_type, body) = received_body(&server).await;
+        assert!(content_type.starts_with("multipart/form-data; boundary="), "{}", content_type);
+        assert!(body.contains("name=\"channels\"\r\n\r\nC1234567890,C0987654321\r\n"), "{}", body);
+        assert!(body.contains("name=\"title\"\r\n\r\nNotes\r\n"), "{}", body);
+        assert!(body.contains("name=\"file\"; filename=\"notes.txt\""), "{}", body);
+        assert!(body.contains("first line\nsecond line"), "{}", body);
+        // Empty fields are not sent.
+        assert!(!body.contains("name=\"content\""), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn test_multipart_form_without_file() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let mut body = files_upload_request();
+        body.content = "inline contents".to_string();
+        let form = multipart_form(&body, None).unwrap();
+        reqwest::Client::new()
+            .post(server.uri())
+            .multipart(form)
+            .send()
+            .await
+            .unwrap();
+
+        let (_, body) = received_body(&server).await;
+        assert!(body.contains("name=\"content\"\r\n\r\ninline contents\r\n"), "{}", body);
+        assert!(!body.contains("filename="), "{}", body);
+    }
+
+    #[tokio::test]
+    async fn test_files_upload() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("POST"))
+            .and(matchers::path("/files.upload"))
+            .and(matchers::header("authorization", "Bearer xoxb-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "file": {"id": "F0S43PZDF", "name": "notes.txt", "title": "Notes"},
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let mut client = crate::Client::new("", "", "", "xoxb-token", "");
+        client.with_host_override(server.uri());
+        let uploaded = client
+            .files()
+            .upload(Some(b"first line\nsecond line"), &files_upload_request())
+            .await
+            .unwrap();
+        assert!(uploaded.ok);
+        assert_eq!(uploaded.file.id, "F0S43PZDF");
+
+        let (content_type, body) = received_body(&server).await;
+        assert!(content_type.starts_with("multipart/form-data"), "{}", content_type);
+        assert!(body.contains("name=\"file\"; filename=\"notes.txt\""), "{}", body);
+        assert!(body.contains("name=\"initial_comment\"\r\n\r\nThe notes from today\r\n"), "{}", body);
+    }
+}
+"##;
+
+const SLACK_TEMPLATE: &str = r#"/// The metadata Slack sends back alongside an error.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SlackResponseMetadata {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct SlackOk {
+    ok: Option<bool>,
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    warning: String,
+    #[serde(default)]
+    response_metadata: Option<SlackResponseMetadata>,
+}
+
+/// Slack returns a 200 for failed calls, with `ok` set to false in the body.
+/// Turn those into a `ClientError::SlackError`.
+pub(crate) fn slack_ok(body: &[u8], request_id: Option<String>) -> Result<(), crate::ClientError> {
+    if let Ok(SlackOk {
+        ok: Some(false),
+        error,
+        warning,
+        response_metadata,
+    }) = serde_json::from_slice::<SlackOk>(body)
+    {
+        return Err(crate::ClientError::SlackError {
+            error,
+            warning,
+            response_metadata,
+            request_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Get the number of seconds to wait from a `Retry-After` header.
+pub(crate) fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<u64> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+}
+
+#[cfg(test)]
+mod slack_tests {
+    use super::{retry_after, slack_ok};
+
+    #[test]
+    fn test_slack_ok() {
+        let body = serde_json::json!({"ok": true, "channel": "C123"});
+        assert!(slack_ok(&serde_json::to_vec(&body).unwrap(), None).is_ok());
+        assert!(slack_ok(b"", None).is_ok());
+
+        let body = serde_json::json!({
+            "ok": false,
+            "error": "invalid_arguments",
+            "response_metadata": {
+                "messages": ["[ERROR] missing required field: channel"],
+            },
+        });
+        match slack_ok(&serde_json::to_vec(&body).unwrap(), Some("req_123".to_string())) {
+            Err(crate::ClientError::SlackError {
+                error,
+                warning,
+                response_metadata,
+                request_id,
+            }) => {
+                assert_eq!(request_id.as_deref(), Some("req_123"));
+                assert_eq!(error, "invalid_arguments");
+                assert_eq!(warning, "");
+                assert_eq!(
+                    response_metadata.unwrap().messages,
+                    vec!["[ERROR] missing required field: channel".to_string()]
+                );
+            }
+            r => panic!("expected a slack error, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(reqwest::header::RETRY_AFTER, "30".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(30));
+    }
+}
+"#;
+
+const SHOPIFY_TEMPLATE: &str = r#"/// Get the name of a shop, ie. `my-shop`, from its `my-shop.myshopify.com`
+/// domain. The name alone, or the domain with a scheme or a trailing slash,
+/// work too.
+///
+/// Anything but letters, digits and dashes is refused, so the urls we build
+/// from it, and send the client secret to, always point at Shopify.
+pub(crate) fn shop_name(shop: &str) -> Result<String, crate::ClientError> {
+    let name = shop.trim();
+    let name = name
+        .strip_prefix("https://")
+        .or_else(|| name.strip_prefix("http://"))
+        .unwrap_or(name);
+    let name = name.trim_end_matches('/');
+    let name = name.strip_suffix(".myshopify.com").unwrap_or(name);
+
+    if name.is_empty()
+        || name.starts_with('-')
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    {
+        return Err(crate::ClientError::InvalidShopDomain(shop.to_string()));
+    }
+
+    Ok(name.to_string())
+}
+
+/// The query parameters Shopify sends to the redirect url once a merchant
+/// has installed the app, see
+/// <https://shopify.dev/apps/auth/oauth/getting-started#step-3-confirm-installation>.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct OAuthCallback {
+    /// The authorization code to exchange for an access token.
+    #[serde(default)]
+    pub code: String,
+    /// The name of the shop, ie. `my-shop`.
+    #[serde(default)]
+    pub shop: String,
+    /// The state passed to the consent url, check it is the one you sent.
+    #[serde(default)]
+    pub state: String,
+    /// The base64 encoded host of the admin the merchant installed the app from.
+    #[serde(default)]
+    pub host: String,
+    /// When Shopify sent the merchant back, in seconds since the epoch.
+    #[serde(default)]
+    pub timestamp: String,
+}
+
+/// Check the `hmac` Shopify signs the query parameters of a redirect with,
+/// see <https://shopify.dev/apps/auth/oauth/getting-started#step-4-verify-the-installation-request>.
+pub(crate) fn verify_oauth_callback(
+    client_secret: &str,
+    query: &str,
+) -> Result<OAuthCallback, crate::ClientError> {
+    let query = query.trim_start_matches('?');
+
+    let mut hmac = None;
+    let mut params: Vec<(String, String)> = Vec::new();
+    for (k, v) in url::form_urlencoded::parse(query.as_bytes()) {
+        match k.as_ref() {
+            "hmac" => hmac = Some(v.to_string()),
+            // Shopify signs the legacy `signature` differently, leave it out.
+            "signature" => (),
+            _ => params.push((k.to_string(), v.to_string())),
+        }
+    }
+
+    let hmac = hmac.ok_or_else(|| {
+        crate::ClientError::InvalidOAuthCallback("missing the hmac parameter".to_string())
+    })?;
+    let hmac = decode_hex(&hmac).ok_or_else(|| {
+        crate::ClientError::InvalidOAuthCallback("the hmac parameter is not hex".to_string())
+    })?;
+
+    // The parameters are signed sorted by name, with `%` and `&` escaped in
+    // the values and `%`, `&` and `=` in the names.
+    params.sort();
+    let message = params
+        .iter()
+        .map(|(k, v)| {
+            let k = k.replace('%', "%25").replace('&', "%26").replace('=', "%3D");
+            let v = v.replace('%', "%25").replace('&', "%26");
+            format!("{}={}", k, v)
+        })
+        .collect::<Vec<String>>()
+        .join("&");
+
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, client_secret.as_bytes());
+    ring::hmac::verify(&key, message.as_bytes(), &hmac).map_err(|_| {
+        crate::ClientError::InvalidOAuthCallback("the hmac does not match".to_string())
+    })?;
+
+    let mut callback: OAuthCallback =
+        serde_urlencoded::from_str(query).map_err(|e| crate::ClientError::InvalidOAuthCallback(e.to_string()))?;
+    callback.shop = shop_name(&callback.shop)?;
+
+    Ok(callback)
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|c| match c {
+            [_, _] => u8::from_str_radix(std::str::from_utf8(c).ok()?, 16).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Put the API version back in the path of a versioned endpoint, ie.
+/// `/admin/api/orders.json` becomes `/admin/api/2026-07/orders.json`.
+pub(crate) fn api_path(api_version: &str, path: &str) -> String {
+    match path.strip_prefix("/admin/api/") {
+        Some(rest) => format!("/admin/api/{}/{}", api_version, rest),
+        None => path.to_string(),
+    }
+}
+
+/// Logs a warning for the responses Shopify flags with
+/// `X-Shopify-API-Deprecated-Reason`: the endpoint, or the API version, is
+/// going away.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeprecationMiddleware;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for DeprecationMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let method = req.method().clone();
+        let path = req.url().path().to_string();
+
+        let response = next.run(req, extensions).await?;
+        if let Some(reason) = response.headers().get("x-shopify-api-deprecated-reason") {
+            log::warn!(
+                "{} {} is deprecated: {}",
+                method,
+                path,
+                String::from_utf8_lossy(reason.as_bytes())
+            );
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod shopify_tests {
+    use super::{api_path, shop_name, verify_oauth_callback};
+
+    #[test]
+    fn test_api_path() {
+        assert_eq!(
+            api_path("2021-01", "/admin/api/orders/1.json"),
+            "/admin/api/2021-01/orders/1.json"
+        );
+        assert_eq!(
+            api_path("2021-01", "/admin/oauth/access_scopes.json"),
+            "/admin/oauth/access_scopes.json"
+        );
+    }
+
+    #[test]
+    fn test_client_api_version() {
+        let mut client = crate::Client::new_for_shop("my-shop", "id", "secret", "", "").unwrap();
+        assert_eq!(
+            client.url("/admin/api/orders.json", None),
+            format!("https://my-shop.myshopify.com/admin/api/{}/orders.json", crate::DEFAULT_API_VERSION)
+        );
+
+        let unstable = client.with_api_version("unstable");
+        assert_eq!(
+            unstable.url("/admin/api/orders.json", None),
+            "https://my-shop.myshopify.com/admin/api/unstable/orders.json"
+        );
+        assert_eq!(client.api_version(), crate::DEFAULT_API_VERSION);
+
+        client.set_api_version("2021-01");
+        assert_eq!(
+            client.url("/admin/api/orders.json", None),
+            "https://my-shop.myshopify.com/admin/api/2021-01/orders.json"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_page_info_pagination() {
+        use futures::StreamExt;
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let path = format!("/admin/api/{}/customers/1/orders.json", crate::DEFAULT_API_VERSION);
+        // The first mock that matches wins, so the second page goes first.
+        Mock::given(matchers::path(path.as_str()))
+            .and(matchers::query_param("page_info", "abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "orders": [{ "id": 2 }] })))
+            .mount(&server)
+            .await;
+        Mock::given(matchers::path(path.as_str()))
+            .and(matchers::query_param("limit", "250"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "link",
+                        format!("<{}{}?limit=250&page_info=abc>; rel=\"next\"", server.uri(), path).as_str(),
+                    )
+                    .insert_header("x-shopify-shop-api-call-limit", "1/40")
+                    .set_body_json(serde_json::json!({ "orders": [{ "id": 1 }] })),
+            )
+            .mount(&server)
+            .await;
+
+        let mut client = crate::Client::new_for_shop("my-shop", "id", "secret", "", "token").unwrap();
+        client.with_host_override(server.uri());
+        let customers = client.customers();
+
+        let orders = customers.get_all_param_orders("1").await.unwrap();
+        assert_eq!(orders.iter().map(|o| o.id).collect::<Vec<_>>(), vec![1, 2]);
+
+        let pages: Vec<_> = customers.get_all_param_orders_pages_stream("1", None).collect().await;
+        let first = pages[0].as_ref().unwrap();
+        assert_eq!(
+            first.next,
+            Some(format!("{}{}?limit=250&page_info=abc", server.uri(), path))
+        );
+        assert_eq!(pages[1].as_ref().unwrap().next, None);
+        assert_eq!(pages.len(), 2);
+    }
+
+    fn sign(secret: &str, message: &str) -> String {
+        let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, secret.as_bytes());
+        ring::hmac::sign(&key, message.as_bytes())
+            .as_ref()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    #[test]
+    fn test_shop_name() {
+        for shop in [
+            "my-shop",
+            "my-shop.myshopify.com",
+            "https://my-shop.myshopify.com/",
+            " my-shop.myshopify.com ",
+        ] {
+            assert_eq!(shop_name(shop).unwrap(), "my-shop");
+        }
+
+        for shop in ["", "evil.com/", "my-shop.evil.com", "evil.com#", "-shop"] {
+            assert!(shop_name(shop).is_err(), "{}", shop);
+        }
+    }
+
+    #[test]
+    fn test_verify_oauth_callback() {
+        let message = "code=0907a61c0c8d55e99db179b68161bc00&shop=some-shop.myshopify.com&state=0.6784241404160823&timestamp=1337178173";
+        let hmac = sign("hush", message);
+
+        // Shopify does not send the parameters in order.
+        let query = format!(
+            "?shop=some-shop.myshopify.com&code=0907a61c0c8d55e99db179b68161bc00&hmac={}&state=0.6784241404160823&timestamp=1337178173",
+            hmac
+        );
+        let callback = verify_oauth_callback("hush", &query).unwrap();
+        assert_eq!(callback.code, "0907a61c0c8d55e99db179b68161bc00");
+        assert_eq!(callback.shop, "some-shop");
+        assert_eq!(callback.state, "0.6784241404160823");
+        assert_eq!(callback.timestamp, "1337178173");
+
+        assert!(verify_oauth_callback("wrong", &query).is_err());
+        assert!(verify_oauth_callback("hush", &query.replace("code=09", "code=19")).is_err());
+        assert!(verify_oauth_callback("hush", message).is_err());
+
+        // A valid signature does not make any shop domain acceptable.
+        let message = "code=abc&shop=evil.com&timestamp=1337178173";
+        let query = format!("{}&hmac={}", message, sign("hush", message));
+        match verify_oauth_callback("hush", &query) {
+            Err(crate::ClientError::InvalidShopDomain(shop)) => assert_eq!(shop, "evil.com"),
+            r => panic!("expected an invalid shop domain, got {:?}", r),
+        }
+    }
+}
+"#;
+
+const PATCH_TEMPLATE: &str = r#"/// A nullable field in a request body.
+///
+/// Unlike an `Option`, this tells "leave it unchanged" apart from "clear it":
+/// `Absent` leaves the field out of the request, `Null` sends `null` and
+/// `Value` sends the value.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Patch<T> {
+    /// Leave the field out of the request.
+    #[default]
+    Absent,
+    /// Send `null`, clearing the field.
+    Null,
+    /// Send the value.
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    /// Get the value, if there is one.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for Patch<T> {
+    fn from(value: T) -> Self {
+        Patch::Value(value)
+    }
+}
+
+impl<T> From<Option<T>> for Patch<T> {
+    /// `None` clears the field.
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(v) => Patch::Value(v),
+            None => Patch::Null,
+        }
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Patch<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Patch::Value(v) => serializer.serialize_some(v),
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Patch<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A missing field never gets here, it is `Absent` through `#[serde(default)]`.
+        Ok(Option::<T>::deserialize(deserializer)?.into())
+    }
+}
+
+impl<T: schemars::JsonSchema> schemars::JsonSchema for Patch<T> {
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn schema_name() -> String {
+        Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Option::<T>::json_schema(gen)
+    }
+}
+
+#[cfg(test)]
+mod patch_tests {
+    use super::Patch;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Request {
+        #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+        milestone: Patch<i64>,
+    }
+
+    #[test]
+    fn test_patch_serialize() {
+        let absent = Request { milestone: Patch::Absent };
+        assert_eq!(serde_json::to_string(&absent).unwrap(), "{}");
+
+        let null = Request { milestone: None.into() };
+        assert_eq!(serde_json::to_string(&null).unwrap(), "{\"milestone\":null}");
+
+        let value = Request { milestone: 3.into() };
+        assert_eq!(serde_json::to_string(&value).unwrap(), "{\"milestone\":3}");
+    }
+
+    #[test]
+    fn test_patch_deserialize() {
+        for (json, milestone) in [
+            ("{}", Patch::Absent),
+            ("{\"milestone\":null}", Patch::Null),
+            ("{\"milestone\":3}", Patch::Value(3)),
+        ] {
+            let r: Request = serde_json::from_str(json).unwrap();
+            assert_eq!(r.milestone, milestone);
+        }
+    }
+}
+"#;
+
+const STRIPE_TEMPLATE: &str = r#"/// Get the kind of a possibly expanded Stripe object.
+///
+/// IDs are strings and expanded objects carry their type in `object`, e.g.
+/// `"customer"`. Deleted objects are prefixed with `deleted_`, so we can tell
+/// a `Customer` from a `DeletedCustomer`.
+pub fn expandable_object(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(_) => "string".to_string(),
+        serde_json::Value::Object(o) => {
+            let object = o
+                .get("object")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .replace('.', "_");
+            if object.is_empty() {
+                return object;
+            }
+
+            if let Some(serde_json::Value::Bool(true)) = o.get("deleted") {
+                format!("deleted_{}", object)
+            } else {
+                object
+            }
+        }
+        _ => "".to_string(),
+    }
+}
+
+/// The error Stripe sends back, see <https://stripe.com/docs/api/errors>.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StripeApiError {
+    /// The type of error, e.g. `card_error` or `invalid_request_error`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    /// A short string saying what went wrong, e.g. `card_declined`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub code: String,
+    /// Why the card issuer declined the charge, for card errors.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub decline_code: String,
+    /// The parameter the error relates to.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub param: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub doc_url: String,
+}
+
+/// Parse the body of a failed request into a `ClientError::StripeError`, if
+/// it is one of Stripe's errors.
+pub(crate) fn stripe_error(
+    status: http::StatusCode,
+    body: &[u8],
+    request_id: Option<String>,
+) -> Option<crate::ClientError> {
+    #[derive(serde::Deserialize)]
+    struct Envelope {
+        error: StripeApiError,
+    }
+
+    let Envelope { error } = serde_json::from_slice(body).ok()?;
+    Some(crate::ClientError::StripeError {
+        status,
+        error: Box::new(error),
+        body: String::from_utf8_lossy(body).into(),
+        request_id,
+    })
+}
+
+/// The Stripe headers sent with the requests of a client, see
+/// `Client::with_account`, `Client::with_api_version` and
+/// `Client::with_idempotency_key`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StripeOptions {
+    /// The connected account to act on behalf of, sent as `Stripe-Account`.
+    pub account: Option<String>,
+    /// The API version to use instead of the default of the account, sent as `Stripe-Version`.
+    pub api_version: Option<String>,
+    /// The `Idempotency-Key` sent with POST requests.
+    pub idempotency_key: Option<String>,
+    /// Generate an `Idempotency-Key` for every POST request without one. The
+    /// retry middleware sends the same key again, so retrying is safe.
+    pub auto_idempotency_keys: bool,
+}
+
+impl Default for StripeOptions {
+    fn default() -> Self {
+        StripeOptions {
+            account: None,
+            api_version: None,
+            idempotency_key: None,
+            auto_idempotency_keys: true,
+        }
+    }
+}
+
+impl StripeOptions {
+    pub(crate) fn apply(
+        &self,
+        mut req: reqwest_middleware::RequestBuilder,
+        method: &reqwest::Method,
+    ) -> crate::ClientResult<reqwest_middleware::RequestBuilder> {
+        if let Some(account) = &self.account {
+            req = req.header("Stripe-Account", reqwest::header::HeaderValue::from_str(account)?);
+        }
+        if let Some(api_version) = &self.api_version {
+            req = req.header("Stripe-Version", reqwest::header::HeaderValue::from_str(api_version)?);
+        }
+
+        if *method == reqwest::Method::POST {
+            let key = match &self.idempotency_key {
+                Some(key) => Some(key.to_string()),
+                None if self.auto_idempotency_keys => Some(uuid::Uuid::new_v4().to_string()),
+                None => None,
+            };
+            if let Some(key) = key {
+                req = req.header("Idempotency-Key", reqwest::header::HeaderValue::from_str(&key)?);
+            }
+        }
+
+        Ok(req)
+    }
+}
+
+#[cfg(test)]
+mod stripe_tests {
+    use super::{expandable_object, stripe_error, StripeOptions};
+
+    #[test]
+    fn test_expandable_object() {
+        assert_eq!(expandable_object(&serde_json::json!("cus_123")), "string");
+        assert_eq!(
+            expandable_object(&serde_json::json!({"id": "cus_123", "object": "customer"})),
+            "customer"
+        );
+        assert_eq!(
+            expandable_object(&serde_json::json!({"id": "cus_123", "object": "customer", "deleted": true})),
+            "deleted_customer"
+        );
+        assert_eq!(
+            expandable_object(&serde_json::json!({"id": "ic_123", "object": "issuing.card"})),
+            "issuing_card"
+        );
+        assert_eq!(expandable_object(&serde_json::json!({"id": "x"})), "");
+        assert_eq!(expandable_object(&serde_json::json!(1)), "");
+    }
+
+    #[test]
+    fn test_expandable_field() {
+        let id: crate::types::CustomerAnyOf =
+            serde_json::from_value(serde_json::json!("cus_123")).unwrap();
+        assert_eq!(
+            id,
+            crate::types::CustomerAnyOf::String("cus_123".to_string())
+        );
+
+        let expanded: crate::types::CustomerAnyOf = serde_json::from_value(serde_json::json!({
+            "id": "cus_123",
+            "object": "customer",
+            "email": "jenny.rosen@example.com",
+        }))
+        .unwrap();
+        match expanded {
+            crate::types::CustomerAnyOf::Customer(customer) => {
+                assert_eq!(customer.id, "cus_123");
+                assert_eq!(customer.email, "jenny.rosen@example.com");
+            }
+            other => panic!("expected an expanded customer, got {:?}", other),
+        }
+
+        let deleted: crate::types::CustomerAnyOf = serde_json::from_value(serde_json::json!({
+            "id": "cus_123",
+            "object": "customer",
+            "deleted": true,
+        }))
+        .unwrap();
+        assert!(matches!(
+            deleted,
+            crate::types::CustomerAnyOf::DeletedCustomer(_)
+        ));
+    }
+
+    #[test]
+    fn test_stripe_error() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "error": {
+                "type": "card_error",
+                "code": "card_declined",
+                "decline_code": "insufficient_funds",
+                "message": "Your card has insufficient funds.",
+                "doc_url": "https://stripe.com/docs/error-codes/card-declined",
+            }
+        }))
+        .unwrap();
+        match stripe_error(http::StatusCode::PAYMENT_REQUIRED, &body, Some("req_123".to_string())) {
+            Some(crate::ClientError::StripeError { status, error, body, request_id }) => {
+                assert_eq!(request_id.as_deref(), Some("req_123"));
+                assert_eq!(status, http::StatusCode::PAYMENT_REQUIRED);
+                assert_eq!(error.type_, "card_error");
+                assert_eq!(error.code, "card_declined");
+                assert_eq!(error.decline_code, "insufficient_funds");
+                assert_eq!(error.param, "");
+                assert!(body.contains("insufficient_funds"));
+            }
+            r => panic!("expected a stripe error, got {:?}", r),
+        }
+
+        assert!(stripe_error(http::StatusCode::BAD_GATEWAY, b"Bad Gateway", None).is_none());
+    }
+
+    #[test]
+    fn test_stripe_options() {
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+        let headers = |options: &StripeOptions, method: reqwest::Method| {
+            let req = client.request(method.clone(), "https://api.stripe.com/v1/charges");
+            options.apply(req, &method).unwrap().build().unwrap().headers().clone()
+        };
+
+        // Every POST gets its own key by default.
+        let options = StripeOptions::default();
+        let first = headers(&options, reqwest::Method::POST);
+        let second = headers(&options, reqwest::Method::POST);
+        assert_eq!(first["idempotency-key"].len(), 36);
+        assert_ne!(first["idempotency-key"], second["idempotency-key"]);
+        assert!(first.get("stripe-account").is_none());
+        assert!(headers(&options, reqwest::Method::GET).get("idempotency-key").is_none());
+
+        let options = StripeOptions {
+            account: Some("acct_123".to_string()),
+            api_version: Some("2022-11-15".to_string()),
+            idempotency_key: Some("order-42".to_string()),
+            ..Default::default()
+        };
+        let post = headers(&options, reqwest::Method::POST);
+        assert_eq!(post["stripe-account"], "acct_123");
+        assert_eq!(post["stripe-version"], "2022-11-15");
+        assert_eq!(post["idempotency-key"], "order-42");
+
+        let options = StripeOptions {
+            auto_idempotency_keys: false,
+            ..Default::default()
+        };
+        assert!(headers(&options, reqwest::Method::POST).get("idempotency-key").is_none());
+    }
+}
+"#;
+
+const OKTA_TEMPLATE: &str = r#"/// The error Okta sends back, see <https://developer.okta.com/docs/reference/error-codes/>.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OktaApiError {
+    pub error_code: OktaErrorCode,
+    #[serde(default)]
+    pub error_summary: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub error_link: String,
+    /// The id to hand to Okta's support.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub error_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error_causes: Vec<OktaErrorCause>,
+}
+
+/// More detail on an `OktaApiError`, e.g. which field failed validation.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OktaErrorCause {
+    #[serde(default)]
+    pub error_summary: String,
+}
+
+/// The `E0000xxx` code of an `OktaApiError`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum OktaErrorCode {
+    /// E0000001
+    ApiValidationFailed,
+    /// E0000003
+    MalformedRequest,
+    /// E0000004
+    AuthenticationFailed,
+    /// E0000005
+    InvalidSession,
+    /// E0000006
+    AccessDenied,
+    /// E0000007
+    NotFound,
+    /// E0000011
+    InvalidToken,
+    /// E0000022
+    MethodNotSupported,
+    /// E0000047
+    RateLimitExceeded,
+    /// Any other code.
+    Unknown(String),
+}
+
+impl OktaErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            OktaErrorCode::ApiValidationFailed => "E0000001",
+            OktaErrorCode::MalformedRequest => "E0000003",
+            OktaErrorCode::AuthenticationFailed => "E0000004",
+            OktaErrorCode::InvalidSession => "E0000005",
+            OktaErrorCode::AccessDenied => "E0000006",
+            OktaErrorCode::NotFound => "E0000007",
+            OktaErrorCode::InvalidToken => "E0000011",
+            OktaErrorCode::MethodNotSupported => "E0000022",
+            OktaErrorCode::RateLimitExceeded => "E0000047",
+            OktaErrorCode::Unknown(code) => code,
+        }
+    }
+}
+
+impl std::fmt::Display for OktaErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::convert::From<String> for OktaErrorCode {
+    fn from(code: String) -> Self {
+        match code.as_str() {
+            "E0000001" => OktaErrorCode::ApiValidationFailed,
+            "E0000003" => OktaErrorCode::MalformedRequest,
+            "E0000004" => OktaErrorCode::AuthenticationFailed,
+            "E0000005" => OktaErrorCode::InvalidSession,
+            "E0000006" => OktaErrorCode::AccessDenied,
+            "E0000007" => OktaErrorCode::NotFound,
+            "E0000011" => OktaErrorCode::InvalidToken,
+            "E0000022" => OktaErrorCode::MethodNotSupported,
+            "E0000047" => OktaErrorCode::RateLimitExceeded,
+            _ => OktaErrorCode::Unknown(code),
+        }
+    }
+}
+
+impl std::convert::From<OktaErrorCode> for String {
+    fn from(code: OktaErrorCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+/// Parse the body of a failed request into a `ClientError::OktaError`, if
+/// it is one of Okta's errors.
+pub(crate) fn okta_error(
+    status: http::StatusCode,
+    body: &[u8],
+    request_id: Option<String>,
+) -> Option<crate::ClientError> {
+    let error = serde_json::from_slice::<OktaApiError>(body).ok()?;
+    Some(crate::ClientError::OktaError {
+        status,
+        error: Box::new(error),
+        body: String::from_utf8_lossy(body).into(),
+        request_id,
+    })
+}
+
+#[cfg(test)]
+mod okta_tests {
+    use super::{okta_error, OktaErrorCode};
+
+    #[test]
+    fn test_okta_error() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "errorCode": "E0000001",
+            "errorSummary": "Api validation failed: login",
+            "errorLink": "E0000001",
+            "errorId": "oaeHfmOAx1iRLa0H10DeMz5fQ",
+            "errorCauses": [
+                {"errorSummary": "login: An object with this field already exists in the current organization"}
+            ],
+        }))
+        .unwrap();
+        match okta_error(http::StatusCode::BAD_REQUEST, &body, Some("req_123".to_string())) {
+            Some(crate::ClientError::OktaError { status, error, body, request_id }) => {
+                assert_eq!(request_id.as_deref(), Some("req_123"));
+                assert_eq!(status, http::StatusCode::BAD_REQUEST);
+                assert_eq!(error.error_code, OktaErrorCode::ApiValidationFailed);
+                assert_eq!(error.error_id, "oaeHfmOAx1iRLa0H10DeMz5fQ");
+                assert_eq!(error.error_causes.len(), 1);
+                assert!(body.contains("E0000001"));
+            }
+            r => panic!("expected an okta error, got {:?}", r),
+        }
+
+        let code: OktaErrorCode = serde_json::from_str("\"E0000999\"").unwrap();
+        assert_eq!(code, OktaErrorCode::Unknown("E0000999".to_string()));
+        assert_eq!(serde_json::to_string(&code).unwrap(), "\"E0000999\"");
+
+        assert!(okta_error(http::StatusCode::NOT_FOUND, b"{}", None).is_none());
+    }
+}
+"#;
+
+const GOOGLE_TEMPLATE: &str = r#"/// Make sure a field mask asks for `nextPageToken`.
+///
+/// Google only sends back the fields we ask for, so if the mask leaves out
+/// `nextPageToken` we would stop after the first page.
+pub fn fields_with_next_page_token(fields: &str) -> String {
+    fields_with(fields, "nextPageToken")
+}
+
+fn fields_with(fields: &str, field: &str) -> String {
+    let fields = fields.trim();
+    if fields.is_empty() || fields == "*" || fields.split(',').any(|f| f.trim() == field) {
+        return fields.to_string();
+    }
+
+    format!("{},{}", field, fields)
+}
+
+/// The error Google sends back, see <https://cloud.google.com/apis/design/errors>.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GoogleApiError {
+    #[serde(default)]
+    pub code: i64,
+    #[serde(default)]
+    pub message: String,
+    /// The canonical error code, e.g. `NOT_FOUND`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GoogleErrorDetail>,
+}
+
+impl GoogleApiError {
+    /// Whether any of the errors is for `reason`, e.g. `rateLimitExceeded`.
+    pub fn has_reason(&self, reason: &str) -> bool {
+        self.errors.iter().any(|e| e.reason == reason)
+    }
+}
+
+/// One of the errors in a `GoogleApiError`.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleErrorDetail {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub domain: String,
+    /// Why the request failed, e.g. `notFound` or `userRateLimitExceeded`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub reason: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub location: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub location_type: String,
+}
+
+/// Parse the body of a failed request into a `ClientError::GoogleError`, if
+/// it is one of Google's errors.
+pub(crate) fn google_error(
+    status: http::StatusCode,
+    body: &[u8],
+    request_id: Option<String>,
+) -> Option<crate::ClientError> {
+    #[derive(serde::Deserialize)]
+    struct Envelope {
+        error: GoogleApiError,
+    }
+
+    let Envelope { error } = serde_json::from_slice(body).ok()?;
+    Some(crate::ClientError::GoogleError {
+        status,
+        error: Box::new(error),
+        body: String::from_utf8_lossy(body).into(),
+        request_id,
+    })
+}
+
+#[cfg(test)]
+mod google_tests {
+    use super::{fields_with_next_page_token, google_error};
+
+    #[test]
+    fn test_fields_with_next_page_token() {
+        assert_eq!(fields_with_next_page_token(""), "");
+        assert_eq!(fields_with_next_page_token("*"), "*");
+        assert_eq!(
+            fields_with_next_page_token("files(id,name,modifiedTime)"),
+            "nextPageToken,files(id,name,modifiedTime)"
+        );
+        assert_eq!(
+            fields_with_next_page_token("files(id),nextPageToken"),
+            "files(id),nextPageToken"
+        );
+    }
+
+    #[test]
+    fn test_google_error() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "error": {
+                "code": 403,
+                "message": "User Rate Limit Exceeded",
+                "errors": [{
+                    "domain": "usageLimits",
+                    "reason": "userRateLimitExceeded",
+                    "message": "User Rate Limit Exceeded",
+                }],
+                "status": "PERMISSION_DENIED",
+            }
+        }))
+        .unwrap();
+        match google_error(http::StatusCode::FORBIDDEN, &body, Some("req_123".to_string())) {
+            Some(crate::ClientError::GoogleError { status, error, body, request_id }) => {
+                assert_eq!(request_id.as_deref(), Some("req_123"));
+                assert_eq!(status, http::StatusCode::FORBIDDEN);
+                assert_eq!(error.code, 403);
+                assert_eq!(error.status, "PERMISSION_DENIED");
+                assert!(error.has_reason("userRateLimitExceeded"));
+                assert!(!error.has_reason("notFound"));
+                assert!(body.contains("usageLimits"));
+            }
+            r => panic!("expected a google error, got {:?}", r),
+        }
+
+        // The token endpoint uses a different shape.
+        let body = b"{\"error\": \"invalid_grant\", \"error_description\": \"Bad Request\"}";
+        assert!(google_error(http::StatusCode::BAD_REQUEST, body, None).is_none());
+    }
+}
+"#;
+
+const GOOGLE_CALENDAR_TEMPLATE: &str = r#"/// Make sure a field mask asks for both `nextPageToken` and `nextSyncToken`.
+pub fn fields_with_sync_tokens(fields: &str) -> String {
+    fields_with(&fields_with_next_page_token(fields), "nextSyncToken")
+}
+
+/// Google Calendar responds with `410 GONE` when a sync token has expired.
+pub(crate) fn full_sync_required(e: crate::ClientError) -> crate::ClientError {
+    match e {
+        crate::ClientError::GoogleError {
+            status: http::StatusCode::GONE,
+            ..
+        }
+        | crate::ClientError::HttpError {
+            status: http::StatusCode::GONE,
+            ..
+        } => crate::ClientError::FullSyncRequired,
+        e => e,
+    }
+}
+
+#[cfg(test)]
+mod google_calendar_tests {
+    use super::{fields_with_sync_tokens, full_sync_required};
+
+    #[test]
+    fn test_fields_with_sync_tokens() {
+        assert_eq!(fields_with_sync_tokens(""), "");
+        assert_eq!(
+            fields_with_sync_tokens("items(id,summary)"),
+            "nextSyncToken,nextPageToken,items(id,summary)"
+        );
+        assert_eq!(
+            fields_with_sync_tokens("nextSyncToken,items(id)"),
+            "nextPageToken,nextSyncToken,items(id)"
+        );
+    }
+
+    #[test]
+    fn test_full_sync_required() {
+        let e = full_sync_required(crate::ClientError::HttpError {
+            status: http::StatusCode::GONE,
+            error: "".to_string(),
+            request_id: None,
+        });
+        assert!(matches!(e, crate::ClientError::FullSyncRequired));
+
+        let e = full_sync_required(crate::ClientError::GoogleError {
+            status: http::StatusCode::GONE,
+            error: Default::default(),
+            body: "".to_string(),
+            request_id: None,
+        });
+        assert!(matches!(e, crate::ClientError::FullSyncRequired));
+
+        let e = full_sync_required(crate::ClientError::HttpError {
+            status: http::StatusCode::NOT_FOUND,
+            error: "".to_string(),
+            request_id: None,
+        });
+        assert!(matches!(e, crate::ClientError::HttpError { .. }));
+    }
+}
+"#;
+
+const RETRY_TEMPLATE: &str = r#"
+/// How long to wait between two attempts at a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// Wait the same time before every retry.
+    Constant(std::time::Duration),
+    /// Wait `initial` before the first retry and `factor` times longer before
+    /// each of the next ones, up to `max`.
+    Exponential {
+        initial: std::time::Duration,
+        factor: u32,
+        max: std::time::Duration,
+    },
+}
+
+impl Backoff {
+    /// The wait before the given retry, counting from 1.
+    pub fn delay(&self, retry: u32) -> std::time::Duration {
+        match *self {
+            Backoff::Constant(delay) => delay,
+            Backoff::Exponential { initial, factor, max } => {
+                let multiplier = factor.checked_pow(retry.saturating_sub(1)).unwrap_or(u32::MAX);
+                initial.checked_mul(multiplier).map_or(max, |delay| delay.min(max))
+            }
+        }
+    }
+}
+
+/// Decides whether a failed attempt at a request is retried, see [`RetryConfig::set_retry_if`].
+pub type RetryPredicate =
+    Arc<dyn Fn(&reqwest::Request, &reqwest_middleware::Result<reqwest::Response>) -> bool + Send + Sync>;
+
+/// When and how often failed requests are retried.
+///
+/// By default, connection errors, timeouts and the `retryable_statuses` are
+/// retried up to 3 times. `POST` and `PATCH` requests are not idempotent, so
+/// they are only retried when they carry an `Idempotency-Key` header.
+#[derive(Clone)]
+pub struct RetryConfig {
+    /// The maximum number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// The wait between two attempts.
+    pub backoff: Backoff,
+    /// The response status codes that are retried.
+    pub retryable_statuses: Vec<http::StatusCode>,
+    /// Wait for as long as the server asks in a `Retry-After` or rate limit
+    /// reset header instead of following the `backoff`. This also retries a
+    /// `403` that comes with one of those headers, as GitHub does for its
+    /// secondary rate limits.
+    pub respect_server_reset: bool,
+    /// Give up instead of waiting for a server reset further away than this,
+    /// like the end of a daily limit.
+    pub max_server_wait: std::time::Duration,
+    /// Replaces the default decision of whether a failed attempt is retried.
+    pub retry_if: Option<RetryPredicate>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_attempts: 4,
+            backoff: Backoff::Exponential {
+                initial: std::time::Duration::from_secs(1),
+                factor: 2,
+                max: std::time::Duration::from_secs(30),
+            },
+            retryable_statuses: vec![
+                http::StatusCode::REQUEST_TIMEOUT,
+                http::StatusCode::TOO_MANY_REQUESTS,
+                http::StatusCode::INTERNAL_SERVER_ERROR,
+                http::StatusCode::BAD_GATEWAY,
+                http::StatusCode::SERVICE_UNAVAILABLE,
+                http::StatusCode::GATEWAY_TIMEOUT,
+            ],
+            respect_server_reset: true,
+            max_server_wait: std::time::Duration::from_secs(60),
+            retry_if: None,
+        }
+    }
+}
+
+impl fmt::Debug for RetryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryConfig")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .field("retryable_statuses", &self.retryable_statuses)
+            .field("respect_server_reset", &self.respect_server_reset)
+            .field("max_server_wait", &self.max_server_wait)
+            .field("retry_if", &self.retry_if.is_some())
+            .finish()
+    }
+}
+
+impl RetryConfig {
+    /// A config that never retries.
+    pub fn none() -> Self {
+        RetryConfig {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Decide per request whether a failed attempt is retried, instead of by
+    /// method and status code. The `max_attempts` still apply.
+    pub fn set_retry_if<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&reqwest::Request, &reqwest_middleware::Result<reqwest::Response>) -> bool + Send + Sync + 'static,
+    {
+        self.retry_if = Some(Arc::new(f));
+        self
+    }
+
+    /// The wait before retrying `request` after the given attempt, or `None`
+    /// if it should not be retried.
+    pub(crate) fn next_attempt(
+        &self,
+        request: &reqwest::Request,
+        result: &reqwest_middleware::Result<reqwest::Response>,
+        attempt: u32,
+        now: std::time::SystemTime,
+    ) -> Option<std::time::Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let retry = match &self.retry_if {
+            Some(retry_if) => retry_if(request, result),
+            None => self.is_retryable(request, result),
+        };
+        if !retry {
+            return None;
+        }
+
+        let server_wait = match result {
+            Ok(response) if self.respect_server_reset => server_wait(response.headers(), now),
+            _ => None,
+        };
+        match server_wait {
+            Some(wait) if wait > self.max_server_wait => None,
+            Some(wait) => Some(wait),
+            None => Some(self.backoff.delay(attempt)),
+        }
+    }
+
+    fn is_retryable(&self, request: &reqwest::Request, result: &reqwest_middleware::Result<reqwest::Response>) -> bool {
+        let idempotent = !matches!(*request.method(), http::Method::POST | http::Method::PATCH)
+            || request.headers().contains_key("idempotency-key");
+        if !idempotent {
+            return false;
+        }
+
+        match result {
+            Ok(response) => {
+                let status = response.status();
+                self.retryable_statuses.contains(&status)
+                    || (self.respect_server_reset
+                        && status == http::StatusCode::FORBIDDEN
+                        && rate_limit_exceeded(response.headers()))
+            }
+            Err(reqwest_middleware::Error::Reqwest(e)) => e.is_connect() || e.is_timeout(),
+            Err(reqwest_middleware::Error::Middleware(_)) => false,
+        }
+    }
+
+    /// Carries the config to the [`RetryMiddleware`] of the client.
+    pub(crate) fn extensions(&self) -> task_local_extensions::Extensions {
+        let mut extensions = task_local_extensions::Extensions::new();
+        extensions.insert(self.clone());
+        extensions
+    }
+}
+
+fn header<'a>(headers: &'a reqwest::header::HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok()).map(|v| v.trim())
+}
+
+/// Whether the response says the rate limit is used up, rather than access being denied.
+fn rate_limit_exceeded(headers: &reqwest::header::HeaderMap) -> bool {
+    headers.contains_key(reqwest::header::RETRY_AFTER)
+        || header(headers, "x-ratelimit-remaining") == Some("0")
+        || header(headers, "x-rate-limit-remaining") == Some("0")
+}
+
+/// How long the server asks to wait before the next attempt: the `Retry-After`
+/// header, in seconds or as a date, or once the rate limit is used up, the
+/// `X-RateLimit-Reset` (GitHub) or `X-Rate-Limit-Reset` (Okta) timestamp.
+fn server_wait(headers: &reqwest::header::HeaderMap, now: std::time::SystemTime) -> Option<std::time::Duration> {
+    let now = now.duration_since(std::time::UNIX_EPOCH).ok()?.as_secs() as i64;
+    let until = |timestamp: i64| std::time::Duration::from_secs((timestamp - now).max(0) as u64);
+
+    if let Some(retry_after) = header(headers, "retry-after") {
+        if let Ok(seconds) = retry_after.parse::<u64>() {
+            return Some(std::time::Duration::from_secs(seconds));
+        }
+        // Shopify sends fractions of a second, ie. `2.0`.
+        if let Ok(seconds) = retry_after.parse::<f64>() {
+            return Some(seconds)
+                .filter(|s| s.is_finite() && *s >= 0.0)
+                .map(std::time::Duration::from_secs_f64);
+        }
+        // Zoom sends the end of its daily limit as an RFC 3339 date, everyone
+        // else an HTTP (RFC 2822) date.
+        return chrono::DateTime::parse_from_rfc3339(retry_after)
+            .or_else(|_| chrono::DateTime::parse_from_rfc2822(retry_after))
+            .ok()
+            .map(|date| until(date.timestamp()));
+    }
+
+    for (remaining, reset) in [
+        ("x-ratelimit-remaining", "x-ratelimit-reset"),
+        ("x-rate-limit-remaining", "x-rate-limit-reset"),
+    ] {
+        if header(headers, remaining) == Some("0") {
+            return header(headers, reset).and_then(|r| r.parse::<i64>().ok()).map(until);
+        }
+    }
+
+    None
+}
+
+/// Retries failed requests following the [`RetryConfig`] of the client that
+/// sent them. Requests sent without one, through a `reqwest_middleware` client
+/// directly, follow the config of the middleware instead.
+#[derive(Debug, Clone, Default)]
+pub struct RetryMiddleware {
+    config: RetryConfig,
+}
+
+impl RetryMiddleware {
+    pub fn new(config: RetryConfig) -> Self {
+        RetryMiddleware { config }
+    }
+
+    fn execute<'a>(
+        &'a self,
+        config: RetryConfig,
+        req: reqwest::Request,
+        next: reqwest_middleware::Next<'a>,
+        extensions: &'a mut task_local_extensions::Extensions,
+        attempt: u32,
+    ) -> futures::future::BoxFuture<'a, reqwest_middleware::Result<reqwest::Response>> {
+        Box::pin(async move {
+            // A request with a streaming body can only be sent once.
+            let duplicate = match req.try_clone() {
+                Some(duplicate) => duplicate,
+                None => return next.run(req, extensions).await,
+            };
+
+            let result = next.clone().run(duplicate, extensions).await;
+            match config.next_attempt(&req, &result, attempt, std::time::SystemTime::now()) {
+                Some(wait) => {
+                    log::debug!("retrying {} {} in {:?}, attempt {} failed", req.method(), req.url(), wait, attempt);
+                    tokio::time::sleep(wait).await;
+                    self.execute(config, req, next, extensions, attempt + 1).await
+                }
+                None => result,
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let config = extensions.get::<RetryConfig>().unwrap_or(&self.config).clone();
+        self.execute(config, req, next, extensions, 1).await
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::{server_wait, Backoff, RetryConfig, RetryMiddleware};
+
+    fn request(method: http::Method) -> reqwest::Request {
+        reqwest::Request::new(method, "https://example.com/things".parse().unwrap())
+    }
+
+    fn response(status: u16, headers: &[(&str, &str)]) -> reqwest_middleware::Result<reqwest::Response> {
+        let mut response = http::Response::builder().status(status);
+        for (name, value) in headers {
+            response = response.header(*name, *value);
+        }
+        Ok(response.body("").unwrap().into())
+    }
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Backoff::Exponential {
+            initial: Duration::from_secs(1),
+            factor: 2,
+            max: Duration::from_secs(5),
+        };
+        assert_eq!(backoff.delay(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay(2), Duration::from_secs(2));
+        assert_eq!(backoff.delay(3), Duration::from_secs(4));
+        assert_eq!(backoff.delay(4), Duration::from_secs(5));
+        assert_eq!(backoff.delay(100), Duration::from_secs(5));
+
+        assert_eq!(Backoff::Constant(Duration::from_secs(3)).delay(7), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_server_wait() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let wait = |headers: &[(&str, &str)]| server_wait(response(429, headers).unwrap().headers(), now);
+
+        assert_eq!(wait(&[]), None);
+        assert_eq!(wait(&[("retry-after", "30")]), Some(Duration::from_secs(30)));
+        assert_eq!(wait(&[("retry-after", "2.5")]), Some(Duration::from_millis(2500)));
+        assert_eq!(
+            wait(&[("retry-after", "Tue, 14 Nov 2023 22:13:27 GMT")]),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(wait(&[("retry-after", "2023-11-14T22:14:20Z")]), Some(Duration::from_secs(60)));
+
+        // GitHub sends the reset on every response, it only matters once the limit is used up.
+        assert_eq!(wait(&[("x-ratelimit-remaining", "12"), ("x-ratelimit-reset", "1700000100")]), None);
+        assert_eq!(
+            wait(&[("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "1700000100")]),
+            Some(Duration::from_secs(100))
+        );
+        assert_eq!(
+            wait(&[("x-rate-limit-remaining", "0"), ("x-rate-limit-reset", "1699999999")]),
+            Some(Duration::from_secs(0))
+        );
+    }
+
+    #[test]
+    fn test_next_attempt() {
+        let config = RetryConfig {
+            backoff: Backoff::Constant(Duration::from_secs(2)),
+            ..Default::default()
+        };
+        let now = SystemTime::now();
+        let get = request(http::Method::GET);
+
+        assert_eq!(config.next_attempt(&get, &response(503, &[]), 1, now), Some(Duration::from_secs(2)));
+        assert_eq!(config.next_attempt(&get, &response(503, &[]), 4, now), None);
+        assert_eq!(config.next_attempt(&get, &response(404, &[]), 1, now), None);
+        assert_eq!(
+            config.next_attempt(&get, &response(429, &[("retry-after", "10")]), 1, now),
+            Some(Duration::from_secs(10))
+        );
+        // Past the longest wait we are willing to sleep for.
+        assert_eq!(config.next_attempt(&get, &response(429, &[("retry-after", "3600")]), 1, now), None);
+
+        // A 403 is only retried when it is a rate limit.
+        assert_eq!(config.next_attempt(&get, &response(403, &[]), 1, now), None);
+        assert_eq!(
+            config.next_attempt(&get, &response(403, &[("retry-after", "5")]), 1, now),
+            Some(Duration::from_secs(5))
+        );
+
+        // POSTs need an idempotency key.
+        let mut post = request(http::Method::POST);
+        assert_eq!(config.next_attempt(&post, &response(503, &[]), 1, now), None);
+        post.headers_mut().insert("idempotency-key", "abc".parse().unwrap());
+        assert_eq!(config.next_attempt(&post, &response(503, &[]), 1, now), Some(Duration::from_secs(2)));
+
+        let config = RetryConfig {
+            respect_server_reset: false,
+            ..config
+        };
+        assert_eq!(
+            config.next_attempt(&get, &response(429, &[("retry-after", "10")]), 1, now),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(config.next_attempt(&get, &response(403, &[("retry-after", "5")]), 1, now), None);
+
+        let mut config = RetryConfig::none();
+        assert_eq!(config.next_attempt(&get, &response(503, &[]), 1, now), None);
+        config.max_attempts = 2;
+        config.set_retry_if(|req, _| req.method() == http::Method::POST);
+        assert_eq!(config.next_attempt(&get, &response(503, &[]), 1, now), None);
+        assert!(config.next_attempt(&request(http::Method::POST), &response(400, &[]), 1, now).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_retry_middleware() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(matchers::method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(RetryMiddleware::new(RetryConfig::none()))
+            .build();
+        let config = RetryConfig {
+            backoff: Backoff::Constant(Duration::from_millis(1)),
+            ..Default::default()
+        };
+
+        // The config of the request wins over the one of the middleware.
+        let response = client
+            .get(server.uri())
+            .send_with_extensions(&mut config.extensions())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+
+        let response = client
+            .post(server.uri())
+            .send_with_extensions(&mut config.extensions())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
+"#;
+
+const RATE_LIMIT_TEMPLATE: &str = r#"
+/// How long the leaky bucket of Shopify takes to drain when full: 40 requests
+/// at 2 a second on standard plans, 400 at 20 a second on Shopify Plus.
+const SHOPIFY_DRAIN_SECONDS: f64 = 20.0;
+
+/// Queues requests to stay under the rate limits of the API, instead of
+/// running into them.
+///
+/// The limiter follows the remaining requests and reset time from the last
+/// response, in the `X-RateLimit-*` (GitHub, Zoom), `X-Rate-Limit-*` (Okta) or
+/// `X-Shopify-Shop-Api-Call-Limit` headers. On top of that it can enforce a
+/// rate and a cap on concurrent requests of its own. Clones share the same
+/// budget, so a client and all of its clones stay under the same limits.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    budget: Arc<Mutex<Budget>>,
+    queue: Arc<tokio::sync::Mutex<()>>,
+    concurrency: Option<Arc<tokio::sync::Semaphore>>,
+}
+
+impl RateLimiter {
+    /// A limiter that only follows the rate limit headers of the API.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Also allow at most `requests` every `per`, in bursts of up to `requests`.
+    ///
+    /// Panics if `requests` or `per` is zero, as no request could ever go out.
+    pub fn with_rate(self, requests: u32, per: std::time::Duration) -> Self {
+        assert!(requests > 0, "a rate limit needs to allow at least one request");
+        assert!(!per.is_zero(), "a rate limit needs a non-zero period");
+        self.budget.lock().unwrap().local = Some(Bucket {
+            tokens: requests as f64,
+            capacity: requests as f64,
+            per_second: requests as f64 / per.as_secs_f64(),
+        });
+        self
+    }
+
+    /// Also allow at most `max` requests in flight at once.
+    ///
+    /// Panics if `max` is zero, as no request could ever go out.
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        assert!(max > 0, "a concurrency cap needs to allow at least one request");
+        self.concurrency = Some(Arc::new(tokio::sync::Semaphore::new(max)));
+        self
+    }
+
+    /// Waits until the budget allows another request. The permit holds a slot
+    /// under the concurrency cap until it is dropped.
+    pub(crate) async fn acquire(&self) -> Option<tokio::sync::OwnedSemaphorePermit> {
+        let permit = match &self.concurrency {
+            Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        // Callers wait for their turn in the order they came in.
+        let _turn = self.queue.lock().await;
+        loop {
+            let wait = self.budget.lock().unwrap().take(tokio::time::Instant::now());
+            match wait {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => return permit,
+            }
+        }
+    }
+
+    /// Takes the budget left on the server from the headers of a response.
+    pub(crate) fn update(&self, headers: &reqwest::header::HeaderMap) {
+        self.budget
+            .lock()
+            .unwrap()
+            .update(headers, tokio::time::Instant::now(), std::time::SystemTime::now());
+    }
+}
+
+#[derive(Debug, Default)]
+struct Budget {
+    /// The rate set with [`RateLimiter::with_rate`].
+    local: Option<Bucket>,
+    /// The requests left on the server.
+    server: Option<Bucket>,
+    /// When the server budget starts over, if it does not refill as it goes.
+    reset: Option<tokio::time::Instant>,
+    updated: Option<tokio::time::Instant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    per_second: f64,
+}
+
+impl Bucket {
+    fn refill(&mut self, elapsed: std::time::Duration) {
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.per_second).min(self.capacity);
+    }
+
+    /// The wait until the next token, if there is none left. Buckets that do
+    /// not refill, the server budget with a reset time, are waited on in `take`.
+    fn wait(&self) -> Option<std::time::Duration> {
+        if self.tokens >= 1.0 || self.per_second <= 0.0 {
+            return None;
+        }
+        Some(std::time::Duration::from_secs_f64((1.0 - self.tokens) / self.per_second))
+    }
+}
+
+impl Budget {
+    fn advance(&mut self, now: tokio::time::Instant) {
+        let elapsed = self.updated.map_or(std::time::Duration::ZERO, |u| now.saturating_duration_since(u));
+        self.updated = Some(now);
+
+        if matches!(self.reset, Some(reset) if now >= reset) {
+            self.server = None;
+            self.reset = None;
+        }
+        for bucket in [&mut self.local, &mut self.server].into_iter().flatten() {
+            bucket.refill(elapsed);
+        }
+    }
+
+    /// Takes a token, or returns how long to wait for one.
+    fn take(&mut self, now: tokio::time::Instant) -> Option<std::time::Duration> {
+        self.advance(now);
+
+        let server_wait = match (&self.server, self.reset) {
+            (Some(server), Some(reset)) if server.tokens < 1.0 => Some(reset - now),
+            (Some(server), _) => server.wait(),
+            (None, _) => None,
+        };
+        let wait = server_wait.into_iter().chain(self.local.and_then(|l| l.wait())).max();
+        if wait.is_some() {
+            return wait;
+        }
+
+        for bucket in [&mut self.local, &mut self.server].into_iter().flatten() {
+            bucket.tokens -= 1.0;
+        }
+        None
+    }
+
+    fn update(&mut self, headers: &reqwest::header::HeaderMap, now: tokio::time::Instant, wall: std::time::SystemTime) {
+        self.advance(now);
+
+        // Shopify sends the fill of its leaky bucket, e.g. `32/40`.
+        if let Some((used, capacity)) = header(headers, "x-shopify-shop-api-call-limit").and_then(|l| l.split_once('/')) {
+            if let (Ok(used), Ok(capacity)) = (used.parse::<f64>(), capacity.parse::<f64>()) {
+                self.server = Some(Bucket {
+                    tokens: capacity - used,
+                    capacity,
+                    per_second: capacity / SHOPIFY_DRAIN_SECONDS,
+                });
+                self.reset = None;
+            }
+            return;
+        }
+
+        let remaining = match header(headers, "x-ratelimit-remaining")
+            .or_else(|| header(headers, "x-rate-limit-remaining"))
+            .and_then(|r| r.parse::<f64>().ok())
+        {
+            Some(remaining) => remaining,
+            None => return,
+        };
+        let seconds = wall.duration_since(std::time::UNIX_EPOCH).map_or(0, |d| d.as_secs() as i64);
+        let until_reset = header(headers, "x-ratelimit-reset")
+            .or_else(|| header(headers, "x-rate-limit-reset"))
+            .and_then(|r| r.parse::<i64>().ok())
+            .map(|reset| std::time::Duration::from_secs((reset - seconds).max(0) as u64))
+            .or_else(|| server_wait(headers, wall));
+
+        // Without a reset time we can not tell when the budget comes back.
+        match until_reset {
+            Some(until_reset) => {
+                self.server = Some(Bucket {
+                    tokens: remaining,
+                    capacity: remaining,
+                    per_second: 0.0,
+                });
+                self.reset = Some(now + until_reset);
+            }
+            None => {
+                self.server = None;
+                self.reset = None;
+            }
+        }
+    }
+}
+
+/// Holds requests back to stay under the [`RateLimiter`] of the client that
+/// sent them. Requests sent without one go straight through.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimitMiddleware;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let limiter = match extensions.get::<RateLimiter>() {
+            Some(limiter) => limiter.clone(),
+            None => return next.run(req, extensions).await,
+        };
+
+        let _permit = limiter.acquire().await;
+        let result = next.run(req, extensions).await;
+        if let Ok(response) = &result {
+            limiter.update(response.headers());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod rate_limit_tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use tokio::time::Instant;
+
+    use super::{Bucket, Budget, RateLimitMiddleware, RateLimiter};
+
+    fn headers(headers: &[(&str, &str)]) -> reqwest::header::HeaderMap {
+        let mut map = reqwest::header::HeaderMap::new();
+        for (name, value) in headers {
+            map.insert(name.parse::<reqwest::header::HeaderName>().unwrap(), value.parse().unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn test_budget_reset() {
+        let now = Instant::now();
+        let wall = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut budget = Budget::default();
+        assert_eq!(budget.take(now), None);
+
+        budget.update(
+            &headers(&[("x-ratelimit-remaining", "2"), ("x-ratelimit-reset", "1700000060")]),
+            now,
+            wall,
+        );
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), Some(Duration::from_secs(60)));
+        assert_eq!(budget.take(now + Duration::from_secs(20)), Some(Duration::from_secs(40)));
+        assert_eq!(budget.take(now + Duration::from_secs(60)), None);
+        assert_eq!(budget.server, None);
+
+        // Okta, and a Retry-After when there is no reset.
+        budget.update(
+            &headers(&[("x-rate-limit-remaining", "0"), ("x-rate-limit-reset", "1700000010")]),
+            now,
+            wall,
+        );
+        assert_eq!(budget.take(now), Some(Duration::from_secs(10)));
+        budget.update(&headers(&[("x-ratelimit-remaining", "0"), ("retry-after", "5")]), now, wall);
+        assert_eq!(budget.take(now), Some(Duration::from_secs(5)));
+
+        // A remaining count that never resets is of no use.
+        budget.update(&headers(&[("x-ratelimit-remaining", "3")]), now, wall);
+        assert_eq!(budget.server, None);
+    }
+
+    #[test]
+    fn test_budget_leaky_bucket() {
+        let now = Instant::now();
+        let mut budget = Budget::default();
+        budget.update(&headers(&[("x-shopify-shop-api-call-limit", "39/40")]), now, SystemTime::now());
+        assert_eq!(
+            budget.server,
+            Some(Bucket {
+                tokens: 1.0,
+                capacity: 40.0,
+                per_second: 2.0
+            })
+        );
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), Some(Duration::from_millis(500)));
+        assert_eq!(budget.take(now + Duration::from_secs(1)), None);
+
+        // Shopify Plus stores have a bigger bucket that drains faster.
+        budget.update(&headers(&[("x-shopify-shop-api-call-limit", "400/400")]), now, SystemTime::now());
+        assert_eq!(budget.take(now), Some(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn test_budget_local_rate() {
+        let now = Instant::now();
+        let limiter = RateLimiter::new().with_rate(2, Duration::from_secs(1));
+        let mut budget = limiter.budget.lock().unwrap();
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), Some(Duration::from_millis(500)));
+        assert_eq!(budget.take(now + Duration::from_millis(500)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one request")]
+    fn test_zero_rate() {
+        RateLimiter::new().with_rate(0, Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero period")]
+    fn test_zero_period() {
+        RateLimiter::new().with_rate(10, Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one request")]
+    fn test_zero_concurrency() {
+        RateLimiter::new().with_max_concurrency(0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire_shared_between_clones() {
+        let limiter = RateLimiter::new()
+            .with_rate(1, Duration::from_secs(1))
+            .with_max_concurrency(1);
+        let clone = limiter.clone();
+
+        let start = Instant::now();
+        let permit = limiter.acquire().await;
+        assert!(permit.is_some());
+        // The clone waits for the permit, then for the next token.
+        let waiting = tokio::spawn(async move { clone.acquire().await.map(|_| Instant::now()) });
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        drop(permit);
+        let acquired = waiting.await.unwrap().unwrap();
+        assert_eq!(acquired - start, Duration::from_secs(5));
+
+        limiter.acquire().await;
+        assert_eq!(Instant::now() - start, Duration::from_secs(6));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_middleware() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(200).insert_header("x-shopify-shop-api-call-limit", "40/40"))
+            .mount(&server)
+            .await;
+
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(RateLimitMiddleware)
+            .build();
+        let limiter = RateLimiter::new();
+        let mut extensions = task_local_extensions::Extensions::new();
+        extensions.insert(limiter.clone());
+        client.get(server.uri()).send_with_extensions(&mut extensions).await.unwrap();
+
+        let tokens = limiter.budget.lock().unwrap().server.unwrap().tokens;
+        assert!(tokens < 1.0, "{}", tokens);
+    }
+}
+"#;
+
+pub fn generate_utils(proper_name: &str, uses_patch: bool) -> String {
+    let mut optional = String::new();
+    if proper_name == "GitHub" {
+        optional = GITHUB_TEMPLATE.to_string();
+    }
+
+    if proper_name == "Stripe" || proper_name == "Slack" {
+        optional.push_str(FORM_TEMPLATE);
+        optional.push_str(MULTIPART_TEMPLATE);
+    }
+
+    if proper_name == "Stripe" {
+        optional.push_str(STRIPE_FORM_TESTS);
+        optional.push_str(STRIPE_TEMPLATE);
+    }
+
+    if uses_patch {
+        optional.push_str(PATCH_TEMPLATE);
+    }
+
+    if proper_name == "Okta" {
+        optional.push_str(OKTA_TEMPLATE);
+    }
+
+    if proper_name.starts_with("Google") {
+        optional.push_str(GOOGLE_TEMPLATE);
+    }
+
+    if proper_name == "Google Calendar" {
+        optional.push_str(GOOGLE_CALENDAR_TEMPLATE);
+    }
+
+    if proper_name == "Slack" {
+        optional.push_str(SLACK_FORM_TESTS);
+        optional.push_str(SLACK_TEMPLATE);
+    }
+
+    if proper_name == "Shopify" {
+        optional.push_str(SHOPIFY_TEMPLATE);
     }
 
-    format!("{}\n{}", optional, TEMPLATE)
+    format!("{}\n{}{}{}", optional, TEMPLATE, RETRY_TEMPLATE, RATE_LIMIT_TEMPLATE)
 }
@@ -24,6 +24,19 @@ impl Gifs {
      * * `ids: &str` -- Filters results by specified GIF IDs, separated by commas.
      */
     pub async fn get(&self, ids: &str) -> ClientResult<crate::types::GetGifsByResponse> {
+        Ok(self.get_with_response(ids).await?.body)
+    }
+    /**
+     * Get GIFs by ID.
+     *
+     * This function performs a `GET` to the `/gifs` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ids.is_empty() {
             query_args.push(("ids".to_string(), ids.to_string()));
@@ -31,7 +44,7 @@ impl Gifs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gifs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -58,6 +71,20 @@ impl Gifs {
         tag: &str,
         rating: &str,
     ) -> ClientResult<crate::types::RandomGifResponse> {
+        Ok(self.random_with_response(tag, rating).await?.body)
+    }
+    /**
+     * Random GIF.
+     *
+     * This function performs a `GET` to the `/gifs/random` endpoint.
+     *
+     * As opposed to `random`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn random_with_response(
+        &self,
+        tag: &str,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !rating.is_empty() {
             query_args.push(("rating".to_string(), rating.to_string()));
@@ -68,7 +95,7 @@ impl Gifs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gifs/random?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -101,6 +128,26 @@ impl Gifs {
         rating: &str,
         lang: &str,
     ) -> ClientResult<crate::types::GetGifsByResponse> {
+        Ok(self
+            .search_with_response(q, limit, offset, rating, lang)
+            .await?
+            .body)
+    }
+    /**
+     * Search GIFs.
+     *
+     * This function performs a `GET` to the `/gifs/search` endpoint.
+     *
+     * As opposed to `search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn search_with_response(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !lang.is_empty() {
             query_args.push(("lang".to_string(), lang.to_string()));
@@ -120,7 +167,7 @@ impl Gifs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gifs/search?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -142,6 +189,19 @@ impl Gifs {
      * * `s: &str` -- The unique bit.ly URL for this GIF.
      */
     pub async fn translate(&self, s: &str) -> ClientResult<crate::types::RandomGifResponse> {
+        Ok(self.translate_with_response(s).await?.body)
+    }
+    /**
+     * Translate phrase to GIF.
+     *
+     * This function performs a `GET` to the `/gifs/translate` endpoint.
+     *
+     * As opposed to `translate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn translate_with_response(
+        &self,
+        s: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !s.is_empty() {
             query_args.push(("s".to_string(), s.to_string()));
@@ -151,7 +211,7 @@ impl Gifs {
             .client
             .url(&format!("/gifs/translate?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -180,6 +240,24 @@ impl Gifs {
         offset: i64,
         rating: &str,
     ) -> ClientResult<crate::types::GetGifsByResponse> {
+        Ok(self
+            .trending_with_response(limit, offset, rating)
+            .await?
+            .body)
+    }
+    /**
+     * Trending GIFs.
+     *
+     * This function performs a `GET` to the `/gifs/trending` endpoint.
+     *
+     * As opposed to `trending`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trending_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -193,7 +271,7 @@ impl Gifs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gifs/trending?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +293,19 @@ impl Gifs {
      * * `gif_id: i64` -- Filters results by specified GIF ID.
      */
     pub async fn get_gifs(&self, gif_id: i64) -> ClientResult<crate::types::RandomGifResponse> {
+        Ok(self.get_gifs_with_response(gif_id).await?.body)
+    }
+    /**
+     * Get GIF by Id.
+     *
+     * This function performs a `GET` to the `/gifs/{gifId}` endpoint.
+     *
+     * As opposed to `get_gifs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_gifs_with_response(
+        &self,
+        gif_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
         let url = self.client.url(
             &format!(
                 "/gifs/{}",
@@ -223,7 +314,7 @@ impl Gifs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -52,7 +52,7 @@
 //!
 //! And then you can create a client from the environment.
 //!
-//! ```
+//! ```no_run
 //! use giphy_api::Client;
 //!
 //! let giphy = Client::new_from_env();
@@ -87,6 +87,9 @@ pub enum ClientError {
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// A response did not match the generated types, in strict mode
+    #[error("Unexpected response at `{path}`: {error}")]
+    DeserializationError { path: String, error: String },
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -101,11 +104,15 @@ pub enum ClientError {
     HttpError {
         status: http::StatusCode,
         error: String,
+        request_id: Option<String>,
     },
 }
 
 pub const FALLBACK_HOST: &str = "https://api.giphy.com/v1";
 
+/// The header the API sends the id of a request back in.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
@@ -132,6 +139,44 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// A response from the API: the status code, headers and raw bytes of the
+/// response, along with the parsed body.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub raw: bytes::Bytes,
+    pub body: T,
+}
+
+impl<T> Response<T> {
+    /// Maps the parsed body, keeping the rest of the response.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            status: self.status,
+            headers: self.headers,
+            raw: self.raw,
+            body: f(self.body),
+        }
+    }
+
+    /// The id the API gave this request, if it sent one. This is the id to hand
+    /// to the API's support when something goes wrong.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+    }
+}
+
+/// The id the API gave a request, kept on the errors so it can be logged.
+pub(crate) fn request_id(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
 use std::env;
 
 #[derive(Debug, Default, Clone)]
@@ -149,6 +194,9 @@ pub struct Client {
     host: String,
     host_override: Option<String>,
     token: String,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,
 
     client: reqwest_middleware::ClientWithMiddleware,
 }
@@ -162,18 +210,15 @@ impl Client {
         T: ToString,
     {
         let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         match client {
             Ok(c) => {
                 let client = reqwest_middleware::ClientBuilder::new(c)
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
+                    .with(crate::utils::RetryMiddleware::default())
+                    // Stay under the rate limits, see `Client::set_rate_limiter`.
+                    .with(crate::utils::RateLimitMiddleware)
                     .build();
 
                 let host = RootDefaultServer::default().default_url().to_string();
@@ -182,6 +227,9 @@ impl Client {
                     host,
                     host_override: None,
                     token: token.to_string(),
+                    coercions: Default::default(),
+                    retry: Default::default(),
+                    limiter: None,
 
                     client,
                 }
@@ -270,7 +318,50 @@ impl Client {
         if let Some(body) = message.body {
             req = req.body(body);
         }
-        Ok(req.send().await?)
+        Ok(req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?)
+    }
+
+    /// Return an error with the JSON path of the offending field when a response
+    /// does not match the generated types, instead of falling back to defaults.
+    pub fn set_strict_deserialization(&mut self, enabled: bool) -> &mut Self {
+        self.coercions.strict = enabled;
+        self
+    }
+
+    /// The fields of past responses that did not match the generated types and
+    /// were replaced with their default value. The log is shared between clones
+    /// of the client and keeps the most recent 1000 fields.
+    pub fn coercions(&self) -> Vec<crate::utils::Coercion> {
+        self.coercions.list()
+    }
+
+    /// Clears the log of coerced fields.
+    pub fn clear_coercions(&self) {
+        self.coercions.clear();
+    }
+
+    /// Sets when and how often failed requests are retried.
+    pub fn set_retry_config(&mut self, config: crate::utils::RetryConfig) -> &mut Self {
+        self.retry = config;
+        self
+    }
+
+    /// Queues requests to stay under the rate limits of the API. The clones
+    /// made from the client afterwards share the same limiter.
+    pub fn set_rate_limiter(&mut self, limiter: crate::utils::RateLimiter) -> &mut Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Carries the retry config and rate limiter to the middleware.
+    fn request_extensions(&self) -> task_local_extensions::Extensions {
+        let mut extensions = self.retry.extensions();
+        if let Some(limiter) = &self.limiter {
+            extensions.insert(limiter.clone());
+        }
+        extensions
     }
 
     async fn request<Out>(
@@ -278,13 +369,15 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         message: Message,
-    ) -> ClientResult<Out>
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
         let response = self.request_raw(method, uri, message).await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -295,19 +388,26 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
             let error = if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -327,6 +427,7 @@ impl Client {
         let response = self.request_raw(method, uri, message).await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -345,7 +446,7 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
             Ok((link, parsed_response))
         } else {
@@ -353,20 +454,34 @@ impl Client {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
             Err(error)
         }
     }
 
-    /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_form_with_response(uri, form).await?.body)
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn post_form_with_response<Out>(
+        &self,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -388,9 +503,13 @@ impl Client {
 
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -405,19 +524,26 @@ impl Client {
                 let s = String::from_utf8(response_body.to_vec())?;
                 serde_json::from_value(serde_json::json!(&s))?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
             let error = if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -425,7 +551,6 @@ impl Client {
         }
     }
 
-    /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn request_with_accept_mime<Out>(
         &self,
@@ -433,6 +558,23 @@ impl Client {
         uri: &str,
         accept_mime_type: &str,
     ) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self
+            .request_with_accept_mime_with_response(method, uri, accept_mime_type)
+            .await?
+            .body)
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_with_accept_mime_with_response<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -440,7 +582,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(method, url);
+        let mut req = instance.client.request(method.clone(), url);
 
         // Set the default headers.
         req = req.header(
@@ -452,9 +594,13 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -469,19 +615,26 @@ impl Client {
                 let s = String::from_utf8(response_body.to_vec())?;
                 serde_json::from_value(serde_json::json!(&s))?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
             let error = if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -505,7 +658,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(method, url);
+        let mut req = instance.client.request(method.clone(), url);
 
         // Set the default headers.
         req = req.header(
@@ -537,9 +690,12 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -550,7 +706,7 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
             Ok(parsed_response)
         } else {
@@ -558,11 +714,13 @@ impl Client {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -575,16 +733,27 @@ impl Client {
         method: http::Method,
         uri: &str,
         message: Message,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let r = self.request(method, uri, message).await?;
-        Ok(r)
+        self.request(method, uri, message).await
     }
 
     #[allow(dead_code)]
     async fn get<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.get_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn get_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -649,6 +818,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn post_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -657,6 +838,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn patch<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.patch_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn patch_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -665,6 +858,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn put<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.put_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn put_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -673,6 +878,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn delete<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.delete_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn delete_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
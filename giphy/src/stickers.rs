@@ -29,6 +29,20 @@ impl Stickers {
         tag: &str,
         rating: &str,
     ) -> ClientResult<crate::types::RandomGifResponse> {
+        Ok(self.random_with_response(tag, rating).await?.body)
+    }
+    /**
+     * Random Sticker.
+     *
+     * This function performs a `GET` to the `/stickers/random` endpoint.
+     *
+     * As opposed to `random`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn random_with_response(
+        &self,
+        tag: &str,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !rating.is_empty() {
             query_args.push(("rating".to_string(), rating.to_string()));
@@ -41,7 +55,7 @@ impl Stickers {
             .client
             .url(&format!("/stickers/random?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -74,6 +88,26 @@ impl Stickers {
         rating: &str,
         lang: &str,
     ) -> ClientResult<crate::types::GetGifsByResponse> {
+        Ok(self
+            .search_with_response(q, limit, offset, rating, lang)
+            .await?
+            .body)
+    }
+    /**
+     * Search Stickers.
+     *
+     * This function performs a `GET` to the `/stickers/search` endpoint.
+     *
+     * As opposed to `search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn search_with_response(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !lang.is_empty() {
             query_args.push(("lang".to_string(), lang.to_string()));
@@ -95,7 +129,7 @@ impl Stickers {
             .client
             .url(&format!("/stickers/search?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -117,6 +151,19 @@ impl Stickers {
      * * `s: &str` -- The unique bit.ly URL for this GIF.
      */
     pub async fn translate(&self, s: &str) -> ClientResult<crate::types::RandomGifResponse> {
+        Ok(self.translate_with_response(s).await?.body)
+    }
+    /**
+     * Translate phrase to Sticker.
+     *
+     * This function performs a `GET` to the `/stickers/translate` endpoint.
+     *
+     * As opposed to `translate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn translate_with_response(
+        &self,
+        s: &str,
+    ) -> ClientResult<crate::Response<crate::types::RandomGifResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !s.is_empty() {
             query_args.push(("s".to_string(), s.to_string()));
@@ -126,7 +173,7 @@ impl Stickers {
             .client
             .url(&format!("/stickers/translate?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -155,6 +202,24 @@ impl Stickers {
         offset: i64,
         rating: &str,
     ) -> ClientResult<crate::types::GetGifsByResponse> {
+        Ok(self
+            .trending_with_response(limit, offset, rating)
+            .await?
+            .body)
+    }
+    /**
+     * Trending Stickers.
+     *
+     * This function performs a `GET` to the `/stickers/trending` endpoint.
+     *
+     * As opposed to `trending`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trending_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGifsByResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -170,7 +235,7 @@ impl Stickers {
             .client
             .url(&format!("/stickers/trending?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -21,104 +21,44 @@ pub struct LoopingAllOf {
 /// An object containing data for various available formats and sizes of this GIF.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Images {
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub downsized: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub downsized_large: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub downsized_medium: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub downsized_small: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub downsized_still: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_height: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_height_downsampled: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_height_small: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_height_small_still: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_height_still: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_width: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_width_downsampled: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_width_small: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_width_small_still: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fixed_width_still: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub looping: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub original: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub original_still: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preview: Option<LoopingAllOf>,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preview_gif: Option<LoopingAllOf>,
 }
@@ -127,23 +67,43 @@ pub struct Images {
 * Type of the gif. By default, this is almost always gif
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "gif")]
     Gif,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Type::Gif => "gif",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "gif" => Type::Gif,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Gif
@@ -162,7 +122,7 @@ pub struct Gif {
     )]
     pub bitly_url: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * Currently unused
      */
     #[serde(
         default,
@@ -180,7 +140,7 @@ pub struct Gif {
     )]
     pub create_datetime: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The unique bit.ly URL for this GIF
+     * A URL used for embedding this GIF
      */
     #[serde(
         default,
@@ -199,7 +159,7 @@ pub struct Gif {
     )]
     pub featured_tags: Vec<String>,
     /**
-     * The unique bit.ly URL for this GIF
+     * This GIF's unique ID
      */
     #[serde(
         default,
@@ -213,7 +173,7 @@ pub struct Gif {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub images: Option<Images>,
     /**
-     * The date this GIF was added to the GIPHY database.
+     * The creation or upload date from this GIF's source.
      */
     #[serde(
         default,
@@ -222,7 +182,7 @@ pub struct Gif {
     )]
     pub import_datetime: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The unique bit.ly URL for this GIF
+     * The MPAA-style rating for this content. Examples include Y, G, PG, PG-13 and R
      */
     #[serde(
         default,
@@ -231,7 +191,7 @@ pub struct Gif {
     )]
     pub rating: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The unique slug used in this GIF's URL
      */
     #[serde(
         default,
@@ -240,7 +200,7 @@ pub struct Gif {
     )]
     pub slug: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The page on which this GIF was found
      */
     #[serde(
         default,
@@ -249,7 +209,7 @@ pub struct Gif {
     )]
     pub source: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The URL of the webpage on which this GIF was found.
      */
     #[serde(
         default,
@@ -258,7 +218,7 @@ pub struct Gif {
     )]
     pub source_post_url: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The top level domain of the source URL.
      */
     #[serde(
         default,
@@ -267,7 +227,7 @@ pub struct Gif {
     )]
     pub source_tld: String,
     /**
-     * An array of featured tags for this GIF (Note: Not available when using the Public Beta Key)
+     * An array of tags for this GIF (Note: Not available when using the Public Beta Key)
      *
      */
     #[serde(
@@ -277,7 +237,7 @@ pub struct Gif {
     )]
     pub tags: Vec<String>,
     /**
-     * The date this GIF was added to the GIPHY database.
+     * The date on which this gif was marked trending, if applicable.
      */
     #[serde(
         default,
@@ -291,7 +251,7 @@ pub struct Gif {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
     /**
-     * The date this GIF was added to the GIPHY database.
+     * The date on which this GIF was last updated.
      */
     #[serde(
         default,
@@ -300,7 +260,7 @@ pub struct Gif {
     )]
     pub update_datetime: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The unique bit.ly URL for this GIF
+     * The unique URL for this GIF
      */
     #[serde(
         default,
@@ -314,7 +274,7 @@ pub struct Gif {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
     /**
-     * The unique bit.ly URL for this GIF
+     * The username this GIF is attached to, if applicable
      */
     #[serde(
         default,
@@ -327,7 +287,7 @@ pub struct Gif {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Image {
     /**
-     * The unique bit.ly URL for this GIF
+     * The number of frames in this GIF.
      */
     #[serde(
         default,
@@ -336,7 +296,7 @@ pub struct Image {
     )]
     pub frames: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The height of this GIF in pixels.
      */
     #[serde(
         default,
@@ -345,7 +305,7 @@ pub struct Image {
     )]
     pub height: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The URL for this GIF in .MP4 format.
      */
     #[serde(
         default,
@@ -355,7 +315,7 @@ pub struct Image {
     )]
     pub mp_4: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The size in bytes of the .MP4 file corresponding to this GIF.
      */
     #[serde(
         default,
@@ -365,7 +325,7 @@ pub struct Image {
     )]
     pub mp_4_size: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The size of this GIF in bytes.
      */
     #[serde(
         default,
@@ -374,7 +334,7 @@ pub struct Image {
     )]
     pub size: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The publicly-accessible direct URL for this GIF.
      */
     #[serde(
         default,
@@ -383,7 +343,7 @@ pub struct Image {
     )]
     pub url: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The URL for this GIF in .webp format.
      */
     #[serde(
         default,
@@ -392,7 +352,7 @@ pub struct Image {
     )]
     pub webp: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The size in bytes of the .webp file corresponding to this GIF.
      */
     #[serde(
         default,
@@ -401,7 +361,7 @@ pub struct Image {
     )]
     pub webp_size: String,
     /**
-     * The unique bit.ly URL for this GIF
+     * The width of this GIF in pixels.
      */
     #[serde(
         default,
@@ -416,8 +376,7 @@ pub struct Image {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Meta {
     /**
-     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
-     *
+     * HTTP Response Message
      */
     #[serde(
         default,
@@ -426,8 +385,7 @@ pub struct Meta {
     )]
     pub msg: String,
     /**
-     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
-     *
+     * A unique ID paired with this response from the API.
      */
     #[serde(
         default,
@@ -436,8 +394,7 @@ pub struct Meta {
     )]
     pub response_id: String,
     /**
-     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
-     *
+     * HTTP Response Code
      */
     #[serde(
         default,
@@ -452,8 +409,7 @@ pub struct Meta {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Pagination {
     /**
-     * The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
-     *
+     * Total number of items returned.
      */
     #[serde(
         default,
@@ -462,8 +418,7 @@ pub struct Pagination {
     )]
     pub count: i64,
     /**
-     * The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
-     *
+     * Position in pagination.
      */
     #[serde(
         default,
@@ -472,8 +427,7 @@ pub struct Pagination {
     )]
     pub offset: i64,
     /**
-     * The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
-     *
+     * Total number of items available.
      */
     #[serde(
         default,
@@ -487,7 +441,7 @@ pub struct Pagination {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct User {
     /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     * The URL for this user's avatar image.
      */
     #[serde(
         default,
@@ -496,7 +450,7 @@ pub struct User {
     )]
     pub avatar_url: String,
     /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     * The URL for the banner image that appears atop this user's profile page.
      */
     #[serde(
         default,
@@ -505,7 +459,7 @@ pub struct User {
     )]
     pub banner_url: String,
     /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     * The display name associated with this user (contains formatting the base username might not).
      */
     #[serde(
         default,
@@ -514,7 +468,7 @@ pub struct User {
     )]
     pub display_name: String,
     /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     * The URL for this user's profile.
      */
     #[serde(
         default,
@@ -523,7 +477,7 @@ pub struct User {
     )]
     pub profile_url: String,
     /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     * The Twitter username associated with this user, if applicable.
      */
     #[serde(
         default,
@@ -532,7 +486,7 @@ pub struct User {
     )]
     pub twitter: String,
     /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     * The username associated with this user.
      */
     #[serde(
         default,
@@ -26,15 +26,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let jwt = JWTCredentials::new(app_id, key.data).unwrap();
 
     let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
     let client = reqwest_middleware::ClientBuilder::new(http)
         // Trace HTTP requests. See the tracing crate to make use of these traces.
         .with(reqwest_tracing::TracingMiddleware::default())
         // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
+        .with(octorust::utils::RetryMiddleware::default())
+        // Stay under the rate limits, see `Client::set_rate_limiter`.
+        .with(octorust::utils::RateLimitMiddleware)
         .build();
 
     // Create the HTTP cache.
@@ -30,6 +30,24 @@ impl Actions {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::ActionsOrganizationPermissions> {
+        Ok(self
+            .get_github_actions_permissions_organization_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Actions permissions for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/permissions` endpoint.
+     *
+     * As opposed to `get_github_actions_permissions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-github-actions-permissions-for-an-organization>
+     */
+    pub async fn get_github_actions_permissions_organization_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsOrganizationPermissions>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/permissions",
@@ -38,7 +56,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +87,24 @@ impl Actions {
         org: &str,
         body: &crate::types::ActionsSetGithubPermissionsOrganizationRequest,
     ) -> ClientResult<()> {
+        self.set_github_actions_permissions_organization_with_response(org, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set GitHub Actions permissions for an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/permissions` endpoint.
+     *
+     * As opposed to `set_github_actions_permissions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-github-actions-permissions-for-an-organization>
+     */
+    pub async fn set_github_actions_permissions_organization_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetGithubPermissionsOrganizationRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/permissions",
@@ -77,7 +113,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -110,6 +146,32 @@ impl Actions {
         page: i64,
     ) -> ClientResult<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>
     {
+        Ok(self
+            .list_selected_repositories_enabled_github_actions_organization_with_response(
+                org, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List selected repositories enabled for GitHub Actions in an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/permissions/repositories` endpoint.
+     *
+     * As opposed to `list_selected_repositories_enabled_github_actions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-selected-repositories-enabled-for-github-actions-in-an-organization>
+     */
+    pub async fn list_selected_repositories_enabled_github_actions_organization_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<
+            crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse,
+        >,
+    > {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -127,7 +189,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -156,6 +218,24 @@ impl Actions {
         org: &str,
         body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
     ) -> ClientResult<()> {
+        self.set_selected_repositories_enabled_github_actions_organization_with_response(org, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set selected repositories enabled for GitHub Actions in an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/permissions/repositories` endpoint.
+     *
+     * As opposed to `set_selected_repositories_enabled_github_actions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-selected-repositories-enabled-for-github-actions-in-an-organization>
+     */
+    pub async fn set_selected_repositories_enabled_github_actions_organization_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/permissions/repositories",
@@ -164,7 +244,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -194,6 +274,27 @@ impl Actions {
         org: &str,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.enable_selected_repository_github_actions_organization_with_response(
+            org,
+            repository_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Enable a selected repository for GitHub Actions in an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/permissions/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `enable_selected_repository_github_actions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#enable-a-selected-repository-for-github-actions-in-an-organization>
+     */
+    pub async fn enable_selected_repository_github_actions_organization_with_response(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/permissions/repositories/{}",
@@ -203,7 +304,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -233,6 +334,27 @@ impl Actions {
         org: &str,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.disable_selected_repository_github_actions_organization_with_response(
+            org,
+            repository_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Disable a selected repository for GitHub Actions in an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/actions/permissions/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `disable_selected_repository_github_actions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#disable-a-selected-repository-for-github-actions-in-an-organization>
+     */
+    pub async fn disable_selected_repository_github_actions_organization_with_response(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/permissions/repositories/{}",
@@ -242,7 +364,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -270,6 +392,24 @@ impl Actions {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::SelectedActions> {
+        Ok(self
+            .get_allowed_actions_organization_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Get allowed actions for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/permissions/selected-actions` endpoint.
+     *
+     * As opposed to `get_allowed_actions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-allowed-actions-for-an-organization>
+     */
+    pub async fn get_allowed_actions_organization_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::SelectedActions>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/permissions/selected-actions",
@@ -278,7 +418,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -311,6 +451,24 @@ impl Actions {
         org: &str,
         body: &crate::types::SelectedActions,
     ) -> ClientResult<()> {
+        self.set_allowed_actions_organization_with_response(org, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set allowed actions for an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/permissions/selected-actions` endpoint.
+     *
+     * As opposed to `set_allowed_actions_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-allowed-actions-for-an-organization>
+     */
+    pub async fn set_allowed_actions_organization_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::SelectedActions,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/permissions/selected-actions",
@@ -319,7 +477,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -353,6 +511,27 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse> {
+        Ok(self
+            .list_self_hosted_runner_groups_for_org_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List self-hosted runner groups for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runner-groups` endpoint.
+     *
+     * As opposed to `list_self_hosted_runner_groups_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runner-groups-for-an-organization>
+     */
+    pub async fn list_self_hosted_runner_groups_for_org_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -370,7 +549,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -401,6 +580,25 @@ impl Actions {
         org: &str,
         body: &crate::types::ActionsCreateSelfHostedRunnerGroupOrgRequest,
     ) -> ClientResult<crate::types::RunnerGroupsOrg> {
+        Ok(self
+            .create_self_hosted_runner_group_for_org_with_response(org, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a self-hosted runner group for an organization.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/actions/runner-groups` endpoint.
+     *
+     * As opposed to `create_self_hosted_runner_group_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-a-self-hosted-runner-group-for-an-organization>
+     */
+    pub async fn create_self_hosted_runner_group_for_org_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsCreateSelfHostedRunnerGroupOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsOrg>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups",
@@ -409,7 +607,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -441,6 +639,25 @@ impl Actions {
         org: &str,
         runner_group_id: i64,
     ) -> ClientResult<crate::types::RunnerGroupsOrg> {
+        Ok(self
+            .get_self_hosted_runner_group_for_org_with_response(org, runner_group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a self-hosted runner group for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}` endpoint.
+     *
+     * As opposed to `get_self_hosted_runner_group_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-self-hosted-runner-group-for-an-organization>
+     */
+    pub async fn get_self_hosted_runner_group_for_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsOrg>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}",
@@ -450,7 +667,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -482,6 +699,24 @@ impl Actions {
         org: &str,
         runner_group_id: i64,
     ) -> ClientResult<()> {
+        self.delete_self_hosted_runner_group_from_org_with_response(org, runner_group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a self-hosted runner group from an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}` endpoint.
+     *
+     * As opposed to `delete_self_hosted_runner_group_from_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-a-self-hosted-runner-group-from-an-organization>
+     */
+    pub async fn delete_self_hosted_runner_group_from_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}",
@@ -491,7 +726,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -524,6 +759,26 @@ impl Actions {
         runner_group_id: i64,
         body: &crate::types::ActionsUpdateSelfHostedRunnerGroupOrgRequest,
     ) -> ClientResult<crate::types::RunnerGroupsOrg> {
+        Ok(self
+            .update_self_hosted_runner_group_for_org_with_response(org, runner_group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a self-hosted runner group for an organization.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}` endpoint.
+     *
+     * As opposed to `update_self_hosted_runner_group_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#update-a-self-hosted-runner-group-for-an-organization>
+     */
+    pub async fn update_self_hosted_runner_group_for_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsUpdateSelfHostedRunnerGroupOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsOrg>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}",
@@ -533,7 +788,7 @@ impl Actions {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -569,6 +824,34 @@ impl Actions {
         page: i64,
         per_page: i64,
     ) -> ClientResult<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse> {
+        Ok(self
+            .list_repo_access_to_self_hosted_runner_group_in_org_with_response(
+                org,
+                runner_group_id,
+                page,
+                per_page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List repository access to a self-hosted runner group in an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories` endpoint.
+     *
+     * As opposed to `list_repo_access_to_self_hosted_runner_group_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-repository-access-to-a-self-hosted-runner-group-in-an-organization>
+     */
+    pub async fn list_repo_access_to_self_hosted_runner_group_in_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse>,
+    > {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -587,7 +870,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -620,6 +903,29 @@ impl Actions {
         runner_group_id: i64,
         body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
     ) -> ClientResult<()> {
+        self.set_repo_access_to_self_hosted_runner_group_in_org_with_response(
+            org,
+            runner_group_id,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Set repository access for a self-hosted runner group in an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories` endpoint.
+     *
+     * As opposed to `set_repo_access_to_self_hosted_runner_group_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-repository-access-to-a-self-hosted-runner-group-in-an-organization>
+     */
+    pub async fn set_repo_access_to_self_hosted_runner_group_in_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}/repositories",
@@ -629,7 +935,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -665,6 +971,29 @@ impl Actions {
         runner_group_id: i64,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.add_repo_access_to_self_hosted_runner_group_in_org_with_response(
+            org,
+            runner_group_id,
+            repository_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Add repository access to a self-hosted runner group in an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `add_repo_access_to_self_hosted_runner_group_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#add-repository-acess-to-a-self-hosted-runner-group-in-an-organization>
+     */
+    pub async fn add_repo_access_to_self_hosted_runner_group_in_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}/repositories/{}",
@@ -675,7 +1004,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -710,6 +1039,29 @@ impl Actions {
         runner_group_id: i64,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.remove_repo_access_to_self_hosted_runner_group_in_org_with_response(
+            org,
+            runner_group_id,
+            repository_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Remove repository access to a self-hosted runner group in an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `remove_repo_access_to_self_hosted_runner_group_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#remove-repository-access-to-a-self-hosted-runner-group-in-an-organization>
+     */
+    pub async fn remove_repo_access_to_self_hosted_runner_group_in_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}/repositories/{}",
@@ -720,7 +1072,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -756,6 +1108,33 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
+        Ok(self
+            .list_self_hosted_runners_in_group_for_org_with_response(
+                org,
+                runner_group_id,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List self-hosted runners in a group for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_in_group_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runners-in-a-group-for-an-organization>
+     */
+    pub async fn list_self_hosted_runners_in_group_for_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -774,7 +1153,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -807,6 +1186,25 @@ impl Actions {
         runner_group_id: i64,
         body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
     ) -> ClientResult<()> {
+        self.set_self_hosted_runners_in_group_for_org_with_response(org, runner_group_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set self-hosted runners in a group for an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners` endpoint.
+     *
+     * As opposed to `set_self_hosted_runners_in_group_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-self-hosted-runners-in-a-group-for-an-organization>
+     */
+    pub async fn set_self_hosted_runners_in_group_for_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}/runners",
@@ -816,7 +1214,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -852,6 +1250,25 @@ impl Actions {
         runner_group_id: i64,
         runner_id: i64,
     ) -> ClientResult<()> {
+        self.add_self_hosted_runner_to_group_for_org_with_response(org, runner_group_id, runner_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add a self-hosted runner to a group for an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `add_self_hosted_runner_to_group_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#add-a-self-hosted-runner-to-a-group-for-an-organization>
+     */
+    pub async fn add_self_hosted_runner_to_group_for_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}/runners/{}",
@@ -862,7 +1279,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -897,7 +1314,30 @@ impl Actions {
         runner_group_id: i64,
         runner_id: i64,
     ) -> ClientResult<()> {
-        let url = self.client.url(
+        self.remove_self_hosted_runner_from_group_for_org_with_response(
+            org,
+            runner_group_id,
+            runner_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Remove a self-hosted runner from a group for an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `remove_self_hosted_runner_from_group_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#remove-a-self-hosted-runner-from-a-group-for-an-organization>
+     */
+    pub async fn remove_self_hosted_runner_from_group_for_org_with_response(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runner-groups/{}/runners/{}",
                 crate::progenitor_support::encode_path(org),
@@ -907,7 +1347,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -939,6 +1379,26 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+        Ok(self
+            .list_self_hosted_runners_for_org_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List self-hosted runners for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runners-for-an-organization>
+     */
+    pub async fn list_self_hosted_runners_for_org_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -956,7 +1416,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -984,6 +1444,24 @@ impl Actions {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::RunnerApplication>> {
+        Ok(self
+            .list_runner_applications_for_org_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * List runner applications for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_runner_applications_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-an-organization>
+     */
+    pub async fn list_runner_applications_for_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runners/downloads",
@@ -992,7 +1470,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1035,6 +1513,79 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List runner applications for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-an-organization>
+     */
+    pub fn list_all_runner_applications_for_org_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::RunnerApplication>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::RunnerApplication>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List runner applications for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-an-organization>
+     */
+    pub fn list_all_runner_applications_for_org_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_runner_applications_for_org_pages_stream(org, None))
+    }
     /**
      * Create a registration token for an organization.
      *
@@ -1062,6 +1613,24 @@ impl Actions {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::AuthenticationToken> {
+        Ok(self
+            .create_registration_token_for_org_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Create a registration token for an organization.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/actions/runners/registration-token` endpoint.
+     *
+     * As opposed to `create_registration_token_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-a-registration-token-for-an-organization>
+     */
+    pub async fn create_registration_token_for_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runners/registration-token",
@@ -1070,7 +1639,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1107,6 +1676,24 @@ impl Actions {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::AuthenticationToken> {
+        Ok(self
+            .create_remove_token_for_org_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Create a remove token for an organization.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/actions/runners/remove-token` endpoint.
+     *
+     * As opposed to `create_remove_token_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-a-remove-token-for-an-organization>
+     */
+    pub async fn create_remove_token_for_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runners/remove-token",
@@ -1115,7 +1702,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1145,6 +1732,25 @@ impl Actions {
         org: &str,
         runner_id: i64,
     ) -> ClientResult<crate::types::Runner> {
+        Ok(self
+            .get_self_hosted_runner_for_org_with_response(org, runner_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a self-hosted runner for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `get_self_hosted_runner_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-self-hosted-runner-for-an-organization>
+     */
+    pub async fn get_self_hosted_runner_for_org_with_response(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Runner>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runners/{}",
@@ -1154,7 +1760,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1184,6 +1790,24 @@ impl Actions {
         org: &str,
         runner_id: i64,
     ) -> ClientResult<()> {
+        self.delete_self_hosted_runner_from_org_with_response(org, runner_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a self-hosted runner from an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/actions/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `delete_self_hosted_runner_from_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-a-self-hosted-runner-from-an-organization>
+     */
+    pub async fn delete_self_hosted_runner_from_org_with_response(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/runners/{}",
@@ -1193,7 +1817,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1223,6 +1847,26 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListOrgSecretsResponse> {
+        Ok(self
+            .list_org_secrets_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization secrets.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/secrets` endpoint.
+     *
+     * As opposed to `list_org_secrets`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-organization-secrets>
+     */
+    pub async fn list_org_secrets_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListOrgSecretsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1240,7 +1884,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1266,6 +1910,21 @@ impl Actions {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::ActionsPublicKey> {
+        Ok(self.get_org_public_key_with_response(org).await?.body)
+    }
+    /**
+     * Get an organization public key.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/secrets/public-key` endpoint.
+     *
+     * As opposed to `get_org_public_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-an-organization-public-key>
+     */
+    pub async fn get_org_public_key_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsPublicKey>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/secrets/public-key",
@@ -1274,7 +1933,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1302,6 +1961,25 @@ impl Actions {
         org: &str,
         secret_name: &str,
     ) -> ClientResult<crate::types::OrganizationActionsSecret> {
+        Ok(self
+            .get_org_secret_with_response(org, secret_name)
+            .await?
+            .body)
+    }
+    /**
+     * Get an organization secret.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `get_org_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-an-organization-secret>
+     */
+    pub async fn get_org_secret_with_response(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrganizationActionsSecret>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/secrets/{}",
@@ -1311,7 +1989,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1414,6 +2092,25 @@ impl Actions {
         secret_name: &str,
         body: &crate::types::ActionsCreateUpdateOrgSecretRequest,
     ) -> ClientResult<()> {
+        self.create_or_update_org_secret_with_response(org, secret_name, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Create or update an organization secret.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `create_or_update_org_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-or-update-an-organization-secret>
+     */
+    pub async fn create_or_update_org_secret_with_response(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateOrgSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/secrets/{}",
@@ -1423,7 +2120,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1447,6 +2144,24 @@ impl Actions {
      * * `secret_name: &str` -- secret_name parameter.
      */
     pub async fn delete_org_secret(&self, org: &str, secret_name: &str) -> ClientResult<()> {
+        self.delete_org_secret_with_response(org, secret_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an organization secret.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/actions/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `delete_org_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-an-organization-secret>
+     */
+    pub async fn delete_org_secret_with_response(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/secrets/{}",
@@ -1456,7 +2171,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1488,6 +2203,28 @@ impl Actions {
         page: i64,
         per_page: i64,
     ) -> ClientResult<crate::types::ActionsListSelectedReposOrgSecretResponse> {
+        Ok(self
+            .list_selected_repos_for_org_secret_with_response(org, secret_name, page, per_page)
+            .await?
+            .body)
+    }
+    /**
+     * List selected repositories for an organization secret.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/secrets/{secret_name}/repositories` endpoint.
+     *
+     * As opposed to `list_selected_repos_for_org_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-selected-repositories-for-an-organization-secret>
+     */
+    pub async fn list_selected_repos_for_org_secret_with_response(
+        &self,
+        org: &str,
+        secret_name: &str,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelectedReposOrgSecretResponse>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1506,7 +2243,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1535,6 +2272,25 @@ impl Actions {
         secret_name: &str,
         body: &crate::types::ActionsSetSelectedReposOrgSecretRequest,
     ) -> ClientResult<()> {
+        self.set_selected_repos_for_org_secret_with_response(org, secret_name, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set selected repositories for an organization secret.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/secrets/{secret_name}/repositories` endpoint.
+     *
+     * As opposed to `set_selected_repos_for_org_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-selected-repositories-for-an-organization-secret>
+     */
+    pub async fn set_selected_repos_for_org_secret_with_response(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsSetSelectedReposOrgSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/secrets/{}/repositories",
@@ -1544,7 +2300,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1574,6 +2330,25 @@ impl Actions {
         secret_name: &str,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.add_selected_repo_to_org_secret_with_response(org, secret_name, repository_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add selected repository to an organization secret.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `add_selected_repo_to_org_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#add-selected-repository-to-an-organization-secret>
+     */
+    pub async fn add_selected_repo_to_org_secret_with_response(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/secrets/{}/repositories/{}",
@@ -1584,7 +2359,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1614,6 +2389,25 @@ impl Actions {
         secret_name: &str,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.remove_selected_repo_from_org_secret_with_response(org, secret_name, repository_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove selected repository from an organization secret.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `remove_selected_repo_from_org_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#remove-selected-repository-from-an-organization-secret>
+     */
+    pub async fn remove_selected_repo_from_org_secret_with_response(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/actions/secrets/{}/repositories/{}",
@@ -1624,7 +2418,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1656,6 +2450,27 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListArtifactsRepoResponse> {
+        Ok(self
+            .list_artifacts_for_repo_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List artifacts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/artifacts` endpoint.
+     *
+     * As opposed to `list_artifacts_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-artifacts-for-a-repository>
+     */
+    pub async fn list_artifacts_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1674,7 +2489,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1704,17 +2519,37 @@ impl Actions {
         repo: &str,
         artifact_id: i64,
     ) -> ClientResult<crate::types::Artifact> {
-        let url = self.client.url(
-            &format!(
-                "/repos/{}/{}/actions/artifacts/{}",
-                crate::progenitor_support::encode_path(owner),
-                crate::progenitor_support::encode_path(repo),
-                crate::progenitor_support::encode_path(&artifact_id.to_string()),
-            ),
-            None,
-        );
-        self.client
-            .get(
+        Ok(self
+            .get_artifact_with_response(owner, repo, artifact_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get an artifact.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}` endpoint.
+     *
+     * As opposed to `get_artifact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-an-artifact>
+     */
+    pub async fn get_artifact_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Artifact>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/artifacts/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&artifact_id.to_string()),
+            ),
+            None,
+        );
+        self.client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1744,6 +2579,25 @@ impl Actions {
         repo: &str,
         artifact_id: i64,
     ) -> ClientResult<()> {
+        self.delete_artifact_with_response(owner, repo, artifact_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an artifact.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}` endpoint.
+     *
+     * As opposed to `delete_artifact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-an-artifact>
+     */
+    pub async fn delete_artifact_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/artifacts/{}",
@@ -1754,7 +2608,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1789,6 +2643,26 @@ impl Actions {
         artifact_id: i64,
         archive_format: &str,
     ) -> ClientResult<()> {
+        self.download_artifact_with_response(owner, repo, artifact_id, archive_format)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Download an artifact.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}` endpoint.
+     *
+     * As opposed to `download_artifact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#download-an-artifact>
+     */
+    pub async fn download_artifact_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/artifacts/{}/{}",
@@ -1800,7 +2674,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1830,6 +2704,26 @@ impl Actions {
         repo: &str,
         job_id: i64,
     ) -> ClientResult<crate::types::Job> {
+        Ok(self
+            .get_job_for_workflow_run_with_response(owner, repo, job_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a job for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/jobs/{job_id}` endpoint.
+     *
+     * As opposed to `get_job_for_workflow_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-job-for-a-workflow-run>
+     */
+    pub async fn get_job_for_workflow_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Job>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/jobs/{}",
@@ -1840,7 +2734,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1873,6 +2767,25 @@ impl Actions {
         repo: &str,
         job_id: i64,
     ) -> ClientResult<()> {
+        self.download_job_logs_for_workflow_run_with_response(owner, repo, job_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Download job logs for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/jobs/{job_id}/logs` endpoint.
+     *
+     * As opposed to `download_job_logs_for_workflow_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#download-job-logs-for-a-workflow-run>
+     */
+    pub async fn download_job_logs_for_workflow_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/jobs/{}/logs",
@@ -1883,7 +2796,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1914,6 +2827,25 @@ impl Actions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::ActionsRepositoryPermissions> {
+        Ok(self
+            .get_github_actions_permissions_repository_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Actions permissions for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/permissions` endpoint.
+     *
+     * As opposed to `get_github_actions_permissions_repository`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-github-actions-permissions-for-a-repository>
+     */
+    pub async fn get_github_actions_permissions_repository_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsRepositoryPermissions>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/permissions",
@@ -1923,7 +2855,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1956,6 +2888,25 @@ impl Actions {
         repo: &str,
         body: &crate::types::ActionsSetGithubPermissionsRepositoryRequest,
     ) -> ClientResult<()> {
+        self.set_github_actions_permissions_repository_with_response(owner, repo, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set GitHub Actions permissions for a repository.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/actions/permissions` endpoint.
+     *
+     * As opposed to `set_github_actions_permissions_repository`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-github-actions-permissions-for-a-repository>
+     */
+    pub async fn set_github_actions_permissions_repository_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActionsSetGithubPermissionsRepositoryRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/permissions",
@@ -1965,7 +2916,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1995,6 +2946,25 @@ impl Actions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::SelectedActions> {
+        Ok(self
+            .get_allowed_actions_repository_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get allowed actions for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/permissions/selected-actions` endpoint.
+     *
+     * As opposed to `get_allowed_actions_repository`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-allowed-actions-for-a-repository>
+     */
+    pub async fn get_allowed_actions_repository_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::SelectedActions>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/permissions/selected-actions",
@@ -2004,7 +2974,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2039,6 +3009,25 @@ impl Actions {
         repo: &str,
         body: &crate::types::SelectedActions,
     ) -> ClientResult<()> {
+        self.set_allowed_actions_repository_with_response(owner, repo, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set allowed actions for a repository.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/actions/permissions/selected-actions` endpoint.
+     *
+     * As opposed to `set_allowed_actions_repository`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#set-allowed-actions-for-a-repository>
+     */
+    pub async fn set_allowed_actions_repository_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::SelectedActions,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/permissions/selected-actions",
@@ -2048,7 +3037,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2080,6 +3069,27 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+        Ok(self
+            .list_self_hosted_runners_for_repo_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List self-hosted runners for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-self-hosted-runners-for-a-repository>
+     */
+    pub async fn list_self_hosted_runners_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersOrgResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2098,7 +3108,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2128,6 +3138,25 @@ impl Actions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::RunnerApplication>> {
+        Ok(self
+            .list_runner_applications_for_repo_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * List runner applications for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_runner_applications_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-a-repository>
+     */
+    pub async fn list_runner_applications_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runners/downloads",
@@ -2137,7 +3166,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2182,6 +3211,84 @@ impl Actions {
             )
             .await
     }
+    /**
+     * List runner applications for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-a-repository>
+     */
+    pub fn list_all_runner_applications_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::RunnerApplication>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::RunnerApplication>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List runner applications for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-a-repository>
+     */
+    pub fn list_all_runner_applications_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_runner_applications_for_repo_pages_stream(owner, repo, None),
+        )
+    }
     /**
      * Create a registration token for a repository.
      *
@@ -2210,6 +3317,25 @@ impl Actions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::AuthenticationToken> {
+        Ok(self
+            .create_registration_token_for_repo_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Create a registration token for a repository.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runners/registration-token` endpoint.
+     *
+     * As opposed to `create_registration_token_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-a-registration-token-for-a-repository>
+     */
+    pub async fn create_registration_token_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runners/registration-token",
@@ -2219,7 +3345,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2256,6 +3382,25 @@ impl Actions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::AuthenticationToken> {
+        Ok(self
+            .create_remove_token_for_repo_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Create a remove token for a repository.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runners/remove-token` endpoint.
+     *
+     * As opposed to `create_remove_token_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-a-remove-token-for-a-repository>
+     */
+    pub async fn create_remove_token_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runners/remove-token",
@@ -2265,7 +3410,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2298,6 +3443,26 @@ impl Actions {
         repo: &str,
         runner_id: i64,
     ) -> ClientResult<crate::types::Runner> {
+        Ok(self
+            .get_self_hosted_runner_for_repo_with_response(owner, repo, runner_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a self-hosted runner for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `get_self_hosted_runner_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-self-hosted-runner-for-a-repository>
+     */
+    pub async fn get_self_hosted_runner_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Runner>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runners/{}",
@@ -2308,7 +3473,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2341,6 +3506,25 @@ impl Actions {
         repo: &str,
         runner_id: i64,
     ) -> ClientResult<()> {
+        self.delete_self_hosted_runner_from_repo_with_response(owner, repo, runner_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a self-hosted runner from a repository.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/actions/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `delete_self_hosted_runner_from_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-a-self-hosted-runner-from-a-repository>
+     */
+    pub async fn delete_self_hosted_runner_from_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runners/{}",
@@ -2351,7 +3535,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2395,24 +3579,52 @@ impl Actions {
         page: i64,
         created: &str,
     ) -> ClientResult<crate::types::ActionsListWorkflowRunsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !actor.is_empty() {
-            query_args.push(("actor".to_string(), actor.to_string()));
-        }
-        if !branch.is_empty() {
-            query_args.push(("branch".to_string(), branch.to_string()));
-        }
-        if !created.is_empty() {
-            query_args.push(("created".to_string(), created.to_string()));
-        }
-        if !event.is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
-        }
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+        Ok(self
+            .list_workflow_runs_for_repo_with_response(
+                owner, repo, actor, branch, event, status, per_page, page, created,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List workflow runs for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs` endpoint.
+     *
+     * As opposed to `list_workflow_runs_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-workflow-runs-for-a-repository>
+     */
+    pub async fn list_workflow_runs_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        actor: &str,
+        branch: &str,
+        event: &str,
+        status: crate::types::WorkflowRunStatus,
+        per_page: i64,
+        page: i64,
+        created: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !actor.is_empty() {
+            query_args.push(("actor".to_string(), actor.to_string()));
+        }
+        if !branch.is_empty() {
+            query_args.push(("branch".to_string(), branch.to_string()));
+        }
+        if !created.is_empty() {
+            query_args.push(("created".to_string(), created.to_string()));
+        }
+        if !event.is_empty() {
+            query_args.push(("event".to_string(), event.to_string()));
+        }
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if per_page > 0 {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
@@ -2428,7 +3640,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2458,6 +3670,26 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<crate::types::WorkflowRun> {
+        Ok(self
+            .get_workflow_run_with_response(owner, repo, run_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}` endpoint.
+     *
+     * As opposed to `get_workflow_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-workflow-run>
+     */
+    pub async fn get_workflow_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WorkflowRun>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}",
@@ -2468,7 +3700,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2500,6 +3732,25 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<()> {
+        self.delete_workflow_run_with_response(owner, repo, run_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a workflow run.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/actions/runs/{run_id}` endpoint.
+     *
+     * As opposed to `delete_workflow_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-a-workflow-run>
+     */
+    pub async fn delete_workflow_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}",
@@ -2510,7 +3761,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2540,6 +3791,26 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<Vec<crate::types::EnvironmentApproval>> {
+        Ok(self
+            .get_reviews_for_run_with_response(owner, repo, run_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get the review history for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals` endpoint.
+     *
+     * As opposed to `get_reviews_for_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-the-review-history-for-a-workflow-run>
+     */
+    pub async fn get_reviews_for_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::EnvironmentApproval>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/approvals",
@@ -2550,7 +3821,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2595,6 +3866,88 @@ impl Actions {
             )
             .await
     }
+    /**
+     * Get the review history for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals` endpoint.
+     *
+     * As opposed to `get_all_reviews_for_run`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-the-review-history-for-a-workflow-run>
+     */
+    pub fn get_all_reviews_for_run_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::EnvironmentApproval>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/approvals",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::EnvironmentApproval>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the review history for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals` endpoint.
+     *
+     * As opposed to `get_all_reviews_for_run`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-the-review-history-for-a-workflow-run>
+     */
+    pub fn get_all_reviews_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EnvironmentApproval>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_reviews_for_run_pages_stream(owner, repo, run_id, None),
+        )
+    }
     /**
      * Approve a workflow run for a fork pull request.
      *
@@ -2618,6 +3971,25 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<()> {
+        self.approve_workflow_run_with_response(owner, repo, run_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Approve a workflow run for a fork pull request.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approve` endpoint.
+     *
+     * As opposed to `approve_workflow_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#approve-a-workflow-run-for-a-fork-pull-request>
+     */
+    pub async fn approve_workflow_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/approve",
@@ -2628,7 +4000,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2662,6 +4034,28 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListArtifactsRepoResponse> {
+        Ok(self
+            .list_workflow_run_artifacts_with_response(owner, repo, run_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List workflow run artifacts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts` endpoint.
+     *
+     * As opposed to `list_workflow_run_artifacts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-workflow-run-artifacts>
+     */
+    pub async fn list_workflow_run_artifacts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListArtifactsRepoResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2681,7 +4075,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2711,6 +4105,25 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<()> {
+        self.cancel_workflow_run_with_response(owner, repo, run_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Cancel a workflow run.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/cancel` endpoint.
+     *
+     * As opposed to `cancel_workflow_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#cancel-a-workflow-run>
+     */
+    pub async fn cancel_workflow_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/cancel",
@@ -2721,7 +4134,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2759,6 +4172,29 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListJobsWorkflowRunResponse> {
+        Ok(self
+            .list_jobs_for_workflow_run_with_response(owner, repo, run_id, filter, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List jobs for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/jobs` endpoint.
+     *
+     * As opposed to `list_jobs_for_workflow_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-jobs-for-a-workflow-run>
+     */
+    pub async fn list_jobs_for_workflow_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        filter: crate::types::ActionsListJobsWorkflowRunFilter,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListJobsWorkflowRunResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.to_string().is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -2781,7 +4217,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2814,6 +4250,25 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<()> {
+        self.download_workflow_run_logs_with_response(owner, repo, run_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Download workflow run logs.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/logs` endpoint.
+     *
+     * As opposed to `download_workflow_run_logs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#download-workflow-run-logs>
+     */
+    pub async fn download_workflow_run_logs_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/logs",
@@ -2824,7 +4279,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2854,6 +4309,25 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<()> {
+        self.delete_workflow_run_logs_with_response(owner, repo, run_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete workflow run logs.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/logs` endpoint.
+     *
+     * As opposed to `delete_workflow_run_logs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-workflow-run-logs>
+     */
+    pub async fn delete_workflow_run_logs_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/logs",
@@ -2864,7 +4338,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2896,6 +4370,26 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<Vec<crate::types::PendingDeployment>> {
+        Ok(self
+            .get_pending_deployments_for_run_with_response(owner, repo, run_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get pending deployments for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `get_pending_deployments_for_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-pending-deployments-for-a-workflow-run>
+     */
+    pub async fn get_pending_deployments_for_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PendingDeployment>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/pending_deployments",
@@ -2906,7 +4400,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2953,6 +4447,87 @@ impl Actions {
             )
             .await
     }
+    /**
+     * Get pending deployments for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `get_all_pending_deployments_for_run`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-pending-deployments-for-a-workflow-run>
+     */
+    pub fn get_all_pending_deployments_for_run_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PendingDeployment>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/pending_deployments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&run_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PendingDeployment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get pending deployments for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `get_all_pending_deployments_for_run`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-pending-deployments-for-a-workflow-run>
+     */
+    pub fn get_all_pending_deployments_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PendingDeployment>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_pending_deployments_for_run_pages_stream(owner, repo, run_id, None),
+        )
+    }
     /**
      * Review pending deployments for a workflow run.
      *
@@ -2977,6 +4552,27 @@ impl Actions {
         run_id: i64,
         body: &crate::types::ActionsReviewPendingDeploymentsRunRequest,
     ) -> ClientResult<Vec<crate::types::Deployment>> {
+        Ok(self
+            .review_pending_deployments_for_run_with_response(owner, repo, run_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Review pending deployments for a workflow run.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `review_pending_deployments_for_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#review-pending-deployments-for-a-workflow-run>
+     */
+    pub async fn review_pending_deployments_for_run_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        body: &crate::types::ActionsReviewPendingDeploymentsRunRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Deployment>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/pending_deployments",
@@ -2987,7 +4583,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3012,6 +4608,25 @@ impl Actions {
      * * `run_id: i64` -- The id of the workflow run.
      */
     pub async fn re_run_workflow(&self, owner: &str, repo: &str, run_id: i64) -> ClientResult<()> {
+        self.re_run_workflow_with_response(owner, repo, run_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Re-run a workflow.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/rerun` endpoint.
+     *
+     * As opposed to `re_run_workflow`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#re-run-a-workflow>
+     */
+    pub async fn re_run_workflow_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/rerun",
@@ -3022,7 +4637,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3036,22 +4651,41 @@ impl Actions {
      *
      * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/rerun-failed-jobs` endpoint.
      *
-     * Re-run all of the failed jobs and their dependent jobs in a workflow run using the `id` of the workflow run. You must authenticate using an access token with the `repo` scope to use this endpoint.
+     * Re-run all of the failed jobs and their dependent jobs in a workflow run using the `id` of the workflow run. You must authenticate using an access token with the `repo` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#re-run-workflow-failed-jobs>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `run_id: i64` -- The id of the workflow run.
+     */
+    pub async fn re_run_workflow_failed_jobs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<()> {
+        self.re_run_workflow_failed_jobs_with_response(owner, repo, run_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Re-run failed jobs from a workflow run.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/rerun-failed-jobs` endpoint.
+     *
+     * As opposed to `re_run_workflow_failed_jobs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      *
      * FROM: <https://docs.github.com/rest/reference/actions#re-run-workflow-failed-jobs>
-     *
-     * **Parameters:**
-     *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `run_id: i64` -- The id of the workflow run.
      */
-    pub async fn re_run_workflow_failed_jobs(
+    pub async fn re_run_workflow_failed_jobs_with_response(
         &self,
         owner: &str,
         repo: &str,
         run_id: i64,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/rerun-failed-jobs",
@@ -3062,7 +4696,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3094,6 +4728,26 @@ impl Actions {
         repo: &str,
         run_id: i64,
     ) -> ClientResult<crate::types::WorkflowRunUsage> {
+        Ok(self
+            .get_workflow_run_usage_with_response(owner, repo, run_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get workflow run usage.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/timing` endpoint.
+     *
+     * As opposed to `get_workflow_run_usage`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-workflow-run-usage>
+     */
+    pub async fn get_workflow_run_usage_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WorkflowRunUsage>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/runs/{}/timing",
@@ -3104,7 +4758,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3136,6 +4790,27 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListRepoSecretsResponse> {
+        Ok(self
+            .list_repo_secrets_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository secrets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/secrets` endpoint.
+     *
+     * As opposed to `list_repo_secrets`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-repository-secrets>
+     */
+    pub async fn list_repo_secrets_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3154,7 +4829,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3182,6 +4857,25 @@ impl Actions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::ActionsPublicKey> {
+        Ok(self
+            .get_repo_public_key_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get a repository public key.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/secrets/public-key` endpoint.
+     *
+     * As opposed to `get_repo_public_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-repository-public-key>
+     */
+    pub async fn get_repo_public_key_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsPublicKey>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/secrets/public-key",
@@ -3191,7 +4885,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3221,6 +4915,26 @@ impl Actions {
         repo: &str,
         secret_name: &str,
     ) -> ClientResult<crate::types::ActionsSecret> {
+        Ok(self
+            .get_repo_secret_with_response(owner, repo, secret_name)
+            .await?
+            .body)
+    }
+    /**
+     * Get a repository secret.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `get_repo_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-repository-secret>
+     */
+    pub async fn get_repo_secret_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsSecret>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/secrets/{}",
@@ -3231,7 +4945,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3336,6 +5050,26 @@ impl Actions {
         secret_name: &str,
         body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
     ) -> ClientResult<()> {
+        self.create_or_update_repo_secret_with_response(owner, repo, secret_name, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Create or update a repository secret.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/actions/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `create_or_update_repo_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-or-update-a-repository-secret>
+     */
+    pub async fn create_or_update_repo_secret_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/secrets/{}",
@@ -3346,7 +5080,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3376,6 +5110,25 @@ impl Actions {
         repo: &str,
         secret_name: &str,
     ) -> ClientResult<()> {
+        self.delete_repo_secret_with_response(owner, repo, secret_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a repository secret.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/actions/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `delete_repo_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-a-repository-secret>
+     */
+    pub async fn delete_repo_secret_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/secrets/{}",
@@ -3386,7 +5139,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3418,6 +5171,27 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListRepoWorkflowsResponse> {
+        Ok(self
+            .list_repo_workflows_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository workflows.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/workflows` endpoint.
+     *
+     * As opposed to `list_repo_workflows`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-repository-workflows>
+     */
+    pub async fn list_repo_workflows_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoWorkflowsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3436,7 +5210,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3466,6 +5240,26 @@ impl Actions {
         repo: &str,
         workflow_id: &str,
     ) -> ClientResult<crate::types::Workflow> {
+        Ok(self
+            .get_workflow_with_response(owner, repo, workflow_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a workflow.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/workflows/{workflow_id}` endpoint.
+     *
+     * As opposed to `get_workflow`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-a-workflow>
+     */
+    pub async fn get_workflow_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Workflow>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/workflows/{}",
@@ -3476,7 +5270,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3508,6 +5302,25 @@ impl Actions {
         repo: &str,
         workflow_id: &str,
     ) -> ClientResult<()> {
+        self.disable_workflow_with_response(owner, repo, workflow_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Disable a workflow.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/disable` endpoint.
+     *
+     * As opposed to `disable_workflow`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#disable-a-workflow>
+     */
+    pub async fn disable_workflow_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/workflows/{}/disable",
@@ -3518,7 +5331,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3553,6 +5366,26 @@ impl Actions {
         workflow_id: &str,
         body: &crate::types::ActionsCreateWorkflowDispatchRequest,
     ) -> ClientResult<()> {
+        self.create_workflow_dispatch_with_response(owner, repo, workflow_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Create a workflow dispatch event.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches` endpoint.
+     *
+     * As opposed to `create_workflow_dispatch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-a-workflow-dispatch-event>
+     */
+    pub async fn create_workflow_dispatch_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        body: &crate::types::ActionsCreateWorkflowDispatchRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/workflows/{}/dispatches",
@@ -3563,7 +5396,7 @@ impl Actions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3595,6 +5428,25 @@ impl Actions {
         repo: &str,
         workflow_id: &str,
     ) -> ClientResult<()> {
+        self.enable_workflow_with_response(owner, repo, workflow_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Enable a workflow.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/enable` endpoint.
+     *
+     * As opposed to `enable_workflow`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#enable-a-workflow>
+     */
+    pub async fn enable_workflow_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/workflows/{}/enable",
@@ -3605,7 +5457,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3651,6 +5503,44 @@ impl Actions {
         page: i64,
         created: &str,
     ) -> ClientResult<crate::types::ActionsListWorkflowRunsResponse> {
+        Ok(self
+            .list_workflow_runs_with_response(
+                owner,
+                repo,
+                workflow_id,
+                actor,
+                branch,
+                event,
+                status,
+                per_page,
+                page,
+                created,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List workflow runs.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs` endpoint.
+     *
+     * As opposed to `list_workflow_runs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-workflow-runs>
+     */
+    pub async fn list_workflow_runs_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        actor: &str,
+        branch: &str,
+        event: &str,
+        status: crate::types::WorkflowRunStatus,
+        per_page: i64,
+        page: i64,
+        created: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListWorkflowRunsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !actor.is_empty() {
             query_args.push(("actor".to_string(), actor.to_string()));
@@ -3685,7 +5575,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3717,6 +5607,26 @@ impl Actions {
         repo: &str,
         workflow_id: &str,
     ) -> ClientResult<crate::types::WorkflowUsage> {
+        Ok(self
+            .get_workflow_usage_with_response(owner, repo, workflow_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get workflow usage.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/workflows/{workflow_id}/timing` endpoint.
+     *
+     * As opposed to `get_workflow_usage`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-workflow-usage>
+     */
+    pub async fn get_workflow_usage_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WorkflowUsage>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/actions/workflows/{}/timing",
@@ -3727,7 +5637,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3759,6 +5669,27 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListRepoSecretsResponse> {
+        Ok(self
+            .list_environment_secrets_with_response(repository_id, environment_name, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List environment secrets.
+     *
+     * This function performs a `GET` to the `/repositories/{repository_id}/environments/{environment_name}/secrets` endpoint.
+     *
+     * As opposed to `list_environment_secrets`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-environment-secrets>
+     */
+    pub async fn list_environment_secrets_with_response(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListRepoSecretsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3777,7 +5708,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3805,6 +5736,25 @@ impl Actions {
         repository_id: i64,
         environment_name: &str,
     ) -> ClientResult<crate::types::ActionsPublicKey> {
+        Ok(self
+            .get_environment_public_key_with_response(repository_id, environment_name)
+            .await?
+            .body)
+    }
+    /**
+     * Get an environment public key.
+     *
+     * This function performs a `GET` to the `/repositories/{repository_id}/environments/{environment_name}/secrets/public-key` endpoint.
+     *
+     * As opposed to `get_environment_public_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-an-environment-public-key>
+     */
+    pub async fn get_environment_public_key_with_response(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsPublicKey>> {
         let url = self.client.url(
             &format!(
                 "/repositories/{}/environments/{}/secrets/public-key",
@@ -3814,7 +5764,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3844,6 +5794,26 @@ impl Actions {
         environment_name: &str,
         secret_name: &str,
     ) -> ClientResult<crate::types::ActionsSecret> {
+        Ok(self
+            .get_environment_secret_with_response(repository_id, environment_name, secret_name)
+            .await?
+            .body)
+    }
+    /**
+     * Get an environment secret.
+     *
+     * This function performs a `GET` to the `/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `get_environment_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-an-environment-secret>
+     */
+    pub async fn get_environment_secret_with_response(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsSecret>> {
         let url = self.client.url(
             &format!(
                 "/repositories/{}/environments/{}/secrets/{}",
@@ -3854,7 +5824,7 @@ impl Actions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3959,6 +5929,31 @@ impl Actions {
         secret_name: &str,
         body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
     ) -> ClientResult<()> {
+        self.create_or_update_environment_secret_with_response(
+            repository_id,
+            environment_name,
+            secret_name,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Create or update an environment secret.
+     *
+     * This function performs a `PUT` to the `/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `create_or_update_environment_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#create-or-update-an-environment-secret>
+     */
+    pub async fn create_or_update_environment_secret_with_response(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repositories/{}/environments/{}/secrets/{}",
@@ -3969,7 +5964,7 @@ impl Actions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3999,6 +5994,25 @@ impl Actions {
         environment_name: &str,
         secret_name: &str,
     ) -> ClientResult<()> {
+        self.delete_environment_secret_with_response(repository_id, environment_name, secret_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an environment secret.
+     *
+     * This function performs a `DELETE` to the `/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}` endpoint.
+     *
+     * As opposed to `delete_environment_secret`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#delete-an-environment-secret>
+     */
+    pub async fn delete_environment_secret_with_response(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repositories/{}/environments/{}/secrets/{}",
@@ -4009,7 +6023,7 @@ impl Actions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -30,6 +30,25 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_public_events_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_public_events`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub async fn list_public_events_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -40,7 +59,7 @@ impl Activity {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/events?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,6 +91,65 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_all_public_events`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub fn list_all_public_events_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url("/events", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_all_public_events`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub fn list_all_public_events_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_events_pages_stream(None))
+    }
     /**
      * Get feeds.
      *
@@ -92,9 +170,23 @@ impl Activity {
      * FROM: <https://docs.github.com/rest/reference/activity#get-feeds>
      */
     pub async fn get_feeds(&self) -> ClientResult<crate::types::Feed> {
+        Ok(self.get_feeds_with_response().await?.body)
+    }
+    /**
+     * Get feeds.
+     *
+     * This function performs a `GET` to the `/feeds` endpoint.
+     *
+     * As opposed to `get_feeds`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#get-feeds>
+     */
+    pub async fn get_feeds_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::Feed>> {
         let url = self.client.url("/feeds", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -126,6 +218,27 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_public_events_for_repo_network_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_public_events_for_repo_network`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub async fn list_public_events_for_repo_network_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -144,7 +257,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -187,6 +300,79 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_repo_network`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub fn list_all_public_events_for_repo_network_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/networks/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_repo_network`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub fn list_all_public_events_for_repo_network_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_public_events_for_repo_network_pages_stream(owner, repo, None),
+        )
+    }
     /**
      * List notifications for the authenticated user.
      *
@@ -214,6 +400,36 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Thread>> {
+        Ok(self
+            .list_notifications_for_authenticated_user_with_response(
+                all,
+                participating,
+                since,
+                before,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_notifications_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     */
+    pub async fn list_notifications_for_authenticated_user_with_response(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if all {
             query_args.push(("all".to_string(), all.to_string()));
@@ -236,7 +452,7 @@ impl Activity {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/notifications?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -288,6 +504,96 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_all_notifications_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_notifications_for_authenticated_user_pages_stream(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Thread>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if all {
+            query_args.push(("all".to_string(), all.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if participating {
+            query_args.push(("participating".to_string(), participating.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/notifications?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Thread>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_all_notifications_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_notifications_for_authenticated_user_stream(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Thread>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_notifications_for_authenticated_user_pages_stream(
+                all,
+                participating,
+                since,
+                before,
+                None,
+            ),
+        )
+    }
     /**
      * Mark notifications as read.
      *
@@ -301,9 +607,27 @@ impl Activity {
         &self,
         body: &crate::types::ActivityMarkNotificationsAsReadRequest,
     ) -> ClientResult<crate::types::Error> {
+        Ok(self
+            .mark_notifications_as_read_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Mark notifications as read.
+     *
+     * This function performs a `PUT` to the `/notifications` endpoint.
+     *
+     * As opposed to `mark_notifications_as_read`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#mark-notifications-as-read>
+     */
+    pub async fn mark_notifications_as_read_with_response(
+        &self,
+        body: &crate::types::ActivityMarkNotificationsAsReadRequest,
+    ) -> ClientResult<crate::Response<crate::types::Error>> {
         let url = self.client.url("/notifications", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -326,6 +650,21 @@ impl Activity {
      * * `thread_id: i64` -- thread_id parameter.
      */
     pub async fn get_thread(&self, thread_id: i64) -> ClientResult<crate::types::Thread> {
+        Ok(self.get_thread_with_response(thread_id).await?.body)
+    }
+    /**
+     * Get a thread.
+     *
+     * This function performs a `GET` to the `/notifications/threads/{thread_id}` endpoint.
+     *
+     * As opposed to `get_thread`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#get-a-thread>
+     */
+    pub async fn get_thread_with_response(
+        &self,
+        thread_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Thread>> {
         let url = self.client.url(
             &format!(
                 "/notifications/threads/{}",
@@ -334,7 +673,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -357,6 +696,22 @@ impl Activity {
      * * `thread_id: i64` -- thread_id parameter.
      */
     pub async fn mark_thread_as_read(&self, thread_id: i64) -> ClientResult<()> {
+        self.mark_thread_as_read_with_response(thread_id).await?;
+        Ok(())
+    }
+    /**
+     * Mark a thread as read.
+     *
+     * This function performs a `PATCH` to the `/notifications/threads/{thread_id}` endpoint.
+     *
+     * As opposed to `mark_thread_as_read`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#mark-a-thread-as-read>
+     */
+    pub async fn mark_thread_as_read_with_response(
+        &self,
+        thread_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/notifications/threads/{}",
@@ -365,7 +720,7 @@ impl Activity {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -393,6 +748,24 @@ impl Activity {
         &self,
         thread_id: i64,
     ) -> ClientResult<crate::types::ThreadSubscription> {
+        Ok(self
+            .get_thread_subscription_for_authenticated_user_with_response(thread_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a thread subscription for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications/threads/{thread_id}/subscription` endpoint.
+     *
+     * As opposed to `get_thread_subscription_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#get-a-thread-subscription-for-the-authenticated-user>
+     */
+    pub async fn get_thread_subscription_for_authenticated_user_with_response(
+        &self,
+        thread_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ThreadSubscription>> {
         let url = self.client.url(
             &format!(
                 "/notifications/threads/{}/subscription",
@@ -401,7 +774,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -432,6 +805,25 @@ impl Activity {
         thread_id: i64,
         body: &crate::types::ActivitySetThreadSubscriptionRequest,
     ) -> ClientResult<crate::types::ThreadSubscription> {
+        Ok(self
+            .set_thread_subscription_with_response(thread_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set a thread subscription.
+     *
+     * This function performs a `PUT` to the `/notifications/threads/{thread_id}/subscription` endpoint.
+     *
+     * As opposed to `set_thread_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#set-a-thread-subscription>
+     */
+    pub async fn set_thread_subscription_with_response(
+        &self,
+        thread_id: i64,
+        body: &crate::types::ActivitySetThreadSubscriptionRequest,
+    ) -> ClientResult<crate::Response<crate::types::ThreadSubscription>> {
         let url = self.client.url(
             &format!(
                 "/notifications/threads/{}/subscription",
@@ -440,7 +832,7 @@ impl Activity {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -463,6 +855,23 @@ impl Activity {
      * * `thread_id: i64` -- thread_id parameter.
      */
     pub async fn delete_thread_subscription(&self, thread_id: i64) -> ClientResult<()> {
+        self.delete_thread_subscription_with_response(thread_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a thread subscription.
+     *
+     * This function performs a `DELETE` to the `/notifications/threads/{thread_id}/subscription` endpoint.
+     *
+     * As opposed to `delete_thread_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#delete-a-thread-subscription>
+     */
+    pub async fn delete_thread_subscription_with_response(
+        &self,
+        thread_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/notifications/threads/{}/subscription",
@@ -471,7 +880,7 @@ impl Activity {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -501,6 +910,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_public_org_events_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public organization events.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
+     *
+     * As opposed to `list_public_org_events`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
+     */
+    pub async fn list_public_org_events_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -518,7 +947,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -560,28 +989,117 @@ impl Activity {
             .await
     }
     /**
-     * List repository events.
-     *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
-     *
-     *
+     * List public organization events.
      *
-     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_public_org_events`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
      */
-    pub async fn list_repo_events(
+    pub fn list_all_public_org_events_pages_stream(
         &self,
-        owner: &str,
-        repo: &str,
-        per_page: i64,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/events",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public organization events.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
+     *
+     * As opposed to `list_all_public_org_events`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
+     */
+    pub fn list_all_public_org_events_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_org_events_pages_stream(org, None))
+    }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `per_page: i64` -- Results per page (max 100).
+     * * `page: i64` -- Page number of the results to fetch.
+     */
+    pub async fn list_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_repo_events_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_repo_events`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub async fn list_repo_events_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -600,7 +1118,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -643,6 +1161,77 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_repo_events`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub fn list_all_repo_events_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_repo_events`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub fn list_all_repo_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_repo_events_pages_stream(owner, repo, None))
+    }
     /**
      * List repository notifications for the authenticated user.
      *
@@ -674,6 +1263,40 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Thread>> {
+        Ok(self
+            .list_repo_notifications_for_authenticated_user_with_response(
+                owner,
+                repo,
+                all,
+                participating,
+                since,
+                before,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_repo_notifications_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub async fn list_repo_notifications_for_authenticated_user_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Thread>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if all {
             query_args.push(("all".to_string(), all.to_string()));
@@ -704,7 +1327,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -766,6 +1389,110 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_all_repo_notifications_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_repo_notifications_for_authenticated_user_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Thread>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if all {
+            query_args.push(("all".to_string(), all.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if participating {
+            query_args.push(("participating".to_string(), participating.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/notifications?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Thread>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_all_repo_notifications_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub fn list_all_repo_notifications_for_authenticated_user_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Thread>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_repo_notifications_for_authenticated_user_pages_stream(
+                owner,
+                repo,
+                all,
+                participating,
+                since,
+                before,
+                None,
+            ),
+        )
+    }
     /**
      * Mark repository notifications as read.
      *
@@ -786,6 +1513,26 @@ impl Activity {
         repo: &str,
         body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
     ) -> ClientResult<crate::types::PullsUpdateBranchResponse> {
+        Ok(self
+            .mark_repo_notifications_as_read_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Mark repository notifications as read.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `mark_repo_notifications_as_read`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#mark-repository-notifications-as-read>
+     */
+    pub async fn mark_repo_notifications_as_read_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullsUpdateBranchResponse>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/notifications",
@@ -795,7 +1542,7 @@ impl Activity {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -829,6 +1576,27 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActivityListStargazersRepoResponseAnyOf> {
+        Ok(self
+            .list_stargazers_for_repo_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List stargazers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stargazers` endpoint.
+     *
+     * As opposed to `list_stargazers_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-stargazers>
+     */
+    pub async fn list_stargazers_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListStargazersRepoResponseAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -847,7 +1615,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -879,6 +1647,27 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_watchers_for_repo_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_watchers_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub async fn list_watchers_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -897,7 +1686,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -940,6 +1729,81 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_all_watchers_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub fn list_all_watchers_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/subscribers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_all_watchers_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub fn list_all_watchers_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_watchers_for_repo_pages_stream(owner, repo, None))
+    }
     /**
      * Get a repository subscription.
      *
@@ -959,6 +1823,25 @@ impl Activity {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::RepositorySubscription> {
+        Ok(self
+            .get_repo_subscription_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get a repository subscription.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscription` endpoint.
+     *
+     * As opposed to `get_repo_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#get-a-repository-subscription>
+     */
+    pub async fn get_repo_subscription_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::RepositorySubscription>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/subscription",
@@ -968,7 +1851,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -997,6 +1880,26 @@ impl Activity {
         repo: &str,
         body: &crate::types::ActivitySetRepoSubscriptionRequest,
     ) -> ClientResult<crate::types::RepositorySubscription> {
+        Ok(self
+            .set_repo_subscription_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set a repository subscription.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/subscription` endpoint.
+     *
+     * As opposed to `set_repo_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#set-a-repository-subscription>
+     */
+    pub async fn set_repo_subscription_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivitySetRepoSubscriptionRequest,
+    ) -> ClientResult<crate::Response<crate::types::RepositorySubscription>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/subscription",
@@ -1006,7 +1909,7 @@ impl Activity {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1030,6 +1933,24 @@ impl Activity {
      * * `repo: &str`
      */
     pub async fn delete_repo_subscription(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.delete_repo_subscription_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a repository subscription.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/subscription` endpoint.
+     *
+     * As opposed to `delete_repo_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#delete-a-repository-subscription>
+     */
+    pub async fn delete_repo_subscription_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/subscription",
@@ -1039,7 +1960,7 @@ impl Activity {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1075,6 +1996,27 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Repository>> {
+        Ok(self
+            .list_repos_starred_by_authenticated_user_with_response(sort, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_repos_starred_by_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub async fn list_repos_starred_by_authenticated_user_with_response(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1091,7 +2033,7 @@ impl Activity {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/starred?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1137,6 +2079,84 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_all_repos_starred_by_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub fn list_all_repos_starred_by_authenticated_user_pages_stream(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Repository>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/starred?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Repository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_all_repos_starred_by_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub fn list_all_repos_starred_by_authenticated_user_stream(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Repository>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_repos_starred_by_authenticated_user_pages_stream(sort, direction, None),
+        )
+    }
     /**
      * Check if a repository is starred by the authenticated user.
      *
@@ -1156,6 +2176,24 @@ impl Activity {
         owner: &str,
         repo: &str,
     ) -> ClientResult<()> {
+        self.check_repo_is_starred_by_authenticated_user_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check if a repository is starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `check_repo_is_starred_by_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#check-if-a-repository-is-starred-by-the-authenticated-user>
+     */
+    pub async fn check_repo_is_starred_by_authenticated_user_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/starred/{}/{}",
@@ -1165,7 +2203,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1193,6 +2231,24 @@ impl Activity {
         owner: &str,
         repo: &str,
     ) -> ClientResult<()> {
+        self.star_repo_for_authenticated_user_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Star a repository for the authenticated user.
+     *
+     * This function performs a `PUT` to the `/user/starred/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `star_repo_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#star-a-repository-for-the-authenticated-user>
+     */
+    pub async fn star_repo_for_authenticated_user_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/starred/{}/{}",
@@ -1202,7 +2258,7 @@ impl Activity {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1230,6 +2286,24 @@ impl Activity {
         owner: &str,
         repo: &str,
     ) -> ClientResult<()> {
+        self.unstar_repo_for_authenticated_user_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unstar a repository for the authenticated user.
+     *
+     * This function performs a `DELETE` to the `/user/starred/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `unstar_repo_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#unstar-a-repository-for-the-authenticated-user>
+     */
+    pub async fn unstar_repo_for_authenticated_user_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/starred/{}/{}",
@@ -1239,7 +2313,7 @@ impl Activity {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1267,6 +2341,25 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_watched_repos_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_watched_repos_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub async fn list_watched_repos_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1279,7 +2372,7 @@ impl Activity {
             .client
             .url(&format!("/user/subscriptions?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1313,6 +2406,72 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_watched_repos_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub fn list_all_watched_repos_for_authenticated_user_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/user/subscriptions", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_watched_repos_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub fn list_all_watched_repos_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_watched_repos_for_authenticated_user_pages_stream(None),
+        )
+    }
     /**
      * List events for the authenticated user.
      *
@@ -1334,6 +2493,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_events_for_authenticated_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_events_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub async fn list_events_for_authenticated_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1351,7 +2530,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1392,6 +2571,76 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub fn list_all_events_for_authenticated_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub fn list_all_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_events_for_authenticated_user_pages_stream(username, None),
+        )
+    }
     /**
      * List organization events for the authenticated user.
      *
@@ -1415,6 +2664,27 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_org_events_for_authenticated_user_with_response(username, org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_org_events_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub async fn list_org_events_for_authenticated_user_with_response(
+        &self,
+        username: &str,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1433,7 +2703,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1476,6 +2746,79 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_all_org_events_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub fn list_all_org_events_for_authenticated_user_pages_stream(
+        &self,
+        username: &str,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events/orgs/{}",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_all_org_events_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub fn list_all_org_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_org_events_for_authenticated_user_pages_stream(username, org, None),
+        )
+    }
     /**
      * List public events for a user.
      *
@@ -1497,6 +2840,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_public_events_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_public_events_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub async fn list_public_events_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1514,7 +2877,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1555,6 +2918,74 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub fn list_all_public_events_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub fn list_all_public_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_events_for_user_pages_stream(username, None))
+    }
     /**
      * List events received by the authenticated user.
      *
@@ -1576,6 +3007,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_received_events_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List events received by the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
+     *
+     * As opposed to `list_received_events_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
+     */
+    pub async fn list_received_events_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1593,7 +3044,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1634,6 +3085,76 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List events received by the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
+     *
+     * As opposed to `list_all_received_events_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
+     */
+    pub fn list_all_received_events_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/received_events",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List events received by the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
+     *
+     * As opposed to `list_all_received_events_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
+     */
+    pub fn list_all_received_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_received_events_for_user_pages_stream(username, None),
+        )
+    }
     /**
      * List public events received by a user.
      *
@@ -1655,6 +3176,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_received_public_events_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_received_public_events_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub async fn list_received_public_events_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1672,7 +3213,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1713,6 +3254,76 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_all_received_public_events_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub fn list_all_received_public_events_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/received_events/public",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Event>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_all_received_public_events_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub fn list_all_received_public_events_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_received_public_events_for_user_pages_stream(username, None),
+        )
+    }
     /**
      * List repositories starred by a user.
      *
@@ -1742,6 +3353,29 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
+        Ok(self
+            .list_repos_starred_by_user_with_response(username, sort, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories starred by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/starred` endpoint.
+     *
+     * As opposed to `list_repos_starred_by_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-a-user>
+     */
+    pub async fn list_repos_starred_by_user_with_response(
+        &self,
+        username: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActivityListReposStarredByUserResponseAnyOf>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1765,7 +3399,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1795,6 +3429,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_repos_watched_by_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_repos_watched_by_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub async fn list_repos_watched_by_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1812,7 +3466,7 @@ impl Activity {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1853,4 +3507,77 @@ impl Activity {
             )
             .await
     }
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_repos_watched_by_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub fn list_all_repos_watched_by_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/subscriptions",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_repos_watched_by_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub fn list_all_repos_watched_by_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_repos_watched_by_user_pages_stream(username, None))
+    }
 }
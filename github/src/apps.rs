@@ -23,9 +23,23 @@ impl Apps {
      * FROM: <https://docs.github.com/rest/reference/apps#get-the-authenticated-app>
      */
     pub async fn get_authenticated(&self) -> ClientResult<crate::types::GitHubApp> {
+        Ok(self.get_authenticated_with_response().await?.body)
+    }
+    /**
+     * Get the authenticated app.
+     *
+     * This function performs a `GET` to the `/app` endpoint.
+     *
+     * As opposed to `get_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-the-authenticated-app>
+     */
+    pub async fn get_authenticated_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GitHubApp>> {
         let url = self.client.url("/app", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,6 +65,21 @@ impl Apps {
         &self,
         code: &str,
     ) -> ClientResult<crate::types::AppsCreateFromManifestResponseAllOf> {
+        Ok(self.create_from_manifest_with_response(code).await?.body)
+    }
+    /**
+     * Create a GitHub App from a manifest.
+     *
+     * This function performs a `POST` to the `/app-manifests/{code}/conversions` endpoint.
+     *
+     * As opposed to `create_from_manifest`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#create-a-github-app-from-a-manifest>
+     */
+    pub async fn create_from_manifest_with_response(
+        &self,
+        code: &str,
+    ) -> ClientResult<crate::Response<crate::types::AppsCreateFromManifestResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/app-manifests/{}/conversions",
@@ -59,7 +88,7 @@ impl Apps {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -80,9 +109,23 @@ impl Apps {
      * FROM: <https://docs.github.com/rest/reference/apps#get-a-webhook-configuration-for-an-app>
      */
     pub async fn get_webhook_config_for_app(&self) -> ClientResult<crate::types::WebhookConfig> {
+        Ok(self.get_webhook_config_for_app_with_response().await?.body)
+    }
+    /**
+     * Get a webhook configuration for an app.
+     *
+     * This function performs a `GET` to the `/app/hook/config` endpoint.
+     *
+     * As opposed to `get_webhook_config_for_app`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-a-webhook-configuration-for-an-app>
+     */
+    pub async fn get_webhook_config_for_app_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
         let url = self.client.url("/app/hook/config", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -106,9 +149,27 @@ impl Apps {
         &self,
         body: &crate::types::AppsUpdateWebhookConfigAppRequest,
     ) -> ClientResult<crate::types::WebhookConfig> {
+        Ok(self
+            .update_webhook_config_for_app_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a webhook configuration for an app.
+     *
+     * This function performs a `PATCH` to the `/app/hook/config` endpoint.
+     *
+     * As opposed to `update_webhook_config_for_app`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#update-a-webhook-configuration-for-an-app>
+     */
+    pub async fn update_webhook_config_for_app_with_response(
+        &self,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
         let url = self.client.url("/app/hook/config", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -138,6 +199,25 @@ impl Apps {
         per_page: i64,
         cursor: &str,
     ) -> ClientResult<Vec<crate::types::HookDeliveryItem>> {
+        Ok(self
+            .list_webhook_deliveries_with_response(per_page, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_webhook_deliveries`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub async fn list_webhook_deliveries_with_response(
+        &self,
+        per_page: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -150,7 +230,7 @@ impl Apps {
             .client
             .url(&format!("/app/hook/deliveries?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -194,6 +274,80 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub fn list_all_webhook_deliveries_pages_stream(
+        &self,
+        cursor: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::HookDeliveryItem>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/app/hook/deliveries?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::HookDeliveryItem>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_webhook_deliveries_pages_stream(cursor, None))
+    }
     /**
      * Get a delivery for an app webhook.
      *
@@ -213,6 +367,24 @@ impl Apps {
         &self,
         delivery_id: i64,
     ) -> ClientResult<crate::types::HookDelivery> {
+        Ok(self
+            .get_webhook_delivery_with_response(delivery_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a delivery for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries/{delivery_id}` endpoint.
+     *
+     * As opposed to `get_webhook_delivery`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-a-delivery-for-an-app-webhook>
+     */
+    pub async fn get_webhook_delivery_with_response(
+        &self,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::HookDelivery>> {
         let url = self.client.url(
             &format!(
                 "/app/hook/deliveries/{}",
@@ -221,7 +393,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -246,6 +418,23 @@ impl Apps {
      * * `delivery_id: i64`
      */
     pub async fn redeliver_webhook_delivery(&self, delivery_id: i64) -> ClientResult<()> {
+        self.redeliver_webhook_delivery_with_response(delivery_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Redeliver a delivery for an app webhook.
+     *
+     * This function performs a `POST` to the `/app/hook/deliveries/{delivery_id}/attempts` endpoint.
+     *
+     * As opposed to `redeliver_webhook_delivery`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#redeliver-a-delivery-for-an-app-webhook>
+     */
+    pub async fn redeliver_webhook_delivery_with_response(
+        &self,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/app/hook/deliveries/{}/attempts",
@@ -254,7 +443,7 @@ impl Apps {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -288,6 +477,27 @@ impl Apps {
         since: Option<chrono::DateTime<chrono::Utc>>,
         outdated: &str,
     ) -> ClientResult<Vec<crate::types::Installation>> {
+        Ok(self
+            .list_installations_with_response(per_page, page, since, outdated)
+            .await?
+            .body)
+    }
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_installations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub async fn list_installations_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Installation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !outdated.is_empty() {
             query_args.push(("outdated".to_string(), outdated.to_string()));
@@ -306,7 +516,7 @@ impl Apps {
             .client
             .url(&format!("/app/installations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -354,6 +564,84 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_all_installations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub fn list_all_installations_pages_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Installation>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !outdated.is_empty() {
+            query_args.push(("outdated".to_string(), outdated.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/app/installations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Installation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_all_installations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub fn list_all_installations_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Installation>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_installations_pages_stream(since, outdated, None))
+    }
     /**
      * Get an installation for the authenticated app.
      *
@@ -373,6 +661,24 @@ impl Apps {
         &self,
         installation_id: i64,
     ) -> ClientResult<crate::types::Installation> {
+        Ok(self
+            .get_installation_with_response(installation_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get an installation for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations/{installation_id}` endpoint.
+     *
+     * As opposed to `get_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-an-installation-for-the-authenticated-app>
+     */
+    pub async fn get_installation_with_response(
+        &self,
+        installation_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
         let url = self.client.url(
             &format!(
                 "/app/installations/{}",
@@ -381,7 +687,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -406,6 +712,23 @@ impl Apps {
      * * `installation_id: i64` -- installation_id parameter.
      */
     pub async fn delete_installation(&self, installation_id: i64) -> ClientResult<()> {
+        self.delete_installation_with_response(installation_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an installation for the authenticated app.
+     *
+     * This function performs a `DELETE` to the `/app/installations/{installation_id}` endpoint.
+     *
+     * As opposed to `delete_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#delete-an-installation-for-the-authenticated-app>
+     */
+    pub async fn delete_installation_with_response(
+        &self,
+        installation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/app/installations/{}",
@@ -414,7 +737,7 @@ impl Apps {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -444,6 +767,25 @@ impl Apps {
         installation_id: i64,
         body: &crate::types::AppsCreateInstallationAccessTokenRequest,
     ) -> ClientResult<crate::types::InstallationToken> {
+        Ok(self
+            .create_installation_access_token_with_response(installation_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an installation access token for an app.
+     *
+     * This function performs a `POST` to the `/app/installations/{installation_id}/access_tokens` endpoint.
+     *
+     * As opposed to `create_installation_access_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps/#create-an-installation-access-token-for-an-app>
+     */
+    pub async fn create_installation_access_token_with_response(
+        &self,
+        installation_id: i64,
+        body: &crate::types::AppsCreateInstallationAccessTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::InstallationToken>> {
         let url = self.client.url(
             &format!(
                 "/app/installations/{}/access_tokens",
@@ -479,6 +821,23 @@ impl Apps {
      * * `installation_id: i64` -- installation_id parameter.
      */
     pub async fn suspend_installation(&self, installation_id: i64) -> ClientResult<()> {
+        self.suspend_installation_with_response(installation_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Suspend an app installation.
+     *
+     * This function performs a `PUT` to the `/app/installations/{installation_id}/suspended` endpoint.
+     *
+     * As opposed to `suspend_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#suspend-an-app-installation>
+     */
+    pub async fn suspend_installation_with_response(
+        &self,
+        installation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/app/installations/{}/suspended",
@@ -487,7 +846,7 @@ impl Apps {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -512,6 +871,23 @@ impl Apps {
      * * `installation_id: i64` -- installation_id parameter.
      */
     pub async fn unsuspend_installation(&self, installation_id: i64) -> ClientResult<()> {
+        self.unsuspend_installation_with_response(installation_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unsuspend an app installation.
+     *
+     * This function performs a `DELETE` to the `/app/installations/{installation_id}/suspended` endpoint.
+     *
+     * As opposed to `unsuspend_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#unsuspend-an-app-installation>
+     */
+    pub async fn unsuspend_installation_with_response(
+        &self,
+        installation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/app/installations/{}/suspended",
@@ -520,7 +896,7 @@ impl Apps {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -548,6 +924,24 @@ impl Apps {
         client_id: &str,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> ClientResult<()> {
+        self.delete_authorization_with_response(client_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an app authorization.
+     *
+     * This function performs a `DELETE` to the `/applications/{client_id}/grant` endpoint.
+     *
+     * As opposed to `delete_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#delete-an-app-authorization>
+     */
+    pub async fn delete_authorization_with_response(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/grant",
@@ -556,7 +950,7 @@ impl Apps {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -588,6 +982,24 @@ impl Apps {
         client_id: &str,
         access_token: &str,
     ) -> ClientResult<()> {
+        self.revoke_grant_for_application_with_response(client_id, access_token)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Revoke a grant for an application.
+     *
+     * This function performs a `DELETE` to the `/applications/{client_id}/grants/{access_token}` endpoint.
+     *
+     * As opposed to `revoke_grant_for_application`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#revoke-a-grant-for-an-application>
+     */
+    pub async fn revoke_grant_for_application_with_response(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/grants/{}",
@@ -597,7 +1009,7 @@ impl Apps {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -624,6 +1036,22 @@ impl Apps {
         client_id: &str,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self.check_token_with_response(client_id, body).await?.body)
+    }
+    /**
+     * Check a token.
+     *
+     * This function performs a `POST` to the `/applications/{client_id}/token` endpoint.
+     *
+     * As opposed to `check_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#check-a-token>
+     */
+    pub async fn check_token_with_response(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/token",
@@ -632,7 +1060,7 @@ impl Apps {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -659,15 +1087,32 @@ impl Apps {
         client_id: &str,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/applications/{}/token",
+        self.delete_token_with_response(client_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Delete an app token.
+     *
+     * This function performs a `DELETE` to the `/applications/{client_id}/token` endpoint.
+     *
+     * As opposed to `delete_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#delete-an-app-token>
+     */
+    pub async fn delete_token_with_response(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url(
+            &format!(
+                "/applications/{}/token",
                 crate::progenitor_support::encode_path(client_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -694,6 +1139,22 @@ impl Apps {
         client_id: &str,
         body: &crate::types::AppsCheckTokenRequest,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self.reset_token_with_response(client_id, body).await?.body)
+    }
+    /**
+     * Reset a token.
+     *
+     * This function performs a `PATCH` to the `/applications/{client_id}/token` endpoint.
+     *
+     * As opposed to `reset_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#reset-a-token>
+     */
+    pub async fn reset_token_with_response(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/token",
@@ -702,7 +1163,7 @@ impl Apps {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -729,6 +1190,22 @@ impl Apps {
         client_id: &str,
         body: &crate::types::AppsScopeTokenRequest,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self.scope_token_with_response(client_id, body).await?.body)
+    }
+    /**
+     * Create a scoped access token.
+     *
+     * This function performs a `POST` to the `/applications/{client_id}/token/scoped` endpoint.
+     *
+     * As opposed to `scope_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#create-a-scoped-access-token>
+     */
+    pub async fn scope_token_with_response(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsScopeTokenRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/token/scoped",
@@ -737,7 +1214,7 @@ impl Apps {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -767,6 +1244,25 @@ impl Apps {
         client_id: &str,
         access_token: &str,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self
+            .check_authorization_with_response(client_id, access_token)
+            .await?
+            .body)
+    }
+    /**
+     * Check an authorization.
+     *
+     * This function performs a `GET` to the `/applications/{client_id}/tokens/{access_token}` endpoint.
+     *
+     * As opposed to `check_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#check-an-authorization>
+     */
+    pub async fn check_authorization_with_response(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/tokens/{}",
@@ -776,7 +1272,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -806,6 +1302,25 @@ impl Apps {
         client_id: &str,
         access_token: &str,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self
+            .reset_authorization_with_response(client_id, access_token)
+            .await?
+            .body)
+    }
+    /**
+     * Reset an authorization.
+     *
+     * This function performs a `POST` to the `/applications/{client_id}/tokens/{access_token}` endpoint.
+     *
+     * As opposed to `reset_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#reset-an-authorization>
+     */
+    pub async fn reset_authorization_with_response(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/tokens/{}",
@@ -815,7 +1330,7 @@ impl Apps {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -845,6 +1360,24 @@ impl Apps {
         client_id: &str,
         access_token: &str,
     ) -> ClientResult<()> {
+        self.revoke_authorization_for_application_with_response(client_id, access_token)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Revoke an authorization for an application.
+     *
+     * This function performs a `DELETE` to the `/applications/{client_id}/tokens/{access_token}` endpoint.
+     *
+     * As opposed to `revoke_authorization_for_application`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#revoke-an-authorization-for-an-application>
+     */
+    pub async fn revoke_authorization_for_application_with_response(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/applications/{}/tokens/{}",
@@ -854,7 +1387,7 @@ impl Apps {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -879,12 +1412,27 @@ impl Apps {
      * * `app_slug: &str`
      */
     pub async fn get_by_slug(&self, app_slug: &str) -> ClientResult<crate::types::GitHubApp> {
+        Ok(self.get_by_slug_with_response(app_slug).await?.body)
+    }
+    /**
+     * Get an app.
+     *
+     * This function performs a `GET` to the `/apps/{app_slug}` endpoint.
+     *
+     * As opposed to `get_by_slug`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps/#get-an-app>
+     */
+    pub async fn get_by_slug_with_response(
+        &self,
+        app_slug: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitHubApp>> {
         let url = self.client.url(
             &format!("/apps/{}", crate::progenitor_support::encode_path(app_slug),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -914,6 +1462,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::AppsListInstallationReposResponse> {
+        Ok(self
+            .list_repos_accessible_to_installation_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories accessible to the app installation.
+     *
+     * This function performs a `GET` to the `/installation/repositories` endpoint.
+     *
+     * As opposed to `list_repos_accessible_to_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-repositories-accessible-to-the-app-installation>
+     */
+    pub async fn list_repos_accessible_to_installation_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -926,7 +1493,7 @@ impl Apps {
             .client
             .url(&format!("/installation/repositories?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -949,9 +1516,25 @@ impl Apps {
      * FROM: <https://docs.github.com/rest/reference/apps#revoke-an-installation-access-token>
      */
     pub async fn revoke_installation_access_token(&self) -> ClientResult<()> {
+        self.revoke_installation_access_token_with_response()
+            .await?;
+        Ok(())
+    }
+    /**
+     * Revoke an installation access token.
+     *
+     * This function performs a `DELETE` to the `/installation/token` endpoint.
+     *
+     * As opposed to `revoke_installation_access_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#revoke-an-installation-access-token>
+     */
+    pub async fn revoke_installation_access_token_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/installation/token", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -979,6 +1562,24 @@ impl Apps {
         &self,
         account_id: i64,
     ) -> ClientResult<crate::types::MarketplacePurchaseData> {
+        Ok(self
+            .get_subscription_plan_for_account_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a subscription plan for an account.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/accounts/{account_id}` endpoint.
+     *
+     * As opposed to `get_subscription_plan_for_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-a-subscription-plan-for-an-account>
+     */
+    pub async fn get_subscription_plan_for_account_with_response(
+        &self,
+        account_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MarketplacePurchaseData>> {
         let url = self.client.url(
             &format!(
                 "/marketplace_listing/accounts/{}",
@@ -987,7 +1588,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1017,6 +1618,22 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MarketplaceListingPlan>> {
+        Ok(self.list_plans_with_response(per_page, page).await?.body)
+    }
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_plans`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub async fn list_plans_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1029,7 +1646,7 @@ impl Apps {
             .client
             .url(&format!("/marketplace_listing/plans?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1063,6 +1680,71 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_all_plans`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub fn list_all_plans_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::MarketplaceListingPlan>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/marketplace_listing/plans", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MarketplaceListingPlan>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_all_plans`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub fn list_all_plans_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplaceListingPlan>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_plans_pages_stream(None))
+    }
     /**
      * List accounts for a plan.
      *
@@ -1092,6 +1774,28 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MarketplacePurchaseData>> {
+        Ok(self
+            .list_accounts_for_plan_with_response(plan_id, sort, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_accounts_for_plan`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub async fn list_accounts_for_plan_with_response(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1115,7 +1819,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1169,6 +1873,95 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub fn list_all_accounts_for_plan_pages_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::MarketplacePurchaseData>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/marketplace_listing/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MarketplacePurchaseData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub fn list_all_accounts_for_plan_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplacePurchaseData>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_accounts_for_plan_pages_stream(plan_id, sort, direction, None),
+        )
+    }
     /**
      * Get a subscription plan for an account (stubbed).
      *
@@ -1188,6 +1981,24 @@ impl Apps {
         &self,
         account_id: i64,
     ) -> ClientResult<crate::types::MarketplacePurchaseData> {
+        Ok(self
+            .get_subscription_plan_for_account_stubbed_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a subscription plan for an account (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/accounts/{account_id}` endpoint.
+     *
+     * As opposed to `get_subscription_plan_for_account_stubbed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-a-subscription-plan-for-an-account-stubbed>
+     */
+    pub async fn get_subscription_plan_for_account_stubbed_with_response(
+        &self,
+        account_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MarketplacePurchaseData>> {
         let url = self.client.url(
             &format!(
                 "/marketplace_listing/stubbed/accounts/{}",
@@ -1196,7 +2007,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1226,6 +2037,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MarketplaceListingPlan>> {
+        Ok(self
+            .list_plans_stubbed_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_plans_stubbed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub async fn list_plans_stubbed_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplaceListingPlan>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1239,7 +2069,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1275,6 +2105,71 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_all_plans_stubbed`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub fn list_all_plans_stubbed_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::MarketplaceListingPlan>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/marketplace_listing/stubbed/plans", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MarketplaceListingPlan>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_all_plans_stubbed`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub fn list_all_plans_stubbed_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplaceListingPlan>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_plans_stubbed_pages_stream(None))
+    }
     /**
      * List accounts for a plan (stubbed).
      *
@@ -1304,6 +2199,28 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MarketplacePurchaseData>> {
+        Ok(self
+            .list_accounts_for_plan_stubbed_with_response(plan_id, sort, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_accounts_for_plan_stubbed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub async fn list_accounts_for_plan_stubbed_with_response(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MarketplacePurchaseData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1327,7 +2244,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1381,6 +2298,95 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan_stubbed`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub fn list_all_accounts_for_plan_stubbed_pages_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::MarketplacePurchaseData>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/marketplace_listing/stubbed/plans/{}/accounts?{}",
+                crate::progenitor_support::encode_path(&plan_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MarketplacePurchaseData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan_stubbed`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub fn list_all_accounts_for_plan_stubbed_stream(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MarketplacePurchaseData>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_accounts_for_plan_stubbed_pages_stream(plan_id, sort, direction, None),
+        )
+    }
     /**
      * Get an organization installation for the authenticated app.
      *
@@ -1400,6 +2406,21 @@ impl Apps {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::Installation> {
+        Ok(self.get_org_installation_with_response(org).await?.body)
+    }
+    /**
+     * Get an organization installation for the authenticated app.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/installation` endpoint.
+     *
+     * As opposed to `get_org_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-an-organization-installation-for-the-authenticated-app>
+     */
+    pub async fn get_org_installation_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/installation",
@@ -1408,7 +2429,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1443,6 +2464,27 @@ impl Apps {
         content_reference_id: i64,
         body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
     ) -> ClientResult<crate::types::ContentReferenceAttachment> {
+        Ok(self
+            .create_content_attachment_with_response(owner, repo, content_reference_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a content attachment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/content_references/{content_reference_id}/attachments` endpoint.
+     *
+     * As opposed to `create_content_attachment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#create-a-content-attachment>
+     */
+    pub async fn create_content_attachment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        content_reference_id: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContentReferenceAttachment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/content_references/{}/attachments",
@@ -1453,7 +2495,7 @@ impl Apps {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1483,6 +2525,25 @@ impl Apps {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::Installation> {
+        Ok(self
+            .get_repo_installation_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get a repository installation for the authenticated app.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/installation` endpoint.
+     *
+     * As opposed to `get_repo_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-a-repository-installation-for-the-authenticated-app>
+     */
+    pub async fn get_repo_installation_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/installation",
@@ -1492,7 +2553,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1526,6 +2587,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::AppsListInstallationsResponse> {
+        Ok(self
+            .list_installations_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List app installations accessible to the user access token.
+     *
+     * This function performs a `GET` to the `/user/installations` endpoint.
+     *
+     * As opposed to `list_installations_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-app-installations-accessible-to-the-user-access-token>
+     */
+    pub async fn list_installations_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1538,7 +2618,7 @@ impl Apps {
             .client
             .url(&format!("/user/installations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1574,6 +2654,30 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::AppsListInstallationReposResponse> {
+        Ok(self
+            .list_installation_repos_for_authenticated_user_with_response(
+                installation_id,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List repositories accessible to the user access token.
+     *
+     * This function performs a `GET` to the `/user/installations/{installation_id}/repositories` endpoint.
+     *
+     * As opposed to `list_installation_repos_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-repositories-accessible-to-the-user-access-token>
+     */
+    pub async fn list_installation_repos_for_authenticated_user_with_response(
+        &self,
+        installation_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationReposResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1591,7 +2695,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1621,6 +2725,24 @@ impl Apps {
         installation_id: i64,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.add_repo_to_installation_with_response(installation_id, repository_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add a repository to an app installation.
+     *
+     * This function performs a `PUT` to the `/user/installations/{installation_id}/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `add_repo_to_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#add-a-repository-to-an-app-installation>
+     */
+    pub async fn add_repo_to_installation_with_response(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/installations/{}/repositories/{}",
@@ -1630,7 +2752,7 @@ impl Apps {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1660,6 +2782,24 @@ impl Apps {
         installation_id: i64,
         repository_id: i64,
     ) -> ClientResult<()> {
+        self.remove_repo_from_installation_with_response(installation_id, repository_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a repository from an app installation.
+     *
+     * This function performs a `DELETE` to the `/user/installations/{installation_id}/repositories/{repository_id}` endpoint.
+     *
+     * As opposed to `remove_repo_from_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#remove-a-repository-from-an-app-installation>
+     */
+    pub async fn remove_repo_from_installation_with_response(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/installations/{}/repositories/{}",
@@ -1669,7 +2809,7 @@ impl Apps {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1697,6 +2837,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::UserMarketplacePurchase>> {
+        Ok(self
+            .list_subscriptions_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_subscriptions_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub async fn list_subscriptions_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1709,7 +2868,7 @@ impl Apps {
             .client
             .url(&format!("/user/marketplace_purchases?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1743,6 +2902,73 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::UserMarketplacePurchase>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/user/marketplace_purchases", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UserMarketplacePurchase>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserMarketplacePurchase>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_subscriptions_for_authenticated_user_pages_stream(None),
+        )
+    }
     /**
      * List subscriptions for the authenticated user (stubbed).
      *
@@ -1762,6 +2988,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::UserMarketplacePurchase>> {
+        Ok(self
+            .list_subscriptions_for_authenticated_user_stubbed_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_subscriptions_for_authenticated_user_stubbed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub async fn list_subscriptions_for_authenticated_user_stubbed_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserMarketplacePurchase>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1775,7 +3020,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1809,6 +3054,73 @@ impl Apps {
             )
             .await
     }
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user_stubbed`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stubbed_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::UserMarketplacePurchase>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/user/marketplace_purchases/stubbed", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UserMarketplacePurchase>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user_stubbed`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub fn list_all_subscriptions_for_authenticated_user_stubbed_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserMarketplacePurchase>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_subscriptions_for_authenticated_user_stubbed_pages_stream(None),
+        )
+    }
     /**
      * Get a user installation for the authenticated app.
      *
@@ -1828,6 +3140,24 @@ impl Apps {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::Installation> {
+        Ok(self
+            .get_user_installation_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * Get a user installation for the authenticated app.
+     *
+     * This function performs a `GET` to the `/users/{username}/installation` endpoint.
+     *
+     * As opposed to `get_user_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#get-a-user-installation-for-the-authenticated-app>
+     */
+    pub async fn get_user_installation_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Installation>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/installation",
@@ -1836,7 +3166,7 @@ impl Apps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
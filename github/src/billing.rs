@@ -32,6 +32,24 @@ impl Billing {
         &self,
         enterprise: &str,
     ) -> ClientResult<crate::types::ActionsBillingUsage> {
+        Ok(self
+            .get_github_actions_billing_ghe_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Actions billing for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/settings/billing/actions` endpoint.
+     *
+     * As opposed to `get_github_actions_billing_ghe`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-github-actions-billing-for-an-enterprise>
+     */
+    pub async fn get_github_actions_billing_ghe_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/settings/billing/actions",
@@ -40,7 +58,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,6 +88,24 @@ impl Billing {
         &self,
         enterprise: &str,
     ) -> ClientResult<crate::types::PackagesBillingUsage> {
+        Ok(self
+            .get_github_packages_billing_ghe_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Packages billing for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/settings/billing/packages` endpoint.
+     *
+     * As opposed to `get_github_packages_billing_ghe`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-github-packages-billing-for-an-enterprise>
+     */
+    pub async fn get_github_packages_billing_ghe_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackagesBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/settings/billing/packages",
@@ -78,7 +114,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -108,6 +144,24 @@ impl Billing {
         &self,
         enterprise: &str,
     ) -> ClientResult<crate::types::CombinedBillingUsage> {
+        Ok(self
+            .get_shared_storage_billing_ghe_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * Get shared storage billing for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/settings/billing/shared-storage` endpoint.
+     *
+     * As opposed to `get_shared_storage_billing_ghe`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-shared-storage-billing-for-an-enterprise>
+     */
+    pub async fn get_shared_storage_billing_ghe_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::CombinedBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/settings/billing/shared-storage",
@@ -116,7 +170,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -146,6 +200,24 @@ impl Billing {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::ActionsBillingUsage> {
+        Ok(self
+            .get_github_actions_billing_org_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Actions billing for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/settings/billing/actions` endpoint.
+     *
+     * As opposed to `get_github_actions_billing_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-github-actions-billing-for-an-organization>
+     */
+    pub async fn get_github_actions_billing_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/settings/billing/actions",
@@ -154,7 +226,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -184,6 +256,24 @@ impl Billing {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::PackagesBillingUsage> {
+        Ok(self
+            .get_github_packages_billing_org_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Packages billing for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/settings/billing/packages` endpoint.
+     *
+     * As opposed to `get_github_packages_billing_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-github-packages-billing-for-an-organization>
+     */
+    pub async fn get_github_packages_billing_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackagesBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/settings/billing/packages",
@@ -192,7 +282,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -222,6 +312,24 @@ impl Billing {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::CombinedBillingUsage> {
+        Ok(self
+            .get_shared_storage_billing_org_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Get shared storage billing for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/settings/billing/shared-storage` endpoint.
+     *
+     * As opposed to `get_shared_storage_billing_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-shared-storage-billing-for-an-organization>
+     */
+    pub async fn get_shared_storage_billing_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::CombinedBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/settings/billing/shared-storage",
@@ -230,7 +338,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -260,6 +368,24 @@ impl Billing {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::ActionsBillingUsage> {
+        Ok(self
+            .get_github_actions_billing_user_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Actions billing for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/settings/billing/actions` endpoint.
+     *
+     * As opposed to `get_github_actions_billing_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-github-actions-billing-for-a-user>
+     */
+    pub async fn get_github_actions_billing_user_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/settings/billing/actions",
@@ -268,7 +394,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -298,6 +424,24 @@ impl Billing {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::PackagesBillingUsage> {
+        Ok(self
+            .get_github_packages_billing_user_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Packages billing for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/settings/billing/packages` endpoint.
+     *
+     * As opposed to `get_github_packages_billing_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-github-packages-billing-for-a-user>
+     */
+    pub async fn get_github_packages_billing_user_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackagesBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/settings/billing/packages",
@@ -306,7 +450,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -336,6 +480,24 @@ impl Billing {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::CombinedBillingUsage> {
+        Ok(self
+            .get_shared_storage_billing_user_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * Get shared storage billing for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/settings/billing/shared-storage` endpoint.
+     *
+     * As opposed to `get_shared_storage_billing_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/billing#get-shared-storage-billing-for-a-user>
+     */
+    pub async fn get_shared_storage_billing_user_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::CombinedBillingUsage>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/settings/billing/shared-storage",
@@ -344,7 +506,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
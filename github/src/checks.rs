@@ -35,6 +35,23 @@ impl Checks {
         repo: &str,
         body: &crate::types::ChecksCreateRequest,
     ) -> ClientResult<crate::types::CheckRun> {
+        Ok(self.create_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Create a check run.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/check-runs` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#create-a-check-run>
+     */
+    pub async fn create_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::CheckRun>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-runs",
@@ -44,7 +61,7 @@ impl Checks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -76,6 +93,26 @@ impl Checks {
         repo: &str,
         check_run_id: i64,
     ) -> ClientResult<crate::types::CheckRun> {
+        Ok(self
+            .get_with_response(owner, repo, check_run_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a check run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#get-a-check-run>
+     */
+    pub async fn get_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CheckRun>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-runs/{}",
@@ -86,7 +123,7 @@ impl Checks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -119,6 +156,27 @@ impl Checks {
         check_run_id: i64,
         body: &crate::types::ChecksUpdateRequest,
     ) -> ClientResult<crate::types::CheckRun> {
+        Ok(self
+            .update_with_response(owner, repo, check_run_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a check run.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#update-a-check-run>
+     */
+    pub async fn update_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        body: &crate::types::ChecksUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::CheckRun>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-runs/{}",
@@ -129,7 +187,7 @@ impl Checks {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -163,6 +221,28 @@ impl Checks {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::CheckAnnotation>> {
+        Ok(self
+            .list_annotations_with_response(owner, repo, check_run_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_annotations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub async fn list_annotations_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CheckAnnotation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -182,7 +262,7 @@ impl Checks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -227,6 +307,89 @@ impl Checks {
             )
             .await
     }
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_all_annotations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub fn list_all_annotations_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CheckAnnotation>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&check_run_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CheckAnnotation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_all_annotations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub fn list_all_annotations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CheckAnnotation>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_annotations_pages_stream(
+            owner,
+            repo,
+            check_run_id,
+            None,
+        ))
+    }
     /**
      * Create a check suite.
      *
@@ -249,6 +412,26 @@ impl Checks {
         repo: &str,
         body: &crate::types::ChecksCreateSuiteRequest,
     ) -> ClientResult<crate::types::CheckSuiteData> {
+        Ok(self
+            .create_suite_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a check suite.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/check-suites` endpoint.
+     *
+     * As opposed to `create_suite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#create-a-check-suite>
+     */
+    pub async fn create_suite_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateSuiteRequest,
+    ) -> ClientResult<crate::Response<crate::types::CheckSuiteData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-suites",
@@ -258,7 +441,7 @@ impl Checks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -287,6 +470,26 @@ impl Checks {
         repo: &str,
         body: &crate::types::Preferences,
     ) -> ClientResult<crate::types::CheckSuitePreference> {
+        Ok(self
+            .set_suites_preferences_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update repository preferences for check suites.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/check-suites/preferences` endpoint.
+     *
+     * As opposed to `set_suites_preferences`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#update-repository-preferences-for-check-suites>
+     */
+    pub async fn set_suites_preferences_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::Preferences,
+    ) -> ClientResult<crate::Response<crate::types::CheckSuitePreference>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-suites/preferences",
@@ -296,7 +499,7 @@ impl Checks {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -328,6 +531,26 @@ impl Checks {
         repo: &str,
         check_suite_id: i64,
     ) -> ClientResult<crate::types::CheckSuiteData> {
+        Ok(self
+            .get_suite_with_response(owner, repo, check_suite_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a check suite.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-suites/{check_suite_id}` endpoint.
+     *
+     * As opposed to `get_suite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#get-a-check-suite>
+     */
+    pub async fn get_suite_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CheckSuiteData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-suites/{}",
@@ -338,7 +561,7 @@ impl Checks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -382,6 +605,40 @@ impl Checks {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ChecksListRefResponse> {
+        Ok(self
+            .list_for_suite_with_response(
+                owner,
+                repo,
+                check_suite_id,
+                check_name,
+                status,
+                filter,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List check runs in a check suite.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs` endpoint.
+     *
+     * As opposed to `list_for_suite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-runs-in-a-check-suite>
+     */
+    pub async fn list_for_suite_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+        check_name: &str,
+        status: crate::types::JobStatus,
+        filter: crate::types::ActionsListJobsWorkflowRunFilter,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !check_name.is_empty() {
             query_args.push(("check_name".to_string(), check_name.to_string()));
@@ -410,7 +667,7 @@ impl Checks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -442,6 +699,25 @@ impl Checks {
         repo: &str,
         check_suite_id: i64,
     ) -> ClientResult<()> {
+        self.rerequest_suite_with_response(owner, repo, check_suite_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Rerequest a check suite.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/check-suites/{check_suite_id}/rerequest` endpoint.
+     *
+     * As opposed to `rerequest_suite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#rerequest-a-check-suite>
+     */
+    pub async fn rerequest_suite_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/check-suites/{}/rerequest",
@@ -452,7 +728,7 @@ impl Checks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -498,6 +774,34 @@ impl Checks {
         page: i64,
         app_id: i64,
     ) -> ClientResult<crate::types::ChecksListRefResponse> {
+        Ok(self
+            .list_for_ref_with_response(
+                owner, repo, ref_, check_name, status, filter, per_page, page, app_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List check runs for a Git reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/check-runs` endpoint.
+     *
+     * As opposed to `list_for_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-runs-for-a-git-reference>
+     */
+    pub async fn list_for_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        check_name: &str,
+        status: crate::types::JobStatus,
+        filter: crate::types::ActionsListJobsWorkflowRunFilter,
+        per_page: i64,
+        page: i64,
+        app_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ChecksListRefResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if app_id > 0 {
             query_args.push(("app_id".to_string(), app_id.to_string()));
@@ -529,7 +833,7 @@ impl Checks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -569,6 +873,32 @@ impl Checks {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ChecksListSuitesRefResponse> {
+        Ok(self
+            .list_suites_for_ref_with_response(
+                owner, repo, ref_, app_id, check_name, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List check suites for a Git reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/check-suites` endpoint.
+     *
+     * As opposed to `list_suites_for_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-suites-for-a-git-reference>
+     */
+    pub async fn list_suites_for_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        app_id: i64,
+        check_name: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ChecksListSuitesRefResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if app_id > 0 {
             query_args.push(("app_id".to_string(), app_id.to_string()));
@@ -594,7 +924,7 @@ impl Checks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,6 +50,33 @@ impl CodeScanning {
         ref_: &str,
         state: crate::types::CodeScanningAlertState,
     ) -> ClientResult<Vec<crate::types::CodeScanningAlertItems>> {
+        Ok(self
+            .list_alerts_for_repo_with_response(
+                owner, repo, tool_name, tool_guid, page, per_page, ref_, state,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_alerts_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub async fn list_alerts_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertItems>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -80,7 +107,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -150,6 +177,108 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CodeScanningAlertItems>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        if !tool_guid.is_empty() {
+            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        }
+        if !tool_name.is_empty() {
+            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CodeScanningAlertItems>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAlertItems>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_alerts_for_repo_pages_stream(
+            owner, repo, tool_name, tool_guid, ref_, state, None,
+        ))
+    }
     /**
      * Get a code scanning alert.
      *
@@ -174,6 +303,26 @@ impl CodeScanning {
         repo: &str,
         alert_number: i64,
     ) -> ClientResult<crate::types::CodeScanningAlert> {
+        Ok(self
+            .get_alert_with_response(owner, repo, alert_number)
+            .await?
+            .body)
+    }
+    /**
+     * Get a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}` endpoint.
+     *
+     * As opposed to `get_alert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#get-a-code-scanning-alert>
+     */
+    pub async fn get_alert_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningAlert>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/code-scanning/alerts/{}",
@@ -184,7 +333,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +364,27 @@ impl CodeScanning {
         alert_number: i64,
         body: &crate::types::CodeScanningUpdateAlertRequest,
     ) -> ClientResult<crate::types::CodeScanningAlert> {
+        Ok(self
+            .update_alert_with_response(owner, repo, alert_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a code scanning alert.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}` endpoint.
+     *
+     * As opposed to `update_alert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#update-a-code-scanning-alert>
+     */
+    pub async fn update_alert_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::CodeScanningUpdateAlertRequest,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningAlert>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/code-scanning/alerts/{}",
@@ -225,7 +395,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -261,6 +431,29 @@ impl CodeScanning {
         per_page: i64,
         ref_: &str,
     ) -> ClientResult<Vec<crate::types::CodeScanningAlertInstance>> {
+        Ok(self
+            .list_alert_instances_with_response(owner, repo, alert_number, page, per_page, ref_)
+            .await?
+            .body)
+    }
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_alert_instances`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub async fn list_alert_instances_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAlertInstance>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -283,7 +476,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -335,6 +528,100 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_all_alert_instances`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub fn list_all_alert_instances_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        ref_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CodeScanningAlertInstance>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&alert_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CodeScanningAlertInstance>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_all_alert_instances`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub fn list_all_alert_instances_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAlertInstance>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_alert_instances_pages_stream(
+            owner,
+            repo,
+            alert_number,
+            ref_,
+            None,
+        ))
+    }
     /**
      * List code scanning analyses for a repository.
      *
@@ -381,6 +668,33 @@ impl CodeScanning {
         ref_: &str,
         sarif_id: &str,
     ) -> ClientResult<Vec<crate::types::CodeScanningAnalysis>> {
+        Ok(self
+            .list_recent_analyses_with_response(
+                owner, repo, tool_name, tool_guid, page, per_page, ref_, sarif_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_recent_analyses`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub async fn list_recent_analyses_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+        sarif_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeScanningAnalysis>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -411,7 +725,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -488,6 +802,108 @@ impl CodeScanning {
             )
             .await
     }
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_all_recent_analyses`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub fn list_all_recent_analyses_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        sarif_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CodeScanningAnalysis>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if !sarif_id.is_empty() {
+            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+        }
+        if !tool_guid.is_empty() {
+            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        }
+        if !tool_name.is_empty() {
+            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CodeScanningAnalysis>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_all_recent_analyses`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub fn list_all_recent_analyses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        ref_: &str,
+        sarif_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeScanningAnalysis>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_recent_analyses_pages_stream(
+            owner, repo, tool_name, tool_guid, ref_, sarif_id, None,
+        ))
+    }
     /**
      * Get a code scanning analysis for a repository.
      *
@@ -529,6 +945,26 @@ impl CodeScanning {
         repo: &str,
         analysis_id: i64,
     ) -> ClientResult<crate::types::CodeScanningAnalysis> {
+        Ok(self
+            .get_analysis_with_response(owner, repo, analysis_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a code scanning analysis for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}` endpoint.
+     *
+     * As opposed to `get_analysis`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#get-a-code-scanning-analysis-for-a-repository>
+     */
+    pub async fn get_analysis_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningAnalysis>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/code-scanning/analyses/{}",
@@ -539,7 +975,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -634,6 +1070,27 @@ impl CodeScanning {
         analysis_id: i64,
         confirm_delete: &str,
     ) -> ClientResult<crate::types::AnalysisDeletion> {
+        Ok(self
+            .delete_analysis_with_response(owner, repo, analysis_id, confirm_delete)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a code scanning analysis from a repository.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}` endpoint.
+     *
+     * As opposed to `delete_analysis`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#delete-a-code-scanning-analysis-from-a-repository>
+     */
+    pub async fn delete_analysis_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+        confirm_delete: &str,
+    ) -> ClientResult<crate::Response<crate::types::AnalysisDeletion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !confirm_delete.is_empty() {
             query_args.push(("confirm_delete".to_string(), confirm_delete.to_string()));
@@ -650,7 +1107,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -695,6 +1152,26 @@ impl CodeScanning {
         repo: &str,
         body: &crate::types::CodeScanningUploadSarifRequest,
     ) -> ClientResult<crate::types::CodeScanningSarifsReceipt> {
+        Ok(self
+            .upload_sarif_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Upload an analysis as SARIF data.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/code-scanning/sarifs` endpoint.
+     *
+     * As opposed to `upload_sarif`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#upload-a-sarif-file>
+     */
+    pub async fn upload_sarif_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::CodeScanningUploadSarifRequest,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningSarifsReceipt>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/code-scanning/sarifs",
@@ -704,7 +1181,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -734,6 +1211,26 @@ impl CodeScanning {
         repo: &str,
         sarif_id: &str,
     ) -> ClientResult<crate::types::CodeScanningSarifsStatus> {
+        Ok(self
+            .get_sarif_with_response(owner, repo, sarif_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get information about a SARIF upload.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/sarifs/{sarif_id}` endpoint.
+     *
+     * As opposed to `get_sarif`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-recent-code-scanning-analyses-for-a-repository>
+     */
+    pub async fn get_sarif_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        sarif_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CodeScanningSarifsStatus>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/code-scanning/sarifs/{}",
@@ -744,7 +1241,7 @@ impl CodeScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -21,9 +21,23 @@ impl CodesOfConduct {
      * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
      */
     pub async fn get_all_codes_of_conduct(&self) -> ClientResult<Vec<crate::types::CodeOfConduct>> {
+        Ok(self.get_all_codes_of_conduct_with_response().await?.body)
+    }
+    /**
+     * Get all codes of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct` endpoint.
+     *
+     * As opposed to `get_all_codes_of_conduct`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
+     */
+    pub async fn get_all_codes_of_conduct_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CodeOfConduct>>> {
         let url = self.client.url("/codes_of_conduct", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,6 +71,69 @@ impl CodesOfConduct {
             )
             .await
     }
+    /**
+     * Get all codes of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct` endpoint.
+     *
+     * As opposed to `get_all_all_codes_of_conduct`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
+     */
+    pub fn get_all_all_codes_of_conduct_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CodeOfConduct>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/codes_of_conduct", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CodeOfConduct>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all codes of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct` endpoint.
+     *
+     * As opposed to `get_all_all_codes_of_conduct`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
+     */
+    pub fn get_all_all_codes_of_conduct_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CodeOfConduct>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_all_codes_of_conduct_pages_stream(None))
+    }
     /**
      * Get a code of conduct.
      *
@@ -71,6 +148,21 @@ impl CodesOfConduct {
      * * `key: &str`
      */
     pub async fn get_conduct_code(&self, key: &str) -> ClientResult<crate::types::CodeOfConduct> {
+        Ok(self.get_conduct_code_with_response(key).await?.body)
+    }
+    /**
+     * Get a code of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct/{key}` endpoint.
+     *
+     * As opposed to `get_conduct_code`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-a-code-of-conduct>
+     */
+    pub async fn get_conduct_code_with_response(
+        &self,
+        key: &str,
+    ) -> ClientResult<crate::Response<crate::types::CodeOfConduct>> {
         let url = self.client.url(
             &format!(
                 "/codes_of_conduct/{}",
@@ -79,7 +171,7 @@ impl CodesOfConduct {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -109,6 +201,22 @@ impl CodesOfConduct {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::CodeOfConduct> {
+        Ok(self.get_for_repo_with_response(owner, repo).await?.body)
+    }
+    /**
+     * Get the code of conduct for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/community/code_of_conduct` endpoint.
+     *
+     * As opposed to `get_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-the-code-of-conduct-for-a-repository>
+     */
+    pub async fn get_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::CodeOfConduct>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/community/code_of_conduct",
@@ -118,7 +226,7 @@ impl CodesOfConduct {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
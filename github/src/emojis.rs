@@ -21,9 +21,21 @@ impl Emojis {
      * FROM: <https://docs.github.com/rest/reference/emojis#get-emojis>
      */
     pub async fn get(&self) -> ClientResult<String> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * Get emojis.
+     *
+     * This function performs a `GET` to the `/emojis` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/emojis#get-emojis>
+     */
+    pub async fn get_with_response(&self) -> ClientResult<crate::Response<String>> {
         let url = self.client.url("/emojis", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -30,6 +30,24 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
     ) -> ClientResult<crate::types::ActionsEnterprisePermissions> {
+        Ok(self
+            .get_github_actions_permissions_enterprise_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Actions permissions for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/permissions` endpoint.
+     *
+     * As opposed to `get_github_actions_permissions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-github-actions-permissions-for-an-enterprise>
+     */
+    pub async fn get_github_actions_permissions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::ActionsEnterprisePermissions>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/permissions",
@@ -38,7 +56,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -67,6 +85,24 @@ impl EnterpriseAdmin {
         enterprise: &str,
         body: &crate::types::EnterpriseAdminSetGithubActionsPermissionsRequest,
     ) -> ClientResult<()> {
+        self.set_github_actions_permissions_enterprise_with_response(enterprise, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set GitHub Actions permissions for an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/permissions` endpoint.
+     *
+     * As opposed to `set_github_actions_permissions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#set-github-actions-permissions-for-an-enterprise>
+     */
+    pub async fn set_github_actions_permissions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetGithubActionsPermissionsRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/permissions",
@@ -75,7 +111,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -108,6 +144,30 @@ impl EnterpriseAdmin {
         page: i64,
     ) -> ClientResult<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>
     {
+        Ok(self
+            .list_selected_organizations_enabled_github_actions_enterprise_with_response(
+                enterprise, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List selected organizations enabled for GitHub Actions in an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/permissions/organizations` endpoint.
+     *
+     * As opposed to `list_selected_organizations_enabled_github_actions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-selected-organizations-enabled-for-github-actions-in-an-enterprise>
+     */
+    pub async fn list_selected_organizations_enabled_github_actions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -125,7 +185,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -154,6 +214,26 @@ impl EnterpriseAdmin {
         enterprise: &str,
         body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
     ) -> ClientResult<()> {
+        self.set_selected_organizations_enabled_github_actions_enterprise_with_response(
+            enterprise, body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Set selected organizations enabled for GitHub Actions in an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/permissions/organizations` endpoint.
+     *
+     * As opposed to `set_selected_organizations_enabled_github_actions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#set-selected-organizations-enabled-for-github-actions-in-an-enterprise>
+     */
+    pub async fn set_selected_organizations_enabled_github_actions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/permissions/organizations",
@@ -162,7 +242,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -192,6 +272,26 @@ impl EnterpriseAdmin {
         enterprise: &str,
         org_id: i64,
     ) -> ClientResult<()> {
+        self.enable_selected_organization_github_actions_enterprise_with_response(
+            enterprise, org_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Enable a selected organization for GitHub Actions in an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/permissions/organizations/{org_id}` endpoint.
+     *
+     * As opposed to `enable_selected_organization_github_actions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#enable-a-selected-organization-for-github-actions-in-an-enterprise>
+     */
+    pub async fn enable_selected_organization_github_actions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/permissions/organizations/{}",
@@ -201,7 +301,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -231,6 +331,26 @@ impl EnterpriseAdmin {
         enterprise: &str,
         org_id: i64,
     ) -> ClientResult<()> {
+        self.disable_selected_organization_github_actions_enterprise_with_response(
+            enterprise, org_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Disable a selected organization for GitHub Actions in an enterprise.
+     *
+     * This function performs a `DELETE` to the `/enterprises/{enterprise}/actions/permissions/organizations/{org_id}` endpoint.
+     *
+     * As opposed to `disable_selected_organization_github_actions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#disable-a-selected-organization-for-github-actions-in-an-enterprise>
+     */
+    pub async fn disable_selected_organization_github_actions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/permissions/organizations/{}",
@@ -240,7 +360,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -268,6 +388,24 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
     ) -> ClientResult<crate::types::SelectedActions> {
+        Ok(self
+            .get_allowed_actions_enterprise_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * Get allowed actions for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/permissions/selected-actions` endpoint.
+     *
+     * As opposed to `get_allowed_actions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-allowed-actions-for-an-enterprise>
+     */
+    pub async fn get_allowed_actions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::SelectedActions>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/permissions/selected-actions",
@@ -276,7 +414,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -305,6 +443,24 @@ impl EnterpriseAdmin {
         enterprise: &str,
         body: &crate::types::SelectedActions,
     ) -> ClientResult<()> {
+        self.set_allowed_actions_enterprise_with_response(enterprise, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set allowed actions for an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/permissions/selected-actions` endpoint.
+     *
+     * As opposed to `set_allowed_actions_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#set-allowed-actions-for-an-enterprise>
+     */
+    pub async fn set_allowed_actions_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        body: &crate::types::SelectedActions,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/permissions/selected-actions",
@@ -313,7 +469,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -345,6 +501,28 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse> {
+        Ok(self
+            .list_self_hosted_runner_groups_for_enterprise_with_response(enterprise, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List self-hosted runner groups for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runner-groups` endpoint.
+     *
+     * As opposed to `list_self_hosted_runner_groups_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-self-hosted-runner-groups-for-an-enterprise>
+     */
+    pub async fn list_self_hosted_runner_groups_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse>,
+    > {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -362,7 +540,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -391,6 +569,25 @@ impl EnterpriseAdmin {
         enterprise: &str,
         body: &crate::types::EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
     ) -> ClientResult<crate::types::RunnerGroupsEnterprise> {
+        Ok(self
+            .create_self_hosted_runner_group_for_enterprise_with_response(enterprise, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a self-hosted runner group for an enterprise.
+     *
+     * This function performs a `POST` to the `/enterprises/{enterprise}/actions/runner-groups` endpoint.
+     *
+     * As opposed to `create_self_hosted_runner_group_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#create-self-hosted-runner-group-for-an-enterprise>
+     */
+    pub async fn create_self_hosted_runner_group_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsEnterprise>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups",
@@ -399,7 +596,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -429,6 +626,25 @@ impl EnterpriseAdmin {
         enterprise: &str,
         runner_group_id: i64,
     ) -> ClientResult<crate::types::RunnerGroupsEnterprise> {
+        Ok(self
+            .get_self_hosted_runner_group_for_enterprise_with_response(enterprise, runner_group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a self-hosted runner group for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}` endpoint.
+     *
+     * As opposed to `get_self_hosted_runner_group_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-a-self-hosted-runner-group-for-an-enterprise>
+     */
+    pub async fn get_self_hosted_runner_group_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsEnterprise>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}",
@@ -438,7 +654,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -468,6 +684,27 @@ impl EnterpriseAdmin {
         enterprise: &str,
         runner_group_id: i64,
     ) -> ClientResult<()> {
+        self.delete_self_hosted_runner_group_from_enterprise_with_response(
+            enterprise,
+            runner_group_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete a self-hosted runner group from an enterprise.
+     *
+     * This function performs a `DELETE` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}` endpoint.
+     *
+     * As opposed to `delete_self_hosted_runner_group_from_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#delete-a-self-hosted-runner-group-from-an-enterprise>
+     */
+    pub async fn delete_self_hosted_runner_group_from_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}",
@@ -477,7 +714,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -508,6 +745,30 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         body: &crate::types::EnterpriseAdminUpdateSelfHostedRunnerGroupRequest,
     ) -> ClientResult<crate::types::RunnerGroupsEnterprise> {
+        Ok(self
+            .update_self_hosted_runner_group_for_enterprise_with_response(
+                enterprise,
+                runner_group_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Update a self-hosted runner group for an enterprise.
+     *
+     * This function performs a `PATCH` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}` endpoint.
+     *
+     * As opposed to `update_self_hosted_runner_group_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#update-a-self-hosted-runner-group-for-an-enterprise>
+     */
+    pub async fn update_self_hosted_runner_group_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminUpdateSelfHostedRunnerGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::RunnerGroupsEnterprise>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}",
@@ -517,7 +778,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -552,6 +813,34 @@ impl EnterpriseAdmin {
         page: i64,
     ) -> ClientResult<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>
     {
+        Ok(self
+            .list_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+                enterprise,
+                runner_group_id,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List organization access to a self-hosted runner group in an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations` endpoint.
+     *
+     * As opposed to `list_org_access_to_self_hosted_runner_group_in_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-organization-access-to-a-self-hosted-runner-group-in-a-enterprise>
+     */
+    pub async fn list_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<
+        crate::Response<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>,
+    > {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -570,7 +859,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -601,6 +890,29 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
     ) -> ClientResult<()> {
+        self.set_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+            enterprise,
+            runner_group_id,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Set organization access for a self-hosted runner group in an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations` endpoint.
+     *
+     * As opposed to `set_org_access_to_self_hosted_runner_group_in_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#set-organization-access-to-a-self-hosted-runner-group-in-an-enterprise>
+     */
+    pub async fn set_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}/organizations",
@@ -610,7 +922,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -642,6 +954,29 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         org_id: i64,
     ) -> ClientResult<()> {
+        self.add_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+            enterprise,
+            runner_group_id,
+            org_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Add organization access to a self-hosted runner group in an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}` endpoint.
+     *
+     * As opposed to `add_org_access_to_self_hosted_runner_group_in_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#add-organization-access-to-a-self-hosted-runner-group-in-an-enterprise>
+     */
+    pub async fn add_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}/organizations/{}",
@@ -652,7 +987,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -684,6 +1019,29 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         org_id: i64,
     ) -> ClientResult<()> {
+        self.remove_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+            enterprise,
+            runner_group_id,
+            org_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Remove organization access to a self-hosted runner group in an enterprise.
+     *
+     * This function performs a `DELETE` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}` endpoint.
+     *
+     * As opposed to `remove_org_access_to_self_hosted_runner_group_in_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#remove-organization-access-to-a-self-hosted-runner-group-in-an-enterprise>
+     */
+    pub async fn remove_org_access_to_self_hosted_runner_group_in_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}/organizations/{}",
@@ -694,7 +1052,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -728,6 +1086,33 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
+        Ok(self
+            .list_self_hosted_runners_in_group_for_enterprise_with_response(
+                enterprise,
+                runner_group_id,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List self-hosted runners in a group for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_in_group_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-self-hosted-runners-in-a-group-for-an-enterprise>
+     */
+    pub async fn list_self_hosted_runners_in_group_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -746,7 +1131,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -777,6 +1162,29 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
     ) -> ClientResult<()> {
+        self.set_self_hosted_runners_in_group_for_enterprise_with_response(
+            enterprise,
+            runner_group_id,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Set self-hosted runners in a group for an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners` endpoint.
+     *
+     * As opposed to `set_self_hosted_runners_in_group_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#set-self-hosted-runners-in-a-group-for-an-enterprise>
+     */
+    pub async fn set_self_hosted_runners_in_group_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}/runners",
@@ -786,7 +1194,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -819,6 +1227,29 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         runner_id: i64,
     ) -> ClientResult<()> {
+        self.add_self_hosted_runner_to_group_for_enterprise_with_response(
+            enterprise,
+            runner_group_id,
+            runner_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Add a self-hosted runner to a group for an enterprise.
+     *
+     * This function performs a `PUT` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `add_self_hosted_runner_to_group_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#add-a-self-hosted-runner-to-a-group-for-an-enterprise>
+     */
+    pub async fn add_self_hosted_runner_to_group_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}/runners/{}",
@@ -829,7 +1260,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -861,7 +1292,30 @@ impl EnterpriseAdmin {
         runner_group_id: i64,
         runner_id: i64,
     ) -> ClientResult<()> {
-        let url = self.client.url(
+        self.remove_self_hosted_runner_from_group_for_enterprise_with_response(
+            enterprise,
+            runner_group_id,
+            runner_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Remove a self-hosted runner from a group for an enterprise.
+     *
+     * This function performs a `DELETE` to the `/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `remove_self_hosted_runner_from_group_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#remove-a-self-hosted-runner-from-a-group-for-an-enterprise>
+     */
+    pub async fn remove_self_hosted_runner_from_group_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runner-groups/{}/runners/{}",
                 crate::progenitor_support::encode_path(enterprise),
@@ -871,7 +1325,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -903,6 +1357,27 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::EnterpriseAdminListSelfHostedRunnersResponse> {
+        Ok(self
+            .list_self_hosted_runners_for_enterprise_with_response(enterprise, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List self-hosted runners for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners` endpoint.
+     *
+     * As opposed to `list_self_hosted_runners_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-self-hosted-runners-for-an-enterprise>
+     */
+    pub async fn list_self_hosted_runners_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::EnterpriseAdminListSelfHostedRunnersResponse>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -920,7 +1395,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -948,6 +1423,24 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
     ) -> ClientResult<Vec<crate::types::RunnerApplication>> {
+        Ok(self
+            .list_runner_applications_for_enterprise_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * List runner applications for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_runner_applications_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-runner-applications-for-an-enterprise>
+     */
+    pub async fn list_runner_applications_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RunnerApplication>>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runners/downloads",
@@ -956,7 +1449,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -999,6 +1492,81 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * List runner applications for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_enterprise`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-runner-applications-for-an-enterprise>
+     */
+    pub fn list_all_runner_applications_for_enterprise_pages_stream(
+        &self,
+        enterprise: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::RunnerApplication>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/enterprises/{}/actions/runners/downloads",
+                crate::progenitor_support::encode_path(enterprise),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::RunnerApplication>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List runner applications for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_enterprise`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-runner-applications-for-an-enterprise>
+     */
+    pub fn list_all_runner_applications_for_enterprise_stream(
+        &self,
+        enterprise: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RunnerApplication>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_runner_applications_for_enterprise_pages_stream(enterprise, None),
+        )
+    }
     /**
      * Create a registration token for an enterprise.
      *
@@ -1026,6 +1594,24 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
     ) -> ClientResult<crate::types::AuthenticationToken> {
+        Ok(self
+            .create_registration_token_for_enterprise_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * Create a registration token for an enterprise.
+     *
+     * This function performs a `POST` to the `/enterprises/{enterprise}/actions/runners/registration-token` endpoint.
+     *
+     * As opposed to `create_registration_token_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#create-a-registration-token-for-an-enterprise>
+     */
+    pub async fn create_registration_token_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runners/registration-token",
@@ -1034,7 +1620,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1071,6 +1657,24 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
     ) -> ClientResult<crate::types::AuthenticationToken> {
+        Ok(self
+            .create_remove_token_for_enterprise_with_response(enterprise)
+            .await?
+            .body)
+    }
+    /**
+     * Create a remove token for an enterprise.
+     *
+     * This function performs a `POST` to the `/enterprises/{enterprise}/actions/runners/remove-token` endpoint.
+     *
+     * As opposed to `create_remove_token_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#create-a-remove-token-for-an-enterprise>
+     */
+    pub async fn create_remove_token_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationToken>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runners/remove-token",
@@ -1079,7 +1683,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1109,6 +1713,25 @@ impl EnterpriseAdmin {
         enterprise: &str,
         runner_id: i64,
     ) -> ClientResult<crate::types::Runner> {
+        Ok(self
+            .get_self_hosted_runner_for_enterprise_with_response(enterprise, runner_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a self-hosted runner for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `get_self_hosted_runner_for_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-a-self-hosted-runner-for-an-enterprise>
+     */
+    pub async fn get_self_hosted_runner_for_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Runner>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runners/{}",
@@ -1118,7 +1741,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1148,6 +1771,24 @@ impl EnterpriseAdmin {
         enterprise: &str,
         runner_id: i64,
     ) -> ClientResult<()> {
+        self.delete_self_hosted_runner_from_enterprise_with_response(enterprise, runner_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a self-hosted runner from an enterprise.
+     *
+     * This function performs a `DELETE` to the `/enterprises/{enterprise}/actions/runners/{runner_id}` endpoint.
+     *
+     * As opposed to `delete_self_hosted_runner_from_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#delete-self-hosted-runner-from-an-enterprise>
+     */
+    pub async fn delete_self_hosted_runner_from_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/enterprises/{}/actions/runners/{}",
@@ -1157,7 +1798,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1205,6 +1846,33 @@ impl EnterpriseAdmin {
         page: i64,
         per_page: i64,
     ) -> ClientResult<Vec<crate::types::AuditLogEvent>> {
+        Ok(self
+            .get_audit_log_with_response(
+                enterprise, phrase, include, after, before, order, page, per_page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_audit_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub async fn get_audit_log_with_response(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1237,7 +1905,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1301,6 +1969,108 @@ impl EnterpriseAdmin {
             )
             .await
     }
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub fn get_all_audit_log_pages_stream(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::AuditLogEvent>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !after.is_empty() {
+            query_args.push(("after".to_string(), after.to_string()));
+        }
+        if !before.is_empty() {
+            query_args.push(("before".to_string(), before.to_string()));
+        }
+        if !include.to_string().is_empty() {
+            query_args.push(("include".to_string(), include.to_string()));
+        }
+        if !order.to_string().is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if !phrase.is_empty() {
+            query_args.push(("phrase".to_string(), phrase.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/enterprises/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(enterprise),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::AuditLogEvent>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuditLogEvent>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_audit_log_pages_stream(
+            enterprise, phrase, include, after, before, order, None,
+        ))
+    }
     /**
      * List provisioned SCIM groups for an enterprise.
      *
@@ -1326,6 +2096,34 @@ impl EnterpriseAdmin {
         filter: &str,
         excluded_attributes: &str,
     ) -> ClientResult<crate::types::ScimGroupListEnterprise> {
+        Ok(self
+            .list_provisioned_groups_enterprise_with_response(
+                enterprise,
+                start_index,
+                count,
+                filter,
+                excluded_attributes,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List provisioned SCIM groups for an enterprise.
+     *
+     * This function performs a `GET` to the `/scim/v2/enterprises/{enterprise}/Groups` endpoint.
+     *
+     * As opposed to `list_provisioned_groups_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-provisioned-scim-groups-for-an-enterprise>
+     */
+    pub async fn list_provisioned_groups_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+        excluded_attributes: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimGroupListEnterprise>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1352,7 +2150,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1381,6 +2179,25 @@ impl EnterpriseAdmin {
         enterprise: &str,
         body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
     ) -> ClientResult<crate::types::ScimEnterpriseGroup> {
+        Ok(self
+            .provision_and_invite_enterprise_group_with_response(enterprise, body)
+            .await?
+            .body)
+    }
+    /**
+     * Provision a SCIM enterprise group and invite users.
+     *
+     * This function performs a `POST` to the `/scim/v2/enterprises/{enterprise}/Groups` endpoint.
+     *
+     * As opposed to `provision_and_invite_enterprise_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#provision-a-scim-enterprise-group-and-invite-users>
+     */
+    pub async fn provision_and_invite_enterprise_group_with_response(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Groups",
@@ -1389,7 +2206,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1419,6 +2236,30 @@ impl EnterpriseAdmin {
         scim_group_id: &str,
         excluded_attributes: &str,
     ) -> ClientResult<crate::types::ScimEnterpriseGroup> {
+        Ok(self
+            .get_provisioning_information_for_enterprise_group_with_response(
+                enterprise,
+                scim_group_id,
+                excluded_attributes,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get SCIM provisioning information for an enterprise group.
+     *
+     * This function performs a `GET` to the `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}` endpoint.
+     *
+     * As opposed to `get_provisioning_information_for_enterprise_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-scim-provisioning-information-for-an-enterprise-group>
+     */
+    pub async fn get_provisioning_information_for_enterprise_group_with_response(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        excluded_attributes: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !excluded_attributes.is_empty() {
             query_args.push((
@@ -1437,7 +2278,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1468,6 +2309,30 @@ impl EnterpriseAdmin {
         scim_group_id: &str,
         body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
     ) -> ClientResult<crate::types::ScimEnterpriseGroup> {
+        Ok(self
+            .set_information_for_provisioned_enterprise_group_with_response(
+                enterprise,
+                scim_group_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Set SCIM information for a provisioned enterprise group.
+     *
+     * This function performs a `PUT` to the `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}` endpoint.
+     *
+     * As opposed to `set_information_for_provisioned_enterprise_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#set-scim-information-for-a-provisioned-enterprise-group>
+     */
+    pub async fn set_information_for_provisioned_enterprise_group_with_response(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Groups/{}",
@@ -1477,7 +2342,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1505,6 +2370,24 @@ impl EnterpriseAdmin {
         enterprise: &str,
         scim_group_id: &str,
     ) -> ClientResult<()> {
+        self.delete_scim_group_from_enterprise_with_response(enterprise, scim_group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a SCIM group from an enterprise.
+     *
+     * This function performs a `DELETE` to the `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}` endpoint.
+     *
+     * As opposed to `delete_scim_group_from_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#delete-a-scim-group-from-an-enterprise>
+     */
+    pub async fn delete_scim_group_from_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Groups/{}",
@@ -1514,7 +2397,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1545,6 +2428,26 @@ impl EnterpriseAdmin {
         scim_group_id: &str,
         body: &crate::types::EnterpriseAdminUpdateAttributeGroupRequest,
     ) -> ClientResult<crate::types::ScimEnterpriseGroup> {
+        Ok(self
+            .update_attribute_for_enterprise_group_with_response(enterprise, scim_group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an attribute for a SCIM enterprise group.
+     *
+     * This function performs a `PATCH` to the `/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}` endpoint.
+     *
+     * As opposed to `update_attribute_for_enterprise_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#update-an-attribute-for-a-scim-enterprise-group>
+     */
+    pub async fn update_attribute_for_enterprise_group_with_response(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseGroup>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Groups/{}",
@@ -1554,7 +2457,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1603,6 +2506,32 @@ impl EnterpriseAdmin {
         count: i64,
         filter: &str,
     ) -> ClientResult<crate::types::ScimUserListEnterprise> {
+        Ok(self
+            .list_provisioned_identities_enterprise_with_response(
+                enterprise,
+                start_index,
+                count,
+                filter,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List SCIM provisioned identities for an enterprise.
+     *
+     * This function performs a `GET` to the `/scim/v2/enterprises/{enterprise}/Users` endpoint.
+     *
+     * As opposed to `list_provisioned_identities_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-scim-provisioned-identities-for-an-enterprise>
+     */
+    pub async fn list_provisioned_identities_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimUserListEnterprise>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1623,7 +2552,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1654,6 +2583,25 @@ impl EnterpriseAdmin {
         enterprise: &str,
         body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
     ) -> ClientResult<crate::types::ScimEnterpriseUser> {
+        Ok(self
+            .provision_and_invite_enterprise_user_with_response(enterprise, body)
+            .await?
+            .body)
+    }
+    /**
+     * Provision and invite a SCIM enterprise user.
+     *
+     * This function performs a `POST` to the `/scim/v2/enterprises/{enterprise}/Users` endpoint.
+     *
+     * As opposed to `provision_and_invite_enterprise_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#provision-and-invite-a-scim-enterprise-user>
+     */
+    pub async fn provision_and_invite_enterprise_user_with_response(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Users",
@@ -1662,7 +2610,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1690,6 +2638,28 @@ impl EnterpriseAdmin {
         enterprise: &str,
         scim_user_id: &str,
     ) -> ClientResult<crate::types::ScimEnterpriseUser> {
+        Ok(self
+            .get_provisioning_information_for_enterprise_user_with_response(
+                enterprise,
+                scim_user_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get SCIM provisioning information for an enterprise user.
+     *
+     * This function performs a `GET` to the `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `get_provisioning_information_for_enterprise_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-scim-provisioning-information-for-an-enterprise-user>
+     */
+    pub async fn get_provisioning_information_for_enterprise_user_with_response(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Users/{}",
@@ -1699,7 +2669,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1734,6 +2704,30 @@ impl EnterpriseAdmin {
         scim_user_id: &str,
         body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
     ) -> ClientResult<crate::types::ScimEnterpriseUser> {
+        Ok(self
+            .set_information_for_provisioned_enterprise_user_with_response(
+                enterprise,
+                scim_user_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Set SCIM information for a provisioned enterprise user.
+     *
+     * This function performs a `PUT` to the `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `set_information_for_provisioned_enterprise_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#set-scim-information-for-a-provisioned-enterprise-user>
+     */
+    pub async fn set_information_for_provisioned_enterprise_user_with_response(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Users/{}",
@@ -1743,7 +2737,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1771,6 +2765,24 @@ impl EnterpriseAdmin {
         enterprise: &str,
         scim_user_id: &str,
     ) -> ClientResult<()> {
+        self.delete_user_from_enterprise_with_response(enterprise, scim_user_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a SCIM user from an enterprise.
+     *
+     * This function performs a `DELETE` to the `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `delete_user_from_enterprise`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#delete-a-scim-user-from-an-enterprise>
+     */
+    pub async fn delete_user_from_enterprise_with_response(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Users/{}",
@@ -1780,7 +2792,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1826,6 +2838,26 @@ impl EnterpriseAdmin {
         scim_user_id: &str,
         body: &crate::types::EnterpriseAdminUpdateAttributeUserRequest,
     ) -> ClientResult<crate::types::ScimEnterpriseUser> {
+        Ok(self
+            .update_attribute_for_enterprise_user_with_response(enterprise, scim_user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an attribute for a SCIM enterprise user.
+     *
+     * This function performs a `PATCH` to the `/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `update_attribute_for_enterprise_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#update-an-attribute-for-a-scim-enterprise-user>
+     */
+    pub async fn update_attribute_for_enterprise_user_with_response(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimEnterpriseUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/enterprises/{}/Users/{}",
@@ -1835,7 +2867,7 @@ impl EnterpriseAdmin {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -32,6 +32,23 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        Ok(self.list_with_response(since, per_page, page).await?.body)
+    }
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub async fn list_with_response(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -45,7 +62,7 @@ impl Gists {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gists?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -85,6 +102,76 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BaseGist>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BaseGist>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(since, None))
+    }
     /**
      * Create a gist.
      *
@@ -100,9 +187,24 @@ impl Gists {
         &self,
         body: &crate::types::GistsCreateRequest,
     ) -> ClientResult<crate::types::GistSimple> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Create a gist.
+     *
+     * This function performs a `POST` to the `/gists` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#create-a-gist>
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::GistsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistSimple>> {
         let url = self.client.url("/gists", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -134,6 +236,26 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        Ok(self
+            .list_public_with_response(since, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_public`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub async fn list_public_with_response(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -147,7 +269,7 @@ impl Gists {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gists/public?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -189,6 +311,76 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub fn list_all_public_pages_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BaseGist>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists/public?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BaseGist>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_pages_stream(since, None))
+    }
     /**
      * List starred gists.
      *
@@ -210,6 +402,26 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        Ok(self
+            .list_starred_with_response(since, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_starred`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub async fn list_starred_with_response(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -223,7 +435,7 @@ impl Gists {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/gists/starred?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -263,6 +475,76 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_all_starred`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub fn list_all_starred_pages_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BaseGist>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/gists/starred?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BaseGist>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_all_starred`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub fn list_all_starred_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_starred_pages_stream(since, None))
+    }
     /**
      * Get a gist.
      *
@@ -277,12 +559,27 @@ impl Gists {
      * * `gist_id: &str` -- gist_id parameter.
      */
     pub async fn get(&self, gist_id: &str) -> ClientResult<crate::types::GistSimple> {
+        Ok(self.get_with_response(gist_id).await?.body)
+    }
+    /**
+     * Get a gist.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#get-a-gist>
+     */
+    pub async fn get_with_response(
+        &self,
+        gist_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GistSimple>> {
         let url = self.client.url(
             &format!("/gists/{}", crate::progenitor_support::encode_path(gist_id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -305,12 +602,25 @@ impl Gists {
      * * `gist_id: &str` -- gist_id parameter.
      */
     pub async fn delete(&self, gist_id: &str) -> ClientResult<()> {
+        self.delete_with_response(gist_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a gist.
+     *
+     * This function performs a `DELETE` to the `/gists/{gist_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#delete-a-gist>
+     */
+    pub async fn delete_with_response(&self, gist_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/gists/{}", crate::progenitor_support::encode_path(gist_id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -337,12 +647,28 @@ impl Gists {
         gist_id: &str,
         body: &crate::types::GistsUpdateRequest,
     ) -> ClientResult<crate::types::GistSimple> {
+        Ok(self.update_with_response(gist_id, body).await?.body)
+    }
+    /**
+     * Update a gist.
+     *
+     * This function performs a `PATCH` to the `/gists/{gist_id}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists/#update-a-gist>
+     */
+    pub async fn update_with_response(
+        &self,
+        gist_id: &str,
+        body: &crate::types::GistsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistSimple>> {
         let url = self.client.url(
             &format!("/gists/{}", crate::progenitor_support::encode_path(gist_id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -372,6 +698,26 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::GistComment>> {
+        Ok(self
+            .list_comments_with_response(gist_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_comments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub async fn list_comments_with_response(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -389,7 +735,7 @@ impl Gists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -430,6 +776,78 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub fn list_all_comments_pages_stream(
+        &self,
+        gist_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GistComment>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/comments",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GistComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistComment>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_comments_pages_stream(gist_id, None))
+    }
     /**
      * Create a gist comment.
      *
@@ -448,6 +866,22 @@ impl Gists {
         gist_id: &str,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::GistComment> {
+        Ok(self.create_comment_with_response(gist_id, body).await?.body)
+    }
+    /**
+     * Create a gist comment.
+     *
+     * This function performs a `POST` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `create_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#create-a-gist-comment>
+     */
+    pub async fn create_comment_with_response(
+        &self,
+        gist_id: &str,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistComment>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/comments",
@@ -456,7 +890,7 @@ impl Gists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -484,6 +918,25 @@ impl Gists {
         gist_id: &str,
         comment_id: i64,
     ) -> ClientResult<crate::types::GistComment> {
+        Ok(self
+            .get_comment_with_response(gist_id, comment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a gist comment.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `get_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#get-a-gist-comment>
+     */
+    pub async fn get_comment_with_response(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GistComment>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/comments/{}",
@@ -493,7 +946,7 @@ impl Gists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -517,6 +970,24 @@ impl Gists {
      * * `comment_id: i64` -- comment_id parameter.
      */
     pub async fn delete_comment(&self, gist_id: &str, comment_id: i64) -> ClientResult<()> {
+        self.delete_comment_with_response(gist_id, comment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a gist comment.
+     *
+     * This function performs a `DELETE` to the `/gists/{gist_id}/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `delete_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#delete-a-gist-comment>
+     */
+    pub async fn delete_comment_with_response(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/comments/{}",
@@ -526,7 +997,7 @@ impl Gists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -555,6 +1026,26 @@ impl Gists {
         comment_id: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::GistComment> {
+        Ok(self
+            .update_comment_with_response(gist_id, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a gist comment.
+     *
+     * This function performs a `PATCH` to the `/gists/{gist_id}/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `update_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#update-a-gist-comment>
+     */
+    pub async fn update_comment_with_response(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::GistComment>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/comments/{}",
@@ -564,7 +1055,7 @@ impl Gists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -594,6 +1085,26 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::GistCommit>> {
+        Ok(self
+            .list_commits_with_response(gist_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub async fn list_commits_with_response(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistCommit>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -611,7 +1122,7 @@ impl Gists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -652,6 +1163,78 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub fn list_all_commits_pages_stream(
+        &self,
+        gist_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GistCommit>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/commits",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GistCommit>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistCommit>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_commits_pages_stream(gist_id, None))
+    }
     /**
      * List gist forks.
      *
@@ -673,6 +1256,26 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::GistSimple>> {
+        Ok(self
+            .list_forks_with_response(gist_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_forks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub async fn list_forks_with_response(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GistSimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -690,7 +1293,7 @@ impl Gists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -731,6 +1334,78 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub fn list_all_forks_pages_stream(
+        &self,
+        gist_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GistSimple>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/gists/{}/forks",
+                crate::progenitor_support::encode_path(gist_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GistSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        gist_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GistSimple>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_forks_pages_stream(gist_id, None))
+    }
     /**
      * Fork a gist.
      *
@@ -745,6 +1420,21 @@ impl Gists {
      * * `gist_id: &str` -- gist_id parameter.
      */
     pub async fn fork(&self, gist_id: &str) -> ClientResult<crate::types::BaseGist> {
+        Ok(self.fork_with_response(gist_id).await?.body)
+    }
+    /**
+     * Fork a gist.
+     *
+     * This function performs a `POST` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `fork`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#fork-a-gist>
+     */
+    pub async fn fork_with_response(
+        &self,
+        gist_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BaseGist>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/forks",
@@ -753,7 +1443,7 @@ impl Gists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -776,6 +1466,22 @@ impl Gists {
      * * `gist_id: &str` -- gist_id parameter.
      */
     pub async fn check_is_starred(&self, gist_id: &str) -> ClientResult<()> {
+        self.check_is_starred_with_response(gist_id).await?;
+        Ok(())
+    }
+    /**
+     * Check if a gist is starred.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/star` endpoint.
+     *
+     * As opposed to `check_is_starred`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#check-if-a-gist-is-starred>
+     */
+    pub async fn check_is_starred_with_response(
+        &self,
+        gist_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/star",
@@ -784,7 +1490,7 @@ impl Gists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -807,6 +1513,19 @@ impl Gists {
      * * `gist_id: &str` -- gist_id parameter.
      */
     pub async fn star(&self, gist_id: &str) -> ClientResult<()> {
+        self.star_with_response(gist_id).await?;
+        Ok(())
+    }
+    /**
+     * Star a gist.
+     *
+     * This function performs a `PUT` to the `/gists/{gist_id}/star` endpoint.
+     *
+     * As opposed to `star`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#star-a-gist>
+     */
+    pub async fn star_with_response(&self, gist_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/star",
@@ -815,7 +1534,7 @@ impl Gists {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -838,6 +1557,19 @@ impl Gists {
      * * `gist_id: &str` -- gist_id parameter.
      */
     pub async fn unstar(&self, gist_id: &str) -> ClientResult<()> {
+        self.unstar_with_response(gist_id).await?;
+        Ok(())
+    }
+    /**
+     * Unstar a gist.
+     *
+     * This function performs a `DELETE` to the `/gists/{gist_id}/star` endpoint.
+     *
+     * As opposed to `unstar`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#unstar-a-gist>
+     */
+    pub async fn unstar_with_response(&self, gist_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/star",
@@ -846,7 +1578,7 @@ impl Gists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -874,6 +1606,22 @@ impl Gists {
         gist_id: &str,
         sha: &str,
     ) -> ClientResult<crate::types::GistSimple> {
+        Ok(self.get_revision_with_response(gist_id, sha).await?.body)
+    }
+    /**
+     * Get a gist revision.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/{sha}` endpoint.
+     *
+     * As opposed to `get_revision`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#get-a-gist-revision>
+     */
+    pub async fn get_revision_with_response(
+        &self,
+        gist_id: &str,
+        sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::GistSimple>> {
         let url = self.client.url(
             &format!(
                 "/gists/{}/{}",
@@ -883,7 +1631,7 @@ impl Gists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -915,6 +1663,27 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::BaseGist>> {
+        Ok(self
+            .list_for_user_with_response(username, since, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub async fn list_for_user_with_response(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BaseGist>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -935,7 +1704,7 @@ impl Gists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -983,4 +1752,83 @@ impl Gists {
             )
             .await
     }
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub fn list_all_for_user_pages_stream(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BaseGist>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/gists?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BaseGist>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BaseGist>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_user_pages_stream(username, since, None))
+    }
 }
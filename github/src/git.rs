@@ -31,6 +31,26 @@ impl Git {
         repo: &str,
         body: &crate::types::GitCreateBlobRequest,
     ) -> ClientResult<crate::types::Tree> {
+        Ok(self
+            .create_blob_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a blob.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/git/blobs` endpoint.
+     *
+     * As opposed to `create_blob`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#create-a-blob>
+     */
+    pub async fn create_blob_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateBlobRequest,
+    ) -> ClientResult<crate::Response<crate::types::Tree>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/blobs",
@@ -40,7 +60,7 @@ impl Git {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -72,6 +92,26 @@ impl Git {
         repo: &str,
         file_sha: &str,
     ) -> ClientResult<crate::types::Blob> {
+        Ok(self
+            .get_blob_with_response(owner, repo, file_sha)
+            .await?
+            .body)
+    }
+    /**
+     * Get a blob.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/blobs/{file_sha}` endpoint.
+     *
+     * As opposed to `get_blob`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#get-a-blob>
+     */
+    pub async fn get_blob_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        file_sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::Blob>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/blobs/{}",
@@ -82,7 +122,7 @@ impl Git {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -140,6 +180,26 @@ impl Git {
         repo: &str,
         body: &crate::types::GitCreateCommitRequest,
     ) -> ClientResult<crate::types::GitCommit> {
+        Ok(self
+            .create_commit_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a commit.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/git/commits` endpoint.
+     *
+     * As opposed to `create_commit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#create-a-commit>
+     */
+    pub async fn create_commit_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateCommitRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitCommit>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/commits",
@@ -149,7 +209,7 @@ impl Git {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -208,6 +268,26 @@ impl Git {
         repo: &str,
         commit_sha: &str,
     ) -> ClientResult<crate::types::GitCommit> {
+        Ok(self
+            .get_commit_with_response(owner, repo, commit_sha)
+            .await?
+            .body)
+    }
+    /**
+     * Get a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/commits/{commit_sha}` endpoint.
+     *
+     * As opposed to `get_commit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#get-a-commit>
+     */
+    pub async fn get_commit_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitCommit>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/commits/{}",
@@ -218,7 +298,7 @@ impl Git {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -258,6 +338,28 @@ impl Git {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::GitRef>> {
+        Ok(self
+            .list_matching_refs_with_response(owner, repo, ref_, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_matching_refs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub async fn list_matching_refs_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitRef>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -277,7 +379,7 @@ impl Git {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -328,6 +430,80 @@ impl Git {
             )
             .await
     }
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_all_matching_refs`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub fn list_all_matching_refs_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GitRef>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/git/matching-refs/{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::GitRef>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_all_matching_refs`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub fn list_all_matching_refs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GitRef>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_matching_refs_pages_stream(owner, repo, ref_, None))
+    }
     /**
      * Get a reference.
      *
@@ -351,6 +527,23 @@ impl Git {
         repo: &str,
         ref_: &str,
     ) -> ClientResult<crate::types::GitRef> {
+        Ok(self.get_ref_with_response(owner, repo, ref_).await?.body)
+    }
+    /**
+     * Get a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/ref/{ref}` endpoint.
+     *
+     * As opposed to `get_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#get-a-reference>
+     */
+    pub async fn get_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitRef>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/ref/{}",
@@ -361,7 +554,7 @@ impl Git {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -390,6 +583,23 @@ impl Git {
         repo: &str,
         body: &crate::types::GitCreateRefRequest,
     ) -> ClientResult<crate::types::GitRef> {
+        Ok(self.create_ref_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Create a reference.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/git/refs` endpoint.
+     *
+     * As opposed to `create_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#create-a-reference>
+     */
+    pub async fn create_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateRefRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitRef>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/refs",
@@ -399,7 +609,7 @@ impl Git {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -424,6 +634,24 @@ impl Git {
      * * `ref_: &str` -- ref parameter.
      */
     pub async fn delete_ref(&self, owner: &str, repo: &str, ref_: &str) -> ClientResult<()> {
+        self.delete_ref_with_response(owner, repo, ref_).await?;
+        Ok(())
+    }
+    /**
+     * Delete a reference.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/git/refs/{ref}` endpoint.
+     *
+     * As opposed to `delete_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#delete-a-reference>
+     */
+    pub async fn delete_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/refs/{}",
@@ -434,7 +662,7 @@ impl Git {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -465,6 +693,27 @@ impl Git {
         ref_: &str,
         body: &crate::types::GitUpdateRefRequest,
     ) -> ClientResult<crate::types::GitRef> {
+        Ok(self
+            .update_ref_with_response(owner, repo, ref_, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a reference.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/git/refs/{ref}` endpoint.
+     *
+     * As opposed to `update_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#update-a-reference>
+     */
+    pub async fn update_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        body: &crate::types::GitUpdateRefRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitRef>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/refs/{}",
@@ -475,7 +724,7 @@ impl Git {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -533,6 +782,23 @@ impl Git {
         repo: &str,
         body: &crate::types::GitCreateTagRequest,
     ) -> ClientResult<crate::types::GitTag> {
+        Ok(self.create_tag_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Create a tag object.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/git/tags` endpoint.
+     *
+     * As opposed to `create_tag`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#create-a-tag-object>
+     */
+    pub async fn create_tag_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTagRequest,
+    ) -> ClientResult<crate::Response<crate::types::GitTag>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/tags",
@@ -542,7 +808,7 @@ impl Git {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -599,6 +865,23 @@ impl Git {
         repo: &str,
         tag_sha: &str,
     ) -> ClientResult<crate::types::GitTag> {
+        Ok(self.get_tag_with_response(owner, repo, tag_sha).await?.body)
+    }
+    /**
+     * Get a tag.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/tags/{tag_sha}` endpoint.
+     *
+     * As opposed to `get_tag`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#get-a-tag>
+     */
+    pub async fn get_tag_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_sha: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitTag>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/tags/{}",
@@ -609,7 +892,7 @@ impl Git {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -640,6 +923,26 @@ impl Git {
         repo: &str,
         body: &crate::types::GitCreateTreeRequestData,
     ) -> ClientResult<crate::types::GitTreeData> {
+        Ok(self
+            .create_tree_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a tree.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/git/trees` endpoint.
+     *
+     * As opposed to `create_tree`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#create-a-tree>
+     */
+    pub async fn create_tree_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTreeRequestData,
+    ) -> ClientResult<crate::Response<crate::types::GitTreeData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/git/trees",
@@ -649,7 +952,7 @@ impl Git {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -683,6 +986,27 @@ impl Git {
         tree_sha: &str,
         recursive: &str,
     ) -> ClientResult<crate::types::GitTreeData> {
+        Ok(self
+            .get_tree_with_response(owner, repo, tree_sha, recursive)
+            .await?
+            .body)
+    }
+    /**
+     * Get a tree.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/trees/{tree_sha}` endpoint.
+     *
+     * As opposed to `get_tree`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#get-a-tree>
+     */
+    pub async fn get_tree_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+        recursive: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitTreeData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !recursive.is_empty() {
             query_args.push(("recursive".to_string(), recursive.to_string()));
@@ -699,7 +1023,7 @@ impl Git {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
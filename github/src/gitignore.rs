@@ -21,9 +21,23 @@ impl Gitignore {
      * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
      */
     pub async fn get_all_templates(&self) -> ClientResult<Vec<String>> {
+        Ok(self.get_all_templates_with_response().await?.body)
+    }
+    /**
+     * Get all gitignore templates.
+     *
+     * This function performs a `GET` to the `/gitignore/templates` endpoint.
+     *
+     * As opposed to `get_all_templates`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
+     */
+    pub async fn get_all_templates_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
         let url = self.client.url("/gitignore/templates", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,6 +69,64 @@ impl Gitignore {
             )
             .await
     }
+    /**
+     * Get all gitignore templates.
+     *
+     * This function performs a `GET` to the `/gitignore/templates` endpoint.
+     *
+     * As opposed to `get_all_all_templates`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
+     */
+    pub fn get_all_all_templates_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<String>>> + Unpin + '_ {
+        let url = self.client.url("/gitignore/templates", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<String>) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all gitignore templates.
+     *
+     * This function performs a `GET` to the `/gitignore/templates` endpoint.
+     *
+     * As opposed to `get_all_all_templates`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
+     */
+    pub fn get_all_all_templates_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_all_templates_pages_stream(None))
+    }
     /**
      * Get a gitignore template.
      *
@@ -70,6 +142,21 @@ impl Gitignore {
      * * `name: &str`
      */
     pub async fn get_template(&self, name: &str) -> ClientResult<crate::types::GitignoreTemplate> {
+        Ok(self.get_template_with_response(name).await?.body)
+    }
+    /**
+     * Get a gitignore template.
+     *
+     * This function performs a `GET` to the `/gitignore/templates/{name}` endpoint.
+     *
+     * As opposed to `get_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-a-gitignore-template>
+     */
+    pub async fn get_template_with_response(
+        &self,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::GitignoreTemplate>> {
         let url = self.client.url(
             &format!(
                 "/gitignore/templates/{}",
@@ -78,7 +165,7 @@ impl Gitignore {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
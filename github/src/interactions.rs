@@ -28,6 +28,21 @@ impl Interactions {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+        Ok(self.get_restrictions_for_org_with_response(org).await?.body)
+    }
+    /**
+     * Get interaction restrictions for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/interaction-limits` endpoint.
+     *
+     * As opposed to `get_restrictions_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#get-interaction-restrictions-for-an-organization>
+     */
+    pub async fn get_restrictions_for_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::InteractionsGetRestrictionsResponseAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/interaction-limits",
@@ -36,7 +51,7 @@ impl Interactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -63,6 +78,25 @@ impl Interactions {
         org: &str,
         body: &crate::types::InteractionLimit,
     ) -> ClientResult<crate::types::InteractionLimits> {
+        Ok(self
+            .set_restrictions_for_org_with_response(org, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set interaction restrictions for an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/interaction-limits` endpoint.
+     *
+     * As opposed to `set_restrictions_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#set-interaction-restrictions-for-an-organization>
+     */
+    pub async fn set_restrictions_for_org_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> ClientResult<crate::Response<crate::types::InteractionLimits>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/interaction-limits",
@@ -71,7 +105,7 @@ impl Interactions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -94,6 +128,22 @@ impl Interactions {
      * * `org: &str`
      */
     pub async fn remove_restrictions_for_org(&self, org: &str) -> ClientResult<()> {
+        self.remove_restrictions_for_org_with_response(org).await?;
+        Ok(())
+    }
+    /**
+     * Remove interaction restrictions for an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/interaction-limits` endpoint.
+     *
+     * As opposed to `remove_restrictions_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#remove-interaction-restrictions-for-an-organization>
+     */
+    pub async fn remove_restrictions_for_org_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/interaction-limits",
@@ -102,7 +152,7 @@ impl Interactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,6 +180,25 @@ impl Interactions {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+        Ok(self
+            .get_restrictions_for_repo_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get interaction restrictions for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/interaction-limits` endpoint.
+     *
+     * As opposed to `get_restrictions_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#get-interaction-restrictions-for-a-repository>
+     */
+    pub async fn get_restrictions_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::InteractionsGetRestrictionsResponseAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/interaction-limits",
@@ -139,7 +208,7 @@ impl Interactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -168,6 +237,26 @@ impl Interactions {
         repo: &str,
         body: &crate::types::InteractionLimit,
     ) -> ClientResult<crate::types::InteractionLimits> {
+        Ok(self
+            .set_restrictions_for_repo_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set interaction restrictions for a repository.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/interaction-limits` endpoint.
+     *
+     * As opposed to `set_restrictions_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#set-interaction-restrictions-for-a-repository>
+     */
+    pub async fn set_restrictions_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> ClientResult<crate::Response<crate::types::InteractionLimits>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/interaction-limits",
@@ -177,7 +266,7 @@ impl Interactions {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -201,6 +290,24 @@ impl Interactions {
      * * `repo: &str`
      */
     pub async fn remove_restrictions_for_repo(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.remove_restrictions_for_repo_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove interaction restrictions for a repository.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/interaction-limits` endpoint.
+     *
+     * As opposed to `remove_restrictions_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#remove-interaction-restrictions-for-a-repository>
+     */
+    pub async fn remove_restrictions_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/interaction-limits",
@@ -210,7 +317,7 @@ impl Interactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -231,9 +338,26 @@ impl Interactions {
     pub async fn get_restrictions_for_authenticated_user(
         &self,
     ) -> ClientResult<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+        Ok(self
+            .get_restrictions_for_authenticated_user_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Get interaction restrictions for your public repositories.
+     *
+     * This function performs a `GET` to the `/user/interaction-limits` endpoint.
+     *
+     * As opposed to `get_restrictions_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#get-interaction-restrictions-for-your-public-repositories>
+     */
+    pub async fn get_restrictions_for_authenticated_user_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::InteractionsGetRestrictionsResponseAnyOf>> {
         let url = self.client.url("/user/interaction-limits", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -255,9 +379,27 @@ impl Interactions {
         &self,
         body: &crate::types::InteractionLimit,
     ) -> ClientResult<crate::types::InteractionLimits> {
+        Ok(self
+            .set_restrictions_for_authenticated_user_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Set interaction restrictions for your public repositories.
+     *
+     * This function performs a `PUT` to the `/user/interaction-limits` endpoint.
+     *
+     * As opposed to `set_restrictions_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#set-interaction-restrictions-for-your-public-repositories>
+     */
+    pub async fn set_restrictions_for_authenticated_user_with_response(
+        &self,
+        body: &crate::types::InteractionLimit,
+    ) -> ClientResult<crate::Response<crate::types::InteractionLimits>> {
         let url = self.client.url("/user/interaction-limits", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -276,9 +418,25 @@ impl Interactions {
      * FROM: <https://docs.github.com/rest/reference/interactions#remove-interaction-restrictions-from-your-public-repositories>
      */
     pub async fn remove_restrictions_for_authenticated_user(&self) -> ClientResult<()> {
+        self.remove_restrictions_for_authenticated_user_with_response()
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove interaction restrictions from your public repositories.
+     *
+     * This function performs a `DELETE` to the `/user/interaction-limits` endpoint.
+     *
+     * As opposed to `remove_restrictions_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/interactions#remove-interaction-restrictions-from-your-public-repositories>
+     */
+    pub async fn remove_restrictions_for_authenticated_user_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/user/interaction-limits", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
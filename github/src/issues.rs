@@ -65,6 +65,38 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Issue>> {
+        Ok(self
+            .list_with_response(
+                filter, state, labels, sort, direction, since, collab, orgs, owned, pulls,
+                per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub async fn list_with_response(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if collab {
             query_args.push(("collab".to_string(), collab.to_string()));
@@ -105,7 +137,7 @@ impl Issues {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/issues?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -189,6 +221,120 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Issue>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if collab {
+            query_args.push(("collab".to_string(), collab.to_string()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if orgs {
+            query_args.push(("orgs".to_string(), orgs.to_string()));
+        }
+        if owned {
+            query_args.push(("owned".to_string(), owned.to_string()));
+        }
+        if pulls {
+            query_args.push(("pulls".to_string(), pulls.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/issues?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Issue>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            filter, state, labels, sort, direction, since, collab, orgs, owned, pulls, None,
+        ))
+    }
     /**
      * List organization issues assigned to the authenticated user.
      *
@@ -234,6 +380,34 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Issue>> {
+        Ok(self
+            .list_for_org_with_response(
+                org, filter, state, labels, sort, direction, since, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub async fn list_for_org_with_response(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -269,7 +443,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -342,6 +516,109 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_org_pages_stream(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Issue>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/issues?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Issue>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_org_pages_stream(
+            org, filter, state, labels, sort, direction, since, None,
+        ))
+    }
     /**
      * List assignees.
      *
@@ -365,6 +642,27 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_assignees_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_assignees`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub async fn list_assignees_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -383,7 +681,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -426,6 +724,81 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_all_assignees`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub fn list_all_assignees_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/assignees",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_all_assignees`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub fn list_all_assignees_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_assignees_pages_stream(owner, repo, None))
+    }
     /**
      * Check if a user can be assigned.
      *
@@ -451,6 +824,25 @@ impl Issues {
         repo: &str,
         assignee: &str,
     ) -> ClientResult<()> {
+        self.check_user_can_be_assigned_with_response(owner, repo, assignee)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check if a user can be assigned.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees/{assignee}` endpoint.
+     *
+     * As opposed to `check_user_can_be_assigned`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#check-if-a-user-can-be-assigned>
+     */
+    pub async fn check_user_can_be_assigned_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        assignee: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/assignees/{}",
@@ -461,7 +853,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -518,16 +910,49 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::IssueSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignee.is_empty() {
-            query_args.push(("assignee".to_string(), assignee.to_string()));
-        }
-        if !creator.is_empty() {
-            query_args.push(("creator".to_string(), creator.to_string()));
-        }
-        if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
-        }
+        Ok(self
+            .list_for_repo_with_response(
+                owner, repo, milestone, state, assignee, creator, mentioned, labels, sort,
+                direction, since, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub async fn list_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueSimple>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !assignee.is_empty() {
+            query_args.push(("assignee".to_string(), assignee.to_string()));
+        }
+        if !creator.is_empty() {
+            query_args.push(("creator".to_string(), creator.to_string()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
         if !labels.is_empty() {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
@@ -563,7 +988,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -650,6 +1075,132 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub fn list_all_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssueSimple>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !assignee.is_empty() {
+            query_args.push(("assignee".to_string(), assignee.to_string()));
+        }
+        if !creator.is_empty() {
+            query_args.push(("creator".to_string(), creator.to_string()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if !mentioned.is_empty() {
+            query_args.push(("mentioned".to_string(), mentioned.to_string()));
+        }
+        if !milestone.is_empty() {
+            query_args.push(("milestone".to_string(), milestone.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IssueSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueSimple>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_repo_pages_stream(
+            owner, repo, milestone, state, assignee, creator, mentioned, labels, sort, direction,
+            since, None,
+        ))
+    }
     /**
      * Create an issue.
      *
@@ -672,6 +1223,23 @@ impl Issues {
         repo: &str,
         body: &crate::types::IssuesCreateRequest,
     ) -> ClientResult<crate::types::Issue> {
+        Ok(self.create_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Create an issue.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#create-an-issue>
+     */
+    pub async fn create_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Issue>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues",
@@ -681,7 +1249,7 @@ impl Issues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -721,6 +1289,32 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::IssueComment>> {
+        Ok(self
+            .list_comments_for_repo_with_response(
+                owner, repo, sort, direction, since, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_comments_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub async fn list_comments_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -748,7 +1342,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -806,6 +1400,101 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub fn list_all_comments_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssueComment>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IssueComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub fn list_all_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueComment>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_comments_for_repo_pages_stream(owner, repo, sort, direction, since, None),
+        )
+    }
     /**
      * Get an issue comment.
      *
@@ -827,6 +1516,26 @@ impl Issues {
         repo: &str,
         comment_id: i64,
     ) -> ClientResult<crate::types::IssueComment> {
+        Ok(self
+            .get_comment_with_response(owner, repo, comment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `get_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#get-an-issue-comment>
+     */
+    pub async fn get_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::IssueComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/comments/{}",
@@ -837,7 +1546,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -867,6 +1576,25 @@ impl Issues {
         repo: &str,
         comment_id: i64,
     ) -> ClientResult<()> {
+        self.delete_comment_with_response(owner, repo, comment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an issue comment.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `delete_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#delete-an-issue-comment>
+     */
+    pub async fn delete_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/comments/{}",
@@ -877,7 +1605,7 @@ impl Issues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -908,6 +1636,27 @@ impl Issues {
         comment_id: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::IssueComment> {
+        Ok(self
+            .update_comment_with_response(owner, repo, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an issue comment.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `update_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#update-an-issue-comment>
+     */
+    pub async fn update_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/comments/{}",
@@ -918,7 +1667,7 @@ impl Issues {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -950,6 +1699,27 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::IssueEvent>> {
+        Ok(self
+            .list_events_for_repo_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_events_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub async fn list_events_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueEvent>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -968,7 +1738,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1012,7 +1782,82 @@ impl Issues {
             .await
     }
     /**
-     * Get an issue event.
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub fn list_all_events_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssueEvent>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IssueEvent>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub fn list_all_events_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueEvent>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_events_for_repo_pages_stream(owner, repo, None))
+    }
+    /**
+     * Get an issue event.
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events/{event_id}` endpoint.
      *
@@ -1032,6 +1877,26 @@ impl Issues {
         repo: &str,
         event_id: i64,
     ) -> ClientResult<crate::types::IssueEvent> {
+        Ok(self
+            .get_event_with_response(owner, repo, event_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get an issue event.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events/{event_id}` endpoint.
+     *
+     * As opposed to `get_event`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#get-an-issue-event>
+     */
+    pub async fn get_event_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        event_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::IssueEvent>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/events/{}",
@@ -1042,7 +1907,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1082,6 +1947,26 @@ impl Issues {
         repo: &str,
         issue_number: i64,
     ) -> ClientResult<crate::types::Issue> {
+        Ok(self
+            .get_with_response(owner, repo, issue_number)
+            .await?
+            .body)
+    }
+    /**
+     * Get an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#get-an-issue>
+     */
+    pub async fn get_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::Issue>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}",
@@ -1092,7 +1977,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1123,6 +2008,27 @@ impl Issues {
         issue_number: i64,
         body: &crate::types::IssuesUpdateRequest,
     ) -> ClientResult<crate::types::Issue> {
+        Ok(self
+            .update_with_response(owner, repo, issue_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an issue.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/issues/{issue_number}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues/#update-an-issue>
+     */
+    pub async fn update_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Issue>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}",
@@ -1133,7 +2039,7 @@ impl Issues {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1164,6 +2070,27 @@ impl Issues {
         issue_number: i64,
         body: &crate::types::IssuesAddAssigneesRequest,
     ) -> ClientResult<crate::types::IssueSimple> {
+        Ok(self
+            .add_assignees_with_response(owner, repo, issue_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add assignees to an issue.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues/{issue_number}/assignees` endpoint.
+     *
+     * As opposed to `add_assignees`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#add-assignees-to-an-issue>
+     */
+    pub async fn add_assignees_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddAssigneesRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueSimple>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/assignees",
@@ -1174,7 +2101,7 @@ impl Issues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1205,6 +2132,27 @@ impl Issues {
         issue_number: i64,
         body: &crate::types::IssuesAddAssigneesRequest,
     ) -> ClientResult<crate::types::IssueSimple> {
+        Ok(self
+            .remove_assignees_with_response(owner, repo, issue_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove assignees from an issue.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/issues/{issue_number}/assignees` endpoint.
+     *
+     * As opposed to `remove_assignees`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#remove-assignees-from-an-issue>
+     */
+    pub async fn remove_assignees_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddAssigneesRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueSimple>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/assignees",
@@ -1215,7 +2163,7 @@ impl Issues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1251,6 +2199,29 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::IssueComment>> {
+        Ok(self
+            .list_comments_with_response(owner, repo, issue_number, since, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_comments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub async fn list_comments_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1273,7 +2244,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1325,6 +2296,98 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub fn list_all_comments_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssueComment>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IssueComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub fn list_all_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueComment>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_comments_pages_stream(
+            owner,
+            repo,
+            issue_number,
+            since,
+            None,
+        ))
+    }
     /**
      * Create an issue comment.
      *
@@ -1347,6 +2410,27 @@ impl Issues {
         issue_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::IssueComment> {
+        Ok(self
+            .create_comment_with_response(owner, repo, issue_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an issue comment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `create_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#create-an-issue-comment>
+     */
+    pub async fn create_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssueComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/comments",
@@ -1357,7 +2441,7 @@ impl Issues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1391,6 +2475,28 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::IssueEventAnyOf>> {
+        Ok(self
+            .list_events_with_response(owner, repo, issue_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List issue events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_events`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub async fn list_events_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssueEventAnyOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1410,7 +2516,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1455,6 +2561,84 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List issue events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_all_events`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub fn list_all_events_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssueEventAnyOf>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/events",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IssueEventAnyOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List issue events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_all_events`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub fn list_all_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssueEventAnyOf>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_events_pages_stream(owner, repo, issue_number, None))
+    }
     /**
      * List labels for an issue.
      *
@@ -1480,6 +2664,28 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        Ok(self
+            .list_labels_on_issue_with_response(owner, repo, issue_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_labels_on_issue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub async fn list_labels_on_issue_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1499,7 +2705,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1524,25 +2730,104 @@ impl Issues {
         owner: &str,
         repo: &str,
         issue_number: i64,
-    ) -> ClientResult<Vec<crate::types::Label>> {
-        let url = self.client.url(
-            &format!(
-                "/repos/{}/{}/issues/{}/labels",
-                crate::progenitor_support::encode_path(owner),
-                crate::progenitor_support::encode_path(repo),
-                crate::progenitor_support::encode_path(&issue_number.to_string()),
-            ),
+    ) -> ClientResult<Vec<crate::types::Label>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_on_issue`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub fn list_all_labels_on_issue_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Label>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Label>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_on_issue`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub fn list_all_labels_on_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_labels_on_issue_pages_stream(
+            owner,
+            repo,
+            issue_number,
             None,
-        );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        ))
     }
     /**
      * Set labels for an issue.
@@ -1566,6 +2851,27 @@ impl Issues {
         issue_number: i64,
         body: &crate::types::IssuesSetLabelsRequestAnyOf,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        Ok(self
+            .set_labels_with_response(owner, repo, issue_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set labels for an issue.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `set_labels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#set-labels-for-an-issue>
+     */
+    pub async fn set_labels_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesSetLabelsRequestAnyOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/labels",
@@ -1576,7 +2882,7 @@ impl Issues {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1607,6 +2913,27 @@ impl Issues {
         issue_number: i64,
         body: &crate::types::IssuesAddLabelsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        Ok(self
+            .add_labels_with_response(owner, repo, issue_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add labels to an issue.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `add_labels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#add-labels-to-an-issue>
+     */
+    pub async fn add_labels_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddLabelsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/labels",
@@ -1617,7 +2944,7 @@ impl Issues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1647,6 +2974,25 @@ impl Issues {
         repo: &str,
         issue_number: i64,
     ) -> ClientResult<()> {
+        self.remove_all_labels_with_response(owner, repo, issue_number)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove all labels from an issue.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `remove_all_labels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#remove-all-labels-from-an-issue>
+     */
+    pub async fn remove_all_labels_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/labels",
@@ -1657,7 +3003,7 @@ impl Issues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1689,6 +3035,27 @@ impl Issues {
         issue_number: i64,
         name: &str,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        Ok(self
+            .remove_label_with_response(owner, repo, issue_number, name)
+            .await?
+            .body)
+    }
+    /**
+     * Remove a label from an issue.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}` endpoint.
+     *
+     * As opposed to `remove_label`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#remove-a-label-from-an-issue>
+     */
+    pub async fn remove_label_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        name: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/labels/{}",
@@ -1700,7 +3067,7 @@ impl Issues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1733,6 +3100,26 @@ impl Issues {
         issue_number: i64,
         body: &crate::types::IssuesLockRequest,
     ) -> ClientResult<()> {
+        self.lock_with_response(owner, repo, issue_number, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Lock an issue.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/issues/{issue_number}/lock` endpoint.
+     *
+     * As opposed to `lock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#lock-an-issue>
+     */
+    pub async fn lock_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesLockRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/lock",
@@ -1743,7 +3130,7 @@ impl Issues {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1768,6 +3155,24 @@ impl Issues {
      * * `issue_number: i64` -- issue_number parameter.
      */
     pub async fn unlock(&self, owner: &str, repo: &str, issue_number: i64) -> ClientResult<()> {
+        self.unlock_with_response(owner, repo, issue_number).await?;
+        Ok(())
+    }
+    /**
+     * Unlock an issue.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/issues/{issue_number}/lock` endpoint.
+     *
+     * As opposed to `unlock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#unlock-an-issue>
+     */
+    pub async fn unlock_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/lock",
@@ -1778,7 +3183,7 @@ impl Issues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1812,6 +3217,28 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Data>> {
+        Ok(self
+            .list_events_for_timeline_with_response(owner, repo, issue_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_events_for_timeline`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub async fn list_events_for_timeline_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1831,7 +3258,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1876,6 +3303,85 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_all_events_for_timeline`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub fn list_all_events_for_timeline_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Data>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/timeline",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Data>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_all_events_for_timeline`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub fn list_all_events_for_timeline_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Data>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_events_for_timeline_pages_stream(
+            owner,
+            repo,
+            issue_number,
+            None,
+        ))
+    }
     /**
      * List labels for a repository.
      *
@@ -1899,6 +3405,27 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        Ok(self
+            .list_labels_for_repo_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_labels_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub async fn list_labels_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1917,7 +3444,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1960,6 +3487,77 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub fn list_all_labels_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Label>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Label>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub fn list_all_labels_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_labels_for_repo_pages_stream(owner, repo, None))
+    }
     /**
      * Create a label.
      *
@@ -1980,6 +3578,26 @@ impl Issues {
         repo: &str,
         body: &crate::types::IssuesCreateLabelRequest,
     ) -> ClientResult<crate::types::Label> {
+        Ok(self
+            .create_label_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a label.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `create_label`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#create-a-label>
+     */
+    pub async fn create_label_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateLabelRequest,
+    ) -> ClientResult<crate::Response<crate::types::Label>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/labels",
@@ -1989,7 +3607,7 @@ impl Issues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2019,6 +3637,23 @@ impl Issues {
         repo: &str,
         name: &str,
     ) -> ClientResult<crate::types::Label> {
+        Ok(self.get_label_with_response(owner, repo, name).await?.body)
+    }
+    /**
+     * Get a label.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels/{name}` endpoint.
+     *
+     * As opposed to `get_label`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#get-a-label>
+     */
+    pub async fn get_label_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Label>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/labels/{}",
@@ -2029,7 +3664,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2054,6 +3689,24 @@ impl Issues {
      * * `name: &str`
      */
     pub async fn delete_label(&self, owner: &str, repo: &str, name: &str) -> ClientResult<()> {
+        self.delete_label_with_response(owner, repo, name).await?;
+        Ok(())
+    }
+    /**
+     * Delete a label.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/labels/{name}` endpoint.
+     *
+     * As opposed to `delete_label`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#delete-a-label>
+     */
+    pub async fn delete_label_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/labels/{}",
@@ -2064,7 +3717,7 @@ impl Issues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2088,13 +3741,34 @@ impl Issues {
      * * `repo: &str`
      * * `name: &str`
      */
-    pub async fn update_label(
+    pub async fn update_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        body: &crate::types::IssuesUpdateLabelRequest,
+    ) -> ClientResult<crate::types::Label> {
+        Ok(self
+            .update_label_with_response(owner, repo, name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a label.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/labels/{name}` endpoint.
+     *
+     * As opposed to `update_label`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#update-a-label>
+     */
+    pub async fn update_label_with_response(
         &self,
         owner: &str,
         repo: &str,
         name: &str,
         body: &crate::types::IssuesUpdateLabelRequest,
-    ) -> ClientResult<crate::types::Label> {
+    ) -> ClientResult<crate::Response<crate::types::Label>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/labels/{}",
@@ -2105,7 +3779,7 @@ impl Issues {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2145,6 +3819,30 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Milestone>> {
+        Ok(self
+            .list_milestones_with_response(owner, repo, state, sort, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_milestones`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub async fn list_milestones_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Milestone>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -2172,7 +3870,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2230,6 +3928,101 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_all_milestones`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub fn list_all_milestones_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Milestone>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/milestones?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Milestone>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_all_milestones`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub fn list_all_milestones_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Milestone>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_milestones_pages_stream(owner, repo, state, sort, direction, None),
+        )
+    }
     /**
      * Create a milestone.
      *
@@ -2250,6 +4043,26 @@ impl Issues {
         repo: &str,
         body: &crate::types::IssuesCreateMilestoneRequest,
     ) -> ClientResult<crate::types::Milestone> {
+        Ok(self
+            .create_milestone_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a milestone.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `create_milestone`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#create-a-milestone>
+     */
+    pub async fn create_milestone_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateMilestoneRequest,
+    ) -> ClientResult<crate::Response<crate::types::Milestone>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/milestones",
@@ -2259,7 +4072,7 @@ impl Issues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2289,6 +4102,26 @@ impl Issues {
         repo: &str,
         milestone_number: i64,
     ) -> ClientResult<crate::types::Milestone> {
+        Ok(self
+            .get_milestone_with_response(owner, repo, milestone_number)
+            .await?
+            .body)
+    }
+    /**
+     * Get a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}` endpoint.
+     *
+     * As opposed to `get_milestone`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#get-a-milestone>
+     */
+    pub async fn get_milestone_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::Milestone>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/milestones/{}",
@@ -2299,7 +4132,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2329,6 +4162,25 @@ impl Issues {
         repo: &str,
         milestone_number: i64,
     ) -> ClientResult<()> {
+        self.delete_milestone_with_response(owner, repo, milestone_number)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a milestone.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/milestones/{milestone_number}` endpoint.
+     *
+     * As opposed to `delete_milestone`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#delete-a-milestone>
+     */
+    pub async fn delete_milestone_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/milestones/{}",
@@ -2339,7 +4191,7 @@ impl Issues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2370,6 +4222,27 @@ impl Issues {
         milestone_number: i64,
         body: &crate::types::IssuesCreateMilestoneRequest,
     ) -> ClientResult<crate::types::Milestone> {
+        Ok(self
+            .update_milestone_with_response(owner, repo, milestone_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a milestone.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/milestones/{milestone_number}` endpoint.
+     *
+     * As opposed to `update_milestone`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#update-a-milestone>
+     */
+    pub async fn update_milestone_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        body: &crate::types::IssuesCreateMilestoneRequest,
+    ) -> ClientResult<crate::Response<crate::types::Milestone>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/milestones/{}",
@@ -2380,7 +4253,7 @@ impl Issues {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2414,6 +4287,28 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Label>> {
+        Ok(self
+            .list_labels_for_milestone_with_response(owner, repo, milestone_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_labels_for_milestone`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub async fn list_labels_for_milestone_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Label>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2433,7 +4328,7 @@ impl Issues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2478,6 +4373,85 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_milestone`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub fn list_all_labels_for_milestone_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Label>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/milestones/{}/labels",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&milestone_number.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Label>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_milestone`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub fn list_all_labels_for_milestone_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Label>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_labels_for_milestone_pages_stream(
+            owner,
+            repo,
+            milestone_number,
+            None,
+        ))
+    }
     /**
      * List user account issues assigned to the authenticated user.
      *
@@ -2521,6 +4495,33 @@ impl Issues {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Issue>> {
+        Ok(self
+            .list_for_authenticated_user_with_response(
+                filter, state, labels, sort, direction, since, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub async fn list_for_authenticated_user_with_response(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Issue>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -2549,7 +4550,7 @@ impl Issues {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/issues?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2614,4 +4615,98 @@ impl Issues {
             )
             .await
     }
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_pages_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Issue>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !labels.is_empty() {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/issues?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Issue>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Issue>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_authenticated_user_pages_stream(
+            filter, state, labels, sort, direction, since, None,
+        ))
+    }
 }
@@ -157,6 +157,24 @@
 //! );
 //! ```
 //!
+//! ## Receiving webhooks
+//!
+//! The `webhooks` module verifies the `X-Hub-Signature-256` header of a delivery
+//! and parses the payload into a typed event, keyed by the `X-GitHub-Event` header.
+//!
+//! ```rust
+//! use octorust::webhooks::{verify_and_parse, WebhookEvent};
+//!
+//! fn handle(secret: &str, headers: &http::HeaderMap, body: &[u8]) -> Result<(), octorust::ClientError> {
+//!     match verify_and_parse(secret, headers, body)? {
+//!         WebhookEvent::Push(push) => println!("{} was pushed to {}", push.after, push.ref_),
+//!         WebhookEvent::PullRequest(pr) => println!("pull request {} was {}", pr.number, pr.action),
+//!         _ => {}
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
 //! ## Acknowledgements
 //!
 //! Shout out to [hubcaps](https://github.com/softprops/hubcaps) for paving the
@@ -241,6 +259,7 @@ pub mod types;
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
+pub mod webhooks;
 
 use thiserror::Error;
 type ClientResult<T> = Result<T, ClientError>;
@@ -255,6 +274,20 @@ pub enum ClientError {
     /// JWT errors from auth.rs
     #[error(transparent)]
     JsonWebTokenError(#[from] jsonwebtoken::errors::Error),
+    /// Webhook signature errors from webhooks.rs
+    #[error("Invalid webhook signature: {0}")]
+    InvalidWebhookSignature(String),
+    /// A webhook delivery is missing a header or has a malformed body
+    #[error("Invalid webhook payload: {0}")]
+    InvalidWebhookPayload(String),
+    /// GitHub responded with an error, `body` is the raw response
+    #[error("GitHub Error. Code: {status}, message: {}", .error.message)]
+    GitHubError {
+        status: http::StatusCode,
+        error: Box<crate::utils::GitHubApiError>,
+        body: String,
+        request_id: Option<String>,
+    },
     /// IO Errors
     #[cfg(feature = "httpcache")]
     #[error(transparent)]
@@ -266,6 +299,9 @@ pub enum ClientError {
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// A response did not match the generated types, in strict mode
+    #[error("Unexpected response at `{path}`: {error}")]
+    DeserializationError { path: String, error: String },
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -280,11 +316,15 @@ pub enum ClientError {
     HttpError {
         status: http::StatusCode,
         error: String,
+        request_id: Option<String>,
     },
 }
 
 pub const FALLBACK_HOST: &str = "https://api.github.com";
 
+/// The header the API sends the id of a request back in.
+pub const REQUEST_ID_HEADER: &str = "x-github-request-id";
+
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
@@ -311,6 +351,44 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// A response from the API: the status code, headers and raw bytes of the
+/// response, along with the parsed body.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub raw: bytes::Bytes,
+    pub body: T,
+}
+
+impl<T> Response<T> {
+    /// Maps the parsed body, keeping the rest of the response.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            status: self.status,
+            headers: self.headers,
+            raw: self.raw,
+            body: f(self.body),
+        }
+    }
+
+    /// The id the API gave this request, if it sent one. This is the id to hand
+    /// to the API's support when something goes wrong.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+    }
+}
+
+/// The id the API gave a request, kept on the errors so it can be logged.
+pub(crate) fn request_id(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct RootDefaultServer {}
 
@@ -328,6 +406,9 @@ pub struct Client {
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -339,16 +420,13 @@ impl Client {
         C: Into<Option<crate::auth::Credentials>>,
     {
         let http = reqwest::Client::builder().build()?;
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         let client = reqwest_middleware::ClientBuilder::new(http)
             // Trace HTTP requests. See the tracing crate to make use of these traces.
             .with(reqwest_tracing::TracingMiddleware::default())
             // Retry failed requests.
-            .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                |req: &reqwest::Request| req.try_clone().is_some(),
-            ))
+            .with(crate::utils::RetryMiddleware::default())
+            // Stay under the rate limits, see `Client::set_rate_limiter`.
+            .with(crate::utils::RateLimitMiddleware)
             .build();
 
         #[cfg(feature = "httpcache")]
@@ -383,6 +461,9 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            coercions: Default::default(),
+            retry: Default::default(),
+            limiter: None,
             http_cache,
         }
     }
@@ -403,6 +484,9 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            coercions: Default::default(),
+            retry: Default::default(),
+            limiter: None,
         }
     }
 
@@ -435,6 +519,47 @@ impl Client {
         )
     }
 
+    /// Return an error with the JSON path of the offending field when a response
+    /// does not match the generated types, instead of falling back to defaults.
+    pub fn set_strict_deserialization(&mut self, enabled: bool) -> &mut Self {
+        self.coercions.strict = enabled;
+        self
+    }
+
+    /// The fields of past responses that did not match the generated types and
+    /// were replaced with their default value. The log is shared between clones
+    /// of the client and keeps the most recent 1000 fields.
+    pub fn coercions(&self) -> Vec<crate::utils::Coercion> {
+        self.coercions.list()
+    }
+
+    /// Clears the log of coerced fields.
+    pub fn clear_coercions(&self) {
+        self.coercions.clear();
+    }
+
+    /// Sets when and how often failed requests are retried.
+    pub fn set_retry_config(&mut self, config: crate::utils::RetryConfig) -> &mut Self {
+        self.retry = config;
+        self
+    }
+
+    /// Queues requests to stay under the rate limits of the API. The clones
+    /// made from the client afterwards share the same limiter.
+    pub fn set_rate_limiter(&mut self, limiter: crate::utils::RateLimiter) -> &mut Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Carries the retry config and rate limiter to the middleware.
+    fn request_extensions(&self) -> task_local_extensions::Extensions {
+        let mut extensions = self.retry.extensions();
+        if let Some(limiter) = &self.limiter {
+            extensions.insert(limiter.clone());
+        }
+        extensions
+    }
+
     pub fn set_credentials<CR>(&mut self, credentials: CR)
     where
         CR: Into<Option<crate::auth::Credentials>>,
@@ -531,7 +656,7 @@ impl Client {
         message: Message,
         media_type: crate::utils::MediaType,
         authentication: crate::auth::AuthenticationConstraint,
-    ) -> ClientResult<(Option<crate::utils::NextLink>, Out)>
+    ) -> ClientResult<(Option<crate::utils::NextLink>, crate::Response<Out>)>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -570,7 +695,9 @@ impl Client {
         if let Some(body) = message.body {
             req = req.body(body);
         }
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         #[cfg(feature = "httpcache")]
         let instance2 = <&Client>::clone(&self);
@@ -585,6 +712,8 @@ impl Client {
         let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -616,9 +745,17 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok((next_link, parsed_response))
+            Ok((
+                next_link,
+                crate::Response {
+                    status,
+                    headers,
+                    raw: response_body,
+                    body: parsed_response,
+                },
+            ))
         } else if status == http::StatusCode::NOT_MODIFIED {
             // only supported case is when client provides if-none-match
             // header when cargo builds with --cfg feature="httpcache"
@@ -633,7 +770,17 @@ impl Client {
                         .lookup_next_link(&uri3)
                         .map(|next_link| next_link.map(crate::utils::NextLink)),
                 };
-                link.map(|link| (link, out))
+                link.map(|link| {
+                    (
+                        link,
+                        crate::Response {
+                            status,
+                            headers,
+                            raw: body.into(),
+                            body: out,
+                        },
+                    )
+                })
             }
             #[cfg(not(feature = "httpcache"))]
             {
@@ -655,11 +802,17 @@ impl Client {
                         ClientError::HttpError {
                             status,
                             error: "empty response".into(),
+                            request_id,
                         }
+                    } else if let Some(error) =
+                        crate::utils::github_error(status, &response_body, request_id.clone())
+                    {
+                        error
                     } else {
                         ClientError::HttpError {
                             status,
                             error: String::from_utf8_lossy(&response_body).into(),
+                            request_id,
                         }
                     }
                 }
@@ -675,7 +828,7 @@ impl Client {
         message: Message,
         media_type: crate::utils::MediaType,
         authentication: crate::auth::AuthenticationConstraint,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -685,7 +838,19 @@ impl Client {
         Ok(r)
     }
 
+    #[allow(dead_code)]
     async fn get<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.get_with_response(uri, message).await?.body)
+    }
+
+    async fn get_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -698,7 +863,7 @@ impl Client {
         uri: &str,
         media: crate::utils::MediaType,
         message: Message,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -726,14 +891,16 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        self.request(
-            http::Method::GET,
-            uri,
-            Message::default(),
-            crate::utils::MediaType::Json,
-            crate::auth::AuthenticationConstraint::Unconstrained,
-        )
-        .await
+        let (link, r) = self
+            .request(
+                http::Method::GET,
+                uri,
+                Message::default(),
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+        Ok((link, r.body))
     }
 
     async fn get_pages_url<D>(
@@ -743,17 +910,31 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        self.request(
-            http::Method::GET,
-            url.as_str(),
-            Message::default(),
-            crate::utils::MediaType::Json,
-            crate::auth::AuthenticationConstraint::Unconstrained,
-        )
-        .await
+        let (link, r) = self
+            .request(
+                http::Method::GET,
+                url.as_str(),
+                Message::default(),
+                crate::utils::MediaType::Json,
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+        Ok((link, r.body))
     }
 
+    #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_with_response(uri, message).await?.body)
+    }
+
+    async fn post_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -772,7 +953,7 @@ impl Client {
         message: Message,
         media: crate::utils::MediaType,
         authentication: crate::auth::AuthenticationConstraint,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -785,7 +966,7 @@ impl Client {
         uri: &str,
         message: Message,
         media: crate::utils::MediaType,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -799,7 +980,19 @@ impl Client {
         .await
     }
 
+    #[allow(dead_code)]
     async fn patch<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.patch_with_response(uri, message).await?.body)
+    }
+
+    async fn patch_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -807,7 +1000,19 @@ impl Client {
             .await
     }
 
+    #[allow(dead_code)]
     async fn put<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.put_with_response(uri, message).await?.body)
+    }
+
+    async fn put_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -820,7 +1025,7 @@ impl Client {
         uri: &str,
         message: Message,
         media: crate::utils::MediaType,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -834,7 +1039,19 @@ impl Client {
         .await
     }
 
+    #[allow(dead_code)]
     async fn delete<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.delete_with_response(uri, message).await?.body)
+    }
+
+    async fn delete_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -32,6 +32,26 @@ impl Licenses {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::LicenseSimple>> {
+        Ok(self
+            .get_all_commonly_used_with_response(featured, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_commonly_used`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub async fn get_all_commonly_used_with_response(
+        &self,
+        featured: bool,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LicenseSimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if featured {
             query_args.push(("featured".to_string(), featured.to_string()));
@@ -45,7 +65,7 @@ impl Licenses {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/licenses?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -85,6 +105,77 @@ impl Licenses {
             )
             .await
     }
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_all_commonly_used`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub fn get_all_all_commonly_used_pages_stream(
+        &self,
+        featured: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::LicenseSimple>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if featured {
+            query_args.push(("featured".to_string(), featured.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/licenses?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::LicenseSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_all_commonly_used`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub fn get_all_all_commonly_used_stream(
+        &self,
+        featured: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LicenseSimple>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_all_commonly_used_pages_stream(featured, None))
+    }
     /**
      * Get a license.
      *
@@ -99,6 +190,21 @@ impl Licenses {
      * * `license: &str`
      */
     pub async fn get(&self, license: &str) -> ClientResult<crate::types::LicenseData> {
+        Ok(self.get_with_response(license).await?.body)
+    }
+    /**
+     * Get a license.
+     *
+     * This function performs a `GET` to the `/licenses/{license}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-a-license>
+     */
+    pub async fn get_with_response(
+        &self,
+        license: &str,
+    ) -> ClientResult<crate::Response<crate::types::LicenseData>> {
         let url = self.client.url(
             &format!(
                 "/licenses/{}",
@@ -107,7 +213,7 @@ impl Licenses {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -137,6 +243,22 @@ impl Licenses {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::LicenseContent> {
+        Ok(self.get_for_repo_with_response(owner, repo).await?.body)
+    }
+    /**
+     * Get the license for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/license` endpoint.
+     *
+     * As opposed to `get_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses/#get-the-license-for-a-repository>
+     */
+    pub async fn get_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::LicenseContent>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/license",
@@ -146,7 +268,7 @@ impl Licenses {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -21,9 +21,24 @@ impl Markdown {
      * FROM: <https://docs.github.com/rest/reference/markdown#render-a-markdown-document>
      */
     pub async fn render(&self, body: &crate::types::MarkdownRenderRequest) -> ClientResult<String> {
+        Ok(self.render_with_response(body).await?.body)
+    }
+    /**
+     * Render a Markdown document.
+     *
+     * This function performs a `POST` to the `/markdown` endpoint.
+     *
+     * As opposed to `render`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/markdown#render-a-markdown-document>
+     */
+    pub async fn render_with_response(
+        &self,
+        body: &crate::types::MarkdownRenderRequest,
+    ) -> ClientResult<crate::Response<String>> {
         let url = self.client.url("/markdown", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -42,9 +57,24 @@ impl Markdown {
      * FROM: <https://docs.github.com/rest/reference/markdown#render-a-markdown-document-in-raw-mode>
      */
     pub async fn render_raw<T: Into<reqwest::Body>>(&self, body: T) -> ClientResult<String> {
+        Ok(self.render_raw_with_response::<T>(body).await?.body)
+    }
+    /**
+     * Render a Markdown document in raw mode.
+     *
+     * This function performs a `POST` to the `/markdown/raw` endpoint.
+     *
+     * As opposed to `render_raw`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/markdown#render-a-markdown-document-in-raw-mode>
+     */
+    pub async fn render_raw_with_response<T: Into<reqwest::Body>>(
+        &self,
+        body: T,
+    ) -> ClientResult<crate::Response<String>> {
         let url = self.client.url("/markdown/raw", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(body.into()),
@@ -21,9 +21,23 @@ impl Meta {
      * FROM: <https://docs.github.com/rest/overview/resources-in-the-rest-api#root-endpoint>
      */
     pub async fn root(&self) -> ClientResult<crate::types::MetaRootResponse> {
+        Ok(self.root_with_response().await?.body)
+    }
+    /**
+     * GitHub API Root.
+     *
+     * This function performs a `GET` to the `/` endpoint.
+     *
+     * As opposed to `root`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/overview/resources-in-the-rest-api#root-endpoint>
+     */
+    pub async fn root_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MetaRootResponse>> {
         let url = self.client.url("", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -44,9 +58,23 @@ impl Meta {
      * FROM: <https://docs.github.com/rest/reference/meta#get-github-meta-information>
      */
     pub async fn get(&self) -> ClientResult<crate::types::ApiOverview> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * Get GitHub meta information.
+     *
+     * This function performs a `GET` to the `/meta` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/meta#get-github-meta-information>
+     */
+    pub async fn get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ApiOverview>> {
         let url = self.client.url("/meta", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +97,21 @@ impl Meta {
      * * `s: &str` -- The words to show in Octocat's speech bubble.
      */
     pub async fn get_octocat(&self, s: &str) -> ClientResult<String> {
+        Ok(self.get_octocat_with_response(s).await?.body)
+    }
+    /**
+     * Get Octocat.
+     *
+     * This function performs a `GET` to the `/octocat` endpoint.
+     *
+     * As opposed to `get_octocat`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/meta#get-octocat>
+     */
+    pub async fn get_octocat_with_response(
+        &self,
+        s: &str,
+    ) -> ClientResult<crate::Response<String>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !s.is_empty() {
             query_args.push(("s".to_string(), s.to_string()));
@@ -76,7 +119,7 @@ impl Meta {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/octocat?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -93,9 +136,19 @@ impl Meta {
      * Get a random sentence from the Zen of GitHub
      */
     pub async fn get_zen(&self) -> ClientResult<String> {
+        Ok(self.get_zen_with_response().await?.body)
+    }
+    /**
+     * Get the Zen of GitHub.
+     *
+     * This function performs a `GET` to the `/zen` endpoint.
+     *
+     * As opposed to `get_zen`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zen_with_response(&self) -> ClientResult<crate::Response<String>> {
         let url = self.client.url("/zen", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -34,6 +34,27 @@ impl Migrations {
         page: i64,
         exclude: &[String],
     ) -> ClientResult<Vec<crate::types::Migration>> {
+        Ok(self
+            .list_for_org_with_response(org, per_page, page, exclude)
+            .await?
+            .body)
+    }
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub async fn list_for_org_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+        exclude: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude.is_empty() {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
@@ -54,7 +75,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -102,6 +123,86 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub fn list_all_for_org_pages_stream(
+        &self,
+        org: &str,
+        exclude: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Migration>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude.is_empty() {
+            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/migrations?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Migration>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        exclude: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Migration>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_org_pages_stream(org, exclude, None))
+    }
     /**
      * Start an organization migration.
      *
@@ -120,6 +221,22 @@ impl Migrations {
         org: &str,
         body: &crate::types::MigrationsStartRequest,
     ) -> ClientResult<crate::types::Migration> {
+        Ok(self.start_for_org_with_response(org, body).await?.body)
+    }
+    /**
+     * Start an organization migration.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `start_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#start-an-organization-migration>
+     */
+    pub async fn start_for_org_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::MigrationsStartRequest,
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/migrations",
@@ -128,7 +245,7 @@ impl Migrations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -165,6 +282,26 @@ impl Migrations {
         migration_id: i64,
         exclude: &[String],
     ) -> ClientResult<crate::types::Migration> {
+        Ok(self
+            .get_status_for_org_with_response(org, migration_id, exclude)
+            .await?
+            .body)
+    }
+    /**
+     * Get an organization migration status.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}` endpoint.
+     *
+     * As opposed to `get_status_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-an-organization-migration-status>
+     */
+    pub async fn get_status_for_org_with_response(
+        &self,
+        org: &str,
+        migration_id: i64,
+        exclude: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude.is_empty() {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
@@ -180,7 +317,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -204,6 +341,24 @@ impl Migrations {
      * * `migration_id: i64` -- migration_id parameter.
      */
     pub async fn download_archive_for_org(&self, org: &str, migration_id: i64) -> ClientResult<()> {
+        self.download_archive_for_org_with_response(org, migration_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Download an organization migration archive.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/archive` endpoint.
+     *
+     * As opposed to `download_archive_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#download-an-organization-migration-archive>
+     */
+    pub async fn download_archive_for_org_with_response(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/migrations/{}/archive",
@@ -213,7 +368,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -237,6 +392,24 @@ impl Migrations {
      * * `migration_id: i64` -- migration_id parameter.
      */
     pub async fn delete_archive_for_org(&self, org: &str, migration_id: i64) -> ClientResult<()> {
+        self.delete_archive_for_org_with_response(org, migration_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an organization migration archive.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/migrations/{migration_id}/archive` endpoint.
+     *
+     * As opposed to `delete_archive_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#delete-an-organization-migration-archive>
+     */
+    pub async fn delete_archive_for_org_with_response(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/migrations/{}/archive",
@@ -246,7 +419,7 @@ impl Migrations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -276,6 +449,25 @@ impl Migrations {
         migration_id: i64,
         repo_name: &str,
     ) -> ClientResult<()> {
+        self.unlock_repo_for_org_with_response(org, migration_id, repo_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unlock an organization repository.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/migrations/{migration_id}/repos/{repo_name}/lock` endpoint.
+     *
+     * As opposed to `unlock_repo_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#unlock-an-organization-repository>
+     */
+    pub async fn unlock_repo_for_org_with_response(
+        &self,
+        org: &str,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/migrations/{}/repos/{}/lock",
@@ -286,7 +478,7 @@ impl Migrations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -318,6 +510,27 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_repos_for_org_with_response(org, migration_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_repos_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub async fn list_repos_for_org_with_response(
+        &self,
+        org: &str,
+        migration_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -336,7 +549,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -379,6 +592,82 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub fn list_all_repos_for_org_pages_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub fn list_all_repos_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_repos_for_org_pages_stream(org, migration_id, None))
+    }
     /**
      * Get an import status.
      *
@@ -431,6 +720,25 @@ impl Migrations {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::Import> {
+        Ok(self
+            .get_import_status_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get an import status.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import` endpoint.
+     *
+     * As opposed to `get_import_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-an-import-status>
+     */
+    pub async fn get_import_status_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import",
@@ -440,7 +748,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -469,6 +777,26 @@ impl Migrations {
         repo: &str,
         body: &crate::types::MigrationsStartImportRequest,
     ) -> ClientResult<crate::types::Import> {
+        Ok(self
+            .start_import_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Start an import.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/import` endpoint.
+     *
+     * As opposed to `start_import`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#start-an-import>
+     */
+    pub async fn start_import_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsStartImportRequest,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import",
@@ -478,7 +806,7 @@ impl Migrations {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -502,6 +830,23 @@ impl Migrations {
      * * `repo: &str`
      */
     pub async fn cancel_import(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.cancel_import_with_response(owner, repo).await?;
+        Ok(())
+    }
+    /**
+     * Cancel an import.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/import` endpoint.
+     *
+     * As opposed to `cancel_import`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#cancel-an-import>
+     */
+    pub async fn cancel_import_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import",
@@ -511,7 +856,7 @@ impl Migrations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -541,6 +886,26 @@ impl Migrations {
         repo: &str,
         body: &crate::types::MigrationsUpdateImportRequest,
     ) -> ClientResult<crate::types::Import> {
+        Ok(self
+            .update_import_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an import.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/import` endpoint.
+     *
+     * As opposed to `update_import`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#update-an-import>
+     */
+    pub async fn update_import_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsUpdateImportRequest,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import",
@@ -550,7 +915,7 @@ impl Migrations {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -582,6 +947,26 @@ impl Migrations {
         repo: &str,
         since: i64,
     ) -> ClientResult<Vec<crate::types::PorterAuthor>> {
+        Ok(self
+            .get_commit_authors_with_response(owner, repo, since)
+            .await?
+            .body)
+    }
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_commit_authors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub async fn get_commit_authors_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PorterAuthor>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if since > 0 {
             query_args.push(("since".to_string(), since.to_string()));
@@ -597,7 +982,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -649,6 +1034,89 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_all_commit_authors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub fn get_all_commit_authors_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PorterAuthor>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/import/authors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PorterAuthor>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_all_commit_authors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub fn get_all_commit_authors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PorterAuthor>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_commit_authors_pages_stream(owner, repo, since, None))
+    }
     /**
      * Map a commit author.
      *
@@ -671,6 +1139,27 @@ impl Migrations {
         author_id: i64,
         body: &crate::types::Author,
     ) -> ClientResult<crate::types::PorterAuthor> {
+        Ok(self
+            .map_commit_author_with_response(owner, repo, author_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Map a commit author.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/import/authors/{author_id}` endpoint.
+     *
+     * As opposed to `map_commit_author`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#map-a-commit-author>
+     */
+    pub async fn map_commit_author_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        author_id: i64,
+        body: &crate::types::Author,
+    ) -> ClientResult<crate::Response<crate::types::PorterAuthor>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import/authors/{}",
@@ -681,7 +1170,7 @@ impl Migrations {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -709,6 +1198,22 @@ impl Migrations {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::PorterLargeFile>> {
+        Ok(self.get_large_files_with_response(owner, repo).await?.body)
+    }
+    /**
+     * Get large files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/large_files` endpoint.
+     *
+     * As opposed to `get_large_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-large-files>
+     */
+    pub async fn get_large_files_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PorterLargeFile>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import/large_files",
@@ -718,7 +1223,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -761,6 +1266,81 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * Get large files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/large_files` endpoint.
+     *
+     * As opposed to `get_all_large_files`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-large-files>
+     */
+    pub fn get_all_large_files_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PorterLargeFile>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/import/large_files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PorterLargeFile>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get large files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/large_files` endpoint.
+     *
+     * As opposed to `get_all_large_files`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-large-files>
+     */
+    pub fn get_all_large_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PorterLargeFile>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_large_files_pages_stream(owner, repo, None))
+    }
     /**
      * Update Git LFS preference.
      *
@@ -781,6 +1361,26 @@ impl Migrations {
         repo: &str,
         body: &crate::types::MigrationsSetLfsPreferenceRequest,
     ) -> ClientResult<crate::types::Import> {
+        Ok(self
+            .set_lfs_preference_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Git LFS preference.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/import/lfs` endpoint.
+     *
+     * As opposed to `set_lfs_preference`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#update-git-lfs-preference>
+     */
+    pub async fn set_lfs_preference_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsSetLfsPreferenceRequest,
+    ) -> ClientResult<crate::Response<crate::types::Import>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/import/lfs",
@@ -790,7 +1390,7 @@ impl Migrations {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -818,6 +1418,25 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Migration>> {
+        Ok(self
+            .list_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub async fn list_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Migration>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -830,7 +1449,7 @@ impl Migrations {
             .client
             .url(&format!("/user/migrations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -864,6 +1483,69 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub fn list_all_for_authenticated_user_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Migration>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/user/migrations", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Migration>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Migration>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_authenticated_user_pages_stream(None))
+    }
     /**
      * Start a user migration.
      *
@@ -877,9 +1559,27 @@ impl Migrations {
         &self,
         body: &crate::types::MigrationsStartRequest,
     ) -> ClientResult<crate::types::Migration> {
+        Ok(self
+            .start_for_authenticated_user_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Start a user migration.
+     *
+     * This function performs a `POST` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `start_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#start-a-user-migration>
+     */
+    pub async fn start_for_authenticated_user_with_response(
+        &self,
+        body: &crate::types::MigrationsStartRequest,
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
         let url = self.client.url("/user/migrations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -914,6 +1614,25 @@ impl Migrations {
         migration_id: i64,
         exclude: &[String],
     ) -> ClientResult<crate::types::Migration> {
+        Ok(self
+            .get_status_for_authenticated_user_with_response(migration_id, exclude)
+            .await?
+            .body)
+    }
+    /**
+     * Get a user migration status.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}` endpoint.
+     *
+     * As opposed to `get_status_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-a-user-migration-status>
+     */
+    pub async fn get_status_for_authenticated_user_with_response(
+        &self,
+        migration_id: i64,
+        exclude: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Migration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude.is_empty() {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
@@ -928,7 +1647,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -971,6 +1690,23 @@ impl Migrations {
      * * `migration_id: i64` -- migration_id parameter.
      */
     pub async fn get_archive_for_authenticated_user(&self, migration_id: i64) -> ClientResult<()> {
+        self.get_archive_for_authenticated_user_with_response(migration_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Download a user migration archive.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/archive` endpoint.
+     *
+     * As opposed to `get_archive_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#download-a-user-migration-archive>
+     */
+    pub async fn get_archive_for_authenticated_user_with_response(
+        &self,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/migrations/{}/archive",
@@ -979,7 +1715,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1005,6 +1741,23 @@ impl Migrations {
         &self,
         migration_id: i64,
     ) -> ClientResult<()> {
+        self.delete_archive_for_authenticated_user_with_response(migration_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a user migration archive.
+     *
+     * This function performs a `DELETE` to the `/user/migrations/{migration_id}/archive` endpoint.
+     *
+     * As opposed to `delete_archive_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#delete-a-user-migration-archive>
+     */
+    pub async fn delete_archive_for_authenticated_user_with_response(
+        &self,
+        migration_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/migrations/{}/archive",
@@ -1013,7 +1766,7 @@ impl Migrations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1041,6 +1794,24 @@ impl Migrations {
         migration_id: i64,
         repo_name: &str,
     ) -> ClientResult<()> {
+        self.unlock_repo_for_authenticated_user_with_response(migration_id, repo_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unlock a user repository.
+     *
+     * This function performs a `DELETE` to the `/user/migrations/{migration_id}/repos/{repo_name}/lock` endpoint.
+     *
+     * As opposed to `unlock_repo_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#unlock-a-user-repository>
+     */
+    pub async fn unlock_repo_for_authenticated_user_with_response(
+        &self,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/migrations/{}/repos/{}/lock",
@@ -1050,7 +1821,7 @@ impl Migrations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1080,6 +1851,26 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_repos_for_user_with_response(migration_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_repos_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub async fn list_repos_for_user_with_response(
+        &self,
+        migration_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1097,7 +1888,7 @@ impl Migrations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1138,4 +1929,77 @@ impl Migrations {
             )
             .await
     }
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub fn list_all_repos_for_user_pages_stream(
+        &self,
+        migration_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/user/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(&migration_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub fn list_all_repos_for_user_stream(
+        &self,
+        migration_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_repos_for_user_pages_stream(migration_id, None))
+    }
 }
@@ -34,6 +34,26 @@ impl OauthAuthorizations {
         page: i64,
         client_id: &str,
     ) -> ClientResult<Vec<crate::types::ApplicationGrant>> {
+        Ok(self
+            .list_grants_with_response(per_page, page, client_id)
+            .await?
+            .body)
+    }
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_grants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub async fn list_grants_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGrant>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -49,7 +69,7 @@ impl OauthAuthorizations {
             .client
             .url(&format!("/applications/grants?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -93,6 +113,80 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub fn list_all_grants_pages_stream(
+        &self,
+        client_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ApplicationGrant>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !client_id.is_empty() {
+            query_args.push(("client_id".to_string(), client_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/applications/grants?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ApplicationGrant>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub fn list_all_grants_stream(
+        &self,
+        client_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationGrant>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_grants_pages_stream(client_id, None))
+    }
     /**
      * Get a single grant.
      *
@@ -107,6 +201,21 @@ impl OauthAuthorizations {
      * * `grant_id: i64` -- grant_id parameter.
      */
     pub async fn get_grant(&self, grant_id: i64) -> ClientResult<crate::types::ApplicationGrant> {
+        Ok(self.get_grant_with_response(grant_id).await?.body)
+    }
+    /**
+     * Get a single grant.
+     *
+     * This function performs a `GET` to the `/applications/grants/{grant_id}` endpoint.
+     *
+     * As opposed to `get_grant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#get-a-single-grant>
+     */
+    pub async fn get_grant_with_response(
+        &self,
+        grant_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ApplicationGrant>> {
         let url = self.client.url(
             &format!(
                 "/applications/grants/{}",
@@ -115,7 +224,7 @@ impl OauthAuthorizations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -140,6 +249,22 @@ impl OauthAuthorizations {
      * * `grant_id: i64` -- grant_id parameter.
      */
     pub async fn delete_grant(&self, grant_id: i64) -> ClientResult<()> {
+        self.delete_grant_with_response(grant_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a grant.
+     *
+     * This function performs a `DELETE` to the `/applications/grants/{grant_id}` endpoint.
+     *
+     * As opposed to `delete_grant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#delete-a-grant>
+     */
+    pub async fn delete_grant_with_response(
+        &self,
+        grant_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/applications/grants/{}",
@@ -148,7 +273,7 @@ impl OauthAuthorizations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -178,6 +303,26 @@ impl OauthAuthorizations {
         page: i64,
         client_id: &str,
     ) -> ClientResult<Vec<crate::types::Authorization>> {
+        Ok(self
+            .list_authorizations_with_response(per_page, page, client_id)
+            .await?
+            .body)
+    }
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_authorizations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub async fn list_authorizations_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Authorization>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -193,7 +338,7 @@ impl OauthAuthorizations {
             .client
             .url(&format!("/authorizations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -235,6 +380,79 @@ impl OauthAuthorizations {
             )
             .await
     }
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_all_authorizations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub fn list_all_authorizations_pages_stream(
+        &self,
+        client_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Authorization>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !client_id.is_empty() {
+            query_args.push(("client_id".to_string(), client_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/authorizations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Authorization>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_all_authorizations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub fn list_all_authorizations_stream(
+        &self,
+        client_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Authorization>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_authorizations_pages_stream(client_id, None))
+    }
     /**
      * Create a new authorization.
      *
@@ -258,9 +476,24 @@ impl OauthAuthorizations {
         &self,
         body: &crate::types::OauthAuthorizationsCreateAuthorizationRequest,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self.create_authorization_with_response(body).await?.body)
+    }
+    /**
+     * Create a new authorization.
+     *
+     * This function performs a `POST` to the `/authorizations` endpoint.
+     *
+     * As opposed to `create_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#create-a-new-authorization>
+     */
+    pub async fn create_authorization_with_response(
+        &self,
+        body: &crate::types::OauthAuthorizationsCreateAuthorizationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url("/authorizations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -295,6 +528,25 @@ impl OauthAuthorizations {
         client_id: &str,
         body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppRequest,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self
+            .get_or_create_authorization_for_app_with_response(client_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Get-or-create an authorization for a specific app.
+     *
+     * This function performs a `PUT` to the `/authorizations/clients/{client_id}` endpoint.
+     *
+     * As opposed to `get_or_create_authorization_for_app`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#get-or-create-an-authorization-for-a-specific-app>
+     */
+    pub async fn get_or_create_authorization_for_app_with_response(
+        &self,
+        client_id: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/authorizations/clients/{}",
@@ -303,7 +555,7 @@ impl OauthAuthorizations {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -338,6 +590,30 @@ impl OauthAuthorizations {
         fingerprint: &str,
         body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self
+            .get_or_create_authorization_for_app_and_fingerprint_with_response(
+                client_id,
+                fingerprint,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get-or-create an authorization for a specific app and fingerprint.
+     *
+     * This function performs a `PUT` to the `/authorizations/clients/{client_id}/{fingerprint}` endpoint.
+     *
+     * As opposed to `get_or_create_authorization_for_app_and_fingerprint`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#get-or-create-an-authorization-for-a-specific-app-and-fingerprint>
+     */
+    pub async fn get_or_create_authorization_for_app_and_fingerprint_with_response(
+        &self,
+        client_id: &str,
+        fingerprint: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/authorizations/clients/{}/{}",
@@ -347,7 +623,7 @@ impl OauthAuthorizations {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -373,6 +649,24 @@ impl OauthAuthorizations {
         &self,
         authorization_id: i64,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self
+            .get_authorization_with_response(authorization_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a single authorization.
+     *
+     * This function performs a `GET` to the `/authorizations/{authorization_id}` endpoint.
+     *
+     * As opposed to `get_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#get-a-single-authorization>
+     */
+    pub async fn get_authorization_with_response(
+        &self,
+        authorization_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/authorizations/{}",
@@ -381,7 +675,7 @@ impl OauthAuthorizations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -404,6 +698,23 @@ impl OauthAuthorizations {
      * * `authorization_id: i64` -- authorization_id parameter.
      */
     pub async fn delete_authorization(&self, authorization_id: i64) -> ClientResult<()> {
+        self.delete_authorization_with_response(authorization_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an authorization.
+     *
+     * This function performs a `DELETE` to the `/authorizations/{authorization_id}` endpoint.
+     *
+     * As opposed to `delete_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#delete-an-authorization>
+     */
+    pub async fn delete_authorization_with_response(
+        &self,
+        authorization_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/authorizations/{}",
@@ -412,7 +723,7 @@ impl OauthAuthorizations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -443,6 +754,25 @@ impl OauthAuthorizations {
         authorization_id: i64,
         body: &crate::types::OauthAuthorizationsUpdateAuthorizationRequest,
     ) -> ClientResult<crate::types::Authorization> {
+        Ok(self
+            .update_authorization_with_response(authorization_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an existing authorization.
+     *
+     * This function performs a `PATCH` to the `/authorizations/{authorization_id}` endpoint.
+     *
+     * As opposed to `update_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#update-an-existing-authorization>
+     */
+    pub async fn update_authorization_with_response(
+        &self,
+        authorization_id: i64,
+        body: &crate::types::OauthAuthorizationsUpdateAuthorizationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Authorization>> {
         let url = self.client.url(
             &format!(
                 "/authorizations/{}",
@@ -451,7 +781,7 @@ impl OauthAuthorizations {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
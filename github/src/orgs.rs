@@ -32,6 +32,22 @@ impl Orgs {
         since: i64,
         per_page: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationSimple>> {
+        Ok(self.list_with_response(since, per_page).await?.body)
+    }
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub async fn list_with_response(
+        &self,
+        since: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if per_page > 0 {
             query_args.push(("per_page".to_string(), per_page.to_string()));
@@ -42,7 +58,7 @@ impl Orgs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/organizations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -84,6 +100,79 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        since: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrganizationSimple>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/organizations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrganizationSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pages_stream(since, None))
+    }
     /**
      * Get an organization.
      *
@@ -100,12 +189,27 @@ impl Orgs {
      * * `org: &str`
      */
     pub async fn get(&self, org: &str) -> ClientResult<crate::types::OrganizationFull> {
+        Ok(self.get_with_response(org).await?.body)
+    }
+    /**
+     * Get an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-an-organization>
+     */
+    pub async fn get_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrganizationFull>> {
         let url = self.client.url(
             &format!("/orgs/{}", crate::progenitor_support::encode_path(org),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -134,12 +238,28 @@ impl Orgs {
         org: &str,
         body: &crate::types::OrgsUpdateRequest,
     ) -> ClientResult<crate::types::OrganizationFull> {
+        Ok(self.update_with_response(org, body).await?.body)
+    }
+    /**
+     * Update an organization.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs/#update-an-organization>
+     */
+    pub async fn update_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrganizationFull>> {
         let url = self.client.url(
             &format!("/orgs/{}", crate::progenitor_support::encode_path(org),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -189,6 +309,31 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::AuditLogEvent>> {
+        Ok(self
+            .get_audit_log_with_response(org, phrase, include, after, before, order, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_audit_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub async fn get_audit_log_with_response(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuditLogEvent>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -221,7 +366,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -287,6 +432,108 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub fn get_all_audit_log_pages_stream(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::AuditLogEvent>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !after.is_empty() {
+            query_args.push(("after".to_string(), after.to_string()));
+        }
+        if !before.is_empty() {
+            query_args.push(("before".to_string(), before.to_string()));
+        }
+        if !include.to_string().is_empty() {
+            query_args.push(("include".to_string(), include.to_string()));
+        }
+        if !order.to_string().is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if !phrase.is_empty() {
+            query_args.push(("phrase".to_string(), phrase.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/audit-log?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::AuditLogEvent>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub fn get_all_audit_log_stream(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuditLogEvent>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_audit_log_pages_stream(org, phrase, include, after, before, order, None),
+        )
+    }
     /**
      * List users blocked by an organization.
      *
@@ -304,6 +551,21 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self.list_blocked_users_with_response(org).await?.body)
+    }
+    /**
+     * List users blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks` endpoint.
+     *
+     * As opposed to `list_blocked_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
+     */
+    pub async fn list_blocked_users_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/blocks",
@@ -312,7 +574,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -353,6 +615,78 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List users blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_users`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
+     */
+    pub fn list_all_blocked_users_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/blocks",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List users blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_users`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
+     */
+    pub fn list_all_blocked_users_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_blocked_users_pages_stream(org, None))
+    }
     /**
      * Check if a user is blocked by an organization.
      *
@@ -368,6 +702,23 @@ impl Orgs {
      * * `username: &str`
      */
     pub async fn check_blocked_user(&self, org: &str, username: &str) -> ClientResult<()> {
+        self.check_blocked_user_with_response(org, username).await?;
+        Ok(())
+    }
+    /**
+     * Check if a user is blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks/{username}` endpoint.
+     *
+     * As opposed to `check_blocked_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#check-if-a-user-is-blocked-by-an-organization>
+     */
+    pub async fn check_blocked_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/blocks/{}",
@@ -377,7 +728,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -401,6 +752,23 @@ impl Orgs {
      * * `username: &str`
      */
     pub async fn block_user(&self, org: &str, username: &str) -> ClientResult<()> {
+        self.block_user_with_response(org, username).await?;
+        Ok(())
+    }
+    /**
+     * Block a user from an organization.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/blocks/{username}` endpoint.
+     *
+     * As opposed to `block_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#block-a-user-from-an-organization>
+     */
+    pub async fn block_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/blocks/{}",
@@ -410,7 +778,7 @@ impl Orgs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -434,6 +802,23 @@ impl Orgs {
      * * `username: &str`
      */
     pub async fn unblock_user(&self, org: &str, username: &str) -> ClientResult<()> {
+        self.unblock_user_with_response(org, username).await?;
+        Ok(())
+    }
+    /**
+     * Unblock a user from an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/blocks/{username}` endpoint.
+     *
+     * As opposed to `unblock_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#unblock-a-user-from-an-organization>
+     */
+    pub async fn unblock_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/blocks/{}",
@@ -443,7 +828,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -471,6 +856,24 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<Vec<crate::types::CredentialAuthorization>> {
+        Ok(self
+            .list_saml_sso_authorizations_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * List SAML SSO authorizations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/credential-authorizations` endpoint.
+     *
+     * As opposed to `list_saml_sso_authorizations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-saml-sso-authorizations-for-an-organization>
+     */
+    pub async fn list_saml_sso_authorizations_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CredentialAuthorization>>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/credential-authorizations",
@@ -479,7 +882,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -522,6 +925,80 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List SAML SSO authorizations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/credential-authorizations` endpoint.
+     *
+     * As opposed to `list_all_saml_sso_authorizations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-saml-sso-authorizations-for-an-organization>
+     */
+    pub fn list_all_saml_sso_authorizations_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CredentialAuthorization>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/credential-authorizations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CredentialAuthorization>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List SAML SSO authorizations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/credential-authorizations` endpoint.
+     *
+     * As opposed to `list_all_saml_sso_authorizations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-saml-sso-authorizations-for-an-organization>
+     */
+    pub fn list_all_saml_sso_authorizations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CredentialAuthorization>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_saml_sso_authorizations_pages_stream(org, None))
+    }
     /**
      * Remove a SAML SSO authorization for an organization.
      *
@@ -543,6 +1020,24 @@ impl Orgs {
         org: &str,
         credential_id: i64,
     ) -> ClientResult<()> {
+        self.remove_saml_sso_authorization_with_response(org, credential_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a SAML SSO authorization for an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/credential-authorizations/{credential_id}` endpoint.
+     *
+     * As opposed to `remove_saml_sso_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#remove-a-saml-sso-authorization-for-an-organization>
+     */
+    pub async fn remove_saml_sso_authorization_with_response(
+        &self,
+        org: &str,
+        credential_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/credential-authorizations/{}",
@@ -552,7 +1047,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -582,6 +1077,26 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        Ok(self
+            .list_failed_invitations_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_failed_invitations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub async fn list_failed_invitations_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -599,7 +1114,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -640,6 +1155,80 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_all_failed_invitations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub fn list_all_failed_invitations_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrganizationInvitation>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/failed_invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrganizationInvitation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_all_failed_invitations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub fn list_all_failed_invitations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_failed_invitations_pages_stream(org, None))
+    }
     /**
      * List organization webhooks.
      *
@@ -651,16 +1240,36 @@ impl Orgs {
      *
      * **Parameters:**
      *
-     * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `org: &str`
+     * * `per_page: i64` -- Results per page (max 100).
+     * * `page: i64` -- Page number of the results to fetch.
+     */
+    pub async fn list_webhooks(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<Vec<crate::types::OrgHook>> {
+        Ok(self
+            .list_webhooks_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_webhooks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
      */
-    pub async fn list_webhooks(
+    pub async fn list_webhooks_with_response(
         &self,
         org: &str,
         per_page: i64,
         page: i64,
-    ) -> ClientResult<Vec<crate::types::OrgHook>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrgHook>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -678,7 +1287,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -716,6 +1325,77 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
+     */
+    pub fn list_all_webhooks_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OrgHook>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/hooks",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrgHook>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrgHook>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_webhooks_pages_stream(org, None))
+    }
     /**
      * Create an organization webhook.
      *
@@ -734,6 +1414,22 @@ impl Orgs {
         org: &str,
         body: &crate::types::OrgsCreateWebhookRequest,
     ) -> ClientResult<crate::types::OrgHook> {
+        Ok(self.create_webhook_with_response(org, body).await?.body)
+    }
+    /**
+     * Create an organization webhook.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `create_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#create-an-organization-webhook>
+     */
+    pub async fn create_webhook_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgHook>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks",
@@ -742,7 +1438,7 @@ impl Orgs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -770,6 +1466,22 @@ impl Orgs {
         org: &str,
         hook_id: i64,
     ) -> ClientResult<crate::types::OrgHook> {
+        Ok(self.get_webhook_with_response(org, hook_id).await?.body)
+    }
+    /**
+     * Get an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}` endpoint.
+     *
+     * As opposed to `get_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-an-organization-webhook>
+     */
+    pub async fn get_webhook_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrgHook>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}",
@@ -779,7 +1491,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -803,6 +1515,23 @@ impl Orgs {
      * * `hook_id: i64`
      */
     pub async fn delete_webhook(&self, org: &str, hook_id: i64) -> ClientResult<()> {
+        self.delete_webhook_with_response(org, hook_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete an organization webhook.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/hooks/{hook_id}` endpoint.
+     *
+     * As opposed to `delete_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#delete-an-organization-webhook>
+     */
+    pub async fn delete_webhook_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}",
@@ -812,7 +1541,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -841,6 +1570,26 @@ impl Orgs {
         hook_id: i64,
         body: &crate::types::OrgsUpdateWebhookRequest,
     ) -> ClientResult<crate::types::OrgHook> {
+        Ok(self
+            .update_webhook_with_response(org, hook_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an organization webhook.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}/hooks/{hook_id}` endpoint.
+     *
+     * As opposed to `update_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#update-an-organization-webhook>
+     */
+    pub async fn update_webhook_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::OrgsUpdateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgHook>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}",
@@ -850,7 +1599,7 @@ impl Orgs {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -880,6 +1629,25 @@ impl Orgs {
         org: &str,
         hook_id: i64,
     ) -> ClientResult<crate::types::WebhookConfig> {
+        Ok(self
+            .get_webhook_config_for_org_with_response(org, hook_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a webhook configuration for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/config` endpoint.
+     *
+     * As opposed to `get_webhook_config_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-a-webhook-configuration-for-an-organization>
+     */
+    pub async fn get_webhook_config_for_org_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}/config",
@@ -889,7 +1657,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -920,6 +1688,26 @@ impl Orgs {
         hook_id: i64,
         body: &crate::types::AppsUpdateWebhookConfigAppRequest,
     ) -> ClientResult<crate::types::WebhookConfig> {
+        Ok(self
+            .update_webhook_config_for_org_with_response(org, hook_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a webhook configuration for an organization.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}/hooks/{hook_id}/config` endpoint.
+     *
+     * As opposed to `update_webhook_config_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#update-a-webhook-configuration-for-an-organization>
+     */
+    pub async fn update_webhook_config_for_org_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}/config",
@@ -929,7 +1717,7 @@ impl Orgs {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -961,6 +1749,27 @@ impl Orgs {
         per_page: i64,
         cursor: &str,
     ) -> ClientResult<Vec<crate::types::HookDeliveryItem>> {
+        Ok(self
+            .list_webhook_deliveries_with_response(org, hook_id, per_page, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_webhook_deliveries`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub async fn list_webhook_deliveries_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+        per_page: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -979,7 +1788,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1029,6 +1838,92 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub fn list_all_webhook_deliveries_pages_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        cursor: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::HookDeliveryItem>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::HookDeliveryItem>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_webhook_deliveries_pages_stream(org, hook_id, cursor, None),
+        )
+    }
     /**
      * Get a webhook delivery for an organization webhook.
      *
@@ -1050,6 +1945,26 @@ impl Orgs {
         hook_id: i64,
         delivery_id: i64,
     ) -> ClientResult<crate::types::HookDelivery> {
+        Ok(self
+            .get_webhook_delivery_with_response(org, hook_id, delivery_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a webhook delivery for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}` endpoint.
+     *
+     * As opposed to `get_webhook_delivery`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-a-webhook-delivery-for-an-organization-webhook>
+     */
+    pub async fn get_webhook_delivery_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::HookDelivery>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}/deliveries/{}",
@@ -1060,7 +1975,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1090,6 +2005,25 @@ impl Orgs {
         hook_id: i64,
         delivery_id: i64,
     ) -> ClientResult<()> {
+        self.redeliver_webhook_delivery_with_response(org, hook_id, delivery_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Redeliver a delivery for an organization webhook.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}/attempts` endpoint.
+     *
+     * As opposed to `redeliver_webhook_delivery`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#redeliver-a-delivery-for-an-organization-webhook>
+     */
+    pub async fn redeliver_webhook_delivery_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}/deliveries/{}/attempts",
@@ -1100,7 +2034,7 @@ impl Orgs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1124,6 +2058,23 @@ impl Orgs {
      * * `hook_id: i64`
      */
     pub async fn ping_webhook(&self, org: &str, hook_id: i64) -> ClientResult<()> {
+        self.ping_webhook_with_response(org, hook_id).await?;
+        Ok(())
+    }
+    /**
+     * Ping an organization webhook.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/hooks/{hook_id}/pings` endpoint.
+     *
+     * As opposed to `ping_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#ping-an-organization-webhook>
+     */
+    pub async fn ping_webhook_with_response(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/hooks/{}/pings",
@@ -1133,7 +2084,7 @@ impl Orgs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1163,6 +2114,26 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::AppsListInstallationsResponse> {
+        Ok(self
+            .list_app_installations_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List app installations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/installations` endpoint.
+     *
+     * As opposed to `list_app_installations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-app-installations-for-an-organization>
+     */
+    pub async fn list_app_installations_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsListInstallationsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1180,7 +2151,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1210,6 +2181,26 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        Ok(self
+            .list_pending_invitations_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_pending_invitations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub async fn list_pending_invitations_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1227,7 +2218,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1268,6 +2259,80 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub fn list_all_pending_invitations_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrganizationInvitation>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrganizationInvitation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub fn list_all_pending_invitations_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pending_invitations_pages_stream(org, None))
+    }
     /**
      * Create an organization invitation.
      *
@@ -1288,6 +2353,22 @@ impl Orgs {
         org: &str,
         body: &crate::types::OrgsCreateInvitationRequest,
     ) -> ClientResult<crate::types::OrganizationInvitation> {
+        Ok(self.create_invitation_with_response(org, body).await?.body)
+    }
+    /**
+     * Create an organization invitation.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `create_invitation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#create-an-organization-invitation>
+     */
+    pub async fn create_invitation_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateInvitationRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrganizationInvitation>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/invitations",
@@ -1296,7 +2377,7 @@ impl Orgs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1322,6 +2403,24 @@ impl Orgs {
      * * `invitation_id: i64` -- invitation_id parameter.
      */
     pub async fn cancel_invitation(&self, org: &str, invitation_id: i64) -> ClientResult<()> {
+        self.cancel_invitation_with_response(org, invitation_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Cancel an organization invitation.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/invitations/{invitation_id}` endpoint.
+     *
+     * As opposed to `cancel_invitation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#cancel-an-organization-invitation>
+     */
+    pub async fn cancel_invitation_with_response(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/invitations/{}",
@@ -1331,7 +2430,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1363,6 +2462,27 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .list_invitation_teams_with_response(org, invitation_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_invitation_teams`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub async fn list_invitation_teams_with_response(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1381,7 +2501,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1424,6 +2544,81 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_invitation_teams`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub fn list_all_invitation_teams_pages_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Team>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/invitations/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&invitation_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Team>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_invitation_teams`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub fn list_all_invitation_teams_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_invitation_teams_pages_stream(
+            org,
+            invitation_id,
+            None,
+        ))
+    }
     /**
      * List organization members.
      *
@@ -1454,6 +2649,28 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_members_with_response(org, filter, role, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub async fn list_members_with_response(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.to_string().is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -1477,7 +2694,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1529,6 +2746,91 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_all_members`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub fn list_all_members_pages_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !role.to_string().is_empty() {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_all_members`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub fn list_all_members_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_members_pages_stream(org, filter, role, None))
+    }
     /**
      * Check organization membership for a user.
      *
@@ -1544,6 +2846,24 @@ impl Orgs {
      * * `username: &str`
      */
     pub async fn check_membership_for_user(&self, org: &str, username: &str) -> ClientResult<()> {
+        self.check_membership_for_user_with_response(org, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check organization membership for a user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members/{username}` endpoint.
+     *
+     * As opposed to `check_membership_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#check-organization-membership-for-a-user>
+     */
+    pub async fn check_membership_for_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/members/{}",
@@ -1553,7 +2873,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1577,6 +2897,23 @@ impl Orgs {
      * * `username: &str`
      */
     pub async fn remove_member(&self, org: &str, username: &str) -> ClientResult<()> {
+        self.remove_member_with_response(org, username).await?;
+        Ok(())
+    }
+    /**
+     * Remove an organization member.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/members/{username}` endpoint.
+     *
+     * As opposed to `remove_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#remove-an-organization-member>
+     */
+    pub async fn remove_member_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/members/{}",
@@ -1586,7 +2923,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1614,6 +2951,25 @@ impl Orgs {
         org: &str,
         username: &str,
     ) -> ClientResult<crate::types::OrgMembership> {
+        Ok(self
+            .get_membership_for_user_with_response(org, username)
+            .await?
+            .body)
+    }
+    /**
+     * Get organization membership for a user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/memberships/{username}` endpoint.
+     *
+     * As opposed to `get_membership_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-organization-membership-for-a-user>
+     */
+    pub async fn get_membership_for_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/memberships/{}",
@@ -1623,7 +2979,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1660,6 +3016,26 @@ impl Orgs {
         username: &str,
         body: &crate::types::OrgsSetMembershipUserRequest,
     ) -> ClientResult<crate::types::OrgMembership> {
+        Ok(self
+            .set_membership_for_user_with_response(org, username, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set organization membership for a user.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/memberships/{username}` endpoint.
+     *
+     * As opposed to `set_membership_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#set-organization-membership-for-a-user>
+     */
+    pub async fn set_membership_for_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+        body: &crate::types::OrgsSetMembershipUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/memberships/{}",
@@ -1669,7 +3045,7 @@ impl Orgs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1695,6 +3071,24 @@ impl Orgs {
      * * `username: &str`
      */
     pub async fn remove_membership_for_user(&self, org: &str, username: &str) -> ClientResult<()> {
+        self.remove_membership_for_user_with_response(org, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove organization membership for a user.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/memberships/{username}` endpoint.
+     *
+     * As opposed to `remove_membership_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#remove-organization-membership-for-a-user>
+     */
+    pub async fn remove_membership_for_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/memberships/{}",
@@ -1704,7 +3098,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1738,6 +3132,27 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_outside_collaborators_with_response(org, filter, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_outside_collaborators`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub async fn list_outside_collaborators_with_response(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !filter.to_string().is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
@@ -1758,7 +3173,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1806,6 +3221,88 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_all_outside_collaborators`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub fn list_all_outside_collaborators_pages_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.to_string().is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/outside_collaborators?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_all_outside_collaborators`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub fn list_all_outside_collaborators_stream(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_outside_collaborators_pages_stream(org, filter, None),
+        )
+    }
     /**
      * Convert an organization member to outside collaborator.
      *
@@ -1825,6 +3322,24 @@ impl Orgs {
         org: &str,
         username: &str,
     ) -> ClientResult<()> {
+        self.convert_member_to_outside_collaborator_with_response(org, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Convert an organization member to outside collaborator.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/outside_collaborators/{username}` endpoint.
+     *
+     * As opposed to `convert_member_to_outside_collaborator`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#convert-an-organization-member-to-outside-collaborator>
+     */
+    pub async fn convert_member_to_outside_collaborator_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/outside_collaborators/{}",
@@ -1834,7 +3349,7 @@ impl Orgs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1858,6 +3373,24 @@ impl Orgs {
      * * `username: &str`
      */
     pub async fn remove_outside_collaborator(&self, org: &str, username: &str) -> ClientResult<()> {
+        self.remove_outside_collaborator_with_response(org, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove outside collaborator from an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/outside_collaborators/{username}` endpoint.
+     *
+     * As opposed to `remove_outside_collaborator`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#remove-outside-collaborator-from-an-organization>
+     */
+    pub async fn remove_outside_collaborator_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/outside_collaborators/{}",
@@ -1867,7 +3400,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1897,6 +3430,26 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_public_members_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_public_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub async fn list_public_members_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1914,7 +3467,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1955,6 +3508,78 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_all_public_members`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub fn list_all_public_members_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/public_members",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_all_public_members`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub fn list_all_public_members_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_members_pages_stream(org, None))
+    }
     /**
      * Check public organization membership for a user.
      *
@@ -1974,6 +3599,24 @@ impl Orgs {
         org: &str,
         username: &str,
     ) -> ClientResult<()> {
+        self.check_public_membership_for_user_with_response(org, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check public organization membership for a user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members/{username}` endpoint.
+     *
+     * As opposed to `check_public_membership_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#check-public-organization-membership-for-a-user>
+     */
+    pub async fn check_public_membership_for_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/public_members/{}",
@@ -1983,7 +3626,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2013,6 +3656,24 @@ impl Orgs {
         org: &str,
         username: &str,
     ) -> ClientResult<()> {
+        self.set_public_membership_for_authenticated_user_with_response(org, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Set public organization membership for the authenticated user.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/public_members/{username}` endpoint.
+     *
+     * As opposed to `set_public_membership_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#set-public-organization-membership-for-the-authenticated-user>
+     */
+    pub async fn set_public_membership_for_authenticated_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/public_members/{}",
@@ -2022,7 +3683,7 @@ impl Orgs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2050,6 +3711,24 @@ impl Orgs {
         org: &str,
         username: &str,
     ) -> ClientResult<()> {
+        self.remove_public_membership_for_authenticated_user_with_response(org, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove public organization membership for the authenticated user.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/public_members/{username}` endpoint.
+     *
+     * As opposed to `remove_public_membership_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#remove-public-organization-membership-for-the-authenticated-user>
+     */
+    pub async fn remove_public_membership_for_authenticated_user_with_response(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/public_members/{}",
@@ -2059,7 +3738,7 @@ impl Orgs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2089,6 +3768,26 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::OrgMembership>> {
+        Ok(self
+            .list_memberships_for_authenticated_user_with_response(state, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_memberships_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub async fn list_memberships_for_authenticated_user_with_response(
+        &self,
+        state: crate::types::OrgMembershipState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrgMembership>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2104,7 +3803,7 @@ impl Orgs {
             .client
             .url(&format!("/user/memberships/orgs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2146,6 +3845,81 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_all_memberships_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub fn list_all_memberships_for_authenticated_user_pages_stream(
+        &self,
+        state: crate::types::OrgMembershipState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OrgMembership>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/user/memberships/orgs?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrgMembership>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_all_memberships_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub fn list_all_memberships_for_authenticated_user_stream(
+        &self,
+        state: crate::types::OrgMembershipState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrgMembership>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_memberships_for_authenticated_user_pages_stream(state, None),
+        )
+    }
     /**
      * Get an organization membership for the authenticated user.
      *
@@ -2163,6 +3937,24 @@ impl Orgs {
         &self,
         org: &str,
     ) -> ClientResult<crate::types::OrgMembership> {
+        Ok(self
+            .get_membership_for_authenticated_user_with_response(org)
+            .await?
+            .body)
+    }
+    /**
+     * Get an organization membership for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs/{org}` endpoint.
+     *
+     * As opposed to `get_membership_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-an-organization-membership-for-the-authenticated-user>
+     */
+    pub async fn get_membership_for_authenticated_user_with_response(
+        &self,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
         let url = self.client.url(
             &format!(
                 "/user/memberships/orgs/{}",
@@ -2171,7 +3963,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2198,6 +3990,25 @@ impl Orgs {
         org: &str,
         body: &crate::types::OrgsUpdateMembershipRequest,
     ) -> ClientResult<crate::types::OrgMembership> {
+        Ok(self
+            .update_membership_for_authenticated_user_with_response(org, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an organization membership for the authenticated user.
+     *
+     * This function performs a `PATCH` to the `/user/memberships/orgs/{org}` endpoint.
+     *
+     * As opposed to `update_membership_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#update-an-organization-membership-for-the-authenticated-user>
+     */
+    pub async fn update_membership_for_authenticated_user_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateMembershipRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrgMembership>> {
         let url = self.client.url(
             &format!(
                 "/user/memberships/orgs/{}",
@@ -2206,7 +4017,7 @@ impl Orgs {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2238,6 +4049,25 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationSimple>> {
+        Ok(self
+            .list_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub async fn list_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2248,7 +4078,7 @@ impl Orgs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/orgs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2286,6 +4116,71 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrganizationSimple>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/user/orgs", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrganizationSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_for_authenticated_user_pages_stream(None))
+    }
     /**
      * List organizations for a user.
      *
@@ -2309,6 +4204,26 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationSimple>> {
+        Ok(self
+            .list_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub async fn list_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationSimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2326,7 +4241,7 @@ impl Orgs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2369,4 +4284,78 @@ impl Orgs {
             )
             .await
     }
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub fn list_all_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrganizationSimple>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/orgs",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrganizationSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationSimple>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_for_user_pages_stream(username, None))
+    }
 }
@@ -35,6 +35,26 @@ impl Packages {
         package_name: &str,
         org: &str,
     ) -> ClientResult<crate::types::Package> {
+        Ok(self
+            .get_package_for_organization_with_response(package_type, package_name, org)
+            .await?
+            .body)
+    }
+    /**
+     * Get a package for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}` endpoint.
+     *
+     * As opposed to `get_package_for_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-a-package-for-an-organization>
+     */
+    pub async fn get_package_for_organization_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> ClientResult<crate::Response<crate::types::Package>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/packages/{}/{}",
@@ -45,7 +65,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,6 +99,25 @@ impl Packages {
         package_name: &str,
         org: &str,
     ) -> ClientResult<()> {
+        self.delete_package_for_org_with_response(package_type, package_name, org)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a package for an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/packages/{package_type}/{package_name}` endpoint.
+     *
+     * As opposed to `delete_package_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#delete-a-package-for-an-organization>
+     */
+    pub async fn delete_package_for_org_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/packages/{}/{}",
@@ -89,7 +128,7 @@ impl Packages {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -129,6 +168,26 @@ impl Packages {
         org: &str,
         token: &str,
     ) -> ClientResult<()> {
+        self.restore_package_for_org_with_response(package_type, package_name, org, token)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Restore a package for an organization.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/packages/{package_type}/{package_name}/restore` endpoint.
+     *
+     * As opposed to `restore_package_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#restore-a-package-for-an-organization>
+     */
+    pub async fn restore_package_for_org_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !token.is_empty() {
             query_args.push(("token".to_string(), token.to_string()));
@@ -145,7 +204,7 @@ impl Packages {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -184,6 +243,36 @@ impl Packages {
         per_page: i64,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> ClientResult<Vec<crate::types::PackageVersion>> {
+        Ok(self
+            .get_all_package_versions_for_package_owned_by_org_with_response(
+                package_type,
+                package_name,
+                org,
+                page,
+                per_page,
+                state,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_package_versions_for_package_owned_by_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub async fn get_all_package_versions_for_package_owned_by_org_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        page: i64,
+        per_page: i64,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -206,7 +295,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -261,6 +350,100 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_org_pages_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PackageVersion>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PackageVersion>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_org_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_all_package_versions_for_package_owned_by_org_pages_stream(
+                package_type,
+                package_name,
+                org,
+                state,
+                None,
+            ),
+        )
+    }
     /**
      * Get a package version for an organization.
      *
@@ -287,6 +470,32 @@ impl Packages {
         org: &str,
         package_version_id: i64,
     ) -> ClientResult<crate::types::PackageVersion> {
+        Ok(self
+            .get_package_version_for_organization_with_response(
+                package_type,
+                package_name,
+                org,
+                package_version_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get a package version for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}` endpoint.
+     *
+     * As opposed to `get_package_version_for_organization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-a-package-version-for-an-organization>
+     */
+    pub async fn get_package_version_for_organization_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PackageVersion>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/packages/{}/{}/versions/{}",
@@ -298,7 +507,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -334,6 +543,31 @@ impl Packages {
         org: &str,
         package_version_id: i64,
     ) -> ClientResult<()> {
+        self.delete_package_version_for_org_with_response(
+            package_type,
+            package_name,
+            org,
+            package_version_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete package version for an organization.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}` endpoint.
+     *
+     * As opposed to `delete_package_version_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#delete-a-package-version-for-an-organization>
+     */
+    pub async fn delete_package_version_for_org_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/packages/{}/{}/versions/{}",
@@ -345,7 +579,7 @@ impl Packages {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -385,6 +619,31 @@ impl Packages {
         org: &str,
         package_version_id: i64,
     ) -> ClientResult<()> {
+        self.restore_package_version_for_org_with_response(
+            package_type,
+            package_name,
+            org,
+            package_version_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Restore package version for an organization.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore` endpoint.
+     *
+     * As opposed to `restore_package_version_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#restore-a-package-version-for-an-organization>
+     */
+    pub async fn restore_package_version_for_org_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/packages/{}/{}/versions/{}/restore",
@@ -396,7 +655,7 @@ impl Packages {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -427,6 +686,25 @@ impl Packages {
         package_type: crate::types::PackageType,
         package_name: &str,
     ) -> ClientResult<crate::types::Package> {
+        Ok(self
+            .get_package_for_authenticated_user_with_response(package_type, package_name)
+            .await?
+            .body)
+    }
+    /**
+     * Get a package for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}` endpoint.
+     *
+     * As opposed to `get_package_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-a-package-for-the-authenticated-user>
+     */
+    pub async fn get_package_for_authenticated_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Package>> {
         let url = self.client.url(
             &format!(
                 "/user/packages/{}/{}",
@@ -436,7 +714,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -467,6 +745,24 @@ impl Packages {
         package_type: crate::types::PackageType,
         package_name: &str,
     ) -> ClientResult<()> {
+        self.delete_package_for_authenticated_user_with_response(package_type, package_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a package for the authenticated user.
+     *
+     * This function performs a `DELETE` to the `/user/packages/{package_type}/{package_name}` endpoint.
+     *
+     * As opposed to `delete_package_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#delete-a-package-for-the-authenticated-user>
+     */
+    pub async fn delete_package_for_authenticated_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/packages/{}/{}",
@@ -476,7 +772,7 @@ impl Packages {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -512,6 +808,29 @@ impl Packages {
         package_name: &str,
         token: &str,
     ) -> ClientResult<()> {
+        self.restore_package_for_authenticated_user_with_response(
+            package_type,
+            package_name,
+            token,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Restore a package for the authenticated user.
+     *
+     * This function performs a `POST` to the `/user/packages/{package_type}/{package_name}/restore` endpoint.
+     *
+     * As opposed to `restore_package_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#restore-a-package-for-the-authenticated-user>
+     */
+    pub async fn restore_package_for_authenticated_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !token.is_empty() {
             query_args.push(("token".to_string(), token.to_string()));
@@ -527,7 +846,7 @@ impl Packages {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -564,6 +883,34 @@ impl Packages {
         per_page: i64,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> ClientResult<Vec<crate::types::PackageVersion>> {
+        Ok(self
+            .get_all_package_versions_for_package_owned_by_authenticated_user_with_response(
+                package_type,
+                package_name,
+                page,
+                per_page,
+                state,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_package_versions_for_package_owned_by_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub async fn get_all_package_versions_for_package_owned_by_authenticated_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        page: i64,
+        per_page: i64,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -585,7 +932,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -638,6 +985,96 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_authenticated_user_pages_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PackageVersion>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/user/packages/{}/{}/versions?{}",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PackageVersion>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_authenticated_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_all_package_versions_for_package_owned_by_authenticated_user_pages_stream(
+                package_type,
+                package_name,
+                state,
+                None,
+            ),
+        )
+    }
     /**
      * Get a package version for the authenticated user.
      *
@@ -662,6 +1099,30 @@ impl Packages {
         package_name: &str,
         package_version_id: i64,
     ) -> ClientResult<crate::types::PackageVersion> {
+        Ok(self
+            .get_package_version_for_authenticated_user_with_response(
+                package_type,
+                package_name,
+                package_version_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get a package version for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions/{package_version_id}` endpoint.
+     *
+     * As opposed to `get_package_version_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-a-package-version-for-the-authenticated-user>
+     */
+    pub async fn get_package_version_for_authenticated_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PackageVersion>> {
         let url = self.client.url(
             &format!(
                 "/user/packages/{}/{}/versions/{}",
@@ -672,7 +1133,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -705,6 +1166,29 @@ impl Packages {
         package_name: &str,
         package_version_id: i64,
     ) -> ClientResult<()> {
+        self.delete_package_version_for_authenticated_user_with_response(
+            package_type,
+            package_name,
+            package_version_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete a package version for the authenticated user.
+     *
+     * This function performs a `DELETE` to the `/user/packages/{package_type}/{package_name}/versions/{package_version_id}` endpoint.
+     *
+     * As opposed to `delete_package_version_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#delete-a-package-version-for-the-authenticated-user>
+     */
+    pub async fn delete_package_version_for_authenticated_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/packages/{}/{}/versions/{}",
@@ -715,7 +1199,7 @@ impl Packages {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -751,6 +1235,29 @@ impl Packages {
         package_name: &str,
         package_version_id: i64,
     ) -> ClientResult<()> {
+        self.restore_package_version_for_authenticated_user_with_response(
+            package_type,
+            package_name,
+            package_version_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Restore a package version for the authenticated user.
+     *
+     * This function performs a `POST` to the `/user/packages/{package_type}/{package_name}/versions/{package_version_id}/restore` endpoint.
+     *
+     * As opposed to `restore_package_version_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#restore-a-package-version-for-the-authenticated-user>
+     */
+    pub async fn restore_package_version_for_authenticated_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/packages/{}/{}/versions/{}/restore",
@@ -761,7 +1268,7 @@ impl Packages {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -794,6 +1301,26 @@ impl Packages {
         package_name: &str,
         username: &str,
     ) -> ClientResult<crate::types::Package> {
+        Ok(self
+            .get_package_for_user_with_response(package_type, package_name, username)
+            .await?
+            .body)
+    }
+    /**
+     * Get a package for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}` endpoint.
+     *
+     * As opposed to `get_package_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-a-package-for-a-user>
+     */
+    pub async fn get_package_for_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Package>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/packages/{}/{}",
@@ -804,7 +1331,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -837,6 +1364,30 @@ impl Packages {
         package_name: &str,
         username: &str,
     ) -> ClientResult<Vec<crate::types::PackageVersion>> {
+        Ok(self
+            .get_all_package_versions_for_package_owned_by_user_with_response(
+                package_type,
+                package_name,
+                username,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get all package versions for a package owned by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_package_versions_for_package_owned_by_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-a-user>
+     */
+    pub async fn get_all_package_versions_for_package_owned_by_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PackageVersion>>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/packages/{}/{}/versions",
@@ -847,7 +1398,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -895,6 +1446,91 @@ impl Packages {
             )
             .await
     }
+    /**
+     * Get all package versions for a package owned by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-a-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_user_pages_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PackageVersion>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(username),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(package_name),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PackageVersion>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all package versions for a package owned by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-a-user>
+     */
+    pub fn get_all_all_package_versions_for_package_owned_by_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PackageVersion>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_all_package_versions_for_package_owned_by_user_pages_stream(
+                package_type,
+                package_name,
+                username,
+                None,
+            ),
+        )
+    }
     /**
      * Get a package version for a user.
      *
@@ -921,6 +1557,32 @@ impl Packages {
         package_version_id: i64,
         username: &str,
     ) -> ClientResult<crate::types::PackageVersion> {
+        Ok(self
+            .get_package_version_for_user_with_response(
+                package_type,
+                package_name,
+                package_version_id,
+                username,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get a package version for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}` endpoint.
+     *
+     * As opposed to `get_package_version_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-a-package-version-for-a-user>
+     */
+    pub async fn get_package_version_for_user_with_response(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PackageVersion>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/packages/{}/{}/versions/{}",
@@ -932,7 +1594,7 @@ impl Packages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
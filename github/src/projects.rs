@@ -34,6 +34,27 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Project>> {
+        Ok(self
+            .list_for_org_with_response(org, state, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub async fn list_for_org_with_response(
+        &self,
+        org: &str,
+        state: crate::types::IssuesListState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -54,7 +75,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -102,6 +123,85 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub fn list_all_for_org_pages_stream(
+        &self,
+        org: &str,
+        state: crate::types::IssuesListState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Project>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/projects?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Project>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_org_pages_stream(org, state, None))
+    }
     /**
      * Create an organization project.
      *
@@ -120,6 +220,22 @@ impl Projects {
         org: &str,
         body: &crate::types::ProjectsCreateRequest,
     ) -> ClientResult<crate::types::Project> {
+        Ok(self.create_for_org_with_response(org, body).await?.body)
+    }
+    /**
+     * Create an organization project.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `create_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#create-an-organization-project>
+     */
+    pub async fn create_for_org_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/projects",
@@ -128,7 +244,7 @@ impl Projects {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -151,6 +267,21 @@ impl Projects {
      * * `card_id: i64` -- card_id parameter.
      */
     pub async fn get_card(&self, card_id: i64) -> ClientResult<crate::types::ProjectCard> {
+        Ok(self.get_card_with_response(card_id).await?.body)
+    }
+    /**
+     * Get a project card.
+     *
+     * This function performs a `GET` to the `/projects/columns/cards/{card_id}` endpoint.
+     *
+     * As opposed to `get_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#get-a-project-card>
+     */
+    pub async fn get_card_with_response(
+        &self,
+        card_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ProjectCard>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/cards/{}",
@@ -159,7 +290,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -182,6 +313,22 @@ impl Projects {
      * * `card_id: i64` -- card_id parameter.
      */
     pub async fn delete_card(&self, card_id: i64) -> ClientResult<()> {
+        self.delete_card_with_response(card_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a project card.
+     *
+     * This function performs a `DELETE` to the `/projects/columns/cards/{card_id}` endpoint.
+     *
+     * As opposed to `delete_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#delete-a-project-card>
+     */
+    pub async fn delete_card_with_response(
+        &self,
+        card_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/cards/{}",
@@ -190,7 +337,7 @@ impl Projects {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -217,6 +364,22 @@ impl Projects {
         card_id: i64,
         body: &crate::types::ProjectsUpdateCardRequest,
     ) -> ClientResult<crate::types::ProjectCard> {
+        Ok(self.update_card_with_response(card_id, body).await?.body)
+    }
+    /**
+     * Update an existing project card.
+     *
+     * This function performs a `PATCH` to the `/projects/columns/cards/{card_id}` endpoint.
+     *
+     * As opposed to `update_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#update-a-project-card>
+     */
+    pub async fn update_card_with_response(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsUpdateCardRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProjectCard>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/cards/{}",
@@ -225,7 +388,7 @@ impl Projects {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -252,6 +415,23 @@ impl Projects {
         card_id: i64,
         body: &crate::types::ProjectsMoveCardRequest,
     ) -> ClientResult<()> {
+        self.move_card_with_response(card_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Move a project card.
+     *
+     * This function performs a `POST` to the `/projects/columns/cards/{card_id}/moves` endpoint.
+     *
+     * As opposed to `move_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#move-a-project-card>
+     */
+    pub async fn move_card_with_response(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsMoveCardRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/cards/{}/moves",
@@ -260,7 +440,7 @@ impl Projects {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -283,6 +463,21 @@ impl Projects {
      * * `column_id: i64` -- column_id parameter.
      */
     pub async fn get_column(&self, column_id: i64) -> ClientResult<crate::types::ProjectColumn> {
+        Ok(self.get_column_with_response(column_id).await?.body)
+    }
+    /**
+     * Get a project column.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}` endpoint.
+     *
+     * As opposed to `get_column`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#get-a-project-column>
+     */
+    pub async fn get_column_with_response(
+        &self,
+        column_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ProjectColumn>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/{}",
@@ -291,7 +486,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -314,6 +509,22 @@ impl Projects {
      * * `column_id: i64` -- column_id parameter.
      */
     pub async fn delete_column(&self, column_id: i64) -> ClientResult<()> {
+        self.delete_column_with_response(column_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a project column.
+     *
+     * This function performs a `DELETE` to the `/projects/columns/{column_id}` endpoint.
+     *
+     * As opposed to `delete_column`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#delete-a-project-column>
+     */
+    pub async fn delete_column_with_response(
+        &self,
+        column_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/{}",
@@ -322,7 +533,7 @@ impl Projects {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -349,6 +560,25 @@ impl Projects {
         column_id: i64,
         body: &crate::types::ProjectsUpdateColumnRequest,
     ) -> ClientResult<crate::types::ProjectColumn> {
+        Ok(self
+            .update_column_with_response(column_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an existing project column.
+     *
+     * This function performs a `PATCH` to the `/projects/columns/{column_id}` endpoint.
+     *
+     * As opposed to `update_column`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#update-a-project-column>
+     */
+    pub async fn update_column_with_response(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsUpdateColumnRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProjectColumn>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/{}",
@@ -357,7 +587,7 @@ impl Projects {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -389,6 +619,27 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::ProjectCard>> {
+        Ok(self
+            .list_cards_with_response(column_id, archived_state, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_cards`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub async fn list_cards_with_response(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProjectCard>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !archived_state.to_string().is_empty() {
             query_args.push(("archived_state".to_string(), archived_state.to_string()));
@@ -409,7 +660,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -457,6 +708,86 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_all_cards`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub fn list_all_cards_pages_stream(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ProjectCard>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !archived_state.to_string().is_empty() {
+            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/projects/columns/{}/cards?{}",
+                crate::progenitor_support::encode_path(&column_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ProjectCard>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_all_cards`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub fn list_all_cards_stream(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProjectCard>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_cards_pages_stream(column_id, archived_state, None))
+    }
     /**
      * Create a project card.
      *
@@ -475,6 +806,22 @@ impl Projects {
         column_id: i64,
         body: &crate::types::ProjectsCreateCardRequestOneOf,
     ) -> ClientResult<crate::types::ProjectCard> {
+        Ok(self.create_card_with_response(column_id, body).await?.body)
+    }
+    /**
+     * Create a project card.
+     *
+     * This function performs a `POST` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `create_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#create-a-project-card>
+     */
+    pub async fn create_card_with_response(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsCreateCardRequestOneOf,
+    ) -> ClientResult<crate::Response<crate::types::ProjectCard>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/{}/cards",
@@ -483,7 +830,7 @@ impl Projects {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -510,6 +857,23 @@ impl Projects {
         column_id: i64,
         body: &crate::types::ProjectsMoveColumnRequest,
     ) -> ClientResult<()> {
+        self.move_column_with_response(column_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Move a project column.
+     *
+     * This function performs a `POST` to the `/projects/columns/{column_id}/moves` endpoint.
+     *
+     * As opposed to `move_column`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#move-a-project-column>
+     */
+    pub async fn move_column_with_response(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsMoveColumnRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/projects/columns/{}/moves",
@@ -518,7 +882,7 @@ impl Projects {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -541,6 +905,21 @@ impl Projects {
      * * `project_id: i64`
      */
     pub async fn get(&self, project_id: i64) -> ClientResult<crate::types::Project> {
+        Ok(self.get_with_response(project_id).await?.body)
+    }
+    /**
+     * Get a project.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#get-a-project>
+     */
+    pub async fn get_with_response(
+        &self,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
         let url = self.client.url(
             &format!(
                 "/projects/{}",
@@ -549,7 +928,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -572,6 +951,19 @@ impl Projects {
      * * `project_id: i64`
      */
     pub async fn delete(&self, project_id: i64) -> ClientResult<()> {
+        self.delete_with_response(project_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a project.
+     *
+     * This function performs a `DELETE` to the `/projects/{project_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#delete-a-project>
+     */
+    pub async fn delete_with_response(&self, project_id: i64) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/projects/{}",
@@ -580,7 +972,7 @@ impl Projects {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -607,6 +999,22 @@ impl Projects {
         project_id: i64,
         body: &crate::types::ProjectsUpdateRequest,
     ) -> ClientResult<crate::types::Project> {
+        Ok(self.update_with_response(project_id, body).await?.body)
+    }
+    /**
+     * Update a project.
+     *
+     * This function performs a `PATCH` to the `/projects/{project_id}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#update-a-project>
+     */
+    pub async fn update_with_response(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
         let url = self.client.url(
             &format!(
                 "/projects/{}",
@@ -615,7 +1023,7 @@ impl Projects {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -650,6 +1058,27 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_collaborators_with_response(project_id, affiliation, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_collaborators`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub async fn list_collaborators_with_response(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
@@ -670,7 +1099,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -718,6 +1147,90 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub fn list_all_collaborators_pages_stream(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !affiliation.to_string().is_empty() {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/projects/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_collaborators_pages_stream(
+            project_id,
+            affiliation,
+            None,
+        ))
+    }
     /**
      * Add project collaborator.
      *
@@ -738,6 +1251,25 @@ impl Projects {
         username: &str,
         body: &crate::types::ProjectsAddCollaboratorRequest,
     ) -> ClientResult<()> {
+        self.add_collaborator_with_response(project_id, username, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add project collaborator.
+     *
+     * This function performs a `PUT` to the `/projects/{project_id}/collaborators/{username}` endpoint.
+     *
+     * As opposed to `add_collaborator`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#add-project-collaborator>
+     */
+    pub async fn add_collaborator_with_response(
+        &self,
+        project_id: i64,
+        username: &str,
+        body: &crate::types::ProjectsAddCollaboratorRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/projects/{}/collaborators/{}",
@@ -747,7 +1279,7 @@ impl Projects {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -771,6 +1303,24 @@ impl Projects {
      * * `username: &str`
      */
     pub async fn remove_collaborator(&self, project_id: i64, username: &str) -> ClientResult<()> {
+        self.remove_collaborator_with_response(project_id, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove user as a collaborator.
+     *
+     * This function performs a `DELETE` to the `/projects/{project_id}/collaborators/{username}` endpoint.
+     *
+     * As opposed to `remove_collaborator`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#remove-project-collaborator>
+     */
+    pub async fn remove_collaborator_with_response(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/projects/{}/collaborators/{}",
@@ -780,7 +1330,7 @@ impl Projects {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -808,6 +1358,25 @@ impl Projects {
         project_id: i64,
         username: &str,
     ) -> ClientResult<crate::types::RepositoryCollaboratorPermission> {
+        Ok(self
+            .get_permission_for_user_with_response(project_id, username)
+            .await?
+            .body)
+    }
+    /**
+     * Get project permission for a user.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators/{username}/permission` endpoint.
+     *
+     * As opposed to `get_permission_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#get-project-permission-for-a-user>
+     */
+    pub async fn get_permission_for_user_with_response(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryCollaboratorPermission>> {
         let url = self.client.url(
             &format!(
                 "/projects/{}/collaborators/{}/permission",
@@ -817,7 +1386,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -847,6 +1416,26 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::ProjectColumn>> {
+        Ok(self
+            .list_columns_with_response(project_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_columns`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub async fn list_columns_with_response(
+        &self,
+        project_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProjectColumn>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -864,7 +1453,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -905,6 +1494,78 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_all_columns`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub fn list_all_columns_pages_stream(
+        &self,
+        project_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ProjectColumn>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/projects/{}/columns",
+                crate::progenitor_support::encode_path(&project_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ProjectColumn>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_all_columns`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub fn list_all_columns_stream(
+        &self,
+        project_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProjectColumn>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_columns_pages_stream(project_id, None))
+    }
     /**
      * Create a project column.
      *
@@ -923,6 +1584,25 @@ impl Projects {
         project_id: i64,
         body: &crate::types::ProjectsUpdateColumnRequest,
     ) -> ClientResult<crate::types::ProjectColumn> {
+        Ok(self
+            .create_column_with_response(project_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a project column.
+     *
+     * This function performs a `POST` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `create_column`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#create-a-project-column>
+     */
+    pub async fn create_column_with_response(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsUpdateColumnRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProjectColumn>> {
         let url = self.client.url(
             &format!(
                 "/projects/{}/columns",
@@ -931,7 +1611,7 @@ impl Projects {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -965,6 +1645,28 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Project>> {
+        Ok(self
+            .list_for_repo_with_response(owner, repo, state, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub async fn list_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -986,7 +1688,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1036,6 +1738,88 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub fn list_all_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Project>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/projects?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Project>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub fn list_all_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_repo_pages_stream(owner, repo, state, None))
+    }
     /**
      * Create a repository project.
      *
@@ -1056,6 +1840,26 @@ impl Projects {
         repo: &str,
         body: &crate::types::ProjectsCreateRequest,
     ) -> ClientResult<crate::types::Project> {
+        Ok(self
+            .create_for_repo_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a repository project.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `create_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#create-a-repository-project>
+     */
+    pub async fn create_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/projects",
@@ -1065,7 +1869,7 @@ impl Projects {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1087,9 +1891,27 @@ impl Projects {
         &self,
         body: &crate::types::ProjectsCreateRequest,
     ) -> ClientResult<crate::types::Project> {
+        Ok(self
+            .create_for_authenticated_user_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a user project.
+     *
+     * This function performs a `POST` to the `/user/projects` endpoint.
+     *
+     * As opposed to `create_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#create-a-user-project>
+     */
+    pub async fn create_for_authenticated_user_with_response(
+        &self,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Project>> {
         let url = self.client.url("/user/projects", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1121,6 +1943,27 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Project>> {
+        Ok(self
+            .list_for_user_with_response(username, state, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub async fn list_for_user_with_response(
+        &self,
+        username: &str,
+        state: crate::types::IssuesListState,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Project>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1141,7 +1984,7 @@ impl Projects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1189,4 +2032,83 @@ impl Projects {
             )
             .await
     }
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub fn list_all_for_user_pages_stream(
+        &self,
+        username: &str,
+        state: crate::types::IssuesListState,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Project>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/projects?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Project>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        state: crate::types::IssuesListState,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Project>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_user_pages_stream(username, state, None))
+    }
 }
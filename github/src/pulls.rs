@@ -46,6 +46,34 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::PullRequestSimple>> {
+        Ok(self
+            .list_with_response(
+                owner, repo, state, head, base, sort, direction, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub async fn list_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !base.is_empty() {
             query_args.push(("base".to_string(), base.to_string()));
@@ -79,7 +107,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -145,6 +173,112 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PullRequestSimple>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !base.is_empty() {
+            query_args.push(("base".to_string(), base.to_string()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !head.is_empty() {
+            query_args.push(("head".to_string(), head.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PullRequestSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub fn list_all_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestSimple>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_pages_stream(owner, repo, state, head, base, sort, direction, None),
+        )
+    }
     /**
      * Create a pull request.
      *
@@ -171,6 +305,23 @@ impl Pulls {
         repo: &str,
         body: &crate::types::PullsCreateRequest,
     ) -> ClientResult<crate::types::PullRequestData> {
+        Ok(self.create_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Create a pull request.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#create-a-pull-request>
+     */
+    pub async fn create_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::PullsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls",
@@ -180,7 +331,7 @@ impl Pulls {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -220,6 +371,32 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::PullRequestReviewComment>> {
+        Ok(self
+            .list_review_comments_for_repo_with_response(
+                owner, repo, sort, direction, since, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_review_comments_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     */
+    pub async fn list_review_comments_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -247,7 +424,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -305,6 +482,103 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     */
+    pub fn list_all_review_comments_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::PullRequestReviewComment>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PullRequestReviewComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     */
+    pub fn list_all_review_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewComment>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_review_comments_for_repo_pages_stream(
+            owner, repo, sort, direction, since, None,
+        ))
+    }
     /**
      * Get a review comment for a pull request.
      *
@@ -326,6 +600,26 @@ impl Pulls {
         repo: &str,
         comment_id: i64,
     ) -> ClientResult<crate::types::PullRequestReviewComment> {
+        Ok(self
+            .get_review_comment_with_response(owner, repo, comment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a review comment for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `get_review_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#get-a-review-comment-for-a-pull-request>
+     */
+    pub async fn get_review_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/comments/{}",
@@ -336,7 +630,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -366,6 +660,25 @@ impl Pulls {
         repo: &str,
         comment_id: i64,
     ) -> ClientResult<()> {
+        self.delete_review_comment_with_response(owner, repo, comment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a review comment for a pull request.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `delete_review_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#delete-a-review-comment-for-a-pull-request>
+     */
+    pub async fn delete_review_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/comments/{}",
@@ -376,7 +689,7 @@ impl Pulls {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -407,6 +720,27 @@ impl Pulls {
         comment_id: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::PullRequestReviewComment> {
+        Ok(self
+            .update_review_comment_with_response(owner, repo, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a review comment for a pull request.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `update_review_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#update-a-review-comment-for-a-pull-request>
+     */
+    pub async fn update_review_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/comments/{}",
@@ -417,7 +751,7 @@ impl Pulls {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -461,6 +795,23 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
     ) -> ClientResult<crate::types::PullRequestData> {
+        Ok(self.get_with_response(owner, repo, pull_number).await?.body)
+    }
+    /**
+     * Get a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#get-a-pull-request>
+     */
+    pub async fn get_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}",
@@ -471,7 +822,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -504,6 +855,27 @@ impl Pulls {
         pull_number: i64,
         body: &crate::types::PullsUpdateRequest,
     ) -> ClientResult<crate::types::PullRequestData> {
+        Ok(self
+            .update_with_response(owner, repo, pull_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a pull request.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/pulls/{pull_number}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls/#update-a-pull-request>
+     */
+    pub async fn update_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}",
@@ -514,7 +886,7 @@ impl Pulls {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -556,6 +928,40 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::PullRequestReviewComment>> {
+        Ok(self
+            .list_review_comments_with_response(
+                owner,
+                repo,
+                pull_number,
+                sort,
+                direction,
+                since,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_review_comments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub async fn list_review_comments_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -584,7 +990,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -644,6 +1050,112 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub fn list_all_review_comments_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::PullRequestReviewComment>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/comments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PullRequestReviewComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub fn list_all_review_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewComment>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_review_comments_pages_stream(
+            owner,
+            repo,
+            pull_number,
+            sort,
+            direction,
+            since,
+            None,
+        ))
+    }
     /**
      * Create a review comment for a pull request.
      *
@@ -673,6 +1185,27 @@ impl Pulls {
         pull_number: i64,
         body: &crate::types::PullsCreateReviewCommentRequest,
     ) -> ClientResult<crate::types::PullRequestReviewComment> {
+        Ok(self
+            .create_review_comment_with_response(owner, repo, pull_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a review comment for a pull request.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `create_review_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#create-a-review-comment-for-a-pull-request>
+     */
+    pub async fn create_review_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewCommentRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/comments",
@@ -683,7 +1216,7 @@ impl Pulls {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -718,6 +1251,34 @@ impl Pulls {
         comment_id: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::PullRequestReviewComment> {
+        Ok(self
+            .create_reply_for_review_comment_with_response(
+                owner,
+                repo,
+                pull_number,
+                comment_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Create a reply for a review comment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies` endpoint.
+     *
+     * As opposed to `create_reply_for_review_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#create-a-reply-for-a-review-comment>
+     */
+    pub async fn create_reply_for_review_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/comments/{}/replies",
@@ -729,7 +1290,7 @@ impl Pulls {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -763,6 +1324,28 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::CommitDataType>> {
+        Ok(self
+            .list_commits_with_response(owner, repo, pull_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub async fn list_commits_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -782,7 +1365,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -827,6 +1410,84 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub fn list_all_commits_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CommitDataType>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/commits",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CommitDataType>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitDataType>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_commits_pages_stream(owner, repo, pull_number, None))
+    }
     /**
      * List pull requests files.
      *
@@ -852,6 +1513,28 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::DiffEntry>> {
+        Ok(self
+            .list_files_with_response(owner, repo, pull_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List pull requests files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub async fn list_files_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DiffEntry>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -871,7 +1554,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -916,6 +1599,84 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List pull requests files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_all_files`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub fn list_all_files_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::DiffEntry>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/files",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::DiffEntry>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List pull requests files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_all_files`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub fn list_all_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DiffEntry>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_files_pages_stream(owner, repo, pull_number, None))
+    }
     /**
      * Check if a pull request has been merged.
      *
@@ -937,6 +1698,25 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
     ) -> ClientResult<()> {
+        self.check_if_merged_with_response(owner, repo, pull_number)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check if a pull request has been merged.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/merge` endpoint.
+     *
+     * As opposed to `check_if_merged`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#check-if-a-pull-request-has-been-merged>
+     */
+    pub async fn check_if_merged_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/merge",
@@ -947,7 +1727,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -978,6 +1758,27 @@ impl Pulls {
         pull_number: i64,
         body: &crate::types::PullsMergeRequest,
     ) -> ClientResult<crate::types::PullRequestMergeResult> {
+        Ok(self
+            .merge_with_response(owner, repo, pull_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Merge a pull request.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/pulls/{pull_number}/merge` endpoint.
+     *
+     * As opposed to `merge`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#merge-a-pull-request>
+     */
+    pub async fn merge_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsMergeRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestMergeResult>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/merge",
@@ -988,7 +1789,7 @@ impl Pulls {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1022,6 +1823,28 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::PullRequestReview> {
+        Ok(self
+            .list_requested_reviewers_with_response(owner, repo, pull_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List requested reviewers for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers` endpoint.
+     *
+     * As opposed to `list_requested_reviewers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-requested-reviewers-for-a-pull-request>
+     */
+    pub async fn list_requested_reviewers_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReview>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1041,7 +1864,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1072,6 +1895,27 @@ impl Pulls {
         pull_number: i64,
         body: &crate::types::PullsRequestReviewers,
     ) -> ClientResult<crate::types::PullRequestSimple> {
+        Ok(self
+            .request_reviewers_with_response(owner, repo, pull_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Request reviewers for a pull request.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers` endpoint.
+     *
+     * As opposed to `request_reviewers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#request-reviewers-for-a-pull-request>
+     */
+    pub async fn request_reviewers_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRequestReviewers,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestSimple>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/requested_reviewers",
@@ -1082,7 +1926,7 @@ impl Pulls {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1113,6 +1957,27 @@ impl Pulls {
         pull_number: i64,
         body: &crate::types::PullsRemoveRequestedReviewersRequest,
     ) -> ClientResult<crate::types::PullRequestSimple> {
+        Ok(self
+            .remove_requested_reviewers_with_response(owner, repo, pull_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove requested reviewers from a pull request.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers` endpoint.
+     *
+     * As opposed to `remove_requested_reviewers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#remove-requested-reviewers-from-a-pull-request>
+     */
+    pub async fn remove_requested_reviewers_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRemoveRequestedReviewersRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestSimple>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/requested_reviewers",
@@ -1123,7 +1988,7 @@ impl Pulls {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1157,6 +2022,28 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::PullRequestReviewData>> {
+        Ok(self
+            .list_reviews_with_response(owner, repo, pull_number, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_reviews`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub async fn list_reviews_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestReviewData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1176,7 +2063,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1221,6 +2108,86 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_all_reviews`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub fn list_all_reviews_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::PullRequestReviewData>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PullRequestReviewData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_all_reviews`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub fn list_all_reviews_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestReviewData>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_reviews_pages_stream(owner, repo, pull_number, None))
+    }
     /**
      * Create a review for a pull request.
      *
@@ -1249,6 +2216,27 @@ impl Pulls {
         pull_number: i64,
         body: &crate::types::PullsCreateReviewRequest,
     ) -> ClientResult<crate::types::PullRequestReviewData> {
+        Ok(self
+            .create_review_with_response(owner, repo, pull_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a review for a pull request.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `create_review`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#create-a-review-for-a-pull-request>
+     */
+    pub async fn create_review_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews",
@@ -1259,7 +2247,7 @@ impl Pulls {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1291,6 +2279,27 @@ impl Pulls {
         pull_number: i64,
         review_id: i64,
     ) -> ClientResult<crate::types::PullRequestReviewData> {
+        Ok(self
+            .get_review_with_response(owner, repo, pull_number, review_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a review for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}` endpoint.
+     *
+     * As opposed to `get_review`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#get-a-review-for-a-pull-request>
+     */
+    pub async fn get_review_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews/{}",
@@ -1302,7 +2311,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1335,6 +2344,28 @@ impl Pulls {
         review_id: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::PullRequestReviewData> {
+        Ok(self
+            .update_review_with_response(owner, repo, pull_number, review_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a review for a pull request.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}` endpoint.
+     *
+     * As opposed to `update_review`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#update-a-review-for-a-pull-request>
+     */
+    pub async fn update_review_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews/{}",
@@ -1346,7 +2377,7 @@ impl Pulls {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1378,6 +2409,27 @@ impl Pulls {
         pull_number: i64,
         review_id: i64,
     ) -> ClientResult<crate::types::PullRequestReviewData> {
+        Ok(self
+            .delete_pending_review_with_response(owner, repo, pull_number, review_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a pending review for a pull request.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}` endpoint.
+     *
+     * As opposed to `delete_pending_review`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#delete-a-pending-review-for-a-pull-request>
+     */
+    pub async fn delete_pending_review_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews/{}",
@@ -1389,7 +2441,7 @@ impl Pulls {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1425,6 +2477,36 @@ impl Pulls {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::ReviewComment>> {
+        Ok(self
+            .list_comments_for_review_with_response(
+                owner,
+                repo,
+                pull_number,
+                review_id,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_comments_for_review`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub async fn list_comments_for_review_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReviewComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1445,7 +2527,7 @@ impl Pulls {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1492,6 +2574,93 @@ impl Pulls {
             )
             .await
     }
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_review`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub fn list_all_comments_for_review_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ReviewComment>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&pull_number.to_string()),
+                crate::progenitor_support::encode_path(&review_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ReviewComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_review`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub fn list_all_comments_for_review_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReviewComment>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_comments_for_review_pages_stream(
+            owner,
+            repo,
+            pull_number,
+            review_id,
+            None,
+        ))
+    }
     /**
      * Dismiss a review for a pull request.
      *
@@ -1516,6 +2685,28 @@ impl Pulls {
         review_id: i64,
         body: &crate::types::PullsDismissReviewRequest,
     ) -> ClientResult<crate::types::PullRequestReviewData> {
+        Ok(self
+            .dismiss_review_with_response(owner, repo, pull_number, review_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Dismiss a review for a pull request.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/dismissals` endpoint.
+     *
+     * As opposed to `dismiss_review`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#dismiss-a-review-for-a-pull-request>
+     */
+    pub async fn dismiss_review_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsDismissReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews/{}/dismissals",
@@ -1527,7 +2718,7 @@ impl Pulls {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1560,6 +2751,28 @@ impl Pulls {
         review_id: i64,
         body: &crate::types::PullsSubmitReviewRequest,
     ) -> ClientResult<crate::types::PullRequestReviewData> {
+        Ok(self
+            .submit_review_with_response(owner, repo, pull_number, review_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Submit a review for a pull request.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events` endpoint.
+     *
+     * As opposed to `submit_review`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request>
+     */
+    pub async fn submit_review_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsSubmitReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullRequestReviewData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/reviews/{}/events",
@@ -1571,7 +2784,7 @@ impl Pulls {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1602,6 +2815,27 @@ impl Pulls {
         pull_number: i64,
         body: &crate::types::PullsUpdateBranchRequest,
     ) -> ClientResult<crate::types::PullsUpdateBranchResponse> {
+        Ok(self
+            .update_branch_with_response(owner, repo, pull_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a pull request branch.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/pulls/{pull_number}/update-branch` endpoint.
+     *
+     * As opposed to `update_branch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#update-a-pull-request-branch>
+     */
+    pub async fn update_branch_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateBranchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PullsUpdateBranchResponse>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/{}/update-branch",
@@ -1612,7 +2846,7 @@ impl Pulls {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -23,9 +23,23 @@ impl RateLimit {
      * FROM: <https://docs.github.com/rest/reference/rate-limit#get-rate-limit-status-for-the-authenticated-user>
      */
     pub async fn get(&self) -> ClientResult<crate::types::RateLimitOverview> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * Get rate limit status for the authenticated user.
+     *
+     * This function performs a `GET` to the `/rate_limit` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/rate-limit#get-rate-limit-status-for-the-authenticated-user>
+     */
+    pub async fn get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::RateLimitOverview>> {
         let url = self.client.url("/rate_limit", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
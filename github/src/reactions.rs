@@ -42,6 +42,38 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_team_discussion_comment_in_org_with_response(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                content,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_comment_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub async fn list_for_team_discussion_comment_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -65,7 +97,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -121,6 +153,103 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub fn list_all_for_team_discussion_comment_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub fn list_all_for_team_discussion_comment_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_for_team_discussion_comment_in_org_pages_stream(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                content,
+                None,
+            ),
+        )
+    }
     /**
      * Create reaction for a team discussion comment.
      *
@@ -147,6 +276,34 @@ impl Reactions {
         comment_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_team_discussion_comment_in_org_with_response(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for a team discussion comment.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `create_for_team_discussion_comment_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-a-team-discussion-comment>
+     */
+    pub async fn create_for_team_discussion_comment_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions",
@@ -158,7 +315,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -194,6 +351,33 @@ impl Reactions {
         comment_number: i64,
         reaction_id: i64,
     ) -> ClientResult<()> {
+        self.delete_for_team_discussion_comment_with_response(
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            reaction_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete team discussion comment reaction.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions/{reaction_id}` endpoint.
+     *
+     * As opposed to `delete_for_team_discussion_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#delete-team-discussion-comment-reaction>
+     */
+    pub async fn delete_for_team_discussion_comment_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions/{}",
@@ -206,7 +390,7 @@ impl Reactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -244,6 +428,36 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_team_discussion_in_org_with_response(
+                org,
+                team_slug,
+                discussion_number,
+                content,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub async fn list_for_team_discussion_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -266,7 +480,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -320,6 +534,97 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub fn list_all_for_team_discussion_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub fn list_all_for_team_discussion_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_team_discussion_in_org_pages_stream(
+            org,
+            team_slug,
+            discussion_number,
+            content,
+            None,
+        ))
+    }
     /**
      * Create reaction for a team discussion.
      *
@@ -344,6 +649,32 @@ impl Reactions {
         discussion_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_team_discussion_in_org_with_response(
+                org,
+                team_slug,
+                discussion_number,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for a team discussion.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `create_for_team_discussion_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-a-team-discussion>
+     */
+    pub async fn create_for_team_discussion_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/reactions",
@@ -354,7 +685,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -388,6 +719,31 @@ impl Reactions {
         discussion_number: i64,
         reaction_id: i64,
     ) -> ClientResult<()> {
+        self.delete_for_team_discussion_with_response(
+            org,
+            team_slug,
+            discussion_number,
+            reaction_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete team discussion reaction.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions/{reaction_id}` endpoint.
+     *
+     * As opposed to `delete_for_team_discussion`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#delete-team-discussion-reaction>
+     */
+    pub async fn delete_for_team_discussion_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/reactions/{}",
@@ -399,7 +755,7 @@ impl Reactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -424,6 +780,22 @@ impl Reactions {
      * * `reaction_id: i64`
      */
     pub async fn delete_legacy(&self, reaction_id: i64) -> ClientResult<()> {
+        self.delete_legacy_with_response(reaction_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a reaction (Legacy).
+     *
+     * This function performs a `DELETE` to the `/reactions/{reaction_id}` endpoint.
+     *
+     * As opposed to `delete_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#delete-a-reaction-legacy>
+     */
+    pub async fn delete_legacy_with_response(
+        &self,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/reactions/{}",
@@ -432,7 +804,7 @@ impl Reactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -468,6 +840,29 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_commit_comment_with_response(owner, repo, comment_id, content, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List reactions for a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_for_commit_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     */
+    pub async fn list_for_commit_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -490,7 +885,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -543,48 +938,156 @@ impl Reactions {
             .await
     }
     /**
-     * Create reaction for a commit comment.
-     *
-     * This function performs a `POST` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
-     *
-     * Create a reaction to a [commit comment](https://docs.github.com/rest/reference/repos#comments). A response with an HTTP `200` status means that you already added the reaction type to this commit comment.
+     * List reactions for a commit comment.
      *
-     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-a-commit-comment>
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_for_commit_comment`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `comment_id: i64` -- comment_id parameter.
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
      */
-    pub async fn create_for_commit_comment(
+    pub fn list_all_for_commit_comment_pages_stream(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
-    ) -> ClientResult<crate::types::Reaction> {
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/repos/{}/{}/comments/{}/reactions",
+                "/repos/{}/{}/comments/{}/reactions?{}",
                 crate::progenitor_support::encode_path(owner),
                 crate::progenitor_support::encode_path(repo),
                 crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
             ),
             None,
         );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Delete a commit comment reaction.
+     * List reactions for a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_commit_comment`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     */
+    pub fn list_all_for_commit_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_for_commit_comment_pages_stream(owner, repo, comment_id, content, None),
+        )
+    }
+    /**
+     * Create reaction for a commit comment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * Create a reaction to a [commit comment](https://docs.github.com/rest/reference/repos#comments). A response with an HTTP `200` status means that you already added the reaction type to this commit comment.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-a-commit-comment>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `comment_id: i64` -- comment_id parameter.
+     */
+    pub async fn create_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_commit_comment_with_response(owner, repo, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for a commit comment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `create_for_commit_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-a-commit-comment>
+     */
+    pub async fn create_for_commit_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/comments/{}/reactions",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+            ),
+            None,
+        );
+        self.client
+            .post_with_response(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
+                },
+            )
+            .await
+    }
+    /**
+     * Delete a commit comment reaction.
      *
      * This function performs a `DELETE` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}` endpoint.
      *
@@ -608,6 +1111,26 @@ impl Reactions {
         comment_id: i64,
         reaction_id: i64,
     ) -> ClientResult<()> {
+        self.delete_for_commit_comment_with_response(owner, repo, comment_id, reaction_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a commit comment reaction.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}` endpoint.
+     *
+     * As opposed to `delete_for_commit_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#delete-a-commit-comment-reaction>
+     */
+    pub async fn delete_for_commit_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/comments/{}/reactions/{}",
@@ -619,7 +1142,7 @@ impl Reactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -655,6 +1178,29 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_issue_comment_with_response(owner, repo, comment_id, content, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_for_issue_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub async fn list_for_issue_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -677,7 +1223,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -729,6 +1275,93 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue_comment`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub fn list_all_for_issue_comment_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue_comment`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub fn list_all_for_issue_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_for_issue_comment_pages_stream(owner, repo, comment_id, content, None),
+        )
+    }
     /**
      * Create reaction for an issue comment.
      *
@@ -751,6 +1384,27 @@ impl Reactions {
         comment_id: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_issue_comment_with_response(owner, repo, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for an issue comment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `create_for_issue_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-an-issue-comment>
+     */
+    pub async fn create_for_issue_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/comments/{}/reactions",
@@ -761,7 +1415,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -795,6 +1449,26 @@ impl Reactions {
         comment_id: i64,
         reaction_id: i64,
     ) -> ClientResult<()> {
+        self.delete_for_issue_comment_with_response(owner, repo, comment_id, reaction_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an issue comment reaction.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}` endpoint.
+     *
+     * As opposed to `delete_for_issue_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#delete-an-issue-comment-reaction>
+     */
+    pub async fn delete_for_issue_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/comments/{}/reactions/{}",
@@ -806,7 +1480,7 @@ impl Reactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -842,6 +1516,29 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_issue_with_response(owner, repo, issue_number, content, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_issue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub async fn list_for_issue_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -864,7 +1561,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -916,6 +1613,97 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub fn list_all_for_issue_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/issues/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&issue_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub fn list_all_for_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_issue_pages_stream(
+            owner,
+            repo,
+            issue_number,
+            content,
+            None,
+        ))
+    }
     /**
      * Create reaction for an issue.
      *
@@ -938,6 +1726,27 @@ impl Reactions {
         issue_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_issue_with_response(owner, repo, issue_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for an issue.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `create_for_issue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-an-issue>
+     */
+    pub async fn create_for_issue_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/reactions",
@@ -948,7 +1757,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -982,6 +1791,26 @@ impl Reactions {
         issue_number: i64,
         reaction_id: i64,
     ) -> ClientResult<()> {
+        self.delete_for_issue_with_response(owner, repo, issue_number, reaction_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an issue reaction.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}` endpoint.
+     *
+     * As opposed to `delete_for_issue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#delete-an-issue-reaction>
+     */
+    pub async fn delete_for_issue_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/issues/{}/reactions/{}",
@@ -993,34 +1822,59 @@ impl Reactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await
+    }
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `comment_id: i64` -- comment_id parameter.
+     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a pull request review comment.
+     * * `per_page: i64` -- Results per page (max 100).
+     * * `page: i64` -- Page number of the results to fetch.
+     */
+    pub async fn list_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_pull_request_review_comment_with_response(
+                owner, repo, comment_id, content, per_page, page,
+            )
+            .await?
+            .body)
     }
     /**
      * List reactions for a pull request review comment.
      *
      * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
      *
-     * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
+     * As opposed to `list_for_pull_request_review_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
-     *
-     * **Parameters:**
-     *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `comment_id: i64` -- comment_id parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a pull request review comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
      */
-    pub async fn list_for_pull_request_review_comment(
+    pub async fn list_for_pull_request_review_comment_with_response(
         &self,
         owner: &str,
         repo: &str,
@@ -1028,7 +1882,7 @@ impl Reactions {
         content: crate::types::Content,
         per_page: i64,
         page: i64,
-    ) -> ClientResult<Vec<crate::types::Reaction>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -1051,7 +1905,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1103,6 +1957,93 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_pull_request_review_comment`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     */
+    pub fn list_all_for_pull_request_review_comment_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pulls/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&comment_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_pull_request_review_comment`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     */
+    pub fn list_all_for_pull_request_review_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_pull_request_review_comment_pages_stream(
+            owner, repo, comment_id, content, None,
+        ))
+    }
     /**
      * Create reaction for a pull request review comment.
      *
@@ -1125,6 +2066,27 @@ impl Reactions {
         comment_id: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_pull_request_review_comment_with_response(owner, repo, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for a pull request review comment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `create_for_pull_request_review_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#create-reaction-for-a-pull-request-review-comment>
+     */
+    pub async fn create_for_pull_request_review_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/comments/{}/reactions",
@@ -1135,7 +2097,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1169,6 +2131,26 @@ impl Reactions {
         comment_id: i64,
         reaction_id: i64,
     ) -> ClientResult<()> {
+        self.delete_for_pull_request_comment_with_response(owner, repo, comment_id, reaction_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a pull request comment reaction.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions/{reaction_id}` endpoint.
+     *
+     * As opposed to `delete_for_pull_request_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#delete-a-pull-request-comment-reaction>
+     */
+    pub async fn delete_for_pull_request_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pulls/comments/{}/reactions/{}",
@@ -1180,7 +2162,7 @@ impl Reactions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1211,6 +2193,27 @@ impl Reactions {
         release_id: i64,
         body: &crate::types::ReactionsCreateReleaseRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_release_with_response(owner, repo, release_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for a release.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/releases/{release_id}/reactions` endpoint.
+     *
+     * As opposed to `create_for_release`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#create-reaction-for-a-release>
+     */
+    pub async fn create_for_release_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReactionsCreateReleaseRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/{}/reactions",
@@ -1221,7 +2224,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1259,6 +2262,36 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_team_discussion_comment_legacy_with_response(
+                team_id,
+                discussion_number,
+                comment_number,
+                content,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_comment_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub async fn list_for_team_discussion_comment_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -1281,7 +2314,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1335,6 +2368,99 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub fn list_all_for_team_discussion_comment_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                crate::progenitor_support::encode_path(&comment_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub fn list_all_for_team_discussion_comment_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_for_team_discussion_comment_legacy_pages_stream(
+                team_id,
+                discussion_number,
+                comment_number,
+                content,
+                None,
+            ),
+        )
+    }
     /**
      * Create reaction for a team discussion comment (Legacy).
      *
@@ -1359,6 +2485,32 @@ impl Reactions {
         comment_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_team_discussion_comment_legacy_with_response(
+                team_id,
+                discussion_number,
+                comment_number,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for a team discussion comment (Legacy).
+     *
+     * This function performs a `POST` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `create_for_team_discussion_comment_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#create-reaction-for-a-team-discussion-comment-legacy>
+     */
+    pub async fn create_for_team_discussion_comment_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}/comments/{}/reactions",
@@ -1369,7 +2521,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1405,6 +2557,34 @@ impl Reactions {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Reaction>> {
+        Ok(self
+            .list_for_team_discussion_legacy_with_response(
+                team_id,
+                discussion_number,
+                content,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_for_team_discussion_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub async fn list_for_team_discussion_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !content.to_string().is_empty() {
             query_args.push(("content".to_string(), content.to_string()));
@@ -1426,7 +2606,7 @@ impl Reactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1478,6 +2658,93 @@ impl Reactions {
             )
             .await
     }
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub fn list_all_for_team_discussion_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reaction>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !content.to_string().is_empty() {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/reactions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Reaction>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub fn list_all_for_team_discussion_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reaction>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_team_discussion_legacy_pages_stream(
+            team_id,
+            discussion_number,
+            content,
+            None,
+        ))
+    }
     /**
      * Create reaction for a team discussion (Legacy).
      *
@@ -1500,6 +2767,26 @@ impl Reactions {
         discussion_number: i64,
         body: &crate::types::ReactionsCreateIssueRequest,
     ) -> ClientResult<crate::types::Reaction> {
+        Ok(self
+            .create_for_team_discussion_legacy_with_response(team_id, discussion_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create reaction for a team discussion (Legacy).
+     *
+     * This function performs a `POST` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `create_for_team_discussion_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#create-reaction-for-a-team-discussion-legacy>
+     */
+    pub async fn create_for_team_discussion_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> ClientResult<crate::Response<crate::types::Reaction>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}/reactions",
@@ -1509,7 +2796,7 @@ impl Reactions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -49,6 +49,29 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_for_org_with_response(org, type_, sort, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub async fn list_for_org_with_response(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -75,7 +98,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -131,6 +154,99 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub fn list_all_for_org_pages_stream(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/repos?{}",
+                crate::progenitor_support::encode_path(org),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub fn list_all_for_org_stream(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_for_org_pages_stream(org, type_, sort, direction, None),
+        )
+    }
     /**
      * Create an organization repository.
      *
@@ -156,6 +272,22 @@ impl Repos {
         org: &str,
         body: &crate::types::ReposCreateInOrgRequest,
     ) -> ClientResult<crate::types::Repository> {
+        Ok(self.create_in_org_with_response(org, body).await?.body)
+    }
+    /**
+     * Create an organization repository.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `create_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-an-organization-repository>
+     */
+    pub async fn create_in_org_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::ReposCreateInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::Repository>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/repos",
@@ -164,7 +296,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -190,6 +322,22 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn get(&self, owner: &str, repo: &str) -> ClientResult<crate::types::FullRepository> {
+        Ok(self.get_with_response(owner, repo).await?.body)
+    }
+    /**
+     * Get a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-repository>
+     */
+    pub async fn get_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::FullRepository>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}",
@@ -199,7 +347,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -226,6 +374,23 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn delete(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.delete_with_response(owner, repo).await?;
+        Ok(())
+    }
+    /**
+     * Delete a repository.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-repository>
+     */
+    pub async fn delete_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}",
@@ -235,7 +400,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -264,6 +429,23 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposUpdateRequest,
     ) -> ClientResult<crate::types::FullRepository> {
+        Ok(self.update_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Update a repository.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos/#update-a-repository>
+     */
+    pub async fn update_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullRepository>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}",
@@ -273,7 +455,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -305,6 +487,26 @@ impl Repos {
         repo: &str,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Autolink>> {
+        Ok(self
+            .list_autolinks_with_response(owner, repo, page)
+            .await?
+            .body)
+    }
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_autolinks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub async fn list_autolinks_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Autolink>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -320,7 +522,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -365,6 +567,80 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_all_autolinks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub fn list_all_autolinks_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Autolink>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/autolinks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Autolink>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_all_autolinks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub fn list_all_autolinks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Autolink>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_autolinks_pages_stream(owner, repo, None))
+    }
     /**
      * Create an autolink reference for a repository.
      *
@@ -385,6 +661,26 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposCreateAutolinkRequest,
     ) -> ClientResult<crate::types::Autolink> {
+        Ok(self
+            .create_autolink_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an autolink reference for a repository.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `create_autolink`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/v3/repos#create-an-autolink>
+     */
+    pub async fn create_autolink_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateAutolinkRequest,
+    ) -> ClientResult<crate::Response<crate::types::Autolink>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/autolinks",
@@ -394,7 +690,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -426,6 +722,26 @@ impl Repos {
         repo: &str,
         autolink_id: i64,
     ) -> ClientResult<crate::types::Autolink> {
+        Ok(self
+            .get_autolink_with_response(owner, repo, autolink_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get an autolink reference of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks/{autolink_id}` endpoint.
+     *
+     * As opposed to `get_autolink`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/v3/repos#get-autolink>
+     */
+    pub async fn get_autolink_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Autolink>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/autolinks/{}",
@@ -436,7 +752,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -468,6 +784,25 @@ impl Repos {
         repo: &str,
         autolink_id: i64,
     ) -> ClientResult<()> {
+        self.delete_autolink_with_response(owner, repo, autolink_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an autolink reference from a repository.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/autolinks/{autolink_id}` endpoint.
+     *
+     * As opposed to `delete_autolink`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/v3/repos#delete-autolink>
+     */
+    pub async fn delete_autolink_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/autolinks/{}",
@@ -478,7 +813,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -506,6 +841,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<()> {
+        self.enable_automated_security_fixes_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Enable automated security fixes.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/automated-security-fixes` endpoint.
+     *
+     * As opposed to `enable_automated_security_fixes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#enable-automated-security-fixes>
+     */
+    pub async fn enable_automated_security_fixes_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/automated-security-fixes",
@@ -515,7 +868,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -543,6 +896,24 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<()> {
+        self.disable_automated_security_fixes_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Disable automated security fixes.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/automated-security-fixes` endpoint.
+     *
+     * As opposed to `disable_automated_security_fixes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#disable-automated-security-fixes>
+     */
+    pub async fn disable_automated_security_fixes_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/automated-security-fixes",
@@ -552,7 +923,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -586,6 +957,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::ShortBranch>> {
+        Ok(self
+            .list_branches_with_response(owner, repo, protected, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List branches.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
+     *
+     * As opposed to `list_branches`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub async fn list_branches_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ShortBranch>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -607,7 +1000,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -658,17 +1051,100 @@ impl Repos {
             .await
     }
     /**
-     * Get a branch.
-     *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}` endpoint.
-     *
-     *
+     * List branches.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#get-a-branch>
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_branches`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * * `owner: &str`
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub fn list_all_branches_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ShortBranch>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if protected {
+            query_args.push(("protected".to_string(), protected.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ShortBranch>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List branches.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
+     *
+     * As opposed to `list_all_branches`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub fn list_all_branches_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ShortBranch>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_branches_pages_stream(owner, repo, protected, None))
+    }
+    /**
+     * Get a branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}` endpoint.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-branch>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
      * * `repo: &str`
      * * `branch: &str` -- The name of the branch.
      */
@@ -678,6 +1154,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::BranchWithProtection> {
+        Ok(self
+            .get_branch_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get a branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}` endpoint.
+     *
+     * As opposed to `get_branch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-branch>
+     */
+    pub async fn get_branch_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::BranchWithProtection>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}",
@@ -688,7 +1184,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -718,6 +1214,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::BranchProtection> {
+        Ok(self
+            .get_branch_protection_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get branch protection.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection` endpoint.
+     *
+     * As opposed to `get_branch_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-branch-protection>
+     */
+    pub async fn get_branch_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::BranchProtection>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection",
@@ -728,7 +1244,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -765,6 +1281,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposUpdateBranchProtectionRequest,
     ) -> ClientResult<crate::types::ProtectedBranch> {
+        Ok(self
+            .update_branch_protection_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update branch protection.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection` endpoint.
+     *
+     * As opposed to `update_branch_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-branch-protection>
+     */
+    pub async fn update_branch_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateBranchProtectionRequest,
+    ) -> ClientResult<crate::Response<crate::types::ProtectedBranch>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection",
@@ -775,7 +1312,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -805,6 +1342,25 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<()> {
+        self.delete_branch_protection_with_response(owner, repo, branch)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete branch protection.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection` endpoint.
+     *
+     * As opposed to `delete_branch_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-branch-protection>
+     */
+    pub async fn delete_branch_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection",
@@ -815,7 +1371,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -845,6 +1401,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::EnforceAdmins> {
+        Ok(self
+            .get_admin_branch_protection_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get admin branch protection.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins` endpoint.
+     *
+     * As opposed to `get_admin_branch_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-admin-branch-protection>
+     */
+    pub async fn get_admin_branch_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/enforce_admins",
@@ -855,7 +1431,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -887,6 +1463,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::EnforceAdmins> {
+        Ok(self
+            .set_admin_branch_protection_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Set admin branch protection.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins` endpoint.
+     *
+     * As opposed to `set_admin_branch_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#set-admin-branch-protection>
+     */
+    pub async fn set_admin_branch_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/enforce_admins",
@@ -897,7 +1493,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -929,6 +1525,25 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<()> {
+        self.delete_admin_branch_protection_with_response(owner, repo, branch)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete admin branch protection.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins` endpoint.
+     *
+     * As opposed to `delete_admin_branch_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-admin-branch-protection>
+     */
+    pub async fn delete_admin_branch_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/enforce_admins",
@@ -939,7 +1554,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -969,6 +1584,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::ProtectedBranchPullRequestReview> {
+        Ok(self
+            .get_pull_request_review_protection_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get pull request review protection.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews` endpoint.
+     *
+     * As opposed to `get_pull_request_review_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-pull-request-review-protection>
+     */
+    pub async fn get_pull_request_review_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProtectedBranchPullRequestReview>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
@@ -979,7 +1614,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1009,6 +1644,25 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<()> {
+        self.delete_pull_request_review_protection_with_response(owner, repo, branch)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete pull request review protection.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews` endpoint.
+     *
+     * As opposed to `delete_pull_request_review_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-pull-request-review-protection>
+     */
+    pub async fn delete_pull_request_review_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
@@ -1019,7 +1673,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1054,6 +1708,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposUpdatePullRequestReviewProtection,
     ) -> ClientResult<crate::types::ProtectedBranchPullRequestReview> {
+        Ok(self
+            .update_pull_request_review_protection_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update pull request review protection.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews` endpoint.
+     *
+     * As opposed to `update_pull_request_review_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-pull-request-review-protection>
+     */
+    pub async fn update_pull_request_review_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdatePullRequestReviewProtection,
+    ) -> ClientResult<crate::Response<crate::types::ProtectedBranchPullRequestReview>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_pull_request_reviews",
@@ -1064,7 +1739,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1098,6 +1773,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::EnforceAdmins> {
+        Ok(self
+            .get_commit_signature_protection_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get commit signature protection.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures` endpoint.
+     *
+     * As opposed to `get_commit_signature_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-commit-signature-protection>
+     */
+    pub async fn get_commit_signature_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_signatures",
@@ -1108,7 +1803,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1140,6 +1835,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::EnforceAdmins> {
+        Ok(self
+            .create_commit_signature_protection_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Create commit signature protection.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures` endpoint.
+     *
+     * As opposed to `create_commit_signature_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-commit-signature-protection>
+     */
+    pub async fn create_commit_signature_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnforceAdmins>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_signatures",
@@ -1150,7 +1865,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1182,6 +1897,25 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<()> {
+        self.delete_commit_signature_protection_with_response(owner, repo, branch)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete commit signature protection.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures` endpoint.
+     *
+     * As opposed to `delete_commit_signature_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-commit-signature-protection>
+     */
+    pub async fn delete_commit_signature_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_signatures",
@@ -1192,7 +1926,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1222,6 +1956,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::StatusCheckPolicy> {
+        Ok(self
+            .get_status_checks_protection_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get status checks protection.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks` endpoint.
+     *
+     * As opposed to `get_status_checks_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-status-checks-protection>
+     */
+    pub async fn get_status_checks_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::StatusCheckPolicy>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks",
@@ -1232,7 +1986,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1262,6 +2016,25 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<()> {
+        self.remove_status_check_protection_with_response(owner, repo, branch)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove status check protection.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks` endpoint.
+     *
+     * As opposed to `remove_status_check_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#remove-status-check-protection>
+     */
+    pub async fn remove_status_check_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks",
@@ -1272,7 +2045,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1305,17 +2078,38 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposUpdateStatusCheckProtectionRequest,
     ) -> ClientResult<crate::types::StatusCheckPolicy> {
-        let url = self.client.url(
-            &format!(
-                "/repos/{}/{}/branches/{}/protection/required_status_checks",
-                crate::progenitor_support::encode_path(owner),
+        Ok(self
+            .update_status_check_protection_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update status check protection.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks` endpoint.
+     *
+     * As opposed to `update_status_check_protection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-status-check-potection>
+     */
+    pub async fn update_status_check_protection_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateStatusCheckProtectionRequest,
+    ) -> ClientResult<crate::Response<crate::types::StatusCheckPolicy>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks",
+                crate::progenitor_support::encode_path(owner),
                 crate::progenitor_support::encode_path(repo),
                 crate::progenitor_support::encode_path(branch),
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1345,6 +2139,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<String>> {
+        Ok(self
+            .get_all_status_check_contexts_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get all status check contexts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `get_all_status_check_contexts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
+     */
+    pub async fn get_all_status_check_contexts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1355,7 +2169,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1400,6 +2214,81 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get all status check contexts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `get_all_all_status_check_contexts`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
+     */
+    pub fn get_all_all_status_check_contexts_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<String>>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<String>) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all status check contexts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `get_all_all_status_check_contexts`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
+     */
+    pub fn get_all_all_status_check_contexts_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_all_status_check_contexts_pages_stream(owner, repo, branch, None),
+        )
+    }
     /**
      * Set status check contexts.
      *
@@ -1422,6 +2311,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
     ) -> ClientResult<Vec<String>> {
+        Ok(self
+            .set_status_check_contexts_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set status check contexts.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `set_status_check_contexts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#set-status-check-contexts>
+     */
+    pub async fn set_status_check_contexts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1432,7 +2342,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1463,6 +2373,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
     ) -> ClientResult<Vec<String>> {
+        Ok(self
+            .add_status_check_contexts_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add status check contexts.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `add_status_check_contexts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#add-status-check-contexts>
+     */
+    pub async fn add_status_check_contexts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1473,7 +2404,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1504,6 +2435,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
     ) -> ClientResult<Vec<String>> {
+        Ok(self
+            .remove_status_check_contexts_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove status check contexts.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `remove_status_check_contexts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#remove-status-check-contexts>
+     */
+    pub async fn remove_status_check_contexts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1514,7 +2466,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1548,6 +2500,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<crate::types::BranchRestrictionPolicy> {
+        Ok(self
+            .get_access_restrictions_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get access restrictions.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions` endpoint.
+     *
+     * As opposed to `get_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-access-restrictions>
+     */
+    pub async fn get_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<crate::types::BranchRestrictionPolicy>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions",
@@ -1558,7 +2530,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1590,6 +2562,25 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<()> {
+        self.delete_access_restrictions_with_response(owner, repo, branch)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete access restrictions.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions` endpoint.
+     *
+     * As opposed to `delete_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-access-restrictions>
+     */
+    pub async fn delete_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions",
@@ -1600,7 +2591,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1632,6 +2623,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<crate::types::GitHubApp>> {
+        Ok(self
+            .get_apps_with_access_to_protected_branch_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get apps with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `get_apps_with_access_to_protected_branch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-apps-with-access-to-the-protected-branch>
+     */
+    pub async fn get_apps_with_access_to_protected_branch_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/apps",
@@ -1642,7 +2653,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1689,6 +2700,88 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get apps with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `get_all_apps_with_access_to_protected_branch`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-apps-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_apps_with_access_to_protected_branch_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GitHubApp>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GitHubApp>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get apps with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `get_all_apps_with_access_to_protected_branch`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-apps-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_apps_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GitHubApp>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_apps_with_access_to_protected_branch_pages_stream(
+                owner, repo, branch, None,
+            ),
+        )
+    }
     /**
      * Set app access restrictions.
      *
@@ -1717,6 +2810,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::GitHubApp>> {
+        Ok(self
+            .set_app_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set app access restrictions.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `set_app_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#set-app-access-restrictions>
+     */
+    pub async fn set_app_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/apps",
@@ -1727,7 +2841,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1764,6 +2878,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::GitHubApp>> {
+        Ok(self
+            .add_app_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add app access restrictions.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `add_app_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#add-app-access-restrictions>
+     */
+    pub async fn add_app_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/apps",
@@ -1774,7 +2909,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1811,6 +2946,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::GitHubApp>> {
+        Ok(self
+            .remove_app_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove app access restrictions.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `remove_app_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#remove-app-access-restrictions>
+     */
+    pub async fn remove_app_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GitHubApp>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/apps",
@@ -1821,7 +2977,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1853,6 +3009,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .get_teams_with_access_to_protected_branch_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get teams with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `get_teams_with_access_to_protected_branch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
+     */
+    pub async fn get_teams_with_access_to_protected_branch_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -1863,7 +3039,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1911,15 +3087,93 @@ impl Repos {
             .await
     }
     /**
-     * Set team access restrictions.
-     *
-     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     * Get teams with access to the protected branch.
      *
-     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
      *
-     * Replaces the list of teams that have push access to this branch. This removes all teams that previously had push access and grants push access to the new list of teams. Team restrictions include child teams.
+     * As opposed to `get_all_teams_with_access_to_protected_branch`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * | Type    | Description                                                                                                                                |
+     * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_teams_with_access_to_protected_branch_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Team>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Team>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get teams with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `get_all_teams_with_access_to_protected_branch`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_teams_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_teams_with_access_to_protected_branch_pages_stream(
+                owner, repo, branch, None,
+            ),
+        )
+    }
+    /**
+     * Set team access restrictions.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Replaces the list of teams that have push access to this branch. This removes all teams that previously had push access and grants push access to the new list of teams. Team restrictions include child teams.
+     *
+     * | Type    | Description                                                                                                                                |
      * | ------- | ------------------------------------------------------------------------------------------------------------------------------------------ |
      * | `array` | The teams that can have push access. Use the team's `slug`. **Note**: The list of users, apps, and teams in total is limited to 100 items. |
      *
@@ -1938,6 +3192,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .set_team_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set team access restrictions.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `set_team_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#set-team-access-restrictions>
+     */
+    pub async fn set_team_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -1948,7 +3223,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1985,6 +3260,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .add_team_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add team access restrictions.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `add_team_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#add-team-access-restrictions>
+     */
+    pub async fn add_team_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -1995,7 +3291,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2032,6 +3328,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .remove_team_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove team access restrictions.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `remove_team_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#remove-team-access-restrictions>
+     */
+    pub async fn remove_team_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -2042,7 +3359,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2074,6 +3391,26 @@ impl Repos {
         repo: &str,
         branch: &str,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .get_users_with_access_to_protected_branch_with_response(owner, repo, branch)
+            .await?
+            .body)
+    }
+    /**
+     * Get users with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `get_users_with_access_to_protected_branch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-users-with-access-to-the-protected-branch>
+     */
+    pub async fn get_users_with_access_to_protected_branch_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/users",
@@ -2084,7 +3421,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2131,6 +3468,88 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get users with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `get_all_users_with_access_to_protected_branch`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-users-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_users_with_access_to_protected_branch_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/branches/{}/protection/restrictions/users",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(branch),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get users with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `get_all_users_with_access_to_protected_branch`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-users-with-access-to-the-protected-branch>
+     */
+    pub fn get_all_users_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_users_with_access_to_protected_branch_pages_stream(
+                owner, repo, branch, None,
+            ),
+        )
+    }
     /**
      * Set user access restrictions.
      *
@@ -2159,6 +3578,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .set_user_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set user access restrictions.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `set_user_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#set-user-access-restrictions>
+     */
+    pub async fn set_user_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/users",
@@ -2169,7 +3609,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2206,6 +3646,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .add_user_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add user access restrictions.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `add_user_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#add-user-access-restrictions>
+     */
+    pub async fn add_user_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/users",
@@ -2216,7 +3677,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2253,6 +3714,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .remove_user_access_restrictions_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove user access restrictions.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * As opposed to `remove_user_access_restrictions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#remove-user-access-restrictions>
+     */
+    pub async fn remove_user_access_restrictions_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/protection/restrictions/users",
@@ -2263,7 +3745,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2308,6 +3790,27 @@ impl Repos {
         branch: &str,
         body: &crate::types::ReposRenameBranchRequest,
     ) -> ClientResult<crate::types::BranchWithProtection> {
+        Ok(self
+            .rename_branch_with_response(owner, repo, branch, body)
+            .await?
+            .body)
+    }
+    /**
+     * Rename a branch.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/branches/{branch}/rename` endpoint.
+     *
+     * As opposed to `rename_branch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#rename-a-branch>
+     */
+    pub async fn rename_branch_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRenameBranchRequest,
+    ) -> ClientResult<crate::Response<crate::types::BranchWithProtection>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/branches/{}/rename",
@@ -2318,7 +3821,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2357,6 +3860,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Collaborator>> {
+        Ok(self
+            .list_collaborators_with_response(owner, repo, affiliation, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_collaborators`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub async fn list_collaborators_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Collaborator>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
@@ -2378,7 +3903,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2430,6 +3955,94 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub fn list_all_collaborators_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Collaborator>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !affiliation.to_string().is_empty() {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/collaborators?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Collaborator>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub fn list_all_collaborators_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Collaborator>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_collaborators_pages_stream(
+            owner,
+            repo,
+            affiliation,
+            None,
+        ))
+    }
     /**
      * Check if a user is a repository collaborator.
      *
@@ -2453,8 +4066,27 @@ impl Repos {
         repo: &str,
         username: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
+        self.check_collaborator_with_response(owner, repo, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check if a user is a repository collaborator.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators/{username}` endpoint.
+     *
+     * As opposed to `check_collaborator`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#check-if-a-user-is-a-repository-collaborator>
+     */
+    pub async fn check_collaborator_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url(
+            &format!(
                 "/repos/{}/{}/collaborators/{}",
                 crate::progenitor_support::encode_path(owner),
                 crate::progenitor_support::encode_path(repo),
@@ -2463,7 +4095,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2504,6 +4136,27 @@ impl Repos {
         username: &str,
         body: &crate::types::ReposAddCollaboratorRequest,
     ) -> ClientResult<crate::types::RepositoryInvitation> {
+        Ok(self
+            .add_collaborator_with_response(owner, repo, username, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add a repository collaborator.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/collaborators/{username}` endpoint.
+     *
+     * As opposed to `add_collaborator`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#add-a-repository-collaborator>
+     */
+    pub async fn add_collaborator_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        body: &crate::types::ReposAddCollaboratorRequest,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryInvitation>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/collaborators/{}",
@@ -2514,7 +4167,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2544,6 +4197,25 @@ impl Repos {
         repo: &str,
         username: &str,
     ) -> ClientResult<()> {
+        self.remove_collaborator_with_response(owner, repo, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a repository collaborator.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/collaborators/{username}` endpoint.
+     *
+     * As opposed to `remove_collaborator`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#remove-a-repository-collaborator>
+     */
+    pub async fn remove_collaborator_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/collaborators/{}",
@@ -2554,7 +4226,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2584,6 +4256,26 @@ impl Repos {
         repo: &str,
         username: &str,
     ) -> ClientResult<crate::types::RepositoryCollaboratorPermission> {
+        Ok(self
+            .get_collaborator_permission_level_with_response(owner, repo, username)
+            .await?
+            .body)
+    }
+    /**
+     * Get repository permissions for a user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators/{username}/permission` endpoint.
+     *
+     * As opposed to `get_collaborator_permission_level`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-repository-permissions-for-a-user>
+     */
+    pub async fn get_collaborator_permission_level_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryCollaboratorPermission>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/collaborators/{}/permission",
@@ -2594,7 +4286,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2628,6 +4320,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::CommitComment>> {
+        Ok(self
+            .list_commit_comments_for_repo_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_commit_comments_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub async fn list_commit_comments_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2646,7 +4359,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2691,6 +4404,83 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_all_commit_comments_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub fn list_all_commit_comments_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CommitComment>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CommitComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_all_commit_comments_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub fn list_all_commit_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitComment>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_commit_comments_for_repo_pages_stream(owner, repo, None),
+        )
+    }
     /**
      * Get a commit comment.
      *
@@ -2712,6 +4502,26 @@ impl Repos {
         repo: &str,
         comment_id: i64,
     ) -> ClientResult<crate::types::CommitComment> {
+        Ok(self
+            .get_commit_comment_with_response(owner, repo, comment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `get_commit_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-commit-comment>
+     */
+    pub async fn get_commit_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::CommitComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/comments/{}",
@@ -2722,7 +4532,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2752,6 +4562,25 @@ impl Repos {
         repo: &str,
         comment_id: i64,
     ) -> ClientResult<()> {
+        self.delete_commit_comment_with_response(owner, repo, comment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a commit comment.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `delete_commit_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-commit-comment>
+     */
+    pub async fn delete_commit_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/comments/{}",
@@ -2762,7 +4591,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2793,6 +4622,27 @@ impl Repos {
         comment_id: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::CommitComment> {
+        Ok(self
+            .update_commit_comment_with_response(owner, repo, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a commit comment.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/comments/{comment_id}` endpoint.
+     *
+     * As opposed to `update_commit_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-a-commit-comment>
+     */
+    pub async fn update_commit_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::CommitComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/comments/{}",
@@ -2803,7 +4653,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2872,6 +4722,34 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::CommitDataType>> {
+        Ok(self
+            .list_commits_with_response(
+                owner, repo, sha, path, author, since, until, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_commits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     */
+    pub async fn list_commits_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitDataType>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !author.is_empty() {
             query_args.push(("author".to_string(), author.to_string()));
@@ -2905,7 +4783,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2998,6 +4876,111 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     */
+    pub fn list_all_commits_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CommitDataType>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !author.is_empty() {
+            query_args.push(("author".to_string(), author.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !sha.is_empty() {
+            query_args.push(("sha".to_string(), sha.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = until {
+            query_args.push(("until".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CommitDataType>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     */
+    pub fn list_all_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitDataType>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_commits_pages_stream(owner, repo, sha, path, author, since, until, None),
+        )
+    }
     /**
      * List branches for HEAD commit.
      *
@@ -3021,6 +5004,26 @@ impl Repos {
         repo: &str,
         commit_sha: &str,
     ) -> ClientResult<Vec<crate::types::BranchShort>> {
+        Ok(self
+            .list_branches_for_head_commit_with_response(owner, repo, commit_sha)
+            .await?
+            .body)
+    }
+    /**
+     * List branches for HEAD commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
+     *
+     * As opposed to `list_branches_for_head_commit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
+     */
+    pub async fn list_branches_for_head_commit_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BranchShort>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/commits/{}/branches-where-head",
@@ -3031,7 +5034,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3079,17 +5082,97 @@ impl Repos {
             .await
     }
     /**
-     * List commit comments.
-     *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
-     *
-     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     * List branches for HEAD commit.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_branches_for_head_commit`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * * `owner: &str`
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
+     */
+    pub fn list_all_branches_for_head_commit_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BranchShort>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/branches-where-head",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BranchShort>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List branches for HEAD commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
+     *
+     * As opposed to `list_all_branches_for_head_commit`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
+     */
+    pub fn list_all_branches_for_head_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BranchShort>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_branches_for_head_commit_pages_stream(owner, repo, commit_sha, None),
+        )
+    }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
      * * `repo: &str`
      * * `commit_sha: &str` -- commit_sha parameter.
      * * `per_page: i64` -- Results per page (max 100).
@@ -3103,6 +5186,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::CommitComment>> {
+        Ok(self
+            .list_comments_for_commit_with_response(owner, repo, commit_sha, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_comments_for_commit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub async fn list_comments_for_commit_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3122,7 +5227,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3167,6 +5272,86 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_commit`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub fn list_all_comments_for_commit_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CommitComment>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/comments",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CommitComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_commit`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub fn list_all_comments_for_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitComment>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_comments_for_commit_pages_stream(owner, repo, commit_sha, None),
+        )
+    }
     /**
      * Create a commit comment.
      *
@@ -3191,6 +5376,27 @@ impl Repos {
         commit_sha: &str,
         body: &crate::types::ReposCreateCommitCommentRequest,
     ) -> ClientResult<crate::types::CommitComment> {
+        Ok(self
+            .create_commit_comment_with_response(owner, repo, commit_sha, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a commit comment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `create_commit_comment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-commit-comment>
+     */
+    pub async fn create_commit_comment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        body: &crate::types::ReposCreateCommitCommentRequest,
+    ) -> ClientResult<crate::Response<crate::types::CommitComment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/commits/{}/comments",
@@ -3201,7 +5407,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3235,6 +5441,30 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::PullRequestSimple>> {
+        Ok(self
+            .list_pull_requests_associated_with_commit_with_response(
+                owner, repo, commit_sha, per_page, page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_pull_requests_associated_with_commit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub async fn list_pull_requests_associated_with_commit_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PullRequestSimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3254,7 +5484,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3299,6 +5529,89 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_all_pull_requests_associated_with_commit`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub fn list_all_pull_requests_associated_with_commit_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PullRequestSimple>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/pulls",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(commit_sha),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PullRequestSimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_all_pull_requests_associated_with_commit`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub fn list_all_pull_requests_associated_with_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PullRequestSimple>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_pull_requests_associated_with_commit_pages_stream(
+                owner, repo, commit_sha, None,
+            ),
+        )
+    }
     /**
      * Get a commit.
      *
@@ -3359,6 +5672,28 @@ impl Repos {
         per_page: i64,
         ref_: &str,
     ) -> ClientResult<crate::types::CommitDataType> {
+        Ok(self
+            .get_commit_with_response(owner, repo, page, per_page, ref_)
+            .await?
+            .body)
+    }
+    /**
+     * Get a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}` endpoint.
+     *
+     * As opposed to `get_commit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-commit>
+     */
+    pub async fn get_commit_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::CommitDataType>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3378,7 +5713,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3420,6 +5755,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::CombinedCommitStatus> {
+        Ok(self
+            .get_combined_status_for_ref_with_response(owner, repo, ref_, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Get the combined status for a specific reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/status` endpoint.
+     *
+     * As opposed to `get_combined_status_for_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-combined-status-for-a-specific-reference>
+     */
+    pub async fn get_combined_status_for_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::CombinedCommitStatus>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3439,7 +5796,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3475,6 +5832,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::StatusData>> {
+        Ok(self
+            .list_commit_statuses_for_ref_with_response(owner, repo, ref_, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_commit_statuses_for_ref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub async fn list_commit_statuses_for_ref_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::StatusData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3494,7 +5873,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3541,6 +5920,86 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_all_commit_statuses_for_ref`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub fn list_all_commit_statuses_for_ref_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::StatusData>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/commits/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(ref_),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::StatusData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_all_commit_statuses_for_ref`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub fn list_all_commit_statuses_for_ref_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::StatusData>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_commit_statuses_for_ref_pages_stream(owner, repo, ref_, None),
+        )
+    }
     /**
      * Get community profile metrics.
      *
@@ -3571,8 +6030,27 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::CommunityProfile> {
-        let url = self.client.url(
-            &format!(
+        Ok(self
+            .get_community_profile_metrics_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get community profile metrics.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/community/profile` endpoint.
+     *
+     * As opposed to `get_community_profile_metrics`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-community-profile-metrics>
+     */
+    pub async fn get_community_profile_metrics_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::CommunityProfile>> {
+        let url = self.client.url(
+            &format!(
                 "/repos/{}/{}/community/profile",
                 crate::progenitor_support::encode_path(owner),
                 crate::progenitor_support::encode_path(repo),
@@ -3580,7 +6058,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3653,6 +6131,28 @@ impl Repos {
         per_page: i64,
         basehead: &str,
     ) -> ClientResult<crate::types::CommitComparison> {
+        Ok(self
+            .compare_commits_with_response(owner, repo, page, per_page, basehead)
+            .await?
+            .body)
+    }
+    /**
+     * Compare two commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/compare/{basehead}` endpoint.
+     *
+     * As opposed to `compare_commits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#compare-two-commits>
+     */
+    pub async fn compare_commits_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+        basehead: &str,
+    ) -> ClientResult<crate::Response<crate::types::CommitComparison>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3672,7 +6172,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4051,6 +6551,27 @@ impl Repos {
         path: &str,
         ref_: &str,
     ) -> ClientResult<crate::types::ReposGetContentResponseOneOf> {
+        Ok(self
+            .get_content_with_response(owner, repo, path, ref_)
+            .await?
+            .body)
+    }
+    /**
+     * Get repository content.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contents/{path}` endpoint.
+     *
+     * As opposed to `get_content`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-repository-content>
+     */
+    pub async fn get_content_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReposGetContentResponseOneOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ref_.is_empty() {
             query_args.push(("ref".to_string(), ref_.to_string()));
@@ -4067,7 +6588,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4098,6 +6619,27 @@ impl Repos {
         path: &str,
         body: &crate::types::ReposCreateUpdateFileContentsRequest,
     ) -> ClientResult<crate::types::FileCommitData> {
+        Ok(self
+            .create_or_update_file_contents_with_response(owner, repo, path, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create or update file contents.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/contents/{path}` endpoint.
+     *
+     * As opposed to `create_or_update_file_contents`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-or-update-file-contents>
+     */
+    pub async fn create_or_update_file_contents_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposCreateUpdateFileContentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::FileCommitData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/contents/{}",
@@ -4108,7 +6650,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4145,6 +6687,27 @@ impl Repos {
         path: &str,
         body: &crate::types::ReposDeleteFileRequest,
     ) -> ClientResult<crate::types::FileCommitData> {
+        Ok(self
+            .delete_file_with_response(owner, repo, path, body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a file.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/contents/{path}` endpoint.
+     *
+     * As opposed to `delete_file`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-file>
+     */
+    pub async fn delete_file_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposDeleteFileRequest,
+    ) -> ClientResult<crate::Response<crate::types::FileCommitData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/contents/{}",
@@ -4155,7 +6718,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4191,6 +6754,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Contributor>> {
+        Ok(self
+            .list_contributors_with_response(owner, repo, anon, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_contributors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub async fn list_contributors_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contributor>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !anon.is_empty() {
             query_args.push(("anon".to_string(), anon.to_string()));
@@ -4212,7 +6797,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4264,6 +6849,89 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_all_contributors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub fn list_all_contributors_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Contributor>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !anon.is_empty() {
+            query_args.push(("anon".to_string(), anon.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/contributors?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Contributor>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_all_contributors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub fn list_all_contributors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contributor>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_contributors_pages_stream(owner, repo, anon, None))
+    }
     /**
      * List deployments.
      *
@@ -4295,6 +6963,40 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Deployment>> {
+        Ok(self
+            .list_deployments_with_response(
+                owner,
+                repo,
+                sha,
+                ref_,
+                task,
+                environment,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_deployments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub async fn list_deployments_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Deployment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !environment.is_empty() {
             query_args.push(("environment".to_string(), environment.to_string()));
@@ -4325,7 +7027,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4387,6 +7089,112 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_all_deployments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub fn list_all_deployments_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Deployment>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !environment.is_empty() {
+            query_args.push(("environment".to_string(), environment.to_string()));
+        }
+        if !ref_.is_empty() {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if !sha.is_empty() {
+            query_args.push(("sha".to_string(), sha.to_string()));
+        }
+        if !task.is_empty() {
+            query_args.push(("task".to_string(), task.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/deployments?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Deployment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_all_deployments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub fn list_all_deployments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Deployment>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_deployments_pages_stream(
+            owner,
+            repo,
+            sha,
+            ref_,
+            task,
+            environment,
+            None,
+        ))
+    }
     /**
      * Create a deployment.
      *
@@ -4451,6 +7259,26 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposCreateDeploymentRequest,
     ) -> ClientResult<crate::types::Deployment> {
+        Ok(self
+            .create_deployment_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a deployment.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `create_deployment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-deployment>
+     */
+    pub async fn create_deployment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeploymentRequest,
+    ) -> ClientResult<crate::Response<crate::types::Deployment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/deployments",
@@ -4460,7 +7288,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4490,6 +7318,26 @@ impl Repos {
         repo: &str,
         deployment_id: i64,
     ) -> ClientResult<crate::types::Deployment> {
+        Ok(self
+            .get_deployment_with_response(owner, repo, deployment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a deployment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}` endpoint.
+     *
+     * As opposed to `get_deployment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-deployment>
+     */
+    pub async fn get_deployment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Deployment>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/deployments/{}",
@@ -4500,7 +7348,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4537,6 +7385,25 @@ impl Repos {
         repo: &str,
         deployment_id: i64,
     ) -> ClientResult<()> {
+        self.delete_deployment_with_response(owner, repo, deployment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a deployment.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/deployments/{deployment_id}` endpoint.
+     *
+     * As opposed to `delete_deployment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-deployment>
+     */
+    pub async fn delete_deployment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/deployments/{}",
@@ -4547,7 +7414,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4581,10 +7448,32 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::DeploymentStatus>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
+        Ok(self
+            .list_deployment_statuses_with_response(owner, repo, deployment_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_deployment_statuses`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub async fn list_deployment_statuses_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DeploymentStatus>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if page > 0 {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
         if per_page > 0 {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
@@ -4600,7 +7489,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4645,6 +7534,90 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_all_deployment_statuses`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub fn list_all_deployment_statuses_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::DeploymentStatus>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/deployments/{}/statuses",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&deployment_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::DeploymentStatus>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_all_deployment_statuses`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub fn list_all_deployment_statuses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DeploymentStatus>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_deployment_statuses_pages_stream(
+            owner,
+            repo,
+            deployment_id,
+            None,
+        ))
+    }
     /**
      * Create a deployment status.
      *
@@ -4669,6 +7642,27 @@ impl Repos {
         deployment_id: i64,
         body: &crate::types::ReposCreateDeploymentStatusRequest,
     ) -> ClientResult<crate::types::DeploymentStatus> {
+        Ok(self
+            .create_deployment_status_with_response(owner, repo, deployment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a deployment status.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `create_deployment_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-deployment-status>
+     */
+    pub async fn create_deployment_status_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        body: &crate::types::ReposCreateDeploymentStatusRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeploymentStatus>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/deployments/{}/statuses",
@@ -4679,7 +7673,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4711,6 +7705,27 @@ impl Repos {
         deployment_id: i64,
         status_id: i64,
     ) -> ClientResult<crate::types::DeploymentStatus> {
+        Ok(self
+            .get_deployment_status_with_response(owner, repo, deployment_id, status_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a deployment status.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses/{status_id}` endpoint.
+     *
+     * As opposed to `get_deployment_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-deployment-status>
+     */
+    pub async fn get_deployment_status_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        status_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::DeploymentStatus>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/deployments/{}/statuses/{}",
@@ -4722,7 +7737,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4760,6 +7775,25 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposCreateDispatchEventRequest,
     ) -> ClientResult<()> {
+        self.create_dispatch_event_with_response(owner, repo, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Create a repository dispatch event.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/dispatches` endpoint.
+     *
+     * As opposed to `create_dispatch_event`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-repository-dispatch-event>
+     */
+    pub async fn create_dispatch_event_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDispatchEventRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/dispatches",
@@ -4769,7 +7803,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4799,6 +7833,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::ReposGetAllEnvironmentsResponse> {
+        Ok(self
+            .get_all_environments_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get all environments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/environments` endpoint.
+     *
+     * As opposed to `get_all_environments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-environments>
+     */
+    pub async fn get_all_environments_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReposGetAllEnvironmentsResponse>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/environments",
@@ -4808,7 +7861,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4838,6 +7891,26 @@ impl Repos {
         repo: &str,
         environment_name: &str,
     ) -> ClientResult<crate::types::EnvironmentData> {
+        Ok(self
+            .get_environment_with_response(owner, repo, environment_name)
+            .await?
+            .body)
+    }
+    /**
+     * Get an environment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/environments/{environment_name}` endpoint.
+     *
+     * As opposed to `get_environment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-an-environment>
+     */
+    pub async fn get_environment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::EnvironmentData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/environments/{}",
@@ -4848,7 +7921,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4885,6 +7958,27 @@ impl Repos {
         environment_name: &str,
         body: &crate::types::ReposCreateUpdateEnvironmentRequest,
     ) -> ClientResult<crate::types::EnvironmentData> {
+        Ok(self
+            .create_or_update_environment_with_response(owner, repo, environment_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create or update an environment.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/environments/{environment_name}` endpoint.
+     *
+     * As opposed to `create_or_update_environment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-or-update-an-environment>
+     */
+    pub async fn create_or_update_environment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+        body: &crate::types::ReposCreateUpdateEnvironmentRequest,
+    ) -> ClientResult<crate::Response<crate::types::EnvironmentData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/environments/{}",
@@ -4895,7 +7989,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4925,6 +8019,25 @@ impl Repos {
         repo: &str,
         environment_name: &str,
     ) -> ClientResult<()> {
+        self.delete_an_environment_with_response(owner, repo, environment_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an environment.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/environments/{environment_name}` endpoint.
+     *
+     * As opposed to `delete_an_environment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-an-environment>
+     */
+    pub async fn delete_an_environment_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/environments/{}",
@@ -4935,7 +8048,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4969,6 +8082,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_forks_with_response(owner, repo, sort, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_forks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub async fn list_forks_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -4990,7 +8125,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5040,6 +8175,90 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub fn list_all_forks_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/forks?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub fn list_all_forks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_forks_pages_stream(owner, repo, sort, None))
+    }
     /**
      * Create a fork.
      *
@@ -5062,6 +8281,26 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposCreateForkRequest,
     ) -> ClientResult<crate::types::FullRepository> {
+        Ok(self
+            .create_fork_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a fork.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `create_fork`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-fork>
+     */
+    pub async fn create_fork_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateForkRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullRepository>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/forks",
@@ -5071,7 +8310,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5103,6 +8342,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Hook>> {
+        Ok(self
+            .list_webhooks_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository webhooks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * As opposed to `list_webhooks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
+     */
+    pub async fn list_webhooks_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Hook>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -5121,7 +8381,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5165,26 +8425,117 @@ impl Repos {
             .await
     }
     /**
-     * Create a repository webhook.
-     *
-     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks` endpoint.
-     *
-     * Repositories can have multiple webhooks installed. Each webhook should have a unique `config`. Multiple webhooks can
-     * share the same `config` as long as those webhooks do not have any `events` that overlap.
+     * List repository webhooks.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#create-a-repository-webhook>
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_webhooks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * * `owner: &str`
-     * * `repo: &str`
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
      */
-    pub async fn create_webhook(
+    pub fn list_all_webhooks_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Hook>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/hooks",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Hook>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository webhooks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
+     */
+    pub fn list_all_webhooks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Hook>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_webhooks_pages_stream(owner, repo, None))
+    }
+    /**
+     * Create a repository webhook.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * Repositories can have multiple webhooks installed. Each webhook should have a unique `config`. Multiple webhooks can
+     * share the same `config` as long as those webhooks do not have any `events` that overlap.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-repository-webhook>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     */
+    pub async fn create_webhook(
         &self,
         owner: &str,
         repo: &str,
         body: &crate::types::ReposCreateWebhookRequest,
     ) -> ClientResult<crate::types::Hook> {
+        Ok(self
+            .create_webhook_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a repository webhook.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * As opposed to `create_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-repository-webhook>
+     */
+    pub async fn create_webhook_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::Hook>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks",
@@ -5194,7 +8545,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5224,6 +8575,26 @@ impl Repos {
         repo: &str,
         hook_id: i64,
     ) -> ClientResult<crate::types::Hook> {
+        Ok(self
+            .get_webhook_with_response(owner, repo, hook_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}` endpoint.
+     *
+     * As opposed to `get_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-repository-webhook>
+     */
+    pub async fn get_webhook_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Hook>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}",
@@ -5234,7 +8605,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5259,6 +8630,25 @@ impl Repos {
      * * `hook_id: i64`
      */
     pub async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> ClientResult<()> {
+        self.delete_webhook_with_response(owner, repo, hook_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a repository webhook.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/hooks/{hook_id}` endpoint.
+     *
+     * As opposed to `delete_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-repository-webhook>
+     */
+    pub async fn delete_webhook_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}",
@@ -5269,7 +8659,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5300,6 +8690,27 @@ impl Repos {
         hook_id: i64,
         body: &crate::types::ReposUpdateWebhookRequest,
     ) -> ClientResult<crate::types::Hook> {
+        Ok(self
+            .update_webhook_with_response(owner, repo, hook_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a repository webhook.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/hooks/{hook_id}` endpoint.
+     *
+     * As opposed to `update_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-a-repository-webhook>
+     */
+    pub async fn update_webhook_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::ReposUpdateWebhookRequest,
+    ) -> ClientResult<crate::Response<crate::types::Hook>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}",
@@ -5310,7 +8721,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5342,6 +8753,26 @@ impl Repos {
         repo: &str,
         hook_id: i64,
     ) -> ClientResult<crate::types::WebhookConfig> {
+        Ok(self
+            .get_webhook_config_for_repo_with_response(owner, repo, hook_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a webhook configuration for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/config` endpoint.
+     *
+     * As opposed to `get_webhook_config_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-webhook-configuration-for-a-repository>
+     */
+    pub async fn get_webhook_config_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}/config",
@@ -5352,7 +8783,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5385,6 +8816,27 @@ impl Repos {
         hook_id: i64,
         body: &crate::types::AppsUpdateWebhookConfigAppRequest,
     ) -> ClientResult<crate::types::WebhookConfig> {
+        Ok(self
+            .update_webhook_config_for_repo_with_response(owner, repo, hook_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a webhook configuration for a repository.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/hooks/{hook_id}/config` endpoint.
+     *
+     * As opposed to `update_webhook_config_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-a-webhook-configuration-for-a-repository>
+     */
+    pub async fn update_webhook_config_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookConfig>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}/config",
@@ -5395,7 +8847,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5429,6 +8881,28 @@ impl Repos {
         per_page: i64,
         cursor: &str,
     ) -> ClientResult<Vec<crate::types::HookDeliveryItem>> {
+        Ok(self
+            .list_webhook_deliveries_with_response(owner, repo, hook_id, per_page, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_webhook_deliveries`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub async fn list_webhook_deliveries_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        per_page: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::HookDeliveryItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -5448,7 +8922,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5500,6 +8974,95 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub fn list_all_webhook_deliveries_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        cursor: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::HookDeliveryItem>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/hooks/{}/deliveries?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&hook_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::HookDeliveryItem>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub fn list_all_webhook_deliveries_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::HookDeliveryItem>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_webhook_deliveries_pages_stream(owner, repo, hook_id, cursor, None),
+        )
+    }
     /**
      * Get a delivery for a repository webhook.
      *
@@ -5523,6 +9086,27 @@ impl Repos {
         hook_id: i64,
         delivery_id: i64,
     ) -> ClientResult<crate::types::HookDelivery> {
+        Ok(self
+            .get_webhook_delivery_with_response(owner, repo, hook_id, delivery_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a delivery for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}` endpoint.
+     *
+     * As opposed to `get_webhook_delivery`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-delivery-for-a-repository-webhook>
+     */
+    pub async fn get_webhook_delivery_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::HookDelivery>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}/deliveries/{}",
@@ -5534,7 +9118,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5566,6 +9150,26 @@ impl Repos {
         hook_id: i64,
         delivery_id: i64,
     ) -> ClientResult<()> {
+        self.redeliver_webhook_delivery_with_response(owner, repo, hook_id, delivery_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Redeliver a delivery for a repository webhook.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}/attempts` endpoint.
+     *
+     * As opposed to `redeliver_webhook_delivery`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#redeliver-a-delivery-for-a-repository-webhook>
+     */
+    pub async fn redeliver_webhook_delivery_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}/deliveries/{}/attempts",
@@ -5577,7 +9181,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5602,6 +9206,25 @@ impl Repos {
      * * `hook_id: i64`
      */
     pub async fn ping_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> ClientResult<()> {
+        self.ping_webhook_with_response(owner, repo, hook_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Ping a repository webhook.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks/{hook_id}/pings` endpoint.
+     *
+     * As opposed to `ping_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#ping-a-repository-webhook>
+     */
+    pub async fn ping_webhook_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}/pings",
@@ -5612,7 +9235,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5644,6 +9267,25 @@ impl Repos {
         repo: &str,
         hook_id: i64,
     ) -> ClientResult<()> {
+        self.test_push_webhook_with_response(owner, repo, hook_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Test the push repository webhook.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks/{hook_id}/tests` endpoint.
+     *
+     * As opposed to `test_push_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#test-the-push-repository-webhook>
+     */
+    pub async fn test_push_webhook_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/hooks/{}/tests",
@@ -5654,7 +9296,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5686,6 +9328,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::RepositoryInvitation>> {
+        Ok(self
+            .list_invitations_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_invitations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub async fn list_invitations_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -5704,7 +9367,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5747,6 +9410,83 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub fn list_all_invitations_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::RepositoryInvitation>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/invitations",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::RepositoryInvitation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub fn list_all_invitations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RepositoryInvitation>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_invitations_pages_stream(owner, repo, None))
+    }
     /**
      * Delete a repository invitation.
      *
@@ -5768,6 +9508,25 @@ impl Repos {
         repo: &str,
         invitation_id: i64,
     ) -> ClientResult<()> {
+        self.delete_invitation_with_response(owner, repo, invitation_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a repository invitation.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/invitations/{invitation_id}` endpoint.
+     *
+     * As opposed to `delete_invitation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-repository-invitation>
+     */
+    pub async fn delete_invitation_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/invitations/{}",
@@ -5778,7 +9537,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5809,6 +9568,27 @@ impl Repos {
         invitation_id: i64,
         body: &crate::types::ReposUpdateInvitationRequest,
     ) -> ClientResult<crate::types::RepositoryInvitation> {
+        Ok(self
+            .update_invitation_with_response(owner, repo, invitation_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a repository invitation.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/invitations/{invitation_id}` endpoint.
+     *
+     * As opposed to `update_invitation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-a-repository-invitation>
+     */
+    pub async fn update_invitation_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+        body: &crate::types::ReposUpdateInvitationRequest,
+    ) -> ClientResult<crate::Response<crate::types::RepositoryInvitation>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/invitations/{}",
@@ -5819,7 +9599,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5851,6 +9631,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::DeployKey>> {
+        Ok(self
+            .list_deploy_keys_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_deploy_keys`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub async fn list_deploy_keys_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DeployKey>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -5869,7 +9670,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5912,6 +9713,81 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_all_deploy_keys`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub fn list_all_deploy_keys_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::DeployKey>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/keys",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::DeployKey>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_all_deploy_keys`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub fn list_all_deploy_keys_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DeployKey>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_deploy_keys_pages_stream(owner, repo, None))
+    }
     /**
      * Create a deploy key.
      *
@@ -5932,6 +9808,26 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposCreateDeployKeyRequest,
     ) -> ClientResult<crate::types::DeployKey> {
+        Ok(self
+            .create_deploy_key_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a deploy key.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `create_deploy_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-deploy-key>
+     */
+    pub async fn create_deploy_key_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeployKeyRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeployKey>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/keys",
@@ -5941,7 +9837,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5971,6 +9867,26 @@ impl Repos {
         repo: &str,
         key_id: i64,
     ) -> ClientResult<crate::types::DeployKey> {
+        Ok(self
+            .get_deploy_key_with_response(owner, repo, key_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a deploy key.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys/{key_id}` endpoint.
+     *
+     * As opposed to `get_deploy_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-deploy-key>
+     */
+    pub async fn get_deploy_key_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::DeployKey>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/keys/{}",
@@ -5981,7 +9897,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6011,6 +9927,25 @@ impl Repos {
         repo: &str,
         key_id: i64,
     ) -> ClientResult<()> {
+        self.delete_deploy_key_with_response(owner, repo, key_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a deploy key.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/keys/{key_id}` endpoint.
+     *
+     * As opposed to `delete_deploy_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-deploy-key>
+     */
+    pub async fn delete_deploy_key_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/keys/{}",
@@ -6021,7 +9956,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6045,6 +9980,22 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn list_languages(&self, owner: &str, repo: &str) -> ClientResult<i64> {
+        Ok(self.list_languages_with_response(owner, repo).await?.body)
+    }
+    /**
+     * List repository languages.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/languages` endpoint.
+     *
+     * As opposed to `list_languages`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-languages>
+     */
+    pub async fn list_languages_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<i64>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/languages",
@@ -6054,7 +10005,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6083,6 +10034,23 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposMergeRequest,
     ) -> ClientResult<crate::types::CommitDataType> {
+        Ok(self.merge_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Merge a branch.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/merges` endpoint.
+     *
+     * As opposed to `merge`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#merge-a-branch>
+     */
+    pub async fn merge_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposMergeRequest,
+    ) -> ClientResult<crate::Response<crate::types::CommitDataType>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/merges",
@@ -6092,7 +10060,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6116,6 +10084,22 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn get_pages(&self, owner: &str, repo: &str) -> ClientResult<crate::types::Page> {
+        Ok(self.get_pages_with_response(owner, repo).await?.body)
+    }
+    /**
+     * Get a GitHub Pages site.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages` endpoint.
+     *
+     * As opposed to `get_pages`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-github-pages-site>
+     */
+    pub async fn get_pages_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Page>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages",
@@ -6125,7 +10109,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6154,6 +10138,25 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposUpdateInformationAboutPagesSiteRequest,
     ) -> ClientResult<()> {
+        self.update_information_about_pages_site_with_response(owner, repo, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update information about a GitHub Pages site.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/pages` endpoint.
+     *
+     * As opposed to `update_information_about_pages_site`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-information-about-a-github-pages-site>
+     */
+    pub async fn update_information_about_pages_site_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateInformationAboutPagesSiteRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages",
@@ -6163,7 +10166,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6192,6 +10195,26 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposCreatePagesSiteRequest,
     ) -> ClientResult<crate::types::Page> {
+        Ok(self
+            .create_pages_site_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a GitHub Pages site.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pages` endpoint.
+     *
+     * As opposed to `create_pages_site`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-github-pages-site>
+     */
+    pub async fn create_pages_site_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreatePagesSiteRequest,
+    ) -> ClientResult<crate::Response<crate::types::Page>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages",
@@ -6201,7 +10224,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6225,6 +10248,23 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn delete_pages_site(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.delete_pages_site_with_response(owner, repo).await?;
+        Ok(())
+    }
+    /**
+     * Delete a GitHub Pages site.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/pages` endpoint.
+     *
+     * As opposed to `delete_pages_site`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-github-pages-site>
+     */
+    pub async fn delete_pages_site_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages",
@@ -6234,7 +10274,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6266,6 +10306,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::PageBuild>> {
+        Ok(self
+            .list_pages_builds_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List GitHub Pages builds.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `list_pages_builds`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub async fn list_pages_builds_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PageBuild>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -6284,7 +10345,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6328,17 +10389,92 @@ impl Repos {
             .await
     }
     /**
-     * Request a GitHub Pages build.
-     *
-     * This function performs a `POST` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
-     *
-     * You can request that your site be built from the latest revision on the default branch. This has the same effect as pushing a commit to your default branch, but does not require an additional commit. Manually triggering page builds can be helpful when diagnosing build warnings and failures.
-     *
-     * Build requests are limited to one concurrent build per repository and one concurrent build per requester. If you request a build while another is still in progress, the second request will be queued until the first completes.
+     * List GitHub Pages builds.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#request-a-github-pages-build>
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_pages_builds`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub fn list_all_pages_builds_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PageBuild>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/pages/builds",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PageBuild>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List GitHub Pages builds.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `list_all_pages_builds`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub fn list_all_pages_builds_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PageBuild>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_builds_pages_stream(owner, repo, None))
+    }
+    /**
+     * Request a GitHub Pages build.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * You can request that your site be built from the latest revision on the default branch. This has the same effect as pushing a commit to your default branch, but does not require an additional commit. Manually triggering page builds can be helpful when diagnosing build warnings and failures.
+     *
+     * Build requests are limited to one concurrent build per repository and one concurrent build per requester. If you request a build while another is still in progress, the second request will be queued until the first completes.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#request-a-github-pages-build>
+     *
+     * **Parameters:**
      *
      * * `owner: &str`
      * * `repo: &str`
@@ -6348,6 +10484,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::PageBuildStatus> {
+        Ok(self
+            .request_pages_build_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Request a GitHub Pages build.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `request_pages_build`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#request-a-github-pages-build>
+     */
+    pub async fn request_pages_build_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageBuildStatus>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages/builds",
@@ -6357,7 +10512,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6385,6 +10540,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::PageBuild> {
+        Ok(self
+            .get_latest_pages_build_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get latest Pages build.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds/latest` endpoint.
+     *
+     * As opposed to `get_latest_pages_build`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-latest-pages-build>
+     */
+    pub async fn get_latest_pages_build_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::PageBuild>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages/builds/latest",
@@ -6394,7 +10568,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6424,6 +10598,26 @@ impl Repos {
         repo: &str,
         build_id: i64,
     ) -> ClientResult<crate::types::PageBuild> {
+        Ok(self
+            .get_pages_build_with_response(owner, repo, build_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get GitHub Pages build.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds/{build_id}` endpoint.
+     *
+     * As opposed to `get_pages_build`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-github-pages-build>
+     */
+    pub async fn get_pages_build_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        build_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PageBuild>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages/builds/{}",
@@ -6434,7 +10628,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6466,6 +10660,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::PagesHealthCheck> {
+        Ok(self
+            .get_pages_health_check_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get a DNS health check for GitHub Pages.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/health` endpoint.
+     *
+     * As opposed to `get_pages_health_check`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-dns-health-check-for-github-pages>
+     */
+    pub async fn get_pages_health_check_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::PagesHealthCheck>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/pages/health",
@@ -6475,7 +10688,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6507,6 +10720,23 @@ impl Repos {
         repo: &str,
         ref_: &str,
     ) -> ClientResult<crate::types::ContentFile> {
+        Ok(self.get_readme_with_response(owner, repo, ref_).await?.body)
+    }
+    /**
+     * Get a repository README.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/readme` endpoint.
+     *
+     * As opposed to `get_readme`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-repository-readme>
+     */
+    pub async fn get_readme_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContentFile>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ref_.is_empty() {
             query_args.push(("ref".to_string(), ref_.to_string()));
@@ -6522,7 +10752,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6556,6 +10786,27 @@ impl Repos {
         dir: &str,
         ref_: &str,
     ) -> ClientResult<crate::types::ContentFile> {
+        Ok(self
+            .get_readme_in_directory_with_response(owner, repo, dir, ref_)
+            .await?
+            .body)
+    }
+    /**
+     * Get a repository README for a directory.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/readme/{dir}` endpoint.
+     *
+     * As opposed to `get_readme_in_directory`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-repository-directory-readme>
+     */
+    pub async fn get_readme_in_directory_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        dir: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContentFile>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ref_.is_empty() {
             query_args.push(("ref".to_string(), ref_.to_string()));
@@ -6572,7 +10823,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6606,6 +10857,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Release>> {
+        Ok(self
+            .list_releases_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List releases.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `list_releases`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
+     */
+    pub async fn list_releases_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Release>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -6624,7 +10896,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6669,6 +10941,80 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List releases.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `list_all_releases`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
+     */
+    pub fn list_all_releases_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Release>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/releases",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Release>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List releases.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `list_all_releases`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
+     */
+    pub fn list_all_releases_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Release>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_releases_pages_stream(owner, repo, None))
+    }
     /**
      * Create a release.
      *
@@ -6691,6 +11037,26 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposCreateReleaseRequest,
     ) -> ClientResult<crate::types::Release> {
+        Ok(self
+            .create_release_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a release.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * As opposed to `create_release`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-release>
+     */
+    pub async fn create_release_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateReleaseRequest,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases",
@@ -6700,7 +11066,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6730,6 +11096,26 @@ impl Repos {
         repo: &str,
         asset_id: i64,
     ) -> ClientResult<crate::types::ReleaseAsset> {
+        Ok(self
+            .get_release_asset_with_response(owner, repo, asset_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a release asset.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/assets/{asset_id}` endpoint.
+     *
+     * As opposed to `get_release_asset`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-release-asset>
+     */
+    pub async fn get_release_asset_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReleaseAsset>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/assets/{}",
@@ -6740,7 +11126,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6770,6 +11156,25 @@ impl Repos {
         repo: &str,
         asset_id: i64,
     ) -> ClientResult<()> {
+        self.delete_release_asset_with_response(owner, repo, asset_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a release asset.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/releases/assets/{asset_id}` endpoint.
+     *
+     * As opposed to `delete_release_asset`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-release-asset>
+     */
+    pub async fn delete_release_asset_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/assets/{}",
@@ -6780,7 +11185,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6811,6 +11216,27 @@ impl Repos {
         asset_id: i64,
         body: &crate::types::ReposUpdateReleaseAssetRequest,
     ) -> ClientResult<crate::types::ReleaseAsset> {
+        Ok(self
+            .update_release_asset_with_response(owner, repo, asset_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a release asset.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/releases/assets/{asset_id}` endpoint.
+     *
+     * As opposed to `update_release_asset`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-a-release-asset>
+     */
+    pub async fn update_release_asset_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+        body: &crate::types::ReposUpdateReleaseAssetRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReleaseAsset>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/assets/{}",
@@ -6821,7 +11247,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6851,6 +11277,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::Release> {
+        Ok(self
+            .get_latest_release_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get the latest release.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/latest` endpoint.
+     *
+     * As opposed to `get_latest_release`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-latest-release>
+     */
+    pub async fn get_latest_release_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/latest",
@@ -6860,7 +11305,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6890,6 +11335,26 @@ impl Repos {
         repo: &str,
         tag: &str,
     ) -> ClientResult<crate::types::Release> {
+        Ok(self
+            .get_release_by_tag_with_response(owner, repo, tag)
+            .await?
+            .body)
+    }
+    /**
+     * Get a release by tag name.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/tags/{tag}` endpoint.
+     *
+     * As opposed to `get_release_by_tag`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-release-by-tag-name>
+     */
+    pub async fn get_release_by_tag_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/tags/{}",
@@ -6900,7 +11365,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6930,8 +11395,28 @@ impl Repos {
         repo: &str,
         release_id: i64,
     ) -> ClientResult<crate::types::Release> {
-        let url = self.client.url(
-            &format!(
+        Ok(self
+            .get_release_with_response(owner, repo, release_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a release.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}` endpoint.
+     *
+     * As opposed to `get_release`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-a-release>
+     */
+    pub async fn get_release_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
+        let url = self.client.url(
+            &format!(
                 "/repos/{}/{}/releases/{}",
                 crate::progenitor_support::encode_path(owner),
                 crate::progenitor_support::encode_path(repo),
@@ -6940,7 +11425,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6970,6 +11455,25 @@ impl Repos {
         repo: &str,
         release_id: i64,
     ) -> ClientResult<()> {
+        self.delete_release_with_response(owner, repo, release_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a release.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/releases/{release_id}` endpoint.
+     *
+     * As opposed to `delete_release`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#delete-a-release>
+     */
+    pub async fn delete_release_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/{}",
@@ -6980,7 +11484,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7011,6 +11515,27 @@ impl Repos {
         release_id: i64,
         body: &crate::types::ReposCreateReleaseRequest,
     ) -> ClientResult<crate::types::Release> {
+        Ok(self
+            .update_release_with_response(owner, repo, release_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a release.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/releases/{release_id}` endpoint.
+     *
+     * As opposed to `update_release`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#update-a-release>
+     */
+    pub async fn update_release_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReposCreateReleaseRequest,
+    ) -> ClientResult<crate::Response<crate::types::Release>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/releases/{}",
@@ -7021,7 +11546,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7055,6 +11580,28 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::ReleaseAsset>> {
+        Ok(self
+            .list_release_assets_with_response(owner, repo, release_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_release_assets`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub async fn list_release_assets_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReleaseAsset>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -7074,7 +11621,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7119,6 +11666,86 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_all_release_assets`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub fn list_all_release_assets_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ReleaseAsset>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/releases/{}/assets",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                crate::progenitor_support::encode_path(&release_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ReleaseAsset>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_all_release_assets`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub fn list_all_release_assets_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReleaseAsset>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_release_assets_pages_stream(owner, repo, release_id, None),
+        )
+    }
     /**
      * Upload a release asset.
      *
@@ -7162,6 +11789,29 @@ impl Repos {
         label: &str,
         body: B,
     ) -> ClientResult<crate::types::ReleaseAsset> {
+        Ok(self
+            .upload_release_asset_with_response::<B>(owner, repo, release_id, name, label, body)
+            .await?
+            .body)
+    }
+    /**
+     * Upload a release asset.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `upload_release_asset`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#upload-a-release-asset>
+     */
+    pub async fn upload_release_asset_with_response<B: Into<reqwest::Body>>(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        name: &str,
+        label: &str,
+        body: B,
+    ) -> ClientResult<crate::Response<crate::types::ReleaseAsset>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !label.is_empty() {
             query_args.push(("label".to_string(), label.to_string()));
@@ -7181,7 +11831,7 @@ impl Repos {
             Some(ReposUploadReleaseAssetDefaultServer::default().default_url()),
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(body.into()),
@@ -7209,6 +11859,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<Vec<i64>>> {
+        Ok(self
+            .get_code_frequency_stats_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get the weekly commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/code_frequency` endpoint.
+     *
+     * As opposed to `get_code_frequency_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-activity>
+     */
+    pub async fn get_code_frequency_stats_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<Vec<i64>>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/code_frequency",
@@ -7218,7 +11887,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7261,6 +11930,76 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the weekly commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/code_frequency` endpoint.
+     *
+     * As opposed to `get_all_code_frequency_stats`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-activity>
+     */
+    pub fn get_all_code_frequency_stats_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<Vec<i64>>>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/code_frequency",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<Vec<i64>>) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the weekly commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/code_frequency` endpoint.
+     *
+     * As opposed to `get_all_code_frequency_stats`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-activity>
+     */
+    pub fn get_all_code_frequency_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<i64>>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_code_frequency_stats_pages_stream(owner, repo, None))
+    }
     /**
      * Get the last year of commit activity.
      *
@@ -7280,6 +12019,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::CommitActivity>> {
+        Ok(self
+            .get_commit_activity_stats_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get the last year of commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/commit_activity` endpoint.
+     *
+     * As opposed to `get_commit_activity_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-last-year-of-commit-activity>
+     */
+    pub async fn get_commit_activity_stats_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommitActivity>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/commit_activity",
@@ -7289,7 +12047,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7332,6 +12090,81 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the last year of commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/commit_activity` endpoint.
+     *
+     * As opposed to `get_all_commit_activity_stats`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-last-year-of-commit-activity>
+     */
+    pub fn get_all_commit_activity_stats_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CommitActivity>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/commit_activity",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CommitActivity>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the last year of commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/commit_activity` endpoint.
+     *
+     * As opposed to `get_all_commit_activity_stats`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-last-year-of-commit-activity>
+     */
+    pub fn get_all_commit_activity_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommitActivity>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_commit_activity_stats_pages_stream(owner, repo, None))
+    }
     /**
      * Get all contributor commit activity.
      *
@@ -7357,6 +12190,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::ContributorActivity>> {
+        Ok(self
+            .get_contributors_stats_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get all contributor commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
+     *
+     * As opposed to `get_contributors_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
+     */
+    pub async fn get_contributors_stats_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ContributorActivity>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/contributors",
@@ -7366,7 +12218,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7416,48 +12268,144 @@ impl Repos {
             .await
     }
     /**
-     * Get the weekly commit count.
-     *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/participation` endpoint.
-     *
-     * Returns the total commit counts for the `owner` and total commit counts in `all`. `all` is everyone combined, including the `owner` in the last 52 weeks. If you'd like to get the commit counts for non-owners, you can subtract `owner` from `all`.
-     *
-     * The array order is oldest week (index 0) to most recent week.
+     * Get all contributor commit activity.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-count>
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_all_contributors_stats`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * * `owner: &str`
-     * * `repo: &str`
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
      */
-    pub async fn get_participation_stats(
+    pub fn get_all_contributors_stats_pages_stream(
         &self,
         owner: &str,
         repo: &str,
-    ) -> ClientResult<crate::types::ParticipationStats> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ContributorActivity>>,
+    > + Unpin
+           + '_ {
         let url = self.client.url(
             &format!(
-                "/repos/{}/{}/stats/participation",
+                "/repos/{}/{}/stats/contributors",
                 crate::progenitor_support::encode_path(owner),
                 crate::progenitor_support::encode_path(repo),
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ContributorActivity>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Get the hourly commit count for each day.
+     * Get all contributor commit activity.
      *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
+     *
+     * As opposed to `get_all_contributors_stats`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
+     */
+    pub fn get_all_contributors_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ContributorActivity>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_contributors_stats_pages_stream(owner, repo, None))
+    }
+    /**
+     * Get the weekly commit count.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/participation` endpoint.
+     *
+     * Returns the total commit counts for the `owner` and total commit counts in `all`. `all` is everyone combined, including the `owner` in the last 52 weeks. If you'd like to get the commit counts for non-owners, you can subtract `owner` from `all`.
+     *
+     * The array order is oldest week (index 0) to most recent week.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-count>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     */
+    pub async fn get_participation_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::types::ParticipationStats> {
+        Ok(self
+            .get_participation_stats_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get the weekly commit count.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/participation` endpoint.
+     *
+     * As opposed to `get_participation_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-count>
+     */
+    pub async fn get_participation_stats_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::ParticipationStats>> {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/participation",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+        self.client
+            .get_with_response(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
      *
      * Each array contains the day number, hour number, and number of commits:
      *
@@ -7479,6 +12427,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<Vec<i64>>> {
+        Ok(self
+            .get_punch_card_stats_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     *
+     * As opposed to `get_punch_card_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
+     */
+    pub async fn get_punch_card_stats_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<Vec<i64>>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/stats/punch_card",
@@ -7488,7 +12455,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7537,6 +12504,76 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     *
+     * As opposed to `get_all_punch_card_stats`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
+     */
+    pub fn get_all_punch_card_stats_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<Vec<i64>>>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/stats/punch_card",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<Vec<i64>>) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     *
+     * As opposed to `get_all_punch_card_stats`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
+     */
+    pub fn get_all_punch_card_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<Vec<i64>>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_punch_card_stats_pages_stream(owner, repo, None))
+    }
     /**
      * Create a commit status.
      *
@@ -7561,6 +12598,27 @@ impl Repos {
         sha: &str,
         body: &crate::types::ReposCreateCommitStatusRequest,
     ) -> ClientResult<crate::types::StatusData> {
+        Ok(self
+            .create_commit_status_with_response(owner, repo, sha, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a commit status.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/statuses/{sha}` endpoint.
+     *
+     * As opposed to `create_commit_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-commit-status>
+     */
+    pub async fn create_commit_status_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        body: &crate::types::ReposCreateCommitStatusRequest,
+    ) -> ClientResult<crate::Response<crate::types::StatusData>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/statuses/{}",
@@ -7571,7 +12629,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7603,6 +12661,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Tag>> {
+        Ok(self
+            .list_tags_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_tags`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub async fn list_tags_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Tag>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -7621,7 +12700,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7664,6 +12743,77 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_all_tags`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub fn list_all_tags_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Tag>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/tags",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Tag>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_all_tags`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub fn list_all_tags_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Tag>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_tags_pages_stream(owner, repo, None))
+    }
     /**
      * Download a repository archive (tar).
      *
@@ -7688,6 +12838,25 @@ impl Repos {
         repo: &str,
         ref_: &str,
     ) -> ClientResult<()> {
+        self.download_tarball_archive_with_response(owner, repo, ref_)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Download a repository archive (tar).
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tarball/{ref}` endpoint.
+     *
+     * As opposed to `download_tarball_archive`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#download-a-repository-archive>
+     */
+    pub async fn download_tarball_archive_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/tarball/{}",
@@ -7698,7 +12867,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7730,6 +12899,27 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .list_teams_with_response(owner, repo, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_teams`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub async fn list_teams_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -7748,7 +12938,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7791,6 +12981,77 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_all_teams`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub fn list_all_teams_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Team>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/teams",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Team>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_all_teams`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub fn list_all_teams_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_teams_pages_stream(owner, repo, None))
+    }
     /**
      * Get all repository topics.
      *
@@ -7814,6 +13075,27 @@ impl Repos {
         page: i64,
         per_page: i64,
     ) -> ClientResult<crate::types::Topic> {
+        Ok(self
+            .get_all_topics_with_response(owner, repo, page, per_page)
+            .await?
+            .body)
+    }
+    /**
+     * Get all repository topics.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/topics` endpoint.
+     *
+     * As opposed to `get_all_topics`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-repository-topics>
+     */
+    pub async fn get_all_topics_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<crate::types::Topic>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -7832,7 +13114,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7861,6 +13143,26 @@ impl Repos {
         repo: &str,
         body: &crate::types::Topic,
     ) -> ClientResult<crate::types::Topic> {
+        Ok(self
+            .replace_all_topics_with_response(owner, repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Replace all repository topics.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/topics` endpoint.
+     *
+     * As opposed to `replace_all_topics`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#replace-all-repository-topics>
+     */
+    pub async fn replace_all_topics_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::Topic,
+    ) -> ClientResult<crate::Response<crate::types::Topic>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/topics",
@@ -7870,7 +13172,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7900,6 +13202,23 @@ impl Repos {
         repo: &str,
         per: crate::types::Per,
     ) -> ClientResult<crate::types::CloneTraffic> {
+        Ok(self.get_clones_with_response(owner, repo, per).await?.body)
+    }
+    /**
+     * Get repository clones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/clones` endpoint.
+     *
+     * As opposed to `get_clones`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-repository-clones>
+     */
+    pub async fn get_clones_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per: crate::types::Per,
+    ) -> ClientResult<crate::Response<crate::types::CloneTraffic>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !per.to_string().is_empty() {
             query_args.push(("per".to_string(), per.to_string()));
@@ -7915,7 +13234,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7943,6 +13262,22 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::ContentTraffic>> {
+        Ok(self.get_top_paths_with_response(owner, repo).await?.body)
+    }
+    /**
+     * Get top referral paths.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/paths` endpoint.
+     *
+     * As opposed to `get_top_paths`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-paths>
+     */
+    pub async fn get_top_paths_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ContentTraffic>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/traffic/popular/paths",
@@ -7952,7 +13287,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7995,6 +13330,81 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get top referral paths.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/paths` endpoint.
+     *
+     * As opposed to `get_all_top_paths`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-paths>
+     */
+    pub fn get_all_top_paths_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ContentTraffic>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/popular/paths",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ContentTraffic>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get top referral paths.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/paths` endpoint.
+     *
+     * As opposed to `get_all_top_paths`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-paths>
+     */
+    pub fn get_all_top_paths_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ContentTraffic>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_top_paths_pages_stream(owner, repo, None))
+    }
     /**
      * Get top referral sources.
      *
@@ -8014,6 +13424,25 @@ impl Repos {
         owner: &str,
         repo: &str,
     ) -> ClientResult<Vec<crate::types::ReferrerTraffic>> {
+        Ok(self
+            .get_top_referrers_with_response(owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Get top referral sources.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/referrers` endpoint.
+     *
+     * As opposed to `get_top_referrers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-sources>
+     */
+    pub async fn get_top_referrers_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReferrerTraffic>>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/traffic/popular/referrers",
@@ -8023,7 +13452,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8066,6 +13495,81 @@ impl Repos {
             )
             .await
     }
+    /**
+     * Get top referral sources.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/referrers` endpoint.
+     *
+     * As opposed to `get_all_top_referrers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-sources>
+     */
+    pub fn get_all_top_referrers_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ReferrerTraffic>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/traffic/popular/referrers",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ReferrerTraffic>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get top referral sources.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/referrers` endpoint.
+     *
+     * As opposed to `get_all_top_referrers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-sources>
+     */
+    pub fn get_all_top_referrers_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReferrerTraffic>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_top_referrers_pages_stream(owner, repo, None))
+    }
     /**
      * Get page views.
      *
@@ -8087,6 +13591,23 @@ impl Repos {
         repo: &str,
         per: crate::types::Per,
     ) -> ClientResult<crate::types::ViewTraffic> {
+        Ok(self.get_views_with_response(owner, repo, per).await?.body)
+    }
+    /**
+     * Get page views.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/views` endpoint.
+     *
+     * As opposed to `get_views`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-page-views>
+     */
+    pub async fn get_views_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        per: crate::types::Per,
+    ) -> ClientResult<crate::Response<crate::types::ViewTraffic>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !per.to_string().is_empty() {
             query_args.push(("per".to_string(), per.to_string()));
@@ -8102,7 +13623,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8131,6 +13652,23 @@ impl Repos {
         repo: &str,
         body: &crate::types::ReposTransferRequest,
     ) -> ClientResult<crate::types::MinimalRepository> {
+        Ok(self.transfer_with_response(owner, repo, body).await?.body)
+    }
+    /**
+     * Transfer a repository.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/transfer` endpoint.
+     *
+     * As opposed to `transfer`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#transfer-a-repository>
+     */
+    pub async fn transfer_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposTransferRequest,
+    ) -> ClientResult<crate::Response<crate::types::MinimalRepository>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/transfer",
@@ -8140,7 +13678,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8164,6 +13702,24 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn check_vulnerability_alerts(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.check_vulnerability_alerts_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check if vulnerability alerts are enabled for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/vulnerability-alerts` endpoint.
+     *
+     * As opposed to `check_vulnerability_alerts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#check-if-vulnerability-alerts-are-enabled-for-a-repository>
+     */
+    pub async fn check_vulnerability_alerts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/vulnerability-alerts",
@@ -8173,7 +13729,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8197,6 +13753,24 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn enable_vulnerability_alerts(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.enable_vulnerability_alerts_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Enable vulnerability alerts.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/vulnerability-alerts` endpoint.
+     *
+     * As opposed to `enable_vulnerability_alerts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#enable-vulnerability-alerts>
+     */
+    pub async fn enable_vulnerability_alerts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/vulnerability-alerts",
@@ -8206,7 +13780,7 @@ impl Repos {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8230,6 +13804,24 @@ impl Repos {
      * * `repo: &str`
      */
     pub async fn disable_vulnerability_alerts(&self, owner: &str, repo: &str) -> ClientResult<()> {
+        self.disable_vulnerability_alerts_with_response(owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Disable vulnerability alerts.
+     *
+     * This function performs a `DELETE` to the `/repos/{owner}/{repo}/vulnerability-alerts` endpoint.
+     *
+     * As opposed to `disable_vulnerability_alerts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#disable-vulnerability-alerts>
+     */
+    pub async fn disable_vulnerability_alerts_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/vulnerability-alerts",
@@ -8239,7 +13831,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8272,6 +13864,25 @@ impl Repos {
         repo: &str,
         ref_: &str,
     ) -> ClientResult<()> {
+        self.download_zipball_archive_with_response(owner, repo, ref_)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Download a repository archive (zip).
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/zipball/{ref}` endpoint.
+     *
+     * As opposed to `download_zipball_archive`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#download-a-repository-archive>
+     */
+    pub async fn download_zipball_archive_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/zipball/{}",
@@ -8282,7 +13893,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8318,6 +13929,26 @@ impl Repos {
         template_repo: &str,
         body: &crate::types::ReposCreateUsingTemplateRequest,
     ) -> ClientResult<crate::types::Repository> {
+        Ok(self
+            .create_using_template_with_response(template_owner, template_repo, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a repository using a template.
+     *
+     * This function performs a `POST` to the `/repos/{template_owner}/{template_repo}/generate` endpoint.
+     *
+     * As opposed to `create_using_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-repository-using-a-template>
+     */
+    pub async fn create_using_template_with_response(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        body: &crate::types::ReposCreateUsingTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Repository>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/generate",
@@ -8327,7 +13958,7 @@ impl Repos {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8349,14 +13980,29 @@ impl Repos {
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
      *
-     * **Parameters:**
+     * **Parameters:**
+     *
+     * * `since: i64` -- A repository ID. Only return repositories with an ID greater than this ID.
+     */
+    pub async fn list_public(
+        &self,
+        since: i64,
+    ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self.list_public_with_response(since).await?.body)
+    }
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_public`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      *
-     * * `since: i64` -- A repository ID. Only return repositories with an ID greater than this ID.
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
      */
-    pub async fn list_public(
+    pub async fn list_public_with_response(
         &self,
         since: i64,
-    ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if since > 0 {
             query_args.push(("since".to_string(), since.to_string()));
@@ -8364,7 +14010,7 @@ impl Repos {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/repositories?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8408,6 +14054,78 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     */
+    pub fn list_all_public_pages_stream(
+        &self,
+        since: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/repositories?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     */
+    pub fn list_all_public_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_public_pages_stream(since, None))
+    }
     /**
      * List repositories for the authenticated user.
      *
@@ -8450,6 +14168,42 @@ impl Repos {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::Repository>> {
+        Ok(self
+            .list_for_authenticated_user_with_response(
+                visibility,
+                affiliation,
+                type_,
+                sort,
+                direction,
+                per_page,
+                page,
+                since,
+                before,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub async fn list_for_authenticated_user_with_response(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Repository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !affiliation.is_empty() {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
@@ -8481,7 +14235,7 @@ impl Repos {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/repos?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8547,6 +14301,116 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_pages_stream(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Repository>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !affiliation.is_empty() {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        if let Some(date) = before {
+            query_args.push(("before".to_string(), date.to_rfc3339()));
+        }
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if !visibility.to_string().is_empty() {
+            query_args.push(("visibility".to_string(), visibility.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/user/repos?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Repository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Repository>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_authenticated_user_pages_stream(
+            visibility,
+            affiliation,
+            type_,
+            sort,
+            direction,
+            since,
+            before,
+            None,
+        ))
+    }
     /**
      * Create a repository for the authenticated user.
      *
@@ -8567,9 +14431,27 @@ impl Repos {
         &self,
         body: &crate::types::ReposCreateRequest,
     ) -> ClientResult<crate::types::Repository> {
+        Ok(self
+            .create_for_authenticated_user_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a repository for the authenticated user.
+     *
+     * This function performs a `POST` to the `/user/repos` endpoint.
+     *
+     * As opposed to `create_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-repository-for-the-authenticated-user>
+     */
+    pub async fn create_for_authenticated_user_with_response(
+        &self,
+        body: &crate::types::ReposCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::Repository>> {
         let url = self.client.url("/user/repos", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8597,6 +14479,25 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::RepositoryInvitation>> {
+        Ok(self
+            .list_invitations_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_invitations_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub async fn list_invitations_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RepositoryInvitation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -8609,7 +14510,7 @@ impl Repos {
             .client
             .url(&format!("/user/repository_invitations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8643,6 +14544,73 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub fn list_all_invitations_for_authenticated_user_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::RepositoryInvitation>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/user/repository_invitations", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::RepositoryInvitation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub fn list_all_invitations_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RepositoryInvitation>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_invitations_for_authenticated_user_pages_stream(None),
+        )
+    }
     /**
      * Decline a repository invitation.
      *
@@ -8657,6 +14625,22 @@ impl Repos {
      * * `invitation_id: i64` -- invitation_id parameter.
      */
     pub async fn decline_invitation(&self, invitation_id: i64) -> ClientResult<()> {
+        self.decline_invitation_with_response(invitation_id).await?;
+        Ok(())
+    }
+    /**
+     * Decline a repository invitation.
+     *
+     * This function performs a `DELETE` to the `/user/repository_invitations/{invitation_id}` endpoint.
+     *
+     * As opposed to `decline_invitation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#decline-a-repository-invitation>
+     */
+    pub async fn decline_invitation_with_response(
+        &self,
+        invitation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/repository_invitations/{}",
@@ -8665,7 +14649,7 @@ impl Repos {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8688,6 +14672,22 @@ impl Repos {
      * * `invitation_id: i64` -- invitation_id parameter.
      */
     pub async fn accept_invitation(&self, invitation_id: i64) -> ClientResult<()> {
+        self.accept_invitation_with_response(invitation_id).await?;
+        Ok(())
+    }
+    /**
+     * Accept a repository invitation.
+     *
+     * This function performs a `PATCH` to the `/user/repository_invitations/{invitation_id}` endpoint.
+     *
+     * As opposed to `accept_invitation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#accept-a-repository-invitation>
+     */
+    pub async fn accept_invitation_with_response(
+        &self,
+        invitation_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/repository_invitations/{}",
@@ -8696,7 +14696,7 @@ impl Repos {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8734,6 +14734,29 @@ impl Repos {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_for_user_with_response(username, type_, sort, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub async fn list_for_user_with_response(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -8760,7 +14783,7 @@ impl Repos {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8816,4 +14839,97 @@ impl Repos {
             )
             .await
     }
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub fn list_all_for_user_pages_stream(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !sort.to_string().is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/users/{}/repos?{}",
+                crate::progenitor_support::encode_path(username),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub fn list_all_for_user_stream(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_for_user_pages_stream(username, type_, sort, direction, None),
+        )
+    }
 }
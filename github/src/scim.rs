@@ -55,6 +55,27 @@ impl Scim {
         count: i64,
         filter: &str,
     ) -> ClientResult<crate::types::ScimUserList> {
+        Ok(self
+            .list_provisioned_identities_with_response(org, start_index, count, filter)
+            .await?
+            .body)
+    }
+    /**
+     * List SCIM provisioned identities.
+     *
+     * This function performs a `GET` to the `/scim/v2/organizations/{org}/Users` endpoint.
+     *
+     * As opposed to `list_provisioned_identities`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/scim#list-scim-provisioned-identities>
+     */
+    pub async fn list_provisioned_identities_with_response(
+        &self,
+        org: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimUserList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -75,7 +96,7 @@ impl Scim {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -102,6 +123,25 @@ impl Scim {
         org: &str,
         body: &crate::types::ScimProvisionInviteUserRequest,
     ) -> ClientResult<crate::types::ScimUser> {
+        Ok(self
+            .provision_and_invite_user_with_response(org, body)
+            .await?
+            .body)
+    }
+    /**
+     * Provision and invite a SCIM user.
+     *
+     * This function performs a `POST` to the `/scim/v2/organizations/{org}/Users` endpoint.
+     *
+     * As opposed to `provision_and_invite_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/scim#provision-and-invite-a-scim-user>
+     */
+    pub async fn provision_and_invite_user_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::ScimProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/organizations/{}/Users",
@@ -110,7 +150,7 @@ impl Scim {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -138,6 +178,25 @@ impl Scim {
         org: &str,
         scim_user_id: &str,
     ) -> ClientResult<crate::types::ScimUser> {
+        Ok(self
+            .get_provisioning_information_for_user_with_response(org, scim_user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get SCIM provisioning information for a user.
+     *
+     * This function performs a `GET` to the `/scim/v2/organizations/{org}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `get_provisioning_information_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/scim#get-scim-provisioning-information-for-a-user>
+     */
+    pub async fn get_provisioning_information_for_user_with_response(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/organizations/{}/Users/{}",
@@ -147,7 +206,7 @@ impl Scim {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -180,6 +239,26 @@ impl Scim {
         scim_user_id: &str,
         body: &crate::types::ScimProvisionInviteUserRequest,
     ) -> ClientResult<crate::types::ScimUser> {
+        Ok(self
+            .set_information_for_provisioned_user_with_response(org, scim_user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a provisioned organization membership.
+     *
+     * This function performs a `PUT` to the `/scim/v2/organizations/{org}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `set_information_for_provisioned_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/scim#set-scim-information-for-a-provisioned-user>
+     */
+    pub async fn set_information_for_provisioned_user_with_response(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimProvisionInviteUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/organizations/{}/Users/{}",
@@ -189,7 +268,7 @@ impl Scim {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -213,6 +292,24 @@ impl Scim {
      * * `scim_user_id: &str` -- scim_user_id parameter.
      */
     pub async fn delete_user_from_org(&self, org: &str, scim_user_id: &str) -> ClientResult<()> {
+        self.delete_user_from_org_with_response(org, scim_user_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a SCIM user from an organization.
+     *
+     * This function performs a `DELETE` to the `/scim/v2/organizations/{org}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `delete_user_from_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/scim#delete-a-scim-user-from-an-organization>
+     */
+    pub async fn delete_user_from_org_with_response(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/organizations/{}/Users/{}",
@@ -222,7 +319,7 @@ impl Scim {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -266,6 +363,26 @@ impl Scim {
         scim_user_id: &str,
         body: &crate::types::ScimUpdateAttributeUserRequest,
     ) -> ClientResult<crate::types::ScimUser> {
+        Ok(self
+            .update_attribute_for_user_with_response(org, scim_user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an attribute for a SCIM user.
+     *
+     * This function performs a `PATCH` to the `/scim/v2/organizations/{org}/Users/{scim_user_id}` endpoint.
+     *
+     * As opposed to `update_attribute_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/scim#update-an-attribute-for-a-scim-user>
+     */
+    pub async fn update_attribute_for_user_with_response(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimUpdateAttributeUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScimUser>> {
         let url = self.client.url(
             &format!(
                 "/scim/v2/organizations/{}/Users/{}",
@@ -275,7 +392,7 @@ impl Scim {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
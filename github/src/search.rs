@@ -53,6 +53,28 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::SearchCodeResponse> {
+        Ok(self
+            .code_with_response(q, sort, order, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Search code.
+     *
+     * This function performs a `GET` to the `/search/code` endpoint.
+     *
+     * As opposed to `code`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-code>
+     */
+    pub async fn code_with_response(
+        &self,
+        q: &str,
+        sort: crate::types::SearchCodeSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchCodeResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order.to_string().is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
@@ -72,7 +94,7 @@ impl Search {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/search/code?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -113,6 +135,28 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::SearchCommitsResponse> {
+        Ok(self
+            .commits_with_response(q, sort, order, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Search commits.
+     *
+     * This function performs a `GET` to the `/search/commits` endpoint.
+     *
+     * As opposed to `commits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-commits>
+     */
+    pub async fn commits_with_response(
+        &self,
+        q: &str,
+        sort: crate::types::SearchCommitsSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchCommitsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order.to_string().is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
@@ -134,7 +178,7 @@ impl Search {
             .client
             .url(&format!("/search/commits?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -179,6 +223,28 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::SearchIssuesPullRequestsResponse> {
+        Ok(self
+            .issues_and_pull_requests_with_response(q, sort, order, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Search issues and pull requests.
+     *
+     * This function performs a `GET` to the `/search/issues` endpoint.
+     *
+     * As opposed to `issues_and_pull_requests`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-issues-and-pull-requests>
+     */
+    pub async fn issues_and_pull_requests_with_response(
+        &self,
+        q: &str,
+        sort: crate::types::SearchIssuesPullRequestsSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchIssuesPullRequestsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order.to_string().is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
@@ -198,7 +264,7 @@ impl Search {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/search/issues?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -242,6 +308,29 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::SearchLabelsResponse> {
+        Ok(self
+            .labels_with_response(repository_id, q, sort, order, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Search labels.
+     *
+     * This function performs a `GET` to the `/search/labels` endpoint.
+     *
+     * As opposed to `labels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-labels>
+     */
+    pub async fn labels_with_response(
+        &self,
+        repository_id: i64,
+        q: &str,
+        sort: crate::types::Sort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchLabelsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order.to_string().is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
@@ -264,7 +353,7 @@ impl Search {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/search/labels?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -310,6 +399,28 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::SearchReposResponse> {
+        Ok(self
+            .repos_with_response(q, sort, order, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Search repositories.
+     *
+     * This function performs a `GET` to the `/search/repositories` endpoint.
+     *
+     * As opposed to `repos`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-repositories>
+     */
+    pub async fn repos_with_response(
+        &self,
+        q: &str,
+        sort: crate::types::SearchReposSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchReposResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order.to_string().is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
@@ -331,7 +442,7 @@ impl Search {
             .client
             .url(&format!("/search/repositories?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -369,6 +480,23 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::SearchTopicsResponse> {
+        Ok(self.topics_with_response(q, per_page, page).await?.body)
+    }
+    /**
+     * Search topics.
+     *
+     * This function performs a `GET` to the `/search/topics` endpoint.
+     *
+     * As opposed to `topics`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-topics>
+     */
+    pub async fn topics_with_response(
+        &self,
+        q: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchTopicsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -382,7 +510,7 @@ impl Search {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/search/topics?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -424,6 +552,28 @@ impl Search {
         per_page: i64,
         page: i64,
     ) -> ClientResult<crate::types::SearchUsersResponse> {
+        Ok(self
+            .users_with_response(q, sort, order, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * Search users.
+     *
+     * This function performs a `GET` to the `/search/users` endpoint.
+     *
+     * As opposed to `users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/search#search-users>
+     */
+    pub async fn users_with_response(
+        &self,
+        q: &str,
+        sort: crate::types::SearchUsersSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<crate::types::SearchUsersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !order.to_string().is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
@@ -443,7 +593,7 @@ impl Search {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/search/users?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
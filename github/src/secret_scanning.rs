@@ -40,6 +40,29 @@ impl SecretScanning {
         page: i64,
         per_page: i64,
     ) -> ClientResult<Vec<crate::types::SecretScanningAlert>> {
+        Ok(self
+            .list_alerts_for_repo_with_response(owner, repo, state, secret_type, page, per_page)
+            .await?
+            .body)
+    }
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_alerts_for_repo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub async fn list_alerts_for_repo_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningAlertState,
+        secret_type: &str,
+        page: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SecretScanningAlert>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -64,7 +87,7 @@ impl SecretScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -120,6 +143,102 @@ impl SecretScanning {
             )
             .await
     }
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_pages_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningAlertState,
+        secret_type: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::SecretScanningAlert>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !secret_type.is_empty() {
+            query_args.push(("secret_type".to_string(), secret_type.to_string()));
+        }
+        if !state.to_string().is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/repos/{}/{}/secret-scanning/alerts?{}",
+                crate::progenitor_support::encode_path(owner),
+                crate::progenitor_support::encode_path(repo),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SecretScanningAlert>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub fn list_all_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningAlertState,
+        secret_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SecretScanningAlert>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_alerts_for_repo_pages_stream(
+            owner,
+            repo,
+            state,
+            secret_type,
+            None,
+        ))
+    }
     /**
      * Get a secret scanning alert.
      *
@@ -143,6 +262,26 @@ impl SecretScanning {
         repo: &str,
         alert_number: i64,
     ) -> ClientResult<crate::types::SecretScanningAlert> {
+        Ok(self
+            .get_alert_with_response(owner, repo, alert_number)
+            .await?
+            .body)
+    }
+    /**
+     * Get a secret scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}` endpoint.
+     *
+     * As opposed to `get_alert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#get-a-secret-scanning-alert>
+     */
+    pub async fn get_alert_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::SecretScanningAlert>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/secret-scanning/alerts/{}",
@@ -153,7 +292,7 @@ impl SecretScanning {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -186,6 +325,27 @@ impl SecretScanning {
         alert_number: i64,
         body: &crate::types::SecretScanningUpdateAlertRequest,
     ) -> ClientResult<crate::types::SecretScanningAlert> {
+        Ok(self
+            .update_alert_with_response(owner, repo, alert_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a secret scanning alert.
+     *
+     * This function performs a `PATCH` to the `/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}` endpoint.
+     *
+     * As opposed to `update_alert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#update-a-secret-scanning-alert>
+     */
+    pub async fn update_alert_with_response(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::SecretScanningUpdateAlertRequest,
+    ) -> ClientResult<crate::Response<crate::types::SecretScanningAlert>> {
         let url = self.client.url(
             &format!(
                 "/repos/{}/{}/secret-scanning/alerts/{}",
@@ -196,7 +356,7 @@ impl SecretScanning {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -36,6 +36,26 @@ impl Teams {
         per_page: i64,
         page: &str,
     ) -> ClientResult<crate::types::GroupMapping> {
+        Ok(self
+            .list_idp_groups_for_org_with_response(org, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List IdP groups for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/team-sync/groups` endpoint.
+     *
+     * As opposed to `list_idp_groups_for_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-idp-groups-for-an-organization>
+     */
+    pub async fn list_idp_groups_for_org_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page.is_empty() {
             query_args.push(("page".to_string(), page.to_string()));
@@ -53,7 +73,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -83,6 +103,23 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self.list_with_response(org, per_page, page).await?.body)
+    }
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub async fn list_with_response(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -100,7 +137,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -138,6 +175,74 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        org: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Team>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams",
+                crate::progenitor_support::encode_path(org),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Team>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub fn list_all_stream(
+        &self,
+        org: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(org, None))
+    }
     /**
      * Create a team.
      *
@@ -158,6 +263,22 @@ impl Teams {
         org: &str,
         body: &crate::types::TeamsCreateRequest,
     ) -> ClientResult<crate::types::FullTeam> {
+        Ok(self.create_with_response(org, body).await?.body)
+    }
+    /**
+     * Create a team.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-a-team>
+     */
+    pub async fn create_with_response(
+        &self,
+        org: &str,
+        body: &crate::types::TeamsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams",
@@ -166,7 +287,7 @@ impl Teams {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -196,6 +317,22 @@ impl Teams {
         org: &str,
         team_slug: &str,
     ) -> ClientResult<crate::types::FullTeam> {
+        Ok(self.get_by_name_with_response(org, team_slug).await?.body)
+    }
+    /**
+     * Get a team by name.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}` endpoint.
+     *
+     * As opposed to `get_by_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-a-team-by-name>
+     */
+    pub async fn get_by_name_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}",
@@ -205,7 +342,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -233,6 +370,23 @@ impl Teams {
      * * `team_slug: &str` -- team_slug parameter.
      */
     pub async fn delete_in_org(&self, org: &str, team_slug: &str) -> ClientResult<()> {
+        self.delete_in_org_with_response(org, team_slug).await?;
+        Ok(())
+    }
+    /**
+     * Delete a team.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}` endpoint.
+     *
+     * As opposed to `delete_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#delete-a-team>
+     */
+    pub async fn delete_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}",
@@ -242,7 +396,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -273,6 +427,26 @@ impl Teams {
         team_slug: &str,
         body: &crate::types::TeamsUpdateInOrgRequest,
     ) -> ClientResult<crate::types::FullTeam> {
+        Ok(self
+            .update_in_org_with_response(org, team_slug, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a team.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}/teams/{team_slug}` endpoint.
+     *
+     * As opposed to `update_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#update-a-team>
+     */
+    pub async fn update_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsUpdateInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}",
@@ -282,7 +456,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -322,6 +496,31 @@ impl Teams {
         page: i64,
         pinned: &str,
     ) -> ClientResult<Vec<crate::types::TeamDiscussion>> {
+        Ok(self
+            .list_discussions_in_org_with_response(
+                org, team_slug, direction, per_page, page, pinned,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_discussions_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub async fn list_discussions_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+        pinned: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -346,7 +545,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -402,6 +601,96 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub fn list_all_discussions_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Order,
+        pinned: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TeamDiscussion>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if !pinned.is_empty() {
+            query_args.push(("pinned".to_string(), pinned.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TeamDiscussion>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub fn list_all_discussions_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Order,
+        pinned: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussion>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_discussions_in_org_pages_stream(org, team_slug, direction, pinned, None),
+        )
+    }
     /**
      * Create a discussion.
      *
@@ -426,6 +715,26 @@ impl Teams {
         team_slug: &str,
         body: &crate::types::TeamsCreateDiscussionInOrgRequest,
     ) -> ClientResult<crate::types::TeamDiscussion> {
+        Ok(self
+            .create_discussion_in_org_with_response(org, team_slug, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a discussion.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `create_discussion_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion>
+     */
+    pub async fn create_discussion_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions",
@@ -435,7 +744,7 @@ impl Teams {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -467,6 +776,26 @@ impl Teams {
         team_slug: &str,
         discussion_number: i64,
     ) -> ClientResult<crate::types::TeamDiscussion> {
+        Ok(self
+            .get_discussion_in_org_with_response(org, team_slug, discussion_number)
+            .await?
+            .body)
+    }
+    /**
+     * Get a discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}` endpoint.
+     *
+     * As opposed to `get_discussion_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-a-discussion>
+     */
+    pub async fn get_discussion_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}",
@@ -477,7 +806,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -509,6 +838,25 @@ impl Teams {
         team_slug: &str,
         discussion_number: i64,
     ) -> ClientResult<()> {
+        self.delete_discussion_in_org_with_response(org, team_slug, discussion_number)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a discussion.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}` endpoint.
+     *
+     * As opposed to `delete_discussion_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#delete-a-discussion>
+     */
+    pub async fn delete_discussion_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}",
@@ -519,7 +867,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -552,6 +900,27 @@ impl Teams {
         discussion_number: i64,
         body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
     ) -> ClientResult<crate::types::TeamDiscussion> {
+        Ok(self
+            .update_discussion_in_org_with_response(org, team_slug, discussion_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a discussion.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}` endpoint.
+     *
+     * As opposed to `update_discussion_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#update-a-discussion>
+     */
+    pub async fn update_discussion_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}",
@@ -562,7 +931,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -602,6 +971,36 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::TeamDiscussionComment>> {
+        Ok(self
+            .list_discussion_comments_in_org_with_response(
+                org,
+                team_slug,
+                discussion_number,
+                direction,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List discussion comments.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_discussion_comments_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub async fn list_discussion_comments_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -624,7 +1023,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -679,21 +1078,115 @@ impl Teams {
             .await
     }
     /**
-     * Create a discussion comment.
-     *
-     * This function performs a `POST` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
-     *
-     * Creates a new comment on a team discussion. OAuth access tokens require the `write:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
-     *
-     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
-     *
-     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `POST /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
+     * List discussion comments.
      *
-     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion-comment>
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_discussion_comments_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
-     * * `org: &str`
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub fn list_all_discussion_comments_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::TeamDiscussionComment>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TeamDiscussionComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List discussion comments.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub fn list_all_discussion_comments_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussionComment>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_discussion_comments_in_org_pages_stream(
+            org,
+            team_slug,
+            discussion_number,
+            direction,
+            None,
+        ))
+    }
+    /**
+     * Create a discussion comment.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * Creates a new comment on a team discussion. OAuth access tokens require the `write:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `POST /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion-comment>
+     *
+     * **Parameters:**
+     *
+     * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
      * * `discussion_number: i64`
      */
@@ -704,6 +1197,27 @@ impl Teams {
         discussion_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::TeamDiscussionComment> {
+        Ok(self
+            .create_discussion_comment_in_org_with_response(org, team_slug, discussion_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a discussion comment.
+     *
+     * This function performs a `POST` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `create_discussion_comment_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion-comment>
+     */
+    pub async fn create_discussion_comment_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/comments",
@@ -714,7 +1228,7 @@ impl Teams {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -748,6 +1262,32 @@ impl Teams {
         discussion_number: i64,
         comment_number: i64,
     ) -> ClientResult<crate::types::TeamDiscussionComment> {
+        Ok(self
+            .get_discussion_comment_in_org_with_response(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get a discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
+     *
+     * As opposed to `get_discussion_comment_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-a-discussion-comment>
+     */
+    pub async fn get_discussion_comment_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/comments/{}",
@@ -759,7 +1299,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -793,6 +1333,31 @@ impl Teams {
         discussion_number: i64,
         comment_number: i64,
     ) -> ClientResult<()> {
+        self.delete_discussion_comment_in_org_with_response(
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete a discussion comment.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
+     *
+     * As opposed to `delete_discussion_comment_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#delete-a-discussion-comment>
+     */
+    pub async fn delete_discussion_comment_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/comments/{}",
@@ -804,7 +1369,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -839,6 +1404,34 @@ impl Teams {
         comment_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::TeamDiscussionComment> {
+        Ok(self
+            .update_discussion_comment_in_org_with_response(
+                org,
+                team_slug,
+                discussion_number,
+                comment_number,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Update a discussion comment.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
+     *
+     * As opposed to `update_discussion_comment_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#update-a-discussion-comment>
+     */
+    pub async fn update_discussion_comment_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/discussions/{}/comments/{}",
@@ -850,7 +1443,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -884,6 +1477,27 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        Ok(self
+            .list_pending_invitations_in_org_with_response(org, team_slug, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_pending_invitations_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub async fn list_pending_invitations_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -902,7 +1516,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -947,6 +1561,85 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub fn list_all_pending_invitations_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrganizationInvitation>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/invitations",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrganizationInvitation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub fn list_all_pending_invitations_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_pending_invitations_in_org_pages_stream(org, team_slug, None),
+        )
+    }
     /**
      * List team members.
      *
@@ -977,6 +1670,28 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_members_in_org_with_response(org, team_slug, role, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List team members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
+     *
+     * As opposed to `list_members_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub async fn list_members_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -998,7 +1713,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1050,6 +1765,91 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
+     *
+     * As opposed to `list_all_members_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub fn list_all_members_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !role.to_string().is_empty() {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List team members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
+     *
+     * As opposed to `list_all_members_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub fn list_all_members_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_members_in_org_pages_stream(org, team_slug, role, None),
+        )
+    }
     /**
      * Get team membership for a user.
      *
@@ -1080,6 +1880,26 @@ impl Teams {
         team_slug: &str,
         username: &str,
     ) -> ClientResult<crate::types::TeamMembership> {
+        Ok(self
+            .get_membership_for_user_in_org_with_response(org, team_slug, username)
+            .await?
+            .body)
+    }
+    /**
+     * Get team membership for a user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/memberships/{username}` endpoint.
+     *
+     * As opposed to `get_membership_for_user_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-team-membership-for-a-user>
+     */
+    pub async fn get_membership_for_user_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/memberships/{}",
@@ -1090,7 +1910,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1131,6 +1951,27 @@ impl Teams {
         username: &str,
         body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
     ) -> ClientResult<crate::types::TeamMembership> {
+        Ok(self
+            .add_or_update_membership_for_user_in_org_with_response(org, team_slug, username, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add or update team membership for a user.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/teams/{team_slug}/memberships/{username}` endpoint.
+     *
+     * As opposed to `add_or_update_membership_for_user_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#add-or-update-team-membership-for-a-user>
+     */
+    pub async fn add_or_update_membership_for_user_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/memberships/{}",
@@ -1141,7 +1982,7 @@ impl Teams {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1171,12 +2012,31 @@ impl Teams {
      * * `team_slug: &str` -- team_slug parameter.
      * * `username: &str`
      */
-    pub async fn remove_membership_for_user_in_org(
+    pub async fn remove_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> ClientResult<()> {
+        self.remove_membership_for_user_in_org_with_response(org, team_slug, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove team membership for a user.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}/memberships/{username}` endpoint.
+     *
+     * As opposed to `remove_membership_for_user_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#remove-team-membership-for-a-user>
+     */
+    pub async fn remove_membership_for_user_in_org_with_response(
         &self,
         org: &str,
         team_slug: &str,
         username: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/memberships/{}",
@@ -1187,7 +2047,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1221,6 +2081,27 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::TeamProject>> {
+        Ok(self
+            .list_projects_in_org_with_response(org, team_slug, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_projects_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub async fn list_projects_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1239,7 +2120,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1284,6 +2165,81 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub fn list_all_projects_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TeamProject>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/projects",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TeamProject>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub fn list_all_projects_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamProject>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_projects_in_org_pages_stream(org, team_slug, None))
+    }
     /**
      * Check team permissions for a project.
      *
@@ -1307,6 +2263,26 @@ impl Teams {
         team_slug: &str,
         project_id: i64,
     ) -> ClientResult<crate::types::TeamProject> {
+        Ok(self
+            .check_permissions_for_project_in_org_with_response(org, team_slug, project_id)
+            .await?
+            .body)
+    }
+    /**
+     * Check team permissions for a project.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects/{project_id}` endpoint.
+     *
+     * As opposed to `check_permissions_for_project_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#check-team-permissions-for-a-project>
+     */
+    pub async fn check_permissions_for_project_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamProject>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/projects/{}",
@@ -1317,7 +2293,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1350,6 +2326,28 @@ impl Teams {
         project_id: i64,
         body: &crate::types::ProjectsAddCollaboratorRequest,
     ) -> ClientResult<()> {
+        self.add_or_update_project_permissions_in_org_with_response(
+            org, team_slug, project_id, body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Add or update team project permissions.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/teams/{team_slug}/projects/{project_id}` endpoint.
+     *
+     * As opposed to `add_or_update_project_permissions_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#add-or-update-team-project-permissions>
+     */
+    pub async fn add_or_update_project_permissions_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+        body: &crate::types::ProjectsAddCollaboratorRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/projects/{}",
@@ -1360,7 +2358,7 @@ impl Teams {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1392,6 +2390,25 @@ impl Teams {
         team_slug: &str,
         project_id: i64,
     ) -> ClientResult<()> {
+        self.remove_project_in_org_with_response(org, team_slug, project_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a project from a team.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}/projects/{project_id}` endpoint.
+     *
+     * As opposed to `remove_project_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#remove-a-project-from-a-team>
+     */
+    pub async fn remove_project_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/projects/{}",
@@ -1402,7 +2419,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1436,6 +2453,27 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_repos_in_org_with_response(org, team_slug, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_repos_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub async fn list_repos_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1454,7 +2492,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1499,6 +2537,82 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub fn list_all_repos_in_org_pages_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/repos",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub fn list_all_repos_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_repos_in_org_pages_stream(org, team_slug, None))
+    }
     /**
      * Check team permissions for a repository.
      *
@@ -1528,6 +2642,27 @@ impl Teams {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::TeamRepository> {
+        Ok(self
+            .check_permissions_for_repo_in_org_with_response(org, team_slug, owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Check team permissions for a repository.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `check_permissions_for_repo_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#check-team-permissions-for-a-repository>
+     */
+    pub async fn check_permissions_for_repo_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamRepository>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/repos/{}/{}",
@@ -1539,7 +2674,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1576,6 +2711,27 @@ impl Teams {
         repo: &str,
         body: &crate::types::TeamsAddUpdateRepoPermissionsInOrgRequest,
     ) -> ClientResult<()> {
+        self.add_or_update_repo_permissions_in_org_with_response(org, team_slug, owner, repo, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add or update team repository permissions.
+     *
+     * This function performs a `PUT` to the `/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `add_or_update_repo_permissions_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#add-or-update-team-repository-permissions>
+     */
+    pub async fn add_or_update_repo_permissions_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsInOrgRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/repos/{}/{}",
@@ -1587,7 +2743,7 @@ impl Teams {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1621,6 +2777,26 @@ impl Teams {
         owner: &str,
         repo: &str,
     ) -> ClientResult<()> {
+        self.remove_repo_in_org_with_response(org, team_slug, owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a repository from a team.
+     *
+     * This function performs a `DELETE` to the `/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `remove_repo_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#remove-a-repository-from-a-team>
+     */
+    pub async fn remove_repo_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/repos/{}/{}",
@@ -1632,7 +2808,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1664,6 +2840,25 @@ impl Teams {
         org: &str,
         team_slug: &str,
     ) -> ClientResult<crate::types::GroupMapping> {
+        Ok(self
+            .list_idp_groups_in_org_with_response(org, team_slug)
+            .await?
+            .body)
+    }
+    /**
+     * List IdP groups for a team.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/team-sync/group-mappings` endpoint.
+     *
+     * As opposed to `list_idp_groups_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-idp-groups-for-a-team>
+     */
+    pub async fn list_idp_groups_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/team-sync/group-mappings",
@@ -1673,7 +2868,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1706,6 +2901,26 @@ impl Teams {
         team_slug: &str,
         body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsInOrgRequest,
     ) -> ClientResult<crate::types::GroupMapping> {
+        Ok(self
+            .create_or_update_idp_group_connections_in_org_with_response(org, team_slug, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create or update IdP group connections.
+     *
+     * This function performs a `PATCH` to the `/orgs/{org}/teams/{team_slug}/team-sync/group-mappings` endpoint.
+     *
+     * As opposed to `create_or_update_idp_group_connections_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-or-update-idp-group-connections>
+     */
+    pub async fn create_or_update_idp_group_connections_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/team-sync/group-mappings",
@@ -1715,7 +2930,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1749,6 +2964,27 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .list_child_in_org_with_response(org, team_slug, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
+     *
+     * As opposed to `list_child_in_org`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub async fn list_child_in_org_with_response(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1767,7 +3003,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1783,17 +3019,51 @@ impl Teams {
      *
      * As opposed to `list_child_in_org`, this function returns all the pages of the request at once.
      *
-     * Lists the child teams of the team specified by `{team_slug}`.
+     * Lists the child teams of the team specified by `{team_slug}`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/teams`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub async fn list_all_child_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> ClientResult<Vec<crate::types::Team>> {
+        let url = self.client.url(
+            &format!(
+                "/orgs/{}/teams/{}/teams",
+                crate::progenitor_support::encode_path(org),
+                crate::progenitor_support::encode_path(team_slug),
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
      *
-     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/teams`.
+     * As opposed to `list_all_child_in_org`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
      */
-    pub async fn list_all_child_in_org(
+    pub fn list_all_child_in_org_pages_stream(
         &self,
         org: &str,
         team_slug: &str,
-    ) -> ClientResult<Vec<crate::types::Team>> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Team>>> + Unpin + '_
+    {
         let url = self.client.url(
             &format!(
                 "/orgs/{}/teams/{}/teams",
@@ -1802,15 +3072,52 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Team>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_in_org`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub fn list_all_child_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_child_in_org_pages_stream(org, team_slug, None))
     }
     /**
      * Get a team (Legacy).
@@ -1826,6 +3133,21 @@ impl Teams {
      * * `team_id: i64`
      */
     pub async fn get_legacy(&self, team_id: i64) -> ClientResult<crate::types::FullTeam> {
+        Ok(self.get_legacy_with_response(team_id).await?.body)
+    }
+    /**
+     * Get a team (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}` endpoint.
+     *
+     * As opposed to `get_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#get-a-team-legacy>
+     */
+    pub async fn get_legacy_with_response(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}",
@@ -1834,7 +3156,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1861,6 +3183,22 @@ impl Teams {
      * * `team_id: i64`
      */
     pub async fn delete_legacy(&self, team_id: i64) -> ClientResult<()> {
+        self.delete_legacy_with_response(team_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a team (Legacy).
+     *
+     * This function performs a `DELETE` to the `/teams/{team_id}` endpoint.
+     *
+     * As opposed to `delete_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#delete-a-team-legacy>
+     */
+    pub async fn delete_legacy_with_response(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}",
@@ -1869,7 +3207,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1900,6 +3238,22 @@ impl Teams {
         team_id: i64,
         body: &crate::types::TeamsUpdateInOrgRequest,
     ) -> ClientResult<crate::types::FullTeam> {
+        Ok(self.update_legacy_with_response(team_id, body).await?.body)
+    }
+    /**
+     * Update a team (Legacy).
+     *
+     * This function performs a `PATCH` to the `/teams/{team_id}` endpoint.
+     *
+     * As opposed to `update_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#update-a-team-legacy>
+     */
+    pub async fn update_legacy_with_response(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsUpdateInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::FullTeam>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}",
@@ -1908,7 +3262,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1944,6 +3298,27 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::TeamDiscussion>> {
+        Ok(self
+            .list_discussions_legacy_with_response(team_id, direction, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_discussions_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub async fn list_discussions_legacy_with_response(
+        &self,
+        team_id: i64,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussion>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -1964,7 +3339,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2014,6 +3389,88 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub fn list_all_discussions_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TeamDiscussion>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TeamDiscussion>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub fn list_all_discussions_legacy_stream(
+        &self,
+        team_id: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussion>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_discussions_legacy_pages_stream(team_id, direction, None),
+        )
+    }
     /**
      * Create a discussion (Legacy).
      *
@@ -2036,6 +3493,25 @@ impl Teams {
         team_id: i64,
         body: &crate::types::TeamsCreateDiscussionInOrgRequest,
     ) -> ClientResult<crate::types::TeamDiscussion> {
+        Ok(self
+            .create_discussion_legacy_with_response(team_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a discussion (Legacy).
+     *
+     * This function performs a `POST` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `create_discussion_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion-legacy>
+     */
+    pub async fn create_discussion_legacy_with_response(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions",
@@ -2044,7 +3520,7 @@ impl Teams {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2074,6 +3550,25 @@ impl Teams {
         team_id: i64,
         discussion_number: i64,
     ) -> ClientResult<crate::types::TeamDiscussion> {
+        Ok(self
+            .get_discussion_legacy_with_response(team_id, discussion_number)
+            .await?
+            .body)
+    }
+    /**
+     * Get a discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}` endpoint.
+     *
+     * As opposed to `get_discussion_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-a-discussion-legacy>
+     */
+    pub async fn get_discussion_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}",
@@ -2083,7 +3578,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2113,6 +3608,24 @@ impl Teams {
         team_id: i64,
         discussion_number: i64,
     ) -> ClientResult<()> {
+        self.delete_discussion_legacy_with_response(team_id, discussion_number)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a discussion (Legacy).
+     *
+     * This function performs a `DELETE` to the `/teams/{team_id}/discussions/{discussion_number}` endpoint.
+     *
+     * As opposed to `delete_discussion_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#delete-a-discussion-legacy>
+     */
+    pub async fn delete_discussion_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}",
@@ -2122,7 +3635,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2153,6 +3666,26 @@ impl Teams {
         discussion_number: i64,
         body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
     ) -> ClientResult<crate::types::TeamDiscussion> {
+        Ok(self
+            .update_discussion_legacy_with_response(team_id, discussion_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a discussion (Legacy).
+     *
+     * This function performs a `PATCH` to the `/teams/{team_id}/discussions/{discussion_number}` endpoint.
+     *
+     * As opposed to `update_discussion_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#update-a-discussion-legacy>
+     */
+    pub async fn update_discussion_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussion>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}",
@@ -2162,7 +3695,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2200,6 +3733,34 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::TeamDiscussionComment>> {
+        Ok(self
+            .list_discussion_comments_legacy_with_response(
+                team_id,
+                discussion_number,
+                direction,
+                per_page,
+                page,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List discussion comments (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_discussion_comments_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub async fn list_discussion_comments_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDiscussionComment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !direction.to_string().is_empty() {
             query_args.push(("direction".to_string(), direction.to_string()));
@@ -2221,7 +3782,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2273,6 +3834,96 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List discussion comments (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub fn list_all_discussion_comments_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Order,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::TeamDiscussionComment>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !direction.to_string().is_empty() {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/discussions/{}/comments?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                crate::progenitor_support::encode_path(&discussion_number.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TeamDiscussionComment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List discussion comments (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub fn list_all_discussion_comments_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Order,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDiscussionComment>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_discussion_comments_legacy_pages_stream(
+            team_id,
+            discussion_number,
+            direction,
+            None,
+        ))
+    }
     /**
      * Create a discussion comment (Legacy).
      *
@@ -2297,6 +3948,26 @@ impl Teams {
         discussion_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::TeamDiscussionComment> {
+        Ok(self
+            .create_discussion_comment_legacy_with_response(team_id, discussion_number, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a discussion comment (Legacy).
+     *
+     * This function performs a `POST` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `create_discussion_comment_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion-comment-legacy>
+     */
+    pub async fn create_discussion_comment_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}/comments",
@@ -2306,7 +3977,7 @@ impl Teams {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2324,20 +3995,40 @@ impl Teams {
      *
      * Get a specific comment on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
      *
-     * FROM: <https://docs.github.com/rest/reference/teams#get-a-discussion-comment-legacy>
+     * FROM: <https://docs.github.com/rest/reference/teams#get-a-discussion-comment-legacy>
+     *
+     * **Parameters:**
+     *
+     * * `team_id: i64`
+     * * `discussion_number: i64`
+     * * `comment_number: i64`
+     */
+    pub async fn get_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::types::TeamDiscussionComment> {
+        Ok(self
+            .get_discussion_comment_legacy_with_response(team_id, discussion_number, comment_number)
+            .await?
+            .body)
+    }
+    /**
+     * Get a discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_discussion_comment_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      *
-     * * `team_id: i64`
-     * * `discussion_number: i64`
-     * * `comment_number: i64`
+     * FROM: <https://docs.github.com/rest/reference/teams#get-a-discussion-comment-legacy>
      */
-    pub async fn get_discussion_comment_legacy(
+    pub async fn get_discussion_comment_legacy_with_response(
         &self,
         team_id: i64,
         discussion_number: i64,
         comment_number: i64,
-    ) -> ClientResult<crate::types::TeamDiscussionComment> {
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}/comments/{}",
@@ -2348,7 +4039,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2380,6 +4071,29 @@ impl Teams {
         discussion_number: i64,
         comment_number: i64,
     ) -> ClientResult<()> {
+        self.delete_discussion_comment_legacy_with_response(
+            team_id,
+            discussion_number,
+            comment_number,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete a discussion comment (Legacy).
+     *
+     * This function performs a `DELETE` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
+     *
+     * As opposed to `delete_discussion_comment_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#delete-a-discussion-comment-legacy>
+     */
+    pub async fn delete_discussion_comment_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}/comments/{}",
@@ -2390,7 +4104,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2423,6 +4137,32 @@ impl Teams {
         comment_number: i64,
         body: &crate::types::PullsUpdateReviewRequest,
     ) -> ClientResult<crate::types::TeamDiscussionComment> {
+        Ok(self
+            .update_discussion_comment_legacy_with_response(
+                team_id,
+                discussion_number,
+                comment_number,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Update a discussion comment (Legacy).
+     *
+     * This function performs a `PATCH` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
+     *
+     * As opposed to `update_discussion_comment_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#update-a-discussion-comment-legacy>
+     */
+    pub async fn update_discussion_comment_legacy_with_response(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamDiscussionComment>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/discussions/{}/comments/{}",
@@ -2433,7 +4173,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2465,6 +4205,26 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::OrganizationInvitation>> {
+        Ok(self
+            .list_pending_invitations_legacy_with_response(team_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_pending_invitations_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub async fn list_pending_invitations_legacy_with_response(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrganizationInvitation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2482,7 +4242,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2525,6 +4285,82 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub fn list_all_pending_invitations_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrganizationInvitation>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/invitations",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrganizationInvitation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub fn list_all_pending_invitations_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrganizationInvitation>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_pending_invitations_legacy_pages_stream(team_id, None),
+        )
+    }
     /**
      * List team members (Legacy).
      *
@@ -2553,6 +4389,27 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_members_legacy_with_response(team_id, role, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_members_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub async fn list_members_legacy_with_response(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersInOrgRole,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2573,7 +4430,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2623,6 +4480,86 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_all_members_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub fn list_all_members_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersInOrgRole,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !role.to_string().is_empty() {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/members?{}",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_all_members_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub fn list_all_members_legacy_stream(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_members_legacy_pages_stream(team_id, role, None))
+    }
     /**
      * Get team member (Legacy).
      *
@@ -2642,6 +4579,24 @@ impl Teams {
      * * `username: &str`
      */
     pub async fn get_member_legacy(&self, team_id: i64, username: &str) -> ClientResult<()> {
+        self.get_member_legacy_with_response(team_id, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Get team member (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members/{username}` endpoint.
+     *
+     * As opposed to `get_member_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-team-member-legacy>
+     */
+    pub async fn get_member_legacy_with_response(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/members/{}",
@@ -2651,7 +4606,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2685,6 +4640,24 @@ impl Teams {
      * * `username: &str`
      */
     pub async fn add_member_legacy(&self, team_id: i64, username: &str) -> ClientResult<()> {
+        self.add_member_legacy_with_response(team_id, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add team member (Legacy).
+     *
+     * This function performs a `PUT` to the `/teams/{team_id}/members/{username}` endpoint.
+     *
+     * As opposed to `add_member_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#add-team-member-legacy>
+     */
+    pub async fn add_member_legacy_with_response(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/members/{}",
@@ -2694,7 +4667,7 @@ impl Teams {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2726,6 +4699,24 @@ impl Teams {
      * * `username: &str`
      */
     pub async fn remove_member_legacy(&self, team_id: i64, username: &str) -> ClientResult<()> {
+        self.remove_member_legacy_with_response(team_id, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove team member (Legacy).
+     *
+     * This function performs a `DELETE` to the `/teams/{team_id}/members/{username}` endpoint.
+     *
+     * As opposed to `remove_member_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#remove-team-member-legacy>
+     */
+    pub async fn remove_member_legacy_with_response(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/members/{}",
@@ -2735,7 +4726,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2772,6 +4763,25 @@ impl Teams {
         team_id: i64,
         username: &str,
     ) -> ClientResult<crate::types::TeamMembership> {
+        Ok(self
+            .get_membership_for_user_legacy_with_response(team_id, username)
+            .await?
+            .body)
+    }
+    /**
+     * Get team membership for a user (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/memberships/{username}` endpoint.
+     *
+     * As opposed to `get_membership_for_user_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-team-membership-for-a-user-legacy>
+     */
+    pub async fn get_membership_for_user_legacy_with_response(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/memberships/{}",
@@ -2781,7 +4791,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2820,6 +4830,26 @@ impl Teams {
         username: &str,
         body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
     ) -> ClientResult<crate::types::TeamMembership> {
+        Ok(self
+            .add_or_update_membership_for_user_legacy_with_response(team_id, username, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add or update team membership for a user (Legacy).
+     *
+     * This function performs a `PUT` to the `/teams/{team_id}/memberships/{username}` endpoint.
+     *
+     * As opposed to `add_or_update_membership_for_user_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#add-or-update-team-membership-for-a-user-legacy>
+     */
+    pub async fn add_or_update_membership_for_user_legacy_with_response(
+        &self,
+        team_id: i64,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
+    ) -> ClientResult<crate::Response<crate::types::TeamMembership>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/memberships/{}",
@@ -2829,7 +4859,7 @@ impl Teams {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2863,6 +4893,24 @@ impl Teams {
         team_id: i64,
         username: &str,
     ) -> ClientResult<()> {
+        self.remove_membership_for_user_legacy_with_response(team_id, username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove team membership for a user (Legacy).
+     *
+     * This function performs a `DELETE` to the `/teams/{team_id}/memberships/{username}` endpoint.
+     *
+     * As opposed to `remove_membership_for_user_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#remove-team-membership-for-a-user-legacy>
+     */
+    pub async fn remove_membership_for_user_legacy_with_response(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/memberships/{}",
@@ -2872,7 +4920,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2904,6 +4952,26 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::TeamProject>> {
+        Ok(self
+            .list_projects_legacy_with_response(team_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_projects_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub async fn list_projects_legacy_with_response(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamProject>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -2921,7 +4989,41 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_projects_legacy`, this function returns all the pages of the request at once.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team projects`](https://docs.github.com/rest/reference/teams#list-team-projects) endpoint.
+     *
+     * Lists the organization projects for a team.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub async fn list_all_projects_legacy(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<Vec<crate::types::TeamProject>> {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/projects",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+        self.client
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -2935,18 +5037,17 @@ impl Teams {
      *
      * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
      *
-     * As opposed to `list_projects_legacy`, this function returns all the pages of the request at once.
-     *
-     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team projects`](https://docs.github.com/rest/reference/teams#list-team-projects) endpoint.
-     *
-     * Lists the organization projects for a team.
+     * As opposed to `list_all_projects_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      *
      * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
      */
-    pub async fn list_all_projects_legacy(
+    pub fn list_all_projects_legacy_pages_stream(
         &self,
         team_id: i64,
-    ) -> ClientResult<Vec<crate::types::TeamProject>> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TeamProject>>>
+           + Unpin
+           + '_ {
         let url = self.client.url(
             &format!(
                 "/teams/{}/projects",
@@ -2954,15 +5055,54 @@ impl Teams {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TeamProject>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub fn list_all_projects_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamProject>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_projects_legacy_pages_stream(team_id, None))
     }
     /**
      * Check team permissions for a project (Legacy).
@@ -2985,6 +5125,25 @@ impl Teams {
         team_id: i64,
         project_id: i64,
     ) -> ClientResult<crate::types::TeamProject> {
+        Ok(self
+            .check_permissions_for_project_legacy_with_response(team_id, project_id)
+            .await?
+            .body)
+    }
+    /**
+     * Check team permissions for a project (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects/{project_id}` endpoint.
+     *
+     * As opposed to `check_permissions_for_project_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#check-team-permissions-for-a-project-legacy>
+     */
+    pub async fn check_permissions_for_project_legacy_with_response(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::TeamProject>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/projects/{}",
@@ -2994,7 +5153,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3025,6 +5184,25 @@ impl Teams {
         project_id: i64,
         body: &crate::types::TeamsAddUpdateProjectPermissionsLegacyRequest,
     ) -> ClientResult<()> {
+        self.add_or_update_project_permissions_legacy_with_response(team_id, project_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add or update team project permissions (Legacy).
+     *
+     * This function performs a `PUT` to the `/teams/{team_id}/projects/{project_id}` endpoint.
+     *
+     * As opposed to `add_or_update_project_permissions_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#add-or-update-team-project-permissions-legacy>
+     */
+    pub async fn add_or_update_project_permissions_legacy_with_response(
+        &self,
+        team_id: i64,
+        project_id: i64,
+        body: &crate::types::TeamsAddUpdateProjectPermissionsLegacyRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/projects/{}",
@@ -3034,7 +5212,7 @@ impl Teams {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3060,6 +5238,24 @@ impl Teams {
      * * `project_id: i64`
      */
     pub async fn remove_project_legacy(&self, team_id: i64, project_id: i64) -> ClientResult<()> {
+        self.remove_project_legacy_with_response(team_id, project_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a project from a team (Legacy).
+     *
+     * This function performs a `DELETE` to the `/teams/{team_id}/projects/{project_id}` endpoint.
+     *
+     * As opposed to `remove_project_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#remove-a-project-from-a-team-legacy>
+     */
+    pub async fn remove_project_legacy_with_response(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/projects/{}",
@@ -3069,7 +5265,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3099,6 +5295,26 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::MinimalRepository>> {
+        Ok(self
+            .list_repos_legacy_with_response(team_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_repos_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub async fn list_repos_legacy_with_response(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MinimalRepository>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3116,7 +5332,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3157,6 +5373,79 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub fn list_all_repos_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MinimalRepository>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/repos",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::MinimalRepository>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub fn list_all_repos_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MinimalRepository>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_repos_legacy_pages_stream(team_id, None))
+    }
     /**
      * Check team permissions for a repository (Legacy).
      *
@@ -3182,6 +5471,26 @@ impl Teams {
         owner: &str,
         repo: &str,
     ) -> ClientResult<crate::types::TeamRepository> {
+        Ok(self
+            .check_permissions_for_repo_legacy_with_response(team_id, owner, repo)
+            .await?
+            .body)
+    }
+    /**
+     * Check team permissions for a repository (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `check_permissions_for_repo_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#check-team-permissions-for-a-repository-legacy>
+     */
+    pub async fn check_permissions_for_repo_legacy_with_response(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamRepository>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/repos/{}/{}",
@@ -3192,7 +5501,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3227,6 +5536,26 @@ impl Teams {
         repo: &str,
         body: &crate::types::TeamsAddUpdateRepoPermissionsLegacyRequest,
     ) -> ClientResult<()> {
+        self.add_or_update_repo_permissions_legacy_with_response(team_id, owner, repo, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add or update team repository permissions (Legacy).
+     *
+     * This function performs a `PUT` to the `/teams/{team_id}/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `add_or_update_repo_permissions_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#add-or-update-team-repository-permissions-legacy>
+     */
+    pub async fn add_or_update_repo_permissions_legacy_with_response(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsLegacyRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/repos/{}/{}",
@@ -3237,7 +5566,7 @@ impl Teams {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3269,6 +5598,25 @@ impl Teams {
         owner: &str,
         repo: &str,
     ) -> ClientResult<()> {
+        self.remove_repo_legacy_with_response(team_id, owner, repo)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a repository from a team (Legacy).
+     *
+     * This function performs a `DELETE` to the `/teams/{team_id}/repos/{owner}/{repo}` endpoint.
+     *
+     * As opposed to `remove_repo_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#remove-a-repository-from-a-team-legacy>
+     */
+    pub async fn remove_repo_legacy_with_response(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/repos/{}/{}",
@@ -3279,7 +5627,7 @@ impl Teams {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3309,6 +5657,24 @@ impl Teams {
         &self,
         team_id: i64,
     ) -> ClientResult<crate::types::GroupMapping> {
+        Ok(self
+            .list_idp_groups_for_legacy_with_response(team_id)
+            .await?
+            .body)
+    }
+    /**
+     * List IdP groups for a team (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/team-sync/group-mappings` endpoint.
+     *
+     * As opposed to `list_idp_groups_for_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-idp-groups-for-a-team-legacy>
+     */
+    pub async fn list_idp_groups_for_legacy_with_response(
+        &self,
+        team_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/team-sync/group-mappings",
@@ -3317,7 +5683,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3348,6 +5714,25 @@ impl Teams {
         team_id: i64,
         body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsLegacyRequest,
     ) -> ClientResult<crate::types::GroupMapping> {
+        Ok(self
+            .create_or_update_idp_group_connections_legacy_with_response(team_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create or update IdP group connections (Legacy).
+     *
+     * This function performs a `PATCH` to the `/teams/{team_id}/team-sync/group-mappings` endpoint.
+     *
+     * As opposed to `create_or_update_idp_group_connections_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-or-update-idp-group-connections-legacy>
+     */
+    pub async fn create_or_update_idp_group_connections_legacy_with_response(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsLegacyRequest,
+    ) -> ClientResult<crate::Response<crate::types::GroupMapping>> {
         let url = self.client.url(
             &format!(
                 "/teams/{}/team-sync/group-mappings",
@@ -3356,7 +5741,7 @@ impl Teams {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3386,6 +5771,26 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Team>> {
+        Ok(self
+            .list_child_legacy_with_response(team_id, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_child_legacy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub async fn list_child_legacy_with_response(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Team>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3403,7 +5808,7 @@ impl Teams {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3444,6 +5849,74 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_legacy`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub fn list_all_child_legacy_pages_stream(
+        &self,
+        team_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Team>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/teams/{}/teams",
+                crate::progenitor_support::encode_path(&team_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Team>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_legacy`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub fn list_all_child_legacy_stream(
+        &self,
+        team_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Team>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_child_legacy_pages_stream(team_id, None))
+    }
     /**
      * List teams for the authenticated user.
      *
@@ -3463,6 +5936,25 @@ impl Teams {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::FullTeam>> {
+        Ok(self
+            .list_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub async fn list_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FullTeam>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -3473,7 +5965,7 @@ impl Teams {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/teams?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3507,4 +5999,66 @@ impl Teams {
             )
             .await
     }
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::FullTeam>>> + Unpin + '_
+    {
+        let url = self.client.url("/user/teams", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::FullTeam>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub fn list_all_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FullTeam>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_for_authenticated_user_pages_stream(None))
+    }
 }
@@ -216,6 +216,9 @@ pub struct GitHubApp {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the GitHub app
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -223,7 +226,7 @@ pub struct GitHubApp {
     )]
     pub id: i64,
     /**
-     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     * The number of installations associated with the GitHub app
      */
     #[serde(
         default,
@@ -231,6 +234,9 @@ pub struct GitHubApp {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub installations_count: i64,
+    /**
+     * The name of the GitHub app
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -256,6 +262,9 @@ pub struct GitHubApp {
      */
     #[serde()]
     pub permissions: Permissions,
+    /**
+     * The slug name of the GitHub app
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -314,9 +323,6 @@ pub struct ValidationErrorSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub documentation_url: String,
-    /**
-     * Validation Error Simple
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -394,9 +400,6 @@ pub struct WebhookConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content_type: String,
-    /**
-     * Configuration object of the webhook
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
     #[serde(
@@ -416,65 +419,98 @@ pub struct WebhookConfig {
 /// Delivery made by a webhook, without request and response information.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct HookDeliveryItem {
+    /**
+     * The type of activity for the event that triggered the delivery.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub action: String,
+    /**
+     * Time when the webhook delivery occurred.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Time spent delivering.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub duration: f64,
+    /**
+     * The event that triggered the delivery.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub event: String,
+    /**
+     * Unique identifier for the event (shared with all deliveries for all webhooks that subscribe to this event).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub guid: String,
+    /**
+     * Unique identifier of the webhook delivery.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The id of the GitHub App installation associated with this event.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub installation_id: i64,
+    /**
+     * Whether the webhook delivery is a redelivery.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub redelivery: bool,
+    /**
+     * The id of the repository associated with this event.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub repository_id: i64,
+    /**
+     * Describes the response returned after attempting the delivery.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /**
+     * Status code received when delivery was made.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -504,9 +540,6 @@ pub struct ScimError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
-    /**
-     * Scim Error
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -520,9 +553,6 @@ pub struct ScimError {
         rename = "scimType"
     )]
     pub scim_type: String,
-    /**
-     * Scim Error
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -654,9 +684,6 @@ pub struct ValidationError {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub documentation_url: String,
-    /**
-     * Validation Error
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -682,7 +709,7 @@ pub struct Request {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub headers: Option<Data>,
     /**
-     * The request headers sent with the webhook delivery.
+     * The webhook payload.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payload: Option<Data>,
@@ -691,10 +718,13 @@ pub struct Request {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Response {
     /**
-     * The request headers sent with the webhook delivery.
+     * The response headers received when the delivery was made.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub headers: Option<Data>,
+    /**
+     * The response payload received.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -706,53 +736,80 @@ pub struct Response {
 /// Delivery made by a webhook.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct HookDelivery {
+    /**
+     * The type of activity for the event that triggered the delivery.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub action: String,
+    /**
+     * Time when the delivery was delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub delivered_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Time spent delivering.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub duration: f64,
+    /**
+     * The event that triggered the delivery.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub event: String,
+    /**
+     * Unique identifier for the event (shared with all deliveries for all webhooks that subscribe to this event).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub guid: String,
+    /**
+     * Unique identifier of the delivery.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The id of the GitHub App installation associated with this event.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub installation_id: i64,
+    /**
+     * Whether the delivery is a redelivery.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub redelivery: bool,
+    /**
+     * The id of the repository associated with this event.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -763,12 +820,18 @@ pub struct HookDelivery {
     pub request: Request,
     #[serde()]
     pub response: Response,
+    /**
+     * Description of the status of the attempted delivery
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /**
+     * Status code received when delivery was made.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -792,6 +855,9 @@ pub struct Enterprise {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * A short description of the enterprise.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -804,12 +870,18 @@ pub struct Enterprise {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the enterprise
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the enterprise.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -822,6 +894,9 @@ pub struct Enterprise {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The slug url identifier for the enterprise.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -834,6 +909,9 @@ pub struct Enterprise {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The enterprise's website URL.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -846,6 +924,7 @@ pub struct Enterprise {
 * The level of permission to grant the access token to retrieve Pages statuses, configuration, and builds, as well as create new builds. Can be one of: `read` or `write`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Pages {
     #[serde(rename = "read")]
     Read,
@@ -853,8 +932,9 @@ pub enum Pages {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Pages {
@@ -863,12 +943,32 @@ impl std::fmt::Display for Pages {
             Pages::Read => "read",
             Pages::Write => "write",
             Pages::Noop => "",
-            Pages::FallthroughString => "*",
+            Pages::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Pages {
+    fn from(s: String) -> Pages {
+        match s.as_str() {
+            "read" => Pages::Read,
+            "write" => Pages::Write,
+            "" => Pages::Noop,
+            _ => Pages::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Pages> for String {
+    fn from(e: Pages) -> String {
+        match e {
+            Pages::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Pages {
     fn default() -> Pages {
         Pages::Noop
@@ -884,6 +984,7 @@ impl Pages {
 * The level of permission to grant the access token to manage repository projects, columns, and cards. Can be one of: `read`, `write`, or `admin`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RepositoryProjects {
     #[serde(rename = "admin")]
     Admin,
@@ -893,8 +994,9 @@ pub enum RepositoryProjects {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositoryProjects {
@@ -904,12 +1006,33 @@ impl std::fmt::Display for RepositoryProjects {
             RepositoryProjects::Read => "read",
             RepositoryProjects::Write => "write",
             RepositoryProjects::Noop => "",
-            RepositoryProjects::FallthroughString => "*",
+            RepositoryProjects::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RepositoryProjects {
+    fn from(s: String) -> RepositoryProjects {
+        match s.as_str() {
+            "admin" => RepositoryProjects::Admin,
+            "read" => RepositoryProjects::Read,
+            "write" => RepositoryProjects::Write,
+            "" => RepositoryProjects::Noop,
+            _ => RepositoryProjects::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RepositoryProjects> for String {
+    fn from(e: RepositoryProjects) -> String {
+        match e {
+            RepositoryProjects::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RepositoryProjects {
     fn default() -> RepositoryProjects {
         RepositoryProjects::Noop
@@ -925,13 +1048,15 @@ impl RepositoryProjects {
 * The level of permission to grant the access token for viewing an organization's plan. Can be one of: `read`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrganizationPlan {
     #[serde(rename = "read")]
     Read,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrganizationPlan {
@@ -939,12 +1064,31 @@ impl std::fmt::Display for OrganizationPlan {
         match self {
             OrganizationPlan::Read => "read",
             OrganizationPlan::Noop => "",
-            OrganizationPlan::FallthroughString => "*",
+            OrganizationPlan::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrganizationPlan {
+    fn from(s: String) -> OrganizationPlan {
+        match s.as_str() {
+            "read" => OrganizationPlan::Read,
+            "" => OrganizationPlan::Noop,
+            _ => OrganizationPlan::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrganizationPlan> for String {
+    fn from(e: OrganizationPlan) -> String {
+        match e {
+            OrganizationPlan::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrganizationPlan {
     fn default() -> OrganizationPlan {
         OrganizationPlan::Noop
@@ -960,13 +1104,15 @@ impl OrganizationPlan {
 * The level of permission to grant the access token to update GitHub Actions workflow files. Can be one of: `write`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Workflows {
     #[serde(rename = "write")]
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Workflows {
@@ -974,12 +1120,31 @@ impl std::fmt::Display for Workflows {
         match self {
             Workflows::Write => "write",
             Workflows::Noop => "",
-            Workflows::FallthroughString => "*",
+            Workflows::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Workflows {
+    fn from(s: String) -> Workflows {
+        match s.as_str() {
+            "write" => Workflows::Write,
+            "" => Workflows::Noop,
+            _ => Workflows::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Workflows> for String {
+    fn from(e: Workflows) -> String {
+        match e {
+            Workflows::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Workflows {
     fn default() -> Workflows {
         Workflows::Noop
@@ -995,157 +1160,157 @@ impl Workflows {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppPermissions {
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for GitHub Actions workflows, workflow runs, and artifacts. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actions: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for repository creation, deletion, settings, teams, and collaborators creation. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub administration: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for checks on code. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub checks: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for notification of content references and creation content attachments. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content_references: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for repository contents, commits, branches, downloads, releases, and merges. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contents: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for deployments and deployment statuses. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deployments: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for managing repository environments. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub environments: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for issues and related comments, assignees, labels, and milestones. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub issues: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for organization teams and members. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to search repositories, list collaborators, and access repository metadata. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage access to an organization. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_administration: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage the post-receive hooks for an organization. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_hooks: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for organization packages published to GitHub Packages. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_packages: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for viewing an organization's plan. Can be one of: `read`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_plan: Option<OrganizationPlan>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage organization projects, columns, and cards. Can be one of: `read`, `write`, or `admin`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_projects: Option<RepositoryProjects>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage organization secrets. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_secrets: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to view and manage GitHub Actions self-hosted runners available to an organization. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_self_hosted_runners: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to view and manage users blocked by the organization. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_user_blocking: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for packages published to GitHub Packages. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub packages: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to retrieve Pages statuses, configuration, and builds, as well as create new builds. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pages: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for pull requests and related comments, assignees, labels, milestones, and merges. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pull_requests: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage the post-receive hooks for a repository. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository_hooks: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage repository projects, columns, and cards. Can be one of: `read`, `write`, or `admin`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository_projects: Option<RepositoryProjects>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to view and manage secret scanning alerts. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub secret_scanning_alerts: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage repository secrets. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub secrets: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to view and manage security events like code scanning alerts. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub security_events: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage just a single file. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub single_file: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token for commit statuses. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub statuses: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to manage team discussions and related comments. Can be one of: `read` or `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub team_discussions: Option<Pages>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to retrieve Dependabot alerts. Can be one of: `read`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vulnerability_alerts: Option<OrganizationPlan>,
     /**
-     * The permissions granted to the user-to-server access token.
+     * The level of permission to grant the access token to update GitHub Actions workflow files. Can be one of: `write`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub workflows: Option<Workflows>,
@@ -1174,6 +1339,7 @@ pub struct AccountAnyOf {
 * Describe whether all repositories have been selected or there's a selection involved
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RepositorySelection {
     #[serde(rename = "all")]
     All,
@@ -1181,8 +1347,9 @@ pub enum RepositorySelection {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositorySelection {
@@ -1191,12 +1358,32 @@ impl std::fmt::Display for RepositorySelection {
             RepositorySelection::All => "all",
             RepositorySelection::Selected => "selected",
             RepositorySelection::Noop => "",
-            RepositorySelection::FallthroughString => "*",
+            RepositorySelection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RepositorySelection {
+    fn from(s: String) -> RepositorySelection {
+        match s.as_str() {
+            "all" => RepositorySelection::All,
+            "selected" => RepositorySelection::Selected,
+            "" => RepositorySelection::Noop,
+            _ => RepositorySelection::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RepositorySelection> for String {
+    fn from(e: RepositorySelection) -> String {
+        match e {
+            RepositorySelection::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RepositorySelection {
     fn default() -> RepositorySelection {
         RepositorySelection::Noop
@@ -1243,18 +1430,12 @@ pub struct Installation {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub events: Vec<String>,
-    /**
-     * Installation
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -1266,6 +1447,9 @@ pub struct Installation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The ID of the installation.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1294,9 +1478,6 @@ pub struct Installation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub single_file_name: String,
-    /**
-     * Installation
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -1311,6 +1492,9 @@ pub struct Installation {
     pub suspended_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub suspended_by: Option<SimpleUser>,
+    /**
+     * The ID of the user or organization this token is being scoped to.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -2003,7 +2187,7 @@ pub struct TemplateRepository {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Repository {
     /**
-     * A git repository
+     * Whether to allow Auto-merge to be used on pull requests.
      */
     #[serde(
         default,
@@ -2011,7 +2195,7 @@ pub struct Repository {
     )]
     pub allow_auto_merge: bool,
     /**
-     * A git repository
+     * Whether to allow merge commits for pull requests.
      */
     #[serde(
         default,
@@ -2019,7 +2203,7 @@ pub struct Repository {
     )]
     pub allow_merge_commit: bool,
     /**
-     * A git repository
+     * Whether to allow rebase merges for pull requests.
      */
     #[serde(
         default,
@@ -2027,7 +2211,7 @@ pub struct Repository {
     )]
     pub allow_rebase_merge: bool,
     /**
-     * A git repository
+     * Whether to allow squash merges for pull requests.
      */
     #[serde(
         default,
@@ -2040,6 +2224,9 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub archive_url: String,
+    /**
+     * Whether the repository is archived.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2111,6 +2298,9 @@ pub struct Repository {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The default branch of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2118,7 +2308,7 @@ pub struct Repository {
     )]
     pub default_branch: String,
     /**
-     * A git repository
+     * Whether to delete head branches when pull requests are merged
      */
     #[serde(
         default,
@@ -2137,6 +2327,9 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Returns whether or not this repository disabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2207,11 +2400,17 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub git_url: String,
+    /**
+     * Whether downloads are enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_downloads: bool,
+    /**
+     * Whether issues are enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2222,11 +2421,17 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_pages: bool,
+    /**
+     * Whether projects are enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_projects: bool,
+    /**
+     * Whether the wiki is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2250,6 +2455,9 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the repository
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -2257,7 +2465,7 @@ pub struct Repository {
     )]
     pub id: i64,
     /**
-     * A git repository
+     * Whether this repository acts as a template that can be used to generate new repositories.
      */
     #[serde(
         default,
@@ -2332,15 +2540,15 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mirror_url: String,
+    /**
+     * The name of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * A git repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -2378,11 +2586,11 @@ pub struct Repository {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<SimpleUser>,
-    /**
-     * A git repository
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<RepositoryPermissions>,
+    /**
+     * Whether the repository is private or public.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2442,9 +2650,6 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statuses_url: String,
-    /**
-     * A git repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -2489,9 +2694,6 @@ pub struct Repository {
     pub temp_clone_token: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_repository: Option<TemplateRepository>,
-    /**
-     * A git repository
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2516,6 +2718,9 @@ pub struct Repository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * The repository visibility: public, private, or internal.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2545,31 +2750,22 @@ pub struct InstallationToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub expires_at: String,
-    /**
-     * Authentication token for a GitHub App installed on a user or org.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_multiple_single_files: bool,
     /**
-     * Authentication token for a GitHub App installed on a user or org.
+     * The permissions granted to the user-to-server access token.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<AppPermissions>,
-    /**
-     * Authentication token for a GitHub App installed on a user or org.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub repositories: Vec<Repository>,
-    /**
-     * Authentication token for a GitHub App installed on a user or org.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository_selection: Option<RepositorySelection>,
     #[serde(
@@ -2578,9 +2774,6 @@ pub struct InstallationToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub single_file: String,
-    /**
-     * Authentication token for a GitHub App installed on a user or org.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2634,9 +2827,6 @@ pub struct ApplicationGrant {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2745,7 +2935,7 @@ pub struct Authorization {
     )]
     pub note_url: String,
     /**
-     * The list of events for the GitHub app
+     * A list of scopes that this authorization is in.
      */
     #[serde(
         default,
@@ -2820,6 +3010,7 @@ pub struct CodeOfConduct {
 * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EnabledRepositories {
     #[serde(rename = "all")]
     All,
@@ -2829,8 +3020,9 @@ pub enum EnabledRepositories {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnabledRepositories {
@@ -2840,12 +3032,33 @@ impl std::fmt::Display for EnabledRepositories {
             EnabledRepositories::None => "none",
             EnabledRepositories::Selected => "selected",
             EnabledRepositories::Noop => "",
-            EnabledRepositories::FallthroughString => "*",
+            EnabledRepositories::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EnabledRepositories {
+    fn from(s: String) -> EnabledRepositories {
+        match s.as_str() {
+            "all" => EnabledRepositories::All,
+            "none" => EnabledRepositories::None,
+            "selected" => EnabledRepositories::Selected,
+            "" => EnabledRepositories::Noop,
+            _ => EnabledRepositories::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EnabledRepositories> for String {
+    fn from(e: EnabledRepositories) -> String {
+        match e {
+            EnabledRepositories::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EnabledRepositories {
     fn default() -> EnabledRepositories {
         EnabledRepositories::Noop
@@ -2861,6 +3074,7 @@ impl EnabledRepositories {
 * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AllowedActions {
     #[serde(rename = "all")]
     All,
@@ -2870,8 +3084,9 @@ pub enum AllowedActions {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AllowedActions {
@@ -2881,12 +3096,33 @@ impl std::fmt::Display for AllowedActions {
             AllowedActions::LocalOnly => "local_only",
             AllowedActions::Selected => "selected",
             AllowedActions::Noop => "",
-            AllowedActions::FallthroughString => "*",
+            AllowedActions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AllowedActions {
+    fn from(s: String) -> AllowedActions {
+        match s.as_str() {
+            "all" => AllowedActions::All,
+            "local_only" => AllowedActions::LocalOnly,
+            "selected" => AllowedActions::Selected,
+            "" => AllowedActions::Noop,
+            _ => AllowedActions::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AllowedActions> for String {
+    fn from(e: AllowedActions) -> String {
+        match e {
+            AllowedActions::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AllowedActions {
     fn default() -> AllowedActions {
         AllowedActions::Noop
@@ -2916,6 +3152,9 @@ pub struct ActionsEnterprisePermissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub selected_actions_url: String,
+    /**
+     * The API URL to use to get or set the selected organizations that are allowed to run GitHub Actions, when `enabled_organizations` is set to `selected`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3003,13 +3242,16 @@ pub struct OrganizationSimple {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SelectedActions {
+    /**
+     * Whether GitHub-owned actions are allowed. For example, this includes the actions in the `actions` organization.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub github_owned_allowed: bool,
     /**
-     * The list of events for the GitHub app
+     * Specifies a list of string-matching patterns to allow specific action(s). Wildcards, tags, and SHAs are allowed. For example, `monalisa/octocat@\*`, `monalisa/octocat@v2`, `monalisa/\*`."
      */
     #[serde(
         default,
@@ -3017,6 +3259,9 @@ pub struct SelectedActions {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub patterns_allowed: Vec<String>,
+    /**
+     * Whether actions in GitHub Marketplace from verified creators are allowed. Set to `true` to allow all GitHub Marketplace actions by verified creators.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -3072,6 +3317,7 @@ pub struct RunnerGroupsEnterprise {
 * The type of label. Read-only labels are applied automatically when the runner is configured.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "custom")]
     Custom,
@@ -3079,8 +3325,9 @@ pub enum Type {
     ReadOnly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -3089,12 +3336,32 @@ impl std::fmt::Display for Type {
             Type::Custom => "custom",
             Type::ReadOnly => "read-only",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "custom" => Type::Custom,
+            "read-only" => Type::ReadOnly,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -3108,12 +3375,18 @@ impl Type {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Labels {
+    /**
+     * Unique identifier of the label.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * Name of the label.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3135,6 +3408,9 @@ pub struct Runner {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub busy: bool,
+    /**
+     * The id of the runner.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3147,18 +3423,27 @@ pub struct Runner {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub labels: Vec<Labels>,
+    /**
+     * The name of the runner.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The Operating System of the runner.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub os: String,
+    /**
+     * The status of the runner.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3201,6 +3486,9 @@ pub struct RunnerApplication {
         rename = "sha256_checksum"
     )]
     pub sha_256_checksum: String,
+    /**
+     * A short lived bearer token used to download the runner, if needed.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3212,19 +3500,19 @@ pub struct RunnerApplication {
 /// Authentication Token
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthenticationToken {
+    /**
+     * The time this token expires
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The request headers sent with the webhook delivery.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<Data>,
     /**
-     * Authentication Token
+     * The repositories this token has access to
      */
     #[serde(
         default,
@@ -3233,7 +3521,7 @@ pub struct AuthenticationToken {
     )]
     pub repositories: Vec<Repository>,
     /**
-     * Authentication Token
+     * Describe whether all repositories have been selected or there's a selection involved
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository_selection: Option<RepositorySelection>,
@@ -3243,6 +3531,9 @@ pub struct AuthenticationToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub single_file: String,
+    /**
+     * The token used for authentication
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3263,6 +3554,9 @@ pub struct ActorLocation {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuditLogEvent {
+    /**
+     * The time the audit log event occurred, given as a [Unix timestamp](http://en.wikipedia.org/wiki/Unix_time).
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3270,6 +3564,9 @@ pub struct AuditLogEvent {
         rename = "@timestamp"
     )]
     pub timestamp: i64,
+    /**
+     * A unique identifier for an audit event.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3277,6 +3574,9 @@ pub struct AuditLogEvent {
         rename = "_document_id"
     )]
     pub document_id: String,
+    /**
+     * The name of the action that was performed, for example `user.login` or `repo.create`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3293,12 +3593,18 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub active_was: bool,
+    /**
+     * The actor who performed the action.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub actor: String,
+    /**
+     * The id of the actor who performed the action.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3307,6 +3613,9 @@ pub struct AuditLogEvent {
     pub actor_id: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actor_location: Option<ActorLocation>,
+    /**
+     * The username of the account being blocked.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3319,18 +3628,12 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub business: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub config: Vec<String>,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -3343,6 +3646,9 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content_type: String,
+    /**
+     * The time the audit log event was recorded, given as a [Unix timestamp](http://en.wikipedia.org/wiki/Unix_time).
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3363,18 +3669,12 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub emoji: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub events: Vec<String>,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -3451,12 +3751,18 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub read_only: bool,
+    /**
+     * The name of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub repo: String,
+    /**
+     * The name of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3480,24 +3786,36 @@ pub struct AuditLogEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team: String,
+    /**
+     * The type of protocol (for example, HTTP or SSH) used to transfer Git data.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub transport_protocol: i64,
+    /**
+     * A human readable name for the protocol (for example, HTTP or SSH) used to transfer Git data.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub transport_protocol_name: String,
+    /**
+     * The user that was affected by the action performed (if available).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user: String,
+    /**
+     * The repository visibility, for example `public` or `private`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3508,6 +3826,9 @@ pub struct AuditLogEvent {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MinutesUsedBreakdown {
+    /**
+     * Total minutes used on macOS runner machines.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3515,6 +3836,9 @@ pub struct MinutesUsedBreakdown {
         rename = "MACOS"
     )]
     pub macos: i64,
+    /**
+     * Total minutes used on Ubuntu runner machines.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3522,6 +3846,9 @@ pub struct MinutesUsedBreakdown {
         rename = "UBUNTU"
     )]
     pub ubuntu: i64,
+    /**
+     * Total minutes used on Windows runner machines.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3533,6 +3860,9 @@ pub struct MinutesUsedBreakdown {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsBillingUsage {
+    /**
+     * The amount of free GitHub Actions minutes available.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3541,12 +3871,18 @@ pub struct ActionsBillingUsage {
     pub included_minutes: i64,
     #[serde()]
     pub minutes_used_breakdown: MinutesUsedBreakdown,
+    /**
+     * The sum of the free and paid GitHub Actions minutes used.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_minutes_used: i64,
+    /**
+     * The total paid GitHub Actions minutes used.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3557,18 +3893,27 @@ pub struct ActionsBillingUsage {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PackagesBillingUsage {
+    /**
+     * Free storage space (GB) for GitHub Packages.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub included_gigabytes_bandwidth: i64,
+    /**
+     * Sum of the free and paid storage space (GB) for GitHuub Packages.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_gigabytes_bandwidth_used: i64,
+    /**
+     * Total paid storage space (GB) for GitHuub Packages.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3579,18 +3924,27 @@ pub struct PackagesBillingUsage {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CombinedBillingUsage {
+    /**
+     * Numbers of days left in billing cycle.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub days_left_in_billing_cycle: i64,
+    /**
+     * Estimated storage space (GB) used in billing cycle.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub estimated_paid_storage_for_month: i64,
+    /**
+     * Estimated sum of free and paid storage space (GB) used in billing cycle.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3643,6 +3997,9 @@ pub struct Actor {
 /// Color-coded labels help you categorize and filter your issues (just like labels in Gmail).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Label {
+    /**
+     * 6-character hex code, without the leading #, identifying the color
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3666,6 +4023,9 @@ pub struct Label {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the label.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3678,6 +4038,9 @@ pub struct Label {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * URL for the label
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3690,13 +4053,15 @@ pub struct Label {
 * The state of the milestone.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum State {
     #[serde(rename = "closed")]
     Closed,
     #[serde(rename = "open")]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -3704,12 +4069,31 @@ impl std::fmt::Display for State {
         match self {
             State::Closed => "closed",
             State::Open => "open",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for State {
+    fn from(s: String) -> State {
+        match s.as_str() {
+            "closed" => State::Closed,
+            "open" => State::Open,
+            _ => State::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<State> for String {
+    fn from(e: State) -> String {
+        match e {
+            State::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Open
@@ -3775,6 +4159,9 @@ pub struct Milestone {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The number of the milestone.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3792,6 +4179,9 @@ pub struct Milestone {
      */
     #[serde(default)]
     pub state: State,
+    /**
+     * The title of the milestone.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3816,6 +4206,7 @@ pub struct Milestone {
 * How the author is associated with the repository.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AuthorAssociation {
     #[serde(rename = "COLLABORATOR")]
     Collaborator,
@@ -3835,8 +4226,9 @@ pub enum AuthorAssociation {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorAssociation {
@@ -3851,12 +4243,38 @@ impl std::fmt::Display for AuthorAssociation {
             AuthorAssociation::None => "NONE",
             AuthorAssociation::Owner => "OWNER",
             AuthorAssociation::Noop => "",
-            AuthorAssociation::FallthroughString => "*",
+            AuthorAssociation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AuthorAssociation {
+    fn from(s: String) -> AuthorAssociation {
+        match s.as_str() {
+            "COLLABORATOR" => AuthorAssociation::Collaborator,
+            "CONTRIBUTOR" => AuthorAssociation::Contributor,
+            "FIRST_TIMER" => AuthorAssociation::FirstTimer,
+            "FIRST_TIME_CONTRIBUTOR" => AuthorAssociation::FirstTimeContributor,
+            "MANNEQUIN" => AuthorAssociation::Mannequin,
+            "MEMBER" => AuthorAssociation::Member,
+            "NONE" => AuthorAssociation::None,
+            "OWNER" => AuthorAssociation::Owner,
+            "" => AuthorAssociation::Noop,
+            _ => AuthorAssociation::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AuthorAssociation> for String {
+    fn from(e: AuthorAssociation) -> String {
+        match e {
+            AuthorAssociation::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AuthorAssociation {
     fn default() -> AuthorAssociation {
         AuthorAssociation::Noop
@@ -4017,13 +4435,10 @@ pub struct IssueSimple {
     pub number: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Issue Simple
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pull_request: Option<PullRequest>,
     /**
-     * Issue Simple
+     * A git repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository: Option<Repository>,
@@ -4141,6 +4556,9 @@ pub struct IssueComment {
      */
     #[serde()]
     pub author_association: AuthorAssociation,
+    /**
+     * Contents of the issue comment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4171,6 +4589,9 @@ pub struct IssueComment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the issue comment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4191,9 +4612,6 @@ pub struct IssueComment {
     pub node_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Comments provide a way for people to collaborate on an issue.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     #[serde(
@@ -4202,6 +4620,9 @@ pub struct IssueComment {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * URL for the issue comment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4321,7 +4742,7 @@ pub struct Event {
     )]
     pub id: String,
     /**
-     * Event
+     * Actor
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub org: Option<Actor>,
@@ -4423,9 +4844,6 @@ pub struct Feed {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub current_user_organization_url: String,
-    /**
-     * Feed
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4534,9 +4952,6 @@ pub struct BaseGist {
     pub description: String,
     #[serde()]
     pub files: Files,
-    /**
-     * Base Gist
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4561,9 +4976,6 @@ pub struct BaseGist {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub git_push_url: String,
-    /**
-     * Base Gist
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4595,9 +5007,6 @@ pub struct BaseGist {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub public: bool,
-    /**
-     * Base Gist
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -4668,9 +5077,6 @@ pub struct PublicUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub blog: String,
-    /**
-     * Public User
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4689,9 +5095,6 @@ pub struct PublicUser {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Public User
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4793,23 +5196,14 @@ pub struct PublicUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organizations_url: String,
-    /**
-     * Public User
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub owned_private_repos: i64,
-    /**
-     * Public User
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan: Option<Plan>,
-    /**
-     * Public User
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4857,18 +5251,12 @@ pub struct PublicUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subscriptions_url: String,
-    /**
-     * Public User
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub suspended_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Public User
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4927,14 +5315,8 @@ pub struct Stats {
 /// Gist History
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GistHistory {
-    /**
-     * Gist History
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub change_status: Option<Stats>,
-    /**
-     * Gist History
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -5028,9 +5410,6 @@ pub struct Gist {
     pub description: String,
     #[serde()]
     pub files: Files,
-    /**
-     * Gist
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5055,9 +5434,6 @@ pub struct Gist {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub git_push_url: String,
-    /**
-     * Gist
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5089,9 +5465,6 @@ pub struct Gist {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub public: bool,
-    /**
-     * Gist
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -5162,9 +5535,6 @@ pub struct FilesAdditionalProperties {
 /// Gist Simple
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GistSimple {
-    /**
-     * Gist Simple
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5255,17 +5625,11 @@ pub struct GistSimple {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<SimpleUser>,
-    /**
-     * Gist Simple
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub public: bool,
-    /**
-     * Gist Simple
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -5299,6 +5663,9 @@ pub struct GistComment {
      */
     #[serde()]
     pub author_association: AuthorAssociation,
+    /**
+     * The comment text.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5492,6 +5859,9 @@ pub struct Issue {
      */
     #[serde()]
     pub author_association: AuthorAssociation,
+    /**
+     * Contents of the issue
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5582,6 +5952,9 @@ pub struct Issue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * Number uniquely identifying the issue within its repository
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5590,18 +5963,12 @@ pub struct Issue {
     pub number: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pull_request: Option<PullRequest>,
-    /**
-     * Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     /**
-     * Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
+     * A git repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository: Option<Repository>,
@@ -5611,6 +5978,9 @@ pub struct Issue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub repository_url: String,
+    /**
+     * State of the issue; either 'open' or 'closed'
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5623,6 +5993,9 @@ pub struct Issue {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub timeline_url: String,
+    /**
+     * Title of the issue
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5635,6 +6008,9 @@ pub struct Issue {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * URL for the issue
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5654,9 +6030,6 @@ pub struct LicenseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5692,9 +6065,6 @@ pub struct LicenseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5713,9 +6083,6 @@ pub struct LicenseData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5745,9 +6112,6 @@ pub struct MarketplaceListingPlan {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub accounts_url: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5840,6 +6204,9 @@ pub struct MarketplacePendingChange {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_installed: bool,
+    /**
+     * Marketplace Listing Plan
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan: Option<MarketplaceListingPlan>,
     #[serde(
@@ -5966,81 +6333,54 @@ pub struct SshKeyFingerprints {
 /// Api Overview
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApiOverview {
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub actions: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub api: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub dependabot: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub git: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub hooks: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub importer: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub packages: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pages: Vec<String>,
-    /**
-     * Api Overview
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ssh_key_fingerprints: Option<SshKeyFingerprints>,
     #[serde(
@@ -6048,9 +6388,6 @@ pub struct ApiOverview {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub verifiable_password_authentication: bool,
-    /**
-     * Api Overview
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -6131,9 +6468,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub archive_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -6164,7 +6498,7 @@ pub struct MinimalRepository {
     )]
     pub clone_url: String,
     /**
-     * Minimal Repository
+     * Code Of Conduct
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub code_of_conduct: Option<CodeOfConduct>,
@@ -6204,9 +6538,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub contributors_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -6219,9 +6550,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub default_branch: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -6239,9 +6567,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -6264,18 +6589,12 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub fork: bool,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub forks: i64,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6318,41 +6637,26 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub git_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_downloads: bool,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_issues: bool,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_pages: bool,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_projects: bool,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -6382,9 +6686,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -6458,9 +6759,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6479,18 +6777,12 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub notifications_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub open_issues: i64,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6502,9 +6794,6 @@ pub struct MinimalRepository {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<SimpleUser>,
-    /**
-     * Minimal Repository
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<MinimalRepositoryPermissions>,
     #[serde(
@@ -6518,9 +6807,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pulls_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -6533,9 +6819,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub releases_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6548,9 +6831,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ssh_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6569,9 +6849,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statuses_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6614,14 +6891,8 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub temp_clone_token: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_repository: Option<Data>,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -6634,9 +6905,6 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub trees_url: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -6655,18 +6923,12 @@ pub struct MinimalRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub visibility: String,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub watchers: i64,
-    /**
-     * Minimal Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6857,9 +7119,6 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub blog: String,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6890,9 +7149,6 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6951,9 +7207,6 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -6983,57 +7236,36 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub members_allowed_repository_creation_type: String,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub members_can_create_internal_repositories: bool,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub members_can_create_pages: bool,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub members_can_create_private_pages: bool,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub members_can_create_private_repositories: bool,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub members_can_create_public_pages: bool,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub members_can_create_public_repositories: bool,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -7057,23 +7289,14 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub owned_private_repos: i64,
-    /**
-     * Organization Full
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan: Option<OrganizationFullPlan>,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -7104,9 +7327,6 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub repos_url: String,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -7119,9 +7339,6 @@ pub struct OrganizationFull {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub twitter_username: String,
-    /**
-     * Organization Full
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -7166,6 +7383,9 @@ pub struct ActionsOrganizationPermissions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub selected_actions_url: String,
+    /**
+     * The API URL to use to get or set the selected repositories that are allowed to run GitHub Actions, when `enabled_repositories` is set to `selected`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7214,6 +7434,9 @@ pub struct RunnerGroupsOrg {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub runners_url: String,
+    /**
+     * Link to the selected repositories resource for this runner group. Not present unless visibility was set to `selected`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7232,6 +7455,7 @@ pub struct RunnerGroupsOrg {
 * Visibility of a secret
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Visibility {
     #[serde(rename = "all")]
     All,
@@ -7241,8 +7465,9 @@ pub enum Visibility {
     Selected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -7252,12 +7477,33 @@ impl std::fmt::Display for Visibility {
             Visibility::Private => "private",
             Visibility::Selected => "selected",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Visibility {
+    fn from(s: String) -> Visibility {
+        match s.as_str() {
+            "all" => Visibility::All,
+            "private" => Visibility::Private,
+            "selected" => Visibility::Selected,
+            "" => Visibility::Noop,
+            _ => Visibility::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Visibility> for String {
+    fn from(e: Visibility) -> String {
+        match e {
+            Visibility::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -7278,6 +7524,9 @@ pub struct OrganizationActionsSecret {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The name of the secret.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7312,21 +7561,24 @@ pub struct ActionsPublicKey {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub created_at: String,
-    /**
-     * The public key used for setting Actions Secrets.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The Base64 encoded public key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key: String,
+    /**
+     * The identifier for the key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7350,21 +7602,24 @@ pub struct ActionsPublicKey {
 /// Credential Authorization
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CredentialAuthorization {
-    /**
-     * Credential Authorization
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub authorized_credential_id: i64,
+    /**
+     * The note given to the token. This will only be present when the credential is a token.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub authorized_credential_note: String,
+    /**
+     * The title given to the ssh key. This will only be present when the credential is an ssh key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7372,7 +7627,7 @@ pub struct CredentialAuthorization {
     )]
     pub authorized_credential_title: String,
     /**
-     * Credential Authorization
+     * Date when the credential was last accessed. May be null if it was never accessed
      */
     #[serde(
         default,
@@ -7380,30 +7635,45 @@ pub struct CredentialAuthorization {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub credential_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Date when the credential was authorized for use.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub credential_authorized_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Unique identifier for the credential.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub credential_id: i64,
+    /**
+     * Human-readable description of the credential type.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub credential_type: String,
+    /**
+     * Unique string to distinguish the credential. Only included in responses with credential_type of SSH Key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fingerprint: String,
+    /**
+     * User login that owns the underlying credential.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7411,7 +7681,7 @@ pub struct CredentialAuthorization {
     )]
     pub login: String,
     /**
-     * Credential Authorization
+     * List of oauth scopes the token has been granted.
      */
     #[serde(
         default,
@@ -7419,6 +7689,9 @@ pub struct CredentialAuthorization {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub scopes: Vec<String>,
+    /**
+     * Last eight characters of the credential. Only included in responses with credential_type of personal access token.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7547,9 +7820,6 @@ pub struct OrgHook {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub deliveries_url: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -7599,6 +7869,7 @@ pub struct OrgHook {
 * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InteractionGroup {
     #[serde(rename = "collaborators_only")]
     CollaboratorsOnly,
@@ -7608,8 +7879,9 @@ pub enum InteractionGroup {
     ExistingUsers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InteractionGroup {
@@ -7619,12 +7891,33 @@ impl std::fmt::Display for InteractionGroup {
             InteractionGroup::ContributorsOnly => "contributors_only",
             InteractionGroup::ExistingUsers => "existing_users",
             InteractionGroup::Noop => "",
-            InteractionGroup::FallthroughString => "*",
+            InteractionGroup::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InteractionGroup {
+    fn from(s: String) -> InteractionGroup {
+        match s.as_str() {
+            "collaborators_only" => InteractionGroup::CollaboratorsOnly,
+            "contributors_only" => InteractionGroup::ContributorsOnly,
+            "existing_users" => InteractionGroup::ExistingUsers,
+            "" => InteractionGroup::Noop,
+            _ => InteractionGroup::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InteractionGroup> for String {
+    fn from(e: InteractionGroup) -> String {
+        match e {
+            InteractionGroup::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InteractionGroup {
     fn default() -> InteractionGroup {
         InteractionGroup::Noop
@@ -7662,6 +7955,7 @@ pub struct InteractionLimits {
 * The duration of the interaction restriction. Can be one of: `one_day`, `three_days`, `one_week`, `one_month`, `six_months`. Default: `one_day`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InteractionExpiry {
     #[serde(rename = "one_day")]
     OneDay,
@@ -7675,8 +7969,9 @@ pub enum InteractionExpiry {
     ThreeDays,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InteractionExpiry {
@@ -7688,12 +7983,35 @@ impl std::fmt::Display for InteractionExpiry {
             InteractionExpiry::SixMonths => "six_months",
             InteractionExpiry::ThreeDays => "three_days",
             InteractionExpiry::Noop => "",
-            InteractionExpiry::FallthroughString => "*",
+            InteractionExpiry::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InteractionExpiry {
+    fn from(s: String) -> InteractionExpiry {
+        match s.as_str() {
+            "one_day" => InteractionExpiry::OneDay,
+            "one_month" => InteractionExpiry::OneMonth,
+            "one_week" => InteractionExpiry::OneWeek,
+            "six_months" => InteractionExpiry::SixMonths,
+            "three_days" => InteractionExpiry::ThreeDays,
+            "" => InteractionExpiry::Noop,
+            _ => InteractionExpiry::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InteractionExpiry> for String {
+    fn from(e: InteractionExpiry) -> String {
+        match e {
+            InteractionExpiry::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InteractionExpiry {
     fn default() -> InteractionExpiry {
         InteractionExpiry::Noop
@@ -7709,7 +8027,7 @@ impl InteractionExpiry {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InteractionLimit {
     /**
-     * Limit interactions to a specific type of user for a specified duration
+     * The duration of the interaction restriction. Can be one of: `one_day`, `three_days`, `one_week`, `one_month`, `six_months`. Default: `one_day`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub expiry: Option<InteractionExpiry>,
@@ -7723,6 +8041,9 @@ pub struct InteractionLimit {
 /// Groups of organization members that gives permissions on specified repositories.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamSimple {
+    /**
+     * Description of the team
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7735,12 +8056,18 @@ pub struct TeamSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the team
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * Distinguished Name (DN) that team maps to within LDAP environment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7753,6 +8080,9 @@ pub struct TeamSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub members_url: String,
+    /**
+     * Name of the team
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7765,12 +8095,18 @@ pub struct TeamSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * Permission that the team will have for its repositories
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub permission: String,
+    /**
+     * The level of privacy this team should have
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7789,6 +8125,9 @@ pub struct TeamSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub slug: String,
+    /**
+     * URL for the team
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7873,9 +8212,6 @@ pub struct Team {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub permission: String,
-    /**
-     * Groups of organization members that gives permissions on specified repositories.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<TeamPermissions>,
     #[serde(
@@ -7908,6 +8244,7 @@ pub struct Team {
 * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrgMembershipState {
     #[serde(rename = "active")]
     Active,
@@ -7915,8 +8252,9 @@ pub enum OrgMembershipState {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgMembershipState {
@@ -7925,12 +8263,32 @@ impl std::fmt::Display for OrgMembershipState {
             OrgMembershipState::Active => "active",
             OrgMembershipState::Pending => "pending",
             OrgMembershipState::Noop => "",
-            OrgMembershipState::FallthroughString => "*",
+            OrgMembershipState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrgMembershipState {
+    fn from(s: String) -> OrgMembershipState {
+        match s.as_str() {
+            "active" => OrgMembershipState::Active,
+            "pending" => OrgMembershipState::Pending,
+            "" => OrgMembershipState::Noop,
+            _ => OrgMembershipState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrgMembershipState> for String {
+    fn from(e: OrgMembershipState) -> String {
+        match e {
+            OrgMembershipState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrgMembershipState {
     fn default() -> OrgMembershipState {
         OrgMembershipState::Noop
@@ -7946,6 +8304,7 @@ impl OrgMembershipState {
 * The user's membership type in the organization.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -7955,8 +8314,9 @@ pub enum Role {
     Member,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -7966,12 +8326,33 @@ impl std::fmt::Display for Role {
             Role::BillingManager => "billing_manager",
             Role::Member => "member",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Role {
+    fn from(s: String) -> Role {
+        match s.as_str() {
+            "admin" => Role::Admin,
+            "billing_manager" => Role::BillingManager,
+            "member" => Role::Member,
+            "" => Role::Noop,
+            _ => Role::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Role> for String {
+    fn from(e: Role) -> String {
+        match e {
+            Role::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -8006,9 +8387,6 @@ pub struct OrgMembership {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organization_url: String,
-    /**
-     * Org Membership
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<OrgMembershipPermissions>,
     /**
@@ -8046,9 +8424,6 @@ pub struct Migration {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * A migration.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -8112,6 +8487,7 @@ pub struct Migration {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PackageType {
     #[serde(rename = "container")]
     Container,
@@ -8127,8 +8503,9 @@ pub enum PackageType {
     Rubygems,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageType {
@@ -8141,12 +8518,36 @@ impl std::fmt::Display for PackageType {
             PackageType::Nuget => "nuget",
             PackageType::Rubygems => "rubygems",
             PackageType::Noop => "",
-            PackageType::FallthroughString => "*",
+            PackageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PackageType {
+    fn from(s: String) -> PackageType {
+        match s.as_str() {
+            "container" => PackageType::Container,
+            "docker" => PackageType::Docker,
+            "maven" => PackageType::Maven,
+            "npm" => PackageType::Npm,
+            "nuget" => PackageType::Nuget,
+            "rubygems" => PackageType::Rubygems,
+            "" => PackageType::Noop,
+            _ => PackageType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PackageType> for String {
+    fn from(e: PackageType) -> String {
+        match e {
+            PackageType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PackageType {
     fn default() -> PackageType {
         PackageType::Noop
@@ -8159,6 +8560,7 @@ impl PackageType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PackageVisibility {
     #[serde(rename = "private")]
     Private,
@@ -8166,8 +8568,9 @@ pub enum PackageVisibility {
     Public,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageVisibility {
@@ -8176,12 +8579,32 @@ impl std::fmt::Display for PackageVisibility {
             PackageVisibility::Private => "private",
             PackageVisibility::Public => "public",
             PackageVisibility::Noop => "",
-            PackageVisibility::FallthroughString => "*",
+            PackageVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PackageVisibility {
+    fn from(s: String) -> PackageVisibility {
+        match s.as_str() {
+            "private" => PackageVisibility::Private,
+            "public" => PackageVisibility::Public,
+            "" => PackageVisibility::Noop,
+            _ => PackageVisibility::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PackageVisibility> for String {
+    fn from(e: PackageVisibility) -> String {
+        match e {
+            PackageVisibility::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PackageVisibility {
     fn default() -> PackageVisibility {
         PackageVisibility::Noop
@@ -8208,12 +8631,18 @@ pub struct Package {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the package.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the package.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8238,6 +8667,9 @@ pub struct Package {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * The number of versions of the package.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -8250,9 +8682,6 @@ pub struct Package {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Container {
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -8290,9 +8719,6 @@ pub struct PackageVersion {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * A version of a software package
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -8311,6 +8737,9 @@ pub struct PackageVersion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the package version.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -8323,11 +8752,11 @@ pub struct PackageVersion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub license: String,
-    /**
-     * A version of a software package
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
+    /**
+     * The name of the package version.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8358,6 +8787,7 @@ pub struct PackageVersion {
 * The baseline permission that all organization members have on this project. Only present if owner is an organization.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrganizationPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -8369,8 +8799,9 @@ pub enum OrganizationPermission {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrganizationPermission {
@@ -8381,12 +8812,34 @@ impl std::fmt::Display for OrganizationPermission {
             OrganizationPermission::Read => "read",
             OrganizationPermission::Write => "write",
             OrganizationPermission::Noop => "",
-            OrganizationPermission::FallthroughString => "*",
+            OrganizationPermission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrganizationPermission {
+    fn from(s: String) -> OrganizationPermission {
+        match s.as_str() {
+            "admin" => OrganizationPermission::Admin,
+            "none" => OrganizationPermission::None,
+            "read" => OrganizationPermission::Read,
+            "write" => OrganizationPermission::Write,
+            "" => OrganizationPermission::Noop,
+            _ => OrganizationPermission::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrganizationPermission> for String {
+    fn from(e: OrganizationPermission) -> String {
+        match e {
+            OrganizationPermission::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrganizationPermission {
     fn default() -> OrganizationPermission {
         OrganizationPermission::Noop
@@ -8401,6 +8854,9 @@ impl OrganizationPermission {
 /// Projects are a way to organize columns and cards of work.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Project {
+    /**
+     * Body of the project
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8433,6 +8889,9 @@ pub struct Project {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * Name of the project
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8452,7 +8911,7 @@ pub struct Project {
     )]
     pub number: i64,
     /**
-     * Projects are a way to organize columns and cards of work.
+     * The baseline permission that all organization members have on this project. Only present if owner is an organization.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_permission: Option<OrganizationPermission>,
@@ -8463,13 +8922,16 @@ pub struct Project {
     )]
     pub owner_url: String,
     /**
-     * Projects are a way to organize columns and cards of work.
+     * Whether or not this project can be seen by everyone. Only present if owner is an organization.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub private: bool,
+    /**
+     * State of the project; either 'open' or 'closed'
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8492,30 +8954,45 @@ pub struct Project {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Groups {
+    /**
+     * a description of the group
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_description: String,
+    /**
+     * The ID of the group
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_id: String,
+    /**
+     * The name of the group
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_name: String,
+    /**
+     * synchronization status for this group mapping
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
+    /**
+     * the time of the last sync for this group-mapping
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8528,7 +9005,7 @@ pub struct Groups {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GroupMapping {
     /**
-     * External Groups to be mapped to a team for membership
+     * Array of groups to be mapped to this team
      */
     #[serde(
         default,
@@ -8542,6 +9019,7 @@ pub struct GroupMapping {
 * The level of privacy this team should have
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Privacy {
     #[serde(rename = "closed")]
     Closed,
@@ -8549,8 +9027,9 @@ pub enum Privacy {
     Secret,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Privacy {
@@ -8559,12 +9038,32 @@ impl std::fmt::Display for Privacy {
             Privacy::Closed => "closed",
             Privacy::Secret => "secret",
             Privacy::Noop => "",
-            Privacy::FallthroughString => "*",
+            Privacy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Privacy {
+    fn from(s: String) -> Privacy {
+        match s.as_str() {
+            "closed" => Privacy::Closed,
+            "secret" => Privacy::Secret,
+            "" => Privacy::Noop,
+            _ => Privacy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Privacy> for String {
+    fn from(e: Privacy) -> String {
+        match e {
+            Privacy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Privacy {
     fn default() -> Privacy {
         Privacy::Noop
@@ -8597,12 +9096,18 @@ pub struct FullTeam {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the team
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * Distinguished Name (DN) that team maps to within LDAP environment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8621,6 +9126,9 @@ pub struct FullTeam {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub members_url: String,
+    /**
+     * Name of the team
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8640,6 +9148,9 @@ pub struct FullTeam {
     pub organization: OrganizationFull,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent: Option<TeamSimple>,
+    /**
+     * Permission that the team will have for its repositories
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8647,7 +9158,7 @@ pub struct FullTeam {
     )]
     pub permission: String,
     /**
-     * Groups of organization members that gives permissions on specified repositories.
+     * The level of privacy this team should have
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
@@ -8675,6 +9186,9 @@ pub struct FullTeam {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * URL for the team
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8688,6 +9202,9 @@ pub struct FullTeam {
 pub struct TeamDiscussion {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<SimpleUser>,
+    /**
+     * The main text of the discussion.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8700,6 +9217,9 @@ pub struct TeamDiscussion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body_html: String,
+    /**
+     * The current version of the body content. If provided, this update operation will be rejected if the given version does not match the latest version on the server.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8742,25 +9262,31 @@ pub struct TeamDiscussion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
-    #[serde(
+    /**
+     * The unique sequence number of a team discussion.
+     */
+    #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub number: i64,
+    /**
+     * Whether or not this discussion should be pinned for easy retrieval.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub pinned: bool,
+    /**
+     * Whether or not this discussion should be restricted to team members and organization administrators.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub private: bool,
-    /**
-     * A team discussion is a persistent record of a free-form conversation within a team.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     #[serde(
@@ -8769,6 +9295,9 @@ pub struct TeamDiscussion {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team_url: String,
+    /**
+     * The title of the discussion.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8794,6 +9323,9 @@ pub struct TeamDiscussion {
 pub struct TeamDiscussionComment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<SimpleUser>,
+    /**
+     * The main text of the comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8806,6 +9338,9 @@ pub struct TeamDiscussionComment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body_html: String,
+    /**
+     * The current version of the body content. If provided, this update operation will be rejected if the given version does not match the latest version on the server.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8842,15 +9377,15 @@ pub struct TeamDiscussionComment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The unique sequence number of a team discussion comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub number: i64,
-    /**
-     * A reply to a discussion within a team.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     #[serde(
@@ -8871,6 +9406,7 @@ pub struct TeamDiscussionComment {
 * The reaction to use
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Content {
     #[serde(rename = "+1")]
     PlusOne,
@@ -8890,8 +9426,9 @@ pub enum Content {
     Rocket,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Content {
@@ -8906,12 +9443,38 @@ impl std::fmt::Display for Content {
             Content::Laugh => "laugh",
             Content::Rocket => "rocket",
             Content::Noop => "",
-            Content::FallthroughString => "*",
+            Content::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Content {
+    fn from(s: String) -> Content {
+        match s.as_str() {
+            "+1" => Content::PlusOne,
+            "-1" => Content::MinusOne,
+            "confused" => Content::Confused,
+            "eyes" => Content::Eyes,
+            "heart" => Content::Heart,
+            "hooray" => Content::Hooray,
+            "laugh" => Content::Laugh,
+            "rocket" => Content::Rocket,
+            "" => Content::Noop,
+            _ => Content::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Content> for String {
+    fn from(e: Content) -> String {
+        match e {
+            Content::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Content {
     fn default() -> Content {
         Content::Noop
@@ -8957,13 +9520,15 @@ pub struct Reaction {
 * The role of the user in the team.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TeamMembershipRole {
     #[serde(rename = "maintainer")]
     Maintainer,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamMembershipRole {
@@ -8971,12 +9536,31 @@ impl std::fmt::Display for TeamMembershipRole {
         match self {
             TeamMembershipRole::Maintainer => "maintainer",
             TeamMembershipRole::Member => "member",
-            TeamMembershipRole::FallthroughString => "*",
+            TeamMembershipRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TeamMembershipRole {
+    fn from(s: String) -> TeamMembershipRole {
+        match s.as_str() {
+            "maintainer" => TeamMembershipRole::Maintainer,
+            "member" => TeamMembershipRole::Member,
+            _ => TeamMembershipRole::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TeamMembershipRole> for String {
+    fn from(e: TeamMembershipRole) -> String {
+        match e {
+            TeamMembershipRole::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TeamMembershipRole {
     fn default() -> TeamMembershipRole {
         TeamMembershipRole::Member
@@ -8992,7 +9576,7 @@ pub struct TeamMembership {
     #[serde(default)]
     pub role: TeamMembershipRole,
     /**
-     * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
+     * The state of the user's membership in the team.
      */
     #[serde(default, skip_serializing_if = "OrgMembershipState::is_noop")]
     pub state: OrgMembershipState,
@@ -9079,6 +9663,9 @@ pub struct TeamProject {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub number: i64,
+    /**
+     * The organization permission for this project. Only present when owner is an organization.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9094,7 +9681,7 @@ pub struct TeamProject {
     #[serde()]
     pub permissions: TeamProjectPermissions,
     /**
-     * A team's access to a project.
+     * Whether the project is private or not. Only present when owner is an organization.
      */
     #[serde(
         default,
@@ -9125,7 +9712,7 @@ pub struct TeamProject {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamRepository {
     /**
-     * A team's access to a repository.
+     * Whether to allow Auto-merge to be used on pull requests.
      */
     #[serde(
         default,
@@ -9133,7 +9720,7 @@ pub struct TeamRepository {
     )]
     pub allow_auto_merge: bool,
     /**
-     * A team's access to a repository.
+     * Whether to allow merge commits for pull requests.
      */
     #[serde(
         default,
@@ -9141,7 +9728,7 @@ pub struct TeamRepository {
     )]
     pub allow_merge_commit: bool,
     /**
-     * A team's access to a repository.
+     * Whether to allow rebase merges for pull requests.
      */
     #[serde(
         default,
@@ -9149,7 +9736,7 @@ pub struct TeamRepository {
     )]
     pub allow_rebase_merge: bool,
     /**
-     * A team's access to a repository.
+     * Whether to allow squash merges for pull requests.
      */
     #[serde(
         default,
@@ -9162,6 +9749,9 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub archive_url: String,
+    /**
+     * Whether the repository is archived.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -9233,6 +9823,9 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The default branch of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9240,7 +9833,7 @@ pub struct TeamRepository {
     )]
     pub default_branch: String,
     /**
-     * A team's access to a repository.
+     * Whether to delete head branches when pull requests are merged
      */
     #[serde(
         default,
@@ -9259,6 +9852,9 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Returns whether or not this repository disabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -9329,11 +9925,17 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub git_url: String,
+    /**
+     * Whether downloads are enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_downloads: bool,
+    /**
+     * Whether issues are enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -9344,11 +9946,17 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_pages: bool,
+    /**
+     * Whether projects are enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub has_projects: bool,
+    /**
+     * Whether the wiki is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -9372,6 +9980,9 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the repository
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9379,7 +9990,7 @@ pub struct TeamRepository {
     )]
     pub id: i64,
     /**
-     * A team's access to a repository.
+     * Whether this repository acts as a template that can be used to generate new repositories.
      */
     #[serde(
         default,
@@ -9454,15 +10065,15 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mirror_url: String,
+    /**
+     * The name of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * A team's access to a repository.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9495,11 +10106,11 @@ pub struct TeamRepository {
     pub open_issues_count: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<SimpleUser>,
-    /**
-     * A team's access to a repository.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<RepositoryPermissions>,
+    /**
+     * Whether the repository is private or public.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -9553,9 +10164,6 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statuses_url: String,
-    /**
-     * A team's access to a repository.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9598,14 +10206,8 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub temp_clone_token: String,
-    /**
-     * A team's access to a repository.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_repository: Option<Data>,
-    /**
-     * A team's access to a repository.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -9630,6 +10232,9 @@ pub struct TeamRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * The repository visibility: public, private, or internal.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9654,7 +10259,7 @@ pub struct TeamRepository {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectCard {
     /**
-     * Project cards represent a scope of work.
+     * Whether or not the card is archived
      */
     #[serde(
         default,
@@ -9687,6 +10292,9 @@ pub struct ProjectCard {
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub creator: Option<SimpleUser>,
+    /**
+     * The project card's ID
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9746,12 +10354,18 @@ pub struct ProjectColumn {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The unique identifier of the project column
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * Name of the project column
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9899,6 +10513,7 @@ pub struct FullRepositoryPermissions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "disabled")]
     Disabled,
@@ -9906,8 +10521,9 @@ pub enum Status {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -9916,12 +10532,32 @@ impl std::fmt::Display for Status {
             Status::Disabled => "disabled",
             Status::Enabled => "enabled",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "disabled" => Status::Disabled,
+            "enabled" => Status::Enabled,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -9950,40 +10586,28 @@ pub struct SecurityAnalysis {
 /// Full Repository
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FullRepository {
-    /**
-     * Full Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_auto_merge: bool,
-    /**
-     * Full Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_merge_commit: bool,
-    /**
-     * Full Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_rebase_merge: bool,
-    /**
-     * Full Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_squash_merge: bool,
     /**
-     * Full Repository
+     * Whether anonymous git access is allowed.
      */
     #[serde(
         default,
@@ -10026,7 +10650,7 @@ pub struct FullRepository {
     )]
     pub clone_url: String,
     /**
-     * Full Repository
+     * Code of Conduct Simple
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub code_of_conduct: Option<CodeOfConductSimple>,
@@ -10078,9 +10702,6 @@ pub struct FullRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub default_branch: String,
-    /**
-     * Full Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -10098,6 +10719,9 @@ pub struct FullRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Returns whether or not this repository disabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -10217,9 +10841,6 @@ pub struct FullRepository {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * Full Repository
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -10337,13 +10958,10 @@ pub struct FullRepository {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<SimpleUser>,
     /**
-     * Full Repository
+     * A git repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent: Option<Repository>,
-    /**
-     * Full Repository
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<FullRepositoryPermissions>,
     #[serde(
@@ -10378,7 +10996,7 @@ pub struct FullRepository {
     )]
     pub size: i64,
     /**
-     * Full Repository
+     * A git repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<Repository>,
@@ -10448,14 +11066,8 @@ pub struct FullRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub temp_clone_token: String,
-    /**
-     * Full Repository
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub template_repository: Option<Data>,
-    /**
-     * Full Repository
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -10480,6 +11092,9 @@ pub struct FullRepository {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * The repository visibility: public, private, or internal.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10515,6 +11130,9 @@ pub struct Artifact {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Whether or not the artifact has expired.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -10532,6 +11150,9 @@ pub struct Artifact {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the artifact.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10544,6 +11165,9 @@ pub struct Artifact {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The size in bytes of the artifact.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10568,6 +11192,7 @@ pub struct Artifact {
 * The phase of the lifecycle that the job is currently in.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum JobStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -10577,8 +11202,9 @@ pub enum JobStatus {
     Queued,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for JobStatus {
@@ -10588,12 +11214,33 @@ impl std::fmt::Display for JobStatus {
             JobStatus::InProgress => "in_progress",
             JobStatus::Queued => "queued",
             JobStatus::Noop => "",
-            JobStatus::FallthroughString => "*",
+            JobStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for JobStatus {
+    fn from(s: String) -> JobStatus {
+        match s.as_str() {
+            "completed" => JobStatus::Completed,
+            "in_progress" => JobStatus::InProgress,
+            "queued" => JobStatus::Queued,
+            "" => JobStatus::Noop,
+            _ => JobStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<JobStatus> for String {
+    fn from(e: JobStatus) -> String {
+        match e {
+            JobStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for JobStatus {
     fn default() -> JobStatus {
         JobStatus::Noop
@@ -10607,18 +11254,27 @@ impl JobStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Steps {
+    /**
+     * The time that the job finished, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The outcome of the job.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub conclusion: String,
+    /**
+     * The name of the job.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10631,6 +11287,9 @@ pub struct Steps {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub number: i64,
+    /**
+     * The time that the step started, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -10653,18 +11312,27 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub check_run_url: String,
+    /**
+     * The time that the job finished, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The outcome of the job.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub conclusion: String,
+    /**
+     * The SHA of the commit that is being run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10677,12 +11345,18 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The id of the job.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the job.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10695,6 +11369,9 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The id of the associated workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10707,6 +11384,9 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub run_url: String,
+    /**
+     * The time that the job started, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -10719,7 +11399,7 @@ pub struct Job {
     #[serde(default, skip_serializing_if = "JobStatus::is_noop")]
     pub status: JobStatus,
     /**
-     * Information of a job execution in a workflow run
+     * Steps in this job.
      */
     #[serde(
         default,
@@ -10852,12 +11532,18 @@ pub struct SimpleCommit {
 /// An invocation of a workflow
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WorkflowRun {
+    /**
+     * The URL to the artifacts for the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub artifacts_url: String,
+    /**
+     * The URL to cancel the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10865,7 +11551,7 @@ pub struct WorkflowRun {
     )]
     pub cancel_url: String,
     /**
-     * An invocation of a workflow
+     * The ID of the associated check suite.
      */
     #[serde(
         default,
@@ -10873,12 +11559,18 @@ pub struct WorkflowRun {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub check_suite_id: i64,
+    /**
+     * The node ID of the associated check suite.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub check_suite_node_id: String,
+    /**
+     * The URL to the associated check suite.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10916,15 +11608,15 @@ pub struct WorkflowRun {
      */
     #[serde()]
     pub head_repository: MinimalRepository,
-    /**
-     * An invocation of a workflow
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub head_repository_id: i64,
+    /**
+     * The SHA of the head commit that points to the version of the worflow being run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10937,24 +11629,36 @@ pub struct WorkflowRun {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The ID of the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The URL to the jobs for the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub jobs_url: String,
+    /**
+     * The URL to download the logs for the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub logs_url: String,
+    /**
+     * The name of the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10978,12 +11682,18 @@ pub struct WorkflowRun {
      */
     #[serde()]
     pub repository: MinimalRepository,
+    /**
+     * The URL to rerun the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rerun_url: String,
+    /**
+     * The auto incrementing run number for the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11002,18 +11712,27 @@ pub struct WorkflowRun {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The URL to the workflow run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * The ID of the parent workflow.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub workflow_id: i64,
+    /**
+     * The URL to the workflow.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11024,6 +11743,9 @@ pub struct WorkflowRun {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Environments {
+    /**
+     * The time that the environment was created, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -11036,12 +11758,18 @@ pub struct Environments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The id of the environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11054,6 +11782,9 @@ pub struct Environments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The time that the environment was last updated, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -11072,6 +11803,7 @@ pub struct Environments {
 * Whether deployment to the environment(s) was approved or rejected
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EnvironmentApprovalState {
     #[serde(rename = "approved")]
     Approved,
@@ -11079,8 +11811,9 @@ pub enum EnvironmentApprovalState {
     Rejected,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnvironmentApprovalState {
@@ -11089,12 +11822,32 @@ impl std::fmt::Display for EnvironmentApprovalState {
             EnvironmentApprovalState::Approved => "approved",
             EnvironmentApprovalState::Rejected => "rejected",
             EnvironmentApprovalState::Noop => "",
-            EnvironmentApprovalState::FallthroughString => "*",
+            EnvironmentApprovalState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EnvironmentApprovalState {
+    fn from(s: String) -> EnvironmentApprovalState {
+        match s.as_str() {
+            "approved" => EnvironmentApprovalState::Approved,
+            "rejected" => EnvironmentApprovalState::Rejected,
+            "" => EnvironmentApprovalState::Noop,
+            _ => EnvironmentApprovalState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EnvironmentApprovalState> for String {
+    fn from(e: EnvironmentApprovalState) -> String {
+        match e {
+            EnvironmentApprovalState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EnvironmentApprovalState {
     fn default() -> EnvironmentApprovalState {
         EnvironmentApprovalState::Noop
@@ -11109,6 +11862,9 @@ impl EnvironmentApprovalState {
 /// An entry in the reviews log for environment deployments
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvironmentApproval {
+    /**
+     * The comment submitted with the deployment review
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11140,6 +11896,7 @@ pub struct EnvironmentApproval {
 * The type of reviewer. Must be one of: `User` or `Team`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DeploymentReviewerType {
     #[serde(rename = "Team")]
     Team,
@@ -11147,8 +11904,9 @@ pub enum DeploymentReviewerType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeploymentReviewerType {
@@ -11157,12 +11915,32 @@ impl std::fmt::Display for DeploymentReviewerType {
             DeploymentReviewerType::Team => "Team",
             DeploymentReviewerType::User => "User",
             DeploymentReviewerType::Noop => "",
-            DeploymentReviewerType::FallthroughString => "*",
+            DeploymentReviewerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DeploymentReviewerType {
+    fn from(s: String) -> DeploymentReviewerType {
+        match s.as_str() {
+            "Team" => DeploymentReviewerType::Team,
+            "User" => DeploymentReviewerType::User,
+            "" => DeploymentReviewerType::Noop,
+            _ => DeploymentReviewerType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DeploymentReviewerType> for String {
+    fn from(e: DeploymentReviewerType) -> String {
+        match e {
+            DeploymentReviewerType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DeploymentReviewerType {
     fn default() -> DeploymentReviewerType {
         DeploymentReviewerType::Noop
@@ -11182,12 +11960,18 @@ pub struct Environment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The id of the environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11241,6 +12025,9 @@ pub struct Reviewers {
 /// Details of a deployment that is waiting for protection rules to pass
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PendingDeployment {
+    /**
+     * Whether the currently authenticated user can approve the deployment
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -11257,12 +12044,18 @@ pub struct PendingDeployment {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub reviewers: Vec<Reviewers>,
+    /**
+     * The set duration of the wait timer
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub wait_timer: i64,
+    /**
+     * The time that the wait timer began.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -11330,12 +12123,18 @@ pub struct Deployment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Name for the target deployment environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub environment: String,
+    /**
+     * Unique identifier of the deployment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11359,13 +12158,16 @@ pub struct Deployment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
     /**
-     * A request for a specific ref(branch,sha,tag) to be deployed
+     * Specifies if the given environment is one that end-users directly interact with. Default: false.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub production_environment: bool,
+    /**
+     * The ref to deploy. This can be a branch, tag, or sha.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11391,6 +12193,9 @@ pub struct Deployment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statuses_url: String,
+    /**
+     * Parameter to specify a task to execute
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11398,7 +12203,7 @@ pub struct Deployment {
     )]
     pub task: String,
     /**
-     * A request for a specific ref(branch,sha,tag) to be deployed
+     * Specifies if the given environment is will no longer exist at some point in the future. Default: false.
      */
     #[serde(
         default,
@@ -11450,9 +12255,6 @@ pub struct Billable {
 pub struct WorkflowRunUsage {
     #[serde()]
     pub billable: Billable,
-    /**
-     * Workflow Run Usage
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11470,6 +12272,9 @@ pub struct ActionsSecret {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The name of the secret.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11485,6 +12290,7 @@ pub struct ActionsSecret {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WorkflowState {
     #[serde(rename = "active")]
     Active,
@@ -11498,8 +12304,9 @@ pub enum WorkflowState {
     DisabledManually,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowState {
@@ -11511,12 +12318,35 @@ impl std::fmt::Display for WorkflowState {
             WorkflowState::DisabledInactivity => "disabled_inactivity",
             WorkflowState::DisabledManually => "disabled_manually",
             WorkflowState::Noop => "",
-            WorkflowState::FallthroughString => "*",
+            WorkflowState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WorkflowState {
+    fn from(s: String) -> WorkflowState {
+        match s.as_str() {
+            "active" => WorkflowState::Active,
+            "deleted" => WorkflowState::Deleted,
+            "disabled_fork" => WorkflowState::DisabledFork,
+            "disabled_inactivity" => WorkflowState::DisabledInactivity,
+            "disabled_manually" => WorkflowState::DisabledManually,
+            "" => WorkflowState::Noop,
+            _ => WorkflowState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WorkflowState> for String {
+    fn from(e: WorkflowState) -> String {
+        match e {
+            WorkflowState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WorkflowState {
     fn default() -> WorkflowState {
         WorkflowState::Noop
@@ -11543,9 +12373,6 @@ pub struct Workflow {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * A GitHub Actions workflow
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -11634,12 +12461,18 @@ pub struct Autolink {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The prefix of a key that is linkified.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key_prefix: String,
+    /**
+     * A template for the target URL that is generated if a key was found.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11686,6 +12519,9 @@ pub struct DismissalRestrictions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * The list of users with review dismissal access.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -11708,9 +12544,6 @@ pub struct ProtectedBranchPullRequestReview {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub dismiss_stale_reviews: bool,
-    /**
-     * Protected Branch Pull Request Review
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dismissal_restrictions: Option<DismissalRestrictions>,
     #[serde(
@@ -11718,9 +12551,6 @@ pub struct ProtectedBranchPullRequestReview {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub require_code_owner_reviews: bool,
-    /**
-     * Protected Branch Pull Request Review
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11995,9 +12825,6 @@ pub struct Apps {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -12101,9 +12928,6 @@ pub struct BranchRestrictionPolicy {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RequiredStatusChecks {
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -12147,27 +12971,15 @@ pub struct AllowDeletions {
 /// Branch Protection
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BranchProtection {
-    /**
-     * Branch Protection
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_deletions: Option<AllowDeletions>,
-    /**
-     * Branch Protection
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_force_pushes: Option<AllowDeletions>,
-    /**
-     * Branch Protection
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
-    /**
-     * Branch Protection
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enforce_admins: Option<EnforceAdmins>,
     #[serde(
@@ -12182,33 +12994,21 @@ pub struct BranchProtection {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub protection_url: String,
-    /**
-     * Branch Protection
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_conversation_resolution: Option<AllowDeletions>,
-    /**
-     * Branch Protection
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_linear_history: Option<AllowDeletions>,
     /**
-     * Branch Protection
+     * Protected Branch Pull Request Review
      */
     #[serde(default)]
     pub required_pull_request_reviews: Option<ProtectedBranchPullRequestReview>,
-    /**
-     * Branch Protection
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_signatures: Option<EnforceAdmins>,
-    /**
-     * Branch Protection
-     */
     #[serde(default)]
     pub required_status_checks: Option<RequiredStatusChecks>,
     /**
-     * Branch Protection
+     * Branch Restriction Policy
      */
     #[serde(default)]
     pub restrictions: Option<BranchRestrictionPolicy>,
@@ -12253,7 +13053,7 @@ pub struct ShortBranch {
     )]
     pub protected: bool,
     /**
-     * Short Branch
+     * Branch Protection
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub protection: Option<BranchProtection>,
@@ -12451,9 +13251,6 @@ pub struct CommitDataType {
     pub commit: CommitData,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub committer: Option<SimpleUser>,
-    /**
-     * Commit
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -12484,9 +13281,6 @@ pub struct CommitDataType {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
-    /**
-     * Commit
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stats: Option<Stats>,
     #[serde(
@@ -12552,9 +13346,6 @@ pub struct BranchWithProtection {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub protection_url: String,
-    /**
-     * Branch With Protection
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -12566,9 +13357,6 @@ pub struct BranchWithProtection {
 /// Status Check Policy
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StatusCheckPolicy {
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -12596,9 +13384,6 @@ pub struct StatusCheckPolicy {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProtectedBranchRequiredPullRequestReviewsDismissalRestrictions {
-    /**
-     * The list of teams with review dismissal access.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -12672,48 +13457,27 @@ pub struct ProtectedBranchRequiredLinearHistory {
 /// Branch protections protect branches
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProtectedBranch {
-    /**
-     * Branch protections protect branches
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_deletions: Option<ProtectedBranchRequiredLinearHistory>,
-    /**
-     * Branch protections protect branches
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_force_pushes: Option<ProtectedBranchRequiredLinearHistory>,
-    /**
-     * Branch protections protect branches
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enforce_admins: Option<EnforceAdmins>,
-    /**
-     * Branch protections protect branches
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_conversation_resolution: Option<AllowDeletions>,
-    /**
-     * Branch protections protect branches
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_linear_history: Option<ProtectedBranchRequiredLinearHistory>,
-    /**
-     * Branch protections protect branches
-     */
     #[serde(default)]
     pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
-    /**
-     * Branch protections protect branches
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_signatures: Option<EnforceAdmins>,
     /**
-     * Branch protections protect branches
+     * Status Check Policy
      */
     #[serde(default)]
     pub required_status_checks: Option<StatusCheckPolicy>,
     /**
-     * Branch protections protect branches
+     * Branch Restriction Policy
      */
     #[serde(default)]
     pub restrictions: Option<BranchRestrictionPolicy>,
@@ -12740,12 +13504,18 @@ pub struct DeploymentSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Name for the target deployment environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub environment: String,
+    /**
+     * Unique identifier of the deployment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -12767,7 +13537,7 @@ pub struct DeploymentSimple {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
     /**
-     * A deployment created as the result of an Actions check run from a workflow that references an environment
+     * Specifies if the given environment is one that end-users directly interact with. Default: false.
      */
     #[serde(
         default,
@@ -12786,6 +13556,9 @@ pub struct DeploymentSimple {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statuses_url: String,
+    /**
+     * Parameter to specify a task to execute
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12793,7 +13566,7 @@ pub struct DeploymentSimple {
     )]
     pub task: String,
     /**
-     * A deployment created as the result of an Actions check run from a workflow that references an environment
+     * Specifies if the given environment is will no longer exist at some point in the future. Default: false.
      */
     #[serde(
         default,
@@ -12815,6 +13588,7 @@ pub struct DeploymentSimple {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Conclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -12832,8 +13606,9 @@ pub enum Conclusion {
     TimedOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Conclusion {
@@ -12847,12 +13622,37 @@ impl std::fmt::Display for Conclusion {
             Conclusion::Success => "success",
             Conclusion::TimedOut => "timed_out",
             Conclusion::Noop => "",
-            Conclusion::FallthroughString => "*",
+            Conclusion::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Conclusion {
+    fn from(s: String) -> Conclusion {
+        match s.as_str() {
+            "action_required" => Conclusion::ActionRequired,
+            "cancelled" => Conclusion::Cancelled,
+            "failure" => Conclusion::Failure,
+            "neutral" => Conclusion::Neutral,
+            "skipped" => Conclusion::Skipped,
+            "success" => Conclusion::Success,
+            "timed_out" => Conclusion::TimedOut,
+            "" => Conclusion::Noop,
+            _ => Conclusion::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Conclusion> for String {
+    fn from(e: Conclusion) -> String {
+        match e {
+            Conclusion::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Conclusion {
     fn default() -> Conclusion {
         Conclusion::Noop
@@ -12924,7 +13724,7 @@ pub struct CheckRun {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conclusion: Option<Conclusion>,
     /**
-     * A check performed on the code of a given code change
+     * A deployment created as the result of an Actions check run from a workflow that references an environment
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deployment: Option<DeploymentSimple>,
@@ -12940,6 +13740,9 @@ pub struct CheckRun {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub external_id: String,
+    /**
+     * The SHA of the commit that is being checked.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12952,12 +13755,18 @@ pub struct CheckRun {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The id of the check.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the check.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12985,7 +13794,7 @@ pub struct CheckRun {
     )]
     pub started_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The phase of the lifecycle that the job is currently in.
+     * The phase of the lifecycle that the check is currently in.
      */
     #[serde(default, skip_serializing_if = "JobStatus::is_noop")]
     pub status: JobStatus,
@@ -13104,6 +13913,9 @@ pub struct CheckSuiteData {
      */
     #[serde()]
     pub head_commit: SimpleCommit,
+    /**
+     * The SHA of the head commit that is being checked.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13139,9 +13951,6 @@ pub struct CheckSuiteData {
      */
     #[serde()]
     pub repository: MinimalRepository,
-    /**
-     * The phase of the lifecycle that the job is currently in.
-     */
     #[serde(default, skip_serializing_if = "JobStatus::is_noop")]
     pub status: JobStatus,
     #[serde(
@@ -13199,6 +14008,7 @@ pub struct CheckSuitePreference {
 * State of a code scanning alert.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CodeScanningAlertState {
     #[serde(rename = "closed")]
     Closed,
@@ -13210,8 +14020,9 @@ pub enum CodeScanningAlertState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertState {
@@ -13222,12 +14033,34 @@ impl std::fmt::Display for CodeScanningAlertState {
             CodeScanningAlertState::Fixed => "fixed",
             CodeScanningAlertState::Open => "open",
             CodeScanningAlertState::Noop => "",
-            CodeScanningAlertState::FallthroughString => "*",
+            CodeScanningAlertState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CodeScanningAlertState {
+    fn from(s: String) -> CodeScanningAlertState {
+        match s.as_str() {
+            "closed" => CodeScanningAlertState::Closed,
+            "dismissed" => CodeScanningAlertState::Dismissed,
+            "fixed" => CodeScanningAlertState::Fixed,
+            "open" => CodeScanningAlertState::Open,
+            "" => CodeScanningAlertState::Noop,
+            _ => CodeScanningAlertState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CodeScanningAlertState> for String {
+    fn from(e: CodeScanningAlertState) -> String {
+        match e {
+            CodeScanningAlertState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CodeScanningAlertState {
     fn default() -> CodeScanningAlertState {
         CodeScanningAlertState::Noop
@@ -13243,6 +14076,7 @@ impl CodeScanningAlertState {
 * **Required when the state is dismissed.** The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CodeScanningAlertDismissedReason {
     #[serde(rename = "false positive")]
     FalsePositive,
@@ -13252,8 +14086,9 @@ pub enum CodeScanningAlertDismissedReason {
     WonTFix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertDismissedReason {
@@ -13263,12 +14098,33 @@ impl std::fmt::Display for CodeScanningAlertDismissedReason {
             CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
             CodeScanningAlertDismissedReason::WonTFix => "won't fix",
             CodeScanningAlertDismissedReason::Noop => "",
-            CodeScanningAlertDismissedReason::FallthroughString => "*",
+            CodeScanningAlertDismissedReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CodeScanningAlertDismissedReason {
+    fn from(s: String) -> CodeScanningAlertDismissedReason {
+        match s.as_str() {
+            "false positive" => CodeScanningAlertDismissedReason::FalsePositive,
+            "used in tests" => CodeScanningAlertDismissedReason::UsedInTests,
+            "won't fix" => CodeScanningAlertDismissedReason::WonTFix,
+            "" => CodeScanningAlertDismissedReason::Noop,
+            _ => CodeScanningAlertDismissedReason::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CodeScanningAlertDismissedReason> for String {
+    fn from(e: CodeScanningAlertDismissedReason) -> String {
+        match e {
+            CodeScanningAlertDismissedReason::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CodeScanningAlertDismissedReason {
     fn default() -> CodeScanningAlertDismissedReason {
         CodeScanningAlertDismissedReason::Noop
@@ -13284,6 +14140,7 @@ impl CodeScanningAlertDismissedReason {
 * The severity of the alert.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Severity {
     #[serde(rename = "error")]
     Error,
@@ -13295,8 +14152,9 @@ pub enum Severity {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -13307,12 +14165,34 @@ impl std::fmt::Display for Severity {
             Severity::Note => "note",
             Severity::Warning => "warning",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Severity {
+    fn from(s: String) -> Severity {
+        match s.as_str() {
+            "error" => Severity::Error,
+            "none" => Severity::None,
+            "note" => Severity::Note,
+            "warning" => Severity::Warning,
+            "" => Severity::Noop,
+            _ => Severity::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Severity> for String {
+    fn from(e: Severity) -> String {
+        match e {
+            Severity::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Severity {
     fn default() -> Severity {
         Severity::Noop
@@ -13326,18 +14206,27 @@ impl Severity {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CodeScanningAlertRuleSummary {
+    /**
+     * A short description of the rule used to detect the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * A unique identifier for the rule used to detect the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * The name of the rule used to detect the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13376,18 +14265,12 @@ pub struct CodeScanningAnalysisTool {
 /// Describe a region within a file for the alert.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CodeScanningAlertLocation {
-    /**
-     * Describe a region within a file for the alert.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub end_column: i64,
-    /**
-     * Describe a region within a file for the alert.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -13400,18 +14283,12 @@ pub struct CodeScanningAlertLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
-    /**
-     * Describe a region within a file for the alert.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_column: i64,
-    /**
-     * Describe a region within a file for the alert.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -13424,6 +14301,7 @@ pub struct CodeScanningAlertLocation {
 * A classification of the file. For example to identify it as generated.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CodeScanningAlertClassification {
     #[serde(rename = "generated")]
     Generated,
@@ -13435,8 +14313,9 @@ pub enum CodeScanningAlertClassification {
     Test,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertClassification {
@@ -13447,12 +14326,34 @@ impl std::fmt::Display for CodeScanningAlertClassification {
             CodeScanningAlertClassification::Source => "source",
             CodeScanningAlertClassification::Test => "test",
             CodeScanningAlertClassification::Noop => "",
-            CodeScanningAlertClassification::FallthroughString => "*",
+            CodeScanningAlertClassification::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CodeScanningAlertClassification {
+    fn from(s: String) -> CodeScanningAlertClassification {
+        match s.as_str() {
+            "generated" => CodeScanningAlertClassification::Generated,
+            "library" => CodeScanningAlertClassification::Library,
+            "source" => CodeScanningAlertClassification::Source,
+            "test" => CodeScanningAlertClassification::Test,
+            "" => CodeScanningAlertClassification::Noop,
+            _ => CodeScanningAlertClassification::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CodeScanningAlertClassification> for String {
+    fn from(e: CodeScanningAlertClassification) -> String {
+        match e {
+            CodeScanningAlertClassification::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CodeScanningAlertClassification {
     fn default() -> CodeScanningAlertClassification {
         CodeScanningAlertClassification::Noop
@@ -13596,6 +14497,7 @@ pub struct CodeScanningAlertItems {
 * The security severity of the alert.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SecuritySeverityLevel {
     #[serde(rename = "critical")]
     Critical,
@@ -13607,8 +14509,9 @@ pub enum SecuritySeverityLevel {
     Medium,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecuritySeverityLevel {
@@ -13619,12 +14522,34 @@ impl std::fmt::Display for SecuritySeverityLevel {
             SecuritySeverityLevel::Low => "low",
             SecuritySeverityLevel::Medium => "medium",
             SecuritySeverityLevel::Noop => "",
-            SecuritySeverityLevel::FallthroughString => "*",
+            SecuritySeverityLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SecuritySeverityLevel {
+    fn from(s: String) -> SecuritySeverityLevel {
+        match s.as_str() {
+            "critical" => SecuritySeverityLevel::Critical,
+            "high" => SecuritySeverityLevel::High,
+            "low" => SecuritySeverityLevel::Low,
+            "medium" => SecuritySeverityLevel::Medium,
+            "" => SecuritySeverityLevel::Noop,
+            _ => SecuritySeverityLevel::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SecuritySeverityLevel> for String {
+    fn from(e: SecuritySeverityLevel) -> String {
+        match e {
+            SecuritySeverityLevel::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SecuritySeverityLevel {
     fn default() -> SecuritySeverityLevel {
         SecuritySeverityLevel::Noop
@@ -13638,30 +14563,45 @@ impl SecuritySeverityLevel {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CodeScanningAlertRule {
+    /**
+     * A short description of the rule used to detect the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * description of the rule used to detect the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub full_description: String,
+    /**
+     * Detailed documentation for the rule as GitHub Flavored Markdown.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub help: String,
+    /**
+     * A unique identifier for the rule used to detect the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * The name of the rule used to detect the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13679,7 +14619,7 @@ pub struct CodeScanningAlertRule {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub severity: Option<Severity>,
     /**
-     * The list of events for the GitHub app
+     * A set of tags applicable for the rule.
      */
     #[serde(
         default,
@@ -13756,6 +14696,7 @@ pub struct CodeScanningAlert {
 * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CodeScanningAlertSetState {
     #[serde(rename = "dismissed")]
     Dismissed,
@@ -13763,8 +14704,9 @@ pub enum CodeScanningAlertSetState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CodeScanningAlertSetState {
@@ -13773,12 +14715,32 @@ impl std::fmt::Display for CodeScanningAlertSetState {
             CodeScanningAlertSetState::Dismissed => "dismissed",
             CodeScanningAlertSetState::Open => "open",
             CodeScanningAlertSetState::Noop => "",
-            CodeScanningAlertSetState::FallthroughString => "*",
+            CodeScanningAlertSetState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CodeScanningAlertSetState {
+    fn from(s: String) -> CodeScanningAlertSetState {
+        match s.as_str() {
+            "dismissed" => CodeScanningAlertSetState::Dismissed,
+            "open" => CodeScanningAlertSetState::Open,
+            "" => CodeScanningAlertSetState::Noop,
+            _ => CodeScanningAlertSetState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CodeScanningAlertSetState> for String {
+    fn from(e: CodeScanningAlertSetState) -> String {
+        match e {
+            CodeScanningAlertSetState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CodeScanningAlertSetState {
     fn default() -> CodeScanningAlertSetState {
         CodeScanningAlertSetState::Noop
@@ -13833,6 +14795,9 @@ pub struct CodeScanningAnalysis {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error: String,
+    /**
+     * Unique identifier for this analysis.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -13846,12 +14811,18 @@ pub struct CodeScanningAnalysis {
         rename = "ref"
     )]
     pub ref_: String,
+    /**
+     * The total number of results in the analysis.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub results_count: i64,
+    /**
+     * The total number of rules used in the analysis.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -13878,6 +14849,9 @@ pub struct CodeScanningAnalysis {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * Warning generated when processing the analysis
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13889,12 +14863,18 @@ pub struct CodeScanningAnalysis {
 /// Successful deletion of a code scanning analysis
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AnalysisDeletion {
+    /**
+     * Next deletable analysis in chain, with last analysis deletion confirmation
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub confirm_delete_url: String,
+    /**
+     * Next deletable analysis in chain, without last analysis deletion confirmation
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13911,6 +14891,9 @@ pub struct CodeScanningSarifsReceipt {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * The REST API URL for checking the status of the upload.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13923,6 +14906,7 @@ pub struct CodeScanningSarifsReceipt {
 * `pending` files have not yet been processed, while `complete` means all results in the SARIF have been stored.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProcessingStatus {
     #[serde(rename = "complete")]
     Complete,
@@ -13930,8 +14914,9 @@ pub enum ProcessingStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProcessingStatus {
@@ -13940,12 +14925,32 @@ impl std::fmt::Display for ProcessingStatus {
             ProcessingStatus::Complete => "complete",
             ProcessingStatus::Pending => "pending",
             ProcessingStatus::Noop => "",
-            ProcessingStatus::FallthroughString => "*",
+            ProcessingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProcessingStatus {
+    fn from(s: String) -> ProcessingStatus {
+        match s.as_str() {
+            "complete" => ProcessingStatus::Complete,
+            "pending" => ProcessingStatus::Pending,
+            "" => ProcessingStatus::Noop,
+            _ => ProcessingStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProcessingStatus> for String {
+    fn from(e: ProcessingStatus) -> String {
+        match e {
+            ProcessingStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProcessingStatus {
     fn default() -> ProcessingStatus {
         ProcessingStatus::Noop
@@ -13959,6 +14964,9 @@ impl ProcessingStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CodeScanningSarifsStatus {
+    /**
+     * The REST API URL for getting the analyses associated with the upload.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -14053,9 +15061,6 @@ pub struct Collaborator {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organizations_url: String,
-    /**
-     * Collaborator
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<FullRepositoryPermissions>,
     #[serde(
@@ -14106,6 +15111,7 @@ pub struct Collaborator {
 * The permission associated with the invitation.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RepositoryInvitationPermissions {
     #[serde(rename = "admin")]
     Admin,
@@ -14119,8 +15125,9 @@ pub enum RepositoryInvitationPermissions {
     Write,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RepositoryInvitationPermissions {
@@ -14132,12 +15139,35 @@ impl std::fmt::Display for RepositoryInvitationPermissions {
             RepositoryInvitationPermissions::Triage => "triage",
             RepositoryInvitationPermissions::Write => "write",
             RepositoryInvitationPermissions::Noop => "",
-            RepositoryInvitationPermissions::FallthroughString => "*",
+            RepositoryInvitationPermissions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RepositoryInvitationPermissions {
+    fn from(s: String) -> RepositoryInvitationPermissions {
+        match s.as_str() {
+            "admin" => RepositoryInvitationPermissions::Admin,
+            "maintain" => RepositoryInvitationPermissions::Maintain,
+            "read" => RepositoryInvitationPermissions::Read,
+            "triage" => RepositoryInvitationPermissions::Triage,
+            "write" => RepositoryInvitationPermissions::Write,
+            "" => RepositoryInvitationPermissions::Noop,
+            _ => RepositoryInvitationPermissions::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RepositoryInvitationPermissions> for String {
+    fn from(e: RepositoryInvitationPermissions) -> String {
+        match e {
+            RepositoryInvitationPermissions::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RepositoryInvitationPermissions {
     fn default() -> RepositoryInvitationPermissions {
         RepositoryInvitationPermissions::Noop
@@ -14159,7 +15189,7 @@ pub struct RepositoryInvitation {
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Repository invitations let you manage who you collaborate with.
+     * Whether or not the invitation has expired
      */
     #[serde(
         default,
@@ -14172,6 +15202,9 @@ pub struct RepositoryInvitation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the repository invitation.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -14201,6 +15234,9 @@ pub struct RepositoryInvitation {
      */
     #[serde()]
     pub repository: MinimalRepository,
+    /**
+     * URL for the repository invitation
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -14271,9 +15307,6 @@ pub struct CommitComment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub position: i64,
-    /**
-     * Commit Comment
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     #[serde(
@@ -14325,6 +15358,7 @@ pub struct Link {
 * The merge method to use.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MergeMethod {
     #[serde(rename = "merge")]
     Merge,
@@ -14334,8 +15368,9 @@ pub enum MergeMethod {
     Squash,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeMethod {
@@ -14345,12 +15380,33 @@ impl std::fmt::Display for MergeMethod {
             MergeMethod::Rebase => "rebase",
             MergeMethod::Squash => "squash",
             MergeMethod::Noop => "",
-            MergeMethod::FallthroughString => "*",
+            MergeMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MergeMethod {
+    fn from(s: String) -> MergeMethod {
+        match s.as_str() {
+            "merge" => MergeMethod::Merge,
+            "rebase" => MergeMethod::Rebase,
+            "squash" => MergeMethod::Squash,
+            "" => MergeMethod::Noop,
+            _ => MergeMethod::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MergeMethod> for String {
+    fn from(e: MergeMethod) -> String {
+        match e {
+            MergeMethod::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MergeMethod {
     fn default() -> MergeMethod {
         MergeMethod::Noop
@@ -14365,12 +15421,18 @@ impl MergeMethod {
 /// The status of auto merging a pull request.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutoMerge {
+    /**
+     * Commit message for the merge commit.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub commit_message: String,
+    /**
+     * Title for the merge commit message.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -14561,7 +15623,7 @@ pub struct PullRequestSimple {
     )]
     pub diff_url: String,
     /**
-     * Pull Request Simple
+     * Indicates whether or not the pull request is a draft.
      */
     #[serde(
         default,
@@ -14637,9 +15699,6 @@ pub struct PullRequestSimple {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub requested_reviewers: Vec<SimpleUser>,
-    /**
-     * Pull Request Simple
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -14895,9 +15954,6 @@ pub struct CommunityHealthFile {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CommunityProfileFiles {
-    /**
-     * Code of Conduct Simple
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub code_of_conduct: Option<CodeOfConductSimple>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -14917,9 +15973,6 @@ pub struct CommunityProfileFiles {
 /// Community Profile
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CommunityProfile {
-    /**
-     * Community Profile
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -15025,6 +16078,7 @@ pub struct DiffEntry {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CommitComparisonStatus {
     #[serde(rename = "ahead")]
     Ahead,
@@ -15036,8 +16090,9 @@ pub enum CommitComparisonStatus {
     Identical,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CommitComparisonStatus {
@@ -15048,12 +16103,34 @@ impl std::fmt::Display for CommitComparisonStatus {
             CommitComparisonStatus::Diverged => "diverged",
             CommitComparisonStatus::Identical => "identical",
             CommitComparisonStatus::Noop => "",
-            CommitComparisonStatus::FallthroughString => "*",
+            CommitComparisonStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CommitComparisonStatus {
+    fn from(s: String) -> CommitComparisonStatus {
+        match s.as_str() {
+            "ahead" => CommitComparisonStatus::Ahead,
+            "behind" => CommitComparisonStatus::Behind,
+            "diverged" => CommitComparisonStatus::Diverged,
+            "identical" => CommitComparisonStatus::Identical,
+            "" => CommitComparisonStatus::Noop,
+            _ => CommitComparisonStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CommitComparisonStatus> for String {
+    fn from(e: CommitComparisonStatus) -> String {
+        match e {
+            CommitComparisonStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CommitComparisonStatus {
     fn default() -> CommitComparisonStatus {
         CommitComparisonStatus::Noop
@@ -15097,9 +16174,6 @@ pub struct CommitComparison {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub diff_url: String,
-    /**
-     * Commit Comparison
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -15148,24 +16222,36 @@ pub struct CommitComparison {
 /// Content Reference attachments allow you to provide context around URLs posted in comments
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContentReferenceAttachment {
+    /**
+     * The body of the attachment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * The ID of the attachment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The node_id of the content attachment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The title of the attachment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -15275,9 +16361,6 @@ pub struct ContentTree {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub download_url: String,
-    /**
-     * Content Tree
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -15759,9 +16842,6 @@ pub struct Contributor {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
-    /**
-     * Contributor
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -15804,9 +16884,6 @@ pub struct Contributor {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub repos_url: String,
-    /**
-     * Contributor
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -15843,6 +16920,7 @@ pub struct Contributor {
 * The state of the status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DeploymentStatusState {
     #[serde(rename = "error")]
     Error,
@@ -15860,8 +16938,9 @@ pub enum DeploymentStatusState {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeploymentStatusState {
@@ -15875,12 +16954,37 @@ impl std::fmt::Display for DeploymentStatusState {
             DeploymentStatusState::Queued => "queued",
             DeploymentStatusState::Success => "success",
             DeploymentStatusState::Noop => "",
-            DeploymentStatusState::FallthroughString => "*",
+            DeploymentStatusState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DeploymentStatusState {
+    fn from(s: String) -> DeploymentStatusState {
+        match s.as_str() {
+            "error" => DeploymentStatusState::Error,
+            "failure" => DeploymentStatusState::Failure,
+            "in_progress" => DeploymentStatusState::InProgress,
+            "inactive" => DeploymentStatusState::Inactive,
+            "pending" => DeploymentStatusState::Pending,
+            "queued" => DeploymentStatusState::Queued,
+            "success" => DeploymentStatusState::Success,
+            "" => DeploymentStatusState::Noop,
+            _ => DeploymentStatusState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DeploymentStatusState> for String {
+    fn from(e: DeploymentStatusState) -> String {
+        match e {
+            DeploymentStatusState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DeploymentStatusState {
     fn default() -> DeploymentStatusState {
         DeploymentStatusState::Noop
@@ -15909,18 +17013,27 @@ pub struct DeploymentStatus {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub deployment_url: String,
+    /**
+     * A short description of the status.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The environment of the deployment that the status is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub environment: String,
+    /**
+     * The URL for accessing your environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -15933,6 +17046,9 @@ pub struct DeploymentStatus {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The URL to associate with this status.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -15958,6 +17074,9 @@ pub struct DeploymentStatus {
      */
     #[serde(default, skip_serializing_if = "DeploymentStatusState::is_noop")]
     pub state: DeploymentStatusState,
+    /**
+     * Deprecated: the URL to associate with this status.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -15981,11 +17100,17 @@ pub struct DeploymentStatus {
 /// The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeploymentBranchPolicy {
+    /**
+     * Whether only branches that match the specified name patterns can deploy to this environment.  If `custom_branch_policies` is `true`, `protected_branches` must be `false`; if `custom_branch_policies` is `false`, `protected_branches` must be `true`.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub custom_branch_policies: bool,
+    /**
+     * Whether only branches with branch protection rules can deploy to this environment. If `protected_branches` is `true`, `custom_branch_policies` must be `false`; if `protected_branches` is `false`, `custom_branch_policies` must be `true`.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -16096,6 +17221,9 @@ pub struct ProtectionRulesAnyOf {
 /// Details of a deployment environment
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnvironmentData {
+    /**
+     * The time that the environment was created, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -16113,12 +17241,18 @@ pub struct EnvironmentData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The id of the environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The name of the environment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16131,15 +17265,15 @@ pub struct EnvironmentData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
-    /**
-     * Details of a deployment environment
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub protection_rules: Vec<ProtectionRulesAnyOf>,
+    /**
+     * The time that the environment was last updated, in ISO 8601 format.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -16204,18 +17338,27 @@ pub struct Blob {
 /// Identifying information for the git-user
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Committer {
+    /**
+     * Timestamp of the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Git email address of the user
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * Name of the git user
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16243,6 +17386,9 @@ pub struct GitCommit {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Message describing the purpose of the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16261,6 +17407,9 @@ pub struct GitCommit {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub parents: Vec<Parents>,
+    /**
+     * SHA for the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16281,6 +17430,9 @@ pub struct GitCommit {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Object {
+    /**
+     * SHA for the reference
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16331,6 +17483,9 @@ pub struct GitRef {
 /// Metadata for a Git tag
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitTag {
+    /**
+     * Message describing the purpose of the tag
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16351,6 +17506,9 @@ pub struct GitTag {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
+    /**
+     * Name of the tag
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16359,15 +17517,15 @@ pub struct GitTag {
     pub tag: String,
     #[serde()]
     pub tagger: Tagger,
+    /**
+     * URL for the tag
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
-    /**
-     * Metadata for a Git tag
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification: Option<Verification>,
 }
@@ -16529,6 +17687,9 @@ pub struct HookConfig {
 /// Webhooks for repositories.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Hook {
+    /**
+     * Determines whether the hook is actually triggered on pushes.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -16549,7 +17710,7 @@ pub struct Hook {
     )]
     pub deliveries_url: String,
     /**
-     * The list of events for the GitHub app
+     * Determines what events the hook is triggered for. Default: ['push'].
      */
     #[serde(
         default,
@@ -16557,6 +17718,9 @@ pub struct Hook {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub events: Vec<String>,
+    /**
+     * Unique identifier of the webhook.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -16565,6 +17729,9 @@ pub struct Hook {
     pub id: i64,
     #[serde()]
     pub last_response: HookResponse,
+    /**
+     * The name of a valid service, use 'web' for a webhook.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -16605,6 +17772,7 @@ pub struct Hook {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImportStatus {
     #[serde(rename = "auth")]
     Auth,
@@ -16634,14 +17802,13 @@ pub enum ImportStatus {
     Pushing,
     #[serde(rename = "setup")]
     Setup,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "waiting_to_push")]
     WaitingToPush,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImportStatus {
@@ -16661,15 +17828,47 @@ impl std::fmt::Display for ImportStatus {
             ImportStatus::None => "none",
             ImportStatus::Pushing => "pushing",
             ImportStatus::Setup => "setup",
-            ImportStatus::Unknown => "unknown",
             ImportStatus::WaitingToPush => "waiting_to_push",
             ImportStatus::Noop => "",
-            ImportStatus::FallthroughString => "*",
+            ImportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ImportStatus {
+    fn from(s: String) -> ImportStatus {
+        match s.as_str() {
+            "auth" => ImportStatus::Auth,
+            "auth_failed" => ImportStatus::AuthFailed,
+            "choose" => ImportStatus::Choose,
+            "complete" => ImportStatus::Complete,
+            "detecting" => ImportStatus::Detecting,
+            "detection_found_multiple" => ImportStatus::DetectionFoundMultiple,
+            "detection_found_nothing" => ImportStatus::DetectionFoundNothing,
+            "detection_needs_auth" => ImportStatus::DetectionNeedsAuth,
+            "error" => ImportStatus::Error,
+            "importing" => ImportStatus::Importing,
+            "mapping" => ImportStatus::Mapping,
+            "none" => ImportStatus::None,
+            "pushing" => ImportStatus::Pushing,
+            "setup" => ImportStatus::Setup,
+            "waiting_to_push" => ImportStatus::WaitingToPush,
+            "" => ImportStatus::Noop,
+            _ => ImportStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ImportStatus> for String {
+    fn from(e: ImportStatus) -> String {
+        match e {
+            ImportStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ImportStatus {
     fn default() -> ImportStatus {
         ImportStatus::Noop
@@ -16706,9 +17905,6 @@ pub struct ProjectChoices {
 /// A repository import from an external source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Import {
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -16721,9 +17917,6 @@ pub struct Import {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub authors_url: String,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -16742,9 +17935,6 @@ pub struct Import {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub failed_step: String,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -16756,27 +17946,18 @@ pub struct Import {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub import_percent: i64,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub large_files_count: i64,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -16789,18 +17970,12 @@ pub struct Import {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub project_choices: Vec<ProjectChoices>,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -16845,9 +18020,6 @@ pub struct Import {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
-    /**
-     * A repository import from an external source.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -16859,6 +18031,9 @@ pub struct Import {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs: String,
+    /**
+     * The URL of the originating repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -17066,7 +18241,7 @@ pub struct IssueEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assigner: Option<SimpleUser>,
     /**
-     * Issue Event
+     * How the author is associated with the repository.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author_association: Option<AuthorAssociation>,
@@ -17088,9 +18263,6 @@ pub struct IssueEvent {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Issue Event
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dismissed_review: Option<DismissedReview>,
     #[serde(
@@ -17106,12 +18278,12 @@ pub struct IssueEvent {
     )]
     pub id: i64,
     /**
-     * Issue Event
+     * Issue Simple
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub issue: Option<IssueSimple>,
     /**
-     * Issue Event
+     * Issue Event Label
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub label: Option<IssueEventLabel>,
@@ -17122,7 +18294,7 @@ pub struct IssueEvent {
     )]
     pub lock_reason: String,
     /**
-     * Issue Event
+     * Issue Event Milestone
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub milestone: Option<IssueEventMilestone>,
@@ -17135,19 +18307,16 @@ pub struct IssueEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
     /**
-     * Issue Event
+     * Issue Event Project Card
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_card: Option<IssueEventProjectCard>,
-    /**
-     * Issue Event
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rename: Option<Rename>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requested_reviewer: Option<SimpleUser>,
     /**
-     * Issue Event
+     * Groups of organization members that gives permissions on specified repositories.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requested_team: Option<Team>,
@@ -17199,9 +18368,6 @@ pub struct LabeledIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * Issue Event Label
-     */
     #[serde()]
     pub label: IssueEventLabel,
     #[serde(
@@ -17328,9 +18494,6 @@ pub struct MilestonedIssueEvent {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * Issue Event Milestone
-     */
     #[serde()]
     pub milestone: IssueEventMilestone,
     #[serde(
@@ -17466,7 +18629,7 @@ pub struct ReviewRequestedIssueEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requested_reviewer: Option<SimpleUser>,
     /**
-     * Review Requested Issue Event
+     * Groups of organization members that gives permissions on specified repositories.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requested_team: Option<Team>,
@@ -17538,7 +18701,7 @@ pub struct ReviewRequestRemovedIssueEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requested_reviewer: Option<SimpleUser>,
     /**
-     * Review Request Removed Issue Event
+     * Groups of organization members that gives permissions on specified repositories.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requested_team: Option<Team>,
@@ -17726,9 +18889,6 @@ pub struct AddedProjectIssueEvent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Added to Project Issue Event
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_card: Option<IssueEventProjectCard>,
     #[serde(
@@ -17788,9 +18948,6 @@ pub struct MovedColumnInProjectIssueEvent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Moved Column in Project Issue Event
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_card: Option<IssueEventProjectCard>,
     #[serde(
@@ -17850,9 +19007,6 @@ pub struct RemovedFromProjectIssueEvent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Removed from Project Issue Event
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_card: Option<IssueEventProjectCard>,
     #[serde(
@@ -17912,9 +19066,6 @@ pub struct ConvertedNoteIssueEvent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Converted Note to Issue Issue Event
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub project_card: Option<IssueEventProjectCard>,
     #[serde(
@@ -18020,6 +19171,9 @@ pub struct TimelineCommentEvent {
      */
     #[serde()]
     pub author_association: AuthorAssociation,
+    /**
+     * Contents of the issue comment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18056,6 +19210,9 @@ pub struct TimelineCommentEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the issue comment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -18079,9 +19236,6 @@ pub struct TimelineCommentEvent {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Timeline Comment Event
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     #[serde(
@@ -18090,6 +19244,9 @@ pub struct TimelineCommentEvent {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * URL for the issue comment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18174,6 +19331,9 @@ pub struct TimelineCommittedEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Message describing the purpose of the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18192,6 +19352,9 @@ pub struct TimelineCommittedEvent {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub parents: Vec<Parents>,
+    /**
+     * SHA for the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18212,14 +19375,8 @@ pub struct TimelineCommittedEvent {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimelineReviewedEventLinks {
-    /**
-     * Hypermedia Link
-     */
     #[serde()]
     pub html: Link,
-    /**
-     * Hypermedia Link
-     */
     #[serde()]
     pub pull_request: Link,
 }
@@ -18234,6 +19391,9 @@ pub struct TimelineReviewedEvent {
      */
     #[serde()]
     pub author_association: AuthorAssociation,
+    /**
+     * The text of the review.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18252,6 +19412,9 @@ pub struct TimelineReviewedEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body_text: String,
+    /**
+     * A commit SHA for the review.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18270,6 +19433,9 @@ pub struct TimelineReviewedEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the review
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -18294,9 +19460,6 @@ pub struct TimelineReviewedEvent {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * Timeline Reviewed Event
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -18312,19 +19475,10 @@ pub struct TimelineReviewedEvent {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullRequestReviewCommentLinks {
-    /**
-     * Hypermedia Link
-     */
     #[serde()]
     pub html: Link,
-    /**
-     * Hypermedia Link
-     */
     #[serde()]
     pub pull_request: Link,
-    /**
-     * Hypermedia Link
-     */
     #[serde(rename = "self")]
     pub self_: Link,
 }
@@ -18333,13 +19487,15 @@ pub struct PullRequestReviewCommentLinks {
 * The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Side {
     #[serde(rename = "LEFT")]
     Left,
     #[serde(rename = "RIGHT")]
     Right,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Side {
@@ -18347,12 +19503,31 @@ impl std::fmt::Display for Side {
         match self {
             Side::Left => "LEFT",
             Side::Right => "RIGHT",
-            Side::FallthroughString => "*",
+            Side::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Side {
+    fn from(s: String) -> Side {
+        match s.as_str() {
+            "LEFT" => Side::Left,
+            "RIGHT" => Side::Right,
+            _ => Side::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Side> for String {
+    fn from(e: Side) -> String {
+        match e {
+            Side::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Side {
     fn default() -> Side {
         Side::Right
@@ -18369,6 +19544,9 @@ pub struct PullRequestReviewComment {
      */
     #[serde()]
     pub author_association: AuthorAssociation,
+    /**
+     * The text of the comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18387,6 +19565,9 @@ pub struct PullRequestReviewComment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body_text: String,
+    /**
+     * The SHA of the commit to which the comment applies.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18399,18 +19580,27 @@ pub struct PullRequestReviewComment {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The diff of the line that the comment refers to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub diff_hunk: String,
+    /**
+     * HTML URL for the pull request review comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * The ID of the pull request review comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -18418,7 +19608,7 @@ pub struct PullRequestReviewComment {
     )]
     pub id: i64,
     /**
-     * Pull Request Review Comments are comments on a portion of the Pull Request's diff.
+     * The comment ID to reply to.
      */
     #[serde(
         default,
@@ -18427,7 +19617,7 @@ pub struct PullRequestReviewComment {
     )]
     pub in_reply_to_id: i64,
     /**
-     * Pull Request Review Comments are comments on a portion of the Pull Request's diff.
+     * The line of the blob to which the comment applies. The last line of the range for a multi-line comment
      */
     #[serde(
         default,
@@ -18435,12 +19625,18 @@ pub struct PullRequestReviewComment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub line: i64,
+    /**
+     * The node ID of the pull request review comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * The SHA of the original commit to which the comment applies.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18448,7 +19644,7 @@ pub struct PullRequestReviewComment {
     )]
     pub original_commit_id: String,
     /**
-     * Pull Request Review Comments are comments on a portion of the Pull Request's diff.
+     * The line of the blob to which the comment applies. The last line of the range for a multi-line comment
      */
     #[serde(
         default,
@@ -18456,6 +19652,9 @@ pub struct PullRequestReviewComment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub original_line: i64,
+    /**
+     * The index of the original line in the diff to which the comment applies.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -18463,7 +19662,7 @@ pub struct PullRequestReviewComment {
     )]
     pub original_position: i64,
     /**
-     * Pull Request Review Comments are comments on a portion of the Pull Request's diff.
+     * The first line of the range for a multi-line comment.
      */
     #[serde(
         default,
@@ -18471,42 +19670,51 @@ pub struct PullRequestReviewComment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub original_start_line: i64,
+    /**
+     * The relative path of the file to which the comment applies.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /**
+     * The line index in the diff to which the comment applies.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub position: i64,
+    /**
+     * The ID of the pull request review to which the comment belongs.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub pull_request_review_id: i64,
+    /**
+     * URL for the pull request that the review comment belongs to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pull_request_url: String,
-    /**
-     * Pull Request Review Comments are comments on a portion of the Pull Request's diff.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     /**
-     * Pull Request Review Comments are comments on a portion of the Pull Request's diff.
+     * The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub side: Option<Side>,
     /**
-     * Pull Request Review Comments are comments on a portion of the Pull Request's diff.
+     * The first line of the range for a multi-line comment.
      */
     #[serde(
         default,
@@ -18525,6 +19733,9 @@ pub struct PullRequestReviewComment {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * URL for the pull request review comment
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18541,9 +19752,6 @@ pub struct PullRequestReviewComment {
 /// Timeline Line Commented Event
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimelineLineCommentedEvent {
-    /**
-     * Timeline Line Commented Event
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -18567,9 +19775,6 @@ pub struct TimelineLineCommentedEvent {
 /// Timeline Commit Commented Event
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimelineCommitCommentedEvent {
-    /**
-     * Timeline Commit Commented Event
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -18796,6 +20001,7 @@ pub struct PagesSourceHash {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PagesHttpsCertificateState {
     #[serde(rename = "approved")]
     Approved,
@@ -18823,8 +20029,9 @@ pub enum PagesHttpsCertificateState {
     Uploaded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PagesHttpsCertificateState {
@@ -18843,12 +20050,42 @@ impl std::fmt::Display for PagesHttpsCertificateState {
             PagesHttpsCertificateState::New => "new",
             PagesHttpsCertificateState::Uploaded => "uploaded",
             PagesHttpsCertificateState::Noop => "",
-            PagesHttpsCertificateState::FallthroughString => "*",
+            PagesHttpsCertificateState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PagesHttpsCertificateState {
+    fn from(s: String) -> PagesHttpsCertificateState {
+        match s.as_str() {
+            "approved" => PagesHttpsCertificateState::Approved,
+            "authorization_created" => PagesHttpsCertificateState::AuthorizationCreated,
+            "authorization_pending" => PagesHttpsCertificateState::AuthorizationPending,
+            "authorization_revoked" => PagesHttpsCertificateState::AuthorizationRevoked,
+            "authorized" => PagesHttpsCertificateState::Authorized,
+            "bad_authz" => PagesHttpsCertificateState::BadAuthz,
+            "destroy_pending" => PagesHttpsCertificateState::DestroyPending,
+            "dns_changed" => PagesHttpsCertificateState::DnsChanged,
+            "errored" => PagesHttpsCertificateState::Errored,
+            "issued" => PagesHttpsCertificateState::Issued,
+            "new" => PagesHttpsCertificateState::New,
+            "uploaded" => PagesHttpsCertificateState::Uploaded,
+            "" => PagesHttpsCertificateState::Noop,
+            _ => PagesHttpsCertificateState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PagesHttpsCertificateState> for String {
+    fn from(e: PagesHttpsCertificateState) -> String {
+        match e {
+            PagesHttpsCertificateState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PagesHttpsCertificateState {
     fn default() -> PagesHttpsCertificateState {
         PagesHttpsCertificateState::Noop
@@ -18869,7 +20106,7 @@ pub struct PagesHttpsCertificate {
     )]
     pub description: String,
     /**
-     * The list of events for the GitHub app
+     * Array of the domain set and its alternate name (if it is configured)
      */
     #[serde(
         default,
@@ -18891,6 +20128,7 @@ pub struct PagesHttpsCertificate {
 * The status of the most recent build of the Page.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PageStatus {
     #[serde(rename = "building")]
     Building,
@@ -18900,8 +20138,9 @@ pub enum PageStatus {
     Errored,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PageStatus {
@@ -18911,12 +20150,33 @@ impl std::fmt::Display for PageStatus {
             PageStatus::Built => "built",
             PageStatus::Errored => "errored",
             PageStatus::Noop => "",
-            PageStatus::FallthroughString => "*",
+            PageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PageStatus {
+    fn from(s: String) -> PageStatus {
+        match s.as_str() {
+            "building" => PageStatus::Building,
+            "built" => PageStatus::Built,
+            "errored" => PageStatus::Errored,
+            "" => PageStatus::Noop,
+            _ => PageStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PageStatus> for String {
+    fn from(e: PageStatus) -> String {
+        match e {
+            PageStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PageStatus {
     fn default() -> PageStatus {
         PageStatus::Noop
@@ -18931,17 +20191,26 @@ impl PageStatus {
 /// The configuration for GitHub Pages for a repository.
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Page {
+    /**
+     * The Pages site's custom domain
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cname: String,
+    /**
+     * Whether the Page has a custom 404 page.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub custom_404: bool,
+    /**
+     * The web address the Page can be accessed from.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -18950,11 +20219,17 @@ pub struct Page {
     pub html_url: String,
     #[serde()]
     pub https_certificate: PagesHttpsCertificate,
+    /**
+     * Whether https is enabled on the domain
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub https_enforced: bool,
+    /**
+     * Whether the GitHub Pages site is publicly visible. If set to `true`, the site is accessible to anyone on the internet. If set to `false`, the site will only be accessible to users who have at least `read` access to the repository that published the site.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -18967,6 +20242,9 @@ pub struct Page {
      */
     #[serde(default, skip_serializing_if = "PageStatus::is_noop")]
     pub status: PageStatus,
+    /**
+     * The API address for accessing this Page resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -19352,9 +20630,6 @@ pub struct AltDomain {
 pub struct PagesHealthCheck {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub alt_domain: Option<AltDomain>,
-    /**
-     * Pages Health Check Status
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<Domain>,
 }
@@ -20380,9 +21655,6 @@ pub struct PullRequestBaseRepo {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub temp_clone_token: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -20546,7 +21818,7 @@ pub struct PullRequestData {
     )]
     pub diff_url: String,
     /**
-     * Pull requests let you tell others about changes you've pushed to a repository on GitHub. Once a pull request is sent, interested parties can review the set of changes, discuss potential modifications, and even push follow-up commits if necessary.
+     * Indicates whether or not the pull request is a draft.
      */
     #[serde(
         default,
@@ -20584,6 +21856,9 @@ pub struct PullRequestData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub locked: bool,
+    /**
+     * Indicates whether maintainers can modify the pull request.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -20627,6 +21902,9 @@ pub struct PullRequestData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * Number uniquely identifying the pull request within its repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -20639,9 +21917,6 @@ pub struct PullRequestData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub patch_url: String,
-    /**
-     * Pull requests let you tell others about changes you've pushed to a repository on GitHub. Once a pull request is sent, interested parties can review the set of changes, discuss potential modifications, and even push follow-up commits if necessary.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -20678,7 +21953,7 @@ pub struct PullRequestData {
     )]
     pub review_comments_url: String,
     /**
-     * The state of the milestone.
+     * State of this Pull Request. Either `open` or `closed`.
      */
     #[serde(default)]
     pub state: State,
@@ -20688,6 +21963,9 @@ pub struct PullRequestData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statuses_url: String,
+    /**
+     * The title of the pull request.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -20735,9 +22013,6 @@ pub struct PullRequestMergeResult {
 /// Pull Request Review Request
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullRequestReview {
-    /**
-     * The list of teams with review dismissal access.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -20762,6 +22037,9 @@ pub struct PullRequestReviewData {
      */
     #[serde()]
     pub author_association: AuthorAssociation,
+    /**
+     * The text of the review.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -20780,6 +22058,9 @@ pub struct PullRequestReviewData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body_text: String,
+    /**
+     * A commit SHA for the review.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -20792,6 +22073,9 @@ pub struct PullRequestReviewData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_url: String,
+    /**
+     * Unique identifier of the review
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -20816,9 +22100,6 @@ pub struct PullRequestReviewData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * Pull Request Reviews are reviews on pull requests.
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -20906,9 +22187,6 @@ pub struct ReviewComment {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * Legacy Review Comment
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -20916,7 +22194,7 @@ pub struct ReviewComment {
     )]
     pub in_reply_to_id: i64,
     /**
-     * Legacy Review Comment
+     * The line of the blob to which the comment applies. The last line of the range for a multi-line comment
      */
     #[serde(
         default,
@@ -20937,7 +22215,7 @@ pub struct ReviewComment {
     )]
     pub original_commit_id: String,
     /**
-     * Legacy Review Comment
+     * The original line of the blob to which the comment applies. The last line of the range for a multi-line comment
      */
     #[serde(
         default,
@@ -20952,7 +22230,7 @@ pub struct ReviewComment {
     )]
     pub original_position: i64,
     /**
-     * Legacy Review Comment
+     * The original first line of the range for a multi-line comment.
      */
     #[serde(
         default,
@@ -20984,18 +22262,15 @@ pub struct ReviewComment {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pull_request_url: String,
-    /**
-     * Legacy Review Comment
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
     /**
-     * Legacy Review Comment
+     * The side of the first line of the range for a multi-line comment.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub side: Option<Side>,
     /**
-     * Legacy Review Comment
+     * The first line of the range for a multi-line comment.
      */
     #[serde(
         default,
@@ -21004,7 +22279,7 @@ pub struct ReviewComment {
     )]
     pub start_line: i64,
     /**
-     * Legacy Review Comment
+     * The side of the first line of the range for a multi-line comment.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_side: Option<Side>,
@@ -21028,6 +22303,7 @@ pub struct ReviewComment {
 * State of the release asset.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReleaseAssetState {
     #[serde(rename = "open")]
     Open,
@@ -21035,8 +22311,9 @@ pub enum ReleaseAssetState {
     Uploaded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReleaseAssetState {
@@ -21045,12 +22322,32 @@ impl std::fmt::Display for ReleaseAssetState {
             ReleaseAssetState::Open => "open",
             ReleaseAssetState::Uploaded => "uploaded",
             ReleaseAssetState::Noop => "",
-            ReleaseAssetState::FallthroughString => "*",
+            ReleaseAssetState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReleaseAssetState {
+    fn from(s: String) -> ReleaseAssetState {
+        match s.as_str() {
+            "open" => ReleaseAssetState::Open,
+            "uploaded" => ReleaseAssetState::Uploaded,
+            "" => ReleaseAssetState::Noop,
+            _ => ReleaseAssetState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReleaseAssetState> for String {
+    fn from(e: ReleaseAssetState) -> String {
+        match e {
+            ReleaseAssetState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReleaseAssetState {
     fn default() -> ReleaseAssetState {
         ReleaseAssetState::Noop
@@ -21101,6 +22398,9 @@ pub struct ReleaseAsset {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub label: String,
+    /**
+     * The file name of the asset.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -21184,12 +22484,18 @@ pub struct Release {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The URL of the release discussion.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub discussion_url: String,
+    /**
+     * true to create a draft (unpublished) release, false to create a published one.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -21207,9 +22513,6 @@ pub struct Release {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * A release.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -21228,6 +22531,9 @@ pub struct Release {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub node_id: String,
+    /**
+     * Whether to identify the release as a prerelease or a full release.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -21239,11 +22545,11 @@ pub struct Release {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub published_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * A release.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reactions: Option<ReactionRollup>,
+    /**
+     * The name of the tag.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -21256,6 +22562,9 @@ pub struct Release {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tarball_url: String,
+    /**
+     * Specifies the commitish value that determines where the Git tag is created from.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -21286,6 +22595,7 @@ pub struct Release {
 * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SecretScanningAlertState {
     #[serde(rename = "open")]
     Open,
@@ -21293,8 +22603,9 @@ pub enum SecretScanningAlertState {
     Resolved,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertState {
@@ -21303,12 +22614,32 @@ impl std::fmt::Display for SecretScanningAlertState {
             SecretScanningAlertState::Open => "open",
             SecretScanningAlertState::Resolved => "resolved",
             SecretScanningAlertState::Noop => "",
-            SecretScanningAlertState::FallthroughString => "*",
+            SecretScanningAlertState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SecretScanningAlertState {
+    fn from(s: String) -> SecretScanningAlertState {
+        match s.as_str() {
+            "open" => SecretScanningAlertState::Open,
+            "resolved" => SecretScanningAlertState::Resolved,
+            "" => SecretScanningAlertState::Noop,
+            _ => SecretScanningAlertState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SecretScanningAlertState> for String {
+    fn from(e: SecretScanningAlertState) -> String {
+        match e {
+            SecretScanningAlertState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SecretScanningAlertState {
     fn default() -> SecretScanningAlertState {
         SecretScanningAlertState::Noop
@@ -21324,6 +22655,7 @@ impl SecretScanningAlertState {
 * **Required when the `state` is `resolved`.** The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SecretScanningAlertResolution {
     #[serde(rename = "false_positive")]
     FalsePositive,
@@ -21335,8 +22667,9 @@ pub enum SecretScanningAlertResolution {
     WontFix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SecretScanningAlertResolution {
@@ -21347,12 +22680,34 @@ impl std::fmt::Display for SecretScanningAlertResolution {
             SecretScanningAlertResolution::UsedInTests => "used_in_tests",
             SecretScanningAlertResolution::WontFix => "wont_fix",
             SecretScanningAlertResolution::Noop => "",
-            SecretScanningAlertResolution::FallthroughString => "*",
+            SecretScanningAlertResolution::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SecretScanningAlertResolution {
+    fn from(s: String) -> SecretScanningAlertResolution {
+        match s.as_str() {
+            "false_positive" => SecretScanningAlertResolution::FalsePositive,
+            "revoked" => SecretScanningAlertResolution::Revoked,
+            "used_in_tests" => SecretScanningAlertResolution::UsedInTests,
+            "wont_fix" => SecretScanningAlertResolution::WontFix,
+            "" => SecretScanningAlertResolution::Noop,
+            _ => SecretScanningAlertResolution::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SecretScanningAlertResolution> for String {
+    fn from(e: SecretScanningAlertResolution) -> String {
+        match e {
+            SecretScanningAlertResolution::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SecretScanningAlertResolution {
     fn default() -> SecretScanningAlertResolution {
         SecretScanningAlertResolution::Noop
@@ -21389,6 +22744,9 @@ pub struct SecretScanningAlert {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resolution: Option<SecretScanningAlertResolution>,
+    /**
+     * The time that the alert was resolved in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -21400,12 +22758,18 @@ pub struct SecretScanningAlert {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resolved_by: Option<SimpleUser>,
+    /**
+     * The secret that was detected.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub secret: String,
+    /**
+     * The type of secret that secret scanning detected.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -21441,9 +22805,6 @@ pub struct Stargazer {
 /// Commit Activity
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CommitActivity {
-    /**
-     * Code Frequency Stat
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -21513,18 +22874,12 @@ pub struct ContributorActivity {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ParticipationStats {
-    /**
-     * Code Frequency Stat
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub all: Vec<i64>,
-    /**
-     * Code Frequency Stat
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -21542,6 +22897,9 @@ pub struct RepositorySubscription {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Determines if all notifications should be blocked from this repository.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -21559,6 +22917,9 @@ pub struct RepositorySubscription {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub repository_url: String,
+    /**
+     * Determines if notifications should be received from this repository.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -21606,9 +22967,6 @@ pub struct Tag {
 /// A topic aggregates entities that are related to a subject.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Topic {
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -21820,9 +23178,6 @@ pub struct ScimEnterpriseGroup {
     pub members: Vec<Members>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<Meta>,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -21847,9 +23202,6 @@ pub struct ScimGroupListEnterprise {
         rename = "itemsPerPage"
     )]
     pub items_per_page: f64,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -21958,9 +23310,6 @@ pub struct ScimEnterpriseUser {
     pub meta: Option<Meta>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<Name>,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -21992,9 +23341,6 @@ pub struct ScimUserListEnterprise {
         rename = "itemsPerPage"
     )]
     pub items_per_page: f64,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -22087,6 +23433,7 @@ pub struct ScimUserMeta {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Op {
     #[serde(rename = "add")]
     Add,
@@ -22096,8 +23443,9 @@ pub enum Op {
     Replace,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Op {
@@ -22107,12 +23455,33 @@ impl std::fmt::Display for Op {
             Op::Remove => "remove",
             Op::Replace => "replace",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Op {
+    fn from(s: String) -> Op {
+        match s.as_str() {
+            "add" => Op::Add,
+            "remove" => Op::Remove,
+            "replace" => Op::Replace,
+            "" => Op::Noop,
+            _ => Op::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Op> for String {
+    fn from(e: Op) -> String {
+        match e {
+            Op::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Op {
     fn default() -> Op {
         Op::Noop
@@ -22207,11 +23576,17 @@ pub struct Operations {
 /// SCIM /Users provisioning endpoints
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ScimUser {
+    /**
+     * The active status of the User.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub active: bool,
+    /**
+     * The name of the user, suitable for display to end-users
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -22228,6 +23603,9 @@ pub struct ScimUser {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub emails: Vec<ScimUserEmails>,
+    /**
+     * The ID of the User.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -22236,7 +23614,7 @@ pub struct ScimUser {
     )]
     pub external_id: String,
     /**
-     * SCIM /Users provisioning endpoints
+     * associated groups
      */
     #[serde(
         default,
@@ -22244,6 +23622,9 @@ pub struct ScimUser {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub groups: Vec<String>,
+    /**
+     * Unique identifier of an external identity
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -22255,7 +23636,7 @@ pub struct ScimUser {
     #[serde()]
     pub name: ScimUserName,
     /**
-     * SCIM /Users provisioning endpoints
+     * Set of operations to be performed
      */
     #[serde(
         default,
@@ -22264,7 +23645,7 @@ pub struct ScimUser {
     )]
     pub operations: Vec<Operations>,
     /**
-     * SCIM /Users provisioning endpoints
+     * The ID of the organization.
      */
     #[serde(
         default,
@@ -22273,7 +23654,7 @@ pub struct ScimUser {
     )]
     pub organization_id: i64,
     /**
-     * The list of events for the GitHub app
+     * SCIM schema used.
      */
     #[serde(
         default,
@@ -22281,6 +23662,9 @@ pub struct ScimUser {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /**
+     * Configured by the admin. Could be an email, login, or username
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -22308,7 +23692,7 @@ pub struct ScimUserList {
     )]
     pub items_per_page: i64,
     /**
-     * The list of events for the GitHub app
+     * SCIM schema used.
      */
     #[serde(
         default,
@@ -22334,9 +23718,6 @@ pub struct ScimUserList {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Matches {
-    /**
-     * Code Frequency Stat
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -22388,9 +23769,6 @@ pub struct SearchResultTextMatches {
 /// Code Search Result Item
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CodeSearchResultItem {
-    /**
-     * Code Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -22415,18 +23793,12 @@ pub struct CodeSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub language: String,
-    /**
-     * Code Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_modified_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Code Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -22462,9 +23834,6 @@ pub struct CodeSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
-    /**
-     * Code Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -22481,9 +23850,6 @@ pub struct CodeSearchResultItem {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CommitSearchResultItem {
-    /**
-     * Identifying information for the git-user
-     */
     #[serde()]
     pub author: Committer,
     #[serde(
@@ -22562,9 +23928,6 @@ pub struct CommitSearchResultItemData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sha: String,
-    /**
-     * Commit Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -22643,9 +24006,6 @@ pub struct IssueSearchResultItem {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Issue Search Result Item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -22702,13 +24062,10 @@ pub struct IssueSearchResultItem {
     pub number: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub performed_via_github_app: Option<GitHubApp>,
-    /**
-     * Issue Search Result Item
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pull_request: Option<PullRequest>,
     /**
-     * Issue Search Result Item
+     * A git repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repository: Option<Repository>,
@@ -22730,9 +24087,6 @@ pub struct IssueSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * Issue Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -22811,9 +24165,6 @@ pub struct LabelSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub score: f64,
-    /**
-     * Label Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -22831,33 +24182,21 @@ pub struct LabelSearchResultItem {
 /// Repo Search Result Item
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RepoSearchResultItem {
-    /**
-     * Repo Search Result Item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_auto_merge: bool,
-    /**
-     * Repo Search Result Item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_merge_commit: bool,
-    /**
-     * Repo Search Result Item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_rebase_merge: bool,
-    /**
-     * Repo Search Result Item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -22946,9 +24285,6 @@ pub struct RepoSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub default_branch: String,
-    /**
-     * Repo Search Result Item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -22966,6 +24302,9 @@ pub struct RepoSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Returns whether or not this repository disabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -23185,9 +24524,6 @@ pub struct RepoSearchResultItem {
     pub open_issues_count: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner: Option<SimpleUser>,
-    /**
-     * Repo Search Result Item
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<FullRepositoryPermissions>,
     #[serde(
@@ -23285,18 +24621,12 @@ pub struct RepoSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub temp_clone_token: String,
-    /**
-     * Repo Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub text_matches: Vec<SearchResultTextMatches>,
-    /**
-     * Repo Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -23436,9 +24766,6 @@ pub struct TopicSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub released: String,
-    /**
-     * Topic Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -23457,9 +24784,6 @@ pub struct TopicSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub short_description: String,
-    /**
-     * Topic Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -23501,9 +24825,6 @@ pub struct UserSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub company: String,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -23522,9 +24843,6 @@ pub struct UserSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub events_url: String,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -23537,9 +24855,6 @@ pub struct UserSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub followers_url: String,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -23564,9 +24879,6 @@ pub struct UserSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub gravatar_id: String,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -23614,18 +24926,12 @@ pub struct UserSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organizations_url: String,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub public_gists: i64,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -23667,18 +24973,12 @@ pub struct UserSearchResultItem {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subscriptions_url: String,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub suspended_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -23692,9 +24992,6 @@ pub struct UserSearchResultItem {
         rename = "type"
     )]
     pub type_: String,
-    /**
-     * User Search Result Item
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -23730,9 +25027,6 @@ pub struct PrivateUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub blog: String,
-    /**
-     * Private User
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -23869,9 +25163,6 @@ pub struct PrivateUser {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub owned_private_repos: i64,
-    /**
-     * Private User
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan: Option<Plan>,
     #[serde(
@@ -23921,9 +25212,6 @@ pub struct PrivateUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subscriptions_url: String,
-    /**
-     * Private User
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -24038,9 +25326,6 @@ pub struct Subkeys {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub created_at: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -24083,9 +25368,6 @@ pub struct Subkeys {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub raw_key: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -24382,6 +25664,7 @@ pub struct KeySimple {
 *   The default is `web`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Include {
     #[serde(rename = "all")]
     All,
@@ -24391,8 +25674,9 @@ pub enum Include {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Include {
@@ -24402,12 +25686,33 @@ impl std::fmt::Display for Include {
             Include::Git => "git",
             Include::Web => "web",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Include {
+    fn from(s: String) -> Include {
+        match s.as_str() {
+            "all" => Include::All,
+            "git" => Include::Git,
+            "web" => Include::Web,
+            "" => Include::Noop,
+            _ => Include::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Include> for String {
+    fn from(e: Include) -> String {
+        match e {
+            Include::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Include {
     fn default() -> Include {
         Include::Noop
@@ -24425,6 +25730,7 @@ impl Include {
 *   The default is `desc`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Order {
     #[serde(rename = "asc")]
     Asc,
@@ -24432,8 +25738,9 @@ pub enum Order {
     Desc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Order {
@@ -24442,12 +25749,32 @@ impl std::fmt::Display for Order {
             Order::Asc => "asc",
             Order::Desc => "desc",
             Order::Noop => "",
-            Order::FallthroughString => "*",
+            Order::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Order {
+    fn from(s: String) -> Order {
+        match s.as_str() {
+            "asc" => Order::Asc,
+            "desc" => Order::Desc,
+            "" => Order::Noop,
+            _ => Order::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Order> for String {
+    fn from(e: Order) -> String {
+        match e {
+            Order::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Order {
     fn default() -> Order {
         Order::Noop
@@ -24463,13 +25790,15 @@ impl Order {
 * One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Sort {
     #[serde(rename = "created")]
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Sort {
@@ -24477,12 +25806,31 @@ impl std::fmt::Display for Sort {
         match self {
             Sort::Created => "created",
             Sort::Updated => "updated",
-            Sort::FallthroughString => "*",
+            Sort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Sort {
+    fn from(s: String) -> Sort {
+        match s.as_str() {
+            "created" => Sort::Created,
+            "updated" => Sort::Updated,
+            _ => Sort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Sort> for String {
+    fn from(e: Sort) -> String {
+        match e {
+            Sort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Sort {
     fn default() -> Sort {
         Sort::Created
@@ -24493,6 +25841,7 @@ impl Default for Sort {
 * Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WorkflowRunStatus {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -24522,8 +25871,9 @@ pub enum WorkflowRunStatus {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowRunStatus {
@@ -24543,12 +25893,43 @@ impl std::fmt::Display for WorkflowRunStatus {
             WorkflowRunStatus::TimedOut => "timed_out",
             WorkflowRunStatus::Waiting => "waiting",
             WorkflowRunStatus::Noop => "",
-            WorkflowRunStatus::FallthroughString => "*",
+            WorkflowRunStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WorkflowRunStatus {
+    fn from(s: String) -> WorkflowRunStatus {
+        match s.as_str() {
+            "action_required" => WorkflowRunStatus::ActionRequired,
+            "cancelled" => WorkflowRunStatus::Cancelled,
+            "completed" => WorkflowRunStatus::Completed,
+            "failure" => WorkflowRunStatus::Failure,
+            "in_progress" => WorkflowRunStatus::InProgress,
+            "neutral" => WorkflowRunStatus::Neutral,
+            "queued" => WorkflowRunStatus::Queued,
+            "requested" => WorkflowRunStatus::Requested,
+            "skipped" => WorkflowRunStatus::Skipped,
+            "stale" => WorkflowRunStatus::Stale,
+            "success" => WorkflowRunStatus::Success,
+            "timed_out" => WorkflowRunStatus::TimedOut,
+            "waiting" => WorkflowRunStatus::Waiting,
+            "" => WorkflowRunStatus::Noop,
+            _ => WorkflowRunStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WorkflowRunStatus> for String {
+    fn from(e: WorkflowRunStatus) -> String {
+        match e {
+            WorkflowRunStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WorkflowRunStatus {
     fn default() -> WorkflowRunStatus {
         WorkflowRunStatus::Noop
@@ -24618,13 +25999,15 @@ impl std::convert::From<TitleOneOf> for String {
 * Must be one of: `day`, `week`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Per {
     #[serde(rename = "day")]
     Day,
     #[serde(rename = "week")]
     Week,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Per {
@@ -24632,12 +26015,31 @@ impl std::fmt::Display for Per {
         match self {
             Per::Day => "day",
             Per::Week => "week",
-            Per::FallthroughString => "*",
+            Per::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Per {
+    fn from(s: String) -> Per {
+        match s.as_str() {
+            "day" => Per::Day,
+            "week" => Per::Week,
+            _ => Per::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Per> for String {
+    fn from(e: Per) -> String {
+        match e {
+            Per::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Per {
     fn default() -> Per {
         Per::Day
@@ -25000,7 +26402,7 @@ pub struct AppsCreateInstallationAccessTokenRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<AppPermissions>,
     /**
-     * The list of events for the GitHub app
+     * List of repository names that the token should have access to
      */
     #[serde(
         default,
@@ -25009,7 +26411,7 @@ pub struct AppsCreateInstallationAccessTokenRequest {
     )]
     pub repositories: Vec<String>,
     /**
-     * Code Frequency Stat
+     * List of repository IDs that the token should have access to
      */
     #[serde(
         default,
@@ -25021,6 +26423,9 @@ pub struct AppsCreateInstallationAccessTokenRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppsCheckTokenRequest {
+    /**
+     * The OAuth access token used to authenticate to the GitHub API.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25031,6 +26436,9 @@ pub struct AppsCheckTokenRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppsScopeTokenRequest {
+    /**
+     * The OAuth access token used to authenticate to the GitHub API.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25043,7 +26451,7 @@ pub struct AppsScopeTokenRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<AppPermissions>,
     /**
-     * The list of events for the GitHub app
+     * The list of repository names to scope the user-to-server access token to. `repositories` may not be specified if `repository_ids` is specified.
      */
     #[serde(
         default,
@@ -25052,7 +26460,7 @@ pub struct AppsScopeTokenRequest {
     )]
     pub repositories: Vec<String>,
     /**
-     * Code Frequency Stat
+     * The list of repository IDs to scope the user-to-server access token to. `repository_ids` may not be specified if `repositories` is specified.
      */
     #[serde(
         default,
@@ -25060,12 +26468,18 @@ pub struct AppsScopeTokenRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub repository_ids: Vec<i64>,
+    /**
+     * The name of the user or organization to scope the user-to-server access token to. \*\*Required\*\* unless `target_id` is specified.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target: String,
+    /**
+     * The ID of the user or organization to scope the user-to-server access token to. \*\*Required\*\* unless `target` is specified.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -25076,30 +26490,45 @@ pub struct AppsScopeTokenRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OauthAuthorizationsCreateAuthorizationRequest {
+    /**
+     * The OAuth app client key for which to create the token.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_id: String,
+    /**
+     * The OAuth app client secret for which to create the token.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_secret: String,
+    /**
+     * A unique string to distinguish an authorization from others created for the same client ID and user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fingerprint: String,
+    /**
+     * A note to remind you what the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub note: String,
+    /**
+     * A URL to remind you what app the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25107,36 +26536,44 @@ pub struct OauthAuthorizationsCreateAuthorizationRequest {
     )]
     pub note_url: String,
     /**
-     * The list of events for the GitHub app
+     * A list of scopes that this authorization is in.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub scopes: crate::utils::Patch<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OauthAuthorizationsGetCreateAuthorizationAppRequest {
+    /**
+     * The OAuth app client secret for which to create the token.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_secret: String,
+    /**
+     * A unique string to distinguish an authorization from others created for the same client ID and user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fingerprint: String,
+    /**
+     * A note to remind you what the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub note: String,
+    /**
+     * A URL to remind you what app the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25144,30 +26581,35 @@ pub struct OauthAuthorizationsGetCreateAuthorizationAppRequest {
     )]
     pub note_url: String,
     /**
-     * The list of events for the GitHub app
+     * A list of scopes that this authorization is in.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub scopes: crate::utils::Patch<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest {
+    /**
+     * The OAuth app client secret for which to create the token.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_secret: String,
+    /**
+     * A note to remind you what the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub note: String,
+    /**
+     * A URL to remind you what app the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25175,20 +26617,16 @@ pub struct OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest {
     )]
     pub note_url: String,
     /**
-     * The list of events for the GitHub app
+     * A list of scopes that this authorization is in.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub scopes: crate::utils::Patch<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OauthAuthorizationsUpdateAuthorizationRequest {
     /**
-     * The list of events for the GitHub app
+     * A list of scopes to add to this authorization.
      */
     #[serde(
         default,
@@ -25196,18 +26634,27 @@ pub struct OauthAuthorizationsUpdateAuthorizationRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub add_scopes: Vec<String>,
+    /**
+     * A unique string to distinguish an authorization from others created for the same client ID and user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fingerprint: String,
+    /**
+     * A note to remind you what the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub note: String,
+    /**
+     * A URL to remind you what app the OAuth token is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25215,7 +26662,7 @@ pub struct OauthAuthorizationsUpdateAuthorizationRequest {
     )]
     pub note_url: String,
     /**
-     * The list of events for the GitHub app
+     * A list of scopes to remove from this authorization.
      */
     #[serde(
         default,
@@ -25224,14 +26671,10 @@ pub struct OauthAuthorizationsUpdateAuthorizationRequest {
     )]
     pub remove_scopes: Vec<String>,
     /**
-     * The list of events for the GitHub app
+     * A list of scopes that this authorization is in.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub scopes: crate::utils::Patch<Vec<String>>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -25267,7 +26710,7 @@ pub struct EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest {
     /**
-     * Code Frequency Stat
+     * List of organization IDs to enable for GitHub Actions.
      */
     #[serde(
         default,
@@ -25295,6 +26738,9 @@ pub struct EnterpriseAdminListSelfHostedRunnerGroupsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
+    /**
+     * Name of the runner group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25302,7 +26748,7 @@ pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
     )]
     pub name: String,
     /**
-     * Code Frequency Stat
+     * List of runner IDs to add to the runner group.
      */
     #[serde(
         default,
@@ -25311,7 +26757,7 @@ pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
     )]
     pub runners: Vec<i64>,
     /**
-     * Code Frequency Stat
+     * List of organization IDs that can access the runner group.
      */
     #[serde(
         default,
@@ -25320,7 +26766,7 @@ pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
     )]
     pub selected_organization_ids: Vec<i64>,
     /**
-     * Describe whether all repositories have been selected or there's a selection involved
+     * Visibility of a runner group. You can select all organizations or select individual organization. Can be one of: `all` or `selected`
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<RepositorySelection>,
@@ -25328,6 +26774,9 @@ pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnterpriseAdminUpdateSelfHostedRunnerGroupRequest {
+    /**
+     * Name of the runner group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25335,7 +26784,7 @@ pub struct EnterpriseAdminUpdateSelfHostedRunnerGroupRequest {
     )]
     pub name: String,
     /**
-     * Describe whether all repositories have been selected or there's a selection involved
+     * Visibility of a runner group. You can select all organizations or select individual organizations. Can be one of: `all` or `selected`
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<RepositorySelection>,
@@ -25360,7 +26809,7 @@ pub struct ActionsListSelfHostedRunnersInGroupOrgResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsSetSelfHostedRunnersInGroupOrgRequest {
     /**
-     * Code Frequency Stat
+     * List of runner IDs to add to the runner group.
      */
     #[serde(
         default,
@@ -25389,6 +26838,9 @@ pub struct EnterpriseAdminListSelfHostedRunnersResponse {
 /// Names and content for the files that make up the gist
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilesAdditionalPropertiesData {
+    /**
+     * Content of the file
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25398,13 +26850,15 @@ pub struct FilesAdditionalPropertiesData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Public {
     #[serde(rename = "false")]
     False,
     #[serde(rename = "true")]
     True,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Public {
@@ -25412,12 +26866,31 @@ impl std::fmt::Display for Public {
         match self {
             Public::False => "false",
             Public::True => "true",
-            Public::FallthroughString => "*",
+            Public::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Public {
+    fn from(s: String) -> Public {
+        match s.as_str() {
+            "false" => Public::False,
+            "true" => Public::True,
+            _ => Public::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Public> for String {
+    fn from(e: Public) -> String {
+        match e {
+            Public::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Public {
     fn default() -> Public {
         Public::False
@@ -25468,6 +26941,9 @@ impl std::convert::From<PublicOneOf> for bool {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GistsCreateRequest {
+    /**
+     * Description of the gist
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25486,12 +26962,18 @@ pub struct GistsCreateRequest {
 /// Names of files to be updated
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilesAdditionalPropertiesDataType {
+    /**
+     * The new content of the file
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content: String,
+    /**
+     * The new filename for the file
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25502,18 +26984,27 @@ pub struct FilesAdditionalPropertiesDataType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GistsUpdateRequest {
+    /**
+     * Description of the gist
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Names of files to be updated
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub files: Option<FilesAdditionalPropertiesDataType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsUpdateReviewRequest {
+    /**
+     * The comment text.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25553,6 +27044,7 @@ pub struct AppsListInstallationReposResponse {
 *   \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Filter {
     #[serde(rename = "all")]
     All,
@@ -25566,8 +27058,9 @@ pub enum Filter {
     Repos,
     #[serde(rename = "subscribed")]
     Subscribed,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Filter {
@@ -25579,12 +27072,35 @@ impl std::fmt::Display for Filter {
             Filter::Mentioned => "mentioned",
             Filter::Repos => "repos",
             Filter::Subscribed => "subscribed",
-            Filter::FallthroughString => "*",
+            Filter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Filter {
+    fn from(s: String) -> Filter {
+        match s.as_str() {
+            "all" => Filter::All,
+            "assigned" => Filter::Assigned,
+            "created" => Filter::Created,
+            "mentioned" => Filter::Mentioned,
+            "repos" => Filter::Repos,
+            "subscribed" => Filter::Subscribed,
+            _ => Filter::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Filter> for String {
+    fn from(e: Filter) -> String {
+        match e {
+            Filter::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Filter {
     fn default() -> Filter {
         Filter::Assigned
@@ -25595,6 +27111,7 @@ impl Default for Filter {
 * Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IssuesListState {
     #[serde(rename = "all")]
     All,
@@ -25602,8 +27119,9 @@ pub enum IssuesListState {
     Closed,
     #[serde(rename = "open")]
     Open,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListState {
@@ -25612,12 +27130,32 @@ impl std::fmt::Display for IssuesListState {
             IssuesListState::All => "all",
             IssuesListState::Closed => "closed",
             IssuesListState::Open => "open",
-            IssuesListState::FallthroughString => "*",
+            IssuesListState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IssuesListState {
+    fn from(s: String) -> IssuesListState {
+        match s.as_str() {
+            "all" => IssuesListState::All,
+            "closed" => IssuesListState::Closed,
+            "open" => IssuesListState::Open,
+            _ => IssuesListState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IssuesListState> for String {
+    fn from(e: IssuesListState) -> String {
+        match e {
+            IssuesListState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IssuesListState {
     fn default() -> IssuesListState {
         IssuesListState::Open
@@ -25628,6 +27166,7 @@ impl Default for IssuesListState {
 * What to sort results by. Can be either `created`, `updated`, `comments`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IssuesListSort {
     #[serde(rename = "comments")]
     Comments,
@@ -25635,8 +27174,9 @@ pub enum IssuesListSort {
     Created,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListSort {
@@ -25645,12 +27185,32 @@ impl std::fmt::Display for IssuesListSort {
             IssuesListSort::Comments => "comments",
             IssuesListSort::Created => "created",
             IssuesListSort::Updated => "updated",
-            IssuesListSort::FallthroughString => "*",
+            IssuesListSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IssuesListSort {
+    fn from(s: String) -> IssuesListSort {
+        match s.as_str() {
+            "comments" => IssuesListSort::Comments,
+            "created" => IssuesListSort::Created,
+            "updated" => IssuesListSort::Updated,
+            _ => IssuesListSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IssuesListSort> for String {
+    fn from(e: IssuesListSort) -> String {
+        match e {
+            IssuesListSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IssuesListSort {
     fn default() -> IssuesListSort {
         IssuesListSort::Created
@@ -25661,13 +27221,15 @@ impl Default for IssuesListSort {
 * The rendering mode.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Mode {
     #[serde(rename = "gfm")]
     Gfm,
     #[serde(rename = "markdown")]
     Markdown,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Mode {
@@ -25675,12 +27237,31 @@ impl std::fmt::Display for Mode {
         match self {
             Mode::Gfm => "gfm",
             Mode::Markdown => "markdown",
-            Mode::FallthroughString => "*",
+            Mode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Mode {
+    fn from(s: String) -> Mode {
+        match s.as_str() {
+            "gfm" => Mode::Gfm,
+            "markdown" => Mode::Markdown,
+            _ => Mode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Mode> for String {
+    fn from(e: Mode) -> String {
+        match e {
+            Mode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Mode {
     fn default() -> Mode {
         Mode::Markdown
@@ -25689,6 +27270,9 @@ impl Default for Mode {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MarkdownRenderRequest {
+    /**
+     * The repository context to use when creating references in `gfm` mode.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25700,6 +27284,9 @@ pub struct MarkdownRenderRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mode: Option<Mode>,
+    /**
+     * The Markdown text to render in HTML.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25710,18 +27297,27 @@ pub struct MarkdownRenderRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivityMarkNotificationsAsReadRequest {
+    /**
+     * Describes the last point that notifications were checked.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_read_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Whether the notification has been read.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub read: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivitySetThreadSubscriptionRequest {
+    /**
+     * Whether to block all notifications from a thread.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ignored: Option<bool>,
 }
@@ -25734,6 +27330,7 @@ pub struct ActivitySetThreadSubscriptionRequest {
 *   **Note:** This parameter is deprecated and will be removed in the future. Its return value ignores internal repositories. Using this parameter overrides values set in `members_can_create_repositories`. See the parameter deprecation notice in the operation description for details.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MembersAllowedRepositoryCreationType {
     #[serde(rename = "all")]
     All,
@@ -25743,8 +27340,9 @@ pub enum MembersAllowedRepositoryCreationType {
     Private,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MembersAllowedRepositoryCreationType {
@@ -25754,12 +27352,33 @@ impl std::fmt::Display for MembersAllowedRepositoryCreationType {
             MembersAllowedRepositoryCreationType::None => "none",
             MembersAllowedRepositoryCreationType::Private => "private",
             MembersAllowedRepositoryCreationType::Noop => "",
-            MembersAllowedRepositoryCreationType::FallthroughString => "*",
+            MembersAllowedRepositoryCreationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MembersAllowedRepositoryCreationType {
+    fn from(s: String) -> MembersAllowedRepositoryCreationType {
+        match s.as_str() {
+            "all" => MembersAllowedRepositoryCreationType::All,
+            "none" => MembersAllowedRepositoryCreationType::None,
+            "private" => MembersAllowedRepositoryCreationType::Private,
+            "" => MembersAllowedRepositoryCreationType::Noop,
+            _ => MembersAllowedRepositoryCreationType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MembersAllowedRepositoryCreationType> for String {
+    fn from(e: MembersAllowedRepositoryCreationType) -> String {
+        match e {
+            MembersAllowedRepositoryCreationType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MembersAllowedRepositoryCreationType {
     fn default() -> MembersAllowedRepositoryCreationType {
         MembersAllowedRepositoryCreationType::Noop
@@ -25773,6 +27392,9 @@ impl MembersAllowedRepositoryCreationType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsUpdateRequest {
+    /**
+     * Billing email address. This address is not publicized.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25785,6 +27407,9 @@ pub struct OrgsUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub blog: String,
+    /**
+     * The company name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25792,26 +27417,45 @@ pub struct OrgsUpdateRequest {
     )]
     pub company: String,
     /**
-     * The baseline permission that all organization members have on this project. Only present if owner is an organization.
+     * Default permission level members have for organization repositories:  
+     *  \\* `read` - can pull, but not push to or administer this repository.  
+     *  \\* `write` - can pull and push, but not administer this repository.  
+     *  \\* `admin` - can pull, push, and administer this repository.  
+     *  \\* `none` - no permissions granted by default.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default_repository_permission: Option<OrganizationPermission>,
+    /**
+     * The description of the company.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The publicly visible email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * Toggles whether an organization can use organization projects.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_organization_projects: Option<bool>,
+    /**
+     * Toggles whether repositories that belong to the organization can use repository projects.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_repository_projects: Option<bool>,
+    /**
+     * The location.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25827,26 +27471,72 @@ pub struct OrgsUpdateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_allowed_repository_creation_type: Option<MembersAllowedRepositoryCreationType>,
+    /**
+     * Toggles whether organization members can create internal repositories, which are visible to all enterprise members. You can only allow members to create internal repositories if your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+. Can be one of:  
+     *  \\* `true` - all organization members can create internal repositories.  
+     *  \\* `false` - only organization owners can create internal repositories.  
+     *  Default: `true`. For more information, see "[Restricting repository creation in your organization](https://help.github.com/github/setting-up-and-managing-organizations-and-teams/restricting-repository-creation-in-your-organization)" in the GitHub Help documentation.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_can_create_internal_repositories: Option<bool>,
+    /**
+     * Toggles whether organization members can create GitHub Pages sites. Can be one of:  
+     *  \\* `true` - all organization members can create GitHub Pages sites.  
+     *  \\* `false` - no organization members can create GitHub Pages sites. Existing published sites will not be impacted.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_can_create_pages: Option<bool>,
+    /**
+     * Toggles whether organization members can create private GitHub Pages sites. Can be one of:  
+     *  \\* `true` - all organization members can create private GitHub Pages sites.  
+     *  \\* `false` - no organization members can create private GitHub Pages sites. Existing published sites will not be impacted.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_can_create_private_pages: Option<bool>,
+    /**
+     * Toggles whether organization members can create private repositories, which are visible to organization members with permission. Can be one of:  
+     *  \\* `true` - all organization members can create private repositories.  
+     *  \\* `false` - only organization owners can create private repositories.  
+     *  Default: `true`. For more information, see "[Restricting repository creation in your organization](https://help.github.com/github/setting-up-and-managing-organizations-and-teams/restricting-repository-creation-in-your-organization)" in the GitHub Help documentation.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_can_create_private_repositories: Option<bool>,
+    /**
+     * Toggles whether organization members can create public GitHub Pages sites. Can be one of:  
+     *  \\* `true` - all organization members can create public GitHub Pages sites.  
+     *  \\* `false` - no organization members can create public GitHub Pages sites. Existing published sites will not be impacted.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_can_create_public_pages: Option<bool>,
+    /**
+     * Toggles whether organization members can create public repositories, which are visible to anyone. Can be one of:  
+     *  \\* `true` - all organization members can create public repositories.  
+     *  \\* `false` - only organization owners can create public repositories.  
+     *  Default: `true`. For more information, see "[Restricting repository creation in your organization](https://help.github.com/github/setting-up-and-managing-organizations-and-teams/restricting-repository-creation-in-your-organization)" in the GitHub Help documentation.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_can_create_public_repositories: Option<bool>,
+    /**
+     * Toggles the ability of non-admin organization members to create repositories. Can be one of:  
+     *  \\* `true` - all organization members can create repositories.  
+     *  \\* `false` - only organization owners can create repositories.  
+     *  Default: `true`  
+     *  \*\*Note:\*\* A parameter can override this parameter. See `members_allowed_repository_creation_type` in this table for details. \*\*Note:\*\* A parameter can override this parameter. See `members_allowed_repository_creation_type` in this table for details.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub members_can_create_repositories: Option<bool>,
+    /**
+     * The shorthand name of the company.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The Twitter username of the company.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25924,7 +27614,7 @@ pub struct ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest {
     /**
-     * Code Frequency Stat
+     * List of repository IDs to enable for GitHub Actions.
      */
     #[serde(
         default,
@@ -25952,6 +27642,9 @@ pub struct ActionsListSelfHostedRunnerGroupsOrgResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
+    /**
+     * Name of the runner group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25959,7 +27652,7 @@ pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
     )]
     pub name: String,
     /**
-     * Code Frequency Stat
+     * List of runner IDs to add to the runner group.
      */
     #[serde(
         default,
@@ -25968,7 +27661,7 @@ pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
     )]
     pub runners: Vec<i64>,
     /**
-     * Code Frequency Stat
+     * List of repository IDs that can access the runner group.
      */
     #[serde(
         default,
@@ -25977,7 +27670,7 @@ pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
     )]
     pub selected_repository_ids: Vec<i64>,
     /**
-     * Visibility of a secret
+     * Visibility of a runner group. You can select all repositories, select individual repositories, or limit access to private repositories. Can be one of: `all`, `selected`, or `private`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
@@ -25985,6 +27678,9 @@ pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsUpdateSelfHostedRunnerGroupOrgRequest {
+    /**
+     * Name of the runner group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -25992,7 +27688,7 @@ pub struct ActionsUpdateSelfHostedRunnerGroupOrgRequest {
     )]
     pub name: String,
     /**
-     * Visibility of a secret
+     * Visibility of a runner group. You can select all repositories, select individual repositories, or all private repositories. Can be one of: `all`, `selected`, or `private`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
@@ -26048,12 +27744,18 @@ pub struct ActionsListOrgSecretsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsCreateUpdateOrgSecretRequest {
+    /**
+     * Value for your secret, encrypted with [LibSodium](https://libsodium.gitbook.io/doc/bindings_for_other_languages) using the public key retrieved from the [Get an organization public key](https://docs.github.com/rest/reference/actions#get-an-organization-public-key) endpoint.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub encrypted_value: String,
+    /**
+     * ID of the key you used to encrypt the secret.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -26061,7 +27763,7 @@ pub struct ActionsCreateUpdateOrgSecretRequest {
     )]
     pub key_id: String,
     /**
-     * The list of events for the GitHub app
+     * An array of repository ids that can access the organization secret. You can only provide a list of repository ids when the `visibility` is set to `selected`. You can manage the list of selected repositories using the [List selected repositories for an organization secret](https://docs.github.com/rest/reference/actions#list-selected-repositories-for-an-organization-secret), [Set selected repositories for an organization secret](https://docs.github.com/rest/reference/actions#set-selected-repositories-for-an-organization-secret), and [Remove selected repository from an organization secret](https://docs.github.com/rest/reference/actions#remove-selected-repository-from-an-organization-secret) endpoints.
      */
     #[serde(
         default,
@@ -26070,7 +27772,10 @@ pub struct ActionsCreateUpdateOrgSecretRequest {
     )]
     pub selected_repository_ids: Vec<String>,
     /**
-     * Visibility of a secret
+     * Configures the access that repositories have to the organization secret. Can be one of:  
+     *  \- `all` - All repositories in an organization can access the secret.  
+     *  \- `private` - Private repositories in an organization can access the secret.  
+     *  \- `selected` - Only specific repositories can access the secret.
      */
     #[serde(default, skip_serializing_if = "Visibility::is_noop")]
     pub visibility: Visibility,
@@ -26095,7 +27800,7 @@ pub struct ActionsListSelectedReposOrgSecretResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsSetSelectedReposOrgSecretRequest {
     /**
-     * Code Frequency Stat
+     * An array of repository ids that can access the organization secret. You can only provide a list of repository ids when the `visibility` is set to `selected`. You can add and remove individual repositories using the [Set selected repositories for an organization secret](https://docs.github.com/rest/reference/actions#set-selected-repositories-for-an-organization-secret) and [Remove selected repository from an organization secret](https://docs.github.com/rest/reference/actions#remove-selected-repository-from-an-organization-secret) endpoints.
      */
     #[serde(
         default,
@@ -26114,9 +27819,6 @@ pub struct OrgsCreateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content_type: String,
-    /**
-     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
     #[serde(
@@ -26147,6 +27849,9 @@ pub struct OrgsCreateWebhookRequestConfig {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsCreateWebhookRequest {
+    /**
+     * Determines if notifications are sent when the webhook is triggered. Set to `true` to send notifications.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
@@ -26155,7 +27860,7 @@ pub struct OrgsCreateWebhookRequest {
     #[serde()]
     pub config: OrgsCreateWebhookRequestConfig,
     /**
-     * The list of events for the GitHub app
+     * Determines what [events](https://docs.github.com/webhooks/event-payloads) the hook is triggered for.
      */
     #[serde(
         default,
@@ -26163,6 +27868,9 @@ pub struct OrgsCreateWebhookRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub events: Vec<String>,
+    /**
+     * Must be passed as "web".
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -26180,9 +27888,6 @@ pub struct OrgsUpdateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content_type: String,
-    /**
-     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#update-hook-config-params).
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
     #[serde(
@@ -26201,6 +27906,9 @@ pub struct OrgsUpdateWebhookRequestConfig {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsUpdateWebhookRequest {
+    /**
+     * Determines if notifications are sent when the webhook is triggered. Set to `true` to send notifications.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
@@ -26209,7 +27917,7 @@ pub struct OrgsUpdateWebhookRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub config: Option<OrgsUpdateWebhookRequestConfig>,
     /**
-     * The list of events for the GitHub app
+     * Determines what [events](https://docs.github.com/webhooks/event-payloads) the hook is triggered for.
      */
     #[serde(
         default,
@@ -26264,6 +27972,7 @@ pub struct InteractionsGetRestrictionsResponseAnyOf {
 *   \* `billing_manager` - Non-owner organization members with ability to manage the billing settings of your organization.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrgsCreateInvitationRequestRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26271,8 +27980,9 @@ pub enum OrgsCreateInvitationRequestRole {
     BillingManager,
     #[serde(rename = "direct_member")]
     DirectMember,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsCreateInvitationRequestRole {
@@ -26281,12 +27991,32 @@ impl std::fmt::Display for OrgsCreateInvitationRequestRole {
             OrgsCreateInvitationRequestRole::Admin => "admin",
             OrgsCreateInvitationRequestRole::BillingManager => "billing_manager",
             OrgsCreateInvitationRequestRole::DirectMember => "direct_member",
-            OrgsCreateInvitationRequestRole::FallthroughString => "*",
+            OrgsCreateInvitationRequestRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrgsCreateInvitationRequestRole {
+    fn from(s: String) -> OrgsCreateInvitationRequestRole {
+        match s.as_str() {
+            "admin" => OrgsCreateInvitationRequestRole::Admin,
+            "billing_manager" => OrgsCreateInvitationRequestRole::BillingManager,
+            "direct_member" => OrgsCreateInvitationRequestRole::DirectMember,
+            _ => OrgsCreateInvitationRequestRole::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrgsCreateInvitationRequestRole> for String {
+    fn from(e: OrgsCreateInvitationRequestRole) -> String {
+        match e {
+            OrgsCreateInvitationRequestRole::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrgsCreateInvitationRequestRole {
     fn default() -> OrgsCreateInvitationRequestRole {
         OrgsCreateInvitationRequestRole::DirectMember
@@ -26295,12 +28025,18 @@ impl Default for OrgsCreateInvitationRequestRole {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsCreateInvitationRequest {
+    /**
+     * \*\*Required unless you provide `invitee_id`\*\*. Email address of the person you are inviting, which can be an existing GitHub user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * \*\*Required unless you provide `email`\*\*. GitHub user ID for the person you are inviting.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -26316,7 +28052,7 @@ pub struct OrgsCreateInvitationRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub role: Option<OrgsCreateInvitationRequestRole>,
     /**
-     * Code Frequency Stat
+     * Specify IDs for the teams you want to invite new members to.
      */
     #[serde(
         default,
@@ -26332,13 +28068,15 @@ pub struct OrgsCreateInvitationRequest {
 *   \* `all` - All members the authenticated user can see.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrgsListMembersFilter {
     #[serde(rename = "2fa_disabled")]
     TwoFaDisabled,
     #[serde(rename = "all")]
     All,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsListMembersFilter {
@@ -26346,12 +28084,31 @@ impl std::fmt::Display for OrgsListMembersFilter {
         match self {
             OrgsListMembersFilter::TwoFaDisabled => "2fa_disabled",
             OrgsListMembersFilter::All => "all",
-            OrgsListMembersFilter::FallthroughString => "*",
+            OrgsListMembersFilter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrgsListMembersFilter {
+    fn from(s: String) -> OrgsListMembersFilter {
+        match s.as_str() {
+            "2fa_disabled" => OrgsListMembersFilter::TwoFaDisabled,
+            "all" => OrgsListMembersFilter::All,
+            _ => OrgsListMembersFilter::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrgsListMembersFilter> for String {
+    fn from(e: OrgsListMembersFilter) -> String {
+        match e {
+            OrgsListMembersFilter::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrgsListMembersFilter {
     fn default() -> OrgsListMembersFilter {
         OrgsListMembersFilter::All
@@ -26365,6 +28122,7 @@ impl Default for OrgsListMembersFilter {
 *   \* `member` - Non-owner organization members.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrgsListMembersRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26372,8 +28130,9 @@ pub enum OrgsListMembersRole {
     All,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsListMembersRole {
@@ -26382,12 +28141,32 @@ impl std::fmt::Display for OrgsListMembersRole {
             OrgsListMembersRole::Admin => "admin",
             OrgsListMembersRole::All => "all",
             OrgsListMembersRole::Member => "member",
-            OrgsListMembersRole::FallthroughString => "*",
+            OrgsListMembersRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrgsListMembersRole {
+    fn from(s: String) -> OrgsListMembersRole {
+        match s.as_str() {
+            "admin" => OrgsListMembersRole::Admin,
+            "all" => OrgsListMembersRole::All,
+            "member" => OrgsListMembersRole::Member,
+            _ => OrgsListMembersRole::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrgsListMembersRole> for String {
+    fn from(e: OrgsListMembersRole) -> String {
+        match e {
+            OrgsListMembersRole::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrgsListMembersRole {
     fn default() -> OrgsListMembersRole {
         OrgsListMembersRole::All
@@ -26400,13 +28179,15 @@ impl Default for OrgsListMembersRole {
 *   \* `member` - The user will become a non-owner member of the organization.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrgsSetMembershipUserRequestRole {
     #[serde(rename = "admin")]
     Admin,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
@@ -26414,12 +28195,31 @@ impl std::fmt::Display for OrgsSetMembershipUserRequestRole {
         match self {
             OrgsSetMembershipUserRequestRole::Admin => "admin",
             OrgsSetMembershipUserRequestRole::Member => "member",
-            OrgsSetMembershipUserRequestRole::FallthroughString => "*",
+            OrgsSetMembershipUserRequestRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrgsSetMembershipUserRequestRole {
+    fn from(s: String) -> OrgsSetMembershipUserRequestRole {
+        match s.as_str() {
+            "admin" => OrgsSetMembershipUserRequestRole::Admin,
+            "member" => OrgsSetMembershipUserRequestRole::Member,
+            _ => OrgsSetMembershipUserRequestRole::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrgsSetMembershipUserRequestRole> for String {
+    fn from(e: OrgsSetMembershipUserRequestRole) -> String {
+        match e {
+            OrgsSetMembershipUserRequestRole::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrgsSetMembershipUserRequestRole {
     fn default() -> OrgsSetMembershipUserRequestRole {
         OrgsSetMembershipUserRequestRole::Member
@@ -26441,13 +28241,15 @@ pub struct OrgsSetMembershipUserRequest {
 * Allowed values that can be passed to the exclude param.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Exclude {
     #[serde(rename = "repositories")]
     Repositories,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Exclude {
@@ -26455,12 +28257,31 @@ impl std::fmt::Display for Exclude {
         match self {
             Exclude::Repositories => "repositories",
             Exclude::Noop => "",
-            Exclude::FallthroughString => "*",
+            Exclude::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Exclude {
+    fn from(s: String) -> Exclude {
+        match s.as_str() {
+            "repositories" => Exclude::Repositories,
+            "" => Exclude::Noop,
+            _ => Exclude::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Exclude> for String {
+    fn from(e: Exclude) -> String {
+        match e {
+            Exclude::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Exclude {
     fn default() -> Exclude {
         Exclude::Noop
@@ -26474,21 +28295,24 @@ impl Exclude {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MigrationsStartRequest {
-    /**
-     * Exclude attributes from the API response to improve performance
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub exclude: Vec<Exclude>,
+    /**
+     * Indicates whether attachments should be excluded from the migration (to reduce migration archive file size).
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub exclude_attachments: Option<bool>,
+    /**
+     * Indicates whether repositories should be locked (to prevent manipulation) while migrating data.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lock_repositories: Option<bool>,
     /**
-     * The list of events for the GitHub app
+     * A list of arrays indicating which repositories should be migrated.
      */
     #[serde(
         default,
@@ -26502,13 +28326,15 @@ pub struct MigrationsStartRequest {
 * The state of the package, either active or deleted.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PackagesGetAllPackageVersionsOwnedByOrgState {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "deleted")]
     Deleted,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
@@ -26516,12 +28342,31 @@ impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
         match self {
             PackagesGetAllPackageVersionsOwnedByOrgState::Active => "active",
             PackagesGetAllPackageVersionsOwnedByOrgState::Deleted => "deleted",
-            PackagesGetAllPackageVersionsOwnedByOrgState::FallthroughString => "*",
+            PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn from(s: String) -> PackagesGetAllPackageVersionsOwnedByOrgState {
+        match s.as_str() {
+            "active" => PackagesGetAllPackageVersionsOwnedByOrgState::Active,
+            "deleted" => PackagesGetAllPackageVersionsOwnedByOrgState::Deleted,
+            _ => PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PackagesGetAllPackageVersionsOwnedByOrgState> for String {
+    fn from(e: PackagesGetAllPackageVersionsOwnedByOrgState) -> String {
+        match e {
+            PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PackagesGetAllPackageVersionsOwnedByOrgState {
     fn default() -> PackagesGetAllPackageVersionsOwnedByOrgState {
         PackagesGetAllPackageVersionsOwnedByOrgState::Active
@@ -26530,12 +28375,14 @@ impl Default for PackagesGetAllPackageVersionsOwnedByOrgState {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsCreateRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub body: String,
+    /**
+     * The description of the project.
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub body: crate::utils::Patch<String>,
+    /**
+     * The name of the project.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -26548,6 +28395,7 @@ pub struct ProjectsCreateRequest {
 * Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposListOrgType {
     #[serde(rename = "all")]
     All,
@@ -26565,8 +28413,9 @@ pub enum ReposListOrgType {
     Sources,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListOrgType {
@@ -26580,12 +28429,37 @@ impl std::fmt::Display for ReposListOrgType {
             ReposListOrgType::Public => "public",
             ReposListOrgType::Sources => "sources",
             ReposListOrgType::Noop => "",
-            ReposListOrgType::FallthroughString => "*",
+            ReposListOrgType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposListOrgType {
+    fn from(s: String) -> ReposListOrgType {
+        match s.as_str() {
+            "all" => ReposListOrgType::All,
+            "forks" => ReposListOrgType::Forks,
+            "internal" => ReposListOrgType::Internal,
+            "member" => ReposListOrgType::Member,
+            "private" => ReposListOrgType::Private,
+            "public" => ReposListOrgType::Public,
+            "sources" => ReposListOrgType::Sources,
+            "" => ReposListOrgType::Noop,
+            _ => ReposListOrgType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposListOrgType> for String {
+    fn from(e: ReposListOrgType) -> String {
+        match e {
+            ReposListOrgType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposListOrgType {
     fn default() -> ReposListOrgType {
         ReposListOrgType::Noop
@@ -26601,6 +28475,7 @@ impl ReposListOrgType {
 * Can be one of `created`, `updated`, `pushed`, `full_name`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposListOrgSort {
     #[serde(rename = "created")]
     Created,
@@ -26610,8 +28485,9 @@ pub enum ReposListOrgSort {
     Pushed,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListOrgSort {
@@ -26621,12 +28497,33 @@ impl std::fmt::Display for ReposListOrgSort {
             ReposListOrgSort::FullName => "full_name",
             ReposListOrgSort::Pushed => "pushed",
             ReposListOrgSort::Updated => "updated",
-            ReposListOrgSort::FallthroughString => "*",
+            ReposListOrgSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposListOrgSort {
+    fn from(s: String) -> ReposListOrgSort {
+        match s.as_str() {
+            "created" => ReposListOrgSort::Created,
+            "full_name" => ReposListOrgSort::FullName,
+            "pushed" => ReposListOrgSort::Pushed,
+            "updated" => ReposListOrgSort::Updated,
+            _ => ReposListOrgSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposListOrgSort> for String {
+    fn from(e: ReposListOrgSort) -> String {
+        match e {
+            ReposListOrgSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposListOrgSort {
     fn default() -> ReposListOrgSort {
         ReposListOrgSort::Created
@@ -26638,6 +28535,7 @@ impl Default for ReposListOrgSort {
 *   The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposCreateInOrgRequestVisibility {
     #[serde(rename = "internal")]
     Internal,
@@ -26649,8 +28547,9 @@ pub enum ReposCreateInOrgRequestVisibility {
     Visibility,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
@@ -26661,12 +28560,34 @@ impl std::fmt::Display for ReposCreateInOrgRequestVisibility {
             ReposCreateInOrgRequestVisibility::Public => "public",
             ReposCreateInOrgRequestVisibility::Visibility => "visibility",
             ReposCreateInOrgRequestVisibility::Noop => "",
-            ReposCreateInOrgRequestVisibility::FallthroughString => "*",
+            ReposCreateInOrgRequestVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposCreateInOrgRequestVisibility {
+    fn from(s: String) -> ReposCreateInOrgRequestVisibility {
+        match s.as_str() {
+            "internal" => ReposCreateInOrgRequestVisibility::Internal,
+            "private" => ReposCreateInOrgRequestVisibility::Private,
+            "public" => ReposCreateInOrgRequestVisibility::Public,
+            "visibility" => ReposCreateInOrgRequestVisibility::Visibility,
+            "" => ReposCreateInOrgRequestVisibility::Noop,
+            _ => ReposCreateInOrgRequestVisibility::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposCreateInOrgRequestVisibility> for String {
+    fn from(e: ReposCreateInOrgRequestVisibility) -> String {
+        match e {
+            ReposCreateInOrgRequestVisibility::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposCreateInOrgRequestVisibility {
     fn default() -> ReposCreateInOrgRequestVisibility {
         ReposCreateInOrgRequestVisibility::Noop
@@ -26680,58 +28601,109 @@ impl ReposCreateInOrgRequestVisibility {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateInOrgRequest {
+    /**
+     * Either `true` to allow auto-merge on pull requests, or `false` to disallow auto-merge.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_auto_merge: Option<bool>,
+    /**
+     * Either `true` to allow merging pull requests with a merge commit, or `false` to prevent merging pull requests with merge commits.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_merge_commit: Option<bool>,
+    /**
+     * Either `true` to allow rebase-merging pull requests, or `false` to prevent rebase-merging.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_rebase_merge: Option<bool>,
+    /**
+     * Either `true` to allow squash-merging pull requests, or `false` to prevent squash-merging.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_squash_merge: Option<bool>,
+    /**
+     * Pass `true` to create an initial commit with empty README.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_init: Option<bool>,
+    /**
+     * Either `true` to allow automatically deleting head branches when pull requests are merged, or `false` to prevent automatic deletion.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_branch_on_merge: Option<bool>,
+    /**
+     * A short description of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Desired language or platform [.gitignore template](https://github.com/github/gitignore) to apply. Use the name of the template without the extension. For example, "Haskell".
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub gitignore_template: String,
+    /**
+     * Either `true` to enable issues for this repository or `false` to disable them.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_issues: Option<bool>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /**
+     * Either `true` to enable projects for this repository or `false` to disable them. \*\*Note:\*\* If you're creating a repository in an organization that has disabled repository projects, the default is `false`, and if you pass `true`, the API returns an error.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_projects: Option<bool>,
+    /**
+     * Either `true` to enable the wiki for this repository or `false` to disable it.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_wiki: Option<bool>,
+    /**
+     * A URL with more information about the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub homepage: String,
+    /**
+     * Either `true` to make this repo available as a template repository or `false` to prevent it.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_template: Option<bool>,
+    /**
+     * Choose an [open source license template](https://choosealicense.com/) that best suits your needs, and then use the [license keyword](https://help.github.com/articles/licensing-a-repository/#searching-github-by-license-type) as the `license_template` string. For example, "mit" or "mpl-2.0".
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub license_template: String,
+    /**
+     * The name of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * Whether the repository is private.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<bool>,
+    /**
+     * The id of the team that will be granted access to this repository. This is only valid when creating a repository in an organization.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -26753,6 +28725,7 @@ pub struct ReposCreateInOrgRequest {
 *   \* `admin` - team members can pull, push and administer newly-added repositories.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Permission {
     #[serde(rename = "admin")]
     Admin,
@@ -26760,8 +28733,9 @@ pub enum Permission {
     Pull,
     #[serde(rename = "push")]
     Push,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Permission {
@@ -26770,12 +28744,32 @@ impl std::fmt::Display for Permission {
             Permission::Admin => "admin",
             Permission::Pull => "pull",
             Permission::Push => "push",
-            Permission::FallthroughString => "*",
+            Permission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Permission {
+    fn from(s: String) -> Permission {
+        match s.as_str() {
+            "admin" => Permission::Admin,
+            "pull" => Permission::Pull,
+            "push" => Permission::Push,
+            _ => Permission::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Permission> for String {
+    fn from(e: Permission) -> String {
+        match e {
+            Permission::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Permission {
     fn default() -> Permission {
         Permission::Pull
@@ -26784,6 +28778,9 @@ impl Default for Permission {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsCreateRequest {
+    /**
+     * The description of the team.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -26791,7 +28788,7 @@ pub struct TeamsCreateRequest {
     )]
     pub description: String,
     /**
-     * The list of events for the GitHub app
+     * List GitHub IDs for organization members who will become team maintainers.
      */
     #[serde(
         default,
@@ -26799,12 +28796,18 @@ pub struct TeamsCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub maintainers: Vec<String>,
+    /**
+     * The name of the team.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The ID of a team to set as the parent team.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -26820,12 +28823,19 @@ pub struct TeamsCreateRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<Permission>,
     /**
-     * The level of privacy this team should have
+     * The level of privacy this team should have. The options are:  
+     *  \*\*For a non-nested team:\*\*  
+     *  \\* `secret` - only visible to organization owners and members of this team.  
+     *  \\* `closed` - visible to all members of this organization.  
+     *  Default: `secret`  
+     *  \*\*For a parent or child team:\*\*  
+     *  \\* `closed` - visible to all members of this organization.  
+     *  Default for child team: `closed`
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
     /**
-     * The list of events for the GitHub app
+     * The full name (e.g., "organization-name/repository-name") of repositories to add the team to.
      */
     #[serde(
         default,
@@ -26837,24 +28847,29 @@ pub struct TeamsCreateRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsUpdateInOrgRequest {
+    /**
+     * The description of the team.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The name of the team.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub parent_team_id: i64,
+    /**
+     * The ID of a team to set as the parent team.
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub parent_team_id: crate::utils::Patch<i64>,
     /**
      * \*\*Deprecated\*\*. The permission that new repositories will be added to the team with when none is specified. Can be one of:  
      *  \\* `pull` - team members can pull, but not push to or administer newly-added repositories.  
@@ -26864,7 +28879,12 @@ pub struct TeamsUpdateInOrgRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<Permission>,
     /**
-     * The level of privacy this team should have
+     * The level of privacy this team should have. Editing teams without specifying this parameter leaves `privacy` intact. When a team is nested, the `privacy` for parent teams cannot be `secret`. The options are:  
+     *  \*\*For a non-nested team:\*\*  
+     *  \\* `secret` - only visible to organization owners and members of this team.  
+     *  \\* `closed` - visible to all members of this organization.  
+     *  \*\*For a parent or child team:\*\*  
+     *  \\* `closed` - visible to all members of this organization.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub privacy: Option<Privacy>,
@@ -26872,14 +28892,23 @@ pub struct TeamsUpdateInOrgRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsCreateDiscussionInOrgRequest {
+    /**
+     * The discussion post's body text.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * Private posts are only visible to team members, organization owners, and team maintainers. Public posts are visible to all members of the organization. Set to `true` to create a private post.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<bool>,
+    /**
+     * The discussion post's title.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -26890,12 +28919,18 @@ pub struct TeamsCreateDiscussionInOrgRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsUpdateDiscussionInOrgRequest {
+    /**
+     * The discussion post's body text.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * The discussion post's title.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -26907,7 +28942,7 @@ pub struct TeamsUpdateDiscussionInOrgRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReactionsCreateIssueRequest {
     /**
-     * The reaction to use
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion comment.
      */
     #[serde(default, skip_serializing_if = "Content::is_noop")]
     pub content: Content,
@@ -26920,6 +28955,7 @@ pub struct ReactionsCreateIssueRequest {
 *   \* `all` - all members of the team.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TeamsListMembersInOrgRole {
     #[serde(rename = "all")]
     All,
@@ -26927,8 +28963,9 @@ pub enum TeamsListMembersInOrgRole {
     Maintainer,
     #[serde(rename = "member")]
     Member,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamsListMembersInOrgRole {
@@ -26937,12 +28974,32 @@ impl std::fmt::Display for TeamsListMembersInOrgRole {
             TeamsListMembersInOrgRole::All => "all",
             TeamsListMembersInOrgRole::Maintainer => "maintainer",
             TeamsListMembersInOrgRole::Member => "member",
-            TeamsListMembersInOrgRole::FallthroughString => "*",
+            TeamsListMembersInOrgRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TeamsListMembersInOrgRole {
+    fn from(s: String) -> TeamsListMembersInOrgRole {
+        match s.as_str() {
+            "all" => TeamsListMembersInOrgRole::All,
+            "maintainer" => TeamsListMembersInOrgRole::Maintainer,
+            "member" => TeamsListMembersInOrgRole::Member,
+            _ => TeamsListMembersInOrgRole::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TeamsListMembersInOrgRole> for String {
+    fn from(e: TeamsListMembersInOrgRole) -> String {
+        match e {
+            TeamsListMembersInOrgRole::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TeamsListMembersInOrgRole {
     fn default() -> TeamsListMembersInOrgRole {
         TeamsListMembersInOrgRole::All
@@ -26952,7 +29009,9 @@ impl Default for TeamsListMembersInOrgRole {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsAddUpdateMembershipUserInOrgRequest {
     /**
-     * The role of the user in the team.
+     * The role that this user should have in the team. Can be one of:  
+     *  \\* `member` - a normal member of the team.  
+     *  \\* `maintainer` - a team maintainer. Able to add/remove other team members, promote other team members to team maintainer, and edit the team's name and description.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub role: Option<TeamMembershipRole>,
@@ -26960,6 +29019,13 @@ pub struct TeamsAddUpdateMembershipUserInOrgRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsAddCollaboratorRequest {
+    /**
+     * The permission to grant to the team for this project. Can be one of:  
+     *  \\* `read` - team members can read, but not write to or administer this project.  
+     *  \\* `write` - team members can read and write, but not administer this project.  
+     *  \\* `admin` - team members can read, write and administer this project.  
+     *  Default: the team's `permission` attribute will be used to determine what permission to grant the team on this project. Note that, if you choose not to pass any parameters, you'll need to set `Content-Length` to zero when calling out to this endpoint. For more information, see "[HTTP verbs](https://docs.github.com/rest/overview/resources-in-the-rest-api#http-verbs)."
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<RepositoryProjects>,
 }
@@ -26975,6 +29041,7 @@ pub struct ProjectsAddCollaboratorRequest {
 *   If no permission is specified, the team's `permission` attribute will be used to determine what permission to grant the team on this repository.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -26988,8 +29055,9 @@ pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     Triage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
@@ -27001,12 +29069,35 @@ impl std::fmt::Display for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push => "push",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage => "triage",
             TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop => "",
-            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::FallthroughString => "*",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+    fn from(s: String) -> TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+        match s.as_str() {
+            "admin" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Admin,
+            "maintain" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Maintain,
+            "pull" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Pull,
+            "push" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push,
+            "triage" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage,
+            "" => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop,
+            _ => TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TeamsAddUpdateRepoPermissionsInOrgRequestPermission> for String {
+    fn from(e: TeamsAddUpdateRepoPermissionsInOrgRequestPermission) -> String {
+        match e {
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     fn default() -> TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
         TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop
@@ -27039,18 +29130,27 @@ pub struct TeamsAddUpdateRepoPermissionsInOrgRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsCreateUpdateIdpGroupConnectionsInOrgRequestGroups {
+    /**
+     * Description of the IdP group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_description: String,
+    /**
+     * ID of the IdP group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_id: String,
+    /**
+     * Name of the IdP group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27080,9 +29180,6 @@ pub struct ProjectsDeleteResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub documentation_url: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -27099,24 +29196,32 @@ pub struct ProjectsDeleteResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsUpdateCardRequest {
+    /**
+     * Whether or not the card is archived
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archived: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub note: String,
+    /**
+     * The project card's note
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub note: crate::utils::Patch<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsMoveCardRequest {
+    /**
+     * The unique identifier of the column the card should be moved to
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub column_id: i64,
+    /**
+     * The position of the card in a column. Can be one of: `top`, `bottom`, or `after:<card_id>` to place after the specified card.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27221,6 +29326,9 @@ pub struct ProjectsCreateCardResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsUpdateColumnRequest {
+    /**
+     * Name of the project column
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27233,6 +29341,7 @@ pub struct ProjectsUpdateColumnRequest {
 * Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ArchivedState {
     #[serde(rename = "all")]
     All,
@@ -27240,8 +29349,9 @@ pub enum ArchivedState {
     Archived,
     #[serde(rename = "not_archived")]
     NotArchived,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ArchivedState {
@@ -27250,12 +29360,32 @@ impl std::fmt::Display for ArchivedState {
             ArchivedState::All => "all",
             ArchivedState::Archived => "archived",
             ArchivedState::NotArchived => "not_archived",
-            ArchivedState::FallthroughString => "*",
+            ArchivedState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ArchivedState {
+    fn from(s: String) -> ArchivedState {
+        match s.as_str() {
+            "all" => ArchivedState::All,
+            "archived" => ArchivedState::Archived,
+            "not_archived" => ArchivedState::NotArchived,
+            _ => ArchivedState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ArchivedState> for String {
+    fn from(e: ArchivedState) -> String {
+        match e {
+            ArchivedState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ArchivedState {
     fn default() -> ArchivedState {
         ArchivedState::NotArchived
@@ -27264,6 +29394,9 @@ impl Default for ArchivedState {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsCreateCardRequest {
+    /**
+     * The project card's note
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27274,12 +29407,18 @@ pub struct ProjectsCreateCardRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsCreateCardRequestData {
+    /**
+     * The unique identifier of the content associated with the card
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub content_id: i64,
+    /**
+     * The piece of content associated with the card
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27320,6 +29459,9 @@ impl ProjectsCreateCardRequestOneOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsMoveColumnRequest {
+    /**
+     * The position of the column in a project. Can be one of: `first`, `last`, or `after:<column_id>` to place after the specified column.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27330,12 +29472,14 @@ pub struct ProjectsMoveColumnRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsUpdateRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub body: String,
+    /**
+     * Body of the project
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub body: crate::utils::Patch<String>,
+    /**
+     * Name of the project
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27343,12 +29487,18 @@ pub struct ProjectsUpdateRequest {
     )]
     pub name: String,
     /**
-     * The baseline permission that all organization members have on this project. Only present if owner is an organization.
+     * The baseline permission that all organization members have on this project
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization_permission: Option<OrganizationPermission>,
+    /**
+     * Whether or not this project can be seen by everyone.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<bool>,
+    /**
+     * State of the project; either 'open' or 'closed'
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27364,6 +29514,7 @@ pub struct ProjectsUpdateRequest {
 *   \* `all`: All collaborators the authenticated user can see.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Affiliation {
     #[serde(rename = "all")]
     All,
@@ -27371,8 +29522,9 @@ pub enum Affiliation {
     Direct,
     #[serde(rename = "outside")]
     Outside,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Affiliation {
@@ -27381,12 +29533,32 @@ impl std::fmt::Display for Affiliation {
             Affiliation::All => "all",
             Affiliation::Direct => "direct",
             Affiliation::Outside => "outside",
-            Affiliation::FallthroughString => "*",
+            Affiliation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Affiliation {
+    fn from(s: String) -> Affiliation {
+        match s.as_str() {
+            "all" => Affiliation::All,
+            "direct" => Affiliation::Direct,
+            "outside" => Affiliation::Outside,
+            _ => Affiliation::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Affiliation> for String {
+    fn from(e: Affiliation) -> String {
+        match e {
+            Affiliation::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Affiliation {
     fn default() -> Affiliation {
         Affiliation::All
@@ -27396,6 +29568,9 @@ impl Default for Affiliation {
 /// Use the `status` property to enable or disable GitHub Advanced Security for this repository. For more information, see "[About GitHub Advanced Security](/github/getting-started-with-github/learning-about-github/about-github-advanced-security)."
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateRequestSecurityAnalysisAdvanced {
+    /**
+     * Can be `enabled` or `disabled`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27408,12 +29583,12 @@ pub struct ReposUpdateRequestSecurityAnalysisAdvanced {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateRequestSecurityAnalysis {
     /**
-     * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+     * Use the `status` property to enable or disable GitHub Advanced Security for this repository. For more information, see "[About GitHub Advanced Security](/github/getting-started-with-github/learning-about-github/about-github-advanced-security)."
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub advanced_security: Option<ReposUpdateRequestSecurityAnalysisAdvanced>,
     /**
-     * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+     * Use the `status` property to enable or disable secret scanning for this repository. For more information, see "[About secret scanning](/code-security/secret-security/about-secret-scanning)."
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub secret_scanning: Option<ReposUpdateRequestSecurityAnalysisAdvanced>,
@@ -27421,60 +29596,105 @@ pub struct ReposUpdateRequestSecurityAnalysis {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateRequest {
+    /**
+     * Either `true` to allow auto-merge on pull requests, or `false` to disallow auto-merge.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_auto_merge: Option<bool>,
+    /**
+     * Either `true` to allow merging pull requests with a merge commit, or `false` to prevent merging pull requests with merge commits.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_merge_commit: Option<bool>,
+    /**
+     * Either `true` to allow rebase-merging pull requests, or `false` to prevent rebase-merging.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_rebase_merge: Option<bool>,
+    /**
+     * Either `true` to allow squash-merging pull requests, or `false` to prevent squash-merging.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_squash_merge: Option<bool>,
+    /**
+     * `true` to archive this repository. \*\*Note\*\*: You cannot unarchive repositories through the API.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archived: Option<bool>,
+    /**
+     * Updates the default branch for this repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub default_branch: String,
+    /**
+     * Either `true` to allow automatically deleting head branches when pull requests are merged, or `false` to prevent automatic deletion.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_branch_on_merge: Option<bool>,
+    /**
+     * A short description of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Either `true` to enable issues for this repository or `false` to disable them.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_issues: Option<bool>,
+    /**
+     * Either `true` to enable projects for this repository or `false` to disable them. \*\*Note:\*\* If you're creating a repository in an organization that has disabled repository projects, the default is `false`, and if you pass `true`, the API returns an error.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_projects: Option<bool>,
+    /**
+     * Either `true` to enable the wiki for this repository or `false` to disable it.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_wiki: Option<bool>,
+    /**
+     * A URL with more information about the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub homepage: String,
+    /**
+     * Either `true` to make this repo available as a template repository or `false` to prevent it.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_template: Option<bool>,
+    /**
+     * The name of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * Either `true` to make the repository private or `false` to make it public. Default: `false`.  
+     *  \*\*Note\*\*: You will get a `422` error if the organization restricts [changing repository visibility](https://help.github.com/articles/repository-permission-levels-for-an-organization#changing-the-visibility-of-repositories) to organization owners and a non-owner tries to change the value of private. \*\*Note\*\*: You will get a `422` error if the organization restricts [changing repository visibility](https://help.github.com/articles/repository-permission-levels-for-an-organization#changing-the-visibility-of-repositories) to organization owners and a non-owner tries to change the value of private.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<bool>,
     /**
      * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub security_and_analysis: Option<ReposUpdateRequestSecurityAnalysis>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub security_and_analysis: crate::utils::Patch<ReposUpdateRequestSecurityAnalysis>,
     /**
-     * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
-     *  The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
+     * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. The `visibility` parameter overrides the `private` parameter when you use both along with the `nebula-preview` preview header.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<ReposCreateInOrgRequestVisibility>,
@@ -27529,13 +29749,15 @@ pub struct ActionsListWorkflowRunsResponse {
 *   \* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ActionsListJobsWorkflowRunFilter {
     #[serde(rename = "all")]
     All,
     #[serde(rename = "latest")]
     Latest,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
@@ -27543,12 +29765,31 @@ impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
         match self {
             ActionsListJobsWorkflowRunFilter::All => "all",
             ActionsListJobsWorkflowRunFilter::Latest => "latest",
-            ActionsListJobsWorkflowRunFilter::FallthroughString => "*",
+            ActionsListJobsWorkflowRunFilter::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ActionsListJobsWorkflowRunFilter {
+    fn from(s: String) -> ActionsListJobsWorkflowRunFilter {
+        match s.as_str() {
+            "all" => ActionsListJobsWorkflowRunFilter::All,
+            "latest" => ActionsListJobsWorkflowRunFilter::Latest,
+            _ => ActionsListJobsWorkflowRunFilter::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ActionsListJobsWorkflowRunFilter> for String {
+    fn from(e: ActionsListJobsWorkflowRunFilter) -> String {
+        match e {
+            ActionsListJobsWorkflowRunFilter::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ActionsListJobsWorkflowRunFilter {
     fn default() -> ActionsListJobsWorkflowRunFilter {
         ActionsListJobsWorkflowRunFilter::Latest
@@ -27573,6 +29814,9 @@ pub struct ActionsListJobsWorkflowRunResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsReviewPendingDeploymentsRunRequest {
+    /**
+     * A comment to accompany the deployment review
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27580,7 +29824,7 @@ pub struct ActionsReviewPendingDeploymentsRunRequest {
     )]
     pub comment: String,
     /**
-     * Code Frequency Stat
+     * The list of environment ids to approve or reject
      */
     #[serde(
         default,
@@ -27589,7 +29833,7 @@ pub struct ActionsReviewPendingDeploymentsRunRequest {
     )]
     pub environment_ids: Vec<i64>,
     /**
-     * Whether deployment to the environment(s) was approved or rejected
+     * Whether to approve or reject deployment to the specified environments. Must be one of: `approved` or `rejected`
      */
     #[serde(default, skip_serializing_if = "EnvironmentApprovalState::is_noop")]
     pub state: EnvironmentApprovalState,
@@ -27613,12 +29857,18 @@ pub struct ActionsListRepoSecretsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsCreateUpdateRepoSecretRequest {
+    /**
+     * Value for your secret, encrypted with [LibSodium](https://libsodium.gitbook.io/doc/bindings_for_other_languages) using the public key retrieved from the [Get a repository public key](https://docs.github.com/rest/reference/actions#get-a-repository-public-key) endpoint.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub encrypted_value: String,
+    /**
+     * ID of the key you used to encrypt the secret.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27645,12 +29895,18 @@ pub struct ActionsListRepoWorkflowsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsCreateWorkflowDispatchRequest {
+    /**
+     * Input keys and values configured in the workflow file. The maximum number of properties is 10. Any default properties configured in the workflow file will be used when `inputs` are omitted.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub inputs: String,
+    /**
+     * The git reference for the workflow. The reference can be a branch or tag name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27662,12 +29918,18 @@ pub struct ActionsCreateWorkflowDispatchRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateAutolinkRequest {
+    /**
+     * The prefix appended by a number will generate a link any time it is found in an issue, pull request, or commit.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key_prefix: String,
+    /**
+     * The URL must contain <num> for the reference number.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -27680,7 +29942,7 @@ pub struct ReposCreateAutolinkRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateBranchProtectionRequestRequiredStatusChecks {
     /**
-     * The list of events for the GitHub app
+     * The list of status checks to require in order to merge into this branch
      */
     #[serde(
         default,
@@ -27688,6 +29950,9 @@ pub struct ReposUpdateBranchProtectionRequestRequiredStatusChecks {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub contexts: Vec<String>,
+    /**
+     * Require branches to be up to date before merging.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -27699,7 +29964,7 @@ pub struct ReposUpdateBranchProtectionRequestRequiredStatusChecks {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions {
     /**
-     * Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
+     * The list of team `slug`s with dismissal access
      */
     #[serde(
         default,
@@ -27708,7 +29973,7 @@ pub struct ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestric
     )]
     pub teams: Vec<String>,
     /**
-     * Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
+     * The list of user `login`s with dismissal access
      */
     #[serde(
         default,
@@ -27722,7 +29987,7 @@ pub struct ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestric
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateBranchProtectionRequestRequiredPullReviews {
     /**
-     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     * Set to `true` if you want to automatically dismiss approving reviews when someone pushes a new commit.
      */
     #[serde(
         default,
@@ -27730,13 +29995,13 @@ pub struct ReposUpdateBranchProtectionRequestRequiredPullReviews {
     )]
     pub dismiss_stale_reviews: bool,
     /**
-     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     * Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dismissal_restrictions:
         Option<ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions>,
     /**
-     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     * Blocks merging pull requests until [code owners](https://help.github.com/articles/about-code-owners/) review them.
      */
     #[serde(
         default,
@@ -27744,7 +30009,7 @@ pub struct ReposUpdateBranchProtectionRequestRequiredPullReviews {
     )]
     pub require_code_owner_reviews: bool,
     /**
-     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     * Specify the number of reviewers required to approve pull requests. Use a number between 1 and 6.
      */
     #[serde(
         default,
@@ -27758,7 +30023,7 @@ pub struct ReposUpdateBranchProtectionRequestRequiredPullReviews {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Restrictions {
     /**
-     * Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
+     * The list of app `slug`s with push access
      */
     #[serde(
         default,
@@ -27767,7 +30032,7 @@ pub struct Restrictions {
     )]
     pub apps: Vec<String>,
     /**
-     * The list of events for the GitHub app
+     * The list of team `slug`s with push access
      */
     #[serde(
         default,
@@ -27776,7 +30041,7 @@ pub struct Restrictions {
     )]
     pub teams: Vec<String>,
     /**
-     * The list of events for the GitHub app
+     * The list of user `login`s with push access
      */
     #[serde(
         default,
@@ -27788,14 +30053,29 @@ pub struct Restrictions {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateBranchProtectionRequest {
+    /**
+     * Allows deletion of the protected branch by anyone with write access to the repository. Set to `false` to prevent deletion of the protected branch. Default: `false`. For more information, see "[Enabling force pushes to a protected branch](https://help.github.com/en/github/administering-a-repository/enabling-force-pushes-to-a-protected-branch)" in the GitHub Help documentation.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_deletions: Option<bool>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub allow_force_pushes: Option<bool>,
+    /**
+     * Permits force pushes to the protected branch by anyone with write access to the repository. Set to `true` to allow force pushes. Set to `false` or `null` to block force pushes. Default: `false`. For more information, see "[Enabling force pushes to a protected branch](https://help.github.com/en/github/administering-a-repository/enabling-force-pushes-to-a-protected-branch)" in the GitHub Help documentation."
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub allow_force_pushes: crate::utils::Patch<bool>,
+    /**
+     * Enforce all configured restrictions for administrators. Set to `true` to enforce required status checks for repository administrators. Set to `null` to disable.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enforce_admins: Option<bool>,
+    /**
+     * Requires all conversations on code to be resolved before a pull request can be merged into a branch that matches this rule. Set to `false` to disable. Default: `false`.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_conversation_resolution: Option<bool>,
+    /**
+     * Enforces a linear commit Git history, which prevents anyone from pushing merge commits to a branch. Set to `true` to enforce a linear commit history. Set to `false` to disable a linear commit Git history. Your repository must allow squash merging or rebase merging before you can enable a linear commit history. Default: `false`. For more information, see "[Requiring a linear commit history](https://help.github.com/github/administering-a-repository/requiring-a-linear-commit-history)" in the GitHub Help documentation.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required_linear_history: Option<bool>,
     /**
@@ -27818,6 +30098,9 @@ pub struct ReposUpdateBranchProtectionRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdatePullRequestReviewProtection {
+    /**
+     * Set to `true` if you want to automatically dismiss approving reviews when someone pushes a new commit.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -27829,11 +30112,17 @@ pub struct ReposUpdatePullRequestReviewProtection {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dismissal_restrictions:
         Option<ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions>,
+    /**
+     * Blocks merging pull requests until [code owners](https://help.github.com/articles/about-code-owners/) have reviewed.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub require_code_owner_reviews: bool,
+    /**
+     * Specifies the number of reviewers required to approve pull requests. Use a number between 1 and 6.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -27845,7 +30134,7 @@ pub struct ReposUpdatePullRequestReviewProtection {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateStatusCheckProtectionRequest {
     /**
-     * The list of events for the GitHub app
+     * The list of status checks to require in order to merge into this branch
      */
     #[serde(
         default,
@@ -27853,6 +30142,9 @@ pub struct ReposUpdateStatusCheckProtectionRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub contexts: Vec<String>,
+    /**
+     * Require branches to be up to date before merging.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub strict: Option<bool>,
 }
@@ -27860,7 +30152,7 @@ pub struct ReposUpdateStatusCheckProtectionRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposAddStatusCheckContextsRequest {
     /**
-     * The list of events for the GitHub app
+     * contexts parameter
      */
     #[serde(
         default,
@@ -27922,7 +30214,7 @@ impl std::convert::From<ReposAddStatusCheckContextsRequestOneOf> for Vec<String>
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposAddAppAccessRestrictionsRequest {
     /**
-     * The list of events for the GitHub app
+     * apps parameter
      */
     #[serde(
         default,
@@ -27985,7 +30277,7 @@ impl std::convert::From<ReposAddAppAccessRestrictionsRequestOneOf> for Vec<Strin
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposAddTeamAccessRestrictionsRequest {
     /**
-     * The list of events for the GitHub app
+     * teams parameter
      */
     #[serde(
         default,
@@ -28048,7 +30340,7 @@ impl std::convert::From<ReposAddTeamAccessRestrictionsRequestOneOf> for Vec<Stri
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposAddUserAccessRestrictionsRequest {
     /**
-     * The list of events for the GitHub app
+     * users parameter
      */
     #[serde(
         default,
@@ -28110,6 +30402,9 @@ impl std::convert::From<ReposAddUserAccessRestrictionsRequestOneOf> for Vec<Stri
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposRenameBranchRequest {
+    /**
+     * The new name of the branch.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28123,6 +30418,7 @@ pub struct ReposRenameBranchRequest {
 *   **Note:** Providing `conclusion` will automatically set the `status` parameter to `completed`. You cannot change a check run conclusion to `stale`, only GitHub can set this.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ChecksCreateRequestConclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -28142,8 +30438,9 @@ pub enum ChecksCreateRequestConclusion {
     TimedOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChecksCreateRequestConclusion {
@@ -28158,12 +30455,38 @@ impl std::fmt::Display for ChecksCreateRequestConclusion {
             ChecksCreateRequestConclusion::Success => "success",
             ChecksCreateRequestConclusion::TimedOut => "timed_out",
             ChecksCreateRequestConclusion::Noop => "",
-            ChecksCreateRequestConclusion::FallthroughString => "*",
+            ChecksCreateRequestConclusion::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ChecksCreateRequestConclusion {
+    fn from(s: String) -> ChecksCreateRequestConclusion {
+        match s.as_str() {
+            "action_required" => ChecksCreateRequestConclusion::ActionRequired,
+            "cancelled" => ChecksCreateRequestConclusion::Cancelled,
+            "failure" => ChecksCreateRequestConclusion::Failure,
+            "neutral" => ChecksCreateRequestConclusion::Neutral,
+            "skipped" => ChecksCreateRequestConclusion::Skipped,
+            "stale" => ChecksCreateRequestConclusion::Stale,
+            "success" => ChecksCreateRequestConclusion::Success,
+            "timed_out" => ChecksCreateRequestConclusion::TimedOut,
+            "" => ChecksCreateRequestConclusion::Noop,
+            _ => ChecksCreateRequestConclusion::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ChecksCreateRequestConclusion> for String {
+    fn from(e: ChecksCreateRequestConclusion) -> String {
+        match e {
+            ChecksCreateRequestConclusion::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ChecksCreateRequestConclusion {
     fn default() -> ChecksCreateRequestConclusion {
         ChecksCreateRequestConclusion::Noop
@@ -28179,6 +30502,7 @@ impl ChecksCreateRequestConclusion {
 * The level of the annotation. Can be one of `notice`, `warning`, or `failure`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AnnotationLevel {
     #[serde(rename = "failure")]
     Failure,
@@ -28188,8 +30512,9 @@ pub enum AnnotationLevel {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AnnotationLevel {
@@ -28199,12 +30524,33 @@ impl std::fmt::Display for AnnotationLevel {
             AnnotationLevel::Notice => "notice",
             AnnotationLevel::Warning => "warning",
             AnnotationLevel::Noop => "",
-            AnnotationLevel::FallthroughString => "*",
+            AnnotationLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AnnotationLevel {
+    fn from(s: String) -> AnnotationLevel {
+        match s.as_str() {
+            "failure" => AnnotationLevel::Failure,
+            "notice" => AnnotationLevel::Notice,
+            "warning" => AnnotationLevel::Warning,
+            "" => AnnotationLevel::Noop,
+            _ => AnnotationLevel::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AnnotationLevel> for String {
+    fn from(e: AnnotationLevel) -> String {
+        match e {
+            AnnotationLevel::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AnnotationLevel {
     fn default() -> AnnotationLevel {
         AnnotationLevel::Noop
@@ -28223,48 +30569,72 @@ pub struct Annotations {
      */
     #[serde(default, skip_serializing_if = "AnnotationLevel::is_noop")]
     pub annotation_level: AnnotationLevel,
+    /**
+     * The end column of the annotation. Annotations only support `start_column` and `end_column` on the same line. Omit this parameter if `start_line` and `end_line` have different values.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub end_column: i64,
+    /**
+     * The end line of the annotation.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub end_line: i64,
+    /**
+     * A short description of the feedback for these lines of code. The maximum size is 64 KB.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /**
+     * The path of the file to add an annotation to. For example, `assets/css/main.css`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /**
+     * Details about this annotation. The maximum size is 64 KB.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub raw_details: String,
+    /**
+     * The start column of the annotation. Annotations only support `start_column` and `end_column` on the same line. Omit this parameter if `start_line` and `end_line` have different values.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_column: i64,
+    /**
+     * The start line of the annotation.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_line: i64,
+    /**
+     * The title that represents the annotation. The maximum size is 255 characters.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28275,18 +30645,27 @@ pub struct Annotations {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Images {
+    /**
+     * The alternative text for the image.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub alt: String,
+    /**
+     * A short image description.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub caption: String,
+    /**
+     * The full URL of the image.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28299,7 +30678,7 @@ pub struct Images {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChecksCreateRequestOutput {
     /**
-     * Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object) description.
+     * Adds information from your analysis to specific lines of code. Annotations are visible on GitHub in the \*\*Checks\*\* and \*\*Files changed\*\* tab of the pull request. The Checks API limits the number of annotations to a maximum of 50 per API request. To create more than 50 annotations, you have to make multiple requests to the [Update a check run](https://docs.github.com/rest/reference/checks#update-a-check-run) endpoint. Each time you update the check run, annotations are appended to the list of annotations that already exist for the check run. For details about how you can view annotations on GitHub, see "[About status checks](https://help.github.com/articles/about-status-checks#checks)". See the [`annotations` object](https://docs.github.com/rest/reference/checks#annotations-object) description for details about how to use this parameter.
      */
     #[serde(
         default,
@@ -28308,7 +30687,7 @@ pub struct ChecksCreateRequestOutput {
     )]
     pub annotations: Vec<Annotations>,
     /**
-     * Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object) description.
+     * Adds images to the output displayed in the GitHub pull request UI. See the [`images` object](https://docs.github.com/rest/reference/checks#images-object) description for details.
      */
     #[serde(
         default,
@@ -28316,18 +30695,27 @@ pub struct ChecksCreateRequestOutput {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub images: Vec<Images>,
+    /**
+     * The summary of the check run. This parameter supports Markdown.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub summary: String,
+    /**
+     * The details of the check run. This parameter supports Markdown.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /**
+     * The title of the check run.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28338,18 +30726,27 @@ pub struct ChecksCreateRequestOutput {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChecksCreateRequestActions {
+    /**
+     * A short explanation of what this action would do. The maximum size is 40 characters.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * A reference for the action on the integrator's system. The maximum size is 20 characters.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub identifier: String,
+    /**
+     * The text to be displayed on a button in the web UI. The maximum size is 20 characters.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28369,6 +30766,9 @@ pub struct ChecksCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub actions: Vec<ChecksCreateRequestActions>,
+    /**
+     * The time the check completed. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -28381,24 +30781,36 @@ pub struct ChecksCreateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conclusion: Option<ChecksCreateRequestConclusion>,
+    /**
+     * The URL of the integrator's site that has the full details of the check. If the integrator does not provide this, then the homepage of the GitHub app is used.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub details_url: String,
+    /**
+     * A reference for the run on the integrator's system.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub external_id: String,
+    /**
+     * The SHA of the commit.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub head_sha: String,
+    /**
+     * The name of the check. For example, "code-coverage".
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28410,6 +30822,9 @@ pub struct ChecksCreateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub output: Option<ChecksCreateRequestOutput>,
+    /**
+     * The time that the check run began. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -28417,7 +30832,7 @@ pub struct ChecksCreateRequest {
     )]
     pub started_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The phase of the lifecycle that the job is currently in.
+     * The current status. Can be one of `queued`, `in_progress`, or `completed`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<JobStatus>,
@@ -28427,7 +30842,7 @@ pub struct ChecksCreateRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChecksUpdateRequestOutput {
     /**
-     * Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object-1) description.
+     * Adds information from your analysis to specific lines of code. Annotations are visible in GitHub's pull request UI. Annotations are visible in GitHub's pull request UI. The Checks API limits the number of annotations to a maximum of 50 per API request. To create more than 50 annotations, you have to make multiple requests to the [Update a check run](https://docs.github.com/rest/reference/checks#update-a-check-run) endpoint. Each time you update the check run, annotations are appended to the list of annotations that already exist for the check run. For details about annotations in the UI, see "[About status checks](https://help.github.com/articles/about-status-checks#checks)". See the [`annotations` object](https://docs.github.com/rest/reference/checks#annotations-object-1) description for details.
      */
     #[serde(
         default,
@@ -28436,7 +30851,7 @@ pub struct ChecksUpdateRequestOutput {
     )]
     pub annotations: Vec<Annotations>,
     /**
-     * Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object-1) description.
+     * Adds images to the output displayed in the GitHub pull request UI. See the [`images` object](https://docs.github.com/rest/reference/checks#annotations-object-1) description for details.
      */
     #[serde(
         default,
@@ -28444,18 +30859,27 @@ pub struct ChecksUpdateRequestOutput {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub images: Vec<Images>,
+    /**
+     * Can contain Markdown.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub summary: String,
+    /**
+     * Can contain Markdown.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub text: String,
+    /**
+     * \*\*Required\*\*.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28467,7 +30891,7 @@ pub struct ChecksUpdateRequestOutput {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChecksUpdateRequest {
     /**
-     * Displays a button on GitHub that can be clicked to alert your app to do additional tasks. For example, a code linting app can display a button that automatically fixes detected errors. The button created in this object is displayed after the check run completes. When a user clicks the button, GitHub sends the [`check_run.requested_action` webhook](https://docs.github.com/webhooks/event-payloads/#check_run) to your app. Each action includes a `label`, `identifier` and `description`. A maximum of three actions are accepted. See the [`actions` object](https://docs.github.com/rest/reference/checks#actions-object) description. To learn more about check runs and requested actions, see "[Check runs and requested actions](https://docs.github.com/rest/reference/checks#check-runs-and-requested-actions)." To learn more about check runs and requested actions, see "[Check runs and requested actions](https://docs.github.com/rest/reference/checks#check-runs-and-requested-actions)."
+     * Possible further actions the integrator can perform, which a user may trigger. Each action includes a `label`, `identifier` and `description`. A maximum of three actions are accepted. See the [`actions` object](https://docs.github.com/rest/reference/checks#actions-object) description. To learn more about check runs and requested actions, see "[Check runs and requested actions](https://docs.github.com/rest/reference/checks#check-runs-and-requested-actions)."
      */
     #[serde(
         default,
@@ -28475,6 +30899,9 @@ pub struct ChecksUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub actions: Vec<ChecksCreateRequestActions>,
+    /**
+     * The time the check completed. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -28482,23 +30909,32 @@ pub struct ChecksUpdateRequest {
     )]
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * \*\*Required if you provide `completed_at` or a `status` of `completed`\*\*. The final conclusion of the check. Can be one of `action_required`, `cancelled`, `failure`, `neutral`, `success`, `skipped`, `stale`, or `timed_out`. When the conclusion is `action_required`, additional details should be provided on the site specified by `details_url`.  
+     * \*\*Required if you provide `completed_at` or a `status` of `completed`\*\*. The final conclusion of the check. Can be one of `action_required`, `cancelled`, `failure`, `neutral`, `success`, `skipped`, `stale`, or `timed_out`.  
      *  \*\*Note:\*\* Providing `conclusion` will automatically set the `status` parameter to `completed`. You cannot change a check run conclusion to `stale`, only GitHub can set this.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conclusion: Option<ChecksCreateRequestConclusion>,
+    /**
+     * The URL of the integrator's site that has the full details of the check.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub details_url: String,
+    /**
+     * A reference for the run on the integrator's system.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub external_id: String,
+    /**
+     * The name of the check. For example, "code-coverage".
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28510,6 +30946,9 @@ pub struct ChecksUpdateRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub output: Option<ChecksUpdateRequestOutput>,
+    /**
+     * This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -28517,7 +30956,7 @@ pub struct ChecksUpdateRequest {
     )]
     pub started_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The phase of the lifecycle that the job is currently in.
+     * The current status. Can be one of `queued`, `in_progress`, or `completed`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<JobStatus>,
@@ -28525,6 +30964,9 @@ pub struct ChecksUpdateRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChecksCreateSuiteRequest {
+    /**
+     * The sha of the head commit.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28565,6 +31007,10 @@ pub struct CodeScanningUpdateAlertRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CodeScanningUploadSarifRequest {
+    /**
+     * The base directory used in the analysis, as it appears in the SARIF file.
+     *  This property is used to convert file paths from absolute to relative, so that alerts can be mapped to their correct location in the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28590,12 +31036,18 @@ pub struct CodeScanningUploadSarifRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sarif: String,
+    /**
+     * The time that the analysis run began. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The name of the tool used to generate the code scanning analysis. If this parameter is not used, the tool name defaults to "API". If the uploaded SARIF contains a tool GUID, this will be available for filtering using the `tool_guid` parameter of operations such as `GET /repos/{owner}/{repo}/code-scanning/alerts`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28607,14 +31059,12 @@ pub struct CodeScanningUploadSarifRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposAddCollaboratorRequest {
     /**
-     * The permission to grant the team on this repository. Can be one of:  
-     *  \\* `pull` - team members can pull, but not push to or administer this repository.  
-     *  \\* `push` - team members can pull and push, but not administer this repository.  
-     *  \\* `admin` - team members can pull, push and administer this repository.  
-     *  \\* `maintain` - team members can manage the repository without access to sensitive or destructive actions. Recommended for project managers. Only applies to repositories owned by organizations.  
-     *  \\* `triage` - team members can proactively manage issues and pull requests without write access. Recommended for contributors who triage a repository. Only applies to repositories owned by organizations.  
-     *    
-     *  If no permission is specified, the team's `permission` attribute will be used to determine what permission to grant the team on this repository.
+     * The permission to grant the collaborator. \*\*Only valid on organization-owned repositories.\*\* Can be one of:  
+     *  \\* `pull` - can pull, but not push to or administer this repository.  
+     *  \\* `push` - can pull and push, but not administer this repository.  
+     *  \\* `admin` - can pull, push and administer this repository.  
+     *  \\* `maintain` - Recommended for project managers who need to manage the repository without access to sensitive or destructive actions.  
+     *  \\* `triage` - Recommended for contributors who need to proactively manage issues and pull requests without write access.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<TeamsAddUpdateRepoPermissionsInOrgRequestPermission>,
@@ -28628,24 +31078,36 @@ pub struct ReposAddCollaboratorRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateCommitCommentRequest {
+    /**
+     * The contents of the comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * \*\*Deprecated\*\*. Use \*\*position\*\* parameter instead. Line number in the file to comment on.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub line: i64,
+    /**
+     * Relative path of the file to comment on.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /**
+     * Line index in the diff to comment on.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -28741,28 +31203,46 @@ impl std::convert::From<ReposGetContentResponseOneOf> for Vec<Entries> {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateUpdateFileContentsRequest {
+    /**
+     * The author of the file. Default: The `committer` or the authenticated user if you omit `committer`.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<Tagger>,
+    /**
+     * The branch name. Default: the repository’s default branch (usually `master`)
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub branch: String,
+    /**
+     * The person that committed the file. Default: the authenticated user.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub committer: Option<Tagger>,
+    /**
+     * The new file content, using Base64 encoding.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content: String,
+    /**
+     * The commit message.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /**
+     * \*\*Required if you are updating a file\*\*. The blob SHA of the file being replaced.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28773,22 +31253,37 @@ pub struct ReposCreateUpdateFileContentsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposDeleteFileRequest {
+    /**
+     * object containing information about the author.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<Author>,
+    /**
+     * The branch name. Default: the repository’s default branch (usually `master`)
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub branch: String,
+    /**
+     * object containing information about the committer.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub committer: Option<Author>,
+    /**
+     * The commit message.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /**
+     * The blob SHA of the file being replaced.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28799,14 +31294,19 @@ pub struct ReposDeleteFileRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateDeploymentRequest {
+    /**
+     * Attempts to automatically merge the default branch into the requested ref, if it's behind the default branch.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_merge: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    /**
+     * Short description of the deployment.
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub description: crate::utils::Patch<String>,
+    /**
+     * Name for the target deployment environment (e.g., `production`, `staging`, `qa`).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28815,8 +31315,15 @@ pub struct ReposCreateDeploymentRequest {
     pub environment: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payload: Option<PayloadOneOf>,
+    /**
+     * Specifies if the given environment is one that end-users directly interact with. Default: `true` when `environment` is `production` and `false` otherwise.  
+     *  \*\*Note:\*\* This parameter requires you to use the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub production_environment: Option<bool>,
+    /**
+     * The ref to deploy. This can be a branch, tag, or SHA.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28825,7 +31332,7 @@ pub struct ReposCreateDeploymentRequest {
     )]
     pub ref_: String,
     /**
-     * The list of events for the GitHub app
+     * The [status](https://docs.github.com/rest/reference/repos#statuses) contexts to verify against commit status checks. If you omit this parameter, GitHub verifies all unique contexts before creating a deployment. To bypass checking entirely, pass an empty array. Defaults to all unique contexts.
      */
     #[serde(
         default,
@@ -28833,12 +31340,19 @@ pub struct ReposCreateDeploymentRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub required_contexts: Vec<String>,
+    /**
+     * Specifies a task to execute (e.g., `deploy` or `deploy:migrations`).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub task: String,
+    /**
+     * Specifies if the given environment is specific to the deployment and will no longer exist at some point in the future. Default: `false`  
+     *  \*\*Note:\*\* This parameter requires you to use the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub transient_environment: Option<bool>,
 }
@@ -28847,6 +31361,7 @@ pub struct ReposCreateDeploymentRequest {
 * Name for the target deployment environment, which can be changed when setting a deploy status. For example, `production`, `staging`, or `qa`. **Note:** This parameter requires you to use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposCreateDeploymentStatusRequestEnvironment {
     #[serde(rename = "production")]
     Production,
@@ -28856,8 +31371,9 @@ pub enum ReposCreateDeploymentStatusRequestEnvironment {
     Staging,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
@@ -28867,12 +31383,33 @@ impl std::fmt::Display for ReposCreateDeploymentStatusRequestEnvironment {
             ReposCreateDeploymentStatusRequestEnvironment::Qa => "qa",
             ReposCreateDeploymentStatusRequestEnvironment::Staging => "staging",
             ReposCreateDeploymentStatusRequestEnvironment::Noop => "",
-            ReposCreateDeploymentStatusRequestEnvironment::FallthroughString => "*",
+            ReposCreateDeploymentStatusRequestEnvironment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposCreateDeploymentStatusRequestEnvironment {
+    fn from(s: String) -> ReposCreateDeploymentStatusRequestEnvironment {
+        match s.as_str() {
+            "production" => ReposCreateDeploymentStatusRequestEnvironment::Production,
+            "qa" => ReposCreateDeploymentStatusRequestEnvironment::Qa,
+            "staging" => ReposCreateDeploymentStatusRequestEnvironment::Staging,
+            "" => ReposCreateDeploymentStatusRequestEnvironment::Noop,
+            _ => ReposCreateDeploymentStatusRequestEnvironment::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposCreateDeploymentStatusRequestEnvironment> for String {
+    fn from(e: ReposCreateDeploymentStatusRequestEnvironment) -> String {
+        match e {
+            ReposCreateDeploymentStatusRequestEnvironment::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposCreateDeploymentStatusRequestEnvironment {
     fn default() -> ReposCreateDeploymentStatusRequestEnvironment {
         ReposCreateDeploymentStatusRequestEnvironment::Noop
@@ -28886,8 +31423,16 @@ impl ReposCreateDeploymentStatusRequestEnvironment {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateDeploymentStatusRequest {
+    /**
+     * Adds a new `inactive` status to all prior non-transient, non-production environment deployments with the same repository and `environment` name as the created status's deployment. An `inactive` status is only added to deployments that had a `success` state. Default: `true`  
+     *  \*\*Note:\*\* To add an `inactive` status to `production` environments, you must use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.  
+     *  \*\*Note:\*\* This parameter requires you to use the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_inactive: Option<bool>,
+    /**
+     * A short description of the status. The maximum description length is 140 characters.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28899,12 +31444,20 @@ pub struct ReposCreateDeploymentStatusRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub environment: Option<ReposCreateDeploymentStatusRequestEnvironment>,
+    /**
+     * Sets the URL for accessing your environment. Default: `""`  
+     *  \*\*Note:\*\* This parameter requires you to use the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub environment_url: String,
+    /**
+     * The full URL of the deployment's output. This parameter replaces `target_url`. We will continue to accept `target_url` to support legacy uses, but we recommend replacing `target_url` with `log_url`. Setting `log_url` will automatically set `target_url` to the same value. Default: `""`  
+     *  \*\*Note:\*\* This parameter requires you to use the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28912,10 +31465,13 @@ pub struct ReposCreateDeploymentStatusRequest {
     )]
     pub log_url: String,
     /**
-     * The state of the status.
+     * The state of the status. Can be one of `error`, `failure`, `inactive`, `in_progress`, `queued` `pending`, or `success`. \*\*Note:\*\* To use the `inactive` state, you must provide the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type. To use the `in_progress` and `queued` states, you must provide the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type. When you set a transient deployment to `inactive`, the deployment will be shown as `destroyed` in GitHub.
      */
     #[serde(default, skip_serializing_if = "DeploymentStatusState::is_noop")]
     pub state: DeploymentStatusState,
+    /**
+     * The target URL to associate with this status. This URL should contain output to keep the user updated while the task is running or serve as historical information for what happened in the deployment. \*\*Note:\*\* It's recommended to use the `log_url` parameter, which replaces `target_url`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28926,8 +31482,14 @@ pub struct ReposCreateDeploymentStatusRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateDispatchEventRequest {
+    /**
+     * JSON payload with extra information about the webhook event that your action or worklow may use.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub client_payload: Option<Data>,
+    /**
+     * A custom webhook event name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -28944,6 +31506,9 @@ pub struct ReposGetAllEnvironmentsResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub environments: Vec<EnvironmentData>,
+    /**
+     * The number of environments in this repository
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -28954,6 +31519,9 @@ pub struct ReposGetAllEnvironmentsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateUpdateEnvironmentRequestReviewers {
+    /**
+     * The id of the user or team who can review the deployment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -28977,12 +31545,8 @@ pub struct ReposCreateUpdateEnvironmentRequest {
     /**
      * The people or teams that may review jobs that reference the environment. You can list up to six users or teams as reviewers. The reviewers must have at least read access to the repository. Only one of the required reviewers needs to approve the job for it to proceed.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub reviewers: Vec<ReposCreateUpdateEnvironmentRequestReviewers>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub reviewers: crate::utils::Patch<Vec<ReposCreateUpdateEnvironmentRequestReviewers>>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -28995,6 +31559,7 @@ pub struct ReposCreateUpdateEnvironmentRequest {
 * The sort order. Can be either `newest`, `oldest`, or `stargazers`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposListForksSort {
     #[serde(rename = "newest")]
     Newest,
@@ -29004,8 +31569,9 @@ pub enum ReposListForksSort {
     Stargazers,
     #[serde(rename = "watchers")]
     Watchers,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListForksSort {
@@ -29015,12 +31581,33 @@ impl std::fmt::Display for ReposListForksSort {
             ReposListForksSort::Oldest => "oldest",
             ReposListForksSort::Stargazers => "stargazers",
             ReposListForksSort::Watchers => "watchers",
-            ReposListForksSort::FallthroughString => "*",
+            ReposListForksSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposListForksSort {
+    fn from(s: String) -> ReposListForksSort {
+        match s.as_str() {
+            "newest" => ReposListForksSort::Newest,
+            "oldest" => ReposListForksSort::Oldest,
+            "stargazers" => ReposListForksSort::Stargazers,
+            "watchers" => ReposListForksSort::Watchers,
+            _ => ReposListForksSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposListForksSort> for String {
+    fn from(e: ReposListForksSort) -> String {
+        match e {
+            ReposListForksSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposListForksSort {
     fn default() -> ReposListForksSort {
         ReposListForksSort::Newest
@@ -29029,6 +31616,9 @@ impl Default for ReposListForksSort {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateForkRequest {
+    /**
+     * Optional parameter to specify the organization name if forking into an organization.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29039,12 +31629,18 @@ pub struct ReposCreateForkRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitCreateBlobRequest {
+    /**
+     * The new blob's content.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content: String,
+    /**
+     * The encoding used for `content`. Currently, `"utf-8"` and `"base64"` are supported.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29057,7 +31653,7 @@ pub struct GitCreateBlobRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitCreateCommitRequestAuthor {
     /**
-     * Information about the author of the commit. By default, the `author` will be the authenticated user and the current date. See the `author` and `committer` object below for details.
+     * Indicates when this commit was authored (or committed). This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
      */
     #[serde(
         default,
@@ -29065,12 +31661,18 @@ pub struct GitCreateCommitRequestAuthor {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The email of the author (or committer) of the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The name of the author (or committer) of the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29083,7 +31685,7 @@ pub struct GitCreateCommitRequestAuthor {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitCreateCommitRequestCommitter {
     /**
-     * Information about the person who is making the commit. By default, `committer` will use the information set in `author`. See the `author` and `committer` object below for details.
+     * Indicates when this commit was authored (or committed). This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
      */
     #[serde(
         default,
@@ -29091,12 +31693,18 @@ pub struct GitCreateCommitRequestCommitter {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The email of the author (or committer) of the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The name of the author (or committer) of the commit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29117,6 +31725,9 @@ pub struct GitCreateCommitRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub committer: Option<GitCreateCommitRequestCommitter>,
+    /**
+     * The commit message
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29124,7 +31735,7 @@ pub struct GitCreateCommitRequest {
     )]
     pub message: String,
     /**
-     * The list of events for the GitHub app
+     * The SHAs of the commits that were the parents of this commit. If omitted or empty, the commit will be written as a root commit. For a single parent, an array of one SHA should be provided; for a merge commit, an array of more than one should be provided.
      */
     #[serde(
         default,
@@ -29132,12 +31743,18 @@ pub struct GitCreateCommitRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub parents: Vec<String>,
+    /**
+     * The [PGP signature](https://en.wikipedia.org/wiki/Pretty_Good_Privacy) of the commit. GitHub adds the signature to the `gpgsig` header of the created commit. For a commit signature to be verifiable by Git or GitHub, it must be an ASCII-armored detached PGP signature over the string commit as it would be written to the object database. To pass a `signature` parameter, you need to first manually create a valid PGP signature, which can be complicated. You may find it easier to [use the command line](https://git-scm.com/book/id/v2/Git-Tools-Signing-Your-Work) to create signed commits.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub signature: String,
+    /**
+     * The SHA of the tree object this commit points to
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29154,6 +31771,9 @@ pub struct GitCreateRefRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key: String,
+    /**
+     * The name of the fully qualified reference (ie: `refs/heads/master`). If it doesn't start with 'refs' and have at least two slashes, it will be rejected.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29161,6 +31781,9 @@ pub struct GitCreateRefRequest {
         rename = "ref"
     )]
     pub ref_: String,
+    /**
+     * The SHA1 value for this reference.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29171,8 +31794,14 @@ pub struct GitCreateRefRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitUpdateRefRequest {
+    /**
+     * Indicates whether to force the update or to make sure the update is a fast-forward update. Leaving this out or setting it to `false` will make sure you're not overwriting work.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub force: Option<bool>,
+    /**
+     * The SHA1 value to set this reference to
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29185,6 +31814,7 @@ pub struct GitUpdateRefRequest {
 * The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GitCreateTagRequestType {
     #[serde(rename = "blob")]
     Blob,
@@ -29194,8 +31824,9 @@ pub enum GitCreateTagRequestType {
     Tree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GitCreateTagRequestType {
@@ -29205,12 +31836,33 @@ impl std::fmt::Display for GitCreateTagRequestType {
             GitCreateTagRequestType::Commit => "commit",
             GitCreateTagRequestType::Tree => "tree",
             GitCreateTagRequestType::Noop => "",
-            GitCreateTagRequestType::FallthroughString => "*",
+            GitCreateTagRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GitCreateTagRequestType {
+    fn from(s: String) -> GitCreateTagRequestType {
+        match s.as_str() {
+            "blob" => GitCreateTagRequestType::Blob,
+            "commit" => GitCreateTagRequestType::Commit,
+            "tree" => GitCreateTagRequestType::Tree,
+            "" => GitCreateTagRequestType::Noop,
+            _ => GitCreateTagRequestType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GitCreateTagRequestType> for String {
+    fn from(e: GitCreateTagRequestType) -> String {
+        match e {
+            GitCreateTagRequestType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GitCreateTagRequestType {
     fn default() -> GitCreateTagRequestType {
         GitCreateTagRequestType::Noop
@@ -29226,7 +31878,7 @@ impl GitCreateTagRequestType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitCreateTagRequestTagger {
     /**
-     * An object with information about the individual creating the tag.
+     * When this object was tagged. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
      */
     #[serde(
         default,
@@ -29234,12 +31886,18 @@ pub struct GitCreateTagRequestTagger {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The email of the author of the tag
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The name of the author of the tag
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29250,18 +31908,27 @@ pub struct GitCreateTagRequestTagger {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitCreateTagRequest {
+    /**
+     * The tag message.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
+    /**
+     * The SHA of the git object this is tagging.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub object: String,
+    /**
+     * The tag's name. This is typically a version (e.g., "v0.0.1").
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29288,6 +31955,7 @@ pub struct GitCreateTagRequest {
 * The file mode; one of `100644` for file (blob), `100755` for executable (blob), `040000` for subdirectory (tree), `160000` for submodule (commit), or `120000` for a blob that specifies the path of a symlink.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GitCreateTreeRequestMode {
     #[serde(rename = "040000")]
     SubdirectoryTree,
@@ -29301,8 +31969,9 @@ pub enum GitCreateTreeRequestMode {
     SubmoduleCommit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GitCreateTreeRequestMode {
@@ -29314,12 +31983,35 @@ impl std::fmt::Display for GitCreateTreeRequestMode {
             GitCreateTreeRequestMode::SymlinkPathBlob => "120000",
             GitCreateTreeRequestMode::SubmoduleCommit => "160000",
             GitCreateTreeRequestMode::Noop => "",
-            GitCreateTreeRequestMode::FallthroughString => "*",
+            GitCreateTreeRequestMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GitCreateTreeRequestMode {
+    fn from(s: String) -> GitCreateTreeRequestMode {
+        match s.as_str() {
+            "040000" => GitCreateTreeRequestMode::SubdirectoryTree,
+            "100644" => GitCreateTreeRequestMode::FileBlob,
+            "100755" => GitCreateTreeRequestMode::ExecutableBlob,
+            "120000" => GitCreateTreeRequestMode::SymlinkPathBlob,
+            "160000" => GitCreateTreeRequestMode::SubmoduleCommit,
+            "" => GitCreateTreeRequestMode::Noop,
+            _ => GitCreateTreeRequestMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GitCreateTreeRequestMode> for String {
+    fn from(e: GitCreateTreeRequestMode) -> String {
+        match e {
+            GitCreateTreeRequestMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GitCreateTreeRequestMode {
     fn default() -> GitCreateTreeRequestMode {
         GitCreateTreeRequestMode::Noop
@@ -29333,6 +32025,11 @@ impl GitCreateTreeRequestMode {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitCreateTreeRequest {
+    /**
+     * The content you want this file to have. GitHub will write this blob out and use that SHA for this entry. Use either this, or `tree.sha`.  
+     *    
+     *  \*\*Note:\*\* Use either `tree.sha` or `content` to specify the contents of the entry. Using both `tree.sha` and `content` will return an error.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29344,12 +32041,20 @@ pub struct GitCreateTreeRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mode: Option<GitCreateTreeRequestMode>,
+    /**
+     * The file referenced in the tree.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /**
+     * The SHA1 checksum ID of the object in the tree. Also called `tree.sha`. If the value is `null` then the file will be deleted.  
+     *    
+     *  \*\*Note:\*\* Use either `tree.sha` or `content` to specify the contents of the entry. Using both `tree.sha` and `content` will return an error.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29357,7 +32062,7 @@ pub struct GitCreateTreeRequest {
     )]
     pub sha: String,
     /**
-     * The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
+     * Either `blob`, `tree`, or `commit`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<GitCreateTagRequestType>,
@@ -29365,6 +32070,11 @@ pub struct GitCreateTreeRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GitCreateTreeRequestData {
+    /**
+     * The SHA1 of an existing Git tree object which will be used as the base for the new tree. If provided, a new Git tree object will be created from entries in the Git tree object pointed to by `base_tree` and entries defined in the `tree` parameter. Entries defined in the `tree` parameter will overwrite items from `base_tree` with the same `path`. If you're creating new changes on a branch, then normally you'd set `base_tree` to the SHA1 of the Git tree object of the current latest commit on the branch you're working on.
+     *  If not provided, GitHub will create a new Git tree object from only the entries defined in the `tree` parameter. If you create a new commit pointing to such a tree, then all files which were a part of the parent commit's tree and were not defined in the `tree` parameter will be listed as deleted by the new commit.
+     *
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29397,9 +32107,6 @@ pub struct ReposCreateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub digest: String,
-    /**
-     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
     #[serde(
@@ -29424,16 +32131,28 @@ pub struct ReposCreateWebhookRequestConfig {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateWebhookRequest {
+    /**
+     * Determines if notifications are sent when the webhook is triggered. Set to `true` to send notifications.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
+    /**
+     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub config: Option<ReposCreateWebhookRequestConfig>,
+    /**
+     * Determines what [events](https://docs.github.com/webhooks/event-payloads) the hook is triggered for.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub events: Vec<String>,
+    /**
+     * Use `web` to create a webhook. Default: `web`. This parameter only accepts the value `web`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29457,9 +32176,6 @@ pub struct ReposUpdateWebhookRequestConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content_type: String,
-    /**
-     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
     #[serde(
@@ -29484,10 +32200,13 @@ pub struct ReposUpdateWebhookRequestConfig {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateWebhookRequest {
+    /**
+     * Determines if notifications are sent when the webhook is triggered. Set to `true` to send notifications.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
-     * The list of events for the GitHub app
+     * Determines a list of events to be added to the list of events that the Hook triggers for.
      */
     #[serde(
         default,
@@ -29501,7 +32220,7 @@ pub struct ReposUpdateWebhookRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub config: Option<ReposUpdateWebhookRequestConfig>,
     /**
-     * The list of events for the GitHub app
+     * Determines what [events](https://docs.github.com/webhooks/event-payloads) the hook is triggered for. This replaces the entire array of events.
      */
     #[serde(
         default,
@@ -29510,7 +32229,7 @@ pub struct ReposUpdateWebhookRequest {
     )]
     pub events: Vec<String>,
     /**
-     * The list of events for the GitHub app
+     * Determines a list of events to be removed from the list of events that the Hook triggers for.
      */
     #[serde(
         default,
@@ -29524,6 +32243,7 @@ pub struct ReposUpdateWebhookRequest {
 * The originating VCS type. Can be one of `subversion`, `git`, `mercurial`, or `tfvc`. Please be aware that without this parameter, the import job will take additional time to detect the VCS type before beginning the import. This detection step will be reflected in the response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Vcs {
     #[serde(rename = "git")]
     Git,
@@ -29535,8 +32255,9 @@ pub enum Vcs {
     Tfvc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Vcs {
@@ -29547,12 +32268,34 @@ impl std::fmt::Display for Vcs {
             Vcs::Subversion => "subversion",
             Vcs::Tfvc => "tfvc",
             Vcs::Noop => "",
-            Vcs::FallthroughString => "*",
+            Vcs::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Vcs {
+    fn from(s: String) -> Vcs {
+        match s.as_str() {
+            "git" => Vcs::Git,
+            "mercurial" => Vcs::Mercurial,
+            "subversion" => Vcs::Subversion,
+            "tfvc" => Vcs::Tfvc,
+            "" => Vcs::Noop,
+            _ => Vcs::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Vcs> for String {
+    fn from(e: Vcs) -> String {
+        match e {
+            Vcs::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Vcs {
     fn default() -> Vcs {
         Vcs::Noop
@@ -29566,6 +32309,9 @@ impl Vcs {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MigrationsStartImportRequest {
+    /**
+     * For a tfvc import, the name of the project that is being imported.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29577,18 +32323,27 @@ pub struct MigrationsStartImportRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vcs: Option<Vcs>,
+    /**
+     * If authentication is required, the password to provide to `vcs_url`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs_password: String,
+    /**
+     * The URL of the originating repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs_url: String,
+    /**
+     * If authentication is required, the username to provide to `vcs_url`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29611,12 +32366,18 @@ pub struct MigrationsUpdateImportRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs: String,
+    /**
+     * The password to provide to the originating repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vcs_password: String,
+    /**
+     * The username to provide to the originating repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29629,6 +32390,7 @@ pub struct MigrationsUpdateImportRequest {
 * Can be one of `opt_in` (large files will be stored using Git LFS) or `opt_out` (large files will be removed during the import).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UseLfs {
     #[serde(rename = "opt_in")]
     OptIn,
@@ -29636,8 +32398,9 @@ pub enum UseLfs {
     OptOut,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UseLfs {
@@ -29646,12 +32409,32 @@ impl std::fmt::Display for UseLfs {
             UseLfs::OptIn => "opt_in",
             UseLfs::OptOut => "opt_out",
             UseLfs::Noop => "",
-            UseLfs::FallthroughString => "*",
+            UseLfs::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UseLfs {
+    fn from(s: String) -> UseLfs {
+        match s.as_str() {
+            "opt_in" => UseLfs::OptIn,
+            "opt_out" => UseLfs::OptOut,
+            "" => UseLfs::Noop,
+            _ => UseLfs::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UseLfs> for String {
+    fn from(e: UseLfs) -> String {
+        match e {
+            UseLfs::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UseLfs {
     fn default() -> UseLfs {
         UseLfs::Noop
@@ -29675,7 +32458,7 @@ pub struct MigrationsSetLfsPreferenceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateInvitationRequest {
     /**
-     * The permission associated with the invitation.
+     * The permissions that the associated user will have on the repository. Valid values are `read`, `write`, `maintain`, `triage`, and `admin`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permissions: Option<RepositoryInvitationPermissions>,
@@ -29753,14 +32536,13 @@ impl std::convert::From<IssuesCreateRequestLabelsOneOf> for String {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesCreateRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub assignee: String,
     /**
-     * The list of events for the GitHub app
+     * Login for the user that this issue should be assigned to. _NOTE: Only users with push access can set the assignee for new issues. The assignee is silently dropped otherwise. \*\*This field is deprecated.\*\*_
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub assignee: crate::utils::Patch<String>,
+    /**
+     * Logins for Users to assign to this issue. _NOTE: Only users with push access can set assignees for new issues. Assignees are silently dropped otherwise._
      */
     #[serde(
         default,
@@ -29768,6 +32550,9 @@ pub struct IssuesCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub assignees: Vec<String>,
+    /**
+     * The contents of the issue.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -29783,22 +32568,24 @@ pub struct IssuesCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub labels: Vec<IssuesCreateRequestLabelsOneOf>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub milestone: Option<TitleOneOf>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub milestone: crate::utils::Patch<TitleOneOf>,
+    /**
+     * The title of the issue.
+     */
     #[serde()]
     pub title: TitleOneOf,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesUpdateRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub assignee: String,
     /**
-     * The list of events for the GitHub app
+     * Login for the user that this issue should be assigned to. \*\*This field is deprecated.\*\*
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub assignee: crate::utils::Patch<String>,
+    /**
+     * Logins for Users to assign to this issue. Pass one or more user logins to _replace_ the set of assignees on this Issue. Send an empty array (`[]`) to clear all assignees from the Issue. _NOTE: Only users with push access can set assignees for new issues. Assignees are silently dropped otherwise._
      */
     #[serde(
         default,
@@ -29806,14 +32593,13 @@ pub struct IssuesUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub assignees: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub body: String,
     /**
-     * Labels to associate with this issue. _NOTE: Only users with push access can set labels for new issues. Labels are silently dropped otherwise._
+     * The contents of the issue.
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub body: crate::utils::Patch<String>,
+    /**
+     * Labels to associate with this issue. Pass one or more Labels to _replace_ the set of Labels on this Issue. Send an empty array (`[]`) to clear all Labels from the Issue. _NOTE: Only users with push access can set labels for issues. Labels are silently dropped otherwise._
      */
     #[serde(
         default,
@@ -29821,21 +32607,24 @@ pub struct IssuesUpdateRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub labels: Vec<IssuesCreateRequestLabelsOneOf>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub milestone: Option<TitleOneOf>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub milestone: crate::utils::Patch<TitleOneOf>,
     /**
-     * The state of the milestone.
+     * State of the issue. Either `open` or `closed`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub title: Option<TitleOneOf>,
+    /**
+     * The title of the issue.
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub title: crate::utils::Patch<TitleOneOf>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesAddAssigneesRequest {
     /**
-     * The list of events for the GitHub app
+     * Usernames of people to assign this issue to. _NOTE: Only users with push access can add assignees to an issue. Assignees are silently ignored otherwise._
      */
     #[serde(
         default,
@@ -29848,7 +32637,7 @@ pub struct IssuesAddAssigneesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesAddLabelsRequest {
     /**
-     * The list of events for the GitHub app
+     * The names of the labels to add to the issue. You can pass an empty array to remove all labels. \*\*Note:\*\* Alternatively, you can pass a single label as a `string` or an `array` of labels directly, but GitHub recommends passing an object with the `labels` key.
      */
     #[serde(
         default,
@@ -29997,6 +32786,7 @@ pub struct IssuesSetLabelsRequestAnyOf {
 *   \* `spam`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LockReason {
     #[serde(rename = "off-topic")]
     OffTopic,
@@ -30008,8 +32798,9 @@ pub enum LockReason {
     TooHeated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LockReason {
@@ -30020,12 +32811,34 @@ impl std::fmt::Display for LockReason {
             LockReason::Spam => "spam",
             LockReason::TooHeated => "too heated",
             LockReason::Noop => "",
-            LockReason::FallthroughString => "*",
+            LockReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LockReason {
+    fn from(s: String) -> LockReason {
+        match s.as_str() {
+            "off-topic" => LockReason::OffTopic,
+            "resolved" => LockReason::Resolved,
+            "spam" => LockReason::Spam,
+            "too heated" => LockReason::TooHeated,
+            "" => LockReason::Noop,
+            _ => LockReason::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LockReason> for String {
+    fn from(e: LockReason) -> String {
+        match e {
+            LockReason::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LockReason {
     fn default() -> LockReason {
         LockReason::Noop
@@ -30039,20 +32852,38 @@ impl LockReason {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesLockRequest {
+    /**
+     * The reason for locking the issue or pull request conversation. Lock will fail if you don't use one of these reasons:  
+     *  \\* `off-topic`  
+     *  \\* `too heated`  
+     *  \\* `resolved`  
+     *  \\* `spam`
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lock_reason: Option<LockReason>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateDeployKeyRequest {
+    /**
+     * The contents of the key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key: String,
+    /**
+     * If `true`, the key will only be able to read repository contents. Otherwise, the key will be able to read and write.  
+     *    
+     *  Deploy keys with write access can perform the same actions as an organization member with admin access, or a collaborator on a personal repository. For more information, see "[Repository permission levels for an organization](https://help.github.com/articles/repository-permission-levels-for-an-organization/)" and "[Permission levels for a user account repository](https://help.github.com/articles/permission-levels-for-a-user-account-repository/)."
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub read_only: Option<bool>,
+    /**
+     * A name for the key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30063,18 +32894,27 @@ pub struct ReposCreateDeployKeyRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesCreateLabelRequest {
+    /**
+     * The [hexadecimal color code](http://www.color-hex.com/) for the label, without the leading `#`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color: String,
+    /**
+     * A short description of the label.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The name of the label. Emoji can be added to label names, using either native emoji or colon-style markup. For example, typing `:strawberry:` will render the emoji ![:strawberry:](https://github.githubassets.com/images/icons/emoji/unicode/1f353.png ":strawberry:"). For a full list of available emoji and codes, see "[Emoji cheat sheet](https://github.com/ikatyang/emoji-cheat-sheet)."
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30085,18 +32925,27 @@ pub struct IssuesCreateLabelRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesUpdateLabelRequest {
+    /**
+     * The [hexadecimal color code](http://www.color-hex.com/) for the label, without the leading `#`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color: String,
+    /**
+     * A short description of the label.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The new name of the label. Emoji can be added to label names, using either native emoji or colon-style markup. For example, typing `:strawberry:` will render the emoji ![:strawberry:](https://github.githubassets.com/images/icons/emoji/unicode/1f353.png ":strawberry:"). For a full list of available emoji and codes, see "[Emoji cheat sheet](https://github.com/ikatyang/emoji-cheat-sheet)."
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30107,18 +32956,27 @@ pub struct IssuesUpdateLabelRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposMergeRequest {
+    /**
+     * The name of the base branch that the head will be merged into.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub base: String,
+    /**
+     * Commit message to use for the merge commit. If omitted, a default message will be used.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub commit_message: String,
+    /**
+     * The head to merge. This can be a branch name or a commit SHA1.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30131,13 +32989,15 @@ pub struct ReposMergeRequest {
 * What to sort results by. Either `due_on` or `completeness`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IssuesListMilestonesSort {
     #[serde(rename = "completeness")]
     Completeness,
     #[serde(rename = "due_on")]
     DueOn,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuesListMilestonesSort {
@@ -30145,12 +33005,31 @@ impl std::fmt::Display for IssuesListMilestonesSort {
         match self {
             IssuesListMilestonesSort::Completeness => "completeness",
             IssuesListMilestonesSort::DueOn => "due_on",
-            IssuesListMilestonesSort::FallthroughString => "*",
+            IssuesListMilestonesSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IssuesListMilestonesSort {
+    fn from(s: String) -> IssuesListMilestonesSort {
+        match s.as_str() {
+            "completeness" => IssuesListMilestonesSort::Completeness,
+            "due_on" => IssuesListMilestonesSort::DueOn,
+            _ => IssuesListMilestonesSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IssuesListMilestonesSort> for String {
+    fn from(e: IssuesListMilestonesSort) -> String {
+        match e {
+            IssuesListMilestonesSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IssuesListMilestonesSort {
     fn default() -> IssuesListMilestonesSort {
         IssuesListMilestonesSort::DueOn
@@ -30159,12 +33038,18 @@ impl Default for IssuesListMilestonesSort {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesCreateMilestoneRequest {
+    /**
+     * A description of the milestone.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The milestone due date. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -30172,10 +33057,13 @@ pub struct IssuesCreateMilestoneRequest {
     )]
     pub due_on: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The state of the milestone.
+     * The state of the milestone. Either `open` or `closed`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
+    /**
+     * The title of the milestone.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30186,6 +33074,9 @@ pub struct IssuesCreateMilestoneRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivityMarkRepoNotificationsAsReadRequest {
+    /**
+     * Describes the last point that notifications were checked. Anything updated since this time will not be marked as read. If you omit this parameter, all notifications are marked as read. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`. Default: The current timestamp.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -30214,13 +33105,15 @@ pub struct PullsUpdateBranchResponse {
 * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`. Default: `/`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Path {
     #[serde(rename = "/")]
     Root,
     #[serde(rename = "/docs")]
     Docs,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Path {
@@ -30228,12 +33121,31 @@ impl std::fmt::Display for Path {
         match self {
             Path::Root => "/",
             Path::Docs => "/docs",
-            Path::FallthroughString => "*",
+            Path::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Path {
+    fn from(s: String) -> Path {
+        match s.as_str() {
+            "/" => Path::Root,
+            "/docs" => Path::Docs,
+            _ => Path::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Path> for String {
+    fn from(e: Path) -> String {
+        match e {
+            Path::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Path {
     fn default() -> Path {
         Path::Root
@@ -30243,6 +33155,9 @@ impl Default for Path {
 /// The source branch and directory used to publish your Pages site.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreatePagesSiteRequestSource {
+    /**
+     * The repository branch used to publish your site's source files.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30250,7 +33165,7 @@ pub struct ReposCreatePagesSiteRequestSource {
     )]
     pub branch: String,
     /**
-     * The source branch and directory used to publish your Pages site.
+     * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`. Default: `/`
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub path: Option<Path>,
@@ -30270,6 +33185,7 @@ pub struct ReposCreatePagesSiteRequest {
 * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SourceData {
     #[serde(rename = "gh-pages")]
     GhPages,
@@ -30279,8 +33195,9 @@ pub enum SourceData {
     MasterDocs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceData {
@@ -30290,12 +33207,33 @@ impl std::fmt::Display for SourceData {
             SourceData::Master => "master",
             SourceData::MasterDocs => "master /docs",
             SourceData::Noop => "",
-            SourceData::FallthroughString => "*",
+            SourceData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SourceData {
+    fn from(s: String) -> SourceData {
+        match s.as_str() {
+            "gh-pages" => SourceData::GhPages,
+            "master" => SourceData::Master,
+            "master /docs" => SourceData::MasterDocs,
+            "" => SourceData::Noop,
+            _ => SourceData::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SourceData> for String {
+    fn from(e: SourceData) -> String {
+        match e {
+            SourceData::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SourceData {
     fn default() -> SourceData {
         SourceData::Noop
@@ -30310,6 +33248,9 @@ impl SourceData {
 /// Update the source for the repository. Must include the branch name and path.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SourceDataType {
+    /**
+     * The repository branch used to publish your site's source files.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30317,7 +33258,7 @@ pub struct SourceDataType {
     )]
     pub branch: String,
     /**
-     * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`. Default: `/`
+     * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`.
      */
     #[serde(default)]
     pub path: Path,
@@ -30344,14 +33285,19 @@ pub struct SourceAnyOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateInformationAboutPagesSiteRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub cname: String,
+    /**
+     * Specify a custom domain for the repository. Sending a `null` value will remove the custom domain. For more about custom domains, see "[Using a custom domain with GitHub Pages](https://help.github.com/articles/using-a-custom-domain-with-github-pages/)."
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub cname: crate::utils::Patch<String>,
+    /**
+     * Specify whether HTTPS should be enforced for the repository.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub https_enforced: Option<bool>,
+    /**
+     * Configures access controls for the GitHub Pages site. If public is set to `true`, the site is accessible to anyone on the internet. If set to `false`, the site will only be accessible to users who have at least `read` access to the repository that published the site. This includes anyone in your Enterprise if the repository is set to `internal` visibility. This feature is only available to repositories in an organization on an Enterprise plan.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub public: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -30362,6 +33308,7 @@ pub struct ReposUpdateInformationAboutPagesSiteRequest {
 * What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PullsListSort {
     #[serde(rename = "created")]
     Created,
@@ -30371,8 +33318,9 @@ pub enum PullsListSort {
     Popularity,
     #[serde(rename = "updated")]
     Updated,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsListSort {
@@ -30382,12 +33330,33 @@ impl std::fmt::Display for PullsListSort {
             PullsListSort::LongRunning => "long-running",
             PullsListSort::Popularity => "popularity",
             PullsListSort::Updated => "updated",
-            PullsListSort::FallthroughString => "*",
+            PullsListSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PullsListSort {
+    fn from(s: String) -> PullsListSort {
+        match s.as_str() {
+            "created" => PullsListSort::Created,
+            "long-running" => PullsListSort::LongRunning,
+            "popularity" => PullsListSort::Popularity,
+            "updated" => PullsListSort::Updated,
+            _ => PullsListSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PullsListSort> for String {
+    fn from(e: PullsListSort) -> String {
+        match e {
+            PullsListSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PullsListSort {
     fn default() -> PullsListSort {
         PullsListSort::Created
@@ -30396,20 +33365,32 @@ impl Default for PullsListSort {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsCreateRequest {
+    /**
+     * The name of the branch you want the changes pulled into. This should be an existing branch on the current repository. You cannot submit a pull request to one repository that requests a merge to a base of another repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub base: String,
+    /**
+     * The contents of the pull request.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * Indicates whether the pull request is a draft. See "[Draft Pull Requests](https://help.github.com/en/articles/about-pull-requests#draft-pull-requests)" in the GitHub Help documentation to learn more.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub draft: Option<bool>,
+    /**
+     * The name of the branch where your changes are implemented. For cross-repository pull requests in the same network, namespace `head` with a user like this: `username:branch`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30422,8 +33403,14 @@ pub struct PullsCreateRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub issue: i64,
+    /**
+     * Indicates whether [maintainers can modify](https://help.github.com/articles/allowing-changes-to-a-pull-request-branch-created-from-a-fork/) the pull request.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maintainer_can_modify: Option<bool>,
+    /**
+     * The title of the new pull request.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30433,6 +33420,7 @@ pub struct PullsCreateRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PullsListReviewCommentsRepoSort {
     #[serde(rename = "created")]
     Created,
@@ -30442,8 +33430,9 @@ pub enum PullsListReviewCommentsRepoSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsListReviewCommentsRepoSort {
@@ -30453,12 +33442,33 @@ impl std::fmt::Display for PullsListReviewCommentsRepoSort {
             PullsListReviewCommentsRepoSort::CreatedAt => "created_at",
             PullsListReviewCommentsRepoSort::Updated => "updated",
             PullsListReviewCommentsRepoSort::Noop => "",
-            PullsListReviewCommentsRepoSort::FallthroughString => "*",
+            PullsListReviewCommentsRepoSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PullsListReviewCommentsRepoSort {
+    fn from(s: String) -> PullsListReviewCommentsRepoSort {
+        match s.as_str() {
+            "created" => PullsListReviewCommentsRepoSort::Created,
+            "created_at" => PullsListReviewCommentsRepoSort::CreatedAt,
+            "updated" => PullsListReviewCommentsRepoSort::Updated,
+            "" => PullsListReviewCommentsRepoSort::Noop,
+            _ => PullsListReviewCommentsRepoSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PullsListReviewCommentsRepoSort> for String {
+    fn from(e: PullsListReviewCommentsRepoSort) -> String {
+        match e {
+            PullsListReviewCommentsRepoSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PullsListReviewCommentsRepoSort {
     fn default() -> PullsListReviewCommentsRepoSort {
         PullsListReviewCommentsRepoSort::Noop
@@ -30472,25 +33482,37 @@ impl PullsListReviewCommentsRepoSort {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsUpdateRequest {
+    /**
+     * The name of the branch you want your changes pulled into. This should be an existing branch on the current repository. You cannot update the base branch on a pull request to point to another repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub base: String,
+    /**
+     * The contents of the pull request.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * Indicates whether [maintainers can modify](https://help.github.com/articles/allowing-changes-to-a-pull-request-branch-created-from-a-fork/) the pull request.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maintainer_can_modify: Option<bool>,
     /**
-     * The state of the milestone.
+     * State of this Pull Request. Either `open` or `closed`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
+    /**
+     * The title of the pull request.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30503,6 +33525,7 @@ pub struct PullsUpdateRequest {
 * **Required when using multi-line comments**. To create multi-line comments, you must use the `comfort-fade` preview header. The `start_side` is the starting side of the diff that the comment applies to. Can be `LEFT` or `RIGHT`. To learn more about multi-line comments, see "[Commenting on a pull request](https://help.github.com/en/articles/commenting-on-a-pull-request#adding-line-comments-to-a-pull-request)" in the GitHub Help documentation. See `side` in this table for additional context.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PullsCreateReviewCommentRequestStartSide {
     #[serde(rename = "LEFT")]
     Left,
@@ -30512,8 +33535,9 @@ pub enum PullsCreateReviewCommentRequestStartSide {
     Side,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
@@ -30523,12 +33547,33 @@ impl std::fmt::Display for PullsCreateReviewCommentRequestStartSide {
             PullsCreateReviewCommentRequestStartSide::Right => "RIGHT",
             PullsCreateReviewCommentRequestStartSide::Side => "side",
             PullsCreateReviewCommentRequestStartSide::Noop => "",
-            PullsCreateReviewCommentRequestStartSide::FallthroughString => "*",
+            PullsCreateReviewCommentRequestStartSide::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PullsCreateReviewCommentRequestStartSide {
+    fn from(s: String) -> PullsCreateReviewCommentRequestStartSide {
+        match s.as_str() {
+            "LEFT" => PullsCreateReviewCommentRequestStartSide::Left,
+            "RIGHT" => PullsCreateReviewCommentRequestStartSide::Right,
+            "side" => PullsCreateReviewCommentRequestStartSide::Side,
+            "" => PullsCreateReviewCommentRequestStartSide::Noop,
+            _ => PullsCreateReviewCommentRequestStartSide::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PullsCreateReviewCommentRequestStartSide> for String {
+    fn from(e: PullsCreateReviewCommentRequestStartSide) -> String {
+        match e {
+            PullsCreateReviewCommentRequestStartSide::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PullsCreateReviewCommentRequestStartSide {
     fn default() -> PullsCreateReviewCommentRequestStartSide {
         PullsCreateReviewCommentRequestStartSide::Noop
@@ -30542,12 +33587,18 @@ impl PullsCreateReviewCommentRequestStartSide {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsCreateReviewCommentRequest {
+    /**
+     * The text of the review comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * The SHA of the commit needing a comment. Not using the latest commit SHA may render your comment outdated if a subsequent commit modifies the line you specify as the `position`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30560,18 +33611,27 @@ pub struct PullsCreateReviewCommentRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub in_reply_to: i64,
+    /**
+     * \*\*Required with `comfort-fade` preview\*\*. The line of the blob in the pull request diff that the comment applies to. For a multi-line comment, the last line of the range that your comment applies to.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub line: i64,
+    /**
+     * The relative path to the file that necessitates a comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /**
+     * \*\*Required without `comfort-fade` preview\*\*. The position in the diff where you want to add a review comment. Note this value is not the same as the line number in the file. For help finding the position value, read the note above.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -30579,10 +33639,13 @@ pub struct PullsCreateReviewCommentRequest {
     )]
     pub position: i64,
     /**
-     * The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
+     * \*\*Required with `comfort-fade` preview\*\*. In a split diff view, the side of the diff that the pull request's changes appear on. Can be `LEFT` or `RIGHT`. Use `LEFT` for deletions that appear in red. Use `RIGHT` for additions that appear in green or unchanged lines that appear in white and are shown for context. For a multi-line comment, side represents whether the last line of the comment range is a deletion or addition. For more information, see "[Diff view options](https://help.github.com/en/articles/about-comparing-branches-in-pull-requests#diff-view-options)" in the GitHub Help documentation.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub side: Option<Side>,
+    /**
+     * \*\*Required when using multi-line comments\*\*. To create multi-line comments, you must use the `comfort-fade` preview header. The `start_line` is the first line in the pull request diff that your multi-line comment applies to. To learn more about multi-line comments, see "[Commenting on a pull request](https://help.github.com/en/articles/commenting-on-a-pull-request#adding-line-comments-to-a-pull-request)" in the GitHub Help documentation.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -30598,20 +33661,32 @@ pub struct PullsCreateReviewCommentRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsMergeRequest {
+    /**
+     * Extra detail to append to automatic commit message.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub commit_message: String,
+    /**
+     * Title for the automatic commit message.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub commit_title: String,
+    /**
+     * Merge method to use. Possible values are `merge`, `squash` or `rebase`. Default is `merge`.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge_method: Option<MergeMethod>,
+    /**
+     * SHA that pull request head must match to allow merge.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30623,7 +33698,7 @@ pub struct PullsMergeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsRequestReviewers {
     /**
-     * The list of events for the GitHub app
+     * An array of user `login`s that will be requested.
      */
     #[serde(
         default,
@@ -30632,7 +33707,7 @@ pub struct PullsRequestReviewers {
     )]
     pub reviewers: Vec<String>,
     /**
-     * The list of events for the GitHub app
+     * An array of team `slug`s that will be requested.
      */
     #[serde(
         default,
@@ -30645,7 +33720,7 @@ pub struct PullsRequestReviewers {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsRemoveRequestedReviewersRequest {
     /**
-     * The list of events for the GitHub app
+     * An array of user `login`s that will be removed.
      */
     #[serde(
         default,
@@ -30654,7 +33729,7 @@ pub struct PullsRemoveRequestedReviewersRequest {
     )]
     pub reviewers: Vec<String>,
     /**
-     * The list of events for the GitHub app
+     * An array of team `slug`s that will be removed.
      */
     #[serde(
         default,
@@ -30668,6 +33743,7 @@ pub struct PullsRemoveRequestedReviewersRequest {
 * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PullsCreateReviewRequestEvent {
     #[serde(rename = "APPROVE")]
     Approve,
@@ -30677,8 +33753,9 @@ pub enum PullsCreateReviewRequestEvent {
     RequestChanges,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PullsCreateReviewRequestEvent {
@@ -30688,12 +33765,33 @@ impl std::fmt::Display for PullsCreateReviewRequestEvent {
             PullsCreateReviewRequestEvent::Comment => "COMMENT",
             PullsCreateReviewRequestEvent::RequestChanges => "REQUEST_CHANGES",
             PullsCreateReviewRequestEvent::Noop => "",
-            PullsCreateReviewRequestEvent::FallthroughString => "*",
+            PullsCreateReviewRequestEvent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PullsCreateReviewRequestEvent {
+    fn from(s: String) -> PullsCreateReviewRequestEvent {
+        match s.as_str() {
+            "APPROVE" => PullsCreateReviewRequestEvent::Approve,
+            "COMMENT" => PullsCreateReviewRequestEvent::Comment,
+            "REQUEST_CHANGES" => PullsCreateReviewRequestEvent::RequestChanges,
+            "" => PullsCreateReviewRequestEvent::Noop,
+            _ => PullsCreateReviewRequestEvent::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PullsCreateReviewRequestEvent> for String {
+    fn from(e: PullsCreateReviewRequestEvent) -> String {
+        match e {
+            PullsCreateReviewRequestEvent::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PullsCreateReviewRequestEvent {
     fn default() -> PullsCreateReviewRequestEvent {
         PullsCreateReviewRequestEvent::Noop
@@ -30707,6 +33805,9 @@ impl PullsCreateReviewRequestEvent {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Comments {
+    /**
+     * Text of the review comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30719,12 +33820,18 @@ pub struct Comments {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub line: i64,
+    /**
+     * The relative path to the file that necessitates a review comment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub path: String,
+    /**
+     * The position in the diff where you want to add a review comment. Note this value is not the same as the line number in the file. For help finding the position value, read the note below.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -30753,6 +33860,9 @@ pub struct Comments {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsCreateReviewRequest {
+    /**
+     * \*\*Required\*\* when using `REQUEST_CHANGES` or `COMMENT` for the `event` parameter. The body text of the pull request review.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30768,6 +33878,9 @@ pub struct PullsCreateReviewRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub comments: Vec<Comments>,
+    /**
+     * The SHA of the commit that needs a review. Not using the latest commit SHA may render your review comment outdated if a subsequent commit modifies the line you specify as the `position`. Defaults to the most recent commit in the pull request when you do not specify a value.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30789,6 +33902,9 @@ pub struct PullsDismissReviewRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub event: String,
+    /**
+     * The message for the pull request review dismissal
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30799,6 +33915,9 @@ pub struct PullsDismissReviewRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsSubmitReviewRequest {
+    /**
+     * The body text of the pull request review
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30806,7 +33925,7 @@ pub struct PullsSubmitReviewRequest {
     )]
     pub body: String,
     /**
-     * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
+     * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. When you leave this blank, the API returns _HTTP 422 (Unrecognizable entity)_ and sets the review action state to `PENDING`, which means you will need to re-submit the pull request review using a review action.
      */
     #[serde(
         default,
@@ -30817,6 +33936,9 @@ pub struct PullsSubmitReviewRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsUpdateBranchRequest {
+    /**
+     * The expected SHA of the pull request's HEAD ref. This is the most recent commit on the pull request's branch. If the expected SHA does not match the pull request's HEAD, you will receive a `422 Unprocessable Entity` status. You can use the "[List commits](https://docs.github.com/rest/reference/repos#list-commits)" endpoint to find the most recent commit SHA. Default: SHA of the pull request's current HEAD ref.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30827,34 +33949,55 @@ pub struct PullsUpdateBranchRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateReleaseRequest {
+    /**
+     * Text describing the contents of the tag.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub body: String,
+    /**
+     * If specified, a discussion of the specified category is created and linked to the release. The value must be a category that already exists in the repository. For more information, see "[Managing categories for discussions in your repository](https://docs.github.com/discussions/managing-discussions-for-your-community/managing-categories-for-discussions-in-your-repository)."
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub discussion_category_name: String,
+    /**
+     * `true` to create a draft (unpublished) release, `false` to create a published one.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub draft: Option<bool>,
+    /**
+     * The name of the release.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * `true` to identify the release as a prerelease. `false` to identify the release as a full release.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub prerelease: Option<bool>,
+    /**
+     * The name of the tag.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tag_name: String,
+    /**
+     * Specifies the commitish value that determines where the Git tag is created from. Can be any branch or commit SHA. Unused if the Git tag already exists. Default: the repository's default branch (usually `master`).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30865,12 +34008,18 @@ pub struct ReposCreateReleaseRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateReleaseAssetRequest {
+    /**
+     * An alternate short description of the asset. Used in place of the filename.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub label: String,
+    /**
+     * The file name of the asset.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -30889,6 +34038,7 @@ pub struct ReposUpdateReleaseAssetRequest {
 * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReactionsCreateReleaseRequestContent {
     #[serde(rename = "+1")]
     PlusOne,
@@ -30904,8 +34054,9 @@ pub enum ReactionsCreateReleaseRequestContent {
     Rocket,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
@@ -30918,12 +34069,36 @@ impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
             ReactionsCreateReleaseRequestContent::Laugh => "laugh",
             ReactionsCreateReleaseRequestContent::Rocket => "rocket",
             ReactionsCreateReleaseRequestContent::Noop => "",
-            ReactionsCreateReleaseRequestContent::FallthroughString => "*",
+            ReactionsCreateReleaseRequestContent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReactionsCreateReleaseRequestContent {
+    fn from(s: String) -> ReactionsCreateReleaseRequestContent {
+        match s.as_str() {
+            "+1" => ReactionsCreateReleaseRequestContent::PlusOne,
+            "eyes" => ReactionsCreateReleaseRequestContent::Eyes,
+            "heart" => ReactionsCreateReleaseRequestContent::Heart,
+            "hooray" => ReactionsCreateReleaseRequestContent::Hooray,
+            "laugh" => ReactionsCreateReleaseRequestContent::Laugh,
+            "rocket" => ReactionsCreateReleaseRequestContent::Rocket,
+            "" => ReactionsCreateReleaseRequestContent::Noop,
+            _ => ReactionsCreateReleaseRequestContent::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReactionsCreateReleaseRequestContent> for String {
+    fn from(e: ReactionsCreateReleaseRequestContent) -> String {
+        match e {
+            ReactionsCreateReleaseRequestContent::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReactionsCreateReleaseRequestContent {
     fn default() -> ReactionsCreateReleaseRequestContent {
         ReactionsCreateReleaseRequestContent::Noop
@@ -30978,6 +34153,7 @@ pub struct ActivityListStargazersRepoResponseAnyOf {
 * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposCreateCommitStatusRequestState {
     #[serde(rename = "error")]
     Error,
@@ -30989,8 +34165,9 @@ pub enum ReposCreateCommitStatusRequestState {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposCreateCommitStatusRequestState {
@@ -31001,12 +34178,34 @@ impl std::fmt::Display for ReposCreateCommitStatusRequestState {
             ReposCreateCommitStatusRequestState::Pending => "pending",
             ReposCreateCommitStatusRequestState::Success => "success",
             ReposCreateCommitStatusRequestState::Noop => "",
-            ReposCreateCommitStatusRequestState::FallthroughString => "*",
+            ReposCreateCommitStatusRequestState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposCreateCommitStatusRequestState {
+    fn from(s: String) -> ReposCreateCommitStatusRequestState {
+        match s.as_str() {
+            "error" => ReposCreateCommitStatusRequestState::Error,
+            "failure" => ReposCreateCommitStatusRequestState::Failure,
+            "pending" => ReposCreateCommitStatusRequestState::Pending,
+            "success" => ReposCreateCommitStatusRequestState::Success,
+            "" => ReposCreateCommitStatusRequestState::Noop,
+            _ => ReposCreateCommitStatusRequestState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposCreateCommitStatusRequestState> for String {
+    fn from(e: ReposCreateCommitStatusRequestState) -> String {
+        match e {
+            ReposCreateCommitStatusRequestState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposCreateCommitStatusRequestState {
     fn default() -> ReposCreateCommitStatusRequestState {
         ReposCreateCommitStatusRequestState::Noop
@@ -31020,12 +34219,18 @@ impl ReposCreateCommitStatusRequestState {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateCommitStatusRequest {
+    /**
+     * A string label to differentiate this status from the status of other systems. This field is case-insensitive.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub context: String,
+    /**
+     * A short description of the status.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31040,6 +34245,11 @@ pub struct ReposCreateCommitStatusRequest {
         skip_serializing_if = "ReposCreateCommitStatusRequestState::is_noop"
     )]
     pub state: ReposCreateCommitStatusRequestState,
+    /**
+     * The target URL to associate with this status. This URL will be linked from the GitHub UI to allow users to easily see the source of the status.  
+     *  For example, if your continuous integration system is posting build status, you would want to provide the deep link for the build output for this specific SHA:  
+     *  `http://ci.example.com/user/repo/build/sha`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31050,14 +34260,23 @@ pub struct ReposCreateCommitStatusRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActivitySetRepoSubscriptionRequest {
+    /**
+     * Determines if all notifications should be blocked from this repository.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ignored: Option<bool>,
+    /**
+     * Determines if notifications should be received from this repository.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subscribed: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposTransferRequest {
+    /**
+     * The username or organization name the repository will be transferred to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31065,7 +34284,7 @@ pub struct ReposTransferRequest {
     )]
     pub new_owner: String,
     /**
-     * Code Frequency Stat
+     * ID of the team or teams to add to the repository. Teams can only be added to organization-owned repositories.
      */
     #[serde(
         default,
@@ -31077,32 +34296,50 @@ pub struct ReposTransferRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateUsingTemplateRequest {
+    /**
+     * A short description of the new repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Set to `true` to include the directory structure and files from all branches in the template repository, and not just the default branch. Default: `false`.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_all_branches: Option<bool>,
+    /**
+     * The name of the new repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The organization or person who will own the new repository. To create a new repository in an organization, the authenticated user must be a member of the specified organization.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub owner: String,
+    /**
+     * Either `true` to create a new private repository or `false` to create a new public one.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnterpriseAdminProvisionInviteGroupRequest {
+    /**
+     * The name of the SCIM group. This must match the GitHub organization that the group maps to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31117,7 +34354,7 @@ pub struct EnterpriseAdminProvisionInviteGroupRequest {
     )]
     pub members: Vec<ScimUserListEnterpriseResourcesGroups>,
     /**
-     * The list of events for the GitHub app
+     * The SCIM schema URIs.
      */
     #[serde(
         default,
@@ -31128,6 +34365,7 @@ pub struct EnterpriseAdminProvisionInviteGroupRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     #[serde(rename = "Add")]
     Add,
@@ -31137,8 +34375,9 @@ pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     Replace,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
@@ -31148,12 +34387,33 @@ impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsO
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove => "Remove",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace => "Replace",
             EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop => "",
-            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::FallthroughString => "*",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn from(s: String) -> EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+        match s.as_str() {
+            "Add" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add,
+            "Remove" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove,
+            "Replace" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace,
+            "" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop,
+            _ => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EnterpriseAdminUpdateAttributeGroupRequestOperationsOp> for String {
+    fn from(e: EnterpriseAdminUpdateAttributeGroupRequestOperationsOp) -> String {
+        match e {
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     fn default() -> EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
         EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop
@@ -31198,7 +34458,7 @@ pub struct EnterpriseAdminUpdateAttributeGroupRequest {
     )]
     pub operations: Vec<EnterpriseAdminUpdateAttributeGroupRequestOperations>,
     /**
-     * The list of events for the GitHub app
+     * The SCIM schema URIs.
      */
     #[serde(
         default,
@@ -31210,11 +34470,17 @@ pub struct EnterpriseAdminUpdateAttributeGroupRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnterpriseAdminProvisionInviteUserRequestEmails {
+    /**
+     * Whether this email address is the primary address.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub primary: bool,
+    /**
+     * The type of email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31222,6 +34488,9 @@ pub struct EnterpriseAdminProvisionInviteUserRequestEmails {
         rename = "type"
     )]
     pub type_: String,
+    /**
+     * The email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31241,6 +34510,9 @@ pub struct EnterpriseAdminProvisionInviteUserRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub emails: Vec<EnterpriseAdminProvisionInviteUserRequestEmails>,
+    /**
+     * List of SCIM group IDs the user is a member of.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -31250,7 +34522,7 @@ pub struct EnterpriseAdminProvisionInviteUserRequest {
     #[serde()]
     pub name: Name,
     /**
-     * The list of events for the GitHub app
+     * The SCIM schema URIs.
      */
     #[serde(
         default,
@@ -31258,6 +34530,9 @@ pub struct EnterpriseAdminProvisionInviteUserRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /**
+     * The username for the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31280,7 +34555,7 @@ pub struct EnterpriseAdminUpdateAttributeUserRequest {
     )]
     pub operations: Vec<Data>,
     /**
-     * The list of events for the GitHub app
+     * The SCIM schema URIs.
      */
     #[serde(
         default,
@@ -31294,6 +34569,9 @@ pub struct EnterpriseAdminUpdateAttributeUserRequest {
 pub struct ScimProvisionInviteUserRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
+    /**
+     * The name of the user, suitable for display to end-users
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31301,6 +34579,9 @@ pub struct ScimProvisionInviteUserRequest {
         rename = "displayName"
     )]
     pub display_name: String,
+    /**
+     * user emails
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -31314,9 +34595,6 @@ pub struct ScimProvisionInviteUserRequest {
         rename = "externalId"
     )]
     pub external_id: String,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -31325,15 +34603,15 @@ pub struct ScimProvisionInviteUserRequest {
     pub groups: Vec<String>,
     #[serde()]
     pub name: ScimUserName,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub schemas: Vec<String>,
+    /**
+     * Configured by the admin. Could be an email, login, or username
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31477,9 +34755,6 @@ pub struct ScimUpdateAttributeUserRequest {
         rename = "Operations"
     )]
     pub operations: Vec<ScimUpdateAttributeUserRequestOperations>,
-    /**
-     * The list of events for the GitHub app
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -31492,13 +34767,15 @@ pub struct ScimUpdateAttributeUserRequest {
 * Sorts the results of your query. Can only be `indexed`, which indicates how recently a file has been indexed by the GitHub search infrastructure. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SearchCodeSort {
     #[serde(rename = "indexed")]
     Indexed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchCodeSort {
@@ -31506,12 +34783,31 @@ impl std::fmt::Display for SearchCodeSort {
         match self {
             SearchCodeSort::Indexed => "indexed",
             SearchCodeSort::Noop => "",
-            SearchCodeSort::FallthroughString => "*",
+            SearchCodeSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SearchCodeSort {
+    fn from(s: String) -> SearchCodeSort {
+        match s.as_str() {
+            "indexed" => SearchCodeSort::Indexed,
+            "" => SearchCodeSort::Noop,
+            _ => SearchCodeSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SearchCodeSort> for String {
+    fn from(e: SearchCodeSort) -> String {
+        match e {
+            SearchCodeSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SearchCodeSort {
     fn default() -> SearchCodeSort {
         SearchCodeSort::Noop
@@ -31548,6 +34844,7 @@ pub struct SearchCodeResponse {
 * Sorts the results of your query by `author-date` or `committer-date`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SearchCommitsSort {
     #[serde(rename = "author-date")]
     AuthorDate,
@@ -31555,8 +34852,9 @@ pub enum SearchCommitsSort {
     CommitterDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchCommitsSort {
@@ -31565,12 +34863,32 @@ impl std::fmt::Display for SearchCommitsSort {
             SearchCommitsSort::AuthorDate => "author-date",
             SearchCommitsSort::CommitterDate => "committer-date",
             SearchCommitsSort::Noop => "",
-            SearchCommitsSort::FallthroughString => "*",
+            SearchCommitsSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SearchCommitsSort {
+    fn from(s: String) -> SearchCommitsSort {
+        match s.as_str() {
+            "author-date" => SearchCommitsSort::AuthorDate,
+            "committer-date" => SearchCommitsSort::CommitterDate,
+            "" => SearchCommitsSort::Noop,
+            _ => SearchCommitsSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SearchCommitsSort> for String {
+    fn from(e: SearchCommitsSort) -> String {
+        match e {
+            SearchCommitsSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SearchCommitsSort {
     fn default() -> SearchCommitsSort {
         SearchCommitsSort::Noop
@@ -31607,6 +34925,7 @@ pub struct SearchCommitsResponse {
 * Sorts the results of your query by the number of `comments`, `reactions`, `reactions-+1`, `reactions--1`, `reactions-smile`, `reactions-thinking_face`, `reactions-heart`, `reactions-tada`, or `interactions`. You can also sort results by how recently the items were `created` or `updated`, Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SearchIssuesPullRequestsSort {
     #[serde(rename = "comments")]
     Comments,
@@ -31632,8 +34951,9 @@ pub enum SearchIssuesPullRequestsSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchIssuesPullRequestsSort {
@@ -31651,12 +34971,41 @@ impl std::fmt::Display for SearchIssuesPullRequestsSort {
             SearchIssuesPullRequestsSort::ReactionsThinkingFace => "reactions-thinking_face",
             SearchIssuesPullRequestsSort::Updated => "updated",
             SearchIssuesPullRequestsSort::Noop => "",
-            SearchIssuesPullRequestsSort::FallthroughString => "*",
+            SearchIssuesPullRequestsSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SearchIssuesPullRequestsSort {
+    fn from(s: String) -> SearchIssuesPullRequestsSort {
+        match s.as_str() {
+            "comments" => SearchIssuesPullRequestsSort::Comments,
+            "created" => SearchIssuesPullRequestsSort::Created,
+            "interactions" => SearchIssuesPullRequestsSort::Interactions,
+            "reactions" => SearchIssuesPullRequestsSort::Reactions,
+            "reactions-+1" => SearchIssuesPullRequestsSort::ReactionsPlusOne,
+            "reactions--1" => SearchIssuesPullRequestsSort::ReactionsMinusOne,
+            "reactions-heart" => SearchIssuesPullRequestsSort::ReactionsHeart,
+            "reactions-smile" => SearchIssuesPullRequestsSort::ReactionsSmile,
+            "reactions-tada" => SearchIssuesPullRequestsSort::ReactionsTada,
+            "reactions-thinking_face" => SearchIssuesPullRequestsSort::ReactionsThinkingFace,
+            "updated" => SearchIssuesPullRequestsSort::Updated,
+            "" => SearchIssuesPullRequestsSort::Noop,
+            _ => SearchIssuesPullRequestsSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SearchIssuesPullRequestsSort> for String {
+    fn from(e: SearchIssuesPullRequestsSort) -> String {
+        match e {
+            SearchIssuesPullRequestsSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SearchIssuesPullRequestsSort {
     fn default() -> SearchIssuesPullRequestsSort {
         SearchIssuesPullRequestsSort::Noop
@@ -31714,6 +35063,7 @@ pub struct SearchLabelsResponse {
 * Sorts the results of your query by number of `stars`, `forks`, or `help-wanted-issues` or how recently the items were `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SearchReposSort {
     #[serde(rename = "forks")]
     Forks,
@@ -31725,8 +35075,9 @@ pub enum SearchReposSort {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchReposSort {
@@ -31737,12 +35088,34 @@ impl std::fmt::Display for SearchReposSort {
             SearchReposSort::Stars => "stars",
             SearchReposSort::Updated => "updated",
             SearchReposSort::Noop => "",
-            SearchReposSort::FallthroughString => "*",
+            SearchReposSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SearchReposSort {
+    fn from(s: String) -> SearchReposSort {
+        match s.as_str() {
+            "forks" => SearchReposSort::Forks,
+            "help-wanted-issues" => SearchReposSort::HelpWantedIssues,
+            "stars" => SearchReposSort::Stars,
+            "updated" => SearchReposSort::Updated,
+            "" => SearchReposSort::Noop,
+            _ => SearchReposSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SearchReposSort> for String {
+    fn from(e: SearchReposSort) -> String {
+        match e {
+            SearchReposSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SearchReposSort {
     fn default() -> SearchReposSort {
         SearchReposSort::Noop
@@ -31800,6 +35173,7 @@ pub struct SearchTopicsResponse {
 * Sorts the results of your query by number of `followers` or `repositories`, or when the person `joined` GitHub. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SearchUsersSort {
     #[serde(rename = "followers")]
     Followers,
@@ -31809,8 +35183,9 @@ pub enum SearchUsersSort {
     Repositories,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SearchUsersSort {
@@ -31820,12 +35195,33 @@ impl std::fmt::Display for SearchUsersSort {
             SearchUsersSort::Joined => "joined",
             SearchUsersSort::Repositories => "repositories",
             SearchUsersSort::Noop => "",
-            SearchUsersSort::FallthroughString => "*",
+            SearchUsersSort::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SearchUsersSort {
+    fn from(s: String) -> SearchUsersSort {
+        match s.as_str() {
+            "followers" => SearchUsersSort::Followers,
+            "joined" => SearchUsersSort::Joined,
+            "repositories" => SearchUsersSort::Repositories,
+            "" => SearchUsersSort::Noop,
+            _ => SearchUsersSort::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SearchUsersSort> for String {
+    fn from(e: SearchUsersSort) -> String {
+        match e {
+            SearchUsersSort::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SearchUsersSort {
     fn default() -> SearchUsersSort {
         SearchUsersSort::Noop
@@ -31861,7 +35257,11 @@ pub struct SearchUsersResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsAddUpdateProjectPermissionsLegacyRequest {
     /**
-     * The level of permission to grant the access token to manage repository projects, columns, and cards. Can be one of: `read`, `write`, or `admin`.
+     * The permission to grant to the team for this project. Can be one of:  
+     *  \\* `read` - team members can read, but not write to or administer this project.  
+     *  \\* `write` - team members can read and write, but not administer this project.  
+     *  \\* `admin` - team members can read, write and administer this project.  
+     *  Default: the team's `permission` attribute will be used to determine what permission to grant the team on this project. Note that, if you choose not to pass any parameters, you'll need to set `Content-Length` to zero when calling out to this endpoint. For more information, see "[HTTP verbs](https://docs.github.com/rest/overview/resources-in-the-rest-api#http-verbs)."
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<RepositoryProjects>,
@@ -31870,10 +35270,12 @@ pub struct TeamsAddUpdateProjectPermissionsLegacyRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsAddUpdateRepoPermissionsLegacyRequest {
     /**
-     * \*\*Deprecated\*\*. The permission that new repositories will be added to the team with when none is specified. Can be one of:  
-     *  \\* `pull` - team members can pull, but not push to or administer newly-added repositories.  
-     *  \\* `push` - team members can pull and push, but not administer newly-added repositories.  
-     *  \\* `admin` - team members can pull, push and administer newly-added repositories.
+     * The permission to grant the team on this repository. Can be one of:  
+     *  \\* `pull` - team members can pull, but not push to or administer this repository.  
+     *  \\* `push` - team members can pull and push, but not administer this repository.  
+     *  \\* `admin` - team members can pull, push and administer this repository.  
+     *    
+     *  If no permission is specified, the team's `permission` attribute will be used to determine what permission to grant the team on this repository.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub permission: Option<Permission>,
@@ -31887,18 +35289,27 @@ pub struct TeamsCreateUpdateIdpGroupConnectionsLegacyRequestGroups {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Description of the IdP group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_description: String,
+    /**
+     * ID of the IdP group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub group_id: String,
+    /**
+     * Name of the IdP group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -31976,54 +35387,77 @@ impl UsersGetByUsernameResponseOneOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersUpdateAuthenticatedRequest {
+    /**
+     * The new short biography of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub bio: String,
+    /**
+     * The new blog URL of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub blog: String,
+    /**
+     * The new company of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub company: String,
+    /**
+     * The publicly visible email address of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The new hiring availability of the user.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hireable: Option<bool>,
+    /**
+     * The new location of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub location: String,
+    /**
+     * The new name of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub twitter_username: String,
+    /**
+     * The new Twitter username of the user.
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub twitter_username: crate::utils::Patch<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
+    /**
+     * Denotes whether an email is publicly visible.
+     */
     #[serde(default, skip_serializing_if = "PackageVisibility::is_noop")]
     pub visibility: PackageVisibility,
 }
@@ -32031,7 +35465,7 @@ pub struct UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersAddEmailAuthenticatedRequest {
     /**
-     * The list of events for the GitHub app
+     * Adds one or more email addresses to your GitHub account. Must contain at least one email address. \*\*Note:\*\* Alternatively, you can pass a single email address or an `array` of emails addresses directly, but we recommend that you pass an object using the `emails` key.
      */
     #[serde(
         default,
@@ -32113,6 +35547,9 @@ impl std::convert::From<UsersAddEmailAuthenticatedRequestOneOf> for Vec<String>
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersCreateGpgKeyAuthenticatedRequest {
+    /**
+     * A GPG key in ASCII-armored format.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -32123,12 +35560,18 @@ pub struct UsersCreateGpgKeyAuthenticatedRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
+    /**
+     * The public SSH key to add to your GitHub account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub key: String,
+    /**
+     * A descriptive name for the new key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -32141,13 +35584,15 @@ pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
 * The state that the membership should be in. Only `"active"` will be accepted.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrgsUpdateMembershipRequestState {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrgsUpdateMembershipRequestState {
@@ -32155,12 +35600,31 @@ impl std::fmt::Display for OrgsUpdateMembershipRequestState {
         match self {
             OrgsUpdateMembershipRequestState::Active => "active",
             OrgsUpdateMembershipRequestState::Noop => "",
-            OrgsUpdateMembershipRequestState::FallthroughString => "*",
+            OrgsUpdateMembershipRequestState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrgsUpdateMembershipRequestState {
+    fn from(s: String) -> OrgsUpdateMembershipRequestState {
+        match s.as_str() {
+            "active" => OrgsUpdateMembershipRequestState::Active,
+            "" => OrgsUpdateMembershipRequestState::Noop,
+            _ => OrgsUpdateMembershipRequestState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrgsUpdateMembershipRequestState> for String {
+    fn from(e: OrgsUpdateMembershipRequestState) -> String {
+        match e {
+            OrgsUpdateMembershipRequestState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrgsUpdateMembershipRequestState {
     fn default() -> OrgsUpdateMembershipRequestState {
         OrgsUpdateMembershipRequestState::Noop
@@ -32188,6 +35652,7 @@ pub struct OrgsUpdateMembershipRequest {
 * Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposListVisibility {
     #[serde(rename = "all")]
     All,
@@ -32195,8 +35660,9 @@ pub enum ReposListVisibility {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListVisibility {
@@ -32205,12 +35671,32 @@ impl std::fmt::Display for ReposListVisibility {
             ReposListVisibility::All => "all",
             ReposListVisibility::Private => "private",
             ReposListVisibility::Public => "public",
-            ReposListVisibility::FallthroughString => "*",
+            ReposListVisibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposListVisibility {
+    fn from(s: String) -> ReposListVisibility {
+        match s.as_str() {
+            "all" => ReposListVisibility::All,
+            "private" => ReposListVisibility::Private,
+            "public" => ReposListVisibility::Public,
+            _ => ReposListVisibility::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposListVisibility> for String {
+    fn from(e: ReposListVisibility) -> String {
+        match e {
+            ReposListVisibility::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposListVisibility {
     fn default() -> ReposListVisibility {
         ReposListVisibility::All
@@ -32223,6 +35709,7 @@ impl Default for ReposListVisibility {
 *   Will cause a `422` error if used in the same request as **visibility** or **affiliation**. Will cause a `422` error if used in the same request as **visibility** or **affiliation**.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposListType {
     #[serde(rename = "all")]
     All,
@@ -32234,8 +35721,9 @@ pub enum ReposListType {
     Private,
     #[serde(rename = "public")]
     Public,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListType {
@@ -32246,12 +35734,34 @@ impl std::fmt::Display for ReposListType {
             ReposListType::Owner => "owner",
             ReposListType::Private => "private",
             ReposListType::Public => "public",
-            ReposListType::FallthroughString => "*",
+            ReposListType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposListType {
+    fn from(s: String) -> ReposListType {
+        match s.as_str() {
+            "all" => ReposListType::All,
+            "member" => ReposListType::Member,
+            "owner" => ReposListType::Owner,
+            "private" => ReposListType::Private,
+            "public" => ReposListType::Public,
+            _ => ReposListType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposListType> for String {
+    fn from(e: ReposListType) -> String {
+        match e {
+            ReposListType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposListType {
     fn default() -> ReposListType {
         ReposListType::All
@@ -32260,60 +35770,114 @@ impl Default for ReposListType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateRequest {
+    /**
+     * Whether to allow Auto-merge to be used on pull requests.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_auto_merge: Option<bool>,
+    /**
+     * Whether to allow merge commits for pull requests.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_merge_commit: Option<bool>,
+    /**
+     * Whether to allow rebase merges for pull requests.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_rebase_merge: Option<bool>,
+    /**
+     * Whether to allow squash merges for pull requests.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_squash_merge: Option<bool>,
+    /**
+     * Whether the repository is initialized with a minimal README.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_init: Option<bool>,
+    /**
+     * Whether to delete head branches when pull requests are merged
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_branch_on_merge: Option<bool>,
+    /**
+     * A short description of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The desired language or platform to apply to the .gitignore.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub gitignore_template: String,
+    /**
+     * Whether downloads are enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_downloads: Option<bool>,
+    /**
+     * Whether issues are enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_issues: Option<bool>,
+    /**
+     * Whether projects are enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_projects: Option<bool>,
+    /**
+     * Whether the wiki is enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_wiki: Option<bool>,
+    /**
+     * A URL with more information about the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub homepage: String,
+    /**
+     * Whether this repository acts as a template that can be used to generate new repositories.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_template: Option<bool>,
+    /**
+     * The license keyword of the open source license for this repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub license_template: String,
+    /**
+     * The name of the repository.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * Whether the repository is private.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<bool>,
+    /**
+     * The id of the team that will be granted access to this repository. This is only valid when creating a repository in an organization.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -32326,6 +35890,7 @@ pub struct ReposCreateRequest {
 * Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. **Required** when using `subject_id`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SubjectType {
     #[serde(rename = "issue")]
     Issue,
@@ -32337,8 +35902,9 @@ pub enum SubjectType {
     Repository,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SubjectType {
@@ -32349,12 +35915,34 @@ impl std::fmt::Display for SubjectType {
             SubjectType::PullRequest => "pull_request",
             SubjectType::Repository => "repository",
             SubjectType::Noop => "",
-            SubjectType::FallthroughString => "*",
+            SubjectType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SubjectType {
+    fn from(s: String) -> SubjectType {
+        match s.as_str() {
+            "issue" => SubjectType::Issue,
+            "organization" => SubjectType::Organization,
+            "pull_request" => SubjectType::PullRequest,
+            "repository" => SubjectType::Repository,
+            "" => SubjectType::Noop,
+            _ => SubjectType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SubjectType> for String {
+    fn from(e: SubjectType) -> String {
+        match e {
+            SubjectType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SubjectType {
     fn default() -> SubjectType {
         SubjectType::Noop
@@ -32370,6 +35958,7 @@ impl SubjectType {
 * Can be one of `all`, `owner`, `member`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReposListUserType {
     #[serde(rename = "all")]
     All,
@@ -32377,8 +35966,9 @@ pub enum ReposListUserType {
     Member,
     #[serde(rename = "owner")]
     Owner,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReposListUserType {
@@ -32387,12 +35977,32 @@ impl std::fmt::Display for ReposListUserType {
             ReposListUserType::All => "all",
             ReposListUserType::Member => "member",
             ReposListUserType::Owner => "owner",
-            ReposListUserType::FallthroughString => "*",
+            ReposListUserType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReposListUserType {
+    fn from(s: String) -> ReposListUserType {
+        match s.as_str() {
+            "all" => ReposListUserType::All,
+            "member" => ReposListUserType::Member,
+            "owner" => ReposListUserType::Owner,
+            _ => ReposListUserType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReposListUserType> for String {
+    fn from(e: ReposListUserType) -> String {
+        match e {
+            ReposListUserType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReposListUserType {
     fn default() -> ReposListUserType {
         ReposListUserType::Owner
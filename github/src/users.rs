@@ -71,9 +71,23 @@ impl Users {
     pub async fn get_authenticated(
         &self,
     ) -> ClientResult<crate::types::UsersGetByUsernameResponseOneOf> {
+        Ok(self.get_authenticated_with_response().await?.body)
+    }
+    /**
+     * Get the authenticated user.
+     *
+     * This function performs a `GET` to the `/user` endpoint.
+     *
+     * As opposed to `get_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#get-the-authenticated-user>
+     */
+    pub async fn get_authenticated_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::UsersGetByUsernameResponseOneOf>> {
         let url = self.client.url("/user", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -95,9 +109,24 @@ impl Users {
         &self,
         body: &crate::types::UsersUpdateAuthenticatedRequest,
     ) -> ClientResult<crate::types::PrivateUser> {
+        Ok(self.update_authenticated_with_response(body).await?.body)
+    }
+    /**
+     * Update the authenticated user.
+     *
+     * This function performs a `PATCH` to the `/user` endpoint.
+     *
+     * As opposed to `update_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users/#update-the-authenticated-user>
+     */
+    pub async fn update_authenticated_with_response(
+        &self,
+        body: &crate::types::UsersUpdateAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<crate::types::PrivateUser>> {
         let url = self.client.url("/user", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -118,9 +147,26 @@ impl Users {
     pub async fn list_blocked_by_authenticated(
         &self,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_blocked_by_authenticated_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * List users blocked by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/blocks` endpoint.
+     *
+     * As opposed to `list_blocked_by_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users-blocked-by-the-authenticated-user>
+     */
+    pub async fn list_blocked_by_authenticated_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let url = self.client.url("/user/blocks", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -154,6 +200,69 @@ impl Users {
             )
             .await
     }
+    /**
+     * List users blocked by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_by_authenticated`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users-blocked-by-the-authenticated-user>
+     */
+    pub fn list_all_blocked_by_authenticated_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/user/blocks", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List users blocked by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_by_authenticated`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users-blocked-by-the-authenticated-user>
+     */
+    pub fn list_all_blocked_by_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_blocked_by_authenticated_pages_stream(None))
+    }
     /**
      * Check if a user is blocked by the authenticated user.
      *
@@ -168,6 +277,22 @@ impl Users {
      * * `username: &str`
      */
     pub async fn check_blocked(&self, username: &str) -> ClientResult<()> {
+        self.check_blocked_with_response(username).await?;
+        Ok(())
+    }
+    /**
+     * Check if a user is blocked by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/blocks/{username}` endpoint.
+     *
+     * As opposed to `check_blocked`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#check-if-a-user-is-blocked-by-the-authenticated-user>
+     */
+    pub async fn check_blocked_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/blocks/{}",
@@ -176,7 +301,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -199,6 +324,19 @@ impl Users {
      * * `username: &str`
      */
     pub async fn block(&self, username: &str) -> ClientResult<()> {
+        self.block_with_response(username).await?;
+        Ok(())
+    }
+    /**
+     * Block a user.
+     *
+     * This function performs a `PUT` to the `/user/blocks/{username}` endpoint.
+     *
+     * As opposed to `block`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#block-a-user>
+     */
+    pub async fn block_with_response(&self, username: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/blocks/{}",
@@ -207,7 +345,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -230,6 +368,19 @@ impl Users {
      * * `username: &str`
      */
     pub async fn unblock(&self, username: &str) -> ClientResult<()> {
+        self.unblock_with_response(username).await?;
+        Ok(())
+    }
+    /**
+     * Unblock a user.
+     *
+     * This function performs a `DELETE` to the `/user/blocks/{username}` endpoint.
+     *
+     * As opposed to `unblock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#unblock-a-user>
+     */
+    pub async fn unblock_with_response(&self, username: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/blocks/{}",
@@ -238,7 +389,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -260,9 +411,27 @@ impl Users {
         &self,
         body: &crate::types::UsersSetPrimaryEmailVisibilityAuthenticatedRequest,
     ) -> ClientResult<Vec<crate::types::Email>> {
+        Ok(self
+            .set_primary_email_visibility_for_authenticated_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Set primary email visibility for the authenticated user.
+     *
+     * This function performs a `PATCH` to the `/user/email/visibility` endpoint.
+     *
+     * As opposed to `set_primary_email_visibility_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#set-primary-email-visibility-for-the-authenticated-user>
+     */
+    pub async fn set_primary_email_visibility_for_authenticated_with_response(
+        &self,
+        body: &crate::types::UsersSetPrimaryEmailVisibilityAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
         let url = self.client.url("/user/email/visibility", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -290,6 +459,25 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Email>> {
+        Ok(self
+            .list_emails_for_authenticated_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/emails` endpoint.
+     *
+     * As opposed to `list_emails_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-email-addresses-for-the-authenticated-user>
+     */
+    pub async fn list_emails_for_authenticated_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -300,7 +488,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/emails?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -334,6 +522,65 @@ impl Users {
             )
             .await
     }
+    /**
+     * List email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/emails` endpoint.
+     *
+     * As opposed to `list_all_emails_for_authenticated`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_emails_for_authenticated_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Email>>> + Unpin + '_
+    {
+        let url = self.client.url("/user/emails", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Email>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/emails` endpoint.
+     *
+     * As opposed to `list_all_emails_for_authenticated`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_emails_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Email>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_emails_for_authenticated_pages_stream(None))
+    }
     /**
      * Add an email address for the authenticated user.
      *
@@ -347,9 +594,27 @@ impl Users {
         &self,
         body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
     ) -> ClientResult<Vec<crate::types::Email>> {
+        Ok(self
+            .add_email_for_authenticated_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Add an email address for the authenticated user.
+     *
+     * This function performs a `POST` to the `/user/emails` endpoint.
+     *
+     * As opposed to `add_email_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#add-an-email-address-for-the-authenticated-user>
+     */
+    pub async fn add_email_for_authenticated_with_response(
+        &self,
+        body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
         let url = self.client.url("/user/emails", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -371,9 +636,26 @@ impl Users {
         &self,
         body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
     ) -> ClientResult<()> {
+        self.delete_email_for_authenticated_with_response(body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an email address for the authenticated user.
+     *
+     * This function performs a `DELETE` to the `/user/emails` endpoint.
+     *
+     * As opposed to `delete_email_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#delete-an-email-address-for-the-authenticated-user>
+     */
+    pub async fn delete_email_for_authenticated_with_response(
+        &self,
+        body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/user/emails", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -401,6 +683,25 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_followers_for_authenticated_user_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List followers of the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/followers` endpoint.
+     *
+     * As opposed to `list_followers_for_authenticated_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-the-authenticated-user>
+     */
+    pub async fn list_followers_for_authenticated_user_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -413,7 +714,7 @@ impl Users {
             .client
             .url(&format!("/user/followers?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -447,6 +748,69 @@ impl Users {
             )
             .await
     }
+    /**
+     * List followers of the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_authenticated_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-the-authenticated-user>
+     */
+    pub fn list_all_followers_for_authenticated_user_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/user/followers", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List followers of the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_authenticated_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-the-authenticated-user>
+     */
+    pub fn list_all_followers_for_authenticated_user_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_followers_for_authenticated_user_pages_stream(None))
+    }
     /**
      * List the people the authenticated user follows.
      *
@@ -466,6 +830,25 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_followed_by_authenticated_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List the people the authenticated user follows.
+     *
+     * This function performs a `GET` to the `/user/following` endpoint.
+     *
+     * As opposed to `list_followed_by_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
+     */
+    pub async fn list_followed_by_authenticated_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -478,7 +861,7 @@ impl Users {
             .client
             .url(&format!("/user/following?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -512,6 +895,69 @@ impl Users {
             )
             .await
     }
+    /**
+     * List the people the authenticated user follows.
+     *
+     * This function performs a `GET` to the `/user/following` endpoint.
+     *
+     * As opposed to `list_all_followed_by_authenticated`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
+     */
+    pub fn list_all_followed_by_authenticated_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/user/following", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List the people the authenticated user follows.
+     *
+     * This function performs a `GET` to the `/user/following` endpoint.
+     *
+     * As opposed to `list_all_followed_by_authenticated`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
+     */
+    pub fn list_all_followed_by_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_followed_by_authenticated_pages_stream(None))
+    }
     /**
      * Check if a person is followed by the authenticated user.
      *
@@ -529,6 +975,23 @@ impl Users {
         &self,
         username: &str,
     ) -> ClientResult<()> {
+        self.check_person_is_followed_by_authenticated_with_response(username)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check if a person is followed by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/following/{username}` endpoint.
+     *
+     * As opposed to `check_person_is_followed_by_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#check-if-a-person-is-followed-by-the-authenticated-user>
+     */
+    pub async fn check_person_is_followed_by_authenticated_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/following/{}",
@@ -537,7 +1000,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -562,6 +1025,19 @@ impl Users {
      * * `username: &str`
      */
     pub async fn follow(&self, username: &str) -> ClientResult<()> {
+        self.follow_with_response(username).await?;
+        Ok(())
+    }
+    /**
+     * Follow a user.
+     *
+     * This function performs a `PUT` to the `/user/following/{username}` endpoint.
+     *
+     * As opposed to `follow`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#follow-a-user>
+     */
+    pub async fn follow_with_response(&self, username: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/following/{}",
@@ -570,7 +1046,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -593,6 +1069,22 @@ impl Users {
      * * `username: &str`
      */
     pub async fn unfollow(&self, username: &str) -> ClientResult<()> {
+        self.unfollow_with_response(username).await?;
+        Ok(())
+    }
+    /**
+     * Unfollow a user.
+     *
+     * This function performs a `DELETE` to the `/user/following/{username}` endpoint.
+     *
+     * As opposed to `unfollow`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#unfollow-a-user>
+     */
+    pub async fn unfollow_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/following/{}",
@@ -601,7 +1093,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -629,6 +1121,25 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::GpgKey>> {
+        Ok(self
+            .list_gpg_keys_for_authenticated_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List GPG keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `list_gpg_keys_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-the-authenticated-user>
+     */
+    pub async fn list_gpg_keys_for_authenticated_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -639,7 +1150,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/gpg_keys?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -673,6 +1184,65 @@ impl Users {
             )
             .await
     }
+    /**
+     * List GPG keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_authenticated`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_gpg_keys_for_authenticated_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GpgKey>>> + Unpin + '_
+    {
+        let url = self.client.url("/user/gpg_keys", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::GpgKey>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List GPG keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_authenticated`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_gpg_keys_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GpgKey>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_gpg_keys_for_authenticated_pages_stream(None))
+    }
     /**
      * Create a GPG key for the authenticated user.
      *
@@ -686,9 +1256,27 @@ impl Users {
         &self,
         body: &crate::types::UsersCreateGpgKeyAuthenticatedRequest,
     ) -> ClientResult<crate::types::GpgKey> {
+        Ok(self
+            .create_gpg_key_for_authenticated_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a GPG key for the authenticated user.
+     *
+     * This function performs a `POST` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `create_gpg_key_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#create-a-gpg-key-for-the-authenticated-user>
+     */
+    pub async fn create_gpg_key_for_authenticated_with_response(
+        &self,
+        body: &crate::types::UsersCreateGpgKeyAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<crate::types::GpgKey>> {
         let url = self.client.url("/user/gpg_keys", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -714,6 +1302,24 @@ impl Users {
         &self,
         gpg_key_id: i64,
     ) -> ClientResult<crate::types::GpgKey> {
+        Ok(self
+            .get_gpg_key_for_authenticated_with_response(gpg_key_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a GPG key for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys/{gpg_key_id}` endpoint.
+     *
+     * As opposed to `get_gpg_key_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#get-a-gpg-key-for-the-authenticated-user>
+     */
+    pub async fn get_gpg_key_for_authenticated_with_response(
+        &self,
+        gpg_key_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GpgKey>> {
         let url = self.client.url(
             &format!(
                 "/user/gpg_keys/{}",
@@ -722,7 +1328,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -745,6 +1351,23 @@ impl Users {
      * * `gpg_key_id: i64` -- gpg_key_id parameter.
      */
     pub async fn delete_gpg_key_for_authenticated(&self, gpg_key_id: i64) -> ClientResult<()> {
+        self.delete_gpg_key_for_authenticated_with_response(gpg_key_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a GPG key for the authenticated user.
+     *
+     * This function performs a `DELETE` to the `/user/gpg_keys/{gpg_key_id}` endpoint.
+     *
+     * As opposed to `delete_gpg_key_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#delete-a-gpg-key-for-the-authenticated-user>
+     */
+    pub async fn delete_gpg_key_for_authenticated_with_response(
+        &self,
+        gpg_key_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/gpg_keys/{}",
@@ -753,7 +1376,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -781,6 +1404,25 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Key>> {
+        Ok(self
+            .list_public_ssh_keys_for_authenticated_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public SSH keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys` endpoint.
+     *
+     * As opposed to `list_public_ssh_keys_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-ssh-keys-for-the-authenticated-user>
+     */
+    pub async fn list_public_ssh_keys_for_authenticated_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Key>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -791,7 +1433,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/user/keys?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -825,6 +1467,65 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public SSH keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys` endpoint.
+     *
+     * As opposed to `list_all_public_ssh_keys_for_authenticated`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-ssh-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_public_ssh_keys_for_authenticated_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Key>>> + Unpin + '_
+    {
+        let url = self.client.url("/user/keys", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Key>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public SSH keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys` endpoint.
+     *
+     * As opposed to `list_all_public_ssh_keys_for_authenticated`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-ssh-keys-for-the-authenticated-user>
+     */
+    pub fn list_all_public_ssh_keys_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Key>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_ssh_keys_for_authenticated_pages_stream(None))
+    }
     /**
      * Create a public SSH key for the authenticated user.
      *
@@ -838,9 +1539,27 @@ impl Users {
         &self,
         body: &crate::types::UsersCreatePublicSshKeyAuthenticatedRequest,
     ) -> ClientResult<crate::types::Key> {
+        Ok(self
+            .create_public_ssh_key_for_authenticated_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a public SSH key for the authenticated user.
+     *
+     * This function performs a `POST` to the `/user/keys` endpoint.
+     *
+     * As opposed to `create_public_ssh_key_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#create-a-public-ssh-key-for-the-authenticated-user>
+     */
+    pub async fn create_public_ssh_key_for_authenticated_with_response(
+        &self,
+        body: &crate::types::UsersCreatePublicSshKeyAuthenticatedRequest,
+    ) -> ClientResult<crate::Response<crate::types::Key>> {
         let url = self.client.url("/user/keys", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -866,6 +1585,24 @@ impl Users {
         &self,
         key_id: i64,
     ) -> ClientResult<crate::types::Key> {
+        Ok(self
+            .get_public_ssh_key_for_authenticated_with_response(key_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a public SSH key for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys/{key_id}` endpoint.
+     *
+     * As opposed to `get_public_ssh_key_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#get-a-public-ssh-key-for-the-authenticated-user>
+     */
+    pub async fn get_public_ssh_key_for_authenticated_with_response(
+        &self,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Key>> {
         let url = self.client.url(
             &format!(
                 "/user/keys/{}",
@@ -874,7 +1611,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -897,6 +1634,23 @@ impl Users {
      * * `key_id: i64` -- key_id parameter.
      */
     pub async fn delete_public_ssh_key_for_authenticated(&self, key_id: i64) -> ClientResult<()> {
+        self.delete_public_ssh_key_for_authenticated_with_response(key_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a public SSH key for the authenticated user.
+     *
+     * This function performs a `DELETE` to the `/user/keys/{key_id}` endpoint.
+     *
+     * As opposed to `delete_public_ssh_key_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#delete-a-public-ssh-key-for-the-authenticated-user>
+     */
+    pub async fn delete_public_ssh_key_for_authenticated_with_response(
+        &self,
+        key_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/keys/{}",
@@ -905,7 +1659,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -933,6 +1687,25 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::Email>> {
+        Ok(self
+            .list_public_emails_for_authenticated_with_response(per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/public_emails` endpoint.
+     *
+     * As opposed to `list_public_emails_for_authenticated`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-email-addresses-for-the-authenticated-user>
+     */
+    pub async fn list_public_emails_for_authenticated_with_response(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Email>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -945,7 +1718,7 @@ impl Users {
             .client
             .url(&format!("/user/public_emails?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -979,6 +1752,65 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/public_emails` endpoint.
+     *
+     * As opposed to `list_all_public_emails_for_authenticated`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_public_emails_for_authenticated_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Email>>> + Unpin + '_
+    {
+        let url = self.client.url("/user/public_emails", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Email>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/public_emails` endpoint.
+     *
+     * As opposed to `list_all_public_emails_for_authenticated`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_all_public_emails_for_authenticated_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Email>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_emails_for_authenticated_pages_stream(None))
+    }
     /**
      * List users.
      *
@@ -999,7 +1831,23 @@ impl Users {
         &self,
         since: i64,
         per_page: i64,
-    ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+    ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self.list_with_response(since, per_page).await?.body)
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     */
+    pub async fn list_with_response(
+        &self,
+        since: i64,
+        per_page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if per_page > 0 {
             query_args.push(("per_page".to_string(), per_page.to_string()));
@@ -1010,7 +1858,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1049,6 +1897,77 @@ impl Users {
             )
             .await
     }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        since: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if since > 0 {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/users?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     */
+    pub fn list_all_stream(
+        &self,
+        since: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(since, None))
+    }
     /**
      * Get a user.
      *
@@ -1152,6 +2071,21 @@ impl Users {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::UsersGetByUsernameResponseOneOf> {
+        Ok(self.get_by_username_with_response(username).await?.body)
+    }
+    /**
+     * Get a user.
+     *
+     * This function performs a `GET` to the `/users/{username}` endpoint.
+     *
+     * As opposed to `get_by_username`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#get-a-user>
+     */
+    pub async fn get_by_username_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersGetByUsernameResponseOneOf>> {
         let url = self.client.url(
             &format!(
                 "/users/{}",
@@ -1160,7 +2094,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1190,6 +2124,26 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_followers_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List followers of a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/followers` endpoint.
+     *
+     * As opposed to `list_followers_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-a-user>
+     */
+    pub async fn list_followers_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1207,7 +2161,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1248,6 +2202,78 @@ impl Users {
             )
             .await
     }
+    /**
+     * List followers of a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-a-user>
+     */
+    pub fn list_all_followers_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/followers",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List followers of a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-a-user>
+     */
+    pub fn list_all_followers_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_followers_for_user_pages_stream(username, None))
+    }
     /**
      * List the people a user follows.
      *
@@ -1269,6 +2295,26 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::SimpleUser>> {
+        Ok(self
+            .list_following_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_following_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-a-user-follows>
+     */
+    pub async fn list_following_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SimpleUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1286,7 +2332,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1327,6 +2373,78 @@ impl Users {
             )
             .await
     }
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_all_following_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-a-user-follows>
+     */
+    pub fn list_all_following_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SimpleUser>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/following",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SimpleUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_all_following_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-a-user-follows>
+     */
+    pub fn list_all_following_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SimpleUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_following_for_user_pages_stream(username, None))
+    }
     /**
      * Check if a user follows another user.
      *
@@ -1346,6 +2464,24 @@ impl Users {
         username: &str,
         target_user: &str,
     ) -> ClientResult<()> {
+        self.check_following_for_user_with_response(username, target_user)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Check if a user follows another user.
+     *
+     * This function performs a `GET` to the `/users/{username}/following/{target_user}` endpoint.
+     *
+     * As opposed to `check_following_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#check-if-a-user-follows-another-user>
+     */
+    pub async fn check_following_for_user_with_response(
+        &self,
+        username: &str,
+        target_user: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/following/{}",
@@ -1355,7 +2491,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1385,6 +2521,26 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::GpgKey>> {
+        Ok(self
+            .list_gpg_keys_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List GPG keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gpg_keys` endpoint.
+     *
+     * As opposed to `list_gpg_keys_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-a-user>
+     */
+    pub async fn list_gpg_keys_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GpgKey>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1402,7 +2558,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1443,6 +2599,74 @@ impl Users {
             )
             .await
     }
+    /**
+     * List GPG keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-a-user>
+     */
+    pub fn list_all_gpg_keys_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GpgKey>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/gpg_keys",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::GpgKey>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List GPG keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-a-user>
+     */
+    pub fn list_all_gpg_keys_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GpgKey>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_gpg_keys_for_user_pages_stream(username, None))
+    }
     /**
      * Get contextual information for a user.
      *
@@ -1471,6 +2695,26 @@ impl Users {
         subject_type: crate::types::SubjectType,
         subject_id: &str,
     ) -> ClientResult<crate::types::Hovercard> {
+        Ok(self
+            .get_context_for_user_with_response(username, subject_type, subject_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get contextual information for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/hovercard` endpoint.
+     *
+     * As opposed to `get_context_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#get-contextual-information-for-a-user>
+     */
+    pub async fn get_context_for_user_with_response(
+        &self,
+        username: &str,
+        subject_type: crate::types::SubjectType,
+        subject_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Hovercard>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !subject_id.is_empty() {
             query_args.push(("subject_id".to_string(), subject_id.to_string()));
@@ -1488,7 +2732,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1518,6 +2762,26 @@ impl Users {
         per_page: i64,
         page: i64,
     ) -> ClientResult<Vec<crate::types::KeySimple>> {
+        Ok(self
+            .list_public_keys_for_user_with_response(username, per_page, page)
+            .await?
+            .body)
+    }
+    /**
+     * List public keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/keys` endpoint.
+     *
+     * As opposed to `list_public_keys_for_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-keys-for-a-user>
+     */
+    pub async fn list_public_keys_for_user_with_response(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::KeySimple>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -1535,7 +2799,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1576,4 +2840,76 @@ impl Users {
             )
             .await
     }
+    /**
+     * List public keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/keys` endpoint.
+     *
+     * As opposed to `list_all_public_keys_for_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-keys-for-a-user>
+     */
+    pub fn list_all_public_keys_for_user_pages_stream(
+        &self,
+        username: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::KeySimple>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/users/{}/keys",
+                crate::progenitor_support::encode_path(username),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::KeySimple>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List public keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/keys` endpoint.
+     *
+     * As opposed to `list_all_public_keys_for_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-keys-for-a-user>
+     */
+    pub fn list_all_public_keys_for_user_stream(
+        &self,
+        username: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::KeySimple>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_public_keys_for_user_pages_stream(username, None))
+    }
 }
@@ -0,0 +1,747 @@
+//! Verify and parse the webhooks GitHub delivers to your app or repository.
+//!
+//! ```ignore
+//! let event = octorust::webhooks::verify_and_parse(&secret, &headers, &body)?;
+//! if let octorust::webhooks::WebhookEvent::Push(push) = event {
+//!     println!("{} pushed to {}", push.pusher.name, push.ref_);
+//! }
+//! ```
+use ring::hmac;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientError, ClientResult};
+
+/// The header carrying the HMAC-SHA256 signature of the payload.
+pub const SIGNATURE_256_HEADER: &str = "X-Hub-Signature-256";
+/// The legacy header carrying the HMAC-SHA1 signature of the payload.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature";
+/// The header carrying the name of the event that triggered the delivery.
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+/// The header carrying the unique ID of the delivery.
+pub const DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+
+/// Verify the signature GitHub sent for a webhook payload.
+///
+/// `signature` is the value of the `X-Hub-Signature-256` header (`sha256=<hex>`).
+/// `body` must be the raw request body, before any parsing. The comparison is
+/// done in constant time.
+pub fn verify_signature(secret: &str, signature: &str, body: &[u8]) -> ClientResult<()> {
+    let hex = signature.strip_prefix("sha256=").ok_or_else(|| {
+        ClientError::InvalidWebhookSignature("unsupported signature algorithm".to_string())
+    })?;
+    verify_hmac(hmac::HMAC_SHA256, secret, hex, body)
+}
+
+/// Verify the legacy HMAC-SHA1 signature GitHub sends in `X-Hub-Signature`
+/// (`sha1=<hex>`).
+///
+/// SHA-1 is only there for servers that can't use SHA-256, prefer
+/// `verify_signature`.
+pub fn verify_legacy_signature(secret: &str, signature: &str, body: &[u8]) -> ClientResult<()> {
+    let hex = signature.strip_prefix("sha1=").ok_or_else(|| {
+        ClientError::InvalidWebhookSignature("unsupported signature algorithm".to_string())
+    })?;
+    verify_hmac(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret, hex, body)
+}
+
+fn verify_hmac(
+    algorithm: hmac::Algorithm,
+    secret: &str,
+    hex: &str,
+    body: &[u8],
+) -> ClientResult<()> {
+    let tag = decode_hex(hex).ok_or_else(|| {
+        ClientError::InvalidWebhookSignature("signature is not valid hex".to_string())
+    })?;
+
+    let key = hmac::Key::new(algorithm, secret.as_bytes());
+    hmac::verify(&key, body, &tag).map_err(|_| {
+        ClientError::InvalidWebhookSignature("signature does not match payload".to_string())
+    })
+}
+
+/// Parse a webhook payload into a typed event, based on the `X-GitHub-Event` header.
+///
+/// Events this module does not model are returned as `WebhookEvent::Unknown`.
+pub fn parse_event(event: &str, body: &[u8]) -> ClientResult<WebhookEvent> {
+    Ok(match event {
+        "ping" => WebhookEvent::Ping(serde_json::from_slice(body)?),
+        "push" => {
+            let mut payload: serde_json::Value = serde_json::from_slice(body)?;
+            // Push payloads send the repository timestamps as unix seconds.
+            if let Some(repository) = payload.get_mut("repository") {
+                normalize_timestamps(repository, &["created_at", "pushed_at"]);
+            }
+            WebhookEvent::Push(serde_json::from_value(payload)?)
+        }
+        "pull_request" => WebhookEvent::PullRequest(serde_json::from_slice(body)?),
+        "check_run" => WebhookEvent::CheckRun(serde_json::from_slice(body)?),
+        "check_suite" => WebhookEvent::CheckSuite(serde_json::from_slice(body)?),
+        "installation" => WebhookEvent::Installation(serde_json::from_slice(body)?),
+        "installation_repositories" => {
+            WebhookEvent::InstallationRepositories(serde_json::from_slice(body)?)
+        }
+        "issues" => WebhookEvent::Issues(serde_json::from_slice(body)?),
+        "issue_comment" => WebhookEvent::IssueComment(serde_json::from_slice(body)?),
+        _ => WebhookEvent::Unknown {
+            event: event.to_string(),
+            payload: serde_json::from_slice(body)?,
+        },
+    })
+}
+
+/// Verify the `X-Hub-Signature-256` signature of a webhook delivery and parse
+/// it into a typed event.
+///
+/// Deliveries without an `X-Hub-Signature-256` header are rejected, use
+/// `verify_and_parse_with_legacy_signature` to accept the SHA-1 one as well.
+pub fn verify_and_parse(
+    secret: &str,
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> ClientResult<WebhookEvent> {
+    let signature = header(headers, SIGNATURE_256_HEADER).ok_or_else(|| {
+        ClientError::InvalidWebhookSignature(format!("missing {} header", SIGNATURE_256_HEADER))
+    })?;
+    verify_signature(secret, signature, body)?;
+
+    parse_delivery(headers, body)
+}
+
+/// Like `verify_and_parse`, but falls back to the legacy SHA-1 signature in
+/// `X-Hub-Signature` when a delivery has no `X-Hub-Signature-256` header.
+pub fn verify_and_parse_with_legacy_signature(
+    secret: &str,
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> ClientResult<WebhookEvent> {
+    if let Some(signature) = header(headers, SIGNATURE_256_HEADER) {
+        verify_signature(secret, signature, body)?;
+    } else if let Some(signature) = header(headers, SIGNATURE_HEADER) {
+        verify_legacy_signature(secret, signature, body)?;
+    } else {
+        return Err(ClientError::InvalidWebhookSignature(
+            "missing signature header".to_string(),
+        ));
+    }
+
+    parse_delivery(headers, body)
+}
+
+fn parse_delivery(headers: &http::HeaderMap, body: &[u8]) -> ClientResult<WebhookEvent> {
+    let event = header(headers, EVENT_HEADER).ok_or_else(|| {
+        ClientError::InvalidWebhookPayload(format!("missing {} header", EVENT_HEADER))
+    })?;
+    parse_event(event, body)
+}
+
+fn header<'a>(headers: &'a http::HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|c| match c {
+            [_, _] => u8::from_str_radix(std::str::from_utf8(c).ok()?, 16).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+fn normalize_timestamps(object: &mut serde_json::Value, fields: &[&str]) {
+    use chrono::TimeZone;
+
+    for field in fields {
+        if let Some(v) = object.get_mut(*field) {
+            if let Some(t) = v
+                .as_i64()
+                .and_then(|secs| chrono::Utc.timestamp_opt(secs, 0).single())
+            {
+                *v = serde_json::Value::String(t.to_rfc3339());
+            }
+        }
+    }
+}
+
+/// A webhook delivery, keyed by the `X-GitHub-Event` header.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    Ping(PingEvent),
+    Push(PushEvent),
+    PullRequest(PullRequestEvent),
+    CheckRun(CheckRunEvent),
+    CheckSuite(CheckSuiteEvent),
+    Installation(InstallationEvent),
+    InstallationRepositories(InstallationRepositoriesEvent),
+    Issues(IssuesEvent),
+    IssueComment(IssueCommentEvent),
+    /// An event this module does not have a type for.
+    Unknown {
+        event: String,
+        payload: serde_json::Value,
+    },
+}
+
+/// The installation a delivery was sent for, when it was sent to a GitHub App.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WebhookInstallation {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+}
+
+/// Sent when a new webhook is created.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PingEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub zen: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub hook_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hook: Option<crate::types::Hook>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<crate::types::Repository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+}
+
+/// A commit included in a push.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PushCommit {
+    #[serde(flatten)]
+    pub commit: crate::types::SimpleCommit,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub distinct: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub added: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub removed: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub modified: Vec<String>,
+}
+
+/// Sent when one or more commits are pushed to a branch or tag.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PushEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub before: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub after: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub created: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub deleted: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub forced: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub base_ref: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub compare: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub commits: Vec<PushCommit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_commit: Option<PushCommit>,
+    pub pusher: crate::types::Author,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<WebhookInstallation>,
+}
+
+/// Sent when a pull request is opened, edited, closed, synchronized, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    pub pull_request: crate::types::PullRequestSimple,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<WebhookInstallation>,
+}
+
+/// Sent when a check run is created, completed, rerequested, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckRunEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub check_run: crate::types::CheckRun,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<WebhookInstallation>,
+}
+
+/// Sent when a check suite is completed, requested or rerequested.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckSuiteEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub check_suite: crate::types::CheckSuite,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<WebhookInstallation>,
+}
+
+/// A repository as listed in installation events.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InstallationRepository {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub full_name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub private: bool,
+}
+
+/// Sent when a GitHub App is installed, uninstalled, suspended, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InstallationEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub installation: crate::types::Installation,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<InstallationRepository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+}
+
+/// Sent when repositories are added to or removed from an installation.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InstallationRepositoriesEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub installation: crate::types::Installation,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_selection: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories_added: Vec<InstallationRepository>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories_removed: Vec<InstallationRepository>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+}
+
+/// Sent when an issue is opened, edited, closed, labeled, etc.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub issue: crate::types::IssueSimple,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<WebhookInstallation>,
+}
+
+/// Sent when a comment on an issue or pull request is created, edited or deleted.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueCommentEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    pub issue: crate::types::IssueSimple,
+    pub comment: crate::types::IssueComment,
+    pub repository: crate::types::Repository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<crate::types::SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installation: Option<WebhookInstallation>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "It's a Secret to Everybody";
+    const BODY: &[u8] = b"Hello, World!";
+
+    #[test]
+    fn verify_signature_accepts_github_example() {
+        // The example from GitHub's "Validating webhook deliveries" docs.
+        verify_signature(
+            SECRET,
+            "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+            BODY,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn verify_signature_rejects_mismatches() {
+        let bad = [
+            "sha256=857107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+            "sha256=757107ea0eb2509fc211221cce984b8a",
+            "sha256=not-hex",
+            "md5=757107ea0eb2509fc211221cce984b8a",
+            "",
+        ];
+        for signature in bad {
+            assert!(
+                matches!(
+                    verify_signature(SECRET, signature, BODY),
+                    Err(ClientError::InvalidWebhookSignature(_))
+                ),
+                "{} should not verify",
+                signature
+            );
+        }
+    }
+
+    #[test]
+    fn verify_signature_needs_opt_in_for_sha1() {
+        let signature = format!("sha1={}", sign(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, BODY));
+
+        assert!(matches!(
+            verify_signature(SECRET, &signature, BODY),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+        verify_legacy_signature(SECRET, &signature, BODY).unwrap();
+        assert!(matches!(
+            verify_legacy_signature(SECRET, "sha1=0000", BODY),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+    }
+
+    #[test]
+    fn parse_push_event() {
+        let body = serde_json::json!({
+            "ref": "refs/heads/main",
+            "before": "0000000000000000000000000000000000000000",
+            "after": "6113728f27ae82c7b1a177c8d03f9e96e0adf246",
+            "created": true,
+            "deleted": false,
+            "forced": false,
+            "base_ref": null,
+            "compare": "https://github.com/Codertocat/Hello-World/compare/6113728f27ae",
+            "commits": [{
+                "id": "6113728f27ae82c7b1a177c8d03f9e96e0adf246",
+                "tree_id": "4b825dc642cb6eb9a060e54bf8d69288fbee4904",
+                "distinct": true,
+                "message": "Initial commit",
+                "timestamp": "2019-05-15T15:20:41Z",
+                "url": "https://github.com/Codertocat/Hello-World/commit/6113728f27ae",
+                "author": {"name": "Codertocat", "email": "octocat@github.com", "username": "Codertocat"},
+                "committer": {"name": "GitHub", "email": "noreply@github.com", "username": "web-flow"},
+                "added": ["README.md"],
+                "removed": [],
+                "modified": []
+            }],
+            "head_commit": null,
+            "pusher": {"name": "Codertocat", "email": "21031067+Codertocat@users.noreply.github.com"},
+            "repository": {
+                "id": 186853002,
+                "name": "Hello-World",
+                "full_name": "Codertocat/Hello-World",
+                "owner": {"login": "Codertocat", "id": 21031067, "type": "User", "site_admin": false},
+                "private": false,
+                "created_at": 1557933565,
+                "pushed_at": 1557933657,
+                "updated_at": "2019-05-15T15:20:41Z",
+                "default_branch": "main"
+            },
+            "sender": {"login": "Codertocat", "id": 21031067, "type": "User", "site_admin": false}
+        });
+
+        let event = parse_event("push", body.to_string().as_bytes()).unwrap();
+        let push = match event {
+            WebhookEvent::Push(push) => push,
+            e => panic!("expected a push event, got {:?}", e),
+        };
+        assert_eq!(push.ref_, "refs/heads/main");
+        assert_eq!(push.commits.len(), 1);
+        assert_eq!(push.commits[0].commit.message, "Initial commit");
+        assert_eq!(push.commits[0].added, vec!["README.md".to_string()]);
+        assert_eq!(push.pusher.name, "Codertocat");
+        assert_eq!(push.repository.full_name, "Codertocat/Hello-World");
+        assert_eq!(
+            push.repository.created_at.unwrap().to_rfc3339(),
+            "2019-05-15T15:19:25+00:00"
+        );
+        assert_eq!(push.sender.unwrap().login, "Codertocat");
+    }
+
+    #[test]
+    fn parse_pull_request_event() {
+        let user = serde_json::json!({"login": "Codertocat", "id": 21031067, "type": "User", "site_admin": false});
+        let repo = serde_json::json!({
+            "id": 186853002,
+            "name": "Hello-World",
+            "full_name": "Codertocat/Hello-World",
+            "owner": user,
+            "created_at": "2019-05-15T15:19:25Z",
+            "pushed_at": "2019-05-15T15:20:57Z"
+        });
+        let body = serde_json::json!({
+            "action": "opened",
+            "number": 2,
+            "pull_request": {
+                "url": "https://api.github.com/repos/Codertocat/Hello-World/pulls/2",
+                "id": 279147437,
+                "number": 2,
+                "state": "open",
+                "locked": false,
+                "title": "Update the README with new information.",
+                "user": user,
+                "body": "This is a pretty simple change that we need to pull into main.",
+                "created_at": "2019-05-15T15:20:33Z",
+                "updated_at": "2019-05-15T15:20:33Z",
+                "closed_at": null,
+                "merged_at": null,
+                "merge_commit_sha": null,
+                "assignees": [],
+                "requested_reviewers": [],
+                "requested_teams": [],
+                "labels": [],
+                "draft": false,
+                "head": {"label": "Codertocat:changes", "ref": "changes", "sha": "ec26c3e57ca3a959ca5aad62de7213c562f8c821", "user": user, "repo": repo},
+                "base": {"label": "Codertocat:main", "ref": "main", "sha": "f95f852bd8fca8fcc58a9a2d6c842781e32a215e", "user": user, "repo": repo},
+                "_links": {
+                    "self": {"href": "https://api.github.com/repos/Codertocat/Hello-World/pulls/2"},
+                    "html": {"href": "https://github.com/Codertocat/Hello-World/pull/2"},
+                    "issue": {"href": "https://api.github.com/repos/Codertocat/Hello-World/issues/2"},
+                    "comments": {"href": "https://api.github.com/repos/Codertocat/Hello-World/issues/2/comments"},
+                    "review_comments": {"href": "https://api.github.com/repos/Codertocat/Hello-World/pulls/2/comments"},
+                    "review_comment": {"href": "https://api.github.com/repos/Codertocat/Hello-World/pulls/comments{/number}"},
+                    "commits": {"href": "https://api.github.com/repos/Codertocat/Hello-World/pulls/2/commits"},
+                    "statuses": {"href": "https://api.github.com/repos/Codertocat/Hello-World/statuses/ec26c3e57ca3a959ca5aad62de7213c562f8c821"}
+                },
+                "author_association": "OWNER"
+            },
+            "repository": repo,
+            "sender": user,
+            "installation": {"id": 2311213, "node_id": "MDIzOkludGVncmF0aW9uSW5zdGFsbGF0aW9uMjMxMTIxMw=="}
+        });
+
+        let event = parse_event("pull_request", body.to_string().as_bytes()).unwrap();
+        let pr = match event {
+            WebhookEvent::PullRequest(pr) => pr,
+            e => panic!("expected a pull_request event, got {:?}", e),
+        };
+        assert_eq!(pr.action, "opened");
+        assert_eq!(pr.number, 2);
+        assert_eq!(pr.pull_request.head.ref_, "changes");
+        assert_eq!(pr.pull_request.user.unwrap().login, "Codertocat");
+        assert_eq!(pr.installation.unwrap().id, 2311213);
+    }
+
+    #[test]
+    fn parse_unknown_event() {
+        let event = parse_event("star", br#"{"action": "created"}"#).unwrap();
+        assert_eq!(
+            event,
+            WebhookEvent::Unknown {
+                event: "star".to_string(),
+                payload: serde_json::json!({"action": "created"}),
+            }
+        );
+    }
+
+    #[test]
+    fn verify_and_parse_requires_signature() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(EVENT_HEADER, "ping".parse().unwrap());
+        let body = br#"{"zen": "Keep it logically awesome.", "hook_id": 1}"#;
+
+        assert!(matches!(
+            verify_and_parse(SECRET, &headers, body),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+
+        // The SHA-1 signature alone isn't enough.
+        let sha1 = format!("sha1={}", sign(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, body));
+        headers.insert(SIGNATURE_HEADER, sha1.parse().unwrap());
+        assert!(matches!(
+            verify_and_parse(SECRET, &headers, body),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+        assert!(matches!(
+            verify_and_parse_with_legacy_signature(SECRET, &headers, body).unwrap(),
+            WebhookEvent::Ping(_)
+        ));
+
+        let sha256 = format!("sha256={}", sign(hmac::HMAC_SHA256, body));
+        headers.insert(SIGNATURE_256_HEADER, sha256.parse().unwrap());
+
+        match verify_and_parse(SECRET, &headers, body).unwrap() {
+            WebhookEvent::Ping(ping) => {
+                assert_eq!(ping.zen, "Keep it logically awesome.");
+                assert_eq!(ping.hook_id, 1);
+            }
+            e => panic!("expected a ping event, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn verify_and_parse_requires_event() {
+        let body = br#"{"zen": "Keep it logically awesome.", "hook_id": 1}"#;
+        let mut headers = http::HeaderMap::new();
+        let signature = format!("sha256={}", sign(hmac::HMAC_SHA256, body));
+        headers.insert(SIGNATURE_256_HEADER, signature.parse().unwrap());
+
+        assert!(matches!(
+            verify_and_parse(SECRET, &headers, body),
+            Err(ClientError::InvalidWebhookPayload(_))
+        ));
+    }
+
+    fn sign(algorithm: hmac::Algorithm, body: &[u8]) -> String {
+        let key = hmac::Key::new(algorithm, SECRET.as_bytes());
+        let tag = hmac::sign(&key, body);
+        tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+}
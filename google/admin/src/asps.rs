@@ -20,16 +20,34 @@ impl Asps {
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      */
-    pub async fn list(&self, user_key: &str) -> ClientResult<crate::types::Asps> {
+    pub async fn list(&self, fields: &str, user_key: &str) -> ClientResult<crate::types::Asps> {
+        Ok(self.list_with_response(fields, user_key).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/asps` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Asps>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/asps",
+                "/admin/directory/v1/users/{}/asps?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -48,17 +66,44 @@ impl Asps {
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      * * `code_id: i64` -- The unique ID of the ASP.
      */
-    pub async fn get(&self, user_key: &str, code_id: i64) -> ClientResult<crate::types::Asp> {
+    pub async fn get(
+        &self,
+        fields: &str,
+        user_key: &str,
+        code_id: i64,
+    ) -> ClientResult<crate::types::Asp> {
+        Ok(self
+            .get_with_response(fields, user_key, code_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/asps/{codeId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        code_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Asp>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/asps/{}",
+                "/admin/directory/v1/users/{}/asps/{}?{}",
                 crate::progenitor_support::encode_path(user_key),
                 crate::progenitor_support::encode_path(&code_id.to_string()),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -77,17 +122,37 @@ impl Asps {
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      * * `code_id: i64` -- The unique ID of the ASP to be deleted.
      */
-    pub async fn delete(&self, user_key: &str, code_id: i64) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, user_key: &str, code_id: i64) -> ClientResult<()> {
+        self.delete_with_response(fields, user_key, code_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/asps/{codeId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        code_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/asps/{}",
+                "/admin/directory/v1/users/{}/asps/{}?{}",
                 crate::progenitor_support::encode_path(user_key),
                 crate::progenitor_support::encode_path(&code_id.to_string()),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -16,10 +16,31 @@ impl Channels {
      *
      * Stops watching resources through this channel.
      */
-    pub async fn admin_stop(&self, body: &crate::types::Channel) -> ClientResult<()> {
-        let url = self.client.url("/admin/directory_v1/channels/stop", None);
+    pub async fn admin_stop(&self, fields: &str, body: &crate::types::Channel) -> ClientResult<()> {
+        self.admin_stop_with_response(fields, body).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory_v1/channels/stop` endpoint.
+     *
+     * As opposed to `admin_stop`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_stop_with_response(
+        &self,
+        fields: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/admin/directory_v1/channels/stop?{}", query_),
+            None,
+        );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
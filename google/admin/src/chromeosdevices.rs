@@ -29,6 +29,7 @@ impl Chromeosdevices {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer_id: &str,
         max_results: i64,
         order_by: crate::types::OrderBy,
@@ -38,7 +39,42 @@ impl Chromeosdevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> ClientResult<Vec<crate::types::ChromeOsDevice>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                customer_id,
+                max_results,
+                order_by,
+                org_unit_path,
+                page_token,
+                projection,
+                query,
+                sort_order,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ChromeOsDevice>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -69,9 +105,9 @@ impl Chromeosdevices {
             ),
             None,
         );
-        let resp: crate::types::ChromeOsDevices = self
+        let resp: crate::Response<crate::types::ChromeOsDevices> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -81,7 +117,7 @@ impl Chromeosdevices {
             .await?;
 
         // Return our response data.
-        Ok(resp.chromeosdevices.to_vec())
+        Ok(resp.map(|r| r.chromeosdevices.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
@@ -92,6 +128,7 @@ impl Chromeosdevices {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         customer_id: &str,
         order_by: crate::types::OrderBy,
         org_unit_path: &str,
@@ -99,7 +136,11 @@ impl Chromeosdevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> ClientResult<Vec<crate::types::ChromeOsDevice>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !order_by.to_string().is_empty() {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
@@ -176,6 +217,127 @@ impl Chromeosdevices {
         // Return our response data.
         Ok(chromeosdevices)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ChromeOsDevice>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !org_unit_path.is_empty() {
+            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ChromeOsDevices = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.chromeosdevices;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ChromeOsDevice>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            customer_id,
+            order_by,
+            org_unit_path,
+            projection,
+            query,
+            sort_order,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu` endpoint.
      *
@@ -188,11 +350,31 @@ impl Chromeosdevices {
      */
     pub async fn move_devices_ou(
         &self,
+        fields: &str,
         customer_id: &str,
         org_unit_path: &str,
         body: &crate::types::ChromeOsMoveDevicesOu,
     ) -> ClientResult<()> {
+        self.move_devices_ou_with_response(fields, customer_id, org_unit_path, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu` endpoint.
+     *
+     * As opposed to `move_devices_ou`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn move_devices_ou_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !org_unit_path.is_empty() {
             query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
         }
@@ -206,7 +388,7 @@ impl Chromeosdevices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -228,11 +410,32 @@ impl Chromeosdevices {
      */
     pub async fn get(
         &self,
+        fields: &str,
         customer_id: &str,
         device_id: &str,
         projection: crate::types::Projection,
     ) -> ClientResult<crate::types::ChromeOsDevice> {
+        Ok(self
+            .get_with_response(fields, customer_id, device_id, projection)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+    ) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !projection.to_string().is_empty() {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
@@ -247,7 +450,7 @@ impl Chromeosdevices {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -269,12 +472,34 @@ impl Chromeosdevices {
      */
     pub async fn update(
         &self,
+        fields: &str,
         customer_id: &str,
         device_id: &str,
         projection: crate::types::Projection,
         body: &crate::types::ChromeOsDevice,
     ) -> ClientResult<crate::types::ChromeOsDevice> {
+        Ok(self
+            .update_with_response(fields, customer_id, device_id, projection, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !projection.to_string().is_empty() {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
@@ -289,7 +514,7 @@ impl Chromeosdevices {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -311,12 +536,34 @@ impl Chromeosdevices {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         customer_id: &str,
         device_id: &str,
         projection: crate::types::Projection,
         body: &crate::types::ChromeOsDevice,
     ) -> ClientResult<crate::types::ChromeOsDevice> {
+        Ok(self
+            .patch_with_response(fields, customer_id, device_id, projection, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> ClientResult<crate::Response<crate::types::ChromeOsDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !projection.to_string().is_empty() {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
@@ -331,7 +578,7 @@ impl Chromeosdevices {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -352,20 +599,43 @@ impl Chromeosdevices {
      */
     pub async fn action(
         &self,
+        fields: &str,
         customer_id: &str,
         resource_id: &str,
         body: &crate::types::ChromeOsDeviceAction,
     ) -> ClientResult<()> {
+        self.action_with_response(fields, customer_id, resource_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action` endpoint.
+     *
+     * As opposed to `action`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn action_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::ChromeOsDeviceAction,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/devices/chromeos/{}/action",
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}/action?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(resource_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,21 +24,50 @@ impl Customer {
      */
     pub async fn admin_devices_chromeos_commands_get(
         &self,
+        fields: &str,
         customer_id: &str,
         device_id: &str,
         command_id: &str,
     ) -> ClientResult<crate::types::DirectoryChromeosdevicesCommand> {
+        Ok(self
+            .admin_devices_chromeos_commands_get_with_response(
+                fields,
+                customer_id,
+                device_id,
+                command_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}/commands/{commandId}` endpoint.
+     *
+     * As opposed to `admin_devices_chromeos_commands_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_devices_chromeos_commands_get_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DirectoryChromeosdevicesCommand>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/devices/chromeos/{}/commands/{}",
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}/commands/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(device_id),
                 crate::progenitor_support::encode_path(command_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,20 +88,50 @@ impl Customer {
      */
     pub async fn admin_devices_chromeos_issue_command(
         &self,
+        fields: &str,
         customer_id: &str,
         device_id: &str,
         body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
     ) -> ClientResult<crate::types::DirectoryChromeosdevicesIssueCommandResponse> {
+        Ok(self
+            .admin_devices_chromeos_issue_command_with_response(
+                fields,
+                customer_id,
+                device_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}:issueCommand` endpoint.
+     *
+     * As opposed to `admin_devices_chromeos_issue_command`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_devices_chromeos_issue_command_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        device_id: &str,
+        body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
+    ) -> ClientResult<crate::Response<crate::types::DirectoryChromeosdevicesIssueCommandResponse>>
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/devices/chromeos/{}/issueCommand",
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}:issueCommand?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(device_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -20,16 +20,38 @@ impl Customers {
      *
      * * `customer_key: &str` -- Id of the customer to be retrieved.
      */
-    pub async fn get(&self, customer_key: &str) -> ClientResult<crate::types::Customer> {
+    pub async fn get(
+        &self,
+        fields: &str,
+        customer_key: &str,
+    ) -> ClientResult<crate::types::Customer> {
+        Ok(self.get_with_response(fields, customer_key).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customers/{}",
+                "/admin/directory/v1/customers/{}?{}",
                 crate::progenitor_support::encode_path(customer_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,18 +71,41 @@ impl Customers {
      */
     pub async fn update(
         &self,
+        fields: &str,
         customer_key: &str,
         body: &crate::types::Customer,
     ) -> ClientResult<crate::types::Customer> {
+        Ok(self
+            .update_with_response(fields, customer_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customers/{}",
+                "/admin/directory/v1/customers/{}?{}",
                 crate::progenitor_support::encode_path(customer_key),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -80,18 +125,41 @@ impl Customers {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         customer_key: &str,
         body: &crate::types::Customer,
     ) -> ClientResult<crate::types::Customer> {
+        Ok(self
+            .patch_with_response(fields, customer_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customers/{}",
+                "/admin/directory/v1/customers/{}?{}",
                 crate::progenitor_support::encode_path(customer_key),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -111,17 +179,39 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_get(
         &self,
+        fields: &str,
         name: &str,
     ) -> ClientResult<crate::types::Printer> {
+        Ok(self
+            .admin_chrome_printers_get_with_response(fields, name)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_get_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Printer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/{}",
+                "/admin/directory/v1/{}?{}",
                 crate::progenitor_support::encode_path(name),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -141,17 +231,39 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_delete(
         &self,
+        fields: &str,
         name: &str,
     ) -> ClientResult<crate::types::Empty> {
+        Ok(self
+            .admin_chrome_printers_delete_with_response(fields, name)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_delete_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Empty>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/{}",
+                "/admin/directory/v1/{}?{}",
                 crate::progenitor_support::encode_path(name),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -173,15 +285,37 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_patch(
         &self,
+        fields: &str,
         name: &str,
         clear_mask: &str,
         update_mask: &str,
         body: &crate::types::Printer,
     ) -> ClientResult<crate::types::Printer> {
+        Ok(self
+            .admin_chrome_printers_patch_with_response(fields, name, clear_mask, update_mask, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_patch_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+        clear_mask: &str,
+        update_mask: &str,
+        body: &crate::types::Printer,
+    ) -> ClientResult<crate::Response<crate::types::Printer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !clear_mask.is_empty() {
             query_args.push(("clearMask".to_string(), clear_mask.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !update_mask.is_empty() {
             query_args.push(("updateMask".to_string(), update_mask.to_string()));
         }
@@ -195,7 +329,7 @@ impl Customers {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -219,13 +353,43 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_list(
         &self,
+        fields: &str,
         parent: &str,
         filter: &str,
         org_unit_id: &str,
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<Vec<crate::types::Printer>> {
+        Ok(self
+            .admin_chrome_printers_list_with_response(
+                fields,
+                parent,
+                filter,
+                org_unit_id,
+                page_size,
+                page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_list_with_response(
+        &self,
+        fields: &str,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Printer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
@@ -247,9 +411,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::ListPrintersResponse = self
+        let resp: crate::Response<crate::types::ListPrintersResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -259,7 +423,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.printers.to_vec())
+        Ok(resp.map(|r| r.printers.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
@@ -270,11 +434,16 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_list_all(
         &self,
+        fields: &str,
         parent: &str,
         filter: &str,
         org_unit_id: &str,
     ) -> ClientResult<Vec<crate::types::Printer>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
@@ -342,6 +511,108 @@ impl Customers {
         // Return our response data.
         Ok(printers)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn admin_chrome_printers_list_all_pages_stream(
+        &self,
+        fields: &str,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Printer>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !org_unit_id.is_empty() {
+            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListPrintersResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.printers;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn admin_chrome_printers_list_all_stream(
+        &self,
+        fields: &str,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Printer>> + Unpin + '_ {
+        crate::utils::page_items(self.admin_chrome_printers_list_all_pages_stream(
+            fields,
+            parent,
+            filter,
+            org_unit_id,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
@@ -353,18 +624,41 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_create(
         &self,
+        fields: &str,
         parent: &str,
         body: &crate::types::Printer,
     ) -> ClientResult<crate::types::Printer> {
+        Ok(self
+            .admin_chrome_printers_create_with_response(fields, parent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_create_with_response(
+        &self,
+        fields: &str,
+        parent: &str,
+        body: &crate::types::Printer,
+    ) -> ClientResult<crate::Response<crate::types::Printer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/{}/chrome/printers",
+                "/admin/directory/v1/{}/chrome/printers?{}",
                 crate::progenitor_support::encode_path(parent),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -384,18 +678,41 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_batch_create(
         &self,
+        fields: &str,
         parent: &str,
         body: &crate::types::BatchCreatePrintersRequest,
     ) -> ClientResult<crate::types::BatchCreatePrintersResponse> {
+        Ok(self
+            .admin_chrome_printers_batch_create_with_response(fields, parent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers:batchCreatePrinters` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_batch_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_batch_create_with_response(
+        &self,
+        fields: &str,
+        parent: &str,
+        body: &crate::types::BatchCreatePrintersRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchCreatePrintersResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/{}/chrome/printers:batchCreatePrinters",
+                "/admin/directory/v1/{}/chrome/printers:batchCreatePrinters?{}",
                 crate::progenitor_support::encode_path(parent),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -415,18 +732,41 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_batch_delete(
         &self,
+        fields: &str,
         parent: &str,
         body: &crate::types::BatchDeletePrintersRequest,
     ) -> ClientResult<crate::types::BatchDeletePrintersResponse> {
+        Ok(self
+            .admin_chrome_printers_batch_delete_with_response(fields, parent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers:batchDeletePrinters` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_batch_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_batch_delete_with_response(
+        &self,
+        fields: &str,
+        parent: &str,
+        body: &crate::types::BatchDeletePrintersRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchDeletePrintersResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/{}/chrome/printers:batchDeletePrinters",
+                "/admin/directory/v1/{}/chrome/printers:batchDeletePrinters?{}",
                 crate::progenitor_support::encode_path(parent),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -449,12 +789,36 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_list_printer_models(
         &self,
+        fields: &str,
         parent: &str,
         filter: &str,
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<Vec<crate::types::PrinterModel>> {
+        Ok(self
+            .admin_chrome_printers_list_printer_models_with_response(
+                fields, parent, filter, page_size, page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_printer_models`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn admin_chrome_printers_list_printer_models_with_response(
+        &self,
+        fields: &str,
+        parent: &str,
+        filter: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PrinterModel>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
@@ -473,9 +837,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::ListPrinterModelsResponse = self
+        let resp: crate::Response<crate::types::ListPrinterModelsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -485,7 +849,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.printer_models.to_vec())
+        Ok(resp.map(|r| r.printer_models.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
@@ -496,10 +860,15 @@ impl Customers {
      */
     pub async fn admin_chrome_printers_list_all_printer_models(
         &self,
+        fields: &str,
         parent: &str,
         filter: &str,
     ) -> ClientResult<Vec<crate::types::PrinterModel>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !filter.is_empty() {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
@@ -564,4 +933,100 @@ impl Customers {
         // Return our response data.
         Ok(printer_models)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all_printer_models`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn admin_chrome_printers_list_all_printer_models_pages_stream(
+        &self,
+        fields: &str,
+        parent: &str,
+        filter: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PrinterModel>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
+                crate::progenitor_support::encode_path(parent),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListPrinterModelsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.printer_models;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all_printer_models`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn admin_chrome_printers_list_all_printer_models_stream(
+        &self,
+        fields: &str,
+        parent: &str,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PrinterModel>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.admin_chrome_printers_list_all_printer_models_pages_stream(
+                fields, parent, filter, None,
+            ),
+        )
+    }
 }
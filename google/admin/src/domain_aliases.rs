@@ -23,10 +23,30 @@ impl DomainAliases {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer: &str,
         parent_domain_name: &str,
     ) -> ClientResult<crate::types::DomainAliases> {
+        Ok(self
+            .list_with_response(fields, customer, parent_domain_name)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        parent_domain_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAliases>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !parent_domain_name.is_empty() {
             query_args.push((
                 "parentDomainName".to_string(),
@@ -43,7 +63,7 @@ impl DomainAliases {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -63,18 +83,41 @@ impl DomainAliases {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         customer: &str,
         body: &crate::types::DomainAlias,
     ) -> ClientResult<crate::types::DomainAlias> {
+        Ok(self
+            .insert_with_response(fields, customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        body: &crate::types::DomainAlias,
+    ) -> ClientResult<crate::Response<crate::types::DomainAlias>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/domainaliases",
+                "/admin/directory/v1/customer/{}/domainaliases?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -95,19 +138,42 @@ impl DomainAliases {
      */
     pub async fn get(
         &self,
+        fields: &str,
         customer: &str,
         domain_alias_name: &str,
     ) -> ClientResult<crate::types::DomainAlias> {
+        Ok(self
+            .get_with_response(fields, customer, domain_alias_name)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAlias>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/domainaliases/{}",
+                "/admin/directory/v1/customer/{}/domainaliases/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(domain_alias_name),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -126,17 +192,43 @@ impl DomainAliases {
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
      * * `domain_alias_name: &str` -- Name of domain alias to be retrieved.
      */
-    pub async fn delete(&self, customer: &str, domain_alias_name: &str) -> ClientResult<()> {
+    pub async fn delete(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> ClientResult<()> {
+        self.delete_with_response(fields, customer, domain_alias_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/domainaliases/{}",
+                "/admin/directory/v1/customer/{}/domainaliases/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(domain_alias_name),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
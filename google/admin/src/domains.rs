@@ -20,16 +20,34 @@ impl Domains {
      *
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
      */
-    pub async fn list(&self, customer: &str) -> ClientResult<crate::types::Domains2> {
+    pub async fn list(&self, fields: &str, customer: &str) -> ClientResult<crate::types::Domains2> {
+        Ok(self.list_with_response(fields, customer).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domains` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains2>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/domains",
+                "/admin/directory/v1/customer/{}/domains?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,18 +67,41 @@ impl Domains {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         customer: &str,
         body: &crate::types::Domains,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .insert_with_response(fields, customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domains` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        body: &crate::types::Domains,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/domains",
+                "/admin/directory/v1/customer/{}/domains?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -81,19 +122,42 @@ impl Domains {
      */
     pub async fn get(
         &self,
+        fields: &str,
         customer: &str,
         domain_name: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_with_response(fields, customer, domain_name)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domains/{domainName}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/domains/{}",
+                "/admin/directory/v1/customer/{}/domains/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(domain_name),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -112,17 +176,43 @@ impl Domains {
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
      * * `domain_name: &str` -- Name of domain to be deleted.
      */
-    pub async fn delete(&self, customer: &str, domain_name: &str) -> ClientResult<()> {
+    pub async fn delete(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain_name: &str,
+    ) -> ClientResult<()> {
+        self.delete_with_response(fields, customer, domain_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domains/{domainName}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/domains/{}",
+                "/admin/directory/v1/customer/{}/domains/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(domain_name),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
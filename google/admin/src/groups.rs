@@ -29,6 +29,7 @@ impl Groups {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer: &str,
         domain: &str,
         max_results: i64,
@@ -38,6 +39,38 @@ impl Groups {
         sort_order: crate::types::SortOrder,
         user_key: &str,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                customer,
+                domain,
+                max_results,
+                order_by,
+                page_token,
+                query,
+                sort_order,
+                user_key,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        page_token: &str,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -45,6 +78,9 @@ impl Groups {
         if !domain.is_empty() {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -67,9 +103,9 @@ impl Groups {
         let url = self
             .client
             .url(&format!("/admin/directory/v1/groups?{}", query_), None);
-        let resp: crate::types::Groups = self
+        let resp: crate::Response<crate::types::Groups> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,7 +115,7 @@ impl Groups {
             .await?;
 
         // Return our response data.
-        Ok(resp.groups.to_vec())
+        Ok(resp.map(|r| r.groups.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
@@ -90,6 +126,7 @@ impl Groups {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         customer: &str,
         domain: &str,
         order_by: crate::types::DirectoryGroupsListOrderBy,
@@ -97,6 +134,7 @@ impl Groups {
         sort_order: crate::types::SortOrder,
         user_key: &str,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -104,6 +142,9 @@ impl Groups {
         if !domain.is_empty() {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !order_by.to_string().is_empty() {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
@@ -172,15 +213,149 @@ impl Groups {
         // Return our response data.
         Ok(groups)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Group>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if !user_key.is_empty() {
+            query_args.push(("userKey".to_string(), user_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/directory/v1/groups?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Groups = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.groups;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields, customer, domain, order_by, query, sort_order, user_key, None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups` endpoint.
      *
      * Creates a group.
      */
-    pub async fn insert(&self, body: &crate::types::Group) -> ClientResult<crate::types::Group> {
-        let url = self.client.url("/admin/directory/v1/groups", None);
+    pub async fn insert(
+        &self,
+        fields: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::types::Group> {
+        Ok(self.insert_with_response(fields, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/directory/v1/groups?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -198,16 +373,34 @@ impl Groups {
      *
      * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
      */
-    pub async fn get(&self, group_key: &str) -> ClientResult<crate::types::Group> {
+    pub async fn get(&self, fields: &str, group_key: &str) -> ClientResult<crate::types::Group> {
+        Ok(self.get_with_response(fields, group_key).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}",
+                "/admin/directory/v1/groups/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -227,18 +420,41 @@ impl Groups {
      */
     pub async fn update(
         &self,
+        fields: &str,
         group_key: &str,
         body: &crate::types::Group,
     ) -> ClientResult<crate::types::Group> {
+        Ok(self
+            .update_with_response(fields, group_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}",
+                "/admin/directory/v1/groups/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -256,16 +472,35 @@ impl Groups {
      *
      * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
      */
-    pub async fn delete(&self, group_key: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, group_key: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, group_key).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}",
+                "/admin/directory/v1/groups/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -285,18 +520,41 @@ impl Groups {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         group_key: &str,
         body: &crate::types::Group,
     ) -> ClientResult<crate::types::Group> {
+        Ok(self
+            .patch_with_response(fields, group_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}",
+                "/admin/directory/v1/groups/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -314,16 +572,41 @@ impl Groups {
      *
      * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
      */
-    pub async fn aliases_list(&self, group_key: &str) -> ClientResult<crate::types::Aliases> {
+    pub async fn aliases_list(
+        &self,
+        fields: &str,
+        group_key: &str,
+    ) -> ClientResult<crate::types::Aliases> {
+        Ok(self
+            .aliases_list_with_response(fields, group_key)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/aliases` endpoint.
+     *
+     * As opposed to `aliases_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn aliases_list_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Aliases>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/aliases",
+                "/admin/directory/v1/groups/{}/aliases?{}",
                 crate::progenitor_support::encode_path(group_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -343,18 +626,41 @@ impl Groups {
      */
     pub async fn aliases_insert(
         &self,
+        fields: &str,
         group_key: &str,
         body: &crate::types::Alias,
     ) -> ClientResult<crate::types::Alias> {
+        Ok(self
+            .aliases_insert_with_response(fields, group_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/aliases` endpoint.
+     *
+     * As opposed to `aliases_insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn aliases_insert_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        body: &crate::types::Alias,
+    ) -> ClientResult<crate::Response<crate::types::Alias>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/aliases",
+                "/admin/directory/v1/groups/{}/aliases?{}",
                 crate::progenitor_support::encode_path(group_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -373,17 +679,43 @@ impl Groups {
      * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
      * * `alias: &str` -- The alias to be removed.
      */
-    pub async fn aliases_delete(&self, group_key: &str, alias: &str) -> ClientResult<()> {
+    pub async fn aliases_delete(
+        &self,
+        fields: &str,
+        group_key: &str,
+        alias: &str,
+    ) -> ClientResult<()> {
+        self.aliases_delete_with_response(fields, group_key, alias)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/aliases/{alias}` endpoint.
+     *
+     * As opposed to `aliases_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn aliases_delete_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        alias: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/aliases/{}",
+                "/admin/directory/v1/groups/{}/aliases/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
                 crate::progenitor_support::encode_path(alias),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
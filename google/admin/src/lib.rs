@@ -46,7 +46,7 @@
 //! ```
 //! use gsuite_api::Client;
 //!
-//! let google admin = Client::new(
+//! let google_admin = Client::new(
 //!     String::from("client-id"),
 //!     String::from("client-secret"),
 //!     String::from("redirect-uri"),
@@ -64,10 +64,10 @@
 //!
 //! And then you can create a client from the environment.
 //!
-//! ```
+//! ```no_run
 //! use gsuite_api::Client;
 //!
-//! let google admin = Client::new_from_env(
+//! let google_admin = Client::new_from_env(
 //!     String::from("token"),
 //!     String::from("refresh-token")
 //! );
@@ -82,22 +82,42 @@
 //! use gsuite_api::Client;
 //!
 //! async fn do_call() {
-//!     let mut google admin = Client::new_from_env("", "");
+//!     let mut google_admin = Client::new_from_env("", "").await;
 //!
 //!     // Get the URL to request consent from the user.
 //!     // You can optionally pass in scopes. If none are provided, then the
 //!     // resulting URL will not have any scopes.
-//!     let user_consent_url = google admin.user_consent_url(&["some-scope".to_string()]);
+//!     let user_consent_url = google_admin.user_consent_url(&["some-scope".to_string()]);
 //!
 //!     // In your redirect URL capture the code sent and our state.
 //!     // Send it along to the request for the token.
 //!     let code = "thing-from-redirect-url";
 //!     let state = "state-from-redirect-url";
-//!     let mut access_token = google admin.get_access_token(code, state).await.unwrap();
+//!     let mut access_token = google_admin.get_access_token(code, state).await.unwrap();
 //!
 //!     // You can additionally refresh the access token with the following.
 //!     // You must have a refresh token to be able to call this function.
-//!     access_token = google admin.refresh_access_token().await.unwrap();
+//!     access_token = google_admin.refresh_access_token().await.unwrap();
+//! }
+//! ```
+//!
+//! For backend automation, you can authenticate as a service account instead.
+//! Pass in the service account's JSON key, the scopes you need and, if you
+//! are using domain-wide delegation, the user to impersonate. Access tokens
+//! are minted and refreshed for you.
+//!
+//! ```
+//! use gsuite_api::Client;
+//!
+//! async fn do_call() {
+//!     let key = std::fs::read("service-account.json").unwrap();
+//!     let google_admin = Client::new_from_service_account(
+//!         key,
+//!         &["some-scope".to_string()],
+//!         Some("user@example.com"),
+//!     )
+//!     .await
+//!     .unwrap();
 //! }
 //! ```
 //!
@@ -146,12 +166,30 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// Google responded with an error, `body` is the raw response
+    #[error("Google Error. Code: {status}, message: {}", .error.message)]
+    GoogleError {
+        status: http::StatusCode,
+        error: Box<crate::utils::GoogleApiError>,
+        body: String,
+        request_id: Option<String>,
+    },
+    /// Errors minting service account tokens
+    #[error(transparent)]
+    OAuth2Error(#[from] yup_oauth2::Error),
+    /// IO Errors
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// A response did not match the generated types, in strict mode
+    #[error("Unexpected response at `{path}`: {error}")]
+    DeserializationError { path: String, error: String },
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -166,11 +204,15 @@ pub enum ClientError {
     HttpError {
         status: http::StatusCode,
         error: String,
+        request_id: Option<String>,
     },
 }
 
 pub const FALLBACK_HOST: &str = "https://www.googleapis.com";
 
+/// The header the API sends the id of a request back in.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
@@ -197,6 +239,44 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// A response from the API: the status code, headers and raw bytes of the
+/// response, along with the parsed body.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub raw: bytes::Bytes,
+    pub body: T,
+}
+
+impl<T> Response<T> {
+    /// Maps the parsed body, keeping the rest of the response.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            status: self.status,
+            headers: self.headers,
+            raw: self.raw,
+            body: f(self.body),
+        }
+    }
+
+    /// The id the API gave this request, if it sent one. This is the id to hand
+    /// to the API's support when something goes wrong.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+    }
+}
+
+/// The id the API gave a request, kept on the errors so it can be logged.
+pub(crate) fn request_id(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
 use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
@@ -225,11 +305,21 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-
+    service_account: Option<ServiceAccount>,
     auto_refresh: bool,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,
     client: reqwest_middleware::ClientWithMiddleware,
 }
 
+/// A service account we mint access tokens for.
+#[derive(Clone)]
+struct ServiceAccount {
+    authenticator: yup_oauth2::authenticator::DefaultAuthenticator,
+    scopes: Vec<String>,
+}
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -298,9 +388,6 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         let client = reqwest::Client::builder().build();
         match client {
             Ok(c) => {
@@ -308,10 +395,9 @@ impl Client {
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
+                    .with(crate::utils::RetryMiddleware::default())
+                    // Stay under the rate limits, see `Client::set_rate_limiter`.
+                    .with(crate::utils::RateLimitMiddleware)
                     .build();
 
                 let host = RootDefaultServer::default().default_url().to_string();
@@ -327,8 +413,11 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
-
+                    service_account: None,
                     auto_refresh: false,
+                    coercions: Default::default(),
+                    retry: Default::default(),
+                    limiter: None,
                     client,
                 }
             }
@@ -442,8 +531,6 @@ impl Client {
             .expect("failed to read from google credential env var");
 
         let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
 
         match client {
             Ok(c) => {
@@ -451,10 +538,9 @@ impl Client {
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
+                    .with(crate::utils::RetryMiddleware::default())
+                    // Stay under the rate limits, see `Client::set_rate_limiter`.
+                    .with(crate::utils::RateLimitMiddleware)
                     .build();
 
                 let host = RootDefaultServer::default().default_url().to_string();
@@ -470,7 +556,11 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    service_account: None,
                     auto_refresh: false,
+                    coercions: Default::default(),
+                    retry: Default::default(),
+                    limiter: None,
                     client,
                 }
             }
@@ -478,6 +568,72 @@ impl Client {
         }
     }
 
+    /// Create a new Client struct that authenticates as a service account. It takes
+    /// the service account's JSON key, the scopes to request and, for domain-wide
+    /// delegation, an optional user to impersonate. Access tokens are minted with the
+    /// JWT bearer grant and refreshed automatically when they expire.
+    pub async fn new_from_service_account<K>(
+        key: K,
+        scopes: &[String],
+        subject: Option<&str>,
+    ) -> ClientResult<Self>
+    where
+        K: AsRef<[u8]>,
+    {
+        let key = yup_oauth2::parse_service_account_key(key)?;
+        let mut builder = yup_oauth2::ServiceAccountAuthenticator::builder(key);
+        if let Some(subject) = subject {
+            builder = builder.subject(subject);
+        }
+        let authenticator = builder.build().await?;
+
+        let mut client = Client::new("", "", "", "", "");
+        client.service_account = Some(ServiceAccount {
+            authenticator,
+            scopes: scopes.to_vec(),
+        });
+        client.auto_refresh = true;
+
+        // Get our first token, so we know when it expires.
+        client.refresh_access_token().await?;
+
+        Ok(client)
+    }
+
+    async fn refresh_service_account_token(
+        &self,
+        service_account: &ServiceAccount,
+    ) -> ClientResult<AccessToken> {
+        let token = service_account
+            .authenticator
+            .token(&service_account.scopes)
+            .await?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        let expires_in = token
+            .expiration_time()
+            .map(|t| t.unix_timestamp() - now)
+            .unwrap_or_default();
+
+        let t = AccessToken {
+            token_type: "Bearer".to_string(),
+            access_token: token.token().unwrap_or_default().to_string(),
+            expires_in,
+            scope: service_account.scopes.join(" "),
+            ..Default::default()
+        };
+
+        *self.token.write().await = InnerToken {
+            access_token: t.access_token.clone(),
+            refresh_token: String::new(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        };
+
+        Ok(t)
+    }
+
     /// Return a user consent url with an optional set of scopes.
     /// If no scopes are provided, they will not be passed in the url.
     pub fn user_consent_url(&self, scopes: &[String]) -> String {
@@ -499,6 +655,11 @@ impl Client {
     /// Refresh an access token from a refresh token. Client must have a refresh token
     /// for this to work.
     pub async fn refresh_access_token(&self) -> ClientResult<AccessToken> {
+        // Service accounts mint a new token with the JWT bearer grant instead.
+        if let Some(service_account) = &self.service_account {
+            return self.refresh_service_account_token(service_account).await;
+        }
+
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
@@ -663,23 +824,69 @@ impl Client {
         }
 
         let req = self.make_request(&method, uri, message).await?;
-        let resp = self.client.execute(req).await?;
+        let resp = self
+            .client
+            .execute_with_extensions(req, &mut self.request_extensions())
+            .await?;
 
         Ok(resp)
     }
 
+    /// Return an error with the JSON path of the offending field when a response
+    /// does not match the generated types, instead of falling back to defaults.
+    pub fn set_strict_deserialization(&mut self, enabled: bool) -> &mut Self {
+        self.coercions.strict = enabled;
+        self
+    }
+
+    /// The fields of past responses that did not match the generated types and
+    /// were replaced with their default value. The log is shared between clones
+    /// of the client and keeps the most recent 1000 fields.
+    pub fn coercions(&self) -> Vec<crate::utils::Coercion> {
+        self.coercions.list()
+    }
+
+    /// Clears the log of coerced fields.
+    pub fn clear_coercions(&self) {
+        self.coercions.clear();
+    }
+
+    /// Sets when and how often failed requests are retried.
+    pub fn set_retry_config(&mut self, config: crate::utils::RetryConfig) -> &mut Self {
+        self.retry = config;
+        self
+    }
+
+    /// Queues requests to stay under the rate limits of the API. The clones
+    /// made from the client afterwards share the same limiter.
+    pub fn set_rate_limiter(&mut self, limiter: crate::utils::RateLimiter) -> &mut Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Carries the retry config and rate limiter to the middleware.
+    fn request_extensions(&self) -> task_local_extensions::Extensions {
+        let mut extensions = self.retry.extensions();
+        if let Some(limiter) = &self.limiter {
+            extensions.insert(limiter.clone());
+        }
+        extensions
+    }
+
     async fn request<Out>(
         &self,
         method: reqwest::Method,
         uri: &str,
         message: Message,
-    ) -> ClientResult<Out>
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
         let response = self.request_raw(method, uri, message).await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -690,19 +897,30 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
-            let error = if response_body.is_empty() {
+            let error = if let Some(error) =
+                crate::utils::google_error(status, &response_body, request_id.clone())
+            {
+                error
+            } else if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -722,6 +940,7 @@ impl Client {
         let response = self.request_raw(method, uri, message).await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -740,28 +959,46 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
             Ok((link, parsed_response))
         } else {
-            let error = if response_body.is_empty() {
+            let error = if let Some(error) =
+                crate::utils::google_error(status, &response_body, request_id.clone())
+            {
+                error
+            } else if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
             Err(error)
         }
     }
 
-    /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_form_with_response(uri, form).await?.body)
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn post_form_with_response<Out>(
+        &self,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -783,9 +1020,13 @@ impl Client {
 
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -800,19 +1041,30 @@ impl Client {
                 let s = String::from_utf8(response_body.to_vec())?;
                 serde_json::from_value(serde_json::json!(&s))?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
-            let error = if response_body.is_empty() {
+            let error = if let Some(error) =
+                crate::utils::google_error(status, &response_body, request_id.clone())
+            {
+                error
+            } else if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -820,7 +1072,6 @@ impl Client {
         }
     }
 
-    /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn request_with_accept_mime<Out>(
         &self,
@@ -828,6 +1079,23 @@ impl Client {
         uri: &str,
         accept_mime_type: &str,
     ) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self
+            .request_with_accept_mime_with_response(method, uri, accept_mime_type)
+            .await?
+            .body)
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_with_accept_mime_with_response<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -835,7 +1103,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(method, url);
+        let mut req = instance.client.request(method.clone(), url);
 
         // Set the default headers.
         req = req.header(
@@ -847,9 +1115,13 @@ impl Client {
             req = req.header(http::header::AUTHORIZATION, &*auth_str);
         }
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -864,19 +1136,30 @@ impl Client {
                 let s = String::from_utf8(response_body.to_vec())?;
                 serde_json::from_value(serde_json::json!(&s))?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
-            let error = if response_body.is_empty() {
+            let error = if let Some(error) =
+                crate::utils::google_error(status, &response_body, request_id.clone())
+            {
+                error
+            } else if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -900,7 +1183,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(method, url);
+        let mut req = instance.client.request(method.clone(), url);
 
         // Set the default headers.
         req = req.header(
@@ -932,9 +1215,12 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -945,19 +1231,25 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
             Ok(parsed_response)
         } else {
-            let error = if response_body.is_empty() {
+            let error = if let Some(error) =
+                crate::utils::google_error(status, &response_body, request_id.clone())
+            {
+                error
+            } else if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -970,16 +1262,27 @@ impl Client {
         method: http::Method,
         uri: &str,
         message: Message,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let r = self.request(method, uri, message).await?;
-        Ok(r)
+        self.request(method, uri, message).await
     }
 
     #[allow(dead_code)]
     async fn get<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.get_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn get_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -1044,6 +1347,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn post_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -1052,6 +1367,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn patch<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.patch_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn patch_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -1060,6 +1387,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn put<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.put_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn put_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -1068,6 +1407,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn delete<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.delete_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn delete_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -23,19 +23,42 @@ impl Members {
      */
     pub async fn has(
         &self,
+        fields: &str,
         group_key: &str,
         member_key: &str,
     ) -> ClientResult<crate::types::MembersHasMember> {
+        Ok(self
+            .has_with_response(fields, group_key, member_key)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/hasMember/{memberKey}` endpoint.
+     *
+     * As opposed to `has`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn has_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        member_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::MembersHasMember>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/hasMember/{}",
+                "/admin/directory/v1/groups/{}/hasMember/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
                 crate::progenitor_support::encode_path(member_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,13 +82,43 @@ impl Members {
      */
     pub async fn list(
         &self,
+        fields: &str,
         group_key: &str,
         include_derived_membership: bool,
         max_results: i64,
         page_token: &str,
         roles: &str,
     ) -> ClientResult<Vec<crate::types::Member>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                group_key,
+                include_derived_membership,
+                max_results,
+                page_token,
+                roles,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        include_derived_membership: bool,
+        max_results: i64,
+        page_token: &str,
+        roles: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Member>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_derived_membership {
             query_args.push((
                 "includeDerivedMembership".to_string(),
@@ -90,9 +143,9 @@ impl Members {
             ),
             None,
         );
-        let resp: crate::types::Members = self
+        let resp: crate::Response<crate::types::Members> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -102,7 +155,7 @@ impl Members {
             .await?;
 
         // Return our response data.
-        Ok(resp.members.to_vec())
+        Ok(resp.map(|r| r.members.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
@@ -113,11 +166,16 @@ impl Members {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         group_key: &str,
         include_derived_membership: bool,
         roles: &str,
     ) -> ClientResult<Vec<crate::types::Member>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_derived_membership {
             query_args.push((
                 "includeDerivedMembership".to_string(),
@@ -188,6 +246,111 @@ impl Members {
         // Return our response data.
         Ok(members)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Member>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if include_derived_membership {
+            query_args.push((
+                "includeDerivedMembership".to_string(),
+                include_derived_membership.to_string(),
+            ));
+        }
+        if !roles.is_empty() {
+            query_args.push(("roles".to_string(), roles.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/groups/{}/members?{}",
+                crate::progenitor_support::encode_path(group_key),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Members = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.members;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Member>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            group_key,
+            include_derived_membership,
+            roles,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
      *
@@ -199,18 +362,41 @@ impl Members {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         group_key: &str,
         body: &crate::types::Member,
     ) -> ClientResult<crate::types::Member> {
+        Ok(self
+            .insert_with_response(fields, group_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        body: &crate::types::Member,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/members",
+                "/admin/directory/v1/groups/{}/members?{}",
                 crate::progenitor_support::encode_path(group_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -231,19 +417,42 @@ impl Members {
      */
     pub async fn get(
         &self,
+        fields: &str,
         group_key: &str,
         member_key: &str,
     ) -> ClientResult<crate::types::Member> {
+        Ok(self
+            .get_with_response(fields, group_key, member_key)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        member_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/members/{}",
+                "/admin/directory/v1/groups/{}/members/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
                 crate::progenitor_support::encode_path(member_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -264,20 +473,44 @@ impl Members {
      */
     pub async fn update(
         &self,
+        fields: &str,
         group_key: &str,
         member_key: &str,
         body: &crate::types::Member,
     ) -> ClientResult<crate::types::Member> {
+        Ok(self
+            .update_with_response(fields, group_key, member_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/members/{}",
+                "/admin/directory/v1/groups/{}/members/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
                 crate::progenitor_support::encode_path(member_key),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -296,17 +529,43 @@ impl Members {
      * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
      * * `member_key: &str` -- Identifies the group member in the API request. A group member can be a user or another group. The value can be the member's (group or user) primary email address, alias, or unique ID.
      */
-    pub async fn delete(&self, group_key: &str, member_key: &str) -> ClientResult<()> {
+    pub async fn delete(
+        &self,
+        fields: &str,
+        group_key: &str,
+        member_key: &str,
+    ) -> ClientResult<()> {
+        self.delete_with_response(fields, group_key, member_key)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        member_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/members/{}",
+                "/admin/directory/v1/groups/{}/members/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
                 crate::progenitor_support::encode_path(member_key),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -327,20 +586,44 @@ impl Members {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         group_key: &str,
         member_key: &str,
         body: &crate::types::Member,
     ) -> ClientResult<crate::types::Member> {
+        Ok(self
+            .patch_with_response(fields, group_key, member_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> ClientResult<crate::Response<crate::types::Member>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/groups/{}/members/{}",
+                "/admin/directory/v1/groups/{}/members/{}?{}",
                 crate::progenitor_support::encode_path(group_key),
                 crate::progenitor_support::encode_path(member_key),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
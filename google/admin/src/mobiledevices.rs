@@ -28,6 +28,7 @@ impl Mobiledevices {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer_id: &str,
         max_results: i64,
         order_by: crate::types::DirectoryMobiledevicesListOrderBy,
@@ -36,7 +37,40 @@ impl Mobiledevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> ClientResult<Vec<crate::types::MobileDevice>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                customer_id,
+                max_results,
+                order_by,
+                page_token,
+                projection,
+                query,
+                sort_order,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MobileDevice>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -64,9 +98,9 @@ impl Mobiledevices {
             ),
             None,
         );
-        let resp: crate::types::MobileDevices = self
+        let resp: crate::Response<crate::types::MobileDevices> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -76,7 +110,7 @@ impl Mobiledevices {
             .await?;
 
         // Return our response data.
-        Ok(resp.mobiledevices.to_vec())
+        Ok(resp.map(|r| r.mobiledevices.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
@@ -87,13 +121,18 @@ impl Mobiledevices {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         customer_id: &str,
         order_by: crate::types::DirectoryMobiledevicesListOrderBy,
         projection: crate::types::Projection,
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> ClientResult<Vec<crate::types::MobileDevice>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !order_by.to_string().is_empty() {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
@@ -167,6 +206,121 @@ impl Mobiledevices {
         // Return our response data.
         Ok(mobiledevices)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MobileDevice>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::MobileDevices = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.mobiledevices;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MobileDevice>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            customer_id,
+            order_by,
+            projection,
+            query,
+            sort_order,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
      *
@@ -180,11 +334,32 @@ impl Mobiledevices {
      */
     pub async fn get(
         &self,
+        fields: &str,
         customer_id: &str,
         resource_id: &str,
         projection: crate::types::Projection,
     ) -> ClientResult<crate::types::MobileDevice> {
+        Ok(self
+            .get_with_response(fields, customer_id, resource_id, projection)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        resource_id: &str,
+        projection: crate::types::Projection,
+    ) -> ClientResult<crate::Response<crate::types::MobileDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !projection.to_string().is_empty() {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
@@ -199,7 +374,7 @@ impl Mobiledevices {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -218,17 +393,43 @@ impl Mobiledevices {
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
      * * `resource_id: &str` -- The unique ID the API service uses to identify the mobile device.
      */
-    pub async fn delete(&self, customer_id: &str, resource_id: &str) -> ClientResult<()> {
+    pub async fn delete(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> ClientResult<()> {
+        self.delete_with_response(fields, customer_id, resource_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        resource_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/devices/mobile/{}",
+                "/admin/directory/v1/customer/{}/devices/mobile/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(resource_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -249,20 +450,43 @@ impl Mobiledevices {
      */
     pub async fn action(
         &self,
+        fields: &str,
         customer_id: &str,
         resource_id: &str,
         body: &crate::types::MobileDeviceAction,
     ) -> ClientResult<()> {
+        self.action_with_response(fields, customer_id, resource_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}/action` endpoint.
+     *
+     * As opposed to `action`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn action_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::MobileDeviceAction,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/devices/mobile/{}/action",
+                "/admin/directory/v1/customer/{}/devices/mobile/{}/action?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(resource_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,11 +24,32 @@ impl Orgunits {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer_id: &str,
         org_unit_path: &str,
         type_: crate::types::DirectoryOrgunitsListType,
     ) -> ClientResult<crate::types::OrgUnits> {
+        Ok(self
+            .list_with_response(fields, customer_id, org_unit_path, type_)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        org_unit_path: &str,
+        type_: crate::types::DirectoryOrgunitsListType,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnits>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !org_unit_path.is_empty() {
             query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
         }
@@ -45,7 +66,7 @@ impl Orgunits {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,18 +86,41 @@ impl Orgunits {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         customer_id: &str,
         body: &crate::types::OrgUnit,
     ) -> ClientResult<crate::types::OrgUnit> {
+        Ok(self
+            .insert_with_response(fields, customer_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        body: &crate::types::OrgUnit,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/orgunits",
+                "/admin/directory/v1/customer/{}/orgunits?{}",
                 crate::progenitor_support::encode_path(customer_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -97,19 +141,42 @@ impl Orgunits {
      */
     pub async fn get(
         &self,
+        fields: &str,
         customer_id: &str,
         org_unit_path: &str,
     ) -> ClientResult<crate::types::OrgUnit> {
+        Ok(self
+            .get_with_response(fields, customer_id, org_unit_path)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/orgunits/{}",
+                "/admin/directory/v1/customer/{}/orgunits/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(org_unit_path),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,20 +197,44 @@ impl Orgunits {
      */
     pub async fn update(
         &self,
+        fields: &str,
         customer_id: &str,
         org_unit_path: &str,
         body: &crate::types::OrgUnit,
     ) -> ClientResult<crate::types::OrgUnit> {
+        Ok(self
+            .update_with_response(fields, customer_id, org_unit_path, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/orgunits/{}",
+                "/admin/directory/v1/customer/{}/orgunits/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(org_unit_path),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -162,17 +253,43 @@ impl Orgunits {
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
      * * `org_unit_path: &str` -- The full path of the organizational unit or its unique ID.
      */
-    pub async fn delete(&self, customer_id: &str, org_unit_path: &str) -> ClientResult<()> {
+    pub async fn delete(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> ClientResult<()> {
+        self.delete_with_response(fields, customer_id, org_unit_path)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        org_unit_path: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/orgunits/{}",
+                "/admin/directory/v1/customer/{}/orgunits/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(org_unit_path),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -193,20 +310,44 @@ impl Orgunits {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         customer_id: &str,
         org_unit_path: &str,
         body: &crate::types::OrgUnit,
     ) -> ClientResult<crate::types::OrgUnit> {
+        Ok(self
+            .patch_with_response(fields, customer_id, org_unit_path, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> ClientResult<crate::Response<crate::types::OrgUnit>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/orgunits/{}",
+                "/admin/directory/v1/customer/{}/orgunits/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(org_unit_path),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
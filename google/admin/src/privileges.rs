@@ -20,16 +20,38 @@ impl Privileges {
      *
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
      */
-    pub async fn list(&self, customer: &str) -> ClientResult<crate::types::Privileges> {
+    pub async fn list(
+        &self,
+        fields: &str,
+        customer: &str,
+    ) -> ClientResult<crate::types::Privileges> {
+        Ok(self.list_with_response(fields, customer).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles/ALL/privileges` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::Privileges>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roles/ALL/privileges",
+                "/admin/directory/v1/customer/{}/roles/ALL/privileges?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -24,11 +24,32 @@ impl Resources {
      */
     pub async fn buildings_list(
         &self,
+        fields: &str,
         customer: &str,
         max_results: i64,
         page_token: &str,
     ) -> ClientResult<Vec<crate::types::Building>> {
+        Ok(self
+            .buildings_list_with_response(fields, customer, max_results, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn buildings_list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Building>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -44,9 +65,9 @@ impl Resources {
             ),
             None,
         );
-        let resp: crate::types::Buildings = self
+        let resp: crate::Response<crate::types::Buildings> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,7 +77,7 @@ impl Resources {
             .await?;
 
         // Return our response data.
-        Ok(resp.buildings.to_vec())
+        Ok(resp.map(|r| r.buildings.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
@@ -67,12 +88,20 @@ impl Resources {
      */
     pub async fn buildings_list_all(
         &self,
+        fields: &str,
         customer: &str,
     ) -> ClientResult<Vec<crate::types::Building>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/buildings",
+                "/admin/directory/v1/customer/{}/resources/buildings?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -128,6 +157,92 @@ impl Resources {
         // Return our response data.
         Ok(buildings)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn buildings_list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Building>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Buildings = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.buildings;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn buildings_list_all_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Building>> + Unpin + '_ {
+        crate::utils::page_items(self.buildings_list_all_pages_stream(fields, customer, None))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
      *
@@ -140,10 +255,28 @@ impl Resources {
      */
     pub async fn buildings_insert(
         &self,
+        fields: &str,
         customer: &str,
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> ClientResult<crate::types::Building> {
+        Ok(self
+            .buildings_insert_with_response(fields, customer, coordinates_source, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn buildings_insert_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
             query_args.push((
@@ -151,6 +284,9 @@ impl Resources {
                 coordinates_source.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -161,7 +297,7 @@ impl Resources {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -182,19 +318,42 @@ impl Resources {
      */
     pub async fn buildings_get(
         &self,
+        fields: &str,
         customer: &str,
         building_id: &str,
     ) -> ClientResult<crate::types::Building> {
+        Ok(self
+            .buildings_get_with_response(fields, customer, building_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn buildings_get_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        building_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(building_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -216,11 +375,30 @@ impl Resources {
      */
     pub async fn buildings_update(
         &self,
+        fields: &str,
         customer: &str,
         building_id: &str,
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> ClientResult<crate::types::Building> {
+        Ok(self
+            .buildings_update_with_response(fields, customer, building_id, coordinates_source, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn buildings_update_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
             query_args.push((
@@ -228,6 +406,9 @@ impl Resources {
                 coordinates_source.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -239,7 +420,7 @@ impl Resources {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -258,17 +439,43 @@ impl Resources {
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
      * * `building_id: &str` -- The id of the building to delete.
      */
-    pub async fn buildings_delete(&self, customer: &str, building_id: &str) -> ClientResult<()> {
+    pub async fn buildings_delete(
+        &self,
+        fields: &str,
+        customer: &str,
+        building_id: &str,
+    ) -> ClientResult<()> {
+        self.buildings_delete_with_response(fields, customer, building_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn buildings_delete_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        building_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(building_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -290,11 +497,30 @@ impl Resources {
      */
     pub async fn buildings_patch(
         &self,
+        fields: &str,
         customer: &str,
         building_id: &str,
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> ClientResult<crate::types::Building> {
+        Ok(self
+            .buildings_patch_with_response(fields, customer, building_id, coordinates_source, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn buildings_patch_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> ClientResult<crate::Response<crate::types::Building>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
             query_args.push((
@@ -302,6 +528,9 @@ impl Resources {
                 coordinates_source.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -313,7 +542,7 @@ impl Resources {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -337,13 +566,43 @@ impl Resources {
      */
     pub async fn calendars_list(
         &self,
+        fields: &str,
         customer: &str,
         max_results: i64,
         order_by: &str,
         page_token: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::CalendarResource>> {
+        Ok(self
+            .calendars_list_with_response(
+                fields,
+                customer,
+                max_results,
+                order_by,
+                page_token,
+                query,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn calendars_list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        max_results: i64,
+        order_by: &str,
+        page_token: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CalendarResource>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -365,9 +624,9 @@ impl Resources {
             ),
             None,
         );
-        let resp: crate::types::CalendarResources = self
+        let resp: crate::Response<crate::types::CalendarResources> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -377,7 +636,7 @@ impl Resources {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
@@ -388,11 +647,16 @@ impl Resources {
      */
     pub async fn calendars_list_all(
         &self,
+        fields: &str,
         customer: &str,
         order_by: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::CalendarResource>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !order_by.is_empty() {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
@@ -460,6 +724,106 @@ impl Resources {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn calendars_list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CalendarResource>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/calendars?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::CalendarResources = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn calendars_list_all_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CalendarResource>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.calendars_list_all_pages_stream(fields, customer, order_by, query, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
      *
@@ -471,18 +835,41 @@ impl Resources {
      */
     pub async fn calendars_insert(
         &self,
+        fields: &str,
         customer: &str,
         body: &crate::types::CalendarResource,
     ) -> ClientResult<crate::types::CalendarResource> {
+        Ok(self
+            .calendars_insert_with_response(fields, customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn calendars_insert_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        body: &crate::types::CalendarResource,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/calendars",
+                "/admin/directory/v1/customer/{}/resources/calendars?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -503,19 +890,42 @@ impl Resources {
      */
     pub async fn calendars_get(
         &self,
+        fields: &str,
         customer: &str,
         calendar_resource_id: &str,
     ) -> ClientResult<crate::types::CalendarResource> {
+        Ok(self
+            .calendars_get_with_response(fields, customer, calendar_resource_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * As opposed to `calendars_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn calendars_get_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                "/admin/directory/v1/customer/{}/resources/calendars/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(calendar_resource_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -536,20 +946,44 @@ impl Resources {
      */
     pub async fn calendars_update(
         &self,
+        fields: &str,
         customer: &str,
         calendar_resource_id: &str,
         body: &crate::types::CalendarResource,
     ) -> ClientResult<crate::types::CalendarResource> {
+        Ok(self
+            .calendars_update_with_response(fields, customer, calendar_resource_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * As opposed to `calendars_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn calendars_update_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                "/admin/directory/v1/customer/{}/resources/calendars/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(calendar_resource_id),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -570,19 +1004,41 @@ impl Resources {
      */
     pub async fn calendars_delete(
         &self,
+        fields: &str,
         customer: &str,
         calendar_resource_id: &str,
     ) -> ClientResult<()> {
+        self.calendars_delete_with_response(fields, customer, calendar_resource_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * As opposed to `calendars_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn calendars_delete_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                "/admin/directory/v1/customer/{}/resources/calendars/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(calendar_resource_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -603,20 +1059,44 @@ impl Resources {
      */
     pub async fn calendars_patch(
         &self,
+        fields: &str,
         customer: &str,
         calendar_resource_id: &str,
         body: &crate::types::CalendarResource,
     ) -> ClientResult<crate::types::CalendarResource> {
+        Ok(self
+            .calendars_patch_with_response(fields, customer, calendar_resource_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * As opposed to `calendars_patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn calendars_patch_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> ClientResult<crate::Response<crate::types::CalendarResource>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/calendars/{}",
+                "/admin/directory/v1/customer/{}/resources/calendars/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(calendar_resource_id),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -638,11 +1118,32 @@ impl Resources {
      */
     pub async fn features_list(
         &self,
+        fields: &str,
         customer: &str,
         max_results: i64,
         page_token: &str,
     ) -> ClientResult<Vec<crate::types::Feature>> {
+        Ok(self
+            .features_list_with_response(fields, customer, max_results, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn features_list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -658,9 +1159,9 @@ impl Resources {
             ),
             None,
         );
-        let resp: crate::types::Features = self
+        let resp: crate::Response<crate::types::Features> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -670,7 +1171,7 @@ impl Resources {
             .await?;
 
         // Return our response data.
-        Ok(resp.features.to_vec())
+        Ok(resp.map(|r| r.features.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
@@ -681,12 +1182,20 @@ impl Resources {
      */
     pub async fn features_list_all(
         &self,
+        fields: &str,
         customer: &str,
     ) -> ClientResult<Vec<crate::types::Feature>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/features",
+                "/admin/directory/v1/customer/{}/resources/features?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -742,6 +1251,92 @@ impl Resources {
         // Return our response data.
         Ok(features)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn features_list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Feature>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/features?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Features = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.features;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn features_list_all_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        crate::utils::page_items(self.features_list_all_pages_stream(fields, customer, None))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
      *
@@ -753,18 +1348,41 @@ impl Resources {
      */
     pub async fn features_insert(
         &self,
+        fields: &str,
         customer: &str,
         body: &crate::types::Feature,
     ) -> ClientResult<crate::types::Feature> {
+        Ok(self
+            .features_insert_with_response(fields, customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn features_insert_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        body: &crate::types::Feature,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/features",
+                "/admin/directory/v1/customer/{}/resources/features?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -785,19 +1403,42 @@ impl Resources {
      */
     pub async fn features_get(
         &self,
+        fields: &str,
         customer: &str,
         feature_key: &str,
     ) -> ClientResult<crate::types::Feature> {
+        Ok(self
+            .features_get_with_response(fields, customer, feature_key)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * As opposed to `features_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn features_get_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        feature_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/features/{}",
+                "/admin/directory/v1/customer/{}/resources/features/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(feature_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -818,20 +1459,44 @@ impl Resources {
      */
     pub async fn features_update(
         &self,
+        fields: &str,
         customer: &str,
         feature_key: &str,
         body: &crate::types::Feature,
     ) -> ClientResult<crate::types::Feature> {
+        Ok(self
+            .features_update_with_response(fields, customer, feature_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * As opposed to `features_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn features_update_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/features/{}",
+                "/admin/directory/v1/customer/{}/resources/features/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(feature_key),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -850,17 +1515,43 @@ impl Resources {
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
      * * `feature_key: &str` -- The unique ID of the feature to delete.
      */
-    pub async fn features_delete(&self, customer: &str, feature_key: &str) -> ClientResult<()> {
+    pub async fn features_delete(
+        &self,
+        fields: &str,
+        customer: &str,
+        feature_key: &str,
+    ) -> ClientResult<()> {
+        self.features_delete_with_response(fields, customer, feature_key)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * As opposed to `features_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn features_delete_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        feature_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/features/{}",
+                "/admin/directory/v1/customer/{}/resources/features/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(feature_key),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -881,20 +1572,44 @@ impl Resources {
      */
     pub async fn features_patch(
         &self,
+        fields: &str,
         customer: &str,
         feature_key: &str,
         body: &crate::types::Feature,
     ) -> ClientResult<crate::types::Feature> {
+        Ok(self
+            .features_patch_with_response(fields, customer, feature_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * As opposed to `features_patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn features_patch_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/features/{}",
+                "/admin/directory/v1/customer/{}/resources/features/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(feature_key),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -915,20 +1630,43 @@ impl Resources {
      */
     pub async fn features_rename(
         &self,
+        fields: &str,
         customer: &str,
         old_name: &str,
         body: &crate::types::FeatureRename,
     ) -> ClientResult<()> {
+        self.features_rename_with_response(fields, customer, old_name, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features/{oldName}/rename` endpoint.
+     *
+     * As opposed to `features_rename`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn features_rename_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        old_name: &str,
+        body: &crate::types::FeatureRename,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/resources/features/{}/rename",
+                "/admin/directory/v1/customer/{}/resources/features/{}/rename?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(old_name),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -26,13 +26,36 @@ impl RoleAssignments {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer: &str,
         max_results: i64,
         page_token: &str,
         role_id: &str,
         user_key: &str,
     ) -> ClientResult<Vec<crate::types::RoleAssignment>> {
+        Ok(self
+            .list_with_response(fields, customer, max_results, page_token, role_id, user_key)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RoleAssignment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -54,9 +77,9 @@ impl RoleAssignments {
             ),
             None,
         );
-        let resp: crate::types::RoleAssignments = self
+        let resp: crate::Response<crate::types::RoleAssignments> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,7 +89,7 @@ impl RoleAssignments {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
@@ -77,11 +100,16 @@ impl RoleAssignments {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         customer: &str,
         role_id: &str,
         user_key: &str,
     ) -> ClientResult<Vec<crate::types::RoleAssignment>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !role_id.is_empty() {
             query_args.push(("roleId".to_string(), role_id.to_string()));
         }
@@ -149,6 +177,105 @@ impl RoleAssignments {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_id: &str,
+        user_key: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::RoleAssignment>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !role_id.is_empty() {
+            query_args.push(("roleId".to_string(), role_id.to_string()));
+        }
+        if !user_key.is_empty() {
+            query_args.push(("userKey".to_string(), user_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/roleassignments?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::RoleAssignments = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RoleAssignment>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_pages_stream(fields, customer, role_id, user_key, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
      *
@@ -160,18 +287,41 @@ impl RoleAssignments {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         customer: &str,
         body: &crate::types::RoleAssignment,
     ) -> ClientResult<crate::types::RoleAssignment> {
+        Ok(self
+            .insert_with_response(fields, customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        body: &crate::types::RoleAssignment,
+    ) -> ClientResult<crate::Response<crate::types::RoleAssignment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roleassignments",
+                "/admin/directory/v1/customer/{}/roleassignments?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -192,19 +342,42 @@ impl RoleAssignments {
      */
     pub async fn get(
         &self,
+        fields: &str,
         customer: &str,
         role_assignment_id: &str,
     ) -> ClientResult<crate::types::RoleAssignment> {
+        Ok(self
+            .get_with_response(fields, customer, role_assignment_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RoleAssignment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roleassignments/{}",
+                "/admin/directory/v1/customer/{}/roleassignments/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(role_assignment_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -223,17 +396,43 @@ impl RoleAssignments {
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
      * * `role_assignment_id: &str` -- Immutable ID of the role assignment.
      */
-    pub async fn delete(&self, customer: &str, role_assignment_id: &str) -> ClientResult<()> {
+    pub async fn delete(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> ClientResult<()> {
+        self.delete_with_response(fields, customer, role_assignment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roleassignments/{}",
+                "/admin/directory/v1/customer/{}/roleassignments/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(role_assignment_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
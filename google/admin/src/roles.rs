@@ -24,11 +24,32 @@ impl Roles {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer: &str,
         max_results: i64,
         page_token: &str,
     ) -> ClientResult<Vec<crate::types::Role>> {
+        Ok(self
+            .list_with_response(fields, customer, max_results, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -44,9 +65,9 @@ impl Roles {
             ),
             None,
         );
-        let resp: crate::types::Roles = self
+        let resp: crate::Response<crate::types::Roles> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,7 +77,7 @@ impl Roles {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
@@ -65,11 +86,22 @@ impl Roles {
      *
      * Retrieves a paginated list of all the roles in a domain.
      */
-    pub async fn list_all(&self, customer: &str) -> ClientResult<Vec<crate::types::Role>> {
+    pub async fn list_all(
+        &self,
+        fields: &str,
+        customer: &str,
+    ) -> ClientResult<Vec<crate::types::Role>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roles",
+                "/admin/directory/v1/customer/{}/roles?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -125,6 +157,92 @@ impl Roles {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Role>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/directory/v1/customer/{}/roles?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Roles = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(fields, customer, None))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
      *
@@ -136,18 +254,41 @@ impl Roles {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         customer: &str,
         body: &crate::types::Role,
     ) -> ClientResult<crate::types::Role> {
+        Ok(self
+            .insert_with_response(fields, customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        body: &crate::types::Role,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roles",
+                "/admin/directory/v1/customer/{}/roles?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -166,17 +307,44 @@ impl Roles {
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
      * * `role_id: &str` -- Immutable ID of the role.
      */
-    pub async fn get(&self, customer: &str, role_id: &str) -> ClientResult<crate::types::Role> {
+    pub async fn get(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::types::Role> {
+        Ok(self
+            .get_with_response(fields, customer, role_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roles/{}",
+                "/admin/directory/v1/customer/{}/roles/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -197,20 +365,44 @@ impl Roles {
      */
     pub async fn update(
         &self,
+        fields: &str,
         customer: &str,
         role_id: &str,
         body: &crate::types::Role,
     ) -> ClientResult<crate::types::Role> {
+        Ok(self
+            .update_with_response(fields, customer, role_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customer}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roles/{}",
+                "/admin/directory/v1/customer/{}/roles/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -229,17 +421,37 @@ impl Roles {
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
      * * `role_id: &str` -- Immutable ID of the role.
      */
-    pub async fn delete(&self, customer: &str, role_id: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, customer: &str, role_id: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, customer, role_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roles/{}",
+                "/admin/directory/v1/customer/{}/roles/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -260,20 +472,44 @@ impl Roles {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         customer: &str,
         role_id: &str,
         body: &crate::types::Role,
     ) -> ClientResult<crate::types::Role> {
+        Ok(self
+            .patch_with_response(fields, customer, role_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/roles/{}",
+                "/admin/directory/v1/customer/{}/roles/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(role_id),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
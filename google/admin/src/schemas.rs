@@ -20,16 +20,38 @@ impl Schemas {
      *
      * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
      */
-    pub async fn list(&self, customer_id: &str) -> ClientResult<crate::types::Schemas> {
+    pub async fn list(
+        &self,
+        fields: &str,
+        customer_id: &str,
+    ) -> ClientResult<crate::types::Schemas> {
+        Ok(self.list_with_response(fields, customer_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/schemas` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Schemas>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/schemas",
+                "/admin/directory/v1/customer/{}/schemas?{}",
                 crate::progenitor_support::encode_path(customer_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,18 +71,41 @@ impl Schemas {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         customer_id: &str,
         body: &crate::types::Schema,
     ) -> ClientResult<crate::types::Schema> {
+        Ok(self
+            .insert_with_response(fields, customer_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/schemas` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        body: &crate::types::Schema,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/schemas",
+                "/admin/directory/v1/customer/{}/schemas?{}",
                 crate::progenitor_support::encode_path(customer_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -81,19 +126,42 @@ impl Schemas {
      */
     pub async fn get(
         &self,
+        fields: &str,
         customer_id: &str,
         schema_key: &str,
     ) -> ClientResult<crate::types::Schema> {
+        Ok(self
+            .get_with_response(fields, customer_id, schema_key)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/schemas/{}",
+                "/admin/directory/v1/customer/{}/schemas/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(schema_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -114,20 +182,44 @@ impl Schemas {
      */
     pub async fn update(
         &self,
+        fields: &str,
         customer_id: &str,
         schema_key: &str,
         body: &crate::types::Schema,
     ) -> ClientResult<crate::types::Schema> {
+        Ok(self
+            .update_with_response(fields, customer_id, schema_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/schemas/{}",
+                "/admin/directory/v1/customer/{}/schemas/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(schema_key),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -146,17 +238,43 @@ impl Schemas {
      * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
      * * `schema_key: &str` -- Name or immutable ID of the schema.
      */
-    pub async fn delete(&self, customer_id: &str, schema_key: &str) -> ClientResult<()> {
+    pub async fn delete(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> ClientResult<()> {
+        self.delete_with_response(fields, customer_id, schema_key)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        schema_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/schemas/{}",
+                "/admin/directory/v1/customer/{}/schemas/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(schema_key),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -177,20 +295,44 @@ impl Schemas {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         customer_id: &str,
         schema_key: &str,
         body: &crate::types::Schema,
     ) -> ClientResult<crate::types::Schema> {
+        Ok(self
+            .patch_with_response(fields, customer_id, schema_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> ClientResult<crate::Response<crate::types::Schema>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/customer/{}/schemas/{}",
+                "/admin/directory/v1/customer/{}/schemas/{}?{}",
                 crate::progenitor_support::encode_path(customer_id),
                 crate::progenitor_support::encode_path(schema_key),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
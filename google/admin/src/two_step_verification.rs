@@ -20,16 +20,35 @@ impl TwoStepVerification {
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      */
-    pub async fn turn_off(&self, user_key: &str) -> ClientResult<()> {
+    pub async fn turn_off(&self, fields: &str, user_key: &str) -> ClientResult<()> {
+        self.turn_off_with_response(fields, user_key).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/twoStepVerification/turnOff` endpoint.
+     *
+     * As opposed to `turn_off`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn turn_off_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/twoStepVerification/turnOff",
+                "/admin/directory/v1/users/{}/twoStepVerification/turnOff?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
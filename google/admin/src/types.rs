@@ -5,45 +5,30 @@ use serde::{Deserialize, Serialize};
 /// JSON template for Alias object in Directory API.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Alias {
-    /**
-     * JSON template for Alias object in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub alias: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -56,27 +41,18 @@ pub struct Alias {
 /// JSON response template to list aliases in Directory API.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Aliases {
-    /**
-     * JSON response template to list aliases in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub aliases: Vec<String>,
-    /**
-     * JSON response template to list aliases in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
-    /**
-     * JSON response template to list aliases in Directory API.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -89,7 +65,7 @@ pub struct Aliases {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Asp {
     /**
-     * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
+     * The unique ID of the ASP.
      */
     #[serde(
         default,
@@ -99,7 +75,7 @@ pub struct Asp {
     )]
     pub code_id: i64,
     /**
-     * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
+     * The time when the ASP was created. Expressed in [Unix time](https://en.wikipedia.org/wiki/Epoch_time) format.
      */
     #[serde(
         default,
@@ -109,7 +85,7 @@ pub struct Asp {
     )]
     pub creation_time: i64,
     /**
-     * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
+     * ETag of the ASP.
      */
     #[serde(
         default,
@@ -118,7 +94,7 @@ pub struct Asp {
     )]
     pub etag: String,
     /**
-     * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
+     * The type of the API resource. This is always `admin#directory#asp`.
      */
     #[serde(
         default,
@@ -127,7 +103,7 @@ pub struct Asp {
     )]
     pub kind: String,
     /**
-     * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
+     * The time when the ASP was last used. Expressed in [Unix time](https://en.wikipedia.org/wiki/Epoch_time) format.
      */
     #[serde(
         default,
@@ -137,7 +113,7 @@ pub struct Asp {
     )]
     pub last_time_used: i64,
     /**
-     * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
+     * The name of the application that the user, represented by their `userId`, entered when the ASP was created.
      */
     #[serde(
         default,
@@ -146,7 +122,7 @@ pub struct Asp {
     )]
     pub name: String,
     /**
-     * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
+     * The unique ID of the user who issued the ASP.
      */
     #[serde(
         default,
@@ -159,6 +135,9 @@ pub struct Asp {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Asps {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -174,6 +153,9 @@ pub struct Asps {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub items: Vec<Asp>,
+    /**
+     * The type of the API resource. This is always `admin#directory#aspList`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -186,6 +168,7 @@ pub struct Asps {
 * Message severity
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Severity {
     #[serde(rename = "SEVERITY_ERROR")]
     SeverityError,
@@ -197,8 +180,9 @@ pub enum Severity {
     SeverityWarning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Severity {
@@ -209,12 +193,34 @@ impl std::fmt::Display for Severity {
             Severity::SeverityUnspecified => "SEVERITY_UNSPECIFIED",
             Severity::SeverityWarning => "SEVERITY_WARNING",
             Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Severity {
+    fn from(s: String) -> Severity {
+        match s.as_str() {
+            "SEVERITY_ERROR" => Severity::SeverityError,
+            "SEVERITY_INFO" => Severity::SeverityInfo,
+            "SEVERITY_UNSPECIFIED" => Severity::SeverityUnspecified,
+            "SEVERITY_WARNING" => Severity::SeverityWarning,
+            "" => Severity::Noop,
+            _ => Severity::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Severity> for String {
+    fn from(e: Severity) -> String {
+        match e {
+            Severity::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Severity {
     fn default() -> Severity {
         Severity::Noop
@@ -230,7 +236,7 @@ impl Severity {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuxiliaryMessage {
     /**
-     * Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
+     * Human readable message in English. Example: "Given printer is invalid or no longer supported."
      */
     #[serde(
         default,
@@ -240,7 +246,7 @@ pub struct AuxiliaryMessage {
     )]
     pub auxiliary_message: String,
     /**
-     * Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
+     * Field that this message concerns.
      */
     #[serde(
         default,
@@ -250,7 +256,7 @@ pub struct AuxiliaryMessage {
     )]
     pub field_mask: String,
     /**
-     * Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
+     * Message severity
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub severity: Option<Severity>,
@@ -260,7 +266,7 @@ pub struct AuxiliaryMessage {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchCreatePrintersRequest {
     /**
-     * Request for adding new printers in batch.
+     * A list of Printers to be created. Max 50 at a time.
      */
     #[serde(
         default,
@@ -274,7 +280,7 @@ pub struct BatchCreatePrintersRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchCreatePrintersResponse {
     /**
-     * Response for adding new printers in batch.
+     * A list of create failures. Printer IDs are not populated, as printer were not created.
      */
     #[serde(
         default,
@@ -283,7 +289,7 @@ pub struct BatchCreatePrintersResponse {
     )]
     pub failures: Vec<FailureInfo>,
     /**
-     * Response for adding new printers in batch.
+     * A list of successfully created printers with their IDs populated.
      */
     #[serde(
         default,
@@ -297,7 +303,7 @@ pub struct BatchCreatePrintersResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchDeletePrintersRequest {
     /**
-     * Request for deleting existing printers in batch.
+     * A list of Printer.id that should be deleted. Max 100 at a time.
      */
     #[serde(
         default,
@@ -312,7 +318,7 @@ pub struct BatchDeletePrintersRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchDeletePrintersResponse {
     /**
-     * Response for deleting existing printers in batch.
+     * A list of update failures.
      */
     #[serde(
         default,
@@ -322,7 +328,7 @@ pub struct BatchDeletePrintersResponse {
     )]
     pub failed_printers: Vec<FailureInfo>,
     /**
-     * Response for deleting existing printers in batch.
+     * A list of Printer.id that were successfully deleted.
      */
     #[serde(
         default,
@@ -342,7 +348,7 @@ pub struct Building {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub address: Option<BuildingAddress>,
     /**
-     * Public API: Resources.buildings
+     * Unique identifier for the building. The maximum length is 100 characters.
      */
     #[serde(
         default,
@@ -352,7 +358,7 @@ pub struct Building {
     )]
     pub building_id: String,
     /**
-     * Public API: Resources.buildings
+     * The building name as seen by users in Calendar. Must be unique for the customer. For example, "NYC-CHEL". The maximum length is 100 characters.
      */
     #[serde(
         default,
@@ -367,7 +373,7 @@ pub struct Building {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub coordinates: Option<BuildingCoordinates>,
     /**
-     * Public API: Resources.buildings
+     * A brief description of the building. For example, "Chelsea Market".
      */
     #[serde(
         default,
@@ -376,7 +382,7 @@ pub struct Building {
     )]
     pub description: String,
     /**
-     * Public API: Resources.buildings
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -385,7 +391,7 @@ pub struct Building {
     )]
     pub etags: String,
     /**
-     * Public API: Resources.buildings
+     * The display names for all floors in this building. The floors are expected to be sorted in ascending order, from lowest floor to highest floor. For example, ["B2", "B1", "L", "1", "2", "2M", "3", "PH"] Must contain at least one entry.
      */
     #[serde(
         default,
@@ -395,7 +401,7 @@ pub struct Building {
     )]
     pub floor_names: Vec<String>,
     /**
-     * Public API: Resources.buildings
+     * Kind of resource this is.
      */
     #[serde(
         default,
@@ -409,7 +415,7 @@ pub struct Building {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BuildingAddress {
     /**
-     * Public API: Resources.buildings
+     * Unstructured address lines describing the lower levels of an address.
      */
     #[serde(
         default,
@@ -419,7 +425,7 @@ pub struct BuildingAddress {
     )]
     pub address_lines: Vec<String>,
     /**
-     * Public API: Resources.buildings
+     * Optional. Highest administrative subdivision which is used for postal addresses of a country or region.
      */
     #[serde(
         default,
@@ -429,7 +435,7 @@ pub struct BuildingAddress {
     )]
     pub administrative_area: String,
     /**
-     * Public API: Resources.buildings
+     * Optional. BCP-47 language code of the contents of this address (if known).
      */
     #[serde(
         default,
@@ -439,7 +445,7 @@ pub struct BuildingAddress {
     )]
     pub language_code: String,
     /**
-     * Public API: Resources.buildings
+     * Optional. Generally refers to the city/town portion of the address. Examples: US city, IT comune, UK post town. In regions of the world where localities are not well defined or do not fit into this structure well, leave locality empty and use addressLines.
      */
     #[serde(
         default,
@@ -448,7 +454,7 @@ pub struct BuildingAddress {
     )]
     pub locality: String,
     /**
-     * Public API: Resources.buildings
+     * Optional. Postal code of the address.
      */
     #[serde(
         default,
@@ -458,7 +464,7 @@ pub struct BuildingAddress {
     )]
     pub postal_code: String,
     /**
-     * Public API: Resources.buildings
+     * Required. CLDR region code of the country/region of the address.
      */
     #[serde(
         default,
@@ -468,7 +474,7 @@ pub struct BuildingAddress {
     )]
     pub region_code: String,
     /**
-     * Public API: Resources.buildings
+     * Optional. Sublocality of the address.
      */
     #[serde(
         default,
@@ -482,7 +488,7 @@ pub struct BuildingAddress {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BuildingCoordinates {
     /**
-     * Public API: Resources.buildings
+     * Latitude in decimal degrees.
      */
     #[serde(
         default,
@@ -491,7 +497,7 @@ pub struct BuildingCoordinates {
     )]
     pub latitude: f64,
     /**
-     * Public API: Resources.buildings
+     * Longitude in decimal degrees.
      */
     #[serde(
         default,
@@ -505,7 +511,7 @@ pub struct BuildingCoordinates {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Buildings {
     /**
-     * Public API: Resources.buildings
+     * The Buildings in this page of results.
      */
     #[serde(
         default,
@@ -514,7 +520,7 @@ pub struct Buildings {
     )]
     pub buildings: Vec<Building>,
     /**
-     * Public API: Resources.buildings
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -523,7 +529,7 @@ pub struct Buildings {
     )]
     pub etag: String,
     /**
-     * Public API: Resources.buildings
+     * Kind of resource this is.
      */
     #[serde(
         default,
@@ -532,7 +538,7 @@ pub struct Buildings {
     )]
     pub kind: String,
     /**
-     * Public API: Resources.buildings
+     * The continuation token, used to page through large result sets. Provide this value in a subsequent request to return the next page of results.
      */
     #[serde(
         default,
@@ -547,7 +553,7 @@ pub struct Buildings {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CalendarResource {
     /**
-     * Public API: Resources.calendars
+     * Unique ID for the building a resource is located in.
      */
     #[serde(
         default,
@@ -557,7 +563,7 @@ pub struct CalendarResource {
     )]
     pub building_id: String,
     /**
-     * Public API: Resources.calendars
+     * Capacity of a resource, number of seats in a room.
      */
     #[serde(
         default,
@@ -566,7 +572,7 @@ pub struct CalendarResource {
     )]
     pub capacity: i64,
     /**
-     * Public API: Resources.calendars
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -575,7 +581,7 @@ pub struct CalendarResource {
     )]
     pub etags: String,
     /**
-     * Public API: Resources.calendars
+     * Instances of features for the calendar resource.
      */
     #[serde(
         default,
@@ -584,7 +590,7 @@ pub struct CalendarResource {
     )]
     pub feature_instances: Option<serde_json::Value>,
     /**
-     * Public API: Resources.calendars
+     * Name of the floor a resource is located on.
      */
     #[serde(
         default,
@@ -594,7 +600,7 @@ pub struct CalendarResource {
     )]
     pub floor_name: String,
     /**
-     * Public API: Resources.calendars
+     * Name of the section within a floor a resource is located in.
      */
     #[serde(
         default,
@@ -604,7 +610,7 @@ pub struct CalendarResource {
     )]
     pub floor_section: String,
     /**
-     * Public API: Resources.calendars
+     * The read-only auto-generated name of the calendar resource which includes metadata about the resource such as building name, floor, capacity, etc. For example, "NYC-2-Training Room 1A (16)".
      */
     #[serde(
         default,
@@ -614,7 +620,7 @@ pub struct CalendarResource {
     )]
     pub generated_resource_name: String,
     /**
-     * Public API: Resources.calendars
+     * The type of the resource. For calendar resources, the value is `admin#directory#resources#calendars#CalendarResource`.
      */
     #[serde(
         default,
@@ -623,7 +629,7 @@ pub struct CalendarResource {
     )]
     pub kind: String,
     /**
-     * Public API: Resources.calendars
+     * The category of the calendar resource. Either CONFERENCE_ROOM or OTHER. Legacy data is set to CATEGORY_UNKNOWN.
      */
     #[serde(
         default,
@@ -633,7 +639,7 @@ pub struct CalendarResource {
     )]
     pub resource_category: String,
     /**
-     * Public API: Resources.calendars
+     * Description of the resource, visible only to admins.
      */
     #[serde(
         default,
@@ -643,7 +649,7 @@ pub struct CalendarResource {
     )]
     pub resource_description: String,
     /**
-     * Public API: Resources.calendars
+     * The read-only email for the calendar resource. Generated as part of creating a new calendar resource.
      */
     #[serde(
         default,
@@ -653,7 +659,7 @@ pub struct CalendarResource {
     )]
     pub resource_email: String,
     /**
-     * Public API: Resources.calendars
+     * The unique ID for the calendar resource.
      */
     #[serde(
         default,
@@ -663,7 +669,7 @@ pub struct CalendarResource {
     )]
     pub resource_id: String,
     /**
-     * Public API: Resources.calendars
+     * The name of the calendar resource. For example, "Training Room 1A".
      */
     #[serde(
         default,
@@ -673,7 +679,7 @@ pub struct CalendarResource {
     )]
     pub resource_name: String,
     /**
-     * Public API: Resources.calendars
+     * The type of the calendar resource, intended for non-room resources.
      */
     #[serde(
         default,
@@ -683,7 +689,7 @@ pub struct CalendarResource {
     )]
     pub resource_type: String,
     /**
-     * Public API: Resources.calendars
+     * Description of the resource, visible to users and admins.
      */
     #[serde(
         default,
@@ -698,7 +704,7 @@ pub struct CalendarResource {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CalendarResources {
     /**
-     * Public API: Resources.calendars
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -707,7 +713,7 @@ pub struct CalendarResources {
     )]
     pub etag: String,
     /**
-     * Public API: Resources.calendars
+     * The CalendarResources in this page of results.
      */
     #[serde(
         default,
@@ -716,7 +722,7 @@ pub struct CalendarResources {
     )]
     pub items: Vec<CalendarResource>,
     /**
-     * Public API: Resources.calendars
+     * Identifies this as a collection of CalendarResources. This is always `admin#directory#resources#calendars#calendarResourcesList`.
      */
     #[serde(
         default,
@@ -725,7 +731,7 @@ pub struct CalendarResources {
     )]
     pub kind: String,
     /**
-     * Public API: Resources.calendars
+     * The continuation token, used to page through large result sets. Provide this value in a subsequent request to return the next page of results.
      */
     #[serde(
         default,
@@ -740,7 +746,7 @@ pub struct CalendarResources {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Channel {
     /**
-     * An notification channel used to watch for resource changes.
+     * The address where notifications are delivered for this channel.
      */
     #[serde(
         default,
@@ -749,7 +755,7 @@ pub struct Channel {
     )]
     pub address: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
      */
     #[serde(
         default,
@@ -758,7 +764,7 @@ pub struct Channel {
     )]
     pub expiration: i64,
     /**
-     * An notification channel used to watch for resource changes.
+     * A UUID or similar unique string that identifies this channel.
      */
     #[serde(
         default,
@@ -767,7 +773,7 @@ pub struct Channel {
     )]
     pub id: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * Identifies this as a notification channel used to watch for changes to a resource, which is `api#channel`.
      */
     #[serde(
         default,
@@ -776,7 +782,7 @@ pub struct Channel {
     )]
     pub kind: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * Additional parameters controlling delivery channel behavior. Optional.
      */
     #[serde(
         default,
@@ -785,7 +791,7 @@ pub struct Channel {
     )]
     pub params: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * A Boolean value to indicate whether payload is wanted. Optional.
      */
     #[serde(
         default,
@@ -793,7 +799,7 @@ pub struct Channel {
     )]
     pub payload: bool,
     /**
-     * An notification channel used to watch for resource changes.
+     * An opaque ID that identifies the resource being watched on this channel. Stable across different API versions.
      */
     #[serde(
         default,
@@ -803,7 +809,7 @@ pub struct Channel {
     )]
     pub resource_id: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * A version-specific identifier for the watched resource.
      */
     #[serde(
         default,
@@ -813,7 +819,7 @@ pub struct Channel {
     )]
     pub resource_uri: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * An arbitrary string delivered to the target address with each notification delivered over this channel. Optional.
      */
     #[serde(
         default,
@@ -822,7 +828,7 @@ pub struct Channel {
     )]
     pub token: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * The type of delivery mechanism used for this channel.
      */
     #[serde(
         default,
@@ -836,7 +842,7 @@ pub struct Channel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActiveTimeRanges {
     /**
-     * The unique ID of the ASP.
+     * Duration of usage in milliseconds.
      */
     #[serde(
         default,
@@ -858,6 +864,9 @@ pub struct ActiveTimeRanges {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CpuTemperatureInfo {
+    /**
+     * CPU label
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -865,7 +874,7 @@ pub struct CpuTemperatureInfo {
     )]
     pub label: String,
     /**
-     * The unique ID of the ASP.
+     * Temperature in Celsius degrees.
      */
     #[serde(
         default,
@@ -909,7 +918,7 @@ pub struct CpuStatusReports {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeviceFiles {
     /**
-     * Date and time the report was received.
+     * Date and time the file was created
      */
     #[serde(
         default,
@@ -918,6 +927,9 @@ pub struct DeviceFiles {
         rename = "createTime"
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * File download URL
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -925,12 +937,18 @@ pub struct DeviceFiles {
         rename = "downloadUrl"
     )]
     pub download_url: String,
+    /**
+     * File name
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * File type
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -943,7 +961,7 @@ pub struct DeviceFiles {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VolumeInfo {
     /**
-     * The unique ID of the ASP.
+     * Free disk space [in bytes]
      */
     #[serde(
         default,
@@ -953,7 +971,7 @@ pub struct VolumeInfo {
     )]
     pub storage_free: i64,
     /**
-     * The unique ID of the ASP.
+     * Total disk space [in bytes]
      */
     #[serde(
         default,
@@ -962,6 +980,9 @@ pub struct VolumeInfo {
         rename = "storageTotal"
     )]
     pub storage_total: i64,
+    /**
+     * Volume id
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -989,7 +1010,7 @@ pub struct DiskVolumeReports {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LastKnownNetwork {
     /**
-     * Information for an ip address.
+     * The IP address.
      */
     #[serde(
         default,
@@ -999,7 +1020,7 @@ pub struct LastKnownNetwork {
     )]
     pub ip_address: String,
     /**
-     * Information for an ip address.
+     * The WAN IP address.
      */
     #[serde(
         default,
@@ -1014,7 +1035,7 @@ pub struct LastKnownNetwork {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecentUsers {
     /**
-     * List of recent device users, in descending order, by last login time.
+     * The user's email address. This is only present if the user type is `USER_TYPE_MANAGED`.
      */
     #[serde(
         default,
@@ -1023,7 +1044,7 @@ pub struct RecentUsers {
     )]
     pub email: String,
     /**
-     * List of recent device users, in descending order, by last login time.
+     * The type of the user.
      */
     #[serde(
         default,
@@ -1059,7 +1080,7 @@ pub struct SystemRamFreeReports {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TpmVersionInfo {
     /**
-     * Trusted Platform Module (TPM) (Read-only)
+     * TPM family. We use the TPM 2.0 style encoding, e.g.: TPM 1.2: "1.2" -> 312e3200 TPM 2.0: "2.0" -> 322e3000
      */
     #[serde(
         default,
@@ -1068,7 +1089,7 @@ pub struct TpmVersionInfo {
     )]
     pub family: String,
     /**
-     * Trusted Platform Module (TPM) (Read-only)
+     * TPM firmware version.
      */
     #[serde(
         default,
@@ -1078,7 +1099,7 @@ pub struct TpmVersionInfo {
     )]
     pub firmware_version: String,
     /**
-     * Trusted Platform Module (TPM) (Read-only)
+     * TPM manufacturer code.
      */
     #[serde(
         default,
@@ -1087,7 +1108,7 @@ pub struct TpmVersionInfo {
     )]
     pub manufacturer: String,
     /**
-     * Trusted Platform Module (TPM) (Read-only)
+     * TPM specification level. See Library Specification for TPM 2.0 and Main Specification for TPM 1.2.
      */
     #[serde(
         default,
@@ -1097,7 +1118,7 @@ pub struct TpmVersionInfo {
     )]
     pub spec_level: String,
     /**
-     * Trusted Platform Module (TPM) (Read-only)
+     * TPM model number.
      */
     #[serde(
         default,
@@ -1107,7 +1128,7 @@ pub struct TpmVersionInfo {
     )]
     pub tpm_model: String,
     /**
-     * Trusted Platform Module (TPM) (Read-only)
+     * Vendor-specific information such as Vendor ID.
      */
     #[serde(
         default,
@@ -1122,7 +1143,7 @@ pub struct TpmVersionInfo {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChromeOsDevice {
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * List of active time ranges (Read-only).
      */
     #[serde(
         default,
@@ -1132,7 +1153,7 @@ pub struct ChromeOsDevice {
     )]
     pub active_time_ranges: Vec<ActiveTimeRanges>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The asset identifier as noted by an administrator or specified during enrollment.
      */
     #[serde(
         default,
@@ -1142,7 +1163,7 @@ pub struct ChromeOsDevice {
     )]
     pub annotated_asset_id: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The address or location of the device as noted by the administrator. Maximum length is `200` characters. Empty values are allowed.
      */
     #[serde(
         default,
@@ -1152,7 +1173,7 @@ pub struct ChromeOsDevice {
     )]
     pub annotated_location: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The user of the device as noted by the administrator. Maximum length is 100 characters. Empty values are allowed.
      */
     #[serde(
         default,
@@ -1162,7 +1183,7 @@ pub struct ChromeOsDevice {
     )]
     pub annotated_user: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * (Read-only) The timestamp after which the device will stop receiving Chrome updates or support
      */
     #[serde(
         default,
@@ -1172,7 +1193,7 @@ pub struct ChromeOsDevice {
     )]
     pub auto_update_expiration: i64,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The boot mode for the device. The possible values are: \* `Verified`: The device is running a valid version of the Chrome OS. \* `Dev`: The devices's developer hardware switch is enabled. When booted, the device has a command line shell. For an example of a developer switch, see the [Chromebook developer information](https://www.chromium.org/chromium-os/developer-information-for-chrome-os-devices/samsung-series-5-chromebook#TOC-Developer-switch).
      */
     #[serde(
         default,
@@ -1182,7 +1203,7 @@ pub struct ChromeOsDevice {
     )]
     pub boot_mode: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Reports of CPU utilization and temperature (Read-only)
      */
     #[serde(
         default,
@@ -1192,7 +1213,7 @@ pub struct ChromeOsDevice {
     )]
     pub cpu_status_reports: Vec<CpuStatusReports>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * List of device files to download (Read-only)
      */
     #[serde(
         default,
@@ -1202,7 +1223,7 @@ pub struct ChromeOsDevice {
     )]
     pub device_files: Vec<DeviceFiles>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The unique ID of the Chrome device.
      */
     #[serde(
         default,
@@ -1212,7 +1233,7 @@ pub struct ChromeOsDevice {
     )]
     pub device_id: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Reports of disk space and other info about mounted/connected volumes.
      */
     #[serde(
         default,
@@ -1222,7 +1243,7 @@ pub struct ChromeOsDevice {
     )]
     pub disk_volume_reports: Vec<DiskVolumeReports>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * (Read-only) Built-in MAC address for the docking station that the device connected to. Factory sets Media access control address (MAC address) assigned for use by a dock. It is reserved specifically for MAC pass through device policy. The format is twelve (12) hexadecimal digits without any delimiter (uppercase letters). This is only relevant for some devices.
      */
     #[serde(
         default,
@@ -1232,7 +1253,7 @@ pub struct ChromeOsDevice {
     )]
     pub dock_mac_address: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -1241,7 +1262,7 @@ pub struct ChromeOsDevice {
     )]
     pub etag: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The device's MAC address on the ethernet network interface.
      */
     #[serde(
         default,
@@ -1251,7 +1272,7 @@ pub struct ChromeOsDevice {
     )]
     pub ethernet_mac_address: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * (Read-only) MAC address used by the Chromebook’s internal ethernet port, and for onboard network (ethernet) interface. The format is twelve (12) hexadecimal digits without any delimiter (uppercase letters). This is only relevant for some devices.
      */
     #[serde(
         default,
@@ -1261,7 +1282,7 @@ pub struct ChromeOsDevice {
     )]
     pub ethernet_mac_address_0: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The Chrome device's firmware version.
      */
     #[serde(
         default,
@@ -1271,7 +1292,7 @@ pub struct ChromeOsDevice {
     )]
     pub firmware_version: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The type of resource. For the Chromeosdevices resource, the value is `admin#directory#chromeosdevice`.
      */
     #[serde(
         default,
@@ -1280,7 +1301,7 @@ pub struct ChromeOsDevice {
     )]
     pub kind: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Date and time the device was last enrolled (Read-only)
      */
     #[serde(
         default,
@@ -1290,7 +1311,7 @@ pub struct ChromeOsDevice {
     )]
     pub last_enrollment_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Contains last known network (Read-only)
      */
     #[serde(
         default,
@@ -1300,7 +1321,7 @@ pub struct ChromeOsDevice {
     )]
     pub last_known_network: Vec<LastKnownNetwork>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Date and time the device was last synchronized with the policy settings in the G Suite administrator control panel (Read-only)
      */
     #[serde(
         default,
@@ -1310,7 +1331,7 @@ pub struct ChromeOsDevice {
     )]
     pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The device's wireless MAC address. If the device does not have this information, it is not included in the response.
      */
     #[serde(
         default,
@@ -1320,7 +1341,7 @@ pub struct ChromeOsDevice {
     )]
     pub mac_address: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * (Read-only) The date the device was manufactured in yyyy-mm-dd format.
      */
     #[serde(
         default,
@@ -1330,7 +1351,7 @@ pub struct ChromeOsDevice {
     )]
     pub manufacture_date: Option<chrono::NaiveDate>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The Mobile Equipment Identifier (MEID) or the International Mobile Equipment Identity (IMEI) for the 3G mobile card in a mobile device. A MEID/IMEI is typically used when adding a device to a wireless carrier's post-pay service plan. If the device does not have this information, this property is not included in the response. For more information on how to export a MEID/IMEI list, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices.html#export_meid).
      */
     #[serde(
         default,
@@ -1339,7 +1360,7 @@ pub struct ChromeOsDevice {
     )]
     pub meid: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The device's model information. If the device does not have this information, this property is not included in the response.
      */
     #[serde(
         default,
@@ -1348,7 +1369,7 @@ pub struct ChromeOsDevice {
     )]
     pub model: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Notes about this device added by the administrator. This property can be [searched](https://support.google.com/chrome/a/answer/1698333) with the [list](/admin-sdk/directory/v1/reference/chromeosdevices/list) method's `query` parameter. Maximum length is 500 characters. Empty values are allowed.
      */
     #[serde(
         default,
@@ -1357,7 +1378,7 @@ pub struct ChromeOsDevice {
     )]
     pub notes: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The device's order number. Only devices directly purchased from Google have an order number.
      */
     #[serde(
         default,
@@ -1367,7 +1388,7 @@ pub struct ChromeOsDevice {
     )]
     pub order_number: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The full parent path with the organizational unit's name associated with the device. Path names are case insensitive. If the parent organizational unit is the top-level organization, it is represented as a forward slash, `/`. This property can be [updated](/admin-sdk/directory/v1/guides/manage-chrome-devices#update_chrome_device) using the API. For more information about how to create an organizational structure for your device, see the [administration help center](https://support.google.com/a/answer/182433).
      */
     #[serde(
         default,
@@ -1377,7 +1398,7 @@ pub struct ChromeOsDevice {
     )]
     pub org_unit_path: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The Chrome device's operating system version.
      */
     #[serde(
         default,
@@ -1387,7 +1408,7 @@ pub struct ChromeOsDevice {
     )]
     pub os_version: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The Chrome device's platform version.
      */
     #[serde(
         default,
@@ -1397,7 +1418,7 @@ pub struct ChromeOsDevice {
     )]
     pub platform_version: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * List of recent device users, in descending order, by last login time.
      */
     #[serde(
         default,
@@ -1407,7 +1428,7 @@ pub struct ChromeOsDevice {
     )]
     pub recent_users: Vec<RecentUsers>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * List of screenshot files to download. Type is always "SCREENSHOT_FILE". (Read-only)
      */
     #[serde(
         default,
@@ -1417,7 +1438,7 @@ pub struct ChromeOsDevice {
     )]
     pub screenshot_files: Vec<DeviceFiles>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The Chrome device serial number entered when the device was enabled. This value is the same as the Admin console's \*Serial Number\* in the \*Chrome OS Devices\* tab.
      */
     #[serde(
         default,
@@ -1427,7 +1448,7 @@ pub struct ChromeOsDevice {
     )]
     pub serial_number: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * The status of the device.
      */
     #[serde(
         default,
@@ -1436,7 +1457,7 @@ pub struct ChromeOsDevice {
     )]
     pub status: String,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Final date the device will be supported (Read-only)
      */
     #[serde(
         default,
@@ -1446,7 +1467,7 @@ pub struct ChromeOsDevice {
     )]
     pub support_end_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Reports of amounts of available RAM memory (Read-only)
      */
     #[serde(
         default,
@@ -1456,7 +1477,7 @@ pub struct ChromeOsDevice {
     )]
     pub system_ram_free_reports: Vec<SystemRamFreeReports>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Total RAM on the device [in bytes] (Read-only)
      */
     #[serde(
         default,
@@ -1466,7 +1487,7 @@ pub struct ChromeOsDevice {
     )]
     pub system_ram_total: i64,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Trusted Platform Module (TPM) (Read-only)
      */
     #[serde(
         default,
@@ -1475,7 +1496,7 @@ pub struct ChromeOsDevice {
     )]
     pub tpm_version_info: Option<TpmVersionInfo>,
     /**
-     * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
+     * Determines if the device will auto renew its support after the support end date. This is a read-only property.
      */
     #[serde(
         default,
@@ -1487,12 +1508,18 @@ pub struct ChromeOsDevice {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChromeOsDeviceAction {
+    /**
+     * Action to be taken on the Chrome OS device.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub action: String,
+    /**
+     * Only used when the action is `deprovision`. With the `deprovision` action, this field is required. \*Note\*: The deprovision reason is audited because it might have implications on licenses for perpetual subscription customers.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1513,18 +1540,27 @@ pub struct ChromeOsDevices {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub chromeosdevices: Vec<ChromeOsDevice>,
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * Token used to access the next page of this result. To access the next page, use this token's value in the `pageToken` query string of this request.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1536,6 +1572,9 @@ pub struct ChromeOsDevices {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChromeOsMoveDevicesOu {
+    /**
+     * Chrome OS devices to be moved to OU
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -1549,7 +1588,7 @@ pub struct ChromeOsMoveDevicesOu {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreatePrinterRequest {
     /**
-     * Request for adding a new printer.
+     * Required. The name of the customer. Format: customers/{customer_id}
      */
     #[serde(
         default,
@@ -1558,7 +1597,7 @@ pub struct CreatePrinterRequest {
     )]
     pub parent: String,
     /**
-     * Request for adding a new printer.
+     * Printer configuration.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub printer: Option<Printer>,
@@ -1566,6 +1605,9 @@ pub struct CreatePrinterRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Customer {
+    /**
+     * The customer's secondary contact email address. This email address cannot be on the same domain as the `customerDomain`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1574,7 +1616,7 @@ pub struct Customer {
     )]
     pub alternate_email: String,
     /**
-     * Date and time the report was received.
+     * The customer's creation time (Readonly)
      */
     #[serde(
         default,
@@ -1583,6 +1625,9 @@ pub struct Customer {
         rename = "customerCreationTime"
     )]
     pub customer_creation_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The customer's primary domain name string. Do not include the `www` prefix when creating a new customer.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1590,30 +1635,45 @@ pub struct Customer {
         rename = "customerDomain"
     )]
     pub customer_domain: String,
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * The unique ID for the customer's Google Workspace account. (Readonly)
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * Identifies the resource as a customer. Value: `admin#directory#customer`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * The customer's ISO 639-2 language code. See the [Language Codes](/admin-sdk/directory/v1/languages) page for the list of supported codes. Valid language codes outside the supported set will be accepted by the API but may lead to unexpected behavior. The default value is `en`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub language: String,
+    /**
+     * The customer's contact phone number in [E.164](https://en.wikipedia.org/wiki/E.164) format.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1631,6 +1691,9 @@ pub struct Customer {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CustomerPostalAddress {
+    /**
+     * A customer's physical address. The address can be composed of one to three lines.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1638,6 +1701,9 @@ pub struct CustomerPostalAddress {
         rename = "addressLine1"
     )]
     pub address_line_1: String,
+    /**
+     * Address line 2 of the address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1645,6 +1711,9 @@ pub struct CustomerPostalAddress {
         rename = "addressLine2"
     )]
     pub address_line_2: String,
+    /**
+     * Address line 3 of the address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1652,6 +1721,9 @@ pub struct CustomerPostalAddress {
         rename = "addressLine3"
     )]
     pub address_line_3: String,
+    /**
+     * The customer contact's name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1659,6 +1731,9 @@ pub struct CustomerPostalAddress {
         rename = "contactName"
     )]
     pub contact_name: String,
+    /**
+     * This is a required property. For `countryCode` information see the [ISO 3166 country code elements](https://www.iso.org/iso/country_codes.htm).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1666,12 +1741,18 @@ pub struct CustomerPostalAddress {
         rename = "countryCode"
     )]
     pub country_code: String,
+    /**
+     * Name of the locality. An example of a locality value is the city of `San Francisco`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub locality: String,
+    /**
+     * The company or company division name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1679,6 +1760,9 @@ pub struct CustomerPostalAddress {
         rename = "organizationName"
     )]
     pub organization_name: String,
+    /**
+     * The postal code. A postalCode example is a postal zip code such as `10009`. This is in accordance with - http: //portablecontacts.net/draft-spec.html#address_element.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1686,6 +1770,9 @@ pub struct CustomerPostalAddress {
         rename = "postalCode"
     )]
     pub postal_code: String,
+    /**
+     * Name of the region. An example of a region value is `NY` for the state of New York.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1698,6 +1785,7 @@ pub struct CustomerPostalAddress {
 * Indicates the command state.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum State {
     #[serde(rename = "ACKED_BY_CLIENT")]
     AckedByClient,
@@ -1715,8 +1803,9 @@ pub enum State {
     StateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -1730,12 +1819,37 @@ impl std::fmt::Display for State {
             State::SentToClient => "SENT_TO_CLIENT",
             State::StateUnspecified => "STATE_UNSPECIFIED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for State {
+    fn from(s: String) -> State {
+        match s.as_str() {
+            "ACKED_BY_CLIENT" => State::AckedByClient,
+            "CANCELLED" => State::Cancelled,
+            "EXECUTED_BY_CLIENT" => State::ExecutedByClient,
+            "EXPIRED" => State::Expired,
+            "PENDING" => State::Pending,
+            "SENT_TO_CLIENT" => State::SentToClient,
+            "STATE_UNSPECIFIED" => State::StateUnspecified,
+            "" => State::Noop,
+            _ => State::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<State> for String {
+    fn from(e: State) -> String {
+        match e {
+            State::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Noop
@@ -1751,6 +1865,7 @@ impl State {
 * The type of the command.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "COMMAND_TYPE_UNSPECIFIED")]
     CommandTypeUnspecified,
@@ -1766,8 +1881,9 @@ pub enum Type {
     WipeUsers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1780,12 +1896,36 @@ impl std::fmt::Display for Type {
             Type::TakeAScreenshot => "TAKE_A_SCREENSHOT",
             Type::WipeUsers => "WIPE_USERS",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "COMMAND_TYPE_UNSPECIFIED" => Type::CommandTypeUnspecified,
+            "REBOOT" => Type::Reboot,
+            "REMOTE_POWERWASH" => Type::RemotePowerwash,
+            "SET_VOLUME" => Type::SetVolume,
+            "TAKE_A_SCREENSHOT" => Type::TakeAScreenshot,
+            "WIPE_USERS" => Type::WipeUsers,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -1801,7 +1941,7 @@ impl Type {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DirectoryChromeosdevicesCommand {
     /**
-     * Information regarding a command that was issued to a device.
+     * The time at which the command will expire. If the device doesn't execute the command within this time the command will become expired.
      */
     #[serde(
         default,
@@ -1811,7 +1951,7 @@ pub struct DirectoryChromeosdevicesCommand {
     )]
     pub command_expire_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Information regarding a command that was issued to a device.
+     * Unique ID of a device command.
      */
     #[serde(
         default,
@@ -1821,7 +1961,7 @@ pub struct DirectoryChromeosdevicesCommand {
     )]
     pub command_id: i64,
     /**
-     * Information regarding a command that was issued to a device.
+     * The result of executing a command.
      */
     #[serde(
         default,
@@ -1830,7 +1970,7 @@ pub struct DirectoryChromeosdevicesCommand {
     )]
     pub command_result: Option<DirectoryChromeosdevicesCommandResult>,
     /**
-     * Information regarding a command that was issued to a device.
+     * The timestamp when the command was issued by the admin.
      */
     #[serde(
         default,
@@ -1840,7 +1980,7 @@ pub struct DirectoryChromeosdevicesCommand {
     )]
     pub issue_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Information regarding a command that was issued to a device.
+     * The payload that the command specified, if any.
      */
     #[serde(
         default,
@@ -1849,12 +1989,12 @@ pub struct DirectoryChromeosdevicesCommand {
     )]
     pub payload: String,
     /**
-     * Information regarding a command that was issued to a device.
+     * Indicates the command state.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
     /**
-     * Information regarding a command that was issued to a device.
+     * The type of the command.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
@@ -1864,6 +2004,7 @@ pub struct DirectoryChromeosdevicesCommand {
 * The result of the command.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Result {
     #[serde(rename = "COMMAND_RESULT_TYPE_UNSPECIFIED")]
     CommandResultTypeUnspecified,
@@ -1875,8 +2016,9 @@ pub enum Result {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -1887,12 +2029,34 @@ impl std::fmt::Display for Result {
             Result::Ignored => "IGNORED",
             Result::Success => "SUCCESS",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Result {
+    fn from(s: String) -> Result {
+        match s.as_str() {
+            "COMMAND_RESULT_TYPE_UNSPECIFIED" => Result::CommandResultTypeUnspecified,
+            "FAILURE" => Result::Failure,
+            "IGNORED" => Result::Ignored,
+            "SUCCESS" => Result::Success,
+            "" => Result::Noop,
+            _ => Result::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Result> for String {
+    fn from(e: Result) -> String {
+        match e {
+            Result::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Result {
     fn default() -> Result {
         Result::Noop
@@ -1908,7 +2072,7 @@ impl Result {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DirectoryChromeosdevicesCommandResult {
     /**
-     * The result of executing a command.
+     * The error message with a short explanation as to why the command failed. Only present if the command failed.
      */
     #[serde(
         default,
@@ -1918,7 +2082,7 @@ pub struct DirectoryChromeosdevicesCommandResult {
     )]
     pub error_message: String,
     /**
-     * The result of executing a command.
+     * The time at which the command was executed or failed to execute.
      */
     #[serde(
         default,
@@ -1928,7 +2092,7 @@ pub struct DirectoryChromeosdevicesCommandResult {
     )]
     pub execute_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The result of executing a command.
+     * The result of the command.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub result: Option<Result>,
@@ -1938,7 +2102,7 @@ pub struct DirectoryChromeosdevicesCommandResult {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DirectoryChromeosdevicesIssueCommandRequest {
     /**
-     * A request for issuing a command.
+     * The type of command.
      */
     #[serde(
         default,
@@ -1947,7 +2111,7 @@ pub struct DirectoryChromeosdevicesIssueCommandRequest {
     )]
     pub command_type: Option<Type>,
     /**
-     * A request for issuing a command.
+     * The payload for the command, provide it only if command supports it. The following commands support adding payload: - SET_VOLUME: Payload is a stringified JSON object in the form: { "volume": 50 }. The volume has to be an integer in the range [0,100].
      */
     #[serde(
         default,
@@ -1961,7 +2125,7 @@ pub struct DirectoryChromeosdevicesIssueCommandRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DirectoryChromeosdevicesIssueCommandResponse {
     /**
-     * A response for issuing a command.
+     * The unique ID of the issued command, used to retrieve the command status.
      */
     #[serde(
         default,
@@ -1975,7 +2139,7 @@ pub struct DirectoryChromeosdevicesIssueCommandResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DomainAlias {
     /**
-     * The unique ID of the ASP.
+     * The creation time of the domain alias. (Read-only).
      */
     #[serde(
         default,
@@ -1984,6 +2148,9 @@ pub struct DomainAlias {
         rename = "creationTime"
     )]
     pub creation_time: i64,
+    /**
+     * The domain alias name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1991,18 +2158,27 @@ pub struct DomainAlias {
         rename = "domainAliasName"
     )]
     pub domain_alias_name: String,
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * The parent domain name that the domain alias is associated with. This can either be a primary or secondary domain name within a customer.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2011,7 +2187,7 @@ pub struct DomainAlias {
     )]
     pub parent_domain_name: String,
     /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
+     * Indicates the verification state of a domain alias. (Read-only)
      */
     #[serde(
         default,
@@ -2032,12 +2208,18 @@ pub struct DomainAliases {
         rename = "domainAliases"
     )]
     pub domain_aliases: Vec<DomainAlias>,
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2049,7 +2231,7 @@ pub struct DomainAliases {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Domains {
     /**
-     * The unique ID of the ASP.
+     * Creation time of the domain. Expressed in [Unix time](https://en.wikipedia.org/wiki/Epoch_time) format. (Read-only).
      */
     #[serde(
         default,
@@ -2059,7 +2241,7 @@ pub struct Domains {
     )]
     pub creation_time: i64,
     /**
-     * List of domain alias objects.
+     * List of domain alias objects. (Read-only)
      */
     #[serde(
         default,
@@ -2068,6 +2250,9 @@ pub struct Domains {
         rename = "domainAliases"
     )]
     pub domain_aliases: Vec<DomainAlias>,
+    /**
+     * The domain name of the customer.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2075,6 +2260,9 @@ pub struct Domains {
         rename = "domainName"
     )]
     pub domain_name: String,
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2082,7 +2270,7 @@ pub struct Domains {
     )]
     pub etag: String,
     /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
+     * Indicates if the domain is a primary domain (Read-only).
      */
     #[serde(
         default,
@@ -2090,6 +2278,9 @@ pub struct Domains {
         rename = "isPrimary"
     )]
     pub is_primary: bool,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2097,7 +2288,7 @@ pub struct Domains {
     )]
     pub kind: String,
     /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
+     * Indicates the verification state of a domain. (Read-only).
      */
     #[serde(
         default,
@@ -2117,12 +2308,18 @@ pub struct Domains2 {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub domains: Vec<Domains>,
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2139,6 +2336,7 @@ pub struct Empty {}
 * Canonical code for why the update failed to apply.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorCode {
     #[serde(rename = "ABORTED")]
     Aborted,
@@ -2172,12 +2370,11 @@ pub enum ErrorCode {
     Unavailable,
     #[serde(rename = "UNIMPLEMENTED")]
     Unimplemented,
-    #[serde(rename = "UNKNOWN")]
-    Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -2199,14 +2396,47 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::Unauthenticated => "UNAUTHENTICATED",
             ErrorCode::Unavailable => "UNAVAILABLE",
             ErrorCode::Unimplemented => "UNIMPLEMENTED",
-            ErrorCode::Unknown => "UNKNOWN",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ErrorCode {
+    fn from(s: String) -> ErrorCode {
+        match s.as_str() {
+            "ABORTED" => ErrorCode::Aborted,
+            "ALREADY_EXISTS" => ErrorCode::AlreadyExists,
+            "CANCELLED" => ErrorCode::Cancelled,
+            "DATA_LOSS" => ErrorCode::DataLoss,
+            "DEADLINE_EXCEEDED" => ErrorCode::DeadlineExceeded,
+            "FAILED_PRECONDITION" => ErrorCode::FailedPrecondition,
+            "INTERNAL" => ErrorCode::Internal,
+            "INVALID_ARGUMENT" => ErrorCode::InvalidArgument,
+            "NOT_FOUND" => ErrorCode::NotFound,
+            "OK" => ErrorCode::Ok,
+            "OUT_OF_RANGE" => ErrorCode::OutOfRange,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "RESOURCE_EXHAUSTED" => ErrorCode::ResourceExhausted,
+            "UNAUTHENTICATED" => ErrorCode::Unauthenticated,
+            "UNAVAILABLE" => ErrorCode::Unavailable,
+            "UNIMPLEMENTED" => ErrorCode::Unimplemented,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ErrorCode> for String {
+    fn from(e: ErrorCode) -> String {
+        match e {
+            ErrorCode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -2222,12 +2452,12 @@ impl ErrorCode {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FailureInfo {
     /**
-     * Info about failures
+     * Canonical code for why the update failed to apply.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "errorCode")]
     pub error_code: Option<ErrorCode>,
     /**
-     * Info about failures
+     * Failure reason message.
      */
     #[serde(
         default,
@@ -2237,12 +2467,12 @@ pub struct FailureInfo {
     )]
     pub error_message: String,
     /**
-     * Info about failures
+     * Printer configuration.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub printer: Option<Printer>,
     /**
-     * Info about failures
+     * Id of a failed printer.
      */
     #[serde(
         default,
@@ -2257,7 +2487,7 @@ pub struct FailureInfo {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Feature {
     /**
-     * JSON template for Feature object in Directory API.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -2266,7 +2496,7 @@ pub struct Feature {
     )]
     pub etags: String,
     /**
-     * JSON template for Feature object in Directory API.
+     * Kind of resource this is.
      */
     #[serde(
         default,
@@ -2275,7 +2505,7 @@ pub struct Feature {
     )]
     pub kind: String,
     /**
-     * JSON template for Feature object in Directory API.
+     * The name of the feature.
      */
     #[serde(
         default,
@@ -2289,7 +2519,7 @@ pub struct Feature {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FeatureInstance {
     /**
-     * JSON template for a feature instance.
+     * JSON template for Feature object in Directory API.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub feature: Option<Feature>,
@@ -2297,6 +2527,9 @@ pub struct FeatureInstance {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FeatureRename {
+    /**
+     * New name of the feature.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2310,7 +2543,7 @@ pub struct FeatureRename {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Features {
     /**
-     * Public API: Resources.features
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -2319,7 +2552,7 @@ pub struct Features {
     )]
     pub etag: String,
     /**
-     * Public API: Resources.features
+     * The Features in this page of results.
      */
     #[serde(
         default,
@@ -2328,7 +2561,7 @@ pub struct Features {
     )]
     pub features: Vec<Feature>,
     /**
-     * Public API: Resources.features
+     * Kind of resource this is.
      */
     #[serde(
         default,
@@ -2337,7 +2570,7 @@ pub struct Features {
     )]
     pub kind: String,
     /**
-     * Public API: Resources.features
+     * The continuation token, used to page through large result sets. Provide this value in a subsequent request to return the next page of results.
      */
     #[serde(
         default,
@@ -2352,7 +2585,7 @@ pub struct Features {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Group {
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * Value is `true` if this group was created by an administrator rather than a user.
      */
     #[serde(
         default,
@@ -2361,7 +2594,7 @@ pub struct Group {
     )]
     pub admin_created: bool,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * List of a group's alias email addresses.
      */
     #[serde(
         default,
@@ -2370,7 +2603,7 @@ pub struct Group {
     )]
     pub aliases: Vec<String>,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * An extended description to help users determine the purpose of a group. For example, you can include information about who should join the group, the types of messages to send to the group, links to FAQs about the group, or related groups. Maximum length is `4,096` characters.
      */
     #[serde(
         default,
@@ -2379,7 +2612,7 @@ pub struct Group {
     )]
     pub description: String,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * The number of users that are direct members of the group. If a group is a member (child) of this group (the parent), members of the child group are not counted in the `directMembersCount` property of the parent group.
      */
     #[serde(
         default,
@@ -2389,7 +2622,7 @@ pub struct Group {
     )]
     pub direct_members_count: i64,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * The group's email address. If your account has multiple domains, select the appropriate domain for the email address. The `email` must be unique. This property is required when creating a group. Group email addresses are subject to the same character usage rules as usernames, see the [help center](https://support.google.com/a/answer/9193374) for details.
      */
     #[serde(
         default,
@@ -2398,7 +2631,7 @@ pub struct Group {
     )]
     pub email: String,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -2407,7 +2640,7 @@ pub struct Group {
     )]
     pub etag: String,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * The unique ID of a group. A group `id` can be used as a group request URI's `groupKey`.
      */
     #[serde(
         default,
@@ -2416,7 +2649,7 @@ pub struct Group {
     )]
     pub id: String,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * The type of the API resource. For Groups resources, the value is `admin#directory#group`.
      */
     #[serde(
         default,
@@ -2425,7 +2658,7 @@ pub struct Group {
     )]
     pub kind: String,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * The group's display name.
      */
     #[serde(
         default,
@@ -2434,7 +2667,7 @@ pub struct Group {
     )]
     pub name: String,
     /**
-     * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
+     * List of the group's non-editable alias email addresses that are outside of the account's primary domain or subdomains. These are functioning email addresses used by the group. This is a read-only property returned in the API's response for a group. If edited in a group's POST or PUT request, the edit is ignored by the API service.
      */
     #[serde(
         default,
@@ -2447,6 +2680,9 @@ pub struct Group {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Groups {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2462,12 +2698,18 @@ pub struct Groups {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub groups: Vec<Group>,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * Token used to access next page of this result.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2481,7 +2723,7 @@ pub struct Groups {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListPrinterModelsResponse {
     /**
-     * Response for listing allowed printer models.
+     * A token, which can be sent as `page_token` to retrieve the next page. If this field is omitted, there are no subsequent pages.
      */
     #[serde(
         default,
@@ -2491,7 +2733,7 @@ pub struct ListPrinterModelsResponse {
     )]
     pub next_page_token: String,
     /**
-     * Response for listing allowed printer models.
+     * Printer models that are currently allowed to be configured for ChromeOs. Some printers may be added or removed over time.
      */
     #[serde(
         default,
@@ -2506,7 +2748,7 @@ pub struct ListPrinterModelsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListPrintersResponse {
     /**
-     * Response for listing printers.
+     * A token, which can be sent as `page_token` to retrieve the next page. If this field is omitted, there are no subsequent pages.
      */
     #[serde(
         default,
@@ -2516,7 +2758,7 @@ pub struct ListPrintersResponse {
     )]
     pub next_page_token: String,
     /**
-     * Response for listing printers.
+     * List of printers. If `org_unit_id` was given in the request, then only printers visible for this OU will be returned. If `org_unit_id` was given in the request, then all printers will be returned.
      */
     #[serde(
         default,
@@ -2530,7 +2772,7 @@ pub struct ListPrintersResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Member {
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * Defines mail delivery preferences of member. This is only supported by create/update/get.
      */
     #[serde(
         default,
@@ -2539,7 +2781,7 @@ pub struct Member {
     )]
     pub delivery_settings: String,
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * The member's email address. A member can be a user or another group. This property is required when adding a member to a group. The `email` must be unique and cannot be an alias of another group. If the email address is changed, the API automatically reflects the email address changes.
      */
     #[serde(
         default,
@@ -2548,7 +2790,7 @@ pub struct Member {
     )]
     pub email: String,
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -2557,7 +2799,7 @@ pub struct Member {
     )]
     pub etag: String,
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * The unique ID of the group member. A member `id` can be used as a member request URI's `memberKey`.
      */
     #[serde(
         default,
@@ -2566,7 +2808,7 @@ pub struct Member {
     )]
     pub id: String,
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * The type of the API resource. For Members resources, the value is `admin#directory#member`.
      */
     #[serde(
         default,
@@ -2575,7 +2817,7 @@ pub struct Member {
     )]
     pub kind: String,
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * The member's role in a group. The API returns an error for cycles in group memberships. For example, if `group1` is a member of `group2`, `group2` cannot be a member of `group1`. For more information about a member's role, see the [administration help center](https://support.google.com/a/answer/167094).
      */
     #[serde(
         default,
@@ -2584,7 +2826,7 @@ pub struct Member {
     )]
     pub role: String,
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * Status of member (Immutable)
      */
     #[serde(
         default,
@@ -2593,7 +2835,7 @@ pub struct Member {
     )]
     pub status: String,
     /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     * The type of group member.
      */
     #[serde(
         default,
@@ -2606,12 +2848,18 @@ pub struct Member {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Members {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2627,6 +2875,9 @@ pub struct Members {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub members: Vec<Member>,
+    /**
+     * Token used to access next page of this result.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2640,7 +2891,7 @@ pub struct Members {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MembersHasMember {
     /**
-     * JSON template for Has Member response in Directory API.
+     * Output only. Identifies whether the given user is a member of the group. Membership can be direct or nested.
      */
     #[serde(
         default,
@@ -2652,6 +2903,9 @@ pub struct MembersHasMember {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Applications {
+    /**
+     * The application's display name. An example is `Browser`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2659,6 +2913,9 @@ pub struct Applications {
         rename = "displayName"
     )]
     pub display_name: String,
+    /**
+     * The application's package name. An example is `com.android.browser`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2666,6 +2923,9 @@ pub struct Applications {
         rename = "packageName"
     )]
     pub package_name: String,
+    /**
+     * The list of permissions of this application. These can be either a standard Android permission or one defined by the application, and are found in an application's [Android manifest](https://developer.android.com/guide/topics/manifest/uses-permission-element.html). Examples of a Calendar application's permissions are `READ_CALENDAR`, or `MANAGE_ACCOUNTS`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2673,7 +2933,7 @@ pub struct Applications {
     )]
     pub permission: Vec<String>,
     /**
-     * The unique ID of the ASP.
+     * The application's version code. An example is `13`.
      */
     #[serde(
         default,
@@ -2682,6 +2942,9 @@ pub struct Applications {
         rename = "versionCode"
     )]
     pub version_code: i64,
+    /**
+     * The application's version name. An example is `3.2-140714`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2695,7 +2958,7 @@ pub struct Applications {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MobileDevice {
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Adb (USB debugging) enabled or disabled on device (Read-only)
      */
     #[serde(
         default,
@@ -2704,7 +2967,7 @@ pub struct MobileDevice {
     )]
     pub adb_status: bool,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The list of applications installed on an Android mobile device. It is not applicable to Google Sync and iOS devices. The list includes any Android applications that access Google Workspace data. When updating an applications list, it is important to note that updates replace the existing list. If the Android device has two existing applications and the API updates the list with five applications, the is now the updated list of five applications.
      */
     #[serde(
         default,
@@ -2713,7 +2976,7 @@ pub struct MobileDevice {
     )]
     pub applications: Vec<Applications>,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's baseband version.
      */
     #[serde(
         default,
@@ -2723,7 +2986,7 @@ pub struct MobileDevice {
     )]
     pub baseband_version: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device Bootloader version (Read-only)
      */
     #[serde(
         default,
@@ -2733,7 +2996,7 @@ pub struct MobileDevice {
     )]
     pub bootloader_version: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device Brand (Read-only)
      */
     #[serde(
         default,
@@ -2742,7 +3005,7 @@ pub struct MobileDevice {
     )]
     pub brand: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's operating system build number.
      */
     #[serde(
         default,
@@ -2752,7 +3015,7 @@ pub struct MobileDevice {
     )]
     pub build_number: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The default locale used on the device.
      */
     #[serde(
         default,
@@ -2762,7 +3025,7 @@ pub struct MobileDevice {
     )]
     pub default_language: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Developer options enabled or disabled on device (Read-only)
      */
     #[serde(
         default,
@@ -2771,7 +3034,7 @@ pub struct MobileDevice {
     )]
     pub developer_options_status: bool,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The compromised device status.
      */
     #[serde(
         default,
@@ -2781,7 +3044,7 @@ pub struct MobileDevice {
     )]
     pub device_compromised_status: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The serial number for a Google Sync mobile device. For Android and iOS devices, this is a software generated unique identifier.
      */
     #[serde(
         default,
@@ -2791,7 +3054,7 @@ pub struct MobileDevice {
     )]
     pub device_id: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * DevicePasswordStatus (Read-only)
      */
     #[serde(
         default,
@@ -2801,7 +3064,7 @@ pub struct MobileDevice {
     )]
     pub device_password_status: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * List of owner's email addresses. If your application needs the current list of user emails, use the [get](/admin-sdk/directory/v1/reference/mobiledevices/get.html) method. For additional information, see the [retrieve a user](/admin-sdk/directory/v1/guides/manage-users#get_user) method.
      */
     #[serde(
         default,
@@ -2810,7 +3073,7 @@ pub struct MobileDevice {
     )]
     pub email: Vec<String>,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device Encryption Status (Read-only)
      */
     #[serde(
         default,
@@ -2820,7 +3083,7 @@ pub struct MobileDevice {
     )]
     pub encryption_status: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -2829,7 +3092,7 @@ pub struct MobileDevice {
     )]
     pub etag: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Date and time the device was first synchronized with the policy settings in the G Suite administrator control panel (Read-only)
      */
     #[serde(
         default,
@@ -2839,7 +3102,7 @@ pub struct MobileDevice {
     )]
     pub first_sync: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device Hardware (Read-only)
      */
     #[serde(
         default,
@@ -2848,7 +3111,7 @@ pub struct MobileDevice {
     )]
     pub hardware: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The IMEI/MEID unique identifier for Android hardware. It is not applicable to Google Sync devices. When adding an Android mobile device, this is an optional property. When updating one of these devices, this is a read-only property.
      */
     #[serde(
         default,
@@ -2858,7 +3121,7 @@ pub struct MobileDevice {
     )]
     pub hardware_id: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's IMEI number.
      */
     #[serde(
         default,
@@ -2867,7 +3130,7 @@ pub struct MobileDevice {
     )]
     pub imei: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's kernel version.
      */
     #[serde(
         default,
@@ -2877,7 +3140,7 @@ pub struct MobileDevice {
     )]
     pub kernel_version: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The type of the API resource. For Mobiledevices resources, the value is `admin#directory#mobiledevice`.
      */
     #[serde(
         default,
@@ -2886,7 +3149,7 @@ pub struct MobileDevice {
     )]
     pub kind: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Date and time the device was last synchronized with the policy settings in the G Suite administrator control panel (Read-only)
      */
     #[serde(
         default,
@@ -2896,7 +3159,7 @@ pub struct MobileDevice {
     )]
     pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Boolean indicating if this account is on owner/primary profile or not.
      */
     #[serde(
         default,
@@ -2905,7 +3168,7 @@ pub struct MobileDevice {
     )]
     pub managed_account_is_on_owner_profile: bool,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device manufacturer (Read-only)
      */
     #[serde(
         default,
@@ -2914,7 +3177,7 @@ pub struct MobileDevice {
     )]
     pub manufacturer: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's MEID number.
      */
     #[serde(
         default,
@@ -2923,7 +3186,7 @@ pub struct MobileDevice {
     )]
     pub meid: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The mobile device's model name, for example Nexus S. This property can be [updated](/admin-sdk/directory/v1/reference/mobiledevices/update.html). For more information, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile=devices#update_mobile_device).
      */
     #[serde(
         default,
@@ -2932,7 +3195,7 @@ pub struct MobileDevice {
     )]
     pub model: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * List of the owner's user names. If your application needs the current list of device owner names, use the [get](/admin-sdk/directory/v1/reference/mobiledevices/get.html) method. For more information about retrieving mobile device user information, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-users#get_user).
      */
     #[serde(
         default,
@@ -2941,7 +3204,7 @@ pub struct MobileDevice {
     )]
     pub name: Vec<String>,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device mobile or network operator (if available) (Read-only)
      */
     #[serde(
         default,
@@ -2951,7 +3214,7 @@ pub struct MobileDevice {
     )]
     pub network_operator: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The mobile device's operating system, for example IOS 4.3 or Android 2.3.5. This property can be [updated](/admin-sdk/directory/v1/reference/mobiledevices/update.html). For more information, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices#update_mobile_device).
      */
     #[serde(
         default,
@@ -2960,7 +3223,7 @@ pub struct MobileDevice {
     )]
     pub os: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * List of accounts added on device (Read-only)
      */
     #[serde(
         default,
@@ -2970,7 +3233,7 @@ pub struct MobileDevice {
     )]
     pub other_accounts_info: Vec<String>,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * DMAgentPermission (Read-only)
      */
     #[serde(
         default,
@@ -2979,7 +3242,7 @@ pub struct MobileDevice {
     )]
     pub privilege: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device release version version (Read-only)
      */
     #[serde(
         default,
@@ -2989,7 +3252,7 @@ pub struct MobileDevice {
     )]
     pub release_version: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The unique ID the API service uses to identify the mobile device.
      */
     #[serde(
         default,
@@ -2999,7 +3262,7 @@ pub struct MobileDevice {
     )]
     pub resource_id: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Mobile Device Security patch level (Read-only)
      */
     #[serde(
         default,
@@ -3009,7 +3272,7 @@ pub struct MobileDevice {
     )]
     pub security_patch_level: i64,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's serial number.
      */
     #[serde(
         default,
@@ -3019,7 +3282,7 @@ pub struct MobileDevice {
     )]
     pub serial_number: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's status.
      */
     #[serde(
         default,
@@ -3028,7 +3291,7 @@ pub struct MobileDevice {
     )]
     pub status: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Work profile supported on device (Read-only)
      */
     #[serde(
         default,
@@ -3037,7 +3300,7 @@ pub struct MobileDevice {
     )]
     pub supports_work_profile: bool,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The type of mobile device.
      */
     #[serde(
         default,
@@ -3047,7 +3310,7 @@ pub struct MobileDevice {
     )]
     pub type_: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Unknown sources enabled or disabled on device (Read-only)
      */
     #[serde(
         default,
@@ -3056,7 +3319,7 @@ pub struct MobileDevice {
     )]
     pub unknown_sources_status: bool,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Gives information about the device such as `os` version. This property can be [updated](/admin-sdk/directory/v1/reference/mobiledevices/update.html). For more information, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices#update_mobile_device).
      */
     #[serde(
         default,
@@ -3066,7 +3329,7 @@ pub struct MobileDevice {
     )]
     pub user_agent: String,
     /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * The device's MAC address on Wi-Fi networks.
      */
     #[serde(
         default,
@@ -3079,6 +3342,9 @@ pub struct MobileDevice {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MobileDeviceAction {
+    /**
+     * The action to be performed on the device.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3089,12 +3355,18 @@ pub struct MobileDeviceAction {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MobileDevices {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3110,6 +3382,9 @@ pub struct MobileDevices {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub mobiledevices: Vec<MobileDevice>,
+    /**
+     * Token used to access next page of this result.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3123,7 +3398,7 @@ pub struct MobileDevices {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgUnit {
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * Determines if a sub-organizational unit can inherit the settings of the parent organization. The default value is `false`, meaning a sub-organizational unit inherits the settings of the nearest parent organizational unit. For more information on inheritance and users in an organization structure, see the [administration help center](https://support.google.com/a/answer/4352075).
      */
     #[serde(
         default,
@@ -3132,7 +3407,7 @@ pub struct OrgUnit {
     )]
     pub block_inheritance: bool,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * Description of the organizational unit.
      */
     #[serde(
         default,
@@ -3141,7 +3416,7 @@ pub struct OrgUnit {
     )]
     pub description: String,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -3150,7 +3425,7 @@ pub struct OrgUnit {
     )]
     pub etag: String,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * The type of the API resource. For Orgunits resources, the value is `admin#directory#orgUnit`.
      */
     #[serde(
         default,
@@ -3159,7 +3434,7 @@ pub struct OrgUnit {
     )]
     pub kind: String,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * The organizational unit's path name. For example, an organizational unit's name within the /corp/support/sales_support parent path is sales_support. Required.
      */
     #[serde(
         default,
@@ -3168,7 +3443,7 @@ pub struct OrgUnit {
     )]
     pub name: String,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * The unique ID of the organizational unit.
      */
     #[serde(
         default,
@@ -3178,7 +3453,7 @@ pub struct OrgUnit {
     )]
     pub org_unit_id: String,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * The full path to the organizational unit. The `orgUnitPath` is a derived property. When listed, it is derived from `parentOrgunitPath` and organizational unit's `name`. For example, for an organizational unit named 'apps' under parent organization '/engineering', the orgUnitPath is '/engineering/apps'. In order to edit an `orgUnitPath`, either update the name of the organization or the `parentOrgunitPath`. A user's organizational unit determines which Google Workspace services the user has access to. If the user is moved to a new organization, the user's access changes. For more information about organization structures, see the [administration help center](https://support.google.com/a/answer/4352075). For more information about moving a user to a different organization, see [Update a user](/admin-sdk/directory/v1/guides/manage-users.html#update_user).
      */
     #[serde(
         default,
@@ -3188,7 +3463,7 @@ pub struct OrgUnit {
     )]
     pub org_unit_path: String,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * The unique ID of the parent organizational unit. Required, unless `parentOrgUnitPath` is set.
      */
     #[serde(
         default,
@@ -3198,7 +3473,7 @@ pub struct OrgUnit {
     )]
     pub parent_org_unit_id: String,
     /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     * The organizational unit's parent path. For example, /corp/sales is the parent path for /corp/sales/sales_support organizational unit. Required, unless `parentOrgUnitId` is set.
      */
     #[serde(
         default,
@@ -3211,12 +3486,18 @@ pub struct OrgUnit {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgUnits {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * The type of the API resource. For Org Unit resources, the type is `admin#directory#orgUnits`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3239,7 +3520,7 @@ pub struct OrgUnits {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Printer {
     /**
-     * Printer configuration.
+     * Output only. Auxiliary messages about issues with the printer configuration if any.
      */
     #[serde(
         default,
@@ -3249,7 +3530,7 @@ pub struct Printer {
     )]
     pub auxiliary_messages: Vec<AuxiliaryMessage>,
     /**
-     * Printer configuration.
+     * Output only. Time when printer was created.
      */
     #[serde(
         default,
@@ -3259,7 +3540,7 @@ pub struct Printer {
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Printer configuration.
+     * Editable. Description of printer.
      */
     #[serde(
         default,
@@ -3268,7 +3549,7 @@ pub struct Printer {
     )]
     pub description: String,
     /**
-     * Printer configuration.
+     * Editable. Name of printer.
      */
     #[serde(
         default,
@@ -3278,7 +3559,7 @@ pub struct Printer {
     )]
     pub display_name: String,
     /**
-     * Printer configuration.
+     * Id of the printer. (During printer creation leave empty)
      */
     #[serde(
         default,
@@ -3287,7 +3568,7 @@ pub struct Printer {
     )]
     pub id: String,
     /**
-     * Printer configuration.
+     * Editable. Make and model of printer. e.g. Lexmark MS610de Value must be in format as seen in ListPrinterModels response.
      */
     #[serde(
         default,
@@ -3297,7 +3578,7 @@ pub struct Printer {
     )]
     pub make_and_model: String,
     /**
-     * Printer configuration.
+     * The resource name of the Printer object, in the format customers/{customer-id}/printers/{printer-id} (During printer creation leave empty)
      */
     #[serde(
         default,
@@ -3306,7 +3587,7 @@ pub struct Printer {
     )]
     pub name: String,
     /**
-     * Printer configuration.
+     * Organization Unit that owns this printer (Only can be set during Printer creation)
      */
     #[serde(
         default,
@@ -3316,7 +3597,7 @@ pub struct Printer {
     )]
     pub org_unit_id: String,
     /**
-     * Printer configuration.
+     * Editable. Printer URI.
      */
     #[serde(
         default,
@@ -3325,7 +3606,7 @@ pub struct Printer {
     )]
     pub uri: String,
     /**
-     * Printer configuration.
+     * Editable. flag to use driverless configuration or not. If it's set to be true, make_and_model can be ignored
      */
     #[serde(
         default,
@@ -3339,7 +3620,7 @@ pub struct Printer {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PrinterModel {
     /**
-     * Printer manufacturer and model
+     * Display name. eq. "Brother MFC-8840D"
      */
     #[serde(
         default,
@@ -3349,7 +3630,7 @@ pub struct PrinterModel {
     )]
     pub display_name: String,
     /**
-     * Printer manufacturer and model
+     * Make and model as represented in "make_and_model" field in Printer object. eq. "brother mfc-8840d"
      */
     #[serde(
         default,
@@ -3359,7 +3640,7 @@ pub struct PrinterModel {
     )]
     pub make_and_model: String,
     /**
-     * Printer manufacturer and model
+     * Manufacturer. eq. "Brother"
      */
     #[serde(
         default,
@@ -3381,6 +3662,9 @@ pub struct Privilege {
         rename = "childPrivileges"
     )]
     pub child_privileges: Vec<Privilege>,
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3388,7 +3672,7 @@ pub struct Privilege {
     )]
     pub etag: String,
     /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
+     * If the privilege can be restricted to an organization unit.
      */
     #[serde(
         default,
@@ -3396,12 +3680,18 @@ pub struct Privilege {
         rename = "isOuScopable"
     )]
     pub is_ou_scopable: bool,
+    /**
+     * The type of the API resource. This is always `admin#directory#privilege`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * The name of the privilege.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3409,6 +3699,9 @@ pub struct Privilege {
         rename = "privilegeName"
     )]
     pub privilege_name: String,
+    /**
+     * The obfuscated ID of the service this privilege is for. This value is returned with [`Privileges.list()`](/admin-sdk/directory/v1/reference/privileges/list).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3416,6 +3709,9 @@ pub struct Privilege {
         rename = "serviceId"
     )]
     pub service_id: String,
+    /**
+     * The name of the service this privilege is for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3427,6 +3723,9 @@ pub struct Privilege {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Privileges {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3434,7 +3733,7 @@ pub struct Privileges {
     )]
     pub etag: String,
     /**
-     * A list of child privileges. Privileges for a service form a tree. Each privilege can have a list of child privileges; this list is empty for a leaf privilege.
+     * A list of Privilege resources.
      */
     #[serde(
         default,
@@ -3442,6 +3741,9 @@ pub struct Privileges {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub items: Vec<Privilege>,
+    /**
+     * The type of the API resource. This is always `admin#directory#privileges`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3452,6 +3754,9 @@ pub struct Privileges {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RolePrivileges {
+    /**
+     * The name of the privilege.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3459,6 +3764,9 @@ pub struct RolePrivileges {
         rename = "privilegeName"
     )]
     pub privilege_name: String,
+    /**
+     * The obfuscated ID of the service this privilege is for. This value is returned with [`Privileges.list()`](/admin-sdk/directory/v1/reference/privileges/list).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3470,6 +3778,9 @@ pub struct RolePrivileges {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Role {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3477,7 +3788,7 @@ pub struct Role {
     )]
     pub etag: String,
     /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
+     * Returns `true` if the role is a super admin role.
      */
     #[serde(
         default,
@@ -3486,7 +3797,7 @@ pub struct Role {
     )]
     pub is_super_admin_role: bool,
     /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
+     * Returns `true` if this is a pre-defined system role.
      */
     #[serde(
         default,
@@ -3494,12 +3805,18 @@ pub struct Role {
         rename = "isSystemRole"
     )]
     pub is_system_role: bool,
+    /**
+     * The type of the API resource. This is always `admin#directory#role`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * A short description of the role.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3508,7 +3825,7 @@ pub struct Role {
     )]
     pub role_description: String,
     /**
-     * The unique ID of the ASP.
+     * ID of the role.
      */
     #[serde(
         default,
@@ -3517,6 +3834,9 @@ pub struct Role {
         rename = "roleId"
     )]
     pub role_id: i64,
+    /**
+     * Name of the role.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3540,7 +3860,7 @@ pub struct Role {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RoleAssignment {
     /**
-     * Defines an assignment of a role.
+     * The unique ID of the user this role is assigned to.
      */
     #[serde(
         default,
@@ -3550,7 +3870,7 @@ pub struct RoleAssignment {
     )]
     pub assigned_to: String,
     /**
-     * Defines an assignment of a role.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -3559,7 +3879,7 @@ pub struct RoleAssignment {
     )]
     pub etag: String,
     /**
-     * Defines an assignment of a role.
+     * The type of the API resource. This is always `admin#directory#roleAssignment`.
      */
     #[serde(
         default,
@@ -3568,7 +3888,7 @@ pub struct RoleAssignment {
     )]
     pub kind: String,
     /**
-     * Defines an assignment of a role.
+     * If the role is restricted to an organization unit, this contains the ID for the organization unit the exercise of this role is restricted to.
      */
     #[serde(
         default,
@@ -3578,7 +3898,7 @@ pub struct RoleAssignment {
     )]
     pub org_unit_id: String,
     /**
-     * Defines an assignment of a role.
+     * ID of this roleAssignment.
      */
     #[serde(
         default,
@@ -3588,7 +3908,7 @@ pub struct RoleAssignment {
     )]
     pub role_assignment_id: i64,
     /**
-     * Defines an assignment of a role.
+     * The ID of the role that is assigned.
      */
     #[serde(
         default,
@@ -3598,7 +3918,7 @@ pub struct RoleAssignment {
     )]
     pub role_id: i64,
     /**
-     * Defines an assignment of a role.
+     * The scope in which this role is assigned.
      */
     #[serde(
         default,
@@ -3611,6 +3931,9 @@ pub struct RoleAssignment {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RoleAssignments {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3626,6 +3949,9 @@ pub struct RoleAssignments {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub items: Vec<RoleAssignment>,
+    /**
+     * The type of the API resource. This is always `admin#directory#roleAssignments`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3643,6 +3969,9 @@ pub struct RoleAssignments {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Roles {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3658,6 +3987,9 @@ pub struct Roles {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub items: Vec<Role>,
+    /**
+     * The type of the API resource. This is always `admin#directory#roles`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3677,7 +4009,7 @@ pub struct Roles {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Schema {
     /**
-     * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
+     * Display name for the schema.
      */
     #[serde(
         default,
@@ -3687,7 +4019,7 @@ pub struct Schema {
     )]
     pub display_name: String,
     /**
-     * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
+     * The ETag of the resource.
      */
     #[serde(
         default,
@@ -3696,7 +4028,7 @@ pub struct Schema {
     )]
     pub etag: String,
     /**
-     * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
+     * A list of fields in the schema.
      */
     #[serde(
         default,
@@ -3705,7 +4037,7 @@ pub struct Schema {
     )]
     pub fields: Vec<SchemaFieldSpec>,
     /**
-     * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
+     * Kind of resource this is.
      */
     #[serde(
         default,
@@ -3714,7 +4046,7 @@ pub struct Schema {
     )]
     pub kind: String,
     /**
-     * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
+     * The unique identifier of the schema (Read-only)
      */
     #[serde(
         default,
@@ -3724,7 +4056,7 @@ pub struct Schema {
     )]
     pub schema_id: String,
     /**
-     * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
+     * The schema's name.
      */
     #[serde(
         default,
@@ -3739,7 +4071,7 @@ pub struct Schema {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NumericIndexingSpec {
     /**
-     * Indexing spec for a numeric field. By default, only exact match queries will be supported for numeric fields. Setting the `numericIndexingSpec` allows range queries to be supported.
+     * Maximum value of this field. This is meant to be indicative rather than enforced. Values outside this range will still be indexed, but search may not be as performant.
      */
     #[serde(
         default,
@@ -3749,7 +4081,7 @@ pub struct NumericIndexingSpec {
     )]
     pub max_value: f64,
     /**
-     * Indexing spec for a numeric field. By default, only exact match queries will be supported for numeric fields. Setting the `numericIndexingSpec` allows range queries to be supported.
+     * Minimum value of this field. This is meant to be indicative rather than enforced. Values outside this range will still be indexed, but search may not be as performant.
      */
     #[serde(
         default,
@@ -3764,7 +4096,7 @@ pub struct NumericIndexingSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SchemaFieldSpec {
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * Display Name of the field.
      */
     #[serde(
         default,
@@ -3774,7 +4106,7 @@ pub struct SchemaFieldSpec {
     )]
     pub display_name: String,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * The ETag of the field.
      */
     #[serde(
         default,
@@ -3783,7 +4115,7 @@ pub struct SchemaFieldSpec {
     )]
     pub etag: String,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * The unique identifier of the field (Read-only)
      */
     #[serde(
         default,
@@ -3793,7 +4125,7 @@ pub struct SchemaFieldSpec {
     )]
     pub field_id: String,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * The name of the field.
      */
     #[serde(
         default,
@@ -3803,7 +4135,7 @@ pub struct SchemaFieldSpec {
     )]
     pub field_name: String,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * The type of the field.
      */
     #[serde(
         default,
@@ -3813,7 +4145,7 @@ pub struct SchemaFieldSpec {
     )]
     pub field_type: String,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * Boolean specifying whether the field is indexed or not. Default: `true`.
      */
     #[serde(
         default,
@@ -3821,7 +4153,7 @@ pub struct SchemaFieldSpec {
     )]
     pub indexed: bool,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * The kind of resource this is. For schema fields this is always `admin#directory#schema#fieldspec`.
      */
     #[serde(
         default,
@@ -3830,7 +4162,7 @@ pub struct SchemaFieldSpec {
     )]
     pub kind: String,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * A boolean specifying whether this is a multi-valued field or not. Default: `false`.
      */
     #[serde(
         default,
@@ -3839,7 +4171,7 @@ pub struct SchemaFieldSpec {
     )]
     pub multi_valued: bool,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * Indexing spec for a numeric field. By default, only exact match queries will be supported for numeric fields. Setting the `numericIndexingSpec` allows range queries to be supported.
      */
     #[serde(
         default,
@@ -3848,7 +4180,7 @@ pub struct SchemaFieldSpec {
     )]
     pub numeric_indexing_spec: Option<NumericIndexingSpec>,
     /**
-     * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
+     * Specifies who can view values of this field. See [Retrieve users as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin) for more information. Note: It may take up to 24 hours for changes to this field to be reflected.
      */
     #[serde(
         default,
@@ -3863,7 +4195,7 @@ pub struct SchemaFieldSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Schemas {
     /**
-     * JSON response template for List Schema operation in Directory API.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -3872,7 +4204,7 @@ pub struct Schemas {
     )]
     pub etag: String,
     /**
-     * JSON response template for List Schema operation in Directory API.
+     * Kind of resource this is.
      */
     #[serde(
         default,
@@ -3881,7 +4213,7 @@ pub struct Schemas {
     )]
     pub kind: String,
     /**
-     * JSON response template for List Schema operation in Directory API.
+     * List of UserSchema objects.
      */
     #[serde(
         default,
@@ -3895,7 +4227,7 @@ pub struct Schemas {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Token {
     /**
-     * JSON template for token resource in Directory API.
+     * Whether the application is registered with Google. The value is `true` if the application has an anonymous Client ID.
      */
     #[serde(
         default,
@@ -3903,7 +4235,7 @@ pub struct Token {
     )]
     pub anonymous: bool,
     /**
-     * JSON template for token resource in Directory API.
+     * The Client ID of the application the token is issued to.
      */
     #[serde(
         default,
@@ -3913,7 +4245,7 @@ pub struct Token {
     )]
     pub client_id: String,
     /**
-     * JSON template for token resource in Directory API.
+     * The displayable name of the application the token is issued to.
      */
     #[serde(
         default,
@@ -3923,7 +4255,7 @@ pub struct Token {
     )]
     pub display_text: String,
     /**
-     * JSON template for token resource in Directory API.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -3932,7 +4264,7 @@ pub struct Token {
     )]
     pub etag: String,
     /**
-     * JSON template for token resource in Directory API.
+     * The type of the API resource. This is always `admin#directory#token`.
      */
     #[serde(
         default,
@@ -3941,7 +4273,7 @@ pub struct Token {
     )]
     pub kind: String,
     /**
-     * JSON template for token resource in Directory API.
+     * Whether the token is issued to an installed application. The value is `true` if the application is installed to a desktop or mobile device.
      */
     #[serde(
         default,
@@ -3950,7 +4282,7 @@ pub struct Token {
     )]
     pub native_app: bool,
     /**
-     * JSON template for token resource in Directory API.
+     * A list of authorization scopes the application is granted.
      */
     #[serde(
         default,
@@ -3959,7 +4291,7 @@ pub struct Token {
     )]
     pub scopes: Vec<String>,
     /**
-     * JSON template for token resource in Directory API.
+     * The unique ID of the user that issued the token.
      */
     #[serde(
         default,
@@ -3974,7 +4306,7 @@ pub struct Token {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Tokens {
     /**
-     * JSON response template for List tokens operation in Directory API.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -3983,7 +4315,7 @@ pub struct Tokens {
     )]
     pub etag: String,
     /**
-     * JSON response template for List tokens operation in Directory API.
+     * A list of Token resources.
      */
     #[serde(
         default,
@@ -3992,7 +4324,7 @@ pub struct Tokens {
     )]
     pub items: Vec<Token>,
     /**
-     * JSON response template for List tokens operation in Directory API.
+     * The type of the API resource. This is always `admin#directory#tokenList`.
      */
     #[serde(
         default,
@@ -4024,9 +4356,6 @@ pub struct Ims {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub im: String,
-    /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -4051,7 +4380,7 @@ pub struct Ims {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct User {
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of the user's addresses. The maximum allowed data size for this field is 10Kb.
      */
     #[serde(
         default,
@@ -4060,7 +4389,7 @@ pub struct User {
     )]
     pub addresses: Vec<UserAddress>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. This property is `true` if the user has completed an initial login and accepted the Terms of Service agreement.
      */
     #[serde(
         default,
@@ -4069,7 +4398,7 @@ pub struct User {
     )]
     pub agreed_to_terms: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. A list of the user's alias email addresses.
      */
     #[serde(
         default,
@@ -4078,7 +4407,7 @@ pub struct User {
     )]
     pub aliases: Vec<String>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Indicates if user is archived.
      */
     #[serde(
         default,
@@ -4086,7 +4415,7 @@ pub struct User {
     )]
     pub archived: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Indicates if the user is forced to change their password at next login. This setting doesn't apply when [the user signs in via a third-party identity provider](https://support.google.com/a/answer/60224).
      */
     #[serde(
         default,
@@ -4095,7 +4424,7 @@ pub struct User {
     )]
     pub change_password_at_next_login: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * User's G Suite account creation time. (Read-only)
      */
     #[serde(
         default,
@@ -4105,13 +4434,13 @@ pub struct User {
     )]
     pub creation_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Custom fields of the user.
      */
     #[serde(rename = "customSchemas")]
     pub custom_schemas:
         std::collections::HashMap<String, std::collections::HashMap<String, serde_json::Value>>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. The customer ID to [retrieve all account users](/admin-sdk/directory/v1/guides/manage-users.html#get_all_users). You can use the alias `my_customer` to represent your account's `customerId`. As a reseller administrator, you can use the resold customer account's `customerId`. To get a `customerId`, use the account's primary domain in the `domain` parameter of a [users.list](/admin-sdk/directory/v1/reference/users/list) request.
      */
     #[serde(
         default,
@@ -4120,9 +4449,6 @@ pub struct User {
         rename = "customerId"
     )]
     pub customer_id: String,
-    /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4131,7 +4457,7 @@ pub struct User {
     )]
     pub deletion_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of the user's email addresses. The maximum allowed data size for this field is 10Kb.
      */
     #[serde(
         default,
@@ -4140,7 +4466,7 @@ pub struct User {
     )]
     pub emails: Vec<UserEmail>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. ETag of the resource.
      */
     #[serde(
         default,
@@ -4149,7 +4475,7 @@ pub struct User {
     )]
     pub etag: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of external IDs for the user, such as an employee or network ID. The maximum allowed data size for this field is 2Kb.
      */
     #[serde(
         default,
@@ -4157,13 +4483,10 @@ pub struct User {
         rename = "externalIds"
     )]
     pub external_ids: Option<serde_json::Value>,
-    /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gender: Option<UserGender>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Stores the hash format of the password property. We recommend sending the `password` property value as a base 16 bit hexadecimal-encoded hash value. Set the `hashFunction` values as either the [SHA-1](https://wikipedia.org/wiki/SHA-1), [MD5](https://wikipedia.org/wiki/MD5), or [crypt](https://en.wikipedia.org/wiki/Crypt_\(C\)) hash format.
      */
     #[serde(
         default,
@@ -4173,7 +4496,7 @@ pub struct User {
     )]
     pub hash_function: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The unique ID for the user. A user `id` can be used as a user request URI's `userKey`.
      */
     #[serde(
         default,
@@ -4182,7 +4505,7 @@ pub struct User {
     )]
     pub id: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The user's Instant Messenger (IM) accounts. A user account can have multiple ims properties. But, only one of these ims properties can be the primary IM contact. The maximum allowed data size for this field is 2Kb.
      */
     #[serde(
         default,
@@ -4191,7 +4514,7 @@ pub struct User {
     )]
     pub ims: Vec<Ims>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Indicates if the user's profile is visible in the Google Workspace global address list when the contact sharing feature is enabled for the domain. For more information about excluding user profiles, see the [administration help center](https://support.google.com/a/answer/1285988).
      */
     #[serde(
         default,
@@ -4200,7 +4523,7 @@ pub struct User {
     )]
     pub include_in_global_address_list: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * If `true`, the user's IP address is [whitelisted](https://support.google.com/a/answer/60752).
      */
     #[serde(
         default,
@@ -4209,7 +4532,7 @@ pub struct User {
     )]
     pub ip_whitelisted: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. Indicates a user with super admininistrator privileges. The `isAdmin` property can only be edited in the [Make a user an administrator](/admin-sdk/directory/v1/guides/manage-users.html#make_admin) operation ( [makeAdmin](/admin-sdk/directory/v1/reference/users/makeAdmin.html) method). If edited in the user [insert](/admin-sdk/directory/v1/reference/users/insert.html) or [update](/admin-sdk/directory/v1/reference/users/update.html) methods, the edit is ignored by the API service.
      */
     #[serde(
         default,
@@ -4218,7 +4541,7 @@ pub struct User {
     )]
     pub is_admin: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. Indicates if the user is a delegated administrator. Delegated administrators are supported by the API but cannot create or undelete users, or make users administrators. These requests are ignored by the API service. Roles and privileges for administrators are assigned using the [Admin console](https://support.google.com/a/answer/33325).
      */
     #[serde(
         default,
@@ -4227,7 +4550,7 @@ pub struct User {
     )]
     pub is_delegated_admin: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. Is 2-step verification enforced (Read-only)
      */
     #[serde(
         default,
@@ -4236,7 +4559,7 @@ pub struct User {
     )]
     pub is_enforced_in_2_sv: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. Is enrolled in 2-step verification (Read-only)
      */
     #[serde(
         default,
@@ -4245,7 +4568,7 @@ pub struct User {
     )]
     pub is_enrolled_in_2_sv: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. Indicates if the user's Google mailbox is created. This property is only applicable if the user has been assigned a Gmail license.
      */
     #[serde(
         default,
@@ -4254,12 +4577,12 @@ pub struct User {
     )]
     pub is_mailbox_setup: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The user's keywords. The maximum allowed data size for this field is 1Kb.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub keywords: Option<serde_json::Value>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. The type of the API resource. For Users resources, the value is `admin#directory#user`.
      */
     #[serde(
         default,
@@ -4268,12 +4591,12 @@ pub struct User {
     )]
     pub kind: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The user's languages. The maximum allowed data size for this field is 1Kb.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub languages: Option<serde_json::Value>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * User's last login time. (Read-only)
      */
     #[serde(
         default,
@@ -4283,7 +4606,7 @@ pub struct User {
     )]
     pub last_login_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The user's locations. The maximum allowed data size for this field is 10Kb.
      */
     #[serde(
         default,
@@ -4291,13 +4614,10 @@ pub struct User {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub locations: Vec<UserLocation>,
-    /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<UserName>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. List of the user's non-editable alias email addresses. These are typically outside the account's primary domain or sub-domain.
      */
     #[serde(
         default,
@@ -4307,12 +4627,12 @@ pub struct User {
     )]
     pub non_editable_aliases: Vec<String>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Notes for the user.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notes: Option<serde_json::Value>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The full path of the parent organization associated with the user. If the parent organization is the top-level, it is represented as a forward slash (`/`).
      */
     #[serde(
         default,
@@ -4322,12 +4642,12 @@ pub struct User {
     )]
     pub org_unit_path: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of organizations the user belongs to. The maximum allowed data size for this field is 10Kb.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organizations: Option<serde_json::Value>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * User's password
      */
     #[serde(
         default,
@@ -4336,7 +4656,7 @@ pub struct User {
     )]
     pub password: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of the user's phone numbers. The maximum allowed data size for this field is 1Kb.
      */
     #[serde(
         default,
@@ -4345,7 +4665,7 @@ pub struct User {
     )]
     pub phones: Vec<UserPhone>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of [POSIX](https://www.opengroup.org/austin/papers/posix_faq.html) account information for the user.
      */
     #[serde(
         default,
@@ -4354,7 +4674,7 @@ pub struct User {
     )]
     pub posix_accounts: Option<serde_json::Value>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The user's primary email address. This property is required in a request to create a user account. The `primaryEmail` must be unique and cannot be an alias of another user.
      */
     #[serde(
         default,
@@ -4364,7 +4684,7 @@ pub struct User {
     )]
     pub primary_email: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Recovery email of the user.
      */
     #[serde(
         default,
@@ -4374,7 +4694,7 @@ pub struct User {
     )]
     pub recovery_email: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Recovery phone of the user. The phone number must be in the E.164 format, starting with the plus sign (+). Example: \*+16506661212\*.
      */
     #[serde(
         default,
@@ -4384,12 +4704,12 @@ pub struct User {
     )]
     pub recovery_phone: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of the user's relationships to other users. The maximum allowed data size for this field is 2Kb.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub relations: Option<serde_json::Value>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * A list of SSH public keys.
      */
     #[serde(
         default,
@@ -4399,7 +4719,7 @@ pub struct User {
     )]
     pub ssh_public_keys: Vec<UserSshPublicKey>,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Indicates if user is suspended.
      */
     #[serde(
         default,
@@ -4407,7 +4727,7 @@ pub struct User {
     )]
     pub suspended: bool,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. Has the reason a user account is suspended either by the administrator or by Google at the time of suspension. The property is returned only if the `suspended` property is `true`.
      */
     #[serde(
         default,
@@ -4417,7 +4737,7 @@ pub struct User {
     )]
     pub suspension_reason: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. ETag of the user's photo (Read-only)
      */
     #[serde(
         default,
@@ -4427,7 +4747,7 @@ pub struct User {
     )]
     pub thumbnail_photo_etag: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * Output only. Photo Url of the user (Read-only)
      */
     #[serde(
         default,
@@ -4437,7 +4757,7 @@ pub struct User {
     )]
     pub thumbnail_photo_url: String,
     /**
-     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     * The user's websites. The maximum allowed data size for this field is 2Kb.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub websites: Option<serde_json::Value>,
@@ -4447,7 +4767,7 @@ pub struct User {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserAbout {
     /**
-     * JSON template for About (notes) of a user in Directory API.
+     * About entry can have a type which indicates the content type. It can either be plain or html. By default, notes contents are assumed to contain plain text.
      */
     #[serde(
         default,
@@ -4457,7 +4777,7 @@ pub struct UserAbout {
     )]
     pub content_type: String,
     /**
-     * JSON template for About (notes) of a user in Directory API.
+     * Actual value of notes.
      */
     #[serde(
         default,
@@ -4471,7 +4791,7 @@ pub struct UserAbout {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserAddress {
     /**
-     * JSON template for address.
+     * Country.
      */
     #[serde(
         default,
@@ -4480,7 +4800,7 @@ pub struct UserAddress {
     )]
     pub country: String,
     /**
-     * JSON template for address.
+     * Country code.
      */
     #[serde(
         default,
@@ -4490,7 +4810,7 @@ pub struct UserAddress {
     )]
     pub country_code: String,
     /**
-     * JSON template for address.
+     * Custom type.
      */
     #[serde(
         default,
@@ -4500,7 +4820,7 @@ pub struct UserAddress {
     )]
     pub custom_type: String,
     /**
-     * JSON template for address.
+     * Extended Address.
      */
     #[serde(
         default,
@@ -4510,7 +4830,7 @@ pub struct UserAddress {
     )]
     pub extended_address: String,
     /**
-     * JSON template for address.
+     * Formatted address.
      */
     #[serde(
         default,
@@ -4519,7 +4839,7 @@ pub struct UserAddress {
     )]
     pub formatted: String,
     /**
-     * JSON template for address.
+     * Locality.
      */
     #[serde(
         default,
@@ -4528,7 +4848,7 @@ pub struct UserAddress {
     )]
     pub locality: String,
     /**
-     * JSON template for address.
+     * Other parts of address.
      */
     #[serde(
         default,
@@ -4538,7 +4858,7 @@ pub struct UserAddress {
     )]
     pub po_box: String,
     /**
-     * JSON template for address.
+     * Postal code.
      */
     #[serde(
         default,
@@ -4548,7 +4868,7 @@ pub struct UserAddress {
     )]
     pub postal_code: String,
     /**
-     * JSON template for address.
+     * If this is user's primary address. Only one entry could be marked as primary.
      */
     #[serde(
         default,
@@ -4556,7 +4876,7 @@ pub struct UserAddress {
     )]
     pub primary: bool,
     /**
-     * JSON template for address.
+     * Region.
      */
     #[serde(
         default,
@@ -4565,7 +4885,7 @@ pub struct UserAddress {
     )]
     pub region: String,
     /**
-     * JSON template for address.
+     * User supplied address was structured. Structured addresses are NOT supported at this time. You might be able to write structured addresses but any values will eventually be clobbered.
      */
     #[serde(
         default,
@@ -4574,7 +4894,7 @@ pub struct UserAddress {
     )]
     pub source_is_structured: bool,
     /**
-     * JSON template for address.
+     * Street.
      */
     #[serde(
         default,
@@ -4584,7 +4904,7 @@ pub struct UserAddress {
     )]
     pub street_address: String,
     /**
-     * JSON template for address.
+     * Each entry can have a type which indicates standard values of that entry. For example address could be of home work etc. In addition to the standard type an entry can have a custom type and can take any value. Such type should have the CUSTOM value as type and also have a customType value.
      */
     #[serde(
         default,
@@ -4599,7 +4919,7 @@ pub struct UserAddress {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserEmail {
     /**
-     * JSON template for an email.
+     * Email id of the user.
      */
     #[serde(
         default,
@@ -4608,7 +4928,7 @@ pub struct UserEmail {
     )]
     pub address: String,
     /**
-     * JSON template for an email.
+     * Custom Type.
      */
     #[serde(
         default,
@@ -4618,7 +4938,7 @@ pub struct UserEmail {
     )]
     pub custom_type: String,
     /**
-     * JSON template for an email.
+     * If this is user's primary email. Only one entry could be marked as primary.
      */
     #[serde(
         default,
@@ -4626,7 +4946,7 @@ pub struct UserEmail {
     )]
     pub primary: bool,
     /**
-     * JSON template for an email.
+     * Each entry can have a type which indicates standard types of that entry. For example email could be of home, work etc. In addition to the standard type, an entry can have a custom type and can take any value Such types should have the CUSTOM value as type and also have a customType value.
      */
     #[serde(
         default,
@@ -4641,7 +4961,7 @@ pub struct UserEmail {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserExternalId {
     /**
-     * JSON template for an externalId entry.
+     * Custom type.
      */
     #[serde(
         default,
@@ -4651,7 +4971,7 @@ pub struct UserExternalId {
     )]
     pub custom_type: String,
     /**
-     * JSON template for an externalId entry.
+     * The type of the Id.
      */
     #[serde(
         default,
@@ -4661,7 +4981,7 @@ pub struct UserExternalId {
     )]
     pub type_: String,
     /**
-     * JSON template for an externalId entry.
+     * The value of the id.
      */
     #[serde(
         default,
@@ -4673,6 +4993,9 @@ pub struct UserExternalId {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserGender {
+    /**
+     * AddressMeAs. A human-readable string containing the proper way to refer to the profile owner by humans for example he/him/his or they/them/their.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4680,6 +5003,9 @@ pub struct UserGender {
         rename = "addressMeAs"
     )]
     pub address_me_as: String,
+    /**
+     * Custom gender.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4687,6 +5013,9 @@ pub struct UserGender {
         rename = "customGender"
     )]
     pub custom_gender: String,
+    /**
+     * Gender.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4700,7 +5029,7 @@ pub struct UserGender {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserIm {
     /**
-     * JSON template for instant messenger of an user.
+     * Custom protocol.
      */
     #[serde(
         default,
@@ -4710,7 +5039,7 @@ pub struct UserIm {
     )]
     pub custom_protocol: String,
     /**
-     * JSON template for instant messenger of an user.
+     * Custom type.
      */
     #[serde(
         default,
@@ -4720,7 +5049,7 @@ pub struct UserIm {
     )]
     pub custom_type: String,
     /**
-     * JSON template for instant messenger of an user.
+     * Instant messenger id.
      */
     #[serde(
         default,
@@ -4729,7 +5058,7 @@ pub struct UserIm {
     )]
     pub im: String,
     /**
-     * JSON template for instant messenger of an user.
+     * If this is user's primary im. Only one entry could be marked as primary.
      */
     #[serde(
         default,
@@ -4737,7 +5066,7 @@ pub struct UserIm {
     )]
     pub primary: bool,
     /**
-     * JSON template for instant messenger of an user.
+     * Protocol used in the instant messenger. It should be one of the values from ImProtocolTypes map. Similar to type it can take a CUSTOM value and specify the custom name in customProtocol field.
      */
     #[serde(
         default,
@@ -4746,7 +5075,7 @@ pub struct UserIm {
     )]
     pub protocol: String,
     /**
-     * JSON template for instant messenger of an user.
+     * Each entry can have a type which indicates standard types of that entry. For example instant messengers could be of home work etc. In addition to the standard type an entry can have a custom type and can take any value. Such types should have the CUSTOM value as type and also have a customType value.
      */
     #[serde(
         default,
@@ -4761,7 +5090,7 @@ pub struct UserIm {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserKeyword {
     /**
-     * JSON template for a keyword entry.
+     * Custom Type.
      */
     #[serde(
         default,
@@ -4771,7 +5100,7 @@ pub struct UserKeyword {
     )]
     pub custom_type: String,
     /**
-     * JSON template for a keyword entry.
+     * Each entry can have a type which indicates standard type of that entry. For example keyword could be of type occupation or outlook. In addition to the standard type an entry can have a custom type and can give it any name. Such types should have the CUSTOM value as type and also have a customType value.
      */
     #[serde(
         default,
@@ -4781,7 +5110,7 @@ pub struct UserKeyword {
     )]
     pub type_: String,
     /**
-     * JSON template for a keyword entry.
+     * Keyword.
      */
     #[serde(
         default,
@@ -4795,7 +5124,7 @@ pub struct UserKeyword {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserLanguage {
     /**
-     * JSON template for a language entry.
+     * Other language. User can provide own language name if there is no corresponding Google III language code. If this is set LanguageCode can't be set
      */
     #[serde(
         default,
@@ -4805,7 +5134,7 @@ pub struct UserLanguage {
     )]
     pub custom_language: String,
     /**
-     * JSON template for a language entry.
+     * Language Code. Should be used for storing Google III LanguageCode string representation for language. Illegal values cause SchemaException.
      */
     #[serde(
         default,
@@ -4820,7 +5149,7 @@ pub struct UserLanguage {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserLocation {
     /**
-     * JSON template for a location entry.
+     * Textual location. This is most useful for display purposes to concisely describe the location. For example 'Mountain View, CA', 'Near Seattle', 'US-NYC-9TH 9A209A.''
      */
     #[serde(
         default,
@@ -4829,7 +5158,7 @@ pub struct UserLocation {
     )]
     pub area: String,
     /**
-     * JSON template for a location entry.
+     * Building Identifier.
      */
     #[serde(
         default,
@@ -4839,7 +5168,7 @@ pub struct UserLocation {
     )]
     pub building_id: String,
     /**
-     * JSON template for a location entry.
+     * Custom Type.
      */
     #[serde(
         default,
@@ -4849,7 +5178,7 @@ pub struct UserLocation {
     )]
     pub custom_type: String,
     /**
-     * JSON template for a location entry.
+     * Most specific textual code of individual desk location.
      */
     #[serde(
         default,
@@ -4859,7 +5188,7 @@ pub struct UserLocation {
     )]
     pub desk_code: String,
     /**
-     * JSON template for a location entry.
+     * Floor name/number.
      */
     #[serde(
         default,
@@ -4869,7 +5198,7 @@ pub struct UserLocation {
     )]
     pub floor_name: String,
     /**
-     * JSON template for a location entry.
+     * Floor section. More specific location within the floor. For example if a floor is divided into sections 'A', 'B' and 'C' this field would identify one of those values.
      */
     #[serde(
         default,
@@ -4879,7 +5208,7 @@ pub struct UserLocation {
     )]
     pub floor_section: String,
     /**
-     * JSON template for a location entry.
+     * Each entry can have a type which indicates standard types of that entry. For example location could be of types default and desk. In addition to standard type an entry can have a custom type and can give it any name. Such types should have 'custom' as type and also have a customType value.
      */
     #[serde(
         default,
@@ -4893,7 +5222,7 @@ pub struct UserLocation {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserMakeAdmin {
     /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
+     * Indicates the administrator status of the user.
      */
     #[serde(
         default,
@@ -4904,6 +5233,9 @@ pub struct UserMakeAdmin {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserName {
+    /**
+     * The user's last name. Required when creating a user account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4911,6 +5243,9 @@ pub struct UserName {
         rename = "familyName"
     )]
     pub family_name: String,
+    /**
+     * The user's full name formed by concatenating the first and last name values.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4918,6 +5253,9 @@ pub struct UserName {
         rename = "fullName"
     )]
     pub full_name: String,
+    /**
+     * The user's first name. Required when creating a user account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4931,7 +5269,7 @@ pub struct UserName {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserOrganization {
     /**
-     * JSON template for an organization entry.
+     * The cost center of the users department.
      */
     #[serde(
         default,
@@ -4941,7 +5279,7 @@ pub struct UserOrganization {
     )]
     pub cost_center: String,
     /**
-     * JSON template for an organization entry.
+     * Custom type.
      */
     #[serde(
         default,
@@ -4951,7 +5289,7 @@ pub struct UserOrganization {
     )]
     pub custom_type: String,
     /**
-     * JSON template for an organization entry.
+     * Department within the organization.
      */
     #[serde(
         default,
@@ -4960,7 +5298,7 @@ pub struct UserOrganization {
     )]
     pub department: String,
     /**
-     * JSON template for an organization entry.
+     * Description of the organization.
      */
     #[serde(
         default,
@@ -4969,7 +5307,7 @@ pub struct UserOrganization {
     )]
     pub description: String,
     /**
-     * JSON template for an organization entry.
+     * The domain to which the organization belongs to.
      */
     #[serde(
         default,
@@ -4978,7 +5316,7 @@ pub struct UserOrganization {
     )]
     pub domain: String,
     /**
-     * JSON template for an organization entry.
+     * The full-time equivalent millipercent within the organization (100000 = 100%).
      */
     #[serde(
         default,
@@ -4988,7 +5326,7 @@ pub struct UserOrganization {
     )]
     pub full_time_equivalent: i64,
     /**
-     * JSON template for an organization entry.
+     * Location of the organization. This need not be fully qualified address.
      */
     #[serde(
         default,
@@ -4997,7 +5335,7 @@ pub struct UserOrganization {
     )]
     pub location: String,
     /**
-     * JSON template for an organization entry.
+     * Name of the organization
      */
     #[serde(
         default,
@@ -5006,7 +5344,7 @@ pub struct UserOrganization {
     )]
     pub name: String,
     /**
-     * JSON template for an organization entry.
+     * If it user's primary organization.
      */
     #[serde(
         default,
@@ -5014,7 +5352,7 @@ pub struct UserOrganization {
     )]
     pub primary: bool,
     /**
-     * JSON template for an organization entry.
+     * Symbol of the organization.
      */
     #[serde(
         default,
@@ -5023,7 +5361,7 @@ pub struct UserOrganization {
     )]
     pub symbol: String,
     /**
-     * JSON template for an organization entry.
+     * Title (designation) of the user in the organization.
      */
     #[serde(
         default,
@@ -5032,7 +5370,7 @@ pub struct UserOrganization {
     )]
     pub title: String,
     /**
-     * JSON template for an organization entry.
+     * Each entry can have a type which indicates standard types of that entry. For example organization could be of school work etc. In addition to the standard type an entry can have a custom type and can give it any name. Such types should have the CUSTOM value as type and also have a CustomType value.
      */
     #[serde(
         default,
@@ -5047,7 +5385,7 @@ pub struct UserOrganization {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserPhone {
     /**
-     * JSON template for a phone entry.
+     * Custom Type.
      */
     #[serde(
         default,
@@ -5057,7 +5395,7 @@ pub struct UserPhone {
     )]
     pub custom_type: String,
     /**
-     * JSON template for a phone entry.
+     * If this is user's primary phone or not.
      */
     #[serde(
         default,
@@ -5065,7 +5403,7 @@ pub struct UserPhone {
     )]
     pub primary: bool,
     /**
-     * JSON template for a phone entry.
+     * Each entry can have a type which indicates standard types of that entry. For example phone could be of home_fax work mobile etc. In addition to the standard type an entry can have a custom type and can give it any name. Such types should have the CUSTOM value as type and also have a customType value.
      */
     #[serde(
         default,
@@ -5075,7 +5413,7 @@ pub struct UserPhone {
     )]
     pub type_: String,
     /**
-     * JSON template for a phone entry.
+     * Phone number.
      */
     #[serde(
         default,
@@ -5087,6 +5425,9 @@ pub struct UserPhone {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserPhoto {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5094,7 +5435,7 @@ pub struct UserPhoto {
     )]
     pub etag: String,
     /**
-     * The unique ID of the ASP.
+     * Height of the photo in pixels.
      */
     #[serde(
         default,
@@ -5102,18 +5443,27 @@ pub struct UserPhoto {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub height: i64,
+    /**
+     * The ID the API uses to uniquely identify the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * The type of the API resource. For Photo resources, this is `admin#directory#user#photo`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * The MIME type of the photo. Allowed values are `JPEG`, `PNG`, `GIF`, `BMP`, `TIFF`, and web-safe base64 encoding.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5126,6 +5476,9 @@ pub struct UserPhoto {
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "photoData")]
     pub photo_data: Option<bytes::Bytes>,
+    /**
+     * The user's primary email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5134,7 +5487,7 @@ pub struct UserPhoto {
     )]
     pub primary_email: String,
     /**
-     * The unique ID of the ASP.
+     * Width of the photo in pixels.
      */
     #[serde(
         default,
@@ -5148,7 +5501,7 @@ pub struct UserPhoto {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserPosixAccount {
     /**
-     * JSON template for a POSIX account entry.
+     * A POSIX account field identifier.
      */
     #[serde(
         default,
@@ -5158,7 +5511,7 @@ pub struct UserPosixAccount {
     )]
     pub account_id: String,
     /**
-     * JSON template for a POSIX account entry.
+     * The GECOS (user information) for this account.
      */
     #[serde(
         default,
@@ -5167,12 +5520,12 @@ pub struct UserPosixAccount {
     )]
     pub gecos: String,
     /**
-     * JSON template for a POSIX account entry.
+     * The default group ID.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gid: Option<u64>,
     /**
-     * JSON template for a POSIX account entry.
+     * The path to the home directory for this account.
      */
     #[serde(
         default,
@@ -5182,7 +5535,7 @@ pub struct UserPosixAccount {
     )]
     pub home_directory: String,
     /**
-     * JSON template for a POSIX account entry.
+     * The operating system type for this account.
      */
     #[serde(
         default,
@@ -5192,7 +5545,7 @@ pub struct UserPosixAccount {
     )]
     pub operating_system_type: String,
     /**
-     * JSON template for a POSIX account entry.
+     * If this is user's primary account within the SystemId.
      */
     #[serde(
         default,
@@ -5200,7 +5553,7 @@ pub struct UserPosixAccount {
     )]
     pub primary: bool,
     /**
-     * JSON template for a POSIX account entry.
+     * The path to the login shell for this account.
      */
     #[serde(
         default,
@@ -5209,7 +5562,7 @@ pub struct UserPosixAccount {
     )]
     pub shell: String,
     /**
-     * JSON template for a POSIX account entry.
+     * System identifier for which account Username or Uid apply to.
      */
     #[serde(
         default,
@@ -5219,12 +5572,12 @@ pub struct UserPosixAccount {
     )]
     pub system_id: String,
     /**
-     * JSON template for a POSIX account entry.
+     * The POSIX compliant user ID.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uid: Option<u64>,
     /**
-     * JSON template for a POSIX account entry.
+     * The username of the account.
      */
     #[serde(
         default,
@@ -5238,7 +5591,7 @@ pub struct UserPosixAccount {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserRelation {
     /**
-     * JSON template for a relation entry.
+     * Custom Type.
      */
     #[serde(
         default,
@@ -5248,7 +5601,7 @@ pub struct UserRelation {
     )]
     pub custom_type: String,
     /**
-     * JSON template for a relation entry.
+     * The relation of the user. Some of the possible values are mother father sister brother manager assistant partner.
      */
     #[serde(
         default,
@@ -5258,7 +5611,7 @@ pub struct UserRelation {
     )]
     pub type_: String,
     /**
-     * JSON template for a relation entry.
+     * The name of the relation.
      */
     #[serde(
         default,
@@ -5272,7 +5625,7 @@ pub struct UserRelation {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserSshPublicKey {
     /**
-     * JSON template for a POSIX account entry.
+     * An expiration time in microseconds since epoch.
      */
     #[serde(
         default,
@@ -5282,7 +5635,7 @@ pub struct UserSshPublicKey {
     )]
     pub expiration_time_usec: i64,
     /**
-     * JSON template for a POSIX account entry.
+     * A SHA-256 fingerprint of the SSH public key. (Read-only)
      */
     #[serde(
         default,
@@ -5291,7 +5644,7 @@ pub struct UserSshPublicKey {
     )]
     pub fingerprint: String,
     /**
-     * JSON template for a POSIX account entry.
+     * An SSH public key.
      */
     #[serde(
         default,
@@ -5303,6 +5656,9 @@ pub struct UserSshPublicKey {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserUndelete {
+    /**
+     * OrgUnit of User
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5316,7 +5672,7 @@ pub struct UserUndelete {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserWebsite {
     /**
-     * JSON template for a website entry.
+     * Custom Type.
      */
     #[serde(
         default,
@@ -5326,7 +5682,7 @@ pub struct UserWebsite {
     )]
     pub custom_type: String,
     /**
-     * JSON template for a website entry.
+     * If this is user's primary website or not.
      */
     #[serde(
         default,
@@ -5334,7 +5690,7 @@ pub struct UserWebsite {
     )]
     pub primary: bool,
     /**
-     * JSON template for a website entry.
+     * Each entry can have a type which indicates standard types of that entry. For example website could be of home work blog etc. In addition to the standard type an entry can have a custom type and can give it any name. Such types should have the CUSTOM value as type and also have a customType value.
      */
     #[serde(
         default,
@@ -5344,7 +5700,7 @@ pub struct UserWebsite {
     )]
     pub type_: String,
     /**
-     * JSON template for a website entry.
+     * Website.
      */
     #[serde(
         default,
@@ -5356,18 +5712,27 @@ pub struct UserWebsite {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Users {
+    /**
+     * ETag of the resource.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
+    /**
+     * Kind of resource this is.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kind: String,
+    /**
+     * Token used to access next page of this result.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5375,6 +5740,9 @@ pub struct Users {
         rename = "nextPageToken"
     )]
     pub next_page_token: String,
+    /**
+     * Event that triggered this response (only used in case of Push Response)
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5396,7 +5764,7 @@ pub struct Users {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VerificationCode {
     /**
-     * The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -5405,7 +5773,7 @@ pub struct VerificationCode {
     )]
     pub etag: String,
     /**
-     * The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
+     * The type of the resource. This is always `admin#directory#verificationCode`.
      */
     #[serde(
         default,
@@ -5414,7 +5782,7 @@ pub struct VerificationCode {
     )]
     pub kind: String,
     /**
-     * The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
+     * The obfuscated unique ID of the user.
      */
     #[serde(
         default,
@@ -5424,7 +5792,7 @@ pub struct VerificationCode {
     )]
     pub user_id: String,
     /**
-     * The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
+     * A current verification code for the user. Invalidated or used verification codes are not returned as part of the result.
      */
     #[serde(
         default,
@@ -5439,7 +5807,7 @@ pub struct VerificationCode {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VerificationCodes {
     /**
-     * JSON response template for List verification codes operation in Directory API.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -5448,7 +5816,7 @@ pub struct VerificationCodes {
     )]
     pub etag: String,
     /**
-     * JSON response template for List verification codes operation in Directory API.
+     * A list of verification code resources.
      */
     #[serde(
         default,
@@ -5457,7 +5825,7 @@ pub struct VerificationCodes {
     )]
     pub items: Vec<VerificationCode>,
     /**
-     * JSON response template for List verification codes operation in Directory API.
+     * The type of the resource. This is always `admin#directory#verificationCodesList`.
      */
     #[serde(
         default,
@@ -5471,6 +5839,7 @@ pub struct VerificationCodes {
 * V1 error format.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -5478,8 +5847,9 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -5488,12 +5858,32 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Xgafv {
+    fn from(s: String) -> Xgafv {
+        match s.as_str() {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Xgafv> for String {
+    fn from(e: Xgafv) -> String {
+        match e {
+            Xgafv::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -5509,6 +5899,7 @@ impl Xgafv {
 * Data format for response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -5518,8 +5909,9 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -5529,12 +5921,33 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Alt {
+        match s.as_str() {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(e: Alt) -> String {
+        match e {
+            Alt::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -5550,6 +5963,7 @@ impl Alt {
 * Device property to use for sorting results.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrderBy {
     #[serde(rename = "annotatedLocation")]
     AnnotatedLocation,
@@ -5567,8 +5981,9 @@ pub enum OrderBy {
     SupportEndDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -5582,12 +5997,37 @@ impl std::fmt::Display for OrderBy {
             OrderBy::Status => "status",
             OrderBy::SupportEndDate => "supportEndDate",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrderBy {
+    fn from(s: String) -> OrderBy {
+        match s.as_str() {
+            "annotatedLocation" => OrderBy::AnnotatedLocation,
+            "annotatedUser" => OrderBy::AnnotatedUser,
+            "lastSync" => OrderBy::LastSync,
+            "notes" => OrderBy::Notes,
+            "serialNumber" => OrderBy::SerialNumber,
+            "status" => OrderBy::Status,
+            "supportEndDate" => OrderBy::SupportEndDate,
+            "" => OrderBy::Noop,
+            _ => OrderBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrderBy> for String {
+    fn from(e: OrderBy) -> String {
+        match e {
+            OrderBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrderBy {
     fn default() -> OrderBy {
         OrderBy::Noop
@@ -5603,6 +6043,7 @@ impl OrderBy {
 * Restrict information returned to a set of selected fields.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Projection {
     #[serde(rename = "BASIC")]
     Basic,
@@ -5610,8 +6051,9 @@ pub enum Projection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Projection {
@@ -5620,12 +6062,32 @@ impl std::fmt::Display for Projection {
             Projection::Basic => "BASIC",
             Projection::Full => "FULL",
             Projection::Noop => "",
-            Projection::FallthroughString => "*",
+            Projection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Projection {
+    fn from(s: String) -> Projection {
+        match s.as_str() {
+            "BASIC" => Projection::Basic,
+            "FULL" => Projection::Full,
+            "" => Projection::Noop,
+            _ => Projection::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Projection> for String {
+    fn from(e: Projection) -> String {
+        match e {
+            Projection::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Projection {
     fn default() -> Projection {
         Projection::Noop
@@ -5641,6 +6103,7 @@ impl Projection {
 * Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -5648,8 +6111,9 @@ pub enum SortOrder {
     Descending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -5658,12 +6122,32 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Ascending => "ASCENDING",
             SortOrder::Descending => "DESCENDING",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SortOrder {
+    fn from(s: String) -> SortOrder {
+        match s.as_str() {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            "" => SortOrder::Noop,
+            _ => SortOrder::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SortOrder> for String {
+    fn from(e: SortOrder) -> String {
+        match e {
+            SortOrder::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -5679,6 +6163,7 @@ impl SortOrder {
 * Device property to use for sorting results.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DirectoryMobiledevicesListOrderBy {
     #[serde(rename = "deviceId")]
     DeviceId,
@@ -5698,8 +6183,9 @@ pub enum DirectoryMobiledevicesListOrderBy {
     Type,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
@@ -5714,12 +6200,38 @@ impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
             DirectoryMobiledevicesListOrderBy::Status => "status",
             DirectoryMobiledevicesListOrderBy::Type => "type",
             DirectoryMobiledevicesListOrderBy::Noop => "",
-            DirectoryMobiledevicesListOrderBy::FallthroughString => "*",
+            DirectoryMobiledevicesListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DirectoryMobiledevicesListOrderBy {
+    fn from(s: String) -> DirectoryMobiledevicesListOrderBy {
+        match s.as_str() {
+            "deviceId" => DirectoryMobiledevicesListOrderBy::DeviceId,
+            "email" => DirectoryMobiledevicesListOrderBy::Email,
+            "lastSync" => DirectoryMobiledevicesListOrderBy::LastSync,
+            "model" => DirectoryMobiledevicesListOrderBy::Model,
+            "name" => DirectoryMobiledevicesListOrderBy::Name,
+            "os" => DirectoryMobiledevicesListOrderBy::Os,
+            "status" => DirectoryMobiledevicesListOrderBy::Status,
+            "type" => DirectoryMobiledevicesListOrderBy::Type,
+            "" => DirectoryMobiledevicesListOrderBy::Noop,
+            _ => DirectoryMobiledevicesListOrderBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DirectoryMobiledevicesListOrderBy> for String {
+    fn from(e: DirectoryMobiledevicesListOrderBy) -> String {
+        match e {
+            DirectoryMobiledevicesListOrderBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DirectoryMobiledevicesListOrderBy {
     fn default() -> DirectoryMobiledevicesListOrderBy {
         DirectoryMobiledevicesListOrderBy::Noop
@@ -5735,6 +6247,7 @@ impl DirectoryMobiledevicesListOrderBy {
 * Whether to return all sub-organizations or just immediate children.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DirectoryOrgunitsListType {
     #[serde(rename = "all")]
     All,
@@ -5742,8 +6255,9 @@ pub enum DirectoryOrgunitsListType {
     Children,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryOrgunitsListType {
@@ -5752,12 +6266,32 @@ impl std::fmt::Display for DirectoryOrgunitsListType {
             DirectoryOrgunitsListType::All => "all",
             DirectoryOrgunitsListType::Children => "children",
             DirectoryOrgunitsListType::Noop => "",
-            DirectoryOrgunitsListType::FallthroughString => "*",
+            DirectoryOrgunitsListType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DirectoryOrgunitsListType {
+    fn from(s: String) -> DirectoryOrgunitsListType {
+        match s.as_str() {
+            "all" => DirectoryOrgunitsListType::All,
+            "children" => DirectoryOrgunitsListType::Children,
+            "" => DirectoryOrgunitsListType::Noop,
+            _ => DirectoryOrgunitsListType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DirectoryOrgunitsListType> for String {
+    fn from(e: DirectoryOrgunitsListType) -> String {
+        match e {
+            DirectoryOrgunitsListType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DirectoryOrgunitsListType {
     fn default() -> DirectoryOrgunitsListType {
         DirectoryOrgunitsListType::Noop
@@ -5773,6 +6307,7 @@ impl DirectoryOrgunitsListType {
 * Source from which Building.coordinates are derived.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CoordinatesSource {
     #[serde(rename = "CLIENT_SPECIFIED")]
     ClientSpecified,
@@ -5782,8 +6317,9 @@ pub enum CoordinatesSource {
     SourceUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CoordinatesSource {
@@ -5793,12 +6329,33 @@ impl std::fmt::Display for CoordinatesSource {
             CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
             CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
             CoordinatesSource::Noop => "",
-            CoordinatesSource::FallthroughString => "*",
+            CoordinatesSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CoordinatesSource {
+    fn from(s: String) -> CoordinatesSource {
+        match s.as_str() {
+            "CLIENT_SPECIFIED" => CoordinatesSource::ClientSpecified,
+            "RESOLVED_FROM_ADDRESS" => CoordinatesSource::ResolvedFromAddress,
+            "SOURCE_UNSPECIFIED" => CoordinatesSource::SourceUnspecified,
+            "" => CoordinatesSource::Noop,
+            _ => CoordinatesSource::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CoordinatesSource> for String {
+    fn from(e: CoordinatesSource) -> String {
+        match e {
+            CoordinatesSource::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CoordinatesSource {
     fn default() -> CoordinatesSource {
         CoordinatesSource::Noop
@@ -5814,13 +6371,15 @@ impl CoordinatesSource {
 * Column to use for sorting results
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DirectoryGroupsListOrderBy {
     #[serde(rename = "email")]
     Email,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryGroupsListOrderBy {
@@ -5828,12 +6387,31 @@ impl std::fmt::Display for DirectoryGroupsListOrderBy {
         match self {
             DirectoryGroupsListOrderBy::Email => "email",
             DirectoryGroupsListOrderBy::Noop => "",
-            DirectoryGroupsListOrderBy::FallthroughString => "*",
+            DirectoryGroupsListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DirectoryGroupsListOrderBy {
+    fn from(s: String) -> DirectoryGroupsListOrderBy {
+        match s.as_str() {
+            "email" => DirectoryGroupsListOrderBy::Email,
+            "" => DirectoryGroupsListOrderBy::Noop,
+            _ => DirectoryGroupsListOrderBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DirectoryGroupsListOrderBy> for String {
+    fn from(e: DirectoryGroupsListOrderBy) -> String {
+        match e {
+            DirectoryGroupsListOrderBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DirectoryGroupsListOrderBy {
     fn default() -> DirectoryGroupsListOrderBy {
         DirectoryGroupsListOrderBy::Noop
@@ -5849,6 +6427,7 @@ impl DirectoryGroupsListOrderBy {
 * Event on which subscription is intended (if subscribing)
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Event {
     #[serde(rename = "add")]
     Add,
@@ -5862,8 +6441,9 @@ pub enum Event {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Event {
@@ -5875,12 +6455,35 @@ impl std::fmt::Display for Event {
             Event::Undelete => "undelete",
             Event::Update => "update",
             Event::Noop => "",
-            Event::FallthroughString => "*",
+            Event::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Event {
+    fn from(s: String) -> Event {
+        match s.as_str() {
+            "add" => Event::Add,
+            "delete" => Event::Delete,
+            "makeAdmin" => Event::MakeAdmin,
+            "undelete" => Event::Undelete,
+            "update" => Event::Update,
+            "" => Event::Noop,
+            _ => Event::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Event> for String {
+    fn from(e: Event) -> String {
+        match e {
+            Event::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Event {
     fn default() -> Event {
         Event::Noop
@@ -5896,6 +6499,7 @@ impl Event {
 * Property to use for sorting results.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DirectoryUsersListOrderBy {
     #[serde(rename = "email")]
     Email,
@@ -5905,8 +6509,9 @@ pub enum DirectoryUsersListOrderBy {
     GivenName,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListOrderBy {
@@ -5916,12 +6521,33 @@ impl std::fmt::Display for DirectoryUsersListOrderBy {
             DirectoryUsersListOrderBy::FamilyName => "familyName",
             DirectoryUsersListOrderBy::GivenName => "givenName",
             DirectoryUsersListOrderBy::Noop => "",
-            DirectoryUsersListOrderBy::FallthroughString => "*",
+            DirectoryUsersListOrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DirectoryUsersListOrderBy {
+    fn from(s: String) -> DirectoryUsersListOrderBy {
+        match s.as_str() {
+            "email" => DirectoryUsersListOrderBy::Email,
+            "familyName" => DirectoryUsersListOrderBy::FamilyName,
+            "givenName" => DirectoryUsersListOrderBy::GivenName,
+            "" => DirectoryUsersListOrderBy::Noop,
+            _ => DirectoryUsersListOrderBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersListOrderBy> for String {
+    fn from(e: DirectoryUsersListOrderBy) -> String {
+        match e {
+            DirectoryUsersListOrderBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DirectoryUsersListOrderBy {
     fn default() -> DirectoryUsersListOrderBy {
         DirectoryUsersListOrderBy::Noop
@@ -5937,6 +6563,7 @@ impl DirectoryUsersListOrderBy {
 * What subset of fields to fetch for this user.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DirectoryUsersListProjection {
     #[serde(rename = "basic")]
     Basic,
@@ -5946,8 +6573,9 @@ pub enum DirectoryUsersListProjection {
     Full,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersListProjection {
@@ -5957,12 +6585,33 @@ impl std::fmt::Display for DirectoryUsersListProjection {
             DirectoryUsersListProjection::Custom => "custom",
             DirectoryUsersListProjection::Full => "full",
             DirectoryUsersListProjection::Noop => "",
-            DirectoryUsersListProjection::FallthroughString => "*",
+            DirectoryUsersListProjection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DirectoryUsersListProjection {
+    fn from(s: String) -> DirectoryUsersListProjection {
+        match s.as_str() {
+            "basic" => DirectoryUsersListProjection::Basic,
+            "custom" => DirectoryUsersListProjection::Custom,
+            "full" => DirectoryUsersListProjection::Full,
+            "" => DirectoryUsersListProjection::Noop,
+            _ => DirectoryUsersListProjection::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersListProjection> for String {
+    fn from(e: DirectoryUsersListProjection) -> String {
+        match e {
+            DirectoryUsersListProjection::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DirectoryUsersListProjection {
     fn default() -> DirectoryUsersListProjection {
         DirectoryUsersListProjection::Noop
@@ -5978,6 +6627,7 @@ impl DirectoryUsersListProjection {
 * Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ViewType {
     #[serde(rename = "admin_view")]
     AdminView,
@@ -5985,8 +6635,9 @@ pub enum ViewType {
     DomainPublic,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewType {
@@ -5995,12 +6646,32 @@ impl std::fmt::Display for ViewType {
             ViewType::AdminView => "admin_view",
             ViewType::DomainPublic => "domain_public",
             ViewType::Noop => "",
-            ViewType::FallthroughString => "*",
+            ViewType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ViewType {
+    fn from(s: String) -> ViewType {
+        match s.as_str() {
+            "admin_view" => ViewType::AdminView,
+            "domain_public" => ViewType::DomainPublic,
+            "" => ViewType::Noop,
+            _ => ViewType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ViewType> for String {
+    fn from(e: ViewType) -> String {
+        match e {
+            ViewType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ViewType {
     fn default() -> ViewType {
         ViewType::Noop
@@ -6016,6 +6687,7 @@ impl ViewType {
 * Events to watch for.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DirectoryUsersAliasesListEvent {
     #[serde(rename = "add")]
     Add,
@@ -6023,8 +6695,9 @@ pub enum DirectoryUsersAliasesListEvent {
     Delete,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DirectoryUsersAliasesListEvent {
@@ -6033,12 +6706,32 @@ impl std::fmt::Display for DirectoryUsersAliasesListEvent {
             DirectoryUsersAliasesListEvent::Add => "add",
             DirectoryUsersAliasesListEvent::Delete => "delete",
             DirectoryUsersAliasesListEvent::Noop => "",
-            DirectoryUsersAliasesListEvent::FallthroughString => "*",
+            DirectoryUsersAliasesListEvent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DirectoryUsersAliasesListEvent {
+    fn from(s: String) -> DirectoryUsersAliasesListEvent {
+        match s.as_str() {
+            "add" => DirectoryUsersAliasesListEvent::Add,
+            "delete" => DirectoryUsersAliasesListEvent::Delete,
+            "" => DirectoryUsersAliasesListEvent::Noop,
+            _ => DirectoryUsersAliasesListEvent::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersAliasesListEvent> for String {
+    fn from(e: DirectoryUsersAliasesListEvent) -> String {
+        match e {
+            DirectoryUsersAliasesListEvent::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DirectoryUsersAliasesListEvent {
     fn default() -> DirectoryUsersAliasesListEvent {
         DirectoryUsersAliasesListEvent::Noop
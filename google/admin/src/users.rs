@@ -33,6 +33,7 @@ impl Users {
      */
     pub async fn list(
         &self,
+        fields: &str,
         customer: &str,
         domain: &str,
         event: crate::types::Event,
@@ -45,6 +46,44 @@ impl Users {
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> ClientResult<Vec<crate::types::User>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                customer,
+                domain,
+                event,
+                max_results,
+                order_by,
+                page_token,
+                projection,
+                query,
+                show_deleted,
+                sort_order,
+                view_type,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -55,6 +94,9 @@ impl Users {
         if !event.to_string().is_empty() {
             query_args.push(("event".to_string(), event.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -83,9 +125,9 @@ impl Users {
         let url = self
             .client
             .url(&format!("/admin/directory/v1/users?{}", query_), None);
-        let resp: crate::types::Users = self
+        let resp: crate::Response<crate::types::Users> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -95,7 +137,7 @@ impl Users {
             .await?;
 
         // Return our response data.
-        Ok(resp.users.to_vec())
+        Ok(resp.map(|r| r.users.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
@@ -106,6 +148,7 @@ impl Users {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         customer: &str,
         domain: &str,
         event: crate::types::Event,
@@ -116,6 +159,7 @@ impl Users {
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> ClientResult<Vec<crate::types::User>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -126,6 +170,9 @@ impl Users {
         if !event.to_string().is_empty() {
             query_args.push(("event".to_string(), event.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !order_by.to_string().is_empty() {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
@@ -200,15 +247,174 @@ impl Users {
         // Return our response data.
         Ok(users)
     }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::User>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if !event.to_string().is_empty() {
+            query_args.push(("event".to_string(), event.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !show_deleted.is_empty() {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if !view_type.to_string().is_empty() {
+            query_args.push(("viewType".to_string(), view_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/directory/v1/users?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Users = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.users;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            customer,
+            domain,
+            event,
+            order_by,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users` endpoint.
      *
      * Creates a user.
      */
-    pub async fn insert(&self, body: &crate::types::User) -> ClientResult<crate::types::User> {
-        let url = self.client.url("/admin/directory/v1/users", None);
+    pub async fn insert(
+        &self,
+        fields: &str,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::types::User> {
+        Ok(self.insert_with_response(fields, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/directory/v1/users?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -239,6 +445,7 @@ impl Users {
      */
     pub async fn watch(
         &self,
+        fields: &str,
         customer: &str,
         domain: &str,
         event: crate::types::Event,
@@ -252,6 +459,46 @@ impl Users {
         view_type: crate::types::ViewType,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .watch_with_response(
+                fields,
+                customer,
+                domain,
+                event,
+                max_results,
+                order_by,
+                page_token,
+                projection,
+                query,
+                show_deleted,
+                sort_order,
+                view_type,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watch_with_response(
+        &self,
+        fields: &str,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -262,6 +509,9 @@ impl Users {
         if !event.to_string().is_empty() {
             query_args.push(("event".to_string(), event.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -291,7 +541,7 @@ impl Users {
             .client
             .url(&format!("/admin/directory/v1/users/watch?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -314,11 +564,32 @@ impl Users {
      */
     pub async fn get(
         &self,
+        fields: &str,
         user_key: &str,
         projection: crate::types::DirectoryUsersListProjection,
         view_type: crate::types::ViewType,
     ) -> ClientResult<crate::types::User> {
+        Ok(self
+            .get_with_response(fields, user_key, projection, view_type)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        view_type: crate::types::ViewType,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !projection.to_string().is_empty() {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
@@ -335,7 +606,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -355,18 +626,41 @@ impl Users {
      */
     pub async fn update(
         &self,
+        fields: &str,
         user_key: &str,
         body: &crate::types::User,
     ) -> ClientResult<crate::types::User> {
+        Ok(self
+            .update_with_response(fields, user_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}",
+                "/admin/directory/v1/users/{}?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -384,16 +678,35 @@ impl Users {
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      */
-    pub async fn delete(&self, user_key: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, user_key: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, user_key).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}",
+                "/admin/directory/v1/users/{}?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -413,18 +726,38 @@ impl Users {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         user_key: &str,
         body: &crate::types::User,
     ) -> ClientResult<crate::types::User> {
+        Ok(self.patch_with_response(fields, user_key, body).await?.body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}",
+                "/admin/directory/v1/users/{}?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -445,13 +778,33 @@ impl Users {
      */
     pub async fn aliases_list(
         &self,
+        fields: &str,
         user_key: &str,
         event: crate::types::DirectoryUsersAliasesListEvent,
     ) -> ClientResult<crate::types::Aliases> {
+        Ok(self
+            .aliases_list_with_response(fields, user_key, event)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
+     *
+     * As opposed to `aliases_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn aliases_list_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+    ) -> ClientResult<crate::Response<crate::types::Aliases>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !event.to_string().is_empty() {
             query_args.push(("event".to_string(), event.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -462,7 +815,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -482,18 +835,41 @@ impl Users {
      */
     pub async fn aliases_insert(
         &self,
+        fields: &str,
         user_key: &str,
         body: &crate::types::Alias,
     ) -> ClientResult<crate::types::Alias> {
+        Ok(self
+            .aliases_insert_with_response(fields, user_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
+     *
+     * As opposed to `aliases_insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn aliases_insert_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        body: &crate::types::Alias,
+    ) -> ClientResult<crate::Response<crate::types::Alias>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/aliases",
+                "/admin/directory/v1/users/{}/aliases?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -514,14 +890,35 @@ impl Users {
      */
     pub async fn aliases_watch(
         &self,
+        fields: &str,
         user_key: &str,
         event: crate::types::DirectoryUsersAliasesListEvent,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .aliases_watch_with_response(fields, user_key, event, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases/watch` endpoint.
+     *
+     * As opposed to `aliases_watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn aliases_watch_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !event.to_string().is_empty() {
             query_args.push(("event".to_string(), event.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -532,7 +929,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -551,17 +948,43 @@ impl Users {
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      * * `alias: &str` -- The alias to be removed.
      */
-    pub async fn aliases_delete(&self, user_key: &str, alias: &str) -> ClientResult<()> {
+    pub async fn aliases_delete(
+        &self,
+        fields: &str,
+        user_key: &str,
+        alias: &str,
+    ) -> ClientResult<()> {
+        self.aliases_delete_with_response(fields, user_key, alias)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/aliases/{alias}` endpoint.
+     *
+     * As opposed to `aliases_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn aliases_delete_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        alias: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/aliases/{}",
+                "/admin/directory/v1/users/{}/aliases/{}?{}",
                 crate::progenitor_support::encode_path(user_key),
                 crate::progenitor_support::encode_path(alias),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -581,18 +1004,40 @@ impl Users {
      */
     pub async fn make_admin(
         &self,
+        fields: &str,
         user_key: &str,
         body: &crate::types::UserMakeAdmin,
     ) -> ClientResult<()> {
+        self.make_admin_with_response(fields, user_key, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/makeAdmin` endpoint.
+     *
+     * As opposed to `make_admin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn make_admin_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        body: &crate::types::UserMakeAdmin,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/makeAdmin",
+                "/admin/directory/v1/users/{}/makeAdmin?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -610,16 +1055,38 @@ impl Users {
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      */
-    pub async fn photos_get(&self, user_key: &str) -> ClientResult<crate::types::UserPhoto> {
+    pub async fn photos_get(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::types::UserPhoto> {
+        Ok(self.photos_get_with_response(fields, user_key).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * As opposed to `photos_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn photos_get_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserPhoto>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/photos/thumbnail",
+                "/admin/directory/v1/users/{}/photos/thumbnail?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -639,18 +1106,41 @@ impl Users {
      */
     pub async fn photos_update(
         &self,
+        fields: &str,
         user_key: &str,
         body: &crate::types::UserPhoto,
     ) -> ClientResult<crate::types::UserPhoto> {
+        Ok(self
+            .photos_update_with_response(fields, user_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * As opposed to `photos_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn photos_update_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> ClientResult<crate::Response<crate::types::UserPhoto>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/photos/thumbnail",
+                "/admin/directory/v1/users/{}/photos/thumbnail?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -668,16 +1158,35 @@ impl Users {
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      */
-    pub async fn photos_delete(&self, user_key: &str) -> ClientResult<()> {
+    pub async fn photos_delete(&self, fields: &str, user_key: &str) -> ClientResult<()> {
+        self.photos_delete_with_response(fields, user_key).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * As opposed to `photos_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn photos_delete_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/photos/thumbnail",
+                "/admin/directory/v1/users/{}/photos/thumbnail?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -697,18 +1206,41 @@ impl Users {
      */
     pub async fn photos_patch(
         &self,
+        fields: &str,
         user_key: &str,
         body: &crate::types::UserPhoto,
     ) -> ClientResult<crate::types::UserPhoto> {
+        Ok(self
+            .photos_patch_with_response(fields, user_key, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * As opposed to `photos_patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn photos_patch_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> ClientResult<crate::Response<crate::types::UserPhoto>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/photos/thumbnail",
+                "/admin/directory/v1/users/{}/photos/thumbnail?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -726,16 +1258,35 @@ impl Users {
      *
      * * `user_key: &str` -- Identifies the target user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      */
-    pub async fn sign_out(&self, user_key: &str) -> ClientResult<()> {
+    pub async fn sign_out(&self, fields: &str, user_key: &str) -> ClientResult<()> {
+        self.sign_out_with_response(fields, user_key).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/signOut` endpoint.
+     *
+     * As opposed to `sign_out`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn sign_out_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/signOut",
+                "/admin/directory/v1/users/{}/signOut?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -755,18 +1306,39 @@ impl Users {
      */
     pub async fn undelete(
         &self,
+        fields: &str,
         user_key: &str,
         body: &crate::types::UserUndelete,
     ) -> ClientResult<()> {
+        self.undelete_with_response(fields, user_key, body).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/undelete` endpoint.
+     *
+     * As opposed to `undelete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn undelete_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+        body: &crate::types::UserUndelete,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/undelete",
+                "/admin/directory/v1/users/{}/undelete?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
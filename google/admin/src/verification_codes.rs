@@ -20,16 +20,38 @@ impl VerificationCodes {
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
      */
-    pub async fn list(&self, user_key: &str) -> ClientResult<crate::types::VerificationCodes> {
+    pub async fn list(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::types::VerificationCodes> {
+        Ok(self.list_with_response(fields, user_key).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/verificationCodes` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<crate::types::VerificationCodes>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/verificationCodes",
+                "/admin/directory/v1/users/{}/verificationCodes?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -47,16 +69,35 @@ impl VerificationCodes {
      *
      * * `user_key: &str` -- Email or immutable ID of the user.
      */
-    pub async fn generate(&self, user_key: &str) -> ClientResult<()> {
+    pub async fn generate(&self, fields: &str, user_key: &str) -> ClientResult<()> {
+        self.generate_with_response(fields, user_key).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/verificationCodes/generate` endpoint.
+     *
+     * As opposed to `generate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn generate_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/verificationCodes/generate",
+                "/admin/directory/v1/users/{}/verificationCodes/generate?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -74,16 +115,35 @@ impl VerificationCodes {
      *
      * * `user_key: &str` -- Email or immutable ID of the user.
      */
-    pub async fn invalidate(&self, user_key: &str) -> ClientResult<()> {
+    pub async fn invalidate(&self, fields: &str, user_key: &str) -> ClientResult<()> {
+        self.invalidate_with_response(fields, user_key).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/verificationCodes/invalidate` endpoint.
+     *
+     * As opposed to `invalidate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn invalidate_with_response(
+        &self,
+        fields: &str,
+        user_key: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/directory/v1/users/{}/verificationCodes/invalidate",
+                "/admin/directory/v1/users/{}/verificationCodes/invalidate?{}",
                 crate::progenitor_support::encode_path(user_key),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -29,12 +29,43 @@ impl Acl {
      */
     pub async fn list(
         &self,
+        fields: &str,
         calendar_id: &str,
         max_results: i64,
         page_token: &str,
         show_deleted: bool,
+        sync_token: &str,
     ) -> ClientResult<Vec<crate::types::AclRule>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                calendar_id,
+                max_results,
+                page_token,
+                show_deleted,
+                sync_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+        sync_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AclRule>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -44,6 +75,9 @@ impl Acl {
         if show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -53,9 +87,9 @@ impl Acl {
             ),
             None,
         );
-        let resp: crate::types::Acl = self
+        let resp: crate::Response<crate::types::Acl> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +99,7 @@ impl Acl {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
@@ -76,10 +110,15 @@ impl Acl {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         calendar_id: &str,
         show_deleted: bool,
     ) -> ClientResult<Vec<crate::types::AclRule>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
@@ -144,6 +183,194 @@ impl Acl {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        show_deleted: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::AclRule>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/acl?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Acl = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        show_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AclRule>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            calendar_id,
+            show_deleted,
+            None,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once, along with the `next_sync_token` to pass as `sync_token` the next time you sync. Leave `sync_token` empty to do a full sync.
+     *
+     * If the sync token has expired this returns `ClientError::FullSyncRequired`, clear your storage and do a full sync.
+     *
+     * Returns the rules in the access control list for the calendar.
+     */
+    pub async fn sync(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        show_deleted: bool,
+        sync_token: &str,
+    ) -> ClientResult<crate::types::Acl> {
+        let fields = crate::utils::fields_with_sync_tokens(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/acl?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+        let mut resp: crate::types::Acl = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+            .map_err(crate::utils::full_sync_required)?;
+
+        let mut items = resp.items;
+        let mut page = resp.next_page_token.to_string();
+
+        // Paginate if we should.
+        while !page.is_empty() {
+            if !url.contains('?') {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}?pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            } else {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}&pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            }
+
+            items.append(&mut resp.items);
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
+                page = resp.next_page_token.to_string();
+            } else {
+                page = "".to_string();
+            }
+        }
+
+        // The last page has the next sync token, give it back with all the items.
+        resp.items = items;
+        Ok(resp)
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/acl` endpoint.
      *
@@ -156,11 +383,32 @@ impl Acl {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         calendar_id: &str,
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> ClientResult<crate::types::AclRule> {
+        Ok(self
+            .insert_with_response(fields, calendar_id, send_notifications, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
@@ -177,7 +425,7 @@ impl Acl {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -204,13 +452,46 @@ impl Acl {
      */
     pub async fn watch(
         &self,
+        fields: &str,
         calendar_id: &str,
         max_results: i64,
         page_token: &str,
         show_deleted: bool,
+        sync_token: &str,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .watch_with_response(
+                fields,
+                calendar_id,
+                max_results,
+                page_token,
+                show_deleted,
+                sync_token,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/acl/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watch_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+        sync_token: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -220,6 +501,9 @@ impl Acl {
         if show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -230,7 +514,7 @@ impl Acl {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -251,19 +535,42 @@ impl Acl {
      */
     pub async fn get(
         &self,
+        fields: &str,
         calendar_id: &str,
         rule_id: &str,
     ) -> ClientResult<crate::types::AclRule> {
+        Ok(self
+            .get_with_response(fields, calendar_id, rule_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/calendars/{}/acl/{}",
+                "/calendars/{}/acl/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
                 crate::progenitor_support::encode_path(rule_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -285,12 +592,34 @@ impl Acl {
      */
     pub async fn update(
         &self,
+        fields: &str,
         calendar_id: &str,
         rule_id: &str,
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> ClientResult<crate::types::AclRule> {
+        Ok(self
+            .update_with_response(fields, calendar_id, rule_id, send_notifications, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
@@ -308,7 +637,7 @@ impl Acl {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -327,17 +656,38 @@ impl Acl {
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `rule_id: &str` -- ETag of the collection.
      */
-    pub async fn delete(&self, calendar_id: &str, rule_id: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, calendar_id: &str, rule_id: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, calendar_id, rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/calendars/{}/acl/{}",
+                "/calendars/{}/acl/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
                 crate::progenitor_support::encode_path(rule_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -359,12 +709,34 @@ impl Acl {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         calendar_id: &str,
         rule_id: &str,
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> ClientResult<crate::types::AclRule> {
+        Ok(self
+            .patch_with_response(fields, calendar_id, rule_id, send_notifications, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> ClientResult<crate::Response<crate::types::AclRule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
@@ -382,7 +754,7 @@ impl Acl {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
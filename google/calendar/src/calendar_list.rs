@@ -31,13 +31,46 @@ impl CalendarList {
      */
     pub async fn list(
         &self,
+        fields: &str,
         max_results: i64,
         min_access_role: crate::types::MinAccessRole,
         page_token: &str,
         show_deleted: bool,
         show_hidden: bool,
+        sync_token: &str,
     ) -> ClientResult<Vec<crate::types::CalendarListEntry>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                max_results,
+                min_access_role,
+                page_token,
+                show_deleted,
+                show_hidden,
+                sync_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        sync_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CalendarListEntry>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -53,13 +86,16 @@ impl CalendarList {
         if show_hidden {
             query_args.push(("showHidden".to_string(), show_hidden.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/users/me/calendarList?{}", query_), None);
-        let resp: crate::types::CalendarList = self
+        let resp: crate::Response<crate::types::CalendarList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,7 +105,7 @@ impl CalendarList {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/users/me/calendarList` endpoint.
@@ -80,11 +116,16 @@ impl CalendarList {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         min_access_role: crate::types::MinAccessRole,
         show_deleted: bool,
         show_hidden: bool,
     ) -> ClientResult<Vec<crate::types::CalendarListEntry>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !min_access_role.to_string().is_empty() {
             query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
         }
@@ -150,6 +191,198 @@ impl CalendarList {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CalendarListEntry>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !min_access_role.to_string().is_empty() {
+            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden {
+            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users/me/calendarList?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::CalendarList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CalendarListEntry>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            min_access_role,
+            show_deleted,
+            show_hidden,
+            None,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once, along with the `next_sync_token` to pass as `sync_token` the next time you sync. Leave `sync_token` empty to do a full sync.
+     *
+     * If the sync token has expired this returns `ClientError::FullSyncRequired`, clear your storage and do a full sync.
+     *
+     * Returns the calendars on the user's calendar list.
+     */
+    pub async fn sync(
+        &self,
+        fields: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        sync_token: &str,
+    ) -> ClientResult<crate::types::CalendarList> {
+        let fields = crate::utils::fields_with_sync_tokens(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden {
+            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+        }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users/me/calendarList?{}", query_), None);
+        let mut resp: crate::types::CalendarList = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+            .map_err(crate::utils::full_sync_required)?;
+
+        let mut items = resp.items;
+        let mut page = resp.next_page_token.to_string();
+
+        // Paginate if we should.
+        while !page.is_empty() {
+            if !url.contains('?') {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}?pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            } else {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}&pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            }
+
+            items.append(&mut resp.items);
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
+                page = resp.next_page_token.to_string();
+            } else {
+                page = "".to_string();
+            }
+        }
+
+        // The last page has the next sync token, give it back with all the items.
+        resp.items = items;
+        Ok(resp)
+    }
     /**
      * This function performs a `POST` to the `/users/me/calendarList` endpoint.
      *
@@ -161,19 +394,39 @@ impl CalendarList {
      */
     pub async fn list_insert(
         &self,
+        fields: &str,
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> ClientResult<crate::types::CalendarListEntry> {
+        Ok(self
+            .list_insert_with_response(fields, color_rgb_format, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_insert_with_response(
+        &self,
+        fields: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if color_rgb_format {
             query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/users/me/calendarList?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -202,14 +455,49 @@ impl CalendarList {
      */
     pub async fn list_watch(
         &self,
+        fields: &str,
         max_results: i64,
         min_access_role: crate::types::MinAccessRole,
         page_token: &str,
         show_deleted: bool,
         show_hidden: bool,
+        sync_token: &str,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .list_watch_with_response(
+                fields,
+                max_results,
+                min_access_role,
+                page_token,
+                show_deleted,
+                show_hidden,
+                sync_token,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/users/me/calendarList/watch` endpoint.
+     *
+     * As opposed to `list_watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_watch_with_response(
+        &self,
+        fields: &str,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        sync_token: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
@@ -225,12 +513,15 @@ impl CalendarList {
         if show_hidden {
             query_args.push(("showHidden".to_string(), show_hidden.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/users/me/calendarList/watch?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -250,17 +541,36 @@ impl CalendarList {
      */
     pub async fn list_get(
         &self,
+        fields: &str,
         calendar_id: &str,
     ) -> ClientResult<crate::types::CalendarListEntry> {
+        Ok(self.list_get_with_response(fields, calendar_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_get_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/users/me/calendarList/{}",
+                "/users/me/calendarList/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -281,14 +591,35 @@ impl CalendarList {
      */
     pub async fn list_update(
         &self,
+        fields: &str,
         calendar_id: &str,
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> ClientResult<crate::types::CalendarListEntry> {
+        Ok(self
+            .list_update_with_response(fields, calendar_id, color_rgb_format, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_update_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if color_rgb_format {
             query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -299,7 +630,7 @@ impl CalendarList {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -317,16 +648,35 @@ impl CalendarList {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      */
-    pub async fn list_delete(&self, calendar_id: &str) -> ClientResult<()> {
+    pub async fn list_delete(&self, fields: &str, calendar_id: &str) -> ClientResult<()> {
+        self.list_delete_with_response(fields, calendar_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_delete_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/users/me/calendarList/{}",
+                "/users/me/calendarList/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -347,14 +697,35 @@ impl CalendarList {
      */
     pub async fn list_patch(
         &self,
+        fields: &str,
         calendar_id: &str,
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> ClientResult<crate::types::CalendarListEntry> {
+        Ok(self
+            .list_patch_with_response(fields, calendar_id, color_rgb_format, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_patch_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> ClientResult<crate::Response<crate::types::CalendarListEntry>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if color_rgb_format {
             query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -365,7 +736,7 @@ impl CalendarList {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
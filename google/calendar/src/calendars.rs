@@ -18,11 +18,29 @@ impl Calendars {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         body: &crate::types::Calendar,
     ) -> ClientResult<crate::types::Calendar> {
-        let url = self.client.url("/calendars", None);
+        Ok(self.insert_with_response(fields, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        body: &crate::types::Calendar,
+    ) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/calendars?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -40,16 +58,38 @@ impl Calendars {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      */
-    pub async fn get(&self, calendar_id: &str) -> ClientResult<crate::types::Calendar> {
+    pub async fn get(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+    ) -> ClientResult<crate::types::Calendar> {
+        Ok(self.get_with_response(fields, calendar_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/calendars/{}",
+                "/calendars/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,18 +109,41 @@ impl Calendars {
      */
     pub async fn update(
         &self,
+        fields: &str,
         calendar_id: &str,
         body: &crate::types::Calendar,
     ) -> ClientResult<crate::types::Calendar> {
+        Ok(self
+            .update_with_response(fields, calendar_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/calendars/{calendarId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/calendars/{}",
+                "/calendars/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
+                query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -98,16 +161,35 @@ impl Calendars {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      */
-    pub async fn delete(&self, calendar_id: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, calendar_id: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, calendar_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/calendars/{calendarId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/calendars/{}",
+                "/calendars/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,18 +209,41 @@ impl Calendars {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         calendar_id: &str,
         body: &crate::types::Calendar,
     ) -> ClientResult<crate::types::Calendar> {
+        Ok(self
+            .patch_with_response(fields, calendar_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> ClientResult<crate::Response<crate::types::Calendar>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/calendars/{}",
+                "/calendars/{}?{}",
                 crate::progenitor_support::encode_path(calendar_id),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -156,16 +261,35 @@ impl Calendars {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      */
-    pub async fn clear(&self, calendar_id: &str) -> ClientResult<()> {
+    pub async fn clear(&self, fields: &str, calendar_id: &str) -> ClientResult<()> {
+        self.clear_with_response(fields, calendar_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/clear` endpoint.
+     *
+     * As opposed to `clear`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn clear_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/calendars/{}/clear",
+                "/calendars/{}/clear?{}",
                 crate::progenitor_support::encode_path(calendar_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -16,10 +16,26 @@ impl Colors {
      *
      * Returns the color definitions for calendars and events.
      */
-    pub async fn get(&self) -> ClientResult<crate::types::Colors> {
-        let url = self.client.url("/colors", None);
+    pub async fn get(&self, fields: &str) -> ClientResult<crate::types::Colors> {
+        Ok(self.get_with_response(fields).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/colors` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::Colors>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/colors?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
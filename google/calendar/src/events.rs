@@ -52,6 +52,7 @@ impl Events {
      */
     pub async fn list(
         &self,
+        fields: &str,
         calendar_id: &str,
         i_cal_uid: &str,
         max_attendees: i64,
@@ -64,12 +65,66 @@ impl Events {
         show_deleted: bool,
         show_hidden_invitations: bool,
         single_events: bool,
+        sync_token: &str,
         time_max: &str,
         time_min: &str,
         time_zone: &str,
         updated_min: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                calendar_id,
+                i_cal_uid,
+                max_attendees,
+                max_results,
+                order_by,
+                page_token,
+                private_extended_property,
+                q,
+                shared_extended_property,
+                show_deleted,
+                show_hidden_invitations,
+                single_events,
+                sync_token,
+                time_max,
+                time_min,
+                time_zone,
+                updated_min,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        sync_token: &str,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !i_cal_uid.is_empty() {
             query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
         }
@@ -112,6 +167,9 @@ impl Events {
         if single_events {
             query_args.push(("singleEvents".to_string(), single_events.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         if !time_max.is_empty() {
             query_args.push(("timeMax".to_string(), time_max.to_string()));
         }
@@ -133,9 +191,9 @@ impl Events {
             ),
             None,
         );
-        let resp: crate::types::Events = self
+        let resp: crate::Response<crate::types::Events> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -145,7 +203,7 @@ impl Events {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
@@ -156,6 +214,7 @@ impl Events {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         calendar_id: &str,
         i_cal_uid: &str,
         max_attendees: i64,
@@ -171,7 +230,11 @@ impl Events {
         time_zone: &str,
         updated_min: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !i_cal_uid.is_empty() {
             query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
         }
@@ -281,6 +344,294 @@ impl Events {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !i_cal_uid.is_empty() {
+            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+        }
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !private_extended_property.is_empty() {
+            query_args.push((
+                "privateExtendedProperty".to_string(),
+                private_extended_property.join(" "),
+            ));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !shared_extended_property.is_empty() {
+            query_args.push((
+                "sharedExtendedProperty".to_string(),
+                shared_extended_property.join(" "),
+            ));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden_invitations {
+            query_args.push((
+                "showHiddenInvitations".to_string(),
+                show_hidden_invitations.to_string(),
+            ));
+        }
+        if single_events {
+            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+        }
+        if !time_max.is_empty() {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if !time_min.is_empty() {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        if !updated_min.is_empty() {
+            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/events?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Events = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            order_by,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+            None,
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once, along with the `next_sync_token` to pass as `sync_token` the next time you sync. Leave `sync_token` empty to do a full sync.
+     *
+     * If the sync token has expired this returns `ClientError::FullSyncRequired`, clear your storage and do a full sync.
+     *
+     * Returns events on the specified calendar.
+     */
+    pub async fn sync(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        max_attendees: i64,
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_zone: &str,
+        sync_token: &str,
+    ) -> ClientResult<crate::types::Events> {
+        let fields = crate::utils::fields_with_sync_tokens(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if show_hidden_invitations {
+            query_args.push((
+                "showHiddenInvitations".to_string(),
+                show_hidden_invitations.to_string(),
+            ));
+        }
+        if single_events {
+            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+        }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/events?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                query_
+            ),
+            None,
+        );
+        let mut resp: crate::types::Events = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+            .map_err(crate::utils::full_sync_required)?;
+
+        let mut items = resp.items;
+        let mut page = resp.next_page_token.to_string();
+
+        // Paginate if we should.
+        while !page.is_empty() {
+            if !url.contains('?') {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}?pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            } else {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}&pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            }
+
+            items.append(&mut resp.items);
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
+                page = resp.next_page_token.to_string();
+            } else {
+                page = "".to_string();
+            }
+        }
+
+        // The last page has the next sync token, give it back with all the items.
+        resp.items = items;
+        Ok(resp)
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events` endpoint.
      *
@@ -299,6 +650,7 @@ impl Events {
      */
     pub async fn insert(
         &self,
+        fields: &str,
         calendar_id: &str,
         conference_data_version: u64,
         max_attendees: i64,
@@ -307,6 +659,36 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> ClientResult<crate::types::Event> {
+        Ok(self
+            .insert_with_response(
+                fields,
+                calendar_id,
+                conference_data_version,
+                max_attendees,
+                send_notifications,
+                send_updates,
+                supports_attachments,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `insert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn insert_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
             query_args.push((
@@ -314,6 +696,9 @@ impl Events {
                 conference_data_version.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_attendees > 0 {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
@@ -342,7 +727,7 @@ impl Events {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -364,11 +749,36 @@ impl Events {
      */
     pub async fn import(
         &self,
+        fields: &str,
         calendar_id: &str,
         conference_data_version: u64,
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> ClientResult<crate::types::Event> {
+        Ok(self
+            .import_with_response(
+                fields,
+                calendar_id,
+                conference_data_version,
+                supports_attachments,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/import` endpoint.
+     *
+     * As opposed to `import`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn import_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        conference_data_version: u64,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
             query_args.push((
@@ -376,6 +786,9 @@ impl Events {
                 conference_data_version.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if supports_attachments {
             query_args.push((
                 "supportsAttachments".to_string(),
@@ -392,7 +805,7 @@ impl Events {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -417,12 +830,34 @@ impl Events {
      */
     pub async fn quick_add(
         &self,
+        fields: &str,
         calendar_id: &str,
         text: &str,
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> ClientResult<crate::types::Event> {
+        Ok(self
+            .quick_add_with_response(fields, calendar_id, text, send_notifications, send_updates)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/quickAdd` endpoint.
+     *
+     * As opposed to `quick_add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn quick_add_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        text: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
@@ -445,7 +880,7 @@ impl Events {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -495,6 +930,7 @@ impl Events {
      */
     pub async fn watch(
         &self,
+        fields: &str,
         calendar_id: &str,
         i_cal_uid: &str,
         max_attendees: i64,
@@ -507,13 +943,69 @@ impl Events {
         show_deleted: bool,
         show_hidden_invitations: bool,
         single_events: bool,
+        sync_token: &str,
         time_max: &str,
         time_min: &str,
         time_zone: &str,
         updated_min: &str,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .watch_with_response(
+                fields,
+                calendar_id,
+                i_cal_uid,
+                max_attendees,
+                max_results,
+                order_by,
+                page_token,
+                private_extended_property,
+                q,
+                shared_extended_property,
+                show_deleted,
+                show_hidden_invitations,
+                single_events,
+                sync_token,
+                time_max,
+                time_min,
+                time_zone,
+                updated_min,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watch_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        sync_token: &str,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !i_cal_uid.is_empty() {
             query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
         }
@@ -556,6 +1048,9 @@ impl Events {
         if single_events {
             query_args.push(("singleEvents".to_string(), single_events.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         if !time_max.is_empty() {
             query_args.push(("timeMax".to_string(), time_max.to_string()));
         }
@@ -578,7 +1073,7 @@ impl Events {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -602,12 +1097,34 @@ impl Events {
      */
     pub async fn get(
         &self,
+        fields: &str,
         calendar_id: &str,
         event_id: &str,
         max_attendees: i64,
         time_zone: &str,
     ) -> ClientResult<crate::types::Event> {
+        Ok(self
+            .get_with_response(fields, calendar_id, event_id, max_attendees, time_zone)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        time_zone: &str,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_attendees > 0 {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
@@ -625,7 +1142,7 @@ impl Events {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -654,6 +1171,7 @@ impl Events {
      */
     pub async fn update(
         &self,
+        fields: &str,
         calendar_id: &str,
         event_id: &str,
         conference_data_version: u64,
@@ -663,6 +1181,38 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> ClientResult<crate::types::Event> {
+        Ok(self
+            .update_with_response(
+                fields,
+                calendar_id,
+                event_id,
+                conference_data_version,
+                max_attendees,
+                send_notifications,
+                send_updates,
+                supports_attachments,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
             query_args.push((
@@ -670,6 +1220,9 @@ impl Events {
                 conference_data_version.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_attendees > 0 {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
@@ -699,7 +1252,7 @@ impl Events {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -724,12 +1277,39 @@ impl Events {
      */
     pub async fn delete(
         &self,
+        fields: &str,
         calendar_id: &str,
         event_id: &str,
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> ClientResult<()> {
+        self.delete_with_response(
+            fields,
+            calendar_id,
+            event_id,
+            send_notifications,
+            send_updates,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
@@ -750,7 +1330,7 @@ impl Events {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -779,6 +1359,7 @@ impl Events {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         calendar_id: &str,
         event_id: &str,
         conference_data_version: u64,
@@ -788,6 +1369,38 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> ClientResult<crate::types::Event> {
+        Ok(self
+            .patch_with_response(
+                fields,
+                calendar_id,
+                event_id,
+                conference_data_version,
+                max_attendees,
+                send_notifications,
+                send_updates,
+                supports_attachments,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
             query_args.push((
@@ -795,6 +1408,9 @@ impl Events {
                 conference_data_version.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_attendees > 0 {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
@@ -824,7 +1440,7 @@ impl Events {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -854,6 +1470,7 @@ impl Events {
      */
     pub async fn instances(
         &self,
+        fields: &str,
         calendar_id: &str,
         event_id: &str,
         max_attendees: i64,
@@ -865,7 +1482,46 @@ impl Events {
         time_min: &str,
         time_zone: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .instances_with_response(
+                fields,
+                calendar_id,
+                event_id,
+                max_attendees,
+                max_results,
+                original_start,
+                page_token,
+                show_deleted,
+                time_max,
+                time_min,
+                time_zone,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `instances`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn instances_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        max_results: i64,
+        original_start: &str,
+        page_token: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_attendees > 0 {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
@@ -900,9 +1556,9 @@ impl Events {
             ),
             None,
         );
-        let resp: crate::types::Events = self
+        let resp: crate::Response<crate::types::Events> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -912,7 +1568,7 @@ impl Events {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
@@ -923,6 +1579,7 @@ impl Events {
      */
     pub async fn get_all_instances(
         &self,
+        fields: &str,
         calendar_id: &str,
         event_id: &str,
         max_attendees: i64,
@@ -932,7 +1589,11 @@ impl Events {
         time_min: &str,
         time_zone: &str,
     ) -> ClientResult<Vec<crate::types::Event>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_attendees > 0 {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
@@ -1013,6 +1674,136 @@ impl Events {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `get_all_instances`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_instances_pages_stream(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if !original_start.is_empty() {
+            query_args.push(("originalStart".to_string(), original_start.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if !time_max.is_empty() {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if !time_min.is_empty() {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/calendars/{}/events/{}/instances?{}",
+                crate::progenitor_support::encode_path(calendar_id),
+                crate::progenitor_support::encode_path(event_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Events = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `get_all_instances`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_instances_stream(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_instances_pages_stream(
+            fields,
+            calendar_id,
+            event_id,
+            max_attendees,
+            original_start,
+            show_deleted,
+            time_max,
+            time_min,
+            time_zone,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/{eventId}/move` endpoint.
      *
@@ -1030,16 +1821,46 @@ impl Events {
      */
     pub async fn mv(
         &self,
+        fields: &str,
         calendar_id: &str,
         event_id: &str,
         destination: &str,
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> ClientResult<crate::types::Event> {
+        Ok(self
+            .mv_with_response(
+                fields,
+                calendar_id,
+                event_id,
+                destination,
+                send_notifications,
+                send_updates,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/calendars/{calendarId}/events/{eventId}/move` endpoint.
+     *
+     * As opposed to `mv`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn mv_with_response(
+        &self,
+        fields: &str,
+        calendar_id: &str,
+        event_id: &str,
+        destination: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !destination.is_empty() {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
@@ -1060,7 +1881,7 @@ impl Events {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
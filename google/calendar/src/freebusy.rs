@@ -18,11 +18,29 @@ impl Freebusy {
      */
     pub async fn query(
         &self,
+        fields: &str,
         body: &crate::types::FreeBusyRequest,
     ) -> ClientResult<crate::types::FreeBusyResponse> {
-        let url = self.client.url("/freeBusy", None);
+        Ok(self.query_with_response(fields, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/freeBusy` endpoint.
+     *
+     * As opposed to `query`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn query_with_response(
+        &self,
+        fields: &str,
+        body: &crate::types::FreeBusyRequest,
+    ) -> ClientResult<crate::Response<crate::types::FreeBusyResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/freeBusy?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -27,23 +27,48 @@ impl Settings {
      */
     pub async fn list(
         &self,
+        fields: &str,
         max_results: i64,
         page_token: &str,
+        sync_token: &str,
     ) -> ClientResult<Vec<crate::types::Setting>> {
+        Ok(self
+            .list_with_response(fields, max_results, page_token, sync_token)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/settings` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        max_results: i64,
+        page_token: &str,
+        sync_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Setting>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
         if !page_token.is_empty() {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/users/me/settings?{}", query_), None);
-        let resp: crate::types::Settings = self
+        let resp: crate::Response<crate::types::Settings> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -53,7 +78,7 @@ impl Settings {
             .await?;
 
         // Return our response data.
-        Ok(resp.items.to_vec())
+        Ok(resp.map(|r| r.items.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/users/me/settings` endpoint.
@@ -62,8 +87,16 @@ impl Settings {
      *
      * Returns all user settings for the authenticated user.
      */
-    pub async fn list_all(&self) -> ClientResult<Vec<crate::types::Setting>> {
-        let url = self.client.url("/users/me/settings", None);
+    pub async fn list_all(&self, fields: &str) -> ClientResult<Vec<crate::types::Setting>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users/me/settings?{}", query_), None);
         let mut resp: crate::types::Settings = self
             .client
             .get(
@@ -116,6 +149,167 @@ impl Settings {
         // Return our response data.
         Ok(items)
     }
+    /**
+     * This function performs a `GET` to the `/users/me/settings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Setting>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users/me/settings?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::Settings = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/settings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Setting>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(fields, None))
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/settings` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once, along with the `next_sync_token` to pass as `sync_token` the next time you sync. Leave `sync_token` empty to do a full sync.
+     *
+     * If the sync token has expired this returns `ClientError::FullSyncRequired`, clear your storage and do a full sync.
+     *
+     * Returns all user settings for the authenticated user.
+     */
+    pub async fn sync(
+        &self,
+        fields: &str,
+        sync_token: &str,
+    ) -> ClientResult<crate::types::Settings> {
+        let fields = crate::utils::fields_with_sync_tokens(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/users/me/settings?{}", query_), None);
+        let mut resp: crate::types::Settings = self
+            .client
+            .get(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+            .map_err(crate::utils::full_sync_required)?;
+
+        let mut items = resp.items;
+        let mut page = resp.next_page_token.to_string();
+
+        // Paginate if we should.
+        while !page.is_empty() {
+            if !url.contains('?') {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}?pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            } else {
+                resp = self
+                    .client
+                    .get(
+                        &format!("{}&pageToken={}", url, page),
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await
+                    .map_err(crate::utils::full_sync_required)?;
+            }
+
+            items.append(&mut resp.items);
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
+                page = resp.next_page_token.to_string();
+            } else {
+                page = "".to_string();
+            }
+        }
+
+        // The last page has the next sync token, give it back with all the items.
+        resp.items = items;
+        Ok(resp)
+    }
     /**
      * This function performs a `POST` to the `/users/me/settings/watch` endpoint.
      *
@@ -132,23 +326,49 @@ impl Settings {
      */
     pub async fn watch(
         &self,
+        fields: &str,
         max_results: i64,
         page_token: &str,
+        sync_token: &str,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .watch_with_response(fields, max_results, page_token, sync_token, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/users/me/settings/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watch_with_response(
+        &self,
+        fields: &str,
+        max_results: i64,
+        page_token: &str,
+        sync_token: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if max_results > 0 {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
         if !page_token.is_empty() {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
+        if !sync_token.is_empty() {
+            query_args.push(("syncToken".to_string(), sync_token.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
             .url(&format!("/users/me/settings/watch?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -166,16 +386,34 @@ impl Settings {
      *
      * * `setting: &str` -- The id of the user setting.
      */
-    pub async fn get(&self, setting: &str) -> ClientResult<crate::types::Setting> {
+    pub async fn get(&self, fields: &str, setting: &str) -> ClientResult<crate::types::Setting> {
+        Ok(self.get_with_response(fields, setting).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/users/me/settings/{setting}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        setting: &str,
+    ) -> ClientResult<crate::Response<crate::types::Setting>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/users/me/settings/{}",
+                "/users/me/settings/{}?{}",
                 crate::progenitor_support::encode_path(setting),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -23,7 +23,7 @@ pub struct Acl {
     )]
     pub items: Vec<AclRule>,
     /**
-     * ETag of the collection.
+     * Type of the collection ("calendar#acl").
      */
     #[serde(
         default,
@@ -32,7 +32,7 @@ pub struct Acl {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Token used to access the next page of this result. Omitted if no further results are available, in which case nextSyncToken is provided.
      */
     #[serde(
         default,
@@ -42,7 +42,7 @@ pub struct Acl {
     )]
     pub next_page_token: String,
     /**
-     * ETag of the collection.
+     * Token used at a later point in time to retrieve only the entries that have changed since this result was returned. Omitted if further results are available, in which case nextPageToken is provided.
      */
     #[serde(
         default,
@@ -57,7 +57,11 @@ pub struct Acl {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Scope {
     /**
-     * The extent to which calendar access is granted by this ACL rule.
+     * The type of the scope. Possible values are:
+     *  - "default" - The public scope. This is the default value.
+     *  - "user" - Limits the scope to a single user.
+     *  - "group" - Limits the scope to a group.
+     *  - "domain" - Limits the scope to a domain.  Note: The permissions granted to the "default", or public, scope apply to any user, authenticated or not.
      */
     #[serde(
         default,
@@ -67,7 +71,7 @@ pub struct Scope {
     )]
     pub type_: String,
     /**
-     * The extent to which calendar access is granted by this ACL rule.
+     * The email address of a user or group, or the name of a domain, depending on the scope type. Omitted for type "default".
      */
     #[serde(
         default,
@@ -80,7 +84,7 @@ pub struct Scope {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AclRule {
     /**
-     * ETag of the collection.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -89,7 +93,7 @@ pub struct AclRule {
     )]
     pub etag: String,
     /**
-     * ETag of the collection.
+     * Identifier of the Access Control List (ACL) rule. See Sharing calendars.
      */
     #[serde(
         default,
@@ -98,7 +102,7 @@ pub struct AclRule {
     )]
     pub id: String,
     /**
-     * ETag of the collection.
+     * Type of the resource ("calendar#aclRule").
      */
     #[serde(
         default,
@@ -107,7 +111,12 @@ pub struct AclRule {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * The role assigned to the scope. Possible values are:
+     *  - "none" - Provides no access.
+     *  - "freeBusyReader" - Provides read access to free/busy information.
+     *  - "reader" - Provides read access to the calendar. Private events will appear to users with reader access, but event details will be hidden.
+     *  - "writer" - Provides read and write access to the calendar. Private events will appear to users with writer access, and event details will be visible.
+     *  - "owner" - Provides ownership of the calendar. This role has all of the permissions of the writer role with the additional ability to see and manipulate ACLs.
      */
     #[serde(
         default,
@@ -131,7 +140,7 @@ pub struct Calendar {
     )]
     pub conference_properties: Option<ConferenceProperties>,
     /**
-     * ETag of the collection.
+     * Description of the calendar. Optional.
      */
     #[serde(
         default,
@@ -140,7 +149,7 @@ pub struct Calendar {
     )]
     pub description: String,
     /**
-     * ETag of the collection.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -149,7 +158,7 @@ pub struct Calendar {
     )]
     pub etag: String,
     /**
-     * ETag of the collection.
+     * Identifier of the calendar. To retrieve IDs call the calendarList.list() method.
      */
     #[serde(
         default,
@@ -158,7 +167,7 @@ pub struct Calendar {
     )]
     pub id: String,
     /**
-     * ETag of the collection.
+     * Type of the resource ("calendar#calendar").
      */
     #[serde(
         default,
@@ -167,7 +176,7 @@ pub struct Calendar {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Geographic location of the calendar as free-form text. Optional.
      */
     #[serde(
         default,
@@ -176,7 +185,7 @@ pub struct Calendar {
     )]
     pub location: String,
     /**
-     * ETag of the collection.
+     * Title of the calendar.
      */
     #[serde(
         default,
@@ -185,7 +194,7 @@ pub struct Calendar {
     )]
     pub summary: String,
     /**
-     * ETag of the collection.
+     * The time zone of the calendar. (Formatted as an IANA Time Zone Database name, e.g. "Europe/Zurich".) Optional.
      */
     #[serde(
         default,
@@ -217,7 +226,7 @@ pub struct CalendarList {
     )]
     pub items: Vec<CalendarListEntry>,
     /**
-     * ETag of the collection.
+     * Type of the collection ("calendar#calendarList").
      */
     #[serde(
         default,
@@ -226,7 +235,7 @@ pub struct CalendarList {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Token used to access the next page of this result. Omitted if no further results are available, in which case nextSyncToken is provided.
      */
     #[serde(
         default,
@@ -236,7 +245,7 @@ pub struct CalendarList {
     )]
     pub next_page_token: String,
     /**
-     * ETag of the collection.
+     * Token used at a later point in time to retrieve only the entries that have changed since this result was returned. Omitted if further results are available, in which case nextPageToken is provided.
      */
     #[serde(
         default,
@@ -251,7 +260,7 @@ pub struct CalendarList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NotificationSettings {
     /**
-     * The notifications that the authenticated user is receiving for this calendar.
+     * The list of notifications set for this calendar.
      */
     #[serde(
         default,
@@ -264,7 +273,11 @@ pub struct NotificationSettings {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CalendarListEntry {
     /**
-     * ETag of the collection.
+     * The effective access role that the authenticated user has on the calendar. Read-only. Possible values are:
+     *  - "freeBusyReader" - Provides read access to free/busy information.
+     *  - "reader" - Provides read access to the calendar. Private events will appear to users with reader access, but event details will be hidden.
+     *  - "writer" - Provides read and write access to the calendar. Private events will appear to users with writer access, and event details will be visible.
+     *  - "owner" - Provides ownership of the calendar. This role has all of the permissions of the writer role with the additional ability to see and manipulate ACLs.
      */
     #[serde(
         default,
@@ -274,7 +287,7 @@ pub struct CalendarListEntry {
     )]
     pub access_role: String,
     /**
-     * ETag of the collection.
+     * The main color of the calendar in the hexadecimal format "#0088aa". This property supersedes the index-based colorId property. To set or change this property, you need to specify colorRgbFormat=true in the parameters of the insert, update and patch methods. Optional.
      */
     #[serde(
         default,
@@ -284,7 +297,7 @@ pub struct CalendarListEntry {
     )]
     pub background_color: String,
     /**
-     * ETag of the collection.
+     * The color of the calendar. This is an ID referring to an entry in the calendar section of the colors definition (see the colors endpoint). This property is superseded by the backgroundColor and foregroundColor properties and can be ignored when using these properties. Optional.
      */
     #[serde(
         default,
@@ -318,7 +331,7 @@ pub struct CalendarListEntry {
     )]
     pub deleted: bool,
     /**
-     * ETag of the collection.
+     * Description of the calendar. Optional. Read-only.
      */
     #[serde(
         default,
@@ -327,7 +340,7 @@ pub struct CalendarListEntry {
     )]
     pub description: String,
     /**
-     * ETag of the collection.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -336,7 +349,7 @@ pub struct CalendarListEntry {
     )]
     pub etag: String,
     /**
-     * ETag of the collection.
+     * The foreground color of the calendar in the hexadecimal format "#ffffff". This property supersedes the index-based colorId property. To set or change this property, you need to specify colorRgbFormat=true in the parameters of the insert, update and patch methods. Optional.
      */
     #[serde(
         default,
@@ -346,7 +359,7 @@ pub struct CalendarListEntry {
     )]
     pub foreground_color: String,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether the calendar has been hidden from the list. Optional. The attribute is only returned when the calendar is hidden, in which case the value is true.
      */
     #[serde(
         default,
@@ -354,7 +367,7 @@ pub struct CalendarListEntry {
     )]
     pub hidden: bool,
     /**
-     * ETag of the collection.
+     * Identifier of the calendar.
      */
     #[serde(
         default,
@@ -363,7 +376,7 @@ pub struct CalendarListEntry {
     )]
     pub id: String,
     /**
-     * ETag of the collection.
+     * Type of the resource ("calendar#calendarListEntry").
      */
     #[serde(
         default,
@@ -372,7 +385,7 @@ pub struct CalendarListEntry {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Geographic location of the calendar as free-form text. Optional. Read-only.
      */
     #[serde(
         default,
@@ -390,7 +403,7 @@ pub struct CalendarListEntry {
     )]
     pub notification_settings: Option<NotificationSettings>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether the calendar is the primary calendar of the authenticated user. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
@@ -398,7 +411,7 @@ pub struct CalendarListEntry {
     )]
     pub primary: bool,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether the calendar content shows up in the calendar UI. Optional. The default is False.
      */
     #[serde(
         default,
@@ -406,7 +419,7 @@ pub struct CalendarListEntry {
     )]
     pub selected: bool,
     /**
-     * ETag of the collection.
+     * Title of the calendar. Read-only.
      */
     #[serde(
         default,
@@ -415,7 +428,7 @@ pub struct CalendarListEntry {
     )]
     pub summary: String,
     /**
-     * ETag of the collection.
+     * The summary that the authenticated user has set for this calendar. Optional.
      */
     #[serde(
         default,
@@ -425,7 +438,7 @@ pub struct CalendarListEntry {
     )]
     pub summary_override: String,
     /**
-     * ETag of the collection.
+     * The time zone of the calendar. Optional. Read-only.
      */
     #[serde(
         default,
@@ -439,7 +452,9 @@ pub struct CalendarListEntry {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CalendarNotification {
     /**
-     * ETag of the collection.
+     * The method used to deliver the notification. The possible value is:
+     *  - "email" - Notifications are sent via email.
+     *  Required when adding a notification.
      */
     #[serde(
         default,
@@ -448,7 +463,13 @@ pub struct CalendarNotification {
     )]
     pub method: String,
     /**
-     * ETag of the collection.
+     * The type of notification. Possible values are:
+     *  - "eventCreation" - Notification sent when a new event is put on the calendar.
+     *  - "eventChange" - Notification sent when an event is changed.
+     *  - "eventCancellation" - Notification sent when an event is cancelled.
+     *  - "eventResponse" - Notification sent when an attendee responds to the event invitation.
+     *  - "agenda" - An agenda with the events of the day (sent out in the morning).
+     *  Required when adding a notification.
      */
     #[serde(
         default,
@@ -462,7 +483,7 @@ pub struct CalendarNotification {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Channel {
     /**
-     * ETag of the collection.
+     * The address where notifications are delivered for this channel.
      */
     #[serde(
         default,
@@ -480,7 +501,7 @@ pub struct Channel {
     )]
     pub expiration: i64,
     /**
-     * ETag of the collection.
+     * A UUID or similar unique string that identifies this channel.
      */
     #[serde(
         default,
@@ -489,7 +510,7 @@ pub struct Channel {
     )]
     pub id: String,
     /**
-     * ETag of the collection.
+     * Identifies this as a notification channel used to watch for changes to a resource, which is "api#channel".
      */
     #[serde(
         default,
@@ -507,7 +528,7 @@ pub struct Channel {
     )]
     pub params: String,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * A Boolean value to indicate whether payload is wanted. Optional.
      */
     #[serde(
         default,
@@ -515,7 +536,7 @@ pub struct Channel {
     )]
     pub payload: bool,
     /**
-     * ETag of the collection.
+     * An opaque ID that identifies the resource being watched on this channel. Stable across different API versions.
      */
     #[serde(
         default,
@@ -525,7 +546,7 @@ pub struct Channel {
     )]
     pub resource_id: String,
     /**
-     * ETag of the collection.
+     * A version-specific identifier for the watched resource.
      */
     #[serde(
         default,
@@ -535,7 +556,7 @@ pub struct Channel {
     )]
     pub resource_uri: String,
     /**
-     * ETag of the collection.
+     * An arbitrary string delivered to the target address with each notification delivered over this channel. Optional.
      */
     #[serde(
         default,
@@ -544,7 +565,7 @@ pub struct Channel {
     )]
     pub token: String,
     /**
-     * ETag of the collection.
+     * The type of delivery mechanism used for this channel. Valid values are "web_hook" (or "webhook"). Both values refer to a channel where Http requests are used to deliver messages.
      */
     #[serde(
         default,
@@ -558,7 +579,7 @@ pub struct Channel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ColorDefinition {
     /**
-     * ETag of the collection.
+     * The background color associated with this color definition.
      */
     #[serde(
         default,
@@ -567,7 +588,7 @@ pub struct ColorDefinition {
     )]
     pub background: String,
     /**
-     * ETag of the collection.
+     * The foreground color that can be used to write on top of a background with 'background' color.
      */
     #[serde(
         default,
@@ -585,12 +606,12 @@ pub struct Colors {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub calendar: Option<ColorDefinition>,
     /**
-     * A global palette of calendar colors, mapping from the color ID to its definition. A calendarListEntry resource refers to one of these color IDs in its colorId field. Read-only.
+     * A global palette of event colors, mapping from the color ID to its definition. An event resource may refer to one of these color IDs in its colorId field. Read-only.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub event: Option<ColorDefinition>,
     /**
-     * ETag of the collection.
+     * Type of the resource ("calendar#colors").
      */
     #[serde(
         default,
@@ -613,7 +634,13 @@ pub struct Colors {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConferenceData {
     /**
-     * ETag of the collection.
+     * The ID of the conference.
+     *  Can be used by developers to keep track of conferences, should not be displayed to users.
+     *  The ID value is formed differently for each conference solution type:
+     *  - eventHangout: ID is not set. (This conference type is deprecated.)
+     *  - eventNamedHangout: ID is the name of the Hangout. (This conference type is deprecated.)
+     *  - hangoutsMeet: ID is the 10-letter meeting code, for example aaa-bbbb-ccc.
+     *  - addOn: ID is defined by the third-party provider.  Optional.
      */
     #[serde(
         default,
@@ -647,7 +674,7 @@ pub struct ConferenceData {
     )]
     pub entry_points: Vec<EntryPoint>,
     /**
-     * ETag of the collection.
+     * Additional notes (such as instructions from the domain administrator, legal notices) to display to the user. Can contain HTML. The maximum length is 2048 characters. Optional.
      */
     #[serde(
         default,
@@ -658,7 +685,10 @@ pub struct ConferenceData {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parameters: Option<ConferenceParameters>,
     /**
-     * ETag of the collection.
+     * The signature of the conference data.
+     *  Generated on server side. Must be preserved while copying the conference data between events, otherwise the conference data will not be copied.
+     *  Unset for a conference with a failed create request.
+     *  Optional for a conference with a pending create request.
      */
     #[serde(
         default,
@@ -684,9 +714,6 @@ pub struct Shared {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConferenceParametersAddOn {
-    /**
-     * Properties that are shared between copies of the event on other attendees' calendars.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Shared>,
 }
@@ -712,7 +739,11 @@ pub struct ConferenceProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConferenceRequestStatus {
     /**
-     * ETag of the collection.
+     * The current status of the conference create request. Read-only.
+     *  The possible values are:
+     *  - "pending": the conference create request is still being processed.
+     *  - "success": the conference create request succeeded, the entry points are populated.
+     *  - "failure": the conference create request failed, there are no entry points.
      */
     #[serde(
         default,
@@ -726,7 +757,7 @@ pub struct ConferenceRequestStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConferenceSolution {
     /**
-     * ETag of the collection.
+     * The user-visible icon for this solution.
      */
     #[serde(
         default,
@@ -738,7 +769,7 @@ pub struct ConferenceSolution {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub key: Option<ConferenceSolutionKey>,
     /**
-     * ETag of the collection.
+     * The user-visible name of this solution. Not localized.
      */
     #[serde(
         default,
@@ -751,7 +782,13 @@ pub struct ConferenceSolution {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConferenceSolutionKey {
     /**
-     * ETag of the collection.
+     * The conference solution type.
+     *  If a client encounters an unfamiliar or empty type, it should still be able to display the entry points. However, it should disallow modifications.
+     *  The possible values are:
+     *  - "eventHangout" for Hangouts for consumers (deprecated; existing events may show this conference solution type but new conferences cannot be created)
+     *  - "eventNamedHangout" for classic Hangouts for Google Workspace users (deprecated; existing events may show this conference solution type but new conferences cannot be created)
+     *  - "hangoutsMeet" for Google Meet (http://meet.google.com)
+     *  - "addOn" for 3P conference providers
      */
     #[serde(
         default,
@@ -771,7 +808,8 @@ pub struct CreateConferenceRequest {
     )]
     pub conference_solution_key: Option<ConferenceSolutionKey>,
     /**
-     * ETag of the collection.
+     * The client-generated unique ID for this request.
+     *  Clients should regenerate this ID for every new request. If an ID provided is the same as for the previous request, the request is ignored.
      */
     #[serde(
         default,
@@ -787,7 +825,9 @@ pub struct CreateConferenceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EntryPoint {
     /**
-     * ETag of the collection.
+     * The access code to access the conference. The maximum length is 128 characters.
+     *  When creating new conference data, populate only the subset of {meetingCode, accessCode, passcode, password, pin} fields that match the terminology that the conference provider uses. Only the populated fields should be displayed.
+     *  Optional.
      */
     #[serde(
         default,
@@ -797,11 +837,7 @@ pub struct EntryPoint {
     )]
     pub access_code: String,
     /**
-     * The types of conference solutions that are supported for this calendar.
-     *  The possible values are:
-     *  - "eventHangout"
-     *  - "eventNamedHangout"
-     *  - "hangoutsMeet"  Optional.
+     * Features of the entry point, such as being toll or toll-free. One entry point can have multiple features. However, toll and toll-free cannot be both set on the same entry point.
      */
     #[serde(
         default,
@@ -811,7 +847,12 @@ pub struct EntryPoint {
     )]
     pub entry_point_features: Vec<String>,
     /**
-     * ETag of the collection.
+     * The type of the conference entry point.
+     *  Possible values are:
+     *  - "video" - joining a conference over HTTP. A conference can have zero or one video entry point.
+     *  - "phone" - joining a conference by dialing a phone number. A conference can have zero or more phone entry points.
+     *  - "sip" - joining a conference over SIP. A conference can have zero or one sip entry point.
+     *  - "more" - further conference joining instructions, for example additional phone numbers. A conference can have zero or one more entry point. A conference with only a more entry point is not a valid conference.
      */
     #[serde(
         default,
@@ -821,7 +862,13 @@ pub struct EntryPoint {
     )]
     pub entry_point_type: String,
     /**
-     * ETag of the collection.
+     * The label for the URI. Visible to end users. Not localized. The maximum length is 512 characters.
+     *  Examples:
+     *  - for video: meet.google.com/aaa-bbbb-ccc
+     *  - for phone: +1 123 268 2601
+     *  - for sip: 12345678@altostrat.com
+     *  - for more: should not be filled
+     *  Optional.
      */
     #[serde(
         default,
@@ -830,7 +877,9 @@ pub struct EntryPoint {
     )]
     pub label: String,
     /**
-     * ETag of the collection.
+     * The meeting code to access the conference. The maximum length is 128 characters.
+     *  When creating new conference data, populate only the subset of {meetingCode, accessCode, passcode, password, pin} fields that match the terminology that the conference provider uses. Only the populated fields should be displayed.
+     *  Optional.
      */
     #[serde(
         default,
@@ -840,7 +889,8 @@ pub struct EntryPoint {
     )]
     pub meeting_code: String,
     /**
-     * ETag of the collection.
+     * The passcode to access the conference. The maximum length is 128 characters.
+     *  When creating new conference data, populate only the subset of {meetingCode, accessCode, passcode, password, pin} fields that match the terminology that the conference provider uses. Only the populated fields should be displayed.
      */
     #[serde(
         default,
@@ -849,7 +899,9 @@ pub struct EntryPoint {
     )]
     pub passcode: String,
     /**
-     * ETag of the collection.
+     * The password to access the conference. The maximum length is 128 characters.
+     *  When creating new conference data, populate only the subset of {meetingCode, accessCode, passcode, password, pin} fields that match the terminology that the conference provider uses. Only the populated fields should be displayed.
+     *  Optional.
      */
     #[serde(
         default,
@@ -858,7 +910,9 @@ pub struct EntryPoint {
     )]
     pub password: String,
     /**
-     * ETag of the collection.
+     * The PIN to access the conference. The maximum length is 128 characters.
+     *  When creating new conference data, populate only the subset of {meetingCode, accessCode, passcode, password, pin} fields that match the terminology that the conference provider uses. Only the populated fields should be displayed.
+     *  Optional.
      */
     #[serde(
         default,
@@ -867,7 +921,8 @@ pub struct EntryPoint {
     )]
     pub pin: String,
     /**
-     * ETag of the collection.
+     * The CLDR/ISO 3166 region code for the country associated with this phone access. Example: "SE" for Sweden.
+     *  Calendar backend will populate this field only for EntryPointType.PHONE.
      */
     #[serde(
         default,
@@ -877,7 +932,12 @@ pub struct EntryPoint {
     )]
     pub region_code: String,
     /**
-     * ETag of the collection.
+     * The URI of the entry point. The maximum length is 1300 characters.
+     *  Format:
+     *  - for video, http: or https: schema is required.
+     *  - for phone, tel: schema is required. The URI should include the entire dial sequence (e.g., tel:+12345678900,,,123456789;1234).
+     *  - for sip, sip: schema is required, e.g., sip:12345678@myprovider.com.
+     *  - for more, http: or https: schema is required.
      */
     #[serde(
         default,
@@ -890,7 +950,7 @@ pub struct EntryPoint {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Error {
     /**
-     * ETag of the collection.
+     * Domain, or broad category, of the error.
      */
     #[serde(
         default,
@@ -899,7 +959,11 @@ pub struct Error {
     )]
     pub domain: String,
     /**
-     * ETag of the collection.
+     * Specific reason for the error. Some of the possible values are:
+     *  - "groupTooBig" - The group of users requested is too large for a single query.
+     *  - "tooManyCalendarsRequested" - The number of calendars requested is too large for a single query.
+     *  - "notFound" - The requested resource was not found.
+     *  - "internalError" - The API service has encountered an internal error.  Additional error types may be added in the future, so clients should gracefully handle additional error statuses not included in this list.
      */
     #[serde(
         default,
@@ -913,7 +977,7 @@ pub struct Error {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Creator {
     /**
-     * The creator of the event. Read-only.
+     * The creator's name, if available.
      */
     #[serde(
         default,
@@ -923,7 +987,7 @@ pub struct Creator {
     )]
     pub display_name: String,
     /**
-     * The creator of the event. Read-only.
+     * The creator's email address, if available.
      */
     #[serde(
         default,
@@ -932,7 +996,7 @@ pub struct Creator {
     )]
     pub email: String,
     /**
-     * The creator of the event. Read-only.
+     * The creator's Profile ID, if available.
      */
     #[serde(
         default,
@@ -941,7 +1005,7 @@ pub struct Creator {
     )]
     pub id: String,
     /**
-     * The creator of the event. Read-only.
+     * Whether the creator corresponds to the calendar on which this copy of the event appears. Read-only. The default is False.
      */
     #[serde(
         default,
@@ -955,12 +1019,12 @@ pub struct Creator {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ExtendedProperties {
     /**
-     * Extended properties of the event.
+     * Properties that are private to the copy of the event that appears on this calendar.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private: Option<Shared>,
     /**
-     * Extended properties of the event.
+     * Properties that are shared between copies of the event on other attendees' calendars.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shared: Option<Shared>,
@@ -970,7 +1034,9 @@ pub struct ExtendedProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Gadget {
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * The gadget's display mode. Deprecated. Possible values are:
+     *  - "icon" - The gadget displays next to the event's title in the calendar view.
+     *  - "chip" - The gadget displays when the event is clicked.
      */
     #[serde(
         default,
@@ -979,7 +1045,7 @@ pub struct Gadget {
     )]
     pub display: String,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * The gadget's height in pixels. The height must be an integer greater than 0. Optional. Deprecated.
      */
     #[serde(
         default,
@@ -988,7 +1054,7 @@ pub struct Gadget {
     )]
     pub height: i64,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * The gadget's icon URL. The URL scheme must be HTTPS. Deprecated.
      */
     #[serde(
         default,
@@ -998,7 +1064,7 @@ pub struct Gadget {
     )]
     pub icon_link: String,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * The gadget's URL. The URL scheme must be HTTPS. Deprecated.
      */
     #[serde(
         default,
@@ -1007,7 +1073,7 @@ pub struct Gadget {
     )]
     pub link: String,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * Preferences.
      */
     #[serde(
         default,
@@ -1016,7 +1082,7 @@ pub struct Gadget {
     )]
     pub preferences: String,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * The gadget's title. Deprecated.
      */
     #[serde(
         default,
@@ -1025,7 +1091,7 @@ pub struct Gadget {
     )]
     pub title: String,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * The gadget's type. Deprecated.
      */
     #[serde(
         default,
@@ -1035,7 +1101,7 @@ pub struct Gadget {
     )]
     pub type_: String,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * The gadget's width in pixels. The width must be an integer greater than 0. Optional. Deprecated.
      */
     #[serde(
         default,
@@ -1049,7 +1115,7 @@ pub struct Gadget {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Organizer {
     /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+     * The organizer's name, if available.
      */
     #[serde(
         default,
@@ -1059,7 +1125,7 @@ pub struct Organizer {
     )]
     pub display_name: String,
     /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+     * The organizer's email address, if available. It must be a valid email address as per RFC5322.
      */
     #[serde(
         default,
@@ -1068,7 +1134,7 @@ pub struct Organizer {
     )]
     pub email: String,
     /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+     * The organizer's Profile ID, if available.
      */
     #[serde(
         default,
@@ -1077,7 +1143,7 @@ pub struct Organizer {
     )]
     pub id: String,
     /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+     * Whether the organizer corresponds to the calendar on which this copy of the event appears. Read-only. The default is False.
      */
     #[serde(
         default,
@@ -1091,7 +1157,7 @@ pub struct Organizer {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Reminders {
     /**
-     * Information about the event's reminders for the authenticated user.
+     * If the event doesn't use the default reminders, this lists the reminders specific to the event, or, if not set, indicates that no reminders are set for this event. The maximum number of override reminders is 5.
      */
     #[serde(
         default,
@@ -1100,7 +1166,7 @@ pub struct Reminders {
     )]
     pub overrides: Vec<EventReminder>,
     /**
-     * Information about the event's reminders for the authenticated user.
+     * Whether the default reminders of the calendar apply to the event.
      */
     #[serde(
         default,
@@ -1114,7 +1180,7 @@ pub struct Reminders {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Source {
     /**
-     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
+     * Title of the source; for example a title of a web page or an email subject.
      */
     #[serde(
         default,
@@ -1123,7 +1189,7 @@ pub struct Source {
     )]
     pub title: String,
     /**
-     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
+     * URL of the source pointing to a resource. The URL scheme must be HTTP or HTTPS.
      */
     #[serde(
         default,
@@ -1136,7 +1202,7 @@ pub struct Source {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Event {
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether anyone can invite themselves to the event (deprecated). Optional. The default is False.
      */
     #[serde(
         default,
@@ -1165,7 +1231,7 @@ pub struct Event {
     )]
     pub attendees: Vec<EventAttendee>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether attendees may have been omitted from the event's representation. When retrieving an event, this may be due to a restriction specified by the maxAttendee query parameter. When updating an event, this can be used to only update the participant's response. Optional. The default is False.
      */
     #[serde(
         default,
@@ -1174,7 +1240,7 @@ pub struct Event {
     )]
     pub attendees_omitted: bool,
     /**
-     * ETag of the collection.
+     * The color of the event. This is an ID referring to an entry in the event section of the colors definition (see the  colors endpoint). Optional.
      */
     #[serde(
         default,
@@ -1190,7 +1256,7 @@ pub struct Event {
     )]
     pub conference_data: Option<ConferenceData>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * Creation time of the event (as a RFC3339 timestamp). Read-only.
      */
     #[serde(
         default,
@@ -1205,7 +1271,7 @@ pub struct Event {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub creator: Option<Creator>,
     /**
-     * ETag of the collection.
+     * Description of the event. Can contain HTML. Optional.
      */
     #[serde(
         default,
@@ -1216,7 +1282,7 @@ pub struct Event {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub end: Option<EventDateTime>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether the end time is actually unspecified. An end time is still provided for compatibility reasons, even if this attribute is set to True. The default is False.
      */
     #[serde(
         default,
@@ -1225,7 +1291,7 @@ pub struct Event {
     )]
     pub end_time_unspecified: bool,
     /**
-     * ETag of the collection.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -1234,7 +1300,9 @@ pub struct Event {
     )]
     pub etag: String,
     /**
-     * ETag of the collection.
+     * Specific type of the event. Read-only. Possible values are:
+     *  - "default" - A regular event or not further specified.
+     *  - "outOfOffice" - An out-of-office event.
      */
     #[serde(
         default,
@@ -1258,7 +1326,7 @@ pub struct Event {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gadget: Option<Gadget>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether attendees other than the organizer can invite others to the event. Optional. The default is True.
      */
     #[serde(
         default,
@@ -1267,7 +1335,7 @@ pub struct Event {
     )]
     pub guests_can_invite_others: bool,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether attendees other than the organizer can modify the event. Optional. The default is False.
      */
     #[serde(
         default,
@@ -1276,7 +1344,7 @@ pub struct Event {
     )]
     pub guests_can_modify: bool,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether attendees other than the organizer can see who the event's attendees are. Optional. The default is True.
      */
     #[serde(
         default,
@@ -1285,7 +1353,7 @@ pub struct Event {
     )]
     pub guests_can_see_other_guests: bool,
     /**
-     * ETag of the collection.
+     * An absolute link to the Google Hangout associated with this event. Read-only.
      */
     #[serde(
         default,
@@ -1295,7 +1363,7 @@ pub struct Event {
     )]
     pub hangout_link: String,
     /**
-     * ETag of the collection.
+     * An absolute link to this event in the Google Calendar Web UI. Read-only.
      */
     #[serde(
         default,
@@ -1305,7 +1373,8 @@ pub struct Event {
     )]
     pub html_link: String,
     /**
-     * ETag of the collection.
+     * Event unique identifier as defined in RFC5545. It is used to uniquely identify events accross calendaring systems and must be supplied when importing events via the import method.
+     *  Note that the icalUID and the id are not identical and only one of them should be supplied at event creation time. One difference in their semantics is that in recurring events, all occurrences of one event have different ids while they all share the same icalUIDs.
      */
     #[serde(
         default,
@@ -1315,7 +1384,12 @@ pub struct Event {
     )]
     pub i_cal_uid: String,
     /**
-     * ETag of the collection.
+     * Opaque identifier of the event. When creating new single or recurring events, you can specify their IDs. Provided IDs must follow these rules:
+     *  - characters allowed in the ID are those used in base32hex encoding, i.e. lowercase letters a-v and digits 0-9, see section 3.1.2 in RFC2938
+     *  - the length of the ID must be between 5 and 1024 characters
+     *  - the ID must be unique per calendar  Due to the globally distributed nature of the system, we cannot guarantee that ID collisions will be detected at event creation time. To minimize the risk of collisions we recommend using an established UUID algorithm such as one described in RFC4122.
+     *  If you do not specify an ID, it will be automatically generated by the server.
+     *  Note that the icalUID and the id are not identical and only one of them should be supplied at event creation time. One difference in their semantics is that in recurring events, all occurrences of one event have different ids while they all share the same icalUIDs.
      */
     #[serde(
         default,
@@ -1324,7 +1398,7 @@ pub struct Event {
     )]
     pub id: String,
     /**
-     * ETag of the collection.
+     * Type of the resource ("calendar#event").
      */
     #[serde(
         default,
@@ -1333,7 +1407,7 @@ pub struct Event {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Geographic location of the event as free-form text. Optional.
      */
     #[serde(
         default,
@@ -1342,7 +1416,7 @@ pub struct Event {
     )]
     pub location: String,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether this is a locked event copy where no changes can be made to the main event fields "summary", "description", "location", "start", "end" or "recurrence". The default is False. Read-Only.
      */
     #[serde(
         default,
@@ -1361,7 +1435,7 @@ pub struct Event {
     )]
     pub original_start_time: Option<EventDateTime>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * If set to True, Event propagation is disabled. Note that it is not the same thing as Private event properties. Optional. Immutable. The default is False.
      */
     #[serde(
         default,
@@ -1370,11 +1444,7 @@ pub struct Event {
     )]
     pub private_copy: bool,
     /**
-     * The types of conference solutions that are supported for this calendar.
-     *  The possible values are:
-     *  - "eventHangout"
-     *  - "eventNamedHangout"
-     *  - "hangoutsMeet"  Optional.
+     * List of RRULE, EXRULE, RDATE and EXDATE lines for a recurring event, as specified in RFC5545. Note that DTSTART and DTEND lines are not allowed in this field; event start and end times are specified in the start and end fields. This field is omitted for single events or instances of recurring events.
      */
     #[serde(
         default,
@@ -1383,7 +1453,7 @@ pub struct Event {
     )]
     pub recurrence: Vec<String>,
     /**
-     * ETag of the collection.
+     * For an instance of a recurring event, this is the id of the recurring event to which this instance belongs. Immutable.
      */
     #[serde(
         default,
@@ -1398,7 +1468,7 @@ pub struct Event {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reminders: Option<Reminders>,
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * Sequence number as per iCalendar.
      */
     #[serde(
         default,
@@ -1414,7 +1484,16 @@ pub struct Event {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start: Option<EventDateTime>,
     /**
-     * ETag of the collection.
+     * Status of the event. Optional. Possible values are:
+     *  - "confirmed" - The event is confirmed. This is the default status.
+     *  - "tentative" - The event is tentatively confirmed.
+     *  - "cancelled" - The event is cancelled (deleted). The list method returns cancelled events only on incremental sync (when syncToken or updatedMin are specified) or if the showDeleted flag is set to true. The get method always returns them.
+     *  A cancelled status represents two different states depending on the event type:
+     *  - Cancelled exceptions of an uncancelled recurring event indicate that this instance should no longer be presented to the user. Clients should store these events for the lifetime of the parent recurring event.
+     *  Cancelled exceptions are only guaranteed to have values for the id, recurringEventId and originalStartTime fields populated. The other fields might be empty.
+     *  - All other cancelled events represent deleted events. Clients should remove their locally synced copies. Such cancelled events will eventually disappear, so do not rely on them being available indefinitely.
+     *  Deleted events are only guaranteed to have the id field populated.   On the organizer's calendar, cancelled events continue to expose event details (summary, location, etc.) so that they can be restored (undeleted). Similarly, the events to which the user was invited and that they manually removed continue to provide details. However, incremental sync requests with showDeleted set to false will not return these details.
+     *  If an event changes its organizer (for example via the move operation) and the original organizer is not on the attendee list, it will leave behind a cancelled event where only the id field is guaranteed to be populated.
      */
     #[serde(
         default,
@@ -1423,7 +1502,7 @@ pub struct Event {
     )]
     pub status: String,
     /**
-     * ETag of the collection.
+     * Title of the event.
      */
     #[serde(
         default,
@@ -1432,7 +1511,9 @@ pub struct Event {
     )]
     pub summary: String,
     /**
-     * ETag of the collection.
+     * Whether the event blocks time on the calendar. Optional. Possible values are:
+     *  - "opaque" - Default value. The event does block time on the calendar. This is equivalent to setting Show me as to Busy in the Calendar UI.
+     *  - "transparent" - The event does not block time on the calendar. This is equivalent to setting Show me as to Available in the Calendar UI.
      */
     #[serde(
         default,
@@ -1441,7 +1522,7 @@ pub struct Event {
     )]
     pub transparency: String,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * Last modification time of the event (as a RFC3339 timestamp). Read-only.
      */
     #[serde(
         default,
@@ -1451,7 +1532,11 @@ pub struct Event {
     )]
     pub updated: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * ETag of the collection.
+     * Visibility of the event. Optional. Possible values are:
+     *  - "default" - Uses the default visibility for events on the calendar. This is the default value.
+     *  - "public" - The event is public and event details are visible to all readers of the calendar.
+     *  - "private" - The event is private and only event attendees may view event details.
+     *  - "confidential" - The event is private. This value is provided for compatibility reasons.
      */
     #[serde(
         default,
@@ -1464,7 +1549,8 @@ pub struct Event {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EventAttachment {
     /**
-     * ETag of the collection.
+     * ID of the attached file. Read-only.
+     *  For Google Drive files, this is the ID of the corresponding Files resource entry in the Drive API.
      */
     #[serde(
         default,
@@ -1474,7 +1560,9 @@ pub struct EventAttachment {
     )]
     pub file_id: String,
     /**
-     * ETag of the collection.
+     * URL link to the attachment.
+     *  For adding Google Drive file attachments use the same format as in alternateLink property of the Files resource in the Drive API.
+     *  Required when adding an attachment.
      */
     #[serde(
         default,
@@ -1484,7 +1572,7 @@ pub struct EventAttachment {
     )]
     pub file_url: String,
     /**
-     * ETag of the collection.
+     * URL link to the attachment's icon. Read-only.
      */
     #[serde(
         default,
@@ -1494,7 +1582,7 @@ pub struct EventAttachment {
     )]
     pub icon_link: String,
     /**
-     * ETag of the collection.
+     * Internet media type (MIME type) of the attachment.
      */
     #[serde(
         default,
@@ -1504,7 +1592,7 @@ pub struct EventAttachment {
     )]
     pub mime_type: String,
     /**
-     * ETag of the collection.
+     * Attachment title.
      */
     #[serde(
         default,
@@ -1517,7 +1605,7 @@ pub struct EventAttachment {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EventAttendee {
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * Number of additional guests. Optional. The default is 0.
      */
     #[serde(
         default,
@@ -1527,7 +1615,7 @@ pub struct EventAttendee {
     )]
     pub additional_guests: i64,
     /**
-     * ETag of the collection.
+     * The attendee's response comment. Optional.
      */
     #[serde(
         default,
@@ -1536,7 +1624,7 @@ pub struct EventAttendee {
     )]
     pub comment: String,
     /**
-     * ETag of the collection.
+     * The attendee's name, if available. Optional.
      */
     #[serde(
         default,
@@ -1546,7 +1634,8 @@ pub struct EventAttendee {
     )]
     pub display_name: String,
     /**
-     * ETag of the collection.
+     * The attendee's email address, if available. This field must be present when adding an attendee. It must be a valid email address as per RFC5322.
+     *  Required when adding an attendee.
      */
     #[serde(
         default,
@@ -1555,7 +1644,7 @@ pub struct EventAttendee {
     )]
     pub email: String,
     /**
-     * ETag of the collection.
+     * The attendee's Profile ID, if available.
      */
     #[serde(
         default,
@@ -1564,7 +1653,7 @@ pub struct EventAttendee {
     )]
     pub id: String,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether this is an optional attendee. Optional. The default is False.
      */
     #[serde(
         default,
@@ -1572,7 +1661,7 @@ pub struct EventAttendee {
     )]
     pub optional: bool,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether the attendee is the organizer of the event. Read-only. The default is False.
      */
     #[serde(
         default,
@@ -1580,7 +1669,7 @@ pub struct EventAttendee {
     )]
     pub organizer: bool,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether the attendee is a resource. Can only be set when the attendee is added to the event for the first time. Subsequent modifications are ignored. Optional. The default is False.
      */
     #[serde(
         default,
@@ -1588,7 +1677,11 @@ pub struct EventAttendee {
     )]
     pub resource: bool,
     /**
-     * ETag of the collection.
+     * The attendee's response status. Possible values are:
+     *  - "needsAction" - The attendee has not responded to the invitation.
+     *  - "declined" - The attendee has declined the invitation.
+     *  - "tentative" - The attendee has tentatively accepted the invitation.
+     *  - "accepted" - The attendee has accepted the invitation.
      */
     #[serde(
         default,
@@ -1598,7 +1691,7 @@ pub struct EventAttendee {
     )]
     pub response_status: String,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Whether this entry represents the calendar on which this copy of the event appears. Read-only. The default is False.
      */
     #[serde(
         default,
@@ -1620,7 +1713,7 @@ pub struct EventDateTime {
     )]
     pub date: Option<chrono::NaiveDate>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The time, as a combined date-time value (formatted according to RFC3339). A time zone offset is required unless a time zone is explicitly specified in timeZone.
      */
     #[serde(
         default,
@@ -1631,7 +1724,7 @@ pub struct EventDateTime {
     )]
     pub date_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * ETag of the collection.
+     * The time zone in which the time is specified. (Formatted as an IANA Time Zone Database name, e.g. "Europe/Zurich".) For recurring events this field is required and specifies the time zone in which the recurrence is expanded. For single events this field is optional and indicates a custom time zone for the event start/end.
      */
     #[serde(
         default,
@@ -1645,7 +1738,10 @@ pub struct EventDateTime {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EventReminder {
     /**
-     * ETag of the collection.
+     * The method used by this reminder. Possible values are:
+     *  - "email" - Reminders are sent via email.
+     *  - "popup" - Reminders are sent via a UI popup.
+     *  Required when adding a reminder.
      */
     #[serde(
         default,
@@ -1654,7 +1750,8 @@ pub struct EventReminder {
     )]
     pub method: String,
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * Number of minutes before the start of the event when the reminder should trigger. Valid values are between 0 and 40320 (4 weeks in minutes).
+     *  Required when adding a reminder.
      */
     #[serde(
         default,
@@ -1667,7 +1764,12 @@ pub struct EventReminder {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Events {
     /**
-     * ETag of the collection.
+     * The user's access role for this calendar. Read-only. Possible values are:
+     *  - "none" - The user has no access.
+     *  - "freeBusyReader" - The user has read access to free/busy information.
+     *  - "reader" - The user has read access to the calendar. Private events will appear to users with reader access, but event details will be hidden.
+     *  - "writer" - The user has read and write access to the calendar. Private events will appear to users with writer access, and event details will be visible.
+     *  - "owner" - The user has ownership of the calendar. This role has all of the permissions of the writer role with the additional ability to see and manipulate ACLs.
      */
     #[serde(
         default,
@@ -1677,7 +1779,7 @@ pub struct Events {
     )]
     pub access_role: String,
     /**
-     * The default reminders that the authenticated user has for this calendar.
+     * The default reminders on the calendar for the authenticated user. These reminders apply to all events on this calendar that do not explicitly override them (i.e. do not have reminders.useDefault set to True).
      */
     #[serde(
         default,
@@ -1687,7 +1789,7 @@ pub struct Events {
     )]
     pub default_reminders: Vec<EventReminder>,
     /**
-     * ETag of the collection.
+     * Description of the calendar. Read-only.
      */
     #[serde(
         default,
@@ -1714,7 +1816,7 @@ pub struct Events {
     )]
     pub items: Vec<Event>,
     /**
-     * ETag of the collection.
+     * Type of the collection ("calendar#events").
      */
     #[serde(
         default,
@@ -1723,7 +1825,7 @@ pub struct Events {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Token used to access the next page of this result. Omitted if no further results are available, in which case nextSyncToken is provided.
      */
     #[serde(
         default,
@@ -1733,7 +1835,7 @@ pub struct Events {
     )]
     pub next_page_token: String,
     /**
-     * ETag of the collection.
+     * Token used at a later point in time to retrieve only the entries that have changed since this result was returned. Omitted if further results are available, in which case nextPageToken is provided.
      */
     #[serde(
         default,
@@ -1743,7 +1845,7 @@ pub struct Events {
     )]
     pub next_sync_token: String,
     /**
-     * ETag of the collection.
+     * Title of the calendar. Read-only.
      */
     #[serde(
         default,
@@ -1752,7 +1854,7 @@ pub struct Events {
     )]
     pub summary: String,
     /**
-     * ETag of the collection.
+     * The time zone of the calendar. Read-only.
      */
     #[serde(
         default,
@@ -1762,7 +1864,7 @@ pub struct Events {
     )]
     pub time_zone: String,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * Last modification time of the calendar (as a RFC3339 timestamp). Read-only.
      */
     #[serde(
         default,
@@ -1798,11 +1900,7 @@ pub struct FreeBusyCalendar {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FreeBusyGroup {
     /**
-     * The types of conference solutions that are supported for this calendar.
-     *  The possible values are:
-     *  - "eventHangout"
-     *  - "eventNamedHangout"
-     *  - "hangoutsMeet"  Optional.
+     * List of calendars' identifiers within a group.
      */
     #[serde(
         default,
@@ -1811,7 +1909,7 @@ pub struct FreeBusyGroup {
     )]
     pub calendars: Vec<String>,
     /**
-     * Optional error(s) (if computation for the calendar failed).
+     * Optional error(s) (if computation for the group failed).
      */
     #[serde(
         default,
@@ -1824,7 +1922,7 @@ pub struct FreeBusyGroup {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FreeBusyRequest {
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * Maximal number of calendars for which FreeBusy information is to be provided. Optional. Maximum value is 50.
      */
     #[serde(
         default,
@@ -1834,7 +1932,7 @@ pub struct FreeBusyRequest {
     )]
     pub calendar_expansion_max: i64,
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * Maximal number of calendar identifiers to be provided for a single group. Optional. An error is returned for a group with more members than this value. Maximum value is 100.
      */
     #[serde(
         default,
@@ -1853,7 +1951,7 @@ pub struct FreeBusyRequest {
     )]
     pub items: Vec<FreeBusyRequestItem>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The end of the interval for the query formatted as per RFC3339.
      */
     #[serde(
         default,
@@ -1864,7 +1962,7 @@ pub struct FreeBusyRequest {
     )]
     pub time_max: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The start of the interval for the query formatted as per RFC3339.
      */
     #[serde(
         default,
@@ -1875,7 +1973,7 @@ pub struct FreeBusyRequest {
     )]
     pub time_min: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * ETag of the collection.
+     * Time zone used in the response. Optional. The default is UTC.
      */
     #[serde(
         default,
@@ -1889,7 +1987,7 @@ pub struct FreeBusyRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FreeBusyRequestItem {
     /**
-     * ETag of the collection.
+     * The identifier of a calendar or a group.
      */
     #[serde(
         default,
@@ -1912,7 +2010,7 @@ pub struct FreeBusyResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub groups: Option<FreeBusyGroup>,
     /**
-     * ETag of the collection.
+     * Type of the resource ("calendar#freeBusy").
      */
     #[serde(
         default,
@@ -1921,7 +2019,7 @@ pub struct FreeBusyResponse {
     )]
     pub kind: String,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The end of the interval.
      */
     #[serde(
         default,
@@ -1932,7 +2030,7 @@ pub struct FreeBusyResponse {
     )]
     pub time_max: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The start of the interval.
      */
     #[serde(
         default,
@@ -1947,7 +2045,7 @@ pub struct FreeBusyResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Setting {
     /**
-     * ETag of the collection.
+     * ETag of the resource.
      */
     #[serde(
         default,
@@ -1956,7 +2054,7 @@ pub struct Setting {
     )]
     pub etag: String,
     /**
-     * ETag of the collection.
+     * The id of the user setting.
      */
     #[serde(
         default,
@@ -1965,7 +2063,7 @@ pub struct Setting {
     )]
     pub id: String,
     /**
-     * ETag of the collection.
+     * Type of the resource ("calendar#setting").
      */
     #[serde(
         default,
@@ -1974,7 +2072,7 @@ pub struct Setting {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Value of the user setting. The format of the value depends on the ID of the setting. It must always be a UTF-8 string of length up to 1024 characters.
      */
     #[serde(
         default,
@@ -1987,7 +2085,7 @@ pub struct Setting {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Settings {
     /**
-     * ETag of the collection.
+     * Etag of the collection.
      */
     #[serde(
         default,
@@ -2005,7 +2103,7 @@ pub struct Settings {
     )]
     pub items: Vec<Setting>,
     /**
-     * ETag of the collection.
+     * Type of the collection ("calendar#settings").
      */
     #[serde(
         default,
@@ -2014,7 +2112,7 @@ pub struct Settings {
     )]
     pub kind: String,
     /**
-     * ETag of the collection.
+     * Token used to access the next page of this result. Omitted if no further results are available, in which case nextSyncToken is provided.
      */
     #[serde(
         default,
@@ -2024,7 +2122,7 @@ pub struct Settings {
     )]
     pub next_page_token: String,
     /**
-     * ETag of the collection.
+     * Token used at a later point in time to retrieve only the entries that have changed since this result was returned. Omitted if further results are available, in which case nextPageToken is provided.
      */
     #[serde(
         default,
@@ -2038,7 +2136,7 @@ pub struct Settings {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimePeriod {
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The (exclusive) end of the time period.
      */
     #[serde(
         default,
@@ -2048,7 +2146,7 @@ pub struct TimePeriod {
     )]
     pub end: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The (inclusive) start of the time period.
      */
     #[serde(
         default,
@@ -2063,13 +2161,15 @@ pub struct TimePeriod {
 * Data format for the response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -2077,12 +2177,31 @@ impl std::fmt::Display for Alt {
         match self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Alt {
+        match s.as_str() {
+            "json" => Alt::Json,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(e: Alt) -> String {
+        match e {
+            Alt::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -2098,6 +2217,7 @@ impl Alt {
 * The order of the events returned in the result. Optional. The default is an unspecified, stable order.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrderBy {
     #[serde(rename = "startTime")]
     StartTime,
@@ -2105,8 +2225,9 @@ pub enum OrderBy {
     Updated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderBy {
@@ -2115,12 +2236,32 @@ impl std::fmt::Display for OrderBy {
             OrderBy::StartTime => "startTime",
             OrderBy::Updated => "updated",
             OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
+            OrderBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrderBy {
+    fn from(s: String) -> OrderBy {
+        match s.as_str() {
+            "startTime" => OrderBy::StartTime,
+            "updated" => OrderBy::Updated,
+            "" => OrderBy::Noop,
+            _ => OrderBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrderBy> for String {
+    fn from(e: OrderBy) -> String {
+        match e {
+            OrderBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrderBy {
     fn default() -> OrderBy {
         OrderBy::Noop
@@ -2136,6 +2277,7 @@ impl OrderBy {
 * Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SendUpdates {
     #[serde(rename = "all")]
     All,
@@ -2145,8 +2287,9 @@ pub enum SendUpdates {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendUpdates {
@@ -2156,12 +2299,33 @@ impl std::fmt::Display for SendUpdates {
             SendUpdates::ExternalOnly => "externalOnly",
             SendUpdates::None => "none",
             SendUpdates::Noop => "",
-            SendUpdates::FallthroughString => "*",
+            SendUpdates::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SendUpdates {
+    fn from(s: String) -> SendUpdates {
+        match s.as_str() {
+            "all" => SendUpdates::All,
+            "externalOnly" => SendUpdates::ExternalOnly,
+            "none" => SendUpdates::None,
+            "" => SendUpdates::Noop,
+            _ => SendUpdates::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SendUpdates> for String {
+    fn from(e: SendUpdates) -> String {
+        match e {
+            SendUpdates::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SendUpdates {
     fn default() -> SendUpdates {
         SendUpdates::Noop
@@ -2177,6 +2341,7 @@ impl SendUpdates {
 * The minimum access role for the user in the returned entries. Optional. The default is no restriction.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MinAccessRole {
     #[serde(rename = "freeBusyReader")]
     FreeBusyReader,
@@ -2188,8 +2353,9 @@ pub enum MinAccessRole {
     Writer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MinAccessRole {
@@ -2200,12 +2366,34 @@ impl std::fmt::Display for MinAccessRole {
             MinAccessRole::Reader => "reader",
             MinAccessRole::Writer => "writer",
             MinAccessRole::Noop => "",
-            MinAccessRole::FallthroughString => "*",
+            MinAccessRole::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MinAccessRole {
+    fn from(s: String) -> MinAccessRole {
+        match s.as_str() {
+            "freeBusyReader" => MinAccessRole::FreeBusyReader,
+            "owner" => MinAccessRole::Owner,
+            "reader" => MinAccessRole::Reader,
+            "writer" => MinAccessRole::Writer,
+            "" => MinAccessRole::Noop,
+            _ => MinAccessRole::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MinAccessRole> for String {
+    fn from(e: MinAccessRole) -> String {
+        match e {
+            MinAccessRole::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MinAccessRole {
     fn default() -> MinAccessRole {
         MinAccessRole::Noop
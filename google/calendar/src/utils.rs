@@ -1,7 +1,211 @@
-use std::{fmt, str::FromStr};
+/// Make sure a field mask asks for `nextPageToken`.
+///
+/// Google only sends back the fields we ask for, so if the mask leaves out
+/// `nextPageToken` we would stop after the first page.
+pub fn fields_with_next_page_token(fields: &str) -> String {
+    fields_with(fields, "nextPageToken")
+}
+
+fn fields_with(fields: &str, field: &str) -> String {
+    let fields = fields.trim();
+    if fields.is_empty() || fields == "*" || fields.split(',').any(|f| f.trim() == field) {
+        return fields.to_string();
+    }
+
+    format!("{},{}", field, fields)
+}
+
+/// The error Google sends back, see <https://cloud.google.com/apis/design/errors>.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GoogleApiError {
+    #[serde(default)]
+    pub code: i64,
+    #[serde(default)]
+    pub message: String,
+    /// The canonical error code, e.g. `NOT_FOUND`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GoogleErrorDetail>,
+}
+
+impl GoogleApiError {
+    /// Whether any of the errors is for `reason`, e.g. `rateLimitExceeded`.
+    pub fn has_reason(&self, reason: &str) -> bool {
+        self.errors.iter().any(|e| e.reason == reason)
+    }
+}
+
+/// One of the errors in a `GoogleApiError`.
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoogleErrorDetail {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub domain: String,
+    /// Why the request failed, e.g. `notFound` or `userRateLimitExceeded`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub reason: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub location: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub location_type: String,
+}
+
+/// Parse the body of a failed request into a `ClientError::GoogleError`, if
+/// it is one of Google's errors.
+pub(crate) fn google_error(
+    status: http::StatusCode,
+    body: &[u8],
+    request_id: Option<String>,
+) -> Option<crate::ClientError> {
+    #[derive(serde::Deserialize)]
+    struct Envelope {
+        error: GoogleApiError,
+    }
+
+    let Envelope { error } = serde_json::from_slice(body).ok()?;
+    Some(crate::ClientError::GoogleError {
+        status,
+        error: Box::new(error),
+        body: String::from_utf8_lossy(body).into(),
+        request_id,
+    })
+}
+
+#[cfg(test)]
+mod google_tests {
+    use super::{fields_with_next_page_token, google_error};
+
+    #[test]
+    fn test_fields_with_next_page_token() {
+        assert_eq!(fields_with_next_page_token(""), "");
+        assert_eq!(fields_with_next_page_token("*"), "*");
+        assert_eq!(
+            fields_with_next_page_token("files(id,name,modifiedTime)"),
+            "nextPageToken,files(id,name,modifiedTime)"
+        );
+        assert_eq!(
+            fields_with_next_page_token("files(id),nextPageToken"),
+            "files(id),nextPageToken"
+        );
+    }
+
+    #[test]
+    fn test_google_error() {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "error": {
+                "code": 403,
+                "message": "User Rate Limit Exceeded",
+                "errors": [{
+                    "domain": "usageLimits",
+                    "reason": "userRateLimitExceeded",
+                    "message": "User Rate Limit Exceeded",
+                }],
+                "status": "PERMISSION_DENIED",
+            }
+        }))
+        .unwrap();
+        match google_error(
+            http::StatusCode::FORBIDDEN,
+            &body,
+            Some("req_123".to_string()),
+        ) {
+            Some(crate::ClientError::GoogleError {
+                status,
+                error,
+                body,
+                request_id,
+            }) => {
+                assert_eq!(request_id.as_deref(), Some("req_123"));
+                assert_eq!(status, http::StatusCode::FORBIDDEN);
+                assert_eq!(error.code, 403);
+                assert_eq!(error.status, "PERMISSION_DENIED");
+                assert!(error.has_reason("userRateLimitExceeded"));
+                assert!(!error.has_reason("notFound"));
+                assert!(body.contains("usageLimits"));
+            }
+            r => panic!("expected a google error, got {:?}", r),
+        }
+
+        // The token endpoint uses a different shape.
+        let body = b"{\"error\": \"invalid_grant\", \"error_description\": \"Bad Request\"}";
+        assert!(google_error(http::StatusCode::BAD_REQUEST, body, None).is_none());
+    }
+}
+/// Make sure a field mask asks for both `nextPageToken` and `nextSyncToken`.
+pub fn fields_with_sync_tokens(fields: &str) -> String {
+    fields_with(&fields_with_next_page_token(fields), "nextSyncToken")
+}
+
+/// Google Calendar responds with `410 GONE` when a sync token has expired.
+pub(crate) fn full_sync_required(e: crate::ClientError) -> crate::ClientError {
+    match e {
+        crate::ClientError::GoogleError {
+            status: http::StatusCode::GONE,
+            ..
+        }
+        | crate::ClientError::HttpError {
+            status: http::StatusCode::GONE,
+            ..
+        } => crate::ClientError::FullSyncRequired,
+        e => e,
+    }
+}
+
+#[cfg(test)]
+mod google_calendar_tests {
+    use super::{fields_with_sync_tokens, full_sync_required};
+
+    #[test]
+    fn test_fields_with_sync_tokens() {
+        assert_eq!(fields_with_sync_tokens(""), "");
+        assert_eq!(
+            fields_with_sync_tokens("items(id,summary)"),
+            "nextSyncToken,nextPageToken,items(id,summary)"
+        );
+        assert_eq!(
+            fields_with_sync_tokens("nextSyncToken,items(id)"),
+            "nextPageToken,nextSyncToken,items(id)"
+        );
+    }
+
+    #[test]
+    fn test_full_sync_required() {
+        let e = full_sync_required(crate::ClientError::HttpError {
+            status: http::StatusCode::GONE,
+            error: "".to_string(),
+            request_id: None,
+        });
+        assert!(matches!(e, crate::ClientError::FullSyncRequired));
+
+        let e = full_sync_required(crate::ClientError::GoogleError {
+            status: http::StatusCode::GONE,
+            error: Default::default(),
+            body: "".to_string(),
+            request_id: None,
+        });
+        assert!(matches!(e, crate::ClientError::FullSyncRequired));
+
+        let e = full_sync_required(crate::ClientError::HttpError {
+            status: http::StatusCode::NOT_FOUND,
+            error: "".to_string(),
+            request_id: None,
+        });
+        assert!(matches!(e, crate::ClientError::HttpError { .. }));
+    }
+}
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
 
 use parse_link_header::LinkMap;
-use serde::de::{self, Visitor};
+use serde::de::{self, DeserializeOwned, Visitor};
 
 pub struct NextLink(pub String);
 
@@ -11,6 +215,280 @@ pub fn next_link(l: &LinkMap) -> Option<NextLink> {
         .map(NextLink)
 }
 
+/// A page of results from a paginated request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// The items on this page.
+    pub items: Vec<T>,
+    /// The cursor for the next page, or `None` if this is the last page.
+    /// Pass it back in to pick up where you left off.
+    pub next: Option<String>,
+}
+
+/// Turn a stream of pages into a stream of their items.
+pub fn page_items<'a, T, S>(
+    pages: S,
+) -> impl futures::Stream<Item = crate::ClientResult<T>> + Unpin + 'a
+where
+    T: 'a,
+    S: futures::Stream<Item = crate::ClientResult<Page<T>>> + Unpin + 'a,
+{
+    use futures::TryStreamExt;
+
+    pages
+        .map_ok(|page| futures::stream::iter(page.items.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// The number of coerced fields kept by [`Coercions`].
+const MAX_COERCIONS: usize = 1000;
+
+thread_local! {
+    /// Whether the `deserialize_null_*` helpers should fail instead of falling back to defaults.
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+    /// The path to the value [`Tracked`] is deserializing.
+    static PATH: RefCell<Vec<Segment>> = const { RefCell::new(Vec::new()) };
+    /// The fields that fell back to their default in the current deserialization.
+    static COERCED: RefCell<Vec<Coercion>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A field in a response that did not match the generated types and was
+/// replaced with its default value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coercion {
+    /// The JSON path of the field, e.g. `meetings[3].recording_files[0].file_size`.
+    pub path: String,
+    /// What was expected and what was found instead.
+    pub error: String,
+}
+
+/// Parses response bodies, either strictly or keeping a log of every field
+/// that had to be coerced to its default value.
+#[derive(Debug, Clone, Default)]
+pub struct Coercions {
+    pub(crate) strict: bool,
+    log: Arc<Mutex<Vec<Coercion>>>,
+}
+
+impl Coercions {
+    pub(crate) fn parse<T: DeserializeOwned>(&self, body: &[u8]) -> crate::ClientResult<T> {
+        if self.strict {
+            return with_strict(|| {
+                serde_path_to_error::deserialize(&mut serde_json::Deserializer::from_slice(body))
+                    .map_err(|e| crate::ClientError::DeserializationError {
+                        path: e.path().to_string(),
+                        error: e.into_inner().to_string(),
+                    })
+            });
+        }
+
+        let value: serde_json::Value = serde_json::from_slice(body)?;
+        COERCED.with(|c| c.borrow_mut().clear());
+        let parsed = T::deserialize(Tracked(&value));
+        let found = COERCED.with(|c| c.take());
+        let parsed = parsed?;
+
+        if !found.is_empty() {
+            let mut log = self.log.lock().unwrap();
+            for coercion in found {
+                log::warn!(
+                    "coerced `{}` to its default: {}",
+                    coercion.path,
+                    coercion.error
+                );
+                log.push(coercion);
+            }
+            let len = log.len();
+            if len > MAX_COERCIONS {
+                log.drain(..len - MAX_COERCIONS);
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub(crate) fn list(&self) -> Vec<Coercion> {
+        self.log.lock().unwrap().clone()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.log.lock().unwrap().clear();
+    }
+}
+
+fn with_strict<R>(f: impl FnOnce() -> R) -> R {
+    let previous = STRICT.with(|s| s.replace(true));
+    let r = f();
+    STRICT.with(|s| s.set(previous));
+    r
+}
+
+/// A step along the path to a value.
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// Runs `f` with `segment` pushed onto the current path.
+fn with_segment<R>(segment: Segment, f: impl FnOnce() -> R) -> R {
+    PATH.with(|p| p.borrow_mut().push(segment));
+    let r = f();
+    PATH.with(|p| p.borrow_mut().pop());
+    r
+}
+
+/// The current path, formatted like `serde_path_to_error` does.
+fn current_path() -> String {
+    PATH.with(|p| {
+        let mut path = String::new();
+        for segment in p.borrow().iter() {
+            match segment {
+                Segment::Key(key) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(key);
+                }
+                Segment::Index(index) => path.push_str(&format!("[{}]", index)),
+            }
+        }
+        if path.is_empty() {
+            path.push('.');
+        }
+        path
+    })
+}
+
+/// Deserializes a `serde_json::Value` while keeping track of the path to the
+/// value being deserialized, so [`coerce`] can tell where it fell back to a
+/// default without parsing the body again.
+struct Tracked<'de>(&'de serde_json::Value);
+
+impl<'de> de::Deserializer<'de> for Tracked<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            serde_json::Value::Array(a) => visitor.visit_seq(TrackedSeq(a.iter().enumerate())),
+            serde_json::Value::Object(o) => visitor.visit_map(TrackedMap {
+                iter: o.iter(),
+                value: None,
+            }),
+            v => de::Deserializer::deserialize_any(v, visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            serde_json::Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        de::Deserializer::deserialize_enum(self.0, name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
+
+struct TrackedSeq<'de>(std::iter::Enumerate<std::slice::Iter<'de, serde_json::Value>>);
+
+impl<'de> de::SeqAccess<'de> for TrackedSeq<'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<S: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        match self.0.next() {
+            Some((index, value)) => {
+                with_segment(Segment::Index(index), || seed.deserialize(Tracked(value))).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct TrackedMap<'de> {
+    iter: serde_json::map::Iter<'de>,
+    value: Option<(&'de String, &'de serde_json::Value)>,
+}
+
+impl<'de> de::MapAccess<'de> for TrackedMap<'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<S: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some((key, value));
+                seed.deserialize(de::value::BorrowedStrDeserializer::new(key))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, Self::Error> {
+        match self.value.take() {
+            Some((key, value)) => with_segment(Segment::Key(key.to_string()), || {
+                seed.deserialize(Tracked(value))
+            }),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+/// Called by the `deserialize_null_*` helpers when a field does not match its
+/// type: an error in strict mode, otherwise the default value.
+fn coerce<T: Default, E: de::Error>(e: E) -> Result<T, E> {
+    if STRICT.with(|s| s.get()) {
+        return Err(e);
+    }
+
+    COERCED.with(|c| {
+        c.borrow_mut().push(Coercion {
+            path: current_path(),
+            error: e.to_string(),
+        })
+    });
+    Ok(Default::default())
+}
+
 pub mod date_format {
     use chrono::NaiveDate;
     use serde::{self, Deserialize, Deserializer};
@@ -124,23 +602,20 @@ pub mod deserialize_empty_url {
     where
         D: Deserializer<'de>,
     {
-        if let Ok(s) = String::deserialize(deserializer) {
-            if s.is_empty() {
-                return Ok(None);
-            }
-
-            match url::Url::parse(&s) {
-                Ok(u) => return Ok(Some(u)),
-                Err(e) => {
-                    return Err(serde::de::Error::custom(format!(
-                        "error url parsing {}: {}",
-                        s, e
-                    )))
-                }
-            }
+        let s = Option::<String>::deserialize(deserializer)
+            .or_else(crate::utils::coerce)?
+            .unwrap_or_default();
+        if s.is_empty() {
+            return Ok(None);
         }
 
-        Ok(None)
+        match url::Url::parse(&s) {
+            Ok(u) => Ok(Some(u)),
+            Err(e) => Err(serde::de::Error::custom(format!(
+                "error url parsing {}: {}",
+                s, e
+            ))),
+        }
     }
 }
 
@@ -158,7 +633,9 @@ pub mod deserialize_null_string {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer).unwrap_or_default();
+        let s = Option::<String>::deserialize(deserializer)
+            .or_else(crate::utils::coerce)?
+            .unwrap_or_default();
 
         Ok(s)
     }
@@ -169,6 +646,20 @@ struct BoolVisitor;
 impl<'de> Visitor<'de> for BoolVisitor {
     type Value = bool;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_bool(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a boolean")
     }
@@ -222,8 +713,8 @@ pub mod deserialize_null_boolean {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_bool(crate::utils::BoolVisitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::BoolVisitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -234,6 +725,20 @@ struct I32Visitor;
 impl<'de> Visitor<'de> for I32Visitor {
     type Value = i32;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_i32(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("an integer between -2^31 and 2^31")
     }
@@ -294,8 +799,8 @@ pub mod deserialize_null_i32 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_i32(crate::utils::I32Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::I32Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -306,6 +811,20 @@ struct I64Visitor;
 impl<'de> Visitor<'de> for I64Visitor {
     type Value = i64;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_i64(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("an integer")
     }
@@ -361,8 +880,8 @@ pub mod deserialize_null_i64 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_i64(crate::utils::I64Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::I64Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -373,6 +892,20 @@ struct F32Visitor;
 impl<'de> Visitor<'de> for F32Visitor {
     type Value = f32;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_f32(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a float between -2^31 and 2^31")
     }
@@ -452,8 +985,8 @@ pub mod deserialize_null_f32 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_f32(crate::utils::F32Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::F32Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -464,6 +997,20 @@ struct F64Visitor;
 impl<'de> Visitor<'de> for F64Visitor {
     type Value = f64;
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(self)
+    }
+
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         formatter.write_str("a float")
     }
@@ -533,8 +1080,8 @@ pub mod deserialize_null_f64 {
         D: Deserializer<'de>,
     {
         let s = deserializer
-            .deserialize_f64(crate::utils::F64Visitor)
-            .unwrap_or_default();
+            .deserialize_option(crate::utils::F64Visitor)
+            .or_else(crate::utils::coerce)?;
 
         Ok(s)
     }
@@ -595,6 +1142,20 @@ where
         formatter.write_str("a very special vector")
     }
 
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Default::default())
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+
     fn visit_seq<A: de::SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
         let mut things: Vec<T> = Default::default();
 
@@ -625,17 +1186,15 @@ pub mod deserialize_null_vector {
         T: Deserialize<'de>,
         D: Deserializer<'de>,
     {
-        if let Ok(r) = deserializer.deserialize_seq(VectorVisitor::<T>(std::marker::PhantomData)) {
-            return Ok(r);
-        }
-
-        Ok(Default::default())
+        deserializer
+            .deserialize_option(VectorVisitor::<T>(std::marker::PhantomData))
+            .or_else(crate::utils::coerce)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::next_link;
+    use super::{next_link, page_items, Coercions, Page};
 
     #[test]
     fn test_hyperx_next_link_compat() {
@@ -646,4 +1205,1063 @@ mod tests {
 
         assert_eq!("https://next-link", next);
     }
+
+    #[tokio::test]
+    async fn test_page_items() {
+        use futures::{StreamExt, TryStreamExt};
+
+        let pages = futures::stream::iter(vec![
+            Ok(Page {
+                items: vec![1, 2],
+                next: Some("2".to_string()),
+            }),
+            Ok(Page {
+                items: vec![],
+                next: Some("3".to_string()),
+            }),
+            Ok(Page {
+                items: vec![3],
+                next: None,
+            }),
+        ]);
+        let items: Vec<i32> = page_items(pages).try_collect().await.unwrap();
+        assert_eq!(items, vec![1, 2, 3]);
+
+        // We stop early if we hit an error.
+        let pages = futures::stream::iter(vec![
+            Ok(Page {
+                items: vec![1],
+                next: Some("2".to_string()),
+            }),
+            Err(crate::ClientError::HttpError {
+                status: http::StatusCode::INTERNAL_SERVER_ERROR,
+                error: "".to_string(),
+                request_id: None,
+            }),
+        ]);
+        let items: Vec<_> = page_items(pages).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(items[1].is_err());
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Item {
+        #[serde(
+            default,
+            deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        )]
+        name: String,
+        #[serde(
+            default,
+            deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        )]
+        size: i64,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct Items {
+        #[serde(
+            default,
+            deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        )]
+        items: Vec<Item>,
+    }
+
+    const ITEMS: &str = "{\"items\": [{\"name\": \"a\", \"size\": 1}, {\"name\": 2, \"size\": null}, {\"name\": null, \"size\": \"big\"}]}";
+
+    #[test]
+    fn test_coercions_logged() {
+        let coercions = Coercions::default();
+        let parsed: Items = coercions.parse(ITEMS.as_bytes()).unwrap();
+        assert_eq!(parsed.items.len(), 3);
+        assert_eq!(parsed.items[1].name, "");
+        assert_eq!(parsed.items[1].size, 0);
+        assert_eq!(parsed.items[2].size, 0);
+
+        // Nulls are expected, only the mismatched types are logged.
+        let paths: Vec<String> = coercions.list().into_iter().map(|c| c.path).collect();
+        assert_eq!(paths, vec!["items[1].name", "items[2].size"]);
+
+        coercions.clear();
+        let _: Items = coercions.parse(b"{\"items\": null}").unwrap();
+        assert!(coercions.list().is_empty());
+    }
+
+    #[test]
+    fn test_coercions_strict() {
+        let coercions = Coercions {
+            strict: true,
+            ..Default::default()
+        };
+        match coercions.parse::<Items>(ITEMS.as_bytes()) {
+            Err(crate::ClientError::DeserializationError { path, error }) => {
+                assert_eq!(path, "items[1].name");
+                assert!(error.contains("expected a string"), "{}", error);
+            }
+            r => panic!("expected a deserialization error, got {:?}", r),
+        }
+
+        // Nulls are still fine.
+        let parsed: Items = coercions
+            .parse(b"{\"items\": [{\"name\": null, \"size\": null}]}")
+            .unwrap();
+        assert_eq!(parsed.items[0].size, 0);
+    }
+
+    #[test]
+    fn test_response() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(crate::REQUEST_ID_HEADER, "req_123".parse().unwrap());
+        let response = crate::Response {
+            status: reqwest::StatusCode::CREATED,
+            headers,
+            raw: bytes::Bytes::from_static(b"{\"items\": []}"),
+            body: Items { items: vec![] },
+        };
+        assert_eq!(response.request_id(), Some("req_123"));
+
+        let response = response.map(|b| b.items.len());
+        assert_eq!(response.status, reqwest::StatusCode::CREATED);
+        assert_eq!(response.body, 0);
+        assert_eq!(&response.raw[..], b"{\"items\": []}");
+
+        // Only the header of this API counts.
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-amzn-requestid", "req_456".parse().unwrap());
+        let response = crate::Response {
+            headers,
+            ..response
+        };
+        assert_eq!(response.request_id(), None);
+    }
+}
+
+/// How long to wait between two attempts at a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backoff {
+    /// Wait the same time before every retry.
+    Constant(std::time::Duration),
+    /// Wait `initial` before the first retry and `factor` times longer before
+    /// each of the next ones, up to `max`.
+    Exponential {
+        initial: std::time::Duration,
+        factor: u32,
+        max: std::time::Duration,
+    },
+}
+
+impl Backoff {
+    /// The wait before the given retry, counting from 1.
+    pub fn delay(&self, retry: u32) -> std::time::Duration {
+        match *self {
+            Backoff::Constant(delay) => delay,
+            Backoff::Exponential {
+                initial,
+                factor,
+                max,
+            } => {
+                let multiplier = factor
+                    .checked_pow(retry.saturating_sub(1))
+                    .unwrap_or(u32::MAX);
+                initial
+                    .checked_mul(multiplier)
+                    .map_or(max, |delay| delay.min(max))
+            }
+        }
+    }
+}
+
+/// Decides whether a failed attempt at a request is retried, see [`RetryConfig::set_retry_if`].
+pub type RetryPredicate = Arc<
+    dyn Fn(&reqwest::Request, &reqwest_middleware::Result<reqwest::Response>) -> bool + Send + Sync,
+>;
+
+/// When and how often failed requests are retried.
+///
+/// By default, connection errors, timeouts and the `retryable_statuses` are
+/// retried up to 3 times. `POST` and `PATCH` requests are not idempotent, so
+/// they are only retried when they carry an `Idempotency-Key` header.
+#[derive(Clone)]
+pub struct RetryConfig {
+    /// The maximum number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// The wait between two attempts.
+    pub backoff: Backoff,
+    /// The response status codes that are retried.
+    pub retryable_statuses: Vec<http::StatusCode>,
+    /// Wait for as long as the server asks in a `Retry-After` or rate limit
+    /// reset header instead of following the `backoff`. This also retries a
+    /// `403` that comes with one of those headers, as GitHub does for its
+    /// secondary rate limits.
+    pub respect_server_reset: bool,
+    /// Give up instead of waiting for a server reset further away than this,
+    /// like the end of a daily limit.
+    pub max_server_wait: std::time::Duration,
+    /// Replaces the default decision of whether a failed attempt is retried.
+    pub retry_if: Option<RetryPredicate>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_attempts: 4,
+            backoff: Backoff::Exponential {
+                initial: std::time::Duration::from_secs(1),
+                factor: 2,
+                max: std::time::Duration::from_secs(30),
+            },
+            retryable_statuses: vec![
+                http::StatusCode::REQUEST_TIMEOUT,
+                http::StatusCode::TOO_MANY_REQUESTS,
+                http::StatusCode::INTERNAL_SERVER_ERROR,
+                http::StatusCode::BAD_GATEWAY,
+                http::StatusCode::SERVICE_UNAVAILABLE,
+                http::StatusCode::GATEWAY_TIMEOUT,
+            ],
+            respect_server_reset: true,
+            max_server_wait: std::time::Duration::from_secs(60),
+            retry_if: None,
+        }
+    }
+}
+
+impl fmt::Debug for RetryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryConfig")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .field("retryable_statuses", &self.retryable_statuses)
+            .field("respect_server_reset", &self.respect_server_reset)
+            .field("max_server_wait", &self.max_server_wait)
+            .field("retry_if", &self.retry_if.is_some())
+            .finish()
+    }
+}
+
+impl RetryConfig {
+    /// A config that never retries.
+    pub fn none() -> Self {
+        RetryConfig {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Decide per request whether a failed attempt is retried, instead of by
+    /// method and status code. The `max_attempts` still apply.
+    pub fn set_retry_if<F>(&mut self, f: F) -> &mut Self
+    where
+        F: Fn(&reqwest::Request, &reqwest_middleware::Result<reqwest::Response>) -> bool
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.retry_if = Some(Arc::new(f));
+        self
+    }
+
+    /// The wait before retrying `request` after the given attempt, or `None`
+    /// if it should not be retried.
+    pub(crate) fn next_attempt(
+        &self,
+        request: &reqwest::Request,
+        result: &reqwest_middleware::Result<reqwest::Response>,
+        attempt: u32,
+        now: std::time::SystemTime,
+    ) -> Option<std::time::Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let retry = match &self.retry_if {
+            Some(retry_if) => retry_if(request, result),
+            None => self.is_retryable(request, result),
+        };
+        if !retry {
+            return None;
+        }
+
+        let server_wait = match result {
+            Ok(response) if self.respect_server_reset => server_wait(response.headers(), now),
+            _ => None,
+        };
+        match server_wait {
+            Some(wait) if wait > self.max_server_wait => None,
+            Some(wait) => Some(wait),
+            None => Some(self.backoff.delay(attempt)),
+        }
+    }
+
+    fn is_retryable(
+        &self,
+        request: &reqwest::Request,
+        result: &reqwest_middleware::Result<reqwest::Response>,
+    ) -> bool {
+        let idempotent = !matches!(*request.method(), http::Method::POST | http::Method::PATCH)
+            || request.headers().contains_key("idempotency-key");
+        if !idempotent {
+            return false;
+        }
+
+        match result {
+            Ok(response) => {
+                let status = response.status();
+                self.retryable_statuses.contains(&status)
+                    || (self.respect_server_reset
+                        && status == http::StatusCode::FORBIDDEN
+                        && rate_limit_exceeded(response.headers()))
+            }
+            Err(reqwest_middleware::Error::Reqwest(e)) => e.is_connect() || e.is_timeout(),
+            Err(reqwest_middleware::Error::Middleware(_)) => false,
+        }
+    }
+
+    /// Carries the config to the [`RetryMiddleware`] of the client.
+    pub(crate) fn extensions(&self) -> task_local_extensions::Extensions {
+        let mut extensions = task_local_extensions::Extensions::new();
+        extensions.insert(self.clone());
+        extensions
+    }
+}
+
+fn header<'a>(headers: &'a reqwest::header::HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim())
+}
+
+/// Whether the response says the rate limit is used up, rather than access being denied.
+fn rate_limit_exceeded(headers: &reqwest::header::HeaderMap) -> bool {
+    headers.contains_key(reqwest::header::RETRY_AFTER)
+        || header(headers, "x-ratelimit-remaining") == Some("0")
+        || header(headers, "x-rate-limit-remaining") == Some("0")
+}
+
+/// How long the server asks to wait before the next attempt: the `Retry-After`
+/// header, in seconds or as a date, or once the rate limit is used up, the
+/// `X-RateLimit-Reset` (GitHub) or `X-Rate-Limit-Reset` (Okta) timestamp.
+fn server_wait(
+    headers: &reqwest::header::HeaderMap,
+    now: std::time::SystemTime,
+) -> Option<std::time::Duration> {
+    let now = now.duration_since(std::time::UNIX_EPOCH).ok()?.as_secs() as i64;
+    let until = |timestamp: i64| std::time::Duration::from_secs((timestamp - now).max(0) as u64);
+
+    if let Some(retry_after) = header(headers, "retry-after") {
+        if let Ok(seconds) = retry_after.parse::<u64>() {
+            return Some(std::time::Duration::from_secs(seconds));
+        }
+        // Shopify sends fractions of a second, ie. `2.0`.
+        if let Ok(seconds) = retry_after.parse::<f64>() {
+            return Some(seconds)
+                .filter(|s| s.is_finite() && *s >= 0.0)
+                .map(std::time::Duration::from_secs_f64);
+        }
+        // Zoom sends the end of its daily limit as an RFC 3339 date.
+        return chrono::DateTime::parse_from_rfc2822(retry_after)
+            .or_else(|_| chrono::DateTime::parse_from_rfc3339(retry_after))
+            .ok()
+            .map(|date| until(date.timestamp()));
+    }
+
+    for (remaining, reset) in [
+        ("x-ratelimit-remaining", "x-ratelimit-reset"),
+        ("x-rate-limit-remaining", "x-rate-limit-reset"),
+    ] {
+        if header(headers, remaining) == Some("0") {
+            return header(headers, reset)
+                .and_then(|r| r.parse::<i64>().ok())
+                .map(until);
+        }
+    }
+
+    None
+}
+
+/// Retries failed requests following the [`RetryConfig`] of the client that
+/// sent them. Requests sent without one, through a `reqwest_middleware` client
+/// directly, follow the config of the middleware instead.
+#[derive(Debug, Clone, Default)]
+pub struct RetryMiddleware {
+    config: RetryConfig,
+}
+
+impl RetryMiddleware {
+    pub fn new(config: RetryConfig) -> Self {
+        RetryMiddleware { config }
+    }
+
+    fn execute<'a>(
+        &'a self,
+        config: RetryConfig,
+        req: reqwest::Request,
+        next: reqwest_middleware::Next<'a>,
+        extensions: &'a mut task_local_extensions::Extensions,
+        attempt: u32,
+    ) -> futures::future::BoxFuture<'a, reqwest_middleware::Result<reqwest::Response>> {
+        Box::pin(async move {
+            // A request with a streaming body can only be sent once.
+            let duplicate = match req.try_clone() {
+                Some(duplicate) => duplicate,
+                None => return next.run(req, extensions).await,
+            };
+
+            let result = next.clone().run(duplicate, extensions).await;
+            match config.next_attempt(&req, &result, attempt, std::time::SystemTime::now()) {
+                Some(wait) => {
+                    log::debug!(
+                        "retrying {} {} in {:?}, attempt {} failed",
+                        req.method(),
+                        req.url(),
+                        wait,
+                        attempt
+                    );
+                    tokio::time::sleep(wait).await;
+                    self.execute(config, req, next, extensions, attempt + 1)
+                        .await
+                }
+                None => result,
+            }
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let config = extensions
+            .get::<RetryConfig>()
+            .unwrap_or(&self.config)
+            .clone();
+        self.execute(config, req, next, extensions, 1).await
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::{server_wait, Backoff, RetryConfig, RetryMiddleware};
+
+    fn request(method: http::Method) -> reqwest::Request {
+        reqwest::Request::new(method, "https://example.com/things".parse().unwrap())
+    }
+
+    fn response(
+        status: u16,
+        headers: &[(&str, &str)],
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let mut response = http::Response::builder().status(status);
+        for (name, value) in headers {
+            response = response.header(*name, *value);
+        }
+        Ok(response.body("").unwrap().into())
+    }
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Backoff::Exponential {
+            initial: Duration::from_secs(1),
+            factor: 2,
+            max: Duration::from_secs(5),
+        };
+        assert_eq!(backoff.delay(1), Duration::from_secs(1));
+        assert_eq!(backoff.delay(2), Duration::from_secs(2));
+        assert_eq!(backoff.delay(3), Duration::from_secs(4));
+        assert_eq!(backoff.delay(4), Duration::from_secs(5));
+        assert_eq!(backoff.delay(100), Duration::from_secs(5));
+
+        assert_eq!(
+            Backoff::Constant(Duration::from_secs(3)).delay(7),
+            Duration::from_secs(3)
+        );
+    }
+
+    #[test]
+    fn test_server_wait() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let wait =
+            |headers: &[(&str, &str)]| server_wait(response(429, headers).unwrap().headers(), now);
+
+        assert_eq!(wait(&[]), None);
+        assert_eq!(
+            wait(&[("retry-after", "30")]),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            wait(&[("retry-after", "2.5")]),
+            Some(Duration::from_millis(2500))
+        );
+        assert_eq!(
+            wait(&[("retry-after", "Tue, 14 Nov 2023 22:13:27 GMT")]),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(
+            wait(&[("retry-after", "2023-11-14T22:14:20Z")]),
+            Some(Duration::from_secs(60))
+        );
+
+        // GitHub sends the reset on every response, it only matters once the limit is used up.
+        assert_eq!(
+            wait(&[
+                ("x-ratelimit-remaining", "12"),
+                ("x-ratelimit-reset", "1700000100")
+            ]),
+            None
+        );
+        assert_eq!(
+            wait(&[
+                ("x-ratelimit-remaining", "0"),
+                ("x-ratelimit-reset", "1700000100")
+            ]),
+            Some(Duration::from_secs(100))
+        );
+        assert_eq!(
+            wait(&[
+                ("x-rate-limit-remaining", "0"),
+                ("x-rate-limit-reset", "1699999999")
+            ]),
+            Some(Duration::from_secs(0))
+        );
+    }
+
+    #[test]
+    fn test_next_attempt() {
+        let config = RetryConfig {
+            backoff: Backoff::Constant(Duration::from_secs(2)),
+            ..Default::default()
+        };
+        let now = SystemTime::now();
+        let get = request(http::Method::GET);
+
+        assert_eq!(
+            config.next_attempt(&get, &response(503, &[]), 1, now),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(config.next_attempt(&get, &response(503, &[]), 4, now), None);
+        assert_eq!(config.next_attempt(&get, &response(404, &[]), 1, now), None);
+        assert_eq!(
+            config.next_attempt(&get, &response(429, &[("retry-after", "10")]), 1, now),
+            Some(Duration::from_secs(10))
+        );
+        // Past the longest wait we are willing to sleep for.
+        assert_eq!(
+            config.next_attempt(&get, &response(429, &[("retry-after", "3600")]), 1, now),
+            None
+        );
+
+        // A 403 is only retried when it is a rate limit.
+        assert_eq!(config.next_attempt(&get, &response(403, &[]), 1, now), None);
+        assert_eq!(
+            config.next_attempt(&get, &response(403, &[("retry-after", "5")]), 1, now),
+            Some(Duration::from_secs(5))
+        );
+
+        // POSTs need an idempotency key.
+        let mut post = request(http::Method::POST);
+        assert_eq!(
+            config.next_attempt(&post, &response(503, &[]), 1, now),
+            None
+        );
+        post.headers_mut()
+            .insert("idempotency-key", "abc".parse().unwrap());
+        assert_eq!(
+            config.next_attempt(&post, &response(503, &[]), 1, now),
+            Some(Duration::from_secs(2))
+        );
+
+        let config = RetryConfig {
+            respect_server_reset: false,
+            ..config
+        };
+        assert_eq!(
+            config.next_attempt(&get, &response(429, &[("retry-after", "10")]), 1, now),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            config.next_attempt(&get, &response(403, &[("retry-after", "5")]), 1, now),
+            None
+        );
+
+        let mut config = RetryConfig::none();
+        assert_eq!(config.next_attempt(&get, &response(503, &[]), 1, now), None);
+        config.max_attempts = 2;
+        config.set_retry_if(|req, _| req.method() == http::Method::POST);
+        assert_eq!(config.next_attempt(&get, &response(503, &[]), 1, now), None);
+        assert!(config
+            .next_attempt(&request(http::Method::POST), &response(400, &[]), 1, now)
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_retry_middleware() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(matchers::method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(RetryMiddleware::new(RetryConfig::none()))
+            .build();
+        let config = RetryConfig {
+            backoff: Backoff::Constant(Duration::from_millis(1)),
+            ..Default::default()
+        };
+
+        // The config of the request wins over the one of the middleware.
+        let response = client
+            .get(server.uri())
+            .send_with_extensions(&mut config.extensions())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+
+        let response = client
+            .post(server.uri())
+            .send_with_extensions(&mut config.extensions())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
+
+/// How long the leaky bucket of Shopify takes to drain when full: 40 requests
+/// at 2 a second on standard plans, 400 at 20 a second on Shopify Plus.
+const SHOPIFY_DRAIN_SECONDS: f64 = 20.0;
+
+/// Queues requests to stay under the rate limits of the API, instead of
+/// running into them.
+///
+/// The limiter follows the remaining requests and reset time from the last
+/// response, in the `X-RateLimit-*` (GitHub, Zoom), `X-Rate-Limit-*` (Okta) or
+/// `X-Shopify-Shop-Api-Call-Limit` headers. On top of that it can enforce a
+/// rate and a cap on concurrent requests of its own. Clones share the same
+/// budget, so a client and all of its clones stay under the same limits.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    budget: Arc<Mutex<Budget>>,
+    queue: Arc<tokio::sync::Mutex<()>>,
+    concurrency: Option<Arc<tokio::sync::Semaphore>>,
+}
+
+impl RateLimiter {
+    /// A limiter that only follows the rate limit headers of the API.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Also allow at most `requests` every `per`, in bursts of up to `requests`.
+    ///
+    /// Panics if `requests` or `per` is zero, as no request could ever go out.
+    pub fn with_rate(self, requests: u32, per: std::time::Duration) -> Self {
+        assert!(
+            requests > 0,
+            "a rate limit needs to allow at least one request"
+        );
+        assert!(!per.is_zero(), "a rate limit needs a non-zero period");
+        self.budget.lock().unwrap().local = Some(Bucket {
+            tokens: requests as f64,
+            capacity: requests as f64,
+            per_second: requests as f64 / per.as_secs_f64(),
+        });
+        self
+    }
+
+    /// Also allow at most `max` requests in flight at once.
+    ///
+    /// Panics if `max` is zero, as no request could ever go out.
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        assert!(
+            max > 0,
+            "a concurrency cap needs to allow at least one request"
+        );
+        self.concurrency = Some(Arc::new(tokio::sync::Semaphore::new(max)));
+        self
+    }
+
+    /// Waits until the budget allows another request. The permit holds a slot
+    /// under the concurrency cap until it is dropped.
+    pub(crate) async fn acquire(&self) -> Option<tokio::sync::OwnedSemaphorePermit> {
+        let permit = match &self.concurrency {
+            Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        // Callers wait for their turn in the order they came in.
+        let _turn = self.queue.lock().await;
+        loop {
+            let wait = self
+                .budget
+                .lock()
+                .unwrap()
+                .take(tokio::time::Instant::now());
+            match wait {
+                Some(wait) => tokio::time::sleep(wait).await,
+                None => return permit,
+            }
+        }
+    }
+
+    /// Takes the budget left on the server from the headers of a response.
+    pub(crate) fn update(&self, headers: &reqwest::header::HeaderMap) {
+        self.budget.lock().unwrap().update(
+            headers,
+            tokio::time::Instant::now(),
+            std::time::SystemTime::now(),
+        );
+    }
+}
+
+#[derive(Debug, Default)]
+struct Budget {
+    /// The rate set with [`RateLimiter::with_rate`].
+    local: Option<Bucket>,
+    /// The requests left on the server.
+    server: Option<Bucket>,
+    /// When the server budget starts over, if it does not refill as it goes.
+    reset: Option<tokio::time::Instant>,
+    updated: Option<tokio::time::Instant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bucket {
+    tokens: f64,
+    capacity: f64,
+    per_second: f64,
+}
+
+impl Bucket {
+    fn refill(&mut self, elapsed: std::time::Duration) {
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.per_second).min(self.capacity);
+    }
+
+    /// The wait until the next token, if there is none left. Buckets that do
+    /// not refill, the server budget with a reset time, are waited on in `take`.
+    fn wait(&self) -> Option<std::time::Duration> {
+        if self.tokens >= 1.0 || self.per_second <= 0.0 {
+            return None;
+        }
+        Some(std::time::Duration::from_secs_f64(
+            (1.0 - self.tokens) / self.per_second,
+        ))
+    }
+}
+
+impl Budget {
+    fn advance(&mut self, now: tokio::time::Instant) {
+        let elapsed = self.updated.map_or(std::time::Duration::ZERO, |u| {
+            now.saturating_duration_since(u)
+        });
+        self.updated = Some(now);
+
+        if matches!(self.reset, Some(reset) if now >= reset) {
+            self.server = None;
+            self.reset = None;
+        }
+        for bucket in [&mut self.local, &mut self.server].into_iter().flatten() {
+            bucket.refill(elapsed);
+        }
+    }
+
+    /// Takes a token, or returns how long to wait for one.
+    fn take(&mut self, now: tokio::time::Instant) -> Option<std::time::Duration> {
+        self.advance(now);
+
+        let server_wait = match (&self.server, self.reset) {
+            (Some(server), Some(reset)) if server.tokens < 1.0 => Some(reset - now),
+            (Some(server), _) => server.wait(),
+            (None, _) => None,
+        };
+        let wait = server_wait
+            .into_iter()
+            .chain(self.local.and_then(|l| l.wait()))
+            .max();
+        if wait.is_some() {
+            return wait;
+        }
+
+        for bucket in [&mut self.local, &mut self.server].into_iter().flatten() {
+            bucket.tokens -= 1.0;
+        }
+        None
+    }
+
+    fn update(
+        &mut self,
+        headers: &reqwest::header::HeaderMap,
+        now: tokio::time::Instant,
+        wall: std::time::SystemTime,
+    ) {
+        self.advance(now);
+
+        // Shopify sends the fill of its leaky bucket, e.g. `32/40`.
+        if let Some((used, capacity)) =
+            header(headers, "x-shopify-shop-api-call-limit").and_then(|l| l.split_once('/'))
+        {
+            if let (Ok(used), Ok(capacity)) = (used.parse::<f64>(), capacity.parse::<f64>()) {
+                self.server = Some(Bucket {
+                    tokens: capacity - used,
+                    capacity,
+                    per_second: capacity / SHOPIFY_DRAIN_SECONDS,
+                });
+                self.reset = None;
+            }
+            return;
+        }
+
+        let remaining = match header(headers, "x-ratelimit-remaining")
+            .or_else(|| header(headers, "x-rate-limit-remaining"))
+            .and_then(|r| r.parse::<f64>().ok())
+        {
+            Some(remaining) => remaining,
+            None => return,
+        };
+        let seconds = wall
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64);
+        let until_reset = header(headers, "x-ratelimit-reset")
+            .or_else(|| header(headers, "x-rate-limit-reset"))
+            .and_then(|r| r.parse::<i64>().ok())
+            .map(|reset| std::time::Duration::from_secs((reset - seconds).max(0) as u64))
+            .or_else(|| server_wait(headers, wall));
+
+        // Without a reset time we can not tell when the budget comes back.
+        match until_reset {
+            Some(until_reset) => {
+                self.server = Some(Bucket {
+                    tokens: remaining,
+                    capacity: remaining,
+                    per_second: 0.0,
+                });
+                self.reset = Some(now + until_reset);
+            }
+            None => {
+                self.server = None;
+                self.reset = None;
+            }
+        }
+    }
+}
+
+/// Holds requests back to stay under the [`RateLimiter`] of the client that
+/// sent them. Requests sent without one go straight through.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RateLimitMiddleware;
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        extensions: &mut task_local_extensions::Extensions,
+        next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let limiter = match extensions.get::<RateLimiter>() {
+            Some(limiter) => limiter.clone(),
+            None => return next.run(req, extensions).await,
+        };
+
+        let _permit = limiter.acquire().await;
+        let result = next.run(req, extensions).await;
+        if let Ok(response) = &result {
+            limiter.update(response.headers());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod rate_limit_tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use tokio::time::Instant;
+
+    use super::{Bucket, Budget, RateLimitMiddleware, RateLimiter};
+
+    fn headers(headers: &[(&str, &str)]) -> reqwest::header::HeaderMap {
+        let mut map = reqwest::header::HeaderMap::new();
+        for (name, value) in headers {
+            map.insert(
+                name.parse::<reqwest::header::HeaderName>().unwrap(),
+                value.parse().unwrap(),
+            );
+        }
+        map
+    }
+
+    #[test]
+    fn test_budget_reset() {
+        let now = Instant::now();
+        let wall = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut budget = Budget::default();
+        assert_eq!(budget.take(now), None);
+
+        budget.update(
+            &headers(&[
+                ("x-ratelimit-remaining", "2"),
+                ("x-ratelimit-reset", "1700000060"),
+            ]),
+            now,
+            wall,
+        );
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), Some(Duration::from_secs(60)));
+        assert_eq!(
+            budget.take(now + Duration::from_secs(20)),
+            Some(Duration::from_secs(40))
+        );
+        assert_eq!(budget.take(now + Duration::from_secs(60)), None);
+        assert_eq!(budget.server, None);
+
+        // Okta, and a Retry-After when there is no reset.
+        budget.update(
+            &headers(&[
+                ("x-rate-limit-remaining", "0"),
+                ("x-rate-limit-reset", "1700000010"),
+            ]),
+            now,
+            wall,
+        );
+        assert_eq!(budget.take(now), Some(Duration::from_secs(10)));
+        budget.update(
+            &headers(&[("x-ratelimit-remaining", "0"), ("retry-after", "5")]),
+            now,
+            wall,
+        );
+        assert_eq!(budget.take(now), Some(Duration::from_secs(5)));
+
+        // A remaining count that never resets is of no use.
+        budget.update(&headers(&[("x-ratelimit-remaining", "3")]), now, wall);
+        assert_eq!(budget.server, None);
+    }
+
+    #[test]
+    fn test_budget_leaky_bucket() {
+        let now = Instant::now();
+        let mut budget = Budget::default();
+        budget.update(
+            &headers(&[("x-shopify-shop-api-call-limit", "39/40")]),
+            now,
+            SystemTime::now(),
+        );
+        assert_eq!(
+            budget.server,
+            Some(Bucket {
+                tokens: 1.0,
+                capacity: 40.0,
+                per_second: 2.0
+            })
+        );
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), Some(Duration::from_millis(500)));
+        assert_eq!(budget.take(now + Duration::from_secs(1)), None);
+
+        // Shopify Plus stores have a bigger bucket that drains faster.
+        budget.update(
+            &headers(&[("x-shopify-shop-api-call-limit", "400/400")]),
+            now,
+            SystemTime::now(),
+        );
+        assert_eq!(budget.take(now), Some(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn test_budget_local_rate() {
+        let now = Instant::now();
+        let limiter = RateLimiter::new().with_rate(2, Duration::from_secs(1));
+        let mut budget = limiter.budget.lock().unwrap();
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), None);
+        assert_eq!(budget.take(now), Some(Duration::from_millis(500)));
+        assert_eq!(budget.take(now + Duration::from_millis(500)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one request")]
+    fn test_zero_rate() {
+        RateLimiter::new().with_rate(0, Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero period")]
+    fn test_zero_period() {
+        RateLimiter::new().with_rate(10, Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one request")]
+    fn test_zero_concurrency() {
+        RateLimiter::new().with_max_concurrency(0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_acquire_shared_between_clones() {
+        let limiter = RateLimiter::new()
+            .with_rate(1, Duration::from_secs(1))
+            .with_max_concurrency(1);
+        let clone = limiter.clone();
+
+        let start = Instant::now();
+        let permit = limiter.acquire().await;
+        assert!(permit.is_some());
+        // The clone waits for the permit, then for the next token.
+        let waiting = tokio::spawn(async move { clone.acquire().await.map(|_| Instant::now()) });
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        drop(permit);
+        let acquired = waiting.await.unwrap().unwrap();
+        assert_eq!(acquired - start, Duration::from_secs(5));
+
+        limiter.acquire().await;
+        assert_eq!(Instant::now() - start, Duration::from_secs(6));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_middleware() {
+        use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(matchers::method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).insert_header("x-shopify-shop-api-call-limit", "40/40"),
+            )
+            .mount(&server)
+            .await;
+
+        let client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(RateLimitMiddleware)
+            .build();
+        let limiter = RateLimiter::new();
+        let mut extensions = task_local_extensions::Extensions::new();
+        extensions.insert(limiter.clone());
+        client
+            .get(server.uri())
+            .send_with_extensions(&mut extensions)
+            .await
+            .unwrap();
+
+        let tokens = limiter.budget.lock().unwrap().server.unwrap().tokens;
+        assert!(tokens < 1.0, "{}", tokens);
+    }
 }
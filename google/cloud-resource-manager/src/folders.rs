@@ -25,12 +25,34 @@ impl Folders {
      */
     pub async fn list(
         &self,
+        fields: &str,
         page_size: i64,
         page_token: &str,
         parent: &str,
         show_deleted: bool,
     ) -> ClientResult<Vec<crate::types::Folder>> {
+        Ok(self
+            .list_with_response(fields, page_size, page_token, parent, show_deleted)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        page_size: i64,
+        page_token: &str,
+        parent: &str,
+        show_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Folder>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if page_size > 0 {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
@@ -45,9 +67,9 @@ impl Folders {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v2/folders?{}", query_), None);
-        let resp: crate::types::ListFoldersResponse = self
+        let resp: crate::Response<crate::types::ListFoldersResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,7 +79,7 @@ impl Folders {
             .await?;
 
         // Return our response data.
-        Ok(resp.folders.to_vec())
+        Ok(resp.map(|r| r.folders.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v2/folders` endpoint.
@@ -68,10 +90,15 @@ impl Folders {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         parent: &str,
         show_deleted: bool,
     ) -> ClientResult<Vec<crate::types::Folder>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !parent.is_empty() {
             query_args.push(("parent".to_string(), parent.to_string()));
         }
@@ -132,6 +159,93 @@ impl Folders {
         // Return our response data.
         Ok(folders)
     }
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        parent: &str,
+        show_deleted: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Folder>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !parent.is_empty() {
+            query_args.push(("parent".to_string(), parent.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v2/folders?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListFoldersResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.folders;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        parent: &str,
+        show_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Folder>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(fields, parent, show_deleted, None))
+    }
     /**
      * This function performs a `POST` to the `/v2/folders` endpoint.
      *
@@ -143,17 +257,34 @@ impl Folders {
      */
     pub async fn create(
         &self,
+        fields: &str,
         parent: &str,
         body: &crate::types::Folder,
     ) -> ClientResult<crate::types::Operation> {
+        Ok(self.create_with_response(fields, parent, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        fields: &str,
+        parent: &str,
+        body: &crate::types::Folder,
+    ) -> ClientResult<crate::Response<crate::types::Operation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !parent.is_empty() {
             query_args.push(("parent".to_string(), parent.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v2/folders?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -169,12 +300,32 @@ impl Folders {
      */
     pub async fn search(
         &self,
+        fields: &str,
         body: &crate::types::SearchFoldersRequest,
     ) -> ClientResult<Vec<crate::types::Folder>> {
-        let url = self.client.url("/v2/folders:search", None);
-        let resp: crate::types::SearchFoldersResponse = self
+        Ok(self.search_with_response(fields, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v2/folders:search` endpoint.
+     *
+     * As opposed to `search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn search_with_response(
+        &self,
+        fields: &str,
+        body: &crate::types::SearchFoldersRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Folder>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .post(
+            .url(&format!("/v2/folders:search?{}", query_), None);
+        let resp: crate::Response<crate::types::SearchFoldersResponse> = self
+            .client
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -184,7 +335,7 @@ impl Folders {
             .await?;
 
         // Return our response data.
-        Ok(resp.folders.to_vec())
+        Ok(resp.map(|r| r.folders.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v2/{name}` endpoint.
@@ -195,13 +346,34 @@ impl Folders {
      *
      * * `name: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
      */
-    pub async fn get(&self, name: &str) -> ClientResult<crate::types::Folder> {
+    pub async fn get(&self, fields: &str, name: &str) -> ClientResult<crate::types::Folder> {
+        Ok(self.get_with_response(fields, name).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v2/{name}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Folder>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v2/{}", crate::progenitor_support::encode_path(name),),
+            &format!(
+                "/v2/{}?{}",
+                crate::progenitor_support::encode_path(name),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -219,13 +391,34 @@ impl Folders {
      *
      * * `name: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
      */
-    pub async fn delete(&self, name: &str) -> ClientResult<crate::types::Folder> {
+    pub async fn delete(&self, fields: &str, name: &str) -> ClientResult<crate::types::Folder> {
+        Ok(self.delete_with_response(fields, name).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v2/{name}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Folder>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v2/{}", crate::progenitor_support::encode_path(name),),
+            &format!(
+                "/v2/{}?{}",
+                crate::progenitor_support::encode_path(name),
+                query_
+            ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -246,11 +439,32 @@ impl Folders {
      */
     pub async fn patch(
         &self,
+        fields: &str,
         name: &str,
         update_mask: &str,
         body: &crate::types::Folder,
     ) -> ClientResult<crate::types::Folder> {
+        Ok(self
+            .patch_with_response(fields, name, update_mask, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/v2/{name}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+        update_mask: &str,
+        body: &crate::types::Folder,
+    ) -> ClientResult<crate::Response<crate::types::Folder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !update_mask.is_empty() {
             query_args.push(("updateMask".to_string(), update_mask.to_string()));
         }
@@ -264,7 +478,7 @@ impl Folders {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -284,15 +498,38 @@ impl Folders {
      */
     pub async fn mv(
         &self,
+        fields: &str,
         name: &str,
         body: &crate::types::MoveFolderRequest,
     ) -> ClientResult<crate::types::Operation> {
+        Ok(self.mv_with_response(fields, name, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v2/{name}:move` endpoint.
+     *
+     * As opposed to `mv`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn mv_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+        body: &crate::types::MoveFolderRequest,
+    ) -> ClientResult<crate::Response<crate::types::Operation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v2/{}/move", crate::progenitor_support::encode_path(name),),
+            &format!(
+                "/v2/{}:move?{}",
+                crate::progenitor_support::encode_path(name),
+                query_
+            ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -312,18 +549,38 @@ impl Folders {
      */
     pub async fn undelete(
         &self,
+        fields: &str,
         name: &str,
         body: &crate::types::MoveProjectMetadata,
     ) -> ClientResult<crate::types::Folder> {
+        Ok(self.undelete_with_response(fields, name, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v2/{name}:undelete` endpoint.
+     *
+     * As opposed to `undelete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn undelete_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+        body: &crate::types::MoveProjectMetadata,
+    ) -> ClientResult<crate::Response<crate::types::Folder>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v2/{}/undelete",
+                "/v2/{}:undelete?{}",
                 crate::progenitor_support::encode_path(name),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -343,18 +600,41 @@ impl Folders {
      */
     pub async fn get_iam_policy(
         &self,
+        fields: &str,
         resource: &str,
         body: &crate::types::GetIamPolicyRequest,
     ) -> ClientResult<crate::types::Policy> {
+        Ok(self
+            .get_iam_policy_with_response(fields, resource, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v2/{resource}:getIamPolicy` endpoint.
+     *
+     * As opposed to `get_iam_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_iam_policy_with_response(
+        &self,
+        fields: &str,
+        resource: &str,
+        body: &crate::types::GetIamPolicyRequest,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v2/{}/getIamPolicy",
+                "/v2/{}:getIamPolicy?{}",
                 crate::progenitor_support::encode_path(resource),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -374,18 +654,41 @@ impl Folders {
      */
     pub async fn set_iam_policy(
         &self,
+        fields: &str,
         resource: &str,
         body: &crate::types::SetIamPolicyRequest,
     ) -> ClientResult<crate::types::Policy> {
+        Ok(self
+            .set_iam_policy_with_response(fields, resource, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v2/{resource}:setIamPolicy` endpoint.
+     *
+     * As opposed to `set_iam_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn set_iam_policy_with_response(
+        &self,
+        fields: &str,
+        resource: &str,
+        body: &crate::types::SetIamPolicyRequest,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v2/{}/setIamPolicy",
+                "/v2/{}:setIamPolicy?{}",
                 crate::progenitor_support::encode_path(resource),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -405,18 +708,41 @@ impl Folders {
      */
     pub async fn test_iam_permissions(
         &self,
+        fields: &str,
         resource: &str,
         body: &crate::types::TestIamPermissionsRequest,
     ) -> ClientResult<crate::types::TestIamPermissionsResponse> {
+        Ok(self
+            .test_iam_permissions_with_response(fields, resource, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v2/{resource}:testIamPermissions` endpoint.
+     *
+     * As opposed to `test_iam_permissions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn test_iam_permissions_with_response(
+        &self,
+        fields: &str,
+        resource: &str,
+        body: &crate::types::TestIamPermissionsRequest,
+    ) -> ClientResult<crate::Response<crate::types::TestIamPermissionsResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v2/{}/testIamPermissions",
+                "/v2/{}:testIamPermissions?{}",
                 crate::progenitor_support::encode_path(resource),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
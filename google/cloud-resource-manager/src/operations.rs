@@ -20,13 +20,34 @@ impl Operations {
      *
      * * `name: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
      */
-    pub async fn get(&self, name: &str) -> ClientResult<crate::types::Operation> {
+    pub async fn get(&self, fields: &str, name: &str) -> ClientResult<crate::types::Operation> {
+        Ok(self.get_with_response(fields, name).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/{name}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Operation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v1/{}", crate::progenitor_support::encode_path(name),),
+            &format!(
+                "/v1/{}?{}",
+                crate::progenitor_support::encode_path(name),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuditConfig {
     /**
-     * Specifies the audit configuration for a service. The configuration determines which permission types are logged, and what identities, if any, are exempted from logging. An AuditConfig must have one or more AuditLogConfigs. If there are AuditConfigs for both `allServices` and a specific service, the union of the two AuditConfigs is used for that service: the log_types specified in each AuditConfig are enabled, and the exempted_members in each AuditLogConfig are exempted. Example Policy with multiple AuditConfigs: { "audit_configs": [ { "service": "allServices", "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" }, { "log_type": "ADMIN_READ" } ] }, { "service": "sampleservice.googleapis.com", "audit_log_configs": [ { "log_type": "DATA_READ" }, { "log_type": "DATA_WRITE", "exempted_members": [ "user:aliya@example.com" ] } ] } ] } For sampleservice, this policy enables DATA_READ, DATA_WRITE and ADMIN_READ logging. It also exempts jose@example.com from DATA_READ logging, and aliya@example.com from DATA_WRITE logging.
+     * The configuration for logging of each type of permission.
      */
     #[serde(
         default,
@@ -16,7 +16,7 @@ pub struct AuditConfig {
     )]
     pub audit_log_configs: Vec<AuditLogConfig>,
     /**
-     * Specifies the audit configuration for a service. The configuration determines which permission types are logged, and what identities, if any, are exempted from logging. An AuditConfig must have one or more AuditLogConfigs. If there are AuditConfigs for both `allServices` and a specific service, the union of the two AuditConfigs is used for that service: the log_types specified in each AuditConfig are enabled, and the exempted_members in each AuditLogConfig are exempted. Example Policy with multiple AuditConfigs: { "audit_configs": [ { "service": "allServices", "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" }, { "log_type": "ADMIN_READ" } ] }, { "service": "sampleservice.googleapis.com", "audit_log_configs": [ { "log_type": "DATA_READ" }, { "log_type": "DATA_WRITE", "exempted_members": [ "user:aliya@example.com" ] } ] } ] } For sampleservice, this policy enables DATA_READ, DATA_WRITE and ADMIN_READ logging. It also exempts jose@example.com from DATA_READ logging, and aliya@example.com from DATA_WRITE logging.
+     * Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
      */
     #[serde(
         default,
@@ -30,6 +30,7 @@ pub struct AuditConfig {
 * The log type that this config enables.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LogType {
     #[serde(rename = "ADMIN_READ")]
     AdminRead,
@@ -41,8 +42,9 @@ pub enum LogType {
     LogTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogType {
@@ -53,12 +55,34 @@ impl std::fmt::Display for LogType {
             LogType::DataWrite => "DATA_WRITE",
             LogType::LogTypeUnspecified => "LOG_TYPE_UNSPECIFIED",
             LogType::Noop => "",
-            LogType::FallthroughString => "*",
+            LogType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LogType {
+    fn from(s: String) -> LogType {
+        match s.as_str() {
+            "ADMIN_READ" => LogType::AdminRead,
+            "DATA_READ" => LogType::DataRead,
+            "DATA_WRITE" => LogType::DataWrite,
+            "LOG_TYPE_UNSPECIFIED" => LogType::LogTypeUnspecified,
+            "" => LogType::Noop,
+            _ => LogType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LogType> for String {
+    fn from(e: LogType) -> String {
+        match e {
+            LogType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LogType {
     fn default() -> LogType {
         LogType::Noop
@@ -74,7 +98,7 @@ impl LogType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuditLogConfig {
     /**
-     * Provides the configuration for logging a type of permissions. Example: { "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" } ] } This enables 'DATA_READ' and 'DATA_WRITE' logging, while exempting jose@example.com from DATA_READ logging.
+     * Specifies the identities that do not cause logging for this type of permission. Follows the same format of Binding.members.
      */
     #[serde(
         default,
@@ -84,7 +108,7 @@ pub struct AuditLogConfig {
     )]
     pub exempted_members: Vec<String>,
     /**
-     * Provides the configuration for logging a type of permissions. Example: { "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" } ] } This enables 'DATA_READ' and 'DATA_WRITE' logging, while exempting jose@example.com from DATA_READ logging.
+     * The log type that this config enables.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "logType")]
     pub log_type: Option<LogType>,
@@ -94,12 +118,12 @@ pub struct AuditLogConfig {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Binding {
     /**
-     * Associates `members` with a `role`.
+     * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub condition: Option<Expr>,
     /**
-     * Associates `members` with a `role`.
+     * Specifies the identities requesting access for a Cloud Platform resource. `members` can have the following values: \* `allUsers`: A special identifier that represents anyone who is on the internet; with or without a Google account. \* `allAuthenticatedUsers`: A special identifier that represents anyone who is authenticated with a Google account or a service account. \* `user:{emailid}`: An email address that represents a specific Google account. For example, `alice@example.com` . \* `serviceAccount:{emailid}`: An email address that represents a service account. For example, `my-other-app@appspot.gserviceaccount.com`. \* `group:{emailid}`: An email address that represents a Google group. For example, `admins@example.com`. \* `deleted:user:{emailid}?uid={uniqueid}`: An email address (plus unique identifier) representing a user that has been recently deleted. For example, `alice@example.com?uid=123456789012345678901`. If the user is recovered, this value reverts to `user:{emailid}` and the recovered user retains the role in the binding. \* `deleted:serviceAccount:{emailid}?uid={uniqueid}`: An email address (plus unique identifier) representing a service account that has been recently deleted. For example, `my-other-app@appspot.gserviceaccount.com?uid=123456789012345678901`. If the service account is undeleted, this value reverts to `serviceAccount:{emailid}` and the undeleted service account retains the role in the binding. \* `deleted:group:{emailid}?uid={uniqueid}`: An email address (plus unique identifier) representing a Google group that has been recently deleted. For example, `admins@example.com?uid=123456789012345678901`. If the group is recovered, this value reverts to `group:{emailid}` and the recovered group retains the role in the binding. \* `domain:{domain}`: The G Suite domain (primary) that represents all the users of that domain. For example, `google.com` or `example.com`.
      */
     #[serde(
         default,
@@ -108,7 +132,7 @@ pub struct Binding {
     )]
     pub members: Vec<String>,
     /**
-     * Associates `members` with a `role`.
+     * Role that is assigned to `members`. For example, `roles/viewer`, `roles/editor`, or `roles/owner`.
      */
     #[serde(
         default,
@@ -122,6 +146,7 @@ pub struct Binding {
 * The type of this operation.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OperationType {
     #[serde(rename = "CREATE")]
     Create,
@@ -131,8 +156,9 @@ pub enum OperationType {
     OperationTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OperationType {
@@ -142,12 +168,33 @@ impl std::fmt::Display for OperationType {
             OperationType::Move => "MOVE",
             OperationType::OperationTypeUnspecified => "OPERATION_TYPE_UNSPECIFIED",
             OperationType::Noop => "",
-            OperationType::FallthroughString => "*",
+            OperationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OperationType {
+    fn from(s: String) -> OperationType {
+        match s.as_str() {
+            "CREATE" => OperationType::Create,
+            "MOVE" => OperationType::Move,
+            "OPERATION_TYPE_UNSPECIFIED" => OperationType::OperationTypeUnspecified,
+            "" => OperationType::Noop,
+            _ => OperationType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OperationType> for String {
+    fn from(e: OperationType) -> String {
+        match e {
+            OperationType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OperationType {
     fn default() -> OperationType {
         OperationType::Noop
@@ -163,7 +210,7 @@ impl OperationType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FolderOperation {
     /**
-     * Metadata describing a long running folder operation
+     * The resource name of the folder or organization we are either creating the folder under or moving the folder to.
      */
     #[serde(
         default,
@@ -173,7 +220,7 @@ pub struct FolderOperation {
     )]
     pub destination_parent: String,
     /**
-     * Metadata describing a long running folder operation
+     * The display name of the folder.
      */
     #[serde(
         default,
@@ -183,7 +230,7 @@ pub struct FolderOperation {
     )]
     pub display_name: String,
     /**
-     * Metadata describing a long running folder operation
+     * The type of this operation.
      */
     #[serde(
         default,
@@ -192,7 +239,7 @@ pub struct FolderOperation {
     )]
     pub operation_type: Option<OperationType>,
     /**
-     * Metadata describing a long running folder operation
+     * The resource name of the folder's parent. Only applicable when the operation_type is MOVE.
      */
     #[serde(
         default,
@@ -207,7 +254,7 @@ pub struct FolderOperation {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateFolderMetadata {
     /**
-     * Metadata pertaining to the Folder creation process.
+     * The display name of the folder.
      */
     #[serde(
         default,
@@ -217,7 +264,7 @@ pub struct CreateFolderMetadata {
     )]
     pub display_name: String,
     /**
-     * Metadata pertaining to the Folder creation process.
+     * The resource name of the folder or organization we are creating the folder under.
      */
     #[serde(
         default,
@@ -231,7 +278,7 @@ pub struct CreateFolderMetadata {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateProjectMetadata {
     /**
-     * A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
+     * Creation time of the project creation workflow.
      */
     #[serde(
         default,
@@ -241,7 +288,7 @@ pub struct CreateProjectMetadata {
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
+     * True if the project can be retrieved using `GetProject`. No other operations on the project are guaranteed to work until the project creation is complete.
      */
     #[serde(
         default,
@@ -249,7 +296,7 @@ pub struct CreateProjectMetadata {
     )]
     pub gettable: bool,
     /**
-     * A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
+     * True if the project creation process is complete.
      */
     #[serde(
         default,
@@ -266,7 +313,7 @@ pub struct MoveProjectMetadata {}
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Expr {
     /**
-     * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
+     * Optional. Description of the expression. This is a longer text which describes the expression, e.g. when hovered over it in a UI.
      */
     #[serde(
         default,
@@ -275,7 +322,7 @@ pub struct Expr {
     )]
     pub description: String,
     /**
-     * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
+     * Textual representation of an expression in Common Expression Language syntax.
      */
     #[serde(
         default,
@@ -284,7 +331,7 @@ pub struct Expr {
     )]
     pub expression: String,
     /**
-     * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
+     * Optional. String indicating the location of the expression for error reporting, e.g. a file name and a position in the file.
      */
     #[serde(
         default,
@@ -293,7 +340,7 @@ pub struct Expr {
     )]
     pub location: String,
     /**
-     * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
+     * Optional. Title for the expression, i.e. a short string describing its purpose. This can be used e.g. in UIs which allow to enter the expression.
      */
     #[serde(
         default,
@@ -307,6 +354,7 @@ pub struct Expr {
 * Output only. The lifecycle state of the folder. Updates to the lifecycle_state must be performed via DeleteFolder and UndeleteFolder.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LifecycleState {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -316,8 +364,9 @@ pub enum LifecycleState {
     LifecycleStateUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LifecycleState {
@@ -327,12 +376,33 @@ impl std::fmt::Display for LifecycleState {
             LifecycleState::DeleteRequested => "DELETE_REQUESTED",
             LifecycleState::LifecycleStateUnspecified => "LIFECYCLE_STATE_UNSPECIFIED",
             LifecycleState::Noop => "",
-            LifecycleState::FallthroughString => "*",
+            LifecycleState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LifecycleState {
+    fn from(s: String) -> LifecycleState {
+        match s.as_str() {
+            "ACTIVE" => LifecycleState::Active,
+            "DELETE_REQUESTED" => LifecycleState::DeleteRequested,
+            "LIFECYCLE_STATE_UNSPECIFIED" => LifecycleState::LifecycleStateUnspecified,
+            "" => LifecycleState::Noop,
+            _ => LifecycleState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LifecycleState> for String {
+    fn from(e: LifecycleState) -> String {
+        match e {
+            LifecycleState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LifecycleState {
     fn default() -> LifecycleState {
         LifecycleState::Noop
@@ -348,7 +418,7 @@ impl LifecycleState {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Folder {
     /**
-     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     * Output only. Timestamp when the Folder was created. Assigned by the server.
      */
     #[serde(
         default,
@@ -358,7 +428,7 @@ pub struct Folder {
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     * The folder's display name. A folder's display name must be unique amongst its siblings, e.g. no two folders with the same parent can share the same display name. The display name must start and end with a letter or digit, may contain letters, digits, spaces, hyphens and underscores and can be no longer than 30 characters. This is captured by the regular expression: `[\p{L}\p{N}]([\p{L}\p{N}_- ]{0,28}[\p{L}\p{N}])?`.
      */
     #[serde(
         default,
@@ -368,7 +438,7 @@ pub struct Folder {
     )]
     pub display_name: String,
     /**
-     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     * Output only. The lifecycle state of the folder. Updates to the lifecycle_state must be performed via DeleteFolder and UndeleteFolder.
      */
     #[serde(
         default,
@@ -377,7 +447,7 @@ pub struct Folder {
     )]
     pub lifecycle_state: Option<LifecycleState>,
     /**
-     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     * Output only. The resource name of the Folder. Its format is `folders/{folder_id}`, for example: "folders/1234".
      */
     #[serde(
         default,
@@ -386,7 +456,7 @@ pub struct Folder {
     )]
     pub name: String,
     /**
-     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     * Required. The Folder's parent's resource name. Updates to the folder's parent must be performed via MoveFolder.
      */
     #[serde(
         default,
@@ -400,6 +470,7 @@ pub struct Folder {
 * The type of operation error experienced.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorMessageId {
     #[serde(rename = "ACTIVE_FOLDER_HEIGHT_VIOLATION")]
     ActiveFolderHeightViolation,
@@ -423,8 +494,9 @@ pub enum ErrorMessageId {
     ResourceDeletedViolation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorMessageId {
@@ -443,12 +515,42 @@ impl std::fmt::Display for ErrorMessageId {
             ErrorMessageId::ParentDeletedViolation => "PARENT_DELETED_VIOLATION",
             ErrorMessageId::ResourceDeletedViolation => "RESOURCE_DELETED_VIOLATION",
             ErrorMessageId::Noop => "",
-            ErrorMessageId::FallthroughString => "*",
+            ErrorMessageId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ErrorMessageId {
+    fn from(s: String) -> ErrorMessageId {
+        match s.as_str() {
+            "ACTIVE_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::ActiveFolderHeightViolation,
+            "CYCLE_INTRODUCED_VIOLATION" => ErrorMessageId::CycleIntroducedViolation,
+            "DELETED_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::DeletedFolderHeightViolation,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorMessageId::ErrorTypeUnspecified,
+            "FOLDER_BEING_MOVED_VIOLATION" => ErrorMessageId::FolderBeingMovedViolation,
+            "FOLDER_NAME_UNIQUENESS_VIOLATION" => ErrorMessageId::FolderNameUniquenessViolation,
+            "FOLDER_TO_DELETE_NON_EMPTY_VIOLATION" => {
+                ErrorMessageId::FolderToDeleteNonEmptyViolation
+            }
+            "MAX_CHILD_FOLDERS_VIOLATION" => ErrorMessageId::MaxChildFoldersViolation,
+            "PARENT_DELETED_VIOLATION" => ErrorMessageId::ParentDeletedViolation,
+            "RESOURCE_DELETED_VIOLATION" => ErrorMessageId::ResourceDeletedViolation,
+            "" => ErrorMessageId::Noop,
+            _ => ErrorMessageId::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ErrorMessageId> for String {
+    fn from(e: ErrorMessageId) -> String {
+        match e {
+            ErrorMessageId::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ErrorMessageId {
     fn default() -> ErrorMessageId {
         ErrorMessageId::Noop
@@ -464,7 +566,7 @@ impl ErrorMessageId {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FolderOperationError {
     /**
-     * A classification of the Folder Operation error.
+     * The type of operation error experienced.
      */
     #[serde(
         default,
@@ -478,7 +580,7 @@ pub struct FolderOperationError {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIamPolicyRequest {
     /**
-     * Request message for `GetIamPolicy` method.
+     * Encapsulates settings provided to GetIamPolicy.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub options: Option<GetPolicyOptions>,
@@ -488,7 +590,7 @@ pub struct GetIamPolicyRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetPolicyOptions {
     /**
-     * Encapsulates settings provided to GetIamPolicy.
+     * Optional. The policy format version to be returned. Valid values are 0, 1, and 3. Requests specifying an invalid value will be rejected. Requests for policies with any conditional bindings must specify version 3. Policies without any conditional bindings may specify any valid value or leave the field unset. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies).
      */
     #[serde(
         default,
@@ -503,7 +605,7 @@ pub struct GetPolicyOptions {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListFoldersResponse {
     /**
-     * The ListFolders response message.
+     * A possibly paginated list of Folders that are direct descendants of the specified parent resource.
      */
     #[serde(
         default,
@@ -512,7 +614,7 @@ pub struct ListFoldersResponse {
     )]
     pub folders: Vec<Folder>,
     /**
-     * The ListFolders response message.
+     * A pagination token returned from a previous call to `ListFolders` that indicates from where listing should continue.
      */
     #[serde(
         default,
@@ -527,7 +629,7 @@ pub struct ListFoldersResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MoveFolderMetadata {
     /**
-     * Metadata pertaining to the folder move process.
+     * The resource name of the folder or organization to move the folder to.
      */
     #[serde(
         default,
@@ -537,7 +639,7 @@ pub struct MoveFolderMetadata {
     )]
     pub destination_parent: String,
     /**
-     * Metadata pertaining to the folder move process.
+     * The display name of the folder.
      */
     #[serde(
         default,
@@ -547,7 +649,7 @@ pub struct MoveFolderMetadata {
     )]
     pub display_name: String,
     /**
-     * Metadata pertaining to the folder move process.
+     * The resource name of the folder's parent.
      */
     #[serde(
         default,
@@ -562,7 +664,7 @@ pub struct MoveFolderMetadata {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MoveFolderRequest {
     /**
-     * The MoveFolder request message.
+     * Required. The resource name of the Folder or Organization to reparent the folder under. Must be of the form `folders/{folder_id}` or `organizations/{org_id}`.
      */
     #[serde(
         default,
@@ -577,7 +679,7 @@ pub struct MoveFolderRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Operation {
     /**
-     * This resource represents a long-running operation that is the result of a network API call.
+     * If the value is `false`, it means the operation is still in progress. If `true`, the operation is completed, and either `error` or `response` is available.
      */
     #[serde(
         default,
@@ -585,17 +687,17 @@ pub struct Operation {
     )]
     pub done: bool,
     /**
-     * This resource represents a long-running operation that is the result of a network API call.
+     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<Status>,
     /**
-     * This resource represents a long-running operation that is the result of a network API call.
+     * Service-specific metadata associated with the operation. It typically contains progress information and common metadata such as create time. Some services might not provide such metadata. Any method that returns a long-running operation should document the metadata type, if any.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
     /**
-     * This resource represents a long-running operation that is the result of a network API call.
+     * The server-assigned name, which is only unique within the same service that originally returns it. If you use the default HTTP mapping, the `name` should be a resource name ending with `operations/{unique_id}`.
      */
     #[serde(
         default,
@@ -604,7 +706,7 @@ pub struct Operation {
     )]
     pub name: String,
     /**
-     * This resource represents a long-running operation that is the result of a network API call.
+     * The normal response of the operation in case of success. If the original method returns no data on success, such as `Delete`, the response is `google.protobuf.Empty`. If the original method is standard `Get`/`Create`/`Update`, the response should be the resource. For other methods, the response should have the type `XxxResponse`, where `Xxx` is the original method name. For example, if the original method name is `TakeSnapshot()`, the inferred response type is `TakeSnapshotResponse`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response: Option<serde_json::Value>,
@@ -614,7 +716,7 @@ pub struct Operation {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Policy {
     /**
-     * An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). \*\*JSON example:\*\* { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } \*\*YAML example:\*\* bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
+     * Specifies cloud audit logging configuration for this policy.
      */
     #[serde(
         default,
@@ -624,7 +726,7 @@ pub struct Policy {
     )]
     pub audit_configs: Vec<AuditConfig>,
     /**
-     * An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). \*\*JSON example:\*\* { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } \*\*YAML example:\*\* bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
+     * Associates a list of `members` to a `role`. Optionally, may specify a `condition` that determines how and when the `bindings` are applied. Each of the `bindings` must contain at least one member.
      */
     #[serde(
         default,
@@ -633,12 +735,12 @@ pub struct Policy {
     )]
     pub bindings: Vec<Binding>,
     /**
-     * An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). \*\*JSON example:\*\* { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } \*\*YAML example:\*\* bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
+     * `etag` is used for optimistic concurrency control as a way to help prevent simultaneous updates of a policy from overwriting each other. It is strongly suggested that systems make use of the `etag` in the read-modify-write cycle to perform policy updates in order to avoid race conditions: An `etag` is returned in the response to `getIamPolicy`, and systems are expected to put that etag in the request to `setIamPolicy` to ensure that their change will be applied to the same version of the policy. \*\*Important:\*\* If you use IAM Conditions, you must include the `etag` field whenever you call `setIamPolicy`. If you omit this field, then IAM allows you to overwrite a version `3` policy with a version `1` policy, and all of the conditions in the version `3` policy are lost.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub etag: Option<bytes::Bytes>,
     /**
-     * An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). \*\*JSON example:\*\* { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } \*\*YAML example:\*\* bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
+     * Specifies the format of the policy. Valid values are `0`, `1`, and `3`. Requests that specify an invalid value are rejected. Any operation that affects conditional role bindings must specify version `3`. This requirement applies to the following operations: \* Getting a policy that includes a conditional role binding \* Adding a conditional role binding to a policy \* Changing a conditional role binding in a policy \* Removing any role binding, with or without a condition, from a policy that includes conditions \*\*Important:\*\* If you use IAM Conditions, you must include the `etag` field whenever you call `setIamPolicy`. If you omit this field, then IAM allows you to overwrite a version `3` policy with a version `1` policy, and all of the conditions in the version `3` policy are lost. If a policy does not include any conditions, operations on that policy may specify any valid version or leave the field unset. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies).
      */
     #[serde(
         default,
@@ -652,7 +754,7 @@ pub struct Policy {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchFoldersRequest {
     /**
-     * The request message for searching folders.
+     * Optional. The maximum number of folders to return in the response. The server can return fewer folders than requested. If unspecified, server picks an appropriate default.
      */
     #[serde(
         default,
@@ -662,7 +764,7 @@ pub struct SearchFoldersRequest {
     )]
     pub page_size: i64,
     /**
-     * The request message for searching folders.
+     * Optional. A pagination token returned from a previous call to `SearchFolders` that indicates from where search should continue.
      */
     #[serde(
         default,
@@ -672,7 +774,7 @@ pub struct SearchFoldersRequest {
     )]
     pub page_token: String,
     /**
-     * The request message for searching folders.
+     * Search criteria used to select the Folders to return. If no search criteria is specified then all accessible folders will be returned. Query expressions can be used to restrict results based upon displayName, lifecycleState and parent, where the operators `=`, `NOT`, `AND` and `OR` can be used along with the suffix wildcard symbol `\*`. The displayName field in a query expression should use escaped quotes for values that include whitespace to prevent unexpected behavior. Some example queries are: \* Query `displayName=Test\*` returns Folder resources whose display name starts with "Test". \* Query `lifecycleState=ACTIVE` returns Folder resources with `lifecycleState` set to `ACTIVE`. \* Query `parent=folders/123` returns Folder resources that have `folders/123` as a parent resource. \* Query `parent=folders/123 AND lifecycleState=ACTIVE` returns active Folder resources that have `folders/123` as a parent resource. \* Query `displayName=\\"Test String\\"` returns Folder resources with display names that include both "Test" and "String".
      */
     #[serde(
         default,
@@ -686,7 +788,7 @@ pub struct SearchFoldersRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchFoldersResponse {
     /**
-     * The response message for searching folders.
+     * A possibly paginated folder search results. the specified parent resource.
      */
     #[serde(
         default,
@@ -695,7 +797,7 @@ pub struct SearchFoldersResponse {
     )]
     pub folders: Vec<Folder>,
     /**
-     * The response message for searching folders.
+     * A pagination token returned from a previous call to `SearchFolders` that indicates from where searching should continue.
      */
     #[serde(
         default,
@@ -710,12 +812,12 @@ pub struct SearchFoldersResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetIamPolicyRequest {
     /**
-     * Request message for `SetIamPolicy` method.
+     * An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). \*\*JSON example:\*\* { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } \*\*YAML example:\*\* bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub policy: Option<Policy>,
     /**
-     * Request message for `SetIamPolicy` method.
+     * OPTIONAL: A FieldMask specifying which fields of the policy to modify. Only the fields in the mask will be modified. If no mask is provided, the following default mask is used: `paths: "bindings, etag"`
      */
     #[serde(
         default,
@@ -730,7 +832,7 @@ pub struct SetIamPolicyRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Status {
     /**
-     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+     * The status code, which should be an enum value of google.rpc.Code.
      */
     #[serde(
         default,
@@ -739,7 +841,7 @@ pub struct Status {
     )]
     pub code: i64,
     /**
-     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+     * A list of messages that carry the error details. There is a common set of message types for APIs to use.
      */
     #[serde(
         default,
@@ -748,7 +850,7 @@ pub struct Status {
     )]
     pub details: Vec<serde_json::Value>,
     /**
-     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+     * A developer-facing error message, which should be in English. Any user-facing error message should be localized and sent in the google.rpc.Status.details field, or localized by the client.
      */
     #[serde(
         default,
@@ -762,7 +864,7 @@ pub struct Status {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TestIamPermissionsRequest {
     /**
-     * Request message for `TestIamPermissions` method.
+     * The set of permissions to check for the `resource`. Permissions with wildcards (such as '\*' or 'storage.\*') are not allowed. For more information see [IAM Overview](https://cloud.google.com/iam/docs/overview#permissions).
      */
     #[serde(
         default,
@@ -776,7 +878,7 @@ pub struct TestIamPermissionsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TestIamPermissionsResponse {
     /**
-     * Response message for `TestIamPermissions` method.
+     * A subset of `TestPermissionsRequest.permissions` that the caller is allowed.
      */
     #[serde(
         default,
@@ -790,6 +892,7 @@ pub struct TestIamPermissionsResponse {
 * V1 error format.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -797,8 +900,9 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -807,12 +911,32 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Xgafv {
+    fn from(s: String) -> Xgafv {
+        match s.as_str() {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Xgafv> for String {
+    fn from(e: Xgafv) -> String {
+        match e {
+            Xgafv::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -828,6 +952,7 @@ impl Xgafv {
 * Data format for response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -837,8 +962,9 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -848,12 +974,33 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Alt {
+        match s.as_str() {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(e: Alt) -> String {
+        match e {
+            Alt::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
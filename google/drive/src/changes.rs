@@ -34,6 +34,7 @@ impl Changes {
      */
     pub async fn list(
         &self,
+        fields: &str,
         page_token: &str,
         drive_id: &str,
         include_corpus_removals: bool,
@@ -48,10 +49,55 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> ClientResult<Vec<crate::types::Change>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                page_token,
+                drive_id,
+                include_corpus_removals,
+                include_items_from_all_drives,
+                include_permissions_for_view,
+                include_removed,
+                include_team_drive_items,
+                page_size,
+                restrict_to_my_drive,
+                spaces,
+                supports_all_drives,
+                supports_team_drives,
+                team_drive_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Change>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !drive_id.is_empty() {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_corpus_removals {
             query_args.push((
                 "includeCorpusRemovals".to_string(),
@@ -111,9 +157,9 @@ impl Changes {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/changes?{}", query_), None);
-        let resp: crate::types::ChangeList = self
+        let resp: crate::Response<crate::types::ChangeList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -123,7 +169,7 @@ impl Changes {
             .await?;
 
         // Return our response data.
-        Ok(resp.changes.to_vec())
+        Ok(resp.map(|r| r.changes.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/changes` endpoint.
@@ -134,6 +180,7 @@ impl Changes {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         drive_id: &str,
         include_corpus_removals: bool,
         include_items_from_all_drives: bool,
@@ -146,10 +193,14 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> ClientResult<Vec<crate::types::Change>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
         if !drive_id.is_empty() {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_corpus_removals {
             query_args.push((
                 "includeCorpusRemovals".to_string(),
@@ -255,6 +306,173 @@ impl Changes {
         // Return our response data.
         Ok(changes)
     }
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Change>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !drive_id.is_empty() {
+            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if include_corpus_removals {
+            query_args.push((
+                "includeCorpusRemovals".to_string(),
+                include_corpus_removals.to_string(),
+            ));
+        }
+        if include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives".to_string(),
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if include_removed {
+            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+        }
+        if include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems".to_string(),
+                include_team_drive_items.to_string(),
+            ));
+        }
+        if restrict_to_my_drive {
+            query_args.push((
+                "restrictToMyDrive".to_string(),
+                restrict_to_my_drive.to_string(),
+            ));
+        }
+        if !spaces.is_empty() {
+            query_args.push(("spaces".to_string(), spaces.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if !team_drive_id.is_empty() {
+            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/changes?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ChangeList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.changes;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Change>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/changes/startPageToken` endpoint.
      *
@@ -269,15 +487,43 @@ impl Changes {
      */
     pub async fn get_start_page_token(
         &self,
+        fields: &str,
         drive_id: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> ClientResult<crate::types::StartPageToken> {
+        Ok(self
+            .get_start_page_token_with_response(
+                fields,
+                drive_id,
+                supports_all_drives,
+                supports_team_drives,
+                team_drive_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/changes/startPageToken` endpoint.
+     *
+     * As opposed to `get_start_page_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_start_page_token_with_response(
+        &self,
+        fields: &str,
+        drive_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::StartPageToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !drive_id.is_empty() {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
@@ -298,7 +544,7 @@ impl Changes {
             .client
             .url(&format!("/changes/startPageToken?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -330,6 +576,7 @@ impl Changes {
      */
     pub async fn watch(
         &self,
+        fields: &str,
         page_token: &str,
         drive_id: &str,
         include_corpus_removals: bool,
@@ -345,10 +592,57 @@ impl Changes {
         team_drive_id: &str,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .watch_with_response(
+                fields,
+                page_token,
+                drive_id,
+                include_corpus_removals,
+                include_items_from_all_drives,
+                include_permissions_for_view,
+                include_removed,
+                include_team_drive_items,
+                page_size,
+                restrict_to_my_drive,
+                spaces,
+                supports_all_drives,
+                supports_team_drives,
+                team_drive_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/changes/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watch_with_response(
+        &self,
+        fields: &str,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !drive_id.is_empty() {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_corpus_removals {
             query_args.push((
                 "includeCorpusRemovals".to_string(),
@@ -409,7 +703,7 @@ impl Changes {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/changes/watch?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -25,12 +25,34 @@ impl Drives {
      */
     pub async fn list(
         &self,
+        fields: &str,
         page_size: i64,
         page_token: &str,
         q: &str,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::Drive>> {
+        Ok(self
+            .list_with_response(fields, page_size, page_token, q, use_domain_admin_access)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/drives` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Drive>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if page_size > 0 {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
@@ -48,9 +70,9 @@ impl Drives {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/drives?{}", query_), None);
-        let resp: crate::types::DriveList = self
+        let resp: crate::Response<crate::types::DriveList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +82,7 @@ impl Drives {
             .await?;
 
         // Return our response data.
-        Ok(resp.drives.to_vec())
+        Ok(resp.map(|r| r.drives.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/drives` endpoint.
@@ -71,10 +93,15 @@ impl Drives {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         q: &str,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::Drive>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -138,6 +165,101 @@ impl Drives {
         // Return our response data.
         Ok(drives)
     }
+    /**
+     * This function performs a `GET` to the `/drives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Drive>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/drives?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::DriveList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.drives;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/drives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Drive>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            q,
+            use_domain_admin_access,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/drives` endpoint.
      *
@@ -149,17 +271,37 @@ impl Drives {
      */
     pub async fn create(
         &self,
+        fields: &str,
         request_id: &str,
         body: &crate::types::Drive,
     ) -> ClientResult<crate::types::Drive> {
+        Ok(self
+            .create_with_response(fields, request_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/drives` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        fields: &str,
+        request_id: &str,
+        body: &crate::types::Drive,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !request_id.is_empty() {
             query_args.push(("requestId".to_string(), request_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/drives?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -180,10 +322,30 @@ impl Drives {
      */
     pub async fn get(
         &self,
+        fields: &str,
         drive_id: &str,
         use_domain_admin_access: bool,
     ) -> ClientResult<crate::types::Drive> {
+        Ok(self
+            .get_with_response(fields, drive_id, use_domain_admin_access)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/drives/{driveId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
@@ -200,7 +362,7 @@ impl Drives {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -218,16 +380,35 @@ impl Drives {
      *
      * * `drive_id: &str` -- A link to this theme's background image.
      */
-    pub async fn delete(&self, drive_id: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, drive_id: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, drive_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/drives/{driveId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        drive_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/drives/{}",
+                "/drives/{}?{}",
                 crate::progenitor_support::encode_path(drive_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -248,11 +429,32 @@ impl Drives {
      */
     pub async fn update(
         &self,
+        fields: &str,
         drive_id: &str,
         use_domain_admin_access: bool,
         body: &crate::types::Drive,
     ) -> ClientResult<crate::types::Drive> {
+        Ok(self
+            .update_with_response(fields, drive_id, use_domain_admin_access, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/drives/{driveId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::Drive,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
@@ -269,7 +471,7 @@ impl Drives {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -287,16 +489,34 @@ impl Drives {
      *
      * * `drive_id: &str` -- A link to this theme's background image.
      */
-    pub async fn hide(&self, drive_id: &str) -> ClientResult<crate::types::Drive> {
+    pub async fn hide(&self, fields: &str, drive_id: &str) -> ClientResult<crate::types::Drive> {
+        Ok(self.hide_with_response(fields, drive_id).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/drives/{driveId}/hide` endpoint.
+     *
+     * As opposed to `hide`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn hide_with_response(
+        &self,
+        fields: &str,
+        drive_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/drives/{}/hide",
+                "/drives/{}/hide?{}",
                 crate::progenitor_support::encode_path(drive_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -314,16 +534,34 @@ impl Drives {
      *
      * * `drive_id: &str` -- A link to this theme's background image.
      */
-    pub async fn unhide(&self, drive_id: &str) -> ClientResult<crate::types::Drive> {
+    pub async fn unhide(&self, fields: &str, drive_id: &str) -> ClientResult<crate::types::Drive> {
+        Ok(self.unhide_with_response(fields, drive_id).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/drives/{driveId}/unhide` endpoint.
+     *
+     * As opposed to `unhide`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unhide_with_response(
+        &self,
+        fields: &str,
+        drive_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Drive>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/drives/{}/unhide",
+                "/drives/{}/unhide?{}",
                 crate::progenitor_support::encode_path(drive_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
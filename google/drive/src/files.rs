@@ -35,6 +35,7 @@ impl Files {
      */
     pub async fn list(
         &self,
+        fields: &str,
         corpora: &str,
         drive_id: &str,
         include_items_from_all_drives: bool,
@@ -49,6 +50,48 @@ impl Files {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> ClientResult<Vec<crate::types::File>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                corpora,
+                drive_id,
+                include_items_from_all_drives,
+                include_permissions_for_view,
+                include_team_drive_items,
+                order_by,
+                page_size,
+                page_token,
+                q,
+                spaces,
+                supports_all_drives,
+                supports_team_drives,
+                team_drive_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !corpora.is_empty() {
             query_args.push(("corpora".to_string(), corpora.to_string()));
@@ -56,6 +99,9 @@ impl Files {
         if !drive_id.is_empty() {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_items_from_all_drives {
             query_args.push((
                 "includeItemsFromAllDrives".to_string(),
@@ -106,9 +152,9 @@ impl Files {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files?{}", query_), None);
-        let resp: crate::types::FileList = self
+        let resp: crate::Response<crate::types::FileList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -118,7 +164,7 @@ impl Files {
             .await?;
 
         // Return our response data.
-        Ok(resp.files.to_vec())
+        Ok(resp.map(|r| r.files.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/files` endpoint.
@@ -129,6 +175,7 @@ impl Files {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         corpora: &str,
         drive_id: &str,
         include_items_from_all_drives: bool,
@@ -141,6 +188,7 @@ impl Files {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> ClientResult<Vec<crate::types::File>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
         if !corpora.is_empty() {
             query_args.push(("corpora".to_string(), corpora.to_string()));
@@ -148,6 +196,9 @@ impl Files {
         if !drive_id.is_empty() {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_items_from_all_drives {
             query_args.push((
                 "includeItemsFromAllDrives".to_string(),
@@ -244,6 +295,167 @@ impl Files {
         // Return our response data.
         Ok(files)
     }
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::File>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !corpora.is_empty() {
+            query_args.push(("corpora".to_string(), corpora.to_string()));
+        }
+        if !drive_id.is_empty() {
+            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives".to_string(),
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems".to_string(),
+                include_team_drive_items.to_string(),
+            ));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !spaces.is_empty() {
+            query_args.push(("spaces".to_string(), spaces.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if !team_drive_id.is_empty() {
+            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/files?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::FileList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.files;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::File>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            corpora,
+            drive_id,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_team_drive_items,
+            order_by,
+            q,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/files` endpoint.
      *
@@ -262,6 +474,7 @@ impl Files {
      */
     pub async fn create(
         &self,
+        fields: &str,
         ignore_default_visibility: bool,
         include_permissions_for_view: &str,
         keep_revision_forever: bool,
@@ -271,7 +484,42 @@ impl Files {
         use_content_as_indexable_text: bool,
         body: &crate::types::File,
     ) -> ClientResult<crate::types::File> {
+        Ok(self
+            .create_with_response(
+                fields,
+                ignore_default_visibility,
+                include_permissions_for_view,
+                keep_revision_forever,
+                ocr_language,
+                supports_all_drives,
+                supports_team_drives,
+                use_content_as_indexable_text,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/files` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        fields: &str,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if ignore_default_visibility {
             query_args.push((
                 "ignoreDefaultVisibility".to_string(),
@@ -314,7 +562,7 @@ impl Files {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -336,14 +584,35 @@ impl Files {
      */
     pub async fn generate_id(
         &self,
+        fields: &str,
         count: i64,
         space: &str,
         type_: &str,
     ) -> ClientResult<crate::types::GeneratedIds> {
+        Ok(self
+            .generate_id_with_response(fields, count, space, type_)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/generateIds` endpoint.
+     *
+     * As opposed to `generate_id`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn generate_id_with_response(
+        &self,
+        fields: &str,
+        count: i64,
+        space: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<crate::types::GeneratedIds>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !space.is_empty() {
             query_args.push(("space".to_string(), space.to_string()));
         }
@@ -355,7 +624,7 @@ impl Files {
             .client
             .url(&format!("/files/generateIds?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -373,10 +642,27 @@ impl Files {
      *
      * * `enforce_single_parent: bool` -- Deprecated. If an item is not in a shared drive and its last parent is deleted but the item itself is not, the item will be placed under its owner's root.
      */
-    pub async fn empty_trash(&self) -> ClientResult<()> {
-        let url = self.client.url("/files/trash", None);
+    pub async fn empty_trash(&self, fields: &str) -> ClientResult<()> {
+        self.empty_trash_with_response(fields).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/files/trash` endpoint.
+     *
+     * As opposed to `empty_trash`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn empty_trash_with_response(
+        &self,
+        fields: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/files/trash?{}", query_), None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -400,12 +686,39 @@ impl Files {
      */
     pub async fn get(
         &self,
+        fields: &str,
         file_id: &str,
         acknowledge_abuse: bool,
         include_permissions_for_view: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
     ) -> ClientResult<crate::types::File> {
+        Ok(self
+            .get_with_response(
+                fields,
+                file_id,
+                acknowledge_abuse,
+                include_permissions_for_view,
+                supports_all_drives,
+                supports_team_drives,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if acknowledge_abuse {
             query_args.push((
@@ -413,6 +726,9 @@ impl Files {
                 acknowledge_abuse.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
                 "includePermissionsForView".to_string(),
@@ -441,7 +757,7 @@ impl Files {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -464,11 +780,31 @@ impl Files {
      */
     pub async fn delete(
         &self,
+        fields: &str,
         file_id: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
     ) -> ClientResult<()> {
+        self.delete_with_response(fields, file_id, supports_all_drives, supports_team_drives)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
@@ -491,7 +827,7 @@ impl Files {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -520,6 +856,7 @@ impl Files {
      */
     pub async fn update(
         &self,
+        fields: &str,
         file_id: &str,
         add_parents: &str,
         include_permissions_for_view: &str,
@@ -531,10 +868,49 @@ impl Files {
         use_content_as_indexable_text: bool,
         body: &crate::types::File,
     ) -> ClientResult<crate::types::File> {
+        Ok(self
+            .update_with_response(
+                fields,
+                file_id,
+                add_parents,
+                include_permissions_for_view,
+                keep_revision_forever,
+                ocr_language,
+                remove_parents,
+                supports_all_drives,
+                supports_team_drives,
+                use_content_as_indexable_text,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        add_parents: &str,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        remove_parents: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !add_parents.is_empty() {
             query_args.push(("addParents".to_string(), add_parents.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
                 "includePermissionsForView".to_string(),
@@ -581,7 +957,7 @@ impl Files {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -608,6 +984,7 @@ impl Files {
      */
     pub async fn copy(
         &self,
+        fields: &str,
         file_id: &str,
         ignore_default_visibility: bool,
         include_permissions_for_view: &str,
@@ -617,7 +994,42 @@ impl Files {
         supports_team_drives: bool,
         body: &crate::types::File,
     ) -> ClientResult<crate::types::File> {
+        Ok(self
+            .copy_with_response(
+                fields,
+                file_id,
+                ignore_default_visibility,
+                include_permissions_for_view,
+                keep_revision_forever,
+                ocr_language,
+                supports_all_drives,
+                supports_team_drives,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/files/{fileId}/copy` endpoint.
+     *
+     * As opposed to `copy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn copy_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::File,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if ignore_default_visibility {
             query_args.push((
                 "ignoreDefaultVisibility".to_string(),
@@ -661,7 +1073,7 @@ impl Files {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -680,8 +1092,26 @@ impl Files {
      * * `file_id: &str` -- A link to this theme's background image.
      * * `mime_type: &str` -- The MIME type of the format requested for this export.
      */
-    pub async fn export(&self, file_id: &str, mime_type: &str) -> ClientResult<()> {
+    pub async fn export(&self, fields: &str, file_id: &str, mime_type: &str) -> ClientResult<()> {
+        self.export_with_response(fields, file_id, mime_type)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/export` endpoint.
+     *
+     * As opposed to `export`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn export_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        mime_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !mime_type.is_empty() {
             query_args.push(("mimeType".to_string(), mime_type.to_string()));
         }
@@ -695,7 +1125,7 @@ impl Files {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -719,6 +1149,7 @@ impl Files {
      */
     pub async fn watch(
         &self,
+        fields: &str,
         file_id: &str,
         acknowledge_abuse: bool,
         include_permissions_for_view: &str,
@@ -726,6 +1157,34 @@ impl Files {
         supports_team_drives: bool,
         body: &crate::types::Channel,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .watch_with_response(
+                fields,
+                file_id,
+                acknowledge_abuse,
+                include_permissions_for_view,
+                supports_all_drives,
+                supports_team_drives,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/files/{fileId}/watch` endpoint.
+     *
+     * As opposed to `watch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn watch_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::Channel,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if acknowledge_abuse {
             query_args.push((
@@ -733,6 +1192,9 @@ impl Files {
                 acknowledge_abuse.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
                 "includePermissionsForView".to_string(),
@@ -761,7 +1223,7 @@ impl Files {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
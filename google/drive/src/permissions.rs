@@ -28,6 +28,7 @@ impl Permissions {
      */
     pub async fn list(
         &self,
+        fields: &str,
         file_id: &str,
         include_permissions_for_view: &str,
         page_size: i64,
@@ -36,7 +37,40 @@ impl Permissions {
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::Permission>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                file_id,
+                include_permissions_for_view,
+                page_size,
+                page_token,
+                supports_all_drives,
+                supports_team_drives,
+                use_domain_admin_access,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        page_size: i64,
+        page_token: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Permission>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
                 "includePermissionsForView".to_string(),
@@ -76,9 +110,9 @@ impl Permissions {
             ),
             None,
         );
-        let resp: crate::types::PermissionList = self
+        let resp: crate::Response<crate::types::PermissionList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -88,7 +122,7 @@ impl Permissions {
             .await?;
 
         // Return our response data.
-        Ok(resp.permissions.to_vec())
+        Ok(resp.map(|r| r.permissions.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
@@ -99,13 +133,18 @@ impl Permissions {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         file_id: &str,
         include_permissions_for_view: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::Permission>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
                 "includePermissionsForView".to_string(),
@@ -191,6 +230,133 @@ impl Permissions {
         // Return our response data.
         Ok(permissions)
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Permission>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/permissions?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::PermissionList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.permissions;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Permission>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            file_id,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
      *
@@ -210,6 +376,7 @@ impl Permissions {
      */
     pub async fn create(
         &self,
+        fields: &str,
         file_id: &str,
         email_message: &str,
         move_to_new_owners_root: bool,
@@ -220,10 +387,47 @@ impl Permissions {
         use_domain_admin_access: bool,
         body: &crate::types::Permission,
     ) -> ClientResult<crate::types::Permission> {
+        Ok(self
+            .create_with_response(
+                fields,
+                file_id,
+                email_message,
+                move_to_new_owners_root,
+                send_notification_email,
+                supports_all_drives,
+                supports_team_drives,
+                transfer_ownership,
+                use_domain_admin_access,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        email_message: &str,
+        move_to_new_owners_root: bool,
+        send_notification_email: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> ClientResult<crate::Response<crate::types::Permission>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email_message.is_empty() {
             query_args.push(("emailMessage".to_string(), email_message.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if move_to_new_owners_root {
             query_args.push((
                 "moveToNewOwnersRoot".to_string(),
@@ -268,7 +472,7 @@ impl Permissions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -292,13 +496,43 @@ impl Permissions {
      */
     pub async fn get(
         &self,
+        fields: &str,
         file_id: &str,
         permission_id: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> ClientResult<crate::types::Permission> {
+        Ok(self
+            .get_with_response(
+                fields,
+                file_id,
+                permission_id,
+                supports_all_drives,
+                supports_team_drives,
+                use_domain_admin_access,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<crate::types::Permission>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
@@ -328,7 +562,7 @@ impl Permissions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -352,13 +586,42 @@ impl Permissions {
      */
     pub async fn delete(
         &self,
+        fields: &str,
         file_id: &str,
         permission_id: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> ClientResult<()> {
+        self.delete_with_response(
+            fields,
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
@@ -388,7 +651,7 @@ impl Permissions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -414,6 +677,7 @@ impl Permissions {
      */
     pub async fn update(
         &self,
+        fields: &str,
         file_id: &str,
         permission_id: &str,
         remove_expiration: bool,
@@ -423,7 +687,42 @@ impl Permissions {
         use_domain_admin_access: bool,
         body: &crate::types::Permission,
     ) -> ClientResult<crate::types::Permission> {
+        Ok(self
+            .update_with_response(
+                fields,
+                file_id,
+                permission_id,
+                remove_expiration,
+                supports_all_drives,
+                supports_team_drives,
+                transfer_ownership,
+                use_domain_admin_access,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        permission_id: &str,
+        remove_expiration: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> ClientResult<crate::Response<crate::types::Permission>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if remove_expiration {
             query_args.push((
                 "removeExpiration".to_string(),
@@ -465,7 +764,7 @@ impl Permissions {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -26,13 +26,43 @@ impl Replies {
      */
     pub async fn list(
         &self,
+        fields: &str,
         file_id: &str,
         comment_id: &str,
         include_deleted: bool,
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<Vec<crate::types::Reply>> {
+        Ok(self
+            .list_with_response(
+                fields,
+                file_id,
+                comment_id,
+                include_deleted,
+                page_size,
+                page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reply>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
@@ -52,9 +82,9 @@ impl Replies {
             ),
             None,
         );
-        let resp: crate::types::ReplyList = self
+        let resp: crate::Response<crate::types::ReplyList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -64,7 +94,7 @@ impl Replies {
             .await?;
 
         // Return our response data.
-        Ok(resp.replies.to_vec())
+        Ok(resp.map(|r| r.replies.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
@@ -75,11 +105,16 @@ impl Replies {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         file_id: &str,
         comment_id: &str,
         include_deleted: bool,
     ) -> ClientResult<Vec<crate::types::Reply>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
@@ -145,6 +180,106 @@ impl Replies {
         // Return our response data.
         Ok(replies)
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reply>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if include_deleted {
+            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/comments/{}/replies?{}",
+                crate::progenitor_support::encode_path(file_id),
+                crate::progenitor_support::encode_path(comment_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ReplyList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.replies;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reply>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            file_id,
+            comment_id,
+            include_deleted,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
      *
@@ -157,20 +292,44 @@ impl Replies {
      */
     pub async fn create(
         &self,
+        fields: &str,
         file_id: &str,
         comment_id: &str,
         body: &crate::types::Reply,
     ) -> ClientResult<crate::types::Reply> {
+        Ok(self
+            .create_with_response(fields, file_id, comment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Reply,
+    ) -> ClientResult<crate::Response<crate::types::Reply>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/files/{}/comments/{}/replies",
+                "/files/{}/comments/{}/replies?{}",
                 crate::progenitor_support::encode_path(file_id),
                 crate::progenitor_support::encode_path(comment_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -193,12 +352,34 @@ impl Replies {
      */
     pub async fn get(
         &self,
+        fields: &str,
         file_id: &str,
         comment_id: &str,
         reply_id: &str,
         include_deleted: bool,
     ) -> ClientResult<crate::types::Reply> {
+        Ok(self
+            .get_with_response(fields, file_id, comment_id, reply_id, include_deleted)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        include_deleted: bool,
+    ) -> ClientResult<crate::Response<crate::types::Reply>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
@@ -214,7 +395,7 @@ impl Replies {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -236,21 +417,44 @@ impl Replies {
      */
     pub async fn delete(
         &self,
+        fields: &str,
         file_id: &str,
         comment_id: &str,
         reply_id: &str,
     ) -> ClientResult<()> {
+        self.delete_with_response(fields, file_id, comment_id, reply_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/files/{}/comments/{}/replies/{}",
+                "/files/{}/comments/{}/replies/{}?{}",
                 crate::progenitor_support::encode_path(file_id),
                 crate::progenitor_support::encode_path(comment_id),
                 crate::progenitor_support::encode_path(reply_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -272,22 +476,47 @@ impl Replies {
      */
     pub async fn update(
         &self,
+        fields: &str,
         file_id: &str,
         comment_id: &str,
         reply_id: &str,
         body: &crate::types::Reply,
     ) -> ClientResult<crate::types::Reply> {
+        Ok(self
+            .update_with_response(fields, file_id, comment_id, reply_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        body: &crate::types::Reply,
+    ) -> ClientResult<crate::Response<crate::types::Reply>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/files/{}/comments/{}/replies/{}",
+                "/files/{}/comments/{}/replies/{}?{}",
                 crate::progenitor_support::encode_path(file_id),
                 crate::progenitor_support::encode_path(comment_id),
                 crate::progenitor_support::encode_path(reply_id),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,11 +24,32 @@ impl Revisions {
      */
     pub async fn list(
         &self,
+        fields: &str,
         file_id: &str,
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<Vec<crate::types::Revision>> {
+        Ok(self
+            .list_with_response(fields, file_id, page_size, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Revision>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if page_size > 0 {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
@@ -44,9 +65,9 @@ impl Revisions {
             ),
             None,
         );
-        let resp: crate::types::RevisionList = self
+        let resp: crate::Response<crate::types::RevisionList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,7 +77,7 @@ impl Revisions {
             .await?;
 
         // Return our response data.
-        Ok(resp.revisions.to_vec())
+        Ok(resp.map(|r| r.revisions.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
@@ -65,11 +86,22 @@ impl Revisions {
      *
      * Lists a file's revisions.
      */
-    pub async fn list_all(&self, file_id: &str) -> ClientResult<Vec<crate::types::Revision>> {
+    pub async fn list_all(
+        &self,
+        fields: &str,
+        file_id: &str,
+    ) -> ClientResult<Vec<crate::types::Revision>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/files/{}/revisions",
+                "/files/{}/revisions?{}",
                 crate::progenitor_support::encode_path(file_id),
+                query_
             ),
             None,
         );
@@ -125,6 +157,92 @@ impl Revisions {
         // Return our response data.
         Ok(revisions)
     }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        file_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Revision>>> + Unpin + '_
+    {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/files/{}/revisions?{}",
+                crate::progenitor_support::encode_path(file_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::RevisionList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.revisions;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        file_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Revision>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(fields, file_id, None))
+    }
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -138,10 +256,28 @@ impl Revisions {
      */
     pub async fn get(
         &self,
+        fields: &str,
         file_id: &str,
         revision_id: &str,
         acknowledge_abuse: bool,
     ) -> ClientResult<crate::types::Revision> {
+        Ok(self
+            .get_with_response(fields, file_id, revision_id, acknowledge_abuse)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+    ) -> ClientResult<crate::Response<crate::types::Revision>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if acknowledge_abuse {
             query_args.push((
@@ -149,6 +285,9 @@ impl Revisions {
                 acknowledge_abuse.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -160,7 +299,7 @@ impl Revisions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -179,17 +318,38 @@ impl Revisions {
      * * `file_id: &str` -- A link to this theme's background image.
      * * `revision_id: &str` -- A link to this theme's background image.
      */
-    pub async fn delete(&self, file_id: &str, revision_id: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, file_id: &str, revision_id: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, file_id, revision_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        revision_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/files/{}/revisions/{}",
+                "/files/{}/revisions/{}?{}",
                 crate::progenitor_support::encode_path(file_id),
                 crate::progenitor_support::encode_path(revision_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -210,20 +370,44 @@ impl Revisions {
      */
     pub async fn update(
         &self,
+        fields: &str,
         file_id: &str,
         revision_id: &str,
         body: &crate::types::Revision,
     ) -> ClientResult<crate::types::Revision> {
+        Ok(self
+            .update_with_response(fields, file_id, revision_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        file_id: &str,
+        revision_id: &str,
+        body: &crate::types::Revision,
+    ) -> ClientResult<crate::Response<crate::types::Revision>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/files/{}/revisions/{}",
+                "/files/{}/revisions/{}?{}",
                 crate::progenitor_support::encode_path(file_id),
                 crate::progenitor_support::encode_path(revision_id),
+                query_
             ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
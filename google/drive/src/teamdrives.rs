@@ -25,12 +25,34 @@ impl Teamdrives {
      */
     pub async fn list(
         &self,
+        fields: &str,
         page_size: i64,
         page_token: &str,
         q: &str,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::TeamDrive>> {
+        Ok(self
+            .list_with_response(fields, page_size, page_token, q, use_domain_admin_access)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        fields: &str,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TeamDrive>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if page_size > 0 {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
@@ -48,9 +70,9 @@ impl Teamdrives {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/teamdrives?{}", query_), None);
-        let resp: crate::types::TeamDriveList = self
+        let resp: crate::Response<crate::types::TeamDriveList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +82,7 @@ impl Teamdrives {
             .await?;
 
         // Return our response data.
-        Ok(resp.team_drives.to_vec())
+        Ok(resp.map(|r| r.team_drives.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/teamdrives` endpoint.
@@ -71,10 +93,15 @@ impl Teamdrives {
      */
     pub async fn list_all(
         &self,
+        fields: &str,
         q: &str,
         use_domain_admin_access: bool,
     ) -> ClientResult<Vec<crate::types::TeamDrive>> {
+        let fields = crate::utils::fields_with_next_page_token(fields);
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -138,6 +165,102 @@ impl Teamdrives {
         // Return our response data.
         Ok(team_drives)
     }
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        fields: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TeamDrive>>>
+           + Unpin
+           + '_ {
+        let fields = crate::utils::fields_with_next_page_token(fields);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/teamdrives?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}pageToken={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::TeamDriveList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.team_drives;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        fields: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TeamDrive>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            fields,
+            q,
+            use_domain_admin_access,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/teamdrives` endpoint.
      *
@@ -149,17 +272,37 @@ impl Teamdrives {
      */
     pub async fn create(
         &self,
+        fields: &str,
         request_id: &str,
         body: &crate::types::TeamDrive,
     ) -> ClientResult<crate::types::TeamDrive> {
+        Ok(self
+            .create_with_response(fields, request_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        fields: &str,
+        request_id: &str,
+        body: &crate::types::TeamDrive,
+    ) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !request_id.is_empty() {
             query_args.push(("requestId".to_string(), request_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/teamdrives?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -180,10 +323,30 @@ impl Teamdrives {
      */
     pub async fn get(
         &self,
+        fields: &str,
         team_drive_id: &str,
         use_domain_admin_access: bool,
     ) -> ClientResult<crate::types::TeamDrive> {
+        Ok(self
+            .get_with_response(fields, team_drive_id, use_domain_admin_access)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
@@ -200,7 +363,7 @@ impl Teamdrives {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -218,16 +381,35 @@ impl Teamdrives {
      *
      * * `team_drive_id: &str` -- A link to this theme's background image.
      */
-    pub async fn delete(&self, team_drive_id: &str) -> ClientResult<()> {
+    pub async fn delete(&self, fields: &str, team_drive_id: &str) -> ClientResult<()> {
+        self.delete_with_response(fields, team_drive_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        fields: &str,
+        team_drive_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/teamdrives/{}",
+                "/teamdrives/{}?{}",
                 crate::progenitor_support::encode_path(team_drive_id),
+                query_
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -248,11 +430,32 @@ impl Teamdrives {
      */
     pub async fn update(
         &self,
+        fields: &str,
         team_drive_id: &str,
         use_domain_admin_access: bool,
         body: &crate::types::TeamDrive,
     ) -> ClientResult<crate::types::TeamDrive> {
+        Ok(self
+            .update_with_response(fields, team_drive_id, use_domain_admin_access, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        fields: &str,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::TeamDrive,
+    ) -> ClientResult<crate::Response<crate::types::TeamDrive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
@@ -269,7 +472,7 @@ impl Teamdrives {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
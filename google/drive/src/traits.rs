@@ -64,6 +64,7 @@ impl PermissionOps for crate::permissions::Permissions {
         // List all the permissions for a file.
         let perms = self
             .list_all(
+                "", // fields
                 file_id,
                 "",   // include_permissions_for_view
                 true, // supports_all_drives
@@ -100,6 +101,7 @@ impl PermissionOps for crate::permissions::Permissions {
 
         // Create the permission and return it.
         self.create(
+            "", // fields
             file_id,
             email_message,
             false, // move_to_new_owners_root
@@ -170,6 +172,7 @@ impl FileOps for crate::files::Files {
         }
 
         self.list_all(
+            "",       // fields
             "drive",  // corpora
             drive_id, // drive id
             true,     // include_items_from_all_drives
@@ -266,6 +269,7 @@ impl FileOps for crate::files::Files {
             .ok_or(ClientError::HttpError {
                 status: resp.status(),
                 error: "Missing Location header".to_string(),
+                request_id: crate::request_id(resp.headers()),
             })?
             .to_str()?;
 
@@ -318,6 +322,7 @@ impl FileOps for crate::files::Files {
         // Check if the folder exists.
         let folders = self
             .list_all(
+                "",       // fields
                 "drive",  // corpora
                 drive_id, // drive id
                 true,     // include_items_from_all_drives
@@ -395,6 +400,7 @@ impl FileOps for crate::files::Files {
 
         // Delete the file.
         self.delete(
+            "", // fields
             &files.get(0).unwrap().id,
             true, // supports all drives
             true, // supports team drives
@@ -415,6 +421,7 @@ impl DriveOps for crate::drives::Drives {
     async fn get_by_name(&self, name: &str) -> ClientResult<crate::types::Drive> {
         let drives = self
             .list_all(
+                "", // fields
                 //&format!("name = '{}'", name), // query
                 "", true, // use domain admin access
             )
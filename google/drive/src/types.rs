@@ -15,7 +15,7 @@ pub struct DriveThemes {
     )]
     pub background_image_link: String,
     /**
-     * A link to this theme's background image.
+     * The color of this theme as an RGB hex string.
      */
     #[serde(
         default,
@@ -25,7 +25,7 @@ pub struct DriveThemes {
     )]
     pub color_rgb: String,
     /**
-     * A link to this theme's background image.
+     * The ID of the theme.
      */
     #[serde(
         default,
@@ -39,7 +39,7 @@ pub struct DriveThemes {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StorageQuota {
     /**
-     * The user's storage quota limits and usage. All fields are measured in bytes.
+     * The usage limit, if applicable. This will not be present if the user has unlimited storage.
      */
     #[serde(
         default,
@@ -48,7 +48,7 @@ pub struct StorageQuota {
     )]
     pub limit: i64,
     /**
-     * The user's storage quota limits and usage. All fields are measured in bytes.
+     * The total usage across all services.
      */
     #[serde(
         default,
@@ -57,7 +57,7 @@ pub struct StorageQuota {
     )]
     pub usage: i64,
     /**
-     * The user's storage quota limits and usage. All fields are measured in bytes.
+     * The usage by all files in Google Drive.
      */
     #[serde(
         default,
@@ -67,7 +67,7 @@ pub struct StorageQuota {
     )]
     pub usage_in_drive: i64,
     /**
-     * The user's storage quota limits and usage. All fields are measured in bytes.
+     * The usage by trashed files in Google Drive.
      */
     #[serde(
         default,
@@ -82,7 +82,7 @@ pub struct StorageQuota {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct About {
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * Whether the user has installed the requesting app.
      */
     #[serde(
         default,
@@ -91,7 +91,7 @@ pub struct About {
     )]
     pub app_installed: Option<bool>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * Whether the user can create shared drives.
      */
     #[serde(
         default,
@@ -100,7 +100,7 @@ pub struct About {
     )]
     pub can_create_drives: Option<bool>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * Deprecated - use canCreateDrives instead.
      */
     #[serde(
         default,
@@ -109,7 +109,7 @@ pub struct About {
     )]
     pub can_create_team_drives: Option<bool>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * A list of themes that are supported for shared drives.
      */
     #[serde(
         default,
@@ -119,7 +119,7 @@ pub struct About {
     )]
     pub drive_themes: Vec<DriveThemes>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * A map of source MIME type to possible targets for all supported exports.
      */
     #[serde(
         default,
@@ -129,7 +129,7 @@ pub struct About {
     )]
     pub export_formats: Vec<String>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * The currently supported folder colors as RGB hex strings.
      */
     #[serde(
         default,
@@ -139,7 +139,7 @@ pub struct About {
     )]
     pub folder_color_palette: Vec<String>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * A map of source MIME type to possible targets for all supported imports.
      */
     #[serde(
         default,
@@ -149,7 +149,7 @@ pub struct About {
     )]
     pub import_formats: Vec<String>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#about".
      */
     #[serde(
         default,
@@ -158,7 +158,7 @@ pub struct About {
     )]
     pub kind: String,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * A map of maximum import sizes by MIME type, in bytes.
      */
     #[serde(
         default,
@@ -168,7 +168,7 @@ pub struct About {
     )]
     pub max_import_sizes: i64,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * The maximum upload size in bytes.
      */
     #[serde(
         default,
@@ -178,7 +178,7 @@ pub struct About {
     )]
     pub max_upload_size: i64,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * The user's storage quota limits and usage. All fields are measured in bytes.
      */
     #[serde(
         default,
@@ -187,7 +187,7 @@ pub struct About {
     )]
     pub storage_quota: Option<StorageQuota>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * Deprecated - use driveThemes instead.
      */
     #[serde(
         default,
@@ -197,7 +197,7 @@ pub struct About {
     )]
     pub team_drive_themes: Vec<DriveThemes>,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * Information about a Drive user.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<User>,
@@ -207,7 +207,7 @@ pub struct About {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Change {
     /**
-     * A change to a file or shared drive.
+     * The type of the change. Possible values are file and drive.
      */
     #[serde(
         default,
@@ -217,12 +217,12 @@ pub struct Change {
     )]
     pub change_type: String,
     /**
-     * A change to a file or shared drive.
+     * Representation of a shared drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub drive: Option<Drive>,
     /**
-     * A change to a file or shared drive.
+     * The ID of the shared drive associated with this change.
      */
     #[serde(
         default,
@@ -232,12 +232,12 @@ pub struct Change {
     )]
     pub drive_id: String,
     /**
-     * A change to a file or shared drive.
+     * The metadata for a file.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub file: Option<File>,
     /**
-     * A change to a file or shared drive.
+     * The ID of the file which has changed.
      */
     #[serde(
         default,
@@ -247,7 +247,7 @@ pub struct Change {
     )]
     pub file_id: String,
     /**
-     * A change to a file or shared drive.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#change".
      */
     #[serde(
         default,
@@ -256,17 +256,17 @@ pub struct Change {
     )]
     pub kind: String,
     /**
-     * A change to a file or shared drive.
+     * Whether the file or shared drive has been removed from this list of changes, for example by deletion or loss of access.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub removed: Option<bool>,
     /**
-     * A change to a file or shared drive.
+     * Deprecated: use the drive collection instead.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "teamDrive")]
     pub team_drive: Option<TeamDrive>,
     /**
-     * A change to a file or shared drive.
+     * Deprecated - use driveId instead.
      */
     #[serde(
         default,
@@ -276,7 +276,7 @@ pub struct Change {
     )]
     pub team_drive_id: String,
     /**
-     * A change to a file or shared drive.
+     * The time of this change (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -285,7 +285,7 @@ pub struct Change {
     )]
     pub time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A change to a file or shared drive.
+     * Deprecated - use changeType instead.
      */
     #[serde(
         default,
@@ -300,7 +300,7 @@ pub struct Change {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChangeList {
     /**
-     * A list of changes for a user.
+     * The list of changes. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -309,7 +309,7 @@ pub struct ChangeList {
     )]
     pub changes: Vec<Change>,
     /**
-     * A list of changes for a user.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#changeList".
      */
     #[serde(
         default,
@@ -318,7 +318,7 @@ pub struct ChangeList {
     )]
     pub kind: String,
     /**
-     * A list of changes for a user.
+     * The starting page token for future changes. This will be present only if the end of the current changes list has been reached.
      */
     #[serde(
         default,
@@ -328,7 +328,7 @@ pub struct ChangeList {
     )]
     pub new_start_page_token: String,
     /**
-     * A list of changes for a user.
+     * The page token for the next page of changes. This will be absent if the end of the changes list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -343,7 +343,7 @@ pub struct ChangeList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Channel {
     /**
-     * An notification channel used to watch for resource changes.
+     * The address where notifications are delivered for this channel.
      */
     #[serde(
         default,
@@ -352,7 +352,7 @@ pub struct Channel {
     )]
     pub address: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
      */
     #[serde(
         default,
@@ -361,7 +361,7 @@ pub struct Channel {
     )]
     pub expiration: i64,
     /**
-     * An notification channel used to watch for resource changes.
+     * A UUID or similar unique string that identifies this channel.
      */
     #[serde(
         default,
@@ -370,7 +370,7 @@ pub struct Channel {
     )]
     pub id: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * Identifies this as a notification channel used to watch for changes to a resource, which is "api#channel".
      */
     #[serde(
         default,
@@ -379,7 +379,7 @@ pub struct Channel {
     )]
     pub kind: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * Additional parameters controlling delivery channel behavior. Optional.
      */
     #[serde(
         default,
@@ -388,12 +388,12 @@ pub struct Channel {
     )]
     pub params: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * A Boolean value to indicate whether payload is wanted. Optional.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payload: Option<bool>,
     /**
-     * An notification channel used to watch for resource changes.
+     * An opaque ID that identifies the resource being watched on this channel. Stable across different API versions.
      */
     #[serde(
         default,
@@ -403,7 +403,7 @@ pub struct Channel {
     )]
     pub resource_id: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * A version-specific identifier for the watched resource.
      */
     #[serde(
         default,
@@ -413,7 +413,7 @@ pub struct Channel {
     )]
     pub resource_uri: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * An arbitrary string delivered to the target address with each notification delivered over this channel. Optional.
      */
     #[serde(
         default,
@@ -422,7 +422,7 @@ pub struct Channel {
     )]
     pub token: String,
     /**
-     * An notification channel used to watch for resource changes.
+     * The type of delivery mechanism used for this channel. Valid values are "web_hook" (or "webhook"). Both values refer to a channel where Http requests are used to deliver messages.
      */
     #[serde(
         default,
@@ -437,7 +437,7 @@ pub struct Channel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct QuotedFileContent {
     /**
-     * The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
+     * The MIME type of the quoted content.
      */
     #[serde(
         default,
@@ -447,7 +447,7 @@ pub struct QuotedFileContent {
     )]
     pub mime_type: String,
     /**
-     * The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
+     * The quoted content itself. This is interpreted as plain text if set through the API.
      */
     #[serde(
         default,
@@ -461,7 +461,7 @@ pub struct QuotedFileContent {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Comment {
     /**
-     * A comment on a file.
+     * A region of the document represented as a JSON string. For details on defining anchor properties, refer to  Add comments and replies.
      */
     #[serde(
         default,
@@ -470,12 +470,12 @@ pub struct Comment {
     )]
     pub anchor: String,
     /**
-     * A comment on a file.
+     * Information about a Drive user.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<User>,
     /**
-     * A comment on a file.
+     * The plain text content of the comment. This field is used for setting the content, while htmlContent should be displayed.
      */
     #[serde(
         default,
@@ -484,7 +484,7 @@ pub struct Comment {
     )]
     pub content: String,
     /**
-     * A comment on a file.
+     * The time at which the comment was created (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -494,12 +494,12 @@ pub struct Comment {
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A comment on a file.
+     * Whether the comment has been deleted. A deleted comment has no content.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
     /**
-     * A comment on a file.
+     * The content of the comment with HTML formatting.
      */
     #[serde(
         default,
@@ -509,7 +509,7 @@ pub struct Comment {
     )]
     pub html_content: String,
     /**
-     * A comment on a file.
+     * The ID of the comment.
      */
     #[serde(
         default,
@@ -518,7 +518,7 @@ pub struct Comment {
     )]
     pub id: String,
     /**
-     * A comment on a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#comment".
      */
     #[serde(
         default,
@@ -527,7 +527,7 @@ pub struct Comment {
     )]
     pub kind: String,
     /**
-     * A comment on a file.
+     * The last time the comment or any of its replies was modified (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -537,7 +537,7 @@ pub struct Comment {
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A comment on a file.
+     * The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
      */
     #[serde(
         default,
@@ -546,7 +546,7 @@ pub struct Comment {
     )]
     pub quoted_file_content: Option<QuotedFileContent>,
     /**
-     * A comment on a file.
+     * The full list of replies to the comment in chronological order.
      */
     #[serde(
         default,
@@ -555,7 +555,7 @@ pub struct Comment {
     )]
     pub replies: Vec<Reply>,
     /**
-     * A comment on a file.
+     * Whether the comment has been resolved by one of its replies.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub resolved: Option<bool>,
@@ -565,7 +565,7 @@ pub struct Comment {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CommentList {
     /**
-     * A list of comments on a file.
+     * The list of comments. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -574,7 +574,7 @@ pub struct CommentList {
     )]
     pub comments: Vec<Comment>,
     /**
-     * A list of comments on a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#commentList".
      */
     #[serde(
         default,
@@ -583,7 +583,7 @@ pub struct CommentList {
     )]
     pub kind: String,
     /**
-     * A list of comments on a file.
+     * The page token for the next page of comments. This will be absent if the end of the comments list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -598,12 +598,12 @@ pub struct CommentList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContentRestriction {
     /**
-     * A restriction for accessing the content of the file.
+     * Whether the content of the file is read-only. If a file is read-only, a new revision of the file may not be added, comments may not be added or modified, and the title of the file may not be modified.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "readOnly")]
     pub read_only: Option<bool>,
     /**
-     * A restriction for accessing the content of the file.
+     * Reason for why the content of the file is restricted. This is only mutable on requests that also set readOnly=true.
      */
     #[serde(
         default,
@@ -612,7 +612,7 @@ pub struct ContentRestriction {
     )]
     pub reason: String,
     /**
-     * A restriction for accessing the content of the file.
+     * Information about a Drive user.
      */
     #[serde(
         default,
@@ -621,7 +621,7 @@ pub struct ContentRestriction {
     )]
     pub restricting_user: Option<User>,
     /**
-     * A restriction for accessing the content of the file.
+     * The time at which the content restriction was set (formatted RFC 3339 timestamp). Only populated if readOnly is true.
      */
     #[serde(
         default,
@@ -631,7 +631,7 @@ pub struct ContentRestriction {
     )]
     pub restriction_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A restriction for accessing the content of the file.
+     * The type of the content restriction. Currently the only possible value is globalContentRestriction.
      */
     #[serde(
         default,
@@ -646,7 +646,7 @@ pub struct ContentRestriction {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BackgroundImageFile {
     /**
-     * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The ID of an image file in Google Drive to use for the background image.
      */
     #[serde(
         default,
@@ -655,7 +655,7 @@ pub struct BackgroundImageFile {
     )]
     pub id: String,
     /**
-     * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The width of the cropped image in the closed range of 0 to 1. This value represents the width of the cropped image divided by the width of the entire image. The height is computed by applying a width to height aspect ratio of 80 to 9. The resulting image must be at least 1280 pixels wide and 144 pixels high.
      */
     #[serde(
         default,
@@ -664,7 +664,7 @@ pub struct BackgroundImageFile {
     )]
     pub width: f64,
     /**
-     * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The X coordinate of the upper left corner of the cropping area in the background image. This is a value in the closed range of 0 to 1. This value represents the horizontal distance from the left side of the entire image to the left side of the cropping area divided by the width of the entire image.
      */
     #[serde(
         default,
@@ -674,7 +674,7 @@ pub struct BackgroundImageFile {
     )]
     pub x_coordinate: f64,
     /**
-     * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The Y coordinate of the upper left corner of the cropping area in the background image. This is a value in the closed range of 0 to 1. This value represents the vertical distance from the top side of the entire image to the top side of the cropping area divided by the height of the entire image.
      */
     #[serde(
         default,
@@ -689,7 +689,7 @@ pub struct BackgroundImageFile {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Capabilities {
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can add children to folders in this shared drive.
      */
     #[serde(
         default,
@@ -698,7 +698,7 @@ pub struct Capabilities {
     )]
     pub can_add_children: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can change the copyRequiresWriterPermission restriction of this shared drive.
      */
     #[serde(
         default,
@@ -707,7 +707,7 @@ pub struct Capabilities {
     )]
     pub can_change_copy_requires_writer_permission_restriction: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can change the domainUsersOnly restriction of this shared drive.
      */
     #[serde(
         default,
@@ -716,7 +716,7 @@ pub struct Capabilities {
     )]
     pub can_change_domain_users_only_restriction: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can change the background of this shared drive.
      */
     #[serde(
         default,
@@ -725,7 +725,7 @@ pub struct Capabilities {
     )]
     pub can_change_drive_background: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can change the driveMembersOnly restriction of this shared drive.
      */
     #[serde(
         default,
@@ -734,7 +734,7 @@ pub struct Capabilities {
     )]
     pub can_change_drive_members_only_restriction: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can comment on files in this shared drive.
      */
     #[serde(
         default,
@@ -743,12 +743,12 @@ pub struct Capabilities {
     )]
     pub can_comment: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can copy files in this shared drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canCopy")]
     pub can_copy: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can delete children from folders in this shared drive.
      */
     #[serde(
         default,
@@ -757,7 +757,7 @@ pub struct Capabilities {
     )]
     pub can_delete_children: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can delete this shared drive. Attempting to delete the shared drive may still fail if there are untrashed items inside the shared drive.
      */
     #[serde(
         default,
@@ -766,7 +766,7 @@ pub struct Capabilities {
     )]
     pub can_delete_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can download files in this shared drive.
      */
     #[serde(
         default,
@@ -775,12 +775,12 @@ pub struct Capabilities {
     )]
     pub can_download: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can edit files in this shared drive
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canEdit")]
     pub can_edit: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can list the children of folders in this shared drive.
      */
     #[serde(
         default,
@@ -789,7 +789,7 @@ pub struct Capabilities {
     )]
     pub can_list_children: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can add members to this shared drive or remove them or change their role.
      */
     #[serde(
         default,
@@ -798,7 +798,7 @@ pub struct Capabilities {
     )]
     pub can_manage_members: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can read the revisions resource of files in this shared drive.
      */
     #[serde(
         default,
@@ -807,12 +807,12 @@ pub struct Capabilities {
     )]
     pub can_read_revisions: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can rename files or folders in this shared drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canRename")]
     pub can_rename: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can rename this shared drive.
      */
     #[serde(
         default,
@@ -821,12 +821,12 @@ pub struct Capabilities {
     )]
     pub can_rename_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can share files or folders in this shared drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canShare")]
     pub can_share: Option<bool>,
     /**
-     * Capabilities the current user has on this shared drive.
+     * Whether the current user can trash children from folders in this shared drive.
      */
     #[serde(
         default,
@@ -840,7 +840,7 @@ pub struct Capabilities {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Restrictions {
     /**
-     * A set of restrictions that apply to this shared drive or items inside this shared drive.
+     * Whether administrative privileges on this shared drive are required to modify restrictions.
      */
     #[serde(
         default,
@@ -849,7 +849,7 @@ pub struct Restrictions {
     )]
     pub admin_managed_restrictions: Option<bool>,
     /**
-     * A set of restrictions that apply to this shared drive or items inside this shared drive.
+     * Whether the options to copy, print, or download files inside this shared drive, should be disabled for readers and commenters. When this restriction is set to true, it will override the similarly named field to true for any file inside this shared drive.
      */
     #[serde(
         default,
@@ -858,7 +858,7 @@ pub struct Restrictions {
     )]
     pub copy_requires_writer_permission: Option<bool>,
     /**
-     * A set of restrictions that apply to this shared drive or items inside this shared drive.
+     * Whether access to this shared drive and items inside this shared drive is restricted to users of the domain to which this shared drive belongs. This restriction may be overridden by other sharing policies controlled outside of this shared drive.
      */
     #[serde(
         default,
@@ -867,7 +867,7 @@ pub struct Restrictions {
     )]
     pub domain_users_only: Option<bool>,
     /**
-     * A set of restrictions that apply to this shared drive or items inside this shared drive.
+     * Whether access to items inside this shared drive is restricted to its members.
      */
     #[serde(
         default,
@@ -881,7 +881,7 @@ pub struct Restrictions {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Drive {
     /**
-     * Representation of a shared drive.
+     * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
      */
     #[serde(
         default,
@@ -890,7 +890,7 @@ pub struct Drive {
     )]
     pub background_image_file: Option<BackgroundImageFile>,
     /**
-     * Representation of a shared drive.
+     * A short-lived link to this shared drive's background image.
      */
     #[serde(
         default,
@@ -900,12 +900,12 @@ pub struct Drive {
     )]
     pub background_image_link: String,
     /**
-     * Representation of a shared drive.
+     * Capabilities the current user has on this shared drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub capabilities: Option<Capabilities>,
     /**
-     * Representation of a shared drive.
+     * The color of this shared drive as an RGB hex string. It can only be set on a drive.drives.update request that does not set themeId.
      */
     #[serde(
         default,
@@ -915,7 +915,7 @@ pub struct Drive {
     )]
     pub color_rgb: String,
     /**
-     * Representation of a shared drive.
+     * The time at which the shared drive was created (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -925,12 +925,12 @@ pub struct Drive {
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Representation of a shared drive.
+     * Whether the shared drive is hidden from default view.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hidden: Option<bool>,
     /**
-     * Representation of a shared drive.
+     * The ID of this shared drive which is also the ID of the top level folder of this shared drive.
      */
     #[serde(
         default,
@@ -939,7 +939,7 @@ pub struct Drive {
     )]
     pub id: String,
     /**
-     * Representation of a shared drive.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#drive".
      */
     #[serde(
         default,
@@ -948,7 +948,7 @@ pub struct Drive {
     )]
     pub kind: String,
     /**
-     * Representation of a shared drive.
+     * The name of this shared drive.
      */
     #[serde(
         default,
@@ -957,12 +957,12 @@ pub struct Drive {
     )]
     pub name: String,
     /**
-     * Representation of a shared drive.
+     * A set of restrictions that apply to this shared drive or items inside this shared drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub restrictions: Option<Restrictions>,
     /**
-     * Representation of a shared drive.
+     * The ID of the theme from which the background image and color will be set. The set of possible driveThemes can be retrieved from a drive.about.get response. When not specified on a drive.drives.create request, a random theme is chosen from which the background image and color are set. This is a write-only field; it can only be set on requests that don't set colorRgb or backgroundImageFile.
      */
     #[serde(
         default,
@@ -977,7 +977,7 @@ pub struct Drive {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DriveList {
     /**
-     * A list of shared drives.
+     * The list of shared drives. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -986,7 +986,7 @@ pub struct DriveList {
     )]
     pub drives: Vec<Drive>,
     /**
-     * A list of shared drives.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#driveList".
      */
     #[serde(
         default,
@@ -995,7 +995,7 @@ pub struct DriveList {
     )]
     pub kind: String,
     /**
-     * A list of shared drives.
+     * The page token for the next page of shared drives. This will be absent if the end of the list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -1010,7 +1010,7 @@ pub struct DriveList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FileCapabilities {
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can add children to this folder. This is always false when the item is not a folder.
      */
     #[serde(
         default,
@@ -1019,7 +1019,7 @@ pub struct FileCapabilities {
     )]
     pub can_add_children: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can add a folder from another drive (different shared drive or My Drive) to this folder. This is false when the item is not a folder. Only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1028,7 +1028,7 @@ pub struct FileCapabilities {
     )]
     pub can_add_folder_from_another_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can add a parent for the item without removing an existing parent in the same request. Not populated for shared drive files.
      */
     #[serde(
         default,
@@ -1037,7 +1037,7 @@ pub struct FileCapabilities {
     )]
     pub can_add_my_drive_parent: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can change the copyRequiresWriterPermission restriction of this file.
      */
     #[serde(
         default,
@@ -1046,7 +1046,7 @@ pub struct FileCapabilities {
     )]
     pub can_change_copy_requires_writer_permission: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can change the securityUpdateEnabled field on link share metadata.
      */
     #[serde(
         default,
@@ -1055,7 +1055,7 @@ pub struct FileCapabilities {
     )]
     pub can_change_security_update_enabled: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated
      */
     #[serde(
         default,
@@ -1064,7 +1064,7 @@ pub struct FileCapabilities {
     )]
     pub can_change_viewers_can_copy_content: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can comment on this file.
      */
     #[serde(
         default,
@@ -1073,17 +1073,17 @@ pub struct FileCapabilities {
     )]
     pub can_comment: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can copy this file. For an item in a shared drive, whether the current user can copy non-folder descendants of this item, or this item itself if it is not a folder.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canCopy")]
     pub can_copy: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can delete this file.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canDelete")]
     pub can_delete: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can delete children of this folder. This is false when the item is not a folder. Only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1092,7 +1092,7 @@ pub struct FileCapabilities {
     )]
     pub can_delete_children: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can download this file.
      */
     #[serde(
         default,
@@ -1101,12 +1101,12 @@ pub struct FileCapabilities {
     )]
     pub can_download: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can edit this file. Other factors may limit the type of changes a user can make to a file. For example, see canChangeCopyRequiresWriterPermission or canModifyContent.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canEdit")]
     pub can_edit: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can list the children of this folder. This is always false when the item is not a folder.
      */
     #[serde(
         default,
@@ -1115,7 +1115,7 @@ pub struct FileCapabilities {
     )]
     pub can_list_children: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can modify the content of this file.
      */
     #[serde(
         default,
@@ -1124,7 +1124,7 @@ pub struct FileCapabilities {
     )]
     pub can_modify_content: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can modify restrictions on content of this file.
      */
     #[serde(
         default,
@@ -1133,7 +1133,7 @@ pub struct FileCapabilities {
     )]
     pub can_modify_content_restriction: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can move children of this folder outside of the shared drive. This is false when the item is not a folder. Only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1142,7 +1142,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_children_out_of_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated - use canMoveChildrenOutOfDrive instead.
      */
     #[serde(
         default,
@@ -1151,7 +1151,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_children_out_of_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can move children of this folder within this drive. This is false when the item is not a folder. Note that a request to move the child may still fail depending on the current user's access to the child and to the destination folder.
      */
     #[serde(
         default,
@@ -1160,7 +1160,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_children_within_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated - use canMoveChildrenWithinDrive instead.
      */
     #[serde(
         default,
@@ -1169,7 +1169,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_children_within_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated - use canMoveItemOutOfDrive instead.
      */
     #[serde(
         default,
@@ -1178,7 +1178,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_item_into_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can move this item outside of this drive by changing its parent. Note that a request to change the parent of the item may still fail depending on the new parent that is being added.
      */
     #[serde(
         default,
@@ -1187,7 +1187,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_item_out_of_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated - use canMoveItemOutOfDrive instead.
      */
     #[serde(
         default,
@@ -1196,7 +1196,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_item_out_of_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can move this item within this drive. Note that a request to change the parent of the item may still fail depending on the new parent that is being added and the parent that is being removed.
      */
     #[serde(
         default,
@@ -1205,7 +1205,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_item_within_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated - use canMoveItemWithinDrive instead.
      */
     #[serde(
         default,
@@ -1214,7 +1214,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_item_within_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated - use canMoveItemWithinDrive or canMoveItemOutOfDrive instead.
      */
     #[serde(
         default,
@@ -1223,7 +1223,7 @@ pub struct FileCapabilities {
     )]
     pub can_move_team_drive_item: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can read the shared drive to which this file belongs. Only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1232,7 +1232,7 @@ pub struct FileCapabilities {
     )]
     pub can_read_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can read the revisions resource of this file. For a shared drive item, whether revisions of non-folder descendants of this item, or this item itself if it is not a folder, can be read.
      */
     #[serde(
         default,
@@ -1241,7 +1241,7 @@ pub struct FileCapabilities {
     )]
     pub can_read_revisions: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Deprecated - use canReadDrive instead.
      */
     #[serde(
         default,
@@ -1250,7 +1250,7 @@ pub struct FileCapabilities {
     )]
     pub can_read_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can remove children from this folder. This is always false when the item is not a folder. For a folder in a shared drive, use canDeleteChildren or canTrashChildren instead.
      */
     #[serde(
         default,
@@ -1259,7 +1259,7 @@ pub struct FileCapabilities {
     )]
     pub can_remove_children: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can remove a parent from the item without adding another parent in the same request. Not populated for shared drive files.
      */
     #[serde(
         default,
@@ -1268,22 +1268,22 @@ pub struct FileCapabilities {
     )]
     pub can_remove_my_drive_parent: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can rename this file.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canRename")]
     pub can_rename: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can modify the sharing settings for this file.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canShare")]
     pub can_share: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can move this file to trash.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canTrash")]
     pub can_trash: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can trash children of this folder. This is false when the item is not a folder. Only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1292,7 +1292,7 @@ pub struct FileCapabilities {
     )]
     pub can_trash_children: Option<bool>,
     /**
-     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
+     * Whether the current user can restore this file from trash.
      */
     #[serde(
         default,
@@ -1306,12 +1306,12 @@ pub struct FileCapabilities {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Thumbnail {
     /**
-     * A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
+     * The thumbnail data encoded with URL-safe Base64 (RFC 4648 section 5).
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub image: Option<bytes::Bytes>,
     /**
-     * A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
+     * The MIME type of the thumbnail.
      */
     #[serde(
         default,
@@ -1326,7 +1326,7 @@ pub struct Thumbnail {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContentHints {
     /**
-     * Additional information about the content of the file. These fields are never populated in responses.
+     * Text to be indexed for the file to improve fullText queries. This is limited to 128KB in length and may contain HTML elements.
      */
     #[serde(
         default,
@@ -1336,7 +1336,7 @@ pub struct ContentHints {
     )]
     pub indexable_text: String,
     /**
-     * Additional information about the content of the file. These fields are never populated in responses.
+     * A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<Thumbnail>,
@@ -1346,7 +1346,7 @@ pub struct ContentHints {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Location {
     /**
-     * Geographic location information stored in the image.
+     * The altitude stored in the image.
      */
     #[serde(
         default,
@@ -1355,7 +1355,7 @@ pub struct Location {
     )]
     pub altitude: f64,
     /**
-     * Geographic location information stored in the image.
+     * The latitude stored in the image.
      */
     #[serde(
         default,
@@ -1364,7 +1364,7 @@ pub struct Location {
     )]
     pub latitude: f64,
     /**
-     * Geographic location information stored in the image.
+     * The longitude stored in the image.
      */
     #[serde(
         default,
@@ -1378,7 +1378,7 @@ pub struct Location {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ImageMediaMetadata {
     /**
-     * Additional metadata about image media, if available.
+     * The aperture used to create the photo (f-number).
      */
     #[serde(
         default,
@@ -1387,7 +1387,7 @@ pub struct ImageMediaMetadata {
     )]
     pub aperture: f64,
     /**
-     * Additional metadata about image media, if available.
+     * The make of the camera used to create the photo.
      */
     #[serde(
         default,
@@ -1397,7 +1397,7 @@ pub struct ImageMediaMetadata {
     )]
     pub camera_make: String,
     /**
-     * Additional metadata about image media, if available.
+     * The model of the camera used to create the photo.
      */
     #[serde(
         default,
@@ -1407,7 +1407,7 @@ pub struct ImageMediaMetadata {
     )]
     pub camera_model: String,
     /**
-     * Additional metadata about image media, if available.
+     * The color space of the photo.
      */
     #[serde(
         default,
@@ -1417,7 +1417,7 @@ pub struct ImageMediaMetadata {
     )]
     pub color_space: String,
     /**
-     * Additional metadata about image media, if available.
+     * The exposure bias of the photo (APEX value).
      */
     #[serde(
         default,
@@ -1427,7 +1427,7 @@ pub struct ImageMediaMetadata {
     )]
     pub exposure_bias: f64,
     /**
-     * Additional metadata about image media, if available.
+     * The exposure mode used to create the photo.
      */
     #[serde(
         default,
@@ -1437,7 +1437,7 @@ pub struct ImageMediaMetadata {
     )]
     pub exposure_mode: String,
     /**
-     * Additional metadata about image media, if available.
+     * The length of the exposure, in seconds.
      */
     #[serde(
         default,
@@ -1447,12 +1447,12 @@ pub struct ImageMediaMetadata {
     )]
     pub exposure_time: f64,
     /**
-     * Additional metadata about image media, if available.
+     * Whether a flash was used to create the photo.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "flashUsed")]
     pub flash_used: Option<bool>,
     /**
-     * Additional metadata about image media, if available.
+     * The focal length used to create the photo, in millimeters.
      */
     #[serde(
         default,
@@ -1462,7 +1462,7 @@ pub struct ImageMediaMetadata {
     )]
     pub focal_length: f64,
     /**
-     * Additional metadata about image media, if available.
+     * The height of the image in pixels.
      */
     #[serde(
         default,
@@ -1471,7 +1471,7 @@ pub struct ImageMediaMetadata {
     )]
     pub height: i64,
     /**
-     * Additional metadata about image media, if available.
+     * The ISO speed used to create the photo.
      */
     #[serde(
         default,
@@ -1481,7 +1481,7 @@ pub struct ImageMediaMetadata {
     )]
     pub iso_speed: i64,
     /**
-     * Additional metadata about image media, if available.
+     * The lens used to create the photo.
      */
     #[serde(
         default,
@@ -1490,12 +1490,12 @@ pub struct ImageMediaMetadata {
     )]
     pub lens: String,
     /**
-     * Additional metadata about image media, if available.
+     * Geographic location information stored in the image.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<Location>,
     /**
-     * Additional metadata about image media, if available.
+     * The smallest f-number of the lens at the focal length used to create the photo (APEX value).
      */
     #[serde(
         default,
@@ -1505,7 +1505,7 @@ pub struct ImageMediaMetadata {
     )]
     pub max_aperture_value: f64,
     /**
-     * Additional metadata about image media, if available.
+     * The metering mode used to create the photo.
      */
     #[serde(
         default,
@@ -1515,7 +1515,7 @@ pub struct ImageMediaMetadata {
     )]
     pub metering_mode: String,
     /**
-     * Additional metadata about image media, if available.
+     * The number of clockwise 90 degree rotations applied from the image's original orientation.
      */
     #[serde(
         default,
@@ -1524,7 +1524,7 @@ pub struct ImageMediaMetadata {
     )]
     pub rotation: i64,
     /**
-     * Additional metadata about image media, if available.
+     * The type of sensor used to create the photo.
      */
     #[serde(
         default,
@@ -1533,7 +1533,7 @@ pub struct ImageMediaMetadata {
     )]
     pub sensor: String,
     /**
-     * Additional metadata about image media, if available.
+     * The distance to the subject of the photo, in meters.
      */
     #[serde(
         default,
@@ -1543,7 +1543,7 @@ pub struct ImageMediaMetadata {
     )]
     pub subject_distance: i64,
     /**
-     * Additional metadata about image media, if available.
+     * The date and time the photo was taken (EXIF DateTime).
      */
     #[serde(
         default,
@@ -1552,7 +1552,7 @@ pub struct ImageMediaMetadata {
     )]
     pub time: String,
     /**
-     * Additional metadata about image media, if available.
+     * The white balance mode used to create the photo.
      */
     #[serde(
         default,
@@ -1562,7 +1562,7 @@ pub struct ImageMediaMetadata {
     )]
     pub white_balance: String,
     /**
-     * Additional metadata about image media, if available.
+     * The width of the image in pixels.
      */
     #[serde(
         default,
@@ -1576,7 +1576,7 @@ pub struct ImageMediaMetadata {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LinkShareMetadata {
     /**
-     * Contains details about the link URLs that clients are using to refer to this item.
+     * Whether the file is eligible for security update.
      */
     #[serde(
         default,
@@ -1585,7 +1585,7 @@ pub struct LinkShareMetadata {
     )]
     pub security_update_eligible: Option<bool>,
     /**
-     * Contains details about the link URLs that clients are using to refer to this item.
+     * Whether the security update is enabled for this file.
      */
     #[serde(
         default,
@@ -1599,7 +1599,7 @@ pub struct LinkShareMetadata {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ShortcutDetails {
     /**
-     * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
+     * The ID of the file that this shortcut points to.
      */
     #[serde(
         default,
@@ -1609,7 +1609,7 @@ pub struct ShortcutDetails {
     )]
     pub target_id: String,
     /**
-     * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
+     * The MIME type of the file that this shortcut points to. The value of this field is a snapshot of the target's MIME type, captured when the shortcut is created.
      */
     #[serde(
         default,
@@ -1619,7 +1619,7 @@ pub struct ShortcutDetails {
     )]
     pub target_mime_type: String,
     /**
-     * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
+     * The ResourceKey for the target file.
      */
     #[serde(
         default,
@@ -1634,7 +1634,7 @@ pub struct ShortcutDetails {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VideoMediaMetadata {
     /**
-     * Additional metadata about video media. This may not be available immediately upon upload.
+     * The duration of the video in milliseconds.
      */
     #[serde(
         default,
@@ -1644,7 +1644,7 @@ pub struct VideoMediaMetadata {
     )]
     pub duration_millis: i64,
     /**
-     * Additional metadata about video media. This may not be available immediately upon upload.
+     * The height of the video in pixels.
      */
     #[serde(
         default,
@@ -1653,7 +1653,7 @@ pub struct VideoMediaMetadata {
     )]
     pub height: i64,
     /**
-     * Additional metadata about video media. This may not be available immediately upon upload.
+     * The width of the video in pixels.
      */
     #[serde(
         default,
@@ -1667,7 +1667,8 @@ pub struct VideoMediaMetadata {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct File {
     /**
-     * The metadata for a file.
+     * A collection of arbitrary key-value pairs which are private to the requesting app.
+     *  Entries with null values are cleared in update and copy requests. These properties can only be retrieved using an authenticated request. An authenticated request uses an access token obtained with a OAuth 2 client ID. You cannot use an API key to retrieve private properties.
      */
     #[serde(
         default,
@@ -1677,12 +1678,12 @@ pub struct File {
     )]
     pub app_properties: String,
     /**
-     * The metadata for a file.
+     * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub capabilities: Option<FileCapabilities>,
     /**
-     * The metadata for a file.
+     * Additional information about the content of the file. These fields are never populated in responses.
      */
     #[serde(
         default,
@@ -1691,7 +1692,7 @@ pub struct File {
     )]
     pub content_hints: Option<ContentHints>,
     /**
-     * The metadata for a file.
+     * Restrictions for accessing the content of the file. Only populated if such a restriction exists.
      */
     #[serde(
         default,
@@ -1701,7 +1702,7 @@ pub struct File {
     )]
     pub content_restrictions: Vec<ContentRestriction>,
     /**
-     * The metadata for a file.
+     * Whether the options to copy, print, or download this file, should be disabled for readers and commenters.
      */
     #[serde(
         default,
@@ -1710,7 +1711,7 @@ pub struct File {
     )]
     pub copy_requires_writer_permission: Option<bool>,
     /**
-     * The metadata for a file.
+     * The time at which the file was created (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -1720,7 +1721,7 @@ pub struct File {
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The metadata for a file.
+     * A short description of the file.
      */
     #[serde(
         default,
@@ -1729,7 +1730,7 @@ pub struct File {
     )]
     pub description: String,
     /**
-     * The metadata for a file.
+     * ID of the shared drive the file resides in. Only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1739,7 +1740,7 @@ pub struct File {
     )]
     pub drive_id: String,
     /**
-     * The metadata for a file.
+     * Whether the file has been explicitly trashed, as opposed to recursively trashed from a parent folder.
      */
     #[serde(
         default,
@@ -1748,7 +1749,7 @@ pub struct File {
     )]
     pub explicitly_trashed: Option<bool>,
     /**
-     * The metadata for a file.
+     * Links for exporting Docs Editors files to specific formats.
      */
     #[serde(
         default,
@@ -1758,7 +1759,7 @@ pub struct File {
     )]
     pub export_links: String,
     /**
-     * The metadata for a file.
+     * The final component of fullFileExtension. This is only available for files with binary content in Google Drive.
      */
     #[serde(
         default,
@@ -1768,7 +1769,8 @@ pub struct File {
     )]
     pub file_extension: String,
     /**
-     * The metadata for a file.
+     * The color for a folder or shortcut to a folder as an RGB hex string. The supported colors are published in the folderColorPalette field of the About resource.
+     *  If an unsupported color is specified, the closest color in the palette will be used instead.
      */
     #[serde(
         default,
@@ -1778,7 +1780,8 @@ pub struct File {
     )]
     pub folder_color_rgb: String,
     /**
-     * The metadata for a file.
+     * The full file extension extracted from the name field. May contain multiple concatenated extensions, such as "tar.gz". This is only available for files with binary content in Google Drive.
+     *  This is automatically updated when the name field changes, however it is not cleared if the new name does not contain a valid extension.
      */
     #[serde(
         default,
@@ -1788,7 +1791,7 @@ pub struct File {
     )]
     pub full_file_extension: String,
     /**
-     * The metadata for a file.
+     * Whether there are permissions directly on this file. This field is only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1797,7 +1800,7 @@ pub struct File {
     )]
     pub has_augmented_permissions: Option<bool>,
     /**
-     * The metadata for a file.
+     * Whether this file has a thumbnail. This does not indicate whether the requesting app has access to the thumbnail. To check access, look for the presence of the thumbnailLink field.
      */
     #[serde(
         default,
@@ -1806,7 +1809,7 @@ pub struct File {
     )]
     pub has_thumbnail: Option<bool>,
     /**
-     * The metadata for a file.
+     * The ID of the file's head revision. This is currently only available for files with binary content in Google Drive.
      */
     #[serde(
         default,
@@ -1816,7 +1819,7 @@ pub struct File {
     )]
     pub head_revision_id: String,
     /**
-     * The metadata for a file.
+     * A static, unauthenticated link to the file's icon.
      */
     #[serde(
         default,
@@ -1826,7 +1829,7 @@ pub struct File {
     )]
     pub icon_link: String,
     /**
-     * The metadata for a file.
+     * The ID of the file.
      */
     #[serde(
         default,
@@ -1835,7 +1838,7 @@ pub struct File {
     )]
     pub id: String,
     /**
-     * The metadata for a file.
+     * Additional metadata about image media, if available.
      */
     #[serde(
         default,
@@ -1844,7 +1847,7 @@ pub struct File {
     )]
     pub image_media_metadata: Option<ImageMediaMetadata>,
     /**
-     * The metadata for a file.
+     * Whether the file was created or opened by the requesting app.
      */
     #[serde(
         default,
@@ -1853,7 +1856,7 @@ pub struct File {
     )]
     pub is_app_authorized: Option<bool>,
     /**
-     * The metadata for a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#file".
      */
     #[serde(
         default,
@@ -1862,7 +1865,7 @@ pub struct File {
     )]
     pub kind: String,
     /**
-     * The metadata for a file.
+     * Information about a Drive user.
      */
     #[serde(
         default,
@@ -1871,7 +1874,7 @@ pub struct File {
     )]
     pub last_modifying_user: Option<User>,
     /**
-     * The metadata for a file.
+     * Contains details about the link URLs that clients are using to refer to this item.
      */
     #[serde(
         default,
@@ -1880,7 +1883,7 @@ pub struct File {
     )]
     pub link_share_metadata: Option<LinkShareMetadata>,
     /**
-     * The metadata for a file.
+     * The MD5 checksum for the content of the file. This is only applicable to files with binary content in Google Drive.
      */
     #[serde(
         default,
@@ -1890,7 +1893,9 @@ pub struct File {
     )]
     pub md_5_checksum: String,
     /**
-     * The metadata for a file.
+     * The MIME type of the file.
+     *  Google Drive will attempt to automatically detect an appropriate value from uploaded content if no value is provided. The value cannot be changed unless a new revision is uploaded.
+     *  If a file is created with a Google Doc MIME type, the uploaded content will be imported if possible. The supported import formats are published in the About resource.
      */
     #[serde(
         default,
@@ -1900,7 +1905,7 @@ pub struct File {
     )]
     pub mime_type: String,
     /**
-     * The metadata for a file.
+     * Whether the file has been modified by this user.
      */
     #[serde(
         default,
@@ -1909,7 +1914,7 @@ pub struct File {
     )]
     pub modified_by_me: Option<bool>,
     /**
-     * The metadata for a file.
+     * The last time the file was modified by the user (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -1919,7 +1924,8 @@ pub struct File {
     )]
     pub modified_by_me_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The metadata for a file.
+     * The last time the file was modified by anyone (RFC 3339 date-time).
+     *  Note that setting modifiedTime will also update modifiedByMeTime for the user.
      */
     #[serde(
         default,
@@ -1929,7 +1935,7 @@ pub struct File {
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The metadata for a file.
+     * The name of the file. This is not necessarily unique within a folder. Note that for immutable items such as the top level folders of shared drives, My Drive root folder, and Application Data folder the name is constant.
      */
     #[serde(
         default,
@@ -1938,7 +1944,7 @@ pub struct File {
     )]
     pub name: String,
     /**
-     * The metadata for a file.
+     * The original filename of the uploaded content if available, or else the original value of the name field. This is only available for files with binary content in Google Drive.
      */
     #[serde(
         default,
@@ -1948,12 +1954,12 @@ pub struct File {
     )]
     pub original_filename: String,
     /**
-     * The metadata for a file.
+     * Whether the user owns the file. Not populated for items in shared drives.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "ownedByMe")]
     pub owned_by_me: Option<bool>,
     /**
-     * The metadata for a file.
+     * The owner of this file. Only certain legacy files may have more than one owner. This field isn't populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1962,7 +1968,8 @@ pub struct File {
     )]
     pub owners: Vec<User>,
     /**
-     * The metadata for a file.
+     * The IDs of the parent folders which contain the file.
+     *  If not specified as part of a create request, the file will be placed directly in the user's My Drive folder. If not specified as part of a copy request, the file will inherit any discoverable parents of the source file. Update requests must use the addParents and removeParents parameters to modify the parents list.
      */
     #[serde(
         default,
@@ -1971,7 +1978,7 @@ pub struct File {
     )]
     pub parents: Vec<String>,
     /**
-     * The metadata for a file.
+     * List of permission IDs for users with access to this file.
      */
     #[serde(
         default,
@@ -1981,7 +1988,7 @@ pub struct File {
     )]
     pub permission_ids: Vec<String>,
     /**
-     * The metadata for a file.
+     * The full list of permissions for the file. This is only available if the requesting user can share the file. Not populated for items in shared drives.
      */
     #[serde(
         default,
@@ -1990,7 +1997,8 @@ pub struct File {
     )]
     pub permissions: Vec<Permission>,
     /**
-     * The metadata for a file.
+     * A collection of arbitrary key-value pairs which are visible to all apps.
+     *  Entries with null values are cleared in update and copy requests.
      */
     #[serde(
         default,
@@ -1999,7 +2007,7 @@ pub struct File {
     )]
     pub properties: String,
     /**
-     * The metadata for a file.
+     * The number of storage quota bytes used by the file. This includes the head revision as well as previous revisions with keepForever enabled.
      */
     #[serde(
         default,
@@ -2009,7 +2017,7 @@ pub struct File {
     )]
     pub quota_bytes_used: i64,
     /**
-     * The metadata for a file.
+     * A key needed to access the item via a shared link.
      */
     #[serde(
         default,
@@ -2019,12 +2027,12 @@ pub struct File {
     )]
     pub resource_key: String,
     /**
-     * The metadata for a file.
+     * Whether the file has been shared. Not populated for items in shared drives.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shared: Option<bool>,
     /**
-     * The metadata for a file.
+     * The time at which the file was shared with the user, if applicable (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -2034,7 +2042,7 @@ pub struct File {
     )]
     pub shared_with_me_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The metadata for a file.
+     * Information about a Drive user.
      */
     #[serde(
         default,
@@ -2043,7 +2051,7 @@ pub struct File {
     )]
     pub sharing_user: Option<User>,
     /**
-     * The metadata for a file.
+     * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
      */
     #[serde(
         default,
@@ -2052,7 +2060,7 @@ pub struct File {
     )]
     pub shortcut_details: Option<ShortcutDetails>,
     /**
-     * The metadata for a file.
+     * The size of the file's content in bytes. This is applicable to binary files in Google Drive and Google Docs files.
      */
     #[serde(
         default,
@@ -2061,7 +2069,7 @@ pub struct File {
     )]
     pub size: i64,
     /**
-     * The metadata for a file.
+     * The list of spaces which contain the file. The currently supported values are 'drive', 'appDataFolder' and 'photos'.
      */
     #[serde(
         default,
@@ -2070,12 +2078,12 @@ pub struct File {
     )]
     pub spaces: Vec<String>,
     /**
-     * The metadata for a file.
+     * Whether the user has starred the file.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub starred: Option<bool>,
     /**
-     * The metadata for a file.
+     * Deprecated - use driveId instead.
      */
     #[serde(
         default,
@@ -2085,7 +2093,7 @@ pub struct File {
     )]
     pub team_drive_id: String,
     /**
-     * The metadata for a file.
+     * A short-lived link to the file's thumbnail, if available. Typically lasts on the order of hours. Only populated when the requesting app can access the file's content. If the file isn't shared publicly, the URL returned in Files.thumbnailLink must be fetched using a credentialed request.
      */
     #[serde(
         default,
@@ -2095,7 +2103,7 @@ pub struct File {
     )]
     pub thumbnail_link: String,
     /**
-     * The metadata for a file.
+     * The thumbnail version for use in thumbnail cache invalidation.
      */
     #[serde(
         default,
@@ -2105,12 +2113,12 @@ pub struct File {
     )]
     pub thumbnail_version: i64,
     /**
-     * The metadata for a file.
+     * Whether the file has been trashed, either explicitly or from a trashed parent folder. Only the owner may trash a file. The trashed item is excluded from all files.list responses returned for any user who does not own the file. However, all users with access to the file can see the trashed item metadata in an API response. All users with access can copy, download, export, and share the file.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trashed: Option<bool>,
     /**
-     * The metadata for a file.
+     * The time that the item was trashed (RFC 3339 date-time). Only populated for items in shared drives.
      */
     #[serde(
         default,
@@ -2120,7 +2128,7 @@ pub struct File {
     )]
     pub trashed_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The metadata for a file.
+     * Information about a Drive user.
      */
     #[serde(
         default,
@@ -2129,7 +2137,7 @@ pub struct File {
     )]
     pub trashing_user: Option<User>,
     /**
-     * The metadata for a file.
+     * A monotonically increasing version number for the file. This reflects every change made to the file on the server, even those not visible to the user.
      */
     #[serde(
         default,
@@ -2138,7 +2146,7 @@ pub struct File {
     )]
     pub version: i64,
     /**
-     * The metadata for a file.
+     * Additional metadata about video media. This may not be available immediately upon upload.
      */
     #[serde(
         default,
@@ -2147,7 +2155,7 @@ pub struct File {
     )]
     pub video_media_metadata: Option<VideoMediaMetadata>,
     /**
-     * The metadata for a file.
+     * Whether the file has been viewed by this user.
      */
     #[serde(
         default,
@@ -2156,7 +2164,7 @@ pub struct File {
     )]
     pub viewed_by_me: Option<bool>,
     /**
-     * The metadata for a file.
+     * The last time the file was viewed by the user (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -2166,7 +2174,7 @@ pub struct File {
     )]
     pub viewed_by_me_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The metadata for a file.
+     * Deprecated - use copyRequiresWriterPermission instead.
      */
     #[serde(
         default,
@@ -2175,7 +2183,7 @@ pub struct File {
     )]
     pub viewers_can_copy_content: Option<bool>,
     /**
-     * The metadata for a file.
+     * A link for downloading the content of the file in a browser. This is only available for files with binary content in Google Drive.
      */
     #[serde(
         default,
@@ -2185,7 +2193,7 @@ pub struct File {
     )]
     pub web_content_link: String,
     /**
-     * The metadata for a file.
+     * A link for opening the file in a relevant Google editor or viewer in a browser.
      */
     #[serde(
         default,
@@ -2195,7 +2203,7 @@ pub struct File {
     )]
     pub web_view_link: String,
     /**
-     * The metadata for a file.
+     * Whether users with only writer permission can modify the file's permissions. Not populated for items in shared drives.
      */
     #[serde(
         default,
@@ -2209,7 +2217,7 @@ pub struct File {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FileList {
     /**
-     * A list of files.
+     * The list of files. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -2218,7 +2226,7 @@ pub struct FileList {
     )]
     pub files: Vec<File>,
     /**
-     * A list of files.
+     * Whether the search process was incomplete. If true, then some search results may be missing, since all documents were not searched. This may occur when searching multiple drives with the "allDrives" corpora, but all corpora could not be searched. When this happens, it is suggested that clients narrow their query by choosing a different corpus such as "user" or "drive".
      */
     #[serde(
         default,
@@ -2227,7 +2235,7 @@ pub struct FileList {
     )]
     pub incomplete_search: Option<bool>,
     /**
-     * A list of files.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#fileList".
      */
     #[serde(
         default,
@@ -2236,7 +2244,7 @@ pub struct FileList {
     )]
     pub kind: String,
     /**
-     * A list of files.
+     * The page token for the next page of files. This will be absent if the end of the files list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -2251,7 +2259,7 @@ pub struct FileList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GeneratedIds {
     /**
-     * A list of generated file IDs which can be provided in create requests.
+     * The IDs generated for the requesting user in the specified space.
      */
     #[serde(
         default,
@@ -2260,7 +2268,7 @@ pub struct GeneratedIds {
     )]
     pub ids: Vec<String>,
     /**
-     * A list of generated file IDs which can be provided in create requests.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#generatedIds".
      */
     #[serde(
         default,
@@ -2269,7 +2277,7 @@ pub struct GeneratedIds {
     )]
     pub kind: String,
     /**
-     * A list of generated file IDs which can be provided in create requests.
+     * The type of file that can be created with these IDs.
      */
     #[serde(
         default,
@@ -2282,12 +2290,12 @@ pub struct GeneratedIds {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PermissionDetails {
     /**
-     * Whether the user has installed the requesting app.
+     * Whether this permission is inherited. This field is always populated. This is an output-only field.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inherited: Option<bool>,
     /**
-     * A link to this theme's background image.
+     * The ID of the item from which this permission is inherited. This is an output-only field.
      */
     #[serde(
         default,
@@ -2297,7 +2305,9 @@ pub struct PermissionDetails {
     )]
     pub inherited_from: String,
     /**
-     * A link to this theme's background image.
+     * The permission type for this user. While new values may be added in future, the following are currently possible:
+     *  - file
+     *  - member
      */
     #[serde(
         default,
@@ -2307,7 +2317,12 @@ pub struct PermissionDetails {
     )]
     pub permission_type: String,
     /**
-     * A link to this theme's background image.
+     * The primary role for this user. While new values may be added in the future, the following are currently possible:
+     *  - organizer
+     *  - fileOrganizer
+     *  - writer
+     *  - commenter
+     *  - reader
      */
     #[serde(
         default,
@@ -2320,12 +2335,12 @@ pub struct PermissionDetails {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamDrivePermissionDetails {
     /**
-     * Whether the user has installed the requesting app.
+     * Deprecated - use permissionDetails/inherited instead.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inherited: Option<bool>,
     /**
-     * A link to this theme's background image.
+     * Deprecated - use permissionDetails/inheritedFrom instead.
      */
     #[serde(
         default,
@@ -2335,7 +2350,7 @@ pub struct TeamDrivePermissionDetails {
     )]
     pub inherited_from: String,
     /**
-     * A link to this theme's background image.
+     * Deprecated - use permissionDetails/role instead.
      */
     #[serde(
         default,
@@ -2344,7 +2359,7 @@ pub struct TeamDrivePermissionDetails {
     )]
     pub role: String,
     /**
-     * A link to this theme's background image.
+     * Deprecated - use permissionDetails/permissionType instead.
      */
     #[serde(
         default,
@@ -2359,7 +2374,7 @@ pub struct TeamDrivePermissionDetails {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Permission {
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * Whether the permission allows the file to be discovered through search. This is only applicable for permissions of type domain or anyone.
      */
     #[serde(
         default,
@@ -2368,12 +2383,16 @@ pub struct Permission {
     )]
     pub allow_file_discovery: Option<bool>,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * Whether the account associated with this permission has been deleted. This field only pertains to user and group permissions.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * The "pretty" name of the value of the permission. The following is a list of examples for each type of permission:
+     *  - user - User's full name, as defined for their Google account, such as "Joe Smith."
+     *  - group - Name of the Google Group, such as "The Company Administrators."
+     *  - domain - String domain name, such as "thecompany.com."
+     *  - anyone - No displayName is present.
      */
     #[serde(
         default,
@@ -2383,7 +2402,7 @@ pub struct Permission {
     )]
     pub display_name: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * The domain to which this permission refers.
      */
     #[serde(
         default,
@@ -2392,7 +2411,7 @@ pub struct Permission {
     )]
     pub domain: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * The email address of the user or group to which this permission refers.
      */
     #[serde(
         default,
@@ -2402,7 +2421,10 @@ pub struct Permission {
     )]
     pub email_address: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * The time at which this permission will expire (RFC 3339 date-time). Expiration times have the following restrictions:
+     *  - They can only be set on user and group permissions
+     *  - The time must be in the future
+     *  - The time cannot be more than a year in the future
      */
     #[serde(
         default,
@@ -2412,7 +2434,7 @@ pub struct Permission {
     )]
     pub expiration_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * The ID of this permission. This is a unique identifier for the grantee, and is published in User resources as permissionId. IDs should be treated as opaque values.
      */
     #[serde(
         default,
@@ -2421,7 +2443,7 @@ pub struct Permission {
     )]
     pub id: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#permission".
      */
     #[serde(
         default,
@@ -2430,7 +2452,7 @@ pub struct Permission {
     )]
     pub kind: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * Details of whether the permissions on this shared drive item are inherited or directly on this item. This is an output-only field which is present only for shared drive items.
      */
     #[serde(
         default,
@@ -2440,7 +2462,7 @@ pub struct Permission {
     )]
     pub permission_details: Vec<PermissionDetails>,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * A link to the user's profile photo, if available.
      */
     #[serde(
         default,
@@ -2450,7 +2472,13 @@ pub struct Permission {
     )]
     pub photo_link: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * The role granted by this permission. While new values may be supported in the future, the following are currently allowed:
+     *  - owner
+     *  - organizer
+     *  - fileOrganizer
+     *  - writer
+     *  - commenter
+     *  - reader
      */
     #[serde(
         default,
@@ -2459,7 +2487,7 @@ pub struct Permission {
     )]
     pub role: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * Deprecated - use permissionDetails instead.
      */
     #[serde(
         default,
@@ -2469,7 +2497,11 @@ pub struct Permission {
     )]
     pub team_drive_permission_details: Vec<TeamDrivePermissionDetails>,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * The type of the grantee. Valid values are:
+     *  - user
+     *  - group
+     *  - domain
+     *  - anyone  When creating a permission, if type is user or group, you must provide an emailAddress for the user or group. When type is domain, you must provide a domain. There isn't extra information required for a anyone type.
      */
     #[serde(
         default,
@@ -2479,7 +2511,7 @@ pub struct Permission {
     )]
     pub type_: String,
     /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     * Indicates the view for this permission. Only populated for permissions that belong to a view. published is the only supported value.
      */
     #[serde(
         default,
@@ -2493,7 +2525,7 @@ pub struct Permission {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PermissionList {
     /**
-     * A list of permissions for a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#permissionList".
      */
     #[serde(
         default,
@@ -2502,7 +2534,7 @@ pub struct PermissionList {
     )]
     pub kind: String,
     /**
-     * A list of permissions for a file.
+     * The page token for the next page of permissions. This field will be absent if the end of the permissions list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -2512,7 +2544,7 @@ pub struct PermissionList {
     )]
     pub next_page_token: String,
     /**
-     * A list of permissions for a file.
+     * The list of permissions. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -2526,7 +2558,9 @@ pub struct PermissionList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Reply {
     /**
-     * A reply to a comment on a file.
+     * The action the reply performed to the parent comment. Valid values are:
+     *  - resolve
+     *  - reopen
      */
     #[serde(
         default,
@@ -2535,12 +2569,12 @@ pub struct Reply {
     )]
     pub action: String,
     /**
-     * A reply to a comment on a file.
+     * Information about a Drive user.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub author: Option<User>,
     /**
-     * A reply to a comment on a file.
+     * The plain text content of the reply. This field is used for setting the content, while htmlContent should be displayed. This is required on creates if no action is specified.
      */
     #[serde(
         default,
@@ -2549,7 +2583,7 @@ pub struct Reply {
     )]
     pub content: String,
     /**
-     * A reply to a comment on a file.
+     * The time at which the reply was created (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -2559,12 +2593,12 @@ pub struct Reply {
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * A reply to a comment on a file.
+     * Whether the reply has been deleted. A deleted reply has no content.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deleted: Option<bool>,
     /**
-     * A reply to a comment on a file.
+     * The content of the reply with HTML formatting.
      */
     #[serde(
         default,
@@ -2574,7 +2608,7 @@ pub struct Reply {
     )]
     pub html_content: String,
     /**
-     * A reply to a comment on a file.
+     * The ID of the reply.
      */
     #[serde(
         default,
@@ -2583,7 +2617,7 @@ pub struct Reply {
     )]
     pub id: String,
     /**
-     * A reply to a comment on a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#reply".
      */
     #[serde(
         default,
@@ -2592,7 +2626,7 @@ pub struct Reply {
     )]
     pub kind: String,
     /**
-     * A reply to a comment on a file.
+     * The last time the reply was modified (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -2607,7 +2641,7 @@ pub struct Reply {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReplyList {
     /**
-     * A list of replies to a comment on a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#replyList".
      */
     #[serde(
         default,
@@ -2616,7 +2650,7 @@ pub struct ReplyList {
     )]
     pub kind: String,
     /**
-     * A list of replies to a comment on a file.
+     * The page token for the next page of replies. This will be absent if the end of the replies list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -2626,7 +2660,7 @@ pub struct ReplyList {
     )]
     pub next_page_token: String,
     /**
-     * A list of replies to a comment on a file.
+     * The list of replies. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -2640,7 +2674,7 @@ pub struct ReplyList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Revision {
     /**
-     * The metadata for a revision to a file.
+     * Links for exporting Docs Editors files to specific formats.
      */
     #[serde(
         default,
@@ -2650,7 +2684,7 @@ pub struct Revision {
     )]
     pub export_links: String,
     /**
-     * The metadata for a revision to a file.
+     * The ID of the revision.
      */
     #[serde(
         default,
@@ -2659,7 +2693,8 @@ pub struct Revision {
     )]
     pub id: String,
     /**
-     * The metadata for a revision to a file.
+     * Whether to keep this revision forever, even if it is no longer the head revision. If not set, the revision will be automatically purged 30 days after newer content is uploaded. This can be set on a maximum of 200 revisions for a file.
+     *  This field is only applicable to files with binary content in Drive.
      */
     #[serde(
         default,
@@ -2668,7 +2703,7 @@ pub struct Revision {
     )]
     pub keep_forever: Option<bool>,
     /**
-     * The metadata for a revision to a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#revision".
      */
     #[serde(
         default,
@@ -2677,7 +2712,7 @@ pub struct Revision {
     )]
     pub kind: String,
     /**
-     * The metadata for a revision to a file.
+     * Information about a Drive user.
      */
     #[serde(
         default,
@@ -2686,7 +2721,7 @@ pub struct Revision {
     )]
     pub last_modifying_user: Option<User>,
     /**
-     * The metadata for a revision to a file.
+     * The MD5 checksum of the revision's content. This is only applicable to files with binary content in Drive.
      */
     #[serde(
         default,
@@ -2696,7 +2731,7 @@ pub struct Revision {
     )]
     pub md_5_checksum: String,
     /**
-     * The metadata for a revision to a file.
+     * The MIME type of the revision.
      */
     #[serde(
         default,
@@ -2706,7 +2741,7 @@ pub struct Revision {
     )]
     pub mime_type: String,
     /**
-     * The metadata for a revision to a file.
+     * The last time the revision was modified (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -2716,7 +2751,7 @@ pub struct Revision {
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The metadata for a revision to a file.
+     * The original filename used to create this revision. This is only applicable to files with binary content in Drive.
      */
     #[serde(
         default,
@@ -2726,7 +2761,7 @@ pub struct Revision {
     )]
     pub original_filename: String,
     /**
-     * The metadata for a revision to a file.
+     * Whether subsequent revisions will be automatically republished. This is only applicable to Docs Editors files.
      */
     #[serde(
         default,
@@ -2735,12 +2770,12 @@ pub struct Revision {
     )]
     pub publish_auto: Option<bool>,
     /**
-     * The metadata for a revision to a file.
+     * Whether this revision is published. This is only applicable to Docs Editors files.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub published: Option<bool>,
     /**
-     * The metadata for a revision to a file.
+     * A link to the published revision. This is only populated for Google Sites files.
      */
     #[serde(
         default,
@@ -2750,7 +2785,7 @@ pub struct Revision {
     )]
     pub published_link: String,
     /**
-     * The metadata for a revision to a file.
+     * Whether this revision is published outside the domain. This is only applicable to Docs Editors files.
      */
     #[serde(
         default,
@@ -2759,7 +2794,7 @@ pub struct Revision {
     )]
     pub published_outside_domain: Option<bool>,
     /**
-     * The metadata for a revision to a file.
+     * The size of the revision's content in bytes. This is only applicable to files with binary content in Drive.
      */
     #[serde(
         default,
@@ -2773,7 +2808,7 @@ pub struct Revision {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RevisionList {
     /**
-     * A list of revisions of a file.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#revisionList".
      */
     #[serde(
         default,
@@ -2782,7 +2817,7 @@ pub struct RevisionList {
     )]
     pub kind: String,
     /**
-     * A list of revisions of a file.
+     * The page token for the next page of revisions. This will be absent if the end of the revisions list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -2792,7 +2827,7 @@ pub struct RevisionList {
     )]
     pub next_page_token: String,
     /**
-     * A list of revisions of a file.
+     * The list of revisions. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -2805,7 +2840,7 @@ pub struct RevisionList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StartPageToken {
     /**
-     * A link to this theme's background image.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#startPageToken".
      */
     #[serde(
         default,
@@ -2814,7 +2849,7 @@ pub struct StartPageToken {
     )]
     pub kind: String,
     /**
-     * A link to this theme's background image.
+     * The starting page token for listing changes.
      */
     #[serde(
         default,
@@ -2829,7 +2864,7 @@ pub struct StartPageToken {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamDriveBackgroundImageFile {
     /**
-     * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The ID of an image file in Drive to use for the background image.
      */
     #[serde(
         default,
@@ -2838,7 +2873,7 @@ pub struct TeamDriveBackgroundImageFile {
     )]
     pub id: String,
     /**
-     * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The width of the cropped image in the closed range of 0 to 1. This value represents the width of the cropped image divided by the width of the entire image. The height is computed by applying a width to height aspect ratio of 80 to 9. The resulting image must be at least 1280 pixels wide and 144 pixels high.
      */
     #[serde(
         default,
@@ -2847,7 +2882,7 @@ pub struct TeamDriveBackgroundImageFile {
     )]
     pub width: f64,
     /**
-     * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The X coordinate of the upper left corner of the cropping area in the background image. This is a value in the closed range of 0 to 1. This value represents the horizontal distance from the left side of the entire image to the left side of the cropping area divided by the width of the entire image.
      */
     #[serde(
         default,
@@ -2857,7 +2892,7 @@ pub struct TeamDriveBackgroundImageFile {
     )]
     pub x_coordinate: f64,
     /**
-     * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
+     * The Y coordinate of the upper left corner of the cropping area in the background image. This is a value in the closed range of 0 to 1. This value represents the vertical distance from the top side of the entire image to the top side of the cropping area divided by the height of the entire image.
      */
     #[serde(
         default,
@@ -2872,7 +2907,7 @@ pub struct TeamDriveBackgroundImageFile {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamDriveCapabilities {
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can add children to folders in this Team Drive.
      */
     #[serde(
         default,
@@ -2881,7 +2916,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_add_children: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can change the copyRequiresWriterPermission restriction of this Team Drive.
      */
     #[serde(
         default,
@@ -2890,7 +2925,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_change_copy_requires_writer_permission_restriction: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can change the domainUsersOnly restriction of this Team Drive.
      */
     #[serde(
         default,
@@ -2899,7 +2934,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_change_domain_users_only_restriction: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can change the background of this Team Drive.
      */
     #[serde(
         default,
@@ -2908,7 +2943,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_change_team_drive_background: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can change the teamMembersOnly restriction of this Team Drive.
      */
     #[serde(
         default,
@@ -2917,7 +2952,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_change_team_members_only_restriction: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can comment on files in this Team Drive.
      */
     #[serde(
         default,
@@ -2926,12 +2961,12 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_comment: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can copy files in this Team Drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canCopy")]
     pub can_copy: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can delete children from folders in this Team Drive.
      */
     #[serde(
         default,
@@ -2940,7 +2975,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_delete_children: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can delete this Team Drive. Attempting to delete the Team Drive may still fail if there are untrashed items inside the Team Drive.
      */
     #[serde(
         default,
@@ -2949,7 +2984,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_delete_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can download files in this Team Drive.
      */
     #[serde(
         default,
@@ -2958,12 +2993,12 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_download: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can edit files in this Team Drive
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canEdit")]
     pub can_edit: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can list the children of folders in this Team Drive.
      */
     #[serde(
         default,
@@ -2972,7 +3007,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_list_children: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can add members to this Team Drive or remove them or change their role.
      */
     #[serde(
         default,
@@ -2981,7 +3016,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_manage_members: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can read the revisions resource of files in this Team Drive.
      */
     #[serde(
         default,
@@ -2990,7 +3025,7 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_read_revisions: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Deprecated - use canDeleteChildren or canTrashChildren instead.
      */
     #[serde(
         default,
@@ -2999,12 +3034,12 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_remove_children: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can rename files or folders in this Team Drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canRename")]
     pub can_rename: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can rename this Team Drive.
      */
     #[serde(
         default,
@@ -3013,12 +3048,12 @@ pub struct TeamDriveCapabilities {
     )]
     pub can_rename_team_drive: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can share files or folders in this Team Drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "canShare")]
     pub can_share: Option<bool>,
     /**
-     * Capabilities the current user has on this Team Drive.
+     * Whether the current user can trash children from folders in this Team Drive.
      */
     #[serde(
         default,
@@ -3032,7 +3067,7 @@ pub struct TeamDriveCapabilities {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamDriveRestrictions {
     /**
-     * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
+     * Whether administrative privileges on this Team Drive are required to modify restrictions.
      */
     #[serde(
         default,
@@ -3041,7 +3076,7 @@ pub struct TeamDriveRestrictions {
     )]
     pub admin_managed_restrictions: Option<bool>,
     /**
-     * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
+     * Whether the options to copy, print, or download files inside this Team Drive, should be disabled for readers and commenters. When this restriction is set to true, it will override the similarly named field to true for any file inside this Team Drive.
      */
     #[serde(
         default,
@@ -3050,7 +3085,7 @@ pub struct TeamDriveRestrictions {
     )]
     pub copy_requires_writer_permission: Option<bool>,
     /**
-     * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
+     * Whether access to this Team Drive and items inside this Team Drive is restricted to users of the domain to which this Team Drive belongs. This restriction may be overridden by other sharing policies controlled outside of this Team Drive.
      */
     #[serde(
         default,
@@ -3059,7 +3094,7 @@ pub struct TeamDriveRestrictions {
     )]
     pub domain_users_only: Option<bool>,
     /**
-     * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
+     * Whether access to items inside this Team Drive is restricted to members of this Team Drive.
      */
     #[serde(
         default,
@@ -3073,7 +3108,7 @@ pub struct TeamDriveRestrictions {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamDrive {
     /**
-     * Deprecated: use the drive collection instead.
+     * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
      */
     #[serde(
         default,
@@ -3082,7 +3117,7 @@ pub struct TeamDrive {
     )]
     pub background_image_file: Option<TeamDriveBackgroundImageFile>,
     /**
-     * Deprecated: use the drive collection instead.
+     * A short-lived link to this Team Drive's background image.
      */
     #[serde(
         default,
@@ -3092,12 +3127,12 @@ pub struct TeamDrive {
     )]
     pub background_image_link: String,
     /**
-     * Deprecated: use the drive collection instead.
+     * Capabilities the current user has on this Team Drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub capabilities: Option<TeamDriveCapabilities>,
     /**
-     * Deprecated: use the drive collection instead.
+     * The color of this Team Drive as an RGB hex string. It can only be set on a drive.teamdrives.update request that does not set themeId.
      */
     #[serde(
         default,
@@ -3107,7 +3142,7 @@ pub struct TeamDrive {
     )]
     pub color_rgb: String,
     /**
-     * Deprecated: use the drive collection instead.
+     * The time at which the Team Drive was created (RFC 3339 date-time).
      */
     #[serde(
         default,
@@ -3117,7 +3152,7 @@ pub struct TeamDrive {
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Deprecated: use the drive collection instead.
+     * The ID of this Team Drive which is also the ID of the top level folder of this Team Drive.
      */
     #[serde(
         default,
@@ -3126,7 +3161,7 @@ pub struct TeamDrive {
     )]
     pub id: String,
     /**
-     * Deprecated: use the drive collection instead.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#teamDrive".
      */
     #[serde(
         default,
@@ -3135,7 +3170,7 @@ pub struct TeamDrive {
     )]
     pub kind: String,
     /**
-     * Deprecated: use the drive collection instead.
+     * The name of this Team Drive.
      */
     #[serde(
         default,
@@ -3144,12 +3179,12 @@ pub struct TeamDrive {
     )]
     pub name: String,
     /**
-     * Deprecated: use the drive collection instead.
+     * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub restrictions: Option<TeamDriveRestrictions>,
     /**
-     * Deprecated: use the drive collection instead.
+     * The ID of the theme from which the background image and color will be set. The set of possible teamDriveThemes can be retrieved from a drive.about.get response. When not specified on a drive.teamdrives.create request, a random theme is chosen from which the background image and color are set. This is a write-only field; it can only be set on requests that don't set colorRgb or backgroundImageFile.
      */
     #[serde(
         default,
@@ -3164,7 +3199,7 @@ pub struct TeamDrive {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamDriveList {
     /**
-     * A list of Team Drives.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#teamDriveList".
      */
     #[serde(
         default,
@@ -3173,7 +3208,7 @@ pub struct TeamDriveList {
     )]
     pub kind: String,
     /**
-     * A list of Team Drives.
+     * The page token for the next page of Team Drives. This will be absent if the end of the Team Drives list has been reached. If the token is rejected for any reason, it should be discarded, and pagination should be restarted from the first page of results.
      */
     #[serde(
         default,
@@ -3183,7 +3218,7 @@ pub struct TeamDriveList {
     )]
     pub next_page_token: String,
     /**
-     * A list of Team Drives.
+     * The list of Team Drives. If nextPageToken is populated, then this list may be incomplete and an additional page of results should be fetched.
      */
     #[serde(
         default,
@@ -3198,7 +3233,7 @@ pub struct TeamDriveList {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct User {
     /**
-     * Information about a Drive user.
+     * A plain text displayable name for this user.
      */
     #[serde(
         default,
@@ -3208,7 +3243,7 @@ pub struct User {
     )]
     pub display_name: String,
     /**
-     * Information about a Drive user.
+     * The email address of the user. This may not be present in certain contexts if the user has not made their email address visible to the requester.
      */
     #[serde(
         default,
@@ -3218,7 +3253,7 @@ pub struct User {
     )]
     pub email_address: String,
     /**
-     * Information about a Drive user.
+     * Identifies what kind of resource this is. Value: the fixed string "drive#user".
      */
     #[serde(
         default,
@@ -3227,12 +3262,12 @@ pub struct User {
     )]
     pub kind: String,
     /**
-     * Information about a Drive user.
+     * Whether this user is the requesting user.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub me: Option<bool>,
     /**
-     * Information about a Drive user.
+     * The user's ID as visible in Permission resources.
      */
     #[serde(
         default,
@@ -3242,7 +3277,7 @@ pub struct User {
     )]
     pub permission_id: String,
     /**
-     * Information about a Drive user.
+     * A link to the user's profile photo, if available.
      */
     #[serde(
         default,
@@ -3257,13 +3292,15 @@ pub struct User {
 * Data format for the response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -3271,12 +3308,31 @@ impl std::fmt::Display for Alt {
         match self {
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Alt {
+        match s.as_str() {
+            "json" => Alt::Json,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(e: Alt) -> String {
+        match e {
+            Alt::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -3292,6 +3348,7 @@ impl Alt {
 * The source of files to list. Deprecated: use 'corpora' instead.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Corpus {
     #[serde(rename = "domain")]
     Domain,
@@ -3299,8 +3356,9 @@ pub enum Corpus {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Corpus {
@@ -3309,12 +3367,32 @@ impl std::fmt::Display for Corpus {
             Corpus::Domain => "domain",
             Corpus::User => "user",
             Corpus::Noop => "",
-            Corpus::FallthroughString => "*",
+            Corpus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Corpus {
+    fn from(s: String) -> Corpus {
+        match s.as_str() {
+            "domain" => Corpus::Domain,
+            "user" => Corpus::User,
+            "" => Corpus::Noop,
+            _ => Corpus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Corpus> for String {
+    fn from(e: Corpus) -> String {
+        match e {
+            Corpus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Corpus {
     fn default() -> Corpus {
         Corpus::Noop
@@ -25,12 +25,32 @@ impl Groups {
     pub async fn get(
         &self,
         alt: crate::types::Alt,
+        fields: &str,
         group_unique_id: &str,
     ) -> ClientResult<crate::types::Groups> {
+        Ok(self
+            .get_with_response(alt, fields, group_unique_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/{groupUniqueId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        alt: crate::types::Alt,
+        fields: &str,
+        group_unique_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !alt.to_string().is_empty() {
             query_args.push(("alt".to_string(), alt.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -41,7 +61,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -64,13 +84,34 @@ impl Groups {
     pub async fn update(
         &self,
         alt: crate::types::Alt,
+        fields: &str,
         group_unique_id: &str,
         body: &crate::types::Groups,
     ) -> ClientResult<crate::types::Groups> {
+        Ok(self
+            .update_with_response(alt, fields, group_unique_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/{groupUniqueId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        alt: crate::types::Alt,
+        fields: &str,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !alt.to_string().is_empty() {
             query_args.push(("alt".to_string(), alt.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -81,7 +122,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -104,13 +145,34 @@ impl Groups {
     pub async fn patch(
         &self,
         alt: crate::types::Alt,
+        fields: &str,
         group_unique_id: &str,
         body: &crate::types::Groups,
     ) -> ClientResult<crate::types::Groups> {
+        Ok(self
+            .patch_with_response(alt, fields, group_unique_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PATCH` to the `/{groupUniqueId}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        alt: crate::types::Alt,
+        fields: &str,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !alt.to_string().is_empty() {
             query_args.push(("alt".to_string(), alt.to_string()));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -121,7 +183,7 @@ impl Groups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Groups {
     /**
-     * JSON template for Group resource
+     * Identifies whether members external to your organization can join the group. Possible values are:  
+     *  - true: G Suite users external to your organization can become members of this group.
+     *  - false: Users not belonging to the organization are not allowed to become members of this group.
      */
     #[serde(
         default,
@@ -16,7 +18,9 @@ pub struct Groups {
     )]
     pub allow_external_members: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. Allows Google to contact administrator of the group.  
+     *  - true: Allow Google to contact managers of this group. Occasionally Google may send updates on the latest features, ask for input on new features, or ask for permission to highlight your group.
+     *  - false: Google can not contact managers of this group.
      */
     #[serde(
         default,
@@ -26,7 +30,9 @@ pub struct Groups {
     )]
     pub allow_google_communication: String,
     /**
-     * JSON template for Group resource
+     * Allows posting from web. Possible values are:  
+     *  - true: Allows any member to post to the group forum.
+     *  - false: Members only use Gmail to communicate with the group.
      */
     #[serde(
         default,
@@ -36,7 +42,12 @@ pub struct Groups {
     )]
     pub allow_web_posting: String,
     /**
-     * JSON template for Group resource
+     * Allows the group to be archived only. Possible values are:  
+     *  - true: Group is archived and the group is inactive. New messages to this group are rejected. The older archived messages are browseable and searchable.  
+     *  - If true, the whoCanPostMessage property is set to NONE_CAN_POST.  
+     *  - If reverted from true to false, whoCanPostMessages is set to ALL_MANAGERS_CAN_POST.  
+     *  - false: The group is active and can receive messages.  
+     *  - When false, updating whoCanPostMessage to NONE_CAN_POST, results in an error.
      */
     #[serde(
         default,
@@ -46,7 +57,7 @@ pub struct Groups {
     )]
     pub archive_only: String,
     /**
-     * JSON template for Group resource
+     * Set the content of custom footer text. The maximum number of characters is 1,000.
      */
     #[serde(
         default,
@@ -56,7 +67,9 @@ pub struct Groups {
     )]
     pub custom_footer_text: String,
     /**
-     * JSON template for Group resource
+     * An email address used when replying to a message if the replyTo property is set to REPLY_TO_CUSTOM. This address is defined by an account administrator.  
+     *  - When the group's ReplyTo property is set to REPLY_TO_CUSTOM, the customReplyTo property holds a custom email address used when replying to a message.
+     *  - If the group's ReplyTo property is set to REPLY_TO_CUSTOM, the customReplyTo property must have a text value or an error is returned.
      */
     #[serde(
         default,
@@ -66,7 +79,9 @@ pub struct Groups {
     )]
     pub custom_reply_to: String,
     /**
-     * JSON template for Group resource
+     * Specifies whether the group has a custom role that's included in one of the settings being merged. This field is read-only and update/patch requests to it are ignored. Possible values are:  
+     *  - true
+     *  - false
      */
     #[serde(
         default,
@@ -76,7 +91,7 @@ pub struct Groups {
     )]
     pub custom_roles_enabled_for_settings_to_be_merged: String,
     /**
-     * JSON template for Group resource
+     * When a message is rejected, this is text for the rejection notification sent to the message's author. By default, this property is empty and has no value in the API's response body. The maximum notification text size is 10,000 characters. Note: Requires sendMessageDenyNotification property to be true.
      */
     #[serde(
         default,
@@ -86,7 +101,7 @@ pub struct Groups {
     )]
     pub default_message_deny_notification_text: String,
     /**
-     * JSON template for Group resource
+     * Default sender for members who can post messages as the group. Possible values are: - `DEFAULT_SELF`: By default messages will be sent from the user - `GROUP`: By default messages will be sent from the group
      */
     #[serde(
         default,
@@ -95,7 +110,7 @@ pub struct Groups {
     )]
     pub default_sender: String,
     /**
-     * JSON template for Group resource
+     * Description of the group. This property value may be an empty string if no group description has been entered. If entered, the maximum group description is no more than 300 characters.
      */
     #[serde(
         default,
@@ -104,7 +119,8 @@ pub struct Groups {
     )]
     pub description: String,
     /**
-     * JSON template for Group resource
+     * The group's email address. This property can be updated using the Directory API. Note: Only a group owner can change a group's email address. A group manager can't do this.
+     *  When you change your group's address using the Directory API or the control panel, you are changing the address your subscribers use to send email and the web address people use to access your group. People can't reach your group by visiting the old address.
      */
     #[serde(
         default,
@@ -113,7 +129,9 @@ pub struct Groups {
     )]
     pub email: String,
     /**
-     * JSON template for Group resource
+     * Specifies whether a collaborative inbox will remain turned on for the group. Possible values are:  
+     *  - true
+     *  - false
      */
     #[serde(
         default,
@@ -123,7 +141,9 @@ pub struct Groups {
     )]
     pub enable_collaborative_inbox: String,
     /**
-     * JSON template for Group resource
+     * Indicates if favorite replies should be displayed above other replies.  
+     *  - true: Favorite replies will be displayed above other replies.
+     *  - false: Favorite replies will not be displayed above other replies.
      */
     #[serde(
         default,
@@ -133,7 +153,9 @@ pub struct Groups {
     )]
     pub favorite_replies_on_top: String,
     /**
-     * JSON template for Group resource
+     * Whether to include custom footer. Possible values are:  
+     *  - true
+     *  - false
      */
     #[serde(
         default,
@@ -143,7 +165,9 @@ pub struct Groups {
     )]
     pub include_custom_footer: String,
     /**
-     * JSON template for Group resource
+     * Enables the group to be included in the Global Address List. For more information, see the help center. Possible values are:  
+     *  - true: Group is included in the Global Address List.
+     *  - false: Group is not included in the Global Address List.
      */
     #[serde(
         default,
@@ -153,7 +177,9 @@ pub struct Groups {
     )]
     pub include_in_global_address_list: String,
     /**
-     * JSON template for Group resource
+     * Allows the Group contents to be archived. Possible values are:  
+     *  - true: Archive messages sent to the group.
+     *  - false: Do not keep an archive of messages sent to this group. If false, previously archived messages remain in the archive.
      */
     #[serde(
         default,
@@ -163,7 +189,7 @@ pub struct Groups {
     )]
     pub is_archived: String,
     /**
-     * JSON template for Group resource
+     * The type of the resource. It is always groupsSettings#groups.
      */
     #[serde(
         default,
@@ -172,7 +198,7 @@ pub struct Groups {
     )]
     pub kind: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. The maximum size of a message is 25Mb.
      */
     #[serde(
         default,
@@ -182,7 +208,9 @@ pub struct Groups {
     )]
     pub max_message_bytes: i64,
     /**
-     * JSON template for Group resource
+     * Enables members to post messages as the group. Possible values are:  
+     *  - true: Group member can post messages using the group's email address instead of their own email address. Message appear to originate from the group itself. Note: When true, any message moderation settings on individual users or new members do not apply to posts made on behalf of the group.
+     *  - false: Members can not post in behalf of the group's email address.
      */
     #[serde(
         default,
@@ -192,7 +220,7 @@ pub struct Groups {
     )]
     pub members_can_post_as_the_group: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. The default message display font always has a value of "DEFAULT_FONT".
      */
     #[serde(
         default,
@@ -202,7 +230,12 @@ pub struct Groups {
     )]
     pub message_display_font: String,
     /**
-     * JSON template for Group resource
+     * Moderation level of incoming messages. Possible values are:  
+     *  - MODERATE_ALL_MESSAGES: All messages are sent to the group owner's email address for approval. If approved, the message is sent to the group.
+     *  - MODERATE_NON_MEMBERS: All messages from non group members are sent to the group owner's email address for approval. If approved, the message is sent to the group.
+     *  - MODERATE_NEW_MEMBERS: All messages from new members are sent to the group owner's email address for approval. If approved, the message is sent to the group.
+     *  - MODERATE_NONE: No moderator approval is required. Messages are delivered directly to the group. Note: When the whoCanPostMessage is set to ANYONE_CAN_POST, we recommend the messageModerationLevel be set to MODERATE_NON_MEMBERS to protect the group from possible spam.
+     *  When memberCanPostAsTheGroup is true, any message moderation settings on individual users or new members will not apply to posts made on behalf of the group.
      */
     #[serde(
         default,
@@ -212,7 +245,7 @@ pub struct Groups {
     )]
     pub message_moderation_level: String,
     /**
-     * JSON template for Group resource
+     * Name of the group, which has a maximum size of 75 characters.
      */
     #[serde(
         default,
@@ -221,7 +254,7 @@ pub struct Groups {
     )]
     pub name: String,
     /**
-     * JSON template for Group resource
+     * The primary language for group. For a group's primary language use the language tags from the G Suite languages found at G Suite Email Settings API Email Language Tags.
      */
     #[serde(
         default,
@@ -231,7 +264,15 @@ pub struct Groups {
     )]
     pub primary_language: String,
     /**
-     * JSON template for Group resource
+     * Specifies who receives the default reply. Possible values are:  
+     *  - REPLY_TO_CUSTOM: For replies to messages, use the group's custom email address.
+     *  When the group's ReplyTo property is set to REPLY_TO_CUSTOM, the customReplyTo property holds the custom email address used when replying to a message. If the group's ReplyTo property is set to REPLY_TO_CUSTOM, the customReplyTo property must have a value. Otherwise an error is returned.
+     *   
+     *  - REPLY_TO_SENDER: The reply sent to author of message.
+     *  - REPLY_TO_LIST: This reply message is sent to the group.
+     *  - REPLY_TO_OWNER: The reply is sent to the owner(s) of the group. This does not include the group's managers.
+     *  - REPLY_TO_IGNORE: Group users individually decide where the message reply is sent.
+     *  - REPLY_TO_MANAGERS: This reply message is sent to the group's managers, which includes all managers and the group owner.
      */
     #[serde(
         default,
@@ -241,7 +282,11 @@ pub struct Groups {
     )]
     pub reply_to: String,
     /**
-     * JSON template for Group resource
+     * Allows a member to be notified if the member's message to the group is denied by the group owner. Possible values are:  
+     *  - true: When a message is rejected, send the deny message notification to the message author.
+     *  The defaultMessageDenyNotificationText property is dependent on the sendMessageDenyNotification property being true.
+     *   
+     *  - false: When a message is rejected, no notification is sent.
      */
     #[serde(
         default,
@@ -251,7 +296,9 @@ pub struct Groups {
     )]
     pub send_message_deny_notification: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanDiscoverGroup setting. Allows the group to be visible in the Groups Directory. Possible values are:  
+     *  - true: All groups in the account are listed in the Groups directory.
+     *  - false: All groups in the account are not listed in the directory.
      */
     #[serde(
         default,
@@ -261,7 +308,11 @@ pub struct Groups {
     )]
     pub show_in_group_directory: String,
     /**
-     * JSON template for Group resource
+     * Specifies moderation levels for messages detected as spam. Possible values are:  
+     *  - ALLOW: Post the message to the group.
+     *  - MODERATE: Send the message to the moderation queue. This is the default.
+     *  - SILENTLY_MODERATE: Send the message to the moderation queue, but do not send notification to moderators.
+     *  - REJECT: Immediately reject the message.
      */
     #[serde(
         default,
@@ -271,7 +322,11 @@ pub struct Groups {
     )]
     pub spam_moderation_level: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateMembers setting. Permissions to add members. Possible values are:  
+     *  - ALL_MEMBERS_CAN_ADD: Managers and members can directly add new members.
+     *  - ALL_MANAGERS_CAN_ADD: Only managers can directly add new members. this includes the group's owner.
+     *  - ALL_OWNERS_CAN_ADD: Only owners can directly add new members.
+     *  - NONE_CAN_ADD: No one can directly add new members.
      */
     #[serde(
         default,
@@ -281,7 +336,7 @@ pub struct Groups {
     )]
     pub who_can_add: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This functionality is no longer supported in the Google Groups UI. The value is always "NONE".
      */
     #[serde(
         default,
@@ -291,7 +346,11 @@ pub struct Groups {
     )]
     pub who_can_add_references: String,
     /**
-     * JSON template for Group resource
+     * Specifies who can approve members who ask to join groups. This permission will be deprecated once it is merged into the new whoCanModerateMembers setting. Possible values are:  
+     *  - ALL_MEMBERS_CAN_APPROVE
+     *  - ALL_MANAGERS_CAN_APPROVE
+     *  - ALL_OWNERS_CAN_APPROVE
+     *  - NONE_CAN_APPROVE
      */
     #[serde(
         default,
@@ -301,7 +360,11 @@ pub struct Groups {
     )]
     pub who_can_approve_members: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can approve pending messages in the moderation queue. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -311,7 +374,12 @@ pub struct Groups {
     )]
     pub who_can_approve_messages: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to assign topics in a forum to another user. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -321,7 +389,12 @@ pub struct Groups {
     )]
     pub who_can_assign_topics: String,
     /**
-     * JSON template for Group resource
+     * Specifies who can moderate metadata. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -331,7 +404,11 @@ pub struct Groups {
     )]
     pub who_can_assist_content: String,
     /**
-     * JSON template for Group resource
+     * Specifies who can deny membership to users. This permission will be deprecated once it is merged into the new whoCanModerateMembers setting. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -341,7 +418,12 @@ pub struct Groups {
     )]
     pub who_can_ban_users: String,
     /**
-     * JSON template for Group resource
+     * Permission to contact owner of the group via web UI. Possible values are:  
+     *  - ALL_IN_DOMAIN_CAN_CONTACT
+     *  - ALL_MANAGERS_CAN_CONTACT
+     *  - ALL_MEMBERS_CAN_CONTACT
+     *  - ANYONE_CAN_CONTACT
+     *  - ALL_OWNERS_CAN_CONTACT
      */
     #[serde(
         default,
@@ -351,7 +433,11 @@ pub struct Groups {
     )]
     pub who_can_contact_owner: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can delete replies to topics. (Authors can always delete their own posts). Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -361,7 +447,11 @@ pub struct Groups {
     )]
     pub who_can_delete_any_post: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can delete topics. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -371,7 +461,10 @@ pub struct Groups {
     )]
     pub who_can_delete_topics: String,
     /**
-     * JSON template for Group resource
+     * Specifies the set of users for whom this group is discoverable. Possible values are:  
+     *  - ANYONE_CAN_DISCOVER
+     *  - ALL_IN_DOMAIN_CAN_DISCOVER
+     *  - ALL_MEMBERS_CAN_DISCOVER
      */
     #[serde(
         default,
@@ -381,7 +474,12 @@ pub struct Groups {
     )]
     pub who_can_discover_group: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to enter free form tags for topics in a forum. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -391,7 +489,11 @@ pub struct Groups {
     )]
     pub who_can_enter_free_form_tags: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can hide posts by reporting them as abuse. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -401,7 +503,11 @@ pub struct Groups {
     )]
     pub who_can_hide_abuse: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateMembers setting. Permissions to invite new members. Possible values are:  
+     *  - ALL_MEMBERS_CAN_INVITE: Managers and members can invite a new member candidate.
+     *  - ALL_MANAGERS_CAN_INVITE: Only managers can invite a new member. This includes the group's owner.
+     *  - ALL_OWNERS_CAN_INVITE: Only owners can invite a new member.
+     *  - NONE_CAN_INVITE: No one can invite a new member candidate.
      */
     #[serde(
         default,
@@ -411,7 +517,11 @@ pub struct Groups {
     )]
     pub who_can_invite: String,
     /**
-     * JSON template for Group resource
+     * Permission to join group. Possible values are:  
+     *  - ANYONE_CAN_JOIN: Anyone in the account domain can join. This includes accounts with multiple domains.
+     *  - ALL_IN_DOMAIN_CAN_JOIN: Any Internet user who is outside your domain can access your Google Groups service and view the list of groups in your Groups directory. Warning: Group owners can add external addresses, outside of the domain to their groups. They can also allow people outside your domain to join their groups. If you later disable this option, any external addresses already added to users' groups remain in those groups.
+     *  - INVITED_CAN_JOIN: Candidates for membership can be invited to join.  
+     *  - CAN_REQUEST_TO_JOIN: Non members can request an invitation to join.
      */
     #[serde(
         default,
@@ -421,7 +531,10 @@ pub struct Groups {
     )]
     pub who_can_join: String,
     /**
-     * JSON template for Group resource
+     * Permission to leave the group. Possible values are:  
+     *  - ALL_MANAGERS_CAN_LEAVE
+     *  - ALL_MEMBERS_CAN_LEAVE
+     *  - NONE_CAN_LEAVE
      */
     #[serde(
         default,
@@ -431,7 +544,11 @@ pub struct Groups {
     )]
     pub who_can_leave_group: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can prevent users from posting replies to topics. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -441,7 +558,11 @@ pub struct Groups {
     )]
     pub who_can_lock_topics: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can make topics appear at the top of the topic list. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -451,7 +572,12 @@ pub struct Groups {
     )]
     pub who_can_make_topics_sticky: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to mark a topic as a duplicate of another topic. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -461,7 +587,12 @@ pub struct Groups {
     )]
     pub who_can_mark_duplicate: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to mark any other user's post as a favorite reply. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -471,7 +602,12 @@ pub struct Groups {
     )]
     pub who_can_mark_favorite_reply_on_any_topic: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to mark a post for a topic they started as a favorite reply. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -481,7 +617,12 @@ pub struct Groups {
     )]
     pub who_can_mark_favorite_reply_on_own_topic: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to mark a topic as not needing a response. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -491,7 +632,11 @@ pub struct Groups {
     )]
     pub who_can_mark_no_response_needed: String,
     /**
-     * JSON template for Group resource
+     * Specifies who can moderate content. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -501,7 +646,11 @@ pub struct Groups {
     )]
     pub who_can_moderate_content: String,
     /**
-     * JSON template for Group resource
+     * Specifies who can manage members. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -511,7 +660,11 @@ pub struct Groups {
     )]
     pub who_can_moderate_members: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateMembers setting. Specifies who can change group members' roles. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -521,7 +674,12 @@ pub struct Groups {
     )]
     pub who_can_modify_members: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to change tags and categories. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -531,7 +689,11 @@ pub struct Groups {
     )]
     pub who_can_modify_tags_and_categories: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can move topics into the group or forum. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -541,7 +703,11 @@ pub struct Groups {
     )]
     pub who_can_move_topics_in: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can move topics out of the group or forum. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -551,7 +717,11 @@ pub struct Groups {
     )]
     pub who_can_move_topics_out: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanModerateContent setting. Specifies who can post announcements, a special topic type. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -561,7 +731,15 @@ pub struct Groups {
     )]
     pub who_can_post_announcements: String,
     /**
-     * JSON template for Group resource
+     * Permissions to post messages. Possible values are:  
+     *  - NONE_CAN_POST: The group is disabled and archived. No one can post a message to this group.  
+     *  - When archiveOnly is false, updating whoCanPostMessage to NONE_CAN_POST, results in an error.
+     *  - If archiveOnly is reverted from true to false, whoCanPostMessages is set to ALL_MANAGERS_CAN_POST.  
+     *  - ALL_MANAGERS_CAN_POST: Managers, including group owners, can post messages.
+     *  - ALL_MEMBERS_CAN_POST: Any group member can post a message.
+     *  - ALL_OWNERS_CAN_POST: Only group owners can post a message.
+     *  - ALL_IN_DOMAIN_CAN_POST: Anyone in the account can post a message.  
+     *  - ANYONE_CAN_POST: Any Internet user who outside your account can access your Google Groups service and post a message. Note: When whoCanPostMessage is set to ANYONE_CAN_POST, we recommend the messageModerationLevel be set to MODERATE_NON_MEMBERS to protect the group from possible spam.
      */
     #[serde(
         default,
@@ -571,7 +749,12 @@ pub struct Groups {
     )]
     pub who_can_post_message: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to take topics in a forum. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -581,7 +764,12 @@ pub struct Groups {
     )]
     pub who_can_take_topics: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to unassign any topic in a forum. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -591,7 +779,12 @@ pub struct Groups {
     )]
     pub who_can_unassign_topic: String,
     /**
-     * JSON template for Group resource
+     * Deprecated. This is merged into the new whoCanAssistContent setting. Permission to unmark any post from a favorite reply. Possible values are:  
+     *  - ALL_MEMBERS
+     *  - OWNERS_AND_MANAGERS
+     *  - MANAGERS_ONLY
+     *  - OWNERS_ONLY
+     *  - NONE
      */
     #[serde(
         default,
@@ -601,7 +794,11 @@ pub struct Groups {
     )]
     pub who_can_unmark_favorite_reply_on_any_topic: String,
     /**
-     * JSON template for Group resource
+     * Permissions to view group messages. Possible values are:  
+     *  - ANYONE_CAN_VIEW: Any Internet user can view the group's messages.  
+     *  - ALL_IN_DOMAIN_CAN_VIEW: Anyone in your account can view this group's messages.
+     *  - ALL_MEMBERS_CAN_VIEW: All group members can view the group's messages.
+     *  - ALL_MANAGERS_CAN_VIEW: Any group manager can view this group's messages.
      */
     #[serde(
         default,
@@ -611,7 +808,12 @@ pub struct Groups {
     )]
     pub who_can_view_group: String,
     /**
-     * JSON template for Group resource
+     * Permissions to view membership. Possible values are:  
+     *  - ALL_IN_DOMAIN_CAN_VIEW: Anyone in the account can view the group members list.
+     *  If a group already has external members, those members can still send email to this group.
+     *   
+     *  - ALL_MEMBERS_CAN_VIEW: The group members can view the group members list.
+     *  - ALL_MANAGERS_CAN_VIEW: The group managers can view group members list.
      */
     #[serde(
         default,
@@ -626,6 +828,7 @@ pub struct Groups {
 * Data format for the response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Alt {
     #[serde(rename = "atom")]
     Atom,
@@ -633,8 +836,9 @@ pub enum Alt {
     Json,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -643,12 +847,32 @@ impl std::fmt::Display for Alt {
             Alt::Atom => "atom",
             Alt::Json => "json",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Alt {
+        match s.as_str() {
+            "atom" => Alt::Atom,
+            "json" => Alt::Json,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(e: Alt) -> String {
+        match e {
+            Alt::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
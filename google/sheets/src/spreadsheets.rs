@@ -18,11 +18,31 @@ impl Spreadsheets {
      */
     pub async fn create(
         &self,
+        fields: &str,
         body: &crate::types::Spreadsheet,
     ) -> ClientResult<crate::types::Spreadsheet> {
-        let url = self.client.url("/v4/spreadsheets", None);
+        Ok(self.create_with_response(fields, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        fields: &str,
+        body: &crate::types::Spreadsheet,
+    ) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v4/spreadsheets?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -44,11 +64,32 @@ impl Spreadsheets {
      */
     pub async fn get(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         include_grid_data: bool,
         ranges: &[String],
     ) -> ClientResult<crate::types::Spreadsheet> {
+        Ok(self
+            .get_with_response(fields, spreadsheet_id, include_grid_data, ranges)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        include_grid_data: bool,
+        ranges: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_grid_data {
             query_args.push(("includeGridData".to_string(), include_grid_data.to_string()));
         }
@@ -65,7 +106,7 @@ impl Spreadsheets {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -86,19 +127,42 @@ impl Spreadsheets {
      */
     pub async fn developer_metadata_get(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         metadata_id: i64,
     ) -> ClientResult<crate::types::DeveloperMetadata> {
+        Ok(self
+            .developer_metadata_get_with_response(fields, spreadsheet_id, metadata_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}/developerMetadata/{metadataId}` endpoint.
+     *
+     * As opposed to `developer_metadata_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn developer_metadata_get_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        metadata_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::DeveloperMetadata>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/developerMetadata/{}",
+                "/v4/spreadsheets/{}/developerMetadata/{}?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
                 crate::progenitor_support::encode_path(&metadata_id.to_string()),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -118,18 +182,41 @@ impl Spreadsheets {
      */
     pub async fn developer_metadata_search(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::SearchDeveloperMetadataRequest,
     ) -> ClientResult<crate::types::SearchDeveloperMetadataResponse> {
+        Ok(self
+            .developer_metadata_search_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/developerMetadata:search` endpoint.
+     *
+     * As opposed to `developer_metadata_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn developer_metadata_search_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::SearchDeveloperMetadataRequest,
+    ) -> ClientResult<crate::Response<crate::types::SearchDeveloperMetadataResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/developerMetadata:search",
+                "/v4/spreadsheets/{}/developerMetadata:search?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -150,20 +237,44 @@ impl Spreadsheets {
      */
     pub async fn sheets_copy(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         sheet_id: i64,
         body: &crate::types::CopySheetAnotherSpreadsheetRequest,
     ) -> ClientResult<crate::types::SheetProperties> {
+        Ok(self
+            .sheets_copy_with_response(fields, spreadsheet_id, sheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/sheets/{sheetId}:copyTo` endpoint.
+     *
+     * As opposed to `sheets_copy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn sheets_copy_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        sheet_id: i64,
+        body: &crate::types::CopySheetAnotherSpreadsheetRequest,
+    ) -> ClientResult<crate::Response<crate::types::SheetProperties>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/sheets/{}/copyTo",
+                "/v4/spreadsheets/{}/sheets/{}:copyTo?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
                 crate::progenitor_support::encode_path(&sheet_id.to_string()),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -187,12 +298,39 @@ impl Spreadsheets {
      */
     pub async fn values_get(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         range: &str,
         date_time_render_option: crate::types::DateTimeRenderOption,
         major_dimension: crate::types::Dimension,
         value_render_option: crate::types::ValueRenderOption,
     ) -> ClientResult<crate::types::ValueRange> {
+        Ok(self
+            .values_get_with_response(
+                fields,
+                spreadsheet_id,
+                range,
+                date_time_render_option,
+                major_dimension,
+                value_render_option,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}` endpoint.
+     *
+     * As opposed to `values_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_get_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        range: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> ClientResult<crate::Response<crate::types::ValueRange>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date_time_render_option.to_string().is_empty() {
             query_args.push((
@@ -200,6 +338,9 @@ impl Spreadsheets {
                 date_time_render_option.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !major_dimension.to_string().is_empty() {
             query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
         }
@@ -220,7 +361,7 @@ impl Spreadsheets {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -245,6 +386,7 @@ impl Spreadsheets {
      */
     pub async fn values_update(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         range: &str,
         include_values_in_response: bool,
@@ -253,7 +395,40 @@ impl Spreadsheets {
         value_input_option: crate::types::ValueInputOption,
         body: &crate::types::ValueRange,
     ) -> ClientResult<crate::types::UpdateValuesResponse> {
+        Ok(self
+            .values_update_with_response(
+                fields,
+                spreadsheet_id,
+                range,
+                include_values_in_response,
+                response_date_time_render_option,
+                response_value_render_option,
+                value_input_option,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}` endpoint.
+     *
+     * As opposed to `values_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_update_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> ClientResult<crate::Response<crate::types::UpdateValuesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_values_in_response {
             query_args.push((
                 "includeValuesInResponse".to_string(),
@@ -289,7 +464,7 @@ impl Spreadsheets {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -315,6 +490,7 @@ impl Spreadsheets {
      */
     pub async fn values_append(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         range: &str,
         include_values_in_response: bool,
@@ -324,7 +500,42 @@ impl Spreadsheets {
         value_input_option: crate::types::ValueInputOption,
         body: &crate::types::ValueRange,
     ) -> ClientResult<crate::types::AppendValuesResponse> {
+        Ok(self
+            .values_append_with_response(
+                fields,
+                spreadsheet_id,
+                range,
+                include_values_in_response,
+                insert_data_option,
+                response_date_time_render_option,
+                response_value_render_option,
+                value_input_option,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}:append` endpoint.
+     *
+     * As opposed to `values_append`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_append_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        insert_data_option: crate::types::InsertDataOption,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> ClientResult<crate::Response<crate::types::AppendValuesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if include_values_in_response {
             query_args.push((
                 "includeValuesInResponse".to_string(),
@@ -358,7 +569,7 @@ impl Spreadsheets {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/values/{}/append?{}",
+                "/v4/spreadsheets/{}/values/{}:append?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
                 crate::progenitor_support::encode_path(range),
                 query_
@@ -366,7 +577,7 @@ impl Spreadsheets {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -387,20 +598,44 @@ impl Spreadsheets {
      */
     pub async fn values_clear(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         range: &str,
         body: &crate::types::ClearValuesRequest,
     ) -> ClientResult<crate::types::ClearValuesResponse> {
+        Ok(self
+            .values_clear_with_response(fields, spreadsheet_id, range, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}:clear` endpoint.
+     *
+     * As opposed to `values_clear`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_clear_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        range: &str,
+        body: &crate::types::ClearValuesRequest,
+    ) -> ClientResult<crate::Response<crate::types::ClearValuesResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/values/{}/clear",
+                "/v4/spreadsheets/{}/values/{}:clear?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
                 crate::progenitor_support::encode_path(range),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -420,18 +655,41 @@ impl Spreadsheets {
      */
     pub async fn values_batch_clear(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::BatchClearValuesRequest,
     ) -> ClientResult<crate::types::BatchClearValuesResponse> {
+        Ok(self
+            .values_batch_clear_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values:batchClear` endpoint.
+     *
+     * As opposed to `values_batch_clear`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_batch_clear_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchClearValuesResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/values:batchClear",
+                "/v4/spreadsheets/{}/values:batchClear?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -451,18 +709,41 @@ impl Spreadsheets {
      */
     pub async fn values_batch_clear_data_filter(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::BatchClearValuesByDataFilterRequest,
     ) -> ClientResult<crate::types::BatchClearValuesByDataFilterResponse> {
+        Ok(self
+            .values_batch_clear_data_filter_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values:batchClearByDataFilter` endpoint.
+     *
+     * As opposed to `values_batch_clear_data_filter`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_batch_clear_data_filter_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchClearValuesByDataFilterResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/values:batchClearByDataFilter",
+                "/v4/spreadsheets/{}/values:batchClearByDataFilter?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -486,12 +767,39 @@ impl Spreadsheets {
      */
     pub async fn values_batch_get(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         date_time_render_option: crate::types::DateTimeRenderOption,
         major_dimension: crate::types::Dimension,
         ranges: &[String],
         value_render_option: crate::types::ValueRenderOption,
     ) -> ClientResult<crate::types::BatchGetValuesResponse> {
+        Ok(self
+            .values_batch_get_with_response(
+                fields,
+                spreadsheet_id,
+                date_time_render_option,
+                major_dimension,
+                ranges,
+                value_render_option,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}/values:batchGet` endpoint.
+     *
+     * As opposed to `values_batch_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_batch_get_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        ranges: &[String],
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> ClientResult<crate::Response<crate::types::BatchGetValuesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date_time_render_option.to_string().is_empty() {
             query_args.push((
@@ -499,6 +807,9 @@ impl Spreadsheets {
                 date_time_render_option.to_string(),
             ));
         }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
         if !major_dimension.to_string().is_empty() {
             query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
         }
@@ -521,7 +832,7 @@ impl Spreadsheets {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -541,18 +852,41 @@ impl Spreadsheets {
      */
     pub async fn values_batch_get_data_filter(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::BatchGetValuesByDataFilterRequest,
     ) -> ClientResult<crate::types::BatchGetValuesByDataFilterResponse> {
+        Ok(self
+            .values_batch_get_data_filter_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values:batchGetByDataFilter` endpoint.
+     *
+     * As opposed to `values_batch_get_data_filter`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_batch_get_data_filter_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchGetValuesByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchGetValuesByDataFilterResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/values:batchGetByDataFilter",
+                "/v4/spreadsheets/{}/values:batchGetByDataFilter?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -572,18 +906,41 @@ impl Spreadsheets {
      */
     pub async fn values_batch_update(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::BatchUpdateValuesRequest,
     ) -> ClientResult<crate::types::BatchUpdateValuesResponse> {
+        Ok(self
+            .values_batch_update_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values:batchUpdate` endpoint.
+     *
+     * As opposed to `values_batch_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_batch_update_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateValuesResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/values:batchUpdate",
+                "/v4/spreadsheets/{}/values:batchUpdate?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -603,18 +960,41 @@ impl Spreadsheets {
      */
     pub async fn values_batch_update_data_filter(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::BatchUpdateValuesByDataFilterRequest,
     ) -> ClientResult<crate::types::BatchUpdateValuesByDataFilterResponse> {
+        Ok(self
+            .values_batch_update_data_filter_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values:batchUpdateByDataFilter` endpoint.
+     *
+     * As opposed to `values_batch_update_data_filter`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn values_batch_update_data_filter_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateValuesByDataFilterResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/values:batchUpdateByDataFilter",
+                "/v4/spreadsheets/{}/values:batchUpdateByDataFilter?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -634,18 +1014,41 @@ impl Spreadsheets {
      */
     pub async fn batch_update(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::BatchUpdateSpreadsheetRequest,
     ) -> ClientResult<crate::types::BatchUpdateSpreadsheetResponse> {
+        Ok(self
+            .batch_update_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}:batchUpdate` endpoint.
+     *
+     * As opposed to `batch_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn batch_update_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateSpreadsheetRequest,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateSpreadsheetResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/batchUpdate",
+                "/v4/spreadsheets/{}:batchUpdate?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -665,18 +1068,41 @@ impl Spreadsheets {
      */
     pub async fn get_data_filter(
         &self,
+        fields: &str,
         spreadsheet_id: &str,
         body: &crate::types::GetSpreadsheetByDataFilterRequest,
     ) -> ClientResult<crate::types::Spreadsheet> {
+        Ok(self
+            .get_data_filter_with_response(fields, spreadsheet_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}:getByDataFilter` endpoint.
+     *
+     * As opposed to `get_data_filter`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_data_filter_with_response(
+        &self,
+        fields: &str,
+        spreadsheet_id: &str,
+        body: &crate::types::GetSpreadsheetByDataFilterRequest,
+    ) -> ClientResult<crate::Response<crate::types::Spreadsheet>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v4/spreadsheets/{}/getByDataFilter",
+                "/v4/spreadsheets/{}:getByDataFilter?{}",
                 crate::progenitor_support::encode_path(spreadsheet_id),
+                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
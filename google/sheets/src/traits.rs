@@ -16,6 +16,7 @@ impl SpreadsheetOps for crate::spreadsheets::Spreadsheets {
     async fn cell_get(&self, sheet_id: &str, cell_name: &str) -> ClientResult<String> {
         let values = self
             .values_get(
+                "", // fields
                 sheet_id,
                 cell_name,
                 crate::types::DateTimeRenderOption::FormattedString,
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBandingRequest {
     /**
-     * Adds a new banded range to the spreadsheet.
+     * A banded (alternating colors) range in a sheet.
      */
     #[serde(
         default,
@@ -20,7 +20,7 @@ pub struct AddBandingRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBandingResponse {
     /**
-     * The result of adding a banded range.
+     * A banded (alternating colors) range in a sheet.
      */
     #[serde(
         default,
@@ -34,7 +34,7 @@ pub struct AddBandingResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddChartRequest {
     /**
-     * Adds a chart to a sheet in the spreadsheet.
+     * A chart embedded in a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chart: Option<EmbeddedChart>,
@@ -44,7 +44,7 @@ pub struct AddChartRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddChartResponse {
     /**
-     * The result of adding a chart to a spreadsheet.
+     * A chart embedded in a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chart: Option<EmbeddedChart>,
@@ -54,7 +54,7 @@ pub struct AddChartResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddConditionalFormatRuleRequest {
     /**
-     * Adds a new conditional format rule at the given index. All subsequent rules' indexes are incremented.
+     * The zero-based index where the rule should be inserted.
      */
     #[serde(
         default,
@@ -63,7 +63,7 @@ pub struct AddConditionalFormatRuleRequest {
     )]
     pub index: i64,
     /**
-     * Adds a new conditional format rule at the given index. All subsequent rules' indexes are incremented.
+     * A rule describing a conditional format.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rule: Option<ConditionalFormatRule>,
@@ -73,7 +73,7 @@ pub struct AddConditionalFormatRuleRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDataSourceRequest {
     /**
-     * Adds a data source. After the data source is added successfully, an associated DATA_SOURCE sheet is created and an execution is triggered to refresh the sheet to read data from the data source. The request requires an additional `bigquery.readonly` OAuth scope.
+     * Information about an external data source in the spreadsheet.
      */
     #[serde(
         default,
@@ -87,7 +87,7 @@ pub struct AddDataSourceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDataSourceResponse {
     /**
-     * The result of adding a data source.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -96,7 +96,7 @@ pub struct AddDataSourceResponse {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * The result of adding a data source.
+     * Information about an external data source in the spreadsheet.
      */
     #[serde(
         default,
@@ -110,7 +110,7 @@ pub struct AddDataSourceResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDimensionGroupRequest {
     /**
-     * Creates a group over the specified range. If the requested range is a superset of the range of an existing group G, then the depth of G is incremented and this new group G' has the depth of that group. For example, a group [C:D, depth 1] + [B:E] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range is a subset of the range of an existing group G, then the depth of the new group G' becomes one greater than the depth of G. For example, a group [B:E, depth 1] + [C:D] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range starts before and ends within, or starts within and ends after, the range of an existing group G, then the range of the existing group G becomes the union of the ranges, and the new group G' has depth one greater than the depth of G and range as the intersection of the ranges. For example, a group [B:D, depth 1] + [C:E] results in groups [B:E, depth 1] and [C:D, depth 2].
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
@@ -120,7 +120,7 @@ pub struct AddDimensionGroupRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddDimensionGroupResponse {
     /**
-     * The result of adding a group.
+     * All groups of a dimension after adding a group to that dimension.
      */
     #[serde(
         default,
@@ -135,7 +135,7 @@ pub struct AddDimensionGroupResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddFilterViewRequest {
     /**
-     * Adds a filter view.
+     * A filter view.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
@@ -145,7 +145,7 @@ pub struct AddFilterViewRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddFilterViewResponse {
     /**
-     * The result of adding a filter view.
+     * A filter view.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
@@ -155,7 +155,7 @@ pub struct AddFilterViewResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddNamedRangeRequest {
     /**
-     * Adds a named range to the spreadsheet.
+     * A named range.
      */
     #[serde(
         default,
@@ -169,7 +169,7 @@ pub struct AddNamedRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddNamedRangeResponse {
     /**
-     * The result of adding a named range.
+     * A named range.
      */
     #[serde(
         default,
@@ -183,7 +183,7 @@ pub struct AddNamedRangeResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddProtectedRangeRequest {
     /**
-     * Adds a new protected range.
+     * A protected range.
      */
     #[serde(
         default,
@@ -197,7 +197,7 @@ pub struct AddProtectedRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddProtectedRangeResponse {
     /**
-     * The result of adding a new protected range.
+     * A protected range.
      */
     #[serde(
         default,
@@ -211,7 +211,7 @@ pub struct AddProtectedRangeResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSheetRequest {
     /**
-     * Adds a new sheet. When a sheet is added at a given index, all subsequent sheets' indexes are incremented. To add an object sheet, use AddChartRequest instead and specify EmbeddedObjectPosition.sheetId or EmbeddedObjectPosition.newSheet.
+     * Properties of a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
@@ -221,7 +221,7 @@ pub struct AddSheetRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSheetResponse {
     /**
-     * The result of adding a sheet.
+     * Properties of a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
@@ -231,7 +231,7 @@ pub struct AddSheetResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSlicerRequest {
     /**
-     * Adds a slicer to a sheet in the spreadsheet.
+     * A slicer in a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub slicer: Option<Slicer>,
@@ -241,7 +241,7 @@ pub struct AddSlicerRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddSlicerResponse {
     /**
-     * The result of adding a slicer to a spreadsheet.
+     * A slicer in a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub slicer: Option<Slicer>,
@@ -251,7 +251,7 @@ pub struct AddSlicerResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppendCellsRequest {
     /**
-     * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
+     * The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -260,7 +260,7 @@ pub struct AppendCellsRequest {
     )]
     pub fields: String,
     /**
-     * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
+     * The data to append.
      */
     #[serde(
         default,
@@ -269,7 +269,7 @@ pub struct AppendCellsRequest {
     )]
     pub rows: Vec<RowData>,
     /**
-     * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
+     * The sheet ID to append the data to.
      */
     #[serde(
         default,
@@ -284,6 +284,7 @@ pub struct AppendCellsRequest {
 * Whether rows or columns should be appended.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Dimension {
     #[serde(rename = "COLUMNS")]
     Columns,
@@ -293,8 +294,9 @@ pub enum Dimension {
     Rows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Dimension {
@@ -304,12 +306,33 @@ impl std::fmt::Display for Dimension {
             Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
             Dimension::Rows => "ROWS",
             Dimension::Noop => "",
-            Dimension::FallthroughString => "*",
+            Dimension::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Dimension {
+    fn from(s: String) -> Dimension {
+        match s.as_str() {
+            "COLUMNS" => Dimension::Columns,
+            "DIMENSION_UNSPECIFIED" => Dimension::DimensionUnspecified,
+            "ROWS" => Dimension::Rows,
+            "" => Dimension::Noop,
+            _ => Dimension::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Dimension> for String {
+    fn from(e: Dimension) -> String {
+        match e {
+            Dimension::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Dimension {
     fn default() -> Dimension {
         Dimension::Noop
@@ -325,12 +348,12 @@ impl Dimension {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppendDimensionRequest {
     /**
-     * Appends rows or columns to the end of a sheet.
+     * Whether rows or columns should be appended.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimension: Option<Dimension>,
     /**
-     * Appends rows or columns to the end of a sheet.
+     * The number of rows or columns to append.
      */
     #[serde(
         default,
@@ -339,7 +362,7 @@ pub struct AppendDimensionRequest {
     )]
     pub length: i64,
     /**
-     * Appends rows or columns to the end of a sheet.
+     * The sheet to append rows or columns to.
      */
     #[serde(
         default,
@@ -354,7 +377,7 @@ pub struct AppendDimensionRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AppendValuesResponse {
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -364,7 +387,7 @@ pub struct AppendValuesResponse {
     )]
     pub spreadsheet_id: String,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The range (in A1 notation) of the table that values are being appended to (before the values were appended). Empty if no table was found.
      */
     #[serde(
         default,
@@ -384,12 +407,12 @@ pub struct AppendValuesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutoFillRequest {
     /**
-     * Fills in more data based on existing data.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Fills in more data based on existing data.
+     * A combination of a source range and how to extend that source.
      */
     #[serde(
         default,
@@ -398,7 +421,7 @@ pub struct AutoFillRequest {
     )]
     pub source_and_destination: Option<SourceDestination>,
     /**
-     * Fills in more data based on existing data.
+     * True if we should generate data with the "alternate" series. This differs based on the type and amount of source data.
      */
     #[serde(
         default,
@@ -412,7 +435,7 @@ pub struct AutoFillRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutoResizeDimensionsRequest {
     /**
-     * Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
+     * A range along a single dimension on a DATA_SOURCE sheet.
      */
     #[serde(
         default,
@@ -421,7 +444,7 @@ pub struct AutoResizeDimensionsRequest {
     )]
     pub data_source_sheet_dimensions: Option<DataSourceSheetDimensionRange>,
     /**
-     * Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<DimensionRange>,
@@ -431,7 +454,7 @@ pub struct AutoResizeDimensionsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BandedRange {
     /**
-     * A banded (alternating colors) range in a sheet.
+     * The id of the banded range.
      */
     #[serde(
         default,
@@ -441,7 +464,7 @@ pub struct BandedRange {
     )]
     pub banded_range_id: i64,
     /**
-     * A banded (alternating colors) range in a sheet.
+     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
     #[serde(
         default,
@@ -450,12 +473,12 @@ pub struct BandedRange {
     )]
     pub column_properties: Option<BandingProperties>,
     /**
-     * A banded (alternating colors) range in a sheet.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * A banded (alternating colors) range in a sheet.
+     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
     #[serde(
         default,
@@ -469,7 +492,7 @@ pub struct BandedRange {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BandingProperties {
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -478,7 +501,7 @@ pub struct BandingProperties {
     )]
     pub first_band_color: Option<Color>,
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * A color value.
      */
     #[serde(
         default,
@@ -487,7 +510,7 @@ pub struct BandingProperties {
     )]
     pub first_band_color_style: Option<ColorStyle>,
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -496,7 +519,7 @@ pub struct BandingProperties {
     )]
     pub footer_color: Option<Color>,
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * A color value.
      */
     #[serde(
         default,
@@ -505,7 +528,7 @@ pub struct BandingProperties {
     )]
     pub footer_color_style: Option<ColorStyle>,
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -514,7 +537,7 @@ pub struct BandingProperties {
     )]
     pub header_color: Option<Color>,
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * A color value.
      */
     #[serde(
         default,
@@ -523,7 +546,7 @@ pub struct BandingProperties {
     )]
     pub header_color_style: Option<ColorStyle>,
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -532,7 +555,7 @@ pub struct BandingProperties {
     )]
     pub second_band_color: Option<Color>,
     /**
-     * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
+     * A color value.
      */
     #[serde(
         default,
@@ -546,6 +569,7 @@ pub struct BandingProperties {
 * The comparison type of key value with baseline value.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ComparisonType {
     #[serde(rename = "ABSOLUTE_DIFFERENCE")]
     AbsoluteDifference,
@@ -555,8 +579,9 @@ pub enum ComparisonType {
     PercentageDifference,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ComparisonType {
@@ -566,12 +591,33 @@ impl std::fmt::Display for ComparisonType {
             ComparisonType::ComparisonTypeUndefined => "COMPARISON_TYPE_UNDEFINED",
             ComparisonType::PercentageDifference => "PERCENTAGE_DIFFERENCE",
             ComparisonType::Noop => "",
-            ComparisonType::FallthroughString => "*",
+            ComparisonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ComparisonType {
+    fn from(s: String) -> ComparisonType {
+        match s.as_str() {
+            "ABSOLUTE_DIFFERENCE" => ComparisonType::AbsoluteDifference,
+            "COMPARISON_TYPE_UNDEFINED" => ComparisonType::ComparisonTypeUndefined,
+            "PERCENTAGE_DIFFERENCE" => ComparisonType::PercentageDifference,
+            "" => ComparisonType::Noop,
+            _ => ComparisonType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ComparisonType> for String {
+    fn from(e: ComparisonType) -> String {
+        match e {
+            ComparisonType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ComparisonType {
     fn default() -> ComparisonType {
         ComparisonType::Noop
@@ -587,7 +633,7 @@ impl ComparisonType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BaselineValueFormat {
     /**
-     * Formatting options for baseline value.
+     * The comparison type of key value with baseline value.
      */
     #[serde(
         default,
@@ -596,7 +642,7 @@ pub struct BaselineValueFormat {
     )]
     pub comparison_type: Option<ComparisonType>,
     /**
-     * Formatting options for baseline value.
+     * Description which is appended after the baseline value. This field is optional.
      */
     #[serde(
         default,
@@ -605,7 +651,7 @@ pub struct BaselineValueFormat {
     )]
     pub description: String,
     /**
-     * Formatting options for baseline value.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -614,7 +660,7 @@ pub struct BaselineValueFormat {
     )]
     pub negative_color: Option<Color>,
     /**
-     * Formatting options for baseline value.
+     * A color value.
      */
     #[serde(
         default,
@@ -623,12 +669,12 @@ pub struct BaselineValueFormat {
     )]
     pub negative_color_style: Option<ColorStyle>,
     /**
-     * Formatting options for baseline value.
+     * Position settings for text.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub position: Option<TextPosition>,
     /**
-     * Formatting options for baseline value.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -637,7 +683,7 @@ pub struct BaselineValueFormat {
     )]
     pub positive_color: Option<Color>,
     /**
-     * Formatting options for baseline value.
+     * A color value.
      */
     #[serde(
         default,
@@ -646,7 +692,7 @@ pub struct BaselineValueFormat {
     )]
     pub positive_color_style: Option<ColorStyle>,
     /**
-     * Formatting options for baseline value.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -660,6 +706,7 @@ pub struct BaselineValueFormat {
 * The position of this axis.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Position {
     #[serde(rename = "BASIC_CHART_AXIS_POSITION_UNSPECIFIED")]
     BasicChartAxisPositionUnspecified,
@@ -671,8 +718,9 @@ pub enum Position {
     RightAxis,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Position {
@@ -683,12 +731,34 @@ impl std::fmt::Display for Position {
             Position::LeftAxis => "LEFT_AXIS",
             Position::RightAxis => "RIGHT_AXIS",
             Position::Noop => "",
-            Position::FallthroughString => "*",
+            Position::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Position {
+    fn from(s: String) -> Position {
+        match s.as_str() {
+            "BASIC_CHART_AXIS_POSITION_UNSPECIFIED" => Position::BasicChartAxisPositionUnspecified,
+            "BOTTOM_AXIS" => Position::BottomAxis,
+            "LEFT_AXIS" => Position::LeftAxis,
+            "RIGHT_AXIS" => Position::RightAxis,
+            "" => Position::Noop,
+            _ => Position::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Position> for String {
+    fn from(e: Position) -> String {
+        match e {
+            Position::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Position {
     fn default() -> Position {
         Position::Noop
@@ -704,17 +774,17 @@ impl Position {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BasicChartAxis {
     /**
-     * An axis of the chart. A chart may not have more than one axis per axis position.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub format: Option<TextFormat>,
     /**
-     * An axis of the chart. A chart may not have more than one axis per axis position.
+     * The position of this axis.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
     /**
-     * An axis of the chart. A chart may not have more than one axis per axis position.
+     * The title of this axis. If set, this overrides any title inferred from headers of the data.
      */
     #[serde(
         default,
@@ -723,7 +793,7 @@ pub struct BasicChartAxis {
     )]
     pub title: String,
     /**
-     * An axis of the chart. A chart may not have more than one axis per axis position.
+     * Position settings for text.
      */
     #[serde(
         default,
@@ -732,7 +802,7 @@ pub struct BasicChartAxis {
     )]
     pub title_text_position: Option<TextPosition>,
     /**
-     * An axis of the chart. A chart may not have more than one axis per axis position.
+     * The options that define a "view window" for a chart (such as the visible values in an axis).
      */
     #[serde(
         default,
@@ -746,12 +816,12 @@ pub struct BasicChartAxis {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BasicChartDomain {
     /**
-     * The domain of a chart. For example, if charting stock prices over time, this would be the date.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<ChartData>,
     /**
-     * The domain of a chart. For example, if charting stock prices over time, this would be the date.
+     * True to reverse the order of the domain values (horizontal axis).
      */
     #[serde(
         default,
@@ -764,6 +834,7 @@ pub struct BasicChartDomain {
 * The type of this series. Valid only if the chartType is COMBO. Different types will change the way the series is visualized. Only LINE, AREA, and COLUMN are supported.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "AREA")]
     Area,
@@ -783,8 +854,9 @@ pub enum Type {
     SteppedArea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -799,12 +871,38 @@ impl std::fmt::Display for Type {
             Type::Scatter => "SCATTER",
             Type::SteppedArea => "STEPPED_AREA",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "AREA" => Type::Area,
+            "BAR" => Type::Bar,
+            "BASIC_CHART_TYPE_UNSPECIFIED" => Type::BasicChartTypeUnspecified,
+            "COLUMN" => Type::Column,
+            "COMBO" => Type::Combo,
+            "LINE" => Type::Line,
+            "SCATTER" => Type::Scatter,
+            "STEPPED_AREA" => Type::SteppedArea,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -820,12 +918,12 @@ impl Type {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BasicChartSeries {
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<Color>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * A color value.
      */
     #[serde(
         default,
@@ -834,17 +932,17 @@ pub struct BasicChartSeries {
     )]
     pub color_style: Option<ColorStyle>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "dataLabel")]
     pub data_label: Option<DataLabel>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * Properties that describe the style of a line.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "lineStyle")]
     pub line_style: Option<LineStyle>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * The style of a point on the chart.
      */
     #[serde(
         default,
@@ -853,12 +951,12 @@ pub struct BasicChartSeries {
     )]
     pub point_style: Option<PointStyle>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub series: Option<ChartData>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * Style override settings for series data points.
      */
     #[serde(
         default,
@@ -868,7 +966,7 @@ pub struct BasicChartSeries {
     )]
     pub style_overrides: Vec<BasicSeriesDataPointStyleOverride>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * The minor axis that will specify the range of values for this series. For example, if charting stocks over time, the "Volume" series may want to be pinned to the right with the prices pinned to the left, because the scale of trading volume is different than the scale of prices. It is an error to specify an axis that isn't a valid minor axis for the chart's type.
      */
     #[serde(
         default,
@@ -877,7 +975,7 @@ pub struct BasicChartSeries {
     )]
     pub target_axis: Option<Position>,
     /**
-     * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
+     * The type of this series. Valid only if the chartType is COMBO. Different types will change the way the series is visualized. Only LINE, AREA, and COLUMN are supported.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
@@ -887,6 +985,7 @@ pub struct BasicChartSeries {
 * The behavior of tooltips and data highlighting when hovering on data and chart area.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CompareMode {
     #[serde(rename = "BASIC_CHART_COMPARE_MODE_UNSPECIFIED")]
     BasicChartCompareModeUnspecified,
@@ -896,8 +995,9 @@ pub enum CompareMode {
     Datum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompareMode {
@@ -907,12 +1007,33 @@ impl std::fmt::Display for CompareMode {
             CompareMode::Category => "CATEGORY",
             CompareMode::Datum => "DATUM",
             CompareMode::Noop => "",
-            CompareMode::FallthroughString => "*",
+            CompareMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CompareMode {
+    fn from(s: String) -> CompareMode {
+        match s.as_str() {
+            "BASIC_CHART_COMPARE_MODE_UNSPECIFIED" => CompareMode::BasicChartCompareModeUnspecified,
+            "CATEGORY" => CompareMode::Category,
+            "DATUM" => CompareMode::Datum,
+            "" => CompareMode::Noop,
+            _ => CompareMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CompareMode> for String {
+    fn from(e: CompareMode) -> String {
+        match e {
+            CompareMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CompareMode {
     fn default() -> CompareMode {
         CompareMode::Noop
@@ -928,6 +1049,7 @@ impl CompareMode {
 * The position of the chart legend.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LegendPosition {
     #[serde(rename = "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED")]
     BasicChartLegendPositionUnspecified,
@@ -943,8 +1065,9 @@ pub enum LegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LegendPosition {
@@ -959,12 +1082,38 @@ impl std::fmt::Display for LegendPosition {
             LegendPosition::RightLegend => "RIGHT_LEGEND",
             LegendPosition::TopLegend => "TOP_LEGEND",
             LegendPosition::Noop => "",
-            LegendPosition::FallthroughString => "*",
+            LegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LegendPosition {
+    fn from(s: String) -> LegendPosition {
+        match s.as_str() {
+            "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                LegendPosition::BasicChartLegendPositionUnspecified
+            }
+            "BOTTOM_LEGEND" => LegendPosition::BottomLegend,
+            "LEFT_LEGEND" => LegendPosition::LeftLegend,
+            "NO_LEGEND" => LegendPosition::NoLegend,
+            "RIGHT_LEGEND" => LegendPosition::RightLegend,
+            "TOP_LEGEND" => LegendPosition::TopLegend,
+            "" => LegendPosition::Noop,
+            _ => LegendPosition::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LegendPosition> for String {
+    fn from(e: LegendPosition) -> String {
+        match e {
+            LegendPosition::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LegendPosition {
     fn default() -> LegendPosition {
         LegendPosition::Noop
@@ -980,6 +1129,7 @@ impl LegendPosition {
 * The stacked type for charts that support vertical stacking. Applies to Area, Bar, Column, Combo, and Stepped Area charts.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StackedType {
     #[serde(rename = "BASIC_CHART_STACKED_TYPE_UNSPECIFIED")]
     BasicChartStackedTypeUnspecified,
@@ -991,8 +1141,9 @@ pub enum StackedType {
     Stacked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StackedType {
@@ -1003,12 +1154,34 @@ impl std::fmt::Display for StackedType {
             StackedType::PercentStacked => "PERCENT_STACKED",
             StackedType::Stacked => "STACKED",
             StackedType::Noop => "",
-            StackedType::FallthroughString => "*",
+            StackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for StackedType {
+    fn from(s: String) -> StackedType {
+        match s.as_str() {
+            "BASIC_CHART_STACKED_TYPE_UNSPECIFIED" => StackedType::BasicChartStackedTypeUnspecified,
+            "NOT_STACKED" => StackedType::NotStacked,
+            "PERCENT_STACKED" => StackedType::PercentStacked,
+            "STACKED" => StackedType::Stacked,
+            "" => StackedType::Noop,
+            _ => StackedType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<StackedType> for String {
+    fn from(e: StackedType) -> String {
+        match e {
+            StackedType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for StackedType {
     fn default() -> StackedType {
         StackedType::Noop
@@ -1024,7 +1197,7 @@ impl StackedType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BasicChartSpec {
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The axis on the chart.
      */
     #[serde(
         default,
@@ -1033,12 +1206,12 @@ pub struct BasicChartSpec {
     )]
     pub axis: Vec<BasicChartAxis>,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The type of the chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "chartType")]
     pub chart_type: Option<Type>,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The behavior of tooltips and data highlighting when hovering on data and chart area.
      */
     #[serde(
         default,
@@ -1047,7 +1220,7 @@ pub struct BasicChartSpec {
     )]
     pub compare_mode: Option<CompareMode>,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The domain of data this is charting. Only a single domain is supported.
      */
     #[serde(
         default,
@@ -1056,7 +1229,7 @@ pub struct BasicChartSpec {
     )]
     pub domains: Vec<BasicChartDomain>,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The number of rows or columns in the data that are "headers". If not set, Google Sheets will guess how many rows are headers based on the data. (Note that BasicChartAxis.title may override the axis title inferred from the header values.)
      */
     #[serde(
         default,
@@ -1066,7 +1239,7 @@ pub struct BasicChartSpec {
     )]
     pub header_count: i64,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * If some values in a series are missing, gaps may appear in the chart (e.g, segments of lines in a line chart will be missing). To eliminate these gaps set this to true. Applies to Line, Area, and Combo charts.
      */
     #[serde(
         default,
@@ -1075,7 +1248,7 @@ pub struct BasicChartSpec {
     )]
     pub interpolate_nulls: bool,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The position of the chart legend.
      */
     #[serde(
         default,
@@ -1084,7 +1257,7 @@ pub struct BasicChartSpec {
     )]
     pub legend_position: Option<LegendPosition>,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * Gets whether all lines should be rendered smooth or straight by default. Applies to Line charts.
      */
     #[serde(
         default,
@@ -1093,7 +1266,7 @@ pub struct BasicChartSpec {
     )]
     pub line_smoothing: bool,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The data this chart is visualizing.
      */
     #[serde(
         default,
@@ -1102,7 +1275,7 @@ pub struct BasicChartSpec {
     )]
     pub series: Vec<BasicChartSeries>,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * The stacked type for charts that support vertical stacking. Applies to Area, Bar, Column, Combo, and Stepped Area charts.
      */
     #[serde(
         default,
@@ -1111,7 +1284,7 @@ pub struct BasicChartSpec {
     )]
     pub stacked_type: Option<StackedType>,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * True to make the chart 3D. Applies to Bar and Column charts.
      */
     #[serde(
         default,
@@ -1120,7 +1293,7 @@ pub struct BasicChartSpec {
     )]
     pub three_dimensional: bool,
     /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
      */
     #[serde(
         default,
@@ -1134,12 +1307,12 @@ pub struct BasicChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BasicFilter {
     /**
-     * The default filter associated with a sheet.
+     * The criteria for showing/hiding values per column. The map's key is the column index, and the value is the criteria for that column. This field is deprecated in favor of filter_specs.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub criteria: Option<FilterCriteria>,
     /**
-     * The default filter associated with a sheet.
+     * The filter criteria per column. Both criteria and filter_specs are populated in responses. If both fields are specified in an update request, this field takes precedence.
      */
     #[serde(
         default,
@@ -1149,12 +1322,12 @@ pub struct BasicFilter {
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
-     * The default filter associated with a sheet.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * The default filter associated with a sheet.
+     * The sort order per column. Later specifications are used when values are equal in the earlier specifications.
      */
     #[serde(
         default,
@@ -1169,12 +1342,12 @@ pub struct BasicFilter {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BasicSeriesDataPointStyleOverride {
     /**
-     * Style override settings for a single series data point.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<Color>,
     /**
-     * Style override settings for a single series data point.
+     * A color value.
      */
     #[serde(
         default,
@@ -1183,7 +1356,7 @@ pub struct BasicSeriesDataPointStyleOverride {
     )]
     pub color_style: Option<ColorStyle>,
     /**
-     * Style override settings for a single series data point.
+     * Zero based index of the series data point.
      */
     #[serde(
         default,
@@ -1192,7 +1365,7 @@ pub struct BasicSeriesDataPointStyleOverride {
     )]
     pub index: i64,
     /**
-     * Style override settings for a single series data point.
+     * The style of a point on the chart.
      */
     #[serde(
         default,
@@ -1206,7 +1379,7 @@ pub struct BasicSeriesDataPointStyleOverride {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesByDataFilterRequest {
     /**
-     * The request for clearing more than one range selected by a DataFilter in a spreadsheet.
+     * The DataFilters used to determine which ranges to clear.
      */
     #[serde(
         default,
@@ -1221,7 +1394,7 @@ pub struct BatchClearValuesByDataFilterRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesByDataFilterResponse {
     /**
-     * The response when clearing a range of values selected with DataFilters in a spreadsheet.
+     * The ranges that were cleared, in A1 notation. If the requests are for an unbounded range or a ranger larger than the bounds of the sheet, this is the actual ranges that were cleared, bounded to the sheet's limits.
      */
     #[serde(
         default,
@@ -1231,7 +1404,7 @@ pub struct BatchClearValuesByDataFilterResponse {
     )]
     pub cleared_ranges: Vec<String>,
     /**
-     * The response when clearing a range of values selected with DataFilters in a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -1246,7 +1419,7 @@ pub struct BatchClearValuesByDataFilterResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesRequest {
     /**
-     * The request for clearing more than one range of values in a spreadsheet.
+     * The ranges to clear, in A1 or R1C1 notation.
      */
     #[serde(
         default,
@@ -1260,7 +1433,7 @@ pub struct BatchClearValuesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchClearValuesResponse {
     /**
-     * The response when clearing a range of values in a spreadsheet.
+     * The ranges that were cleared, in A1 notation. If the requests are for an unbounded range or a ranger larger than the bounds of the sheet, this is the actual ranges that were cleared, bounded to the sheet's limits.
      */
     #[serde(
         default,
@@ -1270,7 +1443,7 @@ pub struct BatchClearValuesResponse {
     )]
     pub cleared_ranges: Vec<String>,
     /**
-     * The response when clearing a range of values in a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -1285,6 +1458,7 @@ pub struct BatchClearValuesResponse {
 * How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateTimeRenderOption {
     #[serde(rename = "FORMATTED_STRING")]
     FormattedString,
@@ -1292,8 +1466,9 @@ pub enum DateTimeRenderOption {
     SerialNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRenderOption {
@@ -1302,12 +1477,32 @@ impl std::fmt::Display for DateTimeRenderOption {
             DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
             DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
             DateTimeRenderOption::Noop => "",
-            DateTimeRenderOption::FallthroughString => "*",
+            DateTimeRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DateTimeRenderOption {
+    fn from(s: String) -> DateTimeRenderOption {
+        match s.as_str() {
+            "FORMATTED_STRING" => DateTimeRenderOption::FormattedString,
+            "SERIAL_NUMBER" => DateTimeRenderOption::SerialNumber,
+            "" => DateTimeRenderOption::Noop,
+            _ => DateTimeRenderOption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DateTimeRenderOption> for String {
+    fn from(e: DateTimeRenderOption) -> String {
+        match e {
+            DateTimeRenderOption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DateTimeRenderOption {
     fn default() -> DateTimeRenderOption {
         DateTimeRenderOption::Noop
@@ -1323,6 +1518,7 @@ impl DateTimeRenderOption {
 * How values should be represented in the output. The default render option is FORMATTED_VALUE.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ValueRenderOption {
     #[serde(rename = "FORMATTED_VALUE")]
     FormattedValue,
@@ -1332,8 +1528,9 @@ pub enum ValueRenderOption {
     UnformattedValue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueRenderOption {
@@ -1343,12 +1540,33 @@ impl std::fmt::Display for ValueRenderOption {
             ValueRenderOption::Formula => "FORMULA",
             ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
             ValueRenderOption::Noop => "",
-            ValueRenderOption::FallthroughString => "*",
+            ValueRenderOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ValueRenderOption {
+    fn from(s: String) -> ValueRenderOption {
+        match s.as_str() {
+            "FORMATTED_VALUE" => ValueRenderOption::FormattedValue,
+            "FORMULA" => ValueRenderOption::Formula,
+            "UNFORMATTED_VALUE" => ValueRenderOption::UnformattedValue,
+            "" => ValueRenderOption::Noop,
+            _ => ValueRenderOption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ValueRenderOption> for String {
+    fn from(e: ValueRenderOption) -> String {
+        match e {
+            ValueRenderOption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ValueRenderOption {
     fn default() -> ValueRenderOption {
         ValueRenderOption::Noop
@@ -1364,7 +1582,7 @@ impl ValueRenderOption {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchGetValuesByDataFilterRequest {
     /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     * The data filters used to match the ranges of values to retrieve. Ranges that match any of the specified data filters are included in the response.
      */
     #[serde(
         default,
@@ -1374,7 +1592,7 @@ pub struct BatchGetValuesByDataFilterRequest {
     )]
     pub data_filters: Vec<DataFilter>,
     /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     * How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
      */
     #[serde(
         default,
@@ -1383,7 +1601,7 @@ pub struct BatchGetValuesByDataFilterRequest {
     )]
     pub date_time_render_option: Option<DateTimeRenderOption>,
     /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     * The major dimension that results should use. For example, if the spreadsheet data is: `A1=1,B1=2,A2=3,B2=4`, then a request that selects that range and sets `majorDimension=ROWS` returns `[[1,2],[3,4]]`, whereas a request that sets `majorDimension=COLUMNS` returns `[[1,3],[2,4]]`.
      */
     #[serde(
         default,
@@ -1392,7 +1610,7 @@ pub struct BatchGetValuesByDataFilterRequest {
     )]
     pub major_dimension: Option<Dimension>,
     /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     * How values should be represented in the output. The default render option is FORMATTED_VALUE.
      */
     #[serde(
         default,
@@ -1406,7 +1624,7 @@ pub struct BatchGetValuesByDataFilterRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchGetValuesByDataFilterResponse {
     /**
-     * The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
+     * The ID of the spreadsheet the data was retrieved from.
      */
     #[serde(
         default,
@@ -1416,7 +1634,7 @@ pub struct BatchGetValuesByDataFilterResponse {
     )]
     pub spreadsheet_id: String,
     /**
-     * The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
+     * The requested values with the list of data filters that matched them.
      */
     #[serde(
         default,
@@ -1431,7 +1649,7 @@ pub struct BatchGetValuesByDataFilterResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchGetValuesResponse {
     /**
-     * The response when retrieving more than one range of values in a spreadsheet.
+     * The ID of the spreadsheet the data was retrieved from.
      */
     #[serde(
         default,
@@ -1441,7 +1659,7 @@ pub struct BatchGetValuesResponse {
     )]
     pub spreadsheet_id: String,
     /**
-     * The response when retrieving more than one range of values in a spreadsheet.
+     * The requested values. The order of the ValueRanges is the same as the order of the requested ranges.
      */
     #[serde(
         default,
@@ -1456,7 +1674,7 @@ pub struct BatchGetValuesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateSpreadsheetRequest {
     /**
-     * The request for updating any aspect of a spreadsheet.
+     * Determines if the update response should include the spreadsheet resource.
      */
     #[serde(
         default,
@@ -1465,7 +1683,7 @@ pub struct BatchUpdateSpreadsheetRequest {
     )]
     pub include_spreadsheet_in_response: Option<bool>,
     /**
-     * The request for updating any aspect of a spreadsheet.
+     * A list of updates to apply to the spreadsheet. Requests will be applied in the order they are specified. If any request is not valid, no requests will be applied.
      */
     #[serde(
         default,
@@ -1474,7 +1692,7 @@ pub struct BatchUpdateSpreadsheetRequest {
     )]
     pub requests: Vec<Request>,
     /**
-     * The request for updating any aspect of a spreadsheet.
+     * True if grid data should be returned. Meaningful only if include_spreadsheet_in_response is 'true'. This parameter is ignored if a field mask was set in the request.
      */
     #[serde(
         default,
@@ -1483,7 +1701,7 @@ pub struct BatchUpdateSpreadsheetRequest {
     )]
     pub response_include_grid_data: Option<bool>,
     /**
-     * The request for updating any aspect of a spreadsheet.
+     * Limits the ranges included in the response spreadsheet. Meaningful only if include_spreadsheet_in_response is 'true'.
      */
     #[serde(
         default,
@@ -1498,7 +1716,7 @@ pub struct BatchUpdateSpreadsheetRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateSpreadsheetResponse {
     /**
-     * The reply for batch updating a spreadsheet.
+     * The reply of the updates. This maps 1:1 with the updates, although replies to some requests may be empty.
      */
     #[serde(
         default,
@@ -1507,7 +1725,7 @@ pub struct BatchUpdateSpreadsheetResponse {
     )]
     pub replies: Vec<Response>,
     /**
-     * The reply for batch updating a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -1517,7 +1735,7 @@ pub struct BatchUpdateSpreadsheetResponse {
     )]
     pub spreadsheet_id: String,
     /**
-     * The reply for batch updating a spreadsheet.
+     * Resource that represents a spreadsheet.
      */
     #[serde(
         default,
@@ -1531,6 +1749,7 @@ pub struct BatchUpdateSpreadsheetResponse {
 * How the input data should be interpreted.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ValueInputOption {
     #[serde(rename = "INPUT_VALUE_OPTION_UNSPECIFIED")]
     InputValueOptionUnspecified,
@@ -1540,8 +1759,9 @@ pub enum ValueInputOption {
     UserEntered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueInputOption {
@@ -1551,12 +1771,33 @@ impl std::fmt::Display for ValueInputOption {
             ValueInputOption::Raw => "RAW",
             ValueInputOption::UserEntered => "USER_ENTERED",
             ValueInputOption::Noop => "",
-            ValueInputOption::FallthroughString => "*",
+            ValueInputOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ValueInputOption {
+    fn from(s: String) -> ValueInputOption {
+        match s.as_str() {
+            "INPUT_VALUE_OPTION_UNSPECIFIED" => ValueInputOption::InputValueOptionUnspecified,
+            "RAW" => ValueInputOption::Raw,
+            "USER_ENTERED" => ValueInputOption::UserEntered,
+            "" => ValueInputOption::Noop,
+            _ => ValueInputOption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ValueInputOption> for String {
+    fn from(e: ValueInputOption) -> String {
+        match e {
+            ValueInputOption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ValueInputOption {
     fn default() -> ValueInputOption {
         ValueInputOption::Noop
@@ -1572,7 +1813,7 @@ impl ValueInputOption {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesByDataFilterRequest {
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * The new values to apply to the spreadsheet. If more than one range is matched by the specified DataFilter the specified values are applied to all of those ranges.
      */
     #[serde(
         default,
@@ -1581,7 +1822,7 @@ pub struct BatchUpdateValuesByDataFilterRequest {
     )]
     pub data: Vec<DataFilterValueRange>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * Determines if the update response should include the values of the cells that were updated. By default, responses do not include the updated values. The `updatedData` field within each of the BatchUpdateValuesResponse.responses contains the updated values. If the range to write was larger than the range actually written, the response includes all values in the requested range (excluding trailing empty rows and columns).
      */
     #[serde(
         default,
@@ -1590,7 +1831,7 @@ pub struct BatchUpdateValuesByDataFilterRequest {
     )]
     pub include_values_in_response: Option<bool>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * Determines how dates, times, and durations in the response should be rendered. This is ignored if response_value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
      */
     #[serde(
         default,
@@ -1599,7 +1840,7 @@ pub struct BatchUpdateValuesByDataFilterRequest {
     )]
     pub response_date_time_render_option: Option<DateTimeRenderOption>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * Determines how values in the response should be rendered. The default render option is FORMATTED_VALUE.
      */
     #[serde(
         default,
@@ -1608,7 +1849,7 @@ pub struct BatchUpdateValuesByDataFilterRequest {
     )]
     pub response_value_render_option: Option<ValueRenderOption>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * How the input data should be interpreted.
      */
     #[serde(
         default,
@@ -1622,7 +1863,7 @@ pub struct BatchUpdateValuesByDataFilterRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesByDataFilterResponse {
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The response for each range updated.
      */
     #[serde(
         default,
@@ -1631,7 +1872,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     )]
     pub responses: Vec<UpdateValuesByDataFilterResponse>,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -1641,7 +1882,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     )]
     pub spreadsheet_id: String,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of cells updated.
      */
     #[serde(
         default,
@@ -1651,7 +1892,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     )]
     pub total_updated_cells: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of columns where at least one cell in the column was updated.
      */
     #[serde(
         default,
@@ -1661,7 +1902,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     )]
     pub total_updated_columns: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of rows where at least one cell in the row was updated.
      */
     #[serde(
         default,
@@ -1671,7 +1912,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     )]
     pub total_updated_rows: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of sheets where at least one cell in the sheet was updated.
      */
     #[serde(
         default,
@@ -1686,7 +1927,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesRequest {
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * The new values to apply to the spreadsheet.
      */
     #[serde(
         default,
@@ -1695,7 +1936,7 @@ pub struct BatchUpdateValuesRequest {
     )]
     pub data: Vec<ValueRange>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * Determines if the update response should include the values of the cells that were updated. By default, responses do not include the updated values. The `updatedData` field within each of the BatchUpdateValuesResponse.responses contains the updated values. If the range to write was larger than the range actually written, the response includes all values in the requested range (excluding trailing empty rows and columns).
      */
     #[serde(
         default,
@@ -1704,7 +1945,7 @@ pub struct BatchUpdateValuesRequest {
     )]
     pub include_values_in_response: Option<bool>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * Determines how dates, times, and durations in the response should be rendered. This is ignored if response_value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
      */
     #[serde(
         default,
@@ -1713,7 +1954,7 @@ pub struct BatchUpdateValuesRequest {
     )]
     pub response_date_time_render_option: Option<DateTimeRenderOption>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * Determines how values in the response should be rendered. The default render option is FORMATTED_VALUE.
      */
     #[serde(
         default,
@@ -1722,7 +1963,7 @@ pub struct BatchUpdateValuesRequest {
     )]
     pub response_value_render_option: Option<ValueRenderOption>,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * How the input data should be interpreted.
      */
     #[serde(
         default,
@@ -1736,7 +1977,7 @@ pub struct BatchUpdateValuesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BatchUpdateValuesResponse {
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * One UpdateValuesResponse per requested range, in the same order as the requests appeared.
      */
     #[serde(
         default,
@@ -1745,7 +1986,7 @@ pub struct BatchUpdateValuesResponse {
     )]
     pub responses: Vec<UpdateValuesResponse>,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -1755,7 +1996,7 @@ pub struct BatchUpdateValuesResponse {
     )]
     pub spreadsheet_id: String,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of cells updated.
      */
     #[serde(
         default,
@@ -1765,7 +2006,7 @@ pub struct BatchUpdateValuesResponse {
     )]
     pub total_updated_cells: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of columns where at least one cell in the column was updated.
      */
     #[serde(
         default,
@@ -1775,7 +2016,7 @@ pub struct BatchUpdateValuesResponse {
     )]
     pub total_updated_columns: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of rows where at least one cell in the row was updated.
      */
     #[serde(
         default,
@@ -1785,7 +2026,7 @@ pub struct BatchUpdateValuesResponse {
     )]
     pub total_updated_rows: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The total number of sheets where at least one cell in the sheet was updated.
      */
     #[serde(
         default,
@@ -1800,7 +2041,7 @@ pub struct BatchUpdateValuesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BigQueryDataSourceSpec {
     /**
-     * The specification of a BigQuery data source that's connected to a sheet.
+     * The ID of a BigQuery enabled GCP project with a billing account attached. For any queries executed against the data source, the project is charged.
      */
     #[serde(
         default,
@@ -1810,12 +2051,12 @@ pub struct BigQueryDataSourceSpec {
     )]
     pub project_id: String,
     /**
-     * The specification of a BigQuery data source that's connected to a sheet.
+     * Specifies a custom BigQuery query.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "querySpec")]
     pub query_spec: Option<BigQuerySpec>,
     /**
-     * The specification of a BigQuery data source that's connected to a sheet.
+     * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "tableSpec")]
     pub table_spec: Option<BigQueryTableSpec>,
@@ -1825,7 +2066,7 @@ pub struct BigQueryDataSourceSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BigQuerySpec {
     /**
-     * Specifies a custom BigQuery query.
+     * The raw query string.
      */
     #[serde(
         default,
@@ -1840,7 +2081,7 @@ pub struct BigQuerySpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BigQueryTableSpec {
     /**
-     * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
+     * The BigQuery dataset id.
      */
     #[serde(
         default,
@@ -1850,7 +2091,7 @@ pub struct BigQueryTableSpec {
     )]
     pub dataset_id: String,
     /**
-     * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
+     * The BigQuery table id.
      */
     #[serde(
         default,
@@ -1860,7 +2101,7 @@ pub struct BigQueryTableSpec {
     )]
     pub table_id: String,
     /**
-     * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
+     * The ID of a BigQuery project the table belongs to. If not specified, the project_id is assumed.
      */
     #[serde(
         default,
@@ -1875,6 +2116,7 @@ pub struct BigQueryTableSpec {
 * The type of condition.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BooleanConditionType {
     #[serde(rename = "BLANK")]
     Blank,
@@ -1942,8 +2184,9 @@ pub enum BooleanConditionType {
     TextStartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BooleanConditionType {
@@ -1982,12 +2225,62 @@ impl std::fmt::Display for BooleanConditionType {
             BooleanConditionType::TextNotEq => "TEXT_NOT_EQ",
             BooleanConditionType::TextStartsWith => "TEXT_STARTS_WITH",
             BooleanConditionType::Noop => "",
-            BooleanConditionType::FallthroughString => "*",
+            BooleanConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BooleanConditionType {
+    fn from(s: String) -> BooleanConditionType {
+        match s.as_str() {
+            "BLANK" => BooleanConditionType::Blank,
+            "BOOLEAN" => BooleanConditionType::Boolean,
+            "CONDITION_TYPE_UNSPECIFIED" => BooleanConditionType::ConditionTypeUnspecified,
+            "CUSTOM_FORMULA" => BooleanConditionType::CustomFormula,
+            "DATE_AFTER" => BooleanConditionType::DateAfter,
+            "DATE_BEFORE" => BooleanConditionType::DateBefore,
+            "DATE_BETWEEN" => BooleanConditionType::DateBetween,
+            "DATE_EQ" => BooleanConditionType::DateEq,
+            "DATE_IS_VALID" => BooleanConditionType::DateIsValid,
+            "DATE_NOT_BETWEEN" => BooleanConditionType::DateNotBetween,
+            "DATE_NOT_EQ" => BooleanConditionType::DateNotEq,
+            "DATE_ON_OR_AFTER" => BooleanConditionType::DateOnOrAfter,
+            "DATE_ON_OR_BEFORE" => BooleanConditionType::DateOnOrBefore,
+            "NOT_BLANK" => BooleanConditionType::NotBlank,
+            "NUMBER_BETWEEN" => BooleanConditionType::NumberBetween,
+            "NUMBER_EQ" => BooleanConditionType::NumberEq,
+            "NUMBER_GREATER" => BooleanConditionType::NumberGreater,
+            "NUMBER_GREATER_THAN_EQ" => BooleanConditionType::NumberGreaterThanEq,
+            "NUMBER_LESS" => BooleanConditionType::NumberLess,
+            "NUMBER_LESS_THAN_EQ" => BooleanConditionType::NumberLessThanEq,
+            "NUMBER_NOT_BETWEEN" => BooleanConditionType::NumberNotBetween,
+            "NUMBER_NOT_EQ" => BooleanConditionType::NumberNotEq,
+            "ONE_OF_LIST" => BooleanConditionType::OneOfList,
+            "ONE_OF_RANGE" => BooleanConditionType::OneOfRange,
+            "TEXT_CONTAINS" => BooleanConditionType::TextContains,
+            "TEXT_ENDS_WITH" => BooleanConditionType::TextEndsWith,
+            "TEXT_EQ" => BooleanConditionType::TextEq,
+            "TEXT_IS_EMAIL" => BooleanConditionType::TextIsEmail,
+            "TEXT_IS_URL" => BooleanConditionType::TextIsUrl,
+            "TEXT_NOT_CONTAINS" => BooleanConditionType::TextNotContains,
+            "TEXT_NOT_EQ" => BooleanConditionType::TextNotEq,
+            "TEXT_STARTS_WITH" => BooleanConditionType::TextStartsWith,
+            "" => BooleanConditionType::Noop,
+            _ => BooleanConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BooleanConditionType> for String {
+    fn from(e: BooleanConditionType) -> String {
+        match e {
+            BooleanConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BooleanConditionType {
     fn default() -> BooleanConditionType {
         BooleanConditionType::Noop
@@ -2003,12 +2296,12 @@ impl BooleanConditionType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BooleanCondition {
     /**
-     * A condition that can evaluate to true or false. BooleanConditions are used by conditional formatting, data validation, and the criteria in filters.
+     * The type of condition.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<BooleanConditionType>,
     /**
-     * A condition that can evaluate to true or false. BooleanConditions are used by conditional formatting, data validation, and the criteria in filters.
+     * The values of the condition. The number of supported values depends on the condition type. Some support zero values, others one or two values, and ConditionType.ONE_OF_LIST supports an arbitrary number of values.
      */
     #[serde(
         default,
@@ -2022,12 +2315,12 @@ pub struct BooleanCondition {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BooleanRule {
     /**
-     * A rule that may or may not match, depending on the condition.
+     * A condition that can evaluate to true or false. BooleanConditions are used by conditional formatting, data validation, and the criteria in filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub condition: Option<BooleanCondition>,
     /**
-     * A rule that may or may not match, depending on the condition.
+     * The format of a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub format: Option<CellFormat>,
@@ -2037,6 +2330,7 @@ pub struct BooleanRule {
 * The style of the border.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Style {
     #[serde(rename = "DASHED")]
     Dashed,
@@ -2056,8 +2350,9 @@ pub enum Style {
     StyleUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Style {
@@ -2072,12 +2367,38 @@ impl std::fmt::Display for Style {
             Style::SolidThick => "SOLID_THICK",
             Style::StyleUnspecified => "STYLE_UNSPECIFIED",
             Style::Noop => "",
-            Style::FallthroughString => "*",
+            Style::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Style {
+    fn from(s: String) -> Style {
+        match s.as_str() {
+            "DASHED" => Style::Dashed,
+            "DOTTED" => Style::Dotted,
+            "DOUBLE" => Style::Double,
+            "NONE" => Style::None,
+            "SOLID" => Style::Solid,
+            "SOLID_MEDIUM" => Style::SolidMedium,
+            "SOLID_THICK" => Style::SolidThick,
+            "STYLE_UNSPECIFIED" => Style::StyleUnspecified,
+            "" => Style::Noop,
+            _ => Style::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Style> for String {
+    fn from(e: Style) -> String {
+        match e {
+            Style::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Style {
     fn default() -> Style {
         Style::Noop
@@ -2093,12 +2414,12 @@ impl Style {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Border {
     /**
-     * A border along a cell.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<Color>,
     /**
-     * A border along a cell.
+     * A color value.
      */
     #[serde(
         default,
@@ -2107,12 +2428,12 @@ pub struct Border {
     )]
     pub color_style: Option<ColorStyle>,
     /**
-     * A border along a cell.
+     * The style of the border.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub style: Option<Style>,
     /**
-     * A border along a cell.
+     * The width of the border, in pixels. Deprecated; the width is determined by the "style" field.
      */
     #[serde(
         default,
@@ -2126,22 +2447,22 @@ pub struct Border {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Borders {
     /**
-     * The borders of the cell.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bottom: Option<Border>,
     /**
-     * The borders of the cell.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub left: Option<Border>,
     /**
-     * The borders of the cell.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub right: Option<Border>,
     /**
-     * The borders of the cell.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub top: Option<Border>,
@@ -2151,6 +2472,7 @@ pub struct Borders {
 * Where the legend of the chart should be drawn.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BubbleChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -2168,8 +2490,9 @@ pub enum BubbleChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BubbleChartSpecLegendPosition {
@@ -2185,12 +2508,39 @@ impl std::fmt::Display for BubbleChartSpecLegendPosition {
             BubbleChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             BubbleChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             BubbleChartSpecLegendPosition::Noop => "",
-            BubbleChartSpecLegendPosition::FallthroughString => "*",
+            BubbleChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BubbleChartSpecLegendPosition {
+    fn from(s: String) -> BubbleChartSpecLegendPosition {
+        match s.as_str() {
+            "BOTTOM_LEGEND" => BubbleChartSpecLegendPosition::BottomLegend,
+            "BUBBLE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                BubbleChartSpecLegendPosition::BubbleChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => BubbleChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => BubbleChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => BubbleChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => BubbleChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => BubbleChartSpecLegendPosition::TopLegend,
+            "" => BubbleChartSpecLegendPosition::Noop,
+            _ => BubbleChartSpecLegendPosition::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BubbleChartSpecLegendPosition> for String {
+    fn from(e: BubbleChartSpecLegendPosition) -> String {
+        match e {
+            BubbleChartSpecLegendPosition::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BubbleChartSpecLegendPosition {
     fn default() -> BubbleChartSpecLegendPosition {
         BubbleChartSpecLegendPosition::Noop
@@ -2206,7 +2556,7 @@ impl BubbleChartSpecLegendPosition {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BubbleChartSpec {
     /**
-     * A bubble chart.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -2215,7 +2565,7 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_border_color: Option<Color>,
     /**
-     * A bubble chart.
+     * A color value.
      */
     #[serde(
         default,
@@ -2224,7 +2574,7 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_border_color_style: Option<ColorStyle>,
     /**
-     * A bubble chart.
+     * The data included in a domain or series.
      */
     #[serde(
         default,
@@ -2233,7 +2583,7 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_labels: Option<ChartData>,
     /**
-     * A bubble chart.
+     * The max radius size of the bubbles, in pixels. If specified, the field must be a positive value.
      */
     #[serde(
         default,
@@ -2243,7 +2593,7 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_max_radius_size: i64,
     /**
-     * A bubble chart.
+     * The minimum radius size of the bubbles, in pixels. If specific, the field must be a positive value.
      */
     #[serde(
         default,
@@ -2253,7 +2603,7 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_min_radius_size: i64,
     /**
-     * A bubble chart.
+     * The opacity of the bubbles between 0 and 1.0. 0 is fully transparent and 1 is fully opaque.
      */
     #[serde(
         default,
@@ -2263,7 +2613,7 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_opacity: f64,
     /**
-     * A bubble chart.
+     * The data included in a domain or series.
      */
     #[serde(
         default,
@@ -2272,7 +2622,7 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_sizes: Option<ChartData>,
     /**
-     * A bubble chart.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -2281,17 +2631,17 @@ pub struct BubbleChartSpec {
     )]
     pub bubble_text_style: Option<TextFormat>,
     /**
-     * A bubble chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<ChartData>,
     /**
-     * A bubble chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupIds")]
     pub group_ids: Option<ChartData>,
     /**
-     * A bubble chart.
+     * Where the legend of the chart should be drawn.
      */
     #[serde(
         default,
@@ -2300,7 +2650,7 @@ pub struct BubbleChartSpec {
     )]
     pub legend_position: Option<BubbleChartSpecLegendPosition>,
     /**
-     * A bubble chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub series: Option<ChartData>,
@@ -2310,7 +2660,7 @@ pub struct BubbleChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CandlestickChartSpec {
     /**
-     * A candlestick chart.
+     * The Candlestick chart data. Only one CandlestickData is supported.
      */
     #[serde(
         default,
@@ -2319,7 +2669,7 @@ pub struct CandlestickChartSpec {
     )]
     pub data: Vec<CandlestickData>,
     /**
-     * A candlestick chart.
+     * The domain of a CandlestickChart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<CandlestickDomain>,
@@ -2329,7 +2679,7 @@ pub struct CandlestickChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CandlestickData {
     /**
-     * The Candlestick chart data, each containing the low, open, close, and high values for a series.
+     * The series of a CandlestickData.
      */
     #[serde(
         default,
@@ -2338,7 +2688,7 @@ pub struct CandlestickData {
     )]
     pub close_series: Option<CandlestickSeries>,
     /**
-     * The Candlestick chart data, each containing the low, open, close, and high values for a series.
+     * The series of a CandlestickData.
      */
     #[serde(
         default,
@@ -2347,12 +2697,12 @@ pub struct CandlestickData {
     )]
     pub high_series: Option<CandlestickSeries>,
     /**
-     * The Candlestick chart data, each containing the low, open, close, and high values for a series.
+     * The series of a CandlestickData.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "lowSeries")]
     pub low_series: Option<CandlestickSeries>,
     /**
-     * The Candlestick chart data, each containing the low, open, close, and high values for a series.
+     * The series of a CandlestickData.
      */
     #[serde(
         default,
@@ -2366,12 +2716,12 @@ pub struct CandlestickData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CandlestickDomain {
     /**
-     * The domain of a CandlestickChart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<ChartData>,
     /**
-     * The domain of a CandlestickChart.
+     * True to reverse the order of the domain values (horizontal axis).
      */
     #[serde(
         default,
@@ -2384,7 +2734,7 @@ pub struct CandlestickDomain {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CandlestickSeries {
     /**
-     * The series of a CandlestickData.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<ChartData>,
@@ -2394,7 +2744,7 @@ pub struct CandlestickSeries {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CellData {
     /**
-     * Data about a specific cell.
+     * A data source formula.
      */
     #[serde(
         default,
@@ -2403,7 +2753,7 @@ pub struct CellData {
     )]
     pub data_source_formula: Option<DataSourceFormula>,
     /**
-     * Data about a specific cell.
+     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
      */
     #[serde(
         default,
@@ -2412,7 +2762,7 @@ pub struct CellData {
     )]
     pub data_source_table: Option<DataSourceTable>,
     /**
-     * Data about a specific cell.
+     * A data validation rule.
      */
     #[serde(
         default,
@@ -2421,7 +2771,7 @@ pub struct CellData {
     )]
     pub data_validation: Option<DataValidationRule>,
     /**
-     * Data about a specific cell.
+     * The format of a cell.
      */
     #[serde(
         default,
@@ -2430,7 +2780,7 @@ pub struct CellData {
     )]
     pub effective_format: Option<CellFormat>,
     /**
-     * Data about a specific cell.
+     * The kinds of value that a cell in a spreadsheet can have.
      */
     #[serde(
         default,
@@ -2439,7 +2789,7 @@ pub struct CellData {
     )]
     pub effective_value: Option<ExtendedValue>,
     /**
-     * Data about a specific cell.
+     * The formatted value of the cell. This is the value as it's shown to the user. This field is read-only.
      */
     #[serde(
         default,
@@ -2449,7 +2799,7 @@ pub struct CellData {
     )]
     pub formatted_value: String,
     /**
-     * Data about a specific cell.
+     * A hyperlink this cell points to, if any. If the cell contains multiple hyperlinks, this field will be empty. This field is read-only. To set it, use a `=HYPERLINK` formula in the userEnteredValue.formulaValue field. A cell-level link can also be set from the userEnteredFormat.textFormat field. Alternatively, set a hyperlink in the textFormatRun.format.link field that spans the entire cell.
      */
     #[serde(
         default,
@@ -2458,7 +2808,7 @@ pub struct CellData {
     )]
     pub hyperlink: String,
     /**
-     * Data about a specific cell.
+     * Any note on the cell.
      */
     #[serde(
         default,
@@ -2467,7 +2817,7 @@ pub struct CellData {
     )]
     pub note: String,
     /**
-     * Data about a specific cell.
+     * A pivot table.
      */
     #[serde(
         default,
@@ -2476,7 +2826,7 @@ pub struct CellData {
     )]
     pub pivot_table: Option<PivotTable>,
     /**
-     * Data about a specific cell.
+     * Runs of rich text applied to subsections of the cell. Runs are only valid on user entered strings, not formulas, bools, or numbers. Properties of a run start at a specific index in the text and continue until the next run. Runs will inherit the properties of the cell unless explicitly changed. When writing, the new runs will overwrite any prior runs. When writing a new user_entered_value, previous runs are erased.
      */
     #[serde(
         default,
@@ -2486,7 +2836,7 @@ pub struct CellData {
     )]
     pub text_format_runs: Vec<TextFormatRun>,
     /**
-     * Data about a specific cell.
+     * The format of a cell.
      */
     #[serde(
         default,
@@ -2495,7 +2845,7 @@ pub struct CellData {
     )]
     pub user_entered_format: Option<CellFormat>,
     /**
-     * Data about a specific cell.
+     * The kinds of value that a cell in a spreadsheet can have.
      */
     #[serde(
         default,
@@ -2509,6 +2859,7 @@ pub struct CellData {
 * The horizontal alignment of the value in the cell.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HorizontalAlignment {
     #[serde(rename = "CENTER")]
     Center,
@@ -2520,8 +2871,9 @@ pub enum HorizontalAlignment {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HorizontalAlignment {
@@ -2532,12 +2884,34 @@ impl std::fmt::Display for HorizontalAlignment {
             HorizontalAlignment::Left => "LEFT",
             HorizontalAlignment::Right => "RIGHT",
             HorizontalAlignment::Noop => "",
-            HorizontalAlignment::FallthroughString => "*",
+            HorizontalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for HorizontalAlignment {
+    fn from(s: String) -> HorizontalAlignment {
+        match s.as_str() {
+            "CENTER" => HorizontalAlignment::Center,
+            "HORIZONTAL_ALIGN_UNSPECIFIED" => HorizontalAlignment::HorizontalAlignUnspecified,
+            "LEFT" => HorizontalAlignment::Left,
+            "RIGHT" => HorizontalAlignment::Right,
+            "" => HorizontalAlignment::Noop,
+            _ => HorizontalAlignment::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<HorizontalAlignment> for String {
+    fn from(e: HorizontalAlignment) -> String {
+        match e {
+            HorizontalAlignment::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for HorizontalAlignment {
     fn default() -> HorizontalAlignment {
         HorizontalAlignment::Noop
@@ -2553,6 +2927,7 @@ impl HorizontalAlignment {
 * How a hyperlink, if it exists, should be displayed in the cell.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HyperlinkDisplayType {
     #[serde(rename = "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED")]
     HyperlinkDisplayTypeUnspecified,
@@ -2562,8 +2937,9 @@ pub enum HyperlinkDisplayType {
     PlainText,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HyperlinkDisplayType {
@@ -2575,12 +2951,35 @@ impl std::fmt::Display for HyperlinkDisplayType {
             HyperlinkDisplayType::Linked => "LINKED",
             HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
             HyperlinkDisplayType::Noop => "",
-            HyperlinkDisplayType::FallthroughString => "*",
+            HyperlinkDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for HyperlinkDisplayType {
+    fn from(s: String) -> HyperlinkDisplayType {
+        match s.as_str() {
+            "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED" => {
+                HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified
+            }
+            "LINKED" => HyperlinkDisplayType::Linked,
+            "PLAIN_TEXT" => HyperlinkDisplayType::PlainText,
+            "" => HyperlinkDisplayType::Noop,
+            _ => HyperlinkDisplayType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<HyperlinkDisplayType> for String {
+    fn from(e: HyperlinkDisplayType) -> String {
+        match e {
+            HyperlinkDisplayType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for HyperlinkDisplayType {
     fn default() -> HyperlinkDisplayType {
         HyperlinkDisplayType::Noop
@@ -2596,6 +2995,7 @@ impl HyperlinkDisplayType {
 * The direction of the text in the cell.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TextDirection {
     #[serde(rename = "LEFT_TO_RIGHT")]
     LeftToRight,
@@ -2605,8 +3005,9 @@ pub enum TextDirection {
     TextDirectionUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextDirection {
@@ -2616,12 +3017,33 @@ impl std::fmt::Display for TextDirection {
             TextDirection::RightToLeft => "RIGHT_TO_LEFT",
             TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
             TextDirection::Noop => "",
-            TextDirection::FallthroughString => "*",
+            TextDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TextDirection {
+    fn from(s: String) -> TextDirection {
+        match s.as_str() {
+            "LEFT_TO_RIGHT" => TextDirection::LeftToRight,
+            "RIGHT_TO_LEFT" => TextDirection::RightToLeft,
+            "TEXT_DIRECTION_UNSPECIFIED" => TextDirection::TextDirectionUnspecified,
+            "" => TextDirection::Noop,
+            _ => TextDirection::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TextDirection> for String {
+    fn from(e: TextDirection) -> String {
+        match e {
+            TextDirection::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TextDirection {
     fn default() -> TextDirection {
         TextDirection::Noop
@@ -2637,6 +3059,7 @@ impl TextDirection {
 * The vertical alignment of the value in the cell.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VerticalAlignment {
     #[serde(rename = "BOTTOM")]
     Bottom,
@@ -2648,8 +3071,9 @@ pub enum VerticalAlignment {
     VerticalAlignUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerticalAlignment {
@@ -2660,12 +3084,34 @@ impl std::fmt::Display for VerticalAlignment {
             VerticalAlignment::Top => "TOP",
             VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
             VerticalAlignment::Noop => "",
-            VerticalAlignment::FallthroughString => "*",
+            VerticalAlignment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for VerticalAlignment {
+    fn from(s: String) -> VerticalAlignment {
+        match s.as_str() {
+            "BOTTOM" => VerticalAlignment::Bottom,
+            "MIDDLE" => VerticalAlignment::Middle,
+            "TOP" => VerticalAlignment::Top,
+            "VERTICAL_ALIGN_UNSPECIFIED" => VerticalAlignment::VerticalAlignUnspecified,
+            "" => VerticalAlignment::Noop,
+            _ => VerticalAlignment::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<VerticalAlignment> for String {
+    fn from(e: VerticalAlignment) -> String {
+        match e {
+            VerticalAlignment::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for VerticalAlignment {
     fn default() -> VerticalAlignment {
         VerticalAlignment::Noop
@@ -2681,6 +3127,7 @@ impl VerticalAlignment {
 * The wrap strategy for the value in the cell.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WrapStrategy {
     #[serde(rename = "CLIP")]
     Clip,
@@ -2694,8 +3141,9 @@ pub enum WrapStrategy {
     WrapStrategyUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WrapStrategy {
@@ -2707,12 +3155,35 @@ impl std::fmt::Display for WrapStrategy {
             WrapStrategy::Wrap => "WRAP",
             WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
             WrapStrategy::Noop => "",
-            WrapStrategy::FallthroughString => "*",
+            WrapStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WrapStrategy {
+    fn from(s: String) -> WrapStrategy {
+        match s.as_str() {
+            "CLIP" => WrapStrategy::Clip,
+            "LEGACY_WRAP" => WrapStrategy::LegacyWrap,
+            "OVERFLOW_CELL" => WrapStrategy::OverflowCell,
+            "WRAP" => WrapStrategy::Wrap,
+            "WRAP_STRATEGY_UNSPECIFIED" => WrapStrategy::WrapStrategyUnspecified,
+            "" => WrapStrategy::Noop,
+            _ => WrapStrategy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WrapStrategy> for String {
+    fn from(e: WrapStrategy) -> String {
+        match e {
+            WrapStrategy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WrapStrategy {
     fn default() -> WrapStrategy {
         WrapStrategy::Noop
@@ -2728,7 +3199,7 @@ impl WrapStrategy {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CellFormat {
     /**
-     * The format of a cell.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -2737,7 +3208,7 @@ pub struct CellFormat {
     )]
     pub background_color: Option<Color>,
     /**
-     * The format of a cell.
+     * A color value.
      */
     #[serde(
         default,
@@ -2746,12 +3217,12 @@ pub struct CellFormat {
     )]
     pub background_color_style: Option<ColorStyle>,
     /**
-     * The format of a cell.
+     * The borders of the cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub borders: Option<Borders>,
     /**
-     * The format of a cell.
+     * The horizontal alignment of the value in the cell.
      */
     #[serde(
         default,
@@ -2760,7 +3231,7 @@ pub struct CellFormat {
     )]
     pub horizontal_alignment: Option<HorizontalAlignment>,
     /**
-     * The format of a cell.
+     * How a hyperlink, if it exists, should be displayed in the cell.
      */
     #[serde(
         default,
@@ -2769,7 +3240,7 @@ pub struct CellFormat {
     )]
     pub hyperlink_display_type: Option<HyperlinkDisplayType>,
     /**
-     * The format of a cell.
+     * The number format of a cell.
      */
     #[serde(
         default,
@@ -2778,12 +3249,12 @@ pub struct CellFormat {
     )]
     pub number_format: Option<NumberFormat>,
     /**
-     * The format of a cell.
+     * The amount of padding around the cell, in pixels. When updating padding, every field must be specified.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub padding: Option<Padding>,
     /**
-     * The format of a cell.
+     * The direction of the text in the cell.
      */
     #[serde(
         default,
@@ -2792,7 +3263,7 @@ pub struct CellFormat {
     )]
     pub text_direction: Option<TextDirection>,
     /**
-     * The format of a cell.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -2801,7 +3272,7 @@ pub struct CellFormat {
     )]
     pub text_format: Option<TextFormat>,
     /**
-     * The format of a cell.
+     * The rotation applied to text in a cell.
      */
     #[serde(
         default,
@@ -2810,7 +3281,7 @@ pub struct CellFormat {
     )]
     pub text_rotation: Option<TextRotation>,
     /**
-     * The format of a cell.
+     * The vertical alignment of the value in the cell.
      */
     #[serde(
         default,
@@ -2819,7 +3290,7 @@ pub struct CellFormat {
     )]
     pub vertical_alignment: Option<VerticalAlignment>,
     /**
-     * The format of a cell.
+     * The wrap strategy for the value in the cell.
      */
     #[serde(
         default,
@@ -2833,6 +3304,7 @@ pub struct CellFormat {
 * The view window's mode.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ViewWindowMode {
     #[serde(rename = "DEFAULT_VIEW_WINDOW_MODE")]
     DefaultViewWindowMode,
@@ -2844,8 +3316,9 @@ pub enum ViewWindowMode {
     ViewWindowModeUnsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ViewWindowMode {
@@ -2856,12 +3329,34 @@ impl std::fmt::Display for ViewWindowMode {
             ViewWindowMode::Pretty => "PRETTY",
             ViewWindowMode::ViewWindowModeUnsupported => "VIEW_WINDOW_MODE_UNSUPPORTED",
             ViewWindowMode::Noop => "",
-            ViewWindowMode::FallthroughString => "*",
+            ViewWindowMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ViewWindowMode {
+    fn from(s: String) -> ViewWindowMode {
+        match s.as_str() {
+            "DEFAULT_VIEW_WINDOW_MODE" => ViewWindowMode::DefaultViewWindowMode,
+            "EXPLICIT" => ViewWindowMode::Explicit,
+            "PRETTY" => ViewWindowMode::Pretty,
+            "VIEW_WINDOW_MODE_UNSUPPORTED" => ViewWindowMode::ViewWindowModeUnsupported,
+            "" => ViewWindowMode::Noop,
+            _ => ViewWindowMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ViewWindowMode> for String {
+    fn from(e: ViewWindowMode) -> String {
+        match e {
+            ViewWindowMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ViewWindowMode {
     fn default() -> ViewWindowMode {
         ViewWindowMode::Noop
@@ -2877,7 +3372,7 @@ impl ViewWindowMode {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartAxisViewWindowOptions {
     /**
-     * The options that define a "view window" for a chart (such as the visible values in an axis).
+     * The maximum numeric value to be shown in this view window. If unset, will automatically determine a maximum value that looks good for the data.
      */
     #[serde(
         default,
@@ -2887,7 +3382,7 @@ pub struct ChartAxisViewWindowOptions {
     )]
     pub view_window_max: f64,
     /**
-     * The options that define a "view window" for a chart (such as the visible values in an axis).
+     * The minimum numeric value to be shown in this view window. If unset, will automatically determine a minimum value that looks good for the data.
      */
     #[serde(
         default,
@@ -2897,7 +3392,7 @@ pub struct ChartAxisViewWindowOptions {
     )]
     pub view_window_min: f64,
     /**
-     * The options that define a "view window" for a chart (such as the visible values in an axis).
+     * The view window's mode.
      */
     #[serde(
         default,
@@ -2911,7 +3406,7 @@ pub struct ChartAxisViewWindowOptions {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartCustomNumberFormatOptions {
     /**
-     * Custom number formatting options for chart attributes.
+     * Custom prefix to be prepended to the chart attribute. This field is optional.
      */
     #[serde(
         default,
@@ -2920,7 +3415,7 @@ pub struct ChartCustomNumberFormatOptions {
     )]
     pub prefix: String,
     /**
-     * Custom number formatting options for chart attributes.
+     * Custom suffix to be appended to the chart attribute. This field is optional.
      */
     #[serde(
         default,
@@ -2934,6 +3429,7 @@ pub struct ChartCustomNumberFormatOptions {
 * The aggregation type for the series of a data source chart. Only supported for data source charts.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AggregateType {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -2951,8 +3447,9 @@ pub enum AggregateType {
     Sum,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregateType {
@@ -2966,12 +3463,37 @@ impl std::fmt::Display for AggregateType {
             AggregateType::Min => "MIN",
             AggregateType::Sum => "SUM",
             AggregateType::Noop => "",
-            AggregateType::FallthroughString => "*",
+            AggregateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AggregateType {
+    fn from(s: String) -> AggregateType {
+        match s.as_str() {
+            "AVERAGE" => AggregateType::Average,
+            "CHART_AGGREGATE_TYPE_UNSPECIFIED" => AggregateType::ChartAggregateTypeUnspecified,
+            "COUNT" => AggregateType::Count,
+            "MAX" => AggregateType::Max,
+            "MEDIAN" => AggregateType::Median,
+            "MIN" => AggregateType::Min,
+            "SUM" => AggregateType::Sum,
+            "" => AggregateType::Noop,
+            _ => AggregateType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AggregateType> for String {
+    fn from(e: AggregateType) -> String {
+        match e {
+            AggregateType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AggregateType {
     fn default() -> AggregateType {
         AggregateType::Noop
@@ -2987,7 +3509,7 @@ impl AggregateType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartData {
     /**
-     * The data included in a domain or series.
+     * The aggregation type for the series of a data source chart. Only supported for data source charts.
      */
     #[serde(
         default,
@@ -2996,7 +3518,7 @@ pub struct ChartData {
     )]
     pub aggregate_type: Option<AggregateType>,
     /**
-     * The data included in a domain or series.
+     * An unique identifier that references a data source column.
      */
     #[serde(
         default,
@@ -3005,12 +3527,12 @@ pub struct ChartData {
     )]
     pub column_reference: Option<DataSourceColumnReference>,
     /**
-     * The data included in a domain or series.
+     * An optional setting on the ChartData of the domain of a data source chart that defines buckets for the values in the domain rather than breaking out each individual value. For example, when plotting a data source chart, you can specify a histogram rule on the domain (it should only contain numeric values), grouping its values into buckets. Any values of a chart series that fall into the same bucket are aggregated based on the aggregate_type.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupRule")]
     pub group_rule: Option<ChartGroupRule>,
     /**
-     * The data included in a domain or series.
+     * Source ranges for a chart.
      */
     #[serde(
         default,
@@ -3024,6 +3546,7 @@ pub struct ChartData {
 * The type of date-time grouping to apply.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ChartDateTimeRuleType {
     #[serde(rename = "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     ChartDateTimeRuleTypeUnspecified,
@@ -3059,8 +3582,9 @@ pub enum ChartDateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChartDateTimeRuleType {
@@ -3085,12 +3609,48 @@ impl std::fmt::Display for ChartDateTimeRuleType {
             ChartDateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             ChartDateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             ChartDateTimeRuleType::Noop => "",
-            ChartDateTimeRuleType::FallthroughString => "*",
+            ChartDateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ChartDateTimeRuleType {
+    fn from(s: String) -> ChartDateTimeRuleType {
+        match s.as_str() {
+            "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED" => {
+                ChartDateTimeRuleType::ChartDateTimeRuleTypeUnspecified
+            }
+            "DAY_MONTH" => ChartDateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => ChartDateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => ChartDateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => ChartDateTimeRuleType::DayOfYear,
+            "HOUR" => ChartDateTimeRuleType::Hour,
+            "HOUR_MINUTE" => ChartDateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => ChartDateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => ChartDateTimeRuleType::Minute,
+            "MONTH" => ChartDateTimeRuleType::Month,
+            "QUARTER" => ChartDateTimeRuleType::Quarter,
+            "SECOND" => ChartDateTimeRuleType::Second,
+            "YEAR" => ChartDateTimeRuleType::Year,
+            "YEAR_MONTH" => ChartDateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => ChartDateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => ChartDateTimeRuleType::YearQuarter,
+            "" => ChartDateTimeRuleType::Noop,
+            _ => ChartDateTimeRuleType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ChartDateTimeRuleType> for String {
+    fn from(e: ChartDateTimeRuleType) -> String {
+        match e {
+            ChartDateTimeRuleType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ChartDateTimeRuleType {
     fn default() -> ChartDateTimeRuleType {
         ChartDateTimeRuleType::Noop
@@ -3106,7 +3666,7 @@ impl ChartDateTimeRuleType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartDateTimeRule {
     /**
-     * Allows you to organize the date-time values in a source data column into buckets based on selected parts of their date or time values.
+     * The type of date-time grouping to apply.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ChartDateTimeRuleType>,
@@ -3116,7 +3676,7 @@ pub struct ChartDateTimeRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartGroupRule {
     /**
-     * An optional setting on the ChartData of the domain of a data source chart that defines buckets for the values in the domain rather than breaking out each individual value. For example, when plotting a data source chart, you can specify a histogram rule on the domain (it should only contain numeric values), grouping its values into buckets. Any values of a chart series that fall into the same bucket are aggregated based on the aggregate_type.
+     * Allows you to organize the date-time values in a source data column into buckets based on selected parts of their date or time values.
      */
     #[serde(
         default,
@@ -3125,7 +3685,7 @@ pub struct ChartGroupRule {
     )]
     pub date_time_rule: Option<ChartDateTimeRule>,
     /**
-     * An optional setting on the ChartData of the domain of a data source chart that defines buckets for the values in the domain rather than breaking out each individual value. For example, when plotting a data source chart, you can specify a histogram rule on the domain (it should only contain numeric values), grouping its values into buckets. Any values of a chart series that fall into the same bucket are aggregated based on the aggregate_type.
+     * Allows you to organize numeric values in a source data column into buckets of constant size.
      */
     #[serde(
         default,
@@ -3139,7 +3699,7 @@ pub struct ChartGroupRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartHistogramRule {
     /**
-     * Allows you to organize numeric values in a source data column into buckets of constant size.
+     * The size of the buckets that are created. Must be positive.
      */
     #[serde(
         default,
@@ -3149,7 +3709,7 @@ pub struct ChartHistogramRule {
     )]
     pub interval_size: f64,
     /**
-     * Allows you to organize numeric values in a source data column into buckets of constant size.
+     * The maximum value at which items are placed into buckets. Values greater than the maximum are grouped into a single bucket. If omitted, it is determined by the maximum item value.
      */
     #[serde(
         default,
@@ -3159,7 +3719,7 @@ pub struct ChartHistogramRule {
     )]
     pub max_value: f64,
     /**
-     * Allows you to organize numeric values in a source data column into buckets of constant size.
+     * The minimum value at which items are placed into buckets. Values that are less than the minimum are grouped into a single bucket. If omitted, it is determined by the minimum item value.
      */
     #[serde(
         default,
@@ -3174,7 +3734,7 @@ pub struct ChartHistogramRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartSourceRange {
     /**
-     * Source ranges for a chart.
+     * The ranges of data for a series or domain. Exactly one dimension must have a length of 1, and all sources in the list must have the same dimension with length 1. The domain (if it exists) & all series must have the same number of source ranges. If using more than one source range, then the source range at a given offset must be in order and contiguous across the domain and series. For example, these are valid configurations: domain sources: A1:A5 series1 sources: B1:B5 series2 sources: D6:D10 domain sources: A1:A5, C10:C12 series1 sources: B1:B5, D10:D12 series2 sources: C1:C5, E10:E12
      */
     #[serde(
         default,
@@ -3188,6 +3748,7 @@ pub struct ChartSourceRange {
 * Determines how the charts will use hidden rows or columns.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HiddenDimensionStrategy {
     #[serde(rename = "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED")]
     ChartHiddenDimensionStrategyUnspecified,
@@ -3201,8 +3762,9 @@ pub enum HiddenDimensionStrategy {
     SkipHiddenRowsAndColumns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HiddenDimensionStrategy {
@@ -3216,12 +3778,37 @@ impl std::fmt::Display for HiddenDimensionStrategy {
             HiddenDimensionStrategy::SkipHiddenRows => "SKIP_HIDDEN_ROWS",
             HiddenDimensionStrategy::SkipHiddenRowsAndColumns => "SKIP_HIDDEN_ROWS_AND_COLUMNS",
             HiddenDimensionStrategy::Noop => "",
-            HiddenDimensionStrategy::FallthroughString => "*",
+            HiddenDimensionStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for HiddenDimensionStrategy {
+    fn from(s: String) -> HiddenDimensionStrategy {
+        match s.as_str() {
+            "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED" => {
+                HiddenDimensionStrategy::ChartHiddenDimensionStrategyUnspecified
+            }
+            "SHOW_ALL" => HiddenDimensionStrategy::ShowAll,
+            "SKIP_HIDDEN_COLUMNS" => HiddenDimensionStrategy::SkipHiddenColumns,
+            "SKIP_HIDDEN_ROWS" => HiddenDimensionStrategy::SkipHiddenRows,
+            "SKIP_HIDDEN_ROWS_AND_COLUMNS" => HiddenDimensionStrategy::SkipHiddenRowsAndColumns,
+            "" => HiddenDimensionStrategy::Noop,
+            _ => HiddenDimensionStrategy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<HiddenDimensionStrategy> for String {
+    fn from(e: HiddenDimensionStrategy) -> String {
+        match e {
+            HiddenDimensionStrategy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for HiddenDimensionStrategy {
     fn default() -> HiddenDimensionStrategy {
         HiddenDimensionStrategy::Noop
@@ -3237,7 +3824,7 @@ impl HiddenDimensionStrategy {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ChartSpec {
     /**
-     * The specifications of a chart.
+     * The alternative text that describes the chart. This is often used for accessibility.
      */
     #[serde(
         default,
@@ -3247,7 +3834,7 @@ pub struct ChartSpec {
     )]
     pub alt_text: String,
     /**
-     * The specifications of a chart.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -3256,7 +3843,7 @@ pub struct ChartSpec {
     )]
     pub background_color: Option<Color>,
     /**
-     * The specifications of a chart.
+     * A color value.
      */
     #[serde(
         default,
@@ -3265,7 +3852,7 @@ pub struct ChartSpec {
     )]
     pub background_color_style: Option<ColorStyle>,
     /**
-     * The specifications of a chart.
+     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
     #[serde(
         default,
@@ -3274,7 +3861,7 @@ pub struct ChartSpec {
     )]
     pub basic_chart: Option<BasicChartSpec>,
     /**
-     * The specifications of a chart.
+     * A bubble chart.
      */
     #[serde(
         default,
@@ -3283,7 +3870,7 @@ pub struct ChartSpec {
     )]
     pub bubble_chart: Option<BubbleChartSpec>,
     /**
-     * The specifications of a chart.
+     * A candlestick chart.
      */
     #[serde(
         default,
@@ -3292,7 +3879,7 @@ pub struct ChartSpec {
     )]
     pub candlestick_chart: Option<CandlestickChartSpec>,
     /**
-     * The specifications of a chart.
+     * Properties of a data source chart.
      */
     #[serde(
         default,
@@ -3301,7 +3888,7 @@ pub struct ChartSpec {
     )]
     pub data_source_chart_properties: Option<DataSourceChartProperties>,
     /**
-     * The specifications of a chart.
+     * The filters applied to the source data of the chart. Only supported for data source charts.
      */
     #[serde(
         default,
@@ -3311,7 +3898,7 @@ pub struct ChartSpec {
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
-     * The specifications of a chart.
+     * The name of the font to use by default for all chart text (e.g. title, axis labels, legend). If a font is specified for a specific part of the chart it will override this font name.
      */
     #[serde(
         default,
@@ -3321,7 +3908,7 @@ pub struct ChartSpec {
     )]
     pub font_name: String,
     /**
-     * The specifications of a chart.
+     * Determines how the charts will use hidden rows or columns.
      */
     #[serde(
         default,
@@ -3330,7 +3917,7 @@ pub struct ChartSpec {
     )]
     pub hidden_dimension_strategy: Option<HiddenDimensionStrategy>,
     /**
-     * The specifications of a chart.
+     * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
      */
     #[serde(
         default,
@@ -3339,7 +3926,7 @@ pub struct ChartSpec {
     )]
     pub histogram_chart: Option<HistogramChartSpec>,
     /**
-     * The specifications of a chart.
+     * True to make a chart fill the entire space in which it's rendered with minimum padding. False to use the default padding. (Not applicable to Geo and Org charts.)
      */
     #[serde(
         default,
@@ -3347,17 +3934,17 @@ pub struct ChartSpec {
     )]
     pub maximized: bool,
     /**
-     * The specifications of a chart.
+     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "orgChart")]
     pub org_chart: Option<OrgChartSpec>,
     /**
-     * The specifications of a chart.
+     * A pie chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "pieChart")]
     pub pie_chart: Option<PieChartSpec>,
     /**
-     * The specifications of a chart.
+     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
     #[serde(
         default,
@@ -3366,7 +3953,7 @@ pub struct ChartSpec {
     )]
     pub scorecard_chart: Option<ScorecardChartSpec>,
     /**
-     * The specifications of a chart.
+     * The order to sort the chart data by. Only a single sort spec is supported. Only supported for data source charts.
      */
     #[serde(
         default,
@@ -3376,7 +3963,7 @@ pub struct ChartSpec {
     )]
     pub sort_specs: Vec<SortSpec>,
     /**
-     * The specifications of a chart.
+     * The subtitle of the chart.
      */
     #[serde(
         default,
@@ -3385,7 +3972,7 @@ pub struct ChartSpec {
     )]
     pub subtitle: String,
     /**
-     * The specifications of a chart.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -3394,7 +3981,7 @@ pub struct ChartSpec {
     )]
     pub subtitle_text_format: Option<TextFormat>,
     /**
-     * The specifications of a chart.
+     * Position settings for text.
      */
     #[serde(
         default,
@@ -3403,7 +3990,7 @@ pub struct ChartSpec {
     )]
     pub subtitle_text_position: Option<TextPosition>,
     /**
-     * The specifications of a chart.
+     * The title of the chart.
      */
     #[serde(
         default,
@@ -3412,7 +3999,7 @@ pub struct ChartSpec {
     )]
     pub title: String,
     /**
-     * The specifications of a chart.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -3421,7 +4008,7 @@ pub struct ChartSpec {
     )]
     pub title_text_format: Option<TextFormat>,
     /**
-     * The specifications of a chart.
+     * Position settings for text.
      */
     #[serde(
         default,
@@ -3430,7 +4017,7 @@ pub struct ChartSpec {
     )]
     pub title_text_position: Option<TextPosition>,
     /**
-     * The specifications of a chart.
+     * A Treemap chart.
      */
     #[serde(
         default,
@@ -3439,7 +4026,7 @@ pub struct ChartSpec {
     )]
     pub treemap_chart: Option<TreemapChartSpec>,
     /**
-     * The specifications of a chart.
+     * A waterfall chart.
      */
     #[serde(
         default,
@@ -3453,7 +4040,7 @@ pub struct ChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ClearBasicFilterRequest {
     /**
-     * Clears the basic filter, if any exists on the sheet.
+     * The sheet ID on which the basic filter should be cleared.
      */
     #[serde(
         default,
@@ -3472,7 +4059,7 @@ pub struct ClearValuesRequest {}
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ClearValuesResponse {
     /**
-     * The response when clearing a range of values in a spreadsheet.
+     * The range (in A1 notation) that was cleared. (If the request was for an unbounded range or a ranger larger than the bounds of the sheet, this will be the actual range that was cleared, bounded to the sheet's limits.)
      */
     #[serde(
         default,
@@ -3482,7 +4069,7 @@ pub struct ClearValuesResponse {
     )]
     pub cleared_range: String,
     /**
-     * The response when clearing a range of values in a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -3497,7 +4084,7 @@ pub struct ClearValuesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Color {
     /**
-     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
+     * The fraction of this color that should be applied to the pixel. That is, the final pixel color is defined by the equation: `pixel color = alpha \* (this color) + (1.0 - alpha) \* (background color)` This means that a value of 1.0 corresponds to a solid color, whereas a value of 0.0 corresponds to a completely transparent color. This uses a wrapper message rather than a simple float scalar so that it is possible to distinguish between a default value and the value being unset. If omitted, this color object is rendered as a solid color (as if the alpha value had been explicitly given a value of 1.0).
      */
     #[serde(
         default,
@@ -3506,7 +4093,7 @@ pub struct Color {
     )]
     pub alpha: f64,
     /**
-     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
+     * The amount of blue in the color as a value in the interval [0, 1].
      */
     #[serde(
         default,
@@ -3515,7 +4102,7 @@ pub struct Color {
     )]
     pub blue: f64,
     /**
-     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
+     * The amount of green in the color as a value in the interval [0, 1].
      */
     #[serde(
         default,
@@ -3524,7 +4111,7 @@ pub struct Color {
     )]
     pub green: f64,
     /**
-     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
+     * The amount of red in the color as a value in the interval [0, 1].
      */
     #[serde(
         default,
@@ -3538,6 +4125,7 @@ pub struct Color {
 * The type of the spreadsheet theme color.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ColorType {
     #[serde(rename = "ACCENT1")]
     Accent1,
@@ -3561,8 +4149,9 @@ pub enum ColorType {
     ThemeColorTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColorType {
@@ -3579,12 +4168,40 @@ impl std::fmt::Display for ColorType {
             ColorType::Text => "TEXT",
             ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
             ColorType::Noop => "",
-            ColorType::FallthroughString => "*",
+            ColorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ColorType {
+    fn from(s: String) -> ColorType {
+        match s.as_str() {
+            "ACCENT1" => ColorType::Accent1,
+            "ACCENT2" => ColorType::Accent2,
+            "ACCENT3" => ColorType::Accent3,
+            "ACCENT4" => ColorType::Accent4,
+            "ACCENT5" => ColorType::Accent5,
+            "ACCENT6" => ColorType::Accent6,
+            "BACKGROUND" => ColorType::Background,
+            "LINK" => ColorType::Link,
+            "TEXT" => ColorType::Text,
+            "THEME_COLOR_TYPE_UNSPECIFIED" => ColorType::ThemeColorTypeUnspecified,
+            "" => ColorType::Noop,
+            _ => ColorType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ColorType> for String {
+    fn from(e: ColorType) -> String {
+        match e {
+            ColorType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ColorType {
     fn default() -> ColorType {
         ColorType::Noop
@@ -3600,12 +4217,12 @@ impl ColorType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ColorStyle {
     /**
-     * A color value.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "rgbColor")]
     pub rgb_color: Option<Color>,
     /**
-     * A color value.
+     * Theme color.
      */
     #[serde(
         default,
@@ -3619,6 +4236,7 @@ pub struct ColorStyle {
 * A relative date (based on the current date). Valid only if the type is DATE_BEFORE, DATE_AFTER, DATE_ON_OR_BEFORE or DATE_ON_OR_AFTER. Relative dates are not supported in data validation. They are supported only in conditional formatting and conditional filters.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RelativeDate {
     #[serde(rename = "PAST_MONTH")]
     PastMonth,
@@ -3636,8 +4254,9 @@ pub enum RelativeDate {
     Yesterday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RelativeDate {
@@ -3651,12 +4270,37 @@ impl std::fmt::Display for RelativeDate {
             RelativeDate::Tomorrow => "TOMORROW",
             RelativeDate::Yesterday => "YESTERDAY",
             RelativeDate::Noop => "",
-            RelativeDate::FallthroughString => "*",
+            RelativeDate::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RelativeDate {
+    fn from(s: String) -> RelativeDate {
+        match s.as_str() {
+            "PAST_MONTH" => RelativeDate::PastMonth,
+            "PAST_WEEK" => RelativeDate::PastWeek,
+            "PAST_YEAR" => RelativeDate::PastYear,
+            "RELATIVE_DATE_UNSPECIFIED" => RelativeDate::RelativeDateUnspecified,
+            "TODAY" => RelativeDate::Today,
+            "TOMORROW" => RelativeDate::Tomorrow,
+            "YESTERDAY" => RelativeDate::Yesterday,
+            "" => RelativeDate::Noop,
+            _ => RelativeDate::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RelativeDate> for String {
+    fn from(e: RelativeDate) -> String {
+        match e {
+            RelativeDate::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RelativeDate {
     fn default() -> RelativeDate {
         RelativeDate::Noop
@@ -3672,7 +4316,7 @@ impl RelativeDate {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConditionValue {
     /**
-     * The value of the condition.
+     * A relative date (based on the current date). Valid only if the type is DATE_BEFORE, DATE_AFTER, DATE_ON_OR_BEFORE or DATE_ON_OR_AFTER. Relative dates are not supported in data validation. They are supported only in conditional formatting and conditional filters.
      */
     #[serde(
         default,
@@ -3681,7 +4325,7 @@ pub struct ConditionValue {
     )]
     pub relative_date: Option<RelativeDate>,
     /**
-     * The value of the condition.
+     * A value the condition is based on. The value is parsed as if the user typed into a cell. Formulas are supported (and must begin with an `=` or a '+').
      */
     #[serde(
         default,
@@ -3696,7 +4340,7 @@ pub struct ConditionValue {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ConditionalFormatRule {
     /**
-     * A rule describing a conditional format.
+     * A rule that may or may not match, depending on the condition.
      */
     #[serde(
         default,
@@ -3705,7 +4349,7 @@ pub struct ConditionalFormatRule {
     )]
     pub boolean_rule: Option<BooleanRule>,
     /**
-     * A rule describing a conditional format.
+     * A rule that applies a gradient color scale format, based on the interpolation points listed. The format of a cell will vary based on its contents as compared to the values of the interpolation points.
      */
     #[serde(
         default,
@@ -3714,7 +4358,7 @@ pub struct ConditionalFormatRule {
     )]
     pub gradient_rule: Option<GradientRule>,
     /**
-     * A rule describing a conditional format.
+     * The ranges that are formatted if the condition is true. All the ranges must be on the same grid.
      */
     #[serde(
         default,
@@ -3728,6 +4372,7 @@ pub struct ConditionalFormatRule {
 * How that data should be oriented when pasting.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PasteOrientation {
     #[serde(rename = "NORMAL")]
     Normal,
@@ -3735,8 +4380,9 @@ pub enum PasteOrientation {
     Transpose,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteOrientation {
@@ -3745,12 +4391,32 @@ impl std::fmt::Display for PasteOrientation {
             PasteOrientation::Normal => "NORMAL",
             PasteOrientation::Transpose => "TRANSPOSE",
             PasteOrientation::Noop => "",
-            PasteOrientation::FallthroughString => "*",
+            PasteOrientation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PasteOrientation {
+    fn from(s: String) -> PasteOrientation {
+        match s.as_str() {
+            "NORMAL" => PasteOrientation::Normal,
+            "TRANSPOSE" => PasteOrientation::Transpose,
+            "" => PasteOrientation::Noop,
+            _ => PasteOrientation::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PasteOrientation> for String {
+    fn from(e: PasteOrientation) -> String {
+        match e {
+            PasteOrientation::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PasteOrientation {
     fn default() -> PasteOrientation {
         PasteOrientation::Noop
@@ -3766,6 +4432,7 @@ impl PasteOrientation {
 * What kind of data to paste.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PasteType {
     #[serde(rename = "PASTE_CONDITIONAL_FORMATTING")]
     PasteConditionalFormatting,
@@ -3783,8 +4450,9 @@ pub enum PasteType {
     PasteValues,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasteType {
@@ -3798,9 +4466,34 @@ impl std::fmt::Display for PasteType {
             PasteType::PasteNoBorders => "PASTE_NO_BORDERS",
             PasteType::PasteValues => "PASTE_VALUES",
             PasteType::Noop => "",
-            PasteType::FallthroughString => "*",
+            PasteType::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::convert::From<String> for PasteType {
+    fn from(s: String) -> PasteType {
+        match s.as_str() {
+            "PASTE_CONDITIONAL_FORMATTING" => PasteType::PasteConditionalFormatting,
+            "PASTE_DATA_VALIDATION" => PasteType::PasteDataValidation,
+            "PASTE_FORMAT" => PasteType::PasteFormat,
+            "PASTE_FORMULA" => PasteType::PasteFormula,
+            "PASTE_NORMAL" => PasteType::PasteNormal,
+            "PASTE_NO_BORDERS" => PasteType::PasteNoBorders,
+            "PASTE_VALUES" => PasteType::PasteValues,
+            "" => PasteType::Noop,
+            _ => PasteType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PasteType> for String {
+    fn from(e: PasteType) -> String {
+        match e {
+            PasteType::Unknown(s) => s,
+            e => e.to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -3819,12 +4512,12 @@ impl PasteType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CopyPasteRequest {
     /**
-     * Copies data from the source to the destination.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub destination: Option<GridRange>,
     /**
-     * Copies data from the source to the destination.
+     * How that data should be oriented when pasting.
      */
     #[serde(
         default,
@@ -3833,12 +4526,12 @@ pub struct CopyPasteRequest {
     )]
     pub paste_orientation: Option<PasteOrientation>,
     /**
-     * Copies data from the source to the destination.
+     * What kind of data to paste.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "pasteType")]
     pub paste_type: Option<PasteType>,
     /**
-     * Copies data from the source to the destination.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
@@ -3848,7 +4541,7 @@ pub struct CopyPasteRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CopySheetAnotherSpreadsheetRequest {
     /**
-     * The request to copy a sheet across spreadsheets.
+     * The ID of the spreadsheet to copy the sheet to.
      */
     #[serde(
         default,
@@ -3863,7 +4556,7 @@ pub struct CopySheetAnotherSpreadsheetRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateDeveloperMetadataRequest {
     /**
-     * A request to create developer metadata.
+     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
      */
     #[serde(
         default,
@@ -3877,7 +4570,7 @@ pub struct CreateDeveloperMetadataRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateDeveloperMetadataResponse {
     /**
-     * The response from creating developer metadata.
+     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
      */
     #[serde(
         default,
@@ -3891,17 +4584,17 @@ pub struct CreateDeveloperMetadataResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CutPasteRequest {
     /**
-     * Moves data from the source to the destination.
+     * A coordinate in a sheet. All indexes are zero-based.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub destination: Option<GridCoordinate>,
     /**
-     * Moves data from the source to the destination.
+     * What kind of data to paste. All the source data will be cut, regardless of what is pasted.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "pasteType")]
     pub paste_type: Option<PasteType>,
     /**
-     * Moves data from the source to the destination.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
@@ -3911,6 +4604,7 @@ pub struct CutPasteRequest {
 * The error code.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorCode {
     #[serde(rename = "CONCURRENT_QUERY")]
     ConcurrentQuery,
@@ -3950,8 +4644,9 @@ pub enum ErrorCode {
     UnsupportedDataType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -3976,12 +4671,48 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::TooManyRows => "TOO_MANY_ROWS",
             ErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ErrorCode {
+    fn from(s: String) -> ErrorCode {
+        match s.as_str() {
+            "CONCURRENT_QUERY" => ErrorCode::ConcurrentQuery,
+            "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED" => ErrorCode::DataExecutionErrorCodeUnspecified,
+            "DATA_NOT_FOUND" => ErrorCode::DataNotFound,
+            "DUPLICATE_COLUMN_NAMES" => ErrorCode::DuplicateColumnNames,
+            "ENGINE" => ErrorCode::Engine,
+            "INTERRUPTED" => ErrorCode::Interrupted,
+            "MISSING_COLUMN_ALIAS" => ErrorCode::MissingColumnAlias,
+            "OBJECT_IN_ERROR_STATE" => ErrorCode::ObjectInErrorState,
+            "OBJECT_NOT_FOUND" => ErrorCode::ObjectNotFound,
+            "OBJECT_SPEC_INVALID" => ErrorCode::ObjectSpecInvalid,
+            "OTHER" => ErrorCode::Other,
+            "PARAMETER_INVALID" => ErrorCode::ParameterInvalid,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "TIMED_OUT" => ErrorCode::TimedOut,
+            "TOO_MANY_CELLS" => ErrorCode::TooManyCells,
+            "TOO_MANY_CHARS_PER_CELL" => ErrorCode::TooManyCharsPerCell,
+            "TOO_MANY_ROWS" => ErrorCode::TooManyRows,
+            "UNSUPPORTED_DATA_TYPE" => ErrorCode::UnsupportedDataType,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ErrorCode> for String {
+    fn from(e: ErrorCode) -> String {
+        match e {
+            ErrorCode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -3997,6 +4728,7 @@ impl ErrorCode {
 * The state of the data execution.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum State {
     #[serde(rename = "DATA_EXECUTION_STATE_UNSPECIFIED")]
     DataExecutionStateUnspecified,
@@ -4010,8 +4742,9 @@ pub enum State {
     Succeeded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for State {
@@ -4023,12 +4756,35 @@ impl std::fmt::Display for State {
             State::Running => "RUNNING",
             State::Succeeded => "SUCCEEDED",
             State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for State {
+    fn from(s: String) -> State {
+        match s.as_str() {
+            "DATA_EXECUTION_STATE_UNSPECIFIED" => State::DataExecutionStateUnspecified,
+            "FAILED" => State::Failed,
+            "NOT_STARTED" => State::NotStarted,
+            "RUNNING" => State::Running,
+            "SUCCEEDED" => State::Succeeded,
+            "" => State::Noop,
+            _ => State::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<State> for String {
+    fn from(e: State) -> String {
+        match e {
+            State::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for State {
     fn default() -> State {
         State::Noop
@@ -4044,12 +4800,12 @@ impl State {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataExecutionStatus {
     /**
-     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
+     * The error code.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "errorCode")]
     pub error_code: Option<ErrorCode>,
     /**
-     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
+     * The error message, which may be empty.
      */
     #[serde(
         default,
@@ -4059,7 +4815,7 @@ pub struct DataExecutionStatus {
     )]
     pub error_message: String,
     /**
-     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
+     * Gets the time the data last successfully refreshed.
      */
     #[serde(
         default,
@@ -4069,7 +4825,7 @@ pub struct DataExecutionStatus {
     )]
     pub last_refresh_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
+     * The state of the data execution.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
@@ -4079,7 +4835,7 @@ pub struct DataExecutionStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataFilter {
     /**
-     * Filter that describes what data should be selected or returned from a request.
+     * Selects data that matches the specified A1 range.
      */
     #[serde(
         default,
@@ -4089,7 +4845,7 @@ pub struct DataFilter {
     )]
     pub a_1_range: String,
     /**
-     * Filter that describes what data should be selected or returned from a request.
+     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
      */
     #[serde(
         default,
@@ -4098,7 +4854,7 @@ pub struct DataFilter {
     )]
     pub developer_metadata_lookup: Option<DeveloperMetadataLookup>,
     /**
-     * Filter that describes what data should be selected or returned from a request.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "gridRange")]
     pub grid_range: Option<GridRange>,
@@ -4108,7 +4864,7 @@ pub struct DataFilter {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataFilterValueRange {
     /**
-     * A range of values whose location is specified by a DataFilter.
+     * Filter that describes what data should be selected or returned from a request.
      */
     #[serde(
         default,
@@ -4117,7 +4873,7 @@ pub struct DataFilterValueRange {
     )]
     pub data_filter: Option<DataFilter>,
     /**
-     * A range of values whose location is specified by a DataFilter.
+     * The major dimension of the values.
      */
     #[serde(
         default,
@@ -4126,7 +4882,7 @@ pub struct DataFilterValueRange {
     )]
     pub major_dimension: Option<Dimension>,
     /**
-     * A range of values whose location is specified by a DataFilter.
+     * The data to be written. If the provided values exceed any of the ranges matched by the data filter then the request fails. If the provided values are less than the matched ranges only the specified values are written, existing values in the matched ranges remain unaffected.
      */
     #[serde(
         default,
@@ -4140,6 +4896,7 @@ pub struct DataFilterValueRange {
 * The placement of the data label relative to the labeled data.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Placement {
     #[serde(rename = "ABOVE")]
     Above,
@@ -4161,8 +4918,9 @@ pub enum Placement {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Placement {
@@ -4178,12 +4936,39 @@ impl std::fmt::Display for Placement {
             Placement::OutsideEnd => "OUTSIDE_END",
             Placement::Right => "RIGHT",
             Placement::Noop => "",
-            Placement::FallthroughString => "*",
+            Placement::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Placement {
+    fn from(s: String) -> Placement {
+        match s.as_str() {
+            "ABOVE" => Placement::Above,
+            "BELOW" => Placement::Below,
+            "CENTER" => Placement::Center,
+            "DATA_LABEL_PLACEMENT_UNSPECIFIED" => Placement::DataLabelPlacementUnspecified,
+            "INSIDE_BASE" => Placement::InsideBase,
+            "INSIDE_END" => Placement::InsideEnd,
+            "LEFT" => Placement::Left,
+            "OUTSIDE_END" => Placement::OutsideEnd,
+            "RIGHT" => Placement::Right,
+            "" => Placement::Noop,
+            _ => Placement::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Placement> for String {
+    fn from(e: Placement) -> String {
+        match e {
+            Placement::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Placement {
     fn default() -> Placement {
         Placement::Noop
@@ -4199,6 +4984,7 @@ impl Placement {
 * The type of the data label.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DataLabelType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -4210,8 +4996,9 @@ pub enum DataLabelType {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DataLabelType {
@@ -4222,12 +5009,34 @@ impl std::fmt::Display for DataLabelType {
             DataLabelType::DataLabelTypeUnspecified => "DATA_LABEL_TYPE_UNSPECIFIED",
             DataLabelType::None => "NONE",
             DataLabelType::Noop => "",
-            DataLabelType::FallthroughString => "*",
+            DataLabelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DataLabelType {
+    fn from(s: String) -> DataLabelType {
+        match s.as_str() {
+            "CUSTOM" => DataLabelType::Custom,
+            "DATA" => DataLabelType::Data,
+            "DATA_LABEL_TYPE_UNSPECIFIED" => DataLabelType::DataLabelTypeUnspecified,
+            "NONE" => DataLabelType::None,
+            "" => DataLabelType::Noop,
+            _ => DataLabelType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DataLabelType> for String {
+    fn from(e: DataLabelType) -> String {
+        match e {
+            DataLabelType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DataLabelType {
     fn default() -> DataLabelType {
         DataLabelType::Noop
@@ -4243,7 +5052,7 @@ impl DataLabelType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataLabel {
     /**
-     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
+     * The data included in a domain or series.
      */
     #[serde(
         default,
@@ -4252,12 +5061,12 @@ pub struct DataLabel {
     )]
     pub custom_label_data: Option<ChartData>,
     /**
-     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
+     * The placement of the data label relative to the labeled data.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub placement: Option<Placement>,
     /**
-     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -4266,7 +5075,7 @@ pub struct DataLabel {
     )]
     pub text_format: Option<TextFormat>,
     /**
-     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
+     * The type of the data label.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DataLabelType>,
@@ -4276,7 +5085,7 @@ pub struct DataLabel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSource {
     /**
-     * Information about an external data source in the spreadsheet.
+     * All calculated columns in the data source.
      */
     #[serde(
         default,
@@ -4286,7 +5095,7 @@ pub struct DataSource {
     )]
     pub calculated_columns: Vec<DataSourceColumn>,
     /**
-     * Information about an external data source in the spreadsheet.
+     * The spreadsheet-scoped unique ID that identifies the data source. Example: 1080547365.
      */
     #[serde(
         default,
@@ -4296,7 +5105,7 @@ pub struct DataSource {
     )]
     pub data_source_id: String,
     /**
-     * Information about an external data source in the spreadsheet.
+     * The ID of the Sheet connected with the data source. The field cannot be changed once set. When creating a data source, an associated DATA_SOURCE sheet is also created, if the field is not specified, the ID of the created sheet will be randomly generated.
      */
     #[serde(
         default,
@@ -4306,7 +5115,7 @@ pub struct DataSource {
     )]
     pub sheet_id: i64,
     /**
-     * Information about an external data source in the spreadsheet.
+     * This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<DataSourceSpec>,
@@ -4316,7 +5125,7 @@ pub struct DataSource {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceChartProperties {
     /**
-     * Properties of a data source chart.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -4325,7 +5134,7 @@ pub struct DataSourceChartProperties {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * Properties of a data source chart.
+     * ID of the data source that the chart is associated with.
      */
     #[serde(
         default,
@@ -4340,7 +5149,7 @@ pub struct DataSourceChartProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceColumn {
     /**
-     * A column in a data source.
+     * The formula of the calculated column.
      */
     #[serde(
         default,
@@ -4349,7 +5158,7 @@ pub struct DataSourceColumn {
     )]
     pub formula: String,
     /**
-     * A column in a data source.
+     * An unique identifier that references a data source column.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference: Option<DataSourceColumnReference>,
@@ -4359,7 +5168,7 @@ pub struct DataSourceColumn {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceColumnReference {
     /**
-     * An unique identifier that references a data source column.
+     * The display name of the column. It should be unique within a data source.
      */
     #[serde(
         default,
@@ -4373,7 +5182,7 @@ pub struct DataSourceColumnReference {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceFormula {
     /**
-     * A data source formula.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -4382,7 +5191,7 @@ pub struct DataSourceFormula {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * A data source formula.
+     * The ID of the data source the formula is associated with.
      */
     #[serde(
         default,
@@ -4397,7 +5206,7 @@ pub struct DataSourceFormula {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceObjectReference {
     /**
-     * Reference to a data source object.
+     * References to a data source chart.
      */
     #[serde(
         default,
@@ -4407,7 +5216,7 @@ pub struct DataSourceObjectReference {
     )]
     pub chart_id: i64,
     /**
-     * Reference to a data source object.
+     * A coordinate in a sheet. All indexes are zero-based.
      */
     #[serde(
         default,
@@ -4416,7 +5225,7 @@ pub struct DataSourceObjectReference {
     )]
     pub data_source_formula_cell: Option<GridCoordinate>,
     /**
-     * Reference to a data source object.
+     * A coordinate in a sheet. All indexes are zero-based.
      */
     #[serde(
         default,
@@ -4425,7 +5234,7 @@ pub struct DataSourceObjectReference {
     )]
     pub data_source_pivot_table_anchor_cell: Option<GridCoordinate>,
     /**
-     * Reference to a data source object.
+     * A coordinate in a sheet. All indexes are zero-based.
      */
     #[serde(
         default,
@@ -4434,7 +5243,7 @@ pub struct DataSourceObjectReference {
     )]
     pub data_source_table_anchor_cell: Option<GridCoordinate>,
     /**
-     * Reference to a data source object.
+     * References to a DATA_SOURCE sheet.
      */
     #[serde(
         default,
@@ -4449,7 +5258,7 @@ pub struct DataSourceObjectReference {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceObjectReferences {
     /**
-     * A list of references to data source objects.
+     * The references.
      */
     #[serde(
         default,
@@ -4463,7 +5272,7 @@ pub struct DataSourceObjectReferences {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceParameter {
     /**
-     * A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
+     * Named parameter. Must be a legitimate identifier for the DataSource that supports it. For example, [BigQuery identifier](https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#identifiers).
      */
     #[serde(
         default,
@@ -4472,7 +5281,7 @@ pub struct DataSourceParameter {
     )]
     pub name: String,
     /**
-     * A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
+     * ID of a NamedRange. Its size must be 1x1.
      */
     #[serde(
         default,
@@ -4482,7 +5291,7 @@ pub struct DataSourceParameter {
     )]
     pub named_range_id: String,
     /**
-     * A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -4492,7 +5301,7 @@ pub struct DataSourceParameter {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceRefreshDailySchedule {
     /**
-     * A schedule for data to refresh every day in a given time interval.
+     * Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
     pub start_time: Option<TimeOfDay>,
@@ -4502,7 +5311,7 @@ pub struct DataSourceRefreshDailySchedule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceRefreshMonthlySchedule {
     /**
-     * A monthly schedule for data to refresh on specific days in the month in a given time interval.
+     * Days of the month to refresh. Only 1-28 are supported, mapping to the 1st to the 28th day. At lesat one day must be specified.
      */
     #[serde(
         default,
@@ -4512,7 +5321,7 @@ pub struct DataSourceRefreshMonthlySchedule {
     )]
     pub days_of_month: Vec<i64>,
     /**
-     * A monthly schedule for data to refresh on specific days in the month in a given time interval.
+     * Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
     pub start_time: Option<TimeOfDay>,
@@ -4522,6 +5331,7 @@ pub struct DataSourceRefreshMonthlySchedule {
 * The scope of the refresh. Must be ALL_DATA_SOURCES.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RefreshScope {
     #[serde(rename = "ALL_DATA_SOURCES")]
     AllDataSources,
@@ -4529,8 +5339,9 @@ pub enum RefreshScope {
     DataSourceRefreshScopeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RefreshScope {
@@ -4541,12 +5352,34 @@ impl std::fmt::Display for RefreshScope {
                 "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"
             }
             RefreshScope::Noop => "",
-            RefreshScope::FallthroughString => "*",
+            RefreshScope::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RefreshScope {
+    fn from(s: String) -> RefreshScope {
+        match s.as_str() {
+            "ALL_DATA_SOURCES" => RefreshScope::AllDataSources,
+            "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED" => {
+                RefreshScope::DataSourceRefreshScopeUnspecified
+            }
+            "" => RefreshScope::Noop,
+            _ => RefreshScope::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RefreshScope> for String {
+    fn from(e: RefreshScope) -> String {
+        match e {
+            RefreshScope::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RefreshScope {
     fn default() -> RefreshScope {
         RefreshScope::Noop
@@ -4562,7 +5395,7 @@ impl RefreshScope {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceRefreshSchedule {
     /**
-     * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
+     * A schedule for data to refresh every day in a given time interval.
      */
     #[serde(
         default,
@@ -4571,7 +5404,7 @@ pub struct DataSourceRefreshSchedule {
     )]
     pub daily_schedule: Option<DataSourceRefreshDailySchedule>,
     /**
-     * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
+     * True if the refresh schedule is enabled, or false otherwise.
      */
     #[serde(
         default,
@@ -4579,7 +5412,7 @@ pub struct DataSourceRefreshSchedule {
     )]
     pub enabled: bool,
     /**
-     * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
+     * A monthly schedule for data to refresh on specific days in the month in a given time interval.
      */
     #[serde(
         default,
@@ -4588,12 +5421,12 @@ pub struct DataSourceRefreshSchedule {
     )]
     pub monthly_schedule: Option<DataSourceRefreshMonthlySchedule>,
     /**
-     * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
+     * Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive). The start must be less than or equal to the end. When the start equals the end, the interval is empty (matches no time). When both start and end are unspecified, the interval matches any time.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "nextRun")]
     pub next_run: Option<Interval>,
     /**
-     * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
+     * The scope of the refresh. Must be ALL_DATA_SOURCES.
      */
     #[serde(
         default,
@@ -4602,7 +5435,7 @@ pub struct DataSourceRefreshSchedule {
     )]
     pub refresh_scope: Option<RefreshScope>,
     /**
-     * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
+     * A weekly schedule for data to refresh on specific days in a given time interval.
      */
     #[serde(
         default,
@@ -4613,6 +5446,7 @@ pub struct DataSourceRefreshSchedule {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DaysOfWeek {
     #[serde(rename = "DAY_OF_WEEK_UNSPECIFIED")]
     DayOfWeekUnspecified,
@@ -4632,8 +5466,9 @@ pub enum DaysOfWeek {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DaysOfWeek {
@@ -4648,12 +5483,38 @@ impl std::fmt::Display for DaysOfWeek {
             DaysOfWeek::Tuesday => "TUESDAY",
             DaysOfWeek::Wednesday => "WEDNESDAY",
             DaysOfWeek::Noop => "",
-            DaysOfWeek::FallthroughString => "*",
+            DaysOfWeek::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DaysOfWeek {
+    fn from(s: String) -> DaysOfWeek {
+        match s.as_str() {
+            "DAY_OF_WEEK_UNSPECIFIED" => DaysOfWeek::DayOfWeekUnspecified,
+            "FRIDAY" => DaysOfWeek::Friday,
+            "MONDAY" => DaysOfWeek::Monday,
+            "SATURDAY" => DaysOfWeek::Saturday,
+            "SUNDAY" => DaysOfWeek::Sunday,
+            "THURSDAY" => DaysOfWeek::Thursday,
+            "TUESDAY" => DaysOfWeek::Tuesday,
+            "WEDNESDAY" => DaysOfWeek::Wednesday,
+            "" => DaysOfWeek::Noop,
+            _ => DaysOfWeek::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DaysOfWeek> for String {
+    fn from(e: DaysOfWeek) -> String {
+        match e {
+            DaysOfWeek::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DaysOfWeek {
     fn default() -> DaysOfWeek {
         DaysOfWeek::Noop
@@ -4669,7 +5530,7 @@ impl DaysOfWeek {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceRefreshWeeklySchedule {
     /**
-     * A weekly schedule for data to refresh on specific days in a given time interval.
+     * Days of the week to refresh. At least one day must be specified.
      */
     #[serde(
         default,
@@ -4679,7 +5540,7 @@ pub struct DataSourceRefreshWeeklySchedule {
     )]
     pub days_of_week: Vec<DaysOfWeek>,
     /**
-     * A weekly schedule for data to refresh on specific days in a given time interval.
+     * Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
     pub start_time: Option<TimeOfDay>,
@@ -4689,7 +5550,7 @@ pub struct DataSourceRefreshWeeklySchedule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceSheetDimensionRange {
     /**
-     * A range along a single dimension on a DATA_SOURCE sheet.
+     * The columns on the data source sheet.
      */
     #[serde(
         default,
@@ -4699,7 +5560,7 @@ pub struct DataSourceSheetDimensionRange {
     )]
     pub column_references: Vec<DataSourceColumnReference>,
     /**
-     * A range along a single dimension on a DATA_SOURCE sheet.
+     * The ID of the data source sheet the range is on.
      */
     #[serde(
         default,
@@ -4714,7 +5575,7 @@ pub struct DataSourceSheetDimensionRange {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceSheetProperties {
     /**
-     * Additional properties of a DATA_SOURCE sheet.
+     * The columns displayed on the sheet, corresponding to the values in RowData.
      */
     #[serde(
         default,
@@ -4723,7 +5584,7 @@ pub struct DataSourceSheetProperties {
     )]
     pub columns: Vec<DataSourceColumn>,
     /**
-     * Additional properties of a DATA_SOURCE sheet.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -4732,7 +5593,7 @@ pub struct DataSourceSheetProperties {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * Additional properties of a DATA_SOURCE sheet.
+     * ID of the DataSource the sheet is connected to.
      */
     #[serde(
         default,
@@ -4747,12 +5608,12 @@ pub struct DataSourceSheetProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceSpec {
     /**
-     * This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
+     * The specification of a BigQuery data source that's connected to a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "bigQuery")]
     pub big_query: Option<BigQueryDataSourceSpec>,
     /**
-     * This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
+     * The parameters of the data source, used when querying the data source.
      */
     #[serde(
         default,
@@ -4766,6 +5627,7 @@ pub struct DataSourceSpec {
 * The type to select columns for the data source table. Defaults to SELECTED.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ColumnSelectionType {
     #[serde(rename = "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED")]
     DataSourceTableColumnSelectionTypeUnspecified,
@@ -4775,8 +5637,9 @@ pub enum ColumnSelectionType {
     SyncAll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ColumnSelectionType {
@@ -4788,12 +5651,35 @@ impl std::fmt::Display for ColumnSelectionType {
             ColumnSelectionType::Selected => "SELECTED",
             ColumnSelectionType::SyncAll => "SYNC_ALL",
             ColumnSelectionType::Noop => "",
-            ColumnSelectionType::FallthroughString => "*",
+            ColumnSelectionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ColumnSelectionType {
+    fn from(s: String) -> ColumnSelectionType {
+        match s.as_str() {
+            "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED" => {
+                ColumnSelectionType::DataSourceTableColumnSelectionTypeUnspecified
+            }
+            "SELECTED" => ColumnSelectionType::Selected,
+            "SYNC_ALL" => ColumnSelectionType::SyncAll,
+            "" => ColumnSelectionType::Noop,
+            _ => ColumnSelectionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ColumnSelectionType> for String {
+    fn from(e: ColumnSelectionType) -> String {
+        match e {
+            ColumnSelectionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ColumnSelectionType {
     fn default() -> ColumnSelectionType {
         ColumnSelectionType::Noop
@@ -4809,7 +5695,7 @@ impl ColumnSelectionType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataSourceTable {
     /**
-     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
+     * The type to select columns for the data source table. Defaults to SELECTED.
      */
     #[serde(
         default,
@@ -4818,7 +5704,7 @@ pub struct DataSourceTable {
     )]
     pub column_selection_type: Option<ColumnSelectionType>,
     /**
-     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
+     * Columns selected for the data source table. The column_selection_type must be SELECTED.
      */
     #[serde(
         default,
@@ -4827,7 +5713,7 @@ pub struct DataSourceTable {
     )]
     pub columns: Vec<DataSourceColumnReference>,
     /**
-     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -4836,7 +5722,7 @@ pub struct DataSourceTable {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
+     * The ID of the data source the data source table is associated with.
      */
     #[serde(
         default,
@@ -4846,7 +5732,7 @@ pub struct DataSourceTable {
     )]
     pub data_source_id: String,
     /**
-     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
+     * Filter specifications in the data source table.
      */
     #[serde(
         default,
@@ -4856,7 +5742,7 @@ pub struct DataSourceTable {
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
-     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
+     * The limit of rows to return. If not set, a default limit is applied. Please refer to the Sheets editor for the default and max limit.
      */
     #[serde(
         default,
@@ -4866,7 +5752,7 @@ pub struct DataSourceTable {
     )]
     pub row_limit: i64,
     /**
-     * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
+     * Sort specifications in the data source table. The result of the data source table is sorted based on the sort specifications in order.
      */
     #[serde(
         default,
@@ -4881,12 +5767,12 @@ pub struct DataSourceTable {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DataValidationRule {
     /**
-     * A data validation rule.
+     * A condition that can evaluate to true or false. BooleanConditions are used by conditional formatting, data validation, and the criteria in filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub condition: Option<BooleanCondition>,
     /**
-     * A data validation rule.
+     * A message to show the user when adding data to the cell.
      */
     #[serde(
         default,
@@ -4896,7 +5782,7 @@ pub struct DataValidationRule {
     )]
     pub input_message: String,
     /**
-     * A data validation rule.
+     * True if the UI should be customized based on the kind of condition. If true, "List" conditions will show a dropdown.
      */
     #[serde(
         default,
@@ -4905,7 +5791,7 @@ pub struct DataValidationRule {
     )]
     pub show_custom_ui: bool,
     /**
-     * A data validation rule.
+     * True if invalid data should be rejected.
      */
     #[serde(
         default,
@@ -4918,6 +5804,7 @@ pub struct DataValidationRule {
 * The type of date-time grouping to apply.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateTimeRuleType {
     #[serde(rename = "DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     DateTimeRuleTypeUnspecified,
@@ -4953,8 +5840,9 @@ pub enum DateTimeRuleType {
     YearQuarter,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateTimeRuleType {
@@ -4977,12 +5865,46 @@ impl std::fmt::Display for DateTimeRuleType {
             DateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             DateTimeRuleType::YearQuarter => "YEAR_QUARTER",
             DateTimeRuleType::Noop => "",
-            DateTimeRuleType::FallthroughString => "*",
+            DateTimeRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DateTimeRuleType {
+    fn from(s: String) -> DateTimeRuleType {
+        match s.as_str() {
+            "DATE_TIME_RULE_TYPE_UNSPECIFIED" => DateTimeRuleType::DateTimeRuleTypeUnspecified,
+            "DAY_MONTH" => DateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => DateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => DateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => DateTimeRuleType::DayOfYear,
+            "HOUR" => DateTimeRuleType::Hour,
+            "HOUR_MINUTE" => DateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => DateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => DateTimeRuleType::Minute,
+            "MONTH" => DateTimeRuleType::Month,
+            "QUARTER" => DateTimeRuleType::Quarter,
+            "SECOND" => DateTimeRuleType::Second,
+            "YEAR" => DateTimeRuleType::Year,
+            "YEAR_MONTH" => DateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => DateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => DateTimeRuleType::YearQuarter,
+            "" => DateTimeRuleType::Noop,
+            _ => DateTimeRuleType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DateTimeRuleType> for String {
+    fn from(e: DateTimeRuleType) -> String {
+        match e {
+            DateTimeRuleType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DateTimeRuleType {
     fn default() -> DateTimeRuleType {
         DateTimeRuleType::Noop
@@ -4998,7 +5920,7 @@ impl DateTimeRuleType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DateTimeRule {
     /**
-     * Allows you to organize the date-time values in a source data column into buckets based on selected parts of their date or time values. For example, consider a pivot table showing sales transactions by date: +----------+--------------+ | Date | SUM of Sales | +----------+--------------+ | 1/1/2017 | $621.14 | | 2/3/2017 | $708.84 | | 5/8/2017 | $326.84 | ... +----------+--------------+ Applying a date-time group rule with a DateTimeRuleType of YEAR_MONTH results in the following pivot table. +--------------+--------------+ | Grouped Date | SUM of Sales | +--------------+--------------+ | 2017-Jan | $53,731.78 | | 2017-Feb | $83,475.32 | | 2017-Mar | $94,385.05 | ... +--------------+--------------+
+     * The type of date-time grouping to apply.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<DateTimeRuleType>,
@@ -5008,7 +5930,7 @@ pub struct DateTimeRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteBandingRequest {
     /**
-     * Removes the banded range with the given ID from the spreadsheet.
+     * The ID of the banded range to delete.
      */
     #[serde(
         default,
@@ -5023,7 +5945,7 @@ pub struct DeleteBandingRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteConditionalFormatRuleRequest {
     /**
-     * Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
+     * The zero-based index of the rule to be deleted.
      */
     #[serde(
         default,
@@ -5032,7 +5954,7 @@ pub struct DeleteConditionalFormatRuleRequest {
     )]
     pub index: i64,
     /**
-     * Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
+     * The sheet the rule is being deleted from.
      */
     #[serde(
         default,
@@ -5047,7 +5969,7 @@ pub struct DeleteConditionalFormatRuleRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteConditionalFormatRuleResponse {
     /**
-     * The result of deleting a conditional format rule.
+     * A rule describing a conditional format.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rule: Option<ConditionalFormatRule>,
@@ -5057,7 +5979,7 @@ pub struct DeleteConditionalFormatRuleResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDataSourceRequest {
     /**
-     * Deletes a data source. The request also deletes the associated data source sheet, and unlinks all associated data source objects.
+     * The ID of the data source to delete.
      */
     #[serde(
         default,
@@ -5072,7 +5994,7 @@ pub struct DeleteDataSourceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDeveloperMetadataRequest {
     /**
-     * A request to delete developer metadata.
+     * Filter that describes what data should be selected or returned from a request.
      */
     #[serde(
         default,
@@ -5086,7 +6008,7 @@ pub struct DeleteDeveloperMetadataRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDeveloperMetadataResponse {
     /**
-     * The response from deleting developer metadata.
+     * The metadata that was deleted.
      */
     #[serde(
         default,
@@ -5101,7 +6023,7 @@ pub struct DeleteDeveloperMetadataResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDimensionGroupRequest {
     /**
-     * Deletes a group over the specified range by decrementing the depth of the dimensions in the range. For example, assume the sheet has a depth-1 group over B:E and a depth-2 group over C:D. Deleting a group over D:E leaves the sheet with a depth-1 group over B:D and a depth-2 group over C:C.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
@@ -5111,7 +6033,7 @@ pub struct DeleteDimensionGroupRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDimensionGroupResponse {
     /**
-     * The result of deleting a group.
+     * All groups of a dimension after deleting a group from that dimension.
      */
     #[serde(
         default,
@@ -5126,7 +6048,7 @@ pub struct DeleteDimensionGroupResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDimensionRequest {
     /**
-     * Deletes the dimensions from the sheet.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
@@ -5136,7 +6058,7 @@ pub struct DeleteDimensionRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDuplicatesRequest {
     /**
-     * Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
+     * The columns in the range to analyze for duplicate values. If no columns are selected then all columns are analyzed for duplicates.
      */
     #[serde(
         default,
@@ -5146,7 +6068,7 @@ pub struct DeleteDuplicatesRequest {
     )]
     pub comparison_columns: Vec<DimensionRange>,
     /**
-     * Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -5156,7 +6078,7 @@ pub struct DeleteDuplicatesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteDuplicatesResponse {
     /**
-     * The result of removing duplicates in a range.
+     * The number of duplicate rows removed.
      */
     #[serde(
         default,
@@ -5171,7 +6093,7 @@ pub struct DeleteDuplicatesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteEmbeddedObjectRequest {
     /**
-     * Deletes the embedded object with the given ID.
+     * The ID of the embedded object to delete.
      */
     #[serde(
         default,
@@ -5186,7 +6108,7 @@ pub struct DeleteEmbeddedObjectRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteFilterViewRequest {
     /**
-     * Deletes a particular filter view.
+     * The ID of the filter to delete.
      */
     #[serde(
         default,
@@ -5201,7 +6123,7 @@ pub struct DeleteFilterViewRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteNamedRangeRequest {
     /**
-     * Removes the named range with the given ID from the spreadsheet.
+     * The ID of the named range to delete.
      */
     #[serde(
         default,
@@ -5216,7 +6138,7 @@ pub struct DeleteNamedRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteProtectedRangeRequest {
     /**
-     * Deletes the protected range with the given ID.
+     * The ID of the protected range to delete.
      */
     #[serde(
         default,
@@ -5231,12 +6153,12 @@ pub struct DeleteProtectedRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteRangeRequest {
     /**
-     * Deletes a range of cells, shifting other cells into the deleted area.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Deletes a range of cells, shifting other cells into the deleted area.
+     * The dimension from which deleted cells will be replaced with. If ROWS, existing cells will be shifted upward to replace the deleted cells. If COLUMNS, existing cells will be shifted left to replace the deleted cells.
      */
     #[serde(
         default,
@@ -5250,7 +6172,7 @@ pub struct DeleteRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteSheetRequest {
     /**
-     * Deletes the requested sheet.
+     * The ID of the sheet to delete. If the sheet is of DATA_SOURCE type, the associated DataSource is also deleted.
      */
     #[serde(
         default,
@@ -5265,6 +6187,7 @@ pub struct DeleteSheetRequest {
 * The metadata visibility. Developer metadata must always have a visibility specified.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Visibility {
     #[serde(rename = "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED")]
     DeveloperMetadataVisibilityUnspecified,
@@ -5274,8 +6197,9 @@ pub enum Visibility {
     Project,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -5287,12 +6211,35 @@ impl std::fmt::Display for Visibility {
             Visibility::Document => "DOCUMENT",
             Visibility::Project => "PROJECT",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Visibility {
+    fn from(s: String) -> Visibility {
+        match s.as_str() {
+            "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED" => {
+                Visibility::DeveloperMetadataVisibilityUnspecified
+            }
+            "DOCUMENT" => Visibility::Document,
+            "PROJECT" => Visibility::Project,
+            "" => Visibility::Noop,
+            _ => Visibility::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Visibility> for String {
+    fn from(e: Visibility) -> String {
+        match e {
+            Visibility::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -5308,12 +6255,12 @@ impl Visibility {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeveloperMetadata {
     /**
-     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
+     * A location where metadata may be associated in a spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<DeveloperMetadataLocation>,
     /**
-     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
+     * The spreadsheet-scoped unique ID that identifies the metadata. IDs may be specified when metadata is created, otherwise one will be randomly generated and assigned. Must be positive.
      */
     #[serde(
         default,
@@ -5323,7 +6270,7 @@ pub struct DeveloperMetadata {
     )]
     pub metadata_id: i64,
     /**
-     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
+     * The metadata key. There may be multiple metadata in a spreadsheet with the same key. Developer metadata must always have a key specified.
      */
     #[serde(
         default,
@@ -5333,7 +6280,7 @@ pub struct DeveloperMetadata {
     )]
     pub metadata_key: String,
     /**
-     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
+     * Data associated with the metadata's key.
      */
     #[serde(
         default,
@@ -5343,7 +6290,7 @@ pub struct DeveloperMetadata {
     )]
     pub metadata_value: String,
     /**
-     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
+     * The metadata visibility. Developer metadata must always have a visibility specified.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
@@ -5353,6 +6300,7 @@ pub struct DeveloperMetadata {
 * The type of location this object represents. This field is read-only.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationType {
     #[serde(rename = "COLUMN")]
     Column,
@@ -5366,8 +6314,9 @@ pub enum LocationType {
     Spreadsheet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationType {
@@ -5381,12 +6330,37 @@ impl std::fmt::Display for LocationType {
             LocationType::Sheet => "SHEET",
             LocationType::Spreadsheet => "SPREADSHEET",
             LocationType::Noop => "",
-            LocationType::FallthroughString => "*",
+            LocationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LocationType {
+    fn from(s: String) -> LocationType {
+        match s.as_str() {
+            "COLUMN" => LocationType::Column,
+            "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED" => {
+                LocationType::DeveloperMetadataLocationTypeUnspecified
+            }
+            "ROW" => LocationType::Row,
+            "SHEET" => LocationType::Sheet,
+            "SPREADSHEET" => LocationType::Spreadsheet,
+            "" => LocationType::Noop,
+            _ => LocationType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LocationType> for String {
+    fn from(e: LocationType) -> String {
+        match e {
+            LocationType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LocationType {
     fn default() -> LocationType {
         LocationType::Noop
@@ -5402,7 +6376,7 @@ impl LocationType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeveloperMetadataLocation {
     /**
-     * A location where metadata may be associated in a spreadsheet.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(
         default,
@@ -5411,7 +6385,7 @@ pub struct DeveloperMetadataLocation {
     )]
     pub dimension_range: Option<DimensionRange>,
     /**
-     * A location where metadata may be associated in a spreadsheet.
+     * The type of location this object represents. This field is read-only.
      */
     #[serde(
         default,
@@ -5420,7 +6394,7 @@ pub struct DeveloperMetadataLocation {
     )]
     pub location_type: Option<LocationType>,
     /**
-     * A location where metadata may be associated in a spreadsheet.
+     * The ID of the sheet when metadata is associated with an entire sheet.
      */
     #[serde(
         default,
@@ -5430,7 +6404,7 @@ pub struct DeveloperMetadataLocation {
     )]
     pub sheet_id: i64,
     /**
-     * A location where metadata may be associated in a spreadsheet.
+     * True when metadata is associated with an entire spreadsheet.
      */
     #[serde(
         default,
@@ -5443,6 +6417,7 @@ pub struct DeveloperMetadataLocation {
 * Determines how this lookup matches the location. If this field is specified as EXACT, only developer metadata associated on the exact location specified is matched. If this field is specified to INTERSECTING, developer metadata associated on intersecting locations is also matched. If left unspecified, this field assumes a default value of INTERSECTING. If this field is specified, a metadataLocation must also be specified.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationMatchingStrategy {
     #[serde(rename = "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED")]
     DeveloperMetadataLocationMatchingStrategyUnspecified,
@@ -5452,8 +6427,9 @@ pub enum LocationMatchingStrategy {
     IntersectingLocation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationMatchingStrategy {
@@ -5465,12 +6441,35 @@ impl std::fmt::Display for LocationMatchingStrategy {
             LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
             LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
             LocationMatchingStrategy::Noop => "",
-            LocationMatchingStrategy::FallthroughString => "*",
+            LocationMatchingStrategy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LocationMatchingStrategy {
+    fn from(s: String) -> LocationMatchingStrategy {
+        match s.as_str() {
+            "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED" => {
+                LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified
+            }
+            "EXACT_LOCATION" => LocationMatchingStrategy::ExactLocation,
+            "INTERSECTING_LOCATION" => LocationMatchingStrategy::IntersectingLocation,
+            "" => LocationMatchingStrategy::Noop,
+            _ => LocationMatchingStrategy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LocationMatchingStrategy> for String {
+    fn from(e: LocationMatchingStrategy) -> String {
+        match e {
+            LocationMatchingStrategy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LocationMatchingStrategy {
     fn default() -> LocationMatchingStrategy {
         LocationMatchingStrategy::Noop
@@ -5486,7 +6485,7 @@ impl LocationMatchingStrategy {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeveloperMetadataLookup {
     /**
-     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
+     * Determines how this lookup matches the location. If this field is specified as EXACT, only developer metadata associated on the exact location specified is matched. If this field is specified to INTERSECTING, developer metadata associated on intersecting locations is also matched. If left unspecified, this field assumes a default value of INTERSECTING. If this field is specified, a metadataLocation must also be specified.
      */
     #[serde(
         default,
@@ -5495,7 +6494,7 @@ pub struct DeveloperMetadataLookup {
     )]
     pub location_matching_strategy: Option<LocationMatchingStrategy>,
     /**
-     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
+     * Limits the selected developer metadata to those entries which are associated with locations of the specified type. For example, when this field is specified as ROW this lookup only considers developer metadata associated on rows. If the field is left unspecified, all location types are considered. This field cannot be specified as SPREADSHEET when the locationMatchingStrategy is specified as INTERSECTING or when the metadataLocation is specified as a non-spreadsheet location: spreadsheet metadata cannot intersect any other developer metadata location. This field also must be left unspecified when the locationMatchingStrategy is specified as EXACT.
      */
     #[serde(
         default,
@@ -5504,7 +6503,7 @@ pub struct DeveloperMetadataLookup {
     )]
     pub location_type: Option<LocationType>,
     /**
-     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
+     * Limits the selected developer metadata to that which has a matching DeveloperMetadata.metadata_id.
      */
     #[serde(
         default,
@@ -5514,7 +6513,7 @@ pub struct DeveloperMetadataLookup {
     )]
     pub metadata_id: i64,
     /**
-     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
+     * Limits the selected developer metadata to that which has a matching DeveloperMetadata.metadata_key.
      */
     #[serde(
         default,
@@ -5524,7 +6523,7 @@ pub struct DeveloperMetadataLookup {
     )]
     pub metadata_key: String,
     /**
-     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
+     * A location where metadata may be associated in a spreadsheet.
      */
     #[serde(
         default,
@@ -5533,7 +6532,7 @@ pub struct DeveloperMetadataLookup {
     )]
     pub metadata_location: Option<DeveloperMetadataLocation>,
     /**
-     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
+     * Limits the selected developer metadata to that which has a matching DeveloperMetadata.metadata_value.
      */
     #[serde(
         default,
@@ -5543,7 +6542,7 @@ pub struct DeveloperMetadataLookup {
     )]
     pub metadata_value: String,
     /**
-     * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
+     * Limits the selected developer metadata to that which has a matching DeveloperMetadata.visibility. If left unspecified, all developer metadata visibile to the requesting project is considered.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visibility: Option<Visibility>,
@@ -5553,7 +6552,7 @@ pub struct DeveloperMetadataLookup {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DimensionGroup {
     /**
-     * A group over an interval of rows or columns on a sheet, which can contain or be contained within other groups. A group can be collapsed or expanded as a unit on the sheet.
+     * This field is true if this group is collapsed. A collapsed group remains collapsed if an overlapping group at a shallower depth is expanded. A true value does not imply that all dimensions within the group are hidden, since a dimension's visibility can change independently from this group property. However, when this property is updated, all dimensions within it are set to hidden if this field is true, or set to visible if this field is false.
      */
     #[serde(
         default,
@@ -5561,7 +6560,7 @@ pub struct DimensionGroup {
     )]
     pub collapsed: bool,
     /**
-     * A group over an interval of rows or columns on a sheet, which can contain or be contained within other groups. A group can be collapsed or expanded as a unit on the sheet.
+     * The depth of the group, representing how many groups have a range that wholly contains the range of this group.
      */
     #[serde(
         default,
@@ -5570,7 +6569,7 @@ pub struct DimensionGroup {
     )]
     pub depth: i64,
     /**
-     * A group over an interval of rows or columns on a sheet, which can contain or be contained within other groups. A group can be collapsed or expanded as a unit on the sheet.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
@@ -5580,7 +6579,7 @@ pub struct DimensionGroup {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DimensionProperties {
     /**
-     * Properties about a dimension.
+     * An unique identifier that references a data source column.
      */
     #[serde(
         default,
@@ -5589,7 +6588,7 @@ pub struct DimensionProperties {
     )]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
-     * Properties about a dimension.
+     * The developer metadata associated with a single row or column.
      */
     #[serde(
         default,
@@ -5599,7 +6598,7 @@ pub struct DimensionProperties {
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
     /**
-     * Properties about a dimension.
+     * True if this dimension is being filtered. This field is read-only.
      */
     #[serde(
         default,
@@ -5608,7 +6607,7 @@ pub struct DimensionProperties {
     )]
     pub hidden_by_filter: bool,
     /**
-     * Properties about a dimension.
+     * True if this dimension is explicitly hidden.
      */
     #[serde(
         default,
@@ -5617,7 +6616,7 @@ pub struct DimensionProperties {
     )]
     pub hidden_by_user: bool,
     /**
-     * Properties about a dimension.
+     * The height (if a row) or width (if a column) of the dimension in pixels.
      */
     #[serde(
         default,
@@ -5632,12 +6631,12 @@ pub struct DimensionProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DimensionRange {
     /**
-     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
+     * The dimension of the span.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimension: Option<Dimension>,
     /**
-     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
+     * The end (exclusive) of the span, or not set if unbounded.
      */
     #[serde(
         default,
@@ -5647,7 +6646,7 @@ pub struct DimensionRange {
     )]
     pub end_index: i64,
     /**
-     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
+     * The sheet this span is on.
      */
     #[serde(
         default,
@@ -5657,7 +6656,7 @@ pub struct DimensionRange {
     )]
     pub sheet_id: i64,
     /**
-     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
+     * The start (inclusive) of the span, or not set if unbounded.
      */
     #[serde(
         default,
@@ -5672,7 +6671,7 @@ pub struct DimensionRange {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateFilterViewRequest {
     /**
-     * Duplicates a particular filter view.
+     * The ID of the filter being duplicated.
      */
     #[serde(
         default,
@@ -5687,7 +6686,7 @@ pub struct DuplicateFilterViewRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateFilterViewResponse {
     /**
-     * The result of a filter view being duplicated.
+     * A filter view.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
@@ -5697,7 +6696,7 @@ pub struct DuplicateFilterViewResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateSheetRequest {
     /**
-     * Duplicates the contents of a sheet.
+     * The zero-based index where the new sheet should be inserted. The index of all sheets after this are incremented.
      */
     #[serde(
         default,
@@ -5707,7 +6706,7 @@ pub struct DuplicateSheetRequest {
     )]
     pub insert_sheet_index: i64,
     /**
-     * Duplicates the contents of a sheet.
+     * If set, the ID of the new sheet. If not set, an ID is chosen. If set, the ID must not conflict with any existing sheet ID. If set, it must be non-negative.
      */
     #[serde(
         default,
@@ -5717,7 +6716,7 @@ pub struct DuplicateSheetRequest {
     )]
     pub new_sheet_id: i64,
     /**
-     * Duplicates the contents of a sheet.
+     * The name of the new sheet. If empty, a new name is chosen for you.
      */
     #[serde(
         default,
@@ -5727,7 +6726,7 @@ pub struct DuplicateSheetRequest {
     )]
     pub new_sheet_name: String,
     /**
-     * Duplicates the contents of a sheet.
+     * The sheet to duplicate. If the source sheet is of DATA_SOURCE type, its backing DataSource is also duplicated and associated with the new copy of the sheet. No data execution is triggered, the grid data of this sheet is also copied over but only available after the batch request completes.
      */
     #[serde(
         default,
@@ -5742,7 +6741,7 @@ pub struct DuplicateSheetRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DuplicateSheetResponse {
     /**
-     * The result of duplicating a sheet.
+     * Properties of a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
@@ -5752,7 +6751,7 @@ pub struct DuplicateSheetResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Editors {
     /**
-     * The editors of a protected range.
+     * True if anyone in the document's domain has edit access to the protected range. Domain protection is only supported on documents within a domain.
      */
     #[serde(
         default,
@@ -5761,7 +6760,7 @@ pub struct Editors {
     )]
     pub domain_users_can_edit: bool,
     /**
-     * The editors of a protected range.
+     * The email addresses of groups with edit access to the protected range.
      */
     #[serde(
         default,
@@ -5770,7 +6769,7 @@ pub struct Editors {
     )]
     pub groups: Vec<String>,
     /**
-     * The editors of a protected range.
+     * The email addresses of users with edit access to the protected range.
      */
     #[serde(
         default,
@@ -5784,12 +6783,12 @@ pub struct Editors {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmbeddedChart {
     /**
-     * A chart embedded in a sheet.
+     * A border along an embedded object.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub border: Option<EmbeddedObjectBorder>,
     /**
-     * A chart embedded in a sheet.
+     * The ID of the chart.
      */
     #[serde(
         default,
@@ -5799,12 +6798,12 @@ pub struct EmbeddedChart {
     )]
     pub chart_id: i64,
     /**
-     * A chart embedded in a sheet.
+     * The position of an embedded object such as a chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub position: Option<EmbeddedObjectPosition>,
     /**
-     * A chart embedded in a sheet.
+     * The specifications of a chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<ChartSpec>,
@@ -5814,12 +6813,12 @@ pub struct EmbeddedChart {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmbeddedObjectBorder {
     /**
-     * A border along an embedded object.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<Color>,
     /**
-     * A border along an embedded object.
+     * A color value.
      */
     #[serde(
         default,
@@ -5833,7 +6832,7 @@ pub struct EmbeddedObjectBorder {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmbeddedObjectPosition {
     /**
-     * The position of an embedded object such as a chart.
+     * If true, the embedded object is put on a new sheet whose ID is chosen for you. Used only when writing.
      */
     #[serde(
         default,
@@ -5842,7 +6841,7 @@ pub struct EmbeddedObjectPosition {
     )]
     pub new_sheet: bool,
     /**
-     * The position of an embedded object such as a chart.
+     * The location an object is overlaid on top of a grid.
      */
     #[serde(
         default,
@@ -5851,7 +6850,7 @@ pub struct EmbeddedObjectPosition {
     )]
     pub overlay_position: Option<OverlayPosition>,
     /**
-     * The position of an embedded object such as a chart.
+     * The sheet this is on. Set only if the embedded object is on its own sheet. Must be non-negative.
      */
     #[serde(
         default,
@@ -5866,6 +6865,7 @@ pub struct EmbeddedObjectPosition {
 * The type of error.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorValueType {
     #[serde(rename = "DIVIDE_BY_ZERO")]
     DivideByZero,
@@ -5889,8 +6889,9 @@ pub enum ErrorValueType {
     Value,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorValueType {
@@ -5907,12 +6908,40 @@ impl std::fmt::Display for ErrorValueType {
             ErrorValueType::Ref => "REF",
             ErrorValueType::Value => "VALUE",
             ErrorValueType::Noop => "",
-            ErrorValueType::FallthroughString => "*",
+            ErrorValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ErrorValueType {
+    fn from(s: String) -> ErrorValueType {
+        match s.as_str() {
+            "DIVIDE_BY_ZERO" => ErrorValueType::DivideByZero,
+            "ERROR" => ErrorValueType::Error,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorValueType::ErrorTypeUnspecified,
+            "LOADING" => ErrorValueType::Loading,
+            "NAME" => ErrorValueType::Name,
+            "NULL_VALUE" => ErrorValueType::NullValue,
+            "NUM" => ErrorValueType::Num,
+            "N_A" => ErrorValueType::NA,
+            "REF" => ErrorValueType::Ref,
+            "VALUE" => ErrorValueType::Value,
+            "" => ErrorValueType::Noop,
+            _ => ErrorValueType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ErrorValueType> for String {
+    fn from(e: ErrorValueType) -> String {
+        match e {
+            ErrorValueType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ErrorValueType {
     fn default() -> ErrorValueType {
         ErrorValueType::Noop
@@ -5928,7 +6957,7 @@ impl ErrorValueType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ErrorValue {
     /**
-     * An error in a cell.
+     * A message with more information about the error (in the spreadsheet's locale).
      */
     #[serde(
         default,
@@ -5937,7 +6966,7 @@ pub struct ErrorValue {
     )]
     pub message: String,
     /**
-     * An error in a cell.
+     * The type of error.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ErrorValueType>,
@@ -5947,7 +6976,7 @@ pub struct ErrorValue {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ExtendedValue {
     /**
-     * The kinds of value that a cell in a spreadsheet can have.
+     * Represents a boolean value.
      */
     #[serde(
         default,
@@ -5956,7 +6985,7 @@ pub struct ExtendedValue {
     )]
     pub bool_value: bool,
     /**
-     * The kinds of value that a cell in a spreadsheet can have.
+     * An error in a cell.
      */
     #[serde(
         default,
@@ -5965,7 +6994,7 @@ pub struct ExtendedValue {
     )]
     pub error_value: Option<ErrorValue>,
     /**
-     * The kinds of value that a cell in a spreadsheet can have.
+     * Represents a formula.
      */
     #[serde(
         default,
@@ -5975,7 +7004,7 @@ pub struct ExtendedValue {
     )]
     pub formula_value: String,
     /**
-     * The kinds of value that a cell in a spreadsheet can have.
+     * Represents a double value. Note: Dates, Times and DateTimes are represented as doubles in SERIAL_NUMBER format.
      */
     #[serde(
         default,
@@ -5985,7 +7014,7 @@ pub struct ExtendedValue {
     )]
     pub number_value: f64,
     /**
-     * The kinds of value that a cell in a spreadsheet can have.
+     * Represents a string value. Leading single quotes are not included. For example, if the user typed `'123` into the UI, this would be represented as a `stringValue` of `"123"`.
      */
     #[serde(
         default,
@@ -6000,12 +7029,12 @@ pub struct ExtendedValue {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilterCriteria {
     /**
-     * Criteria for showing/hiding rows in a filter or filter view.
+     * A condition that can evaluate to true or false. BooleanConditions are used by conditional formatting, data validation, and the criteria in filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub condition: Option<BooleanCondition>,
     /**
-     * Criteria for showing/hiding rows in a filter or filter view.
+     * Values that should be hidden.
      */
     #[serde(
         default,
@@ -6015,7 +7044,7 @@ pub struct FilterCriteria {
     )]
     pub hidden_values: Vec<String>,
     /**
-     * Criteria for showing/hiding rows in a filter or filter view.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -6024,7 +7053,7 @@ pub struct FilterCriteria {
     )]
     pub visible_background_color: Option<Color>,
     /**
-     * Criteria for showing/hiding rows in a filter or filter view.
+     * A color value.
      */
     #[serde(
         default,
@@ -6033,7 +7062,7 @@ pub struct FilterCriteria {
     )]
     pub visible_background_color_style: Option<ColorStyle>,
     /**
-     * Criteria for showing/hiding rows in a filter or filter view.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -6042,7 +7071,7 @@ pub struct FilterCriteria {
     )]
     pub visible_foreground_color: Option<Color>,
     /**
-     * Criteria for showing/hiding rows in a filter or filter view.
+     * A color value.
      */
     #[serde(
         default,
@@ -6056,7 +7085,7 @@ pub struct FilterCriteria {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilterSpec {
     /**
-     * The filter criteria associated with a specific column.
+     * The column index.
      */
     #[serde(
         default,
@@ -6066,7 +7095,7 @@ pub struct FilterSpec {
     )]
     pub column_index: i64,
     /**
-     * The filter criteria associated with a specific column.
+     * An unique identifier that references a data source column.
      */
     #[serde(
         default,
@@ -6075,7 +7104,7 @@ pub struct FilterSpec {
     )]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
-     * The filter criteria associated with a specific column.
+     * Criteria for showing/hiding rows in a filter or filter view.
      */
     #[serde(
         default,
@@ -6089,12 +7118,12 @@ pub struct FilterSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FilterView {
     /**
-     * A filter view.
+     * The criteria for showing/hiding values per column. The map's key is the column index, and the value is the criteria for that column. This field is deprecated in favor of filter_specs.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub criteria: Option<FilterCriteria>,
     /**
-     * A filter view.
+     * The filter criteria for showing/hiding values per column. Both criteria and filter_specs are populated in responses. If both fields are specified in an update request, this field takes precedence.
      */
     #[serde(
         default,
@@ -6104,7 +7133,7 @@ pub struct FilterView {
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
-     * A filter view.
+     * The ID of the filter view.
      */
     #[serde(
         default,
@@ -6114,7 +7143,7 @@ pub struct FilterView {
     )]
     pub filter_view_id: i64,
     /**
-     * A filter view.
+     * The named range this filter view is backed by, if any. When writing, only one of range or named_range_id may be set.
      */
     #[serde(
         default,
@@ -6124,12 +7153,12 @@ pub struct FilterView {
     )]
     pub named_range_id: String,
     /**
-     * A filter view.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * A filter view.
+     * The sort order per column. Later specifications are used when values are equal in the earlier specifications.
      */
     #[serde(
         default,
@@ -6139,7 +7168,7 @@ pub struct FilterView {
     )]
     pub sort_specs: Vec<SortSpec>,
     /**
-     * A filter view.
+     * The name of the filter view.
      */
     #[serde(
         default,
@@ -6153,12 +7182,12 @@ pub struct FilterView {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FindReplaceRequest {
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * True to find/replace over all sheets.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "allSheets")]
     pub all_sheets: Option<bool>,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * The value to search.
      */
     #[serde(
         default,
@@ -6167,7 +7196,7 @@ pub struct FindReplaceRequest {
     )]
     pub find: String,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * True if the search should include cells with formulas. False to skip cells with formulas.
      */
     #[serde(
         default,
@@ -6176,12 +7205,12 @@ pub struct FindReplaceRequest {
     )]
     pub include_formulas: Option<bool>,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * True if the search is case sensitive.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchCase")]
     pub match_case: Option<bool>,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * True if the find value should match the entire cell.
      */
     #[serde(
         default,
@@ -6190,12 +7219,12 @@ pub struct FindReplaceRequest {
     )]
     pub match_entire_cell: Option<bool>,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * The value to use as the replacement.
      */
     #[serde(
         default,
@@ -6204,7 +7233,7 @@ pub struct FindReplaceRequest {
     )]
     pub replacement: String,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * True if the find value is a regex. The regular expression and replacement should follow Java regex rules at https://docs.oracle.com/javase/8/docs/api/java/util/regex/Pattern.html. The replacement string is allowed to refer to capturing groups. For example, if one cell has the contents `"Google Sheets"` and another has `"Google Docs"`, then searching for `"o.\* (.\*)"` with a replacement of `"$1 Rocks"` would change the contents of the cells to `"GSheets Rocks"` and `"GDocs Rocks"` respectively.
      */
     #[serde(
         default,
@@ -6213,7 +7242,7 @@ pub struct FindReplaceRequest {
     )]
     pub search_by_regex: Option<bool>,
     /**
-     * Finds and replaces data in cells over a range, sheet, or all sheets.
+     * The sheet to find/replace over.
      */
     #[serde(
         default,
@@ -6228,7 +7257,7 @@ pub struct FindReplaceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FindReplaceResponse {
     /**
-     * The result of the find/replace.
+     * The number of formula cells changed.
      */
     #[serde(
         default,
@@ -6238,7 +7267,7 @@ pub struct FindReplaceResponse {
     )]
     pub formulas_changed: i64,
     /**
-     * The result of the find/replace.
+     * The number of occurrences (possibly multiple within a cell) changed. For example, if replacing `"e"` with `"o"` in `"Google Sheets"`, this would be `"3"` because `"Google Sheets"` -> `"Googlo Shoots"`.
      */
     #[serde(
         default,
@@ -6248,7 +7277,7 @@ pub struct FindReplaceResponse {
     )]
     pub occurrences_changed: i64,
     /**
-     * The result of the find/replace.
+     * The number of rows changed.
      */
     #[serde(
         default,
@@ -6258,7 +7287,7 @@ pub struct FindReplaceResponse {
     )]
     pub rows_changed: i64,
     /**
-     * The result of the find/replace.
+     * The number of sheets changed.
      */
     #[serde(
         default,
@@ -6268,7 +7297,7 @@ pub struct FindReplaceResponse {
     )]
     pub sheets_changed: i64,
     /**
-     * The result of the find/replace.
+     * The number of non-formula cells changed.
      */
     #[serde(
         default,
@@ -6283,7 +7312,7 @@ pub struct FindReplaceResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSpreadsheetByDataFilterRequest {
     /**
-     * The request for retrieving a Spreadsheet.
+     * The DataFilters used to select which ranges to retrieve from the spreadsheet.
      */
     #[serde(
         default,
@@ -6293,7 +7322,7 @@ pub struct GetSpreadsheetByDataFilterRequest {
     )]
     pub data_filters: Vec<DataFilter>,
     /**
-     * The request for retrieving a Spreadsheet.
+     * True if grid data should be returned. This parameter is ignored if a field mask was set in the request.
      */
     #[serde(
         default,
@@ -6307,17 +7336,17 @@ pub struct GetSpreadsheetByDataFilterRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GradientRule {
     /**
-     * A rule that applies a gradient color scale format, based on the interpolation points listed. The format of a cell will vary based on its contents as compared to the values of the interpolation points.
+     * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub maxpoint: Option<InterpolationPoint>,
     /**
-     * A rule that applies a gradient color scale format, based on the interpolation points listed. The format of a cell will vary based on its contents as compared to the values of the interpolation points.
+     * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub midpoint: Option<InterpolationPoint>,
     /**
-     * A rule that applies a gradient color scale format, based on the interpolation points listed. The format of a cell will vary based on its contents as compared to the values of the interpolation points.
+     * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub minpoint: Option<InterpolationPoint>,
@@ -6327,7 +7356,7 @@ pub struct GradientRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GridCoordinate {
     /**
-     * A coordinate in a sheet. All indexes are zero-based.
+     * The column index of the coordinate.
      */
     #[serde(
         default,
@@ -6337,7 +7366,7 @@ pub struct GridCoordinate {
     )]
     pub column_index: i64,
     /**
-     * A coordinate in a sheet. All indexes are zero-based.
+     * The row index of the coordinate.
      */
     #[serde(
         default,
@@ -6347,7 +7376,7 @@ pub struct GridCoordinate {
     )]
     pub row_index: i64,
     /**
-     * A coordinate in a sheet. All indexes are zero-based.
+     * The sheet this coordinate is on.
      */
     #[serde(
         default,
@@ -6362,7 +7391,7 @@ pub struct GridCoordinate {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GridData {
     /**
-     * Data in the grid, as well as metadata about the dimensions.
+     * Metadata about the requested columns in the grid, starting with the column in start_column.
      */
     #[serde(
         default,
@@ -6372,7 +7401,7 @@ pub struct GridData {
     )]
     pub column_metadata: Vec<DimensionProperties>,
     /**
-     * Data in the grid, as well as metadata about the dimensions.
+     * The data in the grid, one entry per row, starting with the row in startRow. The values in RowData will correspond to columns starting at start_column.
      */
     #[serde(
         default,
@@ -6382,7 +7411,7 @@ pub struct GridData {
     )]
     pub row_data: Vec<RowData>,
     /**
-     * Data in the grid, as well as metadata about the dimensions.
+     * Metadata about the requested rows in the grid, starting with the row in start_row.
      */
     #[serde(
         default,
@@ -6392,7 +7421,7 @@ pub struct GridData {
     )]
     pub row_metadata: Vec<DimensionProperties>,
     /**
-     * Data in the grid, as well as metadata about the dimensions.
+     * The first column this GridData refers to, zero-based.
      */
     #[serde(
         default,
@@ -6402,7 +7431,7 @@ pub struct GridData {
     )]
     pub start_column: i64,
     /**
-     * Data in the grid, as well as metadata about the dimensions.
+     * The first row this GridData refers to, zero-based.
      */
     #[serde(
         default,
@@ -6417,7 +7446,7 @@ pub struct GridData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GridProperties {
     /**
-     * Properties of a grid.
+     * The number of columns in the grid.
      */
     #[serde(
         default,
@@ -6427,7 +7456,7 @@ pub struct GridProperties {
     )]
     pub column_count: i64,
     /**
-     * Properties of a grid.
+     * True if the column grouping control toggle is shown after the group.
      */
     #[serde(
         default,
@@ -6436,7 +7465,7 @@ pub struct GridProperties {
     )]
     pub column_group_control_after: bool,
     /**
-     * Properties of a grid.
+     * The number of columns that are frozen in the grid.
      */
     #[serde(
         default,
@@ -6446,7 +7475,7 @@ pub struct GridProperties {
     )]
     pub frozen_column_count: i64,
     /**
-     * Properties of a grid.
+     * The number of rows that are frozen in the grid.
      */
     #[serde(
         default,
@@ -6456,7 +7485,7 @@ pub struct GridProperties {
     )]
     pub frozen_row_count: i64,
     /**
-     * Properties of a grid.
+     * True if the grid isn't showing gridlines in the UI.
      */
     #[serde(
         default,
@@ -6465,7 +7494,7 @@ pub struct GridProperties {
     )]
     pub hide_gridlines: bool,
     /**
-     * Properties of a grid.
+     * The number of rows in the grid.
      */
     #[serde(
         default,
@@ -6475,7 +7504,7 @@ pub struct GridProperties {
     )]
     pub row_count: i64,
     /**
-     * Properties of a grid.
+     * True if the row grouping control toggle is shown after the group.
      */
     #[serde(
         default,
@@ -6489,7 +7518,7 @@ pub struct GridProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GridRange {
     /**
-     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
+     * The end column (exclusive) of the range, or not set if unbounded.
      */
     #[serde(
         default,
@@ -6499,7 +7528,7 @@ pub struct GridRange {
     )]
     pub end_column_index: i64,
     /**
-     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
+     * The end row (exclusive) of the range, or not set if unbounded.
      */
     #[serde(
         default,
@@ -6509,7 +7538,7 @@ pub struct GridRange {
     )]
     pub end_row_index: i64,
     /**
-     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
+     * The sheet this range is on.
      */
     #[serde(
         default,
@@ -6519,7 +7548,7 @@ pub struct GridRange {
     )]
     pub sheet_id: i64,
     /**
-     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
+     * The start column (inclusive) of the range, or not set if unbounded.
      */
     #[serde(
         default,
@@ -6529,7 +7558,7 @@ pub struct GridRange {
     )]
     pub start_column_index: i64,
     /**
-     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
+     * The start row (inclusive) of the range, or not set if unbounded.
      */
     #[serde(
         default,
@@ -6544,6 +7573,7 @@ pub struct GridRange {
 * The position of the chart legend.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HistogramChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -6561,8 +7591,9 @@ pub enum HistogramChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HistogramChartSpecLegendPosition {
@@ -6578,12 +7609,39 @@ impl std::fmt::Display for HistogramChartSpecLegendPosition {
             HistogramChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             HistogramChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             HistogramChartSpecLegendPosition::Noop => "",
-            HistogramChartSpecLegendPosition::FallthroughString => "*",
+            HistogramChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for HistogramChartSpecLegendPosition {
+    fn from(s: String) -> HistogramChartSpecLegendPosition {
+        match s.as_str() {
+            "BOTTOM_LEGEND" => HistogramChartSpecLegendPosition::BottomLegend,
+            "HISTOGRAM_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                HistogramChartSpecLegendPosition::HistogramChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => HistogramChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => HistogramChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => HistogramChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => HistogramChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => HistogramChartSpecLegendPosition::TopLegend,
+            "" => HistogramChartSpecLegendPosition::Noop,
+            _ => HistogramChartSpecLegendPosition::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<HistogramChartSpecLegendPosition> for String {
+    fn from(e: HistogramChartSpecLegendPosition) -> String {
+        match e {
+            HistogramChartSpecLegendPosition::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for HistogramChartSpecLegendPosition {
     fn default() -> HistogramChartSpecLegendPosition {
         HistogramChartSpecLegendPosition::Noop
@@ -6599,7 +7657,7 @@ impl HistogramChartSpecLegendPosition {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct HistogramChartSpec {
     /**
-     * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
+     * By default the bucket size (the range of values stacked in a single column) is chosen automatically, but it may be overridden here. E.g., A bucket size of 1.5 results in buckets from 0 - 1.5, 1.5 - 3.0, etc. Cannot be negative. This field is optional.
      */
     #[serde(
         default,
@@ -6609,7 +7667,7 @@ pub struct HistogramChartSpec {
     )]
     pub bucket_size: f64,
     /**
-     * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
+     * The position of the chart legend.
      */
     #[serde(
         default,
@@ -6618,7 +7676,7 @@ pub struct HistogramChartSpec {
     )]
     pub legend_position: Option<HistogramChartSpecLegendPosition>,
     /**
-     * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
+     * The outlier percentile is used to ensure that outliers do not adversely affect the calculation of bucket sizes. For example, setting an outlier percentile of 0.05 indicates that the top and bottom 5% of values when calculating buckets. The values are still included in the chart, they will be added to the first or last buckets instead of their own buckets. Must be between 0.0 and 0.5.
      */
     #[serde(
         default,
@@ -6628,7 +7686,7 @@ pub struct HistogramChartSpec {
     )]
     pub outlier_percentile: f64,
     /**
-     * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
+     * The series for a histogram may be either a single series of values to be bucketed or multiple series, each of the same length, containing the name of the series followed by the values to be bucketed for that series.
      */
     #[serde(
         default,
@@ -6637,7 +7695,7 @@ pub struct HistogramChartSpec {
     )]
     pub series: Vec<HistogramSeries>,
     /**
-     * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
+     * Whether horizontal divider lines should be displayed between items in each column.
      */
     #[serde(
         default,
@@ -6651,7 +7709,7 @@ pub struct HistogramChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct HistogramRule {
     /**
-     * Allows you to organize the numeric values in a source data column into buckets of a constant size. All values from HistogramRule.start to HistogramRule.end are placed into groups of size HistogramRule.interval. In addition, all values below HistogramRule.start are placed in one group, and all values above HistogramRule.end are placed in another. Only HistogramRule.interval is required, though if HistogramRule.start and HistogramRule.end are both provided, HistogramRule.start must be less than HistogramRule.end. For example, a pivot table showing average purchase amount by age that has 50+ rows: +-----+-------------------+ | Age | AVERAGE of Amount | +-----+-------------------+ | 16 | $27.13 | | 17 | $5.24 | | 18 | $20.15 | ... +-----+-------------------+ could be turned into a pivot table that looks like the one below by applying a histogram group rule with a HistogramRule.start of 25, an HistogramRule.interval of 20, and an HistogramRule.end of 65. +-------------+-------------------+ | Grouped Age | AVERAGE of Amount | +-------------+-------------------+ | < 25 | $19.34 | | 25-45 | $31.43 | | 45-65 | $35.87 | | > 65 | $27.55 | +-------------+-------------------+ | Grand Total | $29.12 | +-------------+-------------------+
+     * The maximum value at which items are placed into buckets of constant size. Values above end are lumped into a single bucket. This field is optional.
      */
     #[serde(
         default,
@@ -6660,7 +7718,7 @@ pub struct HistogramRule {
     )]
     pub end: f64,
     /**
-     * Allows you to organize the numeric values in a source data column into buckets of a constant size. All values from HistogramRule.start to HistogramRule.end are placed into groups of size HistogramRule.interval. In addition, all values below HistogramRule.start are placed in one group, and all values above HistogramRule.end are placed in another. Only HistogramRule.interval is required, though if HistogramRule.start and HistogramRule.end are both provided, HistogramRule.start must be less than HistogramRule.end. For example, a pivot table showing average purchase amount by age that has 50+ rows: +-----+-------------------+ | Age | AVERAGE of Amount | +-----+-------------------+ | 16 | $27.13 | | 17 | $5.24 | | 18 | $20.15 | ... +-----+-------------------+ could be turned into a pivot table that looks like the one below by applying a histogram group rule with a HistogramRule.start of 25, an HistogramRule.interval of 20, and an HistogramRule.end of 65. +-------------+-------------------+ | Grouped Age | AVERAGE of Amount | +-------------+-------------------+ | < 25 | $19.34 | | 25-45 | $31.43 | | 45-65 | $35.87 | | > 65 | $27.55 | +-------------+-------------------+ | Grand Total | $29.12 | +-------------+-------------------+
+     * The size of the buckets that are created. Must be positive.
      */
     #[serde(
         default,
@@ -6669,7 +7727,7 @@ pub struct HistogramRule {
     )]
     pub interval: f64,
     /**
-     * Allows you to organize the numeric values in a source data column into buckets of a constant size. All values from HistogramRule.start to HistogramRule.end are placed into groups of size HistogramRule.interval. In addition, all values below HistogramRule.start are placed in one group, and all values above HistogramRule.end are placed in another. Only HistogramRule.interval is required, though if HistogramRule.start and HistogramRule.end are both provided, HistogramRule.start must be less than HistogramRule.end. For example, a pivot table showing average purchase amount by age that has 50+ rows: +-----+-------------------+ | Age | AVERAGE of Amount | +-----+-------------------+ | 16 | $27.13 | | 17 | $5.24 | | 18 | $20.15 | ... +-----+-------------------+ could be turned into a pivot table that looks like the one below by applying a histogram group rule with a HistogramRule.start of 25, an HistogramRule.interval of 20, and an HistogramRule.end of 65. +-------------+-------------------+ | Grouped Age | AVERAGE of Amount | +-------------+-------------------+ | < 25 | $19.34 | | 25-45 | $31.43 | | 45-65 | $35.87 | | > 65 | $27.55 | +-------------+-------------------+ | Grand Total | $29.12 | +-------------+-------------------+
+     * The minimum value at which items are placed into buckets of constant size. Values below start are lumped into a single bucket. This field is optional.
      */
     #[serde(
         default,
@@ -6683,12 +7741,12 @@ pub struct HistogramRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct HistogramSeries {
     /**
-     * A histogram series containing the series color and data.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "barColor")]
     pub bar_color: Option<Color>,
     /**
-     * A histogram series containing the series color and data.
+     * A color value.
      */
     #[serde(
         default,
@@ -6697,7 +7755,7 @@ pub struct HistogramSeries {
     )]
     pub bar_color_style: Option<ColorStyle>,
     /**
-     * A histogram series containing the series color and data.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<ChartData>,
@@ -6707,7 +7765,7 @@ pub struct HistogramSeries {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InsertDimensionRequest {
     /**
-     * Inserts rows or columns in a sheet at a particular index.
+     * Whether dimension properties should be extended from the dimensions before or after the newly inserted dimensions. True to inherit from the dimensions before (in which case the start index must be greater than 0), and false to inherit from the dimensions after. For example, if row index 0 has red background and row index 1 has a green background, then inserting 2 rows at index 1 can inherit either the green or red background. If `inheritFromBefore` is true, the two new rows will be red (because the row before the insertion point was red), whereas if `inheritFromBefore` is false, the two new rows will be green (because the row after the insertion point was green).
      */
     #[serde(
         default,
@@ -6716,7 +7774,7 @@ pub struct InsertDimensionRequest {
     )]
     pub inherit_from_before: Option<bool>,
     /**
-     * Inserts rows or columns in a sheet at a particular index.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
@@ -6726,12 +7784,12 @@ pub struct InsertDimensionRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InsertRangeRequest {
     /**
-     * Inserts cells into a range, shifting the existing cells over or down.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Inserts cells into a range, shifting the existing cells over or down.
+     * The dimension which will be shifted when inserting cells. If ROWS, existing cells will be shifted down. If COLUMNS, existing cells will be shifted right.
      */
     #[serde(
         default,
@@ -6745,6 +7803,7 @@ pub struct InsertRangeRequest {
 * How the value should be interpreted.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterpolationPointType {
     #[serde(rename = "INTERPOLATION_POINT_TYPE_UNSPECIFIED")]
     InterpolationPointTypeUnspecified,
@@ -6760,8 +7819,9 @@ pub enum InterpolationPointType {
     Percentile,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterpolationPointType {
@@ -6776,12 +7836,38 @@ impl std::fmt::Display for InterpolationPointType {
             InterpolationPointType::Percent => "PERCENT",
             InterpolationPointType::Percentile => "PERCENTILE",
             InterpolationPointType::Noop => "",
-            InterpolationPointType::FallthroughString => "*",
+            InterpolationPointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InterpolationPointType {
+    fn from(s: String) -> InterpolationPointType {
+        match s.as_str() {
+            "INTERPOLATION_POINT_TYPE_UNSPECIFIED" => {
+                InterpolationPointType::InterpolationPointTypeUnspecified
+            }
+            "MAX" => InterpolationPointType::Max,
+            "MIN" => InterpolationPointType::Min,
+            "NUMBER" => InterpolationPointType::Number,
+            "PERCENT" => InterpolationPointType::Percent,
+            "PERCENTILE" => InterpolationPointType::Percentile,
+            "" => InterpolationPointType::Noop,
+            _ => InterpolationPointType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InterpolationPointType> for String {
+    fn from(e: InterpolationPointType) -> String {
+        match e {
+            InterpolationPointType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InterpolationPointType {
     fn default() -> InterpolationPointType {
         InterpolationPointType::Noop
@@ -6797,12 +7883,12 @@ impl InterpolationPointType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InterpolationPoint {
     /**
-     * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<Color>,
     /**
-     * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
+     * A color value.
      */
     #[serde(
         default,
@@ -6811,12 +7897,12 @@ pub struct InterpolationPoint {
     )]
     pub color_style: Option<ColorStyle>,
     /**
-     * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
+     * How the value should be interpreted.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<InterpolationPointType>,
     /**
-     * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
+     * The value this interpolation point uses. May be a formula. Unused if type is MIN or MAX.
      */
     #[serde(
         default,
@@ -6830,7 +7916,7 @@ pub struct InterpolationPoint {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Interval {
     /**
-     * Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive). The start must be less than or equal to the end. When the start equals the end, the interval is empty (matches no time). When both start and end are unspecified, the interval matches any time.
+     * Optional. Exclusive end of the interval. If specified, a Timestamp matching this interval will have to be before the end.
      */
     #[serde(
         default,
@@ -6840,7 +7926,7 @@ pub struct Interval {
     )]
     pub end_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive). The start must be less than or equal to the end. When the start equals the end, the interval is empty (matches no time). When both start and end are unspecified, the interval matches any time.
+     * Optional. Inclusive start of the interval. If specified, a Timestamp matching this interval will have to be the same or after the start.
      */
     #[serde(
         default,
@@ -6855,7 +7941,7 @@ pub struct Interval {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IterativeCalculationSettings {
     /**
-     * Settings to control how circular dependencies are resolved with iterative calculation.
+     * When iterative calculation is enabled and successive results differ by less than this threshold value, the calculation rounds stop.
      */
     #[serde(
         default,
@@ -6865,7 +7951,7 @@ pub struct IterativeCalculationSettings {
     )]
     pub convergence_threshold: f64,
     /**
-     * Settings to control how circular dependencies are resolved with iterative calculation.
+     * When iterative calculation is enabled, the maximum number of calculation rounds to perform.
      */
     #[serde(
         default,
@@ -6880,12 +7966,12 @@ pub struct IterativeCalculationSettings {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct KeyValueFormat {
     /**
-     * Formatting options for key value.
+     * Position settings for text.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub position: Option<TextPosition>,
     /**
-     * Formatting options for key value.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -6899,6 +7985,7 @@ pub struct KeyValueFormat {
 * The dash type of the line.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LineStyleType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -6920,8 +8007,9 @@ pub enum LineStyleType {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LineStyleType {
@@ -6937,12 +8025,39 @@ impl std::fmt::Display for LineStyleType {
             LineStyleType::MediumDashedDotted => "MEDIUM_DASHED_DOTTED",
             LineStyleType::Solid => "SOLID",
             LineStyleType::Noop => "",
-            LineStyleType::FallthroughString => "*",
+            LineStyleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LineStyleType {
+    fn from(s: String) -> LineStyleType {
+        match s.as_str() {
+            "CUSTOM" => LineStyleType::Custom,
+            "DOTTED" => LineStyleType::Dotted,
+            "INVISIBLE" => LineStyleType::Invisible,
+            "LINE_DASH_TYPE_UNSPECIFIED" => LineStyleType::LineDashTypeUnspecified,
+            "LONG_DASHED" => LineStyleType::LongDashed,
+            "LONG_DASHED_DOTTED" => LineStyleType::LongDashedDotted,
+            "MEDIUM_DASHED" => LineStyleType::MediumDashed,
+            "MEDIUM_DASHED_DOTTED" => LineStyleType::MediumDashedDotted,
+            "SOLID" => LineStyleType::Solid,
+            "" => LineStyleType::Noop,
+            _ => LineStyleType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LineStyleType> for String {
+    fn from(e: LineStyleType) -> String {
+        match e {
+            LineStyleType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LineStyleType {
     fn default() -> LineStyleType {
         LineStyleType::Noop
@@ -6958,12 +8073,12 @@ impl LineStyleType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LineStyle {
     /**
-     * Properties that describe the style of a line.
+     * The dash type of the line.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<LineStyleType>,
     /**
-     * Properties that describe the style of a line.
+     * The thickness of the line, in px.
      */
     #[serde(
         default,
@@ -6977,7 +8092,7 @@ pub struct LineStyle {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Link {
     /**
-     * An external or local reference.
+     * The link identifier.
      */
     #[serde(
         default,
@@ -6991,7 +8106,7 @@ pub struct Link {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ManualRule {
     /**
-     * Allows you to manually organize the values in a source data column into buckets with names of your choosing. For example, a pivot table that aggregates population by state: +-------+-------------------+ | State | SUM of Population | +-------+-------------------+ | AK | 0.7 | | AL | 4.8 | | AR | 2.9 | ... +-------+-------------------+ could be turned into a pivot table that aggregates population by time zone by providing a list of groups (for example, groupName = 'Central', items = ['AL', 'AR', 'IA', ...]) to a manual group rule. Note that a similar effect could be achieved by adding a time zone column to the source data and adjusting the pivot table. +-----------+-------------------+ | Time Zone | SUM of Population | +-----------+-------------------+ | Central | 106.3 | | Eastern | 151.9 | | Mountain | 17.4 | ... +-----------+-------------------+
+     * The list of group names and the corresponding items from the source data that map to each group name.
      */
     #[serde(
         default,
@@ -7005,12 +8120,12 @@ pub struct ManualRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ManualRuleGroup {
     /**
-     * A group name and a list of items from the source data that should be placed in the group with this name.
+     * The kinds of value that a cell in a spreadsheet can have.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupName")]
     pub group_name: Option<ExtendedValue>,
     /**
-     * A group name and a list of items from the source data that should be placed in the group with this name.
+     * The items in the source data that should be placed into this group. Each item may be a string, number, or boolean. Items may appear in at most one group within a given ManualRule. Items that do not appear in any group will appear on their own.
      */
     #[serde(
         default,
@@ -7024,7 +8139,7 @@ pub struct ManualRuleGroup {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MatchedDeveloperMetadata {
     /**
-     * A developer metadata entry and the data filters specified in the original request that matched it.
+     * All filters matching the returned developer metadata.
      */
     #[serde(
         default,
@@ -7034,7 +8149,7 @@ pub struct MatchedDeveloperMetadata {
     )]
     pub data_filters: Vec<DataFilter>,
     /**
-     * A developer metadata entry and the data filters specified in the original request that matched it.
+     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
      */
     #[serde(
         default,
@@ -7048,7 +8163,7 @@ pub struct MatchedDeveloperMetadata {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MatchedValueRange {
     /**
-     * A value range that was matched by one or more data filers.
+     * The DataFilters from the request that matched the range of values.
      */
     #[serde(
         default,
@@ -7058,7 +8173,7 @@ pub struct MatchedValueRange {
     )]
     pub data_filters: Vec<DataFilter>,
     /**
-     * A value range that was matched by one or more data filers.
+     * Data within a range of the spreadsheet.
      */
     #[serde(
         default,
@@ -7072,6 +8187,7 @@ pub struct MatchedValueRange {
 * How the cells should be merged.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MergeType {
     #[serde(rename = "MERGE_ALL")]
     MergeAll,
@@ -7081,8 +8197,9 @@ pub enum MergeType {
     MergeRows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeType {
@@ -7092,12 +8209,33 @@ impl std::fmt::Display for MergeType {
             MergeType::MergeColumns => "MERGE_COLUMNS",
             MergeType::MergeRows => "MERGE_ROWS",
             MergeType::Noop => "",
-            MergeType::FallthroughString => "*",
+            MergeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MergeType {
+    fn from(s: String) -> MergeType {
+        match s.as_str() {
+            "MERGE_ALL" => MergeType::MergeAll,
+            "MERGE_COLUMNS" => MergeType::MergeColumns,
+            "MERGE_ROWS" => MergeType::MergeRows,
+            "" => MergeType::Noop,
+            _ => MergeType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MergeType> for String {
+    fn from(e: MergeType) -> String {
+        match e {
+            MergeType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MergeType {
     fn default() -> MergeType {
         MergeType::Noop
@@ -7113,12 +8251,12 @@ impl MergeType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MergeCellsRequest {
     /**
-     * Merges all cells in the range.
+     * How the cells should be merged.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "mergeType")]
     pub merge_type: Option<MergeType>,
     /**
-     * Merges all cells in the range.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -7128,7 +8266,7 @@ pub struct MergeCellsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MoveDimensionRequest {
     /**
-     * Moves one or more rows or columns.
+     * The zero-based start index of where to move the source data to, based on the coordinates \*before\* the source data is removed from the grid. Existing data will be shifted down or right (depending on the dimension) to make room for the moved dimensions. The source dimensions are removed from the grid, so the the data may end up in a different index than specified. For example, given `A1..A5` of `0, 1, 2, 3, 4` and wanting to move `"1"` and `"2"` to between `"3"` and `"4"`, the source would be `ROWS [1..3)`,and the destination index would be `"4"` (the zero-based index of row 5). The end result would be `A1..A5` of `0, 3, 1, 2, 4`.
      */
     #[serde(
         default,
@@ -7138,7 +8276,7 @@ pub struct MoveDimensionRequest {
     )]
     pub destination_index: i64,
     /**
-     * Moves one or more rows or columns.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<DimensionRange>,
@@ -7148,7 +8286,7 @@ pub struct MoveDimensionRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NamedRange {
     /**
-     * A named range.
+     * The name of the named range.
      */
     #[serde(
         default,
@@ -7157,7 +8295,7 @@ pub struct NamedRange {
     )]
     pub name: String,
     /**
-     * A named range.
+     * The ID of the named range.
      */
     #[serde(
         default,
@@ -7167,7 +8305,7 @@ pub struct NamedRange {
     )]
     pub named_range_id: String,
     /**
-     * A named range.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -7177,6 +8315,7 @@ pub struct NamedRange {
 * The type of the number format. When writing, this field must be set.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NumberFormatType {
     #[serde(rename = "CURRENCY")]
     Currency,
@@ -7198,8 +8337,9 @@ pub enum NumberFormatType {
     Time,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatType {
@@ -7215,12 +8355,39 @@ impl std::fmt::Display for NumberFormatType {
             NumberFormatType::Text => "TEXT",
             NumberFormatType::Time => "TIME",
             NumberFormatType::Noop => "",
-            NumberFormatType::FallthroughString => "*",
+            NumberFormatType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NumberFormatType {
+    fn from(s: String) -> NumberFormatType {
+        match s.as_str() {
+            "CURRENCY" => NumberFormatType::Currency,
+            "DATE" => NumberFormatType::Date,
+            "DATE_TIME" => NumberFormatType::DateTime,
+            "NUMBER" => NumberFormatType::Number,
+            "NUMBER_FORMAT_TYPE_UNSPECIFIED" => NumberFormatType::NumberFormatTypeUnspecified,
+            "PERCENT" => NumberFormatType::Percent,
+            "SCIENTIFIC" => NumberFormatType::Scientific,
+            "TEXT" => NumberFormatType::Text,
+            "TIME" => NumberFormatType::Time,
+            "" => NumberFormatType::Noop,
+            _ => NumberFormatType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NumberFormatType> for String {
+    fn from(e: NumberFormatType) -> String {
+        match e {
+            NumberFormatType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NumberFormatType {
     fn default() -> NumberFormatType {
         NumberFormatType::Noop
@@ -7236,7 +8403,7 @@ impl NumberFormatType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NumberFormat {
     /**
-     * The number format of a cell.
+     * Pattern string used for formatting. If not set, a default pattern based on the user's locale will be used if necessary for the given type. See the [Date and Number Formats guide](/sheets/api/guides/formats) for more information about the supported patterns.
      */
     #[serde(
         default,
@@ -7245,7 +8412,7 @@ pub struct NumberFormat {
     )]
     pub pattern: String,
     /**
-     * The number format of a cell.
+     * The type of the number format. When writing, this field must be set.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<NumberFormatType>,
@@ -7255,6 +8422,7 @@ pub struct NumberFormat {
 * The size of the org chart nodes.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NodeSize {
     #[serde(rename = "LARGE")]
     Large,
@@ -7266,8 +8434,9 @@ pub enum NodeSize {
     Small,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NodeSize {
@@ -7278,12 +8447,34 @@ impl std::fmt::Display for NodeSize {
             NodeSize::OrgChartLabelSizeUnspecified => "ORG_CHART_LABEL_SIZE_UNSPECIFIED",
             NodeSize::Small => "SMALL",
             NodeSize::Noop => "",
-            NodeSize::FallthroughString => "*",
+            NodeSize::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NodeSize {
+    fn from(s: String) -> NodeSize {
+        match s.as_str() {
+            "LARGE" => NodeSize::Large,
+            "MEDIUM" => NodeSize::Medium,
+            "ORG_CHART_LABEL_SIZE_UNSPECIFIED" => NodeSize::OrgChartLabelSizeUnspecified,
+            "SMALL" => NodeSize::Small,
+            "" => NodeSize::Noop,
+            _ => NodeSize::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NodeSize> for String {
+    fn from(e: NodeSize) -> String {
+        match e {
+            NodeSize::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NodeSize {
     fn default() -> NodeSize {
         NodeSize::Noop
@@ -7299,17 +8490,17 @@ impl NodeSize {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgChartSpec {
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub labels: Option<ChartData>,
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "nodeColor")]
     pub node_color: Option<Color>,
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * A color value.
      */
     #[serde(
         default,
@@ -7318,12 +8509,12 @@ pub struct OrgChartSpec {
     )]
     pub node_color_style: Option<ColorStyle>,
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * The size of the org chart nodes.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "nodeSize")]
     pub node_size: Option<NodeSize>,
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * The data included in a domain or series.
      */
     #[serde(
         default,
@@ -7332,7 +8523,7 @@ pub struct OrgChartSpec {
     )]
     pub parent_labels: Option<ChartData>,
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -7341,7 +8532,7 @@ pub struct OrgChartSpec {
     )]
     pub selected_node_color: Option<Color>,
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * A color value.
      */
     #[serde(
         default,
@@ -7350,7 +8541,7 @@ pub struct OrgChartSpec {
     )]
     pub selected_node_color_style: Option<ColorStyle>,
     /**
-     * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tooltips: Option<ChartData>,
@@ -7360,7 +8551,7 @@ pub struct OrgChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OverlayPosition {
     /**
-     * The location an object is overlaid on top of a grid.
+     * A coordinate in a sheet. All indexes are zero-based.
      */
     #[serde(
         default,
@@ -7369,7 +8560,7 @@ pub struct OverlayPosition {
     )]
     pub anchor_cell: Option<GridCoordinate>,
     /**
-     * The location an object is overlaid on top of a grid.
+     * The height of the object, in pixels. Defaults to 371.
      */
     #[serde(
         default,
@@ -7379,7 +8570,7 @@ pub struct OverlayPosition {
     )]
     pub height_pixels: i64,
     /**
-     * The location an object is overlaid on top of a grid.
+     * The horizontal offset, in pixels, that the object is offset from the anchor cell.
      */
     #[serde(
         default,
@@ -7389,7 +8580,7 @@ pub struct OverlayPosition {
     )]
     pub offset_x_pixels: i64,
     /**
-     * The location an object is overlaid on top of a grid.
+     * The vertical offset, in pixels, that the object is offset from the anchor cell.
      */
     #[serde(
         default,
@@ -7399,7 +8590,7 @@ pub struct OverlayPosition {
     )]
     pub offset_y_pixels: i64,
     /**
-     * The location an object is overlaid on top of a grid.
+     * The width of the object, in pixels. Defaults to 600.
      */
     #[serde(
         default,
@@ -7414,7 +8605,7 @@ pub struct OverlayPosition {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Padding {
     /**
-     * The amount of padding around the cell, in pixels. When updating padding, every field must be specified.
+     * The bottom padding of the cell.
      */
     #[serde(
         default,
@@ -7423,7 +8614,7 @@ pub struct Padding {
     )]
     pub bottom: i64,
     /**
-     * The amount of padding around the cell, in pixels. When updating padding, every field must be specified.
+     * The left padding of the cell.
      */
     #[serde(
         default,
@@ -7432,7 +8623,7 @@ pub struct Padding {
     )]
     pub left: i64,
     /**
-     * The amount of padding around the cell, in pixels. When updating padding, every field must be specified.
+     * The right padding of the cell.
      */
     #[serde(
         default,
@@ -7441,7 +8632,7 @@ pub struct Padding {
     )]
     pub right: i64,
     /**
-     * The amount of padding around the cell, in pixels. When updating padding, every field must be specified.
+     * The top padding of the cell.
      */
     #[serde(
         default,
@@ -7455,12 +8646,12 @@ pub struct Padding {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasteDataRequest {
     /**
-     * Inserts data into the spreadsheet starting at the specified coordinate.
+     * A coordinate in a sheet. All indexes are zero-based.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub coordinate: Option<GridCoordinate>,
     /**
-     * Inserts data into the spreadsheet starting at the specified coordinate.
+     * The data to insert.
      */
     #[serde(
         default,
@@ -7469,7 +8660,7 @@ pub struct PasteDataRequest {
     )]
     pub data: String,
     /**
-     * Inserts data into the spreadsheet starting at the specified coordinate.
+     * The delimiter in the data.
      */
     #[serde(
         default,
@@ -7478,12 +8669,12 @@ pub struct PasteDataRequest {
     )]
     pub delimiter: String,
     /**
-     * Inserts data into the spreadsheet starting at the specified coordinate.
+     * True if the data is HTML.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub html: Option<bool>,
     /**
-     * Inserts data into the spreadsheet starting at the specified coordinate.
+     * How the data should be pasted.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<PasteType>,
@@ -7493,6 +8684,7 @@ pub struct PasteDataRequest {
 * Where the legend of the pie chart should be drawn.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PieChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -7510,8 +8702,9 @@ pub enum PieChartSpecLegendPosition {
     TopLegend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PieChartSpecLegendPosition {
@@ -7527,12 +8720,39 @@ impl std::fmt::Display for PieChartSpecLegendPosition {
             PieChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             PieChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
             PieChartSpecLegendPosition::Noop => "",
-            PieChartSpecLegendPosition::FallthroughString => "*",
+            PieChartSpecLegendPosition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PieChartSpecLegendPosition {
+    fn from(s: String) -> PieChartSpecLegendPosition {
+        match s.as_str() {
+            "BOTTOM_LEGEND" => PieChartSpecLegendPosition::BottomLegend,
+            "LABELED_LEGEND" => PieChartSpecLegendPosition::LabeledLegend,
+            "LEFT_LEGEND" => PieChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => PieChartSpecLegendPosition::NoLegend,
+            "PIE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                PieChartSpecLegendPosition::PieChartLegendPositionUnspecified
+            }
+            "RIGHT_LEGEND" => PieChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => PieChartSpecLegendPosition::TopLegend,
+            "" => PieChartSpecLegendPosition::Noop,
+            _ => PieChartSpecLegendPosition::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PieChartSpecLegendPosition> for String {
+    fn from(e: PieChartSpecLegendPosition) -> String {
+        match e {
+            PieChartSpecLegendPosition::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PieChartSpecLegendPosition {
     fn default() -> PieChartSpecLegendPosition {
         PieChartSpecLegendPosition::Noop
@@ -7548,12 +8768,12 @@ impl PieChartSpecLegendPosition {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PieChartSpec {
     /**
-     * A pie chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<ChartData>,
     /**
-     * A pie chart.
+     * Where the legend of the pie chart should be drawn.
      */
     #[serde(
         default,
@@ -7562,7 +8782,7 @@ pub struct PieChartSpec {
     )]
     pub legend_position: Option<PieChartSpecLegendPosition>,
     /**
-     * A pie chart.
+     * The size of the hole in the pie chart.
      */
     #[serde(
         default,
@@ -7572,12 +8792,12 @@ pub struct PieChartSpec {
     )]
     pub pie_hole: f64,
     /**
-     * A pie chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub series: Option<ChartData>,
     /**
-     * A pie chart.
+     * True if the pie is three dimensional.
      */
     #[serde(
         default,
@@ -7591,12 +8811,12 @@ pub struct PieChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotFilterCriteria {
     /**
-     * Criteria for showing/hiding rows in a pivot table.
+     * A condition that can evaluate to true or false. BooleanConditions are used by conditional formatting, data validation, and the criteria in filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub condition: Option<BooleanCondition>,
     /**
-     * Criteria for showing/hiding rows in a pivot table.
+     * Whether values are visible by default. If true, the visible_values are ignored, all values that meet condition (if specified) are shown. If false, values that are both in visible_values and meet condition are shown.
      */
     #[serde(
         default,
@@ -7605,7 +8825,7 @@ pub struct PivotFilterCriteria {
     )]
     pub visible_by_default: bool,
     /**
-     * Criteria for showing/hiding rows in a pivot table.
+     * Values that should be included. Values not listed here are excluded.
      */
     #[serde(
         default,
@@ -7620,7 +8840,7 @@ pub struct PivotFilterCriteria {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotFilterSpec {
     /**
-     * The pivot table filter criteria associated with a specific source column offset.
+     * The column offset of the source range.
      */
     #[serde(
         default,
@@ -7630,7 +8850,7 @@ pub struct PivotFilterSpec {
     )]
     pub column_offset_index: i64,
     /**
-     * The pivot table filter criteria associated with a specific source column offset.
+     * An unique identifier that references a data source column.
      */
     #[serde(
         default,
@@ -7639,7 +8859,7 @@ pub struct PivotFilterSpec {
     )]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
-     * The pivot table filter criteria associated with a specific source column offset.
+     * Criteria for showing/hiding rows in a pivot table.
      */
     #[serde(
         default,
@@ -7653,6 +8873,7 @@ pub struct PivotFilterSpec {
 * The order the values in this group should be sorted.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -7662,8 +8883,9 @@ pub enum SortOrder {
     SortOrderUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -7673,12 +8895,33 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Descending => "DESCENDING",
             SortOrder::SortOrderUnspecified => "SORT_ORDER_UNSPECIFIED",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SortOrder {
+    fn from(s: String) -> SortOrder {
+        match s.as_str() {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            "SORT_ORDER_UNSPECIFIED" => SortOrder::SortOrderUnspecified,
+            "" => SortOrder::Noop,
+            _ => SortOrder::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SortOrder> for String {
+    fn from(e: SortOrder) -> String {
+        match e {
+            SortOrder::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -7694,7 +8937,7 @@ impl SortOrder {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotGroup {
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * An unique identifier that references a data source column.
      */
     #[serde(
         default,
@@ -7703,7 +8946,7 @@ pub struct PivotGroup {
     )]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * The count limit on rows or columns in the pivot group.
      */
     #[serde(
         default,
@@ -7712,12 +8955,12 @@ pub struct PivotGroup {
     )]
     pub group_limit: Option<PivotGroupLimit>,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupRule")]
     pub group_rule: Option<PivotGroupRule>,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * The labels to use for the row/column groups which can be customized. For example, in the following pivot table, the row label is `Region` (which could be renamed to `State`) and the column label is `Product` (which could be renamed `Item`). Pivot tables created before December 2017 do not have header labels. If you'd like to add header labels to an existing pivot table, please delete the existing pivot table and then create a new pivot table with same parameters. +--------------+---------+-------+ | SUM of Units | Product | | | Region | Pen | Paper | +--------------+---------+-------+ | New York | 345 | 98 | | Oregon | 234 | 123 | | Tennessee | 531 | 415 | +--------------+---------+-------+ | Grand Total | 1110 | 636 | +--------------+---------+-------+
      */
     #[serde(
         default,
@@ -7726,7 +8969,7 @@ pub struct PivotGroup {
     )]
     pub label: String,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * True if the headings in this pivot group should be repeated. This is only valid for row groupings and is ignored by columns. By default, we minimize repetition of headings by not showing higher level headings where they are the same. For example, even though the third row below corresponds to "Q1 Mar", "Q1" is not shown because it is redundant with previous rows. Setting repeat_headings to true would cause "Q1" to be repeated for "Feb" and "Mar". +--------------+ | Q1 | Jan | | | Feb | | | Mar | +--------+-----+ | Q1 Total | +--------------+
      */
     #[serde(
         default,
@@ -7735,7 +8978,7 @@ pub struct PivotGroup {
     )]
     pub repeat_headings: bool,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * True if the pivot table should include the totals for this grouping.
      */
     #[serde(
         default,
@@ -7744,12 +8987,12 @@ pub struct PivotGroup {
     )]
     pub show_totals: bool,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * The order the values in this group should be sorted.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "sortOrder")]
     pub sort_order: Option<SortOrder>,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * The column offset of the source range that this grouping is based on. For example, if the source was `C10:E15`, a `sourceColumnOffset` of `0` means this group refers to column `C`, whereas the offset `1` would refer to column `D`.
      */
     #[serde(
         default,
@@ -7759,7 +9002,7 @@ pub struct PivotGroup {
     )]
     pub source_column_offset: i64,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * Information about which values in a pivot group should be used for sorting.
      */
     #[serde(
         default,
@@ -7768,7 +9011,7 @@ pub struct PivotGroup {
     )]
     pub value_bucket: Option<PivotGroupSortValueBucket>,
     /**
-     * A single grouping (either row or column) in a pivot table.
+     * Metadata about values in the grouping.
      */
     #[serde(
         default,
@@ -7783,7 +9026,7 @@ pub struct PivotGroup {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotGroupLimit {
     /**
-     * The count limit on rows or columns in the pivot group.
+     * The order in which the group limit is applied to the pivot table. Pivot group limits are applied from lower to higher order number. Order numbers are normalized to consecutive integers from 0. For write request, to fully customize the applying orders, all pivot group limits should have this field set with an unique number. Otherwise, the order is determined by the index in the PivotTable.rows list and then the PivotTable.columns list.
      */
     #[serde(
         default,
@@ -7793,7 +9036,7 @@ pub struct PivotGroupLimit {
     )]
     pub apply_order: i64,
     /**
-     * The count limit on rows or columns in the pivot group.
+     * The count limit.
      */
     #[serde(
         default,
@@ -7808,7 +9051,7 @@ pub struct PivotGroupLimit {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotGroupRule {
     /**
-     * An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
+     * Allows you to organize the date-time values in a source data column into buckets based on selected parts of their date or time values. For example, consider a pivot table showing sales transactions by date: +----------+--------------+ | Date | SUM of Sales | +----------+--------------+ | 1/1/2017 | $621.14 | | 2/3/2017 | $708.84 | | 5/8/2017 | $326.84 | ... +----------+--------------+ Applying a date-time group rule with a DateTimeRuleType of YEAR_MONTH results in the following pivot table. +--------------+--------------+ | Grouped Date | SUM of Sales | +--------------+--------------+ | 2017-Jan | $53,731.78 | | 2017-Feb | $83,475.32 | | 2017-Mar | $94,385.05 | ... +--------------+--------------+
      */
     #[serde(
         default,
@@ -7817,7 +9060,7 @@ pub struct PivotGroupRule {
     )]
     pub date_time_rule: Option<DateTimeRule>,
     /**
-     * An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
+     * Allows you to organize the numeric values in a source data column into buckets of a constant size. All values from HistogramRule.start to HistogramRule.end are placed into groups of size HistogramRule.interval. In addition, all values below HistogramRule.start are placed in one group, and all values above HistogramRule.end are placed in another. Only HistogramRule.interval is required, though if HistogramRule.start and HistogramRule.end are both provided, HistogramRule.start must be less than HistogramRule.end. For example, a pivot table showing average purchase amount by age that has 50+ rows: +-----+-------------------+ | Age | AVERAGE of Amount | +-----+-------------------+ | 16 | $27.13 | | 17 | $5.24 | | 18 | $20.15 | ... +-----+-------------------+ could be turned into a pivot table that looks like the one below by applying a histogram group rule with a HistogramRule.start of 25, an HistogramRule.interval of 20, and an HistogramRule.end of 65. +-------------+-------------------+ | Grouped Age | AVERAGE of Amount | +-------------+-------------------+ | < 25 | $19.34 | | 25-45 | $31.43 | | 45-65 | $35.87 | | > 65 | $27.55 | +-------------+-------------------+ | Grand Total | $29.12 | +-------------+-------------------+
      */
     #[serde(
         default,
@@ -7826,7 +9069,7 @@ pub struct PivotGroupRule {
     )]
     pub histogram_rule: Option<HistogramRule>,
     /**
-     * An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
+     * Allows you to manually organize the values in a source data column into buckets with names of your choosing. For example, a pivot table that aggregates population by state: +-------+-------------------+ | State | SUM of Population | +-------+-------------------+ | AK | 0.7 | | AL | 4.8 | | AR | 2.9 | ... +-------+-------------------+ could be turned into a pivot table that aggregates population by time zone by providing a list of groups (for example, groupName = 'Central', items = ['AL', 'AR', 'IA', ...]) to a manual group rule. Note that a similar effect could be achieved by adding a time zone column to the source data and adjusting the pivot table. +-----------+-------------------+ | Time Zone | SUM of Population | +-----------+-------------------+ | Central | 106.3 | | Eastern | 151.9 | | Mountain | 17.4 | ... +-----------+-------------------+
      */
     #[serde(
         default,
@@ -7840,7 +9083,7 @@ pub struct PivotGroupRule {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotGroupSortValueBucket {
     /**
-     * Information about which values in a pivot group should be used for sorting.
+     * Determines the bucket from which values are chosen to sort. For example, in a pivot table with one row group & two column groups, the row group can list up to two values. The first value corresponds to a value within the first column group, and the second value corresponds to a value in the second column group. If no values are listed, this would indicate that the row should be sorted according to the "Grand Total" over the column groups. If a single value is listed, this would correspond to using the "Total" of that bucket.
      */
     #[serde(
         default,
@@ -7849,7 +9092,7 @@ pub struct PivotGroupSortValueBucket {
     )]
     pub buckets: Vec<ExtendedValue>,
     /**
-     * Information about which values in a pivot group should be used for sorting.
+     * The offset in the PivotTable.values list which the values in this grouping should be sorted by.
      */
     #[serde(
         default,
@@ -7864,7 +9107,7 @@ pub struct PivotGroupSortValueBucket {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotGroupValueMetadata {
     /**
-     * Metadata about a value in a pivot grouping.
+     * True if the data corresponding to the value is collapsed.
      */
     #[serde(
         default,
@@ -7872,7 +9115,7 @@ pub struct PivotGroupValueMetadata {
     )]
     pub collapsed: bool,
     /**
-     * Metadata about a value in a pivot grouping.
+     * The kinds of value that a cell in a spreadsheet can have.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<ExtendedValue>,
@@ -7882,6 +9125,7 @@ pub struct PivotGroupValueMetadata {
 * Whether values should be listed horizontally (as columns) or vertically (as rows).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ValueLayout {
     #[serde(rename = "HORIZONTAL")]
     Horizontal,
@@ -7889,8 +9133,9 @@ pub enum ValueLayout {
     Vertical,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueLayout {
@@ -7899,12 +9144,32 @@ impl std::fmt::Display for ValueLayout {
             ValueLayout::Horizontal => "HORIZONTAL",
             ValueLayout::Vertical => "VERTICAL",
             ValueLayout::Noop => "",
-            ValueLayout::FallthroughString => "*",
+            ValueLayout::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ValueLayout {
+    fn from(s: String) -> ValueLayout {
+        match s.as_str() {
+            "HORIZONTAL" => ValueLayout::Horizontal,
+            "VERTICAL" => ValueLayout::Vertical,
+            "" => ValueLayout::Noop,
+            _ => ValueLayout::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ValueLayout> for String {
+    fn from(e: ValueLayout) -> String {
+        match e {
+            ValueLayout::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ValueLayout {
     fn default() -> ValueLayout {
         ValueLayout::Noop
@@ -7920,7 +9185,7 @@ impl ValueLayout {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotTable {
     /**
-     * A pivot table.
+     * Each column grouping in the pivot table.
      */
     #[serde(
         default,
@@ -7929,12 +9194,12 @@ pub struct PivotTable {
     )]
     pub columns: Vec<PivotGroup>,
     /**
-     * A pivot table.
+     * An optional mapping of filters per source column offset. The filters are applied before aggregating data into the pivot table. The map's key is the column offset of the source range that you want to filter, and the value is the criteria for that column. For example, if the source was `C10:E15`, a key of `0` will have the filter for column `C`, whereas the key `1` is for column `D`. This field is deprecated in favor of filter_specs.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub criteria: Option<PivotFilterCriteria>,
     /**
-     * A pivot table.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -7943,7 +9208,7 @@ pub struct PivotTable {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * A pivot table.
+     * The ID of the data source the pivot table is reading data from.
      */
     #[serde(
         default,
@@ -7953,7 +9218,7 @@ pub struct PivotTable {
     )]
     pub data_source_id: String,
     /**
-     * A pivot table.
+     * The filters applied to the source columns before aggregating data for the pivot table. Both criteria and filter_specs are populated in responses. If both fields are specified in an update request, this field takes precedence.
      */
     #[serde(
         default,
@@ -7963,7 +9228,7 @@ pub struct PivotTable {
     )]
     pub filter_specs: Vec<PivotFilterSpec>,
     /**
-     * A pivot table.
+     * Each row grouping in the pivot table.
      */
     #[serde(
         default,
@@ -7972,12 +9237,12 @@ pub struct PivotTable {
     )]
     pub rows: Vec<PivotGroup>,
     /**
-     * A pivot table.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
     /**
-     * A pivot table.
+     * Whether values should be listed horizontally (as columns) or vertically (as rows).
      */
     #[serde(
         default,
@@ -7986,7 +9251,7 @@ pub struct PivotTable {
     )]
     pub value_layout: Option<ValueLayout>,
     /**
-     * A pivot table.
+     * A list of values to include in the pivot table.
      */
     #[serde(
         default,
@@ -8000,6 +9265,7 @@ pub struct PivotTable {
 * If specified, indicates that pivot values should be displayed as the result of a calculation with another pivot value. For example, if calculated_display_type is specified as PERCENT_OF_GRAND_TOTAL, all the pivot values are displayed as the percentage of the grand total. In the Sheets editor, this is referred to as "Show As" in the value section of a pivot table.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CalculatedDisplayType {
     #[serde(rename = "PERCENT_OF_COLUMN_TOTAL")]
     PercentOfColumnTotal,
@@ -8011,8 +9277,9 @@ pub enum CalculatedDisplayType {
     PivotValueCalculatedDisplayTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CalculatedDisplayType {
@@ -8025,12 +9292,36 @@ impl std::fmt::Display for CalculatedDisplayType {
                 "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED"
             }
             CalculatedDisplayType::Noop => "",
-            CalculatedDisplayType::FallthroughString => "*",
+            CalculatedDisplayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CalculatedDisplayType {
+    fn from(s: String) -> CalculatedDisplayType {
+        match s.as_str() {
+            "PERCENT_OF_COLUMN_TOTAL" => CalculatedDisplayType::PercentOfColumnTotal,
+            "PERCENT_OF_GRAND_TOTAL" => CalculatedDisplayType::PercentOfGrandTotal,
+            "PERCENT_OF_ROW_TOTAL" => CalculatedDisplayType::PercentOfRowTotal,
+            "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED" => {
+                CalculatedDisplayType::PivotValueCalculatedDisplayTypeUnspecified
+            }
+            "" => CalculatedDisplayType::Noop,
+            _ => CalculatedDisplayType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CalculatedDisplayType> for String {
+    fn from(e: CalculatedDisplayType) -> String {
+        match e {
+            CalculatedDisplayType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CalculatedDisplayType {
     fn default() -> CalculatedDisplayType {
         CalculatedDisplayType::Noop
@@ -8046,6 +9337,7 @@ impl CalculatedDisplayType {
 * A function to summarize the value. If formula is set, the only supported values are SUM and CUSTOM. If sourceColumnOffset is set, then `CUSTOM` is not supported.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SummarizeFunction {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -8079,8 +9371,9 @@ pub enum SummarizeFunction {
     Varp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SummarizeFunction {
@@ -8104,12 +9397,47 @@ impl std::fmt::Display for SummarizeFunction {
             SummarizeFunction::Var => "VAR",
             SummarizeFunction::Varp => "VARP",
             SummarizeFunction::Noop => "",
-            SummarizeFunction::FallthroughString => "*",
+            SummarizeFunction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SummarizeFunction {
+    fn from(s: String) -> SummarizeFunction {
+        match s.as_str() {
+            "AVERAGE" => SummarizeFunction::Average,
+            "COUNT" => SummarizeFunction::Count,
+            "COUNTA" => SummarizeFunction::Counta,
+            "COUNTUNIQUE" => SummarizeFunction::Countunique,
+            "CUSTOM" => SummarizeFunction::Custom,
+            "MAX" => SummarizeFunction::Max,
+            "MEDIAN" => SummarizeFunction::Median,
+            "MIN" => SummarizeFunction::Min,
+            "PIVOT_STANDARD_VALUE_FUNCTION_UNSPECIFIED" => {
+                SummarizeFunction::PivotStandardValueFunctionUnspecified
+            }
+            "PRODUCT" => SummarizeFunction::Product,
+            "STDEV" => SummarizeFunction::Stdev,
+            "STDEVP" => SummarizeFunction::Stdevp,
+            "SUM" => SummarizeFunction::Sum,
+            "VAR" => SummarizeFunction::Var,
+            "VARP" => SummarizeFunction::Varp,
+            "" => SummarizeFunction::Noop,
+            _ => SummarizeFunction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SummarizeFunction> for String {
+    fn from(e: SummarizeFunction) -> String {
+        match e {
+            SummarizeFunction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SummarizeFunction {
     fn default() -> SummarizeFunction {
         SummarizeFunction::Noop
@@ -8125,7 +9453,7 @@ impl SummarizeFunction {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PivotValue {
     /**
-     * The definition of how a value in a pivot table should be calculated.
+     * If specified, indicates that pivot values should be displayed as the result of a calculation with another pivot value. For example, if calculated_display_type is specified as PERCENT_OF_GRAND_TOTAL, all the pivot values are displayed as the percentage of the grand total. In the Sheets editor, this is referred to as "Show As" in the value section of a pivot table.
      */
     #[serde(
         default,
@@ -8134,7 +9462,7 @@ pub struct PivotValue {
     )]
     pub calculated_display_type: Option<CalculatedDisplayType>,
     /**
-     * The definition of how a value in a pivot table should be calculated.
+     * An unique identifier that references a data source column.
      */
     #[serde(
         default,
@@ -8143,7 +9471,7 @@ pub struct PivotValue {
     )]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
-     * The definition of how a value in a pivot table should be calculated.
+     * A custom formula to calculate the value. The formula must start with an `=` character.
      */
     #[serde(
         default,
@@ -8152,7 +9480,7 @@ pub struct PivotValue {
     )]
     pub formula: String,
     /**
-     * The definition of how a value in a pivot table should be calculated.
+     * A name to use for the value.
      */
     #[serde(
         default,
@@ -8161,7 +9489,7 @@ pub struct PivotValue {
     )]
     pub name: String,
     /**
-     * The definition of how a value in a pivot table should be calculated.
+     * The column offset of the source range that this value reads from. For example, if the source was `C10:E15`, a `sourceColumnOffset` of `0` means this value refers to column `C`, whereas the offset `1` would refer to column `D`.
      */
     #[serde(
         default,
@@ -8171,7 +9499,7 @@ pub struct PivotValue {
     )]
     pub source_column_offset: i64,
     /**
-     * The definition of how a value in a pivot table should be calculated.
+     * A function to summarize the value. If formula is set, the only supported values are SUM and CUSTOM. If sourceColumnOffset is set, then `CUSTOM` is not supported.
      */
     #[serde(
         default,
@@ -8185,6 +9513,7 @@ pub struct PivotValue {
 * The point shape. If empty or unspecified, a default shape is used.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Shape {
     #[serde(rename = "CIRCLE")]
     Circle,
@@ -8206,8 +9535,9 @@ pub enum Shape {
     XMark,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Shape {
@@ -8223,12 +9553,39 @@ impl std::fmt::Display for Shape {
             Shape::Triangle => "TRIANGLE",
             Shape::XMark => "X_MARK",
             Shape::Noop => "",
-            Shape::FallthroughString => "*",
+            Shape::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Shape {
+    fn from(s: String) -> Shape {
+        match s.as_str() {
+            "CIRCLE" => Shape::Circle,
+            "DIAMOND" => Shape::Diamond,
+            "HEXAGON" => Shape::Hexagon,
+            "PENTAGON" => Shape::Pentagon,
+            "POINT_SHAPE_UNSPECIFIED" => Shape::PointShapeUnspecified,
+            "SQUARE" => Shape::Square,
+            "STAR" => Shape::Star,
+            "TRIANGLE" => Shape::Triangle,
+            "X_MARK" => Shape::XMark,
+            "" => Shape::Noop,
+            _ => Shape::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Shape> for String {
+    fn from(e: Shape) -> String {
+        match e {
+            Shape::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Shape {
     fn default() -> Shape {
         Shape::Noop
@@ -8244,12 +9601,12 @@ impl Shape {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PointStyle {
     /**
-     * The style of a point on the chart.
+     * The point shape. If empty or unspecified, a default shape is used.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shape: Option<Shape>,
     /**
-     * The style of a point on the chart.
+     * The point size. If empty, a default size is used.
      */
     #[serde(
         default,
@@ -8263,7 +9620,7 @@ pub struct PointStyle {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProtectedRange {
     /**
-     * A protected range.
+     * The description of this protected range.
      */
     #[serde(
         default,
@@ -8272,12 +9629,12 @@ pub struct ProtectedRange {
     )]
     pub description: String,
     /**
-     * A protected range.
+     * The editors of a protected range.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub editors: Option<Editors>,
     /**
-     * A protected range.
+     * The named range this protected range is backed by, if any. When writing, only one of range or named_range_id may be set.
      */
     #[serde(
         default,
@@ -8287,7 +9644,7 @@ pub struct ProtectedRange {
     )]
     pub named_range_id: String,
     /**
-     * A protected range.
+     * The ID of the protected range. This field is read-only.
      */
     #[serde(
         default,
@@ -8297,12 +9654,12 @@ pub struct ProtectedRange {
     )]
     pub protected_range_id: i64,
     /**
-     * A protected range.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * A protected range.
+     * True if the user who requested this protected range can edit the protected area. This field is read-only.
      */
     #[serde(
         default,
@@ -8311,7 +9668,7 @@ pub struct ProtectedRange {
     )]
     pub requesting_user_can_edit: bool,
     /**
-     * A protected range.
+     * The list of unprotected ranges within a protected sheet. Unprotected ranges are only supported on protected sheets.
      */
     #[serde(
         default,
@@ -8321,7 +9678,7 @@ pub struct ProtectedRange {
     )]
     pub unprotected_ranges: Vec<GridRange>,
     /**
-     * A protected range.
+     * True if this protected range will show a warning when editing. Warning-based protection means that every user can edit data in the protected range, except editing will prompt a warning asking the user to confirm the edit. When writing: if this field is true, then editors is ignored. Additionally, if this field is changed from true to false and the `editors` field is not set (nor included in the field mask), then the editors will be set to all the editors in the document.
      */
     #[serde(
         default,
@@ -8335,7 +9692,7 @@ pub struct ProtectedRange {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RandomizeRangeRequest {
     /**
-     * Randomizes the order of the rows in a range.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -8345,7 +9702,7 @@ pub struct RandomizeRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RefreshDataSourceObjectExecutionStatus {
     /**
-     * The execution status of refreshing one data source object.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -8354,7 +9711,7 @@ pub struct RefreshDataSourceObjectExecutionStatus {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * The execution status of refreshing one data source object.
+     * Reference to a data source object.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reference: Option<DataSourceObjectReference>,
@@ -8364,7 +9721,7 @@ pub struct RefreshDataSourceObjectExecutionStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RefreshDataSourceRequest {
     /**
-     * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
+     * Reference to a DataSource. If specified, refreshes all associated data source objects for the data source.
      */
     #[serde(
         default,
@@ -8374,17 +9731,17 @@ pub struct RefreshDataSourceRequest {
     )]
     pub data_source_id: String,
     /**
-     * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
+     * Refreshes the data source objects regardless of the current state. If not set and a referenced data source object was in error state, the refresh will fail immediately.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub force: Option<bool>,
     /**
-     * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
+     * Refreshes all existing data source objects in the spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "isAll")]
     pub is_all: Option<bool>,
     /**
-     * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
+     * A list of references to data source objects.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub references: Option<DataSourceObjectReferences>,
@@ -8394,7 +9751,7 @@ pub struct RefreshDataSourceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RefreshDataSourceResponse {
     /**
-     * The response from refreshing one or multiple data source objects.
+     * All the refresh status for the data source object references specified in the request. If is_all is specified, the field contains only those in failure status.
      */
     #[serde(
         default,
@@ -8408,12 +9765,12 @@ pub struct RefreshDataSourceResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RepeatCellRequest {
     /**
-     * Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
+     * Data about a specific cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cell: Option<CellData>,
     /**
-     * Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
+     * The fields that should be updated. At least one field must be specified. The root `cell` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -8422,7 +9779,7 @@ pub struct RepeatCellRequest {
     )]
     pub fields: String,
     /**
-     * Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -8432,7 +9789,7 @@ pub struct RepeatCellRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Request {
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a new banded range to the spreadsheet.
      */
     #[serde(
         default,
@@ -8441,12 +9798,12 @@ pub struct Request {
     )]
     pub add_banding: Option<AddBandingRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a chart to a sheet in the spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "addChart")]
     pub add_chart: Option<AddChartRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a new conditional format rule at the given index. All subsequent rules' indexes are incremented.
      */
     #[serde(
         default,
@@ -8455,7 +9812,7 @@ pub struct Request {
     )]
     pub add_conditional_format_rule: Option<AddConditionalFormatRuleRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a data source. After the data source is added successfully, an associated DATA_SOURCE sheet is created and an execution is triggered to refresh the sheet to read data from the data source. The request requires an additional `bigquery.readonly` OAuth scope.
      */
     #[serde(
         default,
@@ -8464,7 +9821,7 @@ pub struct Request {
     )]
     pub add_data_source: Option<AddDataSourceRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Creates a group over the specified range. If the requested range is a superset of the range of an existing group G, then the depth of G is incremented and this new group G' has the depth of that group. For example, a group [C:D, depth 1] + [B:E] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range is a subset of the range of an existing group G, then the depth of the new group G' becomes one greater than the depth of G. For example, a group [B:E, depth 1] + [C:D] results in groups [B:E, depth 1] and [C:D, depth 2]. If the requested range starts before and ends within, or starts within and ends after, the range of an existing group G, then the range of the existing group G becomes the union of the ranges, and the new group G' has depth one greater than the depth of G and range as the intersection of the ranges. For example, a group [B:D, depth 1] + [C:E] results in groups [B:E, depth 1] and [C:D, depth 2].
      */
     #[serde(
         default,
@@ -8473,7 +9830,7 @@ pub struct Request {
     )]
     pub add_dimension_group: Option<AddDimensionGroupRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a filter view.
      */
     #[serde(
         default,
@@ -8482,7 +9839,7 @@ pub struct Request {
     )]
     pub add_filter_view: Option<AddFilterViewRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a named range to the spreadsheet.
      */
     #[serde(
         default,
@@ -8491,7 +9848,7 @@ pub struct Request {
     )]
     pub add_named_range: Option<AddNamedRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a new protected range.
      */
     #[serde(
         default,
@@ -8500,17 +9857,17 @@ pub struct Request {
     )]
     pub add_protected_range: Option<AddProtectedRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a new sheet. When a sheet is added at a given index, all subsequent sheets' indexes are incremented. To add an object sheet, use AddChartRequest instead and specify EmbeddedObjectPosition.sheetId or EmbeddedObjectPosition.newSheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSheet")]
     pub add_sheet: Option<AddSheetRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds a slicer to a sheet in the spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSlicer")]
     pub add_slicer: Option<AddSlicerRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
      */
     #[serde(
         default,
@@ -8519,7 +9876,7 @@ pub struct Request {
     )]
     pub append_cells: Option<AppendCellsRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Appends rows or columns to the end of a sheet.
      */
     #[serde(
         default,
@@ -8528,12 +9885,12 @@ pub struct Request {
     )]
     pub append_dimension: Option<AppendDimensionRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Fills in more data based on existing data.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "autoFill")]
     pub auto_fill: Option<AutoFillRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
      */
     #[serde(
         default,
@@ -8542,7 +9899,7 @@ pub struct Request {
     )]
     pub auto_resize_dimensions: Option<AutoResizeDimensionsRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Clears the basic filter, if any exists on the sheet.
      */
     #[serde(
         default,
@@ -8551,12 +9908,12 @@ pub struct Request {
     )]
     pub clear_basic_filter: Option<ClearBasicFilterRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Copies data from the source to the destination.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "copyPaste")]
     pub copy_paste: Option<CopyPasteRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * A request to create developer metadata.
      */
     #[serde(
         default,
@@ -8565,12 +9922,12 @@ pub struct Request {
     )]
     pub create_developer_metadata: Option<CreateDeveloperMetadataRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Moves data from the source to the destination.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "cutPaste")]
     pub cut_paste: Option<CutPasteRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Removes the banded range with the given ID from the spreadsheet.
      */
     #[serde(
         default,
@@ -8579,7 +9936,7 @@ pub struct Request {
     )]
     pub delete_banding: Option<DeleteBandingRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
      */
     #[serde(
         default,
@@ -8588,7 +9945,7 @@ pub struct Request {
     )]
     pub delete_conditional_format_rule: Option<DeleteConditionalFormatRuleRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes a data source. The request also deletes the associated data source sheet, and unlinks all associated data source objects.
      */
     #[serde(
         default,
@@ -8597,7 +9954,7 @@ pub struct Request {
     )]
     pub delete_data_source: Option<DeleteDataSourceRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * A request to delete developer metadata.
      */
     #[serde(
         default,
@@ -8606,7 +9963,7 @@ pub struct Request {
     )]
     pub delete_developer_metadata: Option<DeleteDeveloperMetadataRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes the dimensions from the sheet.
      */
     #[serde(
         default,
@@ -8615,7 +9972,7 @@ pub struct Request {
     )]
     pub delete_dimension: Option<DeleteDimensionRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes a group over the specified range by decrementing the depth of the dimensions in the range. For example, assume the sheet has a depth-1 group over B:E and a depth-2 group over C:D. Deleting a group over D:E leaves the sheet with a depth-1 group over B:D and a depth-2 group over C:C.
      */
     #[serde(
         default,
@@ -8624,7 +9981,7 @@ pub struct Request {
     )]
     pub delete_dimension_group: Option<DeleteDimensionGroupRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
      */
     #[serde(
         default,
@@ -8633,7 +9990,7 @@ pub struct Request {
     )]
     pub delete_duplicates: Option<DeleteDuplicatesRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes the embedded object with the given ID.
      */
     #[serde(
         default,
@@ -8642,7 +9999,7 @@ pub struct Request {
     )]
     pub delete_embedded_object: Option<DeleteEmbeddedObjectRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes a particular filter view.
      */
     #[serde(
         default,
@@ -8651,7 +10008,7 @@ pub struct Request {
     )]
     pub delete_filter_view: Option<DeleteFilterViewRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Removes the named range with the given ID from the spreadsheet.
      */
     #[serde(
         default,
@@ -8660,7 +10017,7 @@ pub struct Request {
     )]
     pub delete_named_range: Option<DeleteNamedRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes the protected range with the given ID.
      */
     #[serde(
         default,
@@ -8669,7 +10026,7 @@ pub struct Request {
     )]
     pub delete_protected_range: Option<DeleteProtectedRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes a range of cells, shifting other cells into the deleted area.
      */
     #[serde(
         default,
@@ -8678,7 +10035,7 @@ pub struct Request {
     )]
     pub delete_range: Option<DeleteRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Deletes the requested sheet.
      */
     #[serde(
         default,
@@ -8687,7 +10044,7 @@ pub struct Request {
     )]
     pub delete_sheet: Option<DeleteSheetRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Duplicates a particular filter view.
      */
     #[serde(
         default,
@@ -8696,7 +10053,7 @@ pub struct Request {
     )]
     pub duplicate_filter_view: Option<DuplicateFilterViewRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Duplicates the contents of a sheet.
      */
     #[serde(
         default,
@@ -8705,7 +10062,7 @@ pub struct Request {
     )]
     pub duplicate_sheet: Option<DuplicateSheetRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
     #[serde(
         default,
@@ -8714,7 +10071,7 @@ pub struct Request {
     )]
     pub find_replace: Option<FindReplaceRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Inserts rows or columns in a sheet at a particular index.
      */
     #[serde(
         default,
@@ -8723,7 +10080,7 @@ pub struct Request {
     )]
     pub insert_dimension: Option<InsertDimensionRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Inserts cells into a range, shifting the existing cells over or down.
      */
     #[serde(
         default,
@@ -8732,7 +10089,7 @@ pub struct Request {
     )]
     pub insert_range: Option<InsertRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Merges all cells in the range.
      */
     #[serde(
         default,
@@ -8741,7 +10098,7 @@ pub struct Request {
     )]
     pub merge_cells: Option<MergeCellsRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Moves one or more rows or columns.
      */
     #[serde(
         default,
@@ -8750,12 +10107,12 @@ pub struct Request {
     )]
     pub move_dimension: Option<MoveDimensionRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Inserts data into the spreadsheet starting at the specified coordinate.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "pasteData")]
     pub paste_data: Option<PasteDataRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Randomizes the order of the rows in a range.
      */
     #[serde(
         default,
@@ -8764,7 +10121,7 @@ pub struct Request {
     )]
     pub randomize_range: Option<RandomizeRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
      */
     #[serde(
         default,
@@ -8773,7 +10130,7 @@ pub struct Request {
     )]
     pub refresh_data_source: Option<RefreshDataSourceRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
      */
     #[serde(
         default,
@@ -8782,7 +10139,7 @@ pub struct Request {
     )]
     pub repeat_cell: Option<RepeatCellRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Sets the basic filter associated with a sheet.
      */
     #[serde(
         default,
@@ -8791,7 +10148,7 @@ pub struct Request {
     )]
     pub set_basic_filter: Option<SetBasicFilterRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Sets a data validation rule to every cell in the range. To clear validation in a range, call this with no rule specified.
      */
     #[serde(
         default,
@@ -8800,12 +10157,12 @@ pub struct Request {
     )]
     pub set_data_validation: Option<SetDataValidationRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Sorts data in rows based on a sort order per column.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "sortRange")]
     pub sort_range: Option<SortRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Splits a column of text into multiple columns, based on a delimiter in each cell.
      */
     #[serde(
         default,
@@ -8814,7 +10171,7 @@ pub struct Request {
     )]
     pub text_to_columns: Option<TextColumnsRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Trims the whitespace (such as spaces, tabs, or new lines) in every cell in the specified range. This request removes all whitespace from the start and end of each cell's text, and reduces any subsequence of remaining whitespace characters to a single space. If the resulting trimmed text starts with a '+' or '=' character, the text remains as a string value and isn't interpreted as a formula.
      */
     #[serde(
         default,
@@ -8823,7 +10180,7 @@ pub struct Request {
     )]
     pub trim_whitespace: Option<TrimWhitespaceRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Unmerges cells in the given range.
      */
     #[serde(
         default,
@@ -8832,7 +10189,7 @@ pub struct Request {
     )]
     pub unmerge_cells: Option<UnmergeCellsRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates properties of the supplied banded range.
      */
     #[serde(
         default,
@@ -8841,7 +10198,7 @@ pub struct Request {
     )]
     pub update_banding: Option<UpdateBandingRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
      */
     #[serde(
         default,
@@ -8850,7 +10207,7 @@ pub struct Request {
     )]
     pub update_borders: Option<UpdateBordersRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates all cells in a range with new data.
      */
     #[serde(
         default,
@@ -8859,7 +10216,7 @@ pub struct Request {
     )]
     pub update_cells: Option<UpdateCellsRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
      */
     #[serde(
         default,
@@ -8868,7 +10225,7 @@ pub struct Request {
     )]
     pub update_chart_spec: Option<UpdateChartSpecRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
      */
     #[serde(
         default,
@@ -8877,7 +10234,7 @@ pub struct Request {
     )]
     pub update_conditional_format_rule: Option<UpdateConditionalFormatRuleRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
      */
     #[serde(
         default,
@@ -8886,7 +10243,7 @@ pub struct Request {
     )]
     pub update_data_source: Option<UpdateDataSourceRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
      */
     #[serde(
         default,
@@ -8895,7 +10252,7 @@ pub struct Request {
     )]
     pub update_developer_metadata: Option<UpdateDeveloperMetadataRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates the state of the specified group.
      */
     #[serde(
         default,
@@ -8904,7 +10261,7 @@ pub struct Request {
     )]
     pub update_dimension_group: Option<UpdateDimensionGroupRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates properties of dimensions within the specified range.
      */
     #[serde(
         default,
@@ -8913,7 +10270,7 @@ pub struct Request {
     )]
     pub update_dimension_properties: Option<UpdateDimensionPropertiesRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates an embedded object's border property.
      */
     #[serde(
         default,
@@ -8922,7 +10279,7 @@ pub struct Request {
     )]
     pub update_embedded_object_border: Option<UpdateEmbeddedObjectBorderRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Update an embedded object's position (such as a moving or resizing a chart or image).
      */
     #[serde(
         default,
@@ -8931,7 +10288,7 @@ pub struct Request {
     )]
     pub update_embedded_object_position: Option<UpdateEmbeddedObjectPositionRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates properties of the filter view.
      */
     #[serde(
         default,
@@ -8940,7 +10297,7 @@ pub struct Request {
     )]
     pub update_filter_view: Option<UpdateFilterViewRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates properties of the named range with the specified namedRangeId.
      */
     #[serde(
         default,
@@ -8949,7 +10306,7 @@ pub struct Request {
     )]
     pub update_named_range: Option<UpdateNamedRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates an existing protected range with the specified protectedRangeId.
      */
     #[serde(
         default,
@@ -8958,7 +10315,7 @@ pub struct Request {
     )]
     pub update_protected_range: Option<UpdateProtectedRangeRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates properties of the sheet with the specified sheetId.
      */
     #[serde(
         default,
@@ -8967,7 +10324,7 @@ pub struct Request {
     )]
     pub update_sheet_properties: Option<UpdateSheetPropertiesRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
      */
     #[serde(
         default,
@@ -8976,7 +10333,7 @@ pub struct Request {
     )]
     pub update_slicer_spec: Option<UpdateSlicerSpecRequest>,
     /**
-     * A single kind of update to apply to a spreadsheet.
+     * Updates properties of a spreadsheet.
      */
     #[serde(
         default,
@@ -8990,7 +10347,7 @@ pub struct Request {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Response {
     /**
-     * A single response from an update.
+     * The result of adding a banded range.
      */
     #[serde(
         default,
@@ -8999,12 +10356,12 @@ pub struct Response {
     )]
     pub add_banding: Option<AddBandingResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a chart to a spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "addChart")]
     pub add_chart: Option<AddChartResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a data source.
      */
     #[serde(
         default,
@@ -9013,7 +10370,7 @@ pub struct Response {
     )]
     pub add_data_source: Option<AddDataSourceResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a group.
      */
     #[serde(
         default,
@@ -9022,7 +10379,7 @@ pub struct Response {
     )]
     pub add_dimension_group: Option<AddDimensionGroupResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a filter view.
      */
     #[serde(
         default,
@@ -9031,7 +10388,7 @@ pub struct Response {
     )]
     pub add_filter_view: Option<AddFilterViewResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a named range.
      */
     #[serde(
         default,
@@ -9040,7 +10397,7 @@ pub struct Response {
     )]
     pub add_named_range: Option<AddNamedRangeResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a new protected range.
      */
     #[serde(
         default,
@@ -9049,17 +10406,17 @@ pub struct Response {
     )]
     pub add_protected_range: Option<AddProtectedRangeResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSheet")]
     pub add_sheet: Option<AddSheetResponse>,
     /**
-     * A single response from an update.
+     * The result of adding a slicer to a spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSlicer")]
     pub add_slicer: Option<AddSlicerResponse>,
     /**
-     * A single response from an update.
+     * The response from creating developer metadata.
      */
     #[serde(
         default,
@@ -9068,7 +10425,7 @@ pub struct Response {
     )]
     pub create_developer_metadata: Option<CreateDeveloperMetadataResponse>,
     /**
-     * A single response from an update.
+     * The result of deleting a conditional format rule.
      */
     #[serde(
         default,
@@ -9077,7 +10434,7 @@ pub struct Response {
     )]
     pub delete_conditional_format_rule: Option<DeleteConditionalFormatRuleResponse>,
     /**
-     * A single response from an update.
+     * The response from deleting developer metadata.
      */
     #[serde(
         default,
@@ -9086,7 +10443,7 @@ pub struct Response {
     )]
     pub delete_developer_metadata: Option<DeleteDeveloperMetadataResponse>,
     /**
-     * A single response from an update.
+     * The result of deleting a group.
      */
     #[serde(
         default,
@@ -9095,7 +10452,7 @@ pub struct Response {
     )]
     pub delete_dimension_group: Option<DeleteDimensionGroupResponse>,
     /**
-     * A single response from an update.
+     * The result of removing duplicates in a range.
      */
     #[serde(
         default,
@@ -9104,7 +10461,7 @@ pub struct Response {
     )]
     pub delete_duplicates: Option<DeleteDuplicatesResponse>,
     /**
-     * A single response from an update.
+     * The result of a filter view being duplicated.
      */
     #[serde(
         default,
@@ -9113,7 +10470,7 @@ pub struct Response {
     )]
     pub duplicate_filter_view: Option<DuplicateFilterViewResponse>,
     /**
-     * A single response from an update.
+     * The result of duplicating a sheet.
      */
     #[serde(
         default,
@@ -9122,7 +10479,7 @@ pub struct Response {
     )]
     pub duplicate_sheet: Option<DuplicateSheetResponse>,
     /**
-     * A single response from an update.
+     * The result of the find/replace.
      */
     #[serde(
         default,
@@ -9131,7 +10488,7 @@ pub struct Response {
     )]
     pub find_replace: Option<FindReplaceResponse>,
     /**
-     * A single response from an update.
+     * The response from refreshing one or multiple data source objects.
      */
     #[serde(
         default,
@@ -9140,7 +10497,7 @@ pub struct Response {
     )]
     pub refresh_data_source: Option<RefreshDataSourceResponse>,
     /**
-     * A single response from an update.
+     * The result of trimming whitespace in cells.
      */
     #[serde(
         default,
@@ -9149,7 +10506,7 @@ pub struct Response {
     )]
     pub trim_whitespace: Option<TrimWhitespaceResponse>,
     /**
-     * A single response from an update.
+     * The result of updating a conditional format rule.
      */
     #[serde(
         default,
@@ -9158,7 +10515,7 @@ pub struct Response {
     )]
     pub update_conditional_format_rule: Option<UpdateConditionalFormatRuleResponse>,
     /**
-     * A single response from an update.
+     * The response from updating data source.
      */
     #[serde(
         default,
@@ -9167,7 +10524,7 @@ pub struct Response {
     )]
     pub update_data_source: Option<UpdateDataSourceResponse>,
     /**
-     * A single response from an update.
+     * The response from updating developer metadata.
      */
     #[serde(
         default,
@@ -9176,7 +10533,7 @@ pub struct Response {
     )]
     pub update_developer_metadata: Option<UpdateDeveloperMetadataResponse>,
     /**
-     * A single response from an update.
+     * The result of updating an embedded object's position.
      */
     #[serde(
         default,
@@ -9190,7 +10547,7 @@ pub struct Response {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RowData {
     /**
-     * Data about each cell in a row.
+     * The values in the row, one per column.
      */
     #[serde(
         default,
@@ -9204,6 +10561,7 @@ pub struct RowData {
 * The number format source used in the scorecard chart. This field is optional.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NumberFormatSource {
     #[serde(rename = "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED")]
     ChartNumberFormatSourceUndefined,
@@ -9213,8 +10571,9 @@ pub enum NumberFormatSource {
     FromData,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NumberFormatSource {
@@ -9226,12 +10585,35 @@ impl std::fmt::Display for NumberFormatSource {
             NumberFormatSource::Custom => "CUSTOM",
             NumberFormatSource::FromData => "FROM_DATA",
             NumberFormatSource::Noop => "",
-            NumberFormatSource::FallthroughString => "*",
+            NumberFormatSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NumberFormatSource {
+    fn from(s: String) -> NumberFormatSource {
+        match s.as_str() {
+            "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED" => {
+                NumberFormatSource::ChartNumberFormatSourceUndefined
+            }
+            "CUSTOM" => NumberFormatSource::Custom,
+            "FROM_DATA" => NumberFormatSource::FromData,
+            "" => NumberFormatSource::Noop,
+            _ => NumberFormatSource::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NumberFormatSource> for String {
+    fn from(e: NumberFormatSource) -> String {
+        match e {
+            NumberFormatSource::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NumberFormatSource {
     fn default() -> NumberFormatSource {
         NumberFormatSource::Noop
@@ -9247,7 +10629,7 @@ impl NumberFormatSource {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ScorecardChartSpec {
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * The aggregation type for key and baseline chart data in scorecard chart. This field is not supported for data source charts. Use the ChartData.aggregateType field of the key_value_data or baseline_value_data instead for data source charts. This field is optional.
      */
     #[serde(
         default,
@@ -9256,7 +10638,7 @@ pub struct ScorecardChartSpec {
     )]
     pub aggregate_type: Option<AggregateType>,
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * The data included in a domain or series.
      */
     #[serde(
         default,
@@ -9265,7 +10647,7 @@ pub struct ScorecardChartSpec {
     )]
     pub baseline_value_data: Option<ChartData>,
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * Formatting options for baseline value.
      */
     #[serde(
         default,
@@ -9274,7 +10656,7 @@ pub struct ScorecardChartSpec {
     )]
     pub baseline_value_format: Option<BaselineValueFormat>,
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * Custom number formatting options for chart attributes.
      */
     #[serde(
         default,
@@ -9283,7 +10665,7 @@ pub struct ScorecardChartSpec {
     )]
     pub custom_format_options: Option<ChartCustomNumberFormatOptions>,
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * The data included in a domain or series.
      */
     #[serde(
         default,
@@ -9292,7 +10674,7 @@ pub struct ScorecardChartSpec {
     )]
     pub key_value_data: Option<ChartData>,
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * Formatting options for key value.
      */
     #[serde(
         default,
@@ -9301,7 +10683,7 @@ pub struct ScorecardChartSpec {
     )]
     pub key_value_format: Option<KeyValueFormat>,
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * The number format source used in the scorecard chart. This field is optional.
      */
     #[serde(
         default,
@@ -9310,7 +10692,7 @@ pub struct ScorecardChartSpec {
     )]
     pub number_format_source: Option<NumberFormatSource>,
     /**
-     * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
+     * Value to scale scorecard key and baseline value. For example, a factor of 10 can be used to divide all values in the chart by 10. This field is optional.
      */
     #[serde(
         default,
@@ -9325,7 +10707,7 @@ pub struct ScorecardChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchDeveloperMetadataRequest {
     /**
-     * A request to retrieve all developer metadata matching the set of specified criteria.
+     * The data filters describing the criteria used to determine which DeveloperMetadata entries to return. DeveloperMetadata matching any of the specified filters are included in the response.
      */
     #[serde(
         default,
@@ -9340,7 +10722,7 @@ pub struct SearchDeveloperMetadataRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SearchDeveloperMetadataResponse {
     /**
-     * A reply to a developer metadata search request.
+     * The metadata matching the criteria of the search request.
      */
     #[serde(
         default,
@@ -9355,7 +10737,7 @@ pub struct SearchDeveloperMetadataResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetBasicFilterRequest {
     /**
-     * Sets the basic filter associated with a sheet.
+     * The default filter associated with a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<BasicFilter>,
@@ -9365,12 +10747,12 @@ pub struct SetBasicFilterRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetDataValidationRequest {
     /**
-     * Sets a data validation rule to every cell in the range. To clear validation in a range, call this with no rule specified.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Sets a data validation rule to every cell in the range. To clear validation in a range, call this with no rule specified.
+     * A data validation rule.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rule: Option<DataValidationRule>,
@@ -9380,7 +10762,7 @@ pub struct SetDataValidationRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Sheet {
     /**
-     * A sheet in a spreadsheet.
+     * The banded (alternating colors) ranges on this sheet.
      */
     #[serde(
         default,
@@ -9390,7 +10772,7 @@ pub struct Sheet {
     )]
     pub banded_ranges: Vec<BandedRange>,
     /**
-     * A sheet in a spreadsheet.
+     * The default filter associated with a sheet.
      */
     #[serde(
         default,
@@ -9399,7 +10781,7 @@ pub struct Sheet {
     )]
     pub basic_filter: Option<BasicFilter>,
     /**
-     * A sheet in a spreadsheet.
+     * The specifications of every chart on this sheet.
      */
     #[serde(
         default,
@@ -9408,7 +10790,7 @@ pub struct Sheet {
     )]
     pub charts: Vec<EmbeddedChart>,
     /**
-     * A sheet in a spreadsheet.
+     * All column groups on this sheet, ordered by increasing range start index, then by group depth.
      */
     #[serde(
         default,
@@ -9418,7 +10800,7 @@ pub struct Sheet {
     )]
     pub column_groups: Vec<DimensionGroup>,
     /**
-     * A sheet in a spreadsheet.
+     * The conditional format rules in this sheet.
      */
     #[serde(
         default,
@@ -9428,7 +10810,7 @@ pub struct Sheet {
     )]
     pub conditional_formats: Vec<ConditionalFormatRule>,
     /**
-     * A sheet in a spreadsheet.
+     * Data in the grid, if this is a grid sheet. The number of GridData objects returned is dependent on the number of ranges requested on this sheet. For example, if this is representing `Sheet1`, and the spreadsheet was requested with ranges `Sheet1!A1:C10` and `Sheet1!D15:E20`, then the first GridData will have a startRow/startColumn of `0`, while the second one will have `startRow 14` (zero-based row 15), and `startColumn 3` (zero-based column D). For a DATA_SOURCE sheet, you can not request a specific range, the GridData contains all the values.
      */
     #[serde(
         default,
@@ -9437,7 +10819,7 @@ pub struct Sheet {
     )]
     pub data: Vec<GridData>,
     /**
-     * A sheet in a spreadsheet.
+     * The developer metadata associated with a sheet.
      */
     #[serde(
         default,
@@ -9447,7 +10829,7 @@ pub struct Sheet {
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
     /**
-     * A sheet in a spreadsheet.
+     * The filter views in this sheet.
      */
     #[serde(
         default,
@@ -9457,7 +10839,7 @@ pub struct Sheet {
     )]
     pub filter_views: Vec<FilterView>,
     /**
-     * A sheet in a spreadsheet.
+     * The ranges that are merged together.
      */
     #[serde(
         default,
@@ -9466,12 +10848,12 @@ pub struct Sheet {
     )]
     pub merges: Vec<GridRange>,
     /**
-     * A sheet in a spreadsheet.
+     * Properties of a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
     /**
-     * A sheet in a spreadsheet.
+     * The protected ranges in this sheet.
      */
     #[serde(
         default,
@@ -9481,7 +10863,7 @@ pub struct Sheet {
     )]
     pub protected_ranges: Vec<ProtectedRange>,
     /**
-     * A sheet in a spreadsheet.
+     * All row groups on this sheet, ordered by increasing range start index, then by group depth.
      */
     #[serde(
         default,
@@ -9491,7 +10873,7 @@ pub struct Sheet {
     )]
     pub row_groups: Vec<DimensionGroup>,
     /**
-     * A sheet in a spreadsheet.
+     * The slicers on this sheet.
      */
     #[serde(
         default,
@@ -9505,6 +10887,7 @@ pub struct Sheet {
 * The type of sheet. Defaults to GRID. This field cannot be changed once set.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SheetType {
     #[serde(rename = "DATA_SOURCE")]
     DataSource,
@@ -9516,8 +10899,9 @@ pub enum SheetType {
     SheetTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SheetType {
@@ -9528,12 +10912,34 @@ impl std::fmt::Display for SheetType {
             SheetType::Object => "OBJECT",
             SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
             SheetType::Noop => "",
-            SheetType::FallthroughString => "*",
+            SheetType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SheetType {
+    fn from(s: String) -> SheetType {
+        match s.as_str() {
+            "DATA_SOURCE" => SheetType::DataSource,
+            "GRID" => SheetType::Grid,
+            "OBJECT" => SheetType::Object,
+            "SHEET_TYPE_UNSPECIFIED" => SheetType::SheetTypeUnspecified,
+            "" => SheetType::Noop,
+            _ => SheetType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SheetType> for String {
+    fn from(e: SheetType) -> String {
+        match e {
+            SheetType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SheetType {
     fn default() -> SheetType {
         SheetType::Noop
@@ -9549,7 +10955,7 @@ impl SheetType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SheetProperties {
     /**
-     * Properties of a sheet.
+     * Additional properties of a DATA_SOURCE sheet.
      */
     #[serde(
         default,
@@ -9558,7 +10964,7 @@ pub struct SheetProperties {
     )]
     pub data_source_sheet_properties: Option<DataSourceSheetProperties>,
     /**
-     * Properties of a sheet.
+     * Properties of a grid.
      */
     #[serde(
         default,
@@ -9567,7 +10973,7 @@ pub struct SheetProperties {
     )]
     pub grid_properties: Option<GridProperties>,
     /**
-     * Properties of a sheet.
+     * True if the sheet is hidden in the UI, false if it's visible.
      */
     #[serde(
         default,
@@ -9575,7 +10981,7 @@ pub struct SheetProperties {
     )]
     pub hidden: bool,
     /**
-     * Properties of a sheet.
+     * The index of the sheet within the spreadsheet. When adding or updating sheet properties, if this field is excluded then the sheet is added or moved to the end of the sheet list. When updating sheet indices or inserting sheets, movement is considered in "before the move" indexes. For example, if there were 3 sheets (S1, S2, S3) in order to move S1 ahead of S2 the index would have to be set to 2. A sheet index update request is ignored if the requested index is identical to the sheets current index or if the requested new index is equal to the current sheet index + 1.
      */
     #[serde(
         default,
@@ -9584,7 +10990,7 @@ pub struct SheetProperties {
     )]
     pub index: i64,
     /**
-     * Properties of a sheet.
+     * True if the sheet is an RTL sheet instead of an LTR sheet.
      */
     #[serde(
         default,
@@ -9593,7 +10999,7 @@ pub struct SheetProperties {
     )]
     pub right_to_left: bool,
     /**
-     * Properties of a sheet.
+     * The ID of the sheet. Must be non-negative. This field cannot be changed once set.
      */
     #[serde(
         default,
@@ -9603,17 +11009,17 @@ pub struct SheetProperties {
     )]
     pub sheet_id: i64,
     /**
-     * Properties of a sheet.
+     * The type of sheet. Defaults to GRID. This field cannot be changed once set.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "sheetType")]
     pub sheet_type: Option<SheetType>,
     /**
-     * Properties of a sheet.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "tabColor")]
     pub tab_color: Option<Color>,
     /**
-     * Properties of a sheet.
+     * A color value.
      */
     #[serde(
         default,
@@ -9622,7 +11028,7 @@ pub struct SheetProperties {
     )]
     pub tab_color_style: Option<ColorStyle>,
     /**
-     * Properties of a sheet.
+     * The name of the sheet.
      */
     #[serde(
         default,
@@ -9636,12 +11042,12 @@ pub struct SheetProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Slicer {
     /**
-     * A slicer in a sheet.
+     * The position of an embedded object such as a chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub position: Option<EmbeddedObjectPosition>,
     /**
-     * A slicer in a sheet.
+     * The ID of the slicer.
      */
     #[serde(
         default,
@@ -9651,7 +11057,7 @@ pub struct Slicer {
     )]
     pub slicer_id: i64,
     /**
-     * A slicer in a sheet.
+     * The specifications of a slicer.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<SlicerSpec>,
@@ -9661,7 +11067,7 @@ pub struct Slicer {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SlicerSpec {
     /**
-     * The specifications of a slicer.
+     * True if the filter should apply to pivot tables. If not set, default to `True`.
      */
     #[serde(
         default,
@@ -9670,7 +11076,7 @@ pub struct SlicerSpec {
     )]
     pub apply_to_pivot_tables: bool,
     /**
-     * The specifications of a slicer.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -9679,7 +11085,7 @@ pub struct SlicerSpec {
     )]
     pub background_color: Option<Color>,
     /**
-     * The specifications of a slicer.
+     * A color value.
      */
     #[serde(
         default,
@@ -9688,7 +11094,7 @@ pub struct SlicerSpec {
     )]
     pub background_color_style: Option<ColorStyle>,
     /**
-     * The specifications of a slicer.
+     * The column index in the data table on which the filter is applied to.
      */
     #[serde(
         default,
@@ -9698,12 +11104,12 @@ pub struct SlicerSpec {
     )]
     pub column_index: i64,
     /**
-     * The specifications of a slicer.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "dataRange")]
     pub data_range: Option<GridRange>,
     /**
-     * The specifications of a slicer.
+     * Criteria for showing/hiding rows in a filter or filter view.
      */
     #[serde(
         default,
@@ -9712,7 +11118,7 @@ pub struct SlicerSpec {
     )]
     pub filter_criteria: Option<FilterCriteria>,
     /**
-     * The specifications of a slicer.
+     * The horizontal alignment of title in the slicer. If unspecified, defaults to `LEFT`
      */
     #[serde(
         default,
@@ -9721,7 +11127,7 @@ pub struct SlicerSpec {
     )]
     pub horizontal_alignment: Option<HorizontalAlignment>,
     /**
-     * The specifications of a slicer.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -9730,7 +11136,7 @@ pub struct SlicerSpec {
     )]
     pub text_format: Option<TextFormat>,
     /**
-     * The specifications of a slicer.
+     * The title of the slicer.
      */
     #[serde(
         default,
@@ -9744,12 +11150,12 @@ pub struct SlicerSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SortRangeRequest {
     /**
-     * Sorts data in rows based on a sort order per column.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Sorts data in rows based on a sort order per column.
+     * The sort order per column. Later specifications are used when values are equal in the earlier specifications.
      */
     #[serde(
         default,
@@ -9764,7 +11170,7 @@ pub struct SortRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SortSpec {
     /**
-     * A sort order associated with a specific column or row.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -9773,7 +11179,7 @@ pub struct SortSpec {
     )]
     pub background_color: Option<Color>,
     /**
-     * A sort order associated with a specific column or row.
+     * A color value.
      */
     #[serde(
         default,
@@ -9782,7 +11188,7 @@ pub struct SortSpec {
     )]
     pub background_color_style: Option<ColorStyle>,
     /**
-     * A sort order associated with a specific column or row.
+     * An unique identifier that references a data source column.
      */
     #[serde(
         default,
@@ -9791,7 +11197,7 @@ pub struct SortSpec {
     )]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
-     * A sort order associated with a specific column or row.
+     * The dimension the sort should be applied to.
      */
     #[serde(
         default,
@@ -9801,7 +11207,7 @@ pub struct SortSpec {
     )]
     pub dimension_index: i64,
     /**
-     * A sort order associated with a specific column or row.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -9810,7 +11216,7 @@ pub struct SortSpec {
     )]
     pub foreground_color: Option<Color>,
     /**
-     * A sort order associated with a specific column or row.
+     * A color value.
      */
     #[serde(
         default,
@@ -9819,7 +11225,7 @@ pub struct SortSpec {
     )]
     pub foreground_color_style: Option<ColorStyle>,
     /**
-     * A sort order associated with a specific column or row.
+     * The order data should be sorted.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "sortOrder")]
     pub sort_order: Option<SortOrder>,
@@ -9829,12 +11235,12 @@ pub struct SortSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SourceDestination {
     /**
-     * A combination of a source range and how to extend that source.
+     * The dimension that data should be filled into.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimension: Option<Dimension>,
     /**
-     * A combination of a source range and how to extend that source.
+     * The number of rows or columns that data should be filled into. Positive numbers expand beyond the last row or last column of the source. Negative numbers expand before the first row or first column of the source.
      */
     #[serde(
         default,
@@ -9844,7 +11250,7 @@ pub struct SourceDestination {
     )]
     pub fill_length: i64,
     /**
-     * A combination of a source range and how to extend that source.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
@@ -9854,7 +11260,7 @@ pub struct SourceDestination {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Spreadsheet {
     /**
-     * Resource that represents a spreadsheet.
+     * Output only. A list of data source refresh schedules.
      */
     #[serde(
         default,
@@ -9864,7 +11270,7 @@ pub struct Spreadsheet {
     )]
     pub data_source_schedules: Vec<DataSourceRefreshSchedule>,
     /**
-     * Resource that represents a spreadsheet.
+     * A list of external data sources connected with the spreadsheet.
      */
     #[serde(
         default,
@@ -9874,7 +11280,7 @@ pub struct Spreadsheet {
     )]
     pub data_sources: Vec<DataSource>,
     /**
-     * Resource that represents a spreadsheet.
+     * The developer metadata associated with a spreadsheet.
      */
     #[serde(
         default,
@@ -9884,7 +11290,7 @@ pub struct Spreadsheet {
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
     /**
-     * Resource that represents a spreadsheet.
+     * The named ranges defined in a spreadsheet.
      */
     #[serde(
         default,
@@ -9894,12 +11300,12 @@ pub struct Spreadsheet {
     )]
     pub named_ranges: Vec<NamedRange>,
     /**
-     * Resource that represents a spreadsheet.
+     * Properties of a spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SpreadsheetProperties>,
     /**
-     * Resource that represents a spreadsheet.
+     * The sheets that are part of a spreadsheet.
      */
     #[serde(
         default,
@@ -9908,7 +11314,7 @@ pub struct Spreadsheet {
     )]
     pub sheets: Vec<Sheet>,
     /**
-     * Resource that represents a spreadsheet.
+     * The ID of the spreadsheet. This field is read-only.
      */
     #[serde(
         default,
@@ -9918,7 +11324,7 @@ pub struct Spreadsheet {
     )]
     pub spreadsheet_id: String,
     /**
-     * Resource that represents a spreadsheet.
+     * The url of the spreadsheet. This field is read-only.
      */
     #[serde(
         default,
@@ -9933,6 +11339,7 @@ pub struct Spreadsheet {
 * The amount of time to wait before volatile functions are recalculated.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AutoRecalc {
     #[serde(rename = "HOUR")]
     Hour,
@@ -9944,8 +11351,9 @@ pub enum AutoRecalc {
     RecalculationIntervalUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecalc {
@@ -9956,12 +11364,34 @@ impl std::fmt::Display for AutoRecalc {
             AutoRecalc::OnChange => "ON_CHANGE",
             AutoRecalc::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
             AutoRecalc::Noop => "",
-            AutoRecalc::FallthroughString => "*",
+            AutoRecalc::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AutoRecalc {
+    fn from(s: String) -> AutoRecalc {
+        match s.as_str() {
+            "HOUR" => AutoRecalc::Hour,
+            "MINUTE" => AutoRecalc::Minute,
+            "ON_CHANGE" => AutoRecalc::OnChange,
+            "RECALCULATION_INTERVAL_UNSPECIFIED" => AutoRecalc::RecalculationIntervalUnspecified,
+            "" => AutoRecalc::Noop,
+            _ => AutoRecalc::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AutoRecalc> for String {
+    fn from(e: AutoRecalc) -> String {
+        match e {
+            AutoRecalc::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AutoRecalc {
     fn default() -> AutoRecalc {
         AutoRecalc::Noop
@@ -9977,7 +11407,7 @@ impl AutoRecalc {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SpreadsheetProperties {
     /**
-     * Properties of a spreadsheet.
+     * The amount of time to wait before volatile functions are recalculated.
      */
     #[serde(
         default,
@@ -9986,7 +11416,7 @@ pub struct SpreadsheetProperties {
     )]
     pub auto_recalc: Option<AutoRecalc>,
     /**
-     * Properties of a spreadsheet.
+     * The format of a cell.
      */
     #[serde(
         default,
@@ -9995,7 +11425,7 @@ pub struct SpreadsheetProperties {
     )]
     pub default_format: Option<CellFormat>,
     /**
-     * Properties of a spreadsheet.
+     * Settings to control how circular dependencies are resolved with iterative calculation.
      */
     #[serde(
         default,
@@ -10004,7 +11434,7 @@ pub struct SpreadsheetProperties {
     )]
     pub iterative_calculation_settings: Option<IterativeCalculationSettings>,
     /**
-     * Properties of a spreadsheet.
+     * The locale of the spreadsheet in one of the following formats: \* an ISO 639-1 language code such as `en` \* an ISO 639-2 language code such as `fil`, if no 639-1 code exists \* a combination of the ISO language code and country code, such as `en_US` Note: when updating this field, not all locales/languages are supported.
      */
     #[serde(
         default,
@@ -10013,7 +11443,7 @@ pub struct SpreadsheetProperties {
     )]
     pub locale: String,
     /**
-     * Properties of a spreadsheet.
+     * Represents spreadsheet theme
      */
     #[serde(
         default,
@@ -10022,7 +11452,7 @@ pub struct SpreadsheetProperties {
     )]
     pub spreadsheet_theme: Option<SpreadsheetTheme>,
     /**
-     * Properties of a spreadsheet.
+     * The time zone of the spreadsheet, in CLDR format such as `America/New_York`. If the time zone isn't recognized, this may be a custom time zone such as `GMT-07:00`.
      */
     #[serde(
         default,
@@ -10032,7 +11462,7 @@ pub struct SpreadsheetProperties {
     )]
     pub time_zone: String,
     /**
-     * Properties of a spreadsheet.
+     * The title of the spreadsheet.
      */
     #[serde(
         default,
@@ -10046,7 +11476,7 @@ pub struct SpreadsheetProperties {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SpreadsheetTheme {
     /**
-     * Represents spreadsheet theme
+     * Name of the primary font family.
      */
     #[serde(
         default,
@@ -10056,7 +11486,7 @@ pub struct SpreadsheetTheme {
     )]
     pub primary_font_family: String,
     /**
-     * Represents spreadsheet theme
+     * The spreadsheet theme color pairs. To update you must provide all theme color pairs.
      */
     #[serde(
         default,
@@ -10071,7 +11501,7 @@ pub struct SpreadsheetTheme {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TextFormat {
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * True if the text is bold.
      */
     #[serde(
         default,
@@ -10079,7 +11509,7 @@ pub struct TextFormat {
     )]
     pub bold: bool,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * The font family.
      */
     #[serde(
         default,
@@ -10089,7 +11519,7 @@ pub struct TextFormat {
     )]
     pub font_family: String,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * The size of the font.
      */
     #[serde(
         default,
@@ -10099,7 +11529,7 @@ pub struct TextFormat {
     )]
     pub font_size: i64,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -10108,7 +11538,7 @@ pub struct TextFormat {
     )]
     pub foreground_color: Option<Color>,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * A color value.
      */
     #[serde(
         default,
@@ -10117,7 +11547,7 @@ pub struct TextFormat {
     )]
     pub foreground_color_style: Option<ColorStyle>,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * True if the text is italicized.
      */
     #[serde(
         default,
@@ -10125,12 +11555,12 @@ pub struct TextFormat {
     )]
     pub italic: bool,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * An external or local reference.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link: Option<Link>,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * True if the text has a strikethrough.
      */
     #[serde(
         default,
@@ -10138,7 +11568,7 @@ pub struct TextFormat {
     )]
     pub strikethrough: bool,
     /**
-     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
+     * True if the text is underlined.
      */
     #[serde(
         default,
@@ -10151,12 +11581,12 @@ pub struct TextFormat {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TextFormatRun {
     /**
-     * A run of a text format. The format of this run continues until the start index of the next run. When updating, all fields must be set.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub format: Option<TextFormat>,
     /**
-     * A run of a text format. The format of this run continues until the start index of the next run. When updating, all fields must be set.
+     * The character index where this run starts.
      */
     #[serde(
         default,
@@ -10171,7 +11601,7 @@ pub struct TextFormatRun {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TextPosition {
     /**
-     * Position settings for text.
+     * Horizontal alignment setting for the piece of text.
      */
     #[serde(
         default,
@@ -10185,7 +11615,7 @@ pub struct TextPosition {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TextRotation {
     /**
-     * The rotation applied to text in a cell.
+     * The angle between the standard orientation and the desired orientation. Measured in degrees. Valid values are between -90 and 90. Positive angles are angled upwards, negative are angled downwards. Note: For LTR text direction positive angles are in the counterclockwise direction, whereas for RTL they are in the clockwise direction
      */
     #[serde(
         default,
@@ -10194,7 +11624,7 @@ pub struct TextRotation {
     )]
     pub angle: i64,
     /**
-     * The rotation applied to text in a cell.
+     * If true, text reads top to bottom, but the orientation of individual characters is unchanged. For example: | V | | e | | r | | t | | i | | c | | a | | l |
      */
     #[serde(
         default,
@@ -10207,6 +11637,7 @@ pub struct TextRotation {
 * The delimiter type to use.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelimiterType {
     #[serde(rename = "AUTODETECT")]
     Autodetect,
@@ -10224,8 +11655,9 @@ pub enum DelimiterType {
     Space,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelimiterType {
@@ -10239,12 +11671,37 @@ impl std::fmt::Display for DelimiterType {
             DelimiterType::Semicolon => "SEMICOLON",
             DelimiterType::Space => "SPACE",
             DelimiterType::Noop => "",
-            DelimiterType::FallthroughString => "*",
+            DelimiterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DelimiterType {
+    fn from(s: String) -> DelimiterType {
+        match s.as_str() {
+            "AUTODETECT" => DelimiterType::Autodetect,
+            "COMMA" => DelimiterType::Comma,
+            "CUSTOM" => DelimiterType::Custom,
+            "DELIMITER_TYPE_UNSPECIFIED" => DelimiterType::DelimiterTypeUnspecified,
+            "PERIOD" => DelimiterType::Period,
+            "SEMICOLON" => DelimiterType::Semicolon,
+            "SPACE" => DelimiterType::Space,
+            "" => DelimiterType::Noop,
+            _ => DelimiterType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DelimiterType> for String {
+    fn from(e: DelimiterType) -> String {
+        match e {
+            DelimiterType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DelimiterType {
     fn default() -> DelimiterType {
         DelimiterType::Noop
@@ -10260,7 +11717,7 @@ impl DelimiterType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TextColumnsRequest {
     /**
-     * Splits a column of text into multiple columns, based on a delimiter in each cell.
+     * The delimiter to use. Used only if delimiterType is CUSTOM.
      */
     #[serde(
         default,
@@ -10269,7 +11726,7 @@ pub struct TextColumnsRequest {
     )]
     pub delimiter: String,
     /**
-     * Splits a column of text into multiple columns, based on a delimiter in each cell.
+     * The delimiter type to use.
      */
     #[serde(
         default,
@@ -10278,7 +11735,7 @@ pub struct TextColumnsRequest {
     )]
     pub delimiter_type: Option<DelimiterType>,
     /**
-     * Splits a column of text into multiple columns, based on a delimiter in each cell.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source: Option<GridRange>,
@@ -10288,12 +11745,12 @@ pub struct TextColumnsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ThemeColorPair {
     /**
-     * A pair mapping a spreadsheet theme color type to the concrete color it represents.
+     * A color value.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<ColorStyle>,
     /**
-     * A pair mapping a spreadsheet theme color type to the concrete color it represents.
+     * The type of the spreadsheet theme color.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorType")]
     pub color_type: Option<ColorType>,
@@ -10303,7 +11760,7 @@ pub struct ThemeColorPair {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimeOfDay {
     /**
-     * Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
+     * Hours of day in 24 hour format. Should be from 0 to 23. An API may choose to allow the value "24:00:00" for scenarios like business closing time.
      */
     #[serde(
         default,
@@ -10312,7 +11769,7 @@ pub struct TimeOfDay {
     )]
     pub hours: i64,
     /**
-     * Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
+     * Minutes of hour of day. Must be from 0 to 59.
      */
     #[serde(
         default,
@@ -10321,7 +11778,7 @@ pub struct TimeOfDay {
     )]
     pub minutes: i64,
     /**
-     * Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
+     * Fractions of seconds in nanoseconds. Must be from 0 to 999,999,999.
      */
     #[serde(
         default,
@@ -10330,7 +11787,7 @@ pub struct TimeOfDay {
     )]
     pub nanos: i64,
     /**
-     * Represents a time of day. The date and time zone are either not significant or are specified elsewhere. An API may choose to allow leap seconds. Related types are google.type.Date and `google.protobuf.Timestamp`.
+     * Seconds of minutes of the time. Must normally be from 0 to 59. An API may allow the value 60 if it allows leap-seconds.
      */
     #[serde(
         default,
@@ -10344,7 +11801,7 @@ pub struct TimeOfDay {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TreemapChartColorScale {
     /**
-     * A color scale for a treemap chart.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -10353,7 +11810,7 @@ pub struct TreemapChartColorScale {
     )]
     pub max_value_color: Option<Color>,
     /**
-     * A color scale for a treemap chart.
+     * A color value.
      */
     #[serde(
         default,
@@ -10362,7 +11819,7 @@ pub struct TreemapChartColorScale {
     )]
     pub max_value_color_style: Option<ColorStyle>,
     /**
-     * A color scale for a treemap chart.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -10371,7 +11828,7 @@ pub struct TreemapChartColorScale {
     )]
     pub mid_value_color: Option<Color>,
     /**
-     * A color scale for a treemap chart.
+     * A color value.
      */
     #[serde(
         default,
@@ -10380,7 +11837,7 @@ pub struct TreemapChartColorScale {
     )]
     pub mid_value_color_style: Option<ColorStyle>,
     /**
-     * A color scale for a treemap chart.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -10389,7 +11846,7 @@ pub struct TreemapChartColorScale {
     )]
     pub min_value_color: Option<Color>,
     /**
-     * A color scale for a treemap chart.
+     * A color value.
      */
     #[serde(
         default,
@@ -10398,7 +11855,7 @@ pub struct TreemapChartColorScale {
     )]
     pub min_value_color_style: Option<ColorStyle>,
     /**
-     * A color scale for a treemap chart.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -10407,7 +11864,7 @@ pub struct TreemapChartColorScale {
     )]
     pub no_data_color: Option<Color>,
     /**
-     * A color scale for a treemap chart.
+     * A color value.
      */
     #[serde(
         default,
@@ -10421,12 +11878,12 @@ pub struct TreemapChartColorScale {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TreemapChartSpec {
     /**
-     * A Treemap chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorData")]
     pub color_data: Option<ChartData>,
     /**
-     * A Treemap chart.
+     * A color scale for a treemap chart.
      */
     #[serde(
         default,
@@ -10435,7 +11892,7 @@ pub struct TreemapChartSpec {
     )]
     pub color_scale: Option<TreemapChartColorScale>,
     /**
-     * A Treemap chart.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(
         default,
@@ -10444,7 +11901,7 @@ pub struct TreemapChartSpec {
     )]
     pub header_color: Option<Color>,
     /**
-     * A Treemap chart.
+     * A color value.
      */
     #[serde(
         default,
@@ -10453,7 +11910,7 @@ pub struct TreemapChartSpec {
     )]
     pub header_color_style: Option<ColorStyle>,
     /**
-     * A Treemap chart.
+     * True to hide tooltips.
      */
     #[serde(
         default,
@@ -10462,7 +11919,7 @@ pub struct TreemapChartSpec {
     )]
     pub hide_tooltips: bool,
     /**
-     * A Treemap chart.
+     * The number of additional data levels beyond the labeled levels to be shown on the treemap chart. These levels are not interactive and are shown without their labels. Defaults to 0 if not specified.
      */
     #[serde(
         default,
@@ -10472,12 +11929,12 @@ pub struct TreemapChartSpec {
     )]
     pub hinted_levels: i64,
     /**
-     * A Treemap chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub labels: Option<ChartData>,
     /**
-     * A Treemap chart.
+     * The number of data levels to show on the treemap chart. These levels are interactive and are shown with their labels. Defaults to 2 if not specified.
      */
     #[serde(
         default,
@@ -10486,7 +11943,7 @@ pub struct TreemapChartSpec {
     )]
     pub levels: i64,
     /**
-     * A Treemap chart.
+     * The maximum possible data value. Cells with values greater than this will have the same color as cells with this value. If not specified, defaults to the actual maximum value from color_data, or the maximum value from size_data if color_data is not specified.
      */
     #[serde(
         default,
@@ -10496,7 +11953,7 @@ pub struct TreemapChartSpec {
     )]
     pub max_value: f64,
     /**
-     * A Treemap chart.
+     * The minimum possible data value. Cells with values less than this will have the same color as cells with this value. If not specified, defaults to the actual minimum value from color_data, or the minimum value from size_data if color_data is not specified.
      */
     #[serde(
         default,
@@ -10506,7 +11963,7 @@ pub struct TreemapChartSpec {
     )]
     pub min_value: f64,
     /**
-     * A Treemap chart.
+     * The data included in a domain or series.
      */
     #[serde(
         default,
@@ -10515,12 +11972,12 @@ pub struct TreemapChartSpec {
     )]
     pub parent_labels: Option<ChartData>,
     /**
-     * A Treemap chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "sizeData")]
     pub size_data: Option<ChartData>,
     /**
-     * A Treemap chart.
+     * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
     #[serde(
         default,
@@ -10534,7 +11991,7 @@ pub struct TreemapChartSpec {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TrimWhitespaceRequest {
     /**
-     * Trims the whitespace (such as spaces, tabs, or new lines) in every cell in the specified range. This request removes all whitespace from the start and end of each cell's text, and reduces any subsequence of remaining whitespace characters to a single space. If the resulting trimmed text starts with a '+' or '=' character, the text remains as a string value and isn't interpreted as a formula.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -10544,7 +12001,7 @@ pub struct TrimWhitespaceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TrimWhitespaceResponse {
     /**
-     * The result of trimming whitespace in cells.
+     * The number of cells that were trimmed of whitespace.
      */
     #[serde(
         default,
@@ -10559,7 +12016,7 @@ pub struct TrimWhitespaceResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UnmergeCellsRequest {
     /**
-     * Unmerges cells in the given range.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
@@ -10569,7 +12026,7 @@ pub struct UnmergeCellsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateBandingRequest {
     /**
-     * Updates properties of the supplied banded range.
+     * A banded (alternating colors) range in a sheet.
      */
     #[serde(
         default,
@@ -10578,7 +12035,7 @@ pub struct UpdateBandingRequest {
     )]
     pub banded_range: Option<BandedRange>,
     /**
-     * Updates properties of the supplied banded range.
+     * The fields that should be updated. At least one field must be specified. The root `bandedRange` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10592,12 +12049,12 @@ pub struct UpdateBandingRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateBordersRequest {
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bottom: Option<Border>,
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     * A border along a cell.
      */
     #[serde(
         default,
@@ -10606,7 +12063,7 @@ pub struct UpdateBordersRequest {
     )]
     pub inner_horizontal: Option<Border>,
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     * A border along a cell.
      */
     #[serde(
         default,
@@ -10615,22 +12072,22 @@ pub struct UpdateBordersRequest {
     )]
     pub inner_vertical: Option<Border>,
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub left: Option<Border>,
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub right: Option<Border>,
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     * A border along a cell.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub top: Option<Border>,
@@ -10640,7 +12097,7 @@ pub struct UpdateBordersRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateCellsRequest {
     /**
-     * Updates all cells in a range with new data.
+     * The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10649,12 +12106,12 @@ pub struct UpdateCellsRequest {
     )]
     pub fields: String,
     /**
-     * Updates all cells in a range with new data.
+     * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<GridRange>,
     /**
-     * Updates all cells in a range with new data.
+     * The data to write.
      */
     #[serde(
         default,
@@ -10663,7 +12120,7 @@ pub struct UpdateCellsRequest {
     )]
     pub rows: Vec<RowData>,
     /**
-     * Updates all cells in a range with new data.
+     * A coordinate in a sheet. All indexes are zero-based.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start: Option<GridCoordinate>,
@@ -10673,7 +12130,7 @@ pub struct UpdateCellsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateChartSpecRequest {
     /**
-     * Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
+     * The ID of the chart to update.
      */
     #[serde(
         default,
@@ -10683,7 +12140,7 @@ pub struct UpdateChartSpecRequest {
     )]
     pub chart_id: i64,
     /**
-     * Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
+     * The specifications of a chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<ChartSpec>,
@@ -10693,7 +12150,7 @@ pub struct UpdateChartSpecRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateConditionalFormatRuleRequest {
     /**
-     * Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
+     * The zero-based index of the rule that should be replaced or moved.
      */
     #[serde(
         default,
@@ -10702,7 +12159,7 @@ pub struct UpdateConditionalFormatRuleRequest {
     )]
     pub index: i64,
     /**
-     * Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
+     * The zero-based new index the rule should end up at.
      */
     #[serde(
         default,
@@ -10712,12 +12169,12 @@ pub struct UpdateConditionalFormatRuleRequest {
     )]
     pub new_index: i64,
     /**
-     * Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
+     * A rule describing a conditional format.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rule: Option<ConditionalFormatRule>,
     /**
-     * Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
+     * The sheet of the rule to move. Required if new_index is set, unused otherwise.
      */
     #[serde(
         default,
@@ -10732,7 +12189,7 @@ pub struct UpdateConditionalFormatRuleRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateConditionalFormatRuleResponse {
     /**
-     * The result of updating a conditional format rule.
+     * The index of the new rule.
      */
     #[serde(
         default,
@@ -10742,12 +12199,12 @@ pub struct UpdateConditionalFormatRuleResponse {
     )]
     pub new_index: i64,
     /**
-     * The result of updating a conditional format rule.
+     * A rule describing a conditional format.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "newRule")]
     pub new_rule: Option<ConditionalFormatRule>,
     /**
-     * The result of updating a conditional format rule.
+     * The old index of the rule. Not set if a rule was replaced (because it is the same as new_index).
      */
     #[serde(
         default,
@@ -10757,7 +12214,7 @@ pub struct UpdateConditionalFormatRuleResponse {
     )]
     pub old_index: i64,
     /**
-     * The result of updating a conditional format rule.
+     * A rule describing a conditional format.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "oldRule")]
     pub old_rule: Option<ConditionalFormatRule>,
@@ -10767,7 +12224,7 @@ pub struct UpdateConditionalFormatRuleResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDataSourceRequest {
     /**
-     * Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
+     * Information about an external data source in the spreadsheet.
      */
     #[serde(
         default,
@@ -10776,7 +12233,7 @@ pub struct UpdateDataSourceRequest {
     )]
     pub data_source: Option<DataSource>,
     /**
-     * Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
+     * The fields that should be updated. At least one field must be specified. The root `dataSource` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10790,7 +12247,7 @@ pub struct UpdateDataSourceRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDataSourceResponse {
     /**
-     * The response from updating data source.
+     * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
     #[serde(
         default,
@@ -10799,7 +12256,7 @@ pub struct UpdateDataSourceResponse {
     )]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
-     * The response from updating data source.
+     * Information about an external data source in the spreadsheet.
      */
     #[serde(
         default,
@@ -10813,7 +12270,7 @@ pub struct UpdateDataSourceResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDeveloperMetadataRequest {
     /**
-     * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
+     * The filters matching the developer metadata entries to update.
      */
     #[serde(
         default,
@@ -10823,7 +12280,7 @@ pub struct UpdateDeveloperMetadataRequest {
     )]
     pub data_filters: Vec<DataFilter>,
     /**
-     * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
+     * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
      */
     #[serde(
         default,
@@ -10832,7 +12289,7 @@ pub struct UpdateDeveloperMetadataRequest {
     )]
     pub developer_metadata: Option<DeveloperMetadata>,
     /**
-     * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
+     * The fields that should be updated. At least one field must be specified. The root `developerMetadata` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10846,7 +12303,7 @@ pub struct UpdateDeveloperMetadataRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDeveloperMetadataResponse {
     /**
-     * The response from updating developer metadata.
+     * The updated developer metadata.
      */
     #[serde(
         default,
@@ -10861,7 +12318,7 @@ pub struct UpdateDeveloperMetadataResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDimensionGroupRequest {
     /**
-     * Updates the state of the specified group.
+     * A group over an interval of rows or columns on a sheet, which can contain or be contained within other groups. A group can be collapsed or expanded as a unit on the sheet.
      */
     #[serde(
         default,
@@ -10870,7 +12327,7 @@ pub struct UpdateDimensionGroupRequest {
     )]
     pub dimension_group: Option<DimensionGroup>,
     /**
-     * Updates the state of the specified group.
+     * The fields that should be updated. At least one field must be specified. The root `dimensionGroup` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10884,7 +12341,7 @@ pub struct UpdateDimensionGroupRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateDimensionPropertiesRequest {
     /**
-     * Updates properties of dimensions within the specified range.
+     * A range along a single dimension on a DATA_SOURCE sheet.
      */
     #[serde(
         default,
@@ -10893,7 +12350,7 @@ pub struct UpdateDimensionPropertiesRequest {
     )]
     pub data_source_sheet_range: Option<DataSourceSheetDimensionRange>,
     /**
-     * Updates properties of dimensions within the specified range.
+     * The fields that should be updated. At least one field must be specified. The root `properties` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10902,12 +12359,12 @@ pub struct UpdateDimensionPropertiesRequest {
     )]
     pub fields: String,
     /**
-     * Updates properties of dimensions within the specified range.
+     * Properties about a dimension.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<DimensionProperties>,
     /**
-     * Updates properties of dimensions within the specified range.
+     * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub range: Option<DimensionRange>,
@@ -10917,12 +12374,12 @@ pub struct UpdateDimensionPropertiesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateEmbeddedObjectBorderRequest {
     /**
-     * Updates an embedded object's border property.
+     * A border along an embedded object.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub border: Option<EmbeddedObjectBorder>,
     /**
-     * Updates an embedded object's border property.
+     * The fields that should be updated. At least one field must be specified. The root `border` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10931,7 +12388,7 @@ pub struct UpdateEmbeddedObjectBorderRequest {
     )]
     pub fields: String,
     /**
-     * Updates an embedded object's border property.
+     * The ID of the embedded object to update.
      */
     #[serde(
         default,
@@ -10946,7 +12403,7 @@ pub struct UpdateEmbeddedObjectBorderRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateEmbeddedObjectPositionRequest {
     /**
-     * Update an embedded object's position (such as a moving or resizing a chart or image).
+     * The fields of OverlayPosition that should be updated when setting a new position. Used only if newPosition.overlayPosition is set, in which case at least one field must be specified. The root `newPosition.overlayPosition` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10955,7 +12412,7 @@ pub struct UpdateEmbeddedObjectPositionRequest {
     )]
     pub fields: String,
     /**
-     * Update an embedded object's position (such as a moving or resizing a chart or image).
+     * The position of an embedded object such as a chart.
      */
     #[serde(
         default,
@@ -10964,7 +12421,7 @@ pub struct UpdateEmbeddedObjectPositionRequest {
     )]
     pub new_position: Option<EmbeddedObjectPosition>,
     /**
-     * Update an embedded object's position (such as a moving or resizing a chart or image).
+     * The ID of the object to moved.
      */
     #[serde(
         default,
@@ -10979,7 +12436,7 @@ pub struct UpdateEmbeddedObjectPositionRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateEmbeddedObjectPositionResponse {
     /**
-     * The result of updating an embedded object's position.
+     * The position of an embedded object such as a chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub position: Option<EmbeddedObjectPosition>,
@@ -10989,7 +12446,7 @@ pub struct UpdateEmbeddedObjectPositionResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateFilterViewRequest {
     /**
-     * Updates properties of the filter view.
+     * The fields that should be updated. At least one field must be specified. The root `filter` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -10998,7 +12455,7 @@ pub struct UpdateFilterViewRequest {
     )]
     pub fields: String,
     /**
-     * Updates properties of the filter view.
+     * A filter view.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterView>,
@@ -11008,7 +12465,7 @@ pub struct UpdateFilterViewRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateNamedRangeRequest {
     /**
-     * Updates properties of the named range with the specified namedRangeId.
+     * The fields that should be updated. At least one field must be specified. The root `namedRange` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -11017,7 +12474,7 @@ pub struct UpdateNamedRangeRequest {
     )]
     pub fields: String,
     /**
-     * Updates properties of the named range with the specified namedRangeId.
+     * A named range.
      */
     #[serde(
         default,
@@ -11031,7 +12488,7 @@ pub struct UpdateNamedRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateProtectedRangeRequest {
     /**
-     * Updates an existing protected range with the specified protectedRangeId.
+     * The fields that should be updated. At least one field must be specified. The root `protectedRange` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -11040,7 +12497,7 @@ pub struct UpdateProtectedRangeRequest {
     )]
     pub fields: String,
     /**
-     * Updates an existing protected range with the specified protectedRangeId.
+     * A protected range.
      */
     #[serde(
         default,
@@ -11054,7 +12511,7 @@ pub struct UpdateProtectedRangeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSheetPropertiesRequest {
     /**
-     * Updates properties of the sheet with the specified sheetId.
+     * The fields that should be updated. At least one field must be specified. The root `properties` is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -11063,7 +12520,7 @@ pub struct UpdateSheetPropertiesRequest {
     )]
     pub fields: String,
     /**
-     * Updates properties of the sheet with the specified sheetId.
+     * Properties of a sheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SheetProperties>,
@@ -11073,7 +12530,7 @@ pub struct UpdateSheetPropertiesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSlicerSpecRequest {
     /**
-     * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
+     * The fields that should be updated. At least one field must be specified. The root `SlicerSpec` is implied and should not be specified. A single "\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -11082,7 +12539,7 @@ pub struct UpdateSlicerSpecRequest {
     )]
     pub fields: String,
     /**
-     * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
+     * The id of the slicer to update.
      */
     #[serde(
         default,
@@ -11092,7 +12549,7 @@ pub struct UpdateSlicerSpecRequest {
     )]
     pub slicer_id: i64,
     /**
-     * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
+     * The specifications of a slicer.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spec: Option<SlicerSpec>,
@@ -11102,7 +12559,7 @@ pub struct UpdateSlicerSpecRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateSpreadsheetPropertiesRequest {
     /**
-     * Updates properties of a spreadsheet.
+     * The fields that should be updated. At least one field must be specified. The root 'properties' is implied and should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      */
     #[serde(
         default,
@@ -11111,7 +12568,7 @@ pub struct UpdateSpreadsheetPropertiesRequest {
     )]
     pub fields: String,
     /**
-     * Updates properties of a spreadsheet.
+     * Properties of a spreadsheet.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub properties: Option<SpreadsheetProperties>,
@@ -11121,7 +12578,7 @@ pub struct UpdateSpreadsheetPropertiesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateValuesByDataFilterResponse {
     /**
-     * The response when updating a range of values by a data filter in a spreadsheet.
+     * Filter that describes what data should be selected or returned from a request.
      */
     #[serde(
         default,
@@ -11130,7 +12587,7 @@ pub struct UpdateValuesByDataFilterResponse {
     )]
     pub data_filter: Option<DataFilter>,
     /**
-     * The response when updating a range of values by a data filter in a spreadsheet.
+     * The number of cells updated.
      */
     #[serde(
         default,
@@ -11140,7 +12597,7 @@ pub struct UpdateValuesByDataFilterResponse {
     )]
     pub updated_cells: i64,
     /**
-     * The response when updating a range of values by a data filter in a spreadsheet.
+     * The number of columns where at least one cell in the column was updated.
      */
     #[serde(
         default,
@@ -11150,7 +12607,7 @@ pub struct UpdateValuesByDataFilterResponse {
     )]
     pub updated_columns: i64,
     /**
-     * The response when updating a range of values by a data filter in a spreadsheet.
+     * Data within a range of the spreadsheet.
      */
     #[serde(
         default,
@@ -11159,7 +12616,7 @@ pub struct UpdateValuesByDataFilterResponse {
     )]
     pub updated_data: Option<ValueRange>,
     /**
-     * The response when updating a range of values by a data filter in a spreadsheet.
+     * The range (in A1 notation) that updates were applied to.
      */
     #[serde(
         default,
@@ -11169,7 +12626,7 @@ pub struct UpdateValuesByDataFilterResponse {
     )]
     pub updated_range: String,
     /**
-     * The response when updating a range of values by a data filter in a spreadsheet.
+     * The number of rows where at least one cell in the row was updated.
      */
     #[serde(
         default,
@@ -11184,7 +12641,7 @@ pub struct UpdateValuesByDataFilterResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateValuesResponse {
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The spreadsheet the updates were applied to.
      */
     #[serde(
         default,
@@ -11194,7 +12651,7 @@ pub struct UpdateValuesResponse {
     )]
     pub spreadsheet_id: String,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The number of cells updated.
      */
     #[serde(
         default,
@@ -11204,7 +12661,7 @@ pub struct UpdateValuesResponse {
     )]
     pub updated_cells: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The number of columns where at least one cell in the column was updated.
      */
     #[serde(
         default,
@@ -11214,7 +12671,7 @@ pub struct UpdateValuesResponse {
     )]
     pub updated_columns: i64,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * Data within a range of the spreadsheet.
      */
     #[serde(
         default,
@@ -11223,7 +12680,7 @@ pub struct UpdateValuesResponse {
     )]
     pub updated_data: Option<ValueRange>,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The range (in A1 notation) that updates were applied to.
      */
     #[serde(
         default,
@@ -11233,7 +12690,7 @@ pub struct UpdateValuesResponse {
     )]
     pub updated_range: String,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * The number of rows where at least one cell in the row was updated.
      */
     #[serde(
         default,
@@ -11248,7 +12705,7 @@ pub struct UpdateValuesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ValueRange {
     /**
-     * Data within a range of the spreadsheet.
+     * The major dimension of the values. For output, if the spreadsheet data is: `A1=1,B1=2,A2=3,B2=4`, then requesting `range=A1:B2,majorDimension=ROWS` will return `[[1,2],[3,4]]`, whereas requesting `range=A1:B2,majorDimension=COLUMNS` will return `[[1,3],[2,4]]`. For input, with `range=A1:B2,majorDimension=ROWS` then `[[1,2],[3,4]]` will set `A1=1,B1=2,A2=3,B2=4`. With `range=A1:B2,majorDimension=COLUMNS` then `[[1,2],[3,4]]` will set `A1=1,B1=3,A2=2,B2=4`. When writing, if this field is not set, it defaults to ROWS.
      */
     #[serde(
         default,
@@ -11257,7 +12714,7 @@ pub struct ValueRange {
     )]
     pub major_dimension: Option<Dimension>,
     /**
-     * Data within a range of the spreadsheet.
+     * The range the values cover, in A1 notation. For output, this range indicates the entire requested range, even though the values will exclude trailing rows and columns. When appending values, this field represents the range to search for a table, after which values will be appended.
      */
     #[serde(
         default,
@@ -11266,7 +12723,7 @@ pub struct ValueRange {
     )]
     pub range: String,
     /**
-     * Data within a range of the spreadsheet.
+     * The data that was read or to be written. This is an array of arrays, the outer array representing all the data and each inner array representing a major dimension. Each item in the inner array corresponds with one cell. For output, empty trailing rows and columns will not be included. For input, supported value types are: bool, string, and double. Null values will be skipped. To set a cell to an empty value, set the string value to an empty string.
      */
     #[serde(
         default,
@@ -11280,12 +12737,12 @@ pub struct ValueRange {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WaterfallChartColumnStyle {
     /**
-     * Styles for a waterfall chart column.
+     * Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor\* fromProto(Color\* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue\* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color\* toProto(UIColor\* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color\* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac \* 255); var green = Math.floor(greenFrac \* 255); var blue = Math.floor(blueFrac \* 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<Color>,
     /**
-     * Styles for a waterfall chart column.
+     * A color value.
      */
     #[serde(
         default,
@@ -11294,7 +12751,7 @@ pub struct WaterfallChartColumnStyle {
     )]
     pub color_style: Option<ColorStyle>,
     /**
-     * Styles for a waterfall chart column.
+     * The label of the column's legend.
      */
     #[serde(
         default,
@@ -11308,7 +12765,7 @@ pub struct WaterfallChartColumnStyle {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WaterfallChartCustomSubtotal {
     /**
-     * A custom subtotal column for a waterfall chart series.
+     * True if the data point at subtotal_index is the subtotal. If false, the subtotal will be computed and appear after the data point.
      */
     #[serde(
         default,
@@ -11317,7 +12774,7 @@ pub struct WaterfallChartCustomSubtotal {
     )]
     pub data_is_subtotal: bool,
     /**
-     * A custom subtotal column for a waterfall chart series.
+     * A label for the subtotal column.
      */
     #[serde(
         default,
@@ -11326,7 +12783,7 @@ pub struct WaterfallChartCustomSubtotal {
     )]
     pub label: String,
     /**
-     * A custom subtotal column for a waterfall chart series.
+     * The 0-based index of a data point within the series. If data_is_subtotal is true, the data point at this index is the subtotal. Otherwise, the subtotal appears after the data point with this index. A series can have multiple subtotals at arbitrary indices, but subtotals do not affect the indices of the data points. For example, if a series has three data points, their indices will always be 0, 1, and 2, regardless of how many subtotals exist on the series or what data points they are associated with.
      */
     #[serde(
         default,
@@ -11341,12 +12798,12 @@ pub struct WaterfallChartCustomSubtotal {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WaterfallChartDomain {
     /**
-     * The domain of a waterfall chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<ChartData>,
     /**
-     * The domain of a waterfall chart.
+     * True to reverse the order of the domain values (horizontal axis).
      */
     #[serde(
         default,
@@ -11359,7 +12816,7 @@ pub struct WaterfallChartDomain {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WaterfallChartSeries {
     /**
-     * A single series of data for a waterfall chart.
+     * Custom subtotal columns appearing in this series. The order in which subtotals are defined is not significant. Only one subtotal may be defined for each data point.
      */
     #[serde(
         default,
@@ -11369,17 +12826,17 @@ pub struct WaterfallChartSeries {
     )]
     pub custom_subtotals: Vec<WaterfallChartCustomSubtotal>,
     /**
-     * A single series of data for a waterfall chart.
+     * The data included in a domain or series.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<ChartData>,
     /**
-     * A single series of data for a waterfall chart.
+     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "dataLabel")]
     pub data_label: Option<DataLabel>,
     /**
-     * A single series of data for a waterfall chart.
+     * True to hide the subtotal column from the end of the series. By default, a subtotal column will appear at the end of each series. Setting this field to true will hide that subtotal column for this series.
      */
     #[serde(
         default,
@@ -11388,7 +12845,7 @@ pub struct WaterfallChartSeries {
     )]
     pub hide_trailing_subtotal: bool,
     /**
-     * A single series of data for a waterfall chart.
+     * Styles for a waterfall chart column.
      */
     #[serde(
         default,
@@ -11397,7 +12854,7 @@ pub struct WaterfallChartSeries {
     )]
     pub negative_columns_style: Option<WaterfallChartColumnStyle>,
     /**
-     * A single series of data for a waterfall chart.
+     * Styles for a waterfall chart column.
      */
     #[serde(
         default,
@@ -11406,7 +12863,7 @@ pub struct WaterfallChartSeries {
     )]
     pub positive_columns_style: Option<WaterfallChartColumnStyle>,
     /**
-     * A single series of data for a waterfall chart.
+     * Styles for a waterfall chart column.
      */
     #[serde(
         default,
@@ -11420,6 +12877,7 @@ pub struct WaterfallChartSeries {
 * The stacked type.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WaterfallChartSpecStackedType {
     #[serde(rename = "SEQUENTIAL")]
     Sequential,
@@ -11429,8 +12887,9 @@ pub enum WaterfallChartSpecStackedType {
     WaterfallStackedTypeUnspecified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaterfallChartSpecStackedType {
@@ -11442,12 +12901,35 @@ impl std::fmt::Display for WaterfallChartSpecStackedType {
                 "WATERFALL_STACKED_TYPE_UNSPECIFIED"
             }
             WaterfallChartSpecStackedType::Noop => "",
-            WaterfallChartSpecStackedType::FallthroughString => "*",
+            WaterfallChartSpecStackedType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WaterfallChartSpecStackedType {
+    fn from(s: String) -> WaterfallChartSpecStackedType {
+        match s.as_str() {
+            "SEQUENTIAL" => WaterfallChartSpecStackedType::Sequential,
+            "STACKED" => WaterfallChartSpecStackedType::Stacked,
+            "WATERFALL_STACKED_TYPE_UNSPECIFIED" => {
+                WaterfallChartSpecStackedType::WaterfallStackedTypeUnspecified
+            }
+            "" => WaterfallChartSpecStackedType::Noop,
+            _ => WaterfallChartSpecStackedType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WaterfallChartSpecStackedType> for String {
+    fn from(e: WaterfallChartSpecStackedType) -> String {
+        match e {
+            WaterfallChartSpecStackedType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WaterfallChartSpecStackedType {
     fn default() -> WaterfallChartSpecStackedType {
         WaterfallChartSpecStackedType::Noop
@@ -11463,7 +12945,7 @@ impl WaterfallChartSpecStackedType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WaterfallChartSpec {
     /**
-     * A waterfall chart.
+     * Properties that describe the style of a line.
      */
     #[serde(
         default,
@@ -11472,12 +12954,12 @@ pub struct WaterfallChartSpec {
     )]
     pub connector_line_style: Option<LineStyle>,
     /**
-     * A waterfall chart.
+     * The domain of a waterfall chart.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain: Option<WaterfallChartDomain>,
     /**
-     * A waterfall chart.
+     * True to interpret the first value as a total.
      */
     #[serde(
         default,
@@ -11486,7 +12968,7 @@ pub struct WaterfallChartSpec {
     )]
     pub first_value_is_total: bool,
     /**
-     * A waterfall chart.
+     * True to hide connector lines between columns.
      */
     #[serde(
         default,
@@ -11495,7 +12977,7 @@ pub struct WaterfallChartSpec {
     )]
     pub hide_connector_lines: bool,
     /**
-     * A waterfall chart.
+     * The data this waterfall chart is visualizing.
      */
     #[serde(
         default,
@@ -11504,7 +12986,7 @@ pub struct WaterfallChartSpec {
     )]
     pub series: Vec<WaterfallChartSeries>,
     /**
-     * A waterfall chart.
+     * The stacked type.
      */
     #[serde(
         default,
@@ -11513,7 +12995,7 @@ pub struct WaterfallChartSpec {
     )]
     pub stacked_type: Option<WaterfallChartSpecStackedType>,
     /**
-     * A waterfall chart.
+     * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
      */
     #[serde(
         default,
@@ -11527,6 +13009,7 @@ pub struct WaterfallChartSpec {
 * V1 error format.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -11534,8 +13017,9 @@ pub enum Xgafv {
     Two,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Xgafv {
@@ -11544,12 +13028,32 @@ impl std::fmt::Display for Xgafv {
             Xgafv::One => "1",
             Xgafv::Two => "2",
             Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
+            Xgafv::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Xgafv {
+    fn from(s: String) -> Xgafv {
+        match s.as_str() {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            "" => Xgafv::Noop,
+            _ => Xgafv::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Xgafv> for String {
+    fn from(e: Xgafv) -> String {
+        match e {
+            Xgafv::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Xgafv {
     fn default() -> Xgafv {
         Xgafv::Noop
@@ -11565,6 +13069,7 @@ impl Xgafv {
 * Data format for response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -11574,8 +13079,9 @@ pub enum Alt {
     Proto,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Alt {
@@ -11585,12 +13091,33 @@ impl std::fmt::Display for Alt {
             Alt::Media => "media",
             Alt::Proto => "proto",
             Alt::Noop => "",
-            Alt::FallthroughString => "*",
+            Alt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Alt {
+        match s.as_str() {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            "" => Alt::Noop,
+            _ => Alt::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(e: Alt) -> String {
+        match e {
+            Alt::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Alt {
     fn default() -> Alt {
         Alt::Noop
@@ -11606,6 +13133,7 @@ impl Alt {
 * How the input data should be inserted.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InsertDataOption {
     #[serde(rename = "INSERT_ROWS")]
     InsertRows,
@@ -11613,8 +13141,9 @@ pub enum InsertDataOption {
     Overwrite,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InsertDataOption {
@@ -11623,12 +13152,32 @@ impl std::fmt::Display for InsertDataOption {
             InsertDataOption::InsertRows => "INSERT_ROWS",
             InsertDataOption::Overwrite => "OVERWRITE",
             InsertDataOption::Noop => "",
-            InsertDataOption::FallthroughString => "*",
+            InsertDataOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InsertDataOption {
+    fn from(s: String) -> InsertDataOption {
+        match s.as_str() {
+            "INSERT_ROWS" => InsertDataOption::InsertRows,
+            "OVERWRITE" => InsertDataOption::Overwrite,
+            "" => InsertDataOption::Noop,
+            _ => InsertDataOption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InsertDataOption> for String {
+    fn from(e: InsertDataOption) -> String {
+        match e {
+            InsertDataOption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InsertDataOption {
     fn default() -> InsertDataOption {
         InsertDataOption::Noop
@@ -24,6 +24,22 @@ impl AdminsBeta {
         &self,
         company_id: &str,
     ) -> ClientResult<Vec<crate::types::Admin>> {
+        Ok(self
+            .get_company_admins_with_response(company_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get all the admins at a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/admins` endpoint.
+     *
+     * As opposed to `get_company_admins`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_admins_with_response(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Admin>>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/admins",
@@ -32,7 +48,7 @@ impl AdminsBeta {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,6 +89,70 @@ impl AdminsBeta {
             )
             .await
     }
+    /**
+     * Get all the admins at a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/admins` endpoint.
+     *
+     * As opposed to `get_all_company_admins`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_admins_pages_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Admin>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/admins",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Admin>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all the admins at a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/admins` endpoint.
+     *
+     * As opposed to `get_all_company_admins`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_admins_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Admin>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_admins_pages_stream(company_id, None))
+    }
     /**
      * Create an admin for the company.
      *
@@ -87,6 +167,23 @@ impl AdminsBeta {
         company_id: &str,
         body: &crate::types::PostCompanyAdminsRequest,
     ) -> ClientResult<crate::types::Admin> {
+        Ok(self
+            .post_company_admin_with_response(company_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an admin for the company.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id}/admins` endpoint.
+     *
+     * As opposed to `post_company_admin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_admin_with_response(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyAdminsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Admin>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/admins",
@@ -95,7 +192,7 @@ impl AdminsBeta {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -21,9 +21,21 @@ impl Benefits {
      * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
      */
     pub async fn get_page(&self) -> ClientResult<Vec<crate::types::SupportedBenefit>> {
+        Ok(self.get_page_with_response().await?.body)
+    }
+    /**
+     * Get all benefits supported by Gusto.
+     *
+     * This function performs a `GET` to the `/v1/benefits` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SupportedBenefit>>> {
         let url = self.client.url("/v1/benefits", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,6 +67,66 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get all benefits supported by Gusto.
+     *
+     * This function performs a `GET` to the `/v1/benefits` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SupportedBenefit>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/v1/benefits", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SupportedBenefit>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all benefits supported by Gusto.
+     *
+     * This function performs a `GET` to the `/v1/benefits` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SupportedBenefit>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_pages_stream(None))
+    }
     /**
      * Get a supported benefit by ID.
      *
@@ -65,6 +137,19 @@ impl Benefits {
      * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
      */
     pub async fn get(&self, benefit_id: &str) -> ClientResult<crate::types::SupportedBenefit> {
+        Ok(self.get_with_response(benefit_id).await?.body)
+    }
+    /**
+     * Get a supported benefit by ID.
+     *
+     * This function performs a `GET` to the `/v1/benefits/{benefit_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        benefit_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SupportedBenefit>> {
         let url = self.client.url(
             &format!(
                 "/v1/benefits/{}",
@@ -73,7 +158,7 @@ impl Benefits {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -95,6 +180,19 @@ impl Benefits {
         &self,
         company_id: &str,
     ) -> ClientResult<Vec<crate::types::CompanyBenefit>> {
+        Ok(self.get_company_with_response(company_id).await?.body)
+    }
+    /**
+     * Get benefits for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CompanyBenefit>>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/company_benefits",
@@ -103,7 +201,7 @@ impl Benefits {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -144,6 +242,74 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get benefits for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pages_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CompanyBenefit>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/company_benefits",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CompanyBenefit>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get benefits for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CompanyBenefit>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_pages_stream(company_id, None))
+    }
     /**
      * Create a company benefit.
      *
@@ -158,6 +324,23 @@ impl Benefits {
         company_id: &str,
         body: &crate::types::PostCompanyBenefitsRequest,
     ) -> ClientResult<crate::types::CompanyBenefit> {
+        Ok(self
+            .post_company_with_response(company_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a company benefit.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `post_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_with_response(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyBenefitsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBenefit>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/company_benefits",
@@ -166,7 +349,7 @@ impl Benefits {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -188,6 +371,22 @@ impl Benefits {
         &self,
         company_benefit_id: &str,
     ) -> ClientResult<crate::types::CompanyBenefit> {
+        Ok(self
+            .get_company_benefits_with_response(company_benefit_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a company benefit.
+     *
+     * This function performs a `GET` to the `/v1/company_benefits/{company_benefit_id}` endpoint.
+     *
+     * As opposed to `get_company_benefits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_benefits_with_response(
+        &self,
+        company_benefit_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBenefit>> {
         let url = self.client.url(
             &format!(
                 "/v1/company_benefits/{}",
@@ -196,7 +395,7 @@ impl Benefits {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -219,6 +418,23 @@ impl Benefits {
         company_benefit_id: &str,
         body: &crate::types::PutCompanyBenefitRequest,
     ) -> ClientResult<crate::types::CompanyBenefit> {
+        Ok(self
+            .put_company_with_response(company_benefit_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a company benefit.
+     *
+     * This function performs a `PUT` to the `/v1/company_benefits/{company_benefit_id}` endpoint.
+     *
+     * As opposed to `put_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_with_response(
+        &self,
+        company_benefit_id: &str,
+        body: &crate::types::PutCompanyBenefitRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBenefit>> {
         let url = self.client.url(
             &format!(
                 "/v1/company_benefits/{}",
@@ -227,7 +443,7 @@ impl Benefits {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -249,6 +465,19 @@ impl Benefits {
         &self,
         employee_id: &str,
     ) -> ClientResult<Vec<crate::types::EmployeeBenefit>> {
+        Ok(self.get_employee_with_response(employee_id).await?.body)
+    }
+    /**
+     * Get an employee's benefits.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `get_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_employee_with_response(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::EmployeeBenefit>>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/employee_benefits",
@@ -257,7 +486,7 @@ impl Benefits {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -298,6 +527,74 @@ impl Benefits {
             )
             .await
     }
+    /**
+     * Get an employee's benefits.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_employee_pages_stream(
+        &self,
+        employee_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::EmployeeBenefit>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/employee_benefits",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::EmployeeBenefit>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get an employee's benefits.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EmployeeBenefit>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_employee_pages_stream(employee_id, None))
+    }
     /**
      * Create an employee benefit.
      *
@@ -310,6 +607,23 @@ impl Benefits {
         employee_id: &str,
         body: &crate::types::PostEmployeeBenefitsRequest,
     ) -> ClientResult<crate::types::EmployeeBenefit> {
+        Ok(self
+            .post_employee_with_response(employee_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an employee benefit.
+     *
+     * This function performs a `POST` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `post_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_employee_with_response(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeBenefitsRequest,
+    ) -> ClientResult<crate::Response<crate::types::EmployeeBenefit>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/employee_benefits",
@@ -318,7 +632,7 @@ impl Benefits {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -339,6 +653,22 @@ impl Benefits {
         employee_id: &str,
         body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
     ) -> ClientResult<()> {
+        self.post_employee_ytd_amounts_from_different_company_with_response(employee_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Year-to-date Benefit Amounts from Different Company.
+     *
+     * This function performs a `POST` to the `/v1/employees/{employee_id}/ytd_benefit_amounts_from_different_company` endpoint.
+     *
+     * As opposed to `post_employee_ytd_amounts_from_different_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_employee_ytd_amounts_from_different_company_with_response(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/ytd_benefit_amounts_from_different_company",
@@ -347,7 +677,7 @@ impl Benefits {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -367,6 +697,22 @@ impl Benefits {
         &self,
         employee_benefit_id: &str,
     ) -> ClientResult<crate::types::EmployeeBenefit> {
+        Ok(self
+            .get_employee_benefits_with_response(employee_benefit_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get an employee benefit.
+     *
+     * This function performs a `GET` to the `/v1/employee_benefits/{employee_benefit_id}` endpoint.
+     *
+     * As opposed to `get_employee_benefits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_employee_benefits_with_response(
+        &self,
+        employee_benefit_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmployeeBenefit>> {
         let url = self.client.url(
             &format!(
                 "/v1/employee_benefits/{}",
@@ -375,7 +721,7 @@ impl Benefits {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -396,6 +742,23 @@ impl Benefits {
         employee_benefit_id: &str,
         body: &crate::types::PutEmployeeBenefitRequest,
     ) -> ClientResult<crate::types::EmployeeBenefit> {
+        Ok(self
+            .put_employee_with_response(employee_benefit_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an employee benefit.
+     *
+     * This function performs a `PUT` to the `/v1/employee_benefits/{employee_benefit_id}` endpoint.
+     *
+     * As opposed to `put_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_employee_with_response(
+        &self,
+        employee_benefit_id: &str,
+        body: &crate::types::PutEmployeeBenefitRequest,
+    ) -> ClientResult<crate::Response<crate::types::EmployeeBenefit>> {
         let url = self.client.url(
             &format!(
                 "/v1/employee_benefits/{}",
@@ -404,7 +767,7 @@ impl Benefits {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -421,6 +784,21 @@ impl Benefits {
      * Employee benefits represent an employee enrolled in a particular company benefit. It includes information specific to that employee’s enrollment.
      */
     pub async fn delete_employee(&self, employee_benefit_id: &str) -> ClientResult<()> {
+        self.delete_employee_with_response(employee_benefit_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an employee benefit.
+     *
+     * This function performs a `DELETE` to the `/v1/employee_benefits/{employee_benefit_id}` endpoint.
+     *
+     * As opposed to `delete_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_employee_with_response(
+        &self,
+        employee_benefit_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/employee_benefits/{}",
@@ -429,7 +807,7 @@ impl Benefits {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -19,6 +19,19 @@ impl Companies {
      * Get a company.
      */
     pub async fn get(&self, company_id_or_uuid: &str) -> ClientResult<crate::types::Company> {
+        Ok(self.get_with_response(company_id_or_uuid).await?.body)
+    }
+    /**
+     * Get a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::Company>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}",
@@ -27,7 +40,7 @@ impl Companies {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,9 +84,22 @@ impl Companies {
         &self,
         body: &crate::types::PostPartnerManagedCompaniesRequest,
     ) -> ClientResult<crate::types::PostPartnerManagedCompaniesResponse> {
+        Ok(self.post_partner_managed_with_response(body).await?.body)
+    }
+    /**
+     * Create a partner managed company (Beta).
+     *
+     * This function performs a `POST` to the `/v1/partner_managed_companies` endpoint.
+     *
+     * As opposed to `post_partner_managed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_partner_managed_with_response(
+        &self,
+        body: &crate::types::PostPartnerManagedCompaniesRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostPartnerManagedCompaniesResponse>> {
         let url = self.client.url("/v1/partner_managed_companies", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -111,9 +137,22 @@ impl Companies {
         &self,
         body: &crate::types::PostProvisionRequest,
     ) -> ClientResult<crate::types::PostProvisionResponse> {
+        Ok(self.post_provision_with_response(body).await?.body)
+    }
+    /**
+     * Create a company.
+     *
+     * This function performs a `POST` to the `/v1/provision` endpoint.
+     *
+     * As opposed to `post_provision`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_provision_with_response(
+        &self,
+        body: &crate::types::PostProvisionRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostProvisionResponse>> {
         let url = self.client.url("/v1/provision", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
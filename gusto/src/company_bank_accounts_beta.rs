@@ -24,6 +24,22 @@ impl CompanyBankAccountsBeta {
         &self,
         company_id_or_uuid: &str,
     ) -> ClientResult<Vec<crate::types::CompanyBankAccount>> {
+        Ok(self
+            .get_company_bank_accounts_with_response(company_id_or_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get all company bank accounts.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_company_bank_accounts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_bank_accounts_with_response(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CompanyBankAccount>>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/bank_accounts",
@@ -32,7 +48,7 @@ impl CompanyBankAccountsBeta {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,6 +89,78 @@ impl CompanyBankAccountsBeta {
             )
             .await
     }
+    /**
+     * Get all company bank accounts.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_all_company_bank_accounts`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_bank_accounts_pages_stream(
+        &self,
+        company_id_or_uuid: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CompanyBankAccount>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/bank_accounts",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CompanyBankAccount>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all company bank accounts.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_all_company_bank_accounts`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_bank_accounts_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CompanyBankAccount>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_company_bank_accounts_pages_stream(company_id_or_uuid, None),
+        )
+    }
     /**
      * Create a company bank account.
      *
@@ -87,6 +175,23 @@ impl CompanyBankAccountsBeta {
         company_id_or_uuid: &str,
         body: &crate::types::PostCompanyBankAccountsRequest,
     ) -> ClientResult<crate::types::CompanyBankAccount> {
+        Ok(self
+            .post_company_bank_account_with_response(company_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a company bank account.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id_or_uuid}/bank_accounts` endpoint.
+     *
+     * As opposed to `post_company_bank_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_bank_account_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyBankAccountsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBankAccount>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/bank_accounts",
@@ -95,7 +200,7 @@ impl CompanyBankAccountsBeta {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -119,6 +224,28 @@ impl CompanyBankAccountsBeta {
         bank_account_uuid: &str,
         body: &crate::types::PutCompanyBankAccountsVerifyRequest,
     ) -> ClientResult<crate::types::CompanyBankAccount> {
+        Ok(self
+            .put_company_bank_accounts_verify_with_response(
+                company_id_or_uuid,
+                bank_account_uuid,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Verify a company bank account.
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id_or_uuid}/bank_accounts/{bank_account_uuid}/verify` endpoint.
+     *
+     * As opposed to `put_company_bank_accounts_verify`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_bank_accounts_verify_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        bank_account_uuid: &str,
+        body: &crate::types::PutCompanyBankAccountsVerifyRequest,
+    ) -> ClientResult<crate::Response<crate::types::CompanyBankAccount>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/bank_accounts/{}/verify",
@@ -128,7 +255,7 @@ impl CompanyBankAccountsBeta {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
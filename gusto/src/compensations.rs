@@ -22,6 +22,19 @@ impl Compensations {
      *
      */
     pub async fn get(&self, compensation_id: &str) -> ClientResult<crate::types::Compensation> {
+        Ok(self.get_with_response(compensation_id).await?.body)
+    }
+    /**
+     * Get a compensation.
+     *
+     * This function performs a `GET` to the `/v1/compensations/{compensation_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        compensation_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Compensation>> {
         let url = self.client.url(
             &format!(
                 "/v1/compensations/{}",
@@ -30,7 +43,7 @@ impl Compensations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -53,6 +66,20 @@ impl Compensations {
         compensation_id: &str,
         body: &crate::types::PutCompensationRequest,
     ) -> ClientResult<crate::types::Compensation> {
+        Ok(self.put_with_response(compensation_id, body).await?.body)
+    }
+    /**
+     * Update a compensation.
+     *
+     * This function performs a `PUT` to the `/v1/compensations/{compensation_id}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        compensation_id: &str,
+        body: &crate::types::PutCompensationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Compensation>> {
         let url = self.client.url(
             &format!(
                 "/v1/compensations/{}",
@@ -61,7 +88,7 @@ impl Compensations {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -82,6 +109,19 @@ impl Compensations {
      * Use the `flsa_status` to determine if an employee is elibgle for overtime.
      */
     pub async fn get_job(&self, job_id: &str) -> ClientResult<Vec<crate::types::Compensation>> {
+        Ok(self.get_job_with_response(job_id).await?.body)
+    }
+    /**
+     * Get compensations for a job.
+     *
+     * This function performs a `GET` to the `/v1/jobs/{job_id}/compensations` endpoint.
+     *
+     * As opposed to `get_job`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_job_with_response(
+        &self,
+        job_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Compensation>>> {
         let url = self.client.url(
             &format!(
                 "/v1/jobs/{}/compensations",
@@ -90,7 +130,7 @@ impl Compensations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,4 +170,72 @@ impl Compensations {
             )
             .await
     }
+    /**
+     * Get compensations for a job.
+     *
+     * This function performs a `GET` to the `/v1/jobs/{job_id}/compensations` endpoint.
+     *
+     * As opposed to `get_all_job`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_job_pages_stream(
+        &self,
+        job_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Compensation>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/jobs/{}/compensations",
+                crate::progenitor_support::encode_path(job_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Compensation>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get compensations for a job.
+     *
+     * This function performs a `GET` to the `/v1/jobs/{job_id}/compensations` endpoint.
+     *
+     * As opposed to `get_all_job`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_job_stream(
+        &self,
+        job_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Compensation>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_job_pages_stream(job_id, None))
+    }
 }
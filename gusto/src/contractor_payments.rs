@@ -29,6 +29,24 @@ impl ContractorPayments {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<crate::types::ContractorPaymentSummary> {
+        Ok(self
+            .get_company_with_response(company_id, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Get contractor payments for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/contractor_payments` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContractorPaymentSummary>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !end_date.is_empty() {
             query_args.push(("end_date".to_string(), end_date.to_string()));
@@ -46,7 +64,7 @@ impl ContractorPayments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -83,6 +101,36 @@ impl ContractorPayments {
         bonus: f64,
         reimbursement: f64,
     ) -> ClientResult<crate::types::ContractorPayment> {
+        Ok(self
+            .post_company_with_response(
+                company_id,
+                date,
+                contractor_id,
+                wage,
+                hours,
+                bonus,
+                reimbursement,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Create a contractor payment (Beta).
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id}/contractor_payments` endpoint.
+     *
+     * As opposed to `post_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_with_response(
+        &self,
+        company_id: &str,
+        date: &str,
+        contractor_id: f64,
+        wage: f64,
+        hours: f64,
+        bonus: f64,
+        reimbursement: f64,
+    ) -> ClientResult<crate::Response<crate::types::ContractorPayment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !bonus.to_string().is_empty() {
             query_args.push(("bonus".to_string(), bonus.to_string()));
@@ -112,7 +160,7 @@ impl ContractorPayments {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -133,6 +181,26 @@ impl ContractorPayments {
         company_id: &str,
         contractor_payment_id_or_uuid: &str,
     ) -> ClientResult<crate::types::ContractorPayment> {
+        Ok(self
+            .get_company_contractor_payments_with_response(
+                company_id,
+                contractor_payment_id_or_uuid,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get a single contractor payment.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get_company_contractor_payments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_contractor_payments_with_response(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContractorPayment>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/contractor_payments/{}",
@@ -142,7 +210,7 @@ impl ContractorPayments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -165,6 +233,22 @@ impl ContractorPayments {
         company_id: &str,
         contractor_payment_id_or_uuid: &str,
     ) -> ClientResult<()> {
+        self.delete_company_with_response(company_id, contractor_payment_id_or_uuid)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Cancel a contractor payment (Beta).
+     *
+     * This function performs a `DELETE` to the `/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}` endpoint.
+     *
+     * As opposed to `delete_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_company_with_response(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/contractor_payments/{}",
@@ -174,7 +258,7 @@ impl ContractorPayments {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -19,6 +19,19 @@ impl Contractors {
      * Get a contractor.
      */
     pub async fn get(&self, contractor_id_or_uuid: &str) -> ClientResult<crate::types::Contractor> {
+        Ok(self.get_with_response(contractor_id_or_uuid).await?.body)
+    }
+    /**
+     * Get a contractor.
+     *
+     * This function performs a `GET` to the `/v1/contractors/{contractor_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        contractor_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::Contractor>> {
         let url = self.client.url(
             &format!(
                 "/v1/contractors/{}",
@@ -27,7 +40,7 @@ impl Contractors {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -48,6 +61,23 @@ impl Contractors {
         contractor_id_or_uuid: &str,
         body: &crate::types::PutComntractorRequest,
     ) -> ClientResult<crate::types::Contractor> {
+        Ok(self
+            .put_with_response(contractor_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a contractor.
+     *
+     * This function performs a `PUT` to the `/v1/contractors/{contractor_id_or_uuid}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        contractor_id_or_uuid: &str,
+        body: &crate::types::PutComntractorRequest,
+    ) -> ClientResult<crate::Response<crate::types::Contractor>> {
         let url = self.client.url(
             &format!(
                 "/v1/contractors/{}",
@@ -56,7 +86,7 @@ impl Contractors {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -76,6 +106,22 @@ impl Contractors {
         &self,
         company_id_or_uuid: &str,
     ) -> ClientResult<Vec<crate::types::Contractor>> {
+        Ok(self
+            .get_company_with_response(company_id_or_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get contractors of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contractor>>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/contractors",
@@ -84,7 +130,7 @@ impl Contractors {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -123,6 +169,74 @@ impl Contractors {
             )
             .await
     }
+    /**
+     * Get contractors of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pages_stream(
+        &self,
+        company_id_or_uuid: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Contractor>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/contractors",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Contractor>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get contractors of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contractor>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_pages_stream(company_id_or_uuid, None))
+    }
     /**
      * Create a contractor.
      *
@@ -135,6 +249,23 @@ impl Contractors {
         company_id_or_uuid: &str,
         body: &crate::types::PostCompanyContractorsRequest,
     ) -> ClientResult<crate::types::Contractor> {
+        Ok(self
+            .post_company_with_response(company_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a contractor.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `post_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyContractorsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Contractor>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/contractors",
@@ -143,7 +274,7 @@ impl Contractors {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -19,9 +19,21 @@ impl CurrentUser {
      * Returns information pertaining to the user associated with the provided access token.
      */
     pub async fn get_me(&self) -> ClientResult<crate::types::CurrentUser> {
+        Ok(self.get_me_with_response().await?.body)
+    }
+    /**
+     * Get the current user.
+     *
+     * This function performs a `GET` to the `/v1/me` endpoint.
+     *
+     * As opposed to `get_me`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_me_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::CurrentUser>> {
         let url = self.client.url("/v1/me", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
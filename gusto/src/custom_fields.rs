@@ -22,6 +22,19 @@ impl CustomFields {
         &self,
         employee_id: &str,
     ) -> ClientResult<crate::types::GetEmployeeCustomFieldsResponse> {
+        Ok(self.get_employee_with_response(employee_id).await?.body)
+    }
+    /**
+     * Get an employee's custom fields.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/custom_fields` endpoint.
+     *
+     * As opposed to `get_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_employee_with_response(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetEmployeeCustomFieldsResponse>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/custom_fields",
@@ -30,7 +43,7 @@ impl CustomFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,6 +63,19 @@ impl CustomFields {
         &self,
         company_id: &str,
     ) -> ClientResult<crate::types::GetCompanyCustomFieldsResponse> {
+        Ok(self.get_company_with_response(company_id).await?.body)
+    }
+    /**
+     * Get the custom fields of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/custom_fields` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCompanyCustomFieldsResponse>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/custom_fields",
@@ -58,7 +84,7 @@ impl CustomFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -28,6 +28,19 @@ impl EarningType {
         &self,
         company_id: &str,
     ) -> ClientResult<crate::types::EarningTypeListResponse> {
+        Ok(self.get_company_with_response(company_id).await?.body)
+    }
+    /**
+     * Get all earning types for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/earning_types` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EarningTypeListResponse>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/earning_types",
@@ -36,7 +49,7 @@ impl EarningType {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,6 +72,23 @@ impl EarningType {
         company_id: &str,
         body: &crate::types::PostCompanyEarningTypesRequest,
     ) -> ClientResult<crate::types::EarningType> {
+        Ok(self
+            .post_company_with_response(company_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a custom earning type.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id}/earning_types` endpoint.
+     *
+     * As opposed to `post_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_with_response(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyEarningTypesRequest,
+    ) -> ClientResult<crate::Response<crate::types::EarningType>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/earning_types",
@@ -67,7 +97,7 @@ impl EarningType {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -89,6 +119,24 @@ impl EarningType {
         earning_type_uuid: &str,
         body: &crate::types::PutCompanyEarningTypeRequest,
     ) -> ClientResult<crate::types::EarningType> {
+        Ok(self
+            .put_company_type_with_response(company_id, earning_type_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an earning type.
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id}/earning_types/{earning_type_uuid}` endpoint.
+     *
+     * As opposed to `put_company_type`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_type_with_response(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+        body: &crate::types::PutCompanyEarningTypeRequest,
+    ) -> ClientResult<crate::Response<crate::types::EarningType>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/earning_types/{}",
@@ -98,7 +146,7 @@ impl EarningType {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -119,6 +167,22 @@ impl EarningType {
         company_id: &str,
         earning_type_uuid: &str,
     ) -> ClientResult<()> {
+        self.delete_company_type_with_response(company_id, earning_type_uuid)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deactivate an earning type.
+     *
+     * This function performs a `DELETE` to the `/v1/companies/{company_id}/earning_types/{earning_type_uuid}` endpoint.
+     *
+     * As opposed to `delete_company_type`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_company_type_with_response(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/earning_types/{}",
@@ -128,7 +192,7 @@ impl EarningType {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
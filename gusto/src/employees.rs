@@ -27,6 +27,23 @@ impl Employees {
         employee_id_or_uuid: &str,
         include: &[String],
     ) -> ClientResult<crate::types::Employee> {
+        Ok(self
+            .get_with_response(employee_id_or_uuid, include)
+            .await?
+            .body)
+    }
+    /**
+     * Get an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        employee_id_or_uuid: &str,
+        include: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Employee>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.join(" ")));
@@ -41,7 +58,7 @@ impl Employees {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -62,6 +79,23 @@ impl Employees {
         employee_id_or_uuid: &str,
         body: &crate::types::PutEmployeesRequest,
     ) -> ClientResult<crate::types::Employee> {
+        Ok(self
+            .put_with_response(employee_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an employee.
+     *
+     * This function performs a `PUT` to the `/v1/employees/{employee_id_or_uuid}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        employee_id_or_uuid: &str,
+        body: &crate::types::PutEmployeesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Employee>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}",
@@ -70,7 +104,7 @@ impl Employees {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -101,6 +135,26 @@ impl Employees {
         per: f64,
         include: &[String],
     ) -> ClientResult<Vec<crate::types::Employee>> {
+        Ok(self
+            .get_company_with_response(company_id_or_uuid, terminated, page, per, include)
+            .await?
+            .body)
+    }
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        page: f64,
+        per: f64,
+        include: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Employee>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.is_empty() {
             query_args.push(("include".to_string(), include.join(" ")));
@@ -124,7 +178,7 @@ impl Employees {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -174,6 +228,91 @@ impl Employees {
             )
             .await
     }
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pages_stream(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Employee>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include".to_string(), include.join(" ")));
+        }
+        if terminated {
+            query_args.push(("terminated".to_string(), terminated.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/employees?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Employee>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Employee>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_pages_stream(
+            company_id_or_uuid,
+            terminated,
+            include,
+            None,
+        ))
+    }
     /**
      * Create an employee.
      *
@@ -186,6 +325,23 @@ impl Employees {
         company_id_or_uuid: &str,
         body: &crate::types::PostEmployeesRequest,
     ) -> ClientResult<crate::types::Employee> {
+        Ok(self
+            .post_with_response(company_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an employee.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostEmployeesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Employee>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/employees",
@@ -194,7 +350,7 @@ impl Employees {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -214,6 +370,19 @@ impl Employees {
         &self,
         employee_id: &str,
     ) -> ClientResult<crate::types::Location> {
+        Ok(self.get_home_address_with_response(employee_id).await?.body)
+    }
+    /**
+     * Get an employee's home address.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/home_address` endpoint.
+     *
+     * As opposed to `get_home_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_home_address_with_response(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/home_address",
@@ -222,7 +391,7 @@ impl Employees {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -243,6 +412,23 @@ impl Employees {
         employee_id: &str,
         body: &crate::types::PutEmployeeHomeAddressRequest,
     ) -> ClientResult<crate::types::Location> {
+        Ok(self
+            .put_home_address_with_response(employee_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an employee's home address.
+     *
+     * This function performs a `PUT` to the `/v1/employees/{employee_id}/home_address` endpoint.
+     *
+     * As opposed to `put_home_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_home_address_with_response(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PutEmployeeHomeAddressRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/home_address",
@@ -251,7 +437,7 @@ impl Employees {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
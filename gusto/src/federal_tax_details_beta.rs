@@ -25,10 +25,16 @@ impl FederalTaxDetailsBeta {
 * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
 * 
 * Fetches attributes relevant for a company's federal taxes.
-*/pub async fn get_company_or_federal_tax_details(&self,company_id_or_uuid: &str,) -> ClientResult<crate::types::GetCompanyFederalTaxDetailsResponse> {let url = self.client.url(
+*/pub async fn get_company_or_federal_tax_details(&self,company_id_or_uuid: &str,) -> ClientResult<crate::types::GetCompanyFederalTaxDetailsResponse> {Ok(self.get_company_or_federal_tax_details_with_response(company_id_or_uuid).await?.body)}/**
+* Get Federal Tax Details.
+*
+* This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/federal_tax_details` endpoint.
+*
+* As opposed to `get_company_or_federal_tax_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+*/pub async fn get_company_or_federal_tax_details_with_response(&self,company_id_or_uuid: &str,) -> ClientResult<crate::Response<crate::types::GetCompanyFederalTaxDetailsResponse>> {let url = self.client.url(
 &format!("/v1/companies/{}/federal_tax_details",
 crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),), None);
-self.client.get(&url, crate::Message { body: None, content_type: None } ).await}/**
+self.client.get_with_response(&url, crate::Message { body: None, content_type: None } ).await}/**
 * Update Federal Tax Details.
 *
 * This function performs a `PUT` to the `/v1/companies/{company_id_or_uuid}/federal_tax_details` endpoint.
@@ -36,8 +42,14 @@ self.client.get(&url, crate::Message { body: None, content_type: None } ).await}
 * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
 * 
 * Updates attributes relevant for a company's federal taxes. This information is required is to onboard a company for use with Gusto Embedded Payroll.
-*/pub async fn put_company_or_federal_tax_details(&self,company_id_or_uuid: &str,body: &crate::types::PutCompanyFederalTaxDetailsRequest) -> ClientResult<crate::types::GetCompanyFederalTaxDetailsResponse> {let url = self.client.url(
+*/pub async fn put_company_or_federal_tax_details(&self,company_id_or_uuid: &str,body: &crate::types::PutCompanyFederalTaxDetailsRequest) -> ClientResult<crate::types::GetCompanyFederalTaxDetailsResponse> {Ok(self.put_company_or_federal_tax_details_with_response(company_id_or_uuid, body).await?.body)}/**
+* Update Federal Tax Details.
+*
+* This function performs a `PUT` to the `/v1/companies/{company_id_or_uuid}/federal_tax_details` endpoint.
+*
+* As opposed to `put_company_or_federal_tax_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+*/pub async fn put_company_or_federal_tax_details_with_response(&self,company_id_or_uuid: &str,body: &crate::types::PutCompanyFederalTaxDetailsRequest) -> ClientResult<crate::Response<crate::types::GetCompanyFederalTaxDetailsResponse>> {let url = self.client.url(
 &format!("/v1/companies/{}/federal_tax_details",
 crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),), None);
-self.client.put(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await}
+self.client.put_with_response(&url, crate::Message { body: Some(reqwest::Body::from(serde_json::to_vec(body)?)), content_type: Some("application/json".to_string()) } ).await}
 }
\ No newline at end of file
@@ -22,6 +22,19 @@ impl Garnishments {
         &self,
         employee_id: &str,
     ) -> ClientResult<Vec<crate::types::Garnishment>> {
+        Ok(self.get_employee_with_response(employee_id).await?.body)
+    }
+    /**
+     * Get garnishments for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/garnishments` endpoint.
+     *
+     * As opposed to `get_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_employee_with_response(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Garnishment>>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/garnishments",
@@ -30,7 +43,7 @@ impl Garnishments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +82,74 @@ impl Garnishments {
             )
             .await
     }
+    /**
+     * Get garnishments for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/garnishments` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_employee_pages_stream(
+        &self,
+        employee_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Garnishment>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/garnishments",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Garnishment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get garnishments for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/garnishments` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Garnishment>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_employee_pages_stream(employee_id, None))
+    }
     /**
      * Create a garnishment.
      *
@@ -81,6 +162,23 @@ impl Garnishments {
         employee_id: &str,
         body: &crate::types::PostEmployeeGarnishmentsRequest,
     ) -> ClientResult<crate::types::Garnishment> {
+        Ok(self
+            .post_employee_with_response(employee_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a garnishment.
+     *
+     * This function performs a `POST` to the `/v1/employees/{employee_id}/garnishments` endpoint.
+     *
+     * As opposed to `post_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_employee_with_response(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeGarnishmentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Garnishment>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/garnishments",
@@ -89,7 +187,7 @@ impl Garnishments {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -106,6 +204,19 @@ impl Garnishments {
      * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
      */
     pub async fn get(&self, garnishment_id: &str) -> ClientResult<crate::types::Garnishment> {
+        Ok(self.get_with_response(garnishment_id).await?.body)
+    }
+    /**
+     * Get a garnishment.
+     *
+     * This function performs a `GET` to the `/v1/garnishments/{garnishment_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        garnishment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Garnishment>> {
         let url = self.client.url(
             &format!(
                 "/v1/garnishments/{}",
@@ -114,7 +225,7 @@ impl Garnishments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -135,6 +246,20 @@ impl Garnishments {
         garnishment_id: &str,
         body: &crate::types::PutGarnishmentRequest,
     ) -> ClientResult<crate::types::Garnishment> {
+        Ok(self.put_with_response(garnishment_id, body).await?.body)
+    }
+    /**
+     * Update a garnishment.
+     *
+     * This function performs a `PUT` to the `/v1/garnishments/{garnishment_id}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        garnishment_id: &str,
+        body: &crate::types::PutGarnishmentRequest,
+    ) -> ClientResult<crate::Response<crate::types::Garnishment>> {
         let url = self.client.url(
             &format!(
                 "/v1/garnishments/{}",
@@ -143,7 +268,7 @@ impl Garnishments {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,6 +24,22 @@ impl JobApplicantsBeta {
         &self,
         company_id: &str,
     ) -> ClientResult<Vec<crate::types::JobApplicant>> {
+        Ok(self
+            .get_company_job_applicants_with_response(company_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get all job applicants for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `get_company_job_applicants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_job_applicants_with_response(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JobApplicant>>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/job_applicants",
@@ -32,7 +48,7 @@ impl JobApplicantsBeta {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,6 +89,74 @@ impl JobApplicantsBeta {
             )
             .await
     }
+    /**
+     * Get all job applicants for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `get_all_company_job_applicants`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_job_applicants_pages_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::JobApplicant>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/job_applicants",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::JobApplicant>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all job applicants for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `get_all_company_job_applicants`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_job_applicants_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JobApplicant>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_job_applicants_pages_stream(company_id, None))
+    }
     /**
      * Create a job applicant.
      *
@@ -87,6 +171,23 @@ impl JobApplicantsBeta {
         company_id: &str,
         body: &crate::types::PostCompanyJobApplicantsRequest,
     ) -> ClientResult<crate::types::JobApplicant> {
+        Ok(self
+            .post_company_job_applicant_with_response(company_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a job applicant.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `post_company_job_applicant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_job_applicant_with_response(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyJobApplicantsRequest,
+    ) -> ClientResult<crate::Response<crate::types::JobApplicant>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/job_applicants",
@@ -95,7 +196,7 @@ impl JobApplicantsBeta {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -118,6 +219,23 @@ impl JobApplicantsBeta {
         company_id: &str,
         job_applicant_uuid: &str,
     ) -> ClientResult<crate::types::JobApplicant> {
+        Ok(self
+            .get_company_job_applicants_applicant_with_response(company_id, job_applicant_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get a job applicant.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}` endpoint.
+     *
+     * As opposed to `get_company_job_applicants_applicant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_job_applicants_applicant_with_response(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::JobApplicant>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/job_applicants/{}",
@@ -127,7 +245,7 @@ impl JobApplicantsBeta {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -151,6 +269,28 @@ impl JobApplicantsBeta {
         job_applicant_uuid: &str,
         body: &crate::types::PutCompanyJobApplicantRequest,
     ) -> ClientResult<crate::types::JobApplicant> {
+        Ok(self
+            .put_company_job_applicants_applicant_with_response(
+                company_id,
+                job_applicant_uuid,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Update a job applicant.
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}` endpoint.
+     *
+     * As opposed to `put_company_job_applicants_applicant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_job_applicants_applicant_with_response(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+        body: &crate::types::PutCompanyJobApplicantRequest,
+    ) -> ClientResult<crate::Response<crate::types::JobApplicant>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/job_applicants/{}",
@@ -160,7 +300,7 @@ impl JobApplicantsBeta {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -183,6 +323,22 @@ impl JobApplicantsBeta {
         company_id: &str,
         job_applicant_uuid: &str,
     ) -> ClientResult<()> {
+        self.delete_company_job_applicants_applicant_with_response(company_id, job_applicant_uuid)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a job applicant.
+     *
+     * This function performs a `DELETE` to the `/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}` endpoint.
+     *
+     * As opposed to `delete_company_job_applicants_applicant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_company_job_applicants_applicant_with_response(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/job_applicants/{}",
@@ -192,7 +348,7 @@ impl JobApplicantsBeta {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -19,6 +19,19 @@ impl Jobs {
      * Get a job.
      */
     pub async fn get(&self, job_id: &str) -> ClientResult<crate::types::Job> {
+        Ok(self.get_with_response(job_id).await?.body)
+    }
+    /**
+     * Get a job.
+     *
+     * This function performs a `GET` to the `/v1/jobs/{job_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        job_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Job>> {
         let url = self.client.url(
             &format!(
                 "/v1/jobs/{}",
@@ -27,7 +40,7 @@ impl Jobs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -48,6 +61,20 @@ impl Jobs {
         job_id: &str,
         body: &crate::types::PutJobRequest,
     ) -> ClientResult<crate::types::Job> {
+        Ok(self.put_with_response(job_id, body).await?.body)
+    }
+    /**
+     * Update a job.
+     *
+     * This function performs a `PUT` to the `/v1/jobs/{job_id}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        job_id: &str,
+        body: &crate::types::PutJobRequest,
+    ) -> ClientResult<crate::Response<crate::types::Job>> {
         let url = self.client.url(
             &format!(
                 "/v1/jobs/{}",
@@ -56,7 +83,7 @@ impl Jobs {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -73,6 +100,17 @@ impl Jobs {
      * Deletes a specific job that an employee holds.
      */
     pub async fn delete(&self, job_id: &str) -> ClientResult<()> {
+        self.delete_with_response(job_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete an individual job.
+     *
+     * This function performs a `DELETE` to the `/v1/jobs/{job_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, job_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/jobs/{}",
@@ -81,7 +119,7 @@ impl Jobs {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -98,6 +136,19 @@ impl Jobs {
      * Get all of the jobs that an employee holds.
      */
     pub async fn get_employee(&self, employee_id: &str) -> ClientResult<Vec<crate::types::Job>> {
+        Ok(self.get_employee_with_response(employee_id).await?.body)
+    }
+    /**
+     * Get jobs for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `get_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_employee_with_response(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Job>>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/jobs",
@@ -106,7 +157,7 @@ impl Jobs {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -145,6 +196,70 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Get jobs for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_employee_pages_stream(
+        &self,
+        employee_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Job>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/jobs",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Job>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get jobs for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Job>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_employee_pages_stream(employee_id, None))
+    }
     /**
      * Create a job.
      *
@@ -157,6 +272,20 @@ impl Jobs {
         employee_id: &str,
         body: &crate::types::PostJobRequest,
     ) -> ClientResult<crate::types::Job> {
+        Ok(self.post_with_response(employee_id, body).await?.body)
+    }
+    /**
+     * Create a job.
+     *
+     * This function performs a `POST` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostJobRequest,
+    ) -> ClientResult<crate::Response<crate::types::Job>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/jobs",
@@ -165,7 +294,7 @@ impl Jobs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -188,6 +317,23 @@ impl Jobs {
         job_id: &str,
         body: &crate::types::PostJobCompensationsRequest,
     ) -> ClientResult<crate::types::Compensation> {
+        Ok(self
+            .post_compensation_with_response(job_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a compensation.
+     *
+     * This function performs a `POST` to the `/v1/jobs/{job_id}/compensations` endpoint.
+     *
+     * As opposed to `post_compensation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_compensation_with_response(
+        &self,
+        job_id: &str,
+        body: &crate::types::PostJobCompensationsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Compensation>> {
         let url = self.client.url(
             &format!(
                 "/v1/jobs/{}/compensations",
@@ -196,7 +342,7 @@ impl Jobs {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,6 +24,22 @@ impl Locations {
         &self,
         company_id_or_uuid: &str,
     ) -> ClientResult<Vec<crate::types::Location>> {
+        Ok(self
+            .get_company_with_response(company_id_or_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get company locations.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/locations` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/locations",
@@ -32,7 +48,7 @@ impl Locations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,6 +89,73 @@ impl Locations {
             )
             .await
     }
+    /**
+     * Get company locations.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/locations` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pages_stream(
+        &self,
+        company_id_or_uuid: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Location>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/locations",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Location>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get company locations.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/locations` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Location>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_pages_stream(company_id_or_uuid, None))
+    }
     /**
      * Create a company location.
      *
@@ -87,6 +170,23 @@ impl Locations {
         company_id_or_uuid: &str,
         body: &crate::types::PostCompanyLocationsRequest,
     ) -> ClientResult<crate::types::Location> {
+        Ok(self
+            .post_company_with_response(company_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a company location.
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id_or_uuid}/locations` endpoint.
+     *
+     * As opposed to `post_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyLocationsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/locations",
@@ -95,7 +195,7 @@ impl Locations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -112,6 +212,19 @@ impl Locations {
      * Get a location.
      */
     pub async fn get(&self, location_id: &str) -> ClientResult<crate::types::Location> {
+        Ok(self.get_with_response(location_id).await?.body)
+    }
+    /**
+     * Get a location.
+     *
+     * This function performs a `GET` to the `/v1/locations/{location_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url(
             &format!(
                 "/v1/locations/{}",
@@ -120,7 +233,7 @@ impl Locations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -141,6 +254,20 @@ impl Locations {
         location_id: &str,
         body: &crate::types::PutLocationRequest,
     ) -> ClientResult<crate::types::Location> {
+        Ok(self.put_with_response(location_id, body).await?.body)
+    }
+    /**
+     * Update a location.
+     *
+     * This function performs a `PUT` to the `/v1/locations/{location_id}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        location_id: &str,
+        body: &crate::types::PutLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url(
             &format!(
                 "/v1/locations/{}",
@@ -149,7 +276,7 @@ impl Locations {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -22,6 +22,19 @@ impl PaySchedules {
         &self,
         company_id: &str,
     ) -> ClientResult<Vec<crate::types::PaySchedule>> {
+        Ok(self.get_company_with_response(company_id).await?.body)
+    }
+    /**
+     * Get the pay schedules for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/pay_schedules` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaySchedule>>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/pay_schedules",
@@ -30,7 +43,7 @@ impl PaySchedules {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +82,74 @@ impl PaySchedules {
             )
             .await
     }
+    /**
+     * Get the pay schedules for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/pay_schedules` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pages_stream(
+        &self,
+        company_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PaySchedule>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/pay_schedules",
+                crate::progenitor_support::encode_path(company_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PaySchedule>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the pay schedules for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/pay_schedules` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaySchedule>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_pages_stream(company_id, None))
+    }
     /**
      * Get a pay schedule.
      *
@@ -81,6 +162,23 @@ impl PaySchedules {
         company_id_or_uuid: &str,
         pay_schedule_id_or_uuid: &str,
     ) -> ClientResult<crate::types::PaySchedule> {
+        Ok(self
+            .get_company_schedule_with_response(company_id_or_uuid, pay_schedule_id_or_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get a pay schedule.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_schedules/{pay_schedule_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get_company_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_schedule_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaySchedule>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/pay_schedules/{}",
@@ -90,7 +188,7 @@ impl PaySchedules {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -114,6 +212,24 @@ impl PaySchedules {
         pay_schedule_id_or_uuid: &str,
         body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
     ) -> ClientResult<crate::types::PaySchedule> {
+        Ok(self
+            .put_company_schedule_with_response(company_id_or_uuid, pay_schedule_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a pay schedule.
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id_or_uuid}/pay_schedules/{pay_schedule_id_or_uuid}` endpoint.
+     *
+     * As opposed to `put_company_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_schedule_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaySchedule>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/pay_schedules/{}",
@@ -123,7 +239,7 @@ impl PaySchedules {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -32,6 +32,24 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::PayPeriod>> {
+        Ok(self
+            .get_company_pay_periods_with_response(company_id_or_uuid, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Get pay periods for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_periods` endpoint.
+     *
+     * As opposed to `get_company_pay_periods`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_pay_periods_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PayPeriod>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !end_date.is_empty() {
             query_args.push(("end_date".to_string(), end_date.to_string()));
@@ -49,7 +67,7 @@ impl Payroll {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -102,6 +120,92 @@ impl Payroll {
             )
             .await
     }
+    /**
+     * Get pay periods for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_periods` endpoint.
+     *
+     * As opposed to `get_all_company_pay_periods`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pay_periods_pages_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PayPeriod>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/pay_periods?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PayPeriod>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get pay periods for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_periods` endpoint.
+     *
+     * As opposed to `get_all_company_pay_periods`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_pay_periods_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PayPeriod>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_pay_periods_pages_stream(
+            company_id_or_uuid,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
     /**
      * Get all payrolls for a company.
      *
@@ -131,6 +235,34 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::PayrollData>> {
+        Ok(self
+            .get_company_with_response(
+                company_id_or_uuid,
+                processed,
+                include_off_cycle,
+                include,
+                start_date,
+                end_date,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get all payrolls for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PayrollData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !end_date.is_empty() {
             query_args.push(("end_date".to_string(), end_date.to_string()));
@@ -160,7 +292,7 @@ impl Payroll {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -230,6 +362,113 @@ impl Payroll {
             )
             .await
     }
+    /**
+     * Get all payrolls for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pages_stream(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PayrollData>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !include.is_empty() {
+            query_args.push(("include".to_string(), include.join(" ")));
+        }
+        if include_off_cycle {
+            query_args.push((
+                "include_off_cycle".to_string(),
+                include_off_cycle.to_string(),
+            ));
+        }
+        if processed {
+            query_args.push(("processed".to_string(), processed.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/payrolls?{}",
+                crate::progenitor_support::encode_path(company_id_or_uuid),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PayrollData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all payrolls for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PayrollData>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_company_pages_stream(
+            company_id_or_uuid,
+            processed,
+            include_off_cycle,
+            include,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
     /**
      * Create an Off-Cycle Payroll (Beta).
      *
@@ -244,6 +483,23 @@ impl Payroll {
         company_id_or_uuid: &str,
         body: &crate::types::PostCompanyPayrollsRequest,
     ) -> ClientResult<crate::types::PayrollData> {
+        Ok(self
+            .post_company_with_response(company_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an Off-Cycle Payroll (Beta).
+     *
+     * This function performs a `POST` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `post_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyPayrollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/payrolls",
@@ -252,7 +508,7 @@ impl Payroll {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -285,6 +541,30 @@ impl Payroll {
         include: crate::types::GetCompanyPayrollsInclude,
         show_calculation: &str,
     ) -> ClientResult<crate::types::PayrollData> {
+        Ok(self
+            .get_company_payroll_with_response(
+                company_id_or_uuid,
+                payroll_id_or_uuid,
+                include,
+                show_calculation,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get a single payroll.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}` endpoint.
+     *
+     * As opposed to `get_company_payroll`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_payroll_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        include: crate::types::GetCompanyPayrollsInclude,
+        show_calculation: &str,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include.to_string().is_empty() {
             query_args.push(("include".to_string(), include.to_string()));
@@ -303,7 +583,7 @@ impl Payroll {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -325,6 +605,24 @@ impl Payroll {
         payroll_id_or_uuid: &str,
         body: &crate::types::PutCompanyPayrollsRequest,
     ) -> ClientResult<crate::types::PayrollData> {
+        Ok(self
+            .put_company_with_response(company_id_or_uuid, payroll_id_or_uuid, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a payroll by ID.
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}` endpoint.
+     *
+     * As opposed to `put_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/payrolls/{}",
@@ -334,7 +632,7 @@ impl Payroll {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -359,6 +657,30 @@ impl Payroll {
         pay_period_end_date: &str,
         body: &crate::types::PutCompanyPayrollsRequest,
     ) -> ClientResult<crate::types::PayrollData> {
+        Ok(self
+            .put_company_pay_period_start_date_end_with_response(
+                company_id_or_uuid,
+                pay_period_start_date,
+                pay_period_end_date,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Update a payroll.
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id_or_uuid}/payrolls/{pay_period_start_date}/{pay_period_end_date}` endpoint.
+     *
+     * As opposed to `put_company_pay_period_start_date_end`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_pay_period_start_date_end_with_response(
+        &self,
+        company_id_or_uuid: &str,
+        pay_period_start_date: &str,
+        pay_period_end_date: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/payrolls/{}/{}",
@@ -369,7 +691,7 @@ impl Payroll {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -394,6 +716,22 @@ impl Payroll {
         company_id: &str,
         payroll_id: &str,
     ) -> ClientResult<()> {
+        self.put_company_calculate_with_response(company_id, payroll_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Calculate a Payroll (Beta).
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id}/payrolls/{payroll_id}/calculate` endpoint.
+     *
+     * As opposed to `put_company_calculate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_calculate_with_response(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/payrolls/{}/calculate",
@@ -403,7 +741,7 @@ impl Payroll {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -422,6 +760,22 @@ impl Payroll {
      * Submits an unprocessed payroll to be calculated and run. Upon success, transitions the payroll to the `processed` state.
      */
     pub async fn put_company_submit(&self, company_id: &str, payroll_id: &str) -> ClientResult<()> {
+        self.put_company_submit_with_response(company_id, payroll_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Submit Payroll (Beta).
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id}/payrolls/{payroll_Id}/submit` endpoint.
+     *
+     * As opposed to `put_company_submit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_submit_with_response(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/payrolls/{}/submit",
@@ -431,7 +785,7 @@ impl Payroll {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -455,6 +809,23 @@ impl Payroll {
         company_id: &str,
         payroll_id: &str,
     ) -> ClientResult<crate::types::PayrollData> {
+        Ok(self
+            .put_company_cancel_with_response(company_id, payroll_id)
+            .await?
+            .body)
+    }
+    /**
+     * Cancel a Payroll (Beta).
+     *
+     * This function performs a `PUT` to the `/v1/companies/{company_id}/payrolls/{payroll_id}/cancel` endpoint.
+     *
+     * As opposed to `put_company_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_company_cancel_with_response(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PayrollData>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/payrolls/{}/cancel",
@@ -464,7 +835,7 @@ impl Payroll {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -484,6 +855,22 @@ impl Payroll {
         &self,
         company_id_or_uuid: &str,
     ) -> ClientResult<crate::types::GetCompanyPayrollReversalsResponse> {
+        Ok(self
+            .get_company_or_reversals_with_response(company_id_or_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get approved Payroll Reversals.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payroll_reversals` endpoint.
+     *
+     * As opposed to `get_company_or_reversals`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_or_reversals_with_response(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCompanyPayrollReversalsResponse>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/payroll_reversals",
@@ -492,7 +879,7 @@ impl Payroll {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -24,6 +24,19 @@ impl Terminations {
         &self,
         employee_id: &str,
     ) -> ClientResult<Vec<crate::types::Termination>> {
+        Ok(self.get_employee_with_response(employee_id).await?.body)
+    }
+    /**
+     * Get terminations for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/terminations` endpoint.
+     *
+     * As opposed to `get_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_employee_with_response(
+        &self,
+        employee_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Termination>>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/terminations",
@@ -32,7 +45,7 @@ impl Terminations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,6 +86,74 @@ impl Terminations {
             )
             .await
     }
+    /**
+     * Get terminations for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/terminations` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_employee_pages_stream(
+        &self,
+        employee_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Termination>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/v1/employees/{}/terminations",
+                crate::progenitor_support::encode_path(employee_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Termination>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get terminations for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/terminations` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_employee_stream(
+        &self,
+        employee_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Termination>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_employee_pages_stream(employee_id, None))
+    }
     /**
      * Create an employee termination.
      *
@@ -87,6 +168,23 @@ impl Terminations {
         employee_id: &str,
         body: &crate::types::PostEmployeeTerminationsRequest,
     ) -> ClientResult<crate::types::Termination> {
+        Ok(self
+            .post_employee_with_response(employee_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create an employee termination.
+     *
+     * This function performs a `POST` to the `/v1/employees/{employee_id}/terminations` endpoint.
+     *
+     * As opposed to `post_employee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_employee_with_response(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeTerminationsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Termination>> {
         let url = self.client.url(
             &format!(
                 "/v1/employees/{}/terminations",
@@ -95,7 +193,7 @@ impl Terminations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -46,6 +46,24 @@ impl TimeOffRequests {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::TimeOffRequest>> {
+        Ok(self
+            .get_company_with_response(company_id, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_with_response(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TimeOffRequest>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !end_date.is_empty() {
             query_args.push(("end_date".to_string(), end_date.to_string()));
@@ -63,7 +81,7 @@ impl TimeOffRequests {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,6 +148,89 @@ impl TimeOffRequests {
             )
             .await
     }
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_company_pages_stream(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TimeOffRequest>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/companies/{}/time_off_requests?{}",
+                crate::progenitor_support::encode_path(company_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TimeOffRequest>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_company_stream(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TimeOffRequest>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_company_pages_stream(company_id, start_date, end_date, None),
+        )
+    }
     /**
      * Get a specific time off request.
      *
@@ -142,6 +243,23 @@ impl TimeOffRequests {
         company_id: &str,
         time_off_request_id: &str,
     ) -> ClientResult<crate::types::TimeOffRequest> {
+        Ok(self
+            .get_company_request_with_response(company_id, time_off_request_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a specific time off request.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests/{time_off_request_id}` endpoint.
+     *
+     * As opposed to `get_company_request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_company_request_with_response(
+        &self,
+        company_id: &str,
+        time_off_request_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TimeOffRequest>> {
         let url = self.client.url(
             &format!(
                 "/v1/companies/{}/time_off_requests/{}",
@@ -151,7 +269,7 @@ impl TimeOffRequests {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
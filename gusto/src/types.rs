@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Employee {
     /**
-     * The representation of an employee in Gusto.
+     * The ID of the company the employee is employed by.
      */
     #[serde(
         default,
@@ -15,7 +15,7 @@ pub struct Employee {
     )]
     pub company_id: f64,
     /**
-     * The representation of an employee in Gusto.
+     * A unique identifier of the company the employee is employed by.
      */
     #[serde(
         default,
@@ -24,7 +24,7 @@ pub struct Employee {
     )]
     pub company_uuid: String,
     /**
-     * The representation of an employee in Gusto.
+     * Custom fields are only included for the employee if the include param has the custom_fields value set
      */
     #[serde(
         default,
@@ -39,7 +39,7 @@ pub struct Employee {
     )]
     pub date_of_birth: Option<chrono::NaiveDate>,
     /**
-     * The representation of an employee in Gusto.
+     * The employee's department in the company.
      */
     #[serde(
         default,
@@ -47,9 +47,6 @@ pub struct Employee {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub department: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -57,7 +54,7 @@ pub struct Employee {
     )]
     pub eligible_paid_time_off: Vec<PaidTimeOff>,
     /**
-     * The representation of an employee in Gusto.
+     * The email address of the employee. This is provided to support syncing users between our system and yours. You may not use this email address for any other purpose (e.g. marketing).
      */
     #[serde(
         default,
@@ -65,18 +62,12 @@ pub struct Employee {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -84,7 +75,7 @@ pub struct Employee {
     )]
     pub garnishments: Vec<Garnishment>,
     /**
-     * The representation of an employee in Gusto.
+     * Indicates whether the employee has an SSN in Gusto.
      */
     #[serde(
         default,
@@ -92,12 +83,12 @@ pub struct Employee {
     )]
     pub has_ssn: bool,
     /**
-     * The representation of an employee in Gusto.
+     * The representation of an address in Gusto.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub home_address: Option<Location>,
     /**
-     * The representation of an employee in Gusto.
+     * The ID of the employee in Gusto.
      */
     #[serde(
         default,
@@ -105,18 +96,12 @@ pub struct Employee {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub id: f64,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub jobs: Vec<Job>,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -124,7 +109,7 @@ pub struct Employee {
     )]
     pub last_name: String,
     /**
-     * The representation of an employee in Gusto.
+     * The ID of the employee's manager in Gusto.
      */
     #[serde(
         default,
@@ -132,9 +117,6 @@ pub struct Employee {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub manager_id: f64,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -142,16 +124,13 @@ pub struct Employee {
     )]
     pub middle_initial: String,
     /**
-     * The representation of an employee in Gusto.
+     * Whether the employee has completed onboarding.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub onboarded: bool,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -159,7 +138,7 @@ pub struct Employee {
     )]
     pub phone: String,
     /**
-     * The representation of an employee in Gusto.
+     *
      */
     #[serde(
         default,
@@ -168,7 +147,7 @@ pub struct Employee {
     )]
     pub preferred_first_name: String,
     /**
-     * The representation of an employee in Gusto.
+     * Deprecated. This field always returns an empty string.
      */
     #[serde(
         default,
@@ -177,16 +156,13 @@ pub struct Employee {
     )]
     pub ssn: String,
     /**
-     * The representation of an employee in Gusto.
+     * Whether the employee is terminated.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub terminated: bool,
-    /**
-     * The representation of an employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -194,7 +170,7 @@ pub struct Employee {
     )]
     pub terminations: Vec<Termination>,
     /**
-     * The representation of an employee in Gusto.
+     * Whether the employee is a two percent shareholder of the company. This field only applies to companies with an S-Corp entity type.
      */
     #[serde(
         default,
@@ -202,7 +178,7 @@ pub struct Employee {
     )]
     pub two_percent_shareholder: bool,
     /**
-     * The representation of an employee in Gusto.
+     * A unique identifier of the employee in Gusto.
      */
     #[serde(
         default,
@@ -211,7 +187,7 @@ pub struct Employee {
     )]
     pub uuid: String,
     /**
-     * The representation of an employee in Gusto.
+     * The current version of the employee. See the versioning guide for details using this field.
      */
     #[serde(
         default,
@@ -220,7 +196,7 @@ pub struct Employee {
     )]
     pub version: String,
     /**
-     * The representation of an employee in Gusto.
+     * The work email address of the employee. This is provided to support syncing users between our system and yours. You may not use this email address for any other purpose (e.g. marketing).
      */
     #[serde(
         default,
@@ -234,16 +210,13 @@ pub struct Employee {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Location {
     /**
-     * The representation of an address in Gusto.
+     * The status of the location. Inactive locations have been deleted, but may still have historical data associated with them.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub active: bool,
-    /**
-     * The representation of an address in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -251,7 +224,7 @@ pub struct Location {
     )]
     pub city: String,
     /**
-     * The representation of an address in Gusto.
+     * The ID for the company to which the location belongs. Only included if the location belongs to a company.
      */
     #[serde(
         default,
@@ -259,9 +232,6 @@ pub struct Location {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub company_id: i64,
-    /**
-     * The representation of an address in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -269,7 +239,7 @@ pub struct Location {
     )]
     pub country: String,
     /**
-     * The representation of an address in Gusto.
+     * The ID for the employee to which the location belongs. Only included if the location belongs to an employee.
      */
     #[serde(
         default,
@@ -278,7 +248,7 @@ pub struct Location {
     )]
     pub employee_id: i64,
     /**
-     * The representation of an address in Gusto.
+     * Specifies if the location is the company's filing address. Only included if the location belongs to a company.
      */
     #[serde(
         default,
@@ -286,7 +256,7 @@ pub struct Location {
     )]
     pub filing_address: bool,
     /**
-     * The representation of an address in Gusto.
+     * The unique identifier of the location in Gusto.
      */
     #[serde(
         default,
@@ -295,7 +265,7 @@ pub struct Location {
     )]
     pub id: i64,
     /**
-     * The representation of an address in Gusto.
+     * Specifies if the location is the company's mailing address. Only included if the location belongs to a company.
      */
     #[serde(
         default,
@@ -303,7 +273,7 @@ pub struct Location {
     )]
     pub mailing_address: bool,
     /**
-     * The representation of an address in Gusto.
+     * The phone number for the location. Required for company locations. Optional for employee locations.
      */
     #[serde(
         default,
@@ -311,27 +281,18 @@ pub struct Location {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
-    /**
-     * The representation of an address in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * The representation of an address in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_1: String,
-    /**
-     * The representation of an address in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -339,7 +300,7 @@ pub struct Location {
     )]
     pub street_2: String,
     /**
-     * The representation of an address in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -347,9 +308,6 @@ pub struct Location {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
-    /**
-     * The representation of an address in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -362,7 +320,7 @@ pub struct Location {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PaidTimeOff {
     /**
-     * The representation of paid time off in Gusto.
+     * The number of accrual units accrued.
      */
     #[serde(
         default,
@@ -371,7 +329,7 @@ pub struct PaidTimeOff {
     )]
     pub accrual_balance: String,
     /**
-     * The representation of paid time off in Gusto.
+     * The frequency at which the PTO type is accrued.
      */
     #[serde(
         default,
@@ -380,7 +338,7 @@ pub struct PaidTimeOff {
     )]
     pub accrual_period: String,
     /**
-     * The representation of paid time off in Gusto.
+     * The number of accrual units accrued per accrual period.
      */
     #[serde(
         default,
@@ -389,7 +347,7 @@ pub struct PaidTimeOff {
     )]
     pub accrual_rate: String,
     /**
-     * The representation of paid time off in Gusto.
+     * The unit the PTO type is accrued in.
      */
     #[serde(
         default,
@@ -398,7 +356,7 @@ pub struct PaidTimeOff {
     )]
     pub accrual_unit: String,
     /**
-     * The representation of paid time off in Gusto.
+     * The maximum number of accrual units allowed. A null value signifies no maximum.
      */
     #[serde(
         default,
@@ -407,7 +365,7 @@ pub struct PaidTimeOff {
     )]
     pub maximum_accrual_balance: String,
     /**
-     * The representation of paid time off in Gusto.
+     * The name of the paid time off type.
      */
     #[serde(
         default,
@@ -416,7 +374,7 @@ pub struct PaidTimeOff {
     )]
     pub name: String,
     /**
-     * The representation of paid time off in Gusto.
+     * Whether the accrual balance is paid to the employee upon termination.
      */
     #[serde(
         default,
@@ -429,7 +387,7 @@ pub struct PaidTimeOff {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Garnishment {
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * Whether or not this garnishment is currently active.
      */
     #[serde(
         default,
@@ -437,7 +395,7 @@ pub struct Garnishment {
     )]
     pub active: bool,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
      */
     #[serde(
         default,
@@ -446,7 +404,7 @@ pub struct Garnishment {
     )]
     pub amount: f64,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The maximum deduction per annum. A null value indicates no maximum. Represented as a float, e.g. "200.00".
      */
     #[serde(
         default,
@@ -455,7 +413,7 @@ pub struct Garnishment {
     )]
     pub annual_maximum: f64,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * Whether the garnishment is court ordered.
      */
     #[serde(
         default,
@@ -463,7 +421,7 @@ pub struct Garnishment {
     )]
     pub court_ordered: bool,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * Whether the amount should be treated as a percentage to be deducted per pay period.
      */
     #[serde(
         default,
@@ -471,7 +429,7 @@ pub struct Garnishment {
     )]
     pub deduct_as_percentage: bool,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The description of the garnishment.
      */
     #[serde(
         default,
@@ -480,7 +438,7 @@ pub struct Garnishment {
     )]
     pub description: String,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The ID of the employee to which this garnishment belongs.
      */
     #[serde(
         default,
@@ -489,7 +447,7 @@ pub struct Garnishment {
     )]
     pub employee_id: i64,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The unique identifier of the garnishment in Gusto.
      */
     #[serde(
         default,
@@ -498,7 +456,7 @@ pub struct Garnishment {
     )]
     pub id: i64,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The maximum deduction per pay period. A null value indicates no maximum. Represented as a float, e.g. "16.00".
      */
     #[serde(
         default,
@@ -507,7 +465,7 @@ pub struct Garnishment {
     )]
     pub pay_period_maximum: f64,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * Whether the garnishment should recur indefinitely.
      */
     #[serde(
         default,
@@ -515,7 +473,7 @@ pub struct Garnishment {
     )]
     pub recurring: bool,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The number of times to apply the garnisment. Ignored if recurring is true.
      */
     #[serde(
         default,
@@ -524,7 +482,7 @@ pub struct Garnishment {
     )]
     pub times: i64,
     /**
-     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -538,13 +496,16 @@ pub struct Garnishment {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Termination {
     /**
-     * The representation of a termination in Gusto.
+     * Whether the employee's termination has gone into effect.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub active: bool,
+    /**
+     * The employee's last day of work.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -552,7 +513,7 @@ pub struct Termination {
     )]
     pub effective_date: Option<chrono::NaiveDate>,
     /**
-     * The representation of a termination in Gusto.
+     * The ID of the employee to which this termination is attached.
      */
     #[serde(
         default,
@@ -561,7 +522,7 @@ pub struct Termination {
     )]
     pub employee_id: i64,
     /**
-     * The representation of a termination in Gusto.
+     * The unique identifier of the termination in Gusto.
      */
     #[serde(
         default,
@@ -570,7 +531,7 @@ pub struct Termination {
     )]
     pub id: i64,
     /**
-     * The representation of a termination in Gusto.
+     * If true, the employee should recieve their final wages via an offcycle payroll. If false, they should recieve their final wages on their current pay schedule.
      */
     #[serde(
         default,
@@ -578,7 +539,7 @@ pub struct Termination {
     )]
     pub run_termination_payroll: bool,
     /**
-     * The representation of a termination in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -592,6 +553,7 @@ pub struct Termination {
 * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PaymentUnit {
     #[serde(rename = "Hour")]
     Hour,
@@ -605,8 +567,9 @@ pub enum PaymentUnit {
     Year,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentUnit {
@@ -618,12 +581,35 @@ impl std::fmt::Display for PaymentUnit {
             PaymentUnit::Week => "Week",
             PaymentUnit::Year => "Year",
             PaymentUnit::Noop => "",
-            PaymentUnit::FallthroughString => "*",
+            PaymentUnit::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PaymentUnit {
+    fn from(s: String) -> PaymentUnit {
+        match s.as_str() {
+            "Hour" => PaymentUnit::Hour,
+            "Month" => PaymentUnit::Month,
+            "Paycheck" => PaymentUnit::Paycheck,
+            "Week" => PaymentUnit::Week,
+            "Year" => PaymentUnit::Year,
+            "" => PaymentUnit::Noop,
+            _ => PaymentUnit::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PaymentUnit> for String {
+    fn from(e: PaymentUnit) -> String {
+        match e {
+            PaymentUnit::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PaymentUnit {
     fn default() -> PaymentUnit {
         PaymentUnit::Noop
@@ -639,6 +625,7 @@ impl PaymentUnit {
 * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FlsaStatus {
     #[serde(rename = "Exempt")]
     Exempt,
@@ -650,8 +637,9 @@ pub enum FlsaStatus {
     SalariedNonexempt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FlsaStatus {
@@ -662,12 +650,34 @@ impl std::fmt::Display for FlsaStatus {
             FlsaStatus::Owner => "Owner",
             FlsaStatus::SalariedNonexempt => "Salaried Nonexempt",
             FlsaStatus::Noop => "",
-            FlsaStatus::FallthroughString => "*",
+            FlsaStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FlsaStatus {
+    fn from(s: String) -> FlsaStatus {
+        match s.as_str() {
+            "Exempt" => FlsaStatus::Exempt,
+            "Nonexempt" => FlsaStatus::Nonexempt,
+            "Owner" => FlsaStatus::Owner,
+            "Salaried Nonexempt" => FlsaStatus::SalariedNonexempt,
+            "" => FlsaStatus::Noop,
+            _ => FlsaStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FlsaStatus> for String {
+    fn from(e: FlsaStatus) -> String {
+        match e {
+            FlsaStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FlsaStatus {
     fn default() -> FlsaStatus {
         FlsaStatus::Noop
@@ -682,6 +692,9 @@ impl FlsaStatus {
 /// The representation of compensation in Gusto.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Compensation {
+    /**
+     * The effective date for this compensation. For the first compensation, this defaults to the job's hire date.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -689,12 +702,12 @@ pub struct Compensation {
     )]
     pub effective_date: Option<chrono::NaiveDate>,
     /**
-     * The representation of compensation in Gusto.
+     * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub flsa_status: Option<FlsaStatus>,
     /**
-     * The representation of compensation in Gusto.
+     * The unique identifier of the compensation in Gusto.
      */
     #[serde(
         default,
@@ -703,7 +716,7 @@ pub struct Compensation {
     )]
     pub id: i64,
     /**
-     * The representation of compensation in Gusto.
+     * The ID of the job to which the compensation belongs.
      */
     #[serde(
         default,
@@ -712,12 +725,12 @@ pub struct Compensation {
     )]
     pub job_id: i64,
     /**
-     * The representation of compensation in Gusto.
+     * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_unit: Option<PaymentUnit>,
     /**
-     * The representation of compensation in Gusto.
+     * The dollar amount paid per payment unit.
      */
     #[serde(
         default,
@@ -726,7 +739,7 @@ pub struct Compensation {
     )]
     pub rate: String,
     /**
-     * The representation of compensation in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -738,27 +751,18 @@ pub struct Compensation {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct JobLocation {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    /**
-     * The ID of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -766,43 +770,31 @@ pub struct JobLocation {
     )]
     pub id: f64,
     /**
-     * Whether the employee is terminated.
+     * Whether the location of the job is active.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub inactive: bool,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_1: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_2: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -814,9 +806,6 @@ pub struct JobLocation {
 /// The representation of a job in Gusto.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Job {
-    /**
-     * The representation of a job in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -824,7 +813,7 @@ pub struct Job {
     )]
     pub compensations: Vec<Compensation>,
     /**
-     * The representation of a job in Gusto.
+     * The ID for the current compensation of the job.
      */
     #[serde(
         default,
@@ -833,7 +822,7 @@ pub struct Job {
     )]
     pub current_compensation_id: i64,
     /**
-     * The representation of a job in Gusto.
+     * The ID of the employee to which the job belongs.
      */
     #[serde(
         default,
@@ -841,6 +830,9 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub employee_id: i64,
+    /**
+     * The date when the employee was hired for the job.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -848,7 +840,7 @@ pub struct Job {
     )]
     pub hire_date: Option<chrono::NaiveDate>,
     /**
-     * The representation of a job in Gusto.
+     * The unique identifier of the job in Gusto.
      */
     #[serde(
         default,
@@ -856,13 +848,10 @@ pub struct Job {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * The representation of a job in Gusto.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<JobLocation>,
     /**
-     * The representation of a job in Gusto.
+     * The ID of the job's work location.
      */
     #[serde(
         default,
@@ -871,7 +860,7 @@ pub struct Job {
     )]
     pub location_id: i64,
     /**
-     * The representation of a job in Gusto.
+     * The payment unit of the current compensation for the job.
      */
     #[serde(
         default,
@@ -880,7 +869,7 @@ pub struct Job {
     )]
     pub payment_unit: String,
     /**
-     * The representation of a job in Gusto.
+     * Whether this is the employee’s primary job. The value will be set to true unless an existing job exists for the employee.
      */
     #[serde(
         default,
@@ -888,7 +877,7 @@ pub struct Job {
     )]
     pub primary: bool,
     /**
-     * The representation of a job in Gusto.
+     * The current compensation rate of the job.
      */
     #[serde(
         default,
@@ -897,7 +886,7 @@ pub struct Job {
     )]
     pub rate: String,
     /**
-     * The representation of a job in Gusto.
+     * The title for the job.
      */
     #[serde(
         default,
@@ -906,7 +895,7 @@ pub struct Job {
     )]
     pub title: String,
     /**
-     * The representation of a job in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -920,7 +909,7 @@ pub struct Job {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Admin {
     /**
-     * The representation of an admin user in Gusto.
+     * The email of the admin. This is used by the admin to log in to their account.
      */
     #[serde(
         default,
@@ -929,7 +918,7 @@ pub struct Admin {
     )]
     pub email: String,
     /**
-     * The representation of an admin user in Gusto.
+     * The first name of the admin.
      */
     #[serde(
         default,
@@ -938,7 +927,7 @@ pub struct Admin {
     )]
     pub first_name: String,
     /**
-     * The representation of an admin user in Gusto.
+     * The last name of the admin.
      */
     #[serde(
         default,
@@ -952,6 +941,7 @@ pub struct Admin {
 * The tax payer type of the company.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EntityType {
     #[serde(rename = "Association")]
     Association,
@@ -979,8 +969,9 @@ pub enum EntityType {
     Trusteeship,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntityType {
@@ -999,12 +990,42 @@ impl std::fmt::Display for EntityType {
             EntityType::SoleProprietor => "Sole proprietor",
             EntityType::Trusteeship => "Trusteeship",
             EntityType::Noop => "",
-            EntityType::FallthroughString => "*",
+            EntityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EntityType {
+    fn from(s: String) -> EntityType {
+        match s.as_str() {
+            "Association" => EntityType::Association,
+            "C-Corporation" => EntityType::CCorporation,
+            "Co-ownership" => EntityType::CoOwnership,
+            "General partnership" => EntityType::GeneralPartnership,
+            "Joint venture" => EntityType::JointVenture,
+            "LLC" => EntityType::Llc,
+            "LLP" => EntityType::Llp,
+            "Limited partnership" => EntityType::LimitedPartnership,
+            "Non-Profit" => EntityType::NonProfit,
+            "S-Corporation" => EntityType::SCorporation,
+            "Sole proprietor" => EntityType::SoleProprietor,
+            "Trusteeship" => EntityType::Trusteeship,
+            "" => EntityType::Noop,
+            _ => EntityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EntityType> for String {
+    fn from(e: EntityType) -> String {
+        match e {
+            EntityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EntityType {
     fn default() -> EntityType {
         EntityType::Noop
@@ -1020,6 +1041,7 @@ impl EntityType {
 * The Gusto product tier of the company.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Tier {
     #[serde(rename = "basic")]
     Basic,
@@ -1033,8 +1055,9 @@ pub enum Tier {
     Core,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Tier {
@@ -1046,12 +1069,35 @@ impl std::fmt::Display for Tier {
             Tier::ContractorOnly => "contractor_only",
             Tier::Core => "core",
             Tier::Noop => "",
-            Tier::FallthroughString => "*",
+            Tier::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Tier {
+    fn from(s: String) -> Tier {
+        match s.as_str() {
+            "basic" => Tier::Basic,
+            "complete" => Tier::Complete,
+            "concierge" => Tier::Concierge,
+            "contractor_only" => Tier::ContractorOnly,
+            "core" => Tier::Core,
+            "" => Tier::Noop,
+            _ => Tier::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Tier> for String {
+    fn from(e: Tier) -> String {
+        match e {
+            Tier::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Tier {
     fn default() -> Tier {
         Tier::Noop
@@ -1067,6 +1113,7 @@ impl Tier {
 * The status of the company in Gusto. "Approved" companies may run payroll with Gusto. "Not Approved" companies may not yet run payroll with Gusto. In order to run payroll, the company may need to complete onboarding or contact support. "Suspended" companies may not run payroll with Gusto. In order to unsuspend their account, the company must contact support.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CompanyStatus {
     #[serde(rename = "Approved")]
     Approved,
@@ -1076,8 +1123,9 @@ pub enum CompanyStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CompanyStatus {
@@ -1087,12 +1135,33 @@ impl std::fmt::Display for CompanyStatus {
             CompanyStatus::NotApproved => "Not Approved",
             CompanyStatus::Suspended => "Suspended",
             CompanyStatus::Noop => "",
-            CompanyStatus::FallthroughString => "*",
+            CompanyStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CompanyStatus {
+    fn from(s: String) -> CompanyStatus {
+        match s.as_str() {
+            "Approved" => CompanyStatus::Approved,
+            "Not Approved" => CompanyStatus::NotApproved,
+            "Suspended" => CompanyStatus::Suspended,
+            "" => CompanyStatus::Noop,
+            _ => CompanyStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CompanyStatus> for String {
+    fn from(e: CompanyStatus) -> String {
+        match e {
+            CompanyStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CompanyStatus {
     fn default() -> CompanyStatus {
         CompanyStatus::Noop
@@ -1106,12 +1175,18 @@ impl CompanyStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Hourly {
+    /**
+     * The amount multiplied by the base rate of a job to calculate compensation.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub multiple: f64,
+    /**
+     * The name of the hourly compensation rate.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1122,6 +1197,9 @@ pub struct Hourly {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Fixed {
+    /**
+     * The name of the fixed compensation.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1134,7 +1212,7 @@ pub struct Fixed {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Compensations {
     /**
-     * The available company-wide compensation rates for the company.
+     * The available fixed compensation rates for the company.
      */
     #[serde(
         default,
@@ -1143,7 +1221,7 @@ pub struct Compensations {
     )]
     pub fixed: Vec<Fixed>,
     /**
-     * The available company-wide compensation rates for the company.
+     * The available hourly compensation rates for the company.
      */
     #[serde(
         default,
@@ -1152,7 +1230,7 @@ pub struct Compensations {
     )]
     pub hourly: Vec<Hourly>,
     /**
-     * The available company-wide compensation rates for the company.
+     * The available types of paid time off for the company.
      */
     #[serde(
         default,
@@ -1205,50 +1283,32 @@ pub struct HomeAddress {
 /// The primary signatory of the company.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PrimarySignatory {
-    /**
-     * The primary signatory of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * The primary signatory of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The primary signatory of the company.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub home_address: Option<HomeAddress>,
-    /**
-     * The primary signatory of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The primary signatory of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub middle_initial: String,
-    /**
-     * The primary signatory of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1260,36 +1320,24 @@ pub struct PrimarySignatory {
 /// The primary payroll admin of the company.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PrimaryPayrollAdmin {
-    /**
-     * The primary payroll admin of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * The primary payroll admin of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The primary payroll admin of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The primary payroll admin of the company.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1302,17 +1350,17 @@ pub struct PrimaryPayrollAdmin {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Company {
     /**
-     * The representation of a company in Gusto.
+     * The status of the company in Gusto. "Approved" companies may run payroll with Gusto. "Not Approved" companies may not yet run payroll with Gusto. In order to run payroll, the company may need to complete onboarding or contact support. "Suspended" companies may not run payroll with Gusto. In order to unsuspend their account, the company must contact support.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub company_status: Option<CompanyStatus>,
     /**
-     * The representation of a company in Gusto.
+     * The available company-wide compensation rates for the company.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub compensations: Option<Compensations>,
     /**
-     * The representation of a company in Gusto.
+     * The Federal Employer Identification Number of the company.
      */
     #[serde(
         default,
@@ -1321,12 +1369,12 @@ pub struct Company {
     )]
     pub ein: String,
     /**
-     * The representation of a company in Gusto.
+     * The tax payer type of the company.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub entity_type: Option<EntityType>,
     /**
-     * The representation of a company in Gusto.
+     * The unique identifier of the company in Gusto.
      */
     #[serde(
         default,
@@ -1335,7 +1383,7 @@ pub struct Company {
     )]
     pub id: f64,
     /**
-     * The representation of a company in Gusto.
+     * Whether or not the company is suspended in Gusto. Suspended companies may not run payroll.
      */
     #[serde(
         default,
@@ -1343,7 +1391,7 @@ pub struct Company {
     )]
     pub is_suspended: bool,
     /**
-     * The representation of a company in Gusto.
+     * The locations of the company.
      */
     #[serde(
         default,
@@ -1352,7 +1400,7 @@ pub struct Company {
     )]
     pub locations: Vec<Location>,
     /**
-     * The representation of a company in Gusto.
+     * The name of the company.
      */
     #[serde(
         default,
@@ -1361,12 +1409,12 @@ pub struct Company {
     )]
     pub name: String,
     /**
-     * The representation of a company in Gusto.
+     * The primary payroll admin of the company.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary_payroll_admin: Option<PrimaryPayrollAdmin>,
     /**
-     * The representation of a company in Gusto.
+     * The primary signatory of the company.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary_signatory: Option<PrimarySignatory>,
@@ -1376,7 +1424,7 @@ pub struct Company {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tier: Option<Tier>,
     /**
-     * The representation of a company in Gusto.
+     * The trade name of the company.
      */
     #[serde(
         default,
@@ -1385,7 +1433,7 @@ pub struct Company {
     )]
     pub trade_name: String,
     /**
-     * The representation of a company in Gusto.
+     * A unique identifier of the company in Gusto.
      */
     #[serde(
         default,
@@ -1399,6 +1447,7 @@ pub struct Company {
 * The contractor's wage type, either "Fixed" or "Hourly".
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WageType {
     #[serde(rename = "Fixed")]
     Fixed,
@@ -1406,8 +1455,9 @@ pub enum WageType {
     Hourly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WageType {
@@ -1416,12 +1466,32 @@ impl std::fmt::Display for WageType {
             WageType::Fixed => "Fixed",
             WageType::Hourly => "Hourly",
             WageType::Noop => "",
-            WageType::FallthroughString => "*",
+            WageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WageType {
+    fn from(s: String) -> WageType {
+        match s.as_str() {
+            "Fixed" => WageType::Fixed,
+            "Hourly" => WageType::Hourly,
+            "" => WageType::Noop,
+            _ => WageType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WageType> for String {
+    fn from(e: WageType) -> String {
+        match e {
+            WageType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WageType {
     fn default() -> WageType {
         WageType::Noop
@@ -1437,6 +1507,7 @@ impl WageType {
 * The contractor's type, either "Individual" or "Business".
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "Business")]
     Business,
@@ -1444,8 +1515,9 @@ pub enum Type {
     Individual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -1454,12 +1526,32 @@ impl std::fmt::Display for Type {
             Type::Business => "Business",
             Type::Individual => "Individual",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "Business" => Type::Business,
+            "Individual" => Type::Individual,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -1474,54 +1566,36 @@ impl Type {
 /// The contractor’s home address.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Address {
-    /**
-     * The contractor’s home address.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     * The contractor’s home address.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    /**
-     * The contractor’s home address.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * The contractor’s home address.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_1: String,
-    /**
-     * The contractor’s home address.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_2: String,
-    /**
-     * The contractor’s home address.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1534,12 +1608,12 @@ pub struct Address {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Contractor {
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor’s home address.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub address: Option<Address>,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The name of the contractor business. This attribute is required for “Business” contractors and will be ignored for “Individual” contractors.
      */
     #[serde(
         default,
@@ -1548,7 +1622,7 @@ pub struct Contractor {
     )]
     pub business_name: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The ID of the company the contractor is employed by.
      */
     #[serde(
         default,
@@ -1557,7 +1631,7 @@ pub struct Contractor {
     )]
     pub company_id: f64,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The Federal Employer Identification Number of the contractor business. This attribute is optional for “Business” contractors and will be ignored for “Individual” contractors.
      */
     #[serde(
         default,
@@ -1566,7 +1640,7 @@ pub struct Contractor {
     )]
     pub ein: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor’s email address. This attribute is optional for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -1575,7 +1649,7 @@ pub struct Contractor {
     )]
     pub email: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor’s first name. This attribute is required for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -1584,7 +1658,7 @@ pub struct Contractor {
     )]
     pub first_name: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor’s hourly rate. This attribute is required if the wage_type is “Hourly”.
      */
     #[serde(
         default,
@@ -1593,7 +1667,7 @@ pub struct Contractor {
     )]
     pub hourly_rate: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The unique identifier of the contractor in Gusto.
      */
     #[serde(
         default,
@@ -1602,7 +1676,7 @@ pub struct Contractor {
     )]
     pub id: f64,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The status of the contractor with the company.
      */
     #[serde(
         default,
@@ -1610,7 +1684,7 @@ pub struct Contractor {
     )]
     pub is_active: bool,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor’s last name. This attribute is required for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -1619,7 +1693,7 @@ pub struct Contractor {
     )]
     pub last_name: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor’s middle initial. This attribute is optional for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -1628,12 +1702,12 @@ pub struct Contractor {
     )]
     pub middle_initial: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor's type, either "Individual" or "Business".
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -1642,7 +1716,7 @@ pub struct Contractor {
     )]
     pub version: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The contractor's wage type, either "Fixed" or "Hourly".
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wage_type: Option<WageType>,
@@ -1652,6 +1726,7 @@ pub struct Contractor {
 * The payment method.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -1663,8 +1738,9 @@ pub enum PaymentMethod {
     HistoricalPayment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentMethod {
@@ -1675,12 +1751,34 @@ impl std::fmt::Display for PaymentMethod {
             PaymentMethod::DirectDeposit => "Direct Deposit",
             PaymentMethod::HistoricalPayment => "Historical Payment",
             PaymentMethod::Noop => "",
-            PaymentMethod::FallthroughString => "*",
+            PaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PaymentMethod {
+    fn from(s: String) -> PaymentMethod {
+        match s.as_str() {
+            "Check" => PaymentMethod::Check,
+            "Correction Payment" => PaymentMethod::CorrectionPayment,
+            "Direct Deposit" => PaymentMethod::DirectDeposit,
+            "Historical Payment" => PaymentMethod::HistoricalPayment,
+            "" => PaymentMethod::Noop,
+            _ => PaymentMethod::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PaymentMethod> for String {
+    fn from(e: PaymentMethod) -> String {
+        match e {
+            PaymentMethod::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PaymentMethod {
     fn default() -> PaymentMethod {
         PaymentMethod::Noop
@@ -1696,7 +1794,7 @@ impl PaymentMethod {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContractorPayment {
     /**
-     * The representation of a single contractor payment.
+     * The bonus amount in the payment.
      */
     #[serde(
         default,
@@ -1705,7 +1803,7 @@ pub struct ContractorPayment {
     )]
     pub bonus: String,
     /**
-     * The representation of a single contractor payment.
+     * The unique identifier of the contractor in Gusto.
      */
     #[serde(
         default,
@@ -1714,7 +1812,7 @@ pub struct ContractorPayment {
     )]
     pub contractor_id: f64,
     /**
-     * The representation of a single contractor payment.
+     * The payment date.
      */
     #[serde(
         default,
@@ -1723,7 +1821,7 @@ pub struct ContractorPayment {
     )]
     pub date: String,
     /**
-     * The representation of a single contractor payment.
+     * The rate per hour worked for the payment.
      */
     #[serde(
         default,
@@ -1732,7 +1830,7 @@ pub struct ContractorPayment {
     )]
     pub hourly_rate: String,
     /**
-     * The representation of a single contractor payment.
+     * The number of hours worked for the payment.
      */
     #[serde(
         default,
@@ -1741,12 +1839,12 @@ pub struct ContractorPayment {
     )]
     pub hours: String,
     /**
-     * The representation of a single contractor payment.
+     * The payment method.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_method: Option<PaymentMethod>,
     /**
-     * The representation of a single contractor payment.
+     * The reimbursement amount in the payment.
      */
     #[serde(
         default,
@@ -1755,7 +1853,7 @@ pub struct ContractorPayment {
     )]
     pub reimbursement: String,
     /**
-     * The representation of a single contractor payment.
+     * The unique identifier of the contractor payment in Gusto.
      */
     #[serde(
         default,
@@ -1764,7 +1862,7 @@ pub struct ContractorPayment {
     )]
     pub uuid: String,
     /**
-     * The representation of a single contractor payment.
+     * The fixed wage of the payment, regardless of hours worked.
      */
     #[serde(
         default,
@@ -1773,7 +1871,7 @@ pub struct ContractorPayment {
     )]
     pub wage: String,
     /**
-     * The representation of a single contractor payment.
+     * (hours \* hourly_rate) + wage + bonus
      */
     #[serde(
         default,
@@ -1782,7 +1880,7 @@ pub struct ContractorPayment {
     )]
     pub wage_total: String,
     /**
-     * The representation of a single contractor payment.
+     * The wage type for the payment.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wage_type: Option<WageType>,
@@ -1792,7 +1890,7 @@ pub struct ContractorPayment {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Total {
     /**
-     * The wage and reimbursement totals for all contractor payments within a given time period.
+     * The total reimbursements for contractor payments within a given time period.
      */
     #[serde(
         default,
@@ -1801,7 +1899,7 @@ pub struct Total {
     )]
     pub reimbursements: String,
     /**
-     * The wage and reimbursement totals for all contractor payments within a given time period.
+     * The total wages for contractor payments within a given time period.
      */
     #[serde(
         default,
@@ -1815,7 +1913,7 @@ pub struct Total {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContractorPayments {
     /**
-     *
+     * The ID of the contractor.
      */
     #[serde(
         default,
@@ -1824,6 +1922,7 @@ pub struct ContractorPayments {
     )]
     pub contractor_id: f64,
     /**
+     * The contractor’s payments within a given time period.
      *
      */
     #[serde(
@@ -1833,7 +1932,7 @@ pub struct ContractorPayments {
     )]
     pub payments: Vec<ContractorPayment>,
     /**
-     *
+     * The total remibursements for the contractor within a given time period.
      */
     #[serde(
         default,
@@ -1842,7 +1941,7 @@ pub struct ContractorPayments {
     )]
     pub reimbursement_total: String,
     /**
-     *
+     * The total wages for the contractor within a given time period.
      */
     #[serde(
         default,
@@ -1856,7 +1955,7 @@ pub struct ContractorPayments {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContractorPaymentSummary {
     /**
-     * The representation of the summary of contractor payments for a given company in a given time period.
+     * The individual contractor payments, within a given time period, grouped by contractor.
      */
     #[serde(
         default,
@@ -1865,7 +1964,7 @@ pub struct ContractorPaymentSummary {
     )]
     pub contractor_payments: Vec<ContractorPayments>,
     /**
-     * The representation of the summary of contractor payments for a given company in a given time period.
+     * The wage and reimbursement totals for all contractor payments within a given time period.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total: Option<Total>,
@@ -1875,6 +1974,7 @@ pub struct ContractorPaymentSummary {
 * The status of the time off request.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "approved")]
     Approved,
@@ -1884,8 +1984,9 @@ pub enum Status {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1895,12 +1996,33 @@ impl std::fmt::Display for Status {
             Status::Denied => "denied",
             Status::Pending => "pending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "approved" => Status::Approved,
+            "denied" => Status::Denied,
+            "pending" => Status::Pending,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1916,6 +2038,7 @@ impl Status {
 * The type of time off request.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RequestType {
     #[serde(rename = "sick")]
     Sick,
@@ -1923,8 +2046,9 @@ pub enum RequestType {
     Vacation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequestType {
@@ -1933,12 +2057,32 @@ impl std::fmt::Display for RequestType {
             RequestType::Sick => "sick",
             RequestType::Vacation => "vacation",
             RequestType::Noop => "",
-            RequestType::FallthroughString => "*",
+            RequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RequestType {
+    fn from(s: String) -> RequestType {
+        match s.as_str() {
+            "sick" => RequestType::Sick,
+            "vacation" => RequestType::Vacation,
+            "" => RequestType::Noop,
+            _ => RequestType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RequestType> for String {
+    fn from(e: RequestType) -> String {
+        match e {
+            RequestType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RequestType {
     fn default() -> RequestType {
         RequestType::Noop
@@ -1958,7 +2102,7 @@ pub struct Days {}
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimeOffRequestEmployee {
     /**
-     *
+     * The full name of the employee the time off request is for.
      */
     #[serde(
         default,
@@ -1967,7 +2111,7 @@ pub struct TimeOffRequestEmployee {
     )]
     pub full_name: String,
     /**
-     *
+     * The ID of the employee the time off request is for.
      */
     #[serde(
         default,
@@ -1981,7 +2125,7 @@ pub struct TimeOffRequestEmployee {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Initiator {
     /**
-     *
+     * The full name of the employee who initiated the time off request.
      */
     #[serde(
         default,
@@ -1990,7 +2134,7 @@ pub struct Initiator {
     )]
     pub full_name: String,
     /**
-     *
+     * The ID of the employee who initiated the time off request.
      */
     #[serde(
         default,
@@ -2004,7 +2148,7 @@ pub struct Initiator {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Approver {
     /**
-     * This value will be null if the request has not been approved.
+     * The full name of the employee who approved the time off request.
      */
     #[serde(
         default,
@@ -2013,7 +2157,7 @@ pub struct Approver {
     )]
     pub full_name: String,
     /**
-     * This value will be null if the request has not been approved.
+     * The ID of the employee who approved the time off request.
      */
     #[serde(
         default,
@@ -2032,17 +2176,17 @@ pub struct TimeOffRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub approver: Option<Approver>,
     /**
-     * The representation of a time off request.
+     * An object that represents the days in the time off request. The keys of the object are the dates, formatted as a YYYY-MM-DD string. The values of the object are the number of hours requested off for each day, formatted as a string representation of a numeric decimal to the thousands place.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub days: Option<Days>,
     /**
-     * The representation of a time off request.
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub employee: Option<TimeOffRequestEmployee>,
     /**
-     * The representation of a time off request.
+     * A note about the time off request, from the employee to the employer.
      */
     #[serde(
         default,
@@ -2051,7 +2195,7 @@ pub struct TimeOffRequest {
     )]
     pub employee_note: String,
     /**
-     * The representation of a time off request.
+     * A note about the time off request, from the employer to the employee.
      */
     #[serde(
         default,
@@ -2060,7 +2204,7 @@ pub struct TimeOffRequest {
     )]
     pub employer_note: String,
     /**
-     * The representation of a time off request.
+     * The ID of the time off request.
      */
     #[serde(
         default,
@@ -2074,12 +2218,12 @@ pub struct TimeOffRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub initiator: Option<Initiator>,
     /**
-     * The representation of a time off request.
+     * The type of time off request.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub request_type: Option<RequestType>,
     /**
-     * The representation of a time off request.
+     * The status of the time off request.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
@@ -2087,24 +2231,36 @@ pub struct TimeOffRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Companies {
+    /**
+     * The ID of the company.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub id: f64,
+    /**
+     * A list of the company locations.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub locations: Vec<Location>,
+    /**
+     * The name of the company.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The trade name of the company.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2115,6 +2271,9 @@ pub struct Companies {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PayrollAdmin {
+    /**
+     * A lists of companies for which the current user has admin permissions. Users (most notably accountants) can have priviliges with multiple companies.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2126,9 +2285,6 @@ pub struct PayrollAdmin {
 /// An object containing each of the user's permissions.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Roles {
-    /**
-     * An object containing each of the user's permissions.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payroll_admin: Option<PayrollAdmin>,
 }
@@ -2137,7 +2293,7 @@ pub struct Roles {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CurrentUser {
     /**
-     *
+     * The email address of the authenticated user.
      */
     #[serde(
         default,
@@ -2146,7 +2302,7 @@ pub struct CurrentUser {
     )]
     pub email: String,
     /**
-     *
+     * The ID of the current user.
      */
     #[serde(
         default,
@@ -2155,13 +2311,14 @@ pub struct CurrentUser {
     )]
     pub id: f64,
     /**
-     *
+     * An object containing each of the user's permissions.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub roles: Option<Roles>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Frequency {
     #[serde(rename = "Every other week")]
     EveryOtherWeek,
@@ -2173,8 +2330,9 @@ pub enum Frequency {
     TwicePerMonth,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -2185,12 +2343,34 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "Monthly",
             Frequency::TwicePerMonth => "Twice per month",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Frequency {
+    fn from(s: String) -> Frequency {
+        match s.as_str() {
+            "Every other week" => Frequency::EveryOtherWeek,
+            "Every week" => Frequency::EveryWeek,
+            "Monthly" => Frequency::Monthly,
+            "Twice per month" => Frequency::TwicePerMonth,
+            "" => Frequency::Noop,
+            _ => Frequency::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Frequency> for String {
+    fn from(e: Frequency) -> String {
+        match e {
+            Frequency::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Frequency {
     fn default() -> Frequency {
         Frequency::Noop
@@ -2205,6 +2385,9 @@ impl Frequency {
 /// The representation of a pay schedule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PaySchedule {
+    /**
+     * The first date that employees on this pay schedule are paid with Gusto.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -2212,7 +2395,7 @@ pub struct PaySchedule {
     )]
     pub anchor_pay_date: Option<chrono::NaiveDate>,
     /**
-     * The representation of a pay schedule.
+     * With Autopilot® enabled, payroll will run automatically one day before your payroll deadlines.
      */
     #[serde(
         default,
@@ -2220,7 +2403,7 @@ pub struct PaySchedule {
     )]
     pub auto_pilot: bool,
     /**
-     * The representation of a pay schedule.
+     * An integer between 1 and 31 indicating the first day of the month that employees are paid. This field is only relevant for pay schedules with the “Twice per month” and “Monthly” frequencies. It will be null for pay schedules with other frequencies.
      */
     #[serde(
         default,
@@ -2229,7 +2412,7 @@ pub struct PaySchedule {
     )]
     pub day_1: i64,
     /**
-     * The representation of a pay schedule.
+     * An integer between 1 and 31 indicating the second day of the month that employees are paid. This field is the second pay date for pay schedules with the “Twice per month” frequency. It will be null for pay schedules with other frequencies.
      */
     #[serde(
         default,
@@ -2237,13 +2420,10 @@ pub struct PaySchedule {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub day_2: i64,
-    /**
-     * The representation of a pay schedule.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub frequency: Option<Frequency>,
     /**
-     * The representation of a pay schedule.
+     * The identifier of the pay schedule.
      */
     #[serde(
         default,
@@ -2252,7 +2432,7 @@ pub struct PaySchedule {
     )]
     pub id: i64,
     /**
-     * The representation of a pay schedule.
+     * Hourly when the pay schedule is for hourly employees. Salaried when the pay schedule is for salaried employees. It will be null when the pay schedule is for all employees.
      */
     #[serde(
         default,
@@ -2261,7 +2441,7 @@ pub struct PaySchedule {
     )]
     pub name: String,
     /**
-     * The representation of a pay schedule.
+     * The unique identifier of the pay schedule in Gusto.
      */
     #[serde(
         default,
@@ -2275,6 +2455,7 @@ pub struct PaySchedule {
 * Bank account type
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountType {
     #[serde(rename = "Checking")]
     Checking,
@@ -2282,8 +2463,9 @@ pub enum AccountType {
     Savings,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountType {
@@ -2292,12 +2474,32 @@ impl std::fmt::Display for AccountType {
             AccountType::Checking => "Checking",
             AccountType::Savings => "Savings",
             AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AccountType {
+    fn from(s: String) -> AccountType {
+        match s.as_str() {
+            "Checking" => AccountType::Checking,
+            "Savings" => AccountType::Savings,
+            "" => AccountType::Noop,
+            _ => AccountType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AccountType> for String {
+    fn from(e: AccountType) -> String {
+        match e {
+            AccountType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AccountType {
     fn default() -> AccountType {
         AccountType::Noop
@@ -2317,6 +2519,7 @@ impl AccountType {
 *   'verified' means the bank account is verified.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VerificationStatus {
     #[serde(rename = "awaiting_deposits")]
     AwaitingDeposits,
@@ -2326,8 +2529,9 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2337,12 +2541,33 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::ReadyForVerification => "ready_for_verification",
             VerificationStatus::Verified => "verified",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for VerificationStatus {
+    fn from(s: String) -> VerificationStatus {
+        match s.as_str() {
+            "awaiting_deposits" => VerificationStatus::AwaitingDeposits,
+            "ready_for_verification" => VerificationStatus::ReadyForVerification,
+            "verified" => VerificationStatus::Verified,
+            "" => VerificationStatus::Noop,
+            _ => VerificationStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<VerificationStatus> for String {
+    fn from(e: VerificationStatus) -> String {
+        match e {
+            VerificationStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for VerificationStatus {
     fn default() -> VerificationStatus {
         VerificationStatus::Noop
@@ -2358,12 +2583,12 @@ impl VerificationStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CompanyBankAccount {
     /**
-     * The company bank account
+     * Bank account type
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub account_type: Option<AccountType>,
     /**
-     * The company bank account
+     * UUID of the company
      */
     #[serde(
         default,
@@ -2372,7 +2597,7 @@ pub struct CompanyBankAccount {
     )]
     pub company_uuid: String,
     /**
-     * The company bank account
+     * masked bank account number
      */
     #[serde(
         default,
@@ -2381,7 +2606,7 @@ pub struct CompanyBankAccount {
     )]
     pub hidden_account_number: String,
     /**
-     * The company bank account
+     * The bank account's routing number
      */
     #[serde(
         default,
@@ -2390,7 +2615,7 @@ pub struct CompanyBankAccount {
     )]
     pub routing_number: String,
     /**
-     * The company bank account
+     * UUID of the bank account
      */
     #[serde(
         default,
@@ -2399,7 +2624,11 @@ pub struct CompanyBankAccount {
     )]
     pub uuid: String,
     /**
-     * The company bank account
+     * The verification status of the bank account.
+     *  
+     *  'awaiting_deposits' means the bank account is just created and money is being transferred.
+     *  'ready_for_verification' means the micro-deposits are completed and the verification process can begin by using the verify endpoint.
+     *  'verified' means the bank account is verified.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub verification_status: Option<VerificationStatus>,
@@ -2409,7 +2638,7 @@ pub struct CompanyBankAccount {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SupportedBenefit {
     /**
-     *
+     * The description of the benefit.
      */
     #[serde(
         default,
@@ -2418,7 +2647,7 @@ pub struct SupportedBenefit {
     )]
     pub description: String,
     /**
-     *
+     * Whether the benefit is healthcare related.
      */
     #[serde(
         default,
@@ -2426,7 +2655,7 @@ pub struct SupportedBenefit {
     )]
     pub healthcare: bool,
     /**
-     *
+     * The ID of the benefit type in Gusto.
      */
     #[serde(
         default,
@@ -2435,7 +2664,7 @@ pub struct SupportedBenefit {
     )]
     pub id: f64,
     /**
-     *
+     * Whether the benefit is considered imputed income.
      */
     #[serde(
         default,
@@ -2443,7 +2672,7 @@ pub struct SupportedBenefit {
     )]
     pub imputed: bool,
     /**
-     *
+     * The name of the benefit.
      */
     #[serde(
         default,
@@ -2452,7 +2681,7 @@ pub struct SupportedBenefit {
     )]
     pub name: String,
     /**
-     *
+     * Whether the benefit is deducted after tax calculations.
      */
     #[serde(
         default,
@@ -2460,7 +2689,7 @@ pub struct SupportedBenefit {
     )]
     pub posttax: bool,
     /**
-     *
+     * Whether the benefit is deducted before tax calculations, thus reducing one’s taxable income
      */
     #[serde(
         default,
@@ -2468,7 +2697,7 @@ pub struct SupportedBenefit {
     )]
     pub pretax: bool,
     /**
-     *
+     * Whether the benefit is associated with retirement planning.
      */
     #[serde(
         default,
@@ -2476,7 +2705,7 @@ pub struct SupportedBenefit {
     )]
     pub retirement: bool,
     /**
-     *
+     * Whether the benefit has a government mandated yearly limit.
      */
     #[serde(
         default,
@@ -2489,7 +2718,7 @@ pub struct SupportedBenefit {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CompanyBenefit {
     /**
-     * The representation of a company benefit.
+     * Whether this benefit is active for employee participation. Company benefits may only be deactivated if no employees are actively participating.
      */
     #[serde(
         default,
@@ -2497,7 +2726,7 @@ pub struct CompanyBenefit {
     )]
     pub active: bool,
     /**
-     * The representation of a company benefit.
+     * The ID of the benefitt to which the company benefit belongs.
      */
     #[serde(
         default,
@@ -2506,7 +2735,7 @@ pub struct CompanyBenefit {
     )]
     pub benefit_id: f64,
     /**
-     * The representation of a company benefit.
+     * The ID of the company to which the company benefit belongs.
      */
     #[serde(
         default,
@@ -2515,7 +2744,7 @@ pub struct CompanyBenefit {
     )]
     pub company_id: f64,
     /**
-     * The representation of a company benefit.
+     * The description of the company benefit.For example, a company may offer multiple benefits with an ID of 1 (for Medical Insurance). The description would show something more specific like “Kaiser Permanente” or “Blue Cross/ Blue Shield”.
      */
     #[serde(
         default,
@@ -2524,7 +2753,7 @@ pub struct CompanyBenefit {
     )]
     pub description: String,
     /**
-     * The representation of a company benefit.
+     * The ID of the company benefit.
      */
     #[serde(
         default,
@@ -2533,7 +2762,7 @@ pub struct CompanyBenefit {
     )]
     pub id: f64,
     /**
-     * The representation of a company benefit.
+     * Whether the employer is subject to file W-2 forms for an employee on leave. Only applicable to third party sick pay benefits.
      */
     #[serde(
         default,
@@ -2542,7 +2771,7 @@ pub struct CompanyBenefit {
     )]
     pub responsible_for_employee_w_2: bool,
     /**
-     * The representation of a company benefit.
+     * Whether the employer is subject to pay employer taxes when an employee is on leave. Only applicable to third party sick pay benefits.
      */
     #[serde(
         default,
@@ -2550,7 +2779,7 @@ pub struct CompanyBenefit {
     )]
     pub responsible_for_employer_taxes: bool,
     /**
-     * The representation of a company benefit.
+     * Whether employee deductions and company contributions can be set as percentages of payroll for an individual employee. This is determined by the type of benefit and is not configurable by the company.
      */
     #[serde(
         default,
@@ -2558,7 +2787,7 @@ pub struct CompanyBenefit {
     )]
     pub supports_percentage_amounts: bool,
     /**
-     * The representation of a company benefit.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -2572,7 +2801,7 @@ pub struct CompanyBenefit {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EarningType {
     /**
-     *
+     * The name of the earning type.
      */
     #[serde(
         default,
@@ -2581,7 +2810,7 @@ pub struct EarningType {
     )]
     pub name: String,
     /**
-     *
+     * The ID of the earning type.
      */
     #[serde(
         default,
@@ -2595,6 +2824,7 @@ pub struct EarningType {
 * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DeductionReducesTaxableIncome {
     #[serde(rename = "does_not_reduce_taxable_income")]
     DoesNotReduceTaxableIncome,
@@ -2602,8 +2832,9 @@ pub enum DeductionReducesTaxableIncome {
     ReducesTaxableIncome,
     #[serde(rename = "unset")]
     Unset,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeductionReducesTaxableIncome {
@@ -2614,12 +2845,34 @@ impl std::fmt::Display for DeductionReducesTaxableIncome {
             }
             DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
             DeductionReducesTaxableIncome::Unset => "unset",
-            DeductionReducesTaxableIncome::FallthroughString => "*",
+            DeductionReducesTaxableIncome::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DeductionReducesTaxableIncome {
+    fn from(s: String) -> DeductionReducesTaxableIncome {
+        match s.as_str() {
+            "does_not_reduce_taxable_income" => {
+                DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome
+            }
+            "reduces_taxable_income" => DeductionReducesTaxableIncome::ReducesTaxableIncome,
+            "unset" => DeductionReducesTaxableIncome::Unset,
+            _ => DeductionReducesTaxableIncome::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DeductionReducesTaxableIncome> for String {
+    fn from(e: DeductionReducesTaxableIncome) -> String {
+        match e {
+            DeductionReducesTaxableIncome::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DeductionReducesTaxableIncome {
     fn default() -> DeductionReducesTaxableIncome {
         DeductionReducesTaxableIncome::Unset
@@ -2630,7 +2883,7 @@ impl Default for DeductionReducesTaxableIncome {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmployeeBenefit {
     /**
-     * The representation of an employee benefit.
+     * Whether the employee benefit is active.
      */
     #[serde(
         default,
@@ -2638,7 +2891,7 @@ pub struct EmployeeBenefit {
     )]
     pub active: bool,
     /**
-     * The representation of an employee benefit.
+     * Whether the employee should use a benefit’s "catch up" rate. Only Roth 401k and 401k benefits use this value for employees over 50.
      */
     #[serde(
         default,
@@ -2646,7 +2899,7 @@ pub struct EmployeeBenefit {
     )]
     pub catch_up: bool,
     /**
-     * The representation of an employee benefit.
+     * The ID of the company to which the benefit belongs.
      */
     #[serde(
         default,
@@ -2655,7 +2908,7 @@ pub struct EmployeeBenefit {
     )]
     pub company_benefit_id: f64,
     /**
-     * The representation of an employee benefit.
+     * The amount to be paid, per pay period, by the company.
      */
     #[serde(
         default,
@@ -2664,7 +2917,7 @@ pub struct EmployeeBenefit {
     )]
     pub company_contribution: String,
     /**
-     * The representation of an employee benefit.
+     * The maximum company contribution amount per year. A null value signifies no limit.
      */
     #[serde(
         default,
@@ -2673,7 +2926,7 @@ pub struct EmployeeBenefit {
     )]
     pub company_contribution_annual_maximum: String,
     /**
-     * The representation of an employee benefit.
+     * Whether the company contribution amount should be treated as a percentage to be deducted from each payroll.
      */
     #[serde(
         default,
@@ -2681,7 +2934,7 @@ pub struct EmployeeBenefit {
     )]
     pub contribute_as_percentage: bool,
     /**
-     * The representation of an employee benefit.
+     * The amount that the employee is insured for. Note: company contribution cannot be present if coverage amount is set.
      */
     #[serde(
         default,
@@ -2690,7 +2943,7 @@ pub struct EmployeeBenefit {
     )]
     pub coverage_amount: String,
     /**
-     * The representation of an employee benefit.
+     * The coverage amount as a multiple of the employee’s salary. Only applicable for Group Term Life benefits. Note: cannot be set if coverage amount is also set.
      */
     #[serde(
         default,
@@ -2699,7 +2952,7 @@ pub struct EmployeeBenefit {
     )]
     pub coverage_salary_multiplier: String,
     /**
-     * The representation of an employee benefit.
+     * Whether the employee deduction amount should be treated as a percentage to be deducted from each payroll.
      */
     #[serde(
         default,
@@ -2712,7 +2965,7 @@ pub struct EmployeeBenefit {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deduction_reduces_taxable_income: Option<DeductionReducesTaxableIncome>,
     /**
-     * The representation of an employee benefit.
+     * The amount to be deducted, per pay period, from the employee's pay.
      */
     #[serde(
         default,
@@ -2721,7 +2974,7 @@ pub struct EmployeeBenefit {
     )]
     pub employee_deduction: String,
     /**
-     * The representation of an employee benefit.
+     * The maximum employee deduction amount per year. A null value signifies no limit.
      */
     #[serde(
         default,
@@ -2730,7 +2983,7 @@ pub struct EmployeeBenefit {
     )]
     pub employee_deduction_annual_maximum: String,
     /**
-     * The representation of an employee benefit.
+     * The ID of the employee to which the benefit belongs.
      */
     #[serde(
         default,
@@ -2739,7 +2992,7 @@ pub struct EmployeeBenefit {
     )]
     pub employee_id: f64,
     /**
-     * The representation of an employee benefit.
+     * The ID of the employee benefit.
      */
     #[serde(
         default,
@@ -2748,7 +3001,7 @@ pub struct EmployeeBenefit {
     )]
     pub id: f64,
     /**
-     * The representation of an employee benefit.
+     * Some benefits require additional information to determine their limit. For example, for an HSA benefit, the limit option should be either "Family" or "Individual". For a Dependent Care FSA benefit, the limit option should be either "Joint Filing or Single" or "Married and Filing Separately".
      */
     #[serde(
         default,
@@ -2757,7 +3010,7 @@ pub struct EmployeeBenefit {
     )]
     pub limit_option: String,
     /**
-     * The representation of an employee benefit.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -2769,12 +3022,18 @@ pub struct EmployeeBenefit {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EligibleEmployees {
+    /**
+     * The ID of the employee that is eligible for the pay period.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub id: f64,
+    /**
+     * The employee's job IDs that are eligible for the pay period.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2787,7 +3046,7 @@ pub struct EligibleEmployees {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Payroll {
     /**
-     * Information about the payroll for the pay period.
+     * The date by which payroll should be run for employees to be paid on time. Payroll data, such as time and attendance data, should be submitted on or before this date.
      */
     #[serde(
         default,
@@ -2796,7 +3055,7 @@ pub struct Payroll {
     )]
     pub payroll_deadline: String,
     /**
-     * Information about the payroll for the pay period.
+     * Whether or not the payroll has been successfully processed. Note that processed payrolls cannot be updated. Additionally, a payroll is not guaranteed to be processed just because the payroll deadline has passed. Late payrolls are not uncommon. Conversely, users may choose to run payroll before the payroll deadline.
      */
     #[serde(
         default,
@@ -2809,7 +3068,7 @@ pub struct Payroll {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PayPeriod {
     /**
-     * The representation of a pay period.
+     * The employees who are (or were) eligible during the pay period.
      */
     #[serde(
         default,
@@ -2817,6 +3076,9 @@ pub struct PayPeriod {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub eligible_employees: Vec<EligibleEmployees>,
+    /**
+     * The end date, inclusive, of the pay period.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -2824,7 +3086,7 @@ pub struct PayPeriod {
     )]
     pub end_date: Option<chrono::NaiveDate>,
     /**
-     * The representation of a pay period.
+     * The ID of the pay schedule to which the pay period belongs.
      */
     #[serde(
         default,
@@ -2833,7 +3095,7 @@ pub struct PayPeriod {
     )]
     pub pay_schedule_id: f64,
     /**
-     * The representation of a pay period.
+     * A unique identifier of the pay schedule to which the pay period belongs.
      */
     #[serde(
         default,
@@ -2842,10 +3104,13 @@ pub struct PayPeriod {
     )]
     pub pay_schedule_uuid: String,
     /**
-     * The representation of a pay period.
+     * Information about the payroll for the pay period.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payroll: Option<Payroll>,
+    /**
+     * The start date, inclusive, of the pay period.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -2856,24 +3121,36 @@ pub struct PayPeriod {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PayrollPayPeriod {
+    /**
+     * The start date, inclusive, of the pay period.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub end_date: Option<chrono::NaiveDate>,
+    /**
+     * The ID of the pay schedule for the payroll.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub pay_schedule_id: f64,
+    /**
+     * A unique identifier of the pay schedule for the payroll..
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pay_schedule_uuid: String,
+    /**
+     * The start date, inclusive, of the pay period.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -2886,7 +3163,7 @@ pub struct PayrollPayPeriod {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Totals {
     /**
-     * The subtotals for the payroll.
+     * The total additional earnings amount for the payroll.
      */
     #[serde(
         default,
@@ -2895,7 +3172,7 @@ pub struct Totals {
     )]
     pub additional_earnings: String,
     /**
-     * The subtotals for the payroll.
+     * The total amount of company contributed benefits for the payroll.
      */
     #[serde(
         default,
@@ -2904,7 +3181,7 @@ pub struct Totals {
     )]
     pub benefits: String,
     /**
-     * The subtotals for the payroll.
+     * The total check amount for the payroll.
      */
     #[serde(
         default,
@@ -2913,7 +3190,7 @@ pub struct Totals {
     )]
     pub check_amount: String,
     /**
-     * The subtotals for the payroll.
+     * The total child support debit for the payroll.
      */
     #[serde(
         default,
@@ -2922,7 +3199,7 @@ pub struct Totals {
     )]
     pub child_support_debit: String,
     /**
-     * The subtotals for the payroll.
+     * The total company debit for the payroll.
      */
     #[serde(
         default,
@@ -2931,7 +3208,7 @@ pub struct Totals {
     )]
     pub company_debit: String,
     /**
-     * The subtotals for the payroll.
+     * The total amount of payroll taxes deferred for the payroll, such as allowed by the CARES act.
      */
     #[serde(
         default,
@@ -2940,7 +3217,7 @@ pub struct Totals {
     )]
     pub deferred_payroll_taxes: String,
     /**
-     * The subtotals for the payroll.
+     * The total amount of employee deducted benefits for the payroll.
      */
     #[serde(
         default,
@@ -2949,7 +3226,7 @@ pub struct Totals {
     )]
     pub employee_benefits_deductions: String,
     /**
-     * The subtotals for the payroll.
+     * The total employee bonuses amount for the payroll.
      */
     #[serde(
         default,
@@ -2958,7 +3235,7 @@ pub struct Totals {
     )]
     pub employee_bonuses: String,
     /**
-     * The subtotals for the payroll.
+     * The total employee cash tips amount for the payroll.
      */
     #[serde(
         default,
@@ -2967,7 +3244,7 @@ pub struct Totals {
     )]
     pub employee_cash_tips: String,
     /**
-     * The subtotals for the payroll.
+     * The total employee commissions amount for the payroll.
      */
     #[serde(
         default,
@@ -2976,7 +3253,7 @@ pub struct Totals {
     )]
     pub employee_commissions: String,
     /**
-     * The subtotals for the payroll.
+     * The total employee paycheck tips amount for the payroll.
      */
     #[serde(
         default,
@@ -2985,7 +3262,7 @@ pub struct Totals {
     )]
     pub employee_paycheck_tips: String,
     /**
-     * The subtotals for the payroll.
+     * The total amount of employee paid taxes for the payroll.
      */
     #[serde(
         default,
@@ -2994,7 +3271,7 @@ pub struct Totals {
     )]
     pub employee_taxes: String,
     /**
-     * The subtotals for the payroll.
+     * The total amount of employer paid taxes for the payroll.
      */
     #[serde(
         default,
@@ -3003,7 +3280,7 @@ pub struct Totals {
     )]
     pub employer_taxes: String,
     /**
-     * The subtotals for the payroll.
+     * The gross pay amount for the payroll.
      */
     #[serde(
         default,
@@ -3012,7 +3289,7 @@ pub struct Totals {
     )]
     pub gross_pay: String,
     /**
-     * The subtotals for the payroll.
+     * The net pay amount for the payroll.
      */
     #[serde(
         default,
@@ -3021,7 +3298,7 @@ pub struct Totals {
     )]
     pub net_pay: String,
     /**
-     * The subtotals for the payroll.
+     * The total company net pay for the payroll.
      */
     #[serde(
         default,
@@ -3030,7 +3307,7 @@ pub struct Totals {
     )]
     pub net_pay_debit: String,
     /**
-     * The subtotals for the payroll.
+     * The total owner's draw for the payroll.
      */
     #[serde(
         default,
@@ -3039,7 +3316,7 @@ pub struct Totals {
     )]
     pub owners_draw: String,
     /**
-     * The subtotals for the payroll.
+     * The total reimbursement debit for the payroll.
      */
     #[serde(
         default,
@@ -3048,7 +3325,7 @@ pub struct Totals {
     )]
     pub reimbursement_debit: String,
     /**
-     * The subtotals for the payroll.
+     * The total reimbursements for the payroll.
      */
     #[serde(
         default,
@@ -3057,7 +3334,7 @@ pub struct Totals {
     )]
     pub reimbursements: String,
     /**
-     * The subtotals for the payroll.
+     * The total tax debit for the payroll.
      */
     #[serde(
         default,
@@ -3071,6 +3348,7 @@ pub struct Totals {
 * The employee's compensation payment method. This value is only available for processed payrolls.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PayrollEmployeeCompensationsPaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -3078,8 +3356,9 @@ pub enum PayrollEmployeeCompensationsPaymentMethod {
     DirectDeposit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
@@ -3088,12 +3367,32 @@ impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
             PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
             PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
             PayrollEmployeeCompensationsPaymentMethod::Noop => "",
-            PayrollEmployeeCompensationsPaymentMethod::FallthroughString => "*",
+            PayrollEmployeeCompensationsPaymentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PayrollEmployeeCompensationsPaymentMethod {
+    fn from(s: String) -> PayrollEmployeeCompensationsPaymentMethod {
+        match s.as_str() {
+            "Check" => PayrollEmployeeCompensationsPaymentMethod::Check,
+            "Direct Deposit" => PayrollEmployeeCompensationsPaymentMethod::DirectDeposit,
+            "" => PayrollEmployeeCompensationsPaymentMethod::Noop,
+            _ => PayrollEmployeeCompensationsPaymentMethod::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PayrollEmployeeCompensationsPaymentMethod> for String {
+    fn from(e: PayrollEmployeeCompensationsPaymentMethod) -> String {
+        match e {
+            PayrollEmployeeCompensationsPaymentMethod::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PayrollEmployeeCompensationsPaymentMethod {
     fn default() -> PayrollEmployeeCompensationsPaymentMethod {
         PayrollEmployeeCompensationsPaymentMethod::Noop
@@ -3108,7 +3407,7 @@ impl PayrollEmployeeCompensationsPaymentMethod {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FixedCompensations {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The amount of the compensation for the pay period.
      */
     #[serde(
         default,
@@ -3117,7 +3416,7 @@ pub struct FixedCompensations {
     )]
     pub amount: String,
     /**
-     * The ID of the employee in Gusto.
+     * The ID of the job for the compensation.
      */
     #[serde(
         default,
@@ -3126,7 +3425,7 @@ pub struct FixedCompensations {
     )]
     pub job_id: f64,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The name of the compensation. This also serves as the unique, immutable identifier for this compensation.
      */
     #[serde(
         default,
@@ -3139,7 +3438,7 @@ pub struct FixedCompensations {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct HourlyCompensations {
     /**
-     * The ID of the employee in Gusto.
+     * The amount multiplied by the base rate to calculate total compensation per hour worked.
      */
     #[serde(
         default,
@@ -3148,7 +3447,7 @@ pub struct HourlyCompensations {
     )]
     pub compensation_multiplier: f64,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The number of hours to be compensated for this pay period.
      */
     #[serde(
         default,
@@ -3157,7 +3456,7 @@ pub struct HourlyCompensations {
     )]
     pub hours: String,
     /**
-     * The ID of the employee in Gusto.
+     * The ID of the job for the compensation.
      */
     #[serde(
         default,
@@ -3166,7 +3465,7 @@ pub struct HourlyCompensations {
     )]
     pub job_id: f64,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The name of the compensation. This also serves as the unique, immutable identifier for this compensation.
      */
     #[serde(
         default,
@@ -3179,7 +3478,7 @@ pub struct HourlyCompensations {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PayrollEmployeeCompensationsPaidTimeOff {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The hours of this PTO taken during the pay period.
      */
     #[serde(
         default,
@@ -3188,7 +3487,7 @@ pub struct PayrollEmployeeCompensationsPaidTimeOff {
     )]
     pub hours: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The name of the PTO. This also serves as the unique, immutable identifier for the PTO.
      */
     #[serde(
         default,
@@ -3243,26 +3542,17 @@ pub struct Deductions {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Taxes {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub amount: String,
-    /**
-     * Whether the employee is terminated.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub employer: bool,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3292,7 +3582,7 @@ pub struct EmployeeCompensations {
     )]
     pub deductions: Vec<Deductions>,
     /**
-     * The ID of the employee in Gusto.
+     * The ID of the employee.
      */
     #[serde(
         default,
@@ -3310,7 +3600,7 @@ pub struct EmployeeCompensations {
     )]
     pub fixed_compensations: Vec<FixedCompensations>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The employee's gross pay. This value is only available for processed payrolls.
      */
     #[serde(
         default,
@@ -3328,7 +3618,7 @@ pub struct EmployeeCompensations {
     )]
     pub hourly_compensations: Vec<HourlyCompensations>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The employee's net pay. This value is only available for processed payrolls.
      */
     #[serde(
         default,
@@ -3365,7 +3655,7 @@ pub struct EmployeeCompensations {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PayrollData {
     /**
-     *
+     * A timestamp of the last valid payroll calculation. Null is there isn't a valid calculation.
      */
     #[serde(
         default,
@@ -3373,6 +3663,9 @@ pub struct PayrollData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub calculated_at: String,
+    /**
+     * The date on which employees will be paid for the payroll.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -3380,7 +3673,7 @@ pub struct PayrollData {
     )]
     pub check_date: Option<chrono::NaiveDate>,
     /**
-     *
+     * The ID of the company for the payroll.
      */
     #[serde(
         default,
@@ -3389,7 +3682,7 @@ pub struct PayrollData {
     )]
     pub company_id: f64,
     /**
-     *
+     * A unique identifier of the company for the payroll.
      */
     #[serde(
         default,
@@ -3397,22 +3690,16 @@ pub struct PayrollData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub company_uuid: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub employee_compensations: Vec<EmployeeCompensations>,
-    /**
-     *
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pay_period: Option<PayrollPayPeriod>,
     /**
-     *
+     * The deadline for the payroll to be run in order for employees to be paid on time.
      */
     #[serde(
         default,
@@ -3421,7 +3708,7 @@ pub struct PayrollData {
     )]
     pub payroll_deadline: String,
     /**
-     *
+     * The ID of the payroll.
      */
     #[serde(
         default,
@@ -3430,7 +3717,7 @@ pub struct PayrollData {
     )]
     pub payroll_id: f64,
     /**
-     *
+     * A unique identifier of the payroll.
      */
     #[serde(
         default,
@@ -3439,13 +3726,16 @@ pub struct PayrollData {
     )]
     pub payroll_uuid: String,
     /**
-     *
+     * Whether or not the payroll has been successfully processed. Note that processed payrolls cannot be updated. Additionally, a payroll is not guaranteed to be processed just because the payroll deadline has passed. Late payrolls are not uncommon. Conversely, users may choose to run payroll before the payroll deadline.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub processed: bool,
+    /**
+     * The date at which the payroll was processed. Null if the payroll isn't processed yet.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -3453,12 +3743,12 @@ pub struct PayrollData {
     )]
     pub processed_date: Option<chrono::NaiveDate>,
     /**
-     *
+     * The subtotals for the payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub totals: Option<Totals>,
     /**
-     *
+     * The current version of the object. See the versioning guide for details using this field.
      */
     #[serde(
         default,
@@ -3469,6 +3759,7 @@ pub struct PayrollData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CustomFieldType {
     #[serde(rename = "currency")]
     Currency,
@@ -3482,8 +3773,9 @@ pub enum CustomFieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CustomFieldType {
@@ -3495,12 +3787,35 @@ impl std::fmt::Display for CustomFieldType {
             CustomFieldType::Radio => "radio",
             CustomFieldType::Text => "text",
             CustomFieldType::Noop => "",
-            CustomFieldType::FallthroughString => "*",
+            CustomFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CustomFieldType {
+    fn from(s: String) -> CustomFieldType {
+        match s.as_str() {
+            "currency" => CustomFieldType::Currency,
+            "date" => CustomFieldType::Date,
+            "number" => CustomFieldType::Number,
+            "radio" => CustomFieldType::Radio,
+            "text" => CustomFieldType::Text,
+            "" => CustomFieldType::Noop,
+            _ => CustomFieldType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CustomFieldType> for String {
+    fn from(e: CustomFieldType) -> String {
+        match e {
+            CustomFieldType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CustomFieldType {
     fn default() -> CustomFieldType {
         CustomFieldType::Noop
@@ -3516,7 +3831,7 @@ impl CustomFieldType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmployeeCustomField {
     /**
-     * A unique identifier of the employee in Gusto.
+     * This is the id of the response object from when you get the company custom fields
      */
     #[serde(
         default,
@@ -3524,27 +3839,18 @@ pub struct EmployeeCustomField {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub company_custom_field_id: String,
-    /**
-     * A custom field of an employee
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3552,7 +3858,7 @@ pub struct EmployeeCustomField {
     )]
     pub name: String,
     /**
-     * A custom field of an employee
+     * An array of options for fields of type radio. Otherwise, null.
      */
     #[serde(
         default,
@@ -3562,9 +3868,6 @@ pub struct EmployeeCustomField {
     pub selection_options: Vec<String>,
     #[serde(rename = "type")]
     pub type_: CustomFieldType,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3576,27 +3879,18 @@ pub struct EmployeeCustomField {
 /// A custom field on a company
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CompanyCustomField {
-    /**
-     * A custom field on a company
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3604,7 +3898,7 @@ pub struct CompanyCustomField {
     )]
     pub name: String,
     /**
-     * A custom field on a company
+     * An array of options for fields of type radio. Otherwise, null.
      */
     #[serde(
         default,
@@ -3617,6 +3911,7 @@ pub struct CompanyCustomField {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GustoPersonType {
     #[serde(rename = "Candidate")]
     Candidate,
@@ -3626,8 +3921,9 @@ pub enum GustoPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GustoPersonType {
@@ -3637,12 +3933,33 @@ impl std::fmt::Display for GustoPersonType {
             GustoPersonType::Contractor => "Contractor",
             GustoPersonType::Employee => "Employee",
             GustoPersonType::Noop => "",
-            GustoPersonType::FallthroughString => "*",
+            GustoPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GustoPersonType {
+    fn from(s: String) -> GustoPersonType {
+        match s.as_str() {
+            "Candidate" => GustoPersonType::Candidate,
+            "Contractor" => GustoPersonType::Contractor,
+            "Employee" => GustoPersonType::Employee,
+            "" => GustoPersonType::Noop,
+            _ => GustoPersonType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GustoPersonType> for String {
+    fn from(e: GustoPersonType) -> String {
+        match e {
+            GustoPersonType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GustoPersonType {
     fn default() -> GustoPersonType {
         GustoPersonType::Noop
@@ -3657,18 +3974,12 @@ impl GustoPersonType {
 /// The representation of a job applicant in Gusto.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct JobApplicant {
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub company_id: f64,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3681,27 +3992,18 @@ pub struct JobApplicant {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -3710,36 +4012,24 @@ pub struct JobApplicant {
     pub gusto_person_id: f64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gusto_person_type: Option<GustoPersonType>,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub gusto_person_uuid: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub job_title: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3752,9 +4042,6 @@ pub struct JobApplicant {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3765,41 +4052,79 @@ pub struct JobApplicant {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FederalTaxDetails {
+    /**
+     * The company's Employer Identification Number (EIN) registered with the IRS
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ein: String,
+    /**
+     * Whether the EIN was able to be verified as a valid EIN with the IRS.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ein_verified: bool,
+    /**
+     * The form used by the company for federal tax filing. One of:
+     *  - 941 (Quarterly federal tax return form)
+     *  - 944 (Annual federal tax return form)
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub filing_form: String,
+    /**
+     * The legal name of the company
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub legal_name: String,
+    /**
+     * What type of tax entity the company is. One of:
+     *  - C-Corporation
+     *  - S-Corporation
+     *  - Sole proprietor
+     *  - LLC
+     *  - LLP
+     *  - Limited partnership
+     *  - Co-ownership
+     *  - Association
+     *  - Trusteeship
+     *  - General partnership
+     *  - Joint venture
+     *  - Non-Profit
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tax_payer_type: String,
+    /**
+     * Whether the company is taxed as an S-Corporation. Tax payer types that may be taxed as an S-Corporation include:
+     *  - S-Corporation
+     *  - C-Corporation
+     *  - LLC
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub taxable_as_scorp: String,
+    /**
+     * The current version of the object. See the versioning guide for details using this field.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3811,7 +4136,7 @@ pub struct FederalTaxDetails {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
     /**
-     * The ID of the employee in Gusto.
+     * The id for the benefit got from the benefits api.
      */
     #[serde(
         default,
@@ -3820,7 +4145,7 @@ pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
     )]
     pub benefit_id: f64,
     /**
-     * The ID of the employee in Gusto.
+     * The tax year for which this amount applies.
      */
     #[serde(
         default,
@@ -3829,7 +4154,7 @@ pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
     )]
     pub tax_year: f64,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The year-to-date company contribution made outside the current company.
      */
     #[serde(
         default,
@@ -3838,7 +4163,7 @@ pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
     )]
     pub ytd_company_contribution_amount: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The year-to-date employee deduction made outside the current company.
      */
     #[serde(
         default,
@@ -3851,7 +4176,7 @@ pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EarningTypeListResponse {
     /**
-     * The default earning types for the company.
+     * The custom earning types for the company.
      */
     #[serde(
         default,
@@ -3871,13 +4196,15 @@ pub struct EarningTypeListResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Include {
     #[serde(rename = "custom_fields")]
     CustomFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Include {
@@ -3885,12 +4212,31 @@ impl std::fmt::Display for Include {
         match self {
             Include::CustomFields => "custom_fields",
             Include::Noop => "",
-            Include::FallthroughString => "*",
+            Include::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Include {
+    fn from(s: String) -> Include {
+        match s.as_str() {
+            "custom_fields" => Include::CustomFields,
+            "" => Include::Noop,
+            _ => Include::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Include> for String {
+    fn from(e: Include) -> String {
+        match e {
+            Include::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Include {
     fn default() -> Include {
         Include::Noop
@@ -3911,58 +4257,40 @@ pub struct PutEmployeesRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub middle_initial: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ssn: String,
-    /**
-     *
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub two_percent_shareholder: Option<bool>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -3981,45 +4309,30 @@ pub struct PostEmployeesRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub middle_initial: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4037,18 +4350,12 @@ pub struct PutJobRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub hire_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub location_id: f64,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4056,7 +4363,7 @@ pub struct PutJobRequest {
     )]
     pub title: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -4075,18 +4382,12 @@ pub struct PostJobRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub hire_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub location_id: f64,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4098,18 +4399,12 @@ pub struct PostJobRequest {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyLocationsRequest {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4117,54 +4412,35 @@ pub struct PostCompanyLocationsRequest {
     )]
     pub country: String,
     /**
-     *
+     * Specify if this location is the company's filing address.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filing_address: Option<bool>,
     /**
-     *
+     * Specify if this location is the company's mailing address.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mailing_address: Option<bool>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_1: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_2: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub street_2: crate::utils::Patch<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4176,18 +4452,12 @@ pub struct PostCompanyLocationsRequest {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutLocationRequest {
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4195,54 +4465,35 @@ pub struct PutLocationRequest {
     )]
     pub country: String,
     /**
-     *
+     * For a company location, specify if this location is the company's filing address.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filing_address: Option<bool>,
     /**
-     *
+     * For a company location, specify if this location is the company's mailing address.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mailing_address: Option<bool>,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_1: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_2: String,
-    /**
-     *
-     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub street_2: crate::utils::Patch<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4255,7 +4506,7 @@ pub struct PutLocationRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutComntractorRequest {
     /**
-     *
+     * The name of the contractor business. This attribute is required for “Business” contractors and will be ignored for “Individual” contractors.
      */
     #[serde(
         default,
@@ -4264,7 +4515,7 @@ pub struct PutComntractorRequest {
     )]
     pub business_name: String,
     /**
-     *
+     * The employer identification number of the contractor business. This attribute is optional for “Business” contractors and will be ignored for “Individual” contractors.
      */
     #[serde(
         default,
@@ -4273,7 +4524,7 @@ pub struct PutComntractorRequest {
     )]
     pub ein: String,
     /**
-     *
+     * The contractor’s first name. This attribute is required for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -4282,7 +4533,7 @@ pub struct PutComntractorRequest {
     )]
     pub first_name: String,
     /**
-     *
+     * The contractor’s hourly rate. This attribute is required if the wage_type is “Hourly”.
      */
     #[serde(
         default,
@@ -4291,7 +4542,7 @@ pub struct PutComntractorRequest {
     )]
     pub hourly_rate: String,
     /**
-     *
+     * The contractor’s last name. This attribute is required for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -4300,7 +4551,7 @@ pub struct PutComntractorRequest {
     )]
     pub last_name: String,
     /**
-     *
+     * null	The contractor’s middle initial. This attribute is optional for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -4308,6 +4559,10 @@ pub struct PutComntractorRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub middle_initial: String,
+    /**
+     * The day when the contractor will start working for the company.
+     *
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4315,7 +4570,7 @@ pub struct PutComntractorRequest {
     )]
     pub start_date: Option<chrono::NaiveDate>,
     /**
-     *
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -4324,6 +4579,7 @@ pub struct PutComntractorRequest {
     )]
     pub version: String,
     /**
+     * The contractor’s wage type, either “Fixed” or “Hourly”.
      *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4334,7 +4590,7 @@ pub struct PutComntractorRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyContractorsRequest {
     /**
-     *
+     * The name of the contractor business. This attribute is required for “Business” contractors and will be ignored for “Individual” contractors.
      */
     #[serde(
         default,
@@ -4343,7 +4599,7 @@ pub struct PostCompanyContractorsRequest {
     )]
     pub business_name: String,
     /**
-     *
+     * The employer identification number of the contractor business. This attribute is optional for “Business” contractors and will be ignored for “Individual” contractors.
      */
     #[serde(
         default,
@@ -4352,7 +4608,7 @@ pub struct PostCompanyContractorsRequest {
     )]
     pub ein: String,
     /**
-     *
+     * The contractor’s email address. This attribute is optional for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -4361,7 +4617,7 @@ pub struct PostCompanyContractorsRequest {
     )]
     pub email: String,
     /**
-     *
+     * The contractor’s first name. This attribute is required for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -4370,7 +4626,7 @@ pub struct PostCompanyContractorsRequest {
     )]
     pub first_name: String,
     /**
-     *
+     * The contractor’s last_name. This attribute is optional for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -4379,7 +4635,7 @@ pub struct PostCompanyContractorsRequest {
     )]
     pub last_name: String,
     /**
-     *
+     * The contractor’s middle initial. This attribute is optional for “Individual” contractors and will be ignored for “Business” contractors.
      */
     #[serde(
         default,
@@ -4388,10 +4644,13 @@ pub struct PostCompanyContractorsRequest {
     )]
     pub middle_initial: String,
     /**
-     *
+     * Whether the contractor or the payroll admin will complete onboarding in Gusto. Self-onboarding is recommended so that contractors receive Gusto accounts. If self_onboarding is true, then email is required.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub self_onboarding: Option<bool>,
+    /**
+     * The day when the contractor will start working for the company.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4399,12 +4658,14 @@ pub struct PostCompanyContractorsRequest {
     )]
     pub start_date: Option<chrono::NaiveDate>,
     /**
-     * The contractor's type, either "Individual" or "Business".
+     * The contractor type, either an “Individual” or a “Business”.
+     *
      */
     #[serde(default, skip_serializing_if = "Type::is_noop", rename = "type")]
     pub type_: Type,
     /**
-     * The contractor's wage type, either "Fixed" or "Hourly".
+     * The contractor’s wage type, either “Fixed” or “Hourly”.
+     *
      */
     #[serde(default, skip_serializing_if = "WageType::is_noop")]
     pub wage_type: WageType,
@@ -4414,17 +4675,17 @@ pub struct PostCompanyContractorsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompensationRequest {
     /**
-     *
+     * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub flsa_status: Option<FlsaStatus>,
     /**
-     *
+     * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_unit: Option<PaymentUnit>,
     /**
-     *
+     * The dollar amount paid per payment unit.
      */
     #[serde(
         default,
@@ -4433,7 +4694,7 @@ pub struct PutCompensationRequest {
     )]
     pub rate: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -4446,6 +4707,9 @@ pub struct PutCompensationRequest {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostJobCompensationsRequest {
+    /**
+     * The effective date for this compensation. For the first compensation, this defaults to the job's hire date.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4463,7 +4727,7 @@ pub struct PostJobCompensationsRequest {
     #[serde(default, skip_serializing_if = "PaymentUnit::is_noop")]
     pub payment_unit: PaymentUnit,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The dollar amount paid per payment unit.
      */
     #[serde(
         default,
@@ -4477,12 +4741,12 @@ pub struct PostJobCompensationsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeGarnishmentsRequest {
     /**
-     *
+     * Whether or not this garnishment is currently active.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
-     * The ID of the employee in Gusto.
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
      */
     #[serde(
         default,
@@ -4491,7 +4755,7 @@ pub struct PostEmployeeGarnishmentsRequest {
     )]
     pub amount: f64,
     /**
-     *
+     * The maximum deduction per annum. A null value indicates no maximum. Represented as a float, e.g. "200.00".
      */
     #[serde(
         default,
@@ -4500,17 +4764,17 @@ pub struct PostEmployeeGarnishmentsRequest {
     )]
     pub annual_maximum: f64,
     /**
-     * Whether the employee is terminated.
+     * Whether the garnishment is court ordered.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub court_ordered: Option<bool>,
     /**
-     *
+     * Whether the amount should be treated as a percentage to be deducted per pay period.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deduct_as_percentage: Option<bool>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The description of the garnishment.
      */
     #[serde(
         default,
@@ -4519,7 +4783,7 @@ pub struct PostEmployeeGarnishmentsRequest {
     )]
     pub description: String,
     /**
-     *
+     * The maximum deduction per pay period. A null value indicates no maximum. Represented as a float, e.g. "16.00".
      */
     #[serde(
         default,
@@ -4528,12 +4792,12 @@ pub struct PostEmployeeGarnishmentsRequest {
     )]
     pub pay_period_maximum: f64,
     /**
-     *
+     * Whether the garnishment should recur indefinitely.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recurring: Option<bool>,
     /**
-     *
+     * The number of times to apply the garnisment. Ignored if recurring is true.
      */
     #[serde(
         default,
@@ -4546,12 +4810,12 @@ pub struct PostEmployeeGarnishmentsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutGarnishmentRequest {
     /**
-     * Whether the employee is terminated.
+     * Whether or not this garnishment is currently active.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
-     * The ID of the employee in Gusto.
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
      */
     #[serde(
         default,
@@ -4560,7 +4824,7 @@ pub struct PutGarnishmentRequest {
     )]
     pub amount: f64,
     /**
-     * The ID of the employee in Gusto.
+     * The maximum deduction per annum. A null value indicates no maximum. Represented as a float, e.g. "200.00".
      */
     #[serde(
         default,
@@ -4569,17 +4833,17 @@ pub struct PutGarnishmentRequest {
     )]
     pub annual_maximum: f64,
     /**
-     * Whether the employee is terminated.
+     * Whether the garnishment is court ordered.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub court_ordered: Option<bool>,
     /**
-     * Whether the employee is terminated.
+     * Whether the amount should be treated as a percentage to be deducted per pay period.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deduct_as_percentage: Option<bool>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The description of the garnishment.
      */
     #[serde(
         default,
@@ -4588,7 +4852,7 @@ pub struct PutGarnishmentRequest {
     )]
     pub description: String,
     /**
-     * The ID of the employee in Gusto.
+     * The maximum deduction per pay period. A null value indicates no maximum. Represented as a float, e.g. "16.00".
      */
     #[serde(
         default,
@@ -4597,12 +4861,12 @@ pub struct PutGarnishmentRequest {
     )]
     pub pay_period_maximum: f64,
     /**
-     * Whether the employee is terminated.
+     * Whether the garnishment should recur indefinitely.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recurring: Option<bool>,
     /**
-     * The unique identifier of the location in Gusto.
+     * The number of times to apply the garnisment. Ignored if recurring is true.
      */
     #[serde(
         default,
@@ -4611,7 +4875,7 @@ pub struct PutGarnishmentRequest {
     )]
     pub times: i64,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -4624,6 +4888,9 @@ pub struct PutGarnishmentRequest {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeTerminationsRequest {
+    /**
+     * The employee's last day of work.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4631,7 +4898,7 @@ pub struct PostEmployeeTerminationsRequest {
     )]
     pub effective_date: Option<chrono::NaiveDate>,
     /**
-     *
+     * If true, the employee should recieve their final wages via an offcycle payroll. If false, they should recieve their final wages on their current pay schedule.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub run_termination_payroll: Option<bool>,
@@ -4640,44 +4907,28 @@ pub struct PostEmployeeTerminationsRequest {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutEmployeeHomeAddressRequest {
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_1: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub street_2: crate::utils::Patch<String>,
     /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_2: String,
-    /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -4685,9 +4936,6 @@ pub struct PutEmployeeHomeAddressRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4700,12 +4948,12 @@ pub struct PutEmployeeHomeAddressRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyPaySchedulesScheduleRequest {
     /**
-     *
+     * With Autopilot® enabled, payroll will run automatically one day before your payroll deadlines.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_pilot: Option<bool>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -4719,7 +4967,7 @@ pub struct PutCompanyPaySchedulesScheduleRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyBankAccountsRequest {
     /**
-     *
+     * The bank account number
      */
     #[serde(
         default,
@@ -4728,12 +4976,12 @@ pub struct PostCompanyBankAccountsRequest {
     )]
     pub account_number: String,
     /**
-     *
+     * The bank account type
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub account_type: Option<AccountType>,
     /**
-     *
+     * The bank routing number
      */
     #[serde(
         default,
@@ -4747,7 +4995,7 @@ pub struct PostCompanyBankAccountsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyBankAccountsVerifyRequest {
     /**
-     *
+     * The dollar amount of the first micro-deposit
      */
     #[serde(
         default,
@@ -4756,7 +5004,7 @@ pub struct PutCompanyBankAccountsVerifyRequest {
     )]
     pub deposit_1: f64,
     /**
-     *
+     * The dollar amount of the second micro-deposit
      */
     #[serde(
         default,
@@ -4770,12 +5018,12 @@ pub struct PutCompanyBankAccountsVerifyRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyBenefitsRequest {
     /**
-     *
+     * Whether this benefit is active for employee participation.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
-     * The ID of the employee in Gusto.
+     * The ID of the benefit to which the company benefit belongs.
      */
     #[serde(
         default,
@@ -4784,7 +5032,7 @@ pub struct PostCompanyBenefitsRequest {
     )]
     pub benefit_id: f64,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The description of the company benefit.For example, a company may offer multiple benefits with an ID of 1 (for Medical Insurance). The description would show something more specific like “Kaiser Permanente” or “Blue Cross/ Blue Shield”.
      */
     #[serde(
         default,
@@ -4793,7 +5041,7 @@ pub struct PostCompanyBenefitsRequest {
     )]
     pub description: String,
     /**
-     *
+     * Whether the employer is subject to file W-2 forms for an employee on leave. Only applicable to third party sick pay benefits.
      */
     #[serde(
         default,
@@ -4802,7 +5050,7 @@ pub struct PostCompanyBenefitsRequest {
     )]
     pub responsible_for_employee_w_2: Option<bool>,
     /**
-     *
+     * Whether the employer is subject to pay employer taxes when an employee is on leave. Only applicable to third party sick pay benefits.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub responsible_for_employer_taxes: Option<bool>,
@@ -4812,12 +5060,12 @@ pub struct PostCompanyBenefitsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyBenefitRequest {
     /**
-     *
+     * Whether this benefit is active for employee participation. Company benefits may only be deactivated if no employees are actively participating.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
-     *
+     * The description of the company benefit.For example, a company may offer multiple benefits with an ID of 1 (for Medical Insurance). The description would show something more specific like “Kaiser Permanente” or “Blue Cross/ Blue Shield”.
      */
     #[serde(
         default,
@@ -4826,7 +5074,7 @@ pub struct PutCompanyBenefitRequest {
     )]
     pub description: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -4839,7 +5087,7 @@ pub struct PutCompanyBenefitRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyEarningTypesRequest {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The name of the custom earning type.
      */
     #[serde(
         default,
@@ -4852,7 +5100,7 @@ pub struct PostCompanyEarningTypesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyEarningTypeRequest {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The name of the custom earning type.
      */
     #[serde(
         default,
@@ -4866,17 +5114,17 @@ pub struct PutCompanyEarningTypeRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostEmployeeBenefitsRequest {
     /**
-     *
+     * Whether the employee benefit is active.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
-     *
+     * Whether the employee should use a benefit’s "catch up" rate. Only Roth 401k and 401k benefits use this value for employees over 50.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catch_up: Option<bool>,
     /**
-     * The ID of the employee in Gusto.
+     * The ID of the company to which the benefit belongs.
      */
     #[serde(
         default,
@@ -4885,7 +5133,7 @@ pub struct PostEmployeeBenefitsRequest {
     )]
     pub company_benefit_id: f64,
     /**
-     *
+     * The amount to be paid, per pay period, by the company.
      */
     #[serde(
         default,
@@ -4894,30 +5142,22 @@ pub struct PostEmployeeBenefitsRequest {
     )]
     pub company_contribution: String,
     /**
-     *
+     * The maximum company contribution amount per year. A null value signifies no limit.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_contribution_annual_maximum: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub company_contribution_annual_maximum: crate::utils::Patch<String>,
     /**
-     *
+     * Whether the company contribution amount should be treated as a percentage to be deducted from each payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contribute_as_percentage: Option<bool>,
     /**
-     *
+     * The amount that the employee is insured for. Note: company contribution cannot be present if coverage amount is set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub coverage_amount: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub coverage_amount: crate::utils::Patch<String>,
     /**
-     *
+     * The coverage amount as a multiple of the employee’s salary. Only applicable for Group Term Life benefits. Note: cannot be set if coverage amount is also set.
      */
     #[serde(
         default,
@@ -4926,17 +5166,17 @@ pub struct PostEmployeeBenefitsRequest {
     )]
     pub coverage_salary_multiplier: String,
     /**
-     *
+     * Whether the employee deduction amount should be treated as a percentage to be deducted from each payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deduct_as_percentage: Option<bool>,
     /**
      * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduction_reduces_taxable_income: Option<DeductionReducesTaxableIncome>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub deduction_reduces_taxable_income: crate::utils::Patch<DeductionReducesTaxableIncome>,
     /**
-     *
+     * The amount to be deducted, per pay period, from the employee's pay.
      */
     #[serde(
         default,
@@ -4945,39 +5185,31 @@ pub struct PostEmployeeBenefitsRequest {
     )]
     pub employee_deduction: String,
     /**
-     *
+     * The maximum employee deduction amount per year. A null value signifies no limit.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_deduction_annual_maximum: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub employee_deduction_annual_maximum: crate::utils::Patch<String>,
     /**
-     *
+     * Some benefits require additional information to determine their limit. For example, for an HSA benefit, the limit option should be either "Family" or "Individual". For a Dependent Care FSA benefit, the limit option should be either "Joint Filing or Single" or "Married and Filing Separately".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub limit_option: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub limit_option: crate::utils::Patch<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutEmployeeBenefitRequest {
     /**
-     * Whether the employee is terminated.
+     * Whether the employee benefit is active.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub active: Option<bool>,
     /**
-     * Whether the employee is terminated.
+     * Whether the employee should use a benefit’s "catch up" rate. Only Roth 401k and 401k benefits use this value for employees over 50.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub catch_up: Option<bool>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The amount to be paid, per pay period, by the company.
      */
     #[serde(
         default,
@@ -4986,30 +5218,22 @@ pub struct PutEmployeeBenefitRequest {
     )]
     pub company_contribution: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The maximum company contribution amount per year. A null value signifies no limit.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_contribution_annual_maximum: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub company_contribution_annual_maximum: crate::utils::Patch<String>,
     /**
-     * Whether the employee is terminated.
+     * Whether the company contribution amount should be treated as a percentage to be deducted from each payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contribute_as_percentage: Option<bool>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The amount that the employee is insured for. Note: company contribution cannot be present if coverage amount is set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub coverage_amount: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub coverage_amount: crate::utils::Patch<String>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The coverage amount as a multiple of the employee’s salary. Only applicable for Group Term Life benefits. Note: cannot be set if coverage amount is also set.
      */
     #[serde(
         default,
@@ -5018,17 +5242,17 @@ pub struct PutEmployeeBenefitRequest {
     )]
     pub coverage_salary_multiplier: String,
     /**
-     * Whether the employee is terminated.
+     * Whether the employee deduction amount should be treated as a percentage to be deducted from each payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deduct_as_percentage: Option<bool>,
     /**
      * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduction_reduces_taxable_income: Option<DeductionReducesTaxableIncome>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub deduction_reduces_taxable_income: crate::utils::Patch<DeductionReducesTaxableIncome>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The amount to be deducted, per pay period, from the employee's pay.
      */
     #[serde(
         default,
@@ -5037,25 +5261,17 @@ pub struct PutEmployeeBenefitRequest {
     )]
     pub employee_deduction: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The maximum employee deduction amount per year. A null value signifies no limit.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_deduction_annual_maximum: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub employee_deduction_annual_maximum: crate::utils::Patch<String>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * Some benefits require additional information to determine their limit. For example, for an HSA benefit, the limit option should be either "Family" or "Individual". For a Dependent Care FSA benefit, the limit option should be either "Joint Filing or Single" or "Married and Filing Separately".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub limit_option: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub limit_option: crate::utils::Patch<String>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for information on how to use this field.
      */
     #[serde(
         default,
@@ -5066,6 +5282,7 @@ pub struct PutEmployeeBenefitRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetCompanyPayrollsInclude {
     #[serde(rename = "benefits")]
     Benefits,
@@ -5075,8 +5292,9 @@ pub enum GetCompanyPayrollsInclude {
     Taxes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCompanyPayrollsInclude {
@@ -5086,12 +5304,33 @@ impl std::fmt::Display for GetCompanyPayrollsInclude {
             GetCompanyPayrollsInclude::Deductions => "deductions",
             GetCompanyPayrollsInclude::Taxes => "taxes",
             GetCompanyPayrollsInclude::Noop => "",
-            GetCompanyPayrollsInclude::FallthroughString => "*",
+            GetCompanyPayrollsInclude::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetCompanyPayrollsInclude {
+    fn from(s: String) -> GetCompanyPayrollsInclude {
+        match s.as_str() {
+            "benefits" => GetCompanyPayrollsInclude::Benefits,
+            "deductions" => GetCompanyPayrollsInclude::Deductions,
+            "taxes" => GetCompanyPayrollsInclude::Taxes,
+            "" => GetCompanyPayrollsInclude::Noop,
+            _ => GetCompanyPayrollsInclude::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetCompanyPayrollsInclude> for String {
+    fn from(e: GetCompanyPayrollsInclude) -> String {
+        match e {
+            GetCompanyPayrollsInclude::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetCompanyPayrollsInclude {
     fn default() -> GetCompanyPayrollsInclude {
         GetCompanyPayrollsInclude::Noop
@@ -5104,6 +5343,7 @@ impl GetCompanyPayrollsInclude {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OffCycleReason {
     #[serde(rename = "Bonus")]
     Bonus,
@@ -5111,8 +5351,9 @@ pub enum OffCycleReason {
     Correction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OffCycleReason {
@@ -5121,12 +5362,32 @@ impl std::fmt::Display for OffCycleReason {
             OffCycleReason::Bonus => "Bonus",
             OffCycleReason::Correction => "Correction",
             OffCycleReason::Noop => "",
-            OffCycleReason::FallthroughString => "*",
+            OffCycleReason::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OffCycleReason {
+    fn from(s: String) -> OffCycleReason {
+        match s.as_str() {
+            "Bonus" => OffCycleReason::Bonus,
+            "Correction" => OffCycleReason::Correction,
+            "" => OffCycleReason::Noop,
+            _ => OffCycleReason::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OffCycleReason> for String {
+    fn from(e: OffCycleReason) -> String {
+        match e {
+            OffCycleReason::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OffCycleReason {
     fn default() -> OffCycleReason {
         OffCycleReason::Noop
@@ -5158,9 +5419,6 @@ pub struct PostCompanyPayrollsRequest {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub end_date: Option<chrono::NaiveDate>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5181,7 +5439,7 @@ pub struct PostCompanyPayrollsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
     /**
-     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+     * The amount of the compensation for the pay period.
      */
     #[serde(
         default,
@@ -5190,7 +5448,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
     )]
     pub amount: String,
     /**
-     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+     * The ID of the job for the compensation.
      */
     #[serde(
         default,
@@ -5199,7 +5457,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
     )]
     pub job_id: i64,
     /**
-     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+     * The name of the compensation. This also serves as the unique, immutable identifier for this compensation.
      */
     #[serde(
         default,
@@ -5213,7 +5471,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyPayrollsRequestEmployeeCompensationsHourly {
     /**
-     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+     * The number of hours to be compensated for this pay period.
      */
     #[serde(
         default,
@@ -5222,7 +5480,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsHourly {
     )]
     pub hours: String,
     /**
-     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+     * The ID of the job for the compensation.
      */
     #[serde(
         default,
@@ -5231,7 +5489,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsHourly {
     )]
     pub job_id: i64,
     /**
-     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+     * The name of the compensation. This also serves as the unique, immutable identifier for this compensation.
      */
     #[serde(
         default,
@@ -5245,7 +5503,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensationsHourly {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyPayrollsRequestEmployeeCompensations {
     /**
-     * The unique identifier of the location in Gusto.
+     * The ID of the employee.
      */
     #[serde(
         default,
@@ -5253,18 +5511,12 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensations {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub employee_id: i64,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub fixed_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensationsFixed>,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5272,7 +5524,7 @@ pub struct PutCompanyPayrollsRequestEmployeeCompensations {
     )]
     pub hourly_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensationsHourly>,
     /**
-     *
+     * An array of all paid time off the employee is eligible for this pay period.
      */
     #[serde(
         default,
@@ -5291,7 +5543,7 @@ pub struct PutCompanyPayrollsRequest {
     )]
     pub employee_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensations>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for details using this field.
      */
     #[serde(
         default,
@@ -5305,7 +5557,7 @@ pub struct PutCompanyPayrollsRequest {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct User {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The email of the user who will be the primary payroll admin.
      */
     #[serde(
         default,
@@ -5314,7 +5566,7 @@ pub struct User {
     )]
     pub email: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The first name of the user who will be the primary payroll admin.
      */
     #[serde(
         default,
@@ -5323,7 +5575,7 @@ pub struct User {
     )]
     pub first_name: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The last name of the user who will be the primary payroll admin.
      */
     #[serde(
         default,
@@ -5332,7 +5584,7 @@ pub struct User {
     )]
     pub last_name: String,
     /**
-     * Information for the user who will be the primary payroll administrator for the new company.
+     * The phone number of the user who will be the primary payroll admin.
      */
     #[serde(
         default,
@@ -5345,7 +5597,7 @@ pub struct User {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostPartnerManagedCompaniesRequestCompany {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The employer identification number (EIN) of the company.
      */
     #[serde(
         default,
@@ -5354,7 +5606,7 @@ pub struct PostPartnerManagedCompaniesRequestCompany {
     )]
     pub ein: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The legal name of the company.
      */
     #[serde(
         default,
@@ -5363,7 +5615,7 @@ pub struct PostPartnerManagedCompaniesRequestCompany {
     )]
     pub name: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The name of the company.
      */
     #[serde(
         default,
@@ -5389,7 +5641,7 @@ pub struct PostPartnerManagedCompaniesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostPartnerManagedCompaniesResponse {
     /**
-     *
+     * Access token that can be used for OAuth access to the account. Access tokens expire 2 hours after they are issued.
      */
     #[serde(
         default,
@@ -5398,7 +5650,7 @@ pub struct PostPartnerManagedCompaniesResponse {
     )]
     pub access_token: String,
     /**
-     *
+     * Gusto’s UUID for the company
      */
     #[serde(
         default,
@@ -5407,7 +5659,7 @@ pub struct PostPartnerManagedCompaniesResponse {
     )]
     pub company_uuid: String,
     /**
-     *
+     * Refresh token that can be exchanged for a new access token.
      */
     #[serde(
         default,
@@ -5419,9 +5671,6 @@ pub struct PostPartnerManagedCompaniesResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Addresses {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5429,7 +5678,7 @@ pub struct Addresses {
     )]
     pub city: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * Whether or not this is a primary address for the company. If set to true, the address will be used as the mailing and filing address for the company and will be added as a work location. If set to false or not included, the address will only be added as a work location for the company. If multiple addresses are included, only one should be marked as primary.
      */
     #[serde(
         default,
@@ -5437,45 +5686,30 @@ pub struct Addresses {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub is_primary: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_1: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_2: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5496,7 +5730,7 @@ pub struct PostProvisionRequestCompany {
     )]
     pub addresses: Vec<Addresses>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The employer identification number (EIN) of the company.
      */
     #[serde(
         default,
@@ -5505,7 +5739,7 @@ pub struct PostProvisionRequestCompany {
     )]
     pub ein: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The legal name of the company.
      */
     #[serde(
         default,
@@ -5514,7 +5748,7 @@ pub struct PostProvisionRequestCompany {
     )]
     pub name: String,
     /**
-     * The ID of the employee in Gusto.
+     * The number of employees in the company.
      */
     #[serde(
         default,
@@ -5523,7 +5757,7 @@ pub struct PostProvisionRequestCompany {
     )]
     pub number_employees: f64,
     /**
-     * An array of options for fields of type radio. Otherwise, null.
+     * The states in which the company operates. States should be included by their two letter code, i.e. NY for New York.
      */
     #[serde(
         default,
@@ -5532,7 +5766,7 @@ pub struct PostProvisionRequestCompany {
     )]
     pub states: Vec<String>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The name of the company.
      */
     #[serde(
         default,
@@ -5558,7 +5792,7 @@ pub struct PostProvisionRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostProvisionResponse {
     /**
-     *
+     * A URL where the user should be redirected to complete their account setup inside of Gusto.
      */
     #[serde(
         default,
@@ -5570,9 +5804,6 @@ pub struct PostProvisionResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetEmployeeCustomFieldsResponse {
-    /**
-     * Custom fields are only included for the employee if the include param has the custom_fields value set
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5595,6 +5826,7 @@ pub struct GetCompanyCustomFieldsResponse {
 * Must be "Employee" if send_offer is set to true.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OnboardingPersonType {
     #[serde(rename = "Contractor")]
     Contractor,
@@ -5602,8 +5834,9 @@ pub enum OnboardingPersonType {
     Employee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OnboardingPersonType {
@@ -5612,12 +5845,32 @@ impl std::fmt::Display for OnboardingPersonType {
             OnboardingPersonType::Contractor => "Contractor",
             OnboardingPersonType::Employee => "Employee",
             OnboardingPersonType::Noop => "",
-            OnboardingPersonType::FallthroughString => "*",
+            OnboardingPersonType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OnboardingPersonType {
+    fn from(s: String) -> OnboardingPersonType {
+        match s.as_str() {
+            "Contractor" => OnboardingPersonType::Contractor,
+            "Employee" => OnboardingPersonType::Employee,
+            "" => OnboardingPersonType::Noop,
+            _ => OnboardingPersonType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OnboardingPersonType> for String {
+    fn from(e: OnboardingPersonType) -> String {
+        match e {
+            OnboardingPersonType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OnboardingPersonType {
     fn default() -> OnboardingPersonType {
         OnboardingPersonType::Noop
@@ -5632,42 +5885,22 @@ impl OnboardingPersonType {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyJobApplicantsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub date_of_birth: crate::utils::Patch<chrono::NaiveDate>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_title: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub job_title: crate::utils::Patch<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5677,106 +5910,52 @@ pub struct PostCompanyJobApplicantsRequest {
     /**
      * Must be "Employee" if send_offer is set to true.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub onboarding_person_type: Option<OnboardingPersonType>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub onboarding_person_type: crate::utils::Patch<OnboardingPersonType>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub phone: crate::utils::Patch<String>,
     /**
-     *
+     * Required if onboarding_person_type is set to "Employee".
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub send_offer: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub send_offer: crate::utils::Patch<bool>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub start_date: crate::utils::Patch<chrono::NaiveDate>,
 }
 
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyJobApplicantRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_title: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub date_of_birth: crate::utils::Patch<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub email: crate::utils::Patch<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub first_name: crate::utils::Patch<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub job_title: crate::utils::Patch<String>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub last_name: crate::utils::Patch<String>,
     /**
      * Must be "Employee" if send_offer is set to true.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub onboarding_person_type: Option<OnboardingPersonType>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub onboarding_person_type: crate::utils::Patch<OnboardingPersonType>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub phone: crate::utils::Patch<String>,
     /**
-     *
+     * Required if onboarding_person_type is set to "Employee".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub send_offer: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub send_offer: crate::utils::Patch<bool>,
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub start_date: crate::utils::Patch<chrono::NaiveDate>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCompanyPayrollReversalsResponse {
     /**
-     * A unique identifier of the employee in Gusto.
+     * Timestamp of when the reversal was approved.
      */
     #[serde(
         default,
@@ -5785,7 +5964,7 @@ pub struct GetCompanyPayrollReversalsResponse {
     )]
     pub approved_at: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * Category chosen by the admin who requested the reversal.
      */
     #[serde(
         default,
@@ -5794,7 +5973,7 @@ pub struct GetCompanyPayrollReversalsResponse {
     )]
     pub category: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * A reason provided by the admin who created the reversal.
      */
     #[serde(
         default,
@@ -5803,7 +5982,7 @@ pub struct GetCompanyPayrollReversalsResponse {
     )]
     pub reason: String,
     /**
-     * The unique identifier of the location in Gusto.
+     * The payroll where the reversal was applied.
      */
     #[serde(
         default,
@@ -5811,6 +5990,9 @@ pub struct GetCompanyPayrollReversalsResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub reversal_payroll_id: i64,
+    /**
+     * Array of employee ids affected.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5818,7 +6000,7 @@ pub struct GetCompanyPayrollReversalsResponse {
     )]
     pub reversed_employee_ids: Vec<i64>,
     /**
-     * The unique identifier of the location in Gusto.
+     * The payroll run being reversed.
      */
     #[serde(
         default,
@@ -5832,7 +6014,7 @@ pub struct GetCompanyPayrollReversalsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCompanyAdminsRequest {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The last name of the admin.
      */
     #[serde(
         default,
@@ -5841,7 +6023,7 @@ pub struct PostCompanyAdminsRequest {
     )]
     pub email: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The email of the admin. This will be used for the admin to log in to their account. If the email matches an existing user, this will create an admin account for them.
      */
     #[serde(
         default,
@@ -5850,7 +6032,7 @@ pub struct PostCompanyAdminsRequest {
     )]
     pub first_name: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The first name of the admin.
      */
     #[serde(
         default,
@@ -5863,7 +6045,7 @@ pub struct PostCompanyAdminsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCompanyFederalTaxDetailsResponse {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The company's EIN
      */
     #[serde(
         default,
@@ -5872,7 +6054,7 @@ pub struct GetCompanyFederalTaxDetailsResponse {
     )]
     pub ein: String,
     /**
-     * Whether the employee is terminated.
+     * Whether the EIN was able to be verified as a valid EIN with the IRS.
      */
     #[serde(
         default,
@@ -5880,7 +6062,9 @@ pub struct GetCompanyFederalTaxDetailsResponse {
     )]
     pub ein_verified: bool,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The form used by the company for federal tax filing. One of:
+     *  - 941 (Quarterly federal tax return form)
+     *  - 944 (Annual federal tax return form)
      */
     #[serde(
         default,
@@ -5889,7 +6073,7 @@ pub struct GetCompanyFederalTaxDetailsResponse {
     )]
     pub filing_form: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The legal name of the company
      */
     #[serde(
         default,
@@ -5898,7 +6082,19 @@ pub struct GetCompanyFederalTaxDetailsResponse {
     )]
     pub legal_name: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * What type of tax entity the company is. One of:
+     *  - C-Corporation
+     *  - S-Corporation
+     *  - Sole proprietor
+     *  - LLC
+     *  - LLP
+     *  - Limited partnership
+     *  - Co-ownership
+     *  - Association
+     *  - Trusteeship
+     *  - General partnership
+     *  - Joint venture
+     *  - Non-Profit
      */
     #[serde(
         default,
@@ -5907,7 +6103,10 @@ pub struct GetCompanyFederalTaxDetailsResponse {
     )]
     pub tax_payer_type: String,
     /**
-     * Whether the employee is terminated.
+     * Whether the company is taxed as an S-Corporation. Tax payer types that may be taxed as an S-Corporation include:
+     *  - S-Corporation
+     *  - C-Corporation
+     *  - LLC
      */
     #[serde(
         default,
@@ -5915,7 +6114,7 @@ pub struct GetCompanyFederalTaxDetailsResponse {
     )]
     pub taxable_as_scorp: bool,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for details using this field.
      */
     #[serde(
         default,
@@ -5928,7 +6127,7 @@ pub struct GetCompanyFederalTaxDetailsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyFederalTaxDetailsRequest {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The EIN of of the company
      */
     #[serde(
         default,
@@ -5937,7 +6136,9 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     )]
     pub ein: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The form used by the company for federal tax filing. One of:
+     *  - 941 (Quarterly federal tax return)
+     *  - 944 (Annual federal tax return)
      */
     #[serde(
         default,
@@ -5946,7 +6147,7 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     )]
     pub filing_form: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The legal name of the company
      */
     #[serde(
         default,
@@ -5955,7 +6156,7 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     )]
     pub legal_name: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * What type of tax entity the company is
      */
     #[serde(
         default,
@@ -5964,12 +6165,12 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     )]
     pub tax_payer_type: String,
     /**
-     * Whether the employee is terminated.
+     * Whether this company should be taxed as an S-Corporation
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub taxable_as_scorp: Option<bool>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The current version of the object. See the versioning guide for details using this field.
      */
     #[serde(
         default,
@@ -28,6 +28,23 @@ impl ActivityFeed {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::GetActivityFeedChimpChatterResponse> {
+        Ok(self
+            .get_chimp_chatter_with_response(count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Get latest chimp chatter.
+     *
+     * This function performs a `GET` to the `/activity-feed/chimp-chatter` endpoint.
+     *
+     * As opposed to `get_chimp_chatter`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_chimp_chatter_with_response(
+        &self,
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetActivityFeedChimpChatterResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -40,7 +57,7 @@ impl ActivityFeed {
             .client
             .url(&format!("/activity-feed/chimp-chatter?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -32,6 +32,25 @@ impl AuthorizedApps {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::GetAuthorizedAppsResponse> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List authorized apps.
+     *
+     * This function performs a `GET` to the `/authorized-apps` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAuthorizedAppsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -50,7 +69,7 @@ impl AuthorizedApps {
             .client
             .url(&format!("/authorized-apps?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -78,6 +97,24 @@ impl AuthorizedApps {
         exclude_fields: &[String],
         app_id: &str,
     ) -> ClientResult<crate::types::Apps> {
+        Ok(self
+            .get_authorized_apps_with_response(fields, exclude_fields, app_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get authorized app info.
+     *
+     * This function performs a `GET` to the `/authorized-apps/{app_id}` endpoint.
+     *
+     * As opposed to `get_authorized_apps`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_authorized_apps_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        app_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Apps>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -95,7 +132,7 @@ impl AuthorizedApps {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
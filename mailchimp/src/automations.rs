@@ -42,6 +42,40 @@ impl Automations {
         since_start_time: Option<chrono::DateTime<chrono::Utc>>,
         status: crate::types::Status,
     ) -> ClientResult<crate::types::GetAutomationsResponse> {
+        Ok(self
+            .get_with_response(
+                count,
+                offset,
+                fields,
+                exclude_fields,
+                before_create_time,
+                since_create_time,
+                before_start_time,
+                since_start_time,
+                status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List automations.
+     *
+     * This function performs a `GET` to the `/automations` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        status: crate::types::Status,
+    ) -> ClientResult<crate::Response<crate::types::GetAutomationsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = before_create_time {
             query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
@@ -73,7 +107,7 @@ impl Automations {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/automations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -93,9 +127,22 @@ impl Automations {
         &self,
         body: &crate::types::AutomationWorkflow,
     ) -> ClientResult<crate::types::Automations> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add automation.
+     *
+     * This function performs a `POST` to the `/automations` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::AutomationWorkflow,
+    ) -> ClientResult<crate::Response<crate::types::Automations>> {
         let url = self.client.url("/automations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -123,6 +170,24 @@ impl Automations {
         exclude_fields: &[String],
         workflow_id: &str,
     ) -> ClientResult<crate::types::Automations> {
+        Ok(self
+            .get_automations_with_response(fields, exclude_fields, workflow_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get automation info.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}` endpoint.
+     *
+     * As opposed to `get_automations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_automations_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Automations>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -140,7 +205,7 @@ impl Automations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -161,6 +226,21 @@ impl Automations {
      * * `workflow_id: &str` -- The unique id for the Automation workflow.
      */
     pub async fn post_actions_pause_all_email(&self, workflow_id: &str) -> ClientResult<()> {
+        self.post_actions_pause_all_email_with_response(workflow_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Pause automation emails.
+     *
+     * This function performs a `POST` to the `/automations/{workflow_id}/actions/pause-all-emails` endpoint.
+     *
+     * As opposed to `post_actions_pause_all_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_pause_all_email_with_response(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/actions/pause-all-emails",
@@ -169,7 +249,7 @@ impl Automations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -190,6 +270,21 @@ impl Automations {
      * * `workflow_id: &str` -- The unique id for the Automation workflow.
      */
     pub async fn post_actions_start_all_email(&self, workflow_id: &str) -> ClientResult<()> {
+        self.post_actions_start_all_email_with_response(workflow_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Start automation emails.
+     *
+     * This function performs a `POST` to the `/automations/{workflow_id}/actions/start-all-emails` endpoint.
+     *
+     * As opposed to `post_actions_start_all_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_start_all_email_with_response(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/actions/start-all-emails",
@@ -198,7 +293,7 @@ impl Automations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -219,6 +314,20 @@ impl Automations {
      * * `workflow_id: &str` -- The unique id for the Automation workflow.
      */
     pub async fn archive(&self, workflow_id: &str) -> ClientResult<()> {
+        self.archive_with_response(workflow_id).await?;
+        Ok(())
+    }
+    /**
+     * Archive automation.
+     *
+     * This function performs a `POST` to the `/automations/{workflow_id}/actions/archive` endpoint.
+     *
+     * As opposed to `archive`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn archive_with_response(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/actions/archive",
@@ -227,7 +336,7 @@ impl Automations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -251,6 +360,19 @@ impl Automations {
         &self,
         workflow_id: &str,
     ) -> ClientResult<crate::types::AutomationEmails> {
+        Ok(self.get_email_with_response(workflow_id).await?.body)
+    }
+    /**
+     * List automated emails.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}/emails` endpoint.
+     *
+     * As opposed to `get_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_email_with_response(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationEmails>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails",
@@ -259,7 +381,7 @@ impl Automations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -285,6 +407,23 @@ impl Automations {
         workflow_id: &str,
         workflow_email_id: &str,
     ) -> ClientResult<crate::types::Emails> {
+        Ok(self
+            .get_email_automations_with_response(workflow_id, workflow_email_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get workflow email info.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}/emails/{workflow_email_id}` endpoint.
+     *
+     * As opposed to `get_email_automations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_email_automations_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Emails>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}",
@@ -294,7 +433,7 @@ impl Automations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -320,6 +459,22 @@ impl Automations {
         workflow_id: &str,
         workflow_email_id: &str,
     ) -> ClientResult<()> {
+        self.delete_emails_with_response(workflow_id, workflow_email_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete workflow email.
+     *
+     * This function performs a `DELETE` to the `/automations/{workflow_id}/emails/{workflow_email_id}` endpoint.
+     *
+     * As opposed to `delete_emails`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_emails_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}",
@@ -329,7 +484,7 @@ impl Automations {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -356,6 +511,24 @@ impl Automations {
         workflow_email_id: &str,
         body: &crate::types::UpdateInformationAboutASpecificWorkflowEmail,
     ) -> ClientResult<crate::types::Emails> {
+        Ok(self
+            .patch_email_workflow_with_response(workflow_id, workflow_email_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update workflow email.
+     *
+     * This function performs a `PATCH` to the `/automations/{workflow_id}/emails/{workflow_email_id}` endpoint.
+     *
+     * As opposed to `patch_email_workflow`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_email_workflow_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::UpdateInformationAboutASpecificWorkflowEmail,
+    ) -> ClientResult<crate::Response<crate::types::Emails>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}",
@@ -365,7 +538,7 @@ impl Automations {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -391,6 +564,23 @@ impl Automations {
         workflow_id: &str,
         workflow_email_id: &str,
     ) -> ClientResult<crate::types::GetAutomationsEmailsQueueResponse> {
+        Ok(self
+            .get_emails_queue_with_response(workflow_id, workflow_email_id)
+            .await?
+            .body)
+    }
+    /**
+     * List automated email subscribers.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}/emails/{workflow_email_id}/queue` endpoint.
+     *
+     * As opposed to `get_emails_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_emails_queue_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAutomationsEmailsQueueResponse>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}/queue",
@@ -400,7 +590,7 @@ impl Automations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -427,6 +617,24 @@ impl Automations {
         workflow_email_id: &str,
         body: &crate::types::SubscriberInAutomationQueue,
     ) -> ClientResult<crate::types::SubscriberInAutomationQueueData> {
+        Ok(self
+            .post_emails_queue_with_response(workflow_id, workflow_email_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add subscriber to workflow email.
+     *
+     * This function performs a `POST` to the `/automations/{workflow_id}/emails/{workflow_email_id}/queue` endpoint.
+     *
+     * As opposed to `post_emails_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_emails_queue_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<crate::types::SubscriberInAutomationQueueData>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}/queue",
@@ -436,7 +644,7 @@ impl Automations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -464,6 +672,28 @@ impl Automations {
         workflow_email_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<crate::types::SubscriberInAutomationQueueData> {
+        Ok(self
+            .get_emails_queue_automations_with_response(
+                workflow_id,
+                workflow_email_id,
+                subscriber_hash,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get automated email subscriber.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}/emails/{workflow_email_id}/queue/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_emails_queue_automations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_emails_queue_automations_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriberInAutomationQueueData>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}/queue/{}",
@@ -474,7 +704,7 @@ impl Automations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -500,6 +730,22 @@ impl Automations {
         workflow_id: &str,
         workflow_email_id: &str,
     ) -> ClientResult<()> {
+        self.post_emails_actions_pause_with_response(workflow_id, workflow_email_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Pause automated email.
+     *
+     * This function performs a `POST` to the `/automations/{workflow_id}/emails/{workflow_email_id}/actions/pause` endpoint.
+     *
+     * As opposed to `post_emails_actions_pause`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_emails_actions_pause_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}/actions/pause",
@@ -509,7 +755,7 @@ impl Automations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -535,6 +781,22 @@ impl Automations {
         workflow_id: &str,
         workflow_email_id: &str,
     ) -> ClientResult<()> {
+        self.post_emails_actions_start_with_response(workflow_id, workflow_email_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Start automated email.
+     *
+     * This function performs a `POST` to the `/automations/{workflow_id}/emails/{workflow_email_id}/actions/start` endpoint.
+     *
+     * As opposed to `post_emails_actions_start`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_emails_actions_start_with_response(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/emails/{}/actions/start",
@@ -544,7 +806,7 @@ impl Automations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -568,6 +830,22 @@ impl Automations {
         &self,
         workflow_id: &str,
     ) -> ClientResult<crate::types::RemovedSubscribers> {
+        Ok(self
+            .get_removed_subscriber_with_response(workflow_id)
+            .await?
+            .body)
+    }
+    /**
+     * List subscribers removed from workflow.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}/removed-subscribers` endpoint.
+     *
+     * As opposed to `get_removed_subscriber`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_removed_subscriber_with_response(
+        &self,
+        workflow_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RemovedSubscribers>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/removed-subscribers",
@@ -576,7 +854,7 @@ impl Automations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -601,6 +879,23 @@ impl Automations {
         workflow_id: &str,
         body: &crate::types::SubscriberInAutomationQueue,
     ) -> ClientResult<crate::types::Subscribers> {
+        Ok(self
+            .post_removed_subscriber_with_response(workflow_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove subscriber from workflow.
+     *
+     * This function performs a `POST` to the `/automations/{workflow_id}/removed-subscribers` endpoint.
+     *
+     * As opposed to `post_removed_subscriber`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_removed_subscriber_with_response(
+        &self,
+        workflow_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<crate::types::Subscribers>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/removed-subscribers",
@@ -609,7 +904,7 @@ impl Automations {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -635,6 +930,23 @@ impl Automations {
         workflow_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<crate::types::Subscribers> {
+        Ok(self
+            .get_removed_subscriber_automations_with_response(workflow_id, subscriber_hash)
+            .await?
+            .body)
+    }
+    /**
+     * Get subscriber removed from workflow.
+     *
+     * This function performs a `GET` to the `/automations/{workflow_id}/removed-subscribers/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_removed_subscriber_automations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_removed_subscriber_automations_with_response(
+        &self,
+        workflow_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscribers>> {
         let url = self.client.url(
             &format!(
                 "/automations/{}/removed-subscribers/{}",
@@ -644,7 +956,7 @@ impl Automations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -32,6 +32,25 @@ impl BatchWebhooks {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::BatchWebhooks> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List batch webhooks.
+     *
+     * This function performs a `GET` to the `/batch-webhooks` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::BatchWebhooks>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -50,7 +69,7 @@ impl BatchWebhooks {
             .client
             .url(&format!("/batch-webhooks?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,9 +89,22 @@ impl BatchWebhooks {
         &self,
         body: &crate::types::BatchWebhook,
     ) -> ClientResult<crate::types::Webhooks> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add batch webhook.
+     *
+     * This function performs a `POST` to the `/batch-webhooks` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::BatchWebhook,
+    ) -> ClientResult<crate::Response<crate::types::Webhooks>> {
         let url = self.client.url("/batch-webhooks", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -100,6 +132,24 @@ impl BatchWebhooks {
         exclude_fields: &[String],
         batch_webhook_id: &str,
     ) -> ClientResult<crate::types::Webhooks> {
+        Ok(self
+            .get_batch_webhooks_with_response(fields, exclude_fields, batch_webhook_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get batch webhook info.
+     *
+     * This function performs a `GET` to the `/batch-webhooks/{batch_webhook_id}` endpoint.
+     *
+     * As opposed to `get_batch_webhooks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_batch_webhooks_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_webhook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Webhooks>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -117,7 +167,7 @@ impl BatchWebhooks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -138,6 +188,20 @@ impl BatchWebhooks {
      * * `batch_webhook_id: &str` -- The unique id for the batch webhook.
      */
     pub async fn delete(&self, batch_webhook_id: &str) -> ClientResult<()> {
+        self.delete_with_response(batch_webhook_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete batch webhook.
+     *
+     * This function performs a `DELETE` to the `/batch-webhooks/{batch_webhook_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        batch_webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/batch-webhooks/{}",
@@ -146,7 +210,7 @@ impl BatchWebhooks {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -171,6 +235,20 @@ impl BatchWebhooks {
         batch_webhook_id: &str,
         body: &crate::types::BatchWebhook,
     ) -> ClientResult<crate::types::Webhooks> {
+        Ok(self.patch_with_response(batch_webhook_id, body).await?.body)
+    }
+    /**
+     * Update batch webhook.
+     *
+     * This function performs a `PATCH` to the `/batch-webhooks/{batch_webhook_id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        batch_webhook_id: &str,
+        body: &crate::types::BatchWebhook,
+    ) -> ClientResult<crate::Response<crate::types::Webhooks>> {
         let url = self.client.url(
             &format!(
                 "/batch-webhooks/{}",
@@ -179,7 +257,7 @@ impl BatchWebhooks {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -32,6 +32,25 @@ impl Batches {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::BatchOperations> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List batch requests.
+     *
+     * This function performs a `GET` to the `/batches` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::BatchOperations>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -48,7 +67,7 @@ impl Batches {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/batches?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,9 +87,22 @@ impl Batches {
         &self,
         body: &crate::types::PostBatchesRequest,
     ) -> ClientResult<crate::types::Batch> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Start batch operation.
+     *
+     * This function performs a `POST` to the `/batches` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostBatchesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Batch>> {
         let url = self.client.url("/batches", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -98,6 +130,24 @@ impl Batches {
         exclude_fields: &[String],
         batch_id: &str,
     ) -> ClientResult<crate::types::Batch> {
+        Ok(self
+            .get_batches_with_response(fields, exclude_fields, batch_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get batch operation status.
+     *
+     * This function performs a `GET` to the `/batches/{batch_id}` endpoint.
+     *
+     * As opposed to `get_batches`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_batches_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Batch>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -115,7 +165,7 @@ impl Batches {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -136,6 +186,17 @@ impl Batches {
      * * `batch_id: &str` -- The unique id for the batch operation.
      */
     pub async fn delete(&self, batch_id: &str) -> ClientResult<()> {
+        self.delete_with_response(batch_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete batch request.
+     *
+     * This function performs a `DELETE` to the `/batches/{batch_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, batch_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/batches/{}",
@@ -144,7 +205,7 @@ impl Batches {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
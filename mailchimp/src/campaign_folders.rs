@@ -32,6 +32,25 @@ impl CampaignFolders {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::CampaignFolders> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List campaign folders.
+     *
+     * This function performs a `GET` to the `/campaign-folders` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolders>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -50,7 +69,7 @@ impl CampaignFolders {
             .client
             .url(&format!("/campaign-folders?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,9 +89,22 @@ impl CampaignFolders {
         &self,
         body: &crate::types::GalleryFolder,
     ) -> ClientResult<crate::types::CampaignFolder> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add campaign folder.
+     *
+     * This function performs a `POST` to the `/campaign-folders` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
         let url = self.client.url("/campaign-folders", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -100,6 +132,24 @@ impl CampaignFolders {
         exclude_fields: &[String],
         folder_id: &str,
     ) -> ClientResult<crate::types::CampaignFolder> {
+        Ok(self
+            .get_campaign_folders_with_response(fields, exclude_fields, folder_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign folder.
+     *
+     * This function performs a `GET` to the `/campaign-folders/{folder_id}` endpoint.
+     *
+     * As opposed to `get_campaign_folders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_campaign_folders_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -117,7 +167,7 @@ impl CampaignFolders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -138,6 +188,17 @@ impl CampaignFolders {
      * * `folder_id: &str` -- The unique id for the campaign folder.
      */
     pub async fn delete(&self, folder_id: &str) -> ClientResult<()> {
+        self.delete_with_response(folder_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete campaign folder.
+     *
+     * This function performs a `DELETE` to the `/campaign-folders/{folder_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, folder_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaign-folders/{}",
@@ -146,7 +207,7 @@ impl CampaignFolders {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -171,6 +232,20 @@ impl CampaignFolders {
         folder_id: &str,
         body: &crate::types::GalleryFolder,
     ) -> ClientResult<crate::types::CampaignFolder> {
+        Ok(self.patch_with_response(folder_id, body).await?.body)
+    }
+    /**
+     * Update campaign folder.
+     *
+     * This function performs a `PATCH` to the `/campaign-folders/{folder_id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFolder>> {
         let url = self.client.url(
             &format!(
                 "/campaign-folders/{}",
@@ -179,7 +254,7 @@ impl CampaignFolders {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
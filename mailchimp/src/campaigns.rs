@@ -54,6 +54,52 @@ impl Campaigns {
         sort_field: crate::types::SortField,
         sort_dir: crate::types::SortDir,
     ) -> ClientResult<crate::types::GetCampaignsResponse> {
+        Ok(self
+            .get_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                type_,
+                status,
+                before_send_time,
+                since_send_time,
+                before_create_time,
+                since_create_time,
+                list_id,
+                folder_id,
+                member_id,
+                sort_field,
+                sort_dir,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List campaigns.
+     *
+     * This function performs a `GET` to the `/campaigns` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        status: crate::types::GetCampaignsStatus,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        list_id: &str,
+        folder_id: &str,
+        member_id: &str,
+        sort_field: crate::types::SortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = before_create_time {
             query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
@@ -103,7 +149,7 @@ impl Campaigns {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/campaigns?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -123,9 +169,22 @@ impl Campaigns {
         &self,
         body: &crate::types::CreatedCampaign,
     ) -> ClientResult<crate::types::Campaign> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add campaign.
+     *
+     * This function performs a `POST` to the `/campaigns` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::CreatedCampaign,
+    ) -> ClientResult<crate::Response<crate::types::Campaign>> {
         let url = self.client.url("/campaigns", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -153,6 +212,24 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::Campaign> {
+        Ok(self
+            .get_campaigns_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign info.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `get_campaigns`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_campaigns_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Campaign>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -170,7 +247,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -191,6 +268,20 @@ impl Campaigns {
      * * `campaign_id: &str` -- The unique id for the campaign.
      */
     pub async fn delete(&self, campaign_id: &str) -> ClientResult<()> {
+        self.delete_with_response(campaign_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete campaign.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}",
@@ -199,7 +290,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -224,6 +315,20 @@ impl Campaigns {
         campaign_id: &str,
         body: &crate::types::CampaignData,
     ) -> ClientResult<crate::types::Campaign> {
+        Ok(self.patch_with_response(campaign_id, body).await?.body)
+    }
+    /**
+     * Update campaign settings.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignData,
+    ) -> ClientResult<crate::Response<crate::types::Campaign>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}",
@@ -232,7 +337,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -253,6 +358,21 @@ impl Campaigns {
      * * `campaign_id: &str` -- The unique id for the campaign.
      */
     pub async fn post_actions_cancel_send(&self, campaign_id: &str) -> ClientResult<()> {
+        self.post_actions_cancel_send_with_response(campaign_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Cancel campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/cancel-send` endpoint.
+     *
+     * As opposed to `post_actions_cancel_send`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_cancel_send_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/cancel-send",
@@ -261,7 +381,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -285,6 +405,22 @@ impl Campaigns {
         &self,
         campaign_id: &str,
     ) -> ClientResult<crate::types::CampaignDataType> {
+        Ok(self
+            .post_actions_replicate_with_response(campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Replicate campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/replicate` endpoint.
+     *
+     * As opposed to `post_actions_replicate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_replicate_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignDataType>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/replicate",
@@ -293,7 +429,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -314,6 +450,20 @@ impl Campaigns {
      * * `campaign_id: &str` -- The unique id for the campaign.
      */
     pub async fn post_actions_send(&self, campaign_id: &str) -> ClientResult<()> {
+        self.post_actions_send_with_response(campaign_id).await?;
+        Ok(())
+    }
+    /**
+     * Send campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/send` endpoint.
+     *
+     * As opposed to `post_actions_send`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_send_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/send",
@@ -322,7 +472,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -347,6 +497,22 @@ impl Campaigns {
         campaign_id: &str,
         body: &crate::types::PostCampaignsActionsScheduleRequest,
     ) -> ClientResult<()> {
+        self.post_actions_schedule_with_response(campaign_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Schedule campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/schedule` endpoint.
+     *
+     * As opposed to `post_actions_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_schedule_with_response(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsScheduleRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/schedule",
@@ -355,7 +521,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -376,6 +542,21 @@ impl Campaigns {
      * * `campaign_id: &str` -- The unique id for the campaign.
      */
     pub async fn post_actions_unschedule(&self, campaign_id: &str) -> ClientResult<()> {
+        self.post_actions_unschedule_with_response(campaign_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unschedule campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/unschedule` endpoint.
+     *
+     * As opposed to `post_actions_unschedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_unschedule_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/unschedule",
@@ -384,7 +565,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -409,6 +590,22 @@ impl Campaigns {
         campaign_id: &str,
         body: &crate::types::PostCampaignsActionsTestRequest,
     ) -> ClientResult<()> {
+        self.post_actions_test_with_response(campaign_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Send test email.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/test` endpoint.
+     *
+     * As opposed to `post_actions_test`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_test_with_response(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsTestRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/test",
@@ -417,7 +614,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -438,6 +635,20 @@ impl Campaigns {
      * * `campaign_id: &str` -- The unique id for the campaign.
      */
     pub async fn post_actions_pause(&self, campaign_id: &str) -> ClientResult<()> {
+        self.post_actions_pause_with_response(campaign_id).await?;
+        Ok(())
+    }
+    /**
+     * Pause rss campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/pause` endpoint.
+     *
+     * As opposed to `post_actions_pause`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_pause_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/pause",
@@ -446,7 +657,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -467,6 +678,20 @@ impl Campaigns {
      * * `campaign_id: &str` -- The unique id for the campaign.
      */
     pub async fn post_actions_resume(&self, campaign_id: &str) -> ClientResult<()> {
+        self.post_actions_resume_with_response(campaign_id).await?;
+        Ok(())
+    }
+    /**
+     * Resume rss campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/resume` endpoint.
+     *
+     * As opposed to `post_actions_resume`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_resume_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/resume",
@@ -475,7 +700,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -499,6 +724,22 @@ impl Campaigns {
         &self,
         campaign_id: &str,
     ) -> ClientResult<crate::types::CampaignDataType> {
+        Ok(self
+            .post_actions_create_resend_with_response(campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Resend campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/actions/create-resend` endpoint.
+     *
+     * As opposed to `post_actions_create_resend`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_create_resend_with_response(
+        &self,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignDataType>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/actions/create-resend",
@@ -507,7 +748,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -535,6 +776,24 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::CampaignContent> {
+        Ok(self
+            .get_content_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign content.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/content` endpoint.
+     *
+     * As opposed to `get_content`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_content_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignContent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -552,7 +811,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -577,6 +836,23 @@ impl Campaigns {
         campaign_id: &str,
         body: &crate::types::CampaignContentData,
     ) -> ClientResult<crate::types::CampaignContent> {
+        Ok(self
+            .put_content_with_response(campaign_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set campaign content.
+     *
+     * This function performs a `PUT` to the `/campaigns/{campaign_id}/content` endpoint.
+     *
+     * As opposed to `put_content`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_content_with_response(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignContentData,
+    ) -> ClientResult<crate::Response<crate::types::CampaignContent>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/content",
@@ -585,7 +861,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -613,6 +889,24 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::CampaignReports> {
+        Ok(self
+            .get_feedback_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List campaign feedback.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/feedback` endpoint.
+     *
+     * As opposed to `get_feedback`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_feedback_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignReports>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -630,7 +924,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -655,6 +949,23 @@ impl Campaigns {
         campaign_id: &str,
         body: &crate::types::CampaignFeedback,
     ) -> ClientResult<crate::types::CampaignFeedbackData> {
+        Ok(self
+            .post_feedback_with_response(campaign_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add campaign feedback.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/feedback` endpoint.
+     *
+     * As opposed to `post_feedback`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_feedback_with_response(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignFeedback,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/feedback",
@@ -663,7 +974,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -693,6 +1004,25 @@ impl Campaigns {
         campaign_id: &str,
         feedback_id: &str,
     ) -> ClientResult<crate::types::CampaignFeedbackData> {
+        Ok(self
+            .get_feedback_campaigns_with_response(fields, exclude_fields, campaign_id, feedback_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign feedback message.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/feedback/{feedback_id}` endpoint.
+     *
+     * As opposed to `get_feedback_campaigns`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_feedback_campaigns_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -711,7 +1041,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -733,6 +1063,22 @@ impl Campaigns {
      * * `feedback_id: &str` -- The unique id for the feedback message.
      */
     pub async fn delete_feedback(&self, campaign_id: &str, feedback_id: &str) -> ClientResult<()> {
+        self.delete_feedback_with_response(campaign_id, feedback_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete campaign feedback message.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}/feedback/{feedback_id}` endpoint.
+     *
+     * As opposed to `delete_feedback`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_feedback_with_response(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/feedback/{}",
@@ -742,7 +1088,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -769,6 +1115,24 @@ impl Campaigns {
         feedback_id: &str,
         body: &crate::types::CampaignFeedbackDataType,
     ) -> ClientResult<crate::types::CampaignFeedbackData> {
+        Ok(self
+            .patch_feedback_with_response(campaign_id, feedback_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update campaign feedback message.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}/feedback/{feedback_id}` endpoint.
+     *
+     * As opposed to `patch_feedback`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_feedback_with_response(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+        body: &crate::types::CampaignFeedbackDataType,
+    ) -> ClientResult<crate::Response<crate::types::CampaignFeedbackData>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/feedback/{}",
@@ -778,7 +1142,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -806,6 +1170,24 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::SendChecklist> {
+        Ok(self
+            .get_send_checklist_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign send checklist.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/send-checklist` endpoint.
+     *
+     * As opposed to `get_send_checklist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_send_checklist_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SendChecklist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -823,7 +1205,7 @@ impl Campaigns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
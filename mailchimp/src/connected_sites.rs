@@ -32,6 +32,25 @@ impl ConnectedSites {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::ConnectedSites> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List connected sites.
+     *
+     * This function performs a `GET` to the `/connected-sites` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::ConnectedSites>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -50,7 +69,7 @@ impl ConnectedSites {
             .client
             .url(&format!("/connected-sites?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,9 +89,22 @@ impl ConnectedSites {
         &self,
         body: &crate::types::ConnectedSite,
     ) -> ClientResult<crate::types::Sites> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add connected site.
+     *
+     * This function performs a `POST` to the `/connected-sites` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::ConnectedSite,
+    ) -> ClientResult<crate::Response<crate::types::Sites>> {
         let url = self.client.url("/connected-sites", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -100,6 +132,24 @@ impl ConnectedSites {
         exclude_fields: &[String],
         connected_site_id: &str,
     ) -> ClientResult<crate::types::Sites> {
+        Ok(self
+            .get_connected_sites_with_response(fields, exclude_fields, connected_site_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get connected site.
+     *
+     * This function performs a `GET` to the `/connected-sites/{connected_site_id}` endpoint.
+     *
+     * As opposed to `get_connected_sites`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_connected_sites_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        connected_site_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Sites>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -117,7 +167,7 @@ impl ConnectedSites {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -138,6 +188,20 @@ impl ConnectedSites {
      * * `connected_site_id: &str` -- The unique identifier for the site.
      */
     pub async fn delete(&self, connected_site_id: &str) -> ClientResult<()> {
+        self.delete_with_response(connected_site_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete connected site.
+     *
+     * This function performs a `DELETE` to the `/connected-sites/{connected_site_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        connected_site_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/connected-sites/{}",
@@ -146,7 +210,7 @@ impl ConnectedSites {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -170,6 +234,21 @@ impl ConnectedSites {
         &self,
         connected_site_id: &str,
     ) -> ClientResult<()> {
+        self.post_actions_verify_script_installation_with_response(connected_site_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Verify connected site script.
+     *
+     * This function performs a `POST` to the `/connected-sites/{connected_site_id}/actions/verify-script-installation` endpoint.
+     *
+     * As opposed to `post_actions_verify_script_installation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_verify_script_installation_with_response(
+        &self,
+        connected_site_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/connected-sites/{}/actions/verify-script-installation",
@@ -178,7 +257,7 @@ impl ConnectedSites {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -38,6 +38,36 @@ impl Conversations {
         list_id: &str,
         campaign_id: &str,
     ) -> ClientResult<crate::types::TrackedConversations> {
+        Ok(self
+            .get_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                has_unread_messages,
+                list_id,
+                campaign_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List conversations.
+     *
+     * This function performs a `GET` to the `/conversations` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        has_unread_messages: crate::types::IsRead,
+        list_id: &str,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrackedConversations>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !campaign_id.is_empty() {
             query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
@@ -66,7 +96,7 @@ impl Conversations {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/conversations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -94,6 +124,24 @@ impl Conversations {
         exclude_fields: &[String],
         conversation_id: &str,
     ) -> ClientResult<crate::types::Conversation> {
+        Ok(self
+            .get_conversations_with_response(fields, exclude_fields, conversation_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get conversation.
+     *
+     * This function performs a `GET` to the `/conversations/{conversation_id}` endpoint.
+     *
+     * As opposed to `get_conversations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_conversations_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Conversation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -111,7 +159,7 @@ impl Conversations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -145,6 +193,34 @@ impl Conversations {
         before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
         since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<crate::types::CollectionOfConversationMessages> {
+        Ok(self
+            .get_message_with_response(
+                fields,
+                exclude_fields,
+                conversation_id,
+                is_read,
+                before_timestamp,
+                since_timestamp,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List messages.
+     *
+     * This function performs a `GET` to the `/conversations/{conversation_id}/messages` endpoint.
+     *
+     * As opposed to `get_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_message_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        is_read: crate::types::IsRead,
+        before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfConversationMessages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = before_timestamp {
             query_args.push(("before_timestamp".to_string(), date.to_rfc3339()));
@@ -171,7 +247,7 @@ impl Conversations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -201,6 +277,30 @@ impl Conversations {
         conversation_id: &str,
         message_id: &str,
     ) -> ClientResult<crate::types::ConversationMessage> {
+        Ok(self
+            .get_message_conversations_with_response(
+                fields,
+                exclude_fields,
+                conversation_id,
+                message_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get message.
+     *
+     * This function performs a `GET` to the `/conversations/{conversation_id}/messages/{message_id}` endpoint.
+     *
+     * As opposed to `get_message_conversations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_message_conversations_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        message_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationMessage>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -219,7 +319,7 @@ impl Conversations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
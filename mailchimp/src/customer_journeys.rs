@@ -29,6 +29,23 @@ impl CustomerJourneys {
         step_id: i64,
         body: &crate::types::SubscriberInAutomationQueue,
     ) -> ClientResult<()> {
+        self.post_steps_actions_trigger_with_response(journey_id, step_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Customer Journeys API trigger for a contact.
+     *
+     * This function performs a `POST` to the `/customer-journeys/journeys/{journey_id}/steps/{step_id}/actions/trigger` endpoint.
+     *
+     * As opposed to `post_steps_actions_trigger`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_steps_actions_trigger_with_response(
+        &self,
+        journey_id: i64,
+        step_id: i64,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/customer-journeys/journeys/{}/steps/{}/actions/trigger",
@@ -38,7 +55,7 @@ impl CustomerJourneys {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
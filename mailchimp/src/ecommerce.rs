@@ -40,6 +40,38 @@ impl Ecommerce {
         customer_id: &str,
         has_outreach: bool,
     ) -> ClientResult<crate::types::OrdersData> {
+        Ok(self
+            .get_order_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                campaign_id,
+                outreach_id,
+                customer_id,
+                has_outreach,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List account orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/orders` endpoint.
+     *
+     * As opposed to `get_order`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_order_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        outreach_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+    ) -> ClientResult<crate::Response<crate::types::OrdersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !campaign_id.is_empty() {
             query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
@@ -70,7 +102,7 @@ impl Ecommerce {
             .client
             .url(&format!("/ecommerce/orders?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -100,6 +132,25 @@ impl Ecommerce {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::ECommerceStores> {
+        Ok(self
+            .get_store_with_response(fields, exclude_fields, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List stores.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores` endpoint.
+     *
+     * As opposed to `get_store`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_store_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceStores>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -118,7 +169,7 @@ impl Ecommerce {
             .client
             .url(&format!("/ecommerce/stores?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -138,9 +189,22 @@ impl Ecommerce {
         &self,
         body: &crate::types::ECommerceStore,
     ) -> ClientResult<crate::types::Stores> {
+        Ok(self.post_store_with_response(body).await?.body)
+    }
+    /**
+     * Add store.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores` endpoint.
+     *
+     * As opposed to `post_store`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_store_with_response(
+        &self,
+        body: &crate::types::ECommerceStore,
+    ) -> ClientResult<crate::Response<crate::types::Stores>> {
         let url = self.client.url("/ecommerce/stores", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -168,6 +232,24 @@ impl Ecommerce {
         exclude_fields: &[String],
         store_id: &str,
     ) -> ClientResult<crate::types::Stores> {
+        Ok(self
+            .get_store_ecommerce_with_response(fields, exclude_fields, store_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get store info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}` endpoint.
+     *
+     * As opposed to `get_store_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_store_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Stores>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -185,7 +267,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -206,6 +288,20 @@ impl Ecommerce {
      * * `store_id: &str` -- The name of the folder.
      */
     pub async fn delete_stores(&self, store_id: &str) -> ClientResult<()> {
+        self.delete_stores_with_response(store_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete store.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}` endpoint.
+     *
+     * As opposed to `delete_stores`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_with_response(
+        &self,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}",
@@ -214,7 +310,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -239,6 +335,20 @@ impl Ecommerce {
         store_id: &str,
         body: &crate::types::ECommerceStoreData,
     ) -> ClientResult<crate::types::Stores> {
+        Ok(self.patch_stores_with_response(store_id, body).await?.body)
+    }
+    /**
+     * Update store.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}` endpoint.
+     *
+     * As opposed to `patch_stores`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_with_response(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceStoreData,
+    ) -> ClientResult<crate::Response<crate::types::Stores>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}",
@@ -247,7 +357,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -279,6 +389,26 @@ impl Ecommerce {
         offset: i64,
         store_id: &str,
     ) -> ClientResult<crate::types::CartsData> {
+        Ok(self
+            .get_stores_cart_with_response(fields, exclude_fields, count, offset, store_id)
+            .await?
+            .body)
+    }
+    /**
+     * List carts.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts` endpoint.
+     *
+     * As opposed to `get_stores_cart`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_cart_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CartsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -302,7 +432,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -327,6 +457,23 @@ impl Ecommerce {
         store_id: &str,
         body: &crate::types::ECommerceCart,
     ) -> ClientResult<crate::types::Carts> {
+        Ok(self
+            .post_stores_cart_with_response(store_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add cart.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/carts` endpoint.
+     *
+     * As opposed to `post_stores_cart`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_cart_with_response(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCart,
+    ) -> ClientResult<crate::Response<crate::types::Carts>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/carts",
@@ -335,7 +482,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -365,6 +512,25 @@ impl Ecommerce {
         store_id: &str,
         cart_id: &str,
     ) -> ClientResult<crate::types::Carts> {
+        Ok(self
+            .get_stores_cart_ecommerce_with_response(fields, exclude_fields, store_id, cart_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get cart info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}` endpoint.
+     *
+     * As opposed to `get_stores_cart_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_cart_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Carts>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -383,7 +549,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -405,6 +571,22 @@ impl Ecommerce {
      * * `cart_id: &str` -- The name of the folder.
      */
     pub async fn delete_stores_carts(&self, store_id: &str, cart_id: &str) -> ClientResult<()> {
+        self.delete_stores_carts_with_response(store_id, cart_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete cart.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/carts/{cart_id}` endpoint.
+     *
+     * As opposed to `delete_stores_carts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_carts_with_response(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/carts/{}",
@@ -414,7 +596,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -441,6 +623,24 @@ impl Ecommerce {
         cart_id: &str,
         body: &crate::types::ECommerceCartData,
     ) -> ClientResult<crate::types::Carts> {
+        Ok(self
+            .patch_stores_carts_with_response(store_id, cart_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update cart.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/carts/{cart_id}` endpoint.
+     *
+     * As opposed to `patch_stores_carts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_carts_with_response(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartData,
+    ) -> ClientResult<crate::Response<crate::types::Carts>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/carts/{}",
@@ -450,7 +650,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -484,6 +684,34 @@ impl Ecommerce {
         store_id: &str,
         cart_id: &str,
     ) -> ClientResult<crate::types::CartLines> {
+        Ok(self
+            .get_stores_carts_line_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                store_id,
+                cart_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List cart line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines` endpoint.
+     *
+     * As opposed to `get_stores_carts_line`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_carts_line_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        cart_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CartLines>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -508,7 +736,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -535,6 +763,24 @@ impl Ecommerce {
         cart_id: &str,
         body: &crate::types::ECommerceCartLineItemData,
     ) -> ClientResult<crate::types::ECommerceCartLineItem> {
+        Ok(self
+            .post_stores_carts_line_with_response(store_id, cart_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add cart line item.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines` endpoint.
+     *
+     * As opposed to `post_stores_carts_line`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_carts_line_with_response(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartLineItemData,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/carts/{}/lines",
@@ -544,7 +790,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -576,6 +822,32 @@ impl Ecommerce {
         cart_id: &str,
         line_id: &str,
     ) -> ClientResult<crate::types::ECommerceCartLineItem> {
+        Ok(self
+            .get_stores_carts_line_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                cart_id,
+                line_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get cart line item.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `get_stores_carts_line_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_carts_line_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -595,7 +867,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -623,6 +895,23 @@ impl Ecommerce {
         cart_id: &str,
         line_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_carts_lines_with_response(store_id, cart_id, line_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete cart line item.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `delete_stores_carts_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_carts_lines_with_response(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/carts/{}/lines/{}",
@@ -633,7 +922,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -662,6 +951,25 @@ impl Ecommerce {
         line_id: &str,
         body: &crate::types::ECommerceCartLineItemDataType,
     ) -> ClientResult<crate::types::ECommerceCartLineItem> {
+        Ok(self
+            .patch_stores_carts_lines_with_response(store_id, cart_id, line_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update cart line item.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `patch_stores_carts_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_carts_lines_with_response(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceCartLineItemDataType,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceCartLineItem>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/carts/{}/lines/{}",
@@ -672,7 +980,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -706,6 +1014,34 @@ impl Ecommerce {
         store_id: &str,
         email_address: &str,
     ) -> ClientResult<crate::types::Customers> {
+        Ok(self
+            .get_stores_customer_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                store_id,
+                email_address,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List customers.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/customers` endpoint.
+     *
+     * As opposed to `get_stores_customer`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_customer_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        email_address: &str,
+    ) -> ClientResult<crate::Response<crate::types::Customers>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -732,7 +1068,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -757,6 +1093,23 @@ impl Ecommerce {
         store_id: &str,
         body: &crate::types::ECommerceCustomerData,
     ) -> ClientResult<crate::types::Customer> {
+        Ok(self
+            .post_stores_customer_with_response(store_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add customer.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/customers` endpoint.
+     *
+     * As opposed to `post_stores_customer`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_customer_with_response(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCustomerData,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/customers",
@@ -765,7 +1118,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -795,6 +1148,30 @@ impl Ecommerce {
         store_id: &str,
         customer_id: &str,
     ) -> ClientResult<crate::types::Customer> {
+        Ok(self
+            .get_stores_customer_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                customer_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get customer info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/customers/{customer_id}` endpoint.
+     *
+     * As opposed to `get_stores_customer_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_customer_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -813,7 +1190,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -840,6 +1217,24 @@ impl Ecommerce {
         customer_id: &str,
         body: &crate::types::ECommerceCustomerDataType,
     ) -> ClientResult<crate::types::Customer> {
+        Ok(self
+            .put_stores_customers_with_response(store_id, customer_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add or update customer.
+     *
+     * This function performs a `PUT` to the `/ecommerce/stores/{store_id}/customers/{customer_id}` endpoint.
+     *
+     * As opposed to `put_stores_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_stores_customers_with_response(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCustomerDataType,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/customers/{}",
@@ -849,7 +1244,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -875,6 +1270,22 @@ impl Ecommerce {
         store_id: &str,
         customer_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_customers_with_response(store_id, customer_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete customer.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/customers/{customer_id}` endpoint.
+     *
+     * As opposed to `delete_stores_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_customers_with_response(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/customers/{}",
@@ -884,7 +1295,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -911,6 +1322,24 @@ impl Ecommerce {
         customer_id: &str,
         body: &crate::types::ECommerceCartCustomer,
     ) -> ClientResult<crate::types::Customer> {
+        Ok(self
+            .patch_stores_customers_with_response(store_id, customer_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update customer.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/customers/{customer_id}` endpoint.
+     *
+     * As opposed to `patch_stores_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_customers_with_response(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCartCustomer,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/customers/{}",
@@ -920,7 +1349,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -952,6 +1381,26 @@ impl Ecommerce {
         offset: i64,
         store_id: &str,
     ) -> ClientResult<crate::types::PromoRulesData> {
+        Ok(self
+            .get_stores_promorule_with_response(fields, exclude_fields, count, offset, store_id)
+            .await?
+            .body)
+    }
+    /**
+     * List promo rules.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules` endpoint.
+     *
+     * As opposed to `get_stores_promorule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_promorule_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoRulesData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -975,7 +1424,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1000,6 +1449,23 @@ impl Ecommerce {
         store_id: &str,
         body: &crate::types::ECommercePromoRule,
     ) -> ClientResult<crate::types::PromoRules> {
+        Ok(self
+            .post_stores_promorule_with_response(store_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add promo rule.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/promo-rules` endpoint.
+     *
+     * As opposed to `post_stores_promorule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_promorule_with_response(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommercePromoRule,
+    ) -> ClientResult<crate::Response<crate::types::PromoRules>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/promo-rules",
@@ -1008,7 +1474,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1038,6 +1504,30 @@ impl Ecommerce {
         store_id: &str,
         promo_rule_id: &str,
     ) -> ClientResult<crate::types::PromoRules> {
+        Ok(self
+            .get_stores_promorule_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                promo_rule_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get promo rule.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}` endpoint.
+     *
+     * As opposed to `get_stores_promorule_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_promorule_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoRules>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1056,7 +1546,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1082,6 +1572,22 @@ impl Ecommerce {
         store_id: &str,
         promo_rule_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_promorules_with_response(store_id, promo_rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete promo rule.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}` endpoint.
+     *
+     * As opposed to `delete_stores_promorules`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_promorules_with_response(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/promo-rules/{}",
@@ -1091,7 +1597,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1118,6 +1624,24 @@ impl Ecommerce {
         promo_rule_id: &str,
         body: &crate::types::ECommercePromoRuleData,
     ) -> ClientResult<crate::types::PromoRules> {
+        Ok(self
+            .patch_stores_promorules_with_response(store_id, promo_rule_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update promo rule.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}` endpoint.
+     *
+     * As opposed to `patch_stores_promorules`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_promorules_with_response(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoRuleData,
+    ) -> ClientResult<crate::Response<crate::types::PromoRules>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/promo-rules/{}",
@@ -1127,7 +1651,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1161,6 +1685,34 @@ impl Ecommerce {
         promo_rule_id: &str,
         store_id: &str,
     ) -> ClientResult<crate::types::PromoCodesData> {
+        Ok(self
+            .get_stores_promocode_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                promo_rule_id,
+                store_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List promo codes.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes` endpoint.
+     *
+     * As opposed to `get_stores_promocode`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_promocode_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodesData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1185,7 +1737,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1212,6 +1764,24 @@ impl Ecommerce {
         promo_rule_id: &str,
         body: &crate::types::ECommercePromoCode,
     ) -> ClientResult<crate::types::PromoCodes> {
+        Ok(self
+            .post_stores_promocode_with_response(store_id, promo_rule_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add promo code.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes` endpoint.
+     *
+     * As opposed to `post_stores_promocode`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_promocode_with_response(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoCode,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/promo-rules/{}/promo-codes",
@@ -1221,7 +1791,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1253,6 +1823,32 @@ impl Ecommerce {
         promo_rule_id: &str,
         promo_code_id: &str,
     ) -> ClientResult<crate::types::PromoCodes> {
+        Ok(self
+            .get_stores_promocode_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                promo_rule_id,
+                promo_code_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get promo code.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}` endpoint.
+     *
+     * As opposed to `get_stores_promocode_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_promocode_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1272,7 +1868,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1300,6 +1896,23 @@ impl Ecommerce {
         promo_rule_id: &str,
         promo_code_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_promocodes_with_response(store_id, promo_rule_id, promo_code_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete promo code.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}` endpoint.
+     *
+     * As opposed to `delete_stores_promocodes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_promocodes_with_response(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
@@ -1310,7 +1923,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1339,6 +1952,25 @@ impl Ecommerce {
         promo_code_id: &str,
         body: &crate::types::ECommercePromoCodeData,
     ) -> ClientResult<crate::types::PromoCodes> {
+        Ok(self
+            .patch_stores_promocodes_with_response(store_id, promo_rule_id, promo_code_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update promo code.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}` endpoint.
+     *
+     * As opposed to `patch_stores_promocodes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_promocodes_with_response(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+        body: &crate::types::ECommercePromoCodeData,
+    ) -> ClientResult<crate::Response<crate::types::PromoCodes>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
@@ -1349,7 +1981,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1389,6 +2021,40 @@ impl Ecommerce {
         campaign_id: &str,
         outreach_id: &str,
     ) -> ClientResult<crate::types::OrdersDataType> {
+        Ok(self
+            .get_stores_order_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                store_id,
+                customer_id,
+                has_outreach,
+                campaign_id,
+                outreach_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders` endpoint.
+     *
+     * As opposed to `get_stores_order`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_order_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+        campaign_id: &str,
+        outreach_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrdersDataType>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !campaign_id.is_empty() {
             query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
@@ -1424,7 +2090,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1449,6 +2115,23 @@ impl Ecommerce {
         store_id: &str,
         body: &crate::types::ECommerceOrder,
     ) -> ClientResult<crate::types::Orders> {
+        Ok(self
+            .post_stores_order_with_response(store_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add order.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/orders` endpoint.
+     *
+     * As opposed to `post_stores_order`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_order_with_response(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceOrder,
+    ) -> ClientResult<crate::Response<crate::types::Orders>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/orders",
@@ -1457,7 +2140,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1487,6 +2170,25 @@ impl Ecommerce {
         store_id: &str,
         order_id: &str,
     ) -> ClientResult<crate::types::Orders> {
+        Ok(self
+            .get_stores_order_ecommerce_with_response(fields, exclude_fields, store_id, order_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get order info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
+     *
+     * As opposed to `get_stores_order_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_order_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Orders>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1505,7 +2207,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1527,6 +2229,22 @@ impl Ecommerce {
      * * `order_id: &str` -- The id for the order in a store.
      */
     pub async fn delete_stores_orders(&self, store_id: &str, order_id: &str) -> ClientResult<()> {
+        self.delete_stores_orders_with_response(store_id, order_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete order.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
+     *
+     * As opposed to `delete_stores_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_orders_with_response(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/orders/{}",
@@ -1536,7 +2254,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1563,6 +2281,24 @@ impl Ecommerce {
         order_id: &str,
         body: &crate::types::ECommerceOrderData,
     ) -> ClientResult<crate::types::Orders> {
+        Ok(self
+            .patch_stores_orders_with_response(store_id, order_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update order.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
+     *
+     * As opposed to `patch_stores_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_orders_with_response(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderData,
+    ) -> ClientResult<crate::Response<crate::types::Orders>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/orders/{}",
@@ -1572,32 +2308,60 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
                     content_type: Some("application/json".to_string()),
                 },
             )
-            .await
+            .await
+    }
+    /**
+     * List order line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
+     *
+     * Get information about an order's line items.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `store_id: &str` -- The name of the folder.
+     * * `order_id: &str` -- The id for the order in a store.
+     */
+    pub async fn get_stores_orders_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        order_id: &str,
+    ) -> ClientResult<crate::types::OrderLines> {
+        Ok(self
+            .get_stores_orders_line_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                store_id,
+                order_id,
+            )
+            .await?
+            .body)
     }
     /**
      * List order line items.
      *
      * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
      *
-     * Get information about an order's line items.
-     *
-     * **Parameters:**
-     *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `store_id: &str` -- The name of the folder.
-     * * `order_id: &str` -- The id for the order in a store.
+     * As opposed to `get_stores_orders_line`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_stores_orders_line(
+    pub async fn get_stores_orders_line_with_response(
         &self,
         fields: &[String],
         exclude_fields: &[String],
@@ -1605,7 +2369,7 @@ impl Ecommerce {
         offset: i64,
         store_id: &str,
         order_id: &str,
-    ) -> ClientResult<crate::types::OrderLines> {
+    ) -> ClientResult<crate::Response<crate::types::OrderLines>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1630,7 +2394,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1657,6 +2421,24 @@ impl Ecommerce {
         order_id: &str,
         body: &crate::types::ECommerceOrderLineItem,
     ) -> ClientResult<crate::types::Lines> {
+        Ok(self
+            .post_stores_orders_line_with_response(store_id, order_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add order line item.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
+     *
+     * As opposed to `post_stores_orders_line`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_orders_line_with_response(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderLineItem,
+    ) -> ClientResult<crate::Response<crate::types::Lines>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/orders/{}/lines",
@@ -1666,7 +2448,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1698,6 +2480,32 @@ impl Ecommerce {
         order_id: &str,
         line_id: &str,
     ) -> ClientResult<crate::types::Lines> {
+        Ok(self
+            .get_stores_orders_line_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                order_id,
+                line_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get order line item.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `get_stores_orders_line_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_orders_line_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Lines>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1717,7 +2525,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1745,6 +2553,23 @@ impl Ecommerce {
         order_id: &str,
         line_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_orders_lines_with_response(store_id, order_id, line_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete order line item.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `delete_stores_orders_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_orders_lines_with_response(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/orders/{}/lines/{}",
@@ -1755,7 +2580,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1784,6 +2609,25 @@ impl Ecommerce {
         line_id: &str,
         body: &crate::types::ECommerceOrderLineItemData,
     ) -> ClientResult<crate::types::Lines> {
+        Ok(self
+            .patch_stores_orders_lines_with_response(store_id, order_id, line_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update order line item.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `patch_stores_orders_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_orders_lines_with_response(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceOrderLineItemData,
+    ) -> ClientResult<crate::Response<crate::types::Lines>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/orders/{}/lines/{}",
@@ -1794,7 +2638,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1826,6 +2670,26 @@ impl Ecommerce {
         offset: i64,
         store_id: &str,
     ) -> ClientResult<crate::types::ProductsData> {
+        Ok(self
+            .get_stores_product_with_response(fields, exclude_fields, count, offset, store_id)
+            .await?
+            .body)
+    }
+    /**
+     * List product.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products` endpoint.
+     *
+     * As opposed to `get_stores_product`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_product_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProductsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1849,7 +2713,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1874,6 +2738,23 @@ impl Ecommerce {
         store_id: &str,
         body: &crate::types::ECommerceProductData,
     ) -> ClientResult<crate::types::ECommerceProduct> {
+        Ok(self
+            .post_stores_product_with_response(store_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add product.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/products` endpoint.
+     *
+     * As opposed to `post_stores_product`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_product_with_response(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceProductData,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products",
@@ -1882,7 +2763,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1912,6 +2793,30 @@ impl Ecommerce {
         store_id: &str,
         product_id: &str,
     ) -> ClientResult<crate::types::ECommerceProduct> {
+        Ok(self
+            .get_stores_product_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                product_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get product info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}` endpoint.
+     *
+     * As opposed to `get_stores_product_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_product_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1930,7 +2835,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1956,6 +2861,22 @@ impl Ecommerce {
         store_id: &str,
         product_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_products_with_response(store_id, product_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete product.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/products/{product_id}` endpoint.
+     *
+     * As opposed to `delete_stores_products`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_products_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}",
@@ -1965,7 +2886,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1992,6 +2913,24 @@ impl Ecommerce {
         product_id: &str,
         body: &crate::types::ECommerceProductDataType,
     ) -> ClientResult<crate::types::ECommerceProduct> {
+        Ok(self
+            .patch_stores_products_with_response(store_id, product_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update product.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/products/{product_id}` endpoint.
+     *
+     * As opposed to `patch_stores_products`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_products_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductDataType,
+    ) -> ClientResult<crate::Response<crate::types::ECommerceProduct>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}",
@@ -2001,7 +2940,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2035,6 +2974,34 @@ impl Ecommerce {
         store_id: &str,
         product_id: &str,
     ) -> ClientResult<crate::types::EcommerceProductVariants> {
+        Ok(self
+            .get_stores_products_variant_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                store_id,
+                product_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List product variants.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants` endpoint.
+     *
+     * As opposed to `get_stores_products_variant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_products_variant_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EcommerceProductVariants>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -2059,7 +3026,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2086,6 +3053,24 @@ impl Ecommerce {
         product_id: &str,
         body: &crate::types::ECommerceProductVariant,
     ) -> ClientResult<crate::types::Variants> {
+        Ok(self
+            .post_stores_products_variant_with_response(store_id, product_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add product variant.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants` endpoint.
+     *
+     * As opposed to `post_stores_products_variant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_products_variant_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}/variants",
@@ -2095,7 +3080,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2127,6 +3112,32 @@ impl Ecommerce {
         product_id: &str,
         variant_id: &str,
     ) -> ClientResult<crate::types::Variants> {
+        Ok(self
+            .get_stores_products_variant_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                product_id,
+                variant_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get product variant info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}` endpoint.
+     *
+     * As opposed to `get_stores_products_variant_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_products_variant_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -2146,7 +3157,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2175,6 +3186,25 @@ impl Ecommerce {
         variant_id: &str,
         body: &crate::types::ECommerceProductVariant,
     ) -> ClientResult<crate::types::Variants> {
+        Ok(self
+            .put_stores_products_variants_with_response(store_id, product_id, variant_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add or update product variant.
+     *
+     * This function performs a `PUT` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}` endpoint.
+     *
+     * As opposed to `put_stores_products_variants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_stores_products_variants_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}/variants/{}",
@@ -2185,7 +3215,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2213,6 +3243,23 @@ impl Ecommerce {
         product_id: &str,
         variant_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_products_variants_with_response(store_id, product_id, variant_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete product variant.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}` endpoint.
+     *
+     * As opposed to `delete_stores_products_variants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_products_variants_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}/variants/{}",
@@ -2223,7 +3270,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2252,6 +3299,25 @@ impl Ecommerce {
         variant_id: &str,
         body: &crate::types::ECommerceProductVariantData,
     ) -> ClientResult<crate::types::Variants> {
+        Ok(self
+            .patch_stores_products_variants_with_response(store_id, product_id, variant_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update product variant.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}` endpoint.
+     *
+     * As opposed to `patch_stores_products_variants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_products_variants_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariantData,
+    ) -> ClientResult<crate::Response<crate::types::Variants>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}/variants/{}",
@@ -2262,7 +3328,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2296,6 +3362,34 @@ impl Ecommerce {
         store_id: &str,
         product_id: &str,
     ) -> ClientResult<crate::types::EcommerceProductImages> {
+        Ok(self
+            .get_stores_products_image_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                store_id,
+                product_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List product images.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/images` endpoint.
+     *
+     * As opposed to `get_stores_products_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_products_image_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EcommerceProductImages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -2320,7 +3414,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2347,6 +3441,24 @@ impl Ecommerce {
         product_id: &str,
         body: &crate::types::ECommerceProductImage,
     ) -> ClientResult<crate::types::Images> {
+        Ok(self
+            .post_stores_products_image_with_response(store_id, product_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add product image.
+     *
+     * This function performs a `POST` to the `/ecommerce/stores/{store_id}/products/{product_id}/images` endpoint.
+     *
+     * As opposed to `post_stores_products_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_stores_products_image_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductImage,
+    ) -> ClientResult<crate::Response<crate::types::Images>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}/images",
@@ -2356,7 +3468,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2388,6 +3500,32 @@ impl Ecommerce {
         product_id: &str,
         image_id: &str,
     ) -> ClientResult<crate::types::Images> {
+        Ok(self
+            .get_stores_products_image_ecommerce_with_response(
+                fields,
+                exclude_fields,
+                store_id,
+                product_id,
+                image_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get product image info.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}` endpoint.
+     *
+     * As opposed to `get_stores_products_image_ecommerce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stores_products_image_ecommerce_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Images>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -2407,7 +3545,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2435,6 +3573,23 @@ impl Ecommerce {
         product_id: &str,
         image_id: &str,
     ) -> ClientResult<()> {
+        self.delete_stores_products_images_with_response(store_id, product_id, image_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete product image.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}` endpoint.
+     *
+     * As opposed to `delete_stores_products_images`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_stores_products_images_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}/images/{}",
@@ -2445,7 +3600,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2474,6 +3629,25 @@ impl Ecommerce {
         image_id: &str,
         body: &crate::types::ECommerceProductImageData,
     ) -> ClientResult<crate::types::Images> {
+        Ok(self
+            .patch_stores_products_images_with_response(store_id, product_id, image_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update product image.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}` endpoint.
+     *
+     * As opposed to `patch_stores_products_images`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_stores_products_images_with_response(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+        body: &crate::types::ECommerceProductImageData,
+    ) -> ClientResult<crate::Response<crate::types::Images>> {
         let url = self.client.url(
             &format!(
                 "/ecommerce/stores/{}/products/{}/images/{}",
@@ -2484,7 +3658,7 @@ impl Ecommerce {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
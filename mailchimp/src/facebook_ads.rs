@@ -36,6 +36,27 @@ impl FacebookAds {
         sort_field: crate::types::GetAllFacebookAdsSortField,
         sort_dir: crate::types::SortDir,
     ) -> ClientResult<crate::types::GetAllFacebookAdsResponse> {
+        Ok(self
+            .get_all_with_response(fields, exclude_fields, count, offset, sort_field, sort_dir)
+            .await?
+            .body)
+    }
+    /**
+     * List facebook ads.
+     *
+     * This function performs a `GET` to the `/facebook-ads` endpoint.
+     *
+     * As opposed to `get_all`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_all_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GetAllFacebookAdsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -58,7 +79,7 @@ impl FacebookAds {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/facebook-ads?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -86,6 +107,24 @@ impl FacebookAds {
         outreach_id: &str,
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::FacebookAdsAllOf> {
+        Ok(self
+            .get_with_response(fields, outreach_id, exclude_fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get facebook ad info.
+     *
+     * This function performs a `GET` to the `/facebook-ads/{outreach_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::FacebookAdsAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -103,7 +142,7 @@ impl FacebookAds {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -44,6 +44,42 @@ impl FileManager {
         sort_field: crate::types::GetFileManagerFilesSortField,
         sort_dir: crate::types::SortDir,
     ) -> ClientResult<crate::types::FileManager> {
+        Ok(self
+            .get_file_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                type_,
+                created_by,
+                before_created_at,
+                since_created_at,
+                sort_field,
+                sort_dir,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List stored files.
+     *
+     * This function performs a `GET` to the `/file-manager/files` endpoint.
+     *
+     * As opposed to `get_file`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_file_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+        sort_field: crate::types::GetFileManagerFilesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::FileManager>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before_created_at.is_empty() {
             query_args.push((
@@ -83,7 +119,7 @@ impl FileManager {
             .client
             .url(&format!("/file-manager/files?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -103,9 +139,22 @@ impl FileManager {
         &self,
         body: &crate::types::GalleryFile,
     ) -> ClientResult<crate::types::Files> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add file.
+     *
+     * This function performs a `POST` to the `/file-manager/files` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::GalleryFile,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
         let url = self.client.url("/file-manager/files", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -133,6 +182,24 @@ impl FileManager {
         exclude_fields: &[String],
         file_id: &str,
     ) -> ClientResult<crate::types::Files> {
+        Ok(self
+            .get_file_file_manager_with_response(fields, exclude_fields, file_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get file.
+     *
+     * This function performs a `GET` to the `/file-manager/files/{file_id}` endpoint.
+     *
+     * As opposed to `get_file_file_manager`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_file_file_manager_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        file_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -150,7 +217,7 @@ impl FileManager {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -171,6 +238,20 @@ impl FileManager {
      * * `file_id: &str` -- The unique id for the File Manager file.
      */
     pub async fn delete_files(&self, file_id: &str) -> ClientResult<()> {
+        self.delete_files_with_response(file_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete file.
+     *
+     * This function performs a `DELETE` to the `/file-manager/files/{file_id}` endpoint.
+     *
+     * As opposed to `delete_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_files_with_response(
+        &self,
+        file_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/file-manager/files/{}",
@@ -179,7 +260,7 @@ impl FileManager {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -204,6 +285,20 @@ impl FileManager {
         file_id: &str,
         body: &crate::types::GalleryFileData,
     ) -> ClientResult<crate::types::Files> {
+        Ok(self.patch_files_with_response(file_id, body).await?.body)
+    }
+    /**
+     * Update file.
+     *
+     * This function performs a `PATCH` to the `/file-manager/files/{file_id}` endpoint.
+     *
+     * As opposed to `patch_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_files_with_response(
+        &self,
+        file_id: &str,
+        body: &crate::types::GalleryFileData,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
         let url = self.client.url(
             &format!(
                 "/file-manager/files/{}",
@@ -212,7 +307,7 @@ impl FileManager {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -248,6 +343,36 @@ impl FileManager {
         before_created_at: &str,
         since_created_at: &str,
     ) -> ClientResult<crate::types::FileManagerFolders> {
+        Ok(self
+            .get_folder_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                created_by,
+                before_created_at,
+                since_created_at,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List folders.
+     *
+     * This function performs a `GET` to the `/file-manager/folders` endpoint.
+     *
+     * As opposed to `get_folder`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_folder_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFolders>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before_created_at.is_empty() {
             query_args.push((
@@ -278,7 +403,7 @@ impl FileManager {
             .client
             .url(&format!("/file-manager/folders?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -298,9 +423,22 @@ impl FileManager {
         &self,
         body: &crate::types::GalleryFolder,
     ) -> ClientResult<crate::types::FileManagerFoldersGalleryFolder> {
+        Ok(self.post_folder_with_response(body).await?.body)
+    }
+    /**
+     * Add folder.
+     *
+     * This function performs a `POST` to the `/file-manager/folders` endpoint.
+     *
+     * As opposed to `post_folder`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_folder_with_response(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
         let url = self.client.url("/file-manager/folders", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -328,6 +466,24 @@ impl FileManager {
         exclude_fields: &[String],
         folder_id: &str,
     ) -> ClientResult<crate::types::FileManagerFoldersGalleryFolder> {
+        Ok(self
+            .get_folder_file_manager_with_response(fields, exclude_fields, folder_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get folder.
+     *
+     * This function performs a `GET` to the `/file-manager/folders/{folder_id}` endpoint.
+     *
+     * As opposed to `get_folder_file_manager`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_folder_file_manager_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -345,7 +501,7 @@ impl FileManager {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -366,6 +522,20 @@ impl FileManager {
      * * `folder_id: &str` -- The unique id for the File Manager folder.
      */
     pub async fn delete_folders(&self, folder_id: &str) -> ClientResult<()> {
+        self.delete_folders_with_response(folder_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete folder.
+     *
+     * This function performs a `DELETE` to the `/file-manager/folders/{folder_id}` endpoint.
+     *
+     * As opposed to `delete_folders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_folders_with_response(
+        &self,
+        folder_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/file-manager/folders/{}",
@@ -374,7 +544,7 @@ impl FileManager {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -399,6 +569,23 @@ impl FileManager {
         folder_id: &str,
         body: &crate::types::GalleryFolder,
     ) -> ClientResult<crate::types::FileManagerFoldersGalleryFolder> {
+        Ok(self
+            .patch_folders_with_response(folder_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update folder.
+     *
+     * This function performs a `PATCH` to the `/file-manager/folders/{folder_id}` endpoint.
+     *
+     * As opposed to `patch_folders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_folders_with_response(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> ClientResult<crate::Response<crate::types::FileManagerFoldersGalleryFolder>> {
         let url = self.client.url(
             &format!(
                 "/file-manager/folders/{}",
@@ -407,7 +594,7 @@ impl FileManager {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -34,6 +34,26 @@ impl LandingPages {
         exclude_fields: &[String],
         count: i64,
     ) -> ClientResult<crate::types::GetAllLandingPagesResponse> {
+        Ok(self
+            .get_all_with_response(sort_dir, sort_field, fields, exclude_fields, count)
+            .await?
+            .body)
+    }
+    /**
+     * List landing pages.
+     *
+     * This function performs a `GET` to the `/landing-pages` endpoint.
+     *
+     * As opposed to `get_all`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_all_with_response(
+        &self,
+        sort_dir: crate::types::SortDir,
+        sort_field: crate::types::GetAllLandingPagesSortField,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAllLandingPagesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -53,7 +73,7 @@ impl LandingPages {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/landing-pages?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -78,6 +98,23 @@ impl LandingPages {
         use_default_list: bool,
         body: &crate::types::LandingPageData,
     ) -> ClientResult<crate::types::LandingPage> {
+        Ok(self
+            .post_all_with_response(use_default_list, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add landing page.
+     *
+     * This function performs a `POST` to the `/landing-pages` endpoint.
+     *
+     * As opposed to `post_all`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_all_with_response(
+        &self,
+        use_default_list: bool,
+        body: &crate::types::LandingPageData,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if use_default_list {
             query_args.push(("use_default_list".to_string(), use_default_list.to_string()));
@@ -85,7 +122,7 @@ impl LandingPages {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/landing-pages?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -113,6 +150,24 @@ impl LandingPages {
         exclude_fields: &[String],
         page_id: &str,
     ) -> ClientResult<crate::types::LandingPage> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, page_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get landing page info.
+     *
+     * This function performs a `GET` to the `/landing-pages/{page_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -130,7 +185,7 @@ impl LandingPages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -151,6 +206,17 @@ impl LandingPages {
      * * `page_id: &str` -- The unique id for the page.
      */
     pub async fn delete(&self, page_id: &str) -> ClientResult<()> {
+        self.delete_with_response(page_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete landing page.
+     *
+     * This function performs a `DELETE` to the `/landing-pages/{page_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, page_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/landing-pages/{}",
@@ -159,7 +225,7 @@ impl LandingPages {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -184,6 +250,20 @@ impl LandingPages {
         page_id: &str,
         body: &crate::types::LandingPageDataType,
     ) -> ClientResult<crate::types::LandingPage> {
+        Ok(self.patch_with_response(page_id, body).await?.body)
+    }
+    /**
+     * Update landing page.
+     *
+     * This function performs a `PATCH` to the `/landing-pages/{page_id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        page_id: &str,
+        body: &crate::types::LandingPageDataType,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
         let url = self.client.url(
             &format!(
                 "/landing-pages/{}",
@@ -192,7 +272,7 @@ impl LandingPages {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -216,6 +296,19 @@ impl LandingPages {
         &self,
         page_id: &str,
     ) -> ClientResult<crate::types::LandingPage> {
+        Ok(self.post_actions_publish_with_response(page_id).await?.body)
+    }
+    /**
+     * Publish landing page.
+     *
+     * This function performs a `POST` to the `/landing-pages/{page_id}/actions/publish` endpoint.
+     *
+     * As opposed to `post_actions_publish`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_publish_with_response(
+        &self,
+        page_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LandingPage>> {
         let url = self.client.url(
             &format!(
                 "/landing-pages/{}/actions/publish",
@@ -224,7 +317,7 @@ impl LandingPages {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -245,6 +338,20 @@ impl LandingPages {
      * * `page_id: &str` -- The unique id for the page.
      */
     pub async fn post_actions_unpublish(&self, page_id: &str) -> ClientResult<()> {
+        self.post_actions_unpublish_with_response(page_id).await?;
+        Ok(())
+    }
+    /**
+     * Unpublish landing page.
+     *
+     * This function performs a `POST` to the `/landing-pages/{page_id}/actions/unpublish` endpoint.
+     *
+     * As opposed to `post_actions_unpublish`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_actions_unpublish_with_response(
+        &self,
+        page_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/landing-pages/{}/actions/unpublish",
@@ -253,7 +360,7 @@ impl LandingPages {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -281,6 +388,24 @@ impl LandingPages {
         exclude_fields: &[String],
         page_id: &str,
     ) -> ClientResult<crate::types::LandingPageContent> {
+        Ok(self
+            .get_content_with_response(fields, exclude_fields, page_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get landing page content.
+     *
+     * This function performs a `GET` to the `/landing-pages/{page_id}/content` endpoint.
+     *
+     * As opposed to `get_content`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_content_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LandingPageContent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -298,7 +423,7 @@ impl LandingPages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,6 +50,48 @@ impl Lists {
         has_ecommerce_store: bool,
         include_total_contacts: bool,
     ) -> ClientResult<crate::types::SubscriberLists> {
+        Ok(self
+            .get_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                before_date_created,
+                since_date_created,
+                before_campaign_last_sent,
+                since_campaign_last_sent,
+                email,
+                sort_field,
+                sort_dir,
+                has_ecommerce_store,
+                include_total_contacts,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get lists info.
+     *
+     * This function performs a `GET` to the `/lists` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        before_date_created: &str,
+        since_date_created: &str,
+        before_campaign_last_sent: &str,
+        since_campaign_last_sent: &str,
+        email: &str,
+        sort_field: crate::types::GetListsSortField,
+        sort_dir: crate::types::SortDir,
+        has_ecommerce_store: bool,
+        include_total_contacts: bool,
+    ) -> ClientResult<crate::Response<crate::types::SubscriberLists>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before_campaign_last_sent.is_empty() {
             query_args.push((
@@ -111,7 +153,7 @@ impl Lists {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/lists?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -131,9 +173,22 @@ impl Lists {
         &self,
         body: &crate::types::SubscriberList,
     ) -> ClientResult<crate::types::Lists> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add list.
+     *
+     * This function performs a `POST` to the `/lists` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::SubscriberList,
+    ) -> ClientResult<crate::Response<crate::types::Lists>> {
         let url = self.client.url("/lists", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -163,6 +218,25 @@ impl Lists {
         list_id: &str,
         include_total_contacts: bool,
     ) -> ClientResult<crate::types::Lists> {
+        Ok(self
+            .get_lists_with_response(fields, exclude_fields, list_id, include_total_contacts)
+            .await?
+            .body)
+    }
+    /**
+     * Get list info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `get_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_lists_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        include_total_contacts: bool,
+    ) -> ClientResult<crate::Response<crate::types::Lists>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -186,7 +260,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +289,25 @@ impl Lists {
         skip_duplicate_check: bool,
         body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
     ) -> ClientResult<crate::types::BatchUpdateListMembers> {
+        Ok(self
+            .post_lists_with_response(list_id, skip_merge_validation, skip_duplicate_check, body)
+            .await?
+            .body)
+    }
+    /**
+     * Batch subscribe or unsubscribe.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `post_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_lists_with_response(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        skip_duplicate_check: bool,
+        body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+    ) -> ClientResult<crate::Response<crate::types::BatchUpdateListMembers>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if skip_duplicate_check {
             query_args.push((
@@ -238,7 +331,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -259,12 +352,23 @@ impl Lists {
      * * `list_id: &str` -- The unique ID for the list.
      */
     pub async fn delete(&self, list_id: &str) -> ClientResult<()> {
+        self.delete_with_response(list_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete list.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, list_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/lists/{}", crate::progenitor_support::encode_path(list_id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -289,12 +393,26 @@ impl Lists {
         list_id: &str,
         body: &crate::types::SubscriberListData,
     ) -> ClientResult<crate::types::Lists> {
+        Ok(self.patch_with_response(list_id, body).await?.body)
+    }
+    /**
+     * Update lists.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        list_id: &str,
+        body: &crate::types::SubscriberListData,
+    ) -> ClientResult<crate::Response<crate::types::Lists>> {
         let url = self.client.url(
             &format!("/lists/{}", crate::progenitor_support::encode_path(list_id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -326,6 +444,26 @@ impl Lists {
         offset: i64,
         list_id: &str,
     ) -> ClientResult<crate::types::AbuseComplaints> {
+        Ok(self
+            .get_abuse_report_with_response(fields, exclude_fields, count, offset, list_id)
+            .await?
+            .body)
+    }
+    /**
+     * List abuse reports.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/abuse-reports` endpoint.
+     *
+     * As opposed to `get_abuse_report`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_abuse_report_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseComplaints>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -349,7 +487,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -383,6 +521,34 @@ impl Lists {
         list_id: &str,
         report_id: &str,
     ) -> ClientResult<crate::types::AbuseReports> {
+        Ok(self
+            .get_abuse_report_lists_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                list_id,
+                report_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get abuse report.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/abuse-reports/{report_id}` endpoint.
+     *
+     * As opposed to `get_abuse_report_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_abuse_report_lists_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseReports>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -407,7 +573,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -435,6 +601,24 @@ impl Lists {
         exclude_fields: &[String],
         list_id: &str,
     ) -> ClientResult<crate::types::ListActivity> {
+        Ok(self
+            .get_activity_with_response(fields, exclude_fields, list_id)
+            .await?
+            .body)
+    }
+    /**
+     * List recent activity.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/activity` endpoint.
+     *
+     * As opposed to `get_activity`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_activity_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListActivity>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -452,7 +636,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -480,6 +664,24 @@ impl Lists {
         exclude_fields: &[String],
         list_id: &str,
     ) -> ClientResult<crate::types::EmailClients> {
+        Ok(self
+            .get_client_with_response(fields, exclude_fields, list_id)
+            .await?
+            .body)
+    }
+    /**
+     * List top email clients.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/clients` endpoint.
+     *
+     * As opposed to `get_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_client_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailClients>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -497,7 +699,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -533,6 +735,36 @@ impl Lists {
         sort_field: crate::types::GetListsGrowthHistorySortField,
         sort_dir: crate::types::SortDir,
     ) -> ClientResult<crate::types::GrowthHistory> {
+        Ok(self
+            .get_growth_history_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                list_id,
+                sort_field,
+                sort_dir,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List growth history data.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/growth-history` endpoint.
+     *
+     * As opposed to `get_growth_history`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_growth_history_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        sort_field: crate::types::GetListsGrowthHistorySortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GrowthHistory>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -562,7 +794,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -592,6 +824,25 @@ impl Lists {
         list_id: &str,
         month: &str,
     ) -> ClientResult<crate::types::History> {
+        Ok(self
+            .get_growth_history_lists_with_response(fields, exclude_fields, list_id, month)
+            .await?
+            .body)
+    }
+    /**
+     * Get growth history by month.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/growth-history/{month}` endpoint.
+     *
+     * As opposed to `get_growth_history_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_growth_history_lists_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        month: &str,
+    ) -> ClientResult<crate::Response<crate::types::History>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -610,7 +861,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -644,6 +895,34 @@ impl Lists {
         offset: i64,
         type_: &str,
     ) -> ClientResult<crate::types::InterestGroupings> {
+        Ok(self
+            .get_interest_categorie_with_response(
+                list_id,
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List interest categories.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories` endpoint.
+     *
+     * As opposed to `get_interest_categorie`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_interest_categorie_with_response(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+    ) -> ClientResult<crate::Response<crate::types::InterestGroupings>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -670,7 +949,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -695,6 +974,23 @@ impl Lists {
         list_id: &str,
         body: &crate::types::InterestCategory,
     ) -> ClientResult<crate::types::Categories> {
+        Ok(self
+            .post_interest_categorie_with_response(list_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add interest category.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/interest-categories` endpoint.
+     *
+     * As opposed to `post_interest_categorie`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_interest_categorie_with_response(
+        &self,
+        list_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> ClientResult<crate::Response<crate::types::Categories>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/interest-categories",
@@ -703,7 +999,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -733,6 +1029,30 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::Categories> {
+        Ok(self
+            .get_interest_categorie_lists_with_response(
+                list_id,
+                interest_category_id,
+                fields,
+                exclude_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get interest category info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}` endpoint.
+     *
+     * As opposed to `get_interest_categorie_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_interest_categorie_lists_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Categories>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -751,7 +1071,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -777,6 +1097,22 @@ impl Lists {
         list_id: &str,
         interest_category_id: &str,
     ) -> ClientResult<()> {
+        self.delete_interest_categories_with_response(list_id, interest_category_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete interest category.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/interest-categories/{interest_category_id}` endpoint.
+     *
+     * As opposed to `delete_interest_categories`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_interest_categories_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/interest-categories/{}",
@@ -786,7 +1122,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -813,6 +1149,24 @@ impl Lists {
         interest_category_id: &str,
         body: &crate::types::InterestCategory,
     ) -> ClientResult<crate::types::Categories> {
+        Ok(self
+            .patch_interest_categories_with_response(list_id, interest_category_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update interest category.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/interest-categories/{interest_category_id}` endpoint.
+     *
+     * As opposed to `patch_interest_categories`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_interest_categories_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> ClientResult<crate::Response<crate::types::Categories>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/interest-categories/{}",
@@ -822,7 +1176,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -856,6 +1210,34 @@ impl Lists {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::InterestsData> {
+        Ok(self
+            .get_interest_categories_interest_with_response(
+                list_id,
+                interest_category_id,
+                fields,
+                exclude_fields,
+                count,
+                offset,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List interests in category.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests` endpoint.
+     *
+     * As opposed to `get_interest_categories_interest`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_interest_categories_interest_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::InterestsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -880,7 +1262,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -907,6 +1289,24 @@ impl Lists {
         interest_category_id: &str,
         body: &crate::types::Interest,
     ) -> ClientResult<crate::types::InterestsInterest> {
+        Ok(self
+            .post_interest_categories_with_response(list_id, interest_category_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add interest in category.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests` endpoint.
+     *
+     * As opposed to `post_interest_categories`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_interest_categories_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::Interest,
+    ) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/interest-categories/{}/interests",
@@ -916,7 +1316,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -948,6 +1348,32 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::InterestsInterest> {
+        Ok(self
+            .get_interest_categories_interest_lists_with_response(
+                list_id,
+                interest_category_id,
+                interest_id,
+                fields,
+                exclude_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get interest in category.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}` endpoint.
+     *
+     * As opposed to `get_interest_categories_interest_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_interest_categories_interest_lists_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -967,7 +1393,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -995,17 +1421,38 @@ impl Lists {
         interest_category_id: &str,
         interest_id: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/lists/{}/interest-categories/{}/interests/{}",
-                crate::progenitor_support::encode_path(list_id),
-                crate::progenitor_support::encode_path(interest_category_id),
-                crate::progenitor_support::encode_path(interest_id),
-            ),
+        self.delete_interest_categories_interests_with_response(
+            list_id,
+            interest_category_id,
+            interest_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete interest in category.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}` endpoint.
+     *
+     * As opposed to `delete_interest_categories_interests`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_interest_categories_interests_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url(
+            &format!(
+                "/lists/{}/interest-categories/{}/interests/{}",
+                crate::progenitor_support::encode_path(list_id),
+                crate::progenitor_support::encode_path(interest_category_id),
+                crate::progenitor_support::encode_path(interest_id),
+            ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1034,6 +1481,30 @@ impl Lists {
         interest_id: &str,
         body: &crate::types::Interest,
     ) -> ClientResult<crate::types::InterestsInterest> {
+        Ok(self
+            .patch_interest_categories_interests_with_response(
+                list_id,
+                interest_category_id,
+                interest_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Update interest in category.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}` endpoint.
+     *
+     * As opposed to `patch_interest_categories_interests`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_interest_categories_interests_with_response(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        body: &crate::types::Interest,
+    ) -> ClientResult<crate::Response<crate::types::InterestsInterest>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/interest-categories/{}/interests/{}",
@@ -1044,7 +1515,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1092,6 +1563,48 @@ impl Lists {
         since_updated_at: &str,
         before_updated_at: &str,
     ) -> ClientResult<crate::types::CollectionOfSegments> {
+        Ok(self
+            .preview_segment_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                list_id,
+                type_,
+                since_created_at,
+                before_created_at,
+                include_cleaned,
+                include_transactional,
+                include_unsubscribed,
+                since_updated_at,
+                before_updated_at,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List segments.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments` endpoint.
+     *
+     * As opposed to `preview_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn preview_segment_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        type_: &str,
+        since_created_at: &str,
+        before_created_at: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+        since_updated_at: &str,
+        before_updated_at: &str,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfSegments>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before_created_at.is_empty() {
             query_args.push((
@@ -1151,7 +1664,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1176,6 +1689,20 @@ impl Lists {
         list_id: &str,
         body: &crate::types::ListData,
     ) -> ClientResult<crate::types::Segments> {
+        Ok(self.post_segment_with_response(list_id, body).await?.body)
+    }
+    /**
+     * Add segment.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/segments` endpoint.
+     *
+     * As opposed to `post_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_segment_with_response(
+        &self,
+        list_id: &str,
+        body: &crate::types::ListData,
+    ) -> ClientResult<crate::Response<crate::types::Segments>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/segments",
@@ -1184,7 +1711,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1220,6 +1747,36 @@ impl Lists {
         include_transactional: bool,
         include_unsubscribed: bool,
     ) -> ClientResult<crate::types::Segments> {
+        Ok(self
+            .get_segment_with_response(
+                fields,
+                exclude_fields,
+                list_id,
+                segment_id,
+                include_cleaned,
+                include_transactional,
+                include_unsubscribed,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get segment info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `get_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_segment_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> ClientResult<crate::Response<crate::types::Segments>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1253,7 +1810,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1280,6 +1837,25 @@ impl Lists {
         segment_id: &str,
         body: &crate::types::MembersAddRemoveFromAStaticSegment,
     ) -> ClientResult<crate::types::BatchAddRemoveListMembersFromStaticSegment> {
+        Ok(self
+            .post_segment_lists_with_response(list_id, segment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Batch add or remove members.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `post_segment_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_segment_lists_with_response(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::MembersAddRemoveFromAStaticSegment,
+    ) -> ClientResult<crate::Response<crate::types::BatchAddRemoveListMembersFromStaticSegment>>
+    {
         let url = self.client.url(
             &format!(
                 "/lists/{}/segments/{}",
@@ -1289,7 +1865,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1311,6 +1887,22 @@ impl Lists {
      * * `segment_id: &str` -- The unique id for the segment.
      */
     pub async fn delete_segments(&self, list_id: &str, segment_id: &str) -> ClientResult<()> {
+        self.delete_segments_with_response(list_id, segment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete segment.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_segments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_segments_with_response(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/segments/{}",
@@ -1320,7 +1912,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1347,6 +1939,24 @@ impl Lists {
         segment_id: &str,
         body: &crate::types::ListDataType,
     ) -> ClientResult<crate::types::Segments> {
+        Ok(self
+            .patch_segments_with_response(list_id, segment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update segment.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_segments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_segments_with_response(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::ListDataType,
+    ) -> ClientResult<crate::Response<crate::types::Segments>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/segments/{}",
@@ -1356,7 +1966,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1396,6 +2006,40 @@ impl Lists {
         include_transactional: bool,
         include_unsubscribed: bool,
     ) -> ClientResult<crate::types::SegmentMembers> {
+        Ok(self
+            .get_segments_member_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                list_id,
+                segment_id,
+                include_cleaned,
+                include_transactional,
+                include_unsubscribed,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List members in segment.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}/members` endpoint.
+     *
+     * As opposed to `get_segments_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_segments_member_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> ClientResult<crate::Response<crate::types::SegmentMembers>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1435,7 +2079,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1462,6 +2106,24 @@ impl Lists {
         segment_id: &str,
         body: &crate::types::SubscriberInAutomationQueue,
     ) -> ClientResult<crate::types::ListMembers> {
+        Ok(self
+            .post_segments_member_with_response(list_id, segment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add member to segment.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/segments/{segment_id}/members` endpoint.
+     *
+     * As opposed to `post_segments_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_segments_member_with_response(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> ClientResult<crate::Response<crate::types::ListMembers>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/segments/{}/members",
@@ -1471,7 +2133,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1499,6 +2161,23 @@ impl Lists {
         segment_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<()> {
+        self.delete_segments_members_with_response(list_id, segment_id, subscriber_hash)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove list member from segment.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `delete_segments_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_segments_members_with_response(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/segments/{}/members/{}",
@@ -1509,7 +2188,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1535,6 +2214,23 @@ impl Lists {
         list_id: &str,
         name: &str,
     ) -> ClientResult<crate::types::TagSearchResults> {
+        Ok(self
+            .search_tags_name_with_response(list_id, name)
+            .await?
+            .body)
+    }
+    /**
+     * Search for tags on a list by name.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/tag-search` endpoint.
+     *
+     * As opposed to `search_tags_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn search_tags_name_with_response(
+        &self,
+        list_id: &str,
+        name: &str,
+    ) -> ClientResult<crate::Response<crate::types::TagSearchResults>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !name.is_empty() {
             query_args.push(("name".to_string(), name.to_string()));
@@ -1549,7 +2245,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1611,6 +2307,62 @@ impl Lists {
         since_last_campaign: bool,
         unsubscribed_since: &str,
     ) -> ClientResult<crate::types::ListMembersDataType> {
+        Ok(self
+            .get_member_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                list_id,
+                email_type,
+                status,
+                since_timestamp_opt,
+                before_timestamp_opt,
+                since_last_changed,
+                before_last_changed,
+                unique_email_id,
+                vip_only,
+                interest_category_id,
+                interest_ids,
+                interest_match,
+                sort_field,
+                sort_dir,
+                since_last_campaign,
+                unsubscribed_since,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List members info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members` endpoint.
+     *
+     * As opposed to `get_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_member_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        email_type: &str,
+        status: crate::types::GetListsMembersStatus,
+        since_timestamp_opt: &str,
+        before_timestamp_opt: &str,
+        since_last_changed: &str,
+        before_last_changed: &str,
+        unique_email_id: &str,
+        vip_only: bool,
+        interest_category_id: &str,
+        interest_ids: &str,
+        interest_match: crate::types::InterestMatch,
+        sort_field: crate::types::GetListsMembersSortField,
+        sort_dir: crate::types::SortDir,
+        since_last_campaign: bool,
+        unsubscribed_since: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersDataType>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before_last_changed.is_empty() {
             query_args.push((
@@ -1700,7 +2452,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1727,6 +2479,24 @@ impl Lists {
         skip_merge_validation: bool,
         body: &crate::types::AddListMembers,
     ) -> ClientResult<crate::types::ListMembersData> {
+        Ok(self
+            .post_member_with_response(list_id, skip_merge_validation, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add member to list.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/members` endpoint.
+     *
+     * As opposed to `post_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_member_with_response(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembers,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if skip_merge_validation {
             query_args.push((
@@ -1744,7 +2514,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1774,6 +2544,25 @@ impl Lists {
         list_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<crate::types::ListMembersData> {
+        Ok(self
+            .get_member_lists_with_response(fields, exclude_fields, list_id, subscriber_hash)
+            .await?
+            .body)
+    }
+    /**
+     * Get member info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_member_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_member_lists_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1792,7 +2581,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1821,6 +2610,25 @@ impl Lists {
         skip_merge_validation: bool,
         body: &crate::types::AddListMembersData,
     ) -> ClientResult<crate::types::ListMembersData> {
+        Ok(self
+            .put_members_with_response(list_id, subscriber_hash, skip_merge_validation, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add or update list member.
+     *
+     * This function performs a `PUT` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `put_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_members_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersData,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if skip_merge_validation {
             query_args.push((
@@ -1839,7 +2647,7 @@ impl Lists {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1861,6 +2669,22 @@ impl Lists {
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
      */
     pub async fn delete_members(&self, list_id: &str, subscriber_hash: &str) -> ClientResult<()> {
+        self.delete_members_with_response(list_id, subscriber_hash)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Archive list member.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `delete_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_members_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/members/{}",
@@ -1870,7 +2694,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1899,6 +2723,25 @@ impl Lists {
         skip_merge_validation: bool,
         body: &crate::types::AddListMembersDataType,
     ) -> ClientResult<crate::types::ListMembersData> {
+        Ok(self
+            .patch_members_with_response(list_id, subscriber_hash, skip_merge_validation, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update list member.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `patch_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_members_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersDataType,
+    ) -> ClientResult<crate::Response<crate::types::ListMembersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if skip_merge_validation {
             query_args.push((
@@ -1917,7 +2760,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1949,15 +2792,41 @@ impl Lists {
         exclude_fields: &[String],
         action: &[String],
     ) -> ClientResult<crate::types::MemberActivityEvents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !action.is_empty() {
-            query_args.push(("action".to_string(), action.join(" ")));
-        }
-        if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+        Ok(self
+            .get_members_activity_with_response(
+                list_id,
+                subscriber_hash,
+                fields,
+                exclude_fields,
+                action,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * View recent activity 50.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/activity` endpoint.
+     *
+     * As opposed to `get_members_activity`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_members_activity_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        action: &[String],
+    ) -> ClientResult<crate::Response<crate::types::MemberActivityEvents>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !action.is_empty() {
+            query_args.push(("action".to_string(), action.join(" ")));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
@@ -1970,7 +2839,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2006,6 +2875,36 @@ impl Lists {
         offset: i64,
         activity_filters: &[String],
     ) -> ClientResult<crate::types::MemberActivityEventsData> {
+        Ok(self
+            .get_members_activity_feed_with_response(
+                list_id,
+                subscriber_hash,
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                activity_filters,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * View recent activity.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/activity-feed` endpoint.
+     *
+     * As opposed to `get_members_activity_feed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_members_activity_feed_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        activity_filters: &[String],
+    ) -> ClientResult<crate::Response<crate::types::MemberActivityEventsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !activity_filters.is_empty() {
             query_args.push(("activity_filters".to_string(), activity_filters.join(" ")));
@@ -2033,7 +2932,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2067,6 +2966,34 @@ impl Lists {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::CollectionOfTags> {
+        Ok(self
+            .get_member_tag_with_response(
+                list_id,
+                subscriber_hash,
+                fields,
+                exclude_fields,
+                count,
+                offset,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List member tags.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/tags` endpoint.
+     *
+     * As opposed to `get_member_tag`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_member_tag_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfTags>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -2091,7 +3018,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2118,6 +3045,23 @@ impl Lists {
         subscriber_hash: &str,
         body: &crate::types::MemberTags,
     ) -> ClientResult<()> {
+        self.post_member_tag_with_response(list_id, subscriber_hash, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add or remove member tags.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/members/{subscriber_hash}/tags` endpoint.
+     *
+     * As opposed to `post_member_tag`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_member_tag_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberTags,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/members/{}/tags",
@@ -2127,7 +3071,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2161,6 +3105,34 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::CollectionOfEvents> {
+        Ok(self
+            .get_members_event_with_response(
+                list_id,
+                subscriber_hash,
+                count,
+                offset,
+                fields,
+                exclude_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List member events.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/events` endpoint.
+     *
+     * As opposed to `get_members_event`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_members_event_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfEvents>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -2185,7 +3157,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2212,6 +3184,23 @@ impl Lists {
         subscriber_hash: &str,
         body: &crate::types::EventsData,
     ) -> ClientResult<()> {
+        self.post_member_event_with_response(list_id, subscriber_hash, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add event.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/members/{subscriber_hash}/events` endpoint.
+     *
+     * As opposed to `post_member_event`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_member_event_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::EventsData,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/members/{}/events",
@@ -2221,7 +3210,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2251,6 +3240,25 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::CollectionOfMemberActivityEvents> {
+        Ok(self
+            .get_members_goal_with_response(list_id, subscriber_hash, fields, exclude_fields)
+            .await?
+            .body)
+    }
+    /**
+     * List member goal events.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/goals` endpoint.
+     *
+     * As opposed to `get_members_goal`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_members_goal_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfMemberActivityEvents>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -2269,7 +3277,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2307,6 +3315,38 @@ impl Lists {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::CollectionOfNotes> {
+        Ok(self
+            .get_members_note_with_response(
+                list_id,
+                subscriber_hash,
+                sort_field,
+                sort_dir,
+                fields,
+                exclude_fields,
+                count,
+                offset,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List recent member notes.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/notes` endpoint.
+     *
+     * As opposed to `get_members_note`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_members_note_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        sort_field: crate::types::GetListsMembersNotesSortField,
+        sort_dir: crate::types::SortDir,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotes>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -2337,7 +3377,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2364,6 +3404,24 @@ impl Lists {
         subscriber_hash: &str,
         body: &crate::types::MemberNotes,
     ) -> ClientResult<crate::types::CollectionOfNotesMember> {
+        Ok(self
+            .post_members_note_with_response(list_id, subscriber_hash, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add member note.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/members/{subscriber_hash}/notes` endpoint.
+     *
+     * As opposed to `post_members_note`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_members_note_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberNotes,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/members/{}/notes",
@@ -2373,7 +3431,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2405,6 +3463,32 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::CollectionOfNotesMember> {
+        Ok(self
+            .get_members_note_lists_with_response(
+                list_id,
+                subscriber_hash,
+                note_id,
+                fields,
+                exclude_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get member note.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}` endpoint.
+     *
+     * As opposed to `get_members_note_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_members_note_lists_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -2424,7 +3508,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2452,6 +3536,23 @@ impl Lists {
         subscriber_hash: &str,
         note_id: &str,
     ) -> ClientResult<()> {
+        self.delete_members_notes_with_response(list_id, subscriber_hash, note_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete note.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}` endpoint.
+     *
+     * As opposed to `delete_members_notes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_members_notes_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/members/{}/notes/{}",
@@ -2462,7 +3563,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2491,6 +3592,25 @@ impl Lists {
         note_id: &str,
         body: &crate::types::MemberNotes,
     ) -> ClientResult<crate::types::CollectionOfNotesMember> {
+        Ok(self
+            .patch_members_notes_with_response(list_id, subscriber_hash, note_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update note.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}` endpoint.
+     *
+     * As opposed to `patch_members_notes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_members_notes_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        body: &crate::types::MemberNotes,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfNotesMember>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/members/{}/notes/{}",
@@ -2501,7 +3621,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2527,6 +3647,22 @@ impl Lists {
         list_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<()> {
+        self.post_members_hash_actions_delete_permanent_with_response(list_id, subscriber_hash)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete list member.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/members/{subscriber_hash}/actions/delete-permanent` endpoint.
+     *
+     * As opposed to `post_members_hash_actions_delete_permanent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_members_hash_actions_delete_permanent_with_response(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/members/{}/actions/delete-permanent",
@@ -2536,7 +3672,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2572,6 +3708,36 @@ impl Lists {
         type_: &str,
         required: bool,
     ) -> ClientResult<crate::types::CollectionOfMergeFields> {
+        Ok(self
+            .get_merge_field_with_response(
+                list_id,
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                type_,
+                required,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List merge fields.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/merge-fields` endpoint.
+     *
+     * As opposed to `get_merge_field`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_merge_field_with_response(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        required: bool,
+    ) -> ClientResult<crate::Response<crate::types::CollectionOfMergeFields>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -2601,7 +3767,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2626,6 +3792,23 @@ impl Lists {
         list_id: &str,
         body: &crate::types::MergeFieldData,
     ) -> ClientResult<crate::types::MergeField> {
+        Ok(self
+            .post_merge_field_with_response(list_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add merge field.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/merge-fields` endpoint.
+     *
+     * As opposed to `post_merge_field`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_merge_field_with_response(
+        &self,
+        list_id: &str,
+        body: &crate::types::MergeFieldData,
+    ) -> ClientResult<crate::Response<crate::types::MergeField>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/merge-fields",
@@ -2634,7 +3817,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2664,6 +3847,25 @@ impl Lists {
         exclude_fields: &[String],
         fields: &[String],
     ) -> ClientResult<crate::types::MergeField> {
+        Ok(self
+            .get_merge_field_lists_with_response(list_id, merge_id, exclude_fields, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get merge field.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/merge-fields/{merge_id}` endpoint.
+     *
+     * As opposed to `get_merge_field_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_merge_field_lists_with_response(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        exclude_fields: &[String],
+        fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::MergeField>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -2682,7 +3884,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2704,6 +3906,22 @@ impl Lists {
      * * `merge_id: &str` -- The id for the merge field.
      */
     pub async fn delete_merge_fields(&self, list_id: &str, merge_id: &str) -> ClientResult<()> {
+        self.delete_merge_fields_with_response(list_id, merge_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete merge field.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/merge-fields/{merge_id}` endpoint.
+     *
+     * As opposed to `delete_merge_fields`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_merge_fields_with_response(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/merge-fields/{}",
@@ -2713,7 +3931,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2740,6 +3958,24 @@ impl Lists {
         merge_id: &str,
         body: &crate::types::MergeFieldDataType,
     ) -> ClientResult<crate::types::MergeField> {
+        Ok(self
+            .patch_merge_fields_with_response(list_id, merge_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update merge field.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/merge-fields/{merge_id}` endpoint.
+     *
+     * As opposed to `patch_merge_fields`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_merge_fields_with_response(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        body: &crate::types::MergeFieldDataType,
+    ) -> ClientResult<crate::Response<crate::types::MergeField>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/merge-fields/{}",
@@ -2749,7 +3985,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2770,6 +4006,19 @@ impl Lists {
      * * `list_id: &str` -- The unique ID for the list.
      */
     pub async fn get_webhook(&self, list_id: &str) -> ClientResult<crate::types::ListWebhooksData> {
+        Ok(self.get_webhook_with_response(list_id).await?.body)
+    }
+    /**
+     * List webhooks.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/webhooks` endpoint.
+     *
+     * As opposed to `get_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_webhook_with_response(
+        &self,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooksData>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/webhooks",
@@ -2778,7 +4027,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2803,6 +4052,20 @@ impl Lists {
         list_id: &str,
         body: &crate::types::AddWebhook,
     ) -> ClientResult<crate::types::ListWebhooks> {
+        Ok(self.post_webhook_with_response(list_id, body).await?.body)
+    }
+    /**
+     * Add webhook.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/webhooks` endpoint.
+     *
+     * As opposed to `post_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_webhook_with_response(
+        &self,
+        list_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooks>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/webhooks",
@@ -2811,7 +4074,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2837,6 +4100,23 @@ impl Lists {
         list_id: &str,
         webhook_id: &str,
     ) -> ClientResult<crate::types::ListWebhooks> {
+        Ok(self
+            .get_webhook_lists_with_response(list_id, webhook_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get webhook info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/webhooks/{webhook_id}` endpoint.
+     *
+     * As opposed to `get_webhook_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_webhook_lists_with_response(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooks>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/webhooks/{}",
@@ -2846,7 +4126,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2868,6 +4148,22 @@ impl Lists {
      * * `webhook_id: &str` -- The name of the folder.
      */
     pub async fn delete_webhooks(&self, list_id: &str, webhook_id: &str) -> ClientResult<()> {
+        self.delete_webhooks_with_response(list_id, webhook_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete webhook.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/webhooks/{webhook_id}` endpoint.
+     *
+     * As opposed to `delete_webhooks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_webhooks_with_response(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/webhooks/{}",
@@ -2877,7 +4173,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2904,6 +4200,24 @@ impl Lists {
         webhook_id: &str,
         body: &crate::types::AddWebhook,
     ) -> ClientResult<crate::types::ListWebhooks> {
+        Ok(self
+            .patch_webhooks_with_response(list_id, webhook_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update webhook.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/webhooks/{webhook_id}` endpoint.
+     *
+     * As opposed to `patch_webhooks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_webhooks_with_response(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> ClientResult<crate::Response<crate::types::ListWebhooks>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/webhooks/{}",
@@ -2913,7 +4227,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2937,6 +4251,19 @@ impl Lists {
         &self,
         list_id: &str,
     ) -> ClientResult<crate::types::ListSignupForms> {
+        Ok(self.get_signup_form_with_response(list_id).await?.body)
+    }
+    /**
+     * List signup forms.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/signup-forms` endpoint.
+     *
+     * As opposed to `get_signup_form`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_signup_form_with_response(
+        &self,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListSignupForms>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/signup-forms",
@@ -2945,7 +4272,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2970,6 +4297,23 @@ impl Lists {
         list_id: &str,
         body: &crate::types::SignupFormData,
     ) -> ClientResult<crate::types::SignupForm> {
+        Ok(self
+            .post_signup_form_with_response(list_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Customize signup form.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/signup-forms` endpoint.
+     *
+     * As opposed to `post_signup_form`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_signup_form_with_response(
+        &self,
+        list_id: &str,
+        body: &crate::types::SignupFormData,
+    ) -> ClientResult<crate::Response<crate::types::SignupForm>> {
         let url = self.client.url(
             &format!(
                 "/lists/{}/signup-forms",
@@ -2978,7 +4322,7 @@ impl Lists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3006,6 +4350,24 @@ impl Lists {
         exclude_fields: &[String],
         list_id: &str,
     ) -> ClientResult<crate::types::ListLocations> {
+        Ok(self
+            .get_location_with_response(fields, exclude_fields, list_id)
+            .await?
+            .body)
+    }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/locations` endpoint.
+     *
+     * As opposed to `get_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_location_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListLocations>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -3023,7 +4385,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
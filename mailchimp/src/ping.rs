@@ -19,9 +19,21 @@ impl Ping {
      * A health check for the API that won't return any account-specific information.
      */
     pub async fn get(&self) -> ClientResult<crate::types::ApiHealthStatus> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * Ping.
+     *
+     * This function performs a `GET` to the `/ping` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ApiHealthStatus>> {
         let url = self.client.url("/ping", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
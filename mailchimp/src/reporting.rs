@@ -36,6 +36,34 @@ impl Reporting {
         sort_field: crate::types::GetAllFacebookAdsSortField,
         sort_dir: crate::types::SortDir,
     ) -> ClientResult<crate::types::GetReportingFacebookAdsResponse> {
+        Ok(self
+            .get_facebook_ads_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                sort_field,
+                sort_dir,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List facebook ads reports.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads` endpoint.
+     *
+     * As opposed to `get_facebook_ads`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_facebook_ads_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -60,7 +88,7 @@ impl Reporting {
             .client
             .url(&format!("/reporting/facebook-ads?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -88,6 +116,24 @@ impl Reporting {
         outreach_id: &str,
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::GetReportingFacebookAdsResponseAllOf> {
+        Ok(self
+            .get_facebook_ad_with_response(fields, outreach_id, exclude_fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get facebook ad report.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads/{outreach_id}` endpoint.
+     *
+     * As opposed to `get_facebook_ad`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_facebook_ad_with_response(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::GetReportingFacebookAdsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -105,7 +151,7 @@ impl Reporting {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -139,6 +185,35 @@ impl Reporting {
         outreach_id: &str,
         sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
     ) -> ClientResult<crate::types::GetReportsEcommerceProductActivityResponse> {
+        Ok(self
+            .get_facebook_ads_ecommerce_product_activity_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                outreach_id,
+                sort_field,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List facebook ecommerce report.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads/{outreach_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_facebook_ads_ecommerce_product_activity`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_facebook_ads_ecommerce_product_activity_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        outreach_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -165,7 +240,7 @@ impl Reporting {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -193,6 +268,24 @@ impl Reporting {
         outreach_id: &str,
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::LandingPages> {
+        Ok(self
+            .get_landing_page_with_response(fields, outreach_id, exclude_fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get landing page report.
+     *
+     * This function performs a `GET` to the `/reporting/landing-pages/{outreach_id}` endpoint.
+     *
+     * As opposed to `get_landing_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_landing_page_with_response(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::LandingPages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -210,7 +303,7 @@ impl Reporting {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -240,6 +333,25 @@ impl Reporting {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::GetReportingLandingPagesResponse> {
+        Ok(self
+            .get_landing_pages_with_response(fields, exclude_fields, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List landing pages reports.
+     *
+     * This function performs a `GET` to the `/reporting/landing-pages` endpoint.
+     *
+     * As opposed to `get_landing_pages`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_landing_pages_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetReportingLandingPagesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -258,7 +370,7 @@ impl Reporting {
             .client
             .url(&format!("/reporting/landing-pages?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
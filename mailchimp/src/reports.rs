@@ -38,6 +38,36 @@ impl Reports {
         before_send_time: Option<chrono::DateTime<chrono::Utc>>,
         since_send_time: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<crate::types::CampaignReportsData> {
+        Ok(self
+            .get_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                type_,
+                before_send_time,
+                since_send_time,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List campaign reports.
+     *
+     * This function performs a `GET` to the `/reports` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<crate::types::CampaignReportsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = before_send_time {
             query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
@@ -63,7 +93,7 @@ impl Reports {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/reports?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -91,6 +121,24 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::Reports> {
+        Ok(self
+            .get_reports_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign report.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}` endpoint.
+     *
+     * As opposed to `get_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_reports_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Reports>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -108,7 +156,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -136,6 +184,24 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::AbuseComplaintsData> {
+        Ok(self
+            .get_abuse_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List abuse reports.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/abuse-reports` endpoint.
+     *
+     * As opposed to `get_abuse`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_abuse_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseComplaintsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -153,7 +219,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -183,6 +249,25 @@ impl Reports {
         campaign_id: &str,
         report_id: &str,
     ) -> ClientResult<crate::types::AbuseComplaint> {
+        Ok(self
+            .get_abuse_reports_with_response(fields, exclude_fields, campaign_id, report_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get abuse report.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/abuse-reports/{report_id}` endpoint.
+     *
+     * As opposed to `get_abuse_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_abuse_reports_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        report_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AbuseComplaint>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -201,7 +286,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -229,6 +314,24 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::CampaignAdviceReport> {
+        Ok(self
+            .get_advice_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List campaign feedback.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/advice` endpoint.
+     *
+     * As opposed to `get_advice`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_advice_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignAdviceReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -246,7 +349,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -278,6 +381,26 @@ impl Reports {
         offset: i64,
         campaign_id: &str,
     ) -> ClientResult<crate::types::ClickDetailReport> {
+        Ok(self
+            .get_click_detail_with_response(fields, exclude_fields, count, offset, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List campaign details.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details` endpoint.
+     *
+     * As opposed to `get_click_detail`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_click_detail_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ClickDetailReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -301,7 +424,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -331,6 +454,25 @@ impl Reports {
         campaign_id: &str,
         link_id: &str,
     ) -> ClientResult<crate::types::UrlsClicked> {
+        Ok(self
+            .get_click_detail_reports_with_response(fields, exclude_fields, campaign_id, link_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign link details.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}` endpoint.
+     *
+     * As opposed to `get_click_detail_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_click_detail_reports_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UrlsClicked>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -349,7 +491,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -383,6 +525,34 @@ impl Reports {
         campaign_id: &str,
         link_id: &str,
     ) -> ClientResult<crate::types::ClickDetailMembers> {
+        Ok(self
+            .get_click_details_member_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                campaign_id,
+                link_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List clicked link subscribers.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members` endpoint.
+     *
+     * As opposed to `get_click_details_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_click_details_member_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        link_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ClickDetailMembers>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -407,7 +577,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -439,6 +609,32 @@ impl Reports {
         link_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<crate::types::ClickDetailMember> {
+        Ok(self
+            .get_click_details_member_reports_with_response(
+                fields,
+                exclude_fields,
+                campaign_id,
+                link_id,
+                subscriber_hash,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get clicked link subscriber.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_click_details_member_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_click_details_member_reports_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::ClickDetailMember>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -458,7 +654,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -492,6 +688,34 @@ impl Reports {
         campaign_id: &str,
         since: &str,
     ) -> ClientResult<crate::types::OpenDetailReport> {
+        Ok(self
+            .get_open_detail_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                campaign_id,
+                since,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List campaign open details.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details` endpoint.
+     *
+     * As opposed to `get_open_detail`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_open_detail_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> ClientResult<crate::Response<crate::types::OpenDetailReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -518,7 +742,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -548,6 +772,30 @@ impl Reports {
         campaign_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<crate::types::OpenActivity> {
+        Ok(self
+            .get_open_details_member_with_response(
+                fields,
+                exclude_fields,
+                campaign_id,
+                subscriber_hash,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get opened campaign subscriber.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_open_details_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_open_details_member_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::OpenActivity>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -566,7 +814,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -594,6 +842,24 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::DomainPerformance> {
+        Ok(self
+            .get_domain_performance_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List domain performance stats.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/domain-performance` endpoint.
+     *
+     * As opposed to `get_domain_performance`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_domain_performance_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainPerformance>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -611,7 +877,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -639,6 +905,24 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::EepurlActivity> {
+        Ok(self
+            .get_eepurl_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List EepURL activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/eepurl` endpoint.
+     *
+     * As opposed to `get_eepurl`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_eepurl_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EepurlActivity>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -656,7 +940,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -690,6 +974,34 @@ impl Reports {
         campaign_id: &str,
         since: &str,
     ) -> ClientResult<crate::types::EmailActivityData> {
+        Ok(self
+            .get_email_activity_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                campaign_id,
+                since,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity` endpoint.
+     *
+     * As opposed to `get_email_activity`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_email_activity_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailActivityData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -716,7 +1028,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -748,6 +1060,32 @@ impl Reports {
         subscriber_hash: &str,
         since: &str,
     ) -> ClientResult<crate::types::EmailActivity> {
+        Ok(self
+            .get_email_activity_reports_with_response(
+                fields,
+                exclude_fields,
+                campaign_id,
+                subscriber_hash,
+                since,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get subscriber email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_email_activity_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_email_activity_reports_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+        since: &str,
+    ) -> ClientResult<crate::Response<crate::types::EmailActivity>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -769,7 +1107,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -801,6 +1139,26 @@ impl Reports {
         count: i64,
         offset: i64,
     ) -> ClientResult<crate::types::OpenLocationsData> {
+        Ok(self
+            .get_location_with_response(fields, exclude_fields, campaign_id, count, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List top open activities.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/locations` endpoint.
+     *
+     * As opposed to `get_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_location_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        count: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::OpenLocationsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -824,7 +1182,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -856,6 +1214,26 @@ impl Reports {
         offset: i64,
         campaign_id: &str,
     ) -> ClientResult<crate::types::SentData> {
+        Ok(self
+            .get_sent_with_response(fields, exclude_fields, count, offset, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List campaign recipients.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sent-to` endpoint.
+     *
+     * As opposed to `get_sent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sent_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SentData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -879,7 +1257,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -909,6 +1287,25 @@ impl Reports {
         campaign_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<crate::types::SentTo> {
+        Ok(self
+            .get_sent_reports_with_response(fields, exclude_fields, campaign_id, subscriber_hash)
+            .await?
+            .body)
+    }
+    /**
+     * Get campaign recipient info.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sent-to/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_sent_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sent_reports_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::SentTo>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -927,7 +1324,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -955,6 +1352,24 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> ClientResult<crate::types::CampaignSubReports> {
+        Ok(self
+            .get_sub_with_response(fields, exclude_fields, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List child campaign reports.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sub-reports` endpoint.
+     *
+     * As opposed to `get_sub`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sub_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CampaignSubReports>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -972,7 +1387,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1004,6 +1419,26 @@ impl Reports {
         offset: i64,
         campaign_id: &str,
     ) -> ClientResult<crate::types::UnsubscribesData> {
+        Ok(self
+            .get_unsubscribed_with_response(fields, exclude_fields, count, offset, campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * List unsubscribed members.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/unsubscribed` endpoint.
+     *
+     * As opposed to `get_unsubscribed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_unsubscribed_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UnsubscribesData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1027,7 +1462,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1057,6 +1492,30 @@ impl Reports {
         campaign_id: &str,
         subscriber_hash: &str,
     ) -> ClientResult<crate::types::Unsubscribes> {
+        Ok(self
+            .get_unsubscribed_reports_with_response(
+                fields,
+                exclude_fields,
+                campaign_id,
+                subscriber_hash,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get unsubscribed member.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/unsubscribed/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `get_unsubscribed_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_unsubscribed_reports_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::Unsubscribes>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -1075,7 +1534,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1109,6 +1568,35 @@ impl Reports {
         campaign_id: &str,
         sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
     ) -> ClientResult<crate::types::GetReportsEcommerceProductActivityResponse> {
+        Ok(self
+            .get_ecommerce_product_activity_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                campaign_id,
+                sort_field,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List campaign product activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_ecommerce_product_activity`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ecommerce_product_activity_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> ClientResult<crate::Response<crate::types::GetReportsEcommerceProductActivityResponse>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -1135,7 +1623,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
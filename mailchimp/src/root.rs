@@ -28,6 +28,20 @@ impl Root {
         fields: &[String],
         exclude_fields: &[String],
     ) -> ClientResult<crate::types::ApiRoot> {
+        Ok(self.get_with_response(fields, exclude_fields).await?.body)
+    }
+    /**
+     * List api root resources.
+     *
+     * This function performs a `GET` to the `/` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> ClientResult<crate::Response<crate::types::ApiRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -38,7 +52,7 @@ impl Root {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
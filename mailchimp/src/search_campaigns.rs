@@ -30,6 +30,24 @@ impl SearchCampaigns {
         exclude_fields: &[String],
         query: &str,
     ) -> ClientResult<crate::types::Campaigns> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, query)
+            .await?
+            .body)
+    }
+    /**
+     * Search campaigns.
+     *
+     * This function performs a `GET` to the `/search-campaigns` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        query: &str,
+    ) -> ClientResult<crate::Response<crate::types::Campaigns>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -45,7 +63,7 @@ impl SearchCampaigns {
             .client
             .url(&format!("/search-campaigns?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -32,6 +32,25 @@ impl SearchMembers {
         query: &str,
         list_id: &str,
     ) -> ClientResult<crate::types::MembersData> {
+        Ok(self
+            .get_with_response(fields, exclude_fields, query, list_id)
+            .await?
+            .body)
+    }
+    /**
+     * Search members.
+     *
+     * This function performs a `GET` to the `/search-members` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        query: &str,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MembersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -50,7 +69,7 @@ impl SearchMembers {
             .client
             .url(&format!("/search-members?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
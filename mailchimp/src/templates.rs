@@ -48,6 +48,46 @@ impl Templates {
         sort_field: crate::types::GetTemplatesSortField,
         sort_dir: crate::types::SortDir,
     ) -> ClientResult<crate::types::TemplatesData> {
+        Ok(self
+            .get_with_response(
+                fields,
+                exclude_fields,
+                count,
+                offset,
+                created_by,
+                since_date_created,
+                before_date_created,
+                type_,
+                category,
+                folder_id,
+                sort_field,
+                sort_dir,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List templates.
+     *
+     * This function performs a `GET` to the `/templates` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        since_date_created: &str,
+        before_date_created: &str,
+        type_: &str,
+        category: &str,
+        folder_id: &str,
+        sort_field: crate::types::GetTemplatesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> ClientResult<crate::Response<crate::types::TemplatesData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before_date_created.is_empty() {
             query_args.push((
@@ -94,7 +134,7 @@ impl Templates {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/templates?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -114,9 +154,22 @@ impl Templates {
         &self,
         body: &crate::types::TemplateInstance,
     ) -> ClientResult<crate::types::Templates> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add template.
+     *
+     * This function performs a `POST` to the `/templates` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::TemplateInstance,
+    ) -> ClientResult<crate::Response<crate::types::Templates>> {
         let url = self.client.url("/templates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -144,6 +197,24 @@ impl Templates {
         exclude_fields: &[String],
         template_id: &str,
     ) -> ClientResult<crate::types::Templates> {
+        Ok(self
+            .get_templates_with_response(fields, exclude_fields, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get template info.
+     *
+     * This function performs a `GET` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `get_templates`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_templates_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Templates>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -161,7 +232,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -182,6 +253,20 @@ impl Templates {
      * * `template_id: &str` -- The unique id for the template.
      */
     pub async fn delete(&self, template_id: &str) -> ClientResult<()> {
+        self.delete_with_response(template_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete template.
+     *
+     * This function performs a `DELETE` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}",
@@ -190,7 +275,7 @@ impl Templates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +300,20 @@ impl Templates {
         template_id: &str,
         body: &crate::types::TemplateInstance,
     ) -> ClientResult<crate::types::Templates> {
+        Ok(self.patch_with_response(template_id, body).await?.body)
+    }
+    /**
+     * Update template.
+     *
+     * This function performs a `PATCH` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        template_id: &str,
+        body: &crate::types::TemplateInstance,
+    ) -> ClientResult<crate::Response<crate::types::Templates>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}",
@@ -223,7 +322,7 @@ impl Templates {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -251,6 +350,24 @@ impl Templates {
         exclude_fields: &[String],
         template_id: &str,
     ) -> ClientResult<crate::types::TemplateDefaultContent> {
+        Ok(self
+            .get_default_content_with_response(fields, exclude_fields, template_id)
+            .await?
+            .body)
+    }
+    /**
+     * View default content.
+     *
+     * This function performs a `GET` to the `/templates/{template_id}/default-content` endpoint.
+     *
+     * As opposed to `get_default_content`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_default_content_with_response(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TemplateDefaultContent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -268,7 +385,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
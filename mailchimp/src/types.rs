@@ -89,6 +89,7 @@ pub struct Interest {
 * Determines how this category’s interests appear on signup forms.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "checkboxes")]
     Checkboxes,
@@ -100,8 +101,9 @@ pub enum Type {
     Radio,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -112,12 +114,34 @@ impl std::fmt::Display for Type {
             Type::Hidden => "hidden",
             Type::Radio => "radio",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "checkboxes" => Type::Checkboxes,
+            "dropdown" => Type::Dropdown,
+            "hidden" => Type::Hidden,
+            "radio" => Type::Radio,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -367,6 +391,7 @@ pub struct ECommerceProductVariant {
 * The type of pricing plan the account is on.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PricingPlanType {
     #[serde(rename = "forever_free")]
     ForeverFree,
@@ -376,8 +401,9 @@ pub enum PricingPlanType {
     PayAsYouGo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PricingPlanType {
@@ -387,12 +413,33 @@ impl std::fmt::Display for PricingPlanType {
             PricingPlanType::Monthly => "monthly",
             PricingPlanType::PayAsYouGo => "pay_as_you_go",
             PricingPlanType::Noop => "",
-            PricingPlanType::FallthroughString => "*",
+            PricingPlanType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PricingPlanType {
+    fn from(s: String) -> PricingPlanType {
+        match s.as_str() {
+            "forever_free" => PricingPlanType::ForeverFree,
+            "monthly" => PricingPlanType::Monthly,
+            "pay_as_you_go" => PricingPlanType::PayAsYouGo,
+            "" => PricingPlanType::Noop,
+            _ => PricingPlanType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PricingPlanType> for String {
+    fn from(e: PricingPlanType) -> String {
+        match e {
+            PricingPlanType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PricingPlanType {
     fn default() -> PricingPlanType {
         PricingPlanType::Noop
@@ -510,6 +557,7 @@ pub struct IndustryStats {
 * The HTTP method that should be used when accessing the URL defined in 'href'.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Method {
     #[serde(rename = "DELETE")]
     Delete,
@@ -527,8 +575,9 @@ pub enum Method {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -542,12 +591,37 @@ impl std::fmt::Display for Method {
             Method::Post => "POST",
             Method::Put => "PUT",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Method {
+    fn from(s: String) -> Method {
+        match s.as_str() {
+            "DELETE" => Method::Delete,
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "OPTIONS" => Method::Options,
+            "PATCH" => Method::Patch,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            "" => Method::Noop,
+            _ => Method::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Method> for String {
+    fn from(e: Method) -> String {
+        match e {
+            Method::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Method {
     fn default() -> Method {
         Method::Noop
@@ -783,6 +857,7 @@ pub struct ApiRoot {
 * The type of activity
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetActivityFeedChimpChatterResponseType {
     #[serde(rename = "campaigns:facebook-likes")]
     CampaignsFacebookLikes,
@@ -798,8 +873,9 @@ pub enum GetActivityFeedChimpChatterResponseType {
     ListsUnsubscribes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
@@ -816,12 +892,40 @@ impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
             GetActivityFeedChimpChatterResponseType::ListsProfileUpdates => "lists:profile-updates",
             GetActivityFeedChimpChatterResponseType::ListsUnsubscribes => "lists:unsubscribes",
             GetActivityFeedChimpChatterResponseType::Noop => "",
-            GetActivityFeedChimpChatterResponseType::FallthroughString => "*",
+            GetActivityFeedChimpChatterResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetActivityFeedChimpChatterResponseType {
+    fn from(s: String) -> GetActivityFeedChimpChatterResponseType {
+        match s.as_str() {
+            "campaigns:facebook-likes" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsFacebookLikes
+            }
+            "campaigns:forward-to-friend" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsForwardToFriend
+            }
+            "lists:imports" => GetActivityFeedChimpChatterResponseType::ListsImports,
+            "lists:new-subscriber" => GetActivityFeedChimpChatterResponseType::ListsNewSubscriber,
+            "lists:profile-updates" => GetActivityFeedChimpChatterResponseType::ListsProfileUpdates,
+            "lists:unsubscribes" => GetActivityFeedChimpChatterResponseType::ListsUnsubscribes,
+            "" => GetActivityFeedChimpChatterResponseType::Noop,
+            _ => GetActivityFeedChimpChatterResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetActivityFeedChimpChatterResponseType> for String {
+    fn from(e: GetActivityFeedChimpChatterResponseType) -> String {
+        match e {
+            GetActivityFeedChimpChatterResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetActivityFeedChimpChatterResponseType {
     fn default() -> GetActivityFeedChimpChatterResponseType {
         GetActivityFeedChimpChatterResponseType::Noop
@@ -1018,6 +1122,7 @@ pub struct GetAuthorizedAppsResponse {
 * Restrict the results to automations with the specified status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "paused")]
     Paused,
@@ -1027,8 +1132,9 @@ pub enum Status {
     Sending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1038,12 +1144,33 @@ impl std::fmt::Display for Status {
             Status::Save => "save",
             Status::Sending => "sending",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "paused" => Status::Paused,
+            "save" => Status::Save,
+            "sending" => Status::Sending,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1059,6 +1186,7 @@ impl Status {
 * Segment match type.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Match {
     #[serde(rename = "all")]
     All,
@@ -1066,8 +1194,9 @@ pub enum Match {
     Any,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Match {
@@ -1076,12 +1205,32 @@ impl std::fmt::Display for Match {
             Match::All => "all",
             Match::Any => "any",
             Match::Noop => "",
-            Match::FallthroughString => "*",
+            Match::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Match {
+    fn from(s: String) -> Match {
+        match s.as_str() {
+            "all" => Match::All,
+            "any" => Match::Any,
+            "" => Match::Noop,
+            _ => Match::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Match> for String {
+    fn from(e: Match) -> String {
+        match e {
+            Match::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Match {
     fn default() -> Match {
         Match::Noop
@@ -1094,13 +1243,15 @@ impl Match {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ConditionType {
     #[serde(rename = "Aim")]
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConditionType {
@@ -1108,12 +1259,31 @@ impl std::fmt::Display for ConditionType {
         match self {
             ConditionType::Aim => "Aim",
             ConditionType::Noop => "",
-            ConditionType::FallthroughString => "*",
+            ConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ConditionType {
+    fn from(s: String) -> ConditionType {
+        match s.as_str() {
+            "Aim" => ConditionType::Aim,
+            "" => ConditionType::Noop,
+            _ => ConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ConditionType> for String {
+    fn from(e: ConditionType) -> String {
+        match e {
+            ConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ConditionType {
     fn default() -> ConditionType {
         ConditionType::Noop
@@ -1129,13 +1299,15 @@ impl ConditionType {
 * Segment by interaction with a specific campaign.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Field {
     #[serde(rename = "aim")]
     Aim,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Field {
@@ -1143,12 +1315,31 @@ impl std::fmt::Display for Field {
         match self {
             Field::Aim => "aim",
             Field::Noop => "",
-            Field::FallthroughString => "*",
+            Field::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Field {
+    fn from(s: String) -> Field {
+        match s.as_str() {
+            "aim" => Field::Aim,
+            "" => Field::Noop,
+            _ => Field::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Field> for String {
+    fn from(e: Field) -> String {
+        match e {
+            Field::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Field {
     fn default() -> Field {
         Field::Noop
@@ -1164,6 +1355,7 @@ impl Field {
 * The status of the member with regard to their campaign interaction. One of the following: opened, clicked, was sent, didn't open, didn't click, or was not sent.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Op {
     #[serde(rename = "click")]
     Click,
@@ -1179,8 +1371,9 @@ pub enum Op {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Op {
@@ -1193,12 +1386,36 @@ impl std::fmt::Display for Op {
             Op::Open => "open",
             Op::Sent => "sent",
             Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Op {
+    fn from(s: String) -> Op {
+        match s.as_str() {
+            "click" => Op::Click,
+            "noclick" => Op::Noclick,
+            "noopen" => Op::Noopen,
+            "nosent" => Op::Nosent,
+            "open" => Op::Open,
+            "sent" => Op::Sent,
+            "" => Op::Noop,
+            _ => Op::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Op> for String {
+    fn from(e: Op) -> String {
+        match e {
+            Op::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Op {
     fn default() -> Op {
         Op::Noop
@@ -1240,13 +1457,15 @@ pub struct Conditions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AutomationSegmentConditionType {
     #[serde(rename = "Automation")]
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutomationSegmentConditionType {
@@ -1254,12 +1473,31 @@ impl std::fmt::Display for AutomationSegmentConditionType {
         match self {
             AutomationSegmentConditionType::Automation => "Automation",
             AutomationSegmentConditionType::Noop => "",
-            AutomationSegmentConditionType::FallthroughString => "*",
+            AutomationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AutomationSegmentConditionType {
+    fn from(s: String) -> AutomationSegmentConditionType {
+        match s.as_str() {
+            "Automation" => AutomationSegmentConditionType::Automation,
+            "" => AutomationSegmentConditionType::Noop,
+            _ => AutomationSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AutomationSegmentConditionType> for String {
+    fn from(e: AutomationSegmentConditionType) -> String {
+        match e {
+            AutomationSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AutomationSegmentConditionType {
     fn default() -> AutomationSegmentConditionType {
         AutomationSegmentConditionType::Noop
@@ -1275,13 +1513,15 @@ impl AutomationSegmentConditionType {
 * Segment by interaction with an Automation workflow.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SegmentField {
     #[serde(rename = "automation")]
     Automation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentField {
@@ -1289,12 +1529,31 @@ impl std::fmt::Display for SegmentField {
         match self {
             SegmentField::Automation => "automation",
             SegmentField::Noop => "",
-            SegmentField::FallthroughString => "*",
+            SegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SegmentField {
+    fn from(s: String) -> SegmentField {
+        match s.as_str() {
+            "automation" => SegmentField::Automation,
+            "" => SegmentField::Noop,
+            _ => SegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SegmentField> for String {
+    fn from(e: SegmentField) -> String {
+        match e {
+            SegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SegmentField {
     fn default() -> SegmentField {
         SegmentField::Noop
@@ -1310,6 +1569,7 @@ impl SegmentField {
 * The status of the member with regard to the automation workflow. One of the following: has started the workflow, has completed the workflow, has not started the workflow, or has not completed the workflow.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SegmentOperator {
     #[serde(rename = "completed")]
     Completed,
@@ -1321,8 +1581,9 @@ pub enum SegmentOperator {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SegmentOperator {
@@ -1333,12 +1594,34 @@ impl std::fmt::Display for SegmentOperator {
             SegmentOperator::NotStarted => "not_started",
             SegmentOperator::Started => "started",
             SegmentOperator::Noop => "",
-            SegmentOperator::FallthroughString => "*",
+            SegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SegmentOperator {
+    fn from(s: String) -> SegmentOperator {
+        match s.as_str() {
+            "completed" => SegmentOperator::Completed,
+            "not_completed" => SegmentOperator::NotCompleted,
+            "not_started" => SegmentOperator::NotStarted,
+            "started" => SegmentOperator::Started,
+            "" => SegmentOperator::Noop,
+            _ => SegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SegmentOperator> for String {
+    fn from(e: SegmentOperator) -> String {
+        match e {
+            SegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SegmentOperator {
     fn default() -> SegmentOperator {
         SegmentOperator::Noop
@@ -1380,13 +1663,15 @@ pub struct AutomationSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollActivitySegmentConditionType {
     #[serde(rename = "CampaignPoll")]
     CampaignPoll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentConditionType {
@@ -1394,12 +1679,31 @@ impl std::fmt::Display for PollActivitySegmentConditionType {
         match self {
             PollActivitySegmentConditionType::CampaignPoll => "CampaignPoll",
             PollActivitySegmentConditionType::Noop => "",
-            PollActivitySegmentConditionType::FallthroughString => "*",
+            PollActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PollActivitySegmentConditionType {
+    fn from(s: String) -> PollActivitySegmentConditionType {
+        match s.as_str() {
+            "CampaignPoll" => PollActivitySegmentConditionType::CampaignPoll,
+            "" => PollActivitySegmentConditionType::Noop,
+            _ => PollActivitySegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PollActivitySegmentConditionType> for String {
+    fn from(e: PollActivitySegmentConditionType) -> String {
+        match e {
+            PollActivitySegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PollActivitySegmentConditionType {
     fn default() -> PollActivitySegmentConditionType {
         PollActivitySegmentConditionType::Noop
@@ -1415,13 +1719,15 @@ impl PollActivitySegmentConditionType {
 * Segment by poll activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollActivitySegmentField {
     #[serde(rename = "poll")]
     Poll,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentField {
@@ -1429,12 +1735,31 @@ impl std::fmt::Display for PollActivitySegmentField {
         match self {
             PollActivitySegmentField::Poll => "poll",
             PollActivitySegmentField::Noop => "",
-            PollActivitySegmentField::FallthroughString => "*",
+            PollActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PollActivitySegmentField {
+    fn from(s: String) -> PollActivitySegmentField {
+        match s.as_str() {
+            "poll" => PollActivitySegmentField::Poll,
+            "" => PollActivitySegmentField::Noop,
+            _ => PollActivitySegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PollActivitySegmentField> for String {
+    fn from(e: PollActivitySegmentField) -> String {
+        match e {
+            PollActivitySegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PollActivitySegmentField {
     fn default() -> PollActivitySegmentField {
         PollActivitySegmentField::Noop
@@ -1450,6 +1775,7 @@ impl PollActivitySegmentField {
 * Members have/have not interacted with a specific poll in a Mailchimp email.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollActivitySegmentOperator {
     #[serde(rename = "member")]
     Member,
@@ -1457,8 +1783,9 @@ pub enum PollActivitySegmentOperator {
     Notmember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollActivitySegmentOperator {
@@ -1467,12 +1794,32 @@ impl std::fmt::Display for PollActivitySegmentOperator {
             PollActivitySegmentOperator::Member => "member",
             PollActivitySegmentOperator::Notmember => "notmember",
             PollActivitySegmentOperator::Noop => "",
-            PollActivitySegmentOperator::FallthroughString => "*",
+            PollActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PollActivitySegmentOperator {
+    fn from(s: String) -> PollActivitySegmentOperator {
+        match s.as_str() {
+            "member" => PollActivitySegmentOperator::Member,
+            "notmember" => PollActivitySegmentOperator::Notmember,
+            "" => PollActivitySegmentOperator::Noop,
+            _ => PollActivitySegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PollActivitySegmentOperator> for String {
+    fn from(e: PollActivitySegmentOperator) -> String {
+        match e {
+            PollActivitySegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PollActivitySegmentOperator {
     fn default() -> PollActivitySegmentOperator {
         PollActivitySegmentOperator::Noop
@@ -1514,13 +1861,15 @@ pub struct PollActivitySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ConversationSegmentConditionType {
     #[serde(rename = "Conversation")]
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentConditionType {
@@ -1528,12 +1877,31 @@ impl std::fmt::Display for ConversationSegmentConditionType {
         match self {
             ConversationSegmentConditionType::Conversation => "Conversation",
             ConversationSegmentConditionType::Noop => "",
-            ConversationSegmentConditionType::FallthroughString => "*",
+            ConversationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ConversationSegmentConditionType {
+    fn from(s: String) -> ConversationSegmentConditionType {
+        match s.as_str() {
+            "Conversation" => ConversationSegmentConditionType::Conversation,
+            "" => ConversationSegmentConditionType::Noop,
+            _ => ConversationSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ConversationSegmentConditionType> for String {
+    fn from(e: ConversationSegmentConditionType) -> String {
+        match e {
+            ConversationSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ConversationSegmentConditionType {
     fn default() -> ConversationSegmentConditionType {
         ConversationSegmentConditionType::Noop
@@ -1549,13 +1917,15 @@ impl ConversationSegmentConditionType {
 * Segment by interaction with a campaign via Conversations.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ConversationSegmentField {
     #[serde(rename = "conversation")]
     Conversation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ConversationSegmentField {
@@ -1563,12 +1933,31 @@ impl std::fmt::Display for ConversationSegmentField {
         match self {
             ConversationSegmentField::Conversation => "conversation",
             ConversationSegmentField::Noop => "",
-            ConversationSegmentField::FallthroughString => "*",
+            ConversationSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ConversationSegmentField {
+    fn from(s: String) -> ConversationSegmentField {
+        match s.as_str() {
+            "conversation" => ConversationSegmentField::Conversation,
+            "" => ConversationSegmentField::Noop,
+            _ => ConversationSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ConversationSegmentField> for String {
+    fn from(e: ConversationSegmentField) -> String {
+        match e {
+            ConversationSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ConversationSegmentField {
     fn default() -> ConversationSegmentField {
         ConversationSegmentField::Noop
@@ -1610,13 +1999,15 @@ pub struct ConversationSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateSegmentConditionType {
     #[serde(rename = "Date")]
     Date,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentConditionType {
@@ -1624,12 +2015,31 @@ impl std::fmt::Display for DateSegmentConditionType {
         match self {
             DateSegmentConditionType::Date => "Date",
             DateSegmentConditionType::Noop => "",
-            DateSegmentConditionType::FallthroughString => "*",
+            DateSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DateSegmentConditionType {
+    fn from(s: String) -> DateSegmentConditionType {
+        match s.as_str() {
+            "Date" => DateSegmentConditionType::Date,
+            "" => DateSegmentConditionType::Noop,
+            _ => DateSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DateSegmentConditionType> for String {
+    fn from(e: DateSegmentConditionType) -> String {
+        match e {
+            DateSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DateSegmentConditionType {
     fn default() -> DateSegmentConditionType {
         DateSegmentConditionType::Noop
@@ -1645,6 +2055,7 @@ impl DateSegmentConditionType {
 * The type of date field to segment on: The opt-in time for a signup, the date the subscriber was last updated, or the date of their last ecomm purchase.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateSegmentField {
     #[serde(rename = "ecomm_date")]
     EcommDate,
@@ -1654,8 +2065,9 @@ pub enum DateSegmentField {
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentField {
@@ -1665,12 +2077,33 @@ impl std::fmt::Display for DateSegmentField {
             DateSegmentField::InfoChanged => "info_changed",
             DateSegmentField::TimestampOpt => "timestamp_opt",
             DateSegmentField::Noop => "",
-            DateSegmentField::FallthroughString => "*",
+            DateSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DateSegmentField {
+    fn from(s: String) -> DateSegmentField {
+        match s.as_str() {
+            "ecomm_date" => DateSegmentField::EcommDate,
+            "info_changed" => DateSegmentField::InfoChanged,
+            "timestamp_opt" => DateSegmentField::TimestampOpt,
+            "" => DateSegmentField::Noop,
+            _ => DateSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DateSegmentField> for String {
+    fn from(e: DateSegmentField) -> String {
+        match e {
+            DateSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DateSegmentField {
     fn default() -> DateSegmentField {
         DateSegmentField::Noop
@@ -1686,6 +2119,7 @@ impl DateSegmentField {
 * When the event took place:  Before, after, is a specific date, is not a specific date, is blank, or is not blank.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -1705,8 +2139,9 @@ pub enum DateSegmentOperator {
     Within,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateSegmentOperator {
@@ -1721,12 +2156,38 @@ impl std::fmt::Display for DateSegmentOperator {
             DateSegmentOperator::Notwithin => "notwithin",
             DateSegmentOperator::Within => "within",
             DateSegmentOperator::Noop => "",
-            DateSegmentOperator::FallthroughString => "*",
+            DateSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DateSegmentOperator {
+    fn from(s: String) -> DateSegmentOperator {
+        match s.as_str() {
+            "blank" => DateSegmentOperator::Blank,
+            "blank_not" => DateSegmentOperator::BlankNot,
+            "greater" => DateSegmentOperator::Greater,
+            "is" => DateSegmentOperator::Is,
+            "less" => DateSegmentOperator::Less,
+            "not" => DateSegmentOperator::Not,
+            "notwithin" => DateSegmentOperator::Notwithin,
+            "within" => DateSegmentOperator::Within,
+            "" => DateSegmentOperator::Noop,
+            _ => DateSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DateSegmentOperator> for String {
+    fn from(e: DateSegmentOperator) -> String {
+        match e {
+            DateSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DateSegmentOperator {
     fn default() -> DateSegmentOperator {
         DateSegmentOperator::Noop
@@ -1777,13 +2238,15 @@ pub struct DateSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClientSegmentConditionType {
     #[serde(rename = "EmailClient")]
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentConditionType {
@@ -1791,12 +2254,31 @@ impl std::fmt::Display for EmailClientSegmentConditionType {
         match self {
             EmailClientSegmentConditionType::EmailClient => "EmailClient",
             EmailClientSegmentConditionType::Noop => "",
-            EmailClientSegmentConditionType::FallthroughString => "*",
+            EmailClientSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailClientSegmentConditionType {
+    fn from(s: String) -> EmailClientSegmentConditionType {
+        match s.as_str() {
+            "EmailClient" => EmailClientSegmentConditionType::EmailClient,
+            "" => EmailClientSegmentConditionType::Noop,
+            _ => EmailClientSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailClientSegmentConditionType> for String {
+    fn from(e: EmailClientSegmentConditionType) -> String {
+        match e {
+            EmailClientSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailClientSegmentConditionType {
     fn default() -> EmailClientSegmentConditionType {
         EmailClientSegmentConditionType::Noop
@@ -1812,13 +2294,15 @@ impl EmailClientSegmentConditionType {
 * Segment by use of a particular email client.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClientSegmentField {
     #[serde(rename = "email_client")]
     EmailClient,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentField {
@@ -1826,12 +2310,31 @@ impl std::fmt::Display for EmailClientSegmentField {
         match self {
             EmailClientSegmentField::EmailClient => "email_client",
             EmailClientSegmentField::Noop => "",
-            EmailClientSegmentField::FallthroughString => "*",
+            EmailClientSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailClientSegmentField {
+    fn from(s: String) -> EmailClientSegmentField {
+        match s.as_str() {
+            "email_client" => EmailClientSegmentField::EmailClient,
+            "" => EmailClientSegmentField::Noop,
+            _ => EmailClientSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailClientSegmentField> for String {
+    fn from(e: EmailClientSegmentField) -> String {
+        match e {
+            EmailClientSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailClientSegmentField {
     fn default() -> EmailClientSegmentField {
         EmailClientSegmentField::Noop
@@ -1847,6 +2350,7 @@ impl EmailClientSegmentField {
 * The operation to determine whether we select clients that match the value, or clients that do not match the value.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClientSegmentOperator {
     #[serde(rename = "client_is")]
     ClientIs,
@@ -1854,8 +2358,9 @@ pub enum EmailClientSegmentOperator {
     ClientNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClientSegmentOperator {
@@ -1864,12 +2369,32 @@ impl std::fmt::Display for EmailClientSegmentOperator {
             EmailClientSegmentOperator::ClientIs => "client_is",
             EmailClientSegmentOperator::ClientNot => "client_not",
             EmailClientSegmentOperator::Noop => "",
-            EmailClientSegmentOperator::FallthroughString => "*",
+            EmailClientSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailClientSegmentOperator {
+    fn from(s: String) -> EmailClientSegmentOperator {
+        match s.as_str() {
+            "client_is" => EmailClientSegmentOperator::ClientIs,
+            "client_not" => EmailClientSegmentOperator::ClientNot,
+            "" => EmailClientSegmentOperator::Noop,
+            _ => EmailClientSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailClientSegmentOperator> for String {
+    fn from(e: EmailClientSegmentOperator) -> String {
+        match e {
+            EmailClientSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailClientSegmentOperator {
     fn default() -> EmailClientSegmentOperator {
         EmailClientSegmentOperator::Noop
@@ -1911,13 +2436,15 @@ pub struct EmailClientSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LanguageSegmentConditionType {
     #[serde(rename = "Language")]
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentConditionType {
@@ -1925,12 +2452,31 @@ impl std::fmt::Display for LanguageSegmentConditionType {
         match self {
             LanguageSegmentConditionType::Language => "Language",
             LanguageSegmentConditionType::Noop => "",
-            LanguageSegmentConditionType::FallthroughString => "*",
+            LanguageSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LanguageSegmentConditionType {
+    fn from(s: String) -> LanguageSegmentConditionType {
+        match s.as_str() {
+            "Language" => LanguageSegmentConditionType::Language,
+            "" => LanguageSegmentConditionType::Noop,
+            _ => LanguageSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LanguageSegmentConditionType> for String {
+    fn from(e: LanguageSegmentConditionType) -> String {
+        match e {
+            LanguageSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LanguageSegmentConditionType {
     fn default() -> LanguageSegmentConditionType {
         LanguageSegmentConditionType::Noop
@@ -1946,13 +2492,15 @@ impl LanguageSegmentConditionType {
 * Segmenting based off of a subscriber's language.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LanguageSegmentField {
     #[serde(rename = "language")]
     Language,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentField {
@@ -1960,12 +2508,31 @@ impl std::fmt::Display for LanguageSegmentField {
         match self {
             LanguageSegmentField::Language => "language",
             LanguageSegmentField::Noop => "",
-            LanguageSegmentField::FallthroughString => "*",
+            LanguageSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LanguageSegmentField {
+    fn from(s: String) -> LanguageSegmentField {
+        match s.as_str() {
+            "language" => LanguageSegmentField::Language,
+            "" => LanguageSegmentField::Noop,
+            _ => LanguageSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LanguageSegmentField> for String {
+    fn from(e: LanguageSegmentField) -> String {
+        match e {
+            LanguageSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LanguageSegmentField {
     fn default() -> LanguageSegmentField {
         LanguageSegmentField::Noop
@@ -1981,6 +2548,7 @@ impl LanguageSegmentField {
 * Whether the member's language is or is not set to a specific language.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LanguageSegmentOperator {
     #[serde(rename = "is")]
     Is,
@@ -1988,8 +2556,9 @@ pub enum LanguageSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LanguageSegmentOperator {
@@ -1998,12 +2567,32 @@ impl std::fmt::Display for LanguageSegmentOperator {
             LanguageSegmentOperator::Is => "is",
             LanguageSegmentOperator::Not => "not",
             LanguageSegmentOperator::Noop => "",
-            LanguageSegmentOperator::FallthroughString => "*",
+            LanguageSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LanguageSegmentOperator {
+    fn from(s: String) -> LanguageSegmentOperator {
+        match s.as_str() {
+            "is" => LanguageSegmentOperator::Is,
+            "not" => LanguageSegmentOperator::Not,
+            "" => LanguageSegmentOperator::Noop,
+            _ => LanguageSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LanguageSegmentOperator> for String {
+    fn from(e: LanguageSegmentOperator) -> String {
+        match e {
+            LanguageSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LanguageSegmentOperator {
     fn default() -> LanguageSegmentOperator {
         LanguageSegmentOperator::Noop
@@ -2045,13 +2634,15 @@ pub struct LanguageSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberRatingSegmentConditionType {
     #[serde(rename = "MemberRating")]
     MemberRating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentConditionType {
@@ -2059,12 +2650,31 @@ impl std::fmt::Display for MemberRatingSegmentConditionType {
         match self {
             MemberRatingSegmentConditionType::MemberRating => "MemberRating",
             MemberRatingSegmentConditionType::Noop => "",
-            MemberRatingSegmentConditionType::FallthroughString => "*",
+            MemberRatingSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MemberRatingSegmentConditionType {
+    fn from(s: String) -> MemberRatingSegmentConditionType {
+        match s.as_str() {
+            "MemberRating" => MemberRatingSegmentConditionType::MemberRating,
+            "" => MemberRatingSegmentConditionType::Noop,
+            _ => MemberRatingSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MemberRatingSegmentConditionType> for String {
+    fn from(e: MemberRatingSegmentConditionType) -> String {
+        match e {
+            MemberRatingSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MemberRatingSegmentConditionType {
     fn default() -> MemberRatingSegmentConditionType {
         MemberRatingSegmentConditionType::Noop
@@ -2080,13 +2690,15 @@ impl MemberRatingSegmentConditionType {
 * Segment by member rating.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberRatingSegmentField {
     #[serde(rename = "rating")]
     Rating,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentField {
@@ -2094,12 +2706,31 @@ impl std::fmt::Display for MemberRatingSegmentField {
         match self {
             MemberRatingSegmentField::Rating => "rating",
             MemberRatingSegmentField::Noop => "",
-            MemberRatingSegmentField::FallthroughString => "*",
+            MemberRatingSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MemberRatingSegmentField {
+    fn from(s: String) -> MemberRatingSegmentField {
+        match s.as_str() {
+            "rating" => MemberRatingSegmentField::Rating,
+            "" => MemberRatingSegmentField::Noop,
+            _ => MemberRatingSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MemberRatingSegmentField> for String {
+    fn from(e: MemberRatingSegmentField) -> String {
+        match e {
+            MemberRatingSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MemberRatingSegmentField {
     fn default() -> MemberRatingSegmentField {
         MemberRatingSegmentField::Noop
@@ -2115,6 +2746,7 @@ impl MemberRatingSegmentField {
 * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberRatingSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -2126,8 +2758,9 @@ pub enum MemberRatingSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberRatingSegmentOperator {
@@ -2138,12 +2771,34 @@ impl std::fmt::Display for MemberRatingSegmentOperator {
             MemberRatingSegmentOperator::Less => "less",
             MemberRatingSegmentOperator::Not => "not",
             MemberRatingSegmentOperator::Noop => "",
-            MemberRatingSegmentOperator::FallthroughString => "*",
+            MemberRatingSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MemberRatingSegmentOperator {
+    fn from(s: String) -> MemberRatingSegmentOperator {
+        match s.as_str() {
+            "greater" => MemberRatingSegmentOperator::Greater,
+            "is" => MemberRatingSegmentOperator::Is,
+            "less" => MemberRatingSegmentOperator::Less,
+            "not" => MemberRatingSegmentOperator::Not,
+            "" => MemberRatingSegmentOperator::Noop,
+            _ => MemberRatingSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MemberRatingSegmentOperator> for String {
+    fn from(e: MemberRatingSegmentOperator) -> String {
+        match e {
+            MemberRatingSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MemberRatingSegmentOperator {
     fn default() -> MemberRatingSegmentOperator {
         MemberRatingSegmentOperator::Noop
@@ -2185,13 +2840,15 @@ pub struct MemberRatingSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignupSourceSegmentType {
     #[serde(rename = "SignupSource")]
     SignupSource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentType {
@@ -2199,12 +2856,31 @@ impl std::fmt::Display for SignupSourceSegmentType {
         match self {
             SignupSourceSegmentType::SignupSource => "SignupSource",
             SignupSourceSegmentType::Noop => "",
-            SignupSourceSegmentType::FallthroughString => "*",
+            SignupSourceSegmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SignupSourceSegmentType {
+    fn from(s: String) -> SignupSourceSegmentType {
+        match s.as_str() {
+            "SignupSource" => SignupSourceSegmentType::SignupSource,
+            "" => SignupSourceSegmentType::Noop,
+            _ => SignupSourceSegmentType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SignupSourceSegmentType> for String {
+    fn from(e: SignupSourceSegmentType) -> String {
+        match e {
+            SignupSourceSegmentType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SignupSourceSegmentType {
     fn default() -> SignupSourceSegmentType {
         SignupSourceSegmentType::Noop
@@ -2217,13 +2893,15 @@ impl SignupSourceSegmentType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignupSourceSegmentField {
     #[serde(rename = "source")]
     Source,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentField {
@@ -2231,12 +2909,31 @@ impl std::fmt::Display for SignupSourceSegmentField {
         match self {
             SignupSourceSegmentField::Source => "source",
             SignupSourceSegmentField::Noop => "",
-            SignupSourceSegmentField::FallthroughString => "*",
+            SignupSourceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SignupSourceSegmentField {
+    fn from(s: String) -> SignupSourceSegmentField {
+        match s.as_str() {
+            "source" => SignupSourceSegmentField::Source,
+            "" => SignupSourceSegmentField::Noop,
+            _ => SignupSourceSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SignupSourceSegmentField> for String {
+    fn from(e: SignupSourceSegmentField) -> String {
+        match e {
+            SignupSourceSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SignupSourceSegmentField {
     fn default() -> SignupSourceSegmentField {
         SignupSourceSegmentField::Noop
@@ -2252,6 +2949,7 @@ impl SignupSourceSegmentField {
 * Whether the member's signup source was/was not a particular value.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignupSourceSegmentOperator {
     #[serde(rename = "source_is")]
     SourceIs,
@@ -2259,8 +2957,9 @@ pub enum SignupSourceSegmentOperator {
     SourceNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignupSourceSegmentOperator {
@@ -2269,19 +2968,39 @@ impl std::fmt::Display for SignupSourceSegmentOperator {
             SignupSourceSegmentOperator::SourceIs => "source_is",
             SignupSourceSegmentOperator::SourceNot => "source_not",
             SignupSourceSegmentOperator::Noop => "",
-            SignupSourceSegmentOperator::FallthroughString => "*",
+            SignupSourceSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
-impl Default for SignupSourceSegmentOperator {
-    fn default() -> SignupSourceSegmentOperator {
-        SignupSourceSegmentOperator::Noop
+impl std::convert::From<String> for SignupSourceSegmentOperator {
+    fn from(s: String) -> SignupSourceSegmentOperator {
+        match s.as_str() {
+            "source_is" => SignupSourceSegmentOperator::SourceIs,
+            "source_not" => SignupSourceSegmentOperator::SourceNot,
+            "" => SignupSourceSegmentOperator::Noop,
+            _ => SignupSourceSegmentOperator::Unknown(s),
+        }
     }
 }
-impl SignupSourceSegmentOperator {
-    pub fn is_noop(&self) -> bool {
+
+impl std::convert::From<SignupSourceSegmentOperator> for String {
+    fn from(e: SignupSourceSegmentOperator) -> String {
+        match e {
+            SignupSourceSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
+impl Default for SignupSourceSegmentOperator {
+    fn default() -> SignupSourceSegmentOperator {
+        SignupSourceSegmentOperator::Noop
+    }
+}
+impl SignupSourceSegmentOperator {
+    pub fn is_noop(&self) -> bool {
         matches!(self, SignupSourceSegmentOperator::Noop)
     }
 }
@@ -2310,13 +3029,15 @@ pub struct SignupSourceSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SurveyMonkeySegmentConditionType {
     #[serde(rename = "SurveyMonkey")]
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentConditionType {
@@ -2324,12 +3045,31 @@ impl std::fmt::Display for SurveyMonkeySegmentConditionType {
         match self {
             SurveyMonkeySegmentConditionType::SurveyMonkey => "SurveyMonkey",
             SurveyMonkeySegmentConditionType::Noop => "",
-            SurveyMonkeySegmentConditionType::FallthroughString => "*",
+            SurveyMonkeySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SurveyMonkeySegmentConditionType {
+    fn from(s: String) -> SurveyMonkeySegmentConditionType {
+        match s.as_str() {
+            "SurveyMonkey" => SurveyMonkeySegmentConditionType::SurveyMonkey,
+            "" => SurveyMonkeySegmentConditionType::Noop,
+            _ => SurveyMonkeySegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SurveyMonkeySegmentConditionType> for String {
+    fn from(e: SurveyMonkeySegmentConditionType) -> String {
+        match e {
+            SurveyMonkeySegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SurveyMonkeySegmentConditionType {
     fn default() -> SurveyMonkeySegmentConditionType {
         SurveyMonkeySegmentConditionType::Noop
@@ -2345,13 +3085,15 @@ impl SurveyMonkeySegmentConditionType {
 * Segment by interaction with a SurveyMonkey survey.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SurveyMonkeySegmentField {
     #[serde(rename = "survey_monkey")]
     SurveyMonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyMonkeySegmentField {
@@ -2359,12 +3101,31 @@ impl std::fmt::Display for SurveyMonkeySegmentField {
         match self {
             SurveyMonkeySegmentField::SurveyMonkey => "survey_monkey",
             SurveyMonkeySegmentField::Noop => "",
-            SurveyMonkeySegmentField::FallthroughString => "*",
+            SurveyMonkeySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SurveyMonkeySegmentField {
+    fn from(s: String) -> SurveyMonkeySegmentField {
+        match s.as_str() {
+            "survey_monkey" => SurveyMonkeySegmentField::SurveyMonkey,
+            "" => SurveyMonkeySegmentField::Noop,
+            _ => SurveyMonkeySegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SurveyMonkeySegmentField> for String {
+    fn from(e: SurveyMonkeySegmentField) -> String {
+        match e {
+            SurveyMonkeySegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SurveyMonkeySegmentField {
     fn default() -> SurveyMonkeySegmentField {
         SurveyMonkeySegmentField::Noop
@@ -2406,13 +3167,15 @@ pub struct SurveyMonkeySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VipSegmentConditionType {
     #[serde(rename = "VIP")]
     Vip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentConditionType {
@@ -2420,12 +3183,31 @@ impl std::fmt::Display for VipSegmentConditionType {
         match self {
             VipSegmentConditionType::Vip => "VIP",
             VipSegmentConditionType::Noop => "",
-            VipSegmentConditionType::FallthroughString => "*",
+            VipSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for VipSegmentConditionType {
+    fn from(s: String) -> VipSegmentConditionType {
+        match s.as_str() {
+            "VIP" => VipSegmentConditionType::Vip,
+            "" => VipSegmentConditionType::Noop,
+            _ => VipSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<VipSegmentConditionType> for String {
+    fn from(e: VipSegmentConditionType) -> String {
+        match e {
+            VipSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for VipSegmentConditionType {
     fn default() -> VipSegmentConditionType {
         VipSegmentConditionType::Noop
@@ -2441,13 +3223,15 @@ impl VipSegmentConditionType {
 * Segment by VIP status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VipSegmentField {
     #[serde(rename = "gmonkey")]
     Gmonkey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VipSegmentField {
@@ -2455,12 +3239,31 @@ impl std::fmt::Display for VipSegmentField {
         match self {
             VipSegmentField::Gmonkey => "gmonkey",
             VipSegmentField::Noop => "",
-            VipSegmentField::FallthroughString => "*",
+            VipSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for VipSegmentField {
+    fn from(s: String) -> VipSegmentField {
+        match s.as_str() {
+            "gmonkey" => VipSegmentField::Gmonkey,
+            "" => VipSegmentField::Noop,
+            _ => VipSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<VipSegmentField> for String {
+    fn from(e: VipSegmentField) -> String {
+        match e {
+            VipSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for VipSegmentField {
     fn default() -> VipSegmentField {
         VipSegmentField::Noop
@@ -2493,13 +3296,15 @@ pub struct VipSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterestsSegmentConditionType {
     #[serde(rename = "Interests")]
     Interests,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentConditionType {
@@ -2507,12 +3312,31 @@ impl std::fmt::Display for InterestsSegmentConditionType {
         match self {
             InterestsSegmentConditionType::Interests => "Interests",
             InterestsSegmentConditionType::Noop => "",
-            InterestsSegmentConditionType::FallthroughString => "*",
+            InterestsSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InterestsSegmentConditionType {
+    fn from(s: String) -> InterestsSegmentConditionType {
+        match s.as_str() {
+            "Interests" => InterestsSegmentConditionType::Interests,
+            "" => InterestsSegmentConditionType::Noop,
+            _ => InterestsSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InterestsSegmentConditionType> for String {
+    fn from(e: InterestsSegmentConditionType) -> String {
+        match e {
+            InterestsSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InterestsSegmentConditionType {
     fn default() -> InterestsSegmentConditionType {
         InterestsSegmentConditionType::Noop
@@ -2528,6 +3352,7 @@ impl InterestsSegmentConditionType {
 * Whether the member is a part of one, all, or none of the groups.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterestsSegmentOperator {
     #[serde(rename = "interestcontains")]
     Interestcontains,
@@ -2537,8 +3362,9 @@ pub enum InterestsSegmentOperator {
     Interestnotcontains,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestsSegmentOperator {
@@ -2548,12 +3374,33 @@ impl std::fmt::Display for InterestsSegmentOperator {
             InterestsSegmentOperator::Interestcontainsall => "interestcontainsall",
             InterestsSegmentOperator::Interestnotcontains => "interestnotcontains",
             InterestsSegmentOperator::Noop => "",
-            InterestsSegmentOperator::FallthroughString => "*",
+            InterestsSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InterestsSegmentOperator {
+    fn from(s: String) -> InterestsSegmentOperator {
+        match s.as_str() {
+            "interestcontains" => InterestsSegmentOperator::Interestcontains,
+            "interestcontainsall" => InterestsSegmentOperator::Interestcontainsall,
+            "interestnotcontains" => InterestsSegmentOperator::Interestnotcontains,
+            "" => InterestsSegmentOperator::Noop,
+            _ => InterestsSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InterestsSegmentOperator> for String {
+    fn from(e: InterestsSegmentOperator) -> String {
+        match e {
+            InterestsSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InterestsSegmentOperator {
     fn default() -> InterestsSegmentOperator {
         InterestsSegmentOperator::Noop
@@ -2599,13 +3446,15 @@ pub struct InterestsSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceCategorySegmentConditionType {
     #[serde(rename = "EcommCategory")]
     EcommCategory,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentConditionType {
@@ -2613,12 +3462,31 @@ impl std::fmt::Display for EcommerceCategorySegmentConditionType {
         match self {
             EcommerceCategorySegmentConditionType::EcommCategory => "EcommCategory",
             EcommerceCategorySegmentConditionType::Noop => "",
-            EcommerceCategorySegmentConditionType::FallthroughString => "*",
+            EcommerceCategorySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceCategorySegmentConditionType {
+    fn from(s: String) -> EcommerceCategorySegmentConditionType {
+        match s.as_str() {
+            "EcommCategory" => EcommerceCategorySegmentConditionType::EcommCategory,
+            "" => EcommerceCategorySegmentConditionType::Noop,
+            _ => EcommerceCategorySegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceCategorySegmentConditionType> for String {
+    fn from(e: EcommerceCategorySegmentConditionType) -> String {
+        match e {
+            EcommerceCategorySegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceCategorySegmentConditionType {
     fn default() -> EcommerceCategorySegmentConditionType {
         EcommerceCategorySegmentConditionType::Noop
@@ -2634,6 +3502,7 @@ impl EcommerceCategorySegmentConditionType {
 * Segment by purchases in specific items or categories.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceCategorySegmentField {
     #[serde(rename = "ecomm_cat")]
     EcommCat,
@@ -2641,8 +3510,9 @@ pub enum EcommerceCategorySegmentField {
     EcommProd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentField {
@@ -2651,12 +3521,32 @@ impl std::fmt::Display for EcommerceCategorySegmentField {
             EcommerceCategorySegmentField::EcommCat => "ecomm_cat",
             EcommerceCategorySegmentField::EcommProd => "ecomm_prod",
             EcommerceCategorySegmentField::Noop => "",
-            EcommerceCategorySegmentField::FallthroughString => "*",
+            EcommerceCategorySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceCategorySegmentField {
+    fn from(s: String) -> EcommerceCategorySegmentField {
+        match s.as_str() {
+            "ecomm_cat" => EcommerceCategorySegmentField::EcommCat,
+            "ecomm_prod" => EcommerceCategorySegmentField::EcommProd,
+            "" => EcommerceCategorySegmentField::Noop,
+            _ => EcommerceCategorySegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceCategorySegmentField> for String {
+    fn from(e: EcommerceCategorySegmentField) -> String {
+        match e {
+            EcommerceCategorySegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceCategorySegmentField {
     fn default() -> EcommerceCategorySegmentField {
         EcommerceCategorySegmentField::Noop
@@ -2672,6 +3562,7 @@ impl EcommerceCategorySegmentField {
 * A member who has purchased from a category/specific item that is/is not a specific name, where the category/item name contains/doesn't contain a specific phrase or string, or a category/item name that starts/ends with a string.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceCategorySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -2687,8 +3578,9 @@ pub enum EcommerceCategorySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceCategorySegmentOperator {
@@ -2701,12 +3593,36 @@ impl std::fmt::Display for EcommerceCategorySegmentOperator {
             EcommerceCategorySegmentOperator::Notcontain => "notcontain",
             EcommerceCategorySegmentOperator::Starts => "starts",
             EcommerceCategorySegmentOperator::Noop => "",
-            EcommerceCategorySegmentOperator::FallthroughString => "*",
+            EcommerceCategorySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceCategorySegmentOperator {
+    fn from(s: String) -> EcommerceCategorySegmentOperator {
+        match s.as_str() {
+            "contains" => EcommerceCategorySegmentOperator::Contains,
+            "ends" => EcommerceCategorySegmentOperator::Ends,
+            "is" => EcommerceCategorySegmentOperator::Is,
+            "not" => EcommerceCategorySegmentOperator::Not,
+            "notcontain" => EcommerceCategorySegmentOperator::Notcontain,
+            "starts" => EcommerceCategorySegmentOperator::Starts,
+            "" => EcommerceCategorySegmentOperator::Noop,
+            _ => EcommerceCategorySegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceCategorySegmentOperator> for String {
+    fn from(e: EcommerceCategorySegmentOperator) -> String {
+        match e {
+            EcommerceCategorySegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceCategorySegmentOperator {
     fn default() -> EcommerceCategorySegmentOperator {
         EcommerceCategorySegmentOperator::Noop
@@ -2748,13 +3664,15 @@ pub struct EcommerceCategorySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceNumberSegmentConditionType {
     #[serde(rename = "EcommNumber")]
     EcommNumber,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentConditionType {
@@ -2762,12 +3680,31 @@ impl std::fmt::Display for EcommerceNumberSegmentConditionType {
         match self {
             EcommerceNumberSegmentConditionType::EcommNumber => "EcommNumber",
             EcommerceNumberSegmentConditionType::Noop => "",
-            EcommerceNumberSegmentConditionType::FallthroughString => "*",
+            EcommerceNumberSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceNumberSegmentConditionType {
+    fn from(s: String) -> EcommerceNumberSegmentConditionType {
+        match s.as_str() {
+            "EcommNumber" => EcommerceNumberSegmentConditionType::EcommNumber,
+            "" => EcommerceNumberSegmentConditionType::Noop,
+            _ => EcommerceNumberSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceNumberSegmentConditionType> for String {
+    fn from(e: EcommerceNumberSegmentConditionType) -> String {
+        match e {
+            EcommerceNumberSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceNumberSegmentConditionType {
     fn default() -> EcommerceNumberSegmentConditionType {
         EcommerceNumberSegmentConditionType::Noop
@@ -2783,6 +3720,7 @@ impl EcommerceNumberSegmentConditionType {
 * Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceNumberSegmentField {
     #[serde(rename = "ecomm_avg_ord")]
     EcommAvgOrd,
@@ -2794,8 +3732,9 @@ pub enum EcommerceNumberSegmentField {
     EcommSpentAvg,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceNumberSegmentField {
@@ -2806,12 +3745,34 @@ impl std::fmt::Display for EcommerceNumberSegmentField {
             EcommerceNumberSegmentField::EcommProdAll => "ecomm_prod_all",
             EcommerceNumberSegmentField::EcommSpentAvg => "ecomm_spent_avg",
             EcommerceNumberSegmentField::Noop => "",
-            EcommerceNumberSegmentField::FallthroughString => "*",
+            EcommerceNumberSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceNumberSegmentField {
+    fn from(s: String) -> EcommerceNumberSegmentField {
+        match s.as_str() {
+            "ecomm_avg_ord" => EcommerceNumberSegmentField::EcommAvgOrd,
+            "ecomm_orders" => EcommerceNumberSegmentField::EcommOrders,
+            "ecomm_prod_all" => EcommerceNumberSegmentField::EcommProdAll,
+            "ecomm_spent_avg" => EcommerceNumberSegmentField::EcommSpentAvg,
+            "" => EcommerceNumberSegmentField::Noop,
+            _ => EcommerceNumberSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceNumberSegmentField> for String {
+    fn from(e: EcommerceNumberSegmentField) -> String {
+        match e {
+            EcommerceNumberSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceNumberSegmentField {
     fn default() -> EcommerceNumberSegmentField {
         EcommerceNumberSegmentField::Noop
@@ -2853,13 +3814,15 @@ pub struct EcommerceNumberSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedSegmentConditionType {
     #[serde(rename = "EcommPurchased")]
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
@@ -2867,12 +3830,31 @@ impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
         match self {
             EcommercePurchasedSegmentConditionType::EcommPurchased => "EcommPurchased",
             EcommercePurchasedSegmentConditionType::Noop => "",
-            EcommercePurchasedSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommercePurchasedSegmentConditionType {
+    fn from(s: String) -> EcommercePurchasedSegmentConditionType {
+        match s.as_str() {
+            "EcommPurchased" => EcommercePurchasedSegmentConditionType::EcommPurchased,
+            "" => EcommercePurchasedSegmentConditionType::Noop,
+            _ => EcommercePurchasedSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommercePurchasedSegmentConditionType> for String {
+    fn from(e: EcommercePurchasedSegmentConditionType) -> String {
+        match e {
+            EcommercePurchasedSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommercePurchasedSegmentConditionType {
     fn default() -> EcommercePurchasedSegmentConditionType {
         EcommercePurchasedSegmentConditionType::Noop
@@ -2888,13 +3870,15 @@ impl EcommercePurchasedSegmentConditionType {
 * Segment by whether someone has purchased anything.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedSegmentField {
     #[serde(rename = "ecomm_purchased")]
     EcommPurchased,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedSegmentField {
@@ -2902,12 +3886,31 @@ impl std::fmt::Display for EcommercePurchasedSegmentField {
         match self {
             EcommercePurchasedSegmentField::EcommPurchased => "ecomm_purchased",
             EcommercePurchasedSegmentField::Noop => "",
-            EcommercePurchasedSegmentField::FallthroughString => "*",
+            EcommercePurchasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommercePurchasedSegmentField {
+    fn from(s: String) -> EcommercePurchasedSegmentField {
+        match s.as_str() {
+            "ecomm_purchased" => EcommercePurchasedSegmentField::EcommPurchased,
+            "" => EcommercePurchasedSegmentField::Noop,
+            _ => EcommercePurchasedSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommercePurchasedSegmentField> for String {
+    fn from(e: EcommercePurchasedSegmentField) -> String {
+        match e {
+            EcommercePurchasedSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommercePurchasedSegmentField {
     fn default() -> EcommercePurchasedSegmentField {
         EcommercePurchasedSegmentField::Noop
@@ -2940,13 +3943,15 @@ pub struct EcommercePurchasedSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSpentSegmentConditionType {
     #[serde(rename = "EcommSpent")]
     EcommSpent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentConditionType {
@@ -2954,12 +3959,31 @@ impl std::fmt::Display for EcommerceSpentSegmentConditionType {
         match self {
             EcommerceSpentSegmentConditionType::EcommSpent => "EcommSpent",
             EcommerceSpentSegmentConditionType::Noop => "",
-            EcommerceSpentSegmentConditionType::FallthroughString => "*",
+            EcommerceSpentSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceSpentSegmentConditionType {
+    fn from(s: String) -> EcommerceSpentSegmentConditionType {
+        match s.as_str() {
+            "EcommSpent" => EcommerceSpentSegmentConditionType::EcommSpent,
+            "" => EcommerceSpentSegmentConditionType::Noop,
+            _ => EcommerceSpentSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceSpentSegmentConditionType> for String {
+    fn from(e: EcommerceSpentSegmentConditionType) -> String {
+        match e {
+            EcommerceSpentSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceSpentSegmentConditionType {
     fn default() -> EcommerceSpentSegmentConditionType {
         EcommerceSpentSegmentConditionType::Noop
@@ -2975,6 +3999,7 @@ impl EcommerceSpentSegmentConditionType {
 * Segment by amount spent on a single order or across all orders.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSpentSegmentField {
     #[serde(rename = "ecomm_spent_all")]
     EcommSpentAll,
@@ -2982,8 +4007,9 @@ pub enum EcommerceSpentSegmentField {
     EcommSpentOne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentField {
@@ -2992,12 +4018,32 @@ impl std::fmt::Display for EcommerceSpentSegmentField {
             EcommerceSpentSegmentField::EcommSpentAll => "ecomm_spent_all",
             EcommerceSpentSegmentField::EcommSpentOne => "ecomm_spent_one",
             EcommerceSpentSegmentField::Noop => "",
-            EcommerceSpentSegmentField::FallthroughString => "*",
+            EcommerceSpentSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceSpentSegmentField {
+    fn from(s: String) -> EcommerceSpentSegmentField {
+        match s.as_str() {
+            "ecomm_spent_all" => EcommerceSpentSegmentField::EcommSpentAll,
+            "ecomm_spent_one" => EcommerceSpentSegmentField::EcommSpentOne,
+            "" => EcommerceSpentSegmentField::Noop,
+            _ => EcommerceSpentSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceSpentSegmentField> for String {
+    fn from(e: EcommerceSpentSegmentField) -> String {
+        match e {
+            EcommerceSpentSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceSpentSegmentField {
     fn default() -> EcommerceSpentSegmentField {
         EcommerceSpentSegmentField::Noop
@@ -3013,6 +4059,7 @@ impl EcommerceSpentSegmentField {
 * Members who have spent 'more' or 'less' than then specified value.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSpentSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3020,8 +4067,9 @@ pub enum EcommerceSpentSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSpentSegmentOperator {
@@ -3030,12 +4078,32 @@ impl std::fmt::Display for EcommerceSpentSegmentOperator {
             EcommerceSpentSegmentOperator::Greater => "greater",
             EcommerceSpentSegmentOperator::Less => "less",
             EcommerceSpentSegmentOperator::Noop => "",
-            EcommerceSpentSegmentOperator::FallthroughString => "*",
+            EcommerceSpentSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceSpentSegmentOperator {
+    fn from(s: String) -> EcommerceSpentSegmentOperator {
+        match s.as_str() {
+            "greater" => EcommerceSpentSegmentOperator::Greater,
+            "less" => EcommerceSpentSegmentOperator::Less,
+            "" => EcommerceSpentSegmentOperator::Noop,
+            _ => EcommerceSpentSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceSpentSegmentOperator> for String {
+    fn from(e: EcommerceSpentSegmentOperator) -> String {
+        match e {
+            EcommerceSpentSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceSpentSegmentOperator {
     fn default() -> EcommerceSpentSegmentOperator {
         EcommerceSpentSegmentOperator::Noop
@@ -3077,13 +4145,15 @@ pub struct EcommerceSpentSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedStoreSegmentConditionType {
     #[serde(rename = "EcommStore")]
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
@@ -3091,12 +4161,31 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
         match self {
             EcommercePurchasedStoreSegmentConditionType::EcommStore => "EcommStore",
             EcommercePurchasedStoreSegmentConditionType::Noop => "",
-            EcommercePurchasedStoreSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommercePurchasedStoreSegmentConditionType {
+    fn from(s: String) -> EcommercePurchasedStoreSegmentConditionType {
+        match s.as_str() {
+            "EcommStore" => EcommercePurchasedStoreSegmentConditionType::EcommStore,
+            "" => EcommercePurchasedStoreSegmentConditionType::Noop,
+            _ => EcommercePurchasedStoreSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommercePurchasedStoreSegmentConditionType> for String {
+    fn from(e: EcommercePurchasedStoreSegmentConditionType) -> String {
+        match e {
+            EcommercePurchasedStoreSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommercePurchasedStoreSegmentConditionType {
     fn default() -> EcommercePurchasedStoreSegmentConditionType {
         EcommercePurchasedStoreSegmentConditionType::Noop
@@ -3112,13 +4201,15 @@ impl EcommercePurchasedStoreSegmentConditionType {
 * Segment by purchases from a specific store.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedStoreSegmentField {
     #[serde(rename = "ecomm_store")]
     EcommStore,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
@@ -3126,12 +4217,31 @@ impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
         match self {
             EcommercePurchasedStoreSegmentField::EcommStore => "ecomm_store",
             EcommercePurchasedStoreSegmentField::Noop => "",
-            EcommercePurchasedStoreSegmentField::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommercePurchasedStoreSegmentField {
+    fn from(s: String) -> EcommercePurchasedStoreSegmentField {
+        match s.as_str() {
+            "ecomm_store" => EcommercePurchasedStoreSegmentField::EcommStore,
+            "" => EcommercePurchasedStoreSegmentField::Noop,
+            _ => EcommercePurchasedStoreSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommercePurchasedStoreSegmentField> for String {
+    fn from(e: EcommercePurchasedStoreSegmentField) -> String {
+        match e {
+            EcommercePurchasedStoreSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommercePurchasedStoreSegmentField {
     fn default() -> EcommercePurchasedStoreSegmentField {
         EcommercePurchasedStoreSegmentField::Noop
@@ -3173,13 +4283,15 @@ pub struct EcommercePurchasedStoreSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalActivitySegmentConditionType {
     #[serde(rename = "GoalActivity")]
     GoalActivity,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentConditionType {
@@ -3187,12 +4299,31 @@ impl std::fmt::Display for GoalActivitySegmentConditionType {
         match self {
             GoalActivitySegmentConditionType::GoalActivity => "GoalActivity",
             GoalActivitySegmentConditionType::Noop => "",
-            GoalActivitySegmentConditionType::FallthroughString => "*",
+            GoalActivitySegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GoalActivitySegmentConditionType {
+    fn from(s: String) -> GoalActivitySegmentConditionType {
+        match s.as_str() {
+            "GoalActivity" => GoalActivitySegmentConditionType::GoalActivity,
+            "" => GoalActivitySegmentConditionType::Noop,
+            _ => GoalActivitySegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GoalActivitySegmentConditionType> for String {
+    fn from(e: GoalActivitySegmentConditionType) -> String {
+        match e {
+            GoalActivitySegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GoalActivitySegmentConditionType {
     fn default() -> GoalActivitySegmentConditionType {
         GoalActivitySegmentConditionType::Noop
@@ -3208,13 +4339,15 @@ impl GoalActivitySegmentConditionType {
 * Segment by Goal activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalActivitySegmentField {
     #[serde(rename = "goal")]
     Goal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentField {
@@ -3222,12 +4355,31 @@ impl std::fmt::Display for GoalActivitySegmentField {
         match self {
             GoalActivitySegmentField::Goal => "goal",
             GoalActivitySegmentField::Noop => "",
-            GoalActivitySegmentField::FallthroughString => "*",
+            GoalActivitySegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GoalActivitySegmentField {
+    fn from(s: String) -> GoalActivitySegmentField {
+        match s.as_str() {
+            "goal" => GoalActivitySegmentField::Goal,
+            "" => GoalActivitySegmentField::Noop,
+            _ => GoalActivitySegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GoalActivitySegmentField> for String {
+    fn from(e: GoalActivitySegmentField) -> String {
+        match e {
+            GoalActivitySegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GoalActivitySegmentField {
     fn default() -> GoalActivitySegmentField {
         GoalActivitySegmentField::Noop
@@ -3243,6 +4395,7 @@ impl GoalActivitySegmentField {
 * Whether the website URL is/not exactly, contains/doesn't contain, starts with/ends with a string.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalActivitySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -3258,8 +4411,9 @@ pub enum GoalActivitySegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalActivitySegmentOperator {
@@ -3272,12 +4426,36 @@ impl std::fmt::Display for GoalActivitySegmentOperator {
             GoalActivitySegmentOperator::Is => "is",
             GoalActivitySegmentOperator::Starts => "starts",
             GoalActivitySegmentOperator::Noop => "",
-            GoalActivitySegmentOperator::FallthroughString => "*",
+            GoalActivitySegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GoalActivitySegmentOperator {
+    fn from(s: String) -> GoalActivitySegmentOperator {
+        match s.as_str() {
+            "contains" => GoalActivitySegmentOperator::Contains,
+            "ends" => GoalActivitySegmentOperator::Ends,
+            "goal_not" => GoalActivitySegmentOperator::GoalNot,
+            "goal_notcontain" => GoalActivitySegmentOperator::GoalNotcontain,
+            "is" => GoalActivitySegmentOperator::Is,
+            "starts" => GoalActivitySegmentOperator::Starts,
+            "" => GoalActivitySegmentOperator::Noop,
+            _ => GoalActivitySegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GoalActivitySegmentOperator> for String {
+    fn from(e: GoalActivitySegmentOperator) -> String {
+        match e {
+            GoalActivitySegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GoalActivitySegmentOperator {
     fn default() -> GoalActivitySegmentOperator {
         GoalActivitySegmentOperator::Noop
@@ -3319,13 +4497,15 @@ pub struct GoalActivitySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalTimestampSegmentConditionType {
     #[serde(rename = "GoalTimestamp")]
     GoalTimestamp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentConditionType {
@@ -3333,12 +4513,31 @@ impl std::fmt::Display for GoalTimestampSegmentConditionType {
         match self {
             GoalTimestampSegmentConditionType::GoalTimestamp => "GoalTimestamp",
             GoalTimestampSegmentConditionType::Noop => "",
-            GoalTimestampSegmentConditionType::FallthroughString => "*",
+            GoalTimestampSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GoalTimestampSegmentConditionType {
+    fn from(s: String) -> GoalTimestampSegmentConditionType {
+        match s.as_str() {
+            "GoalTimestamp" => GoalTimestampSegmentConditionType::GoalTimestamp,
+            "" => GoalTimestampSegmentConditionType::Noop,
+            _ => GoalTimestampSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GoalTimestampSegmentConditionType> for String {
+    fn from(e: GoalTimestampSegmentConditionType) -> String {
+        match e {
+            GoalTimestampSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GoalTimestampSegmentConditionType {
     fn default() -> GoalTimestampSegmentConditionType {
         GoalTimestampSegmentConditionType::Noop
@@ -3354,13 +4553,15 @@ impl GoalTimestampSegmentConditionType {
 * Segment by most recent interaction with a website.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalTimestampSegmentField {
     #[serde(rename = "goal_last_visited")]
     GoalLastVisited,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentField {
@@ -3368,12 +4569,31 @@ impl std::fmt::Display for GoalTimestampSegmentField {
         match self {
             GoalTimestampSegmentField::GoalLastVisited => "goal_last_visited",
             GoalTimestampSegmentField::Noop => "",
-            GoalTimestampSegmentField::FallthroughString => "*",
+            GoalTimestampSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GoalTimestampSegmentField {
+    fn from(s: String) -> GoalTimestampSegmentField {
+        match s.as_str() {
+            "goal_last_visited" => GoalTimestampSegmentField::GoalLastVisited,
+            "" => GoalTimestampSegmentField::Noop,
+            _ => GoalTimestampSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GoalTimestampSegmentField> for String {
+    fn from(e: GoalTimestampSegmentField) -> String {
+        match e {
+            GoalTimestampSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GoalTimestampSegmentField {
     fn default() -> GoalTimestampSegmentField {
         GoalTimestampSegmentField::Noop
@@ -3389,6 +4609,7 @@ impl GoalTimestampSegmentField {
 * Whether the website activity happened after, before, or at a given timestamp.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalTimestampSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3398,8 +4619,9 @@ pub enum GoalTimestampSegmentOperator {
     Less,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GoalTimestampSegmentOperator {
@@ -3409,12 +4631,33 @@ impl std::fmt::Display for GoalTimestampSegmentOperator {
             GoalTimestampSegmentOperator::Is => "is",
             GoalTimestampSegmentOperator::Less => "less",
             GoalTimestampSegmentOperator::Noop => "",
-            GoalTimestampSegmentOperator::FallthroughString => "*",
+            GoalTimestampSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GoalTimestampSegmentOperator {
+    fn from(s: String) -> GoalTimestampSegmentOperator {
+        match s.as_str() {
+            "greater" => GoalTimestampSegmentOperator::Greater,
+            "is" => GoalTimestampSegmentOperator::Is,
+            "less" => GoalTimestampSegmentOperator::Less,
+            "" => GoalTimestampSegmentOperator::Noop,
+            _ => GoalTimestampSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GoalTimestampSegmentOperator> for String {
+    fn from(e: GoalTimestampSegmentOperator) -> String {
+        match e {
+            GoalTimestampSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GoalTimestampSegmentOperator {
     fn default() -> GoalTimestampSegmentOperator {
         GoalTimestampSegmentOperator::Noop
@@ -3456,13 +4699,15 @@ pub struct GoalTimestampSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SimilarSubscribersSegmentMemberConditionType {
     #[serde(rename = "FuzzySegment")]
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
@@ -3470,12 +4715,31 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
         match self {
             SimilarSubscribersSegmentMemberConditionType::FuzzySegment => "FuzzySegment",
             SimilarSubscribersSegmentMemberConditionType::Noop => "",
-            SimilarSubscribersSegmentMemberConditionType::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SimilarSubscribersSegmentMemberConditionType {
+    fn from(s: String) -> SimilarSubscribersSegmentMemberConditionType {
+        match s.as_str() {
+            "FuzzySegment" => SimilarSubscribersSegmentMemberConditionType::FuzzySegment,
+            "" => SimilarSubscribersSegmentMemberConditionType::Noop,
+            _ => SimilarSubscribersSegmentMemberConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SimilarSubscribersSegmentMemberConditionType> for String {
+    fn from(e: SimilarSubscribersSegmentMemberConditionType) -> String {
+        match e {
+            SimilarSubscribersSegmentMemberConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberConditionType {
     fn default() -> SimilarSubscribersSegmentMemberConditionType {
         SimilarSubscribersSegmentMemberConditionType::Noop
@@ -3491,13 +4755,15 @@ impl SimilarSubscribersSegmentMemberConditionType {
 * Segment by similar subscribers.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SimilarSubscribersSegmentMemberField {
     #[serde(rename = "fuzzy_segment")]
     FuzzySegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
@@ -3505,12 +4771,31 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
         match self {
             SimilarSubscribersSegmentMemberField::FuzzySegment => "fuzzy_segment",
             SimilarSubscribersSegmentMemberField::Noop => "",
-            SimilarSubscribersSegmentMemberField::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SimilarSubscribersSegmentMemberField {
+    fn from(s: String) -> SimilarSubscribersSegmentMemberField {
+        match s.as_str() {
+            "fuzzy_segment" => SimilarSubscribersSegmentMemberField::FuzzySegment,
+            "" => SimilarSubscribersSegmentMemberField::Noop,
+            _ => SimilarSubscribersSegmentMemberField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SimilarSubscribersSegmentMemberField> for String {
+    fn from(e: SimilarSubscribersSegmentMemberField) -> String {
+        match e {
+            SimilarSubscribersSegmentMemberField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberField {
     fn default() -> SimilarSubscribersSegmentMemberField {
         SimilarSubscribersSegmentMemberField::Noop
@@ -3526,6 +4811,7 @@ impl SimilarSubscribersSegmentMemberField {
 * Members who are/are not apart of a 'similar subscribers' segment.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SimilarSubscribersSegmentMemberOperator {
     #[serde(rename = "fuzzy_is")]
     FuzzyIs,
@@ -3533,8 +4819,9 @@ pub enum SimilarSubscribersSegmentMemberOperator {
     FuzzyNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
@@ -3543,12 +4830,32 @@ impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
             SimilarSubscribersSegmentMemberOperator::FuzzyIs => "fuzzy_is",
             SimilarSubscribersSegmentMemberOperator::FuzzyNot => "fuzzy_not",
             SimilarSubscribersSegmentMemberOperator::Noop => "",
-            SimilarSubscribersSegmentMemberOperator::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SimilarSubscribersSegmentMemberOperator {
+    fn from(s: String) -> SimilarSubscribersSegmentMemberOperator {
+        match s.as_str() {
+            "fuzzy_is" => SimilarSubscribersSegmentMemberOperator::FuzzyIs,
+            "fuzzy_not" => SimilarSubscribersSegmentMemberOperator::FuzzyNot,
+            "" => SimilarSubscribersSegmentMemberOperator::Noop,
+            _ => SimilarSubscribersSegmentMemberOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SimilarSubscribersSegmentMemberOperator> for String {
+    fn from(e: SimilarSubscribersSegmentMemberOperator) -> String {
+        match e {
+            SimilarSubscribersSegmentMemberOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SimilarSubscribersSegmentMemberOperator {
     fn default() -> SimilarSubscribersSegmentMemberOperator {
         SimilarSubscribersSegmentMemberOperator::Noop
@@ -3596,13 +4903,15 @@ pub struct SimilarSubscribersSegmentMember {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StaticSegmentMemberConditionType {
     #[serde(rename = "StaticSegment")]
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberConditionType {
@@ -3610,12 +4919,31 @@ impl std::fmt::Display for StaticSegmentMemberConditionType {
         match self {
             StaticSegmentMemberConditionType::StaticSegment => "StaticSegment",
             StaticSegmentMemberConditionType::Noop => "",
-            StaticSegmentMemberConditionType::FallthroughString => "*",
+            StaticSegmentMemberConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for StaticSegmentMemberConditionType {
+    fn from(s: String) -> StaticSegmentMemberConditionType {
+        match s.as_str() {
+            "StaticSegment" => StaticSegmentMemberConditionType::StaticSegment,
+            "" => StaticSegmentMemberConditionType::Noop,
+            _ => StaticSegmentMemberConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<StaticSegmentMemberConditionType> for String {
+    fn from(e: StaticSegmentMemberConditionType) -> String {
+        match e {
+            StaticSegmentMemberConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for StaticSegmentMemberConditionType {
     fn default() -> StaticSegmentMemberConditionType {
         StaticSegmentMemberConditionType::Noop
@@ -3631,13 +4959,15 @@ impl StaticSegmentMemberConditionType {
 * Segment by a given static segment.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StaticSegmentMemberField {
     #[serde(rename = "static_segment")]
     StaticSegment,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberField {
@@ -3645,12 +4975,31 @@ impl std::fmt::Display for StaticSegmentMemberField {
         match self {
             StaticSegmentMemberField::StaticSegment => "static_segment",
             StaticSegmentMemberField::Noop => "",
-            StaticSegmentMemberField::FallthroughString => "*",
+            StaticSegmentMemberField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for StaticSegmentMemberField {
+    fn from(s: String) -> StaticSegmentMemberField {
+        match s.as_str() {
+            "static_segment" => StaticSegmentMemberField::StaticSegment,
+            "" => StaticSegmentMemberField::Noop,
+            _ => StaticSegmentMemberField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<StaticSegmentMemberField> for String {
+    fn from(e: StaticSegmentMemberField) -> String {
+        match e {
+            StaticSegmentMemberField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for StaticSegmentMemberField {
     fn default() -> StaticSegmentMemberField {
         StaticSegmentMemberField::Noop
@@ -3666,6 +5015,7 @@ impl StaticSegmentMemberField {
 * Members who are/are not apart of a static segment.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StaticSegmentMemberOperator {
     #[serde(rename = "static_is")]
     StaticIs,
@@ -3673,8 +5023,9 @@ pub enum StaticSegmentMemberOperator {
     StaticNot,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StaticSegmentMemberOperator {
@@ -3683,12 +5034,32 @@ impl std::fmt::Display for StaticSegmentMemberOperator {
             StaticSegmentMemberOperator::StaticIs => "static_is",
             StaticSegmentMemberOperator::StaticNot => "static_not",
             StaticSegmentMemberOperator::Noop => "",
-            StaticSegmentMemberOperator::FallthroughString => "*",
+            StaticSegmentMemberOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for StaticSegmentMemberOperator {
+    fn from(s: String) -> StaticSegmentMemberOperator {
+        match s.as_str() {
+            "static_is" => StaticSegmentMemberOperator::StaticIs,
+            "static_not" => StaticSegmentMemberOperator::StaticNot,
+            "" => StaticSegmentMemberOperator::Noop,
+            _ => StaticSegmentMemberOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<StaticSegmentMemberOperator> for String {
+    fn from(e: StaticSegmentMemberOperator) -> String {
+        match e {
+            StaticSegmentMemberOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for StaticSegmentMemberOperator {
     fn default() -> StaticSegmentMemberOperator {
         StaticSegmentMemberOperator::Noop
@@ -3730,13 +5101,15 @@ pub struct StaticSegmentMember {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoCountryState")]
     IpGeoCountryState,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentConditionType {
@@ -3744,12 +5117,31 @@ impl std::fmt::Display for LocationBasedSegmentConditionType {
         match self {
             LocationBasedSegmentConditionType::IpGeoCountryState => "IPGeoCountryState",
             LocationBasedSegmentConditionType::Noop => "",
-            LocationBasedSegmentConditionType::FallthroughString => "*",
+            LocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LocationBasedSegmentConditionType {
+    fn from(s: String) -> LocationBasedSegmentConditionType {
+        match s.as_str() {
+            "IPGeoCountryState" => LocationBasedSegmentConditionType::IpGeoCountryState,
+            "" => LocationBasedSegmentConditionType::Noop,
+            _ => LocationBasedSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LocationBasedSegmentConditionType> for String {
+    fn from(e: LocationBasedSegmentConditionType) -> String {
+        match e {
+            LocationBasedSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LocationBasedSegmentConditionType {
     fn default() -> LocationBasedSegmentConditionType {
         LocationBasedSegmentConditionType::Noop
@@ -3765,13 +5157,15 @@ impl LocationBasedSegmentConditionType {
 * Segmenting subscribers who are within a specific location.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationBasedSegmentField {
     #[serde(rename = "ipgeo")]
     Ipgeo,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentField {
@@ -3779,12 +5173,31 @@ impl std::fmt::Display for LocationBasedSegmentField {
         match self {
             LocationBasedSegmentField::Ipgeo => "ipgeo",
             LocationBasedSegmentField::Noop => "",
-            LocationBasedSegmentField::FallthroughString => "*",
+            LocationBasedSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LocationBasedSegmentField {
+    fn from(s: String) -> LocationBasedSegmentField {
+        match s.as_str() {
+            "ipgeo" => LocationBasedSegmentField::Ipgeo,
+            "" => LocationBasedSegmentField::Noop,
+            _ => LocationBasedSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LocationBasedSegmentField> for String {
+    fn from(e: LocationBasedSegmentField) -> String {
+        match e {
+            LocationBasedSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LocationBasedSegmentField {
     fn default() -> LocationBasedSegmentField {
         LocationBasedSegmentField::Noop
@@ -3800,6 +5213,7 @@ impl LocationBasedSegmentField {
 * Segment members who are within a specific country or US state.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationBasedSegmentOperator {
     #[serde(rename = "ipgeocountry")]
     Ipgeocountry,
@@ -3811,8 +5225,9 @@ pub enum LocationBasedSegmentOperator {
     Ipgeostate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LocationBasedSegmentOperator {
@@ -3823,12 +5238,34 @@ impl std::fmt::Display for LocationBasedSegmentOperator {
             LocationBasedSegmentOperator::Ipgeonotstate => "ipgeonotstate",
             LocationBasedSegmentOperator::Ipgeostate => "ipgeostate",
             LocationBasedSegmentOperator::Noop => "",
-            LocationBasedSegmentOperator::FallthroughString => "*",
+            LocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LocationBasedSegmentOperator {
+    fn from(s: String) -> LocationBasedSegmentOperator {
+        match s.as_str() {
+            "ipgeocountry" => LocationBasedSegmentOperator::Ipgeocountry,
+            "ipgeonotcountry" => LocationBasedSegmentOperator::Ipgeonotcountry,
+            "ipgeonotstate" => LocationBasedSegmentOperator::Ipgeonotstate,
+            "ipgeostate" => LocationBasedSegmentOperator::Ipgeostate,
+            "" => LocationBasedSegmentOperator::Noop,
+            _ => LocationBasedSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LocationBasedSegmentOperator> for String {
+    fn from(e: LocationBasedSegmentOperator) -> String {
+        match e {
+            LocationBasedSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LocationBasedSegmentOperator {
     fn default() -> LocationBasedSegmentOperator {
         LocationBasedSegmentOperator::Noop
@@ -3870,13 +5307,15 @@ pub struct LocationBasedSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GeolocationSegmentConditionType {
     #[serde(rename = "IPGeoIn")]
     IpGeoIn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentConditionType {
@@ -3884,12 +5323,31 @@ impl std::fmt::Display for GeolocationSegmentConditionType {
         match self {
             GeolocationSegmentConditionType::IpGeoIn => "IPGeoIn",
             GeolocationSegmentConditionType::Noop => "",
-            GeolocationSegmentConditionType::FallthroughString => "*",
+            GeolocationSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GeolocationSegmentConditionType {
+    fn from(s: String) -> GeolocationSegmentConditionType {
+        match s.as_str() {
+            "IPGeoIn" => GeolocationSegmentConditionType::IpGeoIn,
+            "" => GeolocationSegmentConditionType::Noop,
+            _ => GeolocationSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GeolocationSegmentConditionType> for String {
+    fn from(e: GeolocationSegmentConditionType) -> String {
+        match e {
+            GeolocationSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GeolocationSegmentConditionType {
     fn default() -> GeolocationSegmentConditionType {
         GeolocationSegmentConditionType::Noop
@@ -3905,6 +5363,7 @@ impl GeolocationSegmentConditionType {
 * Segment members who are within a specific geographic region.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GeolocationSegmentOperator {
     #[serde(rename = "ipgeoin")]
     Ipgeoin,
@@ -3912,8 +5371,9 @@ pub enum GeolocationSegmentOperator {
     Ipgeonotin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GeolocationSegmentOperator {
@@ -3922,12 +5382,32 @@ impl std::fmt::Display for GeolocationSegmentOperator {
             GeolocationSegmentOperator::Ipgeoin => "ipgeoin",
             GeolocationSegmentOperator::Ipgeonotin => "ipgeonotin",
             GeolocationSegmentOperator::Noop => "",
-            GeolocationSegmentOperator::FallthroughString => "*",
+            GeolocationSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GeolocationSegmentOperator {
+    fn from(s: String) -> GeolocationSegmentOperator {
+        match s.as_str() {
+            "ipgeoin" => GeolocationSegmentOperator::Ipgeoin,
+            "ipgeonotin" => GeolocationSegmentOperator::Ipgeonotin,
+            "" => GeolocationSegmentOperator::Noop,
+            _ => GeolocationSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GeolocationSegmentOperator> for String {
+    fn from(e: GeolocationSegmentOperator) -> String {
+        match e {
+            GeolocationSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GeolocationSegmentOperator {
     fn default() -> GeolocationSegmentOperator {
         GeolocationSegmentOperator::Noop
@@ -3996,13 +5476,15 @@ pub struct GeolocationSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsZipCodeSegmentConditionType {
     #[serde(rename = "IPGeoInZip")]
     IpGeoInZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentConditionType {
@@ -4010,12 +5492,31 @@ impl std::fmt::Display for UsZipCodeSegmentConditionType {
         match self {
             UsZipCodeSegmentConditionType::IpGeoInZip => "IPGeoInZip",
             UsZipCodeSegmentConditionType::Noop => "",
-            UsZipCodeSegmentConditionType::FallthroughString => "*",
+            UsZipCodeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UsZipCodeSegmentConditionType {
+    fn from(s: String) -> UsZipCodeSegmentConditionType {
+        match s.as_str() {
+            "IPGeoInZip" => UsZipCodeSegmentConditionType::IpGeoInZip,
+            "" => UsZipCodeSegmentConditionType::Noop,
+            _ => UsZipCodeSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UsZipCodeSegmentConditionType> for String {
+    fn from(e: UsZipCodeSegmentConditionType) -> String {
+        match e {
+            UsZipCodeSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UsZipCodeSegmentConditionType {
     fn default() -> UsZipCodeSegmentConditionType {
         UsZipCodeSegmentConditionType::Noop
@@ -4031,13 +5532,15 @@ impl UsZipCodeSegmentConditionType {
 * Segment members who are within a specific US zip code.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsZipCodeSegmentOperator {
     #[serde(rename = "ipgeoinzip")]
     Ipgeoinzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsZipCodeSegmentOperator {
@@ -4045,12 +5548,31 @@ impl std::fmt::Display for UsZipCodeSegmentOperator {
         match self {
             UsZipCodeSegmentOperator::Ipgeoinzip => "ipgeoinzip",
             UsZipCodeSegmentOperator::Noop => "",
-            UsZipCodeSegmentOperator::FallthroughString => "*",
+            UsZipCodeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UsZipCodeSegmentOperator {
+    fn from(s: String) -> UsZipCodeSegmentOperator {
+        match s.as_str() {
+            "ipgeoinzip" => UsZipCodeSegmentOperator::Ipgeoinzip,
+            "" => UsZipCodeSegmentOperator::Noop,
+            _ => UsZipCodeSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UsZipCodeSegmentOperator> for String {
+    fn from(e: UsZipCodeSegmentOperator) -> String {
+        match e {
+            UsZipCodeSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UsZipCodeSegmentOperator {
     fn default() -> UsZipCodeSegmentOperator {
         UsZipCodeSegmentOperator::Noop
@@ -4101,13 +5623,15 @@ pub struct UsZipCodeSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UnknownLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoUnknown")]
     IpGeoUnknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
@@ -4115,12 +5639,31 @@ impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
         match self {
             UnknownLocationBasedSegmentConditionType::IpGeoUnknown => "IPGeoUnknown",
             UnknownLocationBasedSegmentConditionType::Noop => "",
-            UnknownLocationBasedSegmentConditionType::FallthroughString => "*",
+            UnknownLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UnknownLocationBasedSegmentConditionType {
+    fn from(s: String) -> UnknownLocationBasedSegmentConditionType {
+        match s.as_str() {
+            "IPGeoUnknown" => UnknownLocationBasedSegmentConditionType::IpGeoUnknown,
+            "" => UnknownLocationBasedSegmentConditionType::Noop,
+            _ => UnknownLocationBasedSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UnknownLocationBasedSegmentConditionType> for String {
+    fn from(e: UnknownLocationBasedSegmentConditionType) -> String {
+        match e {
+            UnknownLocationBasedSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UnknownLocationBasedSegmentConditionType {
     fn default() -> UnknownLocationBasedSegmentConditionType {
         UnknownLocationBasedSegmentConditionType::Noop
@@ -4136,13 +5679,15 @@ impl UnknownLocationBasedSegmentConditionType {
 * Segment members for which location information is unknown.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UnknownLocationBasedSegmentOperator {
     #[serde(rename = "ipgeounknown")]
     Ipgeounknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
@@ -4150,12 +5695,31 @@ impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
         match self {
             UnknownLocationBasedSegmentOperator::Ipgeounknown => "ipgeounknown",
             UnknownLocationBasedSegmentOperator::Noop => "",
-            UnknownLocationBasedSegmentOperator::FallthroughString => "*",
+            UnknownLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UnknownLocationBasedSegmentOperator {
+    fn from(s: String) -> UnknownLocationBasedSegmentOperator {
+        match s.as_str() {
+            "ipgeounknown" => UnknownLocationBasedSegmentOperator::Ipgeounknown,
+            "" => UnknownLocationBasedSegmentOperator::Noop,
+            _ => UnknownLocationBasedSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UnknownLocationBasedSegmentOperator> for String {
+    fn from(e: UnknownLocationBasedSegmentOperator) -> String {
+        match e {
+            UnknownLocationBasedSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UnknownLocationBasedSegmentOperator {
     fn default() -> UnknownLocationBasedSegmentOperator {
         UnknownLocationBasedSegmentOperator::Noop
@@ -4191,13 +5755,15 @@ pub struct UnknownLocationBasedSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ZipCodeLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoZip")]
     IpGeoZip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
@@ -4205,12 +5771,31 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
         match self {
             ZipCodeLocationBasedSegmentConditionType::IpGeoZip => "IPGeoZip",
             ZipCodeLocationBasedSegmentConditionType::Noop => "",
-            ZipCodeLocationBasedSegmentConditionType::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ZipCodeLocationBasedSegmentConditionType {
+    fn from(s: String) -> ZipCodeLocationBasedSegmentConditionType {
+        match s.as_str() {
+            "IPGeoZip" => ZipCodeLocationBasedSegmentConditionType::IpGeoZip,
+            "" => ZipCodeLocationBasedSegmentConditionType::Noop,
+            _ => ZipCodeLocationBasedSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ZipCodeLocationBasedSegmentConditionType> for String {
+    fn from(e: ZipCodeLocationBasedSegmentConditionType) -> String {
+        match e {
+            ZipCodeLocationBasedSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ZipCodeLocationBasedSegmentConditionType {
     fn default() -> ZipCodeLocationBasedSegmentConditionType {
         ZipCodeLocationBasedSegmentConditionType::Noop
@@ -4226,6 +5811,7 @@ impl ZipCodeLocationBasedSegmentConditionType {
 * Segment members who are/are not within a specific US zip code.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ZipCodeLocationBasedSegmentOperator {
     #[serde(rename = "ipgeoiszip")]
     Ipgeoiszip,
@@ -4233,8 +5819,9 @@ pub enum ZipCodeLocationBasedSegmentOperator {
     Ipgeonotzip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
@@ -4243,12 +5830,32 @@ impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
             ZipCodeLocationBasedSegmentOperator::Ipgeoiszip => "ipgeoiszip",
             ZipCodeLocationBasedSegmentOperator::Ipgeonotzip => "ipgeonotzip",
             ZipCodeLocationBasedSegmentOperator::Noop => "",
-            ZipCodeLocationBasedSegmentOperator::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ZipCodeLocationBasedSegmentOperator {
+    fn from(s: String) -> ZipCodeLocationBasedSegmentOperator {
+        match s.as_str() {
+            "ipgeoiszip" => ZipCodeLocationBasedSegmentOperator::Ipgeoiszip,
+            "ipgeonotzip" => ZipCodeLocationBasedSegmentOperator::Ipgeonotzip,
+            "" => ZipCodeLocationBasedSegmentOperator::Noop,
+            _ => ZipCodeLocationBasedSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ZipCodeLocationBasedSegmentOperator> for String {
+    fn from(e: ZipCodeLocationBasedSegmentOperator) -> String {
+        match e {
+            ZipCodeLocationBasedSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ZipCodeLocationBasedSegmentOperator {
     fn default() -> ZipCodeLocationBasedSegmentOperator {
         ZipCodeLocationBasedSegmentOperator::Noop
@@ -4293,13 +5900,15 @@ pub struct ZipCodeLocationBasedSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesAgeSegmentConditionType {
     #[serde(rename = "SocialAge")]
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
@@ -4307,12 +5916,31 @@ impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
         match self {
             SocialProfilesAgeSegmentConditionType::SocialAge => "SocialAge",
             SocialProfilesAgeSegmentConditionType::Noop => "",
-            SocialProfilesAgeSegmentConditionType::FallthroughString => "*",
+            SocialProfilesAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesAgeSegmentConditionType {
+    fn from(s: String) -> SocialProfilesAgeSegmentConditionType {
+        match s.as_str() {
+            "SocialAge" => SocialProfilesAgeSegmentConditionType::SocialAge,
+            "" => SocialProfilesAgeSegmentConditionType::Noop,
+            _ => SocialProfilesAgeSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesAgeSegmentConditionType> for String {
+    fn from(e: SocialProfilesAgeSegmentConditionType) -> String {
+        match e {
+            SocialProfilesAgeSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesAgeSegmentConditionType {
     fn default() -> SocialProfilesAgeSegmentConditionType {
         SocialProfilesAgeSegmentConditionType::Noop
@@ -4328,13 +5956,15 @@ impl SocialProfilesAgeSegmentConditionType {
 * Segment by age ranges in Social Profiles data.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesAgeSegmentField {
     #[serde(rename = "social_age")]
     SocialAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesAgeSegmentField {
@@ -4342,12 +5972,31 @@ impl std::fmt::Display for SocialProfilesAgeSegmentField {
         match self {
             SocialProfilesAgeSegmentField::SocialAge => "social_age",
             SocialProfilesAgeSegmentField::Noop => "",
-            SocialProfilesAgeSegmentField::FallthroughString => "*",
+            SocialProfilesAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesAgeSegmentField {
+    fn from(s: String) -> SocialProfilesAgeSegmentField {
+        match s.as_str() {
+            "social_age" => SocialProfilesAgeSegmentField::SocialAge,
+            "" => SocialProfilesAgeSegmentField::Noop,
+            _ => SocialProfilesAgeSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesAgeSegmentField> for String {
+    fn from(e: SocialProfilesAgeSegmentField) -> String {
+        match e {
+            SocialProfilesAgeSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesAgeSegmentField {
     fn default() -> SocialProfilesAgeSegmentField {
         SocialProfilesAgeSegmentField::Noop
@@ -4363,6 +6012,7 @@ impl SocialProfilesAgeSegmentField {
 * The age range to segment.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Value {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -4374,8 +6024,9 @@ pub enum Value {
     FiftyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -4386,12 +6037,34 @@ impl std::fmt::Display for Value {
             Value::ThreeThousandFiveHundredAndFiftyFour => "35-54",
             Value::FiftyFive => "55+",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Value {
+    fn from(s: String) -> Value {
+        match s.as_str() {
+            "18-24" => Value::OneThousandEightHundredAndTwentyFour,
+            "25-34" => Value::TwoThousandFiveHundredAndThirtyFour,
+            "35-54" => Value::ThreeThousandFiveHundredAndFiftyFour,
+            "55+" => Value::FiftyFive,
+            "" => Value::Noop,
+            _ => Value::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Value> for String {
+    fn from(e: Value) -> String {
+        match e {
+            Value::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Value {
     fn default() -> Value {
         Value::Noop
@@ -4432,13 +6105,15 @@ pub struct SocialProfilesAgeSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesGenderSegmentConditionType {
     #[serde(rename = "SocialGender")]
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
@@ -4446,12 +6121,31 @@ impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
         match self {
             SocialProfilesGenderSegmentConditionType::SocialGender => "SocialGender",
             SocialProfilesGenderSegmentConditionType::Noop => "",
-            SocialProfilesGenderSegmentConditionType::FallthroughString => "*",
+            SocialProfilesGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesGenderSegmentConditionType {
+    fn from(s: String) -> SocialProfilesGenderSegmentConditionType {
+        match s.as_str() {
+            "SocialGender" => SocialProfilesGenderSegmentConditionType::SocialGender,
+            "" => SocialProfilesGenderSegmentConditionType::Noop,
+            _ => SocialProfilesGenderSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesGenderSegmentConditionType> for String {
+    fn from(e: SocialProfilesGenderSegmentConditionType) -> String {
+        match e {
+            SocialProfilesGenderSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentConditionType {
     fn default() -> SocialProfilesGenderSegmentConditionType {
         SocialProfilesGenderSegmentConditionType::Noop
@@ -4467,13 +6161,15 @@ impl SocialProfilesGenderSegmentConditionType {
 * Segment by listed gender in Social Profiles data.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesGenderSegmentField {
     #[serde(rename = "social_gender")]
     SocialGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentField {
@@ -4481,12 +6177,31 @@ impl std::fmt::Display for SocialProfilesGenderSegmentField {
         match self {
             SocialProfilesGenderSegmentField::SocialGender => "social_gender",
             SocialProfilesGenderSegmentField::Noop => "",
-            SocialProfilesGenderSegmentField::FallthroughString => "*",
+            SocialProfilesGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesGenderSegmentField {
+    fn from(s: String) -> SocialProfilesGenderSegmentField {
+        match s.as_str() {
+            "social_gender" => SocialProfilesGenderSegmentField::SocialGender,
+            "" => SocialProfilesGenderSegmentField::Noop,
+            _ => SocialProfilesGenderSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesGenderSegmentField> for String {
+    fn from(e: SocialProfilesGenderSegmentField) -> String {
+        match e {
+            SocialProfilesGenderSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentField {
     fn default() -> SocialProfilesGenderSegmentField {
         SocialProfilesGenderSegmentField::Noop
@@ -4502,6 +6217,7 @@ impl SocialProfilesGenderSegmentField {
 * The Social Profiles gender to segment.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesGenderSegmentOperator {
     #[serde(rename = "female")]
     Female,
@@ -4509,8 +6225,9 @@ pub enum SocialProfilesGenderSegmentOperator {
     Male,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
@@ -4519,12 +6236,32 @@ impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
             SocialProfilesGenderSegmentOperator::Female => "female",
             SocialProfilesGenderSegmentOperator::Male => "male",
             SocialProfilesGenderSegmentOperator::Noop => "",
-            SocialProfilesGenderSegmentOperator::FallthroughString => "*",
+            SocialProfilesGenderSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesGenderSegmentOperator {
+    fn from(s: String) -> SocialProfilesGenderSegmentOperator {
+        match s.as_str() {
+            "female" => SocialProfilesGenderSegmentOperator::Female,
+            "male" => SocialProfilesGenderSegmentOperator::Male,
+            "" => SocialProfilesGenderSegmentOperator::Noop,
+            _ => SocialProfilesGenderSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesGenderSegmentOperator> for String {
+    fn from(e: SocialProfilesGenderSegmentOperator) -> String {
+        match e {
+            SocialProfilesGenderSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesGenderSegmentOperator {
     fn default() -> SocialProfilesGenderSegmentOperator {
         SocialProfilesGenderSegmentOperator::Noop
@@ -4568,13 +6305,15 @@ pub struct SocialProfilesGenderSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesInfluenceSegmentConditionType {
     #[serde(rename = "SocialInfluence")]
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
@@ -4582,12 +6321,31 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
         match self {
             SocialProfilesInfluenceSegmentConditionType::SocialInfluence => "SocialInfluence",
             SocialProfilesInfluenceSegmentConditionType::Noop => "",
-            SocialProfilesInfluenceSegmentConditionType::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesInfluenceSegmentConditionType {
+    fn from(s: String) -> SocialProfilesInfluenceSegmentConditionType {
+        match s.as_str() {
+            "SocialInfluence" => SocialProfilesInfluenceSegmentConditionType::SocialInfluence,
+            "" => SocialProfilesInfluenceSegmentConditionType::Noop,
+            _ => SocialProfilesInfluenceSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesInfluenceSegmentConditionType> for String {
+    fn from(e: SocialProfilesInfluenceSegmentConditionType) -> String {
+        match e {
+            SocialProfilesInfluenceSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesInfluenceSegmentConditionType {
     fn default() -> SocialProfilesInfluenceSegmentConditionType {
         SocialProfilesInfluenceSegmentConditionType::Noop
@@ -4603,13 +6361,15 @@ impl SocialProfilesInfluenceSegmentConditionType {
 * Segment by influence rating in Social Profiles data.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesInfluenceSegmentField {
     #[serde(rename = "social_influence")]
     SocialInfluence,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
@@ -4617,12 +6377,31 @@ impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
         match self {
             SocialProfilesInfluenceSegmentField::SocialInfluence => "social_influence",
             SocialProfilesInfluenceSegmentField::Noop => "",
-            SocialProfilesInfluenceSegmentField::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesInfluenceSegmentField {
+    fn from(s: String) -> SocialProfilesInfluenceSegmentField {
+        match s.as_str() {
+            "social_influence" => SocialProfilesInfluenceSegmentField::SocialInfluence,
+            "" => SocialProfilesInfluenceSegmentField::Noop,
+            _ => SocialProfilesInfluenceSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesInfluenceSegmentField> for String {
+    fn from(e: SocialProfilesInfluenceSegmentField) -> String {
+        match e {
+            SocialProfilesInfluenceSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesInfluenceSegmentField {
     fn default() -> SocialProfilesInfluenceSegmentField {
         SocialProfilesInfluenceSegmentField::Noop
@@ -4667,13 +6446,15 @@ pub struct SocialProfilesInfluenceSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkSegmentConditionType {
     #[serde(rename = "SocialNetworkMember")]
     SocialNetworkMember,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
@@ -4681,12 +6462,31 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
         match self {
             SocialProfilesNetworkSegmentConditionType::SocialNetworkMember => "SocialNetworkMember",
             SocialProfilesNetworkSegmentConditionType::Noop => "",
-            SocialProfilesNetworkSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesNetworkSegmentConditionType {
+    fn from(s: String) -> SocialProfilesNetworkSegmentConditionType {
+        match s.as_str() {
+            "SocialNetworkMember" => SocialProfilesNetworkSegmentConditionType::SocialNetworkMember,
+            "" => SocialProfilesNetworkSegmentConditionType::Noop,
+            _ => SocialProfilesNetworkSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesNetworkSegmentConditionType> for String {
+    fn from(e: SocialProfilesNetworkSegmentConditionType) -> String {
+        match e {
+            SocialProfilesNetworkSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentConditionType {
     fn default() -> SocialProfilesNetworkSegmentConditionType {
         SocialProfilesNetworkSegmentConditionType::Noop
@@ -4702,13 +6502,15 @@ impl SocialProfilesNetworkSegmentConditionType {
 * Segment by social network in Social Profiles data.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkSegmentField {
     #[serde(rename = "social_network")]
     SocialNetwork,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentField {
@@ -4716,12 +6518,31 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentField {
         match self {
             SocialProfilesNetworkSegmentField::SocialNetwork => "social_network",
             SocialProfilesNetworkSegmentField::Noop => "",
-            SocialProfilesNetworkSegmentField::FallthroughString => "*",
+            SocialProfilesNetworkSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesNetworkSegmentField {
+    fn from(s: String) -> SocialProfilesNetworkSegmentField {
+        match s.as_str() {
+            "social_network" => SocialProfilesNetworkSegmentField::SocialNetwork,
+            "" => SocialProfilesNetworkSegmentField::Noop,
+            _ => SocialProfilesNetworkSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesNetworkSegmentField> for String {
+    fn from(e: SocialProfilesNetworkSegmentField) -> String {
+        match e {
+            SocialProfilesNetworkSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentField {
     fn default() -> SocialProfilesNetworkSegmentField {
         SocialProfilesNetworkSegmentField::Noop
@@ -4737,6 +6558,7 @@ impl SocialProfilesNetworkSegmentField {
 * The social network to segment against.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkSegmentOperator {
     #[serde(rename = "facebook")]
     Facebook,
@@ -4762,8 +6584,9 @@ pub enum SocialProfilesNetworkSegmentOperator {
     Youtube,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
@@ -4781,12 +6604,41 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
             SocialProfilesNetworkSegmentOperator::Yelp => "yelp",
             SocialProfilesNetworkSegmentOperator::Youtube => "youtube",
             SocialProfilesNetworkSegmentOperator::Noop => "",
-            SocialProfilesNetworkSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesNetworkSegmentOperator {
+    fn from(s: String) -> SocialProfilesNetworkSegmentOperator {
+        match s.as_str() {
+            "facebook" => SocialProfilesNetworkSegmentOperator::Facebook,
+            "flickr" => SocialProfilesNetworkSegmentOperator::Flickr,
+            "foursquare" => SocialProfilesNetworkSegmentOperator::Foursquare,
+            "lastfm" => SocialProfilesNetworkSegmentOperator::Lastfm,
+            "linkedin" => SocialProfilesNetworkSegmentOperator::Linkedin,
+            "myspace" => SocialProfilesNetworkSegmentOperator::Myspace,
+            "quora" => SocialProfilesNetworkSegmentOperator::Quora,
+            "twitter" => SocialProfilesNetworkSegmentOperator::Twitter,
+            "vimeo" => SocialProfilesNetworkSegmentOperator::Vimeo,
+            "yelp" => SocialProfilesNetworkSegmentOperator::Yelp,
+            "youtube" => SocialProfilesNetworkSegmentOperator::Youtube,
+            "" => SocialProfilesNetworkSegmentOperator::Noop,
+            _ => SocialProfilesNetworkSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesNetworkSegmentOperator> for String {
+    fn from(e: SocialProfilesNetworkSegmentOperator) -> String {
+        match e {
+            SocialProfilesNetworkSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesNetworkSegmentOperator {
     fn default() -> SocialProfilesNetworkSegmentOperator {
         SocialProfilesNetworkSegmentOperator::Noop
@@ -4830,13 +6682,15 @@ pub struct SocialProfilesNetworkSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkFollowSegmentConditionType {
     #[serde(rename = "SocialNetworkFollow")]
     SocialNetworkFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
@@ -4846,12 +6700,33 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
                 "SocialNetworkFollow"
             }
             SocialProfilesNetworkFollowSegmentConditionType::Noop => "",
-            SocialProfilesNetworkFollowSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesNetworkFollowSegmentConditionType {
+    fn from(s: String) -> SocialProfilesNetworkFollowSegmentConditionType {
+        match s.as_str() {
+            "SocialNetworkFollow" => {
+                SocialProfilesNetworkFollowSegmentConditionType::SocialNetworkFollow
+            }
+            "" => SocialProfilesNetworkFollowSegmentConditionType::Noop,
+            _ => SocialProfilesNetworkFollowSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesNetworkFollowSegmentConditionType> for String {
+    fn from(e: SocialProfilesNetworkFollowSegmentConditionType) -> String {
+        match e {
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentConditionType {
     fn default() -> SocialProfilesNetworkFollowSegmentConditionType {
         SocialProfilesNetworkFollowSegmentConditionType::Noop
@@ -4867,6 +6742,7 @@ impl SocialProfilesNetworkFollowSegmentConditionType {
 * Members who are/not following a linked account on a given social network.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkFollowSegmentOperator {
     #[serde(rename = "follow")]
     Follow,
@@ -4874,8 +6750,9 @@ pub enum SocialProfilesNetworkFollowSegmentOperator {
     Notfollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
@@ -4884,12 +6761,32 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
             SocialProfilesNetworkFollowSegmentOperator::Follow => "follow",
             SocialProfilesNetworkFollowSegmentOperator::Notfollow => "notfollow",
             SocialProfilesNetworkFollowSegmentOperator::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesNetworkFollowSegmentOperator {
+    fn from(s: String) -> SocialProfilesNetworkFollowSegmentOperator {
+        match s.as_str() {
+            "follow" => SocialProfilesNetworkFollowSegmentOperator::Follow,
+            "notfollow" => SocialProfilesNetworkFollowSegmentOperator::Notfollow,
+            "" => SocialProfilesNetworkFollowSegmentOperator::Noop,
+            _ => SocialProfilesNetworkFollowSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesNetworkFollowSegmentOperator> for String {
+    fn from(e: SocialProfilesNetworkFollowSegmentOperator) -> String {
+        match e {
+            SocialProfilesNetworkFollowSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentOperator {
     fn default() -> SocialProfilesNetworkFollowSegmentOperator {
         SocialProfilesNetworkFollowSegmentOperator::Noop
@@ -4905,13 +6802,15 @@ impl SocialProfilesNetworkFollowSegmentOperator {
 * The social network to segment against.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkFollowSegmentOperatorData {
     #[serde(rename = "twitter_follow")]
     TwitterFollow,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
@@ -4919,12 +6818,31 @@ impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
         match self {
             SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow => "twitter_follow",
             SocialProfilesNetworkFollowSegmentOperatorData::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperatorData::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn from(s: String) -> SocialProfilesNetworkFollowSegmentOperatorData {
+        match s.as_str() {
+            "twitter_follow" => SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow,
+            "" => SocialProfilesNetworkFollowSegmentOperatorData::Noop,
+            _ => SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SocialProfilesNetworkFollowSegmentOperatorData> for String {
+    fn from(e: SocialProfilesNetworkFollowSegmentOperatorData) -> String {
+        match e {
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SocialProfilesNetworkFollowSegmentOperatorData {
     fn default() -> SocialProfilesNetworkFollowSegmentOperatorData {
         SocialProfilesNetworkFollowSegmentOperatorData::Noop
@@ -4971,13 +6889,15 @@ pub struct SocialProfilesNetworkFollowSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressMergeFieldSegmentConditionType {
     #[serde(rename = "AddressMerge")]
     AddressMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
@@ -4985,12 +6905,31 @@ impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
         match self {
             AddressMergeFieldSegmentConditionType::AddressMerge => "AddressMerge",
             AddressMergeFieldSegmentConditionType::Noop => "",
-            AddressMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AddressMergeFieldSegmentConditionType {
+    fn from(s: String) -> AddressMergeFieldSegmentConditionType {
+        match s.as_str() {
+            "AddressMerge" => AddressMergeFieldSegmentConditionType::AddressMerge,
+            "" => AddressMergeFieldSegmentConditionType::Noop,
+            _ => AddressMergeFieldSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AddressMergeFieldSegmentConditionType> for String {
+    fn from(e: AddressMergeFieldSegmentConditionType) -> String {
+        match e {
+            AddressMergeFieldSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AddressMergeFieldSegmentConditionType {
     fn default() -> AddressMergeFieldSegmentConditionType {
         AddressMergeFieldSegmentConditionType::Noop
@@ -5006,6 +6945,7 @@ impl AddressMergeFieldSegmentConditionType {
 * Whether the member's address merge field contains/does not contain a value or is/is not blank.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5017,8 +6957,9 @@ pub enum AddressMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressMergeFieldSegmentOperator {
@@ -5029,12 +6970,34 @@ impl std::fmt::Display for AddressMergeFieldSegmentOperator {
             AddressMergeFieldSegmentOperator::Contains => "contains",
             AddressMergeFieldSegmentOperator::Notcontain => "notcontain",
             AddressMergeFieldSegmentOperator::Noop => "",
-            AddressMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AddressMergeFieldSegmentOperator {
+    fn from(s: String) -> AddressMergeFieldSegmentOperator {
+        match s.as_str() {
+            "blank" => AddressMergeFieldSegmentOperator::Blank,
+            "blank_not" => AddressMergeFieldSegmentOperator::BlankNot,
+            "contains" => AddressMergeFieldSegmentOperator::Contains,
+            "notcontain" => AddressMergeFieldSegmentOperator::Notcontain,
+            "" => AddressMergeFieldSegmentOperator::Noop,
+            _ => AddressMergeFieldSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AddressMergeFieldSegmentOperator> for String {
+    fn from(e: AddressMergeFieldSegmentOperator) -> String {
+        match e {
+            AddressMergeFieldSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AddressMergeFieldSegmentOperator {
     fn default() -> AddressMergeFieldSegmentOperator {
         AddressMergeFieldSegmentOperator::Noop
@@ -5083,13 +7046,15 @@ pub struct AddressMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressZipMergeFieldSegmentConditionType {
     #[serde(rename = "ZipMerge")]
     ZipMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
@@ -5097,12 +7062,31 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
         match self {
             AddressZipMergeFieldSegmentConditionType::ZipMerge => "ZipMerge",
             AddressZipMergeFieldSegmentConditionType::Noop => "",
-            AddressZipMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressZipMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AddressZipMergeFieldSegmentConditionType {
+    fn from(s: String) -> AddressZipMergeFieldSegmentConditionType {
+        match s.as_str() {
+            "ZipMerge" => AddressZipMergeFieldSegmentConditionType::ZipMerge,
+            "" => AddressZipMergeFieldSegmentConditionType::Noop,
+            _ => AddressZipMergeFieldSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AddressZipMergeFieldSegmentConditionType> for String {
+    fn from(e: AddressZipMergeFieldSegmentConditionType) -> String {
+        match e {
+            AddressZipMergeFieldSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AddressZipMergeFieldSegmentConditionType {
     fn default() -> AddressZipMergeFieldSegmentConditionType {
         AddressZipMergeFieldSegmentConditionType::Noop
@@ -5118,13 +7102,15 @@ impl AddressZipMergeFieldSegmentConditionType {
 * Whether the member's address merge field is within a given distance from a city or zip.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressZipMergeFieldSegmentOperator {
     #[serde(rename = "geoin")]
     Geoin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
@@ -5132,12 +7118,31 @@ impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
         match self {
             AddressZipMergeFieldSegmentOperator::Geoin => "geoin",
             AddressZipMergeFieldSegmentOperator::Noop => "",
-            AddressZipMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressZipMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AddressZipMergeFieldSegmentOperator {
+    fn from(s: String) -> AddressZipMergeFieldSegmentOperator {
+        match s.as_str() {
+            "geoin" => AddressZipMergeFieldSegmentOperator::Geoin,
+            "" => AddressZipMergeFieldSegmentOperator::Noop,
+            _ => AddressZipMergeFieldSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AddressZipMergeFieldSegmentOperator> for String {
+    fn from(e: AddressZipMergeFieldSegmentOperator) -> String {
+        match e {
+            AddressZipMergeFieldSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AddressZipMergeFieldSegmentOperator {
     fn default() -> AddressZipMergeFieldSegmentOperator {
         AddressZipMergeFieldSegmentOperator::Noop
@@ -5195,13 +7200,15 @@ pub struct AddressZipMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BirthdayMergeFieldSegmentConditionType {
     #[serde(rename = "BirthdayMerge")]
     BirthdayMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
@@ -5209,12 +7216,31 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
         match self {
             BirthdayMergeFieldSegmentConditionType::BirthdayMerge => "BirthdayMerge",
             BirthdayMergeFieldSegmentConditionType::Noop => "",
-            BirthdayMergeFieldSegmentConditionType::FallthroughString => "*",
+            BirthdayMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BirthdayMergeFieldSegmentConditionType {
+    fn from(s: String) -> BirthdayMergeFieldSegmentConditionType {
+        match s.as_str() {
+            "BirthdayMerge" => BirthdayMergeFieldSegmentConditionType::BirthdayMerge,
+            "" => BirthdayMergeFieldSegmentConditionType::Noop,
+            _ => BirthdayMergeFieldSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BirthdayMergeFieldSegmentConditionType> for String {
+    fn from(e: BirthdayMergeFieldSegmentConditionType) -> String {
+        match e {
+            BirthdayMergeFieldSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BirthdayMergeFieldSegmentConditionType {
     fn default() -> BirthdayMergeFieldSegmentConditionType {
         BirthdayMergeFieldSegmentConditionType::Noop
@@ -5230,6 +7256,7 @@ impl BirthdayMergeFieldSegmentConditionType {
 * Whether the member's birthday merge information is/is not a certain date or is/is not blank.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BirthdayMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5241,8 +7268,9 @@ pub enum BirthdayMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
@@ -5253,12 +7281,34 @@ impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
             BirthdayMergeFieldSegmentOperator::Is => "is",
             BirthdayMergeFieldSegmentOperator::Not => "not",
             BirthdayMergeFieldSegmentOperator::Noop => "",
-            BirthdayMergeFieldSegmentOperator::FallthroughString => "*",
+            BirthdayMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BirthdayMergeFieldSegmentOperator {
+    fn from(s: String) -> BirthdayMergeFieldSegmentOperator {
+        match s.as_str() {
+            "blank" => BirthdayMergeFieldSegmentOperator::Blank,
+            "blank_not" => BirthdayMergeFieldSegmentOperator::BlankNot,
+            "is" => BirthdayMergeFieldSegmentOperator::Is,
+            "not" => BirthdayMergeFieldSegmentOperator::Not,
+            "" => BirthdayMergeFieldSegmentOperator::Noop,
+            _ => BirthdayMergeFieldSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BirthdayMergeFieldSegmentOperator> for String {
+    fn from(e: BirthdayMergeFieldSegmentOperator) -> String {
+        match e {
+            BirthdayMergeFieldSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BirthdayMergeFieldSegmentOperator {
     fn default() -> BirthdayMergeFieldSegmentOperator {
         BirthdayMergeFieldSegmentOperator::Noop
@@ -5307,13 +7357,15 @@ pub struct BirthdayMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateMergeFieldSegmentConditionType {
     #[serde(rename = "DateMerge")]
     DateMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentConditionType {
@@ -5321,12 +7373,31 @@ impl std::fmt::Display for DateMergeFieldSegmentConditionType {
         match self {
             DateMergeFieldSegmentConditionType::DateMerge => "DateMerge",
             DateMergeFieldSegmentConditionType::Noop => "",
-            DateMergeFieldSegmentConditionType::FallthroughString => "*",
+            DateMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DateMergeFieldSegmentConditionType {
+    fn from(s: String) -> DateMergeFieldSegmentConditionType {
+        match s.as_str() {
+            "DateMerge" => DateMergeFieldSegmentConditionType::DateMerge,
+            "" => DateMergeFieldSegmentConditionType::Noop,
+            _ => DateMergeFieldSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DateMergeFieldSegmentConditionType> for String {
+    fn from(e: DateMergeFieldSegmentConditionType) -> String {
+        match e {
+            DateMergeFieldSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DateMergeFieldSegmentConditionType {
     fn default() -> DateMergeFieldSegmentConditionType {
         DateMergeFieldSegmentConditionType::Noop
@@ -5342,6 +7413,7 @@ impl DateMergeFieldSegmentConditionType {
 * Whether the member's merge information is/is not, is greater/less than a value or is/is not blank.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5357,8 +7429,9 @@ pub enum DateMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DateMergeFieldSegmentOperator {
@@ -5371,12 +7444,36 @@ impl std::fmt::Display for DateMergeFieldSegmentOperator {
             DateMergeFieldSegmentOperator::Less => "less",
             DateMergeFieldSegmentOperator::Not => "not",
             DateMergeFieldSegmentOperator::Noop => "",
-            DateMergeFieldSegmentOperator::FallthroughString => "*",
+            DateMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DateMergeFieldSegmentOperator {
+    fn from(s: String) -> DateMergeFieldSegmentOperator {
+        match s.as_str() {
+            "blank" => DateMergeFieldSegmentOperator::Blank,
+            "blank_not" => DateMergeFieldSegmentOperator::BlankNot,
+            "greater" => DateMergeFieldSegmentOperator::Greater,
+            "is" => DateMergeFieldSegmentOperator::Is,
+            "less" => DateMergeFieldSegmentOperator::Less,
+            "not" => DateMergeFieldSegmentOperator::Not,
+            "" => DateMergeFieldSegmentOperator::Noop,
+            _ => DateMergeFieldSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DateMergeFieldSegmentOperator> for String {
+    fn from(e: DateMergeFieldSegmentOperator) -> String {
+        match e {
+            DateMergeFieldSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DateMergeFieldSegmentOperator {
     fn default() -> DateMergeFieldSegmentOperator {
         DateMergeFieldSegmentOperator::Noop
@@ -5425,13 +7522,15 @@ pub struct DateMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DropdownRadioMergeFieldSegmentConditionType {
     #[serde(rename = "SelectMerge")]
     SelectMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
@@ -5439,12 +7538,31 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
         match self {
             DropdownRadioMergeFieldSegmentConditionType::SelectMerge => "SelectMerge",
             DropdownRadioMergeFieldSegmentConditionType::Noop => "",
-            DropdownRadioMergeFieldSegmentConditionType::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DropdownRadioMergeFieldSegmentConditionType {
+    fn from(s: String) -> DropdownRadioMergeFieldSegmentConditionType {
+        match s.as_str() {
+            "SelectMerge" => DropdownRadioMergeFieldSegmentConditionType::SelectMerge,
+            "" => DropdownRadioMergeFieldSegmentConditionType::Noop,
+            _ => DropdownRadioMergeFieldSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DropdownRadioMergeFieldSegmentConditionType> for String {
+    fn from(e: DropdownRadioMergeFieldSegmentConditionType) -> String {
+        match e {
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DropdownRadioMergeFieldSegmentConditionType {
     fn default() -> DropdownRadioMergeFieldSegmentConditionType {
         DropdownRadioMergeFieldSegmentConditionType::Noop
@@ -5460,6 +7578,7 @@ impl DropdownRadioMergeFieldSegmentConditionType {
 * Whether the member's merge information is/is not a value or is/is not blank.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DropdownRadioMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5475,8 +7594,9 @@ pub enum DropdownRadioMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
@@ -5489,12 +7609,36 @@ impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
             DropdownRadioMergeFieldSegmentOperator::Not => "not",
             DropdownRadioMergeFieldSegmentOperator::Notcontain => "notcontain",
             DropdownRadioMergeFieldSegmentOperator::Noop => "",
-            DropdownRadioMergeFieldSegmentOperator::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DropdownRadioMergeFieldSegmentOperator {
+    fn from(s: String) -> DropdownRadioMergeFieldSegmentOperator {
+        match s.as_str() {
+            "blank" => DropdownRadioMergeFieldSegmentOperator::Blank,
+            "blank_not" => DropdownRadioMergeFieldSegmentOperator::BlankNot,
+            "contains" => DropdownRadioMergeFieldSegmentOperator::Contains,
+            "is" => DropdownRadioMergeFieldSegmentOperator::Is,
+            "not" => DropdownRadioMergeFieldSegmentOperator::Not,
+            "notcontain" => DropdownRadioMergeFieldSegmentOperator::Notcontain,
+            "" => DropdownRadioMergeFieldSegmentOperator::Noop,
+            _ => DropdownRadioMergeFieldSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DropdownRadioMergeFieldSegmentOperator> for String {
+    fn from(e: DropdownRadioMergeFieldSegmentOperator) -> String {
+        match e {
+            DropdownRadioMergeFieldSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DropdownRadioMergeFieldSegmentOperator {
     fn default() -> DropdownRadioMergeFieldSegmentOperator {
         DropdownRadioMergeFieldSegmentOperator::Noop
@@ -5543,13 +7687,15 @@ pub struct DropdownRadioMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TextNumberMergeFieldSegmentConditionType {
     #[serde(rename = "TextMerge")]
     TextMerge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
@@ -5557,12 +7703,31 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
         match self {
             TextNumberMergeFieldSegmentConditionType::TextMerge => "TextMerge",
             TextNumberMergeFieldSegmentConditionType::Noop => "",
-            TextNumberMergeFieldSegmentConditionType::FallthroughString => "*",
+            TextNumberMergeFieldSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TextNumberMergeFieldSegmentConditionType {
+    fn from(s: String) -> TextNumberMergeFieldSegmentConditionType {
+        match s.as_str() {
+            "TextMerge" => TextNumberMergeFieldSegmentConditionType::TextMerge,
+            "" => TextNumberMergeFieldSegmentConditionType::Noop,
+            _ => TextNumberMergeFieldSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TextNumberMergeFieldSegmentConditionType> for String {
+    fn from(e: TextNumberMergeFieldSegmentConditionType) -> String {
+        match e {
+            TextNumberMergeFieldSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TextNumberMergeFieldSegmentConditionType {
     fn default() -> TextNumberMergeFieldSegmentConditionType {
         TextNumberMergeFieldSegmentConditionType::Noop
@@ -5578,6 +7743,7 @@ impl TextNumberMergeFieldSegmentConditionType {
 * Whether the member's merge information is/is not, contains/does not contain, starts/ends with, or is greater/less than a value
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TextNumberMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5601,8 +7767,9 @@ pub enum TextNumberMergeFieldSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
@@ -5619,12 +7786,40 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
             TextNumberMergeFieldSegmentOperator::Notcontain => "notcontain",
             TextNumberMergeFieldSegmentOperator::Starts => "starts",
             TextNumberMergeFieldSegmentOperator::Noop => "",
-            TextNumberMergeFieldSegmentOperator::FallthroughString => "*",
+            TextNumberMergeFieldSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TextNumberMergeFieldSegmentOperator {
+    fn from(s: String) -> TextNumberMergeFieldSegmentOperator {
+        match s.as_str() {
+            "blank" => TextNumberMergeFieldSegmentOperator::Blank,
+            "blank_not" => TextNumberMergeFieldSegmentOperator::BlankNot,
+            "contains" => TextNumberMergeFieldSegmentOperator::Contains,
+            "ends" => TextNumberMergeFieldSegmentOperator::Ends,
+            "greater" => TextNumberMergeFieldSegmentOperator::Greater,
+            "is" => TextNumberMergeFieldSegmentOperator::Is,
+            "less" => TextNumberMergeFieldSegmentOperator::Less,
+            "not" => TextNumberMergeFieldSegmentOperator::Not,
+            "notcontain" => TextNumberMergeFieldSegmentOperator::Notcontain,
+            "starts" => TextNumberMergeFieldSegmentOperator::Starts,
+            "" => TextNumberMergeFieldSegmentOperator::Noop,
+            _ => TextNumberMergeFieldSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TextNumberMergeFieldSegmentOperator> for String {
+    fn from(e: TextNumberMergeFieldSegmentOperator) -> String {
+        match e {
+            TextNumberMergeFieldSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TextNumberMergeFieldSegmentOperator {
     fn default() -> TextNumberMergeFieldSegmentOperator {
         TextNumberMergeFieldSegmentOperator::Noop
@@ -5673,13 +7868,15 @@ pub struct TextNumberMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSegmentConditionType {
     #[serde(rename = "EmailAddress")]
     EmailAddress,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentConditionType {
@@ -5687,12 +7884,31 @@ impl std::fmt::Display for EmailSegmentConditionType {
         match self {
             EmailSegmentConditionType::EmailAddress => "EmailAddress",
             EmailSegmentConditionType::Noop => "",
-            EmailSegmentConditionType::FallthroughString => "*",
+            EmailSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailSegmentConditionType {
+    fn from(s: String) -> EmailSegmentConditionType {
+        match s.as_str() {
+            "EmailAddress" => EmailSegmentConditionType::EmailAddress,
+            "" => EmailSegmentConditionType::Noop,
+            _ => EmailSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailSegmentConditionType> for String {
+    fn from(e: EmailSegmentConditionType) -> String {
+        match e {
+            EmailSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailSegmentConditionType {
     fn default() -> EmailSegmentConditionType {
         EmailSegmentConditionType::Noop
@@ -5708,6 +7924,7 @@ impl EmailSegmentConditionType {
 * Segmenting based off of a subscriber's email address.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSegmentField {
     #[serde(rename = "EMAIL")]
     Email,
@@ -5715,8 +7932,9 @@ pub enum EmailSegmentField {
     Merge0,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentField {
@@ -5725,12 +7943,32 @@ impl std::fmt::Display for EmailSegmentField {
             EmailSegmentField::Email => "EMAIL",
             EmailSegmentField::Merge0 => "merge0",
             EmailSegmentField::Noop => "",
-            EmailSegmentField::FallthroughString => "*",
+            EmailSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailSegmentField {
+    fn from(s: String) -> EmailSegmentField {
+        match s.as_str() {
+            "EMAIL" => EmailSegmentField::Email,
+            "merge0" => EmailSegmentField::Merge0,
+            "" => EmailSegmentField::Noop,
+            _ => EmailSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailSegmentField> for String {
+    fn from(e: EmailSegmentField) -> String {
+        match e {
+            EmailSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailSegmentField {
     fn default() -> EmailSegmentField {
         EmailSegmentField::Noop
@@ -5746,6 +7984,7 @@ impl EmailSegmentField {
 * Whether the email address is/not exactly, contains/doesn't contain, starts/ends with a string.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -5765,8 +8004,9 @@ pub enum EmailSegmentOperator {
     Starts,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSegmentOperator {
@@ -5781,12 +8021,38 @@ impl std::fmt::Display for EmailSegmentOperator {
             EmailSegmentOperator::Notcontain => "notcontain",
             EmailSegmentOperator::Starts => "starts",
             EmailSegmentOperator::Noop => "",
-            EmailSegmentOperator::FallthroughString => "*",
+            EmailSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailSegmentOperator {
+    fn from(s: String) -> EmailSegmentOperator {
+        match s.as_str() {
+            "contains" => EmailSegmentOperator::Contains,
+            "ends" => EmailSegmentOperator::Ends,
+            "greater" => EmailSegmentOperator::Greater,
+            "is" => EmailSegmentOperator::Is,
+            "less" => EmailSegmentOperator::Less,
+            "not" => EmailSegmentOperator::Not,
+            "notcontain" => EmailSegmentOperator::Notcontain,
+            "starts" => EmailSegmentOperator::Starts,
+            "" => EmailSegmentOperator::Noop,
+            _ => EmailSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailSegmentOperator> for String {
+    fn from(e: EmailSegmentOperator) -> String {
+        match e {
+            EmailSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailSegmentOperator {
     fn default() -> EmailSegmentOperator {
         EmailSegmentOperator::Noop
@@ -5828,13 +8094,15 @@ pub struct EmailSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedGenderSegmentConditionType {
     #[serde(rename = "PredictedGender")]
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentConditionType {
@@ -5842,12 +8110,31 @@ impl std::fmt::Display for PredictedGenderSegmentConditionType {
         match self {
             PredictedGenderSegmentConditionType::PredictedGender => "PredictedGender",
             PredictedGenderSegmentConditionType::Noop => "",
-            PredictedGenderSegmentConditionType::FallthroughString => "*",
+            PredictedGenderSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PredictedGenderSegmentConditionType {
+    fn from(s: String) -> PredictedGenderSegmentConditionType {
+        match s.as_str() {
+            "PredictedGender" => PredictedGenderSegmentConditionType::PredictedGender,
+            "" => PredictedGenderSegmentConditionType::Noop,
+            _ => PredictedGenderSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PredictedGenderSegmentConditionType> for String {
+    fn from(e: PredictedGenderSegmentConditionType) -> String {
+        match e {
+            PredictedGenderSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PredictedGenderSegmentConditionType {
     fn default() -> PredictedGenderSegmentConditionType {
         PredictedGenderSegmentConditionType::Noop
@@ -5863,13 +8150,15 @@ impl PredictedGenderSegmentConditionType {
 * Segment by predicted gender.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedGenderSegmentField {
     #[serde(rename = "predicted_gender")]
     PredictedGender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedGenderSegmentField {
@@ -5877,12 +8166,31 @@ impl std::fmt::Display for PredictedGenderSegmentField {
         match self {
             PredictedGenderSegmentField::PredictedGender => "predicted_gender",
             PredictedGenderSegmentField::Noop => "",
-            PredictedGenderSegmentField::FallthroughString => "*",
+            PredictedGenderSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PredictedGenderSegmentField {
+    fn from(s: String) -> PredictedGenderSegmentField {
+        match s.as_str() {
+            "predicted_gender" => PredictedGenderSegmentField::PredictedGender,
+            "" => PredictedGenderSegmentField::Noop,
+            _ => PredictedGenderSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PredictedGenderSegmentField> for String {
+    fn from(e: PredictedGenderSegmentField) -> String {
+        match e {
+            PredictedGenderSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PredictedGenderSegmentField {
     fn default() -> PredictedGenderSegmentField {
         PredictedGenderSegmentField::Noop
@@ -5923,13 +8231,15 @@ pub struct PredictedGenderSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentConditionType {
     #[serde(rename = "PredictedAge")]
     PredictedAge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentConditionType {
@@ -5937,12 +8247,31 @@ impl std::fmt::Display for PredictedAgeSegmentConditionType {
         match self {
             PredictedAgeSegmentConditionType::PredictedAge => "PredictedAge",
             PredictedAgeSegmentConditionType::Noop => "",
-            PredictedAgeSegmentConditionType::FallthroughString => "*",
+            PredictedAgeSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PredictedAgeSegmentConditionType {
+    fn from(s: String) -> PredictedAgeSegmentConditionType {
+        match s.as_str() {
+            "PredictedAge" => PredictedAgeSegmentConditionType::PredictedAge,
+            "" => PredictedAgeSegmentConditionType::Noop,
+            _ => PredictedAgeSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PredictedAgeSegmentConditionType> for String {
+    fn from(e: PredictedAgeSegmentConditionType) -> String {
+        match e {
+            PredictedAgeSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PredictedAgeSegmentConditionType {
     fn default() -> PredictedAgeSegmentConditionType {
         PredictedAgeSegmentConditionType::Noop
@@ -5958,13 +8287,15 @@ impl PredictedAgeSegmentConditionType {
 * Segment by predicted age.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentField {
     #[serde(rename = "predicted_age_range")]
     PredictedAgeRange,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentField {
@@ -5972,12 +8303,31 @@ impl std::fmt::Display for PredictedAgeSegmentField {
         match self {
             PredictedAgeSegmentField::PredictedAgeRange => "predicted_age_range",
             PredictedAgeSegmentField::Noop => "",
-            PredictedAgeSegmentField::FallthroughString => "*",
+            PredictedAgeSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PredictedAgeSegmentField {
+    fn from(s: String) -> PredictedAgeSegmentField {
+        match s.as_str() {
+            "predicted_age_range" => PredictedAgeSegmentField::PredictedAgeRange,
+            "" => PredictedAgeSegmentField::Noop,
+            _ => PredictedAgeSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PredictedAgeSegmentField> for String {
+    fn from(e: PredictedAgeSegmentField) -> String {
+        match e {
+            PredictedAgeSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PredictedAgeSegmentField {
     fn default() -> PredictedAgeSegmentField {
         PredictedAgeSegmentField::Noop
@@ -5993,13 +8343,15 @@ impl PredictedAgeSegmentField {
 * Members who are/not the exact criteria listed.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentOperator {
     #[serde(rename = "is")]
     Is,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperator {
@@ -6007,12 +8359,31 @@ impl std::fmt::Display for PredictedAgeSegmentOperator {
         match self {
             PredictedAgeSegmentOperator::Is => "is",
             PredictedAgeSegmentOperator::Noop => "",
-            PredictedAgeSegmentOperator::FallthroughString => "*",
+            PredictedAgeSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PredictedAgeSegmentOperator {
+    fn from(s: String) -> PredictedAgeSegmentOperator {
+        match s.as_str() {
+            "is" => PredictedAgeSegmentOperator::Is,
+            "" => PredictedAgeSegmentOperator::Noop,
+            _ => PredictedAgeSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PredictedAgeSegmentOperator> for String {
+    fn from(e: PredictedAgeSegmentOperator) -> String {
+        match e {
+            PredictedAgeSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PredictedAgeSegmentOperator {
     fn default() -> PredictedAgeSegmentOperator {
         PredictedAgeSegmentOperator::Noop
@@ -6028,6 +8399,7 @@ impl PredictedAgeSegmentOperator {
 * The predicted age to segment.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentOperatorData {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -6043,8 +8415,9 @@ pub enum PredictedAgeSegmentOperatorData {
     SixtyFive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PredictedAgeSegmentOperatorData {
@@ -6057,12 +8430,36 @@ impl std::fmt::Display for PredictedAgeSegmentOperatorData {
             PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour => "55-64",
             PredictedAgeSegmentOperatorData::SixtyFive => "65+",
             PredictedAgeSegmentOperatorData::Noop => "",
-            PredictedAgeSegmentOperatorData::FallthroughString => "*",
+            PredictedAgeSegmentOperatorData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PredictedAgeSegmentOperatorData {
+    fn from(s: String) -> PredictedAgeSegmentOperatorData {
+        match s.as_str() {
+            "18-24" => PredictedAgeSegmentOperatorData::OneThousandEightHundredAndTwentyFour,
+            "25-34" => PredictedAgeSegmentOperatorData::TwoThousandFiveHundredAndThirtyFour,
+            "35-44" => PredictedAgeSegmentOperatorData::ThreeThousandFiveHundredAndFortyFour,
+            "45-54" => PredictedAgeSegmentOperatorData::FourThousandFiveHundredAndFiftyFour,
+            "55-64" => PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour,
+            "65+" => PredictedAgeSegmentOperatorData::SixtyFive,
+            "" => PredictedAgeSegmentOperatorData::Noop,
+            _ => PredictedAgeSegmentOperatorData::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PredictedAgeSegmentOperatorData> for String {
+    fn from(e: PredictedAgeSegmentOperatorData) -> String {
+        match e {
+            PredictedAgeSegmentOperatorData::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PredictedAgeSegmentOperatorData {
     fn default() -> PredictedAgeSegmentOperatorData {
         PredictedAgeSegmentOperatorData::Noop
@@ -6103,13 +8500,15 @@ pub struct PredictedAgeSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NewSubscribersPrebuiltSegmentConditionType {
     #[serde(rename = "NewSubscribers")]
     NewSubscribers,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
@@ -6117,12 +8516,31 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
         match self {
             NewSubscribersPrebuiltSegmentConditionType::NewSubscribers => "NewSubscribers",
             NewSubscribersPrebuiltSegmentConditionType::Noop => "",
-            NewSubscribersPrebuiltSegmentConditionType::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NewSubscribersPrebuiltSegmentConditionType {
+    fn from(s: String) -> NewSubscribersPrebuiltSegmentConditionType {
+        match s.as_str() {
+            "NewSubscribers" => NewSubscribersPrebuiltSegmentConditionType::NewSubscribers,
+            "" => NewSubscribersPrebuiltSegmentConditionType::Noop,
+            _ => NewSubscribersPrebuiltSegmentConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NewSubscribersPrebuiltSegmentConditionType> for String {
+    fn from(e: NewSubscribersPrebuiltSegmentConditionType) -> String {
+        match e {
+            NewSubscribersPrebuiltSegmentConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentConditionType {
     fn default() -> NewSubscribersPrebuiltSegmentConditionType {
         NewSubscribersPrebuiltSegmentConditionType::Noop
@@ -6138,13 +8556,15 @@ impl NewSubscribersPrebuiltSegmentConditionType {
 * Segment by when people subscribed.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NewSubscribersPrebuiltSegmentField {
     #[serde(rename = "timestamp_opt")]
     TimestampOpt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
@@ -6152,12 +8572,31 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
         match self {
             NewSubscribersPrebuiltSegmentField::TimestampOpt => "timestamp_opt",
             NewSubscribersPrebuiltSegmentField::Noop => "",
-            NewSubscribersPrebuiltSegmentField::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NewSubscribersPrebuiltSegmentField {
+    fn from(s: String) -> NewSubscribersPrebuiltSegmentField {
+        match s.as_str() {
+            "timestamp_opt" => NewSubscribersPrebuiltSegmentField::TimestampOpt,
+            "" => NewSubscribersPrebuiltSegmentField::Noop,
+            _ => NewSubscribersPrebuiltSegmentField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NewSubscribersPrebuiltSegmentField> for String {
+    fn from(e: NewSubscribersPrebuiltSegmentField) -> String {
+        match e {
+            NewSubscribersPrebuiltSegmentField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentField {
     fn default() -> NewSubscribersPrebuiltSegmentField {
         NewSubscribersPrebuiltSegmentField::Noop
@@ -6173,13 +8612,15 @@ impl NewSubscribersPrebuiltSegmentField {
 * Whe the event took place, namely within a time frame.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NewSubscribersPrebuiltSegmentOperator {
     #[serde(rename = "date_within")]
     DateWithin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
@@ -6187,12 +8628,31 @@ impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
         match self {
             NewSubscribersPrebuiltSegmentOperator::DateWithin => "date_within",
             NewSubscribersPrebuiltSegmentOperator::Noop => "",
-            NewSubscribersPrebuiltSegmentOperator::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentOperator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NewSubscribersPrebuiltSegmentOperator {
+    fn from(s: String) -> NewSubscribersPrebuiltSegmentOperator {
+        match s.as_str() {
+            "date_within" => NewSubscribersPrebuiltSegmentOperator::DateWithin,
+            "" => NewSubscribersPrebuiltSegmentOperator::Noop,
+            _ => NewSubscribersPrebuiltSegmentOperator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NewSubscribersPrebuiltSegmentOperator> for String {
+    fn from(e: NewSubscribersPrebuiltSegmentOperator) -> String {
+        match e {
+            NewSubscribersPrebuiltSegmentOperator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NewSubscribersPrebuiltSegmentOperator {
     fn default() -> NewSubscribersPrebuiltSegmentOperator {
         NewSubscribersPrebuiltSegmentOperator::Noop
@@ -6997,6 +9457,7 @@ pub struct Tracking {
 * The type of Automation workflow.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WorkflowType {
     #[serde(rename = "abandonedBrowse")]
     AbandonedBrowse,
@@ -7034,8 +9495,9 @@ pub enum WorkflowType {
     WelcomeSeries,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WorkflowType {
@@ -7059,12 +9521,47 @@ impl std::fmt::Display for WorkflowType {
             WorkflowType::VisitUrl => "visitUrl",
             WorkflowType::WelcomeSeries => "welcomeSeries",
             WorkflowType::Noop => "",
-            WorkflowType::FallthroughString => "*",
+            WorkflowType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WorkflowType {
+    fn from(s: String) -> WorkflowType {
+        match s.as_str() {
+            "abandonedBrowse" => WorkflowType::AbandonedBrowse,
+            "abandonedCart" => WorkflowType::AbandonedCart,
+            "api" => WorkflowType::Api,
+            "bestCustomers" => WorkflowType::BestCustomers,
+            "categoryFollowup" => WorkflowType::CategoryFollowup,
+            "dateAdded" => WorkflowType::DateAdded,
+            "emailFollowup" => WorkflowType::EmailFollowup,
+            "emailSeries" => WorkflowType::EmailSeries,
+            "groupAdd" => WorkflowType::GroupAdd,
+            "groupRemove" => WorkflowType::GroupRemove,
+            "mandrill" => WorkflowType::Mandrill,
+            "productFollowup" => WorkflowType::ProductFollowup,
+            "purchaseFollowup" => WorkflowType::PurchaseFollowup,
+            "recurringEvent" => WorkflowType::RecurringEvent,
+            "specialEvent" => WorkflowType::SpecialEvent,
+            "visitUrl" => WorkflowType::VisitUrl,
+            "welcomeSeries" => WorkflowType::WelcomeSeries,
+            "" => WorkflowType::Noop,
+            _ => WorkflowType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WorkflowType> for String {
+    fn from(e: WorkflowType) -> String {
+        match e {
+            WorkflowType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WorkflowType {
     fn default() -> WorkflowType {
         WorkflowType::Noop
@@ -7077,6 +9574,7 @@ impl WorkflowType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Days {
     #[serde(rename = "friday")]
     Friday,
@@ -7094,8 +9592,9 @@ pub enum Days {
     Wednesday,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Days {
@@ -7109,12 +9608,37 @@ impl std::fmt::Display for Days {
             Days::Tuesday => "tuesday",
             Days::Wednesday => "wednesday",
             Days::Noop => "",
-            Days::FallthroughString => "*",
+            Days::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Days {
+    fn from(s: String) -> Days {
+        match s.as_str() {
+            "friday" => Days::Friday,
+            "monday" => Days::Monday,
+            "saturday" => Days::Saturday,
+            "sunday" => Days::Sunday,
+            "thursday" => Days::Thursday,
+            "tuesday" => Days::Tuesday,
+            "wednesday" => Days::Wednesday,
+            "" => Days::Noop,
+            _ => Days::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Days> for String {
+    fn from(e: Days) -> String {
+        match e {
+            Days::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Days {
     fn default() -> Days {
         Days::Noop
@@ -7130,6 +9654,7 @@ impl Days {
 * When to send the Automation email.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSendTimeSettings {
     #[serde(rename = "send_asap")]
     SendAsap,
@@ -7139,8 +9664,9 @@ pub enum EmailSendTimeSettings {
     SendBetween,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSendTimeSettings {
@@ -7150,12 +9676,33 @@ impl std::fmt::Display for EmailSendTimeSettings {
             EmailSendTimeSettings::SendAt => "send_at",
             EmailSendTimeSettings::SendBetween => "send_between",
             EmailSendTimeSettings::Noop => "",
-            EmailSendTimeSettings::FallthroughString => "*",
+            EmailSendTimeSettings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailSendTimeSettings {
+    fn from(s: String) -> EmailSendTimeSettings {
+        match s.as_str() {
+            "send_asap" => EmailSendTimeSettings::SendAsap,
+            "send_at" => EmailSendTimeSettings::SendAt,
+            "send_between" => EmailSendTimeSettings::SendBetween,
+            "" => EmailSendTimeSettings::Noop,
+            _ => EmailSendTimeSettings::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailSendTimeSettings> for String {
+    fn from(e: EmailSendTimeSettings) -> String {
+        match e {
+            EmailSendTimeSettings::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailSendTimeSettings {
     fn default() -> EmailSendTimeSettings {
         EmailSendTimeSettings::Noop
@@ -7490,6 +10037,7 @@ pub struct AutomationWorkflow {
 * The type of delay for an Automation email.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelayType {
     #[serde(rename = "day")]
     Day,
@@ -7501,8 +10049,9 @@ pub enum DelayType {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayType {
@@ -7513,12 +10062,34 @@ impl std::fmt::Display for DelayType {
             DelayType::Now => "now",
             DelayType::Week => "week",
             DelayType::Noop => "",
-            DelayType::FallthroughString => "*",
+            DelayType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DelayType {
+    fn from(s: String) -> DelayType {
+        match s.as_str() {
+            "day" => DelayType::Day,
+            "hour" => DelayType::Hour,
+            "now" => DelayType::Now,
+            "week" => DelayType::Week,
+            "" => DelayType::Noop,
+            _ => DelayType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DelayType> for String {
+    fn from(e: DelayType) -> String {
+        match e {
+            DelayType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DelayType {
     fn default() -> DelayType {
         DelayType::Noop
@@ -7534,6 +10105,7 @@ impl DelayType {
 * Whether the delay settings describe before or after the delay action of an Automation email.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Direction {
     #[serde(rename = "after")]
     After,
@@ -7541,8 +10113,9 @@ pub enum Direction {
     Before,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -7551,12 +10124,32 @@ impl std::fmt::Display for Direction {
             Direction::After => "after",
             Direction::Before => "before",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Direction {
+    fn from(s: String) -> Direction {
+        match s.as_str() {
+            "after" => Direction::After,
+            "before" => Direction::Before,
+            "" => Direction::Noop,
+            _ => Direction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Direction> for String {
+    fn from(e: Direction) -> String {
+        match e {
+            Direction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Direction {
     fn default() -> Direction {
         Direction::Noop
@@ -7572,6 +10165,7 @@ impl Direction {
 * The action that triggers the delay of an Automation email.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Action {
     #[serde(rename = "annual")]
     Annual,
@@ -7641,8 +10235,9 @@ pub enum Action {
     TagAdd,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -7682,12 +10277,63 @@ impl std::fmt::Display for Action {
             Action::Signup => "signup",
             Action::TagAdd => "tag_add",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Action {
+    fn from(s: String) -> Action {
+        match s.as_str() {
+            "annual" => Action::Annual,
+            "api" => Action::Api,
+            "birthday" => Action::Birthday,
+            "campaign_sent" => Action::CampaignSent,
+            "campaign_specific_clicked" => Action::CampaignSpecificClicked,
+            "clicked_email" => Action::ClickedEmail,
+            "date" => Action::Date,
+            "date_added" => Action::DateAdded,
+            "ecomm_abandoned_cart" => Action::EcommAbandonedCart,
+            "ecomm_bought_any" => Action::EcommBoughtAny,
+            "ecomm_bought_category" => Action::EcommBoughtCategory,
+            "ecomm_bought_product" => Action::EcommBoughtProduct,
+            "ecomm_not_bought_any" => Action::EcommNotBoughtAny,
+            "goal" => Action::Goal,
+            "group_add" => Action::GroupAdd,
+            "group_remove" => Action::GroupRemove,
+            "mandrill_any" => Action::MandrillAny,
+            "mandrill_clicked" => Action::MandrillClicked,
+            "mandrill_opened" => Action::MandrillOpened,
+            "mandrill_sent" => Action::MandrillSent,
+            "manual" => Action::Manual,
+            "merge_changed" => Action::MergeChanged,
+            "not_clicked_email" => Action::NotClickedEmail,
+            "not_opened_email" => Action::NotOpenedEmail,
+            "opened_email" => Action::OpenedEmail,
+            "previous_campaign_clicked_any" => Action::PreviousCampaignClickedAny,
+            "previous_campaign_not_clicked_any" => Action::PreviousCampaignNotClickedAny,
+            "previous_campaign_not_opened" => Action::PreviousCampaignNotOpened,
+            "previous_campaign_opened" => Action::PreviousCampaignOpened,
+            "previous_campaign_sent" => Action::PreviousCampaignSent,
+            "previous_campaign_specific_clicked" => Action::PreviousCampaignSpecificClicked,
+            "signup" => Action::Signup,
+            "tag_add" => Action::TagAdd,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Action> for String {
+    fn from(e: Action) -> String {
+        match e {
+            Action::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -8366,13 +11012,15 @@ pub struct UpdateInformationAboutASpecificWorkflowEmailCampaignSettings {
 * Whether the delay settings describe before or after the delay action of an automation email.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelayDirection {
     #[serde(rename = "after")]
     After,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayDirection {
@@ -8380,12 +11028,31 @@ impl std::fmt::Display for DelayDirection {
         match self {
             DelayDirection::After => "after",
             DelayDirection::Noop => "",
-            DelayDirection::FallthroughString => "*",
+            DelayDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DelayDirection {
+    fn from(s: String) -> DelayDirection {
+        match s.as_str() {
+            "after" => DelayDirection::After,
+            "" => DelayDirection::Noop,
+            _ => DelayDirection::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DelayDirection> for String {
+    fn from(e: DelayDirection) -> String {
+        match e {
+            DelayDirection::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DelayDirection {
     fn default() -> DelayDirection {
         DelayDirection::Noop
@@ -8401,6 +11068,7 @@ impl DelayDirection {
 * The action that triggers the delay of an automation emails.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelayAction {
     #[serde(rename = "ecomm_abandoned_browse")]
     EcommAbandonedBrowse,
@@ -8410,8 +11078,9 @@ pub enum DelayAction {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DelayAction {
@@ -8421,12 +11090,33 @@ impl std::fmt::Display for DelayAction {
             DelayAction::EcommAbandonedCart => "ecomm_abandoned_cart",
             DelayAction::Signup => "signup",
             DelayAction::Noop => "",
-            DelayAction::FallthroughString => "*",
+            DelayAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DelayAction {
+    fn from(s: String) -> DelayAction {
+        match s.as_str() {
+            "ecomm_abandoned_browse" => DelayAction::EcommAbandonedBrowse,
+            "ecomm_abandoned_cart" => DelayAction::EcommAbandonedCart,
+            "signup" => DelayAction::Signup,
+            "" => DelayAction::Noop,
+            _ => DelayAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DelayAction> for String {
+    fn from(e: DelayAction) -> String {
+        match e {
+            DelayAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DelayAction {
     fn default() -> DelayAction {
         DelayAction::Noop
@@ -8776,6 +11466,7 @@ pub struct RemovedSubscribers {
 * The status of the batch call. [Learn more](https://mailchimp.com/developer/marketing/guides/run-async-requests-batch-endpoint/#check-the-status-of-a-batch-operation) about the batch operation status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BatchOperationsStatus {
     #[serde(rename = "finalizing")]
     Finalizing,
@@ -8789,8 +11480,9 @@ pub enum BatchOperationsStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BatchOperationsStatus {
@@ -8802,12 +11494,35 @@ impl std::fmt::Display for BatchOperationsStatus {
             BatchOperationsStatus::Preprocessing => "preprocessing",
             BatchOperationsStatus::Started => "started",
             BatchOperationsStatus::Noop => "",
-            BatchOperationsStatus::FallthroughString => "*",
+            BatchOperationsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BatchOperationsStatus {
+    fn from(s: String) -> BatchOperationsStatus {
+        match s.as_str() {
+            "finalizing" => BatchOperationsStatus::Finalizing,
+            "finished" => BatchOperationsStatus::Finished,
+            "pending" => BatchOperationsStatus::Pending,
+            "preprocessing" => BatchOperationsStatus::Preprocessing,
+            "started" => BatchOperationsStatus::Started,
+            "" => BatchOperationsStatus::Noop,
+            _ => BatchOperationsStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BatchOperationsStatus> for String {
+    fn from(e: BatchOperationsStatus) -> String {
+        match e {
+            BatchOperationsStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BatchOperationsStatus {
     fn default() -> BatchOperationsStatus {
         BatchOperationsStatus::Noop
@@ -8939,6 +11654,7 @@ pub struct BatchOperations {
 * The HTTP method to use for the operation.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HttpMethod {
     #[serde(rename = "DELETE")]
     Delete,
@@ -8952,8 +11668,9 @@ pub enum HttpMethod {
     Put,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HttpMethod {
@@ -8965,12 +11682,35 @@ impl std::fmt::Display for HttpMethod {
             HttpMethod::Post => "POST",
             HttpMethod::Put => "PUT",
             HttpMethod::Noop => "",
-            HttpMethod::FallthroughString => "*",
+            HttpMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for HttpMethod {
+    fn from(s: String) -> HttpMethod {
+        match s.as_str() {
+            "DELETE" => HttpMethod::Delete,
+            "GET" => HttpMethod::Get,
+            "PATCH" => HttpMethod::Patch,
+            "POST" => HttpMethod::Post,
+            "PUT" => HttpMethod::Put,
+            "" => HttpMethod::Noop,
+            _ => HttpMethod::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<HttpMethod> for String {
+    fn from(e: HttpMethod) -> String {
+        match e {
+            HttpMethod::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for HttpMethod {
     fn default() -> HttpMethod {
         HttpMethod::Noop
@@ -9265,6 +12005,7 @@ pub struct CampaignFolders {
 * There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CampaignType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -9278,8 +12019,9 @@ pub enum CampaignType {
     Variate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignType {
@@ -9291,12 +12033,35 @@ impl std::fmt::Display for CampaignType {
             CampaignType::Rss => "rss",
             CampaignType::Variate => "variate",
             CampaignType::Noop => "",
-            CampaignType::FallthroughString => "*",
+            CampaignType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CampaignType {
+    fn from(s: String) -> CampaignType {
+        match s.as_str() {
+            "absplit" => CampaignType::Absplit,
+            "plaintext" => CampaignType::Plaintext,
+            "regular" => CampaignType::Regular,
+            "rss" => CampaignType::Rss,
+            "variate" => CampaignType::Variate,
+            "" => CampaignType::Noop,
+            _ => CampaignType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CampaignType> for String {
+    fn from(e: CampaignType) -> String {
+        match e {
+            CampaignType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CampaignType {
     fn default() -> CampaignType {
         CampaignType::Noop
@@ -9312,6 +12077,7 @@ impl CampaignType {
 * The status of the campaign.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetCampaignsStatus {
     #[serde(rename = "paused")]
     Paused,
@@ -9325,8 +12091,9 @@ pub enum GetCampaignsStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCampaignsStatus {
@@ -9338,12 +12105,35 @@ impl std::fmt::Display for GetCampaignsStatus {
             GetCampaignsStatus::Sending => "sending",
             GetCampaignsStatus::Sent => "sent",
             GetCampaignsStatus::Noop => "",
-            GetCampaignsStatus::FallthroughString => "*",
+            GetCampaignsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetCampaignsStatus {
+    fn from(s: String) -> GetCampaignsStatus {
+        match s.as_str() {
+            "paused" => GetCampaignsStatus::Paused,
+            "save" => GetCampaignsStatus::Save,
+            "schedule" => GetCampaignsStatus::Schedule,
+            "sending" => GetCampaignsStatus::Sending,
+            "sent" => GetCampaignsStatus::Sent,
+            "" => GetCampaignsStatus::Noop,
+            _ => GetCampaignsStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetCampaignsStatus> for String {
+    fn from(e: GetCampaignsStatus) -> String {
+        match e {
+            GetCampaignsStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetCampaignsStatus {
     fn default() -> GetCampaignsStatus {
         GetCampaignsStatus::Noop
@@ -9359,6 +12149,7 @@ impl GetCampaignsStatus {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortField {
     #[serde(rename = "create_time")]
     CreateTime,
@@ -9366,8 +12157,9 @@ pub enum SortField {
     SendTime,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortField {
@@ -9376,12 +12168,32 @@ impl std::fmt::Display for SortField {
             SortField::CreateTime => "create_time",
             SortField::SendTime => "send_time",
             SortField::Noop => "",
-            SortField::FallthroughString => "*",
+            SortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SortField {
+    fn from(s: String) -> SortField {
+        match s.as_str() {
+            "create_time" => SortField::CreateTime,
+            "send_time" => SortField::SendTime,
+            "" => SortField::Noop,
+            _ => SortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SortField> for String {
+    fn from(e: SortField) -> String {
+        match e {
+            SortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SortField {
     fn default() -> SortField {
         SortField::Noop
@@ -9397,6 +12209,7 @@ impl SortField {
 * Determines the order direction for sorted results.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortDir {
     #[serde(rename = "ASC")]
     Asc,
@@ -9404,8 +12217,9 @@ pub enum SortDir {
     Desc,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortDir {
@@ -9414,12 +12228,32 @@ impl std::fmt::Display for SortDir {
             SortDir::Asc => "ASC",
             SortDir::Desc => "DESC",
             SortDir::Noop => "",
-            SortDir::FallthroughString => "*",
+            SortDir::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SortDir {
+    fn from(s: String) -> SortDir {
+        match s.as_str() {
+            "ASC" => SortDir::Asc,
+            "DESC" => SortDir::Desc,
+            "" => SortDir::Noop,
+            _ => SortDir::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SortDir> for String {
+    fn from(e: SortDir) -> String {
+        match e {
+            SortDir::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SortDir {
     fn default() -> SortDir {
         SortDir::Noop
@@ -9435,6 +12269,7 @@ impl SortDir {
 * The current status of the campaign.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CampaignStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -9454,8 +12289,9 @@ pub enum CampaignStatus {
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignStatus {
@@ -9470,12 +12306,38 @@ impl std::fmt::Display for CampaignStatus {
             CampaignStatus::Sending => "sending",
             CampaignStatus::Sent => "sent",
             CampaignStatus::Noop => "",
-            CampaignStatus::FallthroughString => "*",
+            CampaignStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CampaignStatus {
+    fn from(s: String) -> CampaignStatus {
+        match s.as_str() {
+            "archived" => CampaignStatus::Archived,
+            "canceled" => CampaignStatus::Canceled,
+            "canceling" => CampaignStatus::Canceling,
+            "paused" => CampaignStatus::Paused,
+            "save" => CampaignStatus::Save,
+            "schedule" => CampaignStatus::Schedule,
+            "sending" => CampaignStatus::Sending,
+            "sent" => CampaignStatus::Sent,
+            "" => CampaignStatus::Noop,
+            _ => CampaignStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CampaignStatus> for String {
+    fn from(e: CampaignStatus) -> String {
+        match e {
+            CampaignStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CampaignStatus {
     fn default() -> CampaignStatus {
         CampaignStatus::Noop
@@ -9491,6 +12353,7 @@ impl CampaignStatus {
 * How the campaign's content is put together.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ContentType {
     #[serde(rename = "html")]
     Html,
@@ -9502,8 +12365,9 @@ pub enum ContentType {
     Url,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContentType {
@@ -9514,12 +12378,34 @@ impl std::fmt::Display for ContentType {
             ContentType::Template => "template",
             ContentType::Url => "url",
             ContentType::Noop => "",
-            ContentType::FallthroughString => "*",
+            ContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ContentType {
+    fn from(s: String) -> ContentType {
+        match s.as_str() {
+            "html" => ContentType::Html,
+            "multichannel" => ContentType::Multichannel,
+            "template" => ContentType::Template,
+            "url" => ContentType::Url,
+            "" => ContentType::Noop,
+            _ => ContentType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ContentType> for String {
+    fn from(e: ContentType) -> String {
+        match e {
+            ContentType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ContentType {
     fn default() -> ContentType {
         ContentType::Noop
@@ -9685,6 +12571,7 @@ pub struct GetCampaignsResponseCampaignSettings {
 * The combination that performs the best. This may be determined automatically by click rate, open rate, or total revenue -- or you may choose manually based on the reporting data you find the most valuable. For Multivariate Campaigns testing send_time, winner_criteria is ignored. For Multivariate Campaigns with 'manual' as the winner_criteria, the winner must be chosen in the Mailchimp web application.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WinnerCriteria {
     #[serde(rename = "clicks")]
     Clicks,
@@ -9696,8 +12583,9 @@ pub enum WinnerCriteria {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -9708,12 +12596,34 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Opens => "opens",
             WinnerCriteria::TotalRevenue => "total_revenue",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WinnerCriteria {
+    fn from(s: String) -> WinnerCriteria {
+        match s.as_str() {
+            "clicks" => WinnerCriteria::Clicks,
+            "manual" => WinnerCriteria::Manual,
+            "opens" => WinnerCriteria::Opens,
+            "total_revenue" => WinnerCriteria::TotalRevenue,
+            "" => WinnerCriteria::Noop,
+            _ => WinnerCriteria::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WinnerCriteria> for String {
+    fn from(e: WinnerCriteria) -> String {
+        match e {
+            WinnerCriteria::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WinnerCriteria {
     fn default() -> WinnerCriteria {
         WinnerCriteria::Noop
@@ -9896,6 +12806,7 @@ pub struct ABTestOptions {
 * The frequency of the RSS Campaign.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Frequency {
     #[serde(rename = "daily")]
     Daily,
@@ -9905,8 +12816,9 @@ pub enum Frequency {
     Weekly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Frequency {
@@ -9916,12 +12828,33 @@ impl std::fmt::Display for Frequency {
             Frequency::Monthly => "monthly",
             Frequency::Weekly => "weekly",
             Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Frequency {
+    fn from(s: String) -> Frequency {
+        match s.as_str() {
+            "daily" => Frequency::Daily,
+            "monthly" => Frequency::Monthly,
+            "weekly" => Frequency::Weekly,
+            "" => Frequency::Noop,
+            _ => Frequency::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Frequency> for String {
+    fn from(e: Frequency) -> String {
+        match e {
+            Frequency::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Frequency {
     fn default() -> Frequency {
         Frequency::Noop
@@ -10072,6 +13005,7 @@ pub struct RssOpts {
 * The type of AB split to run.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SplitTest {
     #[serde(rename = "from_name")]
     FromName,
@@ -10081,8 +13015,9 @@ pub enum SplitTest {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SplitTest {
@@ -10092,12 +13027,33 @@ impl std::fmt::Display for SplitTest {
             SplitTest::Schedule => "schedule",
             SplitTest::Subject => "subject",
             SplitTest::Noop => "",
-            SplitTest::FallthroughString => "*",
+            SplitTest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SplitTest {
+    fn from(s: String) -> SplitTest {
+        match s.as_str() {
+            "from_name" => SplitTest::FromName,
+            "schedule" => SplitTest::Schedule,
+            "subject" => SplitTest::Subject,
+            "" => SplitTest::Noop,
+            _ => SplitTest::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SplitTest> for String {
+    fn from(e: SplitTest) -> String {
+        match e {
+            SplitTest::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SplitTest {
     fn default() -> SplitTest {
         SplitTest::Noop
@@ -10113,6 +13069,7 @@ impl SplitTest {
 * How we should evaluate a winner. Based on 'opens', 'clicks', or 'manual'.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PickWinner {
     #[serde(rename = "clicks")]
     Clicks,
@@ -10122,8 +13079,9 @@ pub enum PickWinner {
     Opens,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PickWinner {
@@ -10133,12 +13091,33 @@ impl std::fmt::Display for PickWinner {
             PickWinner::Manual => "manual",
             PickWinner::Opens => "opens",
             PickWinner::Noop => "",
-            PickWinner::FallthroughString => "*",
+            PickWinner::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PickWinner {
+    fn from(s: String) -> PickWinner {
+        match s.as_str() {
+            "clicks" => PickWinner::Clicks,
+            "manual" => PickWinner::Manual,
+            "opens" => PickWinner::Opens,
+            "" => PickWinner::Noop,
+            _ => PickWinner::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PickWinner> for String {
+    fn from(e: PickWinner) -> String {
+        match e {
+            PickWinner::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PickWinner {
     fn default() -> PickWinner {
         PickWinner::Noop
@@ -10154,6 +13133,7 @@ impl PickWinner {
 * How unit of time for measuring the winner ('hours' or 'days'). This cannot be changed after a campaign is sent.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WaitTime {
     #[serde(rename = "days")]
     Days,
@@ -10161,8 +13141,9 @@ pub enum WaitTime {
     Hours,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WaitTime {
@@ -10171,12 +13152,32 @@ impl std::fmt::Display for WaitTime {
             WaitTime::Days => "days",
             WaitTime::Hours => "hours",
             WaitTime::Noop => "",
-            WaitTime::FallthroughString => "*",
+            WaitTime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WaitTime {
+    fn from(s: String) -> WaitTime {
+        match s.as_str() {
+            "days" => WaitTime::Days,
+            "hours" => WaitTime::Hours,
+            "" => WaitTime::Noop,
+            _ => WaitTime::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WaitTime> for String {
+    fn from(e: WaitTime) -> String {
+        match e {
+            WaitTime::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WaitTime {
     fn default() -> WaitTime {
         WaitTime::Noop
@@ -10407,6 +13408,7 @@ pub struct GetCampaignsResponseCampaignReportSummary {
 * The current state of a campaign delivery.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CampaignDeliveryStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -10418,8 +13420,9 @@ pub enum CampaignDeliveryStatus {
     Delivering,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CampaignDeliveryStatus {
@@ -10430,12 +13433,34 @@ impl std::fmt::Display for CampaignDeliveryStatus {
             CampaignDeliveryStatus::Delivered => "delivered",
             CampaignDeliveryStatus::Delivering => "delivering",
             CampaignDeliveryStatus::Noop => "",
-            CampaignDeliveryStatus::FallthroughString => "*",
+            CampaignDeliveryStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CampaignDeliveryStatus {
+    fn from(s: String) -> CampaignDeliveryStatus {
+        match s.as_str() {
+            "canceled" => CampaignDeliveryStatus::Canceled,
+            "canceling" => CampaignDeliveryStatus::Canceling,
+            "delivered" => CampaignDeliveryStatus::Delivered,
+            "delivering" => CampaignDeliveryStatus::Delivering,
+            "" => CampaignDeliveryStatus::Noop,
+            _ => CampaignDeliveryStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CampaignDeliveryStatus> for String {
+    fn from(e: CampaignDeliveryStatus) -> String {
+        match e {
+            CampaignDeliveryStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CampaignDeliveryStatus {
     fn default() -> CampaignDeliveryStatus {
         CampaignDeliveryStatus::Noop
@@ -10968,6 +13993,7 @@ pub struct RssOptions {
 * How the campaign's content is put together. The old drag and drop editor uses 'template' while the new editor uses 'multichannel'. Defaults to template.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CreatedCampaignContentType {
     #[serde(rename = "multichannel")]
     Multichannel,
@@ -10975,8 +14001,9 @@ pub enum CreatedCampaignContentType {
     Template,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CreatedCampaignContentType {
@@ -10985,12 +14012,32 @@ impl std::fmt::Display for CreatedCampaignContentType {
             CreatedCampaignContentType::Multichannel => "multichannel",
             CreatedCampaignContentType::Template => "template",
             CreatedCampaignContentType::Noop => "",
-            CreatedCampaignContentType::FallthroughString => "*",
+            CreatedCampaignContentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CreatedCampaignContentType {
+    fn from(s: String) -> CreatedCampaignContentType {
+        match s.as_str() {
+            "multichannel" => CreatedCampaignContentType::Multichannel,
+            "template" => CreatedCampaignContentType::Template,
+            "" => CreatedCampaignContentType::Noop,
+            _ => CreatedCampaignContentType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CreatedCampaignContentType> for String {
+    fn from(e: CreatedCampaignContentType) -> String {
+        match e {
+            CreatedCampaignContentType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CreatedCampaignContentType {
     fn default() -> CreatedCampaignContentType {
         CreatedCampaignContentType::Noop
@@ -11743,6 +14790,7 @@ pub struct PostCampaignsActionsScheduleRequest {
 * Choose the type of test email to send.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SendType {
     #[serde(rename = "html")]
     Html,
@@ -11750,8 +14798,9 @@ pub enum SendType {
     Plaintext,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendType {
@@ -11760,12 +14809,32 @@ impl std::fmt::Display for SendType {
             SendType::Html => "html",
             SendType::Plaintext => "plaintext",
             SendType::Noop => "",
-            SendType::FallthroughString => "*",
+            SendType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SendType {
+    fn from(s: String) -> SendType {
+        match s.as_str() {
+            "html" => SendType::Html,
+            "plaintext" => SendType::Plaintext,
+            "" => SendType::Noop,
+            _ => SendType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SendType> for String {
+    fn from(e: SendType) -> String {
+        match e {
+            SendType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SendType {
     fn default() -> SendType {
         SendType::Noop
@@ -11900,6 +14969,7 @@ pub struct Template {
 * The type of encoded file. Defaults to zip.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ArchiveType {
     #[serde(rename = "tar")]
     Tar,
@@ -11915,8 +14985,9 @@ pub enum ArchiveType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ArchiveType {
@@ -11929,12 +15000,36 @@ impl std::fmt::Display for ArchiveType {
             ArchiveType::Tgz => "tgz",
             ArchiveType::Zip => "zip",
             ArchiveType::Noop => "",
-            ArchiveType::FallthroughString => "*",
+            ArchiveType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ArchiveType {
+    fn from(s: String) -> ArchiveType {
+        match s.as_str() {
+            "tar" => ArchiveType::Tar,
+            "tar.bz2" => ArchiveType::TarBz2,
+            "tar.gz" => ArchiveType::TarGz,
+            "tbz" => ArchiveType::Tbz,
+            "tgz" => ArchiveType::Tgz,
+            "zip" => ArchiveType::Zip,
+            "" => ArchiveType::Noop,
+            _ => ArchiveType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ArchiveType> for String {
+    fn from(e: ArchiveType) -> String {
+        match e {
+            ArchiveType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ArchiveType {
     fn default() -> ArchiveType {
         ArchiveType::Noop
@@ -12089,6 +15184,7 @@ pub struct CampaignContentData {
 * The source of the feedback.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Source {
     #[serde(rename = "android")]
     Android,
@@ -12104,8 +15200,9 @@ pub enum Source {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -12118,12 +15215,36 @@ impl std::fmt::Display for Source {
             Source::Sms => "sms",
             Source::Web => "web",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Source {
+    fn from(s: String) -> Source {
+        match s.as_str() {
+            "android" => Source::Android,
+            "api" => Source::Api,
+            "email" => Source::Email,
+            "ios" => Source::Ios,
+            "sms" => Source::Sms,
+            "web" => Source::Web,
+            "" => Source::Noop,
+            _ => Source::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Source> for String {
+    fn from(e: Source) -> String {
+        match e {
+            Source::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Source {
     fn default() -> Source {
         Source::Noop
@@ -12443,6 +15564,7 @@ pub struct CampaignFeedbackDataType {
 * The item type.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SendChecklistItemsType {
     #[serde(rename = "error")]
     Error,
@@ -12452,8 +15574,9 @@ pub enum SendChecklistItemsType {
     Warning,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendChecklistItemsType {
@@ -12463,12 +15586,33 @@ impl std::fmt::Display for SendChecklistItemsType {
             SendChecklistItemsType::Success => "success",
             SendChecklistItemsType::Warning => "warning",
             SendChecklistItemsType::Noop => "",
-            SendChecklistItemsType::FallthroughString => "*",
+            SendChecklistItemsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SendChecklistItemsType {
+    fn from(s: String) -> SendChecklistItemsType {
+        match s.as_str() {
+            "error" => SendChecklistItemsType::Error,
+            "success" => SendChecklistItemsType::Success,
+            "warning" => SendChecklistItemsType::Warning,
+            "" => SendChecklistItemsType::Noop,
+            _ => SendChecklistItemsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SendChecklistItemsType> for String {
+    fn from(e: SendChecklistItemsType) -> String {
+        match e {
+            SendChecklistItemsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SendChecklistItemsType {
     fn default() -> SendChecklistItemsType {
         SendChecklistItemsType::Noop
@@ -12705,6 +15849,7 @@ pub struct ConnectedSite {
 * Whether a conversation message has been marked as read.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IsRead {
     #[serde(rename = "false")]
     False,
@@ -12712,8 +15857,9 @@ pub enum IsRead {
     True,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IsRead {
@@ -12722,12 +15868,32 @@ impl std::fmt::Display for IsRead {
             IsRead::False => "false",
             IsRead::True => "true",
             IsRead::Noop => "",
-            IsRead::FallthroughString => "*",
+            IsRead::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IsRead {
+    fn from(s: String) -> IsRead {
+        match s.as_str() {
+            "false" => IsRead::False,
+            "true" => IsRead::True,
+            "" => IsRead::Noop,
+            _ => IsRead::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IsRead> for String {
+    fn from(e: IsRead) -> String {
+        match e {
+            IsRead::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IsRead {
     fn default() -> IsRead {
         IsRead::Noop
@@ -13063,13 +16229,15 @@ pub struct CollectionOfConversationMessages {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetFileManagerFilesSortField {
     #[serde(rename = "added_date")]
     AddedDate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetFileManagerFilesSortField {
@@ -13077,12 +16245,31 @@ impl std::fmt::Display for GetFileManagerFilesSortField {
         match self {
             GetFileManagerFilesSortField::AddedDate => "added_date",
             GetFileManagerFilesSortField::Noop => "",
-            GetFileManagerFilesSortField::FallthroughString => "*",
+            GetFileManagerFilesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetFileManagerFilesSortField {
+    fn from(s: String) -> GetFileManagerFilesSortField {
+        match s.as_str() {
+            "added_date" => GetFileManagerFilesSortField::AddedDate,
+            "" => GetFileManagerFilesSortField::Noop,
+            _ => GetFileManagerFilesSortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetFileManagerFilesSortField> for String {
+    fn from(e: GetFileManagerFilesSortField) -> String {
+        match e {
+            GetFileManagerFilesSortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetFileManagerFilesSortField {
     fn default() -> GetFileManagerFilesSortField {
         GetFileManagerFilesSortField::Noop
@@ -13098,6 +16285,7 @@ impl GetFileManagerFilesSortField {
 * The type of file in the File Manager.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FileType {
     #[serde(rename = "file")]
     File,
@@ -13105,8 +16293,9 @@ pub enum FileType {
     Image,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -13115,12 +16304,32 @@ impl std::fmt::Display for FileType {
             FileType::File => "file",
             FileType::Image => "image",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FileType {
+    fn from(s: String) -> FileType {
+        match s.as_str() {
+            "file" => FileType::File,
+            "image" => FileType::Image,
+            "" => FileType::Noop,
+            _ => FileType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FileType> for String {
+    fn from(e: FileType) -> String {
+        match e {
+            FileType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Noop
@@ -13436,13 +16645,15 @@ pub struct FileManagerFolders {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsSortField {
     #[serde(rename = "date_created")]
     DateCreated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsSortField {
@@ -13450,12 +16661,31 @@ impl std::fmt::Display for GetListsSortField {
         match self {
             GetListsSortField::DateCreated => "date_created",
             GetListsSortField::Noop => "",
-            GetListsSortField::FallthroughString => "*",
+            GetListsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetListsSortField {
+    fn from(s: String) -> GetListsSortField {
+        match s.as_str() {
+            "date_created" => GetListsSortField::DateCreated,
+            "" => GetListsSortField::Noop,
+            _ => GetListsSortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetListsSortField> for String {
+    fn from(e: GetListsSortField) -> String {
+        match e {
+            GetListsSortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetListsSortField {
     fn default() -> GetListsSortField {
         GetListsSortField::Noop
@@ -13591,6 +16821,7 @@ pub struct CampaignDefaults {
 * Legacy - visibility settings are no longer used
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Visibility {
     #[serde(rename = "prv")]
     Prv,
@@ -13598,8 +16829,9 @@ pub enum Visibility {
     Pub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Visibility {
@@ -13608,12 +16840,32 @@ impl std::fmt::Display for Visibility {
             Visibility::Prv => "prv",
             Visibility::Pub => "pub",
             Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Visibility {
+    fn from(s: String) -> Visibility {
+        match s.as_str() {
+            "prv" => Visibility::Prv,
+            "pub" => Visibility::Pub,
+            "" => Visibility::Noop,
+            _ => Visibility::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Visibility> for String {
+    fn from(e: Visibility) -> String {
+        match e {
+            Visibility::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Visibility {
     fn default() -> Visibility {
         Visibility::Noop
@@ -14242,6 +17494,7 @@ pub struct SubscriberList {
 * Subscriber's current status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14253,8 +17506,9 @@ pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
@@ -14265,12 +17519,34 @@ impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed => "subscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed => "unsubscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop => "",
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::FallthroughString => "*",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn from(s: String) -> MembersSubscribeUnsubscribeFromAListInBatchStatus {
+        match s.as_str() {
+            "cleaned" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Cleaned,
+            "pending" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Pending,
+            "subscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed,
+            "unsubscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed,
+            "" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop,
+            _ => MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MembersSubscribeUnsubscribeFromAListInBatchStatus> for String {
+    fn from(e: MembersSubscribeUnsubscribeFromAListInBatchStatus) -> String {
+        match e {
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MembersSubscribeUnsubscribeFromAListInBatchStatus {
     fn default() -> MembersSubscribeUnsubscribeFromAListInBatchStatus {
         MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop
@@ -14433,6 +17709,7 @@ pub struct MembersSubscribeUnsubscribeFromAListInBatch {
 * Subscriber's status. This value is required only if the email address is not already present on the list.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StatusIfNew {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14446,8 +17723,9 @@ pub enum StatusIfNew {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for StatusIfNew {
@@ -14459,12 +17737,35 @@ impl std::fmt::Display for StatusIfNew {
             StatusIfNew::Transactional => "transactional",
             StatusIfNew::Unsubscribed => "unsubscribed",
             StatusIfNew::Noop => "",
-            StatusIfNew::FallthroughString => "*",
+            StatusIfNew::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for StatusIfNew {
+    fn from(s: String) -> StatusIfNew {
+        match s.as_str() {
+            "cleaned" => StatusIfNew::Cleaned,
+            "pending" => StatusIfNew::Pending,
+            "subscribed" => StatusIfNew::Subscribed,
+            "transactional" => StatusIfNew::Transactional,
+            "unsubscribed" => StatusIfNew::Unsubscribed,
+            "" => StatusIfNew::Noop,
+            _ => StatusIfNew::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<StatusIfNew> for String {
+    fn from(e: StatusIfNew) -> String {
+        match e {
+            StatusIfNew::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for StatusIfNew {
     fn default() -> StatusIfNew {
         StatusIfNew::Noop
@@ -14816,6 +18117,7 @@ pub struct NewMembers {
 * A unique code that identifies this specifc error.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorCode {
     #[serde(rename = "ERROR_CONTACT_EXISTS")]
     ErrorContactExists,
@@ -14823,8 +18125,9 @@ pub enum ErrorCode {
     ErrorGeneric,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ErrorCode {
@@ -14833,12 +18136,32 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::ErrorContactExists => "ERROR_CONTACT_EXISTS",
             ErrorCode::ErrorGeneric => "ERROR_GENERIC",
             ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ErrorCode {
+    fn from(s: String) -> ErrorCode {
+        match s.as_str() {
+            "ERROR_CONTACT_EXISTS" => ErrorCode::ErrorContactExists,
+            "ERROR_GENERIC" => ErrorCode::ErrorGeneric,
+            "" => ErrorCode::Noop,
+            _ => ErrorCode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ErrorCode> for String {
+    fn from(e: ErrorCode) -> String {
+        match e {
+            ErrorCode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ErrorCode {
     fn default() -> ErrorCode {
         ErrorCode::Noop
@@ -15448,13 +18771,15 @@ pub struct EmailClients {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsGrowthHistorySortField {
     #[serde(rename = "month")]
     Month,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsGrowthHistorySortField {
@@ -15462,12 +18787,31 @@ impl std::fmt::Display for GetListsGrowthHistorySortField {
         match self {
             GetListsGrowthHistorySortField::Month => "month",
             GetListsGrowthHistorySortField::Noop => "",
-            GetListsGrowthHistorySortField::FallthroughString => "*",
+            GetListsGrowthHistorySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetListsGrowthHistorySortField {
+    fn from(s: String) -> GetListsGrowthHistorySortField {
+        match s.as_str() {
+            "month" => GetListsGrowthHistorySortField::Month,
+            "" => GetListsGrowthHistorySortField::Noop,
+            _ => GetListsGrowthHistorySortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetListsGrowthHistorySortField> for String {
+    fn from(e: GetListsGrowthHistorySortField) -> String {
+        match e {
+            GetListsGrowthHistorySortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetListsGrowthHistorySortField {
     fn default() -> GetListsGrowthHistorySortField {
         GetListsGrowthHistorySortField::Noop
@@ -15866,6 +19210,7 @@ pub struct InterestsData {
 * The type of segment. Static segments are now known as tags. Learn more about [tags](https://mailchimp.com/help/getting-started-tags?utm_source=mc-api&utm_medium=docs&utm_campaign=apidocs).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CollectionOfSegmentsType {
     #[serde(rename = "fuzzy")]
     Fuzzy,
@@ -15875,8 +19220,9 @@ pub enum CollectionOfSegmentsType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CollectionOfSegmentsType {
@@ -15886,12 +19232,33 @@ impl std::fmt::Display for CollectionOfSegmentsType {
             CollectionOfSegmentsType::Saved => "saved",
             CollectionOfSegmentsType::Static => "static",
             CollectionOfSegmentsType::Noop => "",
-            CollectionOfSegmentsType::FallthroughString => "*",
+            CollectionOfSegmentsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CollectionOfSegmentsType {
+    fn from(s: String) -> CollectionOfSegmentsType {
+        match s.as_str() {
+            "fuzzy" => CollectionOfSegmentsType::Fuzzy,
+            "saved" => CollectionOfSegmentsType::Saved,
+            "static" => CollectionOfSegmentsType::Static,
+            "" => CollectionOfSegmentsType::Noop,
+            _ => CollectionOfSegmentsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CollectionOfSegmentsType> for String {
+    fn from(e: CollectionOfSegmentsType) -> String {
+        match e {
+            CollectionOfSegmentsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CollectionOfSegmentsType {
     fn default() -> CollectionOfSegmentsType {
         CollectionOfSegmentsType::Noop
@@ -16484,6 +19851,7 @@ pub struct TagSearchResults {
 * The subscriber's status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsMembersStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -16499,8 +19867,9 @@ pub enum GetListsMembersStatus {
     Unsubscribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersStatus {
@@ -16513,12 +19882,36 @@ impl std::fmt::Display for GetListsMembersStatus {
             GetListsMembersStatus::Transactional => "transactional",
             GetListsMembersStatus::Unsubscribed => "unsubscribed",
             GetListsMembersStatus::Noop => "",
-            GetListsMembersStatus::FallthroughString => "*",
+            GetListsMembersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetListsMembersStatus {
+    fn from(s: String) -> GetListsMembersStatus {
+        match s.as_str() {
+            "archived" => GetListsMembersStatus::Archived,
+            "cleaned" => GetListsMembersStatus::Cleaned,
+            "pending" => GetListsMembersStatus::Pending,
+            "subscribed" => GetListsMembersStatus::Subscribed,
+            "transactional" => GetListsMembersStatus::Transactional,
+            "unsubscribed" => GetListsMembersStatus::Unsubscribed,
+            "" => GetListsMembersStatus::Noop,
+            _ => GetListsMembersStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetListsMembersStatus> for String {
+    fn from(e: GetListsMembersStatus) -> String {
+        match e {
+            GetListsMembersStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetListsMembersStatus {
     fn default() -> GetListsMembersStatus {
         GetListsMembersStatus::Noop
@@ -16534,6 +19927,7 @@ impl GetListsMembersStatus {
 * Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterestMatch {
     #[serde(rename = "all")]
     All,
@@ -16543,8 +19937,9 @@ pub enum InterestMatch {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InterestMatch {
@@ -16554,12 +19949,33 @@ impl std::fmt::Display for InterestMatch {
             InterestMatch::Any => "any",
             InterestMatch::None => "none",
             InterestMatch::Noop => "",
-            InterestMatch::FallthroughString => "*",
+            InterestMatch::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InterestMatch {
+    fn from(s: String) -> InterestMatch {
+        match s.as_str() {
+            "all" => InterestMatch::All,
+            "any" => InterestMatch::Any,
+            "none" => InterestMatch::None,
+            "" => InterestMatch::Noop,
+            _ => InterestMatch::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InterestMatch> for String {
+    fn from(e: InterestMatch) -> String {
+        match e {
+            InterestMatch::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InterestMatch {
     fn default() -> InterestMatch {
         InterestMatch::Noop
@@ -16575,6 +19991,7 @@ impl InterestMatch {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsMembersSortField {
     #[serde(rename = "last_changed")]
     LastChanged,
@@ -16584,8 +20001,9 @@ pub enum GetListsMembersSortField {
     TimestampSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersSortField {
@@ -16595,12 +20013,33 @@ impl std::fmt::Display for GetListsMembersSortField {
             GetListsMembersSortField::TimestampOpt => "timestamp_opt",
             GetListsMembersSortField::TimestampSignup => "timestamp_signup",
             GetListsMembersSortField::Noop => "",
-            GetListsMembersSortField::FallthroughString => "*",
+            GetListsMembersSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetListsMembersSortField {
+    fn from(s: String) -> GetListsMembersSortField {
+        match s.as_str() {
+            "last_changed" => GetListsMembersSortField::LastChanged,
+            "timestamp_opt" => GetListsMembersSortField::TimestampOpt,
+            "timestamp_signup" => GetListsMembersSortField::TimestampSignup,
+            "" => GetListsMembersSortField::Noop,
+            _ => GetListsMembersSortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetListsMembersSortField> for String {
+    fn from(e: GetListsMembersSortField) -> String {
+        match e {
+            GetListsMembersSortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetListsMembersSortField {
     fn default() -> GetListsMembersSortField {
         GetListsMembersSortField::Noop
@@ -17465,13 +20904,15 @@ pub struct MemberActivityEvents {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ActivityType {
     #[serde(rename = "open")]
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ActivityType {
@@ -17479,12 +20920,31 @@ impl std::fmt::Display for ActivityType {
         match self {
             ActivityType::Open => "open",
             ActivityType::Noop => "",
-            ActivityType::FallthroughString => "*",
+            ActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ActivityType {
+    fn from(s: String) -> ActivityType {
+        match s.as_str() {
+            "open" => ActivityType::Open,
+            "" => ActivityType::Noop,
+            _ => ActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ActivityType> for String {
+    fn from(e: ActivityType) -> String {
+        match e {
+            ActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ActivityType {
     fn default() -> ActivityType {
         ActivityType::Noop
@@ -17537,13 +20997,15 @@ pub struct EmailOpens {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClicksActivityType {
     #[serde(rename = "click")]
     Click,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailClicksActivityType {
@@ -17551,12 +21013,31 @@ impl std::fmt::Display for EmailClicksActivityType {
         match self {
             EmailClicksActivityType::Click => "click",
             EmailClicksActivityType::Noop => "",
-            EmailClicksActivityType::FallthroughString => "*",
+            EmailClicksActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailClicksActivityType {
+    fn from(s: String) -> EmailClicksActivityType {
+        match s.as_str() {
+            "click" => EmailClicksActivityType::Click,
+            "" => EmailClicksActivityType::Noop,
+            _ => EmailClicksActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailClicksActivityType> for String {
+    fn from(e: EmailClicksActivityType) -> String {
+        match e {
+            EmailClicksActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailClicksActivityType {
     fn default() -> EmailClicksActivityType {
         EmailClicksActivityType::Noop
@@ -17618,13 +21099,15 @@ pub struct EmailClicks {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailBouncedActivityType {
     #[serde(rename = "bounce")]
     Bounce,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailBouncedActivityType {
@@ -17632,12 +21115,31 @@ impl std::fmt::Display for EmailBouncedActivityType {
         match self {
             EmailBouncedActivityType::Bounce => "bounce",
             EmailBouncedActivityType::Noop => "",
-            EmailBouncedActivityType::FallthroughString => "*",
+            EmailBouncedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailBouncedActivityType {
+    fn from(s: String) -> EmailBouncedActivityType {
+        match s.as_str() {
+            "bounce" => EmailBouncedActivityType::Bounce,
+            "" => EmailBouncedActivityType::Noop,
+            _ => EmailBouncedActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailBouncedActivityType> for String {
+    fn from(e: EmailBouncedActivityType) -> String {
+        match e {
+            EmailBouncedActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailBouncedActivityType {
     fn default() -> EmailBouncedActivityType {
         EmailBouncedActivityType::Noop
@@ -17653,6 +21155,7 @@ impl EmailBouncedActivityType {
 * The type of bounce.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BounceType {
     #[serde(rename = "hard")]
     Hard,
@@ -17660,8 +21163,9 @@ pub enum BounceType {
     Soft,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -17670,12 +21174,32 @@ impl std::fmt::Display for BounceType {
             BounceType::Hard => "hard",
             BounceType::Soft => "soft",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BounceType {
+    fn from(s: String) -> BounceType {
+        match s.as_str() {
+            "hard" => BounceType::Hard,
+            "soft" => BounceType::Soft,
+            "" => BounceType::Noop,
+            _ => BounceType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BounceType> for String {
+    fn from(e: BounceType) -> String {
+        match e {
+            BounceType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BounceType {
     fn default() -> BounceType {
         BounceType::Noop
@@ -17741,13 +21265,15 @@ pub struct EmailBounced {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListUnsubscribedActivityType {
     #[serde(rename = "unsub")]
     Unsub,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListUnsubscribedActivityType {
@@ -17755,12 +21281,31 @@ impl std::fmt::Display for ListUnsubscribedActivityType {
         match self {
             ListUnsubscribedActivityType::Unsub => "unsub",
             ListUnsubscribedActivityType::Noop => "",
-            ListUnsubscribedActivityType::FallthroughString => "*",
+            ListUnsubscribedActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListUnsubscribedActivityType {
+    fn from(s: String) -> ListUnsubscribedActivityType {
+        match s.as_str() {
+            "unsub" => ListUnsubscribedActivityType::Unsub,
+            "" => ListUnsubscribedActivityType::Noop,
+            _ => ListUnsubscribedActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListUnsubscribedActivityType> for String {
+    fn from(e: ListUnsubscribedActivityType) -> String {
+        match e {
+            ListUnsubscribedActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListUnsubscribedActivityType {
     fn default() -> ListUnsubscribedActivityType {
         ListUnsubscribedActivityType::Noop
@@ -17830,13 +21375,15 @@ pub struct ListUnsubscribed {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSentActivityType {
     #[serde(rename = "sent")]
     Sent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailSentActivityType {
@@ -17844,12 +21391,31 @@ impl std::fmt::Display for EmailSentActivityType {
         match self {
             EmailSentActivityType::Sent => "sent",
             EmailSentActivityType::Noop => "",
-            EmailSentActivityType::FallthroughString => "*",
+            EmailSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailSentActivityType {
+    fn from(s: String) -> EmailSentActivityType {
+        match s.as_str() {
+            "sent" => EmailSentActivityType::Sent,
+            "" => EmailSentActivityType::Noop,
+            _ => EmailSentActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailSentActivityType> for String {
+    fn from(e: EmailSentActivityType) -> String {
+        match e {
+            EmailSentActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailSentActivityType {
     fn default() -> EmailSentActivityType {
         EmailSentActivityType::Noop
@@ -18000,13 +21566,15 @@ pub struct EmailConversation {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NoteActivityType {
     #[serde(rename = "note")]
     Note,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NoteActivityType {
@@ -18014,12 +21582,31 @@ impl std::fmt::Display for NoteActivityType {
         match self {
             NoteActivityType::Note => "note",
             NoteActivityType::Noop => "",
-            NoteActivityType::FallthroughString => "*",
+            NoteActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NoteActivityType {
+    fn from(s: String) -> NoteActivityType {
+        match s.as_str() {
+            "note" => NoteActivityType::Note,
+            "" => NoteActivityType::Noop,
+            _ => NoteActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NoteActivityType> for String {
+    fn from(e: NoteActivityType) -> String {
+        match e {
+            NoteActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NoteActivityType {
     fn default() -> NoteActivityType {
         NoteActivityType::Noop
@@ -18099,13 +21686,15 @@ pub struct Note {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MarketingPermissionActivityType {
     #[serde(rename = "marketing_permission")]
     MarketingPermission,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MarketingPermissionActivityType {
@@ -18113,12 +21702,31 @@ impl std::fmt::Display for MarketingPermissionActivityType {
         match self {
             MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
             MarketingPermissionActivityType::Noop => "",
-            MarketingPermissionActivityType::FallthroughString => "*",
+            MarketingPermissionActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MarketingPermissionActivityType {
+    fn from(s: String) -> MarketingPermissionActivityType {
+        match s.as_str() {
+            "marketing_permission" => MarketingPermissionActivityType::MarketingPermission,
+            "" => MarketingPermissionActivityType::Noop,
+            _ => MarketingPermissionActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MarketingPermissionActivityType> for String {
+    fn from(e: MarketingPermissionActivityType) -> String {
+        match e {
+            MarketingPermissionActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MarketingPermissionActivityType {
     fn default() -> MarketingPermissionActivityType {
         MarketingPermissionActivityType::Noop
@@ -18179,13 +21787,15 @@ pub struct MarketingPermissionData {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PostcardSentActivityType {
     #[serde(rename = "postcard_sent")]
     PostcardSent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostcardSentActivityType {
@@ -18193,12 +21803,31 @@ impl std::fmt::Display for PostcardSentActivityType {
         match self {
             PostcardSentActivityType::PostcardSent => "postcard_sent",
             PostcardSentActivityType::Noop => "",
-            PostcardSentActivityType::FallthroughString => "*",
+            PostcardSentActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PostcardSentActivityType {
+    fn from(s: String) -> PostcardSentActivityType {
+        match s.as_str() {
+            "postcard_sent" => PostcardSentActivityType::PostcardSent,
+            "" => PostcardSentActivityType::Noop,
+            _ => PostcardSentActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PostcardSentActivityType> for String {
+    fn from(e: PostcardSentActivityType) -> String {
+        match e {
+            PostcardSentActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PostcardSentActivityType {
     fn default() -> PostcardSentActivityType {
         PostcardSentActivityType::Noop
@@ -18251,13 +21880,15 @@ pub struct PostcardSent {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SquatterSignupActivityType {
     #[serde(rename = "squatter_signup")]
     SquatterSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SquatterSignupActivityType {
@@ -18265,12 +21896,31 @@ impl std::fmt::Display for SquatterSignupActivityType {
         match self {
             SquatterSignupActivityType::SquatterSignup => "squatter_signup",
             SquatterSignupActivityType::Noop => "",
-            SquatterSignupActivityType::FallthroughString => "*",
+            SquatterSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SquatterSignupActivityType {
+    fn from(s: String) -> SquatterSignupActivityType {
+        match s.as_str() {
+            "squatter_signup" => SquatterSignupActivityType::SquatterSignup,
+            "" => SquatterSignupActivityType::Noop,
+            _ => SquatterSignupActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SquatterSignupActivityType> for String {
+    fn from(e: SquatterSignupActivityType) -> String {
+        match e {
+            SquatterSignupActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SquatterSignupActivityType {
     fn default() -> SquatterSignupActivityType {
         SquatterSignupActivityType::Noop
@@ -18323,13 +21973,15 @@ pub struct SquatterSignup {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WebsiteSignupActivityType {
     #[serde(rename = "website_signup")]
     WebsiteSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebsiteSignupActivityType {
@@ -18337,12 +21989,31 @@ impl std::fmt::Display for WebsiteSignupActivityType {
         match self {
             WebsiteSignupActivityType::WebsiteSignup => "website_signup",
             WebsiteSignupActivityType::Noop => "",
-            WebsiteSignupActivityType::FallthroughString => "*",
+            WebsiteSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WebsiteSignupActivityType {
+    fn from(s: String) -> WebsiteSignupActivityType {
+        match s.as_str() {
+            "website_signup" => WebsiteSignupActivityType::WebsiteSignup,
+            "" => WebsiteSignupActivityType::Noop,
+            _ => WebsiteSignupActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WebsiteSignupActivityType> for String {
+    fn from(e: WebsiteSignupActivityType) -> String {
+        match e {
+            WebsiteSignupActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WebsiteSignupActivityType {
     fn default() -> WebsiteSignupActivityType {
         WebsiteSignupActivityType::Noop
@@ -18395,13 +22066,15 @@ pub struct WebsiteSignup {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LandingPageSignupActivityType {
     #[serde(rename = "landing_page_signup")]
     LandingPageSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageSignupActivityType {
@@ -18409,12 +22082,31 @@ impl std::fmt::Display for LandingPageSignupActivityType {
         match self {
             LandingPageSignupActivityType::LandingPageSignup => "landing_page_signup",
             LandingPageSignupActivityType::Noop => "",
-            LandingPageSignupActivityType::FallthroughString => "*",
+            LandingPageSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LandingPageSignupActivityType {
+    fn from(s: String) -> LandingPageSignupActivityType {
+        match s.as_str() {
+            "landing_page_signup" => LandingPageSignupActivityType::LandingPageSignup,
+            "" => LandingPageSignupActivityType::Noop,
+            _ => LandingPageSignupActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LandingPageSignupActivityType> for String {
+    fn from(e: LandingPageSignupActivityType) -> String {
+        match e {
+            LandingPageSignupActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LandingPageSignupActivityType {
     fn default() -> LandingPageSignupActivityType {
         LandingPageSignupActivityType::Noop
@@ -18467,13 +22159,15 @@ pub struct LandingPageSignup {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSignupActivityType {
     #[serde(rename = "ecommerce_signup")]
     EcommerceSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceSignupActivityType {
@@ -18481,12 +22175,31 @@ impl std::fmt::Display for EcommerceSignupActivityType {
         match self {
             EcommerceSignupActivityType::EcommerceSignup => "ecommerce_signup",
             EcommerceSignupActivityType::Noop => "",
-            EcommerceSignupActivityType::FallthroughString => "*",
+            EcommerceSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceSignupActivityType {
+    fn from(s: String) -> EcommerceSignupActivityType {
+        match s.as_str() {
+            "ecommerce_signup" => EcommerceSignupActivityType::EcommerceSignup,
+            "" => EcommerceSignupActivityType::Noop,
+            _ => EcommerceSignupActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceSignupActivityType> for String {
+    fn from(e: EcommerceSignupActivityType) -> String {
+        match e {
+            EcommerceSignupActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceSignupActivityType {
     fn default() -> EcommerceSignupActivityType {
         EcommerceSignupActivityType::Noop
@@ -18530,13 +22243,15 @@ pub struct EcommerceSignup {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GenericSignupActivityType {
     #[serde(rename = "generic_signup")]
     GenericSignup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GenericSignupActivityType {
@@ -18544,12 +22259,31 @@ impl std::fmt::Display for GenericSignupActivityType {
         match self {
             GenericSignupActivityType::GenericSignup => "generic_signup",
             GenericSignupActivityType::Noop => "",
-            GenericSignupActivityType::FallthroughString => "*",
+            GenericSignupActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GenericSignupActivityType {
+    fn from(s: String) -> GenericSignupActivityType {
+        match s.as_str() {
+            "generic_signup" => GenericSignupActivityType::GenericSignup,
+            "" => GenericSignupActivityType::Noop,
+            _ => GenericSignupActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GenericSignupActivityType> for String {
+    fn from(e: GenericSignupActivityType) -> String {
+        match e {
+            GenericSignupActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GenericSignupActivityType {
     fn default() -> GenericSignupActivityType {
         GenericSignupActivityType::Noop
@@ -18593,13 +22327,15 @@ pub struct GenericSignup {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceOrderActivityType {
     #[serde(rename = "order")]
     Order,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EcommerceOrderActivityType {
@@ -18607,12 +22343,31 @@ impl std::fmt::Display for EcommerceOrderActivityType {
         match self {
             EcommerceOrderActivityType::Order => "order",
             EcommerceOrderActivityType::Noop => "",
-            EcommerceOrderActivityType::FallthroughString => "*",
+            EcommerceOrderActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EcommerceOrderActivityType {
+    fn from(s: String) -> EcommerceOrderActivityType {
+        match s.as_str() {
+            "order" => EcommerceOrderActivityType::Order,
+            "" => EcommerceOrderActivityType::Noop,
+            _ => EcommerceOrderActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EcommerceOrderActivityType> for String {
+    fn from(e: EcommerceOrderActivityType) -> String {
+        match e {
+            EcommerceOrderActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EcommerceOrderActivityType {
     fn default() -> EcommerceOrderActivityType {
         EcommerceOrderActivityType::Noop
@@ -18815,13 +22570,15 @@ pub struct EcommerceOrder {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ContactActivityEventType {
     #[serde(rename = "event")]
     Event,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactActivityEventType {
@@ -18829,12 +22586,31 @@ impl std::fmt::Display for ContactActivityEventType {
         match self {
             ContactActivityEventType::Event => "event",
             ContactActivityEventType::Noop => "",
-            ContactActivityEventType::FallthroughString => "*",
+            ContactActivityEventType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ContactActivityEventType {
+    fn from(s: String) -> ContactActivityEventType {
+        match s.as_str() {
+            "event" => ContactActivityEventType::Event,
+            "" => ContactActivityEventType::Noop,
+            _ => ContactActivityEventType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ContactActivityEventType> for String {
+    fn from(e: ContactActivityEventType) -> String {
+        match e {
+            ContactActivityEventType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ContactActivityEventType {
     fn default() -> ContactActivityEventType {
         ContactActivityEventType::Noop
@@ -18887,13 +22663,15 @@ pub struct ContactActivityEvent {
 * The type of event activity.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SurveyResponseActivityType {
     #[serde(rename = "survey_response")]
     SurveyResponse,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SurveyResponseActivityType {
@@ -18901,12 +22679,31 @@ impl std::fmt::Display for SurveyResponseActivityType {
         match self {
             SurveyResponseActivityType::SurveyResponse => "survey_response",
             SurveyResponseActivityType::Noop => "",
-            SurveyResponseActivityType::FallthroughString => "*",
+            SurveyResponseActivityType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SurveyResponseActivityType {
+    fn from(s: String) -> SurveyResponseActivityType {
+        match s.as_str() {
+            "survey_response" => SurveyResponseActivityType::SurveyResponse,
+            "" => SurveyResponseActivityType::Noop,
+            _ => SurveyResponseActivityType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SurveyResponseActivityType> for String {
+    fn from(e: SurveyResponseActivityType) -> String {
+        match e {
+            SurveyResponseActivityType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SurveyResponseActivityType {
     fn default() -> SurveyResponseActivityType {
         SurveyResponseActivityType::Noop
@@ -19250,6 +23047,7 @@ pub struct CollectionOfTags {
 * The status for the tag on the member, pass in active to add a tag or inactive to remove it.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberTagsTagStatus {
     #[serde(rename = "active")]
     Active,
@@ -19257,8 +23055,9 @@ pub enum MemberTagsTagStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MemberTagsTagStatus {
@@ -19267,12 +23066,32 @@ impl std::fmt::Display for MemberTagsTagStatus {
             MemberTagsTagStatus::Active => "active",
             MemberTagsTagStatus::Inactive => "inactive",
             MemberTagsTagStatus::Noop => "",
-            MemberTagsTagStatus::FallthroughString => "*",
+            MemberTagsTagStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MemberTagsTagStatus {
+    fn from(s: String) -> MemberTagsTagStatus {
+        match s.as_str() {
+            "active" => MemberTagsTagStatus::Active,
+            "inactive" => MemberTagsTagStatus::Inactive,
+            "" => MemberTagsTagStatus::Noop,
+            _ => MemberTagsTagStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MemberTagsTagStatus> for String {
+    fn from(e: MemberTagsTagStatus) -> String {
+        match e {
+            MemberTagsTagStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MemberTagsTagStatus {
     fn default() -> MemberTagsTagStatus {
         MemberTagsTagStatus::Noop
@@ -19526,6 +23345,7 @@ pub struct CollectionOfMemberActivityEvents {
 * Returns notes sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsMembersNotesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -19535,8 +23355,9 @@ pub enum GetListsMembersNotesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetListsMembersNotesSortField {
@@ -19546,12 +23367,33 @@ impl std::fmt::Display for GetListsMembersNotesSortField {
             GetListsMembersNotesSortField::NoteId => "note_id",
             GetListsMembersNotesSortField::UpdatedAt => "updated_at",
             GetListsMembersNotesSortField::Noop => "",
-            GetListsMembersNotesSortField::FallthroughString => "*",
+            GetListsMembersNotesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetListsMembersNotesSortField {
+    fn from(s: String) -> GetListsMembersNotesSortField {
+        match s.as_str() {
+            "created_at" => GetListsMembersNotesSortField::CreatedAt,
+            "note_id" => GetListsMembersNotesSortField::NoteId,
+            "updated_at" => GetListsMembersNotesSortField::UpdatedAt,
+            "" => GetListsMembersNotesSortField::Noop,
+            _ => GetListsMembersNotesSortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetListsMembersNotesSortField> for String {
+    fn from(e: GetListsMembersNotesSortField) -> String {
+        match e {
+            GetListsMembersNotesSortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetListsMembersNotesSortField {
     fn default() -> GetListsMembersNotesSortField {
         GetListsMembersNotesSortField::Noop
@@ -19696,6 +23538,7 @@ pub struct CollectionOfNotes {
 * The [type](https://mailchimp.com/help/manage-audience-signup-form-fields/#Audience_field_types) for the merge field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MergeFieldType {
     #[serde(rename = "address")]
     Address,
@@ -19721,8 +23564,9 @@ pub enum MergeFieldType {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MergeFieldType {
@@ -19740,12 +23584,41 @@ impl std::fmt::Display for MergeFieldType {
             MergeFieldType::Url => "url",
             MergeFieldType::Zip => "zip",
             MergeFieldType::Noop => "",
-            MergeFieldType::FallthroughString => "*",
+            MergeFieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MergeFieldType {
+    fn from(s: String) -> MergeFieldType {
+        match s.as_str() {
+            "address" => MergeFieldType::Address,
+            "birthday" => MergeFieldType::Birthday,
+            "date" => MergeFieldType::Date,
+            "dropdown" => MergeFieldType::Dropdown,
+            "imageurl" => MergeFieldType::Imageurl,
+            "number" => MergeFieldType::Number,
+            "phone" => MergeFieldType::Phone,
+            "radio" => MergeFieldType::Radio,
+            "text" => MergeFieldType::Text,
+            "url" => MergeFieldType::Url,
+            "zip" => MergeFieldType::Zip,
+            "" => MergeFieldType::Noop,
+            _ => MergeFieldType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MergeFieldType> for String {
+    fn from(e: MergeFieldType) -> String {
+        match e {
+            MergeFieldType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MergeFieldType {
     fn default() -> MergeFieldType {
         MergeFieldType::Noop
@@ -20325,6 +24198,7 @@ pub struct ListWebhooksData {
 * Image alignment.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImageAlign {
     #[serde(rename = "center")]
     Center,
@@ -20336,8 +24210,9 @@ pub enum ImageAlign {
     Right,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageAlign {
@@ -20348,12 +24223,34 @@ impl std::fmt::Display for ImageAlign {
             ImageAlign::None => "none",
             ImageAlign::Right => "right",
             ImageAlign::Noop => "",
-            ImageAlign::FallthroughString => "*",
+            ImageAlign::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ImageAlign {
+    fn from(s: String) -> ImageAlign {
+        match s.as_str() {
+            "center" => ImageAlign::Center,
+            "left" => ImageAlign::Left,
+            "none" => ImageAlign::None,
+            "right" => ImageAlign::Right,
+            "" => ImageAlign::Noop,
+            _ => ImageAlign::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ImageAlign> for String {
+    fn from(e: ImageAlign) -> String {
+        match e {
+            ImageAlign::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ImageAlign {
     fn default() -> ImageAlign {
         ImageAlign::Noop
@@ -20369,6 +24266,7 @@ impl ImageAlign {
 * Image border style.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImageBorderStyle {
     #[serde(rename = "dashed")]
     Dashed,
@@ -20390,8 +24288,9 @@ pub enum ImageBorderStyle {
     Solid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageBorderStyle {
@@ -20407,12 +24306,39 @@ impl std::fmt::Display for ImageBorderStyle {
             ImageBorderStyle::Ridge => "ridge",
             ImageBorderStyle::Solid => "solid",
             ImageBorderStyle::Noop => "",
-            ImageBorderStyle::FallthroughString => "*",
+            ImageBorderStyle::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ImageBorderStyle {
+    fn from(s: String) -> ImageBorderStyle {
+        match s.as_str() {
+            "dashed" => ImageBorderStyle::Dashed,
+            "dotted" => ImageBorderStyle::Dotted,
+            "double" => ImageBorderStyle::Double,
+            "groove" => ImageBorderStyle::Groove,
+            "inset" => ImageBorderStyle::Inset,
+            "none" => ImageBorderStyle::None,
+            "outset" => ImageBorderStyle::Outset,
+            "ridge" => ImageBorderStyle::Ridge,
+            "solid" => ImageBorderStyle::Solid,
+            "" => ImageBorderStyle::Noop,
+            _ => ImageBorderStyle::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ImageBorderStyle> for String {
+    fn from(e: ImageBorderStyle) -> String {
+        match e {
+            ImageBorderStyle::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ImageBorderStyle {
     fn default() -> ImageBorderStyle {
         ImageBorderStyle::Noop
@@ -20428,6 +24354,7 @@ impl ImageBorderStyle {
 * Image link target.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImageTarget {
     #[serde(rename = "_blank")]
     Blank,
@@ -20435,8 +24362,9 @@ pub enum ImageTarget {
     Null,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImageTarget {
@@ -20445,12 +24373,32 @@ impl std::fmt::Display for ImageTarget {
             ImageTarget::Blank => "_blank",
             ImageTarget::Null => "null",
             ImageTarget::Noop => "",
-            ImageTarget::FallthroughString => "*",
+            ImageTarget::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ImageTarget {
+    fn from(s: String) -> ImageTarget {
+        match s.as_str() {
+            "_blank" => ImageTarget::Blank,
+            "null" => ImageTarget::Null,
+            "" => ImageTarget::Noop,
+            _ => ImageTarget::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ImageTarget> for String {
+    fn from(e: ImageTarget) -> String {
+        match e {
+            ImageTarget::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ImageTarget {
     fn default() -> ImageTarget {
         ImageTarget::Noop
@@ -20558,6 +24506,7 @@ pub struct Header {
 * The content section name.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Section {
     #[serde(rename = "signup_message")]
     SignupMessage,
@@ -20567,8 +24516,9 @@ pub enum Section {
     UnsubMessage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Section {
@@ -20578,12 +24528,33 @@ impl std::fmt::Display for Section {
             Section::SignupThankYouTitle => "signup_thank_you_title",
             Section::UnsubMessage => "unsub_message",
             Section::Noop => "",
-            Section::FallthroughString => "*",
+            Section::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Section {
+    fn from(s: String) -> Section {
+        match s.as_str() {
+            "signup_message" => Section::SignupMessage,
+            "signup_thank_you_title" => Section::SignupThankYouTitle,
+            "unsub_message" => Section::UnsubMessage,
+            "" => Section::Noop,
+            _ => Section::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Section> for String {
+    fn from(e: Section) -> String {
+        match e {
+            Section::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Section {
     fn default() -> Section {
         Section::Noop
@@ -20618,6 +24589,7 @@ pub struct Contents {
 * A string that identifies the element selector.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Selector {
     #[serde(rename = "body_background")]
     BodyBackground,
@@ -20649,8 +24621,9 @@ pub enum Selector {
     PageOuterWrapper,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Selector {
@@ -20671,12 +24644,44 @@ impl std::fmt::Display for Selector {
             Selector::PageHeader => "page_header",
             Selector::PageOuterWrapper => "page_outer_wrapper",
             Selector::Noop => "",
-            Selector::FallthroughString => "*",
+            Selector::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Selector {
+    fn from(s: String) -> Selector {
+        match s.as_str() {
+            "body_background" => Selector::BodyBackground,
+            "body_link_style" => Selector::BodyLinkStyle,
+            "forms_buttons" => Selector::FormsButtons,
+            "forms_buttons_hovered" => Selector::FormsButtonsHovered,
+            "forms_errors" => Selector::FormsErrors,
+            "forms_field_label" => Selector::FormsFieldLabel,
+            "forms_field_text" => Selector::FormsFieldText,
+            "forms_help_text" => Selector::FormsHelpText,
+            "forms_required" => Selector::FormsRequired,
+            "forms_required_legend" => Selector::FormsRequiredLegend,
+            "monkey_rewards_badge" => Selector::MonkeyRewardsBadge,
+            "page_background" => Selector::PageBackground,
+            "page_header" => Selector::PageHeader,
+            "page_outer_wrapper" => Selector::PageOuterWrapper,
+            "" => Selector::Noop,
+            _ => Selector::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Selector> for String {
+    fn from(e: Selector) -> String {
+        match e {
+            Selector::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Selector {
     fn default() -> Selector {
         Selector::Noop
@@ -20930,6 +24935,7 @@ pub struct ListLocations {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAllLandingPagesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -20937,8 +24943,9 @@ pub enum GetAllLandingPagesSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllLandingPagesSortField {
@@ -20947,12 +24954,32 @@ impl std::fmt::Display for GetAllLandingPagesSortField {
             GetAllLandingPagesSortField::CreatedAt => "created_at",
             GetAllLandingPagesSortField::UpdatedAt => "updated_at",
             GetAllLandingPagesSortField::Noop => "",
-            GetAllLandingPagesSortField::FallthroughString => "*",
+            GetAllLandingPagesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetAllLandingPagesSortField {
+    fn from(s: String) -> GetAllLandingPagesSortField {
+        match s.as_str() {
+            "created_at" => GetAllLandingPagesSortField::CreatedAt,
+            "updated_at" => GetAllLandingPagesSortField::UpdatedAt,
+            "" => GetAllLandingPagesSortField::Noop,
+            _ => GetAllLandingPagesSortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetAllLandingPagesSortField> for String {
+    fn from(e: GetAllLandingPagesSortField) -> String {
+        match e {
+            GetAllLandingPagesSortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetAllLandingPagesSortField {
     fn default() -> GetAllLandingPagesSortField {
         GetAllLandingPagesSortField::Noop
@@ -20968,6 +24995,7 @@ impl GetAllLandingPagesSortField {
 * The status of this landing page.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LandingPageStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -20977,8 +25005,9 @@ pub enum LandingPageStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageStatus {
@@ -20988,12 +25017,33 @@ impl std::fmt::Display for LandingPageStatus {
             LandingPageStatus::Published => "published",
             LandingPageStatus::Unpublished => "unpublished",
             LandingPageStatus::Noop => "",
-            LandingPageStatus::FallthroughString => "*",
+            LandingPageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LandingPageStatus {
+    fn from(s: String) -> LandingPageStatus {
+        match s.as_str() {
+            "draft" => LandingPageStatus::Draft,
+            "published" => LandingPageStatus::Published,
+            "unpublished" => LandingPageStatus::Unpublished,
+            "" => LandingPageStatus::Noop,
+            _ => LandingPageStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LandingPageStatus> for String {
+    fn from(e: LandingPageStatus) -> String {
+        match e {
+            LandingPageStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LandingPageStatus {
     fn default() -> LandingPageStatus {
         LandingPageStatus::Noop
@@ -21214,6 +25264,7 @@ pub struct GetAllLandingPagesResponse {
 * The type of template the landing page has.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LandingPageTemplateType {
     #[serde(rename = "product")]
     Product,
@@ -21221,8 +25272,9 @@ pub enum LandingPageTemplateType {
     Signup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LandingPageTemplateType {
@@ -21231,12 +25283,32 @@ impl std::fmt::Display for LandingPageTemplateType {
             LandingPageTemplateType::Product => "product",
             LandingPageTemplateType::Signup => "signup",
             LandingPageTemplateType::Noop => "",
-            LandingPageTemplateType::FallthroughString => "*",
+            LandingPageTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LandingPageTemplateType {
+    fn from(s: String) -> LandingPageTemplateType {
+        match s.as_str() {
+            "product" => LandingPageTemplateType::Product,
+            "signup" => LandingPageTemplateType::Signup,
+            "" => LandingPageTemplateType::Noop,
+            _ => LandingPageTemplateType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LandingPageTemplateType> for String {
+    fn from(e: LandingPageTemplateType) -> String {
+        match e {
+            LandingPageTemplateType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LandingPageTemplateType {
     fn default() -> LandingPageTemplateType {
         LandingPageTemplateType::Noop
@@ -22434,6 +26506,7 @@ pub struct AbuseComplaintsData {
 * The sentiment type for a feedback message.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AdviceType {
     #[serde(rename = "negative")]
     Negative,
@@ -22443,8 +26516,9 @@ pub enum AdviceType {
     Positive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AdviceType {
@@ -22454,12 +26528,33 @@ impl std::fmt::Display for AdviceType {
             AdviceType::Neutral => "neutral",
             AdviceType::Positive => "positive",
             AdviceType::Noop => "",
-            AdviceType::FallthroughString => "*",
+            AdviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AdviceType {
+    fn from(s: String) -> AdviceType {
+        match s.as_str() {
+            "negative" => AdviceType::Negative,
+            "neutral" => AdviceType::Neutral,
+            "positive" => AdviceType::Positive,
+            "" => AdviceType::Noop,
+            _ => AdviceType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AdviceType> for String {
+    fn from(e: AdviceType) -> String {
+        match e {
+            AdviceType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AdviceType {
     fn default() -> AdviceType {
         AdviceType::Noop
@@ -24087,6 +28182,7 @@ pub struct UnsubscribesData {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetReportsEcommerceProductActivitySortField {
     #[serde(rename = "title")]
     Title,
@@ -24096,8 +28192,9 @@ pub enum GetReportsEcommerceProductActivitySortField {
     TotalRevenue,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
@@ -24107,12 +28204,33 @@ impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
             GetReportsEcommerceProductActivitySortField::TotalPurchased => "total_purchased",
             GetReportsEcommerceProductActivitySortField::TotalRevenue => "total_revenue",
             GetReportsEcommerceProductActivitySortField::Noop => "",
-            GetReportsEcommerceProductActivitySortField::FallthroughString => "*",
+            GetReportsEcommerceProductActivitySortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetReportsEcommerceProductActivitySortField {
+    fn from(s: String) -> GetReportsEcommerceProductActivitySortField {
+        match s.as_str() {
+            "title" => GetReportsEcommerceProductActivitySortField::Title,
+            "total_purchased" => GetReportsEcommerceProductActivitySortField::TotalPurchased,
+            "total_revenue" => GetReportsEcommerceProductActivitySortField::TotalRevenue,
+            "" => GetReportsEcommerceProductActivitySortField::Noop,
+            _ => GetReportsEcommerceProductActivitySortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetReportsEcommerceProductActivitySortField> for String {
+    fn from(e: GetReportsEcommerceProductActivitySortField) -> String {
+        match e {
+            GetReportsEcommerceProductActivitySortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetReportsEcommerceProductActivitySortField {
     fn default() -> GetReportsEcommerceProductActivitySortField {
         GetReportsEcommerceProductActivitySortField::Noop
@@ -24237,6 +28355,7 @@ pub struct GetReportsEcommerceProductActivityResponse {
 * Returns user templates sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetTemplatesSortField {
     #[serde(rename = "date_created")]
     DateCreated,
@@ -24246,8 +28365,9 @@ pub enum GetTemplatesSortField {
     Name,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetTemplatesSortField {
@@ -24257,12 +28377,33 @@ impl std::fmt::Display for GetTemplatesSortField {
             GetTemplatesSortField::DateEdited => "date_edited",
             GetTemplatesSortField::Name => "name",
             GetTemplatesSortField::Noop => "",
-            GetTemplatesSortField::FallthroughString => "*",
+            GetTemplatesSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetTemplatesSortField {
+    fn from(s: String) -> GetTemplatesSortField {
+        match s.as_str() {
+            "date_created" => GetTemplatesSortField::DateCreated,
+            "date_edited" => GetTemplatesSortField::DateEdited,
+            "name" => GetTemplatesSortField::Name,
+            "" => GetTemplatesSortField::Noop,
+            _ => GetTemplatesSortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetTemplatesSortField> for String {
+    fn from(e: GetTemplatesSortField) -> String {
+        match e {
+            GetTemplatesSortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetTemplatesSortField {
     fn default() -> GetTemplatesSortField {
         GetTemplatesSortField::Noop
@@ -24658,13 +28799,15 @@ pub struct Customer {
 * The Mailchimp tracking code for the order. Uses the 'mc_tc' parameter in E-Commerce tracking URLs.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TrackingCode {
     #[serde(rename = "prec")]
     Prec,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrackingCode {
@@ -24672,12 +28815,31 @@ impl std::fmt::Display for TrackingCode {
         match self {
             TrackingCode::Prec => "prec",
             TrackingCode::Noop => "",
-            TrackingCode::FallthroughString => "*",
+            TrackingCode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TrackingCode {
+    fn from(s: String) -> TrackingCode {
+        match s.as_str() {
+            "prec" => TrackingCode::Prec,
+            "" => TrackingCode::Noop,
+            _ => TrackingCode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TrackingCode> for String {
+    fn from(e: TrackingCode) -> String {
+        match e {
+            TrackingCode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TrackingCode {
     fn default() -> TrackingCode {
         TrackingCode::Noop
@@ -24941,6 +29103,7 @@ pub struct BillingAddress {
 * Type of discount. For free shipping set type to fixed
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrdersPromosType {
     #[serde(rename = "fixed")]
     Fixed,
@@ -24948,8 +29111,9 @@ pub enum OrdersPromosType {
     Percentage,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersPromosType {
@@ -24958,12 +29122,32 @@ impl std::fmt::Display for OrdersPromosType {
             OrdersPromosType::Fixed => "fixed",
             OrdersPromosType::Percentage => "percentage",
             OrdersPromosType::Noop => "",
-            OrdersPromosType::FallthroughString => "*",
+            OrdersPromosType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrdersPromosType {
+    fn from(s: String) -> OrdersPromosType {
+        match s.as_str() {
+            "fixed" => OrdersPromosType::Fixed,
+            "percentage" => OrdersPromosType::Percentage,
+            "" => OrdersPromosType::Noop,
+            _ => OrdersPromosType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrdersPromosType> for String {
+    fn from(e: OrdersPromosType) -> String {
+        match e {
+            OrdersPromosType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrdersPromosType {
     fn default() -> OrdersPromosType {
         OrdersPromosType::Noop
@@ -26647,6 +30831,7 @@ pub struct ECommerceCustomerDataType {
 * The target that the discount applies to.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Target {
     #[serde(rename = "per_item")]
     PerItem,
@@ -26656,8 +30841,9 @@ pub enum Target {
     Total,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Target {
@@ -26667,12 +30853,33 @@ impl std::fmt::Display for Target {
             Target::Shipping => "shipping",
             Target::Total => "total",
             Target::Noop => "",
-            Target::FallthroughString => "*",
+            Target::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Target {
+    fn from(s: String) -> Target {
+        match s.as_str() {
+            "per_item" => Target::PerItem,
+            "shipping" => Target::Shipping,
+            "total" => Target::Total,
+            "" => Target::Noop,
+            _ => Target::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Target> for String {
+    fn from(e: Target) -> String {
+        match e {
+            Target::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Target {
     fn default() -> Target {
         Target::Noop
@@ -28752,6 +32959,7 @@ pub struct ApiHealthStatus {
 * Returns files sorted by the specified field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAllFacebookAdsSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -28761,8 +32969,9 @@ pub enum GetAllFacebookAdsSortField {
     UpdatedAt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAllFacebookAdsSortField {
@@ -28772,12 +32981,33 @@ impl std::fmt::Display for GetAllFacebookAdsSortField {
             GetAllFacebookAdsSortField::EndTime => "end_time",
             GetAllFacebookAdsSortField::UpdatedAt => "updated_at",
             GetAllFacebookAdsSortField::Noop => "",
-            GetAllFacebookAdsSortField::FallthroughString => "*",
+            GetAllFacebookAdsSortField::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetAllFacebookAdsSortField {
+    fn from(s: String) -> GetAllFacebookAdsSortField {
+        match s.as_str() {
+            "created_at" => GetAllFacebookAdsSortField::CreatedAt,
+            "end_time" => GetAllFacebookAdsSortField::EndTime,
+            "updated_at" => GetAllFacebookAdsSortField::UpdatedAt,
+            "" => GetAllFacebookAdsSortField::Noop,
+            _ => GetAllFacebookAdsSortField::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetAllFacebookAdsSortField> for String {
+    fn from(e: GetAllFacebookAdsSortField) -> String {
+        match e {
+            GetAllFacebookAdsSortField::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetAllFacebookAdsSortField {
     fn default() -> GetAllFacebookAdsSortField {
         GetAllFacebookAdsSortField::Noop
@@ -28793,6 +33023,7 @@ impl GetAllFacebookAdsSortField {
 * Supported Campaign, Ad, Page type
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OutreachType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -28824,8 +33055,9 @@ pub enum OutreachType {
     Website,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachType {
@@ -28846,12 +33078,44 @@ impl std::fmt::Display for OutreachType {
             OutreachType::Variate => "variate",
             OutreachType::Website => "website",
             OutreachType::Noop => "",
-            OutreachType::FallthroughString => "*",
+            OutreachType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OutreachType {
+    fn from(s: String) -> OutreachType {
+        match s.as_str() {
+            "absplit" => OutreachType::Absplit,
+            "automation" => OutreachType::Automation,
+            "autoresponder" => OutreachType::Autoresponder,
+            "facebook" => OutreachType::Facebook,
+            "google" => OutreachType::Google,
+            "page" => OutreachType::Page,
+            "plaintext" => OutreachType::Plaintext,
+            "reconfirm" => OutreachType::Reconfirm,
+            "regular" => OutreachType::Regular,
+            "rss" => OutreachType::Rss,
+            "survey" => OutreachType::Survey,
+            "transactional" => OutreachType::Transactional,
+            "variate" => OutreachType::Variate,
+            "website" => OutreachType::Website,
+            "" => OutreachType::Noop,
+            _ => OutreachType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OutreachType> for String {
+    fn from(e: OutreachType) -> String {
+        match e {
+            OutreachType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OutreachType {
     fn default() -> OutreachType {
         OutreachType::Noop
@@ -28867,6 +33131,7 @@ impl OutreachType {
 * Campaign, Ad, or Page status
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OutreachStatus {
     #[serde(rename = "active")]
     Active,
@@ -28904,8 +33169,9 @@ pub enum OutreachStatus {
     Unpublished,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutreachStatus {
@@ -28929,12 +33195,47 @@ impl std::fmt::Display for OutreachStatus {
             OutreachStatus::Somepaused => "somepaused",
             OutreachStatus::Unpublished => "unpublished",
             OutreachStatus::Noop => "",
-            OutreachStatus::FallthroughString => "*",
+            OutreachStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OutreachStatus {
+    fn from(s: String) -> OutreachStatus {
+        match s.as_str() {
+            "active" => OutreachStatus::Active,
+            "canceled" => OutreachStatus::Canceled,
+            "canceling" => OutreachStatus::Canceling,
+            "completed" => OutreachStatus::Completed,
+            "disconnected" => OutreachStatus::Disconnected,
+            "draft" => OutreachStatus::Draft,
+            "partialRejected" => OutreachStatus::PartialRejected,
+            "paused" => OutreachStatus::Paused,
+            "pending" => OutreachStatus::Pending,
+            "published" => OutreachStatus::Published,
+            "rejected" => OutreachStatus::Rejected,
+            "save" => OutreachStatus::Save,
+            "schedule" => OutreachStatus::Schedule,
+            "sending" => OutreachStatus::Sending,
+            "sent" => OutreachStatus::Sent,
+            "somepaused" => OutreachStatus::Somepaused,
+            "unpublished" => OutreachStatus::Unpublished,
+            "" => OutreachStatus::Noop,
+            _ => OutreachStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OutreachStatus> for String {
+    fn from(e: OutreachStatus) -> String {
+        match e {
+            OutreachStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OutreachStatus {
     fn default() -> OutreachStatus {
         OutreachStatus::Noop
@@ -29434,6 +33735,7 @@ pub struct Site {
 * Type of the audience
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FacebookAdsAudienceType {
     #[serde(rename = "Custom Audience")]
     CustomAudience,
@@ -29443,8 +33745,9 @@ pub enum FacebookAdsAudienceType {
     LookalikeAudience,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FacebookAdsAudienceType {
@@ -29454,12 +33757,33 @@ impl std::fmt::Display for FacebookAdsAudienceType {
             FacebookAdsAudienceType::InterestBasedAudience => "Interest-based Audience",
             FacebookAdsAudienceType::LookalikeAudience => "Lookalike Audience",
             FacebookAdsAudienceType::Noop => "",
-            FacebookAdsAudienceType::FallthroughString => "*",
+            FacebookAdsAudienceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FacebookAdsAudienceType {
+    fn from(s: String) -> FacebookAdsAudienceType {
+        match s.as_str() {
+            "Custom Audience" => FacebookAdsAudienceType::CustomAudience,
+            "Interest-based Audience" => FacebookAdsAudienceType::InterestBasedAudience,
+            "Lookalike Audience" => FacebookAdsAudienceType::LookalikeAudience,
+            "" => FacebookAdsAudienceType::Noop,
+            _ => FacebookAdsAudienceType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FacebookAdsAudienceType> for String {
+    fn from(e: FacebookAdsAudienceType) -> String {
+        match e {
+            FacebookAdsAudienceType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FacebookAdsAudienceType {
     fn default() -> FacebookAdsAudienceType {
         FacebookAdsAudienceType::Noop
@@ -29475,6 +33799,7 @@ impl FacebookAdsAudienceType {
 * List or Facebook based audience
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SourceType {
     #[serde(rename = "facebook")]
     Facebook,
@@ -29482,8 +33807,9 @@ pub enum SourceType {
     List,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SourceType {
@@ -29492,12 +33818,32 @@ impl std::fmt::Display for SourceType {
             SourceType::Facebook => "facebook",
             SourceType::List => "list",
             SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SourceType {
+    fn from(s: String) -> SourceType {
+        match s.as_str() {
+            "facebook" => SourceType::Facebook,
+            "list" => SourceType::List,
+            "" => SourceType::Noop,
+            _ => SourceType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SourceType> for String {
+    fn from(e: SourceType) -> String {
+        match e {
+            SourceType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SourceType {
     fn default() -> SourceType {
         SourceType::Noop
@@ -23,6 +23,19 @@ impl VerifiedDomains {
      * * `domain_name: &str` -- The name of the folder.
      */
     pub async fn get(&self, domain_name: &str) -> ClientResult<crate::types::VerifiedDomains> {
+        Ok(self.get_with_response(domain_name).await?.body)
+    }
+    /**
+     * Get domain info.
+     *
+     * This function performs a `GET` to the `/verified-domains/{domain_name}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        domain_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomains>> {
         let url = self.client.url(
             &format!(
                 "/verified-domains/{}",
@@ -31,7 +44,7 @@ impl VerifiedDomains {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -52,6 +65,20 @@ impl VerifiedDomains {
      * * `domain_name: &str` -- The name of the folder.
      */
     pub async fn delete(&self, domain_name: &str) -> ClientResult<()> {
+        self.delete_with_response(domain_name).await?;
+        Ok(())
+    }
+    /**
+     * Delete domain.
+     *
+     * This function performs a `DELETE` to the `/verified-domains/{domain_name}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        domain_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/verified-domains/{}",
@@ -60,7 +87,7 @@ impl VerifiedDomains {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -85,6 +112,23 @@ impl VerifiedDomains {
         domain_name: &str,
         body: &crate::types::VerifyADomainSending,
     ) -> ClientResult<crate::types::VerifiedDomains> {
+        Ok(self
+            .verify_domain_with_response(domain_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Verify domain.
+     *
+     * This function performs a `POST` to the `/verified-domains/{domain_name}/actions/verify` endpoint.
+     *
+     * As opposed to `verify_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn verify_domain_with_response(
+        &self,
+        domain_name: &str,
+        body: &crate::types::VerifyADomainSending,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomains>> {
         let url = self.client.url(
             &format!(
                 "/verified-domains/{}/actions/verify",
@@ -93,7 +137,7 @@ impl VerifiedDomains {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -110,9 +154,21 @@ impl VerifiedDomains {
      * Get all of the sending domains on the account.
      */
     pub async fn get_verified_domains(&self) -> ClientResult<crate::types::VerifiedDomainsData> {
+        Ok(self.get_verified_domains_with_response().await?.body)
+    }
+    /**
+     * List sending domains.
+     *
+     * This function performs a `GET` to the `/verified-domains` endpoint.
+     *
+     * As opposed to `get_verified_domains`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verified_domains_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomainsData>> {
         let url = self.client.url("/verified-domains", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -132,9 +188,22 @@ impl VerifiedDomains {
         &self,
         body: &crate::types::VerifiedDomainsDataType,
     ) -> ClientResult<crate::types::VerifiedDomains> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Add domain to account.
+     *
+     * This function performs a `POST` to the `/verified-domains` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::VerifiedDomainsDataType,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedDomains>> {
         let url = self.client.url("/verified-domains", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -36,6 +36,27 @@ impl Applications {
         expand: &str,
         include_non_deleted: bool,
     ) -> ClientResult<Vec<crate::types::Application>> {
+        Ok(self
+            .list_with_response(q, after, limit, filter, expand, include_non_deleted)
+            .await?
+            .body)
+    }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -61,7 +82,7 @@ impl Applications {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/apps?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -114,6 +135,97 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Application>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if include_non_deleted {
+            query_args.push((
+                "includeNonDeleted".to_string(),
+                include_non_deleted.to_string(),
+            ));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/apps?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Application>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(
+            q,
+            filter,
+            expand,
+            include_non_deleted,
+            None,
+        ))
+    }
     /**
      * Add Application.
      *
@@ -131,6 +243,20 @@ impl Applications {
         activate: bool,
         body: &crate::types::Application,
     ) -> ClientResult<crate::types::Application> {
+        Ok(self.create_with_response(activate, body).await?.body)
+    }
+    /**
+     * Add Application.
+     *
+     * This function performs a `POST` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        activate: bool,
+        body: &crate::types::Application,
+    ) -> ClientResult<crate::Response<crate::types::Application>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if activate {
             query_args.push(("activate".to_string(), activate.to_string()));
@@ -138,7 +264,7 @@ impl Applications {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/apps?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -160,6 +286,20 @@ impl Applications {
      * * `expand: &str`
      */
     pub async fn get(&self, app_id: &str, expand: &str) -> ClientResult<crate::types::Application> {
+        Ok(self.get_with_response(app_id, expand).await?.body)
+    }
+    /**
+     * Get Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::Application>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -174,7 +314,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -199,6 +339,20 @@ impl Applications {
         app_id: &str,
         body: &crate::types::Application,
     ) -> ClientResult<crate::types::Application> {
+        Ok(self.update_with_response(app_id, body).await?.body)
+    }
+    /**
+     * Update Application.
+     *
+     * This function performs a `PUT` to the `/api/v1/apps/{appId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        app_id: &str,
+        body: &crate::types::Application,
+    ) -> ClientResult<crate::Response<crate::types::Application>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}",
@@ -207,7 +361,7 @@ impl Applications {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -228,6 +382,17 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn delete(&self, app_id: &str) -> ClientResult<()> {
+        self.delete_with_response(app_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete Application.
+     *
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, app_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}",
@@ -236,7 +401,7 @@ impl Applications {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -257,6 +422,19 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn list_csrs_fors(&self, app_id: &str) -> ClientResult<Vec<crate::types::Csr>> {
+        Ok(self.list_csrs_fors_with_response(app_id).await?.body)
+    }
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_csrs_fors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_csrs_fors_with_response(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Csr>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/csrs",
@@ -265,7 +443,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -301,6 +479,70 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_csrs_fors_pages_stream(
+        &self,
+        app_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Csr>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Csr>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_csrs_fors_pages_stream(app_id, None))
+    }
     /**
      * Generate Certificate Signing Request for Application.
      *
@@ -317,6 +559,23 @@ impl Applications {
         app_id: &str,
         body: &crate::types::CsrMetadata,
     ) -> ClientResult<crate::types::Csr> {
+        Ok(self
+            .generate_csr_for_with_response(app_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Generate Certificate Signing Request for Application.
+     *
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `generate_csr_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn generate_csr_for_with_response(
+        &self,
+        app_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/csrs",
@@ -325,7 +584,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -343,6 +602,18 @@ impl Applications {
      * * `csr_id: &str`
      */
     pub async fn get_csr_for(&self, app_id: &str, csr_id: &str) -> ClientResult<crate::types::Csr> {
+        Ok(self.get_csr_for_with_response(app_id, csr_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs/{csrId}` endpoint.
+     *
+     * As opposed to `get_csr_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_csr_for_with_response(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/csrs/{}",
@@ -352,7 +623,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -370,6 +641,19 @@ impl Applications {
      * * `csr_id: &str`
      */
     pub async fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> ClientResult<()> {
+        self.revoke_csr_from_with_response(app_id, csr_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/credentials/csrs/{csrId}` endpoint.
+     *
+     * As opposed to `revoke_csr_from`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_csr_from_with_response(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/csrs/{}",
@@ -379,7 +663,7 @@ impl Applications {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -401,6 +685,21 @@ impl Applications {
         app_id: &str,
         csr_id: &str,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self
+            .post_app_credentials_csr_lifecycle_publish_with_response(app_id, csr_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/csrs/{csrId}/lifecycle/publish` endpoint.
+     *
+     * As opposed to `post_app_credentials_csr_lifecycle_publish`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_app_credentials_csr_lifecycle_publish_with_response(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/csrs/{}/lifecycle/publish",
@@ -410,7 +709,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -431,6 +730,19 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn list_keys(&self, app_id: &str) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        Ok(self.list_keys_with_response(app_id).await?.body)
+    }
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_keys`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_keys_with_response(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/keys",
@@ -439,7 +751,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -475,6 +787,74 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_keys_pages_stream(
+        &self,
+        app_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::JsonWebKey>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(app_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::JsonWebKey>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        app_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_keys_pages_stream(app_id, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
      *
@@ -490,6 +870,21 @@ impl Applications {
         app_id: &str,
         validity_years: i64,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self
+            .generate_key_with_response(app_id, validity_years)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
+     *
+     * As opposed to `generate_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn generate_key_with_response(
+        &self,
+        app_id: &str,
+        validity_years: i64,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if validity_years > 0 {
             query_args.push(("validityYears".to_string(), validity_years.to_string()));
@@ -504,7 +899,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -530,6 +925,20 @@ impl Applications {
         app_id: &str,
         key_id: &str,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self.get_key_with_response(app_id, key_id).await?.body)
+    }
+    /**
+     * Get Key Credential for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys/{keyId}` endpoint.
+     *
+     * As opposed to `get_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_key_with_response(
+        &self,
+        app_id: &str,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/credentials/keys/{}",
@@ -539,7 +948,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -567,6 +976,24 @@ impl Applications {
         key_id: &str,
         target_aid: &str,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self
+            .clone_key_with_response(app_id, key_id, target_aid)
+            .await?
+            .body)
+    }
+    /**
+     * Clone Application Key Credential.
+     *
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/{keyId}/clone` endpoint.
+     *
+     * As opposed to `clone_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn clone_key_with_response(
+        &self,
+        app_id: &str,
+        key_id: &str,
+        target_aid: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !target_aid.is_empty() {
             query_args.push(("targetAid".to_string(), target_aid.to_string()));
@@ -582,7 +1009,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -606,6 +1033,21 @@ impl Applications {
         app_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Ok(self
+            .list_scope_consent_grants_with_response(app_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_scope_consent_grants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_scope_consent_grants_with_response(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -620,7 +1062,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -664,6 +1106,82 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_all_scope_consent_grants`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_scope_consent_grants_pages_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OAuth2ScopeConsentGrant>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/grants?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2ScopeConsentGrant>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_all_scope_consent_grants`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_scope_consent_grants_pages_stream(app_id, expand, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/grants` endpoint.
      *
@@ -678,6 +1196,21 @@ impl Applications {
         app_id: &str,
         body: &crate::types::OAuth2ScopeConsentGrant,
     ) -> ClientResult<crate::types::OAuth2ScopeConsentGrant> {
+        Ok(self
+            .grant_consent_scope_with_response(app_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `grant_consent_scope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn grant_consent_scope_with_response(
+        &self,
+        app_id: &str,
+        body: &crate::types::OAuth2ScopeConsentGrant,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/grants",
@@ -686,7 +1219,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -712,6 +1245,22 @@ impl Applications {
         grant_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::OAuth2ScopeConsentGrant> {
+        Ok(self
+            .get_scope_consent_grant_with_response(app_id, grant_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `get_scope_consent_grant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_scope_consent_grant_with_response(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -727,7 +1276,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -751,6 +1300,20 @@ impl Applications {
         app_id: &str,
         grant_id: &str,
     ) -> ClientResult<()> {
+        self.revoke_scope_consent_grant_with_response(app_id, grant_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `revoke_scope_consent_grant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_scope_consent_grant_with_response(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/grants/{}",
@@ -760,7 +1323,7 @@ impl Applications {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -792,6 +1355,26 @@ impl Applications {
         limit: i64,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::ApplicationGroupAssignment>> {
+        Ok(self
+            .list_group_assignments_with_response(app_id, q, after, limit, expand)
+            .await?
+            .body)
+    }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_group_assignments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_group_assignments_with_response(
+        &self,
+        app_id: &str,
+        q: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplicationGroupAssignment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -799,9 +1382,50 @@ impl Applications {
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
+        if limit > 0 {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/groups?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+        self.client
+            .get_with_response(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_group_assignments`, this function returns all the pages of the request at once.
+     *
+     * Enumerates group assignments for an application.
+     */
+    pub async fn list_all_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+    ) -> ClientResult<Vec<crate::types::ApplicationGroupAssignment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
         if !q.is_empty() {
             query_args.push(("q".to_string(), q.to_string()));
         }
@@ -815,7 +1439,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_all_pages(
                 &url,
                 crate::Message {
                     body: None,
@@ -829,16 +1453,18 @@ impl Applications {
      *
      * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
      *
-     * As opposed to `list_group_assignments`, this function returns all the pages of the request at once.
-     *
-     * Enumerates group assignments for an application.
+     * As opposed to `list_all_group_assignments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn list_all_group_assignments(
+    pub fn list_all_group_assignments_pages_stream(
         &self,
         app_id: &str,
         q: &str,
         expand: &str,
-    ) -> ClientResult<Vec<crate::types::ApplicationGroupAssignment>> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ApplicationGroupAssignment>>,
+    > + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -855,15 +1481,57 @@ impl Applications {
             ),
             None,
         );
-        self.client
-            .get_all_pages(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ApplicationGroupAssignment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_all_group_assignments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_group_assignments_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplicationGroupAssignment>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_group_assignments_pages_stream(app_id, q, expand, None),
+        )
     }
     /**
      * Get Assigned Group for Application.
@@ -884,6 +1552,24 @@ impl Applications {
         group_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::ApplicationGroupAssignment> {
+        Ok(self
+            .get_group_assignment_with_response(app_id, group_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * Get Assigned Group for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups/{groupId}` endpoint.
+     *
+     * As opposed to `get_group_assignment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_group_assignment_with_response(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApplicationGroupAssignment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -899,7 +1585,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -926,6 +1612,24 @@ impl Applications {
         group_id: &str,
         body: &crate::types::ApplicationGroupAssignment,
     ) -> ClientResult<crate::types::ApplicationGroupAssignment> {
+        Ok(self
+            .create_group_assignment_with_response(app_id, group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Assign Group to Application.
+     *
+     * This function performs a `PUT` to the `/api/v1/apps/{appId}/groups/{groupId}` endpoint.
+     *
+     * As opposed to `create_group_assignment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_group_assignment_with_response(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        body: &crate::types::ApplicationGroupAssignment,
+    ) -> ClientResult<crate::Response<crate::types::ApplicationGroupAssignment>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/groups/{}",
@@ -935,7 +1639,7 @@ impl Applications {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -957,6 +1661,22 @@ impl Applications {
      * * `group_id: &str`
      */
     pub async fn delete_group_assignment(&self, app_id: &str, group_id: &str) -> ClientResult<()> {
+        self.delete_group_assignment_with_response(app_id, group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove Group from Application.
+     *
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete_group_assignment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_group_assignment_with_response(
+        &self,
+        app_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/groups/{}",
@@ -966,7 +1686,7 @@ impl Applications {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -987,6 +1707,17 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn activate(&self, app_id: &str) -> ClientResult<()> {
+        self.activate_with_response(app_id).await?;
+        Ok(())
+    }
+    /**
+     * Activate Application.
+     *
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_with_response(&self, app_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/lifecycle/activate",
@@ -995,7 +1726,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1016,6 +1747,20 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn deactivate(&self, app_id: &str) -> ClientResult<()> {
+        self.deactivate_with_response(app_id).await?;
+        Ok(())
+    }
+    /**
+     * Deactivate Application.
+     *
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_with_response(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/lifecycle/deactivate",
@@ -1024,7 +1769,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1052,6 +1797,23 @@ impl Applications {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::OAuth2Token>> {
+        Ok(self
+            .list_o_auth_2_tokens_fors_with_response(app_id, expand, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_o_auth_2_tokens_fors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_o_auth_2_tokens_fors_with_response(
+        &self,
+        app_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Token>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1072,7 +1834,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1116,6 +1878,80 @@ impl Applications {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_tokens_fors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_o_auth_2_tokens_fors_pages_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OAuth2Token>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/tokens?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2Token>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_tokens_fors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Token>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_o_auth_2_tokens_fors_pages_stream(app_id, expand, None),
+        )
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens` endpoint.
      *
@@ -1126,6 +1962,19 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> ClientResult<()> {
+        self.revoke_o_auth_2_tokens_for_with_response(app_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `revoke_o_auth_2_tokens_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_o_auth_2_tokens_for_with_response(
+        &self,
+        app_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/tokens",
@@ -1134,7 +1983,7 @@ impl Applications {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1160,6 +2009,22 @@ impl Applications {
         token_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::OAuth2Token> {
+        Ok(self
+            .get_o_auth_2_token_for_with_response(app_id, token_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `get_o_auth_2_token_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_o_auth_2_token_for_with_response(
+        &self,
+        app_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Token>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -1175,7 +2040,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1199,6 +2064,20 @@ impl Applications {
         app_id: &str,
         token_id: &str,
     ) -> ClientResult<()> {
+        self.revoke_o_auth_2_token_for_with_response(app_id, token_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `revoke_o_auth_2_token_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_o_auth_2_token_for_with_response(
+        &self,
+        app_id: &str,
+        token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/tokens/{}",
@@ -1208,7 +2087,7 @@ impl Applications {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1244,6 +2123,28 @@ impl Applications {
         filter: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::AppUser>> {
+        Ok(self
+            .list_users_with_response(app_id, q, query_scope, after, limit, filter, expand)
+            .await?
+            .body)
+    }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_users_with_response(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AppUser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1273,7 +2174,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1331,6 +2232,103 @@ impl Applications {
             )
             .await
     }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_users_pages_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::AppUser>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !query_scope.is_empty() {
+            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/apps/{}/users?{}",
+                crate::progenitor_support::encode_path(app_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::AppUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppUser>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_users_pages_stream(
+            app_id,
+            q,
+            query_scope,
+            filter,
+            expand,
+            None,
+        ))
+    }
     /**
      * Assign User to Application for SSO & Provisioning.
      *
@@ -1347,6 +2345,20 @@ impl Applications {
         app_id: &str,
         body: &crate::types::AppUser,
     ) -> ClientResult<crate::types::AppUser> {
+        Ok(self.assign_user_with_response(app_id, body).await?.body)
+    }
+    /**
+     * Assign User to Application for SSO & Provisioning.
+     *
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `assign_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_user_with_response(
+        &self,
+        app_id: &str,
+        body: &crate::types::AppUser,
+    ) -> ClientResult<crate::Response<crate::types::AppUser>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/users",
@@ -1355,7 +2367,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1383,6 +2395,24 @@ impl Applications {
         user_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::AppUser> {
+        Ok(self
+            .get_user_with_response(app_id, user_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * Get Assigned User for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users/{userId}` endpoint.
+     *
+     * As opposed to `get_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_with_response(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::AppUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -1398,7 +2428,7 @@ impl Applications {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1425,6 +2455,24 @@ impl Applications {
         user_id: &str,
         body: &crate::types::AppUser,
     ) -> ClientResult<crate::types::AppUser> {
+        Ok(self
+            .update_user_with_response(app_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Application Profile for Assigned User.
+     *
+     * This function performs a `POST` to the `/api/v1/apps/{appId}/users/{userId}` endpoint.
+     *
+     * As opposed to `update_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_user_with_response(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        body: &crate::types::AppUser,
+    ) -> ClientResult<crate::Response<crate::types::AppUser>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/apps/{}/users/{}",
@@ -1434,7 +2482,7 @@ impl Applications {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1462,6 +2510,23 @@ impl Applications {
         user_id: &str,
         send_email: bool,
     ) -> ClientResult<()> {
+        self.delete_user_with_response(app_id, user_id, send_email)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove User from Application.
+     *
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/users/{userId}` endpoint.
+     *
+     * As opposed to `delete_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_user_with_response(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
@@ -1477,7 +2542,7 @@ impl Applications {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
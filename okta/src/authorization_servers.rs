@@ -28,6 +28,19 @@ impl AuthorizationServers {
         limit: &str,
         after: &str,
     ) -> ClientResult<Vec<crate::types::AuthorizationServer>> {
+        Ok(self.list_with_response(q, limit, after).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        q: &str,
+        limit: &str,
+        after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -43,7 +56,7 @@ impl AuthorizationServers {
             .client
             .url(&format!("/api/v1/authorizationServers?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -78,6 +91,73 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        q: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::AuthorizationServer>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/authorizationServers?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::AuthorizationServer>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServer>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pages_stream(q, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers` endpoint.
      *
@@ -87,9 +167,20 @@ impl AuthorizationServers {
         &self,
         body: &crate::types::AuthorizationServer,
     ) -> ClientResult<crate::types::AuthorizationServer> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::AuthorizationServer,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServer>> {
         let url = self.client.url("/api/v1/authorizationServers", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -111,6 +202,17 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<crate::types::AuthorizationServer> {
+        Ok(self.get_with_response(auth_server_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServer>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}",
@@ -119,7 +221,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -142,6 +244,18 @@ impl AuthorizationServers {
         auth_server_id: &str,
         body: &crate::types::AuthorizationServer,
     ) -> ClientResult<crate::types::AuthorizationServer> {
+        Ok(self.update_with_response(auth_server_id, body).await?.body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/authorizationServers/{authServerId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServer,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServer>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}",
@@ -150,7 +264,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -169,6 +283,18 @@ impl AuthorizationServers {
      * * `auth_server_id: &str`
      */
     pub async fn delete(&self, auth_server_id: &str) -> ClientResult<()> {
+        self.delete_with_response(auth_server_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}",
@@ -177,7 +303,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -199,6 +325,20 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Claim>> {
+        Ok(self
+            .list_o_auth_2_claims_with_response(auth_server_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `list_o_auth_2_claims`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_o_auth_2_claims_with_response(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Claim>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/claims",
@@ -207,7 +347,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -244,6 +384,70 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_claims`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_o_auth_2_claims_pages_stream(
+        &self,
+        auth_server_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OAuth2Claim>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/claims",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2Claim>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_claims`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_o_auth_2_claims_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Claim>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_o_auth_2_claims_pages_stream(auth_server_id, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
      *
@@ -258,6 +462,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         body: &crate::types::OAuth2Claim,
     ) -> ClientResult<crate::types::OAuth2Claim> {
+        Ok(self
+            .create_o_auth_2_claim_with_response(auth_server_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `create_o_auth_2_claim`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_o_auth_2_claim_with_response(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Claim>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/claims",
@@ -266,7 +485,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -290,6 +509,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         claim_id: &str,
     ) -> ClientResult<crate::types::OAuth2Claim> {
+        Ok(self
+            .get_o_auth_2_claim_with_response(auth_server_id, claim_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims/{claimId}` endpoint.
+     *
+     * As opposed to `get_o_auth_2_claim`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_o_auth_2_claim_with_response(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Claim>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/claims/{}",
@@ -299,7 +533,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -324,6 +558,22 @@ impl AuthorizationServers {
         claim_id: &str,
         body: &crate::types::OAuth2Claim,
     ) -> ClientResult<crate::types::OAuth2Claim> {
+        Ok(self
+            .update_o_auth_2_claim_with_response(auth_server_id, claim_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/authorizationServers/{authServerId}/claims/{claimId}` endpoint.
+     *
+     * As opposed to `update_o_auth_2_claim`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_o_auth_2_claim_with_response(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Claim>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/claims/{}",
@@ -333,7 +583,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -357,6 +607,20 @@ impl AuthorizationServers {
         auth_server_id: &str,
         claim_id: &str,
     ) -> ClientResult<()> {
+        self.delete_o_auth_2_claim_with_response(auth_server_id, claim_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/claims/{claimId}` endpoint.
+     *
+     * As opposed to `delete_o_auth_2_claim`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_o_auth_2_claim_with_response(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/claims/{}",
@@ -366,7 +630,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -388,6 +652,20 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Client>> {
+        Ok(self
+            .list_o_auth_2_clients_fors_with_response(auth_server_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients` endpoint.
+     *
+     * As opposed to `list_o_auth_2_clients_fors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_o_auth_2_clients_fors_with_response(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Client>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/clients",
@@ -396,7 +674,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -433,6 +711,72 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_clients_fors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_o_auth_2_clients_fors_pages_stream(
+        &self,
+        auth_server_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OAuth2Client>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2Client>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_clients_fors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_o_auth_2_clients_fors_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_o_auth_2_clients_fors_pages_stream(auth_server_id, None),
+        )
+    }
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -454,6 +798,30 @@ impl AuthorizationServers {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::OAuth2RefreshToken>> {
+        Ok(self
+            .list_refresh_tokens_for_and_clients_with_response(
+                auth_server_id,
+                client_id,
+                expand,
+                after,
+                limit,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_refresh_tokens_for_and_clients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_refresh_tokens_for_and_clients_with_response(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -475,7 +843,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -521,6 +889,88 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_refresh_tokens_for_and_clients_pages_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OAuth2RefreshToken>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2RefreshToken>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_refresh_tokens_for_and_clients_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_refresh_tokens_for_and_clients_pages_stream(
+            auth_server_id,
+            client_id,
+            expand,
+            None,
+        ))
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -536,6 +986,20 @@ impl AuthorizationServers {
         auth_server_id: &str,
         client_id: &str,
     ) -> ClientResult<()> {
+        self.revoke_refresh_tokens_for_and_client_with_response(auth_server_id, client_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `revoke_refresh_tokens_for_and_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_refresh_tokens_for_and_client_with_response(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/clients/{}/tokens",
@@ -545,7 +1009,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -573,6 +1037,28 @@ impl AuthorizationServers {
         token_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::OAuth2RefreshToken> {
+        Ok(self
+            .get_refresh_token_for_and_client_with_response(
+                auth_server_id,
+                client_id,
+                token_id,
+                expand,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `get_refresh_token_for_and_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_refresh_token_for_and_client_with_response(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -589,7 +1075,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -615,6 +1101,21 @@ impl AuthorizationServers {
         client_id: &str,
         token_id: &str,
     ) -> ClientResult<()> {
+        self.revoke_refresh_token_for_and_client_with_response(auth_server_id, client_id, token_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `revoke_refresh_token_for_and_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_refresh_token_for_and_client_with_response(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
@@ -625,7 +1126,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -647,6 +1148,17 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        Ok(self.list_keys_with_response(auth_server_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_keys`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_keys_with_response(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/credentials/keys",
@@ -655,7 +1167,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -692,6 +1204,70 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_keys_pages_stream(
+        &self,
+        auth_server_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::JsonWebKey>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/credentials/keys",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::JsonWebKey>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_keys_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_keys_pages_stream(auth_server_id, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate` endpoint.
      *
@@ -706,6 +1282,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         body: &crate::types::JwkUse,
     ) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        Ok(self
+            .rotate_keys_with_response(auth_server_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate` endpoint.
+     *
+     * As opposed to `rotate_keys`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn rotate_keys_with_response(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::JwkUse,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/credentials/lifecycle/keyRotate",
@@ -714,7 +1305,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -733,6 +1324,18 @@ impl AuthorizationServers {
      * * `auth_server_id: &str`
      */
     pub async fn activate(&self, auth_server_id: &str) -> ClientResult<()> {
+        self.activate_with_response(auth_server_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_with_response(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/lifecycle/activate",
@@ -741,7 +1344,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -760,6 +1363,18 @@ impl AuthorizationServers {
      * * `auth_server_id: &str`
      */
     pub async fn deactivate(&self, auth_server_id: &str) -> ClientResult<()> {
+        self.deactivate_with_response(auth_server_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_with_response(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/lifecycle/deactivate",
@@ -768,7 +1383,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -784,12 +1399,23 @@ impl AuthorizationServers {
      *
      * **Parameters:**
      *
-     * * `auth_server_id: &str`
+     * * `auth_server_id: &str`
+     */
+    pub async fn list_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> ClientResult<Vec<crate::types::AuthorizationServerPolicy>> {
+        Ok(self.list_policies_with_response(auth_server_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `list_policies`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn list_policies(
+    pub async fn list_policies_with_response(
         &self,
         auth_server_id: &str,
-    ) -> ClientResult<Vec<crate::types::AuthorizationServerPolicy>> {
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServerPolicy>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies",
@@ -798,7 +1424,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -835,6 +1461,72 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `list_all_policies`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_policies_pages_stream(
+        &self,
+        auth_server_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::AuthorizationServerPolicy>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/policies",
+                crate::progenitor_support::encode_path(auth_server_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::AuthorizationServerPolicy>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `list_all_policies`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_policies_stream(
+        &self,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicy>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_policies_pages_stream(auth_server_id, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
      *
@@ -849,6 +1541,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         body: &crate::types::AuthorizationServerPolicy,
     ) -> ClientResult<crate::types::AuthorizationServerPolicy> {
+        Ok(self
+            .create_policy_with_response(auth_server_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `create_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_policy_with_response(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicy>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies",
@@ -857,7 +1564,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -881,6 +1588,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         policy_id: &str,
     ) -> ClientResult<crate::types::AuthorizationServerPolicy> {
+        Ok(self
+            .get_policy_with_response(auth_server_id, policy_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}` endpoint.
+     *
+     * As opposed to `get_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_policy_with_response(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicy>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}",
@@ -890,7 +1612,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -915,6 +1637,22 @@ impl AuthorizationServers {
         policy_id: &str,
         body: &crate::types::AuthorizationServerPolicy,
     ) -> ClientResult<crate::types::AuthorizationServerPolicy> {
+        Ok(self
+            .update_policy_with_response(auth_server_id, policy_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}` endpoint.
+     *
+     * As opposed to `update_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_policy_with_response(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicy>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}",
@@ -924,7 +1662,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -944,6 +1682,20 @@ impl AuthorizationServers {
      * * `policy_id: &str`
      */
     pub async fn delete_policy(&self, auth_server_id: &str, policy_id: &str) -> ClientResult<()> {
+        self.delete_policy_with_response(auth_server_id, policy_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}` endpoint.
+     *
+     * As opposed to `delete_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_policy_with_response(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}",
@@ -953,7 +1705,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -973,6 +1725,20 @@ impl AuthorizationServers {
      * * `policy_id: &str`
      */
     pub async fn activate_policy(&self, auth_server_id: &str, policy_id: &str) -> ClientResult<()> {
+        self.activate_policy_with_response(auth_server_id, policy_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_policy_with_response(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/lifecycle/activate",
@@ -982,7 +1748,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1006,6 +1772,20 @@ impl AuthorizationServers {
         auth_server_id: &str,
         policy_id: &str,
     ) -> ClientResult<()> {
+        self.deactivate_policy_with_response(auth_server_id, policy_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_policy_with_response(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/lifecycle/deactivate",
@@ -1015,7 +1795,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1039,6 +1819,21 @@ impl AuthorizationServers {
         policy_id: &str,
         auth_server_id: &str,
     ) -> ClientResult<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        Ok(self
+            .list_policy_rules_with_response(policy_id, auth_server_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_policy_rules`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_policy_rules_with_response(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AuthorizationServerPolicyRule>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules",
@@ -1048,7 +1843,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1087,6 +1882,80 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_policy_rules_pages_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::AuthorizationServerPolicyRule>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/policies/{}/rules",
+                crate::progenitor_support::encode_path(auth_server_id),
+                crate::progenitor_support::encode_path(policy_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::AuthorizationServerPolicyRule>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AuthorizationServerPolicyRule>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.list_all_policy_rules_pages_stream(
+            policy_id,
+            auth_server_id,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
      *
@@ -1103,6 +1972,22 @@ impl AuthorizationServers {
         auth_server_id: &str,
         body: &crate::types::AuthorizationServerPolicyRule,
     ) -> ClientResult<crate::types::AuthorizationServerPolicyRule> {
+        Ok(self
+            .create_policy_rule_with_response(policy_id, auth_server_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `create_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicyRule>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules",
@@ -1112,7 +1997,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1138,6 +2023,22 @@ impl AuthorizationServers {
         auth_server_id: &str,
         rule_id: &str,
     ) -> ClientResult<crate::types::AuthorizationServerPolicyRule> {
+        Ok(self
+            .get_policy_rule_with_response(policy_id, auth_server_id, rule_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `get_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicyRule>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
@@ -1148,7 +2049,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1175,6 +2076,23 @@ impl AuthorizationServers {
         rule_id: &str,
         body: &crate::types::AuthorizationServerPolicyRule,
     ) -> ClientResult<crate::types::AuthorizationServerPolicyRule> {
+        Ok(self
+            .update_policy_rule_with_response(policy_id, auth_server_id, rule_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `update_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::AuthorizationServerPolicyRule>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
@@ -1185,7 +2103,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1211,6 +2129,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         rule_id: &str,
     ) -> ClientResult<()> {
+        self.delete_policy_rule_with_response(policy_id, auth_server_id, rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `delete_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
@@ -1221,7 +2154,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1247,6 +2180,21 @@ impl AuthorizationServers {
         policy_id: &str,
         rule_id: &str,
     ) -> ClientResult<()> {
+        self.activate_policy_rule_with_response(auth_server_id, policy_id, rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_policy_rule_with_response(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/activate",
@@ -1257,7 +2205,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1283,6 +2231,21 @@ impl AuthorizationServers {
         policy_id: &str,
         rule_id: &str,
     ) -> ClientResult<()> {
+        self.deactivate_policy_rule_with_response(auth_server_id, policy_id, rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_policy_rule_with_response(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/deactivate",
@@ -1293,7 +2256,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1323,6 +2286,24 @@ impl AuthorizationServers {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::OAuth2Scope>> {
+        Ok(self
+            .list_o_auth_2_scopes_with_response(auth_server_id, q, filter, cursor, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_o_auth_2_scopes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_o_auth_2_scopes_with_response(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Scope>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -1346,7 +2327,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1398,6 +2379,94 @@ impl AuthorizationServers {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_scopes`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_o_auth_2_scopes_pages_stream(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OAuth2Scope>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cursor.is_empty() {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/authorizationServers/{}/scopes?{}",
+                crate::progenitor_support::encode_path(auth_server_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2Scope>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_scopes`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_o_auth_2_scopes_stream(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Scope>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_o_auth_2_scopes_pages_stream(
+            auth_server_id,
+            q,
+            filter,
+            cursor,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
      *
@@ -1412,6 +2481,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         body: &crate::types::OAuth2Scope,
     ) -> ClientResult<crate::types::OAuth2Scope> {
+        Ok(self
+            .create_o_auth_2_scope_with_response(auth_server_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `create_o_auth_2_scope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_o_auth_2_scope_with_response(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Scope>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/scopes",
@@ -1420,7 +2504,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1444,6 +2528,21 @@ impl AuthorizationServers {
         auth_server_id: &str,
         scope_id: &str,
     ) -> ClientResult<crate::types::OAuth2Scope> {
+        Ok(self
+            .get_o_auth_2_scope_with_response(auth_server_id, scope_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}` endpoint.
+     *
+     * As opposed to `get_o_auth_2_scope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_o_auth_2_scope_with_response(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Scope>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/scopes/{}",
@@ -1453,7 +2552,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1478,6 +2577,22 @@ impl AuthorizationServers {
         scope_id: &str,
         body: &crate::types::OAuth2Scope,
     ) -> ClientResult<crate::types::OAuth2Scope> {
+        Ok(self
+            .update_o_auth_2_scope_with_response(auth_server_id, scope_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}` endpoint.
+     *
+     * As opposed to `update_o_auth_2_scope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_o_auth_2_scope_with_response(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Scope>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/scopes/{}",
@@ -1487,7 +2602,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1511,6 +2626,20 @@ impl AuthorizationServers {
         auth_server_id: &str,
         scope_id: &str,
     ) -> ClientResult<()> {
+        self.delete_o_auth_2_scope_with_response(auth_server_id, scope_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}` endpoint.
+     *
+     * As opposed to `delete_o_auth_2_scope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_o_auth_2_scope_with_response(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/authorizationServers/{}/scopes/{}",
@@ -1520,7 +2649,7 @@ impl AuthorizationServers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
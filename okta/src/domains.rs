@@ -19,9 +19,21 @@ impl Domains {
      * List all verified custom Domains for the org.
      */
     pub async fn list(&self) -> ClientResult<crate::types::DomainListResponse> {
+        Ok(self.list_with_response().await?.body)
+    }
+    /**
+     * List Domains.
+     *
+     * This function performs a `GET` to the `/api/v1/domains` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DomainListResponse>> {
         let url = self.client.url("/api/v1/domains", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -38,9 +50,22 @@ impl Domains {
      * Creates your domain.
      */
     pub async fn create(&self, body: &crate::types::Domain) -> ClientResult<crate::types::Domain> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Create Domain.
+     *
+     * This function performs a `POST` to the `/api/v1/domains` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::Domain,
+    ) -> ClientResult<crate::Response<crate::types::Domain>> {
         let url = self.client.url("/api/v1/domains", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -61,6 +86,19 @@ impl Domains {
      * * `domain_id: &str`
      */
     pub async fn get(&self, domain_id: &str) -> ClientResult<crate::types::Domain> {
+        Ok(self.get_with_response(domain_id).await?.body)
+    }
+    /**
+     * Get Domain.
+     *
+     * This function performs a `GET` to the `/api/v1/domains/{domainId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        domain_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domain>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/domains/{}",
@@ -69,7 +107,7 @@ impl Domains {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -90,6 +128,17 @@ impl Domains {
      * * `domain_id: &str`
      */
     pub async fn delete(&self, domain_id: &str) -> ClientResult<()> {
+        self.delete_with_response(domain_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete Domain.
+     *
+     * This function performs a `DELETE` to the `/api/v1/domains/{domainId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, domain_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/domains/{}",
@@ -98,7 +147,7 @@ impl Domains {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -123,6 +172,22 @@ impl Domains {
         domain_id: &str,
         body: &crate::types::DomainCertificate,
     ) -> ClientResult<()> {
+        self.create_certificate_with_response(domain_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Create Certificate.
+     *
+     * This function performs a `PUT` to the `/api/v1/domains/{domainId}/certificate` endpoint.
+     *
+     * As opposed to `create_certificate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_certificate_with_response(
+        &self,
+        domain_id: &str,
+        body: &crate::types::DomainCertificate,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/domains/{}/certificate",
@@ -131,7 +196,7 @@ impl Domains {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -152,6 +217,19 @@ impl Domains {
      * * `domain_id: &str`
      */
     pub async fn verify(&self, domain_id: &str) -> ClientResult<crate::types::Domain> {
+        Ok(self.verify_with_response(domain_id).await?.body)
+    }
+    /**
+     * Verify Domain.
+     *
+     * This function performs a `POST` to the `/api/v1/domains/{domainId}/verify` endpoint.
+     *
+     * As opposed to `verify`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn verify_with_response(
+        &self,
+        domain_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domain>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/domains/{}/verify",
@@ -160,7 +238,7 @@ impl Domains {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
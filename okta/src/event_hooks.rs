@@ -17,9 +17,19 @@ impl EventHooks {
      * Success
      */
     pub async fn list(&self) -> ClientResult<Vec<crate::types::EventHook>> {
+        Ok(self.list_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::EventHook>>> {
         let url = self.client.url("/api/v1/eventHooks", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -47,6 +57,61 @@ impl EventHooks {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::EventHook>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/api/v1/eventHooks", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::EventHook>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::EventHook>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/eventHooks` endpoint.
      *
@@ -56,9 +121,20 @@ impl EventHooks {
         &self,
         body: &crate::types::EventHook,
     ) -> ClientResult<crate::types::EventHook> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::EventHook,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
         let url = self.client.url("/api/v1/eventHooks", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -77,6 +153,17 @@ impl EventHooks {
      * * `event_hook_id: &str`
      */
     pub async fn get(&self, event_hook_id: &str) -> ClientResult<crate::types::EventHook> {
+        Ok(self.get_with_response(event_hook_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks/{eventHookId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/eventHooks/{}",
@@ -85,7 +172,7 @@ impl EventHooks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -108,6 +195,18 @@ impl EventHooks {
         event_hook_id: &str,
         body: &crate::types::EventHook,
     ) -> ClientResult<crate::types::EventHook> {
+        Ok(self.update_with_response(event_hook_id, body).await?.body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/eventHooks/{eventHookId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        event_hook_id: &str,
+        body: &crate::types::EventHook,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/eventHooks/{}",
@@ -116,7 +215,7 @@ impl EventHooks {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -135,6 +234,18 @@ impl EventHooks {
      * * `event_hook_id: &str`
      */
     pub async fn delete(&self, event_hook_id: &str) -> ClientResult<()> {
+        self.delete_with_response(event_hook_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/eventHooks/{eventHookId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/eventHooks/{}",
@@ -143,7 +254,7 @@ impl EventHooks {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -162,6 +273,17 @@ impl EventHooks {
      * * `event_hook_id: &str`
      */
     pub async fn activate(&self, event_hook_id: &str) -> ClientResult<crate::types::EventHook> {
+        Ok(self.activate_with_response(event_hook_id).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/eventHooks/{eventHookId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_with_response(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/eventHooks/{}/lifecycle/activate",
@@ -170,7 +292,7 @@ impl EventHooks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -189,6 +311,17 @@ impl EventHooks {
      * * `event_hook_id: &str`
      */
     pub async fn deactivate(&self, event_hook_id: &str) -> ClientResult<crate::types::EventHook> {
+        Ok(self.deactivate_with_response(event_hook_id).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/eventHooks/{eventHookId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_with_response(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/eventHooks/{}/lifecycle/deactivate",
@@ -197,7 +330,7 @@ impl EventHooks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -216,6 +349,17 @@ impl EventHooks {
      * * `event_hook_id: &str`
      */
     pub async fn verify(&self, event_hook_id: &str) -> ClientResult<crate::types::EventHook> {
+        Ok(self.verify_with_response(event_hook_id).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/eventHooks/{eventHookId}/lifecycle/verify` endpoint.
+     *
+     * As opposed to `verify`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn verify_with_response(
+        &self,
+        event_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::EventHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/eventHooks/{}/lifecycle/verify",
@@ -224,7 +368,7 @@ impl EventHooks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -17,9 +17,19 @@ impl Features {
      * Success
      */
     pub async fn list(&self) -> ClientResult<Vec<crate::types::Feature>> {
+        Ok(self.list_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
         let url = self.client.url("/api/v1/features", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -47,6 +57,60 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Feature>>> + Unpin + '_
+    {
+        let url = self.client.url("/api/v1/features", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Feature>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(None))
+    }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}` endpoint.
      *
@@ -57,6 +121,17 @@ impl Features {
      * * `feature_id: &str`
      */
     pub async fn get(&self, feature_id: &str) -> ClientResult<crate::types::Feature> {
+        Ok(self.get_with_response(feature_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        feature_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/features/{}",
@@ -65,7 +140,7 @@ impl Features {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -87,6 +162,17 @@ impl Features {
         &self,
         feature_id: &str,
     ) -> ClientResult<Vec<crate::types::Feature>> {
+        Ok(self.list_dependencies_with_response(feature_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependencies` endpoint.
+     *
+     * As opposed to `list_dependencies`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_dependencies_with_response(
+        &self,
+        feature_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/features/{}/dependencies",
@@ -95,7 +181,7 @@ impl Features {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -132,6 +218,69 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependencies` endpoint.
+     *
+     * As opposed to `list_all_dependencies`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_dependencies_pages_stream(
+        &self,
+        feature_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Feature>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/features/{}/dependencies",
+                crate::progenitor_support::encode_path(feature_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Feature>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependencies` endpoint.
+     *
+     * As opposed to `list_all_dependencies`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_dependencies_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_dependencies_pages_stream(feature_id, None))
+    }
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
      *
@@ -145,6 +294,17 @@ impl Features {
         &self,
         feature_id: &str,
     ) -> ClientResult<Vec<crate::types::Feature>> {
+        Ok(self.list_dependents_with_response(feature_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
+     *
+     * As opposed to `list_dependents`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_dependents_with_response(
+        &self,
+        feature_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Feature>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/features/{}/dependents",
@@ -153,7 +313,7 @@ impl Features {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -190,6 +350,69 @@ impl Features {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
+     *
+     * As opposed to `list_all_dependents`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_dependents_pages_stream(
+        &self,
+        feature_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Feature>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/features/{}/dependents",
+                crate::progenitor_support::encode_path(feature_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Feature>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
+     *
+     * As opposed to `list_all_dependents`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_dependents_stream(
+        &self,
+        feature_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Feature>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_dependents_pages_stream(feature_id, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/features/{featureId}/{lifecycle}` endpoint.
      *
@@ -207,6 +430,22 @@ impl Features {
         lifecycle: &str,
         mode: &str,
     ) -> ClientResult<crate::types::Feature> {
+        Ok(self
+            .update_lifecycle_with_response(feature_id, lifecycle, mode)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/features/{featureId}/{lifecycle}` endpoint.
+     *
+     * As opposed to `update_lifecycle`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_lifecycle_with_response(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+        mode: &str,
+    ) -> ClientResult<crate::Response<crate::types::Feature>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !mode.is_empty() {
             query_args.push(("mode".to_string(), mode.to_string()));
@@ -222,7 +461,7 @@ impl Features {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
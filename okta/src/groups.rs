@@ -34,6 +34,26 @@ impl Groups {
         limit: i64,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        Ok(self
+            .list_with_response(q, search, after, limit, expand)
+            .await?
+            .body)
+    }
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        q: &str,
+        search: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -53,7 +73,7 @@ impl Groups {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/groups?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -99,6 +119,79 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        q: &str,
+        search: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Group>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/groups?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Group>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        search: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(q, search, expand, None))
+    }
     /**
      * Add Group.
      *
@@ -107,9 +200,22 @@ impl Groups {
      * Adds a new group with `OKTA_GROUP` type to your organization.
      */
     pub async fn create(&self, body: &crate::types::Group) -> ClientResult<crate::types::Group> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Add Group.
+     *
+     * This function performs a `POST` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
         let url = self.client.url("/api/v1/groups", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -139,6 +245,25 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::GroupRule>> {
+        Ok(self
+            .list_rules_with_response(limit, after, search, expand)
+            .await?
+            .body)
+    }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_rules`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_rules_with_response(
+        &self,
+        limit: i64,
+        after: &str,
+        search: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GroupRule>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -157,7 +282,7 @@ impl Groups {
             .client
             .url(&format!("/api/v1/groups/rules?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -201,6 +326,80 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_all_rules`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_rules_pages_stream(
+        &self,
+        search: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GroupRule>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/groups/rules?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GroupRule>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_all_rules`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_rules_stream(
+        &self,
+        search: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GroupRule>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_rules_pages_stream(search, expand, None))
+    }
     /**
      * Create Group Rule.
      *
@@ -212,9 +411,22 @@ impl Groups {
         &self,
         body: &crate::types::GroupRule,
     ) -> ClientResult<crate::types::GroupRule> {
+        Ok(self.create_rule_with_response(body).await?.body)
+    }
+    /**
+     * Create Group Rule.
+     *
+     * This function performs a `POST` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `create_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_rule_with_response(
+        &self,
+        body: &crate::types::GroupRule,
+    ) -> ClientResult<crate::Response<crate::types::GroupRule>> {
         let url = self.client.url("/api/v1/groups/rules", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -240,6 +452,20 @@ impl Groups {
         rule_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::GroupRule> {
+        Ok(self.get_rule_with_response(rule_id, expand).await?.body)
+    }
+    /**
+     * Get Group Rule.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `get_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_rule_with_response(
+        &self,
+        rule_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupRule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -254,7 +480,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -277,6 +503,18 @@ impl Groups {
         rule_id: &str,
         body: &crate::types::GroupRule,
     ) -> ClientResult<crate::types::GroupRule> {
+        Ok(self.update_rule_with_response(rule_id, body).await?.body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/groups/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `update_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_rule_with_response(
+        &self,
+        rule_id: &str,
+        body: &crate::types::GroupRule,
+    ) -> ClientResult<crate::Response<crate::types::GroupRule>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/rules/{}",
@@ -285,7 +523,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -307,6 +545,22 @@ impl Groups {
      * * `remove_users: bool` -- Indicates whether to keep or remove users from groups assigned by this rule.
      */
     pub async fn delete_rule(&self, rule_id: &str, remove_users: bool) -> ClientResult<()> {
+        self.delete_rule_with_response(rule_id, remove_users)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a group Rule.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `delete_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_rule_with_response(
+        &self,
+        rule_id: &str,
+        remove_users: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if remove_users {
             query_args.push(("removeUsers".to_string(), remove_users.to_string()));
@@ -321,7 +575,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -342,6 +596,20 @@ impl Groups {
      * * `rule_id: &str`
      */
     pub async fn activate_rule(&self, rule_id: &str) -> ClientResult<()> {
+        self.activate_rule_with_response(rule_id).await?;
+        Ok(())
+    }
+    /**
+     * Activate a group Rule.
+     *
+     * This function performs a `POST` to the `/api/v1/groups/rules/{ruleId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_rule_with_response(
+        &self,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/rules/{}/lifecycle/activate",
@@ -350,7 +618,7 @@ impl Groups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -371,6 +639,20 @@ impl Groups {
      * * `rule_id: &str`
      */
     pub async fn deactivate_rule(&self, rule_id: &str) -> ClientResult<()> {
+        self.deactivate_rule_with_response(rule_id).await?;
+        Ok(())
+    }
+    /**
+     * Deactivate a group Rule.
+     *
+     * This function performs a `POST` to the `/api/v1/groups/rules/{ruleId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_rule_with_response(
+        &self,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/rules/{}/lifecycle/deactivate",
@@ -379,7 +661,7 @@ impl Groups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -400,6 +682,19 @@ impl Groups {
      * * `group_id: &str`
      */
     pub async fn get(&self, group_id: &str) -> ClientResult<crate::types::Group> {
+        Ok(self.get_with_response(group_id).await?.body)
+    }
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}",
@@ -408,7 +703,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -433,6 +728,20 @@ impl Groups {
         group_id: &str,
         body: &crate::types::Group,
     ) -> ClientResult<crate::types::Group> {
+        Ok(self.update_with_response(group_id, body).await?.body)
+    }
+    /**
+     * Update Group.
+     *
+     * This function performs a `PUT` to the `/api/v1/groups/{groupId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::Group,
+    ) -> ClientResult<crate::Response<crate::types::Group>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}",
@@ -441,7 +750,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -462,6 +771,17 @@ impl Groups {
      * * `group_id: &str`
      */
     pub async fn delete(&self, group_id: &str) -> ClientResult<()> {
+        self.delete_with_response(group_id).await?;
+        Ok(())
+    }
+    /**
+     * Remove Group.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, group_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}",
@@ -470,7 +790,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -498,6 +818,24 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::Application>> {
+        Ok(self
+            .list_assigned_applications_fors_with_response(group_id, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_assigned_applications_fors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_assigned_applications_fors_with_response(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Application>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -515,7 +853,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -554,6 +892,76 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_all_assigned_applications_fors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_assigned_applications_fors_pages_stream(
+        &self,
+        group_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Application>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/apps",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Application>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_all_assigned_applications_fors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_assigned_applications_fors_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Application>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_assigned_applications_fors_pages_stream(group_id, None),
+        )
+    }
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -569,6 +977,21 @@ impl Groups {
         group_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Role>> {
+        Ok(self
+            .list_assigned_roles_with_response(group_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_assigned_roles`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_assigned_roles_with_response(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -583,7 +1006,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -627,6 +1050,74 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_assigned_roles_pages_stream(
+        &self,
+        group_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Role>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles?{}",
+                crate::progenitor_support::encode_path(group_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Role>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_assigned_roles_stream(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_assigned_roles_pages_stream(group_id, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -643,6 +1134,22 @@ impl Groups {
         disable_notifications: &str,
         body: &crate::types::AssignRoleRequest,
     ) -> ClientResult<crate::types::Role> {
+        Ok(self
+            .assign_role_with_response(group_id, disable_notifications, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `assign_role`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_role_with_response(
+        &self,
+        group_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !disable_notifications.is_empty() {
             query_args.push((
@@ -660,7 +1167,7 @@ impl Groups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -684,6 +1191,18 @@ impl Groups {
         group_id: &str,
         role_id: &str,
     ) -> ClientResult<crate::types::Role> {
+        Ok(self.get_role_with_response(group_id, role_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `get_role`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_role_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}",
@@ -693,7 +1212,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -713,6 +1232,20 @@ impl Groups {
      * * `role_id: &str`
      */
     pub async fn remove_role_from(&self, group_id: &str, role_id: &str) -> ClientResult<()> {
+        self.remove_role_from_with_response(group_id, role_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `remove_role_from`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_role_from_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}",
@@ -722,7 +1255,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -750,6 +1283,25 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::CatalogApplication>> {
+        Ok(self
+            .list_application_targets_for_administrator_roles_with_response(
+                group_id, role_id, after, limit,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_application_targets_for_administrator_roles`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_application_targets_for_administrator_roles_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -768,7 +1320,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -807,6 +1359,79 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_pages_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CatalogApplication>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CatalogApplication>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_application_targets_for_administrator_roles_pages_stream(
+                group_id, role_id, None,
+            ),
+        )
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
      *
@@ -824,6 +1449,21 @@ impl Groups {
         role_id: &str,
         app_name: &str,
     ) -> ClientResult<()> {
+        self.add_application_target_admin_role_given_with_response(group_id, role_id, app_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
+     *
+     * As opposed to `add_application_target_admin_role_given`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_application_target_admin_role_given_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
@@ -834,7 +1474,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -860,6 +1500,23 @@ impl Groups {
         role_id: &str,
         app_name: &str,
     ) -> ClientResult<()> {
+        self.remove_application_target_from_administrator_role_given_with_response(
+            group_id, role_id, app_name,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_given`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_application_target_from_administrator_role_given_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
@@ -870,7 +1527,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -900,6 +1557,29 @@ impl Groups {
         app_name: &str,
         application_id: &str,
     ) -> ClientResult<()> {
+        self.add_application_instance_target_app_admin_role_given_with_response(
+            group_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Add App Instance Target to App Administrator Role given to a Group.
+     *
+     * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}` endpoint.
+     *
+     * As opposed to `add_application_instance_target_app_admin_role_given`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_application_instance_target_app_admin_role_given_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
@@ -911,7 +1591,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -941,6 +1621,29 @@ impl Groups {
         app_name: &str,
         application_id: &str,
     ) -> ClientResult<()> {
+        self.remove_application_target_from_administrator_role_given_groups_with_response(
+            group_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Remove App Instance Target to App Administrator Role given to a Group.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_given_groups`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_application_target_from_administrator_role_given_groups_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
@@ -952,7 +1655,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -980,6 +1683,23 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        Ok(self
+            .list_targets_for_roles_with_response(group_id, role_id, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_targets_for_roles`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_targets_for_roles_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -998,7 +1718,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1037,6 +1757,71 @@ impl Groups {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_targets_for_roles`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_targets_for_roles_pages_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Group>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(group_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Group>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_targets_for_roles`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_targets_for_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_targets_for_roles_pages_stream(group_id, role_id, None),
+        )
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
      *
@@ -1054,6 +1839,21 @@ impl Groups {
         role_id: &str,
         target_group_id: &str,
     ) -> ClientResult<()> {
+        self.add_target_administrator_role_for_with_response(group_id, role_id, target_group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
+     *
+     * As opposed to `add_target_administrator_role_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_target_administrator_role_for_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/groups/{}",
@@ -1064,7 +1864,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1090,6 +1890,25 @@ impl Groups {
         role_id: &str,
         target_group_id: &str,
     ) -> ClientResult<()> {
+        self.remove_target_from_administrator_role_given_with_response(
+            group_id,
+            role_id,
+            target_group_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
+     *
+     * As opposed to `remove_target_from_administrator_role_given`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_target_from_administrator_role_given_with_response(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/roles/{}/targets/groups/{}",
@@ -1100,7 +1919,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1128,6 +1947,24 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::User>> {
+        Ok(self
+            .list_users_with_response(group_id, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_users_with_response(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1145,7 +1982,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1181,6 +2018,70 @@ impl Groups {
             )
             .await
     }
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_users_pages_stream(
+        &self,
+        group_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::User>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/groups/{}/users",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::User>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_users_pages_stream(group_id, None))
+    }
     /**
      * Add User to Group.
      *
@@ -1194,6 +2095,21 @@ impl Groups {
      * * `user_id: &str`
      */
     pub async fn add_user(&self, group_id: &str, user_id: &str) -> ClientResult<()> {
+        self.add_user_with_response(group_id, user_id).await?;
+        Ok(())
+    }
+    /**
+     * Add User to Group.
+     *
+     * This function performs a `PUT` to the `/api/v1/groups/{groupId}/users/{userId}` endpoint.
+     *
+     * As opposed to `add_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_user_with_response(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/users/{}",
@@ -1203,7 +2119,7 @@ impl Groups {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1225,6 +2141,22 @@ impl Groups {
      * * `user_id: &str`
      */
     pub async fn remove_user_from(&self, group_id: &str, user_id: &str) -> ClientResult<()> {
+        self.remove_user_from_with_response(group_id, user_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove User from Group.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/users/{userId}` endpoint.
+     *
+     * As opposed to `remove_user_from`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_user_from_with_response(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/groups/{}/users/{}",
@@ -1234,7 +2166,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
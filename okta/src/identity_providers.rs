@@ -32,6 +32,22 @@ impl IdentityProviders {
         limit: i64,
         type_: &str,
     ) -> ClientResult<Vec<crate::types::IdentityProvider>> {
+        Ok(self.list_with_response(q, after, limit, type_).await?.body)
+    }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -48,7 +64,7 @@ impl IdentityProviders {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/idps?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -90,6 +106,79 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        q: &str,
+        type_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IdentityProvider>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/idps?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IdentityProvider>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pages_stream(q, type_, None))
+    }
     /**
      * Add Identity Provider.
      *
@@ -101,9 +190,22 @@ impl IdentityProviders {
         &self,
         body: &crate::types::IdentityProvider,
     ) -> ClientResult<crate::types::IdentityProvider> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Add Identity Provider.
+     *
+     * This function performs a `POST` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::IdentityProvider,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
         let url = self.client.url("/api/v1/idps", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -129,6 +231,20 @@ impl IdentityProviders {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        Ok(self.list_keys_with_response(after, limit).await?.body)
+    }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_keys`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_keys_with_response(
+        &self,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -141,7 +257,7 @@ impl IdentityProviders {
             .client
             .url(&format!("/api/v1/idps/credentials/keys?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -171,6 +287,65 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_keys_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::JsonWebKey>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/api/v1/idps/credentials/keys", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::JsonWebKey>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_keys_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_keys_pages_stream(None))
+    }
     /**
      * Add X.509 Certificate Public Key.
      *
@@ -182,9 +357,22 @@ impl IdentityProviders {
         &self,
         body: &crate::types::JsonWebKey,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self.create_key_with_response(body).await?.body)
+    }
+    /**
+     * Add X.509 Certificate Public Key.
+     *
+     * This function performs a `POST` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `create_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_key_with_response(
+        &self,
+        body: &crate::types::JsonWebKey,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let url = self.client.url("/api/v1/idps/credentials/keys", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -205,6 +393,19 @@ impl IdentityProviders {
      * * `key_id: &str`
      */
     pub async fn get_key(&self, key_id: &str) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self.get_key_with_response(key_id).await?.body)
+    }
+    /**
+     * Get Key.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys/{keyId}` endpoint.
+     *
+     * As opposed to `get_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_key_with_response(
+        &self,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/credentials/keys/{}",
@@ -213,7 +414,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -234,6 +435,20 @@ impl IdentityProviders {
      * * `key_id: &str`
      */
     pub async fn delete_key(&self, key_id: &str) -> ClientResult<()> {
+        self.delete_key_with_response(key_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete Key.
+     *
+     * This function performs a `DELETE` to the `/api/v1/idps/credentials/keys/{keyId}` endpoint.
+     *
+     * As opposed to `delete_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_key_with_response(
+        &self,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/credentials/keys/{}",
@@ -242,7 +457,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -263,6 +478,19 @@ impl IdentityProviders {
      * * `idp_id: &str`
      */
     pub async fn get(&self, idp_id: &str) -> ClientResult<crate::types::IdentityProvider> {
+        Ok(self.get_with_response(idp_id).await?.body)
+    }
+    /**
+     * Get Identity Provider.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}",
@@ -271,7 +499,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -296,6 +524,20 @@ impl IdentityProviders {
         idp_id: &str,
         body: &crate::types::IdentityProvider,
     ) -> ClientResult<crate::types::IdentityProvider> {
+        Ok(self.update_with_response(idp_id, body).await?.body)
+    }
+    /**
+     * Update Identity Provider.
+     *
+     * This function performs a `PUT` to the `/api/v1/idps/{idpId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        idp_id: &str,
+        body: &crate::types::IdentityProvider,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}",
@@ -304,7 +546,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -325,6 +567,17 @@ impl IdentityProviders {
      * * `idp_id: &str`
      */
     pub async fn delete(&self, idp_id: &str) -> ClientResult<()> {
+        self.delete_with_response(idp_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete Identity Provider.
+     *
+     * This function performs a `DELETE` to the `/api/v1/idps/{idpId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, idp_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}",
@@ -333,7 +586,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -354,6 +607,19 @@ impl IdentityProviders {
      * * `idp_id: &str`
      */
     pub async fn list_csrs_fors(&self, idp_id: &str) -> ClientResult<Vec<crate::types::Csr>> {
+        Ok(self.list_csrs_fors_with_response(idp_id).await?.body)
+    }
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_csrs_fors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_csrs_fors_with_response(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Csr>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/csrs",
@@ -362,7 +628,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -398,6 +664,70 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_csrs_fors_pages_stream(
+        &self,
+        idp_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Csr>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/csrs",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Csr>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Csr>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_csrs_fors_pages_stream(idp_id, None))
+    }
     /**
      * Generate Certificate Signing Request for IdP.
      *
@@ -414,6 +744,23 @@ impl IdentityProviders {
         idp_id: &str,
         body: &crate::types::CsrMetadata,
     ) -> ClientResult<crate::types::Csr> {
+        Ok(self
+            .generate_csr_for_with_response(idp_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Generate Certificate Signing Request for IdP.
+     *
+     * This function performs a `POST` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `generate_csr_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn generate_csr_for_with_response(
+        &self,
+        idp_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/csrs",
@@ -422,7 +769,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -442,6 +789,18 @@ impl IdentityProviders {
      * * `csr_id: &str`
      */
     pub async fn get_csr_for(&self, idp_id: &str, csr_id: &str) -> ClientResult<crate::types::Csr> {
+        Ok(self.get_csr_for_with_response(idp_id, csr_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs/{csrId}` endpoint.
+     *
+     * As opposed to `get_csr_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_csr_for_with_response(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Csr>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/csrs/{}",
@@ -451,7 +810,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -471,6 +830,19 @@ impl IdentityProviders {
      * * `csr_id: &str`
      */
     pub async fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> ClientResult<()> {
+        self.revoke_csr_for_with_response(idp_id, csr_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/idps/{idpId}/credentials/csrs/{csrId}` endpoint.
+     *
+     * As opposed to `revoke_csr_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_csr_for_with_response(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/csrs/{}",
@@ -480,7 +852,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -504,6 +876,21 @@ impl IdentityProviders {
         idp_id: &str,
         csr_id: &str,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self
+            .post_idp_credentials_csr_lifecycle_publish_with_response(idp_id, csr_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/idps/{idpId}/credentials/csrs/{csrId}/lifecycle/publish` endpoint.
+     *
+     * As opposed to `post_idp_credentials_csr_lifecycle_publish`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_idp_credentials_csr_lifecycle_publish_with_response(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/csrs/{}/lifecycle/publish",
@@ -513,7 +900,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -537,6 +924,19 @@ impl IdentityProviders {
         &self,
         idp_id: &str,
     ) -> ClientResult<Vec<crate::types::JsonWebKey>> {
+        Ok(self.list_signing_keys_with_response(idp_id).await?.body)
+    }
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_signing_keys`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_signing_keys_with_response(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JsonWebKey>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/keys",
@@ -545,7 +945,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -584,6 +984,74 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_signing_keys`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_signing_keys_pages_stream(
+        &self,
+        idp_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::JsonWebKey>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/credentials/keys",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::JsonWebKey>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_signing_keys`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_signing_keys_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JsonWebKey>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_signing_keys_pages_stream(idp_id, None))
+    }
     /**
      * Generate New IdP Signing Key Credential.
      *
@@ -601,6 +1069,23 @@ impl IdentityProviders {
         idp_id: &str,
         validity_years: i64,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self
+            .generate_signing_key_with_response(idp_id, validity_years)
+            .await?
+            .body)
+    }
+    /**
+     * Generate New IdP Signing Key Credential.
+     *
+     * This function performs a `POST` to the `/api/v1/idps/{idpId}/credentials/keys/generate` endpoint.
+     *
+     * As opposed to `generate_signing_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn generate_signing_key_with_response(
+        &self,
+        idp_id: &str,
+        validity_years: i64,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if validity_years > 0 {
             query_args.push(("validityYears".to_string(), validity_years.to_string()));
@@ -615,7 +1100,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -641,6 +1126,23 @@ impl IdentityProviders {
         idp_id: &str,
         key_id: &str,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self
+            .get_signing_key_with_response(idp_id, key_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get Signing Key Credential for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys/{keyId}` endpoint.
+     *
+     * As opposed to `get_signing_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_signing_key_with_response(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/credentials/keys/{}",
@@ -650,7 +1152,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -678,6 +1180,24 @@ impl IdentityProviders {
         key_id: &str,
         target_idp_id: &str,
     ) -> ClientResult<crate::types::JsonWebKey> {
+        Ok(self
+            .clone_key_with_response(idp_id, key_id, target_idp_id)
+            .await?
+            .body)
+    }
+    /**
+     * Clone Signing Key Credential for IdP.
+     *
+     * This function performs a `POST` to the `/api/v1/idps/{idpId}/credentials/keys/{keyId}/clone` endpoint.
+     *
+     * As opposed to `clone_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn clone_key_with_response(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+        target_idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JsonWebKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !target_idp_id.is_empty() {
             query_args.push(("targetIdpId".to_string(), target_idp_id.to_string()));
@@ -693,7 +1213,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -714,6 +1234,19 @@ impl IdentityProviders {
      * * `idp_id: &str`
      */
     pub async fn activate(&self, idp_id: &str) -> ClientResult<crate::types::IdentityProvider> {
+        Ok(self.activate_with_response(idp_id).await?.body)
+    }
+    /**
+     * Activate Identity Provider.
+     *
+     * This function performs a `POST` to the `/api/v1/idps/{idpId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_with_response(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/lifecycle/activate",
@@ -722,7 +1255,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -743,6 +1276,19 @@ impl IdentityProviders {
      * * `idp_id: &str`
      */
     pub async fn deactivate(&self, idp_id: &str) -> ClientResult<crate::types::IdentityProvider> {
+        Ok(self.deactivate_with_response(idp_id).await?.body)
+    }
+    /**
+     * Deactivate Identity Provider.
+     *
+     * This function performs a `POST` to the `/api/v1/idps/{idpId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_with_response(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProvider>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/lifecycle/deactivate",
@@ -751,7 +1297,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -775,6 +1321,22 @@ impl IdentityProviders {
         &self,
         idp_id: &str,
     ) -> ClientResult<Vec<crate::types::IdentityProviderApplicationUser>> {
+        Ok(self
+            .list_application_users_with_response(idp_id)
+            .await?
+            .body)
+    }
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_application_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_application_users_with_response(
+        &self,
+        idp_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProviderApplicationUser>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/users",
@@ -783,7 +1345,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -822,6 +1384,77 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_all_application_users`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_application_users_pages_stream(
+        &self,
+        idp_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::IdentityProviderApplicationUser>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users",
+                crate::progenitor_support::encode_path(idp_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IdentityProviderApplicationUser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_all_application_users`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_application_users_stream(
+        &self,
+        idp_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProviderApplicationUser>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.list_all_application_users_pages_stream(idp_id, None))
+    }
     /**
      * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
      *
@@ -837,6 +1470,21 @@ impl IdentityProviders {
         idp_id: &str,
         user_id: &str,
     ) -> ClientResult<crate::types::IdentityProviderApplicationUser> {
+        Ok(self
+            .get_application_user_with_response(idp_id, user_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
+     *
+     * As opposed to `get_application_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_application_user_with_response(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProviderApplicationUser>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/users/{}",
@@ -846,7 +1494,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -873,6 +1521,24 @@ impl IdentityProviders {
         user_id: &str,
         body: &crate::types::UserIdentityProviderLinkRequest,
     ) -> ClientResult<crate::types::IdentityProviderApplicationUser> {
+        Ok(self
+            .link_user_with_response(idp_id, user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Link a user to a Social IdP without a transaction.
+     *
+     * This function performs a `POST` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
+     *
+     * As opposed to `link_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn link_user_with_response(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        body: &crate::types::UserIdentityProviderLinkRequest,
+    ) -> ClientResult<crate::Response<crate::types::IdentityProviderApplicationUser>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/users/{}",
@@ -882,7 +1548,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -904,6 +1570,21 @@ impl IdentityProviders {
      * * `user_id: &str`
      */
     pub async fn unlink_user_from(&self, idp_id: &str, user_id: &str) -> ClientResult<()> {
+        self.unlink_user_from_with_response(idp_id, user_id).await?;
+        Ok(())
+    }
+    /**
+     * Unlink User from IdP.
+     *
+     * This function performs a `DELETE` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
+     *
+     * As opposed to `unlink_user_from`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unlink_user_from_with_response(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/users/{}",
@@ -913,7 +1594,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -939,6 +1620,23 @@ impl IdentityProviders {
         idp_id: &str,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::SocialAuthToken>> {
+        Ok(self
+            .list_social_auth_tokens_with_response(idp_id, user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_social_auth_tokens`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_social_auth_tokens_with_response(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SocialAuthToken>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/idps/{}/users/{}/credentials/tokens",
@@ -948,7 +1646,7 @@ impl IdentityProviders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -989,4 +1687,77 @@ impl IdentityProviders {
             )
             .await
     }
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_all_social_auth_tokens`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_social_auth_tokens_pages_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SocialAuthToken>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/idps/{}/users/{}/credentials/tokens",
+                crate::progenitor_support::encode_path(idp_id),
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SocialAuthToken>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_all_social_auth_tokens`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SocialAuthToken>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_social_auth_tokens_pages_stream(idp_id, user_id, None),
+        )
+    }
 }
@@ -21,6 +21,17 @@ impl InlineHooks {
      * * `type_: &str`
      */
     pub async fn list(&self, type_: &str) -> ClientResult<Vec<crate::types::InlineHook>> {
+        Ok(self.list_with_response(type_).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InlineHook>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -30,7 +41,7 @@ impl InlineHooks {
             .client
             .url(&format!("/api/v1/inlineHooks?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,6 +76,71 @@ impl InlineHooks {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        type_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InlineHook>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/inlineHooks?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::InlineHook>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InlineHook>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(type_, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/inlineHooks` endpoint.
      *
@@ -74,9 +150,20 @@ impl InlineHooks {
         &self,
         body: &crate::types::InlineHook,
     ) -> ClientResult<crate::types::InlineHook> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::InlineHook,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
         let url = self.client.url("/api/v1/inlineHooks", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -95,6 +182,17 @@ impl InlineHooks {
      * * `inline_hook_id: &str`
      */
     pub async fn get(&self, inline_hook_id: &str) -> ClientResult<crate::types::InlineHook> {
+        Ok(self.get_with_response(inline_hook_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks/{inlineHookId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        inline_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/inlineHooks/{}",
@@ -103,7 +201,7 @@ impl InlineHooks {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -126,6 +224,18 @@ impl InlineHooks {
         inline_hook_id: &str,
         body: &crate::types::InlineHook,
     ) -> ClientResult<crate::types::InlineHook> {
+        Ok(self.update_with_response(inline_hook_id, body).await?.body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/inlineHooks/{inlineHookId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHook,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/inlineHooks/{}",
@@ -134,7 +244,7 @@ impl InlineHooks {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -153,6 +263,18 @@ impl InlineHooks {
      * * `inline_hook_id: &str`
      */
     pub async fn delete(&self, inline_hook_id: &str) -> ClientResult<()> {
+        self.delete_with_response(inline_hook_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/inlineHooks/{inlineHookId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        inline_hook_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/inlineHooks/{}",
@@ -161,7 +283,7 @@ impl InlineHooks {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -184,6 +306,18 @@ impl InlineHooks {
         inline_hook_id: &str,
         body: &crate::types::Links,
     ) -> ClientResult<crate::types::InlineHookResponse> {
+        Ok(self.execute_with_response(inline_hook_id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/inlineHooks/{inlineHookId}/execute` endpoint.
+     *
+     * As opposed to `execute`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn execute_with_response(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::Links,
+    ) -> ClientResult<crate::Response<crate::types::InlineHookResponse>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/inlineHooks/{}/execute",
@@ -192,7 +326,7 @@ impl InlineHooks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -211,6 +345,17 @@ impl InlineHooks {
      * * `inline_hook_id: &str`
      */
     pub async fn activate(&self, inline_hook_id: &str) -> ClientResult<crate::types::InlineHook> {
+        Ok(self.activate_with_response(inline_hook_id).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/inlineHooks/{inlineHookId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_with_response(
+        &self,
+        inline_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/inlineHooks/{}/lifecycle/activate",
@@ -219,7 +364,7 @@ impl InlineHooks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -238,6 +383,17 @@ impl InlineHooks {
      * * `inline_hook_id: &str`
      */
     pub async fn deactivate(&self, inline_hook_id: &str) -> ClientResult<crate::types::InlineHook> {
+        Ok(self.deactivate_with_response(inline_hook_id).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/inlineHooks/{inlineHookId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_with_response(
+        &self,
+        inline_hook_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::InlineHook>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/inlineHooks/{}/lifecycle/deactivate",
@@ -246,7 +402,7 @@ impl InlineHooks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -17,11 +17,21 @@ impl LinkedObjects {
      * Success
      */
     pub async fn list_definitions(&self) -> ClientResult<Vec<crate::types::LinkedObject>> {
+        Ok(self.list_definitions_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `list_definitions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_definitions_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LinkedObject>>> {
         let url = self
             .client
             .url("/api/v1/meta/schemas/user/linkedObjects", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,6 +61,63 @@ impl LinkedObjects {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `list_all_definitions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_definitions_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::LinkedObject>>>
+           + Unpin
+           + '_ {
+        let url = self
+            .client
+            .url("/api/v1/meta/schemas/user/linkedObjects", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::LinkedObject>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `list_all_definitions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_definitions_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LinkedObject>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_definitions_pages_stream(None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
      *
@@ -60,11 +127,22 @@ impl LinkedObjects {
         &self,
         body: &crate::types::LinkedObject,
     ) -> ClientResult<crate::types::LinkedObject> {
+        Ok(self.add_definition_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `add_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_definition_with_response(
+        &self,
+        body: &crate::types::LinkedObject,
+    ) -> ClientResult<crate::Response<crate::types::LinkedObject>> {
         let url = self
             .client
             .url("/api/v1/meta/schemas/user/linkedObjects", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -86,6 +164,20 @@ impl LinkedObjects {
         &self,
         linked_object_name: &str,
     ) -> ClientResult<crate::types::LinkedObject> {
+        Ok(self
+            .get_definition_with_response(linked_object_name)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}` endpoint.
+     *
+     * As opposed to `get_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_definition_with_response(
+        &self,
+        linked_object_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::LinkedObject>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/schemas/user/linkedObjects/{}",
@@ -94,7 +186,7 @@ impl LinkedObjects {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -113,6 +205,19 @@ impl LinkedObjects {
      * * `linked_object_name: &str`
      */
     pub async fn delete_definition(&self, linked_object_name: &str) -> ClientResult<()> {
+        self.delete_definition_with_response(linked_object_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}` endpoint.
+     *
+     * As opposed to `delete_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_definition_with_response(
+        &self,
+        linked_object_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/schemas/user/linkedObjects/{}",
@@ -121,7 +226,7 @@ impl LinkedObjects {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
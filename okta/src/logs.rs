@@ -38,6 +38,28 @@ impl Logs {
         sort_order: &str,
         after: &str,
     ) -> ClientResult<Vec<crate::types::LogEvent>> {
+        Ok(self
+            .get_page_with_response(since, until, filter, q, limit, sort_order, after)
+            .await?
+            .body)
+    }
+    /**
+     * Fetch a list of events from your Okta organization system log.
+     *
+     * This function performs a `GET` to the `/api/v1/logs` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        limit: i64,
+        sort_order: &str,
+        after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LogEvent>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -63,7 +85,7 @@ impl Logs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/logs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -117,4 +139,92 @@ impl Logs {
             )
             .await
     }
+    /**
+     * Fetch a list of events from your Okta organization system log.
+     *
+     * This function performs a `GET` to the `/api/v1/logs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        sort_order: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::LogEvent>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        if !sort_order.is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = until {
+            query_args.push(("until".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/logs?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::LogEvent>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Fetch a list of events from your Okta organization system log.
+     *
+     * This function performs a `GET` to the `/api/v1/logs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        sort_order: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LogEvent>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(since, until, filter, q, sort_order, None),
+        )
+    }
 }
@@ -30,6 +30,21 @@ impl NetworkZones {
         limit: i64,
         filter: &str,
     ) -> ClientResult<Vec<crate::types::NetworkZone>> {
+        Ok(self.list_with_response(after, limit, filter).await?.body)
+    }
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        after: &str,
+        limit: i64,
+        filter: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::NetworkZone>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -43,7 +58,7 @@ impl NetworkZones {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/zones?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -78,6 +93,73 @@ impl NetworkZones {
             )
             .await
     }
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        filter: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::NetworkZone>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/zones?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::NetworkZone>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::NetworkZone>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(filter, None))
+    }
     /**
      * Add Network Zone.
      *
@@ -89,9 +171,22 @@ impl NetworkZones {
         &self,
         body: &crate::types::NetworkZone,
     ) -> ClientResult<crate::types::NetworkZone> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Add Network Zone.
+     *
+     * This function performs a `POST` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::NetworkZone,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
         let url = self.client.url("/api/v1/zones", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -112,6 +207,19 @@ impl NetworkZones {
      * * `zone_id: &str`
      */
     pub async fn get(&self, zone_id: &str) -> ClientResult<crate::types::NetworkZone> {
+        Ok(self.get_with_response(zone_id).await?.body)
+    }
+    /**
+     * Get Network Zone.
+     *
+     * This function performs a `GET` to the `/api/v1/zones/{zoneId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        zone_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/zones/{}",
@@ -120,7 +228,7 @@ impl NetworkZones {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -145,6 +253,20 @@ impl NetworkZones {
         zone_id: &str,
         body: &crate::types::NetworkZone,
     ) -> ClientResult<crate::types::NetworkZone> {
+        Ok(self.update_with_response(zone_id, body).await?.body)
+    }
+    /**
+     * Update Network Zone.
+     *
+     * This function performs a `PUT` to the `/api/v1/zones/{zoneId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        zone_id: &str,
+        body: &crate::types::NetworkZone,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/zones/{}",
@@ -153,7 +275,7 @@ impl NetworkZones {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -174,6 +296,17 @@ impl NetworkZones {
      * * `zone_id: &str`
      */
     pub async fn delete(&self, zone_id: &str) -> ClientResult<()> {
+        self.delete_with_response(zone_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete Network Zone.
+     *
+     * This function performs a `DELETE` to the `/api/v1/zones/{zoneId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, zone_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/zones/{}",
@@ -182,7 +315,7 @@ impl NetworkZones {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -203,6 +336,19 @@ impl NetworkZones {
      * * `zone_id: &str`
      */
     pub async fn activate(&self, zone_id: &str) -> ClientResult<crate::types::NetworkZone> {
+        Ok(self.activate_with_response(zone_id).await?.body)
+    }
+    /**
+     * Activate Network Zone.
+     *
+     * This function performs a `POST` to the `/api/v1/zones/{zoneId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_with_response(
+        &self,
+        zone_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/zones/{}/lifecycle/activate",
@@ -211,7 +357,7 @@ impl NetworkZones {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -232,6 +378,19 @@ impl NetworkZones {
      * * `zone_id: &str`
      */
     pub async fn deactivate(&self, zone_id: &str) -> ClientResult<crate::types::NetworkZone> {
+        Ok(self.deactivate_with_response(zone_id).await?.body)
+    }
+    /**
+     * Deactivate Network Zone.
+     *
+     * This function performs a `POST` to the `/api/v1/zones/{zoneId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_with_response(
+        &self,
+        zone_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::NetworkZone>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/zones/{}/lifecycle/deactivate",
@@ -240,7 +399,7 @@ impl NetworkZones {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
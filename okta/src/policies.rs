@@ -28,6 +28,19 @@ impl Policies {
         status: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Policy>> {
+        Ok(self.list_with_response(type_, status, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Policy>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -43,7 +56,7 @@ impl Policies {
             .client
             .url(&format!("/api/v1/policies?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -89,6 +102,77 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Policy>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/policies?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Policy>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Policy>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(type_, status, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/policies` endpoint.
      *
@@ -103,6 +187,18 @@ impl Policies {
         activate: bool,
         body: &crate::types::Policy,
     ) -> ClientResult<crate::types::Policy> {
+        Ok(self.create_policy_with_response(activate, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `create_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_policy_with_response(
+        &self,
+        activate: bool,
+        body: &crate::types::Policy,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if activate {
             query_args.push(("activate".to_string(), activate.to_string()));
@@ -112,7 +208,7 @@ impl Policies {
             .client
             .url(&format!("/api/v1/policies?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -136,6 +232,18 @@ impl Policies {
         policy_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::Policy> {
+        Ok(self.get_policy_with_response(policy_id, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}` endpoint.
+     *
+     * As opposed to `get_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_policy_with_response(
+        &self,
+        policy_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -150,7 +258,7 @@ impl Policies {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -173,6 +281,21 @@ impl Policies {
         policy_id: &str,
         body: &crate::types::Policy,
     ) -> ClientResult<crate::types::Policy> {
+        Ok(self
+            .update_policy_with_response(policy_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/policies/{policyId}` endpoint.
+     *
+     * As opposed to `update_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_policy_with_response(
+        &self,
+        policy_id: &str,
+        body: &crate::types::Policy,
+    ) -> ClientResult<crate::Response<crate::types::Policy>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}",
@@ -181,7 +304,7 @@ impl Policies {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -200,6 +323,18 @@ impl Policies {
      * * `policy_id: &str`
      */
     pub async fn delete_policy(&self, policy_id: &str) -> ClientResult<()> {
+        self.delete_policy_with_response(policy_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/policies/{policyId}` endpoint.
+     *
+     * As opposed to `delete_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_policy_with_response(
+        &self,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}",
@@ -208,7 +343,7 @@ impl Policies {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -227,6 +362,18 @@ impl Policies {
      * * `policy_id: &str`
      */
     pub async fn activate_policy(&self, policy_id: &str) -> ClientResult<()> {
+        self.activate_policy_with_response(policy_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/policies/{policyId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_policy_with_response(
+        &self,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/lifecycle/activate",
@@ -235,7 +382,7 @@ impl Policies {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -254,6 +401,18 @@ impl Policies {
      * * `policy_id: &str`
      */
     pub async fn deactivate_policy(&self, policy_id: &str) -> ClientResult<()> {
+        self.deactivate_policy_with_response(policy_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/policies/{policyId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate_policy`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_policy_with_response(
+        &self,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/lifecycle/deactivate",
@@ -262,7 +421,7 @@ impl Policies {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -284,6 +443,17 @@ impl Policies {
         &self,
         policy_id: &str,
     ) -> ClientResult<Vec<crate::types::PolicyRule>> {
+        Ok(self.list_policy_rules_with_response(policy_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_policy_rules`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_policy_rules_with_response(
+        &self,
+        policy_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PolicyRule>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules",
@@ -292,7 +462,7 @@ impl Policies {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -329,6 +499,70 @@ impl Policies {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_policy_rules_pages_stream(
+        &self,
+        policy_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PolicyRule>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/policies/{}/rules",
+                crate::progenitor_support::encode_path(policy_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PolicyRule>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_policy_rules_stream(
+        &self,
+        policy_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PolicyRule>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_policy_rules_pages_stream(policy_id, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules` endpoint.
      *
@@ -343,6 +577,21 @@ impl Policies {
         policy_id: &str,
         body: &crate::types::PolicyRule,
     ) -> ClientResult<crate::types::PolicyRule> {
+        Ok(self
+            .create_policy_rule_with_response(policy_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `create_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::PolicyRule>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules",
@@ -351,7 +600,7 @@ impl Policies {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -375,6 +624,21 @@ impl Policies {
         policy_id: &str,
         rule_id: &str,
     ) -> ClientResult<crate::types::PolicyRule> {
+        Ok(self
+            .get_policy_rule_with_response(policy_id, rule_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `get_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PolicyRule>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules/{}",
@@ -384,7 +648,7 @@ impl Policies {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -409,6 +673,22 @@ impl Policies {
         rule_id: &str,
         body: &crate::types::PolicyRule,
     ) -> ClientResult<crate::types::PolicyRule> {
+        Ok(self
+            .update_policy_rule_with_response(policy_id, rule_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `update_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> ClientResult<crate::Response<crate::types::PolicyRule>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules/{}",
@@ -418,7 +698,7 @@ impl Policies {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -438,6 +718,20 @@ impl Policies {
      * * `rule_id: &str`
      */
     pub async fn delete_policy_rule(&self, policy_id: &str, rule_id: &str) -> ClientResult<()> {
+        self.delete_policy_rule_with_response(policy_id, rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `delete_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules/{}",
@@ -447,7 +741,7 @@ impl Policies {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -467,6 +761,20 @@ impl Policies {
      * * `rule_id: &str`
      */
     pub async fn activate_policy_rule(&self, policy_id: &str, rule_id: &str) -> ClientResult<()> {
+        self.activate_policy_rule_with_response(policy_id, rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules/{}/lifecycle/activate",
@@ -476,7 +784,7 @@ impl Policies {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -496,6 +804,20 @@ impl Policies {
      * * `rule_id: &str`
      */
     pub async fn deactivate_policy_rule(&self, policy_id: &str, rule_id: &str) -> ClientResult<()> {
+        self.deactivate_policy_rule_with_response(policy_id, rule_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate_policy_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_policy_rule_with_response(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/policies/{}/rules/{}/lifecycle/deactivate",
@@ -505,7 +827,7 @@ impl Policies {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
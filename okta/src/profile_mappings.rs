@@ -30,6 +30,23 @@ impl ProfileMappings {
         source_id: &str,
         target_id: &str,
     ) -> ClientResult<Vec<crate::types::ProfileMapping>> {
+        Ok(self
+            .list_with_response(after, limit, source_id, target_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        after: &str,
+        limit: i64,
+        source_id: &str,
+        target_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProfileMapping>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -48,7 +65,7 @@ impl ProfileMappings {
             .client
             .url(&format!("/api/v1/mappings?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -90,6 +107,76 @@ impl ProfileMappings {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        source_id: &str,
+        target_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ProfileMapping>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !source_id.is_empty() {
+            query_args.push(("sourceId".to_string(), source_id.to_string()));
+        }
+        if !target_id.is_empty() {
+            query_args.push(("targetId".to_string(), target_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/mappings?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ProfileMapping>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        source_id: &str,
+        target_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProfileMapping>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(source_id, target_id, None))
+    }
     /**
      * Get Profile Mapping.
      *
@@ -102,6 +189,19 @@ impl ProfileMappings {
      * * `mapping_id: &str`
      */
     pub async fn get(&self, mapping_id: &str) -> ClientResult<crate::types::ProfileMapping> {
+        Ok(self.get_with_response(mapping_id).await?.body)
+    }
+    /**
+     * Get Profile Mapping.
+     *
+     * This function performs a `GET` to the `/api/v1/mappings/{mappingId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        mapping_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProfileMapping>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/mappings/{}",
@@ -110,7 +210,7 @@ impl ProfileMappings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -135,6 +235,20 @@ impl ProfileMappings {
         mapping_id: &str,
         body: &crate::types::ProfileMapping,
     ) -> ClientResult<crate::types::ProfileMapping> {
+        Ok(self.update_with_response(mapping_id, body).await?.body)
+    }
+    /**
+     * Update Profile Mapping.
+     *
+     * This function performs a `POST` to the `/api/v1/mappings/{mappingId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        mapping_id: &str,
+        body: &crate::types::ProfileMapping,
+    ) -> ClientResult<crate::Response<crate::types::ProfileMapping>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/mappings/{}",
@@ -143,7 +257,7 @@ impl ProfileMappings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -22,9 +22,22 @@ impl Sessions {
         &self,
         body: &crate::types::CreateSessionRequest,
     ) -> ClientResult<crate::types::Session> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Create Session with Session Token.
+     *
+     * This function performs a `POST` to the `/api/v1/sessions` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::CreateSessionRequest,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
         let url = self.client.url("/api/v1/sessions", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -43,6 +56,17 @@ impl Sessions {
      * * `session_id: &str`
      */
     pub async fn get(&self, session_id: &str) -> ClientResult<crate::types::Session> {
+        Ok(self.get_with_response(session_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/sessions/{sessionId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        session_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/sessions/{}",
@@ -51,7 +75,7 @@ impl Sessions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,6 +96,17 @@ impl Sessions {
      * * `session_id: &str`
      */
     pub async fn end(&self, session_id: &str) -> ClientResult<()> {
+        self.end_with_response(session_id).await?;
+        Ok(())
+    }
+    /**
+     * Close Session.
+     *
+     * This function performs a `DELETE` to the `/api/v1/sessions/{sessionId}` endpoint.
+     *
+     * As opposed to `end`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn end_with_response(&self, session_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/sessions/{}",
@@ -80,7 +115,7 @@ impl Sessions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -101,6 +136,19 @@ impl Sessions {
      * * `session_id: &str`
      */
     pub async fn refresh(&self, session_id: &str) -> ClientResult<crate::types::Session> {
+        Ok(self.refresh_with_response(session_id).await?.body)
+    }
+    /**
+     * Refresh Session.
+     *
+     * This function performs a `POST` to the `/api/v1/sessions/{sessionId}/lifecycle/refresh` endpoint.
+     *
+     * As opposed to `refresh`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn refresh_with_response(
+        &self,
+        session_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/sessions/{}/lifecycle/refresh",
@@ -109,7 +157,7 @@ impl Sessions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
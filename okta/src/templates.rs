@@ -26,6 +26,19 @@ impl Templates {
         &self,
         template_type: &str,
     ) -> ClientResult<Vec<crate::types::SmsTemplate>> {
+        Ok(self.list_sms_with_response(template_type).await?.body)
+    }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_sms`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_sms_with_response(
+        &self,
+        template_type: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SmsTemplate>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !template_type.is_empty() {
             query_args.push(("templateType".to_string(), template_type.to_string()));
@@ -35,7 +48,7 @@ impl Templates {
             .client
             .url(&format!("/api/v1/templates/sms?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -75,6 +88,75 @@ impl Templates {
             )
             .await
     }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_all_sms`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_sms_pages_stream(
+        &self,
+        template_type: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SmsTemplate>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !template_type.is_empty() {
+            query_args.push(("templateType".to_string(), template_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/templates/sms?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SmsTemplate>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_all_sms`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_sms_stream(
+        &self,
+        template_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SmsTemplate>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_sms_pages_stream(template_type, None))
+    }
     /**
      * Add SMS Template.
      *
@@ -86,9 +168,22 @@ impl Templates {
         &self,
         body: &crate::types::SmsTemplate,
     ) -> ClientResult<crate::types::SmsTemplate> {
+        Ok(self.create_sms_with_response(body).await?.body)
+    }
+    /**
+     * Add SMS Template.
+     *
+     * This function performs a `POST` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `create_sms`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_sms_with_response(
+        &self,
+        body: &crate::types::SmsTemplate,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
         let url = self.client.url("/api/v1/templates/sms", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -109,6 +204,19 @@ impl Templates {
      * * `template_id: &str`
      */
     pub async fn get_sm(&self, template_id: &str) -> ClientResult<crate::types::SmsTemplate> {
+        Ok(self.get_sm_with_response(template_id).await?.body)
+    }
+    /**
+     * Get SMS Template.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms/{templateId}` endpoint.
+     *
+     * As opposed to `get_sm`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sm_with_response(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/templates/sms/{}",
@@ -117,7 +225,7 @@ impl Templates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -142,6 +250,20 @@ impl Templates {
         template_id: &str,
         body: &crate::types::SmsTemplate,
     ) -> ClientResult<crate::types::SmsTemplate> {
+        Ok(self.update_sms_with_response(template_id, body).await?.body)
+    }
+    /**
+     * Update SMS Template.
+     *
+     * This function performs a `PUT` to the `/api/v1/templates/sms/{templateId}` endpoint.
+     *
+     * As opposed to `update_sms`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_sms_with_response(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/templates/sms/{}",
@@ -150,7 +272,7 @@ impl Templates {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -175,6 +297,23 @@ impl Templates {
         template_id: &str,
         body: &crate::types::SmsTemplate,
     ) -> ClientResult<crate::types::SmsTemplate> {
+        Ok(self
+            .partial_update_sms_with_response(template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Partial SMS Template Update.
+     *
+     * This function performs a `POST` to the `/api/v1/templates/sms/{templateId}` endpoint.
+     *
+     * As opposed to `partial_update_sms`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn partial_update_sms_with_response(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> ClientResult<crate::Response<crate::types::SmsTemplate>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/templates/sms/{}",
@@ -183,7 +322,7 @@ impl Templates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -204,6 +343,20 @@ impl Templates {
      * * `template_id: &str`
      */
     pub async fn delete_sms(&self, template_id: &str) -> ClientResult<()> {
+        self.delete_sms_with_response(template_id).await?;
+        Ok(())
+    }
+    /**
+     * Remove SMS Template.
+     *
+     * This function performs a `DELETE` to the `/api/v1/templates/sms/{templateId}` endpoint.
+     *
+     * As opposed to `delete_sms`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_sms_with_response(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/templates/sms/{}",
@@ -212,7 +365,7 @@ impl Templates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -19,9 +19,19 @@ impl ThreatInsights {
     pub async fn get_current_configuration(
         &self,
     ) -> ClientResult<crate::types::ThreatInsightConfiguration> {
+        Ok(self.get_current_configuration_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/threats/configuration` endpoint.
+     *
+     * As opposed to `get_current_configuration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_current_configuration_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ThreatInsightConfiguration>> {
         let url = self.client.url("/api/v1/threats/configuration", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -39,9 +49,20 @@ impl ThreatInsights {
         &self,
         body: &crate::types::ThreatInsightConfiguration,
     ) -> ClientResult<crate::types::ThreatInsightConfiguration> {
+        Ok(self.update_configuration_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/threats/configuration` endpoint.
+     *
+     * As opposed to `update_configuration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_configuration_with_response(
+        &self,
+        body: &crate::types::ThreatInsightConfiguration,
+    ) -> ClientResult<crate::Response<crate::types::ThreatInsightConfiguration>> {
         let url = self.client.url("/api/v1/threats/configuration", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
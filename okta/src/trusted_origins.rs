@@ -30,6 +30,23 @@ impl TrustedOrigins {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::TrustedOrigin>> {
+        Ok(self
+            .list_origins_with_response(q, filter, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_origins`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_origins_with_response(
+        &self,
+        q: &str,
+        filter: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TrustedOrigin>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -48,7 +65,7 @@ impl TrustedOrigins {
             .client
             .url(&format!("/api/v1/trustedOrigins?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -90,6 +107,76 @@ impl TrustedOrigins {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_all_origins`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_origins_pages_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TrustedOrigin>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/api/v1/trustedOrigins?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::TrustedOrigin>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_all_origins`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_origins_stream(
+        &self,
+        q: &str,
+        filter: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TrustedOrigin>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_origins_pages_stream(q, filter, None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/trustedOrigins` endpoint.
      *
@@ -99,9 +186,20 @@ impl TrustedOrigins {
         &self,
         body: &crate::types::TrustedOrigin,
     ) -> ClientResult<crate::types::TrustedOrigin> {
+        Ok(self.create_origin_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `create_origin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_origin_with_response(
+        &self,
+        body: &crate::types::TrustedOrigin,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
         let url = self.client.url("/api/v1/trustedOrigins", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -123,6 +221,17 @@ impl TrustedOrigins {
         &self,
         trusted_origin_id: &str,
     ) -> ClientResult<crate::types::TrustedOrigin> {
+        Ok(self.get_origin_with_response(trusted_origin_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins/{trustedOriginId}` endpoint.
+     *
+     * As opposed to `get_origin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_origin_with_response(
+        &self,
+        trusted_origin_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/trustedOrigins/{}",
@@ -131,7 +240,7 @@ impl TrustedOrigins {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -154,6 +263,21 @@ impl TrustedOrigins {
         trusted_origin_id: &str,
         body: &crate::types::TrustedOrigin,
     ) -> ClientResult<crate::types::TrustedOrigin> {
+        Ok(self
+            .update_origin_with_response(trusted_origin_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/trustedOrigins/{trustedOriginId}` endpoint.
+     *
+     * As opposed to `update_origin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_origin_with_response(
+        &self,
+        trusted_origin_id: &str,
+        body: &crate::types::TrustedOrigin,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/trustedOrigins/{}",
@@ -162,7 +286,7 @@ impl TrustedOrigins {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -181,6 +305,18 @@ impl TrustedOrigins {
      * * `trusted_origin_id: &str`
      */
     pub async fn delete_origin(&self, trusted_origin_id: &str) -> ClientResult<()> {
+        self.delete_origin_with_response(trusted_origin_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/trustedOrigins/{trustedOriginId}` endpoint.
+     *
+     * As opposed to `delete_origin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_origin_with_response(
+        &self,
+        trusted_origin_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/trustedOrigins/{}",
@@ -189,7 +325,7 @@ impl TrustedOrigins {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -211,6 +347,20 @@ impl TrustedOrigins {
         &self,
         trusted_origin_id: &str,
     ) -> ClientResult<crate::types::TrustedOrigin> {
+        Ok(self
+            .activate_origin_with_response(trusted_origin_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate_origin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_origin_with_response(
+        &self,
+        trusted_origin_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/trustedOrigins/{}/lifecycle/activate",
@@ -219,7 +369,7 @@ impl TrustedOrigins {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -241,6 +391,20 @@ impl TrustedOrigins {
         &self,
         trusted_origin_id: &str,
     ) -> ClientResult<crate::types::TrustedOrigin> {
+        Ok(self
+            .deactivate_origin_with_response(trusted_origin_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate_origin`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_origin_with_response(
+        &self,
+        trusted_origin_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrustedOrigin>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/trustedOrigins/{}/lifecycle/deactivate",
@@ -249,7 +413,7 @@ impl TrustedOrigins {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,6 +57,7 @@ pub struct ActivateFactorRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "APP")]
     App,
@@ -64,8 +65,9 @@ pub enum Type {
     AppType,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -74,12 +76,32 @@ impl std::fmt::Display for Type {
             Type::App => "APP",
             Type::AppType => "APP_TYPE",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "APP" => Type::App,
+            "APP_TYPE" => Type::AppType,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -315,6 +337,7 @@ pub struct AppUserPasswordCredential {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -324,8 +347,9 @@ pub enum Status {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -335,12 +359,33 @@ impl std::fmt::Display for Status {
             Status::Deleted => "DELETED",
             Status::Inactive => "INACTIVE",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "ACTIVE" => Status::Active,
+            "DELETED" => Status::Deleted,
+            "INACTIVE" => Status::Inactive,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -478,6 +523,7 @@ pub struct ApplicationCredentialsOAuthClient {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ApplicationCredentialsScheme {
     #[serde(rename = "ADMIN_SETS_CREDENTIALS")]
     AdminSetsCredentials,
@@ -491,8 +537,9 @@ pub enum ApplicationCredentialsScheme {
     SharedUsernameAndPassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationCredentialsScheme {
@@ -506,12 +553,37 @@ impl std::fmt::Display for ApplicationCredentialsScheme {
                 "SHARED_USERNAME_AND_PASSWORD"
             }
             ApplicationCredentialsScheme::Noop => "",
-            ApplicationCredentialsScheme::FallthroughString => "*",
+            ApplicationCredentialsScheme::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ApplicationCredentialsScheme {
+    fn from(s: String) -> ApplicationCredentialsScheme {
+        match s.as_str() {
+            "ADMIN_SETS_CREDENTIALS" => ApplicationCredentialsScheme::AdminSetsCredentials,
+            "EDIT_PASSWORD_ONLY" => ApplicationCredentialsScheme::EditPasswordOnly,
+            "EDIT_USERNAME_AND_PASSWORD" => ApplicationCredentialsScheme::EditUsernameAndPassword,
+            "EXTERNAL_PASSWORD_SYNC" => ApplicationCredentialsScheme::ExternalPasswordSync,
+            "SHARED_USERNAME_AND_PASSWORD" => {
+                ApplicationCredentialsScheme::SharedUsernameAndPassword
+            }
+            "" => ApplicationCredentialsScheme::Noop,
+            _ => ApplicationCredentialsScheme::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ApplicationCredentialsScheme> for String {
+    fn from(e: ApplicationCredentialsScheme) -> String {
+        match e {
+            ApplicationCredentialsScheme::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ApplicationCredentialsScheme {
     fn default() -> ApplicationCredentialsScheme {
         ApplicationCredentialsScheme::Noop
@@ -557,13 +629,15 @@ pub struct ApplicationCredentialsSigning {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Use {
     #[serde(rename = "sig")]
     Sig,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Use {
@@ -571,12 +645,31 @@ impl std::fmt::Display for Use {
         match self {
             Use::Sig => "sig",
             Use::Noop => "",
-            Use::FallthroughString => "*",
+            Use::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Use {
+    fn from(s: String) -> Use {
+        match s.as_str() {
+            "sig" => Use::Sig,
+            "" => Use::Noop,
+            _ => Use::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Use> for String {
+    fn from(e: Use) -> String {
+        match e {
+            Use::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Use {
     fn default() -> Use {
         Use::Noop
@@ -720,6 +813,7 @@ pub struct ApplicationSettingsNotificationsVpnNetwork {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ApplicationSignOnMode {
     #[serde(rename = "AUTO_LOGIN")]
     AutoLogin,
@@ -741,8 +835,9 @@ pub enum ApplicationSignOnMode {
     WsFederation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ApplicationSignOnMode {
@@ -758,12 +853,39 @@ impl std::fmt::Display for ApplicationSignOnMode {
             ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
             ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
             ApplicationSignOnMode::Noop => "",
-            ApplicationSignOnMode::FallthroughString => "*",
+            ApplicationSignOnMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ApplicationSignOnMode {
+    fn from(s: String) -> ApplicationSignOnMode {
+        match s.as_str() {
+            "AUTO_LOGIN" => ApplicationSignOnMode::AutoLogin,
+            "BASIC_AUTH" => ApplicationSignOnMode::BasicAuth,
+            "BOOKMARK" => ApplicationSignOnMode::Bookmark,
+            "BROWSER_PLUGIN" => ApplicationSignOnMode::BrowserPlugin,
+            "OPENID_CONNECT" => ApplicationSignOnMode::OpenidConnect,
+            "SAML_1_1" => ApplicationSignOnMode::Saml1,
+            "SAML_2_0" => ApplicationSignOnMode::Saml20,
+            "SECURE_PASSWORD_STORE" => ApplicationSignOnMode::SecurePasswordStore,
+            "WS_FEDERATION" => ApplicationSignOnMode::WsFederation,
+            "" => ApplicationSignOnMode::Noop,
+            _ => ApplicationSignOnMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ApplicationSignOnMode> for String {
+    fn from(e: ApplicationSignOnMode) -> String {
+        match e {
+            ApplicationSignOnMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ApplicationSignOnMode {
     fn default() -> ApplicationSignOnMode {
         ApplicationSignOnMode::Noop
@@ -827,6 +949,7 @@ pub struct AuthenticationProvider {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AuthenticationProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -842,8 +965,9 @@ pub enum AuthenticationProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthenticationProviderType {
@@ -856,12 +980,36 @@ impl std::fmt::Display for AuthenticationProviderType {
             AuthenticationProviderType::Okta => "OKTA",
             AuthenticationProviderType::Social => "SOCIAL",
             AuthenticationProviderType::Noop => "",
-            AuthenticationProviderType::FallthroughString => "*",
+            AuthenticationProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AuthenticationProviderType {
+    fn from(s: String) -> AuthenticationProviderType {
+        match s.as_str() {
+            "ACTIVE_DIRECTORY" => AuthenticationProviderType::ActiveDirectory,
+            "FEDERATION" => AuthenticationProviderType::Federation,
+            "IMPORT" => AuthenticationProviderType::Import,
+            "LDAP" => AuthenticationProviderType::Ldap,
+            "OKTA" => AuthenticationProviderType::Okta,
+            "SOCIAL" => AuthenticationProviderType::Social,
+            "" => AuthenticationProviderType::Noop,
+            _ => AuthenticationProviderType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AuthenticationProviderType> for String {
+    fn from(e: AuthenticationProviderType) -> String {
+        match e {
+            AuthenticationProviderType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AuthenticationProviderType {
     fn default() -> AuthenticationProviderType {
         AuthenticationProviderType::Noop
@@ -874,6 +1022,7 @@ impl AuthenticationProviderType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IssuerMode {
     #[serde(rename = "CUSTOM_URL")]
     CustomUrl,
@@ -881,8 +1030,9 @@ pub enum IssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IssuerMode {
@@ -891,12 +1041,32 @@ impl std::fmt::Display for IssuerMode {
             IssuerMode::CustomUrl => "CUSTOM_URL",
             IssuerMode::OrgUrl => "ORG_URL",
             IssuerMode::Noop => "",
-            IssuerMode::FallthroughString => "*",
+            IssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IssuerMode {
+    fn from(s: String) -> IssuerMode {
+        match s.as_str() {
+            "CUSTOM_URL" => IssuerMode::CustomUrl,
+            "ORG_URL" => IssuerMode::OrgUrl,
+            "" => IssuerMode::Noop,
+            _ => IssuerMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IssuerMode> for String {
+    fn from(e: IssuerMode) -> String {
+        match e {
+            IssuerMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IssuerMode {
     fn default() -> IssuerMode {
         IssuerMode::Noop
@@ -909,6 +1079,7 @@ impl IssuerMode {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RoleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -916,8 +1087,9 @@ pub enum RoleStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleStatus {
@@ -926,12 +1098,32 @@ impl std::fmt::Display for RoleStatus {
             RoleStatus::Active => "ACTIVE",
             RoleStatus::Inactive => "INACTIVE",
             RoleStatus::Noop => "",
-            RoleStatus::FallthroughString => "*",
+            RoleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RoleStatus {
+    fn from(s: String) -> RoleStatus {
+        match s.as_str() {
+            "ACTIVE" => RoleStatus::Active,
+            "INACTIVE" => RoleStatus::Inactive,
+            "" => RoleStatus::Noop,
+            _ => RoleStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RoleStatus> for String {
+    fn from(e: RoleStatus) -> String {
+        match e {
+            RoleStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RoleStatus {
     fn default() -> RoleStatus {
         RoleStatus::Noop
@@ -1009,6 +1201,7 @@ pub struct AuthorizationServerCredentials {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AuthorizationServerCredentialsRotationMode {
     #[serde(rename = "AUTO")]
     Auto,
@@ -1016,8 +1209,9 @@ pub enum AuthorizationServerCredentialsRotationMode {
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
@@ -1026,12 +1220,32 @@ impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
             AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
             AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
             AuthorizationServerCredentialsRotationMode::Noop => "",
-            AuthorizationServerCredentialsRotationMode::FallthroughString => "*",
+            AuthorizationServerCredentialsRotationMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AuthorizationServerCredentialsRotationMode {
+    fn from(s: String) -> AuthorizationServerCredentialsRotationMode {
+        match s.as_str() {
+            "AUTO" => AuthorizationServerCredentialsRotationMode::Auto,
+            "MANUAL" => AuthorizationServerCredentialsRotationMode::Manual,
+            "" => AuthorizationServerCredentialsRotationMode::Noop,
+            _ => AuthorizationServerCredentialsRotationMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AuthorizationServerCredentialsRotationMode> for String {
+    fn from(e: AuthorizationServerCredentialsRotationMode) -> String {
+        match e {
+            AuthorizationServerCredentialsRotationMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AuthorizationServerCredentialsRotationMode {
     fn default() -> AuthorizationServerCredentialsRotationMode {
         AuthorizationServerCredentialsRotationMode::Noop
@@ -1132,13 +1346,15 @@ pub struct AuthorizationServerPolicy {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AuthorizationServerPolicyRuleType {
     #[serde(rename = "RESOURCE_ACCESS")]
     ResourceAccess,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthorizationServerPolicyRuleType {
@@ -1146,12 +1362,31 @@ impl std::fmt::Display for AuthorizationServerPolicyRuleType {
         match self {
             AuthorizationServerPolicyRuleType::ResourceAccess => "RESOURCE_ACCESS",
             AuthorizationServerPolicyRuleType::Noop => "",
-            AuthorizationServerPolicyRuleType::FallthroughString => "*",
+            AuthorizationServerPolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AuthorizationServerPolicyRuleType {
+    fn from(s: String) -> AuthorizationServerPolicyRuleType {
+        match s.as_str() {
+            "RESOURCE_ACCESS" => AuthorizationServerPolicyRuleType::ResourceAccess,
+            "" => AuthorizationServerPolicyRuleType::Noop,
+            _ => AuthorizationServerPolicyRuleType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AuthorizationServerPolicyRuleType> for String {
+    fn from(e: AuthorizationServerPolicyRuleType) -> String {
+        match e {
+            AuthorizationServerPolicyRuleType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AuthorizationServerPolicyRuleType {
     fn default() -> AuthorizationServerPolicyRuleType {
         AuthorizationServerPolicyRuleType::Noop
@@ -1656,6 +1891,7 @@ pub struct DnsRecord {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DnsRecordType {
     #[serde(rename = "CNAME")]
     Cname,
@@ -1663,8 +1899,9 @@ pub enum DnsRecordType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DnsRecordType {
@@ -1673,12 +1910,32 @@ impl std::fmt::Display for DnsRecordType {
             DnsRecordType::Cname => "CNAME",
             DnsRecordType::Txt => "TXT",
             DnsRecordType::Noop => "",
-            DnsRecordType::FallthroughString => "*",
+            DnsRecordType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DnsRecordType {
+    fn from(s: String) -> DnsRecordType {
+        match s.as_str() {
+            "CNAME" => DnsRecordType::Cname,
+            "TXT" => DnsRecordType::Txt,
+            "" => DnsRecordType::Noop,
+            _ => DnsRecordType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DnsRecordType> for String {
+    fn from(e: DnsRecordType) -> String {
+        match e {
+            DnsRecordType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DnsRecordType {
     fn default() -> DnsRecordType {
         DnsRecordType::Noop
@@ -1691,6 +1948,7 @@ impl DnsRecordType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TrustLevel {
     #[serde(rename = "ANY")]
     Any,
@@ -1698,8 +1956,9 @@ pub enum TrustLevel {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TrustLevel {
@@ -1708,12 +1967,32 @@ impl std::fmt::Display for TrustLevel {
             TrustLevel::Any => "ANY",
             TrustLevel::Trusted => "TRUSTED",
             TrustLevel::Noop => "",
-            TrustLevel::FallthroughString => "*",
+            TrustLevel::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TrustLevel {
+    fn from(s: String) -> TrustLevel {
+        match s.as_str() {
+            "ANY" => TrustLevel::Any,
+            "TRUSTED" => TrustLevel::Trusted,
+            "" => TrustLevel::Noop,
+            _ => TrustLevel::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TrustLevel> for String {
+    fn from(e: TrustLevel) -> String {
+        match e {
+            TrustLevel::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TrustLevel {
     fn default() -> TrustLevel {
         TrustLevel::Noop
@@ -1748,6 +2027,7 @@ pub struct DevicePolicyRuleCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SupportedMdmFrameworks {
     #[serde(rename = "AFW")]
     Afw,
@@ -1757,8 +2037,9 @@ pub enum SupportedMdmFrameworks {
     Safe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SupportedMdmFrameworks {
@@ -1768,12 +2049,33 @@ impl std::fmt::Display for SupportedMdmFrameworks {
             SupportedMdmFrameworks::Native => "NATIVE",
             SupportedMdmFrameworks::Safe => "SAFE",
             SupportedMdmFrameworks::Noop => "",
-            SupportedMdmFrameworks::FallthroughString => "*",
+            SupportedMdmFrameworks::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SupportedMdmFrameworks {
+    fn from(s: String) -> SupportedMdmFrameworks {
+        match s.as_str() {
+            "AFW" => SupportedMdmFrameworks::Afw,
+            "NATIVE" => SupportedMdmFrameworks::Native,
+            "SAFE" => SupportedMdmFrameworks::Safe,
+            "" => SupportedMdmFrameworks::Noop,
+            _ => SupportedMdmFrameworks::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SupportedMdmFrameworks> for String {
+    fn from(e: SupportedMdmFrameworks) -> String {
+        match e {
+            SupportedMdmFrameworks::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SupportedMdmFrameworks {
     fn default() -> SupportedMdmFrameworks {
         SupportedMdmFrameworks::Noop
@@ -1786,6 +2088,7 @@ impl SupportedMdmFrameworks {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Types {
     #[serde(rename = "ANDROID")]
     Android,
@@ -1797,8 +2100,9 @@ pub enum Types {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Types {
@@ -1809,12 +2113,34 @@ impl std::fmt::Display for Types {
             Types::Osx => "OSX",
             Types::Windows => "WINDOWS",
             Types::Noop => "",
-            Types::FallthroughString => "*",
+            Types::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Types {
+    fn from(s: String) -> Types {
+        match s.as_str() {
+            "ANDROID" => Types::Android,
+            "IOS" => Types::Ios,
+            "OSX" => Types::Osx,
+            "WINDOWS" => Types::Windows,
+            "" => Types::Noop,
+            _ => Types::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Types> for String {
+    fn from(e: Types) -> String {
+        match e {
+            Types::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Types {
     fn default() -> Types {
         Types::Noop
@@ -1933,13 +2259,15 @@ pub struct DomainCertificateMetadata {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DomainCertificateSourceType {
     #[serde(rename = "MANUAL")]
     Manual,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateSourceType {
@@ -1947,12 +2275,31 @@ impl std::fmt::Display for DomainCertificateSourceType {
         match self {
             DomainCertificateSourceType::Manual => "MANUAL",
             DomainCertificateSourceType::Noop => "",
-            DomainCertificateSourceType::FallthroughString => "*",
+            DomainCertificateSourceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DomainCertificateSourceType {
+    fn from(s: String) -> DomainCertificateSourceType {
+        match s.as_str() {
+            "MANUAL" => DomainCertificateSourceType::Manual,
+            "" => DomainCertificateSourceType::Noop,
+            _ => DomainCertificateSourceType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DomainCertificateSourceType> for String {
+    fn from(e: DomainCertificateSourceType) -> String {
+        match e {
+            DomainCertificateSourceType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DomainCertificateSourceType {
     fn default() -> DomainCertificateSourceType {
         DomainCertificateSourceType::Noop
@@ -1965,13 +2312,15 @@ impl DomainCertificateSourceType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DomainCertificateType {
     #[serde(rename = "PEM")]
     Pem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainCertificateType {
@@ -1979,12 +2328,31 @@ impl std::fmt::Display for DomainCertificateType {
         match self {
             DomainCertificateType::Pem => "PEM",
             DomainCertificateType::Noop => "",
-            DomainCertificateType::FallthroughString => "*",
+            DomainCertificateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DomainCertificateType {
+    fn from(s: String) -> DomainCertificateType {
+        match s.as_str() {
+            "PEM" => DomainCertificateType::Pem,
+            "" => DomainCertificateType::Noop,
+            _ => DomainCertificateType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DomainCertificateType> for String {
+    fn from(e: DomainCertificateType) -> String {
+        match e {
+            DomainCertificateType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DomainCertificateType {
     fn default() -> DomainCertificateType {
         DomainCertificateType::Noop
@@ -2007,6 +2375,7 @@ pub struct DomainListResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DomainValidationStatus {
     #[serde(rename = "COMPLETED")]
     Completed,
@@ -2018,8 +2387,9 @@ pub enum DomainValidationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DomainValidationStatus {
@@ -2030,12 +2400,34 @@ impl std::fmt::Display for DomainValidationStatus {
             DomainValidationStatus::NotStarted => "NOT_STARTED",
             DomainValidationStatus::Verified => "VERIFIED",
             DomainValidationStatus::Noop => "",
-            DomainValidationStatus::FallthroughString => "*",
+            DomainValidationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DomainValidationStatus {
+    fn from(s: String) -> DomainValidationStatus {
+        match s.as_str() {
+            "COMPLETED" => DomainValidationStatus::Completed,
+            "IN_PROGRESS" => DomainValidationStatus::InProgress,
+            "NOT_STARTED" => DomainValidationStatus::NotStarted,
+            "VERIFIED" => DomainValidationStatus::Verified,
+            "" => DomainValidationStatus::Noop,
+            _ => DomainValidationStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DomainValidationStatus> for String {
+    fn from(e: DomainValidationStatus) -> String {
+        match e {
+            DomainValidationStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DomainValidationStatus {
     fn default() -> DomainValidationStatus {
         DomainValidationStatus::Noop
@@ -2080,6 +2472,7 @@ pub struct EmailUserFactorProfile {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EnabledStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -2087,8 +2480,9 @@ pub enum EnabledStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EnabledStatus {
@@ -2097,12 +2491,32 @@ impl std::fmt::Display for EnabledStatus {
             EnabledStatus::Disabled => "DISABLED",
             EnabledStatus::Enabled => "ENABLED",
             EnabledStatus::Noop => "",
-            EnabledStatus::FallthroughString => "*",
+            EnabledStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EnabledStatus {
+    fn from(s: String) -> EnabledStatus {
+        match s.as_str() {
+            "DISABLED" => EnabledStatus::Disabled,
+            "ENABLED" => EnabledStatus::Enabled,
+            "" => EnabledStatus::Noop,
+            _ => EnabledStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EnabledStatus> for String {
+    fn from(e: EnabledStatus) -> String {
+        match e {
+            EnabledStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EnabledStatus {
     fn default() -> EnabledStatus {
         EnabledStatus::Noop
@@ -2115,6 +2529,7 @@ impl EnabledStatus {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VerificationStatus {
     #[serde(rename = "UNVERIFIED")]
     Unverified,
@@ -2122,8 +2537,9 @@ pub enum VerificationStatus {
     Verified,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for VerificationStatus {
@@ -2132,12 +2548,32 @@ impl std::fmt::Display for VerificationStatus {
             VerificationStatus::Unverified => "UNVERIFIED",
             VerificationStatus::Verified => "VERIFIED",
             VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for VerificationStatus {
+    fn from(s: String) -> VerificationStatus {
+        match s.as_str() {
+            "UNVERIFIED" => VerificationStatus::Unverified,
+            "VERIFIED" => VerificationStatus::Verified,
+            "" => VerificationStatus::Noop,
+            _ => VerificationStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<VerificationStatus> for String {
+    fn from(e: VerificationStatus) -> String {
+        match e {
+            VerificationStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for VerificationStatus {
     fn default() -> VerificationStatus {
         VerificationStatus::Noop
@@ -2200,13 +2636,15 @@ pub struct EventHook {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EventHookChannelType {
     #[serde(rename = "HTTP")]
     Http,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelType {
@@ -2214,12 +2652,31 @@ impl std::fmt::Display for EventHookChannelType {
         match self {
             EventHookChannelType::Http => "HTTP",
             EventHookChannelType::Noop => "",
-            EventHookChannelType::FallthroughString => "*",
+            EventHookChannelType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EventHookChannelType {
+    fn from(s: String) -> EventHookChannelType {
+        match s.as_str() {
+            "HTTP" => EventHookChannelType::Http,
+            "" => EventHookChannelType::Noop,
+            _ => EventHookChannelType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EventHookChannelType> for String {
+    fn from(e: EventHookChannelType) -> String {
+        match e {
+            EventHookChannelType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EventHookChannelType {
     fn default() -> EventHookChannelType {
         EventHookChannelType::Noop
@@ -2286,13 +2743,15 @@ pub struct EventHookChannelConfigAuthScheme {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EventHookChannelConfigAuthSchemeType {
     #[serde(rename = "HEADER")]
     Header,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
@@ -2300,12 +2759,31 @@ impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
         match self {
             EventHookChannelConfigAuthSchemeType::Header => "HEADER",
             EventHookChannelConfigAuthSchemeType::Noop => "",
-            EventHookChannelConfigAuthSchemeType::FallthroughString => "*",
+            EventHookChannelConfigAuthSchemeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EventHookChannelConfigAuthSchemeType {
+    fn from(s: String) -> EventHookChannelConfigAuthSchemeType {
+        match s.as_str() {
+            "HEADER" => EventHookChannelConfigAuthSchemeType::Header,
+            "" => EventHookChannelConfigAuthSchemeType::Noop,
+            _ => EventHookChannelConfigAuthSchemeType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EventHookChannelConfigAuthSchemeType> for String {
+    fn from(e: EventHookChannelConfigAuthSchemeType) -> String {
+        match e {
+            EventHookChannelConfigAuthSchemeType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EventHookChannelConfigAuthSchemeType {
     fn default() -> EventHookChannelConfigAuthSchemeType {
         EventHookChannelConfigAuthSchemeType::Noop
@@ -2334,6 +2812,7 @@ pub struct EventHookChannelConfigHeader {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EventSubscriptionsType {
     #[serde(rename = "EVENT_TYPE")]
     EventType,
@@ -2341,8 +2820,9 @@ pub enum EventSubscriptionsType {
     FlowEvent,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventSubscriptionsType {
@@ -2351,12 +2831,32 @@ impl std::fmt::Display for EventSubscriptionsType {
             EventSubscriptionsType::EventType => "EVENT_TYPE",
             EventSubscriptionsType::FlowEvent => "FLOW_EVENT",
             EventSubscriptionsType::Noop => "",
-            EventSubscriptionsType::FallthroughString => "*",
+            EventSubscriptionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EventSubscriptionsType {
+    fn from(s: String) -> EventSubscriptionsType {
+        match s.as_str() {
+            "EVENT_TYPE" => EventSubscriptionsType::EventType,
+            "FLOW_EVENT" => EventSubscriptionsType::FlowEvent,
+            "" => EventSubscriptionsType::Noop,
+            _ => EventSubscriptionsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EventSubscriptionsType> for String {
+    fn from(e: EventSubscriptionsType) -> String {
+        match e {
+            EventSubscriptionsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EventSubscriptionsType {
     fn default() -> EventSubscriptionsType {
         EventSubscriptionsType::Noop
@@ -2381,6 +2881,7 @@ pub struct EventSubscriptions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorProvider {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -2400,8 +2901,9 @@ pub enum FactorProvider {
     Yubico,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorProvider {
@@ -2416,12 +2918,38 @@ impl std::fmt::Display for FactorProvider {
             FactorProvider::Symantec => "SYMANTEC",
             FactorProvider::Yubico => "YUBICO",
             FactorProvider::Noop => "",
-            FactorProvider::FallthroughString => "*",
+            FactorProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FactorProvider {
+    fn from(s: String) -> FactorProvider {
+        match s.as_str() {
+            "CUSTOM" => FactorProvider::Custom,
+            "DUO" => FactorProvider::Duo,
+            "FIDO" => FactorProvider::Fido,
+            "GOOGLE" => FactorProvider::Google,
+            "OKTA" => FactorProvider::Okta,
+            "RSA" => FactorProvider::Rsa,
+            "SYMANTEC" => FactorProvider::Symantec,
+            "YUBICO" => FactorProvider::Yubico,
+            "" => FactorProvider::Noop,
+            _ => FactorProvider::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FactorProvider> for String {
+    fn from(e: FactorProvider) -> String {
+        match e {
+            FactorProvider::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FactorProvider {
     fn default() -> FactorProvider {
         FactorProvider::Noop
@@ -2434,6 +2962,7 @@ impl FactorProvider {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorResultType {
     #[serde(rename = "CANCELLED")]
     Cancelled,
@@ -2457,8 +2986,9 @@ pub enum FactorResultType {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResultType {
@@ -2475,12 +3005,40 @@ impl std::fmt::Display for FactorResultType {
             FactorResultType::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResultType::Waiting => "WAITING",
             FactorResultType::Noop => "",
-            FactorResultType::FallthroughString => "*",
+            FactorResultType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FactorResultType {
+    fn from(s: String) -> FactorResultType {
+        match s.as_str() {
+            "CANCELLED" => FactorResultType::Cancelled,
+            "CHALLENGE" => FactorResultType::Challenge,
+            "ERROR" => FactorResultType::Error,
+            "FAILED" => FactorResultType::Failed,
+            "PASSCODE_REPLAYED" => FactorResultType::PasscodeReplayed,
+            "REJECTED" => FactorResultType::Rejected,
+            "SUCCESS" => FactorResultType::Success,
+            "TIMEOUT" => FactorResultType::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResultType::TimeWindowExceeded,
+            "WAITING" => FactorResultType::Waiting,
+            "" => FactorResultType::Noop,
+            _ => FactorResultType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FactorResultType> for String {
+    fn from(e: FactorResultType) -> String {
+        match e {
+            FactorResultType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FactorResultType {
     fn default() -> FactorResultType {
         FactorResultType::Noop
@@ -2493,6 +3051,7 @@ impl FactorResultType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2510,8 +3069,9 @@ pub enum FactorStatus {
     PendingActivation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorStatus {
@@ -2525,12 +3085,37 @@ impl std::fmt::Display for FactorStatus {
             FactorStatus::NotSetup => "NOT_SETUP",
             FactorStatus::PendingActivation => "PENDING_ACTIVATION",
             FactorStatus::Noop => "",
-            FactorStatus::FallthroughString => "*",
+            FactorStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FactorStatus {
+    fn from(s: String) -> FactorStatus {
+        match s.as_str() {
+            "ACTIVE" => FactorStatus::Active,
+            "DISABLED" => FactorStatus::Disabled,
+            "ENROLLED" => FactorStatus::Enrolled,
+            "EXPIRED" => FactorStatus::Expired,
+            "INACTIVE" => FactorStatus::Inactive,
+            "NOT_SETUP" => FactorStatus::NotSetup,
+            "PENDING_ACTIVATION" => FactorStatus::PendingActivation,
+            "" => FactorStatus::Noop,
+            _ => FactorStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FactorStatus> for String {
+    fn from(e: FactorStatus) -> String {
+        match e {
+            FactorStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FactorStatus {
     fn default() -> FactorStatus {
         FactorStatus::Noop
@@ -2543,6 +3128,7 @@ impl FactorStatus {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorType {
     #[serde(rename = "call")]
     Call,
@@ -2572,8 +3158,9 @@ pub enum FactorType {
     Webauthn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorType {
@@ -2593,12 +3180,43 @@ impl std::fmt::Display for FactorType {
             FactorType::Web => "web",
             FactorType::Webauthn => "webauthn",
             FactorType::Noop => "",
-            FactorType::FallthroughString => "*",
+            FactorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FactorType {
+    fn from(s: String) -> FactorType {
+        match s.as_str() {
+            "call" => FactorType::Call,
+            "email" => FactorType::Email,
+            "hotp" => FactorType::Hotp,
+            "push" => FactorType::Push,
+            "question" => FactorType::Question,
+            "sms" => FactorType::Sms,
+            "token" => FactorType::Token,
+            "token:hardware" => FactorType::TokenHardware,
+            "token:hotp" => FactorType::TokenHotp,
+            "token:software:totp" => FactorType::TokenSoftwareTotp,
+            "u2f" => FactorType::U2F,
+            "web" => FactorType::Web,
+            "webauthn" => FactorType::Webauthn,
+            "" => FactorType::Noop,
+            _ => FactorType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FactorType> for String {
+    fn from(e: FactorType) -> String {
+        match e {
+            FactorType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FactorType {
     fn default() -> FactorType {
         FactorType::Noop
@@ -2649,6 +3267,7 @@ pub struct FeatureStage {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FeatureStageState {
     #[serde(rename = "CLOSED")]
     Closed,
@@ -2656,8 +3275,9 @@ pub enum FeatureStageState {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageState {
@@ -2666,12 +3286,32 @@ impl std::fmt::Display for FeatureStageState {
             FeatureStageState::Closed => "CLOSED",
             FeatureStageState::Open => "OPEN",
             FeatureStageState::Noop => "",
-            FeatureStageState::FallthroughString => "*",
+            FeatureStageState::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FeatureStageState {
+    fn from(s: String) -> FeatureStageState {
+        match s.as_str() {
+            "CLOSED" => FeatureStageState::Closed,
+            "OPEN" => FeatureStageState::Open,
+            "" => FeatureStageState::Noop,
+            _ => FeatureStageState::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FeatureStageState> for String {
+    fn from(e: FeatureStageState) -> String {
+        match e {
+            FeatureStageState::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FeatureStageState {
     fn default() -> FeatureStageState {
         FeatureStageState::Noop
@@ -2684,6 +3324,7 @@ impl FeatureStageState {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FeatureStageValue {
     #[serde(rename = "BETA")]
     Beta,
@@ -2691,8 +3332,9 @@ pub enum FeatureStageValue {
     Ea,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureStageValue {
@@ -2701,12 +3343,32 @@ impl std::fmt::Display for FeatureStageValue {
             FeatureStageValue::Beta => "BETA",
             FeatureStageValue::Ea => "EA",
             FeatureStageValue::Noop => "",
-            FeatureStageValue::FallthroughString => "*",
+            FeatureStageValue::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FeatureStageValue {
+    fn from(s: String) -> FeatureStageValue {
+        match s.as_str() {
+            "BETA" => FeatureStageValue::Beta,
+            "EA" => FeatureStageValue::Ea,
+            "" => FeatureStageValue::Noop,
+            _ => FeatureStageValue::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FeatureStageValue> for String {
+    fn from(e: FeatureStageValue) -> String {
+        match e {
+            FeatureStageValue::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FeatureStageValue {
     fn default() -> FeatureStageValue {
         FeatureStageValue::Noop
@@ -2719,13 +3381,15 @@ impl FeatureStageValue {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FeatureType {
     #[serde(rename = "self-service")]
     SelfService,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FeatureType {
@@ -2733,12 +3397,31 @@ impl std::fmt::Display for FeatureType {
         match self {
             FeatureType::SelfService => "self-service",
             FeatureType::Noop => "",
-            FeatureType::FallthroughString => "*",
+            FeatureType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FeatureType {
+    fn from(s: String) -> FeatureType {
+        match s.as_str() {
+            "self-service" => FeatureType::SelfService,
+            "" => FeatureType::Noop,
+            _ => FeatureType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FeatureType> for String {
+    fn from(e: FeatureType) -> String {
+        match e {
+            FeatureType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FeatureType {
     fn default() -> FeatureType {
         FeatureType::Noop
@@ -2935,6 +3618,7 @@ pub struct GroupRulePeopleCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GroupRuleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2944,8 +3628,9 @@ pub enum GroupRuleStatus {
     Invalid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupRuleStatus {
@@ -2955,12 +3640,33 @@ impl std::fmt::Display for GroupRuleStatus {
             GroupRuleStatus::Inactive => "INACTIVE",
             GroupRuleStatus::Invalid => "INVALID",
             GroupRuleStatus::Noop => "",
-            GroupRuleStatus::FallthroughString => "*",
+            GroupRuleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GroupRuleStatus {
+    fn from(s: String) -> GroupRuleStatus {
+        match s.as_str() {
+            "ACTIVE" => GroupRuleStatus::Active,
+            "INACTIVE" => GroupRuleStatus::Inactive,
+            "INVALID" => GroupRuleStatus::Invalid,
+            "" => GroupRuleStatus::Noop,
+            _ => GroupRuleStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GroupRuleStatus> for String {
+    fn from(e: GroupRuleStatus) -> String {
+        match e {
+            GroupRuleStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GroupRuleStatus {
     fn default() -> GroupRuleStatus {
         GroupRuleStatus::Noop
@@ -2973,6 +3679,7 @@ impl GroupRuleStatus {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GroupType {
     #[serde(rename = "APP_GROUP")]
     AppGroup,
@@ -2982,8 +3689,9 @@ pub enum GroupType {
     OktaGroup,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupType {
@@ -2993,12 +3701,33 @@ impl std::fmt::Display for GroupType {
             GroupType::BuiltIn => "BUILT_IN",
             GroupType::OktaGroup => "OKTA_GROUP",
             GroupType::Noop => "",
-            GroupType::FallthroughString => "*",
+            GroupType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GroupType {
+    fn from(s: String) -> GroupType {
+        match s.as_str() {
+            "APP_GROUP" => GroupType::AppGroup,
+            "BUILT_IN" => GroupType::BuiltIn,
+            "OKTA_GROUP" => GroupType::OktaGroup,
+            "" => GroupType::Noop,
+            _ => GroupType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GroupType> for String {
+    fn from(e: GroupType) -> String {
+        match e {
+            GroupType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GroupType {
     fn default() -> GroupType {
         GroupType::Noop
@@ -3028,6 +3757,7 @@ pub struct WebUserFactorProfile {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IdentityProviderIssuerMode {
     #[serde(rename = "CUSTOM_URL_DOMAIN")]
     CustomUrlDomain,
@@ -3035,8 +3765,9 @@ pub enum IdentityProviderIssuerMode {
     OrgUrl,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderIssuerMode {
@@ -3045,12 +3776,32 @@ impl std::fmt::Display for IdentityProviderIssuerMode {
             IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
             IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
             IdentityProviderIssuerMode::Noop => "",
-            IdentityProviderIssuerMode::FallthroughString => "*",
+            IdentityProviderIssuerMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IdentityProviderIssuerMode {
+    fn from(s: String) -> IdentityProviderIssuerMode {
+        match s.as_str() {
+            "CUSTOM_URL_DOMAIN" => IdentityProviderIssuerMode::CustomUrlDomain,
+            "ORG_URL" => IdentityProviderIssuerMode::OrgUrl,
+            "" => IdentityProviderIssuerMode::Noop,
+            _ => IdentityProviderIssuerMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IdentityProviderIssuerMode> for String {
+    fn from(e: IdentityProviderIssuerMode) -> String {
+        match e {
+            IdentityProviderIssuerMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IdentityProviderIssuerMode {
     fn default() -> IdentityProviderIssuerMode {
         IdentityProviderIssuerMode::Noop
@@ -3063,6 +3814,7 @@ impl IdentityProviderIssuerMode {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IdentityProviderType {
     #[serde(rename = "AgentlessDSSO")]
     AgentlessDsso,
@@ -3086,8 +3838,9 @@ pub enum IdentityProviderType {
     X509,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IdentityProviderType {
@@ -3104,12 +3857,40 @@ impl std::fmt::Display for IdentityProviderType {
             IdentityProviderType::Saml2 => "SAML2",
             IdentityProviderType::X509 => "X509",
             IdentityProviderType::Noop => "",
-            IdentityProviderType::FallthroughString => "*",
+            IdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IdentityProviderType {
+    fn from(s: String) -> IdentityProviderType {
+        match s.as_str() {
+            "AgentlessDSSO" => IdentityProviderType::AgentlessDsso,
+            "FACEBOOK" => IdentityProviderType::Facebook,
+            "GOOGLE" => IdentityProviderType::Google,
+            "IWA" => IdentityProviderType::Iwa,
+            "LINKEDIN" => IdentityProviderType::Linkedin,
+            "MICROSOFT" => IdentityProviderType::Microsoft,
+            "OIDC" => IdentityProviderType::Oidc,
+            "OKTA" => IdentityProviderType::Okta,
+            "SAML2" => IdentityProviderType::Saml2,
+            "X509" => IdentityProviderType::X509,
+            "" => IdentityProviderType::Noop,
+            _ => IdentityProviderType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IdentityProviderType> for String {
+    fn from(e: IdentityProviderType) -> String {
+        match e {
+            IdentityProviderType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IdentityProviderType {
     fn default() -> IdentityProviderType {
         IdentityProviderType::Noop
@@ -3239,6 +4020,7 @@ pub struct IdentityProviderCredentialsSigning {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Revocation {
     #[serde(rename = "CRL")]
     Crl,
@@ -3248,8 +4030,9 @@ pub enum Revocation {
     Ocsp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Revocation {
@@ -3259,9 +4042,30 @@ impl std::fmt::Display for Revocation {
             Revocation::DeltaCrl => "DELTA_CRL",
             Revocation::Ocsp => "OCSP",
             Revocation::Noop => "",
-            Revocation::FallthroughString => "*",
+            Revocation::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::convert::From<String> for Revocation {
+    fn from(s: String) -> Revocation {
+        match s.as_str() {
+            "CRL" => Revocation::Crl,
+            "DELTA_CRL" => Revocation::DeltaCrl,
+            "OCSP" => Revocation::Ocsp,
+            "" => Revocation::Noop,
+            _ => Revocation::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Revocation> for String {
+    fn from(e: Revocation) -> String {
+        match e {
+            Revocation::Unknown(s) => s,
+            e => e.to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -3329,6 +4133,7 @@ pub struct IdentityProviderPolicy {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Provider {
     #[serde(rename = "ANY")]
     Any,
@@ -3338,8 +4143,9 @@ pub enum Provider {
     SpecificIdp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Provider {
@@ -3349,12 +4155,33 @@ impl std::fmt::Display for Provider {
             Provider::Okta => "OKTA",
             Provider::SpecificIdp => "SPECIFIC_IDP",
             Provider::Noop => "",
-            Provider::FallthroughString => "*",
+            Provider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Provider {
+    fn from(s: String) -> Provider {
+        match s.as_str() {
+            "ANY" => Provider::Any,
+            "OKTA" => Provider::Okta,
+            "SPECIFIC_IDP" => Provider::SpecificIdp,
+            "" => Provider::Noop,
+            _ => Provider::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Provider> for String {
+    fn from(e: Provider) -> String {
+        match e {
+            Provider::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Provider {
     fn default() -> Provider {
         Provider::Noop
@@ -3553,6 +4380,7 @@ pub struct InlineHookResponseCommands {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InlineHookType {
     #[serde(rename = "com.okta.import.transform")]
     ComOktaImportTransform,
@@ -3566,8 +4394,9 @@ pub enum InlineHookType {
     ComOktaUserPreRegistration,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for InlineHookType {
@@ -3581,12 +4410,37 @@ impl std::fmt::Display for InlineHookType {
             }
             InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
             InlineHookType::Noop => "",
-            InlineHookType::FallthroughString => "*",
+            InlineHookType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for InlineHookType {
+    fn from(s: String) -> InlineHookType {
+        match s.as_str() {
+            "com.okta.import.transform" => InlineHookType::ComOktaImportTransform,
+            "com.okta.oauth2.tokens.transform" => InlineHookType::ComOktaOauth2TokensTransform,
+            "com.okta.saml.tokens.transform" => InlineHookType::ComOktaSamlTokensTransform,
+            "com.okta.user.credential.password.import" => {
+                InlineHookType::ComOktaUserCredentialPasswordImport
+            }
+            "com.okta.user.pre-registration" => InlineHookType::ComOktaUserPreRegistration,
+            "" => InlineHookType::Noop,
+            _ => InlineHookType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<InlineHookType> for String {
+    fn from(e: InlineHookType) -> String {
+        match e {
+            InlineHookType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for InlineHookType {
     fn default() -> InlineHookType {
         InlineHookType::Noop
@@ -3871,13 +4725,15 @@ pub struct LinkedObjectDetails {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LinkedObjectDetailsType {
     #[serde(rename = "USER")]
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkedObjectDetailsType {
@@ -3885,12 +4741,31 @@ impl std::fmt::Display for LinkedObjectDetailsType {
         match self {
             LinkedObjectDetailsType::User => "USER",
             LinkedObjectDetailsType::Noop => "",
-            LinkedObjectDetailsType::FallthroughString => "*",
+            LinkedObjectDetailsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LinkedObjectDetailsType {
+    fn from(s: String) -> LinkedObjectDetailsType {
+        match s.as_str() {
+            "USER" => LinkedObjectDetailsType::User,
+            "" => LinkedObjectDetailsType::Noop,
+            _ => LinkedObjectDetailsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LinkedObjectDetailsType> for String {
+    fn from(e: LinkedObjectDetailsType) -> String {
+        match e {
+            LinkedObjectDetailsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LinkedObjectDetailsType {
     fn default() -> LinkedObjectDetailsType {
         LinkedObjectDetailsType::Noop
@@ -3980,6 +4855,7 @@ pub struct LogAuthenticationContext {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LogAuthenticationProvider {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -3995,8 +4871,9 @@ pub enum LogAuthenticationProvider {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogAuthenticationProvider {
@@ -4009,12 +4886,36 @@ impl std::fmt::Display for LogAuthenticationProvider {
             LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
             LogAuthenticationProvider::Social => "SOCIAL",
             LogAuthenticationProvider::Noop => "",
-            LogAuthenticationProvider::FallthroughString => "*",
+            LogAuthenticationProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LogAuthenticationProvider {
+    fn from(s: String) -> LogAuthenticationProvider {
+        match s.as_str() {
+            "ACTIVE_DIRECTORY" => LogAuthenticationProvider::ActiveDirectory,
+            "FACTOR_PROVIDER" => LogAuthenticationProvider::FactorProvider,
+            "FEDERATION" => LogAuthenticationProvider::Federation,
+            "LDAP" => LogAuthenticationProvider::Ldap,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogAuthenticationProvider::OktaAuthenticationProvider,
+            "SOCIAL" => LogAuthenticationProvider::Social,
+            "" => LogAuthenticationProvider::Noop,
+            _ => LogAuthenticationProvider::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LogAuthenticationProvider> for String {
+    fn from(e: LogAuthenticationProvider) -> String {
+        match e {
+            LogAuthenticationProvider::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LogAuthenticationProvider {
     fn default() -> LogAuthenticationProvider {
         LogAuthenticationProvider::Noop
@@ -4064,6 +4965,7 @@ pub struct LogClient {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LogCredentialProvider {
     #[serde(rename = "DUO")]
     Duo,
@@ -4081,8 +4983,9 @@ pub enum LogCredentialProvider {
     Yubikey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialProvider {
@@ -4096,12 +4999,37 @@ impl std::fmt::Display for LogCredentialProvider {
             LogCredentialProvider::Symantec => "SYMANTEC",
             LogCredentialProvider::Yubikey => "YUBIKEY",
             LogCredentialProvider::Noop => "",
-            LogCredentialProvider::FallthroughString => "*",
+            LogCredentialProvider::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LogCredentialProvider {
+    fn from(s: String) -> LogCredentialProvider {
+        match s.as_str() {
+            "DUO" => LogCredentialProvider::Duo,
+            "GOOGLE" => LogCredentialProvider::Google,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogCredentialProvider::OktaAuthenticationProvider,
+            "OKTA_CREDENTIAL_PROVIDER" => LogCredentialProvider::OktaCredentialProvider,
+            "RSA" => LogCredentialProvider::Rsa,
+            "SYMANTEC" => LogCredentialProvider::Symantec,
+            "YUBIKEY" => LogCredentialProvider::Yubikey,
+            "" => LogCredentialProvider::Noop,
+            _ => LogCredentialProvider::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LogCredentialProvider> for String {
+    fn from(e: LogCredentialProvider) -> String {
+        match e {
+            LogCredentialProvider::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LogCredentialProvider {
     fn default() -> LogCredentialProvider {
         LogCredentialProvider::Noop
@@ -4114,6 +5042,7 @@ impl LogCredentialProvider {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LogCredentialType {
     #[serde(rename = "ASSERTION")]
     Assertion,
@@ -4133,8 +5062,9 @@ pub enum LogCredentialType {
     Sms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogCredentialType {
@@ -4149,12 +5079,38 @@ impl std::fmt::Display for LogCredentialType {
             LogCredentialType::Password => "PASSWORD",
             LogCredentialType::Sms => "SMS",
             LogCredentialType::Noop => "",
-            LogCredentialType::FallthroughString => "*",
+            LogCredentialType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LogCredentialType {
+    fn from(s: String) -> LogCredentialType {
+        match s.as_str() {
+            "ASSERTION" => LogCredentialType::Assertion,
+            "EMAIL" => LogCredentialType::Email,
+            "IWA" => LogCredentialType::Iwa,
+            "JWT" => LogCredentialType::Jwt,
+            "OAUTH2" => LogCredentialType::Oauth2,
+            "OTP" => LogCredentialType::Otp,
+            "PASSWORD" => LogCredentialType::Password,
+            "SMS" => LogCredentialType::Sms,
+            "" => LogCredentialType::Noop,
+            _ => LogCredentialType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LogCredentialType> for String {
+    fn from(e: LogCredentialType) -> String {
+        match e {
+            LogCredentialType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LogCredentialType {
     fn default() -> LogCredentialType {
         LogCredentialType::Noop
@@ -4407,6 +5363,7 @@ pub struct LogSecurityContext {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LogSeverity {
     #[serde(rename = "DEBUG")]
     Debug,
@@ -4418,8 +5375,9 @@ pub enum LogSeverity {
     Warn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LogSeverity {
@@ -4430,12 +5388,34 @@ impl std::fmt::Display for LogSeverity {
             LogSeverity::Info => "INFO",
             LogSeverity::Warn => "WARN",
             LogSeverity::Noop => "",
-            LogSeverity::FallthroughString => "*",
+            LogSeverity::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LogSeverity {
+    fn from(s: String) -> LogSeverity {
+        match s.as_str() {
+            "DEBUG" => LogSeverity::Debug,
+            "ERROR" => LogSeverity::Error,
+            "INFO" => LogSeverity::Info,
+            "WARN" => LogSeverity::Warn,
+            "" => LogSeverity::Noop,
+            _ => LogSeverity::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LogSeverity> for String {
+    fn from(e: LogSeverity) -> String {
+        match e {
+            LogSeverity::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LogSeverity {
     fn default() -> LogSeverity {
         LogSeverity::Noop
@@ -4527,6 +5507,7 @@ pub struct LogUserAgent {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Enrollment {
     #[serde(rename = "ANY_OR_NONE")]
     AnyOrNone,
@@ -4534,8 +5515,9 @@ pub enum Enrollment {
     Omm,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Enrollment {
@@ -4544,12 +5526,32 @@ impl std::fmt::Display for Enrollment {
             Enrollment::AnyOrNone => "ANY_OR_NONE",
             Enrollment::Omm => "OMM",
             Enrollment::Noop => "",
-            Enrollment::FallthroughString => "*",
+            Enrollment::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Enrollment {
+    fn from(s: String) -> Enrollment {
+        match s.as_str() {
+            "ANY_OR_NONE" => Enrollment::AnyOrNone,
+            "OMM" => Enrollment::Omm,
+            "" => Enrollment::Noop,
+            _ => Enrollment::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Enrollment> for String {
+    fn from(e: Enrollment) -> String {
+        match e {
+            Enrollment::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Enrollment {
     fn default() -> Enrollment {
         Enrollment::Noop
@@ -4659,6 +5661,7 @@ pub struct NetworkZoneAddress {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NetworkZoneAddressType {
     #[serde(rename = "CIDR")]
     Cidr,
@@ -4666,8 +5669,9 @@ pub enum NetworkZoneAddressType {
     Range,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneAddressType {
@@ -4676,12 +5680,32 @@ impl std::fmt::Display for NetworkZoneAddressType {
             NetworkZoneAddressType::Cidr => "CIDR",
             NetworkZoneAddressType::Range => "RANGE",
             NetworkZoneAddressType::Noop => "",
-            NetworkZoneAddressType::FallthroughString => "*",
+            NetworkZoneAddressType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NetworkZoneAddressType {
+    fn from(s: String) -> NetworkZoneAddressType {
+        match s.as_str() {
+            "CIDR" => NetworkZoneAddressType::Cidr,
+            "RANGE" => NetworkZoneAddressType::Range,
+            "" => NetworkZoneAddressType::Noop,
+            _ => NetworkZoneAddressType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NetworkZoneAddressType> for String {
+    fn from(e: NetworkZoneAddressType) -> String {
+        match e {
+            NetworkZoneAddressType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NetworkZoneAddressType {
     fn default() -> NetworkZoneAddressType {
         NetworkZoneAddressType::Noop
@@ -4710,6 +5734,7 @@ pub struct NetworkZoneLocation {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NetworkZoneType {
     #[serde(rename = "DYNAMIC")]
     Dynamic,
@@ -4717,8 +5742,9 @@ pub enum NetworkZoneType {
     Ip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneType {
@@ -4727,12 +5753,32 @@ impl std::fmt::Display for NetworkZoneType {
             NetworkZoneType::Dynamic => "DYNAMIC",
             NetworkZoneType::Ip => "IP",
             NetworkZoneType::Noop => "",
-            NetworkZoneType::FallthroughString => "*",
+            NetworkZoneType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NetworkZoneType {
+    fn from(s: String) -> NetworkZoneType {
+        match s.as_str() {
+            "DYNAMIC" => NetworkZoneType::Dynamic,
+            "IP" => NetworkZoneType::Ip,
+            "" => NetworkZoneType::Noop,
+            _ => NetworkZoneType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NetworkZoneType> for String {
+    fn from(e: NetworkZoneType) -> String {
+        match e {
+            NetworkZoneType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NetworkZoneType {
     fn default() -> NetworkZoneType {
         NetworkZoneType::Noop
@@ -4745,6 +5791,7 @@ impl NetworkZoneType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NetworkZoneUsage {
     #[serde(rename = "BLOCKLIST")]
     Blocklist,
@@ -4752,8 +5799,9 @@ pub enum NetworkZoneUsage {
     Policy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkZoneUsage {
@@ -4762,12 +5810,32 @@ impl std::fmt::Display for NetworkZoneUsage {
             NetworkZoneUsage::Blocklist => "BLOCKLIST",
             NetworkZoneUsage::Policy => "POLICY",
             NetworkZoneUsage::Noop => "",
-            NetworkZoneUsage::FallthroughString => "*",
+            NetworkZoneUsage::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NetworkZoneUsage {
+    fn from(s: String) -> NetworkZoneUsage {
+        match s.as_str() {
+            "BLOCKLIST" => NetworkZoneUsage::Blocklist,
+            "POLICY" => NetworkZoneUsage::Policy,
+            "" => NetworkZoneUsage::Noop,
+            _ => NetworkZoneUsage::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NetworkZoneUsage> for String {
+    fn from(e: NetworkZoneUsage) -> String {
+        match e {
+            NetworkZoneUsage::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NetworkZoneUsage {
     fn default() -> NetworkZoneUsage {
         NetworkZoneUsage::Noop
@@ -4797,6 +5865,7 @@ pub struct OAuth2Actor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ClaimType {
     #[serde(rename = "IDENTITY")]
     Identity,
@@ -4804,8 +5873,9 @@ pub enum ClaimType {
     Resource,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClaimType {
@@ -4814,12 +5884,32 @@ impl std::fmt::Display for ClaimType {
             ClaimType::Identity => "IDENTITY",
             ClaimType::Resource => "RESOURCE",
             ClaimType::Noop => "",
-            ClaimType::FallthroughString => "*",
+            ClaimType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ClaimType {
+    fn from(s: String) -> ClaimType {
+        match s.as_str() {
+            "IDENTITY" => ClaimType::Identity,
+            "RESOURCE" => ClaimType::Resource,
+            "" => ClaimType::Noop,
+            _ => ClaimType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ClaimType> for String {
+    fn from(e: ClaimType) -> String {
+        match e {
+            ClaimType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ClaimType {
     fn default() -> ClaimType {
         ClaimType::Noop
@@ -4832,6 +5922,7 @@ impl ClaimType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GroupFilterType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -4843,8 +5934,9 @@ pub enum GroupFilterType {
     StartsWith,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupFilterType {
@@ -4855,12 +5947,34 @@ impl std::fmt::Display for GroupFilterType {
             GroupFilterType::Regex => "REGEX",
             GroupFilterType::StartsWith => "STARTS_WITH",
             GroupFilterType::Noop => "",
-            GroupFilterType::FallthroughString => "*",
+            GroupFilterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GroupFilterType {
+    fn from(s: String) -> GroupFilterType {
+        match s.as_str() {
+            "CONTAINS" => GroupFilterType::Contains,
+            "EQUALS" => GroupFilterType::Equals,
+            "REGEX" => GroupFilterType::Regex,
+            "STARTS_WITH" => GroupFilterType::StartsWith,
+            "" => GroupFilterType::Noop,
+            _ => GroupFilterType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GroupFilterType> for String {
+    fn from(e: GroupFilterType) -> String {
+        match e {
+            GroupFilterType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GroupFilterType {
     fn default() -> GroupFilterType {
         GroupFilterType::Noop
@@ -4873,6 +5987,7 @@ impl GroupFilterType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ValueType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -4882,8 +5997,9 @@ pub enum ValueType {
     System,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ValueType {
@@ -4893,12 +6009,33 @@ impl std::fmt::Display for ValueType {
             ValueType::Groups => "GROUPS",
             ValueType::System => "SYSTEM",
             ValueType::Noop => "",
-            ValueType::FallthroughString => "*",
+            ValueType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ValueType {
+    fn from(s: String) -> ValueType {
+        match s.as_str() {
+            "EXPRESSION" => ValueType::Expression,
+            "GROUPS" => ValueType::Groups,
+            "SYSTEM" => ValueType::System,
+            "" => ValueType::Noop,
+            _ => ValueType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ValueType> for String {
+    fn from(e: ValueType) -> String {
+        match e {
+            ValueType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ValueType {
     fn default() -> ValueType {
         ValueType::Noop
@@ -4996,6 +6133,7 @@ pub struct OAuth2Client {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OAuth2RefreshTokenStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -5003,8 +6141,9 @@ pub enum OAuth2RefreshTokenStatus {
     Revoked,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2RefreshTokenStatus {
@@ -5013,12 +6152,32 @@ impl std::fmt::Display for OAuth2RefreshTokenStatus {
             OAuth2RefreshTokenStatus::Active => "ACTIVE",
             OAuth2RefreshTokenStatus::Revoked => "REVOKED",
             OAuth2RefreshTokenStatus::Noop => "",
-            OAuth2RefreshTokenStatus::FallthroughString => "*",
+            OAuth2RefreshTokenStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OAuth2RefreshTokenStatus {
+    fn from(s: String) -> OAuth2RefreshTokenStatus {
+        match s.as_str() {
+            "ACTIVE" => OAuth2RefreshTokenStatus::Active,
+            "REVOKED" => OAuth2RefreshTokenStatus::Revoked,
+            "" => OAuth2RefreshTokenStatus::Noop,
+            _ => OAuth2RefreshTokenStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OAuth2RefreshTokenStatus> for String {
+    fn from(e: OAuth2RefreshTokenStatus) -> String {
+        match e {
+            OAuth2RefreshTokenStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OAuth2RefreshTokenStatus {
     fn default() -> OAuth2RefreshTokenStatus {
         OAuth2RefreshTokenStatus::Noop
@@ -5095,6 +6254,7 @@ pub struct OAuth2RefreshToken {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Consent {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5104,8 +6264,9 @@ pub enum Consent {
     Required,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Consent {
@@ -5115,12 +6276,33 @@ impl std::fmt::Display for Consent {
             Consent::Implicit => "IMPLICIT",
             Consent::Required => "REQUIRED",
             Consent::Noop => "",
-            Consent::FallthroughString => "*",
+            Consent::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Consent {
+    fn from(s: String) -> Consent {
+        match s.as_str() {
+            "ADMIN" => Consent::Admin,
+            "IMPLICIT" => Consent::Implicit,
+            "REQUIRED" => Consent::Required,
+            "" => Consent::Noop,
+            _ => Consent::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Consent> for String {
+    fn from(e: Consent) -> String {
+        match e {
+            Consent::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Consent {
     fn default() -> Consent {
         Consent::Noop
@@ -5133,6 +6315,7 @@ impl Consent {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MetadataPublish {
     #[serde(rename = "ALL_CLIENTS")]
     AllClients,
@@ -5140,8 +6323,9 @@ pub enum MetadataPublish {
     NoClients,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MetadataPublish {
@@ -5150,12 +6334,32 @@ impl std::fmt::Display for MetadataPublish {
             MetadataPublish::AllClients => "ALL_CLIENTS",
             MetadataPublish::NoClients => "NO_CLIENTS",
             MetadataPublish::Noop => "",
-            MetadataPublish::FallthroughString => "*",
+            MetadataPublish::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MetadataPublish {
+    fn from(s: String) -> MetadataPublish {
+        match s.as_str() {
+            "ALL_CLIENTS" => MetadataPublish::AllClients,
+            "NO_CLIENTS" => MetadataPublish::NoClients,
+            "" => MetadataPublish::Noop,
+            _ => MetadataPublish::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MetadataPublish> for String {
+    fn from(e: MetadataPublish) -> String {
+        match e {
+            MetadataPublish::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MetadataPublish {
     fn default() -> MetadataPublish {
         MetadataPublish::Noop
@@ -5275,6 +6479,7 @@ pub struct OAuth2ScopeConsentGrant {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OAuth2ScopeConsentGrantSource {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5282,8 +6487,9 @@ pub enum OAuth2ScopeConsentGrantSource {
     EndUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
@@ -5292,12 +6498,32 @@ impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
             OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
             OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
             OAuth2ScopeConsentGrantSource::Noop => "",
-            OAuth2ScopeConsentGrantSource::FallthroughString => "*",
+            OAuth2ScopeConsentGrantSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OAuth2ScopeConsentGrantSource {
+    fn from(s: String) -> OAuth2ScopeConsentGrantSource {
+        match s.as_str() {
+            "ADMIN" => OAuth2ScopeConsentGrantSource::Admin,
+            "END_USER" => OAuth2ScopeConsentGrantSource::EndUser,
+            "" => OAuth2ScopeConsentGrantSource::Noop,
+            _ => OAuth2ScopeConsentGrantSource::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OAuth2ScopeConsentGrantSource> for String {
+    fn from(e: OAuth2ScopeConsentGrantSource) -> String {
+        match e {
+            OAuth2ScopeConsentGrantSource::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OAuth2ScopeConsentGrantSource {
     fn default() -> OAuth2ScopeConsentGrantSource {
         OAuth2ScopeConsentGrantSource::Noop
@@ -5392,6 +6618,7 @@ pub struct OAuthApplicationCredentials {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OAuthEndpointAuthenticationMethod {
     #[serde(rename = "client_secret_basic")]
     ClientSecretBasic,
@@ -5405,8 +6632,9 @@ pub enum OAuthEndpointAuthenticationMethod {
     PrivateKeyJwt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
@@ -5418,12 +6646,35 @@ impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
             OAuthEndpointAuthenticationMethod::None => "none",
             OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
             OAuthEndpointAuthenticationMethod::Noop => "",
-            OAuthEndpointAuthenticationMethod::FallthroughString => "*",
+            OAuthEndpointAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OAuthEndpointAuthenticationMethod {
+    fn from(s: String) -> OAuthEndpointAuthenticationMethod {
+        match s.as_str() {
+            "client_secret_basic" => OAuthEndpointAuthenticationMethod::ClientSecretBasic,
+            "client_secret_jwt" => OAuthEndpointAuthenticationMethod::ClientSecretJwt,
+            "client_secret_post" => OAuthEndpointAuthenticationMethod::ClientSecretPost,
+            "none" => OAuthEndpointAuthenticationMethod::None,
+            "private_key_jwt" => OAuthEndpointAuthenticationMethod::PrivateKeyJwt,
+            "" => OAuthEndpointAuthenticationMethod::Noop,
+            _ => OAuthEndpointAuthenticationMethod::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OAuthEndpointAuthenticationMethod> for String {
+    fn from(e: OAuthEndpointAuthenticationMethod) -> String {
+        match e {
+            OAuthEndpointAuthenticationMethod::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OAuthEndpointAuthenticationMethod {
     fn default() -> OAuthEndpointAuthenticationMethod {
         OAuthEndpointAuthenticationMethod::Noop
@@ -5436,6 +6687,7 @@ impl OAuthEndpointAuthenticationMethod {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OAuthGrantType {
     #[serde(rename = "authorization_code")]
     AuthorizationCode,
@@ -5449,8 +6701,9 @@ pub enum OAuthGrantType {
     RefreshToken,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthGrantType {
@@ -5462,12 +6715,35 @@ impl std::fmt::Display for OAuthGrantType {
             OAuthGrantType::Password => "password",
             OAuthGrantType::RefreshToken => "refresh_token",
             OAuthGrantType::Noop => "",
-            OAuthGrantType::FallthroughString => "*",
+            OAuthGrantType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OAuthGrantType {
+    fn from(s: String) -> OAuthGrantType {
+        match s.as_str() {
+            "authorization_code" => OAuthGrantType::AuthorizationCode,
+            "client_credentials" => OAuthGrantType::ClientCredentials,
+            "implicit" => OAuthGrantType::Implicit,
+            "password" => OAuthGrantType::Password,
+            "refresh_token" => OAuthGrantType::RefreshToken,
+            "" => OAuthGrantType::Noop,
+            _ => OAuthGrantType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OAuthGrantType> for String {
+    fn from(e: OAuthGrantType) -> String {
+        match e {
+            OAuthGrantType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OAuthGrantType {
     fn default() -> OAuthGrantType {
         OAuthGrantType::Noop
@@ -5480,6 +6756,7 @@ impl OAuthGrantType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OAuthResponseType {
     #[serde(rename = "code")]
     Code,
@@ -5489,8 +6766,9 @@ pub enum OAuthResponseType {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OAuthResponseType {
@@ -5500,12 +6778,33 @@ impl std::fmt::Display for OAuthResponseType {
             OAuthResponseType::IdToken => "id_token",
             OAuthResponseType::Token => "token",
             OAuthResponseType::Noop => "",
-            OAuthResponseType::FallthroughString => "*",
+            OAuthResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OAuthResponseType {
+    fn from(s: String) -> OAuthResponseType {
+        match s.as_str() {
+            "code" => OAuthResponseType::Code,
+            "id_token" => OAuthResponseType::IdToken,
+            "token" => OAuthResponseType::Token,
+            "" => OAuthResponseType::Noop,
+            _ => OAuthResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OAuthResponseType> for String {
+    fn from(e: OAuthResponseType) -> String {
+        match e {
+            OAuthResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OAuthResponseType {
     fn default() -> OAuthResponseType {
         OAuthResponseType::Noop
@@ -5564,6 +6863,7 @@ pub struct OktaSignOnPolicyRuleConditions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Access {
     #[serde(rename = "ALLOW")]
     Allow,
@@ -5571,8 +6871,9 @@ pub enum Access {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Access {
@@ -5581,12 +6882,32 @@ impl std::fmt::Display for Access {
             Access::Allow => "ALLOW",
             Access::Deny => "DENY",
             Access::Noop => "",
-            Access::FallthroughString => "*",
+            Access::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Access {
+    fn from(s: String) -> Access {
+        match s.as_str() {
+            "ALLOW" => Access::Allow,
+            "DENY" => Access::Deny,
+            "" => Access::Noop,
+            _ => Access::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Access> for String {
+    fn from(e: Access) -> String {
+        match e {
+            Access::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Access {
     fn default() -> Access {
         Access::Noop
@@ -5599,6 +6920,7 @@ impl Access {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorPromptMode {
     #[serde(rename = "ALWAYS")]
     Always,
@@ -5608,8 +6930,9 @@ pub enum FactorPromptMode {
     Session,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorPromptMode {
@@ -5619,12 +6942,33 @@ impl std::fmt::Display for FactorPromptMode {
             FactorPromptMode::Device => "DEVICE",
             FactorPromptMode::Session => "SESSION",
             FactorPromptMode::Noop => "",
-            FactorPromptMode::FallthroughString => "*",
+            FactorPromptMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FactorPromptMode {
+    fn from(s: String) -> FactorPromptMode {
+        match s.as_str() {
+            "ALWAYS" => FactorPromptMode::Always,
+            "DEVICE" => FactorPromptMode::Device,
+            "SESSION" => FactorPromptMode::Session,
+            "" => FactorPromptMode::Noop,
+            _ => FactorPromptMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FactorPromptMode> for String {
+    fn from(e: FactorPromptMode) -> String {
+        match e {
+            FactorPromptMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FactorPromptMode {
     fn default() -> FactorPromptMode {
         FactorPromptMode::Noop
@@ -5704,6 +7048,7 @@ pub struct OpenConnectApplication {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OpenConnectApplicationConsentMethod {
     #[serde(rename = "REQUIRED")]
     Required,
@@ -5711,8 +7056,9 @@ pub enum OpenConnectApplicationConsentMethod {
     Trusted,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationConsentMethod {
@@ -5721,12 +7067,32 @@ impl std::fmt::Display for OpenConnectApplicationConsentMethod {
             OpenConnectApplicationConsentMethod::Required => "REQUIRED",
             OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
             OpenConnectApplicationConsentMethod::Noop => "",
-            OpenConnectApplicationConsentMethod::FallthroughString => "*",
+            OpenConnectApplicationConsentMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OpenConnectApplicationConsentMethod {
+    fn from(s: String) -> OpenConnectApplicationConsentMethod {
+        match s.as_str() {
+            "REQUIRED" => OpenConnectApplicationConsentMethod::Required,
+            "TRUSTED" => OpenConnectApplicationConsentMethod::Trusted,
+            "" => OpenConnectApplicationConsentMethod::Noop,
+            _ => OpenConnectApplicationConsentMethod::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OpenConnectApplicationConsentMethod> for String {
+    fn from(e: OpenConnectApplicationConsentMethod) -> String {
+        match e {
+            OpenConnectApplicationConsentMethod::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OpenConnectApplicationConsentMethod {
     fn default() -> OpenConnectApplicationConsentMethod {
         OpenConnectApplicationConsentMethod::Noop
@@ -5863,6 +7229,7 @@ pub struct OpenConnectApplicationSettingsRefreshToken {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OpenConnectApplicationType {
     #[serde(rename = "browser")]
     Browser,
@@ -5874,8 +7241,9 @@ pub enum OpenConnectApplicationType {
     Web,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectApplicationType {
@@ -5886,12 +7254,34 @@ impl std::fmt::Display for OpenConnectApplicationType {
             OpenConnectApplicationType::Service => "service",
             OpenConnectApplicationType::Web => "web",
             OpenConnectApplicationType::Noop => "",
-            OpenConnectApplicationType::FallthroughString => "*",
+            OpenConnectApplicationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OpenConnectApplicationType {
+    fn from(s: String) -> OpenConnectApplicationType {
+        match s.as_str() {
+            "browser" => OpenConnectApplicationType::Browser,
+            "native" => OpenConnectApplicationType::Native,
+            "service" => OpenConnectApplicationType::Service,
+            "web" => OpenConnectApplicationType::Web,
+            "" => OpenConnectApplicationType::Noop,
+            _ => OpenConnectApplicationType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OpenConnectApplicationType> for String {
+    fn from(e: OpenConnectApplicationType) -> String {
+        match e {
+            OpenConnectApplicationType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OpenConnectApplicationType {
     fn default() -> OpenConnectApplicationType {
         OpenConnectApplicationType::Noop
@@ -5904,6 +7294,7 @@ impl OpenConnectApplicationType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OpenConnectRefreshTokenRotationType {
     #[serde(rename = "rotate")]
     Rotate,
@@ -5911,8 +7302,9 @@ pub enum OpenConnectRefreshTokenRotationType {
     Static,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
@@ -5921,12 +7313,32 @@ impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
             OpenConnectRefreshTokenRotationType::Rotate => "rotate",
             OpenConnectRefreshTokenRotationType::Static => "static",
             OpenConnectRefreshTokenRotationType::Noop => "",
-            OpenConnectRefreshTokenRotationType::FallthroughString => "*",
+            OpenConnectRefreshTokenRotationType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OpenConnectRefreshTokenRotationType {
+    fn from(s: String) -> OpenConnectRefreshTokenRotationType {
+        match s.as_str() {
+            "rotate" => OpenConnectRefreshTokenRotationType::Rotate,
+            "static" => OpenConnectRefreshTokenRotationType::Static,
+            "" => OpenConnectRefreshTokenRotationType::Noop,
+            _ => OpenConnectRefreshTokenRotationType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OpenConnectRefreshTokenRotationType> for String {
+    fn from(e: OpenConnectRefreshTokenRotationType) -> String {
+        match e {
+            OpenConnectRefreshTokenRotationType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OpenConnectRefreshTokenRotationType {
     fn default() -> OpenConnectRefreshTokenRotationType {
         OpenConnectRefreshTokenRotationType::Noop
@@ -5985,6 +7397,7 @@ pub struct PasswordCredentialHash {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PasswordCredentialHashAlgorithm {
     #[serde(rename = "BCRYPT")]
     Bcrypt,
@@ -5998,8 +7411,9 @@ pub enum PasswordCredentialHashAlgorithm {
     Sha512,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordCredentialHashAlgorithm {
@@ -6011,12 +7425,35 @@ impl std::fmt::Display for PasswordCredentialHashAlgorithm {
             PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
             PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
             PasswordCredentialHashAlgorithm::Noop => "",
-            PasswordCredentialHashAlgorithm::FallthroughString => "*",
+            PasswordCredentialHashAlgorithm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PasswordCredentialHashAlgorithm {
+    fn from(s: String) -> PasswordCredentialHashAlgorithm {
+        match s.as_str() {
+            "BCRYPT" => PasswordCredentialHashAlgorithm::Bcrypt,
+            "MD5" => PasswordCredentialHashAlgorithm::Md5,
+            "SHA-1" => PasswordCredentialHashAlgorithm::ShAminusOne,
+            "SHA-256" => PasswordCredentialHashAlgorithm::Sha256,
+            "SHA-512" => PasswordCredentialHashAlgorithm::Sha512,
+            "" => PasswordCredentialHashAlgorithm::Noop,
+            _ => PasswordCredentialHashAlgorithm::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PasswordCredentialHashAlgorithm> for String {
+    fn from(e: PasswordCredentialHashAlgorithm) -> String {
+        match e {
+            PasswordCredentialHashAlgorithm::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PasswordCredentialHashAlgorithm {
     fn default() -> PasswordCredentialHashAlgorithm {
         PasswordCredentialHashAlgorithm::Noop
@@ -6063,6 +7500,7 @@ pub struct PasswordPolicy {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PasswordPolicyAuthenticationProviderCondition {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -6074,8 +7512,9 @@ pub enum PasswordPolicyAuthenticationProviderCondition {
     Okta,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
@@ -6086,12 +7525,34 @@ impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
             PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
             PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
             PasswordPolicyAuthenticationProviderCondition::Noop => "",
-            PasswordPolicyAuthenticationProviderCondition::FallthroughString => "*",
+            PasswordPolicyAuthenticationProviderCondition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PasswordPolicyAuthenticationProviderCondition {
+    fn from(s: String) -> PasswordPolicyAuthenticationProviderCondition {
+        match s.as_str() {
+            "ACTIVE_DIRECTORY" => PasswordPolicyAuthenticationProviderCondition::ActiveDirectory,
+            "ANY" => PasswordPolicyAuthenticationProviderCondition::Any,
+            "LDAP" => PasswordPolicyAuthenticationProviderCondition::Ldap,
+            "OKTA" => PasswordPolicyAuthenticationProviderCondition::Okta,
+            "" => PasswordPolicyAuthenticationProviderCondition::Noop,
+            _ => PasswordPolicyAuthenticationProviderCondition::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PasswordPolicyAuthenticationProviderCondition> for String {
+    fn from(e: PasswordPolicyAuthenticationProviderCondition) -> String {
+        match e {
+            PasswordPolicyAuthenticationProviderCondition::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PasswordPolicyAuthenticationProviderCondition {
     fn default() -> PasswordPolicyAuthenticationProviderCondition {
         PasswordPolicyAuthenticationProviderCondition::Noop
@@ -6409,6 +7870,7 @@ pub struct PasswordPolicySettingsData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PlatformConditionEvaluatorType {
     #[serde(rename = "ANY")]
     Any,
@@ -6420,8 +7882,9 @@ pub enum PlatformConditionEvaluatorType {
     Other,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorType {
@@ -6432,12 +7895,34 @@ impl std::fmt::Display for PlatformConditionEvaluatorType {
             PlatformConditionEvaluatorType::Mobile => "MOBILE",
             PlatformConditionEvaluatorType::Other => "OTHER",
             PlatformConditionEvaluatorType::Noop => "",
-            PlatformConditionEvaluatorType::FallthroughString => "*",
+            PlatformConditionEvaluatorType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PlatformConditionEvaluatorType {
+    fn from(s: String) -> PlatformConditionEvaluatorType {
+        match s.as_str() {
+            "ANY" => PlatformConditionEvaluatorType::Any,
+            "DESKTOP" => PlatformConditionEvaluatorType::Desktop,
+            "MOBILE" => PlatformConditionEvaluatorType::Mobile,
+            "OTHER" => PlatformConditionEvaluatorType::Other,
+            "" => PlatformConditionEvaluatorType::Noop,
+            _ => PlatformConditionEvaluatorType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PlatformConditionEvaluatorType> for String {
+    fn from(e: PlatformConditionEvaluatorType) -> String {
+        match e {
+            PlatformConditionEvaluatorType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PlatformConditionEvaluatorType {
     fn default() -> PlatformConditionEvaluatorType {
         PlatformConditionEvaluatorType::Noop
@@ -6458,6 +7943,7 @@ pub struct PlatformConditionEvaluator {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PlatformConditionEvaluatorOperatingSystemType {
     #[serde(rename = "ANDROID")]
     Android,
@@ -6473,8 +7959,9 @@ pub enum PlatformConditionEvaluatorOperatingSystemType {
     Windows,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
@@ -6487,12 +7974,36 @@ impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
             PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
             PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
             PlatformConditionEvaluatorOperatingSystemType::Noop => "",
-            PlatformConditionEvaluatorOperatingSystemType::FallthroughString => "*",
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PlatformConditionEvaluatorOperatingSystemType {
+    fn from(s: String) -> PlatformConditionEvaluatorOperatingSystemType {
+        match s.as_str() {
+            "ANDROID" => PlatformConditionEvaluatorOperatingSystemType::Android,
+            "ANY" => PlatformConditionEvaluatorOperatingSystemType::Any,
+            "IOS" => PlatformConditionEvaluatorOperatingSystemType::Ios,
+            "OSX" => PlatformConditionEvaluatorOperatingSystemType::Osx,
+            "OTHER" => PlatformConditionEvaluatorOperatingSystemType::Other,
+            "WINDOWS" => PlatformConditionEvaluatorOperatingSystemType::Windows,
+            "" => PlatformConditionEvaluatorOperatingSystemType::Noop,
+            _ => PlatformConditionEvaluatorOperatingSystemType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PlatformConditionEvaluatorOperatingSystemType> for String {
+    fn from(e: PlatformConditionEvaluatorOperatingSystemType) -> String {
+        match e {
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PlatformConditionEvaluatorOperatingSystemType {
     fn default() -> PlatformConditionEvaluatorOperatingSystemType {
         PlatformConditionEvaluatorOperatingSystemType::Noop
@@ -6519,6 +8030,7 @@ pub struct PlatformConditionEvaluatorOperatingSystem {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MatchType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -6526,8 +8038,9 @@ pub enum MatchType {
     Semver,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -6536,12 +8049,32 @@ impl std::fmt::Display for MatchType {
             MatchType::Expression => "EXPRESSION",
             MatchType::Semver => "SEMVER",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MatchType {
+    fn from(s: String) -> MatchType {
+        match s.as_str() {
+            "EXPRESSION" => MatchType::Expression,
+            "SEMVER" => MatchType::Semver,
+            "" => MatchType::Noop,
+            _ => MatchType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MatchType> for String {
+    fn from(e: MatchType) -> String {
+        match e {
+            MatchType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MatchType {
     fn default() -> MatchType {
         MatchType::Noop
@@ -6638,6 +8171,7 @@ pub struct Policy {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Action {
     #[serde(rename = "AUTO")]
     Auto,
@@ -6645,8 +8179,9 @@ pub enum Action {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -6655,12 +8190,32 @@ impl std::fmt::Display for Action {
             Action::Auto => "AUTO",
             Action::Disabled => "DISABLED",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Action {
+    fn from(s: String) -> Action {
+        match s.as_str() {
+            "AUTO" => Action::Auto,
+            "DISABLED" => Action::Disabled,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Action> for String {
+    fn from(e: Action) -> String {
+        match e {
+            Action::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -6687,6 +8242,7 @@ pub struct PolicyAccountLinkFilter {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Connection {
     #[serde(rename = "ANYWHERE")]
     Anywhere,
@@ -6694,8 +8250,9 @@ pub enum Connection {
     Zone,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Connection {
@@ -6704,12 +8261,32 @@ impl std::fmt::Display for Connection {
             Connection::Anywhere => "ANYWHERE",
             Connection::Zone => "ZONE",
             Connection::Noop => "",
-            Connection::FallthroughString => "*",
+            Connection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Connection {
+    fn from(s: String) -> Connection {
+        match s.as_str() {
+            "ANYWHERE" => Connection::Anywhere,
+            "ZONE" => Connection::Zone,
+            "" => Connection::Noop,
+            _ => Connection::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Connection> for String {
+    fn from(e: Connection) -> String {
+        match e {
+            Connection::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Connection {
     fn default() -> Connection {
         Connection::Noop
@@ -6748,6 +8325,7 @@ pub struct PolicyPeopleCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PolicyRuleType {
     #[serde(rename = "PASSWORD")]
     Password,
@@ -6755,8 +8333,9 @@ pub enum PolicyRuleType {
     SignOn,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleType {
@@ -6765,12 +8344,32 @@ impl std::fmt::Display for PolicyRuleType {
             PolicyRuleType::Password => "PASSWORD",
             PolicyRuleType::SignOn => "SIGN_ON",
             PolicyRuleType::Noop => "",
-            PolicyRuleType::FallthroughString => "*",
+            PolicyRuleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PolicyRuleType {
+    fn from(s: String) -> PolicyRuleType {
+        match s.as_str() {
+            "PASSWORD" => PolicyRuleType::Password,
+            "SIGN_ON" => PolicyRuleType::SignOn,
+            "" => PolicyRuleType::Noop,
+            _ => PolicyRuleType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PolicyRuleType> for String {
+    fn from(e: PolicyRuleType) -> String {
+        match e {
+            PolicyRuleType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PolicyRuleType {
     fn default() -> PolicyRuleType {
         PolicyRuleType::Noop
@@ -6863,6 +8462,7 @@ pub struct PolicyRuleActionsEnroll {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PolicyRuleActionsEnrollSelf {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -6872,8 +8472,9 @@ pub enum PolicyRuleActionsEnrollSelf {
     Never,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
@@ -6883,12 +8484,33 @@ impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
             PolicyRuleActionsEnrollSelf::Login => "LOGIN",
             PolicyRuleActionsEnrollSelf::Never => "NEVER",
             PolicyRuleActionsEnrollSelf::Noop => "",
-            PolicyRuleActionsEnrollSelf::FallthroughString => "*",
+            PolicyRuleActionsEnrollSelf::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PolicyRuleActionsEnrollSelf {
+    fn from(s: String) -> PolicyRuleActionsEnrollSelf {
+        match s.as_str() {
+            "CHALLENGE" => PolicyRuleActionsEnrollSelf::Challenge,
+            "LOGIN" => PolicyRuleActionsEnrollSelf::Login,
+            "NEVER" => PolicyRuleActionsEnrollSelf::Never,
+            "" => PolicyRuleActionsEnrollSelf::Noop,
+            _ => PolicyRuleActionsEnrollSelf::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PolicyRuleActionsEnrollSelf> for String {
+    fn from(e: PolicyRuleActionsEnrollSelf) -> String {
+        match e {
+            PolicyRuleActionsEnrollSelf::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PolicyRuleActionsEnrollSelf {
     fn default() -> PolicyRuleActionsEnrollSelf {
         PolicyRuleActionsEnrollSelf::Noop
@@ -6901,6 +8523,7 @@ impl PolicyRuleActionsEnrollSelf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AuthType {
     #[serde(rename = "ANY")]
     Any,
@@ -6908,8 +8531,9 @@ pub enum AuthType {
     Radius,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AuthType {
@@ -6918,12 +8542,32 @@ impl std::fmt::Display for AuthType {
             AuthType::Any => "ANY",
             AuthType::Radius => "RADIUS",
             AuthType::Noop => "",
-            AuthType::FallthroughString => "*",
+            AuthType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AuthType {
+    fn from(s: String) -> AuthType {
+        match s.as_str() {
+            "ANY" => AuthType::Any,
+            "RADIUS" => AuthType::Radius,
+            "" => AuthType::Noop,
+            _ => AuthType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AuthType> for String {
+    fn from(e: AuthType) -> String {
+        match e {
+            AuthType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AuthType {
     fn default() -> AuthType {
         AuthType::Noop
@@ -7051,6 +8695,7 @@ pub struct PolicySubject {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PolicySubjectMatchType {
     #[serde(rename = "CUSTOM_ATTRIBUTE")]
     CustomAttribute,
@@ -7062,8 +8707,9 @@ pub enum PolicySubjectMatchType {
     UsernameOrEmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicySubjectMatchType {
@@ -7074,12 +8720,34 @@ impl std::fmt::Display for PolicySubjectMatchType {
             PolicySubjectMatchType::Username => "USERNAME",
             PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
             PolicySubjectMatchType::Noop => "",
-            PolicySubjectMatchType::FallthroughString => "*",
+            PolicySubjectMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PolicySubjectMatchType {
+    fn from(s: String) -> PolicySubjectMatchType {
+        match s.as_str() {
+            "CUSTOM_ATTRIBUTE" => PolicySubjectMatchType::CustomAttribute,
+            "EMAIL" => PolicySubjectMatchType::Email,
+            "USERNAME" => PolicySubjectMatchType::Username,
+            "USERNAME_OR_EMAIL" => PolicySubjectMatchType::UsernameOrEmail,
+            "" => PolicySubjectMatchType::Noop,
+            _ => PolicySubjectMatchType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PolicySubjectMatchType> for String {
+    fn from(e: PolicySubjectMatchType) -> String {
+        match e {
+            PolicySubjectMatchType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PolicySubjectMatchType {
     fn default() -> PolicySubjectMatchType {
         PolicySubjectMatchType::Noop
@@ -7092,6 +8760,7 @@ impl PolicySubjectMatchType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PolicyType {
     #[serde(rename = "IDP_DISCOVERY")]
     IdpDiscovery,
@@ -7103,8 +8772,9 @@ pub enum PolicyType {
     Password,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PolicyType {
@@ -7115,12 +8785,34 @@ impl std::fmt::Display for PolicyType {
             PolicyType::OktaSignOn => "OKTA_SIGN_ON",
             PolicyType::Password => "PASSWORD",
             PolicyType::Noop => "",
-            PolicyType::FallthroughString => "*",
+            PolicyType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PolicyType {
+    fn from(s: String) -> PolicyType {
+        match s.as_str() {
+            "IDP_DISCOVERY" => PolicyType::IdpDiscovery,
+            "OAUTH_AUTHORIZATION_POLICY" => PolicyType::OauthAuthorizationPolicy,
+            "OKTA_SIGN_ON" => PolicyType::OktaSignOn,
+            "PASSWORD" => PolicyType::Password,
+            "" => PolicyType::Noop,
+            _ => PolicyType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PolicyType> for String {
+    fn from(e: PolicyType) -> String {
+        match e {
+            PolicyType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PolicyType {
     fn default() -> PolicyType {
         PolicyType::Noop
@@ -7202,6 +8894,7 @@ pub struct ProfileMappingSource {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProtocolType {
     #[serde(rename = "MTLS")]
     Mtls,
@@ -7213,8 +8906,9 @@ pub enum ProtocolType {
     Saml2,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolType {
@@ -7225,12 +8919,34 @@ impl std::fmt::Display for ProtocolType {
             ProtocolType::Oidc => "OIDC",
             ProtocolType::Saml2 => "SAML2",
             ProtocolType::Noop => "",
-            ProtocolType::FallthroughString => "*",
+            ProtocolType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProtocolType {
+    fn from(s: String) -> ProtocolType {
+        match s.as_str() {
+            "MTLS" => ProtocolType::Mtls,
+            "OAUTH2" => ProtocolType::Oauth2,
+            "OIDC" => ProtocolType::Oidc,
+            "SAML2" => ProtocolType::Saml2,
+            "" => ProtocolType::Noop,
+            _ => ProtocolType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProtocolType> for String {
+    fn from(e: ProtocolType) -> String {
+        match e {
+            ProtocolType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProtocolType {
     fn default() -> ProtocolType {
         ProtocolType::Noop
@@ -7277,6 +8993,7 @@ pub struct ProtocolAlgorithmType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Scope {
     #[serde(rename = "ANY")]
     Any,
@@ -7290,8 +9007,9 @@ pub enum Scope {
     Token,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Scope {
@@ -7303,12 +9021,35 @@ impl std::fmt::Display for Scope {
             Scope::Response => "RESPONSE",
             Scope::Token => "TOKEN",
             Scope::Noop => "",
-            Scope::FallthroughString => "*",
+            Scope::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Scope {
+    fn from(s: String) -> Scope {
+        match s.as_str() {
+            "ANY" => Scope::Any,
+            "NONE" => Scope::None,
+            "REQUEST" => Scope::Request,
+            "RESPONSE" => Scope::Response,
+            "TOKEN" => Scope::Token,
+            "" => Scope::Noop,
+            _ => Scope::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Scope> for String {
+    fn from(e: Scope) -> String {
+        match e {
+            Scope::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Scope {
     fn default() -> Scope {
         Scope::Noop
@@ -7341,6 +9082,7 @@ pub struct ProtocolAlgorithms {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Binding {
     #[serde(rename = "HTTP-POST")]
     HttpPost,
@@ -7348,8 +9090,9 @@ pub enum Binding {
     HttpRedirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Binding {
@@ -7358,12 +9101,32 @@ impl std::fmt::Display for Binding {
             Binding::HttpPost => "HTTP-POST",
             Binding::HttpRedirect => "HTTP-REDIRECT",
             Binding::Noop => "",
-            Binding::FallthroughString => "*",
+            Binding::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Binding {
+    fn from(s: String) -> Binding {
+        match s.as_str() {
+            "HTTP-POST" => Binding::HttpPost,
+            "HTTP-REDIRECT" => Binding::HttpRedirect,
+            "" => Binding::Noop,
+            _ => Binding::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Binding> for String {
+    fn from(e: Binding) -> String {
+        match e {
+            Binding::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Binding {
     fn default() -> Binding {
         Binding::Noop
@@ -7376,6 +9139,7 @@ impl Binding {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProtocolEndpointType {
     #[serde(rename = "INSTANCE")]
     Instance,
@@ -7383,8 +9147,9 @@ pub enum ProtocolEndpointType {
     Org,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolEndpointType {
@@ -7393,12 +9158,32 @@ impl std::fmt::Display for ProtocolEndpointType {
             ProtocolEndpointType::Instance => "INSTANCE",
             ProtocolEndpointType::Org => "ORG",
             ProtocolEndpointType::Noop => "",
-            ProtocolEndpointType::FallthroughString => "*",
+            ProtocolEndpointType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProtocolEndpointType {
+    fn from(s: String) -> ProtocolEndpointType {
+        match s.as_str() {
+            "INSTANCE" => ProtocolEndpointType::Instance,
+            "ORG" => ProtocolEndpointType::Org,
+            "" => ProtocolEndpointType::Noop,
+            _ => ProtocolEndpointType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProtocolEndpointType> for String {
+    fn from(e: ProtocolEndpointType) -> String {
+        match e {
+            ProtocolEndpointType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProtocolEndpointType {
     fn default() -> ProtocolEndpointType {
         ProtocolEndpointType::Noop
@@ -7457,6 +9242,7 @@ pub struct ProtocolRelayState {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProtocolRelayStateFormat {
     #[serde(rename = "FROM_URL")]
     FromUrl,
@@ -7464,8 +9250,9 @@ pub enum ProtocolRelayStateFormat {
     Opaque,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProtocolRelayStateFormat {
@@ -7474,12 +9261,32 @@ impl std::fmt::Display for ProtocolRelayStateFormat {
             ProtocolRelayStateFormat::FromUrl => "FROM_URL",
             ProtocolRelayStateFormat::Opaque => "OPAQUE",
             ProtocolRelayStateFormat::Noop => "",
-            ProtocolRelayStateFormat::FallthroughString => "*",
+            ProtocolRelayStateFormat::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProtocolRelayStateFormat {
+    fn from(s: String) -> ProtocolRelayStateFormat {
+        match s.as_str() {
+            "FROM_URL" => ProtocolRelayStateFormat::FromUrl,
+            "OPAQUE" => ProtocolRelayStateFormat::Opaque,
+            "" => ProtocolRelayStateFormat::Noop,
+            _ => ProtocolRelayStateFormat::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProtocolRelayStateFormat> for String {
+    fn from(e: ProtocolRelayStateFormat) -> String {
+        match e {
+            ProtocolRelayStateFormat::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProtocolRelayStateFormat {
     fn default() -> ProtocolRelayStateFormat {
         ProtocolRelayStateFormat::Noop
@@ -7503,6 +9310,7 @@ pub struct ProtocolSettings {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProvisioningAction {
     #[serde(rename = "AUTO")]
     Auto,
@@ -7512,8 +9320,9 @@ pub enum ProvisioningAction {
     Disabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningAction {
@@ -7523,12 +9332,33 @@ impl std::fmt::Display for ProvisioningAction {
             ProvisioningAction::Callout => "CALLOUT",
             ProvisioningAction::Disabled => "DISABLED",
             ProvisioningAction::Noop => "",
-            ProvisioningAction::FallthroughString => "*",
+            ProvisioningAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProvisioningAction {
+    fn from(s: String) -> ProvisioningAction {
+        match s.as_str() {
+            "AUTO" => ProvisioningAction::Auto,
+            "CALLOUT" => ProvisioningAction::Callout,
+            "DISABLED" => ProvisioningAction::Disabled,
+            "" => ProvisioningAction::Noop,
+            _ => ProvisioningAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProvisioningAction> for String {
+    fn from(e: ProvisioningAction) -> String {
+        match e {
+            ProvisioningAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProvisioningAction {
     fn default() -> ProvisioningAction {
         ProvisioningAction::Noop
@@ -7565,6 +9395,7 @@ pub struct ProvisioningConditions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProvisioningDeprovisionedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7572,8 +9403,9 @@ pub enum ProvisioningDeprovisionedConditionAction {
     Reactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
@@ -7582,12 +9414,32 @@ impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
             ProvisioningDeprovisionedConditionAction::None => "NONE",
             ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
             ProvisioningDeprovisionedConditionAction::Noop => "",
-            ProvisioningDeprovisionedConditionAction::FallthroughString => "*",
+            ProvisioningDeprovisionedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProvisioningDeprovisionedConditionAction {
+    fn from(s: String) -> ProvisioningDeprovisionedConditionAction {
+        match s.as_str() {
+            "NONE" => ProvisioningDeprovisionedConditionAction::None,
+            "REACTIVATE" => ProvisioningDeprovisionedConditionAction::Reactivate,
+            "" => ProvisioningDeprovisionedConditionAction::Noop,
+            _ => ProvisioningDeprovisionedConditionAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProvisioningDeprovisionedConditionAction> for String {
+    fn from(e: ProvisioningDeprovisionedConditionAction) -> String {
+        match e {
+            ProvisioningDeprovisionedConditionAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProvisioningDeprovisionedConditionAction {
     fn default() -> ProvisioningDeprovisionedConditionAction {
         ProvisioningDeprovisionedConditionAction::Noop
@@ -7606,6 +9458,7 @@ pub struct ProvisioningDeprovisionedCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProvisioningGroupsAction {
     #[serde(rename = "APPEND")]
     Append,
@@ -7617,8 +9470,9 @@ pub enum ProvisioningGroupsAction {
     Sync,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningGroupsAction {
@@ -7629,12 +9483,34 @@ impl std::fmt::Display for ProvisioningGroupsAction {
             ProvisioningGroupsAction::None => "NONE",
             ProvisioningGroupsAction::Sync => "SYNC",
             ProvisioningGroupsAction::Noop => "",
-            ProvisioningGroupsAction::FallthroughString => "*",
+            ProvisioningGroupsAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProvisioningGroupsAction {
+    fn from(s: String) -> ProvisioningGroupsAction {
+        match s.as_str() {
+            "APPEND" => ProvisioningGroupsAction::Append,
+            "ASSIGN" => ProvisioningGroupsAction::Assign,
+            "NONE" => ProvisioningGroupsAction::None,
+            "SYNC" => ProvisioningGroupsAction::Sync,
+            "" => ProvisioningGroupsAction::Noop,
+            _ => ProvisioningGroupsAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProvisioningGroupsAction> for String {
+    fn from(e: ProvisioningGroupsAction) -> String {
+        match e {
+            ProvisioningGroupsAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProvisioningGroupsAction {
     fn default() -> ProvisioningGroupsAction {
         ProvisioningGroupsAction::Noop
@@ -7672,6 +9548,7 @@ pub struct ProvisioningGroups {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProvisioningSuspendedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7679,8 +9556,9 @@ pub enum ProvisioningSuspendedConditionAction {
     Unsuspend,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProvisioningSuspendedConditionAction {
@@ -7689,12 +9567,32 @@ impl std::fmt::Display for ProvisioningSuspendedConditionAction {
             ProvisioningSuspendedConditionAction::None => "NONE",
             ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
             ProvisioningSuspendedConditionAction::Noop => "",
-            ProvisioningSuspendedConditionAction::FallthroughString => "*",
+            ProvisioningSuspendedConditionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProvisioningSuspendedConditionAction {
+    fn from(s: String) -> ProvisioningSuspendedConditionAction {
+        match s.as_str() {
+            "NONE" => ProvisioningSuspendedConditionAction::None,
+            "UNSUSPEND" => ProvisioningSuspendedConditionAction::Unsuspend,
+            "" => ProvisioningSuspendedConditionAction::Noop,
+            _ => ProvisioningSuspendedConditionAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProvisioningSuspendedConditionAction> for String {
+    fn from(e: ProvisioningSuspendedConditionAction) -> String {
+        match e {
+            ProvisioningSuspendedConditionAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProvisioningSuspendedConditionAction {
     fn default() -> ProvisioningSuspendedConditionAction {
         ProvisioningSuspendedConditionAction::Noop
@@ -7860,6 +9758,7 @@ pub struct Role {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RoleAssignmentType {
     #[serde(rename = "GROUP")]
     Group,
@@ -7867,8 +9766,9 @@ pub enum RoleAssignmentType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleAssignmentType {
@@ -7877,12 +9777,32 @@ impl std::fmt::Display for RoleAssignmentType {
             RoleAssignmentType::Group => "GROUP",
             RoleAssignmentType::User => "USER",
             RoleAssignmentType::Noop => "",
-            RoleAssignmentType::FallthroughString => "*",
+            RoleAssignmentType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RoleAssignmentType {
+    fn from(s: String) -> RoleAssignmentType {
+        match s.as_str() {
+            "GROUP" => RoleAssignmentType::Group,
+            "USER" => RoleAssignmentType::User,
+            "" => RoleAssignmentType::Noop,
+            _ => RoleAssignmentType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RoleAssignmentType> for String {
+    fn from(e: RoleAssignmentType) -> String {
+        match e {
+            RoleAssignmentType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RoleAssignmentType {
     fn default() -> RoleAssignmentType {
         RoleAssignmentType::Noop
@@ -7895,6 +9815,7 @@ impl RoleAssignmentType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RoleType {
     #[serde(rename = "API_ACCESS_MANAGEMENT_ADMIN")]
     ApiAccessManagementAdmin,
@@ -7916,8 +9837,9 @@ pub enum RoleType {
     UserAdmin,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RoleType {
@@ -7933,12 +9855,39 @@ impl std::fmt::Display for RoleType {
             RoleType::SuperAdmin => "SUPER_ADMIN",
             RoleType::UserAdmin => "USER_ADMIN",
             RoleType::Noop => "",
-            RoleType::FallthroughString => "*",
+            RoleType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RoleType {
+    fn from(s: String) -> RoleType {
+        match s.as_str() {
+            "API_ACCESS_MANAGEMENT_ADMIN" => RoleType::ApiAccessManagementAdmin,
+            "APP_ADMIN" => RoleType::AppAdmin,
+            "HELP_DESK_ADMIN" => RoleType::HelpDeskAdmin,
+            "MOBILE_ADMIN" => RoleType::MobileAdmin,
+            "ORG_ADMIN" => RoleType::OrgAdmin,
+            "READ_ONLY_ADMIN" => RoleType::ReadOnlyAdmin,
+            "REPORT_ADMIN" => RoleType::ReportAdmin,
+            "SUPER_ADMIN" => RoleType::SuperAdmin,
+            "USER_ADMIN" => RoleType::UserAdmin,
+            "" => RoleType::Noop,
+            _ => RoleType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RoleType> for String {
+    fn from(e: RoleType) -> String {
+        match e {
+            RoleType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RoleType {
     fn default() -> RoleType {
         RoleType::Noop
@@ -8178,6 +10127,7 @@ pub struct SamlAttributeStatement {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Value {
     #[serde(rename = "ACTIVATING")]
     Activating,
@@ -8197,8 +10147,9 @@ pub enum Value {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Value {
@@ -8213,12 +10164,38 @@ impl std::fmt::Display for Value {
             Value::Pending => "PENDING",
             Value::Suspended => "SUSPENDED",
             Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Value {
+    fn from(s: String) -> Value {
+        match s.as_str() {
+            "ACTIVATING" => Value::Activating,
+            "ACTIVE" => Value::Active,
+            "DELETED" => Value::Deleted,
+            "DELETING" => Value::Deleting,
+            "EXPIRED_PASSWORD" => Value::ExpiredPassword,
+            "INACTIVE" => Value::Inactive,
+            "PENDING" => Value::Pending,
+            "SUSPENDED" => Value::Suspended,
+            "" => Value::Noop,
+            _ => Value::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Value> for String {
+    fn from(e: Value) -> String {
+        match e {
+            Value::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Value {
     fn default() -> Value {
         Value::Noop
@@ -8273,6 +10250,7 @@ pub struct ScopeData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ScopeType {
     #[serde(rename = "CORS")]
     Cors,
@@ -8280,8 +10258,9 @@ pub enum ScopeType {
     Redirect,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScopeType {
@@ -8290,12 +10269,32 @@ impl std::fmt::Display for ScopeType {
             ScopeType::Cors => "CORS",
             ScopeType::Redirect => "REDIRECT",
             ScopeType::Noop => "",
-            ScopeType::FallthroughString => "*",
+            ScopeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ScopeType {
+    fn from(s: String) -> ScopeType {
+        match s.as_str() {
+            "CORS" => ScopeType::Cors,
+            "REDIRECT" => ScopeType::Redirect,
+            "" => ScopeType::Noop,
+            _ => ScopeType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ScopeType> for String {
+    fn from(e: ScopeType) -> String {
+        match e {
+            ScopeType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ScopeType {
     fn default() -> ScopeType {
         ScopeType::Noop
@@ -8482,6 +10481,7 @@ pub struct Session {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SessionAuthenticationMethod {
     #[serde(rename = "fpt")]
     Fpt,
@@ -8505,8 +10505,9 @@ pub enum SessionAuthenticationMethod {
     Tel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionAuthenticationMethod {
@@ -8523,12 +10524,40 @@ impl std::fmt::Display for SessionAuthenticationMethod {
             SessionAuthenticationMethod::Swk => "swk",
             SessionAuthenticationMethod::Tel => "tel",
             SessionAuthenticationMethod::Noop => "",
-            SessionAuthenticationMethod::FallthroughString => "*",
+            SessionAuthenticationMethod::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SessionAuthenticationMethod {
+    fn from(s: String) -> SessionAuthenticationMethod {
+        match s.as_str() {
+            "fpt" => SessionAuthenticationMethod::Fpt,
+            "geo" => SessionAuthenticationMethod::Geo,
+            "hwk" => SessionAuthenticationMethod::Hwk,
+            "kba" => SessionAuthenticationMethod::Kba,
+            "mfa" => SessionAuthenticationMethod::Mfa,
+            "otp" => SessionAuthenticationMethod::Otp,
+            "pwd" => SessionAuthenticationMethod::Pwd,
+            "sms" => SessionAuthenticationMethod::Sms,
+            "swk" => SessionAuthenticationMethod::Swk,
+            "tel" => SessionAuthenticationMethod::Tel,
+            "" => SessionAuthenticationMethod::Noop,
+            _ => SessionAuthenticationMethod::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SessionAuthenticationMethod> for String {
+    fn from(e: SessionAuthenticationMethod) -> String {
+        match e {
+            SessionAuthenticationMethod::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SessionAuthenticationMethod {
     fn default() -> SessionAuthenticationMethod {
         SessionAuthenticationMethod::Noop
@@ -8553,6 +10582,7 @@ pub struct SessionIdentityProvider {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SessionIdentityProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -8566,8 +10596,9 @@ pub enum SessionIdentityProviderType {
     Social,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionIdentityProviderType {
@@ -8579,12 +10610,35 @@ impl std::fmt::Display for SessionIdentityProviderType {
             SessionIdentityProviderType::Okta => "OKTA",
             SessionIdentityProviderType::Social => "SOCIAL",
             SessionIdentityProviderType::Noop => "",
-            SessionIdentityProviderType::FallthroughString => "*",
+            SessionIdentityProviderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SessionIdentityProviderType {
+    fn from(s: String) -> SessionIdentityProviderType {
+        match s.as_str() {
+            "ACTIVE_DIRECTORY" => SessionIdentityProviderType::ActiveDirectory,
+            "FEDERATION" => SessionIdentityProviderType::Federation,
+            "LDAP" => SessionIdentityProviderType::Ldap,
+            "OKTA" => SessionIdentityProviderType::Okta,
+            "SOCIAL" => SessionIdentityProviderType::Social,
+            "" => SessionIdentityProviderType::Noop,
+            _ => SessionIdentityProviderType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SessionIdentityProviderType> for String {
+    fn from(e: SessionIdentityProviderType) -> String {
+        match e {
+            SessionIdentityProviderType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SessionIdentityProviderType {
     fn default() -> SessionIdentityProviderType {
         SessionIdentityProviderType::Noop
@@ -8597,6 +10651,7 @@ impl SessionIdentityProviderType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SessionStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -8606,8 +10661,9 @@ pub enum SessionStatus {
     MfaRequired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SessionStatus {
@@ -8617,12 +10673,33 @@ impl std::fmt::Display for SessionStatus {
             SessionStatus::MfaEnroll => "MFA_ENROLL",
             SessionStatus::MfaRequired => "MFA_REQUIRED",
             SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+            SessionStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SessionStatus {
+    fn from(s: String) -> SessionStatus {
+        match s.as_str() {
+            "ACTIVE" => SessionStatus::Active,
+            "MFA_ENROLL" => SessionStatus::MfaEnroll,
+            "MFA_REQUIRED" => SessionStatus::MfaRequired,
+            "" => SessionStatus::Noop,
+            _ => SessionStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SessionStatus> for String {
+    fn from(e: SessionStatus) -> String {
+        match e {
+            SessionStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SessionStatus {
     fn default() -> SessionStatus {
         SessionStatus::Noop
@@ -8706,13 +10783,15 @@ pub struct SmsTemplate {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SmsTemplateType {
     #[serde(rename = "SMS_VERIFY_CODE")]
     SmsVerifyCode,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SmsTemplateType {
@@ -8720,12 +10799,31 @@ impl std::fmt::Display for SmsTemplateType {
         match self {
             SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
             SmsTemplateType::Noop => "",
-            SmsTemplateType::FallthroughString => "*",
+            SmsTemplateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SmsTemplateType {
+    fn from(s: String) -> SmsTemplateType {
+        match s.as_str() {
+            "SMS_VERIFY_CODE" => SmsTemplateType::SmsVerifyCode,
+            "" => SmsTemplateType::Noop,
+            _ => SmsTemplateType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SmsTemplateType> for String {
+    fn from(e: SmsTemplateType) -> String {
+        match e {
+            SmsTemplateType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SmsTemplateType {
     fn default() -> SmsTemplateType {
         SmsTemplateType::Noop
@@ -8755,6 +10853,7 @@ pub struct SmsUserFactorProfile {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TokenType {
     #[serde(rename = "ACCESS")]
     Access,
@@ -8762,8 +10861,9 @@ pub enum TokenType {
     Refresh,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -8772,12 +10872,32 @@ impl std::fmt::Display for TokenType {
             TokenType::Access => "ACCESS",
             TokenType::Refresh => "REFRESH",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TokenType {
+    fn from(s: String) -> TokenType {
+        match s.as_str() {
+            "ACCESS" => TokenType::Access,
+            "REFRESH" => TokenType::Refresh,
+            "" => TokenType::Noop,
+            _ => TokenType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TokenType> for String {
+    fn from(e: TokenType) -> String {
+        match e {
+            TokenType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TokenType {
     fn default() -> TokenType {
         TokenType::Noop
@@ -9258,6 +11378,7 @@ pub struct UserFactor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -9271,8 +11392,9 @@ pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     Suffix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
@@ -9284,12 +11406,35 @@ impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
             UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
             UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
             UserIdentifierConditionEvaluatorPatternMatchType::Noop => "",
-            UserIdentifierConditionEvaluatorPatternMatchType::FallthroughString => "*",
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn from(s: String) -> UserIdentifierConditionEvaluatorPatternMatchType {
+        match s.as_str() {
+            "CONTAINS" => UserIdentifierConditionEvaluatorPatternMatchType::Contains,
+            "EQUALS" => UserIdentifierConditionEvaluatorPatternMatchType::Equals,
+            "EXPRESSION" => UserIdentifierConditionEvaluatorPatternMatchType::Expression,
+            "STARTS_WITH" => UserIdentifierConditionEvaluatorPatternMatchType::StartsWith,
+            "SUFFIX" => UserIdentifierConditionEvaluatorPatternMatchType::Suffix,
+            "" => UserIdentifierConditionEvaluatorPatternMatchType::Noop,
+            _ => UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserIdentifierConditionEvaluatorPatternMatchType> for String {
+    fn from(e: UserIdentifierConditionEvaluatorPatternMatchType) -> String {
+        match e {
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserIdentifierConditionEvaluatorPatternMatchType {
     fn default() -> UserIdentifierConditionEvaluatorPatternMatchType {
         UserIdentifierConditionEvaluatorPatternMatchType::Noop
@@ -9314,6 +11459,7 @@ pub struct UserIdentifierConditionEvaluatorPattern {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserIdentifierPolicyRuleConditionType {
     #[serde(rename = "ATTRIBUTE")]
     Attribute,
@@ -9321,8 +11467,9 @@ pub enum UserIdentifierPolicyRuleConditionType {
     Identifier,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
@@ -9331,12 +11478,32 @@ impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
             UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
             UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
             UserIdentifierPolicyRuleConditionType::Noop => "",
-            UserIdentifierPolicyRuleConditionType::FallthroughString => "*",
+            UserIdentifierPolicyRuleConditionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserIdentifierPolicyRuleConditionType {
+    fn from(s: String) -> UserIdentifierPolicyRuleConditionType {
+        match s.as_str() {
+            "ATTRIBUTE" => UserIdentifierPolicyRuleConditionType::Attribute,
+            "IDENTIFIER" => UserIdentifierPolicyRuleConditionType::Identifier,
+            "" => UserIdentifierPolicyRuleConditionType::Noop,
+            _ => UserIdentifierPolicyRuleConditionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserIdentifierPolicyRuleConditionType> for String {
+    fn from(e: UserIdentifierPolicyRuleConditionType) -> String {
+        match e {
+            UserIdentifierPolicyRuleConditionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserIdentifierPolicyRuleConditionType {
     fn default() -> UserIdentifierPolicyRuleConditionType {
         UserIdentifierPolicyRuleConditionType::Noop
@@ -9396,13 +11563,15 @@ pub struct UserLifecycleAttributePolicyRuleCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserNextLogin {
     #[serde(rename = "changePassword")]
     ChangePassword,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserNextLogin {
@@ -9410,12 +11579,31 @@ impl std::fmt::Display for UserNextLogin {
         match self {
             UserNextLogin::ChangePassword => "changePassword",
             UserNextLogin::Noop => "",
-            UserNextLogin::FallthroughString => "*",
+            UserNextLogin::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserNextLogin {
+    fn from(s: String) -> UserNextLogin {
+        match s.as_str() {
+            "changePassword" => UserNextLogin::ChangePassword,
+            "" => UserNextLogin::Noop,
+            _ => UserNextLogin::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserNextLogin> for String {
+    fn from(e: UserNextLogin) -> String {
+        match e {
+            UserNextLogin::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserNextLogin {
     fn default() -> UserNextLogin {
         UserNextLogin::Noop
@@ -9980,6 +12168,7 @@ pub struct UserSchemaAttributeMasterPriority {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserSchemaAttributeMasterType {
     #[serde(rename = "OKTA")]
     Okta,
@@ -9989,8 +12178,9 @@ pub enum UserSchemaAttributeMasterType {
     ProfileMaster,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeMasterType {
@@ -10000,12 +12190,33 @@ impl std::fmt::Display for UserSchemaAttributeMasterType {
             UserSchemaAttributeMasterType::Override => "OVERRIDE",
             UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
             UserSchemaAttributeMasterType::Noop => "",
-            UserSchemaAttributeMasterType::FallthroughString => "*",
+            UserSchemaAttributeMasterType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserSchemaAttributeMasterType {
+    fn from(s: String) -> UserSchemaAttributeMasterType {
+        match s.as_str() {
+            "OKTA" => UserSchemaAttributeMasterType::Okta,
+            "OVERRIDE" => UserSchemaAttributeMasterType::Override,
+            "PROFILE_MASTER" => UserSchemaAttributeMasterType::ProfileMaster,
+            "" => UserSchemaAttributeMasterType::Noop,
+            _ => UserSchemaAttributeMasterType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserSchemaAttributeMasterType> for String {
+    fn from(e: UserSchemaAttributeMasterType) -> String {
+        match e {
+            UserSchemaAttributeMasterType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserSchemaAttributeMasterType {
     fn default() -> UserSchemaAttributeMasterType {
         UserSchemaAttributeMasterType::Noop
@@ -10034,6 +12245,7 @@ pub struct UserSchemaAttributePermission {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserSchemaAttributeType {
     #[serde(rename = "array")]
     Array,
@@ -10047,8 +12259,9 @@ pub enum UserSchemaAttributeType {
     String,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSchemaAttributeType {
@@ -10060,12 +12273,35 @@ impl std::fmt::Display for UserSchemaAttributeType {
             UserSchemaAttributeType::Number => "number",
             UserSchemaAttributeType::String => "string",
             UserSchemaAttributeType::Noop => "",
-            UserSchemaAttributeType::FallthroughString => "*",
+            UserSchemaAttributeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserSchemaAttributeType {
+    fn from(s: String) -> UserSchemaAttributeType {
+        match s.as_str() {
+            "array" => UserSchemaAttributeType::Array,
+            "boolean" => UserSchemaAttributeType::Boolean,
+            "integer" => UserSchemaAttributeType::Integer,
+            "number" => UserSchemaAttributeType::Number,
+            "string" => UserSchemaAttributeType::String,
+            "" => UserSchemaAttributeType::Noop,
+            _ => UserSchemaAttributeType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserSchemaAttributeType> for String {
+    fn from(e: UserSchemaAttributeType) -> String {
+        match e {
+            UserSchemaAttributeType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserSchemaAttributeType {
     fn default() -> UserSchemaAttributeType {
         UserSchemaAttributeType::Noop
@@ -10286,6 +12522,7 @@ pub struct UserSchemaPublic {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -10305,8 +12542,9 @@ pub enum UserStatus {
     Suspended,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatus {
@@ -10321,12 +12559,38 @@ impl std::fmt::Display for UserStatus {
             UserStatus::Staged => "STAGED",
             UserStatus::Suspended => "SUSPENDED",
             UserStatus::Noop => "",
-            UserStatus::FallthroughString => "*",
+            UserStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserStatus {
+    fn from(s: String) -> UserStatus {
+        match s.as_str() {
+            "ACTIVE" => UserStatus::Active,
+            "DEPROVISIONED" => UserStatus::Deprovisioned,
+            "LOCKED_OUT" => UserStatus::LockedOut,
+            "PASSWORD_EXPIRED" => UserStatus::PasswordExpired,
+            "PROVISIONED" => UserStatus::Provisioned,
+            "RECOVERY" => UserStatus::Recovery,
+            "STAGED" => UserStatus::Staged,
+            "SUSPENDED" => UserStatus::Suspended,
+            "" => UserStatus::Noop,
+            _ => UserStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserStatus> for String {
+    fn from(e: UserStatus) -> String {
+        match e {
+            UserStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserStatus {
     fn default() -> UserStatus {
         UserStatus::Noop
@@ -10466,6 +12730,7 @@ pub struct VerifyFactorRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorResult {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -10489,8 +12754,9 @@ pub enum FactorResult {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FactorResult {
@@ -10507,12 +12773,40 @@ impl std::fmt::Display for FactorResult {
             FactorResult::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResult::Waiting => "WAITING",
             FactorResult::Noop => "",
-            FactorResult::FallthroughString => "*",
+            FactorResult::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FactorResult {
+    fn from(s: String) -> FactorResult {
+        match s.as_str() {
+            "CHALLENGE" => FactorResult::Challenge,
+            "ERROR" => FactorResult::Error,
+            "EXPIRED" => FactorResult::Expired,
+            "FAILED" => FactorResult::Failed,
+            "PASSCODE_REPLAYED" => FactorResult::PasscodeReplayed,
+            "REJECTED" => FactorResult::Rejected,
+            "SUCCESS" => FactorResult::Success,
+            "TIMEOUT" => FactorResult::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResult::TimeWindowExceeded,
+            "WAITING" => FactorResult::Waiting,
+            "" => FactorResult::Noop,
+            _ => FactorResult::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FactorResult> for String {
+    fn from(e: FactorResult) -> String {
+        match e {
+            FactorResult::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FactorResult {
     fn default() -> FactorResult {
         FactorResult::Noop
@@ -21,6 +21,17 @@ impl UserFactors {
      * * `user_id: &str`
      */
     pub async fn list_factors(&self, user_id: &str) -> ClientResult<Vec<crate::types::UserFactor>> {
+        Ok(self.list_factors_with_response(user_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `list_factors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_factors_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserFactor>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors",
@@ -29,7 +40,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,6 +77,70 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `list_all_factors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_factors_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::UserFactor>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UserFactor>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `list_all_factors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_factors_pages_stream(user_id, None))
+    }
     /**
      * Enroll Factor.
      *
@@ -90,6 +165,34 @@ impl UserFactors {
         activate: bool,
         body: &crate::types::UserFactor,
     ) -> ClientResult<crate::types::UserFactor> {
+        Ok(self
+            .enroll_factor_with_response(
+                user_id,
+                update_phone,
+                template_id,
+                token_lifetime_seconds,
+                activate,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Enroll Factor.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `enroll_factor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn enroll_factor_with_response(
+        &self,
+        user_id: &str,
+        update_phone: bool,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        activate: bool,
+        body: &crate::types::UserFactor,
+    ) -> ClientResult<crate::Response<crate::types::UserFactor>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if activate {
             query_args.push(("activate".to_string(), activate.to_string()));
@@ -116,7 +219,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -138,6 +241,20 @@ impl UserFactors {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::UserFactor>> {
+        Ok(self
+            .list_supported_factors_with_response(user_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
+     *
+     * As opposed to `list_supported_factors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_supported_factors_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserFactor>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/catalog",
@@ -146,7 +263,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -183,6 +300,70 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
+     *
+     * As opposed to `list_all_supported_factors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_supported_factors_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::UserFactor>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors/catalog",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UserFactor>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
+     *
+     * As opposed to `list_all_supported_factors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_supported_factors_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserFactor>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_supported_factors_pages_stream(user_id, None))
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
      *
@@ -196,6 +377,20 @@ impl UserFactors {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::SecurityQuestion>> {
+        Ok(self
+            .list_supported_security_questions_with_response(user_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
+     *
+     * As opposed to `list_supported_security_questions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_supported_security_questions_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SecurityQuestion>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/questions",
@@ -204,7 +399,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -241,6 +436,73 @@ impl UserFactors {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
+     *
+     * As opposed to `list_all_supported_security_questions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_supported_security_questions_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SecurityQuestion>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/factors/questions",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SecurityQuestion>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
+     *
+     * As opposed to `list_all_supported_security_questions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_supported_security_questions_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SecurityQuestion>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_supported_security_questions_pages_stream(user_id, None),
+        )
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
      *
@@ -256,6 +518,21 @@ impl UserFactors {
         user_id: &str,
         factor_id: &str,
     ) -> ClientResult<crate::types::UserFactor> {
+        Ok(self
+            .get_factor_with_response(user_id, factor_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
+     *
+     * As opposed to `get_factor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_factor_with_response(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserFactor>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/{}",
@@ -265,7 +542,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -285,6 +562,19 @@ impl UserFactors {
      * * `factor_id: &str`
      */
     pub async fn delete_factor(&self, user_id: &str, factor_id: &str) -> ClientResult<()> {
+        self.delete_factor_with_response(user_id, factor_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
+     *
+     * As opposed to `delete_factor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_factor_with_response(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/{}",
@@ -294,7 +584,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -321,6 +611,24 @@ impl UserFactors {
         factor_id: &str,
         body: &crate::types::ActivateFactorRequest,
     ) -> ClientResult<crate::types::UserFactor> {
+        Ok(self
+            .activate_factor_with_response(user_id, factor_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Activate Factor.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/factors/{factorId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate_factor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_factor_with_response(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        body: &crate::types::ActivateFactorRequest,
+    ) -> ClientResult<crate::Response<crate::types::UserFactor>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/{}/lifecycle/activate",
@@ -330,7 +638,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -356,6 +664,22 @@ impl UserFactors {
         factor_id: &str,
         transaction_id: &str,
     ) -> ClientResult<crate::types::VerifyUserFactorResponse> {
+        Ok(self
+            .get_factor_transaction_status_with_response(user_id, factor_id, transaction_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/{factorId}/transactions/{transactionId}` endpoint.
+     *
+     * As opposed to `get_factor_transaction_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_factor_transaction_status_with_response(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        transaction_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::VerifyUserFactorResponse>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/factors/{}/transactions/{}",
@@ -366,7 +690,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -400,6 +724,32 @@ impl UserFactors {
         token_lifetime_seconds: i64,
         body: &crate::types::VerifyFactorRequest,
     ) -> ClientResult<crate::types::VerifyUserFactorResponse> {
+        Ok(self
+            .verify_factor_with_response(
+                user_id,
+                factor_id,
+                template_id,
+                token_lifetime_seconds,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Verify MFA Factor.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/factors/{factorId}/verify` endpoint.
+     *
+     * As opposed to `verify_factor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn verify_factor_with_response(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        body: &crate::types::VerifyFactorRequest,
+    ) -> ClientResult<crate::Response<crate::types::VerifyUserFactorResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !template_id.is_empty() {
             query_args.push(("templateId".to_string(), template_id.to_string()));
@@ -421,7 +771,7 @@ impl UserFactors {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
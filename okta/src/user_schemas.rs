@@ -26,6 +26,22 @@ impl UserSchemas {
         &self,
         app_instance_id: &str,
     ) -> ClientResult<crate::types::UserSchema> {
+        Ok(self
+            .get_application_with_response(app_instance_id)
+            .await?
+            .body)
+    }
+    /**
+     * Fetches the Schema for an App User.
+     *
+     * This function performs a `GET` to the `/api/v1/meta/schemas/apps/{appInstanceId}/default` endpoint.
+     *
+     * As opposed to `get_application`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_application_with_response(
+        &self,
+        app_instance_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSchema>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/schemas/apps/{}/default",
@@ -34,7 +50,7 @@ impl UserSchemas {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,6 +75,23 @@ impl UserSchemas {
         app_instance_id: &str,
         body: &crate::types::UserSchema,
     ) -> ClientResult<crate::types::UserSchema> {
+        Ok(self
+            .update_application_user_profile_with_response(app_instance_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Partial updates on the User Profile properties of the Application User Schema.
+     *
+     * This function performs a `POST` to the `/api/v1/meta/schemas/apps/{appInstanceId}/default` endpoint.
+     *
+     * As opposed to `update_application_user_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_application_user_profile_with_response(
+        &self,
+        app_instance_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> ClientResult<crate::Response<crate::types::UserSchema>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/schemas/apps/{}/default",
@@ -67,7 +100,7 @@ impl UserSchemas {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -88,6 +121,19 @@ impl UserSchemas {
      * * `schema_id: &str`
      */
     pub async fn get(&self, schema_id: &str) -> ClientResult<crate::types::UserSchema> {
+        Ok(self.get_with_response(schema_id).await?.body)
+    }
+    /**
+     * Fetches the schema for a Schema Id.
+     *
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/{schemaId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        schema_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSchema>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/schemas/user/{}",
@@ -96,7 +142,7 @@ impl UserSchemas {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -119,6 +165,21 @@ impl UserSchemas {
         schema_id: &str,
         body: &crate::types::UserSchema,
     ) -> ClientResult<crate::types::UserSchema> {
+        Ok(self
+            .update_user_profile_with_response(schema_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/meta/schemas/user/{schemaId}` endpoint.
+     *
+     * As opposed to `update_user_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_user_profile_with_response(
+        &self,
+        schema_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> ClientResult<crate::Response<crate::types::UserSchema>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/schemas/user/{}",
@@ -127,7 +188,7 @@ impl UserSchemas {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
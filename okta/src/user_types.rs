@@ -17,9 +17,19 @@ impl UserTypes {
      * Fetches all User Types in your org
      */
     pub async fn list(&self) -> ClientResult<Vec<crate::types::UserType>> {
+        Ok(self.list_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserType>>> {
         let url = self.client.url("/api/v1/meta/types/user", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -47,6 +57,60 @@ impl UserTypes {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::UserType>>> + Unpin + '_
+    {
+        let url = self.client.url("/api/v1/meta/types/user", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UserType>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserType>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(None))
+    }
     /**
      * This function performs a `POST` to the `/api/v1/meta/types/user` endpoint.
      *
@@ -56,9 +120,20 @@ impl UserTypes {
         &self,
         body: &crate::types::UserType,
     ) -> ClientResult<crate::types::UserType> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::UserType,
+    ) -> ClientResult<crate::Response<crate::types::UserType>> {
         let url = self.client.url("/api/v1/meta/types/user", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -77,6 +152,17 @@ impl UserTypes {
      * * `type_id: &str`
      */
     pub async fn get(&self, type_id: &str) -> ClientResult<crate::types::UserType> {
+        Ok(self.get_with_response(type_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user/{typeId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        type_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserType>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/types/user/{}",
@@ -85,7 +171,7 @@ impl UserTypes {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -108,6 +194,18 @@ impl UserTypes {
         type_id: &str,
         body: &crate::types::UserType,
     ) -> ClientResult<crate::types::UserType> {
+        Ok(self.replace_with_response(type_id, body).await?.body)
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/meta/types/user/{typeId}` endpoint.
+     *
+     * As opposed to `replace`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn replace_with_response(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> ClientResult<crate::Response<crate::types::UserType>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/types/user/{}",
@@ -116,7 +214,7 @@ impl UserTypes {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -139,6 +237,18 @@ impl UserTypes {
         type_id: &str,
         body: &crate::types::UserType,
     ) -> ClientResult<crate::types::UserType> {
+        Ok(self.update_with_response(type_id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/meta/types/user/{typeId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> ClientResult<crate::Response<crate::types::UserType>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/types/user/{}",
@@ -147,7 +257,7 @@ impl UserTypes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -166,6 +276,15 @@ impl UserTypes {
      * * `type_id: &str`
      */
     pub async fn delete(&self, type_id: &str) -> ClientResult<()> {
+        self.delete_with_response(type_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/meta/types/user/{typeId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, type_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/meta/types/user/{}",
@@ -174,7 +293,7 @@ impl UserTypes {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
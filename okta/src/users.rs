@@ -38,6 +38,28 @@ impl Users {
         sort_by: &str,
         sort_order: &str,
     ) -> ClientResult<Vec<crate::types::User>> {
+        Ok(self
+            .list_with_response(q, after, limit, filter, search, sort_by, sort_order)
+            .await?
+            .body)
+    }
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -63,7 +85,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/users?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -117,6 +139,91 @@ impl Users {
             )
             .await
     }
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::User>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        if !sort_by.is_empty() {
+            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+        }
+        if !sort_order.is_empty() {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/api/v1/users?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::User>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        q: &str,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_pages_stream(q, filter, search, sort_by, sort_order, None),
+        )
+    }
     /**
      * Create User.
      *
@@ -137,6 +244,25 @@ impl Users {
         next_login: &str,
         body: &crate::types::CreateUserRequest,
     ) -> ClientResult<crate::types::User> {
+        Ok(self
+            .create_with_response(activate, provider, next_login, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create User.
+     *
+     * This function performs a `POST` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        activate: bool,
+        provider: bool,
+        next_login: &str,
+        body: &crate::types::CreateUserRequest,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if activate {
             query_args.push(("activate".to_string(), activate.to_string()));
@@ -150,7 +276,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api/v1/users?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -174,6 +300,25 @@ impl Users {
         primary_relationship_name: &str,
         primary_user_id: &str,
     ) -> ClientResult<()> {
+        self.set_linked_object_for_with_response(
+            associated_user_id,
+            primary_relationship_name,
+            primary_user_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/users/{associatedUserId}/linkedObjects/{primaryRelationshipName}/{primaryUserId}` endpoint.
+     *
+     * As opposed to `set_linked_object_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn set_linked_object_for_with_response(
+        &self,
+        associated_user_id: &str,
+        primary_relationship_name: &str,
+        primary_user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/linkedObjects/{}/{}",
@@ -184,7 +329,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -205,6 +350,19 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn get(&self, user_id: &str) -> ClientResult<crate::types::User> {
+        Ok(self.get_with_response(user_id).await?.body)
+    }
+    /**
+     * Get User.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}",
@@ -213,7 +371,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -240,6 +398,21 @@ impl Users {
         strict: bool,
         body: &crate::types::User,
     ) -> ClientResult<crate::types::User> {
+        Ok(self.update_with_response(user_id, strict, body).await?.body)
+    }
+    /**
+     * Update User.
+     *
+     * This function performs a `PUT` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if strict {
             query_args.push(("strict".to_string(), strict.to_string()));
@@ -254,7 +427,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -279,6 +452,22 @@ impl Users {
         strict: bool,
         body: &crate::types::User,
     ) -> ClientResult<crate::types::User> {
+        Ok(self
+            .partial_update_with_response(user_id, strict, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `partial_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn partial_update_with_response(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::User,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if strict {
             query_args.push(("strict".to_string(), strict.to_string()));
@@ -293,7 +482,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -315,6 +504,22 @@ impl Users {
      * * `send_email: bool`
      */
     pub async fn deactivate_or_delete(&self, user_id: &str, send_email: bool) -> ClientResult<()> {
+        self.deactivate_or_delete_with_response(user_id, send_email)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete User.
+     *
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `deactivate_or_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_or_delete_with_response(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
@@ -329,7 +534,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -350,6 +555,19 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn list_app_links(&self, user_id: &str) -> ClientResult<Vec<crate::types::AppLink>> {
+        Ok(self.list_app_links_with_response(user_id).await?.body)
+    }
+    /**
+     * Get Assigned App Links.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/appLinks` endpoint.
+     *
+     * As opposed to `list_app_links`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_app_links_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AppLink>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/appLinks",
@@ -358,7 +576,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -397,6 +615,73 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get Assigned App Links.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/appLinks` endpoint.
+     *
+     * As opposed to `list_all_app_links`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_app_links_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::AppLink>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/appLinks",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::AppLink>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get Assigned App Links.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/appLinks` endpoint.
+     *
+     * As opposed to `list_all_app_links`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_app_links_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AppLink>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_app_links_pages_stream(user_id, None))
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
      *
@@ -410,6 +695,17 @@ impl Users {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::OAuth2Client>> {
+        Ok(self.list_clients_with_response(user_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
+     *
+     * As opposed to `list_clients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_clients_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2Client>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/clients",
@@ -418,7 +714,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -455,6 +751,70 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
+     *
+     * As opposed to `list_all_clients`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_clients_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OAuth2Client>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2Client>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
+     *
+     * As opposed to `list_all_clients`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_clients_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2Client>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_clients_pages_stream(user_id, None))
+    }
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -476,6 +836,24 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Ok(self
+            .list_grants_for_and_clients_with_response(user_id, client_id, expand, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_grants_for_and_clients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_grants_for_and_clients_with_response(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -497,7 +875,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -543,6 +921,85 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants_for_and_clients`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_grants_for_and_clients_pages_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OAuth2ScopeConsentGrant>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2ScopeConsentGrant>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants_for_and_clients`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_grants_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_grants_for_and_clients_pages_stream(user_id, client_id, expand, None),
+        )
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -558,6 +1015,20 @@ impl Users {
         user_id: &str,
         client_id: &str,
     ) -> ClientResult<()> {
+        self.revoke_grants_for_and_client_with_response(user_id, client_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `revoke_grants_for_and_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_grants_for_and_client_with_response(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/clients/{}/grants",
@@ -567,7 +1038,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -597,6 +1068,26 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::OAuth2RefreshToken>> {
+        Ok(self
+            .list_refresh_tokens_for_and_clients_with_response(
+                user_id, client_id, expand, after, limit,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_refresh_tokens_for_and_clients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_refresh_tokens_for_and_clients_with_response(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2RefreshToken>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -618,7 +1109,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -664,6 +1155,87 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_refresh_tokens_for_and_clients_pages_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OAuth2RefreshToken>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens?{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(client_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2RefreshToken>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_refresh_tokens_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2RefreshToken>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_refresh_tokens_for_and_clients_pages_stream(
+                user_id, client_id, expand, None,
+            ),
+        )
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
      *
@@ -679,6 +1251,20 @@ impl Users {
         user_id: &str,
         client_id: &str,
     ) -> ClientResult<()> {
+        self.revoke_tokens_for_and_client_with_response(user_id, client_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `revoke_tokens_for_and_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_tokens_for_and_client_with_response(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/clients/{}/tokens",
@@ -688,7 +1274,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -720,6 +1306,27 @@ impl Users {
         limit: i64,
         after: &str,
     ) -> ClientResult<crate::types::OAuth2RefreshToken> {
+        Ok(self
+            .get_refresh_token_for_and_client_with_response(
+                user_id, client_id, token_id, expand, limit, after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `get_refresh_token_for_and_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_refresh_token_for_and_client_with_response(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+        limit: i64,
+        after: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2RefreshToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -742,7 +1349,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -768,6 +1375,21 @@ impl Users {
         client_id: &str,
         token_id: &str,
     ) -> ClientResult<()> {
+        self.revoke_token_for_and_client_with_response(user_id, client_id, token_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `revoke_token_for_and_client`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_token_for_and_client_with_response(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/clients/{}/tokens/{}",
@@ -778,7 +1400,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -799,12 +1421,30 @@ impl Users {
      * * `user_id: &str`
      * * `strict: bool`
      */
-    pub async fn change_password(
+    pub async fn change_password(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::ChangePasswordRequest,
+    ) -> ClientResult<crate::types::UserCredentials> {
+        Ok(self
+            .change_password_with_response(user_id, strict, body)
+            .await?
+            .body)
+    }
+    /**
+     * Change Password.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/credentials/change_password` endpoint.
+     *
+     * As opposed to `change_password`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn change_password_with_response(
         &self,
         user_id: &str,
         strict: bool,
         body: &crate::types::ChangePasswordRequest,
-    ) -> ClientResult<crate::types::UserCredentials> {
+    ) -> ClientResult<crate::Response<crate::types::UserCredentials>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if strict {
             query_args.push(("strict".to_string(), strict.to_string()));
@@ -819,7 +1459,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -844,6 +1484,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserCredentials,
     ) -> ClientResult<crate::types::UserCredentials> {
+        Ok(self
+            .change_recovery_question_with_response(user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Change Recovery Question.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/credentials/change_recovery_question` endpoint.
+     *
+     * As opposed to `change_recovery_question`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn change_recovery_question_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserCredentials,
+    ) -> ClientResult<crate::Response<crate::types::UserCredentials>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/credentials/change_recovery_question",
@@ -852,7 +1509,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -874,6 +1531,22 @@ impl Users {
         &self,
         user_id: &str,
     ) -> ClientResult<crate::types::ResetPasswordToken> {
+        Ok(self
+            .post_credentials_forgot_password_with_response(user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Forgot Password.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/credentials/forgot_password` endpoint.
+     *
+     * As opposed to `post_credentials_forgot_password`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_credentials_forgot_password_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ResetPasswordToken>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/credentials/forgot_password",
@@ -882,7 +1555,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -912,6 +1585,24 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Ok(self
+            .list_grants_with_response(user_id, scope_id, expand, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_grants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_grants_with_response(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OAuth2ScopeConsentGrant>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -935,7 +1626,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -983,6 +1674,85 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_grants_pages_stream(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OAuth2ScopeConsentGrant>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if !scope_id.is_empty() {
+            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/grants?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OAuth2ScopeConsentGrant>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_grants_stream(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OAuth2ScopeConsentGrant>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_grants_pages_stream(user_id, scope_id, expand, None))
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants` endpoint.
      *
@@ -993,6 +1763,18 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn revoke_grants(&self, user_id: &str) -> ClientResult<()> {
+        self.revoke_grants_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `revoke_grants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_grants_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/grants",
@@ -1001,7 +1783,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1027,6 +1809,22 @@ impl Users {
         grant_id: &str,
         expand: &str,
     ) -> ClientResult<crate::types::OAuth2ScopeConsentGrant> {
+        Ok(self
+            .get_grant_with_response(user_id, grant_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `get_grant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_grant_with_response(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2ScopeConsentGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -1042,7 +1840,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1062,6 +1860,19 @@ impl Users {
      * * `grant_id: &str`
      */
     pub async fn revoke_grant(&self, user_id: &str, grant_id: &str) -> ClientResult<()> {
+        self.revoke_grant_with_response(user_id, grant_id).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `revoke_grant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn revoke_grant_with_response(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/grants/{}",
@@ -1071,7 +1882,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1092,6 +1903,19 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn list_groups(&self, user_id: &str) -> ClientResult<Vec<crate::types::Group>> {
+        Ok(self.list_groups_with_response(user_id).await?.body)
+    }
+    /**
+     * Get Member Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/groups` endpoint.
+     *
+     * As opposed to `list_groups`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_groups_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/groups",
@@ -1100,7 +1924,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1136,6 +1960,70 @@ impl Users {
             )
             .await
     }
+    /**
+     * Get Member Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/groups` endpoint.
+     *
+     * As opposed to `list_all_groups`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_groups_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Group>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/groups",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Group>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get Member Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/groups` endpoint.
+     *
+     * As opposed to `list_all_groups`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_groups_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_groups_pages_stream(user_id, None))
+    }
     /**
      * Listing IdPs associated with a user.
      *
@@ -1151,6 +2039,22 @@ impl Users {
         &self,
         user_id: &str,
     ) -> ClientResult<Vec<crate::types::IdentityProvider>> {
+        Ok(self
+            .list_identity_providers_with_response(user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Listing IdPs associated with a user.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/idps` endpoint.
+     *
+     * As opposed to `list_identity_providers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_identity_providers_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IdentityProvider>>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/idps",
@@ -1159,7 +2063,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1198,6 +2102,75 @@ impl Users {
             )
             .await
     }
+    /**
+     * Listing IdPs associated with a user.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/idps` endpoint.
+     *
+     * As opposed to `list_all_identity_providers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_identity_providers_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IdentityProvider>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/idps",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IdentityProvider>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Listing IdPs associated with a user.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/idps` endpoint.
+     *
+     * As opposed to `list_all_identity_providers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_identity_providers_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IdentityProvider>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_identity_providers_pages_stream(user_id, None))
+    }
     /**
      * Activate User.
      *
@@ -1215,6 +2188,20 @@ impl Users {
         user_id: &str,
         send_email: bool,
     ) -> ClientResult<crate::types::UserActivationToken> {
+        Ok(self.activate_with_response(user_id, send_email).await?.body)
+    }
+    /**
+     * Activate User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/activate` endpoint.
+     *
+     * As opposed to `activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn activate_with_response(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::UserActivationToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
@@ -1229,7 +2216,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1251,6 +2238,21 @@ impl Users {
      * * `send_email: bool`
      */
     pub async fn deactivate(&self, user_id: &str, send_email: bool) -> ClientResult<()> {
+        self.deactivate_with_response(user_id, send_email).await?;
+        Ok(())
+    }
+    /**
+     * Deactivate User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/deactivate` endpoint.
+     *
+     * As opposed to `deactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn deactivate_with_response(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
@@ -1265,7 +2267,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1286,6 +2288,19 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn expire_password(&self, user_id: &str) -> ClientResult<crate::types::User> {
+        Ok(self.expire_password_with_response(user_id).await?.body)
+    }
+    /**
+     * Expire Password.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=false` endpoint.
+     *
+     * As opposed to `expire_password`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn expire_password_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/lifecycle/expire_password?tempPassword=false",
@@ -1294,7 +2309,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1318,6 +2333,22 @@ impl Users {
         &self,
         user_id: &str,
     ) -> ClientResult<crate::types::TempPassword> {
+        Ok(self
+            .expire_password_and_get_temporary_with_response(user_id)
+            .await?
+            .body)
+    }
+    /**
+     * Expire Password.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=true` endpoint.
+     *
+     * As opposed to `expire_password_and_get_temporary`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn expire_password_and_get_temporary_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TempPassword>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/lifecycle/expire_password?tempPassword=true",
@@ -1326,7 +2357,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1352,6 +2383,23 @@ impl Users {
         user_id: &str,
         send_email: bool,
     ) -> ClientResult<crate::types::UserActivationToken> {
+        Ok(self
+            .reactivate_with_response(user_id, send_email)
+            .await?
+            .body)
+    }
+    /**
+     * Reactivate User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/reactivate` endpoint.
+     *
+     * As opposed to `reactivate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn reactivate_with_response(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::UserActivationToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
@@ -1366,7 +2414,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1387,6 +2435,20 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn reset_factors(&self, user_id: &str) -> ClientResult<()> {
+        self.reset_factors_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Reset Factors.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/reset_factors` endpoint.
+     *
+     * As opposed to `reset_factors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn reset_factors_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/lifecycle/reset_factors",
@@ -1395,7 +2457,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1421,6 +2483,23 @@ impl Users {
         user_id: &str,
         send_email: bool,
     ) -> ClientResult<crate::types::ResetPasswordToken> {
+        Ok(self
+            .reset_password_with_response(user_id, send_email)
+            .await?
+            .body)
+    }
+    /**
+     * Reset Password.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/reset_password` endpoint.
+     *
+     * As opposed to `reset_password`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn reset_password_with_response(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::ResetPasswordToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
@@ -1435,7 +2514,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1456,6 +2535,17 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn suspend(&self, user_id: &str) -> ClientResult<()> {
+        self.suspend_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Suspend User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/suspend` endpoint.
+     *
+     * As opposed to `suspend`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn suspend_with_response(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/lifecycle/suspend",
@@ -1464,7 +2554,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1485,6 +2575,17 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn unlock(&self, user_id: &str) -> ClientResult<()> {
+        self.unlock_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Unlock User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/unlock` endpoint.
+     *
+     * As opposed to `unlock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unlock_with_response(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/lifecycle/unlock",
@@ -1493,7 +2594,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1514,6 +2615,20 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn unsuspend(&self, user_id: &str) -> ClientResult<()> {
+        self.unsuspend_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Unsuspend User.
+     *
+     * This function performs a `POST` to the `/api/v1/users/{userId}/lifecycle/unsuspend` endpoint.
+     *
+     * As opposed to `unsuspend`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unsuspend_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/lifecycle/unsuspend",
@@ -1522,7 +2637,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1550,6 +2665,23 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::Links>> {
+        Ok(self
+            .get_linked_objects_fors_with_response(user_id, relationship_name, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_linked_objects_fors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_linked_objects_fors_with_response(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Links>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1568,7 +2700,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1607,6 +2739,73 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_all_linked_objects_fors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_linked_objects_fors_pages_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Links>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/linkedObjects/{}",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(relationship_name),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Links>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_all_linked_objects_fors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_linked_objects_fors_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Links>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_linked_objects_fors_pages_stream(
+            user_id,
+            relationship_name,
+            None,
+        ))
+    }
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
      *
@@ -1622,6 +2821,20 @@ impl Users {
         user_id: &str,
         relationship_name: &str,
     ) -> ClientResult<()> {
+        self.remove_linked_object_for_with_response(user_id, relationship_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `remove_linked_object_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_linked_object_for_with_response(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/linkedObjects/{}",
@@ -1631,7 +2844,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1655,6 +2868,21 @@ impl Users {
         user_id: &str,
         expand: &str,
     ) -> ClientResult<Vec<crate::types::Role>> {
+        Ok(self
+            .list_assigned_roles_fors_with_response(user_id, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_assigned_roles_fors`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_assigned_roles_fors_with_response(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Role>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !expand.is_empty() {
             query_args.push(("expand".to_string(), expand.to_string()));
@@ -1669,7 +2897,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1713,6 +2941,76 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles_fors`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_assigned_roles_fors_pages_stream(
+        &self,
+        user_id: &str,
+        expand: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Role>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !expand.is_empty() {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Role>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles_fors`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_assigned_roles_fors_stream(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Role>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_assigned_roles_fors_pages_stream(user_id, expand, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/api/v1/users/{userId}/roles` endpoint.
      *
@@ -1729,6 +3027,22 @@ impl Users {
         disable_notifications: &str,
         body: &crate::types::AssignRoleRequest,
     ) -> ClientResult<crate::types::Role> {
+        Ok(self
+            .assign_role_with_response(user_id, disable_notifications, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `assign_role`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_role_with_response(
+        &self,
+        user_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> ClientResult<crate::Response<crate::types::Role>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !disable_notifications.is_empty() {
             query_args.push((
@@ -1746,7 +3060,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1766,6 +3080,20 @@ impl Users {
      * * `role_id: &str`
      */
     pub async fn remove_role_from(&self, user_id: &str, role_id: &str) -> ClientResult<()> {
+        self.remove_role_from_with_response(user_id, role_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `remove_role_from`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_role_from_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}",
@@ -1775,7 +3103,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1803,6 +3131,25 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::CatalogApplication>> {
+        Ok(self
+            .list_application_targets_for_administrator_roles_with_response(
+                user_id, role_id, after, limit,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_application_targets_for_administrator_roles`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_application_targets_for_administrator_roles_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CatalogApplication>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -1821,7 +3168,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1860,6 +3207,79 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_pages_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CatalogApplication>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CatalogApplication>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_application_targets_for_administrator_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CatalogApplication>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_application_targets_for_administrator_roles_pages_stream(
+                user_id, role_id, None,
+            ),
+        )
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -1875,6 +3295,20 @@ impl Users {
         user_id: &str,
         role_id: &str,
     ) -> ClientResult<()> {
+        self.add_all_apps_as_target_role_with_response(user_id, role_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `add_all_apps_as_target_role`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_all_apps_as_target_role_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/catalog/apps",
@@ -1884,7 +3318,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1910,6 +3344,21 @@ impl Users {
         role_id: &str,
         app_name: &str,
     ) -> ClientResult<()> {
+        self.add_application_target_admin_role_for_with_response(user_id, role_id, app_name)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
+     *
+     * As opposed to `add_application_target_admin_role_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_application_target_admin_role_for_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
@@ -1920,7 +3369,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1946,6 +3395,23 @@ impl Users {
         role_id: &str,
         app_name: &str,
     ) -> ClientResult<()> {
+        self.remove_application_target_from_administrator_role_for_with_response(
+            user_id, role_id, app_name,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_application_target_from_administrator_role_for_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
@@ -1956,7 +3422,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1986,6 +3452,29 @@ impl Users {
         app_name: &str,
         application_id: &str,
     ) -> ClientResult<()> {
+        self.add_application_target_app_admin_role_for_with_response(
+            user_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Add App Instance Target to App Administrator Role given to a User.
+     *
+     * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}` endpoint.
+     *
+     * As opposed to `add_application_target_app_admin_role_for`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_application_target_app_admin_role_for_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
@@ -1997,7 +3486,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2027,6 +3516,29 @@ impl Users {
         app_name: &str,
         application_id: &str,
     ) -> ClientResult<()> {
+        self.remove_application_target_from_administrator_role_for_users_with_response(
+            user_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Remove App Instance Target to App Administrator Role given to a User.
+     *
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_for_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_application_target_from_administrator_role_for_users_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
@@ -2038,7 +3550,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2066,6 +3578,23 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::Group>> {
+        Ok(self
+            .list_group_targets_for_roles_with_response(user_id, role_id, after, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_group_targets_for_roles`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_group_targets_for_roles_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Group>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !after.is_empty() {
             query_args.push(("after".to_string(), after.to_string()));
@@ -2084,7 +3613,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2123,6 +3652,71 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_group_targets_for_roles`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_group_targets_for_roles_pages_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Group>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/groups",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(role_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Group>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_group_targets_for_roles`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_group_targets_for_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Group>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_group_targets_for_roles_pages_stream(user_id, role_id, None),
+        )
+    }
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
      *
@@ -2140,6 +3734,21 @@ impl Users {
         role_id: &str,
         group_id: &str,
     ) -> ClientResult<()> {
+        self.add_group_target_role_with_response(user_id, role_id, group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
+     *
+     * As opposed to `add_group_target_role`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_group_target_role_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/groups/{}",
@@ -2150,7 +3759,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2176,6 +3785,21 @@ impl Users {
         role_id: &str,
         group_id: &str,
     ) -> ClientResult<()> {
+        self.remove_group_target_from_role_with_response(user_id, role_id, group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
+     *
+     * As opposed to `remove_group_target_from_role`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_group_target_from_role_with_response(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/v1/users/{}/roles/{}/targets/groups/{}",
@@ -2186,7 +3810,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2206,6 +3830,20 @@ impl Users {
      * * `oauth_tokens: bool` -- Revoke issued OpenID Connect and OAuth refresh and access tokens.
      */
     pub async fn clear_sessions(&self, user_id: &str, oauth_tokens: bool) -> ClientResult<()> {
+        self.clear_sessions_with_response(user_id, oauth_tokens)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/sessions` endpoint.
+     *
+     * As opposed to `clear_sessions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn clear_sessions_with_response(
+        &self,
+        user_id: &str,
+        oauth_tokens: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if oauth_tokens {
             query_args.push(("oauthTokens".to_string(), oauth_tokens.to_string()));
@@ -2220,7 +3858,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -52,9 +52,21 @@ impl Auths {
      * * `authorization: &str` -- Basic \<base64-encoded client_id:client_secret\>.
      */
     pub async fn post_token(&self) -> ClientResult<crate::types::OAuth2Token> {
+        Ok(self.post_token_with_response().await?.body)
+    }
+    /**
+     * Get OAuth2 token.
+     *
+     * This function performs a `POST` to the `/token` endpoint.
+     *
+     * As opposed to `post_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_token_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::OAuth2Token>> {
         let url = self.client.url("/token", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -23,9 +23,21 @@ impl Businesses {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get_resources_busine(&self) -> ClientResult<crate::types::Business> {
+        Ok(self.get_resources_busine_with_response().await?.body)
+    }
+    /**
+     * GET business metadata.
+     *
+     * This function performs a `GET` to the `/business` endpoint.
+     *
+     * As opposed to `get_resources_busine`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_resources_busine_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::Business>> {
         let url = self.client.url("/business", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -48,9 +60,24 @@ impl Businesses {
     pub async fn get_resources_business_current(
         &self,
     ) -> ClientResult<crate::types::BusinessCurrentStatus> {
+        Ok(self
+            .get_resources_business_current_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * GET current info about a business.
+     *
+     * This function performs a `GET` to the `/business/balance` endpoint.
+     *
+     * As opposed to `get_resources_business_current`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_resources_business_current_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::BusinessCurrentStatus>> {
         let url = self.client.url("/business/balance", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
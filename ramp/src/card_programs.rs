@@ -29,6 +29,20 @@ impl CardPrograms {
         start: &str,
         page_size: f64,
     ) -> ClientResult<Vec<crate::types::CardProgram>> {
+        Ok(self.get_page_with_response(start, page_size).await?.body)
+    }
+    /**
+     * List card programs.
+     *
+     * This function performs a `GET` to the `/card-programs` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CardProgram>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_size.to_string().is_empty() {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -38,9 +52,9 @@ impl CardPrograms {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/card-programs?{}", query_), None);
-        let resp: crate::types::GetCardProgramsResponse = self
+        let resp: crate::Response<crate::types::GetCardProgramsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,7 +64,7 @@ impl CardPrograms {
             .await?;
 
         // Return our response data.
-        Ok(resp.card_programs.to_vec())
+        Ok(resp.map(|r| r.card_programs.to_vec()))
     }
     /**
      * List card programs.
@@ -112,6 +126,92 @@ impl CardPrograms {
         // Return our response data.
         Ok(card_programs)
     }
+    /**
+     * List card programs.
+     *
+     * This function performs a `GET` to the `/card-programs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CardProgram>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/card-programs", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let resp: crate::types::GetCardProgramsResponse = match &cursor {
+                        Some(c) => match self
+                            .client
+                            .get(
+                                c.trim_start_matches(&self.client.host),
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(resp) => resp,
+                            // Ramp sends a 404 when we run out of pages.
+                            Err(crate::ClientError::HttpError {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            }) => return Ok(None),
+                            Err(e) => return Err(e),
+                        },
+                        None => {
+                            self.client
+                                .get(
+                                    &url,
+                                    crate::Message {
+                                        body: None,
+                                        content_type: None,
+                                    },
+                                )
+                                .await?
+                        }
+                    };
+
+                    let next = Some(resp.page.next).filter(|n| !n.is_empty());
+                    let items = resp.card_programs;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List card programs.
+     *
+     * This function performs a `GET` to the `/card-programs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CardProgram>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(None))
+    }
     /**
      * Create a card program.
      *
@@ -127,9 +227,22 @@ impl CardPrograms {
         &self,
         body: &crate::types::PostResourcesCardProgramRequest,
     ) -> ClientResult<crate::types::CardProgram> {
+        Ok(self.post_resources_with_response(body).await?.body)
+    }
+    /**
+     * Create a card program.
+     *
+     * This function performs a `POST` to the `/card-programs` endpoint.
+     *
+     * As opposed to `post_resources`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_resources_with_response(
+        &self,
+        body: &crate::types::PostResourcesCardProgramRequest,
+    ) -> ClientResult<crate::Response<crate::types::CardProgram>> {
         let url = self.client.url("/card-programs", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -150,6 +263,19 @@ impl CardPrograms {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get_program(&self, id: &str) -> ClientResult<crate::types::CardProgram> {
+        Ok(self.get_program_with_response(id).await?.body)
+    }
+    /**
+     * GET a card program.
+     *
+     * This function performs a `GET` to the `/card-programs/{id}` endpoint.
+     *
+     * As opposed to `get_program`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_program_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CardProgram>> {
         let url = self.client.url(
             &format!(
                 "/card-programs/{}",
@@ -158,7 +284,7 @@ impl CardPrograms {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
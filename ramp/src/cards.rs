@@ -33,6 +33,25 @@ impl Cards {
         user_id: &str,
         card_program_id: &str,
     ) -> ClientResult<Vec<crate::types::Card>> {
+        Ok(self
+            .get_page_with_response(start, page_size, user_id, card_program_id)
+            .await?
+            .body)
+    }
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        start: &str,
+        page_size: f64,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !card_program_id.is_empty() {
             query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
@@ -48,9 +67,9 @@ impl Cards {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/cards?{}", query_), None);
-        let resp: crate::types::GetCardsResponse = self
+        let resp: crate::Response<crate::types::GetCardsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +79,7 @@ impl Cards {
             .await?;
 
         // Return our response data.
-        Ok(resp.cards.to_vec())
+        Ok(resp.map(|r| r.cards.to_vec()))
     }
     /**
      * List cards.
@@ -134,6 +153,104 @@ impl Cards {
         // Return our response data.
         Ok(cards)
     }
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Card>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !card_program_id.is_empty() {
+            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+        }
+        if !user_id.is_empty() {
+            query_args.push(("user_id".to_string(), user_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/cards?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let resp: crate::types::GetCardsResponse = match &cursor {
+                        Some(c) => match self
+                            .client
+                            .get(
+                                c.trim_start_matches(&self.client.host),
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(resp) => resp,
+                            // Ramp sends a 404 when we run out of pages.
+                            Err(crate::ClientError::HttpError {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            }) => return Ok(None),
+                            Err(e) => return Err(e),
+                        },
+                        None => {
+                            self.client
+                                .get(
+                                    &url,
+                                    crate::Message {
+                                        body: None,
+                                        content_type: None,
+                                    },
+                                )
+                                .await?
+                        }
+                    };
+
+                    let next = Some(resp.page.next).filter(|n| !n.is_empty());
+                    let items = resp.cards;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Card>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(user_id, card_program_id, None))
+    }
     /**
      * GET a card.
      *
@@ -146,12 +263,25 @@ impl Cards {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get(&self, id: &str) -> ClientResult<crate::types::Card> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * GET a card.
+     *
+     * This function performs a `GET` to the `/cards/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Card>> {
         let url = self.client.url(
             &format!("/cards/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -176,12 +306,27 @@ impl Cards {
         id: &str,
         body: &crate::types::PatchResourcesCardsCardRequest,
     ) -> ClientResult<()> {
+        self.patch_resources_with_response(id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update card.
+     *
+     * This function performs a `PATCH` to the `/cards/{id}` endpoint.
+     *
+     * As opposed to `patch_resources`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_resources_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PatchResourcesCardsCardRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/cards/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -205,9 +350,22 @@ impl Cards {
         &self,
         body: &crate::types::PostResourcesCardPhysicalRequest,
     ) -> ClientResult<crate::types::TaskResponse> {
+        Ok(self.post_resources_physical_with_response(body).await?.body)
+    }
+    /**
+     * Create a physical card.
+     *
+     * This function performs a `POST` to the `/cards/deferred/physical` endpoint.
+     *
+     * As opposed to `post_resources_physical`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_resources_physical_with_response(
+        &self,
+        body: &crate::types::PostResourcesCardPhysicalRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
         let url = self.client.url("/cards/deferred/physical", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -231,9 +389,22 @@ impl Cards {
         &self,
         body: &crate::types::PostResourcesCardVirtualRequest,
     ) -> ClientResult<crate::types::TaskResponse> {
+        Ok(self.post_resources_virtual_with_response(body).await?.body)
+    }
+    /**
+     * Create a virtual card.
+     *
+     * This function performs a `POST` to the `/cards/deferred/virtual` endpoint.
+     *
+     * As opposed to `post_resources_virtual`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_resources_virtual_with_response(
+        &self,
+        body: &crate::types::PostResourcesCardVirtualRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
         let url = self.client.url("/cards/deferred/virtual", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -254,6 +425,23 @@ impl Cards {
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
     ) -> ClientResult<crate::types::TaskResponse> {
+        Ok(self
+            .post_resources_termination_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a card.
+     *
+     * This function performs a `POST` to the `/cards/{id}/deferred/termination` endpoint.
+     *
+     * As opposed to `post_resources_termination`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_resources_termination_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
         let url = self.client.url(
             &format!(
                 "/cards/{}/deferred/termination",
@@ -262,7 +450,7 @@ impl Cards {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -283,6 +471,23 @@ impl Cards {
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
     ) -> ClientResult<crate::types::TaskResponse> {
+        Ok(self
+            .post_resources_suspension_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Suspend a card.
+     *
+     * This function performs a `POST` to the `/cards/{id}/deferred/suspension` endpoint.
+     *
+     * As opposed to `post_resources_suspension`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_resources_suspension_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
         let url = self.client.url(
             &format!(
                 "/cards/{}/deferred/suspension",
@@ -291,7 +496,7 @@ impl Cards {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -312,6 +517,23 @@ impl Cards {
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
     ) -> ClientResult<crate::types::TaskResponse> {
+        Ok(self
+            .post_resources_unsuspension_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Removes a card's suspension.
+     *
+     * This function performs a `POST` to the `/cards/{id}/deferred/unsuspension` endpoint.
+     *
+     * As opposed to `post_resources_unsuspension`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_resources_unsuspension_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaskResponse>> {
         let url = self.client.url(
             &format!(
                 "/cards/{}/deferred/unsuspension",
@@ -320,7 +542,7 @@ impl Cards {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -344,6 +566,19 @@ impl Cards {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::GetResourcesCardsDeferredResponse> {
+        Ok(self.get_resources_deferred_with_response(id).await?.body)
+    }
+    /**
+     * Get status of a deferred card task.
+     *
+     * This function performs a `GET` to the `/cards/deferred/status/{id}` endpoint.
+     *
+     * As opposed to `get_resources_deferred`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_resources_deferred_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetResourcesCardsDeferredResponse>> {
         let url = self.client.url(
             &format!(
                 "/cards/deferred/status/{}",
@@ -352,7 +587,7 @@ impl Cards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -23,9 +23,21 @@ impl CustomIds {
     pub async fn get_custom_provider(
         &self,
     ) -> ClientResult<crate::types::GetCustomProviderResponse> {
+        Ok(self.get_custom_provider_with_response().await?.body)
+    }
+    /**
+     * GET the Custom ID provider linked to the current OAuth token.
+     *
+     * This function performs a `GET` to the `/custom-id-provider` endpoint.
+     *
+     * As opposed to `get_custom_provider`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_custom_provider_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetCustomProviderResponse>> {
         let url = self.client.url("/custom-id-provider", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -48,9 +60,21 @@ impl CustomIds {
     pub async fn postcustom_provider(
         &self,
     ) -> ClientResult<crate::types::PostcustomProviderResponse> {
+        Ok(self.postcustom_provider_with_response().await?.body)
+    }
+    /**
+     * Create a Custom ID provider.
+     *
+     * This function performs a `POST` to the `/custom-id-provider` endpoint.
+     *
+     * As opposed to `postcustom_provider`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn postcustom_provider_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::PostcustomProviderResponse>> {
         let url = self.client.url("/custom-id-provider", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,11 +94,24 @@ impl CustomIds {
         &self,
         body: &crate::types::GetCustomProviderResponse,
     ) -> ClientResult<()> {
+        self.post_custom_provider_application_link_with_response(body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `POST` to the `/custom-id-provider/application-link` endpoint.
+     *
+     * As opposed to `post_custom_provider_application_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_custom_provider_application_link_with_response(
+        &self,
+        body: &crate::types::GetCustomProviderResponse,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self
             .client
             .url("/custom-id-provider/application-link", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -99,6 +136,23 @@ impl CustomIds {
         entity_type: &str,
         custom_id: &str,
     ) -> ClientResult<crate::types::GetEntityTypeCustomRampResponse> {
+        Ok(self
+            .get_entity_type_custom_ramp_with_response(entity_type, custom_id)
+            .await?
+            .body)
+    }
+    /**
+     * Convert custom id to ramp id.
+     *
+     * This function performs a `GET` to the `/custom-id-provider/{entity_type}/{custom_id}/ramp-id` endpoint.
+     *
+     * As opposed to `get_entity_type_custom_ramp`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_entity_type_custom_ramp_with_response(
+        &self,
+        entity_type: &str,
+        custom_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetEntityTypeCustomRampResponse>> {
         let url = self.client.url(
             &format!(
                 "/custom-id-provider/{}/{}/ramp-id",
@@ -108,7 +162,7 @@ impl CustomIds {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -131,6 +185,23 @@ impl CustomIds {
         entity_type: &str,
         ramp_id: &str,
     ) -> ClientResult<crate::types::GetEntityTypeRampCustomResponse> {
+        Ok(self
+            .get_entity_type_ramp_custom_with_response(entity_type, ramp_id)
+            .await?
+            .body)
+    }
+    /**
+     * Convert ramp id to custom id.
+     *
+     * This function performs a `GET` to the `/custom-id-provider/{entity_type}/{ramp_id}/custom-id` endpoint.
+     *
+     * As opposed to `get_entity_type_ramp_custom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_entity_type_ramp_custom_with_response(
+        &self,
+        entity_type: &str,
+        ramp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetEntityTypeRampCustomResponse>> {
         let url = self.client.url(
             &format!(
                 "/custom-id-provider/{}/{}/custom-id",
@@ -140,7 +211,7 @@ impl CustomIds {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -161,6 +232,22 @@ impl CustomIds {
         entity_type: &str,
         body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
     ) -> ClientResult<()> {
+        self.post_custom_provider_entity_type_link_with_response(entity_type, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Create custom id link.
+     *
+     * This function performs a `POST` to the `/custom-id-provider/{entity_type}/custom-id-link` endpoint.
+     *
+     * As opposed to `post_custom_provider_entity_type_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_custom_provider_entity_type_link_with_response(
+        &self,
+        entity_type: &str,
+        body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/custom-id-provider/{}/custom-id-link",
@@ -169,7 +256,7 @@ impl CustomIds {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
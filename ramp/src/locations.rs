@@ -29,6 +29,20 @@ impl Locations {
         start: &str,
         page_size: f64,
     ) -> ClientResult<Vec<crate::types::Location>> {
+        Ok(self.get_page_with_response(start, page_size).await?.body)
+    }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/locations` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_size.to_string().is_empty() {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -38,9 +52,9 @@ impl Locations {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/locations?{}", query_), None);
-        let resp: crate::types::GetLocationResponse = self
+        let resp: crate::Response<crate::types::GetLocationResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,7 +64,7 @@ impl Locations {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * List locations.
@@ -112,6 +126,91 @@ impl Locations {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/locations` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Location>>> + Unpin + '_
+    {
+        let url = self.client.url("/locations", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let resp: crate::types::GetLocationResponse = match &cursor {
+                        Some(c) => match self
+                            .client
+                            .get(
+                                c.trim_start_matches(&self.client.host),
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(resp) => resp,
+                            // Ramp sends a 404 when we run out of pages.
+                            Err(crate::ClientError::HttpError {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            }) => return Ok(None),
+                            Err(e) => return Err(e),
+                        },
+                        None => {
+                            self.client
+                                .get(
+                                    &url,
+                                    crate::Message {
+                                        body: None,
+                                        content_type: None,
+                                    },
+                                )
+                                .await?
+                        }
+                    };
+
+                    let next = Some(resp.page.next).filter(|n| !n.is_empty());
+                    let items = resp.data;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/locations` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Location>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(None))
+    }
     /**
      * Create new location.
      *
@@ -127,9 +226,22 @@ impl Locations {
         &self,
         body: &crate::types::PostLocationRequest,
     ) -> ClientResult<crate::types::Location> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Create new location.
+     *
+     * This function performs a `POST` to the `/locations` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url("/locations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -150,12 +262,25 @@ impl Locations {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get(&self, id: &str) -> ClientResult<crate::types::Location> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * GET a location.
+     *
+     * This function performs a `GET` to the `/locations/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url(
             &format!("/locations/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -176,12 +301,26 @@ impl Locations {
         id: &str,
         body: &crate::types::PostLocationRequest,
     ) -> ClientResult<crate::types::Location> {
+        Ok(self.patch_with_response(id, body).await?.body)
+    }
+    /**
+     * Update location.
+     *
+     * This function performs a `PATCH` to the `/locations/{id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::Location>> {
         let url = self.client.url(
             &format!("/locations/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -36,6 +36,34 @@ impl Receipts {
         start: &str,
         page_size: f64,
     ) -> ClientResult<Vec<crate::types::Receipt>> {
+        Ok(self
+            .get_page_with_response(
+                from_date,
+                to_date,
+                created_after,
+                created_before,
+                start,
+                page_size,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Receipt>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = created_after {
             query_args.push(("created_after".to_string(), date.to_rfc3339()));
@@ -57,9 +85,9 @@ impl Receipts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/receipts?{}", query_), None);
-        let resp: crate::types::GetReceiptsResponse = self
+        let resp: crate::Response<crate::types::GetReceiptsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,7 +97,7 @@ impl Receipts {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * List receipts.
@@ -151,6 +179,120 @@ impl Receipts {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Receipt>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = created_after {
+            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = created_before {
+            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/receipts?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let resp: crate::types::GetReceiptsResponse = match &cursor {
+                        Some(c) => match self
+                            .client
+                            .get(
+                                c.trim_start_matches(&self.client.host),
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(resp) => resp,
+                            // Ramp sends a 404 when we run out of pages.
+                            Err(crate::ClientError::HttpError {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            }) => return Ok(None),
+                            Err(e) => return Err(e),
+                        },
+                        None => {
+                            self.client
+                                .get(
+                                    &url,
+                                    crate::Message {
+                                        body: None,
+                                        content_type: None,
+                                    },
+                                )
+                                .await?
+                        }
+                    };
+
+                    let next = Some(resp.page.next).filter(|n| !n.is_empty());
+                    let items = resp.data;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Receipt>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            from_date,
+            to_date,
+            created_after,
+            created_before,
+            None,
+        ))
+    }
     /**
      * Get details for one receipt.
      *
@@ -159,12 +301,25 @@ impl Receipts {
      *
      */
     pub async fn get(&self, id: &str) -> ClientResult<crate::types::Receipt> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * Get details for one receipt.
+     *
+     * This function performs a `GET` to the `/receipts/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Receipt>> {
         let url = self.client.url(
             &format!("/receipts/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -26,6 +26,20 @@ impl Reimbursements {
         start: &str,
         page_size: f64,
     ) -> ClientResult<Vec<crate::types::Reimbursement>> {
+        Ok(self.get_page_with_response(start, page_size).await?.body)
+    }
+    /**
+     * List Reimbursements.
+     *
+     * This function performs a `GET` to the `/reimbursements` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Reimbursement>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_size.to_string().is_empty() {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -37,9 +51,9 @@ impl Reimbursements {
         let url = self
             .client
             .url(&format!("/reimbursements?{}", query_), None);
-        let resp: crate::types::GetReimbursementsResponse = self
+        let resp: crate::Response<crate::types::GetReimbursementsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,7 +63,7 @@ impl Reimbursements {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * List Reimbursements.
@@ -109,12 +123,111 @@ impl Reimbursements {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List Reimbursements.
+     *
+     * This function performs a `GET` to the `/reimbursements` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Reimbursement>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/reimbursements", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let resp: crate::types::GetReimbursementsResponse = match &cursor {
+                        Some(c) => match self
+                            .client
+                            .get(
+                                c.trim_start_matches(&self.client.host),
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(resp) => resp,
+                            // Ramp sends a 404 when we run out of pages.
+                            Err(crate::ClientError::HttpError {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            }) => return Ok(None),
+                            Err(e) => return Err(e),
+                        },
+                        None => {
+                            self.client
+                                .get(
+                                    &url,
+                                    crate::Message {
+                                        body: None,
+                                        content_type: None,
+                                    },
+                                )
+                                .await?
+                        }
+                    };
+
+                    let next = Some(resp.page.next).filter(|n| !n.is_empty());
+                    let items = resp.data;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Reimbursements.
+     *
+     * This function performs a `GET` to the `/reimbursements` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Reimbursement>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(None))
+    }
     /**
      * Get details for one reimbursement.
      *
      * This function performs a `GET` to the `/reimbursements/{id}` endpoint.
      */
     pub async fn get(&self, id: &str) -> ClientResult<crate::types::Reimbursement> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * Get details for one reimbursement.
+     *
+     * This function performs a `GET` to the `/reimbursements/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Reimbursement>> {
         let url = self.client.url(
             &format!(
                 "/reimbursements/{}",
@@ -123,7 +236,7 @@ impl Reimbursements {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,6 +57,54 @@ impl Transactions {
         page_size: f64,
         requires_memo: bool,
     ) -> ClientResult<Vec<crate::types::Data>> {
+        Ok(self
+            .get_page_with_response(
+                department_id,
+                location_id,
+                from_date,
+                to_date,
+                merchant_id,
+                sk_category_id,
+                order_by_date_desc,
+                order_by_date_asc,
+                order_by_amount_desc,
+                order_by_amount_asc,
+                state,
+                min_amount,
+                max_amount,
+                start,
+                page_size,
+                requires_memo,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        start: &str,
+        page_size: f64,
+        requires_memo: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Data>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !department_id.is_empty() {
             query_args.push(("department_id".to_string(), department_id.to_string()));
@@ -120,9 +168,9 @@ impl Transactions {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/transactions?{}", query_), None);
-        let resp: crate::types::GetTransactionResponse = self
+        let resp: crate::Response<crate::types::GetTransactionResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -132,7 +180,7 @@ impl Transactions {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * List transactions.
@@ -266,6 +314,192 @@ impl Transactions {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Data>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !department_id.is_empty() {
+            query_args.push(("department_id".to_string(), department_id.to_string()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        }
+        if !location_id.is_empty() {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if !max_amount.to_string().is_empty() {
+            query_args.push(("max_amount".to_string(), max_amount.to_string()));
+        }
+        if !merchant_id.is_empty() {
+            query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
+        }
+        if !min_amount.to_string().is_empty() {
+            query_args.push(("min_amount".to_string(), min_amount.to_string()));
+        }
+        if order_by_amount_asc {
+            query_args.push((
+                "order_by_amount_asc".to_string(),
+                order_by_amount_asc.to_string(),
+            ));
+        }
+        if order_by_amount_desc {
+            query_args.push((
+                "order_by_amount_desc".to_string(),
+                order_by_amount_desc.to_string(),
+            ));
+        }
+        if order_by_date_asc {
+            query_args.push((
+                "order_by_date_asc".to_string(),
+                order_by_date_asc.to_string(),
+            ));
+        }
+        if order_by_date_desc {
+            query_args.push((
+                "order_by_date_desc".to_string(),
+                order_by_date_desc.to_string(),
+            ));
+        }
+        if requires_memo {
+            query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
+        }
+        if !sk_category_id.is_empty() {
+            query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
+        }
+        if !state.is_empty() {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/transactions?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let resp: crate::types::GetTransactionResponse = match &cursor {
+                        Some(c) => match self
+                            .client
+                            .get(
+                                c.trim_start_matches(&self.client.host),
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(resp) => resp,
+                            // Ramp sends a 404 when we run out of pages.
+                            Err(crate::ClientError::HttpError {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            }) => return Ok(None),
+                            Err(e) => return Err(e),
+                        },
+                        None => {
+                            self.client
+                                .get(
+                                    &url,
+                                    crate::Message {
+                                        body: None,
+                                        content_type: None,
+                                    },
+                                )
+                                .await?
+                        }
+                    };
+
+                    let next = Some(resp.page.next).filter(|n| !n.is_empty());
+                    let items = resp.data;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Data>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            department_id,
+            location_id,
+            from_date,
+            to_date,
+            merchant_id,
+            sk_category_id,
+            order_by_date_desc,
+            order_by_date_asc,
+            order_by_amount_desc,
+            order_by_amount_asc,
+            state,
+            min_amount,
+            max_amount,
+            requires_memo,
+            None,
+        ))
+    }
     /**
      * GET a transaction.
      *
@@ -278,6 +512,19 @@ impl Transactions {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get_resource(&self, id: &str) -> ClientResult<crate::types::Data> {
+        Ok(self.get_resource_with_response(id).await?.body)
+    }
+    /**
+     * GET a transaction.
+     *
+     * This function performs a `GET` to the `/transactions/{id}` endpoint.
+     *
+     * As opposed to `get_resource`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_resource_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Data>> {
         let url = self.client.url(
             &format!(
                 "/transactions/{}",
@@ -286,7 +533,7 @@ impl Transactions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
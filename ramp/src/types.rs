@@ -13,9 +13,6 @@ pub struct Error {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub details: Option<Details>,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -33,7 +30,7 @@ pub struct ErrorResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TaskResponse {
     /**
-     * The OAuth2 token header
+     * ID of the job started.
      */
     #[serde(
         default,
@@ -44,6 +41,7 @@ pub struct TaskResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Role {
     #[serde(rename = "BUSINESS_ADMIN")]
     BusinessAdmin,
@@ -55,8 +53,9 @@ pub enum Role {
     BusinessUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -67,12 +66,34 @@ impl std::fmt::Display for Role {
             Role::BusinessOwner => "BUSINESS_OWNER",
             Role::BusinessUser => "BUSINESS_USER",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Role {
+    fn from(s: String) -> Role {
+        match s.as_str() {
+            "BUSINESS_ADMIN" => Role::BusinessAdmin,
+            "BUSINESS_BOOKKEEPER" => Role::BusinessBookkeeper,
+            "BUSINESS_OWNER" => Role::BusinessOwner,
+            "BUSINESS_USER" => Role::BusinessUser,
+            "" => Role::Noop,
+            _ => Role::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Role> for String {
+    fn from(e: Role) -> String {
+        match e {
+            Role::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -87,90 +108,60 @@ impl Role {
 /// Ramp User
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct User {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub amount_limit: String,
-    /**
-     * Ramp User
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub business_id: String,
-    /**
-     * Ramp User
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub department_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * Ramp User
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub location_id: String,
-    /**
-     * Ramp User
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub manager_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -183,27 +174,18 @@ pub struct User {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchUsersRequest {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub department_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub direct_manager_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -216,9 +198,6 @@ pub struct PatchUsersRequest {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Page {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -229,54 +208,36 @@ pub struct Page {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CardHolder {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub department_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub department_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub location_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -287,18 +248,12 @@ pub struct CardHolder {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AccountingCategories {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub category_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -308,6 +263,7 @@ pub struct AccountingCategories {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "POLICY_VIOLATION_FROM_ADMIN")]
     PolicyViolationFromAdmin,
@@ -315,8 +271,9 @@ pub enum Type {
     PolicyViolationFromUser,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -325,12 +282,32 @@ impl std::fmt::Display for Type {
             Type::PolicyViolationFromAdmin => "POLICY_VIOLATION_FROM_ADMIN",
             Type::PolicyViolationFromUser => "POLICY_VIOLATION_FROM_USER",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "POLICY_VIOLATION_FROM_ADMIN" => Type::PolicyViolationFromAdmin,
+            "POLICY_VIOLATION_FROM_USER" => Type::PolicyViolationFromUser,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -350,9 +327,6 @@ pub struct PolicyViolations {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -360,7 +334,7 @@ pub struct PolicyViolations {
     )]
     pub id: String,
     /**
-     * The OAuth2 token header
+     * Free form text regarding the policy violation.
      */
     #[serde(
         default,
@@ -373,19 +347,19 @@ pub struct PolicyViolations {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetTransactionResponseDataDisputesType {
     #[serde(rename = "DISPUTE_CANCELLED")]
     DisputeCancelled,
     #[serde(rename = "MERCHANT_ERROR")]
     MerchantError,
-    #[serde(rename = "UNKNOWN")]
-    Unknown,
     #[serde(rename = "UNRECOGNIZED_CHARGE")]
     UnrecognizedCharge,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetTransactionResponseDataDisputesType {
@@ -393,15 +367,35 @@ impl std::fmt::Display for GetTransactionResponseDataDisputesType {
         match self {
             GetTransactionResponseDataDisputesType::DisputeCancelled => "DISPUTE_CANCELLED",
             GetTransactionResponseDataDisputesType::MerchantError => "MERCHANT_ERROR",
-            GetTransactionResponseDataDisputesType::Unknown => "UNKNOWN",
             GetTransactionResponseDataDisputesType::UnrecognizedCharge => "UNRECOGNIZED_CHARGE",
             GetTransactionResponseDataDisputesType::Noop => "",
-            GetTransactionResponseDataDisputesType::FallthroughString => "*",
+            GetTransactionResponseDataDisputesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetTransactionResponseDataDisputesType {
+    fn from(s: String) -> GetTransactionResponseDataDisputesType {
+        match s.as_str() {
+            "DISPUTE_CANCELLED" => GetTransactionResponseDataDisputesType::DisputeCancelled,
+            "MERCHANT_ERROR" => GetTransactionResponseDataDisputesType::MerchantError,
+            "UNRECOGNIZED_CHARGE" => GetTransactionResponseDataDisputesType::UnrecognizedCharge,
+            "" => GetTransactionResponseDataDisputesType::Noop,
+            _ => GetTransactionResponseDataDisputesType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetTransactionResponseDataDisputesType> for String {
+    fn from(e: GetTransactionResponseDataDisputesType) -> String {
+        match e {
+            GetTransactionResponseDataDisputesType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetTransactionResponseDataDisputesType {
     fn default() -> GetTransactionResponseDataDisputesType {
         GetTransactionResponseDataDisputesType::Noop
@@ -421,9 +415,6 @@ pub struct Disputes {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -431,7 +422,7 @@ pub struct Disputes {
     )]
     pub id: String,
     /**
-     * The OAuth2 token header
+     * Free form text provided by the dispute initiator.
      */
     #[serde(
         default,
@@ -447,7 +438,7 @@ pub struct Disputes {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Data {
     /**
-     * Ramp transaction
+     * Accounting categories related to the transaction.
      */
     #[serde(
         default,
@@ -455,9 +446,6 @@ pub struct Data {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub accounting_categories: Vec<AccountingCategories>,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -466,9 +454,6 @@ pub struct Data {
     pub amount: f64,
     #[serde()]
     pub card_holder: CardHolder,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -476,7 +461,7 @@ pub struct Data {
     )]
     pub card_id: String,
     /**
-     * Ramp transaction
+     * Disputes listed in descending order by their creation time, related to the transaction.
      */
     #[serde(
         default,
@@ -484,36 +469,24 @@ pub struct Data {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub disputes: Vec<Disputes>,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * Ramp transaction
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub memo: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub merchant_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -521,7 +494,7 @@ pub struct Data {
     )]
     pub merchant_name: String,
     /**
-     * Ramp transaction
+     * Policy violations listed in descending order by their creation time, related to the transaction.
      */
     #[serde(
         default,
@@ -530,7 +503,7 @@ pub struct Data {
     )]
     pub policy_violations: Vec<PolicyViolations>,
     /**
-     * Ramp transaction
+     * Receipts listed in ascending order by their creation time, related to the transaction.
      */
     #[serde(
         default,
@@ -538,27 +511,18 @@ pub struct Data {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub receipts: Vec<String>,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub sk_category_id: f64,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sk_category_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -588,18 +552,12 @@ pub struct GetTransactionResponse {
 /// Ramp location
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Location {
-    /**
-     * Ramp location
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * Ramp location
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -623,9 +581,6 @@ pub struct GetLocationResponse {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostLocationRequest {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -650,18 +605,12 @@ pub struct GetUsersResponse {
 /// Ramp Department
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Department {
-    /**
-     * Ramp Department
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * Ramp Department
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -684,9 +633,6 @@ pub struct GetDepartmentsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecipientAddress {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -694,54 +640,36 @@ pub struct RecipientAddress {
         rename = "address1"
     )]
     pub address_1: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -759,9 +687,6 @@ pub struct Shipping {
 /// Details for shipping physical cards
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Fulfillment {
-    /**
-     * Details for shipping physical cards
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shipping: Option<Shipping>,
 }
@@ -770,6 +695,7 @@ pub struct Fulfillment {
 * Time interval to apply limit to.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Interval {
     #[serde(rename = "DAILY")]
     Daily,
@@ -781,8 +707,9 @@ pub enum Interval {
     Yearly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Interval {
@@ -793,12 +720,34 @@ impl std::fmt::Display for Interval {
             Interval::Total => "TOTAL",
             Interval::Yearly => "YEARLY",
             Interval::Noop => "",
-            Interval::FallthroughString => "*",
+            Interval::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Interval {
+    fn from(s: String) -> Interval {
+        match s.as_str() {
+            "DAILY" => Interval::Daily,
+            "MONTHLY" => Interval::Monthly,
+            "TOTAL" => Interval::Total,
+            "YEARLY" => Interval::Yearly,
+            "" => Interval::Noop,
+            _ => Interval::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Interval> for String {
+    fn from(e: Interval) -> String {
+        match e {
+            Interval::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Interval {
     fn default() -> Interval {
         Interval::Noop
@@ -814,7 +763,7 @@ impl Interval {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SpendingRestrictions {
     /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * Amount limit total per interval.
      */
     #[serde(
         default,
@@ -823,7 +772,7 @@ pub struct SpendingRestrictions {
     )]
     pub amount: f64,
     /**
-     * Specifies the spend restrictions on a Ramp card.
+     * List of Ramp Category Codes blocked for this card.
      */
     #[serde(
         default,
@@ -832,7 +781,7 @@ pub struct SpendingRestrictions {
     )]
     pub blocked_categories: Vec<f64>,
     /**
-     * Specifies the spend restrictions on a Ramp card.
+     * List of Ramp Category Codes to restrict this card to.
      */
     #[serde(
         default,
@@ -846,7 +795,7 @@ pub struct SpendingRestrictions {
     #[serde(default, skip_serializing_if = "Interval::is_noop")]
     pub interval: Interval,
     /**
-     * Specifies the spend restrictions on a Ramp card.
+     * Date to automatically lock the card. If lock date has passed, set to a future date or to null to unlock the card.
      */
     #[serde(
         default,
@@ -855,7 +804,7 @@ pub struct SpendingRestrictions {
     )]
     pub lock_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Specifies the spend restrictions on a Ramp card.
+     * Whether the card has been suspended
      */
     #[serde(
         default,
@@ -863,7 +812,7 @@ pub struct SpendingRestrictions {
     )]
     pub suspended: bool,
     /**
-     * Specifies the spend restrictions on a Ramp card.
+     * Max amount limit per transaction.
      */
     #[serde(
         default,
@@ -876,36 +825,24 @@ pub struct SpendingRestrictions {
 /// Card data that holds mostly static information about a card.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Card {
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub card_program_id: String,
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cardholder_id: String,
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cardholder_name: String,
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -913,30 +850,21 @@ pub struct Card {
     )]
     pub display_name: String,
     /**
-     * Card data that holds mostly static information about a card.
+     * Details for shipping physical cards
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fulfillment: Option<Fulfillment>,
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_physical: bool,
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -944,7 +872,7 @@ pub struct Card {
     )]
     pub last_four: String,
     /**
-     * Card data that holds mostly static information about a card.
+     * Specifies the spend restrictions on a Ramp card.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spending_restrictions: Option<SpendingRestrictions>,
@@ -965,7 +893,7 @@ pub struct GetCardsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchResourcesCardsCardRequest {
     /**
-     * The OAuth2 token header
+     * Set to link card with a card program, or set to null to detach a card from a card program. If the card is already linked with a card program, it will detach from original card program before linking with the new one.
      */
     #[serde(
         default,
@@ -974,7 +902,7 @@ pub struct PatchResourcesCardsCardRequest {
     )]
     pub card_program_id: String,
     /**
-     * The OAuth2 token header
+     * Cosmetic display name of the card.
      */
     #[serde(
         default,
@@ -982,6 +910,9 @@ pub struct PatchResourcesCardsCardRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
+    /**
+     * Flag to set to enable or disable notifications.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_notifications_enabled: Option<bool>,
     /**
@@ -993,9 +924,6 @@ pub struct PatchResourcesCardsCardRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCustomProviderResponse {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1006,9 +934,6 @@ pub struct GetCustomProviderResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostcustomProviderResponse {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1019,9 +944,6 @@ pub struct PostcustomProviderResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BillingAddress {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1029,36 +951,24 @@ pub struct BillingAddress {
         rename = "address1"
     )]
     pub address_1: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1077,45 +987,30 @@ pub struct Business {
     pub active: bool,
     #[serde()]
     pub billing_address: BillingAddress,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub business_memo_required_threshold: f64,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub business_name_legal: String,
-    /**
-     * Mostly static information about a business that doesn't change often.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub business_name_on_card: String,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub business_receipt_required_threshold: f64,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1127,18 +1022,12 @@ pub struct Business {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enforce_sso: bool,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -1160,18 +1049,12 @@ pub struct Business {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub limit_locked: bool,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1183,7 +1066,7 @@ pub struct Business {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardPhysicalRequest {
     /**
-     * The OAuth2 token header
+     * Alternative method to create card using a card program. Card program's is_physical must be true. If this value is given, no other attributes (other than idempotency_key) may be given.
      */
     #[serde(
         default,
@@ -1191,9 +1074,6 @@ pub struct PostResourcesCardPhysicalRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub card_program_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1206,7 +1086,7 @@ pub struct PostResourcesCardPhysicalRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fulfillment: Option<Fulfillment>,
     /**
-     * The OAuth2 token header
+     * Idempotency key
      */
     #[serde(
         default,
@@ -1219,9 +1099,6 @@ pub struct PostResourcesCardPhysicalRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spending_restrictions: Option<SpendingRestrictions>,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1233,7 +1110,7 @@ pub struct PostResourcesCardPhysicalRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardVirtualRequest {
     /**
-     * The OAuth2 token header
+     * Alternative method to create card using a card program. Card program's is_physical must be false. If this value is given, no other attributes (other than idempotency_key) may be given.
      */
     #[serde(
         default,
@@ -1241,9 +1118,6 @@ pub struct PostResourcesCardVirtualRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub card_program_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1251,7 +1125,7 @@ pub struct PostResourcesCardVirtualRequest {
     )]
     pub display_name: String,
     /**
-     * The OAuth2 token header
+     * Idempotency key
      */
     #[serde(
         default,
@@ -1264,9 +1138,6 @@ pub struct PostResourcesCardVirtualRequest {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spending_restrictions: Option<SpendingRestrictions>,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1276,13 +1147,15 @@ pub struct PostResourcesCardVirtualRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TokenType {
     #[serde(rename = "Bearer")]
     Bearer,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TokenType {
@@ -1290,12 +1163,31 @@ impl std::fmt::Display for TokenType {
         match self {
             TokenType::Bearer => "Bearer",
             TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+            TokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TokenType {
+    fn from(s: String) -> TokenType {
+        match s.as_str() {
+            "Bearer" => TokenType::Bearer,
+            "" => TokenType::Noop,
+            _ => TokenType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TokenType> for String {
+    fn from(e: TokenType) -> String {
+        match e {
+            TokenType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TokenType {
     fn default() -> TokenType {
         TokenType::Noop
@@ -1310,9 +1202,6 @@ impl TokenType {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OAuth2Token {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1328,9 +1217,6 @@ pub struct OAuth2Token {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub expires_in: i64,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1338,7 +1224,7 @@ pub struct OAuth2Token {
     )]
     pub refresh_token: String,
     /**
-     *
+     * Expiration time for refresh token in seconds
      */
     #[serde(
         default,
@@ -1347,7 +1233,7 @@ pub struct OAuth2Token {
     )]
     pub refresh_token_expires_in: i64,
     /**
-     * The OAuth2 token header
+     * Space-separated set of strings representing accessible resources
      */
     #[serde(
         default,
@@ -1362,45 +1248,30 @@ pub struct OAuth2Token {
 /// Current data about the business.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BusinessCurrentStatus {
-    /**
-     * Current data about the business.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub balance_including_pending: f64,
-    /**
-     * Current data about the business.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub max_balance: f64,
-    /**
-     * Current data about the business.
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub next_billing_date: Option<chrono::NaiveDate>,
-    /**
-     * Current data about the business.
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub prev_billing_date: Option<chrono::NaiveDate>,
-    /**
-     * Current data about the business.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -1412,7 +1283,7 @@ pub struct BusinessCurrentStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardsCardSuspensionRequest {
     /**
-     * The OAuth2 token header
+     * Idempotency key
      */
     #[serde(
         default,
@@ -1424,9 +1295,6 @@ pub struct PostResourcesCardsCardSuspensionRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetEntityTypeCustomRampResponse {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1437,9 +1305,6 @@ pub struct GetEntityTypeCustomRampResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetEntityTypeRampCustomResponse {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1450,36 +1315,24 @@ pub struct GetEntityTypeRampCustomResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetResourcesCardsDeferredResponseData {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub card_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1489,6 +1342,7 @@ pub struct GetResourcesCardsDeferredResponseData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "ERROR")]
     Error,
@@ -1500,8 +1354,9 @@ pub enum Status {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1512,12 +1367,34 @@ impl std::fmt::Display for Status {
             Status::Started => "STARTED",
             Status::Success => "SUCCESS",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "ERROR" => Status::Error,
+            "IN_PROGRESS" => Status::InProgress,
+            "STARTED" => Status::Started,
+            "SUCCESS" => Status::Success,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1533,9 +1410,6 @@ impl Status {
 pub struct GetResourcesCardsDeferredResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<GetResourcesCardsDeferredResponseData>,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1547,6 +1421,7 @@ pub struct GetResourcesCardsDeferredResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Icon {
     #[serde(rename = "AdvertisingIcon")]
     AdvertisingIcon,
@@ -1570,8 +1445,9 @@ pub enum Icon {
     WellnessIcon,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Icon {
@@ -1588,12 +1464,40 @@ impl std::fmt::Display for Icon {
             Icon::TravelExpensesIcon => "TravelExpensesIcon",
             Icon::WellnessIcon => "WellnessIcon",
             Icon::Noop => "",
-            Icon::FallthroughString => "*",
+            Icon::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Icon {
+    fn from(s: String) -> Icon {
+        match s.as_str() {
+            "AdvertisingIcon" => Icon::AdvertisingIcon,
+            "CardIcon" => Icon::CardIcon,
+            "EducationStipendIcon" => Icon::EducationStipendIcon,
+            "LunchOrderingIcon" => Icon::LunchOrderingIcon,
+            "OnboardingIcon" => Icon::OnboardingIcon,
+            "PerDiemCardIcon" => Icon::PerDiemCardIcon,
+            "SaasSubscriptionIcon" => Icon::SaasSubscriptionIcon,
+            "SoftwareTrialIcon" => Icon::SoftwareTrialIcon,
+            "TravelExpensesIcon" => Icon::TravelExpensesIcon,
+            "WellnessIcon" => Icon::WellnessIcon,
+            "" => Icon::Noop,
+            _ => Icon::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Icon> for String {
+    fn from(e: Icon) -> String {
+        match e {
+            Icon::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Icon {
     fn default() -> Icon {
         Icon::Noop
@@ -1608,56 +1512,38 @@ impl Icon {
 /// Card Program data that serves as a template for creating new cards.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CardProgram {
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub icon: Option<Icon>,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_default: bool,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_physical: bool,
     /**
-     * Card Program data that serves as a template for creating new cards.
+     * Specifies the spend restrictions on a Ramp card.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spending_restrictions: Option<SpendingRestrictions>,
@@ -1678,18 +1564,12 @@ pub struct GetCardProgramsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardProgramRequest {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1711,63 +1591,42 @@ pub struct PostResourcesCardProgramRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostUsersDeferredRequest {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub department_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub direct_manager_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub location_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1780,36 +1639,24 @@ pub struct PostUsersDeferredRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUsersDeferredStatusResponseData {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub misc: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1821,23 +1668,14 @@ pub struct GetUsersDeferredStatusResponseData {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUsersDeferredStatusResponse {
-    /**
-     *
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<GetUsersDeferredStatusResponseData>,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1849,9 +1687,6 @@ pub struct GetUsersDeferredStatusResponse {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUsersDeferredStatusResponseDataType {
-    /**
-     *
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<Error>,
 }
@@ -1870,6 +1705,9 @@ pub struct Receipt {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * Pre-signed url to download receipt image
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1934,6 +1772,9 @@ pub struct Reimbursement {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub merchant: String,
+    /**
+     * Receipts listed in ascending order by their creation time, related to the reimbursement.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -1968,18 +1809,12 @@ pub struct GetReimbursementsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCustomProviderEntityTypeLinkRequest {
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_id: String,
-    /**
-     * The OAuth2 token header
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
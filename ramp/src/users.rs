@@ -23,12 +23,25 @@ impl Users {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get(&self, id: &str) -> ClientResult<crate::types::User> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * Get User Info by User ID.
+     *
+     * This function performs a `GET` to the `/users/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let url = self.client.url(
             &format!("/users/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -45,12 +58,23 @@ impl Users {
      * Suspends a user. Does not delete the user's cards. Currently this action is not reversible.
      */
     pub async fn delete(&self, id: &str) -> ClientResult<()> {
+        self.delete_with_response(id).await?;
+        Ok(())
+    }
+    /**
+     * Suspend a user.
+     *
+     * This function performs a `DELETE` to the `/users/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/users/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,12 +95,27 @@ impl Users {
         id: &str,
         body: &crate::types::PatchUsersRequest,
     ) -> ClientResult<()> {
+        self.patch_with_response(id, body).await?;
+        Ok(())
+    }
+    /**
+     * Modify Existing User.
+     *
+     * This function performs a `PATCH` to the `/users/{id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PatchUsersRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/users/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -107,6 +146,25 @@ impl Users {
         department_id: &str,
         location_id: &str,
     ) -> ClientResult<Vec<crate::types::User>> {
+        Ok(self
+            .get_page_with_response(start, page_size, department_id, location_id)
+            .await?
+            .body)
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        start: &str,
+        page_size: f64,
+        department_id: &str,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::User>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !department_id.is_empty() {
             query_args.push(("department_id".to_string(), department_id.to_string()));
@@ -122,9 +180,9 @@ impl Users {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users?{}", query_), None);
-        let resp: crate::types::GetUsersResponse = self
+        let resp: crate::Response<crate::types::GetUsersResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -134,7 +192,7 @@ impl Users {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * List users.
@@ -208,6 +266,104 @@ impl Users {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::User>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !department_id.is_empty() {
+            query_args.push(("department_id".to_string(), department_id.to_string()));
+        }
+        if !location_id.is_empty() {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/users?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let resp: crate::types::GetUsersResponse = match &cursor {
+                        Some(c) => match self
+                            .client
+                            .get(
+                                c.trim_start_matches(&self.client.host),
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await
+                        {
+                            Ok(resp) => resp,
+                            // Ramp sends a 404 when we run out of pages.
+                            Err(crate::ClientError::HttpError {
+                                status: http::StatusCode::NOT_FOUND,
+                                ..
+                            }) => return Ok(None),
+                            Err(e) => return Err(e),
+                        },
+                        None => {
+                            self.client
+                                .get(
+                                    &url,
+                                    crate::Message {
+                                        body: None,
+                                        content_type: None,
+                                    },
+                                )
+                                .await?
+                        }
+                    };
+
+                    let next = Some(resp.page.next).filter(|n| !n.is_empty());
+                    let items = resp.data;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        department_id: &str,
+        location_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::User>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(department_id, location_id, None))
+    }
     /**
      * Invite a new user.
      *
@@ -219,9 +375,22 @@ impl Users {
         &self,
         body: &crate::types::PostUsersDeferredRequest,
     ) -> ClientResult<crate::types::User> {
+        Ok(self.post_deferred_with_response(body).await?.body)
+    }
+    /**
+     * Invite a new user.
+     *
+     * This function performs a `POST` to the `/users/deferred` endpoint.
+     *
+     * As opposed to `post_deferred`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_deferred_with_response(
+        &self,
+        body: &crate::types::PostUsersDeferredRequest,
+    ) -> ClientResult<crate::Response<crate::types::User>> {
         let url = self.client.url("/users/deferred", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -241,6 +410,19 @@ impl Users {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::GetUsersDeferredStatusResponse> {
+        Ok(self.get_deferred_status_with_response(id).await?.body)
+    }
+    /**
+     * Get status of a deferred user task.
+     *
+     * This function performs a `GET` to the `/users/deferred/status/{id}` endpoint.
+     *
+     * As opposed to `get_deferred_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_deferred_status_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetUsersDeferredStatusResponse>> {
         let url = self.client.url(
             &format!(
                 "/users/deferred/status/{}",
@@ -249,7 +431,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -19,9 +19,19 @@ impl Account {
      * Get the developer's account information
      */
     pub async fn get(&self) -> ClientResult<crate::types::Account> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * Get Account.
+     *
+     * This function performs a `GET` to the `/account` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(&self) -> ClientResult<crate::Response<crate::types::Account>> {
         let url = self.client.url("/account", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -33,6 +33,24 @@ impl Captions {
         accept: crate::types::Accept,
         speaker_channel: i64,
     ) -> ClientResult<String> {
+        Ok(self
+            .get_with_response(id, accept, speaker_channel)
+            .await?
+            .body)
+    }
+    /**
+     * Get Captions.
+     *
+     * This function performs a `GET` to the `/jobs/{id}/captions` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        speaker_channel: i64,
+    ) -> ClientResult<crate::Response<String>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if speaker_channel > 0 {
             query_args.push(("speaker_channel".to_string(), speaker_channel.to_string()));
@@ -47,7 +65,7 @@ impl Captions {
             None,
         );
         self.client
-            .request_with_accept_mime(reqwest::Method::GET, &url, &accept.to_string())
+            .request_with_accept_mime_with_response(reqwest::Method::GET, &url, &accept.to_string())
             .await
     }
 }
@@ -28,6 +28,23 @@ impl Jobs {
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::JobAllOf>> {
+        Ok(self
+            .get_list_of_with_response(limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_list_of`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_list_of_with_response(
+        &self,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::JobAllOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -38,7 +55,7 @@ impl Jobs {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/jobs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -76,6 +93,72 @@ impl Jobs {
             )
             .await
     }
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_all_list_all_of`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_list_all_of_pages_stream(
+        &self,
+        starting_after: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::JobAllOf>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !starting_after.is_empty() {
+            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/jobs?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::JobAllOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_all_list_all_of`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_list_all_of_stream(
+        &self,
+        starting_after: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::JobAllOf>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_list_all_of_pages_stream(starting_after, None))
+    }
     /**
      * Submit Transcription Job.
      *
@@ -87,9 +170,22 @@ impl Jobs {
         &self,
         body: &crate::types::SubmitJobMediaUrlOptionsAllOf,
     ) -> ClientResult<crate::types::JobAllOf> {
+        Ok(self.submit_transcription_with_response(body).await?.body)
+    }
+    /**
+     * Submit Transcription Job.
+     *
+     * This function performs a `POST` to the `/jobs` endpoint.
+     *
+     * As opposed to `submit_transcription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn submit_transcription_with_response(
+        &self,
+        body: &crate::types::SubmitJobMediaUrlOptionsAllOf,
+    ) -> ClientResult<crate::Response<crate::types::JobAllOf>> {
         let url = self.client.url("/jobs", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -106,12 +202,25 @@ impl Jobs {
      * Returns information about a transcription job
      */
     pub async fn get(&self, id: &str) -> ClientResult<crate::types::JobAllOf> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * Get Job By Id.
+     *
+     * This function performs a `GET` to the `/jobs/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JobAllOf>> {
         let url = self.client.url(
             &format!("/jobs/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -128,12 +237,23 @@ impl Jobs {
      * Deletes a transcription job. All data related to the job, such as input media and transcript, will be permanently deleted. A job can only be deleted once it's completed (either with success or failure).
      */
     pub async fn delete(&self, id: &str) -> ClientResult<()> {
+        self.delete_with_response(id).await?;
+        Ok(())
+    }
+    /**
+     * Delete Job by Id.
+     *
+     * This function performs a `DELETE` to the `/jobs/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/jobs/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
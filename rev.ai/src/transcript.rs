@@ -30,6 +30,20 @@ impl Transcript {
         id: &str,
         accept: crate::types::AcceptTranscript,
     ) -> ClientResult<String> {
+        Ok(self.get_with_response(id, accept).await?.body)
+    }
+    /**
+     * Get Transcript By Id.
+     *
+     * This function performs a `GET` to the `/jobs/{id}/transcript` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: &str,
+        accept: crate::types::AcceptTranscript,
+    ) -> ClientResult<crate::Response<String>> {
         let url = self.client.url(
             &format!(
                 "/jobs/{}/transcript",
@@ -38,7 +52,7 @@ impl Transcript {
             None,
         );
         self.client
-            .request_with_accept_mime(reqwest::Method::GET, &url, &accept.to_string())
+            .request_with_accept_mime_with_response(reqwest::Method::GET, &url, &accept.to_string())
             .await
     }
 }
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Account {
     /**
-     * Rev.ai Account Model
+     * Amount of Rev.ai API credits remaining in seconds
      */
     #[serde(
         default,
@@ -15,7 +15,7 @@ pub struct Account {
     )]
     pub balance_seconds: i64,
     /**
-     * Rev.ai Account Model
+     * Email of developer account
      */
     #[serde(
         default,
@@ -27,9 +27,6 @@ pub struct Account {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DescriptionlessJobOptions {
-    /**
-     * Email of developer account
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -40,9 +37,6 @@ pub struct DescriptionlessJobOptions {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DescriptionlessJobOptionsData {
-    /**
-     * Email of developer account
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -52,6 +46,7 @@ pub struct DescriptionlessJobOptionsData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Language {
     #[serde(rename = "ar")]
     Ar,
@@ -115,8 +110,9 @@ pub enum Language {
     Sv,
     #[serde(rename = "tr")]
     Tr,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Language {
@@ -153,12 +149,60 @@ impl std::fmt::Display for Language {
             Language::Sl => "sl",
             Language::Sv => "sv",
             Language::Tr => "tr",
-            Language::FallthroughString => "*",
+            Language::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Language {
+    fn from(s: String) -> Language {
+        match s.as_str() {
+            "ar" => Language::Ar,
+            "bg" => Language::Bg,
+            "ca" => Language::Ca,
+            "cmn" => Language::Cmn,
+            "cs" => Language::Cs,
+            "da" => Language::Da,
+            "de" => Language::De,
+            "el" => Language::El,
+            "en" => Language::En,
+            "es" => Language::Es,
+            "fi" => Language::Fi,
+            "fr" => Language::Fr,
+            "hi" => Language::Hi,
+            "hr" => Language::Hr,
+            "hu" => Language::Hu,
+            "it" => Language::It,
+            "ja" => Language::Ja,
+            "ko" => Language::Ko,
+            "lt" => Language::Lt,
+            "lv" => Language::Lv,
+            "ms" => Language::Ms,
+            "nl" => Language::Nl,
+            "no" => Language::No,
+            "pl" => Language::Pl,
+            "pt" => Language::Pt,
+            "ro" => Language::Ro,
+            "ru" => Language::Ru,
+            "sk" => Language::Sk,
+            "sl" => Language::Sl,
+            "sv" => Language::Sv,
+            "tr" => Language::Tr,
+            _ => Language::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Language> for String {
+    fn from(e: Language) -> String {
+        match e {
+            Language::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Language {
     fn default() -> Language {
         Language::En
@@ -167,18 +211,12 @@ impl Default for Language {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DescriptionlessJobOptionsDataType {
-    /**
-     * Email of developer account
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_vocabulary_id: String,
-    /**
-     * Amount of Rev.ai API credits remaining in seconds
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -192,9 +230,6 @@ pub struct DescriptionlessJobOptionsDataType {
     pub filter_profanity: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub language: Option<Language>,
-    /**
-     * Email of developer account
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -216,9 +251,6 @@ pub struct DescriptionlessJobOptionsDataType {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub skip_punctuation: bool,
-    /**
-     * Amount of Rev.ai API credits remaining in seconds
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -247,6 +279,7 @@ pub struct DescriptionlessJobOptionsAllOf {
 * Simple reason of why the transcription job failed. Check `failure_detail` for specific details and solutions
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Failure {
     #[serde(rename = "download_failure")]
     DownloadFailure,
@@ -268,8 +301,9 @@ pub enum Failure {
     Transcription,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Failure {
@@ -285,12 +319,39 @@ impl std::fmt::Display for Failure {
             Failure::InvoicingLimitExceeded => "invoicing_limit_exceeded",
             Failure::Transcription => "transcription",
             Failure::Noop => "",
-            Failure::FallthroughString => "*",
+            Failure::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Failure {
+    fn from(s: String) -> Failure {
+        match s.as_str() {
+            "download_failure" => Failure::DownloadFailure,
+            "duration_exceeded" => Failure::DurationExceeded,
+            "duration_too_short" => Failure::DurationTooShort,
+            "empty_media" => Failure::EmptyMedia,
+            "insufficient_balance" => Failure::InsufficientBalance,
+            "internal_processing" => Failure::InternalProcessing,
+            "invalid_media" => Failure::InvalidMedia,
+            "invoicing_limit_exceeded" => Failure::InvoicingLimitExceeded,
+            "transcription" => Failure::Transcription,
+            "" => Failure::Noop,
+            _ => Failure::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Failure> for String {
+    fn from(e: Failure) -> String {
+        match e {
+            Failure::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Failure {
     fn default() -> Failure {
         Failure::Noop
@@ -306,6 +367,7 @@ impl Failure {
 * Current status of the job
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "failed")]
     Failed,
@@ -315,8 +377,9 @@ pub enum Status {
     Transcribed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -326,12 +389,33 @@ impl std::fmt::Display for Status {
             Status::InProgress => "in_progress",
             Status::Transcribed => "transcribed",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "failed" => Status::Failed,
+            "in_progress" => Status::InProgress,
+            "transcribed" => Status::Transcribed,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -347,13 +431,15 @@ impl Status {
 * Type of speech recognition performed. Currently the only supported values are 'async' for asynchronous jobs and `stream` for streaming jobs
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "async")]
     Async,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -361,12 +447,31 @@ impl std::fmt::Display for Type {
         match self {
             Type::Async => "async",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "async" => Type::Async,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -385,10 +490,7 @@ impl Type {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Job {
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Callback url provided by the user.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub callback_url: Option<serde_json::Value>,
@@ -402,7 +504,7 @@ pub struct Job {
     )]
     pub completed_on: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The date and time the job was completed, whether successfully or failing, in ISO-8601 UTC form
+     * The date and time the job was created in ISO-8601 UTC form
      */
     #[serde(
         default,
@@ -411,18 +513,12 @@ pub struct Job {
     )]
     pub created_on: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * User-supplied custom vocabulary ID to be used with job for transcription.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_vocabulary_id: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Amount of time after job completion when job is auto-deleted. Present only when preference set in job request.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_after_seconds: Option<serde_json::Value>,
@@ -441,10 +537,7 @@ pub struct Job {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub failure: Option<Failure>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Human-readable reason why the job failed
      */
     #[serde(
         default,
@@ -453,18 +546,12 @@ pub struct Job {
     )]
     pub failure_detail: String,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * User-supplied preference on whether to remove explicit words.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_profanity: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Id of the job
      */
     #[serde(
         default,
@@ -473,34 +560,22 @@ pub struct Job {
     )]
     pub id: String,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * User-supplied language to transcribe the audio into.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub language: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Media url provided by the job submission. Null if the job was provided using a local file.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub media_url: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Optional metadata that was provided during job submission
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Name of the file provided. Present when the file name is available
      */
     #[serde(
         default,
@@ -509,50 +584,32 @@ pub struct Job {
     )]
     pub name: String,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * User-supplied preference on whether to remove disfluencies.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub remove_disfluencies: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * User-supplied preference on whether to skip diarization.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub skip_diarization: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * User-supplied preference on whether to skip punctuation.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub skip_punctuation: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * User-supplied number of speaker channels in the audio.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub speaker_channels_count: Option<serde_json::Value>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Current status of the job
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
     /**
-     * Rev.ai Transcription Job
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *
+     * Type of speech recognition performed. Currently the only supported values are 'async' for asynchronous jobs and `stream` for streaming jobs
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
@@ -580,7 +637,7 @@ pub struct JobAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubmitJobMediaUrlOptions {
     /**
-     * Email of developer account
+     * Direct download media url. Ignored if submitting job from file. \*\*Note:\*\* Media files longer than 17 hours are not supported for English transcription, and media files longer than 12 hours are not supported for non-English transcription. For non-English jobs, expected turnaround time can be up to 6 hours.
      */
     #[serde(
         default,
@@ -607,52 +664,109 @@ pub struct SubmitJobMediaUrlOptionsAllOf {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubmitJobOptions {
     /**
-     * Rev.ai Job Options Object Model
+     * Optional callback url to invoke when processing is complete
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub callback_url: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * \*\*This feature is in beta.\*\* You can supply the id of a pre-completed custom vocabulary that you submitted through the [Custom Vocabularies API](https://rev.ai/docs/streaming#operation/SubmitCustomVocabulary) instead of uploading the list of phrases using the `custom_vocabularies` parameter. Using `custom_vocabulary_id` or `custom_vocabularies` with the same list of phrases yields the same transcription result, but `custom_vocabulary_id` allows your submission to finish processing faster by 6 seconds on average.
+     *  
+     *  You cannot use both `custom_vocabulary_id` and `custom_vocabularies` at the same time, and doing so will result in a 400 response. If the supplied id represents an incomplete, deleted, or non-existent custom vocabulary then you will receive a 404 response.
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_vocabulary_id: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * Specify the number of seconds after job completion when job is auto-deleted. It may take up to 2 minutes after the scheduled time for the job to be deleted.
+     *  The number of seconds provided must range from `0` seconds to `2592000` seconds (30 days).
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_after_seconds: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * Enabling this option will filter for approx. 600 profanities, which cover most use cases. If a transcribed word matches a word on this list, then all the characters of that word will be replaced by asterisks except for the first and last character.
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_profanity: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * You can provide a language parameter for transcribing audio in one of the following languages:
+     *  
+     *  | Language   | ISO 639 Language Code                                |
+     *  |------------|-----------------------------------------------|
+     *  | Arabic | ar |
+     *  | Bulgarian  | bg |
+     *  | Catalan    | ca |
+     *  | Croatian   | hr |
+     *  | Czech  | cs |
+     *  | Danish | da |
+     *  | Dutch  | nl |
+     *  | English    | en |
+     *  | Finnish    | fi |
+     *  | French | fr |
+     *  | German | de |
+     *  | Greek  | el |
+     *  | Hindi  | hi |
+     *  | Hungarian  | hu |
+     *  | Italian    | it |
+     *  | Japanese   | ja |
+     *  | Korean | ko |
+     *  | Lithuanian | lt |
+     *  | Latvian    | lv |
+     *  | Malay  | ms |
+     *  | Mandarin   | cmn |
+     *  | Norwegian  | no |
+     *  | Polish | pl |
+     *  | Portuguese | pt |
+     *  | Romanian   | ro |
+     *  | Russian    | ru |
+     *  | Slovak | sk |
+     *  | Slovenian  | sl |
+     *  | Spanish    | es |
+     *  | Swedish    | sv |
+     *  | Turkish    | tr |
+     *  Language parameter is provided as a [ISO 639-1 language code](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes), with the exception of Mandarin (cmn) which is supplied as an [ISO 639-3 language code](https://en.wikipedia.org/wiki/ISO_639-3).
+     *  
+     *  Only 1 language can be selected per audio, i.e. no multiple languages in one transcription job. Additionally, the following parameters may \*\*not\*\* be used with non-English languages: `skip_punctuation`, `remove_disfluencies`, `filter_profanity`, `speaker_channels_count`, `custom_vocabulary_id`.
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub language: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * Optional metadata that was provided during submission
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * Currently we only define disfluencies as 'ums' and 'uhs'. When set to true, disfluencies will be not appear in the transcript.
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub remove_disfluencies: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * Specify if speaker diarization will be skipped by the speech engine
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub skip_diarization: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * Specify if "punct" type elements will be skipped by the speech engine. For JSON outputs, this includes removing spaces. For text outputs,
+     *  words will still be delimited by a space
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub skip_punctuation: Option<serde_json::Value>,
     /**
-     * Rev.ai Job Options Object Model
+     * Use to specify the total number of unique speaker channels in the audio.
+     *  
+     *  Given the number of audio channels provided, each channel will be transcribed
+     *  separately and the channel id assigned to the `speaker` label. The final output will be a combination of all individual channel outputs. Overlapping `monologues` will have ordering broken
+     *  by the order in which the first spoken `element` of each `monologue` occurs. If `speaker_channels_count` is greater than the actual channels in the audio,
+     *  the job will fail with `invalid_media`.
+     *  
+     *  \*\*Note:\*\*
+     *    - The amount charged will be the duration of the file multiplied by the number of channels specified.
+     *    - When using `speaker_channels_count` each channel will be diarized as one speaker, and the value of `skip_diarization` will be ignored if provided
+     *
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub speaker_channels_count: Option<serde_json::Value>,
@@ -710,17 +824,17 @@ pub struct SubmitJobOptionsAllOf {
 * Type of transcript element. If Rev.ai was unable to determine the spoken word, the `type` will be `unknown`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TranscriptMonologuesElementsType {
     #[serde(rename = "punct")]
     Punct,
     #[serde(rename = "text")]
     Text,
-    #[serde(rename = "unknown")]
-    Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TranscriptMonologuesElementsType {
@@ -728,14 +842,33 @@ impl std::fmt::Display for TranscriptMonologuesElementsType {
         match self {
             TranscriptMonologuesElementsType::Punct => "punct",
             TranscriptMonologuesElementsType::Text => "text",
-            TranscriptMonologuesElementsType::Unknown => "unknown",
             TranscriptMonologuesElementsType::Noop => "",
-            TranscriptMonologuesElementsType::FallthroughString => "*",
+            TranscriptMonologuesElementsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TranscriptMonologuesElementsType {
+    fn from(s: String) -> TranscriptMonologuesElementsType {
+        match s.as_str() {
+            "punct" => TranscriptMonologuesElementsType::Punct,
+            "text" => TranscriptMonologuesElementsType::Text,
+            "" => TranscriptMonologuesElementsType::Noop,
+            _ => TranscriptMonologuesElementsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TranscriptMonologuesElementsType> for String {
+    fn from(e: TranscriptMonologuesElementsType) -> String {
+        match e {
+            TranscriptMonologuesElementsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TranscriptMonologuesElementsType {
     fn default() -> TranscriptMonologuesElementsType {
         TranscriptMonologuesElementsType::Noop
@@ -750,7 +883,7 @@ impl TranscriptMonologuesElementsType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Elements {
     /**
-     * Duration of the file in seconds. Null if the file could not be retrieved or there was not a valid media file
+     * Confidence score of the provided value. If the element `type` is `punct` or `unknown`, confidence will be `null`
      */
     #[serde(
         default,
@@ -759,7 +892,7 @@ pub struct Elements {
     )]
     pub confidence: f64,
     /**
-     * Duration of the file in seconds. Null if the file could not be retrieved or there was not a valid media file
+     * The timestamp of the beginning of the element relative to the beginning of the audio in seconds (centisecond precision)
      */
     #[serde(
         default,
@@ -768,7 +901,7 @@ pub struct Elements {
     )]
     pub ts: f64,
     /**
-     * Duration of the file in seconds. Null if the file could not be retrieved or there was not a valid media file
+     * The timestamp of the end of the element relative to the beginning of the audio in seconds (centisecond precision)
      */
     #[serde(
         default,
@@ -782,7 +915,7 @@ pub struct Elements {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<TranscriptMonologuesElementsType>,
     /**
-     * Email of developer account
+     * Value of the transcript element.
      */
     #[serde(
         default,
@@ -804,7 +937,7 @@ pub struct Monologues {
     )]
     pub elements: Vec<Elements>,
     /**
-     * Amount of Rev.ai API credits remaining in seconds
+     * Id of the speaker of the monologue
      */
     #[serde(
         default,
@@ -822,14 +955,6 @@ pub struct Monologues {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Transcript {
-    /**
-     * Rev.ai Transcript Model
-     *  \*\*\*
-     *  Note: properties are not displayed in the returned object if they are null
-     *  
-     *  Jobs with skip_diarization set to true will only show a single speaker for the entire duration of the transcript.
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -842,6 +967,7 @@ pub struct Transcript {
 * MIME type specifying the caption output format
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Accept {
     #[serde(rename = "application/x-subrip")]
     ApplicationXSubrip,
@@ -849,8 +975,9 @@ pub enum Accept {
     TextVtt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Accept {
@@ -859,12 +986,32 @@ impl std::fmt::Display for Accept {
             Accept::ApplicationXSubrip => "application/x-subrip",
             Accept::TextVtt => "text/vtt",
             Accept::Noop => "",
-            Accept::FallthroughString => "*",
+            Accept::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Accept {
+    fn from(s: String) -> Accept {
+        match s.as_str() {
+            "application/x-subrip" => Accept::ApplicationXSubrip,
+            "text/vtt" => Accept::TextVtt,
+            "" => Accept::Noop,
+            _ => Accept::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Accept> for String {
+    fn from(e: Accept) -> String {
+        match e {
+            Accept::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Accept {
     fn default() -> Accept {
         Accept::Noop
@@ -880,6 +1027,7 @@ impl Accept {
 * MIME type specifying the transcription output format
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AcceptTranscript {
     #[serde(rename = "application/vnd.rev.transcript.v1.0+json")]
     ApplicationVndRevTranscript0Json,
@@ -887,8 +1035,9 @@ pub enum AcceptTranscript {
     TextPlain,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AcceptTranscript {
@@ -899,12 +1048,34 @@ impl std::fmt::Display for AcceptTranscript {
             }
             AcceptTranscript::TextPlain => "text/plain",
             AcceptTranscript::Noop => "",
-            AcceptTranscript::FallthroughString => "*",
+            AcceptTranscript::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AcceptTranscript {
+    fn from(s: String) -> AcceptTranscript {
+        match s.as_str() {
+            "application/vnd.rev.transcript.v1.0+json" => {
+                AcceptTranscript::ApplicationVndRevTranscript0Json
+            }
+            "text/plain" => AcceptTranscript::TextPlain,
+            "" => AcceptTranscript::Noop,
+            _ => AcceptTranscript::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AcceptTranscript> for String {
+    fn from(e: AcceptTranscript) -> String {
+        match e {
+            AcceptTranscript::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AcceptTranscript {
     fn default() -> AcceptTranscript {
         AcceptTranscript::Noop
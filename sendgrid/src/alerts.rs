@@ -30,9 +30,21 @@ impl Alerts {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_page(&self) -> ClientResult<Vec<crate::types::GetAlertsResponse>> {
+        Ok(self.get_page_with_response().await?.body)
+    }
+    /**
+     * Retrieve all alerts.
+     *
+     * This function performs a `GET` to the `/alerts` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAlertsResponse>>> {
         let url = self.client.url("/alerts", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,6 +80,66 @@ impl Alerts {
             )
             .await
     }
+    /**
+     * Retrieve all alerts.
+     *
+     * This function performs a `GET` to the `/alerts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GetAlertsResponse>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/alerts", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetAlertsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all alerts.
+     *
+     * This function performs a `GET` to the `/alerts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetAlertsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_pages_stream(None))
+    }
     /**
      * Create a new Alert.
      *
@@ -91,9 +163,22 @@ impl Alerts {
         &self,
         body: &crate::types::PostAlertsRequest,
     ) -> ClientResult<crate::types::PostAlertsResponse> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Create a new Alert.
+     *
+     * This function performs a `POST` to the `/alerts` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostAlertsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostAlertsResponse>> {
         let url = self.client.url("/alerts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -121,6 +206,19 @@ impl Alerts {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get(&self, alert_id: i64) -> ClientResult<crate::types::GetAlertsAlertResponse> {
+        Ok(self.get_with_response(alert_id).await?.body)
+    }
+    /**
+     * Retrieve a specific alert.
+     *
+     * This function performs a `GET` to the `/alerts/{alert_id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        alert_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAlertsAlertResponse>> {
         let url = self.client.url(
             &format!(
                 "/alerts/{}",
@@ -129,7 +227,7 @@ impl Alerts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -156,6 +254,19 @@ impl Alerts {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete(&self, alert_id: i64) -> ClientResult<crate::types::Help> {
+        Ok(self.delete_with_response(alert_id).await?.body)
+    }
+    /**
+     * Delete an alert.
+     *
+     * This function performs a `DELETE` to the `/alerts/{alert_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        alert_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/alerts/{}",
@@ -164,7 +275,7 @@ impl Alerts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -195,6 +306,20 @@ impl Alerts {
         alert_id: i64,
         body: &crate::types::PatchAlertsAlertRequest,
     ) -> ClientResult<crate::types::GetAlertsAlertResponse> {
+        Ok(self.patch_with_response(alert_id, body).await?.body)
+    }
+    /**
+     * Update an alert.
+     *
+     * This function performs a `PATCH` to the `/alerts/{alert_id}` endpoint.
+     *
+     * As opposed to `patch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_with_response(
+        &self,
+        alert_id: i64,
+        body: &crate::types::PatchAlertsAlertRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetAlertsAlertResponse>> {
         let url = self.client.url(
             &format!(
                 "/alerts/{}",
@@ -203,7 +328,7 @@ impl Alerts {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
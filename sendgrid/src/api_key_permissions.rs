@@ -31,9 +31,21 @@ impl ApiKeyPermissions {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_scopes(&self) -> ClientResult<crate::types::GetScopesResponse> {
+        Ok(self.get_scopes_with_response().await?.body)
+    }
+    /**
+     * Retrieve a list of scopes for which this user has access.
+     *
+     * This function performs a `GET` to the `/scopes` endpoint.
+     *
+     * As opposed to `get_scopes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_scopes_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetScopesResponse>> {
         let url = self.client.url("/scopes", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -30,6 +30,19 @@ impl ApiKeys {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get(&self, limit: i64) -> ClientResult<crate::types::GetApiKeysResponse> {
+        Ok(self.get_with_response(limit).await?.body)
+    }
+    /**
+     * Retrieve all API Keys belonging to the authenticated user.
+     *
+     * This function performs a `GET` to the `/api_keys` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetApiKeysResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -37,7 +50,7 @@ impl ApiKeys {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api_keys?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,9 +86,22 @@ impl ApiKeys {
         &self,
         body: &crate::types::CreateApiKeysRequest,
     ) -> ClientResult<crate::types::CreateApiKeysResponse> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Create API keys.
+     *
+     * This function performs a `POST` to the `/api_keys` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::CreateApiKeysRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateApiKeysResponse>> {
         let url = self.client.url("/api_keys", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -103,6 +129,19 @@ impl ApiKeys {
         &self,
         api_key_id: &str,
     ) -> ClientResult<crate::types::GetApiKeysKeyResponse> {
+        Ok(self.get_key_with_response(api_key_id).await?.body)
+    }
+    /**
+     * Retrieve an existing API Key.
+     *
+     * This function performs a `GET` to the `/api_keys/{api_key_id}` endpoint.
+     *
+     * As opposed to `get_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_key_with_response(
+        &self,
+        api_key_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetApiKeysKeyResponse>> {
         let url = self.client.url(
             &format!(
                 "/api_keys/{}",
@@ -111,7 +150,7 @@ impl ApiKeys {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -142,6 +181,20 @@ impl ApiKeys {
         api_key_id: &str,
         body: &crate::types::PutApiKeysKeyRequest,
     ) -> ClientResult<crate::types::ApiKeyNameScopesAllOf> {
+        Ok(self.put_key_with_response(api_key_id, body).await?.body)
+    }
+    /**
+     * Update API key name and scopes.
+     *
+     * This function performs a `PUT` to the `/api_keys/{api_key_id}` endpoint.
+     *
+     * As opposed to `put_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_key_with_response(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PutApiKeysKeyRequest,
+    ) -> ClientResult<crate::Response<crate::types::ApiKeyNameScopesAllOf>> {
         let url = self.client.url(
             &format!(
                 "/api_keys/{}",
@@ -150,7 +203,7 @@ impl ApiKeys {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -173,6 +226,20 @@ impl ApiKeys {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_key(&self, api_key_id: &str) -> ClientResult<()> {
+        self.delete_key_with_response(api_key_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete API keys.
+     *
+     * This function performs a `DELETE` to the `/api_keys/{api_key_id}` endpoint.
+     *
+     * As opposed to `delete_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_key_with_response(
+        &self,
+        api_key_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api_keys/{}",
@@ -181,7 +248,7 @@ impl ApiKeys {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -208,6 +275,20 @@ impl ApiKeys {
         api_key_id: &str,
         body: &crate::types::IpPool,
     ) -> ClientResult<crate::types::ApiKeyNameId> {
+        Ok(self.patch_key_with_response(api_key_id, body).await?.body)
+    }
+    /**
+     * Update API key name.
+     *
+     * This function performs a `PATCH` to the `/api_keys/{api_key_id}` endpoint.
+     *
+     * As opposed to `patch_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_key_with_response(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::ApiKeyNameId>> {
         let url = self.client.url(
             &format!(
                 "/api_keys/{}",
@@ -216,7 +297,7 @@ impl ApiKeys {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
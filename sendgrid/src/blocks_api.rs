@@ -33,6 +33,25 @@ impl BlocksApi {
         limit: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::BlocksResponse>> {
+        Ok(self
+            .get_suppression_blocks_with_response(start_time, end_time, limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all blocks.
+     *
+     * This function performs a `GET` to the `/suppression/blocks` endpoint.
+     *
+     * As opposed to `get_suppression_blocks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_blocks_with_response(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlocksResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -51,7 +70,7 @@ impl BlocksApi {
             .client
             .url(&format!("/suppression/blocks?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -99,6 +118,87 @@ impl BlocksApi {
             )
             .await
     }
+    /**
+     * Retrieve all blocks.
+     *
+     * This function performs a `GET` to the `/suppression/blocks` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_blocks_pages_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BlocksResponse>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/blocks?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BlocksResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all blocks.
+     *
+     * This function performs a `GET` to the `/suppression/blocks` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_blocks_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BlocksResponse>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_suppression_blocks_pages_stream(start_time, end_time, offset, None),
+        )
+    }
     /**
      * Delete blocks.
      *
@@ -119,9 +219,25 @@ impl BlocksApi {
         &self,
         body: &crate::types::DeleteSuppressionBlocksRequest,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_suppression_blocks_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete blocks.
+     *
+     * This function performs a `DELETE` to the `/suppression/blocks` endpoint.
+     *
+     * As opposed to `delete_suppression_blocks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_blocks_with_response(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url("/suppression/blocks", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -145,6 +261,22 @@ impl BlocksApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::BlocksResponse>> {
+        Ok(self
+            .get_suppression_blocks_email_with_response(email)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a specific block.
+     *
+     * This function performs a `GET` to the `/suppression/blocks/{email}` endpoint.
+     *
+     * As opposed to `get_suppression_blocks_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_blocks_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlocksResponse>>> {
         let url = self.client.url(
             &format!(
                 "/suppression/blocks/{}",
@@ -153,7 +285,7 @@ impl BlocksApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -192,6 +324,74 @@ impl BlocksApi {
             )
             .await
     }
+    /**
+     * Retrieve a specific block.
+     *
+     * This function performs a `GET` to the `/suppression/blocks/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks_email`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_blocks_email_pages_stream(
+        &self,
+        email: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BlocksResponse>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/suppression/blocks/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BlocksResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve a specific block.
+     *
+     * This function performs a `GET` to the `/suppression/blocks/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks_email`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_blocks_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BlocksResponse>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_suppression_blocks_email_pages_stream(email, None))
+    }
     /**
      * Delete a specific block.
      *
@@ -207,6 +407,22 @@ impl BlocksApi {
         &self,
         email: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_suppression_blocks_email_with_response(email)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a specific block.
+     *
+     * This function performs a `DELETE` to the `/suppression/blocks/{email}` endpoint.
+     *
+     * As opposed to `delete_suppression_blocks_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_blocks_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/suppression/blocks/{}",
@@ -215,7 +431,7 @@ impl BlocksApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -30,6 +30,23 @@ impl BouncesApi {
         start_time: i64,
         end_time: i64,
     ) -> ClientResult<Vec<crate::types::BounceResponse>> {
+        Ok(self
+            .get_suppression_bounces_with_response(start_time, end_time)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_suppression_bounces`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_bounces_with_response(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -42,7 +59,7 @@ impl BouncesApi {
             .client
             .url(&format!("/suppression/bounces?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -86,6 +103,82 @@ impl BouncesApi {
             )
             .await
     }
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_bounces_pages_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BounceResponse>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/bounces?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BounceResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_bounces_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BounceResponse>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_suppression_bounces_pages_stream(start_time, end_time, None),
+        )
+    }
     /**
      * Delete bounces.
      *
@@ -106,9 +199,23 @@ impl BouncesApi {
         &self,
         body: &crate::types::DeleteSuppressionBouncesRequest,
     ) -> ClientResult<()> {
+        self.delete_suppression_bounces_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Delete bounces.
+     *
+     * This function performs a `DELETE` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `delete_suppression_bounces`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_bounces_with_response(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/suppression/bounces", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -132,6 +239,22 @@ impl BouncesApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::BounceResponse>> {
+        Ok(self
+            .get_suppression_bounces_email_with_response(email)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a Bounce.
+     *
+     * This function performs a `GET` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `get_suppression_bounces_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_bounces_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BounceResponse>>> {
         let url = self.client.url(
             &format!(
                 "/suppression/bounces/{}",
@@ -140,7 +263,7 @@ impl BouncesApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -179,6 +302,74 @@ impl BouncesApi {
             )
             .await
     }
+    /**
+     * Retrieve a Bounce.
+     *
+     * This function performs a `GET` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces_email`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_bounces_email_pages_stream(
+        &self,
+        email: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BounceResponse>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/suppression/bounces/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::BounceResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve a Bounce.
+     *
+     * This function performs a `GET` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces_email`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_bounces_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BounceResponse>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_suppression_bounces_email_pages_stream(email, None))
+    }
     /**
      * Delete a bounce.
      *
@@ -197,6 +388,24 @@ impl BouncesApi {
         email_address: &str,
         body: &serde_json::Value,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_suppression_bounces_email_with_response(email, email_address, body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a bounce.
+     *
+     * This function performs a `DELETE` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `delete_suppression_bounces_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_bounces_email_with_response(
+        &self,
+        email: &str,
+        email_address: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email_address.is_empty() {
             query_args.push(("email_address".to_string(), email_address.to_string()));
@@ -211,7 +420,7 @@ impl BouncesApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
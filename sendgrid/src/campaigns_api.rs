@@ -33,6 +33,20 @@ impl CampaignsApi {
         limit: i64,
         offset: i64,
     ) -> ClientResult<crate::types::GetCampaignsResponse> {
+        Ok(self.get_campaigns_with_response(limit, offset).await?.body)
+    }
+    /**
+     * Retrieve all Campaigns.
+     *
+     * This function performs a `GET` to the `/campaigns` endpoint.
+     *
+     * As opposed to `get_campaigns`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_campaigns_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetCampaignsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -43,7 +57,7 @@ impl CampaignsApi {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/campaigns?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,9 +83,22 @@ impl CampaignsApi {
         &self,
         body: &crate::types::CampaignsRequest,
     ) -> ClientResult<crate::types::CampaignResponseAllOf> {
+        Ok(self.post_campaign_with_response(body).await?.body)
+    }
+    /**
+     * Create a Campaign.
+     *
+     * This function performs a `POST` to the `/campaigns` endpoint.
+     *
+     * As opposed to `post_campaign`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_campaign_with_response(
+        &self,
+        body: &crate::types::CampaignsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CampaignResponseAllOf>> {
         let url = self.client.url("/campaigns", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -95,6 +122,22 @@ impl CampaignsApi {
         &self,
         campaign_id: i64,
     ) -> ClientResult<crate::types::GetCampaignsCampaignResponse> {
+        Ok(self
+            .get_campaigns_campaign_with_response(campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a single campaign.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `get_campaigns_campaign`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_campaigns_campaign_with_response(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetCampaignsCampaignResponse>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}",
@@ -103,7 +146,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -124,6 +167,21 @@ impl CampaignsApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_campaigns_campaign(&self, campaign_id: i64) -> ClientResult<()> {
+        self.delete_campaigns_campaign_with_response(campaign_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a Campaign.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `delete_campaigns_campaign`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_campaigns_campaign_with_response(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}",
@@ -132,7 +190,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -159,6 +217,23 @@ impl CampaignsApi {
         campaign_id: i64,
         body: &crate::types::UpdateACampaignRequest,
     ) -> ClientResult<crate::types::CampaignResponseAllOf> {
+        Ok(self
+            .patch_campaigns_campaign_with_response(campaign_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a Campaign.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `patch_campaigns_campaign`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_campaigns_campaign_with_response(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateACampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::CampaignResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}",
@@ -167,7 +242,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -193,6 +268,22 @@ impl CampaignsApi {
         &self,
         campaign_id: i64,
     ) -> ClientResult<crate::types::SendACampaignResponse> {
+        Ok(self
+            .post_campaigns_campaign_schedules_now_with_response(campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * Send a Campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/schedules/now` endpoint.
+     *
+     * As opposed to `post_campaigns_campaign_schedules_now`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_campaigns_campaign_schedules_now_with_response(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::SendACampaignResponse>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/schedules/now",
@@ -201,7 +292,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -225,6 +316,22 @@ impl CampaignsApi {
         &self,
         campaign_id: i64,
     ) -> ClientResult<crate::types::ScheduleACampaignRequest> {
+        Ok(self
+            .get_campaigns_campaign_schedule_with_response(campaign_id)
+            .await?
+            .body)
+    }
+    /**
+     * View Scheduled Time of a Campaign.
+     *
+     * This function performs a `GET` to the `/campaigns/{campaign_id}/schedules` endpoint.
+     *
+     * As opposed to `get_campaigns_campaign_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_campaigns_campaign_schedule_with_response(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ScheduleACampaignRequest>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/schedules",
@@ -233,7 +340,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -260,6 +367,23 @@ impl CampaignsApi {
         campaign_id: i64,
         body: &crate::types::ScheduleACampaignRequest,
     ) -> ClientResult<crate::types::ScheduleACampaignResponse> {
+        Ok(self
+            .post_campaigns_campaign_schedule_with_response(campaign_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Schedule a Campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/schedules` endpoint.
+     *
+     * As opposed to `post_campaigns_campaign_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_campaigns_campaign_schedule_with_response(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::ScheduleACampaignResponse>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/schedules",
@@ -268,7 +392,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -292,6 +416,21 @@ impl CampaignsApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_campaigns_campaign_schedules(&self, campaign_id: i64) -> ClientResult<()> {
+        self.delete_campaigns_campaign_schedules_with_response(campaign_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unschedule a Scheduled Campaign.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}/schedules` endpoint.
+     *
+     * As opposed to `delete_campaigns_campaign_schedules`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_campaigns_campaign_schedules_with_response(
+        &self,
+        campaign_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/schedules",
@@ -300,7 +439,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -325,6 +464,23 @@ impl CampaignsApi {
         campaign_id: i64,
         body: &crate::types::ScheduleACampaignRequest,
     ) -> ClientResult<crate::types::UpdateAScheduledCampaignResponse> {
+        Ok(self
+            .patch_campaigns_campaign_schedules_with_response(campaign_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a Scheduled Campaign.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}/schedules` endpoint.
+     *
+     * As opposed to `patch_campaigns_campaign_schedules`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_campaigns_campaign_schedules_with_response(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::UpdateAScheduledCampaignResponse>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/schedules",
@@ -333,7 +489,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -360,6 +516,23 @@ impl CampaignsApi {
         campaign_id: i64,
         body: &crate::types::SendATestCampaignRequest,
     ) -> ClientResult<crate::types::SendATestCampaignRequest> {
+        Ok(self
+            .post_campaigns_campaign_schedules_test_with_response(campaign_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Send a Test Campaign.
+     *
+     * This function performs a `POST` to the `/campaigns/{campaign_id}/schedules/test` endpoint.
+     *
+     * As opposed to `post_campaigns_campaign_schedules_test`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_campaigns_campaign_schedules_test_with_response(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::SendATestCampaignRequest,
+    ) -> ClientResult<crate::Response<crate::types::SendATestCampaignRequest>> {
         let url = self.client.url(
             &format!(
                 "/campaigns/{}/schedules/test",
@@ -368,7 +541,7 @@ impl CampaignsApi {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
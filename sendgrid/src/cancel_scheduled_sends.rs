@@ -27,9 +27,21 @@ impl CancelScheduledSends {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn post_mail_batch(&self) -> ClientResult<crate::types::MailBatchId> {
+        Ok(self.post_mail_batch_with_response().await?.body)
+    }
+    /**
+     * Create a batch ID.
+     *
+     * This function performs a `POST` to the `/mail/batch` endpoint.
+     *
+     * As opposed to `post_mail_batch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_mail_batch_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailBatchId>> {
         let url = self.client.url("/mail/batch", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -54,9 +66,21 @@ impl CancelScheduledSends {
     pub async fn get_user_scheduled_sends(
         &self,
     ) -> ClientResult<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        Ok(self.get_user_scheduled_sends_with_response().await?.body)
+    }
+    /**
+     * Retrieve all scheduled sends.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `get_user_scheduled_sends`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_scheduled_sends_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserScheduledSendStatusAllOf>>> {
         let url = self.client.url("/user/scheduled_sends", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -90,6 +114,67 @@ impl CancelScheduledSends {
             )
             .await
     }
+    /**
+     * Retrieve all scheduled sends.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_user_scheduled_sends_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::UserScheduledSendStatusAllOf>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/user/scheduled_sends", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UserScheduledSendStatusAllOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all scheduled sends.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_user_scheduled_sends_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserScheduledSendStatusAllOf>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_user_scheduled_sends_pages_stream(None))
+    }
     /**
      * Cancel or pause a scheduled send.
      *
@@ -111,9 +196,25 @@ impl CancelScheduledSends {
         &self,
         body: &crate::types::CancelPauseAScheduledSendRequest,
     ) -> ClientResult<crate::types::UserScheduledSendStatusAllOf> {
+        Ok(self
+            .post_user_scheduled_send_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Cancel or pause a scheduled send.
+     *
+     * This function performs a `POST` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `post_user_scheduled_send`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_user_scheduled_send_with_response(
+        &self,
+        body: &crate::types::CancelPauseAScheduledSendRequest,
+    ) -> ClientResult<crate::Response<crate::types::UserScheduledSendStatusAllOf>> {
         let url = self.client.url("/user/scheduled_sends", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -140,6 +241,19 @@ impl CancelScheduledSends {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_mail_batch(&self, batch_id: &str) -> ClientResult<crate::types::MailBatchId> {
+        Ok(self.get_mail_batch_with_response(batch_id).await?.body)
+    }
+    /**
+     * Validate batch ID.
+     *
+     * This function performs a `GET` to the `/mail/batch/{batch_id}` endpoint.
+     *
+     * As opposed to `get_mail_batch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_batch_with_response(
+        &self,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MailBatchId>> {
         let url = self.client.url(
             &format!(
                 "/mail/batch/{}",
@@ -148,7 +262,7 @@ impl CancelScheduledSends {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -172,6 +286,22 @@ impl CancelScheduledSends {
         &self,
         batch_id: &str,
     ) -> ClientResult<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        Ok(self
+            .get_user_scheduled_sends_batch_with_response(batch_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve scheduled send.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `get_user_scheduled_sends_batch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_scheduled_sends_batch_with_response(
+        &self,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserScheduledSendStatusAllOf>>> {
         let url = self.client.url(
             &format!(
                 "/user/scheduled_sends/{}",
@@ -180,7 +310,7 @@ impl CancelScheduledSends {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -219,6 +349,78 @@ impl CancelScheduledSends {
             )
             .await
     }
+    /**
+     * Retrieve scheduled send.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends_batch`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_user_scheduled_sends_batch_pages_stream(
+        &self,
+        batch_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::UserScheduledSendStatusAllOf>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/user/scheduled_sends/{}",
+                crate::progenitor_support::encode_path(batch_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UserScheduledSendStatusAllOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve scheduled send.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends_batch`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_user_scheduled_sends_batch_stream(
+        &self,
+        batch_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserScheduledSendStatusAllOf>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_user_scheduled_sends_batch_pages_stream(batch_id, None),
+        )
+    }
     /**
      * Delete a cancellation or pause from a scheduled send.
      *
@@ -233,6 +435,21 @@ impl CancelScheduledSends {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_user_scheduled_sends_batch(&self, batch_id: &str) -> ClientResult<()> {
+        self.delete_user_scheduled_sends_batch_with_response(batch_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a cancellation or pause from a scheduled send.
+     *
+     * This function performs a `DELETE` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `delete_user_scheduled_sends_batch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_user_scheduled_sends_batch_with_response(
+        &self,
+        batch_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/scheduled_sends/{}",
@@ -241,7 +458,7 @@ impl CancelScheduledSends {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -268,6 +485,22 @@ impl CancelScheduledSends {
         batch_id: &str,
         body: &crate::types::UserScheduledSendStatus,
     ) -> ClientResult<()> {
+        self.patch_user_scheduled_sends_batch_with_response(batch_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a scheduled send.
+     *
+     * This function performs a `PATCH` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `patch_user_scheduled_sends_batch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_user_scheduled_sends_batch_with_response(
+        &self,
+        batch_id: &str,
+        body: &crate::types::UserScheduledSendStatus,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/user/scheduled_sends/{}",
@@ -276,7 +509,7 @@ impl CancelScheduledSends {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
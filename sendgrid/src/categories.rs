@@ -31,6 +31,24 @@ impl Categories {
         category: &str,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::GetCategoriesResponse>> {
+        Ok(self
+            .get_page_with_response(limit, category, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        limit: i64,
+        category: &str,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCategoriesResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !category.is_empty() {
             query_args.push(("category".to_string(), category.to_string()));
@@ -44,7 +62,7 @@ impl Categories {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/categories?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -86,6 +104,80 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        category: &str,
+        offset: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetCategoriesResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !category.is_empty() {
+            query_args.push(("category".to_string(), category.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/categories?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetCategoriesResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        category: &str,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetCategoriesResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_pages_stream(category, offset, None))
+    }
     /**
      * Retrieve sums of email stats for each category [Needs: Stats object defined, has category ID?].
      *
@@ -116,6 +208,36 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> ClientResult<crate::types::CategoryStats> {
+        Ok(self
+            .get_stats_sum_with_response(
+                sort_by_metric,
+                sort_by_direction,
+                start_date,
+                end_date,
+                limit,
+                offset,
+                aggregated_by,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve sums of email stats for each category [Needs: Stats object defined, has category ID?].
+     *
+     * This function performs a `GET` to the `/categories/stats/sums` endpoint.
+     *
+     * As opposed to `get_stats_sum`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stats_sum_with_response(
+        &self,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -146,7 +268,7 @@ impl Categories {
             .client
             .url(&format!("/categories/stats/sums?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -183,6 +305,34 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> ClientResult<Vec<crate::types::CategoryStats>> {
+        Ok(self
+            .get_stats_with_response(
+                start_date,
+                end_date,
+                categories,
+                limit,
+                offset,
+                aggregated_by,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_stats_with_response(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CategoryStats>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -207,7 +357,7 @@ impl Categories {
             .client
             .url(&format!("/categories/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -265,4 +415,100 @@ impl Categories {
             )
             .await
     }
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_all_stats`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_stats_pages_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CategoryStats>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !categories.is_empty() {
+            query_args.push(("categories".to_string(), categories.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/categories/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::CategoryStats>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_all_stats`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stats_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CategoryStats>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_stats_pages_stream(
+            start_date,
+            end_date,
+            categories,
+            offset,
+            aggregated_by,
+            None,
+        ))
+    }
 }
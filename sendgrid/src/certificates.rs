@@ -22,9 +22,22 @@ impl Certificates {
         &self,
         body: &crate::types::PostSsoCertificatesRequest,
     ) -> ClientResult<crate::types::SsoCertificateBody> {
+        Ok(self.post_sso_with_response(body).await?.body)
+    }
+    /**
+     * Create an SSO Certificate.
+     *
+     * This function performs a `POST` to the `/sso/certificates` endpoint.
+     *
+     * As opposed to `post_sso`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sso_with_response(
+        &self,
+        body: &crate::types::PostSsoCertificatesRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoCertificateBody>> {
         let url = self.client.url("/sso/certificates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -46,6 +59,22 @@ impl Certificates {
         &self,
         integration_id: &str,
     ) -> ClientResult<Vec<crate::types::SsoCertificateBody>> {
+        Ok(self
+            .get_sso_integrations_integration_with_response(integration_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get All SSO Certificates by Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{integration_id}/certificates` endpoint.
+     *
+     * As opposed to `get_sso_integrations_integration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sso_integrations_integration_with_response(
+        &self,
+        integration_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoCertificateBody>>> {
         let url = self.client.url(
             &format!(
                 "/sso/integrations/{}/certificates",
@@ -54,7 +83,7 @@ impl Certificates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -95,6 +124,78 @@ impl Certificates {
             )
             .await
     }
+    /**
+     * Get All SSO Certificates by Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{integration_id}/certificates` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations_integration`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_sso_integrations_integration_pages_stream(
+        &self,
+        integration_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::SsoCertificateBody>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/sso/integrations/{}/certificates",
+                crate::progenitor_support::encode_path(integration_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SsoCertificateBody>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get All SSO Certificates by Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{integration_id}/certificates` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations_integration`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_sso_integrations_integration_stream(
+        &self,
+        integration_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SsoCertificateBody>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_sso_integrations_integration_pages_stream(integration_id, None),
+        )
+    }
     /**
      * Get an SSO Certificate.
      *
@@ -106,6 +207,19 @@ impl Certificates {
         &self,
         cert_id: &str,
     ) -> ClientResult<crate::types::SsoCertificateBody> {
+        Ok(self.get_sso_cert_with_response(cert_id).await?.body)
+    }
+    /**
+     * Get an SSO Certificate.
+     *
+     * This function performs a `GET` to the `/sso/certificates/{cert_id}` endpoint.
+     *
+     * As opposed to `get_sso_cert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sso_cert_with_response(
+        &self,
+        cert_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SsoCertificateBody>> {
         let url = self.client.url(
             &format!(
                 "/sso/certificates/{}",
@@ -114,7 +228,7 @@ impl Certificates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -136,6 +250,19 @@ impl Certificates {
         &self,
         cert_id: &str,
     ) -> ClientResult<crate::types::SsoCertificateBody> {
+        Ok(self.delete_sso_cert_with_response(cert_id).await?.body)
+    }
+    /**
+     * Delete an SSO Certificate.
+     *
+     * This function performs a `DELETE` to the `/sso/certificates/{cert_id}` endpoint.
+     *
+     * As opposed to `delete_sso_cert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_sso_cert_with_response(
+        &self,
+        cert_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SsoCertificateBody>> {
         let url = self.client.url(
             &format!(
                 "/sso/certificates/{}",
@@ -144,7 +271,7 @@ impl Certificates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -167,6 +294,20 @@ impl Certificates {
         cert_id: &str,
         body: &crate::types::PatchSsoCertificatesCertRequest,
     ) -> ClientResult<Vec<crate::types::SsoErrorResponse>> {
+        Ok(self.patch_sso_cert_with_response(cert_id, body).await?.body)
+    }
+    /**
+     * Update SSO Certificate.
+     *
+     * This function performs a `PATCH` to the `/sso/certificates/{cert_id}` endpoint.
+     *
+     * As opposed to `patch_sso_cert`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_sso_cert_with_response(
+        &self,
+        cert_id: &str,
+        body: &crate::types::PatchSsoCertificatesCertRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoErrorResponse>>> {
         let url = self.client.url(
             &format!(
                 "/sso/certificates/{}",
@@ -175,7 +316,7 @@ impl Certificates {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
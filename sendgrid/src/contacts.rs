@@ -25,9 +25,21 @@ impl Contacts {
      * Please note that pagination of the contacts has been deprecated.
      */
     pub async fn get_mc_contats(&self) -> ClientResult<crate::types::GetMcContatsResponse> {
+        Ok(self.get_mc_contats_with_response().await?.body)
+    }
+    /**
+     * Get Sample Contacts.
+     *
+     * This function performs a `GET` to the `/marketing/contacts` endpoint.
+     *
+     * As opposed to `get_mc_contats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_contats_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMcContatsResponse>> {
         let url = self.client.url("/marketing/contacts", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,9 +69,22 @@ impl Contacts {
         &self,
         body: &crate::types::PutMcContactsRequest,
     ) -> ClientResult<crate::types::PutMcContactsResponse> {
+        Ok(self.put_mc_with_response(body).await?.body)
+    }
+    /**
+     * Add or Update a Contact.
+     *
+     * This function performs a `PUT` to the `/marketing/contacts` endpoint.
+     *
+     * As opposed to `put_mc`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_mc_with_response(
+        &self,
+        body: &crate::types::PutMcContactsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutMcContactsResponse>> {
         let url = self.client.url("/marketing/contacts", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -93,6 +118,23 @@ impl Contacts {
         delete_all_contacts: &str,
         ids: &str,
     ) -> ClientResult<crate::types::DeleteMcContactsResponse> {
+        Ok(self
+            .delete_mc_with_response(delete_all_contacts, ids)
+            .await?
+            .body)
+    }
+    /**
+     * Delete Contacts.
+     *
+     * This function performs a `DELETE` to the `/marketing/contacts` endpoint.
+     *
+     * As opposed to `delete_mc`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_mc_with_response(
+        &self,
+        delete_all_contacts: &str,
+        ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteMcContactsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !delete_all_contacts.is_empty() {
             query_args.push((
@@ -108,7 +150,7 @@ impl Contacts {
             .client
             .url(&format!("/marketing/contacts?{}", query_), None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -125,9 +167,21 @@ impl Contacts {
      * **This endpoint returns the total number of contacts you have stored.**
      */
     pub async fn get_mc_count(&self) -> ClientResult<crate::types::GetMcContactsCountResponse> {
+        Ok(self.get_mc_count_with_response().await?.body)
+    }
+    /**
+     * Get Total Contact Count.
+     *
+     * This function performs a `GET` to the `/marketing/contacts/count` endpoint.
+     *
+     * As opposed to `get_mc_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_count_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMcContactsCountResponse>> {
         let url = self.client.url("/marketing/contacts/count", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -152,9 +206,21 @@ impl Contacts {
     pub async fn get_marketing_exports(
         &self,
     ) -> ClientResult<crate::types::GetMarketingContactsExportsResponse> {
+        Ok(self.get_marketing_exports_with_response().await?.body)
+    }
+    /**
+     * Get All Existing Exports.
+     *
+     * This function performs a `GET` to the `/marketing/contacts/exports` endpoint.
+     *
+     * As opposed to `get_marketing_exports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_marketing_exports_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingContactsExportsResponse>> {
         let url = self.client.url("/marketing/contacts/exports", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -184,9 +250,22 @@ impl Contacts {
         &self,
         body: &crate::types::PostMcContactsExportsRequest,
     ) -> ClientResult<crate::types::PostMcContactsExportsResponse> {
+        Ok(self.post_mc_export_with_response(body).await?.body)
+    }
+    /**
+     * Export Contacts.
+     *
+     * This function performs a `POST` to the `/marketing/contacts/exports` endpoint.
+     *
+     * As opposed to `post_mc_export`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_mc_export_with_response(
+        &self,
+        body: &crate::types::PostMcContactsExportsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMcContactsExportsResponse>> {
         let url = self.client.url("/marketing/contacts/exports", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -205,6 +284,19 @@ impl Contacts {
      * The "Get Contacts by Emails" endpoint can be used to get the ID of a contact.
      */
     pub async fn get_mc(&self, id: &str) -> ClientResult<crate::types::ContactDetails3> {
+        Ok(self.get_mc_with_response(id).await?.body)
+    }
+    /**
+     * Get a Contact by ID.
+     *
+     * This function performs a `GET` to the `/marketing/contacts/{id}` endpoint.
+     *
+     * As opposed to `get_mc`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactDetails3>> {
         let url = self.client.url(
             &format!(
                 "/marketing/contacts/{}",
@@ -213,7 +305,7 @@ impl Contacts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -243,9 +335,22 @@ impl Contacts {
         &self,
         body: &crate::types::PostMcContactsSearchRequest,
     ) -> ClientResult<crate::types::PostMcContactsSearchResponse> {
+        Ok(self.post_mc_search_with_response(body).await?.body)
+    }
+    /**
+     * Search Contacts.
+     *
+     * This function performs a `POST` to the `/marketing/contacts/search` endpoint.
+     *
+     * As opposed to `post_mc_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_mc_search_with_response(
+        &self,
+        body: &crate::types::PostMcContactsSearchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMcContactsSearchResponse>> {
         let url = self.client.url("/marketing/contacts/search", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -281,9 +386,22 @@ impl Contacts {
         &self,
         body: &crate::types::PutMcContactsImportsRequest,
     ) -> ClientResult<crate::types::PutMcContactsImportsResponse> {
+        Ok(self.put_mc_imports_with_response(body).await?.body)
+    }
+    /**
+     * Import Contacts.
+     *
+     * This function performs a `PUT` to the `/marketing/contacts/imports` endpoint.
+     *
+     * As opposed to `put_mc_imports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_mc_imports_with_response(
+        &self,
+        body: &crate::types::PutMcContactsImportsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutMcContactsImportsResponse>> {
         let url = self.client.url("/marketing/contacts/imports", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -313,6 +431,19 @@ impl Contacts {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::ContactImport> {
+        Ok(self.get_marketing_import_with_response(id).await?.body)
+    }
+    /**
+     * Import Contacts Status.
+     *
+     * This function performs a `GET` to the `/marketing/contacts/imports/{id}` endpoint.
+     *
+     * As opposed to `get_marketing_import`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_marketing_import_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactImport>> {
         let url = self.client.url(
             &format!(
                 "/marketing/contacts/imports/{}",
@@ -321,7 +452,7 @@ impl Contacts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -342,6 +473,19 @@ impl Contacts {
      * If you would like to download a list, take the `id` that is returned from the "Export Contacts" endpoint and make an API request here to get the `urls`. Once you have the list of URLs, make a `GET` request on each URL to download your CSV file(s).
      */
     pub async fn get_mc_export(&self, id: &str) -> ClientResult<crate::types::ContactExport> {
+        Ok(self.get_mc_export_with_response(id).await?.body)
+    }
+    /**
+     * Export Contacts Status.
+     *
+     * This function performs a `GET` to the `/marketing/contacts/exports/{id}` endpoint.
+     *
+     * As opposed to `get_mc_export`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_export_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactExport>> {
         let url = self.client.url(
             &format!(
                 "/marketing/contacts/exports/{}",
@@ -350,7 +494,7 @@ impl Contacts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -374,9 +518,22 @@ impl Contacts {
         &self,
         body: &crate::types::PostMarketingContactsBatchRequest,
     ) -> ClientResult<crate::types::PostMarketingContactsBatchResponse> {
+        Ok(self.post_marketing_batch_with_response(body).await?.body)
+    }
+    /**
+     * Get Batched Contacts by IDs.
+     *
+     * This function performs a `POST` to the `/marketing/contacts/batch` endpoint.
+     *
+     * As opposed to `post_marketing_batch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_batch_with_response(
+        &self,
+        body: &crate::types::PostMarketingContactsBatchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMarketingContactsBatchResponse>> {
         let url = self.client.url("/marketing/contacts/batch", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -410,9 +567,26 @@ impl Contacts {
         &self,
         body: &crate::types::PostMarketingContactsSearchEmailsRequest,
     ) -> ClientResult<crate::types::PostMarketingContactsSearchEmailsResponse> {
+        Ok(self
+            .post_marketing_search_email_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Get Contacts by Emails.
+     *
+     * This function performs a `POST` to the `/marketing/contacts/search/emails` endpoint.
+     *
+     * As opposed to `post_marketing_search_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_search_email_with_response(
+        &self,
+        body: &crate::types::PostMarketingContactsSearchEmailsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMarketingContactsSearchEmailsResponse>>
+    {
         let url = self.client.url("/marketing/contacts/search/emails", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
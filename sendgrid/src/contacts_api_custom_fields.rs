@@ -25,9 +25,21 @@ impl ContactsApiCustomFields {
     pub async fn get_contactdb_custom_fields(
         &self,
     ) -> ClientResult<crate::types::ListAllCustomFieldsResponse> {
+        Ok(self.get_contactdb_custom_fields_with_response().await?.body)
+    }
+    /**
+     * Retrieve all custom fields.
+     *
+     * This function performs a `GET` to the `/contactdb/custom_fields` endpoint.
+     *
+     * As opposed to `get_contactdb_custom_fields`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_custom_fields_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListAllCustomFieldsResponse>> {
         let url = self.client.url("/contactdb/custom_fields", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -53,9 +65,25 @@ impl ContactsApiCustomFields {
         &self,
         body: &crate::types::PostContactdbCustomFieldsRequest,
     ) -> ClientResult<crate::types::ContactdbCustomFieldWithAllOf> {
+        Ok(self
+            .post_contactdb_custom_field_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a Custom Field.
+     *
+     * This function performs a `POST` to the `/contactdb/custom_fields` endpoint.
+     *
+     * As opposed to `post_contactdb_custom_field`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_contactdb_custom_field_with_response(
+        &self,
+        body: &crate::types::PostContactdbCustomFieldsRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbCustomFieldWithAllOf>> {
         let url = self.client.url("/contactdb/custom_fields", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -79,6 +107,22 @@ impl ContactsApiCustomFields {
         &self,
         custom_field_id: i64,
     ) -> ClientResult<crate::types::ContactdbCustomFieldWithAllOf> {
+        Ok(self
+            .get_contactdb_custom_fields_field_with_response(custom_field_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a Custom Field.
+     *
+     * This function performs a `GET` to the `/contactdb/custom_fields/{custom_field_id}` endpoint.
+     *
+     * As opposed to `get_contactdb_custom_fields_field`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_custom_fields_field_with_response(
+        &self,
+        custom_field_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbCustomFieldWithAllOf>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/custom_fields/{}",
@@ -87,7 +131,7 @@ impl ContactsApiCustomFields {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -111,6 +155,22 @@ impl ContactsApiCustomFields {
         &self,
         custom_field_id: i64,
     ) -> ClientResult<crate::types::GlobalErrorResponseSchema> {
+        Ok(self
+            .delete_contactdb_custom_fields_field_with_response(custom_field_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a Custom Field.
+     *
+     * This function performs a `DELETE` to the `/contactdb/custom_fields/{custom_field_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_custom_fields_field`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contactdb_custom_fields_field_with_response(
+        &self,
+        custom_field_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GlobalErrorResponseSchema>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/custom_fields/{}",
@@ -119,7 +179,7 @@ impl ContactsApiCustomFields {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -142,9 +202,24 @@ impl ContactsApiCustomFields {
     pub async fn get_contactdb_reserved_fields(
         &self,
     ) -> ClientResult<crate::types::GetContactdbReservedFieldsResponse> {
+        Ok(self
+            .get_contactdb_reserved_fields_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve reserved fields.
+     *
+     * This function performs a `GET` to the `/contactdb/reserved_fields` endpoint.
+     *
+     * As opposed to `get_contactdb_reserved_fields`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_reserved_fields_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbReservedFieldsResponse>> {
         let url = self.client.url("/contactdb/reserved_fields", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
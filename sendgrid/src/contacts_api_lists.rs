@@ -23,9 +23,21 @@ impl ContactsApiLists {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_contactdb_lists(&self) -> ClientResult<crate::types::ListAllListsResponse> {
+        Ok(self.get_contactdb_lists_with_response().await?.body)
+    }
+    /**
+     * Retrieve all lists.
+     *
+     * This function performs a `GET` to the `/contactdb/lists` endpoint.
+     *
+     * As opposed to `get_contactdb_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_lists_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListAllListsResponse>> {
         let url = self.client.url("/contactdb/lists", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,9 +61,22 @@ impl ContactsApiLists {
         &self,
         body: &crate::types::IpPool,
     ) -> ClientResult<crate::types::ContactdbList> {
+        Ok(self.post_contactdb_list_with_response(body).await?.body)
+    }
+    /**
+     * Create a List.
+     *
+     * This function performs a `POST` to the `/contactdb/lists` endpoint.
+     *
+     * As opposed to `post_contactdb_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_contactdb_list_with_response(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbList>> {
         let url = self.client.url("/contactdb/lists", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -72,9 +97,23 @@ impl ContactsApiLists {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_contactdb_lists(&self, body: &[i64]) -> ClientResult<()> {
+        self.delete_contactdb_lists_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Delete Multiple lists.
+     *
+     * This function performs a `DELETE` to the `/contactdb/lists` endpoint.
+     *
+     * As opposed to `delete_contactdb_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contactdb_lists_with_response(
+        &self,
+        body: &[i64],
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/contactdb/lists", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -99,6 +138,22 @@ impl ContactsApiLists {
         &self,
         list_id: &str,
     ) -> ClientResult<crate::types::ContactdbList> {
+        Ok(self
+            .get_contactdb_lists_list_with_response(list_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a single list.
+     *
+     * This function performs a `GET` to the `/contactdb/lists/{list_id}` endpoint.
+     *
+     * As opposed to `get_contactdb_lists_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_lists_list_with_response(
+        &self,
+        list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbList>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/lists/{}",
@@ -107,7 +162,7 @@ impl ContactsApiLists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -134,6 +189,23 @@ impl ContactsApiLists {
         delete_contacts: bool,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.delete_contactdb_lists_list_with_response(list_id, delete_contacts, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a List.
+     *
+     * This function performs a `DELETE` to the `/contactdb/lists/{list_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_lists_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contactdb_lists_list_with_response(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if delete_contacts {
             query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
@@ -148,7 +220,7 @@ impl ContactsApiLists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -174,6 +246,23 @@ impl ContactsApiLists {
         list_id: &str,
         body: &crate::types::IpPool,
     ) -> ClientResult<crate::types::PatchContactdbListsListResponse> {
+        Ok(self
+            .patch_contactdb_lists_list_with_response(list_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a List.
+     *
+     * This function performs a `PATCH` to the `/contactdb/lists/{list_id}` endpoint.
+     *
+     * As opposed to `patch_contactdb_lists_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_contactdb_lists_list_with_response(
+        &self,
+        list_id: &str,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::PatchContactdbListsListResponse>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/lists/{}",
@@ -182,7 +271,7 @@ impl ContactsApiLists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -211,6 +300,24 @@ impl ContactsApiLists {
         page: i64,
         page_size: i64,
     ) -> ClientResult<crate::types::GetContactdbRecipientsSearchResponse> {
+        Ok(self
+            .get_contactdb_lists_list_recipients_with_response(list_id, page, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all recipients on a List.
+     *
+     * This function performs a `GET` to the `/contactdb/lists/{list_id}/recipients` endpoint.
+     *
+     * As opposed to `get_contactdb_lists_list_recipients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_lists_list_recipients_with_response(
+        &self,
+        list_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -228,7 +335,7 @@ impl ContactsApiLists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -255,6 +362,22 @@ impl ContactsApiLists {
         list_id: i64,
         body: &[i64],
     ) -> ClientResult<()> {
+        self.post_contactdb_lists_list_recipient_with_response(list_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add Multiple Recipients to a List.
+     *
+     * This function performs a `POST` to the `/contactdb/lists/{list_id}/recipients` endpoint.
+     *
+     * As opposed to `post_contactdb_lists_list_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_contactdb_lists_list_recipient_with_response(
+        &self,
+        list_id: i64,
+        body: &[i64],
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/lists/{}/recipients",
@@ -263,7 +386,7 @@ impl ContactsApiLists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -288,6 +411,22 @@ impl ContactsApiLists {
         list_id: i64,
         recipient_id: &str,
     ) -> ClientResult<()> {
+        self.post_contactdb_lists_list_recipients_recipient_with_response(list_id, recipient_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add a Single Recipient to a List.
+     *
+     * This function performs a `POST` to the `/contactdb/lists/{list_id}/recipients/{recipient_id}` endpoint.
+     *
+     * As opposed to `post_contactdb_lists_list_recipients_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_contactdb_lists_list_recipients_recipient_with_response(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/lists/{}/recipients/{}",
@@ -297,7 +436,7 @@ impl ContactsApiLists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -325,6 +464,27 @@ impl ContactsApiLists {
         recipient_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.delete_contactdb_lists_list_recipients_recipient_with_response(
+            list_id,
+            recipient_id,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete a Single Recipient from a Single List.
+     *
+     * This function performs a `DELETE` to the `/contactdb/lists/{list_id}/recipients/{recipient_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_lists_list_recipients_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contactdb_lists_list_recipients_recipient_with_response(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/lists/{}/recipients/{}",
@@ -334,7 +494,7 @@ impl ContactsApiLists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
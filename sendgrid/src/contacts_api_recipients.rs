@@ -32,6 +32,23 @@ impl ContactsApiRecipients {
         page: i64,
         page_size: i64,
     ) -> ClientResult<crate::types::ListRecipientsResponse> {
+        Ok(self
+            .get_contactdb_recipients_with_response(page, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve recipients.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_recipients_with_response(
+        &self,
+        page: i64,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<crate::types::ListRecipientsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -44,7 +61,7 @@ impl ContactsApiRecipients {
             .client
             .url(&format!("/contactdb/recipients?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,9 +89,25 @@ impl ContactsApiRecipients {
         &self,
         body: &[crate::types::PostContactdbRecipientsRequest],
     ) -> ClientResult<crate::types::ContactDbRecipientResponse> {
+        Ok(self
+            .post_contactdb_recipient_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Add recipients.
+     *
+     * This function performs a `POST` to the `/contactdb/recipients` endpoint.
+     *
+     * As opposed to `post_contactdb_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_contactdb_recipient_with_response(
+        &self,
+        body: &[crate::types::PostContactdbRecipientsRequest],
+    ) -> ClientResult<crate::Response<crate::types::ContactDbRecipientResponse>> {
         let url = self.client.url("/contactdb/recipients", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -100,9 +133,25 @@ impl ContactsApiRecipients {
         &self,
         body: &[String],
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_contactdb_recipients_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete Recipients.
+     *
+     * This function performs a `DELETE` to the `/contactdb/recipients` endpoint.
+     *
+     * As opposed to `delete_contactdb_recipients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contactdb_recipients_with_response(
+        &self,
+        body: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url("/contactdb/recipients", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -130,9 +179,25 @@ impl ContactsApiRecipients {
         &self,
         body: &[crate::types::PatchContactdbRecipientsRequest],
     ) -> ClientResult<crate::types::ContactDbRecipientResponse> {
+        Ok(self
+            .patch_contactdb_recipients_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Recipient.
+     *
+     * This function performs a `PATCH` to the `/contactdb/recipients` endpoint.
+     *
+     * As opposed to `patch_contactdb_recipients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_contactdb_recipients_with_response(
+        &self,
+        body: &[crate::types::PatchContactdbRecipientsRequest],
+    ) -> ClientResult<crate::Response<crate::types::ContactDbRecipientResponse>> {
         let url = self.client.url("/contactdb/recipients", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -155,9 +220,21 @@ impl ContactsApiRecipients {
     pub async fn get_contactdb_statu(
         &self,
     ) -> ClientResult<crate::types::GetContactdbStatusResponseData> {
+        Ok(self.get_contactdb_statu_with_response().await?.body)
+    }
+    /**
+     * Get Recipient Upload Status.
+     *
+     * This function performs a `GET` to the `/contactdb/status` endpoint.
+     *
+     * As opposed to `get_contactdb_statu`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_statu_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbStatusResponseData>> {
         let url = self.client.url("/contactdb/status", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -181,6 +258,22 @@ impl ContactsApiRecipients {
         &self,
         recipient_id: &str,
     ) -> ClientResult<crate::types::ContactdbRecipient> {
+        Ok(self
+            .get_contactdb_recipients_recipient_with_response(recipient_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a single recipient.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients/{recipient_id}` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_recipients_recipient_with_response(
+        &self,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbRecipient>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/recipients/{}",
@@ -189,7 +282,7 @@ impl ContactsApiRecipients {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +308,22 @@ impl ContactsApiRecipients {
         &self,
         recipient_id: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_contactdb_recipients_recipient_with_response(recipient_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a Recipient.
+     *
+     * This function performs a `DELETE` to the `/contactdb/recipients/{recipient_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_recipients_recipient`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contactdb_recipients_recipient_with_response(
+        &self,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/recipients/{}",
@@ -223,7 +332,7 @@ impl ContactsApiRecipients {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -249,6 +358,23 @@ impl ContactsApiRecipients {
         &self,
         recipient_id: &str,
     ) -> ClientResult<crate::types::GetContactdbRecipientsRecipientListsResponse> {
+        Ok(self
+            .get_contactdb_recipients_recipient_lists_with_response(recipient_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve the lists that a recipient is on.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients/{recipient_id}/lists` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients_recipient_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_recipients_recipient_lists_with_response(
+        &self,
+        recipient_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsRecipientListsResponse>>
+    {
         let url = self.client.url(
             &format!(
                 "/contactdb/recipients/{}/lists",
@@ -257,7 +383,7 @@ impl ContactsApiRecipients {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -282,11 +408,26 @@ impl ContactsApiRecipients {
     pub async fn get_contactdb_recipients_billable_count(
         &self,
     ) -> ClientResult<crate::types::ContactdbRecipientCount> {
+        Ok(self
+            .get_contactdb_recipients_billable_count_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve the count of billable recipients.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients/billable_count` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients_billable_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_recipients_billable_count_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbRecipientCount>> {
         let url = self
             .client
             .url("/contactdb/recipients/billable_count", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -309,9 +450,24 @@ impl ContactsApiRecipients {
     pub async fn get_contactdb_recipients_count(
         &self,
     ) -> ClientResult<crate::types::ContactdbRecipientCount> {
+        Ok(self
+            .get_contactdb_recipients_count_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a Count of Recipients.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients/count` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_recipients_count_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbRecipientCount>> {
         let url = self.client.url("/contactdb/recipients/count", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -345,6 +501,22 @@ impl ContactsApiRecipients {
         &self,
         field_name: &str,
     ) -> ClientResult<crate::types::GetContactdbRecipientsSearchResponse> {
+        Ok(self
+            .get_contactdb_recipients_search_with_response(field_name)
+            .await?
+            .body)
+    }
+    /**
+     * Search recipients.
+     *
+     * This function performs a `GET` to the `/contactdb/recipients/search` endpoint.
+     *
+     * As opposed to `get_contactdb_recipients_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_recipients_search_with_response(
+        &self,
+        field_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetContactdbRecipientsSearchResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !field_name.is_empty() {
             query_args.push(("{field_name}".to_string(), field_name.to_string()));
@@ -354,7 +526,7 @@ impl ContactsApiRecipients {
             .client
             .url(&format!("/contactdb/recipients/search?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -416,9 +588,26 @@ impl ContactsApiRecipients {
         &self,
         body: &crate::types::PostContactdbRecipientsSearchRequest,
     ) -> ClientResult<crate::types::PostContactdbRecipientsSearchResponseData> {
+        Ok(self
+            .post_contactdb_recipients_search_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Search recipients.
+     *
+     * This function performs a `POST` to the `/contactdb/recipients/search` endpoint.
+     *
+     * As opposed to `post_contactdb_recipients_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_contactdb_recipients_search_with_response(
+        &self,
+        body: &crate::types::PostContactdbRecipientsSearchRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostContactdbRecipientsSearchResponseData>>
+    {
         let url = self.client.url("/contactdb/recipients/search", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
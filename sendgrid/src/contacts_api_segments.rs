@@ -25,9 +25,21 @@ impl ContactsApiSegments {
     pub async fn get_contactdb_segments(
         &self,
     ) -> ClientResult<crate::types::ListAllSegmentsResponse> {
+        Ok(self.get_contactdb_segments_with_response().await?.body)
+    }
+    /**
+     * Retrieve all segments.
+     *
+     * This function performs a `GET` to the `/contactdb/segments` endpoint.
+     *
+     * As opposed to `get_contactdb_segments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_segments_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListAllSegmentsResponse>> {
         let url = self.client.url("/contactdb/segments", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -90,9 +102,22 @@ impl ContactsApiSegments {
         &self,
         body: &crate::types::ContactdbSegments,
     ) -> ClientResult<crate::types::ContactdbSegmentsWithAllOf> {
+        Ok(self.post_contactdb_segment_with_response(body).await?.body)
+    }
+    /**
+     * Create a Segment.
+     *
+     * This function performs a `POST` to the `/contactdb/segments` endpoint.
+     *
+     * As opposed to `post_contactdb_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_contactdb_segment_with_response(
+        &self,
+        body: &crate::types::ContactdbSegments,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbSegmentsWithAllOf>> {
         let url = self.client.url("/contactdb/segments", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -117,6 +142,22 @@ impl ContactsApiSegments {
         &self,
         segment_id: &str,
     ) -> ClientResult<crate::types::ContactdbSegments> {
+        Ok(self
+            .get_contactdb_segments_segment_with_response(segment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a segment.
+     *
+     * This function performs a `GET` to the `/contactdb/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `get_contactdb_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbSegments>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/segments/{}",
@@ -125,7 +166,7 @@ impl ContactsApiSegments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -154,6 +195,23 @@ impl ContactsApiSegments {
         delete_contacts: bool,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.delete_contactdb_segments_segment_with_response(segment_id, delete_contacts, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a segment.
+     *
+     * This function performs a `DELETE` to the `/contactdb/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_contactdb_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if delete_contacts {
             query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
@@ -168,7 +226,7 @@ impl ContactsApiSegments {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -194,6 +252,23 @@ impl ContactsApiSegments {
         segment_id: &str,
         body: &crate::types::PatchContactdbSegmentsSegmentRequest,
     ) -> ClientResult<crate::types::ContactdbSegments> {
+        Ok(self
+            .patch_contactdb_segments_segment_with_response(segment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a segment.
+     *
+     * This function performs a `PATCH` to the `/contactdb/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_contactdb_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_contactdb_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+        body: &crate::types::PatchContactdbSegmentsSegmentRequest,
+    ) -> ClientResult<crate::Response<crate::types::ContactdbSegments>> {
         let url = self.client.url(
             &format!(
                 "/contactdb/segments/{}",
@@ -202,7 +277,7 @@ impl ContactsApiSegments {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -230,6 +305,24 @@ impl ContactsApiSegments {
         page: i64,
         page_size: i64,
     ) -> ClientResult<crate::types::ListRecipientsOnASegmentResponse> {
+        Ok(self
+            .get_contactdb_segments_segment_recipients_with_response(segment_id, page, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve recipients on a segment.
+     *
+     * This function performs a `GET` to the `/contactdb/segments/{segment_id}/recipients` endpoint.
+     *
+     * As opposed to `get_contactdb_segments_segment_recipients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_contactdb_segments_segment_recipients_with_response(
+        &self,
+        segment_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<crate::types::ListRecipientsOnASegmentResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page > 0 {
             query_args.push(("page".to_string(), page.to_string()));
@@ -247,7 +340,7 @@ impl ContactsApiSegments {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
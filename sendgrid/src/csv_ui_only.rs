@@ -33,6 +33,19 @@ impl CsvUiOnly {
         &self,
         query: &str,
     ) -> ClientResult<crate::types::PostMessagesDownloadResponse> {
+        Ok(self.post_messages_download_with_response(query).await?.body)
+    }
+    /**
+     * Request CSV.
+     *
+     * This function performs a `POST` to the `/messages/download` endpoint.
+     *
+     * As opposed to `post_messages_download`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_messages_download_with_response(
+        &self,
+        query: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostMessagesDownloadResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
@@ -42,7 +55,7 @@ impl CsvUiOnly {
             .client
             .url(&format!("/messages/download?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,6 +79,22 @@ impl CsvUiOnly {
         &self,
         download_uuid: &str,
     ) -> ClientResult<crate::types::GetMessagesDownloadResponse> {
+        Ok(self
+            .get_messages_download_with_response(download_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Download CSV.
+     *
+     * This function performs a `GET` to the `/messages/download/{download_uuid}` endpoint.
+     *
+     * As opposed to `get_messages_download`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_messages_download_with_response(
+        &self,
+        download_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMessagesDownloadResponse>> {
         let url = self.client.url(
             &format!(
                 "/messages/download/{}",
@@ -74,7 +103,7 @@ impl CsvUiOnly {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -21,9 +21,21 @@ impl CustomFields {
     pub async fn get_mc_field_definitions(
         &self,
     ) -> ClientResult<crate::types::GetMcFieldDefinitionsResponse> {
+        Ok(self.get_mc_field_definitions_with_response().await?.body)
+    }
+    /**
+     * Get All Field Definitions.
+     *
+     * This function performs a `GET` to the `/marketing/field_definitions` endpoint.
+     *
+     * As opposed to `get_mc_field_definitions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_field_definitions_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMcFieldDefinitionsResponse>> {
         let url = self.client.url("/marketing/field_definitions", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,9 +61,25 @@ impl CustomFields {
         &self,
         body: &crate::types::PostMcFieldDefinitionsRequest,
     ) -> ClientResult<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        Ok(self
+            .post_mc_field_definition_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create Custom Field Definition.
+     *
+     * This function performs a `POST` to the `/marketing/field_definitions` endpoint.
+     *
+     * As opposed to `post_mc_field_definition`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_mc_field_definition_with_response(
+        &self,
+        body: &crate::types::PostMcFieldDefinitionsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMcFieldDefinitionsResponseAllOf>> {
         let url = self.client.url("/marketing/field_definitions", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -73,6 +101,21 @@ impl CustomFields {
         &self,
         custom_field_id: &str,
     ) -> ClientResult<()> {
+        self.delete_mc_field_definitions_custom_with_response(custom_field_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete Custom Field Definition.
+     *
+     * This function performs a `DELETE` to the `/marketing/field_definitions/{custom_field_id}` endpoint.
+     *
+     * As opposed to `delete_mc_field_definitions_custom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_mc_field_definitions_custom_with_response(
+        &self,
+        custom_field_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/marketing/field_definitions/{}",
@@ -81,7 +124,7 @@ impl CustomFields {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -104,6 +147,23 @@ impl CustomFields {
         custom_field_id: &str,
         body: &crate::types::IpPool,
     ) -> ClientResult<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        Ok(self
+            .patch_mc_field_definitions_custom_with_response(custom_field_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Custom Field Definition.
+     *
+     * This function performs a `PATCH` to the `/marketing/field_definitions/{custom_field_id}` endpoint.
+     *
+     * As opposed to `patch_mc_field_definitions_custom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mc_field_definitions_custom_with_response(
+        &self,
+        custom_field_id: &str,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::PostMcFieldDefinitionsResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/marketing/field_definitions/{}",
@@ -112,7 +172,7 @@ impl CustomFields {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
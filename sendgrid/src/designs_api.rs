@@ -23,12 +23,25 @@ impl DesignsApi {
      * This endpoint is valuable when retrieving information stored in a field that you wish to update using a PATCH request.
      */
     pub async fn get_design(&self, id: &str) -> ClientResult<crate::types::DesignOutputAllOf> {
+        Ok(self.get_design_with_response(id).await?.body)
+    }
+    /**
+     * Get Design.
+     *
+     * This function performs a `GET` to the `/designs/{id}` endpoint.
+     *
+     * As opposed to `get_design`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_design_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
         let url = self.client.url(
             &format!("/designs/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -56,12 +69,29 @@ impl DesignsApi {
         id: &str,
         body: &crate::types::DesignDuplicateInput,
     ) -> ClientResult<crate::types::DesignOutputAllOf> {
+        Ok(self
+            .post_duplicate_design_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Duplicate Design.
+     *
+     * This function performs a `POST` to the `/designs/{id}` endpoint.
+     *
+     * As opposed to `post_duplicate_design`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_duplicate_design_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
         let url = self.client.url(
             &format!("/designs/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -80,12 +110,25 @@ impl DesignsApi {
      * Be sure to check the ID of the design you intend to delete before making this request; deleting a design is a permanent action.
      */
     pub async fn delete_design(&self, id: &str) -> ClientResult<crate::types::Help> {
+        Ok(self.delete_design_with_response(id).await?.body)
+    }
+    /**
+     * Delete Design.
+     *
+     * This function performs a `DELETE` to the `/designs/{id}` endpoint.
+     *
+     * As opposed to `delete_design`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_design_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!("/designs/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -116,12 +159,26 @@ impl DesignsApi {
         id: &str,
         body: &crate::types::PutDesignRequest,
     ) -> ClientResult<crate::types::DesignOutputAllOf> {
+        Ok(self.put_design_with_response(id, body).await?.body)
+    }
+    /**
+     * Update Design.
+     *
+     * This function performs a `PATCH` to the `/designs/{id}` endpoint.
+     *
+     * As opposed to `put_design`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_design_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PutDesignRequest,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
         let url = self.client.url(
             &format!("/designs/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -153,6 +210,24 @@ impl DesignsApi {
         page_token: &str,
         summary: bool,
     ) -> ClientResult<crate::types::ListDesignsResponse> {
+        Ok(self
+            .list_designs_with_response(page_size, page_token, summary)
+            .await?
+            .body)
+    }
+    /**
+     * List Designs.
+     *
+     * This function performs a `GET` to the `/designs` endpoint.
+     *
+     * As opposed to `list_designs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_designs_with_response(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_size.to_string().is_empty() {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -166,7 +241,7 @@ impl DesignsApi {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/designs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -192,9 +267,22 @@ impl DesignsApi {
         &self,
         body: &crate::types::DesignInputAllOf,
     ) -> ClientResult<crate::types::DesignOutputAllOf> {
+        Ok(self.post_design_with_response(body).await?.body)
+    }
+    /**
+     * Create Design.
+     *
+     * This function performs a `POST` to the `/designs` endpoint.
+     *
+     * As opposed to `post_design`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_design_with_response(
+        &self,
+        body: &crate::types::DesignInputAllOf,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
         let url = self.client.url("/designs", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -218,6 +306,22 @@ impl DesignsApi {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::DesignOutputAllOf> {
+        Ok(self
+            .get_sendgrid_pre_built_design_with_response(id)
+            .await?
+            .body)
+    }
+    /**
+     * Get SendGrid Pre-built Design.
+     *
+     * This function performs a `GET` to the `/designs/pre-builts/{id}` endpoint.
+     *
+     * As opposed to `get_sendgrid_pre_built_design`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sendgrid_pre_built_design_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
         let url = self.client.url(
             &format!(
                 "/designs/pre-builts/{}",
@@ -226,7 +330,7 @@ impl DesignsApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -252,6 +356,23 @@ impl DesignsApi {
         id: &str,
         body: &crate::types::DesignDuplicateInput,
     ) -> ClientResult<crate::types::DesignOutputAllOf> {
+        Ok(self
+            .post_sendgrid_pre_built_design_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Duplicate SendGrid Pre-built Design.
+     *
+     * This function performs a `POST` to the `/designs/pre-builts/{id}` endpoint.
+     *
+     * As opposed to `post_sendgrid_pre_built_design`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sendgrid_pre_built_design_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> ClientResult<crate::Response<crate::types::DesignOutputAllOf>> {
         let url = self.client.url(
             &format!(
                 "/designs/pre-builts/{}",
@@ -260,7 +381,7 @@ impl DesignsApi {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -294,6 +415,24 @@ impl DesignsApi {
         page_token: &str,
         summary: bool,
     ) -> ClientResult<crate::types::ListDesignsResponse> {
+        Ok(self
+            .list_sendgrid_pre_built_designs_with_response(page_size, page_token, summary)
+            .await?
+            .body)
+    }
+    /**
+     * List SendGrid Pre-built Designs.
+     *
+     * This function performs a `GET` to the `/designs/pre-builts` endpoint.
+     *
+     * As opposed to `list_sendgrid_pre_built_designs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_sendgrid_pre_built_designs_with_response(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> ClientResult<crate::Response<crate::types::ListDesignsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_size.to_string().is_empty() {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -309,7 +448,7 @@ impl DesignsApi {
             .client
             .url(&format!("/designs/pre-builts?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
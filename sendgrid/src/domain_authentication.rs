@@ -35,6 +35,27 @@ impl DomainAuthentication {
         username: &str,
         domain: &str,
     ) -> ClientResult<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        Ok(self
+            .get_whitelabel_domains_with_response(limit, offset, exclude_subusers, username, domain)
+            .await?
+            .body)
+    }
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_whitelabel_domains`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_domains_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain.is_empty() {
             query_args.push(("domain".to_string(), domain.to_string()));
@@ -56,7 +77,7 @@ impl DomainAuthentication {
             .client
             .url(&format!("/whitelabel/domains?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -108,6 +129,99 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_whitelabel_domains_pages_stream(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::DomainAuthentication200ResponseAllOf>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if exclude_subusers {
+            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !username.is_empty() {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/domains?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::DomainAuthentication200ResponseAllOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_whitelabel_domains_stream(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DomainAuthentication200ResponseAllOf>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_whitelabel_domains_pages_stream(
+            offset,
+            exclude_subusers,
+            username,
+            domain,
+            None,
+        ))
+    }
     /**
      * Authenticate a domain.
      *
@@ -127,9 +241,22 @@ impl DomainAuthentication {
         &self,
         body: &crate::types::PostWhitelabelDomainsRequest,
     ) -> ClientResult<crate::types::AuthenticationDomain> {
+        Ok(self.post_whitelabel_domain_with_response(body).await?.body)
+    }
+    /**
+     * Authenticate a domain.
+     *
+     * This function performs a `POST` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `post_whitelabel_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_domain_with_response(
+        &self,
+        body: &crate::types::PostWhitelabelDomainsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationDomain>> {
         let url = self.client.url("/whitelabel/domains", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -153,6 +280,22 @@ impl DomainAuthentication {
         &self,
         domain_id: &str,
     ) -> ClientResult<crate::types::AuthenticationDomain> {
+        Ok(self
+            .get_whitelabel_domains_domain_with_response(domain_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve an authenticated domain.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/{domain_id}` endpoint.
+     *
+     * As opposed to `get_whitelabel_domains_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_domains_domain_with_response(
+        &self,
+        domain_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AuthenticationDomain>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/domains/{}",
@@ -161,7 +304,7 @@ impl DomainAuthentication {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -185,6 +328,22 @@ impl DomainAuthentication {
         &self,
         domain_id: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_whitelabel_domains_domain_with_response(domain_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete an authenticated domain.
+     *
+     * This function performs a `DELETE` to the `/whitelabel/domains/{domain_id}` endpoint.
+     *
+     * As opposed to `delete_whitelabel_domains_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_whitelabel_domains_domain_with_response(
+        &self,
+        domain_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/domains/{}",
@@ -193,7 +352,7 @@ impl DomainAuthentication {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -218,6 +377,24 @@ impl DomainAuthentication {
         domain_id: &str,
         body: &crate::types::PatchWhitelabelDomainsDomainRequest,
     ) -> ClientResult<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        Ok(self
+            .patch_whitelabel_domains_domain_with_response(domain_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an authenticated domain.
+     *
+     * This function performs a `PATCH` to the `/whitelabel/domains/{domain_id}` endpoint.
+     *
+     * As opposed to `patch_whitelabel_domains_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_whitelabel_domains_domain_with_response(
+        &self,
+        domain_id: &str,
+        body: &crate::types::PatchWhitelabelDomainsDomainRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
         let url = self.client.url(
             &format!(
                 "/whitelabel/domains/{}",
@@ -226,7 +403,7 @@ impl DomainAuthentication {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -255,6 +432,23 @@ impl DomainAuthentication {
         &self,
         domain: &str,
     ) -> ClientResult<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        Ok(self
+            .get_whitelabel_domains_default_with_response(domain)
+            .await?
+            .body)
+    }
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_whitelabel_domains_default`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_domains_default_with_response(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DomainAuthentication200ResponseAllOf>>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain.is_empty() {
             query_args.push(("domain".to_string(), domain.to_string()));
@@ -264,7 +458,7 @@ impl DomainAuthentication {
             .client
             .url(&format!("/whitelabel/domains/default?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -308,6 +502,78 @@ impl DomainAuthentication {
             )
             .await
     }
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains_default`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_whitelabel_domains_default_pages_stream(
+        &self,
+        domain: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::DomainAuthentication200ResponseAllOf>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain.is_empty() {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/domains/default?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::DomainAuthentication200ResponseAllOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains_default`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_whitelabel_domains_default_stream(
+        &self,
+        domain: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DomainAuthentication200ResponseAllOf>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_whitelabel_domains_default_pages_stream(domain, None))
+    }
     /**
      * Add an IP to an authenticated domain.
      *
@@ -324,6 +590,23 @@ impl DomainAuthentication {
         id: i64,
         body: &crate::types::Ips,
     ) -> ClientResult<crate::types::DomainAuthentication> {
+        Ok(self
+            .post_whitelabel_domains_ip_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add an IP to an authenticated domain.
+     *
+     * This function performs a `POST` to the `/whitelabel/domains/{id}/ips` endpoint.
+     *
+     * As opposed to `post_whitelabel_domains_ip`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_domains_ip_with_response(
+        &self,
+        id: i64,
+        body: &crate::types::Ips,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/domains/{}/ips",
@@ -332,7 +615,7 @@ impl DomainAuthentication {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -357,6 +640,23 @@ impl DomainAuthentication {
         id: i64,
         ip: &str,
     ) -> ClientResult<crate::types::DomainAuthentication> {
+        Ok(self
+            .delete_whitelabel_domains_ips_ip_with_response(id, ip)
+            .await?
+            .body)
+    }
+    /**
+     * Remove an IP from an authenticated domain.
+     *
+     * This function performs a `DELETE` to the `/whitelabel/domains/{id}/ips/{ip}` endpoint.
+     *
+     * As opposed to `delete_whitelabel_domains_ips_ip`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_whitelabel_domains_ips_ip_with_response(
+        &self,
+        id: i64,
+        ip: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/domains/{}/ips/{}",
@@ -366,7 +666,7 @@ impl DomainAuthentication {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -390,6 +690,22 @@ impl DomainAuthentication {
         &self,
         id: i64,
     ) -> ClientResult<crate::types::PostWhitelabelDomainsValidateResponse> {
+        Ok(self
+            .post_whitelabel_domains_validate_with_response(id)
+            .await?
+            .body)
+    }
+    /**
+     * Validate a domain authentication.
+     *
+     * This function performs a `POST` to the `/whitelabel/domains/{id}/validate` endpoint.
+     *
+     * As opposed to `post_whitelabel_domains_validate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_domains_validate_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PostWhitelabelDomainsValidateResponse>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/domains/{}/validate",
@@ -398,7 +714,7 @@ impl DomainAuthentication {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -424,6 +740,22 @@ impl DomainAuthentication {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::DomainAuthentication> {
+        Ok(self
+            .get_whitelabel_domains_subuser_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * List the authenticated domain associated with the given user.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/subuser` endpoint.
+     *
+     * As opposed to `get_whitelabel_domains_subuser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_domains_subuser_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !username.is_empty() {
             query_args.push(("username".to_string(), username.to_string()));
@@ -433,7 +765,7 @@ impl DomainAuthentication {
             .client
             .url(&format!("/whitelabel/domains/subuser?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -459,6 +791,22 @@ impl DomainAuthentication {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_whitelabel_domains_subuser_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * Disassociate an authenticated domain from a given user.
+     *
+     * This function performs a `DELETE` to the `/whitelabel/domains/subuser` endpoint.
+     *
+     * As opposed to `delete_whitelabel_domains_subuser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_whitelabel_domains_subuser_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !username.is_empty() {
             query_args.push(("username".to_string(), username.to_string()));
@@ -468,7 +816,7 @@ impl DomainAuthentication {
             .client
             .url(&format!("/whitelabel/domains/subuser?{}", query_), None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -491,6 +839,23 @@ impl DomainAuthentication {
         domain_id: i64,
         body: &crate::types::PutUserUsernameResponse,
     ) -> ClientResult<crate::types::DomainAuthentication> {
+        Ok(self
+            .post_whitelabel_domains_domain_subuser_with_response(domain_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Associate a authenticated domain with a given user.
+     *
+     * This function performs a `POST` to the `/whitelabel/domains/{domain_id}/subuser` endpoint.
+     *
+     * As opposed to `post_whitelabel_domains_domain_subuser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_domains_domain_subuser_with_response(
+        &self,
+        domain_id: i64,
+        body: &crate::types::PutUserUsernameResponse,
+    ) -> ClientResult<crate::Response<crate::types::DomainAuthentication>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/domains/{}/subuser",
@@ -499,7 +864,7 @@ impl DomainAuthentication {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
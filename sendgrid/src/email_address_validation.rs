@@ -22,9 +22,22 @@ impl EmailAddressValidation {
         &self,
         body: &crate::types::PostValidationsEmailRequest,
     ) -> ClientResult<crate::types::PostValidationsEmailResponse> {
+        Ok(self.post_validations_email_with_response(body).await?.body)
+    }
+    /**
+     * Validate an email.
+     *
+     * This function performs a `POST` to the `/validations/email` endpoint.
+     *
+     * As opposed to `post_validations_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_validations_email_with_response(
+        &self,
+        body: &crate::types::PostValidationsEmailRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostValidationsEmailResponse>> {
         let url = self.client.url("/validations/email", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
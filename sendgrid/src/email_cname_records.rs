@@ -30,9 +30,23 @@ impl EmailCnameRecords {
         &self,
         body: &crate::types::PostWhitelabelDnsEmailRequest,
     ) -> ClientResult<()> {
+        self.post_whitelabel_dns_email_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Email DNS records to a co-worker.
+     *
+     * This function performs a `POST` to the `/whitelabel/dns/email` endpoint.
+     *
+     * As opposed to `post_whitelabel_dns_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_dns_email_with_response(
+        &self,
+        body: &crate::types::PostWhitelabelDnsEmailRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/whitelabel/dns/email", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -33,6 +33,25 @@ impl InvalidEmailsApi {
         limit: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::InvalidEmail>> {
+        Ok(self
+            .get_suppression_invalid_emails_with_response(start_time, end_time, limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_suppression_invalid_emails`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_invalid_emails_with_response(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -51,7 +70,7 @@ impl InvalidEmailsApi {
             .client
             .url(&format!("/suppression/invalid_emails?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -99,6 +118,89 @@ impl InvalidEmailsApi {
             )
             .await
     }
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_invalid_emails_pages_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InvalidEmail>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/invalid_emails?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::InvalidEmail>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_invalid_emails_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InvalidEmail>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_suppression_invalid_emails_pages_stream(
+                start_time, end_time, offset, None,
+            ),
+        )
+    }
     /**
      * Delete invalid emails.
      *
@@ -119,9 +221,25 @@ impl InvalidEmailsApi {
         &self,
         body: &crate::types::DeleteSuppressionBlocksRequest,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_suppression_invalid_emails_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete invalid emails.
+     *
+     * This function performs a `DELETE` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `delete_suppression_invalid_emails`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_invalid_emails_with_response(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url("/suppression/invalid_emails", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -145,6 +263,22 @@ impl InvalidEmailsApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::InvalidEmail>> {
+        Ok(self
+            .get_suppression_invalid_emails_email_with_response(email)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a specific invalid email.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `get_suppression_invalid_emails_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_invalid_emails_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvalidEmail>>> {
         let url = self.client.url(
             &format!(
                 "/suppression/invalid_emails/{}",
@@ -153,7 +287,7 @@ impl InvalidEmailsApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -192,6 +326,76 @@ impl InvalidEmailsApi {
             )
             .await
     }
+    /**
+     * Retrieve a specific invalid email.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails_email`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_invalid_emails_email_pages_stream(
+        &self,
+        email: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InvalidEmail>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/suppression/invalid_emails/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::InvalidEmail>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve a specific invalid email.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails_email`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_invalid_emails_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InvalidEmail>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_suppression_invalid_emails_email_pages_stream(email, None),
+        )
+    }
     /**
      * Delete a specific invalid email.
      *
@@ -207,6 +411,22 @@ impl InvalidEmailsApi {
         &self,
         email: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_suppression_invalid_emails_email_with_response(email)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a specific invalid email.
+     *
+     * This function performs a `DELETE` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `delete_suppression_invalid_emails_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_invalid_emails_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/suppression/invalid_emails/{}",
@@ -215,7 +435,7 @@ impl InvalidEmailsApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
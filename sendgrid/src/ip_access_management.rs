@@ -27,9 +27,24 @@ impl IpAccessManagement {
     pub async fn get_access_settings_whitelist(
         &self,
     ) -> ClientResult<crate::types::IpAccessResponse> {
+        Ok(self
+            .get_access_settings_whitelist_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a list of currently allowed IPs.
+     *
+     * This function performs a `GET` to the `/access_settings/whitelist` endpoint.
+     *
+     * As opposed to `get_access_settings_whitelist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_access_settings_whitelist_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::IpAccessResponse>> {
         let url = self.client.url("/access_settings/whitelist", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,9 +70,25 @@ impl IpAccessManagement {
         &self,
         body: &crate::types::PostAccessSettingsWhitelistRequest,
     ) -> ClientResult<crate::types::IpAccessResponse> {
+        Ok(self
+            .post_access_settings_whitelist_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Add one or more IPs to the allow list.
+     *
+     * This function performs a `POST` to the `/access_settings/whitelist` endpoint.
+     *
+     * As opposed to `post_access_settings_whitelist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_access_settings_whitelist_with_response(
+        &self,
+        body: &crate::types::PostAccessSettingsWhitelistRequest,
+    ) -> ClientResult<crate::Response<crate::types::IpAccessResponse>> {
         let url = self.client.url("/access_settings/whitelist", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -85,9 +116,25 @@ impl IpAccessManagement {
         &self,
         body: &crate::types::DeleteAccessSettingsWhitelistRequest,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_access_settings_whitelist_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Remove one or more IPs from the allow list.
+     *
+     * This function performs a `DELETE` to the `/access_settings/whitelist` endpoint.
+     *
+     * As opposed to `delete_access_settings_whitelist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_access_settings_whitelist_with_response(
+        &self,
+        body: &crate::types::DeleteAccessSettingsWhitelistRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url("/access_settings/whitelist", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -112,6 +159,22 @@ impl IpAccessManagement {
         &self,
         limit: i64,
     ) -> ClientResult<crate::types::GetAccessSettingsActivityResponse> {
+        Ok(self
+            .get_access_settings_activity_with_response(limit)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all recent access attempts.
+     *
+     * This function performs a `GET` to the `/access_settings/activity` endpoint.
+     *
+     * As opposed to `get_access_settings_activity`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_access_settings_activity_with_response(
+        &self,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetAccessSettingsActivityResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -121,7 +184,7 @@ impl IpAccessManagement {
             .client
             .url(&format!("/access_settings/activity?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -147,6 +210,22 @@ impl IpAccessManagement {
         &self,
         rule_id: &str,
     ) -> ClientResult<crate::types::IpAccessResponse> {
+        Ok(self
+            .get_access_settings_whitelist_rule_with_response(rule_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a specific allowed IP.
+     *
+     * This function performs a `GET` to the `/access_settings/whitelist/{rule_id}` endpoint.
+     *
+     * As opposed to `get_access_settings_whitelist_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_access_settings_whitelist_rule_with_response(
+        &self,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::IpAccessResponse>> {
         let url = self.client.url(
             &format!(
                 "/access_settings/whitelist/{}",
@@ -155,7 +234,7 @@ impl IpAccessManagement {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -181,6 +260,22 @@ impl IpAccessManagement {
         &self,
         rule_id: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_access_settings_whitelist_rule_with_response(rule_id)
+            .await?
+            .body)
+    }
+    /**
+     * Remove a specific IP from the allowed list.
+     *
+     * This function performs a `DELETE` to the `/access_settings/whitelist/{rule_id}` endpoint.
+     *
+     * As opposed to `delete_access_settings_whitelist_rule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_access_settings_whitelist_rule_with_response(
+        &self,
+        rule_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/access_settings/whitelist/{}",
@@ -189,7 +284,7 @@ impl IpAccessManagement {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
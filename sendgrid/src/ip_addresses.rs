@@ -40,6 +40,34 @@ impl IpAddresses {
         subuser: &str,
         sort_by_direction: crate::types::SortByDirection,
     ) -> ClientResult<Vec<crate::types::GetIpsResponse>> {
+        Ok(self
+            .get_ips_with_response(
+                ip,
+                exclude_whitelabels,
+                limit,
+                offset,
+                subuser,
+                sort_by_direction,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_ips`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_with_response(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        limit: i64,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetIpsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if exclude_whitelabels {
             query_args.push((
@@ -68,7 +96,7 @@ impl IpAddresses {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/ips?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -132,6 +160,106 @@ impl IpAddresses {
             )
             .await
     }
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_all_ips`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_ips_pages_stream(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::GetIpsResponse>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_whitelabels {
+            query_args.push((
+                "exclude_whitelabels".to_string(),
+                exclude_whitelabels.to_string(),
+            ));
+        }
+        if !ip.is_empty() {
+            query_args.push(("ip".to_string(), ip.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !sort_by_direction.to_string().is_empty() {
+            query_args.push((
+                "sort_by_direction".to_string(),
+                sort_by_direction.to_string(),
+            ));
+        }
+        if !subuser.is_empty() {
+            query_args.push(("subuser".to_string(), subuser.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/ips?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetIpsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_all_ips`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_ips_stream(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetIpsResponse>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_ips_pages_stream(
+            ip,
+            exclude_whitelabels,
+            offset,
+            subuser,
+            sort_by_direction,
+            None,
+        ))
+    }
     /**
      * Add IPs.
      *
@@ -143,9 +271,22 @@ impl IpAddresses {
         &self,
         body: &crate::types::PostIpsRequest,
     ) -> ClientResult<crate::types::PostIpsResponseData> {
+        Ok(self.post_ip_with_response(body).await?.body)
+    }
+    /**
+     * Add IPs.
+     *
+     * This function performs a `POST` to the `/ips` endpoint.
+     *
+     * As opposed to `post_ip`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_ip_with_response(
+        &self,
+        body: &crate::types::PostIpsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostIpsResponseData>> {
         let url = self.client.url("/ips", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -162,9 +303,21 @@ impl IpAddresses {
      * **This endpoint gets amount of IP Addresses that can still be created during a given period and the price of those IPs.**
      */
     pub async fn get_ips_remaining(&self) -> ClientResult<crate::types::GetIpsRemainingResponse> {
+        Ok(self.get_ips_remaining_with_response().await?.body)
+    }
+    /**
+     * Get remaining IPs count.
+     *
+     * This function performs a `GET` to the `/ips/remaining` endpoint.
+     *
+     * As opposed to `get_ips_remaining`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_remaining_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsRemainingResponse>> {
         let url = self.client.url("/ips/remaining", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -185,9 +338,21 @@ impl IpAddresses {
     pub async fn get_ips_assigned(
         &self,
     ) -> ClientResult<Vec<crate::types::GetIpsAssignedResponse>> {
+        Ok(self.get_ips_assigned_with_response().await?.body)
+    }
+    /**
+     * Retrieve all assigned IPs.
+     *
+     * This function performs a `GET` to the `/ips/assigned` endpoint.
+     *
+     * As opposed to `get_ips_assigned`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_assigned_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetIpsAssignedResponse>>> {
         let url = self.client.url("/ips/assigned", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -221,6 +386,67 @@ impl IpAddresses {
             )
             .await
     }
+    /**
+     * Retrieve all assigned IPs.
+     *
+     * This function performs a `GET` to the `/ips/assigned` endpoint.
+     *
+     * As opposed to `get_all_ips_assigned`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_ips_assigned_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetIpsAssignedResponse>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/ips/assigned", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetIpsAssignedResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all assigned IPs.
+     *
+     * This function performs a `GET` to the `/ips/assigned` endpoint.
+     *
+     * As opposed to `get_all_ips_assigned`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_ips_assigned_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetIpsAssignedResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_ips_assigned_pages_stream(None))
+    }
     /**
      * Retrieve all IP pools an IP address belongs to.
      *
@@ -236,6 +462,22 @@ impl IpAddresses {
         &self,
         ip_address: &str,
     ) -> ClientResult<crate::types::GetIpsIpAddressResponse> {
+        Ok(self
+            .get_ips_ip_address_with_response(ip_address)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all IP pools an IP address belongs to.
+     *
+     * This function performs a `GET` to the `/ips/{ip_address}` endpoint.
+     *
+     * As opposed to `get_ips_ip_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_ip_address_with_response(
+        &self,
+        ip_address: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsIpAddressResponse>> {
         let url = self.client.url(
             &format!(
                 "/ips/{}",
@@ -244,7 +486,7 @@ impl IpAddresses {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
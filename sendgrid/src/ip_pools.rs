@@ -19,9 +19,21 @@ impl IpPools {
      * **This endpoint allows you to get all of your IP pools.**
      */
     pub async fn get_ips_pools(&self) -> ClientResult<Vec<crate::types::IpPoolsPoolResp>> {
+        Ok(self.get_ips_pools_with_response().await?.body)
+    }
+    /**
+     * Retrieve all IP pools.
+     *
+     * This function performs a `GET` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `get_ips_pools`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_pools_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpPoolsPoolResp>>> {
         let url = self.client.url("/ips/pools", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,6 +63,65 @@ impl IpPools {
             )
             .await
     }
+    /**
+     * Retrieve all IP pools.
+     *
+     * This function performs a `GET` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `get_all_ips_pools`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_ips_pools_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IpPoolsPoolResp>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/ips/pools", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IpPoolsPoolResp>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all IP pools.
+     *
+     * This function performs a `GET` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `get_all_ips_pools`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_ips_pools_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpPoolsPoolResp>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_ips_pools_pages_stream(None))
+    }
     /**
      * Create an IP pool.
      *
@@ -70,9 +141,22 @@ impl IpPools {
         &self,
         body: &crate::types::IpPool,
     ) -> ClientResult<crate::types::IpPoolsPoolResp> {
+        Ok(self.post_ips_pool_with_response(body).await?.body)
+    }
+    /**
+     * Create an IP pool.
+     *
+     * This function performs a `POST` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `post_ips_pool`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_ips_pool_with_response(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::IpPoolsPoolResp>> {
         let url = self.client.url("/ips/pools", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -105,6 +189,23 @@ impl IpPools {
         pool_name: &str,
         body: &crate::types::PostIpsWarmupRequest,
     ) -> ClientResult<crate::types::GetIpsAssignedResponse> {
+        Ok(self
+            .post_ips_pools_pool_name_ip_with_response(pool_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add an IP address to a pool.
+     *
+     * This function performs a `POST` to the `/ips/pools/{pool_name}/ips` endpoint.
+     *
+     * As opposed to `post_ips_pools_pool_name_ip`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_ips_pools_pool_name_ip_with_response(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsAssignedResponse>> {
         let url = self.client.url(
             &format!(
                 "/ips/pools/{}/ips",
@@ -113,7 +214,7 @@ impl IpPools {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -133,6 +234,22 @@ impl IpPools {
         &self,
         pool_name: &str,
     ) -> ClientResult<crate::types::GetIpsPoolsPoolNameResponse> {
+        Ok(self
+            .get_ips_pools_pool_name_with_response(pool_name)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all the IPs in a specified pool.
+     *
+     * This function performs a `GET` to the `/ips/pools/{pool_name}` endpoint.
+     *
+     * As opposed to `get_ips_pools_pool_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_pools_pool_name_with_response(
+        &self,
+        pool_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetIpsPoolsPoolNameResponse>> {
         let url = self.client.url(
             &format!(
                 "/ips/pools/{}",
@@ -141,7 +258,7 @@ impl IpPools {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -162,6 +279,23 @@ impl IpPools {
         pool_name: &str,
         body: &crate::types::PutIpsPoolsPoolNameRequest,
     ) -> ClientResult<crate::types::IpPoolsPoolResp> {
+        Ok(self
+            .put_ips_pools_pool_name_with_response(pool_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Rename an IP pool.
+     *
+     * This function performs a `PUT` to the `/ips/pools/{pool_name}` endpoint.
+     *
+     * As opposed to `put_ips_pools_pool_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_ips_pools_pool_name_with_response(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PutIpsPoolsPoolNameRequest,
+    ) -> ClientResult<crate::Response<crate::types::IpPoolsPoolResp>> {
         let url = self.client.url(
             &format!(
                 "/ips/pools/{}",
@@ -170,7 +304,7 @@ impl IpPools {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -190,6 +324,22 @@ impl IpPools {
         &self,
         pool_name: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_ips_pools_pool_name_with_response(pool_name)
+            .await?
+            .body)
+    }
+    /**
+     * Delete an IP pool.
+     *
+     * This function performs a `DELETE` to the `/ips/pools/{pool_name}` endpoint.
+     *
+     * As opposed to `delete_ips_pools_pool_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_ips_pools_pool_name_with_response(
+        &self,
+        pool_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/ips/pools/{}",
@@ -198,7 +348,7 @@ impl IpPools {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -219,6 +369,23 @@ impl IpPools {
         pool_name: &str,
         ip: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_ips_pools_pool_name_ip_with_response(pool_name, ip)
+            .await?
+            .body)
+    }
+    /**
+     * Remove an IP address from a pool.
+     *
+     * This function performs a `DELETE` to the `/ips/pools/{pool_name}/ips/{ip}` endpoint.
+     *
+     * As opposed to `delete_ips_pools_pool_name_ip`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_ips_pools_pool_name_ip_with_response(
+        &self,
+        pool_name: &str,
+        ip: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/ips/pools/{}/ips/{}",
@@ -228,7 +395,7 @@ impl IpPools {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -19,9 +19,21 @@ impl IpWarmup {
      * **This endpoint allows you to retrieve all of your IP addresses that are currently warming up.**
      */
     pub async fn get_ips_warmup(&self) -> ClientResult<Vec<crate::types::IpWarmupResponse>> {
+        Ok(self.get_ips_warmup_with_response().await?.body)
+    }
+    /**
+     * Retrieve all IPs currently in warmup.
+     *
+     * This function performs a `GET` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `get_ips_warmup`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_warmup_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
         let url = self.client.url("/ips/warmup", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,6 +63,66 @@ impl IpWarmup {
             )
             .await
     }
+    /**
+     * Retrieve all IPs currently in warmup.
+     *
+     * This function performs a `GET` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_ips_warmup_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IpWarmupResponse>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/ips/warmup", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IpWarmupResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all IPs currently in warmup.
+     *
+     * This function performs a `GET` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_ips_warmup_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpWarmupResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_ips_warmup_pages_stream(None))
+    }
     /**
      * Start warming up an IP address.
      *
@@ -62,9 +134,22 @@ impl IpWarmup {
         &self,
         body: &crate::types::PostIpsWarmupRequest,
     ) -> ClientResult<Vec<crate::types::IpWarmupResponse>> {
+        Ok(self.post_ips_warmup_with_response(body).await?.body)
+    }
+    /**
+     * Start warming up an IP address.
+     *
+     * This function performs a `POST` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `post_ips_warmup`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_ips_warmup_with_response(
+        &self,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
         let url = self.client.url("/ips/warmup", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -86,6 +171,22 @@ impl IpWarmup {
         &self,
         ip_address: &str,
     ) -> ClientResult<Vec<crate::types::IpWarmupResponse>> {
+        Ok(self
+            .get_ips_warmup_ip_address_with_response(ip_address)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve the warmup status for a specific IP address.
+     *
+     * This function performs a `GET` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `get_ips_warmup_ip_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ips_warmup_ip_address_with_response(
+        &self,
+        ip_address: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IpWarmupResponse>>> {
         let url = self.client.url(
             &format!(
                 "/ips/warmup/{}",
@@ -94,7 +195,7 @@ impl IpWarmup {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -135,6 +236,75 @@ impl IpWarmup {
             )
             .await
     }
+    /**
+     * Retrieve the warmup status for a specific IP address.
+     *
+     * This function performs a `GET` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup_ip_address`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_ips_warmup_ip_address_pages_stream(
+        &self,
+        ip_address: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IpWarmupResponse>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/ips/warmup/{}",
+                crate::progenitor_support::encode_path(ip_address),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IpWarmupResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve the warmup status for a specific IP address.
+     *
+     * This function performs a `GET` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup_ip_address`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_ips_warmup_ip_address_stream(
+        &self,
+        ip_address: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IpWarmupResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_ips_warmup_ip_address_pages_stream(ip_address, None))
+    }
     /**
      * Stop warming up an IP address.
      *
@@ -148,6 +318,22 @@ impl IpWarmup {
         &self,
         ip_address: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_ips_warmup_ip_address_with_response(ip_address)
+            .await?
+            .body)
+    }
+    /**
+     * Stop warming up an IP address.
+     *
+     * This function performs a `DELETE` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `delete_ips_warmup_ip_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_ips_warmup_ip_address_with_response(
+        &self,
+        ip_address: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/ips/warmup/{}",
@@ -156,7 +342,7 @@ impl IpWarmup {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
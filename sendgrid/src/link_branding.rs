@@ -29,6 +29,19 @@ impl LinkBranding {
         &self,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::LinkBranding200Response>> {
+        Ok(self.get_whitelabel_links_with_response(limit).await?.body)
+    }
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_whitelabel_links`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_links_with_response(
+        &self,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LinkBranding200Response>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -38,7 +51,7 @@ impl LinkBranding {
             .client
             .url(&format!("/whitelabel/links?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,6 +85,67 @@ impl LinkBranding {
             )
             .await
     }
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_links`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_whitelabel_links_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::LinkBranding200Response>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/whitelabel/links", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::LinkBranding200Response>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_links`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_whitelabel_links_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LinkBranding200Response>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_whitelabel_links_pages_stream(None))
+    }
     /**
      * Create a branded link.
      *
@@ -91,9 +165,22 @@ impl LinkBranding {
         &self,
         body: &crate::types::PostWhitelabelLinksRequest,
     ) -> ClientResult<crate::types::LinkBranding200Response> {
+        Ok(self.post_whitelabel_link_with_response(body).await?.body)
+    }
+    /**
+     * Create a branded link.
+     *
+     * This function performs a `POST` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `post_whitelabel_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_link_with_response(
+        &self,
+        body: &crate::types::PostWhitelabelLinksRequest,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
         let url = self.client.url("/whitelabel/links", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -119,6 +206,22 @@ impl LinkBranding {
         &self,
         id: i64,
     ) -> ClientResult<crate::types::PostWhitelabelLinksValidateResponse> {
+        Ok(self
+            .post_whitelabel_links_validate_with_response(id)
+            .await?
+            .body)
+    }
+    /**
+     * Validate a branded link.
+     *
+     * This function performs a `POST` to the `/whitelabel/links/{id}/validate` endpoint.
+     *
+     * As opposed to `post_whitelabel_links_validate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_links_validate_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::PostWhitelabelLinksValidateResponse>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/links/{}/validate",
@@ -127,7 +230,7 @@ impl LinkBranding {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -150,6 +253,23 @@ impl LinkBranding {
         link_id: i64,
         body: &crate::types::PostWhitelabelLinksLinkSubuserRequest,
     ) -> ClientResult<crate::types::LinkBranding200Response> {
+        Ok(self
+            .post_whitelabel_links_link_subuser_with_response(link_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Associate a branded link with a subuser.
+     *
+     * This function performs a `POST` to the `/whitelabel/links/{link_id}/subuser` endpoint.
+     *
+     * As opposed to `post_whitelabel_links_link_subuser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_links_link_subuser_with_response(
+        &self,
+        link_id: i64,
+        body: &crate::types::PostWhitelabelLinksLinkSubuserRequest,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/links/{}/subuser",
@@ -158,7 +278,7 @@ impl LinkBranding {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -184,6 +304,22 @@ impl LinkBranding {
         &self,
         id: i64,
     ) -> ClientResult<crate::types::LinkBranding200Response> {
+        Ok(self
+            .get_whitelabel_links_link_branding_with_response(id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a branded link.
+     *
+     * This function performs a `GET` to the `/whitelabel/links/{id}` endpoint.
+     *
+     * As opposed to `get_whitelabel_links_link_branding`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_links_link_branding_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/links/{}",
@@ -192,7 +328,7 @@ impl LinkBranding {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -217,6 +353,19 @@ impl LinkBranding {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_whitelabel_links(&self, id: i64) -> ClientResult<crate::types::Help> {
+        Ok(self.delete_whitelabel_links_with_response(id).await?.body)
+    }
+    /**
+     * Delete a branded link.
+     *
+     * This function performs a `DELETE` to the `/whitelabel/links/{id}` endpoint.
+     *
+     * As opposed to `delete_whitelabel_links`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_whitelabel_links_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/links/{}",
@@ -225,7 +374,7 @@ impl LinkBranding {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -252,6 +401,23 @@ impl LinkBranding {
         id: i64,
         body: &crate::types::PatchWhitelabelLinksRequest,
     ) -> ClientResult<crate::types::LinkBranding200Response> {
+        Ok(self
+            .patch_whitelabel_links_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a branded link.
+     *
+     * This function performs a `PATCH` to the `/whitelabel/links/{id}` endpoint.
+     *
+     * As opposed to `patch_whitelabel_links`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_whitelabel_links_with_response(
+        &self,
+        id: i64,
+        body: &crate::types::PatchWhitelabelLinksRequest,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/links/{}",
@@ -260,7 +426,7 @@ impl LinkBranding {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -293,6 +459,22 @@ impl LinkBranding {
         &self,
         domain: &str,
     ) -> ClientResult<crate::types::LinkBranding200Response> {
+        Ok(self
+            .get_whitelabel_links_default_with_response(domain)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve the default branded link.
+     *
+     * This function performs a `GET` to the `/whitelabel/links/default` endpoint.
+     *
+     * As opposed to `get_whitelabel_links_default`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_links_default_with_response(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain.is_empty() {
             query_args.push(("domain".to_string(), domain.to_string()));
@@ -302,7 +484,7 @@ impl LinkBranding {
             .client
             .url(&format!("/whitelabel/links/default?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -328,6 +510,22 @@ impl LinkBranding {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::LinkBranding200Response> {
+        Ok(self
+            .get_whitelabel_links_subuser_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a subuser's branded link.
+     *
+     * This function performs a `GET` to the `/whitelabel/links/subuser` endpoint.
+     *
+     * As opposed to `get_whitelabel_links_subuser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_links_subuser_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::LinkBranding200Response>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !username.is_empty() {
             query_args.push(("username".to_string(), username.to_string()));
@@ -337,7 +535,7 @@ impl LinkBranding {
             .client
             .url(&format!("/whitelabel/links/subuser?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -365,6 +563,22 @@ impl LinkBranding {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_whitelabel_links_subuser_with_response(username)
+            .await?
+            .body)
+    }
+    /**
+     * Disassociate a branded link from a subuser.
+     *
+     * This function performs a `DELETE` to the `/whitelabel/links/subuser` endpoint.
+     *
+     * As opposed to `delete_whitelabel_links_subuser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_whitelabel_links_subuser_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !username.is_empty() {
             query_args.push(("username".to_string(), username.to_string()));
@@ -374,7 +588,7 @@ impl LinkBranding {
             .client
             .url(&format!("/whitelabel/links/subuser?{}", query_), None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
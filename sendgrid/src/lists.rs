@@ -28,6 +28,20 @@ impl Lists {
         page_size: f64,
         page_token: &str,
     ) -> ClientResult<crate::types::GetMcListsResponse> {
+        Ok(self.get_mc_with_response(page_size, page_token).await?.body)
+    }
+    /**
+     * Get All Lists.
+     *
+     * This function performs a `GET` to the `/marketing/lists` endpoint.
+     *
+     * As opposed to `get_mc`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_with_response(
+        &self,
+        page_size: f64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMcListsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !page_size.to_string().is_empty() {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -40,7 +54,7 @@ impl Lists {
             .client
             .url(&format!("/marketing/lists?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -61,9 +75,22 @@ impl Lists {
      * A link to the newly created object is in `_metadata`.
      */
     pub async fn post_mc(&self, body: &crate::types::IpPool) -> ClientResult<crate::types::List> {
+        Ok(self.post_mc_with_response(body).await?.body)
+    }
+    /**
+     * Create List.
+     *
+     * This function performs a `POST` to the `/marketing/lists` endpoint.
+     *
+     * As opposed to `post_mc`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_mc_with_response(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> ClientResult<crate::Response<crate::types::List>> {
         let url = self.client.url("/marketing/lists", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -83,6 +110,19 @@ impl Lists {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::GetMcListsContactsCountResponse> {
+        Ok(self.get_mc_contacts_count_with_response(id).await?.body)
+    }
+    /**
+     * Get List Contact Count.
+     *
+     * This function performs a `GET` to the `/marketing/lists/{id}/contacts/count` endpoint.
+     *
+     * As opposed to `get_mc_contacts_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_contacts_count_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMcListsContactsCountResponse>> {
         let url = self.client.url(
             &format!(
                 "/marketing/lists/{}/contacts/count",
@@ -91,7 +131,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -120,6 +160,23 @@ impl Lists {
         id: &str,
         contact_sample: bool,
     ) -> ClientResult<crate::types::GetMcListsResponseAllOf> {
+        Ok(self
+            .get_mc_lists_with_response(id, contact_sample)
+            .await?
+            .body)
+    }
+    /**
+     * Get a List by ID.
+     *
+     * This function performs a `GET` to the `/marketing/lists/{id}` endpoint.
+     *
+     * As opposed to `get_mc_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mc_lists_with_response(
+        &self,
+        id: &str,
+        contact_sample: bool,
+    ) -> ClientResult<crate::Response<crate::types::GetMcListsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if contact_sample {
             query_args.push(("contact_sample".to_string(), contact_sample.to_string()));
@@ -134,7 +191,7 @@ impl Lists {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -161,6 +218,20 @@ impl Lists {
         id: &str,
         delete_contacts: bool,
     ) -> ClientResult<crate::types::DeleteListsResponse> {
+        Ok(self.delete_with_response(id, delete_contacts).await?.body)
+    }
+    /**
+     * Delete a list.
+     *
+     * This function performs a `DELETE` to the `/marketing/lists/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        id: &str,
+        delete_contacts: bool,
+    ) -> ClientResult<crate::Response<crate::types::DeleteListsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if delete_contacts {
             query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
@@ -175,7 +246,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -196,6 +267,20 @@ impl Lists {
         id: &str,
         body: &crate::types::PatchMcListsRequest,
     ) -> ClientResult<crate::types::List> {
+        Ok(self.patch_mc_with_response(id, body).await?.body)
+    }
+    /**
+     * Update List.
+     *
+     * This function performs a `PATCH` to the `/marketing/lists/{id}` endpoint.
+     *
+     * As opposed to `patch_mc`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mc_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PatchMcListsRequest,
+    ) -> ClientResult<crate::Response<crate::types::List>> {
         let url = self.client.url(
             &format!(
                 "/marketing/lists/{}",
@@ -204,7 +289,7 @@ impl Lists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -231,6 +316,23 @@ impl Lists {
         id: &str,
         contact_ids: &str,
     ) -> ClientResult<crate::types::DeleteMcListsContactsResponse> {
+        Ok(self
+            .delete_mc_contacts_with_response(id, contact_ids)
+            .await?
+            .body)
+    }
+    /**
+     * Remove Contacts from a List.
+     *
+     * This function performs a `DELETE` to the `/marketing/lists/{id}/contacts` endpoint.
+     *
+     * As opposed to `delete_mc_contacts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_mc_contacts_with_response(
+        &self,
+        id: &str,
+        contact_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteMcListsContactsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !contact_ids.is_empty() {
             query_args.push(("contact_ids".to_string(), contact_ids.to_string()));
@@ -245,7 +347,7 @@ impl Lists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
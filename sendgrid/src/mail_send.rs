@@ -64,9 +64,23 @@ impl MailSend {
      * `
      */
     pub async fn post(&self, body: &crate::types::PostMailSendRequest) -> ClientResult<()> {
+        self.post_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * v3 Mail Send.
+     *
+     * This function performs a `POST` to the `/mail/send` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostMailSendRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/mail/send", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -36,6 +36,24 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<crate::types::AutomationsResponse> {
+        Ok(self
+            .getall_automation_stats_with_response(automation_ids, page_size, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get All Automation Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations` endpoint.
+     *
+     * As opposed to `getall_automation_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn getall_automation_stats_with_response(
+        &self,
+        automation_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !automation_ids.is_empty() {
             query_args.push(("automation_ids".to_string(), automation_ids.join(" ")));
@@ -51,7 +69,7 @@ impl MarketingCampaignsStats {
             .client
             .url(&format!("/marketing/stats/automations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -94,6 +112,40 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<crate::types::AutomationsResponse> {
+        Ok(self
+            .get_automation_stat_with_response(
+                id,
+                group_by,
+                step_ids,
+                aggregated_by,
+                start_date,
+                end_date,
+                timezone,
+                page_size,
+                page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get Automation Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations/{id}` endpoint.
+     *
+     * As opposed to `get_automation_stat`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_automation_stat_with_response(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -129,7 +181,7 @@ impl MarketingCampaignsStats {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -163,6 +215,24 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<crate::types::SinglesendsResponse> {
+        Ok(self
+            .getall_singlesend_stats_with_response(singlesend_ids, page_size, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get All Single Sends Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends` endpoint.
+     *
+     * As opposed to `getall_singlesend_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn getall_singlesend_stats_with_response(
+        &self,
+        singlesend_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page_size > 0 {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -178,7 +248,7 @@ impl MarketingCampaignsStats {
             .client
             .url(&format!("/marketing/stats/singlesends?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -219,6 +289,38 @@ impl MarketingCampaignsStats {
         page_token: &str,
         group_by: &[String],
     ) -> ClientResult<crate::types::SinglesendsResponse> {
+        Ok(self
+            .get_singlesend_stat_with_response(
+                id,
+                aggregated_by,
+                start_date,
+                end_date,
+                timezone,
+                page_size,
+                page_token,
+                group_by,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get Single Send Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends/{id}` endpoint.
+     *
+     * As opposed to `get_singlesend_stat`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_singlesend_stat_with_response(
+        &self,
+        id: &str,
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+    ) -> ClientResult<crate::Response<crate::types::SinglesendsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -251,7 +353,7 @@ impl MarketingCampaignsStats {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -288,6 +390,26 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<crate::types::AutomationsLinkStatsResponse> {
+        Ok(self
+            .get_automation_link_stat_with_response(id, group_by, step_ids, page_size, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get Automation Click Tracking Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations/{id}/links` endpoint.
+     *
+     * As opposed to `get_automation_link_stat`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_automation_link_stat_with_response(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::AutomationsLinkStatsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !group_by.is_empty() {
             query_args.push(("group_by".to_string(), group_by.join(" ")));
@@ -311,7 +433,7 @@ impl MarketingCampaignsStats {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -350,6 +472,34 @@ impl MarketingCampaignsStats {
         ab_variation_id: &str,
         ab_phase_id: crate::types::AbPhaseId,
     ) -> ClientResult<crate::types::SinglesendsLinkStatsResponse> {
+        Ok(self
+            .get_singlesend_link_stat_with_response(
+                id,
+                page_size,
+                page_token,
+                group_by,
+                ab_variation_id,
+                ab_phase_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get Single Send Click Tracking Stats by ID.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends/{id}/links` endpoint.
+     *
+     * As opposed to `get_singlesend_link_stat`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_singlesend_link_stat_with_response(
+        &self,
+        id: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+        ab_variation_id: &str,
+        ab_phase_id: crate::types::AbPhaseId,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendsLinkStatsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ab_phase_id.to_string().is_empty() {
             query_args.push(("ab_phase_id".to_string(), ab_phase_id.to_string()));
@@ -376,7 +526,7 @@ impl MarketingCampaignsStats {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -406,6 +556,23 @@ impl MarketingCampaignsStats {
         ids: &[String],
         timezone: &str,
     ) -> ClientResult<String> {
+        Ok(self
+            .get_singlesend_stats_export_with_response(ids, timezone)
+            .await?
+            .body)
+    }
+    /**
+     * Export Single Send Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/singlesends/export` endpoint.
+     *
+     * As opposed to `get_singlesend_stats_export`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_singlesend_stats_export_with_response(
+        &self,
+        ids: &[String],
+        timezone: &str,
+    ) -> ClientResult<crate::Response<String>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ids.is_empty() {
             query_args.push(("ids".to_string(), ids.join(" ")));
@@ -419,7 +586,7 @@ impl MarketingCampaignsStats {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -449,6 +616,23 @@ impl MarketingCampaignsStats {
         ids: &[String],
         timezone: &str,
     ) -> ClientResult<String> {
+        Ok(self
+            .get_automations_stats_export_with_response(ids, timezone)
+            .await?
+            .body)
+    }
+    /**
+     * Export Automation Stats.
+     *
+     * This function performs a `GET` to the `/marketing/stats/automations/export` endpoint.
+     *
+     * As opposed to `get_automations_stats_export`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_automations_stats_export_with_response(
+        &self,
+        ids: &[String],
+        timezone: &str,
+    ) -> ClientResult<crate::Response<String>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ids.is_empty() {
             query_args.push(("ids".to_string(), ids.join(" ")));
@@ -462,7 +646,7 @@ impl MarketingCampaignsStats {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,6 +70,20 @@ impl Query {
         query: &str,
         limit: f64,
     ) -> ClientResult<crate::types::GetMessagesResponse> {
+        Ok(self.get_messages_with_response(query, limit).await?.body)
+    }
+    /**
+     * Filter all messages.
+     *
+     * This function performs a `GET` to the `/messages` endpoint.
+     *
+     * As opposed to `get_messages`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_messages_with_response(
+        &self,
+        query: &str,
+        limit: f64,
+    ) -> ClientResult<crate::Response<crate::types::GetMessagesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !limit.to_string().is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -80,7 +94,7 @@ impl Query {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/messages?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -103,6 +117,19 @@ impl Query {
      * * `authorization: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_messages_msg(&self, msg_id: &str) -> ClientResult<crate::types::Message> {
+        Ok(self.get_messages_msg_with_response(msg_id).await?.body)
+    }
+    /**
+     * Filter messages by message ID.
+     *
+     * This function performs a `GET` to the `/messages/{msg_id}` endpoint.
+     *
+     * As opposed to `get_messages_msg`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_messages_msg_with_response(
+        &self,
+        msg_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Message>> {
         let url = self.client.url(
             &format!(
                 "/messages/{}",
@@ -111,7 +138,7 @@ impl Query {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -37,6 +37,24 @@ impl ReverseDns {
         offset: i64,
         ip: &str,
     ) -> ClientResult<Vec<crate::types::ReverseDns>> {
+        Ok(self
+            .get_whitelabel_ips_with_response(limit, offset, ip)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all reverse DNS records.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `get_whitelabel_ips`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_ips_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+        ip: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReverseDns>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ip.is_empty() {
             query_args.push(("ip".to_string(), ip.to_string()));
@@ -52,7 +70,7 @@ impl ReverseDns {
             .client
             .url(&format!("/whitelabel/ips?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -102,6 +120,80 @@ impl ReverseDns {
             )
             .await
     }
+    /**
+     * Retrieve all reverse DNS records.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_ips`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_whitelabel_ips_pages_stream(
+        &self,
+        offset: i64,
+        ip: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ReverseDns>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ip.is_empty() {
+            query_args.push(("ip".to_string(), ip.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/whitelabel/ips?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ReverseDns>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all reverse DNS records.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_ips`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_whitelabel_ips_stream(
+        &self,
+        offset: i64,
+        ip: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReverseDns>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_whitelabel_ips_pages_stream(offset, ip, None))
+    }
     /**
      * Set up reverse DNS.
      *
@@ -117,9 +209,22 @@ impl ReverseDns {
         &self,
         body: &crate::types::PostWhitelabelIpsRequest,
     ) -> ClientResult<crate::types::ReverseDns> {
+        Ok(self.post_whitelabel_ip_with_response(body).await?.body)
+    }
+    /**
+     * Set up reverse DNS.
+     *
+     * This function performs a `POST` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `post_whitelabel_ip`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_ip_with_response(
+        &self,
+        body: &crate::types::PostWhitelabelIpsRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReverseDns>> {
         let url = self.client.url("/whitelabel/ips", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -149,6 +254,22 @@ impl ReverseDns {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::PostWhitelabelIpsValidateResponse> {
+        Ok(self
+            .post_whitelabel_ips_validate_with_response(id)
+            .await?
+            .body)
+    }
+    /**
+     * Validate a reverse DNS record.
+     *
+     * This function performs a `POST` to the `/whitelabel/ips/{id}/validate` endpoint.
+     *
+     * As opposed to `post_whitelabel_ips_validate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_whitelabel_ips_validate_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostWhitelabelIpsValidateResponse>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/ips/{}/validate",
@@ -157,7 +278,7 @@ impl ReverseDns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -180,6 +301,19 @@ impl ReverseDns {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_whitelabel_ip(&self, id: &str) -> ClientResult<crate::types::ReverseDns> {
+        Ok(self.get_whitelabel_ip_with_response(id).await?.body)
+    }
+    /**
+     * Retrieve a reverse DNS record.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips/{id}` endpoint.
+     *
+     * As opposed to `get_whitelabel_ip`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_whitelabel_ip_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReverseDns>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/ips/{}",
@@ -188,7 +322,7 @@ impl ReverseDns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -213,6 +347,19 @@ impl ReverseDns {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_whitelabel_ips(&self, id: &str) -> ClientResult<crate::types::Help> {
+        Ok(self.delete_whitelabel_ips_with_response(id).await?.body)
+    }
+    /**
+     * Delete a reverse DNS record.
+     *
+     * This function performs a `DELETE` to the `/whitelabel/ips/{id}` endpoint.
+     *
+     * As opposed to `delete_whitelabel_ips`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_whitelabel_ips_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/whitelabel/ips/{}",
@@ -221,7 +368,7 @@ impl ReverseDns {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
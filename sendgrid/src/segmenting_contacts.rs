@@ -37,6 +37,23 @@ impl SegmentingContacts {
         parent_list_ids: &str,
         no_parent_list_id: bool,
     ) -> ClientResult<crate::types::GetMarketingSegmentsResponse> {
+        Ok(self
+            .get_marketing_segments_with_response(parent_list_ids, no_parent_list_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get List of Segments.
+     *
+     * This function performs a `GET` to the `/marketing/segments` endpoint.
+     *
+     * As opposed to `get_marketing_segments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_marketing_segments_with_response(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSegmentsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if no_parent_list_id {
             query_args.push((
@@ -52,7 +69,7 @@ impl SegmentingContacts {
             .client
             .url(&format!("/marketing/segments?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,9 +89,22 @@ impl SegmentingContacts {
         &self,
         body: &crate::types::PostMarketingSegmentsRequestAllOf,
     ) -> ClientResult<crate::types::FullSegmentAllOf> {
+        Ok(self.post_marketing_segment_with_response(body).await?.body)
+    }
+    /**
+     * Create Segment.
+     *
+     * This function performs a `POST` to the `/marketing/segments` endpoint.
+     *
+     * As opposed to `post_marketing_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_segment_with_response(
+        &self,
+        body: &crate::types::PostMarketingSegmentsRequestAllOf,
+    ) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
         let url = self.client.url("/marketing/segments", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -99,6 +129,23 @@ impl SegmentingContacts {
         segment_id: &str,
         query_json: bool,
     ) -> ClientResult<crate::types::FullSegmentAllOf> {
+        Ok(self
+            .get_marketing_segments_segment_with_response(segment_id, query_json)
+            .await?
+            .body)
+    }
+    /**
+     * Get Segment by ID.
+     *
+     * This function performs a `GET` to the `/marketing/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `get_marketing_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_marketing_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+        query_json: bool,
+    ) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if query_json {
             query_args.push(("query_json".to_string(), query_json.to_string()));
@@ -113,7 +160,7 @@ impl SegmentingContacts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -135,6 +182,22 @@ impl SegmentingContacts {
         &self,
         segment_id: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_marketing_segments_segment_with_response(segment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete Segment.
+     *
+     * This function performs a `DELETE` to the `/marketing/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_marketing_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_marketing_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/marketing/segments/{}",
@@ -143,7 +206,7 @@ impl SegmentingContacts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -166,6 +229,23 @@ impl SegmentingContacts {
         segment_id: &str,
         body: &crate::types::SegmentWriteV2,
     ) -> ClientResult<crate::types::FullSegmentAllOf> {
+        Ok(self
+            .patch_marketing_segments_segment_with_response(segment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Segment.
+     *
+     * This function performs a `PATCH` to the `/marketing/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_marketing_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_marketing_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentWriteV2,
+    ) -> ClientResult<crate::Response<crate::types::FullSegmentAllOf>> {
         let url = self.client.url(
             &format!(
                 "/marketing/segments/{}",
@@ -174,7 +254,7 @@ impl SegmentingContacts {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -196,9 +276,25 @@ impl SegmentingContacts {
         &self,
         body: &crate::types::PostMarketingSegmentsDeleteRequest,
     ) -> ClientResult<crate::types::PostMarketingSegmentsDeleteResponse> {
+        Ok(self
+            .post_marketing_segments_delete_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Bulk Delete Segments.
+     *
+     * This function performs a `POST` to the `/marketing/segments/delete` endpoint.
+     *
+     * As opposed to `post_marketing_segments_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_segments_delete_with_response(
+        &self,
+        body: &crate::types::PostMarketingSegmentsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostMarketingSegmentsDeleteResponse>> {
         let url = self.client.url("/marketing/segments/delete", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
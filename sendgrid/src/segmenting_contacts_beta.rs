@@ -37,6 +37,23 @@ impl SegmentingContactsBeta {
         parent_list_ids: &str,
         no_parent_list_id: bool,
     ) -> ClientResult<crate::types::AllSegmentsResponse> {
+        Ok(self
+            .get_segments_with_response(parent_list_ids, no_parent_list_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get List of Segments.
+     *
+     * This function performs a `GET` to the `/marketing/segments/2.0` endpoint.
+     *
+     * As opposed to `get_segments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_segments_with_response(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> ClientResult<crate::Response<crate::types::AllSegmentsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if no_parent_list_id {
             query_args.push((
@@ -52,7 +69,7 @@ impl SegmentingContactsBeta {
             .client
             .url(&format!("/marketing/segments/2.0?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -74,9 +91,22 @@ impl SegmentingContactsBeta {
         &self,
         body: &crate::types::SegmentWriteV2,
     ) -> ClientResult<crate::types::SegmentResponse> {
+        Ok(self.post_segment_with_response(body).await?.body)
+    }
+    /**
+     * Create Segment.
+     *
+     * This function performs a `POST` to the `/marketing/segments/2.0` endpoint.
+     *
+     * As opposed to `post_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_segment_with_response(
+        &self,
+        body: &crate::types::SegmentWriteV2,
+    ) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
         let url = self.client.url("/marketing/segments/2.0", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -101,6 +131,23 @@ impl SegmentingContactsBeta {
         segment_id: &str,
         contacts_sample: bool,
     ) -> ClientResult<crate::types::SegmentResponse> {
+        Ok(self
+            .get_segments_segment_with_response(segment_id, contacts_sample)
+            .await?
+            .body)
+    }
+    /**
+     * Get Segment by ID.
+     *
+     * This function performs a `GET` to the `/marketing/segments/2.0/{segment_id}` endpoint.
+     *
+     * As opposed to `get_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+        contacts_sample: bool,
+    ) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if contacts_sample {
             query_args.push(("contacts_sample".to_string(), contacts_sample.to_string()));
@@ -115,7 +162,7 @@ impl SegmentingContactsBeta {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -132,6 +179,21 @@ impl SegmentingContactsBeta {
      * **The Segmentation V2 API is currently in private beta. If you'd like to be added to the beta, please fill out this [form](https://docs.google.com/forms/d/e/1FAIpQLSd5zwC9dRk8lAp1oTWjdGc-aSY69flW_7wnutvKBhpUluSnfQ/viewform)**
      */
     pub async fn delete_segments_segment(&self, segment_id: &str) -> ClientResult<()> {
+        self.delete_segments_segment_with_response(segment_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete segment.
+     *
+     * This function performs a `DELETE` to the `/marketing/segments/2.0/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/marketing/segments/2.0/{}",
@@ -140,7 +202,7 @@ impl SegmentingContactsBeta {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -163,6 +225,23 @@ impl SegmentingContactsBeta {
         segment_id: &str,
         body: &crate::types::SegmentUpdate,
     ) -> ClientResult<crate::types::SegmentResponse> {
+        Ok(self
+            .patch_segments_segment_with_response(segment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Segment.
+     *
+     * This function performs a `PATCH` to the `/marketing/segments/2.0/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_segments_segment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_segments_segment_with_response(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentUpdate,
+    ) -> ClientResult<crate::Response<crate::types::SegmentResponse>> {
         let url = self.client.url(
             &format!(
                 "/marketing/segments/2.0/{}",
@@ -171,7 +250,7 @@ impl SegmentingContactsBeta {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -30,9 +30,25 @@ impl SendTestEmail {
         &self,
         body: &crate::types::PostMarketingTestSendEmailRequest,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .post_marketing_test_send_email_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Send a Test Marketing Email.
+     *
+     * This function performs a `POST` to the `/marketing/test/send_email` endpoint.
+     *
+     * As opposed to `post_marketing_test_send_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_test_send_email_with_response(
+        &self,
+        body: &crate::types::PostMarketingTestSendEmailRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url("/marketing/test/send_email", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
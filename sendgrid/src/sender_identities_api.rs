@@ -23,9 +23,21 @@ impl SenderIdentitiesApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_senders(&self) -> ClientResult<crate::types::GetSendersResponse> {
+        Ok(self.get_senders_with_response().await?.body)
+    }
+    /**
+     * Get all Sender Identities.
+     *
+     * This function performs a `GET` to the `/senders` endpoint.
+     *
+     * As opposed to `get_senders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_senders_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetSendersResponse>> {
         let url = self.client.url("/senders", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,9 +63,22 @@ impl SenderIdentitiesApi {
         &self,
         body: &crate::types::PostSendersRequestAllOf,
     ) -> ClientResult<crate::types::SenderAllOf> {
+        Ok(self.post_sender_with_response(body).await?.body)
+    }
+    /**
+     * Create a Sender Identity.
+     *
+     * This function performs a `POST` to the `/senders` endpoint.
+     *
+     * As opposed to `post_sender`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sender_with_response(
+        &self,
+        body: &crate::types::PostSendersRequestAllOf,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
         let url = self.client.url("/senders", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -77,6 +102,19 @@ impl SenderIdentitiesApi {
         &self,
         sender_id: i64,
     ) -> ClientResult<crate::types::SenderAllOf> {
+        Ok(self.get_senders_sender_with_response(sender_id).await?.body)
+    }
+    /**
+     * View a Sender Identity.
+     *
+     * This function performs a `GET` to the `/senders/{sender_id}` endpoint.
+     *
+     * As opposed to `get_senders_sender`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_senders_sender_with_response(
+        &self,
+        sender_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
         let url = self.client.url(
             &format!(
                 "/senders/{}",
@@ -85,7 +123,7 @@ impl SenderIdentitiesApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -106,6 +144,22 @@ impl SenderIdentitiesApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_senders_sender(&self, sender_id: i64) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_senders_sender_with_response(sender_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a Sender Identity.
+     *
+     * This function performs a `DELETE` to the `/senders/{sender_id}` endpoint.
+     *
+     * As opposed to `delete_senders_sender`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_senders_sender_with_response(
+        &self,
+        sender_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/senders/{}",
@@ -114,7 +168,7 @@ impl SenderIdentitiesApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -143,6 +197,23 @@ impl SenderIdentitiesApi {
         sender_id: i64,
         body: &crate::types::SenderRequest,
     ) -> ClientResult<crate::types::SenderAllOf> {
+        Ok(self
+            .patch_senders_sender_with_response(sender_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a Sender Identity.
+     *
+     * This function performs a `PATCH` to the `/senders/{sender_id}` endpoint.
+     *
+     * As opposed to `patch_senders_sender`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_senders_sender_with_response(
+        &self,
+        sender_id: i64,
+        body: &crate::types::SenderRequest,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
         let url = self.client.url(
             &format!(
                 "/senders/{}",
@@ -151,7 +222,7 @@ impl SenderIdentitiesApi {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -175,6 +246,22 @@ impl SenderIdentitiesApi {
         &self,
         sender_id: i64,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .post_senders_sender_resend_verification_with_response(sender_id)
+            .await?
+            .body)
+    }
+    /**
+     * Resend Sender Identity Verification.
+     *
+     * This function performs a `POST` to the `/senders/{sender_id}/resend_verification` endpoint.
+     *
+     * As opposed to `post_senders_sender_resend_verification`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_senders_sender_resend_verification_with_response(
+        &self,
+        sender_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/senders/{}/resend_verification",
@@ -183,7 +270,7 @@ impl SenderIdentitiesApi {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
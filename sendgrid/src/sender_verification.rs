@@ -25,9 +25,24 @@ impl SenderVerification {
     pub async fn get_verified_senders_domains(
         &self,
     ) -> ClientResult<crate::types::GetVerifiedSendersDomainsResponse> {
+        Ok(self
+            .get_verified_senders_domains_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Domain Warn List.
+     *
+     * This function performs a `GET` to the `/verified_senders/domains` endpoint.
+     *
+     * As opposed to `get_verified_senders_domains`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verified_senders_domains_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersDomainsResponse>> {
         let url = self.client.url("/verified_senders/domains", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,9 +65,24 @@ impl SenderVerification {
     pub async fn get_verified_senders_steps_completed(
         &self,
     ) -> ClientResult<crate::types::GetVerifiedSendersStepsCompletedResponse> {
+        Ok(self
+            .get_verified_senders_steps_completed_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Completed Steps.
+     *
+     * This function performs a `GET` to the `/verified_senders/steps_completed` endpoint.
+     *
+     * As opposed to `get_verified_senders_steps_completed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verified_senders_steps_completed_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersStepsCompletedResponse>> {
         let url = self.client.url("/verified_senders/steps_completed", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -88,6 +118,24 @@ impl SenderVerification {
         last_seen_id: f64,
         id: i64,
     ) -> ClientResult<crate::types::GetVerifiedSendersResponse> {
+        Ok(self
+            .get_verified_senders_with_response(limit, last_seen_id, id)
+            .await?
+            .body)
+    }
+    /**
+     * Get All Verified Senders.
+     *
+     * This function performs a `GET` to the `/verified_senders` endpoint.
+     *
+     * As opposed to `get_verified_senders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verified_senders_with_response(
+        &self,
+        limit: f64,
+        last_seen_id: f64,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetVerifiedSendersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if id > 0 {
             query_args.push(("id".to_string(), id.to_string()));
@@ -103,7 +151,7 @@ impl SenderVerification {
             .client
             .url(&format!("/verified_senders?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -129,9 +177,22 @@ impl SenderVerification {
         &self,
         body: &crate::types::VerifiedSenderRequestSchema,
     ) -> ClientResult<crate::types::VerifiedSenderResponseSchema> {
+        Ok(self.post_verified_sender_with_response(body).await?.body)
+    }
+    /**
+     * Create Verified Sender Request.
+     *
+     * This function performs a `POST` to the `/verified_senders` endpoint.
+     *
+     * As opposed to `post_verified_sender`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_verified_sender_with_response(
+        &self,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedSenderResponseSchema>> {
         let url = self.client.url("/verified_senders", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -150,6 +211,21 @@ impl SenderVerification {
      * The token is generated by SendGrid and included in a verification email delivered to the address that's pending verification.
      */
     pub async fn get_verified_senders_verify_token(&self, token: &str) -> ClientResult<()> {
+        self.get_verified_senders_verify_token_with_response(token)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Verify Sender Request.
+     *
+     * This function performs a `GET` to the `/verified_senders/verify/{token}` endpoint.
+     *
+     * As opposed to `get_verified_senders_verify_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verified_senders_verify_token_with_response(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/verified_senders/verify/{}",
@@ -158,7 +234,7 @@ impl SenderVerification {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -179,6 +255,19 @@ impl SenderVerification {
      * You can retrieve the IDs associated with Sender Identities using the "Get All Verified Senders" endpoint.
      */
     pub async fn delete_verified_senders(&self, id: &str) -> ClientResult<crate::types::Help> {
+        Ok(self.delete_verified_senders_with_response(id).await?.body)
+    }
+    /**
+     * Delete Verified Sender.
+     *
+     * This function performs a `DELETE` to the `/verified_senders/{id}` endpoint.
+     *
+     * As opposed to `delete_verified_senders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_verified_senders_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/verified_senders/{}",
@@ -187,7 +276,7 @@ impl SenderVerification {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -214,6 +303,23 @@ impl SenderVerification {
         id: &str,
         body: &crate::types::VerifiedSenderRequestSchema,
     ) -> ClientResult<crate::types::VerifiedSenderResponseSchema> {
+        Ok(self
+            .patch_verified_senders_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Edit Verified Sender.
+     *
+     * This function performs a `PATCH` to the `/verified_senders/{id}` endpoint.
+     *
+     * As opposed to `patch_verified_senders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_verified_senders_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> ClientResult<crate::Response<crate::types::VerifiedSenderResponseSchema>> {
         let url = self.client.url(
             &format!(
                 "/verified_senders/{}",
@@ -222,7 +328,7 @@ impl SenderVerification {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -243,6 +349,22 @@ impl SenderVerification {
      * You can retrieve the IDs associated with Sender Identities by passing a "Get All Verified Senders" endpoint.
      */
     pub async fn post_verified_senders_resend(&self, id: &str) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .post_verified_senders_resend_with_response(id)
+            .await?
+            .body)
+    }
+    /**
+     * Resend Verified Sender Request.
+     *
+     * This function performs a `POST` to the `/verified_senders/resend/{id}` endpoint.
+     *
+     * As opposed to `post_verified_senders_resend`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_verified_senders_resend_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/verified_senders/resend/{}",
@@ -251,7 +373,7 @@ impl SenderVerification {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -30,9 +30,22 @@ impl Senders {
         &self,
         body: &crate::types::PostMarketingSendersRequest,
     ) -> ClientResult<crate::types::SenderAllOf> {
+        Ok(self.post_marketing_with_response(body).await?.body)
+    }
+    /**
+     * Create a Sender Identity.
+     *
+     * This function performs a `POST` to the `/marketing/senders` endpoint.
+     *
+     * As opposed to `post_marketing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_with_response(
+        &self,
+        body: &crate::types::PostMarketingSendersRequest,
+    ) -> ClientResult<crate::Response<crate::types::SenderAllOf>> {
         let url = self.client.url("/marketing/senders", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -27,9 +27,21 @@ impl SettingsEnforcedTls {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_user(&self) -> ClientResult<crate::types::EnforcedTlsRequestResponse> {
+        Ok(self.get_user_with_response().await?.body)
+    }
+    /**
+     * Retrieve current Enforced TLS settings.
+     *
+     * This function performs a `GET` to the `/user/settings/enforced_tls` endpoint.
+     *
+     * As opposed to `get_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::EnforcedTlsRequestResponse>> {
         let url = self.client.url("/user/settings/enforced_tls", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,9 +69,22 @@ impl SettingsEnforcedTls {
         &self,
         body: &crate::types::EnforcedTlsRequestResponse,
     ) -> ClientResult<crate::types::EnforcedTlsRequestResponse> {
+        Ok(self.patch_user_with_response(body).await?.body)
+    }
+    /**
+     * Update Enforced TLS settings.
+     *
+     * This function performs a `PATCH` to the `/user/settings/enforced_tls` endpoint.
+     *
+     * As opposed to `patch_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_user_with_response(
+        &self,
+        body: &crate::types::EnforcedTlsRequestResponse,
+    ) -> ClientResult<crate::Response<crate::types::EnforcedTlsRequestResponse>> {
         let url = self.client.url("/user/settings/enforced_tls", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
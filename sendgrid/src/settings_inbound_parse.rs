@@ -34,9 +34,25 @@ impl SettingsInboundParse {
         &self,
         body: &crate::types::ParseSetting,
     ) -> ClientResult<crate::types::ParseSetting> {
+        Ok(self
+            .post_user_webhooks_parse_setting_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a parse setting.
+     *
+     * This function performs a `POST` to the `/user/webhooks/parse/settings` endpoint.
+     *
+     * As opposed to `post_user_webhooks_parse_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_user_webhooks_parse_setting_with_response(
+        &self,
+        body: &crate::types::ParseSetting,
+    ) -> ClientResult<crate::Response<crate::types::ParseSetting>> {
         let url = self.client.url("/user/webhooks/parse/settings", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -62,6 +78,22 @@ impl SettingsInboundParse {
         &self,
         hostname: &str,
     ) -> ClientResult<crate::types::ParseSetting> {
+        Ok(self
+            .get_user_webhooks_parse_settings_hostname_with_response(hostname)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a specific parse setting.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/settings/{hostname}` endpoint.
+     *
+     * As opposed to `get_user_webhooks_parse_settings_hostname`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_webhooks_parse_settings_hostname_with_response(
+        &self,
+        hostname: &str,
+    ) -> ClientResult<crate::Response<crate::types::ParseSetting>> {
         let url = self.client.url(
             &format!(
                 "/user/webhooks/parse/settings/{}",
@@ -70,7 +102,7 @@ impl SettingsInboundParse {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -96,6 +128,22 @@ impl SettingsInboundParse {
         &self,
         hostname: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_user_webhooks_parse_settings_hostname_with_response(hostname)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a parse setting.
+     *
+     * This function performs a `DELETE` to the `/user/webhooks/parse/settings/{hostname}` endpoint.
+     *
+     * As opposed to `delete_user_webhooks_parse_settings_hostname`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_user_webhooks_parse_settings_hostname_with_response(
+        &self,
+        hostname: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/user/webhooks/parse/settings/{}",
@@ -104,7 +152,7 @@ impl SettingsInboundParse {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -131,6 +179,23 @@ impl SettingsInboundParse {
         hostname: &str,
         body: &crate::types::ParseSetting,
     ) -> ClientResult<crate::types::ParseSetting> {
+        Ok(self
+            .patch_user_webhooks_parse_settings_hostname_with_response(hostname, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a parse setting.
+     *
+     * This function performs a `PATCH` to the `/user/webhooks/parse/settings/{hostname}` endpoint.
+     *
+     * As opposed to `patch_user_webhooks_parse_settings_hostname`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_user_webhooks_parse_settings_hostname_with_response(
+        &self,
+        hostname: &str,
+        body: &crate::types::ParseSetting,
+    ) -> ClientResult<crate::Response<crate::types::ParseSetting>> {
         let url = self.client.url(
             &format!(
                 "/user/webhooks/parse/settings/{}",
@@ -139,7 +204,7 @@ impl SettingsInboundParse {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -31,6 +31,23 @@ impl SettingsMail {
         limit: i64,
         offset: i64,
     ) -> ClientResult<crate::types::GetMailSettingsResponse> {
+        Ok(self
+            .get_mail_settings_with_response(limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings` endpoint.
+     *
+     * As opposed to `get_mail_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_settings_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetMailSettingsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -41,7 +58,7 @@ impl SettingsMail {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/mail_settings?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,9 +85,24 @@ impl SettingsMail {
     pub async fn get_mail_settings_address_whitelist(
         &self,
     ) -> ClientResult<crate::types::MailSettingsAddressWhitelabel> {
+        Ok(self
+            .get_mail_settings_address_whitelist_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve address whitelist mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings/address_whitelist` endpoint.
+     *
+     * As opposed to `get_mail_settings_address_whitelist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_settings_address_whitelist_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsAddressWhitelabel>> {
         let url = self.client.url("/mail_settings/address_whitelist", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -104,9 +136,25 @@ impl SettingsMail {
         &self,
         body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
     ) -> ClientResult<crate::types::MailSettingsAddressWhitelabel> {
+        Ok(self
+            .patch_mail_settings_address_whitelist_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update address whitelist mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/address_whitelist` endpoint.
+     *
+     * As opposed to `patch_mail_settings_address_whitelist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mail_settings_address_whitelist_with_response(
+        &self,
+        body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsAddressWhitelabel>> {
         let url = self.client.url("/mail_settings/address_whitelist", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -131,9 +179,21 @@ impl SettingsMail {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_mail_settings_footer(&self) -> ClientResult<crate::types::MailSettingsFooter> {
+        Ok(self.get_mail_settings_footer_with_response().await?.body)
+    }
+    /**
+     * Retrieve footer mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings/footer` endpoint.
+     *
+     * As opposed to `get_mail_settings_footer`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_settings_footer_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsFooter>> {
         let url = self.client.url("/mail_settings/footer", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -161,9 +221,25 @@ impl SettingsMail {
         &self,
         body: &crate::types::MailSettingsFooter,
     ) -> ClientResult<crate::types::MailSettingsFooter> {
+        Ok(self
+            .patch_mail_settings_footer_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update footer mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/footer` endpoint.
+     *
+     * As opposed to `patch_mail_settings_footer`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mail_settings_footer_with_response(
+        &self,
+        body: &crate::types::MailSettingsFooter,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsFooter>> {
         let url = self.client.url("/mail_settings/footer", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -188,9 +264,24 @@ impl SettingsMail {
     pub async fn get_mail_settings_forward_spam(
         &self,
     ) -> ClientResult<crate::types::MailSettingsForwardSpam> {
+        Ok(self
+            .get_mail_settings_forward_spam_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve forward spam mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings/forward_spam` endpoint.
+     *
+     * As opposed to `get_mail_settings_forward_spam`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_settings_forward_spam_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardSpam>> {
         let url = self.client.url("/mail_settings/forward_spam", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -229,9 +320,25 @@ impl SettingsMail {
         &self,
         body: &crate::types::MailSettingsForwardSpam,
     ) -> ClientResult<crate::types::MailSettingsForwardSpam> {
+        Ok(self
+            .patch_mail_settings_forward_spam_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update forward spam mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/forward_spam` endpoint.
+     *
+     * As opposed to `patch_mail_settings_forward_spam`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mail_settings_forward_spam_with_response(
+        &self,
+        body: &crate::types::MailSettingsForwardSpam,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardSpam>> {
         let url = self.client.url("/mail_settings/forward_spam", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -258,9 +365,21 @@ impl SettingsMail {
     pub async fn get_mail_settings_template(
         &self,
     ) -> ClientResult<crate::types::MailSettingsTemplate> {
+        Ok(self.get_mail_settings_template_with_response().await?.body)
+    }
+    /**
+     * Retrieve legacy template mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings/template` endpoint.
+     *
+     * As opposed to `get_mail_settings_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_settings_template_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsTemplate>> {
         let url = self.client.url("/mail_settings/template", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -288,9 +407,25 @@ impl SettingsMail {
         &self,
         body: &crate::types::PatchMailSettingsTemplateRequest,
     ) -> ClientResult<crate::types::PatchMailSettingsTemplateResponse> {
+        Ok(self
+            .patch_mail_settings_template_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update template mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/template` endpoint.
+     *
+     * As opposed to `patch_mail_settings_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mail_settings_template_with_response(
+        &self,
+        body: &crate::types::PatchMailSettingsTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::PatchMailSettingsTemplateResponse>> {
         let url = self.client.url("/mail_settings/template", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -321,9 +456,24 @@ impl SettingsMail {
     pub async fn get_mail_settings_bounce_purge(
         &self,
     ) -> ClientResult<crate::types::MailSettingsBouncePurge> {
+        Ok(self
+            .get_mail_settings_bounce_purge_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve bounce purge mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings/bounce_purge` endpoint.
+     *
+     * As opposed to `get_mail_settings_bounce_purge`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_settings_bounce_purge_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsBouncePurge>> {
         let url = self.client.url("/mail_settings/bounce_purge", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -355,9 +505,25 @@ impl SettingsMail {
         &self,
         body: &crate::types::MailSettingsBouncePurge,
     ) -> ClientResult<crate::types::MailSettingsBouncePurge> {
+        Ok(self
+            .patch_mail_settings_bounce_purge_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update bounce purge mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/bounce_purge` endpoint.
+     *
+     * As opposed to `patch_mail_settings_bounce_purge`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mail_settings_bounce_purge_with_response(
+        &self,
+        body: &crate::types::MailSettingsBouncePurge,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsBouncePurge>> {
         let url = self.client.url("/mail_settings/bounce_purge", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -382,9 +548,24 @@ impl SettingsMail {
     pub async fn get_mail_settings_forward_bounce(
         &self,
     ) -> ClientResult<crate::types::MailSettingsForwardBounce> {
+        Ok(self
+            .get_mail_settings_forward_bounce_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve forward bounce mail settings.
+     *
+     * This function performs a `GET` to the `/mail_settings/forward_bounce` endpoint.
+     *
+     * As opposed to `get_mail_settings_forward_bounce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mail_settings_forward_bounce_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardBounce>> {
         let url = self.client.url("/mail_settings/forward_bounce", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -412,9 +593,25 @@ impl SettingsMail {
         &self,
         body: &crate::types::MailSettingsForwardBounce,
     ) -> ClientResult<crate::types::MailSettingsForwardBounce> {
+        Ok(self
+            .patch_mail_settings_forward_bounce_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update forward bounce mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/forward_bounce` endpoint.
+     *
+     * As opposed to `patch_mail_settings_forward_bounce`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_mail_settings_forward_bounce_with_response(
+        &self,
+        body: &crate::types::MailSettingsForwardBounce,
+    ) -> ClientResult<crate::Response<crate::types::MailSettingsForwardBounce>> {
         let url = self.client.url("/mail_settings/forward_bounce", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
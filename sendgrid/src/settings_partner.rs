@@ -29,9 +29,24 @@ impl SettingsPartner {
     pub async fn get_partner_settings_new_relic(
         &self,
     ) -> ClientResult<crate::types::PartnerSettingsNewRelic> {
+        Ok(self
+            .get_partner_settings_new_relic_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Returns all New Relic partner settings.
+     *
+     * This function performs a `GET` to the `/partner_settings/new_relic` endpoint.
+     *
+     * As opposed to `get_partner_settings_new_relic`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_partner_settings_new_relic_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::PartnerSettingsNewRelic>> {
         let url = self.client.url("/partner_settings/new_relic", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,9 +74,25 @@ impl SettingsPartner {
         &self,
         body: &crate::types::PatchPartnerSettingsNewRelicRequest,
     ) -> ClientResult<crate::types::PartnerSettingsNewRelic> {
+        Ok(self
+            .patch_partner_settings_new_relic_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates New Relic partner settings.
+     *
+     * This function performs a `PATCH` to the `/partner_settings/new_relic` endpoint.
+     *
+     * As opposed to `patch_partner_settings_new_relic`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_partner_settings_new_relic_with_response(
+        &self,
+        body: &crate::types::PatchPartnerSettingsNewRelicRequest,
+    ) -> ClientResult<crate::Response<crate::types::PartnerSettingsNewRelic>> {
         let url = self.client.url("/partner_settings/new_relic", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -90,6 +121,23 @@ impl SettingsPartner {
         limit: i64,
         offset: i64,
     ) -> ClientResult<crate::types::GetPartnerSettingsResponse> {
+        Ok(self
+            .get_partner_settings_with_response(limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Returns a list of all partner settings.
+     *
+     * This function performs a `GET` to the `/partner_settings` endpoint.
+     *
+     * As opposed to `get_partner_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_partner_settings_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::GetPartnerSettingsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -102,7 +150,7 @@ impl SettingsPartner {
             .client
             .url(&format!("/partner_settings?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -25,9 +25,21 @@ impl SettingsTracking {
     pub async fn get_tracking_settings(
         &self,
     ) -> ClientResult<crate::types::GetTrackingSettingsResponse> {
+        Ok(self.get_tracking_settings_with_response().await?.body)
+    }
+    /**
+     * Retrieve Tracking Settings.
+     *
+     * This function performs a `GET` to the `/tracking_settings` endpoint.
+     *
+     * As opposed to `get_tracking_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_tracking_settings_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSettingsResponse>> {
         let url = self.client.url("/tracking_settings", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -52,9 +64,21 @@ impl SettingsTracking {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_tracking_settings_click(&self) -> ClientResult<crate::types::ClickTracking> {
+        Ok(self.get_tracking_settings_click_with_response().await?.body)
+    }
+    /**
+     * Retrieve Click Track Settings.
+     *
+     * This function performs a `GET` to the `/tracking_settings/click` endpoint.
+     *
+     * As opposed to `get_tracking_settings_click`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_tracking_settings_click_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ClickTracking>> {
         let url = self.client.url("/tracking_settings/click", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -82,9 +106,25 @@ impl SettingsTracking {
         &self,
         body: &crate::types::PatchTrackingSettingsOpenRequest,
     ) -> ClientResult<crate::types::ClickTracking> {
+        Ok(self
+            .patch_tracking_settings_click_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Click Tracking Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/click` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_click`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_tracking_settings_click_with_response(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> ClientResult<crate::Response<crate::types::ClickTracking>> {
         let url = self.client.url("/tracking_settings/click", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -112,9 +152,24 @@ impl SettingsTracking {
     pub async fn get_tracking_settings_google_analytic(
         &self,
     ) -> ClientResult<crate::types::GoogleAnalyticsSettings> {
+        Ok(self
+            .get_tracking_settings_google_analytic_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve Google Analytics Settings.
+     *
+     * This function performs a `GET` to the `/tracking_settings/google_analytics` endpoint.
+     *
+     * As opposed to `get_tracking_settings_google_analytic`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_tracking_settings_google_analytic_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GoogleAnalyticsSettings>> {
         let url = self.client.url("/tracking_settings/google_analytics", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -142,9 +197,25 @@ impl SettingsTracking {
         &self,
         body: &crate::types::GoogleAnalyticsSettings,
     ) -> ClientResult<crate::types::GoogleAnalyticsSettings> {
+        Ok(self
+            .patch_tracking_settings_google_analytics_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Google Analytics Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/google_analytics` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_google_analytics`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_tracking_settings_google_analytics_with_response(
+        &self,
+        body: &crate::types::GoogleAnalyticsSettings,
+    ) -> ClientResult<crate::Response<crate::types::GoogleAnalyticsSettings>> {
         let url = self.client.url("/tracking_settings/google_analytics", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -173,9 +244,21 @@ impl SettingsTracking {
     pub async fn get_tracking_settings_open(
         &self,
     ) -> ClientResult<crate::types::GetTrackingSettingsOpenResponse> {
+        Ok(self.get_tracking_settings_open_with_response().await?.body)
+    }
+    /**
+     * Get Open Tracking Settings.
+     *
+     * This function performs a `GET` to the `/tracking_settings/open` endpoint.
+     *
+     * As opposed to `get_tracking_settings_open`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_tracking_settings_open_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSettingsOpenResponse>> {
         let url = self.client.url("/tracking_settings/open", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -205,9 +288,25 @@ impl SettingsTracking {
         &self,
         body: &crate::types::PatchTrackingSettingsOpenRequest,
     ) -> ClientResult<crate::types::GetTrackingSettingsOpenResponse> {
+        Ok(self
+            .patch_tracking_settings_open_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Open Tracking Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/open` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_open`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_tracking_settings_open_with_response(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSettingsOpenResponse>> {
         let url = self.client.url("/tracking_settings/open", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -232,9 +331,24 @@ impl SettingsTracking {
     pub async fn get_tracking_settings_subscription(
         &self,
     ) -> ClientResult<crate::types::SubscriptionTrackingSettings> {
+        Ok(self
+            .get_tracking_settings_subscription_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve Subscription Tracking Settings.
+     *
+     * This function performs a `GET` to the `/tracking_settings/subscription` endpoint.
+     *
+     * As opposed to `get_tracking_settings_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_tracking_settings_subscription_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionTrackingSettings>> {
         let url = self.client.url("/tracking_settings/subscription", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -260,9 +374,25 @@ impl SettingsTracking {
         &self,
         body: &crate::types::SubscriptionTrackingSettings,
     ) -> ClientResult<crate::types::SubscriptionTrackingSettings> {
+        Ok(self
+            .patch_tracking_settings_subscription_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Subscription Tracking Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/subscription` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_tracking_settings_subscription_with_response(
+        &self,
+        body: &crate::types::SubscriptionTrackingSettings,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionTrackingSettings>> {
         let url = self.client.url("/tracking_settings/subscription", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -30,6 +30,23 @@ impl SingleSends {
         page_size: i64,
         page_token: &str,
     ) -> ClientResult<crate::types::GetMarketingSinglesendsResponse> {
+        Ok(self
+            .get_marketing_singlesends_with_response(page_size, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get All Single Sends.
+     *
+     * This function performs a `GET` to the `/marketing/singlesends` endpoint.
+     *
+     * As opposed to `get_marketing_singlesends`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_marketing_singlesends_with_response(
+        &self,
+        page_size: i64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page_size > 0 {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -42,7 +59,7 @@ impl SingleSends {
             .client
             .url(&format!("/marketing/singlesends?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -64,9 +81,25 @@ impl SingleSends {
         &self,
         body: &crate::types::SinglesendRequest,
     ) -> ClientResult<crate::types::SinglesendResponseAllOf> {
+        Ok(self
+            .post_marketing_singlesend_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create Single Send.
+     *
+     * This function performs a `POST` to the `/marketing/singlesends` endpoint.
+     *
+     * As opposed to `post_marketing_singlesend`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_singlesend_with_response(
+        &self,
+        body: &crate::types::SinglesendRequest,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
         let url = self.client.url("/marketing/singlesends", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -91,6 +124,20 @@ impl SingleSends {
      * * `ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
      */
     pub async fn delete_marketing_singlesends(&self, ids: &[String]) -> ClientResult<()> {
+        self.delete_marketing_singlesends_with_response(ids).await?;
+        Ok(())
+    }
+    /**
+     * Bulk Delete Single Sends.
+     *
+     * This function performs a `DELETE` to the `/marketing/singlesends` endpoint.
+     *
+     * As opposed to `delete_marketing_singlesends`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_marketing_singlesends_with_response(
+        &self,
+        ids: &[String],
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ids.is_empty() {
             query_args.push(("ids".to_string(), ids.join(" ")));
@@ -100,7 +147,7 @@ impl SingleSends {
             .client
             .url(&format!("/marketing/singlesends?{}", query_), None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -122,6 +169,19 @@ impl SingleSends {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::SinglesendResponseAllOf> {
+        Ok(self.get_marketing_singlesend_with_response(id).await?.body)
+    }
+    /**
+     * Get Single Send by ID.
+     *
+     * This function performs a `GET` to the `/marketing/singlesends/{id}` endpoint.
+     *
+     * As opposed to `get_marketing_singlesend`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_marketing_singlesend_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/marketing/singlesends/{}",
@@ -130,7 +190,7 @@ impl SingleSends {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -155,6 +215,23 @@ impl SingleSends {
         id: &str,
         body: &crate::types::PostMarketingSinglesendsRequest,
     ) -> ClientResult<crate::types::SinglesendResponseAllOf> {
+        Ok(self
+            .post_marketing_singlesend_single_sends_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Duplicate Single Send.
+     *
+     * This function performs a `POST` to the `/marketing/singlesends/{id}` endpoint.
+     *
+     * As opposed to `post_marketing_singlesend_single_sends`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_singlesend_single_sends_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostMarketingSinglesendsRequest,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/marketing/singlesends/{}",
@@ -163,7 +240,7 @@ impl SingleSends {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -184,6 +261,21 @@ impl SingleSends {
      * Please note that a `DELETE` request is permanent, and your Single Send will not be recoverable after deletion.
      */
     pub async fn delete_marketing_singlesends_single_sends(&self, id: &str) -> ClientResult<()> {
+        self.delete_marketing_singlesends_single_sends_with_response(id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete Single Send by ID.
+     *
+     * This function performs a `DELETE` to the `/marketing/singlesends/{id}` endpoint.
+     *
+     * As opposed to `delete_marketing_singlesends_single_sends`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_marketing_singlesends_single_sends_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/marketing/singlesends/{}",
@@ -192,7 +284,7 @@ impl SingleSends {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +307,23 @@ impl SingleSends {
         id: &str,
         body: &crate::types::SinglesendRequest,
     ) -> ClientResult<crate::types::SinglesendResponseAllOf> {
+        Ok(self
+            .patch_marketing_singlesends_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Single Send.
+     *
+     * This function performs a `PATCH` to the `/marketing/singlesends/{id}` endpoint.
+     *
+     * As opposed to `patch_marketing_singlesends`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_marketing_singlesends_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::SinglesendRequest,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/marketing/singlesends/{}",
@@ -223,7 +332,7 @@ impl SingleSends {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -266,6 +375,24 @@ impl SingleSends {
         page_token: &str,
         body: &crate::types::SinglesendSearch,
     ) -> ClientResult<crate::types::GetMarketingSinglesendsResponse> {
+        Ok(self
+            .post_marketing_singlesends_search_with_response(page_size, page_token, body)
+            .await?
+            .body)
+    }
+    /**
+     * Get Single Sends Search.
+     *
+     * This function performs a `POST` to the `/marketing/singlesends/search` endpoint.
+     *
+     * As opposed to `post_marketing_singlesends_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_marketing_singlesends_search_with_response(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        body: &crate::types::SinglesendSearch,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page_size > 0 {
             query_args.push(("page_size".to_string(), page_size.to_string()));
@@ -278,7 +405,7 @@ impl SingleSends {
             .client
             .url(&format!("/marketing/singlesends/search?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -301,6 +428,23 @@ impl SingleSends {
         id: &str,
         body: &crate::types::PutMarketingSinglesendsScheduleRequest,
     ) -> ClientResult<crate::types::PutMarketingSinglesendsScheduleResponse> {
+        Ok(self
+            .put_marketing_singlesends_schedule_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Schedule Single Send.
+     *
+     * This function performs a `PUT` to the `/marketing/singlesends/{id}/schedule` endpoint.
+     *
+     * As opposed to `put_marketing_singlesends_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_marketing_singlesends_schedule_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PutMarketingSinglesendsScheduleRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutMarketingSinglesendsScheduleResponse>> {
         let url = self.client.url(
             &format!(
                 "/marketing/singlesends/{}/schedule",
@@ -309,7 +453,7 @@ impl SingleSends {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -331,6 +475,22 @@ impl SingleSends {
         &self,
         id: &str,
     ) -> ClientResult<crate::types::SinglesendSchedule> {
+        Ok(self
+            .delete_marketing_singlesends_schedule_with_response(id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete Single Send Schedule.
+     *
+     * This function performs a `DELETE` to the `/marketing/singlesends/{id}/schedule` endpoint.
+     *
+     * As opposed to `delete_marketing_singlesends_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_marketing_singlesends_schedule_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::SinglesendSchedule>> {
         let url = self.client.url(
             &format!(
                 "/marketing/singlesends/{}/schedule",
@@ -339,7 +499,7 @@ impl SingleSends {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -360,9 +520,25 @@ impl SingleSends {
     pub async fn get_marketing_singlesends_categories(
         &self,
     ) -> ClientResult<crate::types::GetMarketingSinglesendsCategoriesResponse> {
+        Ok(self
+            .get_marketing_singlesends_categories_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Get All Categories.
+     *
+     * This function performs a `GET` to the `/marketing/singlesends/categories` endpoint.
+     *
+     * As opposed to `get_marketing_singlesends_categories`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_marketing_singlesends_categories_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetMarketingSinglesendsCategoriesResponse>>
+    {
         let url = self.client.url("/marketing/singlesends/categories", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
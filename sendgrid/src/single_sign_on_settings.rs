@@ -28,6 +28,19 @@ impl SingleSignOnSettings {
         &self,
         si: bool,
     ) -> ClientResult<Vec<crate::types::SsoIntegrationAllOf>> {
+        Ok(self.get_sso_integrations_with_response(si).await?.body)
+    }
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_sso_integrations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sso_integrations_with_response(
+        &self,
+        si: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SsoIntegrationAllOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if si {
             query_args.push(("si".to_string(), si.to_string()));
@@ -37,7 +50,7 @@ impl SingleSignOnSettings {
             .client
             .url(&format!("/sso/integrations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,6 +92,77 @@ impl SingleSignOnSettings {
             )
             .await
     }
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_sso_integrations_pages_stream(
+        &self,
+        si: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::SsoIntegrationAllOf>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if si {
+            query_args.push(("si".to_string(), si.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/sso/integrations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SsoIntegrationAllOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_sso_integrations_stream(
+        &self,
+        si: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SsoIntegrationAllOf>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_sso_integrations_pages_stream(si, None))
+    }
     /**
      * Create an SSO Integration.
      *
@@ -90,9 +174,22 @@ impl SingleSignOnSettings {
         &self,
         body: &crate::types::CreateIntegrationRequest,
     ) -> ClientResult<crate::types::SsoIntegrationAllOf> {
+        Ok(self.post_sso_integration_with_response(body).await?.body)
+    }
+    /**
+     * Create an SSO Integration.
+     *
+     * This function performs a `POST` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `post_sso_integration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sso_integration_with_response(
+        &self,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
         let url = self.client.url("/sso/integrations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -119,6 +216,20 @@ impl SingleSignOnSettings {
         id: &str,
         si: bool,
     ) -> ClientResult<crate::types::SsoIntegrationAllOf> {
+        Ok(self.get_sso_integration_with_response(id, si).await?.body)
+    }
+    /**
+     * Get an SSO Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{id}` endpoint.
+     *
+     * As opposed to `get_sso_integration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sso_integration_with_response(
+        &self,
+        id: &str,
+        si: bool,
+    ) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if si {
             query_args.push(("si".to_string(), si.to_string()));
@@ -133,7 +244,7 @@ impl SingleSignOnSettings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -152,6 +263,20 @@ impl SingleSignOnSettings {
      * You can retrieve the IDs for your configurations from the response provided by the "Get All SSO Integrations" endpoint.
      */
     pub async fn delete_sso_integrations(&self, id: &str) -> ClientResult<()> {
+        self.delete_sso_integrations_with_response(id).await?;
+        Ok(())
+    }
+    /**
+     * Delete an SSO Integration.
+     *
+     * This function performs a `DELETE` to the `/sso/integrations/{id}` endpoint.
+     *
+     * As opposed to `delete_sso_integrations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_sso_integrations_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/sso/integrations/{}",
@@ -160,7 +285,7 @@ impl SingleSignOnSettings {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -188,6 +313,24 @@ impl SingleSignOnSettings {
         si: bool,
         body: &crate::types::CreateIntegrationRequest,
     ) -> ClientResult<crate::types::SsoIntegrationAllOf> {
+        Ok(self
+            .patch_sso_integrations_with_response(id, si, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update an SSO Integration.
+     *
+     * This function performs a `PATCH` to the `/sso/integrations/{id}` endpoint.
+     *
+     * As opposed to `patch_sso_integrations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_sso_integrations_with_response(
+        &self,
+        id: &str,
+        si: bool,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoIntegrationAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if si {
             query_args.push(("si".to_string(), si.to_string()));
@@ -202,7 +345,7 @@ impl SingleSignOnSettings {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,9 +24,22 @@ impl SingleSignOnTeammates {
         &self,
         body: &crate::types::SsoTeammateRequestAllOf,
     ) -> ClientResult<crate::types::SsoTeammateResponseAllOf> {
+        Ok(self.post_sso_teammate_with_response(body).await?.body)
+    }
+    /**
+     * Create SSO Teammate.
+     *
+     * This function performs a `POST` to the `/sso/teammates` endpoint.
+     *
+     * As opposed to `post_sso_teammate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sso_teammate_with_response(
+        &self,
+        body: &crate::types::SsoTeammateRequestAllOf,
+    ) -> ClientResult<crate::Response<crate::types::SsoTeammateResponseAllOf>> {
         let url = self.client.url("/sso/teammates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -51,6 +64,23 @@ impl SingleSignOnTeammates {
         username: &str,
         body: &crate::types::PatchSsoTeammatesUsernameRequest,
     ) -> ClientResult<crate::types::SsoTeammatesPatchResponseAllOf> {
+        Ok(self
+            .patch_sso_teammates_username_with_response(username, body)
+            .await?
+            .body)
+    }
+    /**
+     * Edit an SSO Teammate.
+     *
+     * This function performs a `PATCH` to the `/sso/teammates/{username}` endpoint.
+     *
+     * As opposed to `patch_sso_teammates_username`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_sso_teammates_username_with_response(
+        &self,
+        username: &str,
+        body: &crate::types::PatchSsoTeammatesUsernameRequest,
+    ) -> ClientResult<crate::Response<crate::types::SsoTeammatesPatchResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/sso/teammates/{}",
@@ -59,7 +89,7 @@ impl SingleSignOnTeammates {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -33,6 +33,25 @@ impl SpamReportsApi {
         limit: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::SpamReportsResponse>> {
+        Ok(self
+            .get_suppression_spam_reports_with_response(start_time, end_time, limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all spam reports.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `get_suppression_spam_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_spam_reports_with_response(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -51,7 +70,7 @@ impl SpamReportsApi {
             .client
             .url(&format!("/suppression/spam_reports?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -99,6 +118,89 @@ impl SpamReportsApi {
             )
             .await
     }
+    /**
+     * Retrieve all spam reports.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_spam_reports_pages_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::SpamReportsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/spam_reports?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SpamReportsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all spam reports.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_spam_reports_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SpamReportsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_suppression_spam_reports_pages_stream(start_time, end_time, offset, None),
+        )
+    }
     /**
      * Delete spam reports.
      *
@@ -121,9 +223,25 @@ impl SpamReportsApi {
         &self,
         body: &crate::types::DeleteSuppressionBlocksRequest,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_suppression_spam_reports_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Delete spam reports.
+     *
+     * This function performs a `DELETE` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `delete_suppression_spam_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_spam_reports_with_response(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url("/suppression/spam_reports", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -147,6 +265,22 @@ impl SpamReportsApi {
         &self,
         email: &str,
     ) -> ClientResult<Vec<crate::types::SpamReportsResponse>> {
+        Ok(self
+            .get_suppression_spam_reports_email_with_response(email)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a specific spam report.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports/{email}` endpoint.
+     *
+     * As opposed to `get_suppression_spam_reports_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_spam_reports_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SpamReportsResponse>>> {
         let url = self.client.url(
             &format!(
                 "/suppression/spam_reports/{}",
@@ -155,7 +289,7 @@ impl SpamReportsApi {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -194,6 +328,78 @@ impl SpamReportsApi {
             )
             .await
     }
+    /**
+     * Retrieve a specific spam report.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports_email`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_spam_reports_email_pages_stream(
+        &self,
+        email: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::SpamReportsResponse>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/suppression/spam_reports/{}",
+                crate::progenitor_support::encode_path(email),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SpamReportsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve a specific spam report.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports_email`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_spam_reports_email_stream(
+        &self,
+        email: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SpamReportsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_suppression_spam_reports_email_pages_stream(email, None),
+        )
+    }
     /**
      * Delete a specific spam report.
      *
@@ -211,6 +417,22 @@ impl SpamReportsApi {
         &self,
         email: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_suppression_spam_reports_email_with_response(email)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a specific spam report.
+     *
+     * This function performs a `DELETE` to the `/suppression/spam_reports/{email}` endpoint.
+     *
+     * As opposed to `delete_suppression_spam_reports_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_suppression_spam_reports_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/suppression/spam_reports/{}",
@@ -219,7 +441,7 @@ impl SpamReportsApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -36,6 +36,25 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetStatsResponseData>> {
+        Ok(self
+            .get_page_with_response(offset, aggregated_by, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetStatsResponseData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -52,7 +71,7 @@ impl Stats {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -104,6 +123,96 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetStatsResponseData>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetStatsResponseData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetStatsResponseData>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_pages_stream(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
     /**
      * Retrieve email statistics by country and state/province.
      *
@@ -133,6 +242,26 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetGeoStatsResponseData>> {
+        Ok(self
+            .get_geo_with_response(country, offset, aggregated_by, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_geo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_geo_with_response(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetGeoStatsResponseData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -152,7 +281,7 @@ impl Stats {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/geo/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -210,6 +339,102 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_all_geo`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_geo_pages_stream(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetGeoStatsResponseData>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !country.to_string().is_empty() {
+            query_args.push(("country".to_string(), country.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/geo/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetGeoStatsResponseData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_all_geo`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_geo_stream(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetGeoStatsResponseData>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_geo_pages_stream(
+            country,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
     /**
      * Retrieve email statistics by device type.
      *
@@ -246,6 +471,25 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetClientsStatsResponse>> {
+        Ok(self
+            .get_devices_with_response(offset, aggregated_by, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * As opposed to `get_devices`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_devices_with_response(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -262,7 +506,7 @@ impl Stats {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/devices/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -325,6 +569,96 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * As opposed to `get_all_devices`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_devices_pages_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetClientsStatsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/devices/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetClientsStatsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * As opposed to `get_all_devices`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_devices_stream(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_devices_pages_stream(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
     /**
      * Retrieve email statistics by client type.
      *
@@ -349,6 +683,24 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> ClientResult<Vec<crate::types::GetClientsStatsResponse>> {
+        Ok(self
+            .get_clients_with_response(start_date, end_date, aggregated_by)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_clients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_clients_with_response(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -362,7 +714,7 @@ impl Stats {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/clients/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -412,6 +764,90 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_all_clients`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_clients_pages_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetClientsStatsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/clients/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetClientsStatsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_all_clients`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_clients_stream(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_clients_pages_stream(
+            start_date,
+            end_date,
+            aggregated_by,
+            None,
+        ))
+    }
     /**
      * Retrieve stats by a specific client type.
      *
@@ -443,6 +879,25 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> ClientResult<Vec<crate::types::GetClientsStatsResponse>> {
+        Ok(self
+            .get_clients_client_type_with_response(client_type, start_date, end_date, aggregated_by)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_clients_client_type`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_clients_client_type_with_response(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetClientsStatsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -463,7 +918,7 @@ impl Stats {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -527,6 +982,100 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_all_clients_client_type`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_clients_client_type_pages_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetClientsStatsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/clients/{}/stats?{}",
+                crate::progenitor_support::encode_path(&client_type.to_string()),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetClientsStatsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_all_clients_client_type`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_clients_client_type_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetClientsStatsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_clients_client_type_pages_stream(
+            client_type,
+            start_date,
+            end_date,
+            aggregated_by,
+            None,
+        ))
+    }
     /**
      * Retrieve email statistics by mailbox provider.
      *
@@ -556,6 +1105,33 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        Ok(self
+            .get_mailbox_providers_with_response(
+                mailbox_providers,
+                offset,
+                aggregated_by,
+                start_date,
+                end_date,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_mailbox_providers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mailbox_providers_with_response(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetMailboxProvidersStatsResponseData>>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -580,7 +1156,7 @@ impl Stats {
             .client
             .url(&format!("/mailbox_providers/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -643,6 +1219,108 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_all_mailbox_providers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_mailbox_providers_pages_stream(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetMailboxProvidersStatsResponseData>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !mailbox_providers.is_empty() {
+            query_args.push((
+                "mailbox_providers".to_string(),
+                mailbox_providers.to_string(),
+            ));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/mailbox_providers/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetMailboxProvidersStatsResponseData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_all_mailbox_providers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_mailbox_providers_stream(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetMailboxProvidersStatsResponseData>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_mailbox_providers_pages_stream(
+            mailbox_providers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
     /**
      * Retrieve email statistics by browser.
      *
@@ -672,6 +1350,26 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        Ok(self
+            .get_browsers_with_response(browsers, offset, aggregated_by, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_browsers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_browsers_with_response(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetBrowsersStatsResponseData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -693,7 +1391,7 @@ impl Stats {
             .client
             .url(&format!("/browsers/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -753,4 +1451,102 @@ impl Stats {
             )
             .await
     }
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_all_browsers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_browsers_pages_stream(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetBrowsersStatsResponseData>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !browsers.is_empty() {
+            query_args.push(("browsers".to_string(), browsers.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/browsers/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetBrowsersStatsResponseData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_all_browsers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_browsers_stream(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetBrowsersStatsResponseData>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_browsers_pages_stream(
+            browsers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
 }
@@ -20,6 +20,22 @@ impl SubuserMonitorSettings {
         &self,
         subuser_name: &str,
     ) -> ClientResult<crate::types::Monitor> {
+        Ok(self
+            .get_subusers_subuser_name_monitor_with_response(subuser_name)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve monitor settings for a subuser.
+     *
+     * This function performs a `GET` to the `/subusers/{subuser_name}/monitor` endpoint.
+     *
+     * As opposed to `get_subusers_subuser_name_monitor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subusers_subuser_name_monitor_with_response(
+        &self,
+        subuser_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Monitor>> {
         let url = self.client.url(
             &format!(
                 "/subusers/{}/monitor",
@@ -28,7 +44,7 @@ impl SubuserMonitorSettings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -47,6 +63,23 @@ impl SubuserMonitorSettings {
         subuser_name: &str,
         body: &crate::types::Monitor,
     ) -> ClientResult<crate::types::Monitor> {
+        Ok(self
+            .put_subusers_subuser_name_monitor_with_response(subuser_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Monitor Settings for a subuser.
+     *
+     * This function performs a `PUT` to the `/subusers/{subuser_name}/monitor` endpoint.
+     *
+     * As opposed to `put_subusers_subuser_name_monitor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_subusers_subuser_name_monitor_with_response(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> ClientResult<crate::Response<crate::types::Monitor>> {
         let url = self.client.url(
             &format!(
                 "/subusers/{}/monitor",
@@ -55,7 +88,7 @@ impl SubuserMonitorSettings {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -74,6 +107,23 @@ impl SubuserMonitorSettings {
         subuser_name: &str,
         body: &crate::types::Monitor,
     ) -> ClientResult<crate::types::Monitor> {
+        Ok(self
+            .post_subusers_subuser_name_monitor_with_response(subuser_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create monitor settings.
+     *
+     * This function performs a `POST` to the `/subusers/{subuser_name}/monitor` endpoint.
+     *
+     * As opposed to `post_subusers_subuser_name_monitor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_subusers_subuser_name_monitor_with_response(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> ClientResult<crate::Response<crate::types::Monitor>> {
         let url = self.client.url(
             &format!(
                 "/subusers/{}/monitor",
@@ -82,7 +132,7 @@ impl SubuserMonitorSettings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -100,6 +150,22 @@ impl SubuserMonitorSettings {
         &self,
         subuser_name: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_subusers_subuser_name_monitor_with_response(subuser_name)
+            .await?
+            .body)
+    }
+    /**
+     * Delete monitor settings.
+     *
+     * This function performs a `DELETE` to the `/subusers/{subuser_name}/monitor` endpoint.
+     *
+     * As opposed to `delete_subusers_subuser_name_monitor`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_subusers_subuser_name_monitor_with_response(
+        &self,
+        subuser_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/subusers/{}/monitor",
@@ -108,7 +174,7 @@ impl SubuserMonitorSettings {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
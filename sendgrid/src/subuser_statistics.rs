@@ -38,6 +38,34 @@ impl SubuserStatistics {
         limit: i64,
         offset: i64,
     ) -> ClientResult<crate::types::SubuserStatsData> {
+        Ok(self
+            .get_subusers_subuser_name_stats_monthly_with_response(
+                subuser_name,
+                date,
+                sort_by_metric,
+                sort_by_direction,
+                limit,
+                offset,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve the monthly email statistics for a single subuser.
+     *
+     * This function performs a `GET` to the `/subusers/{subuser_name}/stats/monthly` endpoint.
+     *
+     * As opposed to `get_subusers_subuser_name_stats_monthly`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subusers_subuser_name_stats_monthly_with_response(
+        &self,
+        subuser_name: &str,
+        date: &str,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date.is_empty() {
             query_args.push(("date".to_string(), date.to_string()));
@@ -67,7 +95,7 @@ impl SubuserStatistics {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -104,6 +132,34 @@ impl SubuserStatistics {
         limit: i64,
         offset: i64,
     ) -> ClientResult<crate::types::SubuserStatsData> {
+        Ok(self
+            .get_subusers_stats_monthly_with_response(
+                date,
+                subuser,
+                sort_by_metric,
+                sort_by_direction,
+                limit,
+                offset,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve monthly stats for all subusers.
+     *
+     * This function performs a `GET` to the `/subusers/stats/monthly` endpoint.
+     *
+     * As opposed to `get_subusers_stats_monthly`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subusers_stats_monthly_with_response(
+        &self,
+        date: &str,
+        subuser: &str,
+        sort_by_metric: crate::types::SortByMetric,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<crate::types::SubuserStatsData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date.is_empty() {
             query_args.push(("date".to_string(), date.to_string()));
@@ -131,7 +187,7 @@ impl SubuserStatistics {
             .client
             .url(&format!("/subusers/stats/monthly?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -167,6 +223,36 @@ impl SubuserStatistics {
         aggregated_by: &str,
         sort_by_metric: &str,
     ) -> ClientResult<crate::types::CategoryStats> {
+        Ok(self
+            .get_subusers_stats_sum_with_response(
+                sort_by_direction,
+                start_date,
+                end_date,
+                limit,
+                offset,
+                aggregated_by,
+                sort_by_metric,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve the totals for each email statistic metric for all subusers.
+     *
+     * This function performs a `GET` to the `/subusers/stats/sums` endpoint.
+     *
+     * As opposed to `get_subusers_stats_sum`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subusers_stats_sum_with_response(
+        &self,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: &str,
+        sort_by_metric: &str,
+    ) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -197,7 +283,7 @@ impl SubuserStatistics {
             .client
             .url(&format!("/subusers/stats/sums?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -233,6 +319,34 @@ impl SubuserStatistics {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<crate::types::CategoryStats> {
+        Ok(self
+            .get_subusers_stat_with_response(
+                limit,
+                offset,
+                aggregated_by,
+                subusers,
+                start_date,
+                end_date,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve email statistics for your subusers.
+     *
+     * This function performs a `GET` to the `/subusers/stats` endpoint.
+     *
+     * As opposed to `get_subusers_stat`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subusers_stat_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        subusers: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<crate::types::CategoryStats>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -257,7 +371,7 @@ impl SubuserStatistics {
             .client
             .url(&format!("/subusers/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -32,6 +32,24 @@ impl SubusersApi {
         limit: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::Subuser>> {
+        Ok(self
+            .get_subusers_with_response(username, limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_subusers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subusers_with_response(
+        &self,
+        username: &str,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subuser>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -45,7 +63,7 @@ impl SubusersApi {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/subusers?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -89,6 +107,77 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_all_subusers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_subusers_pages_stream(
+        &self,
+        username: &str,
+        offset: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Subuser>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !username.is_empty() {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/subusers?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Subuser>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_all_subusers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_subusers_stream(
+        &self,
+        username: &str,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subuser>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_subusers_pages_stream(username, offset, None))
+    }
     /**
      * Create Subuser.
      *
@@ -100,9 +189,22 @@ impl SubusersApi {
         &self,
         body: &crate::types::PostSubusersRequest,
     ) -> ClientResult<crate::types::SubuserPost> {
+        Ok(self.post_subuser_with_response(body).await?.body)
+    }
+    /**
+     * Create Subuser.
+     *
+     * This function performs a `POST` to the `/subusers` endpoint.
+     *
+     * As opposed to `post_subuser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_subuser_with_response(
+        &self,
+        body: &crate::types::PostSubusersRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubuserPost>> {
         let url = self.client.url("/subusers", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -124,6 +226,22 @@ impl SubusersApi {
         &self,
         subuser_name: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_subusers_subuser_name_with_response(subuser_name)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a subuser.
+     *
+     * This function performs a `DELETE` to the `/subusers/{subuser_name}` endpoint.
+     *
+     * As opposed to `delete_subusers_subuser_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_subusers_subuser_name_with_response(
+        &self,
+        subuser_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/subusers/{}",
@@ -132,7 +250,7 @@ impl SubusersApi {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -153,6 +271,23 @@ impl SubusersApi {
         subuser_name: &str,
         body: &crate::types::PatchSubusersSubuserNameRequest,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .patch_subusers_subuser_name_with_response(subuser_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Enable/disable a subuser.
+     *
+     * This function performs a `PATCH` to the `/subusers/{subuser_name}` endpoint.
+     *
+     * As opposed to `patch_subusers_subuser_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_subusers_subuser_name_with_response(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::PatchSubusersSubuserNameRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/subusers/{}",
@@ -161,7 +296,7 @@ impl SubusersApi {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -187,6 +322,22 @@ impl SubusersApi {
         &self,
         usernames: &str,
     ) -> ClientResult<Vec<crate::types::GetSubusersReputationsResponse>> {
+        Ok(self
+            .get_subusers_reputations_with_response(usernames)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_subusers_reputations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subusers_reputations_with_response(
+        &self,
+        usernames: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSubusersReputationsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !usernames.is_empty() {
             query_args.push(("usernames".to_string(), usernames.to_string()));
@@ -196,7 +347,7 @@ impl SubusersApi {
             .client
             .url(&format!("/subusers/reputations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -238,6 +389,78 @@ impl SubusersApi {
             )
             .await
     }
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_all_subusers_reputations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_subusers_reputations_pages_stream(
+        &self,
+        usernames: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetSubusersReputationsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !usernames.is_empty() {
+            query_args.push(("usernames".to_string(), usernames.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/subusers/reputations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetSubusersReputationsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_all_subusers_reputations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_subusers_reputations_stream(
+        &self,
+        usernames: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetSubusersReputationsResponse>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_subusers_reputations_pages_stream(usernames, None))
+    }
     /**
      * Update IPs assigned to a subuser.
      *
@@ -257,6 +480,23 @@ impl SubusersApi {
         subuser_name: &str,
         body: &[std::net::Ipv4Addr],
     ) -> ClientResult<crate::types::PutSubusersSubuserNameIpsResponse> {
+        Ok(self
+            .put_subusers_subuser_name_ips_with_response(subuser_name, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update IPs assigned to a subuser.
+     *
+     * This function performs a `PUT` to the `/subusers/{subuser_name}/ips` endpoint.
+     *
+     * As opposed to `put_subusers_subuser_name_ips`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_subusers_subuser_name_ips_with_response(
+        &self,
+        subuser_name: &str,
+        body: &[std::net::Ipv4Addr],
+    ) -> ClientResult<crate::Response<crate::types::PutSubusersSubuserNameIpsResponse>> {
         let url = self.client.url(
             &format!(
                 "/subusers/{}/ips",
@@ -265,7 +505,7 @@ impl SubusersApi {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
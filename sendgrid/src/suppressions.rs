@@ -23,6 +23,22 @@ impl Suppressions {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_asm_groups_group(&self, group_id: &str) -> ClientResult<Vec<String>> {
+        Ok(self
+            .get_asm_groups_group_with_response(group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `get_asm_groups_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_asm_groups_group_with_response(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}/suppressions",
@@ -31,7 +47,7 @@ impl Suppressions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -67,6 +83,69 @@ impl Suppressions {
             )
             .await
     }
+    /**
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm_groups_group`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_asm_groups_group_pages_stream(
+        &self,
+        group_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<String>>> + Unpin + '_ {
+        let url = self.client.url(
+            &format!(
+                "/asm/groups/{}/suppressions",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<String>) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm_groups_group`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_asm_groups_group_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<String>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_asm_groups_group_pages_stream(group_id, None))
+    }
     /**
      * Add suppressions to a suppression group.
      *
@@ -85,6 +164,23 @@ impl Suppressions {
         group_id: &str,
         body: &crate::types::SuppressionsRequestBody,
     ) -> ClientResult<crate::types::PostAsmGroupsGroupSuppressionsResponse> {
+        Ok(self
+            .post_asm_groups_group_with_response(group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add suppressions to a suppression group.
+     *
+     * This function performs a `POST` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `post_asm_groups_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_asm_groups_group_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> ClientResult<crate::Response<crate::types::PostAsmGroupsGroupSuppressionsResponse>> {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}/suppressions",
@@ -93,7 +189,7 @@ impl Suppressions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -120,6 +216,23 @@ impl Suppressions {
         group_id: &str,
         body: &crate::types::SuppressionsRequestBody,
     ) -> ClientResult<Vec<String>> {
+        Ok(self
+            .post_asm_groups_group_search_with_response(group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Search for suppressions within a group.
+     *
+     * This function performs a `POST` to the `/asm/groups/{group_id}/suppressions/search` endpoint.
+     *
+     * As opposed to `post_asm_groups_group_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_asm_groups_group_search_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> ClientResult<crate::Response<Vec<String>>> {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}/suppressions/search",
@@ -128,7 +241,7 @@ impl Suppressions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -149,9 +262,21 @@ impl Suppressions {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_asm(&self) -> ClientResult<Vec<crate::types::GetAsmSuppressionsResponse>> {
+        Ok(self.get_asm_with_response().await?.body)
+    }
+    /**
+     * Retrieve all suppressions.
+     *
+     * This function performs a `GET` to the `/asm/suppressions` endpoint.
+     *
+     * As opposed to `get_asm`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_asm_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAsmSuppressionsResponse>>> {
         let url = self.client.url("/asm/suppressions", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -181,6 +306,67 @@ impl Suppressions {
             )
             .await
     }
+    /**
+     * Retrieve all suppressions.
+     *
+     * This function performs a `GET` to the `/asm/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_asm_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetAsmSuppressionsResponse>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/asm/suppressions", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetAsmSuppressionsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all suppressions.
+     *
+     * This function performs a `GET` to the `/asm/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_asm_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetAsmSuppressionsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_asm_pages_stream(None))
+    }
     /**
      * Retrieve all suppression groups for an email address.
      *
@@ -196,6 +382,19 @@ impl Suppressions {
         &self,
         email: &str,
     ) -> ClientResult<crate::types::GetAsmSuppressionsEmailResponse> {
+        Ok(self.get_asm_email_with_response(email).await?.body)
+    }
+    /**
+     * Retrieve all suppression groups for an email address.
+     *
+     * This function performs a `GET` to the `/asm/suppressions/{email}` endpoint.
+     *
+     * As opposed to `get_asm_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_asm_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAsmSuppressionsEmailResponse>> {
         let url = self.client.url(
             &format!(
                 "/asm/suppressions/{}",
@@ -204,7 +403,7 @@ impl Suppressions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -231,6 +430,22 @@ impl Suppressions {
         group_id: &str,
         email: &str,
     ) -> ClientResult<()> {
+        self.delete_asm_groups_group_email_with_response(group_id, email)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a suppression from a suppression group.
+     *
+     * This function performs a `DELETE` to the `/asm/groups/{group_id}/suppressions/{email}` endpoint.
+     *
+     * As opposed to `delete_asm_groups_group_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_asm_groups_group_email_with_response(
+        &self,
+        group_id: &str,
+        email: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}/suppressions/{}",
@@ -240,7 +455,7 @@ impl Suppressions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
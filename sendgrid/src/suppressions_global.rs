@@ -26,9 +26,22 @@ impl SuppressionsGlobal {
         &self,
         body: &crate::types::SuppressionsRequestBody,
     ) -> ClientResult<crate::types::SuppressionsRequestBody> {
+        Ok(self.post_asm_with_response(body).await?.body)
+    }
+    /**
+     * Add recipient addresses to the global suppression group.
+     *
+     * This function performs a `POST` to the `/asm/suppressions/global` endpoint.
+     *
+     * As opposed to `post_asm`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_asm_with_response(
+        &self,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> ClientResult<crate::Response<crate::types::SuppressionsRequestBody>> {
         let url = self.client.url("/asm/suppressions/global", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -59,6 +72,25 @@ impl SuppressionsGlobal {
         limit: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        Ok(self
+            .get_suppression_unsubscribes_with_response(start_time, end_time, limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve all global suppressions.
+     *
+     * This function performs a `GET` to the `/suppression/unsubscribes` endpoint.
+     *
+     * As opposed to `get_suppression_unsubscribes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_suppression_unsubscribes_with_response(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetSuppressionUnsubscribesResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if end_time > 0 {
             query_args.push(("end_time".to_string(), end_time.to_string()));
@@ -77,7 +109,7 @@ impl SuppressionsGlobal {
             .client
             .url(&format!("/suppression/unsubscribes?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -125,6 +157,90 @@ impl SuppressionsGlobal {
             )
             .await
     }
+    /**
+     * Retrieve all global suppressions.
+     *
+     * This function performs a `GET` to the `/suppression/unsubscribes` endpoint.
+     *
+     * As opposed to `get_all_suppression_unsubscribes`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_suppression_unsubscribes_pages_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetSuppressionUnsubscribesResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if end_time > 0 {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if start_time > 0 {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/suppression/unsubscribes?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetSuppressionUnsubscribesResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all global suppressions.
+     *
+     * This function performs a `GET` to the `/suppression/unsubscribes` endpoint.
+     *
+     * As opposed to `get_all_suppression_unsubscribes`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_suppression_unsubscribes_stream(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetSuppressionUnsubscribesResponse>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(
+            self.get_all_suppression_unsubscribes_pages_stream(start_time, end_time, offset, None),
+        )
+    }
     /**
      * Retrieve a Global Suppression.
      *
@@ -142,6 +258,19 @@ impl SuppressionsGlobal {
         &self,
         email: &str,
     ) -> ClientResult<crate::types::RetrieveAGlobalSuppressionResponse> {
+        Ok(self.get_asm_email_with_response(email).await?.body)
+    }
+    /**
+     * Retrieve a Global Suppression.
+     *
+     * This function performs a `GET` to the `/asm/suppressions/global/{email}` endpoint.
+     *
+     * As opposed to `get_asm_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_asm_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::RetrieveAGlobalSuppressionResponse>> {
         let url = self.client.url(
             &format!(
                 "/asm/suppressions/global/{}",
@@ -150,7 +279,7 @@ impl SuppressionsGlobal {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -173,6 +302,19 @@ impl SuppressionsGlobal {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_asm_email(&self, email: &str) -> ClientResult<crate::types::Help> {
+        Ok(self.delete_asm_email_with_response(email).await?.body)
+    }
+    /**
+     * Delete a Global Suppression.
+     *
+     * This function performs a `DELETE` to the `/asm/suppressions/global/{email}` endpoint.
+     *
+     * As opposed to `delete_asm_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_asm_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/asm/suppressions/global/{}",
@@ -181,7 +323,7 @@ impl SuppressionsGlobal {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
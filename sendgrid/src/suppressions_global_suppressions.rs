@@ -16,16 +16,16 @@ impl SuppressionsGlobal {
     }
 
     /**
-* Add recipient addresses to the global suppression group.
-*
-* This function performs a `POST` to the `/asm/suppressions/global` endpoint.
-*
-* **This endpoint allows you to add one or more email addresses to the global suppressions group.**
-*
-* **Parameters:**
-*
-* * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-*/
+     * Add recipient addresses to the global suppression group.
+     *
+     * This function performs a `POST` to the `/asm/suppressions/global` endpoint.
+     *
+     * **This endpoint allows you to add one or more email addresses to the global suppressions group.**
+     *
+     * **Parameters:**
+     *
+     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     */
 pub async fn post_asm_suppressions_global(
 &self,
 on_behalf_of: &str,
@@ -16,16 +16,16 @@ impl Suppressions {
     }
 
     /**
-* Retrieve all suppressions for a suppression group.
-*
-* This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
-*
-* **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
-*
-* **Parameters:**
-*
-* * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-*/
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
+     *
+     * **Parameters:**
+     *
+     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     */
 pub async fn get_asm_groups_group_suppressions(
 &self,
 group_id: &str, on_behalf_of: &str,
@@ -29,6 +29,19 @@ impl SuppressionsUnsubscribeGroups {
         &self,
         id: i64,
     ) -> ClientResult<Vec<crate::types::SuppressionGroup>> {
+        Ok(self.get_asm_groups_with_response(id).await?.body)
+    }
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_asm_groups`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_asm_groups_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SuppressionGroup>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if id > 0 {
             query_args.push(("id".to_string(), id.to_string()));
@@ -36,7 +49,7 @@ impl SuppressionsUnsubscribeGroups {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/asm/groups?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -76,6 +89,74 @@ impl SuppressionsUnsubscribeGroups {
             )
             .await
     }
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_all_asm_groups`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_asm_groups_pages_stream(
+        &self,
+        id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SuppressionGroup>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if id > 0 {
+            query_args.push(("id".to_string(), id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/asm/groups?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::SuppressionGroup>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_all_asm_groups`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_asm_groups_stream(
+        &self,
+        id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SuppressionGroup>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_asm_groups_pages_stream(id, None))
+    }
     /**
      * Create a new suppression group.
      *
@@ -93,9 +174,22 @@ impl SuppressionsUnsubscribeGroups {
         &self,
         body: &crate::types::SuppressionGroupRequestBase,
     ) -> ClientResult<crate::types::PostAsmGroupsResponse> {
+        Ok(self.post_asm_group_with_response(body).await?.body)
+    }
+    /**
+     * Create a new suppression group.
+     *
+     * This function performs a `POST` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `post_asm_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_asm_group_with_response(
+        &self,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> ClientResult<crate::Response<crate::types::PostAsmGroupsResponse>> {
         let url = self.client.url("/asm/groups", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -119,6 +213,22 @@ impl SuppressionsUnsubscribeGroups {
         &self,
         group_id: &str,
     ) -> ClientResult<crate::types::GetAsmGroupsGroupResponseAllOf> {
+        Ok(self
+            .get_asm_groups_group_with_response(group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get information on a single suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}` endpoint.
+     *
+     * As opposed to `get_asm_groups_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_asm_groups_group_with_response(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAsmGroupsGroupResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}",
@@ -127,7 +237,7 @@ impl SuppressionsUnsubscribeGroups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -155,6 +265,22 @@ impl SuppressionsUnsubscribeGroups {
         &self,
         group_id: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_asm_groups_group_with_response(group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a Suppression Group.
+     *
+     * This function performs a `DELETE` to the `/asm/groups/{group_id}` endpoint.
+     *
+     * As opposed to `delete_asm_groups_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_asm_groups_group_with_response(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}",
@@ -163,7 +289,7 @@ impl SuppressionsUnsubscribeGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -188,6 +314,23 @@ impl SuppressionsUnsubscribeGroups {
         group_id: &str,
         body: &crate::types::SuppressionGroupRequestBase,
     ) -> ClientResult<crate::types::SuppressionGroup> {
+        Ok(self
+            .patch_asm_groups_group_with_response(group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a suppression group.
+     *
+     * This function performs a `PATCH` to the `/asm/groups/{group_id}` endpoint.
+     *
+     * As opposed to `patch_asm_groups_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_asm_groups_group_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> ClientResult<crate::Response<crate::types::SuppressionGroup>> {
         let url = self.client.url(
             &format!(
                 "/asm/groups/{}",
@@ -196,7 +339,7 @@ impl SuppressionsUnsubscribeGroups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
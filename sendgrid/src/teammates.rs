@@ -31,6 +31,20 @@ impl Teammates {
         limit: u64,
         offset: u64,
     ) -> ClientResult<crate::types::GetTeammatesResponse> {
+        Ok(self.get_with_response(limit, offset).await?.body)
+    }
+    /**
+     * Retrieve all teammates.
+     *
+     * This function performs a `GET` to the `/teammates` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        limit: u64,
+        offset: u64,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !limit.to_string().is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -41,7 +55,7 @@ impl Teammates {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/teammates?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,9 +83,22 @@ impl Teammates {
         &self,
         body: &crate::types::PostTeammatesRequest,
     ) -> ClientResult<crate::types::PostTeammatesResponse> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Invite teammate.
+     *
+     * This function performs a `POST` to the `/teammates` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostTeammatesRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostTeammatesResponse>> {
         let url = self.client.url("/teammates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -97,6 +124,22 @@ impl Teammates {
         &self,
         token: &str,
     ) -> ClientResult<crate::types::PostTeammatesResponse> {
+        Ok(self
+            .post_pending_token_resend_with_response(token)
+            .await?
+            .body)
+    }
+    /**
+     * Resend teammate invite.
+     *
+     * This function performs a `POST` to the `/teammates/pending/{token}/resend` endpoint.
+     *
+     * As opposed to `post_pending_token_resend`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_pending_token_resend_with_response(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostTeammatesResponse>> {
         let url = self.client.url(
             &format!(
                 "/teammates/pending/{}/resend",
@@ -105,7 +148,7 @@ impl Teammates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -133,6 +176,23 @@ impl Teammates {
         limit: i64,
         offset: i64,
     ) -> ClientResult<Vec<crate::types::GetScopesRequestsResponse>> {
+        Ok(self
+            .get_scopes_requests_with_response(limit, offset)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve access requests.
+     *
+     * This function performs a `GET` to the `/scopes/requests` endpoint.
+     *
+     * As opposed to `get_scopes_requests`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_scopes_requests_with_response(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetScopesRequestsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
@@ -145,7 +205,7 @@ impl Teammates {
             .client
             .url(&format!("/scopes/requests?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -187,6 +247,77 @@ impl Teammates {
             )
             .await
     }
+    /**
+     * Retrieve access requests.
+     *
+     * This function performs a `GET` to the `/scopes/requests` endpoint.
+     *
+     * As opposed to `get_all_scopes_requests`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_scopes_requests_pages_stream(
+        &self,
+        offset: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetScopesRequestsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/scopes/requests?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetScopesRequestsResponse>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieve access requests.
+     *
+     * This function performs a `GET` to the `/scopes/requests` endpoint.
+     *
+     * As opposed to `get_all_scopes_requests`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_scopes_requests_stream(
+        &self,
+        offset: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetScopesRequestsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_scopes_requests_pages_stream(offset, None))
+    }
     /**
      * Retrieve all pending teammates.
      *
@@ -201,9 +332,21 @@ impl Teammates {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_pending(&self) -> ClientResult<crate::types::GetTeammatesPendingResponse> {
+        Ok(self.get_pending_with_response().await?.body)
+    }
+    /**
+     * Retrieve all pending teammates.
+     *
+     * This function performs a `GET` to the `/teammates/pending` endpoint.
+     *
+     * As opposed to `get_pending`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_pending_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesPendingResponse>> {
         let url = self.client.url("/teammates/pending", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -229,6 +372,19 @@ impl Teammates {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::GetTeammatesUsernameResponse> {
+        Ok(self.get_username_with_response(username).await?.body)
+    }
+    /**
+     * Retrieve specific teammate.
+     *
+     * This function performs a `GET` to the `/teammates/{username}` endpoint.
+     *
+     * As opposed to `get_username`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_username_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesUsernameResponse>> {
         let url = self.client.url(
             &format!(
                 "/teammates/{}",
@@ -237,7 +393,7 @@ impl Teammates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -263,6 +419,19 @@ impl Teammates {
         &self,
         username: &str,
     ) -> ClientResult<crate::types::PostSendersResponse> {
+        Ok(self.delete_username_with_response(username).await?.body)
+    }
+    /**
+     * Delete teammate.
+     *
+     * This function performs a `DELETE` to the `/teammates/{username}` endpoint.
+     *
+     * As opposed to `delete_username`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_username_with_response(
+        &self,
+        username: &str,
+    ) -> ClientResult<crate::Response<crate::types::PostSendersResponse>> {
         let url = self.client.url(
             &format!(
                 "/teammates/{}",
@@ -271,7 +440,7 @@ impl Teammates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -302,6 +471,23 @@ impl Teammates {
         username: &str,
         body: &crate::types::PatchTeammatesUsernameRequest,
     ) -> ClientResult<crate::types::GetTeammatesUsernameResponse> {
+        Ok(self
+            .patch_username_with_response(username, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update teammate's permissions.
+     *
+     * This function performs a `PATCH` to the `/teammates/{username}` endpoint.
+     *
+     * As opposed to `patch_username`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_username_with_response(
+        &self,
+        username: &str,
+        body: &crate::types::PatchTeammatesUsernameRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetTeammatesUsernameResponse>> {
         let url = self.client.url(
             &format!(
                 "/teammates/{}",
@@ -310,7 +496,7 @@ impl Teammates {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -332,6 +518,22 @@ impl Teammates {
         &self,
         request_id: &str,
     ) -> ClientResult<crate::types::PatchScopesRequestsApproveResponse> {
+        Ok(self
+            .patch_scopes_requests_approve_with_response(request_id)
+            .await?
+            .body)
+    }
+    /**
+     * Approve access request.
+     *
+     * This function performs a `PATCH` to the `/scopes/requests/{request_id}/approve` endpoint.
+     *
+     * As opposed to `patch_scopes_requests_approve`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_scopes_requests_approve_with_response(
+        &self,
+        request_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PatchScopesRequestsApproveResponse>> {
         let url = self.client.url(
             &format!(
                 "/scopes/requests/{}/approve",
@@ -340,7 +542,7 @@ impl Teammates {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -359,6 +561,21 @@ impl Teammates {
      * **Note:** Only teammate admins may delete a teammate's access request.
      */
     pub async fn delete_scopes_requests_request(&self, request_id: &str) -> ClientResult<()> {
+        self.delete_scopes_requests_request_with_response(request_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deny access request.
+     *
+     * This function performs a `DELETE` to the `/scopes/requests/{request_id}` endpoint.
+     *
+     * As opposed to `delete_scopes_requests_request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_scopes_requests_request_with_response(
+        &self,
+        request_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/scopes/requests/{}",
@@ -367,7 +584,7 @@ impl Teammates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -388,6 +605,20 @@ impl Teammates {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_pending_token(&self, token: &str) -> ClientResult<()> {
+        self.delete_pending_token_with_response(token).await?;
+        Ok(())
+    }
+    /**
+     * Delete pending teammate.
+     *
+     * This function performs a `DELETE` to the `/teammates/pending/{token}` endpoint.
+     *
+     * As opposed to `delete_pending_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_pending_token_with_response(
+        &self,
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/teammates/pending/{}",
@@ -396,7 +627,7 @@ impl Teammates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
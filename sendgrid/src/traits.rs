@@ -86,6 +86,7 @@ impl MailOps for crate::mail_send::MailSend {
             s => Err(ClientError::HttpError {
                 status: s,
                 error: "Posting to /mail/send".to_string(),
+                request_id: crate::request_id(resp.headers()),
             }),
         }
     }
@@ -31,6 +31,24 @@ impl TransactionalTemplates {
         page_size: f64,
         page_token: &str,
     ) -> ClientResult<crate::types::GetTemplatesResponse> {
+        Ok(self
+            .get_templates_with_response(generations, page_size, page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve paged transactional templates.
+     *
+     * This function performs a `GET` to the `/templates` endpoint.
+     *
+     * As opposed to `get_templates`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_templates_with_response(
+        &self,
+        generations: crate::types::Generations,
+        page_size: f64,
+        page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTemplatesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !generations.to_string().is_empty() {
             query_args.push(("generations".to_string(), generations.to_string()));
@@ -44,7 +62,7 @@ impl TransactionalTemplates {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/templates?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,9 +86,22 @@ impl TransactionalTemplates {
         &self,
         body: &crate::types::PostTemplatesRequest,
     ) -> ClientResult<crate::types::TransactionalTemplateAllOf> {
+        Ok(self.post_template_with_response(body).await?.body)
+    }
+    /**
+     * Create a transactional template.
+     *
+     * This function performs a `POST` to the `/templates` endpoint.
+     *
+     * As opposed to `post_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_template_with_response(
+        &self,
+        body: &crate::types::PostTemplatesRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
         let url = self.client.url("/templates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -94,6 +125,22 @@ impl TransactionalTemplates {
         &self,
         template_id: &str,
     ) -> ClientResult<crate::types::TransactionalTemplateAllOf> {
+        Ok(self
+            .get_templates_template_with_response(template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a single transactional template.
+     *
+     * This function performs a `GET` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `get_templates_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_templates_template_with_response(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}",
@@ -102,7 +149,7 @@ impl TransactionalTemplates {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,6 +174,23 @@ impl TransactionalTemplates {
         template_id: &str,
         body: &crate::types::PostTemplatesTemplateRequest,
     ) -> ClientResult<crate::types::TransactionalTemplateAllOf> {
+        Ok(self
+            .post_templates_template_with_response(template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Duplicate a transactional template.
+     *
+     * This function performs a `POST` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `post_templates_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_templates_template_with_response(
+        &self,
+        template_id: &str,
+        body: &crate::types::PostTemplatesTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}",
@@ -135,7 +199,7 @@ impl TransactionalTemplates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -159,6 +223,22 @@ impl TransactionalTemplates {
         &self,
         template_id: &str,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self
+            .delete_templates_template_with_response(template_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a template.
+     *
+     * This function performs a `DELETE` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `delete_templates_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_templates_template_with_response(
+        &self,
+        template_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}",
@@ -167,7 +247,7 @@ impl TransactionalTemplates {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -194,6 +274,23 @@ impl TransactionalTemplates {
         template_id: &str,
         body: &crate::types::PatchTemplatesTemplateRequest,
     ) -> ClientResult<crate::types::TransactionalTemplateAllOf> {
+        Ok(self
+            .patch_templates_template_with_response(template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Edit a transactional template.
+     *
+     * This function performs a `PATCH` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `patch_templates_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_templates_template_with_response(
+        &self,
+        template_id: &str,
+        body: &crate::types::PatchTemplatesTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateAllOf>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}",
@@ -202,7 +299,7 @@ impl TransactionalTemplates {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
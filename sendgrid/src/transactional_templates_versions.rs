@@ -27,6 +27,23 @@ impl TransactionalTemplatesVersions {
         template_id: &str,
         body: &crate::types::TransactionalTemplateVersionCreate,
     ) -> ClientResult<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        Ok(self
+            .post_templates_template_version_with_response(template_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a new transactional template version.
+     *
+     * This function performs a `POST` to the `/templates/{template_id}/versions` endpoint.
+     *
+     * As opposed to `post_templates_template_version`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_templates_template_version_with_response(
+        &self,
+        template_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}/versions",
@@ -35,7 +52,7 @@ impl TransactionalTemplatesVersions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -60,6 +77,26 @@ impl TransactionalTemplatesVersions {
         template_id: &str,
         version_id: &str,
     ) -> ClientResult<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        Ok(self
+            .post_templates_template_versions_version_activate_with_response(
+                template_id,
+                version_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Activate a transactional template version.
+     *
+     * This function performs a `POST` to the `/templates/{template_id}/versions/{version_id}/activate` endpoint.
+     *
+     * As opposed to `post_templates_template_versions_version_activate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_templates_template_versions_version_activate_with_response(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}/versions/{}/activate",
@@ -69,7 +106,7 @@ impl TransactionalTemplatesVersions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -94,6 +131,23 @@ impl TransactionalTemplatesVersions {
         template_id: &str,
         version_id: &str,
     ) -> ClientResult<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        Ok(self
+            .get_templates_template_versions_version_with_response(template_id, version_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a specific transactional template version.
+     *
+     * This function performs a `GET` to the `/templates/{template_id}/versions/{version_id}` endpoint.
+     *
+     * As opposed to `get_templates_template_versions_version`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_templates_template_versions_version_with_response(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}/versions/{}",
@@ -103,7 +157,7 @@ impl TransactionalTemplatesVersions {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -128,6 +182,22 @@ impl TransactionalTemplatesVersions {
         template_id: &str,
         version_id: &str,
     ) -> ClientResult<()> {
+        self.delete_templates_template_versions_version_with_response(template_id, version_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a transactional template version.
+     *
+     * This function performs a `DELETE` to the `/templates/{template_id}/versions/{version_id}` endpoint.
+     *
+     * As opposed to `delete_templates_template_versions_version`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_templates_template_versions_version_with_response(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}/versions/{}",
@@ -137,7 +207,7 @@ impl TransactionalTemplatesVersions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -163,6 +233,24 @@ impl TransactionalTemplatesVersions {
         version_id: &str,
         body: &crate::types::TransactionalTemplateVersionCreate,
     ) -> ClientResult<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        Ok(self
+            .patch_templates_template_versions_version_with_response(template_id, version_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Edit a transactional template version.
+     *
+     * This function performs a `PATCH` to the `/templates/{template_id}/versions/{version_id}` endpoint.
+     *
+     * As opposed to `patch_templates_template_versions_version`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_templates_template_versions_version_with_response(
+        &self,
+        template_id: &str,
+        version_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> ClientResult<crate::Response<crate::types::TransactionalTemplateVersionOutputAllOf>> {
         let url = self.client.url(
             &format!(
                 "/templates/{}/versions/{}",
@@ -172,7 +260,7 @@ impl TransactionalTemplatesVersions {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4,13 +4,16 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PartnerSettingsNewRelic {
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enable_subuser_statistics: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -30,35 +33,53 @@ pub struct PartnerSettingsNewRelic {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubscriptionTrackingSettings {
+    /**
+     * Indicates if subscription tracking is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /**
+     * The information and HTML for your unsubscribe link.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
+    /**
+     * The HTML that will be displayed on the page that your customers will see after clicking unsubscribe, hosted on SendGrid’s server.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub landing: String,
+    /**
+     * The information in plain text for your unsubscribe link. You should have the “<% %>” tag in your content, otherwise the user will have no URL for unsubscribing.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub plain_content: String,
+    /**
+     * Your custom defined replacement tag for your templates. Use this tag to place your unsubscribe content anywhere in your emailtemplate.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub replace: String,
+    /**
+     * The URL where you would like your users sent to unsubscribe.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -69,18 +90,12 @@ pub struct SubscriptionTrackingSettings {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Errors {
-    /**
-     * The indices of the recipient(s) sent that caused the error.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub error_indices: Vec<f64>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -100,6 +115,9 @@ pub struct ContactDbRecipientResponse {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub error_count: f64,
+    /**
+     * The indices of the recipient(s) sent that caused the error.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -113,7 +131,7 @@ pub struct ContactDbRecipientResponse {
     )]
     pub errors: Vec<Errors>,
     /**
-     * The number of errors found while adding recipients.
+     * The count of new recipients added to the contactdb.
      */
     #[serde(
         default,
@@ -131,7 +149,7 @@ pub struct ContactDbRecipientResponse {
     )]
     pub persisted_recipients: Vec<String>,
     /**
-     * The number of errors found while adding recipients.
+     * The recipients who were updated from this request.
      */
     #[serde(
         default,
@@ -150,7 +168,7 @@ pub struct CampaignResponse {
     )]
     pub id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The status of your campaign.
      */
     #[serde(
         default,
@@ -174,6 +192,7 @@ pub struct CampaignResponseAllOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Operator {
     #[serde(rename = "contains")]
     Contains,
@@ -187,8 +206,9 @@ pub enum Operator {
     Ne,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Operator {
@@ -200,12 +220,35 @@ impl std::fmt::Display for Operator {
             Operator::Lt => "lt",
             Operator::Ne => "ne",
             Operator::Noop => "",
-            Operator::FallthroughString => "*",
+            Operator::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Operator {
+    fn from(s: String) -> Operator {
+        match s.as_str() {
+            "contains" => Operator::Contains,
+            "eq" => Operator::Eq,
+            "gt" => Operator::Gt,
+            "lt" => Operator::Lt,
+            "ne" => Operator::Ne,
+            "" => Operator::Noop,
+            _ => Operator::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Operator> for String {
+    fn from(e: Operator) -> String {
+        match e {
+            Operator::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Operator {
     fn default() -> Operator {
         Operator::Noop
@@ -218,6 +261,7 @@ impl Operator {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AndOr {
     #[serde(rename = "and")]
     And,
@@ -225,8 +269,9 @@ pub enum AndOr {
     Or,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AndOr {
@@ -235,12 +280,32 @@ impl std::fmt::Display for AndOr {
             AndOr::And => "and",
             AndOr::Or => "or",
             AndOr::Noop => "",
-            AndOr::FallthroughString => "*",
+            AndOr::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AndOr {
+    fn from(s: String) -> AndOr {
+        match s.as_str() {
+            "and" => AndOr::And,
+            "or" => AndOr::Or,
+            "" => AndOr::Noop,
+            _ => AndOr::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AndOr> for String {
+    fn from(e: AndOr) -> String {
+        match e {
+            AndOr::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AndOr {
     fn default() -> AndOr {
         AndOr::Noop
@@ -256,9 +321,6 @@ impl AndOr {
 pub struct ContactdbSegmentsConditions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub and_or: Option<AndOr>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -267,9 +329,6 @@ pub struct ContactdbSegmentsConditions {
     pub field: String,
     #[serde(default, skip_serializing_if = "Operator::is_noop")]
     pub operator: Operator,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -280,24 +339,36 @@ pub struct ContactdbSegmentsConditions {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BounceResponse {
+    /**
+     * The unix timestamp for when the bounce record was created at SendGrid.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub created: f64,
+    /**
+     * The email address that was added to the bounce list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The reason for the bounce. This typically will be a bounce code, an enhanced code, and a description.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reason: String,
+    /**
+     * Enhanced SMTP bounce response
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -378,6 +449,9 @@ pub struct Contacts {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Users {
+    /**
+     * The ID of a user who can send mail from the IP address.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -385,7 +459,7 @@ pub struct Users {
     )]
     pub user_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The username of a user who can send mail from the IP address.
      */
     #[serde(
         default,
@@ -399,7 +473,7 @@ pub struct Users {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Dkim {
     /**
-     * The license key provided with your New Relic account.
+     * The IP address being set up with Reverse DNS.
      */
     #[serde(
         default,
@@ -408,7 +482,7 @@ pub struct Dkim {
     )]
     pub data: String,
     /**
-     * The license key provided with your New Relic account.
+     * This is the web address that will be mapped to the IP address.
      */
     #[serde(
         default,
@@ -417,7 +491,7 @@ pub struct Dkim {
     )]
     pub host: String,
     /**
-     * The license key provided with your New Relic account.
+     * The type of DNS record.
      */
     #[serde(
         default,
@@ -427,7 +501,7 @@ pub struct Dkim {
     )]
     pub type_: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the a_record is valid.
      */
     #[serde(
         default,
@@ -438,13 +512,10 @@ pub struct Dkim {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReverseDns {
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
     #[serde()]
     pub a_record: Dkim,
     /**
-     * The license key provided with your New Relic account.
+     * The root, or sending, domain.
      */
     #[serde(
         default,
@@ -452,6 +523,9 @@ pub struct ReverseDns {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub domain: String,
+    /**
+     * The ID of the Reverse DNS.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -459,7 +533,7 @@ pub struct ReverseDns {
     )]
     pub id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The IP address that this Reverse DNS was created for.
      */
     #[serde(
         default,
@@ -467,6 +541,9 @@ pub struct ReverseDns {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /**
+     * A Unix epoch timestamp representing the last time of a validation attempt.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -474,7 +551,7 @@ pub struct ReverseDns {
     )]
     pub last_validation_attempt_at: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this Reverse DNS was created using the legacy whitelabel tool. If it is a legacy whitelabel, it will still function, but you'll need to create a new Reverse DNS if you need to update it.
      */
     #[serde(
         default,
@@ -482,7 +559,7 @@ pub struct ReverseDns {
     )]
     pub legacy: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The reverse DNS record for the IP address. This points to the Reverse DNS subdomain.
      */
     #[serde(
         default,
@@ -490,6 +567,9 @@ pub struct ReverseDns {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rdns: String,
+    /**
+     * The subdomain created for this reverse DNS. This is where the rDNS record points.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -506,7 +586,7 @@ pub struct ReverseDns {
     )]
     pub users: Vec<Users>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is a valid Reverse DNS.
      */
     #[serde(
         default,
@@ -517,12 +597,18 @@ pub struct ReverseDns {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SenderId {
+    /**
+     * The time the sender identity was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub created_at: i64,
+    /**
+     * The unique identifier of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -530,13 +616,16 @@ pub struct SenderId {
     )]
     pub id: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * True when the sender id is associated to a campaign in the Draft, Scheduled, or In Progress status. You cannot update or delete a locked sender identity.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub locked: bool,
+    /**
+     * The time the sender identity was last updated.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -544,7 +633,7 @@ pub struct SenderId {
     )]
     pub updated_at: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * If the sender identity is verified or not. Only verified sender identities can be used to send email.
      */
     #[serde(
         default,
@@ -576,6 +665,7 @@ pub struct SenderAllOf {
 * The type of the field.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "date")]
     Date,
@@ -585,8 +675,9 @@ pub enum Type {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -596,12 +687,33 @@ impl std::fmt::Display for Type {
             Type::Number => "number",
             Type::Text => "text",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "date" => Type::Date,
+            "number" => Type::Number,
+            "text" => Type::Text,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -615,12 +727,18 @@ impl Type {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactdbCustomField {
+    /**
+     * The name of the field
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The type of the field.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<Type>,
 }
@@ -628,7 +746,7 @@ pub struct ContactdbCustomField {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Subuser {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Whether or not the user is enabled or disabled.
      */
     #[serde(
         default,
@@ -636,7 +754,7 @@ pub struct Subuser {
     )]
     pub disabled: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The email address to contact this subuser.
      */
     #[serde(
         default,
@@ -645,7 +763,7 @@ pub struct Subuser {
     )]
     pub email: String,
     /**
-     * The number of errors found while adding recipients.
+     * The ID of this subuser.
      */
     #[serde(
         default,
@@ -654,7 +772,7 @@ pub struct Subuser {
     )]
     pub id: f64,
     /**
-     * The license key provided with your New Relic account.
+     * The name by which this subuser will be referred.
      */
     #[serde(
         default,
@@ -666,11 +784,17 @@ pub struct Subuser {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettingsAddressWhitelabel {
+    /**
+     * Indicates if you have an email address whitelist enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /**
+     * All email addresses that are currently on the whitelist.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -683,6 +807,7 @@ pub struct MailSettingsAddressWhitelabel {
 * The type of DNS record that was generated.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LinkBranding200ResponseDnsDomainCnameType {
     #[serde(rename = "cname")]
     Cname,
@@ -692,8 +817,9 @@ pub enum LinkBranding200ResponseDnsDomainCnameType {
     Txt,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
@@ -703,12 +829,33 @@ impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
             LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
             LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
             LinkBranding200ResponseDnsDomainCnameType::Noop => "",
-            LinkBranding200ResponseDnsDomainCnameType::FallthroughString => "*",
+            LinkBranding200ResponseDnsDomainCnameType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LinkBranding200ResponseDnsDomainCnameType {
+    fn from(s: String) -> LinkBranding200ResponseDnsDomainCnameType {
+        match s.as_str() {
+            "cname" => LinkBranding200ResponseDnsDomainCnameType::Cname,
+            "mx" => LinkBranding200ResponseDnsDomainCnameType::Mx,
+            "txt" => LinkBranding200ResponseDnsDomainCnameType::Txt,
+            "" => LinkBranding200ResponseDnsDomainCnameType::Noop,
+            _ => LinkBranding200ResponseDnsDomainCnameType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LinkBranding200ResponseDnsDomainCnameType> for String {
+    fn from(e: LinkBranding200ResponseDnsDomainCnameType) -> String {
+        match e {
+            LinkBranding200ResponseDnsDomainCnameType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LinkBranding200ResponseDnsDomainCnameType {
     fn default() -> LinkBranding200ResponseDnsDomainCnameType {
         LinkBranding200ResponseDnsDomainCnameType::Noop
@@ -724,7 +871,7 @@ impl LinkBranding200ResponseDnsDomainCnameType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DomainCname {
     /**
-     * The license key provided with your New Relic account.
+     * The domain that the DNS record points to.
      */
     #[serde(
         default,
@@ -733,7 +880,7 @@ pub struct DomainCname {
     )]
     pub data: String,
     /**
-     * The license key provided with your New Relic account.
+     * The domain that this link branding will use for the links in your email.
      */
     #[serde(
         default,
@@ -751,7 +898,7 @@ pub struct DomainCname {
     )]
     pub type_: LinkBranding200ResponseDnsDomainCnameType,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the DNS record is valid.
      */
     #[serde(
         default,
@@ -764,7 +911,7 @@ pub struct DomainCname {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OwnerCname {
     /**
-     * The license key provided with your New Relic account.
+     * The domain that the DNS record points to.
      */
     #[serde(
         default,
@@ -773,7 +920,7 @@ pub struct OwnerCname {
     )]
     pub data: String,
     /**
-     * The license key provided with your New Relic account.
+     * Used to verify the link branding. The subdomain of this domain is the ID of the user who created the link branding.
      */
     #[serde(
         default,
@@ -782,12 +929,12 @@ pub struct OwnerCname {
     )]
     pub host: String,
     /**
-     * The DNS record generated to verify who created the link branding.
+     * The type of DNS record generated.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<LinkBranding200ResponseDnsDomainCnameType>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the DNS record is valid.
      */
     #[serde(
         default,
@@ -805,7 +952,7 @@ pub struct Dns {
     #[serde()]
     pub domain_cname: DomainCname,
     /**
-     * The DNS records generated for this link branding.
+     * The DNS record generated to verify who created the link branding.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner_cname: Option<OwnerCname>,
@@ -814,7 +961,7 @@ pub struct Dns {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LinkBranding200Response {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is the default link branding.
      */
     #[serde(
         default,
@@ -827,7 +974,7 @@ pub struct LinkBranding200Response {
     #[serde()]
     pub dns: Dns,
     /**
-     * The license key provided with your New Relic account.
+     * The root domain of the branded link.
      */
     #[serde(
         default,
@@ -835,6 +982,9 @@ pub struct LinkBranding200Response {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub domain: String,
+    /**
+     * The ID of the branded link.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -842,19 +992,25 @@ pub struct LinkBranding200Response {
     )]
     pub id: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this link branding was created using the legacy whitelabel tool. If it is a legacy whitelabel, it will still function, but you'll need to create new link branding if you need to update it.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub legacy: bool,
+    /**
+     * The subdomain used to generate the DNS records for this link branding. This subdomain must be different from the subdomain used for your authenticated domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subdomain: String,
+    /**
+     * The ID of the user that this link branding is associated with.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -862,7 +1018,7 @@ pub struct LinkBranding200Response {
     )]
     pub user_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The username of the account that this link branding is associated with.
      */
     #[serde(
         default,
@@ -871,7 +1027,7 @@ pub struct LinkBranding200Response {
     )]
     pub username: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this link branding is valid.
      */
     #[serde(
         default,
@@ -883,7 +1039,7 @@ pub struct LinkBranding200Response {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FromEmailObject {
     /**
-     * The license key provided with your New Relic account.
+     * The 'From' email address used to deliver the message. This address should be a verified sender in your Twilio SendGrid account.
      */
     #[serde(
         default,
@@ -891,6 +1047,9 @@ pub struct FromEmailObject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * A name or title associated with the sending email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -902,7 +1061,7 @@ pub struct FromEmailObject {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApiKeyNameScopes {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The permissions this API Key has access to.
      */
     #[serde(
         default,
@@ -936,6 +1095,9 @@ pub struct ContactdbSegments {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub conditions: Vec<ContactdbSegmentsConditions>,
+    /**
+     * The list id from which to make this segment. Not including this ID will mean your segment is created from the main contactdb rather than a list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -943,7 +1105,7 @@ pub struct ContactdbSegments {
     )]
     pub list_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The name of this segment.
      */
     #[serde(
         default,
@@ -951,6 +1113,9 @@ pub struct ContactdbSegments {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The count of recipients in this list. This is not included on creation of segments.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -961,12 +1126,18 @@ pub struct ContactdbSegments {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApiKeyNameId {
+    /**
+     * The ID of your API Key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub api_key_id: String,
+    /**
+     * The name of your API Key.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -979,7 +1150,7 @@ pub struct ApiKeyNameId {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdvancedStatsOpens {
     /**
-     * The individual events and their stats.
+     * The total number of times your emails were opened by recipients.
      */
     #[serde(
         default,
@@ -988,7 +1159,7 @@ pub struct AdvancedStatsOpens {
     )]
     pub opens: i64,
     /**
-     * The individual events and their stats.
+     * The number of unique recipients who opened your emails.
      */
     #[serde(
         default,
@@ -1000,11 +1171,17 @@ pub struct AdvancedStatsOpens {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettingsTemplate {
+    /**
+     * Indicates if the legacy email template setting is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /**
+     * The HTML content that you want to use for your legacy email template.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1016,7 +1193,7 @@ pub struct MailSettingsTemplate {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IpWarmupResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The IP address.
      */
     #[serde(
         default,
@@ -1024,6 +1201,9 @@ pub struct IpWarmupResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /**
+     * A Unix timestamp indicating when the IP address entered warmup mode.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1035,7 +1215,7 @@ pub struct IpWarmupResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Monitor {
     /**
-     * The license key provided with your New Relic account.
+     * The email address to which Sendgrid should send emails for monitoring.
      */
     #[serde(
         default,
@@ -1044,7 +1224,7 @@ pub struct Monitor {
     )]
     pub email: String,
     /**
-     * The number of errors found while adding recipients.
+     * The frequency at which to forward monitoring emails. An email will be sent when your subuser sends this many (e.g., 1,000) emails.
      */
     #[serde(
         default,
@@ -1057,7 +1237,7 @@ pub struct Monitor {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GlobalErrorResponseSchemaErrors {
     /**
-     * The license key provided with your New Relic account.
+     * the field that generated the error
      */
     #[serde(
         default,
@@ -1071,7 +1251,7 @@ pub struct GlobalErrorResponseSchemaErrors {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub help: Option<Help>,
     /**
-     * The license key provided with your New Relic account.
+     * the error message
      */
     #[serde(
         default,
@@ -1101,7 +1281,7 @@ pub struct GlobalErrorResponseSchema {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdvancedStatsMailboxProvider {
     /**
-     * The individual events and their stats.
+     * The number of emails that were not allowed to be delivered by ISPs.
      */
     #[serde(
         default,
@@ -1110,7 +1290,7 @@ pub struct AdvancedStatsMailboxProvider {
     )]
     pub blocks: i64,
     /**
-     * The individual events and their stats.
+     * The number of emails that bounced instead of being delivered.
      */
     #[serde(
         default,
@@ -1119,7 +1299,7 @@ pub struct AdvancedStatsMailboxProvider {
     )]
     pub bounces: i64,
     /**
-     * The individual events and their stats.
+     * The number of emails that temporarily could not be delivered.
      */
     #[serde(
         default,
@@ -1128,7 +1308,7 @@ pub struct AdvancedStatsMailboxProvider {
     )]
     pub deferred: i64,
     /**
-     * The individual events and their stats.
+     * The number of emails SendGrid was able to confirm were actually delivered to a recipient.
      */
     #[serde(
         default,
@@ -1137,7 +1317,7 @@ pub struct AdvancedStatsMailboxProvider {
     )]
     pub delivered: i64,
     /**
-     * The individual events and their stats.
+     * The number of emails that were not delivered due to the recipient email address being on a suppression list.
      */
     #[serde(
         default,
@@ -1146,7 +1326,7 @@ pub struct AdvancedStatsMailboxProvider {
     )]
     pub drops: i64,
     /**
-     * The individual events and their stats.
+     * Requests from your website, application, or mail client via SMTP Relay or the Web API that SendGrid processed.
      */
     #[serde(
         default,
@@ -1155,7 +1335,7 @@ pub struct AdvancedStatsMailboxProvider {
     )]
     pub processed: i64,
     /**
-     * The individual events and their stats.
+     * The number of emails that were requested to be delivered.
      */
     #[serde(
         default,
@@ -1164,7 +1344,7 @@ pub struct AdvancedStatsMailboxProvider {
     )]
     pub requests: i64,
     /**
-     * The individual events and their stats.
+     * The number of recipients who marked your email as spam.
      */
     #[serde(
         default,
@@ -1193,7 +1373,7 @@ pub struct AdvancedStatsMailboxProviderAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactdbCustomFieldWithId {
     /**
-     * The number of errors found while adding recipients.
+     * The ID of the custom field.
      */
     #[serde(
         default,
@@ -1219,7 +1399,7 @@ pub struct ContactdbCustomFieldWithAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IpPool {
     /**
-     * The license key provided with your New Relic account.
+     * The name of the IP pool.
      */
     #[serde(
         default,
@@ -1231,35 +1411,53 @@ pub struct IpPool {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GoogleAnalyticsSettings {
+    /**
+     * Indicates if Google Analytics is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /**
+     * The name of the campaign.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub utm_campaign: String,
+    /**
+     * Used to differentiate ads
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub utm_content: String,
+    /**
+     * Name of the marketing medium (e.g. "Email").
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub utm_medium: String,
+    /**
+     * Name of the referrer source.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub utm_source: String,
+    /**
+     * Any paid keywords.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1271,7 +1469,7 @@ pub struct GoogleAnalyticsSettings {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebhooksEventWebhookResponse {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Receiving server could not or would not accept message.
      */
     #[serde(
         default,
@@ -1279,7 +1477,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub bounce: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient clicked on a link within the message. You need to enable Click Tracking for getting this type of event.
      */
     #[serde(
         default,
@@ -1287,7 +1485,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub click: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient's email server temporarily rejected message.
      */
     #[serde(
         default,
@@ -1295,7 +1493,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub deferred: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Message has been successfully delivered to the receiving server.
      */
     #[serde(
         default,
@@ -1303,7 +1501,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub delivered: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * You may see the following drop reasons: Invalid SMTPAPI header, Spam Content (if spam checker app enabled), Unsubscribed Address, Bounced Address, Spam Reporting Address, Invalid, Recipient List over Package Quota
      */
     #[serde(
         default,
@@ -1311,7 +1509,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub dropped: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the event webhook is enabled.
      */
     #[serde(
         default,
@@ -1319,7 +1517,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub enabled: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient resubscribes to specific group by updating preferences. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(
         default,
@@ -1327,19 +1525,25 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub group_resubscribe: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient unsubscribe from specific group, by either direct link or updating preferences. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub group_unsubscribe: bool,
+    /**
+     * The client ID Twilio SendGrid sends to your OAuth server or service provider to generate an OAuth access token.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub oauth_client_id: String,
+    /**
+     * The URL where Twilio SendGrid sends the Client ID and Client Secret to generate an access token. This should be your OAuth server or service provider.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1347,7 +1551,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub oauth_token_url: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient has opened the HTML message. You need to enable Open Tracking for getting this type of event.
      */
     #[serde(
         default,
@@ -1355,7 +1559,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub open: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Message has been received and is ready to be delivered.
      */
     #[serde(
         default,
@@ -1363,7 +1567,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub processed: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient marked a message as spam.
      */
     #[serde(
         default,
@@ -1371,7 +1575,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub spam_report: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient clicked on message's subscription management link. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(
         default,
@@ -1379,7 +1583,7 @@ pub struct WebhooksEventWebhookResponse {
     )]
     pub unsubscribe: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The URL that you want the event webhook to POST to.
      */
     #[serde(
         default,
@@ -1391,12 +1595,18 @@ pub struct WebhooksEventWebhookResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserProfile {
+    /**
+     * The street address for this user profile.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address: String,
+    /**
+     * An optional second line for the street address of this user profile.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1404,54 +1614,81 @@ pub struct UserProfile {
         rename = "address2"
     )]
     pub address_2: String,
+    /**
+     * The city for the user profile.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
+    /**
+     * That company that this user profile is associated with.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub company: String,
+    /**
+     * Th country of this user profile.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
+    /**
+     * The first name of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
+    /**
+     * The last name of the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /**
+     * The phone number for the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone: String,
+    /**
+     * The state for this user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /**
+     * The website associated with this user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub website: String,
+    /**
+     * The zip code for this user.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1462,17 +1699,26 @@ pub struct UserProfile {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettingsFooter {
+    /**
+     * Indicates if the Footer mail setting is currently enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /**
+     * The custom HTML content of your email footer.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
+    /**
+     * The plain text content of your email footer.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1483,96 +1729,144 @@ pub struct MailSettingsFooter {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Metrics {
+    /**
+     * The number of emails that were not allowed to be delivered by ISPs.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub blocks: i64,
+    /**
+     * The number of emails that were dropped because of a bounce.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bounce_drops: i64,
+    /**
+     * The number of emails that bounced instead of being delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bounces: i64,
+    /**
+     * The number of links that were clicked.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub clicks: i64,
+    /**
+     * The number of emails that temporarily could not be delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub deferred: i64,
+    /**
+     * The number of emails SendGrid was able to confirm were actually delivered to a recipient.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub delivered: i64,
+    /**
+     * The number of recipients who had malformed email addresses or whose mail provider reported the address as invalid.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub invalid_emails: i64,
+    /**
+     * The total number of times your emails were opened by recipients.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub opens: i64,
+    /**
+     * Requests from your website, application, or mail client via SMTP Relay or the API that SendGrid processed.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub processed: i64,
+    /**
+     * The number of emails that were requested to be delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub requests: i64,
+    /**
+     * The number of emails that were dropped due to a recipient previously marking your emails as spam.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub spam_report_drops: i64,
+    /**
+     * The number of recipients who marked your email as spam.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub spam_reports: i64,
+    /**
+     * The number of unique recipients who clicked links in your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_clicks: i64,
-    #[serde(
+    /**
+     * The number of unique recipients who opened your emails.
+     */
+    #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /**
+     * The number of emails dropped due to a recipient unsubscribing from your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribe_drops: i64,
+    /**
+     * The number of recipients who unsubscribed from your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1586,7 +1880,7 @@ pub struct Stats {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the category.
      */
     #[serde(
         default,
@@ -1595,7 +1889,7 @@ pub struct Stats {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * How you are segmenting your statistics.
      */
     #[serde(
         default,
@@ -1609,7 +1903,7 @@ pub struct Stats {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CategoryStats {
     /**
-     * The license key provided with your New Relic account.
+     * The date the statistics were gathered.
      */
     #[serde(
         default,
@@ -1627,22 +1921,34 @@ pub struct CategoryStats {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ParseSetting {
+    /**
+     * A specific and unique domain or subdomain that you have created to use exclusively to parse your incoming email. For example, `parse.yourdomain.com`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub hostname: String,
+    /**
+     * Indicates if you would like SendGrid to post the original MIME-type content of your parsed email. When this parameter is set to `true`, SendGrid will send a JSON payload of the content of your email.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub send_raw: bool,
+    /**
+     * Indicates if you would like SendGrid to check the content parsed from your emails for spam before POSTing them to your domain.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub spam_check: bool,
+    /**
+     * The public URL where you would like SendGrid to POST the data parsed from your email. Any emails sent with the given hostname provided (whose MX records have been updated to point to SendGrid) will be parsed and POSTed to this URL.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1672,6 +1978,9 @@ pub struct TransactionalTemplateAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactdbList {
+    /**
+     * The reference ID of your list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1679,7 +1988,7 @@ pub struct ContactdbList {
     )]
     pub id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The name of your list. Must be unique against all other list and segment names.
      */
     #[serde(
         default,
@@ -1687,6 +1996,9 @@ pub struct ContactdbList {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The count of recipients currently in the list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1698,7 +2010,7 @@ pub struct ContactdbList {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SuppressionGroup {
     /**
-     * The license key provided with your New Relic account.
+     * A description of the suppression group.
      */
     #[serde(
         default,
@@ -1707,7 +2019,7 @@ pub struct SuppressionGroup {
     )]
     pub description: String,
     /**
-     * The number of errors found while adding recipients.
+     * The id of the suppression group.
      */
     #[serde(
         default,
@@ -1715,6 +2027,9 @@ pub struct SuppressionGroup {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub id: f64,
+    /**
+     * Indicates if this is the default suppression group.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -1723,7 +2038,7 @@ pub struct SuppressionGroup {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_email_sent_at: Option<serde_json::Value>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the suppression group. Each group created by a user must have a unique name.
      */
     #[serde(
         default,
@@ -1731,6 +2046,9 @@ pub struct SuppressionGroup {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The unsubscribes associated with this group.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1741,17 +2059,26 @@ pub struct SuppressionGroup {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettingsBouncePurge {
+    /**
+     * Indicates if the bounce purge mail setting is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub enabled: bool,
+    /**
+     * The number of days after which SendGrid will purge all contacts from your hard bounces suppression lists.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub hard_bounces: i64,
+    /**
+     * The number of days after which SendGrid will purge all contacts from your soft bounces suppression lists.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1788,27 +2115,18 @@ pub struct TransactionalTemplateVersionOutputAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Permissions {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub api: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mail: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1831,12 +2149,18 @@ pub struct Credentials {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettingsForwardSpam {
+    /**
+     * The email address where you would like the spam reports to be forwarded.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * Indicates if the Forward Spam setting is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -1848,6 +2172,7 @@ pub struct MailSettingsForwardSpam {
 * The editor used in the UI.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Editor {
     #[serde(rename = "code")]
     Code,
@@ -1855,8 +2180,9 @@ pub enum Editor {
     Design,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Editor {
@@ -1865,12 +2191,32 @@ impl std::fmt::Display for Editor {
             Editor::Code => "code",
             Editor::Design => "design",
             Editor::Noop => "",
-            Editor::FallthroughString => "*",
+            Editor::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Editor {
+    fn from(s: String) -> Editor {
+        match s.as_str() {
+            "code" => Editor::Code,
+            "design" => Editor::Design,
+            "" => Editor::Noop,
+            _ => Editor::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Editor> for String {
+    fn from(e: Editor) -> String {
+        match e {
+            Editor::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Editor {
     fn default() -> Editor {
         Editor::Noop
@@ -1884,26 +2230,41 @@ impl Editor {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CampaignsRequest {
+    /**
+     * The categories you would like associated to this campaign.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub categories: Vec<String>,
+    /**
+     * This is the url of the custom unsubscribe page that you provide for customers to unsubscribe from your suppression groups.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_unsubscribe_url: String,
+    /**
+     * The editor used in the UI.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub editor: Option<Editor>,
+    /**
+     * The HTML of your marketing email.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
+    /**
+     * The pool of IPs that you would like to send this email from.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1919,6 +2280,9 @@ pub struct CampaignsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<i64>,
+    /**
+     * The plain text content of your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -1926,7 +2290,7 @@ pub struct CampaignsRequest {
     )]
     pub plain_content: String,
     /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
+     * The segment IDs that you are sending this list to. You can have both segment IDs and list IDs. Segments are limited to 10 segment IDs.
      */
     #[serde(
         default,
@@ -1934,18 +2298,27 @@ pub struct CampaignsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub segment_ids: Vec<i64>,
+    /**
+     * The ID of the "sender" identity that you have created. Your recipients will see this as the "from" on your marketing emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sender_id: i64,
+    /**
+     * The subject of your campaign that your recipients will see.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /**
+     * The suppression group that this marketing email belongs to, allowing recipients to opt-out of emails of this type.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -1953,7 +2326,7 @@ pub struct CampaignsRequest {
     )]
     pub suppression_group_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The display title of your campaign. This will be viewable by you in the Marketing Campaigns UI.
      */
     #[serde(
         default,
@@ -1965,96 +2338,144 @@ pub struct CampaignsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubuserStatsMetrics {
+    /**
+     * The number of emails that were not allowed to be delivered by ISPs.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub blocks: i64,
+    /**
+     * The number of emails that were dropped because of a bounce.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bounce_drops: i64,
+    /**
+     * The number of emails that bounced instead of being delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bounces: i64,
+    /**
+     * The number of links that were clicked in your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub clicks: i64,
+    /**
+     * The number of emails that temporarily could not be delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub deferred: i64,
+    /**
+     * The number of emails SendGrid was able to confirm were actually delivered to a recipient.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub delivered: i64,
+    /**
+     * The number of recipients who had malformed email addresses or whose mail provider reported the address as invalid.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub invalid_emails: i64,
+    /**
+     * The total number of times your emails were opened by recipients.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub opens: i64,
+    /**
+     * Requests from your website, application, or mail client via SMTP Relay or the API that SendGrid processed.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub processed: i64,
+    /**
+     * The number of emails that were requested to be delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub requests: i64,
+    /**
+     * The number of emails that were dropped due to a recipient previously marking your emails as spam.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub spam_report_drops: i64,
+    /**
+     * The number of recipients who marked your email as spam.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub spam_reports: i64,
+    /**
+     * The number of unique recipients who clicked links in your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_clicks: i64,
+    /**
+     * The number of unique recipients who opened your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unique_opens: i64,
+    /**
+     * The number of emails dropped due to a recipient unsubscribing from your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribe_drops: i64,
+    /**
+     * The number of recipients who unsubscribed from your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -2066,7 +2487,7 @@ pub struct SubuserStatsMetrics {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubuserStats {
     /**
-     * The license key provided with your New Relic account.
+     * The first name of the subuser.
      */
     #[serde(
         default,
@@ -2075,7 +2496,7 @@ pub struct SubuserStats {
     )]
     pub first_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The last name of the subuser.
      */
     #[serde(
         default,
@@ -2086,7 +2507,7 @@ pub struct SubuserStats {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<SubuserStatsMetrics>,
     /**
-     * The license key provided with your New Relic account.
+     * The username of the subuser.
      */
     #[serde(
         default,
@@ -2095,7 +2516,7 @@ pub struct SubuserStats {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The type of account.
      */
     #[serde(
         default,
@@ -2108,12 +2529,18 @@ pub struct SubuserStats {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubuserStatsData {
+    /**
+     * The date the statistics were gathered.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub date: String,
+    /**
+     * The list of statistics.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2126,6 +2553,7 @@ pub struct SubuserStatsData {
 * The status of the scheduled send.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "cancel")]
     Cancel,
@@ -2133,8 +2561,9 @@ pub enum Status {
     Pause,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -2143,12 +2572,32 @@ impl std::fmt::Display for Status {
             Status::Cancel => "cancel",
             Status::Pause => "pause",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "cancel" => Status::Cancel,
+            "pause" => Status::Pause,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -2208,7 +2657,7 @@ pub struct AdvancedStatsClicksOpensAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactdbSegmentsWithId {
     /**
-     * The number of errors found while adding recipients.
+     * The ID of the segment.
      */
     #[serde(
         default,
@@ -2235,7 +2684,7 @@ pub struct ContactdbSegmentsWithAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdvancedStatsClicks {
     /**
-     * The individual events and their stats.
+     * The number of links that were clicked in your emails.
      */
     #[serde(
         default,
@@ -2244,7 +2693,7 @@ pub struct AdvancedStatsClicks {
     )]
     pub clicks: i64,
     /**
-     * The individual events and their stats.
+     * The number of unique recipients who clicked links in your emails.
      */
     #[serde(
         default,
@@ -2257,7 +2706,7 @@ pub struct AdvancedStatsClicks {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Recipients {
     /**
-     * The number of errors found while adding recipients.
+     * The time this record was created in your contactdb, in unixtime.
      */
     #[serde(
         default,
@@ -2275,7 +2724,7 @@ pub struct Recipients {
     )]
     pub custom_fields: Vec<ContactdbCustomFieldWithValueAllOf>,
     /**
-     * The license key provided with your New Relic account.
+     * The email address of this recipient. This is a default custom field that SendGrid provides.
      */
     #[serde(
         default,
@@ -2284,7 +2733,7 @@ pub struct Recipients {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The first name of this recipient. This is a default custom field that SendGrid provides.
      */
     #[serde(
         default,
@@ -2293,7 +2742,7 @@ pub struct Recipients {
     )]
     pub first_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The ID of this recipient.
      */
     #[serde(
         default,
@@ -2302,7 +2751,7 @@ pub struct Recipients {
     )]
     pub id: String,
     /**
-     * The number of errors found while adding recipients.
+     * The last time this recipient clicked a link from one of your campaigns, in unixtime.
      */
     #[serde(
         default,
@@ -2311,7 +2760,7 @@ pub struct Recipients {
     )]
     pub last_clicked: f64,
     /**
-     * The number of errors found while adding recipients.
+     * The last time this user was emailed by one of your campaigns, in unixtime.
      */
     #[serde(
         default,
@@ -2320,7 +2769,7 @@ pub struct Recipients {
     )]
     pub last_emailed: f64,
     /**
-     * The license key provided with your New Relic account.
+     * The last name of the recipient.
      */
     #[serde(
         default,
@@ -2329,7 +2778,7 @@ pub struct Recipients {
     )]
     pub last_name: String,
     /**
-     * The number of errors found while adding recipients.
+     * The last time this recipient opened an email from you, in unixtime.
      */
     #[serde(
         default,
@@ -2338,7 +2787,7 @@ pub struct Recipients {
     )]
     pub last_opened: f64,
     /**
-     * The number of errors found while adding recipients.
+     * The last update date for this recipient's record.
      */
     #[serde(
         default,
@@ -2360,12 +2809,18 @@ pub struct ContactdbRecipient {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettingsPatch {
+    /**
+     * The email address of the recipient.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * Indicates if the mail setting is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2375,12 +2830,18 @@ pub struct MailSettingsPatch {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettingsForwardBounce {
+    /**
+     * The email address that you would like your bounce reports forwarded to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * Indicates if the bounce forwarding mail setting is enabled.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2390,9 +2851,6 @@ pub struct MailSettingsForwardBounce {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailBatchId {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2403,9 +2861,6 @@ pub struct MailBatchId {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreditAllocation {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2426,7 +2881,7 @@ pub struct SubuserPost {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub credit_allocation: Option<CreditAllocation>,
     /**
-     * The license key provided with your New Relic account.
+     * The email address for this subuser.
      */
     #[serde(
         default,
@@ -2441,7 +2896,7 @@ pub struct SubuserPost {
     )]
     pub signup_session_token: String,
     /**
-     * The number of errors found while adding recipients.
+     * The user ID for this subuser.
      */
     #[serde(
         default,
@@ -2450,7 +2905,7 @@ pub struct SubuserPost {
     )]
     pub user_id: f64,
     /**
-     * The license key provided with your New Relic account.
+     * The username of the subuser.
      */
     #[serde(
         default,
@@ -2463,7 +2918,7 @@ pub struct SubuserPost {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactdbRecipientCount {
     /**
-     * The number of errors found while adding recipients.
+     * The count of recipients.
      */
     #[serde(
         default,
@@ -2476,7 +2931,7 @@ pub struct ContactdbRecipientCount {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactdbCustomFieldWithValue {
     /**
-     * The license key provided with your New Relic account.
+     * The value of this recipient's custom field
      */
     #[serde(
         default,
@@ -2501,19 +2956,31 @@ pub struct ContactdbCustomFieldWithValueAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TransactionalTemplateVersionCreate {
+    /**
+     * Set the version as the active version associated with the template (0 is inactive, 1 is active). Only one version of a template can be active. The first version created for a template will automatically be set to Active.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub active: i64,
+    /**
+     * The editor used in the UI.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub editor: Option<Editor>,
+    /**
+     * If true, plain_content is always generated from html_content. If false, plain_content is not altered.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub generate_plain_content: bool,
+    /**
+     * The HTML content of the version. Maximum of 1048576 bytes allowed.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2521,7 +2988,7 @@ pub struct TransactionalTemplateVersionCreate {
     )]
     pub html_content: String,
     /**
-     * The license key provided with your New Relic account.
+     * Name of the transactional template version.
      */
     #[serde(
         default,
@@ -2529,6 +2996,9 @@ pub struct TransactionalTemplateVersionCreate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * Text/plain content of the transactional template version. Maximum of 1048576 bytes allowed.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2536,7 +3006,7 @@ pub struct TransactionalTemplateVersionCreate {
     )]
     pub plain_content: String,
     /**
-     * The license key provided with your New Relic account.
+     * Subject of the new transactional template version.
      */
     #[serde(
         default,
@@ -2544,6 +3014,9 @@ pub struct TransactionalTemplateVersionCreate {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /**
+     * For dynamic templates only, the mock json data that will be used for template preview and test sends.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2554,49 +3027,76 @@ pub struct TransactionalTemplateVersionCreate {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TransactionalTemplatesVersionOutputLean {
+    /**
+     * Set the version as the active version associated with the template. Only one version of a template can be active. The first version created for a template will automatically be set to Active.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub active: i64,
+    /**
+     * The editor used in the UI.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub editor: Option<Editor>,
+    /**
+     * If true, plain_content is always generated from html_content. If false, plain_content is not altered.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub generate_plain_content: bool,
+    /**
+     * ID of the transactional template version.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * Name of the transactional template version.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * Subject of the new transactional template version.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /**
+     * ID of the transactional template.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub template_id: String,
+    /**
+     * A Thumbnail preview of the template's html content.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub thumbnail_url: String,
+    /**
+     * The date and time that this transactional template version was updated.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2609,6 +3109,7 @@ pub struct TransactionalTemplatesVersionOutputLean {
 * Defines the generation of the template.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Generation {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -2616,8 +3117,9 @@ pub enum Generation {
     Legacy,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generation {
@@ -2626,12 +3128,32 @@ impl std::fmt::Display for Generation {
             Generation::Dynamic => "dynamic",
             Generation::Legacy => "legacy",
             Generation::Noop => "",
-            Generation::FallthroughString => "*",
+            Generation::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Generation {
+    fn from(s: String) -> Generation {
+        match s.as_str() {
+            "dynamic" => Generation::Dynamic,
+            "legacy" => Generation::Legacy,
+            "" => Generation::Noop,
+            _ => Generation::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Generation> for String {
+    fn from(e: Generation) -> String {
+        match e {
+            Generation::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Generation {
     fn default() -> Generation {
         Generation::Noop
@@ -2651,7 +3173,7 @@ pub struct TransactionalTemplatesTemplateLean {
     #[serde(default, skip_serializing_if = "Generation::is_noop")]
     pub generation: Generation,
     /**
-     * The license key provided with your New Relic account.
+     * The ID of the transactional template.
      */
     #[serde(
         default,
@@ -2660,7 +3182,7 @@ pub struct TransactionalTemplatesTemplateLean {
     )]
     pub id: String,
     /**
-     * The license key provided with your New Relic account.
+     * The name for the transactional template.
      */
     #[serde(
         default,
@@ -2669,7 +3191,7 @@ pub struct TransactionalTemplatesTemplateLean {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The date and time that this transactional template version was updated.
      */
     #[serde(
         default,
@@ -2678,6 +3200,9 @@ pub struct TransactionalTemplatesTemplateLean {
         rename = "updated_at "
     )]
     pub updated_at: String,
+    /**
+     * The different versions of this transactional template.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2721,7 +3246,7 @@ pub struct ContactDetails {
     )]
     pub country: String,
     /**
-     * The license key provided with your New Relic account.
+     * The ISO8601 timestamp when the contact was created.
      */
     #[serde(
         default,
@@ -2729,6 +3254,9 @@ pub struct ContactDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub created_at: String,
+    /**
+     * helper text or docs for troubleshooting
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Help>,
     #[serde(
@@ -2743,9 +3271,6 @@ pub struct ContactDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2758,9 +3283,6 @@ pub struct ContactDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2780,7 +3302,7 @@ pub struct ContactDetails {
     )]
     pub state_province_region: String,
     /**
-     * The license key provided with your New Relic account.
+     * The ISO8601 timestamp when the contact was updated.
      */
     #[serde(
         default,
@@ -2794,7 +3316,7 @@ pub struct ContactDetails {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Results {
     /**
-     * Result map of the import job.
+     * Created contact count from the import.
      */
     #[serde(
         default,
@@ -2803,7 +3325,7 @@ pub struct Results {
     )]
     pub created_count: f64,
     /**
-     * Result map of the import job.
+     * Count of deleted contacts that resulted in error.
      */
     #[serde(
         default,
@@ -2812,7 +3334,7 @@ pub struct Results {
     )]
     pub deleted_count: f64,
     /**
-     * Result map of the import job.
+     * Count of imported contacts that resulted in error.
      */
     #[serde(
         default,
@@ -2821,7 +3343,7 @@ pub struct Results {
     )]
     pub errored_count: f64,
     /**
-     * Result map of the import job.
+     * The download URL of the file which provides information about any errors.
      */
     #[serde(
         default,
@@ -2830,7 +3352,7 @@ pub struct Results {
     )]
     pub errors_url: String,
     /**
-     * Result map of the import job.
+     * Requested contact count from the import.
      */
     #[serde(
         default,
@@ -2839,7 +3361,7 @@ pub struct Results {
     )]
     pub requested_count: f64,
     /**
-     * Result map of the import job.
+     * Updated contact count from the import.
      */
     #[serde(
         default,
@@ -2851,32 +3373,50 @@ pub struct Results {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactImport {
+    /**
+     * The ISO8601 timestamp when the job was finished.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub finished_at: String,
+    /**
+     * The job ID.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * The job type. Allowed values: `upsert`, or `delete`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub job_type: String,
+    /**
+     * Result map of the import job.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub results: Option<Results>,
+    /**
+     * The ISO8601 timestamp when the job was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub started_at: String,
+    /**
+     * The job state. Allowed values: `pending`, `completed`, `errored`, or `failed`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2887,9 +3427,6 @@ pub struct ContactImport {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CustomFields {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2897,9 +3434,6 @@ pub struct CustomFields {
         rename = "custom_field_name1"
     )]
     pub custom_field_name_1: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2911,45 +3445,30 @@ pub struct CustomFields {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Contact {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_line_1: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_line_2: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub alternate_emails: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2958,45 +3477,30 @@ pub struct Contact {
     pub country: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<CustomFields>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3009,6 +3513,9 @@ pub struct Contact {
 pub struct SingleContactRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub contact: Option<Contact>,
+    /**
+     * The contact's list IDs.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -3021,6 +3528,7 @@ pub struct SingleContactRequest {
 * The export job's status. Allowed values: `pending`, `ready`, or `failure`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ContactExportStatus {
     #[serde(rename = "failure")]
     Failure,
@@ -3030,8 +3538,9 @@ pub enum ContactExportStatus {
     Ready,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ContactExportStatus {
@@ -3041,12 +3550,33 @@ impl std::fmt::Display for ContactExportStatus {
             ContactExportStatus::Pending => "pending",
             ContactExportStatus::Ready => "ready",
             ContactExportStatus::Noop => "",
-            ContactExportStatus::FallthroughString => "*",
+            ContactExportStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ContactExportStatus {
+    fn from(s: String) -> ContactExportStatus {
+        match s.as_str() {
+            "failure" => ContactExportStatus::Failure,
+            "pending" => ContactExportStatus::Pending,
+            "ready" => ContactExportStatus::Ready,
+            "" => ContactExportStatus::Noop,
+            _ => ContactExportStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ContactExportStatus> for String {
+    fn from(e: ContactExportStatus) -> String {
+        match e {
+            ContactExportStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ContactExportStatus {
     fn default() -> ContactExportStatus {
         ContactExportStatus::Noop
@@ -3062,12 +3592,18 @@ impl ContactExportStatus {
 pub struct ContactExport {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<Metadata>,
+    /**
+     * The ISO8601 timestamp when the export was completed.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub completed_at: String,
+    /**
+     * The total number of exported contacts.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3075,7 +3611,7 @@ pub struct ContactExport {
     )]
     pub contact_count: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The ISO8601 timestamp when the export was begun.
      */
     #[serde(
         default,
@@ -3084,7 +3620,7 @@ pub struct ContactExport {
     )]
     pub created_at: String,
     /**
-     * The license key provided with your New Relic account.
+     * The ISO8601 timestamp when the exported file on S3 will expire.
      */
     #[serde(
         default,
@@ -3092,15 +3628,15 @@ pub struct ContactExport {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub expires_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * A human readable message if the status is `failure`.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3113,7 +3649,7 @@ pub struct ContactExport {
     #[serde(default, skip_serializing_if = "ContactExportStatus::is_noop")]
     pub status: ContactExportStatus,
     /**
-     * The license key provided with your New Relic account.
+     * The ISO8601 timestamp when the export was updated.
      */
     #[serde(
         default,
@@ -3121,6 +3657,9 @@ pub struct ContactExport {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_at: String,
+    /**
+     * One or more download URLs for the contact file if the status is `ready`.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -3134,7 +3673,7 @@ pub struct ContactSummary {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<SelfMetadata>,
     /**
-     * The number of errors found while adding recipients.
+     * Unix Epoch Timestamp.
      */
     #[serde(
         default,
@@ -3142,6 +3681,9 @@ pub struct ContactSummary {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub created_at: f64,
+    /**
+     * Primary email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3155,7 +3697,7 @@ pub struct ContactSummary {
     )]
     pub first_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * Contact UUID.
      */
     #[serde(
         default,
@@ -3170,7 +3712,7 @@ pub struct ContactSummary {
     )]
     pub last_name: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * List UUID linked with this contact.
      */
     #[serde(
         default,
@@ -3179,7 +3721,7 @@ pub struct ContactSummary {
     )]
     pub list_ids: Vec<String>,
     /**
-     * The number of errors found while adding recipients.
+     * Unix Epoch Timestamp.
      */
     #[serde(
         default,
@@ -3191,40 +3733,58 @@ pub struct ContactSummary {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactRequest {
+    /**
+     * The first line of the address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_line_1: String,
+    /**
+     * An optional second line for the address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_line_2: String,
+    /**
+     * Additional emails associated with the contact.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub alternate_emails: Vec<String>,
+    /**
+     * The contact's city.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
+    /**
+     * The contact's country. Can be a full name or an abbreviation.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
+    /**
+     * helper text or docs for troubleshooting
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Help>,
     /**
-     * The license key provided with your New Relic account.
+     * The contact's primary email. This is required to be a valid email.
      */
     #[serde(
         default,
@@ -3232,24 +3792,36 @@ pub struct ContactRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The contact's personal name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
+    /**
+     * The contact's family name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /**
+     * The contact's ZIP code or other postal code.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
+    /**
+     * The contact's state, province, or region.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3318,9 +3890,6 @@ pub struct ContactDetails2 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3339,9 +3908,6 @@ pub struct ContactDetails2 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub line: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -3394,6 +3960,9 @@ pub struct ContactDetails2 {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SelfMetadata {
+    /**
+     * A link to this object.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3417,9 +3986,6 @@ pub struct Error {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3452,24 +4018,36 @@ pub struct Link {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Metadata {
+    /**
+     * The number of items in the entire list, i.e., across all pages.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub count: f64,
+    /**
+     * The URL of the next page of results. If this field isn't present, you're at the end of the list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next: String,
+    /**
+     * The URL of the previous page of results. If this field isn't present, you're at the start of the list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub prev: String,
+    /**
+     * The URL of the current page of results.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3482,7 +4060,7 @@ pub struct Metadata {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Webhook {
     /**
-     * The license key provided with your New Relic account.
+     * The one time nonce to use when "signature" is "hmac-sha1"
      */
     #[serde(
         default,
@@ -3491,7 +4069,7 @@ pub struct Webhook {
     )]
     pub nonce: String,
     /**
-     * The license key provided with your New Relic account.
+     * The URL to invoke in the webhook
      */
     #[serde(
         default,
@@ -3505,18 +4083,27 @@ pub struct Webhook {
 pub struct List {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<SelfMetadata>,
+    /**
+     * The number of contacts currently stored on the list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub contact_count: i64,
+    /**
+     * The generated ID for your list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * The name you gave your list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3526,6 +4113,7 @@ pub struct List {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FieldType {
     #[serde(rename = "Date")]
     Date,
@@ -3535,8 +4123,9 @@ pub enum FieldType {
     Text,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldType {
@@ -3546,12 +4135,33 @@ impl std::fmt::Display for FieldType {
             FieldType::Number => "Number",
             FieldType::Text => "Text",
             FieldType::Noop => "",
-            FieldType::FallthroughString => "*",
+            FieldType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FieldType {
+    fn from(s: String) -> FieldType {
+        match s.as_str() {
+            "Date" => FieldType::Date,
+            "Number" => FieldType::Number,
+            "Text" => FieldType::Text,
+            "" => FieldType::Noop,
+            _ => FieldType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FieldType> for String {
+    fn from(e: FieldType) -> String {
+        match e {
+            FieldType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FieldType {
     fn default() -> FieldType {
         FieldType::Noop
@@ -3567,9 +4177,6 @@ impl FieldType {
 pub struct ReservedFieldDefinitionsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub field_type: Option<FieldType>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3577,7 +4184,7 @@ pub struct ReservedFieldDefinitionsResponse {
     )]
     pub name: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * When `true` this means API consumers are unable to set the value of this field on contacts.
      */
     #[serde(
         default,
@@ -3590,18 +4197,12 @@ pub struct ReservedFieldDefinitionsResponse {
 pub struct CustomFieldDefinitionsResponse {
     #[serde(default, skip_serializing_if = "FieldType::is_noop")]
     pub field_type: FieldType,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3612,9 +4213,6 @@ pub struct CustomFieldDefinitionsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentWrite {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3622,7 +4220,7 @@ pub struct SegmentWrite {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * Use this field for adding your query string.
      */
     #[serde(
         default,
@@ -3640,15 +4238,16 @@ pub struct SegmentSummary {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub contacts_count: i64,
+    /**
+     * ISO8601 of created timestamp
+     *
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3661,24 +4260,36 @@ pub struct SegmentSummary {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * ISO8601 string that is equal to `sample_updated_at` plus an internally calculated offset that depends on how often contacts enter or exit segments as the scheduled pipeline updates the samples.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_sample_update: String,
+    /**
+     * The id of the list if this segment is a child of a list.  This implies the query `AND CONTAINS(list_ids, ${parent_list_id})`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent_list_id: String,
+    /**
+     * ISO8601 timestamp the sample was last updated
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub sample_updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * ISO8601 timestamp the object was last updated
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -3689,18 +4300,12 @@ pub struct SegmentSummary {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct L {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub t: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3713,9 +4318,6 @@ pub struct L {
 pub struct SegmentQueryJsonContactsL {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub l: Option<L>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3734,18 +4336,12 @@ pub struct SegmentQueryJsonContactsLR {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub args: Vec<L>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub t: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3758,9 +4354,6 @@ pub struct SegmentQueryJsonContactsLR {
 pub struct R {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub l: Option<SegmentQueryJsonContactsLR>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3775,9 +4368,6 @@ pub struct R {
 pub struct SegmentQueryJsonContactsLData {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub l: Option<SegmentQueryJsonContactsL>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3790,18 +4380,12 @@ pub struct SegmentQueryJsonContactsLData {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentQueryJsonContactsR {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub t: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -3814,9 +4398,6 @@ pub struct SegmentQueryJsonContactsR {
 pub struct SegmentQueryJsonContactsRData {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub l: Option<L>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3831,9 +4412,6 @@ pub struct SegmentQueryJsonContactsRData {
 pub struct SegmentQueryJsonContacts {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub l: Option<SegmentQueryJsonContactsLData>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3853,9 +4431,6 @@ pub struct SegmentQueryJson {
 /// The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactResponseCustomFields {
-    /**
-     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3863,9 +4438,6 @@ pub struct ContactResponseCustomFields {
         rename = "custom_field_name1"
     )]
     pub custom_field_name_1: String,
-    /**
-     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3878,7 +4450,7 @@ pub struct ContactResponseCustomFields {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContactResponse {
     /**
-     * The license key provided with your New Relic account.
+     * First line of address of the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3887,7 +4459,7 @@ pub struct ContactResponse {
     )]
     pub address_line_1: String,
     /**
-     * The license key provided with your New Relic account.
+     * Second line of address of the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3896,7 +4468,7 @@ pub struct ContactResponse {
     )]
     pub address_line_2: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * Alternate emails of the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3905,7 +4477,7 @@ pub struct ContactResponse {
     )]
     pub alternate_emails: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * City associated with the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3914,7 +4486,7 @@ pub struct ContactResponse {
     )]
     pub city: String,
     /**
-     * The license key provided with your New Relic account.
+     * Country associated with the address of the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3928,7 +4500,7 @@ pub struct ContactResponse {
     #[serde()]
     pub custom_fields: ContactResponseCustomFields,
     /**
-     * The license key provided with your New Relic account.
+     * Email of the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3937,7 +4509,7 @@ pub struct ContactResponse {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * First name of the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3946,7 +4518,7 @@ pub struct ContactResponse {
     )]
     pub first_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * ID assigned to a contact when added to the system.
      */
     #[serde(
         default,
@@ -3955,7 +4527,7 @@ pub struct ContactResponse {
     )]
     pub id: String,
     /**
-     * The license key provided with your New Relic account.
+     * Last name of the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3963,18 +4535,27 @@ pub struct ContactResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
+    /**
+     * IDs of all lists the contact is part of
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<String>,
+    /**
+     * Zipcode associated with the address of the contact. This is a reserved field.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub postal_code: i64,
+    /**
+     * IDs of all segments the contact is part of
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -3982,7 +4563,7 @@ pub struct ContactResponse {
     )]
     pub segment_ids: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * State associated with the contact. This is a reserved field.
      */
     #[serde(
         default,
@@ -3994,6 +4575,9 @@ pub struct ContactResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TneSenderId {
+    /**
+     * The unique identifier of the sender.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4006,7 +4590,7 @@ pub struct TneSenderId {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Verified {
     /**
-     * Only verified sender identities can be used to send email.
+     * The reason for a verification failure, or null if verification succeeeded or has yet to take place.
      */
     #[serde(
         default,
@@ -4015,7 +4599,7 @@ pub struct Verified {
     )]
     pub reason: String,
     /**
-     * Only verified sender identities can be used to send email.
+     * Whether the sender identity has been verified. Only verified sender identities can be used to send email.
      */
     #[serde(
         default,
@@ -4026,6 +4610,9 @@ pub struct Verified {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TneSenderData {
+    /**
+     * The time the sender identity was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4033,13 +4620,16 @@ pub struct TneSenderData {
     )]
     pub created_at: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * A sender identity is locked when it is associated with a campaign in the Draft, Scheduled, or In Progress state. You can't update or delete a locked sender identity.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub locked: bool,
+    /**
+     * The time the sender identity was last updated.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4071,27 +4661,18 @@ pub struct TneSenderAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApiError {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4142,7 +4723,7 @@ pub struct MetadataType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DesignInput {
     /**
-     * The license key provided with your New Relic account.
+     * The HTML content of the Design.
      */
     #[serde(
         default,
@@ -4151,7 +4732,7 @@ pub struct DesignInput {
     )]
     pub html_content: String,
     /**
-     * The license key provided with your New Relic account.
+     * Plain text content of the Design.
      */
     #[serde(
         default,
@@ -4192,6 +4773,9 @@ pub struct DesignOutputAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DesignOutputSummary {
+    /**
+     * Datetime that Design was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4199,7 +4783,7 @@ pub struct DesignOutputSummary {
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The license key provided with your New Relic account.
+     * ID of the Design.
      */
     #[serde(
         default,
@@ -4208,7 +4792,7 @@ pub struct DesignOutputSummary {
     )]
     pub id: String,
     /**
-     * The license key provided with your New Relic account.
+     * A Thumbnail preview of the template's html content.
      */
     #[serde(
         default,
@@ -4216,6 +4800,9 @@ pub struct DesignOutputSummary {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub thumbnail_url: String,
+    /**
+     * Datetime that Design was last updated.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4242,8 +4829,14 @@ pub struct DesignOutputSummaryAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DesignDuplicateInput {
+    /**
+     * The editor used in the UI.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub editor: Option<Editor>,
+    /**
+     * The name of the new design.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4286,9 +4879,6 @@ pub struct ContactDetails3 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4315,9 +4905,6 @@ pub struct ContactDetails3 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4336,9 +4923,6 @@ pub struct ContactDetails3 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub line: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4357,9 +4941,6 @@ pub struct ContactDetails3 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4378,9 +4959,6 @@ pub struct ContactDetails3 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub unique_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4397,6 +4975,9 @@ pub struct ContactDetails3 {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Warning {
+    /**
+     * Warning message for the user
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4407,9 +4988,6 @@ pub struct Warning {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ErrorsData {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4417,7 +4995,7 @@ pub struct ErrorsData {
     )]
     pub field: String,
     /**
-     * The license key provided with your New Relic account.
+     * A description of what is wrong with the field passed in the request.
      */
     #[serde(
         default,
@@ -4426,7 +5004,7 @@ pub struct ErrorsData {
     )]
     pub message: String,
     /**
-     * The license key provided with your New Relic account.
+     * The parameter in the request body that is incorrect.
      */
     #[serde(
         default,
@@ -4451,6 +5029,7 @@ pub struct ErrorsDataType {
 * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AbPhase {
     #[serde(rename = "all")]
     All,
@@ -4458,8 +5037,9 @@ pub enum AbPhase {
     Send,
     #[serde(rename = "test")]
     Test,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhase {
@@ -4468,12 +5048,32 @@ impl std::fmt::Display for AbPhase {
             AbPhase::All => "all",
             AbPhase::Send => "send",
             AbPhase::Test => "test",
-            AbPhase::FallthroughString => "*",
+            AbPhase::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AbPhase {
+    fn from(s: String) -> AbPhase {
+        match s.as_str() {
+            "all" => AbPhase::All,
+            "send" => AbPhase::Send,
+            "test" => AbPhase::Test,
+            _ => AbPhase::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AbPhase> for String {
+    fn from(e: AbPhase) -> String {
+        match e {
+            AbPhase::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AbPhase {
     fn default() -> AbPhase {
         AbPhase::All
@@ -4488,7 +5088,7 @@ pub struct SinglesendsResponseResults {
     #[serde(default)]
     pub ab_phase: AbPhase,
     /**
-     * The license key provided with your New Relic account.
+     * This is the A/B variation of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_variation` in the request, then the value is "all".
      */
     #[serde(
         default,
@@ -4497,7 +5097,7 @@ pub struct SinglesendsResponseResults {
     )]
     pub ab_variation: String,
     /**
-     * The license key provided with your New Relic account.
+     * This describes the time unit to which the stat is rolled up. It is based on the `aggregated_by` parameter included in the request. It can be "total" or the date (in YYYY-MM-DD format) the stats are for.
      */
     #[serde(
         default,
@@ -4506,7 +5106,7 @@ pub struct SinglesendsResponseResults {
     )]
     pub aggregation: String,
     /**
-     * The license key provided with your New Relic account.
+     * This is the ID of the Single Dend you require stats for.
      */
     #[serde(
         default,
@@ -4533,7 +5133,7 @@ pub struct SinglesendsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutomationsResponseResults {
     /**
-     * The license key provided with your New Relic account.
+     * This describes the time unit to which the stat is rolled up. It is based on the `aggregated_by` parameter included in the request. It can be "total" or the date (in YYYY-MM-DD format) the stats are for.
      */
     #[serde(
         default,
@@ -4542,7 +5142,7 @@ pub struct AutomationsResponseResults {
     )]
     pub aggregation: String,
     /**
-     * The license key provided with your New Relic account.
+     * This is the ID of the Automation you are requesting stats for.
      */
     #[serde(
         default,
@@ -4553,7 +5153,7 @@ pub struct AutomationsResponseResults {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stats: Option<MetricsData>,
     /**
-     * The license key provided with your New Relic account.
+     * This is the ID of the step if the stats were requested to be grouped by `step_id`.
      */
     #[serde(
         default,
@@ -4652,6 +5252,7 @@ pub struct MetricsData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SinglesendSearchStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -4661,8 +5262,9 @@ pub enum SinglesendSearchStatus {
     Triggered,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SinglesendSearchStatus {
@@ -4672,12 +5274,33 @@ impl std::fmt::Display for SinglesendSearchStatus {
             SinglesendSearchStatus::Scheduled => "scheduled",
             SinglesendSearchStatus::Triggered => "triggered",
             SinglesendSearchStatus::Noop => "",
-            SinglesendSearchStatus::FallthroughString => "*",
+            SinglesendSearchStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SinglesendSearchStatus {
+    fn from(s: String) -> SinglesendSearchStatus {
+        match s.as_str() {
+            "draft" => SinglesendSearchStatus::Draft,
+            "scheduled" => SinglesendSearchStatus::Scheduled,
+            "triggered" => SinglesendSearchStatus::Triggered,
+            "" => SinglesendSearchStatus::Noop,
+            _ => SinglesendSearchStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SinglesendSearchStatus> for String {
+    fn from(e: SinglesendSearchStatus) -> String {
+        match e {
+            SinglesendSearchStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SinglesendSearchStatus {
     fn default() -> SinglesendSearchStatus {
         SinglesendSearchStatus::Noop
@@ -4691,18 +5314,27 @@ impl SinglesendSearchStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendSearch {
+    /**
+     * categories to associate with this Single Send, match any single send that has at least one of the categories
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub categories: Vec<String>,
+    /**
+     * leading and trailing wildcard search on name of the Single Send
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * current status of the Single Send
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4714,7 +5346,7 @@ pub struct SinglesendSearch {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendTo {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Set to `true` to send to All Contacts. If set to `false`, at least one `list_ids` or `segment_ids` value must be provided before the Single Send is scheduled to be sent to recipients.
      */
     #[serde(
         default,
@@ -4722,7 +5354,7 @@ pub struct SendTo {
     )]
     pub all: bool,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The recipient List IDs that will receive the Single Send.
      */
     #[serde(
         default,
@@ -4731,7 +5363,7 @@ pub struct SendTo {
     )]
     pub list_ids: Vec<String>,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The recipient Segment IDs that will receive the Single Send.
      */
     #[serde(
         default,
@@ -4744,7 +5376,7 @@ pub struct SendTo {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmailConfig {
     /**
-     * The license key provided with your New Relic account.
+     * The URL allowing recipients to unsubscribe — you must provide this or the `suppression_group_id`.
      */
     #[serde(
         default,
@@ -4753,7 +5385,7 @@ pub struct EmailConfig {
     )]
     pub custom_unsubscribe_url: String,
     /**
-     * The license key provided with your New Relic account.
+     * A `design_id` can be used in place of `html_content`, `plain_content`, and/or `subject`. You can retrieve a design's ID from the ["List Designs" endpoint](https://sendgrid.api-docs.io/v3.0/designs-api/list-designs) or by pulling it from the design's detail page URL in the Marketing Campaigns App.
      */
     #[serde(
         default,
@@ -4762,12 +5394,12 @@ pub struct EmailConfig {
     )]
     pub design_id: String,
     /**
-     * The editor used in the UI.
+     * The editor — `"design"` or `"code"` — used to modify the Single Send's design in the Marketing Campaigns App.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub editor: Option<Editor>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * If set to `true`, `plain_content` is always generated from `html_content`. If set to false, `plain_content` is not altered.
      */
     #[serde(
         default,
@@ -4775,7 +5407,7 @@ pub struct EmailConfig {
     )]
     pub generate_plain_content: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The HTML content of the Single Send. Do not include this field when using a `design_id`.
      */
     #[serde(
         default,
@@ -4784,7 +5416,7 @@ pub struct EmailConfig {
     )]
     pub html_content: String,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the IP Pool from which the Single Send emails are sent.
      */
     #[serde(
         default,
@@ -4793,7 +5425,7 @@ pub struct EmailConfig {
     )]
     pub ip_pool: String,
     /**
-     * The license key provided with your New Relic account.
+     * The plain text content of the Single Send. Do not include this field when using a `design_id`.
      */
     #[serde(
         default,
@@ -4801,6 +5433,9 @@ pub struct EmailConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub plain_content: String,
+    /**
+     * The ID of the verified Sender. You can retrieve a verified Sender's ID from the ["Get Verified Senders" endpoint](https://sendgrid.api-docs.io/v3.0/sender-verification/get-verified-senders) or by pulling it from the Sender's detail page URL in the SendGrid App.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4808,7 +5443,7 @@ pub struct EmailConfig {
     )]
     pub sender_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The subject line of the Single Send. Do not include this field when using a `design_id`.
      */
     #[serde(
         default,
@@ -4816,6 +5451,9 @@ pub struct EmailConfig {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /**
+     * The ID of the Suppression Group to allow recipients to unsubscribe — you must provide this or the `custom_unsubscribe_url`.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -4826,6 +5464,9 @@ pub struct EmailConfig {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendRequest {
+    /**
+     * The categories to associate with this Single Send.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4835,7 +5476,7 @@ pub struct SinglesendRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub email_config: Option<EmailConfig>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the Single Send.
      */
     #[serde(
         default,
@@ -4843,6 +5484,9 @@ pub struct SinglesendRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The ISO 8601 time at which to send the Single Send — this must be set for a future time.
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4855,6 +5499,9 @@ pub struct SinglesendRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendSchedule {
+    /**
+     * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -4867,27 +5514,18 @@ pub struct SinglesendSchedule {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Warnings {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4909,7 +5547,7 @@ pub struct SinglesendWarning {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReplyTo {
     /**
-     * The license key provided with your New Relic account.
+     * The intended recipient's email address.
      */
     #[serde(
         default,
@@ -4918,7 +5556,7 @@ pub struct ReplyTo {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The intended recipient's name.
      */
     #[serde(
         default,
@@ -4931,57 +5569,66 @@ pub struct ReplyTo {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebhooksEventWebhookUpdateWithOAuthRequest {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Receiving server could not or would not accept message.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bounce: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient clicked on a link within the message. You need to enable Click Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub click: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient's email server temporarily rejected message.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deferred: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Message has been successfully delivered to the receiving server.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delivered: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * You may see the following drop reasons: Invalid SMTPAPI header, Spam Content (if spam checker app enabled), Unsubscribed Address, Bounced Address, Spam Reporting Address, Invalid, Recipient List over Package Quota
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dropped: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the event webhook is enabled.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient resubscribes to specific group by updating preferences. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_resubscribe: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient unsubscribe from specific group, by either direct link or updating preferences. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_unsubscribe: Option<bool>,
+    /**
+     * The client ID Twilio SendGrid sends to your OAuth server or service provider to generate an OAuth access token. When passing data in this field, you must also include the oauth_token_url field.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub oauth_client_id: String,
+    /**
+     * This secret is needed only once to create an access token. SendGrid will store this secret, allowing you to update your Client ID and Token URL without passing the secret to SendGrid again.  When passing data in this field, you must also include the oauth_client_id and oauth_token_url fields.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub oauth_client_secret: String,
+    /**
+     * The URL where Twilio SendGrid sends the Client ID and Client Secret to generate an access token. This should be your OAuth server or service provider. When passing data in this field, you must also include the oauth_client_id field.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4989,27 +5636,27 @@ pub struct WebhooksEventWebhookUpdateWithOAuthRequest {
     )]
     pub oauth_token_url: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient has opened the HTML message. You need to enable Open Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub open: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Message has been received and is ready to be delivered.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub processed: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient marked a message as spam.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spam_report: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient clicked on message's subscription management link. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub unsubscribe: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * The URL that you want the event webhook to POST to.
      */
     #[serde(
         default,
@@ -5022,51 +5669,57 @@ pub struct WebhooksEventWebhookUpdateWithOAuthRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebhooksEventWebhookRequest {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Receiving server could not or would not accept message.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bounce: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient clicked on a link within the message. You need to enable Click Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub click: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient's email server temporarily rejected message.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub deferred: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Message has been successfully delivered to the receiving server.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delivered: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * You may see the following drop reasons: Invalid SMTPAPI header, Spam Content (if spam checker app enabled), Unsubscribed Address, Bounced Address, Spam Reporting Address, Invalid, Recipient List over Package Quota
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dropped: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the event webhook is enabled.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient resubscribes to specific group by updating preferences. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_resubscribe: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient unsubscribe from specific group, by either direct link or updating preferences. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_unsubscribe: Option<bool>,
+    /**
+     * The client ID Twilio SendGrid sends to your OAuth server or service provider to generate an OAuth access token. When passing data in this field, you must also include the oauth_token_url field.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub oauth_client_id: String,
+    /**
+     * The URL where Twilio SendGrid sends the Client ID and Client Secret to generate an access token. This should be your OAuth server or service provider. When passing data in this field, you must also include the oauth_client_id field.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5074,27 +5727,27 @@ pub struct WebhooksEventWebhookRequest {
     )]
     pub oauth_token_url: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient has opened the HTML message. You need to enable Open Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub open: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Message has been received and is ready to be delivered.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub processed: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient marked a message as spam.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spam_report: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Recipient clicked on message's subscription management link. You need to enable Subscription Tracking for getting this type of event.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub unsubscribe: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * The URL that you want the event webhook to POST to.
      */
     #[serde(
         default,
@@ -5107,7 +5760,7 @@ pub struct WebhooksEventWebhookRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReplyEmailObject {
     /**
-     * The license key provided with your New Relic account.
+     * The email address where any replies or bounces will be returned.
      */
     #[serde(
         default,
@@ -5115,6 +5768,9 @@ pub struct ReplyEmailObject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * A name or title associated with the `reply_to` email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5125,6 +5781,9 @@ pub struct ReplyEmailObject {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutomationsLinkStatsResponseResults {
+    /**
+     * The number of clicks on this particular link.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5132,7 +5791,7 @@ pub struct AutomationsLinkStatsResponseResults {
     )]
     pub clicks: i64,
     /**
-     * The license key provided with your New Relic account.
+     * This is the ID of the step if the stats were requested to be grouped by `step_id`.
      */
     #[serde(
         default,
@@ -5141,7 +5800,7 @@ pub struct AutomationsLinkStatsResponseResults {
     )]
     pub step_id: String,
     /**
-     * The license key provided with your New Relic account.
+     * This is the URL of the link clicked. If `{{custom_fields}}` are part of the URL, they will be included.
      */
     #[serde(
         default,
@@ -5149,6 +5808,9 @@ pub struct AutomationsLinkStatsResponseResults {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * This is the location of the link clicked in each Automation step. Links are located according to their position within the message; the topmost link has index `0`.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5180,24 +5842,36 @@ pub struct AutomationsLinkStatsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LinkTrackingMetadata {
+    /**
+     * The number of items in the entire list, i.e., across all pages.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub count: f64,
+    /**
+     * The URL of the next page of results. If this field isn't present, you're at the end of the list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next: String,
+    /**
+     * The URL of the previous page of results. If this field isn't present, you're at the start of the list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub prev: String,
+    /**
+     * The URL of the current page of results.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5210,12 +5884,12 @@ pub struct LinkTrackingMetadata {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendsLinkStatsResponseResults {
     /**
-     * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
+     * This is the A/B phase of the Single Send stat returned. If the `ab_phase` query parameter was not provided, it will return `"all"`.
      */
     #[serde(default)]
     pub ab_phase: AbPhase,
     /**
-     * The license key provided with your New Relic account.
+     * This is the A/B variation of the Single Send stat returned. It is set to `"all"` if the `ab_variation` query parameter was not set in the request and `group_by` doesn't contain `ab_variation`.
      */
     #[serde(
         default,
@@ -5223,6 +5897,9 @@ pub struct SinglesendsLinkStatsResponseResults {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ab_variation: String,
+    /**
+     * the number of clicks on this particular link
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5230,7 +5907,7 @@ pub struct SinglesendsLinkStatsResponseResults {
     )]
     pub clicks: i64,
     /**
-     * The license key provided with your New Relic account.
+     * This is the URL of the link clicked. If `{{custom_fields}}` are part of the URL, they will be included.
      */
     #[serde(
         default,
@@ -5238,6 +5915,9 @@ pub struct SinglesendsLinkStatsResponseResults {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub url: String,
+    /**
+     * This is the location of the link clicked in each Single Send A/B variation, or in the Single Send itself if there are no variations. Links are numbered from the top down; the topmost link is index `0`.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5269,6 +5949,9 @@ pub struct SinglesendsLinkStatsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Subusers {
+    /**
+     * The ID of the subuser that this authenticated domain will be associated with.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5276,7 +5959,7 @@ pub struct Subusers {
     )]
     pub user_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The username of the subuser that this authenticated domain is associated with.
      */
     #[serde(
         default,
@@ -5288,6 +5971,9 @@ pub struct Subusers {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DomainAuthentication200Response {
+    /**
+     * A Unix epoch timestamp representing the last time of a validation attempt.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5319,6 +6005,7 @@ pub struct DomainAuthentication200ResponseAllOf {
 * What differs between the A/B tests
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AbTestSummaryType {
     #[serde(rename = "content")]
     Content,
@@ -5326,8 +6013,9 @@ pub enum AbTestSummaryType {
     Subject,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbTestSummaryType {
@@ -5336,12 +6024,32 @@ impl std::fmt::Display for AbTestSummaryType {
             AbTestSummaryType::Content => "content",
             AbTestSummaryType::Subject => "subject",
             AbTestSummaryType::Noop => "",
-            AbTestSummaryType::FallthroughString => "*",
+            AbTestSummaryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AbTestSummaryType {
+    fn from(s: String) -> AbTestSummaryType {
+        match s.as_str() {
+            "content" => AbTestSummaryType::Content,
+            "subject" => AbTestSummaryType::Subject,
+            "" => AbTestSummaryType::Noop,
+            _ => AbTestSummaryType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AbTestSummaryType> for String {
+    fn from(e: AbTestSummaryType) -> String {
+        match e {
+            AbTestSummaryType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AbTestSummaryType {
     fn default() -> AbTestSummaryType {
         AbTestSummaryType::Noop
@@ -5357,6 +6065,7 @@ impl AbTestSummaryType {
 * How the winner will be decided
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WinnerCriteria {
     #[serde(rename = "click")]
     Click,
@@ -5366,8 +6075,9 @@ pub enum WinnerCriteria {
     Open,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WinnerCriteria {
@@ -5377,12 +6087,33 @@ impl std::fmt::Display for WinnerCriteria {
             WinnerCriteria::Manual => "manual",
             WinnerCriteria::Open => "open",
             WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WinnerCriteria {
+    fn from(s: String) -> WinnerCriteria {
+        match s.as_str() {
+            "click" => WinnerCriteria::Click,
+            "manual" => WinnerCriteria::Manual,
+            "open" => WinnerCriteria::Open,
+            "" => WinnerCriteria::Noop,
+            _ => WinnerCriteria::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WinnerCriteria> for String {
+    fn from(e: WinnerCriteria) -> String {
+        match e {
+            WinnerCriteria::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WinnerCriteria {
     fn default() -> WinnerCriteria {
         WinnerCriteria::Noop
@@ -5397,7 +6128,7 @@ impl WinnerCriteria {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AbTestSummary {
     /**
-     * The license key provided with your New Relic account.
+     * How long the A/B Testing will last
      */
     #[serde(
         default,
@@ -5414,6 +6145,9 @@ pub struct AbTestSummary {
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub expiration_date: Option<chrono::NaiveDate>,
+    /**
+     * What percentage of your recipient will be included in your A/B testing
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5435,7 +6169,7 @@ pub struct AbTestSummary {
     #[serde(default, skip_serializing_if = "WinnerCriteria::is_noop")]
     pub winner_criteria: WinnerCriteria,
     /**
-     * The license key provided with your New Relic account.
+     * When the winner was selected
      */
     #[serde(
         default,
@@ -5444,7 +6178,7 @@ pub struct AbTestSummary {
     )]
     pub winner_selected_at: String,
     /**
-     * The license key provided with your New Relic account.
+     * Winner of the A/B Test
      */
     #[serde(
         default,
@@ -5459,7 +6193,7 @@ pub struct SinglesendResponseShort {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub abtest: Option<AbTestSummary>,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * categories to associate with this Single Send
      */
     #[serde(
         default,
@@ -5467,15 +6201,15 @@ pub struct SinglesendResponseShort {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub categories: Vec<String>,
+    /**
+     * the ISO 8601 time at which the Single Send was created
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5483,7 +6217,7 @@ pub struct SinglesendResponseShort {
     )]
     pub id: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * true if the Single Send's AB Test functionality has been toggled on
      */
     #[serde(
         default,
@@ -5491,7 +6225,7 @@ pub struct SinglesendResponseShort {
     )]
     pub is_abtest: bool,
     /**
-     * The license key provided with your New Relic account.
+     * name of the Single Send
      */
     #[serde(
         default,
@@ -5499,14 +6233,23 @@ pub struct SinglesendResponseShort {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * the ISO 8601 time at which to send the Single Send; must be in future
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub send_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * current status of the Single Send
+     */
     #[serde(default, skip_serializing_if = "SinglesendSearchStatus::is_noop")]
     pub status: SinglesendSearchStatus,
+    /**
+     * the ISO 8601 time at which the Single Send was last updated
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -5518,7 +6261,7 @@ pub struct SinglesendResponseShort {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CcBccEmailObject {
     /**
-     * The license key provided with your New Relic account.
+     * The intended recipient's email address.
      */
     #[serde(
         default,
@@ -5526,6 +6269,9 @@ pub struct CcBccEmailObject {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The intended recipient's name.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5561,9 +6307,6 @@ pub struct VerifiedSenderRequestSchema {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5576,18 +6319,12 @@ pub struct VerifiedSenderRequestSchema {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub from_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub nickname: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5703,12 +6440,18 @@ pub struct VerifiedSenderResponseSchema {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Result {
+    /**
+     * A Unix timestamp indicating when the IP was added to the allow list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub created_at: i64,
+    /**
+     * The ID of the allowed IP.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5716,7 +6459,7 @@ pub struct Result {
     )]
     pub id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The allowed IP.
      */
     #[serde(
         default,
@@ -5724,6 +6467,9 @@ pub struct Result {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /**
+     * A Unix timestamp indicating when the IPs allow status was most recently updated.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5734,6 +6480,9 @@ pub struct Result {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IpAccessResponse {
+    /**
+     * An array listing all of your allowed IPs.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5744,72 +6493,108 @@ pub struct IpAccessResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StatsAdvancedGlobal {
+    /**
+     * The number of emails that were not allowed to be delivered by ISPs.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub blocks: i64,
+    /**
+     * The number of emails that were dropped because of a bounce.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bounce_drops: i64,
+    /**
+     * The number of emails that bounced instead of being delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bounces: i64,
+    /**
+     * The number of emails that temporarily could not be delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub deferred: i64,
+    /**
+     * The number of emails SendGrid was able to confirm were actually delivered to a recipient.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub delivered: i64,
+    /**
+     * The number of recipients who had malformed email addresses or whose mail provider reported the address as invalid.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub invalid_emails: i64,
+    /**
+     * Requests from your website, application, or mail client via SMTP Relay or the API that SendGrid processed.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub processed: i64,
+    /**
+     * The number of emails that were requested to be delivered.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub requests: i64,
+    /**
+     * The number of emails that were dropped due to a recipient previously marking your emails as spam.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub spam_report_drops: i64,
+    /**
+     * The number of recipients who marked your email as spam.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub spam_reports: i64,
+    /**
+     * The number of emails dropped due to a recipient unsubscribing from your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub unsubscribe_drops: i64,
+    /**
+     * The number of recipients who unsubscribed from your emails.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -5833,9 +6618,6 @@ pub struct StatsAdvancedGlobalAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StatsAdvancedBaseSchema {
-    /**
-     * helper text or docs for troubleshooting
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Help>,
 }
@@ -5843,7 +6625,7 @@ pub struct StatsAdvancedBaseSchema {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct StatsAdvancedBaseSchemaData {
     /**
-     * The license key provided with your New Relic account.
+     * The date the stats were gathered.
      */
     #[serde(
         default,
@@ -5871,7 +6653,7 @@ pub struct FullSegment {
     )]
     pub contacts_sample: Vec<ContactResponse>,
     /**
-     * helper text or docs for troubleshooting
+     * AST representation of the query DSL
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub query_json: Option<Help>,
@@ -5896,7 +6678,7 @@ pub struct FullSegmentAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct From {
     /**
-     * The license key provided with your New Relic account.
+     * This is where the email will appear to originate from for your recipient
      */
     #[serde(
         default,
@@ -5905,7 +6687,7 @@ pub struct From {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * This is the name appended to the from email field. IE - Your name or company name.
      */
     #[serde(
         default,
@@ -5918,7 +6700,7 @@ pub struct From {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendersRequestBody {
     /**
-     * The license key provided with your New Relic account.
+     * The physical address of the sender identity.
      */
     #[serde(
         default,
@@ -5926,6 +6708,9 @@ pub struct SendersRequestBody {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address: String,
+    /**
+     * Additional sender identity address information.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5933,7 +6718,7 @@ pub struct SendersRequestBody {
     )]
     pub address_2: String,
     /**
-     * The license key provided with your New Relic account.
+     * The city of the sender identity.
      */
     #[serde(
         default,
@@ -5942,7 +6727,7 @@ pub struct SendersRequestBody {
     )]
     pub city: String,
     /**
-     * The license key provided with your New Relic account.
+     * The country of the sender identity.
      */
     #[serde(
         default,
@@ -5953,7 +6738,7 @@ pub struct SendersRequestBody {
     #[serde()]
     pub from: From,
     /**
-     * The license key provided with your New Relic account.
+     * A nickname for the sender identity. Not used for sending.
      */
     #[serde(
         default,
@@ -5963,12 +6748,18 @@ pub struct SendersRequestBody {
     pub nickname: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<ReplyTo>,
+    /**
+     * The state of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /**
+     * The zipcode of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -5979,11 +6770,17 @@ pub struct SendersRequestBody {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EnforcedTlsRequestResponse {
+    /**
+     * Indicates if you want to require your recipients to support TLS.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub require_tls: bool,
+    /**
+     * Indicates if you want to require your recipients to have a valid certificate.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -5993,23 +6790,29 @@ pub struct EnforcedTlsRequestResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendResponse {
+    /**
+     * the ISO 8601 time at which the Single Send was created
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * current status of the Single Send
+     */
     #[serde(default, skip_serializing_if = "SinglesendSearchStatus::is_noop")]
     pub status: SinglesendSearchStatus,
+    /**
+     * the ISO 8601 time at which the Single Send was last updated
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -6040,7 +6843,7 @@ pub struct SinglesendResponseAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DesignCommonFields {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The list of categories applied to the design
      */
     #[serde(
         default,
@@ -6049,7 +6852,7 @@ pub struct DesignCommonFields {
     )]
     pub categories: Vec<String>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * If true, plain_content is always generated from html_content. If false, plain_content is not altered.
      */
     #[serde(
         default,
@@ -6057,7 +6860,7 @@ pub struct DesignCommonFields {
     )]
     pub generate_plain_content: bool,
     /**
-     * The license key provided with your New Relic account.
+     * Subject of the Design.
      */
     #[serde(
         default,
@@ -6082,18 +6885,27 @@ pub struct DesignCommonFieldsAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InvalidEmail {
+    /**
+     * A Unix timestamp indicating when the email address was added to the invalid emails list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub created: i64,
+    /**
+     * The email address that was marked as invalid.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The reason that the email address was marked as invalid.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6106,6 +6918,7 @@ pub struct InvalidEmail {
 * The message's status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailActivityResponseCommonFieldsStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -6115,8 +6928,9 @@ pub enum EmailActivityResponseCommonFieldsStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
@@ -6126,12 +6940,33 @@ impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
             EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
             EmailActivityResponseCommonFieldsStatus::Processed => "processed",
             EmailActivityResponseCommonFieldsStatus::Noop => "",
-            EmailActivityResponseCommonFieldsStatus::FallthroughString => "*",
+            EmailActivityResponseCommonFieldsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EmailActivityResponseCommonFieldsStatus {
+    fn from(s: String) -> EmailActivityResponseCommonFieldsStatus {
+        match s.as_str() {
+            "delivered" => EmailActivityResponseCommonFieldsStatus::Delivered,
+            "not delivered" => EmailActivityResponseCommonFieldsStatus::NotDelivered,
+            "processed" => EmailActivityResponseCommonFieldsStatus::Processed,
+            "" => EmailActivityResponseCommonFieldsStatus::Noop,
+            _ => EmailActivityResponseCommonFieldsStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EmailActivityResponseCommonFieldsStatus> for String {
+    fn from(e: EmailActivityResponseCommonFieldsStatus) -> String {
+        match e {
+            EmailActivityResponseCommonFieldsStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EmailActivityResponseCommonFieldsStatus {
     fn default() -> EmailActivityResponseCommonFieldsStatus {
         EmailActivityResponseCommonFieldsStatus::Noop
@@ -6145,26 +6980,41 @@ impl EmailActivityResponseCommonFieldsStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmailActivityResponseCommonFields {
+    /**
+     * The 'From' email address used to deliver the message. This address should be a verified sender in your Twilio SendGrid account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub from_email: String,
+    /**
+     * A unique ID assigned to the message. This ID can be used to retrieve activity data for the specific message.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub msg_id: String,
+    /**
+     * The message's status.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<EmailActivityResponseCommonFieldsStatus>,
+    /**
+     * The email's subject line.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /**
+     * The intended recipient's email address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6176,7 +7026,7 @@ pub struct EmailActivityResponseCommonFields {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SuppressionsRequestBody {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The array of email addresses to add or find.
      */
     #[serde(
         default,
@@ -6188,17 +7038,26 @@ pub struct SuppressionsRequestBody {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SuppressionGroupRequestBase {
+    /**
+     * A brief description of your suppression group. Required when creating a group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * Indicates if you would like this to be your default suppression group.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_default: bool,
+    /**
+     * The name of your suppression group. Required when creating a group.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6209,30 +7068,45 @@ pub struct SuppressionGroupRequestBase {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SsoCertificateBody {
+    /**
+     * A unique ID assigned to the certificate by SendGrid.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub id: f64,
+    /**
+     * An ID that matches a certificate to a specific IdP integration.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub intergration_id: String,
+    /**
+     * A unix timestamp (e.g., 1603915954) that indicates the time after which the certificate is no longer valid.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub not_after: f64,
+    /**
+     * A unix timestamp (e.g., 1603915954) that indicates the time before which the certificate is not valid.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub not_before: f64,
+    /**
+     * This certificate is used by Twilio SendGrid to verify that SAML requests are coming from Okta. This is called the X509 certificate in the Twilio SendGrid UI.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6244,7 +7118,7 @@ pub struct SsoCertificateBody {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SsoIntegration {
     /**
-     * The license key provided with your New Relic account.
+     * The URL where your IdP should POST its SAML response. This is the Twilio SendGrid URL that is responsible for receiving and parsing a SAML assertion. This is the same URL as the Single Sign-On URL when using SendGrid.
      */
     #[serde(
         default,
@@ -6253,7 +7127,7 @@ pub struct SsoIntegration {
     )]
     pub audience_url: String,
     /**
-     * The license key provided with your New Relic account.
+     * A unique ID assigned to the configuration by SendGrid.
      */
     #[serde(
         default,
@@ -6262,7 +7136,7 @@ pub struct SsoIntegration {
     )]
     pub id: String,
     /**
-     * The number of errors found while adding recipients.
+     * A timestamp representing the last time the configuration was modified.
      */
     #[serde(
         default,
@@ -6271,7 +7145,7 @@ pub struct SsoIntegration {
     )]
     pub last_updated: f64,
     /**
-     * The license key provided with your New Relic account.
+     * The URL where your IdP should POST its SAML response. This is the Twilio SendGrid URL that is responsible for receiving and parsing a SAML assertion. This is the same URL as the Audience URL when using SendGrid.
      */
     #[serde(
         default,
@@ -6296,15 +7170,18 @@ pub struct SsoIntegrationAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateIntegrationRequest {
+    /**
+     * Indicates if the integration is complete.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub completed_integration: Option<bool>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the integration is enabled.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * An identifier provided by your IdP to identify Twilio SendGrid in the SAML interaction. This is called the "SAML Issuer ID" in the Twilio SendGrid UI.
      */
     #[serde(
         default,
@@ -6313,7 +7190,7 @@ pub struct CreateIntegrationRequest {
     )]
     pub entity_id: String,
     /**
-     * The license key provided with your New Relic account.
+     * The name of your integration. This name can be anything that makes sense for your organization (eg. Twilio SendGrid)
      */
     #[serde(
         default,
@@ -6322,7 +7199,7 @@ pub struct CreateIntegrationRequest {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The IdP's SAML POST endpoint. This endpoint should receive requests and initiate an SSO login flow. This is called the "Embed Link" in the Twilio SendGrid UI.
      */
     #[serde(
         default,
@@ -6331,7 +7208,7 @@ pub struct CreateIntegrationRequest {
     )]
     pub signin_url: String,
     /**
-     * The license key provided with your New Relic account.
+     * This URL is relevant only for an IdP-initiated authentication flow. If a user authenticates from their IdP, this URL will return them to their IdP when logging out.
      */
     #[serde(
         default,
@@ -6344,7 +7221,7 @@ pub struct CreateIntegrationRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SsoTeammateResponse {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the Teammate authenticates with SendGrid using SSO or with a username and password.
      */
     #[serde(
         default,
@@ -6352,7 +7229,7 @@ pub struct SsoTeammateResponse {
     )]
     pub is_sso: bool,
     /**
-     * The license key provided with your New Relic account.
+     * This should be set to the Teammate's email address.
      */
     #[serde(
         default,
@@ -6378,7 +7255,7 @@ pub struct SsoTeammateResponseAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetScopesResponse {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The permission scopes assigned to the Teammate.
      */
     #[serde(
         default,
@@ -6405,6 +7282,7 @@ pub struct SsoTeammateRequestAllOf {
 * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserType {
     #[serde(rename = "admin")]
     Admin,
@@ -6414,8 +7292,9 @@ pub enum UserType {
     Teammate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserType {
@@ -6425,12 +7304,33 @@ impl std::fmt::Display for UserType {
             UserType::Owner => "owner",
             UserType::Teammate => "teammate",
             UserType::Noop => "",
-            UserType::FallthroughString => "*",
+            UserType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserType {
+    fn from(s: String) -> UserType {
+        match s.as_str() {
+            "admin" => UserType::Admin,
+            "owner" => UserType::Owner,
+            "teammate" => UserType::Teammate,
+            "" => UserType::Noop,
+            _ => UserType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserType> for String {
+    fn from(e: UserType) -> String {
+        match e {
+            UserType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserType {
     fn default() -> UserType {
         UserType::Noop
@@ -6445,7 +7345,7 @@ impl UserType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SsoTeammatesPatchResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s street address.
      */
     #[serde(
         default,
@@ -6454,7 +7354,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub address: String,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s apartment number, suite number, or other secondary address information that is not part of the physical street address.
      */
     #[serde(
         default,
@@ -6464,7 +7364,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub address_2: String,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate's city.
      */
     #[serde(
         default,
@@ -6473,7 +7373,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub city: String,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s company name.
      */
     #[serde(
         default,
@@ -6482,7 +7382,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub company: String,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s country of residence.
      */
     #[serde(
         default,
@@ -6490,9 +7390,6 @@ pub struct SsoTeammatesPatchResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6500,7 +7397,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s stored phone number.
      */
     #[serde(
         default,
@@ -6509,7 +7406,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub phone: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The permission scopes assigned to the Teammate.
      */
     #[serde(
         default,
@@ -6518,7 +7415,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub scopes: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s state or province.
      */
     #[serde(
         default,
@@ -6532,7 +7429,7 @@ pub struct SsoTeammatesPatchResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_type: Option<UserType>,
     /**
-     * The license key provided with your New Relic account.
+     * A website associated with the Teammate
      */
     #[serde(
         default,
@@ -6541,7 +7438,7 @@ pub struct SsoTeammatesPatchResponse {
     )]
     pub website: String,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s zip code.
      */
     #[serde(
         default,
@@ -6566,27 +7463,18 @@ pub struct SsoTeammatesPatchResponseAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SsoErrorResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6598,7 +7486,7 @@ pub struct SsoErrorResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ClickTracking {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if click tracking is enabled for plain text emails.
      */
     #[serde(
         default,
@@ -6606,7 +7494,7 @@ pub struct ClickTracking {
     )]
     pub enable_text: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if click tracking is enabled or disabled.
      */
     #[serde(
         default,
@@ -6618,7 +7506,7 @@ pub struct ClickTracking {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SsoTeammateCommonFields {
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s email address. This email address will also function as the Teammate’s username and must match the address assigned to the user in your IdP. This address cannot be changed after the Teammate is created.
      */
     #[serde(
         default,
@@ -6627,7 +7515,7 @@ pub struct SsoTeammateCommonFields {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s first name.
      */
     #[serde(
         default,
@@ -6635,18 +7523,24 @@ pub struct SsoTeammateCommonFields {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
+    /**
+     * Indicates if the Teammate has admin permissions.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_admin: bool,
+    /**
+     * Indicates if the Teammate has read_only permissions.
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_read_only: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The Teammate’s last name.
      */
     #[serde(
         default,
@@ -6658,6 +7552,9 @@ pub struct SsoTeammateCommonFields {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SpamReportsResponse {
+    /**
+     * A Unix timestamp that indicates when the recipient marked your message as spam.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6665,7 +7562,7 @@ pub struct SpamReportsResponse {
     )]
     pub created: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address of the recipient that marked your message as spam.
      */
     #[serde(
         default,
@@ -6674,7 +7571,7 @@ pub struct SpamReportsResponse {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The IP address that the message was sent from.
      */
     #[serde(
         default,
@@ -6686,6 +7583,9 @@ pub struct SpamReportsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BlocksResponse {
+    /**
+     * A Unix timestamp indicating when the email address was added to the blocks list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6693,7 +7593,7 @@ pub struct BlocksResponse {
     )]
     pub created: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address that was added to the block list.
      */
     #[serde(
         default,
@@ -6702,7 +7602,7 @@ pub struct BlocksResponse {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * An explanation for the reason of the block.
      */
     #[serde(
         default,
@@ -6711,7 +7611,7 @@ pub struct BlocksResponse {
     )]
     pub reason: String,
     /**
-     * The license key provided with your New Relic account.
+     * The status of the block.
      */
     #[serde(
         default,
@@ -6723,6 +7623,9 @@ pub struct BlocksResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IpPoolsPoolResp {
+    /**
+     * The name of the IP pool.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6734,7 +7637,7 @@ pub struct IpPoolsPoolResp {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SenderRequestFrom {
     /**
-     * The license key provided with your New Relic account.
+     * The email address from which your recipient will receive emails.
      */
     #[serde(
         default,
@@ -6743,7 +7646,7 @@ pub struct SenderRequestFrom {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The name appended to the from email field. Typically your name or company name.
      */
     #[serde(
         default,
@@ -6755,24 +7658,36 @@ pub struct SenderRequestFrom {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SenderRequest {
+    /**
+     * The physical address of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address: String,
+    /**
+     * Additional sender identity address information.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_2: String,
+    /**
+     * The city of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
+    /**
+     * The country of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6781,6 +7696,9 @@ pub struct SenderRequest {
     pub country: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub from: Option<SenderRequestFrom>,
+    /**
+     * A nickname for the sender identity. Not used for sending.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6789,12 +7707,18 @@ pub struct SenderRequest {
     pub nickname: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<SenderRequestFrom>,
+    /**
+     * The state of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /**
+     * The zipcode of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6807,7 +7731,7 @@ pub struct SenderRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentStatusResponse {
     /**
-     * Segment status indicates whether the segment's contacts will be updated periodically
+     * Describes any errors that were encountered during query validation
      */
     #[serde(
         default,
@@ -6816,7 +7740,7 @@ pub struct SegmentStatusResponse {
     )]
     pub error_message: String,
     /**
-     * The license key provided with your New Relic account.
+     * Status of query validation. PENDING, VALID, or INVALID
      */
     #[serde(
         default,
@@ -6830,6 +7754,9 @@ pub struct SegmentStatusResponse {
 pub struct AllSegmentsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<MetadataType>,
+    /**
+     * Total number of contacts present in the segment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -6837,7 +7764,7 @@ pub struct AllSegmentsResponse {
     )]
     pub contacts_count: i64,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the object was created
      */
     #[serde(
         default,
@@ -6846,7 +7773,7 @@ pub struct AllSegmentsResponse {
     )]
     pub created_at: String,
     /**
-     * The license key provided with your New Relic account.
+     * ID assigned to the segment when created.
      */
     #[serde(
         default,
@@ -6855,7 +7782,7 @@ pub struct AllSegmentsResponse {
     )]
     pub id: String,
     /**
-     * The license key provided with your New Relic account.
+     * Name of the segment.
      */
     #[serde(
         default,
@@ -6864,7 +7791,7 @@ pub struct AllSegmentsResponse {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the samples will be next updated
      */
     #[serde(
         default,
@@ -6873,7 +7800,7 @@ pub struct AllSegmentsResponse {
     )]
     pub next_sample_update: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The array of list ids to filter contacts on when building this segment. It allows only one such list id for now. We will support more in future
      */
     #[serde(
         default,
@@ -6882,7 +7809,7 @@ pub struct AllSegmentsResponse {
     )]
     pub parent_list_ids: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * If not set, segment contains a query for use with Segment v1 APIs. If set to '2', segment contains a SQL query for use in v2.
      */
     #[serde(
         default,
@@ -6891,7 +7818,7 @@ pub struct AllSegmentsResponse {
     )]
     pub query_version: String,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the samples were last updated
      */
     #[serde(
         default,
@@ -6905,7 +7832,7 @@ pub struct AllSegmentsResponse {
     #[serde()]
     pub status: SegmentStatusResponse,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the object was last updated
      */
     #[serde(
         default,
@@ -6918,9 +7845,6 @@ pub struct AllSegmentsResponse {
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentSummaryV2 {
-    /**
-     *
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -6931,12 +7855,18 @@ pub struct SegmentSummaryV2 {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentResponse {
+    /**
+     * Total number of contacts present in the segment
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub contacts_count: i64,
+    /**
+     * A subset of all contacts that are in this segment
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -6944,7 +7874,7 @@ pub struct SegmentResponse {
     )]
     pub contacts_sample: Vec<ContactResponse>,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the object was created
      */
     #[serde(
         default,
@@ -6953,7 +7883,7 @@ pub struct SegmentResponse {
     )]
     pub created_at: String,
     /**
-     * The license key provided with your New Relic account.
+     * ID assigned to the segment when created.
      */
     #[serde(
         default,
@@ -6962,7 +7892,7 @@ pub struct SegmentResponse {
     )]
     pub id: String,
     /**
-     * The license key provided with your New Relic account.
+     * Name of the segment.
      */
     #[serde(
         default,
@@ -6971,7 +7901,7 @@ pub struct SegmentResponse {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the samples will be next updated
      */
     #[serde(
         default,
@@ -6980,7 +7910,7 @@ pub struct SegmentResponse {
     )]
     pub next_sample_update: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The array of list ids to filter contacts on when building this segment. It allows only one such list id for now. We will support more in future
      */
     #[serde(
         default,
@@ -6989,7 +7919,7 @@ pub struct SegmentResponse {
     )]
     pub parent_list_ids: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * SQL query which will filter contacts based on the conditions provided
      */
     #[serde(
         default,
@@ -6998,7 +7928,7 @@ pub struct SegmentResponse {
     )]
     pub query_dsl: String,
     /**
-     * The license key provided with your New Relic account.
+     * If not set, segment contains a Query for use with Segment v1 APIs. If set to '2', segment contains a SQL query for use in v2.
      */
     #[serde(
         default,
@@ -7007,7 +7937,7 @@ pub struct SegmentResponse {
     )]
     pub query_version: String,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the samples were last updated
      */
     #[serde(
         default,
@@ -7021,7 +7951,7 @@ pub struct SegmentResponse {
     #[serde()]
     pub status: SegmentStatusResponse,
     /**
-     * The license key provided with your New Relic account.
+     * ISO8601 timestamp of when the object was last updated
      */
     #[serde(
         default,
@@ -7034,7 +7964,7 @@ pub struct SegmentResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ErrorsSeg {
     /**
-     * The license key provided with your New Relic account.
+     * the field in the request body that is incorrect
      */
     #[serde(
         default,
@@ -7043,7 +7973,7 @@ pub struct ErrorsSeg {
     )]
     pub field: String,
     /**
-     * The license key provided with your New Relic account.
+     * a description of what is specifically wrong with the field passed in the request
      */
     #[serde(
         default,
@@ -7067,7 +7997,7 @@ pub struct ErrorsSegData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentWriteV2 {
     /**
-     * The license key provided with your New Relic account.
+     * Name of the segment.
      */
     #[serde(
         default,
@@ -7075,6 +8005,9 @@ pub struct SegmentWriteV2 {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The array of list ids to filter contacts on when building this segment. It allows only one such list id for now. We will support more in future
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -7082,7 +8015,7 @@ pub struct SegmentWriteV2 {
     )]
     pub parent_list_ids: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * SQL query which will filter contacts based on the conditions provided
      */
     #[serde(
         default,
@@ -7094,12 +8027,18 @@ pub struct SegmentWriteV2 {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SegmentUpdate {
+    /**
+     * Name of the segment.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * SQL query which will filter contacts based on the conditions provided
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7109,6 +8048,7 @@ pub struct SegmentUpdate {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AbbvMessageStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -7118,8 +8058,9 @@ pub enum AbbvMessageStatus {
     Processed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbbvMessageStatus {
@@ -7129,12 +8070,33 @@ impl std::fmt::Display for AbbvMessageStatus {
             AbbvMessageStatus::NotDelivered => "not_delivered",
             AbbvMessageStatus::Processed => "processed",
             AbbvMessageStatus::Noop => "",
-            AbbvMessageStatus::FallthroughString => "*",
+            AbbvMessageStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AbbvMessageStatus {
+    fn from(s: String) -> AbbvMessageStatus {
+        match s.as_str() {
+            "delivered" => AbbvMessageStatus::Delivered,
+            "not_delivered" => AbbvMessageStatus::NotDelivered,
+            "processed" => AbbvMessageStatus::Processed,
+            "" => AbbvMessageStatus::Noop,
+            _ => AbbvMessageStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AbbvMessageStatus> for String {
+    fn from(e: AbbvMessageStatus) -> String {
+        match e {
+            AbbvMessageStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AbbvMessageStatus {
     fn default() -> AbbvMessageStatus {
         AbbvMessageStatus::Noop
@@ -7154,9 +8116,6 @@ pub struct Messages {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub clicks_count: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7164,7 +8123,7 @@ pub struct Messages {
     )]
     pub from_email: String,
     /**
-     * The license key provided with your New Relic account.
+     * iso 8601 format
      */
     #[serde(
         default,
@@ -7172,9 +8131,6 @@ pub struct Messages {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_event_time: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7189,18 +8145,12 @@ pub struct Messages {
     pub opens_count: i64,
     #[serde(default, skip_serializing_if = "AbbvMessageStatus::is_noop")]
     pub status: AbbvMessageStatus,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7213,6 +8163,7 @@ pub struct Messages {
 * Name of event
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EventName {
     #[serde(rename = "bounced")]
     Bounced,
@@ -7238,8 +8189,9 @@ pub enum EventName {
     Unsubscribe,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EventName {
@@ -7257,12 +8209,41 @@ impl std::fmt::Display for EventName {
             EventName::SpamReport => "spam_report",
             EventName::Unsubscribe => "unsubscribe",
             EventName::Noop => "",
-            EventName::FallthroughString => "*",
+            EventName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EventName {
+    fn from(s: String) -> EventName {
+        match s.as_str() {
+            "bounced" => EventName::Bounced,
+            "clicked" => EventName::Clicked,
+            "deferred" => EventName::Deferred,
+            "delivered" => EventName::Delivered,
+            "dropped" => EventName::Dropped,
+            "group_resubscribe" => EventName::GroupResubscribe,
+            "group_unsubscribe" => EventName::GroupUnsubscribe,
+            "opened" => EventName::Opened,
+            "processed" => EventName::Processed,
+            "spam_report" => EventName::SpamReport,
+            "unsubscribe" => EventName::Unsubscribe,
+            "" => EventName::Noop,
+            _ => EventName::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EventName> for String {
+    fn from(e: EventName) -> String {
+        match e {
+            EventName::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EventName {
     fn default() -> EventName {
         EventName::Noop
@@ -7278,6 +8259,7 @@ impl EventName {
 * Use to distinguish between types of bounces
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BounceType {
     #[serde(rename = "blocked")]
     Blocked,
@@ -7287,8 +8269,9 @@ pub enum BounceType {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BounceType {
@@ -7298,12 +8281,33 @@ impl std::fmt::Display for BounceType {
             BounceType::Bounced => "bounced",
             BounceType::Expired => "expired",
             BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BounceType {
+    fn from(s: String) -> BounceType {
+        match s.as_str() {
+            "blocked" => BounceType::Blocked,
+            "bounced" => BounceType::Bounced,
+            "expired" => BounceType::Expired,
+            "" => BounceType::Noop,
+            _ => BounceType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BounceType> for String {
+    fn from(e: BounceType) -> String {
+        match e {
+            BounceType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BounceType {
     fn default() -> BounceType {
         BounceType::Noop
@@ -7317,6 +8321,9 @@ impl BounceType {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Event {
+    /**
+     * Used with "deferred" events to indicate the attempt number out of 10. One "deferred" entry will exists under events array for each time a message was deferred with error message from the server.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -7334,7 +8341,7 @@ pub struct Event {
     #[serde(default, skip_serializing_if = "EventName::is_noop")]
     pub event_name: EventName,
     /**
-     * The license key provided with your New Relic account.
+     * Client recipient used to click or open message
      */
     #[serde(
         default,
@@ -7343,7 +8350,7 @@ pub struct Event {
     )]
     pub http_user_agent: String,
     /**
-     * The license key provided with your New Relic account.
+     * For example mx.gmail.com
      */
     #[serde(
         default,
@@ -7352,7 +8359,7 @@ pub struct Event {
     )]
     pub mx_server: String,
     /**
-     * The license key provided with your New Relic account.
+     * Date of when event occurred
      */
     #[serde(
         default,
@@ -7360,6 +8367,9 @@ pub struct Event {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub processed: String,
+    /**
+     * Explanation of what caused "bounced", "deferred", or "blocked". Usually contains error message from the server - e.g. message from gmail why mail was deferred
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7367,7 +8377,7 @@ pub struct Event {
     )]
     pub reason: String,
     /**
-     * The license key provided with your New Relic account.
+     * Used with "clicked" event to indicate which url the user clicked.
      */
     #[serde(
         default,
@@ -7381,6 +8391,7 @@ pub struct Event {
 * Whether or not the outbound IP is dedicated vs shared
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OutboundIpType {
     #[serde(rename = "dedicated")]
     Dedicated,
@@ -7388,8 +8399,9 @@ pub enum OutboundIpType {
     Shared,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OutboundIpType {
@@ -7398,12 +8410,32 @@ impl std::fmt::Display for OutboundIpType {
             OutboundIpType::Dedicated => "dedicated",
             OutboundIpType::Shared => "shared",
             OutboundIpType::Noop => "",
-            OutboundIpType::FallthroughString => "*",
+            OutboundIpType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OutboundIpType {
+    fn from(s: String) -> OutboundIpType {
+        match s.as_str() {
+            "dedicated" => OutboundIpType::Dedicated,
+            "shared" => OutboundIpType::Shared,
+            "" => OutboundIpType::Noop,
+            _ => OutboundIpType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OutboundIpType> for String {
+    fn from(e: OutboundIpType) -> String {
+        match e {
+            OutboundIpType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OutboundIpType {
     fn default() -> OutboundIpType {
         OutboundIpType::Noop
@@ -7417,9 +8449,6 @@ impl OutboundIpType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Message {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7433,7 +8462,7 @@ pub struct Message {
     )]
     pub asm_group_id: i64,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * Categories users associated to the message
      */
     #[serde(
         default,
@@ -7450,18 +8479,12 @@ pub struct Message {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub events: Vec<Event>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub from_email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7474,7 +8497,7 @@ pub struct Message {
     #[serde()]
     pub originating_ip: std::net::Ipv4Addr,
     /**
-     * This is the IP of the user who sent the message.
+     * IP used to send to the remote MTA. Used by UI to display IP in detailed view
      */
     #[serde()]
     pub outbound_ip: std::net::Ipv4Addr,
@@ -7484,16 +8507,13 @@ pub struct Message {
     #[serde(default, skip_serializing_if = "OutboundIpType::is_noop")]
     pub outbound_ip_type: OutboundIpType,
     /**
-     * The message's status.
+     * Quick summary of the status of a message
      */
     #[serde(
         default,
         skip_serializing_if = "EmailActivityResponseCommonFieldsStatus::is_noop"
     )]
     pub status: EmailActivityResponseCommonFieldsStatus,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7501,7 +8521,7 @@ pub struct Message {
     )]
     pub subject: String,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's username
      */
     #[serde(
         default,
@@ -7509,18 +8529,12 @@ pub struct Message {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub teammate: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub template_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7528,7 +8542,7 @@ pub struct Message {
     )]
     pub to_email: String,
     /**
-     * The license key provided with your New Relic account.
+     * JSON hash of arbitrary key-value pairs
      */
     #[serde(
         default,
@@ -7547,17 +8561,17 @@ pub struct DomainAuthenticationDns {
     #[serde()]
     pub dkim: Dkim,
     /**
-     * The DKIM record for messages sent using this authenticated domain.
+     * The SPF record for the root domain.
      */
     #[serde()]
     pub domain_spf: Dkim,
     /**
-     * The DKIM record for messages sent using this authenticated domain.
+     * Designates which mail server is responsible for accepting messages from a domain.
      */
     #[serde()]
     pub mail_server: Dkim,
     /**
-     * The DKIM record for messages sent using this authenticated domain.
+     * The SPF record for the subdomain used to create this authenticated domain.
      */
     #[serde()]
     pub subdomain_spf: Dkim,
@@ -7566,7 +8580,7 @@ pub struct DomainAuthenticationDns {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DomainAuthentication {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this authenticated domain uses automated security.
      */
     #[serde(
         default,
@@ -7574,7 +8588,7 @@ pub struct DomainAuthentication {
     )]
     pub automatic_security: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this authenticated domain uses custom SPF.
      */
     #[serde(
         default,
@@ -7582,7 +8596,7 @@ pub struct DomainAuthentication {
     )]
     pub custom_spf: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is the default domain.
      */
     #[serde(
         default,
@@ -7595,7 +8609,7 @@ pub struct DomainAuthentication {
     #[serde()]
     pub dns: DomainAuthenticationDns,
     /**
-     * The license key provided with your New Relic account.
+     * The domain authenticated.
      */
     #[serde(
         default,
@@ -7603,6 +8617,9 @@ pub struct DomainAuthentication {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub domain: String,
+    /**
+     * The ID of the authenticated domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -7610,7 +8627,7 @@ pub struct DomainAuthentication {
     )]
     pub id: i64,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The IP addresses that are included in the SPF record for this authenticated domain.
      */
     #[serde(
         default,
@@ -7619,19 +8636,25 @@ pub struct DomainAuthentication {
     )]
     pub ips: Vec<String>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this authenticated domain was created using the legacy whitelabel tool. If it is a legacy whitelabel, it will still function, but you'll need to create a new authenticated domain if you need to update it.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub legacy: bool,
+    /**
+     * The subdomain that was used to create this authenticated domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subdomain: String,
+    /**
+     * The user_id of the account that this authenticated domain is associated with.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -7639,7 +8662,7 @@ pub struct DomainAuthentication {
     )]
     pub user_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The username of the account that this authenticated domain is associated with.
      */
     #[serde(
         default,
@@ -7648,7 +8671,7 @@ pub struct DomainAuthentication {
     )]
     pub username: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is a valid authenticated domain .
      */
     #[serde(
         default,
@@ -7661,17 +8684,17 @@ pub struct DomainAuthentication {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthenticationDomainDns {
     /**
-     * The DKIM record for messages sent using this authenticated domain.
+     * A DNS record.
      */
     #[serde(rename = "dkim1")]
     pub dkim_1: Dkim,
     /**
-     * The DKIM record for messages sent using this authenticated domain.
+     * A DNS record.
      */
     #[serde(rename = "dkim2")]
     pub dkim_2: Dkim,
     /**
-     * The DKIM record for messages sent using this authenticated domain.
+     * The CNAME for your sending domain that points to sendgrid.net.
      */
     #[serde()]
     pub mail_cname: Dkim,
@@ -7680,7 +8703,7 @@ pub struct AuthenticationDomainDns {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuthenticationDomain {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this authenticated domain uses automated security.
      */
     #[serde(
         default,
@@ -7688,7 +8711,7 @@ pub struct AuthenticationDomain {
     )]
     pub automatic_security: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates whether this authenticated domain uses custom SPF.
      */
     #[serde(
         default,
@@ -7696,7 +8719,7 @@ pub struct AuthenticationDomain {
     )]
     pub custom_spf: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is the default authenticated domain.
      */
     #[serde(
         default,
@@ -7709,7 +8732,7 @@ pub struct AuthenticationDomain {
     #[serde()]
     pub dns: AuthenticationDomainDns,
     /**
-     * The license key provided with your New Relic account.
+     * The domain to be authenticated.
      */
     #[serde(
         default,
@@ -7718,7 +8741,7 @@ pub struct AuthenticationDomain {
     )]
     pub domain: String,
     /**
-     * The number of errors found while adding recipients.
+     * The ID of the authenticated domain.
      */
     #[serde(
         default,
@@ -7727,7 +8750,7 @@ pub struct AuthenticationDomain {
     )]
     pub id: f64,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The IPs to be included in the custom SPF record for this authenticated domain.
      */
     #[serde(
         default,
@@ -7736,7 +8759,7 @@ pub struct AuthenticationDomain {
     )]
     pub ips: Vec<String>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this authenticated domain was created using the legacy whitelabel tool. If it is a legacy whitelabel, it will still function, but you'll need to create a new authenticated domain if you need to update it.
      */
     #[serde(
         default,
@@ -7744,7 +8767,7 @@ pub struct AuthenticationDomain {
     )]
     pub legacy: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The subdomain to use for this authenticated domain.
      */
     #[serde(
         default,
@@ -7753,7 +8776,7 @@ pub struct AuthenticationDomain {
     )]
     pub subdomain: String,
     /**
-     * The number of errors found while adding recipients.
+     * The ID of the user that this domain is associated with.
      */
     #[serde(
         default,
@@ -7762,7 +8785,7 @@ pub struct AuthenticationDomain {
     )]
     pub user_id: f64,
     /**
-     * The license key provided with your New Relic account.
+     * The username that this domain will be associated with.
      */
     #[serde(
         default,
@@ -7771,7 +8794,7 @@ pub struct AuthenticationDomain {
     )]
     pub username: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is a valid authenticated domain.
      */
     #[serde(
         default,
@@ -7784,13 +8807,15 @@ pub struct AuthenticationDomain {
 * Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AggregatedBy {
     #[serde(rename = "day")]
     Day,
     #[serde(rename = "total")]
     Total,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AggregatedBy {
@@ -7798,12 +8823,31 @@ impl std::fmt::Display for AggregatedBy {
         match self {
             AggregatedBy::Day => "day",
             AggregatedBy::Total => "total",
-            AggregatedBy::FallthroughString => "*",
+            AggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AggregatedBy {
+    fn from(s: String) -> AggregatedBy {
+        match s.as_str() {
+            "day" => AggregatedBy::Day,
+            "total" => AggregatedBy::Total,
+            _ => AggregatedBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AggregatedBy> for String {
+    fn from(e: AggregatedBy) -> String {
+        match e {
+            AggregatedBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AggregatedBy {
     fn default() -> AggregatedBy {
         AggregatedBy::Total
@@ -7811,6 +8855,7 @@ impl Default for AggregatedBy {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GroupBy {
     #[serde(rename = "ab_phase")]
     AbPhase,
@@ -7818,8 +8863,9 @@ pub enum GroupBy {
     AbVariation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GroupBy {
@@ -7828,12 +8874,32 @@ impl std::fmt::Display for GroupBy {
             GroupBy::AbPhase => "ab_phase",
             GroupBy::AbVariation => "ab_variation",
             GroupBy::Noop => "",
-            GroupBy::FallthroughString => "*",
+            GroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GroupBy {
+    fn from(s: String) -> GroupBy {
+        match s.as_str() {
+            "ab_phase" => GroupBy::AbPhase,
+            "ab_variation" => GroupBy::AbVariation,
+            "" => GroupBy::Noop,
+            _ => GroupBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GroupBy> for String {
+    fn from(e: GroupBy) -> String {
+        match e {
+            GroupBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GroupBy {
     fn default() -> GroupBy {
         GroupBy::Noop
@@ -7846,13 +8912,15 @@ impl GroupBy {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TraitAutomationQueryParamsGroupBy {
     #[serde(rename = "step_id")]
     StepId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
@@ -7860,12 +8928,31 @@ impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
         match self {
             TraitAutomationQueryParamsGroupBy::StepId => "step_id",
             TraitAutomationQueryParamsGroupBy::Noop => "",
-            TraitAutomationQueryParamsGroupBy::FallthroughString => "*",
+            TraitAutomationQueryParamsGroupBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TraitAutomationQueryParamsGroupBy {
+    fn from(s: String) -> TraitAutomationQueryParamsGroupBy {
+        match s.as_str() {
+            "step_id" => TraitAutomationQueryParamsGroupBy::StepId,
+            "" => TraitAutomationQueryParamsGroupBy::Noop,
+            _ => TraitAutomationQueryParamsGroupBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TraitAutomationQueryParamsGroupBy> for String {
+    fn from(e: TraitAutomationQueryParamsGroupBy) -> String {
+        match e {
+            TraitAutomationQueryParamsGroupBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TraitAutomationQueryParamsGroupBy {
     fn default() -> TraitAutomationQueryParamsGroupBy {
         TraitAutomationQueryParamsGroupBy::Noop
@@ -7878,6 +8965,7 @@ impl TraitAutomationQueryParamsGroupBy {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AbPhaseId {
     #[serde(rename = "send")]
     Send,
@@ -7885,8 +8973,9 @@ pub enum AbPhaseId {
     Test,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AbPhaseId {
@@ -7895,12 +8984,32 @@ impl std::fmt::Display for AbPhaseId {
             AbPhaseId::Send => "send",
             AbPhaseId::Test => "test",
             AbPhaseId::Noop => "",
-            AbPhaseId::FallthroughString => "*",
+            AbPhaseId::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AbPhaseId {
+    fn from(s: String) -> AbPhaseId {
+        match s.as_str() {
+            "send" => AbPhaseId::Send,
+            "test" => AbPhaseId::Test,
+            "" => AbPhaseId::Noop,
+            _ => AbPhaseId::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AbPhaseId> for String {
+    fn from(e: AbPhaseId) -> String {
+        match e {
+            AbPhaseId::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AbPhaseId {
     fn default() -> AbPhaseId {
         AbPhaseId::Noop
@@ -7916,6 +9025,7 @@ impl AbPhaseId {
 * How to group the statistics. Must be either "day", "week", or "month".
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     #[serde(rename = "day")]
     Day,
@@ -7925,8 +9035,9 @@ pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     Week,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
@@ -7936,12 +9047,33 @@ impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
             TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop => "",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::FallthroughString => "*",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn from(s: String) -> TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+        match s.as_str() {
+            "day" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day,
+            "month" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month,
+            "week" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week,
+            "" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop,
+            _ => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TraitStatsAdvancedBaseQueryStringsAggregatedBy> for String {
+    fn from(e: TraitStatsAdvancedBaseQueryStringsAggregatedBy) -> String {
+        match e {
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     fn default() -> TraitStatsAdvancedBaseQueryStringsAggregatedBy {
         TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop
@@ -7961,9 +9093,6 @@ pub struct TraitPagination200Response {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TraitGlobalErrors500Response {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -7984,23 +9113,14 @@ pub struct GetMessagesMsgResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TraitCancelScheduledSendsErrors400Response {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * helper text or docs for troubleshooting
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub help: Option<Help>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8017,9 +9137,6 @@ pub struct TraitCancelScheduledSendsErrors400ResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub errors: Vec<TraitCancelScheduledSendsErrors400Response>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8031,7 +9148,7 @@ pub struct TraitCancelScheduledSendsErrors400ResponseData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TraitMakoErrorResponse400Errors {
     /**
-     * The license key provided with your New Relic account.
+     * The ID associated with the error.
      */
     #[serde(
         default,
@@ -8040,7 +9157,7 @@ pub struct TraitMakoErrorResponse400Errors {
     )]
     pub error_id: String,
     /**
-     * The license key provided with your New Relic account.
+     * The field that generated the error.
      */
     #[serde(
         default,
@@ -8049,7 +9166,7 @@ pub struct TraitMakoErrorResponse400Errors {
     )]
     pub field: String,
     /**
-     * The license key provided with your New Relic account.
+     * The error message.
      */
     #[serde(
         default,
@@ -8057,9 +9174,6 @@ pub struct TraitMakoErrorResponse400Errors {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8081,7 +9195,7 @@ pub struct TraitMakoErrorResponse400 {
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Personalizations {
     /**
-     * An array of recipients who will receive a copy of your email. Each object in this array must contain the recipient's email address. Each object in the array may optionally contain the recipient's name.
+     * An array of recipients who will receive a blind carbon copy of your email. Each object in this array must contain the recipient's email address. Each object in the array may optionally contain the recipient's name.
      */
     #[serde(
         default,
@@ -8099,22 +9213,25 @@ pub struct Personalizations {
     )]
     pub cc: Vec<CcBccEmailObject>,
     /**
-     * helper text or docs for troubleshooting
+     * Values that are specific to this personalization that will be carried along with the email and its activity data. Substitutions will not be made on custom arguments, so any string that is entered into this parameter will be assumed to be the custom argument that you would like to be used. This field may not exceed 10,000 bytes.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_args: Option<Help>,
     /**
-     * helper text or docs for troubleshooting
+     * Dynamic template data is available using Handlebars syntax in Dynamic Transactional Templates. This field should be used in combination with a Dynamic Transactional Template, which can be identified by a `template_id` starting with `d-`. This field is a collection of key/value pairs following the pattern "variable_name":"value to insert".
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dynamic_template_data: Option<Help>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub from: Option<FromEmailObject>,
     /**
-     * helper text or docs for troubleshooting
+     * A collection of JSON key/value pairs allowing you to specify handling instructions for your email. You may not overwrite the following headers: `x-sg-id`, `x-sg-eid`, `received`, `dkim-signature`, `Content-Type`, `Content-Transfer-Encoding`, `To`, `From`, `Subject`, `Reply-To`, `CC`, `BCC`
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub headers: Option<Help>,
+    /**
+     * A unix timestamp allowing you to specify when your email should be delivered. Scheduling delivery more than 72 hours in advance is forbidden.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -8122,7 +9239,7 @@ pub struct Personalizations {
     )]
     pub send_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The subject of your email. See character length requirements according to [RFC 2822](http://stackoverflow.com/questions/1592291/what-is-the-email-subject-length-limit#answer-1592310).
      */
     #[serde(
         default,
@@ -8131,7 +9248,7 @@ pub struct Personalizations {
     )]
     pub subject: String,
     /**
-     * helper text or docs for troubleshooting
+     * Substitutions allow you to insert data without using Dynamic Transactional Templates. This field should \*\*not\*\* be used in combination with a Dynamic Transactional Template, which can be identified by a `template_id` starting with `d-`. This field is a collection of key/value pairs following the pattern "substitution_tag":"value to substitute". The key/value pairs must be strings. These substitutions will apply to the text and html content of the body of your email, in addition to the `subject` and `reply-to` parameters. The total collective size of your substitutions may not exceed 10,000 bytes per personalization object.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub substitutions: Option<Help>,
@@ -8146,7 +9263,7 @@ pub struct Personalizations {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Content {
     /**
-     * The license key provided with your New Relic account.
+     * The MIME type of the content you are including in your email (e.g., `“text/plain”` or `“text/html”`).
      */
     #[serde(
         default,
@@ -8156,7 +9273,7 @@ pub struct Content {
     )]
     pub type_: String,
     /**
-     * The license key provided with your New Relic account.
+     * The actual content of the specified MIME type that you are including in your email.
      */
     #[serde(
         default,
@@ -8170,13 +9287,15 @@ pub struct Content {
 * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Disposition {
     #[serde(rename = "attachment")]
     Attachment,
     #[serde(rename = "inline")]
     Inline,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Disposition {
@@ -8184,12 +9303,31 @@ impl std::fmt::Display for Disposition {
         match self {
             Disposition::Attachment => "attachment",
             Disposition::Inline => "inline",
-            Disposition::FallthroughString => "*",
+            Disposition::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Disposition {
+    fn from(s: String) -> Disposition {
+        match s.as_str() {
+            "attachment" => Disposition::Attachment,
+            "inline" => Disposition::Inline,
+            _ => Disposition::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Disposition> for String {
+    fn from(e: Disposition) -> String {
+        match e {
+            Disposition::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Disposition {
     fn default() -> Disposition {
         Disposition::Attachment
@@ -8199,7 +9337,7 @@ impl Default for Disposition {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Attachments {
     /**
-     * The license key provided with your New Relic account.
+     * The Base64 encoded content of the attachment.
      */
     #[serde(
         default,
@@ -8208,7 +9346,7 @@ pub struct Attachments {
     )]
     pub content: String,
     /**
-     * The license key provided with your New Relic account.
+     * The attachment's content ID. This is used when the disposition is set to `“inline”` and the attachment is an image, allowing the file to be displayed within the body of your email.
      */
     #[serde(
         default,
@@ -8222,7 +9360,7 @@ pub struct Attachments {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub disposition: Option<Disposition>,
     /**
-     * The license key provided with your New Relic account.
+     * The attachment's filename.
      */
     #[serde(
         default,
@@ -8231,7 +9369,7 @@ pub struct Attachments {
     )]
     pub filename: String,
     /**
-     * The license key provided with your New Relic account.
+     * The MIME type of the content you are attaching (e.g., `“text/plain”` or `“text/html”`).
      */
     #[serde(
         default,
@@ -8245,6 +9383,9 @@ pub struct Attachments {
 /// An object allowing you to specify how to handle unsubscribes.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Asm {
+    /**
+     * The unsubscribe group to associate with this email.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -8252,7 +9393,7 @@ pub struct Asm {
     )]
     pub group_id: i64,
     /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
+     * An array containing the unsubscribe groups that you would like to be displayed on the unsubscribe preferences page.
      */
     #[serde(
         default,
@@ -8266,7 +9407,7 @@ pub struct Asm {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BypassListManagement {
     /**
-     * Allows you to bypass all unsubscribe groups and suppressions to ensure that the email is delivered to every single recipient. This should only be used in emergencies when it is absolutely necessary that every recipient receives your email. This filter cannot be combined with any other bypass filters. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8279,7 +9420,7 @@ pub struct BypassListManagement {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BypassSpamManagement {
     /**
-     * Allows you to bypass the spam report list to ensure that the email is delivered to recipients. Bounce and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8292,7 +9433,7 @@ pub struct BypassSpamManagement {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BypassBounceManagement {
     /**
-     * Allows you to bypass the bounce list to ensure that the email is delivered to recipients. Spam report and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8305,7 +9446,7 @@ pub struct BypassBounceManagement {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BypassUnsubscribeManagement {
     /**
-     * Allows you to bypass the global unsubscribe list to ensure that the email is delivered to recipients. Bounce and spam report lists will still be checked; addresses on these other lists will not receive the message. This filter applies only to global unsubscribes and will not bypass group unsubscribes. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8318,7 +9459,7 @@ pub struct BypassUnsubscribeManagement {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Footer {
     /**
-     * The default footer that you would like included on every email.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8326,7 +9467,7 @@ pub struct Footer {
     )]
     pub enable: bool,
     /**
-     * The default footer that you would like included on every email.
+     * The HTML content of your footer.
      */
     #[serde(
         default,
@@ -8335,7 +9476,7 @@ pub struct Footer {
     )]
     pub html: String,
     /**
-     * The default footer that you would like included on every email.
+     * The plain text content of your footer.
      */
     #[serde(
         default,
@@ -8349,7 +9490,7 @@ pub struct Footer {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SandboxMode {
     /**
-     * Sandbox Mode allows you to send a test email to ensure that your request body is valid and formatted correctly.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8362,32 +9503,32 @@ pub struct SandboxMode {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailSettings {
     /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     * Allows you to bypass the bounce list to ensure that the email is delivered to recipients. Spam report and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bypass_bounce_management: Option<BypassBounceManagement>,
     /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     * Allows you to bypass all unsubscribe groups and suppressions to ensure that the email is delivered to every single recipient. This should only be used in emergencies when it is absolutely necessary that every recipient receives your email. This filter cannot be combined with any other bypass filters. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bypass_list_management: Option<BypassListManagement>,
     /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     * Allows you to bypass the spam report list to ensure that the email is delivered to recipients. Bounce and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bypass_spam_management: Option<BypassSpamManagement>,
     /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     * Allows you to bypass the global unsubscribe list to ensure that the email is delivered to recipients. Bounce and spam report lists will still be checked; addresses on these other lists will not receive the message. This filter applies only to global unsubscribes and will not bypass group unsubscribes. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bypass_unsubscribe_management: Option<BypassUnsubscribeManagement>,
     /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     * The default footer that you would like included on every email.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub footer: Option<Footer>,
     /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     * Sandbox Mode allows you to send a test email to ensure that your request body is valid and formatted correctly.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sandbox_mode: Option<SandboxMode>,
@@ -8397,7 +9538,7 @@ pub struct MailSettings {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMailSendRequestTrackingSettingsClick {
     /**
-     * Allows you to track if a recipient clicked a link in your email.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8405,7 +9546,7 @@ pub struct PostMailSendRequestTrackingSettingsClick {
     )]
     pub enable: bool,
     /**
-     * Allows you to track if a recipient clicked a link in your email.
+     * Indicates if this setting should be included in the `text/plain` portion of your email.
      */
     #[serde(
         default,
@@ -8418,7 +9559,7 @@ pub struct PostMailSendRequestTrackingSettingsClick {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OpenTracking {
     /**
-     * Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8426,7 +9567,7 @@ pub struct OpenTracking {
     )]
     pub enable: bool,
     /**
-     * Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
+     * Allows you to specify a substitution tag that you can insert in the body of your email at a location that you desire. This tag will be replaced by the open tracking pixel.
      */
     #[serde(
         default,
@@ -8440,7 +9581,7 @@ pub struct OpenTracking {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SubscriptionTracking {
     /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8448,7 +9589,7 @@ pub struct SubscriptionTracking {
     )]
     pub enable: bool,
     /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
+     * HTML to be appended to the email with the subscription tracking link. You may control where the link is by using the tag <% %>
      */
     #[serde(
         default,
@@ -8457,7 +9598,7 @@ pub struct SubscriptionTracking {
     )]
     pub html: String,
     /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
+     * A tag that will be replaced with the unsubscribe URL. for example: `[unsubscribe_url]`. If this parameter is used, it will override both the `text` and `html` parameters. The URL of the link will be placed at the substitution tag’s location with no additional formatting.
      */
     #[serde(
         default,
@@ -8466,7 +9607,7 @@ pub struct SubscriptionTracking {
     )]
     pub substitution_tag: String,
     /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
+     * Text to be appended to the email with the subscription tracking link. You may control where the link is by using the tag <% %>
      */
     #[serde(
         default,
@@ -8480,7 +9621,7 @@ pub struct SubscriptionTracking {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Ganalytics {
     /**
-     * Allows you to enable tracking provided by Google Analytics.
+     * Indicates if this setting is enabled.
      */
     #[serde(
         default,
@@ -8488,7 +9629,7 @@ pub struct Ganalytics {
     )]
     pub enable: bool,
     /**
-     * Allows you to enable tracking provided by Google Analytics.
+     * The name of the campaign.
      */
     #[serde(
         default,
@@ -8497,7 +9638,7 @@ pub struct Ganalytics {
     )]
     pub utm_campaign: String,
     /**
-     * Allows you to enable tracking provided by Google Analytics.
+     * Used to differentiate your campaign from advertisements.
      */
     #[serde(
         default,
@@ -8506,7 +9647,7 @@ pub struct Ganalytics {
     )]
     pub utm_content: String,
     /**
-     * Allows you to enable tracking provided by Google Analytics.
+     * Name of the marketing medium. (e.g. Email)
      */
     #[serde(
         default,
@@ -8515,7 +9656,7 @@ pub struct Ganalytics {
     )]
     pub utm_medium: String,
     /**
-     * Allows you to enable tracking provided by Google Analytics.
+     * Name of the referrer source. (e.g. Google, SomeDomain.com, or Marketing Email)
      */
     #[serde(
         default,
@@ -8524,7 +9665,7 @@ pub struct Ganalytics {
     )]
     pub utm_source: String,
     /**
-     * Allows you to enable tracking provided by Google Analytics.
+     * Used to identify any paid keywords.
      */
     #[serde(
         default,
@@ -8538,22 +9679,22 @@ pub struct Ganalytics {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TrackingSettings {
     /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
+     * Allows you to track if a recipient clicked a link in your email.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub click_tracking: Option<PostMailSendRequestTrackingSettingsClick>,
     /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
+     * Allows you to enable tracking provided by Google Analytics.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ganalytics: Option<Ganalytics>,
     /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
+     * Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub open_tracking: Option<OpenTracking>,
     /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
+     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subscription_tracking: Option<SubscriptionTracking>,
@@ -8561,20 +9702,32 @@ pub struct TrackingSettings {
 
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMailSendRequest {
+    /**
+     * An object allowing you to specify how to handle unsubscribes.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub asm: Option<Asm>,
+    /**
+     * An array of objects where you can specify any attachments you want to include.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub attachments: Vec<Attachments>,
+    /**
+     * An ID representing a batch of emails to be sent at the same time. Including a `batch_id` in your request allows you include this email in that batch. It also enables you to cancel or pause the delivery of that batch. For more information, see the [Cancel Scheduled Sends API](https://sendgrid.com/docs/api-reference/).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub batch_id: String,
+    /**
+     * An array of category names for this message. Each category name may not exceed 255 characters.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -8590,6 +9743,9 @@ pub struct PostMailSendRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub content: Vec<Content>,
+    /**
+     * Values that are specific to the entire send that will be carried along with the email and its activity data.  Key/value pairs must be strings. Substitutions will not be made on custom arguments, so any string that is entered into this parameter will be assumed to be the custom argument that you would like to be used. This parameter is overridden by `custom_args` set at the personalizations level. Total `custom_args` size may not exceed 10,000 bytes.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8598,14 +9754,23 @@ pub struct PostMailSendRequest {
     pub custom_args: String,
     #[serde()]
     pub from: FromEmailObject,
+    /**
+     * An object containing key/value pairs of header names and the value to substitute for them. The key/value pairs must be strings. You must ensure these are properly encoded if they contain unicode characters. These headers cannot be one of the reserved headers.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub headers: Option<Help>,
+    /**
+     * The IP Pool that you would like to send this email from.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip_pool_name: String,
+    /**
+     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mail_settings: Option<MailSettings>,
     /**
@@ -8619,6 +9784,9 @@ pub struct PostMailSendRequest {
     pub personalizations: Vec<Personalizations>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<ReplyEmailObject>,
+    /**
+     * A unix timestamp allowing you to specify when you want your email to be delivered. This may be overridden by the `send_at` parameter set at the personalizations level. Delivery cannot be scheduled more than 72 hours in advance. If you have the flexibility, it's better to schedule mail for off-peak times. Most emails are scheduled and sent at the top of the hour or half hour. Scheduling email to avoid peak times — for example, scheduling at 10:53 — can result in lower deferral rates due to the reduced traffic during off-peak times.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -8626,7 +9794,7 @@ pub struct PostMailSendRequest {
     )]
     pub send_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The global or 'message level' subject of your email. This may be overridden by subject lines set in personalizations.
      */
     #[serde(
         default,
@@ -8634,12 +9802,18 @@ pub struct PostMailSendRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject: String,
+    /**
+     * An email template ID. A template that contains a subject and content — either text or html — will override any subject and content values specified at the personalizations or message level.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub template_id: String,
+    /**
+     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tracking_settings: Option<TrackingSettings>,
 }
@@ -8647,7 +9821,7 @@ pub struct PostMailSendRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CancelPauseAScheduledSendRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The batch ID is the identifier that your scheduled mail sends share.
      */
     #[serde(
         default,
@@ -8656,7 +9830,7 @@ pub struct CancelPauseAScheduledSendRequest {
     )]
     pub batch_id: String,
     /**
-     * The status of the scheduled send.
+     * The status of the send you would like to implement. This can be pause or cancel. To delete a pause or cancel status see DELETE /v3/user/scheduled_sends/{batch_id}
      */
     #[serde(default, skip_serializing_if = "Status::is_noop")]
     pub status: Status,
@@ -8675,7 +9849,7 @@ pub struct GetApiKeysResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateApiKeysRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The name you will use to describe this API Key.
      */
     #[serde(
         default,
@@ -8683,6 +9857,9 @@ pub struct CreateApiKeysRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The individual permissions that you are giving to this API Key.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -8693,36 +9870,24 @@ pub struct CreateApiKeysRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateApiKeysResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub api_key: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub api_key_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -8743,9 +9908,6 @@ pub struct GetApiKeysKeyResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutApiKeysKeyRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8762,10 +9924,13 @@ pub struct PutApiKeysKeyRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetScopesResponseErrors {
+    /**
+     * This empty field is returned instead of the list of scopes if the user making the call doesn't have the authorization required.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub field: Option<serde_json::Value>,
     /**
-     * The license key provided with your New Relic account.
+     * Explains why the scopes cannot be returned.
      */
     #[serde(
         default,
@@ -8791,7 +9956,7 @@ pub struct GetScopesResponseData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Ips {
     /**
-     * The license key provided with your New Relic account.
+     * An IP address that you want to allow.
      */
     #[serde(
         default,
@@ -8817,7 +9982,7 @@ pub struct PostAccessSettingsWhitelistRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteAccessSettingsWhitelistRequest {
     /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
+     * An array of the IDs of the IP address that you want to remove from your allow list.
      */
     #[serde(
         default,
@@ -8830,7 +9995,7 @@ pub struct DeleteAccessSettingsWhitelistRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetAccessSettingsActivityResponseResult {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the IP address was granted access to the account.
      */
     #[serde(
         default,
@@ -8838,7 +10003,7 @@ pub struct GetAccessSettingsActivityResponseResult {
     )]
     pub allowed: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The authentication method used when attempting access.
      */
     #[serde(
         default,
@@ -8846,6 +10011,9 @@ pub struct GetAccessSettingsActivityResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub auth_method: String,
+    /**
+     * A Unix timestamp indicating when the first access attempt was made.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -8853,7 +10021,7 @@ pub struct GetAccessSettingsActivityResponseResult {
     )]
     pub first_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The IP addressed used during the access attempt.
      */
     #[serde(
         default,
@@ -8861,6 +10029,9 @@ pub struct GetAccessSettingsActivityResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /**
+     * A Unix timestamp indicating when the most recent access attempt was made
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -8868,7 +10039,7 @@ pub struct GetAccessSettingsActivityResponseResult {
     )]
     pub last_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The geographic location from which the access attempt originated.
      */
     #[serde(
         default,
@@ -8895,12 +10066,12 @@ pub struct GetAccessSettingsActivityResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSsoCertificatesRequest {
     /**
-     *
+     * Indicates if the certificate is enabled.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * An ID that matches a certificate to a specific IdP integration. This is the `id` returned by the "Get All SSO Integrations" endpoint.
      */
     #[serde(
         default,
@@ -8909,7 +10080,7 @@ pub struct PostSsoCertificatesRequest {
     )]
     pub integration_id: String,
     /**
-     * The license key provided with your New Relic account.
+     * This public certificate allows SendGrid to verify that SAML requests it receives are signed by an IdP that it recognizes.
      */
     #[serde(
         default,
@@ -8921,14 +10092,23 @@ pub struct PostSsoCertificatesRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchSsoCertificatesCertRequest {
+    /**
+     * Indicates whether or not the certificate is enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    /**
+     * An ID that matches a certificate to a specific IdP integration.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub integration_id: String,
+    /**
+     * This public certificate allows SendGrid to verify that SAML requests it receives are signed by an IdP that it recognizes.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -8964,7 +10144,7 @@ pub struct PatchSsoTeammatesUsernameRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMailSettingsResponseResult {
     /**
-     * The license key provided with your New Relic account.
+     * A description of the mail setting.
      */
     #[serde(
         default,
@@ -8973,7 +10153,7 @@ pub struct GetMailSettingsResponseResult {
     )]
     pub description: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this mail setting is currently enabled.
      */
     #[serde(
         default,
@@ -8981,7 +10161,7 @@ pub struct GetMailSettingsResponseResult {
     )]
     pub enabled: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the mail setting.
      */
     #[serde(
         default,
@@ -8990,7 +10170,7 @@ pub struct GetMailSettingsResponseResult {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The title of the mail setting.
      */
     #[serde(
         default,
@@ -9015,8 +10195,14 @@ pub struct GetMailSettingsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMailSettingsAddressWhitelistRequest {
+    /**
+     * Indicates if your email address whitelist is enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    /**
+     * Either a single email address that you want whitelisted or a domain, for which all email addresses belonging to this domain will be whitelisted.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -9027,8 +10213,14 @@ pub struct PatchMailSettingsAddressWhitelistRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMailSettingsTemplateRequest {
+    /**
+     * Indicates if you want to enable the legacy email template mail setting.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    /**
+     * The new HTML content for your legacy email template.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9040,7 +10232,7 @@ pub struct PatchMailSettingsTemplateRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMailSettingsTemplateResponse {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the legacy email template mail setting is enabled.
      */
     #[serde(
         default,
@@ -9048,7 +10240,7 @@ pub struct PatchMailSettingsTemplateResponse {
     )]
     pub enabled: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The HTML content of your legacy email template.
      */
     #[serde(
         default,
@@ -9060,10 +10252,19 @@ pub struct PatchMailSettingsTemplateResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchPartnerSettingsNewRelicRequest {
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enable_subuser_statistics: Option<bool>,
+    /**
+     * Indicates if this partner setting is enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    /**
+     * The license key for your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9074,9 +10275,6 @@ pub struct PatchPartnerSettingsNewRelicRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetPartnerSettingsResponse {
-    /**
-     * The list of all mail settings.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -9088,7 +10286,7 @@ pub struct GetPartnerSettingsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTeammatesResponseResult {
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's address
      */
     #[serde(
         default,
@@ -9097,7 +10295,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub address: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's address
      */
     #[serde(
         default,
@@ -9107,7 +10305,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub address_2: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's city
      */
     #[serde(
         default,
@@ -9116,7 +10314,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub city: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's country
      */
     #[serde(
         default,
@@ -9125,7 +10323,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub country: String,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's email
      */
     #[serde(
         default,
@@ -9134,7 +10332,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's first name
      */
     #[serde(
         default,
@@ -9143,7 +10341,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub first_name: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Set to true if teammate has admin privileges
      */
     #[serde(
         default,
@@ -9151,7 +10349,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub is_admin: bool,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's last name
      */
     #[serde(
         default,
@@ -9160,7 +10358,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub last_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's phone number
      */
     #[serde(
         default,
@@ -9169,7 +10367,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub phone: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's state
      */
     #[serde(
         default,
@@ -9178,12 +10376,12 @@ pub struct GetTeammatesResponseResult {
     )]
     pub state: String,
     /**
-     * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
+     * Indicate the type of user: owner user, teammate admin user, or normal teammate
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_type: Option<UserType>,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's username
      */
     #[serde(
         default,
@@ -9192,7 +10390,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub username: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's website
      */
     #[serde(
         default,
@@ -9201,7 +10399,7 @@ pub struct GetTeammatesResponseResult {
     )]
     pub website: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's zip
      */
     #[serde(
         default,
@@ -9224,7 +10422,7 @@ pub struct GetTeammatesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTeammatesRequest {
     /**
-     * The license key provided with your New Relic account.
+     * New teammate's email
      */
     #[serde(
         default,
@@ -9233,12 +10431,12 @@ pub struct PostTeammatesRequest {
     )]
     pub email: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Set to true if teammate should be an admin user
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_admin: Option<bool>,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * Set to specify list of scopes that teammate should have. Should be empty if teammate is an admin.
      */
     #[serde(
         default,
@@ -9251,7 +10449,7 @@ pub struct PostTeammatesRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTeammatesResponse {
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's email address
      */
     #[serde(
         default,
@@ -9260,7 +10458,7 @@ pub struct PostTeammatesResponse {
     )]
     pub email: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Set to true if teammate should have admin privileges
      */
     #[serde(
         default,
@@ -9268,7 +10466,7 @@ pub struct PostTeammatesResponse {
     )]
     pub is_admin: bool,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * Initial set of permissions to give to teammate if they accept the invite
      */
     #[serde(
         default,
@@ -9277,7 +10475,7 @@ pub struct PostTeammatesResponse {
     )]
     pub scopes: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * Token to identify invite
      */
     #[serde(
         default,
@@ -9289,18 +10487,12 @@ pub struct PostTeammatesResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTeammatesResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9322,7 +10514,7 @@ pub struct PostSendersResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetScopesRequestsResponse {
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's email
      */
     #[serde(
         default,
@@ -9331,7 +10523,7 @@ pub struct GetScopesRequestsResponse {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's first name
      */
     #[serde(
         default,
@@ -9339,6 +10531,9 @@ pub struct GetScopesRequestsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
+    /**
+     * Request ID
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9346,7 +10541,7 @@ pub struct GetScopesRequestsResponse {
     )]
     pub id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's last name
      */
     #[serde(
         default,
@@ -9355,7 +10550,7 @@ pub struct GetScopesRequestsResponse {
     )]
     pub last_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * Name of group of scopes associated to page teammate is requesting access to
      */
     #[serde(
         default,
@@ -9364,7 +10559,7 @@ pub struct GetScopesRequestsResponse {
     )]
     pub scope_group_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's username
      */
     #[serde(
         default,
@@ -9377,7 +10572,7 @@ pub struct GetScopesRequestsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTeammatesPendingResponseResult {
     /**
-     * The license key provided with your New Relic account.
+     * Email address teammate invite will be sent to
      */
     #[serde(
         default,
@@ -9385,6 +10580,9 @@ pub struct GetTeammatesPendingResponseResult {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * timestamp indicates when invite will expire. Expiration is 7 days after invite creation
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9392,7 +10590,7 @@ pub struct GetTeammatesPendingResponseResult {
     )]
     pub expiration_date: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Set to true to indicate teammate should have the same set of permissions as parent user
      */
     #[serde(
         default,
@@ -9400,7 +10598,7 @@ pub struct GetTeammatesPendingResponseResult {
     )]
     pub is_admin: bool,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * List of permissions to give teammate if they accept
      */
     #[serde(
         default,
@@ -9409,7 +10607,7 @@ pub struct GetTeammatesPendingResponseResult {
     )]
     pub scopes: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * Invitation token used to identify user
      */
     #[serde(
         default,
@@ -9432,7 +10630,7 @@ pub struct GetTeammatesPendingResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTeammatesUsernameResponse {
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's address
      */
     #[serde(
         default,
@@ -9441,7 +10639,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub address: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's address
      */
     #[serde(
         default,
@@ -9451,7 +10649,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub address_2: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's city
      */
     #[serde(
         default,
@@ -9460,7 +10658,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub city: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's country
      */
     #[serde(
         default,
@@ -9469,7 +10667,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub country: String,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's email
      */
     #[serde(
         default,
@@ -9478,7 +10676,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's first name
      */
     #[serde(
         default,
@@ -9487,7 +10685,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub first_name: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Set to true if teammate has admin privileges
      */
     #[serde(
         default,
@@ -9495,7 +10693,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub is_admin: bool,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's last name
      */
     #[serde(
         default,
@@ -9504,7 +10702,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub last_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's phone number
      */
     #[serde(
         default,
@@ -9513,7 +10711,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub phone: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * Scopes associated to teammate
      */
     #[serde(
         default,
@@ -9522,7 +10720,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub scopes: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's state
      */
     #[serde(
         default,
@@ -9531,12 +10729,12 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub state: String,
     /**
-     * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
+     * Indicate the type of user: account owner, teammate admin user, or normal teammate
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_type: Option<UserType>,
     /**
-     * The license key provided with your New Relic account.
+     * Teammate's username
      */
     #[serde(
         default,
@@ -9545,7 +10743,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub username: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's website
      */
     #[serde(
         default,
@@ -9554,7 +10752,7 @@ pub struct GetTeammatesUsernameResponse {
     )]
     pub website: String,
     /**
-     * The license key provided with your New Relic account.
+     * (optional) Teammate's zip
      */
     #[serde(
         default,
@@ -9567,12 +10765,12 @@ pub struct GetTeammatesUsernameResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTeammatesUsernameRequest {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Set to True if this teammate should be promoted to an admin user. If True, scopes should be an empty array.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_admin: Option<bool>,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * Provide list of scopes that should be given to teammate. If specifying list of scopes, is_admin should be set to False.
      */
     #[serde(
         default,
@@ -9585,7 +10783,7 @@ pub struct PatchTeammatesUsernameRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchScopesRequestsApproveResponse {
     /**
-     * The license key provided with your New Relic account.
+     * name of feature teammate will be given access to
      */
     #[serde(
         default,
@@ -9599,6 +10797,7 @@ pub struct PatchScopesRequestsApproveResponse {
 * The type of alert.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAlertsResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9606,8 +10805,9 @@ pub enum GetAlertsResponseType {
     UsageLimit,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsResponseType {
@@ -9616,12 +10816,32 @@ impl std::fmt::Display for GetAlertsResponseType {
             GetAlertsResponseType::StatsNotification => "stats_notification",
             GetAlertsResponseType::UsageLimit => "usage_limit",
             GetAlertsResponseType::Noop => "",
-            GetAlertsResponseType::FallthroughString => "*",
+            GetAlertsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetAlertsResponseType {
+    fn from(s: String) -> GetAlertsResponseType {
+        match s.as_str() {
+            "stats_notification" => GetAlertsResponseType::StatsNotification,
+            "usage_limit" => GetAlertsResponseType::UsageLimit,
+            "" => GetAlertsResponseType::Noop,
+            _ => GetAlertsResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetAlertsResponseType> for String {
+    fn from(e: GetAlertsResponseType) -> String {
+        match e {
+            GetAlertsResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetAlertsResponseType {
     fn default() -> GetAlertsResponseType {
         GetAlertsResponseType::Noop
@@ -9635,6 +10855,9 @@ impl GetAlertsResponseType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetAlertsResponse {
+    /**
+     * A Unix timestamp indicating when the alert was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9642,7 +10865,7 @@ pub struct GetAlertsResponse {
     )]
     pub created_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address that the alert will be sent to.
      */
     #[serde(
         default,
@@ -9651,7 +10874,7 @@ pub struct GetAlertsResponse {
     )]
     pub email_to: String,
     /**
-     * The license key provided with your New Relic account.
+     * If the alert is of type stats_notification, this indicates how frequently the stats notifications will be sent. For example, "daily", "weekly", or "monthly".
      */
     #[serde(
         default,
@@ -9659,12 +10882,18 @@ pub struct GetAlertsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub frequency: String,
+    /**
+     * The ID of the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * If the alert is of type usage_limit, this indicates the percentage of email usage that must be reached before the alert will be sent.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9680,6 +10909,9 @@ pub struct GetAlertsResponse {
         rename = "type"
     )]
     pub type_: GetAlertsResponseType,
+    /**
+     * A Unix timestamp indicating when the alert was last modified.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9691,7 +10923,8 @@ pub struct GetAlertsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAlertsRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The email address the alert will be sent to.
+     *  Example: test@example.com
      */
     #[serde(
         default,
@@ -9699,12 +10932,20 @@ pub struct PostAlertsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email_to: String,
+    /**
+     * Required for stats_notification. How frequently the alert will be sent.
+     *  Example: daily
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub frequency: String,
+    /**
+     * Required for usage_alert. When this usage threshold is reached, the alert will be sent.
+     *  Example: 90
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9712,7 +10953,8 @@ pub struct PostAlertsRequest {
     )]
     pub percentage: i64,
     /**
-     * The type of alert.
+     * The type of alert you want to create. Can be either usage_limit or stats_notification.
+     *  Example: usage_limit
      */
     #[serde(
         default,
@@ -9724,6 +10966,9 @@ pub struct PostAlertsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAlertsResponse {
+    /**
+     * A Unix timestamp indicating when the alert was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9731,7 +10976,7 @@ pub struct PostAlertsResponse {
     )]
     pub created_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address that the alert will be sent to.
      */
     #[serde(
         default,
@@ -9740,7 +10985,7 @@ pub struct PostAlertsResponse {
     )]
     pub email_to: String,
     /**
-     * The license key provided with your New Relic account.
+     * If the alert is of type stats_notification, this indicates how frequently the stats notifications will be sent. For example, "daily", "weekly", or "monthly".
      */
     #[serde(
         default,
@@ -9748,12 +10993,18 @@ pub struct PostAlertsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub frequency: String,
+    /**
+     * The ID of the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * "If the alert is of type usage_limit, this indicates the percentage of email usage that must be reached before the alert will be sent.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9761,7 +11012,7 @@ pub struct PostAlertsResponse {
     )]
     pub percentage: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The type of alert.
      */
     #[serde(
         default,
@@ -9770,6 +11021,9 @@ pub struct PostAlertsResponse {
         rename = "type"
     )]
     pub type_: String,
+    /**
+     * A Unix timestamp indicating when the alert was last modified.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9782,6 +11036,7 @@ pub struct PostAlertsResponse {
 * The type of alert.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAlertsAlertResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9789,8 +11044,9 @@ pub enum GetAlertsAlertResponseType {
     UsageAlert,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAlertsAlertResponseType {
@@ -9799,12 +11055,32 @@ impl std::fmt::Display for GetAlertsAlertResponseType {
             GetAlertsAlertResponseType::StatsNotification => "stats_notification",
             GetAlertsAlertResponseType::UsageAlert => "usage_alert",
             GetAlertsAlertResponseType::Noop => "",
-            GetAlertsAlertResponseType::FallthroughString => "*",
+            GetAlertsAlertResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetAlertsAlertResponseType {
+    fn from(s: String) -> GetAlertsAlertResponseType {
+        match s.as_str() {
+            "stats_notification" => GetAlertsAlertResponseType::StatsNotification,
+            "usage_alert" => GetAlertsAlertResponseType::UsageAlert,
+            "" => GetAlertsAlertResponseType::Noop,
+            _ => GetAlertsAlertResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetAlertsAlertResponseType> for String {
+    fn from(e: GetAlertsAlertResponseType) -> String {
+        match e {
+            GetAlertsAlertResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetAlertsAlertResponseType {
     fn default() -> GetAlertsAlertResponseType {
         GetAlertsAlertResponseType::Noop
@@ -9818,6 +11094,9 @@ impl GetAlertsAlertResponseType {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetAlertsAlertResponse {
+    /**
+     * A Unix timestamp indicating when the alert was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9825,7 +11104,7 @@ pub struct GetAlertsAlertResponse {
     )]
     pub created_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address that the alert will be sent to.
      */
     #[serde(
         default,
@@ -9834,7 +11113,7 @@ pub struct GetAlertsAlertResponse {
     )]
     pub email_to: String,
     /**
-     * The license key provided with your New Relic account.
+     * If the alert is of type stats_notification, this indicates how frequently the stats notifications will be sent. For example: "daily", "weekly", or "monthly".
      */
     #[serde(
         default,
@@ -9842,12 +11121,18 @@ pub struct GetAlertsAlertResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub frequency: String,
+    /**
+     * The ID of the alert.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * If the alert is of type usage_limit, this indicates the percentage of email usage that must be reached before the alert will be sent.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9863,6 +11148,9 @@ pub struct GetAlertsAlertResponse {
         rename = "type"
     )]
     pub type_: GetAlertsAlertResponseType,
+    /**
+     * A Unix timestamp indicating when the alert was last modified.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9873,18 +11161,30 @@ pub struct GetAlertsAlertResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchAlertsAlertRequest {
+    /**
+     * The new email address you want your alert to be sent to.
+     *  Example: test@example.com
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email_to: String,
+    /**
+     * The new frequency at which to send the stats_notification alert.
+     *  Example: monthly
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub frequency: String,
+    /**
+     * The new percentage threshold at which the usage_limit alert will be sent.
+     *  Example: 90
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -9896,7 +11196,7 @@ pub struct PatchAlertsAlertRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserProfileResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The user's address.
      */
     #[serde(
         default,
@@ -9904,6 +11204,9 @@ pub struct GetUserProfileResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address: String,
+    /**
+     * The second line of the user's address.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9912,7 +11215,7 @@ pub struct GetUserProfileResponse {
     )]
     pub address_2: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's city.
      */
     #[serde(
         default,
@@ -9921,7 +11224,7 @@ pub struct GetUserProfileResponse {
     )]
     pub city: String,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the user's company.
      */
     #[serde(
         default,
@@ -9930,7 +11233,7 @@ pub struct GetUserProfileResponse {
     )]
     pub company: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's country.
      */
     #[serde(
         default,
@@ -9939,7 +11242,7 @@ pub struct GetUserProfileResponse {
     )]
     pub country: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's first name.
      */
     #[serde(
         default,
@@ -9948,7 +11251,7 @@ pub struct GetUserProfileResponse {
     )]
     pub first_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's last name.
      */
     #[serde(
         default,
@@ -9957,7 +11260,7 @@ pub struct GetUserProfileResponse {
     )]
     pub last_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's phone number.
      */
     #[serde(
         default,
@@ -9966,7 +11269,7 @@ pub struct GetUserProfileResponse {
     )]
     pub phone: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's state.
      */
     #[serde(
         default,
@@ -9975,7 +11278,7 @@ pub struct GetUserProfileResponse {
     )]
     pub state: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's website URL.
      */
     #[serde(
         default,
@@ -9984,7 +11287,7 @@ pub struct GetUserProfileResponse {
     )]
     pub website: String,
     /**
-     * The license key provided with your New Relic account.
+     * The user's zip code.
      */
     #[serde(
         default,
@@ -9998,6 +11301,7 @@ pub struct GetUserProfileResponse {
 * The type of account for this user.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetUserAccountResponseType {
     #[serde(rename = "free")]
     Free,
@@ -10005,8 +11309,9 @@ pub enum GetUserAccountResponseType {
     Paid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetUserAccountResponseType {
@@ -10015,12 +11320,32 @@ impl std::fmt::Display for GetUserAccountResponseType {
             GetUserAccountResponseType::Free => "free",
             GetUserAccountResponseType::Paid => "paid",
             GetUserAccountResponseType::Noop => "",
-            GetUserAccountResponseType::FallthroughString => "*",
+            GetUserAccountResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetUserAccountResponseType {
+    fn from(s: String) -> GetUserAccountResponseType {
+        match s.as_str() {
+            "free" => GetUserAccountResponseType::Free,
+            "paid" => GetUserAccountResponseType::Paid,
+            "" => GetUserAccountResponseType::Noop,
+            _ => GetUserAccountResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetUserAccountResponseType> for String {
+    fn from(e: GetUserAccountResponseType) -> String {
+        match e {
+            GetUserAccountResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetUserAccountResponseType {
     fn default() -> GetUserAccountResponseType {
         GetUserAccountResponseType::Noop
@@ -10035,7 +11360,7 @@ impl GetUserAccountResponseType {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserAccountResponse {
     /**
-     * The number of errors found while adding recipients.
+     * The sender reputation for this user.
      */
     #[serde(
         default,
@@ -10057,7 +11382,7 @@ pub struct GetUserAccountResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserEmailResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The email address currently on file for your account.
      */
     #[serde(
         default,
@@ -10069,6 +11394,9 @@ pub struct GetUserEmailResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserEmailRequest {
+    /**
+     * The new email address that you would like to use for your account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10079,6 +11407,9 @@ pub struct PutUserEmailRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserUsernameRequest {
+    /**
+     * The new username you would like to use for your account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10090,7 +11421,7 @@ pub struct PutUserUsernameRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserUsernameResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The current username on file for your account.
      */
     #[serde(
         default,
@@ -10103,7 +11434,7 @@ pub struct PutUserUsernameResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserCreditsResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The date that your credit balance was last reset.
      */
     #[serde(
         default,
@@ -10112,7 +11443,7 @@ pub struct GetUserCreditsResponse {
     )]
     pub last_reset: String,
     /**
-     * The license key provided with your New Relic account.
+     * The next date that your credit balance will be reset.
      */
     #[serde(
         default,
@@ -10120,12 +11451,18 @@ pub struct GetUserCreditsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_reset: String,
+    /**
+     * The number of overdrawn credits for your account.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub overage: i64,
+    /**
+     * The remaining number of credits available on your account.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10133,7 +11470,7 @@ pub struct GetUserCreditsResponse {
     )]
     pub remain: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The frequency at which your credit balance will be reset.
      */
     #[serde(
         default,
@@ -10141,12 +11478,18 @@ pub struct GetUserCreditsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reset_frequency: String,
+    /**
+     * The total number of credits assigned to your account.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total: i64,
+    /**
+     * The number of credits that you have used.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10158,7 +11501,7 @@ pub struct GetUserCreditsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutUserPasswordRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The new password you would like to use for your account.
      */
     #[serde(
         default,
@@ -10167,7 +11510,7 @@ pub struct PutUserPasswordRequest {
     )]
     pub new_password: String,
     /**
-     * The license key provided with your New Relic account.
+     * The old password for your account.
      */
     #[serde(
         default,
@@ -10180,7 +11523,7 @@ pub struct PutUserPasswordRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSubusersRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The email address of the subuser.
      */
     #[serde(
         default,
@@ -10198,7 +11541,7 @@ pub struct PostSubusersRequest {
     )]
     pub ips: Vec<std::net::Ipv4Addr>,
     /**
-     * The license key provided with your New Relic account.
+     * The password this subuser will use when logging into SendGrid.
      */
     #[serde(
         default,
@@ -10207,7 +11550,7 @@ pub struct PostSubusersRequest {
     )]
     pub password: String,
     /**
-     * The license key provided with your New Relic account.
+     * The username for this subuser.
      */
     #[serde(
         default,
@@ -10219,6 +11562,9 @@ pub struct PostSubusersRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchSubusersSubuserNameRequest {
+    /**
+     * Whether or not this subuser is disabled. True means disabled, False means enabled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub disabled: Option<bool>,
 }
@@ -10226,7 +11572,7 @@ pub struct PatchSubusersSubuserNameRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSubusersReputationsResponse {
     /**
-     * The number of errors found while adding recipients.
+     * The sender reputation this subuser has attained.
      */
     #[serde(
         default,
@@ -10235,7 +11581,7 @@ pub struct GetSubusersReputationsResponse {
     )]
     pub reputation: f64,
     /**
-     * The license key provided with your New Relic account.
+     * The subuser that has this reputation.f
      */
     #[serde(
         default,
@@ -10248,7 +11594,7 @@ pub struct GetSubusersReputationsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutSubusersSubuserNameIpsResponse {
     /**
-     * The IP addresses that should be assigned to this subuser.
+     * The IP addresses that are assigned to the subuser.
      */
     #[serde(
         default,
@@ -10262,13 +11608,15 @@ pub struct PutSubusersSubuserNameIpsResponse {
 * The direction you want to sort.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortByDirection {
     #[serde(rename = "asc")]
     Asc,
     #[serde(rename = "desc")]
     Desc,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByDirection {
@@ -10276,12 +11624,31 @@ impl std::fmt::Display for SortByDirection {
         match self {
             SortByDirection::Asc => "asc",
             SortByDirection::Desc => "desc",
-            SortByDirection::FallthroughString => "*",
+            SortByDirection::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SortByDirection {
+    fn from(s: String) -> SortByDirection {
+        match s.as_str() {
+            "asc" => SortByDirection::Asc,
+            "desc" => SortByDirection::Desc,
+            _ => SortByDirection::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SortByDirection> for String {
+    fn from(e: SortByDirection) -> String {
+        match e {
+            SortByDirection::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SortByDirection {
     fn default() -> SortByDirection {
         SortByDirection::Desc
@@ -10292,6 +11659,7 @@ impl Default for SortByDirection {
 * The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortByMetric {
     #[serde(rename = "blocks")]
     Blocks,
@@ -10311,8 +11679,9 @@ pub enum SortByMetric {
     UniqueOpens,
     #[serde(rename = "unsubscribes")]
     Unsubscribes,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortByMetric {
@@ -10327,12 +11696,38 @@ impl std::fmt::Display for SortByMetric {
             SortByMetric::UniqueClicks => "unique_clicks",
             SortByMetric::UniqueOpens => "unique_opens",
             SortByMetric::Unsubscribes => "unsubscribes",
-            SortByMetric::FallthroughString => "*",
+            SortByMetric::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SortByMetric {
+    fn from(s: String) -> SortByMetric {
+        match s.as_str() {
+            "blocks" => SortByMetric::Blocks,
+            "bounces" => SortByMetric::Bounces,
+            "clicks" => SortByMetric::Clicks,
+            "delivered" => SortByMetric::Delivered,
+            "opens" => SortByMetric::Opens,
+            "requests" => SortByMetric::Requests,
+            "unique_clicks" => SortByMetric::UniqueClicks,
+            "unique_opens" => SortByMetric::UniqueOpens,
+            "unsubscribes" => SortByMetric::Unsubscribes,
+            _ => SortByMetric::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SortByMetric> for String {
+    fn from(e: SortByMetric) -> String {
+        match e {
+            SortByMetric::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SortByMetric {
     fn default() -> SortByMetric {
         SortByMetric::Delivered
@@ -10341,10 +11736,13 @@ impl Default for SortByMetric {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksRequest {
+    /**
+     * Indicates if you want to use this link branding as the default or fallback. When setting a new default, the existing default link branding will have its default status removed automatically.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * The root domain for the subdomain that you are creating the link branding for. This should match your FROM email address.
      */
     #[serde(
         default,
@@ -10352,6 +11750,9 @@ pub struct PostWhitelabelLinksRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub domain: String,
+    /**
+     * The subdomain to create the link branding for. Must be different from the subdomain you used for authenticating your domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10364,7 +11765,7 @@ pub struct PostWhitelabelLinksRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksValidateResponseValidationResultsDomainCname {
     /**
-     * The license key provided with your New Relic account.
+     * Null if the DNS record is valid. If the DNS record is invalid, this will explain why.
      */
     #[serde(
         default,
@@ -10373,7 +11774,7 @@ pub struct PostWhitelabelLinksValidateResponseValidationResultsDomainCname {
     )]
     pub reason: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this DNS record is valid.
      */
     #[serde(
         default,
@@ -10391,7 +11792,7 @@ pub struct ValidationResults {
     #[serde()]
     pub domain_cname: PostWhitelabelLinksValidateResponseValidationResultsDomainCname,
     /**
-     * The individual validation results for each of the DNS records associated with this branded link.
+     * The DNS record created to verify the branded link.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owner_cname: Option<PostWhitelabelLinksValidateResponseValidationResultsDomainCname>,
@@ -10399,6 +11800,9 @@ pub struct ValidationResults {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksValidateResponse {
+    /**
+     * The ID of the branded link.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10406,7 +11810,7 @@ pub struct PostWhitelabelLinksValidateResponse {
     )]
     pub id: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the link branding is valid.
      */
     #[serde(
         default,
@@ -10423,7 +11827,7 @@ pub struct PostWhitelabelLinksValidateResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksValidateResponseErrors {
     /**
-     * The license key provided with your New Relic account.
+     * The reason why the link whitelabel could not be validated.
      */
     #[serde(
         default,
@@ -10448,6 +11852,9 @@ pub struct PostMarketingSegmentsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksLinkSubuserRequest {
+    /**
+     * The username of the subuser account that you want to associate the branded link with.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10458,12 +11865,18 @@ pub struct PostWhitelabelLinksLinkSubuserRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchWhitelabelLinksRequest {
+    /**
+     * Indicates if the branded link is set as the default. When setting a new default, the existing default link branding will have its default status removed automatically.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsWarmupRequest {
+    /**
+     * The IP address that you want to begin warming up.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10477,7 +11890,7 @@ pub struct PostIpsWarmupResponseErrors {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub field: Option<serde_json::Value>,
     /**
-     * The license key provided with your New Relic account.
+     * A message explaining why the IP couldn't entered into warmup mode.
      */
     #[serde(
         default,
@@ -10503,7 +11916,7 @@ pub struct PostIpsWarmupResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelIpsRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The root, or sending, domain that will be used to send message from the IP address.
      */
     #[serde(
         default,
@@ -10512,7 +11925,7 @@ pub struct PostWhitelabelIpsRequest {
     )]
     pub domain: String,
     /**
-     * The license key provided with your New Relic account.
+     * The IP address for which you want to set up reverse DNS.
      */
     #[serde(
         default,
@@ -10520,6 +11933,9 @@ pub struct PostWhitelabelIpsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip: String,
+    /**
+     * The subdomain that will be used to send emails from the IP address. This should be the same as the subdomain used to set up an authenticated domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10531,15 +11947,15 @@ pub struct PostWhitelabelIpsRequest {
 /// The specific results of the validation.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelIpsValidateResponseValidationResults {
-    /**
-     * The specific results of the validation.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub a_record: Option<PostWhitelabelLinksValidateResponseValidationResultsDomainCname>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelIpsValidateResponse {
+    /**
+     * The ID of the reverse DNS record.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10547,7 +11963,7 @@ pub struct PostWhitelabelIpsValidateResponse {
     )]
     pub id: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the reverse DNS record is valid.
      */
     #[serde(
         default,
@@ -10564,7 +11980,7 @@ pub struct PostWhitelabelIpsValidateResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostValidationsEmailRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The email address that you want to validate.
      */
     #[serde(
         default,
@@ -10572,6 +11988,9 @@ pub struct PostValidationsEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * A one-word classifier for where this validation originated.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10584,6 +12003,7 @@ pub struct PostValidationsEmailRequest {
 * A generic classification of whether or not the email address is valid.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Verdict {
     #[serde(rename = "Invalid")]
     Invalid,
@@ -10593,8 +12013,9 @@ pub enum Verdict {
     Valid,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Verdict {
@@ -10604,12 +12025,33 @@ impl std::fmt::Display for Verdict {
             Verdict::Risky => "Risky",
             Verdict::Valid => "Valid",
             Verdict::Noop => "",
-            Verdict::FallthroughString => "*",
+            Verdict::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Verdict {
+    fn from(s: String) -> Verdict {
+        match s.as_str() {
+            "Invalid" => Verdict::Invalid,
+            "Risky" => Verdict::Risky,
+            "Valid" => Verdict::Valid,
+            "" => Verdict::Noop,
+            _ => Verdict::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Verdict> for String {
+    fn from(e: Verdict) -> String {
+        match e {
+            Verdict::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Verdict {
     fn default() -> Verdict {
         Verdict::Noop
@@ -10625,7 +12067,7 @@ impl Verdict {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Domain {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Whether the email has appropriate DNS records to deliver a message.
      */
     #[serde(
         default,
@@ -10633,7 +12075,7 @@ pub struct Domain {
     )]
     pub has_mx_or_a_record: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Whether the email address syntax is valid.
      */
     #[serde(
         default,
@@ -10641,7 +12083,7 @@ pub struct Domain {
     )]
     pub has_valid_address_syntax: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Whether the domain appears to be from a disposable email address service.
      */
     #[serde(
         default,
@@ -10654,7 +12096,7 @@ pub struct Domain {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct LocalPart {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Whether the local part of email appears to be a role or group (e.g., hr, admin)
      */
     #[serde(
         default,
@@ -10667,7 +12109,7 @@ pub struct LocalPart {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Additional {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * WHether email sent to this address from your account has bounced.
      */
     #[serde(
         default,
@@ -10675,7 +12117,7 @@ pub struct Additional {
     )]
     pub has_known_bounces: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Whether our model predicts that the email address might bounce.
      */
     #[serde(
         default,
@@ -10712,7 +12154,7 @@ pub struct PostValidationsEmailResponseResult {
     #[serde()]
     pub checks: Checks,
     /**
-     * The license key provided with your New Relic account.
+     * The email being validated
      */
     #[serde(
         default,
@@ -10721,7 +12163,7 @@ pub struct PostValidationsEmailResponseResult {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The domain of the email address.
      */
     #[serde(
         default,
@@ -10730,7 +12172,7 @@ pub struct PostValidationsEmailResponseResult {
     )]
     pub host: String,
     /**
-     * The license key provided with your New Relic account.
+     * The IP address associated with this email.
      */
     #[serde(
         default,
@@ -10739,7 +12181,7 @@ pub struct PostValidationsEmailResponseResult {
     )]
     pub ip_address: String,
     /**
-     * The license key provided with your New Relic account.
+     * The local part of the email address.
      */
     #[serde(
         default,
@@ -10748,7 +12190,7 @@ pub struct PostValidationsEmailResponseResult {
     )]
     pub local: String,
     /**
-     * The number of errors found while adding recipients.
+     * A numeric representation of the email validity.
      */
     #[serde(
         default,
@@ -10757,7 +12199,7 @@ pub struct PostValidationsEmailResponseResult {
     )]
     pub score: f64,
     /**
-     * The license key provided with your New Relic account.
+     * The source of the validation, as per the API request.
      */
     #[serde(
         default,
@@ -10766,7 +12208,7 @@ pub struct PostValidationsEmailResponseResult {
     )]
     pub source: String,
     /**
-     * The license key provided with your New Relic account.
+     * A suggested correction in the event of domain name typos (e.g., gmial.com)
      */
     #[serde(
         default,
@@ -10789,6 +12231,9 @@ pub struct PostValidationsEmailResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDnsEmailRequest {
+    /**
+     * The ID of your SendGrid domain record.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10796,7 +12241,7 @@ pub struct PostWhitelabelDnsEmailRequest {
     )]
     pub domain_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address to send the DNS information to.
      */
     #[serde(
         default,
@@ -10810,6 +12255,9 @@ pub struct PostWhitelabelDnsEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub link_id: i64,
+    /**
+     * A custom text block to include in the email body sent with the records.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10820,18 +12268,12 @@ pub struct PostWhitelabelDnsEmailRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDnsEmailResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10849,7 +12291,7 @@ pub struct PostWhitelabelDnsEmailResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIpsAssignedResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The IP address.
      */
     #[serde(
         default,
@@ -10858,7 +12300,7 @@ pub struct GetIpsAssignedResponse {
     )]
     pub ip: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The IP pools that this IP address has been added to.
      */
     #[serde(
         default,
@@ -10866,6 +12308,9 @@ pub struct GetIpsAssignedResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub pools: Vec<String>,
+    /**
+     * A Unix timestamp indicating when the warmup process began for the added IP address.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10873,7 +12318,7 @@ pub struct GetIpsAssignedResponse {
     )]
     pub start_date: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the IP address is in warmup.
      */
     #[serde(
         default,
@@ -10885,7 +12330,7 @@ pub struct GetIpsAssignedResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIpsPoolsPoolNameResponse {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The IP addresses that belong to this pool.
      */
     #[serde(
         default,
@@ -10894,7 +12339,7 @@ pub struct GetIpsPoolsPoolNameResponse {
     )]
     pub ips: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the IP pool.
      */
     #[serde(
         default,
@@ -10906,6 +12351,9 @@ pub struct GetIpsPoolsPoolNameResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutIpsPoolsPoolNameRequest {
+    /**
+     * The new name for your IP pool.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -10917,7 +12365,7 @@ pub struct PutIpsPoolsPoolNameRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteIpsPoolsPoolNameResponse {
     /**
-     * The license key provided with your New Relic account.
+     * An error explaining why the pool could not be deleted.
      */
     #[serde(
         default,
@@ -10929,6 +12377,9 @@ pub struct DeleteIpsPoolsPoolNameResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIpsResponse {
+    /**
+     * The date that the IP address was assigned to the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -10936,7 +12387,7 @@ pub struct GetIpsResponse {
     )]
     pub assigned_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * An IP address.
      */
     #[serde(
         default,
@@ -10945,7 +12396,7 @@ pub struct GetIpsResponse {
     )]
     pub ip: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The IP pools that this IP has been added to.
      */
     #[serde(
         default,
@@ -10954,7 +12405,7 @@ pub struct GetIpsResponse {
     )]
     pub pools: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * The reverse DNS record for this IP address.
      */
     #[serde(
         default,
@@ -10963,7 +12414,7 @@ pub struct GetIpsResponse {
     )]
     pub rdns: String,
     /**
-     * The number of errors found while adding recipients.
+     * The date that the IP address was entered into warmup.
      */
     #[serde(
         default,
@@ -10972,7 +12423,7 @@ pub struct GetIpsResponse {
     )]
     pub start_date: f64,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The subusers that are able to send email from this IP.
      */
     #[serde(
         default,
@@ -10981,7 +12432,7 @@ pub struct GetIpsResponse {
     )]
     pub subusers: Vec<String>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this IP address is currently warming up.
      */
     #[serde(
         default,
@@ -10989,7 +12440,7 @@ pub struct GetIpsResponse {
     )]
     pub warmup: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this IP address is associated with a reverse DNS record.
      */
     #[serde(
         default,
@@ -11000,18 +12451,27 @@ pub struct GetIpsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsRequest {
+    /**
+     * The amount of IPs to add to the account.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub count: i64,
+    /**
+     * Array of usernames to be assigned a send IP.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub subusers: Vec<String>,
+    /**
+     * Whether or not to warmup the IPs being added.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub warmup: Option<bool>,
 }
@@ -11019,7 +12479,7 @@ pub struct PostIpsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostIpsResponse {
     /**
-     * The license key provided with your New Relic account.
+     * IP added to account.
      */
     #[serde(
         default,
@@ -11028,7 +12488,7 @@ pub struct PostIpsResponse {
     )]
     pub ip: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * Array of usernames assigned a send IP.
      */
     #[serde(
         default,
@@ -11049,6 +12509,9 @@ pub struct PostIpsResponseData {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ips: Vec<PostIpsResponse>,
+    /**
+     * The number of IPs that can still be added to the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11056,7 +12519,7 @@ pub struct PostIpsResponseData {
     )]
     pub remaining_ips: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Whether or not the IPs are being warmed up.
      */
     #[serde(
         default,
@@ -11068,7 +12531,7 @@ pub struct PostIpsResponseData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIpsRemainingResponseResults {
     /**
-     * The license key provided with your New Relic account.
+     * The length of time until user can add more IPs.
      */
     #[serde(
         default,
@@ -11077,7 +12540,7 @@ pub struct GetIpsRemainingResponseResults {
     )]
     pub period: String,
     /**
-     * The number of errors found while adding recipients.
+     * The current cost to add an IP.
      */
     #[serde(
         default,
@@ -11085,6 +12548,9 @@ pub struct GetIpsRemainingResponseResults {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub price_per_ip: f64,
+    /**
+     * The number of IPs that can still be added to the user.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11106,7 +12572,7 @@ pub struct GetIpsRemainingResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetIpsIpAddressResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The IP address.
      */
     #[serde(
         default,
@@ -11115,7 +12581,7 @@ pub struct GetIpsIpAddressResponse {
     )]
     pub ip: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The list of IP pools that this IP address belongs to.
      */
     #[serde(
         default,
@@ -11124,7 +12590,7 @@ pub struct GetIpsIpAddressResponse {
     )]
     pub pools: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * The reverse DNS record for this IP address.
      */
     #[serde(
         default,
@@ -11132,6 +12598,9 @@ pub struct GetIpsIpAddressResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rdns: String,
+    /**
+     * The date that the IP address was entered into warmup.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11139,7 +12608,7 @@ pub struct GetIpsIpAddressResponse {
     )]
     pub start_date: i64,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The subusers that can send email using this IP address.
      */
     #[serde(
         default,
@@ -11148,7 +12617,7 @@ pub struct GetIpsIpAddressResponse {
     )]
     pub subusers: Vec<String>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this IP address is currently warming up.
      */
     #[serde(
         default,
@@ -11156,7 +12625,7 @@ pub struct GetIpsIpAddressResponse {
     )]
     pub warmup: bool,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this IP address is associated with a reverse DNS record.
      */
     #[serde(
         default,
@@ -11167,20 +12636,32 @@ pub struct GetIpsIpAddressResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDomainsRequest {
+    /**
+     * Whether to allow SendGrid to manage your SPF records, DKIM keys, and DKIM key rotation.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub automatic_security: Option<bool>,
+    /**
+     * Add a custom DKIM selector. Accepts three letters or numbers.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_dkim_selector: String,
+    /**
+     * Specify whether to use a custom SPF or allow SendGrid to manage your SPF. This option is only available to authenticated domains set up for manual security.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_spf: Option<bool>,
+    /**
+     * Whether to use this authenticated domain as the fallback if no authenticated domains match the sender's domain.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * Domain being authenticated.
      */
     #[serde(
         default,
@@ -11188,18 +12669,27 @@ pub struct PostWhitelabelDomainsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub domain: String,
+    /**
+     * The IP addresses that will be included in the custom SPF record for this authenticated domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ips: Vec<String>,
+    /**
+     * The subdomain to use for this authenticated domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subdomain: String,
+    /**
+     * The username associated with this domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11210,8 +12700,14 @@ pub struct PostWhitelabelDomainsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchWhitelabelDomainsDomainRequest {
+    /**
+     * Indicates whether to generate a custom SPF record for manual security.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_spf: Option<bool>,
+    /**
+     * Indicates whether this is the default authenticated domain.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default: Option<bool>,
 }
@@ -11220,7 +12716,7 @@ pub struct PatchWhitelabelDomainsDomainRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MailCname {
     /**
-     * The CNAME record for the authenticated domain.
+     * The reason this record is invalid.
      */
     #[serde(
         default,
@@ -11229,7 +12725,7 @@ pub struct MailCname {
     )]
     pub reason: String,
     /**
-     * The CNAME record for the authenticated domain.
+     * Indicates if this DNS record is valid.
      */
     #[serde(
         default,
@@ -11241,9 +12737,6 @@ pub struct MailCname {
 /// A DNS record for this authenticated domain.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Dkim1 {
-    /**
-     * A DNS record for this authenticated domain.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11251,7 +12744,7 @@ pub struct Dkim1 {
     )]
     pub reason: String,
     /**
-     * A DNS record for this authenticated domain.
+     * Indicates if the DNS record is valid.
      */
     #[serde(
         default,
@@ -11263,9 +12756,6 @@ pub struct Dkim1 {
 /// The SPF record for the authenticated domain.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Spf {
-    /**
-     * The SPF record for the authenticated domain.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11273,7 +12763,7 @@ pub struct Spf {
     )]
     pub reason: String,
     /**
-     * The SPF record for the authenticated domain.
+     * Indicates if the SPF record is valid.
      */
     #[serde(
         default,
@@ -11286,22 +12776,22 @@ pub struct Spf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDomainsValidateResponseValidationResults {
     /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     * A DNS record for this authenticated domain.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "dkim1")]
     pub dkim_1: Option<Dkim1>,
     /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     * A DNS record for this authenticated domain.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "dkim2")]
     pub dkim_2: Option<Dkim1>,
     /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     * The CNAME record for the authenticated domain.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mail_cname: Option<MailCname>,
     /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     * The SPF record for the authenticated domain.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spf: Option<Spf>,
@@ -11309,6 +12799,9 @@ pub struct PostWhitelabelDomainsValidateResponseValidationResults {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDomainsValidateResponse {
+    /**
+     * The ID of the authenticated domain.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11316,7 +12809,7 @@ pub struct PostWhitelabelDomainsValidateResponse {
     )]
     pub id: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is a valid authenticated domain.
      */
     #[serde(
         default,
@@ -11332,9 +12825,6 @@ pub struct PostWhitelabelDomainsValidateResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelDomainsValidateResponseData {
-    /**
-     * The reasons why the validation failed.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -11345,18 +12835,12 @@ pub struct PostWhitelabelDomainsValidateResponseData {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetVerifiedSendersDomainsResponseResults {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub hard_failures: Vec<String>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -11373,17 +12857,11 @@ pub struct GetVerifiedSendersDomainsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetVerifiedSendersStepsCompletedResponseResults {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub domain_verified: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -11409,27 +12887,18 @@ pub struct GetVerifiedSendersResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostVerifiedSendersResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11450,18 +12919,12 @@ pub struct PostVerifiedSendersResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetVerifiedSendersVerifyTokenResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11492,32 +12955,50 @@ pub struct DeleteVerifiedSendersResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutDesignRequest {
+    /**
+     * The list of categories applied to the design
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub categories: Vec<String>,
+    /**
+     * If true, plain_content is always generated from html_content. If false, plain_content is not altered.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub generate_plain_content: Option<bool>,
+    /**
+     * The HTML content of the Design.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
+    /**
+     * Name of the Design.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * Plain text content of the Design.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub plain_content: String,
+    /**
+     * Subject of the Design.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11578,7 +13059,7 @@ pub struct PutMcContactsRequest {
     )]
     pub contacts: Vec<ContactRequest>,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * An array of List ID strings that this contact will be added to.
      */
     #[serde(
         default,
@@ -11591,7 +13072,7 @@ pub struct PutMcContactsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutMcContactsResponse {
     /**
-     * The license key provided with your New Relic account.
+     * Indicates that the contacts are queued for processing. Check the job status with the "Import Contacts Status" endpoint.
      */
     #[serde(
         default,
@@ -11605,7 +13086,7 @@ pub struct PutMcContactsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteMcContactsResponse {
     /**
-     * helper text or docs for troubleshooting
+     * The deletion job ID.
      */
     #[serde()]
     pub job_id: Help,
@@ -11625,12 +13106,12 @@ pub struct DeleteMcContactsResponseData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BillableBreakdown {
     /**
-     * `billable_breakdown` will only appear to the parent user in an account with subusers.
+     * A map of each subuser's billable contact usage. Each key is the subuser's ID and each value is the usage thus far this month.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub breakdown: Option<Help>,
     /**
-     * `billable_breakdown` will only appear to the parent user in an account with subusers.
+     * The sum of all the subuser's billable contacts
      */
     #[serde(
         default,
@@ -11647,12 +13128,18 @@ pub struct GetMcContactsCountResponse {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub billable_breakdown: Option<BillableBreakdown>,
+    /**
+     * The count of contacts this month for billing purposes.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub billable_count: i64,
+    /**
+     * The total number of contacts.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11663,9 +13150,6 @@ pub struct GetMcContactsCountResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Lists {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11673,9 +13157,6 @@ pub struct Lists {
         rename = "ID"
     )]
     pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11687,27 +13168,18 @@ pub struct Lists {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMarketingContactsExportsResponseResultMetadata {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub prev: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11722,7 +13194,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<GetMarketingContactsExportsResponseResultMetadata>,
     /**
-     * The license key provided with your New Relic account.
+     * This ISO8601 timestamp when the export was completed.
      */
     #[serde(
         default,
@@ -11731,7 +13203,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     )]
     pub completed_at: String,
     /**
-     * The license key provided with your New Relic account.
+     * This ISO8601 timestamp when the export was created.
      */
     #[serde(
         default,
@@ -11740,7 +13212,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     )]
     pub created_at: String,
     /**
-     * The license key provided with your New Relic account.
+     * This ISO8601 timestamp when the export expires.
      */
     #[serde(
         default,
@@ -11749,7 +13221,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     )]
     pub expires_at: String,
     /**
-     * The license key provided with your New Relic account.
+     * Allowed types: `contacts_export`, `list_export`, or `segment_export`.
      */
     #[serde(
         default,
@@ -11758,7 +13230,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     )]
     pub export_type: String,
     /**
-     * The license key provided with your New Relic account.
+     * Export jobs ID.
      */
     #[serde(
         default,
@@ -11779,7 +13251,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     )]
     pub segments: Vec<Lists>,
     /**
-     * The license key provided with your New Relic account.
+     * Allowed values: `pending`, `ready`, or `failure`.
      */
     #[serde(
         default,
@@ -11788,7 +13260,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     )]
     pub status: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * One or more download URLs for the contact file(s) if the status is `ready`.
      */
     #[serde(
         default,
@@ -11797,7 +13269,7 @@ pub struct GetMarketingContactsExportsResponseResult {
     )]
     pub urls: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * User ID.
      */
     #[serde(
         default,
@@ -11821,18 +13293,12 @@ pub struct GetMarketingContactsExportsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMarketingContactsExportsResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11853,9 +13319,6 @@ pub struct GetMarketingContactsExportsResponseData {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Notifications {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -11867,13 +13330,15 @@ pub struct Notifications {
 * File type for export file. Choose from `json` or `csv`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FileType {
     #[serde(rename = "csv")]
     Csv,
     #[serde(rename = "json")]
     Json,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -11881,12 +13346,31 @@ impl std::fmt::Display for FileType {
         match self {
             FileType::Csv => "csv",
             FileType::Json => "json",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FileType {
+    fn from(s: String) -> FileType {
+        match s.as_str() {
+            "csv" => FileType::Csv,
+            "json" => FileType::Json,
+            _ => FileType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FileType> for String {
+    fn from(e: FileType) -> String {
+        match e {
+            FileType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Csv
@@ -11901,7 +13385,7 @@ pub struct PostMcContactsExportsRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub file_type: Option<FileType>,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * IDs of the contact lists you want to export.
      */
     #[serde(
         default,
@@ -11909,6 +13393,9 @@ pub struct PostMcContactsExportsRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<String>,
+    /**
+     * The maximum size of an export file in MB. Note that when this option is specified, multiple output files may be returned from the export.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -11918,7 +13405,7 @@ pub struct PostMcContactsExportsRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notifications: Option<Notifications>,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * IDs of the contact segments you want to export.
      */
     #[serde(
         default,
@@ -11933,7 +13420,7 @@ pub struct PostMcContactsExportsResponse {
     #[serde(rename = "_metadata")]
     pub metadata: Metadata,
     /**
-     * The license key provided with your New Relic account.
+     * The ID of the export job.
      */
     #[serde(
         default,
@@ -11945,9 +13432,6 @@ pub struct PostMcContactsExportsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcContactsSearchRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -11961,7 +13445,7 @@ pub struct PostMcContactsSearchResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
     pub metadata: Option<SelfMetadata>,
     /**
-     * The number of errors found while adding recipients.
+     * The total number of contacts matched.
      */
     #[serde(
         default,
@@ -11981,13 +13465,15 @@ pub struct PostMcContactsSearchResponse {
 * Upload file type.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PutMcContactsImportsRequestFileType {
     #[serde(rename = "csv")]
     Csv,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMcContactsImportsRequestFileType {
@@ -11995,12 +13481,31 @@ impl std::fmt::Display for PutMcContactsImportsRequestFileType {
         match self {
             PutMcContactsImportsRequestFileType::Csv => "csv",
             PutMcContactsImportsRequestFileType::Noop => "",
-            PutMcContactsImportsRequestFileType::FallthroughString => "*",
+            PutMcContactsImportsRequestFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PutMcContactsImportsRequestFileType {
+    fn from(s: String) -> PutMcContactsImportsRequestFileType {
+        match s.as_str() {
+            "csv" => PutMcContactsImportsRequestFileType::Csv,
+            "" => PutMcContactsImportsRequestFileType::Noop,
+            _ => PutMcContactsImportsRequestFileType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PutMcContactsImportsRequestFileType> for String {
+    fn from(e: PutMcContactsImportsRequestFileType) -> String {
+        match e {
+            PutMcContactsImportsRequestFileType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PutMcContactsImportsRequestFileType {
     fn default() -> PutMcContactsImportsRequestFileType {
         PutMcContactsImportsRequestFileType::Noop
@@ -12048,7 +13553,7 @@ pub struct PutMcContactsImportsRequest {
     )]
     pub file_type: PutMcContactsImportsRequestFileType,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * All contacts will be added to each of the specified lists.
      */
     #[serde(
         default,
@@ -12060,18 +13565,12 @@ pub struct PutMcContactsImportsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UploadHeaders {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub header: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12083,7 +13582,7 @@ pub struct UploadHeaders {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutMcContactsImportsResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The ID of the import job.
      */
     #[serde(
         default,
@@ -12101,7 +13600,7 @@ pub struct PutMcContactsImportsResponse {
     )]
     pub upload_headers: Vec<UploadHeaders>,
     /**
-     * The license key provided with your New Relic account.
+     * The URI to PUT the upload file to.
      */
     #[serde(
         default,
@@ -12130,9 +13629,6 @@ pub struct GetMarketingContactsImportsResponse {
 /// Array of IDs
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingContactsBatchRequest {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -12155,7 +13651,7 @@ pub struct PostMarketingContactsBatchResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingContactsSearchEmailsRequest {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * One or more primary emails and/or alternate emails to search through your contacts for.
      */
     #[serde(
         default,
@@ -12167,9 +13663,6 @@ pub struct PostMarketingContactsSearchEmailsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingContactsSearchEmailsResponse {
-    /**
-     * helper text or docs for troubleshooting
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub result: Option<Help>,
 }
@@ -12177,7 +13670,7 @@ pub struct PostMarketingContactsSearchEmailsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSendersRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The physical address of the sender identity.
      */
     #[serde(
         default,
@@ -12185,6 +13678,9 @@ pub struct PostMarketingSendersRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address: String,
+    /**
+     * Additional sender identity address information.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12192,7 +13688,7 @@ pub struct PostMarketingSendersRequest {
     )]
     pub address_2: String,
     /**
-     * The license key provided with your New Relic account.
+     * The city of the sender identity.
      */
     #[serde(
         default,
@@ -12201,7 +13697,7 @@ pub struct PostMarketingSendersRequest {
     )]
     pub city: String,
     /**
-     * The license key provided with your New Relic account.
+     * The country of the sender identity.
      */
     #[serde(
         default,
@@ -12212,7 +13708,7 @@ pub struct PostMarketingSendersRequest {
     #[serde()]
     pub from: From,
     /**
-     * The license key provided with your New Relic account.
+     * A nickname for the sender identity. Not used for sending.
      */
     #[serde(
         default,
@@ -12222,12 +13718,18 @@ pub struct PostMarketingSendersRequest {
     pub nickname: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<ReplyTo>,
+    /**
+     * The state of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state: String,
+    /**
+     * The zipcode of the sender identity.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12287,7 +13789,7 @@ pub struct GetMcListsResponseAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteListsResponse {
     /**
-     * The delete has been accepted and is processing.
+     * job_id of the async job
      */
     #[serde(
         default,
@@ -12299,6 +13801,9 @@ pub struct DeleteListsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchMcListsRequest {
+    /**
+     * Your name for your list.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12311,7 +13816,7 @@ pub struct PatchMcListsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteMcListsContactsResponse {
     /**
-     * The removal is accepted and processing.
+     * job_id of the async job
      */
     #[serde(
         default,
@@ -12343,9 +13848,6 @@ pub struct GetMcFieldDefinitionsResponse {
 pub struct PostMcFieldDefinitionsRequest {
     #[serde(default, skip_serializing_if = "FieldType::is_noop")]
     pub field_type: FieldType,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12386,7 +13888,7 @@ pub struct GetMarketingSegmentsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSegmentsRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The id of the list if this segment is a child of a list.  This implies the query is rewritten as `(${query_dsl}) AND CONTAINS(list_ids, ${parent_list_id})`
      */
     #[serde(
         default,
@@ -12423,7 +13925,7 @@ pub struct PostMarketingSegmentsDeleteRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Resources {
     /**
-     * resources in which segment is being used
+     * the resource ids
      */
     #[serde(
         default,
@@ -12432,7 +13934,7 @@ pub struct Resources {
     )]
     pub ids: Vec<String>,
     /**
-     * resources in which segment is being used
+     * the type of resource in use (e.g., "automation")
      */
     #[serde(
         default,
@@ -12446,7 +13948,7 @@ pub struct Resources {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSegmentsDeleteResponseErrors {
     /**
-     * The license key provided with your New Relic account.
+     * error message that indicates why segment cannot be deleted ("in-use", "segment not found", "invalid uuid")
      */
     #[serde(
         default,
@@ -12455,7 +13957,7 @@ pub struct PostMarketingSegmentsDeleteResponseErrors {
     )]
     pub error: String,
     /**
-     * The license key provided with your New Relic account.
+     * Segment ID
      */
     #[serde(
         default,
@@ -12505,7 +14007,7 @@ pub struct PostMarketingSinglesendsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingSinglesendsRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The name of the duplicate Single Send. If you choose to leave the name field blank, your duplicate will be assigned the name of the Single Send it was copied from with the text 'Copy of ' prepended to it. The end of the new Single Send name, including 'Copy of ', will be trimmed if the name exceeds the character limit.
      */
     #[serde(
         default,
@@ -12519,13 +14021,15 @@ pub struct PostMarketingSinglesendsRequest {
 * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SendAt {
     #[serde(rename = "now")]
     Now,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SendAt {
@@ -12533,12 +14037,31 @@ impl std::fmt::Display for SendAt {
         match self {
             SendAt::Now => "now",
             SendAt::Noop => "",
-            SendAt::FallthroughString => "*",
+            SendAt::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SendAt {
+    fn from(s: String) -> SendAt {
+        match s.as_str() {
+            "now" => SendAt::Now,
+            "" => SendAt::Noop,
+            _ => SendAt::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SendAt> for String {
+    fn from(e: SendAt) -> String {
+        match e {
+            SendAt::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SendAt {
     fn default() -> SendAt {
         SendAt::Noop
@@ -12560,13 +14083,15 @@ pub struct PutMarketingSinglesendsScheduleRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PutMarketingSinglesendsScheduleResponseStatus {
     #[serde(rename = "scheduled")]
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
@@ -12574,9 +14099,28 @@ impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
         match self {
             PutMarketingSinglesendsScheduleResponseStatus::Scheduled => "scheduled",
             PutMarketingSinglesendsScheduleResponseStatus::Noop => "",
-            PutMarketingSinglesendsScheduleResponseStatus::FallthroughString => "*",
+            PutMarketingSinglesendsScheduleResponseStatus::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::convert::From<String> for PutMarketingSinglesendsScheduleResponseStatus {
+    fn from(s: String) -> PutMarketingSinglesendsScheduleResponseStatus {
+        match s.as_str() {
+            "scheduled" => PutMarketingSinglesendsScheduleResponseStatus::Scheduled,
+            "" => PutMarketingSinglesendsScheduleResponseStatus::Noop,
+            _ => PutMarketingSinglesendsScheduleResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PutMarketingSinglesendsScheduleResponseStatus> for String {
+    fn from(e: PutMarketingSinglesendsScheduleResponseStatus) -> String {
+        match e {
+            PutMarketingSinglesendsScheduleResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -12593,6 +14137,9 @@ impl PutMarketingSinglesendsScheduleResponseStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutMarketingSinglesendsScheduleResponse {
+    /**
+     *
+     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -12606,7 +14153,7 @@ pub struct PutMarketingSinglesendsScheduleResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMarketingSinglesendsCategoriesResponse {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * list of latest one thousand unique categories associated with all Single Sends in ascending order
      */
     #[serde(
         default,
@@ -12618,6 +14165,9 @@ pub struct GetMarketingSinglesendsCategoriesResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMarketingTestSendEmailRequest {
+    /**
+     * A custom unsubscribe URL.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12625,7 +14175,7 @@ pub struct PostMarketingTestSendEmailRequest {
     )]
     pub custom_unsubscribe_url: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * An array of email addresses you want to send the test message to.
      */
     #[serde(
         default,
@@ -12633,12 +14183,18 @@ pub struct PostMarketingTestSendEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub emails: Vec<String>,
+    /**
+     * You can either specify this address or specify a verified sender ID.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub from_address: String,
+    /**
+     * This ID must belong to a verified sender. Alternatively, you may supply a `from_address` email.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -12652,7 +14208,7 @@ pub struct PostMarketingTestSendEmailRequest {
     )]
     pub suppression_group_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The ID of the template that you would like to use. If you use a template that contains a subject and content (either text or HTML), then those values specified at the personalizations or message level will not be used.
      */
     #[serde(
         default,
@@ -12660,6 +14216,9 @@ pub struct PostMarketingTestSendEmailRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub template_id: String,
+    /**
+     *  You can override the active template with an alternative template version by passing the version ID in this field. If this field is blank, the active template version will be used.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12680,14 +14239,8 @@ pub struct GetSendersResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostSendersRequest {
-    /**
-     * helper text or docs for troubleshooting
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub from: Option<Help>,
-    /**
-     * helper text or docs for troubleshooting
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<Help>,
 }
@@ -12717,6 +14270,9 @@ pub struct ListAllListsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchContactdbListsListResponse {
+    /**
+     * The ID of the list
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -12724,7 +14280,7 @@ pub struct PatchContactdbListsListResponse {
     )]
     pub id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The new name for the list
      */
     #[serde(
         default,
@@ -12732,6 +14288,9 @@ pub struct PatchContactdbListsListResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+    /**
+     * The number of recipients on the list
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -12769,7 +14328,7 @@ pub struct PostContactdbRecipientsRequest {
     )]
     pub age: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address of the recipient.
      */
     #[serde(
         default,
@@ -12778,7 +14337,7 @@ pub struct PostContactdbRecipientsRequest {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The first name of the recipient.
      */
     #[serde(
         default,
@@ -12787,7 +14346,7 @@ pub struct PostContactdbRecipientsRequest {
     )]
     pub first_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The last name of the recipient.
      */
     #[serde(
         default,
@@ -12799,9 +14358,6 @@ pub struct PostContactdbRecipientsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchContactdbRecipientsRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12809,7 +14365,7 @@ pub struct PatchContactdbRecipientsRequest {
     )]
     pub email: String,
     /**
-     * The license key provided with your New Relic account.
+     * The first name of the recipient. This is one of the default custom fields.
      */
     #[serde(
         default,
@@ -12818,7 +14374,7 @@ pub struct PatchContactdbRecipientsRequest {
     )]
     pub first_name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The last name of the recipient. This is one of the default custom fields.
      */
     #[serde(
         default,
@@ -12831,7 +14387,7 @@ pub struct PatchContactdbRecipientsRequest {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetContactdbStatusResponse {
     /**
-     * The license key provided with your New Relic account.
+     * Valid values are "worker_delay" and "worker_delay_seconds" (the second value appears only if "worker_delay" has a value of "delayed").
      */
     #[serde(
         default,
@@ -12840,7 +14396,7 @@ pub struct GetContactdbStatusResponse {
     )]
     pub id: String,
     /**
-     * The license key provided with your New Relic account.
+     * Valid values for the ID "worker_delay" are "OK" or "Delayed". Valid values for the ID "worker_delay_seconds" is the time of delay to upload.
      */
     #[serde(
         default,
@@ -12872,9 +14428,6 @@ pub struct GetContactdbRecipientsRecipientListsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostContactdbRecipientsSearchRequest {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -12913,18 +14466,12 @@ pub struct PostContactdbRecipientsSearchResponseCustomFields {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -12950,27 +14497,18 @@ pub struct PostContactdbRecipientsSearchResponse {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_fields: Vec<PostContactdbRecipientsSearchResponseCustomFields>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13048,18 +14586,12 @@ pub struct PostContactdbCustomFieldsRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReservedFields {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13091,21 +14623,24 @@ pub struct ListAllSegmentsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchContactdbSegmentsSegmentRequest {
+    /**
+     * The conditions by which this segment should be created.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub conditions: Vec<ContactdbSegmentsConditions>,
+    /**
+     * The list ID you would like this segment to be built from.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub list_id: f64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13127,7 +14662,7 @@ pub struct ListRecipientsOnASegmentResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCategoriesResponse {
     /**
-     * The license key provided with your New Relic account.
+     * A category used to group emails by broad topic.
      */
     #[serde(
         default,
@@ -13139,6 +14674,9 @@ pub struct GetCategoriesResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCategoriesResponseData {
+    /**
+     * The error returned.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -13159,27 +14697,18 @@ pub struct GetCampaignsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetCampaignsCampaignResponse {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub categories: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_unsubscribe_url: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13192,36 +14721,24 @@ pub struct GetCampaignsCampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip_pool: String,
-    /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<i64>,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub plain_content: String,
-    /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -13234,18 +14751,12 @@ pub struct GetCampaignsCampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sender_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13258,9 +14769,6 @@ pub struct GetCampaignsCampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub suppression_group_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13272,7 +14780,7 @@ pub struct GetCampaignsCampaignResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateACampaignRequest {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The categories you want to tag on this campaign.
      */
     #[serde(
         default,
@@ -13281,7 +14789,7 @@ pub struct UpdateACampaignRequest {
     )]
     pub categories: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * The HTML content of this campaign.
      */
     #[serde(
         default,
@@ -13290,7 +14798,7 @@ pub struct UpdateACampaignRequest {
     )]
     pub html_content: String,
     /**
-     * The license key provided with your New Relic account.
+     * The plain content of this campaign.
      */
     #[serde(
         default,
@@ -13299,7 +14807,7 @@ pub struct UpdateACampaignRequest {
     )]
     pub plain_content: String,
     /**
-     * The license key provided with your New Relic account.
+     * The subject line for your campaign.
      */
     #[serde(
         default,
@@ -13308,7 +14816,7 @@ pub struct UpdateACampaignRequest {
     )]
     pub subject: String,
     /**
-     * The license key provided with your New Relic account.
+     * The title of the campaign.
      */
     #[serde(
         default,
@@ -13326,9 +14834,6 @@ pub struct SendACampaignResponse {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13351,13 +14856,15 @@ pub struct ScheduleACampaignRequest {
 * The status of your campaign.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ScheduleACampaignResponseStatus {
     #[serde(rename = "Scheduled")]
     Scheduled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ScheduleACampaignResponseStatus {
@@ -13365,12 +14872,31 @@ impl std::fmt::Display for ScheduleACampaignResponseStatus {
         match self {
             ScheduleACampaignResponseStatus::Scheduled => "Scheduled",
             ScheduleACampaignResponseStatus::Noop => "",
-            ScheduleACampaignResponseStatus::FallthroughString => "*",
+            ScheduleACampaignResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ScheduleACampaignResponseStatus {
+    fn from(s: String) -> ScheduleACampaignResponseStatus {
+        match s.as_str() {
+            "Scheduled" => ScheduleACampaignResponseStatus::Scheduled,
+            "" => ScheduleACampaignResponseStatus::Noop,
+            _ => ScheduleACampaignResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ScheduleACampaignResponseStatus> for String {
+    fn from(e: ScheduleACampaignResponseStatus) -> String {
+        match e {
+            ScheduleACampaignResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ScheduleACampaignResponseStatus {
     fn default() -> ScheduleACampaignResponseStatus {
         ScheduleACampaignResponseStatus::Noop
@@ -13384,12 +14910,18 @@ impl ScheduleACampaignResponseStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ScheduleACampaignResponse {
+    /**
+     * The campaign ID.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The date time you scheduled your campaign to be sent.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -13408,12 +14940,18 @@ pub struct ScheduleACampaignResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateAScheduledCampaignResponse {
+    /**
+     * The campaign ID
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * The unix timestamp to send the campaign.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -13421,7 +14959,7 @@ pub struct UpdateAScheduledCampaignResponse {
     )]
     pub send_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The status of the schedule.
      */
     #[serde(
         default,
@@ -13434,7 +14972,7 @@ pub struct UpdateAScheduledCampaignResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendATestCampaignRequest {
     /**
-     * The license key provided with your New Relic account.
+     * The email address that should receive the test campaign.
      */
     #[serde(
         default,
@@ -13448,6 +14986,7 @@ pub struct SendATestCampaignRequest {
 * Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Generations {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -13455,8 +14994,9 @@ pub enum Generations {
     Legacy,
     #[serde(rename = "legacy,dynamic")]
     LegacyDynamic,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Generations {
@@ -13465,12 +15005,32 @@ impl std::fmt::Display for Generations {
             Generations::Dynamic => "dynamic",
             Generations::Legacy => "legacy",
             Generations::LegacyDynamic => "legacy,dynamic",
-            Generations::FallthroughString => "*",
+            Generations::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Generations {
+    fn from(s: String) -> Generations {
+        match s.as_str() {
+            "dynamic" => Generations::Dynamic,
+            "legacy" => Generations::Legacy,
+            "legacy,dynamic" => Generations::LegacyDynamic,
+            _ => Generations::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Generations> for String {
+    fn from(e: Generations) -> String {
+        match e {
+            Generations::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Generations {
     fn default() -> Generations {
         Generations::Legacy
@@ -13494,18 +15054,12 @@ pub struct GetTemplatesResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTemplatesResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13526,10 +15080,13 @@ pub struct GetTemplatesResponseData {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTemplatesRequest {
+    /**
+     * Defines whether the template supports dynamic replacement.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub generation: Option<Generation>,
     /**
-     * The license key provided with your New Relic account.
+     * The name for the new transactional template.
      */
     #[serde(
         default,
@@ -13541,6 +15098,9 @@ pub struct PostTemplatesRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostTemplatesTemplateRequest {
+    /**
+     * The name for the new transactional template.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13551,6 +15111,9 @@ pub struct PostTemplatesTemplateRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTemplatesTemplateRequest {
+    /**
+     * The name of the transactional template.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13575,7 +15138,7 @@ pub struct GetUserWebhooksParseSettingsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserWebhooksParseStatsResponseMetrics {
     /**
-     * The number of errors found while adding recipients.
+     * The number of emails received and parsed by the Parse Webhook.
      */
     #[serde(
         default,
@@ -13594,7 +15157,7 @@ pub struct GetUserWebhooksParseStatsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserWebhooksParseStatsResponseData {
     /**
-     * The license key provided with your New Relic account.
+     * The date that the stats were collected.
      */
     #[serde(
         default,
@@ -13616,7 +15179,7 @@ pub struct GetUserWebhooksParseStatsResponseData {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserWebhooksEventSettingsSignedResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The public key you can use to verify the Twilio SendGrid signature.
      */
     #[serde(
         default,
@@ -13628,9 +15191,6 @@ pub struct GetUserWebhooksEventSettingsSignedResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTrackingSettingsOpenResponse {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -13640,18 +15200,12 @@ pub struct GetTrackingSettingsOpenResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchUserWebhooksEventSettingsSignedResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13672,24 +15226,36 @@ pub struct PatchUserWebhooksEventSettingsSignedResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostUserWebhooksEventTestRequest {
+    /**
+     * The client ID Twilio SendGrid sends to your OAuth server or service provider to generate an OAuth access token. When passing data in this field, you must also include the oauth_client_secret and oauth_token_url fields.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub oauth_client_id: String,
+    /**
+     * This secret is needed only once to create an access token. SendGrid will store this secret, allowing you to update your Client ID and Token URL without passing the secret to SendGrid again.  When passing data in this field, you must also include the oauth_client_id and oauth_token_url fields.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub oauth_client_secret: String,
+    /**
+     * The URL where Twilio SendGrid sends the Client ID and Client Secret to generate an access token. This should be your OAuth server or service provider.  When passing data in this field, you must also include the oauth_client_id and oauth_client_secret fields.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub oauth_token_url: String,
+    /**
+     * The URL where you would like the test notification to be sent.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13709,13 +15275,15 @@ pub struct GetMessagesResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PostMessagesDownloadResponseStatus {
     #[serde(rename = "pending")]
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PostMessagesDownloadResponseStatus {
@@ -13723,12 +15291,31 @@ impl std::fmt::Display for PostMessagesDownloadResponseStatus {
         match self {
             PostMessagesDownloadResponseStatus::Pending => "pending",
             PostMessagesDownloadResponseStatus::Noop => "",
-            PostMessagesDownloadResponseStatus::FallthroughString => "*",
+            PostMessagesDownloadResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PostMessagesDownloadResponseStatus {
+    fn from(s: String) -> PostMessagesDownloadResponseStatus {
+        match s.as_str() {
+            "pending" => PostMessagesDownloadResponseStatus::Pending,
+            "" => PostMessagesDownloadResponseStatus::Noop,
+            _ => PostMessagesDownloadResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PostMessagesDownloadResponseStatus> for String {
+    fn from(e: PostMessagesDownloadResponseStatus) -> String {
+        match e {
+            PostMessagesDownloadResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PostMessagesDownloadResponseStatus {
     fn default() -> PostMessagesDownloadResponseStatus {
         PostMessagesDownloadResponseStatus::Noop
@@ -13742,9 +15329,6 @@ impl PostMessagesDownloadResponseStatus {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMessagesDownloadResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -13758,7 +15342,7 @@ pub struct PostMessagesDownloadResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMessagesDownloadResponse {
     /**
-     * The license key provided with your New Relic account.
+     * Returns the aws signed link to the csv file which mako UI should perform a get on to trigger the csv download for the user
      */
     #[serde(
         default,
@@ -13767,7 +15351,7 @@ pub struct GetMessagesDownloadResponse {
     )]
     pub csv: String,
     /**
-     * The license key provided with your New Relic account.
+     * A signed link that will allow you to download the CSV file requested by the Request a CSV endpoint.
      */
     #[serde(
         default,
@@ -13780,7 +15364,7 @@ pub struct GetMessagesDownloadResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTrackingSettingsResponseResult {
     /**
-     * The license key provided with your New Relic account.
+     * A description about the event that is being tracked.
      */
     #[serde(
         default,
@@ -13789,7 +15373,7 @@ pub struct GetTrackingSettingsResponseResult {
     )]
     pub description: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this tracking setting is currently enabled.
      */
     #[serde(
         default,
@@ -13797,7 +15381,7 @@ pub struct GetTrackingSettingsResponseResult {
     )]
     pub enabled: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the event being tracked.
      */
     #[serde(
         default,
@@ -13806,7 +15390,7 @@ pub struct GetTrackingSettingsResponseResult {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The title of the tracking setting.
      */
     #[serde(
         default,
@@ -13831,6 +15415,9 @@ pub struct GetTrackingSettingsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchTrackingSettingsOpenRequest {
+    /**
+     * The setting you want to use for click tracking.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
 }
@@ -13844,7 +15431,7 @@ pub struct GetStatsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetStatsResponseData {
     /**
-     * The license key provided with your New Relic account.
+     * The date the stats were gathered.
      */
     #[serde(
         default,
@@ -13867,6 +15454,7 @@ pub struct GetStatsResponseData {
 * The country you would like to see statistics for. Currently only supported for US and CA.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Country {
     #[serde(rename = "CA")]
     Ca,
@@ -13874,8 +15462,9 @@ pub enum Country {
     Us,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Country {
@@ -13884,12 +15473,32 @@ impl std::fmt::Display for Country {
             Country::Ca => "CA",
             Country::Us => "US",
             Country::Noop => "",
-            Country::FallthroughString => "*",
+            Country::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Country {
+    fn from(s: String) -> Country {
+        match s.as_str() {
+            "CA" => Country::Ca,
+            "US" => Country::Us,
+            "" => Country::Noop,
+            _ => Country::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Country> for String {
+    fn from(e: Country) -> String {
+        match e {
+            Country::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Country {
     fn default() -> Country {
         Country::Noop
@@ -13906,7 +15515,7 @@ pub struct GetGeoStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<AdvancedStatsClicksOpensAllOf>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the specific segmentation.
      */
     #[serde(
         default,
@@ -13915,7 +15524,7 @@ pub struct GetGeoStatsResponse {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The type of segmentation.
      */
     #[serde(
         default,
@@ -13929,7 +15538,7 @@ pub struct GetGeoStatsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetGeoStatsResponseData {
     /**
-     * The license key provided with your New Relic account.
+     * The date that the statistics were gathered.
      */
     #[serde(
         default,
@@ -13956,7 +15565,7 @@ pub struct GetDevicesStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<AdvancedStatsOpens>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the specific segmentation.
      */
     #[serde(
         default,
@@ -13965,7 +15574,7 @@ pub struct GetDevicesStatsResponse {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The type of segmentation.
      */
     #[serde(
         default,
@@ -13979,7 +15588,7 @@ pub struct GetDevicesStatsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetClientsStatsResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The date that the statistics were gathered.
      */
     #[serde(
         default,
@@ -14002,6 +15611,7 @@ pub struct GetClientsStatsResponse {
 * Specifies the type of client to retrieve stats for. Must be either "phone", "tablet", "webmail", or "desktop".
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ClientType {
     #[serde(rename = "desktop")]
     Desktop,
@@ -14013,8 +15623,9 @@ pub enum ClientType {
     Webmail,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ClientType {
@@ -14025,12 +15636,34 @@ impl std::fmt::Display for ClientType {
             ClientType::Tablet => "tablet",
             ClientType::Webmail => "webmail",
             ClientType::Noop => "",
-            ClientType::FallthroughString => "*",
+            ClientType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ClientType {
+    fn from(s: String) -> ClientType {
+        match s.as_str() {
+            "desktop" => ClientType::Desktop,
+            "phone" => ClientType::Phone,
+            "tablet" => ClientType::Tablet,
+            "webmail" => ClientType::Webmail,
+            "" => ClientType::Noop,
+            _ => ClientType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ClientType> for String {
+    fn from(e: ClientType) -> String {
+        match e {
+            ClientType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ClientType {
     fn default() -> ClientType {
         ClientType::Noop
@@ -14047,7 +15680,7 @@ pub struct GetMailboxProvidersStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<AdvancedStatsMailboxProviderAllOf>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the specific segmentation.
      */
     #[serde(
         default,
@@ -14056,7 +15689,7 @@ pub struct GetMailboxProvidersStatsResponse {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The type of segmentation.
      */
     #[serde(
         default,
@@ -14070,7 +15703,7 @@ pub struct GetMailboxProvidersStatsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetMailboxProvidersStatsResponseData {
     /**
-     * The license key provided with your New Relic account.
+     * The date that the statistics were gathered.
      */
     #[serde(
         default,
@@ -14097,7 +15730,7 @@ pub struct GetBrowsersStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metrics: Option<AdvancedStatsClicks>,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the specific segmentation.
      */
     #[serde(
         default,
@@ -14106,7 +15739,7 @@ pub struct GetBrowsersStatsResponse {
     )]
     pub name: String,
     /**
-     * The license key provided with your New Relic account.
+     * The type of segmentation.
      */
     #[serde(
         default,
@@ -14120,7 +15753,7 @@ pub struct GetBrowsersStatsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetBrowsersStatsResponseData {
     /**
-     * The license key provided with your New Relic account.
+     * The date that the statistics were gathered.
      */
     #[serde(
         default,
@@ -14141,8 +15774,14 @@ pub struct GetBrowsersStatsResponseData {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteSuppressionBouncesRequest {
+    /**
+     * This parameter allows you to delete \*\*every\*\* email in your bounce list. This should not be used with the emails parameter.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_all: Option<bool>,
+    /**
+     * Delete multiple emails from your bounce list at the same time. This should not be used with the delete_all parameter.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -14153,8 +15792,14 @@ pub struct DeleteSuppressionBouncesRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteSuppressionBlocksRequest {
+    /**
+     * Indicates if you want to delete all blocked email addresses.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_all: Option<bool>,
+    /**
+     * The specific blocked email addresses that you want to delete.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -14165,6 +15810,9 @@ pub struct DeleteSuppressionBlocksRequest {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetSuppressionUnsubscribesResponse {
+    /**
+     * A Unix timestamp indicating when the recipient was added to the global suppression list.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -14172,7 +15820,7 @@ pub struct GetSuppressionUnsubscribesResponse {
     )]
     pub created: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address of the recipient who is globally suppressed.
      */
     #[serde(
         default,
@@ -14185,7 +15833,7 @@ pub struct GetSuppressionUnsubscribesResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RetrieveAGlobalSuppressionResponse {
     /**
-     * The license key provided with your New Relic account.
+     * The email address that is globally suppressed. This will be an empty object if the email address you included in your call is not globally suppressed.
      */
     #[serde(
         default,
@@ -14198,7 +15846,7 @@ pub struct RetrieveAGlobalSuppressionResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAsmGroupsResponse {
     /**
-     * The license key provided with your New Relic account.
+     * A brief description of the suppression group.
      */
     #[serde(
         default,
@@ -14206,6 +15854,9 @@ pub struct PostAsmGroupsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The ID of the suppression group.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -14213,7 +15864,7 @@ pub struct PostAsmGroupsResponse {
     )]
     pub id: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if this is the default suppression group.
      */
     #[serde(
         default,
@@ -14221,7 +15872,7 @@ pub struct PostAsmGroupsResponse {
     )]
     pub is_default: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the suppression group.
      */
     #[serde(
         default,
@@ -14233,21 +15884,24 @@ pub struct PostAsmGroupsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetAsmGroupsGroupResponse {
+    /**
+     * The ID of the suppression group.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_email_sent_at: String,
+    /**
+     * The number of unsubscribes, or suppressions, in this group.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -14272,7 +15926,7 @@ pub struct GetAsmGroupsGroupResponseAllOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostAsmGroupsGroupSuppressionsResponse {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The email addresses you added to the unsubscribe group
      */
     #[serde(
         default,
@@ -14284,6 +15938,9 @@ pub struct PostAsmGroupsGroupSuppressionsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetAsmSuppressionsResponse {
+    /**
+     * A UNIX timestamp indicating when the suppression was created.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -14291,7 +15948,7 @@ pub struct GetAsmSuppressionsResponse {
     )]
     pub created_at: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The email address that was suppressed.
      */
     #[serde(
         default,
@@ -14299,6 +15956,9 @@ pub struct GetAsmSuppressionsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
+    /**
+     * The id of the suppression group that this email address belongs to.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -14306,7 +15966,7 @@ pub struct GetAsmSuppressionsResponse {
     )]
     pub group_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the suppression group that this email address belongs to.
      */
     #[serde(
         default,
@@ -14319,7 +15979,7 @@ pub struct GetAsmSuppressionsResponse {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Suppressions {
     /**
-     * The license key provided with your New Relic account.
+     * The description of the suppression group.
      */
     #[serde(
         default,
@@ -14327,6 +15987,9 @@ pub struct Suppressions {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
+    /**
+     * The id of the suppression group.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -14334,7 +15997,7 @@ pub struct Suppressions {
     )]
     pub id: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the suppression group  is set as the default.
      */
     #[serde(
         default,
@@ -14342,7 +16005,7 @@ pub struct Suppressions {
     )]
     pub is_default: bool,
     /**
-     * The license key provided with your New Relic account.
+     * The name of the suppression group.
      */
     #[serde(
         default,
@@ -14351,7 +16014,7 @@ pub struct Suppressions {
     )]
     pub name: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Indicates if the given email address is suppressed for this group.
      */
     #[serde(
         default,
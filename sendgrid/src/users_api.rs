@@ -21,9 +21,21 @@ impl UsersApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_user_profile(&self) -> ClientResult<crate::types::GetUserProfileResponse> {
+        Ok(self.get_user_profile_with_response().await?.body)
+    }
+    /**
+     * Get a user's profile.
+     *
+     * This function performs a `GET` to the `/user/profile` endpoint.
+     *
+     * As opposed to `get_user_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_profile_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserProfileResponse>> {
         let url = self.client.url("/user/profile", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,9 +61,22 @@ impl UsersApi {
         &self,
         body: &crate::types::UserProfile,
     ) -> ClientResult<crate::types::UserProfile> {
+        Ok(self.patch_user_profile_with_response(body).await?.body)
+    }
+    /**
+     * Update a user's profile.
+     *
+     * This function performs a `PATCH` to the `/user/profile` endpoint.
+     *
+     * As opposed to `patch_user_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_user_profile_with_response(
+        &self,
+        body: &crate::types::UserProfile,
+    ) -> ClientResult<crate::Response<crate::types::UserProfile>> {
         let url = self.client.url("/user/profile", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -74,9 +99,21 @@ impl UsersApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_user_account(&self) -> ClientResult<crate::types::GetUserAccountResponse> {
+        Ok(self.get_user_account_with_response().await?.body)
+    }
+    /**
+     * Get a user's account information.
+     *
+     * This function performs a `GET` to the `/user/account` endpoint.
+     *
+     * As opposed to `get_user_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_account_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserAccountResponse>> {
         let url = self.client.url("/user/account", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -97,9 +134,21 @@ impl UsersApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_user_email(&self) -> ClientResult<crate::types::GetUserEmailResponse> {
+        Ok(self.get_user_email_with_response().await?.body)
+    }
+    /**
+     * Retrieve your account email address.
+     *
+     * This function performs a `GET` to the `/user/email` endpoint.
+     *
+     * As opposed to `get_user_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_email_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserEmailResponse>> {
         let url = self.client.url("/user/email", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -123,9 +172,22 @@ impl UsersApi {
         &self,
         body: &crate::types::PutUserEmailRequest,
     ) -> ClientResult<crate::types::GetUserEmailResponse> {
+        Ok(self.put_user_email_with_response(body).await?.body)
+    }
+    /**
+     * Update your account email address.
+     *
+     * This function performs a `PUT` to the `/user/email` endpoint.
+     *
+     * As opposed to `put_user_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_user_email_with_response(
+        &self,
+        body: &crate::types::PutUserEmailRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetUserEmailResponse>> {
         let url = self.client.url("/user/email", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -146,9 +208,21 @@ impl UsersApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_user_username(&self) -> ClientResult<crate::types::Users> {
+        Ok(self.get_user_username_with_response().await?.body)
+    }
+    /**
+     * Retrieve your username.
+     *
+     * This function performs a `GET` to the `/user/username` endpoint.
+     *
+     * As opposed to `get_user_username`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_username_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::Users>> {
         let url = self.client.url("/user/username", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -172,9 +246,22 @@ impl UsersApi {
         &self,
         body: &crate::types::PutUserUsernameRequest,
     ) -> ClientResult<crate::types::PutUserUsernameResponse> {
+        Ok(self.put_user_username_with_response(body).await?.body)
+    }
+    /**
+     * Update your username.
+     *
+     * This function performs a `PUT` to the `/user/username` endpoint.
+     *
+     * As opposed to `put_user_username`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_user_username_with_response(
+        &self,
+        body: &crate::types::PutUserUsernameRequest,
+    ) -> ClientResult<crate::Response<crate::types::PutUserUsernameResponse>> {
         let url = self.client.url("/user/username", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -197,9 +284,21 @@ impl UsersApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_user_credits(&self) -> ClientResult<crate::types::GetUserCreditsResponse> {
+        Ok(self.get_user_credits_with_response().await?.body)
+    }
+    /**
+     * Retrieve your credit balance.
+     *
+     * This function performs a `GET` to the `/user/credits` endpoint.
+     *
+     * As opposed to `get_user_credits`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_credits_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserCreditsResponse>> {
         let url = self.client.url("/user/credits", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -223,9 +322,22 @@ impl UsersApi {
         &self,
         body: &crate::types::PutUserPasswordRequest,
     ) -> ClientResult<crate::types::Help> {
+        Ok(self.put_user_password_with_response(body).await?.body)
+    }
+    /**
+     * Update your password.
+     *
+     * This function performs a `PUT` to the `/user/password` endpoint.
+     *
+     * As opposed to `put_user_password`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_user_password_with_response(
+        &self,
+        body: &crate::types::PutUserPasswordRequest,
+    ) -> ClientResult<crate::Response<crate::types::Help>> {
         let url = self.client.url("/user/password", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -31,9 +31,21 @@ impl Webhooks {
     pub async fn get_user_event_settings(
         &self,
     ) -> ClientResult<crate::types::WebhooksEventWebhookResponse> {
+        Ok(self.get_user_event_settings_with_response().await?.body)
+    }
+    /**
+     * Retrieve Event Webhook settings.
+     *
+     * This function performs a `GET` to the `/user/webhooks/event/settings` endpoint.
+     *
+     * As opposed to `get_user_event_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_event_settings_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::WebhooksEventWebhookResponse>> {
         let url = self.client.url("/user/webhooks/event/settings", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -63,9 +75,25 @@ impl Webhooks {
         &self,
         body: &crate::types::WebhooksEventWebhookUpdateWithOAuthRequest,
     ) -> ClientResult<crate::types::WebhooksEventWebhookResponse> {
+        Ok(self
+            .patch_user_event_settings_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Update Event Notification Settings.
+     *
+     * This function performs a `PATCH` to the `/user/webhooks/event/settings` endpoint.
+     *
+     * As opposed to `patch_user_event_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_user_event_settings_with_response(
+        &self,
+        body: &crate::types::WebhooksEventWebhookUpdateWithOAuthRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhooksEventWebhookResponse>> {
         let url = self.client.url("/user/webhooks/event/settings", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -88,9 +116,21 @@ impl Webhooks {
     pub async fn get_user_parse_settings(
         &self,
     ) -> ClientResult<crate::types::GetUserWebhooksParseSettingsResponse> {
+        Ok(self.get_user_parse_settings_with_response().await?.body)
+    }
+    /**
+     * Retrieve all parse settings.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/settings` endpoint.
+     *
+     * As opposed to `get_user_parse_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_parse_settings_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserWebhooksParseSettingsResponse>> {
         let url = self.client.url("/user/webhooks/parse/settings", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,6 +167,27 @@ impl Webhooks {
         start_date: &str,
         end_date: &str,
     ) -> ClientResult<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        Ok(self
+            .get_user_parse_stats_with_response(limit, offset, aggregated_by, start_date, end_date)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves Inbound Parse Webhook statistics.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/stats` endpoint.
+     *
+     * As opposed to `get_user_parse_stats`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_parse_stats_with_response(
+        &self,
+        limit: &str,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
@@ -148,7 +209,7 @@ impl Webhooks {
             .client
             .url(&format!("/user/webhooks/parse/stats?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -204,6 +265,101 @@ impl Webhooks {
             )
             .await
     }
+    /**
+     * Retrieves Inbound Parse Webhook statistics.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/stats` endpoint.
+     *
+     * As opposed to `get_all_user_parse_stats`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_user_parse_stats_pages_stream(
+        &self,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<
+            crate::utils::Page<crate::types::GetUserWebhooksParseStatsResponseData>,
+        >,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !aggregated_by.to_string().is_empty() {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if !end_date.is_empty() {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if !offset.is_empty() {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !start_date.is_empty() {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/user/webhooks/parse/stats?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::GetUserWebhooksParseStatsResponseData>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves Inbound Parse Webhook statistics.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/stats` endpoint.
+     *
+     * As opposed to `get_all_user_parse_stats`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_user_parse_stats_stream(
+        &self,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetUserWebhooksParseStatsResponseData>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_user_parse_stats_pages_stream(
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+            None,
+        ))
+    }
     /**
      * Retrieve Signed Webhook Public Key.
      *
@@ -222,11 +378,27 @@ impl Webhooks {
     pub async fn get_user_event_settings_signed(
         &self,
     ) -> ClientResult<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
+        Ok(self
+            .get_user_event_settings_signed_with_response()
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve Signed Webhook Public Key.
+     *
+     * This function performs a `GET` to the `/user/webhooks/event/settings/signed` endpoint.
+     *
+     * As opposed to `get_user_event_settings_signed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_event_settings_signed_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetUserWebhooksEventSettingsSignedResponse>>
+    {
         let url = self
             .client
             .url("/user/webhooks/event/settings/signed", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -254,11 +426,28 @@ impl Webhooks {
         &self,
         body: &crate::types::GetTrackingSettingsOpenResponse,
     ) -> ClientResult<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
+        Ok(self
+            .patch_user_event_settings_signed_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Enable/Disable Signed Webhook.
+     *
+     * This function performs a `PATCH` to the `/user/webhooks/event/settings/signed` endpoint.
+     *
+     * As opposed to `patch_user_event_settings_signed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn patch_user_event_settings_signed_with_response(
+        &self,
+        body: &crate::types::GetTrackingSettingsOpenResponse,
+    ) -> ClientResult<crate::Response<crate::types::GetUserWebhooksEventSettingsSignedResponse>>
+    {
         let url = self
             .client
             .url("/user/webhooks/event/settings/signed", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -290,9 +479,23 @@ impl Webhooks {
         &self,
         body: &crate::types::PostUserWebhooksEventTestRequest,
     ) -> ClientResult<()> {
+        self.post_user_event_test_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Test Event Notification Settings.
+     *
+     * This function performs a `POST` to the `/user/webhooks/event/test` endpoint.
+     *
+     * As opposed to `post_user_event_test`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_user_event_test_with_response(
+        &self,
+        body: &crate::types::PostUserWebhooksEventTestRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/user/webhooks/event/test", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
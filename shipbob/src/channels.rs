@@ -17,9 +17,21 @@ impl Channels {
      * This function performs a `GET` to the `/channel` endpoint.
      */
     pub async fn get_page(&self) -> ClientResult<Vec<crate::types::Channel>> {
+        Ok(self.get_page_with_response().await?.body)
+    }
+    /**
+     * Get user-authorized channel info.
+     *
+     * This function performs a `GET` to the `/channel` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Channel>>> {
         let url = self.client.url("/channel", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -47,4 +59,62 @@ impl Channels {
             )
             .await
     }
+    /**
+     * Get user-authorized channel info.
+     *
+     * This function performs a `GET` to the `/channel` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Channel>>> + Unpin + '_
+    {
+        let url = self.client.url("/channel", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Channel>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get user-authorized channel info.
+     *
+     * This function performs a `GET` to the `/channel` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Channel>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(None))
+    }
 }
@@ -21,6 +21,19 @@ impl Inventory {
      * * `inventory_id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, inventory_id: i64) -> ClientResult<crate::types::Inventory> {
+        Ok(self.get_with_response(inventory_id).await?.body)
+    }
+    /**
+     * Get an inventory item.
+     *
+     * This function performs a `GET` to the `/inventory/{inventoryId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        inventory_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Inventory>> {
         let url = self.client.url(
             &format!(
                 "/inventory/{}",
@@ -29,7 +42,7 @@ impl Inventory {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,6 +82,28 @@ impl Inventory {
         sort: &str,
         search: &str,
     ) -> ClientResult<Vec<crate::types::Inventory>> {
+        Ok(self
+            .get_page_with_response(page, limit, is_active, is_digital, ids, sort, search)
+            .await?
+            .body)
+    }
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        page: i64,
+        limit: i64,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ids.is_empty() {
             query_args.push(("IDs".to_string(), ids.join(" ")));
@@ -94,7 +129,7 @@ impl Inventory {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/inventory?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -146,6 +181,95 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Inventory>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if is_active {
+            query_args.push(("IsActive".to_string(), is_active.to_string()));
+        }
+        if is_digital {
+            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        if !sort.is_empty() {
+            query_args.push(("Sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/inventory?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Inventory>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Inventory>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(is_active, is_digital, ids, sort, search, None),
+        )
+    }
     /**
      * Get a list of inventory items by product id.
      *
@@ -157,6 +281,19 @@ impl Inventory {
      * * `channel_id: i64` -- Unique id of the channel.
      */
     pub async fn get_product(&self, product_id: i64) -> ClientResult<Vec<crate::types::Inventory>> {
+        Ok(self.get_product_with_response(product_id).await?.body)
+    }
+    /**
+     * Get a list of inventory items by product id.
+     *
+     * This function performs a `GET` to the `/product/{productId}/inventory` endpoint.
+     *
+     * As opposed to `get_product`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_product_with_response(
+        &self,
+        product_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Inventory>>> {
         let url = self.client.url(
             &format!(
                 "/product/{}/inventory",
@@ -165,7 +302,7 @@ impl Inventory {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -202,4 +339,72 @@ impl Inventory {
             )
             .await
     }
+    /**
+     * Get a list of inventory items by product id.
+     *
+     * This function performs a `GET` to the `/product/{productId}/inventory` endpoint.
+     *
+     * As opposed to `get_all_product`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_product_pages_stream(
+        &self,
+        product_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Inventory>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/product/{}/inventory",
+                crate::progenitor_support::encode_path(&product_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Inventory>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get a list of inventory items by product id.
+     *
+     * This function performs a `GET` to the `/product/{productId}/inventory` endpoint.
+     *
+     * As opposed to `get_all_product`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_product_stream(
+        &self,
+        product_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Inventory>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_product_pages_stream(product_id, None))
+    }
 }
@@ -28,6 +28,24 @@ impl Locations {
         receiving_enabled: bool,
         access_granted: bool,
     ) -> ClientResult<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        Ok(self
+            .get_page_with_response(include_inactive, receiving_enabled, access_granted)
+            .await?
+            .body)
+    }
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IntegrationsLocationInternalAllOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if access_granted {
             query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
@@ -44,7 +62,7 @@ impl Locations {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/location?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -91,4 +109,92 @@ impl Locations {
             )
             .await
     }
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::IntegrationsLocationInternalAllOf>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if access_granted {
+            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+        }
+        if include_inactive {
+            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+        }
+        if receiving_enabled {
+            query_args.push((
+                "ReceivingEnabled".to_string(),
+                receiving_enabled.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/location?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::IntegrationsLocationInternalAllOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IntegrationsLocationInternalAllOf>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            include_inactive,
+            receiving_enabled,
+            access_granted,
+            None,
+        ))
+    }
 }
@@ -29,9 +29,22 @@ impl Orders {
         &self,
         body: &crate::types::OrdersEstimateFulfillmentRequestModel,
     ) -> ClientResult<crate::types::OrdersEstimate> {
+        Ok(self.post_estimate_with_response(body).await?.body)
+    }
+    /**
+     * Estimate Fulfillment Cost For Order.
+     *
+     * This function performs a `POST` to the `/order/estimate` endpoint.
+     *
+     * As opposed to `post_estimate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_estimate_with_response(
+        &self,
+        body: &crate::types::OrdersEstimateFulfillmentRequestModel,
+    ) -> ClientResult<crate::Response<crate::types::OrdersEstimate>> {
         let url = self.client.url("/order/estimate", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -51,6 +64,19 @@ impl Orders {
      * * `channel_id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, order_id: i64) -> ClientResult<crate::types::Order> {
+        Ok(self.get_with_response(order_id).await?.body)
+    }
+    /**
+     * Get Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        order_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Order>> {
         let url = self.client.url(
             &format!(
                 "/order/{}",
@@ -59,7 +85,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -104,6 +130,44 @@ impl Orders {
         last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
         is_tracking_uploaded: bool,
     ) -> ClientResult<Vec<crate::types::Order>> {
+        Ok(self
+            .get_page_with_response(
+                page,
+                limit,
+                ids,
+                reference_ids,
+                start_date,
+                end_date,
+                sort_order,
+                has_tracking,
+                last_update_start_date,
+                last_update_end_date,
+                is_tracking_uploaded,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = end_date {
             query_args.push(("EndDate".to_string(), date.to_rfc3339()));
@@ -144,7 +208,7 @@ impl Orders {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/order?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -217,6 +281,123 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Order>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if has_tracking {
+            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if is_tracking_uploaded {
+            query_args.push((
+                "IsTrackingUploaded".to_string(),
+                is_tracking_uploaded.to_string(),
+            ));
+        }
+        if let Some(date) = last_update_end_date {
+            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = last_update_start_date {
+            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/order?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (Option<crate::utils::NextLink>, Vec<crate::types::Order>) =
+                        self.client
+                            .get_pages(cursor.as_deref().unwrap_or(&url))
+                            .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Order>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            ids,
+            reference_ids,
+            start_date,
+            end_date,
+            sort_order,
+            has_tracking,
+            last_update_start_date,
+            last_update_end_date,
+            is_tracking_uploaded,
+            None,
+        ))
+    }
     /**
      * Create Order.
      *
@@ -230,9 +411,22 @@ impl Orders {
         &self,
         body: &crate::types::OrdersCreateOrderModel,
     ) -> ClientResult<crate::types::Order> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Create Order.
+     *
+     * This function performs a `POST` to the `/order` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::OrdersCreateOrderModel,
+    ) -> ClientResult<crate::Response<crate::types::Order>> {
         let url = self.client.url("/order", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -255,6 +449,19 @@ impl Orders {
         &self,
         order_id: i64,
     ) -> ClientResult<crate::types::OrdersCanceledOrder> {
+        Ok(self.post_cancel_with_response(order_id).await?.body)
+    }
+    /**
+     * Cancel single Order by Order ID.
+     *
+     * This function performs a `POST` to the `/order/{orderId}/cancel` endpoint.
+     *
+     * As opposed to `post_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_cancel_with_response(
+        &self,
+        order_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersCanceledOrder>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/cancel",
@@ -263,7 +470,7 @@ impl Orders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -282,6 +489,19 @@ impl Orders {
      * * `order_id: i64` -- The order ID to Get the JSON Stored.
      */
     pub async fn get_store_json(&self, order_id: i64) -> ClientResult<String> {
+        Ok(self.get_store_json_with_response(order_id).await?.body)
+    }
+    /**
+     * Get Order Store Json.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/storeOrderJson` endpoint.
+     *
+     * As opposed to `get_store_json`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_store_json_with_response(
+        &self,
+        order_id: i64,
+    ) -> ClientResult<crate::Response<String>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/storeOrderJson",
@@ -290,7 +510,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -313,6 +533,23 @@ impl Orders {
         order_id: i64,
         body: &crate::types::OrdersAddStoreOrderJsonModel,
     ) -> ClientResult<String> {
+        Ok(self
+            .post_store_json_with_response(order_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Save the Store Order Json.
+     *
+     * This function performs a `POST` to the `/order/{orderId}/storeOrderJson` endpoint.
+     *
+     * As opposed to `post_store_json`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_store_json_with_response(
+        &self,
+        order_id: i64,
+        body: &crate::types::OrdersAddStoreOrderJsonModel,
+    ) -> ClientResult<crate::Response<String>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/storeOrderJson",
@@ -321,7 +558,7 @@ impl Orders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -346,6 +583,23 @@ impl Orders {
         order_id: i64,
         shipment_id: i64,
     ) -> ClientResult<crate::types::OrdersShipment> {
+        Ok(self
+            .get_shipment_with_response(order_id, shipment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}` endpoint.
+     *
+     * As opposed to `get_shipment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipment_with_response(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment/{}",
@@ -355,7 +609,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -380,6 +634,23 @@ impl Orders {
         shipment_id: i64,
         order_id: &str,
     ) -> ClientResult<crate::types::OrdersShipment> {
+        Ok(self
+            .post_shipment_cancel_with_response(shipment_id, order_id)
+            .await?
+            .body)
+    }
+    /**
+     * Cancel one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `POST` to the `/order/{orderId}/shipment/{shipmentId}/cancel` endpoint.
+     *
+     * As opposed to `post_shipment_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_shipment_cancel_with_response(
+        &self,
+        shipment_id: i64,
+        order_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment/{}/cancel",
@@ -389,7 +660,7 @@ impl Orders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -414,6 +685,23 @@ impl Orders {
         order_id: i64,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        Ok(self
+            .get_shipment_timeline_with_response(order_id, shipment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get one Shipment's status timeline by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_shipment_timeline`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipment_timeline_with_response(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment/{}/timeline",
@@ -423,7 +711,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -462,6 +750,82 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get one Shipment's status timeline by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_shipment_timeline_pages_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OrdersShipmentLog>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrdersShipmentLog>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get one Shipment's status timeline by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_shipment_timeline_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_shipment_timeline_pages_stream(
+            order_id,
+            shipment_id,
+            None,
+        ))
+    }
     /**
      * Get all Shipments for Order.
      *
@@ -476,6 +840,19 @@ impl Orders {
         &self,
         order_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipment>> {
+        Ok(self.get_shipments_with_response(order_id).await?.body)
+    }
+    /**
+     * Get all Shipments for Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment` endpoint.
+     *
+     * As opposed to `get_shipments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipments_with_response(
+        &self,
+        order_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipment>>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment",
@@ -484,7 +861,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -521,6 +898,74 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get all Shipments for Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment` endpoint.
+     *
+     * As opposed to `get_all_shipments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_shipments_pages_stream(
+        &self,
+        order_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OrdersShipment>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrdersShipment>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all Shipments for Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment` endpoint.
+     *
+     * As opposed to `get_all_shipments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_shipments_stream(
+        &self,
+        order_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipment>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_shipments_pages_stream(order_id, None))
+    }
     /**
      * Get logs for one Shipment by Order Id and Shipment Id.
      *
@@ -537,6 +982,23 @@ impl Orders {
         order_id: i64,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        Ok(self
+            .get_shipment_logs_with_response(order_id, shipment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get logs for one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_shipment_logs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipment_logs_with_response(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
         let url = self.client.url(
             &format!(
                 "/order/{}/shipment/{}/logs",
@@ -546,7 +1008,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -585,6 +1047,82 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get logs for one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_shipment_logs_pages_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OrdersShipmentLog>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/order/{}/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&order_id.to_string()),
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrdersShipmentLog>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get logs for one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_shipment_logs_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_shipment_logs_pages_stream(
+            order_id,
+            shipment_id,
+            None,
+        ))
+    }
     /**
      * Get one Shipment by Shipment Id.
      *
@@ -599,6 +1137,22 @@ impl Orders {
         &self,
         shipment_id: i64,
     ) -> ClientResult<crate::types::OrdersShipment> {
+        Ok(self
+            .get_shipment_orders_with_response(shipment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}` endpoint.
+     *
+     * As opposed to `get_shipment_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipment_orders_with_response(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
         let url = self.client.url(
             &format!(
                 "/shipment/{}",
@@ -607,7 +1161,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -630,6 +1184,22 @@ impl Orders {
         &self,
         shipment_id: i64,
     ) -> ClientResult<crate::types::OrdersShipment> {
+        Ok(self
+            .post_shipment_cancel_orders_with_response(shipment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Cancel one Shipment by Shipment Id.
+     *
+     * This function performs a `POST` to the `/shipment/{shipmentId}/cancel` endpoint.
+     *
+     * As opposed to `post_shipment_cancel_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_shipment_cancel_orders_with_response(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::OrdersShipment>> {
         let url = self.client.url(
             &format!(
                 "/shipment/{}/cancel",
@@ -638,7 +1208,7 @@ impl Orders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -660,9 +1230,25 @@ impl Orders {
         &self,
         body: &crate::types::OrdersCancelShipmentsModel,
     ) -> ClientResult<crate::types::OrdersCanceledShipments> {
+        Ok(self
+            .post_shipment_cancel_bulk_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Cancel multiple Shipments by Shipment Id.
+     *
+     * This function performs a `POST` to the `/shipment/cancelbulk` endpoint.
+     *
+     * As opposed to `post_shipment_cancel_bulk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_shipment_cancel_bulk_with_response(
+        &self,
+        body: &crate::types::OrdersCancelShipmentsModel,
+    ) -> ClientResult<crate::Response<crate::types::OrdersCanceledShipments>> {
         let url = self.client.url("/shipment/cancelbulk", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -685,6 +1271,22 @@ impl Orders {
         &self,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        Ok(self
+            .get_shipment_timeline_orders_with_response(shipment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get one Shipment's status timeline by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_shipment_timeline_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipment_timeline_orders_with_response(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
         let url = self.client.url(
             &format!(
                 "/shipment/{}/timeline",
@@ -693,7 +1295,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -730,6 +1332,75 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get one Shipment's status timeline by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_shipment_timeline_all_pages_stream(
+        &self,
+        shipment_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OrdersShipmentLog>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/shipment/{}/timeline",
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrdersShipmentLog>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get one Shipment's status timeline by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_shipment_timeline_all_stream(
+        &self,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_shipment_timeline_all_pages_stream(shipment_id, None))
+    }
     /**
      * Get logs for one Shipment by Shipment Id.
      *
@@ -744,6 +1415,22 @@ impl Orders {
         &self,
         shipment_id: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipmentLog>> {
+        Ok(self
+            .get_shipment_logs_orders_with_response(shipment_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get logs for one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_shipment_logs_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipment_logs_orders_with_response(
+        &self,
+        shipment_id: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipmentLog>>> {
         let url = self.client.url(
             &format!(
                 "/shipment/{}/logs",
@@ -752,7 +1439,7 @@ impl Orders {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -789,6 +1476,75 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get logs for one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_shipment_logs_all_pages_stream(
+        &self,
+        shipment_id: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::OrdersShipmentLog>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/shipment/{}/logs",
+                crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrdersShipmentLog>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get logs for one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_shipment_logs_all_stream(
+        &self,
+        shipment_id: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipmentLog>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_shipment_logs_all_pages_stream(shipment_id, None))
+    }
     /**
      * Get shipping methods.
      *
@@ -806,6 +1562,23 @@ impl Orders {
         page: i64,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::OrdersShipMethodDetail>> {
+        Ok(self
+            .get_shipping_method_with_response(page, limit)
+            .await?
+            .body)
+    }
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_shipping_method`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shipping_method_with_response(
+        &self,
+        page: i64,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::OrdersShipMethodDetail>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("Limit".to_string(), limit.to_string()));
@@ -818,7 +1591,7 @@ impl Orders {
             .client
             .url(&format!("/shippingmethod?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -850,4 +1623,65 @@ impl Orders {
             )
             .await
     }
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_all_shipping_method`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_shipping_method_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::OrdersShipMethodDetail>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/shippingmethod", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::OrdersShipMethodDetail>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_all_shipping_method`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_shipping_method_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::OrdersShipMethodDetail>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_shipping_method_pages_stream(None))
+    }
 }
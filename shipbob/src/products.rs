@@ -46,6 +46,36 @@ impl Products {
         active_status: crate::types::ProductActiveStatus,
         bundle_status: crate::types::ProductBundleStatus,
     ) -> ClientResult<Vec<crate::types::Product>> {
+        Ok(self
+            .get_page_with_response(
+                page,
+                limit,
+                ids,
+                reference_ids,
+                search,
+                active_status,
+                bundle_status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !active_status.to_string().is_empty() {
             query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
@@ -71,7 +101,7 @@ impl Products {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/product?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -123,6 +153,99 @@ impl Products {
             )
             .await
     }
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Product>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !active_status.to_string().is_empty() {
+            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+        }
+        if !bundle_status.to_string().is_empty() {
+            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !search.is_empty() {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/product?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Product>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Product>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            ids,
+            reference_ids,
+            search,
+            active_status,
+            bundle_status,
+            None,
+        ))
+    }
     /**
      * Add a single product to the store.
      *
@@ -136,9 +259,22 @@ impl Products {
         &self,
         body: &crate::types::ProductsCreateProductModel,
     ) -> ClientResult<Vec<crate::types::Product>> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Add a single product to the store.
+     *
+     * This function performs a `POST` to the `/product` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::ProductsCreateProductModel,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
         let url = self.client.url("/product", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -158,6 +294,19 @@ impl Products {
      * * `channel_id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, product_id: i64) -> ClientResult<crate::types::Product> {
+        Ok(self.get_with_response(product_id).await?.body)
+    }
+    /**
+     * Get a single product.
+     *
+     * This function performs a `GET` to the `/product/{productId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        product_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Product>> {
         let url = self.client.url(
             &format!(
                 "/product/{}",
@@ -166,7 +315,7 @@ impl Products {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -190,6 +339,20 @@ impl Products {
         product_id: i64,
         body: &crate::types::ProductsUpdateProductModel,
     ) -> ClientResult<Vec<crate::types::Product>> {
+        Ok(self.put_with_response(product_id, body).await?.body)
+    }
+    /**
+     * Modify a single product.
+     *
+     * This function performs a `PUT` to the `/product/{productId}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        product_id: i64,
+        body: &crate::types::ProductsUpdateProductModel,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
         let url = self.client.url(
             &format!(
                 "/product/{}",
@@ -198,7 +361,7 @@ impl Products {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -220,9 +383,22 @@ impl Products {
         &self,
         body: &[crate::types::ProductsCreateProductModel],
     ) -> ClientResult<Vec<crate::types::Product>> {
+        Ok(self.post_batch_with_response(body).await?.body)
+    }
+    /**
+     * Add multiple products to the store.
+     *
+     * This function performs a `POST` to the `/product/batch` endpoint.
+     *
+     * As opposed to `post_batch`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_batch_with_response(
+        &self,
+        body: &[crate::types::ProductsCreateProductModel],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
         let url = self.client.url("/product/batch", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
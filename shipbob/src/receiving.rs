@@ -19,9 +19,21 @@ impl Receiving {
     pub async fn get_fulfillment_center(
         &self,
     ) -> ClientResult<Vec<crate::types::ReceivingFulfillmentCenter>> {
+        Ok(self.get_fulfillment_center_with_response().await?.body)
+    }
+    /**
+     * Get Fulfillment Centers.
+     *
+     * This function performs a `GET` to the `/fulfillmentCenter` endpoint.
+     *
+     * As opposed to `get_fulfillment_center`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_fulfillment_center_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReceivingFulfillmentCenter>>> {
         let url = self.client.url("/fulfillmentCenter", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,6 +63,67 @@ impl Receiving {
             )
             .await
     }
+    /**
+     * Get Fulfillment Centers.
+     *
+     * This function performs a `GET` to the `/fulfillmentCenter` endpoint.
+     *
+     * As opposed to `get_all_fulfillment_center`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_fulfillment_center_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ReceivingFulfillmentCenter>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/fulfillmentCenter", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ReceivingFulfillmentCenter>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get Fulfillment Centers.
+     *
+     * This function performs a `GET` to the `/fulfillmentCenter` endpoint.
+     *
+     * As opposed to `get_all_fulfillment_center`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_fulfillment_center_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReceivingFulfillmentCenter>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_fulfillment_center_pages_stream(None))
+    }
     /**
      * Get Warehouse Receiving Order.
      *
@@ -61,6 +134,19 @@ impl Receiving {
      * * `id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, id: i64) -> ClientResult<crate::types::ReceivingOrder> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * Get Warehouse Receiving Order.
+     *
+     * This function performs a `GET` to the `/receiving/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReceivingOrder>> {
         let url = self.client.url(
             &format!(
                 "/receiving/{}",
@@ -69,7 +155,7 @@ impl Receiving {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -88,6 +174,19 @@ impl Receiving {
      * * `id: i64` -- Unique id of the channel.
      */
     pub async fn get_label(&self, id: i64) -> ClientResult<bytes::Bytes> {
+        Ok(self.get_label_with_response(id).await?.body)
+    }
+    /**
+     * Get Warehouse Receiving Order Box Labels.
+     *
+     * This function performs a `GET` to the `/receiving/{id}/labels` endpoint.
+     *
+     * As opposed to `get_label`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_label_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<bytes::Bytes>> {
         let url = self.client.url(
             &format!(
                 "/receiving/{}/labels",
@@ -96,7 +195,7 @@ impl Receiving {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -114,9 +213,22 @@ impl Receiving {
         &self,
         body: &crate::types::ReceivingCreateOrderModel,
     ) -> ClientResult<crate::types::ReceivingOrder> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Create Warehouse Receiving Order.
+     *
+     * This function performs a `POST` to the `/receiving` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::ReceivingCreateOrderModel,
+    ) -> ClientResult<crate::Response<crate::types::ReceivingOrder>> {
         let url = self.client.url("/receiving", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -135,6 +247,17 @@ impl Receiving {
      * * `id: i64` -- Id of the receiving order to cancel.
      */
     pub async fn post_cancel(&self, id: i64) -> ClientResult<()> {
+        self.post_cancel_with_response(id).await?;
+        Ok(())
+    }
+    /**
+     * Cancel Warehouse Receiving Order.
+     *
+     * This function performs a `POST` to the `/receiving/{id}/cancel` endpoint.
+     *
+     * As opposed to `post_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_cancel_with_response(&self, id: i64) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/receiving/{}/cancel",
@@ -143,7 +266,7 @@ impl Receiving {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -22,6 +22,19 @@ impl Returns {
      * * `channel_id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, id: i64) -> ClientResult<crate::types::ReturnOrder> {
+        Ok(self.get_with_response(id).await?.body)
+    }
+    /**
+     * Get Return Order.
+     *
+     * This function performs a `GET` to the `/return/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
         let url = self.client.url(
             &format!(
                 "/return/{}",
@@ -30,7 +43,7 @@ impl Returns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -54,6 +67,20 @@ impl Returns {
         id: i64,
         body: &crate::types::ReturnsCreateReturn,
     ) -> ClientResult<crate::types::ReturnOrder> {
+        Ok(self.put_with_response(id, body).await?.body)
+    }
+    /**
+     * Modify Return Order.
+     *
+     * This function performs a `PUT` to the `/return/{id}` endpoint.
+     *
+     * As opposed to `put`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn put_with_response(
+        &self,
+        id: i64,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
         let url = self.client.url(
             &format!(
                 "/return/{}",
@@ -62,7 +89,7 @@ impl Returns {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -107,6 +134,46 @@ impl Returns {
         original_shipment_ids: &[String],
         inventory_ids: &[String],
     ) -> ClientResult<Vec<crate::types::ReturnOrder>> {
+        Ok(self
+            .get_page_with_response(
+                page,
+                limit,
+                sort_order,
+                start_date,
+                end_date,
+                ids,
+                reference_ids,
+                status,
+                fulfillment_center_ids,
+                tracking_numbers,
+                original_shipment_ids,
+                inventory_ids,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        page: i64,
+        limit: i64,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReturnOrder>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = end_date {
             query_args.push(("EndDate".to_string(), date.to_rfc3339()));
@@ -153,7 +220,7 @@ impl Returns {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/return?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -231,6 +298,136 @@ impl Returns {
             )
             .await
     }
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ReturnOrder>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        }
+        if !fulfillment_center_ids.is_empty() {
+            query_args.push((
+                "FulfillmentCenterIds".to_string(),
+                fulfillment_center_ids.join(" "),
+            ));
+        }
+        if !ids.is_empty() {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if !inventory_ids.is_empty() {
+            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+        }
+        if !original_shipment_ids.is_empty() {
+            query_args.push((
+                "OriginalShipmentIds".to_string(),
+                original_shipment_ids.join(" "),
+            ));
+        }
+        if !reference_ids.is_empty() {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        }
+        if !status.is_empty() {
+            query_args.push(("Status".to_string(), status.join(" ")));
+        }
+        if !tracking_numbers.is_empty() {
+            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/return?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::ReturnOrder>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReturnOrder>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            sort_order,
+            start_date,
+            end_date,
+            ids,
+            reference_ids,
+            status,
+            fulfillment_center_ids,
+            tracking_numbers,
+            original_shipment_ids,
+            inventory_ids,
+            None,
+        ))
+    }
     /**
      * Create Return Order.
      *
@@ -244,9 +441,22 @@ impl Returns {
         &self,
         body: &crate::types::ReturnsCreateReturn,
     ) -> ClientResult<crate::types::ReturnOrder> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Create Return Order.
+     *
+     * This function performs a `POST` to the `/return` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
         let url = self.client.url("/return", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -266,6 +476,19 @@ impl Returns {
      * * `channel_id: i64` -- Unique id of the channel.
      */
     pub async fn post_cancel(&self, id: i64) -> ClientResult<crate::types::ReturnOrder> {
+        Ok(self.post_cancel_with_response(id).await?.body)
+    }
+    /**
+     * Cancel Return Order.
+     *
+     * This function performs a `POST` to the `/return/{id}/cancel` endpoint.
+     *
+     * As opposed to `post_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_cancel_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrder>> {
         let url = self.client.url(
             &format!(
                 "/return/{}/cancel",
@@ -274,7 +497,7 @@ impl Returns {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -297,6 +520,19 @@ impl Returns {
         &self,
         id: i64,
     ) -> ClientResult<crate::types::ReturnOrderStatusHistory> {
+        Ok(self.get_status_history_with_response(id).await?.body)
+    }
+    /**
+     * Get One Return's status history.
+     *
+     * This function performs a `GET` to the `/return/{id}/statushistory` endpoint.
+     *
+     * As opposed to `get_status_history`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_status_history_with_response(
+        &self,
+        id: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReturnOrderStatusHistory>> {
         let url = self.client.url(
             &format!(
                 "/return/{}/statushistory",
@@ -305,7 +541,7 @@ impl Returns {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Channel {
     /**
-     * Name of the channel
+     * Name of the application that owns the channel
      */
     #[serde(
         default,
@@ -46,7 +46,7 @@ pub struct Channel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InventoryDimension {
     /**
-     * Information about an inventory item's dimensions
+     * Depth in inches of this inventory item
      */
     #[serde(
         default,
@@ -55,7 +55,7 @@ pub struct InventoryDimension {
     )]
     pub depth: f64,
     /**
-     * Information about an inventory item's dimensions
+     * Length in inches of this inventory item
      */
     #[serde(
         default,
@@ -64,7 +64,7 @@ pub struct InventoryDimension {
     )]
     pub length: f64,
     /**
-     * Information about an inventory item's dimensions
+     * Weight in ounces of this inventory item
      */
     #[serde(
         default,
@@ -73,7 +73,7 @@ pub struct InventoryDimension {
     )]
     pub weight: f64,
     /**
-     * Information about an inventory item's dimensions
+     * Width in inches of this inventory item
      */
     #[serde(
         default,
@@ -87,7 +87,7 @@ pub struct InventoryDimension {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InventoryFulfillmentCenterQuantity {
     /**
-     * Break down of fulfillable quantity by fulfillment center
+     * Amount of quantity awaiting arrival of a receiving order at this fulfillment center
      */
     #[serde(
         default,
@@ -96,7 +96,7 @@ pub struct InventoryFulfillmentCenterQuantity {
     )]
     pub awaiting_quantity: i64,
     /**
-     * Break down of fulfillable quantity by fulfillment center
+     * Amount of committed quantity at this fulfillment center
      */
     #[serde(
         default,
@@ -105,7 +105,7 @@ pub struct InventoryFulfillmentCenterQuantity {
     )]
     pub committed_quantity: i64,
     /**
-     * Break down of fulfillable quantity by fulfillment center
+     * Amount of fulfillable quantity at this fulfillment center
      */
     #[serde(
         default,
@@ -114,7 +114,7 @@ pub struct InventoryFulfillmentCenterQuantity {
     )]
     pub fulfillable_quantity: i64,
     /**
-     * Break down of fulfillable quantity by fulfillment center
+     * Unique id of the fulfillment center
      */
     #[serde(
         default,
@@ -123,7 +123,10 @@ pub struct InventoryFulfillmentCenterQuantity {
     )]
     pub id: i64,
     /**
-     * Break down of fulfillable quantity by fulfillment center
+     * The quantity of items that are in the process of internal transit
+     *  between ShipBob fulfillment centers, with a destination of this fulfillment center.
+     *  These items are not pickable or fulfillable until they have been received and moved
+     *  to the "On Hand" quantity of the destination FC.
      */
     #[serde(
         default,
@@ -132,7 +135,7 @@ pub struct InventoryFulfillmentCenterQuantity {
     )]
     pub internal_transfer_quantity: i64,
     /**
-     * Name of the channel
+     * Name of the fulfillment center
      */
     #[serde(
         default,
@@ -141,7 +144,7 @@ pub struct InventoryFulfillmentCenterQuantity {
     )]
     pub name: String,
     /**
-     * Break down of fulfillable quantity by fulfillment center
+     * Amount of onhand quantity at this fulfillment center
      */
     #[serde(
         default,
@@ -155,7 +158,7 @@ pub struct InventoryFulfillmentCenterQuantity {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InventoryLotQuantity {
     /**
-     * Break down of fulfillable quantity by lot
+     * Quantity of inventory items belonging to this lot awaiting arrival of a receiving order
      */
     #[serde(
         default,
@@ -164,7 +167,7 @@ pub struct InventoryLotQuantity {
     )]
     pub awaiting_quantity: i64,
     /**
-     * Break down of fulfillable quantity by lot
+     * Committed quantity of inventory items belonging to this lot
      */
     #[serde(
         default,
@@ -182,7 +185,7 @@ pub struct InventoryLotQuantity {
     )]
     pub expiration_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Break down of fulfillable quantity by lot
+     * Fulfillable quantity of inventory items belonging to this lot
      */
     #[serde(
         default,
@@ -191,7 +194,7 @@ pub struct InventoryLotQuantity {
     )]
     pub fulfillable_quantity: i64,
     /**
-     * Break down of fulfillable quantity by lot
+     * Fulfillable quantity of inventory items belonging to this lot broken down by fulfillment center location
      */
     #[serde(
         default,
@@ -200,7 +203,11 @@ pub struct InventoryLotQuantity {
     )]
     pub fulfillable_quantity_by_fulfillment_center: Vec<InventoryFulfillmentCenterQuantity>,
     /**
-     * Break down of fulfillable quantity by lot
+     * The quantity of all items belonging to this lot that are in the process of internal transit
+     *  between ShipBob fulfillment centers. These items are not pickable or fulfillable
+     *  until they have been received and moved to the "On Hand" quantity of the destination FC.
+     *  Internal transit quantities for each FC represent the incoming transfer stock
+     *  for that specific location.
      */
     #[serde(
         default,
@@ -209,7 +216,7 @@ pub struct InventoryLotQuantity {
     )]
     pub internal_transfer_quantity: i64,
     /**
-     * Name of the channel
+     * Identification number of this lot
      */
     #[serde(
         default,
@@ -218,7 +225,7 @@ pub struct InventoryLotQuantity {
     )]
     pub lot_number: String,
     /**
-     * Break down of fulfillable quantity by lot
+     * OnHand quantity of inventory items belonging to this lot
      */
     #[serde(
         default,
@@ -232,6 +239,7 @@ pub struct InventoryLotQuantity {
 * Attribute influencing the packaging requirements of this inventory item
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PackagingAttribute {
     #[serde(rename = "Book")]
     Book,
@@ -253,8 +261,9 @@ pub enum PackagingAttribute {
     Stackable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackagingAttribute {
@@ -270,12 +279,39 @@ impl std::fmt::Display for PackagingAttribute {
             PackagingAttribute::Poster => "Poster",
             PackagingAttribute::Stackable => "Stackable",
             PackagingAttribute::Noop => "",
-            PackagingAttribute::FallthroughString => "*",
+            PackagingAttribute::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PackagingAttribute {
+    fn from(s: String) -> PackagingAttribute {
+        match s.as_str() {
+            "Book" => PackagingAttribute::Book,
+            "CustomDunnage" => PackagingAttribute::CustomDunnage,
+            "CustomPackaging" => PackagingAttribute::CustomPackaging,
+            "Foldable" => PackagingAttribute::Foldable,
+            "Fragile" => PackagingAttribute::Fragile,
+            "MarketingInsert" => PackagingAttribute::MarketingInsert,
+            "None" => PackagingAttribute::None,
+            "Poster" => PackagingAttribute::Poster,
+            "Stackable" => PackagingAttribute::Stackable,
+            "" => PackagingAttribute::Noop,
+            _ => PackagingAttribute::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PackagingAttribute> for String {
+    fn from(e: PackagingAttribute) -> String {
+        match e {
+            PackagingAttribute::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PackagingAttribute {
     fn default() -> PackagingAttribute {
         PackagingAttribute::Noop
@@ -291,12 +327,12 @@ impl PackagingAttribute {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Inventory {
     /**
-     * Information about an inventory item
+     * Information about an inventory item's dimensions
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimensions: Option<InventoryDimension>,
     /**
-     * Information about an inventory item
+     * Fulfillable quantity of this inventory item broken down by fulfillment center location
      */
     #[serde(
         default,
@@ -305,7 +341,7 @@ pub struct Inventory {
     )]
     pub fulfillable_quantity_by_fulfillment_center: Vec<InventoryFulfillmentCenterQuantity>,
     /**
-     * Information about an inventory item
+     * Fulfillable quantity of this inventory item broken down by lot
      */
     #[serde(
         default,
@@ -314,7 +350,7 @@ pub struct Inventory {
     )]
     pub fulfillable_quantity_by_lot: Vec<InventoryLotQuantity>,
     /**
-     * Information about an inventory item
+     * Unique id of the inventory item
      */
     #[serde(
         default,
@@ -323,7 +359,7 @@ pub struct Inventory {
     )]
     pub id: i64,
     /**
-     * Information about an inventory item
+     * Whether the inventory is active or not
      */
     #[serde(
         default,
@@ -331,7 +367,7 @@ pub struct Inventory {
     )]
     pub is_active: bool,
     /**
-     * Information about an inventory item
+     * True if the inventory item is marked as case pick
      */
     #[serde(
         default,
@@ -339,7 +375,7 @@ pub struct Inventory {
     )]
     pub is_case_pick: bool,
     /**
-     * Information about an inventory item
+     * True if the inventory item is marked as a digital item
      */
     #[serde(
         default,
@@ -347,7 +383,7 @@ pub struct Inventory {
     )]
     pub is_digital: bool,
     /**
-     * Information about an inventory item
+     * True if this inventory item is organized into lots
      */
     #[serde(
         default,
@@ -355,7 +391,7 @@ pub struct Inventory {
     )]
     pub is_lot: bool,
     /**
-     * Name of the channel
+     * Name of the inventory item
      */
     #[serde(
         default,
@@ -364,12 +400,12 @@ pub struct Inventory {
     )]
     pub name: String,
     /**
-     * Information about an inventory item
+     * Attribute influencing the packaging requirements of this inventory item
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub packaging_attribute: Option<PackagingAttribute>,
     /**
-     * Information about an inventory item
+     * Total quantity in unreceived receiving orders for this inventory item
      */
     #[serde(
         default,
@@ -378,7 +414,8 @@ pub struct Inventory {
     )]
     pub total_awaiting_quantity: i64,
     /**
-     * Information about an inventory item
+     * The amount of the item you need to send to ShipBob to fulfill all exception orders containing
+     *  the item. This is the exception_quantity less the fulfillable_quantity of the item.
      */
     #[serde(
         default,
@@ -387,7 +424,7 @@ pub struct Inventory {
     )]
     pub total_backordered_quantity: i64,
     /**
-     * Information about an inventory item
+     * Total committed quantity of this inventory item
      */
     #[serde(
         default,
@@ -396,7 +433,9 @@ pub struct Inventory {
     )]
     pub total_committed_quantity: i64,
     /**
-     * Information about an inventory item
+     * The total quantity of all items that are contained within orders that
+     *  are in exception/out of stock status. Out of stock orders have not been
+     *  processed and therefore do not have lot or fulfillment centers assigned.
      */
     #[serde(
         default,
@@ -405,7 +444,7 @@ pub struct Inventory {
     )]
     pub total_exception_quantity: i64,
     /**
-     * Information about an inventory item
+     * Total fulfillable quantity of this inventory item
      */
     #[serde(
         default,
@@ -414,7 +453,11 @@ pub struct Inventory {
     )]
     pub total_fulfillable_quantity: i64,
     /**
-     * Information about an inventory item
+     * The total quantity of all items that are in the process of internal transit
+     *  between ShipBob fulfillment centers. These items are not pickable or fulfillable
+     *  until they have been received and moved to the "On Hand" quantity of the destination FC.
+     *  Internal transit quantities for each FC represent the incoming transfer stock
+     *  for that specific location.
      */
     #[serde(
         default,
@@ -423,7 +466,7 @@ pub struct Inventory {
     )]
     pub total_internal_transfer_quantity: i64,
     /**
-     * Information about an inventory item
+     * Total onhand quantity of this inventory item
      */
     #[serde(
         default,
@@ -432,7 +475,9 @@ pub struct Inventory {
     )]
     pub total_onhand_quantity: i64,
     /**
-     * Information about an inventory item
+     * Total quantity that can be sold without overselling the inventory item.
+     *  This is calculated by subtracting the total exception quantity from the
+     *  fulfillable quantity.
      */
     #[serde(
         default,
@@ -445,7 +490,7 @@ pub struct Inventory {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersEstimationAddress {
     /**
-     * Name of the channel
+     * First line of the address
      */
     #[serde(
         default,
@@ -455,7 +500,7 @@ pub struct OrdersEstimationAddress {
     )]
     pub address_1: String,
     /**
-     * Name of the channel
+     * Second line of the address
      */
     #[serde(
         default,
@@ -465,7 +510,7 @@ pub struct OrdersEstimationAddress {
     )]
     pub address_2: String,
     /**
-     * Name of the channel
+     * The city
      */
     #[serde(
         default,
@@ -474,7 +519,7 @@ pub struct OrdersEstimationAddress {
     )]
     pub city: String,
     /**
-     * Name of the channel
+     * Name of the company receiving the shipment
      */
     #[serde(
         default,
@@ -483,7 +528,7 @@ pub struct OrdersEstimationAddress {
     )]
     pub company_name: String,
     /**
-     * Name of the channel
+     * The country (Must be ISO Alpha-2 for estimates)
      */
     #[serde(
         default,
@@ -492,7 +537,7 @@ pub struct OrdersEstimationAddress {
     )]
     pub country: String,
     /**
-     * Name of the channel
+     * The state or province
      */
     #[serde(
         default,
@@ -501,7 +546,7 @@ pub struct OrdersEstimationAddress {
     )]
     pub state: String,
     /**
-     * Name of the channel
+     * The zip code or postal code
      */
     #[serde(
         default,
@@ -514,7 +559,7 @@ pub struct OrdersEstimationAddress {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersEstimateProductInfoModel {
     /**
-     * Unique id of the channel
+     * Unique id of the product (Must be provided if reference_id is unknown)
      */
     #[serde(
         default,
@@ -523,7 +568,7 @@ pub struct OrdersEstimateProductInfoModel {
     )]
     pub id: i64,
     /**
-     * Unique id of the channel
+     * The quantity of this product ordered
      */
     #[serde(
         default,
@@ -532,7 +577,7 @@ pub struct OrdersEstimateProductInfoModel {
     )]
     pub quantity: i64,
     /**
-     * Name of the channel
+     * Unique reference id of the product (Must be provided if ID is unknown)
      */
     #[serde(
         default,
@@ -556,7 +601,9 @@ pub struct OrdersEstimateFulfillmentRequestModel {
     )]
     pub products: Vec<OrdersEstimateProductInfoModel>,
     /**
-     * Array of permissions granted for the channel
+     * Array of strings specifying shipping methods for which to fetch estimates.
+     *  
+     *  If this field is omitted we will return estimates for all shipping methods defined in ShipBob
      */
     #[serde(
         default,
@@ -570,7 +617,7 @@ pub struct OrdersEstimateFulfillmentRequestModel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersFulfillmentCenter {
     /**
-     * Information about a fulfillment center that a shipment can belong to
+     * Id of the fulfillment center
      */
     #[serde(
         default,
@@ -579,7 +626,7 @@ pub struct OrdersFulfillmentCenter {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the fulfillment center
      */
     #[serde(
         default,
@@ -592,7 +639,7 @@ pub struct OrdersFulfillmentCenter {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersEstimateDetail {
     /**
-     * Weight in ounces of this inventory item
+     * Estimated price in dollars for the provided shipping method
      */
     #[serde(
         default,
@@ -606,7 +653,7 @@ pub struct OrdersEstimateDetail {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fulfillment_center: Option<OrdersFulfillmentCenter>,
     /**
-     * Name of the channel
+     * Provided shipping method. Maps to ship option in ShipBob.
      */
     #[serde(
         default,
@@ -633,7 +680,7 @@ pub struct OrdersEstimate {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersChannelInfo {
     /**
-     * Created by channel metadata
+     * Unique id of the channel
      */
     #[serde(
         default,
@@ -658,6 +705,7 @@ pub struct OrdersChannelInfo {
 *   MarkFor
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "MarkFor")]
     MarkFor,
@@ -665,8 +713,9 @@ pub enum Type {
     ShipFrom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -675,12 +724,32 @@ impl std::fmt::Display for Type {
             Type::MarkFor => "MarkFor",
             Type::ShipFrom => "ShipFrom",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "MarkFor" => Type::MarkFor,
+            "ShipFrom" => Type::ShipFrom,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -696,7 +765,7 @@ impl Type {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersRetailerProgramDataAddress {
     /**
-     * Name of the channel
+     * First line of the address
      */
     #[serde(
         default,
@@ -706,7 +775,7 @@ pub struct OrdersRetailerProgramDataAddress {
     )]
     pub address_1: String,
     /**
-     * Name of the channel
+     * Second line of the address
      */
     #[serde(
         default,
@@ -716,7 +785,7 @@ pub struct OrdersRetailerProgramDataAddress {
     )]
     pub address_2: String,
     /**
-     * Name of the channel
+     * The city
      */
     #[serde(
         default,
@@ -725,7 +794,7 @@ pub struct OrdersRetailerProgramDataAddress {
     )]
     pub city: String,
     /**
-     * Name of the channel
+     * Name of the company receiving the shipment
      */
     #[serde(
         default,
@@ -734,7 +803,7 @@ pub struct OrdersRetailerProgramDataAddress {
     )]
     pub company_name: String,
     /**
-     * Name of the channel
+     * The country (Must be ISO Alpha-2 for estimates)
      */
     #[serde(
         default,
@@ -743,7 +812,7 @@ pub struct OrdersRetailerProgramDataAddress {
     )]
     pub country: String,
     /**
-     * Name of the channel
+     * The state or province
      */
     #[serde(
         default,
@@ -759,7 +828,7 @@ pub struct OrdersRetailerProgramDataAddress {
     #[serde(default, skip_serializing_if = "Type::is_noop", rename = "type")]
     pub type_: Type,
     /**
-     * Name of the channel
+     * The zip code or postal code
      */
     #[serde(
         default,
@@ -778,7 +847,7 @@ pub struct OrdersRecipientInfo {
     #[serde()]
     pub address: OrdersRetailerProgramDataAddress,
     /**
-     * Name of the channel
+     * Email address of the recipient
      */
     #[serde(
         default,
@@ -787,7 +856,7 @@ pub struct OrdersRecipientInfo {
     )]
     pub email: String,
     /**
-     * Name of the channel
+     * Name of the recipient
      */
     #[serde(
         default,
@@ -796,7 +865,7 @@ pub struct OrdersRecipientInfo {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * Phone number of the recipient
      */
     #[serde(
         default,
@@ -809,7 +878,7 @@ pub struct OrdersRecipientInfo {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersProductInfo {
     /**
-     * Unique id of the channel
+     * Unique id of the product
      */
     #[serde(
         default,
@@ -818,7 +887,7 @@ pub struct OrdersProductInfo {
     )]
     pub id: i64,
     /**
-     * Unique id of the channel
+     * The quantity of this product ordered
      */
     #[serde(
         default,
@@ -827,7 +896,7 @@ pub struct OrdersProductInfo {
     )]
     pub quantity: i64,
     /**
-     * Name of the channel
+     * Unique reference id of the product
      */
     #[serde(
         default,
@@ -836,7 +905,7 @@ pub struct OrdersProductInfo {
     )]
     pub reference_id: String,
     /**
-     * Name of the channel
+     * Stock keeping unit for the product
      */
     #[serde(
         default,
@@ -849,7 +918,7 @@ pub struct OrdersProductInfo {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersTag {
     /**
-     * Name of the channel
+     * The key of the tag
      */
     #[serde(
         default,
@@ -858,7 +927,7 @@ pub struct OrdersTag {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * The value of the tag
      */
     #[serde(
         default,
@@ -872,12 +941,12 @@ pub struct OrdersTag {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersRecipient {
     /**
-     * Information about the recipient of a shipment
+     * Address to used when creating a B2B/DropShip order.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub address: Option<OrdersRetailerProgramDataAddress>,
     /**
-     * Name of the channel
+     * Email address of the recipient
      */
     #[serde(
         default,
@@ -886,7 +955,7 @@ pub struct OrdersRecipient {
     )]
     pub email: String,
     /**
-     * Name of the channel
+     * Name of the recipient
      */
     #[serde(
         default,
@@ -895,7 +964,7 @@ pub struct OrdersRecipient {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * Phone number of the recipient
      */
     #[serde(
         default,
@@ -908,7 +977,7 @@ pub struct OrdersRecipient {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersStatusDetail {
     /**
-     * Name of the channel
+     * Describes what the status detail means
      */
     #[serde(
         default,
@@ -916,9 +985,6 @@ pub struct OrdersStatusDetail {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub description: String,
-    /**
-     * Unique id of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -926,7 +992,7 @@ pub struct OrdersStatusDetail {
     )]
     pub exception_fulfillment_center_id: i64,
     /**
-     * Unique id of the channel
+     * The id of the status detail
      */
     #[serde(
         default,
@@ -935,7 +1001,7 @@ pub struct OrdersStatusDetail {
     )]
     pub id: i64,
     /**
-     * Unique id of the channel
+     * Inventory Id the detail applies to (if applicable)
      */
     #[serde(
         default,
@@ -944,7 +1010,7 @@ pub struct OrdersStatusDetail {
     )]
     pub inventory_id: i64,
     /**
-     * Name of the channel
+     * Short name of the status detail
      */
     #[serde(
         default,
@@ -958,7 +1024,7 @@ pub struct OrdersStatusDetail {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersTracking {
     /**
-     * Name of the channel
+     * Carrier of the shipment
      */
     #[serde(
         default,
@@ -967,7 +1033,7 @@ pub struct OrdersTracking {
     )]
     pub carrier: String,
     /**
-     * Name of the channel
+     * The carrier's service which was used for this shipment
      */
     #[serde(
         default,
@@ -976,7 +1042,7 @@ pub struct OrdersTracking {
     )]
     pub carrier_service: String,
     /**
-     * Name of the channel
+     * Tracking number of the shipment
      */
     #[serde(
         default,
@@ -985,7 +1051,7 @@ pub struct OrdersTracking {
     )]
     pub tracking_number: String,
     /**
-     * Name of the channel
+     * URL to the website where a shipment can be tracked
      */
     #[serde(
         default,
@@ -999,7 +1065,7 @@ pub struct OrdersTracking {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersInventory {
     /**
-     * Expiration date for this lot
+     * Expiration date of the inventory
      */
     #[serde(
         default,
@@ -1008,7 +1074,7 @@ pub struct OrdersInventory {
     )]
     pub expiration_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Information about inventory belonging to a store product
+     * Unique id of the inventory
      */
     #[serde(
         default,
@@ -1017,7 +1083,7 @@ pub struct OrdersInventory {
     )]
     pub id: i64,
     /**
-     * Information about inventory belonging to a store product
+     * Is inventory Dangerous Good
      */
     #[serde(
         default,
@@ -1025,7 +1091,7 @@ pub struct OrdersInventory {
     )]
     pub is_dangerous_goods: bool,
     /**
-     * Name of the channel
+     * Lot number of the inventory
      */
     #[serde(
         default,
@@ -1034,7 +1100,7 @@ pub struct OrdersInventory {
     )]
     pub lot: String,
     /**
-     * Name of the channel
+     * Name of the inventory item
      */
     #[serde(
         default,
@@ -1043,7 +1109,7 @@ pub struct OrdersInventory {
     )]
     pub name: String,
     /**
-     * Information about inventory belonging to a store product
+     * Quantity of the inventory item to be included in the fulfillment
      */
     #[serde(
         default,
@@ -1052,7 +1118,7 @@ pub struct OrdersInventory {
     )]
     pub quantity: i64,
     /**
-     * Information about inventory belonging to a store product
+     * The quantity of the inventory item allocated from the assigned fulfillment center and committed to the order. If quantity committed is less than order quantity, then the inventory item is out of stock at the assigned fulfillment center.
      */
     #[serde(
         default,
@@ -1061,7 +1127,7 @@ pub struct OrdersInventory {
     )]
     pub quantity_committed: i64,
     /**
-     * Array of permissions granted for the channel
+     * Serial number of the inventory
      */
     #[serde(
         default,
@@ -1075,7 +1141,7 @@ pub struct OrdersInventory {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersShipmentProduct {
     /**
-     * Information about a store product belonging to a shipment
+     * Unique id of the product
      */
     #[serde(
         default,
@@ -1084,7 +1150,7 @@ pub struct OrdersShipmentProduct {
     )]
     pub id: i64,
     /**
-     * Information about a store product belonging to a shipment
+     * Information about fulfillable inventory items belonging to this product
      */
     #[serde(
         default,
@@ -1093,7 +1159,7 @@ pub struct OrdersShipmentProduct {
     )]
     pub inventory_items: Vec<OrdersInventory>,
     /**
-     * Name of the channel
+     * Name of the product
      */
     #[serde(
         default,
@@ -1102,7 +1168,7 @@ pub struct OrdersShipmentProduct {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * Unique reference id of the product
      */
     #[serde(
         default,
@@ -1111,7 +1177,7 @@ pub struct OrdersShipmentProduct {
     )]
     pub reference_id: String,
     /**
-     * Name of the channel
+     * Stock keeping unit for the product
      */
     #[serde(
         default,
@@ -1125,7 +1191,7 @@ pub struct OrdersShipmentProduct {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersMeasurements {
     /**
-     * Measurements of a shipment
+     * Total depth in inches
      */
     #[serde(
         default,
@@ -1134,7 +1200,7 @@ pub struct OrdersMeasurements {
     )]
     pub depth_in: i64,
     /**
-     * Measurements of a shipment
+     * Total length in inches
      */
     #[serde(
         default,
@@ -1143,7 +1209,7 @@ pub struct OrdersMeasurements {
     )]
     pub length_in: i64,
     /**
-     * Measurements of a shipment
+     * Total weight in ounces
      */
     #[serde(
         default,
@@ -1152,7 +1218,7 @@ pub struct OrdersMeasurements {
     )]
     pub total_weight_oz: i64,
     /**
-     * Measurements of a shipment
+     * Total width in inches
      */
     #[serde(
         default,
@@ -1166,6 +1232,7 @@ pub struct OrdersMeasurements {
 * The shipment status
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1189,8 +1256,9 @@ pub enum Status {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -1207,12 +1275,40 @@ impl std::fmt::Display for Status {
             Status::Pending => "Pending",
             Status::Processing => "Processing",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "Cancelled" => Status::Cancelled,
+            "CleanSweeped" => Status::CleanSweeped,
+            "Completed" => Status::Completed,
+            "Exception" => Status::Exception,
+            "ImportReview" => Status::ImportReview,
+            "LabeledCreated" => Status::LabeledCreated,
+            "None" => Status::None,
+            "OnHold" => Status::OnHold,
+            "Pending" => Status::Pending,
+            "Processing" => Status::Processing,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -1228,6 +1324,7 @@ impl Status {
 * Container type for the shipment
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PackageMaterialType {
     #[serde(rename = "Bookfold")]
     Bookfold,
@@ -1247,12 +1344,11 @@ pub enum PackageMaterialType {
     PosterTube,
     #[serde(rename = "Undefined")]
     Undefined,
-    #[serde(rename = "Unknown")]
-    Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PackageMaterialType {
@@ -1267,14 +1363,40 @@ impl std::fmt::Display for PackageMaterialType {
             PackageMaterialType::PolyMailer => "PolyMailer",
             PackageMaterialType::PosterTube => "PosterTube",
             PackageMaterialType::Undefined => "Undefined",
-            PackageMaterialType::Unknown => "Unknown",
             PackageMaterialType::Noop => "",
-            PackageMaterialType::FallthroughString => "*",
+            PackageMaterialType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PackageMaterialType {
+    fn from(s: String) -> PackageMaterialType {
+        match s.as_str() {
+            "Bookfold" => PackageMaterialType::Bookfold,
+            "Box" => PackageMaterialType::Box,
+            "BubbleMailer" => PackageMaterialType::BubbleMailer,
+            "Custom" => PackageMaterialType::Custom,
+            "FragileBox" => PackageMaterialType::FragileBox,
+            "OwnContainer" => PackageMaterialType::OwnContainer,
+            "PolyMailer" => PackageMaterialType::PolyMailer,
+            "PosterTube" => PackageMaterialType::PosterTube,
+            "Undefined" => PackageMaterialType::Undefined,
+            "" => PackageMaterialType::Noop,
+            _ => PackageMaterialType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PackageMaterialType> for String {
+    fn from(e: PackageMaterialType) -> String {
+        match e {
+            PackageMaterialType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PackageMaterialType {
     fn default() -> PackageMaterialType {
         PackageMaterialType::Noop
@@ -1290,6 +1412,7 @@ impl PackageMaterialType {
 * Status of ShipBob’s completion of the fulfillment operation.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EstimatedFulfillmentDateStatus {
     #[serde(rename = "AwaitingInventoryAllocation")]
     AwaitingInventoryAllocation,
@@ -1307,8 +1430,9 @@ pub enum EstimatedFulfillmentDateStatus {
     Unavailable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EstimatedFulfillmentDateStatus {
@@ -1324,12 +1448,39 @@ impl std::fmt::Display for EstimatedFulfillmentDateStatus {
             EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
             EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
             EstimatedFulfillmentDateStatus::Noop => "",
-            EstimatedFulfillmentDateStatus::FallthroughString => "*",
+            EstimatedFulfillmentDateStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EstimatedFulfillmentDateStatus {
+    fn from(s: String) -> EstimatedFulfillmentDateStatus {
+        match s.as_str() {
+            "AwaitingInventoryAllocation" => {
+                EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation
+            }
+            "AwaitingReset" => EstimatedFulfillmentDateStatus::AwaitingReset,
+            "FulfilledLate" => EstimatedFulfillmentDateStatus::FulfilledLate,
+            "FulfilledOnTime" => EstimatedFulfillmentDateStatus::FulfilledOnTime,
+            "PendingLate" => EstimatedFulfillmentDateStatus::PendingLate,
+            "PendingOnTime" => EstimatedFulfillmentDateStatus::PendingOnTime,
+            "Unavailable" => EstimatedFulfillmentDateStatus::Unavailable,
+            "" => EstimatedFulfillmentDateStatus::Noop,
+            _ => EstimatedFulfillmentDateStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EstimatedFulfillmentDateStatus> for String {
+    fn from(e: EstimatedFulfillmentDateStatus) -> String {
+        match e {
+            EstimatedFulfillmentDateStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EstimatedFulfillmentDateStatus {
     fn default() -> EstimatedFulfillmentDateStatus {
         EstimatedFulfillmentDateStatus::Noop
@@ -1345,7 +1496,8 @@ impl EstimatedFulfillmentDateStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersShipment {
     /**
-     * Expiration date for this lot
+     * The datetime of ShipBob’s completion of the fulfillment operation as promised.
+     *  Currently, this means the shipment has been picked, packed, and label has been printed.
      */
     #[serde(
         default,
@@ -1354,7 +1506,7 @@ pub struct OrdersShipment {
     )]
     pub actual_fulfillment_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Expiration date for this lot
+     * Date this shipment was created
      */
     #[serde(
         default,
@@ -1363,7 +1515,8 @@ pub struct OrdersShipment {
     )]
     pub created_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Expiration date for this lot
+     * The datetime of ShipBob’s commitment for completing
+     *  the shipment and handing to the carrier for delivery.
      */
     #[serde(
         default,
@@ -1372,12 +1525,12 @@ pub struct OrdersShipment {
     )]
     pub estimated_fulfillment_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Information about a shipment
+     * Status of ShipBob’s completion of the fulfillment operation.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub estimated_fulfillment_date_status: Option<EstimatedFulfillmentDateStatus>,
     /**
-     * Name of the channel
+     * Gift message to be included with order
      */
     #[serde(
         default,
@@ -1386,7 +1539,7 @@ pub struct OrdersShipment {
     )]
     pub gift_message: String,
     /**
-     * Information about a shipment
+     * Unique id of the shipment
      */
     #[serde(
         default,
@@ -1395,7 +1548,7 @@ pub struct OrdersShipment {
     )]
     pub id: i64,
     /**
-     * Information about a shipment
+     * Monetary amount that this shipment was insured for
      */
     #[serde(
         default,
@@ -1404,7 +1557,7 @@ pub struct OrdersShipment {
     )]
     pub insurance_value: f64,
     /**
-     * Information about a shipment
+     * Monetary amount that was invoiced for this shipment
      */
     #[serde(
         default,
@@ -1413,7 +1566,8 @@ pub struct OrdersShipment {
     )]
     pub invoice_amount: f64,
     /**
-     * Information about a shipment
+     * Indicates whether the Shipment was marked with tracking information
+     *  uploaded to a third-party system where the order originated.
      */
     #[serde(
         default,
@@ -1421,7 +1575,7 @@ pub struct OrdersShipment {
     )]
     pub is_tracking_uploaded: bool,
     /**
-     * Expiration date for this lot
+     * Date this shipment was last updated
      */
     #[serde(
         default,
@@ -1430,17 +1584,17 @@ pub struct OrdersShipment {
     )]
     pub last_update_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Information about a shipment
+     * Information about a fulfillment center that a shipment can belong to
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location: Option<OrdersFulfillmentCenter>,
     /**
-     * Information about a shipment
+     * Measurements of a shipment
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub measurements: Option<OrdersMeasurements>,
     /**
-     * Information about a shipment
+     * Id of the order this shipment belongs to
      */
     #[serde(
         default,
@@ -1449,12 +1603,12 @@ pub struct OrdersShipment {
     )]
     pub order_id: i64,
     /**
-     * Information about a shipment
+     * Container type for the shipment
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub package_material_type: Option<PackageMaterialType>,
     /**
-     * Information about a shipment
+     * Information about the products contained in this shipment
      */
     #[serde(
         default,
@@ -1463,12 +1617,12 @@ pub struct OrdersShipment {
     )]
     pub products: Vec<OrdersShipmentProduct>,
     /**
-     * Information about a shipment
+     * Information about the recipient of a shipment
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recipient: Option<OrdersRecipient>,
     /**
-     * Name of the channel
+     * Client-defined external unique id of the order this shipment belongs to
      */
     #[serde(
         default,
@@ -1477,7 +1631,7 @@ pub struct OrdersShipment {
     )]
     pub reference_id: String,
     /**
-     * Information about a shipment
+     * If a shipment requires signature
      */
     #[serde(
         default,
@@ -1485,7 +1639,7 @@ pub struct OrdersShipment {
     )]
     pub require_signature: bool,
     /**
-     * Name of the channel
+     * Name of the shipping option used for this shipment
      */
     #[serde(
         default,
@@ -1494,12 +1648,12 @@ pub struct OrdersShipment {
     )]
     pub ship_option: String,
     /**
-     * Information about a shipment
+     * The shipment status
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
     /**
-     * Information about a shipment
+     * Additional details about the shipment status
      */
     #[serde(
         default,
@@ -1508,7 +1662,7 @@ pub struct OrdersShipment {
     )]
     pub status_details: Vec<OrdersStatusDetail>,
     /**
-     * Information about a shipment
+     * Tracking information for a shipment
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tracking: Option<OrdersTracking>,
@@ -1522,6 +1676,7 @@ pub struct OrdersShipment {
 *   Freight: Larger boxes, usually transported by truckload.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CarrierType {
     #[serde(rename = "Freight")]
     Freight,
@@ -1529,8 +1684,9 @@ pub enum CarrierType {
     Parcel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CarrierType {
@@ -1539,12 +1695,32 @@ impl std::fmt::Display for CarrierType {
             CarrierType::Freight => "Freight",
             CarrierType::Parcel => "Parcel",
             CarrierType::Noop => "",
-            CarrierType::FallthroughString => "*",
+            CarrierType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CarrierType {
+    fn from(s: String) -> CarrierType {
+        match s.as_str() {
+            "Freight" => CarrierType::Freight,
+            "Parcel" => CarrierType::Parcel,
+            "" => CarrierType::Noop,
+            _ => CarrierType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CarrierType> for String {
+    fn from(e: CarrierType) -> String {
+        match e {
+            CarrierType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CarrierType {
     fn default() -> CarrierType {
         CarrierType::Noop
@@ -1566,6 +1742,7 @@ impl CarrierType {
 *   Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PaymentTerm {
     #[serde(rename = "Collect")]
     Collect,
@@ -1575,8 +1752,9 @@ pub enum PaymentTerm {
     ThirdParty,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentTerm {
@@ -1586,12 +1764,33 @@ impl std::fmt::Display for PaymentTerm {
             PaymentTerm::Prepaid => "Prepaid",
             PaymentTerm::ThirdParty => "ThirdParty",
             PaymentTerm::Noop => "",
-            PaymentTerm::FallthroughString => "*",
+            PaymentTerm::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PaymentTerm {
+    fn from(s: String) -> PaymentTerm {
+        match s.as_str() {
+            "Collect" => PaymentTerm::Collect,
+            "Prepaid" => PaymentTerm::Prepaid,
+            "ThirdParty" => PaymentTerm::ThirdParty,
+            "" => PaymentTerm::Noop,
+            _ => PaymentTerm::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PaymentTerm> for String {
+    fn from(e: PaymentTerm) -> String {
+        match e {
+            PaymentTerm::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PaymentTerm {
     fn default() -> PaymentTerm {
         PaymentTerm::Noop
@@ -1632,7 +1831,13 @@ pub struct OrdersShippingTerms {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersRetailerProgramData {
     /**
-     * Contains properties that needs to be used for fulfilling B2B/Dropship orders.
+     * Ship From - Certain retailers want to display the ship from address as their return facility, not Shipbob’s warehouse address        ///
+     *  Mark For Address - Final destination address
+     *  <example>
+     *  Merchant will ship to Retailer Hub.Then Retailer will ship to final destination.
+     *  Mark for address: Retailer Store #101
+     *  Atlanta, GA
+     *  </example>
      */
     #[serde(
         default,
@@ -1641,7 +1846,7 @@ pub struct OrdersRetailerProgramData {
     )]
     pub addresses: Vec<OrdersRetailerProgramDataAddress>,
     /**
-     * Expiration date for this lot
+     * Expected delivery date
      */
     #[serde(
         default,
@@ -1650,7 +1855,10 @@ pub struct OrdersRetailerProgramData {
     )]
     pub delivery_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Store Number
+     *  <example>
+     *  TGT105
+     *  </example>
      */
     #[serde(
         default,
@@ -1659,7 +1867,7 @@ pub struct OrdersRetailerProgramData {
     )]
     pub mark_for_store: String,
     /**
-     * Name of the channel
+     * First initial documentation sent from buyer to seller with item(s) and quantities.
      */
     #[serde(
         default,
@@ -1668,7 +1876,10 @@ pub struct OrdersRetailerProgramData {
     )]
     pub purchase_order_number: String,
     /**
-     * Name of the channel
+     * Identifies retailer-merchant combination
+     *  <example>
+     *  TGTDS
+     *  </example>
      */
     #[serde(
         default,
@@ -1682,6 +1893,7 @@ pub struct OrdersRetailerProgramData {
 * The order status
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrderStatus {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1697,8 +1909,9 @@ pub enum OrderStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderStatus {
@@ -1711,12 +1924,36 @@ impl std::fmt::Display for OrderStatus {
             OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
             OrderStatus::Processing => "Processing",
             OrderStatus::Noop => "",
-            OrderStatus::FallthroughString => "*",
+            OrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrderStatus {
+    fn from(s: String) -> OrderStatus {
+        match s.as_str() {
+            "Cancelled" => OrderStatus::Cancelled,
+            "Exception" => OrderStatus::Exception,
+            "Fulfilled" => OrderStatus::Fulfilled,
+            "ImportReview" => OrderStatus::ImportReview,
+            "PartiallyFulfilled" => OrderStatus::PartiallyFulfilled,
+            "Processing" => OrderStatus::Processing,
+            "" => OrderStatus::Noop,
+            _ => OrderStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrderStatus> for String {
+    fn from(e: OrderStatus) -> String {
+        match e {
+            OrderStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrderStatus {
     fn default() -> OrderStatus {
         OrderStatus::Noop
@@ -1732,6 +1969,7 @@ impl OrderStatus {
 * Shipment type of the order
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrderType {
     #[serde(rename = "B2B")]
     B2B,
@@ -1741,8 +1979,9 @@ pub enum OrderType {
     DropShip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrderType {
@@ -1752,12 +1991,33 @@ impl std::fmt::Display for OrderType {
             OrderType::Dtc => "DTC",
             OrderType::DropShip => "DropShip",
             OrderType::Noop => "",
-            OrderType::FallthroughString => "*",
+            OrderType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrderType {
+    fn from(s: String) -> OrderType {
+        match s.as_str() {
+            "B2B" => OrderType::B2B,
+            "DTC" => OrderType::Dtc,
+            "DropShip" => OrderType::DropShip,
+            "" => OrderType::Noop,
+            _ => OrderType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrderType> for String {
+    fn from(e: OrderType) -> String {
+        match e {
+            OrderType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrderType {
     fn default() -> OrderType {
         OrderType::Noop
@@ -1777,7 +2037,7 @@ pub struct Order {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub channel: Option<OrdersChannelInfo>,
     /**
-     * Expiration date for this lot
+     * Date this order was created
      */
     #[serde(
         default,
@@ -1786,7 +2046,7 @@ pub struct Order {
     )]
     pub created_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Gift Message affiliated with the order
      */
     #[serde(
         default,
@@ -1795,7 +2055,7 @@ pub struct Order {
     )]
     pub gift_message: String,
     /**
-     * Unique id of the channel
+     * Unique id of the order
      */
     #[serde(
         default,
@@ -1804,7 +2064,7 @@ pub struct Order {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * User friendly orderId or store order number that will be shown on the Orders Page. If not provided, referenceId will be used
      */
     #[serde(
         default,
@@ -1822,7 +2082,7 @@ pub struct Order {
     )]
     pub products: Vec<OrdersProductInfo>,
     /**
-     * Expiration date for this lot
+     * Date this order was purchase by the end user
      */
     #[serde(
         default,
@@ -1836,7 +2096,7 @@ pub struct Order {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recipient: Option<OrdersRecipientInfo>,
     /**
-     * Name of the channel
+     * Client-defined external unique id of the order
      */
     #[serde(
         default,
@@ -1859,7 +2119,7 @@ pub struct Order {
     )]
     pub shipments: Vec<OrdersShipment>,
     /**
-     * Name of the channel
+     * Client-defined shipping method
      */
     #[serde(
         default,
@@ -1896,7 +2156,7 @@ pub struct Order {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersAddProductOrderByModel {
     /**
-     * Unique id of the channel
+     * Unique id of the product
      */
     #[serde(
         default,
@@ -1905,7 +2165,7 @@ pub struct OrdersAddProductOrderByModel {
     )]
     pub id: i64,
     /**
-     * Unique id of the channel
+     * The quantity of this product ordered
      */
     #[serde(
         default,
@@ -1918,7 +2178,7 @@ pub struct OrdersAddProductOrderByModel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersAddProductOrderByReferenceModel {
     /**
-     * Name of the channel
+     * Name of the product. Required if there is not an existing ShipBob product with a matching reference_id value.
      */
     #[serde(
         default,
@@ -1927,7 +2187,7 @@ pub struct OrdersAddProductOrderByReferenceModel {
     )]
     pub name: String,
     /**
-     * Unique id of the channel
+     * The quantity of this product ordered
      */
     #[serde(
         default,
@@ -1936,7 +2196,7 @@ pub struct OrdersAddProductOrderByReferenceModel {
     )]
     pub quantity: i64,
     /**
-     * Name of the channel
+     * Unique reference id of the product
      */
     #[serde(
         default,
@@ -1981,7 +2241,7 @@ impl OrdersAddProductOrderModelOneOf {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersCreateOrderModel {
     /**
-     * Name of the channel
+     * Gift message to be included with order
      */
     #[serde(
         default,
@@ -1990,7 +2250,7 @@ pub struct OrdersCreateOrderModel {
     )]
     pub gift_message: String,
     /**
-     * Unique id of the channel
+     * Desired Fulfillment Center Location ID. If not specified, ShipBob will determine the location that fulfills this order.
      */
     #[serde(
         default,
@@ -1999,7 +2259,7 @@ pub struct OrdersCreateOrderModel {
     )]
     pub location_id: i64,
     /**
-     * Name of the channel
+     * User friendly orderId or store order number that will be shown on the Orders Page. If not provided, referenceId will be used
      */
     #[serde(
         default,
@@ -2017,7 +2277,7 @@ pub struct OrdersCreateOrderModel {
     )]
     pub products: Vec<OrdersAddProductOrderModelOneOf>,
     /**
-     * Expiration date for this lot
+     * Date this order was purchase by the end user
      */
     #[serde(
         default,
@@ -2030,9 +2290,6 @@ pub struct OrdersCreateOrderModel {
      */
     #[serde()]
     pub recipient: OrdersRecipientInfo,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2044,9 +2301,6 @@ pub struct OrdersCreateOrderModel {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retailer_program_data: Option<OrdersRetailerProgramData>,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2058,9 +2312,6 @@ pub struct OrdersCreateOrderModel {
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shipping_terms: Option<OrdersShippingTerms>,
-    /**
-     * Client-defined order tags
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2068,20 +2319,22 @@ pub struct OrdersCreateOrderModel {
     )]
     pub tags: Vec<OrdersTag>,
     /**
-     * Shipment type of the order
+     * Defaults to Direct to Consumer (DTC) if not provided. Note: B2B is not supported at this time
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<OrderType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Action {
     #[serde(rename = "Cancel")]
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -2089,12 +2342,31 @@ impl std::fmt::Display for Action {
         match self {
             Action::Cancel => "Cancel",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Action {
+    fn from(s: String) -> Action {
+        match s.as_str() {
+            "Cancel" => Action::Cancel,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Action> for String {
+    fn from(e: Action) -> String {
+        match e {
+            Action::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -2115,7 +2387,7 @@ pub struct OrdersCanceledShipment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<Action>,
     /**
-     *
+     * If the cancel action was successfull
      */
     #[serde(
         default,
@@ -2123,7 +2395,7 @@ pub struct OrdersCanceledShipment {
     )]
     pub is_success: bool,
     /**
-     * Name of the channel
+     * The reason the cancellation result
      */
     #[serde(
         default,
@@ -2132,7 +2404,7 @@ pub struct OrdersCanceledShipment {
     )]
     pub reason: String,
     /**
-     *
+     * The ID of the shipment
      */
     #[serde(
         default,
@@ -2146,6 +2418,7 @@ pub struct OrdersCanceledShipment {
 * The overall result of canceling the shipments associated with the order
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrdersCanceledOrderStatus {
     #[serde(rename = "Failure")]
     Failure,
@@ -2155,8 +2428,9 @@ pub enum OrdersCanceledOrderStatus {
     Success,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OrdersCanceledOrderStatus {
@@ -2166,12 +2440,33 @@ impl std::fmt::Display for OrdersCanceledOrderStatus {
             OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
             OrdersCanceledOrderStatus::Success => "Success",
             OrdersCanceledOrderStatus::Noop => "",
-            OrdersCanceledOrderStatus::FallthroughString => "*",
+            OrdersCanceledOrderStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OrdersCanceledOrderStatus {
+    fn from(s: String) -> OrdersCanceledOrderStatus {
+        match s.as_str() {
+            "Failure" => OrdersCanceledOrderStatus::Failure,
+            "PartialSuccess" => OrdersCanceledOrderStatus::PartialSuccess,
+            "Success" => OrdersCanceledOrderStatus::Success,
+            "" => OrdersCanceledOrderStatus::Noop,
+            _ => OrdersCanceledOrderStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OrdersCanceledOrderStatus> for String {
+    fn from(e: OrdersCanceledOrderStatus) -> String {
+        match e {
+            OrdersCanceledOrderStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OrdersCanceledOrderStatus {
     fn default() -> OrdersCanceledOrderStatus {
         OrdersCanceledOrderStatus::Noop
@@ -2187,7 +2482,7 @@ impl OrdersCanceledOrderStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersCanceledOrder {
     /**
-     *
+     * Results of camceling the shipments associated with the order
      */
     #[serde(
         default,
@@ -2195,13 +2490,10 @@ pub struct OrdersCanceledOrder {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub canceled_shipment_results: Vec<OrdersCanceledShipment>,
-    /**
-     *
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub order: Option<Order>,
     /**
-     *
+     * The ID of the canceled order
      */
     #[serde(
         default,
@@ -2210,7 +2502,7 @@ pub struct OrdersCanceledOrder {
     )]
     pub order_id: i64,
     /**
-     *
+     * The overall result of canceling the shipments associated with the order
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<OrdersCanceledOrderStatus>,
@@ -2220,7 +2512,7 @@ pub struct OrdersCanceledOrder {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersAddStoreOrderJsonModel {
     /**
-     * Name of the channel
+     * Json String that represent the order on a store front system
      */
     #[serde(
         default,
@@ -2233,7 +2525,7 @@ pub struct OrdersAddStoreOrderJsonModel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersShipmentLog {
     /**
-     * Unique id of the channel
+     * Log type id of the shipment
      */
     #[serde(
         default,
@@ -2242,7 +2534,7 @@ pub struct OrdersShipmentLog {
     )]
     pub log_type_id: i64,
     /**
-     * Name of the channel
+     * Name of the log type
      */
     #[serde(
         default,
@@ -2251,7 +2543,7 @@ pub struct OrdersShipmentLog {
     )]
     pub log_type_name: String,
     /**
-     * Name of the channel
+     * Summary of log type meaning
      */
     #[serde(
         default,
@@ -2269,7 +2561,7 @@ pub struct OrdersShipmentLog {
     )]
     pub metadata: String,
     /**
-     * Expiration date for this lot
+     * Timestamp of event
      */
     #[serde(
         default,
@@ -2283,7 +2575,7 @@ pub struct OrdersShipmentLog {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersCancelShipmentsModel {
     /**
-     * Model for cancel multiple shipments at once
+     * Shipment IDs to cancel
      */
     #[serde(
         default,
@@ -2297,7 +2589,7 @@ pub struct OrdersCancelShipmentsModel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersCanceledShipments {
     /**
-     *
+     * The results of all cancellation actions
      */
     #[serde(
         default,
@@ -2310,7 +2602,7 @@ pub struct OrdersCanceledShipments {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersServiceLevelDetail {
     /**
-     * Unique id of the channel
+     * Unique id for the service level
      */
     #[serde(
         default,
@@ -2319,7 +2611,7 @@ pub struct OrdersServiceLevelDetail {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * The name or title of the service level
      */
     #[serde(
         default,
@@ -2332,7 +2624,7 @@ pub struct OrdersServiceLevelDetail {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrdersShipMethodDetail {
     /**
-     * True if the inventory item is marked as a digital item
+     * Indicates if the shipping method is active
      */
     #[serde(
         default,
@@ -2340,7 +2632,7 @@ pub struct OrdersShipMethodDetail {
     )]
     pub active: bool,
     /**
-     * True if the inventory item is marked as a digital item
+     * Indicates the shipping method is a ShipBob default shipping method.
      */
     #[serde(
         default,
@@ -2348,7 +2640,7 @@ pub struct OrdersShipMethodDetail {
     )]
     pub default: bool,
     /**
-     * Unique id of the channel
+     * Unique id for shipping method.
      */
     #[serde(
         default,
@@ -2357,7 +2649,7 @@ pub struct OrdersShipMethodDetail {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the ship method as selected by the merchant and saved in ShipBob’s database (i.e. “ground”). Corresponds to the shipping_method field in the Orders API.
      */
     #[serde(
         default,
@@ -2370,6 +2662,7 @@ pub struct OrdersShipMethodDetail {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProductActiveStatus {
     #[serde(rename = "Active")]
     Active,
@@ -2379,8 +2672,9 @@ pub enum ProductActiveStatus {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductActiveStatus {
@@ -2390,12 +2684,33 @@ impl std::fmt::Display for ProductActiveStatus {
             ProductActiveStatus::Any => "Any",
             ProductActiveStatus::Inactive => "Inactive",
             ProductActiveStatus::Noop => "",
-            ProductActiveStatus::FallthroughString => "*",
+            ProductActiveStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProductActiveStatus {
+    fn from(s: String) -> ProductActiveStatus {
+        match s.as_str() {
+            "Active" => ProductActiveStatus::Active,
+            "Any" => ProductActiveStatus::Any,
+            "Inactive" => ProductActiveStatus::Inactive,
+            "" => ProductActiveStatus::Noop,
+            _ => ProductActiveStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProductActiveStatus> for String {
+    fn from(e: ProductActiveStatus) -> String {
+        match e {
+            ProductActiveStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProductActiveStatus {
     fn default() -> ProductActiveStatus {
         ProductActiveStatus::Noop
@@ -2408,6 +2723,7 @@ impl ProductActiveStatus {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ProductBundleStatus {
     #[serde(rename = "Any")]
     Any,
@@ -2417,8 +2733,9 @@ pub enum ProductBundleStatus {
     NotBundle,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ProductBundleStatus {
@@ -2428,12 +2745,33 @@ impl std::fmt::Display for ProductBundleStatus {
             ProductBundleStatus::Bundle => "Bundle",
             ProductBundleStatus::NotBundle => "NotBundle",
             ProductBundleStatus::Noop => "",
-            ProductBundleStatus::FallthroughString => "*",
+            ProductBundleStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ProductBundleStatus {
+    fn from(s: String) -> ProductBundleStatus {
+        match s.as_str() {
+            "Any" => ProductBundleStatus::Any,
+            "Bundle" => ProductBundleStatus::Bundle,
+            "NotBundle" => ProductBundleStatus::NotBundle,
+            "" => ProductBundleStatus::Noop,
+            _ => ProductBundleStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ProductBundleStatus> for String {
+    fn from(e: ProductBundleStatus) -> String {
+        match e {
+            ProductBundleStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ProductBundleStatus {
     fn default() -> ProductBundleStatus {
         ProductBundleStatus::Noop
@@ -2449,7 +2787,7 @@ impl ProductBundleStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProductsChannel {
     /**
-     * Information about a store channel
+     * Unique id of the store channel
      */
     #[serde(
         default,
@@ -2458,7 +2796,7 @@ pub struct ProductsChannel {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the store channel
      */
     #[serde(
         default,
@@ -2472,7 +2810,7 @@ pub struct ProductsChannel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProductsInventoryItem {
     /**
-     * The inventory that store products can resolve to when packing a shipment
+     * Unique id of the inventory item
      */
     #[serde(
         default,
@@ -2481,7 +2819,7 @@ pub struct ProductsInventoryItem {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the inventory item
      */
     #[serde(
         default,
@@ -2490,7 +2828,7 @@ pub struct ProductsInventoryItem {
     )]
     pub name: String,
     /**
-     * The inventory that store products can resolve to when packing a shipment
+     * Quantity of the inventory item included in a store product
      */
     #[serde(
         default,
@@ -2504,7 +2842,7 @@ pub struct ProductsInventoryItem {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProductsFulfillmentCenterQuantity {
     /**
-     * Break down of quantities by fulfillment center
+     * Amount of committed quantity at this fulfillment center
      */
     #[serde(
         default,
@@ -2513,7 +2851,7 @@ pub struct ProductsFulfillmentCenterQuantity {
     )]
     pub committed_quantity: i64,
     /**
-     * Break down of quantities by fulfillment center
+     * Amount of fulfillable quantity at this fulfillment center
      */
     #[serde(
         default,
@@ -2522,7 +2860,7 @@ pub struct ProductsFulfillmentCenterQuantity {
     )]
     pub fulfillable_quantity: i64,
     /**
-     * Break down of quantities by fulfillment center
+     * Unique id of the fulfillment center
      */
     #[serde(
         default,
@@ -2531,7 +2869,7 @@ pub struct ProductsFulfillmentCenterQuantity {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the fulfillment center
      */
     #[serde(
         default,
@@ -2540,7 +2878,7 @@ pub struct ProductsFulfillmentCenterQuantity {
     )]
     pub name: String,
     /**
-     * Break down of quantities by fulfillment center
+     * Amount of onhand quantity at this fulfillment center
      */
     #[serde(
         default,
@@ -2553,7 +2891,7 @@ pub struct ProductsFulfillmentCenterQuantity {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Product {
     /**
-     * Name of the channel
+     * Barcode for the product
      */
     #[serde(
         default,
@@ -2569,7 +2907,7 @@ pub struct Product {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub channel: Option<ProductsChannel>,
     /**
-     * Expiration date for this lot
+     * Date the product was created
      */
     #[serde(
         default,
@@ -2596,7 +2934,7 @@ pub struct Product {
     )]
     pub fulfillable_quantity_by_fulfillment_center: Vec<ProductsFulfillmentCenterQuantity>,
     /**
-     * Name of the channel
+     * Global Trade Item Number - unique and internationally recognized identifier assigned to item by company GS1.
      */
     #[serde(
         default,
@@ -2605,7 +2943,7 @@ pub struct Product {
     )]
     pub gtin: String,
     /**
-     * Unique id of the channel
+     * Unique identifier of the product
      */
     #[serde(
         default,
@@ -2614,7 +2952,7 @@ pub struct Product {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * The name of the product
      */
     #[serde(
         default,
@@ -2623,7 +2961,7 @@ pub struct Product {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * Unique reference identifier of the product
      */
     #[serde(
         default,
@@ -2632,7 +2970,7 @@ pub struct Product {
     )]
     pub reference_id: String,
     /**
-     * Name of the channel
+     * Stock keeping unit for the product
      */
     #[serde(
         default,
@@ -2641,7 +2979,7 @@ pub struct Product {
     )]
     pub sku: String,
     /**
-     * Unique id of the channel
+     * Total committed quantity of this product
      */
     #[serde(
         default,
@@ -2650,7 +2988,7 @@ pub struct Product {
     )]
     pub total_committed_quantity: i64,
     /**
-     * Unique id of the channel
+     * Total fulfillable quantity of this product
      */
     #[serde(
         default,
@@ -2659,7 +2997,7 @@ pub struct Product {
     )]
     pub total_fulfillable_quantity: i64,
     /**
-     * Unique id of the channel
+     * Total on hand quantity of this product
      */
     #[serde(
         default,
@@ -2668,7 +3006,7 @@ pub struct Product {
     )]
     pub total_onhand_quantity: i64,
     /**
-     * Weight in ounces of this inventory item
+     * The price of one unit
      */
     #[serde(
         default,
@@ -2677,7 +3015,7 @@ pub struct Product {
     )]
     pub unit_price: f64,
     /**
-     * Name of the channel
+     * Universal Product Code - Unique external identifier
      */
     #[serde(
         default,
@@ -2691,7 +3029,7 @@ pub struct Product {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProductsCreateProductModel {
     /**
-     * Name of the channel
+     * Barcode for the product
      */
     #[serde(
         default,
@@ -2700,7 +3038,7 @@ pub struct ProductsCreateProductModel {
     )]
     pub barcode: String,
     /**
-     * Name of the channel
+     * Global Trade Item Number - unique and internationally recognized identifier assigned to item by company GS1.
      */
     #[serde(
         default,
@@ -2709,7 +3047,7 @@ pub struct ProductsCreateProductModel {
     )]
     pub gtin: String,
     /**
-     * Name of the channel
+     * The name of the product
      */
     #[serde(
         default,
@@ -2718,7 +3056,7 @@ pub struct ProductsCreateProductModel {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * Unique reference identifier for the product. Any linked or generated inventory will also be uniquely identified by this value
      */
     #[serde(
         default,
@@ -2727,7 +3065,7 @@ pub struct ProductsCreateProductModel {
     )]
     pub reference_id: String,
     /**
-     * Name of the channel
+     * Stock keeping unit for the product
      */
     #[serde(
         default,
@@ -2736,7 +3074,7 @@ pub struct ProductsCreateProductModel {
     )]
     pub sku: String,
     /**
-     * The product to create
+     * The price of one unit
      */
     #[serde(
         default,
@@ -2745,7 +3083,7 @@ pub struct ProductsCreateProductModel {
     )]
     pub unit_price: f64,
     /**
-     * Name of the channel
+     * Universal Product Code - Unique external identifier
      */
     #[serde(
         default,
@@ -2759,7 +3097,7 @@ pub struct ProductsCreateProductModel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProductsUpdateProductModel {
     /**
-     * Name of the channel
+     * Barcode for the product
      */
     #[serde(
         default,
@@ -2768,7 +3106,7 @@ pub struct ProductsUpdateProductModel {
     )]
     pub barcode: String,
     /**
-     * Name of the channel
+     * Global Trade Item Number - unique and internationally recognized identifier assigned to item by company GS1.
      */
     #[serde(
         default,
@@ -2777,7 +3115,7 @@ pub struct ProductsUpdateProductModel {
     )]
     pub gtin: String,
     /**
-     * Name of the channel
+     * The name of the product
      */
     #[serde(
         default,
@@ -2786,7 +3124,7 @@ pub struct ProductsUpdateProductModel {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * The stock keeping unit of the product
      */
     #[serde(
         default,
@@ -2795,7 +3133,7 @@ pub struct ProductsUpdateProductModel {
     )]
     pub sku: String,
     /**
-     * Updates to an existing product product
+     * The price of one unit
      */
     #[serde(
         default,
@@ -2804,7 +3142,7 @@ pub struct ProductsUpdateProductModel {
     )]
     pub unit_price: f64,
     /**
-     * Name of the channel
+     * Universal Product Code - Unique external identifier
      */
     #[serde(
         default,
@@ -2818,7 +3156,7 @@ pub struct ProductsUpdateProductModel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReceivingFulfillmentCenter {
     /**
-     * Name of the channel
+     * Address line one of the fulfillment center
      */
     #[serde(
         default,
@@ -2828,7 +3166,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub address_1: String,
     /**
-     * Name of the channel
+     * Address line two of the fulfillment center
      */
     #[serde(
         default,
@@ -2838,7 +3176,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub address_2: String,
     /**
-     * Name of the channel
+     * City the fulfillment center is located in
      */
     #[serde(
         default,
@@ -2847,7 +3185,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub city: String,
     /**
-     * Name of the channel
+     * Country the fulfillment center is located in
      */
     #[serde(
         default,
@@ -2856,7 +3194,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub country: String,
     /**
-     * Name of the channel
+     * Email contact for the fulfillment center
      */
     #[serde(
         default,
@@ -2865,7 +3203,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub email: String,
     /**
-     * Information about a fulfillment center
+     * Unique identifier of the fulfillment center
      */
     #[serde(
         default,
@@ -2874,7 +3212,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the fulfillment center
      */
     #[serde(
         default,
@@ -2883,7 +3221,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * Phone number contact for the fulfillment center
      */
     #[serde(
         default,
@@ -2892,7 +3230,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub phone_number: String,
     /**
-     * Name of the channel
+     * State the fulfillment center is located in
      */
     #[serde(
         default,
@@ -2901,7 +3239,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub state: String,
     /**
-     * Name of the channel
+     * Timezone the fulfillment center is located in
      */
     #[serde(
         default,
@@ -2910,7 +3248,7 @@ pub struct ReceivingFulfillmentCenter {
     )]
     pub timezone: String,
     /**
-     * Name of the channel
+     * Postal code of the fulfillment center
      */
     #[serde(
         default,
@@ -2921,6 +3259,7 @@ pub struct ReceivingFulfillmentCenter {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReceivingStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -2938,8 +3277,9 @@ pub enum ReceivingStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingStatus {
@@ -2953,12 +3293,37 @@ impl std::fmt::Display for ReceivingStatus {
             ReceivingStatus::PartiallyArrived => "PartiallyArrived",
             ReceivingStatus::Processing => "Processing",
             ReceivingStatus::Noop => "",
-            ReceivingStatus::FallthroughString => "*",
+            ReceivingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReceivingStatus {
+    fn from(s: String) -> ReceivingStatus {
+        match s.as_str() {
+            "Arrived" => ReceivingStatus::Arrived,
+            "Awaiting" => ReceivingStatus::Awaiting,
+            "Cancelled" => ReceivingStatus::Cancelled,
+            "Completed" => ReceivingStatus::Completed,
+            "Incomplete" => ReceivingStatus::Incomplete,
+            "PartiallyArrived" => ReceivingStatus::PartiallyArrived,
+            "Processing" => ReceivingStatus::Processing,
+            "" => ReceivingStatus::Noop,
+            _ => ReceivingStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReceivingStatus> for String {
+    fn from(e: ReceivingStatus) -> String {
+        match e {
+            ReceivingStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReceivingStatus {
     fn default() -> ReceivingStatus {
         ReceivingStatus::Noop
@@ -2971,6 +3336,7 @@ impl ReceivingStatus {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReceivingPackageType {
     #[serde(rename = "FloorLoadedContainer")]
     FloorLoadedContainer,
@@ -2980,8 +3346,9 @@ pub enum ReceivingPackageType {
     Pallet,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackageType {
@@ -2991,12 +3358,33 @@ impl std::fmt::Display for ReceivingPackageType {
             ReceivingPackageType::Package => "Package",
             ReceivingPackageType::Pallet => "Pallet",
             ReceivingPackageType::Noop => "",
-            ReceivingPackageType::FallthroughString => "*",
+            ReceivingPackageType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReceivingPackageType {
+    fn from(s: String) -> ReceivingPackageType {
+        match s.as_str() {
+            "FloorLoadedContainer" => ReceivingPackageType::FloorLoadedContainer,
+            "Package" => ReceivingPackageType::Package,
+            "Pallet" => ReceivingPackageType::Pallet,
+            "" => ReceivingPackageType::Noop,
+            _ => ReceivingPackageType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReceivingPackageType> for String {
+    fn from(e: ReceivingPackageType) -> String {
+        match e {
+            ReceivingPackageType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReceivingPackageType {
     fn default() -> ReceivingPackageType {
         ReceivingPackageType::Noop
@@ -3009,6 +3397,7 @@ impl ReceivingPackageType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReceivingPackingType {
     #[serde(rename = "EverythingInOneBox")]
     EverythingInOneBox,
@@ -3018,8 +3407,9 @@ pub enum ReceivingPackingType {
     OneSkuPerBox,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingPackingType {
@@ -3029,12 +3419,33 @@ impl std::fmt::Display for ReceivingPackingType {
             ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
             ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
             ReceivingPackingType::Noop => "",
-            ReceivingPackingType::FallthroughString => "*",
+            ReceivingPackingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReceivingPackingType {
+    fn from(s: String) -> ReceivingPackingType {
+        match s.as_str() {
+            "EverythingInOneBox" => ReceivingPackingType::EverythingInOneBox,
+            "MultipleSkuPerBox" => ReceivingPackingType::MultipleSkuPerBox,
+            "OneSkuPerBox" => ReceivingPackingType::OneSkuPerBox,
+            "" => ReceivingPackingType::Noop,
+            _ => ReceivingPackingType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReceivingPackingType> for String {
+    fn from(e: ReceivingPackingType) -> String {
+        match e {
+            ReceivingPackingType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReceivingPackingType {
     fn default() -> ReceivingPackingType {
         ReceivingPackingType::Noop
@@ -3047,6 +3458,7 @@ impl ReceivingPackingType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReceivingBoxStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3062,8 +3474,9 @@ pub enum ReceivingBoxStatus {
     Stowing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReceivingBoxStatus {
@@ -3076,12 +3489,36 @@ impl std::fmt::Display for ReceivingBoxStatus {
             ReceivingBoxStatus::Counting => "Counting",
             ReceivingBoxStatus::Stowing => "Stowing",
             ReceivingBoxStatus::Noop => "",
-            ReceivingBoxStatus::FallthroughString => "*",
+            ReceivingBoxStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReceivingBoxStatus {
+    fn from(s: String) -> ReceivingBoxStatus {
+        match s.as_str() {
+            "Arrived" => ReceivingBoxStatus::Arrived,
+            "Awaiting" => ReceivingBoxStatus::Awaiting,
+            "Cancelled" => ReceivingBoxStatus::Cancelled,
+            "Completed" => ReceivingBoxStatus::Completed,
+            "Counting" => ReceivingBoxStatus::Counting,
+            "Stowing" => ReceivingBoxStatus::Stowing,
+            "" => ReceivingBoxStatus::Noop,
+            _ => ReceivingBoxStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReceivingBoxStatus> for String {
+    fn from(e: ReceivingBoxStatus) -> String {
+        match e {
+            ReceivingBoxStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReceivingBoxStatus {
     fn default() -> ReceivingBoxStatus {
         ReceivingBoxStatus::Noop
@@ -3097,7 +3534,7 @@ impl ReceivingBoxStatus {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReceivingBoxItem {
     /**
-     * Information about an item contained inside a box as part of a receiving order
+     * Unique identifier of the inventory item
      */
     #[serde(
         default,
@@ -3106,7 +3543,7 @@ pub struct ReceivingBoxItem {
     )]
     pub inventory_id: i64,
     /**
-     * Expiration date for this lot
+     * Expiration date of the item's lot
      */
     #[serde(
         default,
@@ -3115,7 +3552,7 @@ pub struct ReceivingBoxItem {
     )]
     pub lot_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Lot number the item belongs to
      */
     #[serde(
         default,
@@ -3124,7 +3561,7 @@ pub struct ReceivingBoxItem {
     )]
     pub lot_number: String,
     /**
-     * Information about an item contained inside a box as part of a receiving order
+     * Quantity of the item included
      */
     #[serde(
         default,
@@ -3133,7 +3570,7 @@ pub struct ReceivingBoxItem {
     )]
     pub quantity: i64,
     /**
-     * Information about an item contained inside a box as part of a receiving order
+     * Quantity of the item that was received after processing the receiving order
      */
     #[serde(
         default,
@@ -3147,7 +3584,7 @@ pub struct ReceivingBoxItem {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReceivingBox {
     /**
-     * Expiration date for this lot
+     * Date the box arrived
      */
     #[serde(
         default,
@@ -3165,7 +3602,7 @@ pub struct ReceivingBox {
     )]
     pub box_items: Vec<ReceivingBoxItem>,
     /**
-     * Information about a box shipment included in a receiving order
+     * The number of the box in the receiving order
      */
     #[serde(
         default,
@@ -3173,13 +3610,10 @@ pub struct ReceivingBox {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub box_number: i64,
-    /**
-     * Information about a box shipment included in a receiving order
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub box_status: Option<ReceivingBoxStatus>,
     /**
-     * Expiration date for this lot
+     * Date counting of the box's inventory items started
      */
     #[serde(
         default,
@@ -3188,7 +3622,7 @@ pub struct ReceivingBox {
     )]
     pub counting_started_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Expiration date for this lot
+     * Date the box was received
      */
     #[serde(
         default,
@@ -3197,7 +3631,7 @@ pub struct ReceivingBox {
     )]
     pub received_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Tracking number of the box shipment
      */
     #[serde(
         default,
@@ -3211,7 +3645,7 @@ pub struct ReceivingBox {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReceivingOrder {
     /**
-     * Name of the channel
+     * URL to the packing slip to be included in each box shipment for this receiving order
      */
     #[serde(
         default,
@@ -3219,9 +3653,6 @@ pub struct ReceivingOrder {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub box_labels_uri: String,
-    /**
-     * Information about a receiving order
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub box_packaging_type: Option<ReceivingPackingType>,
     /**
@@ -3234,7 +3665,7 @@ pub struct ReceivingOrder {
     )]
     pub boxes: Vec<ReceivingBox>,
     /**
-     * Expiration date for this lot
+     * Expected date that all packages will have arrived
      */
     #[serde(
         default,
@@ -3243,12 +3674,12 @@ pub struct ReceivingOrder {
     )]
     pub expected_arrival_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Information about a receiving order
+     * Information about a fulfillment center
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fulfillment_center: Option<ReceivingFulfillmentCenter>,
     /**
-     * Information about a receiving order
+     * Unique id of the warehouse receiving order
      */
     #[serde(
         default,
@@ -3257,7 +3688,7 @@ pub struct ReceivingOrder {
     )]
     pub id: i64,
     /**
-     * Expiration date for this lot
+     * Insert date of the receiving order
      */
     #[serde(
         default,
@@ -3266,7 +3697,7 @@ pub struct ReceivingOrder {
     )]
     pub insert_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Expiration date for this lot
+     * Last date the receiving order was updated
      */
     #[serde(
         default,
@@ -3274,14 +3705,8 @@ pub struct ReceivingOrder {
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_updated_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about a receiving order
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub package_type: Option<ReceivingPackageType>,
-    /**
-     * Information about a receiving order
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReceivingStatus>,
 }
@@ -3291,9 +3716,6 @@ pub struct Extensions {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3308,36 +3730,24 @@ pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
     pub errors: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub extensions: Option<Extensions>,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub instance: String,
-    /**
-     * Unique id of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub status: i64,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3351,7 +3761,7 @@ pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReceivingAssignOrderFulfillmentCenterModel {
     /**
-     * Unique id of the channel
+     * ID of the fulfillment center to assign this receiving order to
      */
     #[serde(
         default,
@@ -3365,7 +3775,7 @@ pub struct ReceivingAssignOrderFulfillmentCenterModel {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReceivingAddBoxItemModel {
     /**
-     * Unique id of the channel
+     * Unique inventory id of the items in the box
      */
     #[serde(
         default,
@@ -3374,7 +3784,7 @@ pub struct ReceivingAddBoxItemModel {
     )]
     pub inventory_id: i64,
     /**
-     * Expiration date for this lot
+     * Lot expiration date for the items in the box
      */
     #[serde(
         default,
@@ -3383,7 +3793,7 @@ pub struct ReceivingAddBoxItemModel {
     )]
     pub lot_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Lot number of the items in the box
      */
     #[serde(
         default,
@@ -3392,7 +3802,7 @@ pub struct ReceivingAddBoxItemModel {
     )]
     pub lot_number: String,
     /**
-     * Unique id of the channel
+     * Quantity of the items in the box
      */
     #[serde(
         default,
@@ -3415,7 +3825,7 @@ pub struct ReceivingAddBoxOrderModel {
     )]
     pub box_items: Vec<ReceivingAddBoxItemModel>,
     /**
-     * Name of the channel
+     * Tracking number for the box shipment
      */
     #[serde(
         default,
@@ -3440,7 +3850,7 @@ pub struct ReceivingCreateOrderModel {
     )]
     pub boxes: Vec<ReceivingAddBoxOrderModel>,
     /**
-     * Expiration date for this lot
+     * Expected arrival date of all the box shipments in this receiving order
      */
     #[serde(
         default,
@@ -3458,6 +3868,7 @@ pub struct ReceivingCreateOrderModel {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReturnStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3471,8 +3882,9 @@ pub enum ReturnStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnStatus {
@@ -3484,12 +3896,35 @@ impl std::fmt::Display for ReturnStatus {
             ReturnStatus::Completed => "Completed",
             ReturnStatus::Processing => "Processing",
             ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+            ReturnStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReturnStatus {
+    fn from(s: String) -> ReturnStatus {
+        match s.as_str() {
+            "Arrived" => ReturnStatus::Arrived,
+            "AwaitingArrival" => ReturnStatus::AwaitingArrival,
+            "Cancelled" => ReturnStatus::Cancelled,
+            "Completed" => ReturnStatus::Completed,
+            "Processing" => ReturnStatus::Processing,
+            "" => ReturnStatus::Noop,
+            _ => ReturnStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReturnStatus> for String {
+    fn from(e: ReturnStatus) -> String {
+        match e {
+            ReturnStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReturnStatus {
     fn default() -> ReturnStatus {
         ReturnStatus::Noop
@@ -3502,6 +3937,7 @@ impl ReturnStatus {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReturnsTransactionLogSource {
     #[serde(rename = "ReturnLabelInvoice")]
     ReturnLabelInvoice,
@@ -3511,8 +3947,9 @@ pub enum ReturnsTransactionLogSource {
     ReturnToSenderFee,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnsTransactionLogSource {
@@ -3522,12 +3959,33 @@ impl std::fmt::Display for ReturnsTransactionLogSource {
             ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
             ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
             ReturnsTransactionLogSource::Noop => "",
-            ReturnsTransactionLogSource::FallthroughString => "*",
+            ReturnsTransactionLogSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReturnsTransactionLogSource {
+    fn from(s: String) -> ReturnsTransactionLogSource {
+        match s.as_str() {
+            "ReturnLabelInvoice" => ReturnsTransactionLogSource::ReturnLabelInvoice,
+            "ReturnProcessingFee" => ReturnsTransactionLogSource::ReturnProcessingFee,
+            "ReturnToSenderFee" => ReturnsTransactionLogSource::ReturnToSenderFee,
+            "" => ReturnsTransactionLogSource::Noop,
+            _ => ReturnsTransactionLogSource::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReturnsTransactionLogSource> for String {
+    fn from(e: ReturnsTransactionLogSource) -> String {
+        match e {
+            ReturnsTransactionLogSource::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReturnsTransactionLogSource {
     fn default() -> ReturnsTransactionLogSource {
         ReturnsTransactionLogSource::Noop
@@ -3542,7 +4000,7 @@ impl ReturnsTransactionLogSource {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReturnsTransaction {
     /**
-     * Weight in ounces of this inventory item
+     * Transaction amount in dollars
      */
     #[serde(
         default,
@@ -3558,7 +4016,7 @@ pub struct ReturnsTransaction {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReturnsFulfillmentCenter {
     /**
-     * Unique id of the channel
+     * Unique identifier of the fulfillment center
      */
     #[serde(
         default,
@@ -3567,7 +4025,7 @@ pub struct ReturnsFulfillmentCenter {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the fulfillment center
      */
     #[serde(
         default,
@@ -3578,6 +4036,7 @@ pub struct ReturnsFulfillmentCenter {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReturnAction {
     #[serde(rename = "Default")]
     Default,
@@ -3589,8 +4048,9 @@ pub enum ReturnAction {
     Restock,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnAction {
@@ -3601,12 +4061,34 @@ impl std::fmt::Display for ReturnAction {
             ReturnAction::Quarantine => "Quarantine",
             ReturnAction::Restock => "Restock",
             ReturnAction::Noop => "",
-            ReturnAction::FallthroughString => "*",
+            ReturnAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReturnAction {
+    fn from(s: String) -> ReturnAction {
+        match s.as_str() {
+            "Default" => ReturnAction::Default,
+            "Dispose" => ReturnAction::Dispose,
+            "Quarantine" => ReturnAction::Quarantine,
+            "Restock" => ReturnAction::Restock,
+            "" => ReturnAction::Noop,
+            _ => ReturnAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReturnAction> for String {
+    fn from(e: ReturnAction) -> String {
+        match e {
+            ReturnAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReturnAction {
     fn default() -> ReturnAction {
         ReturnAction::Noop
@@ -3619,6 +4101,7 @@ impl ReturnAction {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReturnActionSource {
     #[serde(rename = "InventoryDefault")]
     InventoryDefault,
@@ -3626,8 +4109,9 @@ pub enum ReturnActionSource {
     Override,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnActionSource {
@@ -3636,12 +4120,32 @@ impl std::fmt::Display for ReturnActionSource {
             ReturnActionSource::InventoryDefault => "InventoryDefault",
             ReturnActionSource::Override => "Override",
             ReturnActionSource::Noop => "",
-            ReturnActionSource::FallthroughString => "*",
+            ReturnActionSource::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReturnActionSource {
+    fn from(s: String) -> ReturnActionSource {
+        match s.as_str() {
+            "InventoryDefault" => ReturnActionSource::InventoryDefault,
+            "Override" => ReturnActionSource::Override,
+            "" => ReturnActionSource::Noop,
+            _ => ReturnActionSource::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReturnActionSource> for String {
+    fn from(e: ReturnActionSource) -> String {
+        match e {
+            ReturnActionSource::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReturnActionSource {
     fn default() -> ReturnActionSource {
         ReturnActionSource::Noop
@@ -3660,7 +4164,7 @@ pub struct ReturnActionRequested {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action_type: Option<ReturnActionSource>,
     /**
-     * Name of the channel
+     * Specific instructions to be taken for the inventory when processing the return
      */
     #[serde(
         default,
@@ -3675,7 +4179,7 @@ pub struct ReturnActionTaken {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<ReturnAction>,
     /**
-     * Name of the channel
+     * Reason the given action was taken
      */
     #[serde(
         default,
@@ -3684,7 +4188,7 @@ pub struct ReturnActionTaken {
     )]
     pub action_reason: String,
     /**
-     * Unique id of the channel
+     * Quantity of inventory processed with the taken action
      */
     #[serde(
         default,
@@ -3708,7 +4212,7 @@ pub struct ReturnsInventoryItem {
     )]
     pub action_taken: Vec<ReturnActionTaken>,
     /**
-     * Unique id of the channel
+     * Unique id of the inventory item
      */
     #[serde(
         default,
@@ -3717,7 +4221,7 @@ pub struct ReturnsInventoryItem {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Name of the inventory item
      */
     #[serde(
         default,
@@ -3726,7 +4230,7 @@ pub struct ReturnsInventoryItem {
     )]
     pub name: String,
     /**
-     * Unique id of the channel
+     * Quantity expected to be processed with the return
      */
     #[serde(
         default,
@@ -3737,6 +4241,7 @@ pub struct ReturnsInventoryItem {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReturnType {
     #[serde(rename = "Regular")]
     Regular,
@@ -3744,8 +4249,9 @@ pub enum ReturnType {
     ReturnToSender,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReturnType {
@@ -3754,12 +4260,32 @@ impl std::fmt::Display for ReturnType {
             ReturnType::Regular => "Regular",
             ReturnType::ReturnToSender => "ReturnToSender",
             ReturnType::Noop => "",
-            ReturnType::FallthroughString => "*",
+            ReturnType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReturnType {
+    fn from(s: String) -> ReturnType {
+        match s.as_str() {
+            "Regular" => ReturnType::Regular,
+            "ReturnToSender" => ReturnType::ReturnToSender,
+            "" => ReturnType::Noop,
+            _ => ReturnType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReturnType> for String {
+    fn from(e: ReturnType) -> String {
+        match e {
+            ReturnType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReturnType {
     fn default() -> ReturnType {
         ReturnType::Noop
@@ -3784,7 +4310,7 @@ pub struct ReturnOrder {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fulfillment_center: Option<ReturnsFulfillmentCenter>,
     /**
-     * Unique id of the channel
+     * Unique id of the Return Order
      */
     #[serde(
         default,
@@ -3793,7 +4319,7 @@ pub struct ReturnOrder {
     )]
     pub id: i64,
     /**
-     * Expiration date for this lot
+     * Date this return order was created
      */
     #[serde(
         default,
@@ -3811,7 +4337,7 @@ pub struct ReturnOrder {
     )]
     pub inventory: Vec<ReturnsInventoryItem>,
     /**
-     * Weight in ounces of this inventory item
+     * Invoiced amount of return order (sum of transaction amounts)
      */
     #[serde(
         default,
@@ -3820,7 +4346,7 @@ pub struct ReturnOrder {
     )]
     pub invoice_amount: f64,
     /**
-     * Unique id of the channel
+     * Id of the corresponding shipment that is the souce of the return
      */
     #[serde(
         default,
@@ -3829,7 +4355,7 @@ pub struct ReturnOrder {
     )]
     pub original_shipment_id: i64,
     /**
-     * Name of the channel
+     * Client-defined external unique id of the return order
      */
     #[serde(
         default,
@@ -3842,7 +4368,7 @@ pub struct ReturnOrder {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReturnStatus>,
     /**
-     * Name of the channel
+     * Tracking number of the return shipment
      */
     #[serde(
         default,
@@ -3864,7 +4390,7 @@ pub struct ReturnOrder {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReturnInventory {
     /**
-     * Unique id of the channel
+     * ID of the inventory item to return
      */
     #[serde(
         default,
@@ -3873,7 +4399,7 @@ pub struct ReturnInventory {
     )]
     pub id: i64,
     /**
-     * Unique id of the channel
+     * Quantity of the returned inventory item in the return
      */
     #[serde(
         default,
@@ -3902,7 +4428,7 @@ pub struct ReturnsCreateReturn {
     )]
     pub inventory: Vec<ReturnInventory>,
     /**
-     * Unique id of the channel
+     * Shipment from which the items in the return originated
      */
     #[serde(
         default,
@@ -3911,7 +4437,7 @@ pub struct ReturnsCreateReturn {
     )]
     pub original_shipment_id: i64,
     /**
-     * Name of the channel
+     * Client-defined external unique identifier for the return order
      */
     #[serde(
         default,
@@ -3920,7 +4446,7 @@ pub struct ReturnsCreateReturn {
     )]
     pub reference_id: String,
     /**
-     * Name of the channel
+     * Tracking number for the return shipment
      */
     #[serde(
         default,
@@ -3934,6 +4460,7 @@ pub struct ReturnsCreateReturn {
 * Order to sort results in
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortOrder {
     #[serde(rename = "Newest")]
     Newest,
@@ -3941,8 +4468,9 @@ pub enum SortOrder {
     Oldest,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SortOrder {
@@ -3951,12 +4479,32 @@ impl std::fmt::Display for SortOrder {
             SortOrder::Newest => "Newest",
             SortOrder::Oldest => "Oldest",
             SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SortOrder {
+    fn from(s: String) -> SortOrder {
+        match s.as_str() {
+            "Newest" => SortOrder::Newest,
+            "Oldest" => SortOrder::Oldest,
+            "" => SortOrder::Noop,
+            _ => SortOrder::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SortOrder> for String {
+    fn from(e: SortOrder) -> String {
+        match e {
+            SortOrder::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SortOrder {
     fn default() -> SortOrder {
         SortOrder::Noop
@@ -3973,7 +4521,7 @@ pub struct ReturnOrderStatusHistory {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<ReturnStatus>,
     /**
-     * Expiration date for this lot
+     * Date this corresponding return order status was created
      */
     #[serde(
         default,
@@ -3984,6 +4532,7 @@ pub struct ReturnOrderStatusHistory {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WebhooksTopics {
     #[serde(rename = "order_shipped")]
     OrderShipped,
@@ -3995,8 +4544,9 @@ pub enum WebhooksTopics {
     ShipmentOnhold,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebhooksTopics {
@@ -4007,12 +4557,34 @@ impl std::fmt::Display for WebhooksTopics {
             WebhooksTopics::ShipmentException => "shipment_exception",
             WebhooksTopics::ShipmentOnhold => "shipment_onhold",
             WebhooksTopics::Noop => "",
-            WebhooksTopics::FallthroughString => "*",
+            WebhooksTopics::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WebhooksTopics {
+    fn from(s: String) -> WebhooksTopics {
+        match s.as_str() {
+            "order_shipped" => WebhooksTopics::OrderShipped,
+            "shipment_delivered" => WebhooksTopics::ShipmentDelivered,
+            "shipment_exception" => WebhooksTopics::ShipmentException,
+            "shipment_onhold" => WebhooksTopics::ShipmentOnhold,
+            "" => WebhooksTopics::Noop,
+            _ => WebhooksTopics::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WebhooksTopics> for String {
+    fn from(e: WebhooksTopics) -> String {
+        match e {
+            WebhooksTopics::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WebhooksTopics {
     fn default() -> WebhooksTopics {
         WebhooksTopics::Noop
@@ -4027,7 +4599,7 @@ impl WebhooksTopics {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Webhook {
     /**
-     * Expiration date for this lot
+     * Timestamp the webhook subscription was created
      */
     #[serde(
         default,
@@ -4036,7 +4608,7 @@ pub struct Webhook {
     )]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Unique id of the channel
+     * ID of the webhook subscription
      */
     #[serde(
         default,
@@ -4045,7 +4617,7 @@ pub struct Webhook {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * URL subscription events will be posted to
      */
     #[serde(
         default,
@@ -4060,7 +4632,7 @@ pub struct Webhook {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WebhooksCreateWebhookSubscriptionModel {
     /**
-     * Name of the channel
+     * URL we will call when an event matching the subscription topic is raised. Must have ssl enabled (https) and accept POST requests with content type of application/json
      */
     #[serde(
         default,
@@ -4074,9 +4646,6 @@ pub struct WebhooksCreateWebhookSubscriptionModel {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MicrosoftAspNetCoreMvcProblemDetails {
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4084,9 +4653,6 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
         rename = "$type"
     )]
     pub type__: String,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4095,9 +4661,6 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
     pub detail: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub extensions: Option<Extensions>,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4110,18 +4673,12 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub status: i64,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub title: String,
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4132,6 +4689,7 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IntegrationsLocationServiceTypeEnum {
     #[serde(rename = "Receiving")]
     Receiving,
@@ -4139,8 +4697,9 @@ pub enum IntegrationsLocationServiceTypeEnum {
     Returns,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
@@ -4149,12 +4708,32 @@ impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
             IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
             IntegrationsLocationServiceTypeEnum::Returns => "Returns",
             IntegrationsLocationServiceTypeEnum::Noop => "",
-            IntegrationsLocationServiceTypeEnum::FallthroughString => "*",
+            IntegrationsLocationServiceTypeEnum::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IntegrationsLocationServiceTypeEnum {
+    fn from(s: String) -> IntegrationsLocationServiceTypeEnum {
+        match s.as_str() {
+            "Receiving" => IntegrationsLocationServiceTypeEnum::Receiving,
+            "Returns" => IntegrationsLocationServiceTypeEnum::Returns,
+            "" => IntegrationsLocationServiceTypeEnum::Noop,
+            _ => IntegrationsLocationServiceTypeEnum::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IntegrationsLocationServiceTypeEnum> for String {
+    fn from(e: IntegrationsLocationServiceTypeEnum) -> String {
+        match e {
+            IntegrationsLocationServiceTypeEnum::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IntegrationsLocationServiceTypeEnum {
     fn default() -> IntegrationsLocationServiceTypeEnum {
         IntegrationsLocationServiceTypeEnum::Noop
@@ -4170,7 +4749,7 @@ impl IntegrationsLocationServiceTypeEnum {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IntegrationsLocationAddress {
     /**
-     * Name of the channel
+     * First part of the address of the location for this service
      */
     #[serde(
         default,
@@ -4180,7 +4759,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub address_1: String,
     /**
-     * Name of the channel
+     * Second part of the address of the location for this service
      */
     #[serde(
         default,
@@ -4190,7 +4769,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub address_2: String,
     /**
-     * Name of the channel
+     * City of the location
      */
     #[serde(
         default,
@@ -4199,7 +4778,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub city: String,
     /**
-     * Name of the channel
+     * Country of the location
      */
     #[serde(
         default,
@@ -4208,7 +4787,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub country: String,
     /**
-     * Name of the channel
+     * Email of the location for this service
      */
     #[serde(
         default,
@@ -4217,7 +4796,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub email: String,
     /**
-     * Name of the channel
+     * Name to use in the address of the location for this service
      */
     #[serde(
         default,
@@ -4226,7 +4805,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub name: String,
     /**
-     * Name of the channel
+     * Phone Number of the location for this service
      */
     #[serde(
         default,
@@ -4235,7 +4814,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub phone_number: String,
     /**
-     * Name of the channel
+     * State of the location
      */
     #[serde(
         default,
@@ -4244,7 +4823,7 @@ pub struct IntegrationsLocationAddress {
     )]
     pub state: String,
     /**
-     * Name of the channel
+     * Zip code of the location
      */
     #[serde(
         default,
@@ -4262,7 +4841,7 @@ pub struct IntegrationsLocationService {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub address: Option<IntegrationsLocationAddress>,
     /**
-     * True if the inventory item is marked as a digital item
+     * Indicates if the user is authorized to access this service at the location
      */
     #[serde(
         default,
@@ -4275,9 +4854,6 @@ pub struct IntegrationsLocationService {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IntegrationsLocation {
-    /**
-     * Name of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4286,7 +4862,7 @@ pub struct IntegrationsLocation {
     )]
     pub type__: String,
     /**
-     * Name of the channel
+     * Abbreviation of the location. Combination of nearest Airport Code and the sequence number.
      */
     #[serde(
         default,
@@ -4294,13 +4870,16 @@ pub struct IntegrationsLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub abbreviation: String,
+    /**
+     * Indicates whether or not the user is authorized to interact at all with the location
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub access_granted: bool,
     /**
-     * Array of permissions granted for the channel
+     * Available attributes for the location
      */
     #[serde(
         default,
@@ -4308,29 +4887,42 @@ pub struct IntegrationsLocation {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub attributes: Vec<String>,
+    /**
+     * Id of the location in ShipBob’s database
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
+    /**
+     * Indicates if the location is operationally active or inactive
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_active: bool,
+    /**
+     * Indicates if the receiving is enabled for FC
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_receiving_enabled: bool,
+    /**
+     * Indicates if the shipping is enabled for FC
+     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_shipping_enabled: bool,
     /**
-     * Name of the channel
+     * Name of the location. Follows the naming convention City (State Code)
+     *  for domestic FCs and City (Country Code) for international FCs
      */
     #[serde(
         default,
@@ -4350,7 +4942,7 @@ pub struct IntegrationsLocation {
     )]
     pub services: Vec<IntegrationsLocationService>,
     /**
-     * Name of the channel
+     * Time zone of the location
      */
     #[serde(
         default,
@@ -4362,9 +4954,6 @@ pub struct IntegrationsLocation {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IntegrationsLocationInternal {
-    /**
-     * True if the inventory item is marked as a digital item
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -30,6 +30,21 @@ impl Webhooks {
         page: u64,
         limit: i64,
     ) -> ClientResult<Vec<crate::types::Webhook>> {
+        Ok(self.get_page_with_response(topic, page, limit).await?.body)
+    }
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        topic: crate::types::WebhooksTopics,
+        page: u64,
+        limit: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Webhook>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if limit > 0 {
             query_args.push(("Limit".to_string(), limit.to_string()));
@@ -43,7 +58,7 @@ impl Webhooks {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/webhook?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -81,6 +96,72 @@ impl Webhooks {
             )
             .await
     }
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        topic: crate::types::WebhooksTopics,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Webhook>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !topic.to_string().is_empty() {
+            query_args.push(("Topic".to_string(), topic.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/webhook?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::Webhook>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        topic: crate::types::WebhooksTopics,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Webhook>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(topic, None))
+    }
     /**
      * Create a new webhook subscription.
      *
@@ -94,9 +175,22 @@ impl Webhooks {
         &self,
         body: &crate::types::WebhooksCreateWebhookSubscriptionModel,
     ) -> ClientResult<crate::types::Webhook> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * Create a new webhook subscription.
+     *
+     * This function performs a `POST` to the `/webhook` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::WebhooksCreateWebhookSubscriptionModel,
+    ) -> ClientResult<crate::Response<crate::types::Webhook>> {
         let url = self.client.url("/webhook", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -115,6 +209,17 @@ impl Webhooks {
      * * `id: i64` -- Unique id of the channel.
      */
     pub async fn delete(&self, id: i64) -> ClientResult<()> {
+        self.delete_with_response(id).await?;
+        Ok(())
+    }
+    /**
+     * Delete an existing webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/webhook/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, id: i64) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webhook/{}",
@@ -123,7 +228,7 @@ impl Webhooks {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
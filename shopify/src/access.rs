@@ -19,163 +19,20 @@ impl Access {
      * https://shopify.dev/docs/admin-api/rest/reference/access/accessscope#index-2020-10
      */
     pub async fn get_admin_oauth_scope(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/oauth/access_scopes.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of storefront access tokens that have been issued.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-01
-     */
-    pub async fn deprecated_202001_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-01
-     */
-    pub async fn deprecated_202001_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_storefront_tokens_param_token(
-        &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of storefront access tokens that have been issued.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-04
-     */
-    pub async fn deprecated_202004_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.get_admin_oauth_scope_with_response().await?;
+        Ok(())
     }
     /**
-     * Creates a new storefront access token.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-04
-     */
-    pub async fn deprecated_202004_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-04
+     * Retrieves a list of access scopes associated to the access token.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/oauth/access_scopes.json` endpoint.
      *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_admin_oauth_scope`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_delete_storefront_tokens_param_token(
-        &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
-            ),
-            None,
-        );
+    pub async fn get_admin_oauth_scope_with_response(&self) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url("/admin/oauth/access_scopes.json", None);
         self.client
-            .delete(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -187,93 +44,27 @@ impl Access {
     /**
      * Retrieves a list of storefront access tokens that have been issued.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/storefront_access_tokens.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/storefront_access_tokens.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-07
-     */
-    pub async fn deprecated_202007_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-07
-     */
-    pub async fn deprecated_202007_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-10
      */
-    pub async fn deprecated_202007_delete_storefront_tokens_param_token(
-        &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub async fn get_storefront_token(&self) -> ClientResult<()> {
+        self.get_storefront_token_with_response().await?;
+        Ok(())
     }
     /**
      * Retrieves a list of storefront access tokens that have been issued.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/storefront_access_tokens.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/storefront_access_tokens.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2020-10
+     * As opposed to `get_storefront_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_storefront_token(&self) -> ClientResult<()> {
+    pub async fn get_storefront_token_with_response(&self) -> ClientResult<crate::Response<()>> {
         let url = self
             .client
-            .url("/admin/api/2020-10/storefront_access_tokens.json", None);
+            .url("/admin/api/storefront_access_tokens.json", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -285,93 +76,30 @@ impl Access {
     /**
      * Creates a new storefront access token.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/storefront_access_tokens.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/storefront_access_tokens.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2020-10
      */
     pub async fn create_storefront_tokens(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes an existing storefront access token.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_storefront_tokens_param_token(
-        &self,
-        storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of storefront access tokens that have been issued.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-2021-01
-     */
-    pub async fn deprecated_202101_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.create_storefront_tokens_with_response(body).await?;
+        Ok(())
     }
     /**
      * Creates a new storefront access token.
      *
-     * This function performs a `POST` to the `/admin/api/2021-01/storefront_access_tokens.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/storefront_access_tokens.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-2021-01
+     * As opposed to `create_storefront_tokens`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_create_storefront_tokens(
+    pub async fn create_storefront_tokens_with_response(
         &self,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self
             .client
-            .url("/admin/api/2021-01/storefront_access_tokens.json", None);
+            .url("/admin/api/storefront_access_tokens.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -383,104 +111,42 @@ impl Access {
     /**
      * Deletes an existing storefront access token.
      *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-2020-10
      *
      * **Parameters:**
      *
      * * `storefront_access_token_id: &str` -- storefront_access_token_id.
      */
-    pub async fn deprecated_202101_delete_storefront_tokens_param_token(
+    pub async fn delete_storefront_tokens_param_token(
         &self,
         storefront_access_token_id: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/storefront_access_tokens/{}/json",
-                crate::progenitor_support::encode_path(storefront_access_token_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of storefront access tokens that have been issued.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#index-unstable
-     */
-    pub async fn deprecated_unstable_get_storefront_token(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/storefront_access_tokens.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new storefront access token.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/storefront_access_tokens.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#create-unstable
-     */
-    pub async fn deprecated_unstable_create_storefront_tokens(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/storefront_access_tokens.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        self.delete_storefront_tokens_param_token_with_response(storefront_access_token_id)
+            .await?;
+        Ok(())
     }
     /**
      * Deletes an existing storefront access token.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/access/storefrontaccesstoken#destroy-unstable
+     * This function performs a `DELETE` to the `/admin/api/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
      *
-     * **Parameters:**
-     *
-     * * `storefront_access_token_id: &str` -- storefront_access_token_id.
+     * As opposed to `delete_storefront_tokens_param_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_unstable_delete_storefront_tokens_param_token(
+    pub async fn delete_storefront_tokens_param_token_with_response(
         &self,
         storefront_access_token_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/storefront_access_tokens/{}/json",
+                "/admin/api/storefront_access_tokens/{}.json",
                 crate::progenitor_support::encode_path(storefront_access_token_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -14,9 +14,9 @@ impl Customers {
     /**
      * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/customers.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-10
      *
      * **Parameters:**
      *
@@ -27,10 +27,11 @@ impl Customers {
      * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
      * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
      * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
+     *   (default: 50, maximum: 250).
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202001_get(
+    pub async fn get_page(
         &self,
         ids: &str,
         since_id: &str,
@@ -40,7 +41,42 @@ impl Customers {
         updated_at_max: &str,
         limit: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Customer>> {
+        Ok(self
+            .get_page_with_response(
+                ids,
+                since_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                limit,
+                fields,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers.json` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Customer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !created_at_max.is_empty() {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
@@ -57,6 +93,9 @@ impl Customers {
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
@@ -67,31 +106,264 @@ impl Customers {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/customers.json?{}", query_),
-            None,
+        let url = self
+            .client
+            .url(&format!("/admin/api/customers.json?{}", query_), None);
+        let resp: crate::Response<crate::types::CustomersRoot> = self
+            .client
+            .get_with_response(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.customers.to_vec()))
+    }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers.json` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-10
+     */
+    pub async fn get_all(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Customer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/customers.json?{}", query_), None);
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
         );
-        self.client
-            .get(
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::CustomersRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut customers = resp.customers;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::CustomersRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            customers.append(&mut resp.customers);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(customers)
+    }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers.json` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Customer>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/customers.json?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::CustomersRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.customers;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers.json` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Customer>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            fields,
+            None,
+        ))
+    }
+    /**
+     * Creates a customer.
+     *
+     * This function performs a `POST` to the `/admin/api/customers.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#create-2020-10
+     */
+    pub async fn create(
+        &self,
+        body: &crate::types::CustomerRequestRoot,
+    ) -> ClientResult<crate::types::CustomerRoot> {
+        Ok(self.create_with_response(body).await?.body)
     }
     /**
      * Creates a customer.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/customers.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/customers.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#create-2020-01
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202001_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/customers.json", None);
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::CustomerRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomerRoot>> {
+        let url = self.client.url("/admin/api/customers.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -103,26 +375,48 @@ impl Customers {
     /**
      * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/customers/search.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/search.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-10
      *
      * **Parameters:**
      *
      * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
+     *   (default: last_order_date DESC).
      * * `query: &str` -- Text to search for in the shop's customer data.
      * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
+     *   (default: 50, maximum: 250).
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202001_get_search(
+    pub async fn get_search(
         &self,
         order: &str,
         query: &str,
         limit: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Customer>> {
+        Ok(self
+            .get_search_with_response(order, query, limit, fields, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers/search.json` endpoint.
+     *
+     * As opposed to `get_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_search_with_response(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Customer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -133,4100 +427,251 @@ impl Customers {
         if !order.is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-01/customers/search.json?{}", query_),
+            &format!("/admin/api/customers/search.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::CustomersRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.customers.to_vec()))
     }
     /**
-     * Retrieves a single customer.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customers/{customer_id}.json` endpoint.
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-01
+     * This function performs a `GET` to the `/admin/api/customers/search.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_search`, this function returns all the pages of the request at once.
      *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-10
      */
-    pub async fn deprecated_202001_get_param(
+    pub async fn get_all_search(
         &self,
-        customer_id: &str,
+        order: &str,
+        query: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
+        if !order.is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
+            &format!("/admin/api/customers/search.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::CustomersRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut customers = resp.customers;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::CustomersRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            customers.append(&mut resp.customers);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(customers)
     }
     /**
-     * Updates a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#update-2020-01
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/customers/search.json` endpoint.
      *
-     * * `customer_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_all_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202001_update_param(
+    pub fn get_all_search_pages_stream(
         &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        order: &str,
+        query: &str,
+        fields: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Customer>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
+            &format!("/admin/api/customers/search.json?{}", query_),
             None,
         );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::CustomersRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.customers;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers/search.json` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_stream(
+        &self,
+        order: &str,
+        query: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Customer>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_pages_stream(order, query, fields, None))
+    }
+    /**
+     * Retrieves a single customer.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/customers/{customer_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#destroy-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_id: &str` -- storefront_access_token_id.
+     * * `customer_id: &str` -- customer_id.
+     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_delete_param(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
-                The account activation URL generated by this endpoint is for one-time use and will expire after 30 days. If you make a new POST request to this endpoint, then a new URL will be generated. The new URL will be again valid for 30 days, but the previous URL will no longer be valid.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/customers/{customer_id}/account_activation_url.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#account_activation_url-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `customer_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202001_create_param_account_activation_url(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/account_activation_url.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Sends an account invite to a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/customers/{customer_id}/send_invite.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#send_invite-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_param_send_invite(
+    pub async fn get_param(
         &self,
         customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/send_invite.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customers.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customers/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#count-2020-01
-     */
-    pub async fn deprecated_202001_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/customers/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customers/{customer_id}/orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_param_order(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/orders.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get(
-        &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/customers.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#create-2020-04
-     */
-    pub async fn deprecated_202004_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/customers.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customers/search.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_search(
-        &self,
-        order: &str,
-        query: &str,
-        limit: &str,
         fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/customers/search.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    ) -> ClientResult<crate::types::CustomerRoot> {
+        Ok(self
+            .get_param_with_response(customer_id, fields)
+            .await?
+            .body)
     }
     /**
      * Retrieves a single customer.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_param(
-        &self,
-        customer_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/customers/{customer_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_update_param(
+    pub async fn get_param_with_response(
         &self,
         customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a customer. A customer can't be deleted if they have existing orders.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_param(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
-                The account activation URL generated by this endpoint is for one-time use and will expire after 30 days. If you make a new POST request to this endpoint, then a new URL will be generated. The new URL will be again valid for 30 days, but the previous URL will no longer be valid.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/customers/{customer_id}/account_activation_url.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#account_activation_url-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `customer_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_create_param_account_activation_url(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/account_activation_url.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Sends an account invite to a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/customers/{customer_id}/send_invite.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#send_invite-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_param_send_invite(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/send_invite.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customers.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customers/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#count-2020-04
-     */
-    pub async fn deprecated_202004_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/customers/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customers/{customer_id}/orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_param_order(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/orders.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get(
-        &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/customers.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#create-2020-07
-     */
-    pub async fn deprecated_202007_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/customers.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customers/search.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_search(
-        &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/customers/search.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single customer.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_param(
-        &self,
-        customer_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_param(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a customer. A customer can't be deleted if they have existing orders.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_param(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
-                The account activation URL generated by this endpoint is for one-time use and will expire after 30 days. If you make a new POST request to this endpoint, then a new URL will be generated. The new URL will be again valid for 30 days, but the previous URL will no longer be valid.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/customers/{customer_id}/account_activation_url.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#account_activation_url-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `customer_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_create_param_account_activation_url(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/account_activation_url.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Sends an account invite to a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/customers/{customer_id}/send_invite.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#send_invite-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_param_send_invite(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/send_invite.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customers.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customers/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#count-2020-07
-     */
-    pub async fn deprecated_202007_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/customers/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customers/{customer_id}/orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_param_order(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/orders.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get(
-        &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/customers.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#create-2020-10
-     */
-    pub async fn create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/customers.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customers/search.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_search(
-        &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/customers/search.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single customer.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_param(&self, customer_id: &str, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_param(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a customer. A customer can't be deleted if they have existing orders.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_param(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
-                The account activation URL generated by this endpoint is for one-time use and will expire after 30 days. If you make a new POST request to this endpoint, then a new URL will be generated. The new URL will be again valid for 30 days, but the previous URL will no longer be valid.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/customers/{customer_id}/account_activation_url.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#account_activation_url-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `customer_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn create_param_account_activation_url(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/account_activation_url.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Sends an account invite to a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/customers/{customer_id}/send_invite.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#send_invite-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_param_send_invite(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/send_invite.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customers.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customers/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#count-2020-10
-     */
-    pub async fn get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/customers/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customers/{customer_id}/orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_param_order(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/orders.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get(
-        &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/customers.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#create-2021-01
-     */
-    pub async fn deprecated_202101_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/customers.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/customers/search.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_search(
-        &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/customers/search.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single customer.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_param(
-        &self,
-        customer_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_param(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a customer. A customer can't be deleted if they have existing orders.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_param(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
-                The account activation URL generated by this endpoint is for one-time use and will expire after 30 days. If you make a new POST request to this endpoint, then a new URL will be generated. The new URL will be again valid for 30 days, but the previous URL will no longer be valid.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/customers/{customer_id}/account_activation_url.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#account_activation_url-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `customer_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_create_param_account_activation_url(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/account_activation_url.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Sends an account invite to a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/customers/{customer_id}/send_invite.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#send_invite-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_param_send_invite(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/send_invite.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customers.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/customers/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#count-2021-01
-     */
-    pub async fn deprecated_202101_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/customers/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/customers/{customer_id}/orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_param_order(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/orders.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get(
-        &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/customers.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#create-unstable
-     */
-    pub async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/customers.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Searches for customers that match a supplied query. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/customers/search.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#search-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_search(
-        &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/customers/search.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single customer.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_param(
-        &self,
-        customer_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_param(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a customer. A customer can't be deleted if they have existing orders.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/customers/{customer_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_param(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
-                The account activation URL generated by this endpoint is for one-time use and will expire after 30 days. If you make a new POST request to this endpoint, then a new URL will be generated. The new URL will be again valid for 30 days, but the previous URL will no longer be valid.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/customers/{customer_id}/account_activation_url.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#account_activation_url-unstable
-    *
-    * **Parameters:**
-    *
-    * * `customer_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_create_param_account_activation_url(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/account_activation_url.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Sends an account invite to a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/customers/{customer_id}/send_invite.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#send_invite-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_param_send_invite(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/send_invite.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customers.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/customers/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#count-unstable
-     */
-    pub async fn deprecated_unstable_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/customers/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/customers/{customer_id}/orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_param_order(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/orders.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_param_addresse(
-        &self,
-        customer_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new address for a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#create-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_param_addresses(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves details a single customer address.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing customer address.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes an address from a customer’s address list.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Performs bulk operations for multiple customer addresses.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/customers/{customer_id}/addresses/set.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_param_addresses_set(
-        &self,
-        customer_id: &str,
-        address_ids: i64,
-        operation: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
-        }
-        if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/addresses/set.json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Sets the default address for a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#default-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_param_addresses_address_default(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customers/{}/addresses/{}/default.json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_param_addresse(
-        &self,
-        customer_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new address for a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#create-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_param_addresses(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves details a single customer address.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing customer address.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes an address from a customer’s address list.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Performs bulk operations for multiple customer addresses.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/customers/{customer_id}/addresses/set.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_param_addresses_set(
-        &self,
-        customer_id: &str,
-        address_ids: i64,
-        operation: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
-        }
-        if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/addresses/set.json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Sets the default address for a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#default-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_param_addresses_address_default(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customers/{}/addresses/{}/default.json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_param_addresse(
-        &self,
-        customer_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new address for a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#create-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_param_addresses(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves details a single customer address.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing customer address.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes an address from a customer’s address list.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Performs bulk operations for multiple customer addresses.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/customers/{customer_id}/addresses/set.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_param_addresses_set(
-        &self,
-        customer_id: &str,
-        address_ids: i64,
-        operation: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
-        }
-        if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/addresses/set.json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Sets the default address for a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#default-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_param_addresses_address_default(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customers/{}/addresses/{}/default.json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_param_addresse(&self, customer_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new address for a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#create-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_param_addresses(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves details a single customer address.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing customer address.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes an address from a customer’s address list.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Performs bulk operations for multiple customer addresses.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/customers/{customer_id}/addresses/set.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_param_addresses_set(
-        &self,
-        customer_id: &str,
-        address_ids: i64,
-        operation: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
-        }
-        if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/addresses/set.json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Sets the default address for a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#default-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_param_addresses_address_default(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/customers/{}/addresses/{}/default.json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_param_addresse(
-        &self,
-        customer_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new address for a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#create-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_param_addresses(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves details a single customer address.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing customer address.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes an address from a customer’s address list.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Performs bulk operations for multiple customer addresses.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/customers/{customer_id}/addresses/set.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_param_addresses_set(
-        &self,
-        customer_id: &str,
-        address_ids: i64,
-        operation: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
-        }
-        if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/addresses/set.json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Sets the default address for a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#default-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_param_addresses_address_default(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customers/{}/addresses/{}/default.json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_param_addresse(
-        &self,
-        customer_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new address for a customer.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/customers/{customer_id}/addresses.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#create-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_param_addresses(
-        &self,
-        customer_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/addresses.json",
-                crate::progenitor_support::encode_path(customer_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves details a single customer address.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing customer address.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes an address from a customer’s address list.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_param_addresses_address(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/addresses/{}/json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Performs bulk operations for multiple customer addresses.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/customers/{customer_id}/addresses/set.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_param_addresses_set(
-        &self,
-        customer_id: &str,
-        address_ids: i64,
-        operation: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
-        }
-        if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/addresses/set.json?{}",
-                crate::progenitor_support::encode_path(customer_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Sets the default address for a customer.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#default-unstable
-     *
-     * **Parameters:**
-     *
-     * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_param_addresses_address_default(
-        &self,
-        customer_id: &str,
-        address_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customers/{}/addresses/{}/default.json",
-                crate::progenitor_support::encode_path(customer_id),
-                crate::progenitor_support::encode_path(address_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_saved_searche(
-        &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/customer_saved_searches.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a customer saved search.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/customer_saved_searches.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#create-2020-01
-     */
-    pub async fn deprecated_202001_create_saved_searches(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/customer_saved_searches.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customer saved searches.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn deprecated_202001_get_saved_searches_count(
-        &self,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customer_saved_searches/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single customer saved search.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_saved_searches_param_search(
-        &self,
-        customer_saved_search_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customer_saved_searches/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a customer saved search.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_saved_searches_param_search(
-        &self,
-        customer_saved_search_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a customer saved search.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_saved_searches_param_search(
-        &self,
-        customer_saved_search_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all customers returned by a customer saved search.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_saved_searches_param_search_customers(
-        &self,
-        customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/customer_saved_searches/{}/customers.json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_saved_searche(
-        &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/customer_saved_searches.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a customer saved search.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/customer_saved_searches.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#create-2020-04
-     */
-    pub async fn deprecated_202004_create_saved_searches(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/customer_saved_searches.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of all customer saved searches.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn deprecated_202004_get_saved_searches_count(
-        &self,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customer_saved_searches/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single customer saved search.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_saved_searches_param_search(
-        &self,
-        customer_saved_search_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customer_saved_searches/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a customer saved search.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_saved_searches_param_search(
-        &self,
-        customer_saved_search_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a customer saved search.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_saved_searches_param_search(
-        &self,
-        customer_saved_search_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves all customers returned by a customer saved search.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_saved_searches_param_search_customers(
-        &self,
-        customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/customer_saved_searches/{}/customers.json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_saved_searche(
-        &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerRoot>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-07/customer_saved_searches.json?{}", query_),
+            &format!(
+                "/admin/api/customers/{}.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4236,98 +681,90 @@ impl Customers {
             .await
     }
     /**
-     * Creates a customer saved search.
+     * Updates a customer.
+     *
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#update-2020-10
      *
-     * This function performs a `POST` to the `/admin/api/2020-07/customer_saved_searches.json` endpoint.
+     * **Parameters:**
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#create-2020-07
+     * * `customer_id: &str` -- customer_id.
      */
-    pub async fn deprecated_202007_create_saved_searches(
+    pub async fn update_param(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/customer_saved_searches.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        customer_id: &str,
+        body: &crate::types::CustomerRequestRoot,
+    ) -> ClientResult<crate::types::CustomerRoot> {
+        Ok(self
+            .update_param_with_response(customer_id, body)
+            .await?
+            .body)
     }
     /**
-     * Retrieves a count of all customer saved searches.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-07
+     * Updates a customer.
      *
-     * **Parameters:**
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}.json` endpoint.
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * As opposed to `update_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_saved_searches_count(
+    pub async fn update_param_with_response(
         &self,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        customer_id: &str,
+        body: &crate::types::CustomerRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomerRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/customer_saved_searches/count.json?{}",
-                query_
+                "/admin/api/customers/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
         self.client
-            .get(
+            .put_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a single customer saved search.
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/customers/{customer_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `customer_id: &str` -- customer_id.
+     */
+    pub async fn delete_param(&self, customer_id: &str) -> ClientResult<()> {
+        self.delete_param_with_response(customer_id).await?;
+        Ok(())
+    }
+    /**
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     *
+     * This function performs a `DELETE` to the `/admin/api/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `delete_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_saved_searches_param_search(
+    pub async fn delete_param_with_response(
         &self,
-        customer_saved_search_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        customer_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/customer_saved_searches/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
+                "/admin/api/customers/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
         self.client
-            .get(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4337,30 +774,47 @@ impl Customers {
             .await
     }
     /**
-     * Updates a customer saved search.
+     * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
+     * The account activation URL generated by this endpoint is for one-time use and will expire after 30 days. If you make a new POST request to this endpoint, then a new URL will be generated. The new URL will be again valid for 30 days, but the previous URL will no longer be valid.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/customers/{customer_id}/account_activation_url.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#update-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#account_activation_url-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * * `customer_id: &str` -- customer_id.
      */
-    pub async fn deprecated_202007_update_saved_searches_param_search(
+    pub async fn create_param_account_activation_url(
         &self,
-        customer_saved_search_id: &str,
+        customer_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.create_param_account_activation_url_with_response(customer_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Generate an account activation URL for a customer whose account is not yet enabled. This is useful when you've imported a large number of customers and want to send them activation emails all at once. Using this approach, you'll need to generate and send the activation emails yourself.
+     *
+     * This function performs a `POST` to the `/admin/api/customers/{customer_id}/account_activation_url.json` endpoint.
+     *
+     * As opposed to `create_param_account_activation_url`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_param_account_activation_url_with_response(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
+                "/admin/api/customers/{}/account_activation_url.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
         self.client
-            .put(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4370,81 +824,77 @@ impl Customers {
             .await
     }
     /**
-     * Deletes a customer saved search.
+     * Sends an account invite to a customer.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/customers/{customer_id}/send_invite.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#destroy-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#send_invite-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * * `customer_id: &str` -- customer_id.
      */
-    pub async fn deprecated_202007_delete_saved_searches_param_search(
+    pub async fn create_param_send_invite(
         &self,
-        customer_saved_search_id: &str,
+        customer_id: &str,
+        body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.create_param_send_invite_with_response(customer_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Sends an account invite to a customer.
+     *
+     * This function performs a `POST` to the `/admin/api/customers/{customer_id}/send_invite.json` endpoint.
+     *
+     * As opposed to `create_param_send_invite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_param_send_invite_with_response(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
+                "/admin/api/customers/{}/send_invite.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves all customers returned by a customer saved search.
+     * Retrieves a count of all customers.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#count-2020-10
+     */
+    pub async fn get_count(&self) -> ClientResult<crate::types::Count> {
+        Ok(self.get_count_with_response().await?.body)
+    }
+    /**
+     * Retrieves a count of all customers.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/customers/count.json` endpoint.
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * As opposed to `get_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_saved_searches_param_search_customers(
+    pub async fn get_count_with_response(
         &self,
-        customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/customer_saved_searches/{}/customers.json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
-            ),
-            None,
-        );
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
+        let url = self.client.url("/admin/api/customers/count.json", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4454,299 +904,516 @@ impl Customers {
             .await
     }
     /**
-     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/orders.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-2020-10
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `customer_id: &str` -- customer_id.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn get_saved_searche(
+    pub async fn get_param_orders(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+        customer_id: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Order>> {
+        Ok(self
+            .get_param_orders_with_response(customer_id, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
+     *
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/orders.json` endpoint.
+     *
+     * As opposed to `get_param_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_param_orders_with_response(
+        &self,
+        customer_id: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-10/customer_saved_searches.json?{}", query_),
+            &format!(
+                "/admin/api/customers/{}/orders.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
+                query_
+            ),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::OrdersRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.orders.to_vec()))
     }
     /**
-     * Creates a customer saved search.
+     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/customer_saved_searches.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/orders.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#create-2020-10
+     * As opposed to `get_param_orders`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer#orders-2020-10
      */
-    pub async fn create_saved_searches(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
+    pub async fn get_all_param_orders(
+        &self,
+        customer_id: &str,
+    ) -> ClientResult<Vec<crate::types::Order>> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/customers/{}/orders.json",
+                crate::progenitor_support::encode_path(customer_id),
+            ),
+            None,
+        );
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::OrdersRoot) = self
             .client
-            .url("/admin/api/2020-10/customer_saved_searches.json", None);
-        self.client
-            .post(
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut orders = resp.orders;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (Option<crate::utils::NextLink>, crate::types::OrdersRoot) =
+                self.client
+                    .request_with_links(
+                        reqwest::Method::GET,
+                        &next.0,
+                        crate::Message {
+                            body: None,
+                            content_type: None,
+                        },
+                    )
+                    .await?;
+
+            orders.append(&mut resp.orders);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(orders)
     }
     /**
-     * Retrieves a count of all customer saved searches.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-10
+     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/orders.json` endpoint.
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * As opposed to `get_all_param_orders`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn get_saved_searches_count(&self, since_id: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    pub fn get_all_param_orders_pages_stream(
+        &self,
+        customer_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Order>>> + Unpin + '_
+    {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/customer_saved_searches/count.json?{}",
-                query_
+                "/admin/api/customers/{}/orders.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (Option<crate::utils::NextLink>, crate::types::OrdersRoot) =
+                        self.client
+                            .request_with_links(
+                                reqwest::Method::GET,
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.orders;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Retrieves a single customer saved search.
+     * Retrieves all orders belonging to a customer. The query string parameters that are available to the  Order resource are also available to this endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/orders.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-10
+     * As opposed to `get_all_param_orders`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_param_orders_stream(
+        &self,
+        customer_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Order>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_param_orders_pages_stream(customer_id, None))
+    }
+    /**
+     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/addresses.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `customer_id: &str` -- customer_id.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn get_saved_searches_param_search(
+    pub async fn get_param_addresses(
         &self,
-        customer_saved_search_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+        customer_id: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Address>> {
+        Ok(self
+            .get_param_addresses_with_response(customer_id, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/addresses.json` endpoint.
+     *
+     * As opposed to `get_param_addresses`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_param_addresses_with_response(
+        &self,
+        customer_id: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Address>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/customer_saved_searches/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
+                "/admin/api/customers/{}/addresses.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::CustomerAddressesRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.addresses.to_vec()))
     }
     /**
-     * Updates a customer saved search.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#update-2020-10
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/addresses.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_param_addresses`, this function returns all the pages of the request at once.
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#index-2020-10
      */
-    pub async fn update_saved_searches_param_search(
+    pub async fn get_all_param_addresses(
         &self,
-        customer_saved_search_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        customer_id: &str,
+    ) -> ClientResult<Vec<crate::types::Address>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
+                "/admin/api/customers/{}/addresses.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
-        self.client
-            .put(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::CustomerAddressesRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut addresses = resp.addresses;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::CustomerAddressesRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            addresses.append(&mut resp.addresses);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(addresses)
     }
     /**
-     * Deletes a customer saved search.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#destroy-2020-10
+     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/addresses.json` endpoint.
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_all_param_addresses`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn delete_saved_searches_param_search(
+    pub fn get_all_param_addresses_pages_stream(
         &self,
-        customer_saved_search_id: &str,
-    ) -> ClientResult<()> {
+        customer_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Address>>> + Unpin + '_
+    {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
+                "/admin/api/customers/{}/addresses.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::CustomerAddressesRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.addresses;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Retrieves all customers returned by a customer saved search.
+     * Retrieves a list of addresses for a customer. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/addresses.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-10
+     * As opposed to `get_all_param_addresses`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_param_addresses_stream(
+        &self,
+        customer_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Address>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_param_addresses_pages_stream(customer_id, None))
+    }
+    /**
+     * Creates a new address for a customer.
+     *
+     * This function performs a `POST` to the `/admin/api/customers/{customer_id}/addresses.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#create-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `customer_id: &str` -- customer_id.
      */
-    pub async fn get_saved_searches_param_search_customers(
+    pub async fn create_param_addresses(
         &self,
-        customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        customer_id: &str,
+        body: &crate::types::CustomerAddressRequestRoot,
+    ) -> ClientResult<crate::types::CustomerAddressRoot> {
+        Ok(self
+            .create_param_addresses_with_response(customer_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a new address for a customer.
+     *
+     * This function performs a `POST` to the `/admin/api/customers/{customer_id}/addresses.json` endpoint.
+     *
+     * As opposed to `create_param_addresses`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_param_addresses_with_response(
+        &self,
+        customer_id: &str,
+        body: &crate::types::CustomerAddressRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomerAddressRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/customer_saved_searches/{}/customers.json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
+                "/admin/api/customers/{}/addresses.json",
+                crate::progenitor_support::encode_path(customer_id),
             ),
             None,
         );
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Retrieves details a single customer address.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/addresses/{address_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#show-2020-10
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `customer_id: &str` -- customer_id.
+     * * `address_id: &str` -- address_id.
      */
-    pub async fn deprecated_202101_get_saved_searche(
+    pub async fn get_param_addresses_address(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::types::CustomerAddressRoot> {
+        Ok(self
+            .get_param_addresses_address_with_response(customer_id, address_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves details a single customer address.
+     *
+     * This function performs a `GET` to the `/admin/api/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `get_param_addresses_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_param_addresses_address_with_response(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerAddressRoot>> {
         let url = self.client.url(
-            &format!("/admin/api/2021-01/customer_saved_searches.json?{}", query_),
+            &format!(
+                "/admin/api/customers/{}/addresses/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id),
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4756,21 +1423,51 @@ impl Customers {
             .await
     }
     /**
-     * Creates a customer saved search.
+     * Updates an existing customer address.
+     *
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#update-2020-10
      *
-     * This function performs a `POST` to the `/admin/api/2021-01/customer_saved_searches.json` endpoint.
+     * **Parameters:**
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#create-2021-01
+     * * `customer_id: &str` -- customer_id.
+     * * `address_id: &str` -- address_id.
      */
-    pub async fn deprecated_202101_create_saved_searches(
+    pub async fn update_param_addresses_address(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/customer_saved_searches.json", None);
+        customer_id: &str,
+        address_id: &str,
+        body: &crate::types::CustomerAddressRequestRoot,
+    ) -> ClientResult<crate::types::CustomerAddressRoot> {
+        Ok(self
+            .update_param_addresses_address_with_response(customer_id, address_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an existing customer address.
+     *
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `update_param_addresses_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_param_addresses_address_with_response(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &crate::types::CustomerAddressRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomerAddressRoot>> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/customers/{}/addresses/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id),
+            ),
+            None,
+        );
         self.client
-            .post(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -4780,34 +1477,48 @@ impl Customers {
             .await
     }
     /**
-     * Retrieves a count of all customer saved searches.
+     * Removes an address from a customer’s address list.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches/count.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/customers/{customer_id}/addresses/{address_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `customer_id: &str` -- customer_id.
+     * * `address_id: &str` -- address_id.
      */
-    pub async fn deprecated_202101_get_saved_searches_count(
+    pub async fn delete_param_addresses_address(
         &self,
-        since_id: &str,
+        customer_id: &str,
+        address_id: &str,
     ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        self.delete_param_addresses_address_with_response(customer_id, address_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Removes an address from a customer’s address list.
+     *
+     * This function performs a `DELETE` to the `/admin/api/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `delete_param_addresses_address`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_param_addresses_address_with_response(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/customer_saved_searches/count.json?{}",
-                query_
+                "/admin/api/customers/{}/addresses/{}.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id),
             ),
             None,
         );
         self.client
-            .get(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4817,37 +1528,59 @@ impl Customers {
             .await
     }
     /**
-     * Retrieves a single customer saved search.
+     * Performs bulk operations for multiple customer addresses.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}/addresses/set.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#set-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `customer_id: &str` -- customer_id.
+     * * `address_ids: i64` -- address_ids[].
+     * * `operation: &str` -- operation.
      */
-    pub async fn deprecated_202101_get_saved_searches_param_search(
+    pub async fn update_param_addresses_set(
         &self,
-        customer_saved_search_id: &str,
-        fields: &str,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
     ) -> ClientResult<()> {
+        self.update_param_addresses_set_with_response(customer_id, address_ids, operation)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Performs bulk operations for multiple customer addresses.
+     *
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}/addresses/set.json` endpoint.
+     *
+     * As opposed to `update_param_addresses_set`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_param_addresses_set_with_response(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+        if address_ids > 0 {
+            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+        }
+        if !operation.is_empty() {
+            query_args.push(("operation".to_string(), operation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/customer_saved_searches/{}/json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
+                "/admin/api/customers/{}/addresses/set.json?{}",
+                crate::progenitor_support::encode_path(customer_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4857,62 +1590,49 @@ impl Customers {
             .await
     }
     /**
-     * Updates a customer saved search.
+     * Sets the default address for a customer.
      *
-     * This function performs a `PUT` to the `/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#update-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customer-address#default-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * * `customer_id: &str` -- customer_id.
+     * * `address_id: &str` -- address_id.
      */
-    pub async fn deprecated_202101_update_saved_searches_param_search(
+    pub async fn update_param_addresses_address_default(
         &self,
-        customer_saved_search_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::types::CustomerAddressRoot> {
+        Ok(self
+            .update_param_addresses_address_default_with_response(customer_id, address_id)
+            .await?
+            .body)
     }
     /**
-     * Deletes a customer saved search.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#destroy-2021-01
+     * Sets the default address for a customer.
      *
-     * **Parameters:**
+     * This function performs a `PUT` to the `/admin/api/customers/{customer_id}/addresses/{address_id}/default.json` endpoint.
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * As opposed to `update_param_addresses_address_default`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_delete_saved_searches_param_search(
+    pub async fn update_param_addresses_address_default_with_response(
         &self,
-        customer_saved_search_id: &str,
-    ) -> ClientResult<()> {
+        customer_id: &str,
+        address_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerAddressRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/customer_saved_searches/{}/json",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
+                "/admin/api/customers/{}/addresses/{}/default.json",
+                crate::progenitor_support::encode_path(customer_id),
+                crate::progenitor_support::encode_path(address_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -4922,28 +1642,46 @@ impl Customers {
             .await
     }
     /**
-     * Retrieves all customers returned by a customer saved search.
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
      * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
+     *   (default: 50, maximum: 250).
+     * * `since_id: &str` -- Restrict results to after the specified ID.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202101_get_saved_searches_param_search_customers(
+    pub async fn get_saved_searches(
         &self,
-        customer_saved_search_id: &str,
-        order: &str,
         limit: &str,
+        since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::CustomerSavedSearch>> {
+        Ok(self
+            .get_saved_searches_with_response(limit, since_id, fields, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `get_saved_searches`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_saved_searches_with_response(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerSavedSearch>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -4951,92 +1689,228 @@ impl Customers {
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/customer_saved_searches/{}/customers.json?{}",
-                crate::progenitor_support::encode_path(customer_saved_search_id),
-                query_
-            ),
+            &format!("/admin/api/customer_saved_searches.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::CustomerSavedSearchesRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.customer_saved_searches.to_vec()))
     }
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-unstable
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_saved_searches`, this function returns all the pages of the request at once.
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#index-2020-10
      */
-    pub async fn deprecated_unstable_get_saved_searche(
+    pub async fn get_all_saved_searches(
         &self,
-        limit: &str,
         since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<Vec<crate::types::CustomerSavedSearch>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customer_saved_searches.json?{}",
-                query_
-            ),
+            &format!("/admin/api/customer_saved_searches.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::CustomerSavedSearchesRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut customer_saved_searches = resp.customer_saved_searches;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::CustomerSavedSearchesRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            customer_saved_searches.append(&mut resp.customer_saved_searches);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(customer_saved_searches)
+    }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `get_all_saved_searches`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_saved_searches_pages_stream(
+        &self,
+        since_id: &str,
+        fields: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CustomerSavedSearch>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/admin/api/customer_saved_searches.json?{}", query_),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::CustomerSavedSearchesRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.customer_saved_searches;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `get_all_saved_searches`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_saved_searches_stream(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CustomerSavedSearch>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_saved_searches_pages_stream(since_id, fields, None))
     }
     /**
      * Creates a customer saved search.
      *
-     * This function performs a `POST` to the `/admin/api/unstable/customer_saved_searches.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/customer_saved_searches.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#create-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#create-2020-10
      */
-    pub async fn deprecated_unstable_create_saved_searches(
+    pub async fn create_saved_searches(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::CustomerSavedSearchRequestRoot,
+    ) -> ClientResult<crate::types::CustomerSavedSearchRoot> {
+        Ok(self.create_saved_searches_with_response(body).await?.body)
+    }
+    /**
+     * Creates a customer saved search.
+     *
+     * This function performs a `POST` to the `/admin/api/customer_saved_searches.json` endpoint.
+     *
+     * As opposed to `create_saved_searches`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_saved_searches_with_response(
+        &self,
+        body: &crate::types::CustomerSavedSearchRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomerSavedSearchRoot>> {
         let url = self
             .client
-            .url("/admin/api/unstable/customer_saved_searches.json", None);
+            .url("/admin/api/customer_saved_searches.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5048,32 +1922,45 @@ impl Customers {
     /**
      * Retrieves a count of all customer saved searches.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#count-2020-10
      *
      * **Parameters:**
      *
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
-    pub async fn deprecated_unstable_get_saved_searches_count(
+    pub async fn get_saved_searches_count(
         &self,
         since_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_saved_searches_count_with_response(since_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a count of all customer saved searches.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/count.json` endpoint.
+     *
+     * As opposed to `get_saved_searches_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_saved_searches_count_with_response(
+        &self,
+        since_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/customer_saved_searches/count.json?{}",
-                query_
-            ),
+            &format!("/admin/api/customer_saved_searches/count.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5085,20 +1972,37 @@ impl Customers {
     /**
      * Retrieves a single customer saved search.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#show-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * * `customer_saved_search_id: &str` -- customer_saved_search_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_unstable_get_saved_searches_param_search(
+    pub async fn get_saved_searches_param_search(
         &self,
         customer_saved_search_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CustomerSavedSearchRoot> {
+        Ok(self
+            .get_saved_searches_param_search_with_response(customer_saved_search_id, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `get_saved_searches_param_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_saved_searches_param_search_with_response(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerSavedSearchRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -5106,14 +2010,14 @@ impl Customers {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/customer_saved_searches/{}/json?{}",
+                "/admin/api/customer_saved_searches/{}.json?{}",
                 crate::progenitor_support::encode_path(customer_saved_search_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5125,28 +2029,45 @@ impl Customers {
     /**
      * Updates a customer saved search.
      *
-     * This function performs a `PUT` to the `/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#update-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#update-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * * `customer_saved_search_id: &str` -- customer_saved_search_id.
      */
-    pub async fn deprecated_unstable_update_saved_searches_param_search(
+    pub async fn update_saved_searches_param_search(
         &self,
         customer_saved_search_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::CustomerSavedSearchRequestRoot,
+    ) -> ClientResult<crate::types::CustomerSavedSearchRoot> {
+        Ok(self
+            .update_saved_searches_param_search_with_response(customer_saved_search_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a customer saved search.
+     *
+     * This function performs a `PUT` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `update_saved_searches_param_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_saved_searches_param_search_with_response(
+        &self,
+        customer_saved_search_id: &str,
+        body: &crate::types::CustomerSavedSearchRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomerSavedSearchRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/customer_saved_searches/{}/json",
+                "/admin/api/customer_saved_searches/{}.json",
                 crate::progenitor_support::encode_path(customer_saved_search_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -5158,27 +2079,42 @@ impl Customers {
     /**
      * Deletes a customer saved search.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#destroy-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * * `customer_saved_search_id: &str` -- customer_saved_search_id.
      */
-    pub async fn deprecated_unstable_delete_saved_searches_param_search(
+    pub async fn delete_saved_searches_param_search(
         &self,
         customer_saved_search_id: &str,
     ) -> ClientResult<()> {
+        self.delete_saved_searches_param_search_with_response(customer_saved_search_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a customer saved search.
+     *
+     * This function performs a `DELETE` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `delete_saved_searches_param_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_saved_searches_param_search_with_response(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/customer_saved_searches/{}/json",
+                "/admin/api/customer_saved_searches/{}.json",
                 crate::progenitor_support::encode_path(customer_saved_search_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -5190,26 +2126,54 @@ impl Customers {
     /**
      * Retrieves all customers returned by a customer saved search.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-10
      *
      * **Parameters:**
      *
-     * * `customer_saved_search_id: &str` -- storefront_access_token_id.
+     * * `customer_saved_search_id: &str` -- customer_saved_search_id.
      * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
+     *   (default: last_order_date DESC).
      * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
+     *   (default: 50, maximum: 250).
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_unstable_get_saved_searches_param_search_customers(
+    pub async fn get_saved_searches_param_search_customers(
         &self,
         customer_saved_search_id: &str,
         order: &str,
         limit: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Customer>> {
+        Ok(self
+            .get_saved_searches_param_search_customers_with_response(
+                customer_saved_search_id,
+                order,
+                limit,
+                fields,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `get_saved_searches_param_search_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_saved_searches_param_search_customers_with_response(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Customer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -5220,23 +2184,208 @@ impl Customers {
         if !order.is_empty() {
             query_args.push(("order".to_string(), order.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/customer_saved_searches/{}/customers.json?{}",
+                "/admin/api/customer_saved_searches/{}/customers.json?{}",
                 crate::progenitor_support::encode_path(customer_saved_search_id),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::CustomersRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.customers.to_vec()))
+    }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `get_saved_searches_param_search`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-10
+     */
+    pub async fn get_all_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Customer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::CustomersRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        let mut customers = resp.customers;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::CustomersRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            customers.append(&mut resp.customers);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(customers)
+    }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `get_all_saved_searches_param_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_saved_searches_param_search_pages_stream(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        fields: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Customer>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/customer_saved_searches/{}/customers.json?{}",
+                crate::progenitor_support::encode_path(customer_saved_search_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::CustomersRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.customers;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * As opposed to `get_all_saved_searches_param_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_saved_searches_param_search_stream(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Customer>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_saved_searches_param_search_pages_stream(
+            customer_saved_search_id,
+            order,
+            fields,
+            None,
+        ))
     }
 }
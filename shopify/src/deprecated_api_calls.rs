@@ -14,37 +14,29 @@ impl DeprecatedApiCalls {
     /**
      * Retrieves a list of deprecated API calls made by the authenticated private app in the past 30 days.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/deprecated_api_calls.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/deprecated_api_calls.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/deprecated_api_calls#index-2021-01
+     *
+     * The spec only describes this endpoint for API versions `2021-01`, `unstable`.
      */
-    pub async fn deprecated_202101_get_call(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/deprecated_api_calls.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub async fn get_deprecated_call(&self) -> ClientResult<()> {
+        self.get_deprecated_call_with_response().await?;
+        Ok(())
     }
     /**
      * Retrieves a list of deprecated API calls made by the authenticated private app in the past 30 days.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/deprecated_api_calls.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/deprecated_api_calls.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/deprecated_api_calls#index-unstable
+     * As opposed to `get_deprecated_call`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_unstable_get_call(&self) -> ClientResult<()> {
+    pub async fn get_deprecated_call_with_response(&self) -> ClientResult<crate::Response<()>> {
         let url = self
             .client
-            .url("/admin/api/unstable/deprecated_api_calls.json", None);
+            .url("/admin/api/deprecated_api_calls.json", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -14,14 +14,14 @@ impl Events {
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/events.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/events.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-10
      *
      * **Parameters:**
      *
      * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
+     *   (default: 50, maximum: 250).
      * * `since_id: &str` -- Show only results after the specified ID.
      * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
      * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
@@ -29,7 +29,7 @@ impl Events {
      * * `verb: &str` -- Show events of a certain type.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_get(
+    pub async fn get(
         &self,
         limit: &str,
         since_id: &str,
@@ -39,140 +39,26 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-01/events.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single event by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_param(
-        &self,
-        event_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/events/{}/json?{}",
-                crate::progenitor_support::encode_path(event_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of events.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/events/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202001_get_count(
-        &self,
-        created_at_min: &str,
-        created_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/events/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.get_with_response(
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        )
+        .await?;
+        Ok(())
     }
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-04
+     * This function performs a `GET` to the `/admin/api/events.json` endpoint.
      *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get(
+    pub async fn get_with_response(
         &self,
         limit: &str,
         since_id: &str,
@@ -181,7 +67,7 @@ impl Events {
         filter: &str,
         verb: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !created_at_max.is_empty() {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
@@ -207,9 +93,9 @@ impl Events {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
-            .url(&format!("/admin/api/2020-04/events.json?{}", query_), None);
+            .url(&format!("/admin/api/events.json?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -221,163 +107,31 @@ impl Events {
     /**
      * Retrieves a single event by its ID.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_param(
-        &self,
-        event_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/events/{}/json?{}",
-                crate::progenitor_support::encode_path(event_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of events.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/events/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202004_get_count(
-        &self,
-        created_at_min: &str,
-        created_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/events/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/events.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/events/{event_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-10
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
+     * * `event_id: &str` -- event_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202007_get(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-07/events.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub async fn get_param(&self, event_id: &str, fields: &str) -> ClientResult<()> {
+        self.get_param_with_response(event_id, fields).await?;
+        Ok(())
     }
     /**
      * Retrieves a single event by its ID.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/events/{event_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/events/{event_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * As opposed to `get_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_param(
+    pub async fn get_param_with_response(
         &self,
         event_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -385,1687 +139,14 @@ impl Events {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/events/{}/json?{}",
-                crate::progenitor_support::encode_path(event_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of events.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/events/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202007_get_count(
-        &self,
-        created_at_min: &str,
-        created_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/events/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2020-10/events.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single event by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_param(&self, event_id: &str, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/events/{}/json?{}",
-                crate::progenitor_support::encode_path(event_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of events.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/events/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/events/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/2021-01/events.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single event by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_param(
-        &self,
-        event_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/events/{}/json?{}",
-                crate::progenitor_support::encode_path(event_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of events.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/events/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202101_get_count(
-        &self,
-        created_at_min: &str,
-        created_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/events/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/admin/api/unstable/events.json?{}", query_), None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single event by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_param(
-        &self,
-        event_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/events/{}/json?{}",
-                crate::progenitor_support::encode_path(event_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of events.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/events/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_unstable_get_count(
-        &self,
-        created_at_min: &str,
-        created_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/events/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202001_get_webhook(
-        &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new webhook subscription by specifying both an address and a topic.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
-     */
-    pub async fn deprecated_202001_create_webhooks(
-        &self,
-        format: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of existing webhook subscriptions.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/webhooks/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     */
-    pub async fn deprecated_202001_get_webhooks_count(
-        &self,
-        address: &str,
-        topic: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/webhooks/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single webhook subscription.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     */
-    pub async fn deprecated_202001_get_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/webhooks/{}/json?{}",
-                crate::progenitor_support::encode_path(webhook_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update a webhook subscription's topic or address URIs.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a webhook subscription.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202004_get_webhook(
-        &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new webhook subscription by specifying both an address and a topic.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
-     */
-    pub async fn deprecated_202004_create_webhooks(
-        &self,
-        format: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of existing webhook subscriptions.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/webhooks/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     */
-    pub async fn deprecated_202004_get_webhooks_count(
-        &self,
-        address: &str,
-        topic: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/webhooks/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single webhook subscription.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     */
-    pub async fn deprecated_202004_get_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/webhooks/{}/json?{}",
-                crate::progenitor_support::encode_path(webhook_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update a webhook subscription's topic or address URIs.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a webhook subscription.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202007_get_webhook(
-        &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new webhook subscription by specifying both an address and a topic.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
-     */
-    pub async fn deprecated_202007_create_webhooks(
-        &self,
-        format: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of existing webhook subscriptions.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/webhooks/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     */
-    pub async fn deprecated_202007_get_webhooks_count(
-        &self,
-        address: &str,
-        topic: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/webhooks/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single webhook subscription.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     */
-    pub async fn deprecated_202007_get_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/webhooks/{}/json?{}",
-                crate::progenitor_support::encode_path(webhook_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update a webhook subscription's topic or address URIs.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a webhook subscription.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn get_webhook(
-        &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new webhook subscription by specifying both an address and a topic.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
-     */
-    pub async fn create_webhooks(
-        &self,
-        format: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of existing webhook subscriptions.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/webhooks/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     */
-    pub async fn get_webhooks_count(&self, address: &str, topic: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/webhooks/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single webhook subscription.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     */
-    pub async fn get_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/webhooks/{}/json?{}",
-                crate::progenitor_support::encode_path(webhook_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update a webhook subscription's topic or address URIs.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a webhook subscription.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202101_get_webhook(
-        &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new webhook subscription by specifying both an address and a topic.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/webhooks.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
-     */
-    pub async fn deprecated_202101_create_webhooks(
-        &self,
-        format: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of existing webhook subscriptions.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/webhooks/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     */
-    pub async fn deprecated_202101_get_webhooks_count(
-        &self,
-        address: &str,
-        topic: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
-        }
-        if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/webhooks/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single webhook subscription.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     */
-    pub async fn deprecated_202101_get_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/webhooks/{}/json?{}",
-                crate::progenitor_support::encode_path(webhook_id),
+                "/admin/api/events/{}.json?{}",
+                crate::progenitor_support::encode_path(event_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2075,62 +156,47 @@ impl Events {
             .await
     }
     /**
-     * Update a webhook subscription's topic or address URIs.
+     * Retrieves a count of events.
      *
-     * This function performs a `PUT` to the `/admin/api/2021-01/webhooks/{webhook_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/events/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#update-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/events/event#count-2020-10
      *
      * **Parameters:**
      *
-     * * `webhook_id: &str` -- storefront_access_token_id.
+     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
      */
-    pub async fn deprecated_202101_update_webhooks_param_webhook(
-        &self,
-        webhook_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+    pub async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> ClientResult<()> {
+        self.get_count_with_response(created_at_min, created_at_max)
+            .await?;
+        Ok(())
     }
     /**
-     * Delete a webhook subscription.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/webhooks/{webhook_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#destroy-2021-01
+     * Retrieves a count of events.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/events/count.json` endpoint.
      *
-     * * `webhook_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_delete_webhooks_param_webhook(
+    pub async fn get_count_with_response(
         &self,
-        webhook_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/webhooks/{}/json",
-                crate::progenitor_support::encode_path(webhook_id),
-            ),
-            None,
-        );
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/events/count.json?{}", query_), None);
         self.client
-            .delete(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2142,9 +208,9 @@ impl Events {
     /**
      * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/webhooks.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/webhooks.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-10
      *
      * **Parameters:**
      *
@@ -2153,14 +219,15 @@ impl Events {
      * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
      * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
      * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
+     *   (default: 50, maximum: 250).
      * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
      * * `topic: &str` -- Show webhook subscriptions with a given topic.
      *   For a list of valid values, refer to the topic property.>.
      * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
      * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_unstable_get_webhook(
+    pub async fn get_webhooks(
         &self,
         address: &str,
         created_at_max: &str,
@@ -2171,7 +238,44 @@ impl Events {
         topic: &str,
         updated_at_min: &str,
         updated_at_max: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Webhook>> {
+        Ok(self
+            .get_webhooks_with_response(
+                address,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                topic,
+                updated_at_min,
+                updated_at_max,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/webhooks.json` endpoint.
+     *
+     * As opposed to `get_webhooks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_webhooks_with_response(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Webhook>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !address.is_empty() {
             query_args.push(("address".to_string(), address.to_string()));
@@ -2188,6 +292,9 @@ impl Events {
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
@@ -2201,48 +308,271 @@ impl Events {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/webhooks.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
+        let url = self
+            .client
+            .url(&format!("/admin/api/webhooks.json?{}", query_), None);
+        let resp: crate::Response<crate::types::WebhooksRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.webhooks.to_vec()))
     }
     /**
-     * Create a new webhook subscription by specifying both an address and a topic.
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `POST` to the `/admin/api/unstable/webhooks.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/webhooks.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-unstable
+     * As opposed to `get_webhooks`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#index-2020-10
+     */
+    pub async fn get_all_webhooks(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<Vec<crate::types::Webhook>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !address.is_empty() {
+            query_args.push(("address".to_string(), address.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !topic.is_empty() {
+            query_args.push(("topic".to_string(), topic.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/webhooks.json?{}", query_), None);
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::WebhooksRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        let mut webhooks = resp.webhooks;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::WebhooksRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            webhooks.append(&mut resp.webhooks);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(webhooks)
+    }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
+     * This function performs a `GET` to the `/admin/api/webhooks.json` endpoint.
+     *
+     * As opposed to `get_all_webhooks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_unstable_create_webhooks(
+    pub fn get_all_webhooks_pages_stream(
         &self,
-        format: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Webhook>>> + Unpin + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+        if !address.is_empty() {
+            query_args.push(("address".to_string(), address.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !topic.is_empty() {
+            query_args.push(("topic".to_string(), topic.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/webhooks.json?{}", query_),
+        let url = self
+            .client
+            .url(&format!("/admin/api/webhooks.json?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (Option<crate::utils::NextLink>, crate::types::WebhooksRoot) =
+                        self.client
+                            .request_with_links(
+                                reqwest::Method::GET,
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.webhooks;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/webhooks.json` endpoint.
+     *
+     * As opposed to `get_all_webhooks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_webhooks_stream(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Webhook>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_webhooks_pages_stream(
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
             None,
-        );
+        ))
+    }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/webhooks.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2020-10
+     */
+    pub async fn create_webhooks(
+        &self,
+        body: &crate::types::WebhookRequestRoot,
+    ) -> ClientResult<crate::types::WebhookRoot> {
+        Ok(self.create_webhooks_with_response(body).await?.body)
+    }
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/webhooks.json` endpoint.
+     *
+     * As opposed to `create_webhooks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_webhooks_with_response(
+        &self,
+        body: &crate::types::WebhookRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::WebhookRoot>> {
+        let url = self.client.url("/admin/api/webhooks.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2254,9 +584,9 @@ impl Events {
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/webhooks/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/webhooks/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#count-2020-10
      *
      * **Parameters:**
      *
@@ -2264,11 +594,28 @@ impl Events {
      * * `topic: &str` -- Show webhook subscriptions with a given topic.
      *   For a list of valid values, refer to the topic property.>.
      */
-    pub async fn deprecated_unstable_get_webhooks_count(
+    pub async fn get_webhooks_count(
         &self,
         address: &str,
         topic: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_webhooks_count_with_response(address, topic)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a count of existing webhook subscriptions.
+     *
+     * This function performs a `GET` to the `/admin/api/webhooks/count.json` endpoint.
+     *
+     * As opposed to `get_webhooks_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_webhooks_count_with_response(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !address.is_empty() {
             query_args.push(("address".to_string(), address.to_string()));
@@ -2277,12 +624,11 @@ impl Events {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/webhooks/count.json?{}", query_),
-            None,
-        );
+        let url = self
+            .client
+            .url(&format!("/admin/api/webhooks/count.json?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2294,20 +640,37 @@ impl Events {
     /**
      * Retrieves a single webhook subscription.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/webhooks/{webhook_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/webhooks/{webhook_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#show-2020-10
      *
      * **Parameters:**
      *
-     * * `webhook_id: &str` -- storefront_access_token_id.
+     * * `webhook_id: &str` -- webhook_id.
      * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
      */
-    pub async fn deprecated_unstable_get_webhooks_param_webhook(
+    pub async fn get_webhooks_param_webhook(
         &self,
         webhook_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::WebhookRoot> {
+        Ok(self
+            .get_webhooks_param_webhook_with_response(webhook_id, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single webhook subscription.
+     *
+     * This function performs a `GET` to the `/admin/api/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `get_webhooks_param_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_webhooks_param_webhook_with_response(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebhookRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -2315,14 +678,14 @@ impl Events {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/webhooks/{}/json?{}",
+                "/admin/api/webhooks/{}.json?{}",
                 crate::progenitor_support::encode_path(webhook_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2334,28 +697,45 @@ impl Events {
     /**
      * Update a webhook subscription's topic or address URIs.
      *
-     * This function performs a `PUT` to the `/admin/api/unstable/webhooks/{webhook_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/webhooks/{webhook_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#update-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#update-2020-10
      *
      * **Parameters:**
      *
-     * * `webhook_id: &str` -- storefront_access_token_id.
+     * * `webhook_id: &str` -- webhook_id.
      */
-    pub async fn deprecated_unstable_update_webhooks_param_webhook(
+    pub async fn update_webhooks_param_webhook(
         &self,
         webhook_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::WebhookRequestRoot,
+    ) -> ClientResult<crate::types::WebhookRoot> {
+        Ok(self
+            .update_webhooks_param_webhook_with_response(webhook_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Update a webhook subscription's topic or address URIs.
+     *
+     * This function performs a `PUT` to the `/admin/api/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `update_webhooks_param_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_webhooks_param_webhook_with_response(
+        &self,
+        webhook_id: &str,
+        body: &crate::types::WebhookRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::WebhookRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/webhooks/{}/json",
+                "/admin/api/webhooks/{}.json",
                 crate::progenitor_support::encode_path(webhook_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2367,27 +747,39 @@ impl Events {
     /**
      * Delete a webhook subscription.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/webhooks/{webhook_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/webhooks/{webhook_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#destroy-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `webhook_id: &str` -- storefront_access_token_id.
+     * * `webhook_id: &str` -- webhook_id.
      */
-    pub async fn deprecated_unstable_delete_webhooks_param_webhook(
+    pub async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> ClientResult<()> {
+        self.delete_webhooks_param_webhook_with_response(webhook_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/admin/api/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `delete_webhooks_param_webhook`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_webhooks_param_webhook_with_response(
         &self,
         webhook_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/webhooks/{}/json",
+                "/admin/api/webhooks/{}.json",
                 crate::progenitor_support::encode_path(webhook_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
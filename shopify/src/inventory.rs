@@ -14,24 +14,45 @@ impl Inventory {
     /**
      * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/inventory_items.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/inventory_items.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-10
      *
      * **Parameters:**
      *
      * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
+     *   (maximum: 100).
      * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     *   (default: 50, maximum: 250).
+     * * `ids: i64` -- ids.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202001_get_item(
+    pub async fn get_items(
         &self,
         ids_required: &str,
         limit: &str,
         ids: i64,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::InventoryItem>> {
+        Ok(self
+            .get_items_with_response(ids_required, limit, ids, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/inventory_items.json` endpoint.
+     *
+     * As opposed to `get_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_items_with_response(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InventoryItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if ids > 0 {
             query_args.push(("ids".to_string(), ids.to_string()));
@@ -47,107 +68,119 @@ impl Inventory {
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/inventory_items.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
+        let url = self
+            .client
+            .url(&format!("/admin/api/inventory_items.json?{}", query_), None);
+        let resp: crate::Response<crate::types::InventoryItemsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.inventory_items.to_vec()))
     }
     /**
-     * Retrieves a single inventory item by ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/inventory_items/{inventory_item_id}.json` endpoint.
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#show-2020-01
+     * This function performs a `GET` to the `/admin/api/inventory_items.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_items`, this function returns all the pages of the request at once.
      *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-10
      */
-    pub async fn deprecated_202001_get_items_param_item(
+    pub async fn get_all_items(
         &self,
-        inventory_item_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
+        ids_required: &str,
+        ids: i64,
+    ) -> ClientResult<Vec<crate::types::InventoryItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if ids > 0 {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
+        if !ids_required.is_empty() {
+            query_args.push((
+                "ids
+                  required"
+                    .to_string(),
+                ids_required.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/inventory_items.json?{}", query_), None);
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
         );
-        self.client
-            .get(
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::InventoryItemsRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
-    }
-    /**
-     * Updates an existing inventory item.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/inventory_items/{inventory_item_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_items_param_item(
-        &self,
-        inventory_item_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+            .await?;
+
+        let mut inventory_items = resp.inventory_items;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::InventoryItemsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            inventory_items.append(&mut resp.inventory_items);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(inventory_items)
     }
     /**
      * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/inventory_items.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-04
-     *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/inventory_items.json` endpoint.
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     * As opposed to `get_all_items`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202004_get_item(
+    pub fn get_all_items_pages_stream(
         &self,
         ids_required: &str,
-        limit: &str,
         ids: i64,
-    ) -> ClientResult<()> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InventoryItem>>>
+           + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if ids > 0 {
             query_args.push(("ids".to_string(), ids.to_string()));
@@ -160,48 +193,116 @@ impl Inventory {
                 ids_required.to_string(),
             ));
         }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/inventory_items.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        let url = self
+            .client
+            .url(&format!("/admin/api/inventory_items.json?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::InventoryItemsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.inventory_items;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/inventory_items.json` endpoint.
+     *
+     * As opposed to `get_all_items`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_items_stream(
+        &self,
+        ids_required: &str,
+        ids: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InventoryItem>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_items_pages_stream(ids_required, ids, None))
     }
     /**
      * Retrieves a single inventory item by ID.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/inventory_items/{inventory_item_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/inventory_items/{inventory_item_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#show-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#show-2020-10
      *
      * **Parameters:**
      *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
+     * * `inventory_item_id: &str` -- inventory_item_id.
      */
-    pub async fn deprecated_202004_get_items_param_item(
+    pub async fn get_items_param_item(
         &self,
         inventory_item_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::InventoryItemRoot> {
+        Ok(self
+            .get_items_param_item_with_response(inventory_item_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single inventory item by ID.
+     *
+     * This function performs a `GET` to the `/admin/api/inventory_items/{inventory_item_id}.json` endpoint.
+     *
+     * As opposed to `get_items_param_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_items_param_item_with_response(
+        &self,
+        inventory_item_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::InventoryItemRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/inventory_items/{}/json",
+                "/admin/api/inventory_items/{}.json",
                 crate::progenitor_support::encode_path(inventory_item_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -213,28 +314,45 @@ impl Inventory {
     /**
      * Updates an existing inventory item.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-04/inventory_items/{inventory_item_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/inventory_items/{inventory_item_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#update-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#update-2020-10
      *
      * **Parameters:**
      *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
+     * * `inventory_item_id: &str` -- inventory_item_id.
      */
-    pub async fn deprecated_202004_update_items_param_item(
+    pub async fn update_items_param_item(
         &self,
         inventory_item_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::InventoryItemRequestRoot,
+    ) -> ClientResult<crate::types::InventoryItemRoot> {
+        Ok(self
+            .update_items_param_item_with_response(inventory_item_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an existing inventory item.
+     *
+     * This function performs a `PUT` to the `/admin/api/inventory_items/{inventory_item_id}.json` endpoint.
+     *
+     * As opposed to `update_items_param_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_items_param_item_with_response(
+        &self,
+        inventory_item_id: &str,
+        body: &crate::types::InventoryItemRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::InventoryItemRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/inventory_items/{}/json",
+                "/admin/api/inventory_items/{}.json",
                 crate::progenitor_support::encode_path(inventory_item_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -244,2339 +362,341 @@ impl Inventory {
             .await
     }
     /**
-     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Retrieves a list of inventory levels.
+     * You must include inventory_item_ids, location_ids, or both as filter parameters.
+     * Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/inventory_items.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/inventory_levels.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-10
      *
      * **Parameters:**
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
+     * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
+     *   (maximum: 50).
+     * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
+     *   (maximum: 50).
      * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     *   (default: 50, maximum: 250).
+     * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202007_get_item(
+    pub async fn get_levels(
         &self,
-        ids_required: &str,
+        inventory_item_ids: &str,
+        location_ids: &str,
         limit: &str,
-        ids: i64,
-    ) -> ClientResult<()> {
+        updated_at_min: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::InventoryLevel>> {
+        Ok(self
+            .get_levels_with_response(
+                inventory_item_ids,
+                location_ids,
+                limit,
+                updated_at_min,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of inventory levels.
+     *
+     * This function performs a `GET` to the `/admin/api/inventory_levels.json` endpoint.
+     *
+     * As opposed to `get_levels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_levels_with_response(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InventoryLevel>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !ids_required.is_empty() {
+        if !inventory_item_ids.is_empty() {
             query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
+                "inventory_item_ids".to_string(),
+                inventory_item_ids.to_string(),
             ));
         }
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
+        if !location_ids.is_empty() {
+            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+        }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-07/inventory_items.json?{}", query_),
+            &format!("/admin/api/inventory_levels.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::InventoryLevelsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.inventory_levels.to_vec()))
     }
     /**
-     * Retrieves a single inventory item by ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/inventory_items/{inventory_item_id}.json` endpoint.
+     * Retrieves a list of inventory levels.
+     * You must include inventory_item_ids, location_ids, or both as filter parameters.
+     * Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#show-2020-07
+     * This function performs a `GET` to the `/admin/api/inventory_levels.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_levels`, this function returns all the pages of the request at once.
      *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-10
      */
-    pub async fn deprecated_202007_get_items_param_item(
+    pub async fn get_all_levels(
         &self,
-        inventory_item_id: &str,
-    ) -> ClientResult<()> {
+        inventory_item_ids: &str,
+        location_ids: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<Vec<crate::types::InventoryLevel>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !inventory_item_ids.is_empty() {
+            query_args.push((
+                "inventory_item_ids".to_string(),
+                inventory_item_ids.to_string(),
+            ));
+        }
+        if !location_ids.is_empty() {
+            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
+            &format!("/admin/api/inventory_levels.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::InventoryLevelsRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut inventory_levels = resp.inventory_levels;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::InventoryLevelsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            inventory_levels.append(&mut resp.inventory_levels);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(inventory_levels)
     }
     /**
-     * Updates an existing inventory item.
+     * Retrieves a list of inventory levels.
+     * You must include inventory_item_ids, location_ids, or both as filter parameters.
+     * Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-07/inventory_items/{inventory_item_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/inventory_levels.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_all_levels`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202007_update_items_param_item(
+    pub fn get_all_levels_pages_stream(
         &self,
-        inventory_item_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        inventory_item_ids: &str,
+        location_ids: &str,
+        updated_at_min: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InventoryLevel>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !inventory_item_ids.is_empty() {
+            query_args.push((
+                "inventory_item_ids".to_string(),
+                inventory_item_ids.to_string(),
+            ));
+        }
+        if !location_ids.is_empty() {
+            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
+            &format!("/admin/api/inventory_levels.json?{}", query_),
             None,
         );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/inventory_items.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn get_item(&self, ids_required: &str, limit: &str, ids: i64) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/inventory_items.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single inventory item by ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/inventory_items/{inventory_item_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_items_param_item(&self, inventory_item_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing inventory item.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/inventory_items/{inventory_item_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_items_param_item(
-        &self,
-        inventory_item_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/inventory_items.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202101_get_item(
-        &self,
-        ids_required: &str,
-        limit: &str,
-        ids: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/inventory_items.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single inventory item by ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/inventory_items/{inventory_item_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_items_param_item(
-        &self,
-        inventory_item_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing inventory item.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/inventory_items/{inventory_item_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_items_param_item(
-        &self,
-        inventory_item_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/inventory_items.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_unstable_get_item(
-        &self,
-        ids_required: &str,
-        limit: &str,
-        ids: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !ids_required.is_empty() {
-            query_args.push((
-                "ids
-                  required"
-                    .to_string(),
-                ids_required.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/inventory_items.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single inventory item by ID.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/inventory_items/{inventory_item_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_items_param_item(
-        &self,
-        inventory_item_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing inventory item.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/inventory_items/{inventory_item_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventoryitem#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_items_param_item(
-        &self,
-        inventory_item_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/inventory_items/{}/json",
-                crate::progenitor_support::encode_path(inventory_item_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of inventory levels.
-              You must include inventory_item_ids, location_ids, or both as filter parameters.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-01/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
-    */
-    pub async fn deprecated_202001_get_level(
-        &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Deletes an inventory level of an inventory item at a location.
-              Deleting an inventory level for an inventory item removes that item from the specified location.
-              Every inventory item must have at least one inventory level. To move inventory to another location,
-              first connect the inventory item to another location, and then delete the previous inventory level.
-    *
-    * This function performs a `DELETE` to the `/admin/api/2020-01/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
-    */
-    pub async fn deprecated_202001_delete_levels(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adjusts the inventory level of an inventory item at a single location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/inventory_levels/adjust.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
-     */
-    pub async fn deprecated_202001_create_levels_adjust(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
-            query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
-                available_adjustment_required.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/inventory_levels/adjust.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Connects an inventory item to a location by creating an inventory level at that location.
-            When connecting inventory items to locations, it's important to understand the rules around
-            fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/inventory_levels/connect.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202001_create_levels_connect(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/inventory_levels/connect.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Sets the inventory level for an inventory item at a location.
-              If the specified location is not connected, it will be automatically connected first.
-              When connecting inventory items to locations, it's important to understand the rules around
-              fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/inventory_levels/set.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202001_create_levels_set(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
-            query_args.push((
-                "available
-                  required"
-                    .to_string(),
-                available_required.to_string(),
-            ));
-        }
-        if !disconnect_if_necessary.is_empty() {
-            query_args.push((
-                "disconnect_if_necessary".to_string(),
-                disconnect_if_necessary.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/inventory_levels/set.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of inventory levels.
-              You must include inventory_item_ids, location_ids, or both as filter parameters.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-04/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
-    */
-    pub async fn deprecated_202004_get_level(
-        &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Deletes an inventory level of an inventory item at a location.
-              Deleting an inventory level for an inventory item removes that item from the specified location.
-              Every inventory item must have at least one inventory level. To move inventory to another location,
-              first connect the inventory item to another location, and then delete the previous inventory level.
-    *
-    * This function performs a `DELETE` to the `/admin/api/2020-04/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
-    */
-    pub async fn deprecated_202004_delete_levels(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adjusts the inventory level of an inventory item at a single location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/inventory_levels/adjust.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
-     */
-    pub async fn deprecated_202004_create_levels_adjust(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
-            query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
-                available_adjustment_required.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/inventory_levels/adjust.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Connects an inventory item to a location by creating an inventory level at that location.
-            When connecting inventory items to locations, it's important to understand the rules around
-            fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/inventory_levels/connect.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202004_create_levels_connect(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/inventory_levels/connect.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Sets the inventory level for an inventory item at a location.
-              If the specified location is not connected, it will be automatically connected first.
-              When connecting inventory items to locations, it's important to understand the rules around
-              fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/inventory_levels/set.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202004_create_levels_set(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
-            query_args.push((
-                "available
-                  required"
-                    .to_string(),
-                available_required.to_string(),
-            ));
-        }
-        if !disconnect_if_necessary.is_empty() {
-            query_args.push((
-                "disconnect_if_necessary".to_string(),
-                disconnect_if_necessary.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/inventory_levels/set.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of inventory levels.
-              You must include inventory_item_ids, location_ids, or both as filter parameters.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-07/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
-    */
-    pub async fn deprecated_202007_get_level(
-        &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Deletes an inventory level of an inventory item at a location.
-              Deleting an inventory level for an inventory item removes that item from the specified location.
-              Every inventory item must have at least one inventory level. To move inventory to another location,
-              first connect the inventory item to another location, and then delete the previous inventory level.
-    *
-    * This function performs a `DELETE` to the `/admin/api/2020-07/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
-    */
-    pub async fn deprecated_202007_delete_levels(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adjusts the inventory level of an inventory item at a single location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/inventory_levels/adjust.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
-     */
-    pub async fn deprecated_202007_create_levels_adjust(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
-            query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
-                available_adjustment_required.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/inventory_levels/adjust.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Connects an inventory item to a location by creating an inventory level at that location.
-            When connecting inventory items to locations, it's important to understand the rules around
-            fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/inventory_levels/connect.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202007_create_levels_connect(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/inventory_levels/connect.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Sets the inventory level for an inventory item at a location.
-              If the specified location is not connected, it will be automatically connected first.
-              When connecting inventory items to locations, it's important to understand the rules around
-              fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/inventory_levels/set.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202007_create_levels_set(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
-            query_args.push((
-                "available
-                  required"
-                    .to_string(),
-                available_required.to_string(),
-            ));
-        }
-        if !disconnect_if_necessary.is_empty() {
-            query_args.push((
-                "disconnect_if_necessary".to_string(),
-                disconnect_if_necessary.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/inventory_levels/set.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of inventory levels.
-              You must include inventory_item_ids, location_ids, or both as filter parameters.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-10/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
-    */
-    pub async fn get_level(
-        &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Deletes an inventory level of an inventory item at a location.
-              Deleting an inventory level for an inventory item removes that item from the specified location.
-              Every inventory item must have at least one inventory level. To move inventory to another location,
-              first connect the inventory item to another location, and then delete the previous inventory level.
-    *
-    * This function performs a `DELETE` to the `/admin/api/2020-10/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
-    */
-    pub async fn delete_levels(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adjusts the inventory level of an inventory item at a single location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/inventory_levels/adjust.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
-     */
-    pub async fn create_levels_adjust(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
-            query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
-                available_adjustment_required.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/inventory_levels/adjust.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Connects an inventory item to a location by creating an inventory level at that location.
-            When connecting inventory items to locations, it's important to understand the rules around
-            fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/inventory_levels/connect.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn create_levels_connect(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/inventory_levels/connect.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Sets the inventory level for an inventory item at a location.
-              If the specified location is not connected, it will be automatically connected first.
-              When connecting inventory items to locations, it's important to understand the rules around
-              fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/inventory_levels/set.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn create_levels_set(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
-            query_args.push((
-                "available
-                  required"
-                    .to_string(),
-                available_required.to_string(),
-            ));
-        }
-        if !disconnect_if_necessary.is_empty() {
-            query_args.push((
-                "disconnect_if_necessary".to_string(),
-                disconnect_if_necessary.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/inventory_levels/set.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of inventory levels.
-              You must include inventory_item_ids, location_ids, or both as filter parameters.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-    *
-    * This function performs a `GET` to the `/admin/api/2021-01/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
-    */
-    pub async fn deprecated_202101_get_level(
-        &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Deletes an inventory level of an inventory item at a location.
-              Deleting an inventory level for an inventory item removes that item from the specified location.
-              Every inventory item must have at least one inventory level. To move inventory to another location,
-              first connect the inventory item to another location, and then delete the previous inventory level.
-    *
-    * This function performs a `DELETE` to the `/admin/api/2021-01/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
-    */
-    pub async fn deprecated_202101_delete_levels(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adjusts the inventory level of an inventory item at a single location.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/inventory_levels/adjust.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
-     */
-    pub async fn deprecated_202101_create_levels_adjust(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
-            query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
-                available_adjustment_required.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/inventory_levels/adjust.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Connects an inventory item to a location by creating an inventory level at that location.
-            When connecting inventory items to locations, it's important to understand the rules around
-            fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/inventory_levels/connect.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202101_create_levels_connect(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/inventory_levels/connect.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Sets the inventory level for an inventory item at a location.
-              If the specified location is not connected, it will be automatically connected first.
-              When connecting inventory items to locations, it's important to understand the rules around
-              fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/inventory_levels/set.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_202101_create_levels_set(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
-            query_args.push((
-                "available
-                  required"
-                    .to_string(),
-                available_required.to_string(),
-            ));
-        }
-        if !disconnect_if_necessary.is_empty() {
-            query_args.push((
-                "disconnect_if_necessary".to_string(),
-                disconnect_if_necessary.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/inventory_levels/set.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of inventory levels.
-              You must include inventory_item_ids, location_ids, or both as filter parameters.
-              Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-    *
-    * This function performs a `GET` to the `/admin/api/unstable/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#index-unstable
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
-    */
-    pub async fn deprecated_unstable_get_level(
-        &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Deletes an inventory level of an inventory item at a location.
-              Deleting an inventory level for an inventory item removes that item from the specified location.
-              Every inventory item must have at least one inventory level. To move inventory to another location,
-              first connect the inventory item to another location, and then delete the previous inventory level.
-    *
-    * This function performs a `DELETE` to the `/admin/api/unstable/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-unstable
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
-    */
-    pub async fn deprecated_unstable_delete_levels(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/inventory_levels.json?{}", query_),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adjusts the inventory level of an inventory item at a single location.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/adjust.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-unstable
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
-     */
-    pub async fn deprecated_unstable_create_levels_adjust(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
-            query_args.push((
-                "available_adjustment
-                  required"
-                    .to_string(),
-                available_adjustment_required.to_string(),
-            ));
-        }
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/inventory_levels/adjust.json?{}",
-                query_
-            ),
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::InventoryLevelsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.inventory_levels;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of inventory levels.
+     * You must include inventory_item_ids, location_ids, or both as filter parameters.
+     * Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/inventory_levels.json` endpoint.
+     *
+     * As opposed to `get_all_levels`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_levels_stream(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        updated_at_min: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InventoryLevel>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_levels_pages_stream(
+            inventory_item_ids,
+            location_ids,
+            updated_at_min,
             None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        ))
     }
     /**
-    * Connects an inventory item to a location by creating an inventory level at that location.
-            When connecting inventory items to locations, it's important to understand the rules around
-            fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/connect.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-unstable
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_unstable_create_levels_connect(
+     * Deletes an inventory level of an inventory item at a location.
+     * Deleting an inventory level for an inventory item removes that item from the specified location.
+     * Every inventory item must have at least one inventory level. To move inventory to another location,
+     * first connect the inventory item to another location, and then delete the previous inventory level.
+     *
+     * This function performs a `DELETE` to the `/admin/api/inventory_levels.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `inventory_item_required: &str` -- The ID for the inventory item.
+     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
+     * * `inventory_item_id: i64` -- inventory_item_id.
+     * * `location_id: i64` -- location_id.
+     */
+    pub async fn delete_levels(
         &self,
         inventory_item_id_required: &str,
         location_id_required: &str,
-        relocate_if_necessary: &str,
-        body: &serde_json::Value,
+        inventory_item_id: i64,
+        location_id: i64,
     ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
-            query_args.push((
-                "inventory_item_id
-                  required"
-                    .to_string(),
-                inventory_item_id_required.to_string(),
-            ));
-        }
-        if !location_id_required.is_empty() {
-            query_args.push((
-                "location_id
-                  required"
-                    .to_string(),
-                location_id_required.to_string(),
-            ));
-        }
-        if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/inventory_levels/connect.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        self.delete_levels_with_response(
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await?;
+        Ok(())
     }
     /**
-    * Sets the inventory level for an inventory item at a location.
-              If the specified location is not connected, it will be automatically connected first.
-              When connecting inventory items to locations, it's important to understand the rules around
-              fulfillment service locations.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/set.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-unstable
-    *
-    * **Parameters:**
-    *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
-    */
-    pub async fn deprecated_unstable_create_levels_set(
+     * Deletes an inventory level of an inventory item at a location.
+     *
+     * This function performs a `DELETE` to the `/admin/api/inventory_levels.json` endpoint.
+     *
+     * As opposed to `delete_levels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_levels_with_response(
         &self,
         inventory_item_id_required: &str,
         location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
-            query_args.push((
-                "available
-                  required"
-                    .to_string(),
-                available_required.to_string(),
-            ));
-        }
-        if !disconnect_if_necessary.is_empty() {
+        if inventory_item_id > 0 {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
-                disconnect_if_necessary.to_string(),
+                "inventory_item_id".to_string(),
+                inventory_item_id.to_string(),
             ));
         }
         if !inventory_item_id_required.is_empty() {
@@ -2587,229 +707,24 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
+        if location_id > 0 {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
         if !location_id_required.is_empty() {
             query_args.push((
                 "location_id
                   required"
                     .to_string(),
                 location_id_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/inventory_levels/set.json?{}", query_),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of locations.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/locations.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-2020-01
-     */
-    pub async fn deprecated_202001_get_location(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/locations.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single location by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/locations/{location_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `location_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_locations_param_location(
-        &self,
-        location_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/locations/{}/json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of locations.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/locations/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#count-2020-01
-     */
-    pub async fn deprecated_202001_get_locations_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/locations/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/locations/{location_id}/inventory_levels.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `location_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_locations_param_location_level(
-        &self,
-        location_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/locations/{}/inventory_levels.json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of locations.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/locations.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-2020-04
-     */
-    pub async fn deprecated_202004_get_location(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/locations.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single location by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/locations/{location_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `location_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_locations_param_location(
-        &self,
-        location_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/locations/{}/json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of locations.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/locations/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#count-2020-04
-     */
-    pub async fn deprecated_202004_get_locations_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/locations/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/locations/{location_id}/inventory_levels.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `location_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_locations_param_location_level(
-        &self,
-        location_id: &str,
-    ) -> ClientResult<()> {
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/locations/{}/inventory_levels.json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
+            &format!("/admin/api/inventory_levels.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2819,250 +734,342 @@ impl Inventory {
             .await
     }
     /**
-     * Retrieves a list of locations.
+     * Adjusts the inventory level of an inventory item at a single location.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/locations.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/inventory_levels/adjust.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-2020-10
      */
-    pub async fn deprecated_202007_get_location(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/locations.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub async fn create_levels_adjust(
+        &self,
+        body: &crate::types::InventoryLevelAdjustment,
+    ) -> ClientResult<crate::types::InventoryLevelRoot> {
+        Ok(self.create_levels_adjust_with_response(body).await?.body)
     }
     /**
-     * Retrieves a single location by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/locations/{location_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#show-2020-07
+     * Adjusts the inventory level of an inventory item at a single location.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/inventory_levels/adjust.json` endpoint.
      *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * As opposed to `create_levels_adjust`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_locations_param_location(
+    pub async fn create_levels_adjust_with_response(
         &self,
-        location_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/locations/{}/json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
-            None,
-        );
+        body: &crate::types::InventoryLevelAdjustment,
+    ) -> ClientResult<crate::Response<crate::types::InventoryLevelRoot>> {
+        let url = self
+            .client
+            .url("/admin/api/inventory_levels/adjust.json", None);
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a count of locations.
+     * Connects an inventory item to a location by creating an inventory level at that location.
+     * When connecting inventory items to locations, it's important to understand the rules around
+     * fulfillment service locations.
+     *
+     * This function performs a `POST` to the `/admin/api/inventory_levels/connect.json` endpoint.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/locations/count.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#connect-2020-10
+     */
+    pub async fn create_levels_connect(
+        &self,
+        body: &crate::types::InventoryLevelConnection,
+    ) -> ClientResult<crate::types::InventoryLevelRoot> {
+        Ok(self.create_levels_connect_with_response(body).await?.body)
+    }
+    /**
+     * Connects an inventory item to a location by creating an inventory level at that location.
+     *
+     * This function performs a `POST` to the `/admin/api/inventory_levels/connect.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#count-2020-07
+     * As opposed to `create_levels_connect`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_locations_count(&self) -> ClientResult<()> {
+    pub async fn create_levels_connect_with_response(
+        &self,
+        body: &crate::types::InventoryLevelConnection,
+    ) -> ClientResult<crate::Response<crate::types::InventoryLevelRoot>> {
         let url = self
             .client
-            .url("/admin/api/2020-07/locations/count.json", None);
+            .url("/admin/api/inventory_levels/connect.json", None);
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Sets the inventory level for an inventory item at a location.
+     * If the specified location is not connected, it will be automatically connected first.
+     * When connecting inventory items to locations, it's important to understand the rules around
+     * fulfillment service locations.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/locations/{location_id}/inventory_levels.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/inventory_levels/set.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#set-2020-10
      */
-    pub async fn deprecated_202007_get_locations_param_location_level(
+    pub async fn create_levels_set(
         &self,
-        location_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/locations/{}/inventory_levels.json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        body: &crate::types::InventoryLevelUpdate,
+    ) -> ClientResult<crate::types::InventoryLevelRoot> {
+        Ok(self.create_levels_set_with_response(body).await?.body)
     }
     /**
-     * Retrieves a list of locations.
+     * Sets the inventory level for an inventory item at a location.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/locations.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/inventory_levels/set.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-2020-10
+     * As opposed to `create_levels_set`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_location(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/locations.json", None);
+    pub async fn create_levels_set_with_response(
+        &self,
+        body: &crate::types::InventoryLevelUpdate,
+    ) -> ClientResult<crate::Response<crate::types::InventoryLevelRoot>> {
+        let url = self
+            .client
+            .url("/admin/api/inventory_levels/set.json", None);
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieves a single location by its ID.
+     * Retrieves a list of locations.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/locations/{location_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/locations.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#show-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-2020-10
      *
      * **Parameters:**
      *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn get_locations_param_location(&self, location_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/locations/{}/json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub async fn get_locations(
+        &self,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Location>> {
+        Ok(self.get_locations_with_response(page_info).await?.body)
     }
     /**
-     * Retrieves a count of locations.
+     * Retrieves a list of locations.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/locations/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/locations.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#count-2020-10
+     * As opposed to `get_locations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_locations_count(&self) -> ClientResult<()> {
+    pub async fn get_locations_with_response(
+        &self,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Location>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
-            .url("/admin/api/2020-10/locations/count.json", None);
-        self.client
-            .get(
+            .url(&format!("/admin/api/locations.json?{}", query_), None);
+        let resp: crate::Response<crate::types::LocationsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.locations.to_vec()))
     }
     /**
-     * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/locations/{location_id}/inventory_levels.json` endpoint.
+     * Retrieves a list of locations.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-2020-10
+     * This function performs a `GET` to the `/admin/api/locations.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_locations`, this function returns all the pages of the request at once.
      *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-2020-10
      */
-    pub async fn get_locations_param_location_level(&self, location_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/locations/{}/inventory_levels.json",
-                crate::progenitor_support::encode_path(location_id),
-            ),
-            None,
+    pub async fn get_all_locations(&self) -> ClientResult<Vec<crate::types::Location>> {
+        let url = self.client.url("/admin/api/locations.json", None);
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
         );
-        self.client
-            .get(
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::LocationsRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut locations = resp.locations;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::LocationsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            locations.append(&mut resp.locations);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(locations)
     }
     /**
      * Retrieves a list of locations.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/locations.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/locations.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-2021-01
+     * As opposed to `get_all_locations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202101_get_location(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/locations.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub fn get_all_locations_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Location>>> + Unpin + '_
+    {
+        let url = self.client.url("/admin/api/locations.json", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::LocationsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.locations;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of locations.
+     *
+     * This function performs a `GET` to the `/admin/api/locations.json` endpoint.
+     *
+     * As opposed to `get_all_locations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_locations_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Location>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_locations_pages_stream(None))
     }
     /**
      * Retrieves a single location by its ID.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/locations/{location_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/locations/{location_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#show-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#show-2020-10
      *
      * **Parameters:**
      *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * * `location_id: &str` -- location_id.
      */
-    pub async fn deprecated_202101_get_locations_param_location(
+    pub async fn get_locations_param_location(
         &self,
         location_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::LocationRoot> {
+        Ok(self
+            .get_locations_param_location_with_response(location_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single location by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/locations/{location_id}.json` endpoint.
+     *
+     * As opposed to `get_locations_param_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_locations_param_location_with_response(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::LocationRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/locations/{}/json",
+                "/admin/api/locations/{}.json",
                 crate::progenitor_support::encode_path(location_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3074,16 +1081,26 @@ impl Inventory {
     /**
      * Retrieves a count of locations.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/locations/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/locations/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#count-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#count-2020-10
      */
-    pub async fn deprecated_202101_get_locations_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/locations/count.json", None);
+    pub async fn get_locations_count(&self) -> ClientResult<crate::types::Count> {
+        Ok(self.get_locations_count_with_response().await?.body)
+    }
+    /**
+     * Retrieves a count of locations.
+     *
+     * This function performs a `GET` to the `/admin/api/locations/count.json` endpoint.
+     *
+     * As opposed to `get_locations_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_locations_count_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
+        let url = self.client.url("/admin/api/locations/count.json", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3095,137 +1112,217 @@ impl Inventory {
     /**
      * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/locations/{location_id}/inventory_levels.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/locations/{location_id}/inventory_levels.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-2020-10
      *
      * **Parameters:**
      *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * * `location_id: &str` -- location_id.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202101_get_locations_param_location_level(
+    pub async fn get_locations_param_location_levels(
         &self,
         location_id: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::InventoryLevel>> {
+        Ok(self
+            .get_locations_param_location_levels_with_response(location_id, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/locations/{location_id}/inventory_levels.json` endpoint.
+     *
+     * As opposed to `get_locations_param_location_levels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_locations_param_location_levels_with_response(
+        &self,
+        location_id: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InventoryLevel>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/locations/{}/inventory_levels.json",
+                "/admin/api/locations/{}/inventory_levels.json?{}",
                 crate::progenitor_support::encode_path(location_id),
+                query_
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of locations.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/locations.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#index-unstable
-     */
-    pub async fn deprecated_unstable_get_location(&self) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/locations.json", None);
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::InventoryLevelsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.inventory_levels.to_vec()))
     }
     /**
-     * Retrieves a single location by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/locations/{location_id}.json` endpoint.
+     * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#show-unstable
+     * This function performs a `GET` to the `/admin/api/locations/{location_id}/inventory_levels.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_locations_param_location_levels`, this function returns all the pages of the request at once.
      *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-2020-10
      */
-    pub async fn deprecated_unstable_get_locations_param_location(
+    pub async fn get_all_locations_param_location_levels(
         &self,
         location_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<Vec<crate::types::InventoryLevel>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/locations/{}/json",
+                "/admin/api/locations/{}/inventory_levels.json",
                 crate::progenitor_support::encode_path(location_id),
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of locations.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/locations/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#count-unstable
-     */
-    pub async fn deprecated_unstable_get_locations_count(&self) -> ClientResult<()> {
-        let url = self
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::InventoryLevelsRoot,
+        ) = self
             .client
-            .url("/admin/api/unstable/locations/count.json", None);
-        self.client
-            .get(
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut inventory_levels = resp.inventory_levels;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::InventoryLevelsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            inventory_levels.append(&mut resp.inventory_levels);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(inventory_levels)
     }
     /**
      * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/locations/{location_id}/inventory_levels.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/locations/{location_id}/inventory_levels.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/location#inventory_levels-unstable
-     *
-     * **Parameters:**
-     *
-     * * `location_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_all_locations_param_location_levels`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_unstable_get_locations_param_location_level(
+    pub fn get_all_locations_param_location_levels_pages_stream(
         &self,
         location_id: &str,
-    ) -> ClientResult<()> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InventoryLevel>>>
+           + Unpin
+           + '_ {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/locations/{}/inventory_levels.json",
+                "/admin/api/locations/{}/inventory_levels.json",
                 crate::progenitor_support::encode_path(location_id),
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::InventoryLevelsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.inventory_levels;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of inventory levels for a location. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/locations/{location_id}/inventory_levels.json` endpoint.
+     *
+     * As opposed to `get_all_locations_param_location_levels`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_locations_param_location_levels_stream(
+        &self,
+        location_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InventoryLevel>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_locations_param_location_levels_pages_stream(location_id, None),
+        )
     }
 }
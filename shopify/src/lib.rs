@@ -34,13 +34,14 @@
 //! ```
 //! use shopify::Client;
 //!
-//! let shopify = Client::new(
+//! let shopify = Client::new_for_shop(
+//!     "my-shop",
 //!     String::from("client-id"),
 //!     String::from("client-secret"),
 //!     String::from("redirect-uri"),
 //!     String::from("token"),
-//!     String::from("refresh-token")
-//! );
+//! )
+//! .unwrap();
 //! ```
 //!
 //! Alternatively, the library can search for most of the variables required for
@@ -49,10 +50,11 @@
 //! - `SHOPIFY_CLIENT_ID`
 //! - `SHOPIFY_CLIENT_SECRET`
 //! - `SHOPIFY_REDIRECT_URI`
+//! - `SHOPIFY_SHOP`
 //!
 //! And then you can create a client from the environment.
 //!
-//! ```
+//! ```no_run
 //! use shopify::Client;
 //!
 //! let shopify = Client::new_from_env(
@@ -89,6 +91,55 @@
 //! }
 //! ```
 //!
+//! Every shop has its own host and OAuth endpoints, so create a client for
+//! the shop you are talking to. To install your app on a shop, check the
+//! redirect back from Shopify before exchanging the code for a token.
+//!
+//! ```
+//! use shopify::Client;
+//!
+//! async fn do_call(shop: &str, callback_query: &str) {
+//!     let mut shopify = Client::new_for_shop(
+//!         shop,
+//!         String::from("client-id"),
+//!         String::from("client-secret"),
+//!         String::from("redirect-uri"),
+//!         "",
+//!     )
+//!     .unwrap();
+//!
+//!     let user_consent_url = shopify.user_consent_url(&["read_orders".to_string()]);
+//!
+//!     // In your redirect URL, verify the query Shopify sent the merchant
+//!     // back with and check the state is the one you sent.
+//!     let callback = shopify.verify_oauth_callback(callback_query).unwrap();
+//!     let access_token = shopify
+//!         .get_access_token(&callback.code, &callback.state)
+//!         .await
+//!         .unwrap();
+//! }
+//! ```
+//!
+//! The `get_all` functions follow the `page_info` cursors in the `Link`
+//! header to fetch every page of a list, and the `_stream` ones fetch the
+//! pages as you go. Requests are paced with the leaky bucket in
+//! `X-Shopify-Shop-Api-Call-Limit`, so they do not run into `429`s.
+//!
+//! Requests go to the Admin API at `DEFAULT_API_VERSION`. Pick another
+//! version for the client with `set_api_version`, or for a single request
+//! with `with_api_version`. Calls Shopify flags with
+//! `X-Shopify-API-Deprecated-Reason` are logged as warnings.
+//!
+//! ```
+//! use shopify::Client;
+//!
+//! async fn do_call(shopify: &mut Client) {
+//!     shopify.set_api_version("2026-04");
+//!
+//!     let orders = shopify.with_api_version("unstable").orders();
+//! }
+//! ```
+//!
 #![allow(clippy::derive_partial_eq_without_eq)]
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
@@ -118,6 +169,7 @@ pub mod tendertransaction;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
+pub mod webhooks;
 
 use thiserror::Error;
 type ClientResult<T> = Result<T, ClientError>;
@@ -132,12 +184,28 @@ pub enum ClientError {
     /// utf8 convertion error
     #[error(transparent)]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
+
+    /// The shop is not a `*.myshopify.com` domain
+    #[error("Invalid shop domain: {0}")]
+    InvalidShopDomain(String),
+    /// The redirect back from Shopify failed the `hmac` check
+    #[error("Invalid OAuth callback: {0}")]
+    InvalidOAuthCallback(String),
+    /// Webhook signature errors from webhooks.rs
+    #[error("Invalid webhook signature: {0}")]
+    InvalidWebhookSignature(String),
+    /// A webhook delivery is missing a header or has a malformed body
+    #[error("Invalid webhook payload: {0}")]
+    InvalidWebhookPayload(String),
     /// URL Parsing Error
     #[error(transparent)]
     UrlParserError(#[from] url::ParseError),
     /// Serde JSON parsing error
     #[error(transparent)]
     SerdeJsonError(#[from] serde_json::Error),
+    /// A response did not match the generated types, in strict mode
+    #[error("Unexpected response at `{path}`: {error}")]
+    DeserializationError { path: String, error: String },
     /// Errors returned by reqwest
     #[error(transparent)]
     ReqwestError(#[from] reqwest::Error),
@@ -152,10 +220,17 @@ pub enum ClientError {
     HttpError {
         status: http::StatusCode,
         error: String,
+        request_id: Option<String>,
     },
 }
 
-pub const FALLBACK_HOST: &str = "https://{shop}.myshopify.com/admin/api/2021-07";
+pub const FALLBACK_HOST: &str = "https://{shop}.myshopify.com";
+
+/// The header the API sends the id of a request back in.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The Admin API version requests go to, unless set on the `Client`.
+pub const DEFAULT_API_VERSION: &str = "2026-07";
 
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
@@ -183,6 +258,44 @@ pub(crate) struct Message {
     pub content_type: Option<String>,
 }
 
+/// A response from the API: the status code, headers and raw bytes of the
+/// response, along with the parsed body.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub raw: bytes::Bytes,
+    pub body: T,
+}
+
+impl<T> Response<T> {
+    /// Maps the parsed body, keeping the rest of the response.
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Response<U> {
+        Response {
+            status: self.status,
+            headers: self.headers,
+            raw: self.raw,
+            body: f(self.body),
+        }
+    }
+
+    /// The id the API gave this request, if it sent one. This is the id to hand
+    /// to the API's support when something goes wrong.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+    }
+}
+
+/// The id the API gave a request, kept on the errors so it can be logged.
+pub(crate) fn request_id(headers: &reqwest::header::HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
 use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
@@ -202,8 +315,12 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-
+    shop: String,
+    api_version: String,
     auto_refresh: bool,
+    coercions: crate::utils::Coercions,
+    retry: crate::utils::RetryConfig,
+    limiter: Option<crate::utils::RateLimiter>,
     client: reqwest_middleware::ClientWithMiddleware,
 }
 
@@ -261,6 +378,7 @@ impl Client {
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
     /// given a valid API key your requests will work.
+    #[deprecated(note = "the client has no shop to talk to, use `new_for_shop`")]
     pub fn new<I, K, R, T, Q>(
         client_id: I,
         client_secret: K,
@@ -275,9 +393,6 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
         let client = reqwest::Client::builder().build();
         match client {
             Ok(c) => {
@@ -285,10 +400,11 @@ impl Client {
                     // Trace HTTP requests. See the tracing crate to make use of these traces.
                     .with(reqwest_tracing::TracingMiddleware::default())
                     // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
+                    .with(crate::utils::RetryMiddleware::default())
+                    // Stay under the rate limits, see `Client::set_rate_limiter`.
+                    .with(crate::utils::RateLimitMiddleware)
+                    // Warn about calls to deprecated endpoints.
+                    .with(crate::utils::DeprecationMiddleware)
                     .build();
 
                 let host = FALLBACK_HOST.to_string();
@@ -304,8 +420,12 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
-
+                    shop: String::new(),
+                    api_version: DEFAULT_API_VERSION.to_string(),
                     auto_refresh: false,
+                    coercions: Default::default(),
+                    retry: Default::default(),
+                    limiter: Some(crate::utils::RateLimiter::new()),
                     client,
                 }
             }
@@ -398,14 +518,13 @@ impl Client {
             self.get_host_override()
                 .or(host)
                 .unwrap_or(self.host.as_str()),
-            path
+            crate::utils::api_path(&self.api_version, path)
         )
     }
 
-    /// Create a new Client struct from environment variables. It
-    /// takes a type that can convert into
-    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
-    /// given a valid API key and your requests will work.
+    /// Create a new Client for the shop in `SHOPIFY_SHOP` from environment variables,
+    /// see `new_for_shop`. It takes a type that can convert into
+    /// an &str (`String` or `Vec<u8>` for example).
     /// We pass in the token and refresh token to the client so if you are storing
     /// it in a database, you can get it first.
     pub fn new_from_env<T, R>(token: T, refresh_token: R) -> Self
@@ -413,12 +532,92 @@ impl Client {
         T: ToString,
         R: ToString,
     {
+        let shop = env::var("SHOPIFY_SHOP").expect("must set SHOPIFY_SHOP");
+        let shop = crate::utils::shop_name(&shop).expect("SHOPIFY_SHOP is not a shop");
         let client_id = env::var("SHOPIFY_CLIENT_ID").expect("must set SHOPIFY_CLIENT_ID");
         let client_secret =
             env::var("SHOPIFY_CLIENT_SECRET").expect("must set SHOPIFY_CLIENT_SECRET");
         let redirect_uri = env::var("SHOPIFY_REDIRECT_URI").expect("must set SHOPIFY_REDIRECT_URI");
 
-        Client::new(client_id, client_secret, redirect_uri, token, refresh_token)
+        #[allow(deprecated)]
+        let mut client = Client::new(client_id, client_secret, redirect_uri, token, refresh_token);
+        client.shop = shop;
+        client.host = client.shop_url(FALLBACK_HOST);
+
+        client
+    }
+
+    /// Create a new Client for a shop, from its `my-shop.myshopify.com` domain or
+    /// just its name. The host and the OAuth endpoints are built from it, so a
+    /// process serving many shops makes a client for each of them.
+    ///
+    /// Pass an empty `token` to install the app on the shop with
+    /// `user_consent_url` and `get_access_token`.
+    pub fn new_for_shop<S, I, K, R, T>(
+        shop: S,
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+    ) -> ClientResult<Self>
+    where
+        S: ToString,
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+    {
+        let shop = crate::utils::shop_name(&shop.to_string())?;
+
+        // Shopify access tokens do not get refreshed.
+        #[allow(deprecated)]
+        let mut client = Client::new(client_id, client_secret, redirect_uri, token, "");
+        client.shop = shop;
+        client.host = client.shop_url(FALLBACK_HOST);
+
+        Ok(client)
+    }
+
+    /// The name of the shop the client talks to, ie. `my-shop`.
+    pub fn shop(&self) -> &str {
+        &self.shop
+    }
+
+    /// Check the query string Shopify redirected the merchant back with, after
+    /// they installed the app, was signed with our client secret. Then hand the
+    /// `code` to `get_access_token` on a client for the `shop`.
+    ///
+    /// Make sure the `state` is the one you sent the merchant off with.
+    pub fn verify_oauth_callback(&self, query: &str) -> ClientResult<crate::utils::OAuthCallback> {
+        crate::utils::verify_oauth_callback(&self.client_secret, query)
+    }
+
+    fn shop_url(&self, url: &str) -> String {
+        url.replace("{shop}", &self.shop)
+    }
+
+    /// The Admin API version the client talks to, `DEFAULT_API_VERSION` unless set.
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    /// Talk to the Admin API at another version, ie. `2026-04` or `unstable`.
+    pub fn set_api_version<V>(&mut self, api_version: V) -> &mut Self
+    where
+        V: ToString,
+    {
+        self.api_version = api_version.to_string();
+        self
+    }
+
+    /// A client pinned to another Admin API version, for a single request or a few.
+    pub fn with_api_version<V>(&self, api_version: V) -> Self
+    where
+        V: ToString,
+    {
+        let mut client = self.clone();
+        client.api_version = api_version.to_string();
+        client
     }
 
     /// Return a user consent url with an optional set of scopes.
@@ -428,7 +627,10 @@ impl Client {
 
         let url = format!(
             "{}?client_id={}&response_type=code&redirect_uri={}&state={}",
-            USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
+            self.shop_url(USER_CONSENT_ENDPOINT),
+            self.client_id,
+            self.redirect_uri,
+            state
         );
 
         if scopes.is_empty() {
@@ -436,7 +638,7 @@ impl Client {
         }
 
         // Add the scopes.
-        format!("{}&scope={}", url, scopes.join(" "))
+        format!("{}&scope={}", url, scopes.join(","))
     }
 
     /// Refresh an access token from a refresh token. Client must have a refresh token
@@ -464,7 +666,7 @@ impl Client {
             ];
             let client = reqwest::Client::new();
             client
-                .post(TOKEN_ENDPOINT)
+                .post(self.shop_url(TOKEN_ENDPOINT))
                 .headers(headers)
                 .form(&params)
                 .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -505,7 +707,7 @@ impl Client {
         ];
         let client = reqwest::Client::new();
         let resp = client
-            .post(TOKEN_ENDPOINT)
+            .post(self.shop_url(TOKEN_ENDPOINT))
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -527,7 +729,7 @@ impl Client {
     async fn url_and_auth(&self, uri: &str) -> ClientResult<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>()?;
 
-        let auth = format!("Bearer {}", self.token.read().await.access_token);
+        let auth = self.token.read().await.access_token.to_string();
         Ok((parsed_url, Some(auth)))
     }
 
@@ -562,7 +764,7 @@ impl Client {
         }
 
         if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+            req = req.header("X-Shopify-Access-Token", &*auth_str);
         }
 
         if let Some(body) = message.body {
@@ -606,23 +808,69 @@ impl Client {
         }
 
         let req = self.make_request(&method, uri, message).await?;
-        let resp = self.client.execute(req).await?;
+        let resp = self
+            .client
+            .execute_with_extensions(req, &mut self.request_extensions())
+            .await?;
 
         Ok(resp)
     }
 
+    /// Return an error with the JSON path of the offending field when a response
+    /// does not match the generated types, instead of falling back to defaults.
+    pub fn set_strict_deserialization(&mut self, enabled: bool) -> &mut Self {
+        self.coercions.strict = enabled;
+        self
+    }
+
+    /// The fields of past responses that did not match the generated types and
+    /// were replaced with their default value. The log is shared between clones
+    /// of the client and keeps the most recent 1000 fields.
+    pub fn coercions(&self) -> Vec<crate::utils::Coercion> {
+        self.coercions.list()
+    }
+
+    /// Clears the log of coerced fields.
+    pub fn clear_coercions(&self) {
+        self.coercions.clear();
+    }
+
+    /// Sets when and how often failed requests are retried.
+    pub fn set_retry_config(&mut self, config: crate::utils::RetryConfig) -> &mut Self {
+        self.retry = config;
+        self
+    }
+
+    /// Queues requests to stay under the rate limits of the API. The clones
+    /// made from the client afterwards share the same limiter.
+    pub fn set_rate_limiter(&mut self, limiter: crate::utils::RateLimiter) -> &mut Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Carries the retry config and rate limiter to the middleware.
+    fn request_extensions(&self) -> task_local_extensions::Extensions {
+        let mut extensions = self.retry.extensions();
+        if let Some(limiter) = &self.limiter {
+            extensions.insert(limiter.clone());
+        }
+        extensions
+    }
+
     async fn request<Out>(
         &self,
         method: reqwest::Method,
         uri: &str,
         message: Message,
-    ) -> ClientResult<Out>
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
         let response = self.request_raw(method, uri, message).await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -633,19 +881,26 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
             let error = if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -665,6 +920,7 @@ impl Client {
         let response = self.request_raw(method, uri, message).await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -683,7 +939,7 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
             Ok((link, parsed_response))
         } else {
@@ -691,20 +947,34 @@ impl Client {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
             Err(error)
         }
     }
 
-    /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn post_form<Out>(&self, uri: &str, form: reqwest::multipart::Form) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_form_with_response(uri, form).await?.body)
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn post_form_with_response<Out>(
+        &self,
+        uri: &str,
+        form: reqwest::multipart::Form,
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -721,14 +991,18 @@ impl Client {
         );
 
         if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+            req = req.header("X-Shopify-Access-Token", &*auth_str);
         }
 
         req = req.multipart(form);
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -743,19 +1017,26 @@ impl Client {
                 let s = String::from_utf8(response_body.to_vec())?;
                 serde_json::from_value(serde_json::json!(&s))?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
             let error = if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -763,7 +1044,6 @@ impl Client {
         }
     }
 
-    /* TODO: make this more DRY */
     #[allow(dead_code)]
     async fn request_with_accept_mime<Out>(
         &self,
@@ -771,6 +1051,23 @@ impl Client {
         uri: &str,
         accept_mime_type: &str,
     ) -> ClientResult<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self
+            .request_with_accept_mime_with_response(method, uri, accept_mime_type)
+            .await?
+            .body)
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_with_accept_mime_with_response<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> ClientResult<crate::Response<Out>>
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -778,7 +1075,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(method, url);
+        let mut req = instance.client.request(method.clone(), url);
 
         // Set the default headers.
         req = req.header(
@@ -787,12 +1084,16 @@ impl Client {
         );
 
         if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+            req = req.header("X-Shopify-Access-Token", &*auth_str);
         }
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -807,19 +1108,26 @@ impl Client {
                 let s = String::from_utf8(response_body.to_vec())?;
                 serde_json::from_value(serde_json::json!(&s))?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
-            Ok(parsed_response)
+            Ok(crate::Response {
+                status,
+                headers,
+                raw: response_body,
+                body: parsed_response,
+            })
         } else {
             let error = if response_body.is_empty() {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -843,7 +1151,7 @@ impl Client {
 
         let instance = <&Client>::clone(&self);
 
-        let mut req = instance.client.request(method, url);
+        let mut req = instance.client.request(method.clone(), url);
 
         // Set the default headers.
         req = req.header(
@@ -866,7 +1174,7 @@ impl Client {
         );
 
         if let Some(auth_str) = auth {
-            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+            req = req.header("X-Shopify-Access-Token", &*auth_str);
         }
 
         if content.len() > 1 {
@@ -875,9 +1183,12 @@ impl Client {
             req = req.body(b);
         }
 
-        let response = req.send().await?;
+        let response = req
+            .send_with_extensions(&mut self.request_extensions())
+            .await?;
 
         let status = response.status();
+        let request_id = crate::request_id(response.headers());
 
         let response_body = response.bytes().await?;
 
@@ -888,7 +1199,7 @@ impl Client {
             {
                 serde_json::from_str("null")?
             } else {
-                serde_json::from_slice::<Out>(&response_body)?
+                self.coercions.parse::<Out>(&response_body)?
             };
             Ok(parsed_response)
         } else {
@@ -896,11 +1207,13 @@ impl Client {
                 ClientError::HttpError {
                     status,
                     error: "empty response".into(),
+                    request_id,
                 }
             } else {
                 ClientError::HttpError {
                     status,
                     error: String::from_utf8_lossy(&response_body).into(),
+                    request_id,
                 }
             };
 
@@ -913,16 +1226,27 @@ impl Client {
         method: http::Method,
         uri: &str,
         message: Message,
-    ) -> ClientResult<D>
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let r = self.request(method, uri, message).await?;
-        Ok(r)
+        self.request(method, uri, message).await
     }
 
     #[allow(dead_code)]
     async fn get<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.get_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn get_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -987,6 +1311,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn post<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.post_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn post_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -995,6 +1331,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn patch<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.patch_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn patch_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -1003,6 +1351,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn put<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.put_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn put_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
@@ -1011,6 +1371,18 @@ impl Client {
 
     #[allow(dead_code)]
     async fn delete<D>(&self, uri: &str, message: Message) -> ClientResult<D>
+    where
+        D: serde::de::DeserializeOwned + 'static + Send,
+    {
+        Ok(self.delete_with_response(uri, message).await?.body)
+    }
+
+    #[allow(dead_code)]
+    async fn delete_with_response<D>(
+        &self,
+        uri: &str,
+        message: Message,
+    ) -> ClientResult<crate::Response<D>>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
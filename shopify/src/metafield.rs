@@ -14,764 +14,40 @@ impl Metafield {
     /**
      * Retrieves a list of metafields that belong to a Product Image resource.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/metafields.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/metafields.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get(
-        &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
-        }
-        if !metafield_owner_resource.is_empty() {
-            query_args.push((
-                "metafield[owner_resource]".to_string(),
-                metafield_owner_resource.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/metafields.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new metafield for a resource.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#create-2020-01
-     */
-    pub async fn deprecated_202001_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/metafields.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of a resource's metafields.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/metafields/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#count-2020-01
-     */
-    pub async fn deprecated_202001_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/metafields/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single metafield from a resource by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_param(
-        &self,
-        metafield_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/metafields/{}/json?{}",
-                crate::progenitor_support::encode_path(metafield_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a metafield.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_param(
-        &self,
-        metafield_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a metafield by its ID.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_param(&self, metafield_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of metafields that belong to a Product Image resource.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get(
-        &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
-        }
-        if !metafield_owner_resource.is_empty() {
-            query_args.push((
-                "metafield[owner_resource]".to_string(),
-                metafield_owner_resource.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/metafields.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new metafield for a resource.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#create-2020-04
-     */
-    pub async fn deprecated_202004_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/metafields.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of a resource's metafields.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/metafields/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#count-2020-04
-     */
-    pub async fn deprecated_202004_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/metafields/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single metafield from a resource by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_param(
-        &self,
-        metafield_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/metafields/{}/json?{}",
-                crate::progenitor_support::encode_path(metafield_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a metafield.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_param(
-        &self,
-        metafield_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a metafield by its ID.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_param(&self, metafield_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of metafields that belong to a Product Image resource.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get(
-        &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
-        }
-        if !metafield_owner_resource.is_empty() {
-            query_args.push((
-                "metafield[owner_resource]".to_string(),
-                metafield_owner_resource.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/metafields.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new metafield for a resource.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#create-2020-07
-     */
-    pub async fn deprecated_202007_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/metafields.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of a resource's metafields.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/metafields/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#count-2020-07
-     */
-    pub async fn deprecated_202007_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/metafields/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single metafield from a resource by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_param(
-        &self,
-        metafield_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/metafields/{}/json?{}",
-                crate::progenitor_support::encode_path(metafield_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a metafield.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_param(
-        &self,
-        metafield_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a metafield by its ID.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_param(&self, metafield_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of metafields that belong to a Product Image resource.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
-     */
-    pub async fn get(
-        &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
-        }
-        if !metafield_owner_resource.is_empty() {
-            query_args.push((
-                "metafield[owner_resource]".to_string(),
-                metafield_owner_resource.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/metafields.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new metafield for a resource.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#create-2020-10
-     */
-    pub async fn create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/metafields.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of a resource's metafields.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/metafields/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#count-2020-10
-     */
-    pub async fn get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/metafields/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single metafield from a resource by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_param(&self, metafield_id: &str, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/metafields/{}/json?{}",
-                crate::progenitor_support::encode_path(metafield_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a metafield.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#update-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-10
      *
      * **Parameters:**
      *
-     * * `metafield_id: &str` -- storefront_access_token_id.
+     * * `metafield_owner_id: i64` -- metafield[owner_id].
+     * * `metafield_owner_resource: &str` -- metafield[owner_resource].
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn update_param(
+    pub async fn get_page(
         &self,
-        metafield_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a metafield by its ID.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_param(&self, metafield_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Metafield>> {
+        Ok(self
+            .get_page_with_response(metafield_owner_id, metafield_owner_resource, page_info)
+            .await?
+            .body)
     }
     /**
      * Retrieves a list of metafields that belong to a Product Image resource.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/metafields.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/metafields.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_get(
+    pub async fn get_page_with_response(
         &self,
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Metafield>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if metafield_owner_id > 0 {
             query_args.push((
@@ -785,180 +61,117 @@ impl Metafield {
                 metafield_owner_resource.to_string(),
             ));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/metafields.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new metafield for a resource.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#create-2021-01
-     */
-    pub async fn deprecated_202101_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/metafields.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of a resource's metafields.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/metafields/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#count-2021-01
-     */
-    pub async fn deprecated_202101_get_count(&self) -> ClientResult<()> {
         let url = self
             .client
-            .url("/admin/api/2021-01/metafields/count.json", None);
-        self.client
-            .get(
+            .url(&format!("/admin/api/metafields.json?{}", query_), None);
+        let resp: crate::Response<crate::types::MetafieldsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.metafields.to_vec()))
     }
     /**
-     * Retrieves a single metafield from a resource by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/metafields/{metafield_id}.json` endpoint.
+     * Retrieves a list of metafields that belong to a Product Image resource.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2021-01
+     * This function performs a `GET` to the `/admin/api/metafields.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get`, this function returns all the pages of the request at once.
      *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-2020-10
      */
-    pub async fn deprecated_202101_get_param(
+    pub async fn get_all(
         &self,
-        metafield_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> ClientResult<Vec<crate::types::Metafield>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+        if metafield_owner_id > 0 {
+            query_args.push((
+                "metafield[owner_id]".to_string(),
+                metafield_owner_id.to_string(),
+            ));
+        }
+        if !metafield_owner_resource.is_empty() {
+            query_args.push((
+                "metafield[owner_resource]".to_string(),
+                metafield_owner_resource.to_string(),
+            ));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/metafields/{}/json?{}",
-                crate::progenitor_support::encode_path(metafield_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a metafield.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_param(
-        &self,
-        metafield_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a metafield by its ID.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/metafields/{metafield_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `metafield_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_param(&self, metafield_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/metafields/{}/json",
-                crate::progenitor_support::encode_path(metafield_id),
-            ),
-            None,
+        let url = self
+            .client
+            .url(&format!("/admin/api/metafields.json?{}", query_), None);
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
         );
-        self.client
-            .delete(
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::MetafieldsRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut metafields = resp.metafields;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::MetafieldsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            metafields.append(&mut resp.metafields);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(metafields)
     }
     /**
      * Retrieves a list of metafields that belong to a Product Image resource.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/metafields.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#index-unstable
+     * This function performs a `GET` to the `/admin/api/metafields.json` endpoint.
      *
-     * **Parameters:**
-     *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_unstable_get(
+    pub fn get_all_pages_stream(
         &self,
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
-    ) -> ClientResult<()> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Metafield>>>
+           + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if metafield_owner_id > 0 {
             query_args.push((
@@ -973,31 +186,106 @@ impl Metafield {
             ));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/metafields.json?{}", query_),
+        let url = self
+            .client
+            .url(&format!("/admin/api/metafields.json?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::MetafieldsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.metafields;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of metafields that belong to a Product Image resource.
+     *
+     * This function performs a `GET` to the `/admin/api/metafields.json` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Metafield>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            metafield_owner_id,
+            metafield_owner_resource,
             None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        ))
+    }
+    /**
+     * Creates a new metafield for a resource.
+     *
+     * This function performs a `POST` to the `/admin/api/metafields.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#create-2020-10
+     */
+    pub async fn create(
+        &self,
+        body: &crate::types::MetafieldRequestRoot,
+    ) -> ClientResult<crate::types::MetafieldRoot> {
+        Ok(self.create_with_response(body).await?.body)
     }
     /**
      * Creates a new metafield for a resource.
      *
-     * This function performs a `POST` to the `/admin/api/unstable/metafields.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/metafields.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#create-unstable
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/metafields.json", None);
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::MetafieldRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::MetafieldRoot>> {
+        let url = self.client.url("/admin/api/metafields.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1009,16 +297,26 @@ impl Metafield {
     /**
      * Retrieves a count of a resource's metafields.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/metafields/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/metafields/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#count-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#count-2020-10
      */
-    pub async fn deprecated_unstable_get_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/metafields/count.json", None);
+    pub async fn get_count(&self) -> ClientResult<crate::types::Count> {
+        Ok(self.get_count_with_response().await?.body)
+    }
+    /**
+     * Retrieves a count of a resource's metafields.
+     *
+     * This function performs a `GET` to the `/admin/api/metafields/count.json` endpoint.
+     *
+     * As opposed to `get_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_count_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
+        let url = self.client.url("/admin/api/metafields/count.json", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1030,20 +328,37 @@ impl Metafield {
     /**
      * Retrieves a single metafield from a resource by its ID.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/metafields/{metafield_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/metafields/{metafield_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#show-2020-10
      *
      * **Parameters:**
      *
-     * * `metafield_id: &str` -- storefront_access_token_id.
+     * * `metafield_id: &str` -- metafield_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_unstable_get_param(
+    pub async fn get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::types::MetafieldRoot> {
+        Ok(self
+            .get_param_with_response(metafield_id, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single metafield from a resource by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `get_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_param_with_response(
         &self,
         metafield_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<crate::types::MetafieldRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -1051,14 +366,14 @@ impl Metafield {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/metafields/{}/json?{}",
+                "/admin/api/metafields/{}.json?{}",
                 crate::progenitor_support::encode_path(metafield_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1070,28 +385,45 @@ impl Metafield {
     /**
      * Updates a metafield.
      *
-     * This function performs a `PUT` to the `/admin/api/unstable/metafields/{metafield_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/metafields/{metafield_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#update-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#update-2020-10
      *
      * **Parameters:**
      *
-     * * `metafield_id: &str` -- storefront_access_token_id.
+     * * `metafield_id: &str` -- metafield_id.
+     */
+    pub async fn update_param(
+        &self,
+        metafield_id: &str,
+        body: &crate::types::MetafieldRequestRoot,
+    ) -> ClientResult<crate::types::MetafieldRoot> {
+        Ok(self
+            .update_param_with_response(metafield_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a metafield.
+     *
+     * This function performs a `PUT` to the `/admin/api/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `update_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_unstable_update_param(
+    pub async fn update_param_with_response(
         &self,
         metafield_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::MetafieldRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::MetafieldRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/metafields/{}/json",
+                "/admin/api/metafields/{}.json",
                 crate::progenitor_support::encode_path(metafield_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1103,24 +435,38 @@ impl Metafield {
     /**
      * Deletes a metafield by its ID.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/metafields/{metafield_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/metafields/{metafield_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/metafield#destroy-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/metafield#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `metafield_id: &str` -- storefront_access_token_id.
+     * * `metafield_id: &str` -- metafield_id.
+     */
+    pub async fn delete_param(&self, metafield_id: &str) -> ClientResult<()> {
+        self.delete_param_with_response(metafield_id).await?;
+        Ok(())
+    }
+    /**
+     * Deletes a metafield by its ID.
+     *
+     * This function performs a `DELETE` to the `/admin/api/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `delete_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_unstable_delete_param(&self, metafield_id: &str) -> ClientResult<()> {
+    pub async fn delete_param_with_response(
+        &self,
+        metafield_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/metafields/{}/json",
+                "/admin/api/metafields/{}.json",
                 crate::progenitor_support::encode_path(metafield_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
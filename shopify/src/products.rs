@@ -14,23 +14,44 @@ impl Products {
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/collects.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collects.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-10
      *
      * **Parameters:**
      *
      * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
+     *   (default: 50, maximum: 250).
      * * `since_id: &str` -- Restrict results to after the specified ID.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202001_get_collect(
+    pub async fn get_collects(
         &self,
         limit: &str,
         since_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Collect>> {
+        Ok(self
+            .get_collects_with_response(limit, since_id, fields, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/collects.json` endpoint.
+     *
+     * As opposed to `get_collects`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_collects_with_response(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Collect>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -38,38 +59,214 @@ impl Products {
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/collects.json?{}", query_),
-            None,
+        let url = self
+            .client
+            .url(&format!("/admin/api/collects.json?{}", query_), None);
+        let resp: crate::Response<crate::types::CollectsRoot> = self
+            .client
+            .get_with_response(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.collects.to_vec()))
+    }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/collects.json` endpoint.
+     *
+     * As opposed to `get_collects`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-10
+     */
+    pub async fn get_all_collects(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Collect>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/collects.json?{}", query_), None);
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
         );
-        self.client
-            .get(
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::CollectsRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut collects = resp.collects;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::CollectsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            collects.append(&mut resp.collects);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(collects)
+    }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/collects.json` endpoint.
+     *
+     * As opposed to `get_all_collects`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_collects_pages_stream(
+        &self,
+        since_id: &str,
+        fields: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Collect>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/collects.json?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (Option<crate::utils::NextLink>, crate::types::CollectsRoot) =
+                        self.client
+                            .request_with_links(
+                                reqwest::Method::GET,
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.collects;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/collects.json` endpoint.
+     *
+     * As opposed to `get_all_collects`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_collects_stream(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Collect>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_collects_pages_stream(since_id, fields, None))
     }
     /**
      * Adds a product to a custom collection.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/collects.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/collects.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#create-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#create-2020-10
      */
-    pub async fn deprecated_202001_create_collects(
+    pub async fn create_collects(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/collects.json", None);
+        body: &crate::types::CollectRequestRoot,
+    ) -> ClientResult<crate::types::CollectRoot> {
+        Ok(self.create_collects_with_response(body).await?.body)
+    }
+    /**
+     * Adds a product to a custom collection.
+     *
+     * This function performs a `POST` to the `/admin/api/collects.json` endpoint.
+     *
+     * As opposed to `create_collects`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_collects_with_response(
+        &self,
+        body: &crate::types::CollectRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CollectRoot>> {
+        let url = self.client.url("/admin/api/collects.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -81,20 +278,37 @@ impl Products {
     /**
      * Retrieves a specific collect by its ID.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/collects/{collect_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collects/{collect_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-10
      *
      * **Parameters:**
      *
-     * * `collect_id: &str` -- storefront_access_token_id.
+     * * `collect_id: &str` -- collect_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202001_get_collects_param_collect(
+    pub async fn get_collects_param_collect(
         &self,
         collect_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CollectRoot> {
+        Ok(self
+            .get_collects_param_collect_with_response(collect_id, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a specific collect by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `get_collects_param_collect`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_collects_param_collect_with_response(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::CollectRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -102,14 +316,14 @@ impl Products {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/collects/{}/json?{}",
+                "/admin/api/collects/{}.json?{}",
                 crate::progenitor_support::encode_path(collect_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -121,27 +335,39 @@ impl Products {
     /**
      * Removes a product from a collection.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/collects/{collect_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/collects/{collect_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#destroy-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `collect_id: &str` -- storefront_access_token_id.
+     * * `collect_id: &str` -- collect_id.
+     */
+    pub async fn delete_collects_param_collect(&self, collect_id: &str) -> ClientResult<()> {
+        self.delete_collects_param_collect_with_response(collect_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Removes a product from a collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `delete_collects_param_collect`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202001_delete_collects_param_collect(
+    pub async fn delete_collects_param_collect_with_response(
         &self,
         collect_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/collects/{}/json",
+                "/admin/api/collects/{}.json",
                 crate::progenitor_support::encode_path(collect_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -153,29 +379,44 @@ impl Products {
     /**
      * Retrieves a count of collects.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/collects/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collects/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-10
      *
      * **Parameters:**
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * * `collection_id: i64` -- collection_id.
      */
-    pub async fn deprecated_202001_get_collects_count(
+    pub async fn get_collects_count(
         &self,
         collection_id: i64,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_collects_count_with_response(collection_id)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a count of collects.
+     *
+     * This function performs a `GET` to the `/admin/api/collects/count.json` endpoint.
+     *
+     * As opposed to `get_collects_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_collects_count_with_response(
+        &self,
+        collection_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if collection_id > 0 {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/collects/count.json?{}", query_),
-            None,
-        );
+        let url = self
+            .client
+            .url(&format!("/admin/api/collects/count.json?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -185,42 +426,54 @@ impl Products {
             .await
     }
     /**
-     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Retrieves a single collection.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/collects.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collections/{collection_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-10
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `collection_id: &str` -- collection_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202004_get_collect(
+    pub async fn get_collections_param_collection(
         &self,
-        limit: &str,
-        since_id: &str,
+        collection_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CollectionRoot> {
+        Ok(self
+            .get_collections_param_collection_with_response(collection_id, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single collection.
+     *
+     * This function performs a `GET` to the `/admin/api/collections/{collection_id}.json` endpoint.
+     *
+     * As opposed to `get_collections_param_collection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_collections_param_collection_with_response(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::CollectionRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-04/collects.json?{}", query_),
+            &format!(
+                "/admin/api/collections/{}.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -230,6904 +483,255 @@ impl Products {
             .await
     }
     /**
-     * Adds a product to a custom collection.
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
-     * This function performs a `POST` to the `/admin/api/2020-04/collects.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collections/{collection_id}/products.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-10
+     *
+     * **Parameters:**
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#create-2020-04
+     * * `collection_id: &str` -- collection_id.
+     * * `limit: &str` -- The number of products to retrieve.
+     *   (default: 50, maximum: 250).
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202004_create_collects(
+    pub async fn get_collections_param_collection_products(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/collects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
+        collection_id: &str,
+        limit: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Product>> {
+        Ok(self
+            .get_collections_param_collection_products_with_response(
+                collection_id,
+                limit,
+                page_info,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-     * Retrieves a specific collect by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-04
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/collections/{collection_id}/products.json` endpoint.
      *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * As opposed to `get_collections_param_collection_products`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get_collects_param_collect(
+    pub async fn get_collections_param_collection_products_with_response(
         &self,
-        collect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+        collection_id: &str,
+        limit: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/collects/{}/json?{}",
-                crate::progenitor_support::encode_path(collect_id),
+                "/admin/api/collections/{}/products.json?{}",
+                crate::progenitor_support::encode_path(collection_id),
                 query_
             ),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::ProductsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.products.to_vec()))
     }
     /**
-     * Removes a product from a collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/collects/{collect_id}.json` endpoint.
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#destroy-2020-04
+     * This function performs a `GET` to the `/admin/api/collections/{collection_id}/products.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_collections_param_collection`, this function returns all the pages of the request at once.
      *
-     * * `collect_id: &str` -- storefront_access_token_id.
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-10
      */
-    pub async fn deprecated_202004_delete_collects_param_collect(
+    pub async fn get_all_collections_param_collection(
         &self,
-        collect_id: &str,
-    ) -> ClientResult<()> {
+        collection_id: &str,
+    ) -> ClientResult<Vec<crate::types::Product>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/collects/{}/json",
-                crate::progenitor_support::encode_path(collect_id),
+                "/admin/api/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
             ),
             None,
         );
-        self.client
-            .delete(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::ProductsRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut products = resp.products;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::ProductsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            products.append(&mut resp.products);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(products)
     }
     /**
-     * Retrieves a count of collects.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/collects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-04
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/collections/{collection_id}/products.json` endpoint.
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * As opposed to `get_all_collections_param_collection`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202004_get_collects_count(
+    pub fn get_all_collections_param_collection_pages_stream(
         &self,
-        collection_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        collection_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Product>>> + Unpin + '_
+    {
         let url = self.client.url(
-            &format!("/admin/api/2020-04/collects/count.json?{}", query_),
+            &format!(
+                "/admin/api/collections/{}/products.json",
+                crate::progenitor_support::encode_path(collection_id),
+            ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (Option<crate::utils::NextLink>, crate::types::ProductsRoot) =
+                        self.client
+                            .request_with_links(
+                                reqwest::Method::GET,
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.products;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-07
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/collections/{collection_id}/products.json` endpoint.
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_collect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/collects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adds a product to a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#create-2020-07
-     */
-    pub async fn deprecated_202007_create_collects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/collects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific collect by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_collects_param_collect(
-        &self,
-        collect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collects/{}/json?{}",
-                crate::progenitor_support::encode_path(collect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Removes a product from a collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_collects_param_collect(
-        &self,
-        collect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collects/{}/json",
-                crate::progenitor_support::encode_path(collect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of collects.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202007_get_collects_count(
-        &self,
-        collection_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/collects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_collect(&self, limit: &str, since_id: &str, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/collects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adds a product to a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#create-2020-10
-     */
-    pub async fn create_collects(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/collects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific collect by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_collects_param_collect(
-        &self,
-        collect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collects/{}/json?{}",
-                crate::progenitor_support::encode_path(collect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Removes a product from a collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_collects_param_collect(&self, collect_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collects/{}/json",
-                crate::progenitor_support::encode_path(collect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of collects.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn get_collects_count(&self, collection_id: i64) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/collects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_collect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/collects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adds a product to a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#create-2021-01
-     */
-    pub async fn deprecated_202101_create_collects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/collects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific collect by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_collects_param_collect(
-        &self,
-        collect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collects/{}/json?{}",
-                crate::progenitor_support::encode_path(collect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Removes a product from a collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_collects_param_collect(
-        &self,
-        collect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collects/{}/json",
-                crate::progenitor_support::encode_path(collect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of collects.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202101_get_collects_count(
-        &self,
-        collection_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/collects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_collect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/collects.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Adds a product to a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/collects.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#create-unstable
-     */
-    pub async fn deprecated_unstable_create_collects(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/collects.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific collect by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_collects_param_collect(
-        &self,
-        collect_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collects/{}/json?{}",
-                crate::progenitor_support::encode_path(collect_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Removes a product from a collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/collects/{collect_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collect_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_collects_param_collect(
-        &self,
-        collect_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collects/{}/json",
-                crate::progenitor_support::encode_path(collect_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of collects.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collects/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collect#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_unstable_get_collects_count(
-        &self,
-        collection_id: i64,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/collects/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/collections/{collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_collections_param_collection(
-        &self,
-        collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/collections/{}/json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/collections/{collection_id}/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
-     */
-    pub async fn deprecated_202001_get_collections_param_collection_products(
-        &self,
-        collection_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/collections/{}/products.json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/collections/{collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_collections_param_collection(
-        &self,
-        collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/collections/{}/json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/collections/{collection_id}/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
-     */
-    pub async fn deprecated_202004_get_collections_param_collection_products(
-        &self,
-        collection_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/collections/{}/products.json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collections/{collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_collections_param_collection(
-        &self,
-        collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collections/{}/json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collections/{collection_id}/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
-     */
-    pub async fn deprecated_202007_get_collections_param_collection_products(
-        &self,
-        collection_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collections/{}/products.json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collections/{collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_collections_param_collection(
-        &self,
-        collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collections/{}/json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collections/{collection_id}/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
-     */
-    pub async fn get_collections_param_collection_products(
-        &self,
-        collection_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collections/{}/products.json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collections/{collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_collections_param_collection(
-        &self,
-        collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collections/{}/json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collections/{collection_id}/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
-     */
-    pub async fn deprecated_202101_get_collections_param_collection_products(
-        &self,
-        collection_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collections/{}/products.json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single collection.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collections/{collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_collections_param_collection(
-        &self,
-        collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collections/{}/json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collections/{collection_id}/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
-     */
-    pub async fn deprecated_unstable_get_collections_param_collection_products(
-        &self,
-        collection_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collections/{}/products.json?{}",
-                crate::progenitor_support::encode_path(collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_custom_collection(
-        &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/custom_collections.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2020-01
-     */
-    pub async fn deprecated_202001_create_custom_collections(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/custom_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of custom collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/custom_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
-     */
-    pub async fn deprecated_202001_get_custom_collections_count(
-        &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/custom_collections/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single custom collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/custom_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(custom_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing custom collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a custom collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_custom_collection(
-        &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/custom_collections.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2020-04
-     */
-    pub async fn deprecated_202004_create_custom_collections(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/custom_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of custom collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/custom_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
-     */
-    pub async fn deprecated_202004_get_custom_collections_count(
-        &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/custom_collections/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single custom collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/custom_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(custom_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing custom collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a custom collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_custom_collection(
-        &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/custom_collections.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2020-07
-     */
-    pub async fn deprecated_202007_create_custom_collections(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/custom_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of custom collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/custom_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
-     */
-    pub async fn deprecated_202007_get_custom_collections_count(
-        &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/custom_collections/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single custom collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202007_get_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/custom_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(custom_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing custom collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a custom collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_custom_collection(
-        &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/custom_collections.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2020-10
-     */
-    pub async fn create_custom_collections(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/custom_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of custom collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/custom_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
-     */
-    pub async fn get_custom_collections_count(
-        &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/custom_collections/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single custom collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn get_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/custom_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(custom_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing custom collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a custom collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_custom_collection(
-        &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/custom_collections.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2021-01
-     */
-    pub async fn deprecated_202101_create_custom_collections(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/custom_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of custom collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/custom_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
-     */
-    pub async fn deprecated_202101_get_custom_collections_count(
-        &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/custom_collections/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single custom collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202101_get_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/custom_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(custom_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing custom collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a custom collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_custom_collection(
-        &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/custom_collections.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-unstable
-     */
-    pub async fn deprecated_unstable_create_custom_collections(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/custom_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of custom collections.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/custom_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
-     */
-    pub async fn deprecated_unstable_get_custom_collections_count(
-        &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/custom_collections/count.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single custom collection.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_unstable_get_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/custom_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(custom_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing custom collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a custom collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/custom_collections/{custom_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `custom_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_custom_collections_param_collection(
-        &self,
-        custom_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/custom_collections/{}/json",
-                crate::progenitor_support::encode_path(custom_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
-     */
-    pub async fn deprecated_202001_get(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/products.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a new product.
-              If you want to set the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/products.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#create-2020-01
-    */
-    pub async fn deprecated_202001_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/products.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of products.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/products/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     */
-    pub async fn deprecated_202001_get_count(
-        &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/products/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single product.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_param(
-        &self,
-        product_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-01/products/{product_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#update-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `product_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202001_update_param(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a product.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_param(&self, product_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
-     */
-    pub async fn deprecated_202004_get(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/products.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a new product.
-              If you want to set the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/products.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#create-2020-04
-    */
-    pub async fn deprecated_202004_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/products.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of products.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/products/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     */
-    pub async fn deprecated_202004_get_count(
-        &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/products/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single product.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_param(
-        &self,
-        product_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-04/products/{product_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#update-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `product_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_update_param(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a product.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_param(&self, product_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
-     */
-    pub async fn deprecated_202007_get(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/products.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a new product.
-              If you want to set the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/products.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#create-2020-07
-    */
-    pub async fn deprecated_202007_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/products.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of products.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/products/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     */
-    pub async fn deprecated_202007_get_count(
-        &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/products/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single product.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_param(
-        &self,
-        product_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-07/products/{product_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#update-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `product_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_update_param(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a product.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_param(&self, product_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
-     */
-    pub async fn get(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/products.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a new product.
-              If you want to set the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/products.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#create-2020-10
-    */
-    pub async fn create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/products.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of products.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/products/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     */
-    pub async fn get_count(
-        &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/products/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single product.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn get_param(&self, product_id: &str, fields: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-10/products/{product_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#update-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `product_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn update_param(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a product.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_param(&self, product_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
-     */
-    pub async fn deprecated_202101_get(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/products.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a new product.
-              If you want to set the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/products.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#create-2021-01
-    */
-    pub async fn deprecated_202101_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/products.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of products.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/products/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     */
-    pub async fn deprecated_202101_get_count(
-        &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/products/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single product.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_param(
-        &self,
-        product_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `PUT` to the `/admin/api/2021-01/products/{product_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#update-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `product_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_update_param(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a product.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_param(&self, product_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
-     */
-    pub async fn deprecated_unstable_get(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/products.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Creates a new product.
-              If you want to set the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/products.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#create-unstable
-    */
-    pub async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/products.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of products.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/products/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     */
-    pub async fn deprecated_unstable_get_count(
-        &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/products/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single product.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_param(
-        &self,
-        product_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
-    *
-    * This function performs a `PUT` to the `/admin/api/unstable/products/{product_id}.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/products/product#update-unstable
-    *
-    * **Parameters:**
-    *
-    * * `product_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_update_param(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a product.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/products/{product_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_param(&self, product_id: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_param_image(
-        &self,
-        product_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/images.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new product image.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#create-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_param_images(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/images.json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a count of all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}/images/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn deprecated_202001_get_param_images_count(
-        &self,
-        product_id: &str,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/images/count.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a single product image by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/images/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modify an existing product image.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * .
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_param_image(
-        &self,
-        product_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/images.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new product image.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#create-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_param_images(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/images.json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a count of all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}/images/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn deprecated_202004_get_param_images_count(
-        &self,
-        product_id: &str,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/images/count.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a single product image by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/images/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modify an existing product image.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * .
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_param_image(
-        &self,
-        product_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/images.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new product image.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#create-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_param_images(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/images.json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a count of all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}/images/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn deprecated_202007_get_param_images_count(
-        &self,
-        product_id: &str,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/images/count.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a single product image by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/images/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modify an existing product image.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * .
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn get_param_image(
-        &self,
-        product_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/images.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new product image.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#create-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_param_images(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/images.json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a count of all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}/images/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn get_param_images_count(
-        &self,
-        product_id: &str,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/images/count.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a single product image by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn get_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/images/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modify an existing product image.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * .
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_param_image(
-        &self,
-        product_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/images.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new product image.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#create-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_param_images(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/images.json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a count of all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}/images/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn deprecated_202101_get_param_images_count(
-        &self,
-        product_id: &str,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/images/count.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a single product image by id.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/images/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modify an existing product image.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * .
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_param_image(
-        &self,
-        product_id: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/images.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a new product image.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/products/{product_id}/images.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#create-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_param_images(
-        &self,
-        product_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/images.json",
-                crate::progenitor_support::encode_path(product_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Get a count of all product images.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}/images/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     */
-    pub async fn deprecated_unstable_get_param_images_count(
-        &self,
-        product_id: &str,
-        since_id: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/images/count.json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Get a single product image by id.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/images/{}/json?{}",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modify an existing product image.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * .
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/products/{product_id}/images/{image_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `product_id: &str` -- storefront_access_token_id.
-     * * `image_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_param_images_image(
-        &self,
-        product_id: &str,
-        image_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/products/{}/images/{}/json",
-                crate::progenitor_support::encode_path(product_id),
-                crate::progenitor_support::encode_path(image_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/smart_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_smart_collection(
-        &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/smart_collections.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a new smart collection using the specified rules.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/smart_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2020-01
-     */
-    pub async fn deprecated_202001_create_smart_collections(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/smart_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of smart collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/smart_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     */
-    pub async fn deprecated_202001_get_smart_collections_count(
-        &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/smart_collections/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single smart collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/smart_collections/{smart_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202001_get_smart_collections_param_collection(
-        &self,
-        smart_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/smart_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing smart collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/smart_collections/{smart_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_smart_collections_param_collection(
-        &self,
-        smart_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes a smart collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/smart_collections/{smart_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_smart_collections_param_collection(
-        &self,
-        smart_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the ordering type of products in a smart collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/smart_collections/{smart_collection_id}/order.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202001_update_smart_collections_param_collection_order(
-        &self,
-        smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
-        }
-        if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/smart_collections/{}/order.json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+     * As opposed to `get_all_collections_param_collection`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_collections_param_collection_stream(
+        &self,
+        collection_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Product>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_collections_param_collection_pages_stream(collection_id, None),
+        )
     }
     /**
-     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/smart_collections.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/custom_collections.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-10
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
+     * * `limit: &str` -- The maximum number of results to retrieve.
+     *   (default: 50, maximum: 250).
+     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
      * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `title: &str` -- Show custom collections with a given title.
+     * * `product_id: &str` -- Show custom collections that include a given product.
+     * * `handle: &str` -- Filter by custom collection handle.
+     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_status: &str` -- Show custom collectsion with a given published status.
+     *   (default: any)
+     *   
+     *   published: Show only published custom collections.
+     *   unpublished: Show only unpublished custom collections.
+     *   any: Show custom collections of any published status.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202004_get_smart_collection(
+    pub async fn get_custom_collections(
         &self,
         limit: &str,
         ids: &str,
@@ -7141,7 +745,50 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Collection>> {
+        Ok(self
+            .get_custom_collections_with_response(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/custom_collections.json` endpoint.
+     *
+     * As opposed to `get_custom_collections`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_custom_collections_with_response(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Collection>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -7155,6 +802,9 @@ impl Products {
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         if !product_id.is_empty() {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
@@ -7181,76 +831,56 @@ impl Products {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-04/smart_collections.json?{}", query_),
+            &format!("/admin/api/custom_collections.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::CustomCollectionsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
-    }
-    /**
-     * Creates a new smart collection using the specified rules.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/smart_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2020-04
-     */
-    pub async fn deprecated_202004_create_smart_collections(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/smart_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.custom_collections.to_vec()))
     }
     /**
-     * Retrieves a count of smart collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/smart_collections/count.json` endpoint.
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-04
+     * This function performs a `GET` to the `/admin/api/custom_collections.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_custom_collections`, this function returns all the pages of the request at once.
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#index-2020-10
      */
-    pub async fn deprecated_202004_get_smart_collections_count(
+    pub async fn get_all_custom_collections(
         &self,
+        ids: &str,
+        since_id: &str,
         title: &str,
         product_id: &str,
+        handle: &str,
         updated_at_min: &str,
         updated_at_max: &str,
         published_at_min: &str,
         published_at_max: &str,
         published_status: &str,
-    ) -> ClientResult<()> {
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Collection>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
         if !product_id.is_empty() {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
@@ -7263,6 +893,9 @@ impl Products {
         if !published_status.is_empty() {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
         if !title.is_empty() {
             query_args.push(("title".to_string(), title.to_string()));
         }
@@ -7274,203 +907,65 @@ impl Products {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-04/smart_collections/count.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single smart collection.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/smart_collections/{smart_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     */
-    pub async fn deprecated_202004_get_smart_collections_param_collection(
-        &self,
-        smart_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/smart_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates an existing smart collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/smart_collections/{smart_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_smart_collections_param_collection(
-        &self,
-        smart_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
-            ),
+            &format!("/admin/api/custom_collections.json?{}", query_),
             None,
         );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Removes a smart collection.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/smart_collections/{smart_collection_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_smart_collections_param_collection(
-        &self,
-        smart_collection_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
-            ),
-            None,
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
         );
-        self.client
-            .delete(
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::CustomCollectionsRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
-    }
-    /**
-     * Updates the ordering type of products in a smart collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/smart_collections/{smart_collection_id}/order.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
-     */
-    pub async fn deprecated_202004_update_smart_collections_param_collection_order(
-        &self,
-        smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
-        }
-        if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
+            .await?;
+
+        let mut custom_collections = resp.custom_collections;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::CustomCollectionsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            custom_collections.append(&mut resp.custom_collections);
+
+            link = next_link.filter(|l| l.0 != next.0);
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/smart_collections/{}/order.json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+
+        // Return our response data.
+        Ok(custom_collections)
     }
     /**
-     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/smart_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-07
-     *
-     * **Parameters:**
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * This function performs a `GET` to the `/admin/api/custom_collections.json` endpoint.
+     *
+     * As opposed to `get_all_custom_collections`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202007_get_smart_collection(
+    pub fn get_all_custom_collections_pages_stream(
         &self,
-        limit: &str,
         ids: &str,
         since_id: &str,
         title: &str,
@@ -7482,7 +977,10 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Collection>>>
+           + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -7493,9 +991,6 @@ impl Products {
         if !ids.is_empty() {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
         if !product_id.is_empty() {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
@@ -7522,35 +1017,127 @@ impl Products {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-07/smart_collections.json?{}", query_),
+            &format!("/admin/api/custom_collections.json?{}", query_),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::CustomCollectionsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.custom_collections;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Creates a new smart collection using the specified rules.
+     * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `POST` to the `/admin/api/2020-07/smart_collections.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/custom_collections.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2020-07
+     * As opposed to `get_all_custom_collections`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
      */
-    pub async fn deprecated_202007_create_smart_collections(
+    pub fn get_all_custom_collections_stream(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/smart_collections.json", None);
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Collection>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_custom_collections_pages_stream(
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            None,
+        ))
+    }
+    /**
+     * Creates a custom collection.
+     *
+     * This function performs a `POST` to the `/admin/api/custom_collections.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2020-10
+     */
+    pub async fn create_custom_collections(
+        &self,
+        body: &crate::types::CustomCollectionRequestRoot,
+    ) -> ClientResult<crate::types::CustomCollectionRoot> {
+        Ok(self
+            .create_custom_collections_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a custom collection.
+     *
+     * This function performs a `POST` to the `/admin/api/custom_collections.json` endpoint.
+     *
+     * As opposed to `create_custom_collections`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_custom_collections_with_response(
+        &self,
+        body: &crate::types::CustomCollectionRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomCollectionRoot>> {
+        let url = self.client.url("/admin/api/custom_collections.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7560,28 +1147,28 @@ impl Products {
             .await
     }
     /**
-     * Retrieves a count of smart collections.
+     * Retrieves a count of custom collections.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/smart_collections/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/custom_collections/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#count-2020-10
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `title: &str` -- Count custom collections with given title.
+     * * `product_id: &str` -- Count custom collections that include a given product.
+     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+     * * `published_status: &str` -- Count custom collections with a given published status.
+     *   (default: any)
+     *   
+     *   published: Count only published custom collections.
+     *   unpublished: Count only unpublished custom collections.
+     *   any: Count custom collections of any published status.
      */
-    pub async fn deprecated_202007_get_smart_collections_count(
+    pub async fn get_custom_collections_count(
         &self,
         title: &str,
         product_id: &str,
@@ -7590,7 +1177,37 @@ impl Products {
         published_at_min: &str,
         published_at_max: &str,
         published_status: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_custom_collections_count_with_response(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a count of custom collections.
+     *
+     * This function performs a `GET` to the `/admin/api/custom_collections/count.json` endpoint.
+     *
+     * As opposed to `get_custom_collections_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_custom_collections_count_with_response(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !product_id.is_empty() {
             query_args.push(("product_id".to_string(), product_id.to_string()));
@@ -7615,11 +1232,11 @@ impl Products {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-07/smart_collections/count.json?{}", query_),
+            &format!("/admin/api/custom_collections/count.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7629,22 +1246,39 @@ impl Products {
             .await
     }
     /**
-     * Retrieves a single smart collection.
+     * Retrieves a single custom collection.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/custom_collections/{custom_collection_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#show-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `custom_collection_id: &str` -- custom_collection_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202007_get_smart_collections_param_collection(
+    pub async fn get_custom_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        custom_collection_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::CustomCollectionRoot> {
+        Ok(self
+            .get_custom_collections_param_collection_with_response(custom_collection_id, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single custom collection.
+     *
+     * This function performs a `GET` to the `/admin/api/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `get_custom_collections_param_collection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_custom_collections_param_collection_with_response(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomCollectionRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -7652,14 +1286,14 @@ impl Products {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/smart_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
+                "/admin/api/custom_collections/{}.json?{}",
+                crate::progenitor_support::encode_path(custom_collection_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7669,30 +1303,47 @@ impl Products {
             .await
     }
     /**
-     * Updates an existing smart collection.
+     * Updates an existing custom collection.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-07/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/custom_collections/{custom_collection_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#update-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `custom_collection_id: &str` -- custom_collection_id.
      */
-    pub async fn deprecated_202007_update_smart_collections_param_collection(
+    pub async fn update_custom_collections_param_collection(
         &self,
-        smart_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        custom_collection_id: &str,
+        body: &crate::types::CustomCollectionRequestRoot,
+    ) -> ClientResult<crate::types::CustomCollectionRoot> {
+        Ok(self
+            .update_custom_collections_param_collection_with_response(custom_collection_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an existing custom collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `update_custom_collections_param_collection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_custom_collections_param_collection_with_response(
+        &self,
+        custom_collection_id: &str,
+        body: &crate::types::CustomCollectionRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::CustomCollectionRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
+                "/admin/api/custom_collections/{}.json",
+                crate::progenitor_support::encode_path(custom_collection_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7702,129 +1353,418 @@ impl Products {
             .await
     }
     /**
-     * Removes a smart collection.
+     * Deletes a custom collection.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/custom_collections/{custom_collection_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#destroy-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `custom_collection_id: &str` -- custom_collection_id.
      */
-    pub async fn deprecated_202007_delete_smart_collections_param_collection(
+    pub async fn delete_custom_collections_param_collection(
         &self,
-        smart_collection_id: &str,
+        custom_collection_id: &str,
     ) -> ClientResult<()> {
+        self.delete_custom_collections_param_collection_with_response(custom_collection_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a custom collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `delete_custom_collections_param_collection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_custom_collections_param_collection_with_response(
+        &self,
+        custom_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
+                "/admin/api/custom_collections/{}.json",
+                crate::progenitor_support::encode_path(custom_collection_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await
+    }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/products.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
+     * * `limit: &str` -- Return up to this many results per page.
+     *   (default: 50, maximum: 250).
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `title: &str` -- Filter results by product title.
+     * * `vendor: &str` -- Filter results by product vendor.
+     * * `handle: &str` -- Filter results by product handle.
+     * * `product_type: &str` -- Filter results by product type.
+     * * `status: &str` -- Return products by their status.
+     *   (default: active)
+     *   
+     *   active: Show only active products.
+     *   archived: Show only archived products.
+     *   draft: Show only draft products.
+     * * `collection_id: &str` -- Filter results by product collection ID.
+     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_status: &str` -- Return products by their published status
+     *   (default: any)
+     *   
+     *   published: Show only published products.
+     *   unpublished: Show only unpublished products.
+     *   any: Show all products.
+     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
+     */
+    pub async fn get_page(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Product>> {
+        Ok(self
+            .get_page_with_response(
+                ids,
+                limit,
+                since_id,
+                title,
+                vendor,
+                handle,
+                product_type,
+                status,
+                collection_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+                presentment_currencies,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/products.json` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Product>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !collection_id.is_empty() {
+            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        if !presentment_currencies.is_empty() {
+            query_args.push((
+                "presentment_currencies".to_string(),
+                presentment_currencies.to_string(),
+            ));
+        }
+        if !product_type.is_empty() {
+            query_args.push(("product_type".to_string(), product_type.to_string()));
+        }
+        if !published_at_max.is_empty() {
+            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+        }
+        if !published_at_min.is_empty() {
+            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+        }
+        if !published_status.is_empty() {
+            query_args.push(("published_status".to_string(), published_status.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !title.is_empty() {
+            query_args.push(("title".to_string(), title.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        if !vendor.is_empty() {
+            query_args.push(("vendor".to_string(), vendor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/admin/api/products.json?{}", query_), None);
+        let resp: crate::Response<crate::types::ProductsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.products.to_vec()))
     }
     /**
-     * Updates the ordering type of products in a smart collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/smart_collections/{smart_collection_id}/order.json` endpoint.
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-07
+     * This function performs a `GET` to the `/admin/api/products.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get`, this function returns all the pages of the request at once.
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#index-2020-10
      */
-    pub async fn deprecated_202007_update_smart_collections_param_collection_order(
+    pub async fn get_all(
         &self,
-        smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> ClientResult<Vec<crate::types::Product>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+        if !collection_id.is_empty() {
+            query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
+        if !presentment_currencies.is_empty() {
+            query_args.push((
+                "presentment_currencies".to_string(),
+                presentment_currencies.to_string(),
+            ));
+        }
+        if !product_type.is_empty() {
+            query_args.push(("product_type".to_string(), product_type.to_string()));
+        }
+        if !published_at_max.is_empty() {
+            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+        }
+        if !published_at_min.is_empty() {
+            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+        }
+        if !published_status.is_empty() {
+            query_args.push(("published_status".to_string(), published_status.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !title.is_empty() {
+            query_args.push(("title".to_string(), title.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        if !vendor.is_empty() {
+            query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/smart_collections/{}/order.json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
-            ),
-            None,
+        let url = self
+            .client
+            .url(&format!("/admin/api/products.json?{}", query_), None);
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
         );
-        self.client
-            .put(
+        let (mut link, resp): (Option<crate::utils::NextLink>, crate::types::ProductsRoot) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut products = resp.products;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::ProductsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            products.append(&mut resp.products);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(products)
     }
     /**
-     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/smart_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-10
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/products.json` endpoint.
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn get_smart_collection(
+    pub fn get_all_pages_stream(
         &self,
-        limit: &str,
         ids: &str,
         since_id: &str,
         title: &str,
-        product_id: &str,
+        vendor: &str,
         handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
         published_at_min: &str,
         published_at_max: &str,
         published_status: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        presentment_currencies: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Product>>> + Unpin + '_
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
+        if !collection_id.is_empty() {
+            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
@@ -7834,11 +1774,14 @@ impl Products {
         if !ids.is_empty() {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+        if !presentment_currencies.is_empty() {
+            query_args.push((
+                "presentment_currencies".to_string(),
+                presentment_currencies.to_string(),
+            ));
         }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+        if !product_type.is_empty() {
+            query_args.push(("product_type".to_string(), product_type.to_string()));
         }
         if !published_at_max.is_empty() {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
@@ -7852,6 +1795,9 @@ impl Products {
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
         if !title.is_empty() {
             query_args.push(("title".to_string(), title.to_string()));
         }
@@ -7861,34 +1807,141 @@ impl Products {
         if !updated_at_min.is_empty() {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
+        if !vendor.is_empty() {
+            query_args.push(("vendor".to_string(), vendor.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/smart_collections.json?{}", query_),
+        let url = self
+            .client
+            .url(&format!("/admin/api/products.json?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (Option<crate::utils::NextLink>, crate::types::ProductsRoot) =
+                        self.client
+                            .request_with_links(
+                                reqwest::Method::GET,
+                                &url,
+                                crate::Message {
+                                    body: None,
+                                    content_type: None,
+                                },
+                            )
+                            .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.products;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of products. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/products.json` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Product>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            ids,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
             None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        ))
     }
     /**
-     * Creates a new smart collection using the specified rules.
+     * Creates a new product.
+     * If you want to set the product's SEO information, then you can use the following properties:
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/smart_collections.json` endpoint.
+     * metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
+     * metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2020-10
+     * This function performs a `POST` to the `/admin/api/products.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#create-2020-10
      */
-    pub async fn create_smart_collections(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/smart_collections.json", None);
+    pub async fn create(
+        &self,
+        body: &crate::types::ProductRequestRoot,
+    ) -> ClientResult<crate::types::ProductRoot> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Creates a new product.
+     *
+     * This function performs a `POST` to the `/admin/api/products.json` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::ProductRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::ProductRoot>> {
+        let url = self.client.url("/admin/api/products.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7898,40 +1951,91 @@ impl Products {
             .await
     }
     /**
-     * Retrieves a count of smart collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/smart_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-10
+     * Retrieves a count of products.
+     *
+     * This function performs a `GET` to the `/admin/api/products/count.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#count-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `vendor: &str` -- Filter results by product vendor.
+     * * `product_type: &str` -- Filter results by product type.
+     * * `collection_id: &str` -- Filter results by collection ID.
+     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_status: &str` -- Return products by their published status
+     *   (default: any)
+     *   
+     *   published: Show only published products.
+     *   unpublished: Show only unpublished products.
+     *   any: Show all products.
+     */
+    pub async fn get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_count_with_response(
+                vendor,
+                product_type,
+                collection_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a count of products.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/products/count.json` endpoint.
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * As opposed to `get_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_smart_collections_count(
+    pub async fn get_count_with_response(
         &self,
-        title: &str,
-        product_id: &str,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
         updated_at_min: &str,
         updated_at_max: &str,
         published_at_min: &str,
         published_at_max: &str,
         published_status: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+        if !collection_id.is_empty() {
+            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+        }
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !product_type.is_empty() {
+            query_args.push(("product_type".to_string(), product_type.to_string()));
         }
         if !published_at_max.is_empty() {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
@@ -7942,22 +2046,21 @@ impl Products {
         if !published_status.is_empty() {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
         if !updated_at_max.is_empty() {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
+        if !vendor.is_empty() {
+            query_args.push(("vendor".to_string(), vendor.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/smart_collections/count.json?{}", query_),
-            None,
-        );
+        let url = self
+            .client
+            .url(&format!("/admin/api/products/count.json?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7967,22 +2070,36 @@ impl Products {
             .await
     }
     /**
-     * Retrieves a single smart collection.
+     * Retrieves a single product.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/products/{product_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#show-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `product_id: &str` -- product_id.
+     * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
-    pub async fn get_smart_collections_param_collection(
+    pub async fn get_param(
         &self,
-        smart_collection_id: &str,
+        product_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::ProductRoot> {
+        Ok(self.get_param_with_response(product_id, fields).await?.body)
+    }
+    /**
+     * Retrieves a single product.
+     *
+     * This function performs a `GET` to the `/admin/api/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `get_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_param_with_response(
+        &self,
+        product_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProductRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -7990,14 +2107,14 @@ impl Products {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/smart_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
+                "/admin/api/products/{}.json?{}",
+                crate::progenitor_support::encode_path(product_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8007,30 +2124,51 @@ impl Products {
             .await
     }
     /**
-     * Updates an existing smart collection.
+     * Updates a product and its variants and images.
+     * If you want to update the product's SEO information, then you can use the following properties:
      *
-     * This function performs a `PUT` to the `/admin/api/2020-10/smart_collections/{smart_collection_id}.json` endpoint.
+     * metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
+     * metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#update-2020-10
+     * This function performs a `PUT` to the `/admin/api/products/{product_id}.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#update-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `product_id: &str` -- product_id.
      */
-    pub async fn update_smart_collections_param_collection(
+    pub async fn update_param(
         &self,
-        smart_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        product_id: &str,
+        body: &crate::types::ProductRequestRoot,
+    ) -> ClientResult<crate::types::ProductRoot> {
+        Ok(self
+            .update_param_with_response(product_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates a product and its variants and images.
+     *
+     * This function performs a `PUT` to the `/admin/api/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `update_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_param_with_response(
+        &self,
+        product_id: &str,
+        body: &crate::types::ProductRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::ProductRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
+                "/admin/api/products/{}.json",
+                crate::progenitor_support::encode_path(product_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8040,29 +2178,40 @@ impl Products {
             .await
     }
     /**
-     * Removes a smart collection.
+     * Deletes a product.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/products/{product_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#destroy-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `product_id: &str` -- product_id.
      */
-    pub async fn delete_smart_collections_param_collection(
+    pub async fn delete_param(&self, product_id: &str) -> ClientResult<()> {
+        self.delete_param_with_response(product_id).await?;
+        Ok(())
+    }
+    /**
+     * Deletes a product.
+     *
+     * This function performs a `DELETE` to the `/admin/api/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `delete_param`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_param_with_response(
         &self,
-        smart_collection_id: &str,
-    ) -> ClientResult<()> {
+        product_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
+                "/admin/api/products/{}.json",
+                crate::progenitor_support::encode_path(product_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8072,45 +2221,299 @@ impl Products {
             .await
     }
     /**
-     * Updates the ordering type of products in a smart collection.
+     * Get all product images.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-10/smart_collections/{smart_collection_id}/order.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * * `product_id: &str` -- product_id.
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn update_smart_collections_param_collection_order(
+    pub async fn get_param_images(
         &self,
-        smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::ProductImage>> {
+        Ok(self
+            .get_param_images_with_response(product_id, since_id, fields, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `get_param_images`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_param_images_with_response(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ProductImage>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+        let resp: crate::Response<crate::types::ProductImagesRoot> = self
+            .client
+            .get_with_response(
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.images.to_vec()))
+    }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `get_param_images`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#index-2020-10
+     */
+    pub async fn get_all_param_images(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::ProductImage>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/admin/api/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::ProductImagesRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
+                &url,
+                crate::Message {
+                    body: None,
+                    content_type: None,
+                },
+            )
+            .await?;
+
+        let mut images = resp.images;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::ProductImagesRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            images.append(&mut resp.images);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(images)
+    }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `get_all_param_images`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_param_images_pages_stream(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ProductImage>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/smart_collections/{}/order.json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
+                "/admin/api/products/{}/images.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::ProductImagesRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.images;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `get_all_param_images`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_param_images_stream(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ProductImage>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_param_images_pages_stream(product_id, since_id, fields, None),
+        )
+    }
+    /**
+     * Create a new product image.
+     *
+     * This function performs a `POST` to the `/admin/api/products/{product_id}/images.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#create-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `product_id: &str` -- product_id.
+     */
+    pub async fn create_param_images(
+        &self,
+        product_id: &str,
+        body: &crate::types::ProductImageRequestRoot,
+    ) -> ClientResult<crate::types::ProductImageRoot> {
+        Ok(self
+            .create_param_images_with_response(product_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a new product image.
+     *
+     * This function performs a `POST` to the `/admin/api/products/{product_id}/images.json` endpoint.
+     *
+     * As opposed to `create_param_images`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_param_images_with_response(
+        &self,
+        product_id: &str,
+        body: &crate::types::ProductImageRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::ProductImageRoot>> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/products/{}/images.json",
+                crate::progenitor_support::encode_path(product_id),
             ),
             None,
         );
         self.client
-            .put(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8120,92 +2523,54 @@ impl Products {
             .await
     }
     /**
-     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Get a count of all product images.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/smart_collections.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#count-2020-10
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
+     * * `product_id: &str` -- product_id.
      * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_202101_get_smart_collection(
+    pub async fn get_param_images_count(
         &self,
-        limit: &str,
-        ids: &str,
+        product_id: &str,
         since_id: &str,
-        title: &str,
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_param_images_count_with_response(product_id, since_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a count of all product images.
+     *
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images/count.json` endpoint.
+     *
+     * As opposed to `get_param_images_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_param_images_count_with_response(
+        &self,
         product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
+        since_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
         if !since_id.is_empty() {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2021-01/smart_collections.json?{}", query_),
+            &format!(
+                "/admin/api/products/{}/images/count.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8215,90 +2580,58 @@ impl Products {
             .await
     }
     /**
-     * Creates a new smart collection using the specified rules.
+     * Get a single product image by id.
      *
-     * This function performs a `POST` to the `/admin/api/2021-01/smart_collections.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images/{image_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#show-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `product_id: &str` -- product_id.
+     * * `image_id: &str` -- image_id.
+     * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
-    pub async fn deprecated_202101_create_smart_collections(
+    pub async fn get_param_images_image(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/smart_collections.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::types::ProductImageRoot> {
+        Ok(self
+            .get_param_images_image_with_response(product_id, image_id, fields)
+            .await?
+            .body)
     }
     /**
-     * Retrieves a count of smart collections.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/smart_collections/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2021-01
+     * Get a single product image by id.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/products/{product_id}/images/{image_id}.json` endpoint.
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * As opposed to `get_param_images_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_get_smart_collections_count(
+    pub async fn get_param_images_image_with_response(
         &self,
-        title: &str,
         product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> ClientResult<()> {
+        image_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::ProductImageRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
-        }
-        if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
-        }
-        if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
-        }
-        if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
-        }
-        if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2021-01/smart_collections/count.json?{}", query_),
+            &format!(
+                "/admin/api/products/{}/images/{}.json?{}",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8308,189 +2641,372 @@ impl Products {
             .await
     }
     /**
-     * Retrieves a single smart collection.
+     * Modify an existing product image.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/products/{product_id}/images/{image_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#update-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `product_id: &str` -- product_id.
+     * * `image_id: &str` -- image_id.
      */
-    pub async fn deprecated_202101_get_smart_collections_param_collection(
+    pub async fn update_param_images_image(
         &self,
-        smart_collection_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        product_id: &str,
+        image_id: &str,
+        body: &crate::types::ProductImageRequestRoot,
+    ) -> ClientResult<crate::types::ProductImageRoot> {
+        Ok(self
+            .update_param_images_image_with_response(product_id, image_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Modify an existing product image.
+     *
+     * This function performs a `PUT` to the `/admin/api/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `update_param_images_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_param_images_image_with_response(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &crate::types::ProductImageRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::ProductImageRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/smart_collections/{}/json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
+                "/admin/api/products/{}/images/{}.json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
             ),
             None,
         );
         self.client
-            .get(
+            .put_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Updates an existing smart collection.
+     * .
      *
-     * This function performs a `PUT` to the `/admin/api/2021-01/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/products/{product_id}/images/{image_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#update-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/product-image#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `product_id: &str` -- product_id.
+     * * `image_id: &str` -- image_id.
      */
-    pub async fn deprecated_202101_update_smart_collections_param_collection(
+    pub async fn delete_param_images_image(
         &self,
-        smart_collection_id: &str,
-        body: &serde_json::Value,
+        product_id: &str,
+        image_id: &str,
     ) -> ClientResult<()> {
+        self.delete_param_images_image_with_response(product_id, image_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `DELETE` to the `/admin/api/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `delete_param_images_image`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_param_images_image_with_response(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
+                "/admin/api/products/{}/images/{}.json",
+                crate::progenitor_support::encode_path(product_id),
+                crate::progenitor_support::encode_path(image_id),
             ),
             None,
         );
         self.client
-            .put(
+            .delete_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Removes a smart collection.
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/smart_collections.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#destroy-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `limit: &str` -- The number of results to show.
+     *   (default: 50, maximum: 250).
+     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `title: &str` -- Show smart collections with the specified title.
+     * * `product_id: &str` -- Show smart collections that includes the specified product.
+     * * `handle: &str` -- Filter results by smart collection handle.
+     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
+     * * `published_status: &str` -- Filter results based on the published status of smart collections.
+     *   (default: any)
+     *   
+     *   published: Show only published smart collections.
+     *   unpublished: Show only unpublished smart collections.
+     *   any: Show all smart collections.
+     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
+     */
+    pub async fn get_smart_collections(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Collection>> {
+        Ok(self
+            .get_smart_collections_with_response(
+                limit,
+                ids,
+                since_id,
+                title,
+                product_id,
+                handle,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+                fields,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/smart_collections.json` endpoint.
+     *
+     * As opposed to `get_smart_collections`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_delete_smart_collections_param_collection(
+    pub async fn get_smart_collections_with_response(
         &self,
-        smart_collection_id: &str,
-    ) -> ClientResult<()> {
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Collection>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        if !product_id.is_empty() {
+            query_args.push(("product_id".to_string(), product_id.to_string()));
+        }
+        if !published_at_max.is_empty() {
+            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+        }
+        if !published_at_min.is_empty() {
+            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+        }
+        if !published_status.is_empty() {
+            query_args.push(("published_status".to_string(), published_status.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !title.is_empty() {
+            query_args.push(("title".to_string(), title.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/smart_collections/{}/json",
-                crate::progenitor_support::encode_path(smart_collection_id),
-            ),
+            &format!("/admin/api/smart_collections.json?{}", query_),
             None,
         );
-        self.client
-            .delete(
+        let resp: crate::Response<crate::types::SmartCollectionsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.smart_collections.to_vec()))
     }
     /**
-     * Updates the ordering type of products in a smart collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/smart_collections/{smart_collection_id}/order.json` endpoint.
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2021-01
+     * This function performs a `GET` to the `/admin/api/smart_collections.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_smart_collections`, this function returns all the pages of the request at once.
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-2020-10
      */
-    pub async fn deprecated_202101_update_smart_collections_param_collection_order(
+    pub async fn get_all_smart_collections(
         &self,
-        smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> ClientResult<Vec<crate::types::Collection>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
+        if !handle.is_empty() {
+            query_args.push(("handle".to_string(), handle.to_string()));
+        }
+        if !ids.is_empty() {
+            query_args.push(("ids".to_string(), ids.to_string()));
+        }
+        if !product_id.is_empty() {
+            query_args.push(("product_id".to_string(), product_id.to_string()));
+        }
+        if !published_at_max.is_empty() {
+            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+        }
+        if !published_at_min.is_empty() {
+            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+        }
+        if !published_status.is_empty() {
+            query_args.push(("published_status".to_string(), published_status.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !title.is_empty() {
+            query_args.push(("title".to_string(), title.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/smart_collections/{}/order.json?{}",
-                crate::progenitor_support::encode_path(smart_collection_id),
-                query_
-            ),
+            &format!("/admin/api/smart_collections.json?{}", query_),
             None,
         );
-        self.client
-            .put(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::SmartCollectionsRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut smart_collections = resp.smart_collections;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::SmartCollectionsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            smart_collections.append(&mut resp.smart_collections);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(smart_collections)
     }
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/smart_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#index-unstable
-     *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/smart_collections.json` endpoint.
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * As opposed to `get_all_smart_collections`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_unstable_get_smart_collection(
+    pub fn get_all_smart_collections_pages_stream(
         &self,
-        limit: &str,
         ids: &str,
         since_id: &str,
         title: &str,
@@ -8502,7 +3018,10 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Collection>>>
+           + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -8513,9 +3032,6 @@ impl Products {
         if !ids.is_empty() {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
         if !product_id.is_empty() {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
@@ -8542,35 +3058,127 @@ impl Products {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/unstable/smart_collections.json?{}", query_),
+            &format!("/admin/api/smart_collections.json?{}", query_),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::SmartCollectionsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.smart_collections;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/smart_collections.json` endpoint.
+     *
+     * As opposed to `get_all_smart_collections`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_smart_collections_stream(
+        &self,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Collection>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_smart_collections_pages_stream(
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            None,
+        ))
     }
     /**
      * Creates a new smart collection using the specified rules.
      *
-     * This function performs a `POST` to the `/admin/api/unstable/smart_collections.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/smart_collections.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2020-10
      */
-    pub async fn deprecated_unstable_create_smart_collections(
+    pub async fn create_smart_collections(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/smart_collections.json", None);
+        body: &crate::types::SmartCollectionRequestRoot,
+    ) -> ClientResult<crate::types::SmartCollectionRoot> {
+        Ok(self
+            .create_smart_collections_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Creates a new smart collection using the specified rules.
+     *
+     * This function performs a `POST` to the `/admin/api/smart_collections.json` endpoint.
+     *
+     * As opposed to `create_smart_collections`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_smart_collections_with_response(
+        &self,
+        body: &crate::types::SmartCollectionRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::SmartCollectionRoot>> {
+        let url = self.client.url("/admin/api/smart_collections.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8582,9 +3190,9 @@ impl Products {
     /**
      * Retrieves a count of smart collections.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/smart_collections/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/smart_collections/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#count-2020-10
      *
      * **Parameters:**
      *
@@ -8595,13 +3203,13 @@ impl Products {
      * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
      * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
      * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     *   (default: any)
+     *   
+     *   published: Show only published smart collections.
+     *   unpublished: Show only unpublished smart collections.
+     *   any: Show all smart collections.
      */
-    pub async fn deprecated_unstable_get_smart_collections_count(
+    pub async fn get_smart_collections_count(
         &self,
         title: &str,
         product_id: &str,
@@ -8610,7 +3218,37 @@ impl Products {
         published_at_min: &str,
         published_at_max: &str,
         published_status: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_smart_collections_count_with_response(
+                title,
+                product_id,
+                updated_at_min,
+                updated_at_max,
+                published_at_min,
+                published_at_max,
+                published_status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a count of smart collections.
+     *
+     * This function performs a `GET` to the `/admin/api/smart_collections/count.json` endpoint.
+     *
+     * As opposed to `get_smart_collections_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_smart_collections_count_with_response(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !product_id.is_empty() {
             query_args.push(("product_id".to_string(), product_id.to_string()));
@@ -8635,14 +3273,11 @@ impl Products {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/smart_collections/count.json?{}",
-                query_
-            ),
+            &format!("/admin/api/smart_collections/count.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8654,20 +3289,37 @@ impl Products {
     /**
      * Retrieves a single smart collection.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/smart_collections/{smart_collection_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#show-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `smart_collection_id: &str` -- smart_collection_id.
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
-    pub async fn deprecated_unstable_get_smart_collections_param_collection(
+    pub async fn get_smart_collections_param_collection(
         &self,
         smart_collection_id: &str,
         fields: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::SmartCollectionRoot> {
+        Ok(self
+            .get_smart_collections_param_collection_with_response(smart_collection_id, fields)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a single smart collection.
+     *
+     * This function performs a `GET` to the `/admin/api/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `get_smart_collections_param_collection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_smart_collections_param_collection_with_response(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::SmartCollectionRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.to_string()));
@@ -8675,14 +3327,14 @@ impl Products {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/smart_collections/{}/json?{}",
+                "/admin/api/smart_collections/{}.json?{}",
                 crate::progenitor_support::encode_path(smart_collection_id),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8694,28 +3346,45 @@ impl Products {
     /**
      * Updates an existing smart collection.
      *
-     * This function performs a `PUT` to the `/admin/api/unstable/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/smart_collections/{smart_collection_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#update-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#update-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `smart_collection_id: &str` -- smart_collection_id.
      */
-    pub async fn deprecated_unstable_update_smart_collections_param_collection(
+    pub async fn update_smart_collections_param_collection(
         &self,
         smart_collection_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        body: &crate::types::SmartCollectionRequestRoot,
+    ) -> ClientResult<crate::types::SmartCollectionRoot> {
+        Ok(self
+            .update_smart_collections_param_collection_with_response(smart_collection_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Updates an existing smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `update_smart_collections_param_collection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_smart_collections_param_collection_with_response(
+        &self,
+        smart_collection_id: &str,
+        body: &crate::types::SmartCollectionRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::SmartCollectionRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/smart_collections/{}/json",
+                "/admin/api/smart_collections/{}.json",
                 crate::progenitor_support::encode_path(smart_collection_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8727,27 +3396,42 @@ impl Products {
     /**
      * Removes a smart collection.
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/smart_collections/{smart_collection_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/smart_collections/{smart_collection_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#destroy-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `smart_collection_id: &str` -- smart_collection_id.
      */
-    pub async fn deprecated_unstable_delete_smart_collections_param_collection(
+    pub async fn delete_smart_collections_param_collection(
         &self,
         smart_collection_id: &str,
     ) -> ClientResult<()> {
+        self.delete_smart_collections_param_collection_with_response(smart_collection_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Removes a smart collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `delete_smart_collections_param_collection`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_smart_collections_param_collection_with_response(
+        &self,
+        smart_collection_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/smart_collections/{}/json",
+                "/admin/api/smart_collections/{}.json",
                 crate::progenitor_support::encode_path(smart_collection_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8759,25 +3443,48 @@ impl Products {
     /**
      * Updates the ordering type of products in a smart collection.
      *
-     * This function performs a `PUT` to the `/admin/api/unstable/smart_collections/{smart_collection_id}/order.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/smart_collections/{smart_collection_id}/order.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#order-2020-10
      *
      * **Parameters:**
      *
-     * * `smart_collection_id: &str` -- storefront_access_token_id.
+     * * `smart_collection_id: &str` -- smart_collection_id.
      * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
      * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     *   (default: (current value)).
+     * * `products: i64` -- products[].
      */
-    pub async fn deprecated_unstable_update_smart_collections_param_collection_order(
+    pub async fn update_smart_collections_param_collection_order(
         &self,
         smart_collection_id: &str,
         products: &str,
         sort_order: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.update_smart_collections_param_collection_order_with_response(
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Updates the ordering type of products in a smart collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/smart_collections/{smart_collection_id}/order.json` endpoint.
+     *
+     * As opposed to `update_smart_collections_param_collection_order`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_smart_collections_param_collection_order_with_response(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !products.is_empty() {
             query_args.push(("products".to_string(), products.to_string()));
@@ -8788,14 +3495,14 @@ impl Products {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/smart_collections/{}/order.json?{}",
+                "/admin/api/smart_collections/{}/order.json?{}",
                 crate::progenitor_support::encode_path(smart_collection_id),
                 query_
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -14,102 +14,28 @@ impl SalesChannels {
     /**
      * Creates a checkout.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/checkouts.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#create-2020-01
-     */
-    pub async fn deprecated_202001_create_checkouts(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-01/checkouts.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a checkout.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/checkouts.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#create-2020-04
-     */
-    pub async fn deprecated_202004_create_checkouts(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-04/checkouts.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a checkout.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/checkouts.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#create-2020-07
-     */
-    pub async fn deprecated_202007_create_checkouts(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-07/checkouts.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a checkout.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/checkouts.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/checkouts.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#create-2020-10
      */
     pub async fn create_checkouts(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2020-10/checkouts.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        self.create_checkouts_with_response(body).await?;
+        Ok(())
     }
     /**
      * Creates a checkout.
      *
-     * This function performs a `POST` to the `/admin/api/2021-01/checkouts.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/checkouts.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#create-2021-01
+     * As opposed to `create_checkouts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_create_checkouts(
+    pub async fn create_checkouts_with_response(
         &self,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/2021-01/checkouts.json", None);
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url("/admin/api/checkouts.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -119,52 +45,46 @@ impl SalesChannels {
             .await
     }
     /**
-     * Creates a checkout.
+     * Completes a checkout.
+     *
+     * This function performs a `POST` to the `/admin/api/checkouts/{token}/complete.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#complete-2020-10
      *
-     * This function performs a `POST` to the `/admin/api/unstable/checkouts.json` endpoint.
+     * **Parameters:**
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#create-unstable
+     * * `token: &str` -- token.
      */
-    pub async fn deprecated_unstable_create_checkouts(
+    pub async fn create_checkouts_param_token_complete(
         &self,
+        token: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
-        let url = self.client.url("/admin/api/unstable/checkouts.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        self.create_checkouts_param_token_complete_with_response(token, body)
+            .await?;
+        Ok(())
     }
     /**
      * Completes a checkout.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/checkouts/{token}/complete.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/checkouts/{token}/complete.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#complete-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
+     * As opposed to `create_checkouts_param_token_complete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202001_create_checkouts_param_token_complete(
+    pub async fn create_checkouts_param_token_complete_with_response(
         &self,
         token: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/checkouts/{}/complete.json",
+                "/admin/api/checkouts/{}/complete.json",
                 crate::progenitor_support::encode_path(token),
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -176,27 +96,38 @@ impl SalesChannels {
     /**
      * Retrieves a checkout.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/checkouts/{token}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#show-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#show-2020-10
      *
      * **Parameters:**
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * * `token: &str` -- token.
+     */
+    pub async fn get_checkouts_param_token(&self, token: &str) -> ClientResult<()> {
+        self.get_checkouts_param_token_with_response(token).await?;
+        Ok(())
+    }
+    /**
+     * Retrieves a checkout.
+     *
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}.json` endpoint.
+     *
+     * As opposed to `get_checkouts_param_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202001_get_checkouts_param_token(
+    pub async fn get_checkouts_param_token_with_response(
         &self,
         token: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/checkouts/{}/json",
+                "/admin/api/checkouts/{}.json",
                 crate::progenitor_support::encode_path(token),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -208,95 +139,44 @@ impl SalesChannels {
     /**
      * Modifies an existing checkout.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-01/checkouts/{token}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/checkouts/{token}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#update-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#update-2020-10
      *
      * **Parameters:**
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * * `token: &str` -- token.
      */
-    pub async fn deprecated_202001_update_checkouts_param_token(
+    pub async fn update_checkouts_param_token(
         &self,
         token: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
-        Each shipping rate contains the checkout's new subtotal price, total tax, and total price in the event that this shipping rate is selected. This can be used to update the UI without performing further API requests.
-        To apply a shipping rate, update the checkout's shipping line with the handle of the selected rate.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-01/checkouts/{token}/shipping_rates.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#shipping_rates-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `token: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202001_get_checkouts_param_token_shipping_rate(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/checkouts/{}/shipping_rates.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.update_checkouts_param_token_with_response(token, body)
+            .await?;
+        Ok(())
     }
     /**
-     * Completes a checkout.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/checkouts/{token}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#complete-2020-04
+     * Modifies an existing checkout.
      *
-     * **Parameters:**
+     * This function performs a `PUT` to the `/admin/api/checkouts/{token}.json` endpoint.
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * As opposed to `update_checkouts_param_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_create_checkouts_param_token_complete(
+    pub async fn update_checkouts_param_token_with_response(
         &self,
         token: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/checkouts/{}/complete.json",
+                "/admin/api/checkouts/{}.json",
                 crate::progenitor_support::encode_path(token),
             ),
             None,
         );
         self.client
-            .post(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -306,29 +186,43 @@ impl SalesChannels {
             .await
     }
     /**
-     * Retrieves a checkout.
+     * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
+     * Each shipping rate contains the checkout's new subtotal price, total tax, and total price in the event that this shipping rate is selected. This can be used to update the UI without performing further API requests.
+     * To apply a shipping rate, update the checkout's shipping line with the handle of the selected rate.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/checkouts/{token}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/shipping_rates.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#show-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#shipping_rates-2020-10
      *
      * **Parameters:**
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * * `token: &str` -- token.
+     */
+    pub async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> ClientResult<()> {
+        self.get_checkouts_param_token_shipping_rate_with_response(token)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
+     *
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/shipping_rates.json` endpoint.
+     *
+     * As opposed to `get_checkouts_param_token_shipping_rate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get_checkouts_param_token(
+    pub async fn get_checkouts_param_token_shipping_rate_with_response(
         &self,
         token: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/checkouts/{}/json",
+                "/admin/api/checkouts/{}/shipping_rates.json",
                 crate::progenitor_support::encode_path(token),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -338,64 +232,43 @@ impl SalesChannels {
             .await
     }
     /**
-     * Modifies an existing checkout.
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-04/checkouts/{token}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collection_listings.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#update-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-10
      *
      * **Parameters:**
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * * `limit: &str` -- Amount of results
+     *   (default: 50, maximum: 1000).
      */
-    pub async fn deprecated_202004_update_checkouts_param_token(
-        &self,
-        token: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+    pub async fn get_collection_listing(&self, limit: &str) -> ClientResult<()> {
+        self.get_collection_listing_with_response(limit).await?;
+        Ok(())
     }
     /**
-    * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
-        Each shipping rate contains the checkout's new subtotal price, total tax, and total price in the event that this shipping rate is selected. This can be used to update the UI without performing further API requests.
-        To apply a shipping rate, update the checkout's shipping line with the handle of the selected rate.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-04/checkouts/{token}/shipping_rates.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#shipping_rates-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `token: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_get_checkouts_param_token_shipping_rate(
+     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/collection_listings.json` endpoint.
+     *
+     * As opposed to `get_collection_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_collection_listing_with_response(
         &self,
-        token: &str,
-    ) -> ClientResult<()> {
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/checkouts/{}/shipping_rates.json",
-                crate::progenitor_support::encode_path(token),
-            ),
+            &format!("/admin/api/collection_listings.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -405,62 +278,57 @@ impl SalesChannels {
             .await
     }
     /**
-     * Completes a checkout.
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `POST` to the `/admin/api/2020-07/checkouts/{token}/complete.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#complete-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-10
      *
      * **Parameters:**
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * * `collection_listing_id: &str` -- collection_listing_id.
+     * * `limit: &str` -- Amount of results
+     *   (default: 50, maximum: 1000).
      */
-    pub async fn deprecated_202007_create_checkouts_param_token_complete(
+    pub async fn get_collection_listings_param_listing_product_id(
         &self,
-        token: &str,
-        body: &serde_json::Value,
+        collection_listing_id: &str,
+        limit: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/checkouts/{}/complete.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        self.get_collection_listings_param_listing_product_id_with_response(
+            collection_listing_id,
+            limit,
+        )
+        .await?;
+        Ok(())
     }
     /**
-     * Retrieves a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/checkouts/{token}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#show-2020-07
+     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * As opposed to `get_collection_listings_param_listing_product_id`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_checkouts_param_token(
+    pub async fn get_collection_listings_param_listing_product_id_with_response(
         &self,
-        token: &str,
-    ) -> ClientResult<()> {
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
+                "/admin/api/collection_listings/{}/product_ids.json?{}",
+                crate::progenitor_support::encode_path(collection_listing_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -470,64 +338,44 @@ impl SalesChannels {
             .await
     }
     /**
-     * Modifies an existing checkout.
+     * Retrieve a specific collection listing that is published to your app.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-07/checkouts/{token}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/collection_listings/{collection_listing_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#update-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#show-2020-10
      *
      * **Parameters:**
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * * `collection_listing_id: &str` -- collection_listing_id.
      */
-    pub async fn deprecated_202007_update_checkouts_param_token(
+    pub async fn get_collection_listings_param_listing(
         &self,
-        token: &str,
-        body: &serde_json::Value,
+        collection_listing_id: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        self.get_collection_listings_param_listing_with_response(collection_listing_id)
+            .await?;
+        Ok(())
     }
     /**
-    * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
-        Each shipping rate contains the checkout's new subtotal price, total tax, and total price in the event that this shipping rate is selected. This can be used to update the UI without performing further API requests.
-        To apply a shipping rate, update the checkout's shipping line with the handle of the selected rate.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-07/checkouts/{token}/shipping_rates.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#shipping_rates-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `token: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_get_checkouts_param_token_shipping_rate(
+     * Retrieve a specific collection listing that is published to your app.
+     *
+     * This function performs a `GET` to the `/admin/api/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `get_collection_listings_param_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_collection_listings_param_listing_with_response(
         &self,
-        token: &str,
-    ) -> ClientResult<()> {
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/checkouts/{}/shipping_rates.json",
-                crate::progenitor_support::encode_path(token),
+                "/admin/api/collection_listings/{}.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -537,2660 +385,46 @@ impl SalesChannels {
             .await
     }
     /**
-     * Completes a checkout.
+     * Create a collection listing to publish a collection to your app.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/checkouts/{token}/complete.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/collection_listings/{collection_listing_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#complete-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#create-2020-10
      *
      * **Parameters:**
      *
-     * * `token: &str` -- storefront_access_token_id.
+     * * `collection_listing_id: &str` -- collection_listing_id.
      */
-    pub async fn create_checkouts_param_token_complete(
+    pub async fn update_collection_listings_param_listing(
         &self,
-        token: &str,
+        collection_listing_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/complete.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/checkouts/{token}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_checkouts_param_token(&self, token: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modifies an existing checkout.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/checkouts/{token}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_checkouts_param_token(
-        &self,
-        token: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
-        Each shipping rate contains the checkout's new subtotal price, total tax, and total price in the event that this shipping rate is selected. This can be used to update the UI without performing further API requests.
-        To apply a shipping rate, update the checkout's shipping line with the handle of the selected rate.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-10/checkouts/{token}/shipping_rates.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#shipping_rates-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `token: &str` -- storefront_access_token_id.
-    */
-    pub async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/shipping_rates.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Completes a checkout.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/checkouts/{token}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#complete-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_checkouts_param_token_complete(
-        &self,
-        token: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/complete.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/checkouts/{token}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_checkouts_param_token(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modifies an existing checkout.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/checkouts/{token}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_checkouts_param_token(
-        &self,
-        token: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
-        Each shipping rate contains the checkout's new subtotal price, total tax, and total price in the event that this shipping rate is selected. This can be used to update the UI without performing further API requests.
-        To apply a shipping rate, update the checkout's shipping line with the handle of the selected rate.
-    *
-    * This function performs a `GET` to the `/admin/api/2021-01/checkouts/{token}/shipping_rates.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#shipping_rates-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `token: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_get_checkouts_param_token_shipping_rate(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/shipping_rates.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Completes a checkout.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/checkouts/{token}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#complete-unstable
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_checkouts_param_token_complete(
-        &self,
-        token: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/complete.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/checkouts/{token}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_checkouts_param_token(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Modifies an existing checkout.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/checkouts/{token}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_checkouts_param_token(
-        &self,
-        token: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Retrieves a list of available shipping rates for the specified checkout. Implementers need to poll this endpoint until rates become available.
-        Each shipping rate contains the checkout's new subtotal price, total tax, and total price in the event that this shipping rate is selected. This can be used to update the UI without performing further API requests.
-        To apply a shipping rate, update the checkout's shipping line with the handle of the selected rate.
-    *
-    * This function performs a `GET` to the `/admin/api/unstable/checkouts/{token}/shipping_rates.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/checkout#shipping_rates-unstable
-    *
-    * **Parameters:**
-    *
-    * * `token: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_get_checkouts_param_token_shipping_rate(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/shipping_rates.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/collection_listings.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202001_get_collection_listing(&self, limit: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/collection_listings.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202001_get_collection_listings_param_listing_product_id(
-        &self,
-        collection_listing_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/collection_listings/{}/product_ids.json?{}",
-                crate::progenitor_support::encode_path(collection_listing_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific collection listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a collection listing to publish a collection to your app.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#create-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a collection listing to unpublish a collection from your app.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/collection_listings.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202004_get_collection_listing(&self, limit: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-04/collection_listings.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202004_get_collection_listings_param_listing_product_id(
-        &self,
-        collection_listing_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/collection_listings/{}/product_ids.json?{}",
-                crate::progenitor_support::encode_path(collection_listing_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific collection listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a collection listing to publish a collection to your app.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#create-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a collection listing to unpublish a collection from your app.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collection_listings.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202007_get_collection_listing(&self, limit: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/collection_listings.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202007_get_collection_listings_param_listing_product_id(
-        &self,
-        collection_listing_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collection_listings/{}/product_ids.json?{}",
-                crate::progenitor_support::encode_path(collection_listing_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific collection listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a collection listing to publish a collection to your app.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#create-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a collection listing to unpublish a collection from your app.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collection_listings.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn get_collection_listing(&self, limit: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-10/collection_listings.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn get_collection_listings_param_listing_product_id(
-        &self,
-        collection_listing_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collection_listings/{}/product_ids.json?{}",
-                crate::progenitor_support::encode_path(collection_listing_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific collection listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a collection listing to publish a collection to your app.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#create-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a collection listing to unpublish a collection from your app.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collection_listings.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202101_get_collection_listing(&self, limit: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/collection_listings.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202101_get_collection_listings_param_listing_product_id(
-        &self,
-        collection_listing_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collection_listings/{}/product_ids.json?{}",
-                crate::progenitor_support::encode_path(collection_listing_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific collection listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a collection listing to publish a collection to your app.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#create-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a collection listing to unpublish a collection from your app.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve collection listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collection_listings.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_unstable_get_collection_listing(
-        &self,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/unstable/collection_listings.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collection_listings/{collection_listing_id}/product_ids.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#product_ids-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_unstable_get_collection_listings_param_listing_product_id(
-        &self,
-        collection_listing_id: &str,
-        limit: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collection_listings/{}/product_ids.json?{}",
-                crate::progenitor_support::encode_path(collection_listing_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific collection listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a collection listing to publish a collection to your app.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#create-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Delete a collection listing to unpublish a collection from your app.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/collection_listings/{collection_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `collection_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_collection_listings_param_listing(
-        &self,
-        collection_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/collection_listings/{}/json",
-                crate::progenitor_support::encode_path(collection_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of payments on a particular checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_checkouts_param_token_payment(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/checkouts/{}/payments.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a payment on a checkout using the session ID returned by the card vault.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
-     */
-    pub async fn deprecated_202001_create_checkouts_param_token_payments(
-        &self,
-        token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
-        }
-        if !request_details_required.is_empty() {
-            query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
-                request_details_required.to_string(),
-            ));
-        }
-        if !session_id_required.is_empty() {
-            query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
-                session_id_required.to_string(),
-            ));
-        }
-        if !unique_token_required.is_empty() {
-            query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
-                unique_token_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/checkouts/{}/payments.json?{}",
-                crate::progenitor_support::encode_path(token),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the payment information for an existing payment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/checkouts/{token}/payments/{payment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `payment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_checkouts_param_token_payments_payment(
-        &self,
-        token: &str,
-        payment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/checkouts/{}/payments/{}/json",
-                crate::progenitor_support::encode_path(token),
-                crate::progenitor_support::encode_path(payment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Counts the number of payments attempted on a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/checkouts/{token}/payments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_checkouts_param_token_payments_count(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/checkouts/{}/payments/count.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of payments on a particular checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_checkouts_param_token_payment(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/checkouts/{}/payments.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a payment on a checkout using the session ID returned by the card vault.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
-     */
-    pub async fn deprecated_202004_create_checkouts_param_token_payments(
-        &self,
-        token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
-        }
-        if !request_details_required.is_empty() {
-            query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
-                request_details_required.to_string(),
-            ));
-        }
-        if !session_id_required.is_empty() {
-            query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
-                session_id_required.to_string(),
-            ));
-        }
-        if !unique_token_required.is_empty() {
-            query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
-                unique_token_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/checkouts/{}/payments.json?{}",
-                crate::progenitor_support::encode_path(token),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the payment information for an existing payment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/checkouts/{token}/payments/{payment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `payment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_checkouts_param_token_payments_payment(
-        &self,
-        token: &str,
-        payment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/checkouts/{}/payments/{}/json",
-                crate::progenitor_support::encode_path(token),
-                crate::progenitor_support::encode_path(payment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Counts the number of payments attempted on a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/checkouts/{token}/payments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_checkouts_param_token_payments_count(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/checkouts/{}/payments/count.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of payments on a particular checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_checkouts_param_token_payment(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/checkouts/{}/payments.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a payment on a checkout using the session ID returned by the card vault.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
-     */
-    pub async fn deprecated_202007_create_checkouts_param_token_payments(
-        &self,
-        token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
-        }
-        if !request_details_required.is_empty() {
-            query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
-                request_details_required.to_string(),
-            ));
-        }
-        if !session_id_required.is_empty() {
-            query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
-                session_id_required.to_string(),
-            ));
-        }
-        if !unique_token_required.is_empty() {
-            query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
-                unique_token_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/checkouts/{}/payments.json?{}",
-                crate::progenitor_support::encode_path(token),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the payment information for an existing payment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/checkouts/{token}/payments/{payment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `payment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_checkouts_param_token_payments_payment(
-        &self,
-        token: &str,
-        payment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/checkouts/{}/payments/{}/json",
-                crate::progenitor_support::encode_path(token),
-                crate::progenitor_support::encode_path(payment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Counts the number of payments attempted on a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/checkouts/{token}/payments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_checkouts_param_token_payments_count(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/checkouts/{}/payments/count.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of payments on a particular checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_checkouts_param_token_payment(&self, token: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/payments.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a payment on a checkout using the session ID returned by the card vault.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
-     */
-    pub async fn create_checkouts_param_token_payments(
-        &self,
-        token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
-        }
-        if !request_details_required.is_empty() {
-            query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
-                request_details_required.to_string(),
-            ));
-        }
-        if !session_id_required.is_empty() {
-            query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
-                session_id_required.to_string(),
-            ));
-        }
-        if !unique_token_required.is_empty() {
-            query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
-                unique_token_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/payments.json?{}",
-                crate::progenitor_support::encode_path(token),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the payment information for an existing payment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/checkouts/{token}/payments/{payment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `payment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_checkouts_param_token_payments_payment(
-        &self,
-        token: &str,
-        payment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/payments/{}/json",
-                crate::progenitor_support::encode_path(token),
-                crate::progenitor_support::encode_path(payment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Counts the number of payments attempted on a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/checkouts/{token}/payments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_checkouts_param_token_payments_count(&self, token: &str) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/checkouts/{}/payments/count.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of payments on a particular checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_checkouts_param_token_payment(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/payments.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a payment on a checkout using the session ID returned by the card vault.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
-     */
-    pub async fn deprecated_202101_create_checkouts_param_token_payments(
-        &self,
-        token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
-        }
-        if !request_details_required.is_empty() {
-            query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
-                request_details_required.to_string(),
-            ));
-        }
-        if !session_id_required.is_empty() {
-            query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
-                session_id_required.to_string(),
-            ));
-        }
-        if !unique_token_required.is_empty() {
-            query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
-                unique_token_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/payments.json?{}",
-                crate::progenitor_support::encode_path(token),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the payment information for an existing payment.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/checkouts/{token}/payments/{payment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `payment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_checkouts_param_token_payments_payment(
-        &self,
-        token: &str,
-        payment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/payments/{}/json",
-                crate::progenitor_support::encode_path(token),
-                crate::progenitor_support::encode_path(payment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Counts the number of payments attempted on a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/checkouts/{token}/payments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_checkouts_param_token_payments_count(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/checkouts/{}/payments/count.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of payments on a particular checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_checkouts_param_token_payment(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/payments.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a payment on a checkout using the session ID returned by the card vault.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/checkouts/{token}/payments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-unstable
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
-     */
-    pub async fn deprecated_unstable_create_checkouts_param_token_payments(
-        &self,
-        token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
-            query_args.push((
-                "amount
-                  required"
-                    .to_string(),
-                amount_required.to_string(),
-            ));
-        }
-        if !request_details_required.is_empty() {
-            query_args.push((
-                "request_details
-                  required"
-                    .to_string(),
-                request_details_required.to_string(),
-            ));
-        }
-        if !session_id_required.is_empty() {
-            query_args.push((
-                "session_id
-                  required"
-                    .to_string(),
-                session_id_required.to_string(),
-            ));
-        }
-        if !unique_token_required.is_empty() {
-            query_args.push((
-                "unique_token
-                  required"
-                    .to_string(),
-                unique_token_required.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/payments.json?{}",
-                crate::progenitor_support::encode_path(token),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves the payment information for an existing payment.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/checkouts/{token}/payments/{payment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     * * `payment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_checkouts_param_token_payments_payment(
-        &self,
-        token: &str,
-        payment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/payments/{}/json",
-                crate::progenitor_support::encode_path(token),
-                crate::progenitor_support::encode_path(payment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Counts the number of payments attempted on a checkout.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/checkouts/{token}/payments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `token: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_checkouts_param_token_payments_count(
-        &self,
-        token: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/checkouts/{}/payments/count.json",
-                crate::progenitor_support::encode_path(token),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.update_collection_listings_param_listing_with_response(collection_listing_id, body)
+            .await?;
+        Ok(())
     }
     /**
-     * Retrieve product listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/product_listings.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_ids: &str` -- A comma-separated list of product ids.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     * * `collection_id: &str` -- Filter by products belonging to a particular collection.
-     * * `updated_at_min: &str` -- Filter by products last updated after a certain date and time (formatted in ISO 8601).
-     * * `handle: &str` -- Filter by product handle.
-     */
-    pub async fn deprecated_202001_get_product_listing(
-        &self,
-        product_ids: &str,
-        limit: &str,
-        collection_id: &str,
-        updated_at_min: &str,
-        handle: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-01/product_listings.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/product_listings/product_ids.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#product_ids-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     */
-    pub async fn deprecated_202001_get_product_listings_id(&self, limit: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/product_listings/product_ids.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a count of products that are published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/product_listings/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#count-2020-01
-     */
-    pub async fn deprecated_202001_get_product_listings_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/product_listings/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific product listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/product_listings/{product_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_product_listings_param_listing(
-        &self,
-        product_listing_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/product_listings/{}/json",
-                crate::progenitor_support::encode_path(product_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Create a product listing to publish a product to your app.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/product_listings/{product_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#create-2020-01
+     * Create a collection listing to publish a collection to your app.
      *
-     * **Parameters:**
+     * This function performs a `PUT` to the `/admin/api/collection_listings/{collection_listing_id}.json` endpoint.
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * As opposed to `update_collection_listings_param_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202001_update_product_listings_param_listing(
+    pub async fn update_collection_listings_param_listing_with_response(
         &self,
-        product_listing_id: &str,
+        collection_listing_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/product_listings/{}/json",
-                crate::progenitor_support::encode_path(product_listing_id),
+                "/admin/api/collection_listings/{}.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3200,29 +434,44 @@ impl SalesChannels {
             .await
     }
     /**
-     * Delete a product listing to unpublish a product from your app.
+     * Delete a collection listing to unpublish a collection from your app.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/product_listings/{product_listing_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/collection_listings/{collection_listing_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#destroy-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/collectionlisting#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * * `collection_listing_id: &str` -- collection_listing_id.
      */
-    pub async fn deprecated_202001_delete_product_listings_param_listing(
+    pub async fn delete_collection_listings_param_listing(
         &self,
-        product_listing_id: &str,
+        collection_listing_id: &str,
     ) -> ClientResult<()> {
+        self.delete_collection_listings_param_listing_with_response(collection_listing_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a collection listing to unpublish a collection from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `delete_collection_listings_param_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_collection_listings_param_listing_with_response(
+        &self,
+        collection_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/product_listings/{}/json",
-                crate::progenitor_support::encode_path(product_listing_id),
+                "/admin/api/collection_listings/{}.json",
+                crate::progenitor_support::encode_path(collection_listing_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3232,52 +481,41 @@ impl SalesChannels {
             .await
     }
     /**
-     * Retrieve product listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     * Retrieves a list of payments on a particular checkout.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/product_listings.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/payments.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#index-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#index-2020-10
      *
      * **Parameters:**
      *
-     * * `product_ids: &str` -- A comma-separated list of product ids.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     * * `collection_id: &str` -- Filter by products belonging to a particular collection.
-     * * `updated_at_min: &str` -- Filter by products last updated after a certain date and time (formatted in ISO 8601).
-     * * `handle: &str` -- Filter by product handle.
+     * * `token: &str` -- token.
+     */
+    pub async fn get_checkouts_param_token_payment(&self, token: &str) -> ClientResult<()> {
+        self.get_checkouts_param_token_payment_with_response(token)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves a list of payments on a particular checkout.
+     *
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `get_checkouts_param_token_payment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get_product_listing(
+    pub async fn get_checkouts_param_token_payment_with_response(
         &self,
-        product_ids: &str,
-        limit: &str,
-        collection_id: &str,
-        updated_at_min: &str,
-        handle: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
-        }
-        if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
-            &format!("/admin/api/2020-04/product_listings.json?{}", query_),
+            &format!(
+                "/admin/api/checkouts/{}/payments.json",
+                crate::progenitor_support::encode_path(token),
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3287,85 +525,156 @@ impl SalesChannels {
             .await
     }
     /**
-     * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
+     * Creates a payment on a checkout using the session ID returned by the card vault.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/product_listings/product_ids.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/checkouts/{token}/payments.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#product_ids-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#create_payment-2020-10
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `token: &str` -- token.
+     * * `amount_required: &str` -- The amount of the payment.
+     * * `request_details_required: &str` -- The details of the request, including the following attributes:
+     *   
+     *   ip_address: The IP address of the customer.
+     *   accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
+     *   
+     *   user_agent: The user agent string for the customer's device.
+     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
+     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+     */
+    pub async fn create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<()> {
+        self.create_checkouts_param_token_payments_with_response(
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Creates a payment on a checkout using the session ID returned by the card vault.
+     *
+     * This function performs a `POST` to the `/admin/api/checkouts/{token}/payments.json` endpoint.
+     *
+     * As opposed to `create_checkouts_param_token_payments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get_product_listings_id(&self, limit: &str) -> ClientResult<()> {
+    pub async fn create_checkouts_param_token_payments_with_response(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+        if !amount_required.is_empty() {
+            query_args.push((
+                "amount
+                  required"
+                    .to_string(),
+                amount_required.to_string(),
+            ));
+        }
+        if !request_details_required.is_empty() {
+            query_args.push((
+                "request_details
+                  required"
+                    .to_string(),
+                request_details_required.to_string(),
+            ));
+        }
+        if !session_id_required.is_empty() {
+            query_args.push((
+                "session_id
+                  required"
+                    .to_string(),
+                session_id_required.to_string(),
+            ));
+        }
+        if !unique_token_required.is_empty() {
+            query_args.push((
+                "unique_token
+                  required"
+                    .to_string(),
+                unique_token_required.to_string(),
+            ));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/product_listings/product_ids.json?{}",
+                "/admin/api/checkouts/{}/payments.json?{}",
+                crate::progenitor_support::encode_path(token),
                 query_
             ),
             None,
         );
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Retrieve a count of products that are published to your app.
+     * Retrieves the payment information for an existing payment.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/product_listings/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/payments/{payment_id}.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#show-2020-10
+     *
+     * **Parameters:**
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#count-2020-04
+     * * `token: &str` -- token.
+     * * `payment_id: &str` -- payment_id.
      */
-    pub async fn deprecated_202004_get_product_listings_count(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/product_listings/count.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+    pub async fn get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<()> {
+        self.get_checkouts_param_token_payments_payment_with_response(token, payment_id)
+            .await?;
+        Ok(())
     }
     /**
-     * Retrieve a specific product listing that is published to your app.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/product_listings/{product_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#show-2020-04
+     * Retrieves the payment information for an existing payment.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/payments/{payment_id}.json` endpoint.
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_checkouts_param_token_payments_payment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get_product_listings_param_listing(
+    pub async fn get_checkouts_param_token_payments_payment_with_response(
         &self,
-        product_listing_id: &str,
-    ) -> ClientResult<()> {
+        token: &str,
+        payment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/product_listings/{}/json",
-                crate::progenitor_support::encode_path(product_listing_id),
+                "/admin/api/checkouts/{}/payments/{}.json",
+                crate::progenitor_support::encode_path(token),
+                crate::progenitor_support::encode_path(payment_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3375,62 +684,41 @@ impl SalesChannels {
             .await
     }
     /**
-     * Create a product listing to publish a product to your app.
+     * Counts the number of payments attempted on a checkout.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-04/product_listings/{product_listing_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/payments/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#create-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/payment#count-2020-10
      *
      * **Parameters:**
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * * `token: &str` -- token.
      */
-    pub async fn deprecated_202004_update_product_listings_param_listing(
-        &self,
-        product_listing_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/product_listings/{}/json",
-                crate::progenitor_support::encode_path(product_listing_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+    pub async fn get_checkouts_param_token_payments_count(&self, token: &str) -> ClientResult<()> {
+        self.get_checkouts_param_token_payments_count_with_response(token)
+            .await?;
+        Ok(())
     }
     /**
-     * Delete a product listing to unpublish a product from your app.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/product_listings/{product_listing_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#destroy-2020-04
+     * Counts the number of payments attempted on a checkout.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/checkouts/{token}/payments/count.json` endpoint.
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_checkouts_param_token_payments_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_delete_product_listings_param_listing(
+    pub async fn get_checkouts_param_token_payments_count_with_response(
         &self,
-        product_listing_id: &str,
-    ) -> ClientResult<()> {
+        token: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/product_listings/{}/json",
-                crate::progenitor_support::encode_path(product_listing_id),
+                "/admin/api/checkouts/{}/payments/count.json",
+                crate::progenitor_support::encode_path(token),
             ),
             None,
         );
         self.client
-            .delete(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3442,20 +730,22 @@ impl SalesChannels {
     /**
      * Retrieve product listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/product_listings.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/product_listings.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#index-2020-07
      *
+     * The spec only describes this endpoint for API versions `2020-01`, `2020-04`, `2020-07`.
+     *
      * **Parameters:**
      *
      * * `product_ids: &str` -- A comma-separated list of product ids.
      * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     *   (default: 50, maximum: 1000).
      * * `collection_id: &str` -- Filter by products belonging to a particular collection.
      * * `updated_at_min: &str` -- Filter by products last updated after a certain date and time (formatted in ISO 8601).
      * * `handle: &str` -- Filter by product handle.
      */
-    pub async fn deprecated_202007_get_product_listing(
+    pub async fn get_product_listing(
         &self,
         product_ids: &str,
         limit: &str,
@@ -3463,6 +753,31 @@ impl SalesChannels {
         updated_at_min: &str,
         handle: &str,
     ) -> ClientResult<()> {
+        self.get_product_listing_with_response(
+            product_ids,
+            limit,
+            collection_id,
+            updated_at_min,
+            handle,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Retrieve product listings that are published to your app. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/product_listings.json` endpoint.
+     *
+     * As opposed to `get_product_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_product_listing_with_response(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !collection_id.is_empty() {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
@@ -3481,11 +796,11 @@ impl SalesChannels {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/2020-07/product_listings.json?{}", query_),
+            &format!("/admin/api/product_listings.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3497,30 +812,43 @@ impl SalesChannels {
     /**
      * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/product_listings/product_ids.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/product_listings/product_ids.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#product_ids-2020-07
      *
+     * The spec only describes this endpoint for API versions `2020-01`, `2020-04`, `2020-07`.
+     *
      * **Parameters:**
      *
      * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     *   (default: 50, maximum: 1000).
+     */
+    pub async fn get_product_listings_id(&self, limit: &str) -> ClientResult<()> {
+        self.get_product_listings_id_with_response(limit).await?;
+        Ok(())
+    }
+    /**
+     * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
+     *
+     * This function performs a `GET` to the `/admin/api/product_listings/product_ids.json` endpoint.
+     *
+     * As opposed to `get_product_listings_id`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_product_listings_id(&self, limit: &str) -> ClientResult<()> {
+    pub async fn get_product_listings_id_with_response(
+        &self,
+        limit: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !limit.is_empty() {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/product_listings/product_ids.json?{}",
-                query_
-            ),
+            &format!("/admin/api/product_listings/product_ids.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3532,16 +860,31 @@ impl SalesChannels {
     /**
      * Retrieve a count of products that are published to your app.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/product_listings/count.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/product_listings/count.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#count-2020-07
+     *
+     * The spec only describes this endpoint for API versions `2020-01`, `2020-04`, `2020-07`.
+     */
+    pub async fn get_product_listings_count(&self) -> ClientResult<()> {
+        self.get_product_listings_count_with_response().await?;
+        Ok(())
+    }
+    /**
+     * Retrieve a count of products that are published to your app.
+     *
+     * This function performs a `GET` to the `/admin/api/product_listings/count.json` endpoint.
+     *
+     * As opposed to `get_product_listings_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_product_listings_count(&self) -> ClientResult<()> {
+    pub async fn get_product_listings_count_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self
             .client
-            .url("/admin/api/2020-07/product_listings/count.json", None);
+            .url("/admin/api/product_listings/count.json", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3553,27 +896,44 @@ impl SalesChannels {
     /**
      * Retrieve a specific product listing that is published to your app.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/product_listings/{product_listing_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/product_listings/{product_listing_id}.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#show-2020-07
      *
+     * The spec only describes this endpoint for API versions `2020-01`, `2020-04`, `2020-07`.
+     *
      * **Parameters:**
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * * `product_listing_id: &str` -- product_listing_id.
      */
-    pub async fn deprecated_202007_get_product_listings_param_listing(
+    pub async fn get_product_listings_param_listing(
         &self,
         product_listing_id: &str,
     ) -> ClientResult<()> {
+        self.get_product_listings_param_listing_with_response(product_listing_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Retrieve a specific product listing that is published to your app.
+     *
+     * This function performs a `GET` to the `/admin/api/product_listings/{product_listing_id}.json` endpoint.
+     *
+     * As opposed to `get_product_listings_param_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_product_listings_param_listing_with_response(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/product_listings/{}/json",
+                "/admin/api/product_listings/{}.json",
                 crate::progenitor_support::encode_path(product_listing_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3585,28 +945,46 @@ impl SalesChannels {
     /**
      * Create a product listing to publish a product to your app.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-07/product_listings/{product_listing_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/product_listings/{product_listing_id}.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#create-2020-07
      *
+     * The spec only describes this endpoint for API versions `2020-01`, `2020-04`, `2020-07`.
+     *
      * **Parameters:**
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * * `product_listing_id: &str` -- product_listing_id.
      */
-    pub async fn deprecated_202007_update_product_listings_param_listing(
+    pub async fn update_product_listings_param_listing(
         &self,
         product_listing_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.update_product_listings_param_listing_with_response(product_listing_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Create a product listing to publish a product to your app.
+     *
+     * This function performs a `PUT` to the `/admin/api/product_listings/{product_listing_id}.json` endpoint.
+     *
+     * As opposed to `update_product_listings_param_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_product_listings_param_listing_with_response(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/product_listings/{}/json",
+                "/admin/api/product_listings/{}.json",
                 crate::progenitor_support::encode_path(product_listing_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3618,27 +996,44 @@ impl SalesChannels {
     /**
      * Delete a product listing to unpublish a product from your app.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/product_listings/{product_listing_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/product_listings/{product_listing_id}.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/sales-channels/productlisting#destroy-2020-07
      *
+     * The spec only describes this endpoint for API versions `2020-01`, `2020-04`, `2020-07`.
+     *
      * **Parameters:**
      *
-     * * `product_listing_id: &str` -- storefront_access_token_id.
+     * * `product_listing_id: &str` -- product_listing_id.
      */
-    pub async fn deprecated_202007_delete_product_listings_param_listing(
+    pub async fn delete_product_listings_param_listing(
         &self,
         product_listing_id: &str,
     ) -> ClientResult<()> {
+        self.delete_product_listings_param_listing_with_response(product_listing_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a product listing to unpublish a product from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/product_listings/{product_listing_id}.json` endpoint.
+     *
+     * As opposed to `delete_product_listings_param_listing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_product_listings_param_listing_with_response(
+        &self,
+        product_listing_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/product_listings/{}/json",
+                "/admin/api/product_listings/{}.json",
                 crate::progenitor_support::encode_path(product_listing_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -14,75 +14,49 @@ impl ShippingAndFulfillment {
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/assigned_fulfillment_orders.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/assigned_fulfillment_orders.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-10
      *
      * **Parameters:**
      *
      * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
+     *   
+     *   cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
+     *   fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
+     *   fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
      * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `location_ids: i64` -- location_ids[].
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202001_get_assigned_fulfillment_order(
+    pub async fn get_assigned_fulfillment_orders(
         &self,
         assignment_status: &str,
         location_ids: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/assigned_fulfillment_orders.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::FulfillmentOrder>> {
+        Ok(self
+            .get_assigned_fulfillment_orders_with_response(
+                assignment_status,
+                location_ids,
+                page_info,
             )
-            .await
+            .await?
+            .body)
     }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
-     * This function performs a `GET` to the `/admin/api/2020-04/assigned_fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-04
-     *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/assigned_fulfillment_orders.json` endpoint.
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * As opposed to `get_assigned_fulfillment_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get_assigned_fulfillment_order(
+    pub async fn get_assigned_fulfillment_orders_with_response(
         &self,
         assignment_status: &str,
         location_ids: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FulfillmentOrder>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !assignment_status.is_empty() {
             query_args.push((
@@ -93,46 +67,42 @@ impl ShippingAndFulfillment {
         if !location_ids.is_empty() {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/assigned_fulfillment_orders.json?{}",
-                query_
-            ),
+            &format!("/admin/api/assigned_fulfillment_orders.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::FulfillmentOrdersRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.fulfillment_orders.to_vec()))
     }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/assigned_fulfillment_orders.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/assigned_fulfillment_orders.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-07
+     * As opposed to `get_assigned_fulfillment_orders`, this function returns all the pages of the request at once.
      *
-     * **Parameters:**
-     *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-10
      */
-    pub async fn deprecated_202007_get_assigned_fulfillment_order(
+    pub async fn get_all_assigned_fulfillment_orders(
         &self,
         assignment_status: &str,
         location_ids: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<Vec<crate::types::FulfillmentOrder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !assignment_status.is_empty() {
             query_args.push((
@@ -145,44 +115,71 @@ impl ShippingAndFulfillment {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/assigned_fulfillment_orders.json?{}",
-                query_
-            ),
+            &format!("/admin/api/assigned_fulfillment_orders.json?{}", query_),
             None,
         );
-        self.client
-            .get(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::FulfillmentOrdersRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut fulfillment_orders = resp.fulfillment_orders;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::FulfillmentOrdersRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            fulfillment_orders.append(&mut resp.fulfillment_orders);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(fulfillment_orders)
     }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/assigned_fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2020-10
-     *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/assigned_fulfillment_orders.json` endpoint.
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * As opposed to `get_all_assigned_fulfillment_orders`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn get_assigned_fulfillment_order(
+    pub fn get_all_assigned_fulfillment_orders_pages_stream(
         &self,
         assignment_status: &str,
         location_ids: &str,
-    ) -> ClientResult<()> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::FulfillmentOrder>>>
+           + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !assignment_status.is_empty() {
             query_args.push((
@@ -195,155 +192,125 @@ impl ShippingAndFulfillment {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/assigned_fulfillment_orders.json?{}",
-                query_
-            ),
+            &format!("/admin/api/assigned_fulfillment_orders.json?{}", query_),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::FulfillmentOrdersRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.fulfillment_orders;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/assigned_fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-2021-01
-     *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/assigned_fulfillment_orders.json` endpoint.
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * As opposed to `get_all_assigned_fulfillment_orders`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
      */
-    pub async fn deprecated_202101_get_assigned_fulfillment_order(
+    pub fn get_all_assigned_fulfillment_orders_stream(
         &self,
         assignment_status: &str,
         location_ids: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/assigned_fulfillment_orders.json?{}",
-                query_
-            ),
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FulfillmentOrder>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_assigned_fulfillment_orders_pages_stream(
+            assignment_status,
+            location_ids,
             None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        ))
     }
     /**
-     * Retrieves a list of fulfillment orders on a shop for a specific app.
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/assigned_fulfillment_orders.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/assignedfulfillmentorder#index-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-10
      *
      * **Parameters:**
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_unstable_get_assigned_fulfillment_order(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request(
         &self,
-        assignment_status: &str,
-        location_ids: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
-        }
-        if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/assigned_fulfillment_orders.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceCancellationRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_cancellation_request_with_response(
+                fulfillment_order_id,
+                body,
             )
-            .await
+            .await?
+            .body)
     }
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-01
-     *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * As opposed to `create_fulfillment_orders_param_order_cancellation_request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_with_response(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        body: &crate::types::FulfillmentServiceCancellationRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request.json?{}",
+                "/admin/api/fulfillment_orders/{}/cancellation_request.json",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -355,36 +322,48 @@ impl ShippingAndFulfillment {
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_accept(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        body: &crate::types::FulfillmentServiceCancellationRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_cancellation_request_accept_with_response(
+                fulfillment_order_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_cancellation_request_accept`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_accept_with_response(
+        &self,
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceCancellationRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
+                "/admin/api/fulfillment_orders/{}/cancellation_request/accept.json",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -396,77 +375,48 @@ impl ShippingAndFulfillment {
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
+        body: &crate::types::FulfillmentServiceCancellationRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_cancellation_request_reject_with_response(
+                fulfillment_order_id,
+                body,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-     * Sends a cancellation request to the fulfillment service of a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-04
+     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * As opposed to `create_fulfillment_orders_param_order_cancellation_request_reject`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_reject_with_response(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        body: &crate::types::FulfillmentServiceCancellationRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request.json?{}",
+                "/admin/api/fulfillment_orders/{}/cancellation_request/reject.json",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -476,79 +426,60 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     * Retrieves a list of carrier services.
      *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/carrier_services.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#index-2020-10
+     */
+    pub async fn get_carrier_service(&self) -> ClientResult<()> {
+        self.get_carrier_service_with_response().await?;
+        Ok(())
+    }
+    /**
+     * Retrieves a list of carrier services.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/carrier_services.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * As opposed to `get_carrier_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
+    pub async fn get_carrier_service_with_response(&self) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url("/admin/api/carrier_services.json", None);
         self.client
-            .post(
+            .get_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
+     * Creates a carrier service.
      *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/carrier_services.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#create-2020-10
+     */
+    pub async fn create_carrier_services(&self, body: &serde_json::Value) -> ClientResult<()> {
+        self.create_carrier_services_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Creates a carrier service.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/carrier_services.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * As opposed to `create_carrier_services`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn create_carrier_services_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url("/admin/api/carrier_services.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -558,120 +489,93 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     * Retrieves a single carrier service by its ID.
      *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/carrier_services/{carrier_service_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#show-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * * `carrier_service_id: &str` -- carrier_service_id.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn get_carrier_services_param_service(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
+        carrier_service_id: &str,
     ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        self.get_carrier_services_param_service_with_response(carrier_service_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves a single carrier service by its ID.
+     *
+     * This function performs a `GET` to the `/admin/api/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `get_carrier_services_param_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_carrier_services_param_service_with_response(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/carrier_services/{}.json",
+                crate::progenitor_support::encode_path(carrier_service_id),
             ),
             None,
         );
         self.client
-            .post(
+            .get_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     * Updates a carrier service. Only the app that creates a carrier service can update it.
      *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/carrier_services/{carrier_service_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#update-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `carrier_service_id: &str` -- carrier_service_id.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn update_carrier_services_param_service(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
+        carrier_service_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        self.update_carrier_services_param_service_with_response(carrier_service_id, body)
+            .await?;
+        Ok(())
     }
     /**
-     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-07
+     * Updates a carrier service. Only the app that creates a carrier service can update it.
      *
-     * **Parameters:**
+     * This function performs a `PUT` to the `/admin/api/carrier_services/{carrier_service_id}.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * As opposed to `update_carrier_services_param_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn update_carrier_services_param_service_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
+        carrier_service_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/carrier_services/{}.json",
+                crate::progenitor_support::encode_path(carrier_service_id),
             ),
             None,
         );
         self.client
-            .post(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -681,6450 +585,452 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     * Deletes a carrier service.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/carrier_services/{carrier_service_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * * `carrier_service_id: &str` -- carrier_service_id.
      */
-    pub async fn create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn delete_carrier_services_param_service(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
+        carrier_service_id: &str,
     ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        self.delete_carrier_services_param_service_with_response(carrier_service_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a carrier service.
+     *
+     * This function performs a `DELETE` to the `/admin/api/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `delete_carrier_services_param_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_carrier_services_param_service_with_response(
+        &self,
+        carrier_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/carrier_services/{}.json",
+                crate::progenitor_support::encode_path(carrier_service_id),
             ),
             None,
         );
         self.client
-            .post(
+            .delete_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `order_id: &str` -- order_id.
+     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `limit: &str` -- Limit the amount of results.
+     *   (default: 50, maximum: 250).
+     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
+     */
+    pub async fn get_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Fulfillment>> {
+        Ok(self
+            .get_orders_param_order_fulfillments_with_response(
+                order_id,
+                created_at_max,
+                created_at_min,
+                fields,
+                limit,
+                since_id,
+                updated_at_max,
+                updated_at_min,
+                page_info,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
+     *
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn get_orders_param_order_fulfillments_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Fulfillment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !limit.is_empty() {
+            query_args.push(("limit".to_string(), limit.to_string()));
+        }
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
+                "/admin/api/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
                 query_
             ),
             None,
         );
-        self.client
-            .post(
+        let resp: crate::Response<crate::types::FulfillmentsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.fulfillments.to_vec()))
     }
     /**
-     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2020-10
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_orders_param_order_fulfillments`, this function returns all the pages of the request at once.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-10
      */
-    pub async fn create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn get_all_orders_param_order_fulfillments(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> ClientResult<Vec<crate::types::Fulfillment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
+                "/admin/api/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
                 query_
             ),
             None,
         );
-        self.client
-            .post(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::FulfillmentsRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut fulfillments = resp.fulfillments;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::FulfillmentsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            fulfillments.append(&mut resp.fulfillments);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(fulfillments)
     }
     /**
-     * Sends a cancellation request to the fulfillment service of a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-2021-01
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * As opposed to `get_all_orders_param_order_fulfillments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
+    pub fn get_all_orders_param_order_fulfillments_pages_stream(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Fulfillment>>>
+           + Unpin
+           + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
+        }
+        if !since_id.is_empty() {
+            query_args.push(("since_id".to_string(), since_id.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+        }
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
+                "/admin/api/orders/{}/fulfillments.json?{}",
+                crate::progenitor_support::encode_path(order_id),
                 query_
             ),
             None,
         );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::FulfillmentsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.fulfillments;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-2021-01
+     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * As opposed to `get_all_orders_param_order_fulfillments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
-     */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Sends a cancellation request to the fulfillment service of a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
-     */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#accept-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
-     */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#reject-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
-     */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of carrier services.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#index-2020-01
-     */
-    pub async fn deprecated_202001_get_carrier_service(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/carrier_services.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a carrier service.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#create-2020-01
-     */
-    pub async fn deprecated_202001_create_carrier_services(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/carrier_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single carrier service by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a carrier service. Only the app that creates a carrier service can update it.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a carrier service.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of carrier services.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#index-2020-04
-     */
-    pub async fn deprecated_202004_get_carrier_service(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/carrier_services.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a carrier service.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#create-2020-04
-     */
-    pub async fn deprecated_202004_create_carrier_services(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/carrier_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single carrier service by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a carrier service. Only the app that creates a carrier service can update it.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a carrier service.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of carrier services.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#index-2020-07
-     */
-    pub async fn deprecated_202007_get_carrier_service(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/carrier_services.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a carrier service.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#create-2020-07
-     */
-    pub async fn deprecated_202007_create_carrier_services(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/carrier_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single carrier service by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a carrier service. Only the app that creates a carrier service can update it.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a carrier service.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of carrier services.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#index-2020-10
-     */
-    pub async fn get_carrier_service(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/carrier_services.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a carrier service.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#create-2020-10
-     */
-    pub async fn create_carrier_services(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/carrier_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single carrier service by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a carrier service. Only the app that creates a carrier service can update it.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a carrier service.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of carrier services.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#index-2021-01
-     */
-    pub async fn deprecated_202101_get_carrier_service(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/carrier_services.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a carrier service.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#create-2021-01
-     */
-    pub async fn deprecated_202101_create_carrier_services(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/carrier_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single carrier service by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a carrier service. Only the app that creates a carrier service can update it.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a carrier service.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of carrier services.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#index-unstable
-     */
-    pub async fn deprecated_unstable_get_carrier_service(&self) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/carrier_services.json", None);
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a carrier service.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/carrier_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#create-unstable
-     */
-    pub async fn deprecated_unstable_create_carrier_services(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/carrier_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a single carrier service by its ID.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Updates a carrier service. Only the app that creates a carrier service can update it.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a carrier service.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/carrier_services/{carrier_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/carrierservice#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `carrier_service_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_carrier_services_param_service(
-        &self,
-        carrier_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/carrier_services/{}/json",
-                crate::progenitor_support::encode_path(carrier_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillment(
-        &self,
-        order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Create a fulfillment for the specified order and line items.
-              The fulfillment's status depends on the line items in the order:
-
-              If the line items in the fulfillment use a manual or custom fulfillment service, then the status of the returned fulfillment will be set immediately.
-              If the line items use an external fulfillment service, then they will be queued for fulfillment and the status will be set to pending until the external fulfillment service has been invoked.
-
-
-              A fulfillment might then transition to open, which implies it is being processed by the service, before transitioning to success when the items have shipped.
-              If you don't specify line item IDs, then all unfulfilled and partially fulfilled line items for the order will be fulfilled.
-              However, if an order is refunded or if any of its individual line items are refunded, then the order can't be fulfilled.
-
-              All line items being fulfilled must have the same fulfillment service.
-
-
-                Note
-                If you are using this endpoint with a Partner development store or a trial store, then you can create no more than 5 new fulfillments per minute.
-
-              About tracking urls
-               If you're creating a fulfillment for a supported carrier, then you can send the tracking_company and tracking_numbers fields, and Shopify will generate the tracking_url for you. If you're creating a fulfillment for an unsupported carrier (not in the tracking_company list), then send the tracking_company, tracking_numbers, and tracking_urls fields.
-
-
-                Note
-                If you send an unsupported carrier without a tracking URL, then Shopify will still try to generate a valid tracking URL by using pattern matching on the tracking number. However, Shopify does not validate the tracking URL, so you should make sure that your tracking URL is correct for the order and fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/fulfillments.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#create-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202001_create_orders_param_order_fulfillments(
-        &self,
-        order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with a fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order that is associated with the fulfillments.
-     */
-    pub async fn deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of fulfillments associated with a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillments_count(
-        &self,
-        order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/count.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update information associated with a fulfillment.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#createV2-2020-01
-     */
-    pub async fn deprecated_202001_create_fulfillments(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/fulfillments.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the tracking information for a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update_tracking-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillments/{}/update_tracking.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as complete.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#complete-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/complete.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as open.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#open-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/open.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancel a fulfillment for a specific order ID.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancel-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancelV2-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_fulfillments_param_fulfillment_cancel(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillment(
-        &self,
-        order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Create a fulfillment for the specified order and line items.
-              The fulfillment's status depends on the line items in the order:
-
-              If the line items in the fulfillment use a manual or custom fulfillment service, then the status of the returned fulfillment will be set immediately.
-              If the line items use an external fulfillment service, then they will be queued for fulfillment and the status will be set to pending until the external fulfillment service has been invoked.
-
-
-              A fulfillment might then transition to open, which implies it is being processed by the service, before transitioning to success when the items have shipped.
-              If you don't specify line item IDs, then all unfulfilled and partially fulfilled line items for the order will be fulfilled.
-              However, if an order is refunded or if any of its individual line items are refunded, then the order can't be fulfilled.
-
-              All line items being fulfilled must have the same fulfillment service.
-
-
-                Note
-                If you are using this endpoint with a Partner development store or a trial store, then you can create no more than 5 new fulfillments per minute.
-
-              About tracking urls
-               If you're creating a fulfillment for a supported carrier, then you can send the tracking_company and tracking_numbers fields, and Shopify will generate the tracking_url for you. If you're creating a fulfillment for an unsupported carrier (not in the tracking_company list), then send the tracking_company, tracking_numbers, and tracking_urls fields.
-
-
-                Note
-                If you send an unsupported carrier without a tracking URL, then Shopify will still try to generate a valid tracking URL by using pattern matching on the tracking number. However, Shopify does not validate the tracking URL, so you should make sure that your tracking URL is correct for the order and fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/fulfillments.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#create-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202004_create_orders_param_order_fulfillments(
-        &self,
-        order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with a fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order that is associated with the fulfillments.
-     */
-    pub async fn deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of fulfillments associated with a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillments_count(
-        &self,
-        order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/count.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update information associated with a fulfillment.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#createV2-2020-04
-     */
-    pub async fn deprecated_202004_create_fulfillments(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/fulfillments.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the tracking information for a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update_tracking-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillments/{}/update_tracking.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as complete.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#complete-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/complete.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as open.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#open-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/open.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancel a fulfillment for a specific order ID.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancel-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancelV2-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_fulfillments_param_fulfillment_cancel(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillment(
-        &self,
-        order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Create a fulfillment for the specified order and line items.
-              The fulfillment's status depends on the line items in the order:
-
-              If the line items in the fulfillment use a manual or custom fulfillment service, then the status of the returned fulfillment will be set immediately.
-              If the line items use an external fulfillment service, then they will be queued for fulfillment and the status will be set to pending until the external fulfillment service has been invoked.
-
-
-              A fulfillment might then transition to open, which implies it is being processed by the service, before transitioning to success when the items have shipped.
-              If you don't specify line item IDs, then all unfulfilled and partially fulfilled line items for the order will be fulfilled.
-              However, if an order is refunded or if any of its individual line items are refunded, then the order can't be fulfilled.
-
-              All line items being fulfilled must have the same fulfillment service.
-
-
-                Note
-                If you are using this endpoint with a Partner development store or a trial store, then you can create no more than 5 new fulfillments per minute.
-
-              About tracking urls
-               If you're creating a fulfillment for a supported carrier, then you can send the tracking_company and tracking_numbers fields, and Shopify will generate the tracking_url for you. If you're creating a fulfillment for an unsupported carrier (not in the tracking_company list), then send the tracking_company, tracking_numbers, and tracking_urls fields.
-
-
-                Note
-                If you send an unsupported carrier without a tracking URL, then Shopify will still try to generate a valid tracking URL by using pattern matching on the tracking number. However, Shopify does not validate the tracking URL, so you should make sure that your tracking URL is correct for the order and fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/fulfillments.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#create-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202007_create_orders_param_order_fulfillments(
-        &self,
-        order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with a fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order that is associated with the fulfillments.
-     */
-    pub async fn deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of fulfillments associated with a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillments_count(
-        &self,
-        order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/count.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update information associated with a fulfillment.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#createV2-2020-07
-     */
-    pub async fn deprecated_202007_create_fulfillments(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/fulfillments.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the tracking information for a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update_tracking-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillments/{}/update_tracking.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as complete.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#complete-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/complete.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as open.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#open-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/open.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancel a fulfillment for a specific order ID.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancel-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancelV2-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_fulfillments_param_fulfillment_cancel(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn get_orders_param_order_fulfillment(
-        &self,
-        order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Create a fulfillment for the specified order and line items.
-              The fulfillment's status depends on the line items in the order:
-
-              If the line items in the fulfillment use a manual or custom fulfillment service, then the status of the returned fulfillment will be set immediately.
-              If the line items use an external fulfillment service, then they will be queued for fulfillment and the status will be set to pending until the external fulfillment service has been invoked.
-
-
-              A fulfillment might then transition to open, which implies it is being processed by the service, before transitioning to success when the items have shipped.
-              If you don't specify line item IDs, then all unfulfilled and partially fulfilled line items for the order will be fulfilled.
-              However, if an order is refunded or if any of its individual line items are refunded, then the order can't be fulfilled.
-
-              All line items being fulfilled must have the same fulfillment service.
-
-
-                Note
-                If you are using this endpoint with a Partner development store or a trial store, then you can create no more than 5 new fulfillments per minute.
-
-              About tracking urls
-               If you're creating a fulfillment for a supported carrier, then you can send the tracking_company and tracking_numbers fields, and Shopify will generate the tracking_url for you. If you're creating a fulfillment for an unsupported carrier (not in the tracking_company list), then send the tracking_company, tracking_numbers, and tracking_urls fields.
-
-
-                Note
-                If you send an unsupported carrier without a tracking URL, then Shopify will still try to generate a valid tracking URL by using pattern matching on the tracking number. However, Shopify does not validate the tracking URL, so you should make sure that your tracking URL is correct for the order and fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/orders/{order_id}/fulfillments.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#create-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn create_orders_param_order_fulfillments(
-        &self,
-        order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with a fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order that is associated with the fulfillments.
-     */
-    pub async fn get_fulfillment_orders_param_order_fulfillment(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of fulfillments associated with a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn get_orders_param_order_fulfillments_count(
-        &self,
-        order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/count.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
-     */
-    pub async fn get_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update information associated with a fulfillment.
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn update_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#createV2-2020-10
-     */
-    pub async fn create_fulfillments(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/fulfillments.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the tracking information for a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update_tracking-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_fulfillments_param_fulfillment_update_tracking(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillments/{}/update_tracking.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as complete.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#complete-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_orders_param_order_fulfillments_fulfillment_complete(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/complete.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as open.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#open-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_orders_param_order_fulfillments_fulfillment_open(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/open.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancel a fulfillment for a specific order ID.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancel-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_orders_param_order_fulfillments_fulfillment_cancel(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancelV2-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_fulfillments_param_fulfillment_cancel(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillment(
-        &self,
-        order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Create a fulfillment for the specified order and line items.
-              The fulfillment's status depends on the line items in the order:
-
-              If the line items in the fulfillment use a manual or custom fulfillment service, then the status of the returned fulfillment will be set immediately.
-              If the line items use an external fulfillment service, then they will be queued for fulfillment and the status will be set to pending until the external fulfillment service has been invoked.
-
-
-              A fulfillment might then transition to open, which implies it is being processed by the service, before transitioning to success when the items have shipped.
-              If you don't specify line item IDs, then all unfulfilled and partially fulfilled line items for the order will be fulfilled.
-              However, if an order is refunded or if any of its individual line items are refunded, then the order can't be fulfilled.
-
-              All line items being fulfilled must have the same fulfillment service.
-
-
-                Note
-                If you are using this endpoint with a Partner development store or a trial store, then you can create no more than 5 new fulfillments per minute.
-
-              About tracking urls
-               If you're creating a fulfillment for a supported carrier, then you can send the tracking_company and tracking_numbers fields, and Shopify will generate the tracking_url for you. If you're creating a fulfillment for an unsupported carrier (not in the tracking_company list), then send the tracking_company, tracking_numbers, and tracking_urls fields.
-
-
-                Note
-                If you send an unsupported carrier without a tracking URL, then Shopify will still try to generate a valid tracking URL by using pattern matching on the tracking number. However, Shopify does not validate the tracking URL, so you should make sure that your tracking URL is correct for the order and fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/orders/{order_id}/fulfillments.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#create-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_create_orders_param_order_fulfillments(
-        &self,
-        order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with a fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order that is associated with the fulfillments.
-     */
-    pub async fn deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of fulfillments associated with a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillments_count(
-        &self,
-        order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/count.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update information associated with a fulfillment.
-     *
-     * This function performs a `PUT` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#createV2-2021-01
-     */
-    pub async fn deprecated_202101_create_fulfillments(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/fulfillments.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the tracking information for a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update_tracking-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillments/{}/update_tracking.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as complete.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#complete-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/complete.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as open.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#open-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/open.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancel a fulfillment for a specific order ID.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancel-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancelV2-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_fulfillments_param_fulfillment_cancel(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillment(
-        &self,
-        order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * Create a fulfillment for the specified order and line items.
-              The fulfillment's status depends on the line items in the order:
-
-              If the line items in the fulfillment use a manual or custom fulfillment service, then the status of the returned fulfillment will be set immediately.
-              If the line items use an external fulfillment service, then they will be queued for fulfillment and the status will be set to pending until the external fulfillment service has been invoked.
-
-
-              A fulfillment might then transition to open, which implies it is being processed by the service, before transitioning to success when the items have shipped.
-              If you don't specify line item IDs, then all unfulfilled and partially fulfilled line items for the order will be fulfilled.
-              However, if an order is refunded or if any of its individual line items are refunded, then the order can't be fulfilled.
-
-              All line items being fulfilled must have the same fulfillment service.
-
-
-                Note
-                If you are using this endpoint with a Partner development store or a trial store, then you can create no more than 5 new fulfillments per minute.
-
-              About tracking urls
-               If you're creating a fulfillment for a supported carrier, then you can send the tracking_company and tracking_numbers fields, and Shopify will generate the tracking_url for you. If you're creating a fulfillment for an unsupported carrier (not in the tracking_company list), then send the tracking_company, tracking_numbers, and tracking_urls fields.
-
-
-                Note
-                If you send an unsupported carrier without a tracking URL, then Shopify will still try to generate a valid tracking URL by using pattern matching on the tracking number. However, Shopify does not validate the tracking URL, so you should make sure that your tracking URL is correct for the order and fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/orders/{order_id}/fulfillments.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#create-unstable
-    *
-    * **Parameters:**
-    *
-    * * `order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_create_orders_param_order_fulfillments(
-        &self,
-        order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves fulfillments associated with a fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order that is associated with the fulfillments.
-     */
-    pub async fn deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/fulfillments.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a count of fulfillments associated with a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/count.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_count(
-        &self,
-        order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
-        }
-        if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
-        }
-        if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
-        }
-        if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/count.json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieve a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
-     */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        fields: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/json?{}",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Update information associated with a fulfillment.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .put(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillments.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#createV2-unstable
-     */
-    pub async fn deprecated_unstable_create_fulfillments(
-        &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/unstable/fulfillments.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Updates the tracking information for a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update_tracking-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillments/{}/update_tracking.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as complete.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#complete-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/complete.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Mark a fulfillment as open.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#open-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/open.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancel a fulfillment for a specific order ID.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancel-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Cancels a fulfillment.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillments/{fulfillment_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancelV2-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
-        &self,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillments/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment events for a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- The ID of the fulfillment that's associated with the fulfillment event.
-     * * `order_id: &str` -- The ID of the order that's associated with the fulfillment event.
-     */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment event.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#create-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment event.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- The ID of the fulfillment event.
-     */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a fulfillment event.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#destroy-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment events for a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- The ID of the fulfillment that's associated with the fulfillment event.
-     * * `order_id: &str` -- The ID of the order that's associated with the fulfillment event.
-     */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment event.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#create-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment event.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- The ID of the fulfillment event.
-     */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a fulfillment event.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#destroy-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment events for a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- The ID of the fulfillment that's associated with the fulfillment event.
-     * * `order_id: &str` -- The ID of the order that's associated with the fulfillment event.
-     */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment event.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#create-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment event.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- The ID of the fulfillment event.
-     */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a fulfillment event.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#destroy-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment events for a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- The ID of the fulfillment that's associated with the fulfillment event.
-     * * `order_id: &str` -- The ID of the order that's associated with the fulfillment event.
-     */
-    pub async fn get_orders_param_order_fulfillments_fulfillment_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment event.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#create-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_orders_param_order_fulfillments_fulfillment_events(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment event.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- The ID of the fulfillment event.
-     */
-    pub async fn get_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a fulfillment event.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#destroy-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn delete_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment events for a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- The ID of the fulfillment that's associated with the fulfillment event.
-     * * `order_id: &str` -- The ID of the order that's associated with the fulfillment event.
-     */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment event.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#create-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment event.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- The ID of the fulfillment event.
-     */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a fulfillment event.
-     *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#destroy-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment events for a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- The ID of the fulfillment that's associated with the fulfillment event.
-     * * `order_id: &str` -- The ID of the order that's associated with the fulfillment event.
-     */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Creates a fulfillment event.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#create-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/events.json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment event.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- The ID of the fulfillment event.
-     */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Deletes a fulfillment event.
-     *
-     * This function performs a `DELETE` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#destroy-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `event_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(
-        &self,
-        order_id: &str,
-        fulfillment_id: &str,
-        event_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillments/{}/events/{}/json",
-                crate::progenitor_support::encode_path(order_id),
-                crate::progenitor_support::encode_path(fulfillment_id),
-                crate::progenitor_support::encode_path(event_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment orders for a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/orders/{order_id}/fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `order_id: &str` -- The ID of the order that is associated with the fulfillment orders.
-     */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
-        &self,
-        order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/orders/{}/fulfillment_orders.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#show-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_get_fulfillment_orders_param_order(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a fulfillment order as cancelled.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#cancel-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancel(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
-            is unable to ship any remaining items and intends to close the fulfillment order.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
-    */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_close(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/close.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
-     */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_move(
-        &self,
-        fulfillment_order_id: &str,
-        new_location_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/move.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment orders for a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/orders/{order_id}/fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `order_id: &str` -- The ID of the order that is associated with the fulfillment orders.
-     */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
-        &self,
-        order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/orders/{}/fulfillment_orders.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#show-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_get_fulfillment_orders_param_order(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a fulfillment order as cancelled.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#cancel-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancel(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
-            is unable to ship any remaining items and intends to close the fulfillment order.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
-    */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_close(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/close.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-04
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
-     */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_move(
-        &self,
-        fulfillment_order_id: &str,
-        new_location_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/move.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment orders for a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/orders/{order_id}/fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `order_id: &str` -- The ID of the order that is associated with the fulfillment orders.
-     */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
-        &self,
-        order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/orders/{}/fulfillment_orders.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#show-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_get_fulfillment_orders_param_order(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a fulfillment order as cancelled.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#cancel-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancel(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
-            is unable to ship any remaining items and intends to close the fulfillment order.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
-    */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_close(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/close.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-07
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
-     */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_move(
-        &self,
-        fulfillment_order_id: &str,
-        new_location_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/move.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment orders for a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `order_id: &str` -- The ID of the order that is associated with the fulfillment orders.
-     */
-    pub async fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
-        &self,
-        order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/orders/{}/fulfillment_orders.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn get_fulfillment_orders_param_order(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a fulfillment order as cancelled.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#cancel-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn create_fulfillment_orders_param_order_cancel(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
-            is unable to ship any remaining items and intends to close the fulfillment order.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
-    */
-    pub async fn create_fulfillment_orders_param_order_close(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/close.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
-     */
-    pub async fn create_fulfillment_orders_param_order_move(
-        &self,
-        fulfillment_order_id: &str,
-        new_location_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/move.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment orders for a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/orders/{order_id}/fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `order_id: &str` -- The ID of the order that is associated with the fulfillment orders.
-     */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
-        &self,
-        order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/orders/{}/fulfillment_orders.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#show-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_get_fulfillment_orders_param_order(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a fulfillment order as cancelled.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#cancel-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancel(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
-            is unable to ship any remaining items and intends to close the fulfillment order.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
-    */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_close(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/close.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2021-01
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
-     */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_move(
-        &self,
-        fulfillment_order_id: &str,
-        new_location_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/move.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks a scheduled fulfillment order as ready for fulfillment.
-              This endpoint allows merchants to work on a scheduled fulfillment order before its expected fulfill_at datetime.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/open.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#open-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_open(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/open.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Updates the fulfill_at time of a scheduled fulfillment order.
-              This endpoint is used to manage the time a scheduled fulfillment order will be marked as ready for fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/reschedule.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#reschedule-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_reschedule(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/reschedule.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a list of fulfillment orders for a specific order.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillment_orders.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-unstable
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `order_id: &str` -- The ID of the order that is associated with the fulfillment orders.
-     */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
-        &self,
-        order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/orders/{}/fulfillment_orders.json",
-                crate::progenitor_support::encode_path(order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Retrieves a specific fulfillment order.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#show-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_get_fulfillment_orders_param_order(
-        &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-     * Marks a fulfillment order as cancelled.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#cancel-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancel(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/cancel.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Releases the fulfillment order holds for a specific order. Fulfillment orders are created
-              with an ON_HOLD status if the channel that created the order has a fulfillment hold policy.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/release_hold.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#release_hold-unstable
-    *
-    * **Parameters:**
-    *
-    * * `order_id: &str` -- The ID of the order that is associated to the fulfillment orders.
-    */
-    pub async fn deprecated_unstable_create_fulfillment_orders_release_hold(
+    pub fn get_all_orders_param_order_fulfillments_stream(
         &self,
         order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !order_id.is_empty() {
-            query_args.push(("order_id".to_string(), order_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/release_hold.json?{}",
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
-            is unable to ship any remaining items and intends to close the fulfillment order.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-unstable
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
-    */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_close(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/close.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
-     */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_move(
-        &self,
-        fulfillment_order_id: &str,
-        new_location_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/move.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Marks a scheduled fulfillment order as ready for fulfillment.
-              This endpoint allows merchants to work on a scheduled fulfillment order before its expected fulfill_at datetime.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/open.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#open-unstable
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_open(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/open.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-    * Updates the fulfill_at time of a scheduled fulfillment order.
-              This endpoint is used to manage the time a scheduled fulfillment order will be marked as ready for fulfillment.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/reschedule.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#reschedule-unstable
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
-        &self,
-        fulfillment_order_id: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/reschedule.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Fulfillment>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_orders_param_order_fulfillments_pages_stream(
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            since_id,
+            updated_at_max,
+            updated_at_min,
             None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        ))
     }
     /**
-     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     * Create a fulfillment for the specified order and line items.
+     * The fulfillment's status depends on the line items in the order:
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     * If the line items in the fulfillment use a manual or custom fulfillment service, then the status of the returned fulfillment will be set immediately.
+     * If the line items use an external fulfillment service, then they will be queued for fulfillment and the status will be set to pending until the external fulfillment service has been invoked.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-01
      *
-     * **Parameters:**
+     * A fulfillment might then transition to open, which implies it is being processed by the service, before transitioning to success when the items have shipped.
+     * If you don't specify line item IDs, then all unfulfilled and partially fulfilled line items for the order will be fulfilled.
+     * However, if an order is refunded or if any of its individual line items are refunded, then the order can't be fulfilled.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
-     */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request(
-        &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
-            query_args.push((
-                "fulfillment_order_line_items".to_string(),
-                fulfillment_order_line_items.to_string(),
-            ));
-        }
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
-    }
-    /**
-     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     * All line items being fulfilled must have the same fulfillment service.
+     *
+     *
+     * Note
+     * If you are using this endpoint with a Partner development store or a trial store, then you can create no more than 5 new fulfillments per minute.
+     *
+     * About tracking urls
+     * If you're creating a fulfillment for a supported carrier, then you can send the tracking_company and tracking_numbers fields, and Shopify will generate the tracking_url for you. If you're creating a fulfillment for an unsupported carrier (not in the tracking_company list), then send the tracking_company, tracking_numbers, and tracking_urls fields.
      *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-01
+     * Note
+     * If you send an unsupported carrier without a tracking URL, then Shopify will still try to generate a valid tracking URL by using pattern matching on the tracking number. However, Shopify does not validate the tracking URL, so you should make sure that your tracking URL is correct for the order and fulfillment.
+     *
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#create-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `order_id: &str` -- order_id.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept(
+    pub async fn create_orders_param_order_fulfillments(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        order_id: &str,
+        body: &crate::types::FulfillmentRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .create_orders_param_order_fulfillments_with_response(order_id, body)
+            .await?
+            .body)
     }
     /**
-     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-01
+     * Create a fulfillment for the specified order and line items.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * As opposed to `create_orders_param_order_fulfillments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject(
+    pub async fn create_orders_param_order_fulfillments_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        order_id: &str,
+        body: &crate::types::FulfillmentRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/orders/{}/fulfillments.json",
+                crate::progenitor_support::encode_path(order_id),
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7134,390 +1040,424 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     * Retrieves fulfillments associated with a fulfillment order.
      *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
+     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order that is associated with the fulfillments.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request(
+    pub async fn get_fulfillment_orders_param_order_fulfillments(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
-            query_args.push((
-                "fulfillment_order_line_items".to_string(),
-                fulfillment_order_line_items.to_string(),
-            ));
-        }
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::Fulfillment>> {
+        Ok(self
+            .get_fulfillment_orders_param_order_fulfillments_with_response(
+                fulfillment_order_id,
+                page_info,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-04
+     * Retrieves fulfillments associated with a fulfillment order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * As opposed to `get_fulfillment_orders_param_order_fulfillments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept(
+    pub async fn get_fulfillment_orders_param_order_fulfillments_with_response(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Fulfillment>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
+                "/admin/api/fulfillment_orders/{}/fulfillments.json?{}",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
                 query_
             ),
             None,
         );
-        self.client
-            .post(
+        let resp: crate::Response<crate::types::FulfillmentsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.fulfillments.to_vec()))
     }
     /**
-     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     * Retrieves fulfillments associated with a fulfillment order.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-04
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_fulfillment_orders_param_order_fulfillments`, this function returns all the pages of the request at once.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#indexV2-2020-10
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject(
+    pub async fn get_all_fulfillment_orders_param_order_fulfillments(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    ) -> ClientResult<Vec<crate::types::Fulfillment>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
+                "/admin/api/fulfillment_orders/{}/fulfillments.json",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
             ),
             None,
         );
-        self.client
-            .post(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::FulfillmentsRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut fulfillments = resp.fulfillments;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::FulfillmentsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            fulfillments.append(&mut resp.fulfillments);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(fulfillments)
     }
     /**
-     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-07
+     * Retrieves fulfillments associated with a fulfillment order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * As opposed to `get_all_fulfillment_orders_param_order_fulfillments`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request(
+    pub fn get_all_fulfillment_orders_param_order_fulfillments_pages_stream(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
-            query_args.push((
-                "fulfillment_order_line_items".to_string(),
-                fulfillment_order_line_items.to_string(),
-            ));
-        }
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Fulfillment>>>
+           + Unpin
+           + '_ {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request.json?{}",
+                "/admin/api/fulfillment_orders/{}/fulfillments.json",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
             ),
             None,
         );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::FulfillmentsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.fulfillments;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-07
+     * Retrieves fulfillments associated with a fulfillment order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillments.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * As opposed to `get_all_fulfillment_orders_param_order_fulfillments`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept(
+    pub fn get_all_fulfillment_orders_param_order_fulfillments_stream(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Fulfillment>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_fulfillment_orders_param_order_fulfillments_pages_stream(
+                fulfillment_order_id,
+                None,
             ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        )
     }
     /**
-     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     * Retrieves a count of fulfillments associated with a specific order.
      *
-     * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/count.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#count-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `order_id: &str` -- order_id.
+     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject(
+    pub async fn get_orders_param_order_fulfillments_count(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::types::Count> {
+        Ok(self
+            .get_orders_param_order_fulfillments_count_with_response(
+                order_id,
+                created_at_min,
+                created_at_max,
+                updated_at_min,
+                updated_at_max,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-10
+     * Retrieves a count of fulfillments associated with a specific order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/count.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * As opposed to `get_orders_param_order_fulfillments_count`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn create_fulfillment_orders_param_order_request(
+    pub async fn get_orders_param_order_fulfillments_count_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> ClientResult<crate::Response<crate::types::Count>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
-            query_args.push((
-                "fulfillment_order_line_items".to_string(),
-                fulfillment_order_line_items.to_string(),
-            ));
+        if !created_at_max.is_empty() {
+            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+        }
+        if !created_at_min.is_empty() {
+            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+        }
+        if !updated_at_max.is_empty() {
+            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+        if !updated_at_min.is_empty() {
+            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
+                "/admin/api/orders/{}/fulfillments/count.json?{}",
+                crate::progenitor_support::encode_path(order_id),
                 query_
             ),
             None,
         );
         self.client
-            .post(
+            .get_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     * Retrieve a specific fulfillment.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
+     * * `fields: &str` -- Comma-separated list of fields to include in the response.
      */
-    pub async fn create_fulfillment_orders_param_order_request_accept(
+    pub async fn get_orders_param_order_fulfillments_fulfillment(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .get_orders_param_order_fulfillments_fulfillment_with_response(
+                order_id,
+                fulfillment_id,
+                fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillments_fulfillment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_orders_param_order_fulfillments_fulfillment_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
+                "/admin/api/orders/{}/fulfillments/{}.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
                 query_
             ),
             None,
         );
         self.client
-            .post(
+            .get_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     * Update information associated with a fulfillment.
      *
-     * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
      */
-    pub async fn create_fulfillment_orders_param_order_request_reject(
+    pub async fn update_orders_param_order_fulfillments_fulfillment(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &crate::types::FulfillmentRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .update_orders_param_order_fulfillments_fulfillment_with_response(
+                order_id,
+                fulfillment_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Update information associated with a fulfillment.
+     *
+     * This function performs a `PUT` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * As opposed to `update_orders_param_order_fulfillments_fulfillment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_orders_param_order_fulfillments_fulfillment_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &crate::types::FulfillmentRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/orders/{}/fulfillments/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
             ),
             None,
         );
         self.client
-            .post(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7527,46 +1467,32 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
      *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillments.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#createV2-2020-10
+     */
+    pub async fn create_fulfillments(
+        &self,
+        body: &crate::types::FulfillmentRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self.create_fulfillments_with_response(body).await?.body)
+    }
+    /**
+     * Creates a fulfillment for one or many fulfillment orders. The fulfillment orders are associated with the same order and are assigned to the same location.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/fulfillments.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * As opposed to `create_fulfillments`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request(
+    pub async fn create_fulfillments_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
-            query_args.push((
-                "fulfillment_order_line_items".to_string(),
-                fulfillment_order_line_items.to_string(),
-            ));
-        }
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
+        body: &crate::types::FulfillmentRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
+        let url = self.client.url("/admin/api/fulfillments.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7576,79 +1502,50 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     * Updates the tracking information for a fulfillment.
      *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#update_tracking-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `fulfillment_id: &str` -- fulfillment_id.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept(
+    pub async fn create_fulfillments_param_fulfillment_update_tracking(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
+        fulfillment_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .create_fulfillments_param_fulfillment_update_tracking_with_response(
+                fulfillment_id,
+                body,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2021-01
+     * Updates the tracking information for a fulfillment.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/fulfillments/{fulfillment_id}/update_tracking.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * As opposed to `create_fulfillments_param_fulfillment_update_tracking`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject(
+    pub async fn create_fulfillments_param_fulfillment_update_tracking_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
+        fulfillment_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/fulfillments/{}/update_tracking.json",
+                crate::progenitor_support::encode_path(fulfillment_id),
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7658,87 +1555,55 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     * Mark a fulfillment as complete.
      *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#complete-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request(
+    pub async fn create_orders_param_order_fulfillments_fulfillment_complete(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
+        order_id: &str,
+        fulfillment_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
-            query_args.push((
-                "fulfillment_order_line_items".to_string(),
-                fulfillment_order_line_items.to_string(),
-            ));
-        }
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
-            ),
-            None,
-        );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .create_orders_param_order_fulfillments_fulfillment_complete_with_response(
+                order_id,
+                fulfillment_id,
+                body,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-unstable
+     * Mark a fulfillment as complete.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json` endpoint.
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * As opposed to `create_orders_param_order_fulfillments_fulfillment_complete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
+    pub async fn create_orders_param_order_fulfillments_fulfillment_complete_with_response(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
+        order_id: &str,
+        fulfillment_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/orders/{}/fulfillments/{}/complete.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7748,38 +1613,55 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     * Mark a fulfillment as open.
      *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#open-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
+    pub async fn create_orders_param_order_fulfillments_fulfillment_open(
         &self,
-        fulfillment_order_id: &str,
-        message: &str,
+        order_id: &str,
+        fulfillment_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .create_orders_param_order_fulfillments_fulfillment_open_with_response(
+                order_id,
+                fulfillment_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Mark a fulfillment as open.
+     *
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/open.json` endpoint.
+     *
+     * As opposed to `create_orders_param_order_fulfillments_fulfillment_open`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_orders_param_order_fulfillments_fulfillment_open_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-                query_
+                "/admin/api/orders/{}/fulfillments/{}/open.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
             ),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7789,113 +1671,161 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * Get tracking numbers for orders.
+     * Cancel a fulfillment for a specific order ID.
      *
-     * This function performs a `GET` to the `/fetch_tracking_numbers` endpoint.
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#fetch-tracking-numbers
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancel-2020-10
      *
      * **Parameters:**
      *
-     * * `order_names: &str` -- The fulfillment names we require tracking numbers for (i.e. #1001.1).
-     * * `shop: &str` -- The shop's myshopify url.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
      */
-    pub async fn deprecated_unknown_version_get_fetch_tracking_number(
+    pub async fn create_orders_param_order_fulfillments_fulfillment_cancel(
         &self,
-        order_names: &str,
-        shop: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !order_names.is_empty() {
-            query_args.push(("order_names".to_string(), order_names.to_string()));
-        }
-        if !shop.is_empty() {
-            query_args.push(("shop".to_string(), shop.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self
-            .client
-            .url(&format!("/fetch_tracking_numbers?{}", query_), None);
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .create_orders_param_order_fulfillments_fulfillment_cancel_with_response(
+                order_id,
+                fulfillment_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Cancel a fulfillment for a specific order ID.
+     *
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json` endpoint.
+     *
+     * As opposed to `create_orders_param_order_fulfillments_fulfillment_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_orders_param_order_fulfillments_fulfillment_cancel_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/orders/{}/fulfillments/{}/cancel.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            None,
+        );
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * Get inventory levels.
+     * Cancels a fulfillment.
      *
-     * This function performs a `GET` to the `/fetch_stock` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillments/{fulfillment_id}/cancel.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#fetch-stock
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#cancelV2-2020-10
      *
      * **Parameters:**
      *
-     * * `max_retries: &str` -- The maximum amount of times Shopify will send the request for inventory levels.
-     * * `shop: &str` -- The shop's myshopify url.
-     * * `sku: &str` -- The SKU for the Product Variant we need stock levels for.
-     * * `timestamp: &str` -- The Unix timestamp from when the inventory request was made.
+     * * `fulfillment_id: &str` -- fulfillment_id.
      */
-    pub async fn deprecated_unknown_version_get_fetch_stock(
+    pub async fn create_fulfillments_param_fulfillment_cancel(
         &self,
-        max_retries: &str,
-        shop: &str,
-        sku: &str,
-        timestamp: &str,
-    ) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !max_retries.is_empty() {
-            query_args.push(("max_retries".to_string(), max_retries.to_string()));
-        }
-        if !shop.is_empty() {
-            query_args.push(("shop".to_string(), shop.to_string()));
-        }
-        if !sku.is_empty() {
-            query_args.push(("sku".to_string(), sku.to_string()));
-        }
-        if !timestamp.is_empty() {
-            query_args.push(("timestamp".to_string(), timestamp.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(&format!("/fetch_stock?{}", query_), None);
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::types::FulfillmentRoot> {
+        Ok(self
+            .create_fulfillments_param_fulfillment_cancel_with_response(fulfillment_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Cancels a fulfillment.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillments/{fulfillment_id}/cancel.json` endpoint.
+     *
+     * As opposed to `create_fulfillments_param_fulfillment_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_fulfillments_param_fulfillment_cancel_with_response(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentRoot>> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/fulfillments/{}/cancel.json",
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            None,
+        );
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * .
+     * Retrieves a list of fulfillment events for a specific fulfillment.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/fulfillment_services.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-2020-10
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
+     * * `fulfillment_id: &str` -- The ID of the fulfillment that's associated with the fulfillment event.
+     * * `order_id: &str` -- The ID of the order that's associated with the fulfillment event.
      */
-    pub async fn deprecated_202001_get_fulfillment_service(&self, scope: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    pub async fn get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<()> {
+        self.get_orders_param_order_fulfillments_fulfillment_event_with_response(
+            order_id,
+            fulfillment_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves a list of fulfillment events for a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillments_fulfillment_event`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_orders_param_order_fulfillments_fulfillment_event_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
-            &format!("/admin/api/2020-01/fulfillment_services.json?{}", query_),
+            &format!(
+                "/admin/api/orders/{}/fulfillments/{}/events.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7905,24 +1835,54 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-    * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
-              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
-
-              Where THE_TOKEN_GOES_HERE is replaced by the OAuth token given to you by Shopify and https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services is replaced by the authorized shop's URL.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-01/fulfillment_services.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#create-2020-01
-    */
-    pub async fn deprecated_202001_create_fulfillment_services(
+     * Creates a fulfillment event.
+     *
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#create-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
+     */
+    pub async fn create_orders_param_order_fulfillments_fulfillment_events(
         &self,
+        order_id: &str,
+        fulfillment_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-01/fulfillment_services.json", None);
+        self.create_orders_param_order_fulfillments_fulfillment_events_with_response(
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Creates a fulfillment event.
+     *
+     * This function performs a `POST` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
+     *
+     * As opposed to `create_orders_param_order_fulfillments_fulfillment_events`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_orders_param_order_fulfillments_fulfillment_events_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/orders/{}/fulfillments/{}/events.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+            ),
+            None,
+        );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -7932,29 +1892,57 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Retrieves a specific fulfillment event.
      *
-     * This function performs a `GET` to the `/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#show-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#show-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
+     * * `event_id: &str` -- event_id.
+     * * `event_id: &str` -- The ID of the fulfillment event.
      */
-    pub async fn deprecated_202001_get_fulfillment_services_param_service(
+    pub async fn get_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        fulfillment_service_id: &str,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
     ) -> ClientResult<()> {
+        self.get_orders_param_order_fulfillments_fulfillment_events_event_with_response(
+            order_id,
+            fulfillment_id,
+            event_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Retrieves a specific fulfillment event.
+     *
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillments_fulfillment_events_event`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_orders_param_order_fulfillments_fulfillment_events_event_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/orders/{}/fulfillments/{}/events/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                crate::progenitor_support::encode_path(event_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -7964,153 +1952,322 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Deletes a fulfillment event.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#update-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `order_id: &str` -- order_id.
+     * * `fulfillment_id: &str` -- fulfillment_id.
+     * * `event_id: &str` -- event_id.
      */
-    pub async fn deprecated_202001_update_fulfillment_services_param_service(
+    pub async fn delete_orders_param_order_fulfillments_fulfillment_events_event(
         &self,
-        fulfillment_service_id: &str,
-        body: &serde_json::Value,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
     ) -> ClientResult<()> {
+        self.delete_orders_param_order_fulfillments_fulfillment_events_event_with_response(
+            order_id,
+            fulfillment_id,
+            event_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Deletes a fulfillment event.
+     *
+     * This function performs a `DELETE` to the `/admin/api/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `delete_orders_param_order_fulfillments_fulfillment_events_event`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_orders_param_order_fulfillments_fulfillment_events_event_with_response(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/orders/{}/fulfillments/{}/events/{}.json",
+                crate::progenitor_support::encode_path(order_id),
+                crate::progenitor_support::encode_path(fulfillment_id),
+                crate::progenitor_support::encode_path(event_id),
             ),
             None,
         );
         self.client
-            .put(
+            .delete_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * .
+     * Retrieves a list of fulfillment orders for a specific order.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillment_orders.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#destroy-2020-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `order_id: &str` -- order_id.
+     * * `order_id: &str` -- The ID of the order that is associated with the fulfillment orders.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
      */
-    pub async fn deprecated_202001_delete_fulfillment_services_param_service(
+    pub async fn get_orders_param_order_fulfillment(
         &self,
-        fulfillment_service_id: &str,
-    ) -> ClientResult<()> {
+        order_id: &str,
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::FulfillmentOrder>> {
+        Ok(self
+            .get_orders_param_order_fulfillment_with_response(order_id, page_info)
+            .await?
+            .body)
+    }
+    /**
+     * Retrieves a list of fulfillment orders for a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `get_orders_param_order_fulfillment`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_orders_param_order_fulfillment_with_response(
+        &self,
+        order_id: &str,
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FulfillmentOrder>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/orders/{}/fulfillment_orders.json?{}",
+                crate::progenitor_support::encode_path(order_id),
+                query_
             ),
             None,
         );
-        self.client
-            .delete(
+        let resp: crate::Response<crate::types::FulfillmentOrdersRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.fulfillment_orders.to_vec()))
     }
     /**
-     * .
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/fulfillment_services.json` endpoint.
+     * Retrieves a list of fulfillment orders for a specific order.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-04
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillment_orders.json` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_orders_param_order_fulfillment`, this function returns all the pages of the request at once.
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#index-2020-10
      */
-    pub async fn deprecated_202004_get_fulfillment_service(&self, scope: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    pub async fn get_all_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> ClientResult<Vec<crate::types::FulfillmentOrder>> {
         let url = self.client.url(
-            &format!("/admin/api/2020-04/fulfillment_services.json?{}", query_),
+            &format!(
+                "/admin/api/orders/{}/fulfillment_orders.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
             None,
         );
-        self.client
-            .get(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::FulfillmentOrdersRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut fulfillment_orders = resp.fulfillment_orders;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::FulfillmentOrdersRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            fulfillment_orders.append(&mut resp.fulfillment_orders);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(fulfillment_orders)
     }
     /**
-    * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
-              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
+     * Retrieves a list of fulfillment orders for a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `get_all_orders_param_order_fulfillment`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_orders_param_order_fulfillment_pages_stream(
+        &self,
+        order_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::FulfillmentOrder>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/orders/{}/fulfillment_orders.json",
+                crate::progenitor_support::encode_path(order_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
 
-              Where THE_TOKEN_GOES_HERE is replaced by the OAuth token given to you by Shopify and https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services is replaced by the authorized shop's URL.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-04/fulfillment_services.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#create-2020-04
-    */
-    pub async fn deprecated_202004_create_fulfillment_services(
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::FulfillmentOrdersRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.fulfillment_orders;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Retrieves a list of fulfillment orders for a specific order.
+     *
+     * This function performs a `GET` to the `/admin/api/orders/{order_id}/fulfillment_orders.json` endpoint.
+     *
+     * As opposed to `get_all_orders_param_order_fulfillment`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_orders_param_order_fulfillment_stream(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-04/fulfillment_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        order_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FulfillmentOrder>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_orders_param_order_fulfillment_pages_stream(order_id, None),
+        )
+    }
+    /**
+     * Retrieves a specific fulfillment order.
+     *
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#show-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
+     */
+    pub async fn get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .get_fulfillment_orders_param_order_with_response(fulfillment_order_id)
+            .await?
+            .body)
     }
     /**
-     * .
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#show-2020-04
+     * Retrieves a specific fulfillment order.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}.json` endpoint.
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * As opposed to `get_fulfillment_orders_param_order`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_get_fulfillment_services_param_service(
+    pub async fn get_fulfillment_orders_param_order_with_response(
         &self,
-        fulfillment_service_id: &str,
-    ) -> ClientResult<()> {
+        fulfillment_order_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/fulfillment_orders/{}.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8120,30 +2277,47 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Marks a fulfillment order as cancelled.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#update-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#cancel-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
+     */
+    pub async fn create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::types::FulfillmentOrderCancellation> {
+        Ok(self
+            .create_fulfillment_orders_param_order_cancel_with_response(fulfillment_order_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Marks a fulfillment order as cancelled.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/cancel.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_update_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_param_order_cancel_with_response(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_order_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderCancellation>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/fulfillment_orders/{}/cancel.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
         self.client
-            .put(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8153,153 +2327,151 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
+     * is unable to ship any remaining items and intends to close the fulfillment order.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#destroy-2020-04
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#close-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
+     */
+    pub async fn create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentOrderRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_close_with_response(fulfillment_order_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/close.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_close`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202004_delete_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_param_order_close_with_response(
         &self,
-        fulfillment_service_id: &str,
-    ) -> ClientResult<()> {
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentOrderRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-04/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/fulfillment_orders/{}/close.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * .
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
-     * This function performs a `GET` to the `/admin/api/2020-07/fulfillment_services.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#move-2020-10
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_202007_get_fulfillment_service(&self, scope: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2020-07/fulfillment_services.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
-              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
-
-              Where THE_TOKEN_GOES_HERE is replaced by the OAuth token given to you by Shopify and https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services is replaced by the authorized shop's URL.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/fulfillment_services.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#create-2020-07
-    */
-    pub async fn deprecated_202007_create_fulfillment_services(
+    pub async fn create_fulfillment_orders_param_order_move(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-07/fulfillment_services.json", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
-                },
-            )
-            .await
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentOrderRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderMove> {
+        Ok(self
+            .create_fulfillment_orders_param_order_move_with_response(fulfillment_order_id, body)
+            .await?
+            .body)
     }
     /**
-     * .
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#show-2020-07
+     * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/move.json` endpoint.
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * As opposed to `create_fulfillment_orders_param_order_move`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_202007_get_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_param_order_move_with_response(
         &self,
-        fulfillment_service_id: &str,
-    ) -> ClientResult<()> {
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentOrderRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderMove>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/fulfillment_orders/{}/move.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * .
+     * Marks a scheduled fulfillment order as ready for fulfillment.
+     * This endpoint allows merchants to work on a scheduled fulfillment order before its expected fulfill_at datetime.
      *
-     * This function performs a `PUT` to the `/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/open.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#update-2020-07
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#open-2021-01
+     *
+     * The spec only describes this endpoint for API versions `2021-01`, `unstable`.
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_202007_update_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_param_order_open(
         &self,
-        fulfillment_service_id: &str,
+        fulfillment_order_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_open_with_response(fulfillment_order_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Marks a scheduled fulfillment order as ready for fulfillment.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/open.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_open`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_fulfillment_orders_param_order_open_with_response(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/fulfillment_orders/{}/open.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
         self.client
-            .put(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8309,85 +2481,53 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Updates the fulfill_at time of a scheduled fulfillment order.
+     * This endpoint is used to manage the time a scheduled fulfillment order will be marked as ready for fulfillment.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/reschedule.json` endpoint.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#reschedule-2021-01
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#destroy-2020-07
+     * The spec only describes this endpoint for API versions `2021-01`, `unstable`.
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_202007_delete_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_param_order_reschedule(
         &self,
-        fulfillment_service_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-07/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentOrderRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_reschedule_with_response(
+                fulfillment_order_id,
+                body,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-     * .
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/fulfillment_services.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-10
+     * Updates the fulfill_at time of a scheduled fulfillment order.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/reschedule.json` endpoint.
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * As opposed to `create_fulfillment_orders_param_order_reschedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_fulfillment_service(&self, scope: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+    pub async fn create_fulfillment_orders_param_order_reschedule_with_response(
+        &self,
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentOrderRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
-            &format!("/admin/api/2020-10/fulfillment_services.json?{}", query_),
+            &format!(
+                "/admin/api/fulfillment_orders/{}/reschedule.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
             None,
         );
         self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
-    }
-    /**
-    * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
-              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
-
-              Where THE_TOKEN_GOES_HERE is replaced by the OAuth token given to you by Shopify and https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services is replaced by the authorized shop's URL.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-10/fulfillment_services.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#create-2020-10
-    */
-    pub async fn create_fulfillment_services(&self, body: &serde_json::Value) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2020-10/fulfillment_services.json", None);
-        self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8397,62 +2537,51 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Releases the fulfillment order holds for a specific order. Fulfillment orders are created
+     * with an ON_HOLD status if the channel that created the order has a fulfillment hold policy.
      *
-     * This function performs a `GET` to the `/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/release_hold.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#show-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentorder#release_hold-unstable
+     *
+     * The spec only describes this endpoint for API version `unstable`.
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `order_id: &str` -- The ID of the order that is associated to the fulfillment orders.
      */
-    pub async fn get_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_release_hold(
         &self,
-        fulfillment_service_id: &str,
+        order_id: &str,
+        body: &serde_json::Value,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.create_fulfillment_orders_release_hold_with_response(order_id, body)
+            .await?;
+        Ok(())
     }
     /**
-     * .
-     *
-     * This function performs a `PUT` to the `/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#update-2020-10
+     * Releases the fulfillment order holds for a specific order. Fulfillment orders are created.
      *
-     * **Parameters:**
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/release_hold.json` endpoint.
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * As opposed to `create_fulfillment_orders_release_hold`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn update_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_release_hold_with_response(
         &self,
-        fulfillment_service_id: &str,
+        order_id: &str,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !order_id.is_empty() {
+            query_args.push(("order_id".to_string(), order_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!(
-                "/admin/api/2020-10/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
-            ),
+            &format!("/admin/api/fulfillment_orders/release_hold.json?{}", query_),
             None,
         );
         self.client
-            .put(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8462,88 +2591,100 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
-     * This function performs a `DELETE` to the `/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#destroy-2020-10
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#create-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn delete_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_param_order_request(
         &self,
-        fulfillment_service_id: &str,
-    ) -> ClientResult<()> {
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderSubmission> {
+        Ok(self
+            .create_fulfillment_orders_param_order_request_with_response(fulfillment_order_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Sends a fulfillment request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_fulfillment_orders_param_order_request_with_response(
+        &self,
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderSubmission>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/fulfillment_orders/{}/fulfillment_request.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
         self.client
-            .delete(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * .
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/fulfillment_services.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#accept-2020-10
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_202101_get_fulfillment_service(&self, scope: &str) -> ClientResult<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = self.client.url(
-            &format!("/admin/api/2021-01/fulfillment_services.json?{}", query_),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
+    pub async fn create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_request_accept_with_response(
+                fulfillment_order_id,
+                body,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-    * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
-              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
-
-              Where THE_TOKEN_GOES_HERE is replaced by the OAuth token given to you by Shopify and https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services is replaced by the authorized shop's URL.
-    *
-    * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_services.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#create-2021-01
-    */
-    pub async fn deprecated_202101_create_fulfillment_services(
+     * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_request_accept`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_fulfillment_orders_param_order_request_accept_with_response(
         &self,
-        body: &serde_json::Value,
-    ) -> ClientResult<()> {
-        let url = self
-            .client
-            .url("/admin/api/2021-01/fulfillment_services.json", None);
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
+        let url = self.client.url(
+            &format!(
+                "/admin/api/fulfillment_orders/{}/fulfillment_request/accept.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
+            ),
+            None,
+        );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8553,94 +2694,168 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-     * .
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
-     * This function performs a `GET` to the `/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#show-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
      */
-    pub async fn deprecated_202101_get_fulfillment_services_param_service(
+    pub async fn create_fulfillment_orders_param_order_request_reject(
         &self,
-        fulfillment_service_id: &str,
-    ) -> ClientResult<()> {
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceRequestRoot,
+    ) -> ClientResult<crate::types::FulfillmentOrderRoot> {
+        Ok(self
+            .create_fulfillment_orders_param_order_request_reject_with_response(
+                fulfillment_order_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_orders_param_order_request_reject`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_fulfillment_orders_param_order_request_reject_with_response(
+        &self,
+        fulfillment_order_id: &str,
+        body: &crate::types::FulfillmentServiceRequestRoot,
+    ) -> ClientResult<crate::Response<crate::types::FulfillmentOrderRoot>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
+                "/admin/api/fulfillment_orders/{}/fulfillment_request/reject.json",
+                crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
         self.client
-            .get(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
-                    content_type: None,
+                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
+                    content_type: Some("application/json".to_string()),
                 },
             )
             .await
     }
     /**
-     * .
+     * Get tracking numbers for orders.
      *
-     * This function performs a `PUT` to the `/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `GET` to the `/fetch_tracking_numbers` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#update-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#fetch-tracking-numbers
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `order_names: &str` -- The fulfillment names we require tracking numbers for (i.e. #1001.1).
+     * * `shop: &str` -- The shop's myshopify url.
      */
-    pub async fn deprecated_202101_update_fulfillment_services_param_service(
+    pub async fn get_fetch_tracking_number(
         &self,
-        fulfillment_service_id: &str,
-        body: &serde_json::Value,
+        order_names: &str,
+        shop: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
-            ),
-            None,
-        );
+        self.get_fetch_tracking_number_with_response(order_names, shop)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Get tracking numbers for orders.
+     *
+     * This function performs a `GET` to the `/fetch_tracking_numbers` endpoint.
+     *
+     * As opposed to `get_fetch_tracking_number`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_fetch_tracking_number_with_response(
+        &self,
+        order_names: &str,
+        shop: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !order_names.is_empty() {
+            query_args.push(("order_names".to_string(), order_names.to_string()));
+        }
+        if !shop.is_empty() {
+            query_args.push(("shop".to_string(), shop.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/fetch_tracking_numbers?{}", query_), None);
         self.client
-            .put(
+            .get_with_response(
                 &url,
                 crate::Message {
-                    body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
-                    content_type: Some("application/json".to_string()),
+                    body: None,
+                    content_type: None,
                 },
             )
             .await
     }
     /**
-     * .
+     * Get inventory levels.
      *
-     * This function performs a `DELETE` to the `/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `GET` to the `/fetch_stock` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#destroy-2021-01
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#fetch-stock
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `max_retries: &str` -- The maximum amount of times Shopify will send the request for inventory levels.
+     * * `shop: &str` -- The shop's myshopify url.
+     * * `sku: &str` -- The SKU for the Product Variant we need stock levels for.
+     * * `timestamp: &str` -- The Unix timestamp from when the inventory request was made.
      */
-    pub async fn deprecated_202101_delete_fulfillment_services_param_service(
+    pub async fn get_fetch_stock(
         &self,
-        fulfillment_service_id: &str,
+        max_retries: &str,
+        shop: &str,
+        sku: &str,
+        timestamp: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2021-01/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
-            ),
-            None,
-        );
+        self.get_fetch_stock_with_response(max_retries, shop, sku, timestamp)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Get inventory levels.
+     *
+     * This function performs a `GET` to the `/fetch_stock` endpoint.
+     *
+     * As opposed to `get_fetch_stock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_fetch_stock_with_response(
+        &self,
+        max_retries: &str,
+        shop: &str,
+        sku: &str,
+        timestamp: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !max_retries.is_empty() {
+            query_args.push(("max_retries".to_string(), max_retries.to_string()));
+        }
+        if !shop.is_empty() {
+            query_args.push(("shop".to_string(), shop.to_string()));
+        }
+        if !sku.is_empty() {
+            query_args.push(("sku".to_string(), sku.to_string()));
+        }
+        if !timestamp.is_empty() {
+            query_args.push(("timestamp".to_string(), timestamp.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/fetch_stock?{}", query_), None);
         self.client
-            .delete(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8652,30 +2867,39 @@ impl ShippingAndFulfillment {
     /**
      * .
      *
-     * This function performs a `GET` to the `/admin/api/unstable/fulfillment_services.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/fulfillment_services.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#index-2020-10
      *
      * **Parameters:**
      *
      * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     *   all: Returns all the fulfillment providers.
+     */
+    pub async fn get_fulfillment_service(&self, scope: &str) -> ClientResult<()> {
+        self.get_fulfillment_service_with_response(scope).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `GET` to the `/admin/api/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `get_fulfillment_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn deprecated_unstable_get_fulfillment_service(
+    pub async fn get_fulfillment_service_with_response(
         &self,
         scope: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !scope.is_empty() {
             query_args.push(("scope".to_string(), scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/admin/api/unstable/fulfillment_services.json?{}", query_),
+            &format!("/admin/api/fulfillment_services.json?{}", query_),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8685,24 +2909,35 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-    * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
-              Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
-
-              Where THE_TOKEN_GOES_HERE is replaced by the OAuth token given to you by Shopify and https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services is replaced by the authorized shop's URL.
-    *
-    * This function performs a `POST` to the `/admin/api/unstable/fulfillment_services.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#create-unstable
-    */
-    pub async fn deprecated_unstable_create_fulfillment_services(
+     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
+     * Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
+     *
+     * Where THE_TOKEN_GOES_HERE is replaced by the OAuth token given to you by Shopify and https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services is replaced by the authorized shop's URL.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_services.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#create-2020-10
+     */
+    pub async fn create_fulfillment_services(&self, body: &serde_json::Value) -> ClientResult<()> {
+        self.create_fulfillment_services_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:.
+     *
+     * This function performs a `POST` to the `/admin/api/fulfillment_services.json` endpoint.
+     *
+     * As opposed to `create_fulfillment_services`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_fulfillment_services_with_response(
         &self,
         body: &serde_json::Value,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<crate::Response<()>> {
         let url = self
             .client
-            .url("/admin/api/unstable/fulfillment_services.json", None);
+            .url("/admin/api/fulfillment_services.json", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8714,27 +2949,40 @@ impl ShippingAndFulfillment {
     /**
      * .
      *
-     * This function performs a `GET` to the `/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `GET` to the `/admin/api/fulfillment_services/{fulfillment_service_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#show-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#show-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_service_id: &str` -- fulfillment_service_id.
      */
-    pub async fn deprecated_unstable_get_fulfillment_services_param_service(
+    pub async fn get_fulfillment_services_param_service(
         &self,
         fulfillment_service_id: &str,
     ) -> ClientResult<()> {
+        self.get_fulfillment_services_param_service_with_response(fulfillment_service_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `GET` to the `/admin/api/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `get_fulfillment_services_param_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_fulfillment_services_param_service_with_response(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/fulfillment_services/{}/json",
+                "/admin/api/fulfillment_services/{}.json",
                 crate::progenitor_support::encode_path(fulfillment_service_id),
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8746,28 +2994,42 @@ impl ShippingAndFulfillment {
     /**
      * .
      *
-     * This function performs a `PUT` to the `/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `PUT` to the `/admin/api/fulfillment_services/{fulfillment_service_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#update-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#update-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_service_id: &str` -- fulfillment_service_id.
      */
-    pub async fn deprecated_unstable_update_fulfillment_services_param_service(
+    pub async fn update_fulfillment_services_param_service(
         &self,
         fulfillment_service_id: &str,
         body: &serde_json::Value,
     ) -> ClientResult<()> {
+        self.update_fulfillment_services_param_service_with_response(fulfillment_service_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `PUT` to the `/admin/api/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `update_fulfillment_services_param_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_fulfillment_services_param_service_with_response(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/unstable/fulfillment_services/{}/json",
+                "/admin/api/fulfillment_services/{}.json",
                 crate::progenitor_support::encode_path(fulfillment_service_id),
             ),
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -8779,61 +3041,40 @@ impl ShippingAndFulfillment {
     /**
      * .
      *
-     * This function performs a `DELETE` to the `/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/fulfillment_services/{fulfillment_service_id}.json` endpoint.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#destroy-unstable
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentservice#destroy-2020-10
      *
      * **Parameters:**
      *
-     * * `fulfillment_service_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_service_id: &str` -- fulfillment_service_id.
      */
-    pub async fn deprecated_unstable_delete_fulfillment_services_param_service(
+    pub async fn delete_fulfillment_services_param_service(
         &self,
         fulfillment_service_id: &str,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_services/{}/json",
-                crate::progenitor_support::encode_path(fulfillment_service_id),
-            ),
-            None,
-        );
-        self.client
-            .delete(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        self.delete_fulfillment_services_param_service_with_response(fulfillment_service_id)
+            .await?;
+        Ok(())
     }
     /**
-    * Retrieves a list of locations that a fulfillment order can potentially move to.
-              The resulting list is sorted alphabetically in ascending order by location name.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-2020-01
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `fulfillment_order_id: &str` -- The ID of the fulfillment order.
-    */
-    pub async fn deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
+     * This function performs a `DELETE` to the `/admin/api/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `delete_fulfillment_services_param_service`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_fulfillment_services_param_service_with_response(
         &self,
-        fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
+        fulfillment_service_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-01/fulfillment_orders/{}/locations_for_move.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
+                "/admin/api/fulfillment_services/{}.json",
+                crate::progenitor_support::encode_path(fulfillment_service_id),
             ),
             None,
         );
         self.client
-            .get(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -8843,173 +3084,230 @@ impl ShippingAndFulfillment {
             .await
     }
     /**
-    * Retrieves a list of locations that a fulfillment order can potentially move to.
-              The resulting list is sorted alphabetically in ascending order by location name.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-2020-04
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `fulfillment_order_id: &str` -- The ID of the fulfillment order.
-    */
-    pub async fn deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
+     * Retrieves a list of locations that a fulfillment order can potentially move to.
+     * The resulting list is sorted alphabetically in ascending order by location name.
+     *
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `fulfillment_order_id: &str` -- fulfillment_order_id.
+     * * `fulfillment_order_id: &str` -- The ID of the fulfillment order.
+     * * `page_info: &str` -- The cursor of the page to get, from the `Link` header of the previous page.
+     */
+    pub async fn get_fulfillment_orders_param_order_locations_for_move(
         &self,
         fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/2020-04/fulfillment_orders/{}/locations_for_move.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
-            ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
+        page_info: &str,
+    ) -> ClientResult<Vec<crate::types::MoveDestination>> {
+        Ok(self
+            .get_fulfillment_orders_param_order_locations_for_move_with_response(
+                fulfillment_order_id,
+                page_info,
             )
-            .await
+            .await?
+            .body)
     }
     /**
-    * Retrieves a list of locations that a fulfillment order can potentially move to.
-              The resulting list is sorted alphabetically in ascending order by location name.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-2020-07
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `fulfillment_order_id: &str` -- The ID of the fulfillment order.
-    */
-    pub async fn deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
+     * Retrieves a list of locations that a fulfillment order can potentially move to.
+     *
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
+     *
+     * As opposed to `get_fulfillment_orders_param_order_locations_for_move`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_fulfillment_orders_param_order_locations_for_move_with_response(
         &self,
         fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
+        page_info: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::MoveDestination>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !page_info.is_empty() {
+            query_args.push(("page_info".to_string(), page_info.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-07/fulfillment_orders/{}/locations_for_move.json",
+                "/admin/api/fulfillment_orders/{}/locations_for_move.json?{}",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
+                query_
             ),
             None,
         );
-        self.client
-            .get(
+        let resp: crate::Response<crate::types::MoveDestinationsRoot> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        // Return our response data.
+        Ok(resp.map(|r| r.locations_for_move.to_vec()))
     }
     /**
-    * Retrieves a list of locations that a fulfillment order can potentially move to.
-              The resulting list is sorted alphabetically in ascending order by location name.
-    *
-    * This function performs a `GET` to the `/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-2020-10
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `fulfillment_order_id: &str` -- The ID of the fulfillment order.
-    */
-    pub async fn get_fulfillment_orders_param_order_locations_for_move(
+     * Retrieves a list of locations that a fulfillment order can potentially move to.
+     * The resulting list is sorted alphabetically in ascending order by location name.
+     *
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
+     *
+     * As opposed to `get_fulfillment_orders_param_order_locations_for_move`, this function returns all the pages of the request at once.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-2020-10
+     */
+    pub async fn get_all_fulfillment_orders_param_order_locations_for_move(
         &self,
         fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
+    ) -> ClientResult<Vec<crate::types::MoveDestination>> {
         let url = self.client.url(
             &format!(
-                "/admin/api/2020-10/fulfillment_orders/{}/locations_for_move.json",
+                "/admin/api/fulfillment_orders/{}/locations_for_move.json",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
-        self.client
-            .get(
+        let url = format!(
+            "{}{}limit=250",
+            url,
+            if url.contains('?') { '&' } else { '?' }
+        );
+        let (mut link, resp): (
+            Option<crate::utils::NextLink>,
+            crate::types::MoveDestinationsRoot,
+        ) = self
+            .client
+            .request_with_links(
+                reqwest::Method::GET,
                 &url,
                 crate::Message {
                     body: None,
                     content_type: None,
                 },
             )
-            .await
+            .await?;
+
+        let mut locations_for_move = resp.locations_for_move;
+
+        // Follow the `page_info` cursors in the `Link` header.
+        while let Some(next) = link {
+            let (next_link, mut resp): (
+                Option<crate::utils::NextLink>,
+                crate::types::MoveDestinationsRoot,
+            ) = self
+                .client
+                .request_with_links(
+                    reqwest::Method::GET,
+                    &next.0,
+                    crate::Message {
+                        body: None,
+                        content_type: None,
+                    },
+                )
+                .await?;
+
+            locations_for_move.append(&mut resp.locations_for_move);
+
+            link = next_link.filter(|l| l.0 != next.0);
+        }
+
+        // Return our response data.
+        Ok(locations_for_move)
     }
     /**
-    * Retrieves a list of locations that a fulfillment order can potentially move to.
-              The resulting list is sorted alphabetically in ascending order by location name.
-    *
-    * This function performs a `GET` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-2021-01
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `fulfillment_order_id: &str` -- The ID of the fulfillment order.
-    */
-    pub async fn deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
+     * Retrieves a list of locations that a fulfillment order can potentially move to.
+     * The resulting list is sorted alphabetically in ascending order by location name.
+     *
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
+     *
+     * As opposed to `get_all_fulfillment_orders_param_order_locations_for_move`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_fulfillment_orders_param_order_locations_for_move_pages_stream(
         &self,
         fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::MoveDestination>>>
+           + Unpin
+           + '_ {
         let url = self.client.url(
             &format!(
-                "/admin/api/2021-01/fulfillment_orders/{}/locations_for_move.json",
+                "/admin/api/fulfillment_orders/{}/locations_for_move.json",
                 crate::progenitor_support::encode_path(fulfillment_order_id),
             ),
             None,
         );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => c.to_string(),
+                        None => format!(
+                            "{}{}limit=250",
+                            url,
+                            if url.contains('?') { '&' } else { '?' }
+                        ),
+                    };
+                    let (link, resp): (
+                        Option<crate::utils::NextLink>,
+                        crate::types::MoveDestinationsRoot,
+                    ) = self
+                        .client
+                        .request_with_links(
+                            reqwest::Method::GET,
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    // The cursor is the `Link` to the next page, with its `page_info`.
+                    let next = link.map(|l| l.0);
+                    let items = resp.locations_for_move;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-    * Retrieves a list of locations that a fulfillment order can potentially move to.
-              The resulting list is sorted alphabetically in ascending order by location name.
-    *
-    * This function performs a `GET` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/locationsformove#index-unstable
-    *
-    * **Parameters:**
-    *
-    * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `fulfillment_order_id: &str` -- The ID of the fulfillment order.
-    */
-    pub async fn deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
+     * Retrieves a list of locations that a fulfillment order can potentially move to.
+     * The resulting list is sorted alphabetically in ascending order by location name.
+     *
+     * This function performs a `GET` to the `/admin/api/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json` endpoint.
+     *
+     * As opposed to `get_all_fulfillment_orders_param_order_locations_for_move`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_fulfillment_orders_param_order_locations_for_move_stream(
         &self,
         fulfillment_order_id: &str,
-    ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/admin/api/unstable/fulfillment_orders/{}/locations_for_move.json",
-                crate::progenitor_support::encode_path(fulfillment_order_id),
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::MoveDestination>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_fulfillment_orders_param_order_locations_for_move_pages_stream(
+                fulfillment_order_id,
+                None,
             ),
-            None,
-        );
-        self.client
-            .get(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: None,
-                },
-            )
-            .await
+        )
     }
 }
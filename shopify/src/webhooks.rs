@@ -0,0 +1,736 @@
+//! Verify and parse the webhooks Shopify delivers to your app.
+//!
+//! ```ignore
+//! let delivery = shopify::webhooks::verify_and_parse(&client_secret, &headers, &body)?;
+//! match delivery.event {
+//!     shopify::webhooks::WebhookEvent::Order(order) => {
+//!         println!("{} {} on {}", delivery.topic, order.name, delivery.shop_domain);
+//!     }
+//!     shopify::webhooks::WebhookEvent::CustomersRedact(request) => {
+//!         println!("erase customer {} from {}", request.customer.id, request.shop_domain);
+//!     }
+//!     _ => {}
+//! }
+//! ```
+use base64::Engine;
+use ring::hmac;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientError, ClientResult};
+
+/// The header carrying the base64 encoded HMAC-SHA256 of the payload.
+pub const HMAC_HEADER: &str = "X-Shopify-Hmac-Sha256";
+/// The header carrying the topic of the delivery, ie. `orders/create`.
+pub const TOPIC_HEADER: &str = "X-Shopify-Topic";
+/// The header carrying the `*.myshopify.com` domain of the shop.
+pub const SHOP_DOMAIN_HEADER: &str = "X-Shopify-Shop-Domain";
+/// The header carrying the API version the payload was serialized with.
+pub const API_VERSION_HEADER: &str = "X-Shopify-API-Version";
+/// The header carrying the unique ID of the delivery, the same across retries.
+pub const WEBHOOK_ID_HEADER: &str = "X-Shopify-Webhook-Id";
+
+/// Verify the signature Shopify sent for a webhook payload.
+///
+/// `signature` is the value of the `X-Shopify-Hmac-Sha256` header, the base64
+/// encoded HMAC-SHA256 of the body keyed with the app's client secret. `body`
+/// must be the raw request body, before any parsing. The comparison is done in
+/// constant time.
+pub fn verify_signature(secret: &str, signature: &str, body: &[u8]) -> ClientResult<()> {
+    let tag = base64::engine::general_purpose::STANDARD
+        .decode(signature.trim())
+        .map_err(|_| {
+            ClientError::InvalidWebhookSignature("signature is not valid base64".to_string())
+        })?;
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    hmac::verify(&key, body, &tag).map_err(|_| {
+        ClientError::InvalidWebhookSignature("signature does not match payload".to_string())
+    })
+}
+
+/// Parse a webhook payload into the type for its topic.
+///
+/// `orders/*` topics carry an `Order`, `products/*` a `Product` and so on, the
+/// `*/delete` topics only send the ID of what was deleted. Topics this module
+/// does not model are returned as `WebhookEvent::Unknown`.
+pub fn parse_event(topic: &WebhookTopic, body: &[u8]) -> ClientResult<WebhookEvent> {
+    Ok(match topic {
+        WebhookTopic::OrdersCreate
+        | WebhookTopic::OrdersUpdated
+        | WebhookTopic::OrdersPaid
+        | WebhookTopic::OrdersCancelled
+        | WebhookTopic::OrdersFulfilled
+        | WebhookTopic::OrdersPartiallyFulfilled => {
+            WebhookEvent::Order(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::ProductsCreate | WebhookTopic::ProductsUpdate => {
+            WebhookEvent::Product(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::CustomersCreate
+        | WebhookTopic::CustomersUpdate
+        | WebhookTopic::CustomersEnable
+        | WebhookTopic::CustomersDisable => WebhookEvent::Customer(serde_json::from_slice(body)?),
+        WebhookTopic::CollectionsCreate | WebhookTopic::CollectionsUpdate => {
+            WebhookEvent::Collection(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::FulfillmentsCreate | WebhookTopic::FulfillmentsUpdate => {
+            WebhookEvent::Fulfillment(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::InventoryLevelsConnect
+        | WebhookTopic::InventoryLevelsUpdate
+        | WebhookTopic::InventoryLevelsDisconnect => {
+            WebhookEvent::InventoryLevel(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::OrdersDelete
+        | WebhookTopic::ProductsDelete
+        | WebhookTopic::CustomersDelete
+        | WebhookTopic::CollectionsDelete => WebhookEvent::Deleted(serde_json::from_slice(body)?),
+        WebhookTopic::AppUninstalled | WebhookTopic::ShopUpdate => {
+            WebhookEvent::Shop(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::CustomersRedact => {
+            WebhookEvent::CustomersRedact(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::ShopRedact => WebhookEvent::ShopRedact(serde_json::from_slice(body)?),
+        WebhookTopic::CustomersDataRequest => {
+            WebhookEvent::CustomersDataRequest(serde_json::from_slice(body)?)
+        }
+        WebhookTopic::Unknown(_) => WebhookEvent::Unknown(serde_json::from_slice(body)?),
+    })
+}
+
+/// Verify the signature of a webhook delivery and parse it into the type for its topic.
+///
+/// `secret` is the client secret of the app. The signature is read from
+/// `X-Shopify-Hmac-Sha256` and the topic from `X-Shopify-Topic`.
+pub fn verify_and_parse(
+    secret: &str,
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> ClientResult<WebhookDelivery> {
+    let signature = header(headers, HMAC_HEADER).ok_or_else(|| {
+        ClientError::InvalidWebhookSignature("missing signature header".to_string())
+    })?;
+    verify_signature(secret, &signature, body)?;
+
+    let topic: WebhookTopic = header(headers, TOPIC_HEADER)
+        .ok_or_else(|| {
+            ClientError::InvalidWebhookPayload(format!("missing {} header", TOPIC_HEADER))
+        })?
+        .into();
+    let event = parse_event(&topic, body)?;
+
+    Ok(WebhookDelivery {
+        topic,
+        shop_domain: header(headers, SHOP_DOMAIN_HEADER).unwrap_or_default(),
+        api_version: header(headers, API_VERSION_HEADER).unwrap_or_default(),
+        webhook_id: header(headers, WEBHOOK_ID_HEADER).unwrap_or_default(),
+        event,
+    })
+}
+
+fn header(headers: &http::HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
+/// A verified webhook delivery, with the details Shopify sends in its headers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDelivery {
+    pub topic: WebhookTopic,
+    /// The shop the delivery is for, ie. `my-shop.myshopify.com`.
+    pub shop_domain: String,
+    pub api_version: String,
+    /// Use this to skip deliveries you have already handled, Shopify retries
+    /// a delivery until it gets a `2xx` back.
+    pub webhook_id: String,
+    pub event: WebhookEvent,
+}
+
+/// A webhook payload, keyed by the resource its topic is about.
+///
+/// Match on the `topic` of the delivery for the action, e.g. `orders/paid` or
+/// `orders/cancelled`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    Order(crate::types::Order),
+    Product(crate::types::Product),
+    Customer(crate::types::Customer),
+    Collection(crate::types::Collection),
+    Fulfillment(crate::types::Fulfillment),
+    InventoryLevel(crate::types::InventoryLevel),
+    /// The resource of a `*/delete` topic.
+    Deleted(DeletedResource),
+    Shop(Shop),
+    CustomersRedact(CustomersRedact),
+    ShopRedact(ShopRedact),
+    CustomersDataRequest(CustomersDataRequest),
+    /// The payload of a topic this module does not have a type for.
+    Unknown(serde_json::Value),
+}
+
+/**
+* The topic of a webhook delivery, as sent in `X-Shopify-Topic`.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum WebhookTopic {
+    #[serde(rename = "app/uninstalled")]
+    AppUninstalled,
+    #[serde(rename = "collections/create")]
+    CollectionsCreate,
+    #[serde(rename = "collections/delete")]
+    CollectionsDelete,
+    #[serde(rename = "collections/update")]
+    CollectionsUpdate,
+    #[serde(rename = "customers/create")]
+    CustomersCreate,
+    #[serde(rename = "customers/data_request")]
+    CustomersDataRequest,
+    #[serde(rename = "customers/delete")]
+    CustomersDelete,
+    #[serde(rename = "customers/disable")]
+    CustomersDisable,
+    #[serde(rename = "customers/enable")]
+    CustomersEnable,
+    #[serde(rename = "customers/redact")]
+    CustomersRedact,
+    #[serde(rename = "customers/update")]
+    CustomersUpdate,
+    #[serde(rename = "fulfillments/create")]
+    FulfillmentsCreate,
+    #[serde(rename = "fulfillments/update")]
+    FulfillmentsUpdate,
+    #[serde(rename = "inventory_levels/connect")]
+    InventoryLevelsConnect,
+    #[serde(rename = "inventory_levels/disconnect")]
+    InventoryLevelsDisconnect,
+    #[serde(rename = "inventory_levels/update")]
+    InventoryLevelsUpdate,
+    #[serde(rename = "orders/cancelled")]
+    OrdersCancelled,
+    #[serde(rename = "orders/create")]
+    OrdersCreate,
+    #[serde(rename = "orders/delete")]
+    OrdersDelete,
+    #[serde(rename = "orders/fulfilled")]
+    OrdersFulfilled,
+    #[serde(rename = "orders/paid")]
+    OrdersPaid,
+    #[serde(rename = "orders/partially_fulfilled")]
+    OrdersPartiallyFulfilled,
+    #[serde(rename = "orders/updated")]
+    OrdersUpdated,
+    #[serde(rename = "products/create")]
+    ProductsCreate,
+    #[serde(rename = "products/delete")]
+    ProductsDelete,
+    #[serde(rename = "products/update")]
+    ProductsUpdate,
+    #[serde(rename = "shop/redact")]
+    ShopRedact,
+    #[serde(rename = "shop/update")]
+    ShopUpdate,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for WebhookTopic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookTopic::AppUninstalled => "app/uninstalled",
+            WebhookTopic::CollectionsCreate => "collections/create",
+            WebhookTopic::CollectionsDelete => "collections/delete",
+            WebhookTopic::CollectionsUpdate => "collections/update",
+            WebhookTopic::CustomersCreate => "customers/create",
+            WebhookTopic::CustomersDataRequest => "customers/data_request",
+            WebhookTopic::CustomersDelete => "customers/delete",
+            WebhookTopic::CustomersDisable => "customers/disable",
+            WebhookTopic::CustomersEnable => "customers/enable",
+            WebhookTopic::CustomersRedact => "customers/redact",
+            WebhookTopic::CustomersUpdate => "customers/update",
+            WebhookTopic::FulfillmentsCreate => "fulfillments/create",
+            WebhookTopic::FulfillmentsUpdate => "fulfillments/update",
+            WebhookTopic::InventoryLevelsConnect => "inventory_levels/connect",
+            WebhookTopic::InventoryLevelsDisconnect => "inventory_levels/disconnect",
+            WebhookTopic::InventoryLevelsUpdate => "inventory_levels/update",
+            WebhookTopic::OrdersCancelled => "orders/cancelled",
+            WebhookTopic::OrdersCreate => "orders/create",
+            WebhookTopic::OrdersDelete => "orders/delete",
+            WebhookTopic::OrdersFulfilled => "orders/fulfilled",
+            WebhookTopic::OrdersPaid => "orders/paid",
+            WebhookTopic::OrdersPartiallyFulfilled => "orders/partially_fulfilled",
+            WebhookTopic::OrdersUpdated => "orders/updated",
+            WebhookTopic::ProductsCreate => "products/create",
+            WebhookTopic::ProductsDelete => "products/delete",
+            WebhookTopic::ProductsUpdate => "products/update",
+            WebhookTopic::ShopRedact => "shop/redact",
+            WebhookTopic::ShopUpdate => "shop/update",
+            WebhookTopic::Unknown(s) => s.as_str(),
+        }
+        .fmt(f)
+    }
+}
+
+impl std::convert::From<String> for WebhookTopic {
+    fn from(s: String) -> WebhookTopic {
+        match s.as_str() {
+            "app/uninstalled" => WebhookTopic::AppUninstalled,
+            "collections/create" => WebhookTopic::CollectionsCreate,
+            "collections/delete" => WebhookTopic::CollectionsDelete,
+            "collections/update" => WebhookTopic::CollectionsUpdate,
+            "customers/create" => WebhookTopic::CustomersCreate,
+            "customers/data_request" => WebhookTopic::CustomersDataRequest,
+            "customers/delete" => WebhookTopic::CustomersDelete,
+            "customers/disable" => WebhookTopic::CustomersDisable,
+            "customers/enable" => WebhookTopic::CustomersEnable,
+            "customers/redact" => WebhookTopic::CustomersRedact,
+            "customers/update" => WebhookTopic::CustomersUpdate,
+            "fulfillments/create" => WebhookTopic::FulfillmentsCreate,
+            "fulfillments/update" => WebhookTopic::FulfillmentsUpdate,
+            "inventory_levels/connect" => WebhookTopic::InventoryLevelsConnect,
+            "inventory_levels/disconnect" => WebhookTopic::InventoryLevelsDisconnect,
+            "inventory_levels/update" => WebhookTopic::InventoryLevelsUpdate,
+            "orders/cancelled" => WebhookTopic::OrdersCancelled,
+            "orders/create" => WebhookTopic::OrdersCreate,
+            "orders/delete" => WebhookTopic::OrdersDelete,
+            "orders/fulfilled" => WebhookTopic::OrdersFulfilled,
+            "orders/paid" => WebhookTopic::OrdersPaid,
+            "orders/partially_fulfilled" => WebhookTopic::OrdersPartiallyFulfilled,
+            "orders/updated" => WebhookTopic::OrdersUpdated,
+            "products/create" => WebhookTopic::ProductsCreate,
+            "products/delete" => WebhookTopic::ProductsDelete,
+            "products/update" => WebhookTopic::ProductsUpdate,
+            "shop/redact" => WebhookTopic::ShopRedact,
+            "shop/update" => WebhookTopic::ShopUpdate,
+            _ => WebhookTopic::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WebhookTopic> for String {
+    fn from(e: WebhookTopic) -> String {
+        match e {
+            WebhookTopic::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
+impl WebhookTopic {
+    /// The privacy topics every public app has to subscribe to and act on,
+    /// see <https://shopify.dev/apps/webhooks/configuration/mandatory-webhooks>.
+    pub fn is_mandatory(&self) -> bool {
+        matches!(
+            self,
+            WebhookTopic::CustomersDataRequest
+                | WebhookTopic::CustomersRedact
+                | WebhookTopic::ShopRedact
+        )
+    }
+}
+
+/// The payload of the `*/delete` topics, which only identifies what was deleted.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeletedResource {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+}
+
+/// The shop, as sent to `app/uninstalled` and `shop/update`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Shop {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /// The primary domain of the shop, which may be a custom domain.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub myshopify_domain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub shop_owner: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub plan_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub country_code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub currency: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub iana_timezone: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// The customer a privacy request is about.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct PrivacyCustomer {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub phone: String,
+}
+
+/// A `customers/redact` request, erase what you store about the customer and
+/// the listed orders.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomersRedact {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub shop_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub shop_domain: String,
+    #[serde(default)]
+    pub customer: PrivacyCustomer,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub orders_to_redact: Vec<i64>,
+}
+
+/// A `shop/redact` request, sent 48 hours after a shop uninstalls the app.
+/// Erase what you store about the shop.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ShopRedact {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub shop_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub shop_domain: String,
+}
+
+/// A `customers/data_request` request, send the merchant what you store about
+/// the customer and the listed orders.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomersDataRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub shop_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub shop_domain: String,
+    #[serde(default)]
+    pub customer: PrivacyCustomer,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub orders_requested: Vec<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_request: Option<DataRequest>,
+}
+
+/// The request a `customers/data_request` delivery is for.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DataRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "shpss_test_secret";
+
+    fn sign(body: &[u8]) -> String {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, SECRET.as_bytes());
+        base64::engine::general_purpose::STANDARD.encode(hmac::sign(&key, body))
+    }
+
+    fn headers(topic: &str, body: &[u8]) -> http::HeaderMap {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(HMAC_HEADER, sign(body).parse().unwrap());
+        headers.insert(TOPIC_HEADER, topic.parse().unwrap());
+        headers.insert(SHOP_DOMAIN_HEADER, "my-shop.myshopify.com".parse().unwrap());
+        headers.insert(API_VERSION_HEADER, "2020-10".parse().unwrap());
+        headers.insert(
+            WEBHOOK_ID_HEADER,
+            "b54557e4-bdd9-4b37-8a5f-bf7d70bcd043".parse().unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn verify_signature_rejects_mismatches() {
+        let body = br#"{"id": 820982911946154508}"#;
+        verify_signature(SECRET, &sign(body), body).unwrap();
+
+        let good = sign(body);
+        let bad = [
+            sign(br#"{"id": 820982911946154509}"#),
+            good[..20].to_string(),
+            "not base64!".to_string(),
+            good.trim_end_matches('=').to_string(),
+            String::new(),
+        ];
+        for signature in bad {
+            assert!(
+                matches!(
+                    verify_signature(SECRET, &signature, body),
+                    Err(ClientError::InvalidWebhookSignature(_))
+                ),
+                "{} should not verify",
+                signature
+            );
+        }
+        assert!(verify_signature("wrong", &good, body).is_err());
+    }
+
+    #[test]
+    fn topic_round_trips() {
+        for topic in [
+            "orders/create",
+            "orders/partially_fulfilled",
+            "inventory_levels/disconnect",
+            "customers/data_request",
+            "app/uninstalled",
+            "carts/create",
+        ] {
+            let parsed = WebhookTopic::from(topic.to_string());
+            assert_eq!(parsed.to_string(), topic);
+            assert_eq!(String::from(parsed), topic);
+        }
+        assert_eq!(
+            WebhookTopic::from("carts/create".to_string()),
+            WebhookTopic::Unknown("carts/create".to_string())
+        );
+
+        assert!(WebhookTopic::ShopRedact.is_mandatory());
+        assert!(WebhookTopic::CustomersRedact.is_mandatory());
+        assert!(WebhookTopic::CustomersDataRequest.is_mandatory());
+        assert!(!WebhookTopic::AppUninstalled.is_mandatory());
+    }
+
+    #[test]
+    fn verify_and_parse_order_paid() {
+        let body = serde_json::json!({
+            "id": 820982911946154508_i64,
+            "admin_graphql_api_id": "gid://shopify/Order/820982911946154508",
+            "email": "jon@example.com",
+            "name": "#9999",
+            "currency": "USD",
+            "total_price": "403.00",
+            "financial_status": "paid",
+            "fulfillment_status": null,
+            "cancelled_at": null,
+            "created_at": "2021-12-31T19:00:00-05:00",
+            "line_items": [],
+            "customer": {"id": 115310627314723954_i64, "email": "john@example.com"}
+        })
+        .to_string();
+
+        let delivery = verify_and_parse(
+            SECRET,
+            &headers("orders/paid", body.as_bytes()),
+            body.as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(delivery.topic, WebhookTopic::OrdersPaid);
+        assert_eq!(delivery.shop_domain, "my-shop.myshopify.com");
+        assert_eq!(delivery.api_version, "2020-10");
+        assert_eq!(delivery.webhook_id, "b54557e4-bdd9-4b37-8a5f-bf7d70bcd043");
+        match delivery.event {
+            WebhookEvent::Order(order) => {
+                assert_eq!(order.id, 820982911946154508);
+                assert_eq!(order.name, "#9999");
+                assert_eq!(order.total_price, "403.00");
+                assert_eq!(order.customer.unwrap().id, 115310627314723954);
+            }
+            e => panic!("expected an order, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn verify_and_parse_requires_signature_and_topic() {
+        let body = br#"{"id": 788032119674292922}"#;
+
+        let mut missing_signature = headers("products/delete", body);
+        missing_signature.remove(HMAC_HEADER);
+        assert!(matches!(
+            verify_and_parse(SECRET, &missing_signature, body),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+
+        let mut missing_topic = headers("products/delete", body);
+        missing_topic.remove(TOPIC_HEADER);
+        assert!(matches!(
+            verify_and_parse(SECRET, &missing_topic, body),
+            Err(ClientError::InvalidWebhookPayload(_))
+        ));
+
+        let delivery = verify_and_parse(SECRET, &headers("products/delete", body), body).unwrap();
+        assert_eq!(
+            delivery.event,
+            WebhookEvent::Deleted(DeletedResource {
+                id: 788032119674292922
+            })
+        );
+    }
+
+    #[test]
+    fn parse_mandatory_topics() {
+        let body = serde_json::json!({
+            "shop_id": 954889,
+            "shop_domain": "my-shop.myshopify.com",
+            "customer": {"id": 191167, "email": "john@example.com", "phone": "555-625-1199"},
+            "orders_to_redact": [299938, 280263, 220458]
+        });
+        match parse_event(&WebhookTopic::CustomersRedact, body.to_string().as_bytes()).unwrap() {
+            WebhookEvent::CustomersRedact(request) => {
+                assert_eq!(request.shop_domain, "my-shop.myshopify.com");
+                assert_eq!(request.customer.id, 191167);
+                assert_eq!(request.customer.phone, "555-625-1199");
+                assert_eq!(request.orders_to_redact, vec![299938, 280263, 220458]);
+            }
+            e => panic!("expected a customers/redact request, got {:?}", e),
+        }
+
+        let body = serde_json::json!({
+            "shop_id": 954889,
+            "shop_domain": "my-shop.myshopify.com",
+            "orders_requested": [299938, 280263],
+            "customer": {"id": 191167, "email": "john@example.com", "phone": null},
+            "data_request": {"id": 9999}
+        });
+        match parse_event(
+            &WebhookTopic::CustomersDataRequest,
+            body.to_string().as_bytes(),
+        )
+        .unwrap()
+        {
+            WebhookEvent::CustomersDataRequest(request) => {
+                assert_eq!(request.customer.email, "john@example.com");
+                assert_eq!(request.customer.phone, "");
+                assert_eq!(request.orders_requested, vec![299938, 280263]);
+                assert_eq!(request.data_request.unwrap().id, 9999);
+            }
+            e => panic!("expected a customers/data_request request, got {:?}", e),
+        }
+
+        let body = br#"{"shop_id": 954889, "shop_domain": "my-shop.myshopify.com"}"#;
+        assert_eq!(
+            parse_event(&WebhookTopic::ShopRedact, body).unwrap(),
+            WebhookEvent::ShopRedact(ShopRedact {
+                shop_id: 954889,
+                shop_domain: "my-shop.myshopify.com".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_unknown_topic() {
+        let body = br#"{"id": "eeaa8c7b", "token": "eeaa8c7b", "line_items": []}"#;
+        let topic = WebhookTopic::from("carts/create".to_string());
+        assert_eq!(
+            parse_event(&topic, body).unwrap(),
+            WebhookEvent::Unknown(serde_json::json!({
+                "id": "eeaa8c7b",
+                "token": "eeaa8c7b",
+                "line_items": []
+            }))
+        );
+    }
+}
@@ -22,13 +22,29 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn approve(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn approve(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.approve_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.apps.approve` endpoint.
+     *
+     * As opposed to `approve`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.apps.approve>
+     */
+    pub async fn approve_with_response(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.apps.approve", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn restrict(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn restrict(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.restrict_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.apps.restrict` endpoint.
+     *
+     * As opposed to `restrict`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.apps.restrict>
+     */
+    pub async fn restrict_with_response(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.apps.restrict", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
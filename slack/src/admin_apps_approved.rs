@@ -33,6 +33,25 @@ impl AdminAppsApproved {
         team_id: &str,
         enterprise_id: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .list_with_response(limit, cursor, team_id, enterprise_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.apps.approved.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.apps.approved.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+        enterprise_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -51,7 +70,7 @@ impl AdminAppsApproved {
             .client
             .url(&format!("/admin.apps.approved.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -31,6 +31,21 @@ impl AdminAppsRequests {
         cursor: &str,
         team_id: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(limit, cursor, team_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.apps.requests.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.apps.requests.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -46,7 +61,7 @@ impl AdminAppsRequests {
             .client
             .url(&format!("/admin.apps.requests.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
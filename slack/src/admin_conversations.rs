@@ -22,13 +22,29 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn archive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.archive_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.archive` endpoint.
+     *
+     * As opposed to `archive`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.archive>
+     */
+    pub async fn archive_with_response(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.archive", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,15 +61,31 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn convert_private(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn convert_private(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.convert_private_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.convertToPrivate` endpoint.
+     *
+     * As opposed to `convert_private`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.convertToPrivate>
+     */
+    pub async fn convert_private_with_response(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.conversations.convertToPrivate", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -70,13 +102,29 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::AdminConversationsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> ClientResult<crate::types::AdminConversationsCreateSchema> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.create` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.create>
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsCreateSchema>> {
         let url = self.client.url("/admin.conversations.create", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -93,13 +141,29 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.delete_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.delete` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.delete>
+     */
+    pub async fn delete_with_response(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.delete", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -116,15 +180,31 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn disconnect_shared(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn disconnect_shared(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.disconnect_shared_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.disconnectShared` endpoint.
+     *
+     * As opposed to `disconnect_shared`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.disconnectShared>
+     */
+    pub async fn disconnect_shared_with_response(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.conversations.disconnectShared", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -146,6 +226,23 @@ impl AdminConversations {
         &self,
         channel_id: &str,
     ) -> ClientResult<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
+        Ok(self
+            .get_conversation_pref_with_response(channel_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getConversationPrefs` endpoint.
+     *
+     * As opposed to `get_conversation_pref`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getConversationPrefs>
+     */
+    pub async fn get_conversation_pref_with_response(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsGetConversationPrefsSchemaData>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel_id.is_empty() {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
@@ -156,7 +253,7 @@ impl AdminConversations {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -185,6 +282,24 @@ impl AdminConversations {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<crate::types::AdminConversationsGetTeamsSchema> {
+        Ok(self
+            .get_team_with_response(channel_id, cursor, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
+     *
+     * As opposed to `get_team`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getTeams>
+     */
+    pub async fn get_team_with_response(
+        &self,
+        channel_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsGetTeamsSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel_id.is_empty() {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
@@ -200,7 +315,7 @@ impl AdminConversations {
             .client
             .url(&format!("/admin.conversations.getTeams?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -220,13 +335,29 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn invite(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.invite_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.invite` endpoint.
+     *
+     * As opposed to `invite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.invite>
+     */
+    pub async fn invite_with_response(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.invite", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -243,13 +374,29 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn rename(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.rename_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.rename` endpoint.
+     *
+     * As opposed to `rename`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.rename>
+     */
+    pub async fn rename_with_response(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.rename", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -283,6 +430,36 @@ impl AdminConversations {
         sort: &str,
         sort_dir: &str,
     ) -> ClientResult<crate::types::AdminConversationsSearchSchema> {
+        Ok(self
+            .search_with_response(
+                team_ids,
+                query,
+                limit,
+                cursor,
+                search_channel_types,
+                sort,
+                sort_dir,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.search` endpoint.
+     *
+     * As opposed to `search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.search>
+     */
+    pub async fn search_with_response(
+        &self,
+        team_ids: &str,
+        query: &str,
+        limit: i64,
+        cursor: &str,
+        search_channel_types: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> ClientResult<crate::Response<crate::types::AdminConversationsSearchSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -313,7 +490,7 @@ impl AdminConversations {
             .client
             .url(&format!("/admin.conversations.search?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -333,15 +510,31 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_conversation_prefs(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_conversation_prefs(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_conversation_prefs_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.setConversationPrefs` endpoint.
+     *
+     * As opposed to `set_conversation_prefs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.setConversationPrefs>
+     */
+    pub async fn set_conversation_prefs_with_response(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.conversations.setConversationPrefs", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -358,13 +551,29 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_teams(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_teams_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.setTeams` endpoint.
+     *
+     * As opposed to `set_teams`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.setTeams>
+     */
+    pub async fn set_teams_with_response(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.setTeams", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -381,13 +590,29 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn unarchive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.unarchive_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.unarchive` endpoint.
+     *
+     * As opposed to `unarchive`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.unarchive>
+     */
+    pub async fn unarchive_with_response(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.conversations.unarchive", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
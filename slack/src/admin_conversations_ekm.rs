@@ -33,6 +33,30 @@ impl AdminConversationsEkm {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .list_original_connected_channel_info_with_response(
+                channel_ids,
+                team_ids,
+                limit,
+                cursor,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.ekm.listOriginalConnectedChannelInfo` endpoint.
+     *
+     * As opposed to `list_original_connected_channel_info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.ekm.listOriginalConnectedChannelInfo>
+     */
+    pub async fn list_original_connected_channel_info_with_response(
+        &self,
+        channel_ids: &str,
+        team_ids: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel_ids.is_empty() {
             query_args.push(("channel_ids".to_string(), channel_ids.to_string()));
@@ -55,7 +79,7 @@ impl AdminConversationsEkm {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -18,15 +18,31 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup>
      */
-    pub async fn add_group(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_group_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.restrictAccess.addGroup` endpoint.
+     *
+     * As opposed to `add_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup>
+     */
+    pub async fn add_group_with_response(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.addGroup", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -50,6 +66,23 @@ impl AdminConversationsRestrictAccess {
         channel_id: &str,
         team_id: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .list_group_with_response(channel_id, team_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.conversations.restrictAccess.listGroups` endpoint.
+     *
+     * As opposed to `list_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.listGroups>
+     */
+    pub async fn list_group_with_response(
+        &self,
+        channel_id: &str,
+        team_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel_id.is_empty() {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
@@ -63,7 +96,7 @@ impl AdminConversationsRestrictAccess {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,15 +112,31 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup>
      */
-    pub async fn remove_group(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_group_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.conversations.restrictAccess.removeGroup` endpoint.
+     *
+     * As opposed to `remove_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup>
+     */
+    pub async fn remove_group_with_response(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.conversations.restrictAccess.removeGroup", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
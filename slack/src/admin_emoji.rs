@@ -18,13 +18,29 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.add>
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.emoji.add` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.emoji.add>
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.add", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -37,13 +53,29 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.addAlias>
      */
-    pub async fn add_alias(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_alias_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.emoji.addAlias` endpoint.
+     *
+     * As opposed to `add_alias`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.emoji.addAlias>
+     */
+    pub async fn add_alias_with_response(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.addAlias", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -63,6 +95,20 @@ impl AdminEmoji {
      * * `limit: i64` -- The maximum number of items to return. Must be between 1 - 1000 both inclusive.
      */
     pub async fn list(&self, cursor: &str, limit: i64) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(cursor, limit).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.emoji.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.emoji.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -75,7 +121,7 @@ impl AdminEmoji {
             .client
             .url(&format!("/admin.emoji.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -91,13 +137,29 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.remove>
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.emoji.remove` endpoint.
+     *
+     * As opposed to `remove`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.emoji.remove>
+     */
+    pub async fn remove_with_response(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.remove", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -110,13 +172,29 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.rename>
      */
-    pub async fn rename(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.rename_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.emoji.rename` endpoint.
+     *
+     * As opposed to `rename`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.emoji.rename>
+     */
+    pub async fn rename_with_response(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.emoji.rename", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -26,9 +26,22 @@ impl AdminInviteRequests {
         &self,
         body: &crate::types::AdminInviteRequestsApproveRequest,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.approve_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.inviteRequests.approve` endpoint.
+     *
+     * As opposed to `approve`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.inviteRequests.approve>
+     */
+    pub async fn approve_with_response(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.inviteRequests.approve", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -52,9 +65,22 @@ impl AdminInviteRequests {
         &self,
         body: &crate::types::AdminInviteRequestsApproveRequest,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.deny_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.inviteRequests.deny` endpoint.
+     *
+     * As opposed to `deny`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.inviteRequests.deny>
+     */
+    pub async fn deny_with_response(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.inviteRequests.deny", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -83,6 +109,21 @@ impl AdminInviteRequests {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(team_id, cursor, limit).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.inviteRequests.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.inviteRequests.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -98,7 +139,7 @@ impl AdminInviteRequests {
             .client
             .url(&format!("/admin.inviteRequests.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
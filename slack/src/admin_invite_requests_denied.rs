@@ -31,6 +31,21 @@ impl AdminInviteRequestsDenied {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(team_id, cursor, limit).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.inviteRequests.denied.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.inviteRequests.denied.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -47,7 +62,7 @@ impl AdminInviteRequestsDenied {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -22,13 +22,29 @@ impl AdminTeams {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.teams.create` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.create>
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.teams.create", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -48,6 +64,20 @@ impl AdminTeams {
      * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
      */
     pub async fn list(&self, limit: i64, cursor: &str) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(limit, cursor).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.teams.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -60,7 +90,7 @@ impl AdminTeams {
             .client
             .url(&format!("/admin.teams.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
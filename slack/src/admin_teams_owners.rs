@@ -31,6 +31,21 @@ impl AdminTeamsOwners {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(team_id, limit, cursor).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.teams.owners.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.owners.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        team_id: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -46,7 +61,7 @@ impl AdminTeamsOwners {
             .client
             .url(&format!("/admin.teams.owners.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -24,6 +24,19 @@ impl AdminTeamsSettings {
      * * `team_id: &str`
      */
     pub async fn info(&self, team_id: &str) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.info_with_response(team_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.teams.settings.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.settings.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        team_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !team_id.is_empty() {
             query_args.push(("team_id".to_string(), team_id.to_string()));
@@ -33,7 +46,7 @@ impl AdminTeamsSettings {
             .client
             .url(&format!("/admin.teams.settings.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -49,15 +62,31 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setDefaultChannels>
      */
-    pub async fn set_default_channels(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_default_channels(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_default_channels_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.teams.settings.setDefaultChannels` endpoint.
+     *
+     * As opposed to `set_default_channels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.settings.setDefaultChannels>
+     */
+    pub async fn set_default_channels_with_response(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.teams.settings.setDefaultChannels", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -74,15 +103,31 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_description(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_description(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_description_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.teams.settings.setDescription` endpoint.
+     *
+     * As opposed to `set_description`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.settings.setDescription>
+     */
+    pub async fn set_description_with_response(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.teams.settings.setDescription", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -99,15 +144,31 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_discoverability(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_discoverability(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_discoverability_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.teams.settings.setDiscoverability` endpoint.
+     *
+     * As opposed to `set_discoverability`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.settings.setDiscoverability>
+     */
+    pub async fn set_discoverability_with_response(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self
             .client
             .url("/admin.teams.settings.setDiscoverability", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -120,13 +181,29 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setIcon>
      */
-    pub async fn set_icon(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_icon_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.teams.settings.setIcon` endpoint.
+     *
+     * As opposed to `set_icon`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.settings.setIcon>
+     */
+    pub async fn set_icon_with_response(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.teams.settings.setIcon", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -143,13 +220,29 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_name(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_name_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.teams.settings.setName` endpoint.
+     *
+     * As opposed to `set_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.teams.settings.setName>
+     */
+    pub async fn set_name_with_response(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.teams.settings.setName", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -22,13 +22,29 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn add_channels(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_channels_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.usergroups.addChannels` endpoint.
+     *
+     * As opposed to `add_channels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.usergroups.addChannels>
+     */
+    pub async fn add_channels_with_response(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.usergroups.addChannels", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn add_teams(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_teams_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.usergroups.addTeams` endpoint.
+     *
+     * As opposed to `add_teams`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.usergroups.addTeams>
+     */
+    pub async fn add_teams_with_response(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.usergroups.addTeams", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -77,6 +109,24 @@ impl AdminUsergroups {
         team_id: &str,
         include_num_members: bool,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .list_channel_with_response(usergroup_id, team_id, include_num_members)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.usergroups.listChannels` endpoint.
+     *
+     * As opposed to `list_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.usergroups.listChannels>
+     */
+    pub async fn list_channel_with_response(
+        &self,
+        usergroup_id: &str,
+        team_id: &str,
+        include_num_members: bool,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_num_members {
             query_args.push((
@@ -95,7 +145,7 @@ impl AdminUsergroups {
             .client
             .url(&format!("/admin.usergroups.listChannels?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -115,13 +165,29 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn remove_channels(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_channels_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.usergroups.removeChannels` endpoint.
+     *
+     * As opposed to `remove_channels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.usergroups.removeChannels>
+     */
+    pub async fn remove_channels_with_response(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.usergroups.removeChannels", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -22,13 +22,29 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn assign(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn assign(
+        &self,
+        body: &crate::types::AdminUsersAssignRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.assign_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.assign` endpoint.
+     *
+     * As opposed to `assign`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.assign>
+     */
+    pub async fn assign_with_response(
+        &self,
+        body: &crate::types::AdminUsersAssignRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.assign", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn invite(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminUsersInviteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.invite_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.invite` endpoint.
+     *
+     * As opposed to `invite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.invite>
+     */
+    pub async fn invite_with_response(
+        &self,
+        body: &crate::types::AdminUsersInviteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.invite", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -77,6 +109,21 @@ impl AdminUsers {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(team_id, cursor, limit).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/admin.users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -92,7 +139,7 @@ impl AdminUsers {
             .client
             .url(&format!("/admin.users.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -112,13 +159,29 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.remove` endpoint.
+     *
+     * As opposed to `remove`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.remove>
+     */
+    pub async fn remove_with_response(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.remove", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -135,13 +198,29 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.setAdmin` endpoint.
+     *
+     * As opposed to `set`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.setAdmin>
+     */
+    pub async fn set_with_response(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.setAdmin", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -158,13 +237,29 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_expiration(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_expiration(
+        &self,
+        body: &crate::types::AdminUsersSetExpirationRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_expiration_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.setExpiration` endpoint.
+     *
+     * As opposed to `set_expiration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.setExpiration>
+     */
+    pub async fn set_expiration_with_response(
+        &self,
+        body: &crate::types::AdminUsersSetExpirationRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.setExpiration", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -181,13 +276,29 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_owner(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_owner(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_owner_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.setOwner` endpoint.
+     *
+     * As opposed to `set_owner`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.setOwner>
+     */
+    pub async fn set_owner_with_response(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.setOwner", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -204,13 +315,29 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_regular(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_regular(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_regular_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.setRegular` endpoint.
+     *
+     * As opposed to `set_regular`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.setRegular>
+     */
+    pub async fn set_regular_with_response(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.setRegular", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
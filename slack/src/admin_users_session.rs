@@ -22,13 +22,29 @@ impl AdminUsersSession {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn invalidate(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn invalidate(
+        &self,
+        body: &crate::types::AdminUsersSessionInvalidateRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.invalidate_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.session.invalidate` endpoint.
+     *
+     * As opposed to `invalidate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.session.invalidate>
+     */
+    pub async fn invalidate_with_response(
+        &self,
+        body: &crate::types::AdminUsersSessionInvalidateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.session.invalidate", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl AdminUsersSession {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn reset(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn reset(
+        &self,
+        body: &crate::types::AdminUsersSessionResetRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.reset_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/admin.users.session.reset` endpoint.
+     *
+     * As opposed to `reset`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/admin.users.session.reset>
+     */
+    pub async fn reset_with_response(
+        &self,
+        body: &crate::types::AdminUsersSessionResetRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/admin.users.session.reset", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -24,6 +24,20 @@ impl Api {
      * * `foo_: &str` -- example property to return.
      */
     pub async fn test(&self, error: &str, foo_: &str) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.test_with_response(error, foo_).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/api.test` endpoint.
+     *
+     * As opposed to `test`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/api.test>
+     */
+    pub async fn test_with_response(
+        &self,
+        error: &str,
+        foo_: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !error.is_empty() {
             query_args.push(("error".to_string(), error.to_string()));
@@ -34,7 +48,7 @@ impl Api {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/api.test?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
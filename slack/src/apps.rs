@@ -29,6 +29,23 @@ impl Apps {
         client_id: &str,
         client_secret: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .uninstall_with_response(client_id, client_secret)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.uninstall` endpoint.
+     *
+     * As opposed to `uninstall`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.uninstall>
+     */
+    pub async fn uninstall_with_response(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -41,7 +58,7 @@ impl Apps {
             .client
             .url(&format!("/apps.uninstall?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -31,6 +31,24 @@ impl AppsEventAuthorizations {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .list_with_response(event_context, cursor, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.event.authorizations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.event.authorizations.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        event_context: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -46,7 +64,7 @@ impl AppsEventAuthorizations {
             .client
             .url(&format!("/apps.event.authorizations.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -23,9 +23,21 @@ impl AppsPermissions {
      * * `token: &str` -- Authentication token. Requires scope: `none`.
      */
     pub async fn info(&self) -> ClientResult<crate::types::AppsPermissionsInfoSchema> {
+        Ok(self.info_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.info>
+     */
+    pub async fn info_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::AppsPermissionsInfoSchema>> {
         let url = self.client.url("/apps.permissions.info", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -52,6 +64,20 @@ impl AppsPermissions {
         scopes: &str,
         trigger_id: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.request_with_response(scopes, trigger_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.request` endpoint.
+     *
+     * As opposed to `request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.request>
+     */
+    pub async fn request_with_response(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !scopes.is_empty() {
             query_args.push(("scopes".to_string(), scopes.to_string()));
@@ -64,7 +90,7 @@ impl AppsPermissions {
             .client
             .url(&format!("/apps.permissions.request?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
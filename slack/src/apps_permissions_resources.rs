@@ -29,6 +29,21 @@ impl AppsPermissionsResources {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<crate::types::AppsPermissionsResourcesListSuccessSchema> {
+        Ok(self.list_with_response(cursor, limit).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.resources.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::AppsPermissionsResourcesListSuccessSchema>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -42,7 +57,7 @@ impl AppsPermissionsResources {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
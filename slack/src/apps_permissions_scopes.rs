@@ -23,9 +23,21 @@ impl AppsPermissionsScopes {
      * * `token: &str` -- Authentication token. Requires scope: `none`.
      */
     pub async fn list(&self) -> ClientResult<crate::types::ApiPermissionsScopesListSuccessSchema> {
+        Ok(self.list_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.scopes.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.scopes.list>
+     */
+    pub async fn list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ApiPermissionsScopesListSuccessSchema>> {
         let url = self.client.url("/apps.permissions.scopes.list", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
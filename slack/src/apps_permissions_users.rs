@@ -25,6 +25,20 @@ impl AppsPermissionsUsers {
      * * `limit: i64` -- The maximum number of items to return.
      */
     pub async fn list(&self, cursor: &str, limit: i64) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response(cursor, limit).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.users.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -37,7 +51,7 @@ impl AppsPermissionsUsers {
             .client
             .url(&format!("/apps.permissions.users.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,6 +80,24 @@ impl AppsPermissionsUsers {
         trigger_id: &str,
         user: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .request_with_response(scopes, trigger_id, user)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/apps.permissions.users.request` endpoint.
+     *
+     * As opposed to `request`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.users.request>
+     */
+    pub async fn request_with_response(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !scopes.is_empty() {
             query_args.push(("scopes".to_string(), scopes.to_string()));
@@ -81,7 +113,7 @@ impl AppsPermissionsUsers {
             .client
             .url(&format!("/apps.permissions.users.request?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -24,6 +24,19 @@ impl Auth {
      * * `test: bool` -- Setting this parameter to `1` triggers a _testing mode_ where the specified token will not actually be revoked.
      */
     pub async fn revoke(&self, test: bool) -> ClientResult<crate::types::AuthRevokeSchema> {
+        Ok(self.revoke_with_response(test).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/auth.revoke` endpoint.
+     *
+     * As opposed to `revoke`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/auth.revoke>
+     */
+    pub async fn revoke_with_response(
+        &self,
+        test: bool,
+    ) -> ClientResult<crate::Response<crate::types::AuthRevokeSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if test {
             query_args.push(("test".to_string(), test.to_string()));
@@ -31,7 +44,7 @@ impl Auth {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/auth.revoke?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -52,9 +65,21 @@ impl Auth {
      * * `token: &str` -- Authentication token. Requires scope: `none`.
      */
     pub async fn test(&self) -> ClientResult<crate::types::AuthTestSuccessSchema> {
+        Ok(self.test_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/auth.test` endpoint.
+     *
+     * As opposed to `test`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/auth.test>
+     */
+    pub async fn test_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::AuthTestSuccessSchema>> {
         let url = self.client.url("/auth.test", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -0,0 +1,1336 @@
+//! A typed model of Block Kit, the layout blocks used in messages, modals and the app home.
+//!
+//! Blocks are checked against the limits Slack documents before they are serialized, so
+//! payloads Slack would reject with `invalid_blocks` fail here instead, with the reason.
+//!
+//! ```ignore
+//! use slack_chat_api::blocks::{self, Actions, Button, Section, Text, View};
+//!
+//! let blocks = blocks::message_blocks(&[
+//!     Section::new(Text::mrkdwn("*Deploy* `v1.2.3` to production?")).into(),
+//!     Actions::new(vec![Button::new("Deploy", "deploy").value("v1.2.3").into()]).into(),
+//! ])?;
+//! // Send `blocks` as the `blocks` field of a `ChatPostMessageRequest`.
+//!
+//! let view = View::modal("Deploy").submit("Deploy").block(Section::new(Text::plain("Are you sure?")));
+//! slack.views().open(&trigger_id, &view.to_json()?).await?;
+//! ```
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientError, ClientResult};
+
+/// The most blocks a message can have.
+pub const MAX_MESSAGE_BLOCKS: usize = 50;
+/// The most blocks a modal or home tab can have.
+pub const MAX_VIEW_BLOCKS: usize = 100;
+
+/// Validate `blocks` for a message and serialize them for the `blocks` field of
+/// `ChatPostMessageRequest`, `ChatPostEphemeralRequest`, `ChatScheduleMessageRequest` or
+/// `ChatUpdateRequest`.
+pub fn message_blocks(blocks: &[Block]) -> ClientResult<String> {
+    check_count("message blocks", blocks.len(), MAX_MESSAGE_BLOCKS)?;
+    for block in blocks {
+        block.validate()?;
+    }
+    Ok(serde_json::to_string(blocks)?)
+}
+
+/// A text object, plain or formatted with `mrkdwn`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    PlainText {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    /// Plain text, with emoji shortcodes rendered.
+    pub fn plain(text: &str) -> Self {
+        Text::PlainText {
+            text: text.to_string(),
+            emoji: Some(true),
+        }
+    }
+
+    /// Text formatted with Slack's `mrkdwn`.
+    pub fn mrkdwn(text: &str) -> Self {
+        Text::Mrkdwn {
+            text: text.to_string(),
+            verbatim: None,
+        }
+    }
+
+    /// The text, without its formatting type.
+    pub fn text(&self) -> &str {
+        match self {
+            Text::PlainText { text, .. } | Text::Mrkdwn { text, .. } => text,
+        }
+    }
+}
+
+/// A block, the unit messages, modals and the app home are laid out with.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Section(Section),
+    Actions(Actions),
+    Input(Input),
+    Context(Context),
+    Header(Header),
+    Divider(Divider),
+}
+
+impl Block {
+    /// Check the block against the limits Slack documents for it.
+    pub fn validate(&self) -> ClientResult<()> {
+        match self {
+            Block::Section(b) => b.validate(),
+            Block::Actions(b) => b.validate(),
+            Block::Input(b) => b.validate(),
+            Block::Context(b) => b.validate(),
+            Block::Header(b) => b.validate(),
+            Block::Divider(b) => check_id("block_id", &b.block_id),
+        }
+    }
+}
+
+/// Text, optionally in two columns of fields, with an element beside it.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Section {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Text>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<Element>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+}
+
+impl Section {
+    pub fn new(text: Text) -> Self {
+        Section {
+            text: Some(text),
+            ..Default::default()
+        }
+    }
+
+    /// A section of fields only, shown in two columns.
+    pub fn with_fields(fields: Vec<Text>) -> Self {
+        Section {
+            fields,
+            ..Default::default()
+        }
+    }
+
+    pub fn field(mut self, field: Text) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn accessory(mut self, element: impl Into<Element>) -> Self {
+        self.accessory = Some(element.into());
+        self
+    }
+
+    pub fn block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    fn validate(&self) -> ClientResult<()> {
+        if self.text.is_none() && self.fields.is_empty() {
+            return Err(invalid("section needs text or fields"));
+        }
+        if let Some(text) = &self.text {
+            check_len("section text", text.text(), 3000)?;
+        }
+        check_count("section fields", self.fields.len(), 10)?;
+        for field in &self.fields {
+            check_len("section field", field.text(), 2000)?;
+        }
+        if let Some(accessory) = &self.accessory {
+            accessory.validate()?;
+        }
+        check_id("block_id", &self.block_id)
+    }
+}
+
+/// A row of interactive elements.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Actions {
+    pub elements: Vec<Element>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+}
+
+impl Actions {
+    pub fn new(elements: Vec<Element>) -> Self {
+        Actions {
+            elements,
+            block_id: String::new(),
+        }
+    }
+
+    pub fn block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    fn validate(&self) -> ClientResult<()> {
+        if self.elements.is_empty() {
+            return Err(invalid("actions need at least one element"));
+        }
+        check_count("actions elements", self.elements.len(), 25)?;
+        for element in &self.elements {
+            if let Element::Image(_) = element {
+                return Err(invalid("actions cannot contain images"));
+            }
+            element.validate()?;
+        }
+        check_id("block_id", &self.block_id)
+    }
+}
+
+/// A labelled input, in modals and the app home.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Input {
+    pub label: Text,
+    pub element: Element,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dispatch_action: bool,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+}
+
+impl Input {
+    pub fn new(label: &str, element: impl Into<Element>) -> Self {
+        Input {
+            label: Text::plain(label),
+            element: element.into(),
+            hint: None,
+            optional: false,
+            dispatch_action: false,
+            block_id: String::new(),
+        }
+    }
+
+    pub fn hint(mut self, hint: &str) -> Self {
+        self.hint = Some(Text::plain(hint));
+        self
+    }
+
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Send a `block_actions` payload as the input changes, not only on submission.
+    pub fn dispatch_action(mut self, dispatch_action: bool) -> Self {
+        self.dispatch_action = dispatch_action;
+        self
+    }
+
+    pub fn block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    fn validate(&self) -> ClientResult<()> {
+        check_plain("input label", &self.label, 2000)?;
+        if let Some(hint) = &self.hint {
+            check_plain("input hint", hint, 2000)?;
+        }
+        match &self.element {
+            Element::Button(_) | Element::Overflow(_) | Element::Image(_) => {
+                return Err(invalid("inputs can only contain input elements"));
+            }
+            element => element.validate()?,
+        }
+        check_id("block_id", &self.block_id)
+    }
+}
+
+/// Small, muted text and images.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Context {
+    pub elements: Vec<ContextElement>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+}
+
+impl Context {
+    pub fn new(elements: Vec<ContextElement>) -> Self {
+        Context {
+            elements,
+            block_id: String::new(),
+        }
+    }
+
+    pub fn block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    fn validate(&self) -> ClientResult<()> {
+        if self.elements.is_empty() {
+            return Err(invalid("context needs at least one element"));
+        }
+        check_count("context elements", self.elements.len(), 10)?;
+        for element in &self.elements {
+            match element {
+                ContextElement::Text(text) => check_len("context text", text.text(), 3000)?,
+                ContextElement::Image(image) => image.validate()?,
+            }
+        }
+        check_id("block_id", &self.block_id)
+    }
+}
+
+/// An element of a context block.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ContextElement {
+    Text(Text),
+    Image(ContextImage),
+}
+
+impl From<Text> for ContextElement {
+    fn from(text: Text) -> Self {
+        ContextElement::Text(text)
+    }
+}
+
+impl From<Image> for ContextElement {
+    fn from(image: Image) -> Self {
+        ContextElement::Image(ContextImage {
+            type_: "image".to_string(),
+            image,
+        })
+    }
+}
+
+/// An image in a context block, which unlike other elements is not tagged by the `Element` enum.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContextImage {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(flatten)]
+    pub image: Image,
+}
+
+impl ContextImage {
+    fn validate(&self) -> ClientResult<()> {
+        self.image.validate()
+    }
+}
+
+/// Large, bold plain text.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Header {
+    pub text: Text,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+}
+
+impl Header {
+    pub fn new(text: &str) -> Self {
+        Header {
+            text: Text::plain(text),
+            block_id: String::new(),
+        }
+    }
+
+    fn validate(&self) -> ClientResult<()> {
+        check_plain("header text", &self.text, 150)?;
+        check_id("block_id", &self.block_id)
+    }
+}
+
+/// A horizontal rule.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct Divider {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+}
+
+macro_rules! impl_from {
+    ($enum:ident, $($variant:ident),*) => {
+        $(
+            impl From<$variant> for $enum {
+                fn from(v: $variant) -> Self {
+                    $enum::$variant(v)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Block, Section, Actions, Input, Context, Header, Divider);
+
+/// An interactive element, or an image.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Element {
+    Button(Button),
+    StaticSelect(StaticSelect),
+    UsersSelect(UsersSelect),
+    ConversationsSelect(ConversationsSelect),
+    ChannelsSelect(ChannelsSelect),
+    Datepicker(Datepicker),
+    PlainTextInput(PlainTextInput),
+    Checkboxes(Checkboxes),
+    RadioButtons(RadioButtons),
+    Overflow(Overflow),
+    Image(Image),
+}
+
+impl_from!(
+    Element,
+    Button,
+    StaticSelect,
+    UsersSelect,
+    ConversationsSelect,
+    ChannelsSelect,
+    Datepicker,
+    PlainTextInput,
+    Checkboxes,
+    RadioButtons,
+    Overflow,
+    Image
+);
+
+impl Element {
+    fn validate(&self) -> ClientResult<()> {
+        match self {
+            Element::Button(e) => {
+                check_plain("button text", &e.text, 75)?;
+                check_len("button value", &e.value, 2000)?;
+                check_len("button url", &e.url, 3000)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::StaticSelect(e) => {
+                if e.options.is_empty() == e.option_groups.is_empty() {
+                    return Err(invalid(
+                        "static_select needs either options or option_groups",
+                    ));
+                }
+                check_count("static_select options", e.options.len(), 100)?;
+                check_options(&e.options)?;
+                check_count("static_select option_groups", e.option_groups.len(), 100)?;
+                for group in &e.option_groups {
+                    check_plain("option group label", &group.label, 75)?;
+                    check_count("option group options", group.options.len(), 100)?;
+                    check_options(&group.options)?;
+                }
+                check_placeholder(&e.placeholder)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::UsersSelect(e) => {
+                check_placeholder(&e.placeholder)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::ConversationsSelect(e) => {
+                check_placeholder(&e.placeholder)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::ChannelsSelect(e) => {
+                check_placeholder(&e.placeholder)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::Datepicker(e) => {
+                check_placeholder(&e.placeholder)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::PlainTextInput(e) => {
+                check_placeholder(&e.placeholder)?;
+                if e.min_length.unwrap_or_default() > 3000 {
+                    return Err(invalid("plain_text_input min_length is more than 3000"));
+                }
+                if let (Some(min), Some(max)) = (e.min_length, e.max_length) {
+                    if min > max {
+                        return Err(invalid(
+                            "plain_text_input min_length is more than max_length",
+                        ));
+                    }
+                }
+                check_id("action_id", &e.action_id)
+            }
+            Element::Checkboxes(e) => {
+                if e.options.is_empty() {
+                    return Err(invalid("checkboxes need at least one option"));
+                }
+                check_count("checkboxes options", e.options.len(), 10)?;
+                check_options(&e.options)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::RadioButtons(e) => {
+                if e.options.is_empty() {
+                    return Err(invalid("radio_buttons need at least one option"));
+                }
+                check_count("radio_buttons options", e.options.len(), 10)?;
+                check_options(&e.options)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::Overflow(e) => {
+                if !(2..=5).contains(&e.options.len()) {
+                    return Err(invalid("overflow needs between 2 and 5 options"));
+                }
+                check_options(&e.options)?;
+                check_id("action_id", &e.action_id)?;
+                check_confirm(&e.confirm)
+            }
+            Element::Image(e) => e.validate(),
+        }
+    }
+}
+
+/// The color of a button.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Button {
+    pub text: Text,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl Button {
+    pub fn new(text: &str, action_id: &str) -> Self {
+        Button {
+            text: Text::plain(text),
+            action_id: action_id.to_string(),
+            value: String::new(),
+            url: String::new(),
+            style: None,
+            confirm: None,
+        }
+    }
+
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = value.to_string();
+        self
+    }
+
+    /// Open `url` in the browser when clicked, a `block_actions` payload is still sent.
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = url.to_string();
+        self
+    }
+
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: Confirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A menu of options defined in the block.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StaticSelect {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<OptionObject>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub option_groups: Vec<OptionGroup>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl StaticSelect {
+    pub fn new(action_id: &str, options: Vec<OptionObject>) -> Self {
+        StaticSelect {
+            placeholder: None,
+            action_id: action_id.to_string(),
+            options,
+            option_groups: Vec::new(),
+            initial_option: None,
+            confirm: None,
+        }
+    }
+
+    /// A menu with its options in labelled groups.
+    pub fn with_groups(action_id: &str, option_groups: Vec<OptionGroup>) -> Self {
+        StaticSelect {
+            option_groups,
+            ..StaticSelect::new(action_id, Vec::new())
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_option(mut self, option: OptionObject) -> Self {
+        self.initial_option = Some(option);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: Confirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A menu of the users in the workspace.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersSelect {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub initial_user: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl UsersSelect {
+    pub fn new(action_id: &str) -> Self {
+        UsersSelect {
+            placeholder: None,
+            action_id: action_id.to_string(),
+            initial_user: String::new(),
+            confirm: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_user(mut self, user_id: &str) -> Self {
+        self.initial_user = user_id.to_string();
+        self
+    }
+}
+
+/// A menu of the conversations the user can see.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsSelect {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub initial_conversation: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub default_to_current_conversation: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl ConversationsSelect {
+    pub fn new(action_id: &str) -> Self {
+        ConversationsSelect {
+            placeholder: None,
+            action_id: action_id.to_string(),
+            initial_conversation: String::new(),
+            default_to_current_conversation: false,
+            confirm: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_conversation(mut self, conversation_id: &str) -> Self {
+        self.initial_conversation = conversation_id.to_string();
+        self
+    }
+
+    pub fn default_to_current_conversation(mut self, default: bool) -> Self {
+        self.default_to_current_conversation = default;
+        self
+    }
+}
+
+/// A menu of the public channels in the workspace.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChannelsSelect {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub initial_channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl ChannelsSelect {
+    pub fn new(action_id: &str) -> Self {
+        ChannelsSelect {
+            placeholder: None,
+            action_id: action_id.to_string(),
+            initial_channel: String::new(),
+            confirm: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_channel(mut self, channel_id: &str) -> Self {
+        self.initial_channel = channel_id.to_string();
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Datepicker {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl Datepicker {
+    pub fn new(action_id: &str) -> Self {
+        Datepicker {
+            placeholder: None,
+            action_id: action_id.to_string(),
+            initial_date: None,
+            confirm: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_date(mut self, date: chrono::NaiveDate) -> Self {
+        self.initial_date = Some(date);
+        self
+    }
+}
+
+/// A free text field, only allowed in input blocks.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PlainTextInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub initial_value: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multiline: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+impl PlainTextInput {
+    pub fn new(action_id: &str) -> Self {
+        PlainTextInput {
+            placeholder: None,
+            action_id: action_id.to_string(),
+            initial_value: String::new(),
+            multiline: false,
+            min_length: None,
+            max_length: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn initial_value(mut self, value: &str) -> Self {
+        self.initial_value = value.to_string();
+        self
+    }
+
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    pub fn length(mut self, min: Option<u32>, max: Option<u32>) -> Self {
+        self.min_length = min;
+        self.max_length = max;
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Checkboxes {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub initial_options: Vec<OptionObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl Checkboxes {
+    pub fn new(action_id: &str, options: Vec<OptionObject>) -> Self {
+        Checkboxes {
+            action_id: action_id.to_string(),
+            options,
+            initial_options: Vec::new(),
+            confirm: None,
+        }
+    }
+
+    pub fn initial_options(mut self, options: Vec<OptionObject>) -> Self {
+        self.initial_options = options;
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RadioButtons {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl RadioButtons {
+    pub fn new(action_id: &str, options: Vec<OptionObject>) -> Self {
+        RadioButtons {
+            action_id: action_id.to_string(),
+            options,
+            initial_option: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_option(mut self, option: OptionObject) -> Self {
+        self.initial_option = Some(option);
+        self
+    }
+}
+
+/// A `...` menu of 2 to 5 options.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Overflow {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl Overflow {
+    pub fn new(action_id: &str, options: Vec<OptionObject>) -> Self {
+        Overflow {
+            action_id: action_id.to_string(),
+            options,
+            confirm: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Image {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+impl Image {
+    pub fn new(image_url: &str, alt_text: &str) -> Self {
+        Image {
+            image_url: image_url.to_string(),
+            alt_text: alt_text.to_string(),
+        }
+    }
+
+    fn validate(&self) -> ClientResult<()> {
+        check_len("image_url", &self.image_url, 3000)?;
+        check_len("image alt_text", &self.alt_text, 2000)
+    }
+}
+
+/// An option in a select menu, overflow menu, checkboxes or radio buttons.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OptionObject {
+    pub text: Text,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+}
+
+impl OptionObject {
+    pub fn new(text: &str, value: &str) -> Self {
+        OptionObject {
+            text: Text::plain(text),
+            value: value.to_string(),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(Text::plain(description));
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OptionGroup {
+    pub label: Text,
+    pub options: Vec<OptionObject>,
+}
+
+impl OptionGroup {
+    pub fn new(label: &str, options: Vec<OptionObject>) -> Self {
+        OptionGroup {
+            label: Text::plain(label),
+            options,
+        }
+    }
+}
+
+/// A dialog asking the user to confirm before an element's action is sent.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Confirm {
+    pub title: Text,
+    pub text: Text,
+    pub confirm: Text,
+    pub deny: Text,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl Confirm {
+    pub fn new(title: &str, text: &str, confirm: &str, deny: &str) -> Self {
+        Confirm {
+            title: Text::plain(title),
+            text: Text::plain(text),
+            confirm: Text::plain(confirm),
+            deny: Text::plain(deny),
+            style: None,
+        }
+    }
+
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+/// The kind of surface a view is shown on.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewType {
+    Modal,
+    Home,
+}
+
+/// A modal or app home view, for `views.open`, `views.push`, `views.update` and `views.publish`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct View {
+    #[serde(rename = "type")]
+    pub type_: ViewType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submit: Option<Text>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close: Option<Text>,
+    pub blocks: Vec<Block>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub private_metadata: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub callback_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub external_id: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub clear_on_close: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub notify_on_close: bool,
+}
+
+impl View {
+    pub fn modal(title: &str) -> Self {
+        View {
+            type_: ViewType::Modal,
+            title: Some(Text::plain(title)),
+            ..View::home()
+        }
+    }
+
+    pub fn home() -> Self {
+        View {
+            type_: ViewType::Home,
+            title: None,
+            submit: None,
+            close: None,
+            blocks: Vec::new(),
+            private_metadata: String::new(),
+            callback_id: String::new(),
+            external_id: String::new(),
+            clear_on_close: false,
+            notify_on_close: false,
+        }
+    }
+
+    pub fn block(mut self, block: impl Into<Block>) -> Self {
+        self.blocks.push(block.into());
+        self
+    }
+
+    pub fn submit(mut self, submit: &str) -> Self {
+        self.submit = Some(Text::plain(submit));
+        self
+    }
+
+    pub fn close(mut self, close: &str) -> Self {
+        self.close = Some(Text::plain(close));
+        self
+    }
+
+    /// Sent back in `view_submission` and `block_actions` payloads.
+    pub fn private_metadata(mut self, private_metadata: &str) -> Self {
+        self.private_metadata = private_metadata.to_string();
+        self
+    }
+
+    pub fn callback_id(mut self, callback_id: &str) -> Self {
+        self.callback_id = callback_id.to_string();
+        self
+    }
+
+    pub fn external_id(mut self, external_id: &str) -> Self {
+        self.external_id = external_id.to_string();
+        self
+    }
+
+    /// Send a `view_closed` payload when the user closes the modal.
+    pub fn notify_on_close(mut self, notify_on_close: bool) -> Self {
+        self.notify_on_close = notify_on_close;
+        self
+    }
+
+    /// Check the view and its blocks against the limits Slack documents for them.
+    pub fn validate(&self) -> ClientResult<()> {
+        if self.type_ == ViewType::Modal {
+            match &self.title {
+                Some(title) => check_plain("view title", title, 24)?,
+                None => return Err(invalid("modals need a title")),
+            }
+            let has_inputs = self.blocks.iter().any(|b| matches!(b, Block::Input(_)));
+            if has_inputs && self.submit.is_none() {
+                return Err(invalid("modals with input blocks need a submit button"));
+            }
+        }
+        if let Some(submit) = &self.submit {
+            check_plain("view submit", submit, 24)?;
+        }
+        if let Some(close) = &self.close {
+            check_plain("view close", close, 24)?;
+        }
+        check_count("view blocks", self.blocks.len(), MAX_VIEW_BLOCKS)?;
+        for block in &self.blocks {
+            block.validate()?;
+        }
+        check_len("view private_metadata", &self.private_metadata, 3000)?;
+        check_id("callback_id", &self.callback_id)?;
+        check_id("external_id", &self.external_id)
+    }
+
+    /// Validate the view and serialize it for the `view` argument of the `views` methods.
+    pub fn to_json(&self) -> ClientResult<String> {
+        self.validate()?;
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+fn invalid(reason: &str) -> ClientError {
+    ClientError::InvalidBlocks(reason.to_string())
+}
+
+fn check_len(what: &str, s: &str, max: usize) -> ClientResult<()> {
+    if s.chars().count() > max {
+        return Err(ClientError::InvalidBlocks(format!(
+            "{} is longer than {} characters",
+            what, max
+        )));
+    }
+    Ok(())
+}
+
+fn check_count(what: &str, count: usize, max: usize) -> ClientResult<()> {
+    if count > max {
+        return Err(ClientError::InvalidBlocks(format!(
+            "{} has {} items, the most allowed is {}",
+            what, count, max
+        )));
+    }
+    Ok(())
+}
+
+fn check_plain(what: &str, text: &Text, max: usize) -> ClientResult<()> {
+    if let Text::Mrkdwn { .. } = text {
+        return Err(ClientError::InvalidBlocks(format!(
+            "{} must be plain_text",
+            what
+        )));
+    }
+    check_len(what, text.text(), max)
+}
+
+fn check_id(what: &str, id: &str) -> ClientResult<()> {
+    check_len(what, id, 255)
+}
+
+fn check_placeholder(placeholder: &Option<Text>) -> ClientResult<()> {
+    match placeholder {
+        Some(placeholder) => check_plain("placeholder", placeholder, 150),
+        None => Ok(()),
+    }
+}
+
+fn check_options(options: &[OptionObject]) -> ClientResult<()> {
+    for option in options {
+        check_len("option text", option.text.text(), 75)?;
+        check_len("option value", &option.value, 150)?;
+        if let Some(description) = &option.description {
+            check_plain("option description", description, 75)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_confirm(confirm: &Option<Confirm>) -> ClientResult<()> {
+    if let Some(confirm) = confirm {
+        check_plain("confirm title", &confirm.title, 100)?;
+        check_len("confirm text", confirm.text.text(), 300)?;
+        check_plain("confirm button", &confirm.confirm, 30)?;
+        check_plain("deny button", &confirm.deny, 30)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_message_blocks() {
+        let blocks = message_blocks(&[
+            Header::new("Deploy").into(),
+            Section::new(Text::mrkdwn("*v1.2.3* is ready"))
+                .accessory(Image::new("https://example.com/logo.png", "logo"))
+                .into(),
+            Actions::new(vec![
+                Button::new("Deploy", "deploy")
+                    .value("v1.2.3")
+                    .style(ButtonStyle::Primary)
+                    .into(),
+                Overflow::new(
+                    "more",
+                    vec![
+                        OptionObject::new("Logs", "logs"),
+                        OptionObject::new("Cancel", "cancel"),
+                    ],
+                )
+                .into(),
+            ])
+            .block_id("deploy-actions")
+            .into(),
+            Context::new(vec![Text::mrkdwn("Requested by <@U123>").into()]).into(),
+            Divider::default().into(),
+        ])
+        .unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&blocks).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {"type": "header", "text": {"type": "plain_text", "text": "Deploy", "emoji": true}},
+                {
+                    "type": "section",
+                    "text": {"type": "mrkdwn", "text": "*v1.2.3* is ready"},
+                    "accessory": {"type": "image", "image_url": "https://example.com/logo.png", "alt_text": "logo"}
+                },
+                {
+                    "type": "actions",
+                    "block_id": "deploy-actions",
+                    "elements": [
+                        {
+                            "type": "button",
+                            "text": {"type": "plain_text", "text": "Deploy", "emoji": true},
+                            "action_id": "deploy",
+                            "value": "v1.2.3",
+                            "style": "primary"
+                        },
+                        {
+                            "type": "overflow",
+                            "action_id": "more",
+                            "options": [
+                                {"text": {"type": "plain_text", "text": "Logs", "emoji": true}, "value": "logs"},
+                                {"text": {"type": "plain_text", "text": "Cancel", "emoji": true}, "value": "cancel"}
+                            ]
+                        }
+                    ]
+                },
+                {"type": "context", "elements": [{"type": "mrkdwn", "text": "Requested by <@U123>"}]},
+                {"type": "divider"}
+            ])
+        );
+
+        // Blocks round trip, so they can be read back from messages.
+        let parsed: Vec<Block> = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.len(), 5);
+        assert!(matches!(parsed[2], Block::Actions(_)));
+    }
+
+    #[test]
+    fn serialize_modal() {
+        let view = View::modal("Feedback")
+            .submit("Send")
+            .callback_id("feedback")
+            .block(
+                Input::new(
+                    "What went well?",
+                    PlainTextInput::new("answer").multiline(true),
+                )
+                .block_id("went-well"),
+            )
+            .block(Input::new("Date", Datepicker::new("date")).optional(true))
+            .to_json()
+            .unwrap();
+
+        let value: serde_json::Value = serde_json::from_str(&view).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "type": "modal",
+                "title": {"type": "plain_text", "text": "Feedback", "emoji": true},
+                "submit": {"type": "plain_text", "text": "Send", "emoji": true},
+                "callback_id": "feedback",
+                "blocks": [
+                    {
+                        "type": "input",
+                        "block_id": "went-well",
+                        "label": {"type": "plain_text", "text": "What went well?", "emoji": true},
+                        "element": {"type": "plain_text_input", "action_id": "answer", "multiline": true}
+                    },
+                    {
+                        "type": "input",
+                        "optional": true,
+                        "label": {"type": "plain_text", "text": "Date", "emoji": true},
+                        "element": {"type": "datepicker", "action_id": "date"}
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn validate_limits() {
+        let section: Block = Section::new(Text::plain("hi")).into();
+        let invalid_messages = [
+            vec![section.clone(); MAX_MESSAGE_BLOCKS + 1],
+            vec![Section::new(Text::mrkdwn(&"a".repeat(3001))).into()],
+            vec![Section::with_fields(vec![Text::plain("a"); 11]).into()],
+            vec![Section::default().into()],
+            vec![Header::new(&"a".repeat(151)).into()],
+            vec![Header {
+                text: Text::mrkdwn("*bold*"),
+                block_id: String::new(),
+            }
+            .into()],
+            vec![Actions::new(vec![]).into()],
+            vec![Actions::new(vec![Button::new(&"a".repeat(76), "a").into()]).into()],
+            vec![Actions::new(vec![Button::new("a", &"a".repeat(256)).into()]).into()],
+            vec![Actions::new(vec![
+                Overflow::new("a", vec![OptionObject::new("a", "a")]).into()
+            ])
+            .into()],
+            vec![Context::new(vec![Text::plain("a").into(); 11]).into()],
+        ];
+        for blocks in invalid_messages {
+            assert!(
+                matches!(message_blocks(&blocks), Err(ClientError::InvalidBlocks(_))),
+                "{:?} should not validate",
+                blocks
+            );
+        }
+        message_blocks(&vec![section.clone(); MAX_MESSAGE_BLOCKS]).unwrap();
+
+        let invalid_views = [
+            View::modal(&"a".repeat(25)),
+            View::modal("a").block(Input::new("a", PlainTextInput::new("a"))),
+            View::modal("a")
+                .submit("a")
+                .block(Input::new("a", Button::new("a", "a"))),
+            View::home()
+                .block(section.clone())
+                .private_metadata(&"a".repeat(3001)),
+        ];
+        for view in invalid_views {
+            assert!(
+                matches!(view.to_json(), Err(ClientError::InvalidBlocks(_))),
+                "{:?} should not validate",
+                view
+            );
+        }
+        let mut home = View::home();
+        home.blocks = vec![section; MAX_VIEW_BLOCKS];
+        home.to_json().unwrap();
+    }
+}
@@ -24,6 +24,19 @@ impl Bots {
      * * `bot: &str` -- Bot user to get info on.
      */
     pub async fn info(&self, bot: &str) -> ClientResult<crate::types::BotsInfoSchema> {
+        Ok(self.info_with_response(bot).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/bots.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/bots.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        bot: &str,
+    ) -> ClientResult<crate::Response<crate::types::BotsInfoSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !bot.is_empty() {
             query_args.push(("bot".to_string(), bot.to_string()));
@@ -31,7 +44,7 @@ impl Bots {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/bots.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
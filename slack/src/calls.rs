@@ -22,13 +22,29 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/calls.add` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/calls.add>
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/calls.add", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn end(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn end(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.end_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/calls.end` endpoint.
+     *
+     * As opposed to `end`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/calls.end>
+     */
+    pub async fn end_with_response(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/calls.end", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -70,6 +102,19 @@ impl Calls {
      * * `id: &str` -- `id` of the Call returned by the [`calls.add`](/methods/calls.add) method.
      */
     pub async fn info(&self, id: &str) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.info_with_response(id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/calls.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/calls.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !id.is_empty() {
             query_args.push(("id".to_string(), id.to_string()));
@@ -77,7 +122,7 @@ impl Calls {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/calls.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -97,13 +142,29 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.update_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/calls.update` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/calls.update>
+     */
+    pub async fn update_with_response(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/calls.update", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -22,13 +22,29 @@ impl CallsParticipants {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/calls.participants.add` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/calls.participants.add>
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/calls.participants.add", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl CallsParticipants {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/calls.participants.remove` endpoint.
+     *
+     * As opposed to `remove`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/calls.participants.remove>
+     */
+    pub async fn remove_with_response(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/calls.participants.remove", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -22,13 +22,29 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::ChatDeleteSuccessSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> ClientResult<crate::types::ChatDeleteSuccessSchema> {
+        Ok(self.delete_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.delete` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.delete>
+     */
+    pub async fn delete_with_response(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatDeleteSuccessSchema>> {
         let url = self.client.url("/chat.delete", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,32 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete_scheduled_message(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete_scheduled_message(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .delete_scheduled_message_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.deleteScheduledMessage` endpoint.
+     *
+     * As opposed to `delete_scheduled_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.deleteScheduledMessage>
+     */
+    pub async fn delete_scheduled_message_with_response(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/chat.deleteScheduledMessage", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -75,6 +110,23 @@ impl Chat {
         channel: &str,
         message_ts: &str,
     ) -> ClientResult<crate::types::ChatGetPermalinkSuccessSchema> {
+        Ok(self
+            .get_permalink_with_response(channel, message_ts)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/chat.getPermalink` endpoint.
+     *
+     * As opposed to `get_permalink`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.getPermalink>
+     */
+    pub async fn get_permalink_with_response(
+        &self,
+        channel: &str,
+        message_ts: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChatGetPermalinkSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -87,7 +139,7 @@ impl Chat {
             .client
             .url(&format!("/chat.getPermalink?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -107,13 +159,29 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn me_message(&self) -> ClientResult<crate::types::ChatMeMessageSchema> {
+    pub async fn me_message(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> ClientResult<crate::types::ChatMeMessageSchema> {
+        Ok(self.me_message_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.meMessage` endpoint.
+     *
+     * As opposed to `me_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.meMessage>
+     */
+    pub async fn me_message_with_response(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatMeMessageSchema>> {
         let url = self.client.url("/chat.meMessage", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -132,13 +200,27 @@ impl Chat {
      */
     pub async fn post_ephemeral(
         &self,
+        body: &crate::types::ChatPostEphemeralRequest,
     ) -> ClientResult<crate::types::ChatPostEphemeralSuccessSchema> {
+        Ok(self.post_ephemeral_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.postEphemeral` endpoint.
+     *
+     * As opposed to `post_ephemeral`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.postEphemeral>
+     */
+    pub async fn post_ephemeral_with_response(
+        &self,
+        body: &crate::types::ChatPostEphemeralRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatPostEphemeralSuccessSchema>> {
         let url = self.client.url("/chat.postEphemeral", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -155,13 +237,29 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn post_message(&self) -> ClientResult<crate::types::ChatPostMessageSuccessSchema> {
+    pub async fn post_message(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> ClientResult<crate::types::ChatPostMessageSuccessSchema> {
+        Ok(self.post_message_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.postMessage` endpoint.
+     *
+     * As opposed to `post_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.postMessage>
+     */
+    pub async fn post_message_with_response(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatPostMessageSuccessSchema>> {
         let url = self.client.url("/chat.postMessage", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -180,13 +278,27 @@ impl Chat {
      */
     pub async fn schedule_message(
         &self,
+        body: &crate::types::ChatScheduleMessageRequest,
     ) -> ClientResult<crate::types::ChatScheduleMessageSuccessSchema> {
+        Ok(self.schedule_message_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.scheduleMessage` endpoint.
+     *
+     * As opposed to `schedule_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduleMessage>
+     */
+    pub async fn schedule_message_with_response(
+        &self,
+        body: &crate::types::ChatScheduleMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatScheduleMessageSuccessSchema>> {
         let url = self.client.url("/chat.scheduleMessage", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -203,13 +315,29 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `links:write`.
      */
-    pub async fn unfurl(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.unfurl_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.unfurl` endpoint.
+     *
+     * As opposed to `unfurl`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.unfurl>
+     */
+    pub async fn unfurl_with_response(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/chat.unfurl", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -226,13 +354,29 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::ChatUpdateSuccessSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> ClientResult<crate::types::ChatUpdateSuccessSchema> {
+        Ok(self.update_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/chat.update` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.update>
+     */
+    pub async fn update_with_response(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::ChatUpdateSuccessSchema>> {
         let url = self.client.url("/chat.update", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
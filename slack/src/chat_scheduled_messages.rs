@@ -35,6 +35,26 @@ impl ChatScheduledMessages {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::ChatScheduledMessagesListSchema> {
+        Ok(self
+            .list_with_response(channel, latest, oldest, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduledMessages.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ChatScheduledMessagesListSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -56,7 +76,7 @@ impl ChatScheduledMessages {
             .client
             .url(&format!("/chat.scheduledMessages.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -22,13 +22,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn archive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.archive_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.archive` endpoint.
+     *
+     * As opposed to `archive`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.archive>
+     */
+    pub async fn archive_with_response(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.archive", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn close(&self) -> ClientResult<crate::types::ConversationsCloseSuccessSchema> {
+    pub async fn close(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::ConversationsCloseSuccessSchema> {
+        Ok(self.close_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.close` endpoint.
+     *
+     * As opposed to `close`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.close>
+     */
+    pub async fn close_with_response(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsCloseSuccessSchema>> {
         let url = self.client.url("/conversations.close", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -68,13 +100,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.create` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.create>
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.create", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -106,6 +154,27 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::ConversationsHistorySuccessSchema> {
+        Ok(self
+            .history_with_response(channel, latest, oldest, inclusive, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.history` endpoint.
+     *
+     * As opposed to `history`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.history>
+     */
+    pub async fn history_with_response(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsHistorySuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -130,7 +199,7 @@ impl Conversations {
             .client
             .url(&format!("/conversations.history?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -159,6 +228,24 @@ impl Conversations {
         include_locale: bool,
         include_num_members: bool,
     ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+        Ok(self
+            .info_with_response(channel, include_locale, include_num_members)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        channel: &str,
+        include_locale: bool,
+        include_num_members: bool,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -177,7 +264,7 @@ impl Conversations {
             .client
             .url(&format!("/conversations.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -197,13 +284,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn invite(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+        Ok(self.invite_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.invite` endpoint.
+     *
+     * As opposed to `invite`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.invite>
+     */
+    pub async fn invite_with_response(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.invite", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -220,13 +323,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `channels:write`.
      */
-    pub async fn join(&self) -> ClientResult<crate::types::ConversationsJoinSuccessSchema> {
+    pub async fn join(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::ConversationsJoinSuccessSchema> {
+        Ok(self.join_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.join` endpoint.
+     *
+     * As opposed to `join`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.join>
+     */
+    pub async fn join_with_response(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsJoinSuccessSchema>> {
         let url = self.client.url("/conversations.join", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -243,13 +362,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn kick(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.kick_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.kick` endpoint.
+     *
+     * As opposed to `kick`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.kick>
+     */
+    pub async fn kick_with_response(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.kick", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -266,13 +401,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn leave(&self) -> ClientResult<crate::types::ConversationsLeaveSuccessSchema> {
+    pub async fn leave(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::ConversationsLeaveSuccessSchema> {
+        Ok(self.leave_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.leave` endpoint.
+     *
+     * As opposed to `leave`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.leave>
+     */
+    pub async fn leave_with_response(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsLeaveSuccessSchema>> {
         let url = self.client.url("/conversations.leave", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -300,6 +451,25 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::ConversationsListSuccessSchema> {
+        Ok(self
+            .list_with_response(exclude_archived, types, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsListSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -318,7 +488,7 @@ impl Conversations {
             .client
             .url(&format!("/conversations.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -338,13 +508,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn mark(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.mark_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.mark` endpoint.
+     *
+     * As opposed to `mark`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.mark>
+     */
+    pub async fn mark_with_response(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.mark", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -370,6 +556,24 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::ConversationsMembersSuccessSchema> {
+        Ok(self
+            .member_with_response(channel, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.members` endpoint.
+     *
+     * As opposed to `member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.members>
+     */
+    pub async fn member_with_response(
+        &self,
+        channel: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsMembersSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -385,7 +589,7 @@ impl Conversations {
             .client
             .url(&format!("/conversations.members?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -405,13 +609,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn open(&self) -> ClientResult<crate::types::ConversationsOpenSuccessSchema> {
+    pub async fn open(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> ClientResult<crate::types::ConversationsOpenSuccessSchema> {
+        Ok(self.open_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.open` endpoint.
+     *
+     * As opposed to `open`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.open>
+     */
+    pub async fn open_with_response(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsOpenSuccessSchema>> {
         let url = self.client.url("/conversations.open", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -428,13 +648,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn rename(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+        Ok(self.rename_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.rename` endpoint.
+     *
+     * As opposed to `rename`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.rename>
+     */
+    pub async fn rename_with_response(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.rename", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -468,6 +704,28 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::ConversationsRepliesSuccessSchema> {
+        Ok(self
+            .replie_with_response(channel, ts, latest, oldest, inclusive, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/conversations.replies` endpoint.
+     *
+     * As opposed to `replie`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.replies>
+     */
+    pub async fn replie_with_response(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsRepliesSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -495,7 +753,7 @@ impl Conversations {
             .client
             .url(&format!("/conversations.replies?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -515,13 +773,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_purpose(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_purpose(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+        Ok(self.set_purpose_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.setPurpose` endpoint.
+     *
+     * As opposed to `set_purpose`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.setPurpose>
+     */
+    pub async fn set_purpose_with_response(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.setPurpose", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -538,13 +812,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_topic(&self) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_topic(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> ClientResult<crate::types::ConversationsInfoSuccessSchema> {
+        Ok(self.set_topic_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.setTopic` endpoint.
+     *
+     * As opposed to `set_topic`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.setTopic>
+     */
+    pub async fn set_topic_with_response(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> ClientResult<crate::Response<crate::types::ConversationsInfoSuccessSchema>> {
         let url = self.client.url("/conversations.setTopic", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -561,13 +851,29 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn unarchive(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.unarchive_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/conversations.unarchive` endpoint.
+     *
+     * As opposed to `unarchive`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.unarchive>
+     */
+    pub async fn unarchive_with_response(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/conversations.unarchive", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -29,6 +29,20 @@ impl Dialog {
         dialog: &str,
         trigger_id: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.open_with_response(dialog, trigger_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/dialog.open` endpoint.
+     *
+     * As opposed to `open`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/dialog.open>
+     */
+    pub async fn open_with_response(
+        &self,
+        dialog: &str,
+        trigger_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !dialog.is_empty() {
             query_args.push(("dialog".to_string(), dialog.to_string()));
@@ -39,7 +53,7 @@ impl Dialog {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/dialog.open?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
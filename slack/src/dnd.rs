@@ -23,9 +23,21 @@ impl Dnd {
      * * `token: &str` -- Authentication token. Requires scope: `dnd:write`.
      */
     pub async fn end(&self) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.end_with_response().await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/dnd.endDnd` endpoint.
+     *
+     * As opposed to `end`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/dnd.endDnd>
+     */
+    pub async fn end_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/dnd.endDnd", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -46,9 +58,21 @@ impl Dnd {
      * * `token: &str` -- Authentication token. Requires scope: `dnd:write`.
      */
     pub async fn end_snooze(&self) -> ClientResult<crate::types::DndEndSnoozeSchema> {
+        Ok(self.end_snooze_with_response().await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/dnd.endSnooze` endpoint.
+     *
+     * As opposed to `end_snooze`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/dnd.endSnooze>
+     */
+    pub async fn end_snooze_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSnoozeSchema>> {
         let url = self.client.url("/dnd.endSnooze", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,6 +94,19 @@ impl Dnd {
      * * `user: &str` -- User to fetch status for (defaults to current user).
      */
     pub async fn info(&self, user: &str) -> ClientResult<crate::types::DndInfoSchema> {
+        Ok(self.info_with_response(user).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/dnd.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/dnd.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndInfoSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !user.is_empty() {
             query_args.push(("user".to_string(), user.to_string()));
@@ -77,7 +114,7 @@ impl Dnd {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/dnd.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -93,13 +130,29 @@ impl Dnd {
      *
      * FROM: <https://api.slack.com/methods/dnd.setSnooze>
      */
-    pub async fn set_snooze(&self) -> ClientResult<crate::types::DndSetSnoozeSchema> {
+    pub async fn set_snooze(
+        &self,
+        body: &crate::types::DndSetSnoozeRequest,
+    ) -> ClientResult<crate::types::DndSetSnoozeSchema> {
+        Ok(self.set_snooze_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/dnd.setSnooze` endpoint.
+     *
+     * As opposed to `set_snooze`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/dnd.setSnooze>
+     */
+    pub async fn set_snooze_with_response(
+        &self,
+        body: &crate::types::DndSetSnoozeRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndSetSnoozeSchema>> {
         let url = self.client.url("/dnd.setSnooze", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -118,6 +171,19 @@ impl Dnd {
      * * `users: &str` -- Comma-separated list of users to fetch Do Not Disturb status for.
      */
     pub async fn team_info(&self, users: &str) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.team_info_with_response(users).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/dnd.teamInfo` endpoint.
+     *
+     * As opposed to `team_info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/dnd.teamInfo>
+     */
+    pub async fn team_info_with_response(
+        &self,
+        users: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !users.is_empty() {
             query_args.push(("users".to_string(), users.to_string()));
@@ -125,7 +191,7 @@ impl Dnd {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/dnd.teamInfo?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -23,9 +23,21 @@ impl Emoji {
      * * `token: &str` -- Authentication token. Requires scope: `emoji:read`.
      */
     pub async fn list(&self) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.list_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/emoji.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/emoji.list>
+     */
+    pub async fn list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/emoji.list", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
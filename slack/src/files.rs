@@ -22,13 +22,29 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.delete_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.delete` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.delete>
+     */
+    pub async fn delete_with_response(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.delete", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -58,6 +74,26 @@ impl Files {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::FilesInfoSchema> {
+        Ok(self
+            .info_with_response(file, count, page, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        file: &str,
+        count: &str,
+        page: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::FilesInfoSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !count.is_empty() {
             query_args.push(("count".to_string(), count.to_string()));
@@ -77,7 +113,7 @@ impl Files {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -116,6 +152,38 @@ impl Files {
         page: &str,
         show_files_hidden_by_limit: bool,
     ) -> ClientResult<crate::types::FilesListSchema> {
+        Ok(self
+            .list_with_response(
+                user,
+                channel,
+                ts_from,
+                ts_to,
+                types,
+                count,
+                page,
+                show_files_hidden_by_limit,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        user: &str,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        types: &str,
+        count: &str,
+        page: &str,
+        show_files_hidden_by_limit: bool,
+    ) -> ClientResult<crate::Response<crate::types::FilesListSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -147,7 +215,7 @@ impl Files {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/files.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -167,13 +235,29 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn revoke_public_url(&self) -> ClientResult<crate::types::FilesUploadSchema> {
+    pub async fn revoke_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::types::FilesUploadSchema> {
+        Ok(self.revoke_public_url_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.revokePublicURL` endpoint.
+     *
+     * As opposed to `revoke_public_url`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.revokePublicURL>
+     */
+    pub async fn revoke_public_url_with_response(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
         let url = self.client.url("/files.revokePublicURL", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -190,13 +274,29 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn shared_public_url(&self) -> ClientResult<crate::types::FilesUploadSchema> {
+    pub async fn shared_public_url(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::types::FilesUploadSchema> {
+        Ok(self.shared_public_url_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.sharedPublicURL` endpoint.
+     *
+     * As opposed to `shared_public_url`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.sharedPublicURL>
+     */
+    pub async fn shared_public_url_with_response(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
         let url = self.client.url("/files.sharedPublicURL", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -208,17 +308,32 @@ impl Files {
      * Uploads or creates a file.
      *
      * FROM: <https://api.slack.com/methods/files.upload>
+     *
+     * **Parameters:**
+     *
+     * * `file: Option<&[u8]>` -- The file contents, sent as `multipart/form-data`.
+     */
+    pub async fn upload(
+        &self,
+        file: Option<&[u8]>,
+        body: &crate::types::FilesUploadRequest,
+    ) -> ClientResult<crate::types::FilesUploadSchema> {
+        Ok(self.upload_with_response(file, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.upload` endpoint.
+     *
+     * As opposed to `upload`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.upload>
      */
-    pub async fn upload(&self) -> ClientResult<crate::types::FilesUploadSchema> {
+    pub async fn upload_with_response(
+        &self,
+        file: Option<&[u8]>,
+        body: &crate::types::FilesUploadRequest,
+    ) -> ClientResult<crate::Response<crate::types::FilesUploadSchema>> {
         let url = self.client.url("/files.upload", None);
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("application/x-www-form-urlencoded".to_string()),
-                },
-            )
-            .await
+        let form = crate::utils::multipart_form(body, file)?;
+        self.client.post_form_with_response(&url, form).await
     }
 }
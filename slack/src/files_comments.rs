@@ -22,13 +22,29 @@ impl FilesComments {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::FilesCommentsDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.delete_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.comments.delete` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.comments.delete>
+     */
+    pub async fn delete_with_response(
+        &self,
+        body: &crate::types::FilesCommentsDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.comments.delete", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,13 +18,29 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.add>
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.remote.add` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.add>
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.remote.add", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -48,6 +64,20 @@ impl FilesRemote {
         file: &str,
         external_id: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.info_with_response(file, external_id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/files.remote.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        file: &str,
+        external_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !external_id.is_empty() {
             query_args.push(("external_id".to_string(), external_id.to_string()));
@@ -60,7 +90,7 @@ impl FilesRemote {
             .client
             .url(&format!("/files.remote.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -93,6 +123,26 @@ impl FilesRemote {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .list_with_response(channel, ts_from, ts_to, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files.remote.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -114,7 +164,7 @@ impl FilesRemote {
             .client
             .url(&format!("/files.remote.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,13 +180,29 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.remove>
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.remote.remove` endpoint.
+     *
+     * As opposed to `remove`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.remove>
+     */
+    pub async fn remove_with_response(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.remote.remove", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -162,6 +228,24 @@ impl FilesRemote {
         external_id: &str,
         channels: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .share_with_response(file, external_id, channels)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/files.remote.share` endpoint.
+     *
+     * As opposed to `share`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.share>
+     */
+    pub async fn share_with_response(
+        &self,
+        file: &str,
+        external_id: &str,
+        channels: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channels.is_empty() {
             query_args.push(("channels".to_string(), channels.to_string()));
@@ -177,7 +261,7 @@ impl FilesRemote {
             .client
             .url(&format!("/files.remote.share?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -193,13 +277,29 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.update>
      */
-    pub async fn update(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.update_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/files.remote.update` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/files.remote.update>
+     */
+    pub async fn update_with_response(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/files.remote.update", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
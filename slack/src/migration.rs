@@ -31,6 +31,24 @@ impl Migration {
         team_id: &str,
         to_old: bool,
     ) -> ClientResult<crate::types::MigrationExchangeSuccessSchema> {
+        Ok(self
+            .exchange_with_response(users, team_id, to_old)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/migration.exchange` endpoint.
+     *
+     * As opposed to `exchange`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/migration.exchange>
+     */
+    pub async fn exchange_with_response(
+        &self,
+        users: &str,
+        team_id: &str,
+        to_old: bool,
+    ) -> ClientResult<crate::Response<crate::types::MigrationExchangeSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !team_id.is_empty() {
             query_args.push(("team_id".to_string(), team_id.to_string()));
@@ -46,7 +64,7 @@ impl Migration {
             .client
             .url(&format!("/migration.exchange?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
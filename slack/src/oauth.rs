@@ -34,6 +34,26 @@ impl Oauth {
         redirect_uri: &str,
         single_channel: bool,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .access_with_response(client_id, client_secret, code, redirect_uri, single_channel)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/oauth.access` endpoint.
+     *
+     * As opposed to `access`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/oauth.access>
+     */
+    pub async fn access_with_response(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -53,7 +73,7 @@ impl Oauth {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/oauth.access?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -85,6 +105,26 @@ impl Oauth {
         redirect_uri: &str,
         single_channel: bool,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .token_with_response(client_id, client_secret, code, redirect_uri, single_channel)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/oauth.token` endpoint.
+     *
+     * As opposed to `token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/oauth.token>
+     */
+    pub async fn token_with_response(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -104,7 +144,7 @@ impl Oauth {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/oauth.token?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
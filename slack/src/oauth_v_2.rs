@@ -32,6 +32,25 @@ impl OauthV2 {
         code: &str,
         redirect_uri: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .oauth_access_with_response(client_id, client_secret, code, redirect_uri)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/oauth.v2.access` endpoint.
+     *
+     * As opposed to `oauth_access`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/oauth.v2.access>
+     */
+    pub async fn oauth_access_with_response(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_id.is_empty() {
             query_args.push(("client_id".to_string(), client_id.to_string()));
@@ -50,7 +69,7 @@ impl OauthV2 {
             .client
             .url(&format!("/oauth.v2.access?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
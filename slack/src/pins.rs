@@ -22,13 +22,29 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/pins.add` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/pins.add>
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/pins.add", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -50,6 +66,19 @@ impl Pins {
         &self,
         channel: &str,
     ) -> ClientResult<Vec<crate::types::PinsListResponseAnyOf>> {
+        Ok(self.list_with_response(channel).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/pins.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/pins.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        channel: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PinsListResponseAnyOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -57,7 +86,7 @@ impl Pins {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/pins.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -95,6 +124,75 @@ impl Pins {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/pins.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://api.slack.com/methods/pins.list>
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        channel: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::PinsListResponseAnyOf>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/pins.list?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::PinsListResponseAnyOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/pins.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://api.slack.com/methods/pins.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        channel: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PinsListResponseAnyOf>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pages_stream(channel, None))
+    }
     /**
      * This function performs a `POST` to the `/pins.remove` endpoint.
      *
@@ -106,13 +204,29 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/pins.remove` endpoint.
+     *
+     * As opposed to `remove`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/pins.remove>
+     */
+    pub async fn remove_with_response(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/pins.remove", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
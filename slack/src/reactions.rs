@@ -22,13 +22,29 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/reactions.add` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.add>
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reactions.add", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -58,6 +74,26 @@ impl Reactions {
         full: bool,
         timestamp: &str,
     ) -> ClientResult<crate::types::Fields> {
+        Ok(self
+            .get_with_response(channel, file, file_comment, full, timestamp)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/reactions.get` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.get>
+     */
+    pub async fn get_with_response(
+        &self,
+        channel: &str,
+        file: &str,
+        file_comment: &str,
+        full: bool,
+        timestamp: &str,
+    ) -> ClientResult<crate::Response<crate::types::Fields>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -77,7 +113,7 @@ impl Reactions {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/reactions.get?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -112,6 +148,27 @@ impl Reactions {
         cursor: &str,
         limit: i64,
     ) -> ClientResult<crate::types::ReactionsListSchema> {
+        Ok(self
+            .list_with_response(user, full, count, page, cursor, limit)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/reactions.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+        page: i64,
+        cursor: &str,
+        limit: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReactionsListSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -136,7 +193,7 @@ impl Reactions {
             .client
             .url(&format!("/reactions.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -156,13 +213,29 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn remove(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.remove_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/reactions.remove` endpoint.
+     *
+     * As opposed to `remove`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.remove>
+     */
+    pub async fn remove_with_response(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reactions.remove", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -22,13 +22,29 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn add(&self) -> ClientResult<crate::types::RemindersAddSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> ClientResult<crate::types::RemindersAddSchema> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/reminders.add` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reminders.add>
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
         let url = self.client.url("/reminders.add", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn complete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn complete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.complete_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/reminders.complete` endpoint.
+     *
+     * As opposed to `complete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reminders.complete>
+     */
+    pub async fn complete_with_response(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reminders.complete", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -68,13 +100,29 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn delete(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.delete_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/reminders.delete` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reminders.delete>
+     */
+    pub async fn delete_with_response(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/reminders.delete", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -93,6 +141,19 @@ impl Reminders {
      * * `reminder: &str` -- The ID of the reminder.
      */
     pub async fn info(&self, reminder: &str) -> ClientResult<crate::types::RemindersAddSchema> {
+        Ok(self.info_with_response(reminder).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/reminders.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reminders.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        reminder: &str,
+    ) -> ClientResult<crate::Response<crate::types::RemindersAddSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !reminder.is_empty() {
             query_args.push(("reminder".to_string(), reminder.to_string()));
@@ -102,7 +163,7 @@ impl Reminders {
             .client
             .url(&format!("/reminders.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -123,9 +184,21 @@ impl Reminders {
      * * `token: &str` -- Authentication token. Requires scope: `reminders:read`.
      */
     pub async fn list(&self) -> ClientResult<crate::types::RemindersListSchema> {
+        Ok(self.list_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/reminders.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/reminders.list>
+     */
+    pub async fn list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::RemindersListSchema>> {
         let url = self.client.url("/reminders.list", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -29,6 +29,23 @@ impl Rtm {
         batch_presence_aware: bool,
         presence_sub: bool,
     ) -> ClientResult<crate::types::RtmConnectSchema> {
+        Ok(self
+            .connect_with_response(batch_presence_aware, presence_sub)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/rtm.connect` endpoint.
+     *
+     * As opposed to `connect`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/rtm.connect>
+     */
+    pub async fn connect_with_response(
+        &self,
+        batch_presence_aware: bool,
+        presence_sub: bool,
+    ) -> ClientResult<crate::Response<crate::types::RtmConnectSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if batch_presence_aware {
             query_args.push((
@@ -42,7 +59,7 @@ impl Rtm {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/rtm.connect?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -37,6 +37,27 @@ impl Search {
         sort: &str,
         sort_dir: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .message_with_response(count, highlight, page, query, sort, sort_dir)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/search.messages` endpoint.
+     *
+     * As opposed to `message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/search.messages>
+     */
+    pub async fn message_with_response(
+        &self,
+        count: i64,
+        highlight: bool,
+        page: i64,
+        query: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -61,7 +82,7 @@ impl Search {
             .client
             .url(&format!("/search.messages?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
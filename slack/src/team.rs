@@ -31,6 +31,24 @@ impl Team {
         count: &str,
         page: &str,
     ) -> ClientResult<crate::types::TeamAccessLogsSchema> {
+        Ok(self
+            .access_log_with_response(before, count, page)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/team.accessLogs` endpoint.
+     *
+     * As opposed to `access_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/team.accessLogs>
+     */
+    pub async fn access_log_with_response(
+        &self,
+        before: &str,
+        count: &str,
+        page: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamAccessLogsSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before.is_empty() {
             query_args.push(("before".to_string(), before.to_string()));
@@ -46,7 +64,7 @@ impl Team {
             .client
             .url(&format!("/team.accessLogs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,6 +86,19 @@ impl Team {
      * * `user: &str` -- A user to retrieve the billable information for. Defaults to all users.
      */
     pub async fn billable_info(&self, user: &str) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.billable_info_with_response(user).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/team.billableInfo` endpoint.
+     *
+     * As opposed to `billable_info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/team.billableInfo>
+     */
+    pub async fn billable_info_with_response(
+        &self,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !user.is_empty() {
             query_args.push(("user".to_string(), user.to_string()));
@@ -77,7 +108,7 @@ impl Team {
             .client
             .url(&format!("/team.billableInfo?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -99,6 +130,19 @@ impl Team {
      * * `team: &str` -- Team to get info on, if omitted, will return information about the current team. Will only return team that the authenticated token is allowed to see through external shared channels.
      */
     pub async fn info(&self, team: &str) -> ClientResult<crate::types::TeamInfoSchema> {
+        Ok(self.info_with_response(team).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/team.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/team.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        team: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamInfoSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !team.is_empty() {
             query_args.push(("team".to_string(), team.to_string()));
@@ -106,7 +150,7 @@ impl Team {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/team.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -141,6 +185,27 @@ impl Team {
         service_id: &str,
         user: &str,
     ) -> ClientResult<crate::types::TeamIntegrationLogsSchema> {
+        Ok(self
+            .integration_log_with_response(app_id, change_type, count, page, service_id, user)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/team.integrationLogs` endpoint.
+     *
+     * As opposed to `integration_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/team.integrationLogs>
+     */
+    pub async fn integration_log_with_response(
+        &self,
+        app_id: &str,
+        change_type: &str,
+        count: &str,
+        page: &str,
+        service_id: &str,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamIntegrationLogsSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !app_id.is_empty() {
             query_args.push(("app_id".to_string(), app_id.to_string()));
@@ -165,7 +230,7 @@ impl Team {
             .client
             .url(&format!("/team.integrationLogs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -27,6 +27,19 @@ impl TeamProfile {
         &self,
         visibility: &str,
     ) -> ClientResult<crate::types::TeamProfileGetSuccessSchema> {
+        Ok(self.get_with_response(visibility).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/team.profile.get` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/team.profile.get>
+     */
+    pub async fn get_with_response(
+        &self,
+        visibility: &str,
+    ) -> ClientResult<crate::Response<crate::types::TeamProfileGetSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !visibility.is_empty() {
             query_args.push(("visibility".to_string(), visibility.to_string()));
@@ -36,7 +49,7 @@ impl TeamProfile {
             .client
             .url(&format!("/team.profile.get?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1758,6 +1758,9 @@ pub struct ObjsMessage {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub attachments: Vec<Attachments>,
+    /**
+     * This is a very loose definition, in the future, we'll populate this with deeper schema in this definition namespace.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -2130,13 +2133,15 @@ pub struct NewPagingStyle {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Warnings {
     #[serde(rename = "method_deprecated")]
     MethodDeprecated,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Warnings {
@@ -2144,12 +2149,31 @@ impl std::fmt::Display for Warnings {
         match self {
             Warnings::MethodDeprecated => "method_deprecated",
             Warnings::Noop => "",
-            Warnings::FallthroughString => "*",
+            Warnings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Warnings {
+    fn from(s: String) -> Warnings {
+        match s.as_str() {
+            "method_deprecated" => Warnings::MethodDeprecated,
+            "" => Warnings::Noop,
+            _ => Warnings::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Warnings> for String {
+    fn from(e: Warnings) -> String {
+        match e {
+            Warnings::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Warnings {
     fn default() -> Warnings {
         Warnings::Noop
@@ -2216,6 +2240,7 @@ pub struct ObjsResponseMetadataAnyOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AutoType {
     #[serde(rename = "admin")]
     Admin,
@@ -2223,8 +2248,9 @@ pub enum AutoType {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoType {
@@ -2233,12 +2259,32 @@ impl std::fmt::Display for AutoType {
             AutoType::Admin => "admin",
             AutoType::Owner => "owner",
             AutoType::Noop => "",
-            AutoType::FallthroughString => "*",
+            AutoType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AutoType {
+    fn from(s: String) -> AutoType {
+        match s.as_str() {
+            "admin" => AutoType::Admin,
+            "owner" => AutoType::Owner,
+            "" => AutoType::Noop,
+            _ => AutoType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AutoType> for String {
+    fn from(e: AutoType) -> String {
+        match e {
+            AutoType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AutoType {
     fn default() -> AutoType {
         AutoType::Noop
@@ -2428,6 +2474,7 @@ pub struct TzAnyOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Plan {
     #[serde(rename = "compliance")]
     Compliance,
@@ -2439,8 +2486,9 @@ pub enum Plan {
     Std,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Plan {
@@ -2451,12 +2499,34 @@ impl std::fmt::Display for Plan {
             Plan::Plus => "plus",
             Plan::Std => "std",
             Plan::Noop => "",
-            Plan::FallthroughString => "*",
+            Plan::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Plan {
+    fn from(s: String) -> Plan {
+        match s.as_str() {
+            "compliance" => Plan::Compliance,
+            "enterprise" => Plan::Enterprise,
+            "plus" => Plan::Plus,
+            "std" => Plan::Std,
+            "" => Plan::Noop,
+            _ => Plan::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Plan> for String {
+    fn from(e: Plan) -> String {
+        match e {
+            Plan::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Plan {
     fn default() -> Plan {
         Plan::Noop
@@ -2656,6 +2726,7 @@ pub struct OptionsAnyOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "date")]
     Date,
@@ -2671,8 +2742,9 @@ pub enum Type {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2685,12 +2757,36 @@ impl std::fmt::Display for Type {
             Type::Text => "text",
             Type::User => "user",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "date" => Type::Date,
+            "link" => Type::Link,
+            "mailto" => Type::Mailto,
+            "options_list" => Type::OptionsList,
+            "text" => Type::Text,
+            "user" => Type::User,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -2792,31 +2888,19 @@ pub struct Profile {
 /// user object for non enterprise type
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ObjsUser {
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub deleted: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enterprise_user: Option<ObjsEnterpriseUser>,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2828,9 +2912,6 @@ pub struct ObjsUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -2846,73 +2927,46 @@ pub struct ObjsUser {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_bot: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_external: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_forgotten: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_invited_user: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_owner: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_primary_owner: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_restricted: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_stranger: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_ultra_restricted: bool,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2925,9 +2979,6 @@ pub struct ObjsUser {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -2936,68 +2987,44 @@ pub struct ObjsUser {
     pub presence: String,
     #[serde()]
     pub profile: ObjsUserProfile,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub real_name: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team_id: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub team_profile: Option<Profile>,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub two_factor_type: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tz: Vec<TzAnyOf>,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tz_label: String,
-    /**
-     * user object for non enterprise type
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -3016,7 +3043,7 @@ pub struct ObjsUser {
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ObjsUserData {
     /**
-     * enterprise user
+     * refercing to bug: https://jira.tinyspeck.com/browse/EVALUE-1559
      */
     #[serde(
         default,
@@ -3024,22 +3051,13 @@ pub struct ObjsUserData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color: String,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub deleted: bool,
-    /**
-     * enterprise user
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enterprise_user: Option<ObjsEnterpriseUser>,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -3051,9 +3069,6 @@ pub struct ObjsUserData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -3069,65 +3084,41 @@ pub struct ObjsUserData {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_bot: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_external: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_forgotten: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_owner: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_primary_owner: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_restricted: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_stranger: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_ultra_restricted: bool,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3140,9 +3131,6 @@ pub struct ObjsUserData {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3151,68 +3139,44 @@ pub struct ObjsUserData {
     pub presence: String,
     #[serde()]
     pub profile: ObjsUserProfile,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub real_name: String,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team_id: String,
-    /**
-     * enterprise user
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub team_profile: Option<Profile>,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub teams: Vec<String>,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub two_factor_type: String,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub tz: Vec<TzAnyOf>,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tz_label: String,
-    /**
-     * enterprise user
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
@@ -3617,12 +3581,18 @@ pub struct ObjsUserProfileShort {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AdminInviteRequestsApproveRequest {
+    /**
+     * ID of the request to invite.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub invite_request_id: String,
+    /**
+     * ID for the workspace where the invite request was made.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3631,158 +3601,107 @@ pub struct AdminInviteRequestsApproveRequest {
     pub team_id: String,
 }
 
-/// Schema for successful response from dnd.endDnd method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DndEndSchema {
+pub struct AdminAppsApproveRequest {
+    /**
+     * The id of the app to approve.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
-}
-
-/// Schema for successful response of admin.conversations.create
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdminConversationsCreateSchema {
+    pub app_id: String,
     /**
-     * Schema for successful response of admin.conversations.create
+     * The id of the request to approve.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel_id: String,
+    pub request_id: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub team_id: String,
 }
 
+/// Schema for successful response from dnd.endDnd method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CanThread {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "type"
-    )]
-    pub type_: Vec<String>,
+pub struct DndEndSchema {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub user: Vec<String>,
+    pub ok: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdminConversationsGetConversationPrefsSchema {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub can_thread: Option<CanThread>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub who_can_post: Option<CanThread>,
+pub struct AdminConversationsDeleteRequest {
+    /**
+     * The channel to archive.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_id: String,
 }
 
-/// Schema for successful response of admin.conversations.getConversationPrefs
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdminConversationsGetConversationPrefsSchemaData {
+pub struct AdminConversationsCreateRequest {
+    /**
+     * Description of the public or private channel to create.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub description: String,
     /**
-     * Schema for successful response of admin.conversations.getConversationPrefs
+     * When `true`, creates a private channel instead of a public channel
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub prefs: Option<AdminConversationsGetConversationPrefsSchema>,
-}
-
-/// Schema for successful response of admin.conversations.getTeams
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdminConversationsGetTeamsSchema {
+    pub is_private: Option<bool>,
+    /**
+     * Name of the public or private channel to create.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub name: String,
     /**
-     * Schema for successful response of admin.conversations.getTeams
+     * When `true`, the channel will be available org-wide. Note: if the channel is not `org_wide=true`, you must specify a `team_id` for this channel
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub response_metadata: Option<NewPagingStyle>,
+    pub org_wide: Option<bool>,
+    /**
+     * The workspace to create the channel in. Note: this argument is required unless you set `org_wide=true`.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub team_ids: Vec<String>,
+    pub team_id: String,
 }
 
-/// Schema for successful response of admin.conversations.search
+/// Schema for successful response of admin.conversations.create
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdminConversationsSearchSchema {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub channels: Vec<ObjsChannel>,
+pub struct AdminConversationsCreateSchema {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub next_cursor: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Im {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub resources: Option<ObjsResources>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Team {
-    #[serde()]
-    pub resources: ObjsResources,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Info {
-    #[serde()]
-    pub app_home: Im,
-    #[serde()]
-    pub channel: Im,
-    #[serde()]
-    pub group: Im,
-    #[serde()]
-    pub im: Im,
-    #[serde()]
-    pub mpim: Im,
-    #[serde()]
-    pub team: Team,
-}
-
-/// Schema for successful response from apps.permissions.info method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppsPermissionsInfoSchema {
-    #[serde()]
-    pub info: Info,
+    pub channel_id: String,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -3791,581 +3710,2487 @@ pub struct AppsPermissionsInfoSchema {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Resources {
+pub struct AdminConversationsDisconnectSharedRequest {
+    /**
+     * The channel to be disconnected from some workspaces.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub channel_id: String,
+    /**
+     * The team to be removed from the channel. Currently only a single team id can be specified.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub type_: String,
+    pub leaving_team_ids: String,
 }
 
-/// Schema for successful response apps.permissions.resources.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppsPermissionsResourcesListSuccessSchema {
+pub struct CanThread {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "type"
     )]
-    pub ok: bool,
+    pub type_: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub resources: Vec<Resources>,
-    /**
-     * Schema for successful response apps.permissions.resources.list method
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub response_metadata: Option<NewPagingStyle>,
+    pub user: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Scopes {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub app_home: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub channel: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub group: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub im: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub mpim: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub team: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub user: Vec<String>,
+pub struct AdminConversationsGetConversationPrefsSchema {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_thread: Option<CanThread>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub who_can_post: Option<CanThread>,
 }
 
-/// Schema for successful response api.permissions.scopes.list method
+/// Schema for successful response of admin.conversations.getConversationPrefs
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiPermissionsScopesListSuccessSchema {
+pub struct AdminConversationsGetConversationPrefsSchemaData {
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    #[serde()]
-    pub scopes: Scopes,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefs: Option<AdminConversationsGetConversationPrefsSchema>,
 }
 
-/// Schema for successful response from auth.revoke method
+/// Schema for successful response of admin.conversations.getTeams
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthRevokeSchema {
+pub struct AdminConversationsGetTeamsSchema {
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<NewPagingStyle>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub revoked: bool,
+    pub team_ids: Vec<String>,
 }
 
-/// Schema for successful response auth.test method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthTestSuccessSchema {
+pub struct AdminConversationsInviteRequest {
     /**
-     * Schema for successful response auth.test method
+     * The channel that the users will be invited to.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub bot_id: String,
+    pub channel_id: String,
     /**
-     * Schema for successful response auth.test method
+     * The users to invite.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub is_enterprise_install: bool,
+    pub user_ids: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsRenameRequest {
+    /**
+     * The channel to rename.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub channel_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub team: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminConversationsRestrictAccessAddGroupRequest {
+    /**
+     * The channel to link this group to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub team_id: String,
+    pub channel_id: String,
+    /**
+     * The [IDP Group](https://slack.com/help/articles/115001435788-Connect-identity-provider-groups-to-your-Enterprise-Grid-org) ID to be an allowlist for the private channel.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub url: String,
+    pub group_id: String,
+    /**
+     * The workspace where the channel exists. This argument is required for channels only tied to one workspace, and optional for channels that are shared across an organization.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user: String,
+    pub team_id: String,
+    /**
+     * Authentication token. Requires scope: `admin.conversations:write`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user_id: String,
+    pub token: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Bot {
+pub struct AdminConversationsRestrictAccessRemoveGroupRequest {
+    /**
+     * The channel to remove the linked group from.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub app_id: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub deleted: bool,
-    #[serde()]
-    pub icons: Icons,
+    pub channel_id: String,
+    /**
+     * The [IDP Group](https://slack.com/help/articles/115001435788-Connect-identity-provider-groups-to-your-Enterprise-Grid-org) ID to remove from the private channel.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub group_id: String,
+    /**
+     * The workspace where the channel exists. This argument is required for channels only tied to one workspace, and optional for channels that are shared across an organization.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub updated: i64,
+    pub team_id: String,
+    /**
+     * Authentication token. Requires scope: `admin.conversations:write`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user_id: String,
-}
-
-/// Schema for successful response from bots.info method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BotsInfoSchema {
-    #[serde()]
-    pub bot: Bot,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub ok: bool,
+    pub token: String,
 }
 
-/// Schema for successful response of chat.delete method
+/// Schema for successful response of admin.conversations.search
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatDeleteSuccessSchema {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub channel: String,
+pub struct AdminConversationsSearchSchema {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub ok: bool,
+    pub channels: Vec<ObjsChannel>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ts: String,
+    pub next_cursor: String,
 }
 
-/// Schema for successful response chat.getPermalink
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatGetPermalinkSuccessSchema {
+pub struct AdminConversationsSetConversationPrefsRequest {
+    /**
+     * The channel to set the prefs for
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub ok: bool,
+    pub channel_id: String,
+    /**
+     * The prefs for this channel in a stringified JSON format.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub permalink: String,
+    pub prefs: String,
 }
 
-/// Schema for successful response from chat.meMessage method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatMeMessageSchema {
+pub struct AdminConversationsSetTeamsRequest {
     /**
-     * Schema for successful response from chat.meMessage method
+     * The encoded `channel_id` to add or remove to workspaces.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: String,
+    pub channel_id: String,
+    /**
+     * True if channel has to be converted to an org channel
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_channel: Option<bool>,
+    /**
+     * A comma-separated list of workspaces to which the channel should be shared. Not required if the channel is being shared org-wide.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub target_team_ids: String,
     /**
-     * Schema for successful response from chat.meMessage method
+     * The workspace to which the channel belongs. Omit this argument if the channel is a cross-workspace shared channel.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ts: String,
+    pub team_id: String,
 }
 
-/// Schema for successful response from chat.postEphemeral method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatPostEphemeralSuccessSchema {
+pub struct AdminEmojiAddRequest {
+    /**
+     * The name of the emoji to be removed. Colons (`:myemoji:`) around the value are not required, although they may be included.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub message_ts: String,
+    pub name: String,
+    /**
+     * Authentication token. Requires scope: `admin.teams:write`
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub token: String,
+    /**
+     * The URL of a file to use as an image for the emoji. Square images under 128KB and with transparent backgrounds work best.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
 }
 
-/// Schema for successful response of chat.postMessage method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatPostMessageSuccessSchema {
+pub struct AdminEmojiAddAliasRequest {
+    /**
+     * The alias of the emoji.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: String,
-    #[serde()]
-    pub message: ObjsMessage,
+    pub alias_for: String,
+    /**
+     * The name of the emoji to be aliased. Colons (`:myemoji:`) around the value are not required, although they may be included.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub name: String,
+    /**
+     * Authentication token. Requires scope: `admin.teams:write`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ts: String,
+    pub token: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Message {
+pub struct AdminEmojiRemoveRequest {
+    /**
+     * The name of the emoji to be removed. Colons (`:myemoji:`) around the value are not required, although they may be included.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub bot_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bot_profile: Option<ObjsBotProfile>,
+    pub name: String,
+    /**
+     * Authentication token. Requires scope: `admin.teams:write`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub team: String,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminEmojiRenameRequest {
+    /**
+     * The name of the emoji to be renamed. Colons (`:myemoji:`) around the value are not required, although they may be included.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub text: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    pub name: String,
+    /**
+     * The new name of the emoji.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user: String,
+    pub new_name: String,
+    /**
+     * Authentication token. Requires scope: `admin.teams:write`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub username: String,
+    pub token: String,
 }
 
-/// Schema for successful response of chat.scheduleMessage method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatScheduleMessageSuccessSchema {
+pub struct AdminTeamsCreateRequest {
+    /**
+     * Description for the team.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: String,
-    #[serde()]
-    pub message: Message,
+    pub team_description: String,
+    /**
+     * Who can join the team. A team's discoverability can be `open`, `closed`, `invite_only`, or `unlisted`.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub team_discoverability: String,
+    /**
+     * Team domain (for example, slacksoftballteam).
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub post_at: i64,
+    pub team_domain: String,
+    /**
+     * Team name (for example, Slack Softball Team).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub scheduled_message_id: String,
+    pub team_name: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ScheduledMessages {
+pub struct AdminTeamsSettingsSetDefaultChannelsRequest {
+    /**
+     * An array of channel IDs.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub date_created: i64,
+    pub channel_ids: String,
+    /**
+     * ID for the workspace to set the default channel for.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub post_at: i64,
+    pub team_id: String,
+    /**
+     * Authentication token. Requires scope: `admin.teams:write`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub text: String,
+    pub token: String,
 }
 
-/// Schema for successful response from chat.scheduledMessages.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatScheduledMessagesListSchema {
+pub struct AdminTeamsSettingsSetDescriptionRequest {
+    /**
+     * The new description for the workspace.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
-    #[serde()]
-    pub response_metadata: NewPagingStyle,
+    pub description: String,
+    /**
+     * ID for the workspace to set the description for.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub scheduled_messages: Vec<ScheduledMessages>,
+    pub team_id: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MessageObject {
+pub struct AdminTeamsSettingsSetDiscoverabilityRequest {
+    /**
+     * This workspace's discovery setting. It must be set to one of `open`, `invite_only`, `closed`, or `unlisted`.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub attachments: Vec<Fields>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub blocks: Option<Fields>,
+    pub discoverability: String,
+    /**
+     * The ID of the workspace to set discoverability on.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub text: String,
+    pub team_id: String,
 }
 
-/// Schema for successful response of chat.update method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChatUpdateSuccessSchema {
+pub struct AdminTeamsSettingsSetIconRequest {
+    /**
+     * Image URL for the icon
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: String,
-    #[serde()]
-    pub message: MessageObject,
+    pub image_url: String,
+    /**
+     * ID for the workspace to set the icon for.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub team_id: String,
+    /**
+     * Authentication token. Requires scope: `admin.teams:write`
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub text: String,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsSettingsSetNameRequest {
+    /**
+     * The new name of the workspace.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ts: String,
+    pub name: String,
+    /**
+     * ID for the workspace to set the name for.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
 }
 
-/// Schema for successful response conversations.close method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsCloseSuccessSchema {
+pub struct AdminUsergroupsAddChannelsRequest {
     /**
-     * Schema for successful response conversations.close method
+     * Comma separated string of channel IDs.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub already_closed: bool,
+    pub channel_ids: String,
     /**
-     * Schema for successful response conversations.close method
+     * The workspace to add default channels in.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub no_op: bool,
+    pub team_id: String,
+    /**
+     * ID of the IDP group to add default channels for.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub usergroup_id: String,
 }
 
-/// Schema for successful response conversations.info
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsInfoSuccessSchema {
+pub struct AdminUsergroupsAddTeamsRequest {
+    /**
+     * When `true`, this method automatically creates new workspace accounts for the IDP group members.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_provision: Option<bool>,
+    /**
+     * A comma separated list of encoded team (workspace) IDs. Each workspace \*MUST\* belong to the organization associated with the token.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: Vec<ObjsConversationAnyOf>,
+    pub team_ids: String,
+    /**
+     * An encoded usergroup (IDP Group) ID.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `i64`
-/// - `serde_json::Value`
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChannelActionsTsAnyOf {
-    #[serde(flatten)]
-    pub i64: i64,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+    pub usergroup_id: String,
 }
 
-/// Schema for successful response from conversations.history method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsHistorySuccessSchema {
+pub struct AdminUsergroupsRemoveChannelsRequest {
+    /**
+     * Comma-separated string of channel IDs
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel_actions_count: i64,
+    pub channel_ids: String,
+    /**
+     * ID of the IDP Group
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel_actions_ts: Vec<ChannelActionsTsAnyOf>,
+    pub usergroup_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersAssignRequest {
+    /**
+     * Comma separated values of channel IDs to add user in the new workspace.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_ids: String,
+    /**
+     * True if user should be added to the workspace as a guest.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_restricted: Option<bool>,
+    /**
+     * True if user should be added to the workspace as a single-channel guest.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_ultra_restricted: Option<bool>,
+    /**
+     * The ID (`T1234`) of the workspace.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    /**
+     * The ID of the user to add to the workspace.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersInviteRequest {
+    /**
+     * A comma-separated list of `channel_id`s for this user to join. At least one channel is required.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_ids: String,
+    /**
+     * An optional message to send to the user in the invite email.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub custom_message: String,
+    /**
+     * The email address of the person to invite.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * Timestamp when guest account should be disabled. Only include this timestamp if you are inviting a guest user and you want their account to expire on a certain date.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub guest_expiration_ts: String,
+    /**
+     * Is this user a multi-channel guest user? (default: false)
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_restricted: Option<bool>,
+    /**
+     * Is this user a single channel guest user? (default: false)
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_ultra_restricted: Option<bool>,
+    /**
+     * Full name of the user.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub real_name: String,
+    /**
+     * Allow this invite to be resent in the future if a user has not signed up yet. (default: false)
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resend: Option<bool>,
+    /**
+     * The ID (`T1234`) of the workspace.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSetRequest {
+    /**
+     * The ID (`T1234`) of the workspace.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    /**
+     * The ID of the user to remove.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSessionInvalidateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub session_id: i64,
+    /**
+     * ID of the team that the session belongs to
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSessionResetRequest {
+    /**
+     * Only expire mobile sessions (default: false)
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mobile_only: Option<bool>,
+    /**
+     * The ID of the user to wipe sessions for
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+    /**
+     * Only expire web sessions (default: false)
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub web_only: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminUsersSetExpirationRequest {
+    /**
+     * Timestamp when guest account should be disabled.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub expiration_ts: i64,
+    /**
+     * The ID (`T1234`) of the workspace.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    /**
+     * The ID of the user to set an expiration for.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Im {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ObjsResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Team {
+    #[serde()]
+    pub resources: ObjsResources,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Info {
+    #[serde()]
+    pub app_home: Im,
+    #[serde()]
+    pub channel: Im,
+    #[serde()]
+    pub group: Im,
+    #[serde()]
+    pub im: Im,
+    #[serde()]
+    pub mpim: Im,
+    #[serde()]
+    pub team: Team,
+}
+
+/// Schema for successful response from apps.permissions.info method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsPermissionsInfoSchema {
+    #[serde()]
+    pub info: Info,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Resources {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+/// Schema for successful response apps.permissions.resources.list method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsPermissionsResourcesListSuccessSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub resources: Vec<Resources>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<NewPagingStyle>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Scopes {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub app_home: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub channel: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub group: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub im: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub mpim: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub user: Vec<String>,
+}
+
+/// Schema for successful response api.permissions.scopes.list method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApiPermissionsScopesListSuccessSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde()]
+    pub scopes: Scopes,
+}
+
+/// Schema for successful response from auth.revoke method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthRevokeSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub revoked: bool,
+}
+
+/// Schema for successful response auth.test method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthTestSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub bot_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_enterprise_install: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Bot {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub app_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub deleted: bool,
+    #[serde()]
+    pub icons: Icons,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub updated: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+/// Schema for successful response from bots.info method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BotsInfoSchema {
+    #[serde()]
+    pub bot: Bot,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsAddRequest {
+    /**
+     * The valid Slack user ID of the user who created this Call. When this method is called with a user token, the `created_by` field is optional and defaults to the authed user of the token. Otherwise, the field is required.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_by: String,
+    /**
+     * Call start time in UTC UNIX timestamp format
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub date_start: i64,
+    /**
+     * When supplied, available Slack clients will attempt to directly launch the 3rd-party Call with this URL.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub desktop_app_join_url: String,
+    /**
+     * An optional, human-readable ID supplied by the 3rd-party Call provider. If supplied, this ID will be displayed in the Call object.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_display_id: String,
+    /**
+     * An ID supplied by the 3rd-party Call provider. It must be unique across all Calls from that service.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_unique_id: String,
+    /**
+     * The URL required for a client to join the Call.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub join_url: String,
+    /**
+     * The name of the Call.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    /**
+     * The list of users to register as participants in the Call. [Read more on how to specify users here](/apis/calls#users).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsEndRequest {
+    /**
+     * Call duration in seconds
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub duration: i64,
+    /**
+     * `id` returned when registering the call using the [`calls.add`](/methods/calls.add) method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsParticipantsAddRequest {
+    /**
+     * `id` returned by the [`calls.add`](/methods/calls.add) method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The list of users to add as participants in the Call. [Read more on how to specify users here](/apis/calls#users).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CallsUpdateRequest {
+    /**
+     * When supplied, available Slack clients will attempt to directly launch the 3rd-party Call with this URL.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub desktop_app_join_url: String,
+    /**
+     * `id` returned by the [`calls.add`](/methods/calls.add) method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The URL required for a client to join the Call.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub join_url: String,
+    /**
+     * The name of the Call.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatDeleteRequest {
+    /**
+     * Pass true to delete the message as the authed user with `chat:write:user` scope. [Bot users](/bot-users) in this context are considered authed users. If unused or false, the message will be deleted with `chat:write:bot` scope.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
+    /**
+     * Channel containing the message to be deleted.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Timestamp of the message to be deleted.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub ts: f64,
+}
+
+/// Schema for successful response of chat.delete method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatDeleteSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatDeleteScheduledMessageRequest {
+    /**
+     * Pass true to delete the message as the authed user with `chat:write:user` scope. [Bot users](/bot-users) in this context are considered authed users. If unused or false, the message will be deleted with `chat:write:bot` scope.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
+    /**
+     * The channel the scheduled_message is posting to
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * `scheduled_message_id` returned from call to chat.scheduleMessage
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub scheduled_message_id: String,
+}
+
+/// Schema for successful response chat.getPermalink
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatGetPermalinkSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub permalink: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatMeMessageRequest {
+    /**
+     * Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Text of the message to send.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+}
+
+/// Schema for successful response from chat.meMessage method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatMeMessageSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatPostEphemeralRequest {
+    /**
+     * Pass true to post the message as the authed user. Defaults to true if the chat:write:bot scope is not included. Otherwise, defaults to false.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
+    /**
+     * A JSON-based array of structured attachments, presented as a URL-encoded string.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub attachments: String,
+    /**
+     * A JSON-based array of structured blocks, presented as a URL-encoded string.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blocks: String,
+    /**
+     * Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Emoji to use as the icon for this message. Overrides `icon_url`. Must be used in conjunction with `as_user` set to `false`, otherwise ignored. See [authorship](#authorship) below.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub icon_emoji: String,
+    /**
+     * URL to an image to use as the icon for this message. Must be used in conjunction with `as_user` set to false, otherwise ignored. See [authorship](#authorship) below.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub icon_url: String,
+    /**
+     * Find and link channel names and usernames.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_names: Option<bool>,
+    /**
+     * Change how messages are treated. Defaults to `none`. See [below](#formatting).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub parse: String,
+    /**
+     * How this field works and whether it is required depends on other fields you use in your API call. [See below](#text_usage) for more detail.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    /**
+     * Provide another message's `ts` value to post this message in a thread. Avoid using a reply's `ts` value; use its parent's value instead. Ephemeral messages in threads are only shown if there is already an active thread.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub thread_ts: String,
+    /**
+     * `id` of the user who will receive the ephemeral message. The user should be in the channel specified by the `channel` argument.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    /**
+     * Set your bot's user name. Must be used in conjunction with `as_user` set to false, otherwise ignored. See [authorship](#authorship) below.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+/// Schema for successful response from chat.postEphemeral method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatPostEphemeralSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message_ts: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatPostMessageRequest {
+    /**
+     * Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See [authorship](#authorship) below.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub as_user: String,
+    /**
+     * A JSON-based array of structured attachments, presented as a URL-encoded string.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub attachments: String,
+    /**
+     * A JSON-based array of structured blocks, presented as a URL-encoded string.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blocks: String,
+    /**
+     * Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See [below](#channels) for more details.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Emoji to use as the icon for this message. Overrides `icon_url`. Must be used in conjunction with `as_user` set to `false`, otherwise ignored. See [authorship](#authorship) below.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub icon_emoji: String,
+    /**
+     * URL to an image to use as the icon for this message. Must be used in conjunction with `as_user` set to false, otherwise ignored. See [authorship](#authorship) below.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub icon_url: String,
+    /**
+     * Find and link channel names and usernames.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_names: Option<bool>,
+    /**
+     * Disable Slack markup parsing by setting to `false`. Enabled by default.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mrkdwn: Option<bool>,
+    /**
+     * Change how messages are treated. Defaults to `none`. See [below](#formatting).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub parse: String,
+    /**
+     * Used in conjunction with `thread_ts` and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to `false`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    /**
+     * How this field works and whether it is required depends on other fields you use in your API call. [See below](#text_usage) for more detail.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    /**
+     * Provide another message's `ts` value to make this message a reply. Avoid using a reply's `ts` value; use its parent instead.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub thread_ts: String,
+    /**
+     * Pass true to enable unfurling of primarily text-based content.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    /**
+     * Pass false to disable unfurling of media content.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+    /**
+     * Set your bot's user name. Must be used in conjunction with `as_user` set to false, otherwise ignored. See [authorship](#authorship) below.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+/// Schema for successful response of chat.postMessage method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatPostMessageSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde()]
+    pub message: ObjsMessage,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatScheduleMessageRequest {
+    /**
+     * Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See [chat.postMessage](chat.postMessage#authorship).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_user: Option<bool>,
+    /**
+     * A JSON-based array of structured attachments, presented as a URL-encoded string.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub attachments: String,
+    /**
+     * A JSON-based array of structured blocks, presented as a URL-encoded string.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blocks: String,
+    /**
+     * Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name. See [below](#channels) for more details.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Find and link channel names and usernames.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_names: Option<bool>,
+    /**
+     * Change how messages are treated. Defaults to `none`. See [chat.postMessage](chat.postMessage#formatting).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub parse: String,
+    /**
+     * Unix EPOCH timestamp of time in future to send the message.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub post_at: String,
+    /**
+     * Used in conjunction with `thread_ts` and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to `false`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    /**
+     * How this field works and whether it is required depends on other fields you use in your API call. [See below](#text_usage) for more detail.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    /**
+     * Provide another message's `ts` value to make this message a reply. Avoid using a reply's `ts` value; use its parent instead.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub thread_ts: f64,
+    /**
+     * Pass true to enable unfurling of primarily text-based content.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    /**
+     * Pass false to disable unfurling of media content.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Message {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub bot_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_profile: Option<ObjsBotProfile>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+/// Schema for successful response of chat.scheduleMessage method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatScheduleMessageSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde()]
+    pub message: Message,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub post_at: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub scheduled_message_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScheduledMessages {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub date_created: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub post_at: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+}
+
+/// Schema for successful response from chat.scheduledMessages.list method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatScheduledMessagesListSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde()]
+    pub response_metadata: NewPagingStyle,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scheduled_messages: Vec<ScheduledMessages>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatUnfurlRequest {
+    /**
+     * Channel ID of the message
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Timestamp of the message to add unfurl behavior to.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+    /**
+     * URL-encoded JSON map with keys set to URLs featured in the the message, pointing to their unfurl blocks or message attachments.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub unfurls: String,
+    /**
+     * Provide a simply-formatted string to send as an ephemeral message to the user as invitation to authenticate further and enable full unfurling behavior
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_auth_message: String,
+    /**
+     * Set to `true` or `1` to indicate the user must install your Slack app to trigger unfurls for this domain
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_auth_required: Option<bool>,
+    /**
+     * Send users to this custom URL where they will complete authentication in your app to fully trigger unfurling. Value should be properly URL-encoded.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_auth_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatUpdateRequest {
+    /**
+     * Pass true to update the message as the authed user. [Bot users](/bot-users) in this context are considered authed users.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub as_user: String,
+    /**
+     * A JSON-based array of structured attachments, presented as a URL-encoded string. This field is required when not presenting `text`. If you don't include this field, the message's previous `attachments` will be retained. To remove previous `attachments`, include an empty array for this field.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub attachments: String,
+    /**
+     * A JSON-based array of [structured blocks](/block-kit/building), presented as a URL-encoded string. If you don't include this field, the message's previous `blocks` will be retained. To remove previous `blocks`, include an empty array for this field.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blocks: String,
+    /**
+     * Channel containing the message to be updated.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Find and link channel names and usernames. Defaults to `none`. If you do not specify a value for this field, the original value set for the message will be overwritten with the default, `none`.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub link_names: String,
+    /**
+     * Change how messages are treated. Defaults to `client`, unlike `chat.postMessage`. Accepts either `none` or `full`. If you do not specify a value for this field, the original value set for the message will be overwritten with the default, `client`.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub parse: String,
+    /**
+     * New text for the message, using the [default formatting rules](/reference/surfaces/formatting). It's not required when presenting `blocks` or `attachments`.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    /**
+     * Timestamp of the message to be updated.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MessageObject {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub attachments: Vec<Fields>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Fields>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+}
+
+/// Schema for successful response of chat.update method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChatUpdateSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde()]
+    pub message: MessageObject,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsJoinRequest {
+    /**
+     * ID of conversation to archive
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+}
+
+/// Schema for successful response conversations.close method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsCloseSuccessSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub already_closed: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub no_op: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsCreateRequest {
+    /**
+     * Create a private channel instead of a public one
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    /**
+     * Name of the public or private channel to create
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// Schema for successful response conversations.info
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsInfoSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub channel: Vec<ObjsConversationAnyOf>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `i64`
+/// - `serde_json::Value`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChannelActionsTsAnyOf {
+    #[serde(flatten)]
+    pub i64: i64,
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// Schema for successful response from conversations.history method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsHistorySuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub channel_actions_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub channel_actions_ts: Vec<ChannelActionsTsAnyOf>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_more: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub messages: Vec<ObjsMessage>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub pin_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsInviteRequest {
+    /**
+     * The ID of the public or private channel to invite user(s) to.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * A comma separated list of user IDs. Up to 1000 users may be listed.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ResponseMetadata {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub warnings: Vec<String>,
+}
+
+/// Schema for successful response from conversations.join method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsJoinSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub channel: Vec<ObjsConversationAnyOf>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<ResponseMetadata>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub warning: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsKickRequest {
+    /**
+     * ID of conversation to remove user from.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * User ID to be removed.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
+/// Schema for successful response from conversations.leave method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsLeaveSuccessSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub not_in_channel: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+/// Schema for successful response from conversations.list method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsListSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub channels: Vec<Vec<ObjsConversationAnyOf>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_metadata: Option<NewPagingStyle>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsMarkRequest {
+    /**
+     * Channel or conversation to set the read cursor for.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Unique identifier of message you want marked as most recently seen in this conversation.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub ts: f64,
+}
+
+/// Schema for successful response conversations.members method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsMembersSuccessSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub members: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde()]
+    pub response_metadata: NewPagingStyle,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsOpenRequest {
+    /**
+     * Resume a conversation by supplying an `im` or `mpim`'s ID. Or provide the `users` field instead.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Boolean, indicates you want the full IM channel definition in the response.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_im: Option<bool>,
+    /**
+     * Comma separated lists of users. If only one user is included, this creates a 1:1 DM.  The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a `channel` when not supplying `users`.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChannelData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_im: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_open: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_read: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest: Option<ObjsMessage>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub unread_count: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub unread_count_display: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Vec<ObjsConversationAnyOf>`
+/// - `ChannelData`
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChannelAnyOf {
+    #[serde(flatten)]
+    pub objs_conversation_any_of_vector: Vec<ObjsConversationAnyOf>,
+    #[serde(flatten)]
+    pub channel_data: ChannelData,
+}
+
+/// Schema for successful response from conversations.open method when opening channels, ims, mpims
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsOpenSuccessSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub already_open: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub channel: Vec<ChannelAnyOf>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub no_op: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsRenameRequest {
+    /**
+     * ID of conversation to rename
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * New name for conversation.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// Schema for successful response from conversations.replies method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsRepliesSuccessSchema {
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -4376,421 +6201,539 @@ pub struct ConversationsHistorySuccessSchema {
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub messages: Vec<ObjsMessage>,
+    pub messages: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsSetPurposeRequest {
+    /**
+     * Conversation to set the purpose of
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * A new, specialer purpose
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub purpose: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConversationsSetTopicRequest {
+    /**
+     * Conversation to set the topic of
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * The new topic string. Does not support formatting or linkification.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub topic: String,
+}
+
+/// Schema for successful response from dnd.endSnooze method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DndEndSnoozeSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dnd_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub next_dnd_end_ts: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub next_dnd_start_ts: i64,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub snooze_enabled: bool,
+}
+
+/// Schema for successful response from dnd.info method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DndInfoSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dnd_enabled: bool,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub pin_count: i64,
+    pub next_dnd_end_ts: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub next_dnd_start_ts: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub snooze_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub snooze_endtime: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub snooze_remaining: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ResponseMetadata {
+pub struct DndSetSnoozeRequest {
+    /**
+     * Number of minutes, from now, to snooze until.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub warnings: Vec<String>,
+    pub num_minutes: String,
+    /**
+     * Authentication token. Requires scope: `dnd:write`
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
 }
 
-/// Schema for successful response from conversations.join method
+/// Schema for successful response from dnd.setSnooze method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsJoinSuccessSchema {
+pub struct DndSetSnoozeSchema {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub channel: Vec<ObjsConversationAnyOf>,
+    pub ok: bool,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub ok: bool,
-    /**
-     * Schema for successful response from conversations.join method
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub response_metadata: Option<ResponseMetadata>,
+    pub snooze_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub snooze_endtime: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub snooze_remaining: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesCommentsDeleteRequest {
+    /**
+     * File to delete a comment from.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
     /**
-     * Schema for successful response from conversations.join method
+     * The comment to delete.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub warning: String,
+    pub id: String,
 }
 
-/// Schema for successful response from conversations.leave method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsLeaveSuccessSchema {
+pub struct FilesDeleteRequest {
     /**
-     * Schema for successful response from conversations.leave method
+     * ID of file to delete.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub not_in_channel: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub file: String,
 }
 
-/// Schema for successful response from conversations.list method
+/// Schema for successful response from files.info method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsListSuccessSchema {
+pub struct FilesInfoSchema {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub channels: Vec<Vec<ObjsConversationAnyOf>>,
+    pub comments: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_html: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub editor: String,
+    #[serde()]
+    pub file: ObjsFile,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    /**
-     * Schema for successful response from conversations.list method
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub response_metadata: Option<NewPagingStyle>,
+    pub paging: Option<ObjsPaging>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub response_metadata: Vec<ObjsResponseMetadataAnyOf>,
 }
 
-/// Schema for successful response conversations.members method
+/// Schema for successful response from files.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsMembersSuccessSchema {
+pub struct FilesListSchema {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub members: Vec<String>,
+    pub files: Vec<ObjsFile>,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
     #[serde()]
-    pub response_metadata: NewPagingStyle,
+    pub paging: ObjsPaging,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChannelData {
+pub struct FilesRemoteAddRequest {
+    /**
+     * Creator defined GUID for the file.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub created: String,
+    pub external_id: String,
+    /**
+     * URL of the remote file.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_im: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_open: bool,
+    pub external_url: String,
+    /**
+     * type of file
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_read: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub latest: Option<ObjsMessage>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub unread_count: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub unread_count_display: f64,
+    pub filetype: String,
+    /**
+     * A text file (txt, pdf, doc, etc.) containing textual search terms that are used to improve discovery of the remote file.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub user: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `Vec<ObjsConversationAnyOf>`
-/// - `ChannelData`
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChannelAnyOf {
-    #[serde(flatten)]
-    pub objs_conversation_any_of_vector: Vec<ObjsConversationAnyOf>,
-    #[serde(flatten)]
-    pub channel_data: ChannelData,
-}
-
-/// Schema for successful response from conversations.open method when opening channels, ims, mpims
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsOpenSuccessSchema {
+    pub indexable_file_contents: String,
     /**
-     * Schema for successful response from conversations.open method when opening channels, ims, mpims
+     * Preview of the document via `multipart/form-data`.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub already_open: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub channel: Vec<ChannelAnyOf>,
+    pub preview_image: String,
     /**
-     * Schema for successful response from conversations.open method when opening channels, ims, mpims
+     * Title of the file being shared.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub no_op: bool,
+    pub title: String,
+    /**
+     * Authentication token. Requires scope: `remote_files:write`
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub token: String,
 }
 
-/// Schema for successful response from conversations.replies method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConversationsRepliesSuccessSchema {
+pub struct FilesRemoteRemoveRequest {
     /**
-     * Schema for successful response from conversations.replies method
+     * Creator defined GUID for the file.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub has_more: bool,
+    pub external_id: String,
+    /**
+     * Specify a file by providing its ID.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub messages: Vec<String>,
+    pub file: String,
+    /**
+     * Authentication token. Requires scope: `remote_files:write`
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub token: String,
 }
 
-/// Schema for successful response from dnd.endSnooze method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DndEndSnoozeSchema {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub dnd_enabled: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub next_dnd_end_ts: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub next_dnd_start_ts: i64,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub ok: bool,
+pub struct FilesRemoteUpdateRequest {
+    /**
+     * Creator defined GUID for the file.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub snooze_enabled: bool,
-}
-
-/// Schema for successful response from dnd.info method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DndInfoSchema {
+    pub external_id: String,
+    /**
+     * URL of the remote file.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub dnd_enabled: bool,
+    pub external_url: String,
+    /**
+     * Specify a file by providing its ID.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub next_dnd_end_ts: i64,
+    pub file: String,
+    /**
+     * type of file
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub next_dnd_start_ts: i64,
+    pub filetype: String,
+    /**
+     * File containing contents that can be used to improve searchability for the remote file.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub indexable_file_contents: String,
     /**
-     * Schema for successful response from dnd.info method
+     * Preview of the document via `multipart/form-data`.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub snooze_enabled: bool,
+    pub preview_image: String,
     /**
-     * Schema for successful response from dnd.info method
+     * Title of the file being shared.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub snooze_endtime: i64,
+    pub title: String,
     /**
-     * Schema for successful response from dnd.info method
+     * Authentication token. Requires scope: `remote_files:write`
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub snooze_remaining: i64,
+    pub token: String,
 }
 
-/// Schema for successful response from dnd.setSnooze method
+/// Schema for successful response files.upload method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DndSetSnoozeSchema {
+pub struct FilesUploadSchema {
+    #[serde()]
+    pub file: ObjsFile,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FilesUploadRequest {
+    /**
+     * Comma-separated list of channel names or IDs where the file will be shared.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub snooze_enabled: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub snooze_endtime: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub snooze_remaining: i64,
-}
-
-/// Schema for successful response from files.info method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FilesInfoSchema {
+    pub channels: String,
+    /**
+     * File contents via a POST variable. If omitting this parameter, you must provide a `file`.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub comments: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub content_html: Option<serde_json::Value>,
+    pub content: String,
     /**
-     * Schema for successful response from files.info method
+     * File contents via `multipart/form-data`. If omitting this parameter, you must submit `content`.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub editor: String,
-    #[serde()]
-    pub file: ObjsFile,
+    pub file: String,
+    /**
+     * Filename of file.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub filename: String,
     /**
-     * Schema for successful response from files.info method
+     * A [file type](/types/file#file_types) identifier.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub paging: Option<ObjsPaging>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub filetype: String,
     /**
-     * Schema for successful response from files.info method
+     * The message text introducing the file in specified `channels`.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub response_metadata: Vec<ObjsResponseMetadataAnyOf>,
-}
-
-/// Schema for successful response from files.list method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FilesListSchema {
+    pub initial_comment: String,
+    /**
+     * Provide another message's `ts` value to upload this file as a reply. Never use a reply's `ts` value; use its parent instead.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub files: Vec<ObjsFile>,
+    pub thread_ts: f64,
+    /**
+     * Title of file.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
-    #[serde()]
-    pub paging: ObjsPaging,
-}
-
-/// Schema for successful response files.upload method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FilesUploadSchema {
-    #[serde()]
-    pub file: ObjsFile,
+    pub title: String,
+    /**
+     * Authentication token. Requires scope: `files:write:user`
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ok: bool,
+    pub token: String,
 }
 
 /// Schema for successful response from migration.exchange method
@@ -4802,9 +6745,6 @@ pub struct MigrationExchangeSuccessSchema {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub enterprise_id: String,
-    /**
-     * Schema for successful response from migration.exchange method
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4822,21 +6762,42 @@ pub struct MigrationExchangeSuccessSchema {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team_id: String,
-    /**
-     * Schema for successful response from migration.exchange method
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_id_map: Option<Fields>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PinsAddRequest {
+    /**
+     * Channel to pin the item in.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Timestamp of the message to pin.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timestamp: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ItemsType {
     #[serde(rename = "file")]
     File,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ItemsType {
@@ -4844,12 +6805,31 @@ impl std::fmt::Display for ItemsType {
         match self {
             ItemsType::File => "file",
             ItemsType::Noop => "",
-            ItemsType::FallthroughString => "*",
+            ItemsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ItemsType {
+    fn from(s: String) -> ItemsType {
+        match s.as_str() {
+            "file" => ItemsType::File,
+            "" => ItemsType::Noop,
+            _ => ItemsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ItemsType> for String {
+    fn from(e: ItemsType) -> String {
+        match e {
+            ItemsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ItemsType {
     fn default() -> ItemsType {
         ItemsType::Noop
@@ -4882,13 +6862,15 @@ pub struct Items {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MessagePinType {
     #[serde(rename = "message")]
     Message,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MessagePinType {
@@ -4896,12 +6878,31 @@ impl std::fmt::Display for MessagePinType {
         match self {
             MessagePinType::Message => "message",
             MessagePinType::Noop => "",
-            MessagePinType::FallthroughString => "*",
+            MessagePinType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MessagePinType {
+    fn from(s: String) -> MessagePinType {
+        match s.as_str() {
+            "message" => MessagePinType::Message,
+            "" => MessagePinType::Noop,
+            _ => MessagePinType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MessagePinType> for String {
+    fn from(e: MessagePinType) -> String {
+        match e {
+            MessagePinType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MessagePinType {
     fn default() -> MessagePinType {
         MessagePinType::Noop
@@ -4995,6 +6996,37 @@ pub struct PinsListResponseAnyOf {
     pub pins_list_response_data: PinsListResponseData,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsAddRequest {
+    /**
+     * Channel where the message to add reaction to was posted.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * Reaction (emoji) name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Timestamp of the message to add reaction to.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timestamp: String,
+}
+
 /// Schema for successful response from reactions.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReactionsListSchema {
@@ -5009,14 +7041,8 @@ pub struct ReactionsListSchema {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    /**
-     * Schema for successful response from reactions.list method
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub paging: Option<ObjsPaging>,
-    /**
-     * Schema for successful response from reactions.list method
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5025,6 +7051,86 @@ pub struct ReactionsListSchema {
     pub response_metadata: Vec<ObjsResponseMetadataAnyOf>,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsRemoveRequest {
+    /**
+     * Channel where the message to remove reaction from was posted.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /**
+     * File to remove reaction from.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+    /**
+     * File comment to remove reaction from.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file_comment: String,
+    /**
+     * Reaction (emoji) name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Timestamp of the message to remove reaction from.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timestamp: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RemindersAddRequest {
+    /**
+     * The content of the reminder
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    /**
+     * When this reminder should happen: the Unix timestamp (up to five years from now), the number of seconds until the reminder (if within 24 hours), or a natural language description (Ex. "in 15 minutes," or "every Thursday")
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub time: String,
+    /**
+     * The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+}
+
 /// Schema for successful response from reminders.add method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RemindersAddSchema {
@@ -5037,6 +7143,19 @@ pub struct RemindersAddSchema {
     pub reminder: ObjsReminder,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RemindersDeleteRequest {
+    /**
+     * The ID of the reminder to be marked as complete
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reminder: String,
+}
+
 /// Schema for successful response from reminders.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RemindersListSchema {
@@ -5066,49 +7185,89 @@ pub struct SelfData {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RtmConnectSchemaTeam {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// Schema for successful response from rtm.connect method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RtmConnectSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    #[serde(rename = "self")]
+    pub self_: SelfData,
+    #[serde()]
+    pub team: RtmConnectSchemaTeam,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RtmConnectSchemaTeam {
+pub struct StarsAddRequest {
+    /**
+     * Channel to add star to, or channel where the message to add star to was posted (used with `timestamp`).
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub domain: String,
+    pub channel: String,
+    /**
+     * File to add star to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub file: String,
+    /**
+     * File comment to add star to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
-}
-
-/// Schema for successful response from rtm.connect method
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RtmConnectSchema {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub ok: bool,
-    #[serde(rename = "self")]
-    pub self_: SelfData,
-    #[serde()]
-    pub team: RtmConnectSchemaTeam,
+    pub file_comment: String,
+    /**
+     * Timestamp of the message to add star to.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub url: String,
+    pub timestamp: String,
 }
 
 /// Schema for successful response from stars.list method
@@ -5125,9 +7284,6 @@ pub struct StarsListSchema {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    /**
-     * Schema for successful response from stars.list method
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub paging: Option<ObjsPaging>,
 }
@@ -5326,6 +7482,51 @@ pub struct TeamProfileGetSuccessSchema {
     pub profile: Profile,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsCreateRequest {
+    /**
+     * A comma separated string of encoded channel IDs for which the User Group uses as a default.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channels: String,
+    /**
+     * A short description of the User Group.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * A mention handle. Must be unique among channels, users and User Groups.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub handle: String,
+    /**
+     * Include the number of users in each User Group.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /**
+     * A name for the User Group. Must be unique among User Groups.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
 /// Schema for successful response from usergroups.create method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsCreateSchema {
@@ -5338,6 +7539,24 @@ pub struct UsergroupsCreateSchema {
     pub usergroup: ObjsSubteam,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsEnableRequest {
+    /**
+     * Include the number of users in the User Group.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /**
+     * The encoded ID of the User Group to disable.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup: String,
+}
+
 /// Schema for successful response from usergroups.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsListSchema {
@@ -5354,6 +7573,60 @@ pub struct UsergroupsListSchema {
     pub usergroups: Vec<ObjsSubteam>,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsUpdateRequest {
+    /**
+     * A comma separated string of encoded channel IDs for which the User Group uses as a default.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channels: String,
+    /**
+     * A short description of the User Group.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * A mention handle. Must be unique among channels, users and User Groups.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub handle: String,
+    /**
+     * Include the number of users in the User Group.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /**
+     * A name for the User Group. Must be unique among User Groups.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The encoded ID of the User Group to update.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup: String,
+}
+
 /// Schema for successful response from usergroups.users.list method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsergroupsUsersListSchema {
@@ -5370,6 +7643,33 @@ pub struct UsergroupsUsersListSchema {
     pub users: Vec<String>,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsergroupsUsersUpdateRequest {
+    /**
+     * Include the number of users in the User Group.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_count: Option<bool>,
+    /**
+     * The encoded ID of the User Group to update.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub usergroup: String,
+    /**
+     * A comma separated string of encoded user IDs that represent the entire list of users for the User Group.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub users: String,
+}
+
 /// Schema for successful response from users.conversations method. Returned conversation objects do not include `num_members` or `is_member`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersConversationsSuccessSchema {
@@ -5384,45 +7684,43 @@ pub struct UsersConversationsSuccessSchema {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    /**
-     * Schema for successful response from users.conversations method. Returned conversation objects do not include `num_members` or `is_member`
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_metadata: Option<NewPagingStyle>,
 }
 
-/// Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiMethodUsersGetPresence {
+pub struct UsersDeletePhotoRequest {
     /**
-     * Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
+     * Authentication token. Requires scope: `users.profile:write`
      */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+/// Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApiMethodUsersGetPresence {
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub auto_away: bool,
-    /**
-     * Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub connection_count: i64,
-    /**
-     * Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub last_activity: i64,
-    /**
-     * Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -5433,9 +7731,6 @@ pub struct ApiMethodUsersGetPresence {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    /**
-     * Generated from users.getPresence with shasum e7251aec575d8863f9e0eb38663ae9dc26655f65
-     */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
@@ -5725,9 +8020,6 @@ pub struct UsersListSchema {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ok: bool,
-    /**
-     * Schema for successful response from users.list method
-     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -5748,17 +8040,54 @@ pub struct UsersProfileGetSchema {
     pub profile: ObjsUserProfile,
 }
 
-/// Schema for successful response from users.profile.set method
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UsersProfileSetSchema {
+pub struct UsersProfileSetRequest {
+    /**
+     * Name of a single key to set. Usable only if `profile` is not passed.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Collection of key:value pairs presented as a URL-encoded JSON hash. At most 50 fields may be set. Each field name is limited to 255 characters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub profile: String,
+    /**
+     * ID of user to change. This argument may only be specified by team admins on paid teams.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
     /**
-     * Schema for successful response from users.profile.set method
+     * Value to set a single key to. Usable only if `profile` is not passed.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
+    pub value: String,
+}
+
+/// Schema for successful response from users.profile.set method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersProfileSetSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
     pub email_pending: String,
     #[serde(
         default,
@@ -5775,6 +8104,55 @@ pub struct UsersProfileSetSchema {
     pub username: String,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersSetPhotoRequest {
+    /**
+     * Width/height of crop box (always square)
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub crop_w: String,
+    /**
+     * X coordinate of top-left corner of crop box
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub crop_x: String,
+    /**
+     * Y coordinate of top-left corner of crop box
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub crop_y: String,
+    /**
+     * File contents via `multipart/form-data`.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub image: String,
+    /**
+     * Authentication token. Requires scope: `users.profile:write`
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UsersSetPhotoSchemaProfile {
     #[serde(
@@ -5844,3 +8222,16 @@ pub struct UsersSetPhotoSchema {
     #[serde()]
     pub profile: UsersSetPhotoSchemaProfile,
 }
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersSetPresenceRequest {
+    /**
+     * Either `auto` or `away`
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub presence: String,
+}
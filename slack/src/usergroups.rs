@@ -22,13 +22,29 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn create(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/usergroups.create` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.create>
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.create", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -45,13 +61,29 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn disable(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn disable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+        Ok(self.disable_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/usergroups.disable` endpoint.
+     *
+     * As opposed to `disable`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.disable>
+     */
+    pub async fn disable_with_response(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.disable", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -68,13 +100,29 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn enable(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn enable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+        Ok(self.enable_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/usergroups.enable` endpoint.
+     *
+     * As opposed to `enable`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.enable>
+     */
+    pub async fn enable_with_response(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.enable", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -100,6 +148,24 @@ impl Usergroups {
         include_count: bool,
         include_disabled: bool,
     ) -> ClientResult<crate::types::UsergroupsListSchema> {
+        Ok(self
+            .list_with_response(include_users, include_count, include_disabled)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/usergroups.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        include_users: bool,
+        include_count: bool,
+        include_disabled: bool,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsListSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_count {
             query_args.push(("include_count".to_string(), include_count.to_string()));
@@ -115,7 +181,7 @@ impl Usergroups {
             .client
             .url(&format!("/usergroups.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -135,13 +201,29 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+        Ok(self.update_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/usergroups.update` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.update>
+     */
+    pub async fn update_with_response(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.update", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
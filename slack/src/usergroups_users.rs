@@ -29,6 +29,23 @@ impl UsergroupsUsers {
         include_disabled: bool,
         usergroup: &str,
     ) -> ClientResult<crate::types::UsergroupsUsersListSchema> {
+        Ok(self
+            .list_with_response(include_disabled, usergroup)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/usergroups.users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.users.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        include_disabled: bool,
+        usergroup: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsUsersListSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_disabled {
             query_args.push(("include_disabled".to_string(), include_disabled.to_string()));
@@ -41,7 +58,7 @@ impl UsergroupsUsers {
             .client
             .url(&format!("/usergroups.users.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -61,13 +78,29 @@ impl UsergroupsUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
+    ) -> ClientResult<crate::types::UsergroupsCreateSchema> {
+        Ok(self.update_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/usergroups.users.update` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/usergroups.users.update>
+     */
+    pub async fn update_with_response(
+        &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsergroupsCreateSchema>> {
         let url = self.client.url("/usergroups.users.update", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
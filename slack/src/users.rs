@@ -35,6 +35,26 @@ impl Users {
         limit: i64,
         cursor: &str,
     ) -> ClientResult<crate::types::UsersConversationsSuccessSchema> {
+        Ok(self
+            .conversation_with_response(user, types, exclude_archived, limit, cursor)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/users.conversations` endpoint.
+     *
+     * As opposed to `conversation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.conversations>
+     */
+    pub async fn conversation_with_response(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersConversationsSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -56,7 +76,7 @@ impl Users {
             .client
             .url(&format!("/users.conversations?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,13 +92,29 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.deletePhoto>
      */
-    pub async fn delete_photo(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.delete_photo_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/users.deletePhoto` endpoint.
+     *
+     * As opposed to `delete_photo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.deletePhoto>
+     */
+    pub async fn delete_photo_with_response(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/users.deletePhoto", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -100,6 +136,19 @@ impl Users {
         &self,
         user: &str,
     ) -> ClientResult<crate::types::ApiMethodUsersGetPresence> {
+        Ok(self.get_presence_with_response(user).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/users.getPresence` endpoint.
+     *
+     * As opposed to `get_presence`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.getPresence>
+     */
+    pub async fn get_presence_with_response(
+        &self,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::ApiMethodUsersGetPresence>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !user.is_empty() {
             query_args.push(("user".to_string(), user.to_string()));
@@ -109,7 +158,7 @@ impl Users {
             .client
             .url(&format!("/users.getPresence?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,9 +179,21 @@ impl Users {
      * * `token: &str` -- Authentication token. Requires scope: `identity.basic`.
      */
     pub async fn identity(&self) -> ClientResult<Vec<crate::types::UsersIdentityResponseAnyOf>> {
+        Ok(self.identity_with_response().await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/users.identity` endpoint.
+     *
+     * As opposed to `identity`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.identity>
+     */
+    pub async fn identity_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsersIdentityResponseAnyOf>>> {
         let url = self.client.url("/users.identity", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -164,6 +225,67 @@ impl Users {
             )
             .await
     }
+    /**
+     * This function performs a `GET` to the `/users.identity` endpoint.
+     *
+     * As opposed to `get_all_identity`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     *
+     * FROM: <https://api.slack.com/methods/users.identity>
+     */
+    pub fn get_all_identity_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::UsersIdentityResponseAnyOf>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/users.identity", None);
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let (link, items): (
+                        Option<crate::utils::NextLink>,
+                        Vec<crate::types::UsersIdentityResponseAnyOf>,
+                    ) = self
+                        .client
+                        .get_pages(cursor.as_deref().unwrap_or(&url))
+                        .await?;
+                    let next = link.map(|l| l.0);
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/users.identity` endpoint.
+     *
+     * As opposed to `get_all_identity`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     *
+     * FROM: <https://api.slack.com/methods/users.identity>
+     */
+    pub fn get_all_identity_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsersIdentityResponseAnyOf>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_identity_pages_stream(None))
+    }
     /**
      * This function performs a `GET` to the `/users.info` endpoint.
      *
@@ -182,6 +304,20 @@ impl Users {
         include_locale: bool,
         user: &str,
     ) -> ClientResult<crate::types::UsersInfoSuccessSchema> {
+        Ok(self.info_with_response(include_locale, user).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/users.info` endpoint.
+     *
+     * As opposed to `info`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.info>
+     */
+    pub async fn info_with_response(
+        &self,
+        include_locale: bool,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersInfoSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_locale {
             query_args.push(("include_locale".to_string(), include_locale.to_string()));
@@ -192,7 +328,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users.info?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -221,6 +357,24 @@ impl Users {
         cursor: &str,
         include_locale: bool,
     ) -> ClientResult<crate::types::UsersListSchema> {
+        Ok(self
+            .list_with_response(limit, cursor, include_locale)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.list>
+     */
+    pub async fn list_with_response(
+        &self,
+        limit: i64,
+        cursor: &str,
+        include_locale: bool,
+    ) -> ClientResult<crate::Response<crate::types::UsersListSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cursor.is_empty() {
             query_args.push(("cursor".to_string(), cursor.to_string()));
@@ -234,7 +388,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users.list?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -259,6 +413,19 @@ impl Users {
         &self,
         email: &str,
     ) -> ClientResult<crate::types::UsersInfoSuccessSchema> {
+        Ok(self.lookup_email_with_response(email).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/users.lookupByEmail` endpoint.
+     *
+     * As opposed to `lookup_email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.lookupByEmail>
+     */
+    pub async fn lookup_email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersInfoSuccessSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
@@ -268,7 +435,7 @@ impl Users {
             .client
             .url(&format!("/users.lookupByEmail?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -289,9 +456,21 @@ impl Users {
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
     pub async fn set_active(&self) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_active_with_response().await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/users.setActive` endpoint.
+     *
+     * As opposed to `set_active`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.setActive>
+     */
+    pub async fn set_active_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/users.setActive", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -307,13 +486,29 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.setPhoto>
      */
-    pub async fn set_photo(&self) -> ClientResult<crate::types::UsersSetPhotoSchema> {
+    pub async fn set_photo(
+        &self,
+        body: &crate::types::UsersSetPhotoRequest,
+    ) -> ClientResult<crate::types::UsersSetPhotoSchema> {
+        Ok(self.set_photo_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/users.setPhoto` endpoint.
+     *
+     * As opposed to `set_photo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.setPhoto>
+     */
+    pub async fn set_photo_with_response(
+        &self,
+        body: &crate::types::UsersSetPhotoRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsersSetPhotoSchema>> {
         let url = self.client.url("/users.setPhoto", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -330,13 +525,29 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_presence(&self) -> ClientResult<crate::types::DndEndSchema> {
+    pub async fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.set_presence_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/users.setPresence` endpoint.
+     *
+     * As opposed to `set_presence`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.setPresence>
+     */
+    pub async fn set_presence_with_response(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let url = self.client.url("/users.setPresence", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
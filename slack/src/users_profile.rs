@@ -29,6 +29,20 @@ impl UsersProfile {
         include_labels: bool,
         user: &str,
     ) -> ClientResult<crate::types::UsersProfileGetSchema> {
+        Ok(self.get_with_response(include_labels, user).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/users.profile.get` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.profile.get>
+     */
+    pub async fn get_with_response(
+        &self,
+        include_labels: bool,
+        user: &str,
+    ) -> ClientResult<crate::Response<crate::types::UsersProfileGetSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if include_labels {
             query_args.push(("include_labels".to_string(), include_labels.to_string()));
@@ -41,7 +55,7 @@ impl UsersProfile {
             .client
             .url(&format!("/users.profile.get?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -61,13 +75,29 @@ impl UsersProfile {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users.profile:write`.
      */
-    pub async fn set(&self) -> ClientResult<crate::types::UsersProfileSetSchema> {
+    pub async fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> ClientResult<crate::types::UsersProfileSetSchema> {
+        Ok(self.set_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/users.profile.set` endpoint.
+     *
+     * As opposed to `set`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/users.profile.set>
+     */
+    pub async fn set_with_response(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsersProfileSetSchema>> {
         let url = self.client.url("/users.profile.set", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -29,6 +29,20 @@ impl Views {
         trigger_id: &str,
         view: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.open_with_response(trigger_id, view).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/views.open` endpoint.
+     *
+     * As opposed to `open`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/views.open>
+     */
+    pub async fn open_with_response(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !trigger_id.is_empty() {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
@@ -39,7 +53,7 @@ impl Views {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/views.open?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,6 +82,21 @@ impl Views {
         view: &str,
         hash: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.publish_with_response(user_id, view, hash).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/views.publish` endpoint.
+     *
+     * As opposed to `publish`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/views.publish>
+     */
+    pub async fn publish_with_response(
+        &self,
+        user_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !hash.is_empty() {
             query_args.push(("hash".to_string(), hash.to_string()));
@@ -81,7 +110,7 @@ impl Views {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/views.publish?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -108,6 +137,20 @@ impl Views {
         trigger_id: &str,
         view: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self.push_with_response(trigger_id, view).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/views.push` endpoint.
+     *
+     * As opposed to `push`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/views.push>
+     */
+    pub async fn push_with_response(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !trigger_id.is_empty() {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
@@ -118,7 +161,7 @@ impl Views {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/views.push?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -149,6 +192,25 @@ impl Views {
         view: &str,
         hash: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .update_with_response(view_id, external_id, view, hash)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/views.update` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/views.update>
+     */
+    pub async fn update_with_response(
+        &self,
+        view_id: &str,
+        external_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !external_id.is_empty() {
             query_args.push(("external_id".to_string(), external_id.to_string()));
@@ -165,7 +227,7 @@ impl Views {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/views.update?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
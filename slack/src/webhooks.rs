@@ -0,0 +1,1211 @@
+//! Verify and parse the requests Slack sends to your app: Events API deliveries,
+//! slash commands and interactivity payloads.
+//!
+//! ```ignore
+//! match slack_chat_api::webhooks::verify_and_parse(&signing_secret, &headers, &body)? {
+//!     // Respond to the URL verification handshake with the challenge.
+//!     WebhookRequest::UrlVerification { challenge } => Ok(challenge),
+//!     WebhookRequest::Event(callback) => {
+//!         if let Event::AppMention(mention) = callback.event {
+//!             println!("{} mentioned us: {}", mention.message.user, mention.message.text);
+//!         }
+//!         Ok(String::new())
+//!     }
+//!     WebhookRequest::SlashCommand(command) => Ok(format!("ran {} {}", command.command, command.text)),
+//!     WebhookRequest::Interaction(_) => Ok(String::new()),
+//! }
+//! ```
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ring::hmac;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{ClientError, ClientResult};
+
+/// The header carrying the `v0` HMAC-SHA256 signature of the request.
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+/// The header carrying the unix timestamp the request was signed at.
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+/// How far the request timestamp may be from the local clock, as Slack recommends.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Verify the signature Slack sent for a request.
+///
+/// `timestamp` and `signature` are the values of the `X-Slack-Request-Timestamp` and
+/// `X-Slack-Signature` (`v0=<hex>`) headers, and `body` must be the raw request body,
+/// before any parsing. The comparison is done in constant time, and requests signed more
+/// than `tolerance` away from the local clock are rejected to prevent replays.
+pub fn verify_signature(
+    secret: &str,
+    timestamp: &str,
+    signature: &str,
+    body: &[u8],
+    tolerance: Duration,
+) -> ClientResult<()> {
+    verify_signature_at(
+        secret,
+        timestamp,
+        signature,
+        body,
+        tolerance,
+        SystemTime::now(),
+    )
+}
+
+fn verify_signature_at(
+    secret: &str,
+    timestamp: &str,
+    signature: &str,
+    body: &[u8],
+    tolerance: Duration,
+    now: SystemTime,
+) -> ClientResult<()> {
+    let signed_at: i64 = timestamp.trim().parse().map_err(|_| {
+        ClientError::InvalidWebhookSignature("timestamp is not a number".to_string())
+    })?;
+    let now = now
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    if now.abs_diff(signed_at) > tolerance.as_secs() {
+        return Err(ClientError::InvalidWebhookSignature(
+            "timestamp is outside the tolerance".to_string(),
+        ));
+    }
+
+    let hex = signature.strip_prefix("v0=").ok_or_else(|| {
+        ClientError::InvalidWebhookSignature("unsupported signature version".to_string())
+    })?;
+    let tag = decode_hex(hex).ok_or_else(|| {
+        ClientError::InvalidWebhookSignature("signature is not valid hex".to_string())
+    })?;
+
+    // The signed base string is `v0:<timestamp>:<body>`.
+    let mut base = format!("v0:{}:", timestamp.trim()).into_bytes();
+    base.extend_from_slice(body);
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    hmac::verify(&key, &base, &tag).map_err(|_| {
+        ClientError::InvalidWebhookSignature("signature does not match payload".to_string())
+    })
+}
+
+/// Parse the body of a request Slack sent to your app.
+///
+/// Events API deliveries are JSON, slash commands are form encoded and interactivity
+/// payloads are JSON in the `payload` field of a form. Which one it is is detected from
+/// the body itself, so the same endpoint can serve all three.
+pub fn parse_request(body: &[u8]) -> ClientResult<WebhookRequest> {
+    if body.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{') {
+        return parse_event_envelope(serde_json::from_slice(body)?);
+    }
+
+    let mut form: HashMap<String, String> = serde_urlencoded::from_bytes(body).map_err(bad_form)?;
+    if let Some(payload) = form.remove("payload") {
+        return Ok(WebhookRequest::Interaction(parse_interaction(
+            payload.as_bytes(),
+        )?));
+    }
+    if form.contains_key("command") {
+        return Ok(WebhookRequest::SlashCommand(
+            serde_urlencoded::from_bytes(body).map_err(bad_form)?,
+        ));
+    }
+
+    Err(ClientError::InvalidWebhookPayload(
+        "request is not an event, slash command or interaction".to_string(),
+    ))
+}
+
+/// Parse an Events API `event`, based on its `type`.
+///
+/// Events this module does not model are returned as `Event::Unknown`.
+pub fn parse_event(event: serde_json::Value) -> ClientResult<Event> {
+    let type_ = event
+        .get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+        .to_string();
+    Ok(match type_.as_str() {
+        "message" => Event::Message(serde_json::from_value(event)?),
+        "app_mention" => Event::AppMention(serde_json::from_value(event)?),
+        "app_home_opened" => Event::AppHomeOpened(serde_json::from_value(event)?),
+        "reaction_added" => Event::ReactionAdded(serde_json::from_value(event)?),
+        "reaction_removed" => Event::ReactionRemoved(serde_json::from_value(event)?),
+        "member_joined_channel" => Event::MemberJoinedChannel(serde_json::from_value(event)?),
+        "member_left_channel" => Event::MemberLeftChannel(serde_json::from_value(event)?),
+        "team_join" => Event::TeamJoin(serde_json::from_value(event)?),
+        "user_change" => Event::UserChange(serde_json::from_value(event)?),
+        _ => Event::Unknown {
+            type_,
+            payload: event,
+        },
+    })
+}
+
+/// Parse an interactivity payload, the JSON in the `payload` form field, based on its `type`.
+///
+/// Interactions this module does not model are returned as `Interaction::Unknown`.
+pub fn parse_interaction(payload: &[u8]) -> ClientResult<Interaction> {
+    let payload: serde_json::Value = serde_json::from_slice(payload)?;
+    let type_ = payload
+        .get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+        .to_string();
+    Ok(match type_.as_str() {
+        "block_actions" => Interaction::BlockActions(serde_json::from_value(payload)?),
+        "view_submission" => Interaction::ViewSubmission(serde_json::from_value(payload)?),
+        "view_closed" => Interaction::ViewClosed(serde_json::from_value(payload)?),
+        "shortcut" | "message_action" => Interaction::Shortcut(serde_json::from_value(payload)?),
+        _ => Interaction::Unknown { type_, payload },
+    })
+}
+
+/// Verify the signature of a request and parse it.
+///
+/// The signature is read from the `X-Slack-Signature` and `X-Slack-Request-Timestamp`
+/// headers and checked against `DEFAULT_TOLERANCE`.
+pub fn verify_and_parse(
+    secret: &str,
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> ClientResult<WebhookRequest> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .ok_or_else(|| ClientError::InvalidWebhookSignature(format!("missing {} header", name)))
+    };
+    verify_signature(
+        secret,
+        header(TIMESTAMP_HEADER)?,
+        header(SIGNATURE_HEADER)?,
+        body,
+        DEFAULT_TOLERANCE,
+    )?;
+    parse_request(body)
+}
+
+fn parse_event_envelope(payload: serde_json::Value) -> ClientResult<WebhookRequest> {
+    Ok(match payload.get("type").and_then(|t| t.as_str()) {
+        Some("url_verification") => WebhookRequest::UrlVerification {
+            challenge: payload
+                .get("challenge")
+                .and_then(|c| c.as_str())
+                .unwrap_or_default()
+                .to_string(),
+        },
+        Some("app_rate_limited") => {
+            WebhookRequest::AppRateLimited(serde_json::from_value(payload)?)
+        }
+        _ => {
+            let mut callback: EventCallback<serde_json::Value> = serde_json::from_value(payload)?;
+            let event = parse_event(std::mem::take(&mut callback.event))?;
+            WebhookRequest::Event(EventCallback {
+                token: callback.token,
+                team_id: callback.team_id,
+                api_app_id: callback.api_app_id,
+                event_id: callback.event_id,
+                event_time: callback.event_time,
+                event_context: callback.event_context,
+                is_ext_shared_channel: callback.is_ext_shared_channel,
+                event,
+            })
+        }
+    })
+}
+
+fn bad_form(e: serde_urlencoded::de::Error) -> ClientError {
+    ClientError::InvalidWebhookPayload(format!("invalid form body: {}", e))
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|c| match c {
+            [_, _] => u8::from_str_radix(std::str::from_utf8(c).ok()?, 16).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A request Slack sent to your app.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookRequest {
+    /// Sent when the Request URL of an app is saved, respond with `challenge`.
+    UrlVerification { challenge: String },
+    /// An Events API delivery.
+    Event(EventCallback<Event>),
+    /// Sent when an app exceeds the Events API rate limit.
+    AppRateLimited(AppRateLimited),
+    /// A slash command invocation.
+    SlashCommand(SlashCommand),
+    /// A user interacted with a block, a modal or a shortcut.
+    Interaction(Interaction),
+}
+
+/// The envelope an Events API `event` is delivered in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventCallback<E> {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub event_time: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_context: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_ext_shared_channel: bool,
+    pub event: E,
+}
+
+/// Sent when an app exceeds the Events API rate limit, deliveries are dropped until `minute_rate_limited` has passed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppRateLimited {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub minute_rate_limited: i64,
+}
+
+/// An Events API event, keyed by its `type`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Message(MessageEvent),
+    AppMention(MessageEvent),
+    AppHomeOpened(AppHomeOpenedEvent),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
+    MemberJoinedChannel(MemberChannelEvent),
+    MemberLeftChannel(MemberChannelEvent),
+    TeamJoin(UserEvent),
+    UserChange(UserEvent),
+    /// An event this module does not have a type for.
+    Unknown {
+        type_: String,
+        payload: serde_json::Value,
+    },
+}
+
+/// A message was posted, or the app was mentioned in one.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MessageEvent {
+    #[serde(flatten)]
+    pub message: crate::types::ObjsMessage,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /// `channel`, `group`, `im` or `mpim`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+}
+
+/// A user opened the app's home.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppHomeOpenedEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    /// `home` or `messages`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tab: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<View>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+}
+
+/// A reaction was added to or removed from an item.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reaction: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub item_user: String,
+    pub item: ReactionItem,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+}
+
+/// The item a reaction is attached to.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionItem {
+    /// `message`, `file` or `file_comment`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ts: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file: String,
+}
+
+/// A user joined or left a channel.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MemberChannelEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub channel_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team: String,
+    /// The user who invited them, only set on `member_joined_channel`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub inviter: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+}
+
+/// A user joined the workspace, or their profile changed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserEvent {
+    pub user: crate::types::ObjsUser,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event_ts: String,
+}
+
+/// A slash command invocation, sent as a form.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct SlashCommand {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub token: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub command: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub response_url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub trigger_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub user_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub user_name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub team_domain: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub enterprise_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub enterprise_name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub channel_name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub api_app_id: String,
+}
+
+/// An interactivity payload, keyed by its `type`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Interaction {
+    BlockActions(BlockActions),
+    ViewSubmission(ViewSubmission),
+    ViewClosed(ViewClosed),
+    /// A global (`shortcut`) or message (`message_action`) shortcut.
+    Shortcut(Shortcut),
+    /// An interaction this module does not have a type for.
+    Unknown {
+        type_: String,
+        payload: serde_json::Value,
+    },
+}
+
+/// The user who triggered an interaction.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionUser {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+}
+
+/// The workspace or channel an interaction happened in.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionContainer {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+}
+
+/// A user interacted with a block in a message, a modal or the app home.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BlockActions {
+    pub user: InteractionUser,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionContainer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InteractionContainer>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_id: String,
+    /// Only set for actions in messages.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub response_url: String,
+    /// The message the actions were taken in, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<crate::types::ObjsMessage>,
+    /// The modal or home tab the actions were taken in, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<View>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub actions: Vec<BlockAction>,
+}
+
+/// A single action in a `block_actions` payload.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BlockAction {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub block_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action_ts: String,
+    #[serde(flatten)]
+    pub value: ActionValue,
+}
+
+/// The value of an interactive element, as sent in actions and in view state.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct ActionValue {
+    /// The type of the element, e.g. `button`, `static_select` or `plain_text_input`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_option: Option<SelectedOption>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_options: Vec<SelectedOption>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_date: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_time: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_users: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_channel: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_conversation: String,
+}
+
+/// An option picked in a select menu, checkboxes or radio buttons.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SelectedOption {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<serde_json::Value>,
+}
+
+/// A modal or home tab view, as sent back in interactions.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct View {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /// `modal` or `home`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub callback_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub private_metadata: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub hash: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub blocks: Vec<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ViewState>,
+}
+
+/// The values of the inputs in a view.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, Default)]
+pub struct ViewState {
+    /// Keyed by `block_id`, then by `action_id`.
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, ActionValue>>,
+}
+
+impl ViewState {
+    /// Get the value of the element with `action_id` in the block with `block_id`.
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&ActionValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+}
+
+/// A modal was submitted.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ViewSubmission {
+    pub user: InteractionUser,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionContainer>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_id: String,
+    pub view: View,
+}
+
+/// A modal was closed, only sent when the view has `notify_on_close` set.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ViewClosed {
+    pub user: InteractionUser,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionContainer>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_app_id: String,
+    pub view: View,
+    /// Whether the whole stack of views was closed.
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cleared: bool,
+}
+
+/// A global or message shortcut was triggered.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Shortcut {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub callback_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_id: String,
+    pub user: InteractionUser,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<InteractionContainer>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    /// Only set for message shortcuts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InteractionContainer>,
+    /// Only set for message shortcuts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<crate::types::ObjsMessage>,
+    /// Only set for message shortcuts.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub response_url: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example from Slack's "Verifying requests from Slack" docs.
+    const SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+    const TIMESTAMP: &str = "1531420618";
+    const BODY: &[u8] = b"token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+    const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+    fn at(timestamp: i64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(timestamp as u64)
+    }
+
+    fn sign(timestamp: &str, body: &[u8]) -> String {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, SECRET.as_bytes());
+        let mut base = format!("v0:{}:", timestamp).into_bytes();
+        base.extend_from_slice(body);
+        let tag = hmac::sign(&key, &base);
+        let hex: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+        format!("v0={}", hex)
+    }
+
+    #[test]
+    fn verify_signature_accepts_slack_example() {
+        let now = at(1531420618 + 60);
+        verify_signature_at(SECRET, TIMESTAMP, SIGNATURE, BODY, DEFAULT_TOLERANCE, now).unwrap();
+    }
+
+    #[test]
+    fn verify_signature_rejects_mismatches() {
+        let now = at(1531420618);
+        let bad = [
+            ("1531420619", SIGNATURE),
+            (
+                TIMESTAMP,
+                "v0=b2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            ),
+            (TIMESTAMP, "v0=a2114d57b48eac39b9ad189dd8316235"),
+            (
+                TIMESTAMP,
+                "v1=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503",
+            ),
+            (TIMESTAMP, "v0=not-hex"),
+            ("not-a-timestamp", SIGNATURE),
+        ];
+        for (timestamp, signature) in bad {
+            assert!(
+                matches!(
+                    verify_signature_at(SECRET, timestamp, signature, BODY, DEFAULT_TOLERANCE, now),
+                    Err(ClientError::InvalidWebhookSignature(_))
+                ),
+                "{} {} should not verify",
+                timestamp,
+                signature
+            );
+        }
+    }
+
+    #[test]
+    fn verify_signature_checks_skew() {
+        for now in [at(1531420618 + 301), at(1531420618 - 301)] {
+            assert!(matches!(
+                verify_signature_at(SECRET, TIMESTAMP, SIGNATURE, BODY, DEFAULT_TOLERANCE, now),
+                Err(ClientError::InvalidWebhookSignature(_))
+            ));
+        }
+        verify_signature_at(
+            SECRET,
+            TIMESTAMP,
+            SIGNATURE,
+            BODY,
+            Duration::from_secs(3600),
+            at(1531420618 + 301),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn verify_signature_rejects_extreme_timestamps() {
+        let now = at(1531420618);
+        for timestamp in [i64::MIN, i64::MAX] {
+            let timestamp = timestamp.to_string();
+            let signature = sign(&timestamp, BODY);
+            assert!(matches!(
+                verify_signature_at(SECRET, &timestamp, &signature, BODY, DEFAULT_TOLERANCE, now),
+                Err(ClientError::InvalidWebhookSignature(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn parse_slash_command() {
+        let command = match parse_request(BODY).unwrap() {
+            WebhookRequest::SlashCommand(command) => command,
+            r => panic!("expected a slash command, got {:?}", r),
+        };
+        assert_eq!(command.command, "/webhook-collect");
+        assert_eq!(command.text, "");
+        assert_eq!(command.user_name, "roadrunner");
+        assert_eq!(
+            command.response_url,
+            "https://hooks.slack.com/commands/T1DC2JH3J/397700885554/96rGlfmibIGlgcZRskXaIFfN"
+        );
+    }
+
+    #[test]
+    fn parse_unrecognized_request() {
+        assert!(matches!(
+            parse_request(b"token=gIkuvaNzQIHg97ATvDxqgjtO&team_id=T0001"),
+            Err(ClientError::InvalidWebhookPayload(_))
+        ));
+    }
+
+    #[test]
+    fn parse_url_verification() {
+        let body = br#"{"token": "Jhj5dZrVaK7ZwHHjRyZWjbDl", "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P", "type": "url_verification"}"#;
+        assert_eq!(
+            parse_request(body).unwrap(),
+            WebhookRequest::UrlVerification {
+                challenge: "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_app_mention_event() {
+        let body = serde_json::json!({
+            "token": "ZZZZZZWSxiZZZ2yIvs3peJ",
+            "team_id": "T061EG9R6",
+            "api_app_id": "A0MDYCDME",
+            "event": {
+                "type": "app_mention",
+                "user": "U061F7AUR",
+                "text": "What is the hour of the pearl, <@U0LAN0Z89>?",
+                "ts": "1515449522.000016",
+                "channel": "C0LAN2Q65",
+                "event_ts": "1515449522000016"
+            },
+            "type": "event_callback",
+            "event_id": "Ev0LAN670R",
+            "event_time": 1515449522000016_i64,
+            "authed_users": ["U0LAN0Z89"]
+        });
+
+        let callback = match parse_request(body.to_string().as_bytes()).unwrap() {
+            WebhookRequest::Event(callback) => callback,
+            r => panic!("expected an event, got {:?}", r),
+        };
+        assert_eq!(callback.team_id, "T061EG9R6");
+        assert_eq!(callback.event_id, "Ev0LAN670R");
+        let mention = match callback.event {
+            Event::AppMention(mention) => mention,
+            e => panic!("expected an app_mention event, got {:?}", e),
+        };
+        assert_eq!(mention.channel, "C0LAN2Q65");
+        assert_eq!(mention.message.user, "U061F7AUR");
+        assert_eq!(mention.message.ts, "1515449522.000016");
+    }
+
+    #[test]
+    fn parse_unknown_event() {
+        let body = serde_json::json!({
+            "type": "event_callback",
+            "event": {"type": "emoji_changed", "subtype": "add", "name": "picard_facepalm"}
+        });
+        match parse_request(body.to_string().as_bytes()).unwrap() {
+            WebhookRequest::Event(EventCallback {
+                event: Event::Unknown { type_, payload },
+                ..
+            }) => {
+                assert_eq!(type_, "emoji_changed");
+                assert_eq!(payload["name"], "picard_facepalm");
+            }
+            r => panic!("expected an unknown event, got {:?}", r),
+        }
+    }
+
+    #[test]
+    fn parse_block_actions() {
+        let payload = serde_json::json!({
+            "type": "block_actions",
+            "user": {"id": "U0CA5", "username": "Amy McGee", "name": "Amy McGee", "team_id": "T3MDE"},
+            "api_app_id": "A0CA5",
+            "token": "Shh_its_a_seekrit",
+            "container": {"type": "message", "text": "The contents of the original message where the action originated"},
+            "trigger_id": "12466734323.1395872398",
+            "team": {"id": "T0CAG", "domain": "acme-creamery"},
+            "response_url": "https://www.postresponsestome.com/T123567/1509734234",
+            "actions": [{
+                "type": "static_select",
+                "action_id": "WaXA",
+                "block_id": "=qXel",
+                "selected_option": {"text": {"type": "plain_text", "text": "Chocolate"}, "value": "chocolate"},
+                "action_ts": "1548426417.840180"
+            }]
+        });
+        let body = serde_urlencoded::to_string([("payload", payload.to_string())]).unwrap();
+
+        let actions = match parse_request(body.as_bytes()).unwrap() {
+            WebhookRequest::Interaction(Interaction::BlockActions(actions)) => actions,
+            r => panic!("expected block actions, got {:?}", r),
+        };
+        assert_eq!(actions.user.id, "U0CA5");
+        assert_eq!(actions.team.unwrap().domain, "acme-creamery");
+        assert_eq!(actions.actions.len(), 1);
+        assert_eq!(actions.actions[0].action_id, "WaXA");
+        assert_eq!(actions.actions[0].value.type_, "static_select");
+        assert_eq!(
+            actions.actions[0]
+                .value
+                .selected_option
+                .as_ref()
+                .unwrap()
+                .value,
+            "chocolate"
+        );
+    }
+
+    #[test]
+    fn parse_view_submission() {
+        let payload = serde_json::json!({
+            "type": "view_submission",
+            "team": {"id": "T0CAG", "domain": "acme-creamery"},
+            "user": {"id": "U0CA5", "username": "Amy McGee", "name": "Amy McGee", "team_id": "T3MDE"},
+            "api_app_id": "A0CA5",
+            "token": "Shh_its_a_seekrit",
+            "trigger_id": "12345.98765.abcd2358fdea",
+            "view": {
+                "id": "VNHU13V36",
+                "type": "modal",
+                "private_metadata": "shhh-its-secret",
+                "callback_id": "modal-with-inputs",
+                "state": {
+                    "values": {
+                        "multi-line": {"ml-value": {"type": "plain_text_input", "value": "This is my example inputted value"}}
+                    }
+                },
+                "hash": "156663117.cd33ad1f"
+            },
+            "response_urls": []
+        });
+
+        let submission = match parse_interaction(payload.to_string().as_bytes()).unwrap() {
+            Interaction::ViewSubmission(submission) => submission,
+            i => panic!("expected a view submission, got {:?}", i),
+        };
+        assert_eq!(submission.view.callback_id, "modal-with-inputs");
+        assert_eq!(
+            submission
+                .view
+                .state
+                .unwrap()
+                .get("multi-line", "ml-value")
+                .unwrap()
+                .value,
+            "This is my example inputted value"
+        );
+    }
+
+    #[test]
+    fn verify_and_parse_requires_headers() {
+        let body = br#"{"type": "url_verification", "challenge": "abc"}"#;
+        let mut headers = http::HeaderMap::new();
+        assert!(matches!(
+            verify_and_parse(SECRET, &headers, body),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+        headers.insert(TIMESTAMP_HEADER, now.parse().unwrap());
+        headers.insert(SIGNATURE_HEADER, sign(&now, body).parse().unwrap());
+        assert_eq!(
+            verify_and_parse(SECRET, &headers, body).unwrap(),
+            WebhookRequest::UrlVerification {
+                challenge: "abc".to_string()
+            }
+        );
+    }
+}
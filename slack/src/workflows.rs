@@ -29,6 +29,23 @@ impl Workflows {
         workflow_step_execute_id: &str,
         outputs: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .step_completed_with_response(workflow_step_execute_id, outputs)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/workflows.stepCompleted` endpoint.
+     *
+     * As opposed to `step_completed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/workflows.stepCompleted>
+     */
+    pub async fn step_completed_with_response(
+        &self,
+        workflow_step_execute_id: &str,
+        outputs: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !outputs.is_empty() {
             query_args.push(("outputs".to_string(), outputs.to_string()));
@@ -44,7 +61,7 @@ impl Workflows {
             .client
             .url(&format!("/workflows.stepCompleted?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,6 +88,23 @@ impl Workflows {
         workflow_step_execute_id: &str,
         error: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .step_failed_with_response(workflow_step_execute_id, error)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/workflows.stepFailed` endpoint.
+     *
+     * As opposed to `step_failed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/workflows.stepFailed>
+     */
+    pub async fn step_failed_with_response(
+        &self,
+        workflow_step_execute_id: &str,
+        error: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !error.is_empty() {
             query_args.push(("error".to_string(), error.to_string()));
@@ -86,7 +120,7 @@ impl Workflows {
             .client
             .url(&format!("/workflows.stepFailed?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -119,6 +153,32 @@ impl Workflows {
         step_name: &str,
         step_image_url: &str,
     ) -> ClientResult<crate::types::DndEndSchema> {
+        Ok(self
+            .update_step_with_response(
+                workflow_step_edit_id,
+                inputs,
+                outputs,
+                step_name,
+                step_image_url,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/workflows.updateStep` endpoint.
+     *
+     * As opposed to `update_step`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     *
+     * FROM: <https://api.slack.com/methods/workflows.updateStep>
+     */
+    pub async fn update_step_with_response(
+        &self,
+        workflow_step_edit_id: &str,
+        inputs: &str,
+        outputs: &str,
+        step_name: &str,
+        step_image_url: &str,
+    ) -> ClientResult<crate::Response<crate::types::DndEndSchema>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !inputs.is_empty() {
             query_args.push(("inputs".to_string(), inputs.to_string()));
@@ -143,7 +203,7 @@ impl Workflows {
             .client
             .url(&format!("/workflows.updateStep?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -16,10 +16,10 @@ impl 3DSecure {
     }
 
     /**
-* This function performs a `POST` to the `/v1/3d_secure` endpoint.
-*
-* <p>Initiate 3D Secure authentication.</p>
-*/
+     * This function performs a `POST` to the `/v1/3d_secure` endpoint.
+     *
+     * <p>Initiate 3D Secure authentication.</p>
+     */
 pub async fn post(
 &self,
 ) -> Result<crate::types::ThreeDSecure> {
@@ -35,8 +35,8 @@ self.client.post(&url, None).await
 *
 * **Parameters:**
 *
-* * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-* * `three_d_secure: &str` -- The account's country.
+* * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+* * `three_d_secure: &str`
 */
 pub async fn get_three_d(
 &self,
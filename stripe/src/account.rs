@@ -18,12 +18,28 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
-    pub async fn get(&self) -> ClientResult<crate::types::Account> {
-        let url = self.client.url("/v1/account", None);
+    pub async fn get(&self, expand: &[String]) -> ClientResult<crate::types::Account> {
+        Ok(self.get_with_response(expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/account?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -39,13 +55,24 @@ impl Account {
      *
      * <p>To update your own account, use the <a href="https://dashboard.stripe.com/account">Dashboard</a>. Refer to our <a href="/docs/connect/updating-accounts">Connect</a> documentation to learn more about updating accounts.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Account> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> ClientResult<crate::types::Account> {
+        Ok(self.post_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
         let url = self.client.url("/v1/account", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -61,9 +88,19 @@ impl Account {
      * <p>If you want to delete your own account, use the <a href="https://dashboard.stripe.com/account">account information tab in your account settings</a> instead.</p>
      */
     pub async fn delete(&self) -> ClientResult<crate::types::DeletedAccount> {
+        Ok(self.delete_with_response().await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/account` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::DeletedAccount>> {
         let url = self.client.url("/v1/account", None);
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -77,13 +114,27 @@ impl Account {
      *
      * <p>Create an external account for a given account.</p>
      */
-    pub async fn post_bank(&self) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn post_bank<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self.post_bank_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/bank_accounts` endpoint.
+     *
+     * As opposed to `post_bank`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_bank_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url("/v1/account/bank_accounts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -96,19 +147,41 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get_bank(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self.get_bank_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `get_bank`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_bank(&self, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn get_bank_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/bank_accounts/{}",
+                "/v1/account/bank_accounts/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -126,9 +199,28 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
+     */
+    pub async fn post_bank_account<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self
+            .post_bank_account_with_response::<B>(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `post_bank_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_bank_account(&self, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn post_bank_account_with_response<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/bank_accounts/{}",
@@ -137,10 +229,10 @@ impl Account {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -153,12 +245,23 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
     pub async fn delete_bank(
         &self,
         id: &str,
     ) -> ClientResult<crate::types::DeletedExternalAccountAnyOf> {
+        Ok(self.delete_bank_with_response(id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/account/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `delete_bank`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_bank_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/bank_accounts/{}",
@@ -167,7 +270,7 @@ impl Account {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -183,13 +286,34 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     */
+    pub async fn get_capabilities(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Capability>> {
+        Ok(self.get_capabilities_with_response(expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
+     *
+     * As opposed to `get_capabilities`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_capabilities(&self) -> ClientResult<Vec<crate::types::Capability>> {
-        let url = self.client.url("/v1/account/capabilities", None);
-        let resp: crate::types::ListAccountCapability = self
+    pub async fn get_capabilities_with_response(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Capability>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
+            .url(&format!("/v1/account/capabilities?{}", query_), None);
+        let resp: crate::Response<crate::types::ListAccountCapability> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -199,7 +323,7 @@ impl Account {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
@@ -208,8 +332,18 @@ impl Account {
      *
      * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
      */
-    pub async fn get_all_capabilities(&self) -> ClientResult<Vec<crate::types::Capability>> {
-        let url = self.client.url("/v1/account/capabilities", None);
+    pub async fn get_all_capabilities(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/capabilities?{}", query_), None);
         let mut resp: crate::types::ListAccountCapability = self
             .client
             .get(
@@ -269,6 +403,95 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_capabilities_pages_stream(
+        &self,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Capability>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/capabilities?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ListAccountCapability = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Capability>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_capabilities_pages_stream(expand, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/account/capabilities/{capability}` endpoint.
      *
@@ -276,22 +499,44 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `capability: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `capability: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_capabilities_capability(
         &self,
         capability: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::Capability> {
+        Ok(self
+            .get_capabilities_capability_with_response(capability, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities/{capability}` endpoint.
+     *
+     * As opposed to `get_capabilities_capability`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_capabilities_capability_with_response(
+        &self,
+        capability: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/capabilities/{}",
+                "/v1/account/capabilities/{}?{}",
                 crate::progenitor_support::encode_path(capability),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -307,12 +552,28 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `capability: &str` -- The account's country.
+     * * `capability: &str`
      */
-    pub async fn post_capabilities_capability(
+    pub async fn post_capabilities_capability<B: serde::Serialize>(
         &self,
         capability: &str,
+        body: &B,
     ) -> ClientResult<crate::types::Capability> {
+        Ok(self
+            .post_capabilities_capability_with_response::<B>(capability, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/capabilities/{capability}` endpoint.
+     *
+     * As opposed to `post_capabilities_capability`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_capabilities_capability_with_response<B: serde::Serialize>(
+        &self,
+        capability: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/capabilities/{}",
@@ -321,10 +582,10 @@ impl Account {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -338,20 +599,41 @@ impl Account {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_external(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
+        Ok(self
+            .get_external_with_response(ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_external`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_external_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -362,9 +644,9 @@ impl Account {
         let url = self
             .client
             .url(&format!("/v1/account/external_accounts?{}", query_), None);
-        let resp: crate::types::ExternalAccounts = self
+        let resp: crate::Response<crate::types::ExternalAccounts> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -374,7 +656,7 @@ impl Account {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
@@ -383,8 +665,18 @@ impl Account {
      *
      * <p>List external accounts for an account.</p>
      */
-    pub async fn get_all_external(&self) -> ClientResult<Vec<crate::types::DataAnyOf>> {
-        let url = self.client.url("/v1/account/external_accounts", None);
+    pub async fn get_all_external(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/external_accounts?{}", query_), None);
         let mut resp: crate::types::ExternalAccounts = self
             .client
             .get(
@@ -444,18 +736,121 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_external_pages_stream(
+        &self,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::DataAnyOf>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/external_accounts?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ExternalAccounts = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_external_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DataAnyOf>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_external_pages_stream(expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/account/external_accounts` endpoint.
      *
      * <p>Create an external account for a given account.</p>
      */
-    pub async fn post_external(&self) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn post_external<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self.post_external_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `post_external`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_external_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url("/v1/account/external_accounts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -468,19 +863,44 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get_external_account(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self
+            .get_external_account_with_response(expand, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts/{id}` endpoint.
+     *
+     * As opposed to `get_external_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_external_account(&self, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn get_external_account_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/external_accounts/{}",
+                "/v1/account/external_accounts/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -498,9 +918,28 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
+     */
+    pub async fn post_external_account<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self
+            .post_external_account_with_response::<B>(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/external_accounts/{id}` endpoint.
+     *
+     * As opposed to `post_external_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_external_account(&self, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn post_external_account_with_response<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/external_accounts/{}",
@@ -509,10 +948,10 @@ impl Account {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -525,12 +964,23 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
     pub async fn delete_external(
         &self,
         id: &str,
     ) -> ClientResult<crate::types::DeletedExternalAccountAnyOf> {
+        Ok(self.delete_external_with_response(id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/account/external_accounts/{id}` endpoint.
+     *
+     * As opposed to `delete_external`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_external_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/external_accounts/{}",
@@ -539,7 +989,7 @@ impl Account {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -555,13 +1005,27 @@ impl Account {
      *
      * <p><strong>You may only create login links for <a href="/docs/connect/express-accounts">Express accounts</a> connected to your platform</strong>.</p>
      */
-    pub async fn post_login_link(&self) -> ClientResult<crate::types::LoginLink> {
+    pub async fn post_login_link<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::LoginLink> {
+        Ok(self.post_login_link_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/login_links` endpoint.
+     *
+     * As opposed to `post_login_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_login_link_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::LoginLink>> {
         let url = self.client.url("/v1/account/login_links", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -575,7 +1039,7 @@ impl Account {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -583,14 +1047,36 @@ impl Account {
     pub async fn get_people(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        Ok(self
+            .get_people_with_response(ending_before, expand, limit, _relationship, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_people`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_people_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -601,9 +1087,9 @@ impl Account {
         let url = self
             .client
             .url(&format!("/v1/account/people?{}", query_), None);
-        let resp: crate::types::GetAccountPeopleResponse = self
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -613,7 +1099,7 @@ impl Account {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/account/people` endpoint.
@@ -624,9 +1110,17 @@ impl Account {
      */
     pub async fn get_all_people(
         &self,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
-        let url = self.client.url("/v1/account/people", None);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/people?{}", query_), None);
         let mut resp: crate::types::GetAccountPeopleResponse = self
             .client
             .get(
@@ -686,18 +1180,122 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_people_pages_stream(
+        &self,
+        expand: &[String],
+        _relationship: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Person>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/people?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetAccountPeopleResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_people_stream(
+        &self,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_people_pages_stream(expand, _relationship, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/account/people` endpoint.
      *
      * <p>Creates a new person.</p>
      */
-    pub async fn post_people(&self) -> ClientResult<crate::types::Person> {
+    pub async fn post_people<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self.post_people_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `post_people`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_people_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url("/v1/account/people", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -710,19 +1308,44 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `person: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `person: &str`
+     */
+    pub async fn get_people_person(
+        &self,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .get_people_person_with_response(expand, person)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/people/{person}` endpoint.
+     *
+     * As opposed to `get_people_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_people_person(&self, person: &str) -> ClientResult<crate::types::Person> {
+    pub async fn get_people_person_with_response(
+        &self,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/people/{}",
+                "/v1/account/people/{}?{}",
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -738,9 +1361,28 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `person: &str` -- The account's country.
+     * * `person: &str`
+     */
+    pub async fn post_people_person<B: serde::Serialize>(
+        &self,
+        person: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .post_people_person_with_response::<B>(person, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/people/{person}` endpoint.
+     *
+     * As opposed to `post_people_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_people_person(&self, person: &str) -> ClientResult<crate::types::Person> {
+    pub async fn post_people_person_with_response<B: serde::Serialize>(
+        &self,
+        person: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/people/{}",
@@ -749,10 +1391,10 @@ impl Account {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -765,12 +1407,23 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `person: &str` -- The account's country.
+     * * `person: &str`
      */
     pub async fn delete_people_person(
         &self,
         person: &str,
     ) -> ClientResult<crate::types::DeletedPerson> {
+        Ok(self.delete_people_person_with_response(person).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/account/people/{person}` endpoint.
+     *
+     * As opposed to `delete_people_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_people_person_with_response(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/people/{}",
@@ -779,7 +1432,7 @@ impl Account {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -796,7 +1449,7 @@ impl Account {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -804,14 +1457,36 @@ impl Account {
     pub async fn get_persons(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        Ok(self
+            .get_persons_with_response(ending_before, expand, limit, _relationship, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_persons`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_persons_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -822,9 +1497,9 @@ impl Account {
         let url = self
             .client
             .url(&format!("/v1/account/persons?{}", query_), None);
-        let resp: crate::types::GetAccountPeopleResponse = self
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -834,7 +1509,7 @@ impl Account {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/account/persons` endpoint.
@@ -845,9 +1520,17 @@ impl Account {
      */
     pub async fn get_all_persons(
         &self,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
-        let url = self.client.url("/v1/account/persons", None);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/persons?{}", query_), None);
         let mut resp: crate::types::GetAccountPeopleResponse = self
             .client
             .get(
@@ -907,18 +1590,122 @@ impl Account {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_persons_pages_stream(
+        &self,
+        expand: &[String],
+        _relationship: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Person>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/account/persons?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetAccountPeopleResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_persons_pages_stream(expand, _relationship, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/account/persons` endpoint.
      *
      * <p>Creates a new person.</p>
      */
-    pub async fn post_person(&self) -> ClientResult<crate::types::Person> {
+    pub async fn post_person<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self.post_person_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `post_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_person_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url("/v1/account/persons", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -931,19 +1718,44 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `person: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `person: &str`
+     */
+    pub async fn get_persons_person(
+        &self,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .get_persons_person_with_response(expand, person)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/account/persons/{person}` endpoint.
+     *
+     * As opposed to `get_persons_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_persons_person(&self, person: &str) -> ClientResult<crate::types::Person> {
+    pub async fn get_persons_person_with_response(
+        &self,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/account/persons/{}",
+                "/v1/account/persons/{}?{}",
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -959,9 +1771,28 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `person: &str` -- The account's country.
+     * * `person: &str`
+     */
+    pub async fn post_persons_person<B: serde::Serialize>(
+        &self,
+        person: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .post_persons_person_with_response::<B>(person, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account/persons/{person}` endpoint.
+     *
+     * As opposed to `post_persons_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_persons_person(&self, person: &str) -> ClientResult<crate::types::Person> {
+    pub async fn post_persons_person_with_response<B: serde::Serialize>(
+        &self,
+        person: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/persons/{}",
@@ -970,10 +1801,10 @@ impl Account {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -986,12 +1817,23 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `person: &str` -- The account's country.
+     * * `person: &str`
      */
     pub async fn delete_persons_person(
         &self,
         person: &str,
     ) -> ClientResult<crate::types::DeletedPerson> {
+        Ok(self.delete_persons_person_with_response(person).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/account/persons/{person}` endpoint.
+     *
+     * As opposed to `delete_persons_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_persons_person_with_response(
+        &self,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
         let url = self.client.url(
             &format!(
                 "/v1/account/persons/{}",
@@ -1000,7 +1842,7 @@ impl Account {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -16,13 +16,27 @@ impl AccountLinks {
      *
      * <p>Creates an AccountLink object that includes a single-use Stripe URL that the platform can redirect their user to in order to take them through the Connect Onboarding flow.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::AccountLink> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostAccountLinksRequest,
+    ) -> ClientResult<crate::types::AccountLink> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/account_links` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostAccountLinksRequest,
+    ) -> ClientResult<crate::Response<crate::types::AccountLink>> {
         let url = self.client.url("/v1/account_links", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
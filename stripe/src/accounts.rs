@@ -20,7 +20,7 @@ impl Accounts {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -28,13 +28,35 @@ impl Accounts {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Account>> {
+        Ok(self
+            .get_page_with_response(_created, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Account>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -43,9 +65,9 @@ impl Accounts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/accounts?{}", query_), None);
-        let resp: crate::types::GetAccountsResponse = self
+        let resp: crate::Response<crate::types::GetAccountsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,7 +77,7 @@ impl Accounts {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/accounts` endpoint.
@@ -64,8 +86,17 @@ impl Accounts {
      *
      * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
      */
-    pub async fn get_all(&self, _created: &str) -> ClientResult<Vec<crate::types::Account>> {
-        let url = self.client.url("/v1/accounts", None);
+    pub async fn get_all(
+        &self,
+        _created: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Account>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/accounts?{}", query_), None);
         let mut resp: crate::types::GetAccountsResponse = self
             .client
             .get(
@@ -125,19 +156,121 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Account>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/accounts?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetAccountsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Account>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(_created, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts` endpoint.
      *
      * <p>With <a href="/docs/connect">Connect</a>, you can create Stripe accounts for your users.
      * To do this, you’ll first need to <a href="https://dashboard.stripe.com/account/applications/settings">register your platform</a>.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Account> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostAccountsRequest,
+    ) -> ClientResult<crate::types::Account> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostAccountsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
         let url = self.client.url("/v1/accounts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -150,19 +283,41 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `account: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
-    pub async fn get(&self, account: &str) -> ClientResult<crate::types::Account> {
+    pub async fn get(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::Account> {
+        Ok(self.get_with_response(account, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}",
+                "/v1/accounts/{}?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -180,9 +335,25 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
+     */
+    pub async fn post_accounts(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountRequest,
+    ) -> ClientResult<crate::types::Account> {
+        Ok(self.post_accounts_with_response(account, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}` endpoint.
+     *
+     * As opposed to `post_accounts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_accounts(&self, account: &str) -> ClientResult<crate::types::Account> {
+    pub async fn post_accounts_with_response(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountRequest,
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}",
@@ -191,10 +362,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -211,9 +382,20 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
      */
     pub async fn delete(&self, account: &str) -> ClientResult<crate::types::DeletedAccount> {
+        Ok(self.delete_with_response(account).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/accounts/{account}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        account: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedAccount>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}",
@@ -222,7 +404,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -238,9 +420,25 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
+     */
+    pub async fn post_bank<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self.post_bank_with_response::<B>(account, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/bank_accounts` endpoint.
+     *
+     * As opposed to `post_bank`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_bank(&self, account: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn post_bank_with_response<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/bank_accounts",
@@ -249,10 +447,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -265,21 +463,45 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `account: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      */
-    pub async fn get_bank(&self, account: &str, id: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn get_bank(
+        &self,
+        account: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self.get_bank_with_response(account, expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `get_bank`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_bank_with_response(
+        &self,
+        account: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/bank_accounts/{}",
+                "/v1/accounts/{}/bank_accounts/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -297,14 +519,31 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `account: &str`
+     * * `id: &str`
      */
-    pub async fn post_bank_accounts(
+    pub async fn post_bank_accounts<B: serde::Serialize>(
         &self,
         account: &str,
         id: &str,
+        body: &B,
     ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self
+            .post_bank_accounts_with_response::<B>(account, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `post_bank_accounts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_bank_accounts_with_response<B: serde::Serialize>(
+        &self,
+        account: &str,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/bank_accounts/{}",
@@ -314,10 +553,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -330,14 +569,26 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `account: &str`
+     * * `id: &str`
      */
     pub async fn delete_bank(
         &self,
         account: &str,
         id: &str,
     ) -> ClientResult<crate::types::DeletedExternalAccountAnyOf> {
+        Ok(self.delete_bank_with_response(account, id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/accounts/{account}/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `delete_bank`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_bank_with_response(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/bank_accounts/{}",
@@ -347,7 +598,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -363,23 +614,45 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `account: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_capabilities(
         &self,
         account: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::Capability>> {
+        Ok(self
+            .get_capabilities_with_response(account, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
+     *
+     * As opposed to `get_capabilities`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_capabilities_with_response(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Capability>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/capabilities",
+                "/v1/accounts/{}/capabilities?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
-        let resp: crate::types::ListAccountCapability = self
+        let resp: crate::Response<crate::types::ListAccountCapability> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -389,7 +662,7 @@ impl Accounts {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
@@ -401,11 +674,18 @@ impl Accounts {
     pub async fn get_all_capabilities(
         &self,
         account: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/capabilities",
+                "/v1/accounts/{}/capabilities?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
@@ -468,6 +748,102 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_capabilities_pages_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Capability>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/capabilities?{}",
+                crate::progenitor_support::encode_path(account),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ListAccountCapability = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
+     *
+     * As opposed to `get_all_capabilities`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_capabilities_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Capability>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_capabilities_pages_stream(account, expand, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/capabilities/{capability}` endpoint.
      *
@@ -475,25 +851,48 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `capability: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `account: &str`
+     * * `capability: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_capabilities_capability(
         &self,
         account: &str,
         capability: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::Capability> {
+        Ok(self
+            .get_capabilities_capability_with_response(account, capability, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities/{capability}` endpoint.
+     *
+     * As opposed to `get_capabilities_capability`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_capabilities_capability_with_response(
+        &self,
+        account: &str,
+        capability: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/capabilities/{}",
+                "/v1/accounts/{}/capabilities/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(capability),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -509,14 +908,31 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `capability: &str` -- The account's country.
+     * * `account: &str`
+     * * `capability: &str`
      */
     pub async fn post_capabilities_capability(
         &self,
         account: &str,
         capability: &str,
+        body: &crate::types::PostAccountsAccountCapabilitiesCapabilityRequest,
     ) -> ClientResult<crate::types::Capability> {
+        Ok(self
+            .post_capabilities_capability_with_response(account, capability, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/capabilities/{capability}` endpoint.
+     *
+     * As opposed to `post_capabilities_capability`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_capabilities_capability_with_response(
+        &self,
+        account: &str,
+        capability: &str,
+        body: &crate::types::PostAccountsAccountCapabilitiesCapabilityRequest,
+    ) -> ClientResult<crate::Response<crate::types::Capability>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/capabilities/{}",
@@ -526,10 +942,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -542,9 +958,9 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -552,13 +968,35 @@ impl Accounts {
         &self,
         account: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
+        Ok(self
+            .get_external_with_response(account, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_external`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_external_with_response(
+        &self,
+        account: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::DataAnyOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -574,9 +1012,9 @@ impl Accounts {
             ),
             None,
         );
-        let resp: crate::types::ExternalAccounts = self
+        let resp: crate::Response<crate::types::ExternalAccounts> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -586,7 +1024,7 @@ impl Accounts {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
@@ -598,11 +1036,18 @@ impl Accounts {
     pub async fn get_all_external(
         &self,
         account: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/external_accounts",
+                "/v1/accounts/{}/external_accounts?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
@@ -665,6 +1110,102 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_external_pages_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::DataAnyOf>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/external_accounts?{}",
+                crate::progenitor_support::encode_path(account),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ExternalAccounts = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_all_external`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_external_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::DataAnyOf>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_external_pages_stream(account, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/external_accounts` endpoint.
      *
@@ -672,9 +1213,25 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
+     */
+    pub async fn post_external(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountExternalRequest,
+    ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self.post_external_with_response(account, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `post_external`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_external(&self, account: &str) -> ClientResult<crate::types::DataAnyOf> {
+    pub async fn post_external_with_response(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountExternalRequest,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/external_accounts",
@@ -683,10 +1240,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -699,25 +1256,48 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `account: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      */
     pub async fn get_external_accounts(
         &self,
         account: &str,
+        expand: &[String],
         id: &str,
     ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self
+            .get_external_accounts_with_response(account, expand, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts/{id}` endpoint.
+     *
+     * As opposed to `get_external_accounts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_external_accounts_with_response(
+        &self,
+        account: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/external_accounts/{}",
+                "/v1/accounts/{}/external_accounts/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -735,14 +1315,31 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `account: &str`
+     * * `id: &str`
      */
     pub async fn post_external_accounts(
         &self,
         account: &str,
         id: &str,
+        body: &crate::types::PostAccountsAccountExternalRequest,
     ) -> ClientResult<crate::types::DataAnyOf> {
+        Ok(self
+            .post_external_accounts_with_response(account, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/external_accounts/{id}` endpoint.
+     *
+     * As opposed to `post_external_accounts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_external_accounts_with_response(
+        &self,
+        account: &str,
+        id: &str,
+        body: &crate::types::PostAccountsAccountExternalRequest,
+    ) -> ClientResult<crate::Response<crate::types::DataAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/external_accounts/{}",
@@ -752,10 +1349,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -768,14 +1365,26 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `account: &str`
+     * * `id: &str`
      */
     pub async fn delete_external(
         &self,
         account: &str,
         id: &str,
     ) -> ClientResult<crate::types::DeletedExternalAccountAnyOf> {
+        Ok(self.delete_external_with_response(account, id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/accounts/{account}/external_accounts/{id}` endpoint.
+     *
+     * As opposed to `delete_external`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_external_with_response(
+        &self,
+        account: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedExternalAccountAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/external_accounts/{}",
@@ -785,7 +1394,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -803,9 +1412,28 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
+     */
+    pub async fn post_login_link(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountLoginLinksRequest,
+    ) -> ClientResult<crate::types::LoginLink> {
+        Ok(self
+            .post_login_link_with_response(account, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/login_links` endpoint.
+     *
+     * As opposed to `post_login_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_login_link(&self, account: &str) -> ClientResult<crate::types::LoginLink> {
+    pub async fn post_login_link_with_response(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountLoginLinksRequest,
+    ) -> ClientResult<crate::Response<crate::types::LoginLink>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/login_links",
@@ -814,10 +1442,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -830,9 +1458,9 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -841,14 +1469,44 @@ impl Accounts {
         &self,
         account: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        Ok(self
+            .get_people_with_response(
+                account,
+                ending_before,
+                expand,
+                limit,
+                _relationship,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_people`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_people_with_response(
+        &self,
+        account: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -864,9 +1522,9 @@ impl Accounts {
             ),
             None,
         );
-        let resp: crate::types::GetAccountPeopleResponse = self
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -876,7 +1534,7 @@ impl Accounts {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
@@ -888,12 +1546,19 @@ impl Accounts {
     pub async fn get_all_people(
         &self,
         account: &str,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/people",
+                "/v1/accounts/{}/people?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
@@ -956,6 +1621,108 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_people_pages_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        _relationship: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Person>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/people?{}",
+                crate::progenitor_support::encode_path(account),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetAccountPeopleResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_all_people`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_people_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_people_pages_stream(
+            account,
+            expand,
+            _relationship,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/people` endpoint.
      *
@@ -963,9 +1730,28 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
+     */
+    pub async fn post_people<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .post_people_with_response::<B>(account, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `post_people`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_people(&self, account: &str) -> ClientResult<crate::types::Person> {
+    pub async fn post_people_with_response<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/people",
@@ -974,10 +1760,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -990,25 +1776,48 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `person: &str` -- The account's country.
+     * * `account: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `person: &str`
      */
     pub async fn get_people_person(
         &self,
         account: &str,
+        expand: &[String],
         person: &str,
     ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .get_people_person_with_response(account, expand, person)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people/{person}` endpoint.
+     *
+     * As opposed to `get_people_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_people_person_with_response(
+        &self,
+        account: &str,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/people/{}",
+                "/v1/accounts/{}/people/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1024,14 +1833,31 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `person: &str` -- The account's country.
+     * * `account: &str`
+     * * `person: &str`
      */
-    pub async fn post_people_person(
+    pub async fn post_people_person<B: serde::Serialize>(
         &self,
         account: &str,
         person: &str,
+        body: &B,
     ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .post_people_person_with_response::<B>(account, person, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/people/{person}` endpoint.
+     *
+     * As opposed to `post_people_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_people_person_with_response<B: serde::Serialize>(
+        &self,
+        account: &str,
+        person: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/people/{}",
@@ -1041,10 +1867,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1057,14 +1883,29 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `person: &str` -- The account's country.
+     * * `account: &str`
+     * * `person: &str`
      */
     pub async fn delete_people_person(
         &self,
         account: &str,
         person: &str,
     ) -> ClientResult<crate::types::DeletedPerson> {
+        Ok(self
+            .delete_people_person_with_response(account, person)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/accounts/{account}/people/{person}` endpoint.
+     *
+     * As opposed to `delete_people_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_people_person_with_response(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/people/{}",
@@ -1074,7 +1915,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1090,9 +1931,9 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -1101,14 +1942,44 @@ impl Accounts {
         &self,
         account: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _relationship: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        Ok(self
+            .get_persons_with_response(
+                account,
+                ending_before,
+                expand,
+                limit,
+                _relationship,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_persons`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_persons_with_response(
+        &self,
+        account: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Person>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1124,9 +1995,9 @@ impl Accounts {
             ),
             None,
         );
-        let resp: crate::types::GetAccountPeopleResponse = self
+        let resp: crate::Response<crate::types::GetAccountPeopleResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1136,7 +2007,7 @@ impl Accounts {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
@@ -1148,12 +2019,19 @@ impl Accounts {
     pub async fn get_all_persons(
         &self,
         account: &str,
+        expand: &[String],
         _relationship: &str,
     ) -> ClientResult<Vec<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/persons",
+                "/v1/accounts/{}/persons?{}",
                 crate::progenitor_support::encode_path(account),
+                query_
             ),
             None,
         );
@@ -1216,6 +2094,108 @@ impl Accounts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_persons_pages_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        _relationship: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Person>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/accounts/{}/persons?{}",
+                crate::progenitor_support::encode_path(account),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetAccountPeopleResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_all_persons`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_persons_stream(
+        &self,
+        account: &str,
+        expand: &[String],
+        _relationship: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Person>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_persons_pages_stream(
+            account,
+            expand,
+            _relationship,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/accounts/{account}/persons` endpoint.
      *
@@ -1223,9 +2203,25 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
      */
-    pub async fn post_person(&self, account: &str) -> ClientResult<crate::types::Person> {
+    pub async fn post_person(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> ClientResult<crate::types::Person> {
+        Ok(self.post_person_with_response(account, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `post_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_person_with_response(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/persons",
@@ -1234,10 +2230,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1250,25 +2246,48 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `person: &str` -- The account's country.
+     * * `account: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `person: &str`
      */
     pub async fn get_persons_person(
         &self,
         account: &str,
+        expand: &[String],
         person: &str,
     ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .get_persons_person_with_response(account, expand, person)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons/{person}` endpoint.
+     *
+     * As opposed to `get_persons_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_persons_person_with_response(
+        &self,
+        account: &str,
+        expand: &[String],
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/accounts/{}/persons/{}",
+                "/v1/accounts/{}/persons/{}?{}",
                 crate::progenitor_support::encode_path(account),
                 crate::progenitor_support::encode_path(person),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1284,14 +2303,31 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `person: &str` -- The account's country.
+     * * `account: &str`
+     * * `person: &str`
      */
     pub async fn post_persons_person(
         &self,
         account: &str,
         person: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
     ) -> ClientResult<crate::types::Person> {
+        Ok(self
+            .post_persons_person_with_response(account, person, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/persons/{person}` endpoint.
+     *
+     * As opposed to `post_persons_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_persons_person_with_response(
+        &self,
+        account: &str,
+        person: &str,
+        body: &crate::types::PostAccountsAccountPersonsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Person>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/persons/{}",
@@ -1301,10 +2337,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1317,14 +2353,29 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
-     * * `person: &str` -- The account's country.
+     * * `account: &str`
+     * * `person: &str`
      */
     pub async fn delete_persons_person(
         &self,
         account: &str,
         person: &str,
     ) -> ClientResult<crate::types::DeletedPerson> {
+        Ok(self
+            .delete_persons_person_with_response(account, person)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/accounts/{account}/persons/{person}` endpoint.
+     *
+     * As opposed to `delete_persons_person`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_persons_person_with_response(
+        &self,
+        account: &str,
+        person: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPerson>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/persons/{}",
@@ -1334,7 +2385,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1352,9 +2403,25 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `account: &str` -- The account's country.
+     * * `account: &str`
      */
-    pub async fn post_reject(&self, account: &str) -> ClientResult<crate::types::Account> {
+    pub async fn post_reject(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountRejectRequest,
+    ) -> ClientResult<crate::types::Account> {
+        Ok(self.post_reject_with_response(account, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/accounts/{account}/reject` endpoint.
+     *
+     * As opposed to `post_reject`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_reject_with_response(
+        &self,
+        account: &str,
+        body: &crate::types::PostAccountsAccountRejectRequest,
+    ) -> ClientResult<crate::Response<crate::types::Account>> {
         let url = self.client.url(
             &format!(
                 "/v1/accounts/{}/reject",
@@ -1363,10 +2430,10 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,9 +18,9 @@ impl ApplePay {
      *
      * **Parameters:**
      *
-     * * `domain_name: &str` -- The account's country.
+     * * `domain_name: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -28,9 +28,28 @@ impl ApplePay {
         &self,
         domain_name: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::ApplePayDomain>> {
+        Ok(self
+            .get_domains_with_response(domain_name, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_domains`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_domains_with_response(
+        &self,
+        domain_name: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ApplePayDomain>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain_name.is_empty() {
             query_args.push(("domain_name".to_string(), domain_name.to_string()));
@@ -38,6 +57,9 @@ impl ApplePay {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -48,9 +70,9 @@ impl ApplePay {
         let url = self
             .client
             .url(&format!("/v1/apple_pay/domains?{}", query_), None);
-        let resp: crate::types::ApplePayDomainList = self
+        let resp: crate::Response<crate::types::ApplePayDomainList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +82,7 @@ impl ApplePay {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
@@ -72,11 +94,15 @@ impl ApplePay {
     pub async fn get_all_domains(
         &self,
         domain_name: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::ApplePayDomain>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !domain_name.is_empty() {
             query_args.push(("domain_name".to_string(), domain_name.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
@@ -140,18 +166,126 @@ impl ApplePay {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_all_domains`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_domains_pages_stream(
+        &self,
+        domain_name: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ApplePayDomain>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !domain_name.is_empty() {
+            query_args.push(("domain_name".to_string(), domain_name.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/apple_pay/domains?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ApplePayDomainList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_all_domains`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_domains_stream(
+        &self,
+        domain_name: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ApplePayDomain>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_domains_pages_stream(domain_name, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/apple_pay/domains` endpoint.
      *
      * <p>Create an apple pay domain.</p>
      */
-    pub async fn post_domain(&self) -> ClientResult<crate::types::ApplePayDomain> {
+    pub async fn post_domain(
+        &self,
+        body: &crate::types::PostApplePayDomainsRequest,
+    ) -> ClientResult<crate::types::ApplePayDomain> {
+        Ok(self.post_domain_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `post_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_domain_with_response(
+        &self,
+        body: &crate::types::PostApplePayDomainsRequest,
+    ) -> ClientResult<crate::Response<crate::types::ApplePayDomain>> {
         let url = self.client.url("/v1/apple_pay/domains", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -164,22 +298,44 @@ impl ApplePay {
      *
      * **Parameters:**
      *
-     * * `domain: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `domain: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_domains_domain(
         &self,
         domain: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::ApplePayDomain> {
+        Ok(self
+            .get_domains_domain_with_response(domain, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains/{domain}` endpoint.
+     *
+     * As opposed to `get_domains_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_domains_domain_with_response(
+        &self,
+        domain: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::ApplePayDomain>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/apple_pay/domains/{}",
+                "/v1/apple_pay/domains/{}?{}",
                 crate::progenitor_support::encode_path(domain),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -195,12 +351,23 @@ impl ApplePay {
      *
      * **Parameters:**
      *
-     * * `domain: &str` -- The account's country.
+     * * `domain: &str`
      */
     pub async fn delete_domains_domain(
         &self,
         domain: &str,
     ) -> ClientResult<crate::types::DeletedApplePayDomain> {
+        Ok(self.delete_domains_domain_with_response(domain).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/apple_pay/domains/{domain}` endpoint.
+     *
+     * As opposed to `delete_domains_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_domains_domain_with_response(
+        &self,
+        domain: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedApplePayDomain>> {
         let url = self.client.url(
             &format!(
                 "/v1/apple_pay/domains/{}",
@@ -209,7 +376,7 @@ impl ApplePay {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
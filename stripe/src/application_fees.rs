@@ -21,7 +21,7 @@ impl ApplicationFees {
      * * `charge: &str` -- Only return application fees for the charge specified by this charge ID.
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -30,9 +30,36 @@ impl ApplicationFees {
         charge: &str,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PlatformFee>> {
+        Ok(self
+            .get_page_with_response(
+                charge,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        charge: &str,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PlatformFee>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
@@ -40,6 +67,9 @@ impl ApplicationFees {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -50,9 +80,9 @@ impl ApplicationFees {
         let url = self
             .client
             .url(&format!("/v1/application_fees?{}", query_), None);
-        let resp: crate::types::GetApplicationFeesResponse = self
+        let resp: crate::Response<crate::types::GetApplicationFeesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -62,7 +92,7 @@ impl ApplicationFees {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/application_fees` endpoint.
@@ -75,11 +105,15 @@ impl ApplicationFees {
         &self,
         charge: &str,
         _created: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::PlatformFee>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
@@ -143,6 +177,102 @@ impl ApplicationFees {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PlatformFee>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !charge.is_empty() {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/application_fees?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetApplicationFeesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PlatformFee>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(charge, _created, expand, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/application_fees/{fee}/refunds/{id}` endpoint.
      *
@@ -150,25 +280,48 @@ impl ApplicationFees {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `fee: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `fee: &str`
+     * * `id: &str`
      */
     pub async fn get_fee_refund(
         &self,
+        expand: &[String],
         fee: &str,
         id: &str,
     ) -> ClientResult<crate::types::FeeRefund> {
+        Ok(self
+            .get_fee_refund_with_response(expand, fee, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{fee}/refunds/{id}` endpoint.
+     *
+     * As opposed to `get_fee_refund`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_fee_refund_with_response(
+        &self,
+        expand: &[String],
+        fee: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::FeeRefund>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/application_fees/{}/refunds/{}",
+                "/v1/application_fees/{}/refunds/{}?{}",
                 crate::progenitor_support::encode_path(fee),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -186,14 +339,31 @@ impl ApplicationFees {
      *
      * **Parameters:**
      *
-     * * `fee: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `fee: &str`
+     * * `id: &str`
      */
     pub async fn post_fee_refund(
         &self,
         fee: &str,
         id: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> ClientResult<crate::types::FeeRefund> {
+        Ok(self
+            .post_fee_refund_with_response(fee, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/application_fees/{fee}/refunds/{id}` endpoint.
+     *
+     * As opposed to `post_fee_refund`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_fee_refund_with_response(
+        &self,
+        fee: &str,
+        id: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> ClientResult<crate::Response<crate::types::FeeRefund>> {
         let url = self.client.url(
             &format!(
                 "/v1/application_fees/{}/refunds/{}",
@@ -203,10 +373,10 @@ impl ApplicationFees {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -219,19 +389,41 @@ impl ApplicationFees {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::PlatformFee> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::PlatformFee> {
+        Ok(self.get_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PlatformFee>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/application_fees/{}",
+                "/v1/application_fees/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -247,9 +439,25 @@ impl ApplicationFees {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
-    pub async fn post_refund(&self, id: &str) -> ClientResult<crate::types::PlatformFee> {
+    pub async fn post_refund<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::PlatformFee> {
+        Ok(self.post_refund_with_response::<B>(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/application_fees/{id}/refund` endpoint.
+     *
+     * As opposed to `post_refund`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_refund_with_response<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PlatformFee>> {
         let url = self.client.url(
             &format!(
                 "/v1/application_fees/{}/refund",
@@ -258,10 +466,10 @@ impl ApplicationFees {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -275,22 +483,44 @@ impl ApplicationFees {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_refunds(
         &self,
         ending_before: &str,
+        expand: &[String],
         id: &str,
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::FeeRefund>> {
+        Ok(self
+            .get_refunds_with_response(ending_before, expand, id, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_refunds`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_refunds_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        id: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FeeRefund>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -306,9 +536,9 @@ impl ApplicationFees {
             ),
             None,
         );
-        let resp: crate::types::Refunds = self
+        let resp: crate::Response<crate::types::Refunds> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -318,7 +548,7 @@ impl ApplicationFees {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
@@ -327,11 +557,21 @@ impl ApplicationFees {
      *
      * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
      */
-    pub async fn get_all_refunds(&self, id: &str) -> ClientResult<Vec<crate::types::FeeRefund>> {
+    pub async fn get_all_refunds(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<Vec<crate::types::FeeRefund>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/application_fees/{}/refunds",
+                "/v1/application_fees/{}/refunds?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -394,6 +634,102 @@ impl ApplicationFees {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_refunds_pages_stream(
+        &self,
+        expand: &[String],
+        id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::FeeRefund>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/application_fees/{}/refunds?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Refunds = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_refunds_stream(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FeeRefund>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_refunds_pages_stream(expand, id, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/application_fees/{id}/refunds` endpoint.
      *
@@ -409,12 +745,28 @@ impl ApplicationFees {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
     pub async fn post_refund_application_fees(
         &self,
         id: &str,
+        body: &crate::types::PostApplicationFeesRefundsRequest,
     ) -> ClientResult<crate::types::FeeRefund> {
+        Ok(self
+            .post_refund_application_fees_with_response(id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `post_refund_application_fees`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_refund_application_fees_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostApplicationFeesRefundsRequest,
+    ) -> ClientResult<crate::Response<crate::types::FeeRefund>> {
         let url = self.client.url(
             &format!(
                 "/v1/application_fees/{}/refunds",
@@ -423,10 +775,10 @@ impl ApplicationFees {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
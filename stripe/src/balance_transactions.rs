@@ -23,7 +23,7 @@ impl BalanceTransactions {
      * * `created: &str`
      * * `currency: &str` -- Only return transactions in a certain currency. Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase. Must be a [supported currency](https://stripe.com/docs/currencies).
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `payout: &str` -- For automatic Stripe payouts only, only returns transactions that were paid out on the specified payout ID.
      * * `source: &str` -- Only returns the original transaction.
@@ -35,12 +35,45 @@ impl BalanceTransactions {
         _created: &str,
         currency: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payout: &str,
         source: &str,
         starting_after: &str,
         type_: &str,
     ) -> ClientResult<Vec<crate::types::BalanceTransaction>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                currency,
+                ending_before,
+                expand,
+                limit,
+                payout,
+                source,
+                starting_after,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/balance_transactions` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        currency: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payout: &str,
+        source: &str,
+        starting_after: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BalanceTransaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
@@ -48,6 +81,9 @@ impl BalanceTransactions {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -67,9 +103,9 @@ impl BalanceTransactions {
         let url = self
             .client
             .url(&format!("/v1/balance_transactions?{}", query_), None);
-        let resp: crate::types::BalanceTransactionsList = self
+        let resp: crate::Response<crate::types::BalanceTransactionsList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,7 +115,7 @@ impl BalanceTransactions {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/balance_transactions` endpoint.
@@ -94,6 +130,7 @@ impl BalanceTransactions {
         &self,
         _created: &str,
         currency: &str,
+        expand: &[String],
         payout: &str,
         source: &str,
         type_: &str,
@@ -102,6 +139,9 @@ impl BalanceTransactions {
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !payout.is_empty() {
             query_args.push(("payout".to_string(), payout.to_string()));
         }
@@ -174,6 +214,121 @@ impl BalanceTransactions {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/balance_transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        currency: &str,
+        expand: &[String],
+        payout: &str,
+        source: &str,
+        type_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::BalanceTransaction>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !currency.is_empty() {
+            query_args.push(("currency".to_string(), currency.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !payout.is_empty() {
+            query_args.push(("payout".to_string(), payout.to_string()));
+        }
+        if !source.is_empty() {
+            query_args.push(("source".to_string(), source.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/balance_transactions?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::BalanceTransactionsList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/balance_transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        currency: &str,
+        expand: &[String],
+        payout: &str,
+        source: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BalanceTransaction>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_pages_stream(_created, currency, expand, payout, source, type_, None),
+        )
+    }
     /**
      * This function performs a `GET` to the `/v1/balance_transactions/{id}` endpoint.
      *
@@ -183,19 +338,41 @@ impl BalanceTransactions {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::BalanceTransaction> {
+        Ok(self.get_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/balance_transactions/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::BalanceTransaction> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BalanceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/balance_transactions/{}",
+                "/v1/balance_transactions/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
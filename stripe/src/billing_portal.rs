@@ -20,7 +20,7 @@ impl BillingPortal {
      *
      * * `active: bool` -- Only return configurations that are active or inactive (e.g., pass `true` to only list active configurations).
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `is_default: bool` -- Only return the default or non-default configurations (e.g., pass `true` to only list the default configuration).
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -29,10 +29,37 @@ impl BillingPortal {
         &self,
         active: bool,
         ending_before: &str,
+        expand: &[String],
         is_default: bool,
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PortalConfiguration>> {
+        Ok(self
+            .get_configurations_with_response(
+                active,
+                ending_before,
+                expand,
+                is_default,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_configurations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_configurations_with_response(
+        &self,
+        active: bool,
+        ending_before: &str,
+        expand: &[String],
+        is_default: bool,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PortalConfiguration>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -40,6 +67,9 @@ impl BillingPortal {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if is_default {
             query_args.push(("is_default".to_string(), is_default.to_string()));
         }
@@ -54,9 +84,9 @@ impl BillingPortal {
             &format!("/v1/billing_portal/configurations?{}", query_),
             None,
         );
-        let resp: crate::types::GetBillingPortalConfigurationsResponse = self
+        let resp: crate::Response<crate::types::GetBillingPortalConfigurationsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,7 +96,7 @@ impl BillingPortal {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
@@ -78,12 +108,16 @@ impl BillingPortal {
     pub async fn get_all_configurations(
         &self,
         active: bool,
+        expand: &[String],
         is_default: bool,
     ) -> ClientResult<Vec<crate::types::PortalConfiguration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if is_default {
             query_args.push(("is_default".to_string(), is_default.to_string()));
         }
@@ -151,18 +185,136 @@ impl BillingPortal {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_all_configurations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_configurations_pages_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+        is_default: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::PortalConfiguration>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if is_default {
+            query_args.push(("is_default".to_string(), is_default.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/v1/billing_portal/configurations?{}", query_),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetBillingPortalConfigurationsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_all_configurations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_configurations_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+        is_default: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PortalConfiguration>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_configurations_pages_stream(active, expand, is_default, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/billing_portal/configurations` endpoint.
      *
      * <p>Creates a configuration that describes the functionality and behavior of a PortalSession</p>
      */
-    pub async fn post_configuration(&self) -> ClientResult<crate::types::PortalConfiguration> {
+    pub async fn post_configuration<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::PortalConfiguration> {
+        Ok(self.post_configuration_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `post_configuration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_configuration_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PortalConfiguration>> {
         let url = self.client.url("/v1/billing_portal/configurations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -175,22 +327,44 @@ impl BillingPortal {
      *
      * **Parameters:**
      *
-     * * `configuration: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `configuration: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_configurations_configuration(
         &self,
         configuration: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::PortalConfiguration> {
+        Ok(self
+            .get_configurations_configuration_with_response(configuration, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations/{configuration}` endpoint.
+     *
+     * As opposed to `get_configurations_configuration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_configurations_configuration_with_response(
+        &self,
+        configuration: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::PortalConfiguration>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/billing_portal/configurations/{}",
+                "/v1/billing_portal/configurations/{}?{}",
                 crate::progenitor_support::encode_path(configuration),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -206,12 +380,28 @@ impl BillingPortal {
      *
      * **Parameters:**
      *
-     * * `configuration: &str` -- The account's country.
+     * * `configuration: &str`
      */
-    pub async fn post_configurations_configuration(
+    pub async fn post_configurations_configuration<B: serde::Serialize>(
         &self,
         configuration: &str,
+        body: &B,
     ) -> ClientResult<crate::types::PortalConfiguration> {
+        Ok(self
+            .post_configurations_configuration_with_response::<B>(configuration, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/billing_portal/configurations/{configuration}` endpoint.
+     *
+     * As opposed to `post_configurations_configuration`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_configurations_configuration_with_response<B: serde::Serialize>(
+        &self,
+        configuration: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PortalConfiguration>> {
         let url = self.client.url(
             &format!(
                 "/v1/billing_portal/configurations/{}",
@@ -220,10 +410,10 @@ impl BillingPortal {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -234,13 +424,27 @@ impl BillingPortal {
      *
      * <p>Creates a session of the customer portal.</p>
      */
-    pub async fn post_session(&self) -> ClientResult<crate::types::PortalSession> {
+    pub async fn post_session<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::PortalSession> {
+        Ok(self.post_session_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/billing_portal/sessions` endpoint.
+     *
+     * As opposed to `post_session`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_session_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PortalSession>> {
         let url = self.client.url("/v1/billing_portal/sessions", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,23 +18,52 @@ impl Bitcoin {
      *
      * **Parameters:**
      *
-     * * `active: bool` -- Whether the account can create live charges.
+     * * `active: bool` -- Filter for active receivers.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `filled: bool` -- Whether the account can create live charges.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `filled: bool` -- Filter for filled receivers.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `uncaptured_funds: bool` -- Whether the account can create live charges.
+     * * `uncaptured_funds: bool` -- Filter for receivers with uncaptured funds.
      */
     pub async fn get_receivers(
         &self,
         active: bool,
         ending_before: &str,
+        expand: &[String],
         filled: bool,
         limit: i64,
         starting_after: &str,
         uncaptured_funds: bool,
     ) -> ClientResult<Vec<crate::types::BitcoinReceiver>> {
+        Ok(self
+            .get_receivers_with_response(
+                active,
+                ending_before,
+                expand,
+                filled,
+                limit,
+                starting_after,
+                uncaptured_funds,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
+     *
+     * As opposed to `get_receivers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_receivers_with_response(
+        &self,
+        active: bool,
+        ending_before: &str,
+        expand: &[String],
+        filled: bool,
+        limit: i64,
+        starting_after: &str,
+        uncaptured_funds: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinReceiver>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -42,6 +71,9 @@ impl Bitcoin {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if filled {
             query_args.push(("filled".to_string(), filled.to_string()));
         }
@@ -58,9 +90,9 @@ impl Bitcoin {
         let url = self
             .client
             .url(&format!("/v1/bitcoin/receivers?{}", query_), None);
-        let resp: crate::types::GetBitcoinReceiversResponse = self
+        let resp: crate::Response<crate::types::GetBitcoinReceiversResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,7 +102,7 @@ impl Bitcoin {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
@@ -82,6 +114,7 @@ impl Bitcoin {
     pub async fn get_all_receivers(
         &self,
         active: bool,
+        expand: &[String],
         filled: bool,
         uncaptured_funds: bool,
     ) -> ClientResult<Vec<crate::types::BitcoinReceiver>> {
@@ -89,6 +122,9 @@ impl Bitcoin {
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if filled {
             query_args.push(("filled".to_string(), filled.to_string()));
         }
@@ -158,6 +194,116 @@ impl Bitcoin {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
+     *
+     * As opposed to `get_all_receivers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_receivers_pages_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+        filled: bool,
+        uncaptured_funds: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BitcoinReceiver>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if filled {
+            query_args.push(("filled".to_string(), filled.to_string()));
+        }
+        if uncaptured_funds {
+            query_args.push(("uncaptured_funds".to_string(), uncaptured_funds.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/bitcoin/receivers?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetBitcoinReceiversResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
+     *
+     * As opposed to `get_all_receivers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_receivers_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+        filled: bool,
+        uncaptured_funds: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinReceiver>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_receivers_pages_stream(
+            active,
+            expand,
+            filled,
+            uncaptured_funds,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers/{id}` endpoint.
      *
@@ -165,19 +311,41 @@ impl Bitcoin {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get_receiver(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::BitcoinReceiver> {
+        Ok(self.get_receiver_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{id}` endpoint.
+     *
+     * As opposed to `get_receiver`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_receiver(&self, id: &str) -> ClientResult<crate::types::BitcoinReceiver> {
+    pub async fn get_receiver_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BitcoinReceiver>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/bitcoin/receivers/{}",
+                "/v1/bitcoin/receivers/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -195,19 +363,46 @@ impl Bitcoin {
      *
      * * `customer: &str` -- Only return transactions for the customer specified by this customer ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `receiver: &str` -- The account's country.
+     * * `receiver: &str`
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_receivers_receiver_transactions(
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         receiver: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::BitcoinTransaction>> {
+        Ok(self
+            .get_receivers_receiver_transactions_with_response(
+                customer,
+                ending_before,
+                expand,
+                limit,
+                receiver,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
+     *
+     * As opposed to `get_receivers_receiver_transactions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_receivers_receiver_transactions_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        receiver: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -215,6 +410,9 @@ impl Bitcoin {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -230,9 +428,9 @@ impl Bitcoin {
             ),
             None,
         );
-        let resp: crate::types::Transactions = self
+        let resp: crate::Response<crate::types::Transactions> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -242,7 +440,7 @@ impl Bitcoin {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
@@ -254,12 +452,16 @@ impl Bitcoin {
     pub async fn get_all_receivers_receiver_transactions(
         &self,
         customer: &str,
+        expand: &[String],
         receiver: &str,
     ) -> ClientResult<Vec<crate::types::BitcoinTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -328,6 +530,113 @@ impl Bitcoin {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
+     *
+     * As opposed to `get_all_receivers_receiver_transactions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_receivers_receiver_transactions_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        receiver: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::BitcoinTransaction>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/bitcoin/receivers/{}/transactions?{}",
+                crate::progenitor_support::encode_path(receiver),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Transactions = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
+     *
+     * As opposed to `get_all_receivers_receiver_transactions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_receivers_receiver_transactions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        receiver: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinTransaction>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_receivers_receiver_transactions_pages_stream(
+                customer, expand, receiver, None,
+            ),
+        )
+    }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
      *
@@ -337,19 +646,46 @@ impl Bitcoin {
      *
      * * `customer: &str` -- Only return transactions for the customer specified by this customer ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `receiver: &str` -- The account's country.
+     * * `receiver: &str`
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_transactions(
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         receiver: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::BitcoinTransaction>> {
+        Ok(self
+            .get_transactions_with_response(
+                customer,
+                ending_before,
+                expand,
+                limit,
+                receiver,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_transactions_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        receiver: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BitcoinTransaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -357,6 +693,9 @@ impl Bitcoin {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -370,9 +709,9 @@ impl Bitcoin {
         let url = self
             .client
             .url(&format!("/v1/bitcoin/transactions?{}", query_), None);
-        let resp: crate::types::Transactions = self
+        let resp: crate::Response<crate::types::Transactions> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -382,7 +721,7 @@ impl Bitcoin {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
@@ -394,12 +733,16 @@ impl Bitcoin {
     pub async fn get_all_transactions(
         &self,
         customer: &str,
+        expand: &[String],
         receiver: &str,
     ) -> ClientResult<Vec<crate::types::BitcoinTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !receiver.is_empty() {
             query_args.push(("receiver".to_string(), receiver.to_string()));
         }
@@ -466,4 +809,107 @@ impl Bitcoin {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_transactions_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        receiver: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::BitcoinTransaction>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !receiver.is_empty() {
+            query_args.push(("receiver".to_string(), receiver.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/bitcoin/transactions?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Transactions = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        receiver: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BitcoinTransaction>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_transactions_pages_stream(customer, expand, receiver, None),
+        )
+    }
 }
@@ -21,7 +21,7 @@ impl Charges {
      * * `created: &str`
      * * `customer: &str` -- Only return charges for the customer specified by this customer ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `payment_intent: &str` -- Only return charges that were created by the PaymentIntent specified by this PaymentIntent ID.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -32,11 +32,42 @@ impl Charges {
         _created: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
         transfer_group: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                customer,
+                ending_before,
+                expand,
+                limit,
+                payment_intent,
+                starting_after,
+                transfer_group,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+        transfer_group: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -44,6 +75,9 @@ impl Charges {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -58,9 +92,9 @@ impl Charges {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/charges?{}", query_), None);
-        let resp: crate::types::Charges = self
+        let resp: crate::Response<crate::types::Charges> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,7 +104,7 @@ impl Charges {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/charges` endpoint.
@@ -83,6 +117,7 @@ impl Charges {
         &self,
         _created: &str,
         customer: &str,
+        expand: &[String],
         payment_intent: &str,
         transfer_group: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
@@ -90,6 +125,9 @@ impl Charges {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -157,18 +195,142 @@ impl Charges {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/charges` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        payment_intent: &str,
+        transfer_group: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Charge>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !payment_intent.is_empty() {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        if !transfer_group.is_empty() {
+            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/charges?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Charges = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        payment_intent: &str,
+        transfer_group: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            _created,
+            customer,
+            expand,
+            payment_intent,
+            transfer_group,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/charges` endpoint.
      *
      * <p>To charge a credit card or other payment source, you create a <code>Charge</code> object. If your API key is in test mode, the supplied payment source (e.g., card) won’t actually be charged, although everything else will occur as if in live mode. (Stripe assumes that the charge would have completed successfully).</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Charge> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostChargesRequest,
+    ) -> ClientResult<crate::types::Charge> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostChargesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Charge>> {
         let url = self.client.url("/v1/charges", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -184,18 +346,39 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
      * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for charges](https://stripe.com/docs/search#query-fields-for-charges).
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        Ok(self
+            .get_search_with_response(expand, limit, page, query)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_search_with_response(
+        &self,
+        expand: &[String],
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -209,9 +392,9 @@ impl Charges {
         let url = self
             .client
             .url(&format!("/v1/charges/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::Response<crate::types::SearchResult> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -221,7 +404,7 @@ impl Charges {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/charges/search` endpoint.
@@ -233,8 +416,15 @@ impl Charges {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -301,6 +491,99 @@ impl Charges {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/charges/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_search_pages_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Charge>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/charges/search?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::SearchResult = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_pages_stream(expand, query, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/charges/{charge}` endpoint.
      *
@@ -308,19 +591,37 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `charge: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
-    pub async fn get(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn get(&self, charge: &str, expand: &[String]) -> ClientResult<crate::types::Charge> {
+        Ok(self.get_with_response(charge, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Charge>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}",
+                "/v1/charges/{}?{}",
                 crate::progenitor_support::encode_path(charge),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -336,9 +637,25 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
+     * * `charge: &str`
+     */
+    pub async fn post_charges(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRequest,
+    ) -> ClientResult<crate::types::Charge> {
+        Ok(self.post_charges_with_response(charge, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges/{charge}` endpoint.
+     *
+     * As opposed to `post_charges`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_charges(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn post_charges_with_response(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeRequest,
+    ) -> ClientResult<crate::Response<crate::types::Charge>> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}",
@@ -347,10 +664,10 @@ impl Charges {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -365,9 +682,25 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
+     * * `charge: &str`
      */
-    pub async fn post_capture(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn post_capture(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeCaptureRequest,
+    ) -> ClientResult<crate::types::Charge> {
+        Ok(self.post_capture_with_response(charge, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges/{charge}/capture` endpoint.
+     *
+     * As opposed to `post_capture`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_capture_with_response(
+        &self,
+        charge: &str,
+        body: &crate::types::PostChargesChargeCaptureRequest,
+    ) -> ClientResult<crate::Response<crate::types::Charge>> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/capture",
@@ -376,10 +709,10 @@ impl Charges {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -392,19 +725,41 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `charge: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
-    pub async fn get_dispute(&self, charge: &str) -> ClientResult<crate::types::Dispute> {
+    pub async fn get_dispute(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::Dispute> {
+        Ok(self.get_dispute_with_response(charge, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/dispute` endpoint.
+     *
+     * As opposed to `get_dispute`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_dispute_with_response(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}/dispute",
+                "/v1/charges/{}/dispute?{}",
                 crate::progenitor_support::encode_path(charge),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -420,9 +775,28 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
+     * * `charge: &str`
      */
-    pub async fn post_dispute(&self, charge: &str) -> ClientResult<crate::types::Dispute> {
+    pub async fn post_dispute<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Dispute> {
+        Ok(self
+            .post_dispute_with_response::<B>(charge, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges/{charge}/dispute` endpoint.
+     *
+     * As opposed to `post_dispute`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_dispute_with_response<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/dispute",
@@ -431,10 +805,10 @@ impl Charges {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -447,9 +821,28 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
+     * * `charge: &str`
+     */
+    pub async fn post_dispute_close<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Dispute> {
+        Ok(self
+            .post_dispute_close_with_response::<B>(charge, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges/{charge}/dispute/close` endpoint.
+     *
+     * As opposed to `post_dispute_close`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_dispute_close(&self, charge: &str) -> ClientResult<crate::types::Dispute> {
+    pub async fn post_dispute_close_with_response<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/dispute/close",
@@ -458,10 +851,10 @@ impl Charges {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -484,9 +877,28 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
+     * * `charge: &str`
      */
-    pub async fn post_refund(&self, charge: &str) -> ClientResult<crate::types::Charge> {
+    pub async fn post_refund<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Charge> {
+        Ok(self
+            .post_refund_with_response::<B>(charge, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges/{charge}/refund` endpoint.
+     *
+     * As opposed to `post_refund`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_refund_with_response<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Charge>> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/refund",
@@ -495,10 +907,10 @@ impl Charges {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -511,9 +923,9 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
+     * * `charge: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -521,13 +933,35 @@ impl Charges {
         &self,
         charge: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Refund>> {
+        Ok(self
+            .get_refunds_with_response(charge, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
+     *
+     * As opposed to `get_refunds`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_refunds_with_response(
+        &self,
+        charge: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -543,9 +977,9 @@ impl Charges {
             ),
             None,
         );
-        let resp: crate::types::RefundList = self
+        let resp: crate::Response<crate::types::RefundList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -555,7 +989,7 @@ impl Charges {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
@@ -564,11 +998,21 @@ impl Charges {
      *
      * <p>You can see a list of the refunds belonging to a specific charge. Note that the 10 most recent refunds are always available by default on the charge object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
      */
-    pub async fn get_all_refunds(&self, charge: &str) -> ClientResult<Vec<crate::types::Refund>> {
+    pub async fn get_all_refunds(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Refund>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}/refunds",
+                "/v1/charges/{}/refunds?{}",
                 crate::progenitor_support::encode_path(charge),
+                query_
             ),
             None,
         );
@@ -631,6 +1075,101 @@ impl Charges {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_refunds_pages_stream(
+        &self,
+        charge: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Refund>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/charges/{}/refunds?{}",
+                crate::progenitor_support::encode_path(charge),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::RefundList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
+     *
+     * As opposed to `get_all_refunds`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_refunds_stream(
+        &self,
+        charge: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Refund>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_refunds_pages_stream(charge, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/charges/{charge}/refunds` endpoint.
      *
@@ -638,9 +1177,28 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
+     * * `charge: &str`
      */
-    pub async fn post_refund_charges(&self, charge: &str) -> ClientResult<crate::types::Refund> {
+    pub async fn post_refund_charges<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Refund> {
+        Ok(self
+            .post_refund_charges_with_response::<B>(charge, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges/{charge}/refunds` endpoint.
+     *
+     * As opposed to `post_refund_charges`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_refund_charges_with_response<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/refunds",
@@ -649,10 +1207,10 @@ impl Charges {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -665,25 +1223,48 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `refund: &str` -- The account's country.
+     * * `charge: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `refund: &str`
      */
     pub async fn get_refunds_refund(
         &self,
         charge: &str,
+        expand: &[String],
         refund: &str,
     ) -> ClientResult<crate::types::Refund> {
+        Ok(self
+            .get_refunds_refund_with_response(charge, expand, refund)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/refunds/{refund}` endpoint.
+     *
+     * As opposed to `get_refunds_refund`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_refunds_refund_with_response(
+        &self,
+        charge: &str,
+        expand: &[String],
+        refund: &str,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/charges/{}/refunds/{}",
+                "/v1/charges/{}/refunds/{}?{}",
                 crate::progenitor_support::encode_path(charge),
                 crate::progenitor_support::encode_path(refund),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -699,14 +1280,31 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- The account's country.
-     * * `refund: &str` -- The account's country.
+     * * `charge: &str`
+     * * `refund: &str`
      */
-    pub async fn post_refunds_refund(
+    pub async fn post_refunds_refund<B: serde::Serialize>(
         &self,
         charge: &str,
         refund: &str,
+        body: &B,
     ) -> ClientResult<crate::types::Refund> {
+        Ok(self
+            .post_refunds_refund_with_response::<B>(charge, refund, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/charges/{charge}/refunds/{refund}` endpoint.
+     *
+     * As opposed to `post_refunds_refund`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_refunds_refund_with_response<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        refund: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
         let url = self.client.url(
             &format!(
                 "/v1/charges/{}/refunds/{}",
@@ -716,10 +1314,10 @@ impl Charges {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
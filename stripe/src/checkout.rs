@@ -19,7 +19,7 @@ impl Checkout {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `payment_intent: &str` -- Only return the Checkout Session for the PaymentIntent specified.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -28,15 +28,45 @@ impl Checkout {
     pub async fn get_sessions(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::Session>> {
+        Ok(self
+            .get_sessions_with_response(
+                ending_before,
+                expand,
+                limit,
+                payment_intent,
+                starting_after,
+                subscription,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions` endpoint.
+     *
+     * As opposed to `get_sessions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sessions_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Session>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +83,9 @@ impl Checkout {
         let url = self
             .client
             .url(&format!("/v1/checkout/sessions?{}", query_), None);
-        let resp: crate::types::PaymentPagesCheckoutSessionList = self
+        let resp: crate::Response<crate::types::PaymentPagesCheckoutSessionList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +95,7 @@ impl Checkout {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/checkout/sessions` endpoint.
@@ -76,10 +106,14 @@ impl Checkout {
      */
     pub async fn get_all_sessions(
         &self,
+        expand: &[String],
         payment_intent: &str,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::Session>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -149,18 +183,135 @@ impl Checkout {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions` endpoint.
+     *
+     * As opposed to `get_all_sessions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_sessions_pages_stream(
+        &self,
+        expand: &[String],
+        payment_intent: &str,
+        subscription: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Session>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !payment_intent.is_empty() {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/checkout/sessions?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PaymentPagesCheckoutSessionList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions` endpoint.
+     *
+     * As opposed to `get_all_sessions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_sessions_stream(
+        &self,
+        expand: &[String],
+        payment_intent: &str,
+        subscription: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Session>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_sessions_pages_stream(
+            expand,
+            payment_intent,
+            subscription,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/checkout/sessions` endpoint.
      *
      * <p>Creates a Session object.</p>
      */
-    pub async fn post_session(&self) -> ClientResult<crate::types::Session> {
+    pub async fn post_session(
+        &self,
+        body: &crate::types::PostCheckoutSessionsRequest,
+    ) -> ClientResult<crate::types::Session> {
+        Ok(self.post_session_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/checkout/sessions` endpoint.
+     *
+     * As opposed to `post_session`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_session_with_response(
+        &self,
+        body: &crate::types::PostCheckoutSessionsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
         let url = self.client.url("/v1/checkout/sessions", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -173,19 +324,44 @@ impl Checkout {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `session: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `session: &str`
      */
-    pub async fn get_sessions_session(&self, session: &str) -> ClientResult<crate::types::Session> {
+    pub async fn get_sessions_session(
+        &self,
+        expand: &[String],
+        session: &str,
+    ) -> ClientResult<crate::types::Session> {
+        Ok(self
+            .get_sessions_session_with_response(expand, session)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions/{session}` endpoint.
+     *
+     * As opposed to `get_sessions_session`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sessions_session_with_response(
+        &self,
+        expand: &[String],
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/checkout/sessions/{}",
+                "/v1/checkout/sessions/{}?{}",
                 crate::progenitor_support::encode_path(session),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -203,12 +379,28 @@ impl Checkout {
      *
      * **Parameters:**
      *
-     * * `session: &str` -- The account's country.
+     * * `session: &str`
      */
-    pub async fn post_sessions_session_expire(
+    pub async fn post_sessions_session_expire<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> ClientResult<crate::types::Session> {
+        Ok(self
+            .post_sessions_session_expire_with_response::<B>(session, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/checkout/sessions/{session}/expire` endpoint.
+     *
+     * As opposed to `post_sessions_session_expire`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sessions_session_expire_with_response<B: serde::Serialize>(
+        &self,
+        session: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Session>> {
         let url = self.client.url(
             &format!(
                 "/v1/checkout/sessions/{}/expire",
@@ -217,10 +409,10 @@ impl Checkout {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -234,22 +426,50 @@ impl Checkout {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `session: &str` -- The account's country.
+     * * `session: &str`
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_sessions_session_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         session: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        Ok(self
+            .get_sessions_session_line_items_with_response(
+                ending_before,
+                expand,
+                limit,
+                session,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions/{session}/line_items` endpoint.
+     *
+     * As opposed to `get_sessions_session_line_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sessions_session_line_items_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        session: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -265,9 +485,9 @@ impl Checkout {
             ),
             None,
         );
-        let resp: crate::types::LineItems = self
+        let resp: crate::Response<crate::types::LineItems> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -277,7 +497,7 @@ impl Checkout {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/checkout/sessions/{session}/line_items` endpoint.
@@ -288,12 +508,19 @@ impl Checkout {
      */
     pub async fn get_all_sessions_session_line_items(
         &self,
+        expand: &[String],
         session: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/checkout/sessions/{}/line_items",
+                "/v1/checkout/sessions/{}/line_items?{}",
                 crate::progenitor_support::encode_path(session),
+                query_
             ),
             None,
         );
@@ -356,4 +583,101 @@ impl Checkout {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions/{session}/line_items` endpoint.
+     *
+     * As opposed to `get_all_sessions_session_line_items`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_sessions_session_line_items_pages_stream(
+        &self,
+        expand: &[String],
+        session: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Item>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/checkout/sessions/{}/line_items?{}",
+                crate::progenitor_support::encode_path(session),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::LineItems = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions/{session}/line_items` endpoint.
+     *
+     * As opposed to `get_all_sessions_session_line_items`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_sessions_session_line_items_stream(
+        &self,
+        expand: &[String],
+        session: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_sessions_session_line_items_pages_stream(expand, session, None),
+        )
+    }
 }
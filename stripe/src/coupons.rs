@@ -20,7 +20,7 @@ impl Coupons {
      *
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -28,13 +28,35 @@ impl Coupons {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Coupon>> {
+        Ok(self
+            .get_page_with_response(_created, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/coupons` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Coupon>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -43,9 +65,9 @@ impl Coupons {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/coupons?{}", query_), None);
-        let resp: crate::types::GetCouponsResponse = self
+        let resp: crate::Response<crate::types::GetCouponsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,7 +77,7 @@ impl Coupons {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/coupons` endpoint.
@@ -64,8 +86,17 @@ impl Coupons {
      *
      * <p>Returns a list of your coupons.</p>
      */
-    pub async fn get_all(&self, _created: &str) -> ClientResult<Vec<crate::types::Coupon>> {
-        let url = self.client.url("/v1/coupons", None);
+    pub async fn get_all(
+        &self,
+        _created: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Coupon>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/coupons?{}", query_), None);
         let mut resp: crate::types::GetCouponsResponse = self
             .client
             .get(
@@ -125,6 +156,94 @@ impl Coupons {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/coupons` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Coupon>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/coupons?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetCouponsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/coupons` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Coupon>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(_created, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/coupons` endpoint.
      *
@@ -132,13 +251,27 @@ impl Coupons {
      *
      * <p>A coupon has either a <code>percent_off</code> or an <code>amount_off</code> and <code>currency</code>. If you set an <code>amount_off</code>, that amount will be subtracted from any invoice’s subtotal. For example, an invoice with a subtotal of <currency>100</currency> will have a final total of <currency>0</currency> if a coupon with an <code>amount_off</code> of <amount>200</amount> is applied to it and an invoice with a subtotal of <currency>300</currency> will have a final total of <currency>100</currency> if a coupon with an <code>amount_off</code> of <amount>200</amount> is applied to it.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Coupon> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostCouponsRequest,
+    ) -> ClientResult<crate::types::Coupon> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/coupons` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostCouponsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Coupon>> {
         let url = self.client.url("/v1/coupons", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -151,19 +284,37 @@ impl Coupons {
      *
      * **Parameters:**
      *
-     * * `coupon: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `coupon: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
-    pub async fn get(&self, coupon: &str) -> ClientResult<crate::types::Coupon> {
+    pub async fn get(&self, coupon: &str, expand: &[String]) -> ClientResult<crate::types::Coupon> {
+        Ok(self.get_with_response(coupon, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/coupons/{coupon}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        coupon: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Coupon>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/coupons/{}",
+                "/v1/coupons/{}?{}",
                 crate::progenitor_support::encode_path(coupon),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -179,9 +330,25 @@ impl Coupons {
      *
      * **Parameters:**
      *
-     * * `coupon: &str` -- The account's country.
+     * * `coupon: &str`
      */
-    pub async fn post_coupons(&self, coupon: &str) -> ClientResult<crate::types::Coupon> {
+    pub async fn post_coupons(
+        &self,
+        coupon: &str,
+        body: &crate::types::PostCouponsCouponRequest,
+    ) -> ClientResult<crate::types::Coupon> {
+        Ok(self.post_coupons_with_response(coupon, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/coupons/{coupon}` endpoint.
+     *
+     * As opposed to `post_coupons`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_coupons_with_response(
+        &self,
+        coupon: &str,
+        body: &crate::types::PostCouponsCouponRequest,
+    ) -> ClientResult<crate::Response<crate::types::Coupon>> {
         let url = self.client.url(
             &format!(
                 "/v1/coupons/{}",
@@ -190,10 +357,10 @@ impl Coupons {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -206,9 +373,20 @@ impl Coupons {
      *
      * **Parameters:**
      *
-     * * `coupon: &str` -- The account's country.
+     * * `coupon: &str`
      */
     pub async fn delete(&self, coupon: &str) -> ClientResult<crate::types::DeletedCoupon> {
+        Ok(self.delete_with_response(coupon).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/coupons/{coupon}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        coupon: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedCoupon>> {
         let url = self.client.url(
             &format!(
                 "/v1/coupons/{}",
@@ -217,7 +395,7 @@ impl Coupons {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
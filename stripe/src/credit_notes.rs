@@ -20,7 +20,7 @@ impl CreditNotes {
      *
      * * `customer: &str` -- Only return credit notes for the customer specified by this customer ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `invoice: &str` -- Only return credit notes for the invoice specified by this invoice ID.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -29,10 +29,37 @@ impl CreditNotes {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         invoice: &str,
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::CreditNote>> {
+        Ok(self
+            .get_page_with_response(
+                customer,
+                ending_before,
+                expand,
+                invoice,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        invoice: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNote>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -40,6 +67,9 @@ impl CreditNotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -53,9 +83,9 @@ impl CreditNotes {
         let url = self
             .client
             .url(&format!("/v1/credit_notes?{}", query_), None);
-        let resp: crate::types::CreditNotesList = self
+        let resp: crate::Response<crate::types::CreditNotesList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +95,7 @@ impl CreditNotes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/credit_notes` endpoint.
@@ -77,12 +107,16 @@ impl CreditNotes {
     pub async fn get_all(
         &self,
         customer: &str,
+        expand: &[String],
         invoice: &str,
     ) -> ClientResult<Vec<crate::types::CreditNote>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -149,6 +183,105 @@ impl CreditNotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        invoice: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CreditNote>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/credit_notes?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::CreditNotesList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        invoice: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNote>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(customer, expand, invoice, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/credit_notes` endpoint.
      *
@@ -167,13 +300,27 @@ impl CreditNotes {
      * <p>You may issue multiple credit notes for an invoice. Each credit note will increment the invoice’s <code>pre_payment_credit_notes_amount</code>
      * or <code>post_payment_credit_notes_amount</code> depending on its <code>status</code> at the time of credit note creation.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::CreditNote> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostCreditNotesRequest,
+    ) -> ClientResult<crate::types::CreditNote> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostCreditNotesRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreditNote>> {
         let url = self.client.url("/v1/credit_notes", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -186,14 +333,14 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
+     * * `amount: i64` -- The integer amount in **%s** representing the total amount of the credit note.
      * * `credit_amount: i64` -- The integer amount in %s representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `invoice: &str` -- ID of the invoice.
      * * `lines: &[String]` -- Line items that make up the credit note.
      * * `memo: &str` -- The credit note's memo appears on the credit note PDF.
      * * `metadata: &str` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
-     * * `out_of_band_amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
+     * * `out_of_band_amount: i64` -- The integer amount in **%s** representing the amount that is credited outside of Stripe.
      * * `reason: crate::types::Reason` -- Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
      * * `refund: &str` -- ID of an existing refund to link this credit note to.
      * * `refund_amount: i64` -- The integer amount in %s representing the amount to refund. If set, a refund will be created for the charge associated with the invoice.
@@ -202,6 +349,7 @@ impl CreditNotes {
         &self,
         amount: i64,
         credit_amount: i64,
+        expand: &[String],
         invoice: &str,
         _lines: &[String],
         memo: &str,
@@ -211,6 +359,42 @@ impl CreditNotes {
         refund: &str,
         refund_amount: i64,
     ) -> ClientResult<crate::types::CreditNote> {
+        Ok(self
+            .get_preview_with_response(
+                amount,
+                credit_amount,
+                expand,
+                invoice,
+                _lines,
+                memo,
+                _metadata,
+                out_of_band_amount,
+                reason,
+                refund,
+                refund_amount,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview` endpoint.
+     *
+     * As opposed to `get_preview`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_preview_with_response(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        expand: &[String],
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+    ) -> ClientResult<crate::Response<crate::types::CreditNote>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if amount > 0 {
             query_args.push(("amount".to_string(), amount.to_string()));
@@ -218,6 +402,9 @@ impl CreditNotes {
         if credit_amount > 0 {
             query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -244,7 +431,7 @@ impl CreditNotes {
             .client
             .url(&format!("/v1/credit_notes/preview?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -260,16 +447,16 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
+     * * `amount: i64` -- The integer amount in **%s** representing the total amount of the credit note.
      * * `credit_amount: i64` -- The integer amount in %s representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `invoice: &str` -- ID of the invoice.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `lines: &[String]` -- Line items that make up the credit note.
      * * `memo: &str` -- The credit note's memo appears on the credit note PDF.
      * * `metadata: &str` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
-     * * `out_of_band_amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
+     * * `out_of_band_amount: i64` -- The integer amount in **%s** representing the amount that is credited outside of Stripe.
      * * `reason: crate::types::Reason` -- Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
      * * `refund: &str` -- ID of an existing refund to link this credit note to.
      * * `refund_amount: i64` -- The integer amount in %s representing the amount to refund. If set, a refund will be created for the charge associated with the invoice.
@@ -280,6 +467,7 @@ impl CreditNotes {
         amount: i64,
         credit_amount: i64,
         ending_before: &str,
+        expand: &[String],
         invoice: &str,
         limit: i64,
         _lines: &[String],
@@ -291,6 +479,48 @@ impl CreditNotes {
         refund_amount: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::CreditNoteLineItem>> {
+        Ok(self
+            .get_preview_lines_with_response(
+                amount,
+                credit_amount,
+                ending_before,
+                expand,
+                invoice,
+                limit,
+                _lines,
+                memo,
+                _metadata,
+                out_of_band_amount,
+                reason,
+                refund,
+                refund_amount,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_preview_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_preview_lines_with_response(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        ending_before: &str,
+        expand: &[String],
+        invoice: &str,
+        limit: i64,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if amount > 0 {
             query_args.push(("amount".to_string(), amount.to_string()));
@@ -301,6 +531,9 @@ impl CreditNotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -332,9 +565,9 @@ impl CreditNotes {
         let url = self
             .client
             .url(&format!("/v1/credit_notes/preview/lines?{}", query_), None);
-        let resp: crate::types::Lines = self
+        let resp: crate::Response<crate::types::Lines> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -344,7 +577,7 @@ impl CreditNotes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
@@ -357,6 +590,7 @@ impl CreditNotes {
         &self,
         amount: i64,
         credit_amount: i64,
+        expand: &[String],
         invoice: &str,
         _lines: &[String],
         memo: &str,
@@ -373,6 +607,9 @@ impl CreditNotes {
         if credit_amount > 0 {
             query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -457,6 +694,157 @@ impl CreditNotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_all_preview_lines`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_preview_lines_pages_stream(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        expand: &[String],
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CreditNoteLineItem>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if amount > 0 {
+            query_args.push(("amount".to_string(), amount.to_string()));
+        }
+        if credit_amount > 0 {
+            query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        if !memo.is_empty() {
+            query_args.push(("memo".to_string(), memo.to_string()));
+        }
+        if out_of_band_amount > 0 {
+            query_args.push((
+                "out_of_band_amount".to_string(),
+                out_of_band_amount.to_string(),
+            ));
+        }
+        if !reason.to_string().is_empty() {
+            query_args.push(("reason".to_string(), reason.to_string()));
+        }
+        if !refund.is_empty() {
+            query_args.push(("refund".to_string(), refund.to_string()));
+        }
+        if refund_amount > 0 {
+            query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/credit_notes/preview/lines?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Lines = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_all_preview_lines`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_preview_lines_stream(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        expand: &[String],
+        invoice: &str,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNoteLineItem>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_preview_lines_pages_stream(
+            amount,
+            credit_amount,
+            expand,
+            invoice,
+            _lines,
+            memo,
+            _metadata,
+            out_of_band_amount,
+            reason,
+            refund,
+            refund_amount,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
      *
@@ -464,9 +852,9 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `credit_note: &str` -- The account's country.
+     * * `credit_note: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -474,13 +862,35 @@ impl CreditNotes {
         &self,
         credit_note: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::CreditNoteLineItem>> {
+        Ok(self
+            .get_note_lines_with_response(credit_note, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_note_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_note_lines_with_response(
+        &self,
+        credit_note: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CreditNoteLineItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -496,9 +906,9 @@ impl CreditNotes {
             ),
             None,
         );
-        let resp: crate::types::Lines = self
+        let resp: crate::Response<crate::types::Lines> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -508,7 +918,7 @@ impl CreditNotes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
@@ -520,11 +930,18 @@ impl CreditNotes {
     pub async fn get_all_note_lines(
         &self,
         credit_note: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::CreditNoteLineItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/credit_notes/{}/lines",
+                "/v1/credit_notes/{}/lines?{}",
                 crate::progenitor_support::encode_path(credit_note),
+                query_
             ),
             None,
         );
@@ -587,6 +1004,104 @@ impl CreditNotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_all_note_lines`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_note_lines_pages_stream(
+        &self,
+        credit_note: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CreditNoteLineItem>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/credit_notes/{}/lines?{}",
+                crate::progenitor_support::encode_path(credit_note),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Lines = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_all_note_lines`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_note_lines_stream(
+        &self,
+        credit_note: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CreditNoteLineItem>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_note_lines_pages_stream(credit_note, expand, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{id}` endpoint.
      *
@@ -594,19 +1109,37 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get(&self, expand: &[String], id: &str) -> ClientResult<crate::types::CreditNote> {
+        Ok(self.get_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::CreditNote> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::CreditNote>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/credit_notes/{}",
+                "/v1/credit_notes/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -622,9 +1155,25 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
-    pub async fn post_credit_notes(&self, id: &str) -> ClientResult<crate::types::CreditNote> {
+    pub async fn post_credit_notes(
+        &self,
+        id: &str,
+        body: &crate::types::PostCreditNotesRequest,
+    ) -> ClientResult<crate::types::CreditNote> {
+        Ok(self.post_credit_notes_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/credit_notes/{id}` endpoint.
+     *
+     * As opposed to `post_credit_notes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_credit_notes_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostCreditNotesRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreditNote>> {
         let url = self.client.url(
             &format!(
                 "/v1/credit_notes/{}",
@@ -633,10 +1182,10 @@ impl CreditNotes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -649,9 +1198,25 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
-    pub async fn post_void(&self, id: &str) -> ClientResult<crate::types::CreditNote> {
+    pub async fn post_void(
+        &self,
+        id: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::types::CreditNote> {
+        Ok(self.post_void_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/credit_notes/{id}/void` endpoint.
+     *
+     * As opposed to `post_void`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_void_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreditNote>> {
         let url = self.client.url(
             &format!(
                 "/v1/credit_notes/{}/void",
@@ -660,10 +1225,10 @@ impl CreditNotes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -21,7 +21,7 @@ impl Customers {
      * * `created: &str`
      * * `email: &str` -- A case-sensitive filter on the list based on the customer's `email` field. The value must be a string.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `test_clock: &str` -- Provides a list of customers that are associated with the specified test clock. The response will not include customers with test clocks if this parameter is not set.
@@ -31,10 +31,39 @@ impl Customers {
         _created: &str,
         email: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Customer>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                email,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                test_clock,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        email: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Customer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
@@ -42,6 +71,9 @@ impl Customers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +85,9 @@ impl Customers {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/customers?{}", query_), None);
-        let resp: crate::types::GetCustomersResponse = self
+        let resp: crate::Response<crate::types::GetCustomersResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers` endpoint.
@@ -78,12 +110,16 @@ impl Customers {
         &self,
         _created: &str,
         email: &str,
+        expand: &[String],
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !test_clock.is_empty() {
             query_args.push(("test_clock".to_string(), test_clock.to_string()));
         }
@@ -148,18 +184,132 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        email: &str,
+        expand: &[String],
+        test_clock: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Customer>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !email.is_empty() {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !test_clock.is_empty() {
+            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/customers?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetCustomersResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        email: &str,
+        expand: &[String],
+        test_clock: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Customer>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(_created, email, expand, test_clock, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/customers` endpoint.
      *
      * <p>Creates a new customer object.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Customer> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostCustomersRequest,
+    ) -> ClientResult<crate::types::Customer> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostCustomersRequest,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
         let url = self.client.url("/v1/customers", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -175,18 +325,39 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
      * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for customers](https://stripe.com/docs/search#query-fields-for-customers).
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        Ok(self
+            .get_search_with_response(expand, limit, page, query)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_search_with_response(
+        &self,
+        expand: &[String],
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -200,9 +371,9 @@ impl Customers {
         let url = self
             .client
             .url(&format!("/v1/customers/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::Response<crate::types::SearchResult> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -212,7 +383,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/search` endpoint.
@@ -224,8 +395,15 @@ impl Customers {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -292,6 +470,99 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_search_pages_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Charge>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/customers/search?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::SearchResult = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_pages_stream(expand, query, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}` endpoint.
      *
@@ -299,22 +570,41 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::GetCustomersCustomerResponseAnyOf> {
+        Ok(self.get_with_response(customer, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::GetCustomersCustomerResponseAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}",
+                "/v1/customers/{}?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -332,9 +622,28 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
+     */
+    pub async fn post_customers(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerRequest,
+    ) -> ClientResult<crate::types::Customer> {
+        Ok(self
+            .post_customers_with_response(customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}` endpoint.
+     *
+     * As opposed to `post_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_customers(&self, customer: &str) -> ClientResult<crate::types::Customer> {
+    pub async fn post_customers_with_response(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerRequest,
+    ) -> ClientResult<crate::Response<crate::types::Customer>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}",
@@ -343,10 +652,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -359,9 +668,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      */
     pub async fn delete(&self, customer: &str) -> ClientResult<crate::types::DeletedCustomer> {
+        Ok(self.delete_with_response(customer).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedCustomer>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}",
@@ -370,7 +690,7 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -386,9 +706,9 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -396,13 +716,41 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::CustomerBalanceTransaction>> {
+        Ok(self
+            .get_balance_transactions_with_response(
+                customer,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
+     *
+     * As opposed to `get_balance_transactions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_balance_transactions_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerBalanceTransaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -418,9 +766,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::CustomerBalanceTransactionList = self
+        let resp: crate::Response<crate::types::CustomerBalanceTransactionList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -430,7 +778,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
@@ -442,11 +790,18 @@ impl Customers {
     pub async fn get_all_balance_transactions(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::CustomerBalanceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/balance_transactions",
+                "/v1/customers/{}/balance_transactions?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -509,6 +864,106 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
+     *
+     * As opposed to `get_all_balance_transactions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_balance_transactions_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CustomerBalanceTransaction>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/balance_transactions?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::CustomerBalanceTransactionList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
+     *
+     * As opposed to `get_all_balance_transactions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_balance_transactions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CustomerBalanceTransaction>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_balance_transactions_pages_stream(customer, expand, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/balance_transactions` endpoint.
      *
@@ -516,12 +971,28 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      */
     pub async fn post_balance_transaction(
         &self,
         customer: &str,
+        body: &crate::types::PostCustomersCustomerBalanceTransactionsRequest,
     ) -> ClientResult<crate::types::CustomerBalanceTransaction> {
+        Ok(self
+            .post_balance_transaction_with_response(customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/balance_transactions` endpoint.
+     *
+     * As opposed to `post_balance_transaction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_balance_transaction_with_response(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerBalanceTransactionsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CustomerBalanceTransaction>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/balance_transactions",
@@ -530,10 +1001,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -546,25 +1017,48 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `transaction: &str` -- The account's country.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `transaction: &str`
      */
     pub async fn get_balance_transactions_transaction(
         &self,
         customer: &str,
+        expand: &[String],
         transaction: &str,
     ) -> ClientResult<crate::types::CustomerBalanceTransaction> {
+        Ok(self
+            .get_balance_transactions_transaction_with_response(customer, expand, transaction)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions/{transaction}` endpoint.
+     *
+     * As opposed to `get_balance_transactions_transaction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_balance_transactions_transaction_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+        transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::CustomerBalanceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/balance_transactions/{}",
+                "/v1/customers/{}/balance_transactions/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(transaction),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -580,14 +1074,31 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `transaction: &str` -- The account's country.
+     * * `customer: &str`
+     * * `transaction: &str`
      */
     pub async fn post_balance_transactions_transaction(
         &self,
         customer: &str,
         transaction: &str,
+        body: &crate::types::PostTopupsTopupRequest,
     ) -> ClientResult<crate::types::CustomerBalanceTransaction> {
+        Ok(self
+            .post_balance_transactions_transaction_with_response(customer, transaction, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/balance_transactions/{transaction}` endpoint.
+     *
+     * As opposed to `post_balance_transactions_transaction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_balance_transactions_transaction_with_response(
+        &self,
+        customer: &str,
+        transaction: &str,
+        body: &crate::types::PostTopupsTopupRequest,
+    ) -> ClientResult<crate::Response<crate::types::CustomerBalanceTransaction>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/balance_transactions/{}",
@@ -597,10 +1108,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -613,9 +1124,9 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -623,13 +1134,35 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::BankAccount>> {
+        Ok(self
+            .get_bank_accounts_with_response(customer, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_bank_accounts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_bank_accounts_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BankAccount>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -645,9 +1178,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::BankAccountList = self
+        let resp: crate::Response<crate::types::BankAccountList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -657,7 +1190,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
@@ -669,11 +1202,18 @@ impl Customers {
     pub async fn get_all_bank_accounts(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::BankAccount>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/bank_accounts",
+                "/v1/customers/{}/bank_accounts?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -737,65 +1277,200 @@ impl Customers {
         Ok(data.to_vec())
     }
     /**
-     * This function performs a `POST` to the `/v1/customers/{customer}/bank_accounts` endpoint.
-     *
-     * <p>When you create a new credit card, you must specify a customer or recipient on which to create it.</p>
-     *
-     * <p>If the card’s owner has no default card, then the new card will become the default.
-     * However, if the owner already has a default, then it will not change.
-     * To change the default, you should <a href="/docs/api#update_customer">update the customer</a> to have a new <code>default_source</code>.</p>
-     *
-     * **Parameters:**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
      *
-     * * `customer: &str` -- The account's country.
+     * As opposed to `get_all_bank_accounts`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
      */
-    pub async fn post_bank_account(
+    pub fn get_all_bank_accounts_pages_stream(
         &self,
         customer: &str,
-    ) -> ClientResult<crate::types::PaymentSourceAnyOf> {
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BankAccount>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/bank_accounts",
+                "/v1/customers/{}/bank_accounts?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("application/x-www-form-urlencoded".to_string()),
-                },
-            )
-            .await
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::BankAccountList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
     }
     /**
-     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts/{id}` endpoint.
-     *
-     * <p>By default, you can see the 10 most recent sources stored on a Customer directly on the object, but you can also retrieve details about a specific bank account stored on the Stripe account.</p>
-     *
-     * **Parameters:**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * As opposed to `get_all_bank_accounts`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_bank_accounts_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BankAccount>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_bank_accounts_pages_stream(customer, expand, None))
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/bank_accounts` endpoint.
+     *
+     * <p>When you create a new credit card, you must specify a customer or recipient on which to create it.</p>
+     *
+     * <p>If the card’s owner has no default card, then the new card will become the default.
+     * However, if the owner already has a default, then it will not change.
+     * To change the default, you should <a href="/docs/api#update_customer">update the customer</a> to have a new <code>default_source</code>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str`
+     */
+    pub async fn post_bank_account<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::PaymentSourceAnyOf> {
+        Ok(self
+            .post_bank_account_with_response::<B>(customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/bank_accounts` endpoint.
+     *
+     * As opposed to `post_bank_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_bank_account_with_response<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/bank_accounts",
+                crate::progenitor_support::encode_path(customer),
+            ),
+            None,
+        );
+        self.client
+            .post_with_response(
+                &url,
+                crate::Message {
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
+                    content_type: Some("application/x-www-form-urlencoded".to_string()),
+                },
+            )
+            .await
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts/{id}` endpoint.
+     *
+     * <p>By default, you can see the 10 most recent sources stored on a Customer directly on the object, but you can also retrieve details about a specific bank account stored on the Stripe account.</p>
+     *
+     * **Parameters:**
+     *
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      */
     pub async fn get_bank_account(
         &self,
         customer: &str,
+        expand: &[String],
         id: &str,
     ) -> ClientResult<crate::types::BankAccount> {
+        Ok(self
+            .get_bank_account_with_response(customer, expand, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `get_bank_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_bank_account_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BankAccount>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/bank_accounts/{}",
+                "/v1/customers/{}/bank_accounts/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -811,14 +1486,31 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
-    pub async fn post_bank_account_customers(
+    pub async fn post_bank_account_customers<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> ClientResult<crate::types::SourceAnyOf> {
+        Ok(self
+            .post_bank_account_customers_with_response::<B>(customer, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `post_bank_account_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_bank_account_customers_with_response<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::SourceAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/bank_accounts/{}",
@@ -828,10 +1520,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -844,14 +1536,30 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
     pub async fn delete_bank_accounts(
         &self,
         customer: &str,
         id: &str,
     ) -> ClientResult<crate::types::DeleteCustomersCustomerCardsResponseAnyOf> {
+        Ok(self
+            .delete_bank_accounts_with_response(customer, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}/bank_accounts/{id}` endpoint.
+     *
+     * As opposed to `delete_bank_accounts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_bank_accounts_with_response(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteCustomersCustomerCardsResponseAnyOf>>
+    {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/bank_accounts/{}",
@@ -861,7 +1569,7 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -877,14 +1585,31 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
-    pub async fn post_bank_accounts_verify(
+    pub async fn post_bank_accounts_verify<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> ClientResult<crate::types::BankAccount> {
+        Ok(self
+            .post_bank_accounts_verify_with_response::<B>(customer, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/bank_accounts/{id}/verify` endpoint.
+     *
+     * As opposed to `post_bank_accounts_verify`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_bank_accounts_verify_with_response<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::BankAccount>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/bank_accounts/{}/verify",
@@ -894,10 +1619,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -912,9 +1637,9 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -922,13 +1647,35 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Card>> {
+        Ok(self
+            .get_cards_with_response(customer, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
+     *
+     * As opposed to `get_cards`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_cards_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Card>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -944,9 +1691,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::Cards = self
+        let resp: crate::Response<crate::types::Cards> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -956,7 +1703,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
@@ -967,11 +1714,21 @@ impl Customers {
      * Note that the 10 most recent sources are always available on the <code>Customer</code> object.
      * If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional cards.</p>
      */
-    pub async fn get_all_cards(&self, customer: &str) -> ClientResult<Vec<crate::types::Card>> {
+    pub async fn get_all_cards(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::Card>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/cards",
+                "/v1/customers/{}/cards?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -1034,6 +1791,101 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_cards_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Card>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/cards?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Cards = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_cards_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Card>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_cards_pages_stream(customer, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/cards` endpoint.
      *
@@ -1045,12 +1897,28 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      */
-    pub async fn post_card(
+    pub async fn post_card<B: serde::Serialize>(
         &self,
         customer: &str,
+        body: &B,
     ) -> ClientResult<crate::types::PaymentSourceAnyOf> {
+        Ok(self
+            .post_card_with_response::<B>(customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/cards` endpoint.
+     *
+     * As opposed to `post_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_card_with_response<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/cards",
@@ -1059,10 +1927,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1075,21 +1943,48 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get_card(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::Card> {
+        Ok(self
+            .get_card_with_response(customer, expand, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/cards/{id}` endpoint.
+     *
+     * As opposed to `get_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_card(&self, customer: &str, id: &str) -> ClientResult<crate::types::Card> {
+    pub async fn get_card_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Card>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/cards/{}",
+                "/v1/customers/{}/cards/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1105,14 +2000,31 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
-    pub async fn post_card_customers(
+    pub async fn post_card_customers<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> ClientResult<crate::types::SourceAnyOf> {
+        Ok(self
+            .post_card_customers_with_response::<B>(customer, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/cards/{id}` endpoint.
+     *
+     * As opposed to `post_card_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_card_customers_with_response<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::SourceAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/cards/{}",
@@ -1122,10 +2034,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1138,14 +2050,27 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
     pub async fn delete_cards(
         &self,
         customer: &str,
         id: &str,
     ) -> ClientResult<crate::types::DeleteCustomersCustomerCardsResponseAnyOf> {
+        Ok(self.delete_cards_with_response(customer, id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}/cards/{id}` endpoint.
+     *
+     * As opposed to `delete_cards`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_cards_with_response(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeleteCustomersCustomerCardsResponseAnyOf>>
+    {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/cards/{}",
@@ -1155,7 +2080,7 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1171,19 +2096,44 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     */
+    pub async fn get_discount(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::DiscountData> {
+        Ok(self
+            .get_discount_with_response(customer, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/discount` endpoint.
+     *
+     * As opposed to `get_discount`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_discount(&self, customer: &str) -> ClientResult<crate::types::DiscountData> {
+    pub async fn get_discount_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::DiscountData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/discount",
+                "/v1/customers/{}/discount?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1199,12 +2149,23 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      */
     pub async fn delete_discount(
         &self,
         customer: &str,
     ) -> ClientResult<crate::types::DeletedDiscount> {
+        Ok(self.delete_discount_with_response(customer).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}/discount` endpoint.
+     *
+     * As opposed to `delete_discount`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_discount_with_response(
+        &self,
+        customer: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedDiscount>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/discount",
@@ -1213,7 +2174,7 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1229,9 +2190,9 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `type_: crate::types::GetCustomersCustomerPaymentMethodsType` -- A required filter on the list, based on the object `type` field.
@@ -1240,14 +2201,44 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> ClientResult<Vec<crate::types::PaymentMethod>> {
+        Ok(self
+            .get_payment_methods_with_response(
+                customer,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
+     *
+     * As opposed to `get_payment_methods`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_payment_methods_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1266,9 +2257,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::PaymentFlowsMethodList = self
+        let resp: crate::Response<crate::types::PaymentFlowsMethodList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1278,7 +2269,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
@@ -1290,9 +2281,13 @@ impl Customers {
     pub async fn get_all_payment_methods(
         &self,
         customer: &str,
+        expand: &[String],
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> ClientResult<Vec<crate::types::PaymentMethod>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -1364,6 +2359,109 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
+     *
+     * As opposed to `get_all_payment_methods`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_payment_methods_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PaymentMethod>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/payment_methods?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PaymentFlowsMethodList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
+     *
+     * As opposed to `get_all_payment_methods`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_payment_methods_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaymentMethod>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_payment_methods_pages_stream(customer, expand, type_, None),
+        )
+    }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
      *
@@ -1371,9 +2469,9 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `object: &str` -- Filter sources according to a particular object type.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -1382,14 +2480,44 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         object: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::CustomerSourcesDataAnyOf>> {
+        Ok(self
+            .get_sources_with_response(
+                customer,
+                ending_before,
+                expand,
+                limit,
+                object,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
+     *
+     * As opposed to `get_sources`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_sources_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        object: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CustomerSourcesDataAnyOf>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1408,9 +2536,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::Sources = self
+        let resp: crate::Response<crate::types::Sources> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1420,7 +2548,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
@@ -1432,9 +2560,13 @@ impl Customers {
     pub async fn get_all_sources(
         &self,
         customer: &str,
+        expand: &[String],
         object: &str,
     ) -> ClientResult<Vec<crate::types::CustomerSourcesDataAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !object.is_empty() {
             query_args.push(("object".to_string(), object.to_string()));
         }
@@ -1506,6 +2638,109 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
+     *
+     * As opposed to `get_all_sources`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_sources_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        object: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::CustomerSourcesDataAnyOf>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !object.is_empty() {
+            query_args.push(("object".to_string(), object.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/sources?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Sources = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
+     *
+     * As opposed to `get_all_sources`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_sources_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        object: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CustomerSourcesDataAnyOf>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_sources_pages_stream(customer, expand, object, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/sources` endpoint.
      *
@@ -1517,12 +2752,25 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      */
     pub async fn post_source(
         &self,
         customer: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequest,
     ) -> ClientResult<crate::types::PaymentSourceAnyOf> {
+        Ok(self.post_source_with_response(customer, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/sources` endpoint.
+     *
+     * As opposed to `post_source`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_source_with_response(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/sources",
@@ -1531,10 +2779,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1547,25 +2795,48 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      */
     pub async fn get_source(
         &self,
         customer: &str,
+        expand: &[String],
         id: &str,
     ) -> ClientResult<crate::types::PaymentSourceAnyOf> {
+        Ok(self
+            .get_source_with_response(customer, expand, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/sources/{id}` endpoint.
+     *
+     * As opposed to `get_source`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_source_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentSourceAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/sources/{}",
+                "/v1/customers/{}/sources/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1581,14 +2852,31 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
     pub async fn post_source_customers(
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequest,
     ) -> ClientResult<crate::types::SourceAnyOf> {
+        Ok(self
+            .post_source_customers_with_response(customer, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/sources/{id}` endpoint.
+     *
+     * As opposed to `post_source_customers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_source_customers_with_response(
+        &self,
+        customer: &str,
+        id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesRequest,
+    ) -> ClientResult<crate::Response<crate::types::SourceAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/sources/{}",
@@ -1598,10 +2886,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1614,14 +2902,32 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
     pub async fn delete_sources(
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
     ) -> ClientResult<crate::types::DeleteCustomersCustomerCardsResponseAnyOf> {
+        Ok(self
+            .delete_sources_with_response(customer, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}/sources/{id}` endpoint.
+     *
+     * As opposed to `delete_sources`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_sources_with_response(
+        &self,
+        customer: &str,
+        id: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeleteCustomersCustomerCardsResponseAnyOf>>
+    {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/sources/{}",
@@ -1631,10 +2937,10 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1647,14 +2953,31 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
     pub async fn post_sources_verify(
         &self,
         customer: &str,
         id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesVerifyRequest,
     ) -> ClientResult<crate::types::BankAccount> {
+        Ok(self
+            .post_sources_verify_with_response(customer, id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/sources/{id}/verify` endpoint.
+     *
+     * As opposed to `post_sources_verify`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sources_verify_with_response(
+        &self,
+        customer: &str,
+        id: &str,
+        body: &crate::types::PostCustomersCustomerSourcesVerifyRequest,
+    ) -> ClientResult<crate::Response<crate::types::BankAccount>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/sources/{}/verify",
@@ -1664,10 +2987,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1680,9 +3003,9 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -1690,13 +3013,35 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Subscription>> {
+        Ok(self
+            .get_subscriptions_with_response(customer, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
+     *
+     * As opposed to `get_subscriptions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subscriptions_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1712,9 +3057,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::Subscriptions = self
+        let resp: crate::Response<crate::types::Subscriptions> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1724,7 +3069,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
@@ -1736,11 +3081,18 @@ impl Customers {
     pub async fn get_all_subscriptions(
         &self,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::Subscription>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/subscriptions",
+                "/v1/customers/{}/subscriptions?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -1803,6 +3155,102 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
+     *
+     * As opposed to `get_all_subscriptions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_subscriptions_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Subscription>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/subscriptions?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Subscriptions = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
+     *
+     * As opposed to `get_all_subscriptions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_subscriptions_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subscription>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_subscriptions_pages_stream(customer, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/subscriptions` endpoint.
      *
@@ -1810,12 +3258,28 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      */
-    pub async fn post_subscription(
+    pub async fn post_subscription<B: serde::Serialize>(
         &self,
         customer: &str,
+        body: &B,
     ) -> ClientResult<crate::types::Subscription> {
+        Ok(self
+            .post_subscription_with_response::<B>(customer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/subscriptions` endpoint.
+     *
+     * As opposed to `post_subscription`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_subscription_with_response<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/subscriptions",
@@ -1824,10 +3288,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1840,25 +3304,52 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `subscription_exposed_id: &str`
      */
     pub async fn get_subscriptions_subscription_exposed(
         &self,
         customer: &str,
+        expand: &[String],
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::Subscription> {
+        Ok(self
+            .get_subscriptions_subscription_exposed_with_response(
+                customer,
+                expand,
+                subscription_exposed_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}` endpoint.
+     *
+     * As opposed to `get_subscriptions_subscription_exposed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subscriptions_subscription_exposed_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/subscriptions/{}",
+                "/v1/customers/{}/subscriptions/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(subscription_exposed_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1874,14 +3365,35 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `subscription_exposed_id: &str`
      */
-    pub async fn post_subscriptions_subscription_exposed(
+    pub async fn post_subscriptions_subscription_exposed<B: serde::Serialize>(
         &self,
         customer: &str,
         subscription_exposed_id: &str,
+        body: &B,
     ) -> ClientResult<crate::types::Subscription> {
+        Ok(self
+            .post_subscriptions_subscription_exposed_with_response::<B>(
+                customer,
+                subscription_exposed_id,
+                body,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}` endpoint.
+     *
+     * As opposed to `post_subscriptions_subscription_exposed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_subscriptions_subscription_exposed_with_response<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/subscriptions/{}",
@@ -1891,10 +3403,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1911,14 +3423,32 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `subscription_exposed_id: &str`
      */
     pub async fn delete_subscriptions_subscription_exposed(
         &self,
         customer: &str,
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::Subscription> {
+        Ok(self
+            .delete_subscriptions_subscription_exposed_with_response(
+                customer,
+                subscription_exposed_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}` endpoint.
+     *
+     * As opposed to `delete_subscriptions_subscription_exposed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_subscriptions_subscription_exposed_with_response(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/subscriptions/{}",
@@ -1928,7 +3458,7 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1944,25 +3474,52 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `subscription_exposed_id: &str`
      */
     pub async fn get_subscriptions_subscription_exposed_discount(
         &self,
         customer: &str,
+        expand: &[String],
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::DiscountData> {
+        Ok(self
+            .get_subscriptions_subscription_exposed_discount_with_response(
+                customer,
+                expand,
+                subscription_exposed_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}/discount` endpoint.
+     *
+     * As opposed to `get_subscriptions_subscription_exposed_discount`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_subscriptions_subscription_exposed_discount_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DiscountData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/subscriptions/{}/discount",
+                "/v1/customers/{}/subscriptions/{}/discount?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(subscription_exposed_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1978,14 +3535,32 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `subscription_exposed_id: &str`
      */
     pub async fn delete_subscriptions_subscription_exposed_discount(
         &self,
         customer: &str,
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::DeletedDiscount> {
+        Ok(self
+            .delete_subscriptions_subscription_exposed_discount_with_response(
+                customer,
+                subscription_exposed_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}/subscriptions/{subscription_exposed_id}/discount` endpoint.
+     *
+     * As opposed to `delete_subscriptions_subscription_exposed_discount`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_subscriptions_subscription_exposed_discount_with_response(
+        &self,
+        customer: &str,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedDiscount>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/subscriptions/{}/discount",
@@ -1995,7 +3570,7 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2011,9 +3586,9 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -2021,13 +3596,35 @@ impl Customers {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::TaxId>> {
+        Ok(self
+            .get_tax_ids_with_response(customer, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
+     *
+     * As opposed to `get_tax_ids`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_tax_ids_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TaxId>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -2043,9 +3640,9 @@ impl Customers {
             ),
             None,
         );
-        let resp: crate::types::TaxIds = self
+        let resp: crate::Response<crate::types::TaxIds> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2055,7 +3652,7 @@ impl Customers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
@@ -2064,11 +3661,21 @@ impl Customers {
      *
      * <p>Returns a list of tax IDs for a customer.</p>
      */
-    pub async fn get_all_tax_ids(&self, customer: &str) -> ClientResult<Vec<crate::types::TaxId>> {
+    pub async fn get_all_tax_ids(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::TaxId>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/tax_ids",
+                "/v1/customers/{}/tax_ids?{}",
                 crate::progenitor_support::encode_path(customer),
+                query_
             ),
             None,
         );
@@ -2131,6 +3738,101 @@ impl Customers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
+     *
+     * As opposed to `get_all_tax_ids`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_tax_ids_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TaxId>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/customers/{}/tax_ids?{}",
+                crate::progenitor_support::encode_path(customer),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::TaxIds = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
+     *
+     * As opposed to `get_all_tax_ids`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_tax_ids_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TaxId>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_tax_ids_pages_stream(customer, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/customers/{customer}/tax_ids` endpoint.
      *
@@ -2138,9 +3840,25 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
+     * * `customer: &str`
+     */
+    pub async fn post_tax_id(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerTaxIdsRequest,
+    ) -> ClientResult<crate::types::TaxId> {
+        Ok(self.post_tax_id_with_response(customer, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/customers/{customer}/tax_ids` endpoint.
+     *
+     * As opposed to `post_tax_id`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_tax_id(&self, customer: &str) -> ClientResult<crate::types::TaxId> {
+    pub async fn post_tax_id_with_response(
+        &self,
+        customer: &str,
+        body: &crate::types::PostCustomersCustomerTaxIdsRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaxId>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/tax_ids",
@@ -2149,10 +3867,10 @@ impl Customers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -2165,21 +3883,48 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get_tax_id(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::TaxId> {
+        Ok(self
+            .get_tax_id_with_response(customer, expand, id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids/{id}` endpoint.
+     *
+     * As opposed to `get_tax_id`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_tax_id(&self, customer: &str, id: &str) -> ClientResult<crate::types::TaxId> {
+    pub async fn get_tax_id_with_response(
+        &self,
+        customer: &str,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TaxId>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/customers/{}/tax_ids/{}",
+                "/v1/customers/{}/tax_ids/{}?{}",
                 crate::progenitor_support::encode_path(customer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2195,14 +3940,26 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The account's country.
-     * * `id: &str` -- The account's country.
+     * * `customer: &str`
+     * * `id: &str`
      */
     pub async fn delete_tax_ids(
         &self,
         customer: &str,
         id: &str,
     ) -> ClientResult<crate::types::DeletedTaxId> {
+        Ok(self.delete_tax_ids_with_response(customer, id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/customers/{customer}/tax_ids/{id}` endpoint.
+     *
+     * As opposed to `delete_tax_ids`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_tax_ids_with_response(
+        &self,
+        customer: &str,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTaxId>> {
         let url = self.client.url(
             &format!(
                 "/v1/customers/{}/tax_ids/{}",
@@ -2212,7 +3969,7 @@ impl Customers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -21,7 +21,7 @@ impl Disputes {
      * * `charge: &str` -- Only return disputes associated to the charge specified by this charge ID.
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `payment_intent: &str` -- Only return disputes associated to the PaymentIntent specified by this PaymentIntent ID.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -31,10 +31,39 @@ impl Disputes {
         charge: &str,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Dispute>> {
+        Ok(self
+            .get_page_with_response(
+                charge,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                payment_intent,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/disputes` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        charge: &str,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Dispute>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
@@ -42,6 +71,9 @@ impl Disputes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +85,9 @@ impl Disputes {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/disputes?{}", query_), None);
-        let resp: crate::types::GetDisputesResponse = self
+        let resp: crate::Response<crate::types::GetDisputesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl Disputes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/disputes` endpoint.
@@ -78,12 +110,16 @@ impl Disputes {
         &self,
         charge: &str,
         _created: &str,
+        expand: &[String],
         payment_intent: &str,
     ) -> ClientResult<Vec<crate::types::Dispute>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -148,6 +184,110 @@ impl Disputes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/disputes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        expand: &[String],
+        payment_intent: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Dispute>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !charge.is_empty() {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !payment_intent.is_empty() {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/disputes?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetDisputesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/disputes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        expand: &[String],
+        payment_intent: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Dispute>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            charge,
+            _created,
+            expand,
+            payment_intent,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/v1/disputes/{dispute}` endpoint.
      *
@@ -155,19 +295,41 @@ impl Disputes {
      *
      * **Parameters:**
      *
-     * * `dispute: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `dispute: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     */
+    pub async fn get(
+        &self,
+        dispute: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::Dispute> {
+        Ok(self.get_with_response(dispute, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/disputes/{dispute}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, dispute: &str) -> ClientResult<crate::types::Dispute> {
+    pub async fn get_with_response(
+        &self,
+        dispute: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/disputes/{}",
+                "/v1/disputes/{}?{}",
                 crate::progenitor_support::encode_path(dispute),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -185,9 +347,25 @@ impl Disputes {
      *
      * **Parameters:**
      *
-     * * `dispute: &str` -- The account's country.
+     * * `dispute: &str`
+     */
+    pub async fn post(
+        &self,
+        dispute: &str,
+        body: &crate::types::PostDisputesDisputeRequest,
+    ) -> ClientResult<crate::types::Dispute> {
+        Ok(self.post_with_response(dispute, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/disputes/{dispute}` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post(&self, dispute: &str) -> ClientResult<crate::types::Dispute> {
+    pub async fn post_with_response(
+        &self,
+        dispute: &str,
+        body: &crate::types::PostDisputesDisputeRequest,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
         let url = self.client.url(
             &format!(
                 "/v1/disputes/{}",
@@ -196,10 +374,10 @@ impl Disputes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -214,9 +392,25 @@ impl Disputes {
      *
      * **Parameters:**
      *
-     * * `dispute: &str` -- The account's country.
+     * * `dispute: &str`
      */
-    pub async fn post_close(&self, dispute: &str) -> ClientResult<crate::types::Dispute> {
+    pub async fn post_close(
+        &self,
+        dispute: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::types::Dispute> {
+        Ok(self.post_close_with_response(dispute, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/disputes/{dispute}/close` endpoint.
+     *
+     * As opposed to `post_close`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_close_with_response(
+        &self,
+        dispute: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::Dispute>> {
         let url = self.client.url(
             &format!(
                 "/v1/disputes/{}/close",
@@ -225,10 +419,10 @@ impl Disputes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
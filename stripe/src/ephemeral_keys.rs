@@ -16,13 +16,27 @@ impl EphemeralKeys {
      *
      * <p>Creates a short-lived API key for a given resource.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::EphemeralKey> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostEphemeralKeysRequest,
+    ) -> ClientResult<crate::types::EphemeralKey> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/ephemeral_keys` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostEphemeralKeysRequest,
+    ) -> ClientResult<crate::Response<crate::types::EphemeralKey>> {
         let url = self.client.url("/v1/ephemeral_keys", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -35,9 +49,25 @@ impl EphemeralKeys {
      *
      * **Parameters:**
      *
-     * * `key: &str` -- The account's country.
+     * * `key: &str`
+     */
+    pub async fn delete_key(
+        &self,
+        key: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::types::EphemeralKey> {
+        Ok(self.delete_key_with_response(key, body).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/ephemeral_keys/{key}` endpoint.
+     *
+     * As opposed to `delete_key`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn delete_key(&self, key: &str) -> ClientResult<crate::types::EphemeralKey> {
+    pub async fn delete_key_with_response(
+        &self,
+        key: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::EphemeralKey>> {
         let url = self.client.url(
             &format!(
                 "/v1/ephemeral_keys/{}",
@@ -46,10 +76,10 @@ impl EphemeralKeys {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
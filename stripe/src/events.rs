@@ -21,7 +21,7 @@ impl Events {
      * * `created: &str`
      * * `delivery_success: bool` -- Filter events by whether all webhooks were successfully delivered. If false, events which are still pending or have failed all delivery attempts to a webhook endpoint will be returned.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `type_: &str` -- A string containing a specific event name, or group of events using * as a wildcard. The list will be filtered to include only events with a matching event property.
@@ -32,11 +32,42 @@ impl Events {
         _created: &str,
         delivery_success: bool,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: &str,
         _types: &[String],
     ) -> ClientResult<Vec<crate::types::Event>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                delivery_success,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                type_,
+                _types,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/events` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        delivery_success: bool,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        type_: &str,
+        _types: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Event>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if delivery_success {
             query_args.push(("delivery_success".to_string(), delivery_success.to_string()));
@@ -44,6 +75,9 @@ impl Events {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -55,9 +89,9 @@ impl Events {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/events?{}", query_), None);
-        let resp: crate::types::NotificationEventList = self
+        let resp: crate::Response<crate::types::NotificationEventList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -67,7 +101,7 @@ impl Events {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/events` endpoint.
@@ -80,6 +114,7 @@ impl Events {
         &self,
         _created: &str,
         delivery_success: bool,
+        expand: &[String],
         type_: &str,
         _types: &[String],
     ) -> ClientResult<Vec<crate::types::Event>> {
@@ -87,6 +122,9 @@ impl Events {
         if delivery_success {
             query_args.push(("delivery_success".to_string(), delivery_success.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !type_.is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -151,6 +189,113 @@ impl Events {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/events` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        delivery_success: bool,
+        expand: &[String],
+        type_: &str,
+        _types: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Event>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if delivery_success {
+            query_args.push(("delivery_success".to_string(), delivery_success.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/events?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::NotificationEventList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/events` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        delivery_success: bool,
+        expand: &[String],
+        type_: &str,
+        _types: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Event>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            _created,
+            delivery_success,
+            expand,
+            type_,
+            _types,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/v1/events/{id}` endpoint.
      *
@@ -158,16 +303,37 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get(&self, expand: &[String], id: &str) -> ClientResult<crate::types::Event> {
+        Ok(self.get_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/events/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::Event> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Event>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v1/events/{}", crate::progenitor_support::encode_path(id),),
+            &format!(
+                "/v1/events/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -20,7 +20,7 @@ impl FileLinks {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `expired: bool` -- Filter links by their expiration status. By default, all links are returned.
      * * `file: &str` -- Only return links for the given file.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
@@ -30,15 +30,47 @@ impl FileLinks {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         expired: bool,
         file: &str,
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::FileLink>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                ending_before,
+                expand,
+                expired,
+                file,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/file_links` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        expired: bool,
+        file: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::FileLink>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if expired {
             query_args.push(("expired".to_string(), expired.to_string()));
         }
@@ -53,9 +85,9 @@ impl FileLinks {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/file_links?{}", query_), None);
-        let resp: crate::types::Links = self
+        let resp: crate::Response<crate::types::Links> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl FileLinks {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/file_links` endpoint.
@@ -77,10 +109,14 @@ impl FileLinks {
     pub async fn get_all(
         &self,
         _created: &str,
+        expand: &[String],
         expired: bool,
         file: &str,
     ) -> ClientResult<Vec<crate::types::FileLink>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if expired {
             query_args.push(("expired".to_string(), expired.to_string()));
         }
@@ -148,18 +184,130 @@ impl FileLinks {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/file_links` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        expired: bool,
+        file: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::FileLink>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if expired {
+            query_args.push(("expired".to_string(), expired.to_string()));
+        }
+        if !file.is_empty() {
+            query_args.push(("file".to_string(), file.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/file_links?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Links = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/file_links` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        expired: bool,
+        file: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::FileLink>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(_created, expand, expired, file, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/file_links` endpoint.
      *
      * <p>Creates a new file link object.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::FileLink> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostFileLinksRequest,
+    ) -> ClientResult<crate::types::FileLink> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/file_links` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostFileLinksRequest,
+    ) -> ClientResult<crate::Response<crate::types::FileLink>> {
         let url = self.client.url("/v1/file_links", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -172,19 +320,41 @@ impl FileLinks {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `link: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `link: &str`
      */
-    pub async fn get_link(&self, link: &str) -> ClientResult<crate::types::FileLink> {
+    pub async fn get_link(
+        &self,
+        expand: &[String],
+        link: &str,
+    ) -> ClientResult<crate::types::FileLink> {
+        Ok(self.get_link_with_response(expand, link).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/file_links/{link}` endpoint.
+     *
+     * As opposed to `get_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_link_with_response(
+        &self,
+        expand: &[String],
+        link: &str,
+    ) -> ClientResult<crate::Response<crate::types::FileLink>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/file_links/{}",
+                "/v1/file_links/{}?{}",
                 crate::progenitor_support::encode_path(link),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -200,9 +370,25 @@ impl FileLinks {
      *
      * **Parameters:**
      *
-     * * `link: &str` -- The account's country.
+     * * `link: &str`
      */
-    pub async fn post_link(&self, link: &str) -> ClientResult<crate::types::FileLink> {
+    pub async fn post_link(
+        &self,
+        link: &str,
+        body: &crate::types::PostFileLinksLinkRequest,
+    ) -> ClientResult<crate::types::FileLink> {
+        Ok(self.post_link_with_response(link, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/file_links/{link}` endpoint.
+     *
+     * As opposed to `post_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_link_with_response(
+        &self,
+        link: &str,
+        body: &crate::types::PostFileLinksLinkRequest,
+    ) -> ClientResult<crate::Response<crate::types::FileLink>> {
         let url = self.client.url(
             &format!(
                 "/v1/file_links/{}",
@@ -211,10 +397,10 @@ impl FileLinks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
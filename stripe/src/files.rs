@@ -29,7 +29,7 @@ impl Files {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `purpose: crate::types::Purpose` -- The file purpose to filter queries by. If none is provided, files will not be filtered by purpose.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -38,14 +38,44 @@ impl Files {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         purpose: crate::types::Purpose,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::File>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                ending_before,
+                expand,
+                limit,
+                purpose,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/files` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        purpose: crate::types::Purpose,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::File>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -57,9 +87,9 @@ impl Files {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/files?{}", query_), None);
-        let resp: crate::types::GetFilesResponse = self
+        let resp: crate::Response<crate::types::GetFilesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,7 +99,7 @@ impl Files {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/files` endpoint.
@@ -81,9 +111,13 @@ impl Files {
     pub async fn get_all(
         &self,
         _created: &str,
+        expand: &[String],
         purpose: crate::types::Purpose,
     ) -> ClientResult<Vec<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !purpose.to_string().is_empty() {
             query_args.push(("purpose".to_string(), purpose.to_string()));
         }
@@ -148,27 +182,133 @@ impl Files {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/files` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        purpose: crate::types::Purpose,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::File>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !purpose.to_string().is_empty() {
+            query_args.push(("purpose".to_string(), purpose.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/files?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetFilesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/files` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        purpose: crate::types::Purpose,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::File>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(_created, expand, purpose, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/files` endpoint.
      *
      * <p>To upload a file to Stripe, you’ll need to send a request of type <code>multipart/form-data</code>. The request should contain the file you would like to upload, as well as the parameters for creating a file.</p>
      *
      * <p>All of Stripe’s officially supported Client libraries should have support for sending <code>multipart/form-data</code>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `file: Option<&[u8]>` -- The file contents, sent as `multipart/form-data`.
      */
-    pub async fn post(&self) -> ClientResult<crate::types::File> {
+    pub async fn post(
+        &self,
+        file: Option<&[u8]>,
+        body: &crate::types::PostFilesRequest,
+    ) -> ClientResult<crate::types::File> {
+        Ok(self.post_with_response(file, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/files` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        file: Option<&[u8]>,
+        body: &crate::types::PostFilesRequest,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
         let url = self.client.url(
             "/v1/files",
             Some(PostFilesDefaultServer::default().default_url()),
         );
-        self.client
-            .post(
-                &url,
-                crate::Message {
-                    body: None,
-                    content_type: Some("multipart/form-data".to_string()),
-                },
-            )
-            .await
+        let form = crate::utils::multipart_form(body, file)?;
+        self.client.post_form_with_response(&url, form).await
     }
     /**
      * This function performs a `GET` to the `/v1/files/{file}` endpoint.
@@ -177,16 +317,37 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `file: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `file: &str`
      */
-    pub async fn get(&self, file: &str) -> ClientResult<crate::types::File> {
+    pub async fn get(&self, expand: &[String], file: &str) -> ClientResult<crate::types::File> {
+        Ok(self.get_with_response(expand, file).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/files/{file}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        file: &str,
+    ) -> ClientResult<crate::Response<crate::types::File>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v1/files/{}", crate::progenitor_support::encode_path(file),),
+            &format!(
+                "/v1/files/{}?{}",
+                crate::progenitor_support::encode_path(file),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
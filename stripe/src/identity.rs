@@ -20,7 +20,7 @@ impl Identity {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `type_: crate::types::GelatoVerificationReportType` -- Only return VerificationReports of this type.
@@ -30,15 +30,47 @@ impl Identity {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::GelatoVerificationReportType,
         verification_session: &str,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationReport>> {
+        Ok(self
+            .get_verification_reports_with_response(
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                type_,
+                verification_session,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
+     *
+     * As opposed to `get_verification_reports`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verification_reports_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationReport>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -59,9 +91,9 @@ impl Identity {
             &format!("/v1/identity/verification_reports?{}", query_),
             None,
         );
-        let resp: crate::types::GetIdentityVerificationReportsResponse = self
+        let resp: crate::Response<crate::types::GetIdentityVerificationReportsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,7 +103,7 @@ impl Identity {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
@@ -83,10 +115,14 @@ impl Identity {
     pub async fn get_all_verification_reports(
         &self,
         _created: &str,
+        expand: &[String],
         type_: crate::types::GelatoVerificationReportType,
         verification_session: &str,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -160,6 +196,119 @@ impl Identity {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
+     *
+     * As opposed to `get_all_verification_reports`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_verification_reports_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GelatoVerificationReport>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if !verification_session.is_empty() {
+            query_args.push((
+                "verification_session".to_string(),
+                verification_session.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/v1/identity/verification_reports?{}", query_),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetIdentityVerificationReportsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
+     *
+     * As opposed to `get_all_verification_reports`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_verification_reports_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GelatoVerificationReport>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_verification_reports_pages_stream(
+            _created,
+            expand,
+            type_,
+            verification_session,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/v1/identity/verification_reports/{report}` endpoint.
      *
@@ -167,22 +316,44 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `report: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `report: &str`
      */
     pub async fn get_verification_reports_report(
         &self,
+        expand: &[String],
         report: &str,
     ) -> ClientResult<crate::types::GelatoVerificationReport> {
+        Ok(self
+            .get_verification_reports_report_with_response(expand, report)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_reports/{report}` endpoint.
+     *
+     * As opposed to `get_verification_reports_report`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verification_reports_report_with_response(
+        &self,
+        expand: &[String],
+        report: &str,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationReport>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/identity/verification_reports/{}",
+                "/v1/identity/verification_reports/{}?{}",
                 crate::progenitor_support::encode_path(report),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -200,7 +371,7 @@ impl Identity {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: crate::types::GelatoVerificationSessionStatus` -- Only return VerificationSessions with this status. [Learn more about the lifecycle of sessions](https://stripe.com/docs/identity/how-sessions-work).
@@ -209,14 +380,44 @@ impl Identity {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::GelatoVerificationSessionStatus,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationSession>> {
+        Ok(self
+            .get_verification_sessions_with_response(
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
+     *
+     * As opposed to `get_verification_sessions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verification_sessions_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GelatoVerificationSessionStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GelatoVerificationSession>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -231,9 +432,9 @@ impl Identity {
             &format!("/v1/identity/verification_sessions?{}", query_),
             None,
         );
-        let resp: crate::types::GetIdentityVerificationSessionsResponse = self
+        let resp: crate::Response<crate::types::GetIdentityVerificationSessionsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -243,7 +444,7 @@ impl Identity {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
@@ -255,9 +456,13 @@ impl Identity {
     pub async fn get_all_verification_sessions(
         &self,
         _created: &str,
+        expand: &[String],
         status: crate::types::GelatoVerificationSessionStatus,
     ) -> ClientResult<Vec<crate::types::GelatoVerificationSession>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -325,6 +530,107 @@ impl Identity {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
+     *
+     * As opposed to `get_all_verification_sessions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_verification_sessions_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::GelatoVerificationSessionStatus,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GelatoVerificationSession>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!("/v1/identity/verification_sessions?{}", query_),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetIdentityVerificationSessionsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
+     *
+     * As opposed to `get_all_verification_sessions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_verification_sessions_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::GelatoVerificationSessionStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GelatoVerificationSession>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_verification_sessions_pages_stream(_created, expand, status, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/identity/verification_sessions` endpoint.
      *
@@ -336,15 +642,30 @@ impl Identity {
      *
      * <p>Related guide: <a href="/docs/identity/verify-identity-documents">Verify your users’ identity documents</a>.</p>
      */
-    pub async fn post_verification_session(
+    pub async fn post_verification_session<B: serde::Serialize>(
         &self,
+        body: &B,
     ) -> ClientResult<crate::types::GelatoVerificationSession> {
+        Ok(self
+            .post_verification_session_with_response::<B>(body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/identity/verification_sessions` endpoint.
+     *
+     * As opposed to `post_verification_session`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_verification_session_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
         let url = self.client.url("/v1/identity/verification_sessions", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -360,22 +681,44 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `session: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `session: &str`
      */
     pub async fn get_verification_sessions_session(
         &self,
+        expand: &[String],
         session: &str,
     ) -> ClientResult<crate::types::GelatoVerificationSession> {
+        Ok(self
+            .get_verification_sessions_session_with_response(expand, session)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_sessions/{session}` endpoint.
+     *
+     * As opposed to `get_verification_sessions_session`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_verification_sessions_session_with_response(
+        &self,
+        expand: &[String],
+        session: &str,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/identity/verification_sessions/{}",
+                "/v1/identity/verification_sessions/{}?{}",
                 crate::progenitor_support::encode_path(session),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -394,12 +737,28 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `session: &str` -- The account's country.
+     * * `session: &str`
      */
-    pub async fn post_verification_sessions_session(
+    pub async fn post_verification_sessions_session<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> ClientResult<crate::types::GelatoVerificationSession> {
+        Ok(self
+            .post_verification_sessions_session_with_response::<B>(session, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/identity/verification_sessions/{session}` endpoint.
+     *
+     * As opposed to `post_verification_sessions_session`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_verification_sessions_session_with_response<B: serde::Serialize>(
+        &self,
+        session: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
         let url = self.client.url(
             &format!(
                 "/v1/identity/verification_sessions/{}",
@@ -408,10 +767,10 @@ impl Identity {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -426,12 +785,28 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `session: &str` -- The account's country.
+     * * `session: &str`
      */
-    pub async fn post_verification_sessions_session_cancel(
+    pub async fn post_verification_sessions_session_cancel<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> ClientResult<crate::types::GelatoVerificationSession> {
+        Ok(self
+            .post_verification_sessions_session_cancel_with_response::<B>(session, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/identity/verification_sessions/{session}/cancel` endpoint.
+     *
+     * As opposed to `post_verification_sessions_session_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_verification_sessions_session_cancel_with_response<B: serde::Serialize>(
+        &self,
+        session: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
         let url = self.client.url(
             &format!(
                 "/v1/identity/verification_sessions/{}/cancel",
@@ -440,10 +815,10 @@ impl Identity {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -474,12 +849,28 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `session: &str` -- The account's country.
+     * * `session: &str`
      */
-    pub async fn post_verification_sessions_session_redact(
+    pub async fn post_verification_sessions_session_redact<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> ClientResult<crate::types::GelatoVerificationSession> {
+        Ok(self
+            .post_verification_sessions_session_redact_with_response::<B>(session, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/identity/verification_sessions/{session}/redact` endpoint.
+     *
+     * As opposed to `post_verification_sessions_session_redact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_verification_sessions_session_redact_with_response<B: serde::Serialize>(
+        &self,
+        session: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::GelatoVerificationSession>> {
         let url = self.client.url(
             &format!(
                 "/v1/identity/verification_sessions/{}/redact",
@@ -488,10 +879,10 @@ impl Identity {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -21,7 +21,7 @@ impl Invoiceitems {
      * * `created: &str`
      * * `customer: &str` -- The identifier of the customer whose invoice items to return. If none is provided, all invoice items will be returned.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `invoice: &str` -- Only return invoice items belonging to this invoice. If none is provided, all invoice items will be returned. If specifying an invoice, no customer identifier is needed.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `pending: bool` -- Set to `true` to only show pending invoice items, which are not yet attached to any invoices. Set to `false` to only show invoice items already attached to invoices. If unspecified, no filter is applied.
@@ -32,11 +32,42 @@ impl Invoiceitems {
         _created: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         invoice: &str,
         limit: i64,
         pending: bool,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::InvoiceItem>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                customer,
+                ending_before,
+                expand,
+                invoice,
+                limit,
+                pending,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoiceitems` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        invoice: &str,
+        limit: i64,
+        pending: bool,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InvoiceItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -44,6 +75,9 @@ impl Invoiceitems {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -60,9 +94,9 @@ impl Invoiceitems {
         let url = self
             .client
             .url(&format!("/v1/invoiceitems?{}", query_), None);
-        let resp: crate::types::GetInvoiceitemsResponse = self
+        let resp: crate::Response<crate::types::GetInvoiceitemsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,7 +106,7 @@ impl Invoiceitems {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/invoiceitems` endpoint.
@@ -85,6 +119,7 @@ impl Invoiceitems {
         &self,
         _created: &str,
         customer: &str,
+        expand: &[String],
         invoice: &str,
         pending: bool,
     ) -> ClientResult<Vec<crate::types::InvoiceItem>> {
@@ -92,6 +127,9 @@ impl Invoiceitems {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !invoice.is_empty() {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
@@ -161,18 +199,140 @@ impl Invoiceitems {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoiceitems` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        invoice: &str,
+        pending: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InvoiceItem>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !invoice.is_empty() {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        if pending {
+            query_args.push(("pending".to_string(), pending.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/invoiceitems?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetInvoiceitemsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoiceitems` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        invoice: &str,
+        pending: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InvoiceItem>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(_created, customer, expand, invoice, pending, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/invoiceitems` endpoint.
      *
      * <p>Creates an item to be added to a draft invoice (up to 250 items per invoice). If no invoice is specified, the item will be on the next invoice created for the customer specified.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::InvoiceItem> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostInvoiceitemsRequest,
+    ) -> ClientResult<crate::types::InvoiceItem> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoiceitems` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostInvoiceitemsRequest,
+    ) -> ClientResult<crate::Response<crate::types::InvoiceItem>> {
         let url = self.client.url("/v1/invoiceitems", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -185,19 +345,41 @@ impl Invoiceitems {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoiceitem: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `invoiceitem: &str`
+     */
+    pub async fn get(
+        &self,
+        expand: &[String],
+        invoiceitem: &str,
+    ) -> ClientResult<crate::types::InvoiceItem> {
+        Ok(self.get_with_response(expand, invoiceitem).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoiceitems/{invoiceitem}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, invoiceitem: &str) -> ClientResult<crate::types::InvoiceItem> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        invoiceitem: &str,
+    ) -> ClientResult<crate::Response<crate::types::InvoiceItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/invoiceitems/{}",
+                "/v1/invoiceitems/{}?{}",
                 crate::progenitor_support::encode_path(invoiceitem),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -213,12 +395,28 @@ impl Invoiceitems {
      *
      * **Parameters:**
      *
-     * * `invoiceitem: &str` -- The account's country.
+     * * `invoiceitem: &str`
      */
     pub async fn post_invoiceitems(
         &self,
         invoiceitem: &str,
+        body: &crate::types::PostInvoiceitemsInvoiceitemRequest,
     ) -> ClientResult<crate::types::InvoiceItem> {
+        Ok(self
+            .post_invoiceitems_with_response(invoiceitem, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoiceitems/{invoiceitem}` endpoint.
+     *
+     * As opposed to `post_invoiceitems`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_invoiceitems_with_response(
+        &self,
+        invoiceitem: &str,
+        body: &crate::types::PostInvoiceitemsInvoiceitemRequest,
+    ) -> ClientResult<crate::Response<crate::types::InvoiceItem>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoiceitems/{}",
@@ -227,10 +425,10 @@ impl Invoiceitems {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -243,12 +441,23 @@ impl Invoiceitems {
      *
      * **Parameters:**
      *
-     * * `invoiceitem: &str` -- The account's country.
+     * * `invoiceitem: &str`
      */
     pub async fn delete(
         &self,
         invoiceitem: &str,
     ) -> ClientResult<crate::types::DeletedInvoiceItem> {
+        Ok(self.delete_with_response(invoiceitem).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/invoiceitems/{invoiceitem}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        invoiceitem: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedInvoiceItem>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoiceitems/{}",
@@ -257,7 +466,7 @@ impl Invoiceitems {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -23,7 +23,7 @@ impl Invoices {
      * * `customer: &str` -- Only return invoices for the customer specified by this customer ID.
      * * `due_date: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: crate::types::GetInvoicesStatus` -- The status of the invoice, one of `draft`, `open`, `paid`, `uncollectible`, or `void`. [Learn more](https://stripe.com/docs/billing/invoices/workflow#workflow-overview).
@@ -36,11 +36,46 @@ impl Invoices {
         customer: &str,
         _due_date: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::GetInvoicesStatus,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::Invoice>> {
+        Ok(self
+            .get_page_with_response(
+                collection_method,
+                _created,
+                customer,
+                _due_date,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                status,
+                subscription,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        customer: &str,
+        _due_date: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Invoice>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
             query_args.push((
@@ -54,6 +89,9 @@ impl Invoices {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -68,9 +106,9 @@ impl Invoices {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/invoices?{}", query_), None);
-        let resp: crate::types::InvoicesList = self
+        let resp: crate::Response<crate::types::InvoicesList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -80,7 +118,7 @@ impl Invoices {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/invoices` endpoint.
@@ -95,6 +133,7 @@ impl Invoices {
         _created: &str,
         customer: &str,
         _due_date: &str,
+        expand: &[String],
         status: crate::types::GetInvoicesStatus,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::Invoice>> {
@@ -108,6 +147,9 @@ impl Invoices {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -175,18 +217,154 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        customer: &str,
+        _due_date: &str,
+        expand: &[String],
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Invoice>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !collection_method.to_string().is_empty() {
+            query_args.push((
+                "collection_method".to_string(),
+                collection_method.to_string(),
+            ));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/invoices?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::InvoicesList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        customer: &str,
+        _due_date: &str,
+        expand: &[String],
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Invoice>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            collection_method,
+            _created,
+            customer,
+            _due_date,
+            expand,
+            status,
+            subscription,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/invoices` endpoint.
      *
      * <p>This endpoint creates a draft invoice for a given customer. The draft invoice created pulls in all pending invoice items on that customer, including prorations. The invoice remains a draft until you <a href="#finalize_invoice">finalize</a> the invoice, which allows you to <a href="#pay_invoice">pay</a> or <a href="#send_invoice">send</a> the invoice to your customers.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Invoice> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostInvoicesRequest,
+    ) -> ClientResult<crate::types::Invoice> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostInvoicesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let url = self.client.url("/v1/invoices", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -202,18 +380,39 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
      * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for invoices](https://stripe.com/docs/search#query-fields-for-invoices).
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        Ok(self
+            .get_search_with_response(expand, limit, page, query)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_search_with_response(
+        &self,
+        expand: &[String],
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -227,9 +426,9 @@ impl Invoices {
         let url = self
             .client
             .url(&format!("/v1/invoices/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::Response<crate::types::SearchResult> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -239,7 +438,7 @@ impl Invoices {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/invoices/search` endpoint.
@@ -251,8 +450,15 @@ impl Invoices {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -319,6 +525,99 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_search_pages_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Charge>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/invoices/search?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::SearchResult = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_pages_stream(expand, query, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming` endpoint.
      *
@@ -335,7 +634,7 @@ impl Invoices {
      * * `customer: &str` -- The identifier of the customer whose upcoming invoice you'd like to retrieve.
      * * `customer_details: &str` -- Details about the customer you want to invoice or overrides for an existing customer.
      * * `discounts: &str` -- The coupons to redeem into discounts for the invoice preview. If not specified, inherits the discount from the customer or subscription. This only works for coupons directly applied to the invoice. To apply a coupon to a subscription, you must use the `coupon` parameter instead. Pass an empty string to avoid inheriting any discounts. To preview the upcoming invoice for a subscription that hasn't been created, use `coupon` instead.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `invoice_items: &[String]` -- List of invoice items to add or update in the upcoming invoice preview.
      * * `schedule: &str` -- The identifier of the unstarted schedule whose upcoming invoice you'd like to retrieve. Cannot be used with subscription or subscription fields.
      * * `subscription: &str` -- The identifier of the subscription for which you'd like to retrieve the upcoming invoice. If not provided, but a `subscription_items` is provided, you will preview creating a subscription with those items. If neither `subscription` nor `subscription_items` is provided, you will retrieve the next upcoming invoice from among the customer's subscriptions.
@@ -351,7 +650,7 @@ impl Invoices {
      *   
      *   Prorations can be disabled by passing `none`.
      * * `subscription_proration_date: i64` -- If previewing an update to a subscription, and doing proration, `subscription_proration_date` forces the proration to be calculated as though the update was done at the specified time. The time given must be within the current subscription period, and cannot be before the subscription was on its current plan. If set, `subscription`, and one of `subscription_items`, or `subscription_trial_end` are required. Also, `subscription_proration_behavior` cannot be set to 'none'.
-     * * `subscription_start_date: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
+     * * `subscription_start_date: i64` -- Date a subscription is intended to start (can be future or past).
      * * `subscription_trial_end: &str` -- If provided, the invoice returned will preview updating or creating a subscription with that trial end. If set, one of `subscription_items` or `subscription` is required.
      * * `subscription_trial_from_plan: bool` -- Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `subscription_trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `subscription_trial_end` is not allowed. See [Using trial periods on subscriptions](https://stripe.com/docs/billing/subscriptions/trials) to learn more.
      */
@@ -362,6 +661,7 @@ impl Invoices {
         customer: &str,
         _customer_details: &str,
         _discounts: &str,
+        expand: &[String],
         _invoice_items: &[String],
         schedule: &str,
         subscription: &str,
@@ -377,6 +677,60 @@ impl Invoices {
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
     ) -> ClientResult<crate::types::Invoice> {
+        Ok(self
+            .get_upcoming_with_response(
+                _automatic_tax,
+                coupon,
+                customer,
+                _customer_details,
+                _discounts,
+                expand,
+                _invoice_items,
+                schedule,
+                subscription,
+                _subscription_billing_cycle_anchor,
+                _subscription_cancel_at,
+                subscription_cancel_at_period_end,
+                subscription_cancel_now,
+                _subscription_default_tax_rates,
+                _subscription_items,
+                subscription_proration_behavior,
+                subscription_proration_date,
+                subscription_start_date,
+                _subscription_trial_end,
+                subscription_trial_from_plan,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming` endpoint.
+     *
+     * As opposed to `get_upcoming`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_upcoming_with_response(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        expand: &[String],
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !coupon.is_empty() {
             query_args.push(("coupon".to_string(), coupon.to_string()));
@@ -384,6 +738,9 @@ impl Invoices {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !schedule.is_empty() {
             query_args.push(("schedule".to_string(), schedule.to_string()));
         }
@@ -431,7 +788,7 @@ impl Invoices {
             .client
             .url(&format!("/v1/invoices/upcoming?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -453,7 +810,7 @@ impl Invoices {
      * * `customer_details: &str` -- Details about the customer you want to invoice or overrides for an existing customer.
      * * `discounts: &str` -- The coupons to redeem into discounts for the invoice preview. If not specified, inherits the discount from the customer or subscription. This only works for coupons directly applied to the invoice. To apply a coupon to a subscription, you must use the `coupon` parameter instead. Pass an empty string to avoid inheriting any discounts. To preview the upcoming invoice for a subscription that hasn't been created, use `coupon` instead.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `invoice_items: &[String]` -- List of invoice items to add or update in the upcoming invoice preview.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `schedule: &str` -- The identifier of the unstarted schedule whose upcoming invoice you'd like to retrieve. Cannot be used with subscription or subscription fields.
@@ -471,7 +828,7 @@ impl Invoices {
      *   
      *   Prorations can be disabled by passing `none`.
      * * `subscription_proration_date: i64` -- If previewing an update to a subscription, and doing proration, `subscription_proration_date` forces the proration to be calculated as though the update was done at the specified time. The time given must be within the current subscription period, and cannot be before the subscription was on its current plan. If set, `subscription`, and one of `subscription_items`, or `subscription_trial_end` are required. Also, `subscription_proration_behavior` cannot be set to 'none'.
-     * * `subscription_start_date: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
+     * * `subscription_start_date: i64` -- Date a subscription is intended to start (can be future or past).
      * * `subscription_trial_end: &str` -- If provided, the invoice returned will preview updating or creating a subscription with that trial end. If set, one of `subscription_items` or `subscription` is required.
      * * `subscription_trial_from_plan: bool` -- Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `subscription_trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `subscription_trial_end` is not allowed. See [Using trial periods on subscriptions](https://stripe.com/docs/billing/subscriptions/trials) to learn more.
      */
@@ -483,6 +840,7 @@ impl Invoices {
         _customer_details: &str,
         _discounts: &str,
         ending_before: &str,
+        expand: &[String],
         _invoice_items: &[String],
         limit: i64,
         schedule: &str,
@@ -500,6 +858,66 @@ impl Invoices {
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
     ) -> ClientResult<Vec<crate::types::LineItem>> {
+        Ok(self
+            .get_upcoming_lines_with_response(
+                _automatic_tax,
+                coupon,
+                customer,
+                _customer_details,
+                _discounts,
+                ending_before,
+                expand,
+                _invoice_items,
+                limit,
+                schedule,
+                starting_after,
+                subscription,
+                _subscription_billing_cycle_anchor,
+                _subscription_cancel_at,
+                subscription_cancel_at_period_end,
+                subscription_cancel_now,
+                _subscription_default_tax_rates,
+                _subscription_items,
+                subscription_proration_behavior,
+                subscription_proration_date,
+                subscription_start_date,
+                _subscription_trial_end,
+                subscription_trial_from_plan,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_upcoming_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_upcoming_lines_with_response(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        ending_before: &str,
+        expand: &[String],
+        _invoice_items: &[String],
+        limit: i64,
+        schedule: &str,
+        starting_after: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !coupon.is_empty() {
             query_args.push(("coupon".to_string(), coupon.to_string()));
@@ -510,6 +928,9 @@ impl Invoices {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -562,9 +983,9 @@ impl Invoices {
         let url = self
             .client
             .url(&format!("/v1/invoices/upcoming/lines?{}", query_), None);
-        let resp: crate::types::InvoiceLinesList = self
+        let resp: crate::Response<crate::types::InvoiceLinesList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -574,7 +995,7 @@ impl Invoices {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
@@ -590,6 +1011,7 @@ impl Invoices {
         customer: &str,
         _customer_details: &str,
         _discounts: &str,
+        expand: &[String],
         _invoice_items: &[String],
         schedule: &str,
         subscription: &str,
@@ -612,6 +1034,9 @@ impl Invoices {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !schedule.is_empty() {
             query_args.push(("schedule".to_string(), schedule.to_string()));
         }
@@ -717,6 +1142,202 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_all_upcoming_lines`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_upcoming_lines_pages_stream(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        expand: &[String],
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::LineItem>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !coupon.is_empty() {
+            query_args.push(("coupon".to_string(), coupon.to_string()));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !schedule.is_empty() {
+            query_args.push(("schedule".to_string(), schedule.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        if subscription_cancel_at_period_end {
+            query_args.push((
+                "subscription_cancel_at_period_end".to_string(),
+                subscription_cancel_at_period_end.to_string(),
+            ));
+        }
+        if subscription_cancel_now {
+            query_args.push((
+                "subscription_cancel_now".to_string(),
+                subscription_cancel_now.to_string(),
+            ));
+        }
+        if !subscription_proration_behavior.to_string().is_empty() {
+            query_args.push((
+                "subscription_proration_behavior".to_string(),
+                subscription_proration_behavior.to_string(),
+            ));
+        }
+        if subscription_proration_date > 0 {
+            query_args.push((
+                "subscription_proration_date".to_string(),
+                subscription_proration_date.to_string(),
+            ));
+        }
+        if subscription_start_date > 0 {
+            query_args.push((
+                "subscription_start_date".to_string(),
+                subscription_start_date.to_string(),
+            ));
+        }
+        if subscription_trial_from_plan {
+            query_args.push((
+                "subscription_trial_from_plan".to_string(),
+                subscription_trial_from_plan.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/invoices/upcoming/lines?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::InvoiceLinesList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_all_upcoming_lines`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_upcoming_lines_stream(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        expand: &[String],
+        _invoice_items: &[String],
+        schedule: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LineItem>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_upcoming_lines_pages_stream(
+            _automatic_tax,
+            coupon,
+            customer,
+            _customer_details,
+            _discounts,
+            expand,
+            _invoice_items,
+            schedule,
+            subscription,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            subscription_cancel_at_period_end,
+            subscription_cancel_now,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            subscription_proration_behavior,
+            subscription_proration_date,
+            subscription_start_date,
+            _subscription_trial_end,
+            subscription_trial_from_plan,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/v1/invoices/{invoice}` endpoint.
      *
@@ -724,19 +1345,41 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `invoice: &str`
+     */
+    pub async fn get(
+        &self,
+        expand: &[String],
+        invoice: &str,
+    ) -> ClientResult<crate::types::Invoice> {
+        Ok(self.get_with_response(expand, invoice).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, invoice: &str) -> ClientResult<crate::types::Invoice> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        invoice: &str,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/invoices/{}",
+                "/v1/invoices/{}?{}",
                 crate::progenitor_support::encode_path(invoice),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -757,9 +1400,25 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `invoice: &str` -- The account's country.
+     * * `invoice: &str`
      */
-    pub async fn post_invoices(&self, invoice: &str) -> ClientResult<crate::types::Invoice> {
+    pub async fn post_invoices(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoiceRequest,
+    ) -> ClientResult<crate::types::Invoice> {
+        Ok(self.post_invoices_with_response(invoice, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoices/{invoice}` endpoint.
+     *
+     * As opposed to `post_invoices`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_invoices_with_response(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoiceRequest,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}",
@@ -768,10 +1427,10 @@ impl Invoices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -784,9 +1443,20 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `invoice: &str` -- The account's country.
+     * * `invoice: &str`
      */
     pub async fn delete(&self, invoice: &str) -> ClientResult<crate::types::DeletedInvoice> {
+        Ok(self.delete_with_response(invoice).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/invoices/{invoice}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        invoice: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedInvoice>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}",
@@ -795,7 +1465,7 @@ impl Invoices {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -811,9 +1481,25 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `invoice: &str` -- The account's country.
+     * * `invoice: &str`
+     */
+    pub async fn post_finalize(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoiceFinalizeRequest,
+    ) -> ClientResult<crate::types::Invoice> {
+        Ok(self.post_finalize_with_response(invoice, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoices/{invoice}/finalize` endpoint.
+     *
+     * As opposed to `post_finalize`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_finalize(&self, invoice: &str) -> ClientResult<crate::types::Invoice> {
+    pub async fn post_finalize_with_response(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoiceFinalizeRequest,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/finalize",
@@ -822,10 +1508,10 @@ impl Invoices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -839,22 +1525,44 @@ impl Invoices {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `invoice: &str`
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_lines(
         &self,
         ending_before: &str,
+        expand: &[String],
         invoice: &str,
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::LineItem>> {
+        Ok(self
+            .get_lines_with_response(ending_before, expand, invoice, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_lines`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_lines_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        invoice: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::LineItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -870,9 +1578,9 @@ impl Invoices {
             ),
             None,
         );
-        let resp: crate::types::InvoiceLinesList = self
+        let resp: crate::Response<crate::types::InvoiceLinesList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -882,7 +1590,7 @@ impl Invoices {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
@@ -891,11 +1599,21 @@ impl Invoices {
      *
      * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
      */
-    pub async fn get_all_lines(&self, invoice: &str) -> ClientResult<Vec<crate::types::LineItem>> {
+    pub async fn get_all_lines(
+        &self,
+        expand: &[String],
+        invoice: &str,
+    ) -> ClientResult<Vec<crate::types::LineItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/invoices/{}/lines",
+                "/v1/invoices/{}/lines?{}",
                 crate::progenitor_support::encode_path(invoice),
+                query_
             ),
             None,
         );
@@ -958,6 +1676,101 @@ impl Invoices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_all_lines`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_lines_pages_stream(
+        &self,
+        expand: &[String],
+        invoice: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::LineItem>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/invoices/{}/lines?{}",
+                crate::progenitor_support::encode_path(invoice),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::InvoiceLinesList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_all_lines`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_lines_stream(
+        &self,
+        expand: &[String],
+        invoice: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::LineItem>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_lines_pages_stream(expand, invoice, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/invoices/{invoice}/mark_uncollectible` endpoint.
      *
@@ -965,12 +1778,28 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `invoice: &str` -- The account's country.
+     * * `invoice: &str`
      */
     pub async fn post_mark_uncollectible(
         &self,
         invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
     ) -> ClientResult<crate::types::Invoice> {
+        Ok(self
+            .post_mark_uncollectible_with_response(invoice, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoices/{invoice}/mark_uncollectible` endpoint.
+     *
+     * As opposed to `post_mark_uncollectible`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_mark_uncollectible_with_response(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/mark_uncollectible",
@@ -979,10 +1808,10 @@ impl Invoices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -995,9 +1824,25 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `invoice: &str` -- The account's country.
+     * * `invoice: &str`
      */
-    pub async fn post_pay(&self, invoice: &str) -> ClientResult<crate::types::Invoice> {
+    pub async fn post_pay(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoicePayRequest,
+    ) -> ClientResult<crate::types::Invoice> {
+        Ok(self.post_pay_with_response(invoice, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoices/{invoice}/pay` endpoint.
+     *
+     * As opposed to `post_pay`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_pay_with_response(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostInvoicesInvoicePayRequest,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/pay",
@@ -1006,10 +1851,10 @@ impl Invoices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1024,9 +1869,25 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `invoice: &str` -- The account's country.
+     * * `invoice: &str`
      */
-    pub async fn post_send(&self, invoice: &str) -> ClientResult<crate::types::Invoice> {
+    pub async fn post_send(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::types::Invoice> {
+        Ok(self.post_send_with_response(invoice, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoices/{invoice}/send` endpoint.
+     *
+     * As opposed to `post_send`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_send_with_response(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/send",
@@ -1035,10 +1896,10 @@ impl Invoices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1051,9 +1912,25 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `invoice: &str` -- The account's country.
+     * * `invoice: &str`
      */
-    pub async fn post_void(&self, invoice: &str) -> ClientResult<crate::types::Invoice> {
+    pub async fn post_void(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::types::Invoice> {
+        Ok(self.post_void_with_response(invoice, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/invoices/{invoice}/void` endpoint.
+     *
+     * As opposed to `post_void`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_void_with_response(
+        &self,
+        invoice: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::Invoice>> {
         let url = self.client.url(
             &format!(
                 "/v1/invoices/{}/void",
@@ -1062,10 +1939,10 @@ impl Invoices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
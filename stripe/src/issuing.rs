@@ -22,7 +22,7 @@ impl Issuing {
      * * `cardholder: &str` -- Only return authorizations that belong to the given cardholder.
      * * `created: &str` -- Only return authorizations that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: crate::types::IssuingAuthorizationStatus` -- Only return authorizations with the given status. One of `pending`, `closed`, or `reversed`.
@@ -33,10 +33,41 @@ impl Issuing {
         cardholder: &str,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::IssuingAuthorizationStatus,
     ) -> ClientResult<Vec<crate::types::IssuingAuthorization>> {
+        Ok(self
+            .get_authorizations_with_response(
+                card,
+                cardholder,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
+     *
+     * As opposed to `get_authorizations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_authorizations_with_response(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingAuthorizationStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingAuthorization>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !card.is_empty() {
             query_args.push(("card".to_string(), card.to_string()));
@@ -47,6 +78,9 @@ impl Issuing {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -60,9 +94,9 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/authorizations?{}", query_), None);
-        let resp: crate::types::GetIssuingAuthorizationsResponse = self
+        let resp: crate::Response<crate::types::GetIssuingAuthorizationsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,7 +106,7 @@ impl Issuing {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
@@ -86,6 +120,7 @@ impl Issuing {
         card: &str,
         cardholder: &str,
         _created: &str,
+        expand: &[String],
         status: crate::types::IssuingAuthorizationStatus,
     ) -> ClientResult<Vec<crate::types::IssuingAuthorization>> {
         let mut query_args: Vec<(String, String)> = Default::default();
@@ -95,6 +130,9 @@ impl Issuing {
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -161,6 +199,118 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
+     *
+     * As opposed to `get_all_authorizations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_authorizations_pages_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::IssuingAuthorizationStatus,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::IssuingAuthorization>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !card.is_empty() {
+            query_args.push(("card".to_string(), card.to_string()));
+        }
+        if !cardholder.is_empty() {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/authorizations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetIssuingAuthorizationsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
+     *
+     * As opposed to `get_all_authorizations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_authorizations_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::IssuingAuthorizationStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingAuthorization>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_authorizations_pages_stream(
+                card, cardholder, _created, expand, status, None,
+            ),
+        )
+    }
     /**
      * This function performs a `GET` to the `/v1/issuing/authorizations/{authorization}` endpoint.
      *
@@ -168,22 +318,44 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `authorization: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_authorizations_authorization(
         &self,
         authorization: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::IssuingAuthorization> {
+        Ok(self
+            .get_authorizations_authorization_with_response(authorization, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/authorizations/{authorization}` endpoint.
+     *
+     * As opposed to `get_authorizations_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_authorizations_authorization_with_response(
+        &self,
+        authorization: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/authorizations/{}",
+                "/v1/issuing/authorizations/{}?{}",
                 crate::progenitor_support::encode_path(authorization),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -199,12 +371,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The account's country.
+     * * `authorization: &str`
      */
     pub async fn post_authorizations_authorization(
         &self,
         authorization: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
     ) -> ClientResult<crate::types::IssuingAuthorization> {
+        Ok(self
+            .post_authorizations_authorization_with_response(authorization, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/authorizations/{authorization}` endpoint.
+     *
+     * As opposed to `post_authorizations_authorization`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_authorizations_authorization_with_response(
+        &self,
+        authorization: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/authorizations/{}",
@@ -213,10 +401,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -229,12 +417,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The account's country.
+     * * `authorization: &str`
      */
     pub async fn post_authorizations_authorization_approve(
         &self,
         authorization: &str,
+        body: &crate::types::PostIssuingAuthorizationsAuthorizationApproveRequest,
     ) -> ClientResult<crate::types::IssuingAuthorization> {
+        Ok(self
+            .post_authorizations_authorization_approve_with_response(authorization, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/authorizations/{authorization}/approve` endpoint.
+     *
+     * As opposed to `post_authorizations_authorization_approve`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_authorizations_authorization_approve_with_response(
+        &self,
+        authorization: &str,
+        body: &crate::types::PostIssuingAuthorizationsAuthorizationApproveRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/authorizations/{}/approve",
@@ -243,10 +447,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -259,12 +463,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The account's country.
+     * * `authorization: &str`
      */
     pub async fn post_authorizations_authorization_decline(
         &self,
         authorization: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
     ) -> ClientResult<crate::types::IssuingAuthorization> {
+        Ok(self
+            .post_authorizations_authorization_decline_with_response(authorization, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/authorizations/{authorization}/decline` endpoint.
+     *
+     * As opposed to `post_authorizations_authorization_decline`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_authorizations_authorization_decline_with_response(
+        &self,
+        authorization: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingAuthorization>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/authorizations/{}/decline",
@@ -273,10 +493,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -292,7 +512,7 @@ impl Issuing {
      * * `created: &str` -- Only return cardholders that were created during the given date interval.
      * * `email: &str` -- Only return cardholders that have the given email address.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `phone_number: &str` -- Only return cardholders that have the given phone number.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -304,12 +524,45 @@ impl Issuing {
         _created: &str,
         email: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         phone_number: &str,
         starting_after: &str,
         status: crate::types::IssuingCardholderStatus,
         type_: crate::types::AccountHolderType,
     ) -> ClientResult<Vec<crate::types::IssuingCardholder>> {
+        Ok(self
+            .get_cardholders_with_response(
+                _created,
+                email,
+                ending_before,
+                expand,
+                limit,
+                phone_number,
+                starting_after,
+                status,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
+     *
+     * As opposed to `get_cardholders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_cardholders_with_response(
+        &self,
+        _created: &str,
+        email: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        phone_number: &str,
+        starting_after: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingCardholder>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
@@ -317,6 +570,9 @@ impl Issuing {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -336,9 +592,9 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/cardholders?{}", query_), None);
-        let resp: crate::types::GetIssuingCardholdersResponse = self
+        let resp: crate::Response<crate::types::GetIssuingCardholdersResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -348,7 +604,7 @@ impl Issuing {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
@@ -361,6 +617,7 @@ impl Issuing {
         &self,
         _created: &str,
         email: &str,
+        expand: &[String],
         phone_number: &str,
         status: crate::types::IssuingCardholderStatus,
         type_: crate::types::AccountHolderType,
@@ -369,6 +626,9 @@ impl Issuing {
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !phone_number.is_empty() {
             query_args.push(("phone_number".to_string(), phone_number.to_string()));
         }
@@ -441,18 +701,152 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
+     *
+     * As opposed to `get_all_cardholders`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_cardholders_pages_stream(
+        &self,
+        _created: &str,
+        email: &str,
+        expand: &[String],
+        phone_number: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssuingCardholder>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !email.is_empty() {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/cardholders?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetIssuingCardholdersResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
+     *
+     * As opposed to `get_all_cardholders`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_cardholders_stream(
+        &self,
+        _created: &str,
+        email: &str,
+        expand: &[String],
+        phone_number: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingCardholder>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_cardholders_pages_stream(
+            _created,
+            email,
+            expand,
+            phone_number,
+            status,
+            type_,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/issuing/cardholders` endpoint.
      *
      * <p>Creates a new Issuing <code>Cardholder</code> object that can be issued cards.</p>
      */
-    pub async fn post_cardholder(&self) -> ClientResult<crate::types::IssuingCardholder> {
+    pub async fn post_cardholder(
+        &self,
+        body: &crate::types::PostIssuingCardholdersRequest,
+    ) -> ClientResult<crate::types::IssuingCardholder> {
+        Ok(self.post_cardholder_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/cardholders` endpoint.
+     *
+     * As opposed to `post_cardholder`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_cardholder_with_response(
+        &self,
+        body: &crate::types::PostIssuingCardholdersRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCardholder>> {
         let url = self.client.url("/v1/issuing/cardholders", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -465,22 +859,44 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `cardholder: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `cardholder: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_cardholders_cardholder(
         &self,
         cardholder: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::IssuingCardholder> {
+        Ok(self
+            .get_cardholders_cardholder_with_response(cardholder, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cardholders/{cardholder}` endpoint.
+     *
+     * As opposed to `get_cardholders_cardholder`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_cardholders_cardholder_with_response(
+        &self,
+        cardholder: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::IssuingCardholder>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/cardholders/{}",
+                "/v1/issuing/cardholders/{}?{}",
                 crate::progenitor_support::encode_path(cardholder),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -496,12 +912,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `cardholder: &str` -- The account's country.
+     * * `cardholder: &str`
      */
     pub async fn post_cardholders_cardholder(
         &self,
         cardholder: &str,
+        body: &crate::types::PostIssuingCardholdersCardholderRequest,
     ) -> ClientResult<crate::types::IssuingCardholder> {
+        Ok(self
+            .post_cardholders_cardholder_with_response(cardholder, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/cardholders/{cardholder}` endpoint.
+     *
+     * As opposed to `post_cardholders_cardholder`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_cardholders_cardholder_with_response(
+        &self,
+        cardholder: &str,
+        body: &crate::types::PostIssuingCardholdersCardholderRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCardholder>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/cardholders/{}",
@@ -510,10 +942,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -529,9 +961,9 @@ impl Issuing {
      * * `cardholder: &str` -- Only return cards belonging to the Cardholder with the provided ID.
      * * `created: &str` -- Only return cards that were issued during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `exp_month: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `exp_year: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `exp_month: i64` -- Only return cards that have the given expiration month.
+     * * `exp_year: i64` -- Only return cards that have the given expiration year.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `last_4: &str` -- Only return cards that have the given last four digits.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -545,12 +977,49 @@ impl Issuing {
         ending_before: &str,
         exp_month: i64,
         exp_year: i64,
+        expand: &[String],
         last_4: &str,
         limit: i64,
         starting_after: &str,
         status: crate::types::IssuingCardStatus,
         type_: crate::types::IssuingCardType,
     ) -> ClientResult<Vec<crate::types::IssuingCard>> {
+        Ok(self
+            .get_cards_with_response(
+                cardholder,
+                _created,
+                ending_before,
+                exp_month,
+                exp_year,
+                expand,
+                last_4,
+                limit,
+                starting_after,
+                status,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
+     *
+     * As opposed to `get_cards`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_cards_with_response(
+        &self,
+        cardholder: &str,
+        _created: &str,
+        ending_before: &str,
+        exp_month: i64,
+        exp_year: i64,
+        expand: &[String],
+        last_4: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingCard>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
@@ -564,6 +1033,9 @@ impl Issuing {
         if exp_year > 0 {
             query_args.push(("exp_year".to_string(), exp_year.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !last_4.is_empty() {
             query_args.push(("last4".to_string(), last_4.to_string()));
         }
@@ -583,9 +1055,9 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/cards?{}", query_), None);
-        let resp: crate::types::GetIssuingCardsResponse = self
+        let resp: crate::Response<crate::types::GetIssuingCardsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -595,7 +1067,7 @@ impl Issuing {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
@@ -610,6 +1082,7 @@ impl Issuing {
         _created: &str,
         exp_month: i64,
         exp_year: i64,
+        expand: &[String],
         last_4: &str,
         status: crate::types::IssuingCardStatus,
         type_: crate::types::IssuingCardType,
@@ -624,6 +1097,9 @@ impl Issuing {
         if exp_year > 0 {
             query_args.push(("exp_year".to_string(), exp_year.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !last_4.is_empty() {
             query_args.push(("last4".to_string(), last_4.to_string()));
         }
@@ -696,18 +1172,155 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_cards_pages_stream(
+        &self,
+        cardholder: &str,
+        _created: &str,
+        exp_month: i64,
+        exp_year: i64,
+        expand: &[String],
+        last_4: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssuingCard>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !cardholder.is_empty() {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        if exp_month > 0 {
+            query_args.push(("exp_month".to_string(), exp_month.to_string()));
+        }
+        if exp_year > 0 {
+            query_args.push(("exp_year".to_string(), exp_year.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !last_4.is_empty() {
+            query_args.push(("last4".to_string(), last_4.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/cards?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetIssuingCardsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
+     *
+     * As opposed to `get_all_cards`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_cards_stream(
+        &self,
+        cardholder: &str,
+        _created: &str,
+        exp_month: i64,
+        exp_year: i64,
+        expand: &[String],
+        last_4: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingCard>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_cards_pages_stream(
+            cardholder, _created, exp_month, exp_year, expand, last_4, status, type_, None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/issuing/cards` endpoint.
      *
      * <p>Creates an Issuing <code>Card</code> object.</p>
      */
-    pub async fn post_card(&self) -> ClientResult<crate::types::IssuingCard> {
+    pub async fn post_card(
+        &self,
+        body: &crate::types::PostIssuingCardsRequest,
+    ) -> ClientResult<crate::types::IssuingCard> {
+        Ok(self.post_card_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/cards` endpoint.
+     *
+     * As opposed to `post_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_card_with_response(
+        &self,
+        body: &crate::types::PostIssuingCardsRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCard>> {
         let url = self.client.url("/v1/issuing/cards", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -720,19 +1333,41 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `card: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `card: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
-    pub async fn get_cards_card(&self, card: &str) -> ClientResult<crate::types::IssuingCard> {
+    pub async fn get_cards_card(
+        &self,
+        card: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::types::IssuingCard> {
+        Ok(self.get_cards_card_with_response(card, expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cards/{card}` endpoint.
+     *
+     * As opposed to `get_cards_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_cards_card_with_response(
+        &self,
+        card: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::IssuingCard>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/cards/{}",
+                "/v1/issuing/cards/{}?{}",
                 crate::progenitor_support::encode_path(card),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -748,9 +1383,25 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `card: &str` -- The account's country.
+     * * `card: &str`
+     */
+    pub async fn post_cards_card(
+        &self,
+        card: &str,
+        body: &crate::types::PostIssuingCardsCardRequest,
+    ) -> ClientResult<crate::types::IssuingCard> {
+        Ok(self.post_cards_card_with_response(card, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/cards/{card}` endpoint.
+     *
+     * As opposed to `post_cards_card`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_cards_card(&self, card: &str) -> ClientResult<crate::types::IssuingCard> {
+    pub async fn post_cards_card_with_response(
+        &self,
+        card: &str,
+        body: &crate::types::PostIssuingCardsCardRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingCard>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/cards/{}",
@@ -759,10 +1410,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -777,7 +1428,7 @@ impl Issuing {
      *
      * * `created: &str` -- Select Issuing disputes that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: crate::types::IssuingDisputeStatus` -- Select Issuing disputes with the given status.
@@ -787,15 +1438,47 @@ impl Issuing {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::IssuingDisputeStatus,
         transaction: &str,
     ) -> ClientResult<Vec<crate::types::IssuingDispute>> {
+        Ok(self
+            .get_disputes_with_response(
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                status,
+                transaction,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
+     *
+     * As opposed to `get_disputes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_disputes_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingDispute>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -812,9 +1495,9 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/disputes?{}", query_), None);
-        let resp: crate::types::IssuingDisputeList = self
+        let resp: crate::Response<crate::types::IssuingDisputeList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -824,7 +1507,7 @@ impl Issuing {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
@@ -836,10 +1519,14 @@ impl Issuing {
     pub async fn get_all_disputes(
         &self,
         _created: &str,
+        expand: &[String],
         status: crate::types::IssuingDisputeStatus,
         transaction: &str,
     ) -> ClientResult<Vec<crate::types::IssuingDispute>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -909,18 +1596,139 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
+     *
+     * As opposed to `get_all_disputes`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_disputes_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssuingDispute>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !transaction.is_empty() {
+            query_args.push(("transaction".to_string(), transaction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/disputes?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::IssuingDisputeList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
+     *
+     * As opposed to `get_all_disputes`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_disputes_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingDispute>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_disputes_pages_stream(
+            _created,
+            expand,
+            status,
+            transaction,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/issuing/disputes` endpoint.
      *
      * <p>Creates an Issuing <code>Dispute</code> object. Individual pieces of evidence within the <code>evidence</code> object are optional at this point. Stripe only validates that required evidence is present during submission. Refer to <a href="/docs/issuing/purchases/disputes#dispute-reasons-and-evidence">Dispute reasons and evidence</a> for more details about evidence requirements.</p>
      */
-    pub async fn post_dispute(&self) -> ClientResult<crate::types::IssuingDispute> {
+    pub async fn post_dispute(
+        &self,
+        body: &crate::types::PostIssuingDisputesRequest,
+    ) -> ClientResult<crate::types::IssuingDispute> {
+        Ok(self.post_dispute_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/disputes` endpoint.
+     *
+     * As opposed to `post_dispute`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_dispute_with_response(
+        &self,
+        body: &crate::types::PostIssuingDisputesRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
         let url = self.client.url("/v1/issuing/disputes", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -933,22 +1741,44 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `dispute: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `dispute: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_disputes_dispute(
         &self,
         dispute: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::IssuingDispute> {
+        Ok(self
+            .get_disputes_dispute_with_response(dispute, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/disputes/{dispute}` endpoint.
+     *
+     * As opposed to `get_disputes_dispute`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_disputes_dispute_with_response(
+        &self,
+        dispute: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/disputes/{}",
+                "/v1/issuing/disputes/{}?{}",
                 crate::progenitor_support::encode_path(dispute),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -964,12 +1794,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `dispute: &str` -- The account's country.
+     * * `dispute: &str`
      */
     pub async fn post_disputes_dispute(
         &self,
         dispute: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> ClientResult<crate::types::IssuingDispute> {
+        Ok(self
+            .post_disputes_dispute_with_response(dispute, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/disputes/{dispute}` endpoint.
+     *
+     * As opposed to `post_disputes_dispute`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_disputes_dispute_with_response(
+        &self,
+        dispute: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/disputes/{}",
@@ -978,10 +1824,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -994,12 +1840,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `dispute: &str` -- The account's country.
+     * * `dispute: &str`
      */
-    pub async fn post_disputes_dispute_submit(
+    pub async fn post_disputes_dispute_submit<B: serde::Serialize>(
         &self,
         dispute: &str,
+        body: &B,
     ) -> ClientResult<crate::types::IssuingDispute> {
+        Ok(self
+            .post_disputes_dispute_submit_with_response::<B>(dispute, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/disputes/{dispute}/submit` endpoint.
+     *
+     * As opposed to `post_disputes_dispute_submit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_disputes_dispute_submit_with_response<B: serde::Serialize>(
+        &self,
+        dispute: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::IssuingDispute>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/disputes/{}/submit",
@@ -1008,10 +1870,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1026,7 +1888,7 @@ impl Issuing {
      *
      * * `created: &str` -- Only return issuing settlements that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -1034,13 +1896,35 @@ impl Issuing {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::IssuingSettlement>> {
+        Ok(self
+            .get_settlements_with_response(_created, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
+     *
+     * As opposed to `get_settlements`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_settlements_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingSettlement>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1051,9 +1935,9 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/settlements?{}", query_), None);
-        let resp: crate::types::GetIssuingSettlementsResponse = self
+        let resp: crate::Response<crate::types::GetIssuingSettlementsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1063,7 +1947,7 @@ impl Issuing {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
@@ -1075,8 +1959,16 @@ impl Issuing {
     pub async fn get_all_settlements(
         &self,
         _created: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::IssuingSettlement>> {
-        let url = self.client.url("/v1/issuing/settlements", None);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/settlements?{}", query_), None);
         let mut resp: crate::types::GetIssuingSettlementsResponse = self
             .client
             .get(
@@ -1136,6 +2028,98 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
+     *
+     * As opposed to `get_all_settlements`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_settlements_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::IssuingSettlement>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/settlements?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetIssuingSettlementsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
+     *
+     * As opposed to `get_all_settlements`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_settlements_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingSettlement>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_settlements_pages_stream(_created, expand, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/issuing/settlements/{settlement}` endpoint.
      *
@@ -1143,22 +2127,44 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `settlement: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `settlement: &str`
      */
     pub async fn get_settlements_settlement(
         &self,
+        expand: &[String],
         settlement: &str,
     ) -> ClientResult<crate::types::IssuingSettlement> {
+        Ok(self
+            .get_settlements_settlement_with_response(expand, settlement)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/settlements/{settlement}` endpoint.
+     *
+     * As opposed to `get_settlements_settlement`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_settlements_settlement_with_response(
+        &self,
+        expand: &[String],
+        settlement: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingSettlement>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/settlements/{}",
+                "/v1/issuing/settlements/{}?{}",
                 crate::progenitor_support::encode_path(settlement),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1174,12 +2180,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `settlement: &str` -- The account's country.
+     * * `settlement: &str`
      */
-    pub async fn post_settlements_settlement(
+    pub async fn post_settlements_settlement<B: serde::Serialize>(
         &self,
         settlement: &str,
+        body: &B,
     ) -> ClientResult<crate::types::IssuingSettlement> {
+        Ok(self
+            .post_settlements_settlement_with_response::<B>(settlement, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/settlements/{settlement}` endpoint.
+     *
+     * As opposed to `post_settlements_settlement`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_settlements_settlement_with_response<B: serde::Serialize>(
+        &self,
+        settlement: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::IssuingSettlement>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/settlements/{}",
@@ -1188,10 +2210,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -1208,7 +2230,7 @@ impl Issuing {
      * * `cardholder: &str` -- Only return transactions that belong to the given cardholder.
      * * `created: &str` -- Only return transactions that were created during the given date interval.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `type_: crate::types::IssuingTransactionType` -- Only return transactions that have the given type. One of `capture` or `refund`.
@@ -1219,10 +2241,41 @@ impl Issuing {
         cardholder: &str,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::IssuingTransactionType,
     ) -> ClientResult<Vec<crate::types::IssuingTransaction>> {
+        Ok(self
+            .get_transactions_with_response(
+                card,
+                cardholder,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_transactions_with_response(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::IssuingTransactionType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::IssuingTransaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !card.is_empty() {
             query_args.push(("card".to_string(), card.to_string()));
@@ -1233,6 +2286,9 @@ impl Issuing {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -1246,9 +2302,9 @@ impl Issuing {
         let url = self
             .client
             .url(&format!("/v1/issuing/transactions?{}", query_), None);
-        let resp: crate::types::GetIssuingTransactionsResponse = self
+        let resp: crate::Response<crate::types::GetIssuingTransactionsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1258,7 +2314,7 @@ impl Issuing {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
@@ -1272,6 +2328,7 @@ impl Issuing {
         card: &str,
         cardholder: &str,
         _created: &str,
+        expand: &[String],
         type_: crate::types::IssuingTransactionType,
     ) -> ClientResult<Vec<crate::types::IssuingTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
@@ -1281,6 +2338,9 @@ impl Issuing {
         if !cardholder.is_empty() {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -1347,6 +2407,116 @@ impl Issuing {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_transactions_pages_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        expand: &[String],
+        type_: crate::types::IssuingTransactionType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::IssuingTransaction>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !card.is_empty() {
+            query_args.push(("card".to_string(), card.to_string()));
+        }
+        if !cardholder.is_empty() {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/issuing/transactions?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetIssuingTransactionsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        card: &str,
+        cardholder: &str,
+        _created: &str,
+        expand: &[String],
+        type_: crate::types::IssuingTransactionType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::IssuingTransaction>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.get_all_transactions_pages_stream(card, cardholder, _created, expand, type_, None),
+        )
+    }
     /**
      * This function performs a `GET` to the `/v1/issuing/transactions/{transaction}` endpoint.
      *
@@ -1354,22 +2524,44 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `transaction: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `transaction: &str`
      */
     pub async fn get_transactions_transaction(
         &self,
+        expand: &[String],
         transaction: &str,
     ) -> ClientResult<crate::types::IssuingTransaction> {
+        Ok(self
+            .get_transactions_transaction_with_response(expand, transaction)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/issuing/transactions/{transaction}` endpoint.
+     *
+     * As opposed to `get_transactions_transaction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_transactions_transaction_with_response(
+        &self,
+        expand: &[String],
+        transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::IssuingTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/issuing/transactions/{}",
+                "/v1/issuing/transactions/{}?{}",
                 crate::progenitor_support::encode_path(transaction),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1385,12 +2577,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `transaction: &str` -- The account's country.
+     * * `transaction: &str`
      */
     pub async fn post_transactions_transaction(
         &self,
         transaction: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
     ) -> ClientResult<crate::types::IssuingTransaction> {
+        Ok(self
+            .post_transactions_transaction_with_response(transaction, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/issuing/transactions/{transaction}` endpoint.
+     *
+     * As opposed to `post_transactions_transaction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_transactions_transaction_with_response(
+        &self,
+        transaction: &str,
+        body: &crate::types::PostIssuingTransactionsTransactionRequest,
+    ) -> ClientResult<crate::Response<crate::types::IssuingTransaction>> {
         let url = self.client.url(
             &format!(
                 "/v1/issuing/transactions/{}",
@@ -1399,10 +2607,10 @@ impl Issuing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
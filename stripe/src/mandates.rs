@@ -18,19 +18,41 @@ impl Mandates {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `mandate: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `mandate: &str`
      */
-    pub async fn get(&self, mandate: &str) -> ClientResult<crate::types::Mandate> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        mandate: &str,
+    ) -> ClientResult<crate::types::Mandate> {
+        Ok(self.get_with_response(expand, mandate).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/mandates/{mandate}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        mandate: &str,
+    ) -> ClientResult<crate::Response<crate::types::Mandate>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/mandates/{}",
+                "/v1/mandates/{}?{}",
                 crate::progenitor_support::encode_path(mandate),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
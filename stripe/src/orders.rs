@@ -21,19 +21,20 @@ impl Orders {
      * * `created: &str` -- Date this order was created.
      * * `customer: &str` -- Only return orders for the given customer.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `ids: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `ids: &[String]` -- Only return orders with the given IDs.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: &str` -- Only return orders that have the given status. One of `created`, `paid`, `fulfilled`, or `refunded`.
      * * `status_transitions: &str` -- Filter orders based on when they were paid, fulfilled, canceled, or returned.
-     * * `upstream_ids: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `upstream_ids: &[String]` -- Only return orders with the given upstream order IDs.
      */
     pub async fn get_page(
         &self,
         _created: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         _ids: &[String],
         limit: i64,
         starting_after: &str,
@@ -41,6 +42,40 @@ impl Orders {
         _status_transitions: &str,
         _upstream_ids: &[String],
     ) -> ClientResult<Vec<crate::types::Order>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                customer,
+                ending_before,
+                expand,
+                _ids,
+                limit,
+                starting_after,
+                status,
+                _status_transitions,
+                _upstream_ids,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/orders` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        _ids: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: &str,
+        _status_transitions: &str,
+        _upstream_ids: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::Order>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -48,6 +83,9 @@ impl Orders {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -59,9 +97,9 @@ impl Orders {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/orders?{}", query_), None);
-        let resp: crate::types::OrdersLegacyResourceOrderList = self
+        let resp: crate::Response<crate::types::OrdersLegacyResourceOrderList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,7 +109,7 @@ impl Orders {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/orders` endpoint.
@@ -84,6 +122,7 @@ impl Orders {
         &self,
         _created: &str,
         customer: &str,
+        expand: &[String],
         _ids: &[String],
         status: &str,
         _status_transitions: &str,
@@ -93,6 +132,9 @@ impl Orders {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -157,18 +199,145 @@ impl Orders {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/orders` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        _ids: &[String],
+        status: &str,
+        _status_transitions: &str,
+        _upstream_ids: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Order>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/orders?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::OrdersLegacyResourceOrderList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/orders` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        _ids: &[String],
+        status: &str,
+        _status_transitions: &str,
+        _upstream_ids: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Order>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            _created,
+            customer,
+            expand,
+            _ids,
+            status,
+            _status_transitions,
+            _upstream_ids,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/orders` endpoint.
      *
      * <p>Creates a new order object.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Order> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostOrdersRequest,
+    ) -> ClientResult<crate::types::Order> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/orders` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostOrdersRequest,
+    ) -> ClientResult<crate::Response<crate::types::Order>> {
         let url = self.client.url("/v1/orders", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -181,16 +350,37 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::Order> {
+    pub async fn get(&self, expand: &[String], id: &str) -> ClientResult<crate::types::Order> {
+        Ok(self.get_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/orders/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Order>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v1/orders/{}", crate::progenitor_support::encode_path(id),),
+            &format!(
+                "/v1/orders/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -206,18 +396,34 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
-    pub async fn post_orders(&self, id: &str) -> ClientResult<crate::types::Order> {
+    pub async fn post_orders(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersRequest,
+    ) -> ClientResult<crate::types::Order> {
+        Ok(self.post_orders_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/orders/{id}` endpoint.
+     *
+     * As opposed to `post_orders`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_orders_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersRequest,
+    ) -> ClientResult<crate::Response<crate::types::Order>> {
         let url = self.client.url(
             &format!("/v1/orders/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -230,9 +436,25 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
+     */
+    pub async fn post_pay(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersPayRequest,
+    ) -> ClientResult<crate::types::Order> {
+        Ok(self.post_pay_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/orders/{id}/pay` endpoint.
+     *
+     * As opposed to `post_pay`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_pay(&self, id: &str) -> ClientResult<crate::types::Order> {
+    pub async fn post_pay_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersPayRequest,
+    ) -> ClientResult<crate::Response<crate::types::Order>> {
         let url = self.client.url(
             &format!(
                 "/v1/orders/{}/pay",
@@ -241,10 +463,10 @@ impl Orders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -257,9 +479,25 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
-    pub async fn post_return(&self, id: &str) -> ClientResult<crate::types::OrderReturn> {
+    pub async fn post_return(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersReturnsRequest,
+    ) -> ClientResult<crate::types::OrderReturn> {
+        Ok(self.post_return_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/orders/{id}/returns` endpoint.
+     *
+     * As opposed to `post_return`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_return_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostOrdersReturnsRequest,
+    ) -> ClientResult<crate::Response<crate::types::OrderReturn>> {
         let url = self.client.url(
             &format!(
                 "/v1/orders/{}/returns",
@@ -268,10 +506,10 @@ impl Orders {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
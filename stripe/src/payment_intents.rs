@@ -21,7 +21,7 @@ impl PaymentIntents {
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `customer: &str` -- Only return PaymentIntents for the customer specified by this customer ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -30,9 +30,36 @@ impl PaymentIntents {
         _created: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PaymentIntent>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                customer,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentIntent>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -40,6 +67,9 @@ impl PaymentIntents {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -50,9 +80,9 @@ impl PaymentIntents {
         let url = self
             .client
             .url(&format!("/v1/payment_intents?{}", query_), None);
-        let resp: crate::types::PaymentFlowsIntentList = self
+        let resp: crate::Response<crate::types::PaymentFlowsIntentList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -62,7 +92,7 @@ impl PaymentIntents {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/payment_intents` endpoint.
@@ -75,11 +105,15 @@ impl PaymentIntents {
         &self,
         _created: &str,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::PaymentIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
@@ -143,6 +177,102 @@ impl PaymentIntents {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PaymentIntent>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/payment_intents?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PaymentFlowsIntentList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaymentIntent>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(_created, customer, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/payment_intents` endpoint.
      *
@@ -157,13 +287,27 @@ impl PaymentIntents {
      * available in the <a href="/docs/api/payment_intents/confirm">confirm API</a> when <code>confirm=true</code>
      * is supplied.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::PaymentIntent> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPaymentIntentsRequest,
+    ) -> ClientResult<crate::types::PaymentIntent> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_intents` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostPaymentIntentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
         let url = self.client.url("/v1/payment_intents", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -179,18 +323,39 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
      * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for payment intents](https://stripe.com/docs/search#query-fields-for-payment-intents).
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        Ok(self
+            .get_search_with_response(expand, limit, page, query)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_search_with_response(
+        &self,
+        expand: &[String],
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -204,9 +369,9 @@ impl PaymentIntents {
         let url = self
             .client
             .url(&format!("/v1/payment_intents/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::Response<crate::types::SearchResult> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -216,7 +381,7 @@ impl PaymentIntents {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/payment_intents/search` endpoint.
@@ -228,8 +393,15 @@ impl PaymentIntents {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -296,6 +468,99 @@ impl PaymentIntents {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_search_pages_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Charge>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/payment_intents/search?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::SearchResult = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_pages_stream(expand, query, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/payment_intents/{intent}` endpoint.
      *
@@ -308,18 +573,38 @@ impl PaymentIntents {
      * **Parameters:**
      *
      * * `client_secret: &str` -- The client secret of the PaymentIntent. Required if a publishable key is used to retrieve the source.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `intent: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `intent: &str`
      */
     pub async fn get_intent(
         &self,
         client_secret: &str,
+        expand: &[String],
         intent: &str,
     ) -> ClientResult<crate::types::PaymentIntent> {
+        Ok(self
+            .get_intent_with_response(client_secret, expand, intent)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents/{intent}` endpoint.
+     *
+     * As opposed to `get_intent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_intent_with_response(
+        &self,
+        client_secret: &str,
+        expand: &[String],
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_secret.is_empty() {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -330,7 +615,7 @@ impl PaymentIntents {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -352,9 +637,25 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
+     */
+    pub async fn post_intent(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentRequest,
+    ) -> ClientResult<crate::types::PaymentIntent> {
+        Ok(self.post_intent_with_response(intent, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_intents/{intent}` endpoint.
+     *
+     * As opposed to `post_intent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_intent(&self, intent: &str) -> ClientResult<crate::types::PaymentIntent> {
+    pub async fn post_intent_with_response(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}",
@@ -363,10 +664,10 @@ impl PaymentIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -383,12 +684,28 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
     pub async fn post_intent_cancel(
         &self,
         intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentCancelRequest,
     ) -> ClientResult<crate::types::PaymentIntent> {
+        Ok(self
+            .post_intent_cancel_with_response(intent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_intents/{intent}/cancel` endpoint.
+     *
+     * As opposed to `post_intent_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_cancel_with_response(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentCancelRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}/cancel",
@@ -397,10 +714,10 @@ impl PaymentIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -417,12 +734,28 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
     pub async fn post_intent_capture(
         &self,
         intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentCaptureRequest,
     ) -> ClientResult<crate::types::PaymentIntent> {
+        Ok(self
+            .post_intent_capture_with_response(intent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_intents/{intent}/capture` endpoint.
+     *
+     * As opposed to `post_intent_capture`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_capture_with_response(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentCaptureRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}/capture",
@@ -431,10 +764,10 @@ impl PaymentIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -471,12 +804,28 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
     pub async fn post_intent_confirm(
         &self,
         intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentConfirmRequest,
     ) -> ClientResult<crate::types::PaymentIntent> {
+        Ok(self
+            .post_intent_confirm_with_response(intent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_intents/{intent}/confirm` endpoint.
+     *
+     * As opposed to `post_intent_confirm`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_confirm_with_response(
+        &self,
+        intent: &str,
+        body: &crate::types::PostPaymentIntentsIntentConfirmRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}/confirm",
@@ -485,10 +834,10 @@ impl PaymentIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -501,12 +850,28 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
-    pub async fn post_intent_verify_microdeposit(
+    pub async fn post_intent_verify_microdeposit<B: serde::Serialize>(
         &self,
         intent: &str,
+        body: &B,
     ) -> ClientResult<crate::types::PaymentIntent> {
+        Ok(self
+            .post_intent_verify_microdeposit_with_response::<B>(intent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_intents/{intent}/verify_microdeposits` endpoint.
+     *
+     * As opposed to `post_intent_verify_microdeposit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_verify_microdeposit_with_response<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PaymentIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_intents/{}/verify_microdeposits",
@@ -515,10 +880,10 @@ impl PaymentIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
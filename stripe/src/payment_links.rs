@@ -20,7 +20,7 @@ impl PaymentLinks {
      *
      * * `active: bool` -- Only return payment links that are active or inactive (e.g., pass `false` to list all inactive payment links).
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -28,9 +28,28 @@ impl PaymentLinks {
         &self,
         active: bool,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PaymentLink>> {
+        Ok(self
+            .get_page_with_response(active, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        active: bool,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentLink>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -38,6 +57,9 @@ impl PaymentLinks {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -48,9 +70,9 @@ impl PaymentLinks {
         let url = self
             .client
             .url(&format!("/v1/payment_links?{}", query_), None);
-        let resp: crate::types::GetPaymentLinksResponse = self
+        let resp: crate::Response<crate::types::GetPaymentLinksResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +82,7 @@ impl PaymentLinks {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/payment_links` endpoint.
@@ -69,11 +91,18 @@ impl PaymentLinks {
      *
      * <p>Returns a list of your payment links.</p>
      */
-    pub async fn get_all(&self, active: bool) -> ClientResult<Vec<crate::types::PaymentLink>> {
+    pub async fn get_all(
+        &self,
+        active: bool,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::PaymentLink>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
@@ -137,18 +166,126 @@ impl PaymentLinks {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PaymentLink>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/payment_links?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetPaymentLinksResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaymentLink>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(active, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/payment_links` endpoint.
      *
      * <p>Creates a payment link.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::PaymentLink> {
+    pub async fn post<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::PaymentLink> {
+        Ok(self.post_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_links` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PaymentLink>> {
         let url = self.client.url("/v1/payment_links", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -161,19 +298,44 @@ impl PaymentLinks {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `payment_link: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `payment_link: &str`
+     */
+    pub async fn get_link(
+        &self,
+        expand: &[String],
+        payment_link: &str,
+    ) -> ClientResult<crate::types::PaymentLink> {
+        Ok(self
+            .get_link_with_response(expand, payment_link)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links/{payment_link}` endpoint.
+     *
+     * As opposed to `get_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_link(&self, payment_link: &str) -> ClientResult<crate::types::PaymentLink> {
+    pub async fn get_link_with_response(
+        &self,
+        expand: &[String],
+        payment_link: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentLink>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payment_links/{}",
+                "/v1/payment_links/{}?{}",
                 crate::progenitor_support::encode_path(payment_link),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -189,9 +351,28 @@ impl PaymentLinks {
      *
      * **Parameters:**
      *
-     * * `payment_link: &str` -- The account's country.
+     * * `payment_link: &str`
+     */
+    pub async fn post_link<B: serde::Serialize>(
+        &self,
+        payment_link: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::PaymentLink> {
+        Ok(self
+            .post_link_with_response::<B>(payment_link, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_links/{payment_link}` endpoint.
+     *
+     * As opposed to `post_link`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_link(&self, payment_link: &str) -> ClientResult<crate::types::PaymentLink> {
+    pub async fn post_link_with_response<B: serde::Serialize>(
+        &self,
+        payment_link: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PaymentLink>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_links/{}",
@@ -200,10 +381,10 @@ impl PaymentLinks {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -217,22 +398,50 @@ impl PaymentLinks {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `payment_link: &str` -- The account's country.
+     * * `payment_link: &str`
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_link_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_link: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        Ok(self
+            .get_link_line_items_with_response(
+                ending_before,
+                expand,
+                limit,
+                payment_link,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links/{payment_link}/line_items` endpoint.
+     *
+     * As opposed to `get_link_line_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_link_line_items_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payment_link: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -248,9 +457,9 @@ impl PaymentLinks {
             ),
             None,
         );
-        let resp: crate::types::LineItems = self
+        let resp: crate::Response<crate::types::LineItems> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -260,7 +469,7 @@ impl PaymentLinks {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/payment_links/{payment_link}/line_items` endpoint.
@@ -271,12 +480,19 @@ impl PaymentLinks {
      */
     pub async fn get_all_link_line_items(
         &self,
+        expand: &[String],
         payment_link: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payment_links/{}/line_items",
+                "/v1/payment_links/{}/line_items?{}",
                 crate::progenitor_support::encode_path(payment_link),
+                query_
             ),
             None,
         );
@@ -339,4 +555,103 @@ impl PaymentLinks {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links/{payment_link}/line_items` endpoint.
+     *
+     * As opposed to `get_all_link_line_items`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_link_line_items_pages_stream(
+        &self,
+        expand: &[String],
+        payment_link: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Item>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/payment_links/{}/line_items?{}",
+                crate::progenitor_support::encode_path(payment_link),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::LineItems = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_links/{payment_link}/line_items` endpoint.
+     *
+     * As opposed to `get_all_link_line_items`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_link_line_items_stream(
+        &self,
+        expand: &[String],
+        payment_link: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_link_line_items_pages_stream(
+            expand,
+            payment_link,
+            None,
+        ))
+    }
 }
@@ -20,7 +20,7 @@ impl PaymentMethods {
      *
      * * `customer: &str` -- The ID of the customer whose PaymentMethods will be retrieved. If not provided, the response list will be empty.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `type_: crate::types::GetCustomersCustomerPaymentMethodsType` -- A required filter on the list, based on the object `type` field.
@@ -29,10 +29,37 @@ impl PaymentMethods {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> ClientResult<Vec<crate::types::PaymentMethod>> {
+        Ok(self
+            .get_page_with_response(
+                customer,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_methods` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PaymentMethod>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -40,6 +67,9 @@ impl PaymentMethods {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +83,9 @@ impl PaymentMethods {
         let url = self
             .client
             .url(&format!("/v1/payment_methods?{}", query_), None);
-        let resp: crate::types::PaymentFlowsMethodList = self
+        let resp: crate::Response<crate::types::PaymentFlowsMethodList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +95,7 @@ impl PaymentMethods {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/payment_methods` endpoint.
@@ -77,12 +107,16 @@ impl PaymentMethods {
     pub async fn get_all(
         &self,
         customer: &str,
+        expand: &[String],
         type_: crate::types::GetCustomersCustomerPaymentMethodsType,
     ) -> ClientResult<Vec<crate::types::PaymentMethod>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -149,6 +183,105 @@ impl PaymentMethods {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payment_methods` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PaymentMethod>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/payment_methods?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PaymentFlowsMethodList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_methods` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PaymentMethod>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(customer, expand, type_, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/payment_methods` endpoint.
      *
@@ -156,13 +289,27 @@ impl PaymentMethods {
      *
      * <p>Instead of creating a PaymentMethod directly, we recommend using the <a href="/docs/payments/accept-a-payment">PaymentIntents</a> API to accept a payment immediately or the <a href="/docs/payments/save-and-reuse">SetupIntent</a> API to collect payment method details ahead of a future payment.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::PaymentMethod> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPaymentMethodsRequest,
+    ) -> ClientResult<crate::types::PaymentMethod> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_methods` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostPaymentMethodsRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
         let url = self.client.url("/v1/payment_methods", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -175,22 +322,44 @@ impl PaymentMethods {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `payment_method: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `payment_method: &str`
      */
     pub async fn get_method(
         &self,
+        expand: &[String],
         payment_method: &str,
     ) -> ClientResult<crate::types::PaymentMethod> {
+        Ok(self
+            .get_method_with_response(expand, payment_method)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payment_methods/{payment_method}` endpoint.
+     *
+     * As opposed to `get_method`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_method_with_response(
+        &self,
+        expand: &[String],
+        payment_method: &str,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payment_methods/{}",
+                "/v1/payment_methods/{}?{}",
                 crate::progenitor_support::encode_path(payment_method),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -206,12 +375,28 @@ impl PaymentMethods {
      *
      * **Parameters:**
      *
-     * * `payment_method: &str` -- The account's country.
+     * * `payment_method: &str`
      */
     pub async fn post_method(
         &self,
         payment_method: &str,
+        body: &crate::types::PostPaymentMethodsMethodRequest,
     ) -> ClientResult<crate::types::PaymentMethod> {
+        Ok(self
+            .post_method_with_response(payment_method, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_methods/{payment_method}` endpoint.
+     *
+     * As opposed to `post_method`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_method_with_response(
+        &self,
+        payment_method: &str,
+        body: &crate::types::PostPaymentMethodsMethodRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_methods/{}",
@@ -220,10 +405,10 @@ impl PaymentMethods {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -246,12 +431,28 @@ impl PaymentMethods {
      *
      * **Parameters:**
      *
-     * * `payment_method: &str` -- The account's country.
+     * * `payment_method: &str`
      */
     pub async fn post_method_attach(
         &self,
         payment_method: &str,
+        body: &crate::types::PostPaymentMethodsMethodAttachRequest,
     ) -> ClientResult<crate::types::PaymentMethod> {
+        Ok(self
+            .post_method_attach_with_response(payment_method, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_methods/{payment_method}/attach` endpoint.
+     *
+     * As opposed to `post_method_attach`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_method_attach_with_response(
+        &self,
+        payment_method: &str,
+        body: &crate::types::PostPaymentMethodsMethodAttachRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_methods/{}/attach",
@@ -260,10 +461,10 @@ impl PaymentMethods {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -276,12 +477,28 @@ impl PaymentMethods {
      *
      * **Parameters:**
      *
-     * * `payment_method: &str` -- The account's country.
+     * * `payment_method: &str`
      */
     pub async fn post_method_detach(
         &self,
         payment_method: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
     ) -> ClientResult<crate::types::PaymentMethod> {
+        Ok(self
+            .post_method_detach_with_response(payment_method, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payment_methods/{payment_method}/detach` endpoint.
+     *
+     * As opposed to `post_method_detach`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_method_detach_with_response(
+        &self,
+        payment_method: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::PaymentMethod>> {
         let url = self.client.url(
             &format!(
                 "/v1/payment_methods/{}/detach",
@@ -290,10 +507,10 @@ impl PaymentMethods {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -22,7 +22,7 @@ impl Payouts {
      * * `created: &str`
      * * `destination: &str` -- The ID of an external account - only return payouts sent to this external account.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: &str` -- Only return payouts that have the given status: `pending`, `paid`, `failed`, or `canceled`.
@@ -33,10 +33,41 @@ impl Payouts {
         _created: &str,
         destination: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: &str,
     ) -> ClientResult<Vec<crate::types::Payout>> {
+        Ok(self
+            .get_page_with_response(
+                _arrival_date,
+                _created,
+                destination,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payouts` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _arrival_date: &str,
+        _created: &str,
+        destination: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Payout>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !destination.is_empty() {
             query_args.push(("destination".to_string(), destination.to_string()));
@@ -44,6 +75,9 @@ impl Payouts {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -55,9 +89,9 @@ impl Payouts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/payouts?{}", query_), None);
-        let resp: crate::types::PayoutList = self
+        let resp: crate::Response<crate::types::PayoutList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -67,7 +101,7 @@ impl Payouts {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/payouts` endpoint.
@@ -81,12 +115,16 @@ impl Payouts {
         _arrival_date: &str,
         _created: &str,
         destination: &str,
+        expand: &[String],
         status: &str,
     ) -> ClientResult<Vec<crate::types::Payout>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !destination.is_empty() {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -151,6 +189,113 @@ impl Payouts {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/payouts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _arrival_date: &str,
+        _created: &str,
+        destination: &str,
+        expand: &[String],
+        status: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Payout>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !destination.is_empty() {
+            query_args.push(("destination".to_string(), destination.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/payouts?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PayoutList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payouts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _arrival_date: &str,
+        _created: &str,
+        destination: &str,
+        expand: &[String],
+        status: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Payout>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            _arrival_date,
+            _created,
+            destination,
+            expand,
+            status,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/payouts` endpoint.
      *
@@ -160,13 +305,27 @@ impl Payouts {
      *
      * <p>If you are creating a manual payout on a Stripe account that uses multiple payment source types, you’ll need to specify the source type balance that the payout should draw from. The <a href="#balance_object">balance object</a> details available and pending amounts by source type.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Payout> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPayoutsRequest,
+    ) -> ClientResult<crate::types::Payout> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payouts` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostPayoutsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Payout>> {
         let url = self.client.url("/v1/payouts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -179,19 +338,37 @@ impl Payouts {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `payout: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `payout: &str`
+     */
+    pub async fn get(&self, expand: &[String], payout: &str) -> ClientResult<crate::types::Payout> {
+        Ok(self.get_with_response(expand, payout).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/payouts/{payout}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, payout: &str) -> ClientResult<crate::types::Payout> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        payout: &str,
+    ) -> ClientResult<crate::Response<crate::types::Payout>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/payouts/{}",
+                "/v1/payouts/{}?{}",
                 crate::progenitor_support::encode_path(payout),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -207,9 +384,25 @@ impl Payouts {
      *
      * **Parameters:**
      *
-     * * `payout: &str` -- The account's country.
+     * * `payout: &str`
      */
-    pub async fn post_payouts(&self, payout: &str) -> ClientResult<crate::types::Payout> {
+    pub async fn post_payouts(
+        &self,
+        payout: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> ClientResult<crate::types::Payout> {
+        Ok(self.post_payouts_with_response(payout, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payouts/{payout}` endpoint.
+     *
+     * As opposed to `post_payouts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_payouts_with_response(
+        &self,
+        payout: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> ClientResult<crate::Response<crate::types::Payout>> {
         let url = self.client.url(
             &format!(
                 "/v1/payouts/{}",
@@ -218,10 +411,10 @@ impl Payouts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -234,9 +427,25 @@ impl Payouts {
      *
      * **Parameters:**
      *
-     * * `payout: &str` -- The account's country.
+     * * `payout: &str`
+     */
+    pub async fn post_cancel(
+        &self,
+        payout: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::types::Payout> {
+        Ok(self.post_cancel_with_response(payout, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payouts/{payout}/cancel` endpoint.
+     *
+     * As opposed to `post_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_cancel(&self, payout: &str) -> ClientResult<crate::types::Payout> {
+    pub async fn post_cancel_with_response(
+        &self,
+        payout: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::Payout>> {
         let url = self.client.url(
             &format!(
                 "/v1/payouts/{}/cancel",
@@ -245,10 +454,10 @@ impl Payouts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -263,9 +472,28 @@ impl Payouts {
      *
      * **Parameters:**
      *
-     * * `payout: &str` -- The account's country.
+     * * `payout: &str`
+     */
+    pub async fn post_reverse<B: serde::Serialize>(
+        &self,
+        payout: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Payout> {
+        Ok(self
+            .post_reverse_with_response::<B>(payout, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/payouts/{payout}/reverse` endpoint.
+     *
+     * As opposed to `post_reverse`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_reverse(&self, payout: &str) -> ClientResult<crate::types::Payout> {
+    pub async fn post_reverse_with_response<B: serde::Serialize>(
+        &self,
+        payout: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Payout>> {
         let url = self.client.url(
             &format!(
                 "/v1/payouts/{}/reverse",
@@ -274,10 +502,10 @@ impl Payouts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
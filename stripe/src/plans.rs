@@ -21,7 +21,7 @@ impl Plans {
      * * `active: bool` -- Only return plans that are active or inactive (e.g., pass `false` to list all inactive plans).
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `product: &str` -- Only return plans for the given product.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -31,10 +31,39 @@ impl Plans {
         active: bool,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         product: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PlanData>> {
+        Ok(self
+            .get_page_with_response(
+                active,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                product,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/plans` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        active: bool,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        product: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PlanData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -42,6 +71,9 @@ impl Plans {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +85,9 @@ impl Plans {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/plans?{}", query_), None);
-        let resp: crate::types::PlanList = self
+        let resp: crate::Response<crate::types::PlanList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl Plans {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/plans` endpoint.
@@ -78,12 +110,16 @@ impl Plans {
         &self,
         active: bool,
         _created: &str,
+        expand: &[String],
         product: &str,
     ) -> ClientResult<Vec<crate::types::PlanData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !product.is_empty() {
             query_args.push(("product".to_string(), product.to_string()));
         }
@@ -148,18 +184,130 @@ impl Plans {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/plans` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        expand: &[String],
+        product: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PlanData>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !product.is_empty() {
+            query_args.push(("product".to_string(), product.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/plans?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PlanList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/plans` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        expand: &[String],
+        product: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PlanData>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(active, _created, expand, product, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/plans` endpoint.
      *
      * <p>You can now model subscriptions more flexibly using the <a href="#prices">Prices API</a>. It replaces the Plans API and is backwards compatible to simplify your migration.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::PlanData> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPlansRequest,
+    ) -> ClientResult<crate::types::PlanData> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/plans` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostPlansRequest,
+    ) -> ClientResult<crate::Response<crate::types::PlanData>> {
         let url = self.client.url("/v1/plans", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -172,16 +320,37 @@ impl Plans {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `plan: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `plan: &str`
+     */
+    pub async fn get(&self, expand: &[String], plan: &str) -> ClientResult<crate::types::PlanData> {
+        Ok(self.get_with_response(expand, plan).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/plans/{plan}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, plan: &str) -> ClientResult<crate::types::PlanData> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        plan: &str,
+    ) -> ClientResult<crate::Response<crate::types::PlanData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan),),
+            &format!(
+                "/v1/plans/{}?{}",
+                crate::progenitor_support::encode_path(plan),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -197,18 +366,34 @@ impl Plans {
      *
      * **Parameters:**
      *
-     * * `plan: &str` -- The account's country.
+     * * `plan: &str`
+     */
+    pub async fn post_plans(
+        &self,
+        plan: &str,
+        body: &crate::types::PostPlansPlanRequest,
+    ) -> ClientResult<crate::types::PlanData> {
+        Ok(self.post_plans_with_response(plan, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/plans/{plan}` endpoint.
+     *
+     * As opposed to `post_plans`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_plans(&self, plan: &str) -> ClientResult<crate::types::PlanData> {
+    pub async fn post_plans_with_response(
+        &self,
+        plan: &str,
+        body: &crate::types::PostPlansPlanRequest,
+    ) -> ClientResult<crate::Response<crate::types::PlanData>> {
         let url = self.client.url(
             &format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan),),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -221,15 +406,26 @@ impl Plans {
      *
      * **Parameters:**
      *
-     * * `plan: &str` -- The account's country.
+     * * `plan: &str`
      */
     pub async fn delete(&self, plan: &str) -> ClientResult<crate::types::DeletedPlan> {
+        Ok(self.delete_with_response(plan).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/plans/{plan}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        plan: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedPlan>> {
         let url = self.client.url(
             &format!("/v1/plans/{}", crate::progenitor_support::encode_path(plan),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -22,9 +22,9 @@ impl Prices {
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `currency: &str` -- Only return prices for the given currency.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `lookup_keys: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `lookup_keys: &[String]`
      * * `product: &str` -- Only return prices for the given product.
      * * `recurring: &str` -- Only return prices with these recurring fields.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -36,6 +36,7 @@ impl Prices {
         _created: &str,
         currency: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _lookup_keys: &[String],
         product: &str,
@@ -43,6 +44,42 @@ impl Prices {
         starting_after: &str,
         type_: crate::types::PriceType,
     ) -> ClientResult<Vec<crate::types::PriceData>> {
+        Ok(self
+            .get_page_with_response(
+                active,
+                _created,
+                currency,
+                ending_before,
+                expand,
+                limit,
+                _lookup_keys,
+                product,
+                _recurring,
+                starting_after,
+                type_,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/prices` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        _lookup_keys: &[String],
+        product: &str,
+        _recurring: &str,
+        starting_after: &str,
+        type_: crate::types::PriceType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PriceData>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -53,6 +90,9 @@ impl Prices {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -67,9 +107,9 @@ impl Prices {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/prices?{}", query_), None);
-        let resp: crate::types::PriceList = self
+        let resp: crate::Response<crate::types::PriceList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,7 +119,7 @@ impl Prices {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/prices` endpoint.
@@ -93,6 +133,7 @@ impl Prices {
         active: bool,
         _created: &str,
         currency: &str,
+        expand: &[String],
         _lookup_keys: &[String],
         product: &str,
         _recurring: &str,
@@ -105,6 +146,9 @@ impl Prices {
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !product.is_empty() {
             query_args.push(("product".to_string(), product.to_string()));
         }
@@ -172,18 +216,155 @@ impl Prices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/prices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        expand: &[String],
+        _lookup_keys: &[String],
+        product: &str,
+        _recurring: &str,
+        type_: crate::types::PriceType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PriceData>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if !currency.is_empty() {
+            query_args.push(("currency".to_string(), currency.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !product.is_empty() {
+            query_args.push(("product".to_string(), product.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/prices?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PriceList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/prices` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        expand: &[String],
+        _lookup_keys: &[String],
+        product: &str,
+        _recurring: &str,
+        type_: crate::types::PriceType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PriceData>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            active,
+            _created,
+            currency,
+            expand,
+            _lookup_keys,
+            product,
+            _recurring,
+            type_,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/prices` endpoint.
      *
      * <p>Creates a new price for an existing product. The price can be recurring or one-time.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::PriceData> {
+    pub async fn post<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::PriceData> {
+        Ok(self.post_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/prices` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PriceData>> {
         let url = self.client.url("/v1/prices", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -199,18 +380,39 @@ impl Prices {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
      * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for prices](https://stripe.com/docs/search#query-fields-for-prices).
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        Ok(self
+            .get_search_with_response(expand, limit, page, query)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/prices/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_search_with_response(
+        &self,
+        expand: &[String],
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -224,9 +426,9 @@ impl Prices {
         let url = self
             .client
             .url(&format!("/v1/prices/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::Response<crate::types::SearchResult> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -236,7 +438,7 @@ impl Prices {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/prices/search` endpoint.
@@ -248,8 +450,15 @@ impl Prices {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -316,6 +525,99 @@ impl Prices {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/prices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_search_pages_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Charge>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/prices/search?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::SearchResult = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/prices/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_pages_stream(expand, query, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/prices/{price}` endpoint.
      *
@@ -323,19 +625,41 @@ impl Prices {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `price: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `price: &str`
      */
-    pub async fn get(&self, price: &str) -> ClientResult<crate::types::PriceData> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        price: &str,
+    ) -> ClientResult<crate::types::PriceData> {
+        Ok(self.get_with_response(expand, price).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/prices/{price}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        price: &str,
+    ) -> ClientResult<crate::Response<crate::types::PriceData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/prices/{}",
+                "/v1/prices/{}?{}",
                 crate::progenitor_support::encode_path(price),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -351,9 +675,25 @@ impl Prices {
      *
      * **Parameters:**
      *
-     * * `price: &str` -- The account's country.
+     * * `price: &str`
      */
-    pub async fn post_prices(&self, price: &str) -> ClientResult<crate::types::PriceData> {
+    pub async fn post_prices<B: serde::Serialize>(
+        &self,
+        price: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::PriceData> {
+        Ok(self.post_prices_with_response::<B>(price, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/prices/{price}` endpoint.
+     *
+     * As opposed to `post_prices`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_prices_with_response<B: serde::Serialize>(
+        &self,
+        price: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PriceData>> {
         let url = self.client.url(
             &format!(
                 "/v1/prices/{}",
@@ -362,10 +702,10 @@ impl Prices {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
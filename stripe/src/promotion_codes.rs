@@ -24,7 +24,7 @@ impl PromotionCodes {
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `customer: &str` -- Only return promotion codes that are restricted to this customer.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -36,9 +36,42 @@ impl PromotionCodes {
         _created: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::PromotionCode>> {
+        Ok(self
+            .get_page_with_response(
+                active,
+                code,
+                coupon,
+                _created,
+                customer,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/promotion_codes` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        active: bool,
+        code: &str,
+        coupon: &str,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::PromotionCode>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -55,6 +88,9 @@ impl PromotionCodes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -65,9 +101,9 @@ impl PromotionCodes {
         let url = self
             .client
             .url(&format!("/v1/promotion_codes?{}", query_), None);
-        let resp: crate::types::GetPromotionCodesResponse = self
+        let resp: crate::Response<crate::types::GetPromotionCodesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -77,7 +113,7 @@ impl PromotionCodes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/promotion_codes` endpoint.
@@ -93,6 +129,7 @@ impl PromotionCodes {
         coupon: &str,
         _created: &str,
         customer: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::PromotionCode>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
@@ -107,6 +144,9 @@ impl PromotionCodes {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
@@ -170,18 +210,145 @@ impl PromotionCodes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/promotion_codes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        active: bool,
+        code: &str,
+        coupon: &str,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::PromotionCode>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if !code.is_empty() {
+            query_args.push(("code".to_string(), code.to_string()));
+        }
+        if !coupon.is_empty() {
+            query_args.push(("coupon".to_string(), coupon.to_string()));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/promotion_codes?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetPromotionCodesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/promotion_codes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        code: &str,
+        coupon: &str,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::PromotionCode>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(active, code, coupon, _created, customer, expand, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/promotion_codes` endpoint.
      *
      * <p>A promotion code points to a coupon. You can optionally restrict the code to a specific customer, redemption limit, and expiration date.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::PromotionCode> {
+    pub async fn post<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::PromotionCode> {
+        Ok(self.post_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/promotion_codes` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PromotionCode>> {
         let url = self.client.url("/v1/promotion_codes", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -194,22 +361,44 @@ impl PromotionCodes {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `promotion_code: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `promotion_code: &str`
      */
     pub async fn get_code(
         &self,
+        expand: &[String],
         promotion_code: &str,
     ) -> ClientResult<crate::types::PromotionCode> {
+        Ok(self
+            .get_code_with_response(expand, promotion_code)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/promotion_codes/{promotion_code}` endpoint.
+     *
+     * As opposed to `get_code`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_code_with_response(
+        &self,
+        expand: &[String],
+        promotion_code: &str,
+    ) -> ClientResult<crate::Response<crate::types::PromotionCode>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/promotion_codes/{}",
+                "/v1/promotion_codes/{}?{}",
                 crate::progenitor_support::encode_path(promotion_code),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -225,12 +414,28 @@ impl PromotionCodes {
      *
      * **Parameters:**
      *
-     * * `promotion_code: &str` -- The account's country.
+     * * `promotion_code: &str`
      */
-    pub async fn post_code(
+    pub async fn post_code<B: serde::Serialize>(
         &self,
         promotion_code: &str,
+        body: &B,
     ) -> ClientResult<crate::types::PromotionCode> {
+        Ok(self
+            .post_code_with_response::<B>(promotion_code, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/promotion_codes/{promotion_code}` endpoint.
+     *
+     * As opposed to `post_code`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_code_with_response<B: serde::Serialize>(
+        &self,
+        promotion_code: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::PromotionCode>> {
         let url = self.client.url(
             &format!(
                 "/v1/promotion_codes/{}",
@@ -239,10 +444,10 @@ impl PromotionCodes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -20,7 +20,7 @@ impl Quotes {
      *
      * * `customer: &str` -- The ID of the customer whose quotes will be retrieved.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: crate::types::QuoteStatus` -- The status of the quote.
@@ -30,11 +30,40 @@ impl Quotes {
         &self,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::QuoteStatus,
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Quote>> {
+        Ok(self
+            .get_page_with_response(
+                customer,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                status,
+                test_clock,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::QuoteStatus,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Quote>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -42,6 +71,9 @@ impl Quotes {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -56,9 +88,9 @@ impl Quotes {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/quotes?{}", query_), None);
-        let resp: crate::types::GetQuotesResponse = self
+        let resp: crate::Response<crate::types::GetQuotesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -68,7 +100,7 @@ impl Quotes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/quotes` endpoint.
@@ -80,6 +112,7 @@ impl Quotes {
     pub async fn get_all(
         &self,
         customer: &str,
+        expand: &[String],
         status: crate::types::QuoteStatus,
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Quote>> {
@@ -87,6 +120,9 @@ impl Quotes {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -154,18 +190,132 @@ impl Quotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/quotes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        status: crate::types::QuoteStatus,
+        test_clock: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Quote>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !test_clock.is_empty() {
+            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/quotes?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetQuotesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        customer: &str,
+        expand: &[String],
+        status: crate::types::QuoteStatus,
+        test_clock: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Quote>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(customer, expand, status, test_clock, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/quotes` endpoint.
      *
      * <p>A quote models prices and services for a customer. Default options for <code>header</code>, <code>description</code>, <code>footer</code>, and <code>expires_at</code> can be set in the dashboard via the <a href="https://dashboard.stripe.com/settings/billing/quote">quote template</a>.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Quote> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> ClientResult<crate::types::Quote> {
+        Ok(self.post_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/quotes` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Quote>> {
         let url = self.client.url("/v1/quotes", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -178,19 +328,37 @@ impl Quotes {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `quote: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `quote: &str`
+     */
+    pub async fn get(&self, expand: &[String], quote: &str) -> ClientResult<crate::types::Quote> {
+        Ok(self.get_with_response(expand, quote).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, quote: &str) -> ClientResult<crate::types::Quote> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> ClientResult<crate::Response<crate::types::Quote>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}",
+                "/v1/quotes/{}?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -206,9 +374,25 @@ impl Quotes {
      *
      * **Parameters:**
      *
-     * * `quote: &str` -- The account's country.
+     * * `quote: &str`
+     */
+    pub async fn post_quotes<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Quote> {
+        Ok(self.post_quotes_with_response::<B>(quote, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/quotes/{quote}` endpoint.
+     *
+     * As opposed to `post_quotes`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_quotes(&self, quote: &str) -> ClientResult<crate::types::Quote> {
+    pub async fn post_quotes_with_response<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Quote>> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}",
@@ -217,10 +401,10 @@ impl Quotes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -233,9 +417,25 @@ impl Quotes {
      *
      * **Parameters:**
      *
-     * * `quote: &str` -- The account's country.
+     * * `quote: &str`
+     */
+    pub async fn post_accept<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Quote> {
+        Ok(self.post_accept_with_response::<B>(quote, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/quotes/{quote}/accept` endpoint.
+     *
+     * As opposed to `post_accept`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_accept(&self, quote: &str) -> ClientResult<crate::types::Quote> {
+    pub async fn post_accept_with_response<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Quote>> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}/accept",
@@ -244,10 +444,10 @@ impl Quotes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -260,9 +460,25 @@ impl Quotes {
      *
      * **Parameters:**
      *
-     * * `quote: &str` -- The account's country.
+     * * `quote: &str`
+     */
+    pub async fn post_cancel<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Quote> {
+        Ok(self.post_cancel_with_response::<B>(quote, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/quotes/{quote}/cancel` endpoint.
+     *
+     * As opposed to `post_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_cancel(&self, quote: &str) -> ClientResult<crate::types::Quote> {
+    pub async fn post_cancel_with_response<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Quote>> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}/cancel",
@@ -271,10 +487,10 @@ impl Quotes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -288,22 +504,50 @@ impl Quotes {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `quote: &str` -- The account's country.
+     * * `quote: &str`
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_computed_upfront_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         quote: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        Ok(self
+            .get_computed_upfront_line_items_with_response(
+                ending_before,
+                expand,
+                limit,
+                quote,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/computed_upfront_line_items` endpoint.
+     *
+     * As opposed to `get_computed_upfront_line_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_computed_upfront_line_items_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        quote: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -319,9 +563,9 @@ impl Quotes {
             ),
             None,
         );
-        let resp: crate::types::LineItems = self
+        let resp: crate::Response<crate::types::LineItems> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -331,7 +575,7 @@ impl Quotes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/quotes/{quote}/computed_upfront_line_items` endpoint.
@@ -342,12 +586,19 @@ impl Quotes {
      */
     pub async fn get_all_computed_upfront_line_items(
         &self,
+        expand: &[String],
         quote: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}/computed_upfront_line_items",
+                "/v1/quotes/{}/computed_upfront_line_items?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
@@ -410,6 +661,103 @@ impl Quotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/computed_upfront_line_items` endpoint.
+     *
+     * As opposed to `get_all_computed_upfront_line_items`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_computed_upfront_line_items_pages_stream(
+        &self,
+        expand: &[String],
+        quote: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Item>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/quotes/{}/computed_upfront_line_items?{}",
+                crate::progenitor_support::encode_path(quote),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::LineItems = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/computed_upfront_line_items` endpoint.
+     *
+     * As opposed to `get_all_computed_upfront_line_items`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_computed_upfront_line_items_stream(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_computed_upfront_line_items_pages_stream(expand, quote, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/quotes/{quote}/finalize` endpoint.
      *
@@ -417,9 +765,28 @@ impl Quotes {
      *
      * **Parameters:**
      *
-     * * `quote: &str` -- The account's country.
+     * * `quote: &str`
+     */
+    pub async fn post_finalize<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Quote> {
+        Ok(self
+            .post_finalize_with_response::<B>(quote, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/quotes/{quote}/finalize` endpoint.
+     *
+     * As opposed to `post_finalize`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_finalize(&self, quote: &str) -> ClientResult<crate::types::Quote> {
+    pub async fn post_finalize_with_response<B: serde::Serialize>(
+        &self,
+        quote: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Quote>> {
         let url = self.client.url(
             &format!(
                 "/v1/quotes/{}/finalize",
@@ -428,10 +795,10 @@ impl Quotes {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -445,22 +812,44 @@ impl Quotes {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `quote: &str` -- The account's country.
+     * * `quote: &str`
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_line_items(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         quote: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Item>> {
+        Ok(self
+            .get_line_items_with_response(ending_before, expand, limit, quote, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/line_items` endpoint.
+     *
+     * As opposed to `get_line_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_line_items_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        quote: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Item>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -476,9 +865,9 @@ impl Quotes {
             ),
             None,
         );
-        let resp: crate::types::LineItems = self
+        let resp: crate::Response<crate::types::LineItems> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -488,7 +877,7 @@ impl Quotes {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/quotes/{quote}/line_items` endpoint.
@@ -497,11 +886,21 @@ impl Quotes {
      *
      * <p>When retrieving a quote, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
      */
-    pub async fn get_all_line_items(&self, quote: &str) -> ClientResult<Vec<crate::types::Item>> {
+    pub async fn get_all_line_items(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> ClientResult<Vec<crate::types::Item>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}/line_items",
+                "/v1/quotes/{}/line_items?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
@@ -564,6 +963,101 @@ impl Quotes {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/line_items` endpoint.
+     *
+     * As opposed to `get_all_line_items`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_line_items_pages_stream(
+        &self,
+        expand: &[String],
+        quote: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Item>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/quotes/{}/line_items?{}",
+                crate::progenitor_support::encode_path(quote),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::LineItems = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/line_items` endpoint.
+     *
+     * As opposed to `get_all_line_items`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_line_items_stream(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Item>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_line_items_pages_stream(expand, quote, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/quotes/{quote}/pdf` endpoint.
      *
@@ -571,19 +1065,38 @@ impl Quotes {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `quote: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `quote: &str`
+     */
+    pub async fn get_pdf(&self, expand: &[String], quote: &str) -> ClientResult<()> {
+        self.get_pdf_with_response(expand, quote).await?;
+        Ok(())
+    }
+    /**
+     * This function performs a `GET` to the `/v1/quotes/{quote}/pdf` endpoint.
+     *
+     * As opposed to `get_pdf`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get_pdf(&self, quote: &str) -> ClientResult<()> {
+    pub async fn get_pdf_with_response(
+        &self,
+        expand: &[String],
+        quote: &str,
+    ) -> ClientResult<crate::Response<()>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/quotes/{}/pdf",
+                "/v1/quotes/{}/pdf?{}",
                 crate::progenitor_support::encode_path(quote),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
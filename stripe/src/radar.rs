@@ -20,7 +20,7 @@ impl Radar {
      *
      * * `charge: &str` -- Only return early fraud warnings for the charge specified by this charge ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `payment_intent: &str` -- Only return early fraud warnings for charges that were created by the PaymentIntent specified by this PaymentIntent ID.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -29,10 +29,37 @@ impl Radar {
         &self,
         charge: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::RadarEarlyFraudWarning>> {
+        Ok(self
+            .get_early_fraud_warnings_with_response(
+                charge,
+                ending_before,
+                expand,
+                limit,
+                payment_intent,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/early_fraud_warnings` endpoint.
+     *
+     * As opposed to `get_early_fraud_warnings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_early_fraud_warnings_with_response(
+        &self,
+        charge: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarEarlyFraudWarning>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
@@ -40,6 +67,9 @@ impl Radar {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +83,9 @@ impl Radar {
         let url = self
             .client
             .url(&format!("/v1/radar/early_fraud_warnings?{}", query_), None);
-        let resp: crate::types::RadarEarlyFraudWarningList = self
+        let resp: crate::Response<crate::types::RadarEarlyFraudWarningList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +95,7 @@ impl Radar {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/radar/early_fraud_warnings` endpoint.
@@ -77,12 +107,16 @@ impl Radar {
     pub async fn get_all_early_fraud_warnings(
         &self,
         charge: &str,
+        expand: &[String],
         payment_intent: &str,
     ) -> ClientResult<Vec<crate::types::RadarEarlyFraudWarning>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -149,6 +183,112 @@ impl Radar {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/radar/early_fraud_warnings` endpoint.
+     *
+     * As opposed to `get_all_early_fraud_warnings`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_early_fraud_warnings_pages_stream(
+        &self,
+        charge: &str,
+        expand: &[String],
+        payment_intent: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::RadarEarlyFraudWarning>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !charge.is_empty() {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !payment_intent.is_empty() {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/radar/early_fraud_warnings?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::RadarEarlyFraudWarningList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/early_fraud_warnings` endpoint.
+     *
+     * As opposed to `get_all_early_fraud_warnings`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_early_fraud_warnings_stream(
+        &self,
+        charge: &str,
+        expand: &[String],
+        payment_intent: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RadarEarlyFraudWarning>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_early_fraud_warnings_pages_stream(
+            charge,
+            expand,
+            payment_intent,
+            None,
+        ))
+    }
     /**
      * This function performs a `GET` to the `/v1/radar/early_fraud_warnings/{early_fraud_warning}` endpoint.
      *
@@ -158,22 +298,44 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `early_fraud_warning: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `early_fraud_warning: &str`
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
     pub async fn get_early_fraud_warnings_warning(
         &self,
         early_fraud_warning: &str,
+        expand: &[String],
     ) -> ClientResult<crate::types::RadarEarlyFraudWarning> {
+        Ok(self
+            .get_early_fraud_warnings_warning_with_response(early_fraud_warning, expand)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/early_fraud_warnings/{early_fraud_warning}` endpoint.
+     *
+     * As opposed to `get_early_fraud_warnings_warning`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_early_fraud_warnings_warning_with_response(
+        &self,
+        early_fraud_warning: &str,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<crate::types::RadarEarlyFraudWarning>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/radar/early_fraud_warnings/{}",
+                "/v1/radar/early_fraud_warnings/{}?{}",
                 crate::progenitor_support::encode_path(early_fraud_warning),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -191,7 +353,7 @@ impl Radar {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `value: &str` -- Return items belonging to the parent list whose value matches the specified value (using an "is like" match).
@@ -201,15 +363,47 @@ impl Radar {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         value: &str,
         value_list: &str,
     ) -> ClientResult<Vec<crate::types::RadarListItem>> {
+        Ok(self
+            .get_value_list_items_with_response(
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                value,
+                value_list,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_list_items` endpoint.
+     *
+     * As opposed to `get_value_list_items`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_value_list_items_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        value: &str,
+        value_list: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarListItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -226,9 +420,9 @@ impl Radar {
         let url = self
             .client
             .url(&format!("/v1/radar/value_list_items?{}", query_), None);
-        let resp: crate::types::ListItems = self
+        let resp: crate::Response<crate::types::ListItems> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -238,7 +432,7 @@ impl Radar {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/radar/value_list_items` endpoint.
@@ -250,10 +444,14 @@ impl Radar {
     pub async fn get_all_value_list_all_items(
         &self,
         _created: &str,
+        expand: &[String],
         value: &str,
         value_list: &str,
     ) -> ClientResult<Vec<crate::types::RadarListItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !value.is_empty() {
             query_args.push(("value".to_string(), value.to_string()));
         }
@@ -323,18 +521,137 @@ impl Radar {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_list_items` endpoint.
+     *
+     * As opposed to `get_all_value_list_all_items`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_value_list_all_items_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        value: &str,
+        value_list: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::RadarListItem>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !value.is_empty() {
+            query_args.push(("value".to_string(), value.to_string()));
+        }
+        if !value_list.is_empty() {
+            query_args.push(("value_list".to_string(), value_list.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/radar/value_list_items?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ListItems = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_list_items` endpoint.
+     *
+     * As opposed to `get_all_value_list_all_items`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_value_list_all_items_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        value: &str,
+        value_list: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RadarListItem>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_value_list_all_items_pages_stream(
+                _created, expand, value, value_list, None,
+            ),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/radar/value_list_items` endpoint.
      *
      * <p>Creates a new <code>ValueListItem</code> object, which is added to the specified parent value list.</p>
      */
-    pub async fn post_value_list_item(&self) -> ClientResult<crate::types::RadarListItem> {
+    pub async fn post_value_list_item(
+        &self,
+        body: &crate::types::PostRadarValueListItemsRequest,
+    ) -> ClientResult<crate::types::RadarListItem> {
+        Ok(self.post_value_list_item_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/radar/value_list_items` endpoint.
+     *
+     * As opposed to `post_value_list_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_value_list_item_with_response(
+        &self,
+        body: &crate::types::PostRadarValueListItemsRequest,
+    ) -> ClientResult<crate::Response<crate::types::RadarListItem>> {
         let url = self.client.url("/v1/radar/value_list_items", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -347,22 +664,44 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `item: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `item: &str`
      */
     pub async fn get_value_list_items_item(
         &self,
+        expand: &[String],
         item: &str,
     ) -> ClientResult<crate::types::RadarListItem> {
+        Ok(self
+            .get_value_list_items_item_with_response(expand, item)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_list_items/{item}` endpoint.
+     *
+     * As opposed to `get_value_list_items_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_value_list_items_item_with_response(
+        &self,
+        expand: &[String],
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarListItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/radar/value_list_items/{}",
+                "/v1/radar/value_list_items/{}?{}",
                 crate::progenitor_support::encode_path(item),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -378,12 +717,26 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `item: &str` -- The account's country.
+     * * `item: &str`
      */
     pub async fn delete_value_list_items_item(
         &self,
         item: &str,
     ) -> ClientResult<crate::types::RadarListDeletedItem> {
+        Ok(self
+            .delete_value_list_items_item_with_response(item)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/radar/value_list_items/{item}` endpoint.
+     *
+     * As opposed to `delete_value_list_items_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_value_list_items_item_with_response(
+        &self,
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarListDeletedItem>> {
         let url = self.client.url(
             &format!(
                 "/v1/radar/value_list_items/{}",
@@ -392,7 +745,7 @@ impl Radar {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -412,7 +765,7 @@ impl Radar {
      * * `contains: &str` -- A value contained within a value list - returns all value lists containing this value.
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -422,9 +775,38 @@ impl Radar {
         contains: &str,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::RadarList>> {
+        Ok(self
+            .get_value_lists_with_response(
+                alias,
+                contains,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_lists` endpoint.
+     *
+     * As opposed to `get_value_lists`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_value_lists_with_response(
+        &self,
+        alias: &str,
+        contains: &str,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::RadarList>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !alias.is_empty() {
             query_args.push(("alias".to_string(), alias.to_string()));
@@ -435,6 +817,9 @@ impl Radar {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -445,9 +830,9 @@ impl Radar {
         let url = self
             .client
             .url(&format!("/v1/radar/value_lists?{}", query_), None);
-        let resp: crate::types::GetRadarValueListsResponse = self
+        let resp: crate::Response<crate::types::GetRadarValueListsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -457,7 +842,7 @@ impl Radar {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/radar/value_lists` endpoint.
@@ -471,6 +856,7 @@ impl Radar {
         alias: &str,
         contains: &str,
         _created: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::RadarList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !alias.is_empty() {
@@ -479,6 +865,9 @@ impl Radar {
         if !contains.is_empty() {
             query_args.push(("contains".to_string(), contains.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
@@ -542,18 +931,135 @@ impl Radar {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_lists` endpoint.
+     *
+     * As opposed to `get_all_value_lists`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_value_lists_pages_stream(
+        &self,
+        alias: &str,
+        contains: &str,
+        _created: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::RadarList>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !alias.is_empty() {
+            query_args.push(("alias".to_string(), alias.to_string()));
+        }
+        if !contains.is_empty() {
+            query_args.push(("contains".to_string(), contains.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/radar/value_lists?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetRadarValueListsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_lists` endpoint.
+     *
+     * As opposed to `get_all_value_lists`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_value_lists_stream(
+        &self,
+        alias: &str,
+        contains: &str,
+        _created: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::RadarList>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_value_lists_pages_stream(alias, contains, _created, expand, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/radar/value_lists` endpoint.
      *
      * <p>Creates a new <code>ValueList</code> object, which can then be referenced in rules.</p>
      */
-    pub async fn post_value_list(&self) -> ClientResult<crate::types::RadarList> {
+    pub async fn post_value_list(
+        &self,
+        body: &crate::types::PostRadarValueListsRequest,
+    ) -> ClientResult<crate::types::RadarList> {
+        Ok(self.post_value_list_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/radar/value_lists` endpoint.
+     *
+     * As opposed to `post_value_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_value_list_with_response(
+        &self,
+        body: &crate::types::PostRadarValueListsRequest,
+    ) -> ClientResult<crate::Response<crate::types::RadarList>> {
         let url = self.client.url("/v1/radar/value_lists", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -566,22 +1072,44 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `value_list: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `value_list: &str`
      */
     pub async fn get_value_lists_list(
         &self,
+        expand: &[String],
         value_list: &str,
     ) -> ClientResult<crate::types::RadarList> {
+        Ok(self
+            .get_value_lists_list_with_response(expand, value_list)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_lists/{value_list}` endpoint.
+     *
+     * As opposed to `get_value_lists_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_value_lists_list_with_response(
+        &self,
+        expand: &[String],
+        value_list: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarList>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/radar/value_lists/{}",
+                "/v1/radar/value_lists/{}?{}",
                 crate::progenitor_support::encode_path(value_list),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -597,12 +1125,28 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `value_list: &str` -- The account's country.
+     * * `value_list: &str`
      */
     pub async fn post_value_lists_list(
         &self,
         value_list: &str,
+        body: &crate::types::PostRadarValueListsListRequest,
     ) -> ClientResult<crate::types::RadarList> {
+        Ok(self
+            .post_value_lists_list_with_response(value_list, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/radar/value_lists/{value_list}` endpoint.
+     *
+     * As opposed to `post_value_lists_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_value_lists_list_with_response(
+        &self,
+        value_list: &str,
+        body: &crate::types::PostRadarValueListsListRequest,
+    ) -> ClientResult<crate::Response<crate::types::RadarList>> {
         let url = self.client.url(
             &format!(
                 "/v1/radar/value_lists/{}",
@@ -611,10 +1155,10 @@ impl Radar {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -627,12 +1171,26 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `value_list: &str` -- The account's country.
+     * * `value_list: &str`
      */
     pub async fn delete_value_lists_list(
         &self,
         value_list: &str,
     ) -> ClientResult<crate::types::RadarListDeleted> {
+        Ok(self
+            .delete_value_lists_list_with_response(value_list)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/radar/value_lists/{value_list}` endpoint.
+     *
+     * As opposed to `delete_value_lists_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_value_lists_list_with_response(
+        &self,
+        value_list: &str,
+    ) -> ClientResult<crate::Response<crate::types::RadarListDeleted>> {
         let url = self.client.url(
             &format!(
                 "/v1/radar/value_lists/{}",
@@ -641,7 +1199,7 @@ impl Radar {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -20,7 +20,7 @@ impl Recipients {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `type_: crate::types::GetRecipientsType`
@@ -30,15 +30,47 @@ impl Recipients {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         type_: crate::types::GetRecipientsType,
         verified: bool,
     ) -> ClientResult<Vec<crate::types::Recipient>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                type_,
+                verified,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/recipients` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GetRecipientsType,
+        verified: bool,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Recipient>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +85,9 @@ impl Recipients {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/recipients?{}", query_), None);
-        let resp: crate::types::GetRecipientsResponse = self
+        let resp: crate::Response<crate::types::GetRecipientsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl Recipients {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/recipients` endpoint.
@@ -77,10 +109,14 @@ impl Recipients {
     pub async fn get_all(
         &self,
         _created: &str,
+        expand: &[String],
         type_: crate::types::GetRecipientsType,
         verified: bool,
     ) -> ClientResult<Vec<crate::types::Recipient>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
         }
@@ -148,19 +184,132 @@ impl Recipients {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/recipients` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        type_: crate::types::GetRecipientsType,
+        verified: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Recipient>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if verified {
+            query_args.push(("verified".to_string(), verified.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/recipients?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetRecipientsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/recipients` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        type_: crate::types::GetRecipientsType,
+        verified: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Recipient>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(_created, expand, type_, verified, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/recipients` endpoint.
      *
      * <p>Creates a new <code>Recipient</code> object and verifies the recipient’s identity.
      * Also verifies the recipient’s bank account information or debit card, if either is provided.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Recipient> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostRecipientsRequest,
+    ) -> ClientResult<crate::types::Recipient> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/recipients` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostRecipientsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Recipient>> {
         let url = self.client.url("/v1/recipients", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -173,19 +322,41 @@ impl Recipients {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     */
+    pub async fn get(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::GetRecipientsResponseAnyOf> {
+        Ok(self.get_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/recipients/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::GetRecipientsResponseAnyOf> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetRecipientsResponseAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/recipients/{}",
+                "/v1/recipients/{}?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -205,9 +376,25 @@ impl Recipients {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
-    pub async fn post_recipients(&self, id: &str) -> ClientResult<crate::types::Recipient> {
+    pub async fn post_recipients(
+        &self,
+        id: &str,
+        body: &crate::types::PostRecipientsRequest,
+    ) -> ClientResult<crate::types::Recipient> {
+        Ok(self.post_recipients_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/recipients/{id}` endpoint.
+     *
+     * As opposed to `post_recipients`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_recipients_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostRecipientsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Recipient>> {
         let url = self.client.url(
             &format!(
                 "/v1/recipients/{}",
@@ -216,10 +403,10 @@ impl Recipients {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -232,9 +419,20 @@ impl Recipients {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
     pub async fn delete(&self, id: &str) -> ClientResult<crate::types::DeletedRecipient> {
+        Ok(self.delete_with_response(id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/recipients/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedRecipient>> {
         let url = self.client.url(
             &format!(
                 "/v1/recipients/{}",
@@ -243,7 +441,7 @@ impl Recipients {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
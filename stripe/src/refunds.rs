@@ -21,7 +21,7 @@ impl Refunds {
      * * `charge: &str` -- Only return refunds for the charge specified by this charge ID.
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `payment_intent: &str` -- Only return refunds for the PaymentIntent specified by this ID.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -31,10 +31,39 @@ impl Refunds {
         charge: &str,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_intent: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Refund>> {
+        Ok(self
+            .get_page_with_response(
+                charge,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                payment_intent,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/refunds` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        charge: &str,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Refund>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
@@ -42,6 +71,9 @@ impl Refunds {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +85,9 @@ impl Refunds {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/refunds?{}", query_), None);
-        let resp: crate::types::RefundList = self
+        let resp: crate::Response<crate::types::RefundList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl Refunds {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/refunds` endpoint.
@@ -78,12 +110,16 @@ impl Refunds {
         &self,
         charge: &str,
         _created: &str,
+        expand: &[String],
         payment_intent: &str,
     ) -> ClientResult<Vec<crate::types::Refund>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !charge.is_empty() {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !payment_intent.is_empty() {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
@@ -148,18 +184,136 @@ impl Refunds {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/refunds` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        expand: &[String],
+        payment_intent: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Refund>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !charge.is_empty() {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !payment_intent.is_empty() {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/refunds?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::RefundList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/refunds` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        charge: &str,
+        _created: &str,
+        expand: &[String],
+        payment_intent: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Refund>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            charge,
+            _created,
+            expand,
+            payment_intent,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/refunds` endpoint.
      *
      * <p>Create a refund.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Refund> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostRefundsRequest,
+    ) -> ClientResult<crate::types::Refund> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/refunds` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostRefundsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
         let url = self.client.url("/v1/refunds", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -172,19 +326,37 @@ impl Refunds {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `refund: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `refund: &str`
      */
-    pub async fn get(&self, refund: &str) -> ClientResult<crate::types::Refund> {
+    pub async fn get(&self, expand: &[String], refund: &str) -> ClientResult<crate::types::Refund> {
+        Ok(self.get_with_response(expand, refund).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/refunds/{refund}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        refund: &str,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/refunds/{}",
+                "/v1/refunds/{}?{}",
                 crate::progenitor_support::encode_path(refund),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -202,9 +374,25 @@ impl Refunds {
      *
      * **Parameters:**
      *
-     * * `refund: &str` -- The account's country.
+     * * `refund: &str`
+     */
+    pub async fn post_refunds(
+        &self,
+        refund: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> ClientResult<crate::types::Refund> {
+        Ok(self.post_refunds_with_response(refund, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/refunds/{refund}` endpoint.
+     *
+     * As opposed to `post_refunds`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_refunds(&self, refund: &str) -> ClientResult<crate::types::Refund> {
+    pub async fn post_refunds_with_response(
+        &self,
+        refund: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
         let url = self.client.url(
             &format!(
                 "/v1/refunds/{}",
@@ -213,10 +401,10 @@ impl Refunds {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -231,9 +419,28 @@ impl Refunds {
      *
      * **Parameters:**
      *
-     * * `refund: &str` -- The account's country.
+     * * `refund: &str`
+     */
+    pub async fn post_cancel<B: serde::Serialize>(
+        &self,
+        refund: &str,
+        body: &B,
+    ) -> ClientResult<crate::types::Refund> {
+        Ok(self
+            .post_cancel_with_response::<B>(refund, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/refunds/{refund}/cancel` endpoint.
+     *
+     * As opposed to `post_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_cancel(&self, refund: &str) -> ClientResult<crate::types::Refund> {
+    pub async fn post_cancel_with_response<B: serde::Serialize>(
+        &self,
+        refund: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::Refund>> {
         let url = self.client.url(
             &format!(
                 "/v1/refunds/{}/cancel",
@@ -242,10 +449,10 @@ impl Refunds {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
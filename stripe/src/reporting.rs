@@ -20,7 +20,7 @@ impl Reporting {
      *
      * * `created: &str`
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -28,13 +28,35 @@ impl Reporting {
         &self,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::ReportingReportRun>> {
+        Ok(self
+            .get_report_runs_with_response(_created, ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_runs` endpoint.
+     *
+     * As opposed to `get_report_runs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_report_runs_with_response(
+        &self,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReportingReportRun>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -45,9 +67,9 @@ impl Reporting {
         let url = self
             .client
             .url(&format!("/v1/reporting/report_runs?{}", query_), None);
-        let resp: crate::types::GetReportingReportRunsResponse = self
+        let resp: crate::Response<crate::types::GetReportingReportRunsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,7 +79,7 @@ impl Reporting {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/reporting/report_runs` endpoint.
@@ -69,8 +91,16 @@ impl Reporting {
     pub async fn get_all_report_runs(
         &self,
         _created: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::ReportingReportRun>> {
-        let url = self.client.url("/v1/reporting/report_runs", None);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/reporting/report_runs?{}", query_), None);
         let mut resp: crate::types::GetReportingReportRunsResponse = self
             .client
             .get(
@@ -130,18 +160,125 @@ impl Reporting {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_runs` endpoint.
+     *
+     * As opposed to `get_all_report_runs`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_report_runs_pages_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ReportingReportRun>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/reporting/report_runs?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetReportingReportRunsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_runs` endpoint.
+     *
+     * As opposed to `get_all_report_runs`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_report_runs_stream(
+        &self,
+        _created: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReportingReportRun>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_report_runs_pages_stream(_created, expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/reporting/report_runs` endpoint.
      *
      * <p>Creates a new object and begin running the report. (Certain report types require a <a href="https://stripe.com/docs/keys#test-live-modes">live-mode API key</a>.)</p>
      */
-    pub async fn post_report_run(&self) -> ClientResult<crate::types::ReportingReportRun> {
+    pub async fn post_report_run(
+        &self,
+        body: &crate::types::PostReportingReportRunsRequest,
+    ) -> ClientResult<crate::types::ReportingReportRun> {
+        Ok(self.post_report_run_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/reporting/report_runs` endpoint.
+     *
+     * As opposed to `post_report_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_report_run_with_response(
+        &self,
+        body: &crate::types::PostReportingReportRunsRequest,
+    ) -> ClientResult<crate::Response<crate::types::ReportingReportRun>> {
         let url = self.client.url("/v1/reporting/report_runs", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -154,22 +291,44 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `report_run: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `report_run: &str`
      */
     pub async fn get_report_runs_run(
         &self,
+        expand: &[String],
         report_run: &str,
     ) -> ClientResult<crate::types::ReportingReportRun> {
+        Ok(self
+            .get_report_runs_run_with_response(expand, report_run)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_runs/{report_run}` endpoint.
+     *
+     * As opposed to `get_report_runs_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_report_runs_run_with_response(
+        &self,
+        expand: &[String],
+        report_run: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportingReportRun>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/reporting/report_runs/{}",
+                "/v1/reporting/report_runs/{}?{}",
                 crate::progenitor_support::encode_path(report_run),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -185,13 +344,34 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      */
-    pub async fn get_report_types(&self) -> ClientResult<Vec<crate::types::ReportingReportType>> {
-        let url = self.client.url("/v1/reporting/report_types", None);
-        let resp: crate::types::FinancialReportingFinanceReportTypeList = self
+    pub async fn get_report_types(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::ReportingReportType>> {
+        Ok(self.get_report_types_with_response(expand).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_types` endpoint.
+     *
+     * As opposed to `get_report_types`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_report_types_with_response(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<crate::Response<Vec<crate::types::ReportingReportType>>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
             .client
-            .get(
+            .url(&format!("/v1/reporting/report_types?{}", query_), None);
+        let resp: crate::Response<crate::types::FinancialReportingFinanceReportTypeList> = self
+            .client
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -201,7 +381,7 @@ impl Reporting {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/reporting/report_types` endpoint.
@@ -212,8 +392,16 @@ impl Reporting {
      */
     pub async fn get_all_report_types(
         &self,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::ReportingReportType>> {
-        let url = self.client.url("/v1/reporting/report_types", None);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/reporting/report_types?{}", query_), None);
         let mut resp: crate::types::FinancialReportingFinanceReportTypeList = self
             .client
             .get(
@@ -273,6 +461,97 @@ impl Reporting {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_types` endpoint.
+     *
+     * As opposed to `get_all_report_types`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_report_types_pages_stream(
+        &self,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ReportingReportType>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/reporting/report_types?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::FinancialReportingFinanceReportTypeList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_types` endpoint.
+     *
+     * As opposed to `get_all_report_types`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_report_types_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ReportingReportType>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_report_types_pages_stream(expand, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/reporting/report_types/{report_type}` endpoint.
      *
@@ -280,22 +559,44 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `report_type: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `report_type: &str`
      */
     pub async fn get_report_types_type(
         &self,
+        expand: &[String],
         report_type: &str,
     ) -> ClientResult<crate::types::ReportingReportType> {
+        Ok(self
+            .get_report_types_type_with_response(expand, report_type)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_types/{report_type}` endpoint.
+     *
+     * As opposed to `get_report_types_type`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_report_types_type_with_response(
+        &self,
+        expand: &[String],
+        report_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportingReportType>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/reporting/report_types/{}",
+                "/v1/reporting/report_types/{}?{}",
                 crate::progenitor_support::encode_path(report_type),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -21,7 +21,7 @@ impl SetupIntents {
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `customer: &str` -- Only return SetupIntents for the customer specified by this customer ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `payment_method: &str` -- Only return SetupIntents associated with the specified payment method.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -31,10 +31,39 @@ impl SetupIntents {
         _created: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         payment_method: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::SetupIntent>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                customer,
+                ending_before,
+                expand,
+                limit,
+                payment_method,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/setup_intents` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        payment_method: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SetupIntent>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -42,6 +71,9 @@ impl SetupIntents {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -55,9 +87,9 @@ impl SetupIntents {
         let url = self
             .client
             .url(&format!("/v1/setup_intents?{}", query_), None);
-        let resp: crate::types::PaymentFlowsSetupIntentList = self
+        let resp: crate::Response<crate::types::PaymentFlowsSetupIntentList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -67,7 +99,7 @@ impl SetupIntents {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/setup_intents` endpoint.
@@ -80,12 +112,16 @@ impl SetupIntents {
         &self,
         _created: &str,
         customer: &str,
+        expand: &[String],
         payment_method: &str,
     ) -> ClientResult<Vec<crate::types::SetupIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !payment_method.is_empty() {
             query_args.push(("payment_method".to_string(), payment_method.to_string()));
         }
@@ -152,6 +188,113 @@ impl SetupIntents {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/setup_intents` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        payment_method: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SetupIntent>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !payment_method.is_empty() {
+            query_args.push(("payment_method".to_string(), payment_method.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/setup_intents?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::PaymentFlowsSetupIntentList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/setup_intents` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        payment_method: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SetupIntent>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            _created,
+            customer,
+            expand,
+            payment_method,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/setup_intents` endpoint.
      *
@@ -160,13 +303,27 @@ impl SetupIntents {
      * <p>After the SetupIntent is created, attach a payment method and <a href="/docs/api/setup_intents/confirm">confirm</a>
      * to collect any required permissions to charge the payment method later.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::SetupIntent> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSetupIntentsRequest,
+    ) -> ClientResult<crate::types::SetupIntent> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/setup_intents` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostSetupIntentsRequest,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
         let url = self.client.url("/v1/setup_intents", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -184,18 +341,38 @@ impl SetupIntents {
      * **Parameters:**
      *
      * * `client_secret: &str` -- The client secret of the SetupIntent. Required if a publishable key is used to retrieve the SetupIntent.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `intent: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `intent: &str`
      */
     pub async fn get_intent(
         &self,
         client_secret: &str,
+        expand: &[String],
         intent: &str,
     ) -> ClientResult<crate::types::SetupIntent> {
+        Ok(self
+            .get_intent_with_response(client_secret, expand, intent)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/setup_intents/{intent}` endpoint.
+     *
+     * As opposed to `get_intent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_intent_with_response(
+        &self,
+        client_secret: &str,
+        expand: &[String],
+        intent: &str,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_secret.is_empty() {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -206,7 +383,7 @@ impl SetupIntents {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -222,9 +399,25 @@ impl SetupIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
-    pub async fn post_intent(&self, intent: &str) -> ClientResult<crate::types::SetupIntent> {
+    pub async fn post_intent(
+        &self,
+        intent: &str,
+        body: &crate::types::PostSetupIntentsIntentRequest,
+    ) -> ClientResult<crate::types::SetupIntent> {
+        Ok(self.post_intent_with_response(intent, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/setup_intents/{intent}` endpoint.
+     *
+     * As opposed to `post_intent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_with_response(
+        &self,
+        intent: &str,
+        body: &crate::types::PostSetupIntentsIntentRequest,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/setup_intents/{}",
@@ -233,10 +426,10 @@ impl SetupIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -251,12 +444,28 @@ impl SetupIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
     pub async fn post_intent_cancel(
         &self,
         intent: &str,
+        body: &crate::types::PostSetupIntentsIntentCancelRequest,
     ) -> ClientResult<crate::types::SetupIntent> {
+        Ok(self
+            .post_intent_cancel_with_response(intent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/setup_intents/{intent}/cancel` endpoint.
+     *
+     * As opposed to `post_intent_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_cancel_with_response(
+        &self,
+        intent: &str,
+        body: &crate::types::PostSetupIntentsIntentCancelRequest,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/setup_intents/{}/cancel",
@@ -265,10 +474,10 @@ impl SetupIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -293,12 +502,28 @@ impl SetupIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
     pub async fn post_intent_confirm(
         &self,
         intent: &str,
+        body: &crate::types::PostSetupIntentsIntentConfirmRequest,
     ) -> ClientResult<crate::types::SetupIntent> {
+        Ok(self
+            .post_intent_confirm_with_response(intent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/setup_intents/{intent}/confirm` endpoint.
+     *
+     * As opposed to `post_intent_confirm`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_confirm_with_response(
+        &self,
+        intent: &str,
+        body: &crate::types::PostSetupIntentsIntentConfirmRequest,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/setup_intents/{}/confirm",
@@ -307,10 +532,10 @@ impl SetupIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -323,12 +548,28 @@ impl SetupIntents {
      *
      * **Parameters:**
      *
-     * * `intent: &str` -- The account's country.
+     * * `intent: &str`
      */
-    pub async fn post_intent_verify_microdeposit(
+    pub async fn post_intent_verify_microdeposit<B: serde::Serialize>(
         &self,
         intent: &str,
+        body: &B,
     ) -> ClientResult<crate::types::SetupIntent> {
+        Ok(self
+            .post_intent_verify_microdeposit_with_response::<B>(intent, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/setup_intents/{intent}/verify_microdeposits` endpoint.
+     *
+     * As opposed to `post_intent_verify_microdeposit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_intent_verify_microdeposit_with_response<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::SetupIntent>> {
         let url = self.client.url(
             &format!(
                 "/v1/setup_intents/{}/verify_microdeposits",
@@ -337,10 +578,10 @@ impl SetupIntents {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
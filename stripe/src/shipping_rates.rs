@@ -22,7 +22,7 @@ impl ShippingRates {
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `currency: &str` -- Only return shipping rates for the given currency.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
@@ -32,9 +32,38 @@ impl ShippingRates {
         _created: &str,
         currency: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::ShippingRate>> {
+        Ok(self
+            .get_page_with_response(
+                active,
+                _created,
+                currency,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/shipping_rates` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ShippingRate>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -45,6 +74,9 @@ impl ShippingRates {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -55,9 +87,9 @@ impl ShippingRates {
         let url = self
             .client
             .url(&format!("/v1/shipping_rates?{}", query_), None);
-        let resp: crate::types::ShippingResourcesRateList = self
+        let resp: crate::Response<crate::types::ShippingResourcesRateList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -67,7 +99,7 @@ impl ShippingRates {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/shipping_rates` endpoint.
@@ -81,6 +113,7 @@ impl ShippingRates {
         active: bool,
         _created: &str,
         currency: &str,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::ShippingRate>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
@@ -89,6 +122,9 @@ impl ShippingRates {
         if !currency.is_empty() {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self
             .client
@@ -152,18 +188,135 @@ impl ShippingRates {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/shipping_rates` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ShippingRate>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if !currency.is_empty() {
+            query_args.push(("currency".to_string(), currency.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/shipping_rates?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ShippingResourcesRateList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/shipping_rates` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        currency: &str,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ShippingRate>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(active, _created, currency, expand, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/shipping_rates` endpoint.
      *
      * <p>Creates a new shipping rate object.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::ShippingRate> {
+    pub async fn post<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::ShippingRate> {
+        Ok(self.post_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/shipping_rates` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::ShippingRate>> {
         let url = self.client.url("/v1/shipping_rates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -176,22 +329,44 @@ impl ShippingRates {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `shipping_rate_token: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `shipping_rate_token: &str`
      */
     pub async fn get_rate_token(
         &self,
+        expand: &[String],
         shipping_rate_token: &str,
     ) -> ClientResult<crate::types::ShippingRate> {
+        Ok(self
+            .get_rate_token_with_response(expand, shipping_rate_token)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/shipping_rates/{shipping_rate_token}` endpoint.
+     *
+     * As opposed to `get_rate_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_rate_token_with_response(
+        &self,
+        expand: &[String],
+        shipping_rate_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ShippingRate>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/shipping_rates/{}",
+                "/v1/shipping_rates/{}?{}",
                 crate::progenitor_support::encode_path(shipping_rate_token),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -207,12 +382,28 @@ impl ShippingRates {
      *
      * **Parameters:**
      *
-     * * `shipping_rate_token: &str` -- The account's country.
+     * * `shipping_rate_token: &str`
      */
-    pub async fn post_rate_token(
+    pub async fn post_rate_token<B: serde::Serialize>(
         &self,
         shipping_rate_token: &str,
+        body: &B,
     ) -> ClientResult<crate::types::ShippingRate> {
+        Ok(self
+            .post_rate_token_with_response::<B>(shipping_rate_token, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/shipping_rates/{shipping_rate_token}` endpoint.
+     *
+     * As opposed to `post_rate_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_rate_token_with_response<B: serde::Serialize>(
+        &self,
+        shipping_rate_token: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::ShippingRate>> {
         let url = self.client.url(
             &format!(
                 "/v1/shipping_rates/{}",
@@ -221,10 +412,10 @@ impl ShippingRates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -19,20 +19,41 @@ impl Sigma {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_scheduled_query_runs(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::ScheduledQueryRun>> {
+        Ok(self
+            .get_scheduled_query_runs_with_response(ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs` endpoint.
+     *
+     * As opposed to `get_scheduled_query_runs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_scheduled_query_runs_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ScheduledQueryRun>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -43,9 +64,9 @@ impl Sigma {
         let url = self
             .client
             .url(&format!("/v1/sigma/scheduled_query_runs?{}", query_), None);
-        let resp: crate::types::GetSigmaScheduledQueryRunsResponse = self
+        let resp: crate::Response<crate::types::GetSigmaScheduledQueryRunsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,7 +76,7 @@ impl Sigma {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs` endpoint.
@@ -66,8 +87,16 @@ impl Sigma {
      */
     pub async fn get_all_scheduled_query_runs(
         &self,
+        expand: &[String],
     ) -> ClientResult<Vec<crate::types::ScheduledQueryRun>> {
-        let url = self.client.url("/v1/sigma/scheduled_query_runs", None);
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/sigma/scheduled_query_runs?{}", query_), None);
         let mut resp: crate::types::GetSigmaScheduledQueryRunsResponse = self
             .client
             .get(
@@ -127,6 +156,96 @@ impl Sigma {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs` endpoint.
+     *
+     * As opposed to `get_all_scheduled_query_runs`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_scheduled_query_runs_pages_stream(
+        &self,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ScheduledQueryRun>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/sigma/scheduled_query_runs?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetSigmaScheduledQueryRunsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs` endpoint.
+     *
+     * As opposed to `get_all_scheduled_query_runs`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_scheduled_query_runs_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ScheduledQueryRun>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_scheduled_query_runs_pages_stream(expand, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs/{scheduled_query_run}` endpoint.
      *
@@ -134,22 +253,44 @@ impl Sigma {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `scheduled_query_run: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `scheduled_query_run: &str`
      */
     pub async fn get_scheduled_query_runs_run(
         &self,
+        expand: &[String],
         scheduled_query_run: &str,
     ) -> ClientResult<crate::types::ScheduledQueryRun> {
+        Ok(self
+            .get_scheduled_query_runs_run_with_response(expand, scheduled_query_run)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs/{scheduled_query_run}` endpoint.
+     *
+     * As opposed to `get_scheduled_query_runs_run`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_scheduled_query_runs_run_with_response(
+        &self,
+        expand: &[String],
+        scheduled_query_run: &str,
+    ) -> ClientResult<crate::Response<crate::types::ScheduledQueryRun>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sigma/scheduled_query_runs/{}",
+                "/v1/sigma/scheduled_query_runs/{}?{}",
                 crate::progenitor_support::encode_path(scheduled_query_run),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -21,8 +21,8 @@ impl Skus {
      * * `active: bool` -- Only return SKUs that are active or inactive (e.g., pass `false` to list all inactive products).
      * * `attributes: &str` -- Only return SKUs that have the specified key-value pairs in this partially constructed dictionary. Can be specified only if `product` is also supplied. For instance, if the associated product has attributes `["color", "size"]`, passing in `attributes[color]=red` returns all the SKUs for this product that have `color` set to `red`.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `ids: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `ids: &[String]` -- Only return SKUs with the given IDs.
      * * `in_stock: bool` -- Only return SKUs that are either in stock or out of stock (e.g., pass `false` to list all SKUs that are out of stock). If no value is provided, all SKUs are returned.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `product: &str` -- The ID of the product whose SKUs will be retrieved. Must be a product with type `good`.
@@ -33,12 +33,45 @@ impl Skus {
         active: bool,
         _attributes: &str,
         ending_before: &str,
+        expand: &[String],
         _ids: &[String],
         in_stock: bool,
         limit: i64,
         product: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::Sku>> {
+        Ok(self
+            .get_page_with_response(
+                active,
+                _attributes,
+                ending_before,
+                expand,
+                _ids,
+                in_stock,
+                limit,
+                product,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/skus` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        active: bool,
+        _attributes: &str,
+        ending_before: &str,
+        expand: &[String],
+        _ids: &[String],
+        in_stock: bool,
+        limit: i64,
+        product: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Sku>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -46,6 +79,9 @@ impl Skus {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if in_stock {
             query_args.push(("in_stock".to_string(), in_stock.to_string()));
         }
@@ -60,9 +96,9 @@ impl Skus {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/skus?{}", query_), None);
-        let resp: crate::types::GetSkusResponse = self
+        let resp: crate::Response<crate::types::GetSkusResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -72,7 +108,7 @@ impl Skus {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/skus` endpoint.
@@ -85,6 +121,7 @@ impl Skus {
         &self,
         active: bool,
         _attributes: &str,
+        expand: &[String],
         _ids: &[String],
         in_stock: bool,
         product: &str,
@@ -93,6 +130,9 @@ impl Skus {
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if in_stock {
             query_args.push(("in_stock".to_string(), in_stock.to_string()));
         }
@@ -160,18 +200,145 @@ impl Skus {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/skus` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        active: bool,
+        _attributes: &str,
+        expand: &[String],
+        _ids: &[String],
+        in_stock: bool,
+        product: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Sku>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if in_stock {
+            query_args.push(("in_stock".to_string(), in_stock.to_string()));
+        }
+        if !product.is_empty() {
+            query_args.push(("product".to_string(), product.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/skus?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetSkusResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/skus` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _attributes: &str,
+        expand: &[String],
+        _ids: &[String],
+        in_stock: bool,
+        product: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Sku>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            active,
+            _attributes,
+            expand,
+            _ids,
+            in_stock,
+            product,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/skus` endpoint.
      *
      * <p>Creates a new SKU associated with a product.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Sku> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSkusRequest,
+    ) -> ClientResult<crate::types::Sku> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/skus` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostSkusRequest,
+    ) -> ClientResult<crate::Response<crate::types::Sku>> {
         let url = self.client.url("/v1/skus", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -184,16 +351,41 @@ impl Skus {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      */
-    pub async fn get(&self, id: &str) -> ClientResult<crate::types::GetSkusResponseAnyOf> {
+    pub async fn get(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::types::GetSkusResponseAnyOf> {
+        Ok(self.get_with_response(expand, id).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/skus/{id}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSkusResponseAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
-            &format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),),
+            &format!(
+                "/v1/skus/{}?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -211,18 +403,34 @@ impl Skus {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
-    pub async fn post_skus(&self, id: &str) -> ClientResult<crate::types::Sku> {
+    pub async fn post_skus(
+        &self,
+        id: &str,
+        body: &crate::types::PostSkusRequest,
+    ) -> ClientResult<crate::types::Sku> {
+        Ok(self.post_skus_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/skus/{id}` endpoint.
+     *
+     * As opposed to `post_skus`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_skus_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostSkusRequest,
+    ) -> ClientResult<crate::Response<crate::types::Sku>> {
         let url = self.client.url(
             &format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -235,15 +443,26 @@ impl Skus {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
      */
     pub async fn delete(&self, id: &str) -> ClientResult<crate::types::DeletedSku> {
+        Ok(self.delete_with_response(id).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/skus/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedSku>> {
         let url = self.client.url(
             &format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
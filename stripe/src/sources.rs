@@ -16,13 +16,27 @@ impl Sources {
      *
      * <p>Creates a new source object.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::SourceData> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSourcesRequest,
+    ) -> ClientResult<crate::types::SourceData> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/sources` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostSourcesRequest,
+    ) -> ClientResult<crate::Response<crate::types::SourceData>> {
         let url = self.client.url("/v1/sources", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -36,18 +50,38 @@ impl Sources {
      * **Parameters:**
      *
      * * `client_secret: &str` -- The client secret of the source. Required if a publishable key is used to retrieve the source.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `source: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `source: &str`
      */
     pub async fn get(
         &self,
         client_secret: &str,
+        expand: &[String],
         source: &str,
     ) -> ClientResult<crate::types::SourceData> {
+        Ok(self
+            .get_with_response(client_secret, expand, source)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        client_secret: &str,
+        expand: &[String],
+        source: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !client_secret.is_empty() {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
@@ -58,7 +92,7 @@ impl Sources {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -76,9 +110,25 @@ impl Sources {
      *
      * **Parameters:**
      *
-     * * `source: &str` -- The account's country.
+     * * `source: &str`
+     */
+    pub async fn post_sources(
+        &self,
+        source: &str,
+        body: &crate::types::PostSourcesSourceRequest,
+    ) -> ClientResult<crate::types::SourceData> {
+        Ok(self.post_sources_with_response(source, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/sources/{source}` endpoint.
+     *
+     * As opposed to `post_sources`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_sources(&self, source: &str) -> ClientResult<crate::types::SourceData> {
+    pub async fn post_sources_with_response(
+        &self,
+        source: &str,
+        body: &crate::types::PostSourcesSourceRequest,
+    ) -> ClientResult<crate::Response<crate::types::SourceData>> {
         let url = self.client.url(
             &format!(
                 "/v1/sources/{}",
@@ -87,10 +137,10 @@ impl Sources {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -103,25 +153,52 @@ impl Sources {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `mandate_notification: &str` -- The account's country.
-     * * `source: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `mandate_notification: &str`
+     * * `source: &str`
      */
     pub async fn get_mandate_notifications_notification(
         &self,
+        expand: &[String],
         mandate_notification: &str,
         source: &str,
     ) -> ClientResult<crate::types::SourceMandateNotification> {
+        Ok(self
+            .get_mandate_notifications_notification_with_response(
+                expand,
+                mandate_notification,
+                source,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/mandate_notifications/{mandate_notification}` endpoint.
+     *
+     * As opposed to `get_mandate_notifications_notification`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_mandate_notifications_notification_with_response(
+        &self,
+        expand: &[String],
+        mandate_notification: &str,
+        source: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceMandateNotification>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sources/{}/mandate_notifications/{}",
+                "/v1/sources/{}/mandate_notifications/{}?{}",
                 crate::progenitor_support::encode_path(source),
                 crate::progenitor_support::encode_path(mandate_notification),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -138,22 +215,44 @@ impl Sources {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `source: &str` -- The account's country.
+     * * `source: &str`
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_transactions(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         source: &str,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::SourceTransaction>> {
+        Ok(self
+            .get_transactions_with_response(ending_before, expand, limit, source, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_transactions_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        source: &str,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SourceTransaction>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -169,9 +268,9 @@ impl Sources {
             ),
             None,
         );
-        let resp: crate::types::ApmsSourcesSourceTransactionList = self
+        let resp: crate::Response<crate::types::ApmsSourcesSourceTransactionList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -181,7 +280,7 @@ impl Sources {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
@@ -192,12 +291,19 @@ impl Sources {
      */
     pub async fn get_all_transactions(
         &self,
+        expand: &[String],
         source: &str,
     ) -> ClientResult<Vec<crate::types::SourceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sources/{}/source_transactions",
+                "/v1/sources/{}/source_transactions?{}",
                 crate::progenitor_support::encode_path(source),
+                query_
             ),
             None,
         );
@@ -260,6 +366,103 @@ impl Sources {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_transactions_pages_stream(
+        &self,
+        expand: &[String],
+        source: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SourceTransaction>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/sources/{}/source_transactions?{}",
+                crate::progenitor_support::encode_path(source),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::ApmsSourcesSourceTransactionList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_all_transactions`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_transactions_stream(
+        &self,
+        expand: &[String],
+        source: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SourceTransaction>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_transactions_pages_stream(expand, source, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/sources/{source}/source_transactions/{source_transaction}` endpoint.
      *
@@ -267,25 +470,48 @@ impl Sources {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `source: &str` -- The account's country.
-     * * `source_transaction: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `source: &str`
+     * * `source_transaction: &str`
      */
     pub async fn get_transactions_transaction(
         &self,
+        expand: &[String],
         source: &str,
         source_transaction: &str,
     ) -> ClientResult<crate::types::SourceTransaction> {
+        Ok(self
+            .get_transactions_transaction_with_response(expand, source, source_transaction)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions/{source_transaction}` endpoint.
+     *
+     * As opposed to `get_transactions_transaction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_transactions_transaction_with_response(
+        &self,
+        expand: &[String],
+        source: &str,
+        source_transaction: &str,
+    ) -> ClientResult<crate::Response<crate::types::SourceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/sources/{}/source_transactions/{}",
+                "/v1/sources/{}/source_transactions/{}?{}",
                 crate::progenitor_support::encode_path(source),
                 crate::progenitor_support::encode_path(source_transaction),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -301,9 +527,25 @@ impl Sources {
      *
      * **Parameters:**
      *
-     * * `source: &str` -- The account's country.
+     * * `source: &str`
+     */
+    pub async fn post_verify(
+        &self,
+        source: &str,
+        body: &crate::types::PostSourcesSourceVerifyRequest,
+    ) -> ClientResult<crate::types::SourceData> {
+        Ok(self.post_verify_with_response(source, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/sources/{source}/verify` endpoint.
+     *
+     * As opposed to `post_verify`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_verify(&self, source: &str) -> ClientResult<crate::types::SourceData> {
+    pub async fn post_verify_with_response(
+        &self,
+        source: &str,
+        body: &crate::types::PostSourcesSourceVerifyRequest,
+    ) -> ClientResult<crate::Response<crate::types::SourceData>> {
         let url = self.client.url(
             &format!(
                 "/v1/sources/{}/verify",
@@ -312,10 +554,10 @@ impl Sources {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
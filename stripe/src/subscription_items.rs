@@ -19,7 +19,7 @@ impl SubscriptionItems {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `subscription: &str` -- The ID of the subscription whose items will be retrieved.
@@ -27,14 +27,36 @@ impl SubscriptionItems {
     pub async fn get_page(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::SubscriptionItem>> {
+        Ok(self
+            .get_page_with_response(ending_before, expand, limit, starting_after, subscription)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        subscription: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionItem>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -48,9 +70,9 @@ impl SubscriptionItems {
         let url = self
             .client
             .url(&format!("/v1/subscription_items?{}", query_), None);
-        let resp: crate::types::Items = self
+        let resp: crate::Response<crate::types::Items> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +82,7 @@ impl SubscriptionItems {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/subscription_items` endpoint.
@@ -71,9 +93,13 @@ impl SubscriptionItems {
      */
     pub async fn get_all(
         &self,
+        expand: &[String],
         subscription: &str,
     ) -> ClientResult<Vec<crate::types::SubscriptionItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !subscription.is_empty() {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
@@ -140,18 +166,127 @@ impl SubscriptionItems {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        expand: &[String],
+        subscription: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SubscriptionItem>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !subscription.is_empty() {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/subscription_items?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Items = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        expand: &[String],
+        subscription: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SubscriptionItem>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_pages_stream(expand, subscription, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/subscription_items` endpoint.
      *
      * <p>Adds a new item to an existing subscription. No existing items will be changed or replaced.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::SubscriptionItem> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSubscriptionItemsRequest,
+    ) -> ClientResult<crate::types::SubscriptionItem> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscription_items` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostSubscriptionItemsRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionItem>> {
         let url = self.client.url("/v1/subscription_items", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -164,19 +299,41 @@ impl SubscriptionItems {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `item: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `item: &str`
      */
-    pub async fn get_item(&self, item: &str) -> ClientResult<crate::types::SubscriptionItem> {
+    pub async fn get_item(
+        &self,
+        expand: &[String],
+        item: &str,
+    ) -> ClientResult<crate::types::SubscriptionItem> {
+        Ok(self.get_item_with_response(expand, item).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items/{item}` endpoint.
+     *
+     * As opposed to `get_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_item_with_response(
+        &self,
+        expand: &[String],
+        item: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscription_items/{}",
+                "/v1/subscription_items/{}?{}",
                 crate::progenitor_support::encode_path(item),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -192,9 +349,25 @@ impl SubscriptionItems {
      *
      * **Parameters:**
      *
-     * * `item: &str` -- The account's country.
+     * * `item: &str`
      */
-    pub async fn post_item(&self, item: &str) -> ClientResult<crate::types::SubscriptionItem> {
+    pub async fn post_item(
+        &self,
+        item: &str,
+        body: &crate::types::PostSubscriptionItemsItemRequest,
+    ) -> ClientResult<crate::types::SubscriptionItem> {
+        Ok(self.post_item_with_response(item, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscription_items/{item}` endpoint.
+     *
+     * As opposed to `post_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_item_with_response(
+        &self,
+        item: &str,
+        body: &crate::types::PostSubscriptionItemsItemRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionItem>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscription_items/{}",
@@ -203,10 +376,10 @@ impl SubscriptionItems {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -219,12 +392,25 @@ impl SubscriptionItems {
      *
      * **Parameters:**
      *
-     * * `item: &str` -- The account's country.
+     * * `item: &str`
      */
     pub async fn delete_item(
         &self,
         item: &str,
+        body: &crate::types::DeleteSubscriptionItemsItemRequest,
     ) -> ClientResult<crate::types::DeletedSubscriptionItem> {
+        Ok(self.delete_item_with_response(item, body).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/subscription_items/{item}` endpoint.
+     *
+     * As opposed to `delete_item`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_item_with_response(
+        &self,
+        item: &str,
+        body: &crate::types::DeleteSubscriptionItemsItemRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeletedSubscriptionItem>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscription_items/{}",
@@ -233,10 +419,10 @@ impl SubscriptionItems {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -252,22 +438,50 @@ impl SubscriptionItems {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `subscription_item: &str` -- The account's country.
+     * * `subscription_item: &str`
      */
     pub async fn get_item_usage_record_summaries(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         subscription_item: &str,
     ) -> ClientResult<Vec<crate::types::UsageRecordSummary>> {
+        Ok(self
+            .get_item_usage_record_summaries_with_response(
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                subscription_item,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
+     *
+     * As opposed to `get_item_usage_record_summaries`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_item_usage_record_summaries_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        subscription_item: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsageRecordSummary>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -283,9 +497,11 @@ impl SubscriptionItems {
             ),
             None,
         );
-        let resp: crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse = self
+        let resp: crate::Response<
+            crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse,
+        > = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -295,7 +511,7 @@ impl SubscriptionItems {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
@@ -308,12 +524,19 @@ impl SubscriptionItems {
      */
     pub async fn get_all_item_usage_record_summaries(
         &self,
+        expand: &[String],
         subscription_item: &str,
     ) -> ClientResult<Vec<crate::types::UsageRecordSummary>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscription_items/{}/usage_record_summaries",
+                "/v1/subscription_items/{}/usage_record_summaries?{}",
                 crate::progenitor_support::encode_path(subscription_item),
+                query_
             ),
             None,
         );
@@ -376,6 +599,99 @@ impl SubscriptionItems {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
+     *
+     * As opposed to `get_all_item_usage_record_summaries`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_item_usage_record_summaries_pages_stream(
+        &self,
+        expand: &[String],
+        subscription_item: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::UsageRecordSummary>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/subscription_items/{}/usage_record_summaries?{}",
+                crate::progenitor_support::encode_path(subscription_item),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse = self.client.get(&url, crate::Message { body: None, content_type: None }).await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
+     *
+     * As opposed to `get_all_item_usage_record_summaries`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_item_usage_record_summaries_stream(
+        &self,
+        expand: &[String],
+        subscription_item: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsageRecordSummary>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_item_usage_record_summaries_pages_stream(
+            expand,
+            subscription_item,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/subscription_items/{subscription_item}/usage_records` endpoint.
      *
@@ -389,12 +705,28 @@ impl SubscriptionItems {
      *
      * **Parameters:**
      *
-     * * `subscription_item: &str` -- The account's country.
+     * * `subscription_item: &str`
      */
     pub async fn post_item_usage_record(
         &self,
         subscription_item: &str,
+        body: &crate::types::PostSubscriptionItemsItemUsageRecordsRequest,
     ) -> ClientResult<crate::types::UsageRecord> {
+        Ok(self
+            .post_item_usage_record_with_response(subscription_item, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscription_items/{subscription_item}/usage_records` endpoint.
+     *
+     * As opposed to `post_item_usage_record`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_item_usage_record_with_response(
+        &self,
+        subscription_item: &str,
+        body: &crate::types::PostSubscriptionItemsItemUsageRecordsRequest,
+    ) -> ClientResult<crate::Response<crate::types::UsageRecord>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscription_items/{}/usage_records",
@@ -403,10 +735,10 @@ impl SubscriptionItems {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -23,7 +23,7 @@ impl SubscriptionSchedules {
      * * `created: &str` -- Only return subscription schedules that were created during the given date interval.
      * * `customer: &str` -- Only return subscription schedules for the given customer.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `released_at: &str` -- Only return subscription schedules that were released during the given date interval.
      * * `scheduled: bool` -- Only return subscription schedules that have not started yet.
@@ -36,11 +36,46 @@ impl SubscriptionSchedules {
         _created: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         _released_at: &str,
         scheduled: bool,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::SubscriptionSchedule>> {
+        Ok(self
+            .get_page_with_response(
+                _canceled_at,
+                _completed_at,
+                _created,
+                customer,
+                ending_before,
+                expand,
+                limit,
+                _released_at,
+                scheduled,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _canceled_at: &str,
+        _completed_at: &str,
+        _created: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        _released_at: &str,
+        scheduled: bool,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SubscriptionSchedule>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
@@ -48,6 +83,9 @@ impl SubscriptionSchedules {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -61,9 +99,9 @@ impl SubscriptionSchedules {
         let url = self
             .client
             .url(&format!("/v1/subscription_schedules?{}", query_), None);
-        let resp: crate::types::GetSubscriptionSchedulesResponse = self
+        let resp: crate::Response<crate::types::GetSubscriptionSchedulesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,7 +111,7 @@ impl SubscriptionSchedules {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
@@ -88,6 +126,7 @@ impl SubscriptionSchedules {
         _completed_at: &str,
         _created: &str,
         customer: &str,
+        expand: &[String],
         _released_at: &str,
         scheduled: bool,
     ) -> ClientResult<Vec<crate::types::SubscriptionSchedule>> {
@@ -95,6 +134,9 @@ impl SubscriptionSchedules {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if scheduled {
             query_args.push(("scheduled".to_string(), scheduled.to_string()));
         }
@@ -161,18 +203,150 @@ impl SubscriptionSchedules {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _canceled_at: &str,
+        _completed_at: &str,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        _released_at: &str,
+        scheduled: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::SubscriptionSchedule>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if scheduled {
+            query_args.push(("scheduled".to_string(), scheduled.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/subscription_schedules?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetSubscriptionSchedulesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _canceled_at: &str,
+        _completed_at: &str,
+        _created: &str,
+        customer: &str,
+        expand: &[String],
+        _released_at: &str,
+        scheduled: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SubscriptionSchedule>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_pages_stream(
+            _canceled_at,
+            _completed_at,
+            _created,
+            customer,
+            expand,
+            _released_at,
+            scheduled,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/subscription_schedules` endpoint.
      *
      * <p>Creates a new subscription schedule object. Each customer can have up to 500 active or scheduled subscriptions.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::SubscriptionSchedule> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSubscriptionSchedulesRequest,
+    ) -> ClientResult<crate::types::SubscriptionSchedule> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscription_schedules` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostSubscriptionSchedulesRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
         let url = self.client.url("/v1/subscription_schedules", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -185,22 +359,44 @@ impl SubscriptionSchedules {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `schedule: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `schedule: &str`
      */
     pub async fn get_schedule(
         &self,
+        expand: &[String],
         schedule: &str,
     ) -> ClientResult<crate::types::SubscriptionSchedule> {
+        Ok(self
+            .get_schedule_with_response(expand, schedule)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscription_schedules/{schedule}` endpoint.
+     *
+     * As opposed to `get_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_schedule_with_response(
+        &self,
+        expand: &[String],
+        schedule: &str,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscription_schedules/{}",
+                "/v1/subscription_schedules/{}?{}",
                 crate::progenitor_support::encode_path(schedule),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -216,12 +412,25 @@ impl SubscriptionSchedules {
      *
      * **Parameters:**
      *
-     * * `schedule: &str` -- The account's country.
+     * * `schedule: &str`
      */
     pub async fn post_schedule(
         &self,
         schedule: &str,
+        body: &crate::types::PostSubscriptionSchedulesScheduleRequest,
     ) -> ClientResult<crate::types::SubscriptionSchedule> {
+        Ok(self.post_schedule_with_response(schedule, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscription_schedules/{schedule}` endpoint.
+     *
+     * As opposed to `post_schedule`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_schedule_with_response(
+        &self,
+        schedule: &str,
+        body: &crate::types::PostSubscriptionSchedulesScheduleRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscription_schedules/{}",
@@ -230,10 +439,10 @@ impl SubscriptionSchedules {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -246,12 +455,28 @@ impl SubscriptionSchedules {
      *
      * **Parameters:**
      *
-     * * `schedule: &str` -- The account's country.
+     * * `schedule: &str`
      */
     pub async fn post_schedule_cancel(
         &self,
         schedule: &str,
+        body: &crate::types::DeleteSubscriptionsSubscriptionExposedRequest,
     ) -> ClientResult<crate::types::SubscriptionSchedule> {
+        Ok(self
+            .post_schedule_cancel_with_response(schedule, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscription_schedules/{schedule}/cancel` endpoint.
+     *
+     * As opposed to `post_schedule_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_schedule_cancel_with_response(
+        &self,
+        schedule: &str,
+        body: &crate::types::DeleteSubscriptionsSubscriptionExposedRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscription_schedules/{}/cancel",
@@ -260,10 +485,10 @@ impl SubscriptionSchedules {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -276,12 +501,28 @@ impl SubscriptionSchedules {
      *
      * **Parameters:**
      *
-     * * `schedule: &str` -- The account's country.
+     * * `schedule: &str`
      */
     pub async fn post_schedule_release(
         &self,
         schedule: &str,
+        body: &crate::types::PostSubscriptionSchedulesScheduleReleaseRequest,
     ) -> ClientResult<crate::types::SubscriptionSchedule> {
+        Ok(self
+            .post_schedule_release_with_response(schedule, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscription_schedules/{schedule}/release` endpoint.
+     *
+     * As opposed to `post_schedule_release`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_schedule_release_with_response(
+        &self,
+        schedule: &str,
+        body: &crate::types::PostSubscriptionSchedulesScheduleReleaseRequest,
+    ) -> ClientResult<crate::Response<crate::types::SubscriptionSchedule>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscription_schedules/{}/release",
@@ -290,10 +531,10 @@ impl SubscriptionSchedules {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
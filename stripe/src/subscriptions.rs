@@ -24,7 +24,7 @@ impl Subscriptions {
      * * `current_period_start: &str`
      * * `customer: &str` -- The ID of the customer whose subscriptions will be retrieved.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `price: &str` -- Filter for subscriptions that contain this recurring price ID.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -39,12 +39,51 @@ impl Subscriptions {
         _current_period_start: &str,
         customer: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         price: &str,
         starting_after: &str,
         status: crate::types::GetSubscriptionsStatus,
         test_clock: &str,
     ) -> ClientResult<Vec<crate::types::Subscription>> {
+        Ok(self
+            .get_page_with_response(
+                collection_method,
+                _created,
+                _current_period_end,
+                _current_period_start,
+                customer,
+                ending_before,
+                expand,
+                limit,
+                price,
+                starting_after,
+                status,
+                test_clock,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        _current_period_end: &str,
+        _current_period_start: &str,
+        customer: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        price: &str,
+        starting_after: &str,
+        status: crate::types::GetSubscriptionsStatus,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Subscription>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !collection_method.to_string().is_empty() {
             query_args.push((
@@ -58,6 +97,9 @@ impl Subscriptions {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -77,9 +119,9 @@ impl Subscriptions {
         let url = self
             .client
             .url(&format!("/v1/subscriptions?{}", query_), None);
-        let resp: crate::types::Subscriptions = self
+        let resp: crate::Response<crate::types::Subscriptions> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -89,7 +131,7 @@ impl Subscriptions {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/subscriptions` endpoint.
@@ -105,6 +147,7 @@ impl Subscriptions {
         _current_period_end: &str,
         _current_period_start: &str,
         customer: &str,
+        expand: &[String],
         price: &str,
         status: crate::types::GetSubscriptionsStatus,
         test_clock: &str,
@@ -119,6 +162,9 @@ impl Subscriptions {
         if !customer.is_empty() {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !price.is_empty() {
             query_args.push(("price".to_string(), price.to_string()));
         }
@@ -191,6 +237,140 @@ impl Subscriptions {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        _current_period_end: &str,
+        _current_period_start: &str,
+        customer: &str,
+        expand: &[String],
+        price: &str,
+        status: crate::types::GetSubscriptionsStatus,
+        test_clock: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Subscription>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !collection_method.to_string().is_empty() {
+            query_args.push((
+                "collection_method".to_string(),
+                collection_method.to_string(),
+            ));
+        }
+        if !customer.is_empty() {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !price.is_empty() {
+            query_args.push(("price".to_string(), price.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !test_clock.is_empty() {
+            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/subscriptions?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Subscriptions = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        _created: &str,
+        _current_period_end: &str,
+        _current_period_start: &str,
+        customer: &str,
+        expand: &[String],
+        price: &str,
+        status: crate::types::GetSubscriptionsStatus,
+        test_clock: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Subscription>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            collection_method,
+            _created,
+            _current_period_end,
+            _current_period_start,
+            customer,
+            expand,
+            price,
+            status,
+            test_clock,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/subscriptions` endpoint.
      *
@@ -202,13 +382,27 @@ impl Subscriptions {
      * <p>To start subscriptions where the first invoice always begins in a <code>draft</code> status, use <a href="/docs/billing/subscriptions/subscription-schedules#managing">subscription schedules</a> instead.
      * Schedules provide the flexibility to model more complex billing configurations that change over time.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Subscription> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostSubscriptionsRequest,
+    ) -> ClientResult<crate::types::Subscription> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscriptions` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostSubscriptionsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
         let url = self.client.url("/v1/subscriptions", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -224,18 +418,39 @@ impl Subscriptions {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
      * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for subscriptions](https://stripe.com/docs/search#query-fields-for-subscriptions).
      */
     pub async fn get_search(
         &self,
+        expand: &[String],
         limit: i64,
         page: &str,
         query: &str,
     ) -> ClientResult<Vec<crate::types::Charge>> {
+        Ok(self
+            .get_search_with_response(expand, limit, page, query)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_search_with_response(
+        &self,
+        expand: &[String],
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Charge>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -249,9 +464,9 @@ impl Subscriptions {
         let url = self
             .client
             .url(&format!("/v1/subscriptions/search?{}", query_), None);
-        let resp: crate::types::SearchResult = self
+        let resp: crate::Response<crate::types::SearchResult> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -261,7 +476,7 @@ impl Subscriptions {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/subscriptions/search` endpoint.
@@ -273,8 +488,15 @@ impl Subscriptions {
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
      */
-    pub async fn get_all_search(&self, query: &str) -> ClientResult<Vec<crate::types::Charge>> {
+    pub async fn get_all_search(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> ClientResult<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !query.is_empty() {
             query_args.push(("query".to_string(), query.to_string()));
         }
@@ -341,6 +563,99 @@ impl Subscriptions {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_search_pages_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Charge>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/subscriptions/search?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::SearchResult = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions/search` endpoint.
+     *
+     * As opposed to `get_all_search`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_stream(
+        &self,
+        expand: &[String],
+        query: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Charge>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_pages_stream(expand, query, None))
+    }
     /**
      * This function performs a `GET` to the `/v1/subscriptions/{subscription_exposed_id}` endpoint.
      *
@@ -348,22 +663,44 @@ impl Subscriptions {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `subscription_exposed_id: &str`
      */
     pub async fn get_exposed(
         &self,
+        expand: &[String],
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::Subscription> {
+        Ok(self
+            .get_exposed_with_response(expand, subscription_exposed_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/subscriptions/{subscription_exposed_id}` endpoint.
+     *
+     * As opposed to `get_exposed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_exposed_with_response(
+        &self,
+        expand: &[String],
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/subscriptions/{}",
+                "/v1/subscriptions/{}?{}",
                 crate::progenitor_support::encode_path(subscription_exposed_id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -379,12 +716,28 @@ impl Subscriptions {
      *
      * **Parameters:**
      *
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `subscription_exposed_id: &str`
      */
     pub async fn post_exposed(
         &self,
         subscription_exposed_id: &str,
+        body: &crate::types::PostSubscriptionsSubscriptionExposedRequest,
     ) -> ClientResult<crate::types::Subscription> {
+        Ok(self
+            .post_exposed_with_response(subscription_exposed_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/subscriptions/{subscription_exposed_id}` endpoint.
+     *
+     * As opposed to `post_exposed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_exposed_with_response(
+        &self,
+        subscription_exposed_id: &str,
+        body: &crate::types::PostSubscriptionsSubscriptionExposedRequest,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscriptions/{}",
@@ -393,10 +746,10 @@ impl Subscriptions {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -413,12 +766,28 @@ impl Subscriptions {
      *
      * **Parameters:**
      *
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `subscription_exposed_id: &str`
      */
     pub async fn delete_exposed(
         &self,
         subscription_exposed_id: &str,
+        body: &crate::types::DeleteSubscriptionsSubscriptionExposedRequest,
     ) -> ClientResult<crate::types::Subscription> {
+        Ok(self
+            .delete_exposed_with_response(subscription_exposed_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/subscriptions/{subscription_exposed_id}` endpoint.
+     *
+     * As opposed to `delete_exposed`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_exposed_with_response(
+        &self,
+        subscription_exposed_id: &str,
+        body: &crate::types::DeleteSubscriptionsSubscriptionExposedRequest,
+    ) -> ClientResult<crate::Response<crate::types::Subscription>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscriptions/{}",
@@ -427,10 +796,10 @@ impl Subscriptions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -443,12 +812,26 @@ impl Subscriptions {
      *
      * **Parameters:**
      *
-     * * `subscription_exposed_id: &str` -- The account's country.
+     * * `subscription_exposed_id: &str`
      */
     pub async fn delete_exposed_discount(
         &self,
         subscription_exposed_id: &str,
     ) -> ClientResult<crate::types::DeletedDiscount> {
+        Ok(self
+            .delete_exposed_discount_with_response(subscription_exposed_id)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/subscriptions/{subscription_exposed_id}/discount` endpoint.
+     *
+     * As opposed to `delete_exposed_discount`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_exposed_discount_with_response(
+        &self,
+        subscription_exposed_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedDiscount>> {
         let url = self.client.url(
             &format!(
                 "/v1/subscriptions/{}/discount",
@@ -457,7 +840,7 @@ impl Subscriptions {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
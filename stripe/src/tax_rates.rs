@@ -21,7 +21,7 @@ impl TaxRates {
      * * `active: bool` -- Optional flag to filter by tax rates that are either active or inactive (archived).
      * * `created: &str` -- Optional range for filtering created date.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `inclusive: bool` -- Optional flag to filter by tax rates that are inclusive (or those that are not inclusive).
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -31,10 +31,39 @@ impl TaxRates {
         active: bool,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         inclusive: bool,
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::TaxRate>> {
+        Ok(self
+            .get_page_with_response(
+                active,
+                _created,
+                ending_before,
+                expand,
+                inclusive,
+                limit,
+                starting_after,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/tax_rates` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        active: bool,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        inclusive: bool,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TaxRate>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
@@ -42,6 +71,9 @@ impl TaxRates {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if inclusive {
             query_args.push(("inclusive".to_string(), inclusive.to_string()));
         }
@@ -53,9 +85,9 @@ impl TaxRates {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/tax_rates?{}", query_), None);
-        let resp: crate::types::GetTaxRatesResponse = self
+        let resp: crate::Response<crate::types::GetTaxRatesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl TaxRates {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/tax_rates` endpoint.
@@ -78,12 +110,16 @@ impl TaxRates {
         &self,
         active: bool,
         _created: &str,
+        expand: &[String],
         inclusive: bool,
     ) -> ClientResult<Vec<crate::types::TaxRate>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if active {
             query_args.push(("active".to_string(), active.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if inclusive {
             query_args.push(("inclusive".to_string(), inclusive.to_string()));
         }
@@ -148,18 +184,132 @@ impl TaxRates {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/tax_rates` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        expand: &[String],
+        inclusive: bool,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TaxRate>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/tax_rates?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetTaxRatesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/tax_rates` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        active: bool,
+        _created: &str,
+        expand: &[String],
+        inclusive: bool,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TaxRate>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_pages_stream(active, _created, expand, inclusive, None),
+        )
+    }
     /**
      * This function performs a `POST` to the `/v1/tax_rates` endpoint.
      *
      * <p>Creates a new tax rate.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::TaxRate> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTaxRatesRequest,
+    ) -> ClientResult<crate::types::TaxRate> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/tax_rates` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostTaxRatesRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaxRate>> {
         let url = self.client.url("/v1/tax_rates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -172,19 +322,41 @@ impl TaxRates {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `tax_rate: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `tax_rate: &str`
      */
-    pub async fn get_rate(&self, tax_rate: &str) -> ClientResult<crate::types::TaxRate> {
+    pub async fn get_rate(
+        &self,
+        expand: &[String],
+        tax_rate: &str,
+    ) -> ClientResult<crate::types::TaxRate> {
+        Ok(self.get_rate_with_response(expand, tax_rate).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/tax_rates/{tax_rate}` endpoint.
+     *
+     * As opposed to `get_rate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_rate_with_response(
+        &self,
+        expand: &[String],
+        tax_rate: &str,
+    ) -> ClientResult<crate::Response<crate::types::TaxRate>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/tax_rates/{}",
+                "/v1/tax_rates/{}?{}",
                 crate::progenitor_support::encode_path(tax_rate),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -200,9 +372,25 @@ impl TaxRates {
      *
      * **Parameters:**
      *
-     * * `tax_rate: &str` -- The account's country.
+     * * `tax_rate: &str`
      */
-    pub async fn post_rate(&self, tax_rate: &str) -> ClientResult<crate::types::TaxRate> {
+    pub async fn post_rate(
+        &self,
+        tax_rate: &str,
+        body: &crate::types::PostTaxRatesRateRequest,
+    ) -> ClientResult<crate::types::TaxRate> {
+        Ok(self.post_rate_with_response(tax_rate, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/tax_rates/{tax_rate}` endpoint.
+     *
+     * As opposed to `post_rate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_rate_with_response(
+        &self,
+        tax_rate: &str,
+        body: &crate::types::PostTaxRatesRateRequest,
+    ) -> ClientResult<crate::Response<crate::types::TaxRate>> {
         let url = self.client.url(
             &format!(
                 "/v1/tax_rates/{}",
@@ -211,10 +399,10 @@ impl TaxRates {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,13 +18,25 @@ impl Terminal {
      */
     pub async fn post_connection_token(
         &self,
+        body: &crate::types::PostTerminalConnectionTokensRequest,
     ) -> ClientResult<crate::types::TerminalConnectionToken> {
+        Ok(self.post_connection_token_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/connection_tokens` endpoint.
+     *
+     * As opposed to `post_connection_token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_connection_token_with_response(
+        &self,
+        body: &crate::types::PostTerminalConnectionTokensRequest,
+    ) -> ClientResult<crate::Response<crate::types::TerminalConnectionToken>> {
         let url = self.client.url("/v1/terminal/connection_tokens", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -38,20 +50,41 @@ impl Terminal {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_locations(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::TerminalLocation>> {
+        Ok(self
+            .get_locations_with_response(ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
+     *
+     * As opposed to `get_locations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_locations_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TerminalLocation>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -62,9 +95,9 @@ impl Terminal {
         let url = self
             .client
             .url(&format!("/v1/terminal/locations?{}", query_), None);
-        let resp: crate::types::TerminalLocationList = self
+        let resp: crate::Response<crate::types::TerminalLocationList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -74,7 +107,7 @@ impl Terminal {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
@@ -83,8 +116,18 @@ impl Terminal {
      *
      * <p>Returns a list of <code>Location</code> objects.</p>
      */
-    pub async fn get_all_locations(&self) -> ClientResult<Vec<crate::types::TerminalLocation>> {
-        let url = self.client.url("/v1/terminal/locations", None);
+    pub async fn get_all_locations(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::TerminalLocation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/terminal/locations?{}", query_), None);
         let mut resp: crate::types::TerminalLocationList = self
             .client
             .get(
@@ -144,19 +187,123 @@ impl Terminal {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
+     *
+     * As opposed to `get_all_locations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_locations_pages_stream(
+        &self,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TerminalLocation>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/terminal/locations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::TerminalLocationList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
+     *
+     * As opposed to `get_all_locations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_locations_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TerminalLocation>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_locations_pages_stream(expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/terminal/locations` endpoint.
      *
      * <p>Creates a new <code>Location</code> object.
      * For further details, including which address fields are required in each country, see the <a href="/docs/terminal/fleet/locations">Manage locations</a> guide.</p>
      */
-    pub async fn post_location(&self) -> ClientResult<crate::types::TerminalLocation> {
+    pub async fn post_location(
+        &self,
+        body: &crate::types::PostTerminalLocationsRequest,
+    ) -> ClientResult<crate::types::TerminalLocation> {
+        Ok(self.post_location_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/locations` endpoint.
+     *
+     * As opposed to `post_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_location_with_response(
+        &self,
+        body: &crate::types::PostTerminalLocationsRequest,
+    ) -> ClientResult<crate::Response<crate::types::TerminalLocation>> {
         let url = self.client.url("/v1/terminal/locations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -169,22 +316,44 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `location: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `location: &str`
      */
     pub async fn get_locations_location(
         &self,
+        expand: &[String],
         location: &str,
     ) -> ClientResult<crate::types::GetTerminalLocationResponseAnyOf> {
+        Ok(self
+            .get_locations_location_with_response(expand, location)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/locations/{location}` endpoint.
+     *
+     * As opposed to `get_locations_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_locations_location_with_response(
+        &self,
+        expand: &[String],
+        location: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalLocationResponseAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/terminal/locations/{}",
+                "/v1/terminal/locations/{}?{}",
                 crate::progenitor_support::encode_path(location),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -200,12 +369,28 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `location: &str` -- The account's country.
+     * * `location: &str`
      */
     pub async fn post_locations_location(
         &self,
         location: &str,
+        body: &crate::types::PostTerminalLocationRequest,
     ) -> ClientResult<crate::types::GetTerminalLocationResponseAnyOf> {
+        Ok(self
+            .post_locations_location_with_response(location, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/locations/{location}` endpoint.
+     *
+     * As opposed to `post_locations_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_locations_location_with_response(
+        &self,
+        location: &str,
+        body: &crate::types::PostTerminalLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalLocationResponseAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/locations/{}",
@@ -214,10 +399,10 @@ impl Terminal {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -230,12 +415,26 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `location: &str` -- The account's country.
+     * * `location: &str`
      */
     pub async fn delete_locations_location(
         &self,
         location: &str,
     ) -> ClientResult<crate::types::DeletedTerminalLocation> {
+        Ok(self
+            .delete_locations_location_with_response(location)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/terminal/locations/{location}` endpoint.
+     *
+     * As opposed to `delete_locations_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_locations_location_with_response(
+        &self,
+        location: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTerminalLocation>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/locations/{}",
@@ -244,7 +443,7 @@ impl Terminal {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -262,7 +461,7 @@ impl Terminal {
      *
      * * `device_type: crate::types::DeviceType` -- Type of reader, one of `bbpos_wisepad3`, `stripe_m2`, `bbpos_chipper2x`, `bbpos_wisepos_e`, or `verifone_P400`.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `location: &str` -- A location ID to filter the response list to only readers at the specific location.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
@@ -272,11 +471,40 @@ impl Terminal {
         &self,
         device_type: crate::types::DeviceType,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         location: &str,
         starting_after: &str,
         status: crate::types::CustomerAcceptanceType,
     ) -> ClientResult<Vec<crate::types::TerminalReader>> {
+        Ok(self
+            .get_readers_with_response(
+                device_type,
+                ending_before,
+                expand,
+                limit,
+                location,
+                starting_after,
+                status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
+     *
+     * As opposed to `get_readers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_readers_with_response(
+        &self,
+        device_type: crate::types::DeviceType,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        location: &str,
+        starting_after: &str,
+        status: crate::types::CustomerAcceptanceType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TerminalReader>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !device_type.to_string().is_empty() {
             query_args.push(("device_type".to_string(), device_type.to_string()));
@@ -284,6 +512,9 @@ impl Terminal {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -300,9 +531,9 @@ impl Terminal {
         let url = self
             .client
             .url(&format!("/v1/terminal/readers?{}", query_), None);
-        let resp: crate::types::TerminalReaderRetrieve = self
+        let resp: crate::Response<crate::types::TerminalReaderRetrieve> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -312,7 +543,7 @@ impl Terminal {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
@@ -324,6 +555,7 @@ impl Terminal {
     pub async fn get_all_readers(
         &self,
         device_type: crate::types::DeviceType,
+        expand: &[String],
         location: &str,
         status: crate::types::CustomerAcceptanceType,
     ) -> ClientResult<Vec<crate::types::TerminalReader>> {
@@ -331,6 +563,9 @@ impl Terminal {
         if !device_type.to_string().is_empty() {
             query_args.push(("device_type".to_string(), device_type.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !location.is_empty() {
             query_args.push(("location".to_string(), location.to_string()));
         }
@@ -400,18 +635,142 @@ impl Terminal {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
+     *
+     * As opposed to `get_all_readers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_readers_pages_stream(
+        &self,
+        device_type: crate::types::DeviceType,
+        expand: &[String],
+        location: &str,
+        status: crate::types::CustomerAcceptanceType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TerminalReader>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !device_type.to_string().is_empty() {
+            query_args.push(("device_type".to_string(), device_type.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !location.is_empty() {
+            query_args.push(("location".to_string(), location.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/terminal/readers?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::TerminalReaderRetrieve = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
+     *
+     * As opposed to `get_all_readers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_readers_stream(
+        &self,
+        device_type: crate::types::DeviceType,
+        expand: &[String],
+        location: &str,
+        status: crate::types::CustomerAcceptanceType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TerminalReader>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_readers_pages_stream(
+            device_type,
+            expand,
+            location,
+            status,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/terminal/readers` endpoint.
      *
      * <p>Creates a new <code>Reader</code> object.</p>
      */
-    pub async fn post_reader(&self) -> ClientResult<crate::types::TerminalReader> {
+    pub async fn post_reader(
+        &self,
+        body: &crate::types::PostTerminalReadersRequest,
+    ) -> ClientResult<crate::types::TerminalReader> {
+        Ok(self.post_reader_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/readers` endpoint.
+     *
+     * As opposed to `post_reader`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_reader_with_response(
+        &self,
+        body: &crate::types::PostTerminalReadersRequest,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
         let url = self.client.url("/v1/terminal/readers", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -424,22 +783,44 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `reader: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `reader: &str`
      */
     pub async fn get_readers_reader(
         &self,
+        expand: &[String],
         reader: &str,
     ) -> ClientResult<crate::types::GetTerminalReadersReaderResponseAnyOf> {
+        Ok(self
+            .get_readers_reader_with_response(expand, reader)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/terminal/readers/{reader}` endpoint.
+     *
+     * As opposed to `get_readers_reader`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_readers_reader_with_response(
+        &self,
+        expand: &[String],
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalReadersReaderResponseAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/terminal/readers/{}",
+                "/v1/terminal/readers/{}?{}",
                 crate::progenitor_support::encode_path(reader),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -455,12 +836,28 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `reader: &str` -- The account's country.
+     * * `reader: &str`
      */
     pub async fn post_readers_reader(
         &self,
         reader: &str,
+        body: &crate::types::PostTerminalReadersReaderRequest,
     ) -> ClientResult<crate::types::GetTerminalReadersReaderResponseAnyOf> {
+        Ok(self
+            .post_readers_reader_with_response(reader, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/readers/{reader}` endpoint.
+     *
+     * As opposed to `post_readers_reader`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_readers_reader_with_response(
+        &self,
+        reader: &str,
+        body: &crate::types::PostTerminalReadersReaderRequest,
+    ) -> ClientResult<crate::Response<crate::types::GetTerminalReadersReaderResponseAnyOf>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/readers/{}",
@@ -469,10 +866,10 @@ impl Terminal {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -485,12 +882,23 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `reader: &str` -- The account's country.
+     * * `reader: &str`
      */
     pub async fn delete_readers_reader(
         &self,
         reader: &str,
     ) -> ClientResult<crate::types::DeletedTerminalReader> {
+        Ok(self.delete_readers_reader_with_response(reader).await?.body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/terminal/readers/{reader}` endpoint.
+     *
+     * As opposed to `delete_readers_reader`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_readers_reader_with_response(
+        &self,
+        reader: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTerminalReader>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/readers/{}",
@@ -499,7 +907,7 @@ impl Terminal {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -515,12 +923,28 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `reader: &str` -- The account's country.
+     * * `reader: &str`
      */
-    pub async fn post_readers_reader_cancel_action(
+    pub async fn post_readers_reader_cancel_action<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> ClientResult<crate::types::TerminalReader> {
+        Ok(self
+            .post_readers_reader_cancel_action_with_response::<B>(reader, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/readers/{reader}/cancel_action` endpoint.
+     *
+     * As opposed to `post_readers_reader_cancel_action`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_readers_reader_cancel_action_with_response<B: serde::Serialize>(
+        &self,
+        reader: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/readers/{}/cancel_action",
@@ -529,10 +953,10 @@ impl Terminal {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -545,12 +969,28 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `reader: &str` -- The account's country.
+     * * `reader: &str`
      */
-    pub async fn post_readers_reader_process_payment_intent(
+    pub async fn post_readers_reader_process_payment_intent<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> ClientResult<crate::types::TerminalReader> {
+        Ok(self
+            .post_readers_reader_process_payment_intent_with_response::<B>(reader, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/readers/{reader}/process_payment_intent` endpoint.
+     *
+     * As opposed to `post_readers_reader_process_payment_intent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_readers_reader_process_payment_intent_with_response<B: serde::Serialize>(
+        &self,
+        reader: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/readers/{}/process_payment_intent",
@@ -559,10 +999,10 @@ impl Terminal {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -575,12 +1015,28 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `reader: &str` -- The account's country.
+     * * `reader: &str`
      */
-    pub async fn post_readers_reader_process_setup_intent(
+    pub async fn post_readers_reader_process_setup_intent<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> ClientResult<crate::types::TerminalReader> {
+        Ok(self
+            .post_readers_reader_process_setup_intent_with_response::<B>(reader, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/readers/{reader}/process_setup_intent` endpoint.
+     *
+     * As opposed to `post_readers_reader_process_setup_intent`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_readers_reader_process_setup_intent_with_response<B: serde::Serialize>(
+        &self,
+        reader: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/readers/{}/process_setup_intent",
@@ -589,10 +1045,10 @@ impl Terminal {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -605,12 +1061,28 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `reader: &str` -- The account's country.
+     * * `reader: &str`
      */
-    pub async fn post_readers_reader_set_display(
+    pub async fn post_readers_reader_set_display<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> ClientResult<crate::types::TerminalReader> {
+        Ok(self
+            .post_readers_reader_set_display_with_response::<B>(reader, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/terminal/readers/{reader}/set_reader_display` endpoint.
+     *
+     * As opposed to `post_readers_reader_set_display`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_readers_reader_set_display_with_response<B: serde::Serialize>(
+        &self,
+        reader: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
         let url = self.client.url(
             &format!(
                 "/v1/terminal/readers/{}/set_reader_display",
@@ -619,10 +1091,10 @@ impl Terminal {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -18,12 +18,30 @@ impl TestHelpers {
      *
      * **Parameters:**
      *
-     * * `reader: &str` -- The account's country.
+     * * `reader: &str`
      */
-    pub async fn post_terminal_readers_reader_present_payment_method(
+    pub async fn post_terminal_readers_reader_present_payment_method<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> ClientResult<crate::types::TerminalReader> {
+        Ok(self
+            .post_terminal_readers_reader_present_payment_method_with_response::<B>(reader, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/test_helpers/terminal/readers/{reader}/present_payment_method` endpoint.
+     *
+     * As opposed to `post_terminal_readers_reader_present_payment_method`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_terminal_readers_reader_present_payment_method_with_response<
+        B: serde::Serialize,
+    >(
+        &self,
+        reader: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::TerminalReader>> {
         let url = self.client.url(
             &format!(
                 "/v1/test_helpers/terminal/readers/{}/present_payment_method",
@@ -32,10 +50,10 @@ impl TestHelpers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -49,20 +67,41 @@ impl TestHelpers {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_clocks(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::TestClock>> {
+        Ok(self
+            .get_clocks_with_response(ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/test_helpers/test_clocks` endpoint.
+     *
+     * As opposed to `get_clocks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_clocks_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TestClock>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -73,9 +112,9 @@ impl TestHelpers {
         let url = self
             .client
             .url(&format!("/v1/test_helpers/test_clocks?{}", query_), None);
-        let resp: crate::types::GetTestHelpersClocksResponse = self
+        let resp: crate::Response<crate::types::GetTestHelpersClocksResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -85,7 +124,7 @@ impl TestHelpers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/test_helpers/test_clocks` endpoint.
@@ -94,8 +133,18 @@ impl TestHelpers {
      *
      * <p>Returns a list of your test clocks.</p>
      */
-    pub async fn get_all_clocks(&self) -> ClientResult<Vec<crate::types::TestClock>> {
-        let url = self.client.url("/v1/test_helpers/test_clocks", None);
+    pub async fn get_all_clocks(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::TestClock>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/test_helpers/test_clocks?{}", query_), None);
         let mut resp: crate::types::GetTestHelpersClocksResponse = self
             .client
             .get(
@@ -155,18 +204,121 @@ impl TestHelpers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/test_helpers/test_clocks` endpoint.
+     *
+     * As opposed to `get_all_clocks`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_clocks_pages_stream(
+        &self,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TestClock>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/test_helpers/test_clocks?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetTestHelpersClocksResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/test_helpers/test_clocks` endpoint.
+     *
+     * As opposed to `get_all_clocks`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_clocks_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TestClock>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_clocks_pages_stream(expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/test_helpers/test_clocks` endpoint.
      *
      * <p>Creates a new test clock that can be attached to new customers and quotes.</p>
      */
-    pub async fn post_clock(&self) -> ClientResult<crate::types::TestClock> {
+    pub async fn post_clock<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::types::TestClock> {
+        Ok(self.post_clock_with_response::<B>(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/test_helpers/test_clocks` endpoint.
+     *
+     * As opposed to `post_clock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_clock_with_response<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::TestClock>> {
         let url = self.client.url("/v1/test_helpers/test_clocks", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -179,22 +331,44 @@ impl TestHelpers {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `test_clock: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `test_clock: &str`
      */
     pub async fn get_clocks_clock(
         &self,
+        expand: &[String],
         test_clock: &str,
     ) -> ClientResult<crate::types::TestClock> {
+        Ok(self
+            .get_clocks_clock_with_response(expand, test_clock)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/test_helpers/test_clocks/{test_clock}` endpoint.
+     *
+     * As opposed to `get_clocks_clock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_clocks_clock_with_response(
+        &self,
+        expand: &[String],
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<crate::types::TestClock>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/test_helpers/test_clocks/{}",
+                "/v1/test_helpers/test_clocks/{}?{}",
                 crate::progenitor_support::encode_path(test_clock),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -210,12 +384,26 @@ impl TestHelpers {
      *
      * **Parameters:**
      *
-     * * `test_clock: &str` -- The account's country.
+     * * `test_clock: &str`
      */
     pub async fn delete_clocks_clock(
         &self,
         test_clock: &str,
     ) -> ClientResult<crate::types::DeletedTestClock> {
+        Ok(self
+            .delete_clocks_clock_with_response(test_clock)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/test_helpers/test_clocks/{test_clock}` endpoint.
+     *
+     * As opposed to `delete_clocks_clock`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_clocks_clock_with_response(
+        &self,
+        test_clock: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedTestClock>> {
         let url = self.client.url(
             &format!(
                 "/v1/test_helpers/test_clocks/{}",
@@ -224,7 +412,7 @@ impl TestHelpers {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -240,12 +428,28 @@ impl TestHelpers {
      *
      * **Parameters:**
      *
-     * * `test_clock: &str` -- The account's country.
+     * * `test_clock: &str`
      */
-    pub async fn post_clocks_clock_advance(
+    pub async fn post_clocks_clock_advance<B: serde::Serialize>(
         &self,
         test_clock: &str,
+        body: &B,
     ) -> ClientResult<crate::types::TestClock> {
+        Ok(self
+            .post_clocks_clock_advance_with_response::<B>(test_clock, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/test_helpers/test_clocks/{test_clock}/advance` endpoint.
+     *
+     * As opposed to `post_clocks_clock_advance`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_clocks_clock_advance_with_response<B: serde::Serialize>(
+        &self,
+        test_clock: &str,
+        body: &B,
+    ) -> ClientResult<crate::Response<crate::types::TestClock>> {
         let url = self.client.url(
             &format!(
                 "/v1/test_helpers/test_clocks/{}/advance",
@@ -254,10 +458,10 @@ impl TestHelpers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
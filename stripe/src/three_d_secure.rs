@@ -16,13 +16,27 @@ impl ThreeDSecure {
      *
      * <p>Initiate 3D Secure authentication.</p>
      */
-    pub async fn post_3d_secure(&self) -> ClientResult<crate::types::ThreeDSecure> {
+    pub async fn post_3d_secure(
+        &self,
+        body: &crate::types::Post3DSecureRequest,
+    ) -> ClientResult<crate::types::ThreeDSecure> {
+        Ok(self.post_3d_secure_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/3d_secure` endpoint.
+     *
+     * As opposed to `post_3d_secure`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_3d_secure_with_response(
+        &self,
+        body: &crate::types::Post3DSecureRequest,
+    ) -> ClientResult<crate::Response<crate::types::ThreeDSecure>> {
         let url = self.client.url("/v1/3d_secure", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -35,22 +49,44 @@ impl ThreeDSecure {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `three_d_secure: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `three_d_secure: &str`
      */
     pub async fn get_3d_secure_three_d(
         &self,
+        expand: &[String],
         three_d_secure: &str,
     ) -> ClientResult<crate::types::ThreeDSecure> {
+        Ok(self
+            .get_3d_secure_three_d_with_response(expand, three_d_secure)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/3d_secure/{three_d_secure}` endpoint.
+     *
+     * As opposed to `get_3d_secure_three_d`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_3d_secure_three_d_with_response(
+        &self,
+        expand: &[String],
+        three_d_secure: &str,
+    ) -> ClientResult<crate::Response<crate::types::ThreeDSecure>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/3d_secure/{}",
+                "/v1/3d_secure/{}?{}",
                 crate::progenitor_support::encode_path(three_d_secure),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -17,13 +17,27 @@ impl Tokens {
      * <p>Creates a single-use token that represents a bank account’s details.
      * This token can be used with any API method in place of a bank account dictionary. This token can be used only once, by attaching it to a <a href="#accounts">Custom account</a>.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Token> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTokensRequest,
+    ) -> ClientResult<crate::types::Token> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/tokens` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostTokensRequest,
+    ) -> ClientResult<crate::Response<crate::types::Token>> {
         let url = self.client.url("/v1/tokens", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -36,19 +50,37 @@ impl Tokens {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `token: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `token: &str`
+     */
+    pub async fn get(&self, expand: &[String], token: &str) -> ClientResult<crate::types::Token> {
+        Ok(self.get_with_response(expand, token).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/tokens/{token}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, token: &str) -> ClientResult<crate::types::Token> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Token>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/tokens/{}",
+                "/v1/tokens/{}?{}",
                 crate::progenitor_support::encode_path(token),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
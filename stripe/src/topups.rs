@@ -21,7 +21,7 @@ impl Topups {
      * * `amount: &str` -- A positive integer representing how much to transfer.
      * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `status: crate::types::GetTopupsStatus` -- Only return top-ups that have the given status. One of `canceled`, `failed`, `pending` or `succeeded`.
@@ -31,14 +31,46 @@ impl Topups {
         _amount: &str,
         _created: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         status: crate::types::GetTopupsStatus,
     ) -> ClientResult<Vec<crate::types::Topup>> {
+        Ok(self
+            .get_page_with_response(
+                _amount,
+                _created,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                status,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/topups` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _amount: &str,
+        _created: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GetTopupsStatus,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Topup>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -50,9 +82,9 @@ impl Topups {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/topups?{}", query_), None);
-        let resp: crate::types::TopupList = self
+        let resp: crate::Response<crate::types::TopupList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -62,7 +94,7 @@ impl Topups {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/topups` endpoint.
@@ -75,9 +107,13 @@ impl Topups {
         &self,
         _amount: &str,
         _created: &str,
+        expand: &[String],
         status: crate::types::GetTopupsStatus,
     ) -> ClientResult<Vec<crate::types::Topup>> {
         let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !status.to_string().is_empty() {
             query_args.push(("status".to_string(), status.to_string()));
         }
@@ -142,18 +178,127 @@ impl Topups {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/topups` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _amount: &str,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::GetTopupsStatus,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Topup>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/topups?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::TopupList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/topups` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _amount: &str,
+        _created: &str,
+        expand: &[String],
+        status: crate::types::GetTopupsStatus,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Topup>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(_amount, _created, expand, status, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/topups` endpoint.
      *
      * <p>Top up the balance of an account</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Topup> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTopupsRequest,
+    ) -> ClientResult<crate::types::Topup> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/topups` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostTopupsRequest,
+    ) -> ClientResult<crate::Response<crate::types::Topup>> {
         let url = self.client.url("/v1/topups", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -166,19 +311,37 @@ impl Topups {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `topup: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `topup: &str`
+     */
+    pub async fn get(&self, expand: &[String], topup: &str) -> ClientResult<crate::types::Topup> {
+        Ok(self.get_with_response(expand, topup).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/topups/{topup}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, topup: &str) -> ClientResult<crate::types::Topup> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        topup: &str,
+    ) -> ClientResult<crate::Response<crate::types::Topup>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/topups/{}",
+                "/v1/topups/{}?{}",
                 crate::progenitor_support::encode_path(topup),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -194,9 +357,25 @@ impl Topups {
      *
      * **Parameters:**
      *
-     * * `topup: &str` -- The account's country.
+     * * `topup: &str`
+     */
+    pub async fn post_topups(
+        &self,
+        topup: &str,
+        body: &crate::types::PostTopupsTopupRequest,
+    ) -> ClientResult<crate::types::Topup> {
+        Ok(self.post_topups_with_response(topup, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/topups/{topup}` endpoint.
+     *
+     * As opposed to `post_topups`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_topups(&self, topup: &str) -> ClientResult<crate::types::Topup> {
+    pub async fn post_topups_with_response(
+        &self,
+        topup: &str,
+        body: &crate::types::PostTopupsTopupRequest,
+    ) -> ClientResult<crate::Response<crate::types::Topup>> {
         let url = self.client.url(
             &format!(
                 "/v1/topups/{}",
@@ -205,10 +384,10 @@ impl Topups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -221,9 +400,25 @@ impl Topups {
      *
      * **Parameters:**
      *
-     * * `topup: &str` -- The account's country.
+     * * `topup: &str`
+     */
+    pub async fn post_cancel(
+        &self,
+        topup: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::types::Topup> {
+        Ok(self.post_cancel_with_response(topup, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/topups/{topup}/cancel` endpoint.
+     *
+     * As opposed to `post_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_cancel(&self, topup: &str) -> ClientResult<crate::types::Topup> {
+    pub async fn post_cancel_with_response(
+        &self,
+        topup: &str,
+        body: &crate::types::PostCreditNotesVoidRequest,
+    ) -> ClientResult<crate::Response<crate::types::Topup>> {
         let url = self.client.url(
             &format!(
                 "/v1/topups/{}/cancel",
@@ -232,10 +427,10 @@ impl Topups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -21,7 +21,7 @@ impl Transfers {
      * * `created: &str`
      * * `destination: &str` -- Only return transfers for the destination specified by this account ID.
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `transfer_group: &str` -- Only return transfers with the specified transfer group.
@@ -31,10 +31,39 @@ impl Transfers {
         _created: &str,
         destination: &str,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
         transfer_group: &str,
     ) -> ClientResult<Vec<crate::types::Transfer>> {
+        Ok(self
+            .get_page_with_response(
+                _created,
+                destination,
+                ending_before,
+                expand,
+                limit,
+                starting_after,
+                transfer_group,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/transfers` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        _created: &str,
+        destination: &str,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+        transfer_group: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Transfer>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !destination.is_empty() {
             query_args.push(("destination".to_string(), destination.to_string()));
@@ -42,6 +71,9 @@ impl Transfers {
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -53,9 +85,9 @@ impl Transfers {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/transfers?{}", query_), None);
-        let resp: crate::types::TransferList = self
+        let resp: crate::Response<crate::types::TransferList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -65,7 +97,7 @@ impl Transfers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/transfers` endpoint.
@@ -78,12 +110,16 @@ impl Transfers {
         &self,
         _created: &str,
         destination: &str,
+        expand: &[String],
         transfer_group: &str,
     ) -> ClientResult<Vec<crate::types::Transfer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !destination.is_empty() {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if !transfer_group.is_empty() {
             query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
         }
@@ -148,18 +184,136 @@ impl Transfers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/transfers` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        _created: &str,
+        destination: &str,
+        expand: &[String],
+        transfer_group: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Transfer>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !destination.is_empty() {
+            query_args.push(("destination".to_string(), destination.to_string()));
+        }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        if !transfer_group.is_empty() {
+            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/transfers?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::TransferList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/transfers` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        _created: &str,
+        destination: &str,
+        expand: &[String],
+        transfer_group: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Transfer>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            _created,
+            destination,
+            expand,
+            transfer_group,
+            None,
+        ))
+    }
     /**
      * This function performs a `POST` to the `/v1/transfers` endpoint.
      *
      * <p>To send funds from your Stripe account to a connected account, you create a new transfer object. Your <a href="#balance">Stripe balance</a> must be able to cover the transfer amount, or you’ll receive an “Insufficient Funds” error.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::Transfer> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostTransfersRequest,
+    ) -> ClientResult<crate::types::Transfer> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/transfers` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostTransfersRequest,
+    ) -> ClientResult<crate::Response<crate::types::Transfer>> {
         let url = self.client.url("/v1/transfers", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -173,22 +327,44 @@ impl Transfers {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_reversals(
         &self,
         ending_before: &str,
+        expand: &[String],
         id: &str,
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::TransferReversal>> {
+        Ok(self
+            .get_reversals_with_response(ending_before, expand, id, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/transfers/{id}/reversals` endpoint.
+     *
+     * As opposed to `get_reversals`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_reversals_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        id: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::TransferReversal>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -204,9 +380,9 @@ impl Transfers {
             ),
             None,
         );
-        let resp: crate::types::Reversals = self
+        let resp: crate::Response<crate::types::Reversals> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -216,7 +392,7 @@ impl Transfers {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/transfers/{id}/reversals` endpoint.
@@ -227,12 +403,19 @@ impl Transfers {
      */
     pub async fn get_all_reversals(
         &self,
+        expand: &[String],
         id: &str,
     ) -> ClientResult<Vec<crate::types::TransferReversal>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/transfers/{}/reversals",
+                "/v1/transfers/{}/reversals?{}",
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
@@ -295,6 +478,103 @@ impl Transfers {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/transfers/{id}/reversals` endpoint.
+     *
+     * As opposed to `get_all_reversals`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_reversals_pages_stream(
+        &self,
+        expand: &[String],
+        id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::TransferReversal>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/v1/transfers/{}/reversals?{}",
+                crate::progenitor_support::encode_path(id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::Reversals = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/transfers/{id}/reversals` endpoint.
+     *
+     * As opposed to `get_all_reversals`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_reversals_stream(
+        &self,
+        expand: &[String],
+        id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::TransferReversal>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_reversals_pages_stream(expand, id, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/transfers/{id}/reversals` endpoint.
      *
@@ -306,9 +586,25 @@ impl Transfers {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
+     * * `id: &str`
+     */
+    pub async fn post_reversal(
+        &self,
+        id: &str,
+        body: &crate::types::PostTransfersReversalsRequest,
+    ) -> ClientResult<crate::types::TransferReversal> {
+        Ok(self.post_reversal_with_response(id, body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/transfers/{id}/reversals` endpoint.
+     *
+     * As opposed to `post_reversal`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn post_reversal(&self, id: &str) -> ClientResult<crate::types::TransferReversal> {
+    pub async fn post_reversal_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::PostTransfersReversalsRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransferReversal>> {
         let url = self.client.url(
             &format!(
                 "/v1/transfers/{}/reversals",
@@ -317,10 +613,10 @@ impl Transfers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -333,19 +629,41 @@ impl Transfers {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `transfer: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `transfer: &str`
+     */
+    pub async fn get(
+        &self,
+        expand: &[String],
+        transfer: &str,
+    ) -> ClientResult<crate::types::Transfer> {
+        Ok(self.get_with_response(expand, transfer).await?.body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/transfers/{transfer}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
      */
-    pub async fn get(&self, transfer: &str) -> ClientResult<crate::types::Transfer> {
+    pub async fn get_with_response(
+        &self,
+        expand: &[String],
+        transfer: &str,
+    ) -> ClientResult<crate::Response<crate::types::Transfer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/transfers/{}",
+                "/v1/transfers/{}?{}",
                 crate::progenitor_support::encode_path(transfer),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -363,9 +681,28 @@ impl Transfers {
      *
      * **Parameters:**
      *
-     * * `transfer: &str` -- The account's country.
+     * * `transfer: &str`
      */
-    pub async fn post_transfers(&self, transfer: &str) -> ClientResult<crate::types::Transfer> {
+    pub async fn post_transfers(
+        &self,
+        transfer: &str,
+        body: &crate::types::PostTopupsTopupRequest,
+    ) -> ClientResult<crate::types::Transfer> {
+        Ok(self
+            .post_transfers_with_response(transfer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/transfers/{transfer}` endpoint.
+     *
+     * As opposed to `post_transfers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_transfers_with_response(
+        &self,
+        transfer: &str,
+        body: &crate::types::PostTopupsTopupRequest,
+    ) -> ClientResult<crate::Response<crate::types::Transfer>> {
         let url = self.client.url(
             &format!(
                 "/v1/transfers/{}",
@@ -374,10 +711,10 @@ impl Transfers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -390,25 +727,48 @@ impl Transfers {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `id: &str` -- The account's country.
-     * * `transfer: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `id: &str`
+     * * `transfer: &str`
      */
     pub async fn get_reversal(
         &self,
+        expand: &[String],
         id: &str,
         transfer: &str,
     ) -> ClientResult<crate::types::TransferReversal> {
+        Ok(self
+            .get_reversal_with_response(expand, id, transfer)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/transfers/{transfer}/reversals/{id}` endpoint.
+     *
+     * As opposed to `get_reversal`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_reversal_with_response(
+        &self,
+        expand: &[String],
+        id: &str,
+        transfer: &str,
+    ) -> ClientResult<crate::Response<crate::types::TransferReversal>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/transfers/{}/reversals/{}",
+                "/v1/transfers/{}/reversals/{}?{}",
                 crate::progenitor_support::encode_path(transfer),
                 crate::progenitor_support::encode_path(id),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -426,14 +786,31 @@ impl Transfers {
      *
      * **Parameters:**
      *
-     * * `id: &str` -- The account's country.
-     * * `transfer: &str` -- The account's country.
+     * * `id: &str`
+     * * `transfer: &str`
      */
     pub async fn post_reversal_transfers(
         &self,
         id: &str,
         transfer: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
     ) -> ClientResult<crate::types::TransferReversal> {
+        Ok(self
+            .post_reversal_transfers_with_response(id, transfer, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/transfers/{transfer}/reversals/{id}` endpoint.
+     *
+     * As opposed to `post_reversal_transfers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_reversal_transfers_with_response(
+        &self,
+        id: &str,
+        transfer: &str,
+        body: &crate::types::PostPayoutsPayoutRequest,
+    ) -> ClientResult<crate::Response<crate::types::TransferReversal>> {
         let url = self.client.url(
             &format!(
                 "/v1/transfers/{}/reversals/{}",
@@ -443,10 +820,10 @@ impl Transfers {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
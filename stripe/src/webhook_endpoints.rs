@@ -19,20 +19,41 @@ impl WebhookEndpoints {
      * **Parameters:**
      *
      * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
      * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      */
     pub async fn get_page(
         &self,
         ending_before: &str,
+        expand: &[String],
         limit: i64,
         starting_after: &str,
     ) -> ClientResult<Vec<crate::types::WebhookEndpoint>> {
+        Ok(self
+            .get_page_with_response(ending_before, expand, limit, starting_after)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/webhook_endpoints` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        ending_before: &str,
+        expand: &[String],
+        limit: i64,
+        starting_after: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::WebhookEndpoint>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !ending_before.is_empty() {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
         if limit > 0 {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
@@ -43,9 +64,9 @@ impl WebhookEndpoints {
         let url = self
             .client
             .url(&format!("/v1/webhook_endpoints?{}", query_), None);
-        let resp: crate::types::GetWebhookEndpointsResponse = self
+        let resp: crate::Response<crate::types::GetWebhookEndpointsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,7 +76,7 @@ impl WebhookEndpoints {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * This function performs a `GET` to the `/v1/webhook_endpoints` endpoint.
@@ -64,8 +85,18 @@ impl WebhookEndpoints {
      *
      * <p>Returns a list of your webhook endpoints.</p>
      */
-    pub async fn get_all(&self) -> ClientResult<Vec<crate::types::WebhookEndpoint>> {
-        let url = self.client.url("/v1/webhook_endpoints", None);
+    pub async fn get_all(
+        &self,
+        expand: &[String],
+    ) -> ClientResult<Vec<crate::types::WebhookEndpoint>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/webhook_endpoints?{}", query_), None);
         let mut resp: crate::types::GetWebhookEndpointsResponse = self
             .client
             .get(
@@ -125,18 +156,121 @@ impl WebhookEndpoints {
         // Return our response data.
         Ok(data.to_vec())
     }
+    /**
+     * This function performs a `GET` to the `/v1/webhook_endpoints` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        expand: &[String],
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::WebhookEndpoint>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/v1/webhook_endpoints?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}starting_after={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let mut resp: crate::types::GetWebhookEndpointsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = if resp.has_more {
+                        resp.data.last().and_then(|last| {
+                            serde_json::json!(last)
+                                .get("id")
+                                .and_then(|id| id.as_str())
+                                .map(|id| id.to_string())
+                        })
+                    } else {
+                        None
+                    };
+                    // Some of these are boxed, so drain them out.
+                    let items = resp.data.drain(..).collect();
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * This function performs a `GET` to the `/v1/webhook_endpoints` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        expand: &[String],
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::WebhookEndpoint>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(expand, None))
+    }
     /**
      * This function performs a `POST` to the `/v1/webhook_endpoints` endpoint.
      *
      * <p>A webhook endpoint must have a <code>url</code> and a list of <code>enabled_events</code>. You may optionally specify the Boolean <code>connect</code> parameter. If set to true, then a Connect webhook endpoint that notifies the specified <code>url</code> about events from all connected accounts is created; otherwise an account webhook endpoint that notifies the specified <code>url</code> only about events from your account is created. You can also create webhook endpoints in the <a href="https://dashboard.stripe.com/account/webhooks">webhooks settings</a> section of the Dashboard.</p>
      */
-    pub async fn post(&self) -> ClientResult<crate::types::WebhookEndpoint> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostWebhookEndpointsRequest,
+    ) -> ClientResult<crate::types::WebhookEndpoint> {
+        Ok(self.post_with_response(body).await?.body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/webhook_endpoints` endpoint.
+     *
+     * As opposed to `post`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_with_response(
+        &self,
+        body: &crate::types::PostWebhookEndpointsRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookEndpoint>> {
         let url = self.client.url("/v1/webhook_endpoints", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -149,22 +283,44 @@ impl WebhookEndpoints {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `webhook_endpoint: &str` -- The account's country.
+     * * `expand: &[String]` -- Specifies which fields in the response should be expanded.
+     * * `webhook_endpoint: &str`
      */
     pub async fn get_endpoint(
         &self,
+        expand: &[String],
         webhook_endpoint: &str,
     ) -> ClientResult<crate::types::WebhookEndpoint> {
+        Ok(self
+            .get_endpoint_with_response(expand, webhook_endpoint)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `GET` to the `/v1/webhook_endpoints/{webhook_endpoint}` endpoint.
+     *
+     * As opposed to `get_endpoint`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_endpoint_with_response(
+        &self,
+        expand: &[String],
+        webhook_endpoint: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebhookEndpoint>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        for f in expand {
+            query_args.push(("expand[]".to_string(), f.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(
             &format!(
-                "/v1/webhook_endpoints/{}",
+                "/v1/webhook_endpoints/{}?{}",
                 crate::progenitor_support::encode_path(webhook_endpoint),
+                query_
             ),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -180,12 +336,28 @@ impl WebhookEndpoints {
      *
      * **Parameters:**
      *
-     * * `webhook_endpoint: &str` -- The account's country.
+     * * `webhook_endpoint: &str`
      */
     pub async fn post_endpoint(
         &self,
         webhook_endpoint: &str,
+        body: &crate::types::PostWebhookEndpointsEndpointRequest,
     ) -> ClientResult<crate::types::WebhookEndpoint> {
+        Ok(self
+            .post_endpoint_with_response(webhook_endpoint, body)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `POST` to the `/v1/webhook_endpoints/{webhook_endpoint}` endpoint.
+     *
+     * As opposed to `post_endpoint`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_endpoint_with_response(
+        &self,
+        webhook_endpoint: &str,
+        body: &crate::types::PostWebhookEndpointsEndpointRequest,
+    ) -> ClientResult<crate::Response<crate::types::WebhookEndpoint>> {
         let url = self.client.url(
             &format!(
                 "/v1/webhook_endpoints/{}",
@@ -194,10 +366,10 @@ impl WebhookEndpoints {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
-                    body: None,
+                    body: Some(reqwest::Body::from(crate::utils::encode_form(body)?)),
                     content_type: Some("application/x-www-form-urlencoded".to_string()),
                 },
             )
@@ -210,12 +382,26 @@ impl WebhookEndpoints {
      *
      * **Parameters:**
      *
-     * * `webhook_endpoint: &str` -- The account's country.
+     * * `webhook_endpoint: &str`
      */
     pub async fn delete_endpoint(
         &self,
         webhook_endpoint: &str,
     ) -> ClientResult<crate::types::DeletedWebhookEndpoint> {
+        Ok(self
+            .delete_endpoint_with_response(webhook_endpoint)
+            .await?
+            .body)
+    }
+    /**
+     * This function performs a `DELETE` to the `/v1/webhook_endpoints/{webhook_endpoint}` endpoint.
+     *
+     * As opposed to `delete_endpoint`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_endpoint_with_response(
+        &self,
+        webhook_endpoint: &str,
+    ) -> ClientResult<crate::Response<crate::types::DeletedWebhookEndpoint>> {
         let url = self.client.url(
             &format!(
                 "/v1/webhook_endpoints/{}",
@@ -224,7 +410,7 @@ impl WebhookEndpoints {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -0,0 +1,500 @@
+//! Verify and parse the webhook events Stripe sends to your endpoints.
+//!
+//! ```ignore
+//! let event = dolladollabills::webhooks::verify_and_parse(&secret, &headers, &body)?;
+//! if let dolladollabills::webhooks::WebhookEvent::Invoice(event) = event {
+//!     if event.type_ == "invoice.paid" {
+//!         println!("{} paid {}", event.data.object.customer_email, event.data.object.amount_paid);
+//!     }
+//! }
+//! ```
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ring::hmac;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{ClientError, ClientResult};
+
+/// The header carrying the timestamp and signatures of the payload.
+pub const SIGNATURE_HEADER: &str = "Stripe-Signature";
+/// How old a signed payload may be before it is rejected, the same default as Stripe's libraries.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(300);
+
+/// Verify the signature Stripe sent for a webhook payload.
+///
+/// `header` is the value of the `Stripe-Signature` header (`t=<timestamp>,v1=<hex>,...`)
+/// and `body` must be the raw request body, before any parsing. The payload is accepted
+/// if any of the `v1` signatures match, compared in constant time, and the timestamp is
+/// no older than `tolerance`, which guards against replayed deliveries.
+pub fn verify_signature(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    tolerance: Duration,
+) -> ClientResult<()> {
+    verify_signature_at(secret, header, body, tolerance, SystemTime::now())
+}
+
+fn verify_signature_at(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    tolerance: Duration,
+    now: SystemTime,
+) -> ClientResult<()> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for (key, value) in header.split(',').filter_map(|kv| kv.trim().split_once('=')) {
+        match key {
+            "t" => timestamp = value.parse::<i64>().ok(),
+            "v1" => signatures.push(value),
+            _ => {}
+        }
+    }
+
+    let timestamp = timestamp.ok_or_else(|| {
+        ClientError::InvalidWebhookSignature("missing or invalid timestamp".to_string())
+    })?;
+    if signatures.is_empty() {
+        return Err(ClientError::InvalidWebhookSignature(
+            "no v1 signatures found".to_string(),
+        ));
+    }
+
+    // The signed payload is the timestamp and the raw body, joined by a dot.
+    let mut payload = format!("{}.", timestamp).into_bytes();
+    payload.extend_from_slice(body);
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let matched = signatures.iter().any(|signature| {
+        decode_hex(signature)
+            .map(|tag| hmac::verify(&key, &payload, &tag).is_ok())
+            .unwrap_or(false)
+    });
+    if !matched {
+        return Err(ClientError::InvalidWebhookSignature(
+            "signature does not match payload".to_string(),
+        ));
+    }
+
+    let now = now
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    if now.saturating_sub(timestamp) > tolerance.as_secs() as i64 {
+        return Err(ClientError::InvalidWebhookSignature(
+            "timestamp is outside the tolerance".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Parse a webhook payload into a typed event, based on its `type`.
+///
+/// The object in `data.object` is deserialized into the generated type for the resource
+/// the event is about, `invoice.paid` carries an `Invoice`, `customer.subscription.updated`
+/// a `Subscription` and so on. Events this module does not model, or whose object does
+/// not match the generated type, for instance after an API version upgrade, are returned
+/// as `WebhookEvent::Unknown` with the raw object.
+pub fn parse_event(body: &[u8]) -> ClientResult<WebhookEvent> {
+    let event: TypedEvent<serde_json::Value> = serde_json::from_slice(body)?;
+
+    // Strip the action, `customer.subscription.updated` is about a `customer.subscription`.
+    let resource = event
+        .type_
+        .rsplit_once('.')
+        .map(|(resource, _)| resource)
+        .unwrap_or_default();
+
+    Ok(match resource {
+        "account" => typed(event, WebhookEvent::Account),
+        "charge" => typed(event, WebhookEvent::Charge),
+        "charge.dispute" => typed(event, WebhookEvent::Dispute),
+        "charge.refund" => typed(event, WebhookEvent::Refund),
+        "checkout.session" => typed(event, WebhookEvent::CheckoutSession),
+        "coupon" => typed(event, WebhookEvent::Coupon),
+        "credit_note" => typed(event, WebhookEvent::CreditNote),
+        "customer" => typed(event, WebhookEvent::Customer),
+        "customer.subscription" => typed(event, WebhookEvent::Subscription),
+        "file" => typed(event, WebhookEvent::File),
+        "invoice" => typed(event, WebhookEvent::Invoice),
+        "invoiceitem" => typed(event, WebhookEvent::InvoiceItem),
+        "mandate" => typed(event, WebhookEvent::Mandate),
+        "payment_intent" => typed(event, WebhookEvent::PaymentIntent),
+        "payment_method" => typed(event, WebhookEvent::PaymentMethod),
+        "payout" => typed(event, WebhookEvent::Payout),
+        "product" => typed(event, WebhookEvent::Product),
+        "promotion_code" => typed(event, WebhookEvent::PromotionCode),
+        "quote" => typed(event, WebhookEvent::Quote),
+        "review" => typed(event, WebhookEvent::Review),
+        "setup_intent" => typed(event, WebhookEvent::SetupIntent),
+        "subscription_schedule" => typed(event, WebhookEvent::SubscriptionSchedule),
+        "tax_rate" => typed(event, WebhookEvent::TaxRate),
+        "topup" => typed(event, WebhookEvent::Topup),
+        "transfer" => typed(event, WebhookEvent::Transfer),
+        _ => WebhookEvent::Unknown(event),
+    })
+}
+
+/// Wrap the event in `variant`, or keep it raw when its object does not deserialize.
+fn typed<T: DeserializeOwned>(
+    event: TypedEvent<serde_json::Value>,
+    variant: fn(TypedEvent<T>) -> WebhookEvent,
+) -> WebhookEvent {
+    match T::deserialize(&event.data.object) {
+        Ok(object) => variant(event.with_object(object)),
+        Err(e) => {
+            log::warn!(
+                "could not parse the object of {} event {}, returning it untyped: {}",
+                event.type_,
+                event.id,
+                e
+            );
+            WebhookEvent::Unknown(event)
+        }
+    }
+}
+
+/// Verify the signature of a webhook delivery and parse it into a typed event.
+///
+/// The signature is read from the `Stripe-Signature` header and checked against
+/// `DEFAULT_TOLERANCE`, use `verify_signature` and `parse_event` for a different tolerance.
+pub fn verify_and_parse(
+    secret: &str,
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> ClientResult<WebhookEvent> {
+    let signature = headers
+        .get(SIGNATURE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            ClientError::InvalidWebhookSignature("missing signature header".to_string())
+        })?;
+    verify_signature(secret, signature, body, DEFAULT_TOLERANCE)?;
+    parse_event(body)
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|c| match c {
+            [_, _] => u8::from_str_radix(std::str::from_utf8(c).ok()?, 16).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A webhook event, keyed by the resource its `type` is about.
+///
+/// Match on `type_` of the inner event for the action, e.g. `invoice.paid` or `invoice.voided`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    Account(TypedEvent<crate::types::Account>),
+    Charge(TypedEvent<crate::types::Charge>),
+    Dispute(TypedEvent<crate::types::Dispute>),
+    Refund(TypedEvent<crate::types::Refund>),
+    CheckoutSession(TypedEvent<crate::types::Session>),
+    Coupon(TypedEvent<crate::types::Coupon>),
+    CreditNote(TypedEvent<crate::types::CreditNote>),
+    Customer(TypedEvent<crate::types::Customer>),
+    Subscription(TypedEvent<crate::types::Subscription>),
+    File(TypedEvent<crate::types::File>),
+    Invoice(TypedEvent<crate::types::Invoice>),
+    InvoiceItem(TypedEvent<crate::types::InvoiceItem>),
+    Mandate(TypedEvent<crate::types::Mandate>),
+    PaymentIntent(TypedEvent<crate::types::PaymentIntent>),
+    PaymentMethod(TypedEvent<crate::types::PaymentMethod>),
+    Payout(TypedEvent<crate::types::Payout>),
+    Product(TypedEvent<crate::types::Product>),
+    PromotionCode(TypedEvent<crate::types::PromotionCode>),
+    Quote(TypedEvent<crate::types::Quote>),
+    Review(TypedEvent<crate::types::Review>),
+    SetupIntent(TypedEvent<crate::types::SetupIntent>),
+    SubscriptionSchedule(TypedEvent<crate::types::SubscriptionSchedule>),
+    TaxRate(TypedEvent<crate::types::TaxRate>),
+    Topup(TypedEvent<crate::types::Topup>),
+    Transfer(TypedEvent<crate::types::Transfer>),
+    /// An event this module does not have a type for, or whose object did not match it.
+    Unknown(TypedEvent<serde_json::Value>),
+}
+
+/// An `Event` whose `data.object` is deserialized into `T`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TypedEvent<T> {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /// The connected account that originated the event, for Connect webhooks.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_version: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub created: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub livemode: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub pending_webhooks: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<crate::types::NotificationEventRequest>,
+    pub data: TypedEventData<T>,
+}
+
+/// The object an event is about.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TypedEventData<T> {
+    pub object: T,
+    /// The previous values of the attributes that changed, for `*.updated` events.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_attributes: Option<serde_json::Value>,
+}
+
+impl TypedEvent<serde_json::Value> {
+    fn with_object<T>(self, object: T) -> TypedEvent<T> {
+        TypedEvent {
+            id: self.id,
+            type_: self.type_,
+            account: self.account,
+            api_version: self.api_version,
+            created: self.created,
+            livemode: self.livemode,
+            pending_webhooks: self.pending_webhooks,
+            request: self.request,
+            data: TypedEventData {
+                object,
+                previous_attributes: self.data.previous_attributes,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test_secret";
+    const NOW: i64 = 1_700_000_000;
+
+    fn at(timestamp: i64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(timestamp as u64)
+    }
+
+    fn sign(timestamp: i64, body: &[u8]) -> String {
+        let key = hmac::Key::new(hmac::HMAC_SHA256, SECRET.as_bytes());
+        let mut payload = format!("{}.", timestamp).into_bytes();
+        payload.extend_from_slice(body);
+        let tag = hmac::sign(&key, &payload);
+        tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn verify_signature_accepts_any_v1() {
+        let body = br#"{"id": "evt_123"}"#;
+        let header = format!("t={},v1={},v0=deadbeef", NOW, sign(NOW, body));
+        verify_signature_at(SECRET, &header, body, DEFAULT_TOLERANCE, at(NOW)).unwrap();
+
+        // Stripe sends one signature per secret while a secret is being rolled.
+        let header = format!("t={},v1={},v1={}", NOW, "00".repeat(32), sign(NOW, body));
+        verify_signature_at(SECRET, &header, body, DEFAULT_TOLERANCE, at(NOW + 300)).unwrap();
+    }
+
+    #[test]
+    fn verify_signature_rejects_mismatches() {
+        let body = br#"{"id": "evt_123"}"#;
+        let good = sign(NOW, body);
+        let bad = [
+            format!("t={},v1={}", NOW, "00".repeat(32)),
+            format!("t={},v1={}", NOW + 1, good),
+            format!("t={},v1={}", NOW, &good[..32]),
+            format!("t={},v1=not-hex", NOW),
+            format!("t={},v0={}", NOW, good),
+            format!("v1={}", good),
+            String::new(),
+        ];
+        for header in bad {
+            assert!(
+                matches!(
+                    verify_signature_at(SECRET, &header, body, DEFAULT_TOLERANCE, at(NOW)),
+                    Err(ClientError::InvalidWebhookSignature(_))
+                ),
+                "{} should not verify",
+                header
+            );
+        }
+    }
+
+    #[test]
+    fn verify_signature_rejects_old_timestamps() {
+        let body = br#"{"id": "evt_123"}"#;
+        let header = format!("t={},v1={}", NOW, sign(NOW, body));
+        assert!(matches!(
+            verify_signature_at(SECRET, &header, body, DEFAULT_TOLERANCE, at(NOW + 301)),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+        verify_signature_at(
+            SECRET,
+            &header,
+            body,
+            Duration::from_secs(3600),
+            at(NOW + 301),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn parse_subscription_updated_event() {
+        let body = serde_json::json!({
+            "id": "evt_1NG8Du2eZvKYlo2CUI79vXWy",
+            "object": "event",
+            "api_version": "2020-08-27",
+            "created": 1686089970,
+            "livemode": false,
+            "pending_webhooks": 1,
+            "request": {"id": "req_2a3a7a1c8f5d", "idempotency_key": "5c1f3c35-0a5e-4a2b-8d79-5c0f6e2f0f2e"},
+            "type": "customer.subscription.updated",
+            "data": {
+                "object": {
+                    "id": "sub_1NG8Dr2eZvKYlo2C3VqHNd8W",
+                    "object": "subscription",
+                    "customer": "cus_O2D7pWJLOl8D7P",
+                    "status": "active",
+                    "automatic_tax": {"enabled": false},
+                    "default_payment_method": null,
+                    "default_source": null,
+                    "latest_invoice": "in_1NG8Dr2eZvKYlo2CWu1IOqKL",
+                    "pending_setup_intent": null,
+                    "schedule": null,
+                    "test_clock": null,
+                    "cancel_at_period_end": true,
+                    "current_period_start": 1686089967,
+                    "current_period_end": 1688681967,
+                    "items": {"object": "list", "data": [], "has_more": false, "url": "/v1/subscription_items?subscription=sub_1NG8Dr2eZvKYlo2C3VqHNd8W"},
+                    "metadata": {}
+                },
+                "previous_attributes": {"cancel_at_period_end": false}
+            }
+        });
+
+        let event = parse_event(body.to_string().as_bytes()).unwrap();
+        let event = match event {
+            WebhookEvent::Subscription(event) => event,
+            e => panic!("expected a subscription event, got {:?}", e),
+        };
+        assert_eq!(event.type_, "customer.subscription.updated");
+        assert_eq!(event.created, 1686089970);
+        assert_eq!(event.request.unwrap().id, "req_2a3a7a1c8f5d");
+        assert_eq!(event.data.object.id, "sub_1NG8Dr2eZvKYlo2C3VqHNd8W");
+        assert!(event.data.object.cancel_at_period_end);
+        assert_eq!(
+            event.data.previous_attributes,
+            Some(serde_json::json!({"cancel_at_period_end": false}))
+        );
+    }
+
+    #[test]
+    fn parse_unknown_event() {
+        let body = serde_json::json!({
+            "id": "evt_123",
+            "type": "balance.available",
+            "data": {"object": {"object": "balance", "available": []}}
+        });
+
+        let event = parse_event(body.to_string().as_bytes()).unwrap();
+        let event = match event {
+            WebhookEvent::Unknown(event) => event,
+            e => panic!("expected an unknown event, got {:?}", e),
+        };
+        assert_eq!(event.type_, "balance.available");
+        assert_eq!(
+            event.data.object,
+            serde_json::json!({"object": "balance", "available": []})
+        );
+    }
+
+    #[test]
+    fn parse_mismatched_event() {
+        // An object that no longer matches the generated type is kept raw.
+        let body = serde_json::json!({
+            "id": "evt_123",
+            "type": "invoice.paid",
+            "data": {"object": "in_1MtHbELkdIwHu7ixl4OzzPMv"}
+        });
+
+        let event = parse_event(body.to_string().as_bytes()).unwrap();
+        let event = match event {
+            WebhookEvent::Unknown(event) => event,
+            e => panic!("expected an unknown event, got {:?}", e),
+        };
+        assert_eq!(event.type_, "invoice.paid");
+        assert_eq!(
+            event.data.object,
+            serde_json::json!("in_1MtHbELkdIwHu7ixl4OzzPMv")
+        );
+    }
+
+    #[test]
+    fn verify_and_parse_requires_signature() {
+        let body = serde_json::json!({
+            "id": "evt_123",
+            "type": "customer.created",
+            "data": {"object": {"id": "cus_O2D7pWJLOl8D7P", "object": "customer", "email": "jenny.rosen@example.com"}}
+        })
+        .to_string();
+        let mut headers = http::HeaderMap::new();
+
+        assert!(matches!(
+            verify_and_parse(SECRET, &headers, body.as_bytes()),
+            Err(ClientError::InvalidWebhookSignature(_))
+        ));
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        headers.insert(
+            SIGNATURE_HEADER,
+            format!("t={},v1={}", now, sign(now, body.as_bytes()))
+                .parse()
+                .unwrap(),
+        );
+
+        match verify_and_parse(SECRET, &headers, body.as_bytes()).unwrap() {
+            WebhookEvent::Customer(event) => {
+                assert_eq!(event.data.object.id, "cus_O2D7pWJLOl8D7P");
+                assert_eq!(event.data.object.email, "jenny.rosen@example.com");
+            }
+            e => panic!("expected a customer event, got {:?}", e),
+        }
+    }
+}
@@ -40,6 +40,38 @@ impl BookingData {
         size: i64,
         booking_type: crate::types::BookingType,
     ) -> ClientResult<Vec<crate::types::BookingReport>> {
+        Ok(self
+            .get_booking_report_with_response(
+                created_from,
+                created_to,
+                start_date_from,
+                start_date_to,
+                booking_status,
+                page,
+                size,
+                booking_type,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_booking_report`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_booking_report_with_response(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        page: u64,
+        size: i64,
+        booking_type: crate::types::BookingType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BookingReport>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !booking_status.to_string().is_empty() {
             query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
@@ -67,9 +99,9 @@ impl BookingData {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/v1/bookings?{}", query_), None);
-        let resp: crate::types::BookingReportResponse = self
+        let resp: crate::Response<crate::types::BookingReportResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -79,7 +111,7 @@ impl BookingData {
             .await?;
 
         // Return our response data.
-        Ok(resp.data.to_vec())
+        Ok(resp.map(|r| r.data.to_vec()))
     }
     /**
      * Your company's bookings.
@@ -180,4 +212,121 @@ impl BookingData {
         // Return our response data.
         Ok(data)
     }
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_all_booking_report`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_booking_report_pages_stream(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BookingReport>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !booking_status.to_string().is_empty() {
+            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+        }
+        if !booking_type.to_string().is_empty() {
+            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+        }
+        if !created_from.is_empty() {
+            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+        }
+        if !created_to.is_empty() {
+            query_args.push(("createdTo".to_string(), created_to.to_string()));
+        }
+        if !start_date_from.is_empty() {
+            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+        }
+        if !start_date_to.is_empty() {
+            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/v1/bookings?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let page = cursor.as_deref().unwrap_or("0");
+                    let url = format!(
+                        "{}{}page={}&size=100",
+                        url,
+                        if url.contains('?') { '&' } else { '?' },
+                        page
+                    );
+                    let resp: crate::types::BookingReportResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next_page = resp.page.current_page + 1;
+                    let next = if next_page < resp.page.total_pages {
+                        Some(next_page.to_string())
+                    } else {
+                        None
+                    };
+                    let items = resp.data;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_all_booking_report`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_booking_report_stream(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BookingReport>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_booking_report_pages_stream(
+            created_from,
+            created_to,
+            start_date_from,
+            start_date_to,
+            booking_status,
+            booking_type,
+            None,
+        ))
+    }
 }
@@ -3,6 +3,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TravelerType {
     #[serde(rename = "GUEST")]
     Guest,
@@ -10,8 +11,9 @@ pub enum TravelerType {
     Passenger,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TravelerType {
@@ -20,12 +22,32 @@ impl std::fmt::Display for TravelerType {
             TravelerType::Guest => "GUEST",
             TravelerType::Passenger => "PASSENGER",
             TravelerType::Noop => "",
-            TravelerType::FallthroughString => "*",
+            TravelerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TravelerType {
+    fn from(s: String) -> TravelerType {
+        match s.as_str() {
+            "GUEST" => TravelerType::Guest,
+            "PASSENGER" => TravelerType::Passenger,
+            "" => TravelerType::Noop,
+            _ => TravelerType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TravelerType> for String {
+    fn from(e: TravelerType) -> String {
+        match e {
+            TravelerType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TravelerType {
     fn default() -> TravelerType {
         TravelerType::Noop
@@ -38,6 +60,7 @@ impl TravelerType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BookingStatus {
     #[serde(rename = "ACCEPTED")]
     Accepted,
@@ -51,8 +74,9 @@ pub enum BookingStatus {
     Voided,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingStatus {
@@ -64,12 +88,35 @@ impl std::fmt::Display for BookingStatus {
             BookingStatus::Ticketed => "TICKETED",
             BookingStatus::Voided => "VOIDED",
             BookingStatus::Noop => "",
-            BookingStatus::FallthroughString => "*",
+            BookingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BookingStatus {
+    fn from(s: String) -> BookingStatus {
+        match s.as_str() {
+            "ACCEPTED" => BookingStatus::Accepted,
+            "CANCELED" => BookingStatus::Canceled,
+            "CONFIRMED" => BookingStatus::Confirmed,
+            "TICKETED" => BookingStatus::Ticketed,
+            "VOIDED" => BookingStatus::Voided,
+            "" => BookingStatus::Noop,
+            _ => BookingStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BookingStatus> for String {
+    fn from(e: BookingStatus) -> String {
+        match e {
+            BookingStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BookingStatus {
     fn default() -> BookingStatus {
         BookingStatus::Noop
@@ -82,6 +129,7 @@ impl BookingStatus {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BookingType {
     #[serde(rename = "BLACK_CAR")]
     BlackCar,
@@ -97,8 +145,9 @@ pub enum BookingType {
     Transportation,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for BookingType {
@@ -111,12 +160,36 @@ impl std::fmt::Display for BookingType {
             BookingType::Rail => "RAIL",
             BookingType::Transportation => "TRANSPORTATION",
             BookingType::Noop => "",
-            BookingType::FallthroughString => "*",
+            BookingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for BookingType {
+    fn from(s: String) -> BookingType {
+        match s.as_str() {
+            "BLACK_CAR" => BookingType::BlackCar,
+            "CAR" => BookingType::Car,
+            "FLIGHT" => BookingType::Flight,
+            "HOTEL" => BookingType::Hotel,
+            "RAIL" => BookingType::Rail,
+            "TRANSPORTATION" => BookingType::Transportation,
+            "" => BookingType::Noop,
+            _ => BookingType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<BookingType> for String {
+    fn from(e: BookingType) -> String {
+        match e {
+            BookingType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for BookingType {
     fn default() -> BookingType {
         BookingType::Noop
@@ -129,6 +202,7 @@ impl BookingType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PaymentSchedule {
     #[serde(rename = "LATER")]
     Later,
@@ -136,12 +210,11 @@ pub enum PaymentSchedule {
     Now,
     #[serde(rename = "PARTIAL")]
     Partial,
-    #[serde(rename = "UNKNOWN")]
-    Unknown,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PaymentSchedule {
@@ -150,14 +223,34 @@ impl std::fmt::Display for PaymentSchedule {
             PaymentSchedule::Later => "LATER",
             PaymentSchedule::Now => "NOW",
             PaymentSchedule::Partial => "PARTIAL",
-            PaymentSchedule::Unknown => "UNKNOWN",
             PaymentSchedule::Noop => "",
-            PaymentSchedule::FallthroughString => "*",
+            PaymentSchedule::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PaymentSchedule {
+    fn from(s: String) -> PaymentSchedule {
+        match s.as_str() {
+            "LATER" => PaymentSchedule::Later,
+            "NOW" => PaymentSchedule::Now,
+            "PARTIAL" => PaymentSchedule::Partial,
+            "" => PaymentSchedule::Noop,
+            _ => PaymentSchedule::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PaymentSchedule> for String {
+    fn from(e: PaymentSchedule) -> String {
+        match e {
+            PaymentSchedule::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PaymentSchedule {
     fn default() -> PaymentSchedule {
         PaymentSchedule::Noop
@@ -170,6 +263,7 @@ impl PaymentSchedule {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PassengerStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -177,8 +271,9 @@ pub enum PassengerStatus {
     Enabled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PassengerStatus {
@@ -187,12 +282,32 @@ impl std::fmt::Display for PassengerStatus {
             PassengerStatus::Disabled => "DISABLED",
             PassengerStatus::Enabled => "ENABLED",
             PassengerStatus::Noop => "",
-            PassengerStatus::FallthroughString => "*",
+            PassengerStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PassengerStatus {
+    fn from(s: String) -> PassengerStatus {
+        match s.as_str() {
+            "DISABLED" => PassengerStatus::Disabled,
+            "ENABLED" => PassengerStatus::Enabled,
+            "" => PassengerStatus::Noop,
+            _ => PassengerStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PassengerStatus> for String {
+    fn from(e: PassengerStatus) -> String {
+        match e {
+            PassengerStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PassengerStatus {
     fn default() -> PassengerStatus {
         PassengerStatus::Noop
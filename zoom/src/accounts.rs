@@ -41,6 +41,24 @@ impl Accounts {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_with_response(page_size, page_number, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List sub accounts.
+     *
+     * This function performs a `GET` to the `/accounts` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -54,7 +72,7 @@ impl Accounts {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/accounts?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -84,9 +102,22 @@ impl Accounts {
         &self,
         body: &crate::types::AccountCreateRequest,
     ) -> ClientResult<crate::types::AccountCreateResponse> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Create a sub account.
+     *
+     * This function performs a `POST` to the `/accounts` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::AccountCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::AccountCreateResponse>> {
         let url = self.client.url("/accounts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -117,6 +148,19 @@ impl Accounts {
      * * `account_id: &str` -- User's first name.
      */
     pub async fn account(&self, account_id: &str) -> ClientResult<crate::types::AccountResponse> {
+        Ok(self.account_with_response(account_id).await?.body)
+    }
+    /**
+     * Get sub account details.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}` endpoint.
+     *
+     * As opposed to `account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountResponse>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}",
@@ -125,7 +169,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -157,6 +201,20 @@ impl Accounts {
      * * `account_id: &str` -- User's first name.
      */
     pub async fn disassociate(&self, account_id: &str) -> ClientResult<()> {
+        self.disassociate_with_response(account_id).await?;
+        Ok(())
+    }
+    /**
+     * Disassociate a sub account.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}` endpoint.
+     *
+     * As opposed to `disassociate`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn disassociate_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}",
@@ -165,7 +223,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -200,6 +258,21 @@ impl Accounts {
         account_id: &str,
         body: &crate::types::Options,
     ) -> ClientResult<()> {
+        self.options_update_with_response(account_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update options.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/options` endpoint.
+     *
+     * As opposed to `options_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn options_update_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::Options,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/options",
@@ -208,7 +281,7 @@ impl Accounts {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -503,6 +576,24 @@ impl Accounts {
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> ClientResult<crate::types::AccountSettingsResponseOneOf> {
+        Ok(self
+            .setting_with_response(account_id, option, custom_query_fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get settings.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/settings` endpoint.
+     *
+     * As opposed to `setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn setting_with_response(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountSettingsResponseOneOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -523,7 +614,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -555,6 +646,23 @@ impl Accounts {
         option: crate::types::AccountSettingsUpdateOption,
         body: &crate::types::AccountSettingsUpdateRequestOneOf,
     ) -> ClientResult<()> {
+        self.settings_update_with_response(account_id, option, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update settings.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/settings` endpoint.
+     *
+     * As opposed to `settings_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn settings_update_with_response(
+        &self,
+        account_id: &str,
+        option: crate::types::AccountSettingsUpdateOption,
+        body: &crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !option.to_string().is_empty() {
             query_args.push(("option".to_string(), option.to_string()));
@@ -569,7 +677,7 @@ impl Accounts {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -605,6 +713,19 @@ impl Accounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::DomainsList> {
+        Ok(self.managed_domain_with_response(account_id).await?.body)
+    }
+    /**
+     * Get managed domains.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/managed_domains` endpoint.
+     *
+     * As opposed to `managed_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn managed_domain_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::DomainsList>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/managed_domains",
@@ -613,7 +734,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -642,6 +763,19 @@ impl Accounts {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::AccountTrustedDomainResponse> {
+        Ok(self.trusted_domain_with_response(account_id).await?.body)
+    }
+    /**
+     * Get trusted domains.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/trusted_domains` endpoint.
+     *
+     * As opposed to `trusted_domain`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trusted_domain_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountTrustedDomainResponse>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/trusted_domains",
@@ -650,7 +784,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -698,6 +832,24 @@ impl Accounts {
         option: &str,
         custom_query_fields: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_lock_setting_with_response(account_id, option, custom_query_fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get locked settings.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/lock_settings` endpoint.
+     *
+     * As opposed to `get_lock_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_lock_setting_with_response(
+        &self,
+        account_id: &str,
+        option: &str,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -718,7 +870,7 @@ impl Accounts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -749,6 +901,20 @@ impl Accounts {
      * * `account_id: &str` -- Unique Identifier of the account. To retrieve locked settings of the master account or a regular account, provide "me" as the value of this field. <br> To retrieve locked settings of a sub account, provide the Account ID of the sub account in this field.
      */
     pub async fn update_lock_settings(&self, account_id: &str) -> ClientResult<()> {
+        self.update_lock_settings_with_response(account_id).await?;
+        Ok(())
+    }
+    /**
+     * Update locked settings.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/lock_settings` endpoint.
+     *
+     * As opposed to `update_lock_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_lock_settings_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/lock_settings",
@@ -757,7 +923,7 @@ impl Accounts {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -790,6 +956,21 @@ impl Accounts {
         account_id: &str,
         body: &crate::types::Members,
     ) -> ClientResult<()> {
+        self.update_owner_with_response(account_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update the account owner.
+     *
+     * This function performs a `PUT` to the `/accounts/{accountId}/owner` endpoint.
+     *
+     * As opposed to `update_owner`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_owner_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::Members,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/owner",
@@ -798,7 +979,7 @@ impl Accounts {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -831,6 +1012,20 @@ impl Accounts {
         account_id: &str,
         body: &crate::types::UploadVbRequest,
     ) -> ClientResult<crate::types::Files> {
+        Ok(self.upload_vb_with_response(account_id, body).await?.body)
+    }
+    /**
+     * Upload virtual background files.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `upload_vb`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn upload_vb_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/settings/virtual_backgrounds",
@@ -839,7 +1034,7 @@ impl Accounts {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -867,6 +1062,21 @@ impl Accounts {
      * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
      */
     pub async fn del_vb(&self, account_id: &str, file_ids: &str) -> ClientResult<()> {
+        self.del_vb_with_response(account_id, file_ids).await?;
+        Ok(())
+    }
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn del_vb_with_response(
+        &self,
+        account_id: &str,
+        file_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !file_ids.is_empty() {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
@@ -881,7 +1091,7 @@ impl Accounts {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -41,6 +41,32 @@ impl Archiving {
         to: &str,
         query_date_type: crate::types::ListArchivedFilesQueryDateType,
     ) -> ClientResult<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        Ok(self
+            .list_archived_files_with_response(
+                page_size,
+                next_page_token,
+                from,
+                to,
+                query_date_type,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_archived_files_with_response(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListArchivedFilesResponseMeetings>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -59,9 +85,9 @@ impl Archiving {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/archive_files?{}", query_), None);
-        let resp: crate::types::ListArchivedFilesResponse = self
+        let resp: crate::Response<crate::types::ListArchivedFilesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,7 +97,7 @@ impl Archiving {
             .await?;
 
         // Return our response data.
-        Ok(resp.meetings.to_vec())
+        Ok(resp.map(|r| r.meetings.to_vec()))
     }
     /**
      * List archived files.
@@ -160,6 +186,105 @@ impl Archiving {
         // Return our response data.
         Ok(meetings)
     }
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_all_archived_files`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_archived_files_pages_stream(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListArchivedFilesResponseMeetings>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !query_date_type.to_string().is_empty() {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/archive_files?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListArchivedFilesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.meetings;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_all_archived_files`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_archived_files_stream(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListArchivedFilesResponseMeetings>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.list_all_archived_files_pages_stream(
+            from,
+            to,
+            query_date_type,
+            None,
+        ))
+    }
     /**
      * Get meeting archived files.
      *
@@ -183,6 +308,22 @@ impl Archiving {
         &self,
         meeting_uuid: &str,
     ) -> ClientResult<crate::types::CloudArchivedFiles> {
+        Ok(self
+            .testget_record_archived_file_with_response(meeting_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get meeting archived files.
+     *
+     * This function performs a `GET` to the `/past_meetings/{meetingUUID}/archive_files` endpoint.
+     *
+     * As opposed to `testget_record_archived_file`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn testget_record_archived_file_with_response(
+        &self,
+        meeting_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::CloudArchivedFiles>> {
         let url = self.client.url(
             &format!(
                 "/past_meetings/{}/archive_files",
@@ -191,7 +332,7 @@ impl Archiving {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -31,6 +31,19 @@ impl Billing {
      * * `account_id: &str` -- User's first name.
      */
     pub async fn account(&self, account_id: &str) -> ClientResult<crate::types::Contact> {
+        Ok(self.account_with_response(account_id).await?.body)
+    }
+    /**
+     * Get billing information.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/billing` endpoint.
+     *
+     * As opposed to `account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Contact>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/billing",
@@ -39,7 +52,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,6 +86,21 @@ impl Billing {
         account_id: &str,
         body: &crate::types::BillingContact,
     ) -> ClientResult<()> {
+        self.account_update_with_response(account_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update billing information.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/billing` endpoint.
+     *
+     * As opposed to `account_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_update_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingContact,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/billing",
@@ -81,7 +109,7 @@ impl Billing {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -110,6 +138,19 @@ impl Billing {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::AccountPlansResponse> {
+        Ok(self.account_plans_with_response(account_id).await?.body)
+    }
+    /**
+     * Get plan Information.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/plans` endpoint.
+     *
+     * As opposed to `account_plans`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_plans_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::AccountPlansResponse>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans",
@@ -118,7 +159,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -145,6 +186,22 @@ impl Billing {
         account_id: &str,
         body: &crate::types::AccountPlanCreateRequestAllOf,
     ) -> ClientResult<()> {
+        self.account_plan_create_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Subscribe plans.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/plans` endpoint.
+     *
+     * As opposed to `account_plan_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_plan_create_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanCreateRequestAllOf,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans",
@@ -153,7 +210,7 @@ impl Billing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -185,6 +242,22 @@ impl Billing {
         account_id: &str,
         body: &crate::types::PlanBase,
     ) -> ClientResult<()> {
+        self.account_plan_base_update_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a base plan.
+     *
+     * This function performs a `PUT` to the `/accounts/{accountId}/plans/base` endpoint.
+     *
+     * As opposed to `account_plan_base_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_plan_base_update_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans/base",
@@ -193,7 +266,7 @@ impl Billing {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -229,6 +302,22 @@ impl Billing {
         account_id: &str,
         body: &crate::types::PlanBase,
     ) -> ClientResult<()> {
+        self.account_plan_addon_update_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update an additional plan.
+     *
+     * This function performs a `PUT` to the `/accounts/{accountId}/plans/addons` endpoint.
+     *
+     * As opposed to `account_plan_addon_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_plan_addon_update_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans/addons",
@@ -237,7 +326,7 @@ impl Billing {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -268,6 +357,23 @@ impl Billing {
         account_id: &str,
         body: &crate::types::AccountPlanAddonCreateRequestOneOf,
     ) -> ClientResult<crate::types::AccountPlans> {
+        Ok(self
+            .account_plan_addon_create_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Subscribe additional plan.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/plans/addons` endpoint.
+     *
+     * As opposed to `account_plan_addon_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_plan_addon_create_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCreateRequestOneOf,
+    ) -> ClientResult<crate::Response<crate::types::AccountPlans>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans/addons",
@@ -276,7 +382,7 @@ impl Billing {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -309,6 +415,22 @@ impl Billing {
         account_id: &str,
         body: &crate::types::AccountPlanBaseDeleteRequest,
     ) -> ClientResult<()> {
+        self.account_plan_base_delete_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Cancel a base plan.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/plans/base/status` endpoint.
+     *
+     * As opposed to `account_plan_base_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_plan_base_delete_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanBaseDeleteRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans/base/status",
@@ -317,7 +439,7 @@ impl Billing {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -348,6 +470,22 @@ impl Billing {
         account_id: &str,
         body: &crate::types::AccountPlanAddonCancelRequest,
     ) -> ClientResult<()> {
+        self.account_plan_addon_cancel_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Cancel additional plans.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/plans/addons/status` endpoint.
+     *
+     * As opposed to `account_plan_addon_cancel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_plan_addon_cancel_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCancelRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans/addons/status",
@@ -356,7 +494,7 @@ impl Billing {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -381,6 +519,19 @@ impl Billing {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::GetPlanUsageResponse> {
+        Ok(self.get_plan_usage_with_response(account_id).await?.body)
+    }
+    /**
+     * Get plan usage.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/plans/usage` endpoint.
+     *
+     * As opposed to `get_plan_usage`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_plan_usage_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetPlanUsageResponse>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/plans/usage",
@@ -389,7 +540,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -425,6 +576,24 @@ impl Billing {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<crate::types::AccountBillingInvoicesResponseData> {
+        Ok(self
+            .account_invoice_with_response(account_id, from, to)
+            .await?
+            .body)
+    }
+    /**
+     * List billing invoices.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/billing/invoices` endpoint.
+     *
+     * As opposed to `account_invoice`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_invoice_with_response(
+        &self,
+        account_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::AccountBillingInvoicesResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -442,7 +611,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -470,6 +639,23 @@ impl Billing {
         account_id: &str,
         invoice_id: &str,
     ) -> ClientResult<crate::types::GetAccountBillingInvoiceResponse> {
+        Ok(self
+            .get_account_invoice_with_response(account_id, invoice_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get invoice details.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/billing/invoices/{invoiceId}` endpoint.
+     *
+     * As opposed to `get_account_invoice`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_account_invoice_with_response(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetAccountBillingInvoiceResponse>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/billing/invoices/{}",
@@ -479,7 +665,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -500,6 +686,20 @@ impl Billing {
      * * This API has a daily limit of **100 requests per account**.
      */
     pub async fn download_invoice_pdf(&self, invoice_id: &str) -> ClientResult<()> {
+        self.download_invoice_pdf_with_response(invoice_id).await?;
+        Ok(())
+    }
+    /**
+     * Download an invoice file.
+     *
+     * This function performs a `GET` to the `/api/download/billing/invoices/{invoiceId}` endpoint.
+     *
+     * As opposed to `download_invoice_pdf`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn download_invoice_pdf_with_response(
+        &self,
+        invoice_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/api/download/billing/invoices/{}",
@@ -508,7 +708,7 @@ impl Billing {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -36,6 +36,24 @@ impl ChatChannels {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::Channels>> {
+        Ok(self
+            .get_channels_with_response(user_id, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_channels`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_channels_with_response(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Channels>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -52,9 +70,9 @@ impl ChatChannels {
             ),
             None,
         );
-        let resp: crate::types::GetChannelsResponse = self
+        let resp: crate::Response<crate::types::GetChannelsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -64,7 +82,7 @@ impl ChatChannels {
             .await?;
 
         // Return our response data.
-        Ok(resp.channels.to_vec())
+        Ok(resp.map(|r| r.channels.to_vec()))
     }
     /**
      * List user's channels.
@@ -145,6 +163,87 @@ impl ChatChannels {
         // Return our response data.
         Ok(channels)
     }
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_all_channels`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_channels_pages_stream(
+        &self,
+        user_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Channels>>> + Unpin + '_
+    {
+        let url = self.client.url(
+            &format!(
+                "/chat/users/{}/channels",
+                crate::progenitor_support::encode_path(user_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::GetChannelsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.channels;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_all_channels`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_channels_stream(
+        &self,
+        user_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Channels>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_channels_pages_stream(user_id, None))
+    }
     /**
      * Create a channel.
      *
@@ -167,6 +266,20 @@ impl ChatChannels {
         user_id: &str,
         body: &crate::types::CreateChannelRequest,
     ) -> ClientResult<crate::types::CreateChannelResponse> {
+        Ok(self.create_channel_with_response(user_id, body).await?.body)
+    }
+    /**
+     * Create a channel.
+     *
+     * This function performs a `POST` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `create_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_channel_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::CreateChannelRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateChannelResponse>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/channels",
@@ -175,7 +288,7 @@ impl ChatChannels {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -207,6 +320,22 @@ impl ChatChannels {
         &self,
         channel_id: &str,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .get_user_level_channel_with_response(channel_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a channel.
+     *
+     * This function performs a `GET` to the `/chat/channels/{channelId}` endpoint.
+     *
+     * As opposed to `get_user_level_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_level_channel_with_response(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let url = self.client.url(
             &format!(
                 "/chat/channels/{}",
@@ -215,7 +344,7 @@ impl ChatChannels {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -244,6 +373,21 @@ impl ChatChannels {
      * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
      */
     pub async fn delete_user_level_channel(&self, channel_id: &str) -> ClientResult<()> {
+        self.delete_user_level_channel_with_response(channel_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a channel.
+     *
+     * This function performs a `DELETE` to the `/chat/channels/{channelId}` endpoint.
+     *
+     * As opposed to `delete_user_level_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_user_level_channel_with_response(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/channels/{}",
@@ -252,7 +396,7 @@ impl ChatChannels {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -284,6 +428,22 @@ impl ChatChannels {
         channel_id: &str,
         body: &crate::types::Attendees,
     ) -> ClientResult<()> {
+        self.update_user_level_channel_with_response(channel_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a channel.
+     *
+     * This function performs a `PATCH` to the `/chat/channels/{channelId}` endpoint.
+     *
+     * As opposed to `update_user_level_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_user_level_channel_with_response(
+        &self,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/channels/{}",
@@ -292,7 +452,7 @@ impl ChatChannels {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -325,6 +485,22 @@ impl ChatChannels {
         channel_id: &str,
         member_id: &str,
     ) -> ClientResult<()> {
+        self.remove_user_level_channel_member_with_response(channel_id, member_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a member.
+     *
+     * This function performs a `DELETE` to the `/chat/channels/{channelId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `remove_user_level_channel_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_user_level_channel_member_with_response(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/channels/{}/members/{}",
@@ -334,7 +510,7 @@ impl ChatChannels {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -363,6 +539,19 @@ impl ChatChannels {
         &self,
         channel_id: &str,
     ) -> ClientResult<crate::types::JoinChannelResponse> {
+        Ok(self.join_channel_with_response(channel_id).await?.body)
+    }
+    /**
+     * Join a channel.
+     *
+     * This function performs a `POST` to the `/chat/channels/{channelId}/members/me` endpoint.
+     *
+     * As opposed to `join_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn join_channel_with_response(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::JoinChannelResponse>> {
         let url = self.client.url(
             &format!(
                 "/chat/channels/{}/members/me",
@@ -371,7 +560,7 @@ impl ChatChannels {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -397,6 +586,20 @@ impl ChatChannels {
      * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
      */
     pub async fn leave_channel(&self, channel_id: &str) -> ClientResult<()> {
+        self.leave_channel_with_response(channel_id).await?;
+        Ok(())
+    }
+    /**
+     * Leave a channel.
+     *
+     * This function performs a `DELETE` to the `/chat/channels/{channelId}/members/me` endpoint.
+     *
+     * As opposed to `leave_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn leave_channel_with_response(
+        &self,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/channels/{}/members/me",
@@ -405,7 +608,7 @@ impl ChatChannels {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -34,6 +34,23 @@ impl ChatChannelsAccountLevel {
         user_id: &str,
         channel_id: &str,
     ) -> ClientResult<crate::types::Channel> {
+        Ok(self
+            .get_channel_with_response(user_id, channel_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a channel.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}` endpoint.
+     *
+     * As opposed to `get_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_channel_with_response(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Channel>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/channels/{}",
@@ -43,7 +60,7 @@ impl ChatChannelsAccountLevel {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -70,6 +87,22 @@ impl ChatChannelsAccountLevel {
      * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
      */
     pub async fn delete_channel(&self, user_id: &str, channel_id: &str) -> ClientResult<()> {
+        self.delete_channel_with_response(user_id, channel_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a channel.
+     *
+     * This function performs a `DELETE` to the `/chat/users/{userId}/channels/{channelId}` endpoint.
+     *
+     * As opposed to `delete_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_channel_with_response(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/channels/{}",
@@ -79,7 +112,7 @@ impl ChatChannelsAccountLevel {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -112,6 +145,23 @@ impl ChatChannelsAccountLevel {
         channel_id: &str,
         body: &crate::types::Attendees,
     ) -> ClientResult<()> {
+        self.update_channel_with_response(user_id, channel_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a channel.
+     *
+     * This function performs a `PATCH` to the `/chat/users/{userId}/channels/{channelId}` endpoint.
+     *
+     * As opposed to `update_channel`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_channel_with_response(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/channels/{}",
@@ -121,7 +171,7 @@ impl ChatChannelsAccountLevel {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -155,6 +205,25 @@ impl ChatChannelsAccountLevel {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::ListChannelMembersResponse>> {
+        Ok(self
+            .list_channel_members_with_response(user_id, channel_id, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_channel_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_channel_members_with_response(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListChannelMembersResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -172,9 +241,9 @@ impl ChatChannelsAccountLevel {
             ),
             None,
         );
-        let resp: crate::types::ListChannelMembersResponseData = self
+        let resp: crate::Response<crate::types::ListChannelMembersResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -184,7 +253,7 @@ impl ChatChannelsAccountLevel {
             .await?;
 
         // Return our response data.
-        Ok(resp.members.to_vec())
+        Ok(resp.map(|r| r.members.to_vec()))
     }
     /**
      * List channel members.
@@ -265,6 +334,95 @@ impl ChatChannelsAccountLevel {
         // Return our response data.
         Ok(members)
     }
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_all_channel_members`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_channel_members_pages_stream(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListChannelMembersResponse>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/chat/users/{}/channels/{}/members",
+                crate::progenitor_support::encode_path(user_id),
+                crate::progenitor_support::encode_path(channel_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListChannelMembersResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.members;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_all_channel_members`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_channel_members_stream(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListChannelMembersResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(
+            self.list_all_channel_members_pages_stream(user_id, channel_id, None),
+        )
+    }
     /**
      * Invite channel members.
      *
@@ -287,6 +445,24 @@ impl ChatChannelsAccountLevel {
         channel_id: &str,
         body: &crate::types::InviteChannelMembersRequest,
     ) -> ClientResult<crate::types::InviteChannelMembersResponse> {
+        Ok(self
+            .invite_channel_members_with_response(user_id, channel_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Invite channel members.
+     *
+     * This function performs a `POST` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `invite_channel_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn invite_channel_members_with_response(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::InviteChannelMembersRequest,
+    ) -> ClientResult<crate::Response<crate::types::InviteChannelMembersResponse>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/channels/{}/members",
@@ -296,7 +472,7 @@ impl ChatChannelsAccountLevel {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -330,6 +506,23 @@ impl ChatChannelsAccountLevel {
         channel_id: &str,
         member_id: &str,
     ) -> ClientResult<()> {
+        self.remove_channel_member_with_response(user_id, channel_id, member_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a member.
+     *
+     * This function performs a `DELETE` to the `/chat/users/{userId}/channels/{channelId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `remove_channel_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn remove_channel_member_with_response(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/channels/{}/members/{}",
@@ -340,7 +533,7 @@ impl ChatChannelsAccountLevel {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,6 +51,36 @@ impl ChatMessages {
         next_page_token: &str,
         include_deleted_and_edited_message: &str,
     ) -> ClientResult<Vec<crate::types::Messages>> {
+        Ok(self
+            .get_page_with_response(
+                user_id,
+                to_contact,
+                to_channel,
+                date,
+                page_size,
+                next_page_token,
+                include_deleted_and_edited_message,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Messages>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !date.to_string().is_empty() {
             query_args.push(("date".to_string(), date.to_string()));
@@ -82,9 +112,9 @@ impl ChatMessages {
             ),
             None,
         );
-        let resp: crate::types::GetChatMessagesResponse = self
+        let resp: crate::Response<crate::types::GetChatMessagesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -94,7 +124,7 @@ impl ChatMessages {
             .await?;
 
         // Return our response data.
-        Ok(resp.messages.to_vec())
+        Ok(resp.map(|r| r.messages.to_vec()))
     }
     /**
      * List user's chat messages.
@@ -200,6 +230,120 @@ impl ChatMessages {
         // Return our response data.
         Ok(messages)
     }
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Messages>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !date.to_string().is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        if !include_deleted_and_edited_message.is_empty() {
+            query_args.push((
+                "include_deleted_and_edited_message".to_string(),
+                include_deleted_and_edited_message.to_string(),
+            ));
+        }
+        if !to_channel.is_empty() {
+            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+        }
+        if !to_contact.is_empty() {
+            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/chat/users/{}/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::GetChatMessagesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.messages;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Messages>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            include_deleted_and_edited_message,
+            None,
+        ))
+    }
     /**
      * Send a chat message.
      *
@@ -218,6 +362,20 @@ impl ChatMessages {
         user_id: &str,
         body: &crate::types::SendaChatMessageRequest,
     ) -> ClientResult<crate::types::Groups> {
+        Ok(self.senda_with_response(user_id, body).await?.body)
+    }
+    /**
+     * Send a chat message.
+     *
+     * This function performs a `POST` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `senda`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn senda_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::SendaChatMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/messages",
@@ -226,7 +384,7 @@ impl ChatMessages {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -257,6 +415,23 @@ impl ChatMessages {
         message_id: &str,
         body: &crate::types::MarkMessageRequest,
     ) -> ClientResult<()> {
+        self.mark_message_with_response(user_id, message_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Mark message read or unread.
+     *
+     * This function performs a `PATCH` to the `/chat/users/{userId}/messages/{messageId}/status` endpoint.
+     *
+     * As opposed to `mark_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn mark_message_with_response(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::MarkMessageRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/messages/{}/status",
@@ -266,7 +441,7 @@ impl ChatMessages {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -297,6 +472,23 @@ impl ChatMessages {
         message_id: &str,
         body: &crate::types::ReactMessageRequest,
     ) -> ClientResult<()> {
+        self.react_message_with_response(user_id, message_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * React to a chat message.
+     *
+     * This function performs a `PATCH` to the `/chat/users/{userId}/messages/{messageId}/emoji_reactions` endpoint.
+     *
+     * As opposed to `react_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn react_message_with_response(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::ReactMessageRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/messages/{}/emoji_reactions",
@@ -306,7 +498,7 @@ impl ChatMessages {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -343,6 +535,25 @@ impl ChatMessages {
         to_contact: &str,
         to_channel: &str,
     ) -> ClientResult<crate::types::GetChatMessageResponse> {
+        Ok(self
+            .get_with_response(user_id, message_id, to_contact, to_channel)
+            .await?
+            .body)
+    }
+    /**
+     * Get a message.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages/{messageId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetChatMessageResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !to_channel.is_empty() {
             query_args.push(("to_channel".to_string(), to_channel.to_string()));
@@ -361,7 +572,7 @@ impl ChatMessages {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -394,6 +605,23 @@ impl ChatMessages {
         message_id: &str,
         body: &crate::types::EditMessageRequest,
     ) -> ClientResult<()> {
+        self.edit_message_with_response(user_id, message_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a message.
+     *
+     * This function performs a `PUT` to the `/chat/users/{userId}/messages/{messageId}` endpoint.
+     *
+     * As opposed to `edit_message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn edit_message_with_response(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::EditMessageRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/chat/users/{}/messages/{}",
@@ -403,7 +631,7 @@ impl ChatMessages {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -447,6 +675,24 @@ impl ChatMessages {
         to_contact: &str,
         to_channel: &str,
     ) -> ClientResult<()> {
+        self.delete_with_response(user_id, message_id, to_contact, to_channel)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a message.
+     *
+     * This function performs a `DELETE` to the `/chat/users/{userId}/messages/{messageId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !to_channel.is_empty() {
             query_args.push(("to_channel".to_string(), to_channel.to_string()));
@@ -465,7 +711,7 @@ impl ChatMessages {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -25,9 +25,23 @@ impl ChatbotMessages {
      * Learn more about how to authorize chatbots in the [Chatbot Authorization](https://marketplace.zoom.us/docs/guides/chatbots/authorization) guide.
      */
     pub async fn sendchatbot(&self, body: &crate::types::SendchatbotRequest) -> ClientResult<()> {
+        self.sendchatbot_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Send chatbot messages.
+     *
+     * This function performs a `POST` to the `/im/chat/messages` endpoint.
+     *
+     * As opposed to `sendchatbot`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn sendchatbot_with_response(
+        &self,
+        body: &crate::types::SendchatbotRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/im/chat/messages", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -59,6 +73,20 @@ impl ChatbotMessages {
         message_id: &str,
         body: &crate::types::EditChatbotMessageRequest,
     ) -> ClientResult<crate::types::EditChatbotMessageResponse> {
+        Ok(self.edit_with_response(message_id, body).await?.body)
+    }
+    /**
+     * Edit a chatbot message.
+     *
+     * This function performs a `PUT` to the `/im/chat/messages/{message_id}` endpoint.
+     *
+     * As opposed to `edit`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn edit_with_response(
+        &self,
+        message_id: &str,
+        body: &crate::types::EditChatbotMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::EditChatbotMessageResponse>> {
         let url = self.client.url(
             &format!(
                 "/im/chat/messages/{}",
@@ -67,7 +95,7 @@ impl ChatbotMessages {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -90,6 +118,20 @@ impl ChatbotMessages {
         message_id: &str,
         body: &crate::types::DeleteChatbotMessageRequest,
     ) -> ClientResult<crate::types::DeleteChatbotMessageResponse> {
+        Ok(self.delete_with_response(message_id, body).await?.body)
+    }
+    /**
+     * Delete a chatbot message.
+     *
+     * This function performs a `DELETE` to the `/im/chat/messages/{message_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        message_id: &str,
+        body: &crate::types::DeleteChatbotMessageRequest,
+    ) -> ClientResult<crate::Response<crate::types::DeleteChatbotMessageResponse>> {
         let url = self.client.url(
             &format!(
                 "/im/chat/messages/{}",
@@ -98,7 +140,7 @@ impl ChatbotMessages {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -54,6 +54,38 @@ impl CloudRecording {
         to: chrono::NaiveDate,
         trash_type: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .recordings_list_with_response(
+                user_id,
+                page_size,
+                next_page_token,
+                mc,
+                trash,
+                from,
+                to,
+                trash_type,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List all recordings.
+     *
+     * This function performs a `GET` to the `/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `recordings_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recordings_list_with_response(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        mc: &str,
+        trash: bool,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        trash_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -86,7 +118,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -122,6 +154,24 @@ impl CloudRecording {
         include_fields: &str,
         ttl: u64,
     ) -> ClientResult<crate::types::RecordingGetResponseAllOf> {
+        Ok(self
+            .recording_get_with_response(meeting_id, include_fields, ttl)
+            .await?
+            .body)
+    }
+    /**
+     * Get meeting recordings.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/recordings` endpoint.
+     *
+     * As opposed to `recording_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_get_with_response(
+        &self,
+        meeting_id: &str,
+        include_fields: &str,
+        ttl: u64,
+    ) -> ClientResult<crate::Response<crate::types::RecordingGetResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_fields.is_empty() {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
@@ -139,7 +189,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -175,6 +225,22 @@ impl CloudRecording {
         meeting_id: &str,
         action: crate::types::RecordingDeleteAction,
     ) -> ClientResult<()> {
+        self.recording_delete_with_response(meeting_id, action)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete meeting recordings.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/recordings` endpoint.
+     *
+     * As opposed to `recording_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_delete_with_response(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !action.to_string().is_empty() {
             query_args.push(("action".to_string(), action.to_string()));
@@ -189,7 +255,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -223,6 +289,23 @@ impl CloudRecording {
         recording_id: &str,
         action: crate::types::RecordingDeleteAction,
     ) -> ClientResult<()> {
+        self.recording_delete_one_with_response(meeting_id, recording_id, action)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a meeting recording file.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/recordings/{recordingId}` endpoint.
+     *
+     * As opposed to `recording_delete_one`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_delete_one_with_response(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !action.to_string().is_empty() {
             query_args.push(("action".to_string(), action.to_string()));
@@ -238,7 +321,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -272,6 +355,22 @@ impl CloudRecording {
         meeting_id: &str,
         body: &crate::types::RecordingStatusUpdateBodyRequest,
     ) -> ClientResult<()> {
+        self.recording_status_update_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Recover meeting recordings.
+     *
+     * This function performs a `PUT` to the `/meetings/{meetingId}/recordings/status` endpoint.
+     *
+     * As opposed to `recording_status_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_status_update_with_response(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/status",
@@ -280,7 +379,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -315,6 +414,23 @@ impl CloudRecording {
         recording_id: &str,
         body: &crate::types::RecordingStatusUpdateBodyRequest,
     ) -> ClientResult<()> {
+        self.recording_status_update_one_with_response(meeting_id, recording_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Recover a single recording.
+     *
+     * This function performs a `PUT` to the `/meetings/{meetingId}/recordings/{recordingId}/status` endpoint.
+     *
+     * As opposed to `recording_status_update_one`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_status_update_one_with_response(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/{}/status",
@@ -324,7 +440,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -355,6 +471,22 @@ impl CloudRecording {
         &self,
         meeting_id: &str,
     ) -> ClientResult<crate::types::RecordingSettings> {
+        Ok(self
+            .recording_setting_update_with_response(meeting_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get meeting recording settings.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/recordings/settings` endpoint.
+     *
+     * As opposed to `recording_setting_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_setting_update_with_response(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RecordingSettings>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/settings",
@@ -363,7 +495,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -395,6 +527,22 @@ impl CloudRecording {
         meeting_id: &str,
         body: &crate::types::RecordingSettings,
     ) -> ClientResult<()> {
+        self.recording_settings_update_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update meeting recording settings.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/recordings/settings` endpoint.
+     *
+     * As opposed to `recording_settings_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_settings_update_with_response(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingSettings,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/settings",
@@ -403,7 +551,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -446,6 +594,32 @@ impl CloudRecording {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .meeting_recording_registrant_with_response(
+                meeting_id,
+                status,
+                page_size,
+                page_number,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List recording registrants.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/recordings/registrants` endpoint.
+     *
+     * As opposed to `meeting_recording_registrant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_recording_registrant_with_response(
+        &self,
+        meeting_id: i64,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -469,7 +643,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -502,6 +676,22 @@ impl CloudRecording {
         &self,
         meeting_id: i64,
     ) -> ClientResult<crate::types::MeetingRecordingRegistrantCreateResponse> {
+        Ok(self
+            .meeting_recording_registrant_create_with_response(meeting_id)
+            .await?
+            .body)
+    }
+    /**
+     * Create a recording registrant.
+     *
+     * This function performs a `POST` to the `/meetings/{meetingId}/recordings/registrants` endpoint.
+     *
+     * As opposed to `meeting_recording_registrant_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_recording_registrant_create_with_response(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRecordingRegistrantCreateResponse>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/registrants",
@@ -510,7 +700,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -543,6 +733,22 @@ impl CloudRecording {
         meeting_id: i64,
         body: &crate::types::RecordingRegistrantStatus,
     ) -> ClientResult<()> {
+        self.meeting_recording_registrant_status_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update registrant's status.
+     *
+     * This function performs a `PUT` to the `/meetings/{meetingId}/recordings/registrants/status` endpoint.
+     *
+     * As opposed to `meeting_recording_registrant_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_recording_registrant_status_with_response(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::RecordingRegistrantStatus,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/registrants/status",
@@ -551,7 +757,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -585,6 +791,22 @@ impl CloudRecording {
         &self,
         meeting_id: &str,
     ) -> ClientResult<crate::types::RecordingRegistrantQuestionsData> {
+        Ok(self
+            .recording_registrants_questions_get_with_response(meeting_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get registration questions.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/recordings/registrants/questions` endpoint.
+     *
+     * As opposed to `recording_registrants_questions_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_registrants_questions_get_with_response(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::RecordingRegistrantQuestionsData>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/registrants/questions",
@@ -593,7 +815,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -627,6 +849,22 @@ impl CloudRecording {
         meeting_id: &str,
         body: &crate::types::RecordingRegistrantQuestionsData,
     ) -> ClientResult<()> {
+        self.recording_registrant_question_update_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update registration questions.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/recordings/registrants/questions` endpoint.
+     *
+     * As opposed to `recording_registrant_question_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_registrant_question_update_with_response(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingRegistrantQuestionsData,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/recordings/registrants/questions",
@@ -635,7 +873,7 @@ impl CloudRecording {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -678,6 +916,27 @@ impl CloudRecording {
         from: Option<chrono::DateTime<chrono::Utc>>,
         to: Option<chrono::DateTime<chrono::Utc>>,
     ) -> ClientResult<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
+        Ok(self
+            .get_account_with_response(account_id, page_size, next_page_token, from, to)
+            .await?
+            .body)
+    }
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_account_with_response(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if let Some(date) = from {
             query_args.push(("from".to_string(), date.to_rfc3339()));
@@ -700,9 +959,9 @@ impl CloudRecording {
             ),
             None,
         );
-        let resp: crate::types::GetAccountCloudRecordingResponse = self
+        let resp: crate::Response<crate::types::GetAccountCloudRecordingResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -712,7 +971,7 @@ impl CloudRecording {
             .await?;
 
         // Return our response data.
-        Ok(resp.meetings.to_vec())
+        Ok(resp.map(|r| r.meetings.to_vec()))
     }
     /**
      * List recordings of an account.
@@ -809,4 +1068,105 @@ impl CloudRecording {
         // Return our response data.
         Ok(meetings)
     }
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_account`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_account_pages_stream(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<
+            crate::utils::Page<crate::types::GetAccountCloudRecordingResponseMeetings>,
+        >,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = from {
+            query_args.push(("from".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = to {
+            query_args.push(("to".to_string(), date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/accounts/{}/recordings?{}",
+                crate::progenitor_support::encode_path(account_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::GetAccountCloudRecordingResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.meetings;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_account`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_account_stream(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::GetAccountCloudRecordingResponseMeetings>,
+    > + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_account_pages_stream(account_id, from, to, None))
+    }
 }
@@ -38,6 +38,23 @@ impl CommonAreaPhones {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::CommonAreaPhones>> {
+        Ok(self
+            .list_with_response(page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CommonAreaPhones>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -49,9 +66,9 @@ impl CommonAreaPhones {
         let url = self
             .client
             .url(&format!("/phone/common_area_phones?{}", query_), None);
-        let resp: crate::types::ListCommonAreaPhonesResponse = self
+        let resp: crate::Response<crate::types::ListCommonAreaPhonesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -61,7 +78,7 @@ impl CommonAreaPhones {
             .await?;
 
         // Return our response data.
-        Ok(resp.common_area_phones.to_vec())
+        Ok(resp.map(|r| r.common_area_phones.to_vec()))
     }
     /**
      * List common area phones.
@@ -137,6 +154,81 @@ impl CommonAreaPhones {
         // Return our response data.
         Ok(common_area_phones)
     }
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CommonAreaPhones>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/phone/common_area_phones", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListCommonAreaPhonesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.common_area_phones;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CommonAreaPhones>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pages_stream(None))
+    }
     /**
      * Add a common area phone.
      *
@@ -158,9 +250,22 @@ impl CommonAreaPhones {
         &self,
         body: &crate::types::AddCommonAreaPhoneRequest,
     ) -> ClientResult<crate::types::AddCommonAreaPhoneResponse> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * Add a common area phone.
+     *
+     * This function performs a `POST` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::AddCommonAreaPhoneRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddCommonAreaPhoneResponse>> {
         let url = self.client.url("/phone/common_area_phones", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -193,6 +298,19 @@ impl CommonAreaPhones {
         &self,
         common_area_phone_id: &str,
     ) -> ClientResult<crate::types::GetCommonAreaPhoneResponse> {
+        Ok(self.get_with_response(common_area_phone_id).await?.body)
+    }
+    /**
+     * Get common area phone details.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones/{commonAreaPhoneId}` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        common_area_phone_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCommonAreaPhoneResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/common_area_phones/{}",
@@ -201,7 +319,7 @@ impl CommonAreaPhones {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -229,6 +347,20 @@ impl CommonAreaPhones {
      * * `common_area_phone_id: &str` -- Unique Identifier of the common area phone.
      */
     pub async fn delete(&self, common_area_phone_id: &str) -> ClientResult<()> {
+        self.delete_with_response(common_area_phone_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a common area phone.
+     *
+     * This function performs a `DELETE` to the `/phone/common_area_phones/{commonAreaPhoneId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        common_area_phone_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/common_area_phones/{}",
@@ -237,7 +369,7 @@ impl CommonAreaPhones {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -268,6 +400,22 @@ impl CommonAreaPhones {
         common_area_phone_id: &str,
         body: &crate::types::UpdateCommonAreaPhoneRequest,
     ) -> ClientResult<()> {
+        self.update_with_response(common_area_phone_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update common area phone.
+     *
+     * This function performs a `PATCH` to the `/phone/common_area_phones/{commonAreaPhoneId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::UpdateCommonAreaPhoneRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/common_area_phones/{}",
@@ -276,7 +424,7 @@ impl CommonAreaPhones {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -304,6 +452,23 @@ impl CommonAreaPhones {
         common_area_phone_id: &str,
         body: &crate::types::AssignPhoneNumbersCommonAreaRequest,
     ) -> ClientResult<crate::types::AssignPhoneNumbersCommonAreaResponseData> {
+        Ok(self
+            .assign_phone_numbers_common_area_with_response(common_area_phone_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Assign phone numbers to common area phone.
+     *
+     * This function performs a `POST` to the `/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers` endpoint.
+     *
+     * As opposed to `assign_phone_numbers_common_area`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_phone_numbers_common_area_with_response(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignPhoneNumbersCommonAreaRequest,
+    ) -> ClientResult<crate::Response<crate::types::AssignPhoneNumbersCommonAreaResponseData>> {
         let url = self.client.url(
             &format!(
                 "/phone/common_area_phones/{}/phone_numbers",
@@ -312,7 +477,7 @@ impl CommonAreaPhones {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -344,6 +509,25 @@ impl CommonAreaPhones {
         common_area_phone_id: &str,
         phone_number_id: &str,
     ) -> ClientResult<()> {
+        self.unassign_phone_numbers_from_common_area_with_response(
+            common_area_phone_id,
+            phone_number_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Unassign phone numbers from a common area phone.
+     *
+     * This function performs a `DELETE` to the `/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `unassign_phone_numbers_from_common_area`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_phone_numbers_from_common_area_with_response(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/common_area_phones/{}/phone_numbers/{}",
@@ -353,7 +537,7 @@ impl CommonAreaPhones {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -381,6 +565,24 @@ impl CommonAreaPhones {
         common_area_phone_id: &str,
         body: &crate::types::AssignCallingPlansCommonAreaPhoneRequestData,
     ) -> ClientResult<crate::types::AssignCallingPlansCommonAreaPhoneResponseData> {
+        Ok(self
+            .assign_calling_plans_with_response(common_area_phone_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Assign calling plans to common area phone.
+     *
+     * This function performs a `POST` to the `/phone/common_area_phones/{commonAreaPhoneId}/calling_plans` endpoint.
+     *
+     * As opposed to `assign_calling_plans`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_calling_plans_with_response(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignCallingPlansCommonAreaPhoneRequestData,
+    ) -> ClientResult<crate::Response<crate::types::AssignCallingPlansCommonAreaPhoneResponseData>>
+    {
         let url = self.client.url(
             &format!(
                 "/phone/common_area_phones/{}/calling_plans",
@@ -389,7 +591,7 @@ impl CommonAreaPhones {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -421,6 +623,22 @@ impl CommonAreaPhones {
         common_area_phone_id: &str,
         type_: &str,
     ) -> ClientResult<()> {
+        self.unassign_calling_plans_from_with_response(common_area_phone_id, type_)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign calling plan from a common area phone.
+     *
+     * This function performs a `DELETE` to the `/phone/common_area_phones/{commonAreaPhoneId}/calling_plans/{type}` endpoint.
+     *
+     * As opposed to `unassign_calling_plans_from`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_calling_plans_from_with_response(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/common_area_phones/{}/calling_plans/{}",
@@ -430,7 +648,7 @@ impl CommonAreaPhones {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
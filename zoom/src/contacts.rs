@@ -36,6 +36,30 @@ impl Contacts {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::Contacts>> {
+        Ok(self
+            .search_company_with_response(
+                search_key,
+                query_presence_status,
+                page_size,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `search_company`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn search_company_with_response(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Contacts>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -54,9 +78,9 @@ impl Contacts {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/contacts?{}", query_), None);
-        let resp: crate::types::SearchCompanyContactsResponse = self
+        let resp: crate::Response<crate::types::SearchCompanyContactsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -66,7 +90,7 @@ impl Contacts {
             .await?;
 
         // Return our response data.
-        Ok(resp.contacts.to_vec())
+        Ok(resp.map(|r| r.contacts.to_vec()))
     }
     /**
      * Search company contacts.
@@ -151,6 +175,98 @@ impl Contacts {
         // Return our response data.
         Ok(contacts)
     }
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `get_all_search_company`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_search_company_pages_stream(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Contacts>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query_presence_status.is_empty() {
+            query_args.push((
+                "query_presence_status".to_string(),
+                query_presence_status.to_string(),
+            ));
+        }
+        if !search_key.is_empty() {
+            query_args.push(("search_key".to_string(), search_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/contacts?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::SearchCompanyContactsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.contacts;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `get_all_search_company`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_search_company_stream(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Contacts>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_search_company_pages_stream(
+            search_key,
+            query_presence_status,
+            None,
+        ))
+    }
     /**
      * List user's contacts.
      *
@@ -178,6 +294,24 @@ impl Contacts {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::GetUserContactsResponse>> {
+        Ok(self
+            .get_user_with_response(type_, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_with_response(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetUserContactsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -192,9 +326,9 @@ impl Contacts {
         let url = self
             .client
             .url(&format!("/chat/users/me/contacts?{}", query_), None);
-        let resp: crate::types::GetUserContactsResponseData = self
+        let resp: crate::Response<crate::types::GetUserContactsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -204,7 +338,7 @@ impl Contacts {
             .await?;
 
         // Return our response data.
-        Ok(resp.contacts.to_vec())
+        Ok(resp.map(|r| r.contacts.to_vec()))
     }
     /**
      * List user's contacts.
@@ -286,6 +420,91 @@ impl Contacts {
         // Return our response data.
         Ok(contacts)
     }
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_all_user`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_user_pages_stream(
+        &self,
+        type_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetUserContactsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/chat/users/me/contacts?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::GetUserContactsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.contacts;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_all_user`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_user_stream(
+        &self,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetUserContactsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_user_pages_stream(type_, None))
+    }
     /**
      * Get user's contact details.
      *
@@ -309,6 +528,23 @@ impl Contacts {
         contact_id: &str,
         query_presence_status: bool,
     ) -> ClientResult<crate::types::GetUserContactResponse> {
+        Ok(self
+            .get_user_contacts_with_response(contact_id, query_presence_status)
+            .await?
+            .body)
+    }
+    /**
+     * Get user's contact details.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts/{contactId}` endpoint.
+     *
+     * As opposed to `get_user_contacts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_user_contacts_with_response(
+        &self,
+        contact_id: &str,
+        query_presence_status: bool,
+    ) -> ClientResult<crate::Response<crate::types::GetUserContactResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if query_presence_status {
             query_args.push((
@@ -326,7 +562,7 @@ impl Contacts {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
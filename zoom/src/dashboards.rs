@@ -42,6 +42,27 @@ impl Dashboards {
         next_page_token: &str,
         include_fields: crate::types::IncludeFields,
     ) -> ClientResult<crate::types::DashboardMeetingsResponseAllOf> {
+        Ok(self
+            .meeting_with_response(type_, from, to, page_size, next_page_token, include_fields)
+            .await?
+            .body)
+    }
+    /**
+     * List meetings.
+     *
+     * This function performs a `GET` to the `/metrics/meetings` endpoint.
+     *
+     * As opposed to `meeting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_with_response(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::IncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -66,7 +87,7 @@ impl Dashboards {
             .client
             .url(&format!("/metrics/meetings?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -100,6 +121,23 @@ impl Dashboards {
         meeting_id: &str,
         type_: crate::types::DashboardMeetingsType,
     ) -> ClientResult<crate::types::MeetingMetric> {
+        Ok(self
+            .meeting_detail_with_response(meeting_id, type_)
+            .await?
+            .body)
+    }
+    /**
+     * Get meeting details.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `meeting_detail`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_detail_with_response(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> ClientResult<crate::Response<crate::types::MeetingMetric>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -114,7 +152,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -155,6 +193,33 @@ impl Dashboards {
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> ClientResult<crate::types::DashboardMeetingParticipantsResponseAllOf> {
+        Ok(self
+            .meeting_participant_with_response(
+                meeting_id,
+                type_,
+                page_size,
+                next_page_token,
+                include_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List meeting participants.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants` endpoint.
+     *
+     * As opposed to `meeting_participant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_participant_with_response(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantsResponseAllOf>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
@@ -178,7 +243,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -215,6 +280,24 @@ impl Dashboards {
         participant_id: &str,
         type_: crate::types::DashboardMeetingsType,
     ) -> ClientResult<crate::types::ParticipantQos> {
+        Ok(self
+            .meeting_participant_qo_with_response(meeting_id, participant_id, type_)
+            .await?
+            .body)
+    }
+    /**
+     * Get meeting participant QoS.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/{participantId}/qos` endpoint.
+     *
+     * As opposed to `meeting_participant_qo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_participant_qo_with_response(
+        &self,
+        meeting_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -230,7 +313,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -268,6 +351,25 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .meeting_participants_qo_with_response(meeting_id, type_, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List meeting participants QoS.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/qos` endpoint.
+     *
+     * As opposed to `meeting_participants_qo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_participants_qo_with_response(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -288,7 +390,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -326,6 +428,26 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
+        Ok(self
+            .meeting_participant_share_with_response(meeting_id, type_, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get sharing/recording details.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/sharing` endpoint.
+     *
+     * As opposed to `meeting_participant_share`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_participant_share_with_response(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -346,7 +468,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -385,6 +507,26 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::DashboardWebinarsResponseAllOf> {
+        Ok(self
+            .webinar_with_response(type_, from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List webinars.
+     *
+     * This function performs a `GET` to the `/metrics/webinars` endpoint.
+     *
+     * As opposed to `webinar`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_with_response(
+        &self,
+        type_: crate::types::DashboardWebinarsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -406,7 +548,7 @@ impl Dashboards {
             .client
             .url(&format!("/metrics/webinars?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -440,6 +582,23 @@ impl Dashboards {
         webinar_id: &str,
         type_: crate::types::DashboardWebinarsType,
     ) -> ClientResult<crate::types::Webinars> {
+        Ok(self
+            .webinar_detail_with_response(webinar_id, type_)
+            .await?
+            .body)
+    }
+    /**
+     * Get webinar details.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `webinar_detail`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_detail_with_response(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> ClientResult<crate::Response<crate::types::Webinars>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -454,7 +613,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -494,6 +653,33 @@ impl Dashboards {
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> ClientResult<crate::types::DashboardWebinarParticipantsResponseAllOf> {
+        Ok(self
+            .webinar_participant_with_response(
+                webinar_id,
+                type_,
+                page_size,
+                next_page_token,
+                include_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get webinar participants.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `webinar_participant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_participant_with_response(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::DashboardWebinarParticipantsResponseAllOf>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
@@ -517,7 +703,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -552,6 +738,24 @@ impl Dashboards {
         participant_id: &str,
         type_: crate::types::DashboardWebinarsType,
     ) -> ClientResult<crate::types::ParticipantQos> {
+        Ok(self
+            .webinar_participant_qo_with_response(webinar_id, participant_id, type_)
+            .await?
+            .body)
+    }
+    /**
+     * Get webinar participant QoS.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/{participantId}/qos` endpoint.
+     *
+     * As opposed to `webinar_participant_qo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_participant_qo_with_response(
+        &self,
+        webinar_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> ClientResult<crate::Response<crate::types::ParticipantQos>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !type_.to_string().is_empty() {
             query_args.push(("type".to_string(), type_.to_string()));
@@ -567,7 +771,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -605,6 +809,25 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .webinar_participants_qo_with_response(webinar_id, type_, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List webinar participant QoS.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/qos` endpoint.
+     *
+     * As opposed to `webinar_participants_qo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_participants_qo_with_response(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -625,7 +848,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -663,6 +886,26 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
+        Ok(self
+            .webinar_participant_share_with_response(webinar_id, type_, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get sharing/recording details.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/sharing` endpoint.
+     *
+     * As opposed to `webinar_participant_share`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_participant_share_with_response(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardMeetingParticipantShareResponseAllOf>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -683,7 +926,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -719,6 +962,24 @@ impl Dashboards {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .zoom_room_with_response(page_size, page_number, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/metrics/zoomrooms` endpoint.
+     *
+     * As opposed to `zoom_room`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn zoom_room_with_response(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -734,7 +995,7 @@ impl Dashboards {
             .client
             .url(&format!("/metrics/zoomrooms?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -773,6 +1034,26 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .zoom_room_dashboards_with_response(zoomroom_id, from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get Zoom Rooms details.
+     *
+     * This function performs a `GET` to the `/metrics/zoomrooms/{zoomroomId}` endpoint.
+     *
+     * As opposed to `zoom_room_dashboards`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn zoom_room_dashboards_with_response(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -796,7 +1077,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -829,6 +1110,20 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self.crc_with_response(from, to).await?.body)
+    }
+    /**
+     * Get CRC port usage.
+     *
+     * This function performs a `GET` to the `/metrics/crc` endpoint.
+     *
+     * As opposed to `crc`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn crc_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -839,7 +1134,7 @@ impl Dashboards {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/metrics/crc?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -877,6 +1172,25 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::DashboardImResponseAllOf> {
+        Ok(self
+            .im_with_response(from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get IM metrics.
+     *
+     * This function performs a `GET` to the `/metrics/im` endpoint.
+     *
+     * As opposed to `im`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn im_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardImResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -893,7 +1207,7 @@ impl Dashboards {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/metrics/im?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -933,6 +1247,25 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::DashboardChatResponseAllOf> {
+        Ok(self
+            .chat_with_response(from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get chat metrics.
+     *
+     * This function performs a `GET` to the `/metrics/chat` endpoint.
+     *
+     * As opposed to `chat`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn chat_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardChatResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -949,7 +1282,7 @@ impl Dashboards {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/metrics/chat?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -983,6 +1316,20 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<crate::types::DashboardClientFeedbackResponse> {
+        Ok(self.client_feedback_with_response(from, to).await?.body)
+    }
+    /**
+     * List Zoom meetings client feedback.
+     *
+     * This function performs a `GET` to the `/metrics/client/feedback` endpoint.
+     *
+     * As opposed to `client_feedback`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn client_feedback_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -995,7 +1342,7 @@ impl Dashboards {
             .client
             .url(&format!("/metrics/client/feedback?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1028,6 +1375,20 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self.zoom_room_issue_with_response(from, to).await?.body)
+    }
+    /**
+     * Get top 25 issues of Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/metrics/zoomrooms/issues` endpoint.
+     *
+     * As opposed to `zoom_room_issue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn zoom_room_issue_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1040,7 +1401,7 @@ impl Dashboards {
             .client
             .url(&format!("/metrics/zoomrooms/issues?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1071,6 +1432,20 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<crate::types::DashboardIssueZoomRoomResponseAllOf> {
+        Ok(self.issue_zoom_room_with_response(from, to).await?.body)
+    }
+    /**
+     * Get top 25 Zoom Rooms with issues.
+     *
+     * This function performs a `GET` to the `/metrics/issues/zoomrooms` endpoint.
+     *
+     * As opposed to `issue_zoom_room`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn issue_zoom_room_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::DashboardIssueZoomRoomResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1083,7 +1458,7 @@ impl Dashboards {
             .client
             .url(&format!("/metrics/issues/zoomrooms?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1121,6 +1496,27 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::DashboardIssueDetailZoomRoomResponseAllOf> {
+        Ok(self
+            .issue_detail_zoom_room_with_response(zoomroom_id, from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get issues of Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/metrics/issues/zoomrooms/{zoomroomId}` endpoint.
+     *
+     * As opposed to `issue_detail_zoom_room`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn issue_detail_zoom_room_with_response(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardIssueDetailZoomRoomResponseAllOf>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1144,7 +1540,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1185,6 +1581,27 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::DashboardClientFeedbackDetailResponseAllOf> {
+        Ok(self
+            .client_feedback_detail_with_response(feedback_id, from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get zoom meetings client feedback.
+     *
+     * This function performs a `GET` to the `/metrics/client/feedback/{feedbackId}` endpoint.
+     *
+     * As opposed to `client_feedback_detail`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn client_feedback_detail_with_response(
+        &self,
+        feedback_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::DashboardClientFeedbackDetailResponseAllOf>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1208,7 +1625,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1238,6 +1655,23 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<crate::types::ListMeetingSatisfactionResponse> {
+        Ok(self
+            .list_meeting_satisfaction_with_response(from, to)
+            .await?
+            .body)
+    }
+    /**
+     * List client meeting satisfaction.
+     *
+     * This function performs a `GET` to the `/metrics/client/satisfaction` endpoint.
+     *
+     * As opposed to `list_meeting_satisfaction`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_meeting_satisfaction_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::ListMeetingSatisfactionResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1250,7 +1684,7 @@ impl Dashboards {
             .client
             .url(&format!("/metrics/client/satisfaction?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1300,6 +1734,34 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        Ok(self
+            .list_call_logs_metrics_with_response(
+                from,
+                to,
+                site_id,
+                quality_type,
+                page_size,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_call_logs_metrics_with_response(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListCallLogsMetricsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1323,9 +1785,9 @@ impl Dashboards {
         let url = self
             .client
             .url(&format!("/phone/metrics/call_logs?{}", query_), None);
-        let resp: crate::types::ListCallLogsMetricsResponseData = self
+        let resp: crate::Response<crate::types::ListCallLogsMetricsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1335,7 +1797,7 @@ impl Dashboards {
             .await?;
 
         // Return our response data.
-        Ok(resp.call_logs.to_vec())
+        Ok(resp.map(|r| r.call_logs.to_vec()))
     }
     /**
      * List call logs.
@@ -1432,6 +1894,112 @@ impl Dashboards {
         // Return our response data.
         Ok(call_logs)
     }
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_all_call_logs_metrics`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_call_logs_metrics_pages_stream(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListCallLogsMetricsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/metrics/call_logs?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListCallLogsMetricsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.call_logs;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_all_call_logs_metrics`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_call_logs_metrics_stream(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListCallLogsMetricsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_call_logs_metrics_pages_stream(
+            from,
+            to,
+            site_id,
+            quality_type,
+            None,
+        ))
+    }
     /**
      * Get call details from call log.
      *
@@ -1457,6 +2025,22 @@ impl Dashboards {
         &self,
         call_id: &str,
     ) -> ClientResult<crate::types::ListCallLogsMetricsResponse> {
+        Ok(self
+            .get_call_log_metrics_details_with_response(call_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get call details from call log.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs/{call_id}` endpoint.
+     *
+     * As opposed to `get_call_log_metrics_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_call_log_metrics_details_with_response(
+        &self,
+        call_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListCallLogsMetricsResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/metrics/call_logs/{}",
@@ -1465,7 +2049,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1495,6 +2079,19 @@ impl Dashboards {
         &self,
         call_id: &str,
     ) -> ClientResult<crate::types::GetCallQoSResponse> {
+        Ok(self.get_call_qo_with_response(call_id).await?.body)
+    }
+    /**
+     * Get call QoS.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs/{callId}/qos` endpoint.
+     *
+     * As opposed to `get_call_qo`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_call_qo_with_response(
+        &self,
+        call_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCallQoSResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/metrics/call_logs/{}/qos",
@@ -1503,7 +2100,7 @@ impl Dashboards {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1545,6 +2142,26 @@ impl Dashboards {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        Ok(self
+            .participant_feedback_with_response(meeting_id, type_, next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_feedback`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn participant_feedback_with_response(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -1564,9 +2181,9 @@ impl Dashboards {
             ),
             None,
         );
-        let resp: crate::types::ParticipantFeedbackResponse = self
+        let resp: crate::Response<crate::types::ParticipantFeedbackResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1576,7 +2193,7 @@ impl Dashboards {
             .await?;
 
         // Return our response data.
-        Ok(resp.participants.to_vec())
+        Ok(resp.map(|r| r.participants.to_vec()))
     }
     /**
      * Get post meeting feedback.
@@ -1666,6 +2283,104 @@ impl Dashboards {
         // Return our response data.
         Ok(participants)
     }
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_feedback`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_participant_feedback_pages_stream(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<
+            crate::utils::Page<crate::types::ParticipantFeedbackResponseParticipants>,
+        >,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(meeting_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ParticipantFeedbackResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.participants;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_feedback`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_participant_feedback_stream(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::ParticipantFeedbackResponseParticipants>,
+    > + Unpin
+           + '_ {
+        crate::utils::page_items(
+            self.get_all_participant_feedback_pages_stream(meeting_id, type_, None),
+        )
+    }
     /**
      * Get post webinar feedback.
      *
@@ -1700,6 +2415,31 @@ impl Dashboards {
         next_page_token: &str,
         webinar_id: &str,
     ) -> ClientResult<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        Ok(self
+            .participant_webinar_feedback_with_response(
+                type_,
+                page_size,
+                next_page_token,
+                webinar_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_webinar_feedback`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn participant_webinar_feedback_with_response(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ParticipantFeedbackResponseParticipants>>>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -1719,9 +2459,9 @@ impl Dashboards {
             ),
             None,
         );
-        let resp: crate::types::ParticipantFeedbackResponse = self
+        let resp: crate::Response<crate::types::ParticipantFeedbackResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1731,7 +2471,7 @@ impl Dashboards {
             .await?;
 
         // Return our response data.
-        Ok(resp.participants.to_vec())
+        Ok(resp.map(|r| r.participants.to_vec()))
     }
     /**
      * Get post webinar feedback.
@@ -1822,4 +2562,102 @@ impl Dashboards {
         // Return our response data.
         Ok(participants)
     }
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_webinar_feedback`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_participant_webinar_feedback_pages_stream(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<
+            crate::utils::Page<crate::types::ParticipantFeedbackResponseParticipants>,
+        >,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction?{}",
+                crate::progenitor_support::encode_path(webinar_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ParticipantFeedbackResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.participants;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `get_all_participant_webinar_feedback`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_participant_webinar_feedback_stream(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::types::ParticipantFeedbackResponseParticipants>,
+    > + Unpin
+           + '_ {
+        crate::utils::page_items(
+            self.get_all_participant_webinar_feedback_pages_stream(type_, webinar_id, None),
+        )
+    }
 }
@@ -34,6 +34,22 @@ impl DeprecatedApiEndpoints {
         &self,
         meeting_id: &str,
     ) -> ClientResult<crate::types::ListPastMeetingFilesResponse> {
+        Ok(self
+            .list_past_meeting_files_with_response(meeting_id)
+            .await?
+            .body)
+    }
+    /**
+     * List past meeting's files.
+     *
+     * This function performs a `GET` to the `/past_meetings/{meetingId}/files` endpoint.
+     *
+     * As opposed to `list_past_meeting_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_past_meeting_files_with_response(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListPastMeetingFilesResponse>> {
         let url = self.client.url(
             &format!(
                 "/past_meetings/{}/files",
@@ -42,7 +58,7 @@ impl DeprecatedApiEndpoints {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -74,6 +90,22 @@ impl DeprecatedApiEndpoints {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::ListPastMeetingFilesResponse> {
+        Ok(self
+            .list_past_webinar_files_with_response(webinar_id)
+            .await?
+            .body)
+    }
+    /**
+     * List past webinar files.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/files` endpoint.
+     *
+     * As opposed to `list_past_webinar_files`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_past_webinar_files_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListPastMeetingFilesResponse>> {
         let url = self.client.url(
             &format!(
                 "/past_webinars/{}/files",
@@ -82,7 +114,7 @@ impl DeprecatedApiEndpoints {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -36,6 +36,24 @@ impl Devices {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .list_with_response(page_size, page_number, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List H.323/SIP devices.
+     *
+     * This function performs a `GET` to the `/h323/devices` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -49,7 +67,7 @@ impl Devices {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/h323/devices?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -69,9 +87,23 @@ impl Devices {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light` <br>
      */
     pub async fn create(&self, body: &crate::types::Device) -> ClientResult<()> {
+        self.create_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Create a H.323/SIP device.
+     *
+     * This function performs a `POST` to the `/h323/devices` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::Device,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/h323/devices", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -95,6 +127,17 @@ impl Devices {
      * * `device_id: &str` -- User's first name.
      */
     pub async fn delete(&self, device_id: &str) -> ClientResult<()> {
+        self.delete_with_response(device_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a H.323/SIP device.
+     *
+     * This function performs a `DELETE` to the `/h323/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, device_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/h323/devices/{}",
@@ -103,7 +146,7 @@ impl Devices {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,6 +170,21 @@ impl Devices {
      * * `device_id: &str` -- User's first name.
      */
     pub async fn update(&self, device_id: &str, body: &crate::types::Device) -> ClientResult<()> {
+        self.update_with_response(device_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update a H.323/SIP device.
+     *
+     * This function performs a `PATCH` to the `/h323/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        device_id: &str,
+        body: &crate::types::Device,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/h323/devices/{}",
@@ -135,7 +193,7 @@ impl Devices {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
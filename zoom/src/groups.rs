@@ -24,9 +24,21 @@ impl Groups {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
      */
     pub async fn get(&self) -> ClientResult<crate::types::GroupList> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * List groups.
+     *
+     * This function performs a `GET` to the `/groups` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GroupList>> {
         let url = self.client.url("/groups", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,9 +62,23 @@ impl Groups {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      */
     pub async fn create(&self, body: &crate::types::GroupCreateRequest) -> ClientResult<()> {
+        self.create_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Create a group.
+     *
+     * This function performs a `POST` to the `/groups` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::GroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/groups", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -79,6 +105,19 @@ impl Groups {
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
      */
     pub async fn group(&self, group_id: &str) -> ClientResult<crate::types::GroupResponse> {
+        Ok(self.group_with_response(group_id).await?.body)
+    }
+    /**
+     * Get a group.
+     *
+     * This function performs a `GET` to the `/groups/{groupId}` endpoint.
+     *
+     * As opposed to `group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn group_with_response(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GroupResponse>> {
         let url = self.client.url(
             &format!(
                 "/groups/{}",
@@ -87,7 +126,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -114,6 +153,17 @@ impl Groups {
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
      */
     pub async fn delete(&self, group_id: &str) -> ClientResult<()> {
+        self.delete_with_response(group_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a group.
+     *
+     * This function performs a `DELETE` to the `/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, group_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/groups/{}",
@@ -122,7 +172,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -153,6 +203,21 @@ impl Groups {
         group_id: &str,
         body: &crate::types::GroupCreateRequest,
     ) -> ClientResult<()> {
+        self.update_with_response(group_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update a group.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::GroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/groups/{}",
@@ -161,7 +226,7 @@ impl Groups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -200,6 +265,25 @@ impl Groups {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::UserCreateResponse>> {
+        Ok(self
+            .members_with_response(group_id, page_size, page_number, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn members_with_response(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UserCreateResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -219,9 +303,9 @@ impl Groups {
             ),
             None,
         );
-        let resp: crate::types::GroupMembersResponseData = self
+        let resp: crate::Response<crate::types::GroupMembersResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -231,7 +315,7 @@ impl Groups {
             .await?;
 
         // Return our response data.
-        Ok(resp.members.to_vec())
+        Ok(resp.map(|r| r.members.to_vec()))
     }
     /**
      * List group members .
@@ -311,6 +395,90 @@ impl Groups {
         // Return our response data.
         Ok(members)
     }
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_members_pages_stream(
+        &self,
+        group_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::UserCreateResponse>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/groups/{}/members",
+                crate::progenitor_support::encode_path(group_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::GroupMembersResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.members;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_members_stream(
+        &self,
+        group_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UserCreateResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_members_pages_stream(group_id, None))
+    }
     /**
      * Add group members.
      *
@@ -333,6 +501,21 @@ impl Groups {
         group_id: &str,
         body: &crate::types::AddRoleMembersRequest,
     ) -> ClientResult<()> {
+        self.members_create_with_response(group_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Add group members.
+     *
+     * This function performs a `POST` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn members_create_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/groups/{}/members",
@@ -341,7 +524,7 @@ impl Groups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -369,6 +552,22 @@ impl Groups {
      * * `member_id: &str` -- User's first name.
      */
     pub async fn members_delete(&self, group_id: &str, member_id: &str) -> ClientResult<()> {
+        self.members_delete_with_response(group_id, member_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a group member.
+     *
+     * This function performs a `DELETE` to the `/groups/{groupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `members_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn members_delete_with_response(
+        &self,
+        group_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/groups/{}/members/{}",
@@ -378,7 +577,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -416,6 +615,23 @@ impl Groups {
         member_id: &str,
         body: &crate::types::UpdateGroupMemberRequest,
     ) -> ClientResult<()> {
+        self.update_member_with_response(group_id, member_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a group member.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `update_member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_member_with_response(
+        &self,
+        group_id: &str,
+        member_id: &str,
+        body: &crate::types::UpdateGroupMemberRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/groups/{}/members/{}",
@@ -425,7 +641,7 @@ impl Groups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -621,6 +837,24 @@ impl Groups {
         custom_query_fields: &str,
         option: crate::types::OptionData,
     ) -> ClientResult<crate::types::GetGroupSettingsResponseOneOf> {
+        Ok(self
+            .get_setting_with_response(group_id, custom_query_fields, option)
+            .await?
+            .body)
+    }
+    /**
+     * Get a group's settings.
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/settings` endpoint.
+     *
+     * As opposed to `get_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_setting_with_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupSettingsResponseOneOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -641,7 +875,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -673,6 +907,24 @@ impl Groups {
         option: crate::types::UpdateGroupSettingsOption,
         body: &crate::types::UpdateGroupSettingsRequestOneOf,
     ) -> ClientResult<()> {
+        self.update_settings_with_response(group_id, custom_query_fields, option, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a group's settings.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/settings` endpoint.
+     *
+     * As opposed to `update_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_settings_with_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::UpdateGroupSettingsOption,
+        body: &crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -693,7 +945,7 @@ impl Groups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -829,6 +1081,24 @@ impl Groups {
         custom_query_fields: &str,
         option: &str,
     ) -> ClientResult<crate::types::GetGroupLockSettingsResponseOneOf> {
+        Ok(self
+            .get_lock_setting_with_response(group_id, custom_query_fields, option)
+            .await?
+            .body)
+    }
+    /**
+     * Get locked settings.
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/lock_settings` endpoint.
+     *
+     * As opposed to `get_lock_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_lock_setting_with_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetGroupLockSettingsResponseOneOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -849,7 +1119,7 @@ impl Groups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -882,6 +1152,24 @@ impl Groups {
         option: &str,
         body: &crate::types::GroupLockedSettingsRequestOneOf,
     ) -> ClientResult<()> {
+        self.locked_settings_with_response(group_id, custom_query_fields, option, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update locked settings.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/lock_settings` endpoint.
+     *
+     * As opposed to `locked_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn locked_settings_with_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+        body: &crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -902,7 +1190,7 @@ impl Groups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -937,6 +1225,24 @@ impl Groups {
         group_id: &str,
         body: &crate::types::UploadVbRequest,
     ) -> ClientResult<crate::types::Files> {
+        Ok(self
+            .upload_vb_with_response(file_ids, group_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Upload virtual background files.
+     *
+     * This function performs a `POST` to the `/groups/{groupId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `upload_vb`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn upload_vb_with_response(
+        &self,
+        file_ids: &str,
+        group_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !file_ids.is_empty() {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
@@ -951,7 +1257,7 @@ impl Groups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -980,6 +1286,21 @@ impl Groups {
      * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
      */
     pub async fn del_vb(&self, file_ids: &str, group_id: &str) -> ClientResult<()> {
+        self.del_vb_with_response(file_ids, group_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/groups/{groupId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn del_vb_with_response(
+        &self,
+        file_ids: &str,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !file_ids.is_empty() {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
@@ -994,7 +1315,7 @@ impl Groups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
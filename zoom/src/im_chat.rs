@@ -39,6 +39,25 @@ impl ImChat {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::ImChatSessionsResponseAllOf> {
+        Ok(self
+            .session_with_response(from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get IM chat sessions.
+     *
+     * This function performs a `GET` to the `/im/chat/sessions` endpoint.
+     *
+     * As opposed to `session`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn session_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ImChatSessionsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -57,7 +76,7 @@ impl ImChat {
             .client
             .url(&format!("/im/chat/sessions?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -100,6 +119,26 @@ impl ImChat {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::ImChatMessagesResponseAllOf> {
+        Ok(self
+            .message_with_response(session_id, from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get IM chat messages.
+     *
+     * This function performs a `GET` to the `/im/chat/sessions/{sessionId}` endpoint.
+     *
+     * As opposed to `message`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn message_with_response(
+        &self,
+        session_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ImChatMessagesResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -123,7 +162,7 @@ impl ImChat {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -162,6 +201,34 @@ impl ImChat {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::ListimmessagesResponseMessages>> {
+        Ok(self
+            .list_im_messages_with_response(
+                user_id,
+                chat_user,
+                channel,
+                date,
+                page_size,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_im_messages`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_im_messages_with_response(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListimmessagesResponseMessages>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !channel.is_empty() {
             query_args.push(("channel".to_string(), channel.to_string()));
@@ -187,9 +254,9 @@ impl ImChat {
             ),
             None,
         );
-        let resp: crate::types::ListimmessagesResponse = self
+        let resp: crate::Response<crate::types::ListimmessagesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -199,7 +266,7 @@ impl ImChat {
             .await?;
 
         // Return our response data.
-        Ok(resp.messages.to_vec())
+        Ok(resp.map(|r| r.messages.to_vec()))
     }
     /**
      * Get user’s IM messages.
@@ -294,6 +361,111 @@ impl ImChat {
         // Return our response data.
         Ok(messages)
     }
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_all_im_messages`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_im_messages_pages_stream(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListimmessagesResponseMessages>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if !chat_user.is_empty() {
+            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+        }
+        if !date.is_empty() {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/im/users/{}/chat/messages?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListimmessagesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.messages;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_all_im_messages`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_im_messages_stream(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListimmessagesResponseMessages>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(
+            self.list_all_im_messages_pages_stream(user_id, chat_user, channel, date, None),
+        )
+    }
     /**
      * Send IM messages.
      *
@@ -312,6 +484,23 @@ impl ImChat {
         chat_user: &str,
         body: &crate::types::SendimmessagesRequest,
     ) -> ClientResult<crate::types::Groups> {
+        Ok(self
+            .send_im_messages_with_response(chat_user, body)
+            .await?
+            .body)
+    }
+    /**
+     * Send IM messages.
+     *
+     * This function performs a `POST` to the `/im/users/me/chat/messages` endpoint.
+     *
+     * As opposed to `send_im_messages`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn send_im_messages_with_response(
+        &self,
+        chat_user: &str,
+        body: &crate::types::SendimmessagesRequest,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !chat_user.is_empty() {
             query_args.push(("chat_user".to_string(), chat_user.to_string()));
@@ -321,7 +510,7 @@ impl ImChat {
             .client
             .url(&format!("/im/users/me/chat/messages?{}", query_), None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -22,9 +22,19 @@ impl ImGroups {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
      */
     pub async fn get(&self) -> ClientResult<crate::types::Domains> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * List IM directory groups.
+     *
+     * This function performs a `GET` to the `/im/groups` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(&self) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url("/im/groups", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -44,9 +54,23 @@ impl ImGroups {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      */
     pub async fn create(&self, body: &crate::types::ImGroupCreateRequest) -> ClientResult<()> {
+        self.create_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Create an IM directory group.
+     *
+     * This function performs a `POST` to the `/im/groups` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::ImGroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/im/groups", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -74,6 +98,19 @@ impl ImGroups {
         &self,
         group_id: &str,
     ) -> ClientResult<crate::types::ImGroupResponseAllOf> {
+        Ok(self.im_group_with_response(group_id).await?.body)
+    }
+    /**
+     * Retrieve an IM directory group.
+     *
+     * This function performs a `GET` to the `/im/groups/{groupId}` endpoint.
+     *
+     * As opposed to `im_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn im_group_with_response(
+        &self,
+        group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ImGroupResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/im/groups/{}",
@@ -82,7 +119,7 @@ impl ImGroups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -107,6 +144,17 @@ impl ImGroups {
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
      */
     pub async fn delete(&self, group_id: &str) -> ClientResult<()> {
+        self.delete_with_response(group_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete an IM directory group.
+     *
+     * This function performs a `DELETE` to the `/im/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, group_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/im/groups/{}",
@@ -115,7 +163,7 @@ impl ImGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -144,6 +192,21 @@ impl ImGroups {
         group_id: &str,
         body: &crate::types::ImGroupCreateRequest,
     ) -> ClientResult<()> {
+        self.update_with_response(group_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update an IM directory group.
+     *
+     * This function performs a `PATCH` to the `/im/groups/{groupId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::ImGroupCreateRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/im/groups/{}",
@@ -152,7 +215,7 @@ impl ImGroups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -189,6 +252,25 @@ impl ImGroups {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .member_with_response(group_id, page_size, page_number, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List IM directory group members.
+     *
+     * This function performs a `GET` to the `/im/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `member`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn member_with_response(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -209,7 +291,7 @@ impl ImGroups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -238,6 +320,21 @@ impl ImGroups {
         group_id: &str,
         body: &crate::types::AddRoleMembersRequest,
     ) -> ClientResult<()> {
+        self.members_create_with_response(group_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Add IM directory group members.
+     *
+     * This function performs a `POST` to the `/im/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn members_create_with_response(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/im/groups/{}/members",
@@ -246,7 +343,7 @@ impl ImGroups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -272,6 +369,22 @@ impl ImGroups {
      * * `member_id: &str` -- User's first name.
      */
     pub async fn members_delete(&self, group_id: &str, member_id: &str) -> ClientResult<()> {
+        self.members_delete_with_response(group_id, member_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete IM directory group member.
+     *
+     * This function performs a `DELETE` to the `/im/groups/{groupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `members_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn members_delete_with_response(
+        &self,
+        group_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/im/groups/{}/members/{}",
@@ -281,7 +394,7 @@ impl ImGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -38,6 +38,26 @@ impl Meetings {
         next_page_token: &str,
         page_number: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_with_response(user_id, type_, page_size, next_page_token, page_number)
+            .await?
+            .body)
+    }
+    /**
+     * List meetings.
+     *
+     * This function performs a `GET` to the `/users/{userId}/meetings` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        user_id: &str,
+        type_: crate::types::MeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        page_number: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -61,7 +81,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -97,6 +117,20 @@ impl Meetings {
         user_id: &str,
         body: &crate::types::MeetingCreate,
     ) -> ClientResult<crate::types::MeetingCreateResponseAllOf> {
+        Ok(self.create_with_response(user_id, body).await?.body)
+    }
+    /**
+     * Create a meeting.
+     *
+     * This function performs a `POST` to the `/users/{userId}/meetings` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::MeetingCreate,
+    ) -> ClientResult<crate::Response<crate::types::MeetingCreateResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/meetings",
@@ -105,7 +139,7 @@ impl Meetings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -140,6 +174,24 @@ impl Meetings {
         occurrence_id: &str,
         show_previous_occurrences: bool,
     ) -> ClientResult<crate::types::MeetingResponseAllOf> {
+        Ok(self
+            .meeting_with_response(meeting_id, occurrence_id, show_previous_occurrences)
+            .await?
+            .body)
+    }
+    /**
+     * Get a meeting.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `meeting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_with_response(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> ClientResult<crate::Response<crate::types::MeetingResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -160,7 +212,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -201,6 +253,29 @@ impl Meetings {
         schedule_for_reminder: bool,
         cancel_meeting_reminder: &str,
     ) -> ClientResult<()> {
+        self.delete_with_response(
+            meeting_id,
+            occurrence_id,
+            schedule_for_reminder,
+            cancel_meeting_reminder,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete a meeting.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cancel_meeting_reminder.is_empty() {
             query_args.push((
@@ -227,7 +302,7 @@ impl Meetings {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -258,6 +333,23 @@ impl Meetings {
         occurrence_id: &str,
         body: &crate::types::MeetingUpdateRequestAllOf,
     ) -> ClientResult<()> {
+        self.update_with_response(meeting_id, occurrence_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a meeting.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::MeetingUpdateRequestAllOf,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -272,7 +364,7 @@ impl Meetings {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -301,6 +393,21 @@ impl Meetings {
         meeting_id: i64,
         body: &crate::types::MeetingStatusRequest,
     ) -> ClientResult<()> {
+        self.status_with_response(meeting_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update meeting status.
+     *
+     * This function performs a `PUT` to the `/meetings/{meetingId}/status` endpoint.
+     *
+     * As opposed to `status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn status_with_response(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingStatusRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/status",
@@ -309,7 +416,7 @@ impl Meetings {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -351,6 +458,34 @@ impl Meetings {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .registrant_with_response(
+                meeting_id,
+                occurrence_id,
+                status,
+                page_size,
+                page_number,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List meeting registrants.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/registrants` endpoint.
+     *
+     * As opposed to `registrant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_with_response(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -377,7 +512,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -411,6 +546,23 @@ impl Meetings {
         meeting_id: i64,
         occurrence_ids: &str,
     ) -> ClientResult<crate::types::MeetingRegistrantCreateResponse> {
+        Ok(self
+            .registrant_create_with_response(meeting_id, occurrence_ids)
+            .await?
+            .body)
+    }
+    /**
+     * Add meeting registrant.
+     *
+     * This function performs a `POST` to the `/meetings/{meetingId}/registrants` endpoint.
+     *
+     * As opposed to `registrant_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_create_with_response(
+        &self,
+        meeting_id: i64,
+        occurrence_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRegistrantCreateResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_ids.is_empty() {
             query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
@@ -425,7 +577,7 @@ impl Meetings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -456,6 +608,23 @@ impl Meetings {
         meeting_id: i64,
         registrant_id: &str,
     ) -> ClientResult<()> {
+        self.meetingregistrantdelete_with_response(occurrence_id, meeting_id, registrant_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a meeting registrant.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `meetingregistrantdelete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meetingregistrantdelete_with_response(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -471,7 +640,7 @@ impl Meetings {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -502,6 +671,23 @@ impl Meetings {
         occurrence_id: &str,
         body: &crate::types::RegistrantStatus,
     ) -> ClientResult<()> {
+        self.registrant_status_with_response(meeting_id, occurrence_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update registrant's status.
+     *
+     * This function performs a `PUT` to the `/meetings/{meetingId}/registrants/status` endpoint.
+     *
+     * As opposed to `registrant_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_status_with_response(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -516,7 +702,7 @@ impl Meetings {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -546,6 +732,19 @@ impl Meetings {
         &self,
         meeting_uuid: &str,
     ) -> ClientResult<crate::types::PastMeetingDetailsResponse> {
+        Ok(self.past_details_with_response(meeting_uuid).await?.body)
+    }
+    /**
+     * Get past meeting details.
+     *
+     * This function performs a `GET` to the `/past_meetings/{meetingUUID}` endpoint.
+     *
+     * As opposed to `past_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn past_details_with_response(
+        &self,
+        meeting_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::PastMeetingDetailsResponse>> {
         let url = self.client.url(
             &format!(
                 "/past_meetings/{}",
@@ -554,7 +753,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -592,6 +791,24 @@ impl Meetings {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::PastMeetingParticipantsResponseAllOf> {
+        Ok(self
+            .past_participant_with_response(meeting_uuid, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get past meeting participants.
+     *
+     * This function performs a `GET` to the `/past_meetings/{meetingUUID}/participants` endpoint.
+     *
+     * As opposed to `past_participant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn past_participant_with_response(
+        &self,
+        meeting_uuid: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::PastMeetingParticipantsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -609,7 +826,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -635,6 +852,19 @@ impl Meetings {
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
      */
     pub async fn past(&self, meeting_id: i64) -> ClientResult<crate::types::Domains> {
+        Ok(self.past_with_response(meeting_id).await?.body)
+    }
+    /**
+     * List ended meeting instances.
+     *
+     * This function performs a `GET` to the `/past_meetings/{meetingId}/instances` endpoint.
+     *
+     * As opposed to `past`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn past_with_response(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/past_meetings/{}/instances",
@@ -643,7 +873,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -672,6 +902,19 @@ impl Meetings {
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
      */
     pub async fn poll(&self, meeting_id: i64) -> ClientResult<crate::types::Domains> {
+        Ok(self.poll_with_response(meeting_id).await?.body)
+    }
+    /**
+     * List meeting polls.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/polls` endpoint.
+     *
+     * As opposed to `poll`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_with_response(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/polls",
@@ -680,7 +923,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -714,6 +957,20 @@ impl Meetings {
         meeting_id: i64,
         body: &crate::types::Poll,
     ) -> ClientResult<crate::types::MeetingPollGetResponseAllOf> {
+        Ok(self.poll_create_with_response(meeting_id, body).await?.body)
+    }
+    /**
+     * Create a meeting poll.
+     *
+     * This function performs a `POST` to the `/meetings/{meetingId}/polls` endpoint.
+     *
+     * As opposed to `poll_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_create_with_response(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/polls",
@@ -722,7 +979,7 @@ impl Meetings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -756,6 +1013,20 @@ impl Meetings {
         meeting_id: i64,
         poll_id: &str,
     ) -> ClientResult<crate::types::MeetingPollGetResponseAllOf> {
+        Ok(self.poll_get_with_response(meeting_id, poll_id).await?.body)
+    }
+    /**
+     * Get a meeting poll.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_get_with_response(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/polls/{}",
@@ -765,7 +1036,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -799,6 +1070,23 @@ impl Meetings {
         poll_id: &str,
         body: &crate::types::Poll,
     ) -> ClientResult<()> {
+        self.poll_update_with_response(meeting_id, poll_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a meeting poll.
+     *
+     * This function performs a `PUT` to the `/meetings/{meetingId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_update_with_response(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/polls/{}",
@@ -808,7 +1096,7 @@ impl Meetings {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -838,6 +1126,21 @@ impl Meetings {
      * * `poll_id: &str` -- User's first name.
      */
     pub async fn poll_delete(&self, meeting_id: i64, poll_id: &str) -> ClientResult<()> {
+        self.poll_delete_with_response(meeting_id, poll_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a meeting poll.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_delete_with_response(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/polls/{}",
@@ -847,7 +1150,7 @@ impl Meetings {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -879,6 +1182,22 @@ impl Meetings {
         &self,
         meeting_id: i64,
     ) -> ClientResult<crate::types::MeetingRegistrantQuestionsData> {
+        Ok(self
+            .registrants_questions_get_with_response(meeting_id)
+            .await?
+            .body)
+    }
+    /**
+     * List registration questions .
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/registrants/questions` endpoint.
+     *
+     * As opposed to `registrants_questions_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrants_questions_get_with_response(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MeetingRegistrantQuestionsData>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/registrants/questions",
@@ -887,7 +1206,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -919,6 +1238,22 @@ impl Meetings {
         meeting_id: i64,
         body: &crate::types::MeetingRegistrantQuestionsData,
     ) -> ClientResult<()> {
+        self.registrant_question_update_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update registration questions.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/registrants/questions` endpoint.
+     *
+     * As opposed to `registrant_question_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_question_update_with_response(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingRegistrantQuestionsData,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/registrants/questions",
@@ -927,7 +1262,7 @@ impl Meetings {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -958,6 +1293,19 @@ impl Meetings {
         &self,
         meeting_id: i64,
     ) -> ClientResult<crate::types::MeetingInvitation> {
+        Ok(self.invitation_with_response(meeting_id).await?.body)
+    }
+    /**
+     * Get meeting invitation.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/invitation` endpoint.
+     *
+     * As opposed to `invitation`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn invitation_with_response(
+        &self,
+        meeting_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::MeetingInvitation>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/invitation",
@@ -966,7 +1314,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -999,6 +1347,22 @@ impl Meetings {
         &self,
         meeting_id: &str,
     ) -> ClientResult<crate::types::GetLiveStreamDetailsResponse> {
+        Ok(self
+            .get_live_stream_details_with_response(meeting_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get live stream details.
+     *
+     * This function performs a `GET` to the `/meetings/{meetingId}/livestream` endpoint.
+     *
+     * As opposed to `get_live_stream_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_live_stream_details_with_response(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetLiveStreamDetailsResponse>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/livestream",
@@ -1007,7 +1371,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1039,6 +1403,22 @@ impl Meetings {
         meeting_id: &str,
         body: &crate::types::MeetingLiveStream,
     ) -> ClientResult<()> {
+        self.live_stream_update_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a live stream.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/livestream` endpoint.
+     *
+     * As opposed to `live_stream_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn live_stream_update_with_response(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/livestream",
@@ -1047,7 +1427,7 @@ impl Meetings {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1080,6 +1460,22 @@ impl Meetings {
         meeting_id: i64,
         body: &crate::types::MeetingLiveStreamStatus,
     ) -> ClientResult<()> {
+        self.live_stream_status_update_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update Live Stream Status.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/livestream/status` endpoint.
+     *
+     * As opposed to `live_stream_status_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn live_stream_status_update_with_response(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingLiveStreamStatus,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/livestream/status",
@@ -1088,7 +1484,7 @@ impl Meetings {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1120,6 +1516,19 @@ impl Meetings {
         &self,
         meeting_id: &str,
     ) -> ClientResult<crate::types::ReportMeetingPollsResponse> {
+        Ok(self.list_past_polls_with_response(meeting_id).await?.body)
+    }
+    /**
+     * List past meeting's poll results.
+     *
+     * This function performs a `GET` to the `/past_meetings/{meetingId}/polls` endpoint.
+     *
+     * As opposed to `list_past_polls`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_past_polls_with_response(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
         let url = self.client.url(
             &format!(
                 "/past_meetings/{}/polls",
@@ -1128,7 +1537,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1167,6 +1576,23 @@ impl Meetings {
         meeting_id: &str,
         body: &crate::types::AddBatchRegistrantsRequest,
     ) -> ClientResult<crate::types::AddBatchRegistrantsResponse> {
+        Ok(self
+            .add_batch_registrants_with_response(meeting_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Perform batch registration.
+     *
+     * This function performs a `POST` to the `/meetings/{meetingId}/batch_registrants` endpoint.
+     *
+     * As opposed to `add_batch_registrants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_batch_registrants_with_response(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddBatchRegistrantsResponse>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/batch_registrants",
@@ -1175,7 +1601,7 @@ impl Meetings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1208,6 +1634,22 @@ impl Meetings {
         meeting_id: &str,
         body: &crate::types::InMeetingRecordingControlRequest,
     ) -> ClientResult<()> {
+        self.recording_control_with_response(meeting_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Use in-Meeting recording controls.
+     *
+     * This function performs a `PATCH` to the `/live_meetings/{meetingId}/events` endpoint.
+     *
+     * As opposed to `recording_control`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn recording_control_with_response(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::InMeetingRecordingControlRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/live_meetings/{}/events",
@@ -1216,7 +1658,7 @@ impl Meetings {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1233,9 +1675,21 @@ impl Meetings {
      * Get the quality scores of a meeting.
      */
     pub async fn quality_score(&self) -> ClientResult<crate::types::Domains> {
+        Ok(self.quality_score_with_response().await?.body)
+    }
+    /**
+     * Get meeting quality score.
+     *
+     * This function performs a `GET` to the `/metrics/quality` endpoint.
+     *
+     * As opposed to `quality_score`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn quality_score_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url("/metrics/quality", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1267,6 +1721,23 @@ impl Meetings {
         meeting_id: &str,
         body: &crate::types::CreateBatchPollsRequest,
     ) -> ClientResult<crate::types::CreateBatchPollsResponse> {
+        Ok(self
+            .create_batch_polls_with_response(meeting_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Perform batch poll creation.
+     *
+     * This function performs a `POST` to the `/meetings/{meetingId}/batch_polls` endpoint.
+     *
+     * As opposed to `create_batch_polls`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_batch_polls_with_response(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::CreateBatchPollsRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateBatchPollsResponse>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/batch_polls",
@@ -1275,7 +1746,7 @@ impl Meetings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1302,6 +1773,19 @@ impl Meetings {
         &self,
         user_id: &str,
     ) -> ClientResult<crate::types::ListMeetingTemplatesResponseData> {
+        Ok(self.list_template_with_response(user_id).await?.body)
+    }
+    /**
+     * List meeting templates.
+     *
+     * This function performs a `GET` to the `/users/{userId}/meeting_templates` endpoint.
+     *
+     * As opposed to `list_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_template_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListMeetingTemplatesResponseData>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/meeting_templates",
@@ -1310,7 +1794,7 @@ impl Meetings {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1339,6 +1823,23 @@ impl Meetings {
         meeting_id: i64,
         body: &crate::types::InviteLink,
     ) -> ClientResult<crate::types::InviteLinks> {
+        Ok(self
+            .invite_links_create_with_response(meeting_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create meeting's invite links.
+     *
+     * This function performs a `POST` to the `/meetings/{meetingId}/invite_links` endpoint.
+     *
+     * As opposed to `invite_links_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn invite_links_create_with_response(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> ClientResult<crate::Response<crate::types::InviteLinks>> {
         let url = self.client.url(
             &format!(
                 "/meetings/{}/invite_links",
@@ -1347,7 +1848,7 @@ impl Meetings {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -31,6 +31,19 @@ impl Pac {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn user_pa_cs(&self, user_id: &str) -> ClientResult<crate::types::UserPaCsResponse> {
+        Ok(self.user_pa_cs_with_response(user_id).await?.body)
+    }
+    /**
+     * List a user's PAC accounts.
+     *
+     * This function performs a `GET` to the `/users/{userId}/pac` endpoint.
+     *
+     * As opposed to `user_pa_cs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_pa_cs_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserPaCsResponse>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/pac",
@@ -39,7 +52,7 @@ impl Pac {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
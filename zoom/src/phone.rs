@@ -34,6 +34,21 @@ impl Phone {
         account_id: &str,
         body: &crate::types::SetUpAccountRequest,
     ) -> ClientResult<()> {
+        self.set_up_account_with_response(account_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Set up a Zoom Phone account.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/phone/setup` endpoint.
+     *
+     * As opposed to `set_up_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn set_up_account_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::SetUpAccountRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/phone/setup",
@@ -42,7 +57,7 @@ impl Phone {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -90,6 +105,36 @@ impl Phone {
         pending_numbers: bool,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
+        Ok(self
+            .list_account_numbers_with_response(
+                next_page_token,
+                type_,
+                extension_type,
+                page_size,
+                number_type,
+                pending_numbers,
+                site_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_account_numbers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_account_numbers_with_response(
+        &self,
+        next_page_token: &str,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        page_size: i64,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListAccountPhoneNumbersResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !extension_type.to_string().is_empty() {
             query_args.push(("extension_type".to_string(), extension_type.to_string()));
@@ -114,9 +159,9 @@ impl Phone {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/numbers?{}", query_), None);
-        let resp: crate::types::ListAccountPhoneNumbersResponseData = self
+        let resp: crate::Response<crate::types::ListAccountPhoneNumbersResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -126,7 +171,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.phone_numbers.to_vec())
+        Ok(resp.map(|r| r.phone_numbers.to_vec()))
     }
     /**
      * List phone numbers.
@@ -222,6 +267,117 @@ impl Phone {
         // Return our response data.
         Ok(phone_numbers)
     }
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_all_account_numbers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_account_numbers_pages_stream(
+        &self,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListAccountPhoneNumbersResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !extension_type.to_string().is_empty() {
+            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+        }
+        if !number_type.to_string().is_empty() {
+            query_args.push(("number_type".to_string(), number_type.to_string()));
+        }
+        if pending_numbers {
+            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/phone/numbers?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListAccountPhoneNumbersResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.phone_numbers;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_all_account_numbers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_account_numbers_stream(
+        &self,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListAccountPhoneNumbersResponse>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.list_all_account_numbers_pages_stream(
+            type_,
+            extension_type,
+            number_type,
+            pending_numbers,
+            site_id,
+            None,
+        ))
+    }
     /**
      * Get user's profile.
      *
@@ -240,6 +396,19 @@ impl Phone {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn user(&self, user_id: &str) -> ClientResult<crate::types::PhoneUserResponse> {
+        Ok(self.user_with_response(user_id).await?.body)
+    }
+    /**
+     * Get user's profile.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}` endpoint.
+     *
+     * As opposed to `user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PhoneUserResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}",
@@ -248,7 +417,7 @@ impl Phone {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -275,6 +444,22 @@ impl Phone {
         user_id: &str,
         body: &crate::types::UpdateUserProfileRequest,
     ) -> ClientResult<()> {
+        self.update_user_profile_with_response(user_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update user's profile.
+     *
+     * This function performs a `PATCH` to the `/phone/users/{userId}` endpoint.
+     *
+     * As opposed to `update_user_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_user_profile_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdateUserProfileRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}",
@@ -283,7 +468,7 @@ impl Phone {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -309,9 +494,22 @@ impl Phone {
         &self,
         _account_id: &str,
     ) -> ClientResult<crate::types::PhoneSettingResponse> {
+        Ok(self.setting_with_response(_account_id).await?.body)
+    }
+    /**
+     * Get account's setting.
+     *
+     * This function performs a `GET` to the `/phone/settings` endpoint.
+     *
+     * As opposed to `setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn setting_with_response(
+        &self,
+        _account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PhoneSettingResponse>> {
         let url = self.client.url("/phone/settings", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -341,9 +539,25 @@ impl Phone {
         _account_id: &str,
         body: &crate::types::UpdatePhoneSettingsRequest,
     ) -> ClientResult<()> {
+        self.update_settings_with_response(_account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update BYOC settings.
+     *
+     * This function performs a `PATCH` to the `/phone/settings` endpoint.
+     *
+     * As opposed to `update_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_settings_with_response(
+        &self,
+        _account_id: &str,
+        body: &crate::types::UpdatePhoneSettingsRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/phone/settings", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -373,6 +587,19 @@ impl Phone {
         &self,
         user_id: &str,
     ) -> ClientResult<crate::types::PhoneUserSettingsResponse> {
+        Ok(self.user_settings_with_response(user_id).await?.body)
+    }
+    /**
+     * Get user's settings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `user_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_settings_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::PhoneUserSettingsResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/settings",
@@ -381,7 +608,7 @@ impl Phone {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -415,6 +642,24 @@ impl Phone {
         next_page_token: &str,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::Templates>> {
+        Ok(self
+            .list_setting_templates_with_response(page_size, next_page_token, site_id)
+            .await?
+            .body)
+    }
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_setting_templates`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_setting_templates_with_response(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Templates>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -429,9 +674,9 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/setting_templates?{}", query_), None);
-        let resp: crate::types::ListSettingTemplatesResponse = self
+        let resp: crate::Response<crate::types::ListSettingTemplatesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -441,7 +686,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.templates.to_vec())
+        Ok(resp.map(|r| r.templates.to_vec()))
     }
     /**
      * List setting templates.
@@ -523,6 +768,89 @@ impl Phone {
         // Return our response data.
         Ok(templates)
     }
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_all_setting_templates`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_setting_templates_pages_stream(
+        &self,
+        site_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Templates>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/setting_templates?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListSettingTemplatesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.templates;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_all_setting_templates`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_setting_templates_stream(
+        &self,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Templates>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_setting_templates_pages_stream(site_id, None))
+    }
     /**
      * Add a setting template.
      *
@@ -540,9 +868,22 @@ impl Phone {
         &self,
         body: &crate::types::AddSettingTemplateRequest,
     ) -> ClientResult<crate::types::AddSettingTemplateResponse> {
+        Ok(self.add_setting_template_with_response(body).await?.body)
+    }
+    /**
+     * Add a setting template.
+     *
+     * This function performs a `POST` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `add_setting_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_setting_template_with_response(
+        &self,
+        body: &crate::types::AddSettingTemplateRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddSettingTemplateResponse>> {
         let url = self.client.url("/phone/setting_templates", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -562,9 +903,22 @@ impl Phone {
         &self,
         body: &crate::types::BatchAddLocationsRequest,
     ) -> ClientResult<Vec<crate::types::BatchAddLocationsResponse>> {
+        Ok(self.batch_add_locations_with_response(body).await?.body)
+    }
+    /**
+     * Batch add emergency service locations.
+     *
+     * This function performs a `POST` to the `/phone/batch_locations` endpoint.
+     *
+     * As opposed to `batch_add_locations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn batch_add_locations_with_response(
+        &self,
+        body: &crate::types::BatchAddLocationsRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BatchAddLocationsResponse>>> {
         let url = self.client.url("/phone/batch_locations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -596,6 +950,23 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::ListLocationsResponse>> {
+        Ok(self
+            .list_locations_with_response(next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_locations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_locations_with_response(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListLocationsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -607,9 +978,9 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/locations?{}", query_), None);
-        let resp: crate::types::ListLocationsResponseData = self
+        let resp: crate::Response<crate::types::ListLocationsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -619,7 +990,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.locations.to_vec())
+        Ok(resp.map(|r| r.locations.to_vec()))
     }
     /**
      * List emergency service locations.
@@ -693,6 +1064,82 @@ impl Phone {
         // Return our response data.
         Ok(locations)
     }
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_all_locations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_locations_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListLocationsResponse>>,
+    > + Unpin
+           + '_ {
+        let url = self.client.url("/phone/locations", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListLocationsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.locations;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_all_locations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_locations_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListLocationsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_locations_pages_stream(None))
+    }
     /**
      * Add emergency service location.
      *
@@ -710,9 +1157,22 @@ impl Phone {
         &self,
         body: &crate::types::AddLocationRequest,
     ) -> ClientResult<Vec<crate::types::Site>> {
+        Ok(self.add_location_with_response(body).await?.body)
+    }
+    /**
+     * Add emergency service location.
+     *
+     * This function performs a `POST` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `add_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_location_with_response(
+        &self,
+        body: &crate::types::AddLocationRequest,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Site>>> {
         let url = self.client.url("/phone/locations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -742,6 +1202,19 @@ impl Phone {
         &self,
         location_id: &str,
     ) -> ClientResult<crate::types::GetLocationResponse> {
+        Ok(self.get_location_with_response(location_id).await?.body)
+    }
+    /**
+     * Get emergency service location details.
+     *
+     * This function performs a `GET` to the `/phone/locations/{locationId}` endpoint.
+     *
+     * As opposed to `get_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_location_with_response(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetLocationResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/locations/{}",
@@ -750,7 +1223,7 @@ impl Phone {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -777,6 +1250,20 @@ impl Phone {
      * * `location_id: &str` -- The emergency service location's ID.
      */
     pub async fn delete_location(&self, location_id: &str) -> ClientResult<()> {
+        self.delete_location_with_response(location_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete an emergency location.
+     *
+     * This function performs a `DELETE` to the `/phone/locations/{locationId}` endpoint.
+     *
+     * As opposed to `delete_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_location_with_response(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/locations/{}",
@@ -785,7 +1272,7 @@ impl Phone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -812,15 +1299,31 @@ impl Phone {
         location_id: &str,
         body: &crate::types::UpdateLocationRequest,
     ) -> ClientResult<()> {
-        let url = self.client.url(
-            &format!(
-                "/phone/locations/{}",
-                crate::progenitor_support::encode_path(location_id),
-            ),
+        self.update_location_with_response(location_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update emergency service location.
+     *
+     * This function performs a `PATCH` to the `/phone/locations/{locationId}` endpoint.
+     *
+     * As opposed to `update_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_location_with_response(
+        &self,
+        location_id: &str,
+        body: &crate::types::UpdateLocationRequest,
+    ) -> ClientResult<crate::Response<()>> {
+        let url = self.client.url(
+            &format!(
+                "/phone/locations/{}",
+                crate::progenitor_support::encode_path(location_id),
+            ),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -852,6 +1355,23 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::SipGroups>> {
+        Ok(self
+            .list_sip_groups_with_response(next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_sip_groups`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_sip_groups_with_response(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SipGroups>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -863,9 +1383,9 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/sip_groups?{}", query_), None);
-        let resp: crate::types::ListSipGroupsResponse = self
+        let resp: crate::Response<crate::types::ListSipGroupsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -875,7 +1395,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.sip_groups.to_vec())
+        Ok(resp.map(|r| r.sip_groups.to_vec()))
     }
     /**
      * List SIP groups.
@@ -947,6 +1467,80 @@ impl Phone {
         // Return our response data.
         Ok(sip_groups)
     }
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_all_sip_groups`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_sip_groups_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SipGroups>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/phone/sip_groups", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListSipGroupsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.sip_groups;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_all_sip_groups`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_sip_groups_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SipGroups>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_sip_groups_pages_stream(None))
+    }
     /**
      * Get setting template details.
      *
@@ -970,6 +1564,23 @@ impl Phone {
         template_id: &str,
         custom_query_fields: &str,
     ) -> ClientResult<crate::types::GetSettingTemplateResponse> {
+        Ok(self
+            .get_setting_template_with_response(template_id, custom_query_fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get setting template details.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates/{templateId}` endpoint.
+     *
+     * As opposed to `get_setting_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_setting_template_with_response(
+        &self,
+        template_id: &str,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSettingTemplateResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -987,7 +1598,7 @@ impl Phone {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1018,6 +1629,22 @@ impl Phone {
         template_id: &str,
         body: &crate::types::UpdateSettingTemplateRequest,
     ) -> ClientResult<()> {
+        self.update_setting_template_with_response(template_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a setting template.
+     *
+     * This function performs a `PATCH` to the `/phone/setting_templates/{templateId}` endpoint.
+     *
+     * As opposed to `update_setting_template`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_setting_template_with_response(
+        &self,
+        template_id: &str,
+        body: &crate::types::UpdateSettingTemplateRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/setting_templates/{}",
@@ -1026,7 +1653,7 @@ impl Phone {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1070,6 +1697,38 @@ impl Phone {
         phone_number: &str,
         time_type: crate::types::TimeType,
     ) -> ClientResult<Vec<crate::types::CallLogs>> {
+        Ok(self
+            .user_call_logs_with_response(
+                user_id,
+                page_size,
+                from,
+                to,
+                type_,
+                next_page_token,
+                phone_number,
+                time_type,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `user_call_logs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_call_logs_with_response(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CallLogs>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1101,9 +1760,9 @@ impl Phone {
             ),
             None,
         );
-        let resp: crate::types::PhoneUserCallLogsResponse = self
+        let resp: crate::Response<crate::types::PhoneUserCallLogsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1113,7 +1772,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.call_logs.to_vec())
+        Ok(resp.map(|r| r.call_logs.to_vec()))
     }
     /**
      * Get user's call logs.
@@ -1217,6 +1876,123 @@ impl Phone {
         // Return our response data.
         Ok(call_logs)
     }
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `get_all_user_call_logs`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_user_call_logs_pages_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CallLogs>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if !time_type.to_string().is_empty() {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/call_logs?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::PhoneUserCallLogsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.call_logs;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `get_all_user_call_logs`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_user_call_logs_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CallLogs>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_user_call_logs_pages_stream(
+            user_id,
+            from,
+            to,
+            type_,
+            phone_number,
+            time_type,
+            None,
+        ))
+    }
     /**
      * Get user's recordings.
      *
@@ -1246,6 +2022,26 @@ impl Phone {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<Vec<crate::types::Recordings>> {
+        Ok(self
+            .user_recordings_with_response(user_id, page_size, next_page_token, from, to)
+            .await?
+            .body)
+    }
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `user_recordings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_recordings_with_response(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Recordings>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1268,9 +2064,9 @@ impl Phone {
             ),
             None,
         );
-        let resp: crate::types::PhoneUserRecordingsResponse = self
+        let resp: crate::Response<crate::types::PhoneUserRecordingsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1280,7 +2076,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.recordings.to_vec())
+        Ok(resp.map(|r| r.recordings.to_vec()))
     }
     /**
      * Get user's recordings.
@@ -1372,6 +2168,101 @@ impl Phone {
         // Return our response data.
         Ok(recordings)
     }
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_user_recordings`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_user_recordings_pages_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Recordings>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/recordings?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::PhoneUserRecordingsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.recordings;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_user_recordings`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_user_recordings_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Recordings>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_user_recordings_pages_stream(user_id, from, to, None))
+    }
     /**
      * Get user's voicemails.
      *
@@ -1403,6 +2294,27 @@ impl Phone {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<Vec<crate::types::VoiceMails>> {
+        Ok(self
+            .user_voice_mails_with_response(user_id, page_size, status, next_page_token, from, to)
+            .await?
+            .body)
+    }
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `user_voice_mails`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_voice_mails_with_response(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::VoiceMails>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1428,9 +2340,9 @@ impl Phone {
             ),
             None,
         );
-        let resp: crate::types::PhoneUserVoiceMailsResponse = self
+        let resp: crate::Response<crate::types::PhoneUserVoiceMailsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1440,7 +2352,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.voice_mails.to_vec())
+        Ok(resp.map(|r| r.voice_mails.to_vec()))
     }
     /**
      * Get user's voicemails.
@@ -1536,6 +2448,108 @@ impl Phone {
         // Return our response data.
         Ok(voice_mails)
     }
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `get_all_user_voice_mails`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_user_voice_mails_pages_stream(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::VoiceMails>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/users/{}/voice_mails?{}",
+                crate::progenitor_support::encode_path(user_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::PhoneUserVoiceMailsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.voice_mails;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `get_all_user_voice_mails`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_user_voice_mails_stream(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::VoiceMails>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.get_all_user_voice_mails_pages_stream(user_id, status, from, to, None),
+        )
+    }
     /**
      * Set up shared access.
      *
@@ -1563,6 +2577,24 @@ impl Phone {
         setting_type: &str,
         body: &crate::types::AddUserSettingRequest,
     ) -> ClientResult<crate::types::AddUserSettingResponse> {
+        Ok(self
+            .add_user_setting_with_response(user_id, setting_type, body)
+            .await?
+            .body)
+    }
+    /**
+     * Set up shared access.
+     *
+     * This function performs a `POST` to the `/phone/users/{userId}/settings/{settingType}` endpoint.
+     *
+     * As opposed to `add_user_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_user_setting_with_response(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        body: &crate::types::AddUserSettingRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddUserSettingResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/settings/{}",
@@ -1572,7 +2604,7 @@ impl Phone {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1607,6 +2639,23 @@ impl Phone {
         setting_type: &str,
         shared_id: &str,
     ) -> ClientResult<()> {
+        self.delete_user_setting_with_response(user_id, setting_type, shared_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove shared access.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/settings/{settingType}` endpoint.
+     *
+     * As opposed to `delete_user_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_user_setting_with_response(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !shared_id.is_empty() {
             query_args.push(("shared_id".to_string(), shared_id.to_string()));
@@ -1622,7 +2671,7 @@ impl Phone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1658,6 +2707,23 @@ impl Phone {
         user_id: &str,
         body: &crate::types::UpdateUserSettingRequest,
     ) -> ClientResult<()> {
+        self.update_user_setting_with_response(setting_type, user_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update shared access.
+     *
+     * This function performs a `PATCH` to the `/phone/users/{userId}/settings/{settingType}` endpoint.
+     *
+     * As opposed to `update_user_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_user_setting_with_response(
+        &self,
+        setting_type: &str,
+        user_id: &str,
+        body: &crate::types::UpdateUserSettingRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/settings/{}",
@@ -1667,7 +2733,7 @@ impl Phone {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1716,6 +2782,38 @@ impl Phone {
         time_type: crate::types::TimeType,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::AccountCallLogsResponse>> {
+        Ok(self
+            .account_call_logs_with_response(
+                page_size,
+                from,
+                to,
+                type_,
+                next_page_token,
+                path,
+                time_type,
+                site_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `account_call_logs`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn account_call_logs_with_response(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AccountCallLogsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -1745,9 +2843,9 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/call_logs?{}", query_), None);
-        let resp: crate::types::AccountCallLogsResponseData = self
+        let resp: crate::Response<crate::types::AccountCallLogsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1757,7 +2855,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.call_logs.to_vec())
+        Ok(resp.map(|r| r.call_logs.to_vec()))
     }
     /**
      * Get account's call logs.
@@ -1860,6 +2958,118 @@ impl Phone {
         // Return our response data.
         Ok(call_logs)
     }
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `get_all_account_call_logs`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_account_call_logs_pages_stream(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::AccountCallLogsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !time_type.to_string().is_empty() {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/call_logs?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::AccountCallLogsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.call_logs;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `get_all_account_call_logs`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_account_call_logs_stream(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AccountCallLogsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_account_call_logs_pages_stream(
+            from, to, type_, path, time_type, site_id, None,
+        ))
+    }
     /**
      * Assign phone number to user.
      *
@@ -1878,6 +3088,20 @@ impl Phone {
         user_id: &str,
         body: &crate::types::AddByocNumberResponse,
     ) -> ClientResult<crate::types::AddByocNumberResponse> {
+        Ok(self.assign_number_with_response(user_id, body).await?.body)
+    }
+    /**
+     * Assign phone number to user.
+     *
+     * This function performs a `POST` to the `/phone/users/{userId}/phone_numbers` endpoint.
+     *
+     * As opposed to `assign_number`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_number_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<crate::types::AddByocNumberResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/phone_numbers",
@@ -1886,7 +3110,7 @@ impl Phone {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1917,6 +3141,22 @@ impl Phone {
      * * `phone_number_id: &str` -- Provide either phone number or phoneNumberId of the user. .
      */
     pub async fn unassign_number(&self, user_id: &str, phone_number_id: &str) -> ClientResult<()> {
+        self.unassign_number_with_response(user_id, phone_number_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign phone number.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `unassign_number`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_number_with_response(
+        &self,
+        user_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/phone_numbers/{}",
@@ -1926,7 +3166,7 @@ impl Phone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1953,6 +3193,22 @@ impl Phone {
         user_id: &str,
         body: &crate::types::AssignCallingPlanRequest,
     ) -> ClientResult<()> {
+        self.assign_calling_plan_with_response(user_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Assign calling plan to a user.
+     *
+     * This function performs a `POST` to the `/phone/users/{userId}/calling_plans` endpoint.
+     *
+     * As opposed to `assign_calling_plan`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_calling_plan_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::AssignCallingPlanRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/calling_plans",
@@ -1961,7 +3217,7 @@ impl Phone {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1989,6 +3245,22 @@ impl Phone {
      *   .
      */
     pub async fn unassign_calling_plan(&self, user_id: &str, type_: &str) -> ClientResult<()> {
+        self.unassign_calling_plan_with_response(user_id, type_)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign user's calling plan.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/calling_plans/{type}` endpoint.
+     *
+     * As opposed to `unassign_calling_plan`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_calling_plan_with_response(
+        &self,
+        user_id: &str,
+        type_: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/calling_plans/{}",
@@ -1998,7 +3270,7 @@ impl Phone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2048,6 +3320,38 @@ impl Phone {
         site_id: &str,
         query_date_type: crate::types::QueryDateType,
     ) -> ClientResult<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        Ok(self
+            .get_recordings_with_response(
+                page_size,
+                next_page_token,
+                from,
+                to,
+                owner_type,
+                recording_type,
+                site_id,
+                query_date_type,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_recordings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_recordings_with_response(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetPhoneRecordingsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -2077,9 +3381,9 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/recordings?{}", query_), None);
-        let resp: crate::types::GetPhoneRecordingsResponseData = self
+        let resp: crate::Response<crate::types::GetPhoneRecordingsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2089,7 +3393,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.recordings.to_vec())
+        Ok(resp.map(|r| r.recordings.to_vec()))
     }
     /**
      * Get call recordings.
@@ -2192,6 +3496,124 @@ impl Phone {
         // Return our response data.
         Ok(recordings)
     }
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_all_recordings`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_recordings_pages_stream(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetPhoneRecordingsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !owner_type.is_empty() {
+            query_args.push(("owner_type".to_string(), owner_type.to_string()));
+        }
+        if !query_date_type.to_string().is_empty() {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if !recording_type.is_empty() {
+            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+        }
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/phone/recordings?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::GetPhoneRecordingsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.recordings;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_all_recordings`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_recordings_stream(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetPhoneRecordingsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.get_all_recordings_pages_stream(
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+            None,
+        ))
+    }
     /**
      * List BYOC SIP trunks.
      *
@@ -2214,6 +3636,23 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::ByocSipTrunk>> {
+        Ok(self
+            .list_byocsip_trunk_with_response(next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_byocsip_trunk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_byocsip_trunk_with_response(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ByocSipTrunk>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -2225,9 +3664,9 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/sip_trunk/trunks?{}", query_), None);
-        let resp: crate::types::ListByocsipTrunkResponse = self
+        let resp: crate::Response<crate::types::ListByocsipTrunkResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2237,7 +3676,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.byoc_sip_trunk.to_vec())
+        Ok(resp.map(|r| r.byoc_sip_trunk.to_vec()))
     }
     /**
      * List BYOC SIP trunks.
@@ -2308,6 +3747,80 @@ impl Phone {
         // Return our response data.
         Ok(byoc_sip_trunk)
     }
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_all_byocsip_trunk`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_byocsip_trunk_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ByocSipTrunk>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/phone/sip_trunk/trunks", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListByocsipTrunkResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.byoc_sip_trunk;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_all_byocsip_trunk`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_byocsip_trunk_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ByocSipTrunk>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_byocsip_trunk_pages_stream(None))
+    }
     /**
      * Assign SIP trunks.
      *
@@ -2329,6 +3842,23 @@ impl Phone {
         account_id: &str,
         body: &crate::types::PostPhoneSipTrunkRequest,
     ) -> ClientResult<crate::types::PostPhoneSipTrunkRequest> {
+        Ok(self
+            .post_sip_trunk_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Assign SIP trunks.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `post_sip_trunk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn post_sip_trunk_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::PostPhoneSipTrunkRequest,
+    ) -> ClientResult<crate::Response<crate::types::PostPhoneSipTrunkRequest>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/phone/sip_trunk/trunks",
@@ -2337,7 +3867,7 @@ impl Phone {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2369,6 +3899,23 @@ impl Phone {
         account_id: &str,
         body: &crate::types::UpdatePhoneSipTrunkRequest,
     ) -> ClientResult<()> {
+        self.update_sip_trunk_with_response(sip_trunk_id, account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update SIP trunk details.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}` endpoint.
+     *
+     * As opposed to `update_sip_trunk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_sip_trunk_with_response(
+        &self,
+        sip_trunk_id: &str,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSipTrunkRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/phone/sip_trunk/trunks/{}",
@@ -2378,7 +3925,7 @@ impl Phone {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2410,6 +3957,23 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::ExternalContacts>> {
+        Ok(self
+            .list_external_contacts_with_response(next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_external_contacts`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_external_contacts_with_response(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ExternalContacts>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -2421,9 +3985,9 @@ impl Phone {
         let url = self
             .client
             .url(&format!("/phone/external_contacts?{}", query_), None);
-        let resp: crate::types::ListExternalContactsResponse = self
+        let resp: crate::Response<crate::types::ListExternalContactsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2433,7 +3997,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.external_contacts.to_vec())
+        Ok(resp.map(|r| r.external_contacts.to_vec()))
     }
     /**
      * List external contacts.
@@ -2507,6 +4071,81 @@ impl Phone {
         // Return our response data.
         Ok(external_contacts)
     }
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_all_external_contacts`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_external_contacts_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ExternalContacts>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/phone/external_contacts", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListExternalContactsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.external_contacts;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_all_external_contacts`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_external_contacts_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ExternalContacts>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_external_contacts_pages_stream(None))
+    }
     /**
      * Add an external contact.
      *
@@ -2524,9 +4163,23 @@ impl Phone {
         &self,
         body: &crate::types::AddExternalContactRequest,
     ) -> ClientResult<()> {
+        self.add_external_contact_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Add an external contact.
+     *
+     * This function performs a `POST` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `add_external_contact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_external_contact_with_response(
+        &self,
+        body: &crate::types::AddExternalContactRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/phone/external_contacts", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2556,6 +4209,22 @@ impl Phone {
         &self,
         external_contact_id: &str,
     ) -> ClientResult<crate::types::ExternalContacts> {
+        Ok(self
+            .get_external_contact_with_response(external_contact_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get external contact details.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * As opposed to `get_external_contact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_external_contact_with_response(
+        &self,
+        external_contact_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ExternalContacts>> {
         let url = self.client.url(
             &format!(
                 "/phone/external_contacts/{}",
@@ -2564,7 +4233,7 @@ impl Phone {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2591,6 +4260,21 @@ impl Phone {
      * * `external_contact_id: &str` -- The external contact's ID.
      */
     pub async fn delete_external_contact(&self, external_contact_id: &str) -> ClientResult<()> {
+        self.delete_external_contact_with_response(external_contact_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an external contact.
+     *
+     * This function performs a `DELETE` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * As opposed to `delete_external_contact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_external_contact_with_response(
+        &self,
+        external_contact_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/external_contacts/{}",
@@ -2599,7 +4283,7 @@ impl Phone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2630,6 +4314,22 @@ impl Phone {
         external_contact_id: &str,
         body: &crate::types::UpdateExternalContactRequest,
     ) -> ClientResult<()> {
+        self.update_external_contact_with_response(external_contact_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update external contact.
+     *
+     * This function performs a `PATCH` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * As opposed to `update_external_contact`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_external_contact_with_response(
+        &self,
+        external_contact_id: &str,
+        body: &crate::types::UpdateExternalContactRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/external_contacts/{}",
@@ -2638,7 +4338,7 @@ impl Phone {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2668,6 +4368,19 @@ impl Phone {
         &self,
         number_id: &str,
     ) -> ClientResult<crate::types::GetPhoneNumberDetailsResponse> {
+        Ok(self.get_number_details_with_response(number_id).await?.body)
+    }
+    /**
+     * Get phone number details.
+     *
+     * This function performs a `GET` to the `/phone/numbers/{numberId}` endpoint.
+     *
+     * As opposed to `get_number_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_number_details_with_response(
+        &self,
+        number_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetPhoneNumberDetailsResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/numbers/{}",
@@ -2676,7 +4389,7 @@ impl Phone {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2706,6 +4419,22 @@ impl Phone {
         number_id: &str,
         body: &crate::types::UpdatePhoneNumberDetailsRequest,
     ) -> ClientResult<()> {
+        self.update_number_details_with_response(number_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update phone number details.
+     *
+     * This function performs a `PATCH` to the `/phone/numbers/{numberId}` endpoint.
+     *
+     * As opposed to `update_number_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_number_details_with_response(
+        &self,
+        number_id: &str,
+        body: &crate::types::UpdatePhoneNumberDetailsRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/numbers/{}",
@@ -2714,7 +4443,7 @@ impl Phone {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2742,9 +4471,23 @@ impl Phone {
         &self,
         body: &crate::types::ChangeMainCompanyNumberRequest,
     ) -> ClientResult<()> {
+        self.change_main_company_number_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Change main company number.
+     *
+     * This function performs a `PUT` to the `/phone/company_number` endpoint.
+     *
+     * As opposed to `change_main_company_number`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn change_main_company_number_with_response(
+        &self,
+        body: &crate::types::ChangeMainCompanyNumberRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/phone/company_number", None);
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -2769,9 +4512,21 @@ impl Phone {
     pub async fn list_calling_plan(
         &self,
     ) -> ClientResult<crate::types::ListCallingPlansResponseData> {
+        Ok(self.list_calling_plan_with_response().await?.body)
+    }
+    /**
+     * List calling plans.
+     *
+     * This function performs a `GET` to the `/phone/calling_plans` endpoint.
+     *
+     * As opposed to `list_calling_plan`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_calling_plan_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListCallingPlansResponseData>> {
         let url = self.client.url("/phone/calling_plans", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2805,6 +4560,24 @@ impl Phone {
         next_page_token: &str,
         site_id: &str,
     ) -> ClientResult<Vec<crate::types::ListPhoneUsersResponse>> {
+        Ok(self
+            .list_users_with_response(page_size, next_page_token, site_id)
+            .await?
+            .body)
+    }
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_users_with_response(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneUsersResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -2817,9 +4590,9 @@ impl Phone {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/users?{}", query_), None);
-        let resp: crate::types::ListPhoneUsersResponseData = self
+        let resp: crate::Response<crate::types::ListPhoneUsersResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2829,7 +4602,7 @@ impl Phone {
             .await?;
 
         // Return our response data.
-        Ok(resp.users.to_vec())
+        Ok(resp.map(|r| r.users.to_vec()))
     }
     /**
      * List phone users.
@@ -2909,6 +4682,89 @@ impl Phone {
         // Return our response data.
         Ok(users)
     }
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_users_pages_stream(
+        &self,
+        site_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListPhoneUsersResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !site_id.is_empty() {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/phone/users?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListPhoneUsersResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.users;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_users_stream(
+        &self,
+        site_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListPhoneUsersResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_users_pages_stream(site_id, None))
+    }
     /**
      * Get call log details.
      *
@@ -2930,6 +4786,22 @@ impl Phone {
         &self,
         call_log_id: &str,
     ) -> ClientResult<crate::types::GetCallLogDetailsResponse> {
+        Ok(self
+            .get_call_log_details_with_response(call_log_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get call log details.
+     *
+     * This function performs a `GET` to the `/phone/call_logs/{callLogId}` endpoint.
+     *
+     * As opposed to `get_call_log_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_call_log_details_with_response(
+        &self,
+        call_log_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCallLogDetailsResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_logs/{}",
@@ -2938,7 +4810,7 @@ impl Phone {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -2966,6 +4838,22 @@ impl Phone {
      * * `call_log_id: &str` -- Unique identifier of the call log. The value for this field can be retrieved from [account's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/accountcalllogs) or [user's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneusercalllogs).
      */
     pub async fn delete_call_log(&self, user_id: &str, call_log_id: &str) -> ClientResult<()> {
+        self.delete_call_log_with_response(user_id, call_log_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a user's call log.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/call_logs/{callLogId}` endpoint.
+     *
+     * As opposed to `delete_call_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_call_log_with_response(
+        &self,
+        user_id: &str,
+        call_log_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/users/{}/call_logs/{}",
@@ -2975,7 +4863,7 @@ impl Phone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -3001,9 +4889,22 @@ impl Phone {
         &self,
         body: &crate::types::AddByocNumberRequest,
     ) -> ClientResult<crate::types::AddByocNumberResponse> {
+        Ok(self.add_byoc_number_with_response(body).await?.body)
+    }
+    /**
+     * Add BYOC phone numbers.
+     *
+     * This function performs a `POST` to the `/phone/byoc_numbers` endpoint.
+     *
+     * As opposed to `add_byoc_number`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_byoc_number_with_response(
+        &self,
+        body: &crate::types::AddByocNumberRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddByocNumberResponse>> {
         let url = self.client.url("/phone/byoc_numbers", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -3029,6 +4930,20 @@ impl Phone {
      * * `voicemail_id: &str` -- Unique identifier of the voicemail. Retrieve the value for this field by calling the [Get voicemails](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneuservoicemails) API.
      */
     pub async fn delete_voicemail(&self, voicemail_id: &str) -> ClientResult<()> {
+        self.delete_voicemail_with_response(voicemail_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a voicemail.
+     *
+     * This function performs a `DELETE` to the `/phone/voice_mails/{voicemailId}` endpoint.
+     *
+     * As opposed to `delete_voicemail`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_voicemail_with_response(
+        &self,
+        voicemail_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/voice_mails/{}",
@@ -3037,7 +4952,7 @@ impl Phone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
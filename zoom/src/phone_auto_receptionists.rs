@@ -34,6 +34,22 @@ impl PhoneAutoReceptionists {
         auto_receptionist_id: &str,
         body: &crate::types::UpdateAutoReceptionistRequest,
     ) -> ClientResult<()> {
+        self.update_auto_receptionist_with_response(auto_receptionist_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update auto receptionist details.
+     *
+     * This function performs a `PATCH` to the `/phone/auto_receptionists/{autoReceptionistId}` endpoint.
+     *
+     * As opposed to `update_auto_receptionist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_auto_receptionist_with_response(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::UpdateAutoReceptionistRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/auto_receptionists/{}",
@@ -42,7 +58,7 @@ impl PhoneAutoReceptionists {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -75,6 +91,22 @@ impl PhoneAutoReceptionists {
         auto_receptionist_id: &str,
         body: &crate::types::AddByocNumberResponse,
     ) -> ClientResult<()> {
+        self.assign_phone_numbers_auto_receptionist_with_response(auto_receptionist_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Assign phone numbers.
+     *
+     * This function performs a `POST` to the `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers` endpoint.
+     *
+     * As opposed to `assign_phone_numbers_auto_receptionist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_phone_numbers_auto_receptionist_with_response(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/auto_receptionists/{}/phone_numbers",
@@ -83,7 +115,7 @@ impl PhoneAutoReceptionists {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -111,6 +143,21 @@ impl PhoneAutoReceptionists {
         &self,
         auto_receptionist_id: &str,
     ) -> ClientResult<()> {
+        self.unassign_all_phone_nums_auto_receptionist_with_response(auto_receptionist_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign all phone numbers.
+     *
+     * This function performs a `DELETE` to the `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers` endpoint.
+     *
+     * As opposed to `unassign_all_phone_nums_auto_receptionist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_all_phone_nums_auto_receptionist_with_response(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/auto_receptionists/{}/phone_numbers",
@@ -119,7 +166,7 @@ impl PhoneAutoReceptionists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -153,6 +200,25 @@ impl PhoneAutoReceptionists {
         auto_receptionist_id: &str,
         phone_number_id: &str,
     ) -> ClientResult<()> {
+        self.unassign_phone_num_auto_receptionist_with_response(
+            auto_receptionist_id,
+            phone_number_id,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Unassign a phone number.
+     *
+     * This function performs a `DELETE` to the `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `unassign_phone_num_auto_receptionist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_phone_num_auto_receptionist_with_response(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/auto_receptionists/{}/phone_numbers/{}",
@@ -162,7 +228,7 @@ impl PhoneAutoReceptionists {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -189,9 +255,22 @@ impl PhoneAutoReceptionists {
         &self,
         body: &crate::types::AddAutoReceptionistRequest,
     ) -> ClientResult<crate::types::AddAutoReceptionistResponse> {
+        Ok(self.add_auto_receptionist_with_response(body).await?.body)
+    }
+    /**
+     * Add an auto receptionist.
+     *
+     * This function performs a `POST` to the `/phone/auto_receptionists` endpoint.
+     *
+     * As opposed to `add_auto_receptionist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_auto_receptionist_with_response(
+        &self,
+        body: &crate::types::AddAutoReceptionistRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddAutoReceptionistResponse>> {
         let url = self.client.url("/phone/auto_receptionists", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -34,6 +34,23 @@ impl PhoneBlockedList {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::BlockedList>> {
+        Ok(self
+            .list_blocked_with_response(next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_blocked`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_blocked_with_response(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::BlockedList>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -45,9 +62,9 @@ impl PhoneBlockedList {
         let url = self
             .client
             .url(&format!("/phone/blocked_list?{}", query_), None);
-        let resp: crate::types::ListBlockedResponse = self
+        let resp: crate::Response<crate::types::ListBlockedResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -57,7 +74,7 @@ impl PhoneBlockedList {
             .await?;
 
         // Return our response data.
-        Ok(resp.blocked_list.to_vec())
+        Ok(resp.map(|r| r.blocked_list.to_vec()))
     }
     /**
      * List blocked lists.
@@ -129,6 +146,80 @@ impl PhoneBlockedList {
         // Return our response data.
         Ok(blocked_list)
     }
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_all_blocked`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_blocked_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::BlockedList>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/phone/blocked_list", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListBlockedResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.blocked_list;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_all_blocked`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_blocked_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::BlockedList>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_blocked_pages_stream(None))
+    }
     /**
      * Create a blocked list.
      *
@@ -146,9 +237,25 @@ impl PhoneBlockedList {
         &self,
         body: &crate::types::UpdateBlockedListRequest,
     ) -> ClientResult<crate::types::Groups> {
+        Ok(self
+            .add_anumber_blocked_list_with_response(body)
+            .await?
+            .body)
+    }
+    /**
+     * Create a blocked list.
+     *
+     * This function performs a `POST` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `add_anumber_blocked_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_anumber_blocked_list_with_response(
+        &self,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> ClientResult<crate::Response<crate::types::Groups>> {
         let url = self.client.url("/phone/blocked_list", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -177,6 +284,22 @@ impl PhoneBlockedList {
         &self,
         blocked_list_id: &str,
     ) -> ClientResult<crate::types::BlockedList> {
+        Ok(self
+            .get_blocked_list_with_response(blocked_list_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get blocked list details.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list/{blockedListId}` endpoint.
+     *
+     * As opposed to `get_blocked_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_blocked_list_with_response(
+        &self,
+        blocked_list_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::BlockedList>> {
         let url = self.client.url(
             &format!(
                 "/phone/blocked_list/{}",
@@ -185,7 +308,7 @@ impl PhoneBlockedList {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -213,6 +336,21 @@ impl PhoneBlockedList {
      * * `blocked_list_id: &str` -- Unique Identifier of the blocked list. This can be retrieved from the List Blocked List API.
      */
     pub async fn delete_blocked_list(&self, blocked_list_id: &str) -> ClientResult<()> {
+        self.delete_blocked_list_with_response(blocked_list_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a blocked list.
+     *
+     * This function performs a `DELETE` to the `/phone/blocked_list/{blockedListId}` endpoint.
+     *
+     * As opposed to `delete_blocked_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_blocked_list_with_response(
+        &self,
+        blocked_list_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/blocked_list/{}",
@@ -221,7 +359,7 @@ impl PhoneBlockedList {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -252,6 +390,22 @@ impl PhoneBlockedList {
         blocked_list_id: &str,
         body: &crate::types::UpdateBlockedListRequest,
     ) -> ClientResult<()> {
+        self.update_blocked_list_with_response(blocked_list_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a blocked list.
+     *
+     * This function performs a `PATCH` to the `/phone/blocked_list/{blockedListId}` endpoint.
+     *
+     * As opposed to `update_blocked_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_blocked_list_with_response(
+        &self,
+        blocked_list_id: &str,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/blocked_list/{}",
@@ -260,7 +414,7 @@ impl PhoneBlockedList {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
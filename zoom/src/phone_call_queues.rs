@@ -37,6 +37,23 @@ impl PhoneCallQueues {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::CallQueues>> {
+        Ok(self
+            .list_call_queues_with_response(next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_call_queues`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_call_queues_with_response(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::CallQueues>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -48,9 +65,9 @@ impl PhoneCallQueues {
         let url = self
             .client
             .url(&format!("/phone/call_queues?{}", query_), None);
-        let resp: crate::types::ListCallQueuesResponse = self
+        let resp: crate::Response<crate::types::ListCallQueuesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +77,7 @@ impl PhoneCallQueues {
             .await?;
 
         // Return our response data.
-        Ok(resp.call_queues.to_vec())
+        Ok(resp.map(|r| r.call_queues.to_vec()))
     }
     /**
      * List call queues.
@@ -135,6 +152,80 @@ impl PhoneCallQueues {
         // Return our response data.
         Ok(call_queues)
     }
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_all_call_queues`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_call_queues_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::CallQueues>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/phone/call_queues", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListCallQueuesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.call_queues;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_all_call_queues`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_call_queues_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::CallQueues>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_call_queues_pages_stream(None))
+    }
     /**
      * Create a call queue.
      *
@@ -155,9 +246,22 @@ impl PhoneCallQueues {
         &self,
         body: &crate::types::CreateCallQueueRequest,
     ) -> ClientResult<crate::types::CreateCallQueueResponse> {
+        Ok(self.create_call_queue_with_response(body).await?.body)
+    }
+    /**
+     * Create a call queue.
+     *
+     * This function performs a `POST` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `create_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_call_queue_with_response(
+        &self,
+        body: &crate::types::CreateCallQueueRequest,
+    ) -> ClientResult<crate::Response<crate::types::CreateCallQueueResponse>> {
         let url = self.client.url("/phone/call_queues", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -189,6 +293,19 @@ impl PhoneCallQueues {
         &self,
         call_queue_id: &str,
     ) -> ClientResult<crate::types::GetCallQueueResponse> {
+        Ok(self.get_call_queue_with_response(call_queue_id).await?.body)
+    }
+    /**
+     * Get call queue details.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}` endpoint.
+     *
+     * As opposed to `get_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_call_queue_with_response(
+        &self,
+        call_queue_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetCallQueueResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}",
@@ -197,7 +314,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -228,6 +345,20 @@ impl PhoneCallQueues {
      * * `call_queue_id: &str` -- Unique Identifier of the call queue.
      */
     pub async fn delete_call_queue(&self, call_queue_id: &str) -> ClientResult<()> {
+        self.delete_call_queue_with_response(call_queue_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a call queue.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}` endpoint.
+     *
+     * As opposed to `delete_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_call_queue_with_response(
+        &self,
+        call_queue_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}",
@@ -236,7 +367,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -269,6 +400,22 @@ impl PhoneCallQueues {
         call_queue_id: &str,
         body: &crate::types::UpdateCallQueueRequest,
     ) -> ClientResult<()> {
+        self.update_call_queue_with_response(call_queue_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update call queue details.
+     *
+     * This function performs a `PATCH` to the `/phone/call_queues/{callQueueId}` endpoint.
+     *
+     * As opposed to `update_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_call_queue_with_response(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::UpdateCallQueueRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}",
@@ -277,7 +424,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -310,6 +457,20 @@ impl PhoneCallQueues {
         call_queue_id: &str,
         body: &crate::types::AddByocNumberResponse,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self.assign_with_response(call_queue_id, body).await?.body)
+    }
+    /**
+     * Assign numbers to a call queue.
+     *
+     * This function performs a `POST` to the `/phone/call_queues/{callQueueId}/phone_numbers` endpoint.
+     *
+     * As opposed to `assign`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_with_response(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}/phone_numbers",
@@ -318,7 +479,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -349,6 +510,22 @@ impl PhoneCallQueues {
         &self,
         call_queue_id: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .unassign_phone_num_call_queue_with_response(call_queue_id)
+            .await?
+            .body)
+    }
+    /**
+     * Unassign all phone numbers.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}/phone_numbers` endpoint.
+     *
+     * As opposed to `unassign_phone_num_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_phone_num_call_queue_with_response(
+        &self,
+        call_queue_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}/phone_numbers",
@@ -357,7 +534,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -390,6 +567,23 @@ impl PhoneCallQueues {
         call_queue_id: &str,
         phone_number_id: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .un_assign_phone_num_call_queue_with_response(call_queue_id, phone_number_id)
+            .await?
+            .body)
+    }
+    /**
+     * Unassign a phone number.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `un_assign_phone_num_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn un_assign_phone_num_call_queue_with_response(
+        &self,
+        call_queue_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}/phone_numbers/{}",
@@ -399,7 +593,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -431,6 +625,23 @@ impl PhoneCallQueues {
         call_queue_id: &str,
         body: &crate::types::AddMembersCallQueueRequestData,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .add_members_call_queue_with_response(call_queue_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add members to a call queue.
+     *
+     * This function performs a `POST` to the `/phone/call_queues/{callQueueId}/members` endpoint.
+     *
+     * As opposed to `add_members_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_members_call_queue_with_response(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddMembersCallQueueRequestData,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}/members",
@@ -439,7 +650,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -467,6 +678,21 @@ impl PhoneCallQueues {
      * * `call_queue_id: &str` -- User's first name.
      */
     pub async fn unassign_all_members(&self, call_queue_id: &str) -> ClientResult<()> {
+        self.unassign_all_members_with_response(call_queue_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign all members.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}/members` endpoint.
+     *
+     * As opposed to `unassign_all_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_all_members_with_response(
+        &self,
+        call_queue_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}/members",
@@ -475,7 +701,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -508,6 +734,22 @@ impl PhoneCallQueues {
         call_queue_id: &str,
         member_id: &str,
     ) -> ClientResult<()> {
+        self.unassign_member_from_call_queue_with_response(call_queue_id, member_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign a member.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `unassign_member_from_call_queue`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn unassign_member_from_call_queue_with_response(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}/members/{}",
@@ -517,7 +759,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -551,6 +793,22 @@ impl PhoneCallQueues {
         call_queue_id: &str,
         body: &crate::types::ChangeCallQueueManagerRequest,
     ) -> ClientResult<()> {
+        self.change_call_queue_manager_with_response(call_queue_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Change call queue manager.
+     *
+     * This function performs a `PUT` to the `/phone/call_queues/{callQueueId}/manager` endpoint.
+     *
+     * As opposed to `change_call_queue_manager`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn change_call_queue_manager_with_response(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::ChangeCallQueueManagerRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/call_queues/{}/manager",
@@ -559,7 +817,7 @@ impl PhoneCallQueues {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -598,6 +856,32 @@ impl PhoneCallQueues {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<Vec<crate::types::GetCallQueueRecordingsResponse>> {
+        Ok(self
+            .get_call_queue_recordings_with_response(
+                call_queue_id,
+                page_size,
+                next_page_token,
+                from,
+                to,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_call_queue_recordings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_call_queue_recordings_with_response(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<Vec<crate::types::GetCallQueueRecordingsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -620,9 +904,9 @@ impl PhoneCallQueues {
             ),
             None,
         );
-        let resp: crate::types::GetCallQueueRecordingsResponseData = self
+        let resp: crate::Response<crate::types::GetCallQueueRecordingsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -632,7 +916,7 @@ impl PhoneCallQueues {
             .await?;
 
         // Return our response data.
-        Ok(resp.recordings.to_vec())
+        Ok(resp.map(|r| r.recordings.to_vec()))
     }
     /**
      * Get call queue recordings.
@@ -725,4 +1009,107 @@ impl PhoneCallQueues {
         // Return our response data.
         Ok(recordings)
     }
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_call_queue_recordings`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_call_queue_recordings_pages_stream(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::GetCallQueueRecordingsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/phone/call_queues/{}/recordings?{}",
+                crate::progenitor_support::encode_path(call_queue_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::GetCallQueueRecordingsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.recordings;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_all_call_queue_recordings`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_call_queue_recordings_stream(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::GetCallQueueRecordingsResponse>>
+           + Unpin
+           + '_ {
+        crate::utils::page_items(self.get_all_call_queue_recordings_pages_stream(
+            call_queue_id,
+            from,
+            to,
+            None,
+        ))
+    }
 }
@@ -36,6 +36,24 @@ impl PhoneDevices {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::ListPhoneDevicesResponse>> {
+        Ok(self
+            .list_with_response(type_, next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListPhoneDevicesResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -48,9 +66,9 @@ impl PhoneDevices {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/devices?{}", query_), None);
-        let resp: crate::types::ListPhoneDevicesResponseData = self
+        let resp: crate::Response<crate::types::ListPhoneDevicesResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +78,7 @@ impl PhoneDevices {
             .await?;
 
         // Return our response data.
-        Ok(resp.devices.to_vec())
+        Ok(resp.map(|r| r.devices.to_vec()))
     }
     /**
      * List devices.
@@ -140,6 +158,89 @@ impl PhoneDevices {
         // Return our response data.
         Ok(devices)
     }
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListPhoneDevicesResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/phone/devices?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListPhoneDevicesResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.devices;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListPhoneDevicesResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_pages_stream(type_, None))
+    }
     /**
      * Add a device.
      *
@@ -157,9 +258,23 @@ impl PhoneDevices {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      */
     pub async fn add(&self, body: &crate::types::AddPhoneDeviceRequest) -> ClientResult<()> {
+        self.add_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Add a device.
+     *
+     * This function performs a `POST` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::AddPhoneDeviceRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/phone/devices", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -189,6 +304,19 @@ impl PhoneDevices {
         &self,
         device_id: &str,
     ) -> ClientResult<crate::types::GetDeviceResponse> {
+        Ok(self.get_device_with_response(device_id).await?.body)
+    }
+    /**
+     * Get device details.
+     *
+     * This function performs a `GET` to the `/phone/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `get_device`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_device_with_response(
+        &self,
+        device_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetDeviceResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/devices/{}",
@@ -197,7 +325,7 @@ impl PhoneDevices {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -226,6 +354,20 @@ impl PhoneDevices {
      * * `device_id: &str` -- Unique Identifier of the device.
      */
     pub async fn delete_device(&self, device_id: &str) -> ClientResult<()> {
+        self.delete_device_with_response(device_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a device.
+     *
+     * This function performs a `DELETE` to the `/phone/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `delete_device`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_device_with_response(
+        &self,
+        device_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/devices/{}",
@@ -234,7 +376,7 @@ impl PhoneDevices {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -266,6 +408,21 @@ impl PhoneDevices {
         device_id: &str,
         body: &crate::types::UpdateDeviceRequest,
     ) -> ClientResult<()> {
+        self.update_device_with_response(device_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update a device.
+     *
+     * This function performs a `PATCH` to the `/phone/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `update_device`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_device_with_response(
+        &self,
+        device_id: &str,
+        body: &crate::types::UpdateDeviceRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/devices/{}",
@@ -274,7 +431,7 @@ impl PhoneDevices {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
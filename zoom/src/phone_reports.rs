@@ -41,6 +41,26 @@ impl PhoneReports {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::ReportOperationLogsResponseAllOf> {
+        Ok(self
+            .get_ps_operation_log_with_response(from, to, category_type, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get operation logs report.
+     *
+     * This function performs a `GET` to the `/phone/reports/operationlogs` endpoint.
+     *
+     * As opposed to `get_ps_operation_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_ps_operation_log_with_response(
+        &self,
+        from: &str,
+        to: &str,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !category_type.is_empty() {
             query_args.push(("category_type".to_string(), category_type.to_string()));
@@ -62,7 +82,7 @@ impl PhoneReports {
             .client
             .url(&format!("/phone/reports/operationlogs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
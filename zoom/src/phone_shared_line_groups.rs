@@ -37,6 +37,23 @@ impl PhoneSharedLineGroups {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::SharedLineGroups>> {
+        Ok(self
+            .list_shared_line_groups_with_response(page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_shared_line_groups`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_shared_line_groups_with_response(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::SharedLineGroups>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -48,9 +65,9 @@ impl PhoneSharedLineGroups {
         let url = self
             .client
             .url(&format!("/phone/shared_line_groups?{}", query_), None);
-        let resp: crate::types::ListSharedLineGroupsResponse = self
+        let resp: crate::Response<crate::types::ListSharedLineGroupsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -60,7 +77,7 @@ impl PhoneSharedLineGroups {
             .await?;
 
         // Return our response data.
-        Ok(resp.shared_line_groups.to_vec())
+        Ok(resp.map(|r| r.shared_line_groups.to_vec()))
     }
     /**
      * List shared line groups.
@@ -137,6 +154,81 @@ impl PhoneSharedLineGroups {
         // Return our response data.
         Ok(shared_line_groups)
     }
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_all_shared_line_groups`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_shared_line_groups_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::SharedLineGroups>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url("/phone/shared_line_groups", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListSharedLineGroupsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.shared_line_groups;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_all_shared_line_groups`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_shared_line_groups_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::SharedLineGroups>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_shared_line_groups_pages_stream(None))
+    }
     /**
      * Create a shared line group.
      *
@@ -156,9 +248,23 @@ impl PhoneSharedLineGroups {
         &self,
         body: &crate::types::CreateSharedLineGroupRequest,
     ) -> ClientResult<()> {
+        self.create_shared_line_group_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Create a shared line group.
+     *
+     * This function performs a `POST` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `create_shared_line_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_shared_line_group_with_response(
+        &self,
+        body: &crate::types::CreateSharedLineGroupRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/phone/shared_line_groups", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -191,6 +297,22 @@ impl PhoneSharedLineGroups {
         &self,
         shared_line_group_id: &str,
     ) -> ClientResult<crate::types::GetSharedLineGroupResponse> {
+        Ok(self
+            .get_shared_line_group_with_response(shared_line_group_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a shared line group.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups/{sharedLineGroupId}` endpoint.
+     *
+     * As opposed to `get_shared_line_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_shared_line_group_with_response(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSharedLineGroupResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}",
@@ -199,7 +321,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -228,6 +350,21 @@ impl PhoneSharedLineGroups {
      * * `shared_line_group_id: &str` -- Unique Identifier of the shared line group that you would like to delete.
      */
     pub async fn delete_shared_line_group(&self, shared_line_group_id: &str) -> ClientResult<()> {
+        self.delete_shared_line_group_with_response(shared_line_group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a shared line group.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}` endpoint.
+     *
+     * As opposed to `delete_shared_line_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_shared_line_group_with_response(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}",
@@ -236,7 +373,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -268,6 +405,22 @@ impl PhoneSharedLineGroups {
         shared_line_group_id: &str,
         body: &crate::types::UpdateSharedLineGroupRequest,
     ) -> ClientResult<()> {
+        self.update_shared_line_group_with_response(shared_line_group_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a shared line group.
+     *
+     * This function performs a `PATCH` to the `/phone/shared_line_groups/{sharedLineGroupId}` endpoint.
+     *
+     * As opposed to `update_shared_line_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_shared_line_group_with_response(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::UpdateSharedLineGroupRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}",
@@ -276,7 +429,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -311,6 +464,22 @@ impl PhoneSharedLineGroups {
         shared_line_group_id: &str,
         body: &crate::types::AddMembersSharedLineGroupRequestData,
     ) -> ClientResult<()> {
+        self.add_members_shared_line_group_with_response(shared_line_group_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Add members to a shared line group.
+     *
+     * This function performs a `POST` to the `/phone/shared_line_groups/{sharedLineGroupId}/members` endpoint.
+     *
+     * As opposed to `add_members_shared_line_group`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_members_shared_line_group_with_response(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddMembersSharedLineGroupRequestData,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}/members",
@@ -319,7 +488,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -349,6 +518,21 @@ impl PhoneSharedLineGroups {
      * * `shared_line_group_id: &str` -- Unique identifier of the Shared Line Group that you would like to delete.
      */
     pub async fn delete_members_of_slg(&self, shared_line_group_id: &str) -> ClientResult<()> {
+        self.delete_members_of_slg_with_response(shared_line_group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign members of a shared line group.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/members` endpoint.
+     *
+     * As opposed to `delete_members_of_slg`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_members_of_slg_with_response(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}/members",
@@ -357,7 +541,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -392,6 +576,22 @@ impl PhoneSharedLineGroups {
         shared_line_group_id: &str,
         member_id: &str,
     ) -> ClientResult<()> {
+        self.delete_member_slg_with_response(shared_line_group_id, member_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign a member from a shared line group.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `delete_member_slg`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_member_slg_with_response(
+        &self,
+        shared_line_group_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}/members/{}",
@@ -401,7 +601,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -433,6 +633,22 @@ impl PhoneSharedLineGroups {
         shared_line_group_id: &str,
         body: &crate::types::AddByocNumberResponse,
     ) -> ClientResult<()> {
+        self.assign_phone_numbers_slg_with_response(shared_line_group_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Assign phone numbers.
+     *
+     * This function performs a `POST` to the `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers` endpoint.
+     *
+     * As opposed to `assign_phone_numbers_slg`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_phone_numbers_slg_with_response(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}/phone_numbers",
@@ -441,7 +657,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -469,6 +685,21 @@ impl PhoneSharedLineGroups {
      * * `shared_line_group_id: &str` -- Unique Identifier of the Shared Line Group.
      */
     pub async fn delete_phone_numbers_slg(&self, shared_line_group_id: &str) -> ClientResult<()> {
+        self.delete_phone_numbers_slg_with_response(shared_line_group_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign all phone numbers.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers` endpoint.
+     *
+     * As opposed to `delete_phone_numbers_slg`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_phone_numbers_slg_with_response(
+        &self,
+        shared_line_group_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}/phone_numbers",
@@ -477,7 +708,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -510,6 +741,22 @@ impl PhoneSharedLineGroups {
         shared_line_group_id: &str,
         phone_number_id: &str,
     ) -> ClientResult<()> {
+        self.delete_phone_number_slg_with_response(shared_line_group_id, phone_number_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Unassign a phone number.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `delete_phone_number_slg`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_phone_number_slg_with_response(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/shared_line_groups/{}/phone_numbers/{}",
@@ -519,7 +766,7 @@ impl PhoneSharedLineGroups {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
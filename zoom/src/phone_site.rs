@@ -34,6 +34,23 @@ impl PhoneSite {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::Sites>> {
+        Ok(self
+            .list_with_response(page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Sites>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -43,9 +60,9 @@ impl PhoneSite {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/phone/sites?{}", query_), None);
-        let resp: crate::types::ListPhoneSitesResponse = self
+        let resp: crate::Response<crate::types::ListPhoneSitesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,7 +72,7 @@ impl PhoneSite {
             .await?;
 
         // Return our response data.
-        Ok(resp.sites.to_vec())
+        Ok(resp.map(|r| r.sites.to_vec()))
     }
     /**
      * List phone sites.
@@ -127,6 +144,79 @@ impl PhoneSite {
         // Return our response data.
         Ok(sites)
     }
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Sites>>> + Unpin + '_
+    {
+        let url = self.client.url("/phone/sites", None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListPhoneSitesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.sites;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Sites>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(None))
+    }
     /**
      * Create a phone site.
      *
@@ -146,9 +236,22 @@ impl PhoneSite {
         &self,
         body: &crate::types::CreatePhoneSiteRequest,
     ) -> ClientResult<crate::types::Site> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Create a phone site.
+     *
+     * This function performs a `POST` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::CreatePhoneSiteRequest,
+    ) -> ClientResult<crate::Response<crate::types::Site>> {
         let url = self.client.url("/phone/sites", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -178,6 +281,19 @@ impl PhoneSite {
      * * `site_id: &str` -- Unique Identifier of the Site.
      */
     pub async fn get_site(&self, site_id: &str) -> ClientResult<crate::types::GetSiteResponse> {
+        Ok(self.get_site_with_response(site_id).await?.body)
+    }
+    /**
+     * Get phone site details.
+     *
+     * This function performs a `GET` to the `/phone/sites/{siteId}` endpoint.
+     *
+     * As opposed to `get_site`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_site_with_response(
+        &self,
+        site_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetSiteResponse>> {
         let url = self.client.url(
             &format!(
                 "/phone/sites/{}",
@@ -186,7 +302,7 @@ impl PhoneSite {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -217,6 +333,21 @@ impl PhoneSite {
      * * `transfer_site_id: &str` -- The Site ID of another site where the assets of the current site (users, numbers and phones) can be transferred to.
      */
     pub async fn delete(&self, site_id: &str, transfer_site_id: &str) -> ClientResult<()> {
+        self.delete_with_response(site_id, transfer_site_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a phone site.
+     *
+     * This function performs a `DELETE` to the `/phone/sites/{siteId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        site_id: &str,
+        transfer_site_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !transfer_site_id.is_empty() {
             query_args.push(("transfer_site_id".to_string(), transfer_site_id.to_string()));
@@ -231,7 +362,7 @@ impl PhoneSite {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -264,6 +395,22 @@ impl PhoneSite {
         site_id: &str,
         body: &crate::types::UpdateSiteDetailsRequest,
     ) -> ClientResult<()> {
+        self.update_site_details_with_response(site_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update phone site details.
+     *
+     * This function performs a `PATCH` to the `/phone/sites/{siteId}` endpoint.
+     *
+     * As opposed to `update_site_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_site_details_with_response(
+        &self,
+        site_id: &str,
+        body: &crate::types::UpdateSiteDetailsRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/phone/sites/{}",
@@ -272,7 +419,7 @@ impl PhoneSite {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -33,6 +33,20 @@ impl Reports {
         year: i64,
         month: i64,
     ) -> ClientResult<crate::types::ReportDailyResponse> {
+        Ok(self.daily_with_response(year, month).await?.body)
+    }
+    /**
+     * Get daily usage report.
+     *
+     * This function performs a `GET` to the `/report/daily` endpoint.
+     *
+     * As opposed to `daily`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn daily_with_response(
+        &self,
+        year: i64,
+        month: i64,
+    ) -> ClientResult<crate::Response<crate::types::ReportDailyResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if month > 0 {
             query_args.push(("month".to_string(), month.to_string()));
@@ -43,7 +57,7 @@ impl Reports {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/report/daily?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -83,6 +97,27 @@ impl Reports {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .user_with_response(type_, from, to, page_size, page_number, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get active/inactive host reports.
+     *
+     * This function performs a `GET` to the `/report/users` endpoint.
+     *
+     * As opposed to `user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_with_response(
+        &self,
+        type_: crate::types::ReportUsersType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -105,7 +140,7 @@ impl Reports {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/report/users?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -146,6 +181,27 @@ impl Reports {
         next_page_token: &str,
         type_: crate::types::ReportMeetingsType,
     ) -> ClientResult<crate::types::ReportMeetingsResponseAllOf> {
+        Ok(self
+            .meeting_with_response(user_id, from, to, page_size, next_page_token, type_)
+            .await?
+            .body)
+    }
+    /**
+     * Get meeting reports.
+     *
+     * This function performs a `GET` to the `/report/users/{userId}/meetings` endpoint.
+     *
+     * As opposed to `meeting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_with_response(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        type_: crate::types::ReportMeetingsType,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -172,7 +228,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -204,6 +260,19 @@ impl Reports {
         &self,
         meeting_id: &str,
     ) -> ClientResult<crate::types::ReportMeetingDetailsResponse> {
+        Ok(self.meeting_details_with_response(meeting_id).await?.body)
+    }
+    /**
+     * Get meeting detail reports.
+     *
+     * This function performs a `GET` to the `/report/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `meeting_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_details_with_response(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingDetailsResponse>> {
         let url = self.client.url(
             &format!(
                 "/report/meetings/{}",
@@ -212,7 +281,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -251,6 +320,30 @@ impl Reports {
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> ClientResult<crate::types::ReportMeetingParticipantsResponseAllOf> {
+        Ok(self
+            .meeting_participant_with_response(
+                meeting_id,
+                page_size,
+                next_page_token,
+                include_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get meeting participant reports.
+     *
+     * This function performs a `GET` to the `/report/meetings/{meetingId}/participants` endpoint.
+     *
+     * As opposed to `meeting_participant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_participant_with_response(
+        &self,
+        meeting_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingParticipantsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
@@ -271,7 +364,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -303,6 +396,19 @@ impl Reports {
         &self,
         meeting_id: &str,
     ) -> ClientResult<crate::types::ReportMeetingPollsResponse> {
+        Ok(self.meeting_polls_with_response(meeting_id).await?.body)
+    }
+    /**
+     * Get meeting poll reports.
+     *
+     * This function performs a `GET` to the `/report/meetings/{meetingId}/polls` endpoint.
+     *
+     * As opposed to `meeting_polls`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn meeting_polls_with_response(
+        &self,
+        meeting_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
         let url = self.client.url(
             &format!(
                 "/report/meetings/{}/polls",
@@ -311,7 +417,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -342,6 +448,19 @@ impl Reports {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::ReportMeetingDetailsResponse> {
+        Ok(self.webinar_details_with_response(webinar_id).await?.body)
+    }
+    /**
+     * Get webinar detail reports.
+     *
+     * This function performs a `GET` to the `/report/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `webinar_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_details_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingDetailsResponse>> {
         let url = self.client.url(
             &format!(
                 "/report/webinars/{}",
@@ -350,7 +469,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -387,6 +506,30 @@ impl Reports {
         next_page_token: &str,
         include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
     ) -> ClientResult<crate::types::ReportWebinarParticipantsResponseAllOf> {
+        Ok(self
+            .webinar_participant_with_response(
+                webinar_id,
+                page_size,
+                next_page_token,
+                include_fields,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get webinar participant reports.
+     *
+     * This function performs a `GET` to the `/report/webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `webinar_participant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_participant_with_response(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarParticipantsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
@@ -407,7 +550,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -438,6 +581,19 @@ impl Reports {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::ReportMeetingPollsResponse> {
+        Ok(self.webinar_polls_with_response(webinar_id).await?.body)
+    }
+    /**
+     * Get webinar poll reports.
+     *
+     * This function performs a `GET` to the `/report/webinars/{webinarId}/polls` endpoint.
+     *
+     * As opposed to `webinar_polls`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_polls_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
         let url = self.client.url(
             &format!(
                 "/report/webinars/{}/polls",
@@ -446,7 +602,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -479,6 +635,19 @@ impl Reports {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::ReportWebinarQaResponse> {
+        Ok(self.webinar_qa_with_response(webinar_id).await?.body)
+    }
+    /**
+     * Get webinar Q&A report.
+     *
+     * This function performs a `GET` to the `/report/webinars/{webinarId}/qa` endpoint.
+     *
+     * As opposed to `webinar_qa`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_qa_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarQaResponse>> {
         let url = self.client.url(
             &format!(
                 "/report/webinars/{}/qa",
@@ -487,7 +656,7 @@ impl Reports {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -532,6 +701,36 @@ impl Reports {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .telephone_with_response(
+                type_,
+                query_date_type,
+                from,
+                to,
+                page_size,
+                page_number,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * Get telephone reports.
+     *
+     * This function performs a `GET` to the `/report/telephone` endpoint.
+     *
+     * As opposed to `telephone`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn telephone_with_response(
+        &self,
+        type_: &str,
+        query_date_type: crate::types::QueryDateType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -559,7 +758,7 @@ impl Reports {
             .client
             .url(&format!("/report/telephone?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -590,6 +789,20 @@ impl Reports {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> ClientResult<crate::types::ReportCloudRecordingResponseAllOf> {
+        Ok(self.cloud_recording_with_response(from, to).await?.body)
+    }
+    /**
+     * Get cloud recording usage report.
+     *
+     * This function performs a `GET` to the `/report/cloud_recording` endpoint.
+     *
+     * As opposed to `cloud_recording`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn cloud_recording_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> ClientResult<crate::Response<crate::types::ReportCloudRecordingResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -602,7 +815,7 @@ impl Reports {
             .client
             .url(&format!("/report/cloud_recording?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -642,6 +855,26 @@ impl Reports {
         next_page_token: &str,
         category_type: crate::types::CategoryType,
     ) -> ClientResult<crate::types::ReportOperationLogsResponseAllOf> {
+        Ok(self
+            .operation_log_with_response(from, to, page_size, next_page_token, category_type)
+            .await?
+            .body)
+    }
+    /**
+     * Get operation logs report.
+     *
+     * This function performs a `GET` to the `/report/operationlogs` endpoint.
+     *
+     * As opposed to `operation_log`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn operation_log_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        category_type: crate::types::CategoryType,
+    ) -> ClientResult<crate::Response<crate::types::ReportOperationLogsResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !category_type.to_string().is_empty() {
             query_args.push(("category_type".to_string(), category_type.to_string()));
@@ -663,7 +896,7 @@ impl Reports {
             .client
             .url(&format!("/report/operationlogs?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -698,6 +931,25 @@ impl Reports {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::ActivityLogs>> {
+        Ok(self
+            .sign_out_activities_with_response(from, to, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `sign_out_activities`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn sign_out_activities_with_response(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ActivityLogs>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !from.to_string().is_empty() {
             query_args.push(("from".to_string(), from.to_string()));
@@ -715,9 +967,9 @@ impl Reports {
         let url = self
             .client
             .url(&format!("/report/activities?{}", query_), None);
-        let resp: crate::types::ReportSignInOutActivitiesResponse = self
+        let resp: crate::Response<crate::types::ReportSignInOutActivitiesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -727,7 +979,7 @@ impl Reports {
             .await?;
 
         // Return our response data.
-        Ok(resp.activity_logs.to_vec())
+        Ok(resp.map(|r| r.activity_logs.to_vec()))
     }
     /**
      * Get sign In / sign out activity report.
@@ -812,6 +1064,94 @@ impl Reports {
         // Return our response data.
         Ok(activity_logs)
     }
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `get_all_sign_out_activities`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_sign_out_activities_pages_stream(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::ActivityLogs>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/report/activities?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ReportSignInOutActivitiesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.activity_logs;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `get_all_sign_out_activities`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_sign_out_activities_stream(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ActivityLogs>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_sign_out_activities_pages_stream(from, to, None))
+    }
     /**
      * Get billing reports.
      *
@@ -827,9 +1167,21 @@ impl Reports {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
      */
     pub async fn get_billing(&self) -> ClientResult<crate::types::GetBillingReportResponse> {
+        Ok(self.get_billing_with_response().await?.body)
+    }
+    /**
+     * Get billing reports.
+     *
+     * This function performs a `GET` to the `/report/billing` endpoint.
+     *
+     * As opposed to `get_billing`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_billing_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetBillingReportResponse>> {
         let url = self.client.url("/report/billing", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -863,6 +1215,22 @@ impl Reports {
         &self,
         billing_id: &str,
     ) -> ClientResult<crate::types::GetBillingInvoicesReportsResponse> {
+        Ok(self
+            .get_billing_invoices_with_response(billing_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get billing invoice reports.
+     *
+     * This function performs a `GET` to the `/report/billing/invoices` endpoint.
+     *
+     * As opposed to `get_billing_invoices`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_billing_invoices_with_response(
+        &self,
+        billing_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetBillingInvoicesReportsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !billing_id.is_empty() {
             query_args.push(("billing_id".to_string(), billing_id.to_string()));
@@ -872,7 +1240,7 @@ impl Reports {
             .client
             .url(&format!("/report/billing/invoices?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
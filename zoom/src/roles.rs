@@ -27,9 +27,19 @@ impl Roles {
      * *  For subsequent role management, you must be the Account Owner or user with role management permissions.
      */
     pub async fn get(&self) -> ClientResult<crate::types::Domains> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * List roles.
+     *
+     * This function performs a `GET` to the `/roles` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(&self) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url("/roles", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -54,9 +64,23 @@ impl Roles {
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      */
     pub async fn create(&self, body: &crate::types::CreateRoleRequest) -> ClientResult<()> {
+        self.create_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Create a role.
+     *
+     * This function performs a `POST` to the `/roles` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::CreateRoleRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/roles", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -96,6 +120,26 @@ impl Roles {
         next_page_token: &str,
         page_size: i64,
     ) -> ClientResult<Vec<crate::types::Domains>> {
+        Ok(self
+            .members_with_response(role_id, page_count, page_number, next_page_token, page_size)
+            .await?
+            .body)
+    }
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn members_with_response(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Domains>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -118,9 +162,9 @@ impl Roles {
             ),
             None,
         );
-        let resp: crate::types::RoleMembersList = self
+        let resp: crate::Response<crate::types::RoleMembersList> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,7 +174,7 @@ impl Roles {
             .await?;
 
         // Return our response data.
-        Ok(resp.members.to_vec())
+        Ok(resp.map(|r| r.members.to_vec()))
     }
     /**
      * List members in a role.
@@ -217,6 +261,95 @@ impl Roles {
         // Return our response data.
         Ok(members)
     }
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_members_pages_stream(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Domains>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !page_count.is_empty() {
+            query_args.push(("page_count".to_string(), page_count.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(
+            &format!(
+                "/roles/{}/members?{}",
+                crate::progenitor_support::encode_path(role_id),
+                query_
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::RoleMembersList = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.members;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `get_all_members`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_members_stream(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Domains>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_members_pages_stream(role_id, page_count, None))
+    }
     /**
      * Assign a role.
      *
@@ -239,6 +372,20 @@ impl Roles {
         role_id: &str,
         body: &crate::types::AddRoleMembersRequest,
     ) -> ClientResult<crate::types::AddRoleMembersResponse> {
+        Ok(self.add_members_with_response(role_id, body).await?.body)
+    }
+    /**
+     * Assign a role.
+     *
+     * This function performs a `POST` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `add_members`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_members_with_response(
+        &self,
+        role_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddRoleMembersResponse>> {
         let url = self.client.url(
             &format!(
                 "/roles/{}/members",
@@ -247,7 +394,7 @@ impl Roles {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -275,6 +422,21 @@ impl Roles {
      * * `member_id: &str` -- User's first name.
      */
     pub async fn member_delete(&self, role_id: &str, member_id: &str) -> ClientResult<()> {
+        self.member_delete_with_response(role_id, member_id).await?;
+        Ok(())
+    }
+    /**
+     * Unassign a role.
+     *
+     * This function performs a `DELETE` to the `/roles/{roleId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `member_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn member_delete_with_response(
+        &self,
+        role_id: &str,
+        member_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/roles/{}/members/{}",
@@ -284,7 +446,7 @@ impl Roles {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -317,12 +479,25 @@ impl Roles {
         &self,
         role_id: &str,
     ) -> ClientResult<crate::types::GetRoleInformationResponse> {
+        Ok(self.get_information_with_response(role_id).await?.body)
+    }
+    /**
+     * Get role information.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}` endpoint.
+     *
+     * As opposed to `get_information`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_information_with_response(
+        &self,
+        role_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetRoleInformationResponse>> {
         let url = self.client.url(
             &format!("/roles/{}", crate::progenitor_support::encode_path(role_id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -352,12 +527,23 @@ impl Roles {
      * * `role_id: &str` -- User's first name.
      */
     pub async fn delete(&self, role_id: &str) -> ClientResult<()> {
+        self.delete_with_response(role_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a role.
+     *
+     * This function performs a `DELETE` to the `/roles/{roleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, role_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/roles/{}", crate::progenitor_support::encode_path(role_id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -389,12 +575,27 @@ impl Roles {
         role_id: &str,
         body: &crate::types::UpdateRoleRequest,
     ) -> ClientResult<()> {
+        self.update_with_response(role_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update role information.
+     *
+     * This function performs a `PATCH` to the `/roles/{roleId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        role_id: &str,
+        body: &crate::types::UpdateRoleRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!("/roles/{}", crate::progenitor_support::encode_path(role_id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -40,6 +40,34 @@ impl Rooms {
         next_page_token: &str,
         location_id: &str,
     ) -> ClientResult<Vec<crate::types::ListZoomRoomsResponse>> {
+        Ok(self
+            .list_zoom_with_response(
+                status,
+                type_,
+                unassigned_rooms,
+                page_size,
+                next_page_token,
+                location_id,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_zoom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_zoom_with_response(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::ListZoomRoomsResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !location_id.is_empty() {
             query_args.push(("location_id".to_string(), location_id.to_string()));
@@ -61,9 +89,9 @@ impl Rooms {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/rooms?{}", query_), None);
-        let resp: crate::types::ListZoomRoomsResponseData = self
+        let resp: crate::Response<crate::types::ListZoomRoomsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -73,7 +101,7 @@ impl Rooms {
             .await?;
 
         // Return our response data.
-        Ok(resp.rooms.to_vec())
+        Ok(resp.map(|r| r.rooms.to_vec()))
     }
     /**
      * List Zoom Rooms.
@@ -163,6 +191,110 @@ impl Rooms {
         // Return our response data.
         Ok(rooms)
     }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_all_zoom`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_zoom_pages_stream(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        location_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::ListZoomRoomsResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !location_id.is_empty() {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if unassigned_rooms {
+            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/rooms?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListZoomRoomsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.rooms;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_all_zoom`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_zoom_stream(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::ListZoomRoomsResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_zoom_pages_stream(
+            status,
+            type_,
+            unassigned_rooms,
+            location_id,
+            None,
+        ))
+    }
     /**
      * Add a Zoom Room.
      *
@@ -178,9 +310,22 @@ impl Rooms {
         &self,
         body: &crate::types::AddRoomRequest,
     ) -> ClientResult<crate::types::AddRoomResponse> {
+        Ok(self.add_with_response(body).await?.body)
+    }
+    /**
+     * Add a Zoom Room.
+     *
+     * This function performs a `POST` to the `/rooms` endpoint.
+     *
+     * As opposed to `add`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_with_response(
+        &self,
+        body: &crate::types::AddRoomRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddRoomResponse>> {
         let url = self.client.url("/rooms", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -210,12 +355,25 @@ impl Rooms {
         &self,
         room_id: &str,
     ) -> ClientResult<crate::types::GetZrProfileResponse> {
+        Ok(self.get_zr_profile_with_response(room_id).await?.body)
+    }
+    /**
+     * Get Zoom Room profile.
+     *
+     * This function performs a `GET` to the `/rooms/{roomId}` endpoint.
+     *
+     * As opposed to `get_zr_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zr_profile_with_response(
+        &self,
+        room_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetZrProfileResponse>> {
         let url = self.client.url(
             &format!("/rooms/{}", crate::progenitor_support::encode_path(room_id),),
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -240,12 +398,25 @@ impl Rooms {
      * * `room_id: &str` -- Unique Identifier of a Zoom Room.
      */
     pub async fn delete_zoom(&self, room_id: &str) -> ClientResult<crate::types::Domains> {
+        Ok(self.delete_zoom_with_response(room_id).await?.body)
+    }
+    /**
+     * Delete a Zoom Room.
+     *
+     * This function performs a `DELETE` to the `/rooms/{roomId}` endpoint.
+     *
+     * As opposed to `delete_zoom`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_zoom_with_response(
+        &self,
+        room_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!("/rooms/{}", crate::progenitor_support::encode_path(room_id),),
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -275,12 +446,26 @@ impl Rooms {
         room_id: &str,
         body: &crate::types::UpdateRoomProfileRequest,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self.update_profile_with_response(room_id, body).await?.body)
+    }
+    /**
+     * Update a Zoom Room profile.
+     *
+     * This function performs a `PATCH` to the `/rooms/{roomId}` endpoint.
+     *
+     * As opposed to `update_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_profile_with_response(
+        &self,
+        room_id: &str,
+        body: &crate::types::UpdateRoomProfileRequest,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!("/rooms/{}", crate::progenitor_support::encode_path(room_id),),
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -313,6 +498,23 @@ impl Rooms {
         room_id: &str,
         setting_type: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_zr_setting_with_response(room_id, setting_type)
+            .await?
+            .body)
+    }
+    /**
+     * Get Zoom Room settings.
+     *
+     * This function performs a `GET` to the `/rooms/{roomId}/settings` endpoint.
+     *
+     * As opposed to `get_zr_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zr_setting_with_response(
+        &self,
+        room_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !setting_type.is_empty() {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
@@ -327,7 +529,7 @@ impl Rooms {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -356,6 +558,22 @@ impl Rooms {
      *   `signage`: Digital signage settings applied on the Zoom Room.
      */
     pub async fn update_zr_settings(&self, room_id: &str, setting_type: &str) -> ClientResult<()> {
+        self.update_zr_settings_with_response(room_id, setting_type)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update Zoom Room settings.
+     *
+     * This function performs a `PATCH` to the `/rooms/{roomId}/settings` endpoint.
+     *
+     * As opposed to `update_zr_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_zr_settings_with_response(
+        &self,
+        room_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !setting_type.is_empty() {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
@@ -370,7 +588,7 @@ impl Rooms {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -400,6 +618,19 @@ impl Rooms {
         &self,
         room_id: &str,
     ) -> ClientResult<crate::types::ListZrDevicesResponse> {
+        Ok(self.list_zr_devices_with_response(room_id).await?.body)
+    }
+    /**
+     * List Zoom Room devices.
+     *
+     * This function performs a `GET` to the `/rooms/{roomId}/devices` endpoint.
+     *
+     * As opposed to `list_zr_devices`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_zr_devices_with_response(
+        &self,
+        room_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListZrDevicesResponse>> {
         let url = self.client.url(
             &format!(
                 "/rooms/{}/devices",
@@ -408,7 +639,7 @@ impl Rooms {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -438,6 +669,21 @@ impl Rooms {
         room_id: &str,
         body: &crate::types::ChangeZrLocationRequest,
     ) -> ClientResult<()> {
+        self.change_zr_location_with_response(room_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Change a Zoom Room's location.
+     *
+     * This function performs a `PUT` to the `/rooms/{roomId}/location` endpoint.
+     *
+     * As opposed to `change_zr_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn change_zr_location_with_response(
+        &self,
+        room_id: &str,
+        body: &crate::types::ChangeZrLocationRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/rooms/{}/location",
@@ -446,7 +692,7 @@ impl Rooms {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -477,6 +723,21 @@ impl Rooms {
         id: &str,
         body: &crate::types::CheckInRoomsRequest,
     ) -> ClientResult<()> {
+        self.check_with_response(id, body).await?;
+        Ok(())
+    }
+    /**
+     * Check-in or check-out of a Zoom Room.
+     *
+     * This function performs a `PATCH` to the `/rooms/{id}/events` endpoint.
+     *
+     * As opposed to `check`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn check_with_response(
+        &self,
+        id: &str,
+        body: &crate::types::CheckInRoomsRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/rooms/{}/events",
@@ -485,7 +746,7 @@ impl Rooms {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -524,6 +785,30 @@ impl Rooms {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::Site>> {
+        Ok(self
+            .list_digital_signage_content_with_response(
+                type_,
+                folder_id,
+                page_size,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_digital_signage_content`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_digital_signage_content_with_response(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Site>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !folder_id.is_empty() {
             query_args.push(("folder_id".to_string(), folder_id.to_string()));
@@ -541,9 +826,9 @@ impl Rooms {
         let url = self
             .client
             .url(&format!("/rooms/digital_signage?{}", query_), None);
-        let resp: crate::types::ListDigitalSignageContentResponse = self
+        let resp: crate::Response<crate::types::ListDigitalSignageContentResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -553,7 +838,7 @@ impl Rooms {
             .await?;
 
         // Return our response data.
-        Ok(resp.contents.to_vec())
+        Ok(resp.map(|r| r.contents.to_vec()))
     }
     /**
      * List digital signage contents.
@@ -640,6 +925,95 @@ impl Rooms {
         // Return our response data.
         Ok(contents)
     }
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_all_digital_signage_content`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_digital_signage_content_pages_stream(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Site>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/rooms/digital_signage?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListDigitalSignageContentResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.contents;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_all_digital_signage_content`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_digital_signage_content_stream(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Site>> + Unpin + '_ {
+        crate::utils::page_items(
+            self.list_all_digital_signage_content_pages_stream(type_, folder_id, None),
+        )
+    }
     /**
      * Update E911 digital signage.
      *
@@ -657,9 +1031,21 @@ impl Rooms {
     pub async fn manage_e_91_1signage(
         &self,
     ) -> ClientResult<crate::types::ManageE911SignageResponse> {
+        Ok(self.manage_e_91_1signage_with_response().await?.body)
+    }
+    /**
+     * Update E911 digital signage.
+     *
+     * This function performs a `PATCH` to the `/rooms/events` endpoint.
+     *
+     * As opposed to `manage_e_91_1signage`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn manage_e_91_1signage_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ManageE911SignageResponse>> {
         let url = self.client.url("/rooms/events", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -27,9 +27,21 @@ impl RoomsAccount {
     pub async fn get_zr_account_profile(
         &self,
     ) -> ClientResult<crate::types::UpdateZrAccProfileRequest> {
+        Ok(self.get_zr_account_profile_with_response().await?.body)
+    }
+    /**
+     * Get Zoom Room account profile.
+     *
+     * This function performs a `GET` to the `/rooms/account_profile` endpoint.
+     *
+     * As opposed to `get_zr_account_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zr_account_profile_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::UpdateZrAccProfileRequest>> {
         let url = self.client.url("/rooms/account_profile", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -55,9 +67,22 @@ impl RoomsAccount {
         &self,
         body: &crate::types::UpdateZrAccProfileRequest,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self.update_zr_acc_profile_with_response(body).await?.body)
+    }
+    /**
+     * Update Zoom Room account profile.
+     *
+     * This function performs a `PATCH` to the `/rooms/account_profile` endpoint.
+     *
+     * As opposed to `update_zr_acc_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_zr_acc_profile_with_response(
+        &self,
+        body: &crate::types::UpdateZrAccProfileRequest,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url("/rooms/account_profile", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -89,6 +114,22 @@ impl RoomsAccount {
         &self,
         setting_type: crate::types::SettingType,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_zr_account_setting_with_response(setting_type)
+            .await?
+            .body)
+    }
+    /**
+     * Get Zoom Room account settings.
+     *
+     * This function performs a `GET` to the `/rooms/account_settings` endpoint.
+     *
+     * As opposed to `get_zr_account_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zr_account_setting_with_response(
+        &self,
+        setting_type: crate::types::SettingType,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !setting_type.to_string().is_empty() {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
@@ -98,7 +139,7 @@ impl RoomsAccount {
             .client
             .url(&format!("/rooms/account_settings?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -130,6 +171,22 @@ impl RoomsAccount {
         &self,
         setting_type: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .update_zoom_room_acc_settings_with_response(setting_type)
+            .await?
+            .body)
+    }
+    /**
+     * Update Zoom Room account settings.
+     *
+     * This function performs a `PATCH` to the `/rooms/account_settings` endpoint.
+     *
+     * As opposed to `update_zoom_room_acc_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_zoom_room_acc_settings_with_response(
+        &self,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !setting_type.is_empty() {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
@@ -139,7 +196,7 @@ impl RoomsAccount {
             .client
             .url(&format!("/rooms/account_settings?{}", query_), None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -33,6 +33,23 @@ impl RoomsDevices {
         device_id: &str,
         body: &crate::types::ChangeZoomRoomsAppVersionRequest,
     ) -> ClientResult<()> {
+        self.change_zoom_rooms_app_version_with_response(room_id, device_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Change Zoom Rooms' app version.
+     *
+     * This function performs a `PUT` to the `/rooms/{roomId}/devices/{deviceId}/app_version` endpoint.
+     *
+     * As opposed to `change_zoom_rooms_app_version`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn change_zoom_rooms_app_version_with_response(
+        &self,
+        room_id: &str,
+        device_id: &str,
+        body: &crate::types::ChangeZoomRoomsAppVersionRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/rooms/{}/devices/{}/app_version",
@@ -42,7 +59,7 @@ impl RoomsDevices {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
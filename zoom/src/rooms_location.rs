@@ -39,6 +39,25 @@ impl RoomsLocation {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::AddAzrLocationResponse>> {
+        Ok(self
+            .list_zr_locations_with_response(parent_location_id, type_, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_zr_locations`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_zr_locations_with_response(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::AddAzrLocationResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -59,9 +78,9 @@ impl RoomsLocation {
         let url = self
             .client
             .url(&format!("/rooms/locations?{}", query_), None);
-        let resp: crate::types::ListZrLocationsResponseData = self
+        let resp: crate::Response<crate::types::ListZrLocationsResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -71,7 +90,7 @@ impl RoomsLocation {
             .await?;
 
         // Return our response data.
-        Ok(resp.locations.to_vec())
+        Ok(resp.map(|r| r.locations.to_vec()))
     }
     /**
      * List Zoom Room locations.
@@ -160,6 +179,103 @@ impl RoomsLocation {
         // Return our response data.
         Ok(locations)
     }
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_all_zr_locations`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_zr_locations_pages_stream(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<
+        Item = ClientResult<crate::utils::Page<crate::types::AddAzrLocationResponse>>,
+    > + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !parent_location_id.is_empty() {
+            query_args.push((
+                "parent_location_id".to_string(),
+                parent_location_id.to_string(),
+            ));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self
+            .client
+            .url(&format!("/rooms/locations?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListZrLocationsResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.locations;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_all_zr_locations`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_zr_locations_stream(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::AddAzrLocationResponse>> + Unpin + '_
+    {
+        crate::utils::page_items(self.list_all_zr_locations_pages_stream(
+            parent_location_id,
+            type_,
+            None,
+        ))
+    }
     /**
      * Add a location.
      *
@@ -179,9 +295,22 @@ impl RoomsLocation {
         &self,
         body: &crate::types::AddAzrLocationRequest,
     ) -> ClientResult<crate::types::AddAzrLocationResponse> {
+        Ok(self.add_azr_location_with_response(body).await?.body)
+    }
+    /**
+     * Add a location.
+     *
+     * This function performs a `POST` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `add_azr_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_azr_location_with_response(
+        &self,
+        body: &crate::types::AddAzrLocationRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddAzrLocationResponse>> {
         let url = self.client.url("/rooms/locations", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -212,6 +341,22 @@ impl RoomsLocation {
         &self,
         location_id: &str,
     ) -> ClientResult<crate::types::GetZrLocationProfileResponse> {
+        Ok(self
+            .get_zr_location_profile_with_response(location_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get Zoom Room location profile.
+     *
+     * This function performs a `GET` to the `/rooms/locations/{locationId}` endpoint.
+     *
+     * As opposed to `get_zr_location_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zr_location_profile_with_response(
+        &self,
+        location_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetZrLocationProfileResponse>> {
         let url = self.client.url(
             &format!(
                 "/rooms/locations/{}",
@@ -220,7 +365,7 @@ impl RoomsLocation {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -253,6 +398,22 @@ impl RoomsLocation {
         location_id: &str,
         body: &crate::types::GetZrLocationProfileResponse,
     ) -> ClientResult<()> {
+        self.update_zr_location_profile_with_response(location_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update Zoom Room location profile.
+     *
+     * This function performs a `PATCH` to the `/rooms/locations/{locationId}` endpoint.
+     *
+     * As opposed to `update_zr_location_profile`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_zr_location_profile_with_response(
+        &self,
+        location_id: &str,
+        body: &crate::types::GetZrLocationProfileResponse,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/rooms/locations/{}",
@@ -261,7 +422,7 @@ impl RoomsLocation {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -294,6 +455,23 @@ impl RoomsLocation {
         location_id: &str,
         setting_type: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_zr_location_setting_with_response(location_id, setting_type)
+            .await?
+            .body)
+    }
+    /**
+     * Get location settings.
+     *
+     * This function performs a `GET` to the `/rooms/locations/{locationId}/settings` endpoint.
+     *
+     * As opposed to `get_zr_location_setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zr_location_setting_with_response(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !setting_type.is_empty() {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
@@ -308,7 +486,7 @@ impl RoomsLocation {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -341,6 +519,22 @@ impl RoomsLocation {
         location_id: &str,
         setting_type: &str,
     ) -> ClientResult<()> {
+        self.update_zr_location_settings_with_response(location_id, setting_type)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update location settings.
+     *
+     * This function performs a `PATCH` to the `/rooms/locations/{locationId}/settings` endpoint.
+     *
+     * As opposed to `update_zr_location_settings`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_zr_location_settings_with_response(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !setting_type.is_empty() {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
@@ -355,7 +549,7 @@ impl RoomsLocation {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -379,9 +573,21 @@ impl RoomsLocation {
     pub async fn get_zr_location_structure(
         &self,
     ) -> ClientResult<crate::types::GetZrLocationStructureResponse> {
+        Ok(self.get_zr_location_structure_with_response().await?.body)
+    }
+    /**
+     * Get Zoom Room location structure.
+     *
+     * This function performs a `GET` to the `/rooms/locations/structure` endpoint.
+     *
+     * As opposed to `get_zr_location_structure`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_zr_location_structure_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::GetZrLocationStructureResponse>> {
         let url = self.client.url("/rooms/locations/structure", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -406,9 +612,23 @@ impl RoomsLocation {
         &self,
         body: &crate::types::GetZrLocationStructureResponse,
     ) -> ClientResult<()> {
+        self.update_zoom_structure_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Update Zoom Rooms location structure.
+     *
+     * This function performs a `PATCH` to the `/rooms/locations/structure` endpoint.
+     *
+     * As opposed to `update_zoom_structure`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_zoom_structure_with_response(
+        &self,
+        body: &crate::types::GetZrLocationStructureResponse,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/rooms/locations/structure", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -438,6 +658,22 @@ impl RoomsLocation {
         location_id: &str,
         body: &crate::types::ChangeParentLocationRequest,
     ) -> ClientResult<()> {
+        self.change_parent_location_with_response(location_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Change the assigned parent location.
+     *
+     * This function performs a `PUT` to the `/rooms/locations/{locationId}/location` endpoint.
+     *
+     * As opposed to `change_parent_location`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn change_parent_location_with_response(
+        &self,
+        location_id: &str,
+        body: &crate::types::ChangeParentLocationRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/rooms/locations/{}/location",
@@ -446,7 +682,7 @@ impl RoomsLocation {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -28,9 +28,21 @@ impl SipConnectedAudio {
     pub async fn list_sip_trunk_numbers(
         &self,
     ) -> ClientResult<crate::types::ListSipTrunkNumbersResponse> {
+        Ok(self.list_sip_trunk_numbers_with_response().await?.body)
+    }
+    /**
+     * List SIP trunk numbers.
+     *
+     * This function performs a `GET` to the `/sip_trunk/numbers` endpoint.
+     *
+     * As opposed to `list_sip_trunk_numbers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_sip_trunk_numbers_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::ListSipTrunkNumbersResponse>> {
         let url = self.client.url("/sip_trunk/numbers", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -59,6 +71,22 @@ impl SipConnectedAudio {
         account_id: &str,
         body: &crate::types::AssignSipConfigRequest,
     ) -> ClientResult<()> {
+        self.assign_sip_config_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Assign SIP trunk configuration.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/sip_trunk/settings` endpoint.
+     *
+     * As opposed to `assign_sip_config`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_sip_config_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipConfigRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/settings",
@@ -67,7 +95,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -99,6 +127,22 @@ impl SipConnectedAudio {
         account_id: &str,
         body: &crate::types::AssignSipTrunkNumbersRequest,
     ) -> ClientResult<()> {
+        self.assign_sip_trunk_numbers_with_response(account_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Assign numbers.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/sip_trunk/numbers` endpoint.
+     *
+     * As opposed to `assign_sip_trunk_numbers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_sip_trunk_numbers_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunkNumbersRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/numbers",
@@ -107,7 +151,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -134,6 +178,21 @@ impl SipConnectedAudio {
      * * `account_id: &str` -- Account ID of the sub account from which the numbers are to be deleted. This can be retrieved from [List sub accounts](https://marketplace.zoom.us/docs/api-reference/zoom-api/accounts/account) API.
      */
     pub async fn delete_all_sip_numbers(&self, account_id: &str) -> ClientResult<()> {
+        self.delete_all_sip_numbers_with_response(account_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete all numbers.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/sip_trunk/numbers` endpoint.
+     *
+     * As opposed to `delete_all_sip_numbers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_all_sip_numbers_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/numbers",
@@ -142,7 +201,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -169,6 +228,19 @@ impl SipConnectedAudio {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::ListSipTrunksResponseData> {
+        Ok(self.list_sip_trunk_with_response(account_id).await?.body)
+    }
+    /**
+     * List SIP trunks.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_sip_trunk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_sip_trunk_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListSipTrunksResponseData>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/trunks",
@@ -177,7 +249,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -204,6 +276,23 @@ impl SipConnectedAudio {
         account_id: &str,
         body: &crate::types::AssignSipTrunksRequestData,
     ) -> ClientResult<crate::types::AssignSipTrunksResponseData> {
+        Ok(self
+            .assign_sip_trunks_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Assign SIP trunks.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `assign_sip_trunks`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assign_sip_trunks_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunksRequestData,
+    ) -> ClientResult<crate::Response<crate::types::AssignSipTrunksResponseData>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/trunks",
@@ -212,7 +301,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -241,6 +330,23 @@ impl SipConnectedAudio {
         account_id: &str,
         trunk_id: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .delete_sip_trunk_with_response(account_id, trunk_id)
+            .await?
+            .body)
+    }
+    /**
+     * Delete a SIP trunk.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/sip_trunk/trunks/{trunkId}` endpoint.
+     *
+     * As opposed to `delete_sip_trunk`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_sip_trunk_with_response(
+        &self,
+        account_id: &str,
+        trunk_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/trunks/{}",
@@ -250,7 +356,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -277,6 +383,22 @@ impl SipConnectedAudio {
         &self,
         account_id: &str,
     ) -> ClientResult<crate::types::ListInternalCalloutCountriesResponse> {
+        Ok(self
+            .list_internal_callout_countries_with_response(account_id)
+            .await?
+            .body)
+    }
+    /**
+     * List internal call-out countries.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/callout_countries` endpoint.
+     *
+     * As opposed to `list_internal_callout_countries`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_internal_callout_countries_with_response(
+        &self,
+        account_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListInternalCalloutCountriesResponse>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/callout_countries",
@@ -285,7 +407,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -313,6 +435,23 @@ impl SipConnectedAudio {
         account_id: &str,
         body: &crate::types::AddCalloutCountriesRequestData,
     ) -> ClientResult<crate::types::AddCalloutCountriesResponse> {
+        Ok(self
+            .add_callout_countries_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add internal call-out countries.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/sip_trunk/callout_countries` endpoint.
+     *
+     * As opposed to `add_callout_countries`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_callout_countries_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddCalloutCountriesRequestData,
+    ) -> ClientResult<crate::Response<crate::types::AddCalloutCountriesResponse>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/callout_countries",
@@ -321,7 +460,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -351,6 +490,22 @@ impl SipConnectedAudio {
         account_id: &str,
         country_id: &str,
     ) -> ClientResult<()> {
+        self.delete_internal_call_out_country_with_response(account_id, country_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete internal call-out country.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/sip_trunk/callout_countries/{countryId}` endpoint.
+     *
+     * As opposed to `delete_internal_call_out_country`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_internal_call_out_country_with_response(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/callout_countries/{}",
@@ -360,7 +515,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -394,6 +549,24 @@ impl SipConnectedAudio {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::InternalNumbers>> {
+        Ok(self
+            .list_internal_numbers_with_response(account_id, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_internal_numbers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_internal_numbers_with_response(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::InternalNumbers>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -410,9 +583,9 @@ impl SipConnectedAudio {
             ),
             None,
         );
-        let resp: crate::types::ListInternalNumbersResponse = self
+        let resp: crate::Response<crate::types::ListInternalNumbersResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -422,7 +595,7 @@ impl SipConnectedAudio {
             .await?;
 
         // Return our response data.
-        Ok(resp.internal_numbers.to_vec())
+        Ok(resp.map(|r| r.internal_numbers.to_vec()))
     }
     /**
      * List internal numbers.
@@ -503,6 +676,88 @@ impl SipConnectedAudio {
         // Return our response data.
         Ok(internal_numbers)
     }
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_all_internal_numbers`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_internal_numbers_pages_stream(
+        &self,
+        account_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::InternalNumbers>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                crate::progenitor_support::encode_path(account_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListInternalNumbersResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.internal_numbers;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_all_internal_numbers`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_internal_numbers_stream(
+        &self,
+        account_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::InternalNumbers>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_internal_numbers_pages_stream(account_id, None))
+    }
     /**
      * Add internal numbers.
      *
@@ -524,6 +779,23 @@ impl SipConnectedAudio {
         account_id: &str,
         body: &crate::types::AddInternalNumbersRequest,
     ) -> ClientResult<crate::types::AddInternalNumbersResponseData> {
+        Ok(self
+            .add_internal_numbers_with_response(account_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add internal numbers.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `add_internal_numbers`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_internal_numbers_with_response(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddInternalNumbersRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddInternalNumbersResponseData>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/internal_numbers",
@@ -532,7 +804,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -564,6 +836,22 @@ impl SipConnectedAudio {
         account_id: &str,
         number_id: &str,
     ) -> ClientResult<()> {
+        self.delete_internal_number_with_response(account_id, number_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete an internal number.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/sip_trunk/internal_numbers/{numberId}` endpoint.
+     *
+     * As opposed to `delete_internal_number`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_internal_number_with_response(
+        &self,
+        account_id: &str,
+        number_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/sip_trunk/internal_numbers/{}",
@@ -573,7 +861,7 @@ impl SipConnectedAudio {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -40,6 +40,25 @@ impl SipPhone {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::Phones>> {
+        Ok(self
+            .list_with_response(page_number, search_key, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List SIP phones.
+     *
+     * This function performs a `GET` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_with_response(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Phones>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -55,9 +74,9 @@ impl SipPhone {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/sip_phones?{}", query_), None);
-        let resp: crate::types::ListSipPhonesResponse = self
+        let resp: crate::Response<crate::types::ListSipPhonesResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -67,7 +86,7 @@ impl SipPhone {
             .await?;
 
         // Return our response data.
-        Ok(resp.phones.to_vec())
+        Ok(resp.map(|r| r.phones.to_vec()))
     }
     /**
      * List SIP phones.
@@ -143,6 +162,86 @@ impl SipPhone {
         // Return our response data.
         Ok(phones)
     }
+    /**
+     * List SIP phones.
+     *
+     * This function performs a `GET` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_pages_stream(
+        &self,
+        search_key: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Phones>>> + Unpin + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !search_key.is_empty() {
+            query_args.push(("search_key".to_string(), search_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/sip_phones?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListSipPhonesResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.phones;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List SIP phones.
+     *
+     * This function performs a `GET` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_stream(
+        &self,
+        search_key: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Phones>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_pages_stream(search_key, None))
+    }
     /**
      * Enable SIP phone.
      *
@@ -158,9 +257,23 @@ impl SipPhone {
      *
      */
     pub async fn create(&self, body: &crate::types::CreateSipPhoneRequest) -> ClientResult<()> {
+        self.create_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Enable SIP phone.
+     *
+     * This function performs a `POST` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::CreateSipPhoneRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/sip_phones", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -186,6 +299,17 @@ impl SipPhone {
      * * `phone_id: &str` -- Unique Identifier of the SIP Phone. It can be retrieved from the List SIP Phones API.
      */
     pub async fn delete(&self, phone_id: &str) -> ClientResult<()> {
+        self.delete_with_response(phone_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete SIP phone.
+     *
+     * This function performs a `DELETE` to the `/sip_phones/{phoneId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(&self, phone_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/sip_phones/{}",
@@ -194,7 +318,7 @@ impl SipPhone {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -224,6 +348,21 @@ impl SipPhone {
         phone_id: &str,
         body: &crate::types::UpdateSipPhoneRequest,
     ) -> ClientResult<()> {
+        self.update_with_response(phone_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update SIP phone.
+     *
+     * This function performs a `PATCH` to the `/sip_phones/{phoneId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        phone_id: &str,
+        body: &crate::types::UpdateSipPhoneRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/sip_phones/{}",
@@ -232,7 +371,7 @@ impl SipPhone {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -24,9 +24,21 @@ impl TrackingField {
      * * Business, Education, API or higher plan
      */
     pub async fn trackingfield_list(&self) -> ClientResult<crate::types::Domains> {
+        Ok(self.trackingfield_list_with_response().await?.body)
+    }
+    /**
+     * List tracking fields.
+     *
+     * This function performs a `GET` to the `/tracking_fields` endpoint.
+     *
+     * As opposed to `trackingfield_list`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trackingfield_list_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url("/tracking_fields", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -51,9 +63,22 @@ impl TrackingField {
         &self,
         body: &crate::types::TrackingField,
     ) -> ClientResult<crate::types::TrackingfieldGetResponseAllOf> {
+        Ok(self.trackingfield_create_with_response(body).await?.body)
+    }
+    /**
+     * Create a tracking field.
+     *
+     * This function performs a `POST` to the `/tracking_fields` endpoint.
+     *
+     * As opposed to `trackingfield_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trackingfield_create_with_response(
+        &self,
+        body: &crate::types::TrackingField,
+    ) -> ClientResult<crate::Response<crate::types::TrackingfieldGetResponseAllOf>> {
         let url = self.client.url("/tracking_fields", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -83,6 +108,19 @@ impl TrackingField {
         &self,
         field_id: &str,
     ) -> ClientResult<crate::types::TrackingfieldGetResponseAllOf> {
+        Ok(self.trackingfield_get_with_response(field_id).await?.body)
+    }
+    /**
+     * Get a tracking field.
+     *
+     * This function performs a `GET` to the `/tracking_fields/{fieldId}` endpoint.
+     *
+     * As opposed to `trackingfield_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trackingfield_get_with_response(
+        &self,
+        field_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TrackingfieldGetResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/tracking_fields/{}",
@@ -91,7 +129,7 @@ impl TrackingField {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -117,6 +155,20 @@ impl TrackingField {
      * * `field_id: &str` -- The Tracking Field ID.
      */
     pub async fn trackingfield_delete(&self, field_id: &str) -> ClientResult<()> {
+        self.trackingfield_delete_with_response(field_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a tracking field.
+     *
+     * This function performs a `DELETE` to the `/tracking_fields/{fieldId}` endpoint.
+     *
+     * As opposed to `trackingfield_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trackingfield_delete_with_response(
+        &self,
+        field_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/tracking_fields/{}",
@@ -125,7 +177,7 @@ impl TrackingField {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -155,6 +207,22 @@ impl TrackingField {
         field_id: &str,
         body: &crate::types::TrackingField,
     ) -> ClientResult<()> {
+        self.trackingfield_update_with_response(field_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a tracking field.
+     *
+     * This function performs a `PATCH` to the `/tracking_fields/{fieldId}` endpoint.
+     *
+     * As opposed to `trackingfield_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn trackingfield_update_with_response(
+        &self,
+        field_id: &str,
+        body: &crate::types::TrackingField,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/tracking_fields/{}",
@@ -163,7 +231,7 @@ impl TrackingField {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -25,9 +25,21 @@ impl Tsp {
      * * A Pro or a higher plan.
      */
     pub async fn get(&self) -> ClientResult<crate::types::TspResponse> {
+        Ok(self.get_with_response().await?.body)
+    }
+    /**
+     * Get account's TSP information.
+     *
+     * This function performs a `GET` to the `/tsp` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::TspResponse>> {
         let url = self.client.url("/tsp", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -50,9 +62,23 @@ impl Tsp {
      *
      */
     pub async fn update(&self, body: &crate::types::TspUpdateRequest) -> ClientResult<()> {
+        self.update_with_response(body).await?;
+        Ok(())
+    }
+    /**
+     * Update account's TSP information.
+     *
+     * This function performs a `PATCH` to the `/tsp` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        body: &crate::types::TspUpdateRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url("/tsp", None);
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -76,6 +102,19 @@ impl Tsp {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn user_ts_ps(&self, user_id: &str) -> ClientResult<crate::types::UserTsPsResponse> {
+        Ok(self.user_ts_ps_with_response(user_id).await?.body)
+    }
+    /**
+     * List user's TSP accounts.
+     *
+     * This function performs a `GET` to the `/users/{userId}/tsp` endpoint.
+     *
+     * As opposed to `user_ts_ps`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_ts_ps_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserTsPsResponse>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/tsp",
@@ -84,7 +123,7 @@ impl Tsp {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -113,6 +152,20 @@ impl Tsp {
         user_id: &str,
         body: &crate::types::TspAccountsList,
     ) -> ClientResult<crate::types::TspAccountsList> {
+        Ok(self.user_create_with_response(user_id, body).await?.body)
+    }
+    /**
+     * Add a user's TSP account.
+     *
+     * This function performs a `POST` to the `/users/{userId}/tsp` endpoint.
+     *
+     * As opposed to `user_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_create_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::TspAccountsList,
+    ) -> ClientResult<crate::Response<crate::types::TspAccountsList>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/tsp",
@@ -121,7 +174,7 @@ impl Tsp {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -152,6 +205,20 @@ impl Tsp {
         user_id: &str,
         tsp_id: &str,
     ) -> ClientResult<crate::types::TspAccount> {
+        Ok(self.user_with_response(user_id, tsp_id).await?.body)
+    }
+    /**
+     * Get a user's TSP account.
+     *
+     * This function performs a `GET` to the `/users/{userId}/tsp/{tspId}` endpoint.
+     *
+     * As opposed to `user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_with_response(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::TspAccount>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/tsp/{}",
@@ -161,7 +228,7 @@ impl Tsp {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -188,6 +255,21 @@ impl Tsp {
      *  `3` - SIP Connected Audio.
      */
     pub async fn user_delete(&self, user_id: &str, tsp_id: &str) -> ClientResult<()> {
+        self.user_delete_with_response(user_id, tsp_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a user's TSP account.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/tsp/{tspId}` endpoint.
+     *
+     * As opposed to `user_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_delete_with_response(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/tsp/{}",
@@ -197,7 +279,7 @@ impl Tsp {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -229,6 +311,23 @@ impl Tsp {
         tsp_id: &str,
         body: &crate::types::TspAccountData,
     ) -> ClientResult<()> {
+        self.user_update_with_response(user_id, tsp_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a TSP account.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}/tsp/{tspId}` endpoint.
+     *
+     * As opposed to `user_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_update_with_response(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+        body: &crate::types::TspAccountData,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/tsp/{}",
@@ -238,7 +337,7 @@ impl Tsp {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -267,6 +366,21 @@ impl Tsp {
         user_id: &str,
         body: &crate::types::TspGlobalDialIn,
     ) -> ClientResult<()> {
+        self.url_update_with_response(user_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Set global dial-in URL for a TSP user.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}/tsp/settings` endpoint.
+     *
+     * As opposed to `url_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn url_update_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::TspGlobalDialIn,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/tsp/settings",
@@ -275,7 +389,7 @@ impl Tsp {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
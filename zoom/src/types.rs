@@ -6,13 +6,15 @@ use serde::{Deserialize, Serialize};
 * Payee:<br>`master` - master account holder pays.<br>`sub` - Sub account holder pays.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PayMode {
     #[serde(rename = "master")]
     Master,
     #[serde(rename = "sub")]
     Sub,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PayMode {
@@ -20,12 +22,31 @@ impl std::fmt::Display for PayMode {
         match self {
             PayMode::Master => "master",
             PayMode::Sub => "sub",
-            PayMode::FallthroughString => "*",
+            PayMode::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PayMode {
+    fn from(s: String) -> PayMode {
+        match s.as_str() {
+            "master" => PayMode::Master,
+            "sub" => PayMode::Sub,
+            _ => PayMode::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PayMode> for String {
+    fn from(e: PayMode) -> String {
+        match e {
+            PayMode::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PayMode {
     fn default() -> PayMode {
         PayMode::Master
@@ -476,6 +497,7 @@ pub struct AccountPlans {
 * Determine how participants can join the audio portion of the meeting.<br>`both` - Telephony and VoIP.<br>`telephony` - Audio PSTN telephony only.<br>`voip` - VoIP only.<br>`thirdParty` - 3rd party audio conference.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AudioType {
     #[serde(rename = "both")]
     Both,
@@ -485,8 +507,9 @@ pub enum AudioType {
     ThirdParty,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioType {
@@ -496,12 +519,33 @@ impl std::fmt::Display for AudioType {
             AudioType::Telephony => "telephony",
             AudioType::ThirdParty => "thirdParty",
             AudioType::Voip => "voip",
-            AudioType::FallthroughString => "*",
+            AudioType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AudioType {
+    fn from(s: String) -> AudioType {
+        match s.as_str() {
+            "both" => AudioType::Both,
+            "telephony" => AudioType::Telephony,
+            "thirdParty" => AudioType::ThirdParty,
+            "voip" => AudioType::Voip,
+            _ => AudioType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AudioType> for String {
+    fn from(e: AudioType) -> String {
+        match e {
+            AudioType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AudioType {
     fn default() -> AudioType {
         AudioType::Both
@@ -513,6 +557,7 @@ impl Default for AudioType {
 *   
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RequirePasswordPmiMeetings {
     #[serde(rename = "all")]
     All,
@@ -522,8 +567,9 @@ pub enum RequirePasswordPmiMeetings {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RequirePasswordPmiMeetings {
@@ -533,12 +579,33 @@ impl std::fmt::Display for RequirePasswordPmiMeetings {
             RequirePasswordPmiMeetings::JbhOnly => "jbh_only",
             RequirePasswordPmiMeetings::None => "none",
             RequirePasswordPmiMeetings::Noop => "",
-            RequirePasswordPmiMeetings::FallthroughString => "*",
+            RequirePasswordPmiMeetings::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RequirePasswordPmiMeetings {
+    fn from(s: String) -> RequirePasswordPmiMeetings {
+        match s.as_str() {
+            "all" => RequirePasswordPmiMeetings::All,
+            "jbh_only" => RequirePasswordPmiMeetings::JbhOnly,
+            "none" => RequirePasswordPmiMeetings::None,
+            "" => RequirePasswordPmiMeetings::Noop,
+            _ => RequirePasswordPmiMeetings::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RequirePasswordPmiMeetings> for String {
+    fn from(e: RequirePasswordPmiMeetings) -> String {
+        match e {
+            RequirePasswordPmiMeetings::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RequirePasswordPmiMeetings {
     fn default() -> RequirePasswordPmiMeetings {
         RequirePasswordPmiMeetings::Noop
@@ -767,6 +834,7 @@ pub struct ScheduleMeeting {
 * Play sound when participants join or leave.<br>`host` - Heard by host only.<br>`all` - Heard by host and all attendees.<br>`none` - Disable.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EntryExitChime {
     #[serde(rename = "all")]
     All,
@@ -776,8 +844,9 @@ pub enum EntryExitChime {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EntryExitChime {
@@ -787,12 +856,33 @@ impl std::fmt::Display for EntryExitChime {
             EntryExitChime::Host => "host",
             EntryExitChime::None => "none",
             EntryExitChime::Noop => "",
-            EntryExitChime::FallthroughString => "*",
+            EntryExitChime::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EntryExitChime {
+    fn from(s: String) -> EntryExitChime {
+        match s.as_str() {
+            "all" => EntryExitChime::All,
+            "host" => EntryExitChime::Host,
+            "none" => EntryExitChime::None,
+            "" => EntryExitChime::Noop,
+            _ => EntryExitChime::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EntryExitChime> for String {
+    fn from(e: EntryExitChime) -> String {
+        match e {
+            EntryExitChime::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EntryExitChime {
     fn default() -> EntryExitChime {
         EntryExitChime::Noop
@@ -898,6 +988,7 @@ pub struct VirtualBackgroundSettings {
 *   
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WhoCanShareScreen {
     #[serde(rename = "all")]
     All,
@@ -905,8 +996,9 @@ pub enum WhoCanShareScreen {
     Host,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WhoCanShareScreen {
@@ -915,12 +1007,32 @@ impl std::fmt::Display for WhoCanShareScreen {
             WhoCanShareScreen::All => "all",
             WhoCanShareScreen::Host => "host",
             WhoCanShareScreen::Noop => "",
-            WhoCanShareScreen::FallthroughString => "*",
+            WhoCanShareScreen::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WhoCanShareScreen {
+    fn from(s: String) -> WhoCanShareScreen {
+        match s.as_str() {
+            "all" => WhoCanShareScreen::All,
+            "host" => WhoCanShareScreen::Host,
+            "" => WhoCanShareScreen::Noop,
+            _ => WhoCanShareScreen::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WhoCanShareScreen> for String {
+    fn from(e: WhoCanShareScreen) -> String {
+        match e {
+            WhoCanShareScreen::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WhoCanShareScreen {
     fn default() -> WhoCanShareScreen {
         WhoCanShareScreen::Noop
@@ -1544,6 +1656,7 @@ pub struct PasswordRequirement {
 *   
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignInWithTwoFactorAuth {
     #[serde(rename = "all")]
     All,
@@ -1555,8 +1668,9 @@ pub enum SignInWithTwoFactorAuth {
     Role,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SignInWithTwoFactorAuth {
@@ -1567,12 +1681,34 @@ impl std::fmt::Display for SignInWithTwoFactorAuth {
             SignInWithTwoFactorAuth::None => "none",
             SignInWithTwoFactorAuth::Role => "role",
             SignInWithTwoFactorAuth::Noop => "",
-            SignInWithTwoFactorAuth::FallthroughString => "*",
+            SignInWithTwoFactorAuth::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SignInWithTwoFactorAuth {
+    fn from(s: String) -> SignInWithTwoFactorAuth {
+        match s.as_str() {
+            "all" => SignInWithTwoFactorAuth::All,
+            "group" => SignInWithTwoFactorAuth::Group,
+            "none" => SignInWithTwoFactorAuth::None,
+            "role" => SignInWithTwoFactorAuth::Role,
+            "" => SignInWithTwoFactorAuth::Noop,
+            _ => SignInWithTwoFactorAuth::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SignInWithTwoFactorAuth> for String {
+    fn from(e: SignInWithTwoFactorAuth) -> String {
+        match e {
+            SignInWithTwoFactorAuth::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SignInWithTwoFactorAuth {
     fn default() -> SignInWithTwoFactorAuth {
         SignInWithTwoFactorAuth::Noop
@@ -1663,6 +1799,7 @@ pub struct Security {
 * Automatic recording:<br>`local` - Record on local.<br>`cloud` -  Record on cloud.<br>`none` - Disabled.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AutoRecording {
     #[serde(rename = "cloud")]
     Cloud,
@@ -1672,8 +1809,9 @@ pub enum AutoRecording {
     None,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AutoRecording {
@@ -1683,12 +1821,33 @@ impl std::fmt::Display for AutoRecording {
             AutoRecording::Local => "local",
             AutoRecording::None => "none",
             AutoRecording::Noop => "",
-            AutoRecording::FallthroughString => "*",
+            AutoRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AutoRecording {
+    fn from(s: String) -> AutoRecording {
+        match s.as_str() {
+            "cloud" => AutoRecording::Cloud,
+            "local" => AutoRecording::Local,
+            "none" => AutoRecording::None,
+            "" => AutoRecording::Noop,
+            _ => AutoRecording::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AutoRecording> for String {
+    fn from(e: AutoRecording) -> String {
+        match e {
+            AutoRecording::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AutoRecording {
     fn default() -> AutoRecording {
         AutoRecording::Noop
@@ -2564,6 +2723,7 @@ pub struct DateTime {
 * Device protocol:<br>`H.323` - H.323.<br>`SIP` - SIP.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Protocol {
     #[serde(rename = "H.323")]
     H323,
@@ -2571,8 +2731,9 @@ pub enum Protocol {
     Sip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Protocol {
@@ -2581,12 +2742,32 @@ impl std::fmt::Display for Protocol {
             Protocol::H323 => "H.323",
             Protocol::Sip => "SIP",
             Protocol::Noop => "",
-            Protocol::FallthroughString => "*",
+            Protocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Protocol {
+    fn from(s: String) -> Protocol {
+        match s.as_str() {
+            "H.323" => Protocol::H323,
+            "SIP" => Protocol::Sip,
+            "" => Protocol::Noop,
+            _ => Protocol::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Protocol> for String {
+    fn from(e: Protocol) -> String {
+        match e {
+            Protocol::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Protocol {
     fn default() -> Protocol {
         Protocol::Noop
@@ -2602,6 +2783,7 @@ impl Protocol {
 * Device encryption:<br>`auto` - auto.<br>`yes` - yes.<br>`no` - no.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Encryption {
     #[serde(rename = "auto")]
     Auto,
@@ -2611,8 +2793,9 @@ pub enum Encryption {
     Yes,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Encryption {
@@ -2622,12 +2805,33 @@ impl std::fmt::Display for Encryption {
             Encryption::No => "no",
             Encryption::Yes => "yes",
             Encryption::Noop => "",
-            Encryption::FallthroughString => "*",
+            Encryption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Encryption {
+    fn from(s: String) -> Encryption {
+        match s.as_str() {
+            "auto" => Encryption::Auto,
+            "no" => Encryption::No,
+            "yes" => Encryption::Yes,
+            "" => Encryption::Noop,
+            _ => Encryption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Encryption> for String {
+    fn from(e: Encryption) -> String {
+        match e {
+            Encryption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Encryption {
     fn default() -> Encryption {
         Encryption::Noop
@@ -2873,6 +3077,7 @@ pub struct Occurrence {
 * Determine how participants can join the audio portion of the meeting.<br>`both` - Both Telephony and VoIP.<br>`telephony` - Telephony only.<br>`voip` - VoIP only.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Audio {
     #[serde(rename = "both")]
     Both,
@@ -2880,8 +3085,9 @@ pub enum Audio {
     Telephony,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Audio {
@@ -2890,12 +3096,32 @@ impl std::fmt::Display for Audio {
             Audio::Both => "both",
             Audio::Telephony => "telephony",
             Audio::Voip => "voip",
-            Audio::FallthroughString => "*",
+            Audio::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Audio {
+    fn from(s: String) -> Audio {
+        match s.as_str() {
+            "both" => Audio::Both,
+            "telephony" => Audio::Telephony,
+            "voip" => Audio::Voip,
+            _ => Audio::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Audio> for String {
+    fn from(e: Audio) -> String {
+        match e {
+            Audio::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Audio {
     fn default() -> Audio {
         Audio::Both
@@ -2906,6 +3132,7 @@ impl Default for Audio {
 * Type of number.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "toll")]
     Toll,
@@ -2913,8 +3140,9 @@ pub enum Type {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Type {
@@ -2923,12 +3151,32 @@ impl std::fmt::Display for Type {
             Type::Toll => "toll",
             Type::Tollfree => "tollfree",
             Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Type {
+        match s.as_str() {
+            "toll" => Type::Toll,
+            "tollfree" => Type::Tollfree,
+            "" => Type::Noop,
+            _ => Type::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(e: Type) -> String {
+        match e {
+            Type::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Type {
     fn default() -> Type {
         Type::Noop
@@ -2992,6 +3240,7 @@ pub struct GlobalDialInNumbers {
 *   `e2ee`: [End-to-end encryption](https://support.zoom.us/hc/en-us/articles/360048660871). The encryption key is stored in your local device and can not be obtained by anyone else. Enabling this setting also **disables** the following features: join before host, cloud recording, streaming, live transcription, breakout rooms, polling, 1:1 private chat, and meeting reactions.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EncryptionType {
     #[serde(rename = "e2ee")]
     E2Ee,
@@ -2999,8 +3248,9 @@ pub enum EncryptionType {
     EnhancedEncryption,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for EncryptionType {
@@ -3009,12 +3259,32 @@ impl std::fmt::Display for EncryptionType {
             EncryptionType::E2Ee => "e2ee",
             EncryptionType::EnhancedEncryption => "enhanced_encryption",
             EncryptionType::Noop => "",
-            EncryptionType::FallthroughString => "*",
+            EncryptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for EncryptionType {
+    fn from(s: String) -> EncryptionType {
+        match s.as_str() {
+            "e2ee" => EncryptionType::E2Ee,
+            "enhanced_encryption" => EncryptionType::EnhancedEncryption,
+            "" => EncryptionType::Noop,
+            _ => EncryptionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<EncryptionType> for String {
+    fn from(e: EncryptionType) -> String {
+        match e {
+            EncryptionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for EncryptionType {
     fn default() -> EncryptionType {
         EncryptionType::Noop
@@ -3032,6 +3302,7 @@ impl EncryptionType {
 *   `deny`: Block users from specific regions/countries from joining this meeting. If this setting is selected, the approved regions/countries must be included in the `denied_list`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Method {
     #[serde(rename = "approve")]
     Approve,
@@ -3039,8 +3310,9 @@ pub enum Method {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Method {
@@ -3049,12 +3321,32 @@ impl std::fmt::Display for Method {
             Method::Approve => "approve",
             Method::Deny => "deny",
             Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Method {
+    fn from(s: String) -> Method {
+        match s.as_str() {
+            "approve" => Method::Approve,
+            "deny" => Method::Deny,
+            "" => Method::Noop,
+            _ => Method::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Method> for String {
+    fn from(e: Method) -> String {
+        match e {
+            Method::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Method {
     fn default() -> Method {
         Method::Noop
@@ -3544,6 +3836,7 @@ pub struct MeetingSettings {
 *    <br>`1`  - Sunday. <br>`2` - Monday.<br>`3` - Tuesday.<br>`4` -  Wednesday.<br>`5` -  Thursday.<br>`6` - Friday.<br>`7` - Saturday.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WeeklyDays {
     #[serde(rename = "1")]
     One,
@@ -3559,8 +3852,9 @@ pub enum WeeklyDays {
     Six,
     #[serde(rename = "7")]
     Seven,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WeeklyDays {
@@ -3573,12 +3867,36 @@ impl std::fmt::Display for WeeklyDays {
             WeeklyDays::Five => "5",
             WeeklyDays::Six => "6",
             WeeklyDays::Seven => "7",
-            WeeklyDays::FallthroughString => "*",
+            WeeklyDays::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WeeklyDays {
+    fn from(s: String) -> WeeklyDays {
+        match s.as_str() {
+            "1" => WeeklyDays::One,
+            "2" => WeeklyDays::Two,
+            "3" => WeeklyDays::Three,
+            "4" => WeeklyDays::Four,
+            "5" => WeeklyDays::Five,
+            "6" => WeeklyDays::Six,
+            "7" => WeeklyDays::Seven,
+            _ => WeeklyDays::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WeeklyDays> for String {
+    fn from(e: WeeklyDays) -> String {
+        match e {
+            WeeklyDays::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WeeklyDays {
     fn default() -> WeeklyDays {
         WeeklyDays::One
@@ -3814,6 +4132,7 @@ pub struct MeetingInfo {
 * Meeting status
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "started")]
     Started,
@@ -3821,8 +4140,9 @@ pub enum Status {
     Waiting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Status {
@@ -3831,12 +4151,32 @@ impl std::fmt::Display for Status {
             Status::Started => "started",
             Status::Waiting => "waiting",
             Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Status {
+        match s.as_str() {
+            "started" => Status::Started,
+            "waiting" => Status::Waiting,
+            "" => Status::Noop,
+            _ => Status::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(e: Status) -> String {
+        match e {
+            Status::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Status {
     fn default() -> Status {
         Status::Noop
@@ -4245,6 +4585,7 @@ pub struct MeetingLiveStream {
 *   `stop`: Stop an ongoing live stream.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Action {
     #[serde(rename = "start")]
     Start,
@@ -4252,8 +4593,9 @@ pub enum Action {
     Stop,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Action {
@@ -4262,12 +4604,32 @@ impl std::fmt::Display for Action {
             Action::Start => "start",
             Action::Stop => "stop",
             Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Action {
+    fn from(s: String) -> Action {
+        match s.as_str() {
+            "start" => Action::Start,
+            "stop" => Action::Stop,
+            "" => Action::Noop,
+            _ => Action::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Action> for String {
+    fn from(e: Action) -> String {
+        match e {
+            Action::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Action {
     fn default() -> Action {
         Action::Noop
@@ -4599,13 +4961,15 @@ pub struct QosAudio {
 * The recording status.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingStatus {
     #[serde(rename = "completed")]
     Completed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatus {
@@ -4613,12 +4977,31 @@ impl std::fmt::Display for RecordingStatus {
         match self {
             RecordingStatus::Completed => "completed",
             RecordingStatus::Noop => "",
-            RecordingStatus::FallthroughString => "*",
+            RecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RecordingStatus {
+    fn from(s: String) -> RecordingStatus {
+        match s.as_str() {
+            "completed" => RecordingStatus::Completed,
+            "" => RecordingStatus::Noop,
+            _ => RecordingStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RecordingStatus> for String {
+    fn from(e: RecordingStatus) -> String {
+        match e {
+            RecordingStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RecordingStatus {
     fn default() -> RecordingStatus {
         RecordingStatus::Noop
@@ -4816,6 +5199,7 @@ pub struct RecordingSeparateAudio {
 * Determine how the meeting recording is shared.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ShareRecording {
     #[serde(rename = "internally")]
     Internally,
@@ -4825,8 +5209,9 @@ pub enum ShareRecording {
     Publicly,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ShareRecording {
@@ -4836,12 +5221,33 @@ impl std::fmt::Display for ShareRecording {
             ShareRecording::None => "none",
             ShareRecording::Publicly => "publicly",
             ShareRecording::Noop => "",
-            ShareRecording::FallthroughString => "*",
+            ShareRecording::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ShareRecording {
+    fn from(s: String) -> ShareRecording {
+        match s.as_str() {
+            "internally" => ShareRecording::Internally,
+            "none" => ShareRecording::None,
+            "publicly" => ShareRecording::Publicly,
+            "" => ShareRecording::Noop,
+            _ => ShareRecording::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ShareRecording> for String {
+    fn from(e: ShareRecording) -> String {
+        match e {
+            ShareRecording::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ShareRecording {
     fn default() -> ShareRecording {
         ShareRecording::Noop
@@ -5097,6 +5503,7 @@ pub struct Registrant {
 * Registrant Status:<br>`approve` - Approve registrant.<br>`cancel` - Cancel previously approved registrant's registration.<br>`deny` - Deny registrant.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RegistrantStatusAction {
     #[serde(rename = "approve")]
     Approve,
@@ -5106,8 +5513,9 @@ pub enum RegistrantStatusAction {
     Deny,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RegistrantStatusAction {
@@ -5117,12 +5525,33 @@ impl std::fmt::Display for RegistrantStatusAction {
             RegistrantStatusAction::Cancel => "cancel",
             RegistrantStatusAction::Deny => "deny",
             RegistrantStatusAction::Noop => "",
-            RegistrantStatusAction::FallthroughString => "*",
+            RegistrantStatusAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RegistrantStatusAction {
+    fn from(s: String) -> RegistrantStatusAction {
+        match s.as_str() {
+            "approve" => RegistrantStatusAction::Approve,
+            "cancel" => RegistrantStatusAction::Cancel,
+            "deny" => RegistrantStatusAction::Deny,
+            "" => RegistrantStatusAction::Noop,
+            _ => RegistrantStatusAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RegistrantStatusAction> for String {
+    fn from(e: RegistrantStatusAction) -> String {
+        match e {
+            RegistrantStatusAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RegistrantStatusAction {
     fn default() -> RegistrantStatusAction {
         RegistrantStatusAction::Noop
@@ -5576,6 +6005,7 @@ pub struct RecurrenceWebinar {
 *   *  `all`: Attendees are able to view all questions submitted in the Q&A.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AnswerQuestions {
     #[serde(rename = "all")]
     All,
@@ -5583,8 +6013,9 @@ pub enum AnswerQuestions {
     Only,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AnswerQuestions {
@@ -5593,12 +6024,32 @@ impl std::fmt::Display for AnswerQuestions {
             AnswerQuestions::All => "all",
             AnswerQuestions::Only => "only",
             AnswerQuestions::Noop => "",
-            AnswerQuestions::FallthroughString => "*",
+            AnswerQuestions::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AnswerQuestions {
+    fn from(s: String) -> AnswerQuestions {
+        match s.as_str() {
+            "all" => AnswerQuestions::All,
+            "only" => AnswerQuestions::Only,
+            "" => AnswerQuestions::Noop,
+            _ => AnswerQuestions::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AnswerQuestions> for String {
+    fn from(e: AnswerQuestions) -> String {
+        match e {
+            AnswerQuestions::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AnswerQuestions {
     fn default() -> AnswerQuestions {
         AnswerQuestions::Noop
@@ -6191,6 +6642,7 @@ pub struct SessionWebinarUpdate {
 *   `media_link` - Media link.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TspAccountsListDialInNumbersType {
     #[serde(rename = "media_link")]
     MediaLink,
@@ -6200,8 +6652,9 @@ pub enum TspAccountsListDialInNumbersType {
     Tollfree,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspAccountsListDialInNumbersType {
@@ -6211,12 +6664,33 @@ impl std::fmt::Display for TspAccountsListDialInNumbersType {
             TspAccountsListDialInNumbersType::Toll => "toll",
             TspAccountsListDialInNumbersType::Tollfree => "tollfree",
             TspAccountsListDialInNumbersType::Noop => "",
-            TspAccountsListDialInNumbersType::FallthroughString => "*",
+            TspAccountsListDialInNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TspAccountsListDialInNumbersType {
+    fn from(s: String) -> TspAccountsListDialInNumbersType {
+        match s.as_str() {
+            "media_link" => TspAccountsListDialInNumbersType::MediaLink,
+            "toll" => TspAccountsListDialInNumbersType::Toll,
+            "tollfree" => TspAccountsListDialInNumbersType::Tollfree,
+            "" => TspAccountsListDialInNumbersType::Noop,
+            _ => TspAccountsListDialInNumbersType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TspAccountsListDialInNumbersType> for String {
+    fn from(e: TspAccountsListDialInNumbersType) -> String {
+        match e {
+            TspAccountsListDialInNumbersType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TspAccountsListDialInNumbersType {
     fn default() -> TspAccountsListDialInNumbersType {
         TspAccountsListDialInNumbersType::Noop
@@ -6269,6 +6743,7 @@ pub struct DialInNumbers {
 * Telephony bridge
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TspBridge {
     #[serde(rename = "EU_TSP_TB")]
     EuTspTb,
@@ -6276,8 +6751,9 @@ pub enum TspBridge {
     UsTspTb,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TspBridge {
@@ -6286,12 +6762,32 @@ impl std::fmt::Display for TspBridge {
             TspBridge::EuTspTb => "EU_TSP_TB",
             TspBridge::UsTspTb => "US_TSP_TB",
             TspBridge::Noop => "",
-            TspBridge::FallthroughString => "*",
+            TspBridge::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TspBridge {
+    fn from(s: String) -> TspBridge {
+        match s.as_str() {
+            "EU_TSP_TB" => TspBridge::EuTspTb,
+            "US_TSP_TB" => TspBridge::UsTspTb,
+            "" => TspBridge::Noop,
+            _ => TspBridge::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TspBridge> for String {
+    fn from(e: TspBridge) -> String {
+        match e {
+            TspBridge::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TspBridge {
     fn default() -> TspBridge {
         TspBridge::Noop
@@ -6566,6 +7062,7 @@ pub struct CustomAttributes {
 *   * `Fax`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Label {
     #[serde(rename = "Fax")]
     Fax,
@@ -6577,8 +7074,9 @@ pub enum Label {
     Office,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Label {
@@ -6589,12 +7087,34 @@ impl std::fmt::Display for Label {
             Label::Mobile => "Mobile",
             Label::Office => "Office",
             Label::Noop => "",
-            Label::FallthroughString => "*",
+            Label::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Label {
+    fn from(s: String) -> Label {
+        match s.as_str() {
+            "Fax" => Label::Fax,
+            "Home" => Label::Home,
+            "Mobile" => Label::Mobile,
+            "Office" => Label::Office,
+            "" => Label::Noop,
+            _ => Label::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Label> for String {
+    fn from(e: Label) -> String {
+        match e {
+            Label::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Label {
     fn default() -> Label {
         Label::Noop
@@ -8336,6 +8856,7 @@ pub struct ZoomRoom {
 * Poll Question & Answer type:<br>`single` - Single choice<br>`mutliple` - Multiple choice
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollQuestionsType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8343,8 +8864,9 @@ pub enum PollQuestionsType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PollQuestionsType {
@@ -8353,12 +8875,32 @@ impl std::fmt::Display for PollQuestionsType {
             PollQuestionsType::Multiple => "multiple",
             PollQuestionsType::Single => "single",
             PollQuestionsType::Noop => "",
-            PollQuestionsType::FallthroughString => "*",
+            PollQuestionsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PollQuestionsType {
+    fn from(s: String) -> PollQuestionsType {
+        match s.as_str() {
+            "multiple" => PollQuestionsType::Multiple,
+            "single" => PollQuestionsType::Single,
+            "" => PollQuestionsType::Noop,
+            _ => PollQuestionsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PollQuestionsType> for String {
+    fn from(e: PollQuestionsType) -> String {
+        match e {
+            PollQuestionsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PollQuestionsType {
     fn default() -> PollQuestionsType {
         PollQuestionsType::Noop
@@ -8427,6 +8969,7 @@ pub struct Poll {
 * Field name of the question.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FieldName {
     #[serde(rename = "address")]
     Address,
@@ -8456,8 +8999,9 @@ pub enum FieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FieldName {
@@ -8477,12 +9021,43 @@ impl std::fmt::Display for FieldName {
             FieldName::State => "state",
             FieldName::Zip => "zip",
             FieldName::Noop => "",
-            FieldName::FallthroughString => "*",
+            FieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FieldName {
+    fn from(s: String) -> FieldName {
+        match s.as_str() {
+            "address" => FieldName::Address,
+            "city" => FieldName::City,
+            "comments" => FieldName::Comments,
+            "country" => FieldName::Country,
+            "industry" => FieldName::Industry,
+            "job_title" => FieldName::JobTitle,
+            "no_of_employees" => FieldName::NoOfEmployees,
+            "org" => FieldName::Org,
+            "phone" => FieldName::Phone,
+            "purchasing_time_frame" => FieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => FieldName::RoleInPurchaseProcess,
+            "state" => FieldName::State,
+            "zip" => FieldName::Zip,
+            "" => FieldName::Noop,
+            _ => FieldName::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FieldName> for String {
+    fn from(e: FieldName) -> String {
+        match e {
+            FieldName::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FieldName {
     fn default() -> FieldName {
         FieldName::Noop
@@ -8515,6 +9090,7 @@ pub struct MeetingRegistrantQuestions {
 * Type of the question being asked.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingRegistrantQuestionsCustomType {
     #[serde(rename = "short")]
     Short,
@@ -8522,8 +9098,9 @@ pub enum MeetingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
@@ -8532,12 +9109,32 @@ impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
             MeetingRegistrantQuestionsCustomType::Short => "short",
             MeetingRegistrantQuestionsCustomType::Single => "single",
             MeetingRegistrantQuestionsCustomType::Noop => "",
-            MeetingRegistrantQuestionsCustomType::FallthroughString => "*",
+            MeetingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MeetingRegistrantQuestionsCustomType {
+    fn from(s: String) -> MeetingRegistrantQuestionsCustomType {
+        match s.as_str() {
+            "short" => MeetingRegistrantQuestionsCustomType::Short,
+            "single" => MeetingRegistrantQuestionsCustomType::Single,
+            "" => MeetingRegistrantQuestionsCustomType::Noop,
+            _ => MeetingRegistrantQuestionsCustomType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MeetingRegistrantQuestionsCustomType> for String {
+    fn from(e: MeetingRegistrantQuestionsCustomType) -> String {
+        match e {
+            MeetingRegistrantQuestionsCustomType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MeetingRegistrantQuestionsCustomType {
     fn default() -> MeetingRegistrantQuestionsCustomType {
         MeetingRegistrantQuestionsCustomType::Noop
@@ -8637,6 +9234,7 @@ pub struct WebinarRegistrantQuestions {
 * Field name.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingRegistrantQuestionsFieldName {
     #[serde(rename = "address")]
     Address,
@@ -8668,8 +9266,9 @@ pub enum RecordingRegistrantQuestionsFieldName {
     Zip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
@@ -8692,12 +9291,46 @@ impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
             RecordingRegistrantQuestionsFieldName::State => "state",
             RecordingRegistrantQuestionsFieldName::Zip => "zip",
             RecordingRegistrantQuestionsFieldName::Noop => "",
-            RecordingRegistrantQuestionsFieldName::FallthroughString => "*",
+            RecordingRegistrantQuestionsFieldName::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RecordingRegistrantQuestionsFieldName {
+    fn from(s: String) -> RecordingRegistrantQuestionsFieldName {
+        match s.as_str() {
+            "address" => RecordingRegistrantQuestionsFieldName::Address,
+            "city" => RecordingRegistrantQuestionsFieldName::City,
+            "comments" => RecordingRegistrantQuestionsFieldName::Comments,
+            "country" => RecordingRegistrantQuestionsFieldName::Country,
+            "industry" => RecordingRegistrantQuestionsFieldName::Industry,
+            "job_title" => RecordingRegistrantQuestionsFieldName::JobTitle,
+            "last_name" => RecordingRegistrantQuestionsFieldName::LastName,
+            "no_of_employees" => RecordingRegistrantQuestionsFieldName::NoOfEmployees,
+            "org" => RecordingRegistrantQuestionsFieldName::Org,
+            "phone" => RecordingRegistrantQuestionsFieldName::Phone,
+            "purchasing_time_frame" => RecordingRegistrantQuestionsFieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => {
+                RecordingRegistrantQuestionsFieldName::RoleInPurchaseProcess
+            }
+            "state" => RecordingRegistrantQuestionsFieldName::State,
+            "zip" => RecordingRegistrantQuestionsFieldName::Zip,
+            "" => RecordingRegistrantQuestionsFieldName::Noop,
+            _ => RecordingRegistrantQuestionsFieldName::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RecordingRegistrantQuestionsFieldName> for String {
+    fn from(e: RecordingRegistrantQuestionsFieldName) -> String {
+        match e {
+            RecordingRegistrantQuestionsFieldName::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RecordingRegistrantQuestionsFieldName {
     fn default() -> RecordingRegistrantQuestionsFieldName {
         RecordingRegistrantQuestionsFieldName::Noop
@@ -8730,6 +9363,7 @@ pub struct RecordingRegistrantQuestions {
 * The type of registration question and answers.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingRegistrantQuestionsCustomType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8739,8 +9373,9 @@ pub enum RecordingRegistrantQuestionsCustomType {
     Single,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
@@ -8750,12 +9385,33 @@ impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
             RecordingRegistrantQuestionsCustomType::Short => "short",
             RecordingRegistrantQuestionsCustomType::Single => "single",
             RecordingRegistrantQuestionsCustomType::Noop => "",
-            RecordingRegistrantQuestionsCustomType::FallthroughString => "*",
+            RecordingRegistrantQuestionsCustomType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RecordingRegistrantQuestionsCustomType {
+    fn from(s: String) -> RecordingRegistrantQuestionsCustomType {
+        match s.as_str() {
+            "multiple" => RecordingRegistrantQuestionsCustomType::Multiple,
+            "short" => RecordingRegistrantQuestionsCustomType::Short,
+            "single" => RecordingRegistrantQuestionsCustomType::Single,
+            "" => RecordingRegistrantQuestionsCustomType::Noop,
+            _ => RecordingRegistrantQuestionsCustomType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RecordingRegistrantQuestionsCustomType> for String {
+    fn from(e: RecordingRegistrantQuestionsCustomType) -> String {
+        match e {
+            RecordingRegistrantQuestionsCustomType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RecordingRegistrantQuestionsCustomType {
     fn default() -> RecordingRegistrantQuestionsCustomType {
         RecordingRegistrantQuestionsCustomType::Noop
@@ -10248,6 +10904,7 @@ pub struct ParticipantQos {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     #[serde(rename = "enforce_login")]
     EnforceLogin,
@@ -10257,8 +10914,9 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     Internally,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionType {
@@ -10270,12 +10928,35 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionTyp
             }
             AccountSettingsRecordingAuthenticationUpdateOptionType::Internally => "internally",
             AccountSettingsRecordingAuthenticationUpdateOptionType::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionType::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn from(s: String) -> AccountSettingsRecordingAuthenticationUpdateOptionType {
+        match s.as_str() {
+            "enforce_login" => AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLogin,
+            "enforce_login_with_domains" => {
+                AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLoginWithDomains
+            }
+            "internally" => AccountSettingsRecordingAuthenticationUpdateOptionType::Internally,
+            "" => AccountSettingsRecordingAuthenticationUpdateOptionType::Noop,
+            _ => AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AccountSettingsRecordingAuthenticationUpdateOptionType> for String {
+    fn from(e: AccountSettingsRecordingAuthenticationUpdateOptionType) -> String {
+        match e {
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AccountSettingsRecordingAuthenticationUpdateOptionType {
     fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionType {
         AccountSettingsRecordingAuthenticationUpdateOptionType::Noop
@@ -10291,6 +10972,7 @@ impl AccountSettingsRecordingAuthenticationUpdateOptionType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     #[serde(rename = "add")]
     Add,
@@ -10300,8 +10982,9 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     Update,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAction {
@@ -10311,12 +10994,33 @@ impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAct
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete => "delete",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Update => "update",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn from(s: String) -> AccountSettingsRecordingAuthenticationUpdateOptionAction {
+        match s.as_str() {
+            "add" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Add,
+            "delete" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete,
+            "update" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Update,
+            "" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop,
+            _ => AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AccountSettingsRecordingAuthenticationUpdateOptionAction> for String {
+    fn from(e: AccountSettingsRecordingAuthenticationUpdateOptionAction) -> String {
+        match e {
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AccountSettingsRecordingAuthenticationUpdateOptionAction {
     fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionAction {
         AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop
@@ -10884,6 +11588,7 @@ pub struct Metrics {
 *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FileType {
     #[serde(rename = "CC")]
     Cc,
@@ -10899,8 +11604,9 @@ pub enum FileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for FileType {
@@ -10913,12 +11619,36 @@ impl std::fmt::Display for FileType {
             FileType::Mp4 => "MP4",
             FileType::Transcript => "TRANSCRIPT",
             FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for FileType {
+    fn from(s: String) -> FileType {
+        match s.as_str() {
+            "CC" => FileType::Cc,
+            "CHAT" => FileType::Chat,
+            "CSV" => FileType::Csv,
+            "M4A" => FileType::M4A,
+            "MP4" => FileType::Mp4,
+            "TRANSCRIPT" => FileType::Transcript,
+            "" => FileType::Noop,
+            _ => FileType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<FileType> for String {
+    fn from(e: FileType) -> String {
+        match e {
+            FileType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for FileType {
     fn default() -> FileType {
         FileType::Noop
@@ -10947,6 +11677,7 @@ impl FileType {
 *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -10972,8 +11703,9 @@ pub enum RecordingType {
     SpeakerView,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingType {
@@ -10991,12 +11723,41 @@ impl std::fmt::Display for RecordingType {
             RecordingType::SharedScreenWithSpeakerViewCc => "shared_screen_with_speaker_view(CC)",
             RecordingType::SpeakerView => "speaker_view",
             RecordingType::Noop => "",
-            RecordingType::FallthroughString => "*",
+            RecordingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RecordingType {
+    fn from(s: String) -> RecordingType {
+        match s.as_str() {
+            "active_speaker" => RecordingType::ActiveSpeaker,
+            "audio_only" => RecordingType::AudioOnly,
+            "audio_transcript" => RecordingType::AudioTranscript,
+            "chat_file" => RecordingType::ChatFile,
+            "gallery_view" => RecordingType::GalleryView,
+            "host_video" => RecordingType::HostVideo,
+            "shared_screen" => RecordingType::SharedScreen,
+            "shared_screen_with_gallery_view" => RecordingType::SharedScreenWithGalleryView,
+            "shared_screen_with_speaker_view" => RecordingType::SharedScreenWithSpeakerView,
+            "shared_screen_with_speaker_view(CC)" => RecordingType::SharedScreenWithSpeakerViewCc,
+            "speaker_view" => RecordingType::SpeakerView,
+            "" => RecordingType::Noop,
+            _ => RecordingType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RecordingType> for String {
+    fn from(e: RecordingType) -> String {
+        match e {
+            RecordingType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RecordingType {
     fn default() -> RecordingType {
         RecordingType::Noop
@@ -11265,13 +12026,15 @@ pub struct InviteLinks {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingStatusUpdateBodyRequestAction {
     #[serde(rename = "recover")]
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
@@ -11279,12 +12042,31 @@ impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
         match self {
             RecordingStatusUpdateBodyRequestAction::Recover => "recover",
             RecordingStatusUpdateBodyRequestAction::Noop => "",
-            RecordingStatusUpdateBodyRequestAction::FallthroughString => "*",
+            RecordingStatusUpdateBodyRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RecordingStatusUpdateBodyRequestAction {
+    fn from(s: String) -> RecordingStatusUpdateBodyRequestAction {
+        match s.as_str() {
+            "recover" => RecordingStatusUpdateBodyRequestAction::Recover,
+            "" => RecordingStatusUpdateBodyRequestAction::Noop,
+            _ => RecordingStatusUpdateBodyRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RecordingStatusUpdateBodyRequestAction> for String {
+    fn from(e: RecordingStatusUpdateBodyRequestAction) -> String {
+        match e {
+            RecordingStatusUpdateBodyRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RecordingStatusUpdateBodyRequestAction {
     fn default() -> RecordingStatusUpdateBodyRequestAction {
         RecordingStatusUpdateBodyRequestAction::Noop
@@ -11402,6 +12184,7 @@ pub struct SetUpAccountRequest {
 *   `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListAccountPhoneNumbersType {
     #[serde(rename = "all")]
     All,
@@ -11411,8 +12194,9 @@ pub enum ListAccountPhoneNumbersType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersType {
@@ -11422,12 +12206,33 @@ impl std::fmt::Display for ListAccountPhoneNumbersType {
             ListAccountPhoneNumbersType::Assigned => "assigned",
             ListAccountPhoneNumbersType::Unassigned => "unassigned",
             ListAccountPhoneNumbersType::Noop => "",
-            ListAccountPhoneNumbersType::FallthroughString => "*",
+            ListAccountPhoneNumbersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListAccountPhoneNumbersType {
+    fn from(s: String) -> ListAccountPhoneNumbersType {
+        match s.as_str() {
+            "all" => ListAccountPhoneNumbersType::All,
+            "assigned" => ListAccountPhoneNumbersType::Assigned,
+            "unassigned" => ListAccountPhoneNumbersType::Unassigned,
+            "" => ListAccountPhoneNumbersType::Noop,
+            _ => ListAccountPhoneNumbersType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListAccountPhoneNumbersType> for String {
+    fn from(e: ListAccountPhoneNumbersType) -> String {
+        match e {
+            ListAccountPhoneNumbersType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListAccountPhoneNumbersType {
     fn default() -> ListAccountPhoneNumbersType {
         ListAccountPhoneNumbersType::Noop
@@ -11445,6 +12250,7 @@ impl ListAccountPhoneNumbersType {
 *   `commonAreaPhone`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -11456,8 +12262,9 @@ pub enum ExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ExtensionType {
@@ -11468,12 +12275,34 @@ impl std::fmt::Display for ExtensionType {
             ExtensionType::CommonAreaPhone => "commonAreaPhone",
             ExtensionType::User => "user",
             ExtensionType::Noop => "",
-            ExtensionType::FallthroughString => "*",
+            ExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ExtensionType {
+    fn from(s: String) -> ExtensionType {
+        match s.as_str() {
+            "autoReceptionist" => ExtensionType::AutoReceptionist,
+            "callQueue" => ExtensionType::CallQueue,
+            "commonAreaPhone" => ExtensionType::CommonAreaPhone,
+            "user" => ExtensionType::User,
+            "" => ExtensionType::Noop,
+            _ => ExtensionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ExtensionType> for String {
+    fn from(e: ExtensionType) -> String {
+        match e {
+            ExtensionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ExtensionType {
     fn default() -> ExtensionType {
         ExtensionType::Noop
@@ -11489,6 +12318,7 @@ impl ExtensionType {
 * Source of phone number.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Source {
     #[serde(rename = "external")]
     External,
@@ -11496,8 +12326,9 @@ pub enum Source {
     Internal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Source {
@@ -11506,12 +12337,32 @@ impl std::fmt::Display for Source {
             Source::External => "external",
             Source::Internal => "internal",
             Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Source {
+    fn from(s: String) -> Source {
+        match s.as_str() {
+            "external" => Source::External,
+            "internal" => Source::Internal,
+            "" => Source::Noop,
+            _ => Source::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Source> for String {
+    fn from(e: Source) -> String {
+        match e {
+            Source::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Source {
     fn default() -> Source {
         Source::Noop
@@ -11527,6 +12378,7 @@ impl Source {
 * Status of the number.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListAccountPhoneNumbersResponseStatus {
     #[serde(rename = "available")]
     Available,
@@ -11534,8 +12386,9 @@ pub enum ListAccountPhoneNumbersResponseStatus {
     Pending,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
@@ -11544,12 +12397,32 @@ impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
             ListAccountPhoneNumbersResponseStatus::Available => "available",
             ListAccountPhoneNumbersResponseStatus::Pending => "pending",
             ListAccountPhoneNumbersResponseStatus::Noop => "",
-            ListAccountPhoneNumbersResponseStatus::FallthroughString => "*",
+            ListAccountPhoneNumbersResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListAccountPhoneNumbersResponseStatus {
+    fn from(s: String) -> ListAccountPhoneNumbersResponseStatus {
+        match s.as_str() {
+            "available" => ListAccountPhoneNumbersResponseStatus::Available,
+            "pending" => ListAccountPhoneNumbersResponseStatus::Pending,
+            "" => ListAccountPhoneNumbersResponseStatus::Noop,
+            _ => ListAccountPhoneNumbersResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListAccountPhoneNumbersResponseStatus> for String {
+    fn from(e: ListAccountPhoneNumbersResponseStatus) -> String {
+        match e {
+            ListAccountPhoneNumbersResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListAccountPhoneNumbersResponseStatus {
     fn default() -> ListAccountPhoneNumbersResponseStatus {
         ListAccountPhoneNumbersResponseStatus::Noop
@@ -11789,6 +12662,7 @@ pub struct ListAccountPhoneNumbersResponseData {
 * Protocols supported by the SIP provider.<br> The value must be either `UDP`, `TCP`, `TLS`, `AUTO`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TransportProtocol {
     #[serde(rename = "AUTO")]
     Auto,
@@ -11800,8 +12674,9 @@ pub enum TransportProtocol {
     Udp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TransportProtocol {
@@ -11812,12 +12687,34 @@ impl std::fmt::Display for TransportProtocol {
             TransportProtocol::Tls => "TLS",
             TransportProtocol::Udp => "UDP",
             TransportProtocol::Noop => "",
-            TransportProtocol::FallthroughString => "*",
+            TransportProtocol::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TransportProtocol {
+    fn from(s: String) -> TransportProtocol {
+        match s.as_str() {
+            "AUTO" => TransportProtocol::Auto,
+            "TCP" => TransportProtocol::Tcp,
+            "TLS" => TransportProtocol::Tls,
+            "UDP" => TransportProtocol::Udp,
+            "" => TransportProtocol::Noop,
+            _ => TransportProtocol::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TransportProtocol> for String {
+    fn from(e: TransportProtocol) -> String {
+        match e {
+            TransportProtocol::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TransportProtocol {
     fn default() -> TransportProtocol {
         TransportProtocol::Noop
@@ -12335,6 +13232,7 @@ pub struct UpdateSipPhoneRequest {
 * The status of the Zoom Room.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZoomRoomsStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12346,8 +13244,9 @@ pub enum ListZoomRoomsStatus {
     UnderConstruction,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsStatus {
@@ -12358,12 +13257,34 @@ impl std::fmt::Display for ListZoomRoomsStatus {
             ListZoomRoomsStatus::Offline => "Offline",
             ListZoomRoomsStatus::UnderConstruction => "UnderConstruction",
             ListZoomRoomsStatus::Noop => "",
-            ListZoomRoomsStatus::FallthroughString => "*",
+            ListZoomRoomsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListZoomRoomsStatus {
+    fn from(s: String) -> ListZoomRoomsStatus {
+        match s.as_str() {
+            "Available" => ListZoomRoomsStatus::Available,
+            "InMeeting" => ListZoomRoomsStatus::InMeeting,
+            "Offline" => ListZoomRoomsStatus::Offline,
+            "UnderConstruction" => ListZoomRoomsStatus::UnderConstruction,
+            "" => ListZoomRoomsStatus::Noop,
+            _ => ListZoomRoomsStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListZoomRoomsStatus> for String {
+    fn from(e: ListZoomRoomsStatus) -> String {
+        match e {
+            ListZoomRoomsStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListZoomRoomsStatus {
     fn default() -> ListZoomRoomsStatus {
         ListZoomRoomsStatus::Noop
@@ -12379,6 +13300,7 @@ impl ListZoomRoomsStatus {
 * Type of the Zoom Rooms.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZoomRoomsType {
     #[serde(rename = "DigitalSignageOnly")]
     DigitalSignageOnly,
@@ -12388,8 +13310,9 @@ pub enum ListZoomRoomsType {
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZoomRoomsType {
@@ -12399,12 +13322,33 @@ impl std::fmt::Display for ListZoomRoomsType {
             ListZoomRoomsType::SchedulingDisplayOnly => "SchedulingDisplayOnly",
             ListZoomRoomsType::ZoomRoom => "ZoomRoom",
             ListZoomRoomsType::Noop => "",
-            ListZoomRoomsType::FallthroughString => "*",
+            ListZoomRoomsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListZoomRoomsType {
+    fn from(s: String) -> ListZoomRoomsType {
+        match s.as_str() {
+            "DigitalSignageOnly" => ListZoomRoomsType::DigitalSignageOnly,
+            "SchedulingDisplayOnly" => ListZoomRoomsType::SchedulingDisplayOnly,
+            "ZoomRoom" => ListZoomRoomsType::ZoomRoom,
+            "" => ListZoomRoomsType::Noop,
+            _ => ListZoomRoomsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListZoomRoomsType> for String {
+    fn from(e: ListZoomRoomsType) -> String {
+        match e {
+            ListZoomRoomsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListZoomRoomsType {
     fn default() -> ListZoomRoomsType {
         ListZoomRoomsType::Noop
@@ -12948,6 +13892,7 @@ pub struct UpdateSiteDetailsRequest {
 *   `Do_Not_Disturb`<br> `away`<br> `Available`<br> `Offline`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PresenceStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12959,8 +13904,9 @@ pub enum PresenceStatus {
     Offline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PresenceStatus {
@@ -12971,12 +13917,34 @@ impl std::fmt::Display for PresenceStatus {
             PresenceStatus::DoNotDisturb => "Do_Not_Disturb",
             PresenceStatus::Offline => "Offline",
             PresenceStatus::Noop => "",
-            PresenceStatus::FallthroughString => "*",
+            PresenceStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PresenceStatus {
+    fn from(s: String) -> PresenceStatus {
+        match s.as_str() {
+            "Available" => PresenceStatus::Available,
+            "Away" => PresenceStatus::Away,
+            "Do_Not_Disturb" => PresenceStatus::DoNotDisturb,
+            "Offline" => PresenceStatus::Offline,
+            "" => PresenceStatus::Noop,
+            _ => PresenceStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PresenceStatus> for String {
+    fn from(e: PresenceStatus) -> String {
+        match e {
+            PresenceStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PresenceStatus {
     fn default() -> PresenceStatus {
         PresenceStatus::Noop
@@ -13152,6 +14120,7 @@ pub struct SearchCompanyContactsResponse {
 *   This field is only returned if you set the value of `for include_deleted_and_edited_message` query parameter to `true`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetChatMessagesResponseStatus {
     #[serde(rename = "Deleted")]
     Deleted,
@@ -13161,8 +14130,9 @@ pub enum GetChatMessagesResponseStatus {
     Normal,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetChatMessagesResponseStatus {
@@ -13172,12 +14142,33 @@ impl std::fmt::Display for GetChatMessagesResponseStatus {
             GetChatMessagesResponseStatus::Edited => "Edited",
             GetChatMessagesResponseStatus::Normal => "Normal",
             GetChatMessagesResponseStatus::Noop => "",
-            GetChatMessagesResponseStatus::FallthroughString => "*",
+            GetChatMessagesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetChatMessagesResponseStatus {
+    fn from(s: String) -> GetChatMessagesResponseStatus {
+        match s.as_str() {
+            "Deleted" => GetChatMessagesResponseStatus::Deleted,
+            "Edited" => GetChatMessagesResponseStatus::Edited,
+            "Normal" => GetChatMessagesResponseStatus::Normal,
+            "" => GetChatMessagesResponseStatus::Noop,
+            _ => GetChatMessagesResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetChatMessagesResponseStatus> for String {
+    fn from(e: GetChatMessagesResponseStatus) -> String {
+        match e {
+            GetChatMessagesResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetChatMessagesResponseStatus {
     fn default() -> GetChatMessagesResponseStatus {
         GetChatMessagesResponseStatus::Noop
@@ -13468,6 +14459,7 @@ pub struct MarkMessageRequest {
 *   * `remove` — Remove an emoji reaction.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReactMessageRequestAction {
     #[serde(rename = "add")]
     Add,
@@ -13475,8 +14467,9 @@ pub enum ReactMessageRequestAction {
     Remove,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReactMessageRequestAction {
@@ -13485,12 +14478,32 @@ impl std::fmt::Display for ReactMessageRequestAction {
             ReactMessageRequestAction::Add => "add",
             ReactMessageRequestAction::Remove => "remove",
             ReactMessageRequestAction::Noop => "",
-            ReactMessageRequestAction::FallthroughString => "*",
+            ReactMessageRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReactMessageRequestAction {
+    fn from(s: String) -> ReactMessageRequestAction {
+        match s.as_str() {
+            "add" => ReactMessageRequestAction::Add,
+            "remove" => ReactMessageRequestAction::Remove,
+            "" => ReactMessageRequestAction::Noop,
+            _ => ReactMessageRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReactMessageRequestAction> for String {
+    fn from(e: ReactMessageRequestAction) -> String {
+        match e {
+            ReactMessageRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReactMessageRequestAction {
     fn default() -> ReactMessageRequestAction {
         ReactMessageRequestAction::Noop
@@ -14022,6 +15035,7 @@ pub struct GetUserContactResponse {
 * The role of the member. The value can be one of these: `owner`, `admin`, `member`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -14031,8 +15045,9 @@ pub enum Role {
     Owner,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Role {
@@ -14042,12 +15057,33 @@ impl std::fmt::Display for Role {
             Role::Member => "member",
             Role::Owner => "owner",
             Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Role {
+    fn from(s: String) -> Role {
+        match s.as_str() {
+            "admin" => Role::Admin,
+            "member" => Role::Member,
+            "owner" => Role::Owner,
+            "" => Role::Noop,
+            _ => Role::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Role> for String {
+    fn from(e: Role) -> String {
+        match e {
+            Role::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Role {
     fn default() -> Role {
         Role::Noop
@@ -14370,6 +15406,7 @@ pub struct AccountResponse {
 *   * `meeting_security` — View the account's meeting security settings.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OptionData {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14377,8 +15414,9 @@ pub enum OptionData {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for OptionData {
@@ -14387,12 +15425,32 @@ impl std::fmt::Display for OptionData {
             OptionData::MeetingAuthentication => "meeting_authentication",
             OptionData::RecordingAuthentication => "recording_authentication",
             OptionData::Noop => "",
-            OptionData::FallthroughString => "*",
+            OptionData::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for OptionData {
+    fn from(s: String) -> OptionData {
+        match s.as_str() {
+            "meeting_authentication" => OptionData::MeetingAuthentication,
+            "recording_authentication" => OptionData::RecordingAuthentication,
+            "" => OptionData::Noop,
+            _ => OptionData::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<OptionData> for String {
+    fn from(e: OptionData) -> String {
+        match e {
+            OptionData::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for OptionData {
     fn default() -> OptionData {
         OptionData::Noop
@@ -14495,6 +15553,7 @@ impl AccountSettingsUpdateRequestOneOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14506,8 +15565,9 @@ pub enum AccountSettingsUpdateOption {
     Security,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountSettingsUpdateOption {
@@ -14518,12 +15578,34 @@ impl std::fmt::Display for AccountSettingsUpdateOption {
             AccountSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             AccountSettingsUpdateOption::Security => "security",
             AccountSettingsUpdateOption::Noop => "",
-            AccountSettingsUpdateOption::FallthroughString => "*",
+            AccountSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AccountSettingsUpdateOption {
+    fn from(s: String) -> AccountSettingsUpdateOption {
+        match s.as_str() {
+            "meeting_authentication" => AccountSettingsUpdateOption::MeetingAuthentication,
+            "meeting_security" => AccountSettingsUpdateOption::MeetingSecurity,
+            "recording_authentication" => AccountSettingsUpdateOption::RecordingAuthentication,
+            "security" => AccountSettingsUpdateOption::Security,
+            "" => AccountSettingsUpdateOption::Noop,
+            _ => AccountSettingsUpdateOption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AccountSettingsUpdateOption> for String {
+    fn from(e: AccountSettingsUpdateOption) -> String {
+        match e {
+            AccountSettingsUpdateOption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AccountSettingsUpdateOption {
     fn default() -> AccountSettingsUpdateOption {
         AccountSettingsUpdateOption::Noop
@@ -14555,6 +15637,7 @@ pub struct AccountTrustedDomainResponse {
 * Status of the plan.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountPlansResponsePlanBaseStatus {
     #[serde(rename = "active")]
     Active,
@@ -14564,8 +15647,9 @@ pub enum AccountPlansResponsePlanBaseStatus {
     Expired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
@@ -14575,12 +15659,33 @@ impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
             AccountPlansResponsePlanBaseStatus::Cancel => "cancel",
             AccountPlansResponsePlanBaseStatus::Expired => "expired",
             AccountPlansResponsePlanBaseStatus::Noop => "",
-            AccountPlansResponsePlanBaseStatus::FallthroughString => "*",
+            AccountPlansResponsePlanBaseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AccountPlansResponsePlanBaseStatus {
+    fn from(s: String) -> AccountPlansResponsePlanBaseStatus {
+        match s.as_str() {
+            "active" => AccountPlansResponsePlanBaseStatus::Active,
+            "cancel" => AccountPlansResponsePlanBaseStatus::Cancel,
+            "expired" => AccountPlansResponsePlanBaseStatus::Expired,
+            "" => AccountPlansResponsePlanBaseStatus::Noop,
+            _ => AccountPlansResponsePlanBaseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AccountPlansResponsePlanBaseStatus> for String {
+    fn from(e: AccountPlansResponsePlanBaseStatus) -> String {
+        match e {
+            AccountPlansResponsePlanBaseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AccountPlansResponsePlanBaseStatus {
     fn default() -> AccountPlansResponsePlanBaseStatus {
         AccountPlansResponsePlanBaseStatus::Noop
@@ -14689,6 +15794,7 @@ pub struct PlanWebinar {
 * Status of additional Cloud Recording plan.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PlanRecordingStatus {
     #[serde(rename = "Active")]
     Active,
@@ -14696,8 +15802,9 @@ pub enum PlanRecordingStatus {
     Cancelled,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PlanRecordingStatus {
@@ -14706,12 +15813,32 @@ impl std::fmt::Display for PlanRecordingStatus {
             PlanRecordingStatus::Active => "Active",
             PlanRecordingStatus::Cancelled => "Cancelled",
             PlanRecordingStatus::Noop => "",
-            PlanRecordingStatus::FallthroughString => "*",
+            PlanRecordingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PlanRecordingStatus {
+    fn from(s: String) -> PlanRecordingStatus {
+        match s.as_str() {
+            "Active" => PlanRecordingStatus::Active,
+            "Cancelled" => PlanRecordingStatus::Cancelled,
+            "" => PlanRecordingStatus::Noop,
+            _ => PlanRecordingStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PlanRecordingStatus> for String {
+    fn from(e: PlanRecordingStatus) -> String {
+        match e {
+            PlanRecordingStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PlanRecordingStatus {
     fn default() -> PlanRecordingStatus {
         PlanRecordingStatus::Noop
@@ -15207,6 +16334,7 @@ pub struct GroupMembersCreateResponse {
 *   * `set_primary` — Set the user's primary group.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UpdateGroupMemberRequestAction {
     #[serde(rename = "move")]
     Move,
@@ -15214,8 +16342,9 @@ pub enum UpdateGroupMemberRequestAction {
     SetPrimary,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupMemberRequestAction {
@@ -15224,12 +16353,32 @@ impl std::fmt::Display for UpdateGroupMemberRequestAction {
             UpdateGroupMemberRequestAction::Move => "move",
             UpdateGroupMemberRequestAction::SetPrimary => "set_primary",
             UpdateGroupMemberRequestAction::Noop => "",
-            UpdateGroupMemberRequestAction::FallthroughString => "*",
+            UpdateGroupMemberRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UpdateGroupMemberRequestAction {
+    fn from(s: String) -> UpdateGroupMemberRequestAction {
+        match s.as_str() {
+            "move" => UpdateGroupMemberRequestAction::Move,
+            "set_primary" => UpdateGroupMemberRequestAction::SetPrimary,
+            "" => UpdateGroupMemberRequestAction::Noop,
+            _ => UpdateGroupMemberRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UpdateGroupMemberRequestAction> for String {
+    fn from(e: UpdateGroupMemberRequestAction) -> String {
+        match e {
+            UpdateGroupMemberRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UpdateGroupMemberRequestAction {
     fn default() -> UpdateGroupMemberRequestAction {
         UpdateGroupMemberRequestAction::Noop
@@ -15300,6 +16449,7 @@ pub struct TrackingfieldGetResponseAllOf {
 * IM Group types:<br>`normal` - Only members can see automatically see the other members of this group. Other people can search for members within this group. <br>`shared` - Everyone under an account can see the group members automatically.<br>`restricted` - Nobody can see the group or search for members except the members in the group.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImGroupCreateRequestType {
     #[serde(rename = "normal")]
     Normal,
@@ -15307,8 +16457,9 @@ pub enum ImGroupCreateRequestType {
     Restricted,
     #[serde(rename = "shared")]
     Shared,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImGroupCreateRequestType {
@@ -15317,12 +16468,32 @@ impl std::fmt::Display for ImGroupCreateRequestType {
             ImGroupCreateRequestType::Normal => "normal",
             ImGroupCreateRequestType::Restricted => "restricted",
             ImGroupCreateRequestType::Shared => "shared",
-            ImGroupCreateRequestType::FallthroughString => "*",
+            ImGroupCreateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ImGroupCreateRequestType {
+    fn from(s: String) -> ImGroupCreateRequestType {
+        match s.as_str() {
+            "normal" => ImGroupCreateRequestType::Normal,
+            "restricted" => ImGroupCreateRequestType::Restricted,
+            "shared" => ImGroupCreateRequestType::Shared,
+            _ => ImGroupCreateRequestType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ImGroupCreateRequestType> for String {
+    fn from(e: ImGroupCreateRequestType) -> String {
+        match e {
+            ImGroupCreateRequestType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ImGroupCreateRequestType {
     fn default() -> ImGroupCreateRequestType {
         ImGroupCreateRequestType::Normal
@@ -15456,6 +16627,7 @@ pub struct DashboardImResponse {
 * IM chat session type.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImChatSessionsResponseType {
     #[serde(rename = "1:1")]
     One,
@@ -15463,8 +16635,9 @@ pub enum ImChatSessionsResponseType {
     Group,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ImChatSessionsResponseType {
@@ -15473,12 +16646,32 @@ impl std::fmt::Display for ImChatSessionsResponseType {
             ImChatSessionsResponseType::One => "1:1",
             ImChatSessionsResponseType::Group => "Group",
             ImChatSessionsResponseType::Noop => "",
-            ImChatSessionsResponseType::FallthroughString => "*",
+            ImChatSessionsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ImChatSessionsResponseType {
+    fn from(s: String) -> ImChatSessionsResponseType {
+        match s.as_str() {
+            "1:1" => ImChatSessionsResponseType::One,
+            "Group" => ImChatSessionsResponseType::Group,
+            "" => ImChatSessionsResponseType::Noop,
+            _ => ImChatSessionsResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ImChatSessionsResponseType> for String {
+    fn from(e: ImChatSessionsResponseType) -> String {
+        match e {
+            ImChatSessionsResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ImChatSessionsResponseType {
     fn default() -> ImChatSessionsResponseType {
         ImChatSessionsResponseType::Noop
@@ -15674,6 +16867,7 @@ pub struct ImChatMessagesResponseAllOf {
 * The meeting types: <br>`scheduled` - This includes all valid past meetings (unexpired), live meetings and upcoming scheduled meetings. It is equivalent to the combined list of "Previous Meetings" and "Upcoming Meetings" displayed in the user's [Meetings page](https://zoom.us/meeting) on the Zoom Web Portal.<br>`live` - All the ongoing meetings.<br>`upcoming` - All upcoming meetings including live meetings.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -15681,8 +16875,9 @@ pub enum MeetingsType {
     Scheduled,
     #[serde(rename = "upcoming")]
     Upcoming,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingsType {
@@ -15691,12 +16886,32 @@ impl std::fmt::Display for MeetingsType {
             MeetingsType::Live => "live",
             MeetingsType::Scheduled => "scheduled",
             MeetingsType::Upcoming => "upcoming",
-            MeetingsType::FallthroughString => "*",
+            MeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MeetingsType {
+    fn from(s: String) -> MeetingsType {
+        match s.as_str() {
+            "live" => MeetingsType::Live,
+            "scheduled" => MeetingsType::Scheduled,
+            "upcoming" => MeetingsType::Upcoming,
+            _ => MeetingsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MeetingsType> for String {
+    fn from(e: MeetingsType) -> String {
+        match e {
+            MeetingsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MeetingsType {
     fn default() -> MeetingsType {
         MeetingsType::Live
@@ -15826,6 +17041,11 @@ pub struct MeetingResponseAllOf {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingUpdateRequest {
+    /**
+     * Meeting description.
+     */
+    #[serde(default, skip_serializing_if = "crate::utils::Patch::is_absent")]
+    pub agenda: crate::utils::Patch<String>,
     /**
      * User's first name.
      */
@@ -15856,6 +17076,7 @@ pub struct MeetingUpdateRequestAllOf {
 *   
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingStatusRequestAction {
     #[serde(rename = "end")]
     End,
@@ -15863,8 +17084,9 @@ pub enum MeetingStatusRequestAction {
     Recover,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingStatusRequestAction {
@@ -15873,12 +17095,32 @@ impl std::fmt::Display for MeetingStatusRequestAction {
             MeetingStatusRequestAction::End => "end",
             MeetingStatusRequestAction::Recover => "recover",
             MeetingStatusRequestAction::Noop => "",
-            MeetingStatusRequestAction::FallthroughString => "*",
+            MeetingStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MeetingStatusRequestAction {
+    fn from(s: String) -> MeetingStatusRequestAction {
+        match s.as_str() {
+            "end" => MeetingStatusRequestAction::End,
+            "recover" => MeetingStatusRequestAction::Recover,
+            "" => MeetingStatusRequestAction::Noop,
+            _ => MeetingStatusRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MeetingStatusRequestAction> for String {
+    fn from(e: MeetingStatusRequestAction) -> String {
+        match e {
+            MeetingStatusRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MeetingStatusRequestAction {
     fn default() -> MeetingStatusRequestAction {
         MeetingStatusRequestAction::Noop
@@ -15905,6 +17147,7 @@ pub struct MeetingStatusRequest {
 * The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingRegistrantsStatus {
     #[serde(rename = "approved")]
     Approved,
@@ -15912,8 +17155,9 @@ pub enum MeetingRegistrantsStatus {
     Denied,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingRegistrantsStatus {
@@ -15922,12 +17166,32 @@ impl std::fmt::Display for MeetingRegistrantsStatus {
             MeetingRegistrantsStatus::Approved => "approved",
             MeetingRegistrantsStatus::Denied => "denied",
             MeetingRegistrantsStatus::Pending => "pending",
-            MeetingRegistrantsStatus::FallthroughString => "*",
+            MeetingRegistrantsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MeetingRegistrantsStatus {
+    fn from(s: String) -> MeetingRegistrantsStatus {
+        match s.as_str() {
+            "approved" => MeetingRegistrantsStatus::Approved,
+            "denied" => MeetingRegistrantsStatus::Denied,
+            "pending" => MeetingRegistrantsStatus::Pending,
+            _ => MeetingRegistrantsStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MeetingRegistrantsStatus> for String {
+    fn from(e: MeetingRegistrantsStatus) -> String {
+        match e {
+            MeetingRegistrantsStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MeetingRegistrantsStatus {
     fn default() -> MeetingRegistrantsStatus {
         MeetingRegistrantsStatus::Approved
@@ -16160,6 +17424,7 @@ pub struct PastMeetingParticipantsResponseAllOf {
 * Status of the Meeting Poll:<br>`notstart` - Poll not started<br>`started` - Poll started<br>`ended` - Poll ended<br>`sharing` - Sharing poll results
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingPollCreateResponseStatus {
     #[serde(rename = "ended")]
     Ended,
@@ -16171,8 +17436,9 @@ pub enum MeetingPollCreateResponseStatus {
     Started,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MeetingPollCreateResponseStatus {
@@ -16183,12 +17449,34 @@ impl std::fmt::Display for MeetingPollCreateResponseStatus {
             MeetingPollCreateResponseStatus::Sharing => "sharing",
             MeetingPollCreateResponseStatus::Started => "started",
             MeetingPollCreateResponseStatus::Noop => "",
-            MeetingPollCreateResponseStatus::FallthroughString => "*",
+            MeetingPollCreateResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MeetingPollCreateResponseStatus {
+    fn from(s: String) -> MeetingPollCreateResponseStatus {
+        match s.as_str() {
+            "ended" => MeetingPollCreateResponseStatus::Ended,
+            "notstart" => MeetingPollCreateResponseStatus::Notstart,
+            "sharing" => MeetingPollCreateResponseStatus::Sharing,
+            "started" => MeetingPollCreateResponseStatus::Started,
+            "" => MeetingPollCreateResponseStatus::Noop,
+            _ => MeetingPollCreateResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MeetingPollCreateResponseStatus> for String {
+    fn from(e: MeetingPollCreateResponseStatus) -> String {
+        match e {
+            MeetingPollCreateResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MeetingPollCreateResponseStatus {
     fn default() -> MeetingPollCreateResponseStatus {
         MeetingPollCreateResponseStatus::Noop
@@ -16273,13 +17561,15 @@ pub struct RecordingGetResponseAllOf {
 * The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "trash")]
     Trash,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingDeleteAction {
@@ -16287,12 +17577,31 @@ impl std::fmt::Display for RecordingDeleteAction {
         match self {
             RecordingDeleteAction::Delete => "delete",
             RecordingDeleteAction::Trash => "trash",
-            RecordingDeleteAction::FallthroughString => "*",
+            RecordingDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RecordingDeleteAction {
+    fn from(s: String) -> RecordingDeleteAction {
+        match s.as_str() {
+            "delete" => RecordingDeleteAction::Delete,
+            "trash" => RecordingDeleteAction::Trash,
+            _ => RecordingDeleteAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RecordingDeleteAction> for String {
+    fn from(e: RecordingDeleteAction) -> String {
+        match e {
+            RecordingDeleteAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RecordingDeleteAction {
     fn default() -> RecordingDeleteAction {
         RecordingDeleteAction::Trash
@@ -16345,6 +17654,7 @@ pub struct MeetingRecordingRegistrantCreateResponse {
 *   If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -16352,8 +17662,9 @@ pub enum DashboardMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingsType {
@@ -16362,12 +17673,32 @@ impl std::fmt::Display for DashboardMeetingsType {
             DashboardMeetingsType::Live => "live",
             DashboardMeetingsType::Past => "past",
             DashboardMeetingsType::PastOne => "pastOne",
-            DashboardMeetingsType::FallthroughString => "*",
+            DashboardMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DashboardMeetingsType {
+    fn from(s: String) -> DashboardMeetingsType {
+        match s.as_str() {
+            "live" => DashboardMeetingsType::Live,
+            "past" => DashboardMeetingsType::Past,
+            "pastOne" => DashboardMeetingsType::PastOne,
+            _ => DashboardMeetingsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DashboardMeetingsType> for String {
+    fn from(e: DashboardMeetingsType) -> String {
+        match e {
+            DashboardMeetingsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DashboardMeetingsType {
     fn default() -> DashboardMeetingsType {
         DashboardMeetingsType::Live
@@ -16378,13 +17709,15 @@ impl Default for DashboardMeetingsType {
 * Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IncludeFields {
     #[serde(rename = "tracking_fields")]
     TrackingFields,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for IncludeFields {
@@ -16392,12 +17725,31 @@ impl std::fmt::Display for IncludeFields {
         match self {
             IncludeFields::TrackingFields => "tracking_fields",
             IncludeFields::Noop => "",
-            IncludeFields::FallthroughString => "*",
+            IncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for IncludeFields {
+    fn from(s: String) -> IncludeFields {
+        match s.as_str() {
+            "tracking_fields" => IncludeFields::TrackingFields,
+            "" => IncludeFields::Noop,
+            _ => IncludeFields::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<IncludeFields> for String {
+    fn from(e: IncludeFields) -> String {
+        match e {
+            IncludeFields::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for IncludeFields {
     fn default() -> IncludeFields {
         IncludeFields::Noop
@@ -16445,13 +17797,15 @@ pub struct DashboardMeetingsResponseAllOf {
 * Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingParticipantsIncludeFields {
     #[serde(rename = "registrant_id")]
     RegistrantId,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
@@ -16459,12 +17813,31 @@ impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
         match self {
             DashboardMeetingParticipantsIncludeFields::RegistrantId => "registrant_id",
             DashboardMeetingParticipantsIncludeFields::Noop => "",
-            DashboardMeetingParticipantsIncludeFields::FallthroughString => "*",
+            DashboardMeetingParticipantsIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DashboardMeetingParticipantsIncludeFields {
+    fn from(s: String) -> DashboardMeetingParticipantsIncludeFields {
+        match s.as_str() {
+            "registrant_id" => DashboardMeetingParticipantsIncludeFields::RegistrantId,
+            "" => DashboardMeetingParticipantsIncludeFields::Noop,
+            _ => DashboardMeetingParticipantsIncludeFields::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DashboardMeetingParticipantsIncludeFields> for String {
+    fn from(e: DashboardMeetingParticipantsIncludeFields) -> String {
+        match e {
+            DashboardMeetingParticipantsIncludeFields::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DashboardMeetingParticipantsIncludeFields {
     fn default() -> DashboardMeetingParticipantsIncludeFields {
         DashboardMeetingParticipantsIncludeFields::Noop
@@ -16488,6 +17861,7 @@ impl DashboardMeetingParticipantsIncludeFields {
 *   **Note:** This response returns an empty string (`““`) value for any users who are **not** a part of the host’s account (external users).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingParticipantsResponseDevice {
     #[serde(rename = "Android")]
     Android,
@@ -16503,8 +17877,9 @@ pub enum DashboardMeetingParticipantsResponseDevice {
     IOs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
@@ -16517,12 +17892,36 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
             DashboardMeetingParticipantsResponseDevice::Windows => "Windows",
             DashboardMeetingParticipantsResponseDevice::IOs => "iOS",
             DashboardMeetingParticipantsResponseDevice::Noop => "",
-            DashboardMeetingParticipantsResponseDevice::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseDevice::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DashboardMeetingParticipantsResponseDevice {
+    fn from(s: String) -> DashboardMeetingParticipantsResponseDevice {
+        match s.as_str() {
+            "Android" => DashboardMeetingParticipantsResponseDevice::Android,
+            "H.323/SIP" => DashboardMeetingParticipantsResponseDevice::H323Sip,
+            "Mac" => DashboardMeetingParticipantsResponseDevice::Mac,
+            "Phone" => DashboardMeetingParticipantsResponseDevice::Phone,
+            "Windows" => DashboardMeetingParticipantsResponseDevice::Windows,
+            "iOS" => DashboardMeetingParticipantsResponseDevice::IOs,
+            "" => DashboardMeetingParticipantsResponseDevice::Noop,
+            _ => DashboardMeetingParticipantsResponseDevice::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DashboardMeetingParticipantsResponseDevice> for String {
+    fn from(e: DashboardMeetingParticipantsResponseDevice) -> String {
+        match e {
+            DashboardMeetingParticipantsResponseDevice::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DashboardMeetingParticipantsResponseDevice {
     fn default() -> DashboardMeetingParticipantsResponseDevice {
         DashboardMeetingParticipantsResponseDevice::Noop
@@ -16544,6 +17943,7 @@ impl DashboardMeetingParticipantsResponseDevice {
 *   * `Others` — An unknown device.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NetworkType {
     #[serde(rename = "Cellular")]
     Cellular,
@@ -16557,8 +17957,9 @@ pub enum NetworkType {
     Wired,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for NetworkType {
@@ -16570,12 +17971,35 @@ impl std::fmt::Display for NetworkType {
             NetworkType::Wifi => "Wifi",
             NetworkType::Wired => "Wired",
             NetworkType::Noop => "",
-            NetworkType::FallthroughString => "*",
+            NetworkType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for NetworkType {
+    fn from(s: String) -> NetworkType {
+        match s.as_str() {
+            "Cellular" => NetworkType::Cellular,
+            "Others" => NetworkType::Others,
+            "PPP" => NetworkType::Ppp,
+            "Wifi" => NetworkType::Wifi,
+            "Wired" => NetworkType::Wired,
+            "" => NetworkType::Noop,
+            _ => NetworkType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<NetworkType> for String {
+    fn from(e: NetworkType) -> String {
+        match e {
+            NetworkType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for NetworkType {
     fn default() -> NetworkType {
         NetworkType::Noop
@@ -16591,6 +18015,7 @@ impl NetworkType {
 * Audio quality of the participant.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AudioQuality {
     #[serde(rename = "bad")]
     Bad,
@@ -16602,8 +18027,9 @@ pub enum AudioQuality {
     Poor,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AudioQuality {
@@ -16614,12 +18040,34 @@ impl std::fmt::Display for AudioQuality {
             AudioQuality::Good => "good",
             AudioQuality::Poor => "poor",
             AudioQuality::Noop => "",
-            AudioQuality::FallthroughString => "*",
+            AudioQuality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AudioQuality {
+    fn from(s: String) -> AudioQuality {
+        match s.as_str() {
+            "bad" => AudioQuality::Bad,
+            "fair" => AudioQuality::Fair,
+            "good" => AudioQuality::Good,
+            "poor" => AudioQuality::Poor,
+            "" => AudioQuality::Noop,
+            _ => AudioQuality::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AudioQuality> for String {
+    fn from(e: AudioQuality) -> String {
+        match e {
+            AudioQuality::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AudioQuality {
     fn default() -> AudioQuality {
         AudioQuality::Noop
@@ -16637,6 +18085,7 @@ impl AudioQuality {
 *   The value of this field can be `in_meeting` or `in_waiting_room`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingParticipantsResponseStatus {
     #[serde(rename = "in_meeting")]
     InMeeting,
@@ -16644,8 +18093,9 @@ pub enum DashboardMeetingParticipantsResponseStatus {
     InWaitingRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
@@ -16654,12 +18104,32 @@ impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
             DashboardMeetingParticipantsResponseStatus::InMeeting => "in_meeting",
             DashboardMeetingParticipantsResponseStatus::InWaitingRoom => "in_waiting_room",
             DashboardMeetingParticipantsResponseStatus::Noop => "",
-            DashboardMeetingParticipantsResponseStatus::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DashboardMeetingParticipantsResponseStatus {
+    fn from(s: String) -> DashboardMeetingParticipantsResponseStatus {
+        match s.as_str() {
+            "in_meeting" => DashboardMeetingParticipantsResponseStatus::InMeeting,
+            "in_waiting_room" => DashboardMeetingParticipantsResponseStatus::InWaitingRoom,
+            "" => DashboardMeetingParticipantsResponseStatus::Noop,
+            _ => DashboardMeetingParticipantsResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DashboardMeetingParticipantsResponseStatus> for String {
+    fn from(e: DashboardMeetingParticipantsResponseStatus) -> String {
+        match e {
+            DashboardMeetingParticipantsResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DashboardMeetingParticipantsResponseStatus {
     fn default() -> DashboardMeetingParticipantsResponseStatus {
         DashboardMeetingParticipantsResponseStatus::Noop
@@ -17086,13 +18556,15 @@ pub struct DashboardMeetingParticipantShareResponseAllOf {
 * The webinar type.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardWebinarsType {
     #[serde(rename = "live")]
     Live,
     #[serde(rename = "past")]
     Past,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DashboardWebinarsType {
@@ -17100,12 +18572,31 @@ impl std::fmt::Display for DashboardWebinarsType {
         match self {
             DashboardWebinarsType::Live => "live",
             DashboardWebinarsType::Past => "past",
-            DashboardWebinarsType::FallthroughString => "*",
+            DashboardWebinarsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DashboardWebinarsType {
+    fn from(s: String) -> DashboardWebinarsType {
+        match s.as_str() {
+            "live" => DashboardWebinarsType::Live,
+            "past" => DashboardWebinarsType::Past,
+            _ => DashboardWebinarsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DashboardWebinarsType> for String {
+    fn from(e: DashboardWebinarsType) -> String {
+        match e {
+            DashboardWebinarsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DashboardWebinarsType {
     fn default() -> DashboardWebinarsType {
         DashboardWebinarsType::Live
@@ -18080,6 +19571,7 @@ pub struct ReportDailyResponse {
 * Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReportUsersType {
     #[serde(rename = "active")]
     Active,
@@ -18087,8 +19579,9 @@ pub enum ReportUsersType {
     Inactive,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportUsersType {
@@ -18097,12 +19590,32 @@ impl std::fmt::Display for ReportUsersType {
             ReportUsersType::Active => "active",
             ReportUsersType::Inactive => "inactive",
             ReportUsersType::Noop => "",
-            ReportUsersType::FallthroughString => "*",
+            ReportUsersType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReportUsersType {
+    fn from(s: String) -> ReportUsersType {
+        match s.as_str() {
+            "active" => ReportUsersType::Active,
+            "inactive" => ReportUsersType::Inactive,
+            "" => ReportUsersType::Noop,
+            _ => ReportUsersType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReportUsersType> for String {
+    fn from(e: ReportUsersType) -> String {
+        match e {
+            ReportUsersType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReportUsersType {
     fn default() -> ReportUsersType {
         ReportUsersType::Noop
@@ -18118,13 +19631,15 @@ impl ReportUsersType {
 * The meeting types: <br>`past` - Past meetings.<br>`pastOne` - Past one user meetings.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReportMeetingsType {
     #[serde(rename = "past")]
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ReportMeetingsType {
@@ -18132,12 +19647,31 @@ impl std::fmt::Display for ReportMeetingsType {
         match self {
             ReportMeetingsType::Past => "past",
             ReportMeetingsType::PastOne => "pastOne",
-            ReportMeetingsType::FallthroughString => "*",
+            ReportMeetingsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ReportMeetingsType {
+    fn from(s: String) -> ReportMeetingsType {
+        match s.as_str() {
+            "past" => ReportMeetingsType::Past,
+            "pastOne" => ReportMeetingsType::PastOne,
+            _ => ReportMeetingsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ReportMeetingsType> for String {
+    fn from(e: ReportMeetingsType) -> String {
+        match e {
+            ReportMeetingsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ReportMeetingsType {
     fn default() -> ReportMeetingsType {
         ReportMeetingsType::Past
@@ -18900,13 +20434,15 @@ pub struct ReportWebinarQaResponse {
 * Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum QueryDateType {
     #[serde(rename = "end_time")]
     EndTime,
     #[serde(rename = "start_time")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for QueryDateType {
@@ -18914,12 +20450,31 @@ impl std::fmt::Display for QueryDateType {
         match self {
             QueryDateType::EndTime => "end_time",
             QueryDateType::StartTime => "start_time",
-            QueryDateType::FallthroughString => "*",
+            QueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for QueryDateType {
+    fn from(s: String) -> QueryDateType {
+        match s.as_str() {
+            "end_time" => QueryDateType::EndTime,
+            "start_time" => QueryDateType::StartTime,
+            _ => QueryDateType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<QueryDateType> for String {
+    fn from(e: QueryDateType) -> String {
+        match e {
+            QueryDateType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for QueryDateType {
     fn default() -> QueryDateType {
         QueryDateType::StartTime
@@ -18961,6 +20516,7 @@ pub struct ReportCloudRecordingResponseAllOf {
 *   The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CategoryType {
     #[serde(rename = "account")]
     Account,
@@ -18988,8 +20544,9 @@ pub enum CategoryType {
     ZoomRooms,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CategoryType {
@@ -19008,12 +20565,42 @@ impl std::fmt::Display for CategoryType {
             CategoryType::Webinar => "webinar",
             CategoryType::ZoomRooms => "zoom_rooms",
             CategoryType::Noop => "",
-            CategoryType::FallthroughString => "*",
+            CategoryType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CategoryType {
+    fn from(s: String) -> CategoryType {
+        match s.as_str() {
+            "account" => CategoryType::Account,
+            "all" => CategoryType::All,
+            "billing" => CategoryType::Billing,
+            "im" => CategoryType::Im,
+            "phone_contacts" => CategoryType::PhoneContacts,
+            "recording" => CategoryType::Recording,
+            "role" => CategoryType::Role,
+            "sub_account" => CategoryType::SubAccount,
+            "user" => CategoryType::User,
+            "user_settings" => CategoryType::UserSettings,
+            "webinar" => CategoryType::Webinar,
+            "zoom_rooms" => CategoryType::ZoomRooms,
+            "" => CategoryType::Noop,
+            _ => CategoryType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CategoryType> for String {
+    fn from(e: CategoryType) -> String {
+        match e {
+            CategoryType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CategoryType {
     fn default() -> CategoryType {
         CategoryType::Noop
@@ -19359,6 +20946,7 @@ pub struct TspUpdateRequest {
 *   This value defaults to `active`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsersStatus {
     #[serde(rename = "active")]
     Active,
@@ -19366,8 +20954,9 @@ pub enum UsersStatus {
     Inactive,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersStatus {
@@ -19376,12 +20965,32 @@ impl std::fmt::Display for UsersStatus {
             UsersStatus::Active => "active",
             UsersStatus::Inactive => "inactive",
             UsersStatus::Pending => "pending",
-            UsersStatus::FallthroughString => "*",
+            UsersStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UsersStatus {
+    fn from(s: String) -> UsersStatus {
+        match s.as_str() {
+            "active" => UsersStatus::Active,
+            "inactive" => UsersStatus::Inactive,
+            "pending" => UsersStatus::Pending,
+            _ => UsersStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UsersStatus> for String {
+    fn from(e: UsersStatus) -> String {
+        match e {
+            UsersStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UsersStatus {
     fn default() -> UsersStatus {
         UsersStatus::Active
@@ -19394,6 +21003,7 @@ impl Default for UsersStatus {
 *   * `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsersIncludeFields {
     #[serde(rename = "custom_attributes")]
     CustomAttributes,
@@ -19401,8 +21011,9 @@ pub enum UsersIncludeFields {
     HostKey,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UsersIncludeFields {
@@ -19411,12 +21022,32 @@ impl std::fmt::Display for UsersIncludeFields {
             UsersIncludeFields::CustomAttributes => "custom_attributes",
             UsersIncludeFields::HostKey => "host_key",
             UsersIncludeFields::Noop => "",
-            UsersIncludeFields::FallthroughString => "*",
+            UsersIncludeFields::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UsersIncludeFields {
+    fn from(s: String) -> UsersIncludeFields {
+        match s.as_str() {
+            "custom_attributes" => UsersIncludeFields::CustomAttributes,
+            "host_key" => UsersIncludeFields::HostKey,
+            "" => UsersIncludeFields::Noop,
+            _ => UsersIncludeFields::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UsersIncludeFields> for String {
+    fn from(e: UsersIncludeFields) -> String {
+        match e {
+            UsersIncludeFields::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UsersIncludeFields {
     fn default() -> UsersIncludeFields {
         UsersIncludeFields::Noop
@@ -19706,6 +21337,7 @@ pub struct UsersResponseData {
 * Specify how to create the new user: <br>`create` - User will get an email sent from Zoom. There is a confirmation link in this email. The user will then need to use the link to activate their Zoom account. The user can then set or change their password.<br>`autoCreate` - This action is provided for the enterprise customer who has a managed domain. This feature is disabled by default because of the security risk involved in creating a user who does not belong to your domain.<br>`custCreate` - Users created via this option do not have passwords and will not have the ability to log into the Zoom Web Portal or the Zoom Client. These users can still host and join meetings using the `start_url` and `join_url` respectively. To use this option, you must contact the ISV Platform Sales team at isv@zoom.us.<br>`ssoCreate` - This action is provided for the enabled “Pre-provisioning SSO User” option. A user created in this way has no password. If not a basic user, a personal vanity URL using the user name (no domain) of the provisioning email will be generated. If the user name or PMI is invalid or occupied, it will use a random number or random personal vanity URL.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserCreateRequestAction {
     #[serde(rename = "autoCreate")]
     AutoCreate,
@@ -19717,8 +21349,9 @@ pub enum UserCreateRequestAction {
     SsoCreate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserCreateRequestAction {
@@ -19729,12 +21362,34 @@ impl std::fmt::Display for UserCreateRequestAction {
             UserCreateRequestAction::CustCreate => "custCreate",
             UserCreateRequestAction::SsoCreate => "ssoCreate",
             UserCreateRequestAction::Noop => "",
-            UserCreateRequestAction::FallthroughString => "*",
+            UserCreateRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserCreateRequestAction {
+    fn from(s: String) -> UserCreateRequestAction {
+        match s.as_str() {
+            "autoCreate" => UserCreateRequestAction::AutoCreate,
+            "create" => UserCreateRequestAction::Create,
+            "custCreate" => UserCreateRequestAction::CustCreate,
+            "ssoCreate" => UserCreateRequestAction::SsoCreate,
+            "" => UserCreateRequestAction::Noop,
+            _ => UserCreateRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserCreateRequestAction> for String {
+    fn from(e: UserCreateRequestAction) -> String {
+        match e {
+            UserCreateRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserCreateRequestAction {
     fn default() -> UserCreateRequestAction {
         UserCreateRequestAction::Noop
@@ -19817,6 +21472,7 @@ pub struct UserCreateRequest {
 *   `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LoginType {
     #[serde(rename = "0")]
     Zero,
@@ -19844,8 +21500,9 @@ pub enum LoginType {
     NinetyNine,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for LoginType {
@@ -19864,12 +21521,42 @@ impl std::fmt::Display for LoginType {
             LoginType::NinetyEight => "98",
             LoginType::NinetyNine => "99",
             LoginType::Noop => "",
-            LoginType::FallthroughString => "*",
+            LoginType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for LoginType {
+    fn from(s: String) -> LoginType {
+        match s.as_str() {
+            "0" => LoginType::Zero,
+            "1" => LoginType::One,
+            "100" => LoginType::OneHundred,
+            "101" => LoginType::OneHundredAndOne,
+            "11" => LoginType::Eleven,
+            "21" => LoginType::TwentyOne,
+            "23" => LoginType::TwentyThree,
+            "24" => LoginType::TwentyFour,
+            "27" => LoginType::TwentySeven,
+            "97" => LoginType::NinetySeven,
+            "98" => LoginType::NinetyEight,
+            "99" => LoginType::NinetyNine,
+            "" => LoginType::Noop,
+            _ => LoginType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<LoginType> for String {
+    fn from(e: LoginType) -> String {
+        match e {
+            LoginType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for LoginType {
     fn default() -> LoginType {
         LoginType::Noop
@@ -20209,13 +21896,15 @@ pub struct UserResponseAllOf {
 * Delete action options:<br>`disassociate` - Disassociate a user.<br>`delete`-  Permanently delete a user.<br>Note: To delete pending user in the account, use `disassociate`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "disassociate")]
     Disassociate,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserDeleteAction {
@@ -20223,12 +21912,31 @@ impl std::fmt::Display for UserDeleteAction {
         match self {
             UserDeleteAction::Delete => "delete",
             UserDeleteAction::Disassociate => "disassociate",
-            UserDeleteAction::FallthroughString => "*",
+            UserDeleteAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserDeleteAction {
+    fn from(s: String) -> UserDeleteAction {
+        match s.as_str() {
+            "delete" => UserDeleteAction::Delete,
+            "disassociate" => UserDeleteAction::Disassociate,
+            _ => UserDeleteAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserDeleteAction> for String {
+    fn from(e: UserDeleteAction) -> String {
+        match e {
+            UserDeleteAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserDeleteAction {
     fn default() -> UserDeleteAction {
         UserDeleteAction::Disassociate
@@ -20327,6 +22035,7 @@ impl UserSettingsUpdateRequestOneOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -20336,8 +22045,9 @@ pub enum UserSettingsUpdateOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserSettingsUpdateOption {
@@ -20347,12 +22057,33 @@ impl std::fmt::Display for UserSettingsUpdateOption {
             UserSettingsUpdateOption::MeetingSecuirty => "meeting_secuirty",
             UserSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             UserSettingsUpdateOption::Noop => "",
-            UserSettingsUpdateOption::FallthroughString => "*",
+            UserSettingsUpdateOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserSettingsUpdateOption {
+    fn from(s: String) -> UserSettingsUpdateOption {
+        match s.as_str() {
+            "meeting_authentication" => UserSettingsUpdateOption::MeetingAuthentication,
+            "meeting_secuirty" => UserSettingsUpdateOption::MeetingSecuirty,
+            "recording_authentication" => UserSettingsUpdateOption::RecordingAuthentication,
+            "" => UserSettingsUpdateOption::Noop,
+            _ => UserSettingsUpdateOption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserSettingsUpdateOption> for String {
+    fn from(e: UserSettingsUpdateOption) -> String {
+        match e {
+            UserSettingsUpdateOption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserSettingsUpdateOption {
     fn default() -> UserSettingsUpdateOption {
         UserSettingsUpdateOption::Noop
@@ -20368,6 +22099,7 @@ impl UserSettingsUpdateOption {
 * The action types:<br>`activate` - Activate a deactivated user.<br>`deactivate` - Deactivate a user.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserStatusRequestAction {
     #[serde(rename = "activate")]
     Activate,
@@ -20375,8 +22107,9 @@ pub enum UserStatusRequestAction {
     Deactivate,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserStatusRequestAction {
@@ -20385,12 +22118,32 @@ impl std::fmt::Display for UserStatusRequestAction {
             UserStatusRequestAction::Activate => "activate",
             UserStatusRequestAction::Deactivate => "deactivate",
             UserStatusRequestAction::Noop => "",
-            UserStatusRequestAction::FallthroughString => "*",
+            UserStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserStatusRequestAction {
+    fn from(s: String) -> UserStatusRequestAction {
+        match s.as_str() {
+            "activate" => UserStatusRequestAction::Activate,
+            "deactivate" => UserStatusRequestAction::Deactivate,
+            "" => UserStatusRequestAction::Noop,
+            _ => UserStatusRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserStatusRequestAction> for String {
+    fn from(e: UserStatusRequestAction) -> String {
+        match e {
+            UserStatusRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserStatusRequestAction {
     fn default() -> UserStatusRequestAction {
         UserStatusRequestAction::Noop
@@ -20627,6 +22380,7 @@ pub struct TspAccountData {
 * User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserTokenType {
     #[serde(rename = "token")]
     Token,
@@ -20634,8 +22388,9 @@ pub enum UserTokenType {
     Zak,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UserTokenType {
@@ -20644,12 +22399,32 @@ impl std::fmt::Display for UserTokenType {
             UserTokenType::Token => "token",
             UserTokenType::Zak => "zak",
             UserTokenType::Noop => "",
-            UserTokenType::FallthroughString => "*",
+            UserTokenType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UserTokenType {
+    fn from(s: String) -> UserTokenType {
+        match s.as_str() {
+            "token" => UserTokenType::Token,
+            "zak" => UserTokenType::Zak,
+            "" => UserTokenType::Noop,
+            _ => UserTokenType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UserTokenType> for String {
+    fn from(e: UserTokenType) -> String {
+        match e {
+            UserTokenType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UserTokenType {
     fn default() -> UserTokenType {
         UserTokenType::Noop
@@ -20864,13 +22639,15 @@ pub struct ListWebinarParticipantsResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WebinarStatusRequestAction {
     #[serde(rename = "end")]
     End,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for WebinarStatusRequestAction {
@@ -20878,12 +22655,31 @@ impl std::fmt::Display for WebinarStatusRequestAction {
         match self {
             WebinarStatusRequestAction::End => "end",
             WebinarStatusRequestAction::Noop => "",
-            WebinarStatusRequestAction::FallthroughString => "*",
+            WebinarStatusRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for WebinarStatusRequestAction {
+    fn from(s: String) -> WebinarStatusRequestAction {
+        match s.as_str() {
+            "end" => WebinarStatusRequestAction::End,
+            "" => WebinarStatusRequestAction::Noop,
+            _ => WebinarStatusRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<WebinarStatusRequestAction> for String {
+    fn from(e: WebinarStatusRequestAction) -> String {
+        match e {
+            WebinarStatusRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for WebinarStatusRequestAction {
     fn default() -> WebinarStatusRequestAction {
         WebinarStatusRequestAction::Noop
@@ -22002,6 +23798,7 @@ pub struct PhoneUserSettingsResponse {
 *   * `interop`<br>
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListSettingTemplatesResponseType {
     #[serde(rename = "autReceptionist")]
     AutReceptionist,
@@ -22017,8 +23814,9 @@ pub enum ListSettingTemplatesResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListSettingTemplatesResponseType {
@@ -22031,12 +23829,36 @@ impl std::fmt::Display for ListSettingTemplatesResponseType {
             ListSettingTemplatesResponseType::User => "user",
             ListSettingTemplatesResponseType::Zr => "zr",
             ListSettingTemplatesResponseType::Noop => "",
-            ListSettingTemplatesResponseType::FallthroughString => "*",
+            ListSettingTemplatesResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListSettingTemplatesResponseType {
+    fn from(s: String) -> ListSettingTemplatesResponseType {
+        match s.as_str() {
+            "autReceptionist" => ListSettingTemplatesResponseType::AutReceptionist,
+            "commonArea" => ListSettingTemplatesResponseType::CommonArea,
+            "group" => ListSettingTemplatesResponseType::Group,
+            "interop" => ListSettingTemplatesResponseType::Interop,
+            "user" => ListSettingTemplatesResponseType::User,
+            "zr" => ListSettingTemplatesResponseType::Zr,
+            "" => ListSettingTemplatesResponseType::Noop,
+            _ => ListSettingTemplatesResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListSettingTemplatesResponseType> for String {
+    fn from(e: ListSettingTemplatesResponseType) -> String {
+        match e {
+            ListSettingTemplatesResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListSettingTemplatesResponseType {
     fn default() -> ListSettingTemplatesResponseType {
         ListSettingTemplatesResponseType::Noop
@@ -22132,6 +23954,7 @@ pub struct ListSettingTemplatesResponse {
 * The type of template. Values include `user`, `group`, `autoReceptionist`, `commonarea`, or `interop`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddSettingTemplateRequestType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -22145,8 +23968,9 @@ pub enum AddSettingTemplateRequestType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AddSettingTemplateRequestType {
@@ -22158,12 +23982,35 @@ impl std::fmt::Display for AddSettingTemplateRequestType {
             AddSettingTemplateRequestType::Interop => "interop",
             AddSettingTemplateRequestType::User => "user",
             AddSettingTemplateRequestType::Noop => "",
-            AddSettingTemplateRequestType::FallthroughString => "*",
+            AddSettingTemplateRequestType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AddSettingTemplateRequestType {
+    fn from(s: String) -> AddSettingTemplateRequestType {
+        match s.as_str() {
+            "autoReceptionist" => AddSettingTemplateRequestType::AutoReceptionist,
+            "commonarea" => AddSettingTemplateRequestType::Commonarea,
+            "group" => AddSettingTemplateRequestType::Group,
+            "interop" => AddSettingTemplateRequestType::Interop,
+            "user" => AddSettingTemplateRequestType::User,
+            "" => AddSettingTemplateRequestType::Noop,
+            _ => AddSettingTemplateRequestType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AddSettingTemplateRequestType> for String {
+    fn from(e: AddSettingTemplateRequestType) -> String {
+        match e {
+            AddSettingTemplateRequestType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AddSettingTemplateRequestType {
     fn default() -> AddSettingTemplateRequestType {
         AddSettingTemplateRequestType::Noop
@@ -23170,6 +25017,7 @@ pub struct ListSipGroupsResponse {
 * The type of template being queried. Values: `user`, `group`, `auto receptionist` `common area`,`zr`, `interop`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetSettingTemplateResponseType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23185,8 +25033,9 @@ pub enum GetSettingTemplateResponseType {
     Zr,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetSettingTemplateResponseType {
@@ -23199,12 +25048,36 @@ impl std::fmt::Display for GetSettingTemplateResponseType {
             GetSettingTemplateResponseType::User => "user",
             GetSettingTemplateResponseType::Zr => "zr",
             GetSettingTemplateResponseType::Noop => "",
-            GetSettingTemplateResponseType::FallthroughString => "*",
+            GetSettingTemplateResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetSettingTemplateResponseType {
+    fn from(s: String) -> GetSettingTemplateResponseType {
+        match s.as_str() {
+            "autoReceptionist" => GetSettingTemplateResponseType::AutoReceptionist,
+            "commonArea" => GetSettingTemplateResponseType::CommonArea,
+            "group" => GetSettingTemplateResponseType::Group,
+            "interop" => GetSettingTemplateResponseType::Interop,
+            "user" => GetSettingTemplateResponseType::User,
+            "zr" => GetSettingTemplateResponseType::Zr,
+            "" => GetSettingTemplateResponseType::Noop,
+            _ => GetSettingTemplateResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetSettingTemplateResponseType> for String {
+    fn from(e: GetSettingTemplateResponseType) -> String {
+        match e {
+            GetSettingTemplateResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetSettingTemplateResponseType {
     fn default() -> GetSettingTemplateResponseType {
         GetSettingTemplateResponseType::Noop
@@ -23400,6 +25273,7 @@ pub struct CustomHours {
 *   7-sharedLineGroup
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23415,8 +25289,9 @@ pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectO
     ZoomRoom,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display
@@ -23431,12 +25306,46 @@ GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorTy
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
 GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop => "",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::FallthroughString => "*",
+GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s) => s.as_str(),
 }
 .fmt(f)
     }
 }
 
+impl std::convert::From<String>
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    fn from(
+        s: String,
+    ) -> GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
+        match s.as_str() {
+            "autoReceptionist" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist,
+            "callQueue" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue,
+            "commonAreaPhone" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone,
+            "sharedLineGroup" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup,
+            "user" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User,
+            "zoomRoom" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom,
+            "" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop,
+            _ => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s),
+        }
+    }
+}
+
+impl
+    std::convert::From<
+        GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType,
+    > for String
+{
+    fn from(
+        e: GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType,
+    ) -> String {
+        match e {
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default
     for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
 {
@@ -23578,6 +25487,7 @@ pub struct GetSettingTemplateResponseUserSettingsDeskPhone {
 *   * `disable`: This means that the hold music is disabled.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HoldMusic {
     #[serde(rename = "default")]
     Default,
@@ -23585,8 +25495,9 @@ pub enum HoldMusic {
     Disable,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for HoldMusic {
@@ -23595,12 +25506,32 @@ impl std::fmt::Display for HoldMusic {
             HoldMusic::Default => "default",
             HoldMusic::Disable => "disable",
             HoldMusic::Noop => "",
-            HoldMusic::FallthroughString => "*",
+            HoldMusic::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for HoldMusic {
+    fn from(s: String) -> HoldMusic {
+        match s.as_str() {
+            "default" => HoldMusic::Default,
+            "disable" => HoldMusic::Disable,
+            "" => HoldMusic::Noop,
+            _ => HoldMusic::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<HoldMusic> for String {
+    fn from(e: HoldMusic) -> String {
+        match e {
+            HoldMusic::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for HoldMusic {
     fn default() -> HoldMusic {
         HoldMusic::Noop
@@ -23692,6 +25623,7 @@ pub struct GetSettingTemplateResponse {
 * Values: inbound, outbound, both.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingCalls {
     #[serde(rename = "both")]
     Both,
@@ -23701,8 +25633,9 @@ pub enum RecordingCalls {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for RecordingCalls {
@@ -23712,12 +25645,33 @@ impl std::fmt::Display for RecordingCalls {
             RecordingCalls::Inbound => "inbound",
             RecordingCalls::Outbound => "outbound",
             RecordingCalls::Noop => "",
-            RecordingCalls::FallthroughString => "*",
+            RecordingCalls::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for RecordingCalls {
+    fn from(s: String) -> RecordingCalls {
+        match s.as_str() {
+            "both" => RecordingCalls::Both,
+            "inbound" => RecordingCalls::Inbound,
+            "outbound" => RecordingCalls::Outbound,
+            "" => RecordingCalls::Noop,
+            _ => RecordingCalls::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<RecordingCalls> for String {
+    fn from(e: RecordingCalls) -> String {
+        match e {
+            RecordingCalls::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for RecordingCalls {
     fn default() -> RecordingCalls {
         RecordingCalls::Noop
@@ -23803,6 +25757,7 @@ pub struct UpdateSettingTemplateRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserCallLogsType {
     #[serde(rename = "all")]
     All,
@@ -23810,8 +25765,9 @@ pub enum PhoneUserCallLogsType {
     Missed,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsType {
@@ -23820,12 +25776,32 @@ impl std::fmt::Display for PhoneUserCallLogsType {
             PhoneUserCallLogsType::All => "all",
             PhoneUserCallLogsType::Missed => "missed",
             PhoneUserCallLogsType::Noop => "",
-            PhoneUserCallLogsType::FallthroughString => "*",
+            PhoneUserCallLogsType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PhoneUserCallLogsType {
+    fn from(s: String) -> PhoneUserCallLogsType {
+        match s.as_str() {
+            "all" => PhoneUserCallLogsType::All,
+            "missed" => PhoneUserCallLogsType::Missed,
+            "" => PhoneUserCallLogsType::Noop,
+            _ => PhoneUserCallLogsType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PhoneUserCallLogsType> for String {
+    fn from(e: PhoneUserCallLogsType) -> String {
+        match e {
+            PhoneUserCallLogsType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PhoneUserCallLogsType {
     fn default() -> PhoneUserCallLogsType {
         PhoneUserCallLogsType::Noop
@@ -23841,13 +25817,15 @@ impl PhoneUserCallLogsType {
 * Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TimeType {
     #[serde(rename = "endTime")]
     EndTime,
     #[serde(rename = "startTime")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for TimeType {
@@ -23855,12 +25833,31 @@ impl std::fmt::Display for TimeType {
         match self {
             TimeType::EndTime => "endTime",
             TimeType::StartTime => "startTime",
-            TimeType::FallthroughString => "*",
+            TimeType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for TimeType {
+    fn from(s: String) -> TimeType {
+        match s.as_str() {
+            "endTime" => TimeType::EndTime,
+            "startTime" => TimeType::StartTime,
+            _ => TimeType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<TimeType> for String {
+    fn from(e: TimeType) -> String {
+        match e {
+            TimeType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for TimeType {
     fn default() -> TimeType {
         TimeType::StartTime
@@ -23918,6 +25915,7 @@ pub struct ForwardedTo {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23931,8 +25929,9 @@ pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
@@ -23946,12 +25945,37 @@ impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
             PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup => "sharedLineGroup",
             PhoneUserCallLogsResponseForwardedByExtensionType::User => "user",
             PhoneUserCallLogsResponseForwardedByExtensionType::Noop => "",
-            PhoneUserCallLogsResponseForwardedByExtensionType::FallthroughString => "*",
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn from(s: String) -> PhoneUserCallLogsResponseForwardedByExtensionType {
+        match s.as_str() {
+            "autoReceptionist" => {
+                PhoneUserCallLogsResponseForwardedByExtensionType::AutoReceptionist
+            }
+            "callQueue" => PhoneUserCallLogsResponseForwardedByExtensionType::CallQueue,
+            "commonAreaPhone" => PhoneUserCallLogsResponseForwardedByExtensionType::CommonAreaPhone,
+            "sharedLineGroup" => PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup,
+            "user" => PhoneUserCallLogsResponseForwardedByExtensionType::User,
+            "" => PhoneUserCallLogsResponseForwardedByExtensionType::Noop,
+            _ => PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PhoneUserCallLogsResponseForwardedByExtensionType> for String {
+    fn from(e: PhoneUserCallLogsResponseForwardedByExtensionType) -> String {
+        match e {
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PhoneUserCallLogsResponseForwardedByExtensionType {
     fn default() -> PhoneUserCallLogsResponseForwardedByExtensionType {
         PhoneUserCallLogsResponseForwardedByExtensionType::Noop
@@ -24606,6 +26630,7 @@ pub struct PhoneUserRecordingsResponse {
 * Status of the voice mail
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserVoiceMailsStatus {
     #[serde(rename = "all")]
     All,
@@ -24613,8 +26638,9 @@ pub enum PhoneUserVoiceMailsStatus {
     Read,
     #[serde(rename = "unread")]
     Unread,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsStatus {
@@ -24623,12 +26649,32 @@ impl std::fmt::Display for PhoneUserVoiceMailsStatus {
             PhoneUserVoiceMailsStatus::All => "all",
             PhoneUserVoiceMailsStatus::Read => "read",
             PhoneUserVoiceMailsStatus::Unread => "unread",
-            PhoneUserVoiceMailsStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PhoneUserVoiceMailsStatus {
+    fn from(s: String) -> PhoneUserVoiceMailsStatus {
+        match s.as_str() {
+            "all" => PhoneUserVoiceMailsStatus::All,
+            "read" => PhoneUserVoiceMailsStatus::Read,
+            "unread" => PhoneUserVoiceMailsStatus::Unread,
+            _ => PhoneUserVoiceMailsStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PhoneUserVoiceMailsStatus> for String {
+    fn from(e: PhoneUserVoiceMailsStatus) -> String {
+        match e {
+            PhoneUserVoiceMailsStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PhoneUserVoiceMailsStatus {
     fn default() -> PhoneUserVoiceMailsStatus {
         PhoneUserVoiceMailsStatus::All
@@ -24639,6 +26685,7 @@ impl Default for PhoneUserVoiceMailsStatus {
 * Status of the voice mail. Can be either 'read' or 'unread'
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserVoiceMailsResponseStatus {
     #[serde(rename = "read")]
     Read,
@@ -24646,8 +26693,9 @@ pub enum PhoneUserVoiceMailsResponseStatus {
     Unread,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
@@ -24656,12 +26704,32 @@ impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
             PhoneUserVoiceMailsResponseStatus::Read => "read",
             PhoneUserVoiceMailsResponseStatus::Unread => "unread",
             PhoneUserVoiceMailsResponseStatus::Noop => "",
-            PhoneUserVoiceMailsResponseStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for PhoneUserVoiceMailsResponseStatus {
+    fn from(s: String) -> PhoneUserVoiceMailsResponseStatus {
+        match s.as_str() {
+            "read" => PhoneUserVoiceMailsResponseStatus::Read,
+            "unread" => PhoneUserVoiceMailsResponseStatus::Unread,
+            "" => PhoneUserVoiceMailsResponseStatus::Noop,
+            _ => PhoneUserVoiceMailsResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<PhoneUserVoiceMailsResponseStatus> for String {
+    fn from(e: PhoneUserVoiceMailsResponseStatus) -> String {
+        match e {
+            PhoneUserVoiceMailsResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for PhoneUserVoiceMailsResponseStatus {
     fn default() -> PhoneUserVoiceMailsResponseStatus {
         PhoneUserVoiceMailsResponseStatus::Noop
@@ -24980,6 +27048,7 @@ pub struct UpdateUserSettingRequest {
 *   *`contactCenter`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CallType {
     #[serde(rename = "contactCenter")]
     ContactCenter,
@@ -24993,8 +27062,9 @@ pub enum CallType {
     Voip,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for CallType {
@@ -25006,12 +27076,35 @@ impl std::fmt::Display for CallType {
             CallType::Tollfree => "tollfree",
             CallType::Voip => "voip",
             CallType::Noop => "",
-            CallType::FallthroughString => "*",
+            CallType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for CallType {
+    fn from(s: String) -> CallType {
+        match s.as_str() {
+            "contactCenter" => CallType::ContactCenter,
+            "international" => CallType::International,
+            "pstn" => CallType::Pstn,
+            "tollfree" => CallType::Tollfree,
+            "voip" => CallType::Voip,
+            "" => CallType::Noop,
+            _ => CallType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<CallType> for String {
+    fn from(e: CallType) -> String {
+        match e {
+            CallType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for CallType {
     fn default() -> CallType {
         CallType::Noop
@@ -25381,6 +27474,7 @@ pub struct AssignCallingPlanRequest {
 * The owner type: `user` or `call queue`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetPhoneRecordingsResponseOwnerType {
     #[serde(rename = "call queue")]
     CallQueue,
@@ -25388,8 +27482,9 @@ pub enum GetPhoneRecordingsResponseOwnerType {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
@@ -25398,12 +27493,32 @@ impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
             GetPhoneRecordingsResponseOwnerType::CallQueue => "call queue",
             GetPhoneRecordingsResponseOwnerType::User => "user",
             GetPhoneRecordingsResponseOwnerType::Noop => "",
-            GetPhoneRecordingsResponseOwnerType::FallthroughString => "*",
+            GetPhoneRecordingsResponseOwnerType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetPhoneRecordingsResponseOwnerType {
+    fn from(s: String) -> GetPhoneRecordingsResponseOwnerType {
+        match s.as_str() {
+            "call queue" => GetPhoneRecordingsResponseOwnerType::CallQueue,
+            "user" => GetPhoneRecordingsResponseOwnerType::User,
+            "" => GetPhoneRecordingsResponseOwnerType::Noop,
+            _ => GetPhoneRecordingsResponseOwnerType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetPhoneRecordingsResponseOwnerType> for String {
+    fn from(e: GetPhoneRecordingsResponseOwnerType) -> String {
+        match e {
+            GetPhoneRecordingsResponseOwnerType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetPhoneRecordingsResponseOwnerType {
     fn default() -> GetPhoneRecordingsResponseOwnerType {
         GetPhoneRecordingsResponseOwnerType::Noop
@@ -25456,6 +27571,7 @@ pub struct GetPhoneRecordingsResponseOwner {
 * The direction of the call. Values: `inbound` or `outbound`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Direction {
     #[serde(rename = "inbound")]
     Inbound,
@@ -25463,8 +27579,9 @@ pub enum Direction {
     Outbound,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Direction {
@@ -25473,12 +27590,32 @@ impl std::fmt::Display for Direction {
             Direction::Inbound => "inbound",
             Direction::Outbound => "outbound",
             Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Direction {
+    fn from(s: String) -> Direction {
+        match s.as_str() {
+            "inbound" => Direction::Inbound,
+            "outbound" => Direction::Outbound,
+            "" => Direction::Noop,
+            _ => Direction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Direction> for String {
+    fn from(e: Direction) -> String {
+        match e {
+            Direction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Direction {
     fn default() -> Direction {
         Direction::Noop
@@ -25850,13 +27987,15 @@ pub struct AccountPlanBaseDeleteRequest {
 * The action that needs to be taken for this sub account. Value must be set to "cancel".
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountPlanAddonCancelRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
@@ -25864,12 +28003,31 @@ impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
         match self {
             AccountPlanAddonCancelRequestAction::Cancel => "cancel",
             AccountPlanAddonCancelRequestAction::Noop => "",
-            AccountPlanAddonCancelRequestAction::FallthroughString => "*",
+            AccountPlanAddonCancelRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for AccountPlanAddonCancelRequestAction {
+    fn from(s: String) -> AccountPlanAddonCancelRequestAction {
+        match s.as_str() {
+            "cancel" => AccountPlanAddonCancelRequestAction::Cancel,
+            "" => AccountPlanAddonCancelRequestAction::Noop,
+            _ => AccountPlanAddonCancelRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<AccountPlanAddonCancelRequestAction> for String {
+    fn from(e: AccountPlanAddonCancelRequestAction) -> String {
+        match e {
+            AccountPlanAddonCancelRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for AccountPlanAddonCancelRequestAction {
     fn default() -> AccountPlanAddonCancelRequestAction {
         AccountPlanAddonCancelRequestAction::Noop
@@ -27353,6 +29511,7 @@ impl UpdateGroupSettingsRequestOneOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UpdateGroupSettingsOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -27362,8 +29521,9 @@ pub enum UpdateGroupSettingsOption {
     RecordingAuthentication,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdateGroupSettingsOption {
@@ -27373,12 +29533,33 @@ impl std::fmt::Display for UpdateGroupSettingsOption {
             UpdateGroupSettingsOption::MeetingSecurity => "meeting_security",
             UpdateGroupSettingsOption::RecordingAuthentication => "recording_authentication",
             UpdateGroupSettingsOption::Noop => "",
-            UpdateGroupSettingsOption::FallthroughString => "*",
+            UpdateGroupSettingsOption::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UpdateGroupSettingsOption {
+    fn from(s: String) -> UpdateGroupSettingsOption {
+        match s.as_str() {
+            "meeting_authentication" => UpdateGroupSettingsOption::MeetingAuthentication,
+            "meeting_security" => UpdateGroupSettingsOption::MeetingSecurity,
+            "recording_authentication" => UpdateGroupSettingsOption::RecordingAuthentication,
+            "" => UpdateGroupSettingsOption::Noop,
+            _ => UpdateGroupSettingsOption::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UpdateGroupSettingsOption> for String {
+    fn from(e: UpdateGroupSettingsOption) -> String {
+        match e {
+            UpdateGroupSettingsOption::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UpdateGroupSettingsOption {
     fn default() -> UpdateGroupSettingsOption {
         UpdateGroupSettingsOption::Noop
@@ -28520,6 +30701,7 @@ pub struct SwitchUserAccountRequest {
 *   	`id`, `status`, `file_size`, `recording_type`, and `play_url`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     #[serde(rename = "CC")]
     Cc,
@@ -28537,8 +30719,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     Transcript,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType {
@@ -28552,12 +30735,37 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb => "TB",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript => "TRANSCRIPT",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn from(s: String) -> GetAccountCloudRecordingResponseMeetingsFilesFileType {
+        match s.as_str() {
+            "CC" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc,
+            "CHAT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Chat,
+            "CSV" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Csv,
+            "M4A" => GetAccountCloudRecordingResponseMeetingsFilesFileType::M4A,
+            "MP4" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Mp4,
+            "TB" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb,
+            "TRANSCRIPT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetAccountCloudRecordingResponseMeetingsFilesFileType> for String {
+    fn from(e: GetAccountCloudRecordingResponseMeetingsFilesFileType) -> String {
+        match e {
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesFileType {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesFileType {
         GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop
@@ -28576,6 +30784,7 @@ impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
 * The status of the recording, either `completed` or `processing`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -28583,8 +30792,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     Processing,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
@@ -28593,12 +30803,32 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed => "completed",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing => "processing",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn from(s: String) -> GetAccountCloudRecordingResponseMeetingsFilesStatus {
+        match s.as_str() {
+            "completed" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed,
+            "processing" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetAccountCloudRecordingResponseMeetingsFilesStatus> for String {
+    fn from(e: GetAccountCloudRecordingResponseMeetingsFilesStatus) -> String {
+        match e {
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesStatus {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesStatus {
         GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop
@@ -28632,6 +30862,7 @@ impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
 *   
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -28663,8 +30894,9 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     Timeline,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
@@ -28693,12 +30925,52 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
             GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail => "thumbnail",
             GetAccountCloudRecordingResponseMeetingsFilesType::Timeline => "timeline",
             GetAccountCloudRecordingResponseMeetingsFilesType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn from(s: String) -> GetAccountCloudRecordingResponseMeetingsFilesType {
+        match s.as_str() {
+            "active_speaker" => GetAccountCloudRecordingResponseMeetingsFilesType::ActiveSpeaker,
+            "audio_only" => GetAccountCloudRecordingResponseMeetingsFilesType::AudioOnly,
+            "audio_transcript" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::AudioTranscript
+            }
+            "chat_file" => GetAccountCloudRecordingResponseMeetingsFilesType::ChatFile,
+            "closed_caption" => GetAccountCloudRecordingResponseMeetingsFilesType::ClosedCaption,
+            "gallery_view" => GetAccountCloudRecordingResponseMeetingsFilesType::GalleryView,
+            "host_video" => GetAccountCloudRecordingResponseMeetingsFilesType::HostVideo,
+            "poll" => GetAccountCloudRecordingResponseMeetingsFilesType::Poll,
+            "shared_screen" => GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreen,
+            "shared_screen_with_gallery_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithGalleryView
+            }
+            "shared_screen_with_speaker_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerView
+            }
+            "shared_screen_with_speaker_view(CC)" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerViewCc
+            }
+            "thumbnail" => GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail,
+            "timeline" => GetAccountCloudRecordingResponseMeetingsFilesType::Timeline,
+            "" => GetAccountCloudRecordingResponseMeetingsFilesType::Noop,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetAccountCloudRecordingResponseMeetingsFilesType> for String {
+    fn from(e: GetAccountCloudRecordingResponseMeetingsFilesType) -> String {
+        match e {
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetAccountCloudRecordingResponseMeetingsFilesType {
     fn default() -> GetAccountCloudRecordingResponseMeetingsFilesType {
         GetAccountCloudRecordingResponseMeetingsFilesType::Noop
@@ -29085,13 +31357,15 @@ pub struct UpdateZrAccProfileRequest {
 *   `signage`: View digital signage settings of the Zoom Rooms Account.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SettingType {
     #[serde(rename = "alert")]
     Alert,
     #[serde(rename = "meeting")]
     Meeting,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for SettingType {
@@ -29099,12 +31373,31 @@ impl std::fmt::Display for SettingType {
         match self {
             SettingType::Alert => "alert",
             SettingType::Meeting => "meeting",
-            SettingType::FallthroughString => "*",
+            SettingType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for SettingType {
+    fn from(s: String) -> SettingType {
+        match s.as_str() {
+            "alert" => SettingType::Alert,
+            "meeting" => SettingType::Meeting,
+            _ => SettingType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<SettingType> for String {
+    fn from(e: SettingType) -> String {
+        match e {
+            SettingType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for SettingType {
     fn default() -> SettingType {
         SettingType::Meeting
@@ -29116,6 +31409,7 @@ impl Default for SettingType {
 *   `country`, `states`, `city`, `campus`, `building`, `floor`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZrLocationsResponseType {
     #[serde(rename = "building")]
     Building,
@@ -29131,8 +31425,9 @@ pub enum ListZrLocationsResponseType {
     States,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrLocationsResponseType {
@@ -29145,12 +31440,36 @@ impl std::fmt::Display for ListZrLocationsResponseType {
             ListZrLocationsResponseType::Floor => "floor",
             ListZrLocationsResponseType::States => "states",
             ListZrLocationsResponseType::Noop => "",
-            ListZrLocationsResponseType::FallthroughString => "*",
+            ListZrLocationsResponseType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListZrLocationsResponseType {
+    fn from(s: String) -> ListZrLocationsResponseType {
+        match s.as_str() {
+            "building" => ListZrLocationsResponseType::Building,
+            "campus" => ListZrLocationsResponseType::Campus,
+            "city" => ListZrLocationsResponseType::City,
+            "country" => ListZrLocationsResponseType::Country,
+            "floor" => ListZrLocationsResponseType::Floor,
+            "states" => ListZrLocationsResponseType::States,
+            "" => ListZrLocationsResponseType::Noop,
+            _ => ListZrLocationsResponseType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListZrLocationsResponseType> for String {
+    fn from(e: ListZrLocationsResponseType) -> String {
+        match e {
+            ListZrLocationsResponseType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListZrLocationsResponseType {
     fn default() -> ListZrLocationsResponseType {
         ListZrLocationsResponseType::Noop
@@ -29484,6 +31803,7 @@ pub struct UpdateRoomProfileRequest {
 * Type of the device. The value of this field can be one of the following:<br>`Zoom Rooms Computer`,<br> `Controller`, `Scheduling Display`, `Zoom Rooms Control System`, `Companion Whiteboard`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DeviceType {
     #[serde(rename = "Companion Whiteboard")]
     CompanionWhiteboard,
@@ -29497,8 +31817,9 @@ pub enum DeviceType {
     ZoomRoomsControlSystem,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for DeviceType {
@@ -29510,12 +31831,35 @@ impl std::fmt::Display for DeviceType {
             DeviceType::ZoomRoomsComputer => "Zoom Rooms Computer",
             DeviceType::ZoomRoomsControlSystem => "Zoom Rooms Control System",
             DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for DeviceType {
+    fn from(s: String) -> DeviceType {
+        match s.as_str() {
+            "Companion Whiteboard" => DeviceType::CompanionWhiteboard,
+            "Controller" => DeviceType::Controller,
+            "Scheduling Display" => DeviceType::SchedulingDisplay,
+            "Zoom Rooms Computer" => DeviceType::ZoomRoomsComputer,
+            "Zoom Rooms Control System" => DeviceType::ZoomRoomsControlSystem,
+            "" => DeviceType::Noop,
+            _ => DeviceType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<DeviceType> for String {
+    fn from(e: DeviceType) -> String {
+        match e {
+            DeviceType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for DeviceType {
     fn default() -> DeviceType {
         DeviceType::Noop
@@ -29531,6 +31875,7 @@ impl DeviceType {
 * Status of the device. The value can be either `Online` or `Offline`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZrDevicesResponseStatus {
     #[serde(rename = "Offline")]
     Offline,
@@ -29538,8 +31883,9 @@ pub enum ListZrDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListZrDevicesResponseStatus {
@@ -29548,12 +31894,32 @@ impl std::fmt::Display for ListZrDevicesResponseStatus {
             ListZrDevicesResponseStatus::Offline => "Offline",
             ListZrDevicesResponseStatus::Online => "Online",
             ListZrDevicesResponseStatus::Noop => "",
-            ListZrDevicesResponseStatus::FallthroughString => "*",
+            ListZrDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListZrDevicesResponseStatus {
+    fn from(s: String) -> ListZrDevicesResponseStatus {
+        match s.as_str() {
+            "Offline" => ListZrDevicesResponseStatus::Offline,
+            "Online" => ListZrDevicesResponseStatus::Online,
+            "" => ListZrDevicesResponseStatus::Noop,
+            _ => ListZrDevicesResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListZrDevicesResponseStatus> for String {
+    fn from(e: ListZrDevicesResponseStatus) -> String {
+        match e {
+            ListZrDevicesResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListZrDevicesResponseStatus {
     fn default() -> ListZrDevicesResponseStatus {
         ListZrDevicesResponseStatus::Noop
@@ -29835,6 +32201,7 @@ pub struct ListMeetingSatisfactionResponse {
 * State of the device. The value should be either `assigned` to list devices that have been assigned to user(s) or `unassigned` to list devices that have not yet been assigned to any user in the Zoom account.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListPhoneDevicesType {
     #[serde(rename = "assigned")]
     Assigned,
@@ -29842,8 +32209,9 @@ pub enum ListPhoneDevicesType {
     Unassigned,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesType {
@@ -29852,12 +32220,32 @@ impl std::fmt::Display for ListPhoneDevicesType {
             ListPhoneDevicesType::Assigned => "assigned",
             ListPhoneDevicesType::Unassigned => "unassigned",
             ListPhoneDevicesType::Noop => "",
-            ListPhoneDevicesType::FallthroughString => "*",
+            ListPhoneDevicesType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListPhoneDevicesType {
+    fn from(s: String) -> ListPhoneDevicesType {
+        match s.as_str() {
+            "assigned" => ListPhoneDevicesType::Assigned,
+            "unassigned" => ListPhoneDevicesType::Unassigned,
+            "" => ListPhoneDevicesType::Noop,
+            _ => ListPhoneDevicesType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListPhoneDevicesType> for String {
+    fn from(e: ListPhoneDevicesType) -> String {
+        match e {
+            ListPhoneDevicesType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListPhoneDevicesType {
     fn default() -> ListPhoneDevicesType {
         ListPhoneDevicesType::Noop
@@ -29904,6 +32292,7 @@ pub struct AddAutoReceptionistResponse {
 * Status of the device. The value is either `online` or `offline`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListPhoneDevicesResponseStatus {
     #[serde(rename = "offline")]
     Offline,
@@ -29911,8 +32300,9 @@ pub enum ListPhoneDevicesResponseStatus {
     Online,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListPhoneDevicesResponseStatus {
@@ -29921,12 +32311,32 @@ impl std::fmt::Display for ListPhoneDevicesResponseStatus {
             ListPhoneDevicesResponseStatus::Offline => "offline",
             ListPhoneDevicesResponseStatus::Online => "online",
             ListPhoneDevicesResponseStatus::Noop => "",
-            ListPhoneDevicesResponseStatus::FallthroughString => "*",
+            ListPhoneDevicesResponseStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListPhoneDevicesResponseStatus {
+    fn from(s: String) -> ListPhoneDevicesResponseStatus {
+        match s.as_str() {
+            "offline" => ListPhoneDevicesResponseStatus::Offline,
+            "online" => ListPhoneDevicesResponseStatus::Online,
+            "" => ListPhoneDevicesResponseStatus::Noop,
+            _ => ListPhoneDevicesResponseStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListPhoneDevicesResponseStatus> for String {
+    fn from(e: ListPhoneDevicesResponseStatus) -> String {
+        match e {
+            ListPhoneDevicesResponseStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListPhoneDevicesResponseStatus {
     fn default() -> ListPhoneDevicesResponseStatus {
         ListPhoneDevicesResponseStatus::Noop
@@ -30116,6 +32526,7 @@ pub struct GetDeviceResponseAssignee {
 *   
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetDeviceResponseProvisionType {
     #[serde(rename = "assisted")]
     Assisted,
@@ -30125,8 +32536,9 @@ pub enum GetDeviceResponseProvisionType {
     Ztp,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetDeviceResponseProvisionType {
@@ -30136,12 +32548,33 @@ impl std::fmt::Display for GetDeviceResponseProvisionType {
             GetDeviceResponseProvisionType::Manual => "manual",
             GetDeviceResponseProvisionType::Ztp => "ztp",
             GetDeviceResponseProvisionType::Noop => "",
-            GetDeviceResponseProvisionType::FallthroughString => "*",
+            GetDeviceResponseProvisionType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetDeviceResponseProvisionType {
+    fn from(s: String) -> GetDeviceResponseProvisionType {
+        match s.as_str() {
+            "assisted" => GetDeviceResponseProvisionType::Assisted,
+            "manual" => GetDeviceResponseProvisionType::Manual,
+            "ztp" => GetDeviceResponseProvisionType::Ztp,
+            "" => GetDeviceResponseProvisionType::Noop,
+            _ => GetDeviceResponseProvisionType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetDeviceResponseProvisionType> for String {
+    fn from(e: GetDeviceResponseProvisionType) -> String {
+        match e {
+            GetDeviceResponseProvisionType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetDeviceResponseProvisionType {
     fn default() -> GetDeviceResponseProvisionType {
         GetDeviceResponseProvisionType::Noop
@@ -30884,6 +33317,7 @@ pub struct CreateCallQueueResponse {
 *   `user`: Regular user without the privileges of a manager.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Level {
     #[serde(rename = "manager")]
     Manager,
@@ -30891,8 +33325,9 @@ pub enum Level {
     User,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Level {
@@ -30901,12 +33336,32 @@ impl std::fmt::Display for Level {
             Level::Manager => "manager",
             Level::User => "user",
             Level::Noop => "",
-            Level::FallthroughString => "*",
+            Level::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Level {
+    fn from(s: String) -> Level {
+        match s.as_str() {
+            "manager" => Level::Manager,
+            "user" => Level::User,
+            "" => Level::Noop,
+            _ => Level::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Level> for String {
+    fn from(e: Level) -> String {
+        match e {
+            Level::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Level {
     fn default() -> Level {
         Level::Noop
@@ -31851,6 +34306,7 @@ pub struct InternationalCall {
 * Allow Hot Desking feature to current device.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     #[serde(rename = "off")]
     Off,
@@ -31860,8 +34316,9 @@ pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     Unsupported,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
@@ -31871,12 +34328,33 @@ impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On => "on",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported => "unsupported",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop => "",
-            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::FallthroughString => "*",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn from(s: String) -> GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+        match s.as_str() {
+            "off" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Off,
+            "on" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On,
+            "unsupported" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported,
+            "" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop,
+            _ => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<GetCommonAreaPhoneResponsePolicyHotDeskingStatus> for String {
+    fn from(e: GetCommonAreaPhoneResponsePolicyHotDeskingStatus) -> String {
+        match e {
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     fn default() -> GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
         GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop
@@ -32158,6 +34636,7 @@ pub struct AssignCallingPlansCommonAreaPhoneResponseData {
 *   `prefix`: Indicates that all numbers starting with prefix that is shown in the `phone_number` field are blocked.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MatchType {
     #[serde(rename = "phoneNumber")]
     PhoneNumber,
@@ -32165,8 +34644,9 @@ pub enum MatchType {
     Prefix,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for MatchType {
@@ -32175,12 +34655,32 @@ impl std::fmt::Display for MatchType {
             MatchType::PhoneNumber => "phoneNumber",
             MatchType::Prefix => "prefix",
             MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for MatchType {
+    fn from(s: String) -> MatchType {
+        match s.as_str() {
+            "phoneNumber" => MatchType::PhoneNumber,
+            "prefix" => MatchType::Prefix,
+            "" => MatchType::Noop,
+            _ => MatchType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<MatchType> for String {
+    fn from(e: MatchType) -> String {
+        match e {
+            MatchType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for MatchType {
     fn default() -> MatchType {
         MatchType::Noop
@@ -33361,6 +35861,7 @@ pub struct AddCalloutCountriesResponse {
 * Specify the language in which this phone number should be displayed. Currently, the only supported languages are `en-GB`, `en-US`, `de-DE`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Languages {
     #[serde(rename = "de-DE")]
     De,
@@ -33370,8 +35871,9 @@ pub enum Languages {
     EnUs,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Languages {
@@ -33381,12 +35883,33 @@ impl std::fmt::Display for Languages {
             Languages::EnGb => "en-GB",
             Languages::EnUs => "en-US",
             Languages::Noop => "",
-            Languages::FallthroughString => "*",
+            Languages::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Languages {
+    fn from(s: String) -> Languages {
+        match s.as_str() {
+            "de-DE" => Languages::De,
+            "en-GB" => Languages::EnGb,
+            "en-US" => Languages::EnUs,
+            "" => Languages::Noop,
+            _ => Languages::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Languages> for String {
+    fn from(e: Languages) -> String {
+        match e {
+            Languages::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Languages {
     fn default() -> Languages {
         Languages::Noop
@@ -33630,6 +36153,7 @@ pub struct AddInternalNumbersResponseData {
 *   `cancel`: Cancel an ongoing upgrade or downgrade process.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ChangeZoomRoomsAppVersionRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
@@ -33639,8 +36163,9 @@ pub enum ChangeZoomRoomsAppVersionRequestAction {
     Upgrade,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
@@ -33650,12 +36175,33 @@ impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
             ChangeZoomRoomsAppVersionRequestAction::Downgrade => "downgrade",
             ChangeZoomRoomsAppVersionRequestAction::Upgrade => "upgrade",
             ChangeZoomRoomsAppVersionRequestAction::Noop => "",
-            ChangeZoomRoomsAppVersionRequestAction::FallthroughString => "*",
+            ChangeZoomRoomsAppVersionRequestAction::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ChangeZoomRoomsAppVersionRequestAction {
+    fn from(s: String) -> ChangeZoomRoomsAppVersionRequestAction {
+        match s.as_str() {
+            "cancel" => ChangeZoomRoomsAppVersionRequestAction::Cancel,
+            "downgrade" => ChangeZoomRoomsAppVersionRequestAction::Downgrade,
+            "upgrade" => ChangeZoomRoomsAppVersionRequestAction::Upgrade,
+            "" => ChangeZoomRoomsAppVersionRequestAction::Noop,
+            _ => ChangeZoomRoomsAppVersionRequestAction::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ChangeZoomRoomsAppVersionRequestAction> for String {
+    fn from(e: ChangeZoomRoomsAppVersionRequestAction) -> String {
+        match e {
+            ChangeZoomRoomsAppVersionRequestAction::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ChangeZoomRoomsAppVersionRequestAction {
     fn default() -> ChangeZoomRoomsAppVersionRequestAction {
         ChangeZoomRoomsAppVersionRequestAction::Noop
@@ -33748,6 +36294,7 @@ pub struct ListWebinarTemplatesResponse {
 *   * `Away` to `Available`
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UpdatePresenceStatusRequest {
     #[serde(rename = "Available")]
     Available,
@@ -33765,8 +36312,9 @@ pub enum UpdatePresenceStatusRequest {
     Presenting,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for UpdatePresenceStatusRequest {
@@ -33780,12 +36328,37 @@ impl std::fmt::Display for UpdatePresenceStatusRequest {
             UpdatePresenceStatusRequest::OnACall => "On_A_Call",
             UpdatePresenceStatusRequest::Presenting => "Presenting",
             UpdatePresenceStatusRequest::Noop => "",
-            UpdatePresenceStatusRequest::FallthroughString => "*",
+            UpdatePresenceStatusRequest::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for UpdatePresenceStatusRequest {
+    fn from(s: String) -> UpdatePresenceStatusRequest {
+        match s.as_str() {
+            "Available" => UpdatePresenceStatusRequest::Available,
+            "Away" => UpdatePresenceStatusRequest::Away,
+            "Do_No_Disturb" => UpdatePresenceStatusRequest::DoNoDisturb,
+            "In_A_Zoom_Meeting" => UpdatePresenceStatusRequest::InAZoomMeeting,
+            "In_Calendar_Event" => UpdatePresenceStatusRequest::InCalendarEvent,
+            "On_A_Call" => UpdatePresenceStatusRequest::OnACall,
+            "Presenting" => UpdatePresenceStatusRequest::Presenting,
+            "" => UpdatePresenceStatusRequest::Noop,
+            _ => UpdatePresenceStatusRequest::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<UpdatePresenceStatusRequest> for String {
+    fn from(e: UpdatePresenceStatusRequest) -> String {
+        match e {
+            UpdatePresenceStatusRequest::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for UpdatePresenceStatusRequest {
     fn default() -> UpdatePresenceStatusRequest {
         UpdatePresenceStatusRequest::Noop
@@ -34681,6 +37254,7 @@ pub struct GetAccountBillingInvoiceResponse {
 *   * `NOT GOOD`: Thumbs down.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Quality {
     #[serde(rename = "GOOD")]
     Good,
@@ -34688,8 +37262,9 @@ pub enum Quality {
     NotGood,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Quality {
@@ -34698,12 +37273,32 @@ impl std::fmt::Display for Quality {
             Quality::Good => "GOOD",
             Quality::NotGood => "NOT GOOD",
             Quality::Noop => "",
-            Quality::FallthroughString => "*",
+            Quality::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Quality {
+    fn from(s: String) -> Quality {
+        match s.as_str() {
+            "GOOD" => Quality::Good,
+            "NOT GOOD" => Quality::NotGood,
+            "" => Quality::Noop,
+            _ => Quality::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Quality> for String {
+    fn from(e: Quality) -> String {
+        match e {
+            Quality::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Quality {
     fn default() -> Quality {
         Quality::Noop
@@ -34786,6 +37381,7 @@ pub struct ParticipantFeedbackResponse {
 * Result of the call: `call_connected` | `recorded` | `no_answer`.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Result {
     #[serde(rename = "call_connected")]
     CallConnected,
@@ -34795,8 +37391,9 @@ pub enum Result {
     Recorded,
     #[serde(rename = "")]
     Noop,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for Result {
@@ -34806,12 +37403,33 @@ impl std::fmt::Display for Result {
             Result::NoAnswer => "no_answer",
             Result::Recorded => "recorded",
             Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for Result {
+    fn from(s: String) -> Result {
+        match s.as_str() {
+            "call_connected" => Result::CallConnected,
+            "no_answer" => Result::NoAnswer,
+            "recorded" => Result::Recorded,
+            "" => Result::Noop,
+            _ => Result::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<Result> for String {
+    fn from(e: Result) -> String {
+        match e {
+            Result::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for Result {
     fn default() -> Result {
         Result::Noop
@@ -35192,13 +37810,15 @@ pub struct ListMeetingTemplatesResponseData {
 * The query date type for the `from` and `to` parameters.
 */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListArchivedFilesQueryDateType {
     #[serde(rename = "archive_complete_time")]
     ArchiveCompleteTime,
     #[serde(rename = "meeting_start_time")]
     MeetingStartTime,
-    #[serde(other)]
-    FallthroughString,
+    /// A value not known to this version of the client, kept as it was sent.
+    #[schemars(skip)]
+    Unknown(String),
 }
 
 impl std::fmt::Display for ListArchivedFilesQueryDateType {
@@ -35206,12 +37826,31 @@ impl std::fmt::Display for ListArchivedFilesQueryDateType {
         match self {
             ListArchivedFilesQueryDateType::ArchiveCompleteTime => "archive_complete_time",
             ListArchivedFilesQueryDateType::MeetingStartTime => "meeting_start_time",
-            ListArchivedFilesQueryDateType::FallthroughString => "*",
+            ListArchivedFilesQueryDateType::Unknown(s) => s.as_str(),
         }
         .fmt(f)
     }
 }
 
+impl std::convert::From<String> for ListArchivedFilesQueryDateType {
+    fn from(s: String) -> ListArchivedFilesQueryDateType {
+        match s.as_str() {
+            "archive_complete_time" => ListArchivedFilesQueryDateType::ArchiveCompleteTime,
+            "meeting_start_time" => ListArchivedFilesQueryDateType::MeetingStartTime,
+            _ => ListArchivedFilesQueryDateType::Unknown(s),
+        }
+    }
+}
+
+impl std::convert::From<ListArchivedFilesQueryDateType> for String {
+    fn from(e: ListArchivedFilesQueryDateType) -> String {
+        match e {
+            ListArchivedFilesQueryDateType::Unknown(s) => s,
+            e => e.to_string(),
+        }
+    }
+}
+
 impl Default for ListArchivedFilesQueryDateType {
     fn default() -> ListArchivedFilesQueryDateType {
         ListArchivedFilesQueryDateType::MeetingStartTime
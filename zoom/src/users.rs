@@ -45,6 +45,34 @@ impl Users {
         include_fields: crate::types::UsersIncludeFields,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::UsersResponse>> {
+        Ok(self
+            .get_page_with_response(
+                status,
+                page_size,
+                role_id,
+                page_number,
+                include_fields,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_page`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_page_with_response(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: crate::types::UsersIncludeFields,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::UsersResponse>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !include_fields.to_string().is_empty() {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
@@ -66,9 +94,9 @@ impl Users {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users?{}", query_), None);
-        let resp: crate::types::UsersResponseData = self
+        let resp: crate::Response<crate::types::UsersResponseData> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -78,7 +106,7 @@ impl Users {
             .await?;
 
         // Return our response data.
-        Ok(resp.users.to_vec())
+        Ok(resp.map(|r| r.users.to_vec()))
     }
     /**
      * List users.
@@ -162,6 +190,97 @@ impl Users {
         // Return our response data.
         Ok(users)
     }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: crate::types::UsersIncludeFields,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::UsersResponse>>>
+           + Unpin
+           + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !include_fields.to_string().is_empty() {
+            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        }
+        if !role_id.is_empty() {
+            query_args.push(("role_id".to_string(), role_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = self.client.url(&format!("/users?{}", query_), None);
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::UsersResponseData = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.users;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn get_all_stream(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: crate::types::UsersIncludeFields,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::UsersResponse>> + Unpin + '_ {
+        crate::utils::page_items(self.get_all_pages_stream(status, role_id, include_fields, None))
+    }
     /**
      * Create users.
      *
@@ -178,9 +297,22 @@ impl Users {
         &self,
         body: &crate::types::UserCreateRequest,
     ) -> ClientResult<crate::types::UserCreateResponse> {
+        Ok(self.create_with_response(body).await?.body)
+    }
+    /**
+     * Create users.
+     *
+     * This function performs a `POST` to the `/users` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        body: &crate::types::UserCreateRequest,
+    ) -> ClientResult<crate::Response<crate::types::UserCreateResponse>> {
         let url = self.client.url("/users", None);
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -218,6 +350,24 @@ impl Users {
         login_type: crate::types::LoginType,
         encrypted_email: bool,
     ) -> ClientResult<crate::types::UserResponseAllOf> {
+        Ok(self
+            .user_with_response(user_id, login_type, encrypted_email)
+            .await?
+            .body)
+    }
+    /**
+     * Get a user.
+     *
+     * This function performs a `GET` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `user`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn user_with_response(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        encrypted_email: bool,
+    ) -> ClientResult<crate::Response<crate::types::UserResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if encrypted_email {
             query_args.push(("encrypted_email".to_string(), encrypted_email.to_string()));
@@ -235,7 +385,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -275,6 +425,33 @@ impl Users {
         transfer_webinar: bool,
         transfer_recording: bool,
     ) -> ClientResult<()> {
+        self.delete_with_response(
+            user_id,
+            action,
+            transfer_email,
+            transfer_meeting,
+            transfer_webinar,
+            transfer_recording,
+        )
+        .await?;
+        Ok(())
+    }
+    /**
+     * Delete a user.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !action.to_string().is_empty() {
             query_args.push(("action".to_string(), action.to_string()));
@@ -304,7 +481,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -339,6 +516,22 @@ impl Users {
         login_type: crate::types::LoginType,
         body: &crate::types::UserUpdate,
     ) -> ClientResult<()> {
+        self.update_with_response(user_id, login_type, body).await?;
+        Ok(())
+    }
+    /**
+     * Update a user.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        body: &crate::types::UserUpdate,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !login_type.to_string().is_empty() {
             query_args.push(("login_type".to_string(), login_type.to_string()));
@@ -353,7 +546,7 @@ impl Users {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -375,9 +568,21 @@ impl Users {
      *
      */
     pub async fn zak(&self) -> ClientResult<crate::types::UserZakResponse> {
+        Ok(self.zak_with_response().await?.body)
+    }
+    /**
+     * Get user's ZAK.
+     *
+     * This function performs a `GET` to the `/users/me/zak` endpoint.
+     *
+     * As opposed to `zak`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn zak_with_response(
+        &self,
+    ) -> ClientResult<crate::Response<crate::types::UserZakResponse>> {
         let url = self.client.url("/users/me/zak", None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -406,6 +611,19 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn assistant(&self, user_id: &str) -> ClientResult<crate::types::UserAssistantsList> {
+        Ok(self.assistant_with_response(user_id).await?.body)
+    }
+    /**
+     * List user assistants.
+     *
+     * This function performs a `GET` to the `/users/{userId}/assistants` endpoint.
+     *
+     * As opposed to `assistant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assistant_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserAssistantsList>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/assistants",
@@ -414,7 +632,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -447,6 +665,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserAssistantsList,
     ) -> ClientResult<crate::types::AddRoleMembersResponse> {
+        Ok(self
+            .assistant_create_with_response(user_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Add assistants.
+     *
+     * This function performs a `POST` to the `/users/{userId}/assistants` endpoint.
+     *
+     * As opposed to `assistant_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assistant_create_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserAssistantsList,
+    ) -> ClientResult<crate::Response<crate::types::AddRoleMembersResponse>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/assistants",
@@ -455,7 +690,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -484,6 +719,20 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn assistants_delete(&self, user_id: &str) -> ClientResult<()> {
+        self.assistants_delete_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete user assistants.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/assistants` endpoint.
+     *
+     * As opposed to `assistants_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assistants_delete_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/assistants",
@@ -492,7 +741,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -522,6 +771,22 @@ impl Users {
      * * `assistant_id: &str` -- User's first name.
      */
     pub async fn assistant_delete(&self, user_id: &str, assistant_id: &str) -> ClientResult<()> {
+        self.assistant_delete_with_response(user_id, assistant_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a user assistant.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/assistants/{assistantId}` endpoint.
+     *
+     * As opposed to `assistant_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn assistant_delete_with_response(
+        &self,
+        user_id: &str,
+        assistant_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/assistants/{}",
@@ -531,7 +796,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -559,6 +824,19 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn scheduler(&self, user_id: &str) -> ClientResult<crate::types::UserSchedulersList> {
+        Ok(self.scheduler_with_response(user_id).await?.body)
+    }
+    /**
+     * List user schedulers.
+     *
+     * This function performs a `GET` to the `/users/{userId}/schedulers` endpoint.
+     *
+     * As opposed to `scheduler`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn scheduler_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSchedulersList>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/schedulers",
@@ -567,7 +845,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -595,6 +873,20 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn schedulers_delete(&self, user_id: &str) -> ClientResult<()> {
+        self.schedulers_delete_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete user schedulers.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/schedulers` endpoint.
+     *
+     * As opposed to `schedulers_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn schedulers_delete_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/schedulers",
@@ -603,7 +895,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -632,6 +924,22 @@ impl Users {
      * * `scheduler_id: &str` -- User's first name.
      */
     pub async fn scheduler_delete(&self, user_id: &str, scheduler_id: &str) -> ClientResult<()> {
+        self.scheduler_delete_with_response(user_id, scheduler_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a scheduler.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/schedulers/{schedulerId}` endpoint.
+     *
+     * As opposed to `scheduler_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn scheduler_delete_with_response(
+        &self,
+        user_id: &str,
+        scheduler_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/schedulers/{}",
@@ -641,7 +949,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -666,6 +974,17 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn picture(&self, user_id: &str) -> ClientResult<()> {
+        self.picture_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Upload a user's profile picture.
+     *
+     * This function performs a `POST` to the `/users/{userId}/picture` endpoint.
+     *
+     * As opposed to `picture`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn picture_with_response(&self, user_id: &str) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/picture",
@@ -674,7 +993,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -935,6 +1254,25 @@ impl Users {
         option: crate::types::OptionData,
         custom_query_fields: &str,
     ) -> ClientResult<crate::types::UserSettingsResponseOneOf> {
+        Ok(self
+            .setting_with_response(user_id, login_type, option, custom_query_fields)
+            .await?
+            .body)
+    }
+    /**
+     * Get user settings.
+     *
+     * This function performs a `GET` to the `/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `setting`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn setting_with_response(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserSettingsResponseOneOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !custom_query_fields.is_empty() {
             query_args.push((
@@ -958,7 +1296,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -987,6 +1325,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserSettingsUpdateRequestOneOf,
     ) -> ClientResult<()> {
+        self.settings_update_with_response(option, user_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update user settings.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `settings_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn settings_update_with_response(
+        &self,
+        option: crate::types::UserSettingsUpdateOption,
+        user_id: &str,
+        body: &crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !option.to_string().is_empty() {
             query_args.push(("option".to_string(), option.to_string()));
@@ -1001,7 +1356,7 @@ impl Users {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1030,6 +1385,21 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserStatusRequest,
     ) -> ClientResult<()> {
+        self.status_with_response(user_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update user status.
+     *
+     * This function performs a `PUT` to the `/users/{userId}/status` endpoint.
+     *
+     * As opposed to `status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn status_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserStatusRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/status",
@@ -1038,7 +1408,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1070,6 +1440,21 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserPasswordRequest,
     ) -> ClientResult<()> {
+        self.password_with_response(user_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update a user's password.
+     *
+     * This function performs a `PUT` to the `/users/{userId}/password` endpoint.
+     *
+     * As opposed to `password`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn password_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserPasswordRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/password",
@@ -1078,7 +1463,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1103,6 +1488,19 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn permission(&self, user_id: &str) -> ClientResult<crate::types::UserPermissions> {
+        Ok(self.permission_with_response(user_id).await?.body)
+    }
+    /**
+     * Get user permissions.
+     *
+     * This function performs a `GET` to the `/users/{userId}/permissions` endpoint.
+     *
+     * As opposed to `permission`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn permission_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserPermissions>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/permissions",
@@ -1111,7 +1509,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1143,6 +1541,21 @@ impl Users {
         type_: crate::types::UserTokenType,
         ttl: i64,
     ) -> ClientResult<crate::types::UserZakResponse> {
+        Ok(self.token_with_response(user_id, type_, ttl).await?.body)
+    }
+    /**
+     * Get a user token.
+     *
+     * This function performs a `GET` to the `/users/{userId}/token` endpoint.
+     *
+     * As opposed to `token`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn token_with_response(
+        &self,
+        user_id: &str,
+        type_: crate::types::UserTokenType,
+        ttl: i64,
+    ) -> ClientResult<crate::Response<crate::types::UserZakResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if ttl > 0 {
             query_args.push(("ttl".to_string(), ttl.to_string()));
@@ -1160,7 +1573,7 @@ impl Users {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1185,6 +1598,20 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn sso_token_delete(&self, user_id: &str) -> ClientResult<()> {
+        self.sso_token_delete_with_response(user_id).await?;
+        Ok(())
+    }
+    /**
+     * Revoke a user's SSO token.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/token` endpoint.
+     *
+     * As opposed to `sso_token_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn sso_token_delete_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/token",
@@ -1193,7 +1620,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1221,6 +1648,19 @@ impl Users {
      * * `email: &str` -- The email address to be verified.
      */
     pub async fn email(&self, email: &str) -> ClientResult<crate::types::UserEmailResponse> {
+        Ok(self.email_with_response(email).await?.body)
+    }
+    /**
+     * Check a user email.
+     *
+     * This function performs a `GET` to the `/users/email` endpoint.
+     *
+     * As opposed to `email`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn email_with_response(
+        &self,
+        email: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserEmailResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !email.is_empty() {
             query_args.push(("email".to_string(), email.to_string()));
@@ -1228,7 +1668,7 @@ impl Users {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = self.client.url(&format!("/users/email?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1262,6 +1702,21 @@ impl Users {
         user_id: &str,
         body: &crate::types::Members,
     ) -> ClientResult<()> {
+        self.email_update_with_response(user_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update a user's email.
+     *
+     * This function performs a `PUT` to the `/users/{userId}/email` endpoint.
+     *
+     * As opposed to `email_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn email_update_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::Members,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/email",
@@ -1270,7 +1725,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1298,6 +1753,19 @@ impl Users {
         &self,
         vanity_name: &str,
     ) -> ClientResult<crate::types::UserVanityNameResponse> {
+        Ok(self.vanity_name_with_response(vanity_name).await?.body)
+    }
+    /**
+     * Check a user's PM room.
+     *
+     * This function performs a `GET` to the `/users/vanity_name` endpoint.
+     *
+     * As opposed to `vanity_name`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn vanity_name_with_response(
+        &self,
+        vanity_name: &str,
+    ) -> ClientResult<crate::Response<crate::types::UserVanityNameResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !vanity_name.is_empty() {
             query_args.push(("vanity_name".to_string(), vanity_name.to_string()));
@@ -1307,7 +1775,7 @@ impl Users {
             .client
             .url(&format!("/users/vanity_name?{}", query_), None);
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1346,6 +1814,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::SwitchUserAccountRequest,
     ) -> ClientResult<()> {
+        self.switch_account_with_response(account_id, user_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Switch a user's account.
+     *
+     * This function performs a `PUT` to the `/accounts/{accountId}/users/{userId}/account` endpoint.
+     *
+     * As opposed to `switch_account`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn switch_account_with_response(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::SwitchUserAccountRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/accounts/{}/users/{}/account",
@@ -1355,7 +1840,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1391,6 +1876,22 @@ impl Users {
         user_id: &str,
         body: &crate::types::UpdatePresenceStatusRequestData,
     ) -> ClientResult<()> {
+        self.update_presence_status_with_response(user_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a user's presence status.
+     *
+     * This function performs a `PUT` to the `/users/{userId}/presence_status` endpoint.
+     *
+     * As opposed to `update_presence_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_presence_status_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdatePresenceStatusRequestData,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/presence_status",
@@ -1399,7 +1900,7 @@ impl Users {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1432,6 +1933,20 @@ impl Users {
         user_id: &str,
         body: &crate::types::UploadVbRequest,
     ) -> ClientResult<crate::types::Files> {
+        Ok(self.upload_v_buser_with_response(user_id, body).await?.body)
+    }
+    /**
+     * Upload virtual background files.
+     *
+     * This function performs a `POST` to the `/users/{userId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `upload_v_buser`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn upload_v_buser_with_response(
+        &self,
+        user_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> ClientResult<crate::Response<crate::types::Files>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/settings/virtual_backgrounds",
@@ -1440,7 +1955,7 @@ impl Users {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1467,6 +1982,21 @@ impl Users {
      * * `user_id: &str` -- Unique identifier of the user. Retrieve the value of this field by calling the [List users](https://marketplace.zoom.us/docs/api-reference/zoom-api/users/users) API. .
      */
     pub async fn del_vb(&self, user_id: &str, file_ids: &str) -> ClientResult<()> {
+        self.del_vb_with_response(user_id, file_ids).await?;
+        Ok(())
+    }
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn del_vb_with_response(
+        &self,
+        user_id: &str,
+        file_ids: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !file_ids.is_empty() {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
@@ -1481,7 +2011,7 @@ impl Users {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
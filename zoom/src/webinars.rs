@@ -40,6 +40,24 @@ impl Webinars {
         page_size: i64,
         page_number: i64,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .get_with_response(user_id, page_size, page_number)
+            .await?
+            .body)
+    }
+    /**
+     * List webinars.
+     *
+     * This function performs a `GET` to the `/users/{userId}/webinars` endpoint.
+     *
+     * As opposed to `get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_with_response(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        page_number: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if page_number > 0 {
             query_args.push(("page_number".to_string(), page_number.to_string()));
@@ -57,7 +75,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -88,6 +106,19 @@ impl Webinars {
         &self,
         user_id: &str,
     ) -> ClientResult<crate::types::WebinarCreateResponseAllOf> {
+        Ok(self.create_with_response(user_id).await?.body)
+    }
+    /**
+     * Create a webinar.
+     *
+     * This function performs a `POST` to the `/users/{userId}/webinars` endpoint.
+     *
+     * As opposed to `create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn create_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebinarCreateResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/webinars",
@@ -96,7 +127,7 @@ impl Webinars {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -127,6 +158,24 @@ impl Webinars {
         occurrence_id: &str,
         show_previous_occurrences: bool,
     ) -> ClientResult<crate::types::WebinarResponseAllOf> {
+        Ok(self
+            .webinar_with_response(webinar_id, occurrence_id, show_previous_occurrences)
+            .await?
+            .body)
+    }
+    /**
+     * Get a webinar.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `webinar`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn webinar_with_response(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> ClientResult<crate::Response<crate::types::WebinarResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -147,7 +196,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -184,6 +233,23 @@ impl Webinars {
         occurrence_id: &str,
         cancel_webinar_reminder: &str,
     ) -> ClientResult<()> {
+        self.delete_with_response(webinar_id, occurrence_id, cancel_webinar_reminder)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a webinar.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_with_response(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !cancel_webinar_reminder.is_empty() {
             query_args.push((
@@ -204,7 +270,7 @@ impl Webinars {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -232,6 +298,21 @@ impl Webinars {
      * * `occurrence_id: &str` -- Webinar occurrence id. Support change of agenda, start_time, duration, settings: {host_video, panelist_video, hd_video, watermark, auto_recording}.
      */
     pub async fn update(&self, webinar_id: i64, occurrence_id: &str) -> ClientResult<()> {
+        self.update_with_response(webinar_id, occurrence_id).await?;
+        Ok(())
+    }
+    /**
+     * Update a webinar.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn update_with_response(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -246,7 +327,7 @@ impl Webinars {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -284,6 +365,24 @@ impl Webinars {
         page_size: i64,
         next_page_token: &str,
     ) -> ClientResult<Vec<crate::types::Participants>> {
+        Ok(self
+            .list_participants_with_response(webinar_id, page_size, next_page_token)
+            .await?
+            .body)
+    }
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_participants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_participants_with_response(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<Vec<crate::types::Participants>>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -300,9 +399,9 @@ impl Webinars {
             ),
             None,
         );
-        let resp: crate::types::ListWebinarParticipantsResponse = self
+        let resp: crate::Response<crate::types::ListWebinarParticipantsResponse> = self
             .client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -312,7 +411,7 @@ impl Webinars {
             .await?;
 
         // Return our response data.
-        Ok(resp.participants.to_vec())
+        Ok(resp.map(|r| r.participants.to_vec()))
     }
     /**
      * List webinar participants.
@@ -397,6 +496,88 @@ impl Webinars {
         // Return our response data.
         Ok(participants)
     }
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_all_participants`, this function returns a stream of the pages of the request, only fetching each page when the stream gets to it. Each page has the `next` cursor, pass it in as `page_cursor` to pick up where you left off.
+     */
+    pub fn list_all_participants_pages_stream(
+        &self,
+        webinar_id: &str,
+        page_cursor: Option<String>,
+    ) -> impl futures::Stream<Item = ClientResult<crate::utils::Page<crate::types::Participants>>>
+           + Unpin
+           + '_ {
+        let url = self.client.url(
+            &format!(
+                "/past_webinars/{}/participants",
+                crate::progenitor_support::encode_path(webinar_id),
+            ),
+            None,
+        );
+
+        Box::pin(futures::stream::try_unfold(
+            Some(page_cursor),
+            move |cursor| {
+                let url = url.clone();
+                async move {
+                    // We are out of pages.
+                    let cursor = match cursor {
+                        Some(cursor) => cursor,
+                        None => return Ok(None),
+                    };
+
+                    let url = match &cursor {
+                        Some(c) => format!(
+                            "{}{}next_page_token={}",
+                            url,
+                            if url.contains('?') { '&' } else { '?' },
+                            c
+                        ),
+                        None => url,
+                    };
+                    let resp: crate::types::ListWebinarParticipantsResponse = self
+                        .client
+                        .get(
+                            &url,
+                            crate::Message {
+                                body: None,
+                                content_type: None,
+                            },
+                        )
+                        .await?;
+
+                    let next = Some(resp.next_page_token).filter(|n| !n.is_empty());
+                    let items = resp.participants;
+
+                    // Don't keep asking for the same page.
+                    let next = next.filter(|n| Some(n) != cursor.as_ref());
+                    Ok(Some((
+                        crate::utils::Page {
+                            items,
+                            next: next.clone(),
+                        },
+                        next.map(Some),
+                    )))
+                }
+            },
+        ))
+    }
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_all_participants`, this function returns a stream of the items of the request, only fetching each page when the stream gets to it.
+     */
+    pub fn list_all_participants_stream(
+        &self,
+        webinar_id: &str,
+    ) -> impl futures::Stream<Item = ClientResult<crate::types::Participants>> + Unpin + '_ {
+        crate::utils::page_items(self.list_all_participants_pages_stream(webinar_id, None))
+    }
     /**
      * Update webinar status.
      *
@@ -418,6 +599,21 @@ impl Webinars {
         webinar_id: i64,
         body: &crate::types::WebinarStatusRequest,
     ) -> ClientResult<()> {
+        self.status_with_response(webinar_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Update webinar status.
+     *
+     * This function performs a `PUT` to the `/webinars/{webinarId}/status` endpoint.
+     *
+     * As opposed to `status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn status_with_response(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarStatusRequest,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/status",
@@ -426,7 +622,7 @@ impl Webinars {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -454,6 +650,19 @@ impl Webinars {
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
      */
     pub async fn panelist(&self, webinar_id: i64) -> ClientResult<crate::types::Domains> {
+        Ok(self.panelist_with_response(webinar_id).await?.body)
+    }
+    /**
+     * List panelists.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/panelists` endpoint.
+     *
+     * As opposed to `panelist`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn panelist_with_response(
+        &self,
+        webinar_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/panelists",
@@ -462,7 +671,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -493,6 +702,21 @@ impl Webinars {
         webinar_id: i64,
         body: &crate::types::WebinarPanelist,
     ) -> ClientResult<()> {
+        self.panelist_create_with_response(webinar_id, body).await?;
+        Ok(())
+    }
+    /**
+     * Add panelists.
+     *
+     * This function performs a `POST` to the `/webinars/{webinarId}/panelists` endpoint.
+     *
+     * As opposed to `panelist_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn panelist_create_with_response(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarPanelist,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/panelists",
@@ -501,7 +725,7 @@ impl Webinars {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -527,6 +751,20 @@ impl Webinars {
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
      */
     pub async fn panelists_delete(&self, webinar_id: i64) -> ClientResult<()> {
+        self.panelists_delete_with_response(webinar_id).await?;
+        Ok(())
+    }
+    /**
+     * Remove panelists.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/panelists` endpoint.
+     *
+     * As opposed to `panelists_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn panelists_delete_with_response(
+        &self,
+        webinar_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/panelists",
@@ -535,7 +773,7 @@ impl Webinars {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -564,6 +802,22 @@ impl Webinars {
      * * `panelist_id: i64` -- The panelist ID or panelist email.
      */
     pub async fn panelist_delete(&self, webinar_id: i64, panelist_id: i64) -> ClientResult<()> {
+        self.panelist_delete_with_response(webinar_id, panelist_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Remove a panelist.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/panelists/{panelistId}` endpoint.
+     *
+     * As opposed to `panelist_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn panelist_delete_with_response(
+        &self,
+        webinar_id: i64,
+        panelist_id: i64,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/panelists/{}",
@@ -573,7 +827,7 @@ impl Webinars {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -619,6 +873,36 @@ impl Webinars {
         page_number: i64,
         next_page_token: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .registrant_with_response(
+                webinar_id,
+                occurrence_id,
+                status,
+                tracking_source_id,
+                page_size,
+                page_number,
+                next_page_token,
+            )
+            .await?
+            .body)
+    }
+    /**
+     * List webinar registrants.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/registrants` endpoint.
+     *
+     * As opposed to `registrant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_with_response(
+        &self,
+        webinar_id: &str,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        tracking_source_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -651,7 +935,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -682,6 +966,23 @@ impl Webinars {
         webinar_id: &str,
         occurrence_ids: &str,
     ) -> ClientResult<crate::types::WebinarRegistrantCreateResponse> {
+        Ok(self
+            .registrant_create_with_response(webinar_id, occurrence_ids)
+            .await?
+            .body)
+    }
+    /**
+     * Add a webinar registrant.
+     *
+     * This function performs a `POST` to the `/webinars/{webinarId}/registrants` endpoint.
+     *
+     * As opposed to `registrant_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_create_with_response(
+        &self,
+        webinar_id: &str,
+        occurrence_ids: &str,
+    ) -> ClientResult<crate::Response<crate::types::WebinarRegistrantCreateResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_ids.is_empty() {
             query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
@@ -696,7 +997,7 @@ impl Webinars {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -735,6 +1036,23 @@ impl Webinars {
         webinar_id: &str,
         body: &crate::types::AddBatchRegistrantsRequest,
     ) -> ClientResult<crate::types::AddBatchRegistrantsResponse> {
+        Ok(self
+            .add_batch_registrants_with_response(webinar_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Perform batch registration.
+     *
+     * This function performs a `POST` to the `/webinars/{webinarId}/batch_registrants` endpoint.
+     *
+     * As opposed to `add_batch_registrants`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn add_batch_registrants_with_response(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> ClientResult<crate::Response<crate::types::AddBatchRegistrantsResponse>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/batch_registrants",
@@ -743,7 +1061,7 @@ impl Webinars {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -775,6 +1093,23 @@ impl Webinars {
         occurrence_id: &str,
         body: &crate::types::RegistrantStatus,
     ) -> ClientResult<()> {
+        self.registrant_status_with_response(webinar_id, occurrence_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update registrant's status.
+     *
+     * This function performs a `PUT` to the `/webinars/{webinarId}/registrants/status` endpoint.
+     *
+     * As opposed to `registrant_status`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_status_with_response(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -789,7 +1124,7 @@ impl Webinars {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -815,6 +1150,19 @@ impl Webinars {
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
      */
     pub async fn past(&self, webinar_id: i64) -> ClientResult<crate::types::Domains> {
+        Ok(self.past_with_response(webinar_id).await?.body)
+    }
+    /**
+     * List past webinar instances.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/instances` endpoint.
+     *
+     * As opposed to `past`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn past_with_response(
+        &self,
+        webinar_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/past_webinars/{}/instances",
@@ -823,7 +1171,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -849,6 +1197,19 @@ impl Webinars {
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
      */
     pub async fn poll(&self, webinar_id: i64) -> ClientResult<crate::types::Domains> {
+        Ok(self.poll_with_response(webinar_id).await?.body)
+    }
+    /**
+     * List a webinar's polls .
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/polls` endpoint.
+     *
+     * As opposed to `poll`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_with_response(
+        &self,
+        webinar_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/polls",
@@ -857,7 +1218,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -887,6 +1248,20 @@ impl Webinars {
         webinar_id: i64,
         body: &crate::types::Poll,
     ) -> ClientResult<crate::types::MeetingPollGetResponseAllOf> {
+        Ok(self.poll_create_with_response(webinar_id, body).await?.body)
+    }
+    /**
+     * Create a webinar's poll.
+     *
+     * This function performs a `POST` to the `/webinars/{webinarId}/polls` endpoint.
+     *
+     * As opposed to `poll_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_create_with_response(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/polls",
@@ -895,7 +1270,7 @@ impl Webinars {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -926,6 +1301,20 @@ impl Webinars {
         webinar_id: i64,
         poll_id: &str,
     ) -> ClientResult<crate::types::MeetingPollGetResponseAllOf> {
+        Ok(self.poll_get_with_response(webinar_id, poll_id).await?.body)
+    }
+    /**
+     * Get a webinar poll.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_get_with_response(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::MeetingPollGetResponseAllOf>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/polls/{}",
@@ -935,7 +1324,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -967,6 +1356,23 @@ impl Webinars {
         poll_id: &str,
         body: &crate::types::Poll,
     ) -> ClientResult<()> {
+        self.poll_update_with_response(webinar_id, poll_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a webinar poll.
+     *
+     * This function performs a `PUT` to the `/webinars/{webinarId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_update_with_response(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/polls/{}",
@@ -976,7 +1382,7 @@ impl Webinars {
             None,
         );
         self.client
-            .put(
+            .put_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1003,6 +1409,21 @@ impl Webinars {
      * * `poll_id: &str` -- User's first name.
      */
     pub async fn poll_delete(&self, webinar_id: i64, poll_id: &str) -> ClientResult<()> {
+        self.poll_delete_with_response(webinar_id, poll_id).await?;
+        Ok(())
+    }
+    /**
+     * Delete a webinar poll.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_delete`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn poll_delete_with_response(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/polls/{}",
@@ -1012,7 +1433,7 @@ impl Webinars {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1043,6 +1464,22 @@ impl Webinars {
         &self,
         webinar_id: i64,
     ) -> ClientResult<crate::types::WebinarRegistrantQuestions> {
+        Ok(self
+            .registrants_questions_get_with_response(webinar_id)
+            .await?
+            .body)
+    }
+    /**
+     * List registration questions.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/registrants/questions` endpoint.
+     *
+     * As opposed to `registrants_questions_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrants_questions_get_with_response(
+        &self,
+        webinar_id: i64,
+    ) -> ClientResult<crate::Response<crate::types::WebinarRegistrantQuestions>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/registrants/questions",
@@ -1051,7 +1488,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1084,6 +1521,22 @@ impl Webinars {
         webinar_id: i64,
         body: &crate::types::WebinarRegistrantQuestions,
     ) -> ClientResult<()> {
+        self.registrant_question_update_with_response(webinar_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update registration questions.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}/registrants/questions` endpoint.
+     *
+     * As opposed to `registrant_question_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_question_update_with_response(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarRegistrantQuestions,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/registrants/questions",
@@ -1092,7 +1545,7 @@ impl Webinars {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1125,6 +1578,24 @@ impl Webinars {
         registrant_id: &str,
         occurrence_id: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .registrant_get_with_response(webinar_id, registrant_id, occurrence_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get a webinar registrant.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `registrant_get`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn registrant_get_with_response(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -1140,7 +1611,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1171,6 +1642,23 @@ impl Webinars {
         registrant_id: &str,
         occurrence_id: &str,
     ) -> ClientResult<()> {
+        self.delete_registrant_with_response(webinar_id, registrant_id, occurrence_id)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Delete a webinar registrant.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `delete_registrant`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn delete_registrant_with_response(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> ClientResult<crate::Response<()>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !occurrence_id.is_empty() {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
@@ -1186,7 +1674,7 @@ impl Webinars {
             None,
         );
         self.client
-            .delete(
+            .delete_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1218,6 +1706,25 @@ impl Webinars {
         next_page_token: &str,
         webinar_uuid: &str,
     ) -> ClientResult<crate::types::Domains> {
+        Ok(self
+            .absentee_with_response(occurrence_id, page_size, next_page_token, webinar_uuid)
+            .await?
+            .body)
+    }
+    /**
+     * Get webinar absentees.
+     *
+     * This function performs a `GET` to the `/past_webinars/{WebinarUUID}/absentees` endpoint.
+     *
+     * As opposed to `absentee`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn absentee_with_response(
+        &self,
+        occurrence_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_uuid: &str,
+    ) -> ClientResult<crate::Response<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !next_page_token.is_empty() {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
@@ -1238,7 +1745,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1269,6 +1776,22 @@ impl Webinars {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::GetTrackingSourcesResponse> {
+        Ok(self
+            .get_tracking_sources_with_response(webinar_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get webinar tracking sources.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/tracking_sources` endpoint.
+     *
+     * As opposed to `get_tracking_sources`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_tracking_sources_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetTrackingSourcesResponse>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/tracking_sources",
@@ -1277,7 +1800,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1310,6 +1833,22 @@ impl Webinars {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::ReportMeetingPollsResponse> {
+        Ok(self
+            .list_past_poll_results_with_response(webinar_id)
+            .await?
+            .body)
+    }
+    /**
+     * List past webinar poll results.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/polls` endpoint.
+     *
+     * As opposed to `list_past_poll_results`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_past_poll_results_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportMeetingPollsResponse>> {
         let url = self.client.url(
             &format!(
                 "/past_webinars/{}/polls",
@@ -1318,7 +1857,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1353,6 +1892,19 @@ impl Webinars {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::ReportWebinarQaResponse> {
+        Ok(self.list_past_qa_with_response(webinar_id).await?.body)
+    }
+    /**
+     * List Q&A of past webinar.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/qa` endpoint.
+     *
+     * As opposed to `list_past_qa`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_past_qa_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ReportWebinarQaResponse>> {
         let url = self.client.url(
             &format!(
                 "/past_webinars/{}/qa",
@@ -1361,7 +1913,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1386,6 +1938,19 @@ impl Webinars {
         &self,
         user_id: &str,
     ) -> ClientResult<crate::types::ListWebinarTemplatesResponse> {
+        Ok(self.list_templates_with_response(user_id).await?.body)
+    }
+    /**
+     * List webinar templates.
+     *
+     * This function performs a `GET` to the `/users/{userId}/webinar_templates` endpoint.
+     *
+     * As opposed to `list_templates`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn list_templates_with_response(
+        &self,
+        user_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::ListWebinarTemplatesResponse>> {
         let url = self.client.url(
             &format!(
                 "/users/{}/webinar_templates",
@@ -1394,7 +1959,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1427,6 +1992,22 @@ impl Webinars {
         &self,
         webinar_id: &str,
     ) -> ClientResult<crate::types::GetLiveStreamDetailsResponse> {
+        Ok(self
+            .get_live_stream_details_with_response(webinar_id)
+            .await?
+            .body)
+    }
+    /**
+     * Get live stream details.
+     *
+     * This function performs a `GET` to the `/webinars/{webinarId}/livestream` endpoint.
+     *
+     * As opposed to `get_live_stream_details`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn get_live_stream_details_with_response(
+        &self,
+        webinar_id: &str,
+    ) -> ClientResult<crate::Response<crate::types::GetLiveStreamDetailsResponse>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/livestream",
@@ -1435,7 +2016,7 @@ impl Webinars {
             None,
         );
         self.client
-            .get(
+            .get_with_response(
                 &url,
                 crate::Message {
                     body: None,
@@ -1468,6 +2049,22 @@ impl Webinars {
         webinar_id: &str,
         body: &crate::types::MeetingLiveStream,
     ) -> ClientResult<()> {
+        self.live_stream_update_with_response(webinar_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update a live stream.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}/livestream` endpoint.
+     *
+     * As opposed to `live_stream_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn live_stream_update_with_response(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/livestream",
@@ -1476,7 +2073,7 @@ impl Webinars {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1508,6 +2105,22 @@ impl Webinars {
         webinar_id: i64,
         body: &crate::types::WebinarLiveStreamStatus,
     ) -> ClientResult<()> {
+        self.live_stream_status_update_with_response(webinar_id, body)
+            .await?;
+        Ok(())
+    }
+    /**
+     * Update Live Stream Status.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}/livestream/status` endpoint.
+     *
+     * As opposed to `live_stream_status_update`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn live_stream_status_update_with_response(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarLiveStreamStatus,
+    ) -> ClientResult<crate::Response<()>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/livestream/status",
@@ -1516,7 +2129,7 @@ impl Webinars {
             None,
         );
         self.client
-            .patch(
+            .patch_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),
@@ -1547,6 +2160,23 @@ impl Webinars {
         webinar_id: i64,
         body: &crate::types::InviteLink,
     ) -> ClientResult<crate::types::InviteLinks> {
+        Ok(self
+            .invite_links_create_with_response(webinar_id, body)
+            .await?
+            .body)
+    }
+    /**
+     * Create webinar's invite links.
+     *
+     * This function performs a `POST` to the `/webinars/{webinarId}/invite_links` endpoint.
+     *
+     * As opposed to `invite_links_create`, this function returns the whole response: the status code, headers and raw bytes along with the parsed body.
+     */
+    pub async fn invite_links_create_with_response(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> ClientResult<crate::Response<crate::types::InviteLinks>> {
         let url = self.client.url(
             &format!(
                 "/webinars/{}/invite_links",
@@ -1555,7 +2185,7 @@ impl Webinars {
             None,
         );
         self.client
-            .post(
+            .post_with_response(
                 &url,
                 crate::Message {
                     body: Some(reqwest::Body::from(serde_json::to_vec(body)?)),